@@ -0,0 +1,269 @@
+#!/usr/bin/env python3
+"""Generate the typed client layer from openapi/openapi.json.
+
+Writes src/flowcatalyst/generated/models.py (a pydantic model per
+component schema) and src/flowcatalyst/generated/operations.py (one
+method per operationId). Stdlib only, so `make sdk-generate` needs no
+virtualenv. The output is committed; never edit it by hand — refresh the
+spec with `make sdk-spec` and re-run this instead.
+
+    python3 scripts/generate.py [--check]
+
+--check exits 1 when the committed output is stale, for CI.
+"""
+
+from __future__ import annotations
+
+import json
+import keyword
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+SPEC = ROOT / "openapi" / "openapi.json"
+OUT = ROOT / "src" / "flowcatalyst" / "generated"
+
+HEADER = "# Code generated by scripts/generate.py from openapi/openapi.json. DO NOT EDIT.\n"
+
+# Attributes of pydantic.BaseModel a field must not shadow.
+RESERVED = {
+    "construct", "copy", "dict", "fields", "from_orm", "json", "parse_file",
+    "parse_obj", "parse_raw", "schema", "schema_json", "update_forward_refs",
+    "validate",
+}
+
+
+def snake(name: str) -> str:
+    name = re.sub(r"([A-Z]+)([A-Z][a-z])", r"\1_\2", name)
+    name = re.sub(r"([a-z0-9])([A-Z])", r"\1_\2", name)
+    name = re.sub(r"[^0-9A-Za-z]+", "_", name)
+    return name.strip("_").lower()
+
+
+def ident(name: str) -> str:
+    """A safe Python identifier for a field or parameter."""
+    out = snake(name)
+    if keyword.iskeyword(out) or out in RESERVED:
+        out += "_"
+    return out
+
+
+def ref_name(ref: str) -> str:
+    return ref.rsplit("/", 1)[-1]
+
+
+def py_type(schema: dict) -> tuple[str, bool]:
+    """The annotation for schema and whether it admits null."""
+    if "$ref" in schema:
+        return ref_name(schema["$ref"]), False
+    types = schema.get("type")
+    nullable = False
+    if isinstance(types, list):
+        nullable = "null" in types
+        types = [t for t in types if t != "null"]
+        types = types[0] if len(types) == 1 else None
+    if types == "string":
+        return ("datetime" if schema.get("format") == "date-time" else "str"), nullable
+    if types == "integer":
+        return "int", nullable
+    if types == "number":
+        return "float", nullable
+    if types == "boolean":
+        return "bool", nullable
+    if types == "array":
+        item, _ = py_type(schema.get("items") or {})
+        return f"list[{item}]", nullable
+    if types == "object":
+        extra = schema.get("additionalProperties")
+        if isinstance(extra, dict) and extra:
+            value, _ = py_type(extra)
+            return f"dict[str, {value}]", nullable
+        return "dict[str, Any]", nullable
+    return "Any", nullable
+
+
+def doc(text: str | None, indent: str) -> list[str]:
+    if not text:
+        return []
+    text = text.strip().replace("\\", "\\\\").replace('"""', '\\"\\"\\"')
+    lines = text.splitlines()
+    if len(lines) == 1:
+        return [f'{indent}"""{lines[0]}"""']
+    return [f'{indent}"""{lines[0]}', *[f"{indent}{ln}".rstrip() for ln in lines[1:]], f'{indent}"""']
+
+
+def gen_models(spec: dict) -> str:
+    schemas = spec["components"]["schemas"]
+    out = [
+        HEADER,
+        '"""Request and response models for the FlowCatalyst platform API."""',
+        "",
+        "from __future__ import annotations",
+        "",
+        "from datetime import datetime",
+        "from typing import Any",
+        "",
+        "from pydantic import BaseModel, ConfigDict, Field",
+        "",
+        "",
+        "class Model(BaseModel):",
+        '    """Base for every generated model.',
+        "",
+        "    Fields are snake_case and aliased to the API's camelCase; either",
+        "    name is accepted on input. Unknown fields are kept, so a client",
+        "    built against an older spec still round-trips newer responses.",
+        '    """',
+        "",
+        '    model_config = ConfigDict(populate_by_name=True, extra="allow", protected_namespaces=())',
+    ]
+    for name in sorted(schemas):
+        schema = schemas[name]
+        out += ["", "", f"class {name}(Model):"]
+        body = doc(schema.get("description"), "    ")
+        required = set(schema.get("required", []))
+        for prop, ps in schema.get("properties", {}).items():
+            if prop == "$schema":
+                continue
+            field = ident(prop)
+            ann, nullable = py_type(ps)
+            args = []
+            optional = nullable or prop not in required
+            if optional:
+                ann += " | None"
+                args.append("default=None")
+            if field != prop:
+                args.append(f"alias={prop!r}")
+            if ps.get("description"):
+                args.append(f"description={ps['description']!r}")
+            if args:
+                body.append(f"    {field}: {ann} = Field({', '.join(args)})")
+            else:
+                body.append(f"    {field}: {ann}")
+        out += body or ["    pass"]
+    out += ["", ""]
+    out += [f"{name}.model_rebuild()" for name in sorted(schemas)]
+    return "\n".join(out) + "\n"
+
+
+def response_type(op: dict) -> str:
+    for code, resp in sorted(op.get("responses", {}).items()):
+        if not code.startswith("2"):
+            continue
+        schema = resp.get("content", {}).get("application/json", {}).get("schema")
+        if schema:
+            return py_type(schema)[0]
+    return "None"
+
+
+def gen_operations(spec: dict) -> str:
+    out = [
+        HEADER,
+        '"""One method per platform API operation, named after its operationId."""',
+        "",
+        "from __future__ import annotations",
+        "",
+        "from datetime import datetime  # noqa: F401 (used in annotations)",
+        "from typing import Any",
+        "from urllib.parse import quote",
+        "",
+        "from . import models as m",
+        "",
+        "",
+        "def _path(value: Any) -> str:",
+        '    return quote(str(value), safe="")',
+        "",
+        "",
+        "class Operations:",
+        '    """Typed wrappers over _request, mixed into FlowCatalystClient."""',
+        "",
+        "    def _request(self, method: str, path: str, *, params: dict[str, Any] | None = None,",
+        "                 headers: dict[str, Any] | None = None, json: Any = None, response: Any = None) -> Any:",
+        "        raise NotImplementedError",
+    ]
+
+    def qualify(ann: str) -> str:
+        # Model names live in the models module; builtins and typing don't.
+        return re.sub(r"\b([A-Z]\w*)\b", lambda mt: mt.group(1) if mt.group(1) in ("Any", "None") else f"m.{mt.group(1)}", ann)
+
+    for path, ops in spec["paths"].items():
+        for method, op in ops.items():
+            name = snake(op["operationId"])
+            positional, keywords = [], []
+            path_expr = path
+            params, headers = [], []
+            seen = set()
+            for p in op.get("parameters", []):
+                arg = ident(p["name"])
+                if arg in seen:
+                    # An alias of an earlier parameter (e.g. pageSize/page_size).
+                    continue
+                seen.add(arg)
+                ann, _ = py_type(p.get("schema") or {})
+                ann = qualify(ann)
+                if p["in"] == "path":
+                    positional.append(f"{arg}: {ann}")
+                    path_expr = path_expr.replace("{" + p["name"] + "}", "{_path(" + arg + ")}")
+                elif p.get("required"):
+                    keywords.append(f"{arg}: {ann}")
+                else:
+                    keywords.append(f"{arg}: {ann} | None = None")
+                if p["in"] == "query":
+                    params.append((p["name"], arg))
+                elif p["in"] == "header":
+                    headers.append((p["name"], arg))
+            body = None
+            if "requestBody" in op:
+                schema = op["requestBody"]["content"].get("application/json", {}).get("schema", {})
+                ann = qualify(py_type(schema)[0])
+                ann = f"{ann} | dict[str, Any]" if ann != "Any" else ann
+                if op["requestBody"].get("required"):
+                    positional.append(f"body: {ann}")
+                else:
+                    positional.append(f"body: {ann} | None = None")
+                body = "body"
+            resp = response_type(op)
+            sig = ["self", *positional]
+            if keywords:
+                sig += ["*", *keywords]
+            out += ["", f"    def {name}({', '.join(sig)}) -> {qualify(resp)}:"]
+            out += doc(op.get("summary"), "        ")
+            call = [repr(method.upper()), ('f"' + path_expr + '"') if "{" in path_expr else repr(path_expr)]
+            if params:
+                call.append("params={" + ", ".join(f"{k!r}: {v}" for k, v in params) + "}")
+            if headers:
+                call.append("headers={" + ", ".join(f"{k!r}: {v}" for k, v in headers) + "}")
+            if body:
+                call.append(f"json={body}")
+            if resp != "None":
+                call.append(f"response={qualify(resp)}")
+            out.append(f"        return self._request({', '.join(call)})")
+    return "\n".join(out) + "\n"
+
+
+def main() -> int:
+    spec = json.loads(SPEC.read_text())
+    files = {
+        OUT / "models.py": gen_models(spec),
+        OUT / "operations.py": gen_operations(spec),
+        OUT / "__init__.py": HEADER,
+    }
+    check = "--check" in sys.argv[1:]
+    stale = []
+    for path, text in files.items():
+        if path.exists() and path.read_text() == text:
+            continue
+        stale.append(path.relative_to(ROOT))
+        if not check:
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(text)
+    if check and stale:
+        print("stale generated files (run scripts/generate.py):", *stale, sep="\n  ", file=sys.stderr)
+        return 1
+    for path in stale:
+        print(f">> wrote {path}")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,21 @@
+"""FlowCatalyst Python SDK.
+
+The typed API client is generated from the platform's OpenAPI spec
+(see scripts/generate.py); the webhook and outbox helpers are
+hand-written to match the Go and TypeScript SDKs.
+"""
+
+from .auth import ClientCredentialsProvider
+from .client import FlowCatalystClient, TokenProvider
+from .errors import APIError, AuthError, FlowCatalystError
+from .generated import models
+
+__all__ = [
+    "APIError",
+    "AuthError",
+    "ClientCredentialsProvider",
+    "FlowCatalystClient",
+    "FlowCatalystError",
+    "TokenProvider",
+    "models",
+]
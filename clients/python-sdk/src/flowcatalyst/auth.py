@@ -0,0 +1,96 @@
+"""Service-account tokens via the OAuth2 client_credentials grant."""
+
+from __future__ import annotations
+
+import threading
+import time
+from collections.abc import Callable, Sequence
+
+import httpx
+
+from .errors import AuthError
+
+
+class ClientCredentialsProvider:
+    """Obtains and refreshes service-account access tokens. Pass it as the
+    client's token provider:
+
+    >>> cc = ClientCredentialsProvider("https://fc.example.com", "id", "secret")
+    >>> fc = FlowCatalystClient("https://fc.example.com", token_provider=cc)
+
+    A token is reused until it is within ``safety_window`` seconds of
+    expiry. Safe for concurrent use.
+    """
+
+    def __init__(
+        self,
+        issuer_url: str,
+        client_id: str,
+        client_secret: str,
+        *,
+        scopes: Sequence[str] = (),
+        audience: str | None = None,
+        safety_window: float = 60.0,
+        http_client: httpx.Client | None = None,
+        clock: Callable[[], float] = time.monotonic,
+    ) -> None:
+        self.issuer_url = issuer_url.rstrip("/")
+        self.client_id = client_id
+        self.client_secret = client_secret
+        self.scopes = list(scopes)
+        self.audience = audience
+        self.safety_window = safety_window
+        self._http = http_client or httpx.Client(timeout=30.0)
+        self._clock = clock
+        self._lock = threading.Lock()
+        self._token = ""
+        self._expires_at = 0.0
+
+    def __call__(self) -> str:
+        return self.token()
+
+    def token(self) -> str:
+        """A valid access token, fetching a fresh one when needed."""
+        with self._lock:
+            if self._token and self._clock() < self._expires_at - self.safety_window:
+                return self._token
+            self._refresh_locked()
+            return self._token
+
+    def invalidate(self) -> None:
+        """Drop the cached token; the next call fetches."""
+        with self._lock:
+            self._token = ""
+            self._expires_at = 0.0
+
+    def _refresh_locked(self) -> None:
+        form = {
+            "grant_type": "client_credentials",
+            "client_id": self.client_id,
+            "client_secret": self.client_secret,
+        }
+        if self.scopes:
+            form["scope"] = " ".join(self.scopes)
+        if self.audience:
+            form["audience"] = self.audience
+        try:
+            resp = self._http.post(
+                self.issuer_url + "/oauth/token",
+                data=form,
+                headers={"Accept": "application/json"},
+            )
+        except httpx.HTTPError as e:
+            raise AuthError(f"client_credentials grant: {e}") from e
+        if not resp.is_success:
+            raise AuthError(f"client_credentials grant failed ({resp.status_code}): {resp.text}")
+        try:
+            out = resp.json()
+        except ValueError as e:
+            raise AuthError(f"parse token response: {e}") from e
+        if not out.get("access_token"):
+            raise AuthError("token response missing access_token")
+        self._token = out["access_token"]
+        # Without expires_in, treat the token as ~5 minutes long so it is
+        # still refreshed.
+        expires_in = out.get("expires_in") or 300
+        self._expires_at = self._clock() + float(expires_in)
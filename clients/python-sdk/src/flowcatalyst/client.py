@@ -0,0 +1,148 @@
+"""The platform API client."""
+
+from __future__ import annotations
+
+import functools
+import time
+from collections.abc import Callable
+from datetime import datetime
+from typing import Any
+
+import httpx
+from pydantic import BaseModel, TypeAdapter
+
+from .errors import APIError, FlowCatalystError
+from .generated.operations import Operations
+
+TokenProvider = Callable[[], str]
+
+
+class FlowCatalystClient(Operations):
+    """A synchronous client for the FlowCatalyst platform API.
+
+    Every operation in the OpenAPI spec is a method named after its
+    operationId in snake_case (``create_event_type``, ``list_subscriptions``,
+    ...). Request bodies take the generated model or a plain dict;
+    responses are parsed into the generated models.
+
+    >>> with FlowCatalystClient("http://localhost:8080", token="...") as fc:
+    ...     fc.list_event_types()
+
+    Transport errors and the transient statuses (see ``APIError.retryable``)
+    are retried with exponential backoff, ``retry_attempts`` tries in all.
+    """
+
+    def __init__(
+        self,
+        base_url: str,
+        *,
+        token: str | None = None,
+        token_provider: TokenProvider | None = None,
+        timeout: float = 30.0,
+        retry_attempts: int = 3,
+        retry_delay: float = 0.1,
+        http_client: httpx.Client | None = None,
+    ) -> None:
+        if token is not None and token_provider is not None:
+            raise ValueError("pass token or token_provider, not both")
+        if token is not None:
+            token_provider = lambda: token  # noqa: E731
+        self.base_url = base_url.rstrip("/")
+        self._token_provider = token_provider
+        self._retry_attempts = max(1, retry_attempts)
+        self._retry_delay = retry_delay
+        self._owns_http = http_client is None
+        self._http = http_client or httpx.Client(timeout=timeout)
+
+    def close(self) -> None:
+        """Close the underlying HTTP client, unless it was passed in."""
+        if self._owns_http:
+            self._http.close()
+
+    def __enter__(self) -> FlowCatalystClient:
+        return self
+
+    def __exit__(self, *exc: object) -> None:
+        self.close()
+
+    def _request(
+        self,
+        method: str,
+        path: str,
+        *,
+        params: dict[str, Any] | None = None,
+        headers: dict[str, Any] | None = None,
+        json: Any = None,
+        response: Any = None,
+    ) -> Any:
+        req_headers = {"Accept": "application/json", **_strings(headers)}
+        body = _dump(json)
+        delay = self._retry_delay
+        for attempt in range(self._retry_attempts):
+            last = attempt == self._retry_attempts - 1
+            if self._token_provider is not None:
+                token = self._token_provider()
+                if token:
+                    req_headers["Authorization"] = f"Bearer {token}"
+            try:
+                resp = self._http.request(
+                    method,
+                    self.base_url + path,
+                    params=_strings(params),
+                    headers=req_headers,
+                    json=body,
+                )
+            except httpx.TimeoutException as e:
+                raise FlowCatalystError(f"http: {e}") from e
+            except httpx.TransportError as e:
+                if last:
+                    raise FlowCatalystError(f"http: {e}") from e
+                time.sleep(delay)
+                delay *= 2
+                continue
+            if resp.is_success:
+                if response is None or resp.status_code == 204 or not resp.content:
+                    return None
+                return _adapter(response).validate_python(resp.json())
+            err = APIError(resp.status_code, resp.text)
+            if not err.retryable or last:
+                raise err
+            time.sleep(delay)
+            delay *= 2
+        raise AssertionError("unreachable")
+
+
+@functools.lru_cache(maxsize=None)
+def _adapter(tp: Any) -> TypeAdapter[Any]:
+    return TypeAdapter(tp)
+
+
+def _dump(value: Any) -> Any:
+    """A request body as JSON-ready data. Models drop the fields the caller
+    never set, so an update only sends what it changes."""
+    if isinstance(value, BaseModel):
+        return value.model_dump(mode="json", by_alias=True, exclude_unset=True)
+    if isinstance(value, list):
+        return [_dump(v) for v in value]
+    return value
+
+
+def _strings(values: dict[str, Any] | None) -> dict[str, str]:
+    """Query and header values as the API expects them: unset ones dropped,
+    booleans lowercase, lists comma-separated."""
+    out: dict[str, str] = {}
+    for key, value in (values or {}).items():
+        if value is None:
+            continue
+        if isinstance(value, (list, tuple)):
+            value = ",".join(_scalar(v) for v in value)
+        out[key] = _scalar(value)
+    return out
+
+
+def _scalar(value: Any) -> str:
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, datetime):
+        return value.isoformat()
+    return str(value)
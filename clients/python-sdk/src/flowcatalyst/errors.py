@@ -0,0 +1,86 @@
+"""Errors raised by the client."""
+
+from __future__ import annotations
+
+import json
+from http import HTTPStatus
+
+# The usual transient statuses; see APIError.retryable.
+RETRYABLE_STATUSES = frozenset({408, 425, 429, 500, 502, 503, 504})
+
+
+class FlowCatalystError(Exception):
+    """Base class for every error the SDK raises."""
+
+
+class AuthError(FlowCatalystError):
+    """A token could not be obtained."""
+
+
+class APIError(FlowCatalystError):
+    """The platform answered with a non-2xx status.
+
+    ``body`` is the raw response body; ``code`` and ``message`` are read
+    from it when it is a structured error envelope
+    (``{"error": "EVENT_TYPE_NOT_FOUND", "message": "..."}``).
+    """
+
+    def __init__(self, status_code: int, body: str = "") -> None:
+        self.status_code = status_code
+        self.body = body
+        self.code, self.message = _code_and_message(body)
+        super().__init__(self._render())
+
+    def _render(self) -> str:
+        try:
+            phrase = HTTPStatus(self.status_code).phrase
+        except ValueError:
+            phrase = ""
+        head = f"flowcatalyst api: {self.status_code} {phrase}".rstrip()
+        if self.code or self.message:
+            return f"{head}: {self.code} — {self.message}"
+        if self.body:
+            return f"{head}: {self.body}"
+        return head
+
+    @property
+    def retryable(self) -> bool:
+        """Whether the caller should retry: 408, 425, 429, 500, 502, 503, 504."""
+        return self.status_code in RETRYABLE_STATUSES
+
+    @property
+    def is_not_found(self) -> bool:
+        return self.status_code == 404
+
+    @property
+    def is_unauthorized(self) -> bool:
+        return self.status_code == 401
+
+    @property
+    def is_forbidden(self) -> bool:
+        return self.status_code == 403
+
+    @property
+    def is_conflict(self) -> bool:
+        return self.status_code == 409
+
+    @property
+    def is_precondition_failed(self) -> bool:
+        """Status 412: a stale If-Match."""
+        return self.status_code == 412
+
+
+def _code_and_message(body: str) -> tuple[str, str]:
+    """Parse the platform (error/message), legacy (code) or OAuth
+    (error_description) envelope; ("", "") for anything else."""
+    if not body:
+        return "", ""
+    try:
+        env = json.loads(body)
+    except ValueError:
+        return "", ""
+    if not isinstance(env, dict):
+        return "", ""
+    code = env.get("error") or env.get("code") or ""
+    message = env.get("message") or env.get("error_description") or ""
+    return str(code), str(message)
@@ -0,0 +1 @@
+# Code generated by scripts/generate.py from openapi/openapi.json. DO NOT EDIT.
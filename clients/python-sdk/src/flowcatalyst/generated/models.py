@@ -0,0 +1,2505 @@
+# Code generated by scripts/generate.py from openapi/openapi.json. DO NOT EDIT.
+
+"""Request and response models for the FlowCatalyst platform API."""
+
+from __future__ import annotations
+
+from datetime import datetime
+from typing import Any
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class Model(BaseModel):
+    """Base for every generated model.
+
+    Fields are snake_case and aliased to the API's camelCase; either
+    name is accepted on input. Unknown fields are kept, so a client
+    built against an older spec still round-trips newer responses.
+    """
+
+    model_config = ConfigDict(populate_by_name=True, extra="allow", protected_namespaces=())
+
+
+class AccessListResponse(Model):
+    items: list[AccessResponse]
+
+
+class AccessResponse(Model):
+    application_code: str = Field(alias='applicationCode')
+    can_read: bool = Field(alias='canRead')
+    can_write: bool = Field(alias='canWrite')
+    created_at: datetime = Field(alias='createdAt')
+    id: str
+    role_code: str = Field(alias='roleCode')
+
+
+class AckRequest(Model):
+    ack_token: str = Field(alias='ackToken', description='Token the receiver returned with its 202')
+
+
+class AckResponse(Model):
+    id: str
+    status: str
+
+
+class AddNoteRequest(Model):
+    category: str
+    text: str
+
+
+class AddOriginRequest(Model):
+    description: str | None = Field(default=None)
+    origin: str = Field(description='CORS-allowed origin (e.g. https://example.com)')
+
+
+class AddRoleRequest(Model):
+    role: str
+
+
+class AddSchemaRequest(Model):
+    schema_: Any = Field(alias='schema', description="JSON Schema document, or for PROTO {messageType, fileDescriptorSet} with the set in protobuf's JSON mapping")
+    schema_type: str | None = Field(default=None, alias='schemaType', description="JSON_SCHEMA (default) or PROTO. PROTOBUF subscriptions transcode payloads with the event type's CURRENT PROTO schema")
+    version: str = Field(description='Schema version (typically semver)')
+
+
+class AllowedOriginResponse(Model):
+    created_at: datetime = Field(alias='createdAt')
+    created_by: str | None = Field(default=None, alias='createdBy')
+    description: str | None = Field(default=None)
+    id: str
+    origin: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class AnchorDomainListResponse(Model):
+    items: list[AnchorDomainResponse]
+
+
+class AnchorDomainResponse(Model):
+    created_at: datetime = Field(alias='createdAt')
+    domain: str
+    id: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class AnomalyListResponse(Model):
+    anomalies: list[AnomalyResponse]
+
+
+class AnomalyResponse(Model):
+    entity_key: str = Field(alias='entityKey', description='Event type code or subscription id')
+    entity_kind: str = Field(alias='entityKind', description='EVENT_TYPE or SUBSCRIPTION')
+    expected: float = Field(description="The baseline's mean for the same measure")
+    id: str
+    kind: str = Field(description='INGESTION_DROP or FAILURE_SPIKE')
+    last_seen_at: datetime = Field(alias='lastSeenAt')
+    message: str
+    observed: float = Field(description="The window's value: events ingested, or the failure rate (0-1)")
+    raised_at: datetime = Field(alias='raisedAt')
+    resolved_at: datetime | None = Field(default=None, alias='resolvedAt')
+    status: str = Field(description='OPEN or RESOLVED')
+    z_score: float = Field(alias='zScore', description='Standard deviations between observed and expected')
+
+
+class AnomalySettingListResponse(Model):
+    settings: list[AnomalySettingResponse]
+
+
+class AnomalySettingResponse(Model):
+    enabled: bool
+    entity_key: str = Field(alias='entityKey', description='Event type code or subscription id')
+    entity_kind: str = Field(alias='entityKind', description='EVENT_TYPE or SUBSCRIPTION')
+    id: str
+    sensitivity: float | None = Field(default=None, description='Z-score threshold; absent means the default')
+    snoozed_until: datetime | None = Field(default=None, alias='snoozedUntil')
+    updated_at: datetime = Field(alias='updatedAt')
+    updated_by: str | None = Field(default=None, alias='updatedBy', description='Principal that last changed the setting')
+
+
+class ApplicationAccessListResponse(Model):
+    all_applications: bool = Field(alias='allApplications')
+    applications: list[ApplicationAccessResponse]
+    total: int
+
+
+class ApplicationAccessResponse(Model):
+    application_code: str = Field(alias='applicationCode')
+    application_id: str = Field(alias='applicationId')
+    application_name: str = Field(alias='applicationName')
+
+
+class ApplicationFilterListResponse(Model):
+    application_codes: list[str] = Field(alias='applicationCodes')
+
+
+class ApplicationListResponse(Model):
+    applications: list[ApplicationResponse]
+    total: int
+
+
+class ApplicationLoginClientCredentials(Model):
+    client_type: str = Field(alias='clientType')
+    oauth_client: ApplicationOAuthClientCredentials = Field(alias='oauthClient')
+    redirect_uris: list[str] = Field(alias='redirectUris')
+
+
+class ApplicationOAuthClientCredentials(Model):
+    client_id: str = Field(alias='clientId')
+    client_secret: str | None = Field(default=None, alias='clientSecret')
+    id: str
+
+
+class ApplicationProvisionLoginClientResponse(Model):
+    login_client: ApplicationLoginClientCredentials = Field(alias='loginClient')
+    message: str
+
+
+class ApplicationProvisionServiceAccountResponse(Model):
+    message: str
+    service_account: ApplicationServiceAccountCredentials = Field(alias='serviceAccount')
+
+
+class ApplicationResponse(Model):
+    active: bool
+    code: str
+    created_at: datetime = Field(alias='createdAt')
+    default_base_url: str | None = Field(default=None, alias='defaultBaseUrl')
+    description: str | None = Field(default=None)
+    has_login_client: bool = Field(alias='hasLoginClient')
+    icon_url: str | None = Field(default=None, alias='iconUrl')
+    id: str
+    logo: str | None = Field(default=None)
+    logo_mime_type: str | None = Field(default=None, alias='logoMimeType')
+    name: str
+    service_account_id: str | None = Field(default=None, alias='serviceAccountId')
+    type: str
+    updated_at: datetime = Field(alias='updatedAt')
+    website: str | None = Field(default=None)
+
+
+class ApplicationRolesResponse(Model):
+    roles: list[str]
+
+
+class ApplicationServiceAccountCredentials(Model):
+    name: str
+    oauth_client: ApplicationOAuthClientCredentials = Field(alias='oauthClient')
+    principal_id: str = Field(alias='principalId')
+
+
+class AssignApplicationAccessRequest(Model):
+    all_applications: bool | None = Field(default=None, alias='allApplications')
+    application_ids: list[str] = Field(alias='applicationIds')
+
+
+class AssignPrincipalRolesRequest(Model):
+    roles: list[str]
+
+
+class AssignRolesRequest(Model):
+    roles: list[str]
+
+
+class AttachServiceAccountRequest(Model):
+    service_account_code: str = Field(alias='serviceAccountCode')
+    service_account_id: str = Field(alias='serviceAccountId')
+
+
+class AttemptDTO(Model):
+    attempt_number: int = Field(alias='attemptNumber')
+    attempted_at: datetime = Field(alias='attemptedAt')
+    completed_at: datetime | None = Field(default=None, alias='completedAt')
+    duration_millis: int | None = Field(default=None, alias='durationMillis')
+    error_message: str | None = Field(default=None, alias='errorMessage')
+    error_type: str | None = Field(default=None, alias='errorType')
+    response_body: str | None = Field(default=None, alias='responseBody')
+    response_code: int | None = Field(default=None, alias='responseCode')
+    success: bool
+
+
+class AuditLogApplicationIDsResponse(Model):
+    application_ids: list[str] = Field(alias='applicationIds')
+
+
+class AuditLogClientIDsResponse(Model):
+    client_ids: list[str] = Field(alias='clientIds')
+
+
+class AuditLogEntityTypesResponse(Model):
+    entity_types: list[str] = Field(alias='entityTypes')
+
+
+class AuditLogListResponse(Model):
+    audit_logs: list[AuditLogResponse] = Field(alias='auditLogs')
+    has_more: bool = Field(alias='hasMore')
+    next_cursor: str | None = Field(default=None, alias='nextCursor')
+
+
+class AuditLogOperationsResponse(Model):
+    operations: list[str]
+
+
+class AuditLogResponse(Model):
+    application_id: str | None = Field(default=None, alias='applicationId')
+    client_id: str | None = Field(default=None, alias='clientId')
+    entity_id: str = Field(alias='entityId')
+    entity_type: str = Field(alias='entityType')
+    id: str
+    operation: str
+    operation_json: str | None = Field(default=None, alias='operationJson')
+    performed_at: datetime = Field(alias='performedAt')
+    principal_id: str | None = Field(default=None, alias='principalId')
+    principal_name: str | None = Field(default=None, alias='principalName')
+
+
+class AuthConfigItem(Model):
+    additional_clients: list[str] | None = Field(default=None, alias='additionalClients')
+    auth_provider: str | None = Field(default=None, alias='authProvider')
+    config_type: str = Field(alias='configType')
+    email_domain: str = Field(alias='emailDomain')
+    granted_clients: list[str] | None = Field(default=None, alias='grantedClients')
+    oidc_client_id: str | None = Field(default=None, alias='oidcClientId')
+    oidc_issuer_pattern: str | None = Field(default=None, alias='oidcIssuerPattern')
+    oidc_issuer_url: str | None = Field(default=None, alias='oidcIssuerUrl')
+    oidc_multi_tenant: bool | None = Field(default=None, alias='oidcMultiTenant')
+    primary_client: str | None = Field(default=None, alias='primaryClient')
+
+
+class AuthConfigListResponse(Model):
+    items: list[AuthConfigResponse]
+
+
+class AuthConfigResponse(Model):
+    additional_client_ids: list[str] = Field(alias='additionalClientIds')
+    auth_provider: str = Field(alias='authProvider')
+    config_type: str = Field(alias='configType')
+    created_at: datetime = Field(alias='createdAt')
+    email_domain: str = Field(alias='emailDomain')
+    granted_client_ids: list[str] = Field(alias='grantedClientIds')
+    id: str
+    oidc_client_id: str | None = Field(default=None, alias='oidcClientId')
+    oidc_client_secret_ref: str | None = Field(default=None, alias='oidcClientSecretRef')
+    oidc_issuer_pattern: str | None = Field(default=None, alias='oidcIssuerPattern')
+    oidc_issuer_url: str | None = Field(default=None, alias='oidcIssuerUrl')
+    oidc_multi_tenant: bool = Field(alias='oidcMultiTenant')
+    primary_client_id: str | None = Field(default=None, alias='primaryClientId')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class AuthenticateBeginRequest(Model):
+    email: str
+
+
+class AuthenticateBeginResponse(Model):
+    options: Any
+    state_id: str = Field(alias='stateId')
+
+
+class AuthenticateCompleteRequest(Model):
+    credential: Any
+    state_id: str = Field(alias='stateId')
+
+
+class BatchEventItem(Model):
+    causation_id: str | None = Field(default=None, alias='causationId')
+    client_code: str | None = Field(default=None, alias='clientCode')
+    client_id: str | None = Field(default=None, alias='clientId')
+    context_data: list[ContextEntryDTO] | None = Field(default=None, alias='contextData')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    data: Any | None = Field(default=None)
+    deduplication_id: str | None = Field(default=None, alias='deduplicationId')
+    id: str | None = Field(default=None)
+    message_group: str | None = Field(default=None, alias='messageGroup')
+    receipt: ReceiptRequest | None = Field(default=None)
+    source: str | None = Field(default=None)
+    spec_version: str | None = Field(default=None, alias='specVersion')
+    subject: str | None = Field(default=None)
+    type: str | None = Field(default=None)
+
+
+class BatchRequest(Model):
+    items: list[BatchEventItem]
+
+
+class BatchResponse(Model):
+    results: list[BatchResultItem]
+
+
+class BatchResultItem(Model):
+    error: str | None = Field(default=None)
+    id: str
+    status: str
+
+
+class Batching(Model):
+    max_bytes: int = Field(alias='maxBytes')
+    max_items: int = Field(alias='maxItems')
+    max_wait_seconds: int = Field(alias='maxWaitSeconds')
+
+
+class BatchingDTO(Model):
+    max_bytes: int | None = Field(default=None, alias='maxBytes', description="Cap on the array's size in bytes; 0 means no cap")
+    max_items: int = Field(alias='maxItems', description='Most dispatch jobs POSTed together as one JSON array (at most 100); 0 or 1 delivers one at a time')
+    max_wait_seconds: int | None = Field(default=None, alias='maxWaitSeconds', description="How long a short batch may wait for more jobs, from its oldest job's creation (at most 300); 0 sends at once")
+
+
+class BindingItem(Model):
+    event_type_code: str = Field(alias='eventTypeCode')
+    filter: str | None = Field(default=None)
+    spec_version: str | None = Field(default=None, alias='specVersion')
+
+
+class BulkImportRequest(Model):
+    client_id: str = Field(alias='clientId', description='Client all imported users are created under')
+    users: list[BulkImportUser]
+
+
+class BulkImportResponse(Model):
+    created: int
+    failed: int
+    results: list[BulkImportResult]
+    skipped: int
+
+
+class BulkImportResult(Model):
+    email: str
+    message: str | None = Field(default=None)
+    row: int
+    status: str = Field(description='created | exists | dropped | error')
+
+
+class BulkImportUser(Model):
+    email: str
+    name: str
+    roles: list[str] | None = Field(default=None, description='Role names to assign (pipe-separated in the CSV)')
+
+
+class Bundle(Model):
+    auth_configs: list[AuthConfigItem] | None = Field(default=None, alias='authConfigs')
+    dispatch_pools: list[DispatchPoolItem] | None = Field(default=None, alias='dispatchPools')
+    event_types: list[EventTypeItem] | None = Field(default=None, alias='eventTypes')
+    exported_at: datetime | None = Field(default=None, alias='exportedAt')
+    roles: list[RoleItem] | None = Field(default=None)
+    subscriptions: list[SubscriptionItem] | None = Field(default=None)
+    version: int
+
+
+class CancelRequest(Model):
+    ids: list[str] = Field(description='Dispatch job ids to cancel')
+
+
+class CancelResponse(Model):
+    cancelled: list[str]
+
+
+class CheckEmailDomainResponse(Model):
+    allowed_client_ids: list[str] = Field(alias='allowedClientIds')
+    auth_method: str = Field(alias='authMethod')
+    auth_provider: str = Field(alias='authProvider')
+    derived_scope: str = Field(alias='derivedScope')
+    domain: str
+    email_exists: bool = Field(alias='emailExists')
+    has_idp_config: bool = Field(alias='hasIdpConfig')
+    idp_issuer: str | None = Field(default=None, alias='idpIssuer')
+    info: str | None = Field(default=None)
+    is_anchor_domain: bool = Field(alias='isAnchorDomain')
+    login_url: str | None = Field(default=None, alias='loginUrl')
+    requires_client_id: bool = Field(alias='requiresClientId')
+    warning: str | None = Field(default=None)
+
+
+class ClientAccessGrantListResponse(Model):
+    grants: list[ClientAccessGrantResponse]
+
+
+class ClientAccessGrantResponse(Model):
+    client_id: str = Field(alias='clientId')
+    expires_at: datetime | None = Field(default=None, alias='expiresAt')
+    granted_at: datetime = Field(alias='grantedAt')
+    id: str
+
+
+class ClientApplicationResponse(Model):
+    active: bool
+    code: str
+    description: str | None = Field(default=None)
+    enabled_for_client: bool = Field(alias='enabledForClient')
+    icon_url: str | None = Field(default=None, alias='iconUrl')
+    id: str
+    name: str
+
+
+class ClientApplicationsResponse(Model):
+    applications: list[ClientApplicationResponse]
+    total: int
+
+
+class ClientAssociationRequest(Model):
+    client_id: str = Field(alias='clientId', description='Target client id, or "*" for anchor (all-client) access')
+    mode: str | None = Field(default=None, description='CHANGE_CLIENT | TO_PARTNER — required for a specific clientId, ignored for "*"')
+
+
+class ClientConfigListResponse(Model):
+    items: list[ClientConfigResponse]
+
+
+class ClientConfigResponse(Model):
+    application_id: str = Field(alias='applicationId')
+    base_url_override: str | None = Field(default=None, alias='baseUrlOverride')
+    client_id: str = Field(alias='clientId')
+    config_json: Any | None = Field(default=None, alias='configJson')
+    created_at: datetime = Field(alias='createdAt')
+    enabled: bool
+    id: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class ClientListResponse(Model):
+    clients: list[ClientResponse]
+    total: int
+
+
+class ClientResponse(Model):
+    created_at: datetime = Field(alias='createdAt')
+    id: str
+    identifier: str
+    name: str
+    notes: list[NoteResponse]
+    status: str
+    status_changed_at: datetime | None = Field(default=None, alias='statusChangedAt')
+    status_reason: str | None = Field(default=None, alias='statusReason')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class CompleteInstanceRequest(Model):
+    completion_result: Any | None = Field(default=None, alias='completionResult')
+    completion_status: str | None = Field(default=None, alias='completionStatus')
+    result: Any | None = Field(default=None, description='SDK alias for completionResult')
+    status: str | None = Field(default=None, description='COMPLETED|FAILED|… (instance status) or SUCCESS|FAILURE (completion outcome)')
+
+
+class ConfigEntry(Model):
+    key: str
+    value: str
+
+
+class ConfigEntryDTO(Model):
+    key: str
+    value: str
+
+
+class ConfigListResponse(Model):
+    items: list[ConfigResponse]
+
+
+class ConfigResponse(Model):
+    application_code: str = Field(alias='applicationCode')
+    client_id: str | None = Field(default=None, alias='clientId')
+    created_at: datetime = Field(alias='createdAt')
+    description: str | None = Field(default=None)
+    id: str
+    property: str
+    scope: str
+    section: str
+    updated_at: datetime = Field(alias='updatedAt')
+    value: str
+    value_type: str = Field(alias='valueType')
+
+
+class ConnectionListResponse(Model):
+    connections: list[ConnectionResponse]
+    total: int
+
+
+class ConnectionResponse(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    client_identifier: str | None = Field(default=None, alias='clientIdentifier')
+    code: str
+    created_at: datetime = Field(alias='createdAt')
+    description: str | None = Field(default=None)
+    external_id: str | None = Field(default=None, alias='externalId')
+    id: str
+    name: str
+    service_account_id: str = Field(alias='serviceAccountId')
+    status: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class ConsumeAckRequest(Model):
+    id: str = Field(description='Dispatch job id of the message')
+    lease_token: str = Field(alias='leaseToken')
+
+
+class ConsumeNackRequest(Model):
+    error: str | None = Field(default=None, description="Why processing failed; recorded as the job's last error")
+    id: str = Field(description='Dispatch job id of the message')
+    lease_token: str = Field(alias='leaseToken')
+
+
+class ConsumeResponse(Model):
+    messages: list[ConsumedMessage]
+
+
+class ConsumedMessage(Model):
+    attempt_number: int = Field(alias='attemptNumber')
+    body: Any = Field(description="What a push subscription's webhook would receive; a non-JSON payload arrives as a JSON string")
+    event_id: str | None = Field(default=None, alias='eventId')
+    id: str = Field(description='Dispatch job id')
+    lease_expires_at: datetime = Field(alias='leaseExpiresAt', description='Unsettled by then, the message counts as failed')
+    lease_token: str = Field(alias='leaseToken', description='Presented with the ack or nack')
+    type: str = Field(description='Event type code')
+
+
+class ContextEntryDTO(Model):
+    key: str
+    value: str
+
+
+class CorsOriginListResponse(Model):
+    cors_origins: list[AllowedOriginResponse] = Field(alias='corsOrigins')
+    total: int
+
+
+class CreateAnchorDomainRequest(Model):
+    domain: str
+
+
+class CreateApplicationRequest(Model):
+    code: str = Field(description='Application code (lowercase, alphanumeric, hyphens)')
+    default_base_url: str | None = Field(default=None, alias='defaultBaseUrl')
+    description: str | None = Field(default=None)
+    icon_url: str | None = Field(default=None, alias='iconUrl')
+    logo: str | None = Field(default=None, description='Inline SVG logo content')
+    logo_mime_type: str | None = Field(default=None, alias='logoMimeType')
+    name: str
+    type: str | None = Field(default=None, description='APPLICATION or INTEGRATION')
+    website: str | None = Field(default=None)
+
+
+class CreateAuthConfigRequest(Model):
+    additional_client_ids: list[str] | None = Field(default=None, alias='additionalClientIds')
+    auth_provider: str = Field(alias='authProvider')
+    config_type: str = Field(alias='configType')
+    email_domain: str = Field(alias='emailDomain')
+    granted_client_ids: list[str] | None = Field(default=None, alias='grantedClientIds')
+    oidc_client_id: str | None = Field(default=None, alias='oidcClientId')
+    oidc_client_secret_ref: str | None = Field(default=None, alias='oidcClientSecretRef')
+    oidc_issuer_pattern: str | None = Field(default=None, alias='oidcIssuerPattern')
+    oidc_issuer_url: str | None = Field(default=None, alias='oidcIssuerUrl')
+    oidc_multi_tenant: bool = Field(alias='oidcMultiTenant')
+    primary_client_id: str | None = Field(default=None, alias='primaryClientId')
+
+
+class CreateClientRequest(Model):
+    identifier: str = Field(description='URL-safe identifier (lowercase alphanumeric, hyphens)')
+    name: str
+
+
+class CreateConnectionRequest(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    code: str = Field(description='Connection code (lowercase, alphanumeric, hyphens)')
+    description: str | None = Field(default=None)
+    external_id: str | None = Field(default=None, alias='externalId')
+    name: str
+    service_account_id: str = Field(alias='serviceAccountId')
+
+
+class CreateDispatchPoolRequest(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    code: str = Field(description='Pool code (lowercase, alphanumeric, hyphens)')
+    concurrency: int | None = Field(default=None, description='Max concurrent dispatches (default 10)')
+    description: str | None = Field(default=None)
+    name: str
+    rate_limit: int | None = Field(default=None, alias='rateLimit', description='Messages per minute (nil = no rate limit)')
+
+
+class CreateEventRequest(Model):
+    causation_id: str | None = Field(default=None, alias='causationId', description='Causation ID - the event that caused this event')
+    client_id: str | None = Field(default=None, alias='clientId', description="Client ID (optional, defaults to caller's client)")
+    context_data: list[ContextEntryDTO] | None = Field(default=None, alias='contextData', description='Context data for filtering/searching')
+    correlation_id: str | None = Field(default=None, alias='correlationId', description='Correlation ID for request tracing')
+    data: Any = Field(description='Event payload data')
+    deduplication_id: str | None = Field(default=None, alias='deduplicationId', description='Deduplication ID for exactly-once delivery')
+    event_type: str = Field(alias='eventType', description='Event type code (e.g., "orders:fulfillment:shipment:shipped")')
+    message_group: str | None = Field(default=None, alias='messageGroup', description='Message group for FIFO ordering')
+    receipt: ReceiptRequest | None = Field(default=None)
+    source: str = Field(description='Event source URI')
+    subject: str | None = Field(default=None, description='Event subject (optional context)')
+
+
+class CreateEventResponse(Model):
+    dispatch_job_count: int = Field(alias='dispatchJobCount', description='Number of dispatch jobs created for matching subscriptions')
+    event: CreatedEvent
+    is_duplicate: bool = Field(alias='isDuplicate', description='True if this was a deduplicated request (event already existed)')
+
+
+class CreateEventTypeRequest(Model):
+    client_id: str | None = Field(default=None, alias='clientId', description='Optional client scope; absent means anchor-level')
+    code: str = Field(description='Event type code in application:subdomain:aggregate:event format')
+    description: str | None = Field(default=None)
+    name: str = Field(description='Human-readable event type name')
+    schema_: Any | None = Field(default=None, alias='schema', description='Optional JSON Schema for the initial spec version')
+
+
+class CreateIdentityProviderRequest(Model):
+    allowed_email_domains: list[str] | None = Field(default=None, alias='allowedEmailDomains')
+    code: str = Field(description='IDP code (e.g. internal, entra)')
+    name: str = Field(description='Display name')
+    oidc_client_id: str | None = Field(default=None, alias='oidcClientId')
+    oidc_client_secret_ref: str | None = Field(default=None, alias='oidcClientSecretRef')
+    oidc_issuer_pattern: str | None = Field(default=None, alias='oidcIssuerPattern')
+    oidc_issuer_url: str | None = Field(default=None, alias='oidcIssuerUrl')
+    oidc_multi_tenant: bool = Field(alias='oidcMultiTenant')
+    type: str = Field(description='IDP type (INTERNAL or OIDC)')
+
+
+class CreateIdpRoleMappingRequest(Model):
+    idp_role_name: str = Field(alias='idpRoleName')
+    idp_type: str = Field(alias='idpType')
+    platform_role_name: str = Field(alias='platformRoleName')
+
+
+class CreateMappingRequest(Model):
+    additional_client_ids: list[str] | None = Field(default=None, alias='additionalClientIds')
+    allowed2fa_methods: list[str] | None = Field(default=None, alias='allowed2faMethods', description='Permitted 2FA methods (TOTP, EMAIL_PIN). ≥1 required when require2fa is set.')
+    allowed_role_ids: list[str] | None = Field(default=None, alias='allowedRoleIds')
+    email_domain: str = Field(alias='emailDomain', description='DNS-like email domain (e.g. example.com)')
+    granted_client_ids: list[str] | None = Field(default=None, alias='grantedClientIds')
+    identity_provider_id: str = Field(alias='identityProviderId')
+    primary_client_id: str | None = Field(default=None, alias='primaryClientId')
+    remember_device_days: int | None = Field(default=None, alias='rememberDeviceDays')
+    remember_device_enabled: bool | None = Field(default=None, alias='rememberDeviceEnabled')
+    require2fa: bool | None = Field(default=None)
+    require_passkey_for_admins: bool | None = Field(default=None, alias='requirePasskeyForAdmins', description='Admin-scope users must sign in with a passkey; without one, password sign-in only allows registering one (internal-auth domains only).')
+    required_oidc_tenant_id: str | None = Field(default=None, alias='requiredOidcTenantId')
+    scope_type: str = Field(alias='scopeType', description='Scope of mapping (ANCHOR, PARTNER, CLIENT)')
+    sync_roles_from_idp: bool | None = Field(default=None, alias='syncRolesFromIdp')
+
+
+class CreateOAuthClientRequest(Model):
+    allowed_origins: list[str] | None = Field(default=None, alias='allowedOrigins')
+    application_ids: list[str] | None = Field(default=None, alias='applicationIds')
+    client_name: str = Field(alias='clientName')
+    client_type: str = Field(alias='clientType', description='PUBLIC or CONFIDENTIAL')
+    default_scopes: str | None = Field(default=None, alias='defaultScopes')
+    grant_types: list[str] | None = Field(default=None, alias='grantTypes')
+    pkce_required: bool | None = Field(default=None, alias='pkceRequired')
+    post_logout_redirect_uris: list[str] | None = Field(default=None, alias='postLogoutRedirectUris')
+    principal_id: str | None = Field(default=None, alias='principalId')
+    redirect_uris: list[str] | None = Field(default=None, alias='redirectUris')
+    scopes: list[str] | None = Field(default=None)
+
+
+class CreateOAuthClientResponse(Model):
+    client: OAuthClientResponse
+    client_secret: str | None = Field(default=None, alias='clientSecret')
+
+
+class CreatePrincipalRequest(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    email: str
+    idp_type: str | None = Field(default=None, alias='idpType')
+    name: str | None = Field(default=None)
+    password: str | None = Field(default=None)
+    scope: str = Field(description='Principal scope (ANCHOR, PARTNER, CLIENT)')
+
+
+class CreateProcessRequest(Model):
+    body: str | None = Field(default=None, description='Process documentation body')
+    code: str = Field(description='Process code in application:subdomain:name format')
+    description: str | None = Field(default=None)
+    diagram_type: str | None = Field(default=None, alias='diagramType', description='Diagram syntax (e.g. mermaid)')
+    name: str
+    tags: list[str] | None = Field(default=None)
+
+
+class CreateRoleRequest(Model):
+    application_code: str = Field(alias='applicationCode', description='Application code (e.g. platform, iam)')
+    client_managed: bool = Field(alias='clientManaged', description='Whether the role is managed at client scope')
+    description: str | None = Field(default=None)
+    display_name: str = Field(alias='displayName', description='Human-readable role name')
+    permissions: list[str] | None = Field(default=None, description='Permission codes assigned to the role')
+    role_name: str = Field(alias='roleName', description='Role name within the application')
+
+
+class CreateScheduledJobRequest(Model):
+    application_id: str | None = Field(default=None, alias='applicationId')
+    client_id: str | None = Field(default=None, alias='clientId')
+    code: str
+    concurrent: bool
+    crons: list[str]
+    delivery_max_attempts: int | None = Field(default=None, alias='deliveryMaxAttempts')
+    description: str | None = Field(default=None)
+    name: str
+    payload: Any | None = Field(default=None)
+    target_url: str | None = Field(default=None, alias='targetUrl')
+    timeout_seconds: int | None = Field(default=None, alias='timeoutSeconds')
+    timezone: str | None = Field(default=None)
+    tracks_completion: bool = Field(alias='tracksCompletion')
+
+
+class CreateServiceAccountRequest(Model):
+    application_id: str | None = Field(default=None, alias='applicationId')
+    client_ids: list[str] | None = Field(default=None, alias='clientIds')
+    code: str
+    description: str | None = Field(default=None)
+    name: str
+    scope: str | None = Field(default=None)
+    webhook_credentials: WebhookCredentialsDTO | None = Field(default=None, alias='webhookCredentials')
+
+
+class CreateServiceAccountResponse(Model):
+    oauth: ServiceAccountOAuthSecrets
+    principal_id: str = Field(alias='principalId')
+    service_account: ServiceAccountResponse = Field(alias='serviceAccount')
+    webhook: ServiceAccountWebhookSecrets
+
+
+class CreateSubscriptionRequest(Model):
+    batching: BatchingDTO | None = Field(default=None)
+    client_id: str | None = Field(default=None, alias='clientId')
+    code: str
+    connection_id: str | None = Field(default=None, alias='connectionId')
+    custom_config: list[ConfigEntryDTO] | None = Field(default=None, alias='customConfig')
+    data_only: bool | None = Field(default=None, alias='dataOnly')
+    delay_seconds: int | None = Field(default=None, alias='delaySeconds')
+    delivery: str | None = Field(default=None, description='PUSH (default) POSTs each dispatch job to the endpoint; PULL holds them for the consumer to fetch from GET /api/consume/{subscriptionId}')
+    description: str | None = Field(default=None)
+    dispatch_pool_id: str | None = Field(default=None, alias='dispatchPoolId')
+    endpoint: str | None = Field(default=None, description='http(s) URL delivery target; required unless delivery is PULL')
+    event_types: list[EventTypeBindingDTO] | None = Field(default=None, alias='eventTypes')
+    headers: list[ConfigEntryDTO] | None = Field(default=None, description='Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time')
+    large_payloads: str | None = Field(default=None, alias='largePayloads', description='How a payload offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES is delivered: INLINE (default) rehydrates it; PRESIGNED_URL sends its $blobRef reference with a pre-signed url to fetch it from')
+    max_age_seconds: int | None = Field(default=None, alias='maxAgeSeconds')
+    max_retries: int | None = Field(default=None, alias='maxRetries')
+    mode: str | None = Field(default=None, description='Dispatch mode (IMMEDIATE, NEXT_ON_ERROR, BLOCK_ON_ERROR)')
+    name: str
+    payload_format: str | None = Field(default=None, alias='payloadFormat', description="How deliveries are serialized: JSON (default), CLOUDEVENTS_JSON, NDJSON or PROTOBUF (transcoded with the event type's current PROTO schema). Pull consumers always receive JSON")
+    service_account_id: str | None = Field(default=None, alias='serviceAccountId')
+    timeout_seconds: int | None = Field(default=None, alias='timeoutSeconds')
+    transport: str | None = Field(default=None, description='Named outbound transport (SSH bastion or SOCKS5 proxy, see FC_OUTBOUND_TRANSPORTS) deliveries go through; omit to deliver directly')
+
+
+class CreateUserRequest(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    email: str
+    enforce_password_complexity: bool | None = Field(default=None, alias='enforcePasswordComplexity')
+    name: str
+    password: str | None = Field(default=None)
+
+
+class CreatedEvent(Model):
+    causation_id: str | None = Field(default=None, alias='causationId')
+    client_id: str | None = Field(default=None, alias='clientId')
+    context_data: list[ContextEntryDTO] | None = Field(default=None, alias='contextData')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    created_at: datetime = Field(alias='createdAt')
+    data: Any
+    deduplication_id: str | None = Field(default=None, alias='deduplicationId')
+    event_type: str = Field(alias='eventType')
+    id: str
+    message_group: str | None = Field(default=None, alias='messageGroup')
+    source: str
+    spec_version: str = Field(alias='specVersion')
+    subject: str | None = Field(default=None)
+    time: datetime
+
+
+class CreatedResponse(Model):
+    id: str
+
+
+class DeveloperUserListResponse(Model):
+    principals: list[PrincipalResponse]
+    total: int
+
+
+class DispatchJobFilterOptionsResponse(Model):
+    client_ids: list[str] = Field(alias='clientIds')
+    codes: list[str]
+    dispatch_pool_ids: list[str] = Field(alias='dispatchPoolIds')
+    kinds: list[str]
+    statuses: list[str]
+    subscription_ids: list[str] = Field(alias='subscriptionIds')
+
+
+class DispatchJobRead(Model):
+    aggregate: str | None = Field(default=None)
+    application: str | None = Field(default=None)
+    attempt_count: int = Field(alias='attemptCount')
+    client_id: str | None = Field(default=None, alias='clientId')
+    client_identifier: str | None = Field(default=None, alias='clientIdentifier')
+    client_name: str | None = Field(default=None, alias='clientName')
+    code: str
+    completed_at: datetime | None = Field(default=None, alias='completedAt')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    created_at: datetime = Field(alias='createdAt')
+    dispatch_mode: str | None = Field(default=None, alias='dispatchMode')
+    event_id: str | None = Field(default=None, alias='eventId')
+    event_type_name: str | None = Field(default=None, alias='eventTypeName')
+    id: str
+    kind: str
+    last_attempt_at: datetime | None = Field(default=None, alias='lastAttemptAt')
+    mode: str
+    priority: int | None = Field(default=None)
+    scheduled_for: datetime | None = Field(default=None, alias='scheduledFor')
+    source: str | None = Field(default=None)
+    status: str
+    subdomain: str | None = Field(default=None)
+    subject: str | None = Field(default=None)
+    subscription_id: str | None = Field(default=None, alias='subscriptionId')
+    subscription_name: str | None = Field(default=None, alias='subscriptionName')
+    target_host: str | None = Field(default=None, alias='targetHost')
+    target_url: str = Field(alias='targetUrl')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class DispatchJobResponse(Model):
+    attempt_count: int = Field(alias='attemptCount')
+    attempts: list[AttemptDTO] | None = Field(default=None)
+    client_id: str | None = Field(default=None, alias='clientId')
+    code: str
+    completed_at: datetime | None = Field(default=None, alias='completedAt')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    created_at: datetime = Field(alias='createdAt')
+    data_only: bool = Field(alias='dataOnly')
+    dispatch_pool_id: str | None = Field(default=None, alias='dispatchPoolId')
+    duration_millis: int | None = Field(default=None, alias='durationMillis')
+    event_id: str | None = Field(default=None, alias='eventId')
+    expires_at: datetime | None = Field(default=None, alias='expiresAt')
+    external_id: str | None = Field(default=None, alias='externalId')
+    id: str
+    idempotency_key: str | None = Field(default=None, alias='idempotencyKey')
+    kind: str
+    last_attempt_at: datetime | None = Field(default=None, alias='lastAttemptAt')
+    last_error: str | None = Field(default=None, alias='lastError')
+    max_retries: int = Field(alias='maxRetries')
+    message_group: str | None = Field(default=None, alias='messageGroup')
+    metadata: list[MetadataDTO] | None = Field(default=None)
+    mode: str
+    payload: str | None = Field(default=None)
+    payload_content_type: str = Field(alias='payloadContentType')
+    protocol: str
+    retry_strategy: str = Field(alias='retryStrategy')
+    scheduled_for: datetime | None = Field(default=None, alias='scheduledFor')
+    schema_id: str | None = Field(default=None, alias='schemaId')
+    sequence: int
+    service_account_id: str | None = Field(default=None, alias='serviceAccountId')
+    source: str | None = Field(default=None)
+    status: str
+    subject: str | None = Field(default=None)
+    subscription_id: str | None = Field(default=None, alias='subscriptionId')
+    target_url: str = Field(alias='targetUrl')
+    timeout_seconds: int = Field(alias='timeoutSeconds')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class DispatchPoolItem(Model):
+    code: str
+    concurrency: int
+    description: str | None = Field(default=None)
+    name: str
+    rate_limit: int | None = Field(default=None, alias='rateLimit')
+    status: str | None = Field(default=None)
+
+
+class DispatchPoolListResponse(Model):
+    pools: list[DispatchPoolResponse]
+    total: int
+
+
+class DispatchPoolResponse(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    client_identifier: str | None = Field(default=None, alias='clientIdentifier')
+    code: str
+    concurrency: int
+    created_at: datetime = Field(alias='createdAt')
+    description: str | None = Field(default=None)
+    id: str
+    name: str
+    rate_limit: int | None = Field(default=None, alias='rateLimit')
+    status: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class DriftItemResponse(Model):
+    action: str = Field(description='create, update, conflict or invalid')
+    changed: list[str] | None = Field(default=None, description='Fields that differ from the spec')
+    key: str = Field(description='Code, role name or email domain')
+    kind: str = Field(description='dispatchPool, eventType, role, authConfig, subscription or serviceAccount')
+    message: str | None = Field(default=None)
+
+
+class EngageKillSwitchRequest(Model):
+    reason: str = Field(description='Why delivery is stopped; kept in the audit log')
+    scope: str = Field(description='What the switch stops: CLIENT, SUBSCRIPTION or POOL')
+    target_id: str = Field(alias='targetId', description='Client, subscription or dispatch pool id, per scope')
+
+
+class ErasureRemainingResponse(Model):
+    data_keys: int = Field(alias='dataKeys')
+    dispatch_job_reads: int = Field(alias='dispatchJobReads')
+    dispatch_jobs: int = Field(alias='dispatchJobs')
+    event_reads: int = Field(alias='eventReads')
+    events: int
+
+
+class ErasureReportResponse(Model):
+    data_keys_destroyed: int = Field(alias='dataKeysDestroyed')
+    dispatch_job_reads_erased: int = Field(alias='dispatchJobReadsErased')
+    dispatch_jobs_erased: int = Field(alias='dispatchJobsErased')
+    event_reads_erased: int = Field(alias='eventReadsErased')
+    events_erased: int = Field(alias='eventsErased')
+    passes: int
+    remaining: ErasureRemainingResponse
+    verified_at: datetime | None = Field(default=None, alias='verifiedAt')
+
+
+class ErasureRequestListResponse(Model):
+    items: list[ErasureRequestResponse]
+
+
+class ErasureRequestResponse(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    completed_at: datetime | None = Field(default=None, alias='completedAt')
+    context_keys: list[str] = Field(alias='contextKeys')
+    created_at: datetime = Field(alias='createdAt')
+    error: str | None = Field(default=None)
+    id: str
+    report: ErasureReportResponse
+    requested_by: str | None = Field(default=None, alias='requestedBy')
+    started_at: datetime | None = Field(default=None, alias='startedAt')
+    status: str = Field(description='PENDING, IN_PROGRESS, COMPLETED or FAILED')
+    subject_hash: str = Field(alias='subjectHash', description='SHA-256 (hex) of the subject identifier')
+    subject_type: str = Field(alias='subjectType')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class ErrorModel(Model):
+    details: dict[str, Any] | None = Field(default=None)
+    error: str
+    message: str
+
+
+class EventFilterOption(Model):
+    label: str
+    value: str
+
+
+class EventFilterOptionsResponse(Model):
+    applications: list[EventFilterOption]
+    event_types: list[EventFilterOption] = Field(alias='eventTypes')
+    subdomains: list[EventFilterOption]
+
+
+class EventRead(Model):
+    aggregate: str | None = Field(default=None)
+    application: str | None = Field(default=None)
+    client_id: str | None = Field(default=None, alias='clientId')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    id: str
+    message_group: str | None = Field(default=None, alias='messageGroup')
+    projected_at: datetime = Field(alias='projectedAt')
+    source: str
+    subdomain: str | None = Field(default=None)
+    subject: str | None = Field(default=None)
+    time: datetime
+    type: str
+
+
+class EventResponse(Model):
+    aggregate: str | None = Field(default=None)
+    application: str | None = Field(default=None)
+    causation_id: str | None = Field(default=None, alias='causationId')
+    client_id: str | None = Field(default=None, alias='clientId')
+    context_data: list[ContextEntryDTO] | None = Field(default=None, alias='contextData')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    created_at: datetime = Field(alias='createdAt')
+    data: Any | None = Field(default=None)
+    deduplication_id: str = Field(alias='deduplicationId')
+    id: str
+    message_group: str | None = Field(default=None, alias='messageGroup')
+    projected_at: datetime | None = Field(default=None, alias='projectedAt')
+    source: str
+    spec_version: str = Field(alias='specVersion')
+    subdomain: str | None = Field(default=None)
+    subject: str
+    time: datetime
+    type: str
+
+
+class EventTypeBindingDTO(Model):
+    event_type_code: str = Field(alias='eventTypeCode')
+    event_type_id: str | None = Field(default=None, alias='eventTypeId')
+    filter: str | None = Field(default=None)
+    spec_version: str | None = Field(default=None, alias='specVersion')
+
+
+class EventTypeItem(Model):
+    client_scoped: bool | None = Field(default=None, alias='clientScoped')
+    code: str
+    description: str | None = Field(default=None)
+    metadata_retention_days: int | None = Field(default=None, alias='metadataRetentionDays')
+    name: str
+    payload_retention_days: int | None = Field(default=None, alias='payloadRetentionDays')
+    redact_fields: list[str] | None = Field(default=None, alias='redactFields')
+    schemas: list[SchemaItem] | None = Field(default=None)
+    source: str | None = Field(default=None)
+    status: str | None = Field(default=None)
+
+
+class EventTypeListResponse(Model):
+    items: list[EventTypeResponse]
+
+
+class EventTypeResponse(Model):
+    aggregate: str
+    application: str
+    client_id: str | None = Field(default=None, alias='clientId')
+    code: str
+    created_at: datetime = Field(alias='createdAt')
+    created_by: str | None = Field(default=None, alias='createdBy')
+    description: str | None = Field(default=None)
+    event_name: str = Field(alias='eventName')
+    id: str
+    metadata_retention_days: int | None = Field(default=None, alias='metadataRetentionDays')
+    name: str
+    payload_retention_days: int | None = Field(default=None, alias='payloadRetentionDays')
+    redact_fields: list[str] = Field(alias='redactFields')
+    source: str
+    spec_versions: list[SpecVersionResponse] = Field(alias='specVersions')
+    status: str
+    subdomain: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class ExpireSigningKeyRequest(Model):
+    at: datetime | None = Field(default=None, description='When the key stops being valid (default now)')
+
+
+class FireNowRequest(Model):
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+
+
+class FireNowResponse(Model):
+    id: str
+    instance_id: str = Field(alias='instanceId')
+    scheduled_job_id: str = Field(alias='scheduledJobId')
+
+
+class GrantAccessRequest(Model):
+    can_write: bool = Field(alias='canWrite')
+    role_code: str = Field(alias='roleCode')
+
+
+class GrantClientAccessRequest(Model):
+    client_id: str = Field(alias='clientId')
+
+
+class GrantPermissionRequest(Model):
+    permission: str
+
+
+class IdentityProviderListResponse(Model):
+    identity_providers: list[IdentityProviderResponse] = Field(alias='identityProviders')
+    total: int
+
+
+class IdentityProviderResponse(Model):
+    allowed_email_domains: list[str] = Field(alias='allowedEmailDomains')
+    code: str
+    created_at: datetime = Field(alias='createdAt')
+    has_client_secret: bool = Field(alias='hasClientSecret')
+    id: str
+    name: str
+    oidc_client_id: str | None = Field(default=None, alias='oidcClientId')
+    oidc_issuer_pattern: str | None = Field(default=None, alias='oidcIssuerPattern')
+    oidc_issuer_url: str | None = Field(default=None, alias='oidcIssuerUrl')
+    oidc_multi_tenant: bool = Field(alias='oidcMultiTenant')
+    type: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class IdpRoleMappingListResponse(Model):
+    items: list[IdpRoleMappingResponse]
+
+
+class IdpRoleMappingResponse(Model):
+    created_at: datetime = Field(alias='createdAt')
+    id: str
+    idp_role_name: str = Field(alias='idpRoleName')
+    idp_type: str = Field(alias='idpType')
+    platform_role_name: str = Field(alias='platformRoleName')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class ImportItemResponse(Model):
+    action: str = Field(description='create, update, unchanged, conflict or invalid')
+    changed: list[str] | None = Field(default=None, description='Fields that differ from the existing item')
+    key: str = Field(description='Code, role name or email domain')
+    kind: str = Field(description='dispatchPool, eventType, role, authConfig or subscription')
+    message: str | None = Field(default=None)
+
+
+class ImportResponse(Model):
+    dry_run: bool = Field(alias='dryRun')
+    items: list[ImportItemResponse]
+    on_conflict: str = Field(alias='onConflict')
+    summary: ImportSummaryResponse
+
+
+class ImportSummaryResponse(Model):
+    conflict: int
+    create: int
+    invalid: int
+    unchanged: int
+    update: int
+
+
+class KillSwitchListResponse(Model):
+    kill_switches: list[KillSwitchResponse] = Field(alias='killSwitches')
+
+
+class KillSwitchResponse(Model):
+    engaged_at: datetime = Field(alias='engagedAt')
+    engaged_by: str | None = Field(default=None, alias='engagedBy', description='Principal that engaged the switch')
+    id: str
+    reason: str
+    scope: str
+    target_id: str = Field(alias='targetId')
+
+
+class ListOutputBody(Model):
+    requests: list[RequestDTO]
+
+
+class LoginAttemptListResponse(Model):
+    has_more: bool = Field(alias='hasMore')
+    items: list[LoginAttemptResponse]
+    next_cursor: str | None = Field(default=None, alias='nextCursor')
+
+
+class LoginAttemptResponse(Model):
+    attempt_type: str = Field(alias='attemptType')
+    attempted_at: datetime = Field(alias='attemptedAt')
+    failure_reason: str | None = Field(default=None, alias='failureReason')
+    id: str
+    identifier: str
+    ip_address: str | None = Field(default=None, alias='ipAddress')
+    outcome: str
+    principal_id: str | None = Field(default=None, alias='principalId')
+    user_agent: str | None = Field(default=None, alias='userAgent')
+
+
+class MappingListResponse(Model):
+    mappings: list[MappingResponse]
+    total: int
+
+
+class MappingResponse(Model):
+    additional_client_ids: list[str] = Field(alias='additionalClientIds')
+    allowed2fa_methods: list[str] = Field(alias='allowed2faMethods')
+    allowed_role_ids: list[str] = Field(alias='allowedRoleIds')
+    created_at: datetime = Field(alias='createdAt')
+    email_domain: str = Field(alias='emailDomain')
+    granted_client_ids: list[str] = Field(alias='grantedClientIds')
+    id: str
+    identity_provider_id: str = Field(alias='identityProviderId')
+    identity_provider_name: str | None = Field(default=None, alias='identityProviderName')
+    primary_client_id: str | None = Field(default=None, alias='primaryClientId')
+    remember_device_days: int = Field(alias='rememberDeviceDays')
+    remember_device_enabled: bool = Field(alias='rememberDeviceEnabled')
+    require2fa: bool
+    require_passkey_for_admins: bool = Field(alias='requirePasskeyForAdmins')
+    required_oidc_tenant_id: str | None = Field(default=None, alias='requiredOidcTenantId')
+    scope_type: str = Field(alias='scopeType')
+    sync_roles_from_idp: bool = Field(alias='syncRolesFromIdp')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class MetadataDTO(Model):
+    key: str
+    value: str
+
+
+class NackRequest(Model):
+    ack_token: str = Field(alias='ackToken', description='Token the receiver returned with its 202')
+    error: str | None = Field(default=None, description="Why processing failed; recorded as the job's last error")
+
+
+class NoteResponse(Model):
+    added_at: datetime = Field(alias='addedAt')
+    added_by: str | None = Field(default=None, alias='addedBy')
+    category: str
+    text: str
+
+
+class OAuthClientApplicationRef(Model):
+    id: str
+    name: str
+
+
+class OAuthClientListResponse(Model):
+    clients: list[OAuthClientResponse]
+
+
+class OAuthClientResponse(Model):
+    active: bool
+    allowed_origins: list[str] = Field(alias='allowedOrigins')
+    application_ids: list[str] = Field(alias='applicationIds')
+    applications: list[OAuthClientApplicationRef]
+    client_id: str = Field(alias='clientId')
+    client_name: str = Field(alias='clientName')
+    client_type: str = Field(alias='clientType')
+    created_at: datetime = Field(alias='createdAt')
+    default_scopes: list[str] = Field(alias='defaultScopes')
+    grant_types: list[str] = Field(alias='grantTypes')
+    id: str
+    pkce_required: bool = Field(alias='pkceRequired')
+    post_logout_redirect_uris: list[str] = Field(alias='postLogoutRedirectUris')
+    redirect_uris: list[str] = Field(alias='redirectUris')
+    service_account_principal_id: str | None = Field(default=None, alias='serviceAccountPrincipalId')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class OffsetPageScheduledJobInstanceResponse(Model):
+    data: list[ScheduledJobInstanceResponse]
+    page: int
+    size: int
+    total: int
+    total_pages: int
+
+
+class OffsetPageScheduledJobResponse(Model):
+    data: list[ScheduledJobResponse]
+    page: int
+    size: int
+    total: int
+    total_pages: int
+
+
+class PermissionListResponse(Model):
+    permissions: list[PermissionResponse]
+    total: int
+
+
+class PermissionResponse(Model):
+    category: str | None = Field(default=None)
+    description: str | None = Field(default=None)
+    name: str
+    permission: str
+
+
+class PrincipalAvailableApplication(Model):
+    code: str
+    id: str
+    name: str
+
+
+class PrincipalAvailableApplicationsResponse(Model):
+    applications: list[PrincipalAvailableApplication]
+
+
+class PrincipalListResponse(Model):
+    principals: list[PrincipalResponse]
+    total: int
+
+
+class PrincipalResponse(Model):
+    active: bool
+    client_id: str | None = Field(default=None, alias='clientId')
+    created_at: datetime = Field(alias='createdAt')
+    developer_credential_updated_at: datetime | None = Field(default=None, alias='developerCredentialUpdatedAt')
+    email: str | None = Field(default=None)
+    granted_client_ids: list[str] = Field(alias='grantedClientIds')
+    has_developer_credential: bool = Field(alias='hasDeveloperCredential')
+    id: str
+    idp_type: str | None = Field(default=None, alias='idpType')
+    is_anchor_user: bool = Field(alias='isAnchorUser')
+    name: str
+    roles: list[str]
+    scope: str
+    two_factor_methods: list[str] | None = Field(default=None, alias='twoFactorMethods')
+    type: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class PrincipalRoleAssignmentDTO(Model):
+    assigned_at: datetime = Field(alias='assignedAt')
+    assignment_source: str = Field(alias='assignmentSource')
+    id: str
+    role_name: str = Field(alias='roleName')
+
+
+class PrincipalRoleListResponse(Model):
+    roles: list[PrincipalRoleAssignmentDTO]
+
+
+class PrincipalVersionResponse(Model):
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class ProcessListResponse(Model):
+    items: list[ProcessResponse]
+
+
+class ProcessResponse(Model):
+    application: str
+    body: str
+    code: str
+    created_at: datetime = Field(alias='createdAt')
+    created_by: str | None = Field(default=None, alias='createdBy')
+    description: str | None = Field(default=None)
+    diagram_type: str = Field(alias='diagramType')
+    id: str
+    name: str
+    process_name: str = Field(alias='processName')
+    source: str
+    status: str
+    subdomain: str
+    tags: list[str]
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class ProvisionLoginClientRequest(Model):
+    allowed_origins: list[str] | None = Field(default=None, alias='allowedOrigins')
+    client_type: str | None = Field(default=None, alias='clientType', description='PUBLIC (default) or CONFIDENTIAL')
+    redirect_uris: list[str] = Field(alias='redirectUris')
+
+
+class PublicAllowedResponse(Model):
+    origins: list[str]
+
+
+class RawDispatchJobResponse(Model):
+    attempt_count: int = Field(alias='attemptCount')
+    attempt_history_count: int = Field(alias='attemptHistoryCount')
+    client_id: str | None = Field(default=None, alias='clientId')
+    code: str
+    completed_at: datetime | None = Field(default=None, alias='completedAt')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    created_at: datetime = Field(alias='createdAt')
+    dispatch_pool_id: str | None = Field(default=None, alias='dispatchPoolId')
+    event_id: str | None = Field(default=None, alias='eventId')
+    external_id: str | None = Field(default=None, alias='externalId')
+    id: str
+    idempotency_key: str | None = Field(default=None, alias='idempotencyKey')
+    kind: str
+    last_error: str | None = Field(default=None, alias='lastError')
+    max_retries: int = Field(alias='maxRetries')
+    message_group: str | None = Field(default=None, alias='messageGroup')
+    mode: str
+    payload_content_type: str = Field(alias='payloadContentType')
+    payload_length: int = Field(alias='payloadLength')
+    protocol: str
+    retry_strategy: str = Field(alias='retryStrategy')
+    scheduled_for: datetime | None = Field(default=None, alias='scheduledFor')
+    sequence: int
+    service_account_id: str | None = Field(default=None, alias='serviceAccountId')
+    source: str | None = Field(default=None)
+    status: str
+    subject: str | None = Field(default=None)
+    subscription_id: str | None = Field(default=None, alias='subscriptionId')
+    target_url: str = Field(alias='targetUrl')
+    timeout_seconds: int = Field(alias='timeoutSeconds')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class RawEventResponse(Model):
+    causation_id: str | None = Field(default=None, alias='causationId')
+    client_id: str | None = Field(default=None, alias='clientId')
+    context_data: list[ContextEntryDTO] | None = Field(default=None, alias='contextData')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    data: Any | None = Field(default=None)
+    deduplication_id: str | None = Field(default=None, alias='deduplicationId')
+    event_type: str = Field(alias='eventType')
+    id: str
+    message_group: str | None = Field(default=None, alias='messageGroup')
+    source: str
+    spec_version: str = Field(alias='specVersion')
+    subject: str | None = Field(default=None)
+    time: datetime
+
+
+class ReceiptRequest(Model):
+    callback_url: str | None = Field(default=None, alias='callbackUrl', description='Webhook the receipt is POSTed to')
+    event_type: str | None = Field(default=None, alias='eventType', description="Event type code the receipt is emitted as, delivered to that type's subscriptions")
+
+
+class ReconcilerStatusResponse(Model):
+    applied: bool = Field(description='Whether the last pass wrote the drift back')
+    drift: list[DriftItemResponse]
+    enabled: bool
+    error: str | None = Field(default=None)
+    in_sync: bool = Field(alias='inSync')
+    last_applied_at: datetime | None = Field(default=None, alias='lastAppliedAt')
+    last_run_at: datetime | None = Field(default=None, alias='lastRunAt')
+    mode: str | None = Field(default=None, description='apply or report')
+    source: SpecSourceResponse
+
+
+class RegenerateAuthTokenResponse(Model):
+    auth_token: str | None = Field(default=None, alias='authToken')
+    id: str
+
+
+class RegenerateSigningSecretResponse(Model):
+    id: str
+    signing_secret: str | None = Field(default=None, alias='signingSecret')
+
+
+class RegisterBeginRequest(Model):
+    display_name: str | None = Field(default=None, alias='displayName')
+
+
+class RegisterBeginResponse(Model):
+    options: Any
+    state_id: str = Field(alias='stateId')
+
+
+class RegisterCompleteRequest(Model):
+    credential: Any
+    name: str | None = Field(default=None)
+    state_id: str = Field(alias='stateId')
+
+
+class RegisterCompleteResponse(Model):
+    credential_id: str = Field(alias='credentialId')
+
+
+class RequestDTO(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    created_at: datetime = Field(alias='createdAt')
+    email: str
+    expires_at: datetime = Field(alias='expiresAt')
+    id: str
+    name: str
+    principal_id: str = Field(alias='principalId')
+
+
+class RequestErasureRequest(Model):
+    client_id: str | None = Field(default=None, alias='clientId', description='Restrict a SUBJECT erasure to one client')
+    context_keys: list[str] | None = Field(default=None, alias='contextKeys', description='Event context keys that also hold the identifier (SUBJECT only)')
+    subject: str = Field(description='Subject identifier to erase; for subjectType CLIENT, the client id')
+    subject_type: str | None = Field(default=None, alias='subjectType', description='SUBJECT (default) or CLIENT')
+
+
+class RequeueRequest(Model):
+    ids: list[str] = Field(description='Dispatch job ids to reset to PENDING for re-dispatch')
+
+
+class RequeueResponse(Model):
+    requeued: int
+
+
+class ResetPasswordRequest(Model):
+    enforce_password_complexity: bool | None = Field(default=None, alias='enforcePasswordComplexity')
+    new_password: str = Field(alias='newPassword')
+
+
+class RoleAssignmentDTO(Model):
+    assigned_at: datetime = Field(alias='assignedAt')
+    assigned_by: str | None = Field(default=None, alias='assignedBy')
+    assignment_source: str | None = Field(default=None, alias='assignmentSource')
+    client_id: str | None = Field(default=None, alias='clientId')
+    role_name: str = Field(alias='roleName')
+
+
+class RoleItem(Model):
+    application_code: str = Field(alias='applicationCode')
+    client_managed: bool | None = Field(default=None, alias='clientManaged')
+    description: str | None = Field(default=None)
+    display_name: str = Field(alias='displayName')
+    name: str
+    permissions: list[str] | None = Field(default=None)
+    source: str | None = Field(default=None)
+
+
+class RoleListResponse(Model):
+    roles: list[RoleResponse]
+    total: int
+
+
+class RolePermissionListResponse(Model):
+    permissions: list[str]
+
+
+class RoleResponse(Model):
+    application_code: str = Field(alias='applicationCode')
+    application_id: str | None = Field(default=None, alias='applicationId')
+    client_managed: bool = Field(alias='clientManaged')
+    created_at: datetime = Field(alias='createdAt')
+    description: str | None = Field(default=None)
+    display_name: str = Field(alias='displayName')
+    id: str
+    name: str
+    permissions: list[str]
+    source: str
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class RolesAssignedResponse(Model):
+    added: list[str]
+    removed: list[str]
+    roles: list[PrincipalRoleAssignmentDTO]
+
+
+class RotateOAuthClientSecretResponse(Model):
+    client_id: str = Field(alias='clientId')
+    client_secret: str | None = Field(default=None, alias='clientSecret')
+
+
+class RotateSigningKeyRequest(Model):
+    active_from: datetime | None = Field(default=None, alias='activeFrom', description='When the new key starts signing (default now, at most 30 days ahead)')
+    key_id: str | None = Field(default=None, alias='keyId', description='Name of the new key, sent as X-FLOWCATALYST-KEY-ID (default v<n>)')
+    overlap_seconds: int | None = Field(default=None, alias='overlapSeconds', description='How long the replaced keys stay valid after activeFrom (default 86400)')
+
+
+class ScheduledJobInstanceLogResponse(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    created_at: datetime = Field(alias='createdAt')
+    id: str
+    instance_id: str = Field(alias='instanceId')
+    level: str
+    message: str
+    metadata: Any | None = Field(default=None)
+    scheduled_job_id: str | None = Field(default=None, alias='scheduledJobId')
+
+
+class ScheduledJobInstanceResponse(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    completed_at: datetime | None = Field(default=None, alias='completedAt')
+    completion_result: Any | None = Field(default=None, alias='completionResult')
+    completion_status: str | None = Field(default=None, alias='completionStatus')
+    correlation_id: str | None = Field(default=None, alias='correlationId')
+    created_at: datetime = Field(alias='createdAt')
+    delivered_at: datetime | None = Field(default=None, alias='deliveredAt')
+    delivery_attempts: int = Field(alias='deliveryAttempts')
+    delivery_error: str | None = Field(default=None, alias='deliveryError')
+    fired_at: datetime = Field(alias='firedAt')
+    id: str
+    job_code: str = Field(alias='jobCode')
+    scheduled_for: datetime | None = Field(default=None, alias='scheduledFor')
+    scheduled_job_id: str = Field(alias='scheduledJobId')
+    status: str
+    trigger_kind: str = Field(alias='triggerKind')
+
+
+class ScheduledJobResponse(Model):
+    application_id: str | None = Field(default=None, alias='applicationId')
+    client_id: str | None = Field(default=None, alias='clientId')
+    code: str
+    concurrent: bool
+    created_at: datetime = Field(alias='createdAt')
+    created_by: str | None = Field(default=None, alias='createdBy')
+    crons: list[str]
+    delivery_max_attempts: int = Field(alias='deliveryMaxAttempts')
+    description: str | None = Field(default=None)
+    has_active_instance: bool = Field(alias='hasActiveInstance')
+    id: str
+    last_fired_at: datetime | None = Field(default=None, alias='lastFiredAt')
+    name: str
+    payload: Any | None = Field(default=None)
+    status: str
+    target_url: str | None = Field(default=None, alias='targetUrl')
+    timeout_seconds: int | None = Field(default=None, alias='timeoutSeconds')
+    timezone: str
+    tracks_completion: bool = Field(alias='tracksCompletion')
+    updated_at: datetime = Field(alias='updatedAt')
+    updated_by: str | None = Field(default=None, alias='updatedBy')
+    version: int
+
+
+class SchemaItem(Model):
+    content: Any | None = Field(default=None)
+    mime_type: str | None = Field(default=None, alias='mimeType')
+    schema_type: str | None = Field(default=None, alias='schemaType')
+    status: str | None = Field(default=None)
+    version: str
+
+
+class SearchClientRequest(Model):
+    term: str
+
+
+class SendPasswordResetInputBody(Model):
+    reset2fa: bool | None = Field(default=None)
+
+
+class ServiceAccountListResponse(Model):
+    service_accounts: list[ServiceAccountResponse] = Field(alias='serviceAccounts')
+    total: int
+
+
+class ServiceAccountOAuthSecrets(Model):
+    client_id: str = Field(alias='clientId')
+    client_secret: str = Field(alias='clientSecret')
+
+
+class ServiceAccountResponse(Model):
+    active: bool
+    application_id: str | None = Field(default=None, alias='applicationId')
+    auth_type: str = Field(alias='authType')
+    client_ids: list[str] = Field(alias='clientIds')
+    code: str
+    created_at: datetime = Field(alias='createdAt')
+    description: str | None = Field(default=None)
+    id: str
+    last_used_at: datetime | None = Field(default=None, alias='lastUsedAt')
+    name: str
+    principal_id: str | None = Field(default=None, alias='principalId')
+    roles: list[str]
+    scope: str | None = Field(default=None)
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class ServiceAccountRoleListResponse(Model):
+    roles: list[RoleAssignmentDTO]
+
+
+class ServiceAccountRolesAssignedResponse(Model):
+    added_roles: list[str] = Field(alias='addedRoles')
+    removed_roles: list[str] = Field(alias='removedRoles')
+    roles: list[RoleAssignmentDTO]
+
+
+class ServiceAccountWebhookSecrets(Model):
+    auth_token: str = Field(alias='authToken')
+    signing_secret: str = Field(alias='signingSecret')
+
+
+class SetApplicationAccessResponse(Model):
+    added: int
+    all_applications: bool = Field(alias='allApplications')
+    applications: list[ApplicationAccessResponse]
+    removed: int
+
+
+class SetDeveloperCredentialResponse(Model):
+    client_secret: str | None = Field(default=None, alias='clientSecret')
+    id: str
+
+
+class SetPropertyRequest(Model):
+    client_id: str | None = Field(default=None, alias='clientId')
+    description: str | None = Field(default=None)
+    value: str
+    value_type: str | None = Field(default=None, alias='valueType', description='PLAIN or SECRET')
+
+
+class SigningKeyCreatedResponse(Model):
+    secret: str = Field(description='HMAC-SHA256 signing secret. Shown only in this response.')
+    signing_key: SigningKeyResponse = Field(alias='signingKey')
+
+
+class SigningKeyListResponse(Model):
+    signing_keys: list[SigningKeyResponse] = Field(alias='signingKeys')
+
+
+class SigningKeyResponse(Model):
+    active_from: datetime = Field(alias='activeFrom')
+    client_id: str = Field(alias='clientId')
+    created_at: datetime = Field(alias='createdAt')
+    created_by: str | None = Field(default=None, alias='createdBy')
+    expires_at: datetime | None = Field(default=None, alias='expiresAt')
+    id: str
+    key_id: str = Field(alias='keyId')
+    signing: bool
+    status: str = Field(description='PENDING, ACTIVE or EXPIRED')
+
+
+class SpecSourceResponse(Model):
+    digest: str | None = Field(default=None, description='SHA-256 over the spec files')
+    files: list[str]
+    path: str
+    revision: str | None = Field(default=None, description='Git commit checked out at the spec path, when it is a git working tree')
+
+
+class SpecVersionResponse(Model):
+    created_at: datetime = Field(alias='createdAt')
+    schema_: Any = Field(alias='schema')
+    schema_type: str = Field(alias='schemaType')
+    status: str
+    version: str
+
+
+class StatusChangeRequest(Model):
+    reason: str
+
+
+class StatusChangeResponse(Model):
+    message: str
+
+
+class StreamDLQEntryResponse(Model):
+    attempts: int = Field(description='Failed attempts, summed across reprocessing')
+    created_at: datetime = Field(alias='createdAt')
+    error: str = Field(description='The last failure')
+    event_created_at: datetime = Field(alias='eventCreatedAt')
+    event_type: str = Field(alias='eventType')
+    id: str
+    message_group: str | None = Field(default=None, alias='messageGroup')
+    stage: str = Field(description='Pipeline step that failed: FAN_OUT')
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class StreamDLQListResponse(Model):
+    items: list[StreamDLQEntryResponse]
+
+
+class SubscriptionItem(Model):
+    application_code: str | None = Field(default=None, alias='applicationCode')
+    batching: Batching | None = Field(default=None)
+    client_scoped: bool | None = Field(default=None, alias='clientScoped')
+    code: str
+    connection_code: str | None = Field(default=None, alias='connectionCode')
+    custom_config: list[ConfigEntry] | None = Field(default=None, alias='customConfig')
+    data_only: bool | None = Field(default=None, alias='dataOnly')
+    delay_seconds: int = Field(alias='delaySeconds')
+    delivery: str | None = Field(default=None)
+    description: str | None = Field(default=None)
+    dispatch_pool_code: str | None = Field(default=None, alias='dispatchPoolCode')
+    endpoint: str | None = Field(default=None)
+    event_types: list[BindingItem] = Field(alias='eventTypes')
+    headers: list[ConfigEntry] | None = Field(default=None)
+    large_payloads: str | None = Field(default=None, alias='largePayloads')
+    max_age_seconds: int = Field(alias='maxAgeSeconds')
+    max_retries: int = Field(alias='maxRetries')
+    mode: str | None = Field(default=None)
+    name: str
+    payload_format: str | None = Field(default=None, alias='payloadFormat')
+    queue: str | None = Field(default=None)
+    sequence: int
+    source: str | None = Field(default=None)
+    status: str | None = Field(default=None)
+    timeout_seconds: int = Field(alias='timeoutSeconds')
+    transport: str | None = Field(default=None)
+
+
+class SubscriptionListResponse(Model):
+    subscriptions: list[SubscriptionResponse]
+    total: int
+
+
+class SubscriptionResponse(Model):
+    application_code: str | None = Field(default=None, alias='applicationCode')
+    batching: BatchingDTO | None = Field(default=None)
+    client_id: str | None = Field(default=None, alias='clientId')
+    client_identifier: str | None = Field(default=None, alias='clientIdentifier')
+    client_scoped: bool = Field(alias='clientScoped')
+    code: str
+    connection_id: str | None = Field(default=None, alias='connectionId')
+    created_at: datetime = Field(alias='createdAt')
+    created_by: str | None = Field(default=None, alias='createdBy')
+    custom_config: list[ConfigEntryDTO] = Field(alias='customConfig')
+    data_only: bool = Field(alias='dataOnly')
+    delay_seconds: int = Field(alias='delaySeconds')
+    delivery: str
+    description: str | None = Field(default=None)
+    dispatch_pool_code: str | None = Field(default=None, alias='dispatchPoolCode')
+    dispatch_pool_id: str | None = Field(default=None, alias='dispatchPoolId')
+    endpoint: str
+    event_types: list[EventTypeBindingDTO] = Field(alias='eventTypes')
+    headers: list[ConfigEntryDTO]
+    id: str
+    large_payloads: str = Field(alias='largePayloads')
+    max_age_seconds: int = Field(alias='maxAgeSeconds')
+    max_retries: int = Field(alias='maxRetries')
+    mode: str
+    name: str
+    payload_format: str = Field(alias='payloadFormat')
+    queue: str | None = Field(default=None)
+    sequence: int
+    service_account_id: str | None = Field(default=None, alias='serviceAccountId')
+    source: str
+    status: str
+    timeout_seconds: int = Field(alias='timeoutSeconds')
+    transport: str | None = Field(default=None)
+    updated_at: datetime = Field(alias='updatedAt')
+
+
+class SuccessResponse(Model):
+    message: str | None = Field(default=None)
+    success: bool
+
+
+class SuspendClientRequest(Model):
+    reason: str
+
+
+class SyncDispatchPoolInputRequest(Model):
+    code: str
+    concurrency: int | None = Field(default=None)
+    description: str | None = Field(default=None)
+    name: str
+    rate_limit: int | None = Field(default=None, alias='rateLimit', description='Messages per minute; omit for concurrency-only')
+
+
+class SyncDispatchPoolsRequest(Model):
+    pools: list[SyncDispatchPoolInputRequest]
+
+
+class SyncEventTypeInputRequest(Model):
+    code: str = Field(description='Full code (application:subdomain:aggregate:event)')
+    description: str | None = Field(default=None)
+    name: str
+
+
+class SyncEventTypesRequest(Model):
+    event_types: list[SyncEventTypeInputRequest] = Field(alias='eventTypes')
+
+
+class SyncOpenApiSpecResponse(Model):
+    application_code: str = Field(alias='applicationCode')
+    archived_prior_version: str | None = Field(default=None, alias='archivedPriorVersion')
+    has_breaking: bool = Field(alias='hasBreaking')
+    spec_id: str = Field(alias='specId')
+    status: str
+    unchanged: bool
+    version: str
+
+
+class SyncOpenapiRequest(Model):
+    spec: Any = Field(description='The OpenAPI document (OpenAPI 3.x or Swagger 2.x)')
+
+
+class SyncPrincipalInputRequest(Model):
+    active: bool | None = Field(default=None, description='Whether the user is active (default true)')
+    email: str = Field(description="User's email address (unique identifier for matching)")
+    name: str
+    password_hash: str | None = Field(default=None, alias='passwordHash', description='Pre-hashed password (bcrypt/argon2i/argon2id), stored verbatim; migrated on first login')
+    roles: list[str] | None = Field(default=None, description='Role short names (prefixed with applicationCode)')
+
+
+class SyncPrincipalsRequest(Model):
+    principals: list[SyncPrincipalInputRequest]
+
+
+class SyncProcessInputRequest(Model):
+    body: str | None = Field(default=None, description='Diagram body (typically Mermaid source)')
+    code: str = Field(description='Full code (application:subdomain:process-name)')
+    description: str | None = Field(default=None)
+    diagram_type: str | None = Field(default=None, alias='diagramType')
+    name: str
+    tags: list[str] | None = Field(default=None)
+
+
+class SyncProcessesByBodyRequest(Model):
+    application_code: str = Field(alias='applicationCode', description='Application code (carried in the body for /api/processes/sync)')
+    processes: list[SyncProcessInputRequest]
+
+
+class SyncProcessesRequest(Model):
+    processes: list[SyncProcessInputRequest]
+
+
+class SyncResultResponse(Model):
+    application_code: str = Field(alias='applicationCode')
+    created: int
+    deleted: int
+    synced_codes: list[str] = Field(alias='syncedCodes')
+    updated: int
+
+
+class SyncRoleInputRequest(Model):
+    client_managed: bool | None = Field(default=None, alias='clientManaged')
+    description: str | None = Field(default=None)
+    display_name: str | None = Field(default=None, alias='displayName')
+    name: str
+    permissions: list[str] | None = Field(default=None)
+
+
+class SyncRolesRequest(Model):
+    roles: list[SyncRoleInputRequest]
+
+
+class SyncScheduledJobInputRequest(Model):
+    code: str
+    concurrent: bool | None = Field(default=None)
+    crons: list[str]
+    delivery_max_attempts: int | None = Field(default=None, alias='deliveryMaxAttempts', description='Default 3 when omitted')
+    description: str | None = Field(default=None)
+    name: str
+    payload: Any | None = Field(default=None)
+    target_url: str | None = Field(default=None, alias='targetUrl')
+    timeout_seconds: int | None = Field(default=None, alias='timeoutSeconds')
+    timezone: str | None = Field(default=None, description='IANA timezone (default UTC)')
+    tracks_completion: bool | None = Field(default=None, alias='tracksCompletion')
+
+
+class SyncScheduledJobsRequest(Model):
+    archive_unlisted: bool | None = Field(default=None, alias='archiveUnlisted')
+    client_id: str | None = Field(default=None, alias='clientId')
+    jobs: list[SyncScheduledJobInputRequest]
+
+
+class SyncScheduledJobsResultResponse(Model):
+    application_code: str = Field(alias='applicationCode')
+    archived: list[str]
+    created: list[str]
+    updated: list[str]
+
+
+class SyncSubscriptionEventTypeRequest(Model):
+    event_type_code: str = Field(alias='eventTypeCode')
+    filter: str | None = Field(default=None)
+
+
+class SyncSubscriptionInputRequest(Model):
+    code: str
+    connection_id: str | None = Field(default=None, alias='connectionId')
+    data_only: bool | None = Field(default=None, alias='dataOnly')
+    description: str | None = Field(default=None)
+    dispatch_pool_code: str | None = Field(default=None, alias='dispatchPoolCode')
+    event_types: list[SyncSubscriptionEventTypeRequest] = Field(alias='eventTypes')
+    max_retries: int | None = Field(default=None, alias='maxRetries')
+    mode: str | None = Field(default=None)
+    name: str
+    target: str
+    timeout_seconds: int | None = Field(default=None, alias='timeoutSeconds')
+
+
+class SyncSubscriptionsRequest(Model):
+    subscriptions: list[SyncSubscriptionInputRequest]
+
+
+class SyncUserInput(Model):
+    active: bool | None = Field(default=None, description='Whether the user is active (default true)')
+    email: str = Field(description="User's email address (unique identifier for matching)")
+    name: str = Field(description='Display name')
+    password_hash: str | None = Field(default=None, alias='passwordHash', description='Pre-hashed password (bcrypt/argon2i/argon2id), stored verbatim; migrated on first login. Omit to leave any existing password untouched.')
+    roles: list[str] | None = Field(default=None, description="Role names to assign (SDK_SYNC source; replaces this source's prior set)")
+
+
+class SyncUsersRequest(Model):
+    principals: list[SyncUserInput]
+
+
+class SyncUsersResponse(Model):
+    created: int
+    deleted: int
+    synced_emails: list[str] = Field(alias='syncedEmails')
+    updated: int
+
+
+class UpdateAnchorDomainRequest(Model):
+    domain: str
+
+
+class UpdateAnomalySettingRequest(Model):
+    enabled: bool = Field(description='False stops the entity raising anomalies; its baseline keeps learning')
+    sensitivity: float | None = Field(default=None, description="Z-score a window's value must reach to raise an anomaly, 2-10 (default 3). Lower is more sensitive.")
+    snoozed_until: datetime | None = Field(default=None, alias='snoozedUntil', description='Raise nothing for the entity until then')
+
+
+class UpdateApplicationRequest(Model):
+    default_base_url: str | None = Field(default=None, alias='defaultBaseUrl')
+    description: str | None = Field(default=None)
+    icon_url: str | None = Field(default=None, alias='iconUrl')
+    logo: str | None = Field(default=None)
+    logo_mime_type: str | None = Field(default=None, alias='logoMimeType')
+    name: str | None = Field(default=None)
+    website: str | None = Field(default=None)
+
+
+class UpdateAuthConfigRequest(Model):
+    additional_client_ids: list[str] | None = Field(default=None, alias='additionalClientIds')
+    auth_provider: str | None = Field(default=None, alias='authProvider')
+    granted_client_ids: list[str] | None = Field(default=None, alias='grantedClientIds')
+    oidc_client_id: str | None = Field(default=None, alias='oidcClientId')
+    oidc_client_secret_ref: str | None = Field(default=None, alias='oidcClientSecretRef')
+    oidc_issuer_pattern: str | None = Field(default=None, alias='oidcIssuerPattern')
+    oidc_issuer_url: str | None = Field(default=None, alias='oidcIssuerUrl')
+    oidc_multi_tenant: bool | None = Field(default=None, alias='oidcMultiTenant')
+    primary_client_id: str | None = Field(default=None, alias='primaryClientId')
+
+
+class UpdateClientApplicationsRequest(Model):
+    enabled_application_ids: list[str] = Field(alias='enabledApplicationIds')
+
+
+class UpdateClientRequest(Model):
+    name: str | None = Field(default=None)
+
+
+class UpdateConnectionRequest(Model):
+    description: str | None = Field(default=None)
+    external_id: str | None = Field(default=None, alias='externalId')
+    name: str
+    status: str | None = Field(default=None)
+
+
+class UpdateDataPolicyRequest(Model):
+    metadata_retention_days: int | None = Field(default=None, alias='metadataRetentionDays', description='Delete events and their dispatch jobs after this many days; absent keeps them until partition drop')
+    payload_retention_days: int | None = Field(default=None, alias='payloadRetentionDays', description='Purge event data and dispatch payloads after this many days; absent keeps them until partition drop')
+    redact_fields: list[str] | None = Field(default=None, alias='redactFields', description='Dotted JSON paths masked on read and debug views (arrays are walked; * matches any key)')
+
+
+class UpdateDispatchPoolRequest(Model):
+    concurrency: int | None = Field(default=None)
+    description: str | None = Field(default=None)
+    name: str | None = Field(default=None)
+    rate_limit: int | None = Field(default=None, alias='rateLimit')
+
+
+class UpdateEventTypeRequest(Model):
+    description: str | None = Field(default=None)
+    name: str
+
+
+class UpdateIdentityProviderRequest(Model):
+    allowed_email_domains: list[str] | None = Field(default=None, alias='allowedEmailDomains')
+    name: str | None = Field(default=None)
+    oidc_client_id: str | None = Field(default=None, alias='oidcClientId')
+    oidc_client_secret_ref: str | None = Field(default=None, alias='oidcClientSecretRef')
+    oidc_issuer_pattern: str | None = Field(default=None, alias='oidcIssuerPattern')
+    oidc_issuer_url: str | None = Field(default=None, alias='oidcIssuerUrl')
+    oidc_multi_tenant: bool | None = Field(default=None, alias='oidcMultiTenant')
+
+
+class UpdateMappingRequest(Model):
+    additional_client_ids: list[str] | None = Field(default=None, alias='additionalClientIds')
+    allowed2fa_methods: list[str] | None = Field(default=None, alias='allowed2faMethods')
+    allowed_role_ids: list[str] | None = Field(default=None, alias='allowedRoleIds')
+    granted_client_ids: list[str] | None = Field(default=None, alias='grantedClientIds')
+    identity_provider_id: str | None = Field(default=None, alias='identityProviderId')
+    primary_client_id: str | None = Field(default=None, alias='primaryClientId')
+    remember_device_days: int | None = Field(default=None, alias='rememberDeviceDays')
+    remember_device_enabled: bool | None = Field(default=None, alias='rememberDeviceEnabled')
+    require2fa: bool | None = Field(default=None)
+    require_passkey_for_admins: bool | None = Field(default=None, alias='requirePasskeyForAdmins')
+    required_oidc_tenant_id: str | None = Field(default=None, alias='requiredOidcTenantId')
+    sync_roles_from_idp: bool | None = Field(default=None, alias='syncRolesFromIdp')
+
+
+class UpdateOAuthClientRequest(Model):
+    allowed_origins: list[str] | None = Field(default=None, alias='allowedOrigins')
+    application_ids: list[str] | None = Field(default=None, alias='applicationIds')
+    client_name: str | None = Field(default=None, alias='clientName')
+    default_scopes: list[str] | None = Field(default=None, alias='defaultScopes')
+    grant_types: list[str] | None = Field(default=None, alias='grantTypes')
+    pkce_required: bool | None = Field(default=None, alias='pkceRequired')
+    post_logout_redirect_uris: list[str] | None = Field(default=None, alias='postLogoutRedirectUris')
+    redirect_uris: list[str] | None = Field(default=None, alias='redirectUris')
+    scopes: list[str] | None = Field(default=None)
+
+
+class UpdatePrincipalRequest(Model):
+    active: bool | None = Field(default=None)
+    email: str | None = Field(default=None, description='Optional; asserted against the stored email — a different value is rejected, not treated as a rename')
+    name: str | None = Field(default=None)
+
+
+class UpdateProcessRequest(Model):
+    body: str | None = Field(default=None)
+    description: str | None = Field(default=None)
+    diagram_type: str | None = Field(default=None, alias='diagramType')
+    name: str | None = Field(default=None)
+    tags: list[str] | None = Field(default=None)
+
+
+class UpdateRoleRequest(Model):
+    client_managed: bool | None = Field(default=None, alias='clientManaged')
+    description: str | None = Field(default=None)
+    display_name: str | None = Field(default=None, alias='displayName')
+    permissions: list[str] | None = Field(default=None)
+
+
+class UpdateScheduledJobRequest(Model):
+    concurrent: bool | None = Field(default=None)
+    crons: list[str] | None = Field(default=None)
+    delivery_max_attempts: int | None = Field(default=None, alias='deliveryMaxAttempts')
+    description: str | None = Field(default=None)
+    name: str | None = Field(default=None)
+    payload: Any | None = Field(default=None)
+    target_url: str | None = Field(default=None, alias='targetUrl')
+    timeout_seconds: int | None = Field(default=None, alias='timeoutSeconds')
+    timezone: str | None = Field(default=None)
+    tracks_completion: bool | None = Field(default=None, alias='tracksCompletion')
+
+
+class UpdateServiceAccountRequest(Model):
+    client_ids: list[str] | None = Field(default=None, alias='clientIds')
+    description: str | None = Field(default=None)
+    name: str | None = Field(default=None)
+    scope: str | None = Field(default=None)
+    webhook_credentials: WebhookCredentialsDTO | None = Field(default=None, alias='webhookCredentials')
+
+
+class UpdateSubscriptionRequest(Model):
+    batching: BatchingDTO | None = Field(default=None)
+    connection_id: str | None = Field(default=None, alias='connectionId')
+    custom_config: list[ConfigEntryDTO] | None = Field(default=None, alias='customConfig')
+    data_only: bool | None = Field(default=None, alias='dataOnly')
+    delay_seconds: int | None = Field(default=None, alias='delaySeconds')
+    delivery: str | None = Field(default=None, description='PUSH or PULL; switching to PUSH needs a valid endpoint')
+    description: str | None = Field(default=None)
+    dispatch_pool_id: str | None = Field(default=None, alias='dispatchPoolId')
+    endpoint: str | None = Field(default=None)
+    event_types: list[EventTypeBindingDTO] | None = Field(default=None, alias='eventTypes')
+    headers: list[ConfigEntryDTO] | None = Field(default=None, description='Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time')
+    large_payloads: str | None = Field(default=None, alias='largePayloads', description='INLINE or PRESIGNED_URL')
+    max_age_seconds: int | None = Field(default=None, alias='maxAgeSeconds')
+    max_retries: int | None = Field(default=None, alias='maxRetries')
+    mode: str | None = Field(default=None)
+    name: str | None = Field(default=None)
+    payload_format: str | None = Field(default=None, alias='payloadFormat', description='JSON, CLOUDEVENTS_JSON, NDJSON or PROTOBUF')
+    service_account_id: str | None = Field(default=None, alias='serviceAccountId')
+    timeout_seconds: int | None = Field(default=None, alias='timeoutSeconds')
+    transport: str | None = Field(default=None, description='Named outbound transport deliveries go through; an empty string switches back to direct delivery')
+
+
+class UpsertDispatchPoolRequest(Model):
+    concurrency: int | None = Field(default=None, description='Max concurrent dispatches (default 10)')
+    description: str | None = Field(default=None)
+    name: str
+    rate_limit: int | None = Field(default=None, alias='rateLimit', description='Messages per minute (nil = no rate limit)')
+
+
+class UpsertEventTypeRequest(Model):
+    description: str | None = Field(default=None)
+    name: str = Field(description='Human-readable event type name')
+    schema_: Any | None = Field(default=None, alias='schema', description='Optional JSON Schema for the initial spec version; used only on create')
+
+
+class UpsertServiceAccountRequest(Model):
+    application_id: str | None = Field(default=None, alias='applicationId')
+    client_ids: list[str] | None = Field(default=None, alias='clientIds')
+    description: str | None = Field(default=None)
+    name: str
+    scope: str | None = Field(default=None)
+    webhook_credentials: WebhookCredentialsDTO | None = Field(default=None, alias='webhookCredentials')
+
+
+class UpsertServiceAccountResponse(Model):
+    oauth: ServiceAccountOAuthSecrets | None = Field(default=None)
+    principal_id: str | None = Field(default=None, alias='principalId')
+    service_account: ServiceAccountResponse = Field(alias='serviceAccount')
+    webhook: ServiceAccountWebhookSecrets | None = Field(default=None)
+
+
+class UpsertSubscriptionRequest(Model):
+    batching: BatchingDTO | None = Field(default=None)
+    connection_id: str | None = Field(default=None, alias='connectionId')
+    custom_config: list[ConfigEntryDTO] | None = Field(default=None, alias='customConfig')
+    data_only: bool | None = Field(default=None, alias='dataOnly')
+    delay_seconds: int | None = Field(default=None, alias='delaySeconds')
+    delivery: str | None = Field(default=None, description='PUSH (default) POSTs each dispatch job to the endpoint; PULL holds them for the consumer to fetch from GET /api/consume/{subscriptionId}')
+    description: str | None = Field(default=None)
+    dispatch_pool_id: str | None = Field(default=None, alias='dispatchPoolId')
+    endpoint: str | None = Field(default=None, description='http(s) URL delivery target; required unless delivery is PULL')
+    event_types: list[EventTypeBindingDTO] | None = Field(default=None, alias='eventTypes')
+    headers: list[ConfigEntryDTO] | None = Field(default=None, description='Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time')
+    large_payloads: str | None = Field(default=None, alias='largePayloads', description='How a payload offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES is delivered: INLINE (default) rehydrates it; PRESIGNED_URL sends its $blobRef reference with a pre-signed url to fetch it from')
+    max_age_seconds: int | None = Field(default=None, alias='maxAgeSeconds')
+    max_retries: int | None = Field(default=None, alias='maxRetries')
+    mode: str | None = Field(default=None, description='Dispatch mode (IMMEDIATE, NEXT_ON_ERROR, BLOCK_ON_ERROR)')
+    name: str
+    payload_format: str | None = Field(default=None, alias='payloadFormat', description="How deliveries are serialized: JSON (default), CLOUDEVENTS_JSON, NDJSON or PROTOBUF (transcoded with the event type's current PROTO schema). Pull consumers always receive JSON")
+    service_account_id: str | None = Field(default=None, alias='serviceAccountId')
+    timeout_seconds: int | None = Field(default=None, alias='timeoutSeconds')
+    transport: str | None = Field(default=None, description='Named outbound transport (SSH bastion or SOCKS5 proxy, see FC_OUTBOUND_TRANSPORTS) deliveries go through; omit to deliver directly')
+
+
+class WebauthnAuthenticateCompleteResponse(Model):
+    email: str | None = Field(default=None)
+    name: str
+    principal_id: str = Field(alias='principalId')
+    roles: list[str]
+
+
+class WebauthnCredentialSummary(Model):
+    created_at: datetime = Field(alias='createdAt')
+    id: str
+    last_used_at: datetime | None = Field(default=None, alias='lastUsedAt')
+    name: str | None = Field(default=None)
+
+
+class WebhookCredentialsDTO(Model):
+    auth_type: str = Field(alias='authType')
+    header_name: str | None = Field(default=None, alias='headerName')
+    password: str | None = Field(default=None)
+    signature_header: str | None = Field(default=None, alias='signatureHeader')
+    signing_algorithm: str | None = Field(default=None, alias='signingAlgorithm')
+    signing_secret: str | None = Field(default=None, alias='signingSecret')
+    token: str | None = Field(default=None)
+    username: str | None = Field(default=None)
+
+
+class WriteInstanceLogRequest(Model):
+    level: str = Field(description='DEBUG | INFO | WARN | ERROR')
+    message: str
+    metadata: Any | None = Field(default=None)
+
+
+AccessListResponse.model_rebuild()
+AccessResponse.model_rebuild()
+AckRequest.model_rebuild()
+AckResponse.model_rebuild()
+AddNoteRequest.model_rebuild()
+AddOriginRequest.model_rebuild()
+AddRoleRequest.model_rebuild()
+AddSchemaRequest.model_rebuild()
+AllowedOriginResponse.model_rebuild()
+AnchorDomainListResponse.model_rebuild()
+AnchorDomainResponse.model_rebuild()
+AnomalyListResponse.model_rebuild()
+AnomalyResponse.model_rebuild()
+AnomalySettingListResponse.model_rebuild()
+AnomalySettingResponse.model_rebuild()
+ApplicationAccessListResponse.model_rebuild()
+ApplicationAccessResponse.model_rebuild()
+ApplicationFilterListResponse.model_rebuild()
+ApplicationListResponse.model_rebuild()
+ApplicationLoginClientCredentials.model_rebuild()
+ApplicationOAuthClientCredentials.model_rebuild()
+ApplicationProvisionLoginClientResponse.model_rebuild()
+ApplicationProvisionServiceAccountResponse.model_rebuild()
+ApplicationResponse.model_rebuild()
+ApplicationRolesResponse.model_rebuild()
+ApplicationServiceAccountCredentials.model_rebuild()
+AssignApplicationAccessRequest.model_rebuild()
+AssignPrincipalRolesRequest.model_rebuild()
+AssignRolesRequest.model_rebuild()
+AttachServiceAccountRequest.model_rebuild()
+AttemptDTO.model_rebuild()
+AuditLogApplicationIDsResponse.model_rebuild()
+AuditLogClientIDsResponse.model_rebuild()
+AuditLogEntityTypesResponse.model_rebuild()
+AuditLogListResponse.model_rebuild()
+AuditLogOperationsResponse.model_rebuild()
+AuditLogResponse.model_rebuild()
+AuthConfigItem.model_rebuild()
+AuthConfigListResponse.model_rebuild()
+AuthConfigResponse.model_rebuild()
+AuthenticateBeginRequest.model_rebuild()
+AuthenticateBeginResponse.model_rebuild()
+AuthenticateCompleteRequest.model_rebuild()
+BatchEventItem.model_rebuild()
+BatchRequest.model_rebuild()
+BatchResponse.model_rebuild()
+BatchResultItem.model_rebuild()
+Batching.model_rebuild()
+BatchingDTO.model_rebuild()
+BindingItem.model_rebuild()
+BulkImportRequest.model_rebuild()
+BulkImportResponse.model_rebuild()
+BulkImportResult.model_rebuild()
+BulkImportUser.model_rebuild()
+Bundle.model_rebuild()
+CancelRequest.model_rebuild()
+CancelResponse.model_rebuild()
+CheckEmailDomainResponse.model_rebuild()
+ClientAccessGrantListResponse.model_rebuild()
+ClientAccessGrantResponse.model_rebuild()
+ClientApplicationResponse.model_rebuild()
+ClientApplicationsResponse.model_rebuild()
+ClientAssociationRequest.model_rebuild()
+ClientConfigListResponse.model_rebuild()
+ClientConfigResponse.model_rebuild()
+ClientListResponse.model_rebuild()
+ClientResponse.model_rebuild()
+CompleteInstanceRequest.model_rebuild()
+ConfigEntry.model_rebuild()
+ConfigEntryDTO.model_rebuild()
+ConfigListResponse.model_rebuild()
+ConfigResponse.model_rebuild()
+ConnectionListResponse.model_rebuild()
+ConnectionResponse.model_rebuild()
+ConsumeAckRequest.model_rebuild()
+ConsumeNackRequest.model_rebuild()
+ConsumeResponse.model_rebuild()
+ConsumedMessage.model_rebuild()
+ContextEntryDTO.model_rebuild()
+CorsOriginListResponse.model_rebuild()
+CreateAnchorDomainRequest.model_rebuild()
+CreateApplicationRequest.model_rebuild()
+CreateAuthConfigRequest.model_rebuild()
+CreateClientRequest.model_rebuild()
+CreateConnectionRequest.model_rebuild()
+CreateDispatchPoolRequest.model_rebuild()
+CreateEventRequest.model_rebuild()
+CreateEventResponse.model_rebuild()
+CreateEventTypeRequest.model_rebuild()
+CreateIdentityProviderRequest.model_rebuild()
+CreateIdpRoleMappingRequest.model_rebuild()
+CreateMappingRequest.model_rebuild()
+CreateOAuthClientRequest.model_rebuild()
+CreateOAuthClientResponse.model_rebuild()
+CreatePrincipalRequest.model_rebuild()
+CreateProcessRequest.model_rebuild()
+CreateRoleRequest.model_rebuild()
+CreateScheduledJobRequest.model_rebuild()
+CreateServiceAccountRequest.model_rebuild()
+CreateServiceAccountResponse.model_rebuild()
+CreateSubscriptionRequest.model_rebuild()
+CreateUserRequest.model_rebuild()
+CreatedEvent.model_rebuild()
+CreatedResponse.model_rebuild()
+DeveloperUserListResponse.model_rebuild()
+DispatchJobFilterOptionsResponse.model_rebuild()
+DispatchJobRead.model_rebuild()
+DispatchJobResponse.model_rebuild()
+DispatchPoolItem.model_rebuild()
+DispatchPoolListResponse.model_rebuild()
+DispatchPoolResponse.model_rebuild()
+DriftItemResponse.model_rebuild()
+EngageKillSwitchRequest.model_rebuild()
+ErasureRemainingResponse.model_rebuild()
+ErasureReportResponse.model_rebuild()
+ErasureRequestListResponse.model_rebuild()
+ErasureRequestResponse.model_rebuild()
+ErrorModel.model_rebuild()
+EventFilterOption.model_rebuild()
+EventFilterOptionsResponse.model_rebuild()
+EventRead.model_rebuild()
+EventResponse.model_rebuild()
+EventTypeBindingDTO.model_rebuild()
+EventTypeItem.model_rebuild()
+EventTypeListResponse.model_rebuild()
+EventTypeResponse.model_rebuild()
+ExpireSigningKeyRequest.model_rebuild()
+FireNowRequest.model_rebuild()
+FireNowResponse.model_rebuild()
+GrantAccessRequest.model_rebuild()
+GrantClientAccessRequest.model_rebuild()
+GrantPermissionRequest.model_rebuild()
+IdentityProviderListResponse.model_rebuild()
+IdentityProviderResponse.model_rebuild()
+IdpRoleMappingListResponse.model_rebuild()
+IdpRoleMappingResponse.model_rebuild()
+ImportItemResponse.model_rebuild()
+ImportResponse.model_rebuild()
+ImportSummaryResponse.model_rebuild()
+KillSwitchListResponse.model_rebuild()
+KillSwitchResponse.model_rebuild()
+ListOutputBody.model_rebuild()
+LoginAttemptListResponse.model_rebuild()
+LoginAttemptResponse.model_rebuild()
+MappingListResponse.model_rebuild()
+MappingResponse.model_rebuild()
+MetadataDTO.model_rebuild()
+NackRequest.model_rebuild()
+NoteResponse.model_rebuild()
+OAuthClientApplicationRef.model_rebuild()
+OAuthClientListResponse.model_rebuild()
+OAuthClientResponse.model_rebuild()
+OffsetPageScheduledJobInstanceResponse.model_rebuild()
+OffsetPageScheduledJobResponse.model_rebuild()
+PermissionListResponse.model_rebuild()
+PermissionResponse.model_rebuild()
+PrincipalAvailableApplication.model_rebuild()
+PrincipalAvailableApplicationsResponse.model_rebuild()
+PrincipalListResponse.model_rebuild()
+PrincipalResponse.model_rebuild()
+PrincipalRoleAssignmentDTO.model_rebuild()
+PrincipalRoleListResponse.model_rebuild()
+PrincipalVersionResponse.model_rebuild()
+ProcessListResponse.model_rebuild()
+ProcessResponse.model_rebuild()
+ProvisionLoginClientRequest.model_rebuild()
+PublicAllowedResponse.model_rebuild()
+RawDispatchJobResponse.model_rebuild()
+RawEventResponse.model_rebuild()
+ReceiptRequest.model_rebuild()
+ReconcilerStatusResponse.model_rebuild()
+RegenerateAuthTokenResponse.model_rebuild()
+RegenerateSigningSecretResponse.model_rebuild()
+RegisterBeginRequest.model_rebuild()
+RegisterBeginResponse.model_rebuild()
+RegisterCompleteRequest.model_rebuild()
+RegisterCompleteResponse.model_rebuild()
+RequestDTO.model_rebuild()
+RequestErasureRequest.model_rebuild()
+RequeueRequest.model_rebuild()
+RequeueResponse.model_rebuild()
+ResetPasswordRequest.model_rebuild()
+RoleAssignmentDTO.model_rebuild()
+RoleItem.model_rebuild()
+RoleListResponse.model_rebuild()
+RolePermissionListResponse.model_rebuild()
+RoleResponse.model_rebuild()
+RolesAssignedResponse.model_rebuild()
+RotateOAuthClientSecretResponse.model_rebuild()
+RotateSigningKeyRequest.model_rebuild()
+ScheduledJobInstanceLogResponse.model_rebuild()
+ScheduledJobInstanceResponse.model_rebuild()
+ScheduledJobResponse.model_rebuild()
+SchemaItem.model_rebuild()
+SearchClientRequest.model_rebuild()
+SendPasswordResetInputBody.model_rebuild()
+ServiceAccountListResponse.model_rebuild()
+ServiceAccountOAuthSecrets.model_rebuild()
+ServiceAccountResponse.model_rebuild()
+ServiceAccountRoleListResponse.model_rebuild()
+ServiceAccountRolesAssignedResponse.model_rebuild()
+ServiceAccountWebhookSecrets.model_rebuild()
+SetApplicationAccessResponse.model_rebuild()
+SetDeveloperCredentialResponse.model_rebuild()
+SetPropertyRequest.model_rebuild()
+SigningKeyCreatedResponse.model_rebuild()
+SigningKeyListResponse.model_rebuild()
+SigningKeyResponse.model_rebuild()
+SpecSourceResponse.model_rebuild()
+SpecVersionResponse.model_rebuild()
+StatusChangeRequest.model_rebuild()
+StatusChangeResponse.model_rebuild()
+StreamDLQEntryResponse.model_rebuild()
+StreamDLQListResponse.model_rebuild()
+SubscriptionItem.model_rebuild()
+SubscriptionListResponse.model_rebuild()
+SubscriptionResponse.model_rebuild()
+SuccessResponse.model_rebuild()
+SuspendClientRequest.model_rebuild()
+SyncDispatchPoolInputRequest.model_rebuild()
+SyncDispatchPoolsRequest.model_rebuild()
+SyncEventTypeInputRequest.model_rebuild()
+SyncEventTypesRequest.model_rebuild()
+SyncOpenApiSpecResponse.model_rebuild()
+SyncOpenapiRequest.model_rebuild()
+SyncPrincipalInputRequest.model_rebuild()
+SyncPrincipalsRequest.model_rebuild()
+SyncProcessInputRequest.model_rebuild()
+SyncProcessesByBodyRequest.model_rebuild()
+SyncProcessesRequest.model_rebuild()
+SyncResultResponse.model_rebuild()
+SyncRoleInputRequest.model_rebuild()
+SyncRolesRequest.model_rebuild()
+SyncScheduledJobInputRequest.model_rebuild()
+SyncScheduledJobsRequest.model_rebuild()
+SyncScheduledJobsResultResponse.model_rebuild()
+SyncSubscriptionEventTypeRequest.model_rebuild()
+SyncSubscriptionInputRequest.model_rebuild()
+SyncSubscriptionsRequest.model_rebuild()
+SyncUserInput.model_rebuild()
+SyncUsersRequest.model_rebuild()
+SyncUsersResponse.model_rebuild()
+UpdateAnchorDomainRequest.model_rebuild()
+UpdateAnomalySettingRequest.model_rebuild()
+UpdateApplicationRequest.model_rebuild()
+UpdateAuthConfigRequest.model_rebuild()
+UpdateClientApplicationsRequest.model_rebuild()
+UpdateClientRequest.model_rebuild()
+UpdateConnectionRequest.model_rebuild()
+UpdateDataPolicyRequest.model_rebuild()
+UpdateDispatchPoolRequest.model_rebuild()
+UpdateEventTypeRequest.model_rebuild()
+UpdateIdentityProviderRequest.model_rebuild()
+UpdateMappingRequest.model_rebuild()
+UpdateOAuthClientRequest.model_rebuild()
+UpdatePrincipalRequest.model_rebuild()
+UpdateProcessRequest.model_rebuild()
+UpdateRoleRequest.model_rebuild()
+UpdateScheduledJobRequest.model_rebuild()
+UpdateServiceAccountRequest.model_rebuild()
+UpdateSubscriptionRequest.model_rebuild()
+UpsertDispatchPoolRequest.model_rebuild()
+UpsertEventTypeRequest.model_rebuild()
+UpsertServiceAccountRequest.model_rebuild()
+UpsertServiceAccountResponse.model_rebuild()
+UpsertSubscriptionRequest.model_rebuild()
+WebauthnAuthenticateCompleteResponse.model_rebuild()
+WebauthnCredentialSummary.model_rebuild()
+WebhookCredentialsDTO.model_rebuild()
+WriteInstanceLogRequest.model_rebuild()
@@ -0,0 +1,1071 @@
+# Code generated by scripts/generate.py from openapi/openapi.json. DO NOT EDIT.
+
+"""One method per platform API operation, named after its operationId."""
+
+from __future__ import annotations
+
+from datetime import datetime  # noqa: F401 (used in annotations)
+from typing import Any
+from urllib.parse import quote
+
+from . import models as m
+
+
+def _path(value: Any) -> str:
+    return quote(str(value), safe="")
+
+
+class Operations:
+    """Typed wrappers over _request, mixed into FlowCatalystClient."""
+
+    def _request(self, method: str, path: str, *, params: dict[str, Any] | None = None,
+                 headers: dict[str, Any] | None = None, json: Any = None, response: Any = None) -> Any:
+        raise NotImplementedError
+
+    def export_configuration(self) -> m.Bundle:
+        """Export platform configuration as a bundle"""
+        return self._request('GET', '/api/admin/export', response=m.Bundle)
+
+    def import_configuration(self, body: m.Bundle | dict[str, Any], *, dry_run: bool | None = None, on_conflict: str | None = None) -> m.ImportResponse:
+        """Import a configuration bundle"""
+        return self._request('POST', '/api/admin/import', params={'dryRun': dry_run, 'onConflict': on_conflict}, json=body, response=m.ImportResponse)
+
+    def list_erasure_requests(self, *, limit: int | None = None) -> m.ErasureRequestListResponse:
+        """List recent erasure requests"""
+        return self._request('GET', '/api/admin/privacy/erasure', params={'limit': limit}, response=m.ErasureRequestListResponse)
+
+    def request_erasure(self, body: m.RequestErasureRequest | dict[str, Any]) -> m.ErasureRequestResponse:
+        """Request erasure of a data subject"""
+        return self._request('POST', '/api/admin/privacy/erasure', json=body, response=m.ErasureRequestResponse)
+
+    def get_erasure_request(self, id: str) -> m.ErasureRequestResponse:
+        """Get an erasure request and its report"""
+        return self._request('GET', f"/api/admin/privacy/erasure/{_path(id)}", response=m.ErasureRequestResponse)
+
+    def get_reconciler_status(self) -> m.ReconcilerStatusResponse:
+        """Get the configuration reconciler's last pass and drift"""
+        return self._request('GET', '/api/admin/reconciler', response=m.ReconcilerStatusResponse)
+
+    def run_reconciler(self) -> m.ReconcilerStatusResponse:
+        """Run a configuration reconcile pass now"""
+        return self._request('POST', '/api/admin/reconciler/run', response=m.ReconcilerStatusResponse)
+
+    def list_stream_dlq(self, *, limit: int | None = None) -> m.StreamDLQListResponse:
+        """List events the stream fan-out dead-lettered"""
+        return self._request('GET', '/api/admin/stream-dlq', params={'limit': limit}, response=m.StreamDLQListResponse)
+
+    def discard_stream_dlq_entry(self, id: str) -> None:
+        """Discard a dead-lettered event"""
+        return self._request('DELETE', f"/api/admin/stream-dlq/{_path(id)}")
+
+    def reprocess_stream_dlq_entry(self, id: str) -> None:
+        """Return a dead-lettered event to fan-out"""
+        return self._request('POST', f"/api/admin/stream-dlq/{_path(id)}/reprocess")
+
+    def list_anchor_domains(self) -> m.AnchorDomainListResponse:
+        """List anchor domains"""
+        return self._request('GET', '/api/anchor-domains', response=m.AnchorDomainListResponse)
+
+    def create_anchor_domain(self, body: m.CreateAnchorDomainRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create an anchor domain"""
+        return self._request('POST', '/api/anchor-domains', json=body, response=m.CreatedResponse)
+
+    def delete_anchor_domain(self, id: str) -> None:
+        """Delete an anchor domain"""
+        return self._request('DELETE', f"/api/anchor-domains/{_path(id)}")
+
+    def update_anchor_domain(self, id: str, body: m.UpdateAnchorDomainRequest | dict[str, Any]) -> None:
+        """Update an anchor domain"""
+        return self._request('PUT', f"/api/anchor-domains/{_path(id)}", json=body)
+
+    def list_anomalies(self, *, status: str | None = None, limit: int | None = None) -> m.AnomalyListResponse:
+        """List ingestion and delivery anomalies"""
+        return self._request('GET', '/api/anomalies', params={'status': status, 'limit': limit}, response=m.AnomalyListResponse)
+
+    def list_anomaly_settings(self) -> m.AnomalySettingListResponse:
+        """List per-entity anomaly detection settings"""
+        return self._request('GET', '/api/anomaly-settings', response=m.AnomalySettingListResponse)
+
+    def update_anomaly_setting(self, entity_kind: str, entity_key: str, body: m.UpdateAnomalySettingRequest | dict[str, Any]) -> m.AnomalySettingResponse:
+        """Set an event type's or subscription's anomaly sensitivity or snooze"""
+        return self._request('PUT', f"/api/anomaly-settings/{_path(entity_kind)}/{_path(entity_key)}", json=body, response=m.AnomalySettingResponse)
+
+    def list_applications(self, *, type: str | None = None, active: str | None = None) -> m.ApplicationListResponse:
+        """List applications"""
+        return self._request('GET', '/api/applications', params={'type': type, 'active': active}, response=m.ApplicationListResponse)
+
+    def create_application(self, body: m.CreateApplicationRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create an application"""
+        return self._request('POST', '/api/applications', json=body, response=m.CreatedResponse)
+
+    def get_application_by_code(self, code: str) -> m.ApplicationResponse:
+        """Get an application by code"""
+        return self._request('GET', f"/api/applications/by-code/{_path(code)}", response=m.ApplicationResponse)
+
+    def list_application_roles(self, id: str) -> m.ApplicationRolesResponse:
+        """List roles registered against an application"""
+        return self._request('GET', f"/api/applications/by-id/{_path(id)}/roles", response=m.ApplicationRolesResponse)
+
+    def sync_dispatch_pools(self, app_code: str, body: m.SyncDispatchPoolsRequest | dict[str, Any], *, remove_unlisted: bool | None = None) -> m.SyncResultResponse:
+        """Sync dispatch pools (SDK self-registration)"""
+        return self._request('POST', f"/api/applications/{_path(app_code)}/dispatch-pools/sync", params={'removeUnlisted': remove_unlisted}, json=body, response=m.SyncResultResponse)
+
+    def sync_event_types(self, app_code: str, body: m.SyncEventTypesRequest | dict[str, Any], *, remove_unlisted: bool | None = None) -> m.SyncResultResponse:
+        """Sync an application's event types (SDK self-registration)"""
+        return self._request('POST', f"/api/applications/{_path(app_code)}/event-types/sync", params={'removeUnlisted': remove_unlisted}, json=body, response=m.SyncResultResponse)
+
+    def sync_openapi(self, app_code: str, body: m.SyncOpenapiRequest | dict[str, Any]) -> m.SyncOpenApiSpecResponse:
+        """Sync an application's OpenAPI document (SDK self-registration)"""
+        return self._request('POST', f"/api/applications/{_path(app_code)}/openapi/sync", json=body, response=m.SyncOpenApiSpecResponse)
+
+    def sync_principals(self, app_code: str, body: m.SyncPrincipalsRequest | dict[str, Any], *, remove_unlisted: bool | None = None) -> m.SyncResultResponse:
+        """Sync an application's principals (SDK self-registration)"""
+        return self._request('POST', f"/api/applications/{_path(app_code)}/principals/sync", params={'removeUnlisted': remove_unlisted}, json=body, response=m.SyncResultResponse)
+
+    def sync_processes(self, app_code: str, body: m.SyncProcessesRequest | dict[str, Any], *, remove_unlisted: bool | None = None) -> m.SyncResultResponse:
+        """Sync an application's processes (SDK self-registration)"""
+        return self._request('POST', f"/api/applications/{_path(app_code)}/processes/sync", params={'removeUnlisted': remove_unlisted}, json=body, response=m.SyncResultResponse)
+
+    def sync_roles(self, app_code: str, body: m.SyncRolesRequest | dict[str, Any], *, remove_unlisted: bool | None = None) -> m.SyncResultResponse:
+        """Sync an application's roles (SDK self-registration)"""
+        return self._request('POST', f"/api/applications/{_path(app_code)}/roles/sync", params={'removeUnlisted': remove_unlisted}, json=body, response=m.SyncResultResponse)
+
+    def sync_scheduled_jobs(self, app_code: str, body: m.SyncScheduledJobsRequest | dict[str, Any]) -> m.SyncScheduledJobsResultResponse:
+        """Sync scheduled jobs (SDK self-registration)"""
+        return self._request('POST', f"/api/applications/{_path(app_code)}/scheduled-jobs/sync", json=body, response=m.SyncScheduledJobsResultResponse)
+
+    def sync_subscriptions(self, app_code: str, body: m.SyncSubscriptionsRequest | dict[str, Any], *, remove_unlisted: bool | None = None) -> m.SyncResultResponse:
+        """Sync an application's subscriptions (SDK self-registration)"""
+        return self._request('POST', f"/api/applications/{_path(app_code)}/subscriptions/sync", params={'removeUnlisted': remove_unlisted}, json=body, response=m.SyncResultResponse)
+
+    def delete_application(self, id: str) -> None:
+        """Delete an application"""
+        return self._request('DELETE', f"/api/applications/{_path(id)}")
+
+    def get_application(self, id: str) -> m.ApplicationResponse:
+        """Get an application by id"""
+        return self._request('GET', f"/api/applications/{_path(id)}", response=m.ApplicationResponse)
+
+    def update_application(self, id: str, body: m.UpdateApplicationRequest | dict[str, Any]) -> None:
+        """Update an application"""
+        return self._request('PUT', f"/api/applications/{_path(id)}", json=body)
+
+    def activate_application(self, id: str) -> m.ApplicationResponse:
+        """Activate an application"""
+        return self._request('POST', f"/api/applications/{_path(id)}/activate", response=m.ApplicationResponse)
+
+    def list_application_client_configs(self, id: str) -> m.ClientConfigListResponse:
+        """List per-client configurations for an application"""
+        return self._request('GET', f"/api/applications/{_path(id)}/clients", response=m.ClientConfigListResponse)
+
+    def get_application_client_config(self, id: str, client_id: str) -> m.ClientConfigResponse:
+        """Get a single application-client config"""
+        return self._request('GET', f"/api/applications/{_path(id)}/clients/{_path(client_id)}", response=m.ClientConfigResponse)
+
+    def disable_application_for_client(self, id: str, client_id: str) -> None:
+        """Disable an application for a client"""
+        return self._request('POST', f"/api/applications/{_path(id)}/clients/{_path(client_id)}/disable")
+
+    def enable_application_for_client(self, id: str, client_id: str) -> None:
+        """Enable an application for a client"""
+        return self._request('POST', f"/api/applications/{_path(id)}/clients/{_path(client_id)}/enable")
+
+    def deactivate_application(self, id: str) -> m.ApplicationResponse:
+        """Deactivate an application"""
+        return self._request('POST', f"/api/applications/{_path(id)}/deactivate", response=m.ApplicationResponse)
+
+    def provision_application_login_client(self, id: str, body: m.ProvisionLoginClientRequest | dict[str, Any]) -> m.ApplicationProvisionLoginClientResponse:
+        """Create a public OAuth login client for the application"""
+        return self._request('POST', f"/api/applications/{_path(id)}/provision-login-client", json=body, response=m.ApplicationProvisionLoginClientResponse)
+
+    def provision_application_service_account(self, id: str) -> m.ApplicationProvisionServiceAccountResponse:
+        """Create + attach a dedicated service account for the application"""
+        return self._request('POST', f"/api/applications/{_path(id)}/provision-service-account", response=m.ApplicationProvisionServiceAccountResponse)
+
+    def attach_application_service_account(self, id: str, body: m.AttachServiceAccountRequest | dict[str, Any]) -> None:
+        """Attach a service account to an application"""
+        return self._request('POST', f"/api/applications/{_path(id)}/service-account", json=body)
+
+    def list_audit_logs(self, *, after: str | None = None, page_size: int | None = None, entity_type: str | None = None, entity_id: str | None = None, principal_id: str | None = None, operation: str | None = None, application_ids: str | None = None, client_ids: str | None = None) -> m.AuditLogListResponse:
+        """List audit logs with filters"""
+        return self._request('GET', '/api/audit-logs', params={'after': after, 'pageSize': page_size, 'entityType': entity_type, 'entityId': entity_id, 'principalId': principal_id, 'operation': operation, 'applicationIds': application_ids, 'clientIds': client_ids}, response=m.AuditLogListResponse)
+
+    def audit_log_application_i_ds(self) -> m.AuditLogApplicationIDsResponse:
+        """Distinct application ids"""
+        return self._request('GET', '/api/audit-logs/application-ids', response=m.AuditLogApplicationIDsResponse)
+
+    def audit_log_client_i_ds(self) -> m.AuditLogClientIDsResponse:
+        """Distinct client ids"""
+        return self._request('GET', '/api/audit-logs/client-ids', response=m.AuditLogClientIDsResponse)
+
+    def audit_log_entity_types(self) -> m.AuditLogEntityTypesResponse:
+        """Distinct entity types"""
+        return self._request('GET', '/api/audit-logs/entity-types', response=m.AuditLogEntityTypesResponse)
+
+    def audit_logs_by_entity(self, entity_type: str, entity_id: str) -> m.AuditLogListResponse:
+        """Audit logs for a specific entity"""
+        return self._request('GET', f"/api/audit-logs/entity/{_path(entity_type)}/{_path(entity_id)}", response=m.AuditLogListResponse)
+
+    def audit_log_operations(self) -> m.AuditLogOperationsResponse:
+        """Distinct operations"""
+        return self._request('GET', '/api/audit-logs/operations', response=m.AuditLogOperationsResponse)
+
+    def audit_logs_by_principal(self, principal_id: str) -> m.AuditLogListResponse:
+        """Audit logs for a specific principal"""
+        return self._request('GET', f"/api/audit-logs/principal/{_path(principal_id)}", response=m.AuditLogListResponse)
+
+    def list_audit_logs_recent(self, *, after: str | None = None, page_size: int | None = None, entity_type: str | None = None, entity_id: str | None = None, principal_id: str | None = None, operation: str | None = None, application_ids: str | None = None, client_ids: str | None = None) -> m.AuditLogListResponse:
+        """List recent audit logs (alias for list)"""
+        return self._request('GET', '/api/audit-logs/recent', params={'after': after, 'pageSize': page_size, 'entityType': entity_type, 'entityId': entity_id, 'principalId': principal_id, 'operation': operation, 'applicationIds': application_ids, 'clientIds': client_ids}, response=m.AuditLogListResponse)
+
+    def get_audit_log(self, id: str) -> m.AuditLogResponse:
+        """Get an audit log by id"""
+        return self._request('GET', f"/api/audit-logs/{_path(id)}", response=m.AuditLogResponse)
+
+    def list_auth_configs(self) -> m.AuthConfigListResponse:
+        """List client auth configs"""
+        return self._request('GET', '/api/auth-configs', response=m.AuthConfigListResponse)
+
+    def create_auth_config(self, body: m.CreateAuthConfigRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create a client auth config"""
+        return self._request('POST', '/api/auth-configs', json=body, response=m.CreatedResponse)
+
+    def delete_auth_config(self, id: str) -> None:
+        """Delete a client auth config"""
+        return self._request('DELETE', f"/api/auth-configs/{_path(id)}")
+
+    def update_auth_config(self, id: str, body: m.UpdateAuthConfigRequest | dict[str, Any]) -> None:
+        """Update a client auth config"""
+        return self._request('PUT', f"/api/auth-configs/{_path(id)}", json=body)
+
+    def list_clients(self) -> m.ClientListResponse:
+        """List clients"""
+        return self._request('GET', '/api/clients', response=m.ClientListResponse)
+
+    def create_client(self, body: m.CreateClientRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create a client"""
+        return self._request('POST', '/api/clients', json=body, response=m.CreatedResponse)
+
+    def get_client_by_identifier(self, identifier: str) -> m.ClientResponse:
+        """Get a client by identifier"""
+        return self._request('GET', f"/api/clients/by-identifier/{_path(identifier)}", response=m.ClientResponse)
+
+    def search_clients_by_query(self, *, q: str | None = None) -> m.ClientListResponse:
+        """Search clients (SDK alias; ?q=<term>)"""
+        return self._request('GET', '/api/clients/search', params={'q': q}, response=m.ClientListResponse)
+
+    def search_clients(self, body: m.SearchClientRequest | dict[str, Any]) -> m.ClientListResponse:
+        """Search clients"""
+        return self._request('POST', '/api/clients/search', json=body, response=m.ClientListResponse)
+
+    def delete_client(self, id: str) -> None:
+        """Delete a client"""
+        return self._request('DELETE', f"/api/clients/{_path(id)}")
+
+    def get_client(self, id: str) -> m.ClientResponse:
+        """Get a client by id"""
+        return self._request('GET', f"/api/clients/{_path(id)}", response=m.ClientResponse)
+
+    def update_client(self, id: str, body: m.UpdateClientRequest | dict[str, Any]) -> None:
+        """Update a client"""
+        return self._request('PUT', f"/api/clients/{_path(id)}", json=body)
+
+    def activate_client(self, id: str) -> m.StatusChangeResponse:
+        """Activate a client"""
+        return self._request('POST', f"/api/clients/{_path(id)}/activate", response=m.StatusChangeResponse)
+
+    def get_client_applications(self, id: str) -> m.ClientApplicationsResponse:
+        """List applications and their enabled state for the client"""
+        return self._request('GET', f"/api/clients/{_path(id)}/applications", response=m.ClientApplicationsResponse)
+
+    def update_client_applications(self, id: str, body: m.UpdateClientApplicationsRequest | dict[str, Any]) -> None:
+        """Replace the client's enabled applications (bulk)"""
+        return self._request('PUT', f"/api/clients/{_path(id)}/applications", json=body)
+
+    def disable_client_application(self, id: str, application_id: str) -> None:
+        """Disable an application for the client"""
+        return self._request('POST', f"/api/clients/{_path(id)}/applications/{_path(application_id)}/disable")
+
+    def enable_client_application(self, id: str, application_id: str) -> None:
+        """Enable an application for the client"""
+        return self._request('POST', f"/api/clients/{_path(id)}/applications/{_path(application_id)}/enable")
+
+    def deactivate_client(self, id: str, body: m.StatusChangeRequest | dict[str, Any]) -> m.StatusChangeResponse:
+        """Deactivate a client (soft delete)"""
+        return self._request('POST', f"/api/clients/{_path(id)}/deactivate", json=body, response=m.StatusChangeResponse)
+
+    def add_client_note(self, id: str, body: m.AddNoteRequest | dict[str, Any]) -> m.StatusChangeResponse:
+        """Add a note to a client"""
+        return self._request('POST', f"/api/clients/{_path(id)}/notes", json=body, response=m.StatusChangeResponse)
+
+    def list_client_signing_keys(self, id: str) -> m.SigningKeyListResponse:
+        """List a client's webhook signing keys"""
+        return self._request('GET', f"/api/clients/{_path(id)}/signing-keys", response=m.SigningKeyListResponse)
+
+    def rotate_client_signing_key(self, id: str, body: m.RotateSigningKeyRequest | dict[str, Any] | None = None) -> m.SigningKeyCreatedResponse:
+        """Add a signing key, retiring the current ones after an overlap"""
+        return self._request('POST', f"/api/clients/{_path(id)}/signing-keys", json=body, response=m.SigningKeyCreatedResponse)
+
+    def expire_client_signing_key(self, id: str, signing_key_id: str, body: m.ExpireSigningKeyRequest | dict[str, Any] | None = None) -> m.SigningKeyResponse:
+        """Expire a signing key"""
+        return self._request('POST', f"/api/clients/{_path(id)}/signing-keys/{_path(signing_key_id)}/expire", json=body, response=m.SigningKeyResponse)
+
+    def suspend_client(self, id: str, body: m.SuspendClientRequest | dict[str, Any]) -> m.StatusChangeResponse:
+        """Suspend a client"""
+        return self._request('POST', f"/api/clients/{_path(id)}/suspend", json=body, response=m.StatusChangeResponse)
+
+    def delete_platform_config_property(self, app: str, section: str, property: str, *, client_id: str | None = None) -> None:
+        """Delete a platform-config property"""
+        return self._request('DELETE', f"/api/config/{_path(app)}/{_path(section)}/{_path(property)}", params={'clientId': client_id})
+
+    def get_platform_config_property(self, app: str, section: str, property: str, *, client_id: str | None = None) -> m.ConfigResponse:
+        """Get a single platform-config property"""
+        return self._request('GET', f"/api/config/{_path(app)}/{_path(section)}/{_path(property)}", params={'clientId': client_id}, response=m.ConfigResponse)
+
+    def set_platform_config_property(self, app: str, section: str, property: str, body: m.SetPropertyRequest | dict[str, Any], *, client_id: str | None = None) -> m.ConfigResponse:
+        """Set a platform-config property"""
+        return self._request('PUT', f"/api/config/{_path(app)}/{_path(section)}/{_path(property)}", params={'clientId': client_id}, json=body, response=m.ConfigResponse)
+
+    def list_connections(self, *, status: str | None = None, client_id: str | None = None) -> m.ConnectionListResponse:
+        """List connections"""
+        return self._request('GET', '/api/connections', params={'status': status, 'clientId': client_id}, response=m.ConnectionListResponse)
+
+    def create_connection(self, body: m.CreateConnectionRequest | dict[str, Any]) -> m.ConnectionResponse:
+        """Create a connection"""
+        return self._request('POST', '/api/connections', json=body, response=m.ConnectionResponse)
+
+    def delete_connection(self, id: str) -> None:
+        """Delete a connection"""
+        return self._request('DELETE', f"/api/connections/{_path(id)}")
+
+    def get_connection(self, id: str) -> m.ConnectionResponse:
+        """Get a connection by id"""
+        return self._request('GET', f"/api/connections/{_path(id)}", response=m.ConnectionResponse)
+
+    def update_connection(self, id: str, body: m.UpdateConnectionRequest | dict[str, Any]) -> None:
+        """Update a connection"""
+        return self._request('PUT', f"/api/connections/{_path(id)}", json=body)
+
+    def activate_connection(self, id: str) -> m.ConnectionResponse:
+        """Activate a connection"""
+        return self._request('POST', f"/api/connections/{_path(id)}/activate", response=m.ConnectionResponse)
+
+    def pause_connection(self, id: str) -> m.ConnectionResponse:
+        """Pause a connection"""
+        return self._request('POST', f"/api/connections/{_path(id)}/pause", response=m.ConnectionResponse)
+
+    def consume_subscription(self, subscription_id: str, *, wait: str | None = None, max: int | None = None, lease: str | None = None) -> m.ConsumeResponse:
+        """Lease a pull subscription's due messages, waiting for one if asked"""
+        return self._request('GET', f"/api/consume/{_path(subscription_id)}", params={'wait': wait, 'max': max, 'lease': lease}, response=m.ConsumeResponse)
+
+    def ack_consumed_message(self, subscription_id: str, body: m.ConsumeAckRequest | dict[str, Any]) -> m.AckResponse:
+        """Acknowledge a leased message"""
+        return self._request('POST', f"/api/consume/{_path(subscription_id)}/ack", json=body, response=m.AckResponse)
+
+    def nack_consumed_message(self, subscription_id: str, body: m.ConsumeNackRequest | dict[str, Any]) -> m.AckResponse:
+        """Reject a leased message; it is retried like a failed delivery"""
+        return self._request('POST', f"/api/consume/{_path(subscription_id)}/nack", json=body, response=m.AckResponse)
+
+    def list_dispatch_jobs(self, *, status: str | None = None, client_id: str | None = None, dispatch_pool_id: str | None = None, subscription_id: str | None = None, code: str | None = None, since: str | None = None, until: str | None = None, limit: int | None = None, offset: int | None = None, size: int | None = None, client_ids: str | None = None, statuses: str | None = None, applications: str | None = None, subdomains: str | None = None, aggregates: str | None = None, codes: str | None = None, source: str | None = None, after: str | None = None, pagination: str | None = None) -> list[m.DispatchJobRead]:
+        """List dispatch jobs with filters"""
+        return self._request('GET', '/api/dispatch-jobs', params={'status': status, 'clientId': client_id, 'dispatchPoolId': dispatch_pool_id, 'subscriptionId': subscription_id, 'code': code, 'since': since, 'until': until, 'limit': limit, 'offset': offset, 'size': size, 'clientIds': client_ids, 'statuses': statuses, 'applications': applications, 'subdomains': subdomains, 'aggregates': aggregates, 'codes': codes, 'source': source, 'after': after, 'pagination': pagination}, response=list[m.DispatchJobRead])
+
+    def dispatch_jobs_by_event_alias(self, event_id: str) -> list[m.DispatchJobRead]:
+        """Dispatch jobs spawned by an event (SDK alias of /event/{eventId})"""
+        return self._request('GET', f"/api/dispatch-jobs/by-event/{_path(event_id)}", response=list[m.DispatchJobRead])
+
+    def cancel_dispatch_jobs(self, body: m.CancelRequest | dict[str, Any]) -> m.CancelResponse:
+        """Cancel dispatch jobs, including copies already queued"""
+        return self._request('POST', '/api/dispatch-jobs/cancel', json=body, response=m.CancelResponse)
+
+    def dispatch_jobs_by_event(self, event_id: str) -> list[m.DispatchJobRead]:
+        """Dispatch jobs spawned by a specific event"""
+        return self._request('GET', f"/api/dispatch-jobs/event/{_path(event_id)}", response=list[m.DispatchJobRead])
+
+    def dispatch_job_filter_options(self) -> m.DispatchJobFilterOptionsResponse:
+        """Distinct facet values for dispatch jobs"""
+        return self._request('GET', '/api/dispatch-jobs/filter-options', response=m.DispatchJobFilterOptionsResponse)
+
+    def list_dispatch_jobs_raw(self, *, status: str | None = None, client_id: str | None = None, dispatch_pool_id: str | None = None, subscription_id: str | None = None, code: str | None = None, since: str | None = None, until: str | None = None, limit: int | None = None, offset: int | None = None, size: int | None = None, client_ids: str | None = None, statuses: str | None = None, applications: str | None = None, subdomains: str | None = None, aggregates: str | None = None, codes: str | None = None, source: str | None = None, after: str | None = None, pagination: str | None = None) -> list[m.DispatchJobRead]:
+        """List dispatch jobs (raw)"""
+        return self._request('GET', '/api/dispatch-jobs/list-raw', params={'status': status, 'clientId': client_id, 'dispatchPoolId': dispatch_pool_id, 'subscriptionId': subscription_id, 'code': code, 'since': since, 'until': until, 'limit': limit, 'offset': offset, 'size': size, 'clientIds': client_ids, 'statuses': statuses, 'applications': applications, 'subdomains': subdomains, 'aggregates': aggregates, 'codes': codes, 'source': source, 'after': after, 'pagination': pagination}, response=list[m.DispatchJobRead])
+
+    def list_dispatch_jobs_raw_alias(self, *, status: str | None = None, client_id: str | None = None, dispatch_pool_id: str | None = None, subscription_id: str | None = None, code: str | None = None, since: str | None = None, until: str | None = None, limit: int | None = None, offset: int | None = None, size: int | None = None, client_ids: str | None = None, statuses: str | None = None, applications: str | None = None, subdomains: str | None = None, aggregates: str | None = None, codes: str | None = None, source: str | None = None, after: str | None = None, pagination: str | None = None) -> list[m.DispatchJobRead]:
+        """List dispatch jobs raw (SDK alias of /list-raw)"""
+        return self._request('GET', '/api/dispatch-jobs/raw', params={'status': status, 'clientId': client_id, 'dispatchPoolId': dispatch_pool_id, 'subscriptionId': subscription_id, 'code': code, 'since': since, 'until': until, 'limit': limit, 'offset': offset, 'size': size, 'clientIds': client_ids, 'statuses': statuses, 'applications': applications, 'subdomains': subdomains, 'aggregates': aggregates, 'codes': codes, 'source': source, 'after': after, 'pagination': pagination}, response=list[m.DispatchJobRead])
+
+    def requeue_dispatch_jobs(self, body: m.RequeueRequest | dict[str, Any]) -> m.RequeueResponse:
+        """Reset dispatch jobs to PENDING for re-dispatch"""
+        return self._request('POST', '/api/dispatch-jobs/requeue', json=body, response=m.RequeueResponse)
+
+    def get_dispatch_job(self, id: str) -> m.DispatchJobResponse:
+        """Get a dispatch job by id"""
+        return self._request('GET', f"/api/dispatch-jobs/{_path(id)}", response=m.DispatchJobResponse)
+
+    def ack_dispatch_job(self, id: str, body: m.AckRequest | dict[str, Any]) -> m.AckResponse:
+        """Acknowledge a delivery accepted for asynchronous processing"""
+        return self._request('POST', f"/api/dispatch-jobs/{_path(id)}/ack", json=body, response=m.AckResponse)
+
+    def list_dispatch_job_attempts(self, id: str) -> list[m.AttemptDTO]:
+        """List a dispatch job's attempt history"""
+        return self._request('GET', f"/api/dispatch-jobs/{_path(id)}/attempts", response=list[m.AttemptDTO])
+
+    def nack_dispatch_job(self, id: str, body: m.NackRequest | dict[str, Any]) -> m.AckResponse:
+        """Reject a delivery accepted for asynchronous processing"""
+        return self._request('POST', f"/api/dispatch-jobs/{_path(id)}/nack", json=body, response=m.AckResponse)
+
+    def get_dispatch_job_raw(self, id: str) -> m.DispatchJobResponse:
+        """Get a dispatch job (raw)"""
+        return self._request('GET', f"/api/dispatch-jobs/{_path(id)}/raw", response=m.DispatchJobResponse)
+
+    def list_dispatch_pools(self, *, status: str | None = None, client_id: str | None = None) -> m.DispatchPoolListResponse:
+        """List dispatch pools"""
+        return self._request('GET', '/api/dispatch-pools', params={'status': status, 'clientId': client_id}, response=m.DispatchPoolListResponse)
+
+    def create_dispatch_pool(self, body: m.CreateDispatchPoolRequest | dict[str, Any], *, idempotency_key: str | None = None) -> m.CreatedResponse:
+        """Create a dispatch pool"""
+        return self._request('POST', '/api/dispatch-pools', headers={'Idempotency-Key': idempotency_key}, json=body, response=m.CreatedResponse)
+
+    def upsert_dispatch_pool(self, code: str, body: m.UpsertDispatchPoolRequest | dict[str, Any], *, client_id: str | None = None, if_match: str | None = None, if_none_match: str | None = None) -> m.DispatchPoolResponse:
+        """Create or update a dispatch pool by code"""
+        return self._request('PUT', f"/api/dispatch-pools/by-code/{_path(code)}", params={'clientId': client_id}, headers={'If-Match': if_match, 'If-None-Match': if_none_match}, json=body, response=m.DispatchPoolResponse)
+
+    def delete_dispatch_pool(self, id: str, *, if_match: str | None = None) -> None:
+        """Delete a dispatch pool"""
+        return self._request('DELETE', f"/api/dispatch-pools/{_path(id)}", headers={'If-Match': if_match})
+
+    def get_dispatch_pool(self, id: str) -> m.DispatchPoolResponse:
+        """Get a dispatch pool by id"""
+        return self._request('GET', f"/api/dispatch-pools/{_path(id)}", response=m.DispatchPoolResponse)
+
+    def update_dispatch_pool(self, id: str, body: m.UpdateDispatchPoolRequest | dict[str, Any], *, if_match: str | None = None) -> None:
+        """Update a dispatch pool"""
+        return self._request('PUT', f"/api/dispatch-pools/{_path(id)}", headers={'If-Match': if_match}, json=body)
+
+    def activate_dispatch_pool(self, id: str) -> None:
+        """Resume a suspended dispatch pool"""
+        return self._request('POST', f"/api/dispatch-pools/{_path(id)}/activate")
+
+    def archive_dispatch_pool(self, id: str) -> None:
+        """Archive a dispatch pool"""
+        return self._request('POST', f"/api/dispatch-pools/{_path(id)}/archive")
+
+    def suspend_dispatch_pool(self, id: str) -> None:
+        """Suspend dispatch into a pool"""
+        return self._request('POST', f"/api/dispatch-pools/{_path(id)}/suspend")
+
+    def list_email_domain_mappings(self) -> m.MappingListResponse:
+        """List email-domain mappings"""
+        return self._request('GET', '/api/email-domain-mappings', response=m.MappingListResponse)
+
+    def create_email_domain_mapping(self, body: m.CreateMappingRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create an email-domain mapping"""
+        return self._request('POST', '/api/email-domain-mappings', json=body, response=m.CreatedResponse)
+
+    def get_email_domain_mapping_by_domain(self, domain: str) -> m.MappingResponse:
+        """Resolve an email domain to its mapping (path param)"""
+        return self._request('GET', f"/api/email-domain-mappings/by-domain/{_path(domain)}", response=m.MappingResponse)
+
+    def lookup_email_domain_mapping(self, *, domain: str | None = None) -> None:
+        """Resolve an email domain to its mapping"""
+        return self._request('GET', '/api/email-domain-mappings/lookup', params={'domain': domain})
+
+    def delete_email_domain_mapping(self, id: str) -> None:
+        """Delete an email-domain mapping"""
+        return self._request('DELETE', f"/api/email-domain-mappings/{_path(id)}")
+
+    def get_email_domain_mapping(self, id: str) -> m.MappingResponse:
+        """Get an email-domain mapping by id"""
+        return self._request('GET', f"/api/email-domain-mappings/{_path(id)}", response=m.MappingResponse)
+
+    def update_email_domain_mapping(self, id: str, body: m.UpdateMappingRequest | dict[str, Any]) -> None:
+        """Update an email-domain mapping"""
+        return self._request('PUT', f"/api/email-domain-mappings/{_path(id)}", json=body)
+
+    def list_event_types(self, *, application: str | None = None, client_id: str | None = None, status: str | None = None, subdomain: str | None = None, aggregate: str | None = None) -> m.EventTypeListResponse:
+        """List event types"""
+        return self._request('GET', '/api/event-types', params={'application': application, 'clientId': client_id, 'status': status, 'subdomain': subdomain, 'aggregate': aggregate}, response=m.EventTypeListResponse)
+
+    def create_event_type(self, body: m.CreateEventTypeRequest | dict[str, Any], *, idempotency_key: str | None = None) -> m.CreatedResponse:
+        """Create an event type"""
+        return self._request('POST', '/api/event-types', headers={'Idempotency-Key': idempotency_key}, json=body, response=m.CreatedResponse)
+
+    def get_event_type_by_code(self, code: str) -> m.EventTypeResponse:
+        """Get an event type by code"""
+        return self._request('GET', f"/api/event-types/by-code/{_path(code)}", response=m.EventTypeResponse)
+
+    def upsert_event_type(self, code: str, body: m.UpsertEventTypeRequest | dict[str, Any], *, client_id: str | None = None, if_match: str | None = None, if_none_match: str | None = None) -> m.EventTypeResponse:
+        """Create or update an event type by code"""
+        return self._request('PUT', f"/api/event-types/by-code/{_path(code)}", params={'clientId': client_id}, headers={'If-Match': if_match, 'If-None-Match': if_none_match}, json=body, response=m.EventTypeResponse)
+
+    def delete_event_type(self, id: str, *, if_match: str | None = None) -> None:
+        """Archive an event type"""
+        return self._request('DELETE', f"/api/event-types/{_path(id)}", headers={'If-Match': if_match})
+
+    def get_event_type(self, id: str) -> m.EventTypeResponse:
+        """Get an event type by id"""
+        return self._request('GET', f"/api/event-types/{_path(id)}", response=m.EventTypeResponse)
+
+    def update_event_type(self, id: str, body: m.UpdateEventTypeRequest | dict[str, Any], *, if_match: str | None = None) -> None:
+        """Update an event type"""
+        return self._request('PUT', f"/api/event-types/{_path(id)}", headers={'If-Match': if_match}, json=body)
+
+    def update_event_type_data_policy(self, id: str, body: m.UpdateDataPolicyRequest | dict[str, Any]) -> None:
+        """Replace an event type's retention and redaction policy"""
+        return self._request('PUT', f"/api/event-types/{_path(id)}/data-policy", json=body)
+
+    def add_event_type_schema(self, id: str, body: m.AddSchemaRequest | dict[str, Any]) -> m.EventTypeResponse:
+        """Add a schema version to an event type (Go-historical alias)"""
+        return self._request('POST', f"/api/event-types/{_path(id)}/schemas", json=body, response=m.EventTypeResponse)
+
+    def add_event_type_version(self, id: str, body: m.AddSchemaRequest | dict[str, Any]) -> m.EventTypeResponse:
+        """Add a schema version to an event type"""
+        return self._request('POST', f"/api/event-types/{_path(id)}/versions", json=body, response=m.EventTypeResponse)
+
+    def list_events(self, *, type: str | None = None, source: str | None = None, subject: str | None = None, client_id: str | None = None, principal_id: str | None = None, correlation_id: str | None = None, since: str | None = None, until: str | None = None, limit: int | None = None, offset: int | None = None, size: int | None = None, client_ids: str | None = None, applications: str | None = None, subdomains: str | None = None, aggregates: str | None = None, types: str | None = None, after: str | None = None, pagination: str | None = None) -> list[m.EventRead]:
+        """List events with filters"""
+        return self._request('GET', '/api/events', params={'type': type, 'source': source, 'subject': subject, 'clientId': client_id, 'principalId': principal_id, 'correlationId': correlation_id, 'since': since, 'until': until, 'limit': limit, 'offset': offset, 'size': size, 'clientIds': client_ids, 'applications': applications, 'subdomains': subdomains, 'aggregates': aggregates, 'types': types, 'after': after, 'pagination': pagination}, response=list[m.EventRead])
+
+    def create_event(self, body: m.CreateEventRequest | dict[str, Any]) -> m.CreateEventResponse:
+        """Create a single event (SDK)"""
+        return self._request('POST', '/api/events', json=body, response=m.CreateEventResponse)
+
+    def batch_ingest_events(self, body: m.BatchRequest | dict[str, Any]) -> m.BatchResponse:
+        """Ingest a batch of events (SDK)"""
+        return self._request('POST', '/api/events/batch', json=body, response=m.BatchResponse)
+
+    def event_filter_options(self) -> m.EventFilterOptionsResponse:
+        """Distinct event types/sources/clients for filter UI"""
+        return self._request('GET', '/api/events/filter-options', response=m.EventFilterOptionsResponse)
+
+    def list_events_raw(self, *, type: str | None = None, source: str | None = None, subject: str | None = None, client_id: str | None = None, principal_id: str | None = None, correlation_id: str | None = None, since: str | None = None, until: str | None = None, limit: int | None = None, offset: int | None = None, size: int | None = None, client_ids: str | None = None, applications: str | None = None, subdomains: str | None = None, aggregates: str | None = None, types: str | None = None, after: str | None = None, pagination: str | None = None) -> list[m.EventRead]:
+        """List events with raw JSONB rows"""
+        return self._request('GET', '/api/events/list-raw', params={'type': type, 'source': source, 'subject': subject, 'clientId': client_id, 'principalId': principal_id, 'correlationId': correlation_id, 'since': since, 'until': until, 'limit': limit, 'offset': offset, 'size': size, 'clientIds': client_ids, 'applications': applications, 'subdomains': subdomains, 'aggregates': aggregates, 'types': types, 'after': after, 'pagination': pagination}, response=list[m.EventRead])
+
+    def list_events_raw_alias(self, *, type: str | None = None, source: str | None = None, subject: str | None = None, client_id: str | None = None, principal_id: str | None = None, correlation_id: str | None = None, since: str | None = None, until: str | None = None, limit: int | None = None, offset: int | None = None, size: int | None = None, client_ids: str | None = None, applications: str | None = None, subdomains: str | None = None, aggregates: str | None = None, types: str | None = None, after: str | None = None, pagination: str | None = None) -> list[m.EventRead]:
+        """List events raw (SDK alias of /list-raw)"""
+        return self._request('GET', '/api/events/raw', params={'type': type, 'source': source, 'subject': subject, 'clientId': client_id, 'principalId': principal_id, 'correlationId': correlation_id, 'since': since, 'until': until, 'limit': limit, 'offset': offset, 'size': size, 'clientIds': client_ids, 'applications': applications, 'subdomains': subdomains, 'aggregates': aggregates, 'types': types, 'after': after, 'pagination': pagination}, response=list[m.EventRead])
+
+    def get_event(self, id: str) -> m.EventResponse:
+        """Get an event by id"""
+        return self._request('GET', f"/api/events/{_path(id)}", response=m.EventResponse)
+
+    def list_identity_providers(self) -> m.IdentityProviderListResponse:
+        """List identity providers"""
+        return self._request('GET', '/api/identity-providers', response=m.IdentityProviderListResponse)
+
+    def create_identity_provider(self, body: m.CreateIdentityProviderRequest | dict[str, Any]) -> m.IdentityProviderResponse:
+        """Create an identity provider"""
+        return self._request('POST', '/api/identity-providers', json=body, response=m.IdentityProviderResponse)
+
+    def delete_identity_provider(self, id: str) -> None:
+        """Delete an identity provider"""
+        return self._request('DELETE', f"/api/identity-providers/{_path(id)}")
+
+    def get_identity_provider(self, id: str) -> m.IdentityProviderResponse:
+        """Get an identity provider by id"""
+        return self._request('GET', f"/api/identity-providers/{_path(id)}", response=m.IdentityProviderResponse)
+
+    def update_identity_provider(self, id: str, body: m.UpdateIdentityProviderRequest | dict[str, Any]) -> m.IdentityProviderResponse:
+        """Update an identity provider"""
+        return self._request('PUT', f"/api/identity-providers/{_path(id)}", json=body, response=m.IdentityProviderResponse)
+
+    def list_idp_role_mappings(self) -> m.IdpRoleMappingListResponse:
+        """List IDP role mappings"""
+        return self._request('GET', '/api/idp-role-mappings', response=m.IdpRoleMappingListResponse)
+
+    def create_idp_role_mapping(self, body: m.CreateIdpRoleMappingRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create an IDP role mapping"""
+        return self._request('POST', '/api/idp-role-mappings', json=body, response=m.CreatedResponse)
+
+    def delete_idp_role_mapping(self, id: str) -> None:
+        """Delete an IDP role mapping"""
+        return self._request('DELETE', f"/api/idp-role-mappings/{_path(id)}")
+
+    def list_kill_switches(self) -> m.KillSwitchListResponse:
+        """List engaged kill switches"""
+        return self._request('GET', '/api/kill-switches', response=m.KillSwitchListResponse)
+
+    def engage_kill_switch(self, body: m.EngageKillSwitchRequest | dict[str, Any]) -> m.KillSwitchResponse:
+        """Stop delivery for a client, subscription or dispatch pool"""
+        return self._request('POST', '/api/kill-switches', json=body, response=m.KillSwitchResponse)
+
+    def release_kill_switch(self, id: str) -> None:
+        """Release a kill switch, resuming delivery"""
+        return self._request('DELETE', f"/api/kill-switches/{_path(id)}")
+
+    def list_login_attempts(self, *, attempt_type: str | None = None, outcome: str | None = None, identifier: str | None = None, principal_id: str | None = None, date_from: str | None = None, date_to: str | None = None, after: str | None = None, page_size: int | None = None) -> m.LoginAttemptListResponse:
+        """List login attempts (cursor-paginated)"""
+        return self._request('GET', '/api/login-attempts', params={'attemptType': attempt_type, 'outcome': outcome, 'identifier': identifier, 'principalId': principal_id, 'dateFrom': date_from, 'dateTo': date_to, 'after': after, 'pageSize': page_size}, response=m.LoginAttemptListResponse)
+
+    def list_o_auth_clients(self) -> m.OAuthClientListResponse:
+        """List OAuth clients"""
+        return self._request('GET', '/api/oauth-clients', response=m.OAuthClientListResponse)
+
+    def create_o_auth_client(self, body: m.CreateOAuthClientRequest | dict[str, Any]) -> m.CreateOAuthClientResponse:
+        """Create an OAuth client"""
+        return self._request('POST', '/api/oauth-clients', json=body, response=m.CreateOAuthClientResponse)
+
+    def get_o_auth_client_by_client_id(self, client_id: str) -> m.OAuthClientResponse:
+        """Get an OAuth client by its client_id (SDK lookup)"""
+        return self._request('GET', f"/api/oauth-clients/by-client-id/{_path(client_id)}", response=m.OAuthClientResponse)
+
+    def delete_o_auth_client(self, id: str) -> None:
+        """Delete an OAuth client"""
+        return self._request('DELETE', f"/api/oauth-clients/{_path(id)}")
+
+    def get_o_auth_client(self, id: str) -> m.OAuthClientResponse:
+        """Get an OAuth client by id"""
+        return self._request('GET', f"/api/oauth-clients/{_path(id)}", response=m.OAuthClientResponse)
+
+    def update_o_auth_client(self, id: str, body: m.UpdateOAuthClientRequest | dict[str, Any]) -> None:
+        """Update an OAuth client"""
+        return self._request('PUT', f"/api/oauth-clients/{_path(id)}", json=body)
+
+    def activate_o_auth_client(self, id: str) -> m.SuccessResponse:
+        """Activate an OAuth client"""
+        return self._request('POST', f"/api/oauth-clients/{_path(id)}/activate", response=m.SuccessResponse)
+
+    def deactivate_o_auth_client(self, id: str) -> m.SuccessResponse:
+        """Deactivate an OAuth client"""
+        return self._request('POST', f"/api/oauth-clients/{_path(id)}/deactivate", response=m.SuccessResponse)
+
+    def regenerate_o_auth_client_secret(self, id: str) -> m.RotateOAuthClientSecretResponse:
+        """Regenerate an OAuth client's secret (SDK alias of rotate-secret)"""
+        return self._request('POST', f"/api/oauth-clients/{_path(id)}/regenerate-secret", response=m.RotateOAuthClientSecretResponse)
+
+    def rotate_o_auth_client_secret(self, id: str) -> m.RotateOAuthClientSecretResponse:
+        """Rotate an OAuth client's secret"""
+        return self._request('POST', f"/api/oauth-clients/{_path(id)}/rotate-secret", response=m.RotateOAuthClientSecretResponse)
+
+    def revoke_platform_config_access(self, id: str) -> None:
+        """Revoke a platform-config access grant"""
+        return self._request('DELETE', f"/api/platform-config/access/{_path(id)}")
+
+    def list_platform_config_properties(self, app: str) -> m.ConfigListResponse:
+        """List platform-config properties for an application"""
+        return self._request('GET', f"/api/platform-config/{_path(app)}", response=m.ConfigListResponse)
+
+    def list_platform_config_access(self, app: str) -> m.AccessListResponse:
+        """List access grants for an application"""
+        return self._request('GET', f"/api/platform-config/{_path(app)}/access", response=m.AccessListResponse)
+
+    def grant_platform_config_access(self, app: str, body: m.GrantAccessRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Grant access to platform-config for a role"""
+        return self._request('POST', f"/api/platform-config/{_path(app)}/access", json=body, response=m.CreatedResponse)
+
+    def list_cors_origins(self) -> m.CorsOriginListResponse:
+        """List CORS origins (anchor)"""
+        return self._request('GET', '/api/platform/cors', response=m.CorsOriginListResponse)
+
+    def add_cors_origin(self, body: m.AddOriginRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Add a CORS origin"""
+        return self._request('POST', '/api/platform/cors', json=body, response=m.CreatedResponse)
+
+    def public_allowed_origins(self) -> m.PublicAllowedResponse:
+        """List allowed CORS origins (public)"""
+        return self._request('GET', '/api/platform/cors/allowed', response=m.PublicAllowedResponse)
+
+    def delete_cors_origin(self, id: str) -> None:
+        """Remove a CORS origin"""
+        return self._request('DELETE', f"/api/platform/cors/{_path(id)}")
+
+    def get_cors_origin(self, id: str) -> m.AllowedOriginResponse:
+        """Get a CORS origin by id (anchor)"""
+        return self._request('GET', f"/api/platform/cors/{_path(id)}", response=m.AllowedOriginResponse)
+
+    def list_principals(self, *, type: str | None = None, client_id: str | None = None, active: str | None = None, q: str | None = None, roles: str | None = None, page: int | None = None, page_size: int | None = None, sort_field: str | None = None, sort_order: str | None = None) -> m.PrincipalListResponse:
+        """List principals"""
+        return self._request('GET', '/api/principals', params={'type': type, 'clientId': client_id, 'active': active, 'q': q, 'roles': roles, 'page': page, 'pageSize': page_size, 'sortField': sort_field, 'sortOrder': sort_order}, response=m.PrincipalListResponse)
+
+    def create_principal(self, body: m.CreatePrincipalRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create a principal"""
+        return self._request('POST', '/api/principals', json=body, response=m.CreatedResponse)
+
+    def bulk_import_users(self, body: m.BulkImportRequest | dict[str, Any]) -> m.BulkImportResponse:
+        """Bulk-import CLIENT users for a client (CSV onboarding)"""
+        return self._request('POST', '/api/principals/bulk-import', json=body, response=m.BulkImportResponse)
+
+    def check_principal_email_domain(self, *, email: str | None = None) -> m.CheckEmailDomainResponse:
+        """Resolve auth-method for an email's domain"""
+        return self._request('GET', '/api/principals/check-email-domain', params={'email': email}, response=m.CheckEmailDomainResponse)
+
+    def list_developer_users(self) -> m.DeveloperUserListResponse:
+        """List USER principals holding the developer role"""
+        return self._request('GET', '/api/principals/developer-users', response=m.DeveloperUserListResponse)
+
+    def sync_users(self, body: m.SyncUsersRequest | dict[str, Any]) -> m.SyncUsersResponse:
+        """Sync users (declarative upsert by email; no application scope)"""
+        return self._request('POST', '/api/principals/sync', json=body, response=m.SyncUsersResponse)
+
+    def create_user(self, body: m.CreateUserRequest | dict[str, Any]) -> m.PrincipalResponse:
+        """Create a user principal (scope derived from email domain)"""
+        return self._request('POST', '/api/principals/users', json=body, response=m.PrincipalResponse)
+
+    def delete_principal(self, id: str) -> None:
+        """Delete a principal"""
+        return self._request('DELETE', f"/api/principals/{_path(id)}")
+
+    def get_principal(self, id: str) -> m.PrincipalResponse:
+        """Get a principal by id"""
+        return self._request('GET', f"/api/principals/{_path(id)}", response=m.PrincipalResponse)
+
+    def update_principal(self, id: str, body: m.UpdatePrincipalRequest | dict[str, Any]) -> m.PrincipalResponse:
+        """Update a principal"""
+        return self._request('PUT', f"/api/principals/{_path(id)}", json=body, response=m.PrincipalResponse)
+
+    def activate_principal(self, id: str) -> m.StatusChangeResponse:
+        """Activate a principal"""
+        return self._request('POST', f"/api/principals/{_path(id)}/activate", response=m.StatusChangeResponse)
+
+    def list_principal_application_access(self, id: str) -> m.ApplicationAccessListResponse:
+        """List application IDs the principal can access"""
+        return self._request('GET', f"/api/principals/{_path(id)}/application-access", response=m.ApplicationAccessListResponse)
+
+    def assign_principal_application_access(self, id: str, body: m.AssignApplicationAccessRequest | dict[str, Any]) -> m.SetApplicationAccessResponse:
+        """Assign application access to a principal"""
+        return self._request('PUT', f"/api/principals/{_path(id)}/application-access", json=body, response=m.SetApplicationAccessResponse)
+
+    def list_principal_available_applications(self, id: str) -> m.PrincipalAvailableApplicationsResponse:
+        """List applications a principal can be granted access to"""
+        return self._request('GET', f"/api/principals/{_path(id)}/available-applications", response=m.PrincipalAvailableApplicationsResponse)
+
+    def list_principal_client_access(self, id: str) -> m.ClientAccessGrantListResponse:
+        """List client-access grants for a principal"""
+        return self._request('GET', f"/api/principals/{_path(id)}/client-access", response=m.ClientAccessGrantListResponse)
+
+    def grant_principal_client_access(self, id: str, body: m.GrantClientAccessRequest | dict[str, Any]) -> m.ClientAccessGrantResponse:
+        """Grant a client-access for a principal"""
+        return self._request('POST', f"/api/principals/{_path(id)}/client-access", json=body, response=m.ClientAccessGrantResponse)
+
+    def revoke_principal_client_access(self, id: str, client_id: str) -> None:
+        """Revoke a client-access grant"""
+        return self._request('DELETE', f"/api/principals/{_path(id)}/client-access/{_path(client_id)}")
+
+    def set_principal_client_association(self, id: str, body: m.ClientAssociationRequest | dict[str, Any]) -> m.PrincipalResponse:
+        """Change a principal's scope/client association (anchor-gated)"""
+        return self._request('PUT', f"/api/principals/{_path(id)}/client-association", json=body, response=m.PrincipalResponse)
+
+    def deactivate_principal(self, id: str) -> m.StatusChangeResponse:
+        """Deactivate a principal"""
+        return self._request('POST', f"/api/principals/{_path(id)}/deactivate", response=m.StatusChangeResponse)
+
+    def revoke_principal_developer_credential(self, id: str) -> None:
+        """Revoke a principal's self-service developer API credential"""
+        return self._request('DELETE', f"/api/principals/{_path(id)}/developer-credential")
+
+    def set_principal_developer_credential(self, id: str) -> m.SetDeveloperCredentialResponse:
+        """Create or rotate a principal's self-service developer API credential"""
+        return self._request('POST', f"/api/principals/{_path(id)}/developer-credential", response=m.SetDeveloperCredentialResponse)
+
+    def reset_principal_two_factor(self, id: str) -> m.StatusChangeResponse:
+        """Clear a user's two-factor methods (forces re-enrollment)"""
+        return self._request('POST', f"/api/principals/{_path(id)}/reset-2fa", response=m.StatusChangeResponse)
+
+    def reset_principal_password(self, id: str, body: m.ResetPasswordRequest | dict[str, Any]) -> m.StatusChangeResponse:
+        """Reset a user's password"""
+        return self._request('POST', f"/api/principals/{_path(id)}/reset-password", json=body, response=m.StatusChangeResponse)
+
+    def list_principal_roles(self, id: str) -> m.PrincipalRoleListResponse:
+        """List a principal's assigned roles"""
+        return self._request('GET', f"/api/principals/{_path(id)}/roles", response=m.PrincipalRoleListResponse)
+
+    def add_principal_role(self, id: str, body: m.AddRoleRequest | dict[str, Any]) -> m.PrincipalResponse:
+        """Add a single role to a principal"""
+        return self._request('POST', f"/api/principals/{_path(id)}/roles", json=body, response=m.PrincipalResponse)
+
+    def assign_principal_roles(self, id: str, body: m.AssignPrincipalRolesRequest | dict[str, Any]) -> m.RolesAssignedResponse:
+        """Assign roles to a principal (replaces full set)"""
+        return self._request('PUT', f"/api/principals/{_path(id)}/roles", json=body, response=m.RolesAssignedResponse)
+
+    def remove_principal_role(self, id: str, role: str) -> m.PrincipalResponse:
+        """Remove a single role from a principal"""
+        return self._request('DELETE', f"/api/principals/{_path(id)}/roles/{_path(role)}", response=m.PrincipalResponse)
+
+    def send_principal_password_reset(self, id: str, body: m.SendPasswordResetInputBody | dict[str, Any] | None = None) -> m.StatusChangeResponse:
+        """Send a password-reset email to a user"""
+        return self._request('POST', f"/api/principals/{_path(id)}/send-password-reset", json=body, response=m.StatusChangeResponse)
+
+    def get_principal_version(self, id: str) -> m.PrincipalVersionResponse:
+        """Get when a principal (or a role it holds) last changed — used by SDKs to detect a revoked/changed session"""
+        return self._request('GET', f"/api/principals/{_path(id)}/version", response=m.PrincipalVersionResponse)
+
+    def list_processes(self, *, application: str | None = None, subdomain: str | None = None, status: str | None = None) -> m.ProcessListResponse:
+        """List processes"""
+        return self._request('GET', '/api/processes', params={'application': application, 'subdomain': subdomain, 'status': status}, response=m.ProcessListResponse)
+
+    def create_process(self, body: m.CreateProcessRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create a process"""
+        return self._request('POST', '/api/processes', json=body, response=m.CreatedResponse)
+
+    def get_process_by_code(self, code: str) -> m.ProcessResponse:
+        """Get a process by code"""
+        return self._request('GET', f"/api/processes/by-code/{_path(code)}", response=m.ProcessResponse)
+
+    def sync_processes_by_body(self, body: m.SyncProcessesByBodyRequest | dict[str, Any], *, remove_unlisted: bool | None = None) -> m.SyncResultResponse:
+        """Sync processes (SDK alias; applicationCode in the body)"""
+        return self._request('POST', '/api/processes/sync', params={'removeUnlisted': remove_unlisted}, json=body, response=m.SyncResultResponse)
+
+    def delete_process(self, id: str) -> None:
+        """Delete a process"""
+        return self._request('DELETE', f"/api/processes/{_path(id)}")
+
+    def get_process(self, id: str) -> m.ProcessResponse:
+        """Get a process by id"""
+        return self._request('GET', f"/api/processes/{_path(id)}", response=m.ProcessResponse)
+
+    def update_process(self, id: str, body: m.UpdateProcessRequest | dict[str, Any]) -> None:
+        """Update a process"""
+        return self._request('PUT', f"/api/processes/{_path(id)}", json=body)
+
+    def archive_process(self, id: str) -> None:
+        """Archive a process"""
+        return self._request('POST', f"/api/processes/{_path(id)}/archive")
+
+    def list_reset_approvals(self) -> m.ListOutputBody:
+        """List pending lost-device reset requests for your client(s)"""
+        return self._request('GET', '/api/reset-approvals', response=m.ListOutputBody)
+
+    def approve_reset_approval(self, id: str) -> m.StatusChangeResponse:
+        """Approve a lost-device reset (emails the user a reset link)"""
+        return self._request('POST', f"/api/reset-approvals/{_path(id)}/approve", response=m.StatusChangeResponse)
+
+    def deny_reset_approval(self, id: str) -> m.StatusChangeResponse:
+        """Deny a lost-device reset request"""
+        return self._request('POST', f"/api/reset-approvals/{_path(id)}/deny", response=m.StatusChangeResponse)
+
+    def list_roles(self) -> m.RoleListResponse:
+        """List roles"""
+        return self._request('GET', '/api/roles', response=m.RoleListResponse)
+
+    def create_role(self, body: m.CreateRoleRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create a role"""
+        return self._request('POST', '/api/roles', json=body, response=m.CreatedResponse)
+
+    def get_roles_by_application(self, application_id: str) -> list[m.RoleResponse]:
+        """List roles for an application"""
+        return self._request('GET', f"/api/roles/by-application/{_path(application_id)}", response=list[m.RoleResponse])
+
+    def get_role_by_code(self, code: str) -> m.RoleResponse:
+        """Get a role by name (code)"""
+        return self._request('GET', f"/api/roles/by-code/{_path(code)}", response=m.RoleResponse)
+
+    def get_roles_by_source(self, source: str) -> list[m.RoleResponse]:
+        """List roles by source (CODE | DATABASE | SDK)"""
+        return self._request('GET', f"/api/roles/by-source/{_path(source)}", response=list[m.RoleResponse])
+
+    def get_role_application_filters(self) -> m.ApplicationFilterListResponse:
+        """List distinct application codes used by roles"""
+        return self._request('GET', '/api/roles/filters/applications', response=m.ApplicationFilterListResponse)
+
+    def list_permissions(self) -> m.PermissionListResponse:
+        """List the platform permission catalog"""
+        return self._request('GET', '/api/roles/permissions', response=m.PermissionListResponse)
+
+    def delete_permission(self, permission: str) -> None:
+        """Delete a permission from the catalog"""
+        return self._request('DELETE', f"/api/roles/permissions/{_path(permission)}")
+
+    def get_permission(self, permission: str) -> m.PermissionResponse:
+        """Get a single permission catalog entry"""
+        return self._request('GET', f"/api/roles/permissions/{_path(permission)}", response=m.PermissionResponse)
+
+    def delete_role(self, id: str) -> None:
+        """Delete a role"""
+        return self._request('DELETE', f"/api/roles/{_path(id)}")
+
+    def get_role(self, id: str) -> m.RoleResponse:
+        """Get a role by id"""
+        return self._request('GET', f"/api/roles/{_path(id)}", response=m.RoleResponse)
+
+    def update_role(self, id: str, body: m.UpdateRoleRequest | dict[str, Any]) -> None:
+        """Update a role"""
+        return self._request('PUT', f"/api/roles/{_path(id)}", json=body)
+
+    def list_role_permissions(self, role_name: str) -> m.RolePermissionListResponse:
+        """List permissions granted to a role"""
+        return self._request('GET', f"/api/roles/{_path(role_name)}/permissions", response=m.RolePermissionListResponse)
+
+    def grant_role_permission_by_body(self, role_name: str, body: m.GrantPermissionRequest | dict[str, Any]) -> m.RoleResponse:
+        """Grant a permission to a role (SDK; permission in body)"""
+        return self._request('POST', f"/api/roles/{_path(role_name)}/permissions", json=body, response=m.RoleResponse)
+
+    def revoke_role_permission(self, role_name: str, permission: str) -> m.RoleResponse:
+        """Revoke a permission from a role"""
+        return self._request('DELETE', f"/api/roles/{_path(role_name)}/permissions/{_path(permission)}", response=m.RoleResponse)
+
+    def grant_role_permission(self, role_name: str, permission: str) -> m.RoleResponse:
+        """Grant a permission to a role"""
+        return self._request('POST', f"/api/roles/{_path(role_name)}/permissions/{_path(permission)}", response=m.RoleResponse)
+
+    def list_scheduled_jobs(self, *, status: str | None = None, client_id: str | None = None, search: str | None = None, page: int | None = None, size: int | None = None, limit: int | None = None, page_size: int | None = None) -> m.OffsetPageScheduledJobResponse:
+        """List scheduled jobs"""
+        return self._request('GET', '/api/scheduled-jobs', params={'status': status, 'clientId': client_id, 'search': search, 'page': page, 'size': size, 'limit': limit, 'pageSize': page_size}, response=m.OffsetPageScheduledJobResponse)
+
+    def create_scheduled_job(self, body: m.CreateScheduledJobRequest | dict[str, Any]) -> m.CreatedResponse:
+        """Create a scheduled job"""
+        return self._request('POST', '/api/scheduled-jobs', json=body, response=m.CreatedResponse)
+
+    def get_scheduled_job_by_code(self, code: str, *, client_id: str | None = None) -> m.ScheduledJobResponse:
+        """Get a scheduled job by code"""
+        return self._request('GET', f"/api/scheduled-jobs/by-code/{_path(code)}", params={'clientId': client_id}, response=m.ScheduledJobResponse)
+
+    def get_scheduled_job_instance(self, instance_id: str) -> m.ScheduledJobInstanceResponse:
+        """Get a single scheduled-job instance"""
+        return self._request('GET', f"/api/scheduled-jobs/instances/{_path(instance_id)}", response=m.ScheduledJobInstanceResponse)
+
+    def complete_scheduled_job_instance(self, instance_id: str, body: m.CompleteInstanceRequest | dict[str, Any]) -> None:
+        """Mark a scheduled-job instance as completed"""
+        return self._request('POST', f"/api/scheduled-jobs/instances/{_path(instance_id)}/complete", json=body)
+
+    def write_scheduled_job_instance_log(self, instance_id: str, body: m.WriteInstanceLogRequest | dict[str, Any]) -> None:
+        """Append a log entry to an instance"""
+        return self._request('POST', f"/api/scheduled-jobs/instances/{_path(instance_id)}/log", json=body)
+
+    def list_scheduled_job_instance_logs(self, instance_id: str) -> list[m.ScheduledJobInstanceLogResponse]:
+        """List log entries for an instance"""
+        return self._request('GET', f"/api/scheduled-jobs/instances/{_path(instance_id)}/logs", response=list[m.ScheduledJobInstanceLogResponse])
+
+    def delete_scheduled_job(self, id: str) -> None:
+        """Delete a scheduled job"""
+        return self._request('DELETE', f"/api/scheduled-jobs/{_path(id)}")
+
+    def get_scheduled_job(self, id: str) -> m.ScheduledJobResponse:
+        """Get a scheduled job by id"""
+        return self._request('GET', f"/api/scheduled-jobs/{_path(id)}", response=m.ScheduledJobResponse)
+
+    def update_scheduled_job(self, id: str, body: m.UpdateScheduledJobRequest | dict[str, Any]) -> None:
+        """Update a scheduled job"""
+        return self._request('PUT', f"/api/scheduled-jobs/{_path(id)}", json=body)
+
+    def archive_scheduled_job(self, id: str) -> None:
+        """Archive a scheduled job"""
+        return self._request('POST', f"/api/scheduled-jobs/{_path(id)}/archive")
+
+    def fire_scheduled_job_now(self, id: str, body: m.FireNowRequest | dict[str, Any] | None = None) -> m.FireNowResponse:
+        """Fire a scheduled job immediately"""
+        return self._request('POST', f"/api/scheduled-jobs/{_path(id)}/fire", json=body, response=m.FireNowResponse)
+
+    def list_scheduled_job_instances(self, id: str, *, status: str | None = None, page: int | None = None, size: int | None = None, limit: int | None = None, page_size: int | None = None) -> m.OffsetPageScheduledJobInstanceResponse:
+        """List firings for a scheduled job"""
+        return self._request('GET', f"/api/scheduled-jobs/{_path(id)}/instances", params={'status': status, 'page': page, 'size': size, 'limit': limit, 'pageSize': page_size}, response=m.OffsetPageScheduledJobInstanceResponse)
+
+    def pause_scheduled_job(self, id: str) -> None:
+        """Pause a scheduled job"""
+        return self._request('POST', f"/api/scheduled-jobs/{_path(id)}/pause")
+
+    def resume_scheduled_job(self, id: str) -> None:
+        """Resume a scheduled job"""
+        return self._request('POST', f"/api/scheduled-jobs/{_path(id)}/resume")
+
+    def list_service_accounts(self) -> m.ServiceAccountListResponse:
+        """List service accounts"""
+        return self._request('GET', '/api/service-accounts', response=m.ServiceAccountListResponse)
+
+    def create_service_account(self, body: m.CreateServiceAccountRequest | dict[str, Any], *, idempotency_key: str | None = None) -> m.CreateServiceAccountResponse:
+        """Create a service account"""
+        return self._request('POST', '/api/service-accounts', headers={'Idempotency-Key': idempotency_key}, json=body, response=m.CreateServiceAccountResponse)
+
+    def get_service_account_by_code(self, code: str) -> m.ServiceAccountResponse:
+        """Get a service account by code"""
+        return self._request('GET', f"/api/service-accounts/code/{_path(code)}", response=m.ServiceAccountResponse)
+
+    def upsert_service_account(self, code: str, body: m.UpsertServiceAccountRequest | dict[str, Any], *, if_match: str | None = None, if_none_match: str | None = None) -> m.UpsertServiceAccountResponse:
+        """Create or update a service account by code"""
+        return self._request('PUT', f"/api/service-accounts/code/{_path(code)}", headers={'If-Match': if_match, 'If-None-Match': if_none_match}, json=body, response=m.UpsertServiceAccountResponse)
+
+    def delete_service_account(self, id: str, *, if_match: str | None = None) -> None:
+        """Delete a service account"""
+        return self._request('DELETE', f"/api/service-accounts/{_path(id)}", headers={'If-Match': if_match})
+
+    def get_service_account(self, id: str) -> m.ServiceAccountResponse:
+        """Get a service account by id"""
+        return self._request('GET', f"/api/service-accounts/{_path(id)}", response=m.ServiceAccountResponse)
+
+    def update_service_account(self, id: str, body: m.UpdateServiceAccountRequest | dict[str, Any], *, if_match: str | None = None) -> None:
+        """Update a service account"""
+        return self._request('PUT', f"/api/service-accounts/{_path(id)}", headers={'If-Match': if_match}, json=body)
+
+    def deactivate_service_account(self, id: str) -> None:
+        """Deactivate a service account"""
+        return self._request('POST', f"/api/service-accounts/{_path(id)}/deactivate")
+
+    def regenerate_service_account_auth_token_regenerate_auth_token(self, id: str) -> m.RegenerateAuthTokenResponse:
+        """Regenerate a service account's auth token"""
+        return self._request('POST', f"/api/service-accounts/{_path(id)}/regenerate-auth-token", response=m.RegenerateAuthTokenResponse)
+
+    def regenerate_service_account_signing_secret_regenerate_secret(self, id: str) -> m.RegenerateSigningSecretResponse:
+        """Regenerate a service account's signing secret"""
+        return self._request('POST', f"/api/service-accounts/{_path(id)}/regenerate-secret", response=m.RegenerateSigningSecretResponse)
+
+    def regenerate_service_account_signing_secret_regenerate_signing_secret(self, id: str) -> m.RegenerateSigningSecretResponse:
+        """Regenerate a service account's signing secret"""
+        return self._request('POST', f"/api/service-accounts/{_path(id)}/regenerate-signing-secret", response=m.RegenerateSigningSecretResponse)
+
+    def regenerate_service_account_auth_token_regenerate_token(self, id: str) -> m.RegenerateAuthTokenResponse:
+        """Regenerate a service account's auth token"""
+        return self._request('POST', f"/api/service-accounts/{_path(id)}/regenerate-token", response=m.RegenerateAuthTokenResponse)
+
+    def list_service_account_roles(self, id: str) -> m.ServiceAccountRoleListResponse:
+        """List a service account's roles"""
+        return self._request('GET', f"/api/service-accounts/{_path(id)}/roles", response=m.ServiceAccountRoleListResponse)
+
+    def assign_service_account_roles(self, id: str, body: m.AssignRolesRequest | dict[str, Any]) -> m.ServiceAccountRolesAssignedResponse:
+        """Assign roles to a service account"""
+        return self._request('PUT', f"/api/service-accounts/{_path(id)}/roles", json=body, response=m.ServiceAccountRolesAssignedResponse)
+
+    def list_subscriptions(self, *, status: str | None = None, client_id: str | None = None) -> m.SubscriptionListResponse:
+        """List subscriptions"""
+        return self._request('GET', '/api/subscriptions', params={'status': status, 'clientId': client_id}, response=m.SubscriptionListResponse)
+
+    def create_subscription(self, body: m.CreateSubscriptionRequest | dict[str, Any], *, idempotency_key: str | None = None) -> m.CreatedResponse:
+        """Create a subscription"""
+        return self._request('POST', '/api/subscriptions', headers={'Idempotency-Key': idempotency_key}, json=body, response=m.CreatedResponse)
+
+    def upsert_subscription(self, code: str, body: m.UpsertSubscriptionRequest | dict[str, Any], *, client_id: str | None = None, if_match: str | None = None, if_none_match: str | None = None) -> m.SubscriptionResponse:
+        """Create or update a subscription by code"""
+        return self._request('PUT', f"/api/subscriptions/by-code/{_path(code)}", params={'clientId': client_id}, headers={'If-Match': if_match, 'If-None-Match': if_none_match}, json=body, response=m.SubscriptionResponse)
+
+    def delete_subscription(self, id: str, *, if_match: str | None = None) -> None:
+        """Delete a subscription"""
+        return self._request('DELETE', f"/api/subscriptions/{_path(id)}", headers={'If-Match': if_match})
+
+    def get_subscription(self, id: str) -> m.SubscriptionResponse:
+        """Get a subscription by id"""
+        return self._request('GET', f"/api/subscriptions/{_path(id)}", response=m.SubscriptionResponse)
+
+    def update_subscription(self, id: str, body: m.UpdateSubscriptionRequest | dict[str, Any], *, if_match: str | None = None) -> None:
+        """Update a subscription"""
+        return self._request('PUT', f"/api/subscriptions/{_path(id)}", headers={'If-Match': if_match}, json=body)
+
+    def pause_subscription(self, id: str) -> None:
+        """Pause a subscription"""
+        return self._request('POST', f"/api/subscriptions/{_path(id)}/pause")
+
+    def resume_subscription(self, id: str) -> None:
+        """Resume a subscription"""
+        return self._request('POST', f"/api/subscriptions/{_path(id)}/resume")
+
+    def webauthn_authenticate_begin(self, body: m.AuthenticateBeginRequest | dict[str, Any]) -> m.AuthenticateBeginResponse:
+        """Begin a WebAuthn authentication ceremony"""
+        return self._request('POST', '/auth/webauthn/authenticate/begin', json=body, response=m.AuthenticateBeginResponse)
+
+    def webauthn_authenticate_complete(self, body: m.AuthenticateCompleteRequest | dict[str, Any]) -> m.WebauthnAuthenticateCompleteResponse:
+        """Complete a WebAuthn authentication ceremony"""
+        return self._request('POST', '/auth/webauthn/authenticate/complete', json=body, response=m.WebauthnAuthenticateCompleteResponse)
+
+    def list_webauthn_credentials(self) -> list[m.WebauthnCredentialSummary]:
+        """List the current user's WebAuthn credentials"""
+        return self._request('GET', '/auth/webauthn/credentials', response=list[m.WebauthnCredentialSummary])
+
+    def delete_webauthn_credential(self, id: str) -> None:
+        """Revoke a WebAuthn credential"""
+        return self._request('DELETE', f"/auth/webauthn/credentials/{_path(id)}")
+
+    def webauthn_register_begin(self, body: m.RegisterBeginRequest | dict[str, Any]) -> m.RegisterBeginResponse:
+        """Begin a WebAuthn registration ceremony"""
+        return self._request('POST', '/auth/webauthn/register/begin', json=body, response=m.RegisterBeginResponse)
+
+    def webauthn_register_complete(self, body: m.RegisterCompleteRequest | dict[str, Any]) -> m.RegisterCompleteResponse:
+        """Complete a WebAuthn registration ceremony"""
+        return self._request('POST', '/auth/webauthn/register/complete', json=body, response=m.RegisterCompleteResponse)
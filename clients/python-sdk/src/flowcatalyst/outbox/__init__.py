@@ -0,0 +1,21 @@
+"""Transactional outbox writer.
+
+Write events, dispatch jobs and audit logs to your own database in the
+same transaction as your business data; `fc-dev outbox poll` (or the
+platform's outbox processor) forwards them.
+"""
+
+from .dbapi import DbApiOutboxDriver
+from .dto import CreateAuditLogDto, CreateDispatchJobDto, CreateEventDto
+from .manager import OutboxDriver, OutboxManager, OutboxMessage, OutboxStatus
+
+__all__ = [
+    "CreateAuditLogDto",
+    "CreateDispatchJobDto",
+    "CreateEventDto",
+    "DbApiOutboxDriver",
+    "OutboxDriver",
+    "OutboxManager",
+    "OutboxMessage",
+    "OutboxStatus",
+]
@@ -0,0 +1,70 @@
+"""An outbox driver over any DB-API 2.0 connection (psycopg, psycopg2,
+PyMySQL, mysqlclient, ...).
+
+The table is migrations/{postgresql,mysql}/001_create_outbox_messages.sql,
+or the one `fc-dev outbox poll` creates — they are the same schema.
+"""
+
+from __future__ import annotations
+
+import json
+from typing import Any
+
+from .manager import OutboxMessage
+
+_COLUMNS = (
+    "id, type, message_group, payload, status, created_at, updated_at, "
+    "client_id, payload_size, headers"
+)
+
+
+class DbApiOutboxDriver:
+    """Inserts rows through ``connection``, or through ``tx`` when the
+    manager is given one (a connection or cursor in the caller's
+    transaction).
+
+    The driver never commits: DB-API connections are transactional by
+    default, so the rows commit with whatever else the caller commits.
+    ``placeholder`` is the driver's paramstyle marker; ``%s`` suits the
+    common Postgres and MySQL drivers, ``?`` suits sqlite3.
+    """
+
+    def __init__(self, connection: Any, *, table: str = "outbox_messages", placeholder: str = "%s") -> None:
+        self.connection = connection
+        marks = ", ".join([placeholder] * 10)
+        self._sql = f"INSERT INTO {table} ({_COLUMNS}) VALUES ({marks})"
+
+    def insert(self, message: OutboxMessage, tx: Any = None) -> None:
+        self.insert_batch([message], tx)
+
+    def insert_batch(self, messages: list[OutboxMessage], tx: Any = None) -> None:
+        if not messages:
+            return
+        cursor = self._cursor(tx)
+        try:
+            cursor.executemany(self._sql, [_params(m) for m in messages])
+        finally:
+            if cursor is not tx:
+                cursor.close()
+
+    def _cursor(self, tx: Any) -> Any:
+        if tx is None:
+            return self.connection.cursor()
+        if hasattr(tx, "executemany"):
+            return tx
+        return tx.cursor()
+
+
+def _params(m: OutboxMessage) -> tuple[Any, ...]:
+    return (
+        m.id,
+        m.type,
+        m.message_group,
+        m.payload,
+        int(m.status),
+        m.created_at,
+        m.updated_at,
+        m.client_id,
+        m.payload_size,
+        json.dumps(m.headers) if m.headers else None,
+    )
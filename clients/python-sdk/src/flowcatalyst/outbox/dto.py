@@ -0,0 +1,151 @@
+"""What an application writes to the outbox.
+
+Each DTO's ``to_payload`` is the JSON the outbox processor forwards to the
+platform, the same shape as the TypeScript and Laravel SDKs write.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from typing import Any
+
+
+def _iso(ts: datetime) -> str:
+    if ts.tzinfo is None:
+        ts = ts.replace(tzinfo=timezone.utc)
+    return ts.astimezone(timezone.utc).isoformat(timespec="milliseconds").replace("+00:00", "Z")
+
+
+def _without_nulls(payload: dict[str, Any]) -> dict[str, Any]:
+    return {k: v for k, v in payload.items() if v is not None}
+
+
+@dataclass
+class CreateEventDto:
+    """An event to publish.
+
+    >>> CreateEventDto("orders:order:created", {"orderId": "123"}, message_group="order-123")
+    """
+
+    type: str
+    data: dict[str, Any]
+    source: str | None = None
+    subject: str | None = None
+    correlation_id: str | None = None
+    causation_id: str | None = None
+    deduplication_id: str | None = None
+    message_group: str | None = None
+    client_code: str | None = None
+    context_data: list[dict[str, str]] = field(default_factory=list)
+    # Stored on the outbox row, not sent in the payload.
+    headers: dict[str, str] = field(default_factory=dict)
+
+    def to_payload(self) -> dict[str, Any]:
+        return _without_nulls({
+            "specVersion": "1.0",
+            "type": self.type,
+            "source": self.source,
+            "subject": self.subject,
+            "data": json.dumps(self.data),
+            "correlationId": self.correlation_id,
+            "causationId": self.causation_id,
+            "deduplicationId": self.deduplication_id,
+            "messageGroup": self.message_group,
+            "clientCode": self.client_code,
+            "contextData": self.context_data or None,
+        })
+
+
+@dataclass
+class CreateDispatchJobDto:
+    """A dispatch job to create directly, without an event.
+
+    ``payload`` is sent as-is when it is a string and JSON-encoded
+    otherwise.
+    """
+
+    source: str
+    code: str
+    target_url: str
+    payload: str | dict[str, Any]
+    dispatch_pool_id: str
+    subject: str | None = None
+    correlation_id: str | None = None
+    event_id: str | None = None
+    metadata: dict[str, str] = field(default_factory=dict)
+    headers: dict[str, str] = field(default_factory=dict)
+    payload_content_type: str = "application/json"
+    data_only: bool = True
+    message_group: str | None = None
+    sequence: int | None = None
+    timeout_seconds: int = 30
+    max_retries: int = 5
+    retry_strategy: str | None = None
+    scheduled_for: datetime | None = None
+    expires_at: datetime | None = None
+    idempotency_key: str | None = None
+    external_id: str | None = None
+    connection_id: str | None = None
+
+    def to_payload(self) -> dict[str, Any]:
+        return _without_nulls({
+            "source": self.source,
+            "code": self.code,
+            "targetUrl": self.target_url,
+            "payload": self.payload if isinstance(self.payload, str) else json.dumps(self.payload),
+            "payloadContentType": self.payload_content_type,
+            "dispatchPoolId": self.dispatch_pool_id,
+            "subject": self.subject,
+            "correlationId": self.correlation_id,
+            "eventId": self.event_id,
+            "metadata": self.metadata or None,
+            "headers": self.headers or None,
+            "dataOnly": self.data_only,
+            "messageGroup": self.message_group,
+            "sequence": self.sequence,
+            "timeoutSeconds": self.timeout_seconds,
+            "maxRetries": self.max_retries,
+            "retryStrategy": self.retry_strategy,
+            "scheduledFor": _iso(self.scheduled_for) if self.scheduled_for else None,
+            "expiresAt": _iso(self.expires_at) if self.expires_at else None,
+            "idempotencyKey": self.idempotency_key,
+            "externalId": self.external_id,
+            "connectionId": self.connection_id,
+        })
+
+
+@dataclass
+class CreateAuditLogDto:
+    """An audit log entry. ``performed_at`` defaults to when the payload is
+    built."""
+
+    entity_type: str
+    entity_id: str
+    operation: str
+    operation_data: dict[str, Any] | None = None
+    principal_id: str | None = None
+    performed_at: datetime | None = None
+    source: str | None = None
+    correlation_id: str | None = None
+    application_code: str | None = None
+    client_code: str | None = None
+    metadata: dict[str, str] = field(default_factory=dict)
+    # Stored on the outbox row, not sent in the payload.
+    headers: dict[str, str] = field(default_factory=dict)
+
+    def to_payload(self) -> dict[str, Any]:
+        return _without_nulls({
+            "entityType": self.entity_type,
+            "entityId": self.entity_id,
+            "operation": self.operation,
+            "operationData": json.dumps(self.operation_data) if self.operation_data else None,
+            "principalId": self.principal_id,
+            "performedAt": _iso(self.performed_at or datetime.now(timezone.utc)),
+            "source": self.source,
+            "correlationId": self.correlation_id,
+            "applicationCode": self.application_code,
+            "clientCode": self.client_code,
+            "metadata": self.metadata or None,
+        })
@@ -0,0 +1,115 @@
+"""The outbox writer: turns DTOs into outbox_messages rows."""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from enum import IntEnum
+from typing import Any, Protocol
+
+from . import tsid
+from .dto import CreateAuditLogDto, CreateDispatchJobDto, CreateEventDto
+
+
+class OutboxStatus(IntEnum):
+    """Row status codes, as the outbox processor reads them. The SDK only
+    writes PENDING; the processor sets the rest."""
+
+    PENDING = 0
+    SUCCESS = 1
+    BAD_REQUEST = 2
+    INTERNAL_ERROR = 3
+    UNAUTHORIZED = 4
+    FORBIDDEN = 5
+    GATEWAY_ERROR = 6
+    EXPIRED = 7
+    IN_PROGRESS = 9
+
+
+@dataclass
+class OutboxMessage:
+    """One outbox_messages row."""
+
+    id: str
+    type: str  # EVENT | DISPATCH_JOB | AUDIT_LOG
+    message_group: str | None
+    payload: str
+    status: int
+    created_at: datetime
+    updated_at: datetime
+    client_id: str
+    payload_size: int
+    headers: dict[str, str] | None
+
+
+class OutboxDriver(Protocol):
+    """Writes rows. ``tx`` is the caller's transaction handle, if any."""
+
+    def insert(self, message: OutboxMessage, tx: Any = None) -> None: ...
+
+    def insert_batch(self, messages: list[OutboxMessage], tx: Any = None) -> None: ...
+
+
+class OutboxManager:
+    """Creates outbox messages for the transactional outbox pattern.
+
+    Pass ``tx`` to write in the caller's transaction, so the outbox row
+    commits or rolls back with the business writes next to it.
+
+    >>> outbox = OutboxManager(DbApiOutboxDriver(conn), client_id="0HZXEQ5Y8JY5Z")
+    >>> event_id = outbox.create_event(CreateEventDto("orders:order:created", {"orderId": "123"}))
+    >>> conn.commit()
+    """
+
+    def __init__(self, driver: OutboxDriver, client_id: str) -> None:
+        if not client_id:
+            raise ValueError("OutboxManager: client_id is required")
+        self.driver = driver
+        self.client_id = client_id
+
+    def create_event(self, event: CreateEventDto, tx: Any = None) -> str:
+        """Write one event; returns its TSID."""
+        return self.create_events([event], tx)[0]
+
+    def create_events(self, events: list[CreateEventDto], tx: Any = None) -> list[str]:
+        return self._write("EVENT", [(e.to_payload(), e.message_group, e.headers) for e in events], tx)
+
+    def create_dispatch_job(self, job: CreateDispatchJobDto, tx: Any = None) -> str:
+        """Write one dispatch job; returns its TSID."""
+        return self.create_dispatch_jobs([job], tx)[0]
+
+    def create_dispatch_jobs(self, jobs: list[CreateDispatchJobDto], tx: Any = None) -> list[str]:
+        return self._write("DISPATCH_JOB", [(j.to_payload(), j.message_group, None) for j in jobs], tx)
+
+    def create_audit_log(self, audit_log: CreateAuditLogDto, tx: Any = None) -> str:
+        """Write one audit log; returns its TSID."""
+        return self.create_audit_logs([audit_log], tx)[0]
+
+    def create_audit_logs(self, audit_logs: list[CreateAuditLogDto], tx: Any = None) -> list[str]:
+        return self._write("AUDIT_LOG", [(a.to_payload(), None, a.headers) for a in audit_logs], tx)
+
+    def _write(self, type_: str, items: list[tuple[dict[str, Any], str | None, dict[str, str] | None]], tx: Any) -> list[str]:
+        if not items:
+            return []
+        now = datetime.now(timezone.utc)
+        messages = []
+        for payload, group, headers in items:
+            body = json.dumps(payload)
+            messages.append(OutboxMessage(
+                id=tsid.generate(),
+                type=type_,
+                message_group=group,
+                payload=body,
+                status=OutboxStatus.PENDING,
+                created_at=now,
+                updated_at=now,
+                client_id=self.client_id,
+                payload_size=len(body.encode()),
+                headers=headers or None,
+            ))
+        if len(messages) == 1:
+            self.driver.insert(messages[0], tx)
+        else:
+            self.driver.insert_batch(messages, tx)
+        return [m.id for m in messages]
@@ -0,0 +1,35 @@
+"""TSIDs: the time-sortable ids the platform uses.
+
+13 Crockford base32 characters encoding (milliseconds since 2020-01-01
+UTC) << 22 | 22 random bits, so ids sort by creation time. Matches
+pkg/fcsdk/tsid and the TypeScript SDK.
+"""
+
+from __future__ import annotations
+
+import secrets
+import time
+
+_ALPHABET = "0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+_EPOCH_MS = 1577836800000  # 2020-01-01T00:00:00Z
+
+
+def generate() -> str:
+    """A new TSID."""
+    ms = int(time.time() * 1000) - _EPOCH_MS
+    value = (ms << 22) | secrets.randbits(22)
+    return encode(value)
+
+
+def generate_prefixed(prefix: str) -> str:
+    """A new TSID as "<prefix>_<tsid>"."""
+    return f"{prefix}_{generate()}"
+
+
+def encode(value: int) -> str:
+    """The 13-character Crockford base32 form of a 64-bit value."""
+    out = []
+    for _ in range(13):
+        out.append(_ALPHABET[value & 31])
+        value >>= 5
+    return "".join(reversed(out))
@@ -0,0 +1,290 @@
+"""Webhook signature verification and payload decoding for receivers.
+
+The signature is HMAC-SHA256(secret, timestamp || body) as lowercase hex,
+the same as the router signs and pkg/fcsdk/webhook verifies. Always
+verify over the raw request body, before parsing it — re-serialising JSON
+changes the bytes.
+
+>>> verifier = Verifier(secret)
+>>> verifier.verify(body, headers[SIGNATURE_HEADER], headers[TIMESTAMP_HEADER])
+>>> for msg in decode(headers, body):
+...     handle(msg.job_id, msg.data)
+"""
+
+from __future__ import annotations
+
+import hashlib
+import hmac
+import json
+from collections.abc import Mapping
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from email.message import Message as _MIMEHeader
+from typing import Any
+
+SIGNATURE_HEADER = "X-FLOWCATALYST-SIGNATURE"
+TIMESTAMP_HEADER = "X-FLOWCATALYST-TIMESTAMP"
+# Names the client signing key a delivery was signed with; only set when
+# the platform signs with a per-client key. See KeyRing.
+KEY_ID_HEADER = "X-FLOWCATALYST-KEY-ID"
+
+# Delivery headers set next to the signature.
+JOB_ID_HEADER = "X-Dispatch-Job-Id"
+EVENT_TYPE_HEADER = "X-Event-Type"
+JOB_IDS_HEADER = "X-Dispatch-Job-Ids"
+BATCH_SIZE_HEADER = "X-Dispatch-Batch-Size"
+
+DEFAULT_MAX_CLOCK_SKEW = 300.0
+
+# The protobuf message of a batched PROTOBUF delivery
+# (api/delivery/batch.proto).
+_BATCH_MESSAGE_TYPE = "flowcatalyst.delivery.v1.Batch"
+
+
+class WebhookError(Exception):
+    """Base class for verification and decoding failures."""
+
+
+class MissingSignature(WebhookError):
+    def __init__(self) -> None:
+        super().__init__("webhook: missing signature header")
+
+
+class MissingTimestamp(WebhookError):
+    def __init__(self) -> None:
+        super().__init__("webhook: missing timestamp header")
+
+
+class BadTimestamp(WebhookError):
+    def __init__(self) -> None:
+        super().__init__("webhook: malformed timestamp")
+
+
+class StaleTimestamp(WebhookError):
+    def __init__(self) -> None:
+        super().__init__("webhook: timestamp outside clock skew tolerance")
+
+
+class BadSignature(WebhookError):
+    def __init__(self) -> None:
+        super().__init__("webhook: signature does not match")
+
+
+class UnknownKeyID(WebhookError):
+    def __init__(self) -> None:
+        super().__init__("webhook: signing key id not recognised")
+
+
+class UnknownFormat(WebhookError):
+    """The Content-Type is not one a payload format produces."""
+
+
+def sign(secret: str, timestamp: str, body: bytes) -> str:
+    """The hex signature for body at timestamp."""
+    mac = hmac.new(secret.encode(), timestamp.encode(), hashlib.sha256)
+    mac.update(body)
+    return mac.hexdigest()
+
+
+def _parse_timestamp(timestamp: str) -> datetime:
+    # The router sends millisecond precision ("2026-05-24T08:30:00.123Z");
+    # any RFC 3339 time is accepted.
+    try:
+        ts = datetime.fromisoformat(timestamp.replace("Z", "+00:00"))
+    except ValueError:
+        raise BadTimestamp() from None
+    if ts.tzinfo is None:
+        raise BadTimestamp()
+    return ts
+
+
+class Verifier:
+    """Validates inbound webhook signatures against one secret.
+
+    ``max_clock_skew`` (seconds) is how far the timestamp may be from now;
+    0 disables the check.
+    """
+
+    def __init__(self, secret: str, *, max_clock_skew: float = DEFAULT_MAX_CLOCK_SKEW) -> None:
+        self._secret = secret
+        self.max_clock_skew = max_clock_skew
+
+    def verify(self, body: bytes, signature: str | None, timestamp: str | None) -> None:
+        """Raise a WebhookError unless signature is valid for body."""
+        if not signature:
+            raise MissingSignature()
+        if not timestamp:
+            raise MissingTimestamp()
+        ts = _parse_timestamp(timestamp)
+        if self.max_clock_skew > 0:
+            delta = abs((datetime.now(timezone.utc) - ts).total_seconds())
+            if delta > self.max_clock_skew:
+                raise StaleTimestamp()
+        if not hmac.compare_digest(sign(self._secret, timestamp, body), signature):
+            raise BadSignature()
+
+
+class KeyRing:
+    """Verifies deliveries signed with per-client signing keys, where more
+    than one key is valid while a rotation overlaps. Load it with every key
+    the platform lists as not yet expired, keyed by key id."""
+
+    def __init__(self, keys: Mapping[str, str], *, max_clock_skew: float = DEFAULT_MAX_CLOCK_SKEW) -> None:
+        self._keys = dict(keys)
+        self.max_clock_skew = max_clock_skew
+
+    def verify(self, body: bytes, signature: str | None, timestamp: str | None, key_id: str | None = None) -> None:
+        """Check against the key named by key_id (the X-FLOWCATALYST-KEY-ID
+        header). When key_id is empty or unknown every key is tried, so a
+        receiver mid-rotation keeps working before it learns the new id."""
+        if key_id and key_id in self._keys:
+            Verifier(self._keys[key_id], max_clock_skew=self.max_clock_skew).verify(body, signature, timestamp)
+            return
+        for secret in self._keys.values():
+            try:
+                Verifier(secret, max_clock_skew=self.max_clock_skew).verify(body, signature, timestamp)
+                return
+            except BadSignature:
+                continue
+        if key_id:
+            raise UnknownKeyID()
+        raise BadSignature()
+
+
+@dataclass
+class Message:
+    """One dispatch job of a delivery, whatever the payload format."""
+
+    job_id: str = ""
+    # Empty for a JSON or NDJSON batch, whose elements carry the type only
+    # when not data-only.
+    event_type: str = ""
+    # The job's body: its JSON (one NDJSON line likewise), a CloudEvent's
+    # data, or the protobuf message named by message_type.
+    data: bytes = b""
+    message_type: str = ""
+    # The whole event; CLOUDEVENTS_JSON only.
+    cloud_event: dict[str, Any] | None = None
+
+
+def _header(headers: Mapping[str, str], name: str) -> str:
+    lower = name.lower()
+    for key, value in headers.items():
+        if key.lower() == lower:
+            return value
+    return ""
+
+
+def decode(headers: Mapping[str, str], body: bytes) -> list[Message]:
+    """Split a verified delivery into its jobs, reading the format off the
+    Content-Type header: one Message for a single-job delivery, one per job
+    for a batch."""
+    parsed = _MIMEHeader()
+    parsed["Content-Type"] = _header(headers, "Content-Type")
+    media_type = parsed.get_content_type() if parsed["Content-Type"] else ""
+    message_type = parsed.get_param("messagetype") or ""
+    batched = _header(headers, BATCH_SIZE_HEADER) != ""
+    job_id, event_type = _header(headers, JOB_ID_HEADER), _header(headers, EVENT_TYPE_HEADER)
+
+    if media_type == "application/json":
+        if not batched:
+            return [Message(job_id=job_id, event_type=event_type, data=body)]
+        try:
+            items = json.loads(body)
+        except ValueError as e:
+            raise WebhookError(f"webhook: JSON batch: {e}") from e
+        # Elements are re-encoded compactly; verify over body, not these.
+        msgs = [Message(data=json.dumps(it, separators=(",", ":")).encode()) for it in items]
+    elif media_type == "application/x-ndjson":
+        msgs = [Message(data=line) for line in body.split(b"\n") if line.strip()]
+    elif media_type in ("application/cloudevents+json", "application/cloudevents-batch+json"):
+        try:
+            events = json.loads(body)
+        except ValueError as e:
+            raise WebhookError(f"webhook: CloudEvents: {e}") from e
+        if media_type == "application/cloudevents+json":
+            events = [events]
+        return [
+            Message(
+                job_id=ce.get("dispatchjobid", ""),
+                event_type=ce.get("type", ""),
+                data=json.dumps(ce["data"], separators=(",", ":")).encode() if "data" in ce else b"",
+                cloud_event=ce,
+            )
+            for ce in events
+        ]
+    elif media_type == "application/x-protobuf":
+        if message_type == _BATCH_MESSAGE_TYPE:
+            return _decode_proto_batch(body)
+        return [Message(job_id=job_id, event_type=event_type, data=body, message_type=message_type)]
+    else:
+        raise UnknownFormat(f"webhook: unknown payload format: {media_type}")
+
+    # A JSON or NDJSON batch's ids come in a header, in body order.
+    ids = _header(headers, JOB_IDS_HEADER).split(",")
+    if len(ids) == len(msgs):
+        for msg, id_ in zip(msgs, ids):
+            msg.job_id = id_
+    if len(msgs) == 1 and job_id:
+        msgs[0].job_id, msgs[0].event_type = job_id, event_type
+    return msgs
+
+
+def _decode_proto_batch(body: bytes) -> list[Message]:
+    """Read a flowcatalyst.delivery.v1.Batch without a protobuf runtime."""
+    msgs = []
+    try:
+        for num, item in _proto_fields(body):
+            if num != 1:  # items
+                continue
+            msg = Message()
+            for field, value in _proto_fields(item):
+                if field == 1:
+                    msg.job_id = value.decode()
+                elif field == 2:
+                    msg.event_type = value.decode()
+                elif field == 3:
+                    msg.message_type = value.decode()
+                elif field == 4:
+                    msg.data = value
+            msgs.append(msg)
+    except ValueError as e:
+        raise WebhookError(f"webhook: protobuf batch: {e}") from e
+    return msgs
+
+
+def _uvarint(b: bytes, i: int) -> tuple[int, int]:
+    out = shift = 0
+    while i < len(b):
+        c = b[i]
+        i += 1
+        out |= (c & 0x7F) << shift
+        if c < 0x80:
+            return out, i
+        shift += 7
+    raise ValueError("truncated varint")
+
+
+def _proto_fields(b: bytes):
+    """Yield (number, bytes) for every length-delimited field in b,
+    skipping the other wire types."""
+    i = 0
+    while i < len(b):
+        tag, i = _uvarint(b, i)
+        wire = tag & 7
+        if wire == 0:
+            _, i = _uvarint(b, i)
+        elif wire == 1:
+            i += 8
+        elif wire == 5:
+            i += 4
+        elif wire == 2:
+            n, i = _uvarint(b, i)
+            if i + n > len(b):
+                raise ValueError("truncated field")
+            yield tag >> 3, b[i:i + n]
+            i += n
+        else:
+            raise ValueError(f"unsupported wire type {wire}")
+        if i > len(b):
+            raise ValueError("truncated field")
@@ -0,0 +1,53 @@
+import json
+
+import httpx
+import pytest
+
+from flowcatalyst import APIError, FlowCatalystClient, models
+
+
+def _client(handler, **kw) -> FlowCatalystClient:
+    return FlowCatalystClient(
+        "http://fc.test/",
+        http_client=httpx.Client(transport=httpx.MockTransport(handler)),
+        retry_delay=0,
+        **kw,
+    )
+
+
+def test_request_and_response():
+    seen = []
+
+    def handler(req: httpx.Request) -> httpx.Response:
+        seen.append(req)
+        return httpx.Response(200, json={
+            "dryRun": True,
+            "onConflict": "skip",
+            "items": [],
+            "summary": {"conflict": 0, "create": 1, "invalid": 0, "unchanged": 0, "update": 0},
+        })
+
+    fc = _client(handler, token="t0k")
+    out = fc.import_configuration(models.Bundle(version=1), dry_run=True)
+    assert isinstance(out, models.ImportResponse) and out.summary.create == 1
+
+    req = seen[0]
+    assert req.url.path == "/api/admin/import"
+    assert req.url.params == httpx.QueryParams({"dryRun": "true"}), "unset params are dropped"
+    assert req.headers["Authorization"] == "Bearer t0k"
+    assert json.loads(req.content) == {"version": 1}, "only fields the caller set are sent"
+
+
+def test_api_error_and_retry():
+    statuses = [503, 404]
+
+    def handler(req: httpx.Request) -> httpx.Response:
+        status = statuses.pop(0)
+        return httpx.Response(status, json={"error": "NOT_FOUND", "message": "no such request"})
+
+    with pytest.raises(APIError) as exc:
+        _client(handler).get_erasure_request("er_1")
+    assert statuses == [], "the 503 was retried"
+    err = exc.value
+    assert err.is_not_found and not err.retryable
+    assert (err.code, err.message) == ("NOT_FOUND", "no such request")
@@ -0,0 +1,31 @@
+import subprocess
+import sys
+from pathlib import Path
+
+from flowcatalyst.generated import models
+from flowcatalyst.generated.operations import Operations
+
+ROOT = Path(__file__).resolve().parent.parent
+
+
+def test_generated_code_matches_spec():
+    # The committed client must be regenerated whenever openapi/openapi.json moves.
+    result = subprocess.run([sys.executable, "scripts/generate.py", "--check"], cwd=ROOT, capture_output=True, text=True)
+    assert result.returncode == 0, result.stderr
+
+
+def test_models_alias_camel_case():
+    err = models.ErrorModel.model_validate({"error": "NOT_FOUND", "message": "gone", "traceId": "t1"})
+    assert err.error == "NOT_FOUND"
+    assert err.model_extra == {"traceId": "t1"}, "unknown fields are kept"
+
+
+def test_operations_call_request():
+    calls = []
+
+    class Recorder(Operations):
+        def _request(self, method, path, **kw):
+            calls.append((method, path, kw))
+
+    Recorder().get_erasure_request("a/b")
+    assert calls == [("GET", "/api/admin/privacy/erasure/a%2Fb", {"response": models.ErasureRequestResponse})]
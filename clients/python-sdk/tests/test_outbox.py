@@ -0,0 +1,98 @@
+import json
+import re
+import sqlite3
+from datetime import datetime, timezone
+
+from flowcatalyst.outbox import (
+    CreateAuditLogDto,
+    CreateDispatchJobDto,
+    CreateEventDto,
+    DbApiOutboxDriver,
+    OutboxManager,
+    OutboxStatus,
+)
+from flowcatalyst.outbox import tsid
+
+
+def _db() -> sqlite3.Connection:
+    conn = sqlite3.connect(":memory:")
+    conn.execute(
+        "CREATE TABLE outbox_messages (id TEXT PRIMARY KEY, type TEXT, message_group TEXT, payload TEXT,"
+        " status INTEGER, created_at TEXT, updated_at TEXT, client_id TEXT, payload_size INTEGER, headers TEXT)"
+    )
+    return conn
+
+
+def test_tsid():
+    a, b = tsid.generate(), tsid.generate()
+    assert re.fullmatch(r"[0-9A-HJKMNP-TV-Z]{13}", a)
+    assert a[:8] <= b[:8], "ids sort by creation time"
+    assert tsid.encode(0) == "0000000000000"
+    assert tsid.generate_prefixed("evt").startswith("evt_")
+
+
+def test_create_event():
+    conn = _db()
+    outbox = OutboxManager(DbApiOutboxDriver(conn, placeholder="?"), client_id="0HZXEQ5Y8JY5Z")
+    event = CreateEventDto(
+        "orders:order:created",
+        {"orderId": "123"},
+        message_group="order-123",
+        headers={"X-Tenant": "acme"},
+    )
+    id_ = outbox.create_event(event)
+    conn.commit()
+
+    row = conn.execute("SELECT type, message_group, payload, status, client_id, payload_size, headers FROM outbox_messages WHERE id = ?", (id_,)).fetchone()
+    type_, group, payload, status, client_id, size, headers = row
+    assert (type_, group, status, client_id) == ("EVENT", "order-123", OutboxStatus.PENDING, "0HZXEQ5Y8JY5Z")
+    assert size == len(payload.encode())
+    assert json.loads(headers) == {"X-Tenant": "acme"}
+    assert json.loads(payload) == {
+        "specVersion": "1.0",
+        "type": "orders:order:created",
+        "data": '{"orderId": "123"}',
+        "messageGroup": "order-123",
+    }
+
+
+def test_batch_joins_caller_transaction():
+    conn = _db()
+    outbox = OutboxManager(DbApiOutboxDriver(conn, placeholder="?"), client_id="c1")
+    ids = outbox.create_dispatch_jobs(
+        [
+            CreateDispatchJobDto("app", "sync", "https://x/hook", {"a": 1}, "pool1"),
+            CreateDispatchJobDto(
+                "app", "sync", "https://x/hook", "raw", "pool1",
+                scheduled_for=datetime(2026, 3, 1, 9, 0, tzinfo=timezone.utc),
+            ),
+        ],
+        tx=conn.cursor(),
+    )
+    conn.rollback()
+    assert conn.execute("SELECT COUNT(*) FROM outbox_messages").fetchone()[0] == 0, "rolled back with the caller"
+    assert len(ids) == 2 and ids[0] != ids[1]
+
+    payload = CreateDispatchJobDto(
+        "app", "sync", "https://x/hook", "raw", "pool1",
+        scheduled_for=datetime(2026, 3, 1, 9, 0, tzinfo=timezone.utc),
+    ).to_payload()
+    assert payload["payload"] == "raw"
+    assert payload["scheduledFor"] == "2026-03-01T09:00:00.000Z"
+    assert payload["dataOnly"] is True and payload["timeoutSeconds"] == 30 and payload["maxRetries"] == 5
+    assert "metadata" not in payload
+
+
+def test_audit_log_payload():
+    payload = CreateAuditLogDto(
+        "Order", "123", "CREATE",
+        operation_data={"total": 10},
+        performed_at=datetime(2026, 3, 1, 9, 0, 0, 123000, tzinfo=timezone.utc),
+    ).to_payload()
+    assert payload == {
+        "entityType": "Order",
+        "entityId": "123",
+        "operation": "CREATE",
+        "operationData": '{"total": 10}',
+        "performedAt": "2026-03-01T09:00:00.123Z",
+    }
@@ -0,0 +1,100 @@
+import hashlib
+import hmac
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from flowcatalyst import webhook
+
+
+def _now() -> str:
+    return datetime.now(timezone.utc).isoformat(timespec="milliseconds").replace("+00:00", "Z")
+
+
+def test_sign_matches_router_algorithm():
+    # HMAC-SHA256(secret, timestamp_bytes || body_bytes) -> hex
+    secret, ts, body = "test-secret-do-not-use-in-prod", "2026-05-24T08:30:00.123Z", b'{"messageId":"msg_TEST123456"}'
+    want = hmac.new(secret.encode(), ts.encode() + body, hashlib.sha256).hexdigest()
+    assert webhook.sign(secret, ts, body) == want
+
+
+def test_verify():
+    body, ts = b'{"ok":true}', _now()
+    v = webhook.Verifier("s3cret")
+    v.verify(body, webhook.sign("s3cret", ts, body), ts)
+
+    with pytest.raises(webhook.BadSignature):
+        v.verify(b'{"ok":false}', webhook.sign("s3cret", ts, body), ts)
+    with pytest.raises(webhook.MissingSignature):
+        v.verify(body, "", ts)
+    with pytest.raises(webhook.MissingTimestamp):
+        v.verify(body, "abc", None)
+    with pytest.raises(webhook.BadTimestamp):
+        v.verify(body, "abc", "yesterday")
+
+    old = (datetime.now(timezone.utc) - timedelta(minutes=10)).isoformat()
+    with pytest.raises(webhook.StaleTimestamp):
+        v.verify(body, webhook.sign("s3cret", old, body), old)
+    webhook.Verifier("s3cret", max_clock_skew=0).verify(body, webhook.sign("s3cret", old, body), old)
+
+
+def test_key_ring_rotation():
+    ring = webhook.KeyRing({"k1": "old", "k2": "new"})
+    body, ts = b"{}", _now()
+    sig = webhook.sign("new", ts, body)
+
+    ring.verify(body, sig, ts, "k2")
+    ring.verify(body, sig, ts)  # no key id: every key is tried
+    ring.verify(body, sig, ts, "k3")  # an id the ring hasn't learned yet
+    with pytest.raises(webhook.BadSignature):
+        ring.verify(body, sig, ts, "k1")
+    with pytest.raises(webhook.UnknownKeyID):
+        ring.verify(body, webhook.sign("other", ts, body), ts, "k3")
+
+
+def test_decode_formats():
+    single = webhook.decode(
+        {"content-type": "application/json", "X-Dispatch-Job-Id": "j1", "X-Event-Type": "orders:order:created"},
+        b'{"a":1}',
+    )
+    assert single == [webhook.Message(job_id="j1", event_type="orders:order:created", data=b'{"a":1}')]
+
+    batch = webhook.decode(
+        {"Content-Type": "application/json", "X-Dispatch-Batch-Size": "2", "X-Dispatch-Job-Ids": "j1,j2"},
+        b'[{"a":1},{"a":2}]',
+    )
+    assert [(m.job_id, m.data) for m in batch] == [("j1", b'{"a":1}'), ("j2", b'{"a":2}')]
+
+    ndjson = webhook.decode(
+        {"Content-Type": "application/x-ndjson", "X-Dispatch-Batch-Size": "2", "X-Dispatch-Job-Ids": "j1,j2"},
+        b'{"a":1}\n{"a":2}\n',
+    )
+    assert [(m.job_id, m.data) for m in ndjson] == [("j1", b'{"a":1}'), ("j2", b'{"a":2}')]
+
+    ce = webhook.decode(
+        {"Content-Type": "application/cloudevents+json"},
+        b'{"specversion":"1.0","id":"e1","type":"t","source":"s","dispatchjobid":"j1","data":{"a":1}}',
+    )
+    assert ce[0].job_id == "j1" and ce[0].event_type == "t" and ce[0].data == b'{"a":1}'
+    assert ce[0].cloud_event["id"] == "e1"
+
+    with pytest.raises(webhook.UnknownFormat):
+        webhook.decode({"Content-Type": "text/plain"}, b"")
+
+
+def _field(num: int, value: bytes) -> bytes:
+    return bytes([num << 3 | 2, len(value)]) + value
+
+
+def test_decode_protobuf_batch():
+    item = _field(1, b"j1") + _field(2, b"t") + _field(3, b"acme.Order") + _field(4, b"\x08\x01")
+    body = _field(1, item) + bytes([2 << 3, 7])  # plus an unknown varint field
+    msgs = webhook.decode(
+        {"Content-Type": "application/x-protobuf; messagetype=flowcatalyst.delivery.v1.Batch"}, body
+    )
+    assert msgs == [webhook.Message(job_id="j1", event_type="t", data=b"\x08\x01", message_type="acme.Order")]
+
+    with pytest.raises(webhook.WebhookError):
+        webhook.decode(
+            {"Content-Type": "application/x-protobuf; messagetype=flowcatalyst.delivery.v1.Batch"}, b"\x0a\x05ab"
+        )
@@ -29,6 +29,8 @@ export const OutboxStatus = {
 	FORBIDDEN: 5,
 	/** API returned 502/503/504 Gateway Error (retryable). */
 	GATEWAY_ERROR: 6,
+	/** Payload expiresAt passed before delivery; never sent (permanent). */
+	EXPIRED: 7,
 	/** Currently being processed - crash recovery marker. */
 	IN_PROGRESS: 9,
 } as const;
@@ -0,0 +1,276 @@
+package main
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+	"sync"
+	"time"
+)
+
+// apiClient is a thin JSON client for one FlowCatalyst HTTP surface. auth
+// decorates every request (BasicAuth for the router, bearer for the
+// platform).
+type apiClient struct {
+	base   string
+	auth   func(ctx context.Context, req *http.Request) error
+	client *http.Client
+}
+
+func newRouterClient(p Profile) *apiClient {
+	return &apiClient{
+		base: strings.TrimRight(p.RouterURL, "/"),
+		auth: func(_ context.Context, req *http.Request) error {
+			if p.RouterUser != "" {
+				req.SetBasicAuth(p.RouterUser, p.RouterPassword)
+			}
+			return nil
+		},
+		client: &http.Client{Timeout: 30 * time.Second},
+	}
+}
+
+func newPlatformClient(p Profile) *apiClient {
+	base := strings.TrimRight(p.PlatformURL, "/")
+	var tokens *tokenSource
+	if p.Token == "" && p.ClientID != "" {
+		tokens = &tokenSource{
+			tokenURL:     base + "/oauth/token",
+			clientID:     p.ClientID,
+			clientSecret: p.ClientSecret,
+			client:       &http.Client{Timeout: 15 * time.Second},
+		}
+	}
+	return &apiClient{
+		base: base,
+		auth: func(ctx context.Context, req *http.Request) error {
+			tok := p.Token
+			if tokens != nil {
+				var err error
+				if tok, err = tokens.Token(ctx); err != nil {
+					return err
+				}
+			}
+			if tok != "" {
+				req.Header.Set("Authorization", "Bearer "+tok)
+			}
+			return nil
+		},
+		client: &http.Client{Timeout: 30 * time.Second},
+	}
+}
+
+// apiError is a non-2xx response, rendered from the huma problem body when
+// there is one.
+type apiError struct {
+	Method string
+	Path   string
+	Status int
+	Detail string
+}
+
+func (e *apiError) Error() string {
+	if e.Detail == "" {
+		return fmt.Sprintf("%s %s: %d %s", e.Method, e.Path, e.Status, http.StatusText(e.Status))
+	}
+	return fmt.Sprintf("%s %s: %d %s", e.Method, e.Path, e.Status, e.Detail)
+}
+
+// do sends a JSON request and decodes a JSON response into out (when
+// non-nil). query may be nil.
+func (c *apiClient) do(ctx context.Context, method, path string, query url.Values, in, out any) error {
+	resp, err := c.send(ctx, method, path, query, in, "application/json")
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	body, err := io.ReadAll(io.LimitReader(resp.Body, 32<<20))
+	if err != nil {
+		return fmt.Errorf("%s %s: read response: %w", method, path, err)
+	}
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return &apiError{Method: method, Path: path, Status: resp.StatusCode, Detail: problemDetail(body)}
+	}
+	if out == nil || len(bytes.TrimSpace(body)) == 0 {
+		return nil
+	}
+	if err := json.Unmarshal(body, out); err != nil {
+		return fmt.Errorf("%s %s: decode response: %w", method, path, err)
+	}
+	return nil
+}
+
+func (c *apiClient) send(ctx context.Context, method, path string, query url.Values, in any, accept string) (*http.Response, error) {
+	target := c.base + path
+	if len(query) > 0 {
+		target += "?" + query.Encode()
+	}
+	var body io.Reader
+	if in != nil {
+		data, err := json.Marshal(in)
+		if err != nil {
+			return nil, err
+		}
+		body = bytes.NewReader(data)
+	}
+	req, err := http.NewRequestWithContext(ctx, method, target, body)
+	if err != nil {
+		return nil, err
+	}
+	if in != nil {
+		req.Header.Set("Content-Type", "application/json")
+	}
+	req.Header.Set("Accept", accept)
+	if err := c.auth(ctx, req); err != nil {
+		return nil, fmt.Errorf("authenticate: %w", err)
+	}
+	resp, err := c.client.Do(req)
+	if err != nil {
+		return nil, fmt.Errorf("%s %s: %w", method, path, err)
+	}
+	return resp, nil
+}
+
+// problemDetail pulls the human-readable message out of an RFC 9457
+// problem body (huma's error shape), falling back to the raw text.
+func problemDetail(body []byte) string {
+	var p struct {
+		Title  string `json:"title"`
+		Detail string `json:"detail"`
+	}
+	if json.Unmarshal(body, &p) == nil {
+		if p.Detail != "" {
+			return p.Detail
+		}
+		if p.Title != "" {
+			return p.Title
+		}
+	}
+	s := strings.TrimSpace(string(body))
+	if len(s) > 300 {
+		s = s[:300] + "…"
+	}
+	return s
+}
+
+// stream opens a Server-Sent Events feed and calls fn for each event until
+// ctx is cancelled, the server closes the stream, or fn returns an error.
+// The client timeout is bypassed — streams are long-lived.
+func (c *apiClient) stream(ctx context.Context, path string, query url.Values, fn func(event, data string) error) error {
+	streaming := *c
+	streaming.client = &http.Client{Transport: c.client.Transport}
+	resp, err := streaming.send(ctx, http.MethodGet, path, query, nil, "text/event-stream")
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		body, _ := io.ReadAll(io.LimitReader(resp.Body, 64<<10))
+		return &apiError{Method: http.MethodGet, Path: path, Status: resp.StatusCode, Detail: problemDetail(body)}
+	}
+	err = readSSE(resp.Body, fn)
+	if ctx.Err() != nil {
+		return nil
+	}
+	return err
+}
+
+// readSSE parses an event stream: `event:` / `data:` fields accumulate
+// until a blank line dispatches them; `:` comment lines (heartbeats) are
+// skipped. Multi-line data is joined with "\n" per the SSE spec.
+func readSSE(r io.Reader, fn func(event, data string) error) error {
+	sc := bufio.NewScanner(r)
+	sc.Buffer(make([]byte, 64<<10), 1<<20)
+	var event string
+	var data []string
+	for sc.Scan() {
+		line := sc.Text()
+		switch {
+		case line == "":
+			if len(data) > 0 {
+				if event == "" {
+					event = "message"
+				}
+				if err := fn(event, strings.Join(data, "\n")); err != nil {
+					return err
+				}
+			}
+			event, data = "", nil
+		case strings.HasPrefix(line, ":"):
+			// comment / heartbeat
+		default:
+			field, value, _ := strings.Cut(line, ":")
+			value = strings.TrimPrefix(value, " ")
+			switch field {
+			case "event":
+				event = value
+			case "data":
+				data = append(data, value)
+			}
+		}
+	}
+	return sc.Err()
+}
+
+// tokenSource mints a platform access token via the OAuth
+// client_credentials grant and caches it until shortly before expiry.
+type tokenSource struct {
+	tokenURL     string
+	clientID     string
+	clientSecret string
+	client       *http.Client
+
+	mu     sync.Mutex
+	cached string
+	expiry time.Time
+}
+
+func (s *tokenSource) Token(ctx context.Context) (string, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if s.cached != "" && time.Now().Before(s.expiry.Add(-30*time.Second)) {
+		return s.cached, nil
+	}
+
+	form := url.Values{}
+	form.Set("grant_type", "client_credentials")
+	form.Set("client_id", s.clientID)
+	form.Set("client_secret", s.clientSecret)
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, s.tokenURL, strings.NewReader(form.Encode()))
+	if err != nil {
+		return "", err
+	}
+	req.Header.Set("Content-Type", "application/x-www-form-urlencoded")
+	req.Header.Set("Accept", "application/json")
+	resp, err := s.client.Do(req)
+	if err != nil {
+		return "", fmt.Errorf("token request: %w", err)
+	}
+	defer resp.Body.Close()
+	body, _ := io.ReadAll(io.LimitReader(resp.Body, 1<<20))
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return "", fmt.Errorf("token endpoint %s: %s", resp.Status, problemDetail(body))
+	}
+	var out struct {
+		AccessToken string `json:"access_token"`
+		ExpiresIn   int64  `json:"expires_in"`
+	}
+	if err := json.Unmarshal(body, &out); err != nil {
+		return "", fmt.Errorf("decode token response: %w", err)
+	}
+	if out.AccessToken == "" {
+		return "", fmt.Errorf("token endpoint returned no access_token")
+	}
+	ttl := time.Duration(out.ExpiresIn) * time.Second
+	if ttl <= 0 {
+		ttl = 5 * time.Minute
+	}
+	s.cached, s.expiry = out.AccessToken, time.Now().Add(ttl)
+	return s.cached, nil
+}
@@ -0,0 +1,131 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestReadSSE(t *testing.T) {
+	stream := ": connected\n\n" +
+		"event: warning\nid: w-1\ndata: {\"id\":\"w-1\"}\n\n" +
+		": ping\n\n" +
+		"data: line one\ndata: line two\n\n" +
+		"event: warning\ndata:no-space\n\n"
+
+	var got []string
+	err := readSSE(strings.NewReader(stream), func(event, data string) error {
+		got = append(got, event+"|"+data)
+		return nil
+	})
+	if err != nil {
+		t.Fatalf("readSSE: %v", err)
+	}
+	want := []string{`warning|{"id":"w-1"}`, "message|line one\nline two", "warning|no-space"}
+	if strings.Join(got, ";") != strings.Join(want, ";") {
+		t.Fatalf("events = %q, want %q", got, want)
+	}
+
+	stop := errors.New("stop")
+	if err := readSSE(strings.NewReader(stream), func(string, string) error { return stop }); !errors.Is(err, stop) {
+		t.Fatalf("callback error not propagated: %v", err)
+	}
+}
+
+func TestConfigResolveAndSave(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "nested", "config.yaml")
+	cfg, err := loadConfig(path)
+	if err != nil {
+		t.Fatalf("load missing: %v", err)
+	}
+	name, p, err := cfg.resolve("")
+	if err != nil || name != defaultProfile || p.PlatformURL != defaultPlatformURL || p.RouterURL != defaultRouterURL {
+		t.Fatalf("implicit default: %q %+v %v", name, p, err)
+	}
+	if _, _, err := cfg.resolve("prod"); err == nil {
+		t.Fatal("explicit unknown profile should fail")
+	}
+
+	cfg.Profiles["prod"] = Profile{PlatformURL: "https://fc.example.com", Token: "t"}
+	cfg.Current = "prod"
+	if err := cfg.save(path); err != nil {
+		t.Fatalf("save: %v", err)
+	}
+	info, err := os.Stat(path)
+	if err != nil {
+		t.Fatalf("stat: %v", err)
+	}
+	if info.Mode().Perm() != 0o600 {
+		t.Fatalf("config mode = %v, want 0600", info.Mode().Perm())
+	}
+
+	cfg, err = loadConfig(path)
+	if err != nil {
+		t.Fatalf("reload: %v", err)
+	}
+	name, p, err = cfg.resolve("")
+	if err != nil || name != "prod" || p.PlatformURL != "https://fc.example.com" || p.Token != "t" || p.RouterURL != defaultRouterURL {
+		t.Fatalf("current profile: %q %+v %v", name, p, err)
+	}
+}
+
+func TestRouterClientAuthAndErrors(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if u, pw, ok := r.BasicAuth(); !ok || u != "ops" || pw != "pw" {
+			w.WriteHeader(http.StatusUnauthorized)
+			return
+		}
+		w.Header().Set("Content-Type", "application/problem+json")
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = w.Write([]byte(`{"title":"Not Found","status":404,"detail":"Warning not found: w-9"}`))
+	}))
+	defer srv.Close()
+
+	c := newRouterClient(Profile{RouterURL: srv.URL + "/", RouterUser: "ops", RouterPassword: "pw"})
+	err := c.do(context.Background(), http.MethodPost, "/warnings/w-9/acknowledge", nil, nil, nil)
+	var apiErr *apiError
+	if !errors.As(err, &apiErr) || apiErr.Status != http.StatusNotFound || apiErr.Detail != "Warning not found: w-9" {
+		t.Fatalf("err = %v", err)
+	}
+}
+
+func TestPlatformClientMintsAndCachesToken(t *testing.T) {
+	mints := 0
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/oauth/token":
+			mints++
+			if r.FormValue("grant_type") != "client_credentials" || r.FormValue("client_id") != "svc" {
+				w.WriteHeader(http.StatusBadRequest)
+				return
+			}
+			_, _ = w.Write([]byte(`{"access_token":"minted","expires_in":3600}`))
+		case "/api/dispatch-pools":
+			if r.Header.Get("Authorization") != "Bearer minted" {
+				w.WriteHeader(http.StatusUnauthorized)
+				return
+			}
+			_, _ = w.Write([]byte(`{"pools":[{"id":"dp_1","code":"orders","status":"ACTIVE"}],"total":1}`))
+		}
+	}))
+	defer srv.Close()
+
+	c := newPlatformClient(Profile{PlatformURL: srv.URL, ClientID: "svc", ClientSecret: "s"})
+	for range 2 {
+		p, err := findPool(context.Background(), c, "ORDERS")
+		if err != nil || p.ID != "dp_1" {
+			t.Fatalf("findPool: %+v %v", p, err)
+		}
+	}
+	if mints != 1 {
+		t.Fatalf("token minted %d times, want 1", mints)
+	}
+	if _, err := findPool(context.Background(), c, "missing"); err == nil {
+		t.Fatal("unknown pool should fail")
+	}
+}
@@ -0,0 +1,325 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+)
+
+// Platform wire shapes (dispatchpool/api and dispatchjob/api DTOs).
+type (
+	dispatchPool struct {
+		ID          string `json:"id"`
+		Code        string `json:"code"`
+		Name        string `json:"name"`
+		Concurrency int32  `json:"concurrency"`
+		RateLimit   *int32 `json:"rateLimit,omitempty"`
+		Status      string `json:"status"`
+	}
+	dispatchJob struct {
+		ID           string  `json:"id"`
+		Code         string  `json:"code"`
+		Status       string  `json:"status"`
+		TargetURL    string  `json:"targetUrl"`
+		ClientID     *string `json:"clientId,omitempty"`
+		AttemptCount int32   `json:"attemptCount"`
+		CreatedAt    string  `json:"createdAt"`
+	}
+	dispatchAttempt struct {
+		AttemptNumber  int32   `json:"attemptNumber"`
+		AttemptedAt    string  `json:"attemptedAt"`
+		DurationMillis *int64  `json:"durationMillis,omitempty"`
+		ResponseCode   *int    `json:"responseCode,omitempty"`
+		Success        bool    `json:"success"`
+		ErrorMessage   *string `json:"errorMessage,omitempty"`
+	}
+)
+
+func newPoolsCmd(a *app) *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "pools",
+		Short: "List, pause and resume dispatch pools",
+	}
+
+	var status string
+	list := &cobra.Command{
+		Use:   "list",
+		Short: "List dispatch pools",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			pools, err := listPools(cmd.Context(), a.platform(), status)
+			if err != nil {
+				return err
+			}
+			if a.jsonOutput {
+				return printJSON(cmd.OutOrStdout(), pools)
+			}
+			tw := newTable(cmd.OutOrStdout())
+			fmt.Fprintln(tw, "ID\tCODE\tSTATUS\tCONCURRENCY\tRATE/MIN\tNAME")
+			for _, p := range pools {
+				rate := "-"
+				if p.RateLimit != nil {
+					rate = strconv.Itoa(int(*p.RateLimit))
+				}
+				fmt.Fprintf(tw, "%s\t%s\t%s\t%d\t%s\t%s\n", p.ID, p.Code, p.Status, p.Concurrency, rate, p.Name)
+			}
+			return tw.Flush()
+		},
+	}
+	list.Flags().StringVar(&status, "status", "", "ACTIVE, SUSPENDED or ARCHIVED")
+
+	cmd.AddCommand(list,
+		poolActionCmd(a, "pause", "suspend", "Suspend dispatch into a pool (queued jobs wait)", "paused"),
+		poolActionCmd(a, "resume", "activate", "Resume a paused pool", "resumed"))
+	return cmd
+}
+
+// poolActionCmd builds pause/resume: both take a pool id or code and POST
+// the matching dispatch-pool transition.
+func poolActionCmd(a *app, use, action, short, done string) *cobra.Command {
+	return &cobra.Command{
+		Use:   use + " <id-or-code>",
+		Short: short,
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			pc := a.platform()
+			pool, err := findPool(cmd.Context(), pc, args[0])
+			if err != nil {
+				return err
+			}
+			if err := pc.do(cmd.Context(), http.MethodPost, "/api/dispatch-pools/"+url.PathEscape(pool.ID)+"/"+action, nil, nil, nil); err != nil {
+				return err
+			}
+			fmt.Fprintf(cmd.OutOrStdout(), "%s %s (%s)\n", done, pool.Code, pool.ID)
+			return nil
+		},
+	}
+}
+
+func listPools(ctx context.Context, pc *apiClient, status string) ([]dispatchPool, error) {
+	q := url.Values{}
+	if status != "" {
+		q.Set("status", strings.ToUpper(status))
+	}
+	var out struct {
+		Pools []dispatchPool `json:"pools"`
+	}
+	if err := pc.do(ctx, http.MethodGet, "/api/dispatch-pools", q, nil, &out); err != nil {
+		return nil, err
+	}
+	return out.Pools, nil
+}
+
+// findPool resolves an id or code to a pool. Codes are what operators
+// know; the API addresses pools by id.
+func findPool(ctx context.Context, pc *apiClient, ref string) (dispatchPool, error) {
+	pools, err := listPools(ctx, pc, "")
+	if err != nil {
+		return dispatchPool{}, err
+	}
+	for _, p := range pools {
+		if p.ID == ref || strings.EqualFold(p.Code, ref) {
+			return p, nil
+		}
+	}
+	return dispatchPool{}, fmt.Errorf("no dispatch pool with id or code %q", ref)
+}
+
+type jobFilter struct {
+	status   string
+	clientID string
+	code     string
+	since    string
+	limit    int
+}
+
+func (f *jobFilter) register(cmd *cobra.Command, withStatus bool) {
+	fl := cmd.Flags()
+	if withStatus {
+		fl.StringVar(&f.status, "status", "", "PENDING, QUEUED, PROCESSING, COMPLETED, FAILED, CANCELLED or EXPIRED")
+	}
+	fl.StringVar(&f.clientID, "client", "", "only this client id")
+	fl.StringVar(&f.code, "code", "", "only this event/job code")
+	fl.StringVar(&f.since, "since", "", "only jobs created at or after this RFC3339 time")
+	fl.IntVar(&f.limit, "limit", 50, "max rows (up to 1000)")
+}
+
+func (f *jobFilter) query() url.Values {
+	q := url.Values{}
+	for k, v := range map[string]string{
+		"status":   strings.ToUpper(f.status),
+		"clientId": f.clientID,
+		"code":     f.code,
+		"since":    f.since,
+	} {
+		if v != "" {
+			q.Set(k, v)
+		}
+	}
+	if f.limit > 0 {
+		q.Set("limit", strconv.Itoa(f.limit))
+	}
+	return q
+}
+
+func (a *app) printJobs(cmd *cobra.Command, jobs []dispatchJob) error {
+	if a.jsonOutput {
+		return printJSON(cmd.OutOrStdout(), jobs)
+	}
+	tw := newTable(cmd.OutOrStdout())
+	fmt.Fprintln(tw, "ID\tCREATED\tSTATUS\tATTEMPTS\tCODE\tCLIENT\tTARGET")
+	for _, j := range jobs {
+		fmt.Fprintf(tw, "%s\t%s\t%s\t%d\t%s\t%s\t%s\n",
+			j.ID, j.CreatedAt, j.Status, j.AttemptCount, j.Code, deref(j.ClientID), j.TargetURL)
+	}
+	return tw.Flush()
+}
+
+func newJobsCmd(a *app) *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "jobs",
+		Short: "Inspect dispatch jobs",
+	}
+
+	var f jobFilter
+	list := &cobra.Command{
+		Use:   "list",
+		Short: "List dispatch jobs, newest first",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			var jobs []dispatchJob
+			if err := a.platform().do(cmd.Context(), http.MethodGet, "/api/dispatch-jobs", f.query(), nil, &jobs); err != nil {
+				return err
+			}
+			return a.printJobs(cmd, jobs)
+		},
+	}
+	f.register(list, true)
+
+	get := &cobra.Command{
+		Use:   "get <id>",
+		Short: "Show a dispatch job (payload included, redacted per event type)",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			var job map[string]any
+			if err := a.platform().do(cmd.Context(), http.MethodGet, "/api/dispatch-jobs/"+url.PathEscape(args[0]), nil, nil, &job); err != nil {
+				return err
+			}
+			return printJSON(cmd.OutOrStdout(), job)
+		},
+	}
+
+	attempts := &cobra.Command{
+		Use:   "attempts <id>",
+		Short: "Show a dispatch job's delivery attempts",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			var out []dispatchAttempt
+			if err := a.platform().do(cmd.Context(), http.MethodGet, "/api/dispatch-jobs/"+url.PathEscape(args[0])+"/attempts", nil, nil, &out); err != nil {
+				return err
+			}
+			if a.jsonOutput {
+				return printJSON(cmd.OutOrStdout(), out)
+			}
+			tw := newTable(cmd.OutOrStdout())
+			fmt.Fprintln(tw, "#\tAT\tOK\tSTATUS\tMS\tERROR")
+			for _, at := range out {
+				code, ms := "-", "-"
+				if at.ResponseCode != nil {
+					code = strconv.Itoa(*at.ResponseCode)
+				}
+				if at.DurationMillis != nil {
+					ms = strconv.FormatInt(*at.DurationMillis, 10)
+				}
+				fmt.Fprintf(tw, "%d\t%s\t%t\t%s\t%s\t%s\n", at.AttemptNumber, at.AttemptedAt, at.Success, code, ms, deref(at.ErrorMessage))
+			}
+			return tw.Flush()
+		},
+	}
+
+	cmd.AddCommand(list, get, attempts)
+	return cmd
+}
+
+// newDLQCmd works the platform's dead letters: dispatch jobs that exhausted
+// their retries and ended FAILED. Retrying resets them to PENDING so the
+// scheduler dispatches them again (POST /api/dispatch-jobs/requeue).
+func newDLQCmd(a *app) *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "dlq",
+		Short: "List and retry failed dispatch jobs",
+	}
+
+	var f jobFilter
+	list := &cobra.Command{
+		Use:   "list",
+		Short: "List FAILED dispatch jobs",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			jobs, err := failedJobs(cmd.Context(), a.platform(), f)
+			if err != nil {
+				return err
+			}
+			return a.printJobs(cmd, jobs)
+		},
+	}
+	f.register(list, false)
+
+	var rf jobFilter
+	var all bool
+	retry := &cobra.Command{
+		Use:   "retry [id...]",
+		Short: "Requeue failed jobs by id, or every match of the filters with --all",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			pc := a.platform()
+			ids := args
+			switch {
+			case all && len(args) > 0:
+				return errors.New("pass job ids or --all, not both")
+			case all:
+				jobs, err := failedJobs(cmd.Context(), pc, rf)
+				if err != nil {
+					return err
+				}
+				for _, j := range jobs {
+					ids = append(ids, j.ID)
+				}
+			case len(args) == 0:
+				return errors.New("pass job ids or --all")
+			}
+			if len(ids) == 0 {
+				fmt.Fprintln(cmd.OutOrStdout(), "nothing to retry")
+				return nil
+			}
+			var out struct {
+				Requeued int64 `json:"requeued"`
+			}
+			body := map[string][]string{"ids": ids}
+			if err := pc.do(cmd.Context(), http.MethodPost, "/api/dispatch-jobs/requeue", nil, body, &out); err != nil {
+				return err
+			}
+			fmt.Fprintf(cmd.OutOrStdout(), "requeued %d of %d job(s)\n", out.Requeued, len(ids))
+			return nil
+		},
+	}
+	rf.register(retry, false)
+	retry.Flags().BoolVar(&all, "all", false, "retry every failed job matching the filters (bounded by --limit)")
+
+	cmd.AddCommand(list, retry)
+	return cmd
+}
+
+func failedJobs(ctx context.Context, pc *apiClient, f jobFilter) ([]dispatchJob, error) {
+	f.status = "FAILED"
+	var jobs []dispatchJob
+	if err := pc.do(ctx, http.MethodGet, "/api/dispatch-jobs", f.query(), nil, &jobs); err != nil {
+		return nil, err
+	}
+	return jobs, nil
+}
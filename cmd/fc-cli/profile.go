@@ -0,0 +1,234 @@
+package main
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/spf13/cobra"
+	"gopkg.in/yaml.v3"
+)
+
+// Profile is one named set of endpoints + credentials. The router surface
+// uses HTTP BasicAuth (FC_ROUTER_AUTH_USER / FC_ROUTER_AUTH_PASS on the
+// server); the platform API takes a bearer token, either pasted (Token) or
+// minted via the client_credentials grant (ClientID + ClientSecret).
+type Profile struct {
+	RouterURL      string `yaml:"router_url,omitempty"`
+	RouterUser     string `yaml:"router_user,omitempty"`
+	RouterPassword string `yaml:"router_password,omitempty"`
+
+	PlatformURL  string `yaml:"platform_url,omitempty"`
+	Token        string `yaml:"token,omitempty"`
+	ClientID     string `yaml:"client_id,omitempty"`
+	ClientSecret string `yaml:"client_secret,omitempty"`
+}
+
+// Config is the on-disk ~/.flowcatalyst/config.yaml.
+type Config struct {
+	Current  string             `yaml:"current,omitempty"`
+	Profiles map[string]Profile `yaml:"profiles"`
+}
+
+const (
+	defaultProfile     = "default"
+	defaultPlatformURL = "http://localhost:8080"
+	defaultRouterURL   = "http://localhost:8080/router"
+)
+
+// configPath resolves the config file: FC_CLI_CONFIG, else
+// ~/.flowcatalyst/config.yaml.
+func configPath() (string, error) {
+	if p := os.Getenv("FC_CLI_CONFIG"); p != "" {
+		return p, nil
+	}
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return "", fmt.Errorf("locate home directory: %w", err)
+	}
+	return filepath.Join(home, ".flowcatalyst", "config.yaml"), nil
+}
+
+// loadConfig reads the config file. A missing file is an empty config so
+// the CLI works against a local fc-dev with no setup.
+func loadConfig(path string) (*Config, error) {
+	cfg := &Config{Profiles: map[string]Profile{}}
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return cfg, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("read %s: %w", path, err)
+	}
+	if err := yaml.Unmarshal(data, cfg); err != nil {
+		return nil, fmt.Errorf("parse %s: %w", path, err)
+	}
+	if cfg.Profiles == nil {
+		cfg.Profiles = map[string]Profile{}
+	}
+	return cfg, nil
+}
+
+// save writes the config with owner-only permissions — it holds secrets.
+func (c *Config) save(path string) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0o700); err != nil {
+		return fmt.Errorf("create config dir: %w", err)
+	}
+	data, err := yaml.Marshal(c)
+	if err != nil {
+		return err
+	}
+	return os.WriteFile(path, data, 0o600)
+}
+
+// names returns the profile names in sorted order.
+func (c *Config) names() []string {
+	out := make([]string, 0, len(c.Profiles))
+	for n := range c.Profiles {
+		out = append(out, n)
+	}
+	sort.Strings(out)
+	return out
+}
+
+// resolve picks the active profile: the explicit name, else Current, else
+// "default". An explicitly named profile must exist; the implicit one falls
+// back to the local defaults. Unset URLs take the local defaults too.
+func (c *Config) resolve(name string) (string, Profile, error) {
+	explicit := name != ""
+	if name == "" {
+		name = c.Current
+	}
+	if name == "" {
+		name = defaultProfile
+	}
+	p, ok := c.Profiles[name]
+	if !ok && explicit {
+		return "", Profile{}, fmt.Errorf("profile %q not found (see `fc-cli profile list`)", name)
+	}
+	if p.PlatformURL == "" {
+		p.PlatformURL = defaultPlatformURL
+	}
+	if p.RouterURL == "" {
+		p.RouterURL = defaultRouterURL
+	}
+	return name, p, nil
+}
+
+func newProfileCmd(a *app) *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "profile",
+		Short: "Manage connection profiles in ~/.flowcatalyst/config.yaml",
+	}
+
+	cmd.AddCommand(&cobra.Command{
+		Use:   "list",
+		Short: "List profiles (* marks the current one)",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			current, _, _ := a.cfg.resolve("")
+			tw := newTable(cmd.OutOrStdout())
+			fmt.Fprintln(tw, "\tNAME\tPLATFORM\tROUTER")
+			for _, n := range a.cfg.names() {
+				p := a.cfg.Profiles[n]
+				mark := ""
+				if n == current {
+					mark = "*"
+				}
+				fmt.Fprintf(tw, "%s\t%s\t%s\t%s\n", mark, n, p.PlatformURL, p.RouterURL)
+			}
+			return tw.Flush()
+		},
+	})
+
+	cmd.AddCommand(&cobra.Command{
+		Use:   "show",
+		Short: "Show the resolved profile (secrets masked)",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			p := a.profile
+			for _, s := range []*string{&p.RouterPassword, &p.Token, &p.ClientSecret} {
+				if *s != "" {
+					*s = "********"
+				}
+			}
+			return printJSON(cmd.OutOrStdout(), p)
+		},
+	})
+
+	set := &cobra.Command{
+		Use:   "set <name>",
+		Short: "Create or update a profile; only the flags given are changed",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			p := a.cfg.Profiles[args[0]]
+			for flag, dst := range map[string]*string{
+				"platform-url":    &p.PlatformURL,
+				"router-url":      &p.RouterURL,
+				"router-user":     &p.RouterUser,
+				"router-password": &p.RouterPassword,
+				"token":           &p.Token,
+				"client-id":       &p.ClientID,
+				"client-secret":   &p.ClientSecret,
+			} {
+				if cmd.Flags().Changed(flag) {
+					*dst, _ = cmd.Flags().GetString(flag)
+				}
+			}
+			a.cfg.Profiles[args[0]] = p
+			if a.cfg.Current == "" {
+				a.cfg.Current = args[0]
+			}
+			if err := a.cfg.save(a.cfgPath); err != nil {
+				return err
+			}
+			fmt.Fprintf(cmd.OutOrStdout(), "saved profile %q to %s\n", args[0], a.cfgPath)
+			return nil
+		},
+	}
+	// --platform-url / --router-url are the root's persistent flags; set
+	// reads them via Changed so they double as the stored values.
+	f := set.Flags()
+	f.String("router-user", "", "router BasicAuth username")
+	f.String("router-password", "", "router BasicAuth password")
+	f.String("token", "", "platform bearer token")
+	f.String("client-id", "", "service-account client id (client_credentials)")
+	f.String("client-secret", "", "service-account client secret")
+	cmd.AddCommand(set)
+
+	cmd.AddCommand(&cobra.Command{
+		Use:   "use <name>",
+		Short: "Make a profile the current one",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if _, ok := a.cfg.Profiles[args[0]]; !ok {
+				return fmt.Errorf("profile %q not found", args[0])
+			}
+			a.cfg.Current = args[0]
+			if err := a.cfg.save(a.cfgPath); err != nil {
+				return err
+			}
+			fmt.Fprintf(cmd.OutOrStdout(), "now using profile %q\n", args[0])
+			return nil
+		},
+	})
+
+	cmd.AddCommand(&cobra.Command{
+		Use:   "delete <name>",
+		Short: "Delete a profile",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if _, ok := a.cfg.Profiles[args[0]]; !ok {
+				return fmt.Errorf("profile %q not found", args[0])
+			}
+			delete(a.cfg.Profiles, args[0])
+			if a.cfg.Current == args[0] {
+				a.cfg.Current = ""
+			}
+			return a.cfg.save(a.cfgPath)
+		},
+	})
+	return cmd
+}
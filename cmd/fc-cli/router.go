@@ -0,0 +1,232 @@
+package main
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"os/signal"
+	"syscall"
+	"time"
+
+	"github.com/spf13/cobra"
+)
+
+// Router wire shapes (internal/router/api/dto.go). Decoded locally so the
+// CLI stays independent of the server packages.
+type (
+	publishRequest struct {
+		ID              string `json:"id,omitempty"`
+		PoolCode        string `json:"pool_code"`
+		MediationTarget string `json:"mediation_target"`
+		MessageGroupID  string `json:"message_group_id,omitempty"`
+		HighPriority    bool   `json:"high_priority,omitempty"`
+		DispatchMode    string `json:"dispatch_mode,omitempty"`
+		AuthToken       string `json:"auth_token,omitempty"`
+		DelaySeconds    int    `json:"delay_seconds,omitempty"`
+	}
+	publishResponse struct {
+		MessageID       string     `json:"message_id"`
+		BrokerMessageID string     `json:"broker_message_id"`
+		PoolCode        string     `json:"pool_code"`
+		QueueIdentifier string     `json:"queue_identifier"`
+		DeliverAt       *time.Time `json:"deliver_at,omitempty"`
+	}
+	warning struct {
+		ID           string    `json:"id"`
+		Category     string    `json:"category"`
+		Severity     string    `json:"severity"`
+		Message      string    `json:"message"`
+		Source       string    `json:"source"`
+		CreatedAt    time.Time `json:"created_at"`
+		Acknowledged bool      `json:"acknowledged"`
+	}
+)
+
+func newPublishCmd(a *app) *cobra.Command {
+	var req publishRequest
+	var delay time.Duration
+	cmd := &cobra.Command{
+		Use:   "publish",
+		Short: "Publish a test message to a router pool's queue",
+		Long: `Publish one message through the router's POST /messages. The router
+looks up the pool's queue and publishes via the same broker its consumer
+reads, so the message flows through the normal delivery path.`,
+		Args: cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			req.DelaySeconds = int(delay.Round(time.Second) / time.Second)
+			var out publishResponse
+			if err := a.router().do(cmd.Context(), http.MethodPost, "/messages", nil, req, &out); err != nil {
+				return err
+			}
+			if a.jsonOutput {
+				return printJSON(cmd.OutOrStdout(), out)
+			}
+			fmt.Fprintf(cmd.OutOrStdout(), "published %s to %s (%s, broker id %s)\n",
+				out.MessageID, out.PoolCode, out.QueueIdentifier, out.BrokerMessageID)
+			if out.DeliverAt != nil {
+				fmt.Fprintf(cmd.OutOrStdout(), "scheduled for %s\n", out.DeliverAt.Local().Format(time.RFC3339))
+			}
+			return nil
+		},
+	}
+	f := cmd.Flags()
+	f.StringVar(&req.PoolCode, "pool", "DEFAULT-POOL", "target pool code")
+	f.StringVar(&req.MediationTarget, "target", "", "URL the router delivers to (required)")
+	f.StringVar(&req.ID, "id", "", "message id (generated when empty)")
+	f.StringVar(&req.MessageGroupID, "group", "", "message group id (FIFO ordering)")
+	f.StringVar(&req.DispatchMode, "mode", "", "IMMEDIATE | NEXT_ON_ERROR | BLOCK_ON_ERROR")
+	f.StringVar(&req.AuthToken, "auth-token", "", "bearer token the router sends to the target")
+	f.BoolVar(&req.HighPriority, "high-priority", false, "publish with the high-priority hint")
+	f.DurationVar(&delay, "delay", 0, "hold the message back this long before delivery (e.g. 90s, 2h)")
+	_ = cmd.MarkFlagRequired("target")
+	return cmd
+}
+
+func newWarningsCmd(a *app) *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "warnings",
+		Short: "List, acknowledge and tail router warnings",
+	}
+	cmd.AddCommand(newWarningsListCmd(a), newWarningsAckCmd(a), newWarningsTailCmd(a))
+	return cmd
+}
+
+func newWarningsListCmd(a *app) *cobra.Command {
+	var severity, category string
+	var unacked bool
+	cmd := &cobra.Command{
+		Use:   "list",
+		Short: "List warnings, newest first",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			q := url.Values{}
+			if severity != "" {
+				q.Set("severity", severity)
+			}
+			if category != "" {
+				q.Set("category", category)
+			}
+			if unacked {
+				q.Set("acknowledged", "false")
+			}
+			var out []warning
+			if err := a.router().do(cmd.Context(), http.MethodGet, "/warnings", q, nil, &out); err != nil {
+				return err
+			}
+			if a.jsonOutput {
+				return printJSON(cmd.OutOrStdout(), out)
+			}
+			tw := newTable(cmd.OutOrStdout())
+			fmt.Fprintln(tw, "ID\tCREATED\tSEVERITY\tCATEGORY\tACK\tMESSAGE")
+			for _, w := range out {
+				fmt.Fprintf(tw, "%s\t%s\t%s\t%s\t%t\t%s\n", w.ID, w.CreatedAt.Format(time.RFC3339),
+					w.Severity, w.Category, w.Acknowledged, w.Message)
+			}
+			return tw.Flush()
+		},
+	}
+	f := cmd.Flags()
+	f.StringVar(&severity, "severity", "", "only this severity (INFO, WARN, ERROR, CRITICAL)")
+	f.StringVar(&category, "category", "", "only this category")
+	f.BoolVar(&unacked, "unacked", false, "only unacknowledged warnings")
+	return cmd
+}
+
+func newWarningsAckCmd(a *app) *cobra.Command {
+	var all bool
+	cmd := &cobra.Command{
+		Use:   "ack [id...]",
+		Short: "Acknowledge warnings by id, or every one with --all",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			rc := a.router()
+			if all {
+				var out struct {
+					Acknowledged uint64 `json:"acknowledged"`
+				}
+				if err := rc.do(cmd.Context(), http.MethodPost, "/warnings/acknowledge-all", nil, nil, &out); err != nil {
+					return err
+				}
+				fmt.Fprintf(cmd.OutOrStdout(), "acknowledged %d warning(s)\n", out.Acknowledged)
+				return nil
+			}
+			if len(args) == 0 {
+				return errors.New("pass warning ids or --all")
+			}
+			for _, id := range args {
+				if err := rc.do(cmd.Context(), http.MethodPost, "/warnings/"+url.PathEscape(id)+"/acknowledge", nil, nil, nil); err != nil {
+					return err
+				}
+				fmt.Fprintf(cmd.OutOrStdout(), "acknowledged %s\n", id)
+			}
+			return nil
+		},
+	}
+	cmd.Flags().BoolVar(&all, "all", false, "acknowledge every unacknowledged warning")
+	return cmd
+}
+
+func newWarningsTailCmd(a *app) *cobra.Command {
+	var severity string
+	cmd := &cobra.Command{
+		Use:   "tail",
+		Short: "Stream new warnings as they are raised (Ctrl-C to stop)",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			ctx, stop := signal.NotifyContext(cmd.Context(), syscall.SIGINT, syscall.SIGTERM)
+			defer stop()
+			q := url.Values{}
+			if severity != "" {
+				q.Set("severity", severity)
+			}
+			w := cmd.OutOrStdout()
+			return a.router().stream(ctx, "/warnings/stream", q, func(event, data string) error {
+				if event != "warning" {
+					return nil
+				}
+				if a.jsonOutput {
+					_, err := fmt.Fprintln(w, data)
+					return err
+				}
+				var wn warning
+				if err := json.Unmarshal([]byte(data), &wn); err != nil {
+					return fmt.Errorf("decode warning: %w", err)
+				}
+				_, err := fmt.Fprintf(w, "%s  %-8s  %-12s  %s  [%s]\n", wn.CreatedAt.Format(time.RFC3339),
+					wn.Severity, wn.Category, wn.Message, wn.ID)
+				return err
+			})
+		},
+	}
+	cmd.Flags().StringVar(&severity, "severity", "", "only this severity")
+	return cmd
+}
+
+func newConfigCmd(a *app) *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "config",
+		Short: "Router configuration",
+	}
+	cmd.AddCommand(&cobra.Command{
+		Use:   "reload",
+		Short: "Re-fetch pool and queue config from the platform now",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			var out struct {
+				Success bool   `json:"success"`
+				Note    string `json:"note,omitempty"`
+			}
+			if err := a.router().do(cmd.Context(), http.MethodPost, "/config/reload", nil, nil, &out); err != nil {
+				return err
+			}
+			if out.Note != "" {
+				fmt.Fprintf(cmd.OutOrStdout(), "reloaded (%s)\n", out.Note)
+				return nil
+			}
+			fmt.Fprintln(cmd.OutOrStdout(), "reloaded")
+			return nil
+		},
+	})
+	return cmd
+}
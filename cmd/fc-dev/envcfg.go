@@ -1,6 +1,8 @@
 package main
 
 import (
+	"os"
+
 	"github.com/flowcatalyst/flowcatalyst-go/internal/server"
 )
 
@@ -37,5 +39,10 @@ func devEnvCfg(opts startOpts, databaseURL string) server.EnvCfg {
 	if cfg.DefaultBroker == "" {
 		cfg.DefaultBroker = "postgres"
 	}
+	// Local webhook receivers live on localhost; the SSRF policy would
+	// refuse them. Set FC_OUTBOUND_ALLOW_PRIVATE=false to test the policy.
+	if os.Getenv("FC_OUTBOUND_ALLOW_PRIVATE") == "" {
+		cfg.OutboundAllowPrivate = true
+	}
 	return cfg
 }
@@ -49,6 +49,7 @@ var freshTables = []string{
 	// Auth tracking.
 	"iam_login_attempts",
 	"iam_password_reset_tokens",
+	"iam_idempotency_keys",
 	// IAM + tenancy relations. additional_client_ids and
 	// granted_client_ids are JSONB columns on tnt_client_auth_configs,
 	// not separate junction tables.
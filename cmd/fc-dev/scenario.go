@@ -0,0 +1,582 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/stream"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// scenarioHistory is how far back the seeded events and dispatch jobs
+// reach. Short enough to stay inside the default retention windows.
+const scenarioHistory = 72 * time.Hour
+
+// scenario is a named bundle of realistic platform data for local dev:
+// tenants, an event catalog with schemas, subscriptions, service accounts
+// and a few days of event + delivery history so the UI has something to
+// show on first boot.
+type scenario struct {
+	name            string
+	clients         []scenarioClient
+	eventTypes      []scenarioEventType
+	serviceAccounts []scenarioServiceAccount
+	subscriptions   []scenarioSubscription
+	// eventsPerClient is the number of historical events minted per client,
+	// cycling through eventTypes.
+	eventsPerClient int
+}
+
+type scenarioClient struct {
+	identifier string
+	name       string
+}
+
+type scenarioEventType struct {
+	code   string
+	name   string
+	schema string
+	// sample builds the n-th event's data for a client.
+	sample func(n int, client string) map[string]any
+}
+
+type scenarioServiceAccount struct {
+	code string
+	name string
+}
+
+// scenarioSubscription binds event-type patterns (`*` per segment) to an
+// endpoint. client scopes it to one tenant; empty means every client.
+// Per-binding filters aren't persisted (no column), so the patterns and
+// client scope are the filtering the seeded data exercises.
+type scenarioSubscription struct {
+	code           string
+	name           string
+	endpoint       string
+	patterns       []string
+	client         string
+	serviceAccount string
+	mode           common.DispatchMode
+}
+
+// scenarios is the catalog behind `fc-dev start --seed-scenario`.
+var scenarios = map[string]scenario{
+	"minimal": {
+		name:    "minimal",
+		clients: []scenarioClient{{"demo", "Demo Tenant"}},
+		eventTypes: []scenarioEventType{
+			{
+				code:   "demo:app:item:created",
+				name:   "Item Created",
+				schema: objectSchema("itemId", "name"),
+				sample: func(n int, _ string) map[string]any {
+					return map[string]any{"itemId": fmt.Sprintf("item-%04d", n), "name": fmt.Sprintf("Item %d", n)}
+				},
+			},
+			{
+				code:   "demo:app:item:deleted",
+				name:   "Item Deleted",
+				schema: objectSchema("itemId"),
+				sample: func(n int, _ string) map[string]any {
+					return map[string]any{"itemId": fmt.Sprintf("item-%04d", n)}
+				},
+			},
+		},
+		serviceAccounts: []scenarioServiceAccount{{"demo:webhooks", "Demo Webhooks"}},
+		subscriptions: []scenarioSubscription{
+			{code: "demo-items", name: "Demo item sync", endpoint: "https://hooks.example.com/demo/items",
+				patterns: []string{"demo:app:item:*"}, serviceAccount: "demo:webhooks"},
+		},
+		eventsPerClient: 10,
+	},
+	"ecommerce": {
+		name: "ecommerce",
+		clients: []scenarioClient{
+			{"northwind", "Northwind Traders"},
+			{"contoso", "Contoso Retail"},
+			{"fabrikam", "Fabrikam Outfitters"},
+		},
+		eventTypes: []scenarioEventType{
+			{
+				code:   "shop:orders:order:placed",
+				name:   "Order Placed",
+				schema: objectSchema("orderId", "customerId", "total", "currency"),
+				sample: func(n int, c string) map[string]any {
+					return map[string]any{"orderId": orderID(c, n), "customerId": fmt.Sprintf("cus-%05d", 1000+n*7%900),
+						"total": float64(1999+n*733%48000) / 100, "currency": "EUR"}
+				},
+			},
+			{
+				code:   "shop:orders:order:shipped",
+				name:   "Order Shipped",
+				schema: objectSchema("orderId", "carrier", "trackingNumber"),
+				sample: func(n int, c string) map[string]any {
+					return map[string]any{"orderId": orderID(c, n), "carrier": []string{"DHL", "UPS", "PostNL"}[n%3],
+						"trackingNumber": fmt.Sprintf("TRK%010d", 48213*n)}
+				},
+			},
+			{
+				code:   "shop:orders:order:cancelled",
+				name:   "Order Cancelled",
+				schema: objectSchema("orderId", "reason"),
+				sample: func(n int, c string) map[string]any {
+					return map[string]any{"orderId": orderID(c, n), "reason": []string{"customer_request", "out_of_stock", "payment_declined"}[n%3]}
+				},
+			},
+			{
+				code:   "shop:payments:payment:captured",
+				name:   "Payment Captured",
+				schema: objectSchema("paymentId", "orderId", "amount"),
+				sample: func(n int, c string) map[string]any {
+					return map[string]any{"paymentId": fmt.Sprintf("pay-%06d", n), "orderId": orderID(c, n), "amount": float64(1999+n*733%48000) / 100}
+				},
+			},
+			{
+				code:   "shop:payments:payment:refunded",
+				name:   "Payment Refunded",
+				schema: objectSchema("paymentId", "amount"),
+				sample: func(n int, _ string) map[string]any {
+					return map[string]any{"paymentId": fmt.Sprintf("pay-%06d", n), "amount": float64(500+n*311%9000) / 100}
+				},
+			},
+			{
+				code:   "shop:inventory:stock:low",
+				name:   "Stock Low",
+				schema: objectSchema("sku", "available", "threshold"),
+				sample: func(n int, _ string) map[string]any {
+					return map[string]any{"sku": fmt.Sprintf("SKU-%05d", 20000+n), "available": n % 5, "threshold": 5}
+				},
+			},
+		},
+		serviceAccounts: []scenarioServiceAccount{
+			{"shop:fulfilment", "Fulfilment Service"},
+			{"shop:finance", "Finance Ledger"},
+		},
+		subscriptions: []scenarioSubscription{
+			{code: "fulfilment-orders", name: "Fulfilment — all order events", endpoint: "https://fulfilment.example.com/webhooks/orders",
+				patterns: []string{"shop:orders:*:*"}, serviceAccount: "shop:fulfilment", mode: common.DispatchNextOnError},
+			{code: "finance-ledger", name: "Finance ledger — payments", endpoint: "https://ledger.example.com/hooks/payments",
+				patterns: []string{"shop:payments:payment:*"}, serviceAccount: "shop:finance", mode: common.DispatchBlockOnError},
+			{code: "northwind-stock-alerts", name: "Northwind low-stock alerts", endpoint: "https://northwind.example.com/alerts/stock",
+				patterns: []string{"shop:inventory:stock:low"}, client: "northwind", serviceAccount: "shop:fulfilment"},
+		},
+		eventsPerClient: 40,
+	},
+	"saas": {
+		name: "saas",
+		clients: []scenarioClient{
+			{"acme", "Acme Corp"},
+			{"globex", "Globex Corporation"},
+			{"initech", "Initech"},
+		},
+		eventTypes: []scenarioEventType{
+			{
+				code:   "saas:identity:user:signed-up",
+				name:   "User Signed Up",
+				schema: objectSchema("userId", "email", "plan"),
+				sample: func(n int, c string) map[string]any {
+					return map[string]any{"userId": fmt.Sprintf("usr-%05d", n), "email": fmt.Sprintf("user%d@%s.example.com", n, c),
+						"plan": []string{"free", "team", "business"}[n%3]}
+				},
+			},
+			{
+				code:   "saas:billing:subscription:created",
+				name:   "Subscription Created",
+				schema: objectSchema("subscriptionId", "plan", "seats"),
+				sample: func(n int, _ string) map[string]any {
+					return map[string]any{"subscriptionId": fmt.Sprintf("sub-%05d", n), "plan": []string{"team", "business"}[n%2], "seats": 5 + n%20}
+				},
+			},
+			{
+				code:   "saas:billing:invoice:paid",
+				name:   "Invoice Paid",
+				schema: objectSchema("invoiceId", "amount", "currency"),
+				sample: func(n int, _ string) map[string]any {
+					return map[string]any{"invoiceId": fmt.Sprintf("inv-%06d", n), "amount": float64(4900+n*1200%90000) / 100, "currency": "USD"}
+				},
+			},
+			{
+				code:   "saas:billing:invoice:payment-failed",
+				name:   "Invoice Payment Failed",
+				schema: objectSchema("invoiceId", "attempt", "reason"),
+				sample: func(n int, _ string) map[string]any {
+					return map[string]any{"invoiceId": fmt.Sprintf("inv-%06d", n), "attempt": 1 + n%3, "reason": "card_declined"}
+				},
+			},
+		},
+		serviceAccounts: []scenarioServiceAccount{
+			{"saas:billing-sync", "Billing Sync"},
+			{"saas:crm", "CRM Connector"},
+		},
+		subscriptions: []scenarioSubscription{
+			{code: "billing-sync", name: "Billing sync — all billing events", endpoint: "https://billing.example.com/webhooks/flowcatalyst",
+				patterns: []string{"saas:billing:*:*"}, serviceAccount: "saas:billing-sync"},
+			{code: "crm-signups", name: "CRM — new sign-ups", endpoint: "https://crm.example.com/api/hooks/signup",
+				patterns: []string{"saas:identity:user:signed-up"}, serviceAccount: "saas:crm"},
+			{code: "acme-dunning", name: "Acme dunning alerts", endpoint: "https://acme.example.com/dunning",
+				patterns: []string{"saas:billing:invoice:payment-failed"}, client: "acme", serviceAccount: "saas:billing-sync"},
+		},
+		eventsPerClient: 30,
+	},
+}
+
+// scenarioNames returns the catalog names, sorted, for help text and errors.
+func scenarioNames() []string {
+	out := make([]string, 0, len(scenarios))
+	for n := range scenarios {
+		out = append(out, n)
+	}
+	sort.Strings(out)
+	return out
+}
+
+// lookupScenario resolves a --seed-scenario value. Empty means none.
+func lookupScenario(name string) (*scenario, error) {
+	name = strings.ToLower(strings.TrimSpace(name))
+	if name == "" {
+		return nil, nil
+	}
+	sc, ok := scenarios[name]
+	if !ok {
+		return nil, fmt.Errorf("unknown seed scenario %q (want one of: %s)", name, strings.Join(scenarioNames(), ", "))
+	}
+	return &sc, nil
+}
+
+// seedScenario loads a scenario into the database. Idempotent per
+// scenario: if its first client already exists the scenario is assumed
+// seeded and nothing is written, so restarting with the same flag is safe.
+//
+// Dev-only, and like init/mcp_bootstrap it writes through the repositories
+// directly (no UoW, no domain events). History is written already
+// fanned-out and with terminal dispatch statuses, so neither the stream
+// fan-out nor the dispatch scheduler picks it up and nothing is delivered
+// to the (fictional) endpoints.
+func seedScenario(ctx context.Context, pool *pgxpool.Pool, sc *scenario) error {
+	clientRepo := client.NewRepository(pool)
+	existing, err := clientRepo.FindByIdentifier(ctx, sc.clients[0].identifier)
+	if err != nil {
+		return fmt.Errorf("look up scenario client: %w", err)
+	}
+	if existing != nil {
+		slog.Info("seed scenario already applied, skipping", "scenario", sc.name, "client", existing.Identifier)
+		return nil
+	}
+
+	// ── Catalog: clients, event types, service accounts, subscriptions ──
+	clients := make(map[string]*client.Client, len(sc.clients))
+	for _, c := range sc.clients {
+		clients[c.identifier] = client.New(c.name, c.identifier)
+	}
+	eventTypes := make([]*eventtype.EventType, 0, len(sc.eventTypes))
+	for _, def := range sc.eventTypes {
+		et, err := eventtype.New(def.code, def.name)
+		if err != nil {
+			return fmt.Errorf("event type %s: %w", def.code, err)
+		}
+		sv := eventtype.NewSpecVersion(et.ID, "1.0", json.RawMessage(def.schema))
+		sv.Status = eventtype.SpecCurrent
+		et.AddSchemaVersion(sv)
+		eventTypes = append(eventTypes, et)
+	}
+	firstClientID := clients[sc.clients[0].identifier].ID
+	accounts := make(map[string]*serviceaccount.ServiceAccount, len(sc.serviceAccounts))
+	principals := make([]*principal.Principal, 0, len(sc.serviceAccounts))
+	for _, def := range sc.serviceAccounts {
+		sa := serviceaccount.New(def.code, def.name)
+		desc := "Seeded by fc-dev scenario " + sc.name
+		sa.Description = &desc
+		for _, c := range sc.clients {
+			sa.ClientIDs = append(sa.ClientIDs, clients[c.identifier].ID)
+		}
+		p := principal.NewService(sa.ID, def.name)
+		p.ClientID = &firstClientID
+		p.Scope = principal.ScopePartner
+		accounts[def.code] = sa
+		principals = append(principals, p)
+	}
+	subs := make([]*subscription.Subscription, 0, len(sc.subscriptions))
+	for _, def := range sc.subscriptions {
+		s := subscription.New(def.code, def.name, def.endpoint)
+		for _, pattern := range def.patterns {
+			s.EventTypes = append(s.EventTypes, subscription.NewEventTypeBinding(pattern))
+		}
+		if def.mode != "" {
+			s.Mode = def.mode
+		}
+		if def.client != "" {
+			c := clients[def.client]
+			s.ClientScoped = true
+			s.ClientID = &c.ID
+			s.ClientIdentifier = &c.Identifier
+		}
+		if sa, ok := accounts[def.serviceAccount]; ok {
+			s.ServiceAccountID = &sa.ID
+		}
+		subs = append(subs, s)
+	}
+
+	etRepo := eventtype.NewRepository(pool)
+	saRepo := serviceaccount.NewRepository(pool)
+	principalRepo := principal.NewRepository(pool)
+	subRepo := subscription.NewRepository(pool)
+	if err := infraPersist(ctx, pool, func(tx *usecasepgx.DbTx) error {
+		for _, c := range sc.clients {
+			if err := clientRepo.Persist(ctx, clients[c.identifier], tx); err != nil {
+				return fmt.Errorf("client %s: %w", c.identifier, err)
+			}
+		}
+		for _, et := range eventTypes {
+			if err := etRepo.Persist(ctx, et, tx); err != nil {
+				return fmt.Errorf("event type %s: %w", et.Code, err)
+			}
+		}
+		for i, def := range sc.serviceAccounts {
+			if err := principalRepo.Persist(ctx, principals[i], tx); err != nil {
+				return fmt.Errorf("principal for %s: %w", def.code, err)
+			}
+			if err := saRepo.Persist(ctx, accounts[def.code], tx); err != nil {
+				return fmt.Errorf("service account %s: %w", def.code, err)
+			}
+		}
+		for _, s := range subs {
+			if err := subRepo.Persist(ctx, s, tx); err != nil {
+				return fmt.Errorf("subscription %s: %w", s.Code, err)
+			}
+		}
+		return nil
+	}); err != nil {
+		return err
+	}
+
+	// ── History: events + dispatch jobs + attempts ──────────────────────
+	now := time.Now().UTC()
+	if err := stream.NewPartitionManager(pool).EnsureFrom(ctx, now.Add(-scenarioHistory)); err != nil {
+		return fmt.Errorf("ensure partitions: %w", err)
+	}
+	events, jobs, attempts := scenarioHistoryRows(sc, clients, subs, now)
+	for _, e := range events {
+		if _, err := pool.Exec(ctx,
+			`INSERT INTO msg_events
+			     (id, spec_version, type, source, subject, time, data, correlation_id,
+			      deduplication_id, client_id, context_data, created_at, fanned_out_at)
+			 VALUES ($1, '1.0', $2, $3, $4, $5, $6::jsonb, $7, $8, $9, '[]'::jsonb, $5, $5)
+			 ON CONFLICT DO NOTHING`,
+			e.id, e.eventType, e.source, e.subject, e.at, e.data, e.correlationID,
+			e.eventType+"-"+e.id, e.clientID); err != nil {
+			return fmt.Errorf("insert event: %w", err)
+		}
+	}
+	jobRepo := dispatchjob.NewRepository(pool)
+	if err := jobRepo.InsertBatch(ctx, jobs); err != nil {
+		return fmt.Errorf("insert dispatch jobs: %w", err)
+	}
+	for _, a := range attempts {
+		if err := jobRepo.RecordAttempt(ctx, a.jobID, &a.attempt); err != nil {
+			return fmt.Errorf("record attempt: %w", err)
+		}
+	}
+
+	slog.Info("seeded scenario", "scenario", sc.name,
+		"clients", len(sc.clients), "event_types", len(eventTypes),
+		"service_accounts", len(accounts), "subscriptions", len(subs),
+		"events", len(events), "dispatch_jobs", len(jobs))
+	return nil
+}
+
+type scenarioEvent struct {
+	id            string
+	eventType     string
+	source        string
+	subject       string
+	data          string
+	correlationID string
+	clientID      string
+	at            time.Time
+}
+
+type scenarioAttempt struct {
+	jobID   string
+	attempt dispatchjob.Attempt
+}
+
+// scenarioHistoryRows builds the historical events, the dispatch job each
+// matching subscription would have produced, and their attempts. Events
+// are spread evenly over scenarioHistory; outcomes are deterministic so a
+// given scenario always looks the same.
+func scenarioHistoryRows(sc *scenario, clients map[string]*client.Client, subs []*subscription.Subscription, now time.Time) ([]scenarioEvent, []dispatchjob.DispatchJob, []scenarioAttempt) {
+	var (
+		events   []scenarioEvent
+		jobs     []dispatchjob.DispatchJob
+		attempts []scenarioAttempt
+	)
+	total := sc.eventsPerClient * len(sc.clients)
+	step := scenarioHistory / time.Duration(total+1)
+	seq := 0
+	for n := 0; n < sc.eventsPerClient; n++ {
+		for _, sclient := range sc.clients {
+			seq++
+			c := clients[sclient.identifier]
+			def := sc.eventTypes[(n+seq)%len(sc.eventTypes)]
+			data, _ := json.Marshal(def.sample(n, sclient.identifier))
+			at := now.Add(-scenarioHistory + time.Duration(seq)*step)
+			parts := strings.Split(def.code, ":")
+			e := scenarioEvent{
+				id:            tsid.GenerateUntyped(),
+				eventType:     def.code,
+				source:        parts[0] + ":" + parts[1],
+				subject:       fmt.Sprintf("%s.%s.%d", parts[2], sclient.identifier, n),
+				data:          string(data),
+				correlationID: tsid.GenerateUntyped(),
+				clientID:      c.ID,
+				at:            at,
+			}
+			events = append(events, e)
+
+			for _, s := range subs {
+				if !scenarioSubscriptionMatches(s, e.eventType, c.ID) {
+					continue
+				}
+				job, jobAttempts := scenarioJob(e, s, seq)
+				jobs = append(jobs, job)
+				attempts = append(attempts, jobAttempts...)
+			}
+		}
+	}
+	return events, jobs, attempts
+}
+
+func scenarioSubscriptionMatches(s *subscription.Subscription, eventType, clientID string) bool {
+	if s.ClientScoped && (s.ClientID == nil || *s.ClientID != clientID) {
+		return false
+	}
+	for _, b := range s.EventTypes {
+		if b.Matches(eventType) {
+			return true
+		}
+	}
+	return false
+}
+
+// scenarioJob mints one terminal dispatch job. Mostly COMPLETED (one
+// attempt, first-try success or a retry), with FAILED (retries exhausted
+// against a 503), EXPIRED and CANCELLED sprinkled in.
+func scenarioJob(e scenarioEvent, s *subscription.Subscription, seq int) (dispatchjob.DispatchJob, []scenarioAttempt) {
+	eventID, correlationID, clientID, subID := e.id, e.correlationID, e.clientID, s.ID
+	source, subject, payload := e.source, e.subject, e.data
+	id := tsid.GenerateUntyped()
+	job := dispatchjob.DispatchJob{
+		ID:                 id,
+		Kind:               dispatchjob.KindEvent,
+		Code:               e.eventType,
+		Source:             &source,
+		Subject:            &subject,
+		TargetURL:          s.Endpoint,
+		Protocol:           dispatchjob.ProtocolHTTPWebhook,
+		Payload:            &payload,
+		PayloadContentType: "application/json",
+		DataOnly:           s.DataOnly,
+		EventID:            &eventID,
+		CorrelationID:      &correlationID,
+		ClientID:           &clientID,
+		SubscriptionID:     &subID,
+		ServiceAccountID:   s.ServiceAccountID,
+		Mode:               s.Mode,
+		Sequence:           s.Sequence,
+		TimeoutSeconds:     uint32(s.TimeoutSeconds),
+		MaxRetries:         uint32(s.MaxRetries),
+		RetryStrategy:      dispatchjob.RetryExponentialBackoff,
+		CreatedAt:          e.at,
+	}
+	idem := e.id + ":" + s.ID
+	job.IdempotencyKey = &idem
+
+	var outcomes []int // HTTP status per attempt; 0 = connection error
+	switch {
+	case seq%17 == 0:
+		job.Status = common.DispatchCancelled
+	case seq%13 == 0:
+		job.Status = common.DispatchExpired
+	case seq%7 == 0:
+		job.Status = common.DispatchFailed
+		for i := 0; i <= int(job.MaxRetries); i++ {
+			outcomes = append(outcomes, 503)
+		}
+	case seq%5 == 0:
+		job.Status = common.DispatchCompleted
+		outcomes = []int{0, 200}
+	default:
+		job.Status = common.DispatchCompleted
+		outcomes = []int{200}
+	}
+
+	var attempts []scenarioAttempt
+	at := e.at.Add(150 * time.Millisecond)
+	for i, status := range outcomes {
+		a := dispatchjob.Attempt{AttemptNumber: int32(i + 1), AttemptedAt: at}
+		ms := int64(40 + (seq*37+i*11)%400)
+		done := at.Add(time.Duration(ms) * time.Millisecond)
+		a.CompletedAt, a.DurationMillis = &done, &ms
+		switch status {
+		case 200:
+			code := status
+			a.ResponseCode, a.Success = &code, true
+		case 0:
+			msg, errType := "dial tcp: connection refused", dispatchjob.ErrorConnection
+			a.ErrorMessage, a.ErrorType = &msg, &errType
+		default:
+			code, msg, errType := status, fmt.Sprintf("HTTP %d: Service Unavailable", status), dispatchjob.ErrorHTTPError
+			a.ResponseCode, a.ErrorMessage, a.ErrorType = &code, &msg, &errType
+		}
+		attempts = append(attempts, scenarioAttempt{jobID: id, attempt: a})
+		job.AttemptCount = a.AttemptNumber
+		job.LastAttemptAt = &a.AttemptedAt
+		job.LastError = a.ErrorMessage
+		at = done.Add(time.Duration(1<<i) * 30 * time.Second)
+	}
+	if n := len(attempts); n > 0 {
+		completed := *attempts[n-1].attempt.CompletedAt
+		job.CompletedAt = &completed
+		d := completed.Sub(e.at).Milliseconds()
+		job.DurationMillis = &d
+	}
+	return job, attempts
+}
+
+// orderID keeps order ids stable across the order/payment events of one
+// client so the seeded history reads like connected flows.
+func orderID(client string, n int) string {
+	return fmt.Sprintf("%s-%05d", strings.ToUpper(client[:3]), 10000+n)
+}
+
+// objectSchema is a minimal JSON Schema with the given required string-or-
+// number properties — enough for the schema viewer and validation UI.
+func objectSchema(required ...string) string {
+	props := make(map[string]any, len(required))
+	for _, r := range required {
+		props[r] = map[string]any{}
+	}
+	b, _ := json.Marshal(map[string]any{
+		"$schema":              "https://json-schema.org/draft/2020-12/schema",
+		"type":                 "object",
+		"required":             required,
+		"properties":           props,
+		"additionalProperties": true,
+	})
+	return string(b)
+}
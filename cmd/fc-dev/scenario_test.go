@@ -0,0 +1,119 @@
+package main
+
+import (
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+func TestLookupScenario(t *testing.T) {
+	if sc, err := lookupScenario(""); sc != nil || err != nil {
+		t.Fatalf("empty name = %v, %v; want nil, nil", sc, err)
+	}
+	if sc, err := lookupScenario(" ECommerce "); err != nil || sc.name != "ecommerce" {
+		t.Fatalf("lookupScenario(ECommerce) = %v, %v", sc, err)
+	}
+	if _, err := lookupScenario("banking"); err == nil {
+		t.Fatal("unknown scenario should fail")
+	}
+}
+
+// TestScenarioCatalog checks every scenario is internally consistent: valid
+// event type codes, and subscriptions that reference declared clients and
+// service accounts. The first client doubles as the idempotency marker, so
+// it must be unique across scenarios.
+func TestScenarioCatalog(t *testing.T) {
+	markers := map[string]string{}
+	for name, sc := range scenarios {
+		if sc.name != name {
+			t.Errorf("scenario %q has name %q", name, sc.name)
+		}
+		if prev, ok := markers[sc.clients[0].identifier]; ok {
+			t.Errorf("scenarios %q and %q share first client %q", prev, name, sc.clients[0].identifier)
+		}
+		markers[sc.clients[0].identifier] = name
+
+		clients := map[string]bool{}
+		for _, c := range sc.clients {
+			clients[c.identifier] = true
+		}
+		accounts := map[string]bool{}
+		for _, sa := range sc.serviceAccounts {
+			accounts[sa.code] = true
+		}
+		for _, et := range sc.eventTypes {
+			if _, err := eventtype.New(et.code, et.name); err != nil {
+				t.Errorf("%s: event type %q: %v", name, et.code, err)
+			}
+		}
+		for _, s := range sc.subscriptions {
+			if s.client != "" && !clients[s.client] {
+				t.Errorf("%s: subscription %q scoped to unknown client %q", name, s.code, s.client)
+			}
+			if !accounts[s.serviceAccount] {
+				t.Errorf("%s: subscription %q uses unknown service account %q", name, s.code, s.serviceAccount)
+			}
+		}
+	}
+}
+
+func TestScenarioHistoryRows(t *testing.T) {
+	sc := scenarios["ecommerce"]
+	clients := map[string]*client.Client{}
+	for _, c := range sc.clients {
+		clients[c.identifier] = client.New(c.name, c.identifier)
+	}
+	var subs []*subscription.Subscription
+	for _, def := range sc.subscriptions {
+		s := subscription.New(def.code, def.name, def.endpoint)
+		for _, p := range def.patterns {
+			s.EventTypes = append(s.EventTypes, subscription.NewEventTypeBinding(p))
+		}
+		if def.client != "" {
+			s.ClientScoped = true
+			s.ClientID = &clients[def.client].ID
+		}
+		subs = append(subs, s)
+	}
+
+	now := time.Now().UTC()
+	events, jobs, attempts := scenarioHistoryRows(&sc, clients, subs, now)
+	if len(events) != sc.eventsPerClient*len(sc.clients) {
+		t.Fatalf("events = %d, want %d", len(events), sc.eventsPerClient*len(sc.clients))
+	}
+	for _, e := range events {
+		if e.at.After(now) || e.at.Before(now.Add(-scenarioHistory)) {
+			t.Fatalf("event at %s outside history window", e.at)
+		}
+	}
+
+	perJob := map[string]int{}
+	for _, a := range attempts {
+		perJob[a.jobID]++
+	}
+	statuses := map[common.DispatchStatus]int{}
+	for _, j := range jobs {
+		statuses[j.Status]++
+		// Seeded history must never be picked up by the scheduler.
+		switch j.Status {
+		case common.DispatchCompleted, common.DispatchFailed, common.DispatchCancelled, common.DispatchExpired:
+		default:
+			t.Fatalf("job %s has non-terminal status %s", j.ID, j.Status)
+		}
+		if int(j.AttemptCount) != perJob[j.ID] {
+			t.Errorf("job %s attempt_count = %d, recorded %d", j.ID, j.AttemptCount, perJob[j.ID])
+		}
+		if j.Status == common.DispatchFailed && j.AttemptCount != int32(j.MaxRetries)+1 {
+			t.Errorf("failed job %s made %d attempts, want %d", j.ID, j.AttemptCount, j.MaxRetries+1)
+		}
+	}
+	for _, st := range []common.DispatchStatus{common.DispatchCompleted, common.DispatchFailed, common.DispatchCancelled, common.DispatchExpired} {
+		if statuses[st] == 0 {
+			t.Errorf("no %s jobs in seeded history", st)
+		}
+	}
+}
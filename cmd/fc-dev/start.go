@@ -8,6 +8,7 @@ import (
 	"os"
 	"os/signal"
 	"path/filepath"
+	"strings"
 	"syscall"
 
 	embeddedpostgres "github.com/fergusstrange/embedded-postgres"
@@ -37,6 +38,7 @@ type startOpts struct {
 	OutboxEnabled       bool
 	RouterEnabled       bool
 	MCPEnabled          bool
+	SeedScenario        string
 }
 
 func newStartCmd() *cobra.Command {
@@ -63,6 +65,7 @@ func addStartFlags(cmd *cobra.Command) {
 	cmd.Flags().Bool("outbox", envBoolDefault("FC_OUTBOX_ENABLED", false), "run the outbox processor")
 	cmd.Flags().Bool("router", envBoolDefault("FC_ROUTER_ENABLED", true), "run the message router (uses the embedded Postgres broker by default)")
 	cmd.Flags().Bool("mcp", envBoolDefault("FC_MCP_ENABLED", false), "run the MCP HTTP server")
+	cmd.Flags().String("seed-scenario", envStrDefault("FC_DEV_SEED_SCENARIO", ""), "load realistic sample data on boot: "+strings.Join(scenarioNames(), "|")+" (idempotent)")
 	cmd.Flags().String("pid-file", envStrDefault("FC_DEV_PID_FILE", pidFilePath()), "PID file written while running; used by `fc-dev stop`")
 }
 
@@ -84,11 +87,16 @@ func optsFromFlags(cmd *cobra.Command) startOpts {
 		OutboxEnabled:       getBool("outbox"),
 		RouterEnabled:       getBool("router"),
 		MCPEnabled:          getBool("mcp"),
+		SeedScenario:        getStr("seed-scenario"),
 	}
 }
 
 func runStart(cmd *cobra.Command, _ []string) error {
 	opts := optsFromFlags(cmd)
+	sc, err := lookupScenario(opts.SeedScenario)
+	if err != nil {
+		return err
+	}
 	banner(opts)
 
 	// Record our PID so `fc-dev stop` can find and gracefully signal us.
@@ -181,6 +189,11 @@ func runStart(cmd *cobra.Command, _ []string) error {
 	if err := seed.NewSeeder(pool).Run(rootCtx); err != nil {
 		return fmt.Errorf("seed: %w", err)
 	}
+	if sc != nil {
+		if err := seedScenario(rootCtx, pool, sc); err != nil {
+			return fmt.Errorf("seed scenario %s: %w", sc.name, err)
+		}
+	}
 
 	// Bootstrap local MCP credentials (idempotent, dev-only) so `fc-dev mcp`
 	// and `--mcp` just work. Non-fatal — a failure must not block the dev boot.
@@ -224,6 +237,7 @@ func banner(opts startOpts) {
 		"outbox", opts.OutboxEnabled,
 		"router", opts.RouterEnabled,
 		"mcp", opts.MCPEnabled,
+		"seed_scenario", opts.SeedScenario,
 	)
 }
 
@@ -6,11 +6,13 @@
 // contract. fc-dev wraps the same `server.Run` orchestrator with
 // embedded-Postgres + dev defaults for local work.
 //
-// See internal/server/envcfg.go for the full env-var list.
+// See docs/environment-variables.md for the full env-var list, or run
+// `fc-server --print-env` for the effective values.
 package main
 
 import (
 	"context"
+	"flag"
 	"log/slog"
 	"os"
 	"os/signal"
@@ -25,11 +27,25 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/seed"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/database"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/server"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/startup"
 )
 
 func main() {
+	printEnv := flag.Bool("print-env", false, "print every supported environment variable with its effective value and source, then exit")
+	flag.Parse()
+	if *printEnv {
+		if err := server.PrintEnv(os.Stdout); err != nil {
+			os.Exit(1)
+		}
+		return
+	}
+
 	logging.Init()
 	cfg := server.LoadEnv()
+	if err := server.CheckEnv(cfg); err != nil {
+		slog.Error("environment check failed", "err", err)
+		os.Exit(1)
+	}
 
 	slog.Info("starting fc-server",
 		"platform", cfg.PlatformEnabled,
@@ -55,6 +71,12 @@ func main() {
 	needsDB := cfg.PlatformEnabled || cfg.StreamEnabled || cfg.SchedulerEnabled ||
 		cfg.ScheduledJobEnabled || cfg.OutboxEnabled
 
+	// Every external dependency is probed with bounded retries before it is
+	// used, so a database or Redis still coming up alongside this pod delays
+	// startup instead of killing it with a raw driver error. Meanwhile the
+	// metrics port reports which dependency startup is waiting on.
+	deps := server.StartupDependencies(cfg)
+	queryTracer := server.QueryTracer(cfg)
 	var pool *pgxpool.Pool
 	if needsDB {
 		// AWS Secrets Manager DB mode: when DB_SECRET_ARN + DB_HOST are set (and no
@@ -70,7 +92,7 @@ func main() {
 
 		// DB-secret rotation: when SM mode + DB_SECRET_REFRESH_INTERVAL_MS != 0,
 		// poll for rotated creds and inject them into new connections (no restart).
-		dbCfg := database.Config{URL: cfg.DatabaseURL}
+		dbCfg := database.Config{URL: cfg.DatabaseURL, Tracer: queryTracer}
 		var beforeConnect func(context.Context, *pgx.ConnConfig) error
 		if refresher, err := server.NewDBSecretRefresher(rootCtx); err != nil {
 			slog.Error("DB secret refresher init failed", "err", err)
@@ -81,20 +103,44 @@ func main() {
 			slog.Info("DB secret rotation enabled")
 		}
 
-		p, err := database.NewPoolWithBeforeConnect(rootCtx, dbCfg, beforeConnect)
-		if err != nil {
-			slog.Error("postgres connect failed", "err", err)
-			os.Exit(1)
-		}
-		defer p.Close()
-		pool = p
+		// The postgres probe IS the pool connect: a successful attempt keeps
+		// its pool.
+		deps = append([]startup.Dependency{{Name: "postgres", Probe: func(ctx context.Context) error {
+			p, err := database.NewPoolWithBeforeConnect(ctx, dbCfg, beforeConnect)
+			if err != nil {
+				return err
+			}
+			pool = p
+			return nil
+		}}}, deps...)
+	}
+
+	orch := startup.New(server.StartupConfig(cfg), deps...)
+	stopStatus := server.ServeStartupStatus(cfg, orch)
+	err := orch.Run(rootCtx)
+	stopStatus()
+	if err != nil {
+		slog.Error("startup failed", "err", err)
+		os.Exit(1)
+	}
+
+	if pool != nil {
+		defer pool.Close()
 		slog.Info("postgres connected")
 
+		if cfg.MigrateDryRun {
+			if err := server.LogMigrationPlan(rootCtx, pool); err != nil {
+				slog.Error("migration dry run failed", "err", err)
+				os.Exit(1)
+			}
+			return
+		}
 		if err := migrate.Run(rootCtx, pool); err != nil {
 			slog.Error("migrations failed", "err", err)
 			os.Exit(1)
 		}
 		slog.Info("migrations applied")
+		server.LogIndexDrift(rootCtx, pool)
 
 		if err := seed.NewSeeder(pool).Run(rootCtx); err != nil {
 			slog.Error("seed failed", "err", err)
@@ -121,7 +167,7 @@ func main() {
 	// "/" redirects to an OIDC flow that instance can't satisfy; the router's own
 	// UI/API lives under the /router prefix (basic-auth). No-op when dist wasn't
 	// embedded.
-	runOpts := server.RunOptions{}
+	runOpts := server.RunOptions{Startup: orch, QueryTracer: queryTracer}
 	if cfg.PlatformEnabled && frontend.IsAvailable() {
 		runOpts.Fallback = frontend.Handler()
 		slog.Info("embedded Vue SPA available")
@@ -0,0 +1,92 @@
+// Command payload-keys maintains the per-client data keys behind
+// encrypted-at-rest payloads (FC_PAYLOAD_ENCRYPTION_CLIENTS).
+//
+// Usage:
+//
+//	payload-keys list    <clientId>   show the client's keys (never key material)
+//	payload-keys rotate  <clientId>   retire the active key and mint a new one
+//	payload-keys migrate <clientId>   seal plaintext rows and re-seal rows under
+//	                                  retired keys (safe to re-run / interrupt)
+//
+// Reads the same env as fc-server: FC_DATABASE_URL (or DATABASE_URL),
+// FLOWCATALYST_APP_KEY (+ _PREVIOUS), and FC_PAYLOAD_ENCRYPTION_CLIENTS —
+// rotate and migrate refuse clients that aren't opted in. The usual flow
+// after opting a client in or rotating is `rotate` (optional) then `migrate`.
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/database"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/payloadcrypt"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/server"
+)
+
+func main() {
+	if len(os.Args) != 3 {
+		fmt.Fprintln(os.Stderr, "usage: payload-keys list|rotate|migrate <clientId>")
+		os.Exit(2)
+	}
+	if err := run(context.Background(), os.Args[1], os.Args[2]); err != nil {
+		fmt.Fprintln(os.Stderr, "payload-keys:", err)
+		os.Exit(1)
+	}
+}
+
+func run(ctx context.Context, cmd, clientID string) error {
+	cfg := server.LoadEnv()
+	pool, err := database.NewPool(ctx, database.Config{URL: cfg.DatabaseURL})
+	if err != nil {
+		return fmt.Errorf("connect: %w", err)
+	}
+	defer pool.Close()
+
+	if cmd == "list" {
+		keys, err := payloadcrypt.NewPgKeyStore(pool).List(ctx, clientID)
+		if err != nil {
+			return err
+		}
+		for _, k := range keys {
+			retired := "-"
+			if k.RetiredAt != nil {
+				retired = k.RetiredAt.Format(time.RFC3339)
+			}
+			fmt.Printf("%s\t%s\tcreated=%s\tretired=%s\n", k.ID, k.Status, k.CreatedAt.Format(time.RFC3339), retired)
+		}
+		return nil
+	}
+
+	kek, err := encryption.FromEnv()
+	if err != nil {
+		return fmt.Errorf("app key: %w", err)
+	}
+	cipher, err := server.NewPayloadCipher(cfg, pool, kek)
+	if err != nil {
+		return err
+	}
+	if !cipher.Encrypts(&clientID) {
+		return fmt.Errorf("client %s is not in FC_PAYLOAD_ENCRYPTION_CLIENTS", clientID)
+	}
+
+	switch cmd {
+	case "rotate":
+		id, err := cipher.Rotate(ctx, clientID)
+		if err != nil {
+			return err
+		}
+		fmt.Printf("active data key for %s is now %s — run `payload-keys migrate %s` to re-seal existing rows\n", clientID, id, clientID)
+		return nil
+	case "migrate":
+		reports, err := (&payloadcrypt.Migrator{Pool: pool, Cipher: cipher}).Run(ctx, clientID)
+		out, _ := json.MarshalIndent(reports, "", "  ")
+		fmt.Println(string(out))
+		return err
+	default:
+		return fmt.Errorf("unknown command %q", cmd)
+	}
+}
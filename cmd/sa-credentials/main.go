@@ -0,0 +1,82 @@
+// Command sa-credentials migrates service-account webhook credentials to
+// their at-rest form: auth tokens stored as salted hashes and signing
+// secrets encrypted with the app key.
+//
+// Usage:
+//
+//	sa-credentials status    count rows still holding plaintext or a secret
+//	                         sealed under a previous app key
+//	sa-credentials migrate   hash plaintext tokens, encrypt plaintext signing
+//	                         secrets and re-encrypt ones sealed under
+//	                         FLOWCATALYST_APP_KEY_PREVIOUS (safe to re-run)
+//
+// Reads the same env as fc-server: FC_DATABASE_URL (or DATABASE_URL) and
+// FLOWCATALYST_APP_KEY (+ _PREVIOUS). After rotating the app key, run
+// `migrate` before dropping the previous key.
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/database"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/server"
+)
+
+func main() {
+	if len(os.Args) != 2 {
+		fmt.Fprintln(os.Stderr, "usage: sa-credentials status|migrate")
+		os.Exit(2)
+	}
+	if err := run(context.Background(), os.Args[1]); err != nil {
+		fmt.Fprintln(os.Stderr, "sa-credentials:", err)
+		os.Exit(1)
+	}
+}
+
+func run(ctx context.Context, cmd string) error {
+	cfg := server.LoadEnv()
+	pool, err := database.NewPool(ctx, database.Config{URL: cfg.DatabaseURL})
+	if err != nil {
+		return fmt.Errorf("connect: %w", err)
+	}
+	defer pool.Close()
+
+	enc, err := encryption.FromEnv()
+	if err != nil {
+		return fmt.Errorf("app key: %w", err)
+	}
+	if enc == nil {
+		return errors.New("FLOWCATALYST_APP_KEY is not set")
+	}
+	repo := serviceaccount.NewRepository(pool)
+
+	switch cmd {
+	case "status":
+		all, err := repo.FindAll(ctx)
+		if err != nil {
+			return err
+		}
+		pending := 0
+		for _, sa := range all {
+			if sa.WebhookCredentials.NeedsReseal(enc) {
+				pending++
+				fmt.Printf("%s\t%s\n", sa.ID, sa.Code)
+			}
+		}
+		fmt.Printf("%d of %d service accounts need migrating\n", pending, len(all))
+		return nil
+	case "migrate":
+		report, err := repo.ResealCredentials(ctx, enc)
+		out, _ := json.MarshalIndent(report, "", "  ")
+		fmt.Println(string(out))
+		return err
+	default:
+		return fmt.Errorf("unknown command %q", cmd)
+	}
+}
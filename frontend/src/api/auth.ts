@@ -24,8 +24,14 @@ interface LoginResponse {
 // RawLoginResponse is the on-the-wire shape of /auth/login and the 2FA
 // completion endpoints (verify / enroll-confirm): an "ok" payload carries the
 // principal; the pending statuses carry a token + method list instead.
+// "passkey_enrollment_required" is a session that can only register a passkey
+// (an admin whose domain requires one and who has none yet).
 export interface RawLoginResponse extends Partial<LoginResponse> {
-	status?: "ok" | "mfa_required" | "enrollment_required";
+	status?:
+		| "ok"
+		| "mfa_required"
+		| "enrollment_required"
+		| "passkey_enrollment_required";
 	mfaToken?: string;
 	enrollToken?: string;
 	methods?: TwoFactorMethod[];
@@ -47,7 +53,8 @@ export type LoginResult =
 			status: "enrollment_required";
 			enrollToken: string;
 			allowedMethods: TwoFactorMethod[];
-	  };
+	  }
+	| { status: "passkey_enrollment_required" };
 
 export interface DomainCheckResponse {
 	authMethod: "internal" | "external";
@@ -77,13 +84,28 @@ export async function checkEmailDomain(
 	});
 }
 
+// passkeyEnrollmentEmail is set while the session is enrollment-only: the
+// password (and 2FA) passed, but the user must register a passkey and sign in
+// with it before they get a real session.
+let passkeyEnrollmentEmail: string | null = null;
+
+export function pendingPasskeyEnrollment(): string | null {
+	return passkeyEnrollmentEmail;
+}
+
 export async function checkSession(): Promise<boolean> {
 	const authStore = useAuthStore();
 	authStore.setLoading(true);
 
 	try {
-		const data = await authFetch<LoginResponse>("/me");
-		authStore.setUser(mapLoginResponseToUser(data));
+		const data = await authFetch<RawLoginResponse>("/me");
+		// An enrollment-only session isn't a signed-in user: back to login.
+		if (data.status === "passkey_enrollment_required") {
+			authStore.clearAuth();
+			return false;
+		}
+		passkeyEnrollmentEmail = null;
+		authStore.setUser(mapLoginResponseToUser(data as LoginResponse));
 		return true;
 	} catch {
 		authStore.clearAuth();
@@ -96,6 +118,13 @@ export async function checkSession(): Promise<boolean> {
 // to show something first (e.g. recovery codes) call redirectAfterLogin later.
 export function setSessionUser(data: RawLoginResponse): void {
 	const authStore = useAuthStore();
+	if (data.status === "passkey_enrollment_required") {
+		// Not signed in — redirectAfterLogin sends them to register a passkey.
+		authStore.clearAuth();
+		passkeyEnrollmentEmail = data.email ?? "";
+		return;
+	}
+	passkeyEnrollmentEmail = null;
 	authStore.setUser(mapLoginResponseToUser(data as LoginResponse));
 }
 
@@ -162,6 +191,16 @@ export function externalIdpRedirectUrl(loginUrl: string): string {
 // redirectAfterLogin performs the post-login navigation: OIDC interaction,
 // OAuth authorize round-trip, or the dashboard.
 export function redirectAfterLogin(): void {
+	// Enrollment-only session: the login page's passkey step, keeping the
+	// OIDC / OAuth context in the query for the real sign-in that follows.
+	if (passkeyEnrollmentEmail !== null) {
+		const query = router.currentRoute.value.query;
+		void router.replace({
+			name: "login",
+			query: { ...query, passkey: "enroll" },
+		});
+		return;
+	}
 	const urlParams = new URLSearchParams(window.location.search);
 	const interactionUid = urlParams.get("interaction");
 	if (interactionUid) {
@@ -172,6 +211,14 @@ export function redirectAfterLogin(): void {
 		window.location.href = oauthAuthorizeUrl((f) => urlParams.get(f));
 		return;
 	}
+	// A protected page that bounced here (authGuard's ?redirect=) gets the
+	// user back — e.g. the device verification page with its user_code. Only
+	// same-origin paths: "//host" would be a protocol-relative open redirect.
+	const redirect = urlParams.get("redirect");
+	if (redirect && redirect.startsWith("/") && !redirect.startsWith("//")) {
+		void router.replace(redirect);
+		return;
+	}
 	// The most capable page the user can reach: dashboard (anchor/admin), else a
 	// client-administrator's user-management page, else their profile.
 	const authStore = useAuthStore();
@@ -217,6 +264,11 @@ export async function login(
 				allowedMethods: data.allowedMethods ?? [],
 			};
 		}
+		if (data.status === "passkey_enrollment_required") {
+			authStore.setLoading(false);
+			setSessionUser(data);
+			return { status: "passkey_enrollment_required" };
+		}
 
 		applyLoginSuccess(data);
 		return { status: "ok" };
@@ -5,6 +5,8 @@
 // MFA_REQUIRED when the user has 2FA enrolled, so this client surfaces the
 // response code rather than collapsing everything into a thrown Error.
 
+import { csrfHeaders } from "./client";
+
 const AUTH = "/auth";
 
 export interface ChangePasswordResult {
@@ -23,7 +25,7 @@ export async function changePassword(input: {
 }): Promise<ChangePasswordResult> {
 	const res = await fetch(`${AUTH}/change-password`, {
 		method: "POST",
-		headers: { "Content-Type": "application/json" },
+		headers: { "Content-Type": "application/json", ...csrfHeaders() },
 		body: JSON.stringify(input),
 		credentials: "include",
 	});
@@ -47,6 +49,7 @@ export async function changePassword(input: {
 export async function sendChangePasswordEmailCode(): Promise<{ message: string }> {
 	const res = await fetch(`${AUTH}/change-password/send-email-code`, {
 		method: "POST",
+		headers: csrfHeaders(),
 		credentials: "include",
 	});
 	const data: { message?: string } = await res.json().catch(() => ({}));
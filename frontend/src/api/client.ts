@@ -11,6 +11,15 @@ export const API_BASE_URL = "/api";
 export const BFF_BASE_URL = "/bff";
 export const AUTH_BASE_URL = "/auth";
 
+/**
+ * The double-submit CSRF header: the server sets the fc_csrf cookie and
+ * rejects cookie-session mutations that don't echo it back.
+ */
+export function csrfHeaders(): Record<string, string> {
+	const match = document.cookie.match(/(?:^|;\s*)fc_csrf=([^;]+)/);
+	return match ? { "X-CSRF-Token": decodeURIComponent(match[1]) } : {};
+}
+
 /**
  * Custom error class for API errors that includes status code
  */
@@ -125,6 +134,7 @@ async function baseFetch<T>(
 		options;
 
 	const headers: Record<string, string> = {
+		...csrfHeaders(),
 		...(init.headers as Record<string, string>),
 	};
 	if (init.body) {
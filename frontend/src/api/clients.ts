@@ -24,6 +24,7 @@ export interface CreateClientRequest {
 
 export interface UpdateClientRequest {
 	name: string;
+	requirePasskeyForAdmins?: boolean;
 }
 
 export interface ClientSearchParams {
@@ -0,0 +1,59 @@
+// Stays hand-rolled: the RFC 8628 verification endpoints are chi-mounted
+// under /oauth (outside huma), like the rest of the OAuth surface. Errors
+// come back in the RFC-6749 {error, error_description} shape.
+
+import { csrfHeaders } from "./client";
+
+export interface DeviceAuthorization {
+	userCode: string;
+	clientId: string;
+	clientName: string;
+	scope?: string;
+	expiresAt: string;
+}
+
+export class DeviceFlowError extends Error {
+	code: string;
+	status: number;
+
+	constructor(code: string, message: string, status: number) {
+		super(message);
+		this.name = "DeviceFlowError";
+		this.code = code;
+		this.status = status;
+	}
+}
+
+async function deviceFetch<T>(init?: RequestInit, query = ""): Promise<T> {
+	const response = await fetch(`/oauth/device/verify${query}`, {
+		credentials: "include",
+		headers: { "Content-Type": "application/json", ...csrfHeaders() },
+		...init,
+	});
+	const body = await response.json().catch(() => ({}));
+	if (!response.ok) {
+		throw new DeviceFlowError(
+			body.error ?? "server_error",
+			body.error_description ?? "Request failed",
+			response.status,
+		);
+	}
+	return body as T;
+}
+
+export function lookupDeviceCode(userCode: string): Promise<DeviceAuthorization> {
+	return deviceFetch<DeviceAuthorization>(
+		undefined,
+		`?user_code=${encodeURIComponent(userCode)}`,
+	);
+}
+
+export function decideDeviceCode(
+	userCode: string,
+	action: "approve" | "deny",
+): Promise<{ status: string }> {
+	return deviceFetch<{ status: string }>({
+		method: "POST",
+		body: JSON.stringify({ userCode, action }),
+	});
+}
@@ -1,3 +1,3 @@
 // This file is auto-generated by @hey-api/openapi-ts
 
-export type { AccessListResponse, AccessListResponseWritable, AccessResponse, ActivateApplicationData, ActivateApplicationError, ActivateApplicationErrors, ActivateApplicationResponse, ActivateApplicationResponses, ActivateClientData, ActivateClientError, ActivateClientErrors, ActivateClientResponse, ActivateClientResponses, ActivateConnectionData, ActivateConnectionError, ActivateConnectionErrors, ActivateConnectionResponse, ActivateConnectionResponses, ActivateDispatchPoolData, ActivateDispatchPoolError, ActivateDispatchPoolErrors, ActivateDispatchPoolResponse, ActivateDispatchPoolResponses, ActivateOAuthClientData, ActivateOAuthClientError, ActivateOAuthClientErrors, ActivateOAuthClientResponse, ActivateOAuthClientResponses, ActivatePrincipalData, ActivatePrincipalError, ActivatePrincipalErrors, ActivatePrincipalResponse, ActivatePrincipalResponses, AddClientNoteData, AddClientNoteError, AddClientNoteErrors, AddClientNoteResponse, AddClientNoteResponses, AddCorsOriginData, AddCorsOriginError, AddCorsOriginErrors, AddCorsOriginResponse, AddCorsOriginResponses, AddEventTypeSchemaData, AddEventTypeSchemaError, AddEventTypeSchemaErrors, AddEventTypeSchemaResponse, AddEventTypeSchemaResponses, AddEventTypeVersionData, AddEventTypeVersionError, AddEventTypeVersionErrors, AddEventTypeVersionResponse, AddEventTypeVersionResponses, AddNoteRequest, AddNoteRequestWritable, AddOriginRequest, AddOriginRequestWritable, AddPrincipalRoleData, AddPrincipalRoleError, AddPrincipalRoleErrors, AddPrincipalRoleResponse, AddPrincipalRoleResponses, AddRoleRequest, AddRoleRequestWritable, AddSchemaRequest, AddSchemaRequestWritable, AllowedOriginResponse, AllowedOriginResponseWritable, AnchorDomainListResponse, AnchorDomainListResponseWritable, AnchorDomainResponse, ApplicationAccessListResponse, ApplicationAccessListResponseWritable, ApplicationAccessResponse, ApplicationFilterListResponse, ApplicationFilterListResponseWritable, ApplicationListResponse, ApplicationListResponseWritable, ApplicationLoginClientCredentials, ApplicationOAuthClientCredentials, ApplicationProvisionLoginClientResponse, ApplicationProvisionLoginClientResponseWritable, ApplicationProvisionServiceAccountResponse, ApplicationProvisionServiceAccountResponseWritable, ApplicationResponse, ApplicationResponseWritable, ApplicationRolesResponse, ApplicationRolesResponseWritable, ApplicationServiceAccountCredentials, ApproveResetApprovalData, ApproveResetApprovalError, ApproveResetApprovalErrors, ApproveResetApprovalResponse, ApproveResetApprovalResponses, ArchiveDispatchPoolData, ArchiveDispatchPoolError, ArchiveDispatchPoolErrors, ArchiveDispatchPoolResponse, ArchiveDispatchPoolResponses, ArchiveProcessData, ArchiveProcessError, ArchiveProcessErrors, ArchiveProcessResponse, ArchiveProcessResponses, ArchiveScheduledJobData, ArchiveScheduledJobError, ArchiveScheduledJobErrors, ArchiveScheduledJobResponse, ArchiveScheduledJobResponses, AssignApplicationAccessRequest, AssignApplicationAccessRequestWritable, AssignPrincipalApplicationAccessData, AssignPrincipalApplicationAccessError, AssignPrincipalApplicationAccessErrors, AssignPrincipalApplicationAccessResponse, AssignPrincipalApplicationAccessResponses, AssignPrincipalRolesData, AssignPrincipalRolesError, AssignPrincipalRolesErrors, AssignPrincipalRolesRequest, AssignPrincipalRolesRequestWritable, AssignPrincipalRolesResponse, AssignPrincipalRolesResponses, AssignRolesRequest, AssignRolesRequestWritable, AssignServiceAccountRolesData, AssignServiceAccountRolesError, AssignServiceAccountRolesErrors, AssignServiceAccountRolesResponse, AssignServiceAccountRolesResponses, AttachApplicationServiceAccountData, AttachApplicationServiceAccountError, AttachApplicationServiceAccountErrors, AttachApplicationServiceAccountResponse, AttachApplicationServiceAccountResponses, AttachServiceAccountRequest, AttachServiceAccountRequestWritable, AttemptDto, AuditLogApplicationIdsData, AuditLogApplicationIdsError, AuditLogApplicationIdsErrors, AuditLogApplicationIdsResponse, AuditLogApplicationIdsResponse2, AuditLogApplicationIdsResponses, AuditLogApplicationIdsResponseWritable, AuditLogClientIdsData, AuditLogClientIdsError, AuditLogClientIdsErrors, AuditLogClientIdsResponse, AuditLogClientIdsResponse2, AuditLogClientIdsResponses, AuditLogClientIdsResponseWritable, AuditLogEntityTypesData, AuditLogEntityTypesError, AuditLogEntityTypesErrors, AuditLogEntityTypesResponse, AuditLogEntityTypesResponse2, AuditLogEntityTypesResponses, AuditLogEntityTypesResponseWritable, AuditLogListResponse, AuditLogListResponseWritable, AuditLogOperationsData, AuditLogOperationsError, AuditLogOperationsErrors, AuditLogOperationsResponse, AuditLogOperationsResponse2, AuditLogOperationsResponses, AuditLogOperationsResponseWritable, AuditLogResponse, AuditLogResponseWritable, AuditLogsByEntityData, AuditLogsByEntityError, AuditLogsByEntityErrors, AuditLogsByEntityResponse, AuditLogsByEntityResponses, AuditLogsByPrincipalData, AuditLogsByPrincipalError, AuditLogsByPrincipalErrors, AuditLogsByPrincipalResponse, AuditLogsByPrincipalResponses, AuthConfigListResponse, AuthConfigListResponseWritable, AuthConfigResponse, AuthenticateBeginRequest, AuthenticateBeginRequestWritable, AuthenticateBeginResponse, AuthenticateBeginResponseWritable, AuthenticateCompleteRequest, AuthenticateCompleteRequestWritable, BatchEventItem, BatchIngestEventsData, BatchIngestEventsError, BatchIngestEventsErrors, BatchIngestEventsResponse, BatchIngestEventsResponses, BatchRequest, BatchRequestWritable, BatchResponse, BatchResponseWritable, BatchResultItem, BulkImportRequest, BulkImportRequestWritable, BulkImportResponse, BulkImportResponseWritable, BulkImportResult, BulkImportUser, BulkImportUsersData, BulkImportUsersError, BulkImportUsersErrors, BulkImportUsersResponse, BulkImportUsersResponses, CheckEmailDomainResponse, CheckEmailDomainResponseWritable, CheckPrincipalEmailDomainData, CheckPrincipalEmailDomainError, CheckPrincipalEmailDomainErrors, CheckPrincipalEmailDomainResponse, CheckPrincipalEmailDomainResponses, ClientAccessGrantListResponse, ClientAccessGrantListResponseWritable, ClientAccessGrantResponse, ClientAccessGrantResponseWritable, ClientApplicationResponse, ClientApplicationsResponse, ClientApplicationsResponseWritable, ClientAssociationRequest, ClientAssociationRequestWritable, ClientConfigListResponse, ClientConfigListResponseWritable, ClientConfigResponse, ClientConfigResponseWritable, ClientListResponse, ClientListResponseWritable, ClientOptions, ClientResponse, ClientResponseWritable, CompleteInstanceRequest, CompleteInstanceRequestWritable, CompleteScheduledJobInstanceData, CompleteScheduledJobInstanceError, CompleteScheduledJobInstanceErrors, CompleteScheduledJobInstanceResponse, CompleteScheduledJobInstanceResponses, ConfigEntryDto, ConfigListResponse, ConfigListResponseWritable, ConfigResponse, ConfigResponseWritable, ConnectionListResponse, ConnectionListResponseWritable, ConnectionResponse, ConnectionResponseWritable, ContextEntryDto, CorsOriginListResponse, CorsOriginListResponseWritable, CreateAnchorDomainData, CreateAnchorDomainError, CreateAnchorDomainErrors, CreateAnchorDomainRequest, CreateAnchorDomainRequestWritable, CreateAnchorDomainResponse, CreateAnchorDomainResponses, CreateApplicationData, CreateApplicationError, CreateApplicationErrors, CreateApplicationRequest, CreateApplicationRequestWritable, CreateApplicationResponse, CreateApplicationResponses, CreateAuthConfigData, CreateAuthConfigError, CreateAuthConfigErrors, CreateAuthConfigRequest, CreateAuthConfigRequestWritable, CreateAuthConfigResponse, CreateAuthConfigResponses, CreateClientData, CreateClientError, CreateClientErrors, CreateClientRequest, CreateClientRequestWritable, CreateClientResponse, CreateClientResponses, CreateConnectionData, CreateConnectionError, CreateConnectionErrors, CreateConnectionRequest, CreateConnectionRequestWritable, CreateConnectionResponse, CreateConnectionResponses, CreatedEvent, CreateDispatchPoolData, CreateDispatchPoolError, CreateDispatchPoolErrors, CreateDispatchPoolRequest, CreateDispatchPoolRequestWritable, CreateDispatchPoolResponse, CreateDispatchPoolResponses, CreatedResponse, CreatedResponseWritable, CreateEmailDomainMappingData, CreateEmailDomainMappingError, CreateEmailDomainMappingErrors, CreateEmailDomainMappingResponse, CreateEmailDomainMappingResponses, CreateEventData, CreateEventError, CreateEventErrors, CreateEventRequest, CreateEventRequestWritable, CreateEventResponse, CreateEventResponse2, CreateEventResponses, CreateEventResponseWritable, CreateEventTypeData, CreateEventTypeError, CreateEventTypeErrors, CreateEventTypeRequest, CreateEventTypeRequestWritable, CreateEventTypeResponse, CreateEventTypeResponses, CreateIdentityProviderData, CreateIdentityProviderError, CreateIdentityProviderErrors, CreateIdentityProviderRequest, CreateIdentityProviderRequestWritable, CreateIdentityProviderResponse, CreateIdentityProviderResponses, CreateIdpRoleMappingData, CreateIdpRoleMappingError, CreateIdpRoleMappingErrors, CreateIdpRoleMappingRequest, CreateIdpRoleMappingRequestWritable, CreateIdpRoleMappingResponse, CreateIdpRoleMappingResponses, CreateMappingRequest, CreateMappingRequestWritable, CreateOAuthClientData, CreateOAuthClientError, CreateOAuthClientErrors, CreateOAuthClientRequest, CreateOAuthClientRequestWritable, CreateOAuthClientResponse, CreateOAuthClientResponse2, CreateOAuthClientResponses, CreateOAuthClientResponseWritable, CreatePrincipalData, CreatePrincipalError, CreatePrincipalErrors, CreatePrincipalRequest, CreatePrincipalRequestWritable, CreatePrincipalResponse, CreatePrincipalResponses, CreateProcessData, CreateProcessError, CreateProcessErrors, CreateProcessRequest, CreateProcessRequestWritable, CreateProcessResponse, CreateProcessResponses, CreateRoleData, CreateRoleError, CreateRoleErrors, CreateRoleRequest, CreateRoleRequestWritable, CreateRoleResponse, CreateRoleResponses, CreateScheduledJobData, CreateScheduledJobError, CreateScheduledJobErrors, CreateScheduledJobRequest, CreateScheduledJobRequestWritable, CreateScheduledJobResponse, CreateScheduledJobResponses, CreateServiceAccountData, CreateServiceAccountError, CreateServiceAccountErrors, CreateServiceAccountRequest, CreateServiceAccountRequestWritable, CreateServiceAccountResponse, CreateServiceAccountResponse2, CreateServiceAccountResponses, CreateServiceAccountResponseWritable, CreateSubscriptionData, CreateSubscriptionError, CreateSubscriptionErrors, CreateSubscriptionRequest, CreateSubscriptionRequestWritable, CreateSubscriptionResponse, CreateSubscriptionResponses, CreateUserData, CreateUserError, CreateUserErrors, CreateUserRequest, CreateUserRequestWritable, CreateUserResponse, CreateUserResponses, DeactivateApplicationData, DeactivateApplicationError, DeactivateApplicationErrors, DeactivateApplicationResponse, DeactivateApplicationResponses, DeactivateClientData, DeactivateClientError, DeactivateClientErrors, DeactivateClientResponse, DeactivateClientResponses, DeactivateOAuthClientData, DeactivateOAuthClientError, DeactivateOAuthClientErrors, DeactivateOAuthClientResponse, DeactivateOAuthClientResponses, DeactivatePrincipalData, DeactivatePrincipalError, DeactivatePrincipalErrors, DeactivatePrincipalResponse, DeactivatePrincipalResponses, DeactivateServiceAccountData, DeactivateServiceAccountError, DeactivateServiceAccountErrors, DeactivateServiceAccountResponse, DeactivateServiceAccountResponses, DeleteAnchorDomainData, DeleteAnchorDomainError, DeleteAnchorDomainErrors, DeleteAnchorDomainResponse, DeleteAnchorDomainResponses, DeleteApplicationData, DeleteApplicationError, DeleteApplicationErrors, DeleteApplicationResponse, DeleteApplicationResponses, DeleteAuthConfigData, DeleteAuthConfigError, DeleteAuthConfigErrors, DeleteAuthConfigResponse, DeleteAuthConfigResponses, DeleteClientData, DeleteClientError, DeleteClientErrors, DeleteClientResponse, DeleteClientResponses, DeleteConnectionData, DeleteConnectionError, DeleteConnectionErrors, DeleteConnectionResponse, DeleteConnectionResponses, DeleteCorsOriginData, DeleteCorsOriginError, DeleteCorsOriginErrors, DeleteCorsOriginResponse, DeleteCorsOriginResponses, DeleteDispatchPoolData, DeleteDispatchPoolError, DeleteDispatchPoolErrors, DeleteDispatchPoolResponse, DeleteDispatchPoolResponses, DeleteEmailDomainMappingData, DeleteEmailDomainMappingError, DeleteEmailDomainMappingErrors, DeleteEmailDomainMappingResponse, DeleteEmailDomainMappingResponses, DeleteEventTypeData, DeleteEventTypeError, DeleteEventTypeErrors, DeleteEventTypeResponse, DeleteEventTypeResponses, DeleteIdentityProviderData, DeleteIdentityProviderError, DeleteIdentityProviderErrors, DeleteIdentityProviderResponse, DeleteIdentityProviderResponses, DeleteIdpRoleMappingData, DeleteIdpRoleMappingError, DeleteIdpRoleMappingErrors, DeleteIdpRoleMappingResponse, DeleteIdpRoleMappingResponses, DeleteOAuthClientData, DeleteOAuthClientError, DeleteOAuthClientErrors, DeleteOAuthClientResponse, DeleteOAuthClientResponses, DeletePermissionData, DeletePermissionError, DeletePermissionErrors, DeletePermissionResponse, DeletePermissionResponses, DeletePlatformConfigPropertyData, DeletePlatformConfigPropertyError, DeletePlatformConfigPropertyErrors, DeletePlatformConfigPropertyResponse, DeletePlatformConfigPropertyResponses, DeletePrincipalData, DeletePrincipalError, DeletePrincipalErrors, DeletePrincipalResponse, DeletePrincipalResponses, DeleteProcessData, DeleteProcessError, DeleteProcessErrors, DeleteProcessResponse, DeleteProcessResponses, DeleteRoleData, DeleteRoleError, DeleteRoleErrors, DeleteRoleResponse, DeleteRoleResponses, DeleteScheduledJobData, DeleteScheduledJobError, DeleteScheduledJobErrors, DeleteScheduledJobResponse, DeleteScheduledJobResponses, DeleteServiceAccountData, DeleteServiceAccountError, DeleteServiceAccountErrors, DeleteServiceAccountResponse, DeleteServiceAccountResponses, DeleteSubscriptionData, DeleteSubscriptionError, DeleteSubscriptionErrors, DeleteSubscriptionResponse, DeleteSubscriptionResponses, DeleteWebauthnCredentialData, DeleteWebauthnCredentialError, DeleteWebauthnCredentialErrors, DeleteWebauthnCredentialResponse, DeleteWebauthnCredentialResponses, DenyResetApprovalData, DenyResetApprovalError, DenyResetApprovalErrors, DenyResetApprovalResponse, DenyResetApprovalResponses, DeveloperUserListResponse, DeveloperUserListResponseWritable, DisableApplicationForClientData, DisableApplicationForClientError, DisableApplicationForClientErrors, DisableApplicationForClientResponse, DisableApplicationForClientResponses, DisableClientApplicationData, DisableClientApplicationError, DisableClientApplicationErrors, DisableClientApplicationResponse, DisableClientApplicationResponses, DispatchJobFilterOptionsData, DispatchJobFilterOptionsError, DispatchJobFilterOptionsErrors, DispatchJobFilterOptionsResponse, DispatchJobFilterOptionsResponse2, DispatchJobFilterOptionsResponses, DispatchJobFilterOptionsResponseWritable, DispatchJobRead, DispatchJobResponse, DispatchJobResponseWritable, DispatchJobsByEventAliasData, DispatchJobsByEventAliasError, DispatchJobsByEventAliasErrors, DispatchJobsByEventAliasResponse, DispatchJobsByEventAliasResponses, DispatchJobsByEventData, DispatchJobsByEventError, DispatchJobsByEventErrors, DispatchJobsByEventResponse, DispatchJobsByEventResponses, DispatchPoolListResponse, DispatchPoolListResponseWritable, DispatchPoolResponse, DispatchPoolResponseWritable, EnableApplicationForClientData, EnableApplicationForClientError, EnableApplicationForClientErrors, EnableApplicationForClientResponse, EnableApplicationForClientResponses, EnableClientApplicationData, EnableClientApplicationError, EnableClientApplicationErrors, EnableClientApplicationResponse, EnableClientApplicationResponses, ErrorModel, ErrorModelWritable, EventFilterOption, EventFilterOptionsData, EventFilterOptionsError, EventFilterOptionsErrors, EventFilterOptionsResponse, EventFilterOptionsResponse2, EventFilterOptionsResponses, EventFilterOptionsResponseWritable, EventRead, EventResponse, EventResponseWritable, EventTypeBindingDto, EventTypeListResponse, EventTypeListResponseWritable, EventTypeResponse, EventTypeResponseWritable, FireNowRequest, FireNowRequestWritable, FireNowResponse, FireNowResponseWritable, FireScheduledJobNowData, FireScheduledJobNowError, FireScheduledJobNowErrors, FireScheduledJobNowResponse, FireScheduledJobNowResponses, GetApplicationByCodeData, GetApplicationByCodeError, GetApplicationByCodeErrors, GetApplicationByCodeResponse, GetApplicationByCodeResponses, GetApplicationClientConfigData, GetApplicationClientConfigError, GetApplicationClientConfigErrors, GetApplicationClientConfigResponse, GetApplicationClientConfigResponses, GetApplicationData, GetApplicationError, GetApplicationErrors, GetApplicationResponse, GetApplicationResponses, GetAuditLogData, GetAuditLogError, GetAuditLogErrors, GetAuditLogResponse, GetAuditLogResponses, GetClientApplicationsData, GetClientApplicationsError, GetClientApplicationsErrors, GetClientApplicationsResponse, GetClientApplicationsResponses, GetClientByIdentifierData, GetClientByIdentifierError, GetClientByIdentifierErrors, GetClientByIdentifierResponse, GetClientByIdentifierResponses, GetClientData, GetClientError, GetClientErrors, GetClientResponse, GetClientResponses, GetConnectionData, GetConnectionError, GetConnectionErrors, GetConnectionResponse, GetConnectionResponses, GetCorsOriginData, GetCorsOriginError, GetCorsOriginErrors, GetCorsOriginResponse, GetCorsOriginResponses, GetDispatchJobData, GetDispatchJobError, GetDispatchJobErrors, GetDispatchJobRawData, GetDispatchJobRawError, GetDispatchJobRawErrors, GetDispatchJobRawResponse, GetDispatchJobRawResponses, GetDispatchJobResponse, GetDispatchJobResponses, GetDispatchPoolData, GetDispatchPoolError, GetDispatchPoolErrors, GetDispatchPoolResponse, GetDispatchPoolResponses, GetEmailDomainMappingByDomainData, GetEmailDomainMappingByDomainError, GetEmailDomainMappingByDomainErrors, GetEmailDomainMappingByDomainResponse, GetEmailDomainMappingByDomainResponses, GetEmailDomainMappingData, GetEmailDomainMappingError, GetEmailDomainMappingErrors, GetEmailDomainMappingResponse, GetEmailDomainMappingResponses, GetEventData, GetEventError, GetEventErrors, GetEventResponse, GetEventResponses, GetEventTypeByCodeData, GetEventTypeByCodeError, GetEventTypeByCodeErrors, GetEventTypeByCodeResponse, GetEventTypeByCodeResponses, GetEventTypeData, GetEventTypeError, GetEventTypeErrors, GetEventTypeResponse, GetEventTypeResponses, GetIdentityProviderData, GetIdentityProviderError, GetIdentityProviderErrors, GetIdentityProviderResponse, GetIdentityProviderResponses, GetOAuthClientByClientIdData, GetOAuthClientByClientIdError, GetOAuthClientByClientIdErrors, GetOAuthClientByClientIdResponse, GetOAuthClientByClientIdResponses, GetOAuthClientData, GetOAuthClientError, GetOAuthClientErrors, GetOAuthClientResponse, GetOAuthClientResponses, GetPermissionData, GetPermissionError, GetPermissionErrors, GetPermissionResponse, GetPermissionResponses, GetPlatformConfigPropertyData, GetPlatformConfigPropertyError, GetPlatformConfigPropertyErrors, GetPlatformConfigPropertyResponse, GetPlatformConfigPropertyResponses, GetPrincipalData, GetPrincipalError, GetPrincipalErrors, GetPrincipalResponse, GetPrincipalResponses, GetPrincipalVersionData, GetPrincipalVersionError, GetPrincipalVersionErrors, GetPrincipalVersionResponse, GetPrincipalVersionResponses, GetProcessByCodeData, GetProcessByCodeError, GetProcessByCodeErrors, GetProcessByCodeResponse, GetProcessByCodeResponses, GetProcessData, GetProcessError, GetProcessErrors, GetProcessResponse, GetProcessResponses, GetRoleApplicationFiltersData, GetRoleApplicationFiltersError, GetRoleApplicationFiltersErrors, GetRoleApplicationFiltersResponse, GetRoleApplicationFiltersResponses, GetRoleByCodeData, GetRoleByCodeError, GetRoleByCodeErrors, GetRoleByCodeResponse, GetRoleByCodeResponses, GetRoleData, GetRoleError, GetRoleErrors, GetRoleResponse, GetRoleResponses, GetRolesByApplicationData, GetRolesByApplicationError, GetRolesByApplicationErrors, GetRolesByApplicationResponse, GetRolesByApplicationResponses, GetRolesBySourceData, GetRolesBySourceError, GetRolesBySourceErrors, GetRolesBySourceResponse, GetRolesBySourceResponses, GetScheduledJobByCodeData, GetScheduledJobByCodeError, GetScheduledJobByCodeErrors, GetScheduledJobByCodeResponse, GetScheduledJobByCodeResponses, GetScheduledJobData, GetScheduledJobError, GetScheduledJobErrors, GetScheduledJobInstanceData, GetScheduledJobInstanceError, GetScheduledJobInstanceErrors, GetScheduledJobInstanceResponse, GetScheduledJobInstanceResponses, GetScheduledJobResponse, GetScheduledJobResponses, GetServiceAccountByCodeData, GetServiceAccountByCodeError, GetServiceAccountByCodeErrors, GetServiceAccountByCodeResponse, GetServiceAccountByCodeResponses, GetServiceAccountData, GetServiceAccountError, GetServiceAccountErrors, GetServiceAccountResponse, GetServiceAccountResponses, GetSubscriptionData, GetSubscriptionError, GetSubscriptionErrors, GetSubscriptionResponse, GetSubscriptionResponses, GrantAccessRequest, GrantAccessRequestWritable, GrantClientAccessRequest, GrantClientAccessRequestWritable, GrantPermissionRequest, GrantPermissionRequestWritable, GrantPlatformConfigAccessData, GrantPlatformConfigAccessError, GrantPlatformConfigAccessErrors, GrantPlatformConfigAccessResponse, GrantPlatformConfigAccessResponses, GrantPrincipalClientAccessData, GrantPrincipalClientAccessError, GrantPrincipalClientAccessErrors, GrantPrincipalClientAccessResponse, GrantPrincipalClientAccessResponses, GrantRolePermissionByBodyData, GrantRolePermissionByBodyError, GrantRolePermissionByBodyErrors, GrantRolePermissionByBodyResponse, GrantRolePermissionByBodyResponses, GrantRolePermissionData, GrantRolePermissionError, GrantRolePermissionErrors, GrantRolePermissionResponse, GrantRolePermissionResponses, IdentityProviderListResponse, IdentityProviderListResponseWritable, IdentityProviderResponse, IdentityProviderResponseWritable, IdpRoleMappingListResponse, IdpRoleMappingListResponseWritable, IdpRoleMappingResponse, ListAnchorDomainsData, ListAnchorDomainsError, ListAnchorDomainsErrors, ListAnchorDomainsResponse, ListAnchorDomainsResponses, ListApplicationClientConfigsData, ListApplicationClientConfigsError, ListApplicationClientConfigsErrors, ListApplicationClientConfigsResponse, ListApplicationClientConfigsResponses, ListApplicationRolesData, ListApplicationRolesError, ListApplicationRolesErrors, ListApplicationRolesResponse, ListApplicationRolesResponses, ListApplicationsData, ListApplicationsError, ListApplicationsErrors, ListApplicationsResponse, ListApplicationsResponses, ListAuditLogsData, ListAuditLogsError, ListAuditLogsErrors, ListAuditLogsRecentData, ListAuditLogsRecentError, ListAuditLogsRecentErrors, ListAuditLogsRecentResponse, ListAuditLogsRecentResponses, ListAuditLogsResponse, ListAuditLogsResponses, ListAuthConfigsData, ListAuthConfigsError, ListAuthConfigsErrors, ListAuthConfigsResponse, ListAuthConfigsResponses, ListClientsData, ListClientsError, ListClientsErrors, ListClientsResponse, ListClientsResponses, ListConnectionsData, ListConnectionsError, ListConnectionsErrors, ListConnectionsResponse, ListConnectionsResponses, ListCorsOriginsData, ListCorsOriginsError, ListCorsOriginsErrors, ListCorsOriginsResponse, ListCorsOriginsResponses, ListDeveloperUsersData, ListDeveloperUsersError, ListDeveloperUsersErrors, ListDeveloperUsersResponse, ListDeveloperUsersResponses, ListDispatchJobAttemptsData, ListDispatchJobAttemptsError, ListDispatchJobAttemptsErrors, ListDispatchJobAttemptsResponse, ListDispatchJobAttemptsResponses, ListDispatchJobsData, ListDispatchJobsError, ListDispatchJobsErrors, ListDispatchJobsRawAliasData, ListDispatchJobsRawAliasError, ListDispatchJobsRawAliasErrors, ListDispatchJobsRawAliasResponse, ListDispatchJobsRawAliasResponses, ListDispatchJobsRawData, ListDispatchJobsRawError, ListDispatchJobsRawErrors, ListDispatchJobsRawResponse, ListDispatchJobsRawResponses, ListDispatchJobsResponse, ListDispatchJobsResponses, ListDispatchPoolsData, ListDispatchPoolsError, ListDispatchPoolsErrors, ListDispatchPoolsResponse, ListDispatchPoolsResponses, ListEmailDomainMappingsData, ListEmailDomainMappingsError, ListEmailDomainMappingsErrors, ListEmailDomainMappingsResponse, ListEmailDomainMappingsResponses, ListEventsData, ListEventsError, ListEventsErrors, ListEventsRawAliasData, ListEventsRawAliasError, ListEventsRawAliasErrors, ListEventsRawAliasResponse, ListEventsRawAliasResponses, ListEventsRawData, ListEventsRawError, ListEventsRawErrors, ListEventsRawResponse, ListEventsRawResponses, ListEventsResponse, ListEventsResponses, ListEventTypesData, ListEventTypesError, ListEventTypesErrors, ListEventTypesResponse, ListEventTypesResponses, ListIdentityProvidersData, ListIdentityProvidersError, ListIdentityProvidersErrors, ListIdentityProvidersResponse, ListIdentityProvidersResponses, ListIdpRoleMappingsData, ListIdpRoleMappingsError, ListIdpRoleMappingsErrors, ListIdpRoleMappingsResponse, ListIdpRoleMappingsResponses, ListLoginAttemptsData, ListLoginAttemptsError, ListLoginAttemptsErrors, ListLoginAttemptsResponse, ListLoginAttemptsResponses, ListOAuthClientsData, ListOAuthClientsError, ListOAuthClientsErrors, ListOAuthClientsResponse, ListOAuthClientsResponses, ListOutputBody, ListOutputBodyWritable, ListPermissionsData, ListPermissionsError, ListPermissionsErrors, ListPermissionsResponse, ListPermissionsResponses, ListPlatformConfigAccessData, ListPlatformConfigAccessError, ListPlatformConfigAccessErrors, ListPlatformConfigAccessResponse, ListPlatformConfigAccessResponses, ListPlatformConfigPropertiesData, ListPlatformConfigPropertiesError, ListPlatformConfigPropertiesErrors, ListPlatformConfigPropertiesResponse, ListPlatformConfigPropertiesResponses, ListPrincipalApplicationAccessData, ListPrincipalApplicationAccessError, ListPrincipalApplicationAccessErrors, ListPrincipalApplicationAccessResponse, ListPrincipalApplicationAccessResponses, ListPrincipalAvailableApplicationsData, ListPrincipalAvailableApplicationsError, ListPrincipalAvailableApplicationsErrors, ListPrincipalAvailableApplicationsResponse, ListPrincipalAvailableApplicationsResponses, ListPrincipalClientAccessData, ListPrincipalClientAccessError, ListPrincipalClientAccessErrors, ListPrincipalClientAccessResponse, ListPrincipalClientAccessResponses, ListPrincipalRolesData, ListPrincipalRolesError, ListPrincipalRolesErrors, ListPrincipalRolesResponse, ListPrincipalRolesResponses, ListPrincipalsData, ListPrincipalsError, ListPrincipalsErrors, ListPrincipalsResponse, ListPrincipalsResponses, ListProcessesData, ListProcessesError, ListProcessesErrors, ListProcessesResponse, ListProcessesResponses, ListResetApprovalsData, ListResetApprovalsError, ListResetApprovalsErrors, ListResetApprovalsResponse, ListResetApprovalsResponses, ListRolePermissionsData, ListRolePermissionsError, ListRolePermissionsErrors, ListRolePermissionsResponse, ListRolePermissionsResponses, ListRolesData, ListRolesError, ListRolesErrors, ListRolesResponse, ListRolesResponses, ListScheduledJobInstanceLogsData, ListScheduledJobInstanceLogsError, ListScheduledJobInstanceLogsErrors, ListScheduledJobInstanceLogsResponse, ListScheduledJobInstanceLogsResponses, ListScheduledJobInstancesData, ListScheduledJobInstancesError, ListScheduledJobInstancesErrors, ListScheduledJobInstancesResponse, ListScheduledJobInstancesResponses, ListScheduledJobsData, ListScheduledJobsError, ListScheduledJobsErrors, ListScheduledJobsResponse, ListScheduledJobsResponses, ListServiceAccountRolesData, ListServiceAccountRolesError, ListServiceAccountRolesErrors, ListServiceAccountRolesResponse, ListServiceAccountRolesResponses, ListServiceAccountsData, ListServiceAccountsError, ListServiceAccountsErrors, ListServiceAccountsResponse, ListServiceAccountsResponses, ListSubscriptionsData, ListSubscriptionsError, ListSubscriptionsErrors, ListSubscriptionsResponse, ListSubscriptionsResponses, ListWebauthnCredentialsData, ListWebauthnCredentialsError, ListWebauthnCredentialsErrors, ListWebauthnCredentialsResponse, ListWebauthnCredentialsResponses, LoginAttemptListResponse, LoginAttemptListResponseWritable, LoginAttemptResponse, LookupEmailDomainMappingData, LookupEmailDomainMappingError, LookupEmailDomainMappingErrors, LookupEmailDomainMappingResponses, MappingListResponse, MappingListResponseWritable, MappingResponse, MappingResponseWritable, MetadataDto, NoteResponse, OAuthClientApplicationRef, OAuthClientListResponse, OAuthClientListResponseWritable, OAuthClientResponse, OAuthClientResponseWritable, OffsetPageScheduledJobInstanceResponse, OffsetPageScheduledJobInstanceResponseWritable, OffsetPageScheduledJobResponse, OffsetPageScheduledJobResponseWritable, PauseConnectionData, PauseConnectionError, PauseConnectionErrors, PauseConnectionResponse, PauseConnectionResponses, PauseScheduledJobData, PauseScheduledJobError, PauseScheduledJobErrors, PauseScheduledJobResponse, PauseScheduledJobResponses, PauseSubscriptionData, PauseSubscriptionError, PauseSubscriptionErrors, PauseSubscriptionResponse, PauseSubscriptionResponses, PermissionListResponse, PermissionListResponseWritable, PermissionResponse, PermissionResponseWritable, PrincipalAvailableApplication, PrincipalAvailableApplicationsResponse, PrincipalAvailableApplicationsResponseWritable, PrincipalListResponse, PrincipalListResponseWritable, PrincipalResponse, PrincipalResponseWritable, PrincipalRoleAssignmentDto, PrincipalRoleListResponse, PrincipalRoleListResponseWritable, PrincipalVersionResponse, PrincipalVersionResponseWritable, ProcessListResponse, ProcessListResponseWritable, ProcessResponse, ProcessResponseWritable, ProvisionApplicationLoginClientData, ProvisionApplicationLoginClientError, ProvisionApplicationLoginClientErrors, ProvisionApplicationLoginClientResponse, ProvisionApplicationLoginClientResponses, ProvisionApplicationServiceAccountData, ProvisionApplicationServiceAccountError, ProvisionApplicationServiceAccountErrors, ProvisionApplicationServiceAccountResponse, ProvisionApplicationServiceAccountResponses, ProvisionLoginClientRequest, ProvisionLoginClientRequestWritable, PublicAllowedOriginsData, PublicAllowedOriginsError, PublicAllowedOriginsErrors, PublicAllowedOriginsResponse, PublicAllowedOriginsResponses, PublicAllowedResponse, PublicAllowedResponseWritable, RawDispatchJobResponse, RawEventResponse, RegenerateAuthTokenResponse, RegenerateAuthTokenResponseWritable, RegenerateOAuthClientSecretData, RegenerateOAuthClientSecretError, RegenerateOAuthClientSecretErrors, RegenerateOAuthClientSecretResponse, RegenerateOAuthClientSecretResponses, RegenerateServiceAccountAuthTokenRegenerateAuthTokenData, RegenerateServiceAccountAuthTokenRegenerateAuthTokenError, RegenerateServiceAccountAuthTokenRegenerateAuthTokenErrors, RegenerateServiceAccountAuthTokenRegenerateAuthTokenResponse, RegenerateServiceAccountAuthTokenRegenerateAuthTokenResponses, RegenerateServiceAccountAuthTokenRegenerateTokenData, RegenerateServiceAccountAuthTokenRegenerateTokenError, RegenerateServiceAccountAuthTokenRegenerateTokenErrors, RegenerateServiceAccountAuthTokenRegenerateTokenResponse, RegenerateServiceAccountAuthTokenRegenerateTokenResponses, RegenerateServiceAccountSigningSecretRegenerateSecretData, RegenerateServiceAccountSigningSecretRegenerateSecretError, RegenerateServiceAccountSigningSecretRegenerateSecretErrors, RegenerateServiceAccountSigningSecretRegenerateSecretResponse, RegenerateServiceAccountSigningSecretRegenerateSecretResponses, RegenerateServiceAccountSigningSecretRegenerateSigningSecretData, RegenerateServiceAccountSigningSecretRegenerateSigningSecretError, RegenerateServiceAccountSigningSecretRegenerateSigningSecretErrors, RegenerateServiceAccountSigningSecretRegenerateSigningSecretResponse, RegenerateServiceAccountSigningSecretRegenerateSigningSecretResponses, RegenerateSigningSecretResponse, RegenerateSigningSecretResponseWritable, RegisterBeginRequest, RegisterBeginRequestWritable, RegisterBeginResponse, RegisterBeginResponseWritable, RegisterCompleteRequest, RegisterCompleteRequestWritable, RegisterCompleteResponse, RegisterCompleteResponseWritable, RemovePrincipalRoleData, RemovePrincipalRoleError, RemovePrincipalRoleErrors, RemovePrincipalRoleResponse, RemovePrincipalRoleResponses, RequestDto, RequeueDispatchJobsData, RequeueDispatchJobsError, RequeueDispatchJobsErrors, RequeueDispatchJobsResponse, RequeueDispatchJobsResponses, RequeueRequest, RequeueRequestWritable, RequeueResponse, RequeueResponseWritable, ResetPasswordRequest, ResetPasswordRequestWritable, ResetPrincipalPasswordData, ResetPrincipalPasswordError, ResetPrincipalPasswordErrors, ResetPrincipalPasswordResponse, ResetPrincipalPasswordResponses, ResetPrincipalTwoFactorData, ResetPrincipalTwoFactorError, ResetPrincipalTwoFactorErrors, ResetPrincipalTwoFactorResponse, ResetPrincipalTwoFactorResponses, ResumeScheduledJobData, ResumeScheduledJobError, ResumeScheduledJobErrors, ResumeScheduledJobResponse, ResumeScheduledJobResponses, ResumeSubscriptionData, ResumeSubscriptionError, ResumeSubscriptionErrors, ResumeSubscriptionResponse, ResumeSubscriptionResponses, RevokePlatformConfigAccessData, RevokePlatformConfigAccessError, RevokePlatformConfigAccessErrors, RevokePlatformConfigAccessResponse, RevokePlatformConfigAccessResponses, RevokePrincipalClientAccessData, RevokePrincipalClientAccessError, RevokePrincipalClientAccessErrors, RevokePrincipalClientAccessResponse, RevokePrincipalClientAccessResponses, RevokePrincipalDeveloperCredentialData, RevokePrincipalDeveloperCredentialError, RevokePrincipalDeveloperCredentialErrors, RevokePrincipalDeveloperCredentialResponse, RevokePrincipalDeveloperCredentialResponses, RevokeRolePermissionData, RevokeRolePermissionError, RevokeRolePermissionErrors, RevokeRolePermissionResponse, RevokeRolePermissionResponses, RoleAssignmentDto, RoleListResponse, RoleListResponseWritable, RolePermissionListResponse, RolePermissionListResponseWritable, RoleResponse, RoleResponseWritable, RolesAssignedResponse, RolesAssignedResponseWritable, RotateOAuthClientSecretData, RotateOAuthClientSecretError, RotateOAuthClientSecretErrors, RotateOAuthClientSecretResponse, RotateOAuthClientSecretResponse2, RotateOAuthClientSecretResponses, RotateOAuthClientSecretResponseWritable, ScheduledJobInstanceLogResponse, ScheduledJobInstanceResponse, ScheduledJobInstanceResponseWritable, ScheduledJobResponse, ScheduledJobResponseWritable, SearchClientRequest, SearchClientRequestWritable, SearchClientsByQueryData, SearchClientsByQueryError, SearchClientsByQueryErrors, SearchClientsByQueryResponse, SearchClientsByQueryResponses, SearchClientsData, SearchClientsError, SearchClientsErrors, SearchClientsResponse, SearchClientsResponses, SendPasswordResetInputBody, SendPasswordResetInputBodyWritable, SendPrincipalPasswordResetData, SendPrincipalPasswordResetError, SendPrincipalPasswordResetErrors, SendPrincipalPasswordResetResponse, SendPrincipalPasswordResetResponses, ServiceAccountListResponse, ServiceAccountListResponseWritable, ServiceAccountOAuthSecrets, ServiceAccountResponse, ServiceAccountResponseWritable, ServiceAccountRoleListResponse, ServiceAccountRoleListResponseWritable, ServiceAccountRolesAssignedResponse, ServiceAccountRolesAssignedResponseWritable, ServiceAccountWebhookSecrets, SetApplicationAccessResponse, SetApplicationAccessResponseWritable, SetDeveloperCredentialResponse, SetDeveloperCredentialResponseWritable, SetPlatformConfigPropertyData, SetPlatformConfigPropertyError, SetPlatformConfigPropertyErrors, SetPlatformConfigPropertyResponse, SetPlatformConfigPropertyResponses, SetPrincipalClientAssociationData, SetPrincipalClientAssociationError, SetPrincipalClientAssociationErrors, SetPrincipalClientAssociationResponse, SetPrincipalClientAssociationResponses, SetPrincipalDeveloperCredentialData, SetPrincipalDeveloperCredentialError, SetPrincipalDeveloperCredentialErrors, SetPrincipalDeveloperCredentialResponse, SetPrincipalDeveloperCredentialResponses, SetPropertyRequest, SetPropertyRequestWritable, SpecVersionResponse, StatusChangeRequest, StatusChangeRequestWritable, StatusChangeResponse, StatusChangeResponseWritable, SubscriptionListResponse, SubscriptionListResponseWritable, SubscriptionResponse, SubscriptionResponseWritable, SuccessResponse, SuccessResponseWritable, SuspendClientData, SuspendClientError, SuspendClientErrors, SuspendClientRequest, SuspendClientRequestWritable, SuspendClientResponse, SuspendClientResponses, SuspendDispatchPoolData, SuspendDispatchPoolError, SuspendDispatchPoolErrors, SuspendDispatchPoolResponse, SuspendDispatchPoolResponses, SyncDispatchPoolInputRequest, SyncDispatchPoolsData, SyncDispatchPoolsError, SyncDispatchPoolsErrors, SyncDispatchPoolsRequest, SyncDispatchPoolsRequestWritable, SyncDispatchPoolsResponse, SyncDispatchPoolsResponses, SyncEventTypeInputRequest, SyncEventTypesData, SyncEventTypesError, SyncEventTypesErrors, SyncEventTypesRequest, SyncEventTypesRequestWritable, SyncEventTypesResponse, SyncEventTypesResponses, SyncOpenapiData, SyncOpenapiError, SyncOpenapiErrors, SyncOpenapiRequest, SyncOpenapiRequestWritable, SyncOpenapiResponse, SyncOpenapiResponses, SyncOpenApiSpecResponse, SyncOpenApiSpecResponseWritable, SyncPrincipalInputRequest, SyncPrincipalsData, SyncPrincipalsError, SyncPrincipalsErrors, SyncPrincipalsRequest, SyncPrincipalsRequestWritable, SyncPrincipalsResponse, SyncPrincipalsResponses, SyncProcessesByBodyData, SyncProcessesByBodyError, SyncProcessesByBodyErrors, SyncProcessesByBodyRequest, SyncProcessesByBodyRequestWritable, SyncProcessesByBodyResponse, SyncProcessesByBodyResponses, SyncProcessesData, SyncProcessesError, SyncProcessesErrors, SyncProcessesRequest, SyncProcessesRequestWritable, SyncProcessesResponse, SyncProcessesResponses, SyncProcessInputRequest, SyncResultResponse, SyncResultResponseWritable, SyncRoleInputRequest, SyncRolesData, SyncRolesError, SyncRolesErrors, SyncRolesRequest, SyncRolesRequestWritable, SyncRolesResponse, SyncRolesResponses, SyncScheduledJobInputRequest, SyncScheduledJobsData, SyncScheduledJobsError, SyncScheduledJobsErrors, SyncScheduledJobsRequest, SyncScheduledJobsRequestWritable, SyncScheduledJobsResponse, SyncScheduledJobsResponses, SyncScheduledJobsResultResponse, SyncScheduledJobsResultResponseWritable, SyncSubscriptionEventTypeRequest, SyncSubscriptionInputRequest, SyncSubscriptionsData, SyncSubscriptionsError, SyncSubscriptionsErrors, SyncSubscriptionsRequest, SyncSubscriptionsRequestWritable, SyncSubscriptionsResponse, SyncSubscriptionsResponses, SyncUserInput, SyncUsersData, SyncUsersError, SyncUsersErrors, SyncUsersRequest, SyncUsersRequestWritable, SyncUsersResponse, SyncUsersResponse2, SyncUsersResponses, SyncUsersResponseWritable, UpdateAnchorDomainData, UpdateAnchorDomainError, UpdateAnchorDomainErrors, UpdateAnchorDomainRequest, UpdateAnchorDomainRequestWritable, UpdateAnchorDomainResponse, UpdateAnchorDomainResponses, UpdateApplicationData, UpdateApplicationError, UpdateApplicationErrors, UpdateApplicationRequest, UpdateApplicationRequestWritable, UpdateApplicationResponse, UpdateApplicationResponses, UpdateAuthConfigData, UpdateAuthConfigError, UpdateAuthConfigErrors, UpdateAuthConfigRequest, UpdateAuthConfigRequestWritable, UpdateAuthConfigResponse, UpdateAuthConfigResponses, UpdateClientApplicationsData, UpdateClientApplicationsError, UpdateClientApplicationsErrors, UpdateClientApplicationsRequest, UpdateClientApplicationsRequestWritable, UpdateClientApplicationsResponse, UpdateClientApplicationsResponses, UpdateClientData, UpdateClientError, UpdateClientErrors, UpdateClientRequest, UpdateClientRequestWritable, UpdateClientResponse, UpdateClientResponses, UpdateConnectionData, UpdateConnectionError, UpdateConnectionErrors, UpdateConnectionRequest, UpdateConnectionRequestWritable, UpdateConnectionResponse, UpdateConnectionResponses, UpdateDispatchPoolData, UpdateDispatchPoolError, UpdateDispatchPoolErrors, UpdateDispatchPoolRequest, UpdateDispatchPoolRequestWritable, UpdateDispatchPoolResponse, UpdateDispatchPoolResponses, UpdateEmailDomainMappingData, UpdateEmailDomainMappingError, UpdateEmailDomainMappingErrors, UpdateEmailDomainMappingResponse, UpdateEmailDomainMappingResponses, UpdateEventTypeData, UpdateEventTypeError, UpdateEventTypeErrors, UpdateEventTypeRequest, UpdateEventTypeRequestWritable, UpdateEventTypeResponse, UpdateEventTypeResponses, UpdateIdentityProviderData, UpdateIdentityProviderError, UpdateIdentityProviderErrors, UpdateIdentityProviderRequest, UpdateIdentityProviderRequestWritable, UpdateIdentityProviderResponse, UpdateIdentityProviderResponses, UpdateMappingRequest, UpdateMappingRequestWritable, UpdateOAuthClientData, UpdateOAuthClientError, UpdateOAuthClientErrors, UpdateOAuthClientRequest, UpdateOAuthClientRequestWritable, UpdateOAuthClientResponse, UpdateOAuthClientResponses, UpdatePrincipalData, UpdatePrincipalError, UpdatePrincipalErrors, UpdatePrincipalRequest, UpdatePrincipalRequestWritable, UpdatePrincipalResponse, UpdatePrincipalResponses, UpdateProcessData, UpdateProcessError, UpdateProcessErrors, UpdateProcessRequest, UpdateProcessRequestWritable, UpdateProcessResponse, UpdateProcessResponses, UpdateRoleData, UpdateRoleError, UpdateRoleErrors, UpdateRoleRequest, UpdateRoleRequestWritable, UpdateRoleResponse, UpdateRoleResponses, UpdateScheduledJobData, UpdateScheduledJobError, UpdateScheduledJobErrors, UpdateScheduledJobRequest, UpdateScheduledJobRequestWritable, UpdateScheduledJobResponse, UpdateScheduledJobResponses, UpdateServiceAccountData, UpdateServiceAccountError, UpdateServiceAccountErrors, UpdateServiceAccountRequest, UpdateServiceAccountRequestWritable, UpdateServiceAccountResponse, UpdateServiceAccountResponses, UpdateSubscriptionData, UpdateSubscriptionError, UpdateSubscriptionErrors, UpdateSubscriptionRequest, UpdateSubscriptionRequestWritable, UpdateSubscriptionResponse, UpdateSubscriptionResponses, WebauthnAuthenticateBeginData, WebauthnAuthenticateBeginError, WebauthnAuthenticateBeginErrors, WebauthnAuthenticateBeginResponse, WebauthnAuthenticateBeginResponses, WebauthnAuthenticateCompleteData, WebauthnAuthenticateCompleteError, WebauthnAuthenticateCompleteErrors, WebauthnAuthenticateCompleteResponse, WebauthnAuthenticateCompleteResponse2, WebauthnAuthenticateCompleteResponses, WebauthnAuthenticateCompleteResponseWritable, WebauthnCredentialSummary, WebauthnRegisterBeginData, WebauthnRegisterBeginError, WebauthnRegisterBeginErrors, WebauthnRegisterBeginResponse, WebauthnRegisterBeginResponses, WebauthnRegisterCompleteData, WebauthnRegisterCompleteError, WebauthnRegisterCompleteErrors, WebauthnRegisterCompleteResponse, WebauthnRegisterCompleteResponses, WebhookCredentialsDto, WriteInstanceLogRequest, WriteInstanceLogRequestWritable, WriteScheduledJobInstanceLogData, WriteScheduledJobInstanceLogError, WriteScheduledJobInstanceLogErrors, WriteScheduledJobInstanceLogResponse, WriteScheduledJobInstanceLogResponses } from './types.gen';
+export type { AccessListResponse, AccessListResponseWritable, AccessResponse, AckConsumedMessageData, AckConsumedMessageError, AckConsumedMessageErrors, AckConsumedMessageResponse, AckConsumedMessageResponses, AckDispatchJobData, AckDispatchJobError, AckDispatchJobErrors, AckDispatchJobResponse, AckDispatchJobResponses, AckRequest, AckRequestWritable, AckResponse, AckResponseWritable, ActivateApplicationData, ActivateApplicationError, ActivateApplicationErrors, ActivateApplicationResponse, ActivateApplicationResponses, ActivateClientData, ActivateClientError, ActivateClientErrors, ActivateClientResponse, ActivateClientResponses, ActivateConnectionData, ActivateConnectionError, ActivateConnectionErrors, ActivateConnectionResponse, ActivateConnectionResponses, ActivateDispatchPoolData, ActivateDispatchPoolError, ActivateDispatchPoolErrors, ActivateDispatchPoolResponse, ActivateDispatchPoolResponses, ActivateOAuthClientData, ActivateOAuthClientError, ActivateOAuthClientErrors, ActivateOAuthClientResponse, ActivateOAuthClientResponses, ActivatePrincipalData, ActivatePrincipalError, ActivatePrincipalErrors, ActivatePrincipalResponse, ActivatePrincipalResponses, AddApplicationProducerBindingData, AddApplicationProducerBindingError, AddApplicationProducerBindingErrors, AddApplicationProducerBindingResponse, AddApplicationProducerBindingResponses, AddClientNoteData, AddClientNoteError, AddClientNoteErrors, AddClientNoteResponse, AddClientNoteResponses, AddCorsOriginData, AddCorsOriginError, AddCorsOriginErrors, AddCorsOriginResponse, AddCorsOriginResponses, AddEventTypeSchemaData, AddEventTypeSchemaError, AddEventTypeSchemaErrors, AddEventTypeSchemaResponse, AddEventTypeSchemaResponses, AddEventTypeVersionData, AddEventTypeVersionError, AddEventTypeVersionErrors, AddEventTypeVersionResponse, AddEventTypeVersionResponses, AddNoteRequest, AddNoteRequestWritable, AddOriginRequest, AddOriginRequestWritable, AddPrincipalRoleData, AddPrincipalRoleError, AddPrincipalRoleErrors, AddPrincipalRoleResponse, AddPrincipalRoleResponses, AddProducerBindingRequest, AddProducerBindingRequestWritable, AddRoleRequest, AddRoleRequestWritable, AddSchemaRequest, AddSchemaRequestWritable, AllowedOriginResponse, AllowedOriginResponseWritable, AnchorDomainListResponse, AnchorDomainListResponseWritable, AnchorDomainResponse, AnomalyListResponse, AnomalyListResponseWritable, AnomalyResponse, AnomalySettingListResponse, AnomalySettingListResponseWritable, AnomalySettingResponse, AnomalySettingResponseWritable, ApplicationAccessListResponse, ApplicationAccessListResponseWritable, ApplicationAccessResponse, ApplicationFilterListResponse, ApplicationFilterListResponseWritable, ApplicationListResponse, ApplicationListResponseWritable, ApplicationLoginClientCredentials, ApplicationOAuthClientCredentials, ApplicationProvisionLoginClientResponse, ApplicationProvisionLoginClientResponseWritable, ApplicationProvisionServiceAccountResponse, ApplicationProvisionServiceAccountResponseWritable, ApplicationResponse, ApplicationResponseWritable, ApplicationRolesResponse, ApplicationRolesResponseWritable, ApplicationServiceAccountCredentials, ApproveResetApprovalData, ApproveResetApprovalError, ApproveResetApprovalErrors, ApproveResetApprovalResponse, ApproveResetApprovalResponses, ArchiveDispatchPoolData, ArchiveDispatchPoolError, ArchiveDispatchPoolErrors, ArchiveDispatchPoolResponse, ArchiveDispatchPoolResponses, ArchiveFieldResponse, ArchiveFileResponse, ArchiveManifestResponse, ArchiveManifestResponseWritable, ArchiveProcessData, ArchiveProcessError, ArchiveProcessErrors, ArchiveProcessResponse, ArchiveProcessResponses, ArchiveReplayListResponse, ArchiveReplayListResponseWritable, ArchiveReplayResponse, ArchiveReplayResponseWritable, ArchiveScheduledJobData, ArchiveScheduledJobError, ArchiveScheduledJobErrors, ArchiveScheduledJobResponse, ArchiveScheduledJobResponses, ArchiveStatusResponse, ArchiveStatusResponseWritable, ArchiveStreamResponse, AssignApplicationAccessRequest, AssignApplicationAccessRequestWritable, AssignPrincipalApplicationAccessData, AssignPrincipalApplicationAccessError, AssignPrincipalApplicationAccessErrors, AssignPrincipalApplicationAccessResponse, AssignPrincipalApplicationAccessResponses, AssignPrincipalRolesData, AssignPrincipalRolesError, AssignPrincipalRolesErrors, AssignPrincipalRolesRequest, AssignPrincipalRolesRequestWritable, AssignPrincipalRolesResponse, AssignPrincipalRolesResponses, AssignRolesRequest, AssignRolesRequestWritable, AssignServiceAccountRolesData, AssignServiceAccountRolesError, AssignServiceAccountRolesErrors, AssignServiceAccountRolesResponse, AssignServiceAccountRolesResponses, AttachApplicationServiceAccountData, AttachApplicationServiceAccountError, AttachApplicationServiceAccountErrors, AttachApplicationServiceAccountResponse, AttachApplicationServiceAccountResponses, AttachServiceAccountRequest, AttachServiceAccountRequestWritable, AttemptDto, AuditLogApplicationIdsData, AuditLogApplicationIdsError, AuditLogApplicationIdsErrors, AuditLogApplicationIdsResponse, AuditLogApplicationIdsResponse2, AuditLogApplicationIdsResponseWritable, AuditLogApplicationIdsResponses, AuditLogClientIdsData, AuditLogClientIdsError, AuditLogClientIdsErrors, AuditLogClientIdsResponse, AuditLogClientIdsResponse2, AuditLogClientIdsResponseWritable, AuditLogClientIdsResponses, AuditLogEntityTypesData, AuditLogEntityTypesError, AuditLogEntityTypesErrors, AuditLogEntityTypesResponse, AuditLogEntityTypesResponse2, AuditLogEntityTypesResponseWritable, AuditLogEntityTypesResponses, AuditLogListResponse, AuditLogListResponseWritable, AuditLogOperationsData, AuditLogOperationsError, AuditLogOperationsErrors, AuditLogOperationsResponse, AuditLogOperationsResponse2, AuditLogOperationsResponseWritable, AuditLogOperationsResponses, AuditLogResponse, AuditLogResponseWritable, AuditLogsByEntityData, AuditLogsByEntityError, AuditLogsByEntityErrors, AuditLogsByEntityResponse, AuditLogsByEntityResponses, AuditLogsByPrincipalData, AuditLogsByPrincipalError, AuditLogsByPrincipalErrors, AuditLogsByPrincipalResponse, AuditLogsByPrincipalResponses, AuthConfigItem, AuthConfigListResponse, AuthConfigListResponseWritable, AuthConfigResponse, AuthenticateBeginRequest, AuthenticateBeginRequestWritable, AuthenticateBeginResponse, AuthenticateBeginResponseWritable, AuthenticateCompleteRequest, AuthenticateCompleteRequestWritable, BatchEventItem, BatchIngestEventsData, BatchIngestEventsError, BatchIngestEventsErrors, BatchIngestEventsResponse, BatchIngestEventsResponses, BatchRequest, BatchRequestWritable, BatchResponse, BatchResponseWritable, BatchResultItem, Batching, BatchingDto, BindingItem, BulkImportRequest, BulkImportRequestWritable, BulkImportResponse, BulkImportResponseWritable, BulkImportResult, BulkImportUser, BulkImportUsersData, BulkImportUsersError, BulkImportUsersErrors, BulkImportUsersResponse, BulkImportUsersResponses, Bundle, BundleWritable, CancelArchiveReplayData, CancelArchiveReplayError, CancelArchiveReplayErrors, CancelArchiveReplayResponse, CancelArchiveReplayResponses, CancelDispatchJobsData, CancelDispatchJobsError, CancelDispatchJobsErrors, CancelDispatchJobsResponse, CancelDispatchJobsResponses, CancelRequest, CancelRequestWritable, CancelResponse, CancelResponseWritable, CheckEmailDomainResponse, CheckEmailDomainResponseWritable, CheckPrincipalEmailDomainData, CheckPrincipalEmailDomainError, CheckPrincipalEmailDomainErrors, CheckPrincipalEmailDomainResponse, CheckPrincipalEmailDomainResponses, ClientAccessGrantListResponse, ClientAccessGrantListResponseWritable, ClientAccessGrantResponse, ClientAccessGrantResponseWritable, ClientApplicationResponse, ClientApplicationsResponse, ClientApplicationsResponseWritable, ClientAssociationRequest, ClientAssociationRequestWritable, ClientConfigListResponse, ClientConfigListResponseWritable, ClientConfigResponse, ClientConfigResponseWritable, ClientListResponse, ClientListResponseWritable, ClientOptions, ClientResponse, ClientResponseWritable, CompleteInstanceRequest, CompleteInstanceRequestWritable, CompleteScheduledJobInstanceData, CompleteScheduledJobInstanceError, CompleteScheduledJobInstanceErrors, CompleteScheduledJobInstanceResponse, CompleteScheduledJobInstanceResponses, ConfigEntry, ConfigEntryDto, ConfigListResponse, ConfigListResponseWritable, ConfigResponse, ConfigResponseWritable, ConnectionListResponse, ConnectionListResponseWritable, ConnectionResponse, ConnectionResponseWritable, ConsumeAckRequest, ConsumeAckRequestWritable, ConsumeNackRequest, ConsumeNackRequestWritable, ConsumeResponse, ConsumeResponseWritable, ConsumeSubscriptionData, ConsumeSubscriptionError, ConsumeSubscriptionErrors, ConsumeSubscriptionResponse, ConsumeSubscriptionResponses, ConsumedMessage, ContextEntryDto, CorsOriginListResponse, CorsOriginListResponseWritable, CreateAnchorDomainData, CreateAnchorDomainError, CreateAnchorDomainErrors, CreateAnchorDomainRequest, CreateAnchorDomainRequestWritable, CreateAnchorDomainResponse, CreateAnchorDomainResponses, CreateApplicationData, CreateApplicationError, CreateApplicationErrors, CreateApplicationRequest, CreateApplicationRequestWritable, CreateApplicationResponse, CreateApplicationResponses, CreateAuthConfigData, CreateAuthConfigError, CreateAuthConfigErrors, CreateAuthConfigRequest, CreateAuthConfigRequestWritable, CreateAuthConfigResponse, CreateAuthConfigResponses, CreateClientData, CreateClientError, CreateClientErrors, CreateClientRequest, CreateClientRequestWritable, CreateClientResponse, CreateClientResponses, CreateConnectionData, CreateConnectionError, CreateConnectionErrors, CreateConnectionRequest, CreateConnectionRequestWritable, CreateConnectionResponse, CreateConnectionResponses, CreateDispatchPoolData, CreateDispatchPoolError, CreateDispatchPoolErrors, CreateDispatchPoolRequest, CreateDispatchPoolRequestWritable, CreateDispatchPoolResponse, CreateDispatchPoolResponses, CreateEmailDomainMappingData, CreateEmailDomainMappingError, CreateEmailDomainMappingErrors, CreateEmailDomainMappingResponse, CreateEmailDomainMappingResponses, CreateEventData, CreateEventError, CreateEventErrors, CreateEventRequest, CreateEventRequestWritable, CreateEventResponse, CreateEventResponse2, CreateEventResponseWritable, CreateEventResponses, CreateEventTypeData, CreateEventTypeError, CreateEventTypeErrors, CreateEventTypeRequest, CreateEventTypeRequestWritable, CreateEventTypeResponse, CreateEventTypeResponses, CreateFederationPeerData, CreateFederationPeerError, CreateFederationPeerErrors, CreateFederationPeerRequest, CreateFederationPeerRequestWritable, CreateFederationPeerResponse, CreateFederationPeerResponses, CreateIdentityProviderData, CreateIdentityProviderError, CreateIdentityProviderErrors, CreateIdentityProviderRequest, CreateIdentityProviderRequestWritable, CreateIdentityProviderResponse, CreateIdentityProviderResponses, CreateIdpRoleMappingData, CreateIdpRoleMappingError, CreateIdpRoleMappingErrors, CreateIdpRoleMappingRequest, CreateIdpRoleMappingRequestWritable, CreateIdpRoleMappingResponse, CreateIdpRoleMappingResponses, CreateMappingRequest, CreateMappingRequestWritable, CreateOAuthClientData, CreateOAuthClientError, CreateOAuthClientErrors, CreateOAuthClientRequest, CreateOAuthClientRequestWritable, CreateOAuthClientResponse, CreateOAuthClientResponse2, CreateOAuthClientResponseWritable, CreateOAuthClientResponses, CreatePrincipalData, CreatePrincipalError, CreatePrincipalErrors, CreatePrincipalRequest, CreatePrincipalRequestWritable, CreatePrincipalResponse, CreatePrincipalResponses, CreateProcessData, CreateProcessError, CreateProcessErrors, CreateProcessRequest, CreateProcessRequestWritable, CreateProcessResponse, CreateProcessResponses, CreateRoleData, CreateRoleError, CreateRoleErrors, CreateRoleRequest, CreateRoleRequestWritable, CreateRoleResponse, CreateRoleResponses, CreateScheduledJobData, CreateScheduledJobError, CreateScheduledJobErrors, CreateScheduledJobRequest, CreateScheduledJobRequestWritable, CreateScheduledJobResponse, CreateScheduledJobResponses, CreateServiceAccountData, CreateServiceAccountError, CreateServiceAccountErrors, CreateServiceAccountRequest, CreateServiceAccountRequestWritable, CreateServiceAccountResponse, CreateServiceAccountResponse2, CreateServiceAccountResponseWritable, CreateServiceAccountResponses, CreateSubscriptionData, CreateSubscriptionError, CreateSubscriptionErrors, CreateSubscriptionRequest, CreateSubscriptionRequestWritable, CreateSubscriptionResponse, CreateSubscriptionResponses, CreateUserData, CreateUserError, CreateUserErrors, CreateUserRequest, CreateUserRequestWritable, CreateUserResponse, CreateUserResponses, CreatedEvent, CreatedResponse, CreatedResponseWritable, DeactivateApplicationData, DeactivateApplicationError, DeactivateApplicationErrors, DeactivateApplicationResponse, DeactivateApplicationResponses, DeactivateClientData, DeactivateClientError, DeactivateClientErrors, DeactivateClientResponse, DeactivateClientResponses, DeactivateOAuthClientData, DeactivateOAuthClientError, DeactivateOAuthClientErrors, DeactivateOAuthClientResponse, DeactivateOAuthClientResponses, DeactivatePrincipalData, DeactivatePrincipalError, DeactivatePrincipalErrors, DeactivatePrincipalResponse, DeactivatePrincipalResponses, DeactivateServiceAccountData, DeactivateServiceAccountError, DeactivateServiceAccountErrors, DeactivateServiceAccountResponse, DeactivateServiceAccountResponses, DeleteAnchorDomainData, DeleteAnchorDomainError, DeleteAnchorDomainErrors, DeleteAnchorDomainResponse, DeleteAnchorDomainResponses, DeleteApplicationData, DeleteApplicationError, DeleteApplicationErrors, DeleteApplicationResponse, DeleteApplicationResponses, DeleteAuthConfigData, DeleteAuthConfigError, DeleteAuthConfigErrors, DeleteAuthConfigResponse, DeleteAuthConfigResponses, DeleteClientData, DeleteClientError, DeleteClientErrors, DeleteClientResponse, DeleteClientResponses, DeleteConnectionData, DeleteConnectionError, DeleteConnectionErrors, DeleteConnectionResponse, DeleteConnectionResponses, DeleteCorsOriginData, DeleteCorsOriginError, DeleteCorsOriginErrors, DeleteCorsOriginResponse, DeleteCorsOriginResponses, DeleteDispatchPoolData, DeleteDispatchPoolError, DeleteDispatchPoolErrors, DeleteDispatchPoolResponse, DeleteDispatchPoolResponses, DeleteEmailDomainMappingData, DeleteEmailDomainMappingError, DeleteEmailDomainMappingErrors, DeleteEmailDomainMappingResponse, DeleteEmailDomainMappingResponses, DeleteEventTypeData, DeleteEventTypeError, DeleteEventTypeErrors, DeleteEventTypeResponse, DeleteEventTypeResponses, DeleteFederationPeerData, DeleteFederationPeerError, DeleteFederationPeerErrors, DeleteFederationPeerResponse, DeleteFederationPeerResponses, DeleteIdentityProviderData, DeleteIdentityProviderError, DeleteIdentityProviderErrors, DeleteIdentityProviderResponse, DeleteIdentityProviderResponses, DeleteIdpRoleMappingData, DeleteIdpRoleMappingError, DeleteIdpRoleMappingErrors, DeleteIdpRoleMappingResponse, DeleteIdpRoleMappingResponses, DeleteOAuthClientData, DeleteOAuthClientError, DeleteOAuthClientErrors, DeleteOAuthClientResponse, DeleteOAuthClientResponses, DeletePermissionData, DeletePermissionError, DeletePermissionErrors, DeletePermissionResponse, DeletePermissionResponses, DeletePlatformConfigPropertyData, DeletePlatformConfigPropertyError, DeletePlatformConfigPropertyErrors, DeletePlatformConfigPropertyResponse, DeletePlatformConfigPropertyResponses, DeletePrincipalData, DeletePrincipalError, DeletePrincipalErrors, DeletePrincipalResponse, DeletePrincipalResponses, DeleteProcessData, DeleteProcessError, DeleteProcessErrors, DeleteProcessResponse, DeleteProcessResponses, DeleteRoleData, DeleteRoleError, DeleteRoleErrors, DeleteRoleResponse, DeleteRoleResponses, DeleteScheduledJobData, DeleteScheduledJobError, DeleteScheduledJobErrors, DeleteScheduledJobResponse, DeleteScheduledJobResponses, DeleteServiceAccountData, DeleteServiceAccountError, DeleteServiceAccountErrors, DeleteServiceAccountResponse, DeleteServiceAccountResponses, DeleteSubscriptionData, DeleteSubscriptionError, DeleteSubscriptionErrors, DeleteSubscriptionResponse, DeleteSubscriptionResponses, DeleteWebauthnCredentialData, DeleteWebauthnCredentialError, DeleteWebauthnCredentialErrors, DeleteWebauthnCredentialResponse, DeleteWebauthnCredentialResponses, DenyResetApprovalData, DenyResetApprovalError, DenyResetApprovalErrors, DenyResetApprovalResponse, DenyResetApprovalResponses, DeveloperUserListResponse, DeveloperUserListResponseWritable, DisableApplicationForClientData, DisableApplicationForClientError, DisableApplicationForClientErrors, DisableApplicationForClientResponse, DisableApplicationForClientResponses, DisableClientApplicationData, DisableClientApplicationError, DisableClientApplicationErrors, DisableClientApplicationResponse, DisableClientApplicationResponses, DiscardStreamDlqEntryData, DiscardStreamDlqEntryError, DiscardStreamDlqEntryErrors, DiscardStreamDlqEntryResponse, DiscardStreamDlqEntryResponses, DispatchJobFilterOptionsData, DispatchJobFilterOptionsError, DispatchJobFilterOptionsErrors, DispatchJobFilterOptionsResponse, DispatchJobFilterOptionsResponse2, DispatchJobFilterOptionsResponseWritable, DispatchJobFilterOptionsResponses, DispatchJobRead, DispatchJobResponse, DispatchJobResponseWritable, DispatchJobsByEventAliasData, DispatchJobsByEventAliasError, DispatchJobsByEventAliasErrors, DispatchJobsByEventAliasResponse, DispatchJobsByEventAliasResponses, DispatchJobsByEventData, DispatchJobsByEventError, DispatchJobsByEventErrors, DispatchJobsByEventResponse, DispatchJobsByEventResponses, DispatchPoolItem, DispatchPoolListResponse, DispatchPoolListResponseWritable, DispatchPoolResponse, DispatchPoolResponseWritable, DriftItemResponse, EnableApplicationForClientData, EnableApplicationForClientError, EnableApplicationForClientErrors, EnableApplicationForClientResponse, EnableApplicationForClientResponses, EnableClientApplicationData, EnableClientApplicationError, EnableClientApplicationErrors, EnableClientApplicationResponse, EnableClientApplicationResponses, EngageKillSwitchData, EngageKillSwitchError, EngageKillSwitchErrors, EngageKillSwitchRequest, EngageKillSwitchRequestWritable, EngageKillSwitchResponse, EngageKillSwitchResponses, ErasureRemainingResponse, ErasureReportResponse, ErasureRequestListResponse, ErasureRequestListResponseWritable, ErasureRequestResponse, ErasureRequestResponseWritable, ErrorModel, ErrorModelWritable, EventFilterOption, EventFilterOptionsData, EventFilterOptionsError, EventFilterOptionsErrors, EventFilterOptionsResponse, EventFilterOptionsResponse2, EventFilterOptionsResponseWritable, EventFilterOptionsResponses, EventRead, EventResponse, EventResponseWritable, EventTypeBindingDto, EventTypeItem, EventTypeListResponse, EventTypeListResponseWritable, EventTypeProducersResponse, EventTypeProducersResponseWritable, EventTypeResponse, EventTypeResponseWritable, ExpireClientSigningKeyData, ExpireClientSigningKeyError, ExpireClientSigningKeyErrors, ExpireClientSigningKeyResponse, ExpireClientSigningKeyResponses, ExpireSigningKeyRequest, ExpireSigningKeyRequestWritable, ExportConfigurationData, ExportConfigurationError, ExportConfigurationErrors, ExportConfigurationResponse, ExportConfigurationResponses, FederatedEventResponse, FederationContextEntry, FederationFeedResponse, FederationFeedResponseWritable, FederationPeerListResponse, FederationPeerListResponseWritable, FederationPeerResponse, FederationPeerResponseWritable, FireNowRequest, FireNowRequestWritable, FireNowResponse, FireNowResponseWritable, FireScheduledJobNowData, FireScheduledJobNowError, FireScheduledJobNowErrors, FireScheduledJobNowResponse, FireScheduledJobNowResponses, GetApplicationByCodeData, GetApplicationByCodeError, GetApplicationByCodeErrors, GetApplicationByCodeResponse, GetApplicationByCodeResponses, GetApplicationClientConfigData, GetApplicationClientConfigError, GetApplicationClientConfigErrors, GetApplicationClientConfigResponse, GetApplicationClientConfigResponses, GetApplicationData, GetApplicationError, GetApplicationErrors, GetApplicationResponse, GetApplicationResponses, GetArchiveReplayData, GetArchiveReplayError, GetArchiveReplayErrors, GetArchiveReplayResponse, GetArchiveReplayResponses, GetArchiveStatusData, GetArchiveStatusError, GetArchiveStatusErrors, GetArchiveStatusResponse, GetArchiveStatusResponses, GetAuditLogData, GetAuditLogError, GetAuditLogErrors, GetAuditLogResponse, GetAuditLogResponses, GetClientApplicationsData, GetClientApplicationsError, GetClientApplicationsErrors, GetClientApplicationsResponse, GetClientApplicationsResponses, GetClientByIdentifierData, GetClientByIdentifierError, GetClientByIdentifierErrors, GetClientByIdentifierResponse, GetClientByIdentifierResponses, GetClientData, GetClientError, GetClientErrors, GetClientResponse, GetClientResponses, GetConnectionData, GetConnectionError, GetConnectionErrors, GetConnectionResponse, GetConnectionResponses, GetCorsOriginData, GetCorsOriginError, GetCorsOriginErrors, GetCorsOriginResponse, GetCorsOriginResponses, GetDispatchJobData, GetDispatchJobError, GetDispatchJobErrors, GetDispatchJobRawData, GetDispatchJobRawError, GetDispatchJobRawErrors, GetDispatchJobRawResponse, GetDispatchJobRawResponses, GetDispatchJobResponse, GetDispatchJobResponses, GetDispatchPoolData, GetDispatchPoolError, GetDispatchPoolErrors, GetDispatchPoolResponse, GetDispatchPoolResponses, GetEmailDomainMappingByDomainData, GetEmailDomainMappingByDomainError, GetEmailDomainMappingByDomainErrors, GetEmailDomainMappingByDomainResponse, GetEmailDomainMappingByDomainResponses, GetEmailDomainMappingData, GetEmailDomainMappingError, GetEmailDomainMappingErrors, GetEmailDomainMappingResponse, GetEmailDomainMappingResponses, GetErasureRequestData, GetErasureRequestError, GetErasureRequestErrors, GetErasureRequestResponse, GetErasureRequestResponses, GetEventData, GetEventError, GetEventErrors, GetEventResponse, GetEventResponses, GetEventTypeByCodeData, GetEventTypeByCodeError, GetEventTypeByCodeErrors, GetEventTypeByCodeResponse, GetEventTypeByCodeResponses, GetEventTypeData, GetEventTypeError, GetEventTypeErrors, GetEventTypeResponse, GetEventTypeResponses, GetFederationFeedData, GetFederationFeedError, GetFederationFeedErrors, GetFederationFeedResponse, GetFederationFeedResponses, GetFederationPeerData, GetFederationPeerError, GetFederationPeerErrors, GetFederationPeerResponse, GetFederationPeerResponses, GetIdentityProviderData, GetIdentityProviderError, GetIdentityProviderErrors, GetIdentityProviderResponse, GetIdentityProviderResponses, GetOAuthClientByClientIdData, GetOAuthClientByClientIdError, GetOAuthClientByClientIdErrors, GetOAuthClientByClientIdResponse, GetOAuthClientByClientIdResponses, GetOAuthClientData, GetOAuthClientError, GetOAuthClientErrors, GetOAuthClientResponse, GetOAuthClientResponses, GetPermissionData, GetPermissionError, GetPermissionErrors, GetPermissionMatrixData, GetPermissionMatrixError, GetPermissionMatrixErrors, GetPermissionMatrixResponse, GetPermissionMatrixResponses, GetPermissionResponse, GetPermissionResponses, GetPlatformConfigPropertyData, GetPlatformConfigPropertyError, GetPlatformConfigPropertyErrors, GetPlatformConfigPropertyResponse, GetPlatformConfigPropertyResponses, GetPrincipalData, GetPrincipalError, GetPrincipalErrors, GetPrincipalResponse, GetPrincipalResponses, GetPrincipalVersionData, GetPrincipalVersionError, GetPrincipalVersionErrors, GetPrincipalVersionResponse, GetPrincipalVersionResponses, GetProcessByCodeData, GetProcessByCodeError, GetProcessByCodeErrors, GetProcessByCodeResponse, GetProcessByCodeResponses, GetProcessData, GetProcessError, GetProcessErrors, GetProcessResponse, GetProcessResponses, GetProducersReportData, GetProducersReportError, GetProducersReportErrors, GetProducersReportResponse, GetProducersReportResponses, GetReconcilerStatusData, GetReconcilerStatusError, GetReconcilerStatusErrors, GetReconcilerStatusResponse, GetReconcilerStatusResponses, GetRoleApplicationFiltersData, GetRoleApplicationFiltersError, GetRoleApplicationFiltersErrors, GetRoleApplicationFiltersResponse, GetRoleApplicationFiltersResponses, GetRoleByCodeData, GetRoleByCodeError, GetRoleByCodeErrors, GetRoleByCodeResponse, GetRoleByCodeResponses, GetRoleData, GetRoleError, GetRoleErrors, GetRoleResponse, GetRoleResponses, GetRolesByApplicationData, GetRolesByApplicationError, GetRolesByApplicationErrors, GetRolesByApplicationResponse, GetRolesByApplicationResponses, GetRolesBySourceData, GetRolesBySourceError, GetRolesBySourceErrors, GetRolesBySourceResponse, GetRolesBySourceResponses, GetScheduledJobByCodeData, GetScheduledJobByCodeError, GetScheduledJobByCodeErrors, GetScheduledJobByCodeResponse, GetScheduledJobByCodeResponses, GetScheduledJobData, GetScheduledJobError, GetScheduledJobErrors, GetScheduledJobInstanceData, GetScheduledJobInstanceError, GetScheduledJobInstanceErrors, GetScheduledJobInstanceResponse, GetScheduledJobInstanceResponses, GetScheduledJobResponse, GetScheduledJobResponses, GetServiceAccountByCodeData, GetServiceAccountByCodeError, GetServiceAccountByCodeErrors, GetServiceAccountByCodeResponse, GetServiceAccountByCodeResponses, GetServiceAccountData, GetServiceAccountError, GetServiceAccountErrors, GetServiceAccountResponse, GetServiceAccountResponses, GetSubscriptionData, GetSubscriptionError, GetSubscriptionErrors, GetSubscriptionResponse, GetSubscriptionResponses, GetSubscriptionShadowReportData, GetSubscriptionShadowReportError, GetSubscriptionShadowReportErrors, GetSubscriptionShadowReportResponse, GetSubscriptionShadowReportResponses, GetSubscriptionTargetStatsData, GetSubscriptionTargetStatsError, GetSubscriptionTargetStatsErrors, GetSubscriptionTargetStatsResponse, GetSubscriptionTargetStatsResponses, GrantAccessRequest, GrantAccessRequestWritable, GrantClientAccessRequest, GrantClientAccessRequestWritable, GrantPermissionRequest, GrantPermissionRequestWritable, GrantPlatformConfigAccessData, GrantPlatformConfigAccessError, GrantPlatformConfigAccessErrors, GrantPlatformConfigAccessResponse, GrantPlatformConfigAccessResponses, GrantPrincipalClientAccessData, GrantPrincipalClientAccessError, GrantPrincipalClientAccessErrors, GrantPrincipalClientAccessResponse, GrantPrincipalClientAccessResponses, GrantRolePermissionByBodyData, GrantRolePermissionByBodyError, GrantRolePermissionByBodyErrors, GrantRolePermissionByBodyResponse, GrantRolePermissionByBodyResponses, GrantRolePermissionData, GrantRolePermissionError, GrantRolePermissionErrors, GrantRolePermissionResponse, GrantRolePermissionResponses, IdentityProviderListResponse, IdentityProviderListResponseWritable, IdentityProviderResponse, IdentityProviderResponseWritable, IdpRoleMappingListResponse, IdpRoleMappingListResponseWritable, IdpRoleMappingResponse, ImportConfigurationData, ImportConfigurationError, ImportConfigurationErrors, ImportConfigurationResponse, ImportConfigurationResponses, ImportItemResponse, ImportResponse, ImportResponseWritable, ImportSummaryResponse, KillSwitchListResponse, KillSwitchListResponseWritable, KillSwitchResponse, KillSwitchResponseWritable, ListAnchorDomainsData, ListAnchorDomainsError, ListAnchorDomainsErrors, ListAnchorDomainsResponse, ListAnchorDomainsResponses, ListAnomaliesData, ListAnomaliesError, ListAnomaliesErrors, ListAnomaliesResponse, ListAnomaliesResponses, ListAnomalySettingsData, ListAnomalySettingsError, ListAnomalySettingsErrors, ListAnomalySettingsResponse, ListAnomalySettingsResponses, ListApplicationClientConfigsData, ListApplicationClientConfigsError, ListApplicationClientConfigsErrors, ListApplicationClientConfigsResponse, ListApplicationClientConfigsResponses, ListApplicationProducerBindingsData, ListApplicationProducerBindingsError, ListApplicationProducerBindingsErrors, ListApplicationProducerBindingsResponse, ListApplicationProducerBindingsResponses, ListApplicationRolesData, ListApplicationRolesError, ListApplicationRolesErrors, ListApplicationRolesResponse, ListApplicationRolesResponses, ListApplicationsData, ListApplicationsError, ListApplicationsErrors, ListApplicationsResponse, ListApplicationsResponses, ListArchiveFilesData, ListArchiveFilesError, ListArchiveFilesErrors, ListArchiveFilesResponse, ListArchiveFilesResponses, ListArchiveReplaysData, ListArchiveReplaysError, ListArchiveReplaysErrors, ListArchiveReplaysResponse, ListArchiveReplaysResponses, ListAuditLogsData, ListAuditLogsError, ListAuditLogsErrors, ListAuditLogsRecentData, ListAuditLogsRecentError, ListAuditLogsRecentErrors, ListAuditLogsRecentResponse, ListAuditLogsRecentResponses, ListAuditLogsResponse, ListAuditLogsResponses, ListAuthConfigsData, ListAuthConfigsError, ListAuthConfigsErrors, ListAuthConfigsResponse, ListAuthConfigsResponses, ListClientSigningKeysData, ListClientSigningKeysError, ListClientSigningKeysErrors, ListClientSigningKeysResponse, ListClientSigningKeysResponses, ListClientsData, ListClientsError, ListClientsErrors, ListClientsResponse, ListClientsResponses, ListConnectionsData, ListConnectionsError, ListConnectionsErrors, ListConnectionsResponse, ListConnectionsResponses, ListCorsOriginsData, ListCorsOriginsError, ListCorsOriginsErrors, ListCorsOriginsResponse, ListCorsOriginsResponses, ListDeveloperUsersData, ListDeveloperUsersError, ListDeveloperUsersErrors, ListDeveloperUsersResponse, ListDeveloperUsersResponses, ListDispatchJobAttemptsData, ListDispatchJobAttemptsError, ListDispatchJobAttemptsErrors, ListDispatchJobAttemptsResponse, ListDispatchJobAttemptsResponses, ListDispatchJobsData, ListDispatchJobsError, ListDispatchJobsErrors, ListDispatchJobsRawAliasData, ListDispatchJobsRawAliasError, ListDispatchJobsRawAliasErrors, ListDispatchJobsRawAliasResponse, ListDispatchJobsRawAliasResponses, ListDispatchJobsRawData, ListDispatchJobsRawError, ListDispatchJobsRawErrors, ListDispatchJobsRawResponse, ListDispatchJobsRawResponses, ListDispatchJobsResponse, ListDispatchJobsResponses, ListDispatchPoolsData, ListDispatchPoolsError, ListDispatchPoolsErrors, ListDispatchPoolsResponse, ListDispatchPoolsResponses, ListEmailDomainMappingsData, ListEmailDomainMappingsError, ListEmailDomainMappingsErrors, ListEmailDomainMappingsResponse, ListEmailDomainMappingsResponses, ListErasureRequestsData, ListErasureRequestsError, ListErasureRequestsErrors, ListErasureRequestsResponse, ListErasureRequestsResponses, ListEventTypesData, ListEventTypesError, ListEventTypesErrors, ListEventTypesResponse, ListEventTypesResponses, ListEventsData, ListEventsError, ListEventsErrors, ListEventsRawAliasData, ListEventsRawAliasError, ListEventsRawAliasErrors, ListEventsRawAliasResponse, ListEventsRawAliasResponses, ListEventsRawData, ListEventsRawError, ListEventsRawErrors, ListEventsRawResponse, ListEventsRawResponses, ListEventsResponse, ListEventsResponses, ListFederationPeersData, ListFederationPeersError, ListFederationPeersErrors, ListFederationPeersResponse, ListFederationPeersResponses, ListIdentityProvidersData, ListIdentityProvidersError, ListIdentityProvidersErrors, ListIdentityProvidersResponse, ListIdentityProvidersResponses, ListIdpRoleMappingsData, ListIdpRoleMappingsError, ListIdpRoleMappingsErrors, ListIdpRoleMappingsResponse, ListIdpRoleMappingsResponses, ListKillSwitchesData, ListKillSwitchesError, ListKillSwitchesErrors, ListKillSwitchesResponse, ListKillSwitchesResponses, ListLockoutsData, ListLockoutsError, ListLockoutsErrors, ListLockoutsResponse, ListLockoutsResponses, ListLoginAttemptsData, ListLoginAttemptsError, ListLoginAttemptsErrors, ListLoginAttemptsResponse, ListLoginAttemptsResponses, ListOAuthClientsData, ListOAuthClientsError, ListOAuthClientsErrors, ListOAuthClientsResponse, ListOAuthClientsResponses, ListOutputBody, ListOutputBodyWritable, ListPermissionsData, ListPermissionsError, ListPermissionsErrors, ListPermissionsResponse, ListPermissionsResponses, ListPlatformConfigAccessData, ListPlatformConfigAccessError, ListPlatformConfigAccessErrors, ListPlatformConfigAccessResponse, ListPlatformConfigAccessResponses, ListPlatformConfigPropertiesData, ListPlatformConfigPropertiesError, ListPlatformConfigPropertiesErrors, ListPlatformConfigPropertiesResponse, ListPlatformConfigPropertiesResponses, ListPrincipalApplicationAccessData, ListPrincipalApplicationAccessError, ListPrincipalApplicationAccessErrors, ListPrincipalApplicationAccessResponse, ListPrincipalApplicationAccessResponses, ListPrincipalAvailableApplicationsData, ListPrincipalAvailableApplicationsError, ListPrincipalAvailableApplicationsErrors, ListPrincipalAvailableApplicationsResponse, ListPrincipalAvailableApplicationsResponses, ListPrincipalClientAccessData, ListPrincipalClientAccessError, ListPrincipalClientAccessErrors, ListPrincipalClientAccessResponse, ListPrincipalClientAccessResponses, ListPrincipalRolesData, ListPrincipalRolesError, ListPrincipalRolesErrors, ListPrincipalRolesResponse, ListPrincipalRolesResponses, ListPrincipalsData, ListPrincipalsError, ListPrincipalsErrors, ListPrincipalsResponse, ListPrincipalsResponses, ListProcessesData, ListProcessesError, ListProcessesErrors, ListProcessesResponse, ListProcessesResponses, ListResetApprovalsData, ListResetApprovalsError, ListResetApprovalsErrors, ListResetApprovalsResponse, ListResetApprovalsResponses, ListRolePermissionsData, ListRolePermissionsError, ListRolePermissionsErrors, ListRolePermissionsResponse, ListRolePermissionsResponses, ListRolesData, ListRolesError, ListRolesErrors, ListRolesResponse, ListRolesResponses, ListScheduledJobInstanceLogsData, ListScheduledJobInstanceLogsError, ListScheduledJobInstanceLogsErrors, ListScheduledJobInstanceLogsResponse, ListScheduledJobInstanceLogsResponses, ListScheduledJobInstancesData, ListScheduledJobInstancesError, ListScheduledJobInstancesErrors, ListScheduledJobInstancesResponse, ListScheduledJobInstancesResponses, ListScheduledJobsData, ListScheduledJobsError, ListScheduledJobsErrors, ListScheduledJobsResponse, ListScheduledJobsResponses, ListServiceAccountRolesData, ListServiceAccountRolesError, ListServiceAccountRolesErrors, ListServiceAccountRolesResponse, ListServiceAccountRolesResponses, ListServiceAccountsData, ListServiceAccountsError, ListServiceAccountsErrors, ListServiceAccountsResponse, ListServiceAccountsResponses, ListStreamDlqData, ListStreamDlqError, ListStreamDlqErrors, ListStreamDlqResponse, ListStreamDlqResponses, ListSubscriptionsData, ListSubscriptionsError, ListSubscriptionsErrors, ListSubscriptionsResponse, ListSubscriptionsResponses, ListWebauthnCredentialsData, ListWebauthnCredentialsError, ListWebauthnCredentialsErrors, ListWebauthnCredentialsResponse, ListWebauthnCredentialsResponses, LockoutListResponse, LockoutListResponseWritable, LockoutResponse, LoginAttemptListResponse, LoginAttemptListResponseWritable, LoginAttemptResponse, LookupEmailDomainMappingData, LookupEmailDomainMappingError, LookupEmailDomainMappingErrors, LookupEmailDomainMappingResponses, MappingListResponse, MappingListResponseWritable, MappingResponse, MappingResponseWritable, MetadataDto, NackConsumedMessageData, NackConsumedMessageError, NackConsumedMessageErrors, NackConsumedMessageResponse, NackConsumedMessageResponses, NackDispatchJobData, NackDispatchJobError, NackDispatchJobErrors, NackDispatchJobResponse, NackDispatchJobResponses, NackRequest, NackRequestWritable, NoteResponse, OAuthClientApplicationRef, OAuthClientListResponse, OAuthClientListResponseWritable, OAuthClientResponse, OAuthClientResponseWritable, OffsetPageScheduledJobInstanceResponse, OffsetPageScheduledJobInstanceResponseWritable, OffsetPageScheduledJobResponse, OffsetPageScheduledJobResponseWritable, PauseConnectionData, PauseConnectionError, PauseConnectionErrors, PauseConnectionResponse, PauseConnectionResponses, PauseScheduledJobData, PauseScheduledJobError, PauseScheduledJobErrors, PauseScheduledJobResponse, PauseScheduledJobResponses, PauseSubscriptionData, PauseSubscriptionError, PauseSubscriptionErrors, PauseSubscriptionResponse, PauseSubscriptionResponses, PermissionListResponse, PermissionListResponseWritable, PermissionMatrixResponse, PermissionMatrixResponseWritable, PermissionResponse, PermissionResponseWritable, PrincipalAvailableApplication, PrincipalAvailableApplicationsResponse, PrincipalAvailableApplicationsResponseWritable, PrincipalListResponse, PrincipalListResponseWritable, PrincipalResponse, PrincipalResponseWritable, PrincipalRoleAssignmentDto, PrincipalRoleListResponse, PrincipalRoleListResponseWritable, PrincipalVersionResponse, PrincipalVersionResponseWritable, ProcessListResponse, ProcessListResponseWritable, ProcessResponse, ProcessResponseWritable, ProducerBindingListResponse, ProducerBindingListResponseWritable, ProducerBindingResponse, ProducerBindingResponseWritable, ProducerResponse, ProducerResponseWritable, ProducersReportResponse, ProducersReportResponseWritable, PromoteSubscriptionShadowData, PromoteSubscriptionShadowError, PromoteSubscriptionShadowErrors, PromoteSubscriptionShadowResponse, PromoteSubscriptionShadowResponses, ProvisionApplicationLoginClientData, ProvisionApplicationLoginClientError, ProvisionApplicationLoginClientErrors, ProvisionApplicationLoginClientResponse, ProvisionApplicationLoginClientResponses, ProvisionApplicationServiceAccountData, ProvisionApplicationServiceAccountError, ProvisionApplicationServiceAccountErrors, ProvisionApplicationServiceAccountResponse, ProvisionApplicationServiceAccountResponses, ProvisionLoginClientRequest, ProvisionLoginClientRequestWritable, PublicAllowedOriginsData, PublicAllowedOriginsError, PublicAllowedOriginsErrors, PublicAllowedOriginsResponse, PublicAllowedOriginsResponses, PublicAllowedResponse, PublicAllowedResponseWritable, RawDispatchJobResponse, RawEventResponse, ReceiptRequest, ReconcilerStatusResponse, ReconcilerStatusResponseWritable, RegenerateAuthTokenResponse, RegenerateAuthTokenResponseWritable, RegenerateOAuthClientSecretData, RegenerateOAuthClientSecretError, RegenerateOAuthClientSecretErrors, RegenerateOAuthClientSecretResponse, RegenerateOAuthClientSecretResponses, RegenerateServiceAccountAuthTokenRegenerateAuthTokenData, RegenerateServiceAccountAuthTokenRegenerateAuthTokenError, RegenerateServiceAccountAuthTokenRegenerateAuthTokenErrors, RegenerateServiceAccountAuthTokenRegenerateAuthTokenResponse, RegenerateServiceAccountAuthTokenRegenerateAuthTokenResponses, RegenerateServiceAccountAuthTokenRegenerateTokenData, RegenerateServiceAccountAuthTokenRegenerateTokenError, RegenerateServiceAccountAuthTokenRegenerateTokenErrors, RegenerateServiceAccountAuthTokenRegenerateTokenResponse, RegenerateServiceAccountAuthTokenRegenerateTokenResponses, RegenerateServiceAccountSigningSecretRegenerateSecretData, RegenerateServiceAccountSigningSecretRegenerateSecretError, RegenerateServiceAccountSigningSecretRegenerateSecretErrors, RegenerateServiceAccountSigningSecretRegenerateSecretResponse, RegenerateServiceAccountSigningSecretRegenerateSecretResponses, RegenerateServiceAccountSigningSecretRegenerateSigningSecretData, RegenerateServiceAccountSigningSecretRegenerateSigningSecretError, RegenerateServiceAccountSigningSecretRegenerateSigningSecretErrors, RegenerateServiceAccountSigningSecretRegenerateSigningSecretResponse, RegenerateServiceAccountSigningSecretRegenerateSigningSecretResponses, RegenerateSigningSecretResponse, RegenerateSigningSecretResponseWritable, RegisterBeginRequest, RegisterBeginRequestWritable, RegisterBeginResponse, RegisterBeginResponseWritable, RegisterCompleteRequest, RegisterCompleteRequestWritable, RegisterCompleteResponse, RegisterCompleteResponseWritable, ReleaseKillSwitchData, ReleaseKillSwitchError, ReleaseKillSwitchErrors, ReleaseKillSwitchResponse, ReleaseKillSwitchResponses, ReleaseLockoutData, ReleaseLockoutError, ReleaseLockoutErrors, ReleaseLockoutResponse, ReleaseLockoutResponses, RemoveApplicationProducerBindingData, RemoveApplicationProducerBindingError, RemoveApplicationProducerBindingErrors, RemoveApplicationProducerBindingResponse, RemoveApplicationProducerBindingResponses, RemovePrincipalRoleData, RemovePrincipalRoleError, RemovePrincipalRoleErrors, RemovePrincipalRoleResponse, RemovePrincipalRoleResponses, ReprocessStreamDlqEntryData, ReprocessStreamDlqEntryError, ReprocessStreamDlqEntryErrors, ReprocessStreamDlqEntryResponse, ReprocessStreamDlqEntryResponses, RequestDto, RequestErasureData, RequestErasureError, RequestErasureErrors, RequestErasureRequest, RequestErasureRequestWritable, RequestErasureResponse, RequestErasureResponses, RequeueDispatchJobsData, RequeueDispatchJobsError, RequeueDispatchJobsErrors, RequeueDispatchJobsResponse, RequeueDispatchJobsResponses, RequeueRequest, RequeueRequestWritable, RequeueResponse, RequeueResponseWritable, ResetPasswordRequest, ResetPasswordRequestWritable, ResetPrincipalPasswordData, ResetPrincipalPasswordError, ResetPrincipalPasswordErrors, ResetPrincipalPasswordResponse, ResetPrincipalPasswordResponses, ResetPrincipalTwoFactorData, ResetPrincipalTwoFactorError, ResetPrincipalTwoFactorErrors, ResetPrincipalTwoFactorResponse, ResetPrincipalTwoFactorResponses, ResumeScheduledJobData, ResumeScheduledJobError, ResumeScheduledJobErrors, ResumeScheduledJobResponse, ResumeScheduledJobResponses, ResumeSubscriptionData, ResumeSubscriptionError, ResumeSubscriptionErrors, ResumeSubscriptionResponse, ResumeSubscriptionResponses, RevokePlatformConfigAccessData, RevokePlatformConfigAccessError, RevokePlatformConfigAccessErrors, RevokePlatformConfigAccessResponse, RevokePlatformConfigAccessResponses, RevokePrincipalClientAccessData, RevokePrincipalClientAccessError, RevokePrincipalClientAccessErrors, RevokePrincipalClientAccessResponse, RevokePrincipalClientAccessResponses, RevokePrincipalDeveloperCredentialData, RevokePrincipalDeveloperCredentialError, RevokePrincipalDeveloperCredentialErrors, RevokePrincipalDeveloperCredentialResponse, RevokePrincipalDeveloperCredentialResponses, RevokeRolePermissionData, RevokeRolePermissionError, RevokeRolePermissionErrors, RevokeRolePermissionResponse, RevokeRolePermissionResponses, RoleAssignmentDto, RoleItem, RoleListResponse, RoleListResponseWritable, RolePermissionListResponse, RolePermissionListResponseWritable, RoleResponse, RoleResponseWritable, RolesAssignedResponse, RolesAssignedResponseWritable, RotateClientSigningKeyData, RotateClientSigningKeyError, RotateClientSigningKeyErrors, RotateClientSigningKeyResponse, RotateClientSigningKeyResponses, RotateOAuthClientSecretData, RotateOAuthClientSecretError, RotateOAuthClientSecretErrors, RotateOAuthClientSecretResponse, RotateOAuthClientSecretResponse2, RotateOAuthClientSecretResponseWritable, RotateOAuthClientSecretResponses, RotateSigningKeyRequest, RotateSigningKeyRequestWritable, RoutePermissionResponse, RunReconcilerData, RunReconcilerError, RunReconcilerErrors, RunReconcilerResponse, RunReconcilerResponses, ScheduledJobInstanceLogResponse, ScheduledJobInstanceResponse, ScheduledJobInstanceResponseWritable, ScheduledJobResponse, ScheduledJobResponseWritable, SchemaItem, SearchClientRequest, SearchClientRequestWritable, SearchClientsByQueryData, SearchClientsByQueryError, SearchClientsByQueryErrors, SearchClientsByQueryResponse, SearchClientsByQueryResponses, SearchClientsData, SearchClientsError, SearchClientsErrors, SearchClientsResponse, SearchClientsResponses, SendPasswordResetInputBody, SendPasswordResetInputBodyWritable, SendPrincipalPasswordResetData, SendPrincipalPasswordResetError, SendPrincipalPasswordResetErrors, SendPrincipalPasswordResetResponse, SendPrincipalPasswordResetResponses, ServiceAccountListResponse, ServiceAccountListResponseWritable, ServiceAccountOAuthSecrets, ServiceAccountResponse, ServiceAccountResponseWritable, ServiceAccountRoleListResponse, ServiceAccountRoleListResponseWritable, ServiceAccountRolesAssignedResponse, ServiceAccountRolesAssignedResponseWritable, ServiceAccountWebhookSecrets, SetApplicationAccessResponse, SetApplicationAccessResponseWritable, SetDeveloperCredentialResponse, SetDeveloperCredentialResponseWritable, SetPlatformConfigPropertyData, SetPlatformConfigPropertyError, SetPlatformConfigPropertyErrors, SetPlatformConfigPropertyResponse, SetPlatformConfigPropertyResponses, SetPrincipalClientAssociationData, SetPrincipalClientAssociationError, SetPrincipalClientAssociationErrors, SetPrincipalClientAssociationResponse, SetPrincipalClientAssociationResponses, SetPrincipalDeveloperCredentialData, SetPrincipalDeveloperCredentialError, SetPrincipalDeveloperCredentialErrors, SetPrincipalDeveloperCredentialResponse, SetPrincipalDeveloperCredentialResponses, SetPropertyRequest, SetPropertyRequestWritable, SetSubscriptionTrafficSplitData, SetSubscriptionTrafficSplitError, SetSubscriptionTrafficSplitErrors, SetSubscriptionTrafficSplitResponse, SetSubscriptionTrafficSplitResponses, SetTrafficSplitRequest, SetTrafficSplitRequestWritable, ShadowOutcomeDto, ShadowReportResponse, ShadowReportResponseWritable, ShadowSideDto, SigningKeyCreatedResponse, SigningKeyCreatedResponseWritable, SigningKeyListResponse, SigningKeyListResponseWritable, SigningKeyResponse, SigningKeyResponseWritable, SpecSourceResponse, SpecVersionResponse, SplitTargetDto, StartArchiveReplayData, StartArchiveReplayError, StartArchiveReplayErrors, StartArchiveReplayRequest, StartArchiveReplayRequestWritable, StartArchiveReplayResponse, StartArchiveReplayResponses, StatusChangeRequest, StatusChangeRequestWritable, StatusChangeResponse, StatusChangeResponseWritable, StreamDlqEntryResponse, StreamDlqListResponse, StreamDlqListResponseWritable, SubscriptionItem, SubscriptionListResponse, SubscriptionListResponseWritable, SubscriptionResponse, SubscriptionResponseWritable, SuccessResponse, SuccessResponseWritable, SuspendClientData, SuspendClientError, SuspendClientErrors, SuspendClientRequest, SuspendClientRequestWritable, SuspendClientResponse, SuspendClientResponses, SuspendDispatchPoolData, SuspendDispatchPoolError, SuspendDispatchPoolErrors, SuspendDispatchPoolResponse, SuspendDispatchPoolResponses, SyncDispatchPoolInputRequest, SyncDispatchPoolsData, SyncDispatchPoolsError, SyncDispatchPoolsErrors, SyncDispatchPoolsRequest, SyncDispatchPoolsRequestWritable, SyncDispatchPoolsResponse, SyncDispatchPoolsResponses, SyncEventTypeInputRequest, SyncEventTypesData, SyncEventTypesError, SyncEventTypesErrors, SyncEventTypesRequest, SyncEventTypesRequestWritable, SyncEventTypesResponse, SyncEventTypesResponses, SyncOpenApiSpecResponse, SyncOpenApiSpecResponseWritable, SyncOpenapiData, SyncOpenapiError, SyncOpenapiErrors, SyncOpenapiRequest, SyncOpenapiRequestWritable, SyncOpenapiResponse, SyncOpenapiResponses, SyncPrincipalInputRequest, SyncPrincipalsData, SyncPrincipalsError, SyncPrincipalsErrors, SyncPrincipalsRequest, SyncPrincipalsRequestWritable, SyncPrincipalsResponse, SyncPrincipalsResponses, SyncProcessInputRequest, SyncProcessesByBodyData, SyncProcessesByBodyError, SyncProcessesByBodyErrors, SyncProcessesByBodyRequest, SyncProcessesByBodyRequestWritable, SyncProcessesByBodyResponse, SyncProcessesByBodyResponses, SyncProcessesData, SyncProcessesError, SyncProcessesErrors, SyncProcessesRequest, SyncProcessesRequestWritable, SyncProcessesResponse, SyncProcessesResponses, SyncResultResponse, SyncResultResponseWritable, SyncRoleInputRequest, SyncRolesData, SyncRolesError, SyncRolesErrors, SyncRolesRequest, SyncRolesRequestWritable, SyncRolesResponse, SyncRolesResponses, SyncScheduledJobInputRequest, SyncScheduledJobsData, SyncScheduledJobsError, SyncScheduledJobsErrors, SyncScheduledJobsRequest, SyncScheduledJobsRequestWritable, SyncScheduledJobsResponse, SyncScheduledJobsResponses, SyncScheduledJobsResultResponse, SyncScheduledJobsResultResponseWritable, SyncSubscriptionEventTypeRequest, SyncSubscriptionInputRequest, SyncSubscriptionsData, SyncSubscriptionsError, SyncSubscriptionsErrors, SyncSubscriptionsRequest, SyncSubscriptionsRequestWritable, SyncSubscriptionsResponse, SyncSubscriptionsResponses, SyncUserInput, SyncUsersData, SyncUsersError, SyncUsersErrors, SyncUsersRequest, SyncUsersRequestWritable, SyncUsersResponse, SyncUsersResponse2, SyncUsersResponseWritable, SyncUsersResponses, TLSTrust, TLSTrustDTO, TargetStatsDto, TargetStatsResponse, TargetStatsResponseWritable, UpdateAnchorDomainData, UpdateAnchorDomainError, UpdateAnchorDomainErrors, UpdateAnchorDomainRequest, UpdateAnchorDomainRequestWritable, UpdateAnchorDomainResponse, UpdateAnchorDomainResponses, UpdateAnomalySettingData, UpdateAnomalySettingError, UpdateAnomalySettingErrors, UpdateAnomalySettingRequest, UpdateAnomalySettingRequestWritable, UpdateAnomalySettingResponse, UpdateAnomalySettingResponses, UpdateApplicationData, UpdateApplicationError, UpdateApplicationErrors, UpdateApplicationRequest, UpdateApplicationRequestWritable, UpdateApplicationResponse, UpdateApplicationResponses, UpdateAuthConfigData, UpdateAuthConfigError, UpdateAuthConfigErrors, UpdateAuthConfigRequest, UpdateAuthConfigRequestWritable, UpdateAuthConfigResponse, UpdateAuthConfigResponses, UpdateClientApplicationsData, UpdateClientApplicationsError, UpdateClientApplicationsErrors, UpdateClientApplicationsRequest, UpdateClientApplicationsRequestWritable, UpdateClientApplicationsResponse, UpdateClientApplicationsResponses, UpdateClientData, UpdateClientError, UpdateClientErrors, UpdateClientRequest, UpdateClientRequestWritable, UpdateClientResponse, UpdateClientResponses, UpdateConnectionData, UpdateConnectionError, UpdateConnectionErrors, UpdateConnectionRequest, UpdateConnectionRequestWritable, UpdateConnectionResponse, UpdateConnectionResponses, UpdateDataPolicyRequest, UpdateDataPolicyRequestWritable, UpdateDispatchPoolData, UpdateDispatchPoolError, UpdateDispatchPoolErrors, UpdateDispatchPoolRequest, UpdateDispatchPoolRequestWritable, UpdateDispatchPoolResponse, UpdateDispatchPoolResponses, UpdateEmailDomainMappingData, UpdateEmailDomainMappingError, UpdateEmailDomainMappingErrors, UpdateEmailDomainMappingResponse, UpdateEmailDomainMappingResponses, UpdateEventTypeData, UpdateEventTypeDataPolicyData, UpdateEventTypeDataPolicyError, UpdateEventTypeDataPolicyErrors, UpdateEventTypeDataPolicyResponse, UpdateEventTypeDataPolicyResponses, UpdateEventTypeError, UpdateEventTypeErrors, UpdateEventTypeLifecycleData, UpdateEventTypeLifecycleError, UpdateEventTypeLifecycleErrors, UpdateEventTypeLifecycleResponse, UpdateEventTypeLifecycleResponses, UpdateEventTypeRegistryBindingData, UpdateEventTypeRegistryBindingError, UpdateEventTypeRegistryBindingErrors, UpdateEventTypeRegistryBindingResponse, UpdateEventTypeRegistryBindingResponses, UpdateEventTypeRequest, UpdateEventTypeRequestWritable, UpdateEventTypeResponse, UpdateEventTypeResponses, UpdateFederationPeerData, UpdateFederationPeerError, UpdateFederationPeerErrors, UpdateFederationPeerRequest, UpdateFederationPeerRequestWritable, UpdateFederationPeerResponse, UpdateFederationPeerResponses, UpdateIdentityProviderData, UpdateIdentityProviderError, UpdateIdentityProviderErrors, UpdateIdentityProviderRequest, UpdateIdentityProviderRequestWritable, UpdateIdentityProviderResponse, UpdateIdentityProviderResponses, UpdateLifecycleRequest, UpdateLifecycleRequestWritable, UpdateMappingRequest, UpdateMappingRequestWritable, UpdateOAuthClientData, UpdateOAuthClientError, UpdateOAuthClientErrors, UpdateOAuthClientRequest, UpdateOAuthClientRequestWritable, UpdateOAuthClientResponse, UpdateOAuthClientResponses, UpdatePrincipalData, UpdatePrincipalError, UpdatePrincipalErrors, UpdatePrincipalRequest, UpdatePrincipalRequestWritable, UpdatePrincipalResponse, UpdatePrincipalResponses, UpdateProcessData, UpdateProcessError, UpdateProcessErrors, UpdateProcessRequest, UpdateProcessRequestWritable, UpdateProcessResponse, UpdateProcessResponses, UpdateRegistryBindingRequest, UpdateRegistryBindingRequestWritable, UpdateRoleData, UpdateRoleError, UpdateRoleErrors, UpdateRoleRequest, UpdateRoleRequestWritable, UpdateRoleResponse, UpdateRoleResponses, UpdateScheduledJobData, UpdateScheduledJobError, UpdateScheduledJobErrors, UpdateScheduledJobRequest, UpdateScheduledJobRequestWritable, UpdateScheduledJobResponse, UpdateScheduledJobResponses, UpdateServiceAccountData, UpdateServiceAccountError, UpdateServiceAccountErrors, UpdateServiceAccountRequest, UpdateServiceAccountRequestWritable, UpdateServiceAccountResponse, UpdateServiceAccountResponses, UpdateSubscriptionData, UpdateSubscriptionError, UpdateSubscriptionErrors, UpdateSubscriptionRequest, UpdateSubscriptionRequestWritable, UpdateSubscriptionResponse, UpdateSubscriptionResponses, UpsertDispatchPoolData, UpsertDispatchPoolError, UpsertDispatchPoolErrors, UpsertDispatchPoolRequest, UpsertDispatchPoolRequestWritable, UpsertDispatchPoolResponse, UpsertDispatchPoolResponses, UpsertEventTypeData, UpsertEventTypeError, UpsertEventTypeErrors, UpsertEventTypeRequest, UpsertEventTypeRequestWritable, UpsertEventTypeResponse, UpsertEventTypeResponses, UpsertServiceAccountData, UpsertServiceAccountError, UpsertServiceAccountErrors, UpsertServiceAccountRequest, UpsertServiceAccountRequestWritable, UpsertServiceAccountResponse, UpsertServiceAccountResponseWritable, UpsertServiceAccountResponses, UpsertSubscriptionData, UpsertSubscriptionError, UpsertSubscriptionErrors, UpsertSubscriptionRequest, UpsertSubscriptionRequestWritable, UpsertSubscriptionResponse, UpsertSubscriptionResponses, WebauthnAuthenticateBeginData, WebauthnAuthenticateBeginError, WebauthnAuthenticateBeginErrors, WebauthnAuthenticateBeginResponse, WebauthnAuthenticateBeginResponses, WebauthnAuthenticateCompleteData, WebauthnAuthenticateCompleteError, WebauthnAuthenticateCompleteErrors, WebauthnAuthenticateCompleteResponse, WebauthnAuthenticateCompleteResponse2, WebauthnAuthenticateCompleteResponseWritable, WebauthnAuthenticateCompleteResponses, WebauthnCredentialSummary, WebauthnRegisterBeginData, WebauthnRegisterBeginError, WebauthnRegisterBeginErrors, WebauthnRegisterBeginResponse, WebauthnRegisterBeginResponses, WebauthnRegisterCompleteData, WebauthnRegisterCompleteError, WebauthnRegisterCompleteErrors, WebauthnRegisterCompleteResponse, WebauthnRegisterCompleteResponses, WebhookCredentialsDto, WriteInstanceLogRequest, WriteInstanceLogRequestWritable, WriteScheduledJobInstanceLogData, WriteScheduledJobInstanceLogError, WriteScheduledJobInstanceLogErrors, WriteScheduledJobInstanceLogResponse, WriteScheduledJobInstanceLogResponses } from './types.gen';
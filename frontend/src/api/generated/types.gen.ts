@@ -21,6 +21,27 @@ export type AccessResponse = {
     roleCode: string;
 };
 
+export type AckRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Token the receiver returned with its 202
+     */
+    ackToken: string;
+    [key: string]: unknown;
+};
+
+export type AckResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    id: string;
+    status: string;
+};
+
 export type AddNoteRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -44,6 +65,22 @@ export type AddOriginRequest = {
     [key: string]: unknown;
 };
 
+export type AddProducerBindingRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Event type code the application may publish; * matches any one segment (e.g. orders:sales:order:*). Ingestion enforces a new or removed binding at once when FC_REDIS_URL is set, else on other replicas within 30 seconds
+     */
+    eventTypePattern: string;
+    /**
+     * Service account principal allowed to publish. Omit for the application's own service account
+     */
+    principalId?: string;
+    [key: string]: unknown;
+};
+
 export type AddRoleRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -59,9 +96,13 @@ export type AddSchemaRequest = {
      */
     readonly $schema?: string;
     /**
-     * JSON Schema document
+     * JSON Schema document, or for PROTO {messageType, fileDescriptorSet} with the set in protobuf's JSON mapping
      */
     schema: unknown;
+    /**
+     * JSON_SCHEMA (default) or PROTO. PROTOBUF subscriptions transcode payloads with the event type's CURRENT PROTO schema
+     */
+    schemaType?: string;
     /**
      * Schema version (typically semver)
      */
@@ -97,6 +138,85 @@ export type AnchorDomainResponse = {
     updatedAt: string;
 };
 
+export type AnomalyListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    anomalies: Array<AnomalyResponse>;
+};
+
+export type AnomalyResponse = {
+    /**
+     * Event type code or subscription id
+     */
+    entityKey: string;
+    /**
+     * EVENT_TYPE or SUBSCRIPTION
+     */
+    entityKind: string;
+    /**
+     * The baseline's mean for the same measure
+     */
+    expected: number;
+    id: string;
+    /**
+     * INGESTION_DROP or FAILURE_SPIKE
+     */
+    kind: string;
+    lastSeenAt: string;
+    message: string;
+    /**
+     * The window's value: events ingested, or the failure rate (0-1)
+     */
+    observed: number;
+    raisedAt: string;
+    resolvedAt?: string;
+    /**
+     * OPEN or RESOLVED
+     */
+    status: string;
+    /**
+     * Standard deviations between observed and expected
+     */
+    zScore: number;
+};
+
+export type AnomalySettingListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    settings: Array<AnomalySettingResponse>;
+};
+
+export type AnomalySettingResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    enabled: boolean;
+    /**
+     * Event type code or subscription id
+     */
+    entityKey: string;
+    /**
+     * EVENT_TYPE or SUBSCRIPTION
+     */
+    entityKind: string;
+    id: string;
+    /**
+     * Z-score threshold; absent means the default
+     */
+    sensitivity?: number;
+    snoozedUntil?: string;
+    updatedAt: string;
+    /**
+     * Principal that last changed the setting
+     */
+    updatedBy?: string;
+};
+
 export type ApplicationAccessListResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -196,6 +316,139 @@ export type ApplicationServiceAccountCredentials = {
     principalId: string;
 };
 
+export type ArchiveFieldResponse = {
+    name: string;
+    /**
+     * Schema version that added the field
+     */
+    since: number;
+    /**
+     * string, timestamp, integer, boolean or json
+     */
+    type: string;
+};
+
+export type ArchiveFileResponse = {
+    /**
+     * Compressed size
+     */
+    bytes: number;
+    createdAt: string;
+    firstId: string;
+    id: string;
+    /**
+     * Object key in FC_ARCHIVE_STORE_URI
+     */
+    key: string;
+    /**
+     * EVENTS or DELIVERIES
+     */
+    kind: string;
+    lastId: string;
+    maxTime: string;
+    minTime: string;
+    /**
+     * UTC hour the records fall in
+     */
+    partitionHour: string;
+    records: number;
+    /**
+     * Schema version the object's records are written with
+     */
+    schemaVersion: number;
+};
+
+export type ArchiveManifestResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Pass as after to read the next page; empty on the last
+     */
+    cursor: string;
+    files: Array<ArchiveFileResponse>;
+    hasMore: boolean;
+};
+
+export type ArchiveReplayListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    items: Array<ArchiveReplayResponse>;
+};
+
+export type ArchiveReplayResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    clientId?: string;
+    completedAt?: string;
+    createdAt: string;
+    error?: string;
+    eventTypes: Array<string>;
+    /**
+     * Archived events read so far
+     */
+    eventsRead: number;
+    /**
+     * Events re-ingested so far
+     */
+    eventsReplayed: number;
+    /**
+     * Archive objects read so far
+     */
+    filesRead: number;
+    from: string;
+    id: string;
+    requestedBy?: string;
+    startedAt?: string;
+    /**
+     * PENDING, RUNNING, COMPLETED, FAILED or CANCELLED
+     */
+    status: string;
+    subscriptionId?: string;
+    to: string;
+    updatedAt: string;
+};
+
+export type ArchiveStatusResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Whether FC_ARCHIVE_STORE_URI is set
+     */
+    enabled: boolean;
+    streams: Array<ArchiveStreamResponse>;
+};
+
+export type ArchiveStreamResponse = {
+    /**
+     * Records archived since the stream started
+     */
+    archived: number;
+    /**
+     * Timestamp of the last record archived
+     */
+    archivedUntil?: string;
+    fields: Array<ArchiveFieldResponse>;
+    kind: string;
+    /**
+     * How far behind now the last record archived is
+     */
+    lagSeconds?: number;
+    /**
+     * Why the last run failed; absent after a clean one
+     */
+    lastError?: string;
+    lastRunAt?: string;
+    schemaVersion: number;
+};
+
 export type AssignApplicationAccessRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -305,6 +558,19 @@ export type AuditLogResponse = {
     principalName?: string;
 };
 
+export type AuthConfigItem = {
+    additionalClients?: Array<string>;
+    authProvider?: string;
+    configType: string;
+    emailDomain: string;
+    grantedClients?: Array<string>;
+    oidcClientId?: string;
+    oidcIssuerPattern?: string;
+    oidcIssuerUrl?: string;
+    oidcMultiTenant?: boolean;
+    primaryClient?: string;
+};
+
 export type AuthConfigListResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -368,6 +634,8 @@ export type BatchEventItem = {
     deduplicationId?: string;
     id?: string;
     messageGroup?: string;
+    receipt?: ReceiptRequest;
+    schemaId?: string;
     source?: string;
     specVersion?: string;
     subject?: string;
@@ -389,6 +657,10 @@ export type BatchResponse = {
      */
     readonly $schema?: string;
     results: Array<BatchResultItem>;
+    /**
+     * One per deprecated event type in the batch: its sunset and what to migrate to
+     */
+    warnings?: Array<string>;
 };
 
 export type BatchResultItem = {
@@ -397,6 +669,33 @@ export type BatchResultItem = {
     status: string;
 };
 
+export type Batching = {
+    maxBytes: number;
+    maxItems: number;
+    maxWaitSeconds: number;
+};
+
+export type BatchingDto = {
+    /**
+     * Cap on the array's size in bytes; 0 means no cap
+     */
+    maxBytes?: number;
+    /**
+     * Most dispatch jobs POSTed together as one JSON array (at most 100); 0 or 1 delivers one at a time
+     */
+    maxItems: number;
+    /**
+     * How long a short batch may wait for more jobs, from its oldest job's creation (at most 300); 0 sends at once
+     */
+    maxWaitSeconds?: number;
+};
+
+export type BindingItem = {
+    eventTypeCode: string;
+    filter?: string;
+    specVersion?: string;
+};
+
 export type BulkImportRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -440,6 +739,41 @@ export type BulkImportUser = {
     roles?: Array<string>;
 };
 
+export type Bundle = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    authConfigs?: Array<AuthConfigItem>;
+    dispatchPools?: Array<DispatchPoolItem>;
+    eventTypes?: Array<EventTypeItem>;
+    exportedAt?: string;
+    roles?: Array<RoleItem>;
+    subscriptions?: Array<SubscriptionItem>;
+    version: number;
+    [key: string]: unknown;
+};
+
+export type CancelRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Dispatch job ids to cancel
+     */
+    ids: Array<string>;
+    [key: string]: unknown;
+};
+
+export type CancelResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    cancelled: Array<string>;
+};
+
 export type CheckEmailDomainResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -556,6 +890,8 @@ export type ClientResponse = {
     identifier: string;
     name: string;
     notes: Array<NoteResponse>;
+    region?: string;
+    requirePasskeyForAdmins: boolean;
     status: string;
     statusChangedAt?: string;
     statusReason?: string;
@@ -580,6 +916,11 @@ export type CompleteInstanceRequest = {
     [key: string]: unknown;
 };
 
+export type ConfigEntry = {
+    key: string;
+    value: string;
+};
+
 export type ConfigEntryDto = {
     key: string;
     value: string;
@@ -638,65 +979,128 @@ export type ConnectionResponse = {
     updatedAt: string;
 };
 
-export type ContextEntryDto = {
-    key: string;
-    value: string;
-};
-
-export type CorsOriginListResponse = {
+export type ConsumeAckRequest = {
     /**
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
-    corsOrigins: Array<AllowedOriginResponse>;
-    total: number;
-};
-
-export type CreateAnchorDomainRequest = {
     /**
-     * A URL to the JSON Schema for this object.
+     * Dispatch job id of the message
      */
-    readonly $schema?: string;
-    domain: string;
+    id: string;
+    leaseToken: string;
     [key: string]: unknown;
 };
 
-export type CreateApplicationRequest = {
+export type ConsumeNackRequest = {
     /**
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
     /**
-     * Application code (lowercase, alphanumeric, hyphens)
-     */
-    code: string;
-    defaultBaseUrl?: string;
-    description?: string;
-    iconUrl?: string;
-    /**
-     * Inline SVG logo content
+     * Why processing failed; recorded as the job's last error
      */
-    logo?: string;
-    logoMimeType?: string;
-    name: string;
+    error?: string;
     /**
-     * APPLICATION or INTEGRATION
+     * Dispatch job id of the message
      */
-    type?: string;
-    website?: string;
+    id: string;
+    leaseToken: string;
     [key: string]: unknown;
 };
 
-export type CreateAuthConfigRequest = {
+export type ConsumeResponse = {
     /**
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
-    additionalClientIds?: Array<string>;
-    authProvider: string;
-    configType: string;
-    emailDomain: string;
-    grantedClientIds?: Array<string>;
+    messages: Array<ConsumedMessage>;
+};
+
+export type ConsumedMessage = {
+    attemptNumber: number;
+    /**
+     * What a push subscription's webhook would receive; a non-JSON payload arrives as a JSON string
+     */
+    body: unknown;
+    eventId?: string;
+    /**
+     * Dispatch job id
+     */
+    id: string;
+    /**
+     * Unsettled by then, the message counts as failed
+     */
+    leaseExpiresAt: string;
+    /**
+     * Presented with the ack or nack
+     */
+    leaseToken: string;
+    /**
+     * Event type code
+     */
+    type: string;
+};
+
+export type ContextEntryDto = {
+    key: string;
+    value: string;
+};
+
+export type CorsOriginListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    corsOrigins: Array<AllowedOriginResponse>;
+    total: number;
+};
+
+export type CreateAnchorDomainRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    domain: string;
+    [key: string]: unknown;
+};
+
+export type CreateApplicationRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Application code (lowercase, alphanumeric, hyphens)
+     */
+    code: string;
+    defaultBaseUrl?: string;
+    description?: string;
+    iconUrl?: string;
+    /**
+     * Inline SVG logo content
+     */
+    logo?: string;
+    logoMimeType?: string;
+    name: string;
+    /**
+     * APPLICATION or INTEGRATION
+     */
+    type?: string;
+    website?: string;
+    [key: string]: unknown;
+};
+
+export type CreateAuthConfigRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    additionalClientIds?: Array<string>;
+    authProvider: string;
+    configType: string;
+    emailDomain: string;
+    grantedClientIds?: Array<string>;
     oidcClientId?: string;
     oidcClientSecretRef?: string;
     oidcIssuerPattern?: string;
@@ -716,6 +1120,14 @@ export type CreateClientRequest = {
      */
     identifier: string;
     name: string;
+    /**
+     * Data-residency region (e.g. eu); subscriptions must use a dispatch pool in the same region
+     */
+    region?: string;
+    /**
+     * The client's admin-scope users must sign in with a passkey; without one, password sign-in only allows registering one.
+     */
+    requirePasskeyForAdmins?: boolean;
     [key: string]: unknown;
 };
 
@@ -756,6 +1168,10 @@ export type CreateDispatchPoolRequest = {
      * Messages per minute (nil = no rate limit)
      */
     rateLimit?: number;
+    /**
+     * Data-residency region deliveries egress from (e.g. eu); blank = unrestricted
+     */
+    region?: string;
     [key: string]: unknown;
 };
 
@@ -796,6 +1212,11 @@ export type CreateEventRequest = {
      * Message group for FIFO ordering
      */
     messageGroup?: string;
+    receipt?: ReceiptRequest;
+    /**
+     * Schema registry id the data was produced with. Defaults to the bound subject's latest version
+     */
+    schemaId?: string;
     /**
      * Event source URI
      */
@@ -821,6 +1242,10 @@ export type CreateEventResponse = {
      * True if this was a deduplicated request (event already existed)
      */
     isDuplicate: boolean;
+    /**
+     * Set when the event type is deprecated: its sunset and what to migrate to
+     */
+    warnings?: Array<string>;
 };
 
 export type CreateEventTypeRequest = {
@@ -848,6 +1273,32 @@ export type CreateEventTypeRequest = {
     [key: string]: unknown;
 };
 
+export type CreateFederationPeerRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * The peer instance's public URL, e.g. https://eu.flowcatalyst.example
+     */
+    baseUrl: string;
+    /**
+     * OAuth client id of an anchor service account on the peer
+     */
+    clientId: string;
+    /**
+     * Its client secret, or a secret-manager reference; sealed before it is stored
+     */
+    clientSecret: string;
+    code: string;
+    /**
+     * Event type codes to pull from the peer
+     */
+    eventTypes: Array<string>;
+    name: string;
+    [key: string]: unknown;
+};
+
 export type CreateIdentityProviderRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -920,6 +1371,7 @@ export type CreateOAuthClientRequest = {
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
+    allowedCidrs?: Array<string>;
     allowedOrigins?: Array<string>;
     applicationIds?: Array<string>;
     clientName: string;
@@ -1041,6 +1493,7 @@ export type CreateServiceAccountRequest = {
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
+    allowedCidrs?: Array<string>;
     applicationId?: string;
     clientIds?: Array<string>;
     code: string;
@@ -1067,19 +1520,32 @@ export type CreateSubscriptionRequest = {
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
+    batching?: BatchingDto;
     clientId?: string;
     code: string;
     connectionId?: string;
     customConfig?: Array<ConfigEntryDto>;
     dataOnly?: boolean;
     delaySeconds?: number;
+    /**
+     * PUSH (default) POSTs each dispatch job to the endpoint; PULL holds them for the consumer to fetch from GET /api/consume/{subscriptionId}
+     */
+    delivery?: string;
     description?: string;
     dispatchPoolId?: string;
     /**
-     * http(s) URL delivery target
+     * http(s) URL delivery target; required unless delivery is PULL
      */
-    endpoint: string;
+    endpoint?: string;
     eventTypes?: Array<EventTypeBindingDto>;
+    /**
+     * Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time
+     */
+    headers?: Array<ConfigEntryDto>;
+    /**
+     * How a payload offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES is delivered: INLINE (default) rehydrates it; PRESIGNED_URL sends its $blobRef reference with a pre-signed url to fetch it from
+     */
+    largePayloads?: string;
     maxAgeSeconds?: number;
     maxRetries?: number;
     /**
@@ -1087,8 +1553,21 @@ export type CreateSubscriptionRequest = {
      */
     mode?: string;
     name: string;
+    /**
+     * How deliveries are serialized: JSON (default), CLOUDEVENTS_JSON, NDJSON or PROTOBUF (transcoded with the event type's current PROTO schema). Pull consumers always receive JSON
+     */
+    payloadFormat?: string;
     serviceAccountId?: string;
+    /**
+     * http(s) URL each push delivery is also copied to, for comparing a new endpoint before promoting it; its answers never affect the delivery
+     */
+    shadowEndpoint?: string;
     timeoutSeconds?: number;
+    tls?: TlsTrustDto;
+    /**
+     * Named outbound transport (SSH bastion or SOCKS5 proxy, see FC_OUTBOUND_TRANSPORTS) deliveries go through; omit to deliver directly
+     */
+    transport?: string;
     [key: string]: unknown;
 };
 
@@ -1116,6 +1595,7 @@ export type CreatedEvent = {
     eventType: string;
     id: string;
     messageGroup?: string;
+    schemaId?: string;
     source: string;
     specVersion: string;
     subject?: string;
@@ -1158,12 +1638,14 @@ export type DispatchJobRead = {
     attemptCount: number;
     clientId?: string;
     clientIdentifier?: string;
+    clientName?: string;
     code: string;
     completedAt?: string;
     correlationId?: string;
     createdAt: string;
     dispatchMode?: string;
     eventId?: string;
+    eventTypeName?: string;
     id: string;
     kind: string;
     lastAttemptAt?: string;
@@ -1175,6 +1657,8 @@ export type DispatchJobRead = {
     subdomain?: string;
     subject?: string;
     subscriptionId?: string;
+    subscriptionName?: string;
+    targetHost?: string;
     targetUrl: string;
     updatedAt: string;
 };
@@ -1223,6 +1707,15 @@ export type DispatchJobResponse = {
     updatedAt: string;
 };
 
+export type DispatchPoolItem = {
+    code: string;
+    concurrency: number;
+    description?: string;
+    name: string;
+    rateLimit?: number;
+    status?: string;
+};
+
 export type DispatchPoolListResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1246,7 +1739,101 @@ export type DispatchPoolResponse = {
     id: string;
     name: string;
     rateLimit?: number;
+    region?: string;
+    status: string;
+    updatedAt: string;
+};
+
+export type DriftItemResponse = {
+    /**
+     * create, update, conflict or invalid
+     */
+    action: string;
+    /**
+     * Fields that differ from the spec
+     */
+    changed?: Array<string>;
+    /**
+     * Code, role name or email domain
+     */
+    key: string;
+    /**
+     * dispatchPool, eventType, role, authConfig, subscription or serviceAccount
+     */
+    kind: string;
+    message?: string;
+};
+
+export type EngageKillSwitchRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Why delivery is stopped; kept in the audit log
+     */
+    reason: string;
+    /**
+     * What the switch stops: CLIENT, SUBSCRIPTION or POOL
+     */
+    scope: string;
+    /**
+     * Client, subscription or dispatch pool id, per scope
+     */
+    targetId: string;
+    [key: string]: unknown;
+};
+
+export type ErasureRemainingResponse = {
+    dataKeys: number;
+    dispatchJobReads: number;
+    dispatchJobs: number;
+    eventReads: number;
+    events: number;
+};
+
+export type ErasureReportResponse = {
+    dataKeysDestroyed: number;
+    dispatchJobReadsErased: number;
+    dispatchJobsErased: number;
+    eventReadsErased: number;
+    eventsErased: number;
+    passes: number;
+    remaining: ErasureRemainingResponse;
+    verifiedAt?: string;
+};
+
+export type ErasureRequestListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    items: Array<ErasureRequestResponse>;
+};
+
+export type ErasureRequestResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    clientId?: string;
+    completedAt?: string;
+    contextKeys: Array<string>;
+    createdAt: string;
+    error?: string;
+    id: string;
+    report: ErasureReportResponse;
+    requestedBy?: string;
+    startedAt?: string;
+    /**
+     * PENDING, IN_PROGRESS, COMPLETED or FAILED
+     */
     status: string;
+    /**
+     * SHA-256 (hex) of the subject identifier
+     */
+    subjectHash: string;
+    subjectType: string;
     updatedAt: string;
 };
 
@@ -1324,6 +1911,19 @@ export type EventTypeBindingDto = {
     specVersion?: string;
 };
 
+export type EventTypeItem = {
+    clientScoped?: boolean;
+    code: string;
+    description?: string;
+    metadataRetentionDays?: number;
+    name: string;
+    payloadRetentionDays?: number;
+    redactFields?: Array<string>;
+    schemas?: Array<SchemaItem>;
+    source?: string;
+    status?: string;
+};
+
 export type EventTypeListResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1332,6 +1932,19 @@ export type EventTypeListResponse = {
     items: Array<EventTypeResponse>;
 };
 
+export type EventTypeProducersResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    eventTypeCode: string;
+    producers: Array<ProducerResponse>;
+    /**
+     * Whether only the listed producers may publish it; false means any caller with events-write may
+     */
+    restricted: boolean;
+};
+
 export type EventTypeResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1343,79 +1956,188 @@ export type EventTypeResponse = {
     code: string;
     createdAt: string;
     createdBy?: string;
+    deprecatedAt?: string;
     description?: string;
     eventName: string;
     id: string;
+    metadataRetentionDays?: number;
+    migrationHint?: string;
     name: string;
+    payloadRetentionDays?: number;
+    redactFields: Array<string>;
+    registryMode: string;
+    registrySubject?: string;
     source: string;
     specVersions: Array<SpecVersionResponse>;
     status: string;
     subdomain: string;
+    sunsetAt?: string;
     updatedAt: string;
 };
 
-export type FireNowRequest = {
+export type ExpireSigningKeyRequest = {
     /**
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
-    correlationId?: string;
+    /**
+     * When the key stops being valid (default now)
+     */
+    at?: string;
     [key: string]: unknown;
 };
 
-export type FireNowResponse = {
+export type FederatedEventResponse = {
+    causationId?: string;
+    clientIdentifier?: string;
     /**
-     * A URL to the JSON Schema for this object.
+     * The event's context, without the federation provenance keys
      */
-    readonly $schema?: string;
+    context: Array<FederationContextEntry>;
+    correlationId?: string;
+    data?: unknown;
+    deduplicationId: string;
+    /**
+     * Instances the event was forwarded across before this one
+     */
+    hops: number;
     id: string;
-    instanceId: string;
-    scheduledJobId: string;
-};
-
-export type GrantAccessRequest = {
+    messageGroup?: string;
     /**
-     * A URL to the JSON Schema for this object.
+     * Instance id the event was first produced on
      */
-    readonly $schema?: string;
-    canWrite: boolean;
-    roleCode: string;
-    [key: string]: unknown;
+    origin: string;
+    source: string;
+    specVersion: string;
+    subject: string;
+    time: string;
+    type: string;
 };
 
-export type GrantClientAccessRequest = {
-    /**
-     * A URL to the JSON Schema for this object.
-     */
-    readonly $schema?: string;
-    clientId: string;
-    [key: string]: unknown;
+export type FederationContextEntry = {
+    key: string;
+    value: string;
 };
 
-export type GrantPermissionRequest = {
+export type FederationFeedResponse = {
     /**
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
-    permission: string;
-    [key: string]: unknown;
+    /**
+     * Pass as after to read the next page
+     */
+    cursor: string;
+    events: Array<FederatedEventResponse>;
+    hasMore: boolean;
+    /**
+     * This instance's FC_FEDERATION_INSTANCE_ID
+     */
+    instanceId: string;
 };
 
-export type IdentityProviderListResponse = {
+export type FederationPeerListResponse = {
     /**
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
-    identityProviders: Array<IdentityProviderResponse>;
-    total: number;
+    peers: Array<FederationPeerResponse>;
 };
 
-export type IdentityProviderResponse = {
+export type FederationPeerResponse = {
     /**
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
-    allowedEmailDomains: Array<string>;
+    baseUrl: string;
+    clientId: string;
+    code: string;
+    createdAt: string;
+    eventTypes: Array<string>;
+    id: string;
+    /**
+     * Why the last pull failed; absent after a successful one
+     */
+    lastError?: string;
+    /**
+     * When the peer's feed was last read
+     */
+    lastPulledAt?: string;
+    name: string;
+    /**
+     * Events ingested from the peer
+     */
+    received: number;
+    /**
+     * Events dropped by loop prevention or already present
+     */
+    skipped: number;
+    status: string;
+    updatedAt: string;
+};
+
+export type FireNowRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    correlationId?: string;
+    [key: string]: unknown;
+};
+
+export type FireNowResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    id: string;
+    instanceId: string;
+    scheduledJobId: string;
+};
+
+export type GrantAccessRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    canWrite: boolean;
+    roleCode: string;
+    [key: string]: unknown;
+};
+
+export type GrantClientAccessRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    clientId: string;
+    [key: string]: unknown;
+};
+
+export type GrantPermissionRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    permission: string;
+    [key: string]: unknown;
+};
+
+export type IdentityProviderListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    identityProviders: Array<IdentityProviderResponse>;
+    total: number;
+};
+
+export type IdentityProviderResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    allowedEmailDomains: Array<string>;
     code: string;
     createdAt: string;
     hasClientSecret: boolean;
@@ -1446,6 +2168,69 @@ export type IdpRoleMappingResponse = {
     updatedAt: string;
 };
 
+export type ImportItemResponse = {
+    /**
+     * create, update, unchanged, conflict or invalid
+     */
+    action: string;
+    /**
+     * Fields that differ from the existing item
+     */
+    changed?: Array<string>;
+    /**
+     * Code, role name or email domain
+     */
+    key: string;
+    /**
+     * dispatchPool, eventType, role, authConfig or subscription
+     */
+    kind: string;
+    message?: string;
+};
+
+export type ImportResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    dryRun: boolean;
+    items: Array<ImportItemResponse>;
+    onConflict: string;
+    summary: ImportSummaryResponse;
+};
+
+export type ImportSummaryResponse = {
+    conflict: number;
+    create: number;
+    invalid: number;
+    unchanged: number;
+    update: number;
+};
+
+export type KillSwitchListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    killSwitches: Array<KillSwitchResponse>;
+};
+
+export type KillSwitchResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    engagedAt: string;
+    /**
+     * Principal that engaged the switch
+     */
+    engagedBy?: string;
+    id: string;
+    reason: string;
+    scope: string;
+    targetId: string;
+};
+
 export type ListOutputBody = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1454,6 +2239,30 @@ export type ListOutputBody = {
     requests: Array<RequestDto>;
 };
 
+export type LockoutListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    lockouts: Array<LockoutResponse>;
+};
+
+export type LockoutResponse = {
+    /**
+     * Login email, OAuth client_id or IP address, per kind
+     */
+    key: string;
+    /**
+     * PRINCIPAL, CLIENT or IP
+     */
+    kind: string;
+    /**
+     * Locks within the escalation window; each doubles the lock duration
+     */
+    level: number;
+    lockedUntil: string;
+};
+
 export type LoginAttemptListResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1514,6 +2323,22 @@ export type MetadataDto = {
     value: string;
 };
 
+export type NackRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Token the receiver returned with its 202
+     */
+    ackToken: string;
+    /**
+     * Why processing failed; recorded as the job's last error
+     */
+    error?: string;
+    [key: string]: unknown;
+};
+
 export type NoteResponse = {
     addedAt: string;
     addedBy?: string;
@@ -1540,6 +2365,7 @@ export type OAuthClientResponse = {
      */
     readonly $schema?: string;
     active: boolean;
+    allowedCidrs: Array<string>;
     allowedOrigins: Array<string>;
     applicationIds: Array<string>;
     applications: Array<OAuthClientApplicationRef>;
@@ -1590,6 +2416,15 @@ export type PermissionListResponse = {
     total: number;
 };
 
+export type PermissionMatrixResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    routes: Array<RoutePermissionResponse>;
+    total: number;
+};
+
 export type PermissionResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1700,6 +2535,51 @@ export type ProcessResponse = {
     updatedAt: string;
 };
 
+export type ProducerBindingListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    items: Array<ProducerBindingResponse>;
+};
+
+export type ProducerBindingResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    applicationId: string;
+    createdAt: string;
+    eventTypePattern: string;
+    id: string;
+    principalId?: string;
+};
+
+export type ProducerResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    applicationActive: boolean;
+    applicationCode: string;
+    applicationId: string;
+    bindingId: string;
+    eventTypePattern: string;
+    /**
+     * Principal allowed to publish; absent when the application has no service account
+     */
+    principalId?: string;
+};
+
+export type ProducersReportResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    eventTypes: Array<EventTypeProducersResponse>;
+    unmatchedBindings: Array<ProducerResponse>;
+};
+
 export type ProvisionLoginClientRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1772,6 +2652,39 @@ export type RawEventResponse = {
     time: string;
 };
 
+export type ReceiptRequest = {
+    /**
+     * Webhook the receipt is POSTed to
+     */
+    callbackUrl?: string;
+    /**
+     * Event type code the receipt is emitted as, delivered to that type's subscriptions
+     */
+    eventType?: string;
+};
+
+export type ReconcilerStatusResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Whether the last pass wrote the drift back
+     */
+    applied: boolean;
+    drift: Array<DriftItemResponse>;
+    enabled: boolean;
+    error?: string;
+    inSync: boolean;
+    lastAppliedAt?: string;
+    lastRunAt?: string;
+    /**
+     * apply or report
+     */
+    mode?: string;
+    source: SpecSourceResponse;
+};
+
 export type RegenerateAuthTokenResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1837,6 +2750,30 @@ export type RequestDto = {
     principalId: string;
 };
 
+export type RequestErasureRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Restrict a SUBJECT erasure to one client
+     */
+    clientId?: string;
+    /**
+     * Event context keys that also hold the identifier (SUBJECT only)
+     */
+    contextKeys?: Array<string>;
+    /**
+     * Subject identifier to erase; for subjectType CLIENT, the client id
+     */
+    subject: string;
+    /**
+     * SUBJECT (default) or CLIENT
+     */
+    subjectType?: string;
+    [key: string]: unknown;
+};
+
 export type RequeueRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1875,6 +2812,16 @@ export type RoleAssignmentDto = {
     roleName: string;
 };
 
+export type RoleItem = {
+    applicationCode: string;
+    clientManaged?: boolean;
+    description?: string;
+    displayName: string;
+    name: string;
+    permissions?: Array<string>;
+    source?: string;
+};
+
 export type RoleListResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -1929,6 +2876,33 @@ export type RotateOAuthClientSecretResponse = {
     clientSecret?: string;
 };
 
+export type RotateSigningKeyRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * When the new key starts signing (default now, at most 30 days ahead)
+     */
+    activeFrom?: string;
+    /**
+     * Name of the new key, sent as X-FLOWCATALYST-KEY-ID (default v<n>)
+     */
+    keyId?: string;
+    /**
+     * How long the replaced keys stay valid after activeFrom (default 86400)
+     */
+    overlapSeconds?: number;
+    [key: string]: unknown;
+};
+
+export type RoutePermissionResponse = {
+    access: string;
+    method: string;
+    path: string;
+    permissions: Array<string>;
+};
+
 export type ScheduledJobInstanceLogResponse = {
     clientId?: string;
     createdAt: string;
@@ -1992,6 +2966,14 @@ export type ScheduledJobResponse = {
     version: number;
 };
 
+export type SchemaItem = {
+    content?: unknown;
+    mimeType?: string;
+    schemaType?: string;
+    status?: string;
+    version: string;
+};
+
 export type SearchClientRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2030,6 +3012,7 @@ export type ServiceAccountResponse = {
      */
     readonly $schema?: string;
     active: boolean;
+    allowedCidrs: Array<string>;
     applicationId?: string;
     authType: string;
     clientIds: Array<string>;
@@ -2103,13 +3086,164 @@ export type SetPropertyRequest = {
     [key: string]: unknown;
 };
 
-export type SpecVersionResponse = {
-    createdAt: string;
-    schema: unknown;
-    status: string;
+export type SetTrafficSplitRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Replaces the split; empty sends every message group to the endpoint
+     */
+    split: Array<SplitTargetDto>;
+    [key: string]: unknown;
+};
+
+export type ShadowOutcomeDto = {
+    deliveries: number;
+    /**
+     * Absent when no response came back
+     */
+    primaryStatusCode?: number;
+    primarySuccess: boolean;
+    /**
+     * Absent when no response came back
+     */
+    shadowStatusCode?: number;
+    shadowSuccess: boolean;
+};
+
+export type ShadowReportResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Deliveries both endpoints accepted or both refused
+     */
+    agreed: number;
+    /**
+     * Deliveries copied to the shadow endpoint since the given time
+     */
+    deliveries: number;
+    /**
+     * Status code combinations, most common first
+     */
+    outcomes: Array<ShadowOutcomeDto>;
+    primary: ShadowSideDto;
+    shadow: ShadowSideDto;
+    shadowEndpoint: string;
+    since: string;
+};
+
+export type ShadowSideDto = {
+    /**
+     * Mean attempt latency in milliseconds
+     */
+    avgMs: number;
+    p50Ms: number;
+    p95Ms: number;
+    /**
+     * Deliveries the endpoint accepted
+     */
+    successes: number;
+};
+
+export type SigningKeyCreatedResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * HMAC-SHA256 signing secret. Shown only in this response.
+     */
+    secret: string;
+    signingKey: SigningKeyResponse;
+};
+
+export type SigningKeyListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    signingKeys: Array<SigningKeyResponse>;
+};
+
+export type SigningKeyResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    activeFrom: string;
+    clientId: string;
+    createdAt: string;
+    createdBy?: string;
+    expiresAt?: string;
+    id: string;
+    keyId: string;
+    signing: boolean;
+    /**
+     * PENDING, ACTIVE or EXPIRED
+     */
+    status: string;
+};
+
+export type SpecSourceResponse = {
+    /**
+     * SHA-256 over the spec files
+     */
+    digest?: string;
+    files: Array<string>;
+    path: string;
+    /**
+     * Git commit checked out at the spec path, when it is a git working tree
+     */
+    revision?: string;
+};
+
+export type SpecVersionResponse = {
+    createdAt: string;
+    schema: unknown;
+    schemaType: string;
+    status: string;
     version: string;
 };
 
+export type SplitTargetDto = {
+    endpoint: string;
+    /**
+     * Percentage of message groups routed to this endpoint, 1-100
+     */
+    weight: number;
+};
+
+export type StartArchiveReplayRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Replay only this client's events
+     */
+    clientId?: string;
+    /**
+     * Event type patterns (* matches a segment); every type when omitted
+     */
+    eventTypes?: Array<string>;
+    /**
+     * Replay events ingested at or after this time
+     */
+    from: string;
+    /**
+     * Deliver the replayed events to this subscription only
+     */
+    subscriptionId?: string;
+    /**
+     * Replay events ingested before this time; must already be archived
+     */
+    to: string;
+    [key: string]: unknown;
+};
+
 export type StatusChangeRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2127,6 +3261,65 @@ export type StatusChangeResponse = {
     message: string;
 };
 
+export type StreamDlqEntryResponse = {
+    /**
+     * Failed attempts, summed across reprocessing
+     */
+    attempts: number;
+    createdAt: string;
+    /**
+     * The last failure
+     */
+    error: string;
+    eventCreatedAt: string;
+    eventType: string;
+    id: string;
+    messageGroup?: string;
+    /**
+     * Pipeline step that failed: FAN_OUT
+     */
+    stage: string;
+    updatedAt: string;
+};
+
+export type StreamDlqListResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    items: Array<StreamDlqEntryResponse>;
+};
+
+export type SubscriptionItem = {
+    applicationCode?: string;
+    batching?: Batching;
+    clientScoped?: boolean;
+    code: string;
+    connectionCode?: string;
+    customConfig?: Array<ConfigEntry>;
+    dataOnly?: boolean;
+    delaySeconds: number;
+    delivery?: string;
+    description?: string;
+    dispatchPoolCode?: string;
+    endpoint?: string;
+    eventTypes: Array<BindingItem>;
+    headers?: Array<ConfigEntry>;
+    largePayloads?: string;
+    maxAgeSeconds: number;
+    maxRetries: number;
+    mode?: string;
+    name: string;
+    payloadFormat?: string;
+    queue?: string;
+    sequence: number;
+    source?: string;
+    status?: string;
+    timeoutSeconds: number;
+    tls?: TlsTrust;
+    transport?: string;
+};
+
 export type SubscriptionListResponse = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2142,6 +3335,7 @@ export type SubscriptionResponse = {
      */
     readonly $schema?: string;
     applicationCode?: string;
+    batching?: BatchingDto;
     clientId?: string;
     clientIdentifier?: string;
     clientScoped: boolean;
@@ -2152,22 +3346,33 @@ export type SubscriptionResponse = {
     customConfig: Array<ConfigEntryDto>;
     dataOnly: boolean;
     delaySeconds: number;
+    delivery: string;
     description?: string;
     dispatchPoolCode?: string;
     dispatchPoolId?: string;
     endpoint: string;
     eventTypes: Array<EventTypeBindingDto>;
+    headers: Array<ConfigEntryDto>;
     id: string;
+    largePayloads: string;
     maxAgeSeconds: number;
     maxRetries: number;
     mode: string;
     name: string;
+    payloadFormat: string;
     queue?: string;
     sequence: number;
     serviceAccountId?: string;
+    shadowEndpoint?: string;
     source: string;
     status: string;
     timeoutSeconds: number;
+    tls?: TlsTrustDto;
+    /**
+     * Endpoints taking a share of the message groups; the endpoint keeps the rest
+     */
+    trafficSplit: Array<SplitTargetDto>;
+    transport?: string;
     updatedAt: string;
 };
 
@@ -2461,6 +3666,66 @@ export type SyncUsersResponse = {
     updated: number;
 };
 
+export type TargetStatsDto = {
+    attempts: number;
+    /**
+     * Mean attempt latency in milliseconds
+     */
+    avgMs: number;
+    completed: number;
+    endpoint: string;
+    /**
+     * Jobs that failed or expired
+     */
+    failed: number;
+    /**
+     * Dispatch jobs created for the endpoint
+     */
+    jobs: number;
+    p95Ms: number;
+    /**
+     * Attempts the endpoint accepted
+     */
+    successes: number;
+    /**
+     * Current share of message groups; 0 for an endpoint no longer routed to
+     */
+    weight: number;
+};
+
+export type TargetStatsResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    since: string;
+    /**
+     * The endpoint, then its traffic split, then any endpoint routed to earlier in the window
+     */
+    targets: Array<TargetStatsDto>;
+};
+
+export type TlsTrust = {
+    caBundle?: string;
+    minVersion?: string;
+    spkiPins?: Array<string>;
+};
+
+export type TlsTrustDto = {
+    /**
+     * PEM root certificates trusted in addition to the system roots (at most 256 KiB)
+     */
+    caBundle?: string;
+    /**
+     * Lowest TLS version accepted: 1.2 or 1.3
+     */
+    minVersion?: string;
+    /**
+     * "sha256/<base64>" hashes of subject public key infos; when set, a certificate in the verified chain must match one (at most 10)
+     */
+    spkiPins?: Array<string>;
+};
+
 export type UpdateAnchorDomainRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2470,6 +3735,26 @@ export type UpdateAnchorDomainRequest = {
     [key: string]: unknown;
 };
 
+export type UpdateAnomalySettingRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * False stops the entity raising anomalies; its baseline keeps learning
+     */
+    enabled: boolean;
+    /**
+     * Z-score a window's value must reach to raise an anomaly, 2-10 (default 3). Lower is more sensitive.
+     */
+    sensitivity?: number;
+    /**
+     * Raise nothing for the entity until then
+     */
+    snoozedUntil?: string;
+    [key: string]: unknown;
+};
+
 export type UpdateApplicationRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2517,6 +3802,11 @@ export type UpdateClientRequest = {
      */
     readonly $schema?: string;
     name?: string;
+    /**
+     * Data-residency region; empty string clears it
+     */
+    region?: string;
+    requirePasskeyForAdmins?: boolean;
     [key: string]: unknown;
 };
 
@@ -2532,6 +3822,26 @@ export type UpdateConnectionRequest = {
     [key: string]: unknown;
 };
 
+export type UpdateDataPolicyRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * Delete events and their dispatch jobs after this many days; absent keeps them until partition drop
+     */
+    metadataRetentionDays?: number;
+    /**
+     * Purge event data and dispatch payloads after this many days; absent keeps them until partition drop
+     */
+    payloadRetentionDays?: number;
+    /**
+     * Dotted JSON paths masked on read and debug views (arrays are walked; * matches any key)
+     */
+    redactFields?: Array<string>;
+    [key: string]: unknown;
+};
+
 export type UpdateDispatchPoolRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2541,6 +3851,10 @@ export type UpdateDispatchPoolRequest = {
     description?: string;
     name?: string;
     rateLimit?: number;
+    /**
+     * Data-residency region; empty string clears it
+     */
+    region?: string;
     [key: string]: unknown;
 };
 
@@ -2554,6 +3868,26 @@ export type UpdateEventTypeRequest = {
     [key: string]: unknown;
 };
 
+export type UpdateFederationPeerRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    baseUrl: string;
+    clientId: string;
+    /**
+     * A new client secret; omit to keep the stored one
+     */
+    clientSecret?: string;
+    eventTypes: Array<string>;
+    name: string;
+    /**
+     * ACTIVE or PAUSED
+     */
+    status: string;
+    [key: string]: unknown;
+};
+
 export type UpdateIdentityProviderRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2569,6 +3903,26 @@ export type UpdateIdentityProviderRequest = {
     [key: string]: unknown;
 };
 
+export type UpdateLifecycleRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * true deprecates the event type; false undeprecates it and clears the sunset and hint
+     */
+    deprecated: boolean;
+    /**
+     * What producers and subscribers should move to, e.g. the replacement event type; returned with every warning and rejection
+     */
+    migrationHint?: string;
+    /**
+     * When ingestion starts rejecting the event type's events; must be in the future. Absent: no sunset
+     */
+    sunsetAt?: string;
+    [key: string]: unknown;
+};
+
 export type UpdateMappingRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2593,6 +3947,7 @@ export type UpdateOAuthClientRequest = {
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
+    allowedCidrs?: Array<string>;
     allowedOrigins?: Array<string>;
     applicationIds?: Array<string>;
     clientName?: string;
@@ -2632,6 +3987,22 @@ export type UpdateProcessRequest = {
     [key: string]: unknown;
 };
 
+export type UpdateRegistryBindingRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    /**
+     * RESOLVE (default) checks and stamps each event's schema id; VALIDATE also validates its data against the schema
+     */
+    mode?: string;
+    /**
+     * Schema registry subject the event type's payloads are registered under; absent or blank unbinds
+     */
+    subject?: string;
+    [key: string]: unknown;
+};
+
 export type UpdateRoleRequest = {
     /**
      * A URL to the JSON Schema for this object.
@@ -2667,6 +4038,7 @@ export type UpdateServiceAccountRequest = {
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
+    allowedCidrs?: Array<string>;
     clientIds?: Array<string>;
     description?: string;
     name?: string;
@@ -2680,32 +4052,173 @@ export type UpdateSubscriptionRequest = {
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
+    batching?: BatchingDto;
     connectionId?: string;
     customConfig?: Array<ConfigEntryDto>;
     dataOnly?: boolean;
     delaySeconds?: number;
+    /**
+     * PUSH or PULL; switching to PUSH needs a valid endpoint
+     */
+    delivery?: string;
     description?: string;
     dispatchPoolId?: string;
     endpoint?: string;
     eventTypes?: Array<EventTypeBindingDto>;
+    /**
+     * Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time
+     */
+    headers?: Array<ConfigEntryDto>;
+    /**
+     * INLINE or PRESIGNED_URL
+     */
+    largePayloads?: string;
     maxAgeSeconds?: number;
     maxRetries?: number;
     mode?: string;
     name?: string;
+    /**
+     * JSON, CLOUDEVENTS_JSON, NDJSON or PROTOBUF
+     */
+    payloadFormat?: string;
     serviceAccountId?: string;
+    /**
+     * http(s) URL push deliveries are also copied to; an empty string stops shadowing
+     */
+    shadowEndpoint?: string;
     timeoutSeconds?: number;
+    tls?: TlsTrustDto;
+    /**
+     * Named outbound transport deliveries go through; an empty string switches back to direct delivery
+     */
+    transport?: string;
     [key: string]: unknown;
 };
 
-export type WebauthnAuthenticateCompleteResponse = {
+export type UpsertDispatchPoolRequest = {
     /**
      * A URL to the JSON Schema for this object.
      */
     readonly $schema?: string;
-    email: string | null;
-    name: string;
-    principalId: string;
-    roles: Array<string>;
+    /**
+     * Max concurrent dispatches (default 10)
+     */
+    concurrency?: number;
+    description?: string;
+    name: string;
+    /**
+     * Messages per minute (nil = no rate limit)
+     */
+    rateLimit?: number;
+    [key: string]: unknown;
+};
+
+export type UpsertEventTypeRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    description?: string;
+    /**
+     * Human-readable event type name
+     */
+    name: string;
+    /**
+     * Optional JSON Schema for the initial spec version; used only on create
+     */
+    schema?: unknown;
+    [key: string]: unknown;
+};
+
+export type UpsertServiceAccountRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    allowedCidrs?: Array<string>;
+    applicationId?: string;
+    clientIds?: Array<string>;
+    description?: string;
+    name: string;
+    scope?: string;
+    webhookCredentials?: WebhookCredentialsDto;
+    [key: string]: unknown;
+};
+
+export type UpsertServiceAccountResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    oauth?: ServiceAccountOAuthSecrets;
+    principalId?: string;
+    serviceAccount: ServiceAccountResponse;
+    webhook?: ServiceAccountWebhookSecrets;
+};
+
+export type UpsertSubscriptionRequest = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    batching?: BatchingDto;
+    connectionId?: string;
+    customConfig?: Array<ConfigEntryDto>;
+    dataOnly?: boolean;
+    delaySeconds?: number;
+    /**
+     * PUSH (default) POSTs each dispatch job to the endpoint; PULL holds them for the consumer to fetch from GET /api/consume/{subscriptionId}
+     */
+    delivery?: string;
+    description?: string;
+    dispatchPoolId?: string;
+    /**
+     * http(s) URL delivery target; required unless delivery is PULL
+     */
+    endpoint?: string;
+    eventTypes?: Array<EventTypeBindingDto>;
+    /**
+     * Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time
+     */
+    headers?: Array<ConfigEntryDto>;
+    /**
+     * How a payload offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES is delivered: INLINE (default) rehydrates it; PRESIGNED_URL sends its $blobRef reference with a pre-signed url to fetch it from
+     */
+    largePayloads?: string;
+    maxAgeSeconds?: number;
+    maxRetries?: number;
+    /**
+     * Dispatch mode (IMMEDIATE, NEXT_ON_ERROR, BLOCK_ON_ERROR)
+     */
+    mode?: string;
+    name: string;
+    /**
+     * How deliveries are serialized: JSON (default), CLOUDEVENTS_JSON, NDJSON or PROTOBUF (transcoded with the event type's current PROTO schema). Pull consumers always receive JSON
+     */
+    payloadFormat?: string;
+    serviceAccountId?: string;
+    /**
+     * http(s) URL each push delivery is also copied to, for comparing a new endpoint before promoting it; its answers never affect the delivery
+     */
+    shadowEndpoint?: string;
+    timeoutSeconds?: number;
+    tls?: TlsTrustDto;
+    /**
+     * Named outbound transport (SSH bastion or SOCKS5 proxy, see FC_OUTBOUND_TRANSPORTS) deliveries go through; omit to deliver directly
+     */
+    transport?: string;
+    [key: string]: unknown;
+};
+
+export type WebauthnAuthenticateCompleteResponse = {
+    /**
+     * A URL to the JSON Schema for this object.
+     */
+    readonly $schema?: string;
+    email: string | null;
+    name: string;
+    principalId: string;
+    roles: Array<string>;
 };
 
 export type WebauthnCredentialSummary = {
@@ -2744,6 +4257,19 @@ export type AccessListResponseWritable = {
     items: Array<AccessResponse>;
 };
 
+export type AckRequestWritable = {
+    /**
+     * Token the receiver returned with its 202
+     */
+    ackToken: string;
+    [key: string]: unknown;
+};
+
+export type AckResponseWritable = {
+    id: string;
+    status: string;
+};
+
 export type AddNoteRequestWritable = {
     category: string;
     text: string;
@@ -2759,6 +4285,18 @@ export type AddOriginRequestWritable = {
     [key: string]: unknown;
 };
 
+export type AddProducerBindingRequestWritable = {
+    /**
+     * Event type code the application may publish; * matches any one segment (e.g. orders:sales:order:*). Ingestion enforces a new or removed binding at once when FC_REDIS_URL is set, else on other replicas within 30 seconds
+     */
+    eventTypePattern: string;
+    /**
+     * Service account principal allowed to publish. Omit for the application's own service account
+     */
+    principalId?: string;
+    [key: string]: unknown;
+};
+
 export type AddRoleRequestWritable = {
     role: string;
     [key: string]: unknown;
@@ -2766,9 +4304,13 @@ export type AddRoleRequestWritable = {
 
 export type AddSchemaRequestWritable = {
     /**
-     * JSON Schema document
+     * JSON Schema document, or for PROTO {messageType, fileDescriptorSet} with the set in protobuf's JSON mapping
      */
     schema: unknown;
+    /**
+     * JSON_SCHEMA (default) or PROTO. PROTOBUF subscriptions transcode payloads with the event type's CURRENT PROTO schema
+     */
+    schemaType?: string;
     /**
      * Schema version (typically semver)
      */
@@ -2789,6 +4331,37 @@ export type AnchorDomainListResponseWritable = {
     items: Array<AnchorDomainResponse>;
 };
 
+export type AnomalyListResponseWritable = {
+    anomalies: Array<AnomalyResponse>;
+};
+
+export type AnomalySettingListResponseWritable = {
+    settings: Array<AnomalySettingResponseWritable>;
+};
+
+export type AnomalySettingResponseWritable = {
+    enabled: boolean;
+    /**
+     * Event type code or subscription id
+     */
+    entityKey: string;
+    /**
+     * EVENT_TYPE or SUBSCRIPTION
+     */
+    entityKind: string;
+    id: string;
+    /**
+     * Z-score threshold; absent means the default
+     */
+    sensitivity?: number;
+    snoozedUntil?: string;
+    updatedAt: string;
+    /**
+     * Principal that last changed the setting
+     */
+    updatedBy?: string;
+};
+
 export type ApplicationAccessListResponseWritable = {
     allApplications: boolean;
     applications: Array<ApplicationAccessResponse>;
@@ -2836,6 +4409,58 @@ export type ApplicationRolesResponseWritable = {
     roles: Array<string>;
 };
 
+export type ArchiveManifestResponseWritable = {
+    /**
+     * Pass as after to read the next page; empty on the last
+     */
+    cursor: string;
+    files: Array<ArchiveFileResponse>;
+    hasMore: boolean;
+};
+
+export type ArchiveReplayListResponseWritable = {
+    items: Array<ArchiveReplayResponseWritable>;
+};
+
+export type ArchiveReplayResponseWritable = {
+    clientId?: string;
+    completedAt?: string;
+    createdAt: string;
+    error?: string;
+    eventTypes: Array<string>;
+    /**
+     * Archived events read so far
+     */
+    eventsRead: number;
+    /**
+     * Events re-ingested so far
+     */
+    eventsReplayed: number;
+    /**
+     * Archive objects read so far
+     */
+    filesRead: number;
+    from: string;
+    id: string;
+    requestedBy?: string;
+    startedAt?: string;
+    /**
+     * PENDING, RUNNING, COMPLETED, FAILED or CANCELLED
+     */
+    status: string;
+    subscriptionId?: string;
+    to: string;
+    updatedAt: string;
+};
+
+export type ArchiveStatusResponseWritable = {
+    /**
+     * Whether FC_ARCHIVE_STORE_URI is set
+     */
+    enabled: boolean;
+    streams: Array<ArchiveStreamResponse>;
+};
+
 export type AssignApplicationAccessRequestWritable = {
     allApplications?: boolean;
     applicationIds: Array<string>;
@@ -2920,6 +4545,10 @@ export type BatchRequestWritable = {
 
 export type BatchResponseWritable = {
     results: Array<BatchResultItem>;
+    /**
+     * One per deprecated event type in the batch: its sunset and what to migrate to
+     */
+    warnings?: Array<string>;
 };
 
 export type BulkImportRequestWritable = {
@@ -2938,6 +4567,29 @@ export type BulkImportResponseWritable = {
     skipped: number;
 };
 
+export type BundleWritable = {
+    authConfigs?: Array<AuthConfigItem>;
+    dispatchPools?: Array<DispatchPoolItem>;
+    eventTypes?: Array<EventTypeItem>;
+    exportedAt?: string;
+    roles?: Array<RoleItem>;
+    subscriptions?: Array<SubscriptionItem>;
+    version: number;
+    [key: string]: unknown;
+};
+
+export type CancelRequestWritable = {
+    /**
+     * Dispatch job ids to cancel
+     */
+    ids: Array<string>;
+    [key: string]: unknown;
+};
+
+export type CancelResponseWritable = {
+    cancelled: Array<string>;
+};
+
 export type CheckEmailDomainResponseWritable = {
     allowedClientIds: Array<string>;
     authMethod: string;
@@ -3008,6 +4660,8 @@ export type ClientResponseWritable = {
     identifier: string;
     name: string;
     notes: Array<NoteResponse>;
+    region?: string;
+    requirePasskeyForAdmins: boolean;
     status: string;
     statusChangedAt?: string;
     statusReason?: string;
@@ -3065,6 +4719,32 @@ export type ConnectionResponseWritable = {
     updatedAt: string;
 };
 
+export type ConsumeAckRequestWritable = {
+    /**
+     * Dispatch job id of the message
+     */
+    id: string;
+    leaseToken: string;
+    [key: string]: unknown;
+};
+
+export type ConsumeNackRequestWritable = {
+    /**
+     * Why processing failed; recorded as the job's last error
+     */
+    error?: string;
+    /**
+     * Dispatch job id of the message
+     */
+    id: string;
+    leaseToken: string;
+    [key: string]: unknown;
+};
+
+export type ConsumeResponseWritable = {
+    messages: Array<ConsumedMessage>;
+};
+
 export type CorsOriginListResponseWritable = {
     corsOrigins: Array<AllowedOriginResponseWritable>;
     total: number;
@@ -3118,6 +4798,14 @@ export type CreateClientRequestWritable = {
      */
     identifier: string;
     name: string;
+    /**
+     * Data-residency region (e.g. eu); subscriptions must use a dispatch pool in the same region
+     */
+    region?: string;
+    /**
+     * The client's admin-scope users must sign in with a passkey; without one, password sign-in only allows registering one.
+     */
+    requirePasskeyForAdmins?: boolean;
     [key: string]: unknown;
 };
 
@@ -3150,6 +4838,10 @@ export type CreateDispatchPoolRequestWritable = {
      * Messages per minute (nil = no rate limit)
      */
     rateLimit?: number;
+    /**
+     * Data-residency region deliveries egress from (e.g. eu); blank = unrestricted
+     */
+    region?: string;
     [key: string]: unknown;
 };
 
@@ -3186,6 +4878,11 @@ export type CreateEventRequestWritable = {
      * Message group for FIFO ordering
      */
     messageGroup?: string;
+    receipt?: ReceiptRequest;
+    /**
+     * Schema registry id the data was produced with. Defaults to the bound subject's latest version
+     */
+    schemaId?: string;
     /**
      * Event source URI
      */
@@ -3207,6 +4904,10 @@ export type CreateEventResponseWritable = {
      * True if this was a deduplicated request (event already existed)
      */
     isDuplicate: boolean;
+    /**
+     * Set when the event type is deprecated: its sunset and what to migrate to
+     */
+    warnings?: Array<string>;
 };
 
 export type CreateEventTypeRequestWritable = {
@@ -3230,6 +4931,28 @@ export type CreateEventTypeRequestWritable = {
     [key: string]: unknown;
 };
 
+export type CreateFederationPeerRequestWritable = {
+    /**
+     * The peer instance's public URL, e.g. https://eu.flowcatalyst.example
+     */
+    baseUrl: string;
+    /**
+     * OAuth client id of an anchor service account on the peer
+     */
+    clientId: string;
+    /**
+     * Its client secret, or a secret-manager reference; sealed before it is stored
+     */
+    clientSecret: string;
+    code: string;
+    /**
+     * Event type codes to pull from the peer
+     */
+    eventTypes: Array<string>;
+    name: string;
+    [key: string]: unknown;
+};
+
 export type CreateIdentityProviderRequestWritable = {
     allowedEmailDomains?: Array<string>;
     /**
@@ -3401,19 +5124,32 @@ export type CreateServiceAccountResponseWritable = {
 };
 
 export type CreateSubscriptionRequestWritable = {
+    batching?: BatchingDto;
     clientId?: string;
     code: string;
     connectionId?: string;
     customConfig?: Array<ConfigEntryDto>;
     dataOnly?: boolean;
     delaySeconds?: number;
+    /**
+     * PUSH (default) POSTs each dispatch job to the endpoint; PULL holds them for the consumer to fetch from GET /api/consume/{subscriptionId}
+     */
+    delivery?: string;
     description?: string;
     dispatchPoolId?: string;
     /**
-     * http(s) URL delivery target
+     * http(s) URL delivery target; required unless delivery is PULL
      */
-    endpoint: string;
+    endpoint?: string;
     eventTypes?: Array<EventTypeBindingDto>;
+    /**
+     * Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time
+     */
+    headers?: Array<ConfigEntryDto>;
+    /**
+     * How a payload offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES is delivered: INLINE (default) rehydrates it; PRESIGNED_URL sends its $blobRef reference with a pre-signed url to fetch it from
+     */
+    largePayloads?: string;
     maxAgeSeconds?: number;
     maxRetries?: number;
     /**
@@ -3421,8 +5157,21 @@ export type CreateSubscriptionRequestWritable = {
      */
     mode?: string;
     name: string;
+    /**
+     * How deliveries are serialized: JSON (default), CLOUDEVENTS_JSON, NDJSON or PROTOBUF (transcoded with the event type's current PROTO schema). Pull consumers always receive JSON
+     */
+    payloadFormat?: string;
     serviceAccountId?: string;
+    /**
+     * http(s) URL each push delivery is also copied to, for comparing a new endpoint before promoting it; its answers never affect the delivery
+     */
+    shadowEndpoint?: string;
     timeoutSeconds?: number;
+    tls?: TlsTrustDto;
+    /**
+     * Named outbound transport (SSH bastion or SOCKS5 proxy, see FC_OUTBOUND_TRANSPORTS) deliveries go through; omit to deliver directly
+     */
+    transport?: string;
     [key: string]: unknown;
 };
 
@@ -3508,27 +5257,70 @@ export type DispatchPoolResponseWritable = {
     id: string;
     name: string;
     rateLimit?: number;
+    region?: string;
     status: string;
     updatedAt: string;
 };
 
-export type ErrorModelWritable = {
-    details?: {
-        [key: string]: unknown;
-    };
-    error: string;
-    message: string;
+export type EngageKillSwitchRequestWritable = {
+    /**
+     * Why delivery is stopped; kept in the audit log
+     */
+    reason: string;
+    /**
+     * What the switch stops: CLIENT, SUBSCRIPTION or POOL
+     */
+    scope: string;
+    /**
+     * Client, subscription or dispatch pool id, per scope
+     */
+    targetId: string;
+    [key: string]: unknown;
 };
 
-export type EventFilterOptionsResponseWritable = {
-    applications: Array<EventFilterOption>;
-    eventTypes: Array<EventFilterOption>;
-    subdomains: Array<EventFilterOption>;
+export type ErasureRequestListResponseWritable = {
+    items: Array<ErasureRequestResponseWritable>;
 };
 
-export type EventResponseWritable = {
-    aggregate?: string;
-    application?: string;
+export type ErasureRequestResponseWritable = {
+    clientId?: string;
+    completedAt?: string;
+    contextKeys: Array<string>;
+    createdAt: string;
+    error?: string;
+    id: string;
+    report: ErasureReportResponse;
+    requestedBy?: string;
+    startedAt?: string;
+    /**
+     * PENDING, IN_PROGRESS, COMPLETED or FAILED
+     */
+    status: string;
+    /**
+     * SHA-256 (hex) of the subject identifier
+     */
+    subjectHash: string;
+    subjectType: string;
+    updatedAt: string;
+};
+
+export type ErrorModelWritable = {
+    details?: {
+        [key: string]: unknown;
+    };
+    error: string;
+    message: string;
+};
+
+export type EventFilterOptionsResponseWritable = {
+    applications: Array<EventFilterOption>;
+    eventTypes: Array<EventFilterOption>;
+    subdomains: Array<EventFilterOption>;
+};
+
+export type EventResponseWritable = {
+    aggregate?: string;
+    application?: string;
     causationId?: string;
     clientId?: string;
     contextData?: Array<ContextEntryDto>;
@@ -3551,6 +5343,15 @@ export type EventTypeListResponseWritable = {
     items: Array<EventTypeResponseWritable>;
 };
 
+export type EventTypeProducersResponseWritable = {
+    eventTypeCode: string;
+    producers: Array<ProducerResponseWritable>;
+    /**
+     * Whether only the listed producers may publish it; false means any caller with events-write may
+     */
+    restricted: boolean;
+};
+
 export type EventTypeResponseWritable = {
     aggregate: string;
     application: string;
@@ -3558,14 +5359,75 @@ export type EventTypeResponseWritable = {
     code: string;
     createdAt: string;
     createdBy?: string;
+    deprecatedAt?: string;
     description?: string;
     eventName: string;
     id: string;
+    metadataRetentionDays?: number;
+    migrationHint?: string;
     name: string;
+    payloadRetentionDays?: number;
+    redactFields: Array<string>;
+    registryMode: string;
+    registrySubject?: string;
     source: string;
     specVersions: Array<SpecVersionResponse>;
     status: string;
     subdomain: string;
+    sunsetAt?: string;
+    updatedAt: string;
+};
+
+export type ExpireSigningKeyRequestWritable = {
+    /**
+     * When the key stops being valid (default now)
+     */
+    at?: string;
+    [key: string]: unknown;
+};
+
+export type FederationFeedResponseWritable = {
+    /**
+     * Pass as after to read the next page
+     */
+    cursor: string;
+    events: Array<FederatedEventResponse>;
+    hasMore: boolean;
+    /**
+     * This instance's FC_FEDERATION_INSTANCE_ID
+     */
+    instanceId: string;
+};
+
+export type FederationPeerListResponseWritable = {
+    peers: Array<FederationPeerResponseWritable>;
+};
+
+export type FederationPeerResponseWritable = {
+    baseUrl: string;
+    clientId: string;
+    code: string;
+    createdAt: string;
+    eventTypes: Array<string>;
+    id: string;
+    /**
+     * Why the last pull failed; absent after a successful one
+     */
+    lastError?: string;
+    /**
+     * When the peer's feed was last read
+     */
+    lastPulledAt?: string;
+    name: string;
+    /**
+     * Events ingested from the peer
+     */
+    received: number;
+    /**
+     * Events dropped by loop prevention or already present
+     */
+    skipped: number;
+    status: string;
     updatedAt: string;
 };
 
@@ -3620,10 +5482,37 @@ export type IdpRoleMappingListResponseWritable = {
     items: Array<IdpRoleMappingResponse>;
 };
 
+export type ImportResponseWritable = {
+    dryRun: boolean;
+    items: Array<ImportItemResponse>;
+    onConflict: string;
+    summary: ImportSummaryResponse;
+};
+
+export type KillSwitchListResponseWritable = {
+    killSwitches: Array<KillSwitchResponseWritable>;
+};
+
+export type KillSwitchResponseWritable = {
+    engagedAt: string;
+    /**
+     * Principal that engaged the switch
+     */
+    engagedBy?: string;
+    id: string;
+    reason: string;
+    scope: string;
+    targetId: string;
+};
+
 export type ListOutputBodyWritable = {
     requests: Array<RequestDto>;
 };
 
+export type LockoutListResponseWritable = {
+    lockouts: Array<LockoutResponse>;
+};
+
 export type LoginAttemptListResponseWritable = {
     hasMore: boolean;
     items: Array<LoginAttemptResponse>;
@@ -3655,12 +5544,25 @@ export type MappingResponseWritable = {
     updatedAt: string;
 };
 
+export type NackRequestWritable = {
+    /**
+     * Token the receiver returned with its 202
+     */
+    ackToken: string;
+    /**
+     * Why processing failed; recorded as the job's last error
+     */
+    error?: string;
+    [key: string]: unknown;
+};
+
 export type OAuthClientListResponseWritable = {
     clients: Array<OAuthClientResponseWritable>;
 };
 
 export type OAuthClientResponseWritable = {
     active: boolean;
+    allowedCidrs: Array<string>;
     allowedOrigins: Array<string>;
     applicationIds: Array<string>;
     applications: Array<OAuthClientApplicationRef>;
@@ -3699,6 +5601,11 @@ export type PermissionListResponseWritable = {
     total: number;
 };
 
+export type PermissionMatrixResponseWritable = {
+    routes: Array<RoutePermissionResponse>;
+    total: number;
+};
+
 export type PermissionResponseWritable = {
     category?: string;
     description?: string;
@@ -3764,6 +5671,35 @@ export type ProcessResponseWritable = {
     updatedAt: string;
 };
 
+export type ProducerBindingListResponseWritable = {
+    items: Array<ProducerBindingResponseWritable>;
+};
+
+export type ProducerBindingResponseWritable = {
+    applicationId: string;
+    createdAt: string;
+    eventTypePattern: string;
+    id: string;
+    principalId?: string;
+};
+
+export type ProducerResponseWritable = {
+    applicationActive: boolean;
+    applicationCode: string;
+    applicationId: string;
+    bindingId: string;
+    eventTypePattern: string;
+    /**
+     * Principal allowed to publish; absent when the application has no service account
+     */
+    principalId?: string;
+};
+
+export type ProducersReportResponseWritable = {
+    eventTypes: Array<EventTypeProducersResponseWritable>;
+    unmatchedBindings: Array<ProducerResponseWritable>;
+};
+
 export type ProvisionLoginClientRequestWritable = {
     allowedOrigins?: Array<string>;
     /**
@@ -3778,6 +5714,24 @@ export type PublicAllowedResponseWritable = {
     origins: Array<string>;
 };
 
+export type ReconcilerStatusResponseWritable = {
+    /**
+     * Whether the last pass wrote the drift back
+     */
+    applied: boolean;
+    drift: Array<DriftItemResponse>;
+    enabled: boolean;
+    error?: string;
+    inSync: boolean;
+    lastAppliedAt?: string;
+    lastRunAt?: string;
+    /**
+     * apply or report
+     */
+    mode?: string;
+    source: SpecSourceResponse;
+};
+
 export type RegenerateAuthTokenResponseWritable = {
     authToken?: string;
     id: string;
@@ -3809,6 +5763,26 @@ export type RegisterCompleteResponseWritable = {
     credentialId: string;
 };
 
+export type RequestErasureRequestWritable = {
+    /**
+     * Restrict a SUBJECT erasure to one client
+     */
+    clientId?: string;
+    /**
+     * Event context keys that also hold the identifier (SUBJECT only)
+     */
+    contextKeys?: Array<string>;
+    /**
+     * Subject identifier to erase; for subjectType CLIENT, the client id
+     */
+    subject: string;
+    /**
+     * SUBJECT (default) or CLIENT
+     */
+    subjectType?: string;
+    [key: string]: unknown;
+};
+
 export type RequeueRequestWritable = {
     /**
      * Dispatch job ids to reset to PENDING for re-dispatch
@@ -3861,6 +5835,22 @@ export type RotateOAuthClientSecretResponseWritable = {
     clientSecret?: string;
 };
 
+export type RotateSigningKeyRequestWritable = {
+    /**
+     * When the new key starts signing (default now, at most 30 days ahead)
+     */
+    activeFrom?: string;
+    /**
+     * Name of the new key, sent as X-FLOWCATALYST-KEY-ID (default v<n>)
+     */
+    keyId?: string;
+    /**
+     * How long the replaced keys stay valid after activeFrom (default 86400)
+     */
+    overlapSeconds?: number;
+    [key: string]: unknown;
+};
+
 export type ScheduledJobInstanceResponseWritable = {
     clientId?: string;
     completedAt?: string;
@@ -3922,6 +5912,7 @@ export type ServiceAccountListResponseWritable = {
 
 export type ServiceAccountResponseWritable = {
     active: boolean;
+    allowedCidrs: Array<string>;
     applicationId?: string;
     authType: string;
     clientIds: Array<string>;
@@ -3970,6 +5961,84 @@ export type SetPropertyRequestWritable = {
     [key: string]: unknown;
 };
 
+export type SetTrafficSplitRequestWritable = {
+    /**
+     * Replaces the split; empty sends every message group to the endpoint
+     */
+    split: Array<SplitTargetDto>;
+    [key: string]: unknown;
+};
+
+export type ShadowReportResponseWritable = {
+    /**
+     * Deliveries both endpoints accepted or both refused
+     */
+    agreed: number;
+    /**
+     * Deliveries copied to the shadow endpoint since the given time
+     */
+    deliveries: number;
+    /**
+     * Status code combinations, most common first
+     */
+    outcomes: Array<ShadowOutcomeDto>;
+    primary: ShadowSideDto;
+    shadow: ShadowSideDto;
+    shadowEndpoint: string;
+    since: string;
+};
+
+export type SigningKeyCreatedResponseWritable = {
+    /**
+     * HMAC-SHA256 signing secret. Shown only in this response.
+     */
+    secret: string;
+    signingKey: SigningKeyResponseWritable;
+};
+
+export type SigningKeyListResponseWritable = {
+    signingKeys: Array<SigningKeyResponseWritable>;
+};
+
+export type SigningKeyResponseWritable = {
+    activeFrom: string;
+    clientId: string;
+    createdAt: string;
+    createdBy?: string;
+    expiresAt?: string;
+    id: string;
+    keyId: string;
+    signing: boolean;
+    /**
+     * PENDING, ACTIVE or EXPIRED
+     */
+    status: string;
+};
+
+export type StartArchiveReplayRequestWritable = {
+    /**
+     * Replay only this client's events
+     */
+    clientId?: string;
+    /**
+     * Event type patterns (* matches a segment); every type when omitted
+     */
+    eventTypes?: Array<string>;
+    /**
+     * Replay events ingested at or after this time
+     */
+    from: string;
+    /**
+     * Deliver the replayed events to this subscription only
+     */
+    subscriptionId?: string;
+    /**
+     * Replay events ingested before this time; must already be archived
+     */
+    to: string;
+    [key: string]: unknown;
+};
+
 export type StatusChangeRequestWritable = {
     reason: string;
     [key: string]: unknown;
@@ -3979,6 +6048,10 @@ export type StatusChangeResponseWritable = {
     message: string;
 };
 
+export type StreamDlqListResponseWritable = {
+    items: Array<StreamDlqEntryResponse>;
+};
+
 export type SubscriptionListResponseWritable = {
     subscriptions: Array<SubscriptionResponseWritable>;
     total: number;
@@ -3986,6 +6059,7 @@ export type SubscriptionListResponseWritable = {
 
 export type SubscriptionResponseWritable = {
     applicationCode?: string;
+    batching?: BatchingDto;
     clientId?: string;
     clientIdentifier?: string;
     clientScoped: boolean;
@@ -3996,22 +6070,33 @@ export type SubscriptionResponseWritable = {
     customConfig: Array<ConfigEntryDto>;
     dataOnly: boolean;
     delaySeconds: number;
+    delivery: string;
     description?: string;
     dispatchPoolCode?: string;
     dispatchPoolId?: string;
     endpoint: string;
     eventTypes: Array<EventTypeBindingDto>;
+    headers: Array<ConfigEntryDto>;
     id: string;
+    largePayloads: string;
     maxAgeSeconds: number;
     maxRetries: number;
     mode: string;
     name: string;
+    payloadFormat: string;
     queue?: string;
     sequence: number;
     serviceAccountId?: string;
+    shadowEndpoint?: string;
     source: string;
     status: string;
     timeoutSeconds: number;
+    tls?: TlsTrustDto;
+    /**
+     * Endpoints taking a share of the message groups; the endpoint keeps the rest
+     */
+    trafficSplit: Array<SplitTargetDto>;
+    transport?: string;
     updatedAt: string;
 };
 
@@ -4116,11 +6201,35 @@ export type SyncUsersResponseWritable = {
     updated: number;
 };
 
+export type TargetStatsResponseWritable = {
+    since: string;
+    /**
+     * The endpoint, then its traffic split, then any endpoint routed to earlier in the window
+     */
+    targets: Array<TargetStatsDto>;
+};
+
 export type UpdateAnchorDomainRequestWritable = {
     domain: string;
     [key: string]: unknown;
 };
 
+export type UpdateAnomalySettingRequestWritable = {
+    /**
+     * False stops the entity raising anomalies; its baseline keeps learning
+     */
+    enabled: boolean;
+    /**
+     * Z-score a window's value must reach to raise an anomaly, 2-10 (default 3). Lower is more sensitive.
+     */
+    sensitivity?: number;
+    /**
+     * Raise nothing for the entity until then
+     */
+    snoozedUntil?: string;
+    [key: string]: unknown;
+};
+
 export type UpdateApplicationRequestWritable = {
     defaultBaseUrl?: string;
     description?: string;
@@ -4152,6 +6261,11 @@ export type UpdateClientApplicationsRequestWritable = {
 
 export type UpdateClientRequestWritable = {
     name?: string;
+    /**
+     * Data-residency region; empty string clears it
+     */
+    region?: string;
+    requirePasskeyForAdmins?: boolean;
     [key: string]: unknown;
 };
 
@@ -4163,11 +6277,31 @@ export type UpdateConnectionRequestWritable = {
     [key: string]: unknown;
 };
 
+export type UpdateDataPolicyRequestWritable = {
+    /**
+     * Delete events and their dispatch jobs after this many days; absent keeps them until partition drop
+     */
+    metadataRetentionDays?: number;
+    /**
+     * Purge event data and dispatch payloads after this many days; absent keeps them until partition drop
+     */
+    payloadRetentionDays?: number;
+    /**
+     * Dotted JSON paths masked on read and debug views (arrays are walked; * matches any key)
+     */
+    redactFields?: Array<string>;
+    [key: string]: unknown;
+};
+
 export type UpdateDispatchPoolRequestWritable = {
     concurrency?: number;
     description?: string;
     name?: string;
     rateLimit?: number;
+    /**
+     * Data-residency region; empty string clears it
+     */
+    region?: string;
     [key: string]: unknown;
 };
 
@@ -4177,6 +6311,22 @@ export type UpdateEventTypeRequestWritable = {
     [key: string]: unknown;
 };
 
+export type UpdateFederationPeerRequestWritable = {
+    baseUrl: string;
+    clientId: string;
+    /**
+     * A new client secret; omit to keep the stored one
+     */
+    clientSecret?: string;
+    eventTypes: Array<string>;
+    name: string;
+    /**
+     * ACTIVE or PAUSED
+     */
+    status: string;
+    [key: string]: unknown;
+};
+
 export type UpdateIdentityProviderRequestWritable = {
     allowedEmailDomains?: Array<string>;
     name?: string;
@@ -4188,6 +6338,22 @@ export type UpdateIdentityProviderRequestWritable = {
     [key: string]: unknown;
 };
 
+export type UpdateLifecycleRequestWritable = {
+    /**
+     * true deprecates the event type; false undeprecates it and clears the sunset and hint
+     */
+    deprecated: boolean;
+    /**
+     * What producers and subscribers should move to, e.g. the replacement event type; returned with every warning and rejection
+     */
+    migrationHint?: string;
+    /**
+     * When ingestion starts rejecting the event type's events; must be in the future. Absent: no sunset
+     */
+    sunsetAt?: string;
+    [key: string]: unknown;
+};
+
 export type UpdateMappingRequestWritable = {
     additionalClientIds?: Array<string>;
     allowed2faMethods?: Array<string>;
@@ -4235,6 +6401,18 @@ export type UpdateProcessRequestWritable = {
     [key: string]: unknown;
 };
 
+export type UpdateRegistryBindingRequestWritable = {
+    /**
+     * RESOLVE (default) checks and stamps each event's schema id; VALIDATE also validates its data against the schema
+     */
+    mode?: string;
+    /**
+     * Schema registry subject the event type's payloads are registered under; absent or blank unbinds
+     */
+    subject?: string;
+    [key: string]: unknown;
+};
+
 export type UpdateRoleRequestWritable = {
     clientManaged?: boolean;
     description?: string;
@@ -4267,21 +6445,141 @@ export type UpdateServiceAccountRequestWritable = {
 };
 
 export type UpdateSubscriptionRequestWritable = {
+    batching?: BatchingDto;
     connectionId?: string;
     customConfig?: Array<ConfigEntryDto>;
     dataOnly?: boolean;
     delaySeconds?: number;
+    /**
+     * PUSH or PULL; switching to PUSH needs a valid endpoint
+     */
+    delivery?: string;
     description?: string;
     dispatchPoolId?: string;
     endpoint?: string;
     eventTypes?: Array<EventTypeBindingDto>;
+    /**
+     * Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time
+     */
+    headers?: Array<ConfigEntryDto>;
+    /**
+     * INLINE or PRESIGNED_URL
+     */
+    largePayloads?: string;
     maxAgeSeconds?: number;
     maxRetries?: number;
     mode?: string;
     name?: string;
+    /**
+     * JSON, CLOUDEVENTS_JSON, NDJSON or PROTOBUF
+     */
+    payloadFormat?: string;
     serviceAccountId?: string;
+    /**
+     * http(s) URL push deliveries are also copied to; an empty string stops shadowing
+     */
+    shadowEndpoint?: string;
     timeoutSeconds?: number;
-    [key: string]: unknown;
+    tls?: TlsTrustDto;
+    /**
+     * Named outbound transport deliveries go through; an empty string switches back to direct delivery
+     */
+    transport?: string;
+    [key: string]: unknown;
+};
+
+export type UpsertDispatchPoolRequestWritable = {
+    /**
+     * Max concurrent dispatches (default 10)
+     */
+    concurrency?: number;
+    description?: string;
+    name: string;
+    /**
+     * Messages per minute (nil = no rate limit)
+     */
+    rateLimit?: number;
+    [key: string]: unknown;
+};
+
+export type UpsertEventTypeRequestWritable = {
+    description?: string;
+    /**
+     * Human-readable event type name
+     */
+    name: string;
+    /**
+     * Optional JSON Schema for the initial spec version; used only on create
+     */
+    schema?: unknown;
+    [key: string]: unknown;
+};
+
+export type UpsertServiceAccountRequestWritable = {
+    applicationId?: string;
+    clientIds?: Array<string>;
+    description?: string;
+    name: string;
+    scope?: string;
+    webhookCredentials?: WebhookCredentialsDto;
+    [key: string]: unknown;
+};
+
+export type UpsertServiceAccountResponseWritable = {
+    oauth?: ServiceAccountOAuthSecrets;
+    principalId?: string;
+    serviceAccount: ServiceAccountResponseWritable;
+    webhook?: ServiceAccountWebhookSecrets;
+};
+
+export type UpsertSubscriptionRequestWritable = {
+    batching?: BatchingDto;
+    connectionId?: string;
+    customConfig?: Array<ConfigEntryDto>;
+    dataOnly?: boolean;
+    delaySeconds?: number;
+    /**
+     * PUSH (default) POSTs each dispatch job to the endpoint; PULL holds them for the consumer to fetch from GET /api/consume/{subscriptionId}
+     */
+    delivery?: string;
+    description?: string;
+    dispatchPoolId?: string;
+    /**
+     * http(s) URL delivery target; required unless delivery is PULL
+     */
+    endpoint?: string;
+    eventTypes?: Array<EventTypeBindingDto>;
+    /**
+     * Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time
+     */
+    headers?: Array<ConfigEntryDto>;
+    /**
+     * How a payload offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES is delivered: INLINE (default) rehydrates it; PRESIGNED_URL sends its $blobRef reference with a pre-signed url to fetch it from
+     */
+    largePayloads?: string;
+    maxAgeSeconds?: number;
+    maxRetries?: number;
+    /**
+     * Dispatch mode (IMMEDIATE, NEXT_ON_ERROR, BLOCK_ON_ERROR)
+     */
+    mode?: string;
+    name: string;
+    /**
+     * How deliveries are serialized: JSON (default), CLOUDEVENTS_JSON, NDJSON or PROTOBUF (transcoded with the event type's current PROTO schema). Pull consumers always receive JSON
+     */
+    payloadFormat?: string;
+    serviceAccountId?: string;
+    /**
+     * http(s) URL each push delivery is also copied to, for comparing a new endpoint before promoting it; its answers never affect the delivery
+     */
+    shadowEndpoint?: string;
+    timeoutSeconds?: number;
+    tls?: TlsTrustDto;
+    /**
+     * Named outbound transport (SSH bastion or SOCKS5 proxy, see FC_OUTBOUND_TRANSPORTS) deliveries go through; omit to deliver directly
+     */
+    transport?: string;
+    [key: string]: unknown;
 };
 
 export type WebauthnAuthenticateCompleteResponseWritable = {
@@ -4405,6 +6703,99 @@ export type UpdateAnchorDomainResponses = {
 
 export type UpdateAnchorDomainResponse = UpdateAnchorDomainResponses[keyof UpdateAnchorDomainResponses];
 
+export type ListAnomaliesData = {
+    body?: never;
+    path?: never;
+    query?: {
+        /**
+         * OPEN (default) or RESOLVED
+         */
+        status?: string;
+        /**
+         * Maximum anomalies to return (default 100, max 500)
+         */
+        limit?: number;
+    };
+    url: '/api/anomalies';
+};
+
+export type ListAnomaliesErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListAnomaliesError = ListAnomaliesErrors[keyof ListAnomaliesErrors];
+
+export type ListAnomaliesResponses = {
+    /**
+     * OK
+     */
+    200: AnomalyListResponse;
+};
+
+export type ListAnomaliesResponse = ListAnomaliesResponses[keyof ListAnomaliesResponses];
+
+export type ListAnomalySettingsData = {
+    body?: never;
+    path?: never;
+    query?: never;
+    url: '/api/anomaly-settings';
+};
+
+export type ListAnomalySettingsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListAnomalySettingsError = ListAnomalySettingsErrors[keyof ListAnomalySettingsErrors];
+
+export type ListAnomalySettingsResponses = {
+    /**
+     * OK
+     */
+    200: AnomalySettingListResponse;
+};
+
+export type ListAnomalySettingsResponse = ListAnomalySettingsResponses[keyof ListAnomalySettingsResponses];
+
+export type UpdateAnomalySettingData = {
+    body: UpdateAnomalySettingRequestWritable;
+    path: {
+        /**
+         * EVENT_TYPE or SUBSCRIPTION
+         */
+        entityKind: string;
+        /**
+         * Event type code or subscription id
+         */
+        entityKey: string;
+    };
+    query?: never;
+    url: '/api/anomaly-settings/{entityKind}/{entityKey}';
+};
+
+export type UpdateAnomalySettingErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpdateAnomalySettingError = UpdateAnomalySettingErrors[keyof UpdateAnomalySettingErrors];
+
+export type UpdateAnomalySettingResponses = {
+    /**
+     * OK
+     */
+    200: AnomalySettingResponse;
+};
+
+export type UpdateAnomalySettingResponse = UpdateAnomalySettingResponses[keyof UpdateAnomalySettingResponses];
+
 export type ListApplicationsData = {
     body?: never;
     path?: never;
@@ -4512,6 +6903,31 @@ export type ListApplicationRolesResponses = {
 
 export type ListApplicationRolesResponse = ListApplicationRolesResponses[keyof ListApplicationRolesResponses];
 
+export type GetProducersReportData = {
+    body?: never;
+    path?: never;
+    query?: never;
+    url: '/api/applications/producers';
+};
+
+export type GetProducersReportErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetProducersReportError = GetProducersReportErrors[keyof GetProducersReportErrors];
+
+export type GetProducersReportResponses = {
+    /**
+     * OK
+     */
+    200: ProducersReportResponse;
+};
+
+export type GetProducersReportResponse = GetProducersReportResponses[keyof GetProducersReportResponses];
+
 export type SyncDispatchPoolsData = {
     body: SyncDispatchPoolsRequestWritable;
     path: {
@@ -5028,6 +7444,88 @@ export type DeactivateApplicationResponses = {
 
 export type DeactivateApplicationResponse = DeactivateApplicationResponses[keyof DeactivateApplicationResponses];
 
+export type ListApplicationProducerBindingsData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/applications/{id}/producers';
+};
+
+export type ListApplicationProducerBindingsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListApplicationProducerBindingsError = ListApplicationProducerBindingsErrors[keyof ListApplicationProducerBindingsErrors];
+
+export type ListApplicationProducerBindingsResponses = {
+    /**
+     * OK
+     */
+    200: ProducerBindingListResponse;
+};
+
+export type ListApplicationProducerBindingsResponse = ListApplicationProducerBindingsResponses[keyof ListApplicationProducerBindingsResponses];
+
+export type AddApplicationProducerBindingData = {
+    body: AddProducerBindingRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/applications/{id}/producers';
+};
+
+export type AddApplicationProducerBindingErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type AddApplicationProducerBindingError = AddApplicationProducerBindingErrors[keyof AddApplicationProducerBindingErrors];
+
+export type AddApplicationProducerBindingResponses = {
+    /**
+     * Created
+     */
+    201: CreatedResponse;
+};
+
+export type AddApplicationProducerBindingResponse = AddApplicationProducerBindingResponses[keyof AddApplicationProducerBindingResponses];
+
+export type RemoveApplicationProducerBindingData = {
+    body?: never;
+    path: {
+        id: string;
+        bindingId: string;
+    };
+    query?: never;
+    url: '/api/applications/{id}/producers/{bindingId}';
+};
+
+export type RemoveApplicationProducerBindingErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type RemoveApplicationProducerBindingError = RemoveApplicationProducerBindingErrors[keyof RemoveApplicationProducerBindingErrors];
+
+export type RemoveApplicationProducerBindingResponses = {
+    /**
+     * No Content
+     */
+    204: void;
+};
+
+export type RemoveApplicationProducerBindingResponse = RemoveApplicationProducerBindingResponses[keyof RemoveApplicationProducerBindingResponses];
+
 export type ProvisionApplicationLoginClientData = {
     body: ProvisionLoginClientRequestWritable;
     path: {
@@ -5109,94 +7607,274 @@ export type AttachApplicationServiceAccountResponses = {
 
 export type AttachApplicationServiceAccountResponse = AttachApplicationServiceAccountResponses[keyof AttachApplicationServiceAccountResponses];
 
-export type ListAuditLogsData = {
+export type ListArchiveFilesData = {
     body?: never;
     path?: never;
     query?: {
         /**
-         * Opaque cursor from a previous page's nextCursor
+         * EVENTS or DELIVERIES; both when omitted
          */
-        after?: string;
+        kind?: string;
         /**
-         * Page size (default 50, capped at 200)
+         * RFC3339; objects whose partition hour is at or after it
          */
-        pageSize?: number;
-        entityType?: string;
-        entityId?: string;
-        principalId?: string;
-        operation?: string;
+        since?: string;
         /**
-         * CSV of application ids
+         * RFC3339; objects whose partition hour is before it
          */
-        applicationIds?: string;
+        until?: string;
         /**
-         * CSV of client ids
+         * Opaque cursor from a previous page
          */
-        clientIds?: string;
+        after?: string;
+        /**
+         * Maximum objects to return (default 500, max 5000)
+         */
+        limit?: number;
     };
-    url: '/api/audit-logs';
+    url: '/api/archive/files';
 };
 
-export type ListAuditLogsErrors = {
+export type ListArchiveFilesErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type ListAuditLogsError = ListAuditLogsErrors[keyof ListAuditLogsErrors];
+export type ListArchiveFilesError = ListArchiveFilesErrors[keyof ListArchiveFilesErrors];
 
-export type ListAuditLogsResponses = {
+export type ListArchiveFilesResponses = {
     /**
      * OK
      */
-    200: AuditLogListResponse;
+    200: ArchiveManifestResponse;
 };
 
-export type ListAuditLogsResponse = ListAuditLogsResponses[keyof ListAuditLogsResponses];
+export type ListArchiveFilesResponse = ListArchiveFilesResponses[keyof ListArchiveFilesResponses];
 
-export type AuditLogApplicationIdsData = {
+export type GetArchiveStatusData = {
     body?: never;
     path?: never;
     query?: never;
-    url: '/api/audit-logs/application-ids';
+    url: '/api/archive/status';
 };
 
-export type AuditLogApplicationIdsErrors = {
+export type GetArchiveStatusErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type AuditLogApplicationIdsError = AuditLogApplicationIdsErrors[keyof AuditLogApplicationIdsErrors];
+export type GetArchiveStatusError = GetArchiveStatusErrors[keyof GetArchiveStatusErrors];
 
-export type AuditLogApplicationIdsResponses = {
+export type GetArchiveStatusResponses = {
     /**
      * OK
      */
-    200: AuditLogApplicationIdsResponse;
+    200: ArchiveStatusResponse;
 };
 
-export type AuditLogApplicationIdsResponse2 = AuditLogApplicationIdsResponses[keyof AuditLogApplicationIdsResponses];
+export type GetArchiveStatusResponse = GetArchiveStatusResponses[keyof GetArchiveStatusResponses];
 
-export type AuditLogClientIdsData = {
-    body?: never;
+export type StartArchiveReplayData = {
+    body: StartArchiveReplayRequestWritable;
     path?: never;
     query?: never;
-    url: '/api/audit-logs/client-ids';
+    url: '/api/archive/replays';
 };
 
-export type AuditLogClientIdsErrors = {
+export type StartArchiveReplayErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type AuditLogClientIdsError = AuditLogClientIdsErrors[keyof AuditLogClientIdsErrors];
+export type StartArchiveReplayError = StartArchiveReplayErrors[keyof StartArchiveReplayErrors];
 
-export type AuditLogClientIdsResponses = {
+export type StartArchiveReplayResponses = {
+    /**
+     * Accepted
+     */
+    202: ArchiveReplayResponse;
+};
+
+export type StartArchiveReplayResponse = StartArchiveReplayResponses[keyof StartArchiveReplayResponses];
+
+export type ListArchiveReplaysData = {
+    body?: never;
+    path?: never;
+    query?: {
+        /**
+         * Maximum replays to return (default 50, max 200)
+         */
+        limit?: number;
+    };
+    url: '/api/archive/replays';
+};
+
+export type ListArchiveReplaysErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListArchiveReplaysError = ListArchiveReplaysErrors[keyof ListArchiveReplaysErrors];
+
+export type ListArchiveReplaysResponses = {
+    /**
+     * OK
+     */
+    200: ArchiveReplayListResponse;
+};
+
+export type ListArchiveReplaysResponse = ListArchiveReplaysResponses[keyof ListArchiveReplaysResponses];
+
+export type GetArchiveReplayData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/archive/replays/{id}';
+};
+
+export type GetArchiveReplayErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetArchiveReplayError = GetArchiveReplayErrors[keyof GetArchiveReplayErrors];
+
+export type GetArchiveReplayResponses = {
+    /**
+     * OK
+     */
+    200: ArchiveReplayResponse;
+};
+
+export type GetArchiveReplayResponse = GetArchiveReplayResponses[keyof GetArchiveReplayResponses];
+
+export type CancelArchiveReplayData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/archive/replays/{id}/cancel';
+};
+
+export type CancelArchiveReplayErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type CancelArchiveReplayError = CancelArchiveReplayErrors[keyof CancelArchiveReplayErrors];
+
+export type CancelArchiveReplayResponses = {
+    /**
+     * OK
+     */
+    200: ArchiveReplayResponse;
+};
+
+export type CancelArchiveReplayResponse = CancelArchiveReplayResponses[keyof CancelArchiveReplayResponses];
+
+export type ListAuditLogsData = {
+    body?: never;
+    path?: never;
+    query?: {
+        /**
+         * Opaque cursor from a previous page's nextCursor
+         */
+        after?: string;
+        /**
+         * Page size (default 50, capped at 200)
+         */
+        pageSize?: number;
+        entityType?: string;
+        entityId?: string;
+        principalId?: string;
+        operation?: string;
+        /**
+         * CSV of application ids
+         */
+        applicationIds?: string;
+        /**
+         * CSV of client ids
+         */
+        clientIds?: string;
+    };
+    url: '/api/audit-logs';
+};
+
+export type ListAuditLogsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListAuditLogsError = ListAuditLogsErrors[keyof ListAuditLogsErrors];
+
+export type ListAuditLogsResponses = {
+    /**
+     * OK
+     */
+    200: AuditLogListResponse;
+};
+
+export type ListAuditLogsResponse = ListAuditLogsResponses[keyof ListAuditLogsResponses];
+
+export type AuditLogApplicationIdsData = {
+    body?: never;
+    path?: never;
+    query?: never;
+    url: '/api/audit-logs/application-ids';
+};
+
+export type AuditLogApplicationIdsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type AuditLogApplicationIdsError = AuditLogApplicationIdsErrors[keyof AuditLogApplicationIdsErrors];
+
+export type AuditLogApplicationIdsResponses = {
+    /**
+     * OK
+     */
+    200: AuditLogApplicationIdsResponse;
+};
+
+export type AuditLogApplicationIdsResponse2 = AuditLogApplicationIdsResponses[keyof AuditLogApplicationIdsResponses];
+
+export type AuditLogClientIdsData = {
+    body?: never;
+    path?: never;
+    query?: never;
+    url: '/api/audit-logs/client-ids';
+};
+
+export type AuditLogClientIdsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type AuditLogClientIdsError = AuditLogClientIdsErrors[keyof AuditLogClientIdsErrors];
+
+export type AuditLogClientIdsResponses = {
     /**
      * OK
      */
@@ -5888,6 +8566,88 @@ export type AddClientNoteResponses = {
 
 export type AddClientNoteResponse = AddClientNoteResponses[keyof AddClientNoteResponses];
 
+export type ListClientSigningKeysData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/clients/{id}/signing-keys';
+};
+
+export type ListClientSigningKeysErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListClientSigningKeysError = ListClientSigningKeysErrors[keyof ListClientSigningKeysErrors];
+
+export type ListClientSigningKeysResponses = {
+    /**
+     * OK
+     */
+    200: SigningKeyListResponse;
+};
+
+export type ListClientSigningKeysResponse = ListClientSigningKeysResponses[keyof ListClientSigningKeysResponses];
+
+export type RotateClientSigningKeyData = {
+    body?: RotateSigningKeyRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/clients/{id}/signing-keys';
+};
+
+export type RotateClientSigningKeyErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type RotateClientSigningKeyError = RotateClientSigningKeyErrors[keyof RotateClientSigningKeyErrors];
+
+export type RotateClientSigningKeyResponses = {
+    /**
+     * Created
+     */
+    201: SigningKeyCreatedResponse;
+};
+
+export type RotateClientSigningKeyResponse = RotateClientSigningKeyResponses[keyof RotateClientSigningKeyResponses];
+
+export type ExpireClientSigningKeyData = {
+    body?: ExpireSigningKeyRequestWritable;
+    path: {
+        id: string;
+        signingKeyId: string;
+    };
+    query?: never;
+    url: '/api/clients/{id}/signing-keys/{signingKeyId}/expire';
+};
+
+export type ExpireClientSigningKeyErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ExpireClientSigningKeyError = ExpireClientSigningKeyErrors[keyof ExpireClientSigningKeyErrors];
+
+export type ExpireClientSigningKeyResponses = {
+    /**
+     * OK
+     */
+    200: SigningKeyResponse;
+};
+
+export type ExpireClientSigningKeyResponse = ExpireClientSigningKeyResponses[keyof ExpireClientSigningKeyResponses];
+
 export type SuspendClientData = {
     body: SuspendClientRequestWritable;
     path: {
@@ -6196,57 +8956,159 @@ export type PauseConnectionResponses = {
 
 export type PauseConnectionResponse = PauseConnectionResponses[keyof PauseConnectionResponses];
 
-export type ListDispatchJobsData = {
+export type ConsumeSubscriptionData = {
     body?: never;
-    path?: never;
+    path: {
+        subscriptionId: string;
+    };
     query?: {
-        status?: string;
-        clientId?: string;
-        dispatchPoolId?: string;
-        subscriptionId?: string;
-        code?: string;
-        /**
-         * RFC3339 timestamp
-         */
-        since?: string;
-        /**
-         * RFC3339 timestamp
-         */
-        until?: string;
-        limit?: number;
-        offset?: number;
-        /**
-         * Max rows (default 50, max 1000)
-         */
-        size?: number;
-        /**
-         * CSV of client ids
-         */
-        clientIds?: string;
-        /**
-         * CSV of statuses
-         */
-        statuses?: string;
-        /**
-         * CSV of application codes
-         */
-        applications?: string;
         /**
-         * CSV of subdomains
+         * How long to wait when no message is due, e.g. 30s (at most 1m). Default: answer at once
          */
-        subdomains?: string;
+        wait?: string;
         /**
-         * CSV of aggregates
+         * Most messages to lease (default 10, max 100)
          */
-        aggregates?: string;
+        max?: number;
         /**
-         * CSV of codes
+         * How long the messages stay leased before they count as failed, e.g. 5m (default 1m, at most 24h)
          */
-        codes?: string;
-        /**
+        lease?: string;
+    };
+    url: '/api/consume/{subscriptionId}';
+};
+
+export type ConsumeSubscriptionErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ConsumeSubscriptionError = ConsumeSubscriptionErrors[keyof ConsumeSubscriptionErrors];
+
+export type ConsumeSubscriptionResponses = {
+    /**
+     * OK
+     */
+    200: ConsumeResponse;
+};
+
+export type ConsumeSubscriptionResponse = ConsumeSubscriptionResponses[keyof ConsumeSubscriptionResponses];
+
+export type AckConsumedMessageData = {
+    body: ConsumeAckRequestWritable;
+    path: {
+        subscriptionId: string;
+    };
+    query?: never;
+    url: '/api/consume/{subscriptionId}/ack';
+};
+
+export type AckConsumedMessageErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type AckConsumedMessageError = AckConsumedMessageErrors[keyof AckConsumedMessageErrors];
+
+export type AckConsumedMessageResponses = {
+    /**
+     * OK
+     */
+    200: AckResponse;
+};
+
+export type AckConsumedMessageResponse = AckConsumedMessageResponses[keyof AckConsumedMessageResponses];
+
+export type NackConsumedMessageData = {
+    body: ConsumeNackRequestWritable;
+    path: {
+        subscriptionId: string;
+    };
+    query?: never;
+    url: '/api/consume/{subscriptionId}/nack';
+};
+
+export type NackConsumedMessageErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type NackConsumedMessageError = NackConsumedMessageErrors[keyof NackConsumedMessageErrors];
+
+export type NackConsumedMessageResponses = {
+    /**
+     * OK
+     */
+    200: AckResponse;
+};
+
+export type NackConsumedMessageResponse = NackConsumedMessageResponses[keyof NackConsumedMessageResponses];
+
+export type ListDispatchJobsData = {
+    body?: never;
+    path?: never;
+    query?: {
+        status?: string;
+        clientId?: string;
+        dispatchPoolId?: string;
+        subscriptionId?: string;
+        code?: string;
+        /**
+         * RFC3339 timestamp
+         */
+        since?: string;
+        /**
+         * RFC3339 timestamp
+         */
+        until?: string;
+        limit?: number;
+        offset?: number;
+        /**
+         * Max rows (default 50, max 1000)
+         */
+        size?: number;
+        /**
+         * CSV of client ids
+         */
+        clientIds?: string;
+        /**
+         * CSV of statuses
+         */
+        statuses?: string;
+        /**
+         * CSV of application codes
+         */
+        applications?: string;
+        /**
+         * CSV of subdomains
+         */
+        subdomains?: string;
+        /**
+         * CSV of aggregates
+         */
+        aggregates?: string;
+        /**
+         * CSV of codes
+         */
+        codes?: string;
+        /**
          * Free-text source filter
          */
         source?: string;
+        /**
+         * Opaque cursor from the previous page's X-Next-Cursor header
+         */
+        after?: string;
+        /**
+         * cursor (default) or offset (legacy limit/offset paging)
+         */
+        pagination?: string;
     };
     url: '/api/dispatch-jobs';
 };
@@ -6296,6 +9158,31 @@ export type DispatchJobsByEventAliasResponses = {
 
 export type DispatchJobsByEventAliasResponse = DispatchJobsByEventAliasResponses[keyof DispatchJobsByEventAliasResponses];
 
+export type CancelDispatchJobsData = {
+    body: CancelRequestWritable;
+    path?: never;
+    query?: never;
+    url: '/api/dispatch-jobs/cancel';
+};
+
+export type CancelDispatchJobsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type CancelDispatchJobsError = CancelDispatchJobsErrors[keyof CancelDispatchJobsErrors];
+
+export type CancelDispatchJobsResponses = {
+    /**
+     * OK
+     */
+    200: CancelResponse;
+};
+
+export type CancelDispatchJobsResponse = CancelDispatchJobsResponses[keyof CancelDispatchJobsResponses];
+
 export type DispatchJobsByEventData = {
     body?: never;
     path: {
@@ -6399,6 +9286,14 @@ export type ListDispatchJobsRawData = {
          * Free-text source filter
          */
         source?: string;
+        /**
+         * Opaque cursor from the previous page's X-Next-Cursor header
+         */
+        after?: string;
+        /**
+         * cursor (default) or offset (legacy limit/offset paging)
+         */
+        pagination?: string;
     };
     url: '/api/dispatch-jobs/list-raw';
 };
@@ -6472,6 +9367,14 @@ export type ListDispatchJobsRawAliasData = {
          * Free-text source filter
          */
         source?: string;
+        /**
+         * Opaque cursor from the previous page's X-Next-Cursor header
+         */
+        after?: string;
+        /**
+         * cursor (default) or offset (legacy limit/offset paging)
+         */
+        pagination?: string;
     };
     url: '/api/dispatch-jobs/raw';
 };
@@ -6546,6 +9449,33 @@ export type GetDispatchJobResponses = {
 
 export type GetDispatchJobResponse = GetDispatchJobResponses[keyof GetDispatchJobResponses];
 
+export type AckDispatchJobData = {
+    body: AckRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/dispatch-jobs/{id}/ack';
+};
+
+export type AckDispatchJobErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type AckDispatchJobError = AckDispatchJobErrors[keyof AckDispatchJobErrors];
+
+export type AckDispatchJobResponses = {
+    /**
+     * OK
+     */
+    200: AckResponse;
+};
+
+export type AckDispatchJobResponse = AckDispatchJobResponses[keyof AckDispatchJobResponses];
+
 export type ListDispatchJobAttemptsData = {
     body?: never;
     path: {
@@ -6573,6 +9503,33 @@ export type ListDispatchJobAttemptsResponses = {
 
 export type ListDispatchJobAttemptsResponse = ListDispatchJobAttemptsResponses[keyof ListDispatchJobAttemptsResponses];
 
+export type NackDispatchJobData = {
+    body: NackRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/dispatch-jobs/{id}/nack';
+};
+
+export type NackDispatchJobErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type NackDispatchJobError = NackDispatchJobErrors[keyof NackDispatchJobErrors];
+
+export type NackDispatchJobResponses = {
+    /**
+     * OK
+     */
+    200: AckResponse;
+};
+
+export type NackDispatchJobResponse = NackDispatchJobResponses[keyof NackDispatchJobResponses];
+
 export type GetDispatchJobRawData = {
     body?: never;
     path: {
@@ -6636,6 +9593,12 @@ export type ListDispatchPoolsResponse = ListDispatchPoolsResponses[keyof ListDis
 
 export type CreateDispatchPoolData = {
     body: CreateDispatchPoolRequestWritable;
+    headers?: {
+        /**
+         * Client-chosen key that makes retries of this create return the first response instead of creating again
+         */
+        'Idempotency-Key'?: string;
+    };
     path?: never;
     query?: never;
     url: '/api/dispatch-pools';
@@ -6659,8 +9622,59 @@ export type CreateDispatchPoolResponses = {
 
 export type CreateDispatchPoolResponse = CreateDispatchPoolResponses[keyof CreateDispatchPoolResponses];
 
+export type UpsertDispatchPoolData = {
+    body: UpsertDispatchPoolRequestWritable;
+    headers?: {
+        /**
+         * Update only if the pool exists with this ETag
+         */
+        'If-Match'?: string;
+        /**
+         * Send * to create only, failing if the code is taken
+         */
+        'If-None-Match'?: string;
+    };
+    path: {
+        /**
+         * Pool code
+         */
+        code: string;
+    };
+    query?: {
+        /**
+         * Client the pool belongs to; absent means anchor-level
+         */
+        clientId?: string;
+    };
+    url: '/api/dispatch-pools/by-code/{code}';
+};
+
+export type UpsertDispatchPoolErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpsertDispatchPoolError = UpsertDispatchPoolErrors[keyof UpsertDispatchPoolErrors];
+
+export type UpsertDispatchPoolResponses = {
+    /**
+     * OK
+     */
+    200: DispatchPoolResponse;
+};
+
+export type UpsertDispatchPoolResponse = UpsertDispatchPoolResponses[keyof UpsertDispatchPoolResponses];
+
 export type DeleteDispatchPoolData = {
     body?: never;
+    headers?: {
+        /**
+         * Proceed only if the resource's current ETag matches
+         */
+        'If-Match'?: string;
+    };
     path: {
         id: string;
     };
@@ -6715,6 +9729,12 @@ export type GetDispatchPoolResponse = GetDispatchPoolResponses[keyof GetDispatch
 
 export type UpdateDispatchPoolData = {
     body: UpdateDispatchPoolRequestWritable;
+    headers?: {
+        /**
+         * Proceed only if the pool's current ETag matches
+         */
+        'If-Match'?: string;
+    };
     path: {
         id: string;
     };
@@ -7058,6 +10078,12 @@ export type ListEventTypesResponse = ListEventTypesResponses[keyof ListEventType
 
 export type CreateEventTypeData = {
     body: CreateEventTypeRequestWritable;
+    headers?: {
+        /**
+         * Client-chosen key that makes retries of this create return the first response instead of creating again
+         */
+        'Idempotency-Key'?: string;
+    };
     path?: never;
     query?: never;
     url: '/api/event-types';
@@ -7111,8 +10137,59 @@ export type GetEventTypeByCodeResponses = {
 
 export type GetEventTypeByCodeResponse = GetEventTypeByCodeResponses[keyof GetEventTypeByCodeResponses];
 
+export type UpsertEventTypeData = {
+    body: UpsertEventTypeRequestWritable;
+    headers?: {
+        /**
+         * Update only if the event type exists with this ETag
+         */
+        'If-Match'?: string;
+        /**
+         * Send * to create only, failing if the code is taken
+         */
+        'If-None-Match'?: string;
+    };
+    path: {
+        /**
+         * Event type code (e.g. platform:iam:user:created)
+         */
+        code: string;
+    };
+    query?: {
+        /**
+         * Client scope; absent means anchor-level. Fixed at creation
+         */
+        clientId?: string;
+    };
+    url: '/api/event-types/by-code/{code}';
+};
+
+export type UpsertEventTypeErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpsertEventTypeError = UpsertEventTypeErrors[keyof UpsertEventTypeErrors];
+
+export type UpsertEventTypeResponses = {
+    /**
+     * OK
+     */
+    200: EventTypeResponse;
+};
+
+export type UpsertEventTypeResponse = UpsertEventTypeResponses[keyof UpsertEventTypeResponses];
+
 export type DeleteEventTypeData = {
     body?: never;
+    headers?: {
+        /**
+         * Proceed only if the resource's current ETag matches
+         */
+        'If-Match'?: string;
+    };
     path: {
         id: string;
     };
@@ -7170,6 +10247,12 @@ export type GetEventTypeResponse = GetEventTypeResponses[keyof GetEventTypeRespo
 
 export type UpdateEventTypeData = {
     body: UpdateEventTypeRequestWritable;
+    headers?: {
+        /**
+         * Proceed only if the event type's current ETag matches
+         */
+        'If-Match'?: string;
+    };
     path: {
         id: string;
     };
@@ -7195,16 +10278,97 @@ export type UpdateEventTypeResponses = {
 
 export type UpdateEventTypeResponse = UpdateEventTypeResponses[keyof UpdateEventTypeResponses];
 
-export type AddEventTypeSchemaData = {
-    body: AddSchemaRequestWritable;
+export type UpdateEventTypeDataPolicyData = {
+    body: UpdateDataPolicyRequestWritable;
     path: {
         id: string;
     };
     query?: never;
-    url: '/api/event-types/{id}/schemas';
+    url: '/api/event-types/{id}/data-policy';
 };
 
-export type AddEventTypeSchemaErrors = {
+export type UpdateEventTypeDataPolicyErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpdateEventTypeDataPolicyError = UpdateEventTypeDataPolicyErrors[keyof UpdateEventTypeDataPolicyErrors];
+
+export type UpdateEventTypeDataPolicyResponses = {
+    /**
+     * No Content
+     */
+    204: void;
+};
+
+export type UpdateEventTypeDataPolicyResponse = UpdateEventTypeDataPolicyResponses[keyof UpdateEventTypeDataPolicyResponses];
+
+export type UpdateEventTypeRegistryBindingData = {
+    body: UpdateRegistryBindingRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/event-types/{id}/schema-registry';
+};
+
+export type UpdateEventTypeRegistryBindingErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpdateEventTypeRegistryBindingError = UpdateEventTypeRegistryBindingErrors[keyof UpdateEventTypeRegistryBindingErrors];
+
+export type UpdateEventTypeRegistryBindingResponses = {
+    /**
+     * No Content
+     */
+    204: void;
+};
+
+export type UpdateEventTypeRegistryBindingResponse = UpdateEventTypeRegistryBindingResponses[keyof UpdateEventTypeRegistryBindingResponses];
+
+export type UpdateEventTypeLifecycleData = {
+    body: UpdateLifecycleRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/event-types/{id}/lifecycle';
+};
+
+export type UpdateEventTypeLifecycleErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpdateEventTypeLifecycleError = UpdateEventTypeLifecycleErrors[keyof UpdateEventTypeLifecycleErrors];
+
+export type UpdateEventTypeLifecycleResponses = {
+    /**
+     * No Content
+     */
+    204: void;
+};
+
+export type UpdateEventTypeLifecycleResponse = UpdateEventTypeLifecycleResponses[keyof UpdateEventTypeLifecycleResponses];
+
+export type AddEventTypeSchemaData = {
+    body: AddSchemaRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/event-types/{id}/schemas';
+};
+
+export type AddEventTypeSchemaErrors = {
     /**
      * Error
      */
@@ -7293,6 +10457,14 @@ export type ListEventsData = {
          * CSV of event types
          */
         types?: string;
+        /**
+         * Opaque cursor from the previous page's X-Next-Cursor header
+         */
+        after?: string;
+        /**
+         * cursor (default) or offset (legacy limit/offset paging)
+         */
+        pagination?: string;
     };
     url: '/api/events';
 };
@@ -7434,6 +10606,14 @@ export type ListEventsRawData = {
          * CSV of event types
          */
         types?: string;
+        /**
+         * Opaque cursor from the previous page's X-Next-Cursor header
+         */
+        after?: string;
+        /**
+         * cursor (default) or offset (legacy limit/offset paging)
+         */
+        pagination?: string;
     };
     url: '/api/events/list-raw';
 };
@@ -7500,6 +10680,14 @@ export type ListEventsRawAliasData = {
          * CSV of event types
          */
         types?: string;
+        /**
+         * Opaque cursor from the previous page's X-Next-Cursor header
+         */
+        after?: string;
+        /**
+         * cursor (default) or offset (legacy limit/offset paging)
+         */
+        pagination?: string;
     };
     url: '/api/events/raw';
 };
@@ -7549,6 +10737,175 @@ export type GetEventResponses = {
 
 export type GetEventResponse = GetEventResponses[keyof GetEventResponses];
 
+export type GetFederationFeedData = {
+    body?: never;
+    path?: never;
+    query?: {
+        /**
+         * Comma-separated event type codes
+         */
+        types?: string;
+        /**
+         * Opaque cursor from a previous page
+         */
+        after?: string;
+        /**
+         * Maximum events to return (default 100, max 500)
+         */
+        limit?: number;
+    };
+    url: '/api/federation/feed';
+};
+
+export type GetFederationFeedErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetFederationFeedError = GetFederationFeedErrors[keyof GetFederationFeedErrors];
+
+export type GetFederationFeedResponses = {
+    /**
+     * OK
+     */
+    200: FederationFeedResponse;
+};
+
+export type GetFederationFeedResponse = GetFederationFeedResponses[keyof GetFederationFeedResponses];
+
+export type ListFederationPeersData = {
+    body?: never;
+    path?: never;
+    query?: never;
+    url: '/api/federation/peers';
+};
+
+export type ListFederationPeersErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListFederationPeersError = ListFederationPeersErrors[keyof ListFederationPeersErrors];
+
+export type ListFederationPeersResponses = {
+    /**
+     * OK
+     */
+    200: FederationPeerListResponse;
+};
+
+export type ListFederationPeersResponse = ListFederationPeersResponses[keyof ListFederationPeersResponses];
+
+export type CreateFederationPeerData = {
+    body: CreateFederationPeerRequestWritable;
+    path?: never;
+    query?: never;
+    url: '/api/federation/peers';
+};
+
+export type CreateFederationPeerErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type CreateFederationPeerError = CreateFederationPeerErrors[keyof CreateFederationPeerErrors];
+
+export type CreateFederationPeerResponses = {
+    /**
+     * Created
+     */
+    201: FederationPeerResponse;
+};
+
+export type CreateFederationPeerResponse = CreateFederationPeerResponses[keyof CreateFederationPeerResponses];
+
+export type DeleteFederationPeerData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/federation/peers/{id}';
+};
+
+export type DeleteFederationPeerErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type DeleteFederationPeerError = DeleteFederationPeerErrors[keyof DeleteFederationPeerErrors];
+
+export type DeleteFederationPeerResponses = {
+    /**
+     * No Content
+     */
+    204: void;
+};
+
+export type DeleteFederationPeerResponse = DeleteFederationPeerResponses[keyof DeleteFederationPeerResponses];
+
+export type GetFederationPeerData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/federation/peers/{id}';
+};
+
+export type GetFederationPeerErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetFederationPeerError = GetFederationPeerErrors[keyof GetFederationPeerErrors];
+
+export type GetFederationPeerResponses = {
+    /**
+     * OK
+     */
+    200: FederationPeerResponse;
+};
+
+export type GetFederationPeerResponse = GetFederationPeerResponses[keyof GetFederationPeerResponses];
+
+export type UpdateFederationPeerData = {
+    body: UpdateFederationPeerRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/federation/peers/{id}';
+};
+
+export type UpdateFederationPeerErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpdateFederationPeerError = UpdateFederationPeerErrors[keyof UpdateFederationPeerErrors];
+
+export type UpdateFederationPeerResponses = {
+    /**
+     * OK
+     */
+    200: FederationPeerResponse;
+};
+
+export type UpdateFederationPeerResponse = UpdateFederationPeerResponses[keyof UpdateFederationPeerResponses];
+
 export type ListIdentityProvidersData = {
     body?: never;
     path?: never;
@@ -7757,113 +11114,246 @@ export type DeleteIdpRoleMappingResponses = {
 
 export type DeleteIdpRoleMappingResponse = DeleteIdpRoleMappingResponses[keyof DeleteIdpRoleMappingResponses];
 
-export type ListLoginAttemptsData = {
+export type ListKillSwitchesData = {
     body?: never;
     path?: never;
-    query?: {
-        attemptType?: string;
-        outcome?: string;
-        identifier?: string;
-        principalId?: string;
-        dateFrom?: string;
-        dateTo?: string;
-        after?: string;
-        pageSize?: number;
-    };
-    url: '/api/login-attempts';
+    query?: never;
+    url: '/api/kill-switches';
 };
 
-export type ListLoginAttemptsErrors = {
+export type ListKillSwitchesErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type ListLoginAttemptsError = ListLoginAttemptsErrors[keyof ListLoginAttemptsErrors];
+export type ListKillSwitchesError = ListKillSwitchesErrors[keyof ListKillSwitchesErrors];
 
-export type ListLoginAttemptsResponses = {
+export type ListKillSwitchesResponses = {
     /**
      * OK
      */
-    200: LoginAttemptListResponse;
+    200: KillSwitchListResponse;
 };
 
-export type ListLoginAttemptsResponse = ListLoginAttemptsResponses[keyof ListLoginAttemptsResponses];
+export type ListKillSwitchesResponse = ListKillSwitchesResponses[keyof ListKillSwitchesResponses];
 
-export type ListOAuthClientsData = {
-    body?: never;
+export type EngageKillSwitchData = {
+    body: EngageKillSwitchRequestWritable;
     path?: never;
     query?: never;
-    url: '/api/oauth-clients';
+    url: '/api/kill-switches';
 };
 
-export type ListOAuthClientsErrors = {
+export type EngageKillSwitchErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type ListOAuthClientsError = ListOAuthClientsErrors[keyof ListOAuthClientsErrors];
+export type EngageKillSwitchError = EngageKillSwitchErrors[keyof EngageKillSwitchErrors];
 
-export type ListOAuthClientsResponses = {
+export type EngageKillSwitchResponses = {
     /**
-     * OK
+     * Created
      */
-    200: OAuthClientListResponse;
+    201: KillSwitchResponse;
 };
 
-export type ListOAuthClientsResponse = ListOAuthClientsResponses[keyof ListOAuthClientsResponses];
+export type EngageKillSwitchResponse = EngageKillSwitchResponses[keyof EngageKillSwitchResponses];
 
-export type CreateOAuthClientData = {
-    body: CreateOAuthClientRequestWritable;
-    path?: never;
+export type ReleaseKillSwitchData = {
+    body?: never;
+    path: {
+        id: string;
+    };
     query?: never;
-    url: '/api/oauth-clients';
+    url: '/api/kill-switches/{id}';
 };
 
-export type CreateOAuthClientErrors = {
+export type ReleaseKillSwitchErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type CreateOAuthClientError = CreateOAuthClientErrors[keyof CreateOAuthClientErrors];
+export type ReleaseKillSwitchError = ReleaseKillSwitchErrors[keyof ReleaseKillSwitchErrors];
 
-export type CreateOAuthClientResponses = {
+export type ReleaseKillSwitchResponses = {
     /**
-     * Created
+     * No Content
      */
-    201: CreateOAuthClientResponse;
+    204: void;
 };
 
-export type CreateOAuthClientResponse2 = CreateOAuthClientResponses[keyof CreateOAuthClientResponses];
+export type ReleaseKillSwitchResponse = ReleaseKillSwitchResponses[keyof ReleaseKillSwitchResponses];
 
-export type GetOAuthClientByClientIdData = {
+export type ListLockoutsData = {
     body?: never;
-    path: {
-        clientId: string;
-    };
+    path?: never;
     query?: never;
-    url: '/api/oauth-clients/by-client-id/{clientId}';
+    url: '/api/lockouts';
 };
 
-export type GetOAuthClientByClientIdErrors = {
+export type ListLockoutsErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type GetOAuthClientByClientIdError = GetOAuthClientByClientIdErrors[keyof GetOAuthClientByClientIdErrors];
+export type ListLockoutsError = ListLockoutsErrors[keyof ListLockoutsErrors];
 
-export type GetOAuthClientByClientIdResponses = {
+export type ListLockoutsResponses = {
     /**
      * OK
      */
-    200: OAuthClientResponse;
+    200: LockoutListResponse;
+};
+
+export type ListLockoutsResponse = ListLockoutsResponses[keyof ListLockoutsResponses];
+
+export type ReleaseLockoutData = {
+    body?: never;
+    path: {
+        /**
+         * PRINCIPAL, CLIENT or IP
+         */
+        kind: string;
+        key: string;
+    };
+    query?: never;
+    url: '/api/lockouts/{kind}/{key}';
+};
+
+export type ReleaseLockoutErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ReleaseLockoutError = ReleaseLockoutErrors[keyof ReleaseLockoutErrors];
+
+export type ReleaseLockoutResponses = {
+    /**
+     * No Content
+     */
+    204: void;
+};
+
+export type ReleaseLockoutResponse = ReleaseLockoutResponses[keyof ReleaseLockoutResponses];
+
+export type ListLoginAttemptsData = {
+    body?: never;
+    path?: never;
+    query?: {
+        attemptType?: string;
+        outcome?: string;
+        identifier?: string;
+        principalId?: string;
+        dateFrom?: string;
+        dateTo?: string;
+        after?: string;
+        pageSize?: number;
+    };
+    url: '/api/login-attempts';
+};
+
+export type ListLoginAttemptsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListLoginAttemptsError = ListLoginAttemptsErrors[keyof ListLoginAttemptsErrors];
+
+export type ListLoginAttemptsResponses = {
+    /**
+     * OK
+     */
+    200: LoginAttemptListResponse;
+};
+
+export type ListLoginAttemptsResponse = ListLoginAttemptsResponses[keyof ListLoginAttemptsResponses];
+
+export type ListOAuthClientsData = {
+    body?: never;
+    path?: never;
+    query?: never;
+    url: '/api/oauth-clients';
+};
+
+export type ListOAuthClientsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListOAuthClientsError = ListOAuthClientsErrors[keyof ListOAuthClientsErrors];
+
+export type ListOAuthClientsResponses = {
+    /**
+     * OK
+     */
+    200: OAuthClientListResponse;
+};
+
+export type ListOAuthClientsResponse = ListOAuthClientsResponses[keyof ListOAuthClientsResponses];
+
+export type CreateOAuthClientData = {
+    body: CreateOAuthClientRequestWritable;
+    path?: never;
+    query?: never;
+    url: '/api/oauth-clients';
+};
+
+export type CreateOAuthClientErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type CreateOAuthClientError = CreateOAuthClientErrors[keyof CreateOAuthClientErrors];
+
+export type CreateOAuthClientResponses = {
+    /**
+     * Created
+     */
+    201: CreateOAuthClientResponse;
+};
+
+export type CreateOAuthClientResponse2 = CreateOAuthClientResponses[keyof CreateOAuthClientResponses];
+
+export type GetOAuthClientByClientIdData = {
+    body?: never;
+    path: {
+        clientId: string;
+    };
+    query?: never;
+    url: '/api/oauth-clients/by-client-id/{clientId}';
+};
+
+export type GetOAuthClientByClientIdErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetOAuthClientByClientIdError = GetOAuthClientByClientIdErrors[keyof GetOAuthClientByClientIdErrors];
+
+export type GetOAuthClientByClientIdResponses = {
+    /**
+     * OK
+     */
+    200: OAuthClientResponse;
 };
 
 export type GetOAuthClientByClientIdResponse = GetOAuthClientByClientIdResponses[keyof GetOAuthClientByClientIdResponses];
@@ -8912,197 +12402,497 @@ export type ResetPrincipalTwoFactorResponses = {
     200: StatusChangeResponse;
 };
 
-export type ResetPrincipalTwoFactorResponse = ResetPrincipalTwoFactorResponses[keyof ResetPrincipalTwoFactorResponses];
+export type ResetPrincipalTwoFactorResponse = ResetPrincipalTwoFactorResponses[keyof ResetPrincipalTwoFactorResponses];
+
+export type ResetPrincipalPasswordData = {
+    body: ResetPasswordRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/principals/{id}/reset-password';
+};
+
+export type ResetPrincipalPasswordErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ResetPrincipalPasswordError = ResetPrincipalPasswordErrors[keyof ResetPrincipalPasswordErrors];
+
+export type ResetPrincipalPasswordResponses = {
+    /**
+     * OK
+     */
+    200: StatusChangeResponse;
+};
+
+export type ResetPrincipalPasswordResponse = ResetPrincipalPasswordResponses[keyof ResetPrincipalPasswordResponses];
+
+export type ListPrincipalRolesData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/principals/{id}/roles';
+};
+
+export type ListPrincipalRolesErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListPrincipalRolesError = ListPrincipalRolesErrors[keyof ListPrincipalRolesErrors];
+
+export type ListPrincipalRolesResponses = {
+    /**
+     * OK
+     */
+    200: PrincipalRoleListResponse;
+};
+
+export type ListPrincipalRolesResponse = ListPrincipalRolesResponses[keyof ListPrincipalRolesResponses];
+
+export type AddPrincipalRoleData = {
+    body: AddRoleRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/principals/{id}/roles';
+};
+
+export type AddPrincipalRoleErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type AddPrincipalRoleError = AddPrincipalRoleErrors[keyof AddPrincipalRoleErrors];
+
+export type AddPrincipalRoleResponses = {
+    /**
+     * OK
+     */
+    200: PrincipalResponse;
+};
+
+export type AddPrincipalRoleResponse = AddPrincipalRoleResponses[keyof AddPrincipalRoleResponses];
+
+export type AssignPrincipalRolesData = {
+    body: AssignPrincipalRolesRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/principals/{id}/roles';
+};
+
+export type AssignPrincipalRolesErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type AssignPrincipalRolesError = AssignPrincipalRolesErrors[keyof AssignPrincipalRolesErrors];
+
+export type AssignPrincipalRolesResponses = {
+    /**
+     * OK
+     */
+    200: RolesAssignedResponse;
+};
+
+export type AssignPrincipalRolesResponse = AssignPrincipalRolesResponses[keyof AssignPrincipalRolesResponses];
+
+export type RemovePrincipalRoleData = {
+    body?: never;
+    path: {
+        id: string;
+        role: string;
+    };
+    query?: never;
+    url: '/api/principals/{id}/roles/{role}';
+};
+
+export type RemovePrincipalRoleErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type RemovePrincipalRoleError = RemovePrincipalRoleErrors[keyof RemovePrincipalRoleErrors];
+
+export type RemovePrincipalRoleResponses = {
+    /**
+     * OK
+     */
+    200: PrincipalResponse;
+};
+
+export type RemovePrincipalRoleResponse = RemovePrincipalRoleResponses[keyof RemovePrincipalRoleResponses];
+
+export type SendPrincipalPasswordResetData = {
+    body?: SendPasswordResetInputBodyWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/principals/{id}/send-password-reset';
+};
+
+export type SendPrincipalPasswordResetErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type SendPrincipalPasswordResetError = SendPrincipalPasswordResetErrors[keyof SendPrincipalPasswordResetErrors];
+
+export type SendPrincipalPasswordResetResponses = {
+    /**
+     * OK
+     */
+    200: StatusChangeResponse;
+};
+
+export type SendPrincipalPasswordResetResponse = SendPrincipalPasswordResetResponses[keyof SendPrincipalPasswordResetResponses];
+
+export type GetPrincipalVersionData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/principals/{id}/version';
+};
+
+export type GetPrincipalVersionErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetPrincipalVersionError = GetPrincipalVersionErrors[keyof GetPrincipalVersionErrors];
+
+export type GetPrincipalVersionResponses = {
+    /**
+     * OK
+     */
+    200: PrincipalVersionResponse;
+};
+
+export type GetPrincipalVersionResponse = GetPrincipalVersionResponses[keyof GetPrincipalVersionResponses];
+
+export type RequestErasureData = {
+    body: RequestErasureRequestWritable;
+    path?: never;
+    query?: never;
+    url: '/api/admin/privacy/erasure';
+};
+
+export type RequestErasureErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type RequestErasureError = RequestErasureErrors[keyof RequestErasureErrors];
+
+export type RequestErasureResponses = {
+    /**
+     * Accepted
+     */
+    202: ErasureRequestResponse;
+};
+
+export type RequestErasureResponse = RequestErasureResponses[keyof RequestErasureResponses];
+
+export type ListErasureRequestsData = {
+    body?: never;
+    path?: never;
+    query?: {
+        /**
+         * Maximum requests to return (default 50, max 200)
+         */
+        limit?: number;
+    };
+    url: '/api/admin/privacy/erasure';
+};
+
+export type ListErasureRequestsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ListErasureRequestsError = ListErasureRequestsErrors[keyof ListErasureRequestsErrors];
+
+export type ListErasureRequestsResponses = {
+    /**
+     * OK
+     */
+    200: ErasureRequestListResponse;
+};
+
+export type ListErasureRequestsResponse = ListErasureRequestsResponses[keyof ListErasureRequestsResponses];
+
+export type GetErasureRequestData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/admin/privacy/erasure/{id}';
+};
+
+export type GetErasureRequestErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetErasureRequestError = GetErasureRequestErrors[keyof GetErasureRequestErrors];
+
+export type GetErasureRequestResponses = {
+    /**
+     * OK
+     */
+    200: ErasureRequestResponse;
+};
+
+export type GetErasureRequestResponse = GetErasureRequestResponses[keyof GetErasureRequestResponses];
+
+export type ExportConfigurationData = {
+    body?: never;
+    path?: never;
+    query?: never;
+    url: '/api/admin/export';
+};
+
+export type ExportConfigurationErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type ExportConfigurationError = ExportConfigurationErrors[keyof ExportConfigurationErrors];
+
+export type ExportConfigurationResponses = {
+    /**
+     * OK
+     */
+    200: Bundle;
+};
+
+export type ExportConfigurationResponse = ExportConfigurationResponses[keyof ExportConfigurationResponses];
 
-export type ResetPrincipalPasswordData = {
-    body: ResetPasswordRequestWritable;
-    path: {
-        id: string;
+export type ImportConfigurationData = {
+    body: BundleWritable;
+    path?: never;
+    query?: {
+        /**
+         * Diff the bundle against this environment and report, without writing
+         */
+        dryRun?: boolean;
+        /**
+         * What to do with items that exist here with different values: skip (default), overwrite, or fail the whole import
+         */
+        onConflict?: string;
     };
-    query?: never;
-    url: '/api/principals/{id}/reset-password';
+    url: '/api/admin/import';
 };
 
-export type ResetPrincipalPasswordErrors = {
+export type ImportConfigurationErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type ResetPrincipalPasswordError = ResetPrincipalPasswordErrors[keyof ResetPrincipalPasswordErrors];
+export type ImportConfigurationError = ImportConfigurationErrors[keyof ImportConfigurationErrors];
 
-export type ResetPrincipalPasswordResponses = {
+export type ImportConfigurationResponses = {
     /**
      * OK
      */
-    200: StatusChangeResponse;
+    200: ImportResponse;
 };
 
-export type ResetPrincipalPasswordResponse = ResetPrincipalPasswordResponses[keyof ResetPrincipalPasswordResponses];
+export type ImportConfigurationResponse = ImportConfigurationResponses[keyof ImportConfigurationResponses];
 
-export type ListPrincipalRolesData = {
+export type GetPermissionMatrixData = {
     body?: never;
-    path: {
-        id: string;
-    };
+    path?: never;
     query?: never;
-    url: '/api/principals/{id}/roles';
+    url: '/api/admin/permissions/matrix';
 };
 
-export type ListPrincipalRolesErrors = {
+export type GetPermissionMatrixErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type ListPrincipalRolesError = ListPrincipalRolesErrors[keyof ListPrincipalRolesErrors];
+export type GetPermissionMatrixError = GetPermissionMatrixErrors[keyof GetPermissionMatrixErrors];
 
-export type ListPrincipalRolesResponses = {
+export type GetPermissionMatrixResponses = {
     /**
      * OK
      */
-    200: PrincipalRoleListResponse;
+    200: PermissionMatrixResponse;
 };
 
-export type ListPrincipalRolesResponse = ListPrincipalRolesResponses[keyof ListPrincipalRolesResponses];
+export type GetPermissionMatrixResponse = GetPermissionMatrixResponses[keyof GetPermissionMatrixResponses];
 
-export type AddPrincipalRoleData = {
-    body: AddRoleRequestWritable;
-    path: {
-        id: string;
-    };
+export type GetReconcilerStatusData = {
+    body?: never;
+    path?: never;
     query?: never;
-    url: '/api/principals/{id}/roles';
+    url: '/api/admin/reconciler';
 };
 
-export type AddPrincipalRoleErrors = {
+export type GetReconcilerStatusErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type AddPrincipalRoleError = AddPrincipalRoleErrors[keyof AddPrincipalRoleErrors];
+export type GetReconcilerStatusError = GetReconcilerStatusErrors[keyof GetReconcilerStatusErrors];
 
-export type AddPrincipalRoleResponses = {
+export type GetReconcilerStatusResponses = {
     /**
      * OK
      */
-    200: PrincipalResponse;
+    200: ReconcilerStatusResponse;
 };
 
-export type AddPrincipalRoleResponse = AddPrincipalRoleResponses[keyof AddPrincipalRoleResponses];
+export type GetReconcilerStatusResponse = GetReconcilerStatusResponses[keyof GetReconcilerStatusResponses];
 
-export type AssignPrincipalRolesData = {
-    body: AssignPrincipalRolesRequestWritable;
-    path: {
-        id: string;
-    };
+export type RunReconcilerData = {
+    body?: never;
+    path?: never;
     query?: never;
-    url: '/api/principals/{id}/roles';
+    url: '/api/admin/reconciler/run';
 };
 
-export type AssignPrincipalRolesErrors = {
+export type RunReconcilerErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type AssignPrincipalRolesError = AssignPrincipalRolesErrors[keyof AssignPrincipalRolesErrors];
+export type RunReconcilerError = RunReconcilerErrors[keyof RunReconcilerErrors];
 
-export type AssignPrincipalRolesResponses = {
+export type RunReconcilerResponses = {
     /**
      * OK
      */
-    200: RolesAssignedResponse;
+    200: ReconcilerStatusResponse;
 };
 
-export type AssignPrincipalRolesResponse = AssignPrincipalRolesResponses[keyof AssignPrincipalRolesResponses];
+export type RunReconcilerResponse = RunReconcilerResponses[keyof RunReconcilerResponses];
 
-export type RemovePrincipalRoleData = {
+export type ListStreamDlqData = {
     body?: never;
-    path: {
-        id: string;
-        role: string;
+    path?: never;
+    query?: {
+        /**
+         * Maximum entries to return (default 50, max 500)
+         */
+        limit?: number;
     };
-    query?: never;
-    url: '/api/principals/{id}/roles/{role}';
+    url: '/api/admin/stream-dlq';
 };
 
-export type RemovePrincipalRoleErrors = {
+export type ListStreamDlqErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type RemovePrincipalRoleError = RemovePrincipalRoleErrors[keyof RemovePrincipalRoleErrors];
+export type ListStreamDlqError = ListStreamDlqErrors[keyof ListStreamDlqErrors];
 
-export type RemovePrincipalRoleResponses = {
+export type ListStreamDlqResponses = {
     /**
      * OK
      */
-    200: PrincipalResponse;
+    200: StreamDlqListResponse;
 };
 
-export type RemovePrincipalRoleResponse = RemovePrincipalRoleResponses[keyof RemovePrincipalRoleResponses];
+export type ListStreamDlqResponse = ListStreamDlqResponses[keyof ListStreamDlqResponses];
 
-export type SendPrincipalPasswordResetData = {
-    body?: SendPasswordResetInputBodyWritable;
+export type ReprocessStreamDlqEntryData = {
+    body?: never;
     path: {
         id: string;
     };
     query?: never;
-    url: '/api/principals/{id}/send-password-reset';
+    url: '/api/admin/stream-dlq/{id}/reprocess';
 };
 
-export type SendPrincipalPasswordResetErrors = {
+export type ReprocessStreamDlqEntryErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type SendPrincipalPasswordResetError = SendPrincipalPasswordResetErrors[keyof SendPrincipalPasswordResetErrors];
+export type ReprocessStreamDlqEntryError = ReprocessStreamDlqEntryErrors[keyof ReprocessStreamDlqEntryErrors];
 
-export type SendPrincipalPasswordResetResponses = {
+export type ReprocessStreamDlqEntryResponses = {
     /**
-     * OK
+     * No Content
      */
-    200: StatusChangeResponse;
+    204: void;
 };
 
-export type SendPrincipalPasswordResetResponse = SendPrincipalPasswordResetResponses[keyof SendPrincipalPasswordResetResponses];
+export type ReprocessStreamDlqEntryResponse = ReprocessStreamDlqEntryResponses[keyof ReprocessStreamDlqEntryResponses];
 
-export type GetPrincipalVersionData = {
+export type DiscardStreamDlqEntryData = {
     body?: never;
     path: {
         id: string;
     };
     query?: never;
-    url: '/api/principals/{id}/version';
+    url: '/api/admin/stream-dlq/{id}';
 };
 
-export type GetPrincipalVersionErrors = {
+export type DiscardStreamDlqEntryErrors = {
     /**
      * Error
      */
     default: ErrorModel;
 };
 
-export type GetPrincipalVersionError = GetPrincipalVersionErrors[keyof GetPrincipalVersionErrors];
+export type DiscardStreamDlqEntryError = DiscardStreamDlqEntryErrors[keyof DiscardStreamDlqEntryErrors];
 
-export type GetPrincipalVersionResponses = {
+export type DiscardStreamDlqEntryResponses = {
     /**
-     * OK
+     * No Content
      */
-    200: PrincipalVersionResponse;
+    204: void;
 };
 
-export type GetPrincipalVersionResponse = GetPrincipalVersionResponses[keyof GetPrincipalVersionResponses];
+export type DiscardStreamDlqEntryResponse = DiscardStreamDlqEntryResponses[keyof DiscardStreamDlqEntryResponses];
 
 export type ListProcessesData = {
     body?: never;
@@ -10280,6 +14070,12 @@ export type ListServiceAccountsResponse = ListServiceAccountsResponses[keyof Lis
 
 export type CreateServiceAccountData = {
     body: CreateServiceAccountRequestWritable;
+    headers?: {
+        /**
+         * Client-chosen key that makes retries of this create return the first response instead of creating again
+         */
+        'Idempotency-Key'?: string;
+    };
     path?: never;
     query?: never;
     url: '/api/service-accounts';
@@ -10330,8 +14126,51 @@ export type GetServiceAccountByCodeResponses = {
 
 export type GetServiceAccountByCodeResponse = GetServiceAccountByCodeResponses[keyof GetServiceAccountByCodeResponses];
 
+export type UpsertServiceAccountData = {
+    body: UpsertServiceAccountRequestWritable;
+    headers?: {
+        /**
+         * Update only if the service account exists with this ETag
+         */
+        'If-Match'?: string;
+        /**
+         * Send * to create only, failing if the code is taken
+         */
+        'If-None-Match'?: string;
+    };
+    path: {
+        code: string;
+    };
+    query?: never;
+    url: '/api/service-accounts/code/{code}';
+};
+
+export type UpsertServiceAccountErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpsertServiceAccountError = UpsertServiceAccountErrors[keyof UpsertServiceAccountErrors];
+
+export type UpsertServiceAccountResponses = {
+    /**
+     * OK
+     */
+    200: UpsertServiceAccountResponse;
+};
+
+export type UpsertServiceAccountResponse = UpsertServiceAccountResponses[keyof UpsertServiceAccountResponses];
+
 export type DeleteServiceAccountData = {
     body?: never;
+    headers?: {
+        /**
+         * Proceed only if the resource's current ETag matches
+         */
+        'If-Match'?: string;
+    };
     path: {
         id: string;
     };
@@ -10386,6 +14225,12 @@ export type GetServiceAccountResponse = GetServiceAccountResponses[keyof GetServ
 
 export type UpdateServiceAccountData = {
     body: UpdateServiceAccountRequestWritable;
+    headers?: {
+        /**
+         * Proceed only if the service account's current ETag matches
+         */
+        'If-Match'?: string;
+    };
     path: {
         id: string;
     };
@@ -10630,6 +14475,12 @@ export type ListSubscriptionsResponse = ListSubscriptionsResponses[keyof ListSub
 
 export type CreateSubscriptionData = {
     body: CreateSubscriptionRequestWritable;
+    headers?: {
+        /**
+         * Client-chosen key that makes retries of this create return the first response instead of creating again
+         */
+        'Idempotency-Key'?: string;
+    };
     path?: never;
     query?: never;
     url: '/api/subscriptions';
@@ -10653,8 +14504,59 @@ export type CreateSubscriptionResponses = {
 
 export type CreateSubscriptionResponse = CreateSubscriptionResponses[keyof CreateSubscriptionResponses];
 
+export type UpsertSubscriptionData = {
+    body: UpsertSubscriptionRequestWritable;
+    headers?: {
+        /**
+         * Update only if the subscription exists with this ETag
+         */
+        'If-Match'?: string;
+        /**
+         * Send * to create only, failing if the code is taken
+         */
+        'If-None-Match'?: string;
+    };
+    path: {
+        /**
+         * Subscription code
+         */
+        code: string;
+    };
+    query?: {
+        /**
+         * Client the subscription belongs to; absent means anchor-level
+         */
+        clientId?: string;
+    };
+    url: '/api/subscriptions/by-code/{code}';
+};
+
+export type UpsertSubscriptionErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type UpsertSubscriptionError = UpsertSubscriptionErrors[keyof UpsertSubscriptionErrors];
+
+export type UpsertSubscriptionResponses = {
+    /**
+     * OK
+     */
+    200: SubscriptionResponse;
+};
+
+export type UpsertSubscriptionResponse = UpsertSubscriptionResponses[keyof UpsertSubscriptionResponses];
+
 export type DeleteSubscriptionData = {
     body?: never;
+    headers?: {
+        /**
+         * Proceed only if the resource's current ETag matches
+         */
+        'If-Match'?: string;
+    };
     path: {
         id: string;
     };
@@ -10709,6 +14611,12 @@ export type GetSubscriptionResponse = GetSubscriptionResponses[keyof GetSubscrip
 
 export type UpdateSubscriptionData = {
     body: UpdateSubscriptionRequestWritable;
+    headers?: {
+        /**
+         * Proceed only if the subscription's current ETag matches
+         */
+        'If-Match'?: string;
+    };
     path: {
         id: string;
     };
@@ -10788,6 +14696,124 @@ export type ResumeSubscriptionResponses = {
 
 export type ResumeSubscriptionResponse = ResumeSubscriptionResponses[keyof ResumeSubscriptionResponses];
 
+export type GetSubscriptionShadowReportData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: {
+        /**
+         * RFC3339; compare deliveries copied at or after it. Defaults to the last 24 hours
+         */
+        since?: string;
+    };
+    url: '/api/subscriptions/{id}/shadow-report';
+};
+
+export type GetSubscriptionShadowReportErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetSubscriptionShadowReportError = GetSubscriptionShadowReportErrors[keyof GetSubscriptionShadowReportErrors];
+
+export type GetSubscriptionShadowReportResponses = {
+    /**
+     * OK
+     */
+    200: ShadowReportResponse;
+};
+
+export type GetSubscriptionShadowReportResponse = GetSubscriptionShadowReportResponses[keyof GetSubscriptionShadowReportResponses];
+
+export type PromoteSubscriptionShadowData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/subscriptions/{id}/promote-shadow';
+};
+
+export type PromoteSubscriptionShadowErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type PromoteSubscriptionShadowError = PromoteSubscriptionShadowErrors[keyof PromoteSubscriptionShadowErrors];
+
+export type PromoteSubscriptionShadowResponses = {
+    /**
+     * No Content
+     */
+    204: void;
+};
+
+export type PromoteSubscriptionShadowResponse = PromoteSubscriptionShadowResponses[keyof PromoteSubscriptionShadowResponses];
+
+export type SetSubscriptionTrafficSplitData = {
+    body: SetTrafficSplitRequestWritable;
+    path: {
+        id: string;
+    };
+    query?: never;
+    url: '/api/subscriptions/{id}/traffic-split';
+};
+
+export type SetSubscriptionTrafficSplitErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type SetSubscriptionTrafficSplitError = SetSubscriptionTrafficSplitErrors[keyof SetSubscriptionTrafficSplitErrors];
+
+export type SetSubscriptionTrafficSplitResponses = {
+    /**
+     * No Content
+     */
+    204: void;
+};
+
+export type SetSubscriptionTrafficSplitResponse = SetSubscriptionTrafficSplitResponses[keyof SetSubscriptionTrafficSplitResponses];
+
+export type GetSubscriptionTargetStatsData = {
+    body?: never;
+    path: {
+        id: string;
+    };
+    query?: {
+        /**
+         * RFC3339; count jobs created at or after it. Defaults to the last 24 hours
+         */
+        since?: string;
+    };
+    url: '/api/subscriptions/{id}/target-stats';
+};
+
+export type GetSubscriptionTargetStatsErrors = {
+    /**
+     * Error
+     */
+    default: ErrorModel;
+};
+
+export type GetSubscriptionTargetStatsError = GetSubscriptionTargetStatsErrors[keyof GetSubscriptionTargetStatsErrors];
+
+export type GetSubscriptionTargetStatsResponses = {
+    /**
+     * OK
+     */
+    200: TargetStatsResponse;
+};
+
+export type GetSubscriptionTargetStatsResponse = GetSubscriptionTargetStatsResponses[keyof GetSubscriptionTargetStatsResponses];
+
 export type WebauthnAuthenticateBeginData = {
     body: AuthenticateBeginRequestWritable;
     path?: never;
@@ -28,6 +28,7 @@ export interface CreateOAuthClientRequest {
 	redirectUris: string[];
 	postLogoutRedirectUris?: string[];
 	allowedOrigins?: string[];
+	allowedCidrs?: string[];
 	grantTypes: string[];
 	defaultScopes?: string;
 	pkceRequired?: boolean;
@@ -39,6 +40,7 @@ export interface UpdateOAuthClientRequest {
 	redirectUris?: string[];
 	postLogoutRedirectUris?: string[];
 	allowedOrigins?: string[];
+	allowedCidrs?: string[];
 	grantTypes?: string[];
 	defaultScopes?: string[];
 	pkceRequired?: boolean;
@@ -48,6 +48,7 @@ export interface CreateServiceAccountRequest {
 	name: string;
 	description?: string;
 	clientIds?: string[];
+	allowedCidrs?: string[];
 	applicationId?: string;
 	scope?: PrincipalScope;
 }
@@ -56,6 +57,7 @@ export interface UpdateServiceAccountRequest {
 	name?: string;
 	description?: string;
 	clientIds?: string[];
+	allowedCidrs?: string[];
 	scope?: PrincipalScope;
 }
 
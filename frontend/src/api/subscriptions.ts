@@ -1,10 +1,14 @@
-import { apiFetch } from "./client";
+import { apiFetch, bffFetch } from "./client";
 import type {
+	BatchingDto,
 	ConfigEntryDto,
 	CreatedResponse,
 	EventTypeBindingDto,
+	ShadowReportResponse,
+	SplitTargetDto,
 	SubscriptionListResponse as GenSubscriptionListResponse,
 	SubscriptionResponse,
+	TargetStatsResponse,
 } from "./generated";
 
 // Request-side string unions the forms rely on. The generated response
@@ -13,6 +17,13 @@ import type {
 export type SubscriptionStatus = "ACTIVE" | "PAUSED";
 export type SubscriptionSource = "API" | "UI";
 export type SubscriptionMode = "IMMEDIATE" | "NEXT_ON_ERROR" | "BLOCK_ON_ERROR";
+export type SubscriptionDelivery = "PUSH" | "PULL";
+export type SubscriptionPayloadFormat =
+	| "JSON"
+	| "CLOUDEVENTS_JSON"
+	| "NDJSON"
+	| "PROTOBUF";
+export type SubscriptionLargePayloads = "INLINE" | "PRESIGNED_URL";
 
 // Response types alias the generated contract (api/openapi.lock.json) so
 // `vue-tsc` fails on backend drift. Aliased under the historical names so
@@ -21,19 +32,30 @@ export type Subscription = SubscriptionResponse;
 export type SubscriptionListResponse = GenSubscriptionListResponse;
 export type EventTypeBinding = EventTypeBindingDto;
 export type ConfigEntry = ConfigEntryDto;
+export type SubscriptionBatching = BatchingDto;
+export type SubscriptionShadowReport = ShadowReportResponse;
+export type SplitTarget = SplitTargetDto;
+export type SubscriptionTargetStats = TargetStatsResponse;
 
 export interface CreateSubscriptionRequest {
 	code: string;
 	applicationCode?: string;
 	name: string;
 	description?: string;
-	endpoint: string;
+	endpoint?: string;
+	shadowEndpoint?: string;
+	delivery?: SubscriptionDelivery;
+	batching?: SubscriptionBatching;
+	payloadFormat?: SubscriptionPayloadFormat;
+	largePayloads?: SubscriptionLargePayloads;
 	clientScoped: boolean;
 	clientId?: string;
 	eventTypes: EventTypeBinding[];
 	connectionId?: string;
 	queue: string;
 	customConfig?: ConfigEntry[];
+	headers?: ConfigEntry[];
+	transport?: string;
 	source?: SubscriptionSource;
 	maxAgeSeconds?: number;
 	dispatchPoolId: string;
@@ -47,10 +69,17 @@ export interface UpdateSubscriptionRequest {
 	name?: string;
 	description?: string;
 	endpoint?: string;
+	shadowEndpoint?: string;
+	delivery?: SubscriptionDelivery;
+	batching?: SubscriptionBatching;
+	payloadFormat?: SubscriptionPayloadFormat;
+	largePayloads?: SubscriptionLargePayloads;
 	eventTypes?: EventTypeBinding[];
 	connectionId?: string;
 	queue?: string;
 	customConfig?: ConfigEntry[];
+	headers?: ConfigEntry[];
+	transport?: string;
 	status?: SubscriptionStatus;
 	maxAgeSeconds?: number;
 	dispatchPoolId?: string;
@@ -69,6 +98,50 @@ export interface SubscriptionFilters {
 	anchorLevel?: boolean;
 }
 
+// ── Delivery analytics (hand-rolled: /bff paths are stripped from the spec) ──
+// Verified against internal/platform/shared/bff/subscription_analytics.go.
+
+export type SubscriptionAnalyticsRange = "6h" | "24h" | "7d" | "30d";
+
+/** Estimates from the hourly rollup's latency histogram, in milliseconds. */
+export interface LatencyPercentiles {
+	p50?: number;
+	p90?: number;
+	p95?: number;
+	p99?: number;
+}
+
+/** One hour; hours without attempts come back with zero counts. */
+export interface SubscriptionAnalyticsBucket {
+	bucketStart: string;
+	attempts: number;
+	successes: number;
+	failures: number;
+	successRate?: number;
+	latencyP50Ms?: number;
+	latencyP95Ms?: number;
+}
+
+export interface SubscriptionAnalytics {
+	subscriptionId: string;
+	range: SubscriptionAnalyticsRange;
+	from: string;
+	to: string;
+	summary: {
+		attempts: number;
+		successes: number;
+		failures: number;
+		successRate?: number;
+		avgLatencyMs?: number;
+		latencyMs: LatencyPercentiles;
+	};
+	series: SubscriptionAnalyticsBucket[];
+	/** A response code such as "503", or an error type such as "TIMEOUT". */
+	topErrors: { code: string; count: number }[];
+	/** Attempts by attempt number; the `orMore` entry takes that number and above. */
+	retries: { attempt: number; count: number; orMore?: boolean }[];
+}
+
 export const subscriptionsApi = {
 	list(filters: SubscriptionFilters = {}): Promise<SubscriptionListResponse> {
 		const params = new URLSearchParams();
@@ -124,4 +197,39 @@ export const subscriptionsApi = {
 			method: "POST",
 		});
 	},
+
+	/** Compares the endpoint with the shadow since `since` (default: the last 24 hours). */
+	shadowReport(id: string, since?: string): Promise<SubscriptionShadowReport> {
+		const query = since ? `?since=${encodeURIComponent(since)}` : "";
+		return apiFetch(`/subscriptions/${id}/shadow-report${query}`);
+	},
+
+	promoteShadow(id: string): Promise<void> {
+		return apiFetch(`/subscriptions/${id}/promote-shadow`, {
+			method: "POST",
+		});
+	},
+
+	/** Replaces the traffic split; an empty split sends everything to the endpoint. */
+	setTrafficSplit(id: string, split: SplitTarget[]): Promise<void> {
+		return apiFetch(`/subscriptions/${id}/traffic-split`, {
+			method: "PUT",
+			body: JSON.stringify({ split }),
+		});
+	},
+
+	/** Delivery metrics per endpoint since `since` (default: the last 24 hours). */
+	targetStats(id: string, since?: string): Promise<SubscriptionTargetStats> {
+		const query = since ? `?since=${encodeURIComponent(since)}` : "";
+		return apiFetch(`/subscriptions/${id}/target-stats${query}`);
+	},
+
+	analytics(
+		id: string,
+		range: SubscriptionAnalyticsRange = "24h",
+	): Promise<SubscriptionAnalytics> {
+		return bffFetch(
+			`/subscriptions/${encodeURIComponent(id)}/analytics?range=${range}`,
+		);
+	},
 };
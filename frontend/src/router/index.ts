@@ -16,6 +16,15 @@ const router = createRouter({
 			name: "logout",
 			component: () => import("@/pages/auth/LogoutPage.vue"),
 		},
+		// RFC 8628 device verification. Standalone (no layout) like /logout,
+		// but requires a session: authGuard bounces through login and
+		// redirectAfterLogin brings the user back with their user_code.
+		{
+			path: "/device",
+			name: "device",
+			component: () => import("@/pages/auth/DevicePage.vue"),
+			beforeEnter: authGuard,
+		},
 		// Auth routes (no layout, guest only)
 		{
 			path: "/auth",
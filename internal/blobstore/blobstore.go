@@ -0,0 +1,93 @@
+// Package blobstore keeps oversized event payloads out of Postgres and the
+// queues. A payload over the configured threshold is written to an object
+// store and replaced by a reference (ref.go) that travels through the
+// platform in its place: event ingestion and the outbox HTTP dispatcher
+// offload, and dispatch processing rehydrates the payload — or hands the
+// receiver a pre-signed URL — just before delivery.
+//
+// Stores are opened from a URI (FC_BLOB_STORE_URI):
+//
+//	s3://bucket/prefix?region=eu-west-1
+//	s3://bucket/prefix?endpoint=https://storage.googleapis.com
+//	file:///var/lib/flowcatalyst/blobs
+//
+// s3:// speaks the S3 REST API with SigV4, so any S3-compatible store
+// works: AWS S3, GCS through its XML interoperability API (HMAC keys),
+// MinIO, Cloudflare R2. Credentials come from the default AWS chain
+// (environment, shared config, instance or task role). file:// is for
+// development; it cannot pre-sign. Azure Blob Storage has no S3 API and is
+// not supported natively — front it with an S3-compatible gateway.
+package blobstore
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// ErrNotFound is returned by Get for a key the store does not hold.
+var ErrNotFound = errors.New("blobstore: object not found")
+
+// Store is an object store holding payloads by key.
+type Store interface {
+	Put(ctx context.Context, key string, data []byte, contentType string) error
+	Get(ctx context.Context, key string) ([]byte, error)
+	Delete(ctx context.Context, key string) error
+}
+
+// Presigner is a Store that can hand out a time-limited GET URL, so a
+// receiver fetches the payload straight from the store.
+type Presigner interface {
+	PresignGet(ctx context.Context, key string, ttl time.Duration) (string, error)
+}
+
+// Open opens the store uri names; see the package doc for the schemes.
+func Open(ctx context.Context, uri string) (Store, error) {
+	u, err := url.Parse(uri)
+	if err != nil {
+		return nil, fmt.Errorf("blob store uri: %w", err)
+	}
+	switch u.Scheme {
+	case "s3":
+		return openS3(ctx, u)
+	case "file":
+		return openFile(u)
+	case "azblob", "azure":
+		return nil, errors.New("blob store: Azure Blob Storage is not supported; use an S3-compatible gateway")
+	default:
+		return nil, fmt.Errorf("blob store: unknown scheme %q (s3, file)", u.Scheme)
+	}
+}
+
+// validKey reports whether key is a relative, slash-separated path of
+// URL-safe characters — what the platform generates — so stores need no
+// escaping and a key cannot climb out of a file store's root.
+func validKey(key string) bool {
+	if key == "" || strings.HasPrefix(key, "/") || strings.HasSuffix(key, "/") {
+		return false
+	}
+	for _, seg := range strings.Split(key, "/") {
+		if seg == "" || seg == "." || seg == ".." {
+			return false
+		}
+	}
+	for _, c := range key {
+		switch {
+		case c >= 'a' && c <= 'z', c >= 'A' && c <= 'Z', c >= '0' && c <= '9':
+		case c == '/', c == '-', c == '_', c == '.':
+		default:
+			return false
+		}
+	}
+	return true
+}
+
+func checkKey(key string) error {
+	if !validKey(key) {
+		return fmt.Errorf("blob store: invalid key %q", key)
+	}
+	return nil
+}
@@ -0,0 +1,165 @@
+package blobstore
+
+import (
+	"context"
+	"encoding/json"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/aws/aws-sdk-go-v2/aws"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestOffloaderRoundTrip(t *testing.T) {
+	ctx := context.Background()
+	store, err := Open(ctx, "file://"+t.TempDir())
+	require.NoError(t, err)
+	o := NewOffloader(store, 16, 0)
+
+	small := json.RawMessage(`{"a":1}`)
+	out, err := o.Offload(ctx, "events/small", small)
+	require.NoError(t, err)
+	assert.Equal(t, small, out, "payloads under the threshold stay inline")
+
+	big := json.RawMessage(`{"orders":[1,2,3,4,5,6,7,8,9,10]}`)
+	out, err = o.Offload(ctx, "events/big", big)
+	require.NoError(t, err)
+	ref, ok := ParseRef(out)
+	require.True(t, ok, "offloaded payload is a reference: %s", out)
+	assert.Equal(t, "events/big", ref.Key)
+	assert.Equal(t, int64(len(big)), ref.Size)
+
+	again, err := o.Offload(ctx, "events/other", out)
+	require.NoError(t, err)
+	assert.Equal(t, out, again, "a reference is never offloaded again")
+
+	data, err := o.Fetch(ctx, ref)
+	require.NoError(t, err)
+	assert.JSONEq(t, string(big), string(data))
+
+	ref.SHA256 = digest([]byte("tampered"))
+	_, err = o.Fetch(ctx, ref)
+	assert.ErrorContains(t, err, "sha256 mismatch")
+
+	_, ok, err = o.Presign(ctx, ref)
+	require.NoError(t, err)
+	assert.False(t, ok, "file stores cannot pre-sign")
+
+	require.NoError(t, store.Delete(ctx, "events/big"))
+	_, err = store.Get(ctx, "events/big")
+	assert.ErrorIs(t, err, ErrNotFound)
+
+	var none *Offloader
+	out, err = none.Offload(ctx, "events/big", big)
+	require.NoError(t, err)
+	assert.Equal(t, big, out)
+	_, err = none.Fetch(ctx, ref)
+	assert.Error(t, err)
+}
+
+func TestParseRef(t *testing.T) {
+	ref := Ref{Key: "outbox/abc", Size: 3, SHA256: "x"}
+	got, ok := ParseRef(ref.JSON())
+	require.True(t, ok)
+	assert.Equal(t, ref, got)
+
+	for _, data := range []string{
+		`{"a":1}`,
+		`{"$blobRef":{"key":"k"},"other":1}`,
+		`{"$blobRef":{}}`,
+		`["$blobRef"]`,
+		`not json $blobRef`,
+	} {
+		_, ok := ParseRef([]byte(data))
+		assert.False(t, ok, data)
+	}
+}
+
+func TestValidKey(t *testing.T) {
+	for _, k := range []string{"events/0HZ1", "outbox/ab-c_d.json"} {
+		assert.True(t, validKey(k), k)
+	}
+	for _, k := range []string{"", "/abs", "dir/", "a//b", "../up", "a/./b", "sp ace", "q?x"} {
+		assert.False(t, validKey(k), k)
+	}
+}
+
+func TestOpenRejectsUnsupportedSchemes(t *testing.T) {
+	ctx := context.Background()
+	_, err := Open(ctx, "azblob://account/container")
+	assert.ErrorContains(t, err, "S3-compatible gateway")
+	_, err = Open(ctx, "ftp://host/dir")
+	assert.ErrorContains(t, err, "unknown scheme")
+}
+
+// fakeS3 holds objects by request path and records the signed requests.
+type fakeS3 struct {
+	mu      sync.Mutex
+	objects map[string][]byte
+	auth    []string
+}
+
+func (f *fakeS3) ServeHTTP(w http.ResponseWriter, r *http.Request) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	f.auth = append(f.auth, r.Header.Get("Authorization"))
+	switch r.Method {
+	case http.MethodPut:
+		b, _ := io.ReadAll(r.Body)
+		f.objects[r.URL.Path] = b
+	case http.MethodGet:
+		b, ok := f.objects[r.URL.Path]
+		if !ok {
+			http.Error(w, "<Error><Code>NoSuchKey</Code></Error>", http.StatusNotFound)
+			return
+		}
+		_, _ = w.Write(b)
+	case http.MethodDelete:
+		delete(f.objects, r.URL.Path)
+		w.WriteHeader(http.StatusNoContent)
+	}
+}
+
+func TestS3StoreSignsPathStyleRequests(t *testing.T) {
+	ctx := context.Background()
+	fake := &fakeS3{objects: map[string][]byte{}}
+	srv := httptest.NewServer(fake)
+	defer srv.Close()
+
+	creds := aws.CredentialsProviderFunc(func(context.Context) (aws.Credentials, error) {
+		return aws.Credentials{AccessKeyID: "AKID", SecretAccessKey: "secret"}, nil
+	})
+	s := newS3Store(s3Config{Bucket: "payloads", Prefix: "fc", Region: "auto", Endpoint: srv.URL}, creds)
+
+	require.NoError(t, s.Put(ctx, "events/e1", []byte(`{"big":true}`), "application/json"))
+	assert.Contains(t, fake.objects, "/payloads/fc/events/e1")
+	got, err := s.Get(ctx, "events/e1")
+	require.NoError(t, err)
+	assert.Equal(t, `{"big":true}`, string(got))
+	require.NoError(t, s.Delete(ctx, "events/e1"))
+	_, err = s.Get(ctx, "events/e1")
+	assert.ErrorIs(t, err, ErrNotFound)
+	for _, a := range fake.auth {
+		assert.True(t, strings.HasPrefix(a, "AWS4-HMAC-SHA256 Credential=AKID/"), a)
+	}
+
+	signed, err := s.PresignGet(ctx, "events/e1", time.Hour)
+	require.NoError(t, err)
+	u, err := url.Parse(signed)
+	require.NoError(t, err)
+	assert.Equal(t, "/payloads/fc/events/e1", u.Path)
+	assert.Equal(t, "3600", u.Query().Get("X-Amz-Expires"))
+	assert.NotEmpty(t, u.Query().Get("X-Amz-Signature"))
+}
+
+func TestS3ObjectURLVirtualHosted(t *testing.T) {
+	s := newS3Store(s3Config{Bucket: "payloads", Region: "eu-west-1"}, nil)
+	assert.Equal(t, "https://payloads.s3.eu-west-1.amazonaws.com/events/e1", s.objectURL("events/e1"))
+}
@@ -0,0 +1,109 @@
+package blobstore
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"time"
+)
+
+// Defaults for FC_BLOB_THRESHOLD_BYTES and FC_BLOB_PRESIGN_TTL_SECONDS.
+const (
+	DefaultThreshold  = 256 << 10
+	DefaultPresignTTL = 15 * time.Minute
+)
+
+const contentTypeJSON = "application/json"
+
+// Offloader moves payloads over a size threshold into a Store. A nil
+// *Offloader is a deployment without a blob store: nothing is offloaded
+// and references cannot be resolved.
+type Offloader struct {
+	store      Store
+	threshold  int
+	presignTTL time.Duration
+}
+
+// NewOffloader offloads payloads larger than threshold bytes to store;
+// pre-signed URLs live for presignTTL, at most 7 days. Zero values take
+// the defaults.
+func NewOffloader(store Store, threshold int, presignTTL time.Duration) *Offloader {
+	if threshold <= 0 {
+		threshold = DefaultThreshold
+	}
+	if presignTTL <= 0 {
+		presignTTL = DefaultPresignTTL
+	}
+	presignTTL = min(presignTTL, maxPresignTTL)
+	return &Offloader{store: store, threshold: threshold, presignTTL: presignTTL}
+}
+
+// Enabled reports whether a blob store is configured.
+func (o *Offloader) Enabled() bool { return o != nil }
+
+// Over reports whether a payload of size bytes is offloaded.
+func (o *Offloader) Over(size int) bool { return o != nil && size > o.threshold }
+
+// Offload stores data under key when it exceeds the threshold and returns
+// the placeholder to keep in its place; smaller data, a payload that is
+// already a placeholder, and a nil Offloader return data unchanged.
+func (o *Offloader) Offload(ctx context.Context, key string, data json.RawMessage) (json.RawMessage, error) {
+	if !o.Over(len(data)) {
+		return data, nil
+	}
+	if _, ok := ParseRef(data); ok {
+		return data, nil
+	}
+	if err := o.store.Put(ctx, key, data, contentTypeJSON); err != nil {
+		return nil, err
+	}
+	return Ref{Key: key, Size: int64(len(data)), SHA256: digest(data), ContentType: contentTypeJSON}.JSON(), nil
+}
+
+// Fetch reads r's payload back, checking it is the one r describes.
+func (o *Offloader) Fetch(ctx context.Context, r Ref) ([]byte, error) {
+	if o == nil {
+		return nil, errors.New("payload is in the blob store, which is not configured")
+	}
+	data, err := o.store.Get(ctx, r.Key)
+	if err != nil {
+		return nil, fmt.Errorf("fetch %s: %w", r.Key, err)
+	}
+	if r.SHA256 != "" && digest(data) != r.SHA256 {
+		return nil, fmt.Errorf("fetch %s: sha256 mismatch", r.Key)
+	}
+	return data, nil
+}
+
+// Presign returns r with a pre-signed URL and its expiry. ok is false
+// when the store cannot pre-sign; the caller then delivers inline.
+func (o *Offloader) Presign(ctx context.Context, r Ref) (_ Ref, ok bool, err error) {
+	if o == nil {
+		return r, false, nil
+	}
+	p, can := o.store.(Presigner)
+	if !can {
+		return r, false, nil
+	}
+	url, err := p.PresignGet(ctx, r.Key, o.presignTTL)
+	if err != nil {
+		return r, false, err
+	}
+	exp := time.Now().UTC().Add(o.presignTTL).Truncate(time.Second)
+	r.URL, r.ExpiresAt = url, &exp
+	return r, true, nil
+}
+
+// ContentKey is a key under prefix derived from data's digest, so
+// re-sending the same payload rewrites the same object.
+func ContentKey(prefix string, data []byte) string {
+	return prefix + digest(data)
+}
+
+func digest(data []byte) string {
+	sum := sha256.Sum256(data)
+	return hex.EncodeToString(sum[:])
+}
@@ -0,0 +1,61 @@
+package blobstore
+
+import (
+	"bytes"
+	"encoding/json"
+	"time"
+)
+
+// refField is the single member of an offloaded payload's placeholder:
+//
+//	{"$blobRef": {"key": "events/0HZ…", "size": 912345, "sha256": "…"}}
+//
+// The "$" keeps it out of the way of real payloads, which the platform's
+// event types describe with plain JSON Schema property names.
+const refField = "$blobRef"
+
+// Key prefixes. Ingestion stores under EventsPrefix; an outbox stores
+// under OutboxPrefix, the only prefix ingestion accepts a reference to.
+const (
+	EventsPrefix = "events/"
+	OutboxPrefix = "outbox/"
+)
+
+// Ref locates an offloaded payload. URL and ExpiresAt are set only on a
+// delivery to a subscription that takes pre-signed URLs.
+type Ref struct {
+	Key         string     `json:"key"`
+	Size        int64      `json:"size"`
+	SHA256      string     `json:"sha256"`
+	ContentType string     `json:"contentType,omitempty"`
+	URL         string     `json:"url,omitempty"`
+	ExpiresAt   *time.Time `json:"expiresAt,omitempty"`
+}
+
+// JSON is r as the payload placeholder.
+func (r Ref) JSON() json.RawMessage {
+	b, _ := json.Marshal(map[string]Ref{refField: r})
+	return b
+}
+
+// ParseRef reports whether data is a payload placeholder, and the
+// reference it holds. Anything else — including an object with other
+// members beside $blobRef — is an ordinary payload.
+func ParseRef(data []byte) (Ref, bool) {
+	if !bytes.Contains(data, []byte(refField)) {
+		return Ref{}, false
+	}
+	var m map[string]json.RawMessage
+	if json.Unmarshal(data, &m) != nil || len(m) != 1 {
+		return Ref{}, false
+	}
+	raw, ok := m[refField]
+	if !ok {
+		return Ref{}, false
+	}
+	var r Ref
+	if json.Unmarshal(raw, &r) != nil || r.Key == "" {
+		return Ref{}, false
+	}
+	return r, true
+}
@@ -0,0 +1,211 @@
+package blobstore
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/aws/aws-sdk-go-v2/aws"
+	v4 "github.com/aws/aws-sdk-go-v2/aws/signer/v4"
+	awsconfig "github.com/aws/aws-sdk-go-v2/config"
+)
+
+// S3 request constants.
+const (
+	s3Service = "s3"
+	// emptySHA256 is the payload hash of a request without a body.
+	emptySHA256     = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+	unsignedPayload = "UNSIGNED-PAYLOAD"
+	// maxObjectSize caps what Get reads back; payloads are bounded by the
+	// ingestion request limit well below this.
+	maxObjectSize = 256 << 20
+	// maxPresignTTL is the longest SigV4 allows.
+	maxPresignTTL = 7 * 24 * time.Hour
+)
+
+// s3Config is an s3:// URI, resolved.
+type s3Config struct {
+	Bucket string
+	Prefix string
+	Region string
+	// Endpoint, when set, is an S3-compatible service addressed
+	// path-style (endpoint/bucket/key); otherwise AWS virtual-hosted
+	// style (bucket.s3.region.amazonaws.com/key).
+	Endpoint string
+}
+
+// s3Store speaks the S3 REST API directly: three verbs and a pre-signed
+// GET need no more than a SigV4 signer.
+type s3Store struct {
+	cfg    s3Config
+	creds  aws.CredentialsProvider
+	signer *v4.Signer
+	client *http.Client
+}
+
+func openS3(ctx context.Context, u *url.URL) (Store, error) {
+	q := u.Query()
+	cfg := s3Config{
+		Bucket:   u.Host,
+		Prefix:   strings.Trim(u.Path, "/"),
+		Region:   q.Get("region"),
+		Endpoint: strings.TrimRight(q.Get("endpoint"), "/"),
+	}
+	if cfg.Bucket == "" {
+		return nil, errors.New("blob store: s3:// needs a bucket")
+	}
+	if cfg.Prefix != "" && !validKey(cfg.Prefix) {
+		return nil, fmt.Errorf("blob store: invalid prefix %q", cfg.Prefix)
+	}
+	var opts []func(*awsconfig.LoadOptions) error
+	if cfg.Region != "" {
+		opts = append(opts, awsconfig.WithRegion(cfg.Region))
+	}
+	awsCfg, err := awsconfig.LoadDefaultConfig(ctx, opts...)
+	if err != nil {
+		return nil, fmt.Errorf("blob store: load AWS config: %w", err)
+	}
+	if cfg.Region == "" {
+		cfg.Region = awsCfg.Region
+	}
+	if cfg.Region == "" {
+		// S3-compatible services mostly ignore the region, but SigV4
+		// still needs one.
+		if cfg.Endpoint == "" {
+			return nil, errors.New("blob store: s3:// needs ?region= or AWS_REGION")
+		}
+		cfg.Region = "auto"
+	}
+	return newS3Store(cfg, awsCfg.Credentials), nil
+}
+
+func newS3Store(cfg s3Config, creds aws.CredentialsProvider) *s3Store {
+	return &s3Store{
+		cfg:   cfg,
+		creds: creds,
+		// S3 signs the path as sent, not escaped a second time.
+		signer: v4.NewSigner(func(o *v4.SignerOptions) { o.DisableURIPathEscaping = true }),
+		client: &http.Client{Timeout: 60 * time.Second},
+	}
+}
+
+// objectURL addresses key; keys are validKey, so need no escaping.
+func (s *s3Store) objectURL(key string) string {
+	if s.cfg.Prefix != "" {
+		key = s.cfg.Prefix + "/" + key
+	}
+	if s.cfg.Endpoint != "" {
+		return s.cfg.Endpoint + "/" + s.cfg.Bucket + "/" + key
+	}
+	return "https://" + s.cfg.Bucket + ".s3." + s.cfg.Region + ".amazonaws.com/" + key
+}
+
+// do signs and sends a request for key.
+func (s *s3Store) do(ctx context.Context, method, key string, body []byte, contentType string) (*http.Response, error) {
+	if err := checkKey(key); err != nil {
+		return nil, err
+	}
+	req, err := http.NewRequestWithContext(ctx, method, s.objectURL(key), bytes.NewReader(body))
+	if err != nil {
+		return nil, err
+	}
+	hash := emptySHA256
+	if body != nil {
+		sum := sha256.Sum256(body)
+		hash = hex.EncodeToString(sum[:])
+		req.ContentLength = int64(len(body))
+		req.Header.Set("Content-Type", contentType)
+	} else {
+		req.Body = http.NoBody
+	}
+	req.Header.Set("X-Amz-Content-Sha256", hash)
+	creds, err := s.creds.Retrieve(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("blob store: credentials: %w", err)
+	}
+	if err := s.signer.SignHTTP(ctx, creds, req, hash, s3Service, s.cfg.Region, time.Now()); err != nil {
+		return nil, fmt.Errorf("blob store: sign: %w", err)
+	}
+	return s.client.Do(req)
+}
+
+func (s *s3Store) Put(ctx context.Context, key string, data []byte, contentType string) error {
+	if data == nil {
+		data = []byte{}
+	}
+	resp, err := s.do(ctx, http.MethodPut, key, data, contentType)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode/100 != 2 {
+		return s3Error("put", resp)
+	}
+	return nil
+}
+
+func (s *s3Store) Get(ctx context.Context, key string) ([]byte, error) {
+	resp, err := s.do(ctx, http.MethodGet, key, nil, "")
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+	switch {
+	case resp.StatusCode == http.StatusNotFound:
+		return nil, ErrNotFound
+	case resp.StatusCode/100 != 2:
+		return nil, s3Error("get", resp)
+	}
+	return io.ReadAll(io.LimitReader(resp.Body, maxObjectSize))
+}
+
+func (s *s3Store) Delete(ctx context.Context, key string) error {
+	resp, err := s.do(ctx, http.MethodDelete, key, nil, "")
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode/100 != 2 && resp.StatusCode != http.StatusNotFound {
+		return s3Error("delete", resp)
+	}
+	return nil
+}
+
+// PresignGet returns a GET URL for key valid for ttl (at most 7 days).
+func (s *s3Store) PresignGet(ctx context.Context, key string, ttl time.Duration) (string, error) {
+	if err := checkKey(key); err != nil {
+		return "", err
+	}
+	ttl = min(max(ttl, time.Second), maxPresignTTL)
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, s.objectURL(key), nil)
+	if err != nil {
+		return "", err
+	}
+	q := req.URL.Query()
+	q.Set("X-Amz-Expires", strconv.Itoa(int(ttl/time.Second)))
+	req.URL.RawQuery = q.Encode()
+	creds, err := s.creds.Retrieve(ctx)
+	if err != nil {
+		return "", fmt.Errorf("blob store: credentials: %w", err)
+	}
+	signed, _, err := s.signer.PresignHTTP(ctx, creds, req, unsignedPayload, s3Service, s.cfg.Region, time.Now())
+	if err != nil {
+		return "", fmt.Errorf("blob store: presign: %w", err)
+	}
+	return signed, nil
+}
+
+// s3Error reports a failed request with the start of S3's XML error body.
+func s3Error(op string, resp *http.Response) error {
+	b, _ := io.ReadAll(io.LimitReader(resp.Body, 512))
+	return fmt.Errorf("blob store: %s: %s: %s", op, resp.Status, strings.TrimSpace(string(b)))
+}
@@ -11,6 +11,36 @@ type PoolConfig struct {
 	Code               string  `json:"code"`
 	Concurrency        uint32  `json:"concurrency"`
 	RateLimitPerMinute *uint32 `json:"rateLimitPerMinute,omitempty"`
+	// CPUSlots (Go extension) caps how many of the pool's deliveries run
+	// CPU-bound mediation middleware at once; 0 leaves it uncapped.
+	CPUSlots uint32 `json:"cpuSlots,omitempty"`
+	// Killed (Go extension) is the pool's kill switch: while set, the
+	// router holds the pool's messages undelivered, in order.
+	Killed bool `json:"killed,omitempty"`
+	// Region (Go extension) is the data-residency region the pool
+	// delivers from; only it may deliver messages carrying that region.
+	Region string `json:"region,omitempty"`
+	// RateProfiles (Go extension) vary the pool's rate with the time of
+	// day: inside a profile's window its rate applies, and
+	// RateLimitPerMinute everywhere else. See router.RateSchedule.
+	RateProfiles []RateProfile `json:"rateProfiles,omitempty"`
+	// Timezone (Go extension) is the IANA zone RateProfiles' times are
+	// in; empty is UTC.
+	Timezone string `json:"timezone,omitempty"`
+}
+
+// RateProfile is one time-of-day rate window of a pool (Go extension).
+type RateProfile struct {
+	Name string `json:"name"`
+	// Days is a cron day-of-week field — "1-5", "0,6" — with 0 (or 7)
+	// Sunday; "*" or empty is every day.
+	Days string `json:"days,omitempty"`
+	// Start and End are "HH:MM" wall-clock times. An End at or before
+	// Start runs past midnight, the window belonging to the day it starts.
+	Start string `json:"start"`
+	End   string `json:"end"`
+	// RateLimitPerMinute is the rate inside the window; nil is unlimited.
+	RateLimitPerMinute *uint32 `json:"rateLimitPerMinute,omitempty"`
 }
 
 // QueueConfig is the per-queue connection configuration.
@@ -27,6 +57,18 @@ type QueueConfig struct {
 	URI               string `json:"queueUri"`
 	Connections       uint32 `json:"connections"`
 	VisibilityTimeout uint32 `json:"visibilityTimeout"`
+	// WaitSeconds (Go extension) is the SQS long-poll wait, at most 20;
+	// 0 uses 20.
+	WaitSeconds uint32 `json:"waitSeconds,omitempty"`
+	// IdlePollMinMs / IdlePollMaxMs (Go extension) bound the pause after
+	// an empty poll: it starts at the minimum and doubles with each empty
+	// poll in a row up to the maximum, and drops back as soon as a poll
+	// returns messages. 0 uses the router's defaults (1s and 10s).
+	IdlePollMinMs uint32 `json:"idlePollMinMs,omitempty"`
+	IdlePollMaxMs uint32 `json:"idlePollMaxMs,omitempty"`
+	// Region (Go extension) tags the queue with a data-residency region;
+	// messages carrying a different region are held, not delivered.
+	Region string `json:"region,omitempty"`
 }
 
 // UnmarshalJSON accepts both the canonical camelCase keys (queueName,
@@ -42,6 +84,10 @@ func (q *QueueConfig) UnmarshalJSON(data []byte) error {
 		URI               *string `json:"uri"`
 		Connections       *uint32 `json:"connections"`
 		VisibilityTimeout *uint32 `json:"visibilityTimeout"`
+		WaitSeconds       uint32  `json:"waitSeconds"`
+		IdlePollMinMs     uint32  `json:"idlePollMinMs"`
+		IdlePollMaxMs     uint32  `json:"idlePollMaxMs"`
+		Region            string  `json:"region"`
 	}
 	if err := json.Unmarshal(data, &raw); err != nil {
 		return err
@@ -74,13 +120,55 @@ func (q *QueueConfig) UnmarshalJSON(data []byte) error {
 	} else {
 		q.VisibilityTimeout = 120
 	}
+	q.WaitSeconds, q.IdlePollMinMs, q.IdlePollMaxMs = raw.WaitSeconds, raw.IdlePollMinMs, raw.IdlePollMaxMs
+	q.Region = raw.Region
 	return nil
 }
 
 // RouterConfig is what the router fetches from its config source.
+// RoutingRules and ApplyGuard are Go extensions; routers that don't know
+// them ignore them.
 type RouterConfig struct {
 	ProcessingPools []PoolConfig  `json:"processingPools"`
 	Queues          []QueueConfig `json:"queues"`
+	RoutingRules    []RoutingRule `json:"routingRules,omitempty"`
+	ApplyGuard      *ApplyGuard   `json:"applyGuard,omitempty"`
+}
+
+// ApplyGuard asks the router to apply a config guarded: it keeps the
+// previous config, watches deliveries for a bake period, and rolls back if
+// the error rate or the buffered queue depth crosses a threshold. Zero
+// fields take the defaults in parentheses.
+type ApplyGuard struct {
+	// BakeSeconds is how long the new config is watched (120).
+	BakeSeconds uint32 `json:"bakeSeconds,omitempty"`
+	// MaxErrorRate is the share of mediations during the bake that may
+	// fail, 0–1 (0.25).
+	MaxErrorRate float64 `json:"maxErrorRate,omitempty"`
+	// MinSamples is how many mediations the bake must see before the
+	// error rate is judged (20).
+	MinSamples uint64 `json:"minSamples,omitempty"`
+	// MaxQueueDepth caps messages buffered across all pools; 0 skips the
+	// check.
+	MaxQueueDepth uint32 `json:"maxQueueDepth,omitempty"`
+}
+
+// RoutingRule assigns a pool, and optionally a queue, to messages
+// published without a pool code. Every non-empty predicate must match;
+// string predicates are path.Match globs ("*.example.com", "order.*").
+// Rules are tried by ascending Priority, ties in config order, and the
+// first match wins.
+type RoutingRule struct {
+	Name     string `json:"name"`
+	Priority int    `json:"priority,omitempty"`
+	// TargetHost matches the mediation target's hostname.
+	TargetHost string `json:"targetHost,omitempty"`
+	EventType  string `json:"eventType,omitempty"`
+	ClientID   string `json:"clientId,omitempty"`
+	// Attributes match payload fields the producer supplied, by key.
+	Attributes map[string]string `json:"attributes,omitempty"`
+	PoolCode   string            `json:"poolCode"`
+	QueueName  string            `json:"queueName,omitempty"`
 }
 
 // LeaderElectionConfig is the unified leader-election configuration
@@ -106,6 +194,38 @@ func NewLeaderElectionConfig(redisURL string) LeaderElectionConfig {
 	}
 }
 
+// MongoConfig is the MongoDB client configuration. Mirrors the Rust
+// MongoConfig; zero timeouts / pool sizes leave the driver default.
+type MongoConfig struct {
+	URI                      string
+	Database                 string
+	MaxPoolSize              uint64
+	MinPoolSize              uint64
+	ConnectTimeoutMS         uint64
+	ServerSelectionTimeoutMS uint64
+	RetryWrites              bool
+	RetryReads               bool
+	// Compressors is the wire-compression preference list ("zstd",
+	// "snappy", "zlib"), negotiated with the server; empty = off.
+	Compressors []string
+}
+
+// NewMongoConfig creates a config with sane defaults: a bounded pool,
+// fail-fast timeouts so an unreachable cluster surfaces in seconds rather
+// than the driver's 30s, and retryable reads/writes on.
+func NewMongoConfig(uri, database string) MongoConfig {
+	return MongoConfig{
+		URI:                      uri,
+		Database:                 database,
+		MaxPoolSize:              50,
+		MinPoolSize:              0,
+		ConnectTimeoutMS:         10_000,
+		ServerSelectionTimeoutMS: 10_000,
+		RetryWrites:              true,
+		RetryReads:               true,
+	}
+}
+
 // StallConfig controls stall detection in the router.
 type StallConfig struct {
 	Enabled               bool   `json:"enabled"`
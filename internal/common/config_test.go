@@ -32,6 +32,19 @@ func TestQueueConfigUnmarshal_LegacyAliases(t *testing.T) {
 	}
 }
 
+// TestQueueConfigUnmarshal_GoExtensions verifies the Go-only polling keys
+// decode, and stay zero (router defaults) when absent.
+func TestQueueConfigUnmarshal_GoExtensions(t *testing.T) {
+	const body = `{"queueUri":"sqs://orders","waitSeconds":5,"idlePollMinMs":500,"idlePollMaxMs":30000,"region":"eu"}`
+	var q QueueConfig
+	if err := json.Unmarshal([]byte(body), &q); err != nil {
+		t.Fatalf("unmarshal: %v", err)
+	}
+	if q.WaitSeconds != 5 || q.IdlePollMinMs != 500 || q.IdlePollMaxMs != 30000 || q.Region != "eu" {
+		t.Fatalf("got %+v", q)
+	}
+}
+
 // TestQueueConfigUnmarshal_Defaults mirrors the Rust QueueConfigResponse ->
 // QueueConfig conversion: name falls back to uri, connections -> 1,
 // visibilityTimeout -> 120 when absent.
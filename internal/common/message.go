@@ -8,7 +8,11 @@
 // byte-compatible.
 package common
 
-import "time"
+import (
+	"encoding/json"
+	"fmt"
+	"time"
+)
 
 // MediationType is the kind of mediation (currently only HTTP).
 type MediationType string
@@ -45,9 +49,28 @@ func (d DispatchMode) RequiresOrdering() bool {
 	return d == DispatchNextOnError || d == DispatchBlockOnError
 }
 
+// MessageSchemaVersion is the envelope version this build writes.
+// Envelopes without schemaVersion (Java's MessagePointer and Rust before
+// versioning) are version 0 and are upgraded on decode; see
+// messageUpgrades. Bump it, and add an upgrade, whenever a field changes
+// meaning or becomes required.
+const MessageSchemaVersion = 1
+
 // Message is the core message structure that flows through the system.
 // Compatible with Java's MessagePointer.
+//
+// Decoding is tolerant in both directions: unknown fields from a newer
+// producer are ignored, and older envelopes are upgraded to the current
+// version. Encoding stamps MessageSchemaVersion when SchemaVersion is
+// unset.
+//
+// ExpiresAt is optional: a message still undelivered at that instant is
+// dropped rather than mediated (see Expired). Older producers never set
+// it, so it needs no schema bump. Region is optional the same way: a
+// message carrying one is only delivered by a pool tagged with that
+// data-residency region, and never off a queue tagged with another.
 type Message struct {
+	SchemaVersion   int           `json:"schemaVersion,omitempty"`
 	ID              string        `json:"id"`
 	PoolCode        string        `json:"poolCode,omitempty"`
 	AuthToken       *string       `json:"authToken,omitempty"`
@@ -57,6 +80,66 @@ type Message struct {
 	MessageGroupID  *string       `json:"messageGroupId,omitempty"`
 	HighPriority    bool          `json:"highPriority,omitempty"`
 	DispatchMode    DispatchMode  `json:"dispatchMode,omitempty"`
+	ExpiresAt       *time.Time    `json:"expiresAt,omitempty"`
+	Region          *string       `json:"region,omitempty"`
+}
+
+// Expired reports whether the message carries an expiry that is at or
+// before now.
+func (m *Message) Expired(now time.Time) bool {
+	return m.ExpiresAt != nil && !m.ExpiresAt.After(now)
+}
+
+// messageUpgrades[v] upgrades a version-v envelope to version v+1.
+var messageUpgrades = []func(*Message){
+	// 0 → 1: Java and early Rust producers could omit mediationType;
+	// HTTP was the only kind.
+	func(m *Message) {
+		if m.MediationType == "" {
+			m.MediationType = MediationTypeHTTP
+		}
+	},
+}
+
+// wireMessage is Message without its JSON methods.
+type wireMessage Message
+
+// MarshalJSON stamps the current schema version on unversioned messages.
+func (m Message) MarshalJSON() ([]byte, error) {
+	w := wireMessage(m)
+	if w.SchemaVersion == 0 {
+		w.SchemaVersion = MessageSchemaVersion
+	}
+	return json.Marshal(w)
+}
+
+// UnmarshalJSON decodes any envelope version; see Upgrade.
+func (m *Message) UnmarshalJSON(b []byte) error {
+	var w wireMessage
+	if err := json.Unmarshal(b, &w); err != nil {
+		return err
+	}
+	*m = Message(w)
+	return m.Upgrade()
+}
+
+// Upgrade brings a decoded envelope to MessageSchemaVersion. Older
+// envelopes are upgraded; newer ones keep their version, so a re-publish
+// does not downgrade them, and their unknown fields are dropped by the
+// decoder. An unknown dispatch mode becomes IMMEDIATE, matching
+// ParseDispatchMode. Every envelope decoder calls it.
+func (m *Message) Upgrade() error {
+	if m.SchemaVersion < 0 {
+		return fmt.Errorf("message %s: invalid schemaVersion %d", m.ID, m.SchemaVersion)
+	}
+	for v := m.SchemaVersion; v < MessageSchemaVersion; v++ {
+		messageUpgrades[v](m)
+		m.SchemaVersion = v + 1
+	}
+	if m.DispatchMode != "" {
+		m.DispatchMode = ParseDispatchMode(string(m.DispatchMode))
+	}
+	return nil
 }
 
 // QueuedMessage is a Message received from a queue with broker tracking.
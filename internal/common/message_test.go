@@ -2,7 +2,11 @@ package common_test
 
 import (
 	"encoding/json"
+	"os"
+	"path/filepath"
 	"testing"
+	"time"
+	"unicode/utf8"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -19,6 +23,7 @@ func TestMessageJSONRoundtrip(t *testing.T) {
 	groupID := "group-1"
 
 	m := common.Message{
+		SchemaVersion:   common.MessageSchemaVersion,
 		ID:              "msg_01",
 		PoolCode:        "pool-a",
 		AuthToken:       &authToken,
@@ -35,6 +40,7 @@ func TestMessageJSONRoundtrip(t *testing.T) {
 	got := string(b)
 
 	// Field names are camelCase (matching Rust's #[serde(rename_all = "camelCase")]).
+	assert.Contains(t, got, `"schemaVersion":1`)
 	assert.Contains(t, got, `"id":"msg_01"`)
 	assert.Contains(t, got, `"poolCode":"pool-a"`)
 	assert.Contains(t, got, `"authToken":"bearer-token"`)
@@ -70,6 +76,92 @@ func TestMessageOmitsEmptyOptionals(t *testing.T) {
 	assert.NotContains(t, got, `"dispatchMode"`)
 }
 
+// TestMessageDecodesGoldenEnvelopes pins decoding of every envelope shape
+// a queue may still hold: Java MessagePointer, Rust before versioning,
+// the current version, and a newer producer's.
+func TestMessageDecodesGoldenEnvelopes(t *testing.T) {
+	token, secret, group := "eyJhbGciOiJIUzI1NiJ9.e30.sig", "shh", "order-123"
+	rustToken, rustGroup := "bearer-token", "group-1"
+	for file, want := range map[string]common.Message{
+		"java_message_pointer.json": {
+			SchemaVersion:   1,
+			ID:              "0HZXEQ5Y8JY5Z",
+			PoolCode:        "DEFAULT",
+			AuthToken:       &token,
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: "http://localhost:8080/api/dispatch/process",
+			MessageGroupID:  &group,
+		},
+		"rust_v0_minimal.json": {
+			SchemaVersion:   1,
+			ID:              "0HZXEQ5Y8JY60",
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: "http://localhost:8080/api/dispatch/process",
+		},
+		"rust_v0.json": {
+			SchemaVersion:   1,
+			ID:              "0HZXEQ5Y8JY61",
+			PoolCode:        "pool-a",
+			AuthToken:       &rustToken,
+			SigningSecret:   &secret,
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: "https://example.com/webhook",
+			MessageGroupID:  &rustGroup,
+			HighPriority:    true,
+			DispatchMode:    common.DispatchBlockOnError,
+		},
+		"v1.json": {
+			SchemaVersion:   1,
+			ID:              "0HZXEQ5Y8JY62",
+			PoolCode:        "pool-a",
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: "https://example.com/webhook",
+			MessageGroupID:  &rustGroup,
+			DispatchMode:    common.DispatchNextOnError,
+		},
+		"v2_future.json": {
+			SchemaVersion:   2,
+			ID:              "0HZXEQ5Y8JY63",
+			PoolCode:        "pool-a",
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: "https://example.com/webhook",
+			DispatchMode:    common.DispatchImmediate,
+		},
+	} {
+		raw, err := os.ReadFile(filepath.Join("testdata", "messages", file))
+		require.NoError(t, err)
+		var got common.Message
+		require.NoError(t, json.Unmarshal(raw, &got), file)
+		assert.Equal(t, want, got, file)
+	}
+}
+
+// TestMessageEncodesCurrentVersion checks the encoder against the golden
+// current envelope, and that re-publishing a newer envelope keeps its
+// version.
+func TestMessageEncodesCurrentVersion(t *testing.T) {
+	group := "group-1"
+	b, err := json.Marshal(common.Message{
+		ID:              "0HZXEQ5Y8JY62",
+		PoolCode:        "pool-a",
+		MediationType:   common.MediationTypeHTTP,
+		MediationTarget: "https://example.com/webhook",
+		MessageGroupID:  &group,
+		DispatchMode:    common.DispatchNextOnError,
+	})
+	require.NoError(t, err)
+	golden, err := os.ReadFile(filepath.Join("testdata", "messages", "v1.json"))
+	require.NoError(t, err)
+	assert.JSONEq(t, string(golden), string(b))
+
+	b, err = json.Marshal(common.Message{SchemaVersion: 2, ID: "m", MediationType: common.MediationTypeHTTP})
+	require.NoError(t, err)
+	assert.Contains(t, string(b), `"schemaVersion":2`)
+
+	var m common.Message
+	assert.Error(t, json.Unmarshal([]byte(`{"schemaVersion":-1,"id":"m"}`), &m))
+}
+
 func TestDispatchModeParseLenient(t *testing.T) {
 	assert.Equal(t, common.DispatchImmediate, common.ParseDispatchMode(""))
 	assert.Equal(t, common.DispatchImmediate, common.ParseDispatchMode("UNKNOWN_GIBBERISH"))
@@ -97,11 +189,32 @@ func TestParseDispatchStatusLenient(t *testing.T) {
 	assert.Equal(t, common.DispatchPending, common.ParseDispatchStatus("WHO_KNOWS"))
 }
 
+func TestMessageExpired(t *testing.T) {
+	now := time.Now()
+	m := common.Message{ID: "msg_03"}
+	assert.False(t, m.Expired(now), "no expiresAt never expires")
+
+	past, future := now.Add(-time.Second), now.Add(time.Minute)
+	m.ExpiresAt = &past
+	assert.True(t, m.Expired(now))
+	m.ExpiresAt = &now
+	assert.True(t, m.Expired(now), "expiry is inclusive")
+	m.ExpiresAt = &future
+	assert.False(t, m.Expired(now))
+
+	b, err := json.Marshal(m)
+	require.NoError(t, err)
+	var back common.Message
+	require.NoError(t, json.Unmarshal(b, &back))
+	require.NotNil(t, back.ExpiresAt)
+	assert.True(t, future.Equal(*back.ExpiresAt))
+}
+
 func TestOutboxStatusCodes(t *testing.T) {
 	for _, s := range []common.OutboxStatus{
 		common.OutboxPending, common.OutboxSuccess, common.OutboxBadRequest,
 		common.OutboxInternalError, common.OutboxUnauthorized, common.OutboxForbidden,
-		common.OutboxGatewayError, common.OutboxInProgress,
+		common.OutboxGatewayError, common.OutboxExpired, common.OutboxInProgress,
 	} {
 		assert.Equal(t, s, common.FromOutboxCode(s.Code()))
 	}
@@ -122,3 +235,99 @@ func TestParseOutboxItemTypeAliases(t *testing.T) {
 	_, ok := common.ParseOutboxItemType("UNKNOWN")
 	assert.False(t, ok)
 }
+
+// messageKeys is every key a Message may encode, in camelCase.
+var messageKeys = map[string]bool{
+	"schemaVersion": true, "id": true, "poolCode": true, "authToken": true,
+	"signingSecret": true, "mediationType": true, "mediationTarget": true,
+	"messageGroupId": true, "highPriority": true, "dispatchMode": true,
+}
+
+// FuzzMessageDecode feeds arbitrary envelopes to the decoder, which reads
+// every message a queue holds. Whatever decodes must re-encode with
+// camelCase keys and decode back to the same message.
+func FuzzMessageDecode(f *testing.F) {
+	files, err := os.ReadDir(filepath.Join("testdata", "messages"))
+	require.NoError(f, err)
+	for _, e := range files {
+		raw, err := os.ReadFile(filepath.Join("testdata", "messages", e.Name()))
+		require.NoError(f, err)
+		f.Add(raw)
+	}
+	f.Add([]byte(`{"ID":"upper","DispatchMode":"next_on_error","schemaVersion":0}`))
+	f.Add([]byte(`{"id":"m","authToken":null,"messageGroupId":"","schemaVersion":9}`))
+	f.Fuzz(func(t *testing.T, raw []byte) {
+		var m common.Message
+		if json.Unmarshal(raw, &m) != nil {
+			return
+		}
+		assert.GreaterOrEqual(t, m.SchemaVersion, common.MessageSchemaVersion)
+		assert.Contains(t, []common.DispatchMode{"", common.DispatchImmediate, common.DispatchNextOnError, common.DispatchBlockOnError}, m.DispatchMode)
+
+		b, err := json.Marshal(m)
+		require.NoError(t, err)
+		var keys map[string]json.RawMessage
+		require.NoError(t, json.Unmarshal(b, &keys))
+		for k := range keys {
+			assert.True(t, messageKeys[k], "unexpected key %q", k)
+		}
+		var back common.Message
+		require.NoError(t, json.Unmarshal(b, &back))
+		assert.Equal(t, m, back)
+	})
+}
+
+// FuzzMessageRoundTrip checks that any message this build publishes
+// decodes to itself.
+func FuzzMessageRoundTrip(f *testing.F) {
+	f.Add("msg_01", "pool-a", "https://example.com/webhook", "group-1", "tok", true, uint8(1))
+	f.Add("", "", "", "", "", false, uint8(0))
+	f.Add("id with \"quotes\" <&>", "ü", "http://[::1]:8080/", "g\x00", " ", false, uint8(2))
+	f.Fuzz(func(t *testing.T, id, pool, target, group, token string, high bool, mode uint8) {
+		for _, s := range []string{id, pool, target, group, token} {
+			if !utf8.ValidString(s) {
+				return // encoding/json replaces invalid UTF-8; not a round-trip
+			}
+		}
+		m := common.Message{
+			SchemaVersion:   common.MessageSchemaVersion,
+			ID:              id,
+			PoolCode:        pool,
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: target,
+			HighPriority:    high,
+			DispatchMode:    []common.DispatchMode{"", common.DispatchImmediate, common.DispatchNextOnError, common.DispatchBlockOnError}[mode%4],
+		}
+		if group != "" {
+			m.MessageGroupID = &group
+		}
+		if token != "" {
+			m.AuthToken = &token
+		}
+		b, err := json.Marshal(m)
+		require.NoError(t, err)
+		var back common.Message
+		require.NoError(t, json.Unmarshal(b, &back))
+		assert.Equal(t, m, back)
+	})
+}
+
+// FuzzOutboxStatusCode checks the DB code mapping: every code maps to a
+// status that maps back to itself, unknown codes are PENDING, and no
+// status is both retryable and terminal.
+func FuzzOutboxStatusCode(f *testing.F) {
+	for _, c := range []int{-1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 1 << 40} {
+		f.Add(c)
+	}
+	f.Fuzz(func(t *testing.T, c int) {
+		s := common.FromOutboxCode(c)
+		assert.Equal(t, s, common.FromOutboxCode(s.Code()))
+		if s.Code() != c {
+			assert.Equal(t, common.OutboxPending, s)
+		}
+		assert.False(t, s.IsRetryable() && s.IsTerminal(), s.String())
+		if s != common.OutboxPending {
+			assert.NotEqual(t, "PENDING", s.String())
+		}
+	})
+}
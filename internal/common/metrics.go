@@ -30,11 +30,13 @@ type WindowedMetrics struct {
 
 // EnhancedPoolMetrics is the rolling-window snapshot embedded in
 // PoolStats.Metrics. Mirrors `fc_common::EnhancedPoolMetrics`
-// (crates/fc-common/src/lib.rs:832).
+// (crates/fc-common/src/lib.rs:832). TotalExpired is a Go extension:
+// messages dropped unmediated because their expiresAt had passed.
 type EnhancedPoolMetrics struct {
 	TotalSuccess     uint64                `json:"totalSuccess"`
 	TotalFailure     uint64                `json:"totalFailure"`
 	TotalRateLimited uint64                `json:"totalRateLimited"`
+	TotalExpired     uint64                `json:"totalExpired"`
 	SuccessRate      float64               `json:"successRate"`
 	ProcessingTime   ProcessingTimeMetrics `json:"processingTime"`
 	Last5Min         WindowedMetrics       `json:"last5Min"`
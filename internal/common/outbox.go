@@ -12,6 +12,7 @@ const (
 	OutboxUnauthorized  OutboxStatus = 4
 	OutboxForbidden     OutboxStatus = 5
 	OutboxGatewayError  OutboxStatus = 6
+	OutboxExpired       OutboxStatus = 7
 	OutboxInProgress    OutboxStatus = 9
 )
 
@@ -21,7 +22,7 @@ func (s OutboxStatus) Code() int { return int(s) }
 // FromOutboxCode maps an integer to OutboxStatus; unknown codes default to PENDING.
 func FromOutboxCode(c int) OutboxStatus {
 	switch c {
-	case 0, 1, 2, 3, 4, 5, 6, 9:
+	case 0, 1, 2, 3, 4, 5, 6, 7, 9:
 		return OutboxStatus(c)
 	default:
 		return OutboxPending
@@ -37,10 +38,11 @@ func (s OutboxStatus) IsRetryable() bool {
 	return false
 }
 
-// IsTerminal reports whether this status will not be retried.
+// IsTerminal reports whether this status will not be retried. EXPIRED
+// marks an item whose payload expiresAt passed before it was delivered.
 func (s OutboxStatus) IsTerminal() bool {
 	switch s {
-	case OutboxSuccess, OutboxBadRequest, OutboxForbidden:
+	case OutboxSuccess, OutboxBadRequest, OutboxForbidden, OutboxExpired:
 		return true
 	}
 	return false
@@ -63,6 +65,8 @@ func (s OutboxStatus) String() string {
 		return "FORBIDDEN"
 	case OutboxGatewayError:
 		return "GATEWAY_ERROR"
+	case OutboxExpired:
+		return "EXPIRED"
 	case OutboxInProgress:
 		return "IN_PROGRESS"
 	default:
@@ -0,0 +1,65 @@
+package connect
+
+import (
+	"encoding/json"
+	"errors"
+	"net/http"
+
+	"github.com/go-chi/chi/v5"
+)
+
+// AdminHandler returns the connectors' health and control API.
+// StartConnectors serves it on FC_CONNECT_ADMIN_PORT, localhost only:
+//
+//	GET  /health                         — 200 when no connector is failing, else 503
+//	GET  /connectors                     — every connector's state and checkpoint
+//	GET  /connectors/{name}              — one connector
+//	POST /connectors/{name}/snapshot     — backfill the connector's sources, then
+//	                                       resume streaming
+func (r *Runner) AdminHandler() http.Handler {
+	m := chi.NewRouter()
+	m.Get("/health", func(w http.ResponseWriter, _ *http.Request) {
+		statuses := r.Statuses()
+		status := http.StatusOK
+		if Failing(statuses) > 0 {
+			status = http.StatusServiceUnavailable
+		}
+		writeAdminJSON(w, status, map[string]any{"connectors": statuses})
+	})
+	m.Get("/connectors", func(w http.ResponseWriter, _ *http.Request) {
+		writeAdminJSON(w, http.StatusOK, map[string]any{"connectors": r.Statuses()})
+	})
+	m.Get("/connectors/{name}", func(w http.ResponseWriter, req *http.Request) {
+		s, err := r.Status(chi.URLParam(req, "name"))
+		if errors.Is(err, ErrUnknownConnector) {
+			writeAdminJSON(w, http.StatusNotFound, map[string]string{"error": err.Error()})
+			return
+		}
+		writeAdminJSON(w, http.StatusOK, s)
+	})
+	m.Post("/connectors/{name}/snapshot", func(w http.ResponseWriter, req *http.Request) {
+		if err := r.RequestSnapshot(chi.URLParam(req, "name")); err != nil {
+			writeAdminJSON(w, http.StatusNotFound, map[string]string{"error": err.Error()})
+			return
+		}
+		writeAdminJSON(w, http.StatusAccepted, map[string]string{"status": string(SnapshotRequested)})
+	})
+	return m
+}
+
+// Failing counts the connectors in StateFailing.
+func Failing(statuses []Status) int {
+	n := 0
+	for _, s := range statuses {
+		if s.State == StateFailing {
+			n++
+		}
+	}
+	return n
+}
+
+func writeAdminJSON(w http.ResponseWriter, status int, v any) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	_ = json.NewEncoder(w).Encode(v)
+}
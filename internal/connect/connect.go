@@ -0,0 +1,266 @@
+// Package connect is the change data capture connector framework: it turns
+// row and document changes in a producer's own database into platform
+// events, for producers that can't adopt the outbox. Each connector reads
+// one source database — a MongoDB change stream or a Postgres logical
+// replication slot decoded by wal2json — and maps the changes of the
+// tables or collections it names to event types.
+//
+// Connectors are declared in the FC_CONNECT_CONFIG_PATH file (YAML or
+// JSON):
+//
+//	connectors:
+//	  - name: shop-orders
+//	    kind: postgres
+//	    uri: postgres://cdc@orders-db/shop
+//	    snapshot: true
+//	    mappings:
+//	      - source: public.orders
+//	        events: {insert: shop:orders:order:created, update: shop:orders:order:updated}
+//	        subject: orders.order.{id}
+//	        messageGroup: order:{id}
+//	  - name: crm
+//	    kind: mongo
+//	    uri: mongodb://crm-db:27017
+//	    database: crm
+//	    mappings:
+//	      - source: contacts
+//	        events: {insert: crm:contacts:contact:created, delete: crm:contacts:contact:deleted}
+//	        subject: contacts.contact.{_id.$oid}
+//
+// Delivery is at least once. A connector's position (a Mongo resume token
+// or a Postgres commit LSN) is checkpointed in msg_connector_checkpoints
+// after each batch of events is stored, so a restart replays at most the
+// batch in flight; replays carry the same deduplicationId.
+package connect
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"os"
+	"regexp"
+	"time"
+
+	"gopkg.in/yaml.v3"
+)
+
+// Kind is the type of source database a connector reads.
+type Kind string
+
+const (
+	KindMongo    Kind = "mongo"
+	KindPostgres Kind = "postgres"
+)
+
+// Op is the kind of change a source reports.
+type Op string
+
+const (
+	OpInsert Op = "insert"
+	OpUpdate Op = "update"
+	OpDelete Op = "delete"
+	// OpSnapshot is a row or document read by a backfill snapshot rather
+	// than a change.
+	OpSnapshot Op = "snapshot"
+)
+
+// DefaultBatchSize is how many changes a connector stores per batch when
+// its config doesn't say.
+const DefaultBatchSize = 500
+
+// DefaultPollInterval is how often a Postgres connector peeks an idle slot.
+const DefaultPollInterval = time.Second
+
+// Config is the FC_CONNECT_CONFIG_PATH document.
+type Config struct {
+	Connectors []ConnectorConfig `json:"connectors"`
+}
+
+// ConnectorConfig is one source database and the changes taken from it.
+type ConnectorConfig struct {
+	// Name identifies the connector in checkpoints, health and events.
+	Name string `json:"name"`
+	Kind Kind   `json:"kind"`
+	URI  string `json:"uri"`
+	// Database is the Mongo database watched. Mongo only.
+	Database string `json:"database,omitempty"`
+	// Slot is the Postgres logical replication slot, created with the
+	// wal2json plugin when missing. Defaults to fc_connect_<name>.
+	// Postgres only.
+	Slot string `json:"slot,omitempty"`
+	// Snapshot backfills the mapped tables or collections on the first
+	// start, before streaming. A snapshot can also be requested later
+	// through the admin API.
+	Snapshot bool `json:"snapshot,omitempty"`
+	// BatchSize is the most changes stored per batch. Defaults to
+	// DefaultBatchSize.
+	BatchSize int `json:"batchSize,omitempty"`
+	// PollIntervalMS is how often an idle Postgres slot is peeked.
+	// Defaults to DefaultPollInterval.
+	PollIntervalMS int       `json:"pollIntervalMs,omitempty"`
+	Mappings       []Mapping `json:"mappings"`
+}
+
+// Mapping turns the changes of one table or collection into events.
+type Mapping struct {
+	// Source is the collection (Mongo) or schema-qualified table
+	// (Postgres; an unqualified name is in public).
+	Source string `json:"source"`
+	// Events names the event type per Op. Ops without one are not
+	// emitted; snapshot rows use the insert type unless snapshot is set.
+	Events map[Op]string `json:"events"`
+	// Subject is the event subject, with {path} placeholders filled from
+	// the document by dotted path. Required.
+	Subject string `json:"subject"`
+	// MessageGroup is an optional template like Subject.
+	MessageGroup string `json:"messageGroup,omitempty"`
+	// EventSource is the CloudEvents source. Defaults to
+	// connect:<connector>.
+	EventSource string `json:"eventSource,omitempty"`
+	// Fields projects the document onto these top-level fields; empty
+	// keeps the whole document.
+	Fields []string `json:"fields,omitempty"`
+	// ClientID links the events to a platform client.
+	ClientID string `json:"clientId,omitempty"`
+}
+
+// LoadConfig reads and validates a connector config file.
+func LoadConfig(path string) (Config, error) {
+	raw, err := os.ReadFile(path)
+	if err != nil {
+		return Config{}, fmt.Errorf("read connector config: %w", err)
+	}
+	return ParseConfig(raw)
+}
+
+// ParseConfig decodes a YAML or JSON connector document (JSON is YAML)
+// and validates it.
+func ParseConfig(raw []byte) (Config, error) {
+	var doc any
+	if err := yaml.Unmarshal(raw, &doc); err != nil {
+		return Config{}, fmt.Errorf("parse connector config: %w", err)
+	}
+	asJSON, err := json.Marshal(doc)
+	if err != nil {
+		return Config{}, fmt.Errorf("parse connector config: %w", err)
+	}
+	var c Config
+	dec := json.NewDecoder(bytes.NewReader(asJSON))
+	dec.DisallowUnknownFields()
+	if err := dec.Decode(&c); err != nil {
+		return Config{}, fmt.Errorf("parse connector config: %w", err)
+	}
+	return c, c.Validate()
+}
+
+var namePattern = regexp.MustCompile(`^[a-z0-9][a-z0-9_-]{0,62}$`)
+
+// Validate checks names are unique and every connector and mapping is
+// complete for its kind.
+func (c Config) Validate() error {
+	seen := map[string]bool{}
+	for i, cc := range c.Connectors {
+		if !namePattern.MatchString(cc.Name) {
+			return fmt.Errorf("connector %d: name %q must be lowercase letters, digits, '-' or '_'", i, cc.Name)
+		}
+		if seen[cc.Name] {
+			return fmt.Errorf("connector %q: duplicate name", cc.Name)
+		}
+		seen[cc.Name] = true
+		if err := cc.validate(); err != nil {
+			return fmt.Errorf("connector %q: %w", cc.Name, err)
+		}
+	}
+	return nil
+}
+
+func (cc ConnectorConfig) validate() error {
+	if cc.URI == "" {
+		return fmt.Errorf("uri is required")
+	}
+	switch cc.Kind {
+	case KindMongo:
+		if cc.Database == "" {
+			return fmt.Errorf("database is required for a mongo connector")
+		}
+		if cc.Slot != "" {
+			return fmt.Errorf("slot applies to postgres connectors only")
+		}
+	case KindPostgres:
+		if cc.Database != "" {
+			return fmt.Errorf("database applies to mongo connectors only; name it in the uri")
+		}
+	default:
+		return fmt.Errorf("unknown kind %q (want mongo|postgres)", cc.Kind)
+	}
+	if len(cc.Mappings) == 0 {
+		return fmt.Errorf("at least one mapping is required")
+	}
+	sources := map[string]bool{}
+	for i, m := range cc.Mappings {
+		src := m.Source
+		if cc.Kind == KindPostgres {
+			src = qualifiedTable(src)
+		}
+		if m.Source == "" {
+			return fmt.Errorf("mapping %d: source is required", i)
+		}
+		if sources[src] {
+			return fmt.Errorf("mapping %d: source %q is mapped twice", i, m.Source)
+		}
+		sources[src] = true
+		if len(m.Events) == 0 {
+			return fmt.Errorf("mapping %q: events is required", m.Source)
+		}
+		for op, typ := range m.Events {
+			switch op {
+			case OpInsert, OpUpdate, OpDelete, OpSnapshot:
+			default:
+				return fmt.Errorf("mapping %q: unknown op %q (want insert|update|delete|snapshot)", m.Source, op)
+			}
+			if typ == "" {
+				return fmt.Errorf("mapping %q: op %q has no event type", m.Source, op)
+			}
+		}
+		if m.Subject == "" {
+			return fmt.Errorf("mapping %q: subject is required", m.Source)
+		}
+	}
+	return nil
+}
+
+// slot is the replication slot a Postgres connector reads.
+func (cc ConnectorConfig) slot() string {
+	if cc.Slot != "" {
+		return cc.Slot
+	}
+	return "fc_connect_" + cc.Name
+}
+
+func (cc ConnectorConfig) batchSize() int {
+	if cc.BatchSize > 0 {
+		return cc.BatchSize
+	}
+	return DefaultBatchSize
+}
+
+func (cc ConnectorConfig) pollInterval() time.Duration {
+	if cc.PollIntervalMS > 0 {
+		return time.Duration(cc.PollIntervalMS) * time.Millisecond
+	}
+	return DefaultPollInterval
+}
+
+// sources lists the mapped tables or collections, as the source reports
+// them in Change.Source.
+func (cc ConnectorConfig) sources() []string {
+	out := make([]string, 0, len(cc.Mappings))
+	for _, m := range cc.Mappings {
+		if cc.Kind == KindPostgres {
+			out = append(out, qualifiedTable(m.Source))
+			continue
+		}
+		out = append(out, m.Source)
+	}
+	return out
+}
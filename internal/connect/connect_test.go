@@ -0,0 +1,214 @@
+package connect
+
+import (
+	"context"
+	"encoding/json"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+)
+
+const configYAML = `
+connectors:
+  - name: shop
+    kind: postgres
+    uri: postgres://cdc@db/shop
+    snapshot: true
+    mappings:
+      - source: orders
+        events: {insert: shop:orders:order:created, delete: shop:orders:order:deleted}
+        subject: orders.order.{id}
+        messageGroup: order:{customer.id}
+        fields: [id, status]
+  - name: crm
+    kind: mongo
+    uri: mongodb://crm:27017
+    database: crm
+    mappings:
+      - source: contacts
+        events: {update: crm:contacts:contact:updated}
+        subject: contacts.{_id.$oid}
+`
+
+func TestParseConfig(t *testing.T) {
+	cfg, err := ParseConfig([]byte(configYAML))
+	require.NoError(t, err)
+	require.Len(t, cfg.Connectors, 2)
+	assert.Equal(t, "fc_connect_shop", cfg.Connectors[0].slot())
+	assert.Equal(t, []string{"public.orders"}, cfg.Connectors[0].sources(), "an unqualified table is in public")
+	assert.Equal(t, []string{"contacts"}, cfg.Connectors[1].sources())
+	assert.Equal(t, DefaultBatchSize, cfg.Connectors[1].batchSize())
+}
+
+func TestParseConfigRejects(t *testing.T) {
+	for name, doc := range map[string]string{
+		"unknown field":  `connectors: [{name: a, kind: mongo, uri: x, database: d, typo: 1, mappings: [{source: c, events: {insert: t}, subject: s}]}]`,
+		"bad name":       `connectors: [{name: "A B", kind: mongo, uri: x, database: d, mappings: [{source: c, events: {insert: t}, subject: s}]}]`,
+		"unknown kind":   `connectors: [{name: a, kind: mysql, uri: x, mappings: [{source: c, events: {insert: t}, subject: s}]}]`,
+		"mongo no db":    `connectors: [{name: a, kind: mongo, uri: x, mappings: [{source: c, events: {insert: t}, subject: s}]}]`,
+		"unknown op":     `connectors: [{name: a, kind: mongo, uri: x, database: d, mappings: [{source: c, events: {upsert: t}, subject: s}]}]`,
+		"no subject":     `connectors: [{name: a, kind: mongo, uri: x, database: d, mappings: [{source: c, events: {insert: t}}]}]`,
+		"mapped twice":   `connectors: [{name: a, kind: postgres, uri: x, mappings: [{source: t, events: {insert: t}, subject: s}, {source: public.t, events: {delete: t}, subject: s}]}]`,
+		"duplicate name": `connectors: [{name: a, kind: postgres, uri: x, mappings: [{source: t, events: {insert: t}, subject: s}]}, {name: a, kind: postgres, uri: y, mappings: [{source: t, events: {insert: t}, subject: s}]}]`,
+	} {
+		_, err := ParseConfig([]byte(doc))
+		assert.Error(t, err, name)
+	}
+}
+
+func TestMappingEvent(t *testing.T) {
+	cfg, err := ParseConfig([]byte(configYAML))
+	require.NoError(t, err)
+	m := cfg.Connectors[0].Mappings[0]
+	now := time.Date(2026, 10, 16, 12, 0, 0, 0, time.UTC)
+	doc := map[string]any{"id": json.Number("42"), "status": "NEW", "secret": "x", "customer": map[string]any{"id": "c-7"}}
+
+	e, err := m.Event("shop", Change{ID: "0/16B3748", Op: OpInsert, Source: "public.orders", Document: doc}, now)
+	require.NoError(t, err)
+	require.NotNil(t, e)
+	assert.Equal(t, "shop:orders:order:created", e.Type)
+	assert.Equal(t, "connect:shop", e.Source)
+	assert.Equal(t, "orders.order.42", e.Subject)
+	require.NotNil(t, e.MessageGroup)
+	assert.Equal(t, "order:c-7", *e.MessageGroup)
+	assert.JSONEq(t, `{"id": 42, "status": "NEW"}`, string(e.Data), "fields projects the document")
+	assert.Equal(t, "shop:0/16B3748", e.DeduplicationID)
+	assert.True(t, now.Equal(e.Time), "a change without a time takes now")
+	assert.Contains(t, e.Context, event.ContextEntry{Key: ContextOp, Value: "insert"})
+
+	snap, err := m.Event("shop", Change{ID: "s", Op: OpSnapshot, Source: "public.orders", Document: doc}, now)
+	require.NoError(t, err)
+	assert.Equal(t, "shop:orders:order:created", snap.Type, "snapshot rows fall back to the insert type")
+
+	skipped, err := m.Event("shop", Change{ID: "u", Op: OpUpdate, Source: "public.orders", Document: doc}, now)
+	require.NoError(t, err)
+	assert.Nil(t, skipped, "an op without an event type isn't emitted")
+}
+
+func TestRender(t *testing.T) {
+	doc := map[string]any{"_id": map[string]any{"$oid": "65f0"}, "n": 1.5, "ok": true}
+	assert.Equal(t, "c.65f0", render("c.{_id.$oid}", doc))
+	assert.Equal(t, "1.5/true/", render("{n}/{ok}/{missing}", doc))
+}
+
+// memStore is an in-memory Store.
+type memStore struct {
+	mu  sync.Mutex
+	cps map[string]Checkpoint
+}
+
+func (s *memStore) Load(_ context.Context, name string) (*Checkpoint, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	cp, ok := s.cps[name]
+	if !ok {
+		return nil, nil
+	}
+	return &cp, nil
+}
+
+func (s *memStore) Save(_ context.Context, cp *Checkpoint) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.cps[cp.Connector] = *cp
+	return nil
+}
+
+func (s *memStore) get(name string) Checkpoint {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.cps[name]
+}
+
+// memEvents collects inserted events.
+type memEvents struct {
+	mu     sync.Mutex
+	events []event.Event
+}
+
+func (m *memEvents) InsertBatch(_ context.Context, events []event.Event) (int, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.events = append(m.events, events...)
+	return len(events), nil
+}
+
+func (m *memEvents) dedupIDs() []string {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	out := make([]string, 0, len(m.events))
+	for _, e := range m.events {
+		out = append(out, e.DeduplicationID)
+	}
+	return out
+}
+
+// fakeSource snapshots one row and streams one change, then waits.
+type fakeSource struct {
+	streamedFrom chan string
+}
+
+func (f *fakeSource) Snapshot(ctx context.Context, emit func(context.Context, []Change) error) (string, error) {
+	err := emit(ctx, []Change{{ID: "public.orders:1", Op: OpSnapshot, Source: "public.orders", Document: map[string]any{"id": 1}}})
+	return "0/10", err
+}
+
+func (f *fakeSource) Stream(ctx context.Context, position string, emit func(context.Context, []Change, string) error) error {
+	f.streamedFrom <- position
+	if position == "0/10" {
+		if err := emit(ctx, []Change{{ID: "0/20", Op: OpInsert, Source: "public.orders", Document: map[string]any{"id": 2}}}, "0/30"); err != nil {
+			return err
+		}
+	}
+	<-ctx.Done()
+	return ctx.Err()
+}
+
+func (f *fakeSource) Close(context.Context) error { return nil }
+
+func TestRunnerSnapshotsThenStreams(t *testing.T) {
+	cfg, err := ParseConfig([]byte(configYAML))
+	require.NoError(t, err)
+	cfg.Connectors = cfg.Connectors[:1]
+	store := &memStore{cps: map[string]Checkpoint{}}
+	events := &memEvents{}
+	src := &fakeSource{streamedFrom: make(chan string, 4)}
+	r := NewRunner(RunnerDeps{
+		Store: store, Events: events,
+		Open: func(context.Context, ConnectorConfig) (Source, error) { return src, nil },
+	}, cfg)
+
+	ctx, cancel := context.WithCancel(t.Context())
+	done := make(chan struct{})
+	go func() { r.Run(ctx); close(done) }()
+
+	assert.Equal(t, "0/10", <-src.streamedFrom, "streaming resumes from the snapshot's position")
+	require.Eventually(t, func() bool {
+		cp := store.get("shop")
+		return cp.Position != nil && *cp.Position == "0/30"
+	}, 2*time.Second, 10*time.Millisecond)
+	cp := store.get("shop")
+	assert.Equal(t, SnapshotCompleted, cp.SnapshotStatus)
+	assert.Equal(t, int64(2), cp.Emitted)
+	ids := events.dedupIDs()
+	require.Len(t, ids, 2)
+	assert.Regexp(t, `^shop:snapshot:\d+:public.orders:1$`, ids[0])
+	assert.Equal(t, "shop:0/20", ids[1])
+
+	s, err := r.Status("shop")
+	require.NoError(t, err)
+	assert.Equal(t, StateStreaming, s.State)
+
+	require.NoError(t, r.RequestSnapshot("shop"))
+	assert.Equal(t, "0/10", <-src.streamedFrom, "a requested snapshot runs before streaming resumes")
+	require.Eventually(t, func() bool { return len(events.dedupIDs()) == 4 }, 2*time.Second, 10*time.Millisecond)
+	assert.ErrorIs(t, r.RequestSnapshot("nope"), ErrUnknownConnector)
+
+	cancel()
+	<-done
+}
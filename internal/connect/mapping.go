@@ -0,0 +1,156 @@
+package connect
+
+import (
+	"encoding/json"
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// Change is one row or document change read from a source.
+type Change struct {
+	// ID identifies the change within its connector, stably across
+	// replays: the event's deduplicationId is derived from it.
+	ID string
+	Op Op
+	// Source is the collection or schema-qualified table changed.
+	Source string
+	// Document is the row or document after the change; for a delete,
+	// its key.
+	Document map[string]any
+	// Time is when the source committed the change; zero when it doesn't
+	// say.
+	Time time.Time
+}
+
+// Context keys stamped on every connector event.
+const (
+	ContextConnector = "connector"
+	ContextSource    = "connectorSource"
+	ContextOp        = "connectorOp"
+)
+
+var placeholder = regexp.MustCompile(`\{([^{}]+)\}`)
+
+// eventType is the event type m emits for op; empty when op isn't mapped.
+func (m Mapping) eventType(op Op) string {
+	if t, ok := m.Events[op]; ok {
+		return t
+	}
+	if op == OpSnapshot {
+		return m.Events[OpInsert]
+	}
+	return ""
+}
+
+// Event maps ch to the event connector emits for it, or nil when the
+// mapping doesn't emit ch's op.
+func (m Mapping) Event(connector string, ch Change, now time.Time) (*event.Event, error) {
+	typ := m.eventType(ch.Op)
+	if typ == "" {
+		return nil, nil
+	}
+	doc := ch.Document
+	if len(m.Fields) > 0 && ch.Op != OpDelete {
+		doc = make(map[string]any, len(m.Fields))
+		for _, f := range m.Fields {
+			if v, ok := ch.Document[f]; ok {
+				doc[f] = v
+			}
+		}
+	}
+	data, err := json.Marshal(doc)
+	if err != nil {
+		return nil, fmt.Errorf("%s change %s: marshal document: %w", ch.Source, ch.ID, err)
+	}
+	source := m.EventSource
+	if source == "" {
+		source = "connect:" + connector
+	}
+	t := ch.Time
+	if t.IsZero() {
+		t = now
+	}
+	e := &event.Event{
+		ID:              tsid.GenerateUntyped(),
+		SpecVersion:     "1.0",
+		Type:            typ,
+		Source:          source,
+		Subject:         render(m.Subject, ch.Document),
+		Time:            t.UTC(),
+		Data:            data,
+		DeduplicationID: connector + ":" + ch.ID,
+		Context: []event.ContextEntry{
+			{Key: ContextConnector, Value: connector},
+			{Key: ContextSource, Value: ch.Source},
+			{Key: ContextOp, Value: string(ch.Op)},
+		},
+		CreatedAt: now,
+	}
+	if m.MessageGroup != "" {
+		group := render(m.MessageGroup, ch.Document)
+		e.MessageGroup = &group
+	}
+	if m.ClientID != "" {
+		clientID := m.ClientID
+		e.ClientID = &clientID
+	}
+	return e, nil
+}
+
+// render fills tmpl's {path} placeholders from doc. A missing path
+// renders empty.
+func render(tmpl string, doc map[string]any) string {
+	return placeholder.ReplaceAllStringFunc(tmpl, func(p string) string {
+		v, ok := lookup(doc, strings.TrimSpace(p[1:len(p)-1]))
+		if !ok {
+			return ""
+		}
+		return scalar(v)
+	})
+}
+
+// lookup walks a dotted path through nested objects.
+func lookup(doc map[string]any, path string) (any, bool) {
+	var cur any = doc
+	for _, part := range strings.Split(path, ".") {
+		obj, ok := cur.(map[string]any)
+		if !ok {
+			return nil, false
+		}
+		if cur, ok = obj[part]; !ok {
+			return nil, false
+		}
+	}
+	return cur, true
+}
+
+func scalar(v any) string {
+	switch x := v.(type) {
+	case nil:
+		return ""
+	case string:
+		return x
+	case float64:
+		return strconv.FormatFloat(x, 'f', -1, 64)
+	case json.Number:
+		return x.String()
+	case bool:
+		return strconv.FormatBool(x)
+	}
+	raw, _ := json.Marshal(v)
+	return string(raw)
+}
+
+// qualifiedTable puts an unqualified Postgres table in public.
+func qualifiedTable(name string) string {
+	if strings.Contains(name, ".") {
+		return name
+	}
+	return "public." + name
+}
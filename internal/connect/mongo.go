@@ -0,0 +1,245 @@
+package connect
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"go.mongodb.org/mongo-driver/bson"
+	"go.mongodb.org/mongo-driver/bson/primitive"
+	"go.mongodb.org/mongo-driver/mongo"
+	"go.mongodb.org/mongo-driver/mongo/options"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	outboxmongo "github.com/flowcatalyst/flowcatalyst-go/internal/outbox/mongo"
+)
+
+// idleCheckpoint is how long a quiet change stream goes before its
+// position is stored anyway, so a restart resumes from a token still in
+// the oplog.
+const idleCheckpoint = time.Minute
+
+// mongoSource watches a database's change stream, filtered to the mapped
+// collections. Needs a replica set or sharded cluster.
+type mongoSource struct {
+	cfg    ConnectorConfig
+	client *mongo.Client
+	db     *mongo.Database
+}
+
+func openMongo(ctx context.Context, cfg ConnectorConfig) (*mongoSource, error) {
+	client, err := mongo.Connect(ctx, outboxmongo.ClientOptions(common.NewMongoConfig(cfg.URI, cfg.Database)))
+	if err != nil {
+		return nil, fmt.Errorf("mongo connect: %w", err)
+	}
+	return &mongoSource{cfg: cfg, client: client, db: client.Database(cfg.Database)}, nil
+}
+
+// changeDoc is the part of a change event the source reads.
+type changeDoc struct {
+	ID            bson.Raw `bson:"_id"`
+	OperationType string   `bson:"operationType"`
+	NS            struct {
+		Coll string `bson:"coll"`
+	} `bson:"ns"`
+	DocumentKey  bson.Raw            `bson:"documentKey"`
+	FullDocument bson.RawValue       `bson:"fullDocument"`
+	ClusterTime  primitive.Timestamp `bson:"clusterTime"`
+}
+
+func (s *mongoSource) pipeline() mongo.Pipeline {
+	colls := bson.A{}
+	for _, c := range s.cfg.sources() {
+		colls = append(colls, c)
+	}
+	return mongo.Pipeline{{{Key: "$match", Value: bson.D{
+		{Key: "ns.coll", Value: bson.D{{Key: "$in", Value: colls}}},
+		{Key: "operationType", Value: bson.D{{Key: "$in", Value: bson.A{"insert", "update", "replace", "delete"}}}},
+	}}}}
+}
+
+func (s *mongoSource) watch(ctx context.Context, position string) (*mongo.ChangeStream, error) {
+	opts := options.ChangeStream().
+		SetFullDocument(options.UpdateLookup).
+		SetBatchSize(int32(min(s.cfg.batchSize(), 10_000))). //nolint:gosec // G115: clamped above
+		SetMaxAwaitTime(time.Second)
+	if position != "" {
+		var token bson.Raw
+		if err := bson.UnmarshalExtJSON([]byte(position), true, &token); err != nil {
+			return nil, fmt.Errorf("decode resume token: %w", err)
+		}
+		opts.SetResumeAfter(token)
+	}
+	cs, err := s.db.Watch(ctx, s.pipeline(), opts)
+	if err != nil {
+		return nil, fmt.Errorf("watch %s: %w", s.cfg.Database, err)
+	}
+	return cs, nil
+}
+
+// Snapshot implements Source: the change stream is opened first for its
+// resume token, then each collection is read in _id order.
+func (s *mongoSource) Snapshot(ctx context.Context, emit func(ctx context.Context, batch []Change) error) (string, error) {
+	cs, err := s.watch(ctx, "")
+	if err != nil {
+		return "", err
+	}
+	token := cs.ResumeToken()
+	_ = cs.Close(ctx)
+	if token == nil {
+		return "", errors.New("server returned no resume token; change streams need MongoDB 4.0.7 or later")
+	}
+	position, err := extJSON(token)
+	if err != nil {
+		return "", err
+	}
+	size := s.cfg.batchSize()
+	for _, coll := range s.cfg.sources() {
+		cur, err := s.db.Collection(coll).Find(ctx, bson.D{},
+			options.Find().SetSort(bson.D{{Key: "_id", Value: 1}}).SetBatchSize(int32(min(size, 10_000)))) //nolint:gosec // G115: clamped above
+		if err != nil {
+			return "", fmt.Errorf("read %s: %w", coll, err)
+		}
+		batch := make([]Change, 0, size)
+		for cur.Next(ctx) {
+			doc, err := documentJSON(cur.Current)
+			if err != nil {
+				_ = cur.Close(ctx)
+				return "", fmt.Errorf("read %s: %w", coll, err)
+			}
+			batch = append(batch, Change{ID: coll + ":" + scalar(doc["_id"]), Op: OpSnapshot, Source: coll, Document: doc})
+			if len(batch) == size {
+				if err := emit(ctx, batch); err != nil {
+					_ = cur.Close(ctx)
+					return "", err
+				}
+				batch = make([]Change, 0, size)
+			}
+		}
+		err = cur.Err()
+		_ = cur.Close(ctx)
+		if err != nil {
+			return "", fmt.Errorf("read %s: %w", coll, err)
+		}
+		if len(batch) > 0 {
+			if err := emit(ctx, batch); err != nil {
+				return "", err
+			}
+		}
+	}
+	return position, nil
+}
+
+// Stream implements Source. A batch is handed over once it is full or
+// the server has nothing more buffered; a quiet stream still hands over
+// an empty batch every idleCheckpoint so its position keeps up.
+func (s *mongoSource) Stream(ctx context.Context, position string, emit func(ctx context.Context, batch []Change, position string) error) error {
+	cs, err := s.watch(ctx, position)
+	if err != nil {
+		return err
+	}
+	defer func() { _ = cs.Close(context.WithoutCancel(ctx)) }()
+	size := s.cfg.batchSize()
+	batch := make([]Change, 0, size)
+	lastEmit := time.Now()
+	for {
+		switch {
+		case cs.TryNext(ctx):
+			var cd changeDoc
+			if err := cs.Decode(&cd); err != nil {
+				return fmt.Errorf("decode change: %w", err)
+			}
+			ch, err := toChange(cd)
+			if err != nil {
+				return err
+			}
+			batch = append(batch, ch)
+			if len(batch) < size && cs.RemainingBatchLength() > 0 {
+				continue
+			}
+		case cs.Err() != nil:
+			return cs.Err()
+		case ctx.Err() != nil:
+			return ctx.Err()
+		case len(batch) == 0 && time.Since(lastEmit) < idleCheckpoint:
+			continue
+		}
+		token := cs.ResumeToken()
+		if token == nil && len(batch) == 0 {
+			lastEmit = time.Now()
+			continue
+		}
+		position, err := extJSON(token)
+		if err != nil {
+			return err
+		}
+		if err := emit(ctx, batch, position); err != nil {
+			return err
+		}
+		batch = make([]Change, 0, size)
+		lastEmit = time.Now()
+	}
+}
+
+// Close implements Source.
+func (s *mongoSource) Close(ctx context.Context) error { return s.client.Disconnect(ctx) }
+
+// toChange maps a change event. Updates and replaces both report the
+// whole document after the change (fullDocument updateLookup); one
+// deleted before the lookup reports its key.
+func toChange(cd changeDoc) (Change, error) {
+	id, ok := cd.ID.Lookup("_data").StringValueOK()
+	if !ok {
+		id = cd.ID.String()
+	}
+	ch := Change{ID: id, Source: cd.NS.Coll}
+	if cd.ClusterTime.T > 0 {
+		ch.Time = time.Unix(int64(cd.ClusterTime.T), 0).UTC()
+	}
+	raw := cd.DocumentKey
+	if cd.FullDocument.Type == bson.TypeEmbeddedDocument {
+		raw = cd.FullDocument.Document()
+	}
+	switch cd.OperationType {
+	case "insert":
+		ch.Op = OpInsert
+	case "update", "replace":
+		ch.Op = OpUpdate
+	case "delete":
+		ch.Op = OpDelete
+		raw = cd.DocumentKey
+	default:
+		return Change{}, fmt.Errorf("unexpected change %q", cd.OperationType)
+	}
+	doc, err := documentJSON(raw)
+	if err != nil {
+		return Change{}, fmt.Errorf("change %s: %w", id, err)
+	}
+	ch.Document = doc
+	return ch, nil
+}
+
+// documentJSON renders a BSON document as relaxed extended JSON, the
+// shape events carry it in: ObjectIds are {"$oid": …}, numbers plain.
+func documentJSON(raw bson.Raw) (map[string]any, error) {
+	js, err := bson.MarshalExtJSON(raw, false, false)
+	if err != nil {
+		return nil, fmt.Errorf("render document: %w", err)
+	}
+	doc, err := decodeObject(js)
+	if err != nil {
+		return nil, fmt.Errorf("render document: %w", err)
+	}
+	return doc, nil
+}
+
+// extJSON renders a resume token as canonical extended JSON, the form
+// positions are stored in.
+func extJSON(token bson.Raw) (string, error) {
+	js, err := bson.MarshalExtJSON(token, true, false)
+	if err != nil {
+		return "", fmt.Errorf("encode resume token: %w", err)
+	}
+	return string(js), nil
+}
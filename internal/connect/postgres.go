@@ -0,0 +1,259 @@
+package connect
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// pgSource reads a logical replication slot decoded by wal2json (format
+// version 2) over plain SQL: changes are peeked, handed over, and only
+// then consumed, so a failed batch is read again. The slot is the
+// source's own checkpoint; the stored position mirrors it for health.
+type pgSource struct {
+	cfg  ConnectorConfig
+	pool *pgxpool.Pool
+}
+
+func openPostgres(ctx context.Context, cfg ConnectorConfig) (*pgSource, error) {
+	pool, err := pgxpool.New(ctx, cfg.URI)
+	if err != nil {
+		return nil, fmt.Errorf("postgres connect: %w", err)
+	}
+	return &pgSource{cfg: cfg, pool: pool}, nil
+}
+
+// walChange is one wal2json format-version 2 row.
+type walChange struct {
+	Action    string      `json:"action"`
+	Schema    string      `json:"schema"`
+	Table     string      `json:"table"`
+	Timestamp string      `json:"timestamp"`
+	Columns   []walColumn `json:"columns"`
+	Identity  []walColumn `json:"identity"`
+}
+
+type walColumn struct {
+	Name  string `json:"name"`
+	Value any    `json:"value"`
+}
+
+// ensureSlot creates the connector's slot when it is missing and returns
+// the position it is confirmed up to.
+func (s *pgSource) ensureSlot(ctx context.Context) (string, error) {
+	var plugin, lsn *string
+	err := s.pool.QueryRow(ctx,
+		`SELECT plugin, confirmed_flush_lsn::text FROM pg_replication_slots WHERE slot_name = $1`,
+		s.cfg.slot()).Scan(&plugin, &lsn)
+	switch {
+	case err == nil:
+		if plugin == nil || *plugin != "wal2json" {
+			return "", fmt.Errorf("replication slot %q does not use wal2json", s.cfg.slot())
+		}
+		if lsn == nil {
+			return "", nil
+		}
+		return *lsn, nil
+	case !errors.Is(err, pgx.ErrNoRows):
+		return "", fmt.Errorf("find replication slot: %w", err)
+	}
+	var created string
+	if err := s.pool.QueryRow(ctx,
+		`SELECT lsn::text FROM pg_create_logical_replication_slot($1, 'wal2json')`,
+		s.cfg.slot()).Scan(&created); err != nil {
+		return "", fmt.Errorf("create replication slot %q: %w", s.cfg.slot(), err)
+	}
+	return created, nil
+}
+
+// Snapshot implements Source: the slot is created first, so changes
+// made while the tables are read wait in it.
+func (s *pgSource) Snapshot(ctx context.Context, emit func(ctx context.Context, batch []Change) error) (string, error) {
+	position, err := s.ensureSlot(ctx)
+	if err != nil {
+		return "", err
+	}
+	size := s.cfg.batchSize()
+	for _, table := range s.cfg.sources() {
+		ident := pgx.Identifier(strings.SplitN(table, ".", 2)).Sanitize()
+		rows, err := s.pool.Query(ctx, `SELECT to_jsonb(t)::text FROM `+ident+` t`)
+		if err != nil {
+			return "", fmt.Errorf("read %s: %w", table, err)
+		}
+		batch := make([]Change, 0, size)
+		n := 0
+		for rows.Next() {
+			var raw string
+			if err := rows.Scan(&raw); err != nil {
+				rows.Close()
+				return "", fmt.Errorf("read %s: %w", table, err)
+			}
+			doc, err := decodeObject([]byte(raw))
+			if err != nil {
+				rows.Close()
+				return "", fmt.Errorf("read %s: %w", table, err)
+			}
+			n++
+			batch = append(batch, Change{ID: table + ":" + strconv.Itoa(n), Op: OpSnapshot, Source: table, Document: doc})
+			if len(batch) == size {
+				if err := emit(ctx, batch); err != nil {
+					rows.Close()
+					return "", err
+				}
+				batch = make([]Change, 0, size)
+			}
+		}
+		rows.Close()
+		if err := rows.Err(); err != nil {
+			return "", fmt.Errorf("read %s: %w", table, err)
+		}
+		if len(batch) > 0 {
+			if err := emit(ctx, batch); err != nil {
+				return "", err
+			}
+		}
+	}
+	return position, nil
+}
+
+// Stream implements Source. The stored position isn't needed: the slot
+// resumes after the last transaction consumed.
+func (s *pgSource) Stream(ctx context.Context, _ string, emit func(ctx context.Context, batch []Change, position string) error) error {
+	if _, err := s.ensureSlot(ctx); err != nil {
+		return err
+	}
+	for {
+		batch, commitLSN, err := s.peek(ctx)
+		if err != nil {
+			return err
+		}
+		if commitLSN == "" {
+			select {
+			case <-ctx.Done(): // shutdown or snapshot request
+				return ctx.Err()
+			case <-time.After(s.cfg.pollInterval()): // poll the slot again
+			}
+			continue
+		}
+		if err := emit(ctx, batch, commitLSN); err != nil {
+			return err
+		}
+		if err := s.consume(ctx, commitLSN); err != nil {
+			return err
+		}
+	}
+}
+
+// slotOptions are the wal2json options every read of the slot passes.
+func (s *pgSource) slotOptions() []any {
+	return []any{"format-version", "2", "include-timestamp", "1", "add-tables", strings.Join(s.cfg.sources(), ",")}
+}
+
+// peek reads up to a batch of changes, in whole transactions, without
+// consuming them. It returns the LSN of the last commit read, or "" when
+// the slot is empty.
+func (s *pgSource) peek(ctx context.Context) ([]Change, string, error) {
+	args := append([]any{s.cfg.slot(), s.cfg.batchSize()}, s.slotOptions()...)
+	rows, err := s.pool.Query(ctx,
+		`SELECT lsn::text, data FROM pg_logical_slot_peek_changes($1, NULL, $2, $3, $4, $5, $6, $7, $8)`, args...)
+	if err != nil {
+		return nil, "", fmt.Errorf("peek slot %q: %w", s.cfg.slot(), err)
+	}
+	defer rows.Close()
+	var (
+		batch     []Change
+		commitLSN string
+		txTime    time.Time
+	)
+	for rows.Next() {
+		var lsn, data string
+		if err := rows.Scan(&lsn, &data); err != nil {
+			return nil, "", fmt.Errorf("peek slot %q: %w", s.cfg.slot(), err)
+		}
+		var wc walChange
+		dec := json.NewDecoder(strings.NewReader(data))
+		dec.UseNumber()
+		if err := dec.Decode(&wc); err != nil {
+			return nil, "", fmt.Errorf("decode change at %s: %w", lsn, err)
+		}
+		switch wc.Action {
+		case "B":
+			txTime = parseWalTime(wc.Timestamp)
+		case "C":
+			commitLSN = lsn
+		case "I", "U", "D":
+			batch = append(batch, walToChange(lsn, wc, txTime))
+		}
+	}
+	if err := rows.Err(); err != nil {
+		return nil, "", fmt.Errorf("peek slot %q: %w", s.cfg.slot(), err)
+	}
+	return batch, commitLSN, nil
+}
+
+// consume drops the transactions up to and including the one committed
+// at commitLSN from the slot.
+func (s *pgSource) consume(ctx context.Context, commitLSN string) error {
+	args := append([]any{s.cfg.slot(), commitLSN}, s.slotOptions()...)
+	_, err := s.pool.Exec(ctx,
+		`SELECT count(*) FROM pg_logical_slot_get_changes($1, $2::pg_lsn, NULL, $3, $4, $5, $6, $7, $8)`, args...)
+	if err != nil {
+		return fmt.Errorf("advance slot %q: %w", s.cfg.slot(), err)
+	}
+	return nil
+}
+
+// Close implements Source.
+func (s *pgSource) Close(context.Context) error {
+	s.pool.Close()
+	return nil
+}
+
+// walToChange maps a wal2json row. An update carries its new row; a
+// delete, the replica identity (the primary key by default).
+func walToChange(lsn string, wc walChange, at time.Time) Change {
+	ch := Change{ID: lsn, Source: wc.Schema + "." + wc.Table, Time: at}
+	cols := wc.Columns
+	switch wc.Action {
+	case "I":
+		ch.Op = OpInsert
+	case "U":
+		ch.Op = OpUpdate
+	case "D":
+		ch.Op = OpDelete
+		cols = wc.Identity
+	}
+	ch.Document = make(map[string]any, len(cols))
+	for _, c := range cols {
+		ch.Document[c.Name] = c.Value
+	}
+	return ch
+}
+
+// parseWalTime reads wal2json's timestamp ("2006-01-02 15:04:05.999999-07").
+func parseWalTime(s string) time.Time {
+	for _, layout := range []string{"2006-01-02 15:04:05.999999-07", "2006-01-02 15:04:05.999999-07:00", time.RFC3339Nano} {
+		if t, err := time.Parse(layout, s); err == nil {
+			return t.UTC()
+		}
+	}
+	return time.Time{}
+}
+
+func decodeObject(raw []byte) (map[string]any, error) {
+	dec := json.NewDecoder(bytes.NewReader(raw))
+	dec.UseNumber()
+	var doc map[string]any
+	if err := dec.Decode(&doc); err != nil {
+		return nil, err
+	}
+	return doc, nil
+}
@@ -0,0 +1,449 @@
+package connect
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"log/slog"
+	"strconv"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+)
+
+// Source reads one connector's source database. OpenSource builds the
+// Mongo and Postgres ones.
+type Source interface {
+	// Snapshot reads every document or row of the mapped sources as
+	// OpSnapshot changes, handing them to emit in batches. It returns the
+	// position streaming continues from, taken before the read so that
+	// nothing changed during it is missed.
+	Snapshot(ctx context.Context, emit func(ctx context.Context, batch []Change) error) (position string, err error)
+	// Stream reads changes after position ("" starts from now) until ctx
+	// is cancelled or the source fails, handing each batch to emit with
+	// the position after it. The source only moves past a batch once emit
+	// returns nil.
+	Stream(ctx context.Context, position string, emit func(ctx context.Context, batch []Change, position string) error) error
+	Close(ctx context.Context) error
+}
+
+// EventWriter stores connector events. *event.Repository implements it.
+type EventWriter interface {
+	InsertBatch(ctx context.Context, events []event.Event) (int, error)
+}
+
+// RunnerDeps are the runner's collaborators.
+type RunnerDeps struct {
+	Store  Store
+	Events EventWriter
+	// Blobs offloads large documents like ingest does; nil keeps them
+	// inline.
+	Blobs *blobstore.Offloader
+	// Open builds a connector's source. Defaults to OpenSource.
+	Open func(ctx context.Context, cfg ConnectorConfig) (Source, error)
+}
+
+// State is what a connector is doing.
+type State string
+
+const (
+	StateStarting     State = "STARTING"
+	StateStandby      State = "STANDBY"
+	StateSnapshotting State = "SNAPSHOTTING"
+	StateStreaming    State = "STREAMING"
+	StateFailing      State = "FAILING"
+)
+
+// Status is a connector's health, as the admin API and health check
+// report it.
+type Status struct {
+	Name       string      `json:"name"`
+	Kind       Kind        `json:"kind"`
+	State      State       `json:"state"`
+	Error      *string     `json:"error,omitempty"`
+	Checkpoint *Checkpoint `json:"checkpoint,omitempty"`
+}
+
+// ErrUnknownConnector is returned for a name the config doesn't declare.
+var ErrUnknownConnector = errors.New("unknown connector")
+
+var errNotLeader = errors.New("no longer leader")
+
+// Runner runs every configured connector until its context is cancelled.
+type Runner struct {
+	deps  RunnerDeps
+	conns []*connector
+
+	// IsLeader gates the connectors: a source has one reader and a
+	// checkpoint one writer. Nil means always leader.
+	IsLeader func() bool
+	// RetryInterval is the wait after a failed session and between
+	// leadership checks. Defaults to 5s.
+	RetryInterval time.Duration
+}
+
+// connector is one configured connector's runtime state.
+type connector struct {
+	cfg      ConnectorConfig
+	mappings map[string]Mapping // by Change.Source
+
+	mu          sync.Mutex // guards the fields below
+	state       State
+	lastErr     *string
+	checkpoint  *Checkpoint
+	snapshotReq bool
+	cancel      context.CancelFunc // stops the current stream; nil outside one
+}
+
+// NewRunner builds a runner for cfg's connectors.
+func NewRunner(deps RunnerDeps, cfg Config) *Runner {
+	if deps.Open == nil {
+		deps.Open = OpenSource
+	}
+	r := &Runner{deps: deps, RetryInterval: 5 * time.Second}
+	for _, cc := range cfg.Connectors {
+		c := &connector{cfg: cc, mappings: map[string]Mapping{}, state: StateStarting}
+		for i, src := range cc.sources() {
+			c.mappings[src] = cc.Mappings[i]
+		}
+		r.conns = append(r.conns, c)
+	}
+	return r
+}
+
+// Run runs each connector in its own goroutine and returns once ctx is
+// cancelled and they have all stopped.
+func (r *Runner) Run(ctx context.Context) {
+	var wg sync.WaitGroup
+	for _, c := range r.conns {
+		wg.Go(func() { r.runConnector(ctx, c) })
+	}
+	wg.Wait()
+}
+
+// Statuses reports every connector, in config order.
+func (r *Runner) Statuses() []Status {
+	out := make([]Status, 0, len(r.conns))
+	for _, c := range r.conns {
+		out = append(out, c.status())
+	}
+	return out
+}
+
+// Status reports one connector.
+func (r *Runner) Status(name string) (Status, error) {
+	c := r.find(name)
+	if c == nil {
+		return Status{}, ErrUnknownConnector
+	}
+	return c.status(), nil
+}
+
+// RequestSnapshot asks connector name to backfill: its current stream is
+// stopped and a snapshot runs before streaming resumes. Only the leader
+// runs connectors, so the request is honoured by the instance it is made
+// on once that instance leads.
+func (r *Runner) RequestSnapshot(name string) error {
+	c := r.find(name)
+	if c == nil {
+		return ErrUnknownConnector
+	}
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.snapshotReq = true
+	if c.cancel != nil {
+		c.cancel()
+	}
+	return nil
+}
+
+func (r *Runner) find(name string) *connector {
+	for _, c := range r.conns {
+		if c.cfg.Name == name {
+			return c
+		}
+	}
+	return nil
+}
+
+func (r *Runner) leader() bool { return r.IsLeader == nil || r.IsLeader() }
+
+// runConnector runs sessions of c back to back, waiting out standby and
+// failures.
+func (r *Runner) runConnector(ctx context.Context, c *connector) {
+	slog.Info("connector started", "connector", c.cfg.Name, "kind", c.cfg.Kind)
+	for ctx.Err() == nil {
+		var err error
+		if r.leader() {
+			err = r.session(ctx, c)
+		} else {
+			c.setState(StateStandby, nil)
+		}
+		if ctx.Err() != nil {
+			break
+		}
+		if err != nil && !errors.Is(err, errNotLeader) {
+			slog.Warn("connector failed", "connector", c.cfg.Name, "err", err)
+			c.setState(StateFailing, err)
+			r.recordError(ctx, c, err)
+		}
+		if err == nil {
+			continue // stream stopped for a snapshot request
+		}
+		select {
+		case <-ctx.Done(): // shutdown
+		case <-time.After(r.RetryInterval): // retry or re-check leadership
+		}
+	}
+	slog.Info("connector stopped", "connector", c.cfg.Name)
+}
+
+// session opens the source, runs a pending snapshot and streams until the
+// stream fails or is stopped. It returns nil only when the stream was
+// stopped by RequestSnapshot.
+func (r *Runner) session(ctx context.Context, c *connector) error {
+	src, err := r.deps.Open(ctx, c.cfg)
+	if err != nil {
+		return fmt.Errorf("open source: %w", err)
+	}
+	defer func() { //nolint:gosec // G118: close after the session: ctx may already be done
+		cctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+		defer cancel()
+		_ = src.Close(cctx)
+	}()
+
+	cp, err := r.deps.Store.Load(ctx, c.cfg.Name)
+	if err != nil {
+		return fmt.Errorf("load checkpoint: %w", err)
+	}
+	if cp == nil {
+		cp = &Checkpoint{Connector: c.cfg.Name}
+		if c.cfg.Snapshot {
+			cp.SnapshotStatus = SnapshotRequested
+		}
+	}
+	if c.takeSnapshotRequest() {
+		cp.SnapshotStatus = SnapshotRequested
+	}
+	c.setCheckpoint(cp)
+
+	if cp.SnapshotStatus == SnapshotRequested || cp.SnapshotStatus == SnapshotRunning {
+		if err := r.snapshot(ctx, c, src, cp); err != nil {
+			return err
+		}
+	}
+
+	sctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+	if !c.startStream(cancel) {
+		return nil // a snapshot was requested during the snapshot
+	}
+	defer c.stopStream()
+	c.setState(StateStreaming, nil)
+	var position string
+	if cp.Position != nil {
+		position = *cp.Position
+	}
+	err = src.Stream(sctx, position, func(ctx context.Context, batch []Change, position string) error {
+		if !r.leader() {
+			return errNotLeader
+		}
+		n, err := r.emit(ctx, c, batch, "")
+		if err != nil {
+			return err
+		}
+		cp.Position = &position
+		cp.Emitted += int64(n)
+		cp.LastError = nil
+		if at := lastChangeAt(batch); at != nil {
+			cp.LastChangeAt = at
+		}
+		return r.save(ctx, c, cp)
+	})
+	if sctx.Err() != nil && ctx.Err() == nil {
+		return nil
+	}
+	if err == nil {
+		return errors.New("stream ended")
+	}
+	return err
+}
+
+// snapshot backfills c's mapped sources and moves the checkpoint to the
+// position the snapshot was taken at.
+func (r *Runner) snapshot(ctx context.Context, c *connector, src Source, cp *Checkpoint) error {
+	now := time.Now().UTC()
+	cp.SnapshotStatus = SnapshotRunning
+	cp.SnapshotStartedAt = &now
+	cp.SnapshotCompletedAt = nil
+	if err := r.save(ctx, c, cp); err != nil {
+		return err
+	}
+	c.setState(StateSnapshotting, nil)
+	slog.Info("connector snapshot started", "connector", c.cfg.Name)
+
+	// Snapshot rows are told apart by snapshot, so a second backfill
+	// isn't dropped as a replay of the first.
+	prefix := "snapshot:" + strconv.FormatInt(now.UnixMilli(), 10) + ":"
+	position, err := src.Snapshot(ctx, func(ctx context.Context, batch []Change) error {
+		if !r.leader() {
+			return errNotLeader
+		}
+		n, err := r.emit(ctx, c, batch, prefix)
+		if err != nil {
+			return err
+		}
+		cp.Emitted += int64(n)
+		return r.save(ctx, c, cp)
+	})
+	if err != nil {
+		return fmt.Errorf("snapshot: %w", err)
+	}
+	done := time.Now().UTC()
+	cp.Position = &position
+	cp.SnapshotStatus = SnapshotCompleted
+	cp.SnapshotCompletedAt = &done
+	cp.LastError = nil
+	slog.Info("connector snapshot completed", "connector", c.cfg.Name, "emitted", cp.Emitted)
+	return r.save(ctx, c, cp)
+}
+
+// emit maps batch to events and stores them, returning how many were
+// stored. idPrefix is prepended to each change id.
+func (r *Runner) emit(ctx context.Context, c *connector, batch []Change, idPrefix string) (int, error) {
+	now := time.Now().UTC()
+	events := make([]event.Event, 0, len(batch))
+	for _, ch := range batch {
+		m, ok := c.mappings[ch.Source]
+		if !ok {
+			continue
+		}
+		ch.ID = idPrefix + ch.ID
+		e, err := m.Event(c.cfg.Name, ch, now)
+		if err != nil {
+			return 0, err
+		}
+		if e == nil {
+			continue
+		}
+		if e.Data, err = r.deps.Blobs.Offload(ctx, blobstore.ContentKey(blobstore.EventsPrefix, e.Data), e.Data); err != nil {
+			return 0, fmt.Errorf("offload %s change %s: %w", ch.Source, ch.ID, err)
+		}
+		events = append(events, *e)
+	}
+	if _, err := r.deps.Events.InsertBatch(ctx, events); err != nil {
+		return 0, fmt.Errorf("insert events: %w", err)
+	}
+	return len(events), nil
+}
+
+func (r *Runner) save(ctx context.Context, c *connector, cp *Checkpoint) error {
+	if err := r.deps.Store.Save(ctx, cp); err != nil {
+		return fmt.Errorf("save checkpoint: %w", err)
+	}
+	c.setCheckpoint(cp)
+	return nil
+}
+
+// recordError stores err on the connector's checkpoint, when it has one,
+// so it outlives this process.
+func (r *Runner) recordError(ctx context.Context, c *connector, err error) {
+	c.mu.Lock()
+	var cp Checkpoint
+	if c.checkpoint != nil {
+		cp = *c.checkpoint
+	} else {
+		cp = Checkpoint{Connector: c.cfg.Name}
+	}
+	c.mu.Unlock()
+	msg := err.Error()
+	cp.LastError = &msg
+	if saveErr := r.save(ctx, c, &cp); saveErr != nil {
+		slog.Error("connector: record error failed", "connector", c.cfg.Name, "err", saveErr)
+	}
+}
+
+func lastChangeAt(batch []Change) *time.Time {
+	var at time.Time
+	for _, ch := range batch {
+		if ch.Time.After(at) {
+			at = ch.Time
+		}
+	}
+	if at.IsZero() {
+		if len(batch) == 0 {
+			return nil
+		}
+		at = time.Now()
+	}
+	at = at.UTC()
+	return &at
+}
+
+func (c *connector) status() Status {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	s := Status{Name: c.cfg.Name, Kind: c.cfg.Kind, State: c.state, Error: c.lastErr}
+	if c.checkpoint != nil {
+		cp := *c.checkpoint
+		s.Checkpoint = &cp
+	}
+	return s
+}
+
+func (c *connector) setState(s State, err error) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.state = s
+	c.lastErr = nil
+	if err != nil {
+		msg := err.Error()
+		c.lastErr = &msg
+	}
+}
+
+func (c *connector) setCheckpoint(cp *Checkpoint) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	snap := *cp
+	c.checkpoint = &snap
+}
+
+func (c *connector) takeSnapshotRequest() bool {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	req := c.snapshotReq
+	c.snapshotReq = false
+	return req
+}
+
+// startStream records cancel as the way to stop the stream about to
+// start. It reports false when a snapshot was requested in the meantime.
+func (c *connector) startStream(cancel context.CancelFunc) bool {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	if c.snapshotReq {
+		return false
+	}
+	c.cancel = cancel
+	return true
+}
+
+func (c *connector) stopStream() {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.cancel = nil
+}
+
+// OpenSource connects to cfg's source database.
+func OpenSource(ctx context.Context, cfg ConnectorConfig) (Source, error) {
+	switch cfg.Kind {
+	case KindMongo:
+		return openMongo(ctx, cfg)
+	case KindPostgres:
+		return openPostgres(ctx, cfg)
+	}
+	return nil, fmt.Errorf("unknown connector kind %q", cfg.Kind)
+}
@@ -0,0 +1,95 @@
+package connect
+
+import (
+	"context"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+)
+
+// SnapshotStatus is where a connector's backfill snapshot stands.
+type SnapshotStatus string
+
+const (
+	SnapshotNone      SnapshotStatus = ""
+	SnapshotRequested SnapshotStatus = "REQUESTED"
+	SnapshotRunning   SnapshotStatus = "RUNNING"
+	SnapshotCompleted SnapshotStatus = "COMPLETED"
+)
+
+// Checkpoint is a connector's stored progress.
+type Checkpoint struct {
+	Connector string `json:"connector"`
+	// Position is the source's resume point; nil before the first batch.
+	Position            *string        `json:"position,omitempty"`
+	SnapshotStatus      SnapshotStatus `json:"snapshotStatus,omitempty"`
+	SnapshotStartedAt   *time.Time     `json:"snapshotStartedAt,omitempty"`
+	SnapshotCompletedAt *time.Time     `json:"snapshotCompletedAt,omitempty"`
+	LastChangeAt        *time.Time     `json:"lastChangeAt,omitempty"`
+	LastError           *string        `json:"lastError,omitempty"`
+	Emitted             int64          `json:"emitted"`
+	UpdatedAt           time.Time      `json:"updatedAt"`
+}
+
+// Store keeps checkpoints. Load returns (nil, nil) for a connector that
+// has none yet.
+type Store interface {
+	Load(ctx context.Context, connector string) (*Checkpoint, error)
+	Save(ctx context.Context, cp *Checkpoint) error
+}
+
+// PgStore is the Postgres Store. Table: msg_connector_checkpoints.
+type PgStore struct {
+	q *dbq.Queries
+}
+
+// NewPgStore wires a store.
+func NewPgStore(pool *pgxpool.Pool) *PgStore {
+	return &PgStore{q: dbq.New(pool)}
+}
+
+// Load implements Store.
+func (s *PgStore) Load(ctx context.Context, connector string) (*Checkpoint, error) {
+	res, err := s.q.ConnectorCheckpointFind(ctx, connector)
+	row, err := repocommon.One(res, err, "connector checkpoint")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	var status SnapshotStatus
+	if row.SnapshotStatus != nil {
+		status = SnapshotStatus(*row.SnapshotStatus)
+	}
+	return &Checkpoint{
+		Connector:           row.Connector,
+		Position:            row.Position,
+		SnapshotStatus:      status,
+		SnapshotStartedAt:   row.SnapshotStartedAt,
+		SnapshotCompletedAt: row.SnapshotCompletedAt,
+		LastChangeAt:        row.LastChangeAt,
+		LastError:           row.LastError,
+		Emitted:             row.Emitted,
+		UpdatedAt:           row.UpdatedAt,
+	}, nil
+}
+
+// Save implements Store: cp replaces the stored row.
+func (s *PgStore) Save(ctx context.Context, cp *Checkpoint) error {
+	var status *string
+	if cp.SnapshotStatus != SnapshotNone {
+		v := string(cp.SnapshotStatus)
+		status = &v
+	}
+	return s.q.ConnectorCheckpointUpsert(ctx, dbq.ConnectorCheckpointUpsertParams{
+		Connector:           cp.Connector,
+		Position:            cp.Position,
+		SnapshotStatus:      status,
+		SnapshotStartedAt:   cp.SnapshotStartedAt,
+		SnapshotCompletedAt: cp.SnapshotCompletedAt,
+		LastChangeAt:        cp.LastChangeAt,
+		LastError:           cp.LastError,
+		Emitted:             cp.Emitted,
+	})
+}
@@ -0,0 +1,227 @@
+// Package degrade tracks features that keep running while a shared
+// dependency (in practice Redis) is unavailable, and the policy each one
+// applies until it returns.
+//
+// A feature reports every call to the dependency through Observe. The
+// first failure flips it to degraded and logs a warning; while it stays
+// degraded the warning is repeated at most once per ReminderInterval; the
+// first success after that flips it back and logs the recovery. Features
+// never give up on the dependency — recovery is automatic.
+//
+// The policy says what a feature does meanwhile:
+//
+//   - FailClosed refuses the guarded action. Leader election is always
+//     fail-closed: an instance that cannot reach the lock never leads, so
+//     two replicas can never be active at once.
+//   - FailOpen carries on without the dependency — the rate limiter falls
+//     back to Postgres or allows, the shared caches go to the database.
+//
+// FC_REDIS_DEGRADE_POLICY overrides the default per feature, as
+// comma-separated name=open|closed pairs ("rate-limit=closed"). Snapshot
+// feeds the DEGRADED /q/health check.
+package degrade
+
+import (
+	"fmt"
+	"log/slog"
+	"os"
+	"sort"
+	"strings"
+	"sync"
+	"time"
+)
+
+// Policy is what a feature does while its dependency is unavailable.
+type Policy string
+
+const (
+	FailClosed Policy = "closed"
+	FailOpen   Policy = "open"
+)
+
+// ReminderInterval spaces the repeated warning a degraded feature logs.
+const ReminderInterval = time.Minute
+
+// Status is a feature's point-in-time state.
+type Status struct {
+	Name     string     `json:"name"`
+	Policy   Policy     `json:"policy"`
+	Degraded bool       `json:"degraded"`
+	Since    *time.Time `json:"since,omitempty"`
+	Error    string     `json:"error,omitempty"`
+	Failures uint64     `json:"failures"`
+}
+
+// Feature is one Redis-backed capability. Safe for concurrent use.
+type Feature struct {
+	name   string
+	policy Policy
+
+	mu         sync.Mutex
+	degraded   bool
+	since      time.Time
+	lastErr    string
+	lastWarned time.Time
+	failures   uint64
+}
+
+// Name returns the feature name.
+func (f *Feature) Name() string { return f.name }
+
+// Policy returns the feature's effective policy.
+func (f *Feature) Policy() Policy { return f.policy }
+
+// Degraded reports whether the last observed call failed.
+func (f *Feature) Degraded() bool {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	return f.degraded
+}
+
+// Observe records the outcome of one call to the dependency: a non-nil
+// err marks the feature degraded, nil recovers it.
+func (f *Feature) Observe(err error) {
+	now := time.Now()
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	if err == nil {
+		if f.degraded {
+			slog.Info("redis available again; feature recovered",
+				"feature", f.name, "degraded_for", now.Sub(f.since).Round(time.Second))
+			f.degraded = false
+		}
+		return
+	}
+	f.failures++
+	f.lastErr = err.Error()
+	if !f.degraded {
+		f.degraded, f.since, f.lastWarned = true, now, now
+		slog.Warn("redis unavailable; feature degraded", "feature", f.name, "policy", string(f.policy), "err", err)
+		return
+	}
+	if now.Sub(f.lastWarned) >= ReminderInterval {
+		f.lastWarned = now
+		slog.Warn("still operating without redis", "feature", f.name, "policy", string(f.policy),
+			"degraded_for", now.Sub(f.since).Round(time.Second), "err", err)
+	}
+}
+
+// Status returns the feature's current state.
+func (f *Feature) Status() Status {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	s := Status{Name: f.name, Policy: f.policy, Degraded: f.degraded, Failures: f.failures}
+	if f.degraded {
+		since := f.since
+		s.Since, s.Error = &since, f.lastErr
+	}
+	return s
+}
+
+// Registry holds the features of one process.
+type Registry struct {
+	mu        sync.Mutex
+	features  map[string]*Feature
+	overrides map[string]Policy
+}
+
+// NewRegistry returns an empty registry applying the given policy
+// overrides (see ParsePolicies).
+func NewRegistry(overrides map[string]Policy) *Registry {
+	return &Registry{features: map[string]*Feature{}, overrides: overrides}
+}
+
+// Register returns the feature with this name, creating it with def (or
+// its override) on first use. Features that can only be fail-closed pass
+// fixed=true, and an override to open is ignored with a warning.
+func (r *Registry) Register(name string, def Policy, fixed bool) *Feature {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	if f, ok := r.features[name]; ok {
+		return f
+	}
+	p := def
+	if o, ok := r.overrides[baseName(name)]; ok {
+		if fixed && o != def {
+			slog.Warn("ignoring redis degrade policy override; feature policy is fixed",
+				"feature", name, "policy", string(def), "override", string(o))
+		} else {
+			p = o
+		}
+	}
+	f := &Feature{name: name, policy: p}
+	r.features[name] = f
+	return f
+}
+
+// Snapshot returns every feature's state, sorted by name.
+func (r *Registry) Snapshot() []Status {
+	r.mu.Lock()
+	features := make([]*Feature, 0, len(r.features))
+	for _, f := range r.features {
+		features = append(features, f)
+	}
+	r.mu.Unlock()
+	out := make([]Status, len(features))
+	for i, f := range features {
+		out[i] = f.Status()
+	}
+	sort.Slice(out, func(i, j int) bool { return out[i].Name < out[j].Name })
+	return out
+}
+
+// baseName strips a "(qualifier)" suffix, so one override covers every
+// instance of a feature ("leader-election(fc:leader:outbox)").
+func baseName(name string) string {
+	if i := strings.IndexByte(name, '('); i > 0 {
+		return name[:i]
+	}
+	return name
+}
+
+// ParsePolicies parses "name=open|closed,..." into overrides.
+func ParsePolicies(s string) (map[string]Policy, error) {
+	out := map[string]Policy{}
+	for _, part := range strings.Split(s, ",") {
+		part = strings.TrimSpace(part)
+		if part == "" {
+			continue
+		}
+		name, val, ok := strings.Cut(part, "=")
+		name, val = strings.TrimSpace(name), strings.ToLower(strings.TrimSpace(val))
+		if !ok || name == "" {
+			return nil, fmt.Errorf("degrade policy %q: want name=open|closed", part)
+		}
+		switch Policy(val) {
+		case FailOpen, FailClosed:
+			out[name] = Policy(val)
+		default:
+			return nil, fmt.Errorf("degrade policy %q: %q is not open or closed", part, val)
+		}
+	}
+	return out, nil
+}
+
+var (
+	defaultOnce sync.Once
+	defaultReg  *Registry
+)
+
+// Default is the process-wide registry, with overrides read once from
+// FC_REDIS_DEGRADE_POLICY. A malformed value is logged and ignored.
+func Default() *Registry {
+	defaultOnce.Do(func() {
+		overrides, err := ParsePolicies(os.Getenv("FC_REDIS_DEGRADE_POLICY"))
+		if err != nil {
+			slog.Warn("invalid FC_REDIS_DEGRADE_POLICY; using default policies", "err", err)
+			overrides = nil
+		}
+		defaultReg = NewRegistry(overrides)
+	})
+	return defaultReg
+}
+
+// Register registers a feature in the Default registry.
+func Register(name string, def Policy, fixed bool) *Feature {
+	return Default().Register(name, def, fixed)
+}
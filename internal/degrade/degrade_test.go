@@ -0,0 +1,53 @@
+package degrade
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestParsePolicies(t *testing.T) {
+	got, err := ParsePolicies(" rate-limit=CLOSED, leader-election=open ,")
+	if err != nil {
+		t.Fatalf("ParsePolicies: %v", err)
+	}
+	if got["rate-limit"] != FailClosed || got["leader-election"] != FailOpen || len(got) != 2 {
+		t.Errorf("ParsePolicies = %v", got)
+	}
+	for _, bad := range []string{"rate-limit", "=open", "rate-limit=maybe"} {
+		if _, err := ParsePolicies(bad); err == nil {
+			t.Errorf("ParsePolicies(%q) accepted", bad)
+		}
+	}
+}
+
+func TestRegisterAppliesOverrides(t *testing.T) {
+	r := NewRegistry(map[string]Policy{"rate-limit": FailClosed, "leader-election": FailOpen})
+	if p := r.Register("rate-limit", FailOpen, false).Policy(); p != FailClosed {
+		t.Errorf("rate-limit policy = %s, want closed", p)
+	}
+	if p := r.Register("leader-election(fc:leader)", FailClosed, true).Policy(); p != FailClosed {
+		t.Errorf("fixed leader-election policy = %s, want closed", p)
+	}
+	if a, b := r.Register("x", FailOpen, false), r.Register("x", FailClosed, false); a != b {
+		t.Error("re-registering returned a new feature")
+	}
+}
+
+func TestObserveDegradesAndRecovers(t *testing.T) {
+	r := NewRegistry(nil)
+	f := r.Register("rate-limit", FailOpen, false)
+	f.Observe(nil)
+	if f.Degraded() {
+		t.Fatal("degraded after a success")
+	}
+	f.Observe(errors.New("connection refused"))
+	f.Observe(errors.New("i/o timeout"))
+	s := r.Snapshot()
+	if len(s) != 1 || !s[0].Degraded || s[0].Since == nil || s[0].Error != "i/o timeout" || s[0].Failures != 2 {
+		t.Fatalf("Snapshot = %+v", s)
+	}
+	f.Observe(nil)
+	if s := f.Status(); s.Degraded || s.Since != nil || s.Error != "" || s.Failures != 2 {
+		t.Errorf("after recovery Status = %+v", s)
+	}
+}
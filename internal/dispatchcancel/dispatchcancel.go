@@ -0,0 +1,134 @@
+// Package dispatchcancel shares cancelled dispatch-job ids between the
+// platform and the router.
+//
+// Cancelling a job flips its row to CANCELLED, which the scheduler never
+// claims again, but a copy the poller already published may still sit in
+// a broker queue. The platform therefore also writes a tombstone per job;
+// the router looks it up before mediating a message and ACKs a cancelled
+// one without delivering it. The processing callback's terminal-status
+// check stays the backstop when no tombstone store is configured.
+//
+// Build selects Redis when FC_REDIS_URL is reachable, else a Noop store
+// that records nothing and reports nothing cancelled.
+package dispatchcancel
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"os"
+	"time"
+
+	"github.com/redis/go-redis/v9"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/redisconn"
+)
+
+// TTL is how long a tombstone lives: SQS retains a message for at most 14
+// days, so no queued copy outlives it.
+const TTL = 14 * 24 * time.Hour
+
+// keyPrefix namespaces tombstones: one key per job, "fc:dc:{jobId}".
+const keyPrefix = "fc:dc:"
+
+// redisConnectTimeout bounds the startup PING.
+const redisConnectTimeout = 2 * time.Second
+
+// Store records and answers cancellations.
+type Store interface {
+	// Mark tombstones the given job ids.
+	Mark(ctx context.Context, ids []string) error
+	// Clear removes their tombstones — for jobs an operator requeues.
+	Clear(ctx context.Context, ids []string) error
+	// IsCancelled reports whether id carries a tombstone.
+	IsCancelled(ctx context.Context, id string) (bool, error)
+}
+
+// NoopStore records nothing and reports nothing cancelled.
+type NoopStore struct{}
+
+// Mark does nothing.
+func (NoopStore) Mark(context.Context, []string) error { return nil }
+
+// Clear does nothing.
+func (NoopStore) Clear(context.Context, []string) error { return nil }
+
+// IsCancelled always reports false.
+func (NoopStore) IsCancelled(context.Context, string) (bool, error) { return false, nil }
+
+// Build selects the backend: Redis when FC_REDIS_URL is set and reachable
+// (PING within a short timeout), else NoopStore. The choice is logged at
+// startup.
+func Build(ctx context.Context) Store {
+	url := os.Getenv("FC_REDIS_URL")
+	if url == "" {
+		slog.Info("FC_REDIS_URL not set; queued copies of cancelled dispatch jobs are dropped by the processing callback")
+		return NoopStore{}
+	}
+	s, err := NewRedisStore(ctx, url)
+	if err != nil {
+		slog.Warn("FC_REDIS_URL set but Redis unreachable; queued copies of cancelled dispatch jobs are dropped by the processing callback", "err", err)
+		return NoopStore{}
+	}
+	slog.Info("dispatch cancellation tombstones: Redis")
+	return s
+}
+
+// RedisStore keeps each tombstone as its own key with TTL, so a lookup is
+// one EXISTS and expiry needs no sweeper.
+type RedisStore struct {
+	client redis.UniversalClient
+}
+
+// NewRedisStore connects, PINGs to confirm liveness, and returns the store.
+func NewRedisStore(ctx context.Context, url string) (*RedisStore, error) {
+	client, err := redisconn.Open(url)
+	if err != nil {
+		return nil, fmt.Errorf("invalid redis url: %w", err)
+	}
+	pingCtx, cancel := context.WithTimeout(ctx, redisConnectTimeout)
+	defer cancel()
+	if err := client.Ping(pingCtx).Err(); err != nil {
+		_ = client.Close()
+		return nil, fmt.Errorf("redis ping: %w", err)
+	}
+	return &RedisStore{client: client}, nil
+}
+
+// Mark writes one tombstone per id in a single pipeline.
+func (s *RedisStore) Mark(ctx context.Context, ids []string) error {
+	if len(ids) == 0 {
+		return nil
+	}
+	_, err := s.client.Pipelined(ctx, func(p redis.Pipeliner) error {
+		for _, id := range ids {
+			p.Set(ctx, keyPrefix+id, 1, TTL)
+		}
+		return nil
+	})
+	return err
+}
+
+// Clear deletes the ids' tombstones, one DEL per key so a cluster client
+// can route each to its own slot.
+func (s *RedisStore) Clear(ctx context.Context, ids []string) error {
+	if len(ids) == 0 {
+		return nil
+	}
+	_, err := s.client.Pipelined(ctx, func(p redis.Pipeliner) error {
+		for _, id := range ids {
+			p.Del(ctx, keyPrefix+id)
+		}
+		return nil
+	})
+	return err
+}
+
+// IsCancelled reports whether id's tombstone exists.
+func (s *RedisStore) IsCancelled(ctx context.Context, id string) (bool, error) {
+	n, err := s.client.Exists(ctx, keyPrefix+id).Result()
+	if err != nil {
+		return false, err
+	}
+	return n > 0, nil
+}
@@ -69,3 +69,49 @@ func TestUint(t *testing.T) {
 		t.Error("Uint(unset) ok = true, want false")
 	}
 }
+
+func TestUnknown(t *testing.T) {
+	got := Unknown([]string{
+		"FC_API_PORT=8080", "PORT=1", "FC_API_PROT=8080", "FLOWCATALYST_APP_KEYS=x",
+		"HOME=/root", "FC_OUTBOX_DB_TYPE=mongo", "FC_API_PROT=again",
+	})
+	if len(got) != 2 || got[0] != "FC_API_PROT" || got[1] != "FLOWCATALYST_APP_KEYS" {
+		t.Errorf("Unknown = %v", got)
+	}
+	if s := Suggest("FC_API_PROT"); s != "FC_API_PORT" {
+		t.Errorf("Suggest = %q, want FC_API_PORT", s)
+	}
+	if s := Suggest("FC_NOTHING_LIKE_IT"); s != "" {
+		t.Errorf("Suggest = %q, want none", s)
+	}
+}
+
+func TestResolve(t *testing.T) {
+	v := Var{Name: "ENVUTIL_T_CANON", Default: "d", Aliases: []string{"ENVUTIL_T_ALIAS"}}
+	if val, src, _ := v.Resolve(); val != "d" || src != SourceDefault {
+		t.Errorf("unset = %q %s", val, src)
+	}
+	t.Setenv("ENVUTIL_T_ALIAS", "a")
+	if val, src, from := v.Resolve(); val != "a" || src != SourceAlias || from != "ENVUTIL_T_ALIAS" {
+		t.Errorf("alias = %q %s %s", val, src, from)
+	}
+	t.Setenv("ENVUTIL_T_CANON", "c")
+	if val, src, _ := v.Resolve(); val != "c" || src != SourceEnv {
+		t.Errorf("canonical = %q %s", val, src)
+	}
+}
+
+func TestRedact(t *testing.T) {
+	cases := []struct{ name, in, want string }{
+		{"FC_SMTP_PASSWORD", "hunter2", "***"},
+		{"FLOWCATALYST_APP_KEY", "base64", "***"},
+		{"FC_DATABASE_URL", "postgres://u:p@db:5432/fc?sslmode=off", "postgres://***@db:5432/fc?sslmode=off"},
+		{"FC_STANDBY_REDIS_URL", "redis://cache:6379/0", "redis://cache:6379/0"},
+		{"FC_API_PORT", "8080", "8080"},
+	}
+	for _, c := range cases {
+		if got := Redact(c.name, c.in); got != c.want {
+			t.Errorf("Redact(%s, %q) = %q, want %q", c.name, c.in, got, c.want)
+		}
+	}
+}
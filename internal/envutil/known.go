@@ -0,0 +1,126 @@
+package envutil
+
+import (
+	"os"
+	"slices"
+	"strings"
+)
+
+// Var is one documented environment variable.
+type Var struct {
+	Name    string
+	Default string   // as documented; "" when there is none
+	Aliases []string // in priority order, after Name
+}
+
+// Known returns every documented variable, in document order. The list
+// (known_vars.go) is generated from docs/environment-variables.md by
+// `make env-vars`, so a variable is known exactly when it is documented.
+func Known() []Var { return slices.Clone(known) }
+
+// Source says where a variable's effective value came from.
+type Source string
+
+const (
+	SourceDefault Source = "default"
+	SourceEnv     Source = "env"
+	SourceAlias   Source = "alias"
+)
+
+// Resolve returns v's effective value and where it came from: the first
+// non-empty of Name and its Aliases, else the documented default. from
+// names the variable that supplied it.
+func (v Var) Resolve() (value string, src Source, from string) {
+	for i, name := range append([]string{v.Name}, v.Aliases...) {
+		if val := os.Getenv(name); val != "" {
+			if i == 0 {
+				return val, SourceEnv, name
+			}
+			return val, SourceAlias, name
+		}
+	}
+	return v.Default, SourceDefault, ""
+}
+
+// Unknown returns the FC_* and FLOWCATALYST_* names in environ (os.Environ
+// form) that are neither a documented variable nor one of its aliases,
+// sorted.
+func Unknown(environ []string) []string {
+	names := map[string]bool{}
+	for _, v := range known {
+		names[v.Name] = true
+		for _, a := range v.Aliases {
+			names[a] = true
+		}
+	}
+	var out []string
+	for _, kv := range environ {
+		name, _, _ := strings.Cut(kv, "=")
+		if (strings.HasPrefix(name, "FC_") || strings.HasPrefix(name, "FLOWCATALYST_")) && !names[name] {
+			out = append(out, name)
+		}
+	}
+	slices.Sort(out)
+	return slices.Compact(out)
+}
+
+// Suggest returns the documented name closest to a mistyped one, or ""
+// when none is within two edits.
+func Suggest(name string) string {
+	best, bestDist := "", 3
+	for _, v := range known {
+		if d := editDistance(name, v.Name); d < bestDist {
+			best, bestDist = v.Name, d
+		}
+	}
+	return best
+}
+
+// editDistance is the Levenshtein distance between two ASCII names.
+func editDistance(a, b string) int {
+	prev := make([]int, len(b)+1)
+	cur := make([]int, len(b)+1)
+	for j := range prev {
+		prev[j] = j
+	}
+	for i := 1; i <= len(a); i++ {
+		cur[0] = i
+		for j := 1; j <= len(b); j++ {
+			cost := 1
+			if a[i-1] == b[j-1] {
+				cost = 0
+			}
+			cur[j] = min(prev[j]+1, cur[j-1]+1, prev[j-1]+cost)
+		}
+		prev, cur = cur, prev
+	}
+	return prev[len(b)]
+}
+
+// Sensitive reports whether a variable's value must not be printed.
+func Sensitive(name string) bool {
+	for _, s := range []string{"SECRET", "PASSWORD", "TOKEN", "PRIVATE_KEY", "SIGNING_KEY_PEM", "APP_KEY", "AUTH_PASS", "ENCRYPTION_KEY"} {
+		if strings.Contains(name, s) {
+			return true
+		}
+	}
+	return false
+}
+
+// Redact masks a value for display: sensitive variables entirely, and the
+// password of any URL-shaped value.
+func Redact(name, value string) string {
+	if value == "" {
+		return ""
+	}
+	if Sensitive(name) {
+		return "***"
+	}
+	if i := strings.Index(value, "://"); i >= 0 {
+		rest := value[i+3:]
+		if at := strings.LastIndexByte(rest[:strings.IndexAny(rest+"/", "/?")], '@'); at >= 0 {
+			return value[:i+3] + "***@" + rest[at+1:]
+		}
+	}
+	return value
+}
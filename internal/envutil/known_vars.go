@@ -0,0 +1,271 @@
+// Code generated by tools/envdoc from docs/environment-variables.md; DO NOT EDIT.
+
+package envutil
+
+var known = []Var{
+	{Name: "FC_API_PORT", Default: "8080", Aliases: []string{"PORT"}},
+	{Name: "FC_METRICS_PORT", Default: "9090"},
+	{Name: "FC_PLATFORM_ENABLED", Default: "true", Aliases: []string{"PLATFORM_ENABLED"}},
+	{Name: "FC_ROUTER_ENABLED", Default: "false", Aliases: []string{"MESSAGE_ROUTER_ENABLED"}},
+	{Name: "FC_SCHEDULER_ENABLED", Default: "false", Aliases: []string{"DISPATCH_SCHEDULER_ENABLED"}},
+	{Name: "FC_SCHEDULER_REGION_QUEUES", Default: "\"\" (none)"},
+	{Name: "FC_DISPATCH_PROCESSING_ENDPOINT", Default: "http://localhost:<FC_API_PORT>/api/dispatch/process"},
+	{Name: "FC_SCHEDULED_JOB_ENABLED", Default: "false", Aliases: []string{"SCHEDULED_JOB_SCHEDULER_ENABLED"}},
+	{Name: "FC_STREAM_PROCESSOR_ENABLED", Default: "false", Aliases: []string{"STREAM_PROCESSOR_ENABLED"}},
+	{Name: "FC_OUTBOX_ENABLED", Default: "false", Aliases: []string{"OUTBOX_PROCESSOR_ENABLED"}},
+	{Name: "FC_MCP_ENABLED", Default: "false"},
+	{Name: "FC_DEFAULT_BROKER", Default: "\"\" (no pools start)"},
+	{Name: "FC_QUEUE_ENCODING", Default: "json"},
+	{Name: "FC_QUEUE_SCHEMA_REGISTRY_URL"},
+	{Name: "FC_QUEUE_SCHEMA_REGISTRY_SUBJECT", Default: "flowcatalyst-queue-message"},
+	{Name: "FC_BLOB_STORE_URI", Default: "\"\" (off)"},
+	{Name: "FC_BLOB_THRESHOLD_BYTES", Default: "262144"},
+	{Name: "FC_BLOB_PRESIGN_TTL_SECONDS", Default: "900"},
+	{Name: "FC_SCHEMA_REGISTRY_URL", Default: "\"\" (off)"},
+	{Name: "FC_SCHEMA_REGISTRY_USERNAME"},
+	{Name: "FC_SCHEMA_REGISTRY_PASSWORD"},
+	{Name: "FC_SCHEMA_REGISTRY_CACHE_TTL_SECS", Default: "300"},
+	{Name: "FC_STARTUP_PROBE_ATTEMPTS", Default: "10"},
+	{Name: "FC_STARTUP_PROBE_BACKOFF_MS", Default: "500"},
+	{Name: "FC_STARTUP_PROBE_MAX_BACKOFF_MS", Default: "10000"},
+	{Name: "FC_RECONCILE_SPEC_PATH", Default: "\"\" (off)"},
+	{Name: "FC_RECONCILE_INTERVAL_SECS", Default: "60"},
+	{Name: "FC_RECONCILE_APPLY", Default: "false"},
+	{Name: "FC_FEDERATION_INSTANCE_ID", Default: "\"\" (off)"},
+	{Name: "FC_FEDERATION_MAX_HOPS", Default: "4"},
+	{Name: "FC_FEDERATION_INTERVAL_SECS", Default: "5"},
+	{Name: "FC_CONNECT_CONFIG_PATH", Default: "\"\" (off)"},
+	{Name: "FC_CONNECT_ADMIN_PORT", Default: "0 (off)"},
+	{Name: "FC_ARCHIVE_STORE_URI", Default: "\"\" (off)"},
+	{Name: "FC_ARCHIVE_INTERVAL_SECS", Default: "300"},
+	{Name: "FC_ARCHIVE_SETTLE_SECS", Default: "60"},
+	{Name: "FC_IDEMPOTENCY_TTL_HOURS", Default: "24"},
+	{Name: "FC_ENV_STRICT", Default: "false"},
+	{Name: "FC_DATABASE_URL", Default: "local-dev DSN (see above)", Aliases: []string{"DATABASE_URL"}},
+	{Name: "DB_HOST"},
+	{Name: "DB_PORT", Default: "5432"},
+	{Name: "DB_NAME", Default: "flowcatalyst"},
+	{Name: "DB_USERNAME", Default: "postgres"},
+	{Name: "DB_PASSWORD", Default: "\"\""},
+	{Name: "DB_SECRET_ARN"},
+	{Name: "DB_SECRET_PROVIDER", Default: "aws"},
+	{Name: "DB_SECRET_REFRESH_INTERVAL_MS", Default: "300000 (5 min)"},
+	{Name: "FC_DB_QUERY_TIMEOUT_MS", Default: "30000"},
+	{Name: "FC_DB_SLOW_QUERY_MS", Default: "500"},
+	{Name: "FC_MIGRATE_DRY_RUN", Default: "false"},
+	{Name: "FC_READ_CACHE_SIZE", Default: "1000"},
+	{Name: "FC_READ_CACHE_TTL_SECS", Default: "60"},
+	{Name: "FC_PRINCIPAL_VERSION_CACHE_SIZE", Default: "10000"},
+	{Name: "FC_PRINCIPAL_VERSION_CACHE_TTL_SECS", Default: "30"},
+	{Name: "FC_JWT_ISSUER", Default: "http://localhost:8080", Aliases: []string{"FC_EXTERNAL_BASE_URL", "EXTERNAL_BASE_URL"}},
+	{Name: "FC_JWT_SIGNING_KEY_PATH"},
+	{Name: "FLOWCATALYST_JWT_PRIVATE_KEY"},
+	{Name: "FC_JWT_SIGNING_KEY_PEM"},
+	{Name: "FLOWCATALYST_JWT_PREVIOUS_PUBLIC_KEY"},
+	{Name: "FC_JWT_KEYRING_ENABLED", Default: "false"},
+	{Name: "FC_JWT_KEY_ROTATION_DAYS", Default: "0 (never)"},
+	{Name: "FC_JWT_KEY_GRACE_HOURS", Default: "48"},
+	{Name: "FC_JWT_KEY_REFRESH_MINUTES", Default: "5"},
+	{Name: "FC_SECRETS_DATA_DIR", Default: "./data"},
+	{Name: "FC_SECRETS_ENCRYPTION_KEY"},
+	{Name: "FC_ROUTER_AUTH_MODE", Default: "inferred", Aliases: []string{"AUTH_MODE"}},
+	{Name: "FC_ROUTER_AUTH_USER", Default: "\"\"", Aliases: []string{"AUTH_BASIC_USERNAME"}},
+	{Name: "FC_ROUTER_AUTH_PASS", Default: "\"\"", Aliases: []string{"AUTH_BASIC_PASSWORD"}},
+	{Name: "FC_ROUTER_AUTH_TOKENS"},
+	{Name: "FC_AUTH_ALLOW_TEST_HEADERS", Default: "false"},
+	{Name: "FC_AUTH_MODE", Default: "embedded"},
+	{Name: "FC_AUTH_REMOTE_ISSUERS"},
+	{Name: "FC_AUTH_CLOCK_SKEW_SECS", Default: "60"},
+	{Name: "FC_AUTH_JWKS_REFRESH_SECS", Default: "3600"},
+	{Name: "FC_CORS_ORIGINS", Default: "(none)", Aliases: []string{"CORS_ORIGINS"}},
+	{Name: "FC_CORS_ALLOW_CREDENTIALS", Default: "false"},
+	{Name: "FC_CORS_MAX_AGE_SECS", Default: "600"},
+	{Name: "FC_CSRF_ENABLED", Default: "true"},
+	{Name: "FC_HSTS_MAX_AGE_SECS", Default: "31536000"},
+	{Name: "FC_TRUSTED_PROXIES", Default: "(none)"},
+	{Name: "FC_HTTP_MAX_BODY_BYTES", Default: "1048576"},
+	{Name: "FC_HTTP_BATCH_MAX_BODY_BYTES", Default: "10485760"},
+	{Name: "FC_HTTP_BODY_READ_TIMEOUT_SECS", Default: "30"},
+	{Name: "FC_HTTP_READ_HEADER_TIMEOUT_SECS", Default: "10"},
+	{Name: "FC_HTTP_READ_TIMEOUT_SECS", Default: "60"},
+	{Name: "FC_HTTP_IDLE_TIMEOUT_SECS", Default: "120"},
+	{Name: "FC_HTTP_MAX_CONNECTIONS", Default: "10000"},
+	{Name: "FC_OUTBOUND_SCHEMES", Default: "https,http"},
+	{Name: "FC_OUTBOUND_ALLOW_PRIVATE", Default: "false (true under fc-dev)"},
+	{Name: "FC_OUTBOUND_ALLOWED_NETS"},
+	{Name: "FC_OUTBOUND_ALLOWED_HOSTS"},
+	{Name: "FC_OUTBOUND_CLIENT_POLICIES"},
+	{Name: "FC_OUTBOUND_TRANSPORTS"},
+	{Name: "FC_OUTBOUND_TLS_CA_FILE"},
+	{Name: "FC_OUTBOUND_TLS_MIN_VERSION"},
+	{Name: "FLOWCATALYST_APP_KEY"},
+	{Name: "FLOWCATALYST_APP_KEY_PREVIOUS"},
+	{Name: "FC_PAYLOAD_ENCRYPTION_CLIENTS", Default: "\"\" (off)"},
+	{Name: "FLOWCATALYST_SIGNING_SECRET"},
+	{Name: "FC_RL_OAUTH_TOKEN_IP_PER_MIN", Default: "600"},
+	{Name: "FC_RL_OAUTH_TOKEN_CLIENT_PER_MIN", Default: "300"},
+	{Name: "FC_RL_OAUTH_AUTHORIZE_IP_PER_MIN", Default: "600"},
+	{Name: "FC_RL_OAUTH_AUTHORIZE_CLIENT_PER_MIN", Default: "300"},
+	{Name: "FC_RL_PASSWORD_RESET_IP_PER_HOUR", Default: "20"},
+	{Name: "FC_RL_PASSWORD_RESET_EMAIL_PER_HOUR", Default: "5"},
+	{Name: "FC_OAUTH_TOKEN_IP_RATE_PER_MIN", Default: "120"},
+	{Name: "FC_OAUTH_TOKEN_IP_BURST", Default: "60"},
+	{Name: "FC_OAUTH_TOKEN_CLIENT_RATE_PER_MIN", Default: "60"},
+	{Name: "FC_OAUTH_TOKEN_CLIENT_BURST", Default: "30"},
+	{Name: "FC_OIDC_RATE_PER_MIN", Default: "60"},
+	{Name: "FC_OIDC_BURST", Default: "30"},
+	{Name: "FC_RATE_LIMIT_DISABLE", Default: "unset"},
+	{Name: "FC_REDIS_DEGRADE_POLICY", Default: "unset"},
+	{Name: "FC_REDIS_URL"},
+	{Name: "FC_LOGIN_BACKOFF_FREE_ATTEMPTS", Default: "3"},
+	{Name: "FC_LOGIN_BACKOFF_BASE_SECS", Default: "2"},
+	{Name: "FC_LOGIN_BACKOFF_MAX_SECS", Default: "300"},
+	{Name: "FC_LOGIN_GLOBAL_WINDOW_SECS", Default: "3600"},
+	{Name: "FC_LOGIN_GLOBAL_CEILING", Default: "100"},
+	{Name: "FC_LOGIN_GLOBAL_LOCK_SECS", Default: "900"},
+	{Name: "FC_LOCKOUT_THRESHOLD", Default: "10"},
+	{Name: "FC_LOCKOUT_IP_THRESHOLD", Default: "50"},
+	{Name: "FC_LOCKOUT_WINDOW_SECS", Default: "900"},
+	{Name: "FC_LOCKOUT_BASE_SECS", Default: "300"},
+	{Name: "FC_LOCKOUT_MAX_SECS", Default: "86400"},
+	{Name: "FC_LOCKOUT_LEVEL_TTL_SECS", Default: "86400"},
+	{Name: "FC_LOCKOUT_CHALLENGE_AFTER", Default: "3"},
+	{Name: "FC_LOGIN_CHALLENGE_VERIFY_URL"},
+	{Name: "FC_LOGIN_CHALLENGE_SECRET"},
+	{Name: "FC_SMTP_HOST", Default: "(unset → log-only mailer)", Aliases: []string{"SMTP_HOST"}},
+	{Name: "FC_SMTP_PORT", Default: "587", Aliases: []string{"SMTP_PORT"}},
+	{Name: "FC_SMTP_USERNAME", Default: "\"\"", Aliases: []string{"SMTP_USERNAME"}},
+	{Name: "FC_SMTP_PASSWORD", Default: "\"\"", Aliases: []string{"SMTP_PASSWORD"}},
+	{Name: "FC_SMTP_FROM", Default: "noreply@flowcatalyst.local", Aliases: []string{"SMTP_FROM"}},
+	{Name: "FC_SMTP_SECURE", Default: "false (STARTTLS)", Aliases: []string{"SMTP_SECURE"}},
+	{Name: "FC_WEBAUTHN_RP_ID", Default: "localhost"},
+	{Name: "FC_WEBAUTHN_ORIGINS", Default: "http://localhost:8080", Aliases: []string{"FC_WEBAUTHN_RP_ORIGIN"}},
+	{Name: "FC_ROUTER_HTTP_PREFIX", Default: "/router"},
+	{Name: "FC_DRAIN_TIMEOUT_SECONDS", Default: "60"},
+	{Name: "FLOWCATALYST_DEV_MODE", Default: "false"},
+	{Name: "FC_OUTBOX_PLATFORM_URL", Default: "(**required**: processor logs an error and skips startup without it)", Aliases: []string{"FC_OUTBOX_API_URL", "FC_API_BASE_URL", "FLOWCATALYST_URL"}},
+	{Name: "FC_OUTBOX_PLATFORM_AUTH_TOKEN", Default: "\"\"", Aliases: []string{"FC_OUTBOX_TOKEN", "FC_API_TOKEN"}},
+	{Name: "FC_OUTBOX_BATCH_SIZE", Default: "0 (library default 100)"},
+	{Name: "FC_OUTBOX_MAX_IN_FLIGHT", Default: "0 (library default 1000)"},
+	{Name: "FC_OUTBOX_POLL_INTERVAL_MS", Default: "0 (library default 1000)"},
+	{Name: "FC_OUTBOX_MAX_CONCURRENT_GROUPS", Default: "0 (library default 10)", Aliases: []string{"FC_MAX_CONCURRENT_GROUPS"}},
+	{Name: "FC_OUTBOX_BLOCK_ON_ERROR", Default: "true"},
+	{Name: "FC_OUTBOX_GROUP_POLICY", Default: "\"\" (from FC_OUTBOX_BLOCK_ON_ERROR: block, else skip)"},
+	{Name: "FC_OUTBOX_GROUP_POLICIES", Default: "\"\""},
+	{Name: "FC_OUTBOX_GROUP_PARK_SECONDS", Default: "0 (library default 300)"},
+	{Name: "FC_OUTBOX_STUCK_GROUP_SECONDS", Default: "0 (library default 300)"},
+	{Name: "FC_OUTBOX_ADMIN_PORT", Default: "0 (off)"},
+	{Name: "FC_OUTBOX_TABLE_CONFIG", Default: "(SDK outbox_messages)"},
+	{Name: "FC_OUTBOX_TWO_PHASE_PUBLISH", Default: "false"},
+	{Name: "FC_OUTBOX_ROUTES_PATH", Default: "(everything to FC_OUTBOX_PLATFORM_URL)"},
+	{Name: "FC_OUTBOX_BACKEND", Default: "postgres", Aliases: []string{"FC_OUTBOX_DB_TYPE"}},
+	{Name: "FC_OUTBOX_MONGO_URI", Aliases: []string{"FC_OUTBOX_DB_URL"}},
+	{Name: "FC_OUTBOX_MONGO_DB", Default: "flowcatalyst"},
+	{Name: "FC_OUTBOX_MONGO_MAX_POOL_SIZE", Default: "50"},
+	{Name: "FC_OUTBOX_MONGO_MIN_POOL_SIZE", Default: "0"},
+	{Name: "FC_OUTBOX_MONGO_CONNECT_TIMEOUT_MS", Default: "10000"},
+	{Name: "FC_OUTBOX_MONGO_SERVER_SELECTION_TIMEOUT_MS", Default: "10000"},
+	{Name: "FC_OUTBOX_MONGO_RETRY_WRITES", Default: "true"},
+	{Name: "FC_OUTBOX_MONGO_RETRY_READS", Default: "true"},
+	{Name: "FC_OUTBOX_MONGO_COMPRESSORS", Default: "\"\" (off)"},
+	{Name: "FC_OUTBOX_MONGO_CLAIM_LEASE_SECS", Default: "300"},
+	{Name: "FC_OUTBOX_SOURCE_DB_URL"},
+	{Name: "FC_OUTBOX_CLIENT_ID", Aliases: []string{"FLOWCATALYST_CLIENT_ID"}},
+	{Name: "FC_OUTBOX_CLIENT_SECRET", Aliases: []string{"FLOWCATALYST_CLIENT_SECRET"}},
+	{Name: "FC_OUTBOX_TOKEN_URL", Default: "<target-url>/oauth/token"},
+	{Name: "FC_OUTBOX_SCOPE"},
+	{Name: "FC_STREAM_EVENTS_ENABLED", Default: "true"},
+	{Name: "FC_STREAM_DISPATCH_JOBS_ENABLED", Default: "true"},
+	{Name: "FC_STREAM_FAN_OUT_ENABLED", Default: "true"},
+	{Name: "FC_STREAM_PARTITION_MANAGER_ENABLED", Default: "true", Aliases: []string{"FC_STREAM_PARTITIONS_ENABLED"}},
+	{Name: "FC_STREAM_BATCH_SIZE", Default: "0 (per-projection defaults)"},
+	{Name: "FC_STREAM_EVENTS_BATCH_SIZE", Default: "0 (default 100)"},
+	{Name: "FC_STREAM_DISPATCH_JOBS_BATCH_SIZE", Default: "0 (default 100)"},
+	{Name: "FC_STREAM_FAN_OUT_BATCH_SIZE", Default: "0 (default 200)"},
+	{Name: "FC_STREAM_FAN_OUT_SUBS_REFRESH_SECS", Default: "0 (default 5s)"},
+	{Name: "FC_STREAM_FAN_OUT_MAX_ATTEMPTS", Default: "0 (default 3)"},
+	{Name: "FC_STREAM_PARTITION_MONTHS_FORWARD", Default: "0 (default 3)"},
+	{Name: "FC_STREAM_PARTITION_RETENTION_DAYS", Default: "0 (default 90)"},
+	{Name: "FC_STREAM_PARTITION_TICK_HOURS", Default: "0 (default 24)"},
+	{Name: "FC_STREAM_RETENTION_ENABLED", Default: "true"},
+	{Name: "FC_STREAM_RETENTION_BATCH_SIZE", Default: "0 (default 1000)"},
+	{Name: "FC_STREAM_RETENTION_TICK_MINUTES", Default: "0 (default 60)"},
+	{Name: "FC_STREAM_READ_NAMES_ENABLED", Default: "true"},
+	{Name: "FC_STREAM_READ_NAMES_TICK_SECS", Default: "0 (default 60)"},
+	{Name: "FC_STREAM_DELIVERY_STATS_ENABLED", Default: "true"},
+	{Name: "FC_STREAM_DELIVERY_STATS_TICK_SECS", Default: "0 (default 60)"},
+	{Name: "FC_STREAM_DELIVERY_STATS_RETENTION_DAYS", Default: "0 (default 30)"},
+	{Name: "FC_STREAM_ANOMALIES_ENABLED", Default: "true"},
+	{Name: "FC_STREAM_ANOMALIES_WINDOW_MINS", Default: "0 (default 15)"},
+	{Name: "FC_STREAM_ANOMALIES_MIN_EVENTS", Default: "0 (default 10)"},
+	{Name: "FC_SCHEDULED_JOB_POLL_SECONDS", Default: "30"},
+	{Name: "FC_SCHEDULED_JOB_DISPATCH_SECONDS", Default: "5"},
+	{Name: "FC_SCHEDULED_JOB_DISPATCH_BATCH", Default: "32"},
+	{Name: "FC_SCHEDULED_JOB_HTTP_TIMEOUT_SECONDS", Default: "10"},
+	{Name: "FC_STANDBY_ENABLED", Default: "false", Aliases: []string{"STANDBY_ENABLED"}},
+	{Name: "FC_STANDBY_REDIS_URL", Default: "redis://127.0.0.1:6379", Aliases: []string{"REDIS_URL"}},
+	{Name: "FC_STANDBY_LOCK_KEY", Default: "fc:server:leader"},
+	{Name: "FC_MCP_PORT", Default: "8090"},
+	{Name: "FC_MCP_BIND", Default: "127.0.0.1 (localhost-only)"},
+	{Name: "FLOWCATALYST_URL", Default: "http://localhost:8080 (after credentials-file fallback)", Aliases: []string{"FC_MCP_PLATFORM_URL"}},
+	{Name: "FLOWCATALYST_CLIENT_ID", Default: "(credentials-file fallback)"},
+	{Name: "FLOWCATALYST_CLIENT_SECRET", Default: "(credentials-file fallback)"},
+	{Name: "FLOWCATALYST_BOOTSTRAP_ADMIN_EMAIL"},
+	{Name: "FLOWCATALYST_BOOTSTRAP_ADMIN_PASSWORD"},
+	{Name: "FLOWCATALYST_BOOTSTRAP_ADMIN_NAME", Default: "Bootstrap Admin"},
+	{Name: "FC_BOOTSTRAP_ADMIN_EMAIL"},
+	{Name: "FC_BOOTSTRAP_ADMIN_PASSWORD"},
+	{Name: "FC_LOG_LEVEL", Default: "info"},
+	{Name: "FC_LOG_ADMIN_TOKEN", Default: "(loopback only)"},
+	{Name: "FLOWCATALYST_CONFIG_URL"},
+	{Name: "FC_NOTIFY_WEBHOOK_URL", Default: "(log-only)"},
+	{Name: "FC_ROUTER_ATTEMPT_SINK", Default: "(off)"},
+	{Name: "FC_ROUTER_ATTEMPT_SINK_URL"},
+	{Name: "FC_ROUTER_ATTEMPT_SINK_TOPIC"},
+	{Name: "FC_ROUTER_ATTEMPT_SINK_PATH"},
+	{Name: "FC_ROUTER_ATTEMPT_SINK_TOKEN"},
+	{Name: "FC_ROUTER_ATTEMPT_SINK_BUFFER", Default: "10000"},
+	{Name: "FC_ROUTER_ATTEMPT_SINK_BATCH_SIZE", Default: "500"},
+	{Name: "FC_ROUTER_ATTEMPT_SINK_FLUSH_MS", Default: "1000"},
+	{Name: "FC_ROUTER_EGRESS_BIND", Default: "(OS default)"},
+	{Name: "FC_ROUTER_PREFLIGHT_SECS", Default: "300"},
+	{Name: "FC_ROUTER_PREFLIGHT_NEGATIVE_SECS", Default: "60"},
+	{Name: "FC_ROUTER_SLOW_START_SECS", Default: "0 (off)"},
+	{Name: "FC_ROUTER_SLOW_START_CURVE", Default: "linear"},
+	{Name: "FC_ROUTER_SLOW_START_MIN_PERCENT", Default: "10"},
+	{Name: "FC_ROUTER_WARM_TARGETS", Default: "0 (off)"},
+	{Name: "FC_ROUTER_GROUP_HANDOVER_SECS", Default: "30"},
+	{Name: "FC_ROUTER_STATE_SNAPSHOT", Default: "(off)"},
+	{Name: "FC_ROUTER_STATE_SNAPSHOT_MAX_AGE_SECS", Default: "300"},
+	{Name: "FC_ROUTER_CONFIG_HISTORY_MONGO_URI", Default: "(off)"},
+	{Name: "FC_ROUTER_CONFIG_HISTORY_MONGO_DB", Default: "flowcatalyst"},
+	{Name: "FC_ROUTER_CONFIG_HISTORY_RETENTION_DAYS", Default: "30"},
+	{Name: "FC_ROUTER_PUBLISH_RATE_PER_SEC", Default: "0 (unlimited)"},
+	{Name: "FC_SCALE_MESSAGES_PER_REPLICA", Default: "100"},
+	{Name: "FC_SCALE_QUEUE_TARGETS"},
+	{Name: "FC_SCALE_MIN_REPLICAS", Default: "1"},
+	{Name: "FC_SCALE_MAX_REPLICAS", Default: "0 (unbounded)"},
+	{Name: "FC_EGRESS_IPS"},
+	{Name: "FC_EGRESS_IPS_DISCOVER_URL", Default: "(off)"},
+	{Name: "FC_ALB_ENABLED", Default: "false"},
+	{Name: "FC_ALB_TARGET_GROUP_ARN"},
+	{Name: "FC_ALB_TARGET_ID", Aliases: []string{"FC_ALB_INSTANCE_IP"}},
+	{Name: "FC_ALB_TARGET_PORT", Default: "8080"},
+	{Name: "FC_ALB_REGION", Default: "(AWS SDK default region chain)"},
+	{Name: "FC_ALB_DEREGISTRATION_DELAY_SECONDS", Default: "0"},
+	{Name: "XDG_DATA_HOME", Default: "OS app-data dir"},
+	{Name: "FC_EMBEDDED_DB", Default: "true"},
+	{Name: "FC_EMBEDDED_DB_PORT", Default: "15432"},
+	{Name: "FC_EMBEDDED_DB_PATH", Default: "<user-data-dir>/flowcatalyst/embedded-pg"},
+	{Name: "FC_DEV_SEED_SCENARIO", Default: "\"\" (none)"},
+	{Name: "FC_DEV_UPGRADE_REPO", Default: "flowcatalyst/flowcatalyst"},
+	{Name: "FC_DEV_PID_FILE", Default: "<user-data-dir>/flowcatalyst/fc-dev.pid"},
+	{Name: "FC_CLI_CONFIG", Default: "~/.flowcatalyst/config.yaml"},
+	{Name: "FC_CLI_PROFILE", Default: "the file's current profile, else default"},
+	{Name: "FC_BASE_URL", Default: "(required)"},
+	{Name: "FC_TOKEN", Default: "(required by fc-sync / scheduled-jobs-runner; optional in list-event-types)"},
+	{Name: "FC_APP"},
+	{Name: "FC_ISSUER"},
+	{Name: "FC_CLIENT_ID"},
+	{Name: "FC_CLIENT_SECRET"},
+}
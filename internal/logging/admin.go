@@ -0,0 +1,89 @@
+package logging
+
+import (
+	"encoding/json"
+	"errors"
+	"net/http"
+)
+
+// LevelRequest is the PUT /admin/log-level body. An empty Target changes the
+// default level; an empty Level clears Target's override. Filter, when set,
+// replaces the whole filter with a directive list instead.
+type LevelRequest struct {
+	Target string `json:"target,omitempty"`
+	Level  string `json:"level,omitempty"`
+	Filter string `json:"filter,omitempty"`
+}
+
+// LevelResponse reports the filter after a read or change.
+type LevelResponse struct {
+	Default string            `json:"default"`
+	Targets map[string]string `json:"targets"`
+	Filter  string            `json:"filter"`
+}
+
+// AdminHandler serves the runtime level filter:
+//
+//	GET /admin/log-level — current default, per-target overrides and directive string
+//	PUT /admin/log-level — change one target (or the default), or replace the filter
+//
+// Changes apply immediately and are not persisted; a restart reverts to
+// FC_LOG_LEVEL. The caller is responsible for access control.
+func AdminHandler(levels *Levels) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.Method {
+		case http.MethodGet:
+		case http.MethodPut:
+			var req LevelRequest
+			if err := json.NewDecoder(r.Body).Decode(&req); err != nil {
+				writeJSON(w, http.StatusBadRequest, map[string]string{"error": "invalid JSON body"})
+				return
+			}
+			if err := apply(levels, req); err != nil {
+				writeJSON(w, http.StatusBadRequest, map[string]string{"error": err.Error()})
+				return
+			}
+		default:
+			w.Header().Set("Allow", "GET, PUT")
+			w.WriteHeader(http.StatusMethodNotAllowed)
+			return
+		}
+		writeJSON(w, http.StatusOK, snapshot(levels))
+	})
+}
+
+var errLevelRequired = errors.New("level is required to change the default")
+
+func apply(levels *Levels, req LevelRequest) error {
+	if req.Filter != "" {
+		return levels.Parse(req.Filter)
+	}
+	if req.Level == "" {
+		if req.Target == "" {
+			return errLevelRequired
+		}
+		levels.Clear(req.Target)
+		return nil
+	}
+	lvl, err := ParseLevel(req.Level)
+	if err != nil {
+		return err
+	}
+	levels.Set(req.Target, lvl)
+	return nil
+}
+
+func snapshot(levels *Levels) LevelResponse {
+	def, targets := levels.Snapshot()
+	out := LevelResponse{Default: LevelName(def), Targets: map[string]string{}, Filter: levels.String()}
+	for t, lvl := range targets {
+		out.Targets[t] = LevelName(lvl)
+	}
+	return out
+}
+
+func writeJSON(w http.ResponseWriter, status int, v any) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	_ = json.NewEncoder(w).Encode(v)
+}
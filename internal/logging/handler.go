@@ -0,0 +1,66 @@
+package logging
+
+import (
+	"context"
+	"log/slog"
+)
+
+// Handler wraps another slog.Handler with the per-target filter and
+// context enrichment: records logged with a context (slog.InfoContext and
+// friends) carry the trace IDs and any fields added with With, the way a
+// tracing span's fields ride along with every event inside it.
+type Handler struct {
+	inner  slog.Handler
+	levels *Levels
+	// bound holds keys already attached via WithAttrs, so a logger from
+	// FromContext that is also handed the context doesn't log them twice.
+	bound map[string]bool
+}
+
+// NewHandler wraps inner. inner should use levels as its Leveler so its own
+// Enabled check admits everything some target wants.
+func NewHandler(inner slog.Handler, levels *Levels) *Handler {
+	return &Handler{inner: inner, levels: levels}
+}
+
+// Enabled implements slog.Handler.
+func (h *Handler) Enabled(ctx context.Context, lvl slog.Level) bool {
+	return h.inner.Enabled(ctx, lvl)
+}
+
+// Handle implements slog.Handler.
+func (h *Handler) Handle(ctx context.Context, r slog.Record) error {
+	if !h.levels.enabledAt(r.Level, r.PC) {
+		return nil
+	}
+	if fields := contextAttrs(ctx); len(fields) > 0 {
+		present := map[string]bool{}
+		r.Attrs(func(a slog.Attr) bool {
+			present[a.Key] = true
+			return true
+		})
+		for _, a := range fields {
+			if !present[a.Key] && !h.bound[a.Key] {
+				r.AddAttrs(a)
+			}
+		}
+	}
+	return h.inner.Handle(ctx, r)
+}
+
+// WithAttrs implements slog.Handler.
+func (h *Handler) WithAttrs(attrs []slog.Attr) slog.Handler {
+	bound := make(map[string]bool, len(h.bound)+len(attrs))
+	for k := range h.bound {
+		bound[k] = true
+	}
+	for _, a := range attrs {
+		bound[a.Key] = true
+	}
+	return &Handler{inner: h.inner.WithAttrs(attrs), levels: h.levels, bound: bound}
+}
+
+// WithGroup implements slog.Handler.
+func (h *Handler) WithGroup(name string) slog.Handler {
+	return &Handler{inner: h.inner.WithGroup(name), levels: h.levels, bound: h.bound}
+}
@@ -0,0 +1,212 @@
+package logging
+
+import (
+	"fmt"
+	"log/slog"
+	"runtime"
+	"sort"
+	"strings"
+	"sync"
+	"sync/atomic"
+)
+
+// modulePath is stripped from package paths so targets read like the
+// source tree ("internal/router", not the full import path).
+const modulePath = "github.com/flowcatalyst/flowcatalyst-go/"
+
+// LevelTrace sits below debug for parity with the Rust tracing levels.
+const LevelTrace = slog.LevelDebug - 4
+
+// Levels is the runtime log filter: a default level plus per-target
+// overrides, the slog counterpart of a tracing EnvFilter. A target is a
+// package path relative to the module root ("internal/router") and covers
+// its subpackages; third-party packages use their full import path. The
+// most specific matching target wins.
+//
+// Levels implements slog.Leveler by reporting the lowest level any target
+// enables, so the handler's fast path stays a single comparison and only
+// records that pass it pay for the per-target lookup.
+type Levels struct {
+	mu      sync.RWMutex
+	def     slog.Level
+	targets map[string]slog.Level
+	min     atomic.Int64
+	pkgs    sync.Map // record PC -> package target
+}
+
+// NewLevels returns a filter with the given default and no overrides.
+func NewLevels(def slog.Level) *Levels {
+	l := &Levels{def: def, targets: map[string]slog.Level{}}
+	l.min.Store(int64(def))
+	return l
+}
+
+// Level implements slog.Leveler.
+func (l *Levels) Level() slog.Level { return slog.Level(l.min.Load()) }
+
+// Set sets the level for target; the empty target is the default.
+func (l *Levels) Set(target string, lvl slog.Level) {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	if target == "" {
+		l.def = lvl
+	} else {
+		l.targets[target] = lvl
+	}
+	l.recompute()
+}
+
+// Clear drops the override for target so it inherits again.
+func (l *Levels) Clear(target string) {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	delete(l.targets, target)
+	l.recompute()
+}
+
+// Parse replaces the whole filter with a directive list such as
+// "info,internal/router=debug,internal/stream=warn". A bare level sets the
+// default; an empty string resets to info with no overrides.
+func (l *Levels) Parse(directives string) error {
+	def := slog.LevelInfo
+	targets := map[string]slog.Level{}
+	for _, d := range strings.Split(directives, ",") {
+		d = strings.TrimSpace(d)
+		if d == "" {
+			continue
+		}
+		target, level, ok := strings.Cut(d, "=")
+		if !ok {
+			lvl, err := ParseLevel(d)
+			if err != nil {
+				return err
+			}
+			def = lvl
+			continue
+		}
+		target = strings.TrimSpace(target)
+		if target == "" {
+			return fmt.Errorf("log directive %q: empty target", d)
+		}
+		lvl, err := ParseLevel(level)
+		if err != nil {
+			return fmt.Errorf("log directive %q: %w", d, err)
+		}
+		targets[target] = lvl
+	}
+
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	l.def = def
+	l.targets = targets
+	l.recompute()
+	return nil
+}
+
+// Snapshot returns the default level and a copy of the overrides.
+func (l *Levels) Snapshot() (slog.Level, map[string]slog.Level) {
+	l.mu.RLock()
+	defer l.mu.RUnlock()
+	out := make(map[string]slog.Level, len(l.targets))
+	for t, lvl := range l.targets {
+		out[t] = lvl
+	}
+	return l.def, out
+}
+
+// String renders the filter in Parse's directive syntax.
+func (l *Levels) String() string {
+	def, targets := l.Snapshot()
+	parts := []string{LevelName(def)}
+	names := make([]string, 0, len(targets))
+	for t := range targets {
+		names = append(names, t)
+	}
+	sort.Strings(names)
+	for _, t := range names {
+		parts = append(parts, t+"="+LevelName(targets[t]))
+	}
+	return strings.Join(parts, ",")
+}
+
+// For returns the effective level for a package target.
+func (l *Levels) For(pkg string) slog.Level {
+	l.mu.RLock()
+	defer l.mu.RUnlock()
+	return l.forLocked(pkg)
+}
+
+// enabledAt reports whether a record logged at lvl from pc passes.
+func (l *Levels) enabledAt(lvl slog.Level, pc uintptr) bool {
+	l.mu.RLock()
+	defer l.mu.RUnlock()
+	if len(l.targets) == 0 || pc == 0 {
+		return lvl >= l.def
+	}
+	return lvl >= l.forLocked(l.pkgOf(pc))
+}
+
+func (l *Levels) forLocked(pkg string) slog.Level {
+	lvl, best := l.def, -1
+	for t, tl := range l.targets {
+		if (pkg == t || strings.HasPrefix(pkg, t+"/")) && len(t) > best {
+			lvl, best = tl, len(t)
+		}
+	}
+	return lvl
+}
+
+func (l *Levels) recompute() {
+	lowest := l.def
+	for _, lvl := range l.targets {
+		lowest = min(lowest, lvl)
+	}
+	l.min.Store(int64(lowest))
+}
+
+// pkgOf resolves the package a record was logged from. Results are cached
+// per PC: a process has a bounded set of log call sites.
+func (l *Levels) pkgOf(pc uintptr) string {
+	if v, ok := l.pkgs.Load(pc); ok {
+		return v.(string)
+	}
+	frame, _ := runtime.CallersFrames([]uintptr{pc}).Next()
+	pkg := packageOf(frame.Function)
+	l.pkgs.Store(pc, pkg)
+	return pkg
+}
+
+// packageOf trims a qualified function name
+// ("…/internal/router.(*Pool).processOne") to its package target.
+func packageOf(fn string) string {
+	slash := strings.LastIndex(fn, "/")
+	if dot := strings.Index(fn[slash+1:], "."); dot >= 0 {
+		fn = fn[:slash+1+dot]
+	}
+	return strings.TrimPrefix(fn, modulePath)
+}
+
+// ParseLevel accepts trace, debug, info, warn/warning and error in any
+// case, plus slog's offset forms ("INFO+2").
+func ParseLevel(s string) (slog.Level, error) {
+	s = strings.TrimSpace(s)
+	switch strings.ToLower(s) {
+	case "trace":
+		return LevelTrace, nil
+	case "warning":
+		return slog.LevelWarn, nil
+	}
+	var lvl slog.Level
+	if err := lvl.UnmarshalText([]byte(s)); err != nil {
+		return 0, fmt.Errorf("unknown log level %q", s)
+	}
+	return lvl, nil
+}
+
+// LevelName renders a level the way ParseLevel reads it back.
+func LevelName(lvl slog.Level) string {
+	if lvl == LevelTrace {
+		return "trace"
+	}
+	return strings.ToLower(lvl.String())
+}
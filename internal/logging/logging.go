@@ -4,6 +4,10 @@
 // codebases can be aggregated in the same pipeline during cutover:
 //   - correlation_id, causation_id, principal_id, execution_id
 //   - aggregate_type, aggregate_id, event_type
+//
+// Subsystems attach per-unit-of-work fields (message_id, pool, …) with
+// With; every record logged with that context carries them. Levels can be
+// changed per package at runtime through Filter (see AdminHandler).
 package logging
 
 import (
@@ -19,22 +23,25 @@ const (
 	causationIDKey
 	principalIDKey
 	executionIDKey
+	fieldsKey
 )
 
+// filter is the process-wide level filter installed by Init.
+var filter = NewLevels(slog.LevelInfo)
+
+// Filter returns the process-wide level filter.
+func Filter() *Levels { return filter }
+
 // Init configures the default slog logger with JSON output to stderr.
-// Level is read from FC_LOG_LEVEL (default info).
+// Levels are read from FC_LOG_LEVEL (default info), either a bare level or
+// a directive list such as "info,internal/router=debug".
 func Init() {
-	lvl := slog.LevelInfo
-	switch os.Getenv("FC_LOG_LEVEL") {
-	case "debug", "DEBUG":
-		lvl = slog.LevelDebug
-	case "warn", "WARN", "warning", "WARNING":
-		lvl = slog.LevelWarn
-	case "error", "ERROR":
-		lvl = slog.LevelError
+	err := filter.Parse(os.Getenv("FC_LOG_LEVEL"))
+	inner := slog.NewJSONHandler(os.Stderr, &slog.HandlerOptions{Level: filter})
+	slog.SetDefault(slog.New(NewHandler(inner, filter)))
+	if err != nil {
+		slog.Warn("invalid FC_LOG_LEVEL; using info", "value", os.Getenv("FC_LOG_LEVEL"), "err", err)
 	}
-	h := slog.NewJSONHandler(os.Stderr, &slog.HandlerOptions{Level: lvl})
-	slog.SetDefault(slog.New(h))
 }
 
 // WithCorrelationID stores a correlation ID on the context for log enrichment.
@@ -57,20 +64,51 @@ func WithExecutionID(ctx context.Context, id string) context.Context {
 	return context.WithValue(ctx, executionIDKey, id)
 }
 
+// With returns a context whose log records carry args (slog key/value
+// pairs or Attrs) in addition to any fields already on ctx.
+func With(ctx context.Context, args ...any) context.Context {
+	r := slog.Record{}
+	r.Add(args...)
+	prev, _ := ctx.Value(fieldsKey).([]slog.Attr)
+	fields := make([]slog.Attr, 0, len(prev)+r.NumAttrs())
+	fields = append(fields, prev...)
+	r.Attrs(func(a slog.Attr) bool {
+		fields = append(fields, a)
+		return true
+	})
+	return context.WithValue(ctx, fieldsKey, fields)
+}
+
 // FromContext returns a logger enriched with the trace fields stored on the context.
 func FromContext(ctx context.Context) *slog.Logger {
 	l := slog.Default()
-	if v, ok := ctx.Value(correlationIDKey).(string); ok && v != "" {
-		l = l.With("correlation_id", v)
+	for _, a := range contextAttrs(ctx) {
+		l = l.With(a)
 	}
-	if v, ok := ctx.Value(causationIDKey).(string); ok && v != "" {
-		l = l.With("causation_id", v)
+	return l
+}
+
+// contextAttrs collects the trace IDs and With fields stored on ctx.
+func contextAttrs(ctx context.Context) []slog.Attr {
+	if ctx == nil {
+		return nil
 	}
-	if v, ok := ctx.Value(principalIDKey).(string); ok && v != "" {
-		l = l.With("principal_id", v)
+	var out []slog.Attr
+	for _, f := range []struct {
+		key  ctxKey
+		name string
+	}{
+		{correlationIDKey, "correlation_id"},
+		{causationIDKey, "causation_id"},
+		{principalIDKey, "principal_id"},
+		{executionIDKey, "execution_id"},
+	} {
+		if v, ok := ctx.Value(f.key).(string); ok && v != "" {
+			out = append(out, slog.String(f.name, v))
+		}
 	}
-	if v, ok := ctx.Value(executionIDKey).(string); ok && v != "" {
-		l = l.With("execution_id", v)
+	if fields, ok := ctx.Value(fieldsKey).([]slog.Attr); ok {
+		out = append(out, fields...)
 	}
-	return l
+	return out
 }
@@ -0,0 +1,147 @@
+package logging
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"log/slog"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func newTestLogger(levels *Levels) (*slog.Logger, *bytes.Buffer) {
+	var buf bytes.Buffer
+	inner := slog.NewJSONHandler(&buf, &slog.HandlerOptions{Level: levels})
+	return slog.New(NewHandler(inner, levels)), &buf
+}
+
+func decodeLines(t *testing.T, buf *bytes.Buffer) []map[string]any {
+	t.Helper()
+	var out []map[string]any
+	for _, line := range strings.Split(strings.TrimSpace(buf.String()), "\n") {
+		if line == "" {
+			continue
+		}
+		var m map[string]any
+		require.NoError(t, json.Unmarshal([]byte(line), &m))
+		out = append(out, m)
+	}
+	return out
+}
+
+func TestParseDirectivesRoundTrip(t *testing.T) {
+	l := NewLevels(slog.LevelInfo)
+	require.NoError(t, l.Parse("warn, internal/router=debug,internal/stream=TRACE"))
+	assert.Equal(t, "warn,internal/router=debug,internal/stream=trace", l.String())
+	assert.Equal(t, LevelTrace, l.Level(), "Leveler reports the lowest enabled level")
+
+	require.NoError(t, l.Parse(""))
+	assert.Equal(t, "info", l.String())
+
+	assert.Error(t, l.Parse("internal/router=loud"))
+	assert.Error(t, l.Parse("=debug"))
+}
+
+func TestMostSpecificTargetWins(t *testing.T) {
+	l := NewLevels(slog.LevelInfo)
+	l.Set("internal/router", slog.LevelDebug)
+	l.Set("internal/router/api", slog.LevelError)
+
+	assert.Equal(t, slog.LevelDebug, l.For("internal/router"))
+	assert.Equal(t, slog.LevelError, l.For("internal/router/api"))
+	assert.Equal(t, slog.LevelInfo, l.For("internal/routerx"), "targets match whole path segments")
+
+	l.Clear("internal/router")
+	assert.Equal(t, slog.LevelInfo, l.For("internal/router"))
+	assert.Equal(t, slog.LevelInfo, l.Level())
+}
+
+func TestPackageOf(t *testing.T) {
+	assert.Equal(t, "internal/router",
+		packageOf("github.com/flowcatalyst/flowcatalyst-go/internal/router.(*Pool).processOne"))
+	assert.Equal(t, "github.com/jackc/pgx/v5/pgxpool", packageOf("github.com/jackc/pgx/v5/pgxpool.(*Pool).Acquire"))
+	assert.Equal(t, "main", packageOf("main.main"))
+}
+
+func TestHandlerFiltersByCallerPackage(t *testing.T) {
+	levels := NewLevels(slog.LevelDebug)
+	log, buf := newTestLogger(levels)
+
+	levels.Set("internal/logging", slog.LevelWarn)
+	log.Info("dropped")
+	log.Warn("kept")
+
+	levels.Clear("internal/logging")
+	log.Debug("kept again")
+
+	lines := decodeLines(t, buf)
+	require.Len(t, lines, 2)
+	assert.Equal(t, "kept", lines[0]["msg"])
+	assert.Equal(t, "kept again", lines[1]["msg"])
+}
+
+func TestHandlerAddsContextFields(t *testing.T) {
+	log, buf := newTestLogger(NewLevels(slog.LevelInfo))
+
+	ctx := WithCorrelationID(context.Background(), "corr-1")
+	ctx = With(ctx, "message_id", "m-1", "pool", "DEFAULT-POOL")
+	ctx = With(ctx, slog.String("queue", "q"))
+
+	log.InfoContext(ctx, "delivered", "message_id", "m-1")
+	log.With("pool", "bound").InfoContext(ctx, "bound")
+	log.Info("no context")
+
+	lines := decodeLines(t, buf)
+	require.Len(t, lines, 3)
+	assert.Equal(t, "corr-1", lines[0]["correlation_id"])
+	assert.Equal(t, "m-1", lines[0]["message_id"])
+	assert.Equal(t, "DEFAULT-POOL", lines[0]["pool"])
+	assert.Equal(t, "q", lines[0]["queue"])
+	first := strings.SplitN(buf.String(), "\n", 2)[0]
+	assert.Equal(t, 1, strings.Count(first, `"message_id"`), "a field logged explicitly is not repeated from the context")
+	assert.Equal(t, "bound", lines[1]["pool"])
+	assert.NotContains(t, lines[2], "message_id")
+}
+
+func TestAdminHandler(t *testing.T) {
+	levels := NewLevels(slog.LevelInfo)
+	h := AdminHandler(levels)
+
+	put := func(body string) *httptest.ResponseRecorder {
+		rec := httptest.NewRecorder()
+		h.ServeHTTP(rec, httptest.NewRequest(http.MethodPut, "/admin/log-level", strings.NewReader(body)))
+		return rec
+	}
+
+	rec := put(`{"target":"internal/router","level":"debug"}`)
+	require.Equal(t, http.StatusOK, rec.Code)
+	var resp LevelResponse
+	require.NoError(t, json.Unmarshal(rec.Body.Bytes(), &resp))
+	assert.Equal(t, "info", resp.Default)
+	assert.Equal(t, map[string]string{"internal/router": "debug"}, resp.Targets)
+	assert.Equal(t, "info,internal/router=debug", resp.Filter)
+
+	require.Equal(t, http.StatusOK, put(`{"level":"warn"}`).Code)
+	assert.Equal(t, slog.LevelWarn, levels.For("internal/stream"))
+
+	require.Equal(t, http.StatusOK, put(`{"target":"internal/router"}`).Code)
+	assert.Equal(t, "warn", levels.String())
+
+	require.Equal(t, http.StatusOK, put(`{"filter":"error,internal/outbox=info"}`).Code)
+	assert.Equal(t, "error,internal/outbox=info", levels.String())
+
+	assert.Equal(t, http.StatusBadRequest, put(`{"level":"loud"}`).Code)
+	assert.Equal(t, http.StatusBadRequest, put(`{}`).Code)
+	assert.Equal(t, http.StatusBadRequest, put(`not json`).Code)
+
+	rec = httptest.NewRecorder()
+	h.ServeHTTP(rec, httptest.NewRequest(http.MethodGet, "/admin/log-level", nil))
+	require.Equal(t, http.StatusOK, rec.Code)
+	require.NoError(t, json.Unmarshal(rec.Body.Bytes(), &resp))
+	assert.Equal(t, "error", resp.Default)
+}
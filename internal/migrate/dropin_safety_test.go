@@ -66,6 +66,14 @@ func TestDropInSafety(t *testing.T) {
 	if err := Run(ctx, pool); err != nil {
 		t.Fatalf("initial migrate: %v", err)
 	}
+	// A fresh schema holds exactly the indexes the migrations declare.
+	drift, err := CheckIndexes(ctx, pool)
+	if err != nil {
+		t.Fatalf("check indexes: %v", err)
+	}
+	if !drift.InSync() {
+		t.Fatalf("fresh schema has index drift: missing=%v extra=%v", drift.Missing, drift.Extra)
+	}
 	mustExec(`INSERT INTO msg_events (id, type, source, time) VALUES ('SENTINEL','test','test',NOW())`)
 	if sentinelCount() != 1 {
 		t.Fatal("sentinel not inserted")
@@ -0,0 +1,183 @@
+package migrate
+
+import (
+	"context"
+	"fmt"
+	"io/fs"
+	"path"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// Index is one secondary index the migrations declare.
+type Index struct {
+	Name       string `json:"name"`
+	Table      string `json:"table"`
+	Definition string `json:"definition,omitempty"` // the CREATE INDEX statement, whitespace-collapsed
+}
+
+// IndexDrift compares the indexes the embedded migrations declare with
+// the ones present in the database. Missing indexes are the actionable
+// half — a repository query relying on one degrades to a sequential
+// scan; Extra lists indexes nobody declared (hand-made hotfixes that
+// should become a migration, or leftovers a migration forgot to drop).
+type IndexDrift struct {
+	Expected int     `json:"expected"`
+	Missing  []Index `json:"missing"`
+	Extra    []Index `json:"extra"`
+}
+
+// InSync reports whether the live schema matches the migrations.
+func (d IndexDrift) InSync() bool { return len(d.Missing) == 0 && len(d.Extra) == 0 }
+
+var (
+	sqlCommentRe   = regexp.MustCompile(`--[^\n]*`)
+	createIndexRe  = regexp.MustCompile(`(?i)\bCREATE\s+(?:UNIQUE\s+)?INDEX\s+(?:CONCURRENTLY\s+)?(?:IF\s+NOT\s+EXISTS\s+)?([a-z0-9_]+)\s+ON\s+(?:ONLY\s+)?([a-z0-9_]+)[^;]*`)
+	dropIndexRe    = regexp.MustCompile(`(?i)\bDROP\s+INDEX\s+(?:CONCURRENTLY\s+)?(?:IF\s+EXISTS\s+)?([a-z0-9_]+)`)
+	createTableRe  = regexp.MustCompile(`(?i)\bCREATE\s+TABLE\s+(?:IF\s+NOT\s+EXISTS\s+)?([a-z0-9_]+)`)
+	dropTableRe    = regexp.MustCompile(`(?i)\bDROP\s+TABLE\s+(?:IF\s+EXISTS\s+)?([a-z0-9_]+)`)
+	indexStatement = []*regexp.Regexp{createIndexRe, dropIndexRe, createTableRe, dropTableRe}
+)
+
+// DeclaredIndexes replays the embedded migrations' index DDL in order —
+// CREATE INDEX adds, DROP INDEX removes, DROP TABLE removes the table's
+// indexes — and returns the resulting set, plus every table the
+// migrations create. Conditional blocks (the 019/022 partition rebuilds)
+// are replayed unconditionally; they drop and recreate the same names,
+// so the end state is what a fully migrated database holds.
+func DeclaredIndexes() (indexes []Index, tables map[string]bool, err error) {
+	files, err := fs.Glob(migrationsFS, "sql/*.sql")
+	if err != nil {
+		return nil, nil, err
+	}
+	sort.Strings(files)
+
+	declared := map[string]Index{}
+	tables = map[string]bool{}
+	for _, f := range files {
+		raw, err := fs.ReadFile(migrationsFS, f)
+		if err != nil {
+			return nil, nil, fmt.Errorf("read %s: %w", path.Base(f), err)
+		}
+		replayIndexDDL(sqlCommentRe.ReplaceAllString(string(raw), ""), declared, tables)
+	}
+
+	indexes = make([]Index, 0, len(declared))
+	for _, ix := range declared {
+		indexes = append(indexes, ix)
+	}
+	sortIndexes(indexes)
+	return indexes, tables, nil
+}
+
+// replayIndexDDL applies one migration's statements, in file order.
+func replayIndexDDL(sql string, declared map[string]Index, tables map[string]bool) {
+	type match struct {
+		re  *regexp.Regexp
+		loc []int
+	}
+	var matches []match
+	for _, re := range indexStatement {
+		for _, loc := range re.FindAllStringSubmatchIndex(sql, -1) {
+			matches = append(matches, match{re, loc})
+		}
+	}
+	sort.Slice(matches, func(i, j int) bool { return matches[i].loc[0] < matches[j].loc[0] })
+
+	for _, m := range matches {
+		name := strings.ToLower(sql[m.loc[2]:m.loc[3]])
+		switch m.re {
+		case createIndexRe:
+			declared[name] = Index{
+				Name:       name,
+				Table:      strings.ToLower(sql[m.loc[4]:m.loc[5]]),
+				Definition: strings.Join(strings.Fields(sql[m.loc[0]:m.loc[1]]), " "),
+			}
+		case dropIndexRe:
+			delete(declared, name)
+		case createTableRe:
+			tables[name] = true
+		case dropTableRe:
+			for k, ix := range declared {
+				if ix.Table == name {
+					delete(declared, k)
+				}
+			}
+		}
+	}
+}
+
+// liveIndexesSQL lists secondary indexes in the current schema. Indexes
+// backing a primary-key, unique or exclusion constraint come from
+// CREATE TABLE, not CREATE INDEX, and are skipped; so are indexes on
+// partitions, which Postgres clones from the partitioned parent.
+const liveIndexesSQL = `
+SELECT i.relname, t.relname
+FROM pg_index ix
+JOIN pg_class i ON i.oid = ix.indexrelid
+JOIN pg_class t ON t.oid = ix.indrelid
+JOIN pg_namespace n ON n.oid = t.relnamespace
+WHERE n.nspname = current_schema()
+  AND NOT t.relispartition
+  AND NOT EXISTS (
+    SELECT 1 FROM pg_constraint c
+    WHERE c.conindid = ix.indexrelid AND c.contype IN ('p', 'u', 'x')
+  )`
+
+// CheckIndexes reports index drift between the migrations and pool's
+// database. Extra indexes are only reported on tables the migrations
+// create, so the outbox and queue tables other components manage in the
+// same database don't show up.
+func CheckIndexes(ctx context.Context, pool *pgxpool.Pool) (IndexDrift, error) {
+	declared, tables, err := DeclaredIndexes()
+	if err != nil {
+		return IndexDrift{}, fmt.Errorf("declared indexes: %w", err)
+	}
+	rows, err := pool.Query(ctx, liveIndexesSQL)
+	if err != nil {
+		return IndexDrift{}, fmt.Errorf("live indexes: %w", err)
+	}
+	defer rows.Close()
+	live := map[string]Index{}
+	for rows.Next() {
+		var ix Index
+		if err := rows.Scan(&ix.Name, &ix.Table); err != nil {
+			return IndexDrift{}, fmt.Errorf("live indexes: %w", err)
+		}
+		live[ix.Name] = ix
+	}
+	if err := rows.Err(); err != nil {
+		return IndexDrift{}, fmt.Errorf("live indexes: %w", err)
+	}
+	return diffIndexes(declared, tables, live), nil
+}
+
+func diffIndexes(declared []Index, tables map[string]bool, live map[string]Index) IndexDrift {
+	d := IndexDrift{Expected: len(declared), Missing: []Index{}, Extra: []Index{}}
+	want := make(map[string]bool, len(declared))
+	for _, ix := range declared {
+		want[ix.Name] = true
+		if got, ok := live[ix.Name]; !ok || got.Table != ix.Table {
+			d.Missing = append(d.Missing, ix)
+		}
+	}
+	for _, ix := range live {
+		if !want[ix.Name] && tables[ix.Table] {
+			d.Extra = append(d.Extra, ix)
+		}
+	}
+	sortIndexes(d.Extra)
+	return d
+}
+
+func sortIndexes(ixs []Index) {
+	sort.Slice(ixs, func(i, j int) bool {
+		if ixs[i].Table != ixs[j].Table {
+			return ixs[i].Table < ixs[j].Table
+		}
+		return ixs[i].Name < ixs[j].Name
+	})
+}
@@ -0,0 +1,64 @@
+package migrate
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDeclaredIndexes(t *testing.T) {
+	indexes, tables, err := DeclaredIndexes()
+	require.NoError(t, err)
+	require.NotEmpty(t, indexes)
+
+	byName := map[string]Index{}
+	for _, ix := range indexes {
+		byName[ix.Name] = ix
+		require.True(t, tables[ix.Table], "index %s is on %s, which no migration creates", ix.Name, ix.Table)
+	}
+	require.Equal(t, "tnt_clients", byName["idx_tnt_clients_identifier"].Table)
+	require.Equal(t,
+		"CREATE INDEX IF NOT EXISTS idx_tnt_clients_identifier ON tnt_clients (identifier)",
+		byName["idx_tnt_clients_identifier"].Definition)
+	// Dropped by a later migration.
+	require.NotContains(t, byName, "idx_msg_events_read_time")
+}
+
+func TestReplayIndexDDL(t *testing.T) {
+	declared, tables := map[string]Index{}, map[string]bool{}
+	replayIndexDDL(`
+CREATE TABLE IF NOT EXISTS things (id TEXT PRIMARY KEY, a TEXT, b TEXT);
+CREATE INDEX IF NOT EXISTS idx_things_a ON things (a);
+CREATE UNIQUE INDEX idx_things_b
+    ON things (b)
+    WHERE b IS NOT NULL;
+CREATE INDEX idx_others_x ON others (x);
+DROP INDEX IF EXISTS idx_things_a;
+DROP TABLE IF EXISTS others CASCADE;
+`, declared, tables)
+
+	require.Equal(t, map[string]bool{"things": true}, tables)
+	require.Equal(t, map[string]Index{
+		"idx_things_b": {Name: "idx_things_b", Table: "things", Definition: "CREATE UNIQUE INDEX idx_things_b ON things (b) WHERE b IS NOT NULL"},
+	}, declared)
+}
+
+func TestDiffIndexes(t *testing.T) {
+	declared := []Index{
+		{Name: "idx_a", Table: "things"},
+		{Name: "idx_b", Table: "things"},
+		{Name: "idx_c", Table: "things"},
+	}
+	live := map[string]Index{
+		"idx_a":         {Name: "idx_a", Table: "things"},
+		"idx_c":         {Name: "idx_c", Table: "others"}, // same name, wrong table
+		"idx_hotfix":    {Name: "idx_hotfix", Table: "things"},
+		"idx_outbox_xy": {Name: "idx_outbox_xy", Table: "outbox_messages"}, // not a migration table
+	}
+	d := diffIndexes(declared, map[string]bool{"things": true}, live)
+
+	require.Equal(t, 3, d.Expected)
+	require.Equal(t, []Index{{Name: "idx_b", Table: "things"}, {Name: "idx_c", Table: "things"}}, d.Missing)
+	require.Equal(t, []Index{{Name: "idx_hotfix", Table: "things"}}, d.Extra)
+	require.False(t, d.InSync())
+}
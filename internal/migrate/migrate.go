@@ -12,6 +12,11 @@
 // `_fc_migrations` tracker, the Rust platform's `_schema_migrations` tracker,
 // and — as a final safety net — a populated-but-untracked schema are all
 // recognised. See bootstrap for the precedence.
+//
+// Replicas starting together don't race: Run holds a Postgres advisory
+// lock for the whole pass, so one instance migrates while the others wait
+// and then find nothing pending. CheckMigrations reports the database's
+// position without changing it — the status endpoint and dry-run mode.
 package migrate
 
 import (
@@ -26,13 +31,29 @@ import (
 	"github.com/jackc/pgx/v5/pgxpool"
 	"github.com/jackc/pgx/v5/stdlib"
 	"github.com/pressly/goose/v3"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/database"
 )
 
 //go:embed all:sql
 var migrationsFS embed.FS
 
-// Run applies every pending migration to pool's database.
+// lockKey is the advisory lock a migration pass holds ("fcmigrat").
+const lockKey int64 = 0x66636d6967726174
+
+// Run applies every pending migration to pool's database. It holds one of
+// pool's connections for the migration lock, so the pool needs at least
+// two.
 func Run(ctx context.Context, pool *pgxpool.Pool) error {
+	// A migration rewriting a large table legitimately outlasts the
+	// repository statement timeout.
+	ctx = database.WithQueryTimeout(ctx, 0)
+	unlock, err := database.AdvisoryLock(ctx, pool, lockKey, "migration")
+	if err != nil {
+		return err
+	}
+	defer unlock()
+
 	db := stdlib.OpenDBFromPool(pool)
 	defer db.Close()
 
@@ -0,0 +1,10 @@
+-- +goose Up
+-- Per-client passkey policy for admin-scope principals. When set, a holder
+-- of an admin role (platform:super-admin / platform:client-admin) whose
+-- home or assigned client sets it, or an anchor-scope user when any client
+-- sets it, may not sign in with a password once they have registered a
+-- passkey — they must use the WebAuthn ceremony. Additive, default off, so
+-- existing clients are unaffected.
+
+ALTER TABLE tnt_clients
+    ADD COLUMN IF NOT EXISTS require_passkey_for_admins BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,21 @@
+-- +goose Up
+-- Per-client data keys for encrypted-at-rest payloads (msg_events.data,
+-- msg_events_read.data, msg_dispatch_jobs.payload). Each key is a random
+-- AES-256 key wrapped with the platform app key (FLOWCATALYST_APP_KEY, the
+-- encryption.Service envelope) — the database alone never holds usable key
+-- material. Exactly one ACTIVE key per client encrypts new payloads; RETIRED
+-- keys stay for decrypting older rows until the migration job re-seals them.
+
+CREATE TABLE IF NOT EXISTS tnt_client_data_keys (
+    id          VARCHAR(17) PRIMARY KEY,
+    client_id   VARCHAR(17) NOT NULL,
+    wrapped_key TEXT NOT NULL,
+    status      VARCHAR(20) NOT NULL DEFAULT 'ACTIVE',
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    retired_at  TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tnt_client_data_keys_active
+    ON tnt_client_data_keys (client_id) WHERE status = 'ACTIVE';
+CREATE INDEX IF NOT EXISTS idx_tnt_client_data_keys_client
+    ON tnt_client_data_keys (client_id);
@@ -0,0 +1,12 @@
+-- +goose Up
+-- Per-event-type data policy. payload_retention_days purges event data and
+-- dispatch job payloads once they are older than the window (the envelope —
+-- type, subject, ids, timestamps — stays); metadata_retention_days deletes
+-- the rows outright. NULL means "keep until the partition is dropped".
+-- redact_fields lists dotted JSON paths masked on the read API and debug
+-- views. Enforced by the stream processor's retention reaper.
+
+ALTER TABLE msg_event_types
+    ADD COLUMN IF NOT EXISTS payload_retention_days INTEGER,
+    ADD COLUMN IF NOT EXISTS metadata_retention_days INTEGER,
+    ADD COLUMN IF NOT EXISTS redact_fields TEXT[] NOT NULL DEFAULT ARRAY[]::TEXT[];
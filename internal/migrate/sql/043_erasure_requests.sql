@@ -0,0 +1,49 @@
+-- +goose Up
+-- Data-subject erasure requests (POST /api/admin/privacy/erasure). A request
+-- names a subject identifier; the erasure worker redacts every event,
+-- dispatch job and read projection that carries it and records a running
+-- report. The raw identifier is only kept while the request is open —
+-- subject is cleared on completion and subject_hash (SHA-256 hex) remains so
+-- a repeat request for the same subject can be matched.
+
+CREATE TABLE IF NOT EXISTS aud_erasure_requests (
+    id            VARCHAR(17) PRIMARY KEY,
+    subject_type  VARCHAR(20) NOT NULL DEFAULT 'SUBJECT',
+    subject       TEXT,
+    subject_hash  VARCHAR(64) NOT NULL,
+    context_keys  TEXT[] NOT NULL DEFAULT ARRAY[]::TEXT[],
+    client_id     VARCHAR(17),
+    status        VARCHAR(20) NOT NULL DEFAULT 'PENDING',
+    report        JSONB NOT NULL DEFAULT '{}'::jsonb,
+    error         TEXT,
+    requested_by  VARCHAR(17),
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    started_at    TIMESTAMPTZ,
+    completed_at  TIMESTAMPTZ,
+    updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_aud_erasure_requests_open
+    ON aud_erasure_requests (updated_at) WHERE status IN ('PENDING', 'IN_PROGRESS');
+CREATE INDEX IF NOT EXISTS idx_aud_erasure_requests_subject_hash
+    ON aud_erasure_requests (subject_hash);
+
+-- ── Subject lookups ─────────────────────────────────────────────────────
+-- Erasure finds rows by subject (and, for events, by context entries), or
+-- by client_id for whole-client erasure (already indexed). Migration 015
+-- kept the write tables free of query indexes; these are the exception,
+-- partial on non-NULL so rows without a subject cost nothing. The same
+-- partitioned-parent caveat as migration 036 applies on populated
+-- databases.
+CREATE INDEX IF NOT EXISTS idx_msg_events_subject
+    ON msg_events (subject) WHERE subject IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_msg_events_context_data
+    ON msg_events USING GIN (context_data jsonb_path_ops);
+CREATE INDEX IF NOT EXISTS idx_msg_events_read_subject
+    ON msg_events_read (subject) WHERE subject IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_msg_dispatch_jobs_subject
+    ON msg_dispatch_jobs (subject) WHERE subject IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_msg_dispatch_jobs_event_id
+    ON msg_dispatch_jobs (event_id) WHERE event_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_msg_dispatch_jobs_read_subject
+    ON msg_dispatch_jobs_read (subject) WHERE subject IS NOT NULL;
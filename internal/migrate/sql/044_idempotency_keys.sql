@@ -0,0 +1,30 @@
+-- +goose Up
+-- Idempotency keys for the admin create endpoints (Idempotency-Key header).
+-- A key is scoped to the principal that sent it and the operation it was
+-- sent to, so two callers (or one caller's two resource types) never share
+-- a key space. request_hash is the SHA-256 of the request body: replaying a
+-- key with a different body is refused rather than answered with the first
+-- body's response.
+--
+-- A row is PENDING while its request runs and DONE once the response is
+-- recorded. response holds the JSON body, sealed with the application key
+-- when one is configured (create responses can carry one-time credentials);
+-- it is NULL when a sensitive response couldn't be sealed and so was not
+-- kept. Rows expire after FC_IDEMPOTENCY_TTL_HOURS and are reaped by the
+-- store's prune loop; an expired key may be reused.
+
+CREATE TABLE IF NOT EXISTS iam_idempotency_keys (
+    principal_id  VARCHAR(100) NOT NULL,
+    scope         VARCHAR(100) NOT NULL,
+    key           VARCHAR(255) NOT NULL,
+    request_hash  VARCHAR(64)  NOT NULL,
+    status        VARCHAR(20)  NOT NULL DEFAULT 'PENDING',
+    response      TEXT,
+    sealed        BOOLEAN      NOT NULL DEFAULT FALSE,
+    created_at    TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    expires_at    TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (principal_id, scope, key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_iam_idempotency_keys_expires_at
+    ON iam_idempotency_keys (expires_at);
@@ -0,0 +1,15 @@
+-- +goose Up
+-- Custom headers added to every delivery of a subscription (a tenant id, a
+-- routing hint, an API key). header_value is stored as entered: either the
+-- value itself or a secrets reference (env://, aws-sm://, encrypted:, ...)
+-- resolved at delivery time, so a secret never lands in this table or in
+-- msg_dispatch_jobs.
+
+CREATE TABLE IF NOT EXISTS msg_subscription_headers (
+    id SERIAL PRIMARY KEY,
+    subscription_id VARCHAR(17) NOT NULL,
+    header_name VARCHAR(100) NOT NULL,
+    header_value VARCHAR(1000) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_msg_sub_headers_subscription ON msg_subscription_headers (subscription_id);
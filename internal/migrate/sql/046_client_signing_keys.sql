@@ -0,0 +1,22 @@
+-- +goose Up
+-- Per-client webhook signing keys. A delivery is signed with the client's
+-- newest key whose window [active_from, expires_at) contains now, and names
+-- it in X-FLOWCATALYST-KEY-ID so the receiver can pick the matching secret.
+-- Rotation adds a key and gives the old ones an expires_at in the future, so
+-- for that overlap receivers can hold both. The secret is sealed with the
+-- platform app key (encryption.Service); the plaintext is shown once.
+
+CREATE TABLE IF NOT EXISTS tnt_client_signing_keys (
+    id          VARCHAR(17) PRIMARY KEY,
+    client_id   VARCHAR(17) NOT NULL,
+    key_id      VARCHAR(64) NOT NULL,
+    secret_enc  TEXT NOT NULL,
+    active_from TIMESTAMPTZ NOT NULL,
+    expires_at  TIMESTAMPTZ,
+    created_by  VARCHAR(17),
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tnt_client_signing_keys_kid
+    ON tnt_client_signing_keys (client_id, key_id);
@@ -0,0 +1,6 @@
+-- +goose Up
+-- The named outbound transport (FC_OUTBOUND_TRANSPORTS) a subscription's
+-- deliveries go through — an SSH bastion or SOCKS5 proxy for receivers
+-- that only accept traffic from their own network. NULL delivers directly.
+
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS transport VARCHAR(100);
@@ -0,0 +1,21 @@
+-- +goose Up
+-- Events the stream processor's fan-out gave up on: creating their
+-- dispatch jobs failed FC_STREAM_FAN_OUT_MAX_ATTEMPTS times in a row. The
+-- event is stamped fanned out so the rest of the stream moves on; an
+-- operator reprocesses (un-stamps) or discards it via /api/admin/stream-dlq.
+-- One row per event; a reprocessed event that fails again re-enters with
+-- its attempts added to the previous count.
+
+CREATE TABLE IF NOT EXISTS msg_stream_dlq (
+    event_id VARCHAR(13) PRIMARY KEY,
+    event_created_at TIMESTAMPTZ NOT NULL,
+    event_type VARCHAR(200) NOT NULL,
+    message_group VARCHAR(200),
+    stage VARCHAR(20) NOT NULL,
+    error TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_msg_stream_dlq_created_at ON msg_stream_dlq (created_at);
@@ -0,0 +1,12 @@
+-- +goose Up
+-- Display names on the dispatch-job read model, so the list grid shows
+-- "Order webhooks → Acme" instead of bare ids. The projection fills them
+-- from msg_subscriptions / tnt_clients / msg_event_types when it upserts a
+-- row; the stream processor's read-name sync rewrites them when a source
+-- row is renamed, and its first pass after startup backfills existing
+-- rows, target_host included. DDL only: no table-wide UPDATE here.
+
+ALTER TABLE msg_dispatch_jobs_read ADD COLUMN IF NOT EXISTS subscription_name VARCHAR(255);
+ALTER TABLE msg_dispatch_jobs_read ADD COLUMN IF NOT EXISTS client_name VARCHAR(255);
+ALTER TABLE msg_dispatch_jobs_read ADD COLUMN IF NOT EXISTS event_type_name VARCHAR(255);
+ALTER TABLE msg_dispatch_jobs_read ADD COLUMN IF NOT EXISTS target_host VARCHAR(255);
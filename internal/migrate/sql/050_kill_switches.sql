@@ -0,0 +1,19 @@
+-- +goose Up
+-- Incident kill switches: while a row exists, nothing is delivered for its
+-- target — a client, a subscription or a dispatch pool. The scheduler
+-- leaves the target's jobs PENDING and the processing callback puts back
+-- any copy already queued; releasing the switch (deleting the row) lets
+-- them flow again. Who engaged and released each switch is in the audit
+-- log, via the KillSwitchEngaged / KillSwitchReleased events.
+
+CREATE TABLE IF NOT EXISTS msg_kill_switches (
+    id VARCHAR(17) PRIMARY KEY,
+    scope VARCHAR(20) NOT NULL,
+    target_id VARCHAR(17) NOT NULL,
+    reason TEXT NOT NULL,
+    engaged_by VARCHAR(17),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_msg_kill_switches_target ON msg_kill_switches (scope, target_id);
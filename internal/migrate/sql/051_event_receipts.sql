@@ -0,0 +1,8 @@
+-- +goose Up
+-- Delivery receipts: an event may ask to be told when each of its dispatch
+-- jobs reaches a terminal status (delivered, or failed with its retries
+-- spent) — by a webhook to receipt_callback_url, by an event of type
+-- receipt_event_type, or both. NULL in both asks for nothing.
+
+ALTER TABLE msg_events ADD COLUMN IF NOT EXISTS receipt_callback_url TEXT;
+ALTER TABLE msg_events ADD COLUMN IF NOT EXISTS receipt_event_type VARCHAR(255);
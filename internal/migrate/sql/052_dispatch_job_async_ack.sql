@@ -0,0 +1,12 @@
+-- +goose Up
+-- Consumer-managed acknowledgement: a receiver that answers a delivery with
+-- 202 and an ack token keeps the job PROCESSING until it calls
+-- POST /api/dispatch-jobs/{id}/ack (or /nack) with that token. Past
+-- ack_deadline the job is settled as a nack and goes back for a retry.
+-- Both columns are cleared whenever the job is settled or re-attempted.
+
+ALTER TABLE msg_dispatch_jobs ADD COLUMN IF NOT EXISTS ack_token VARCHAR(255);
+ALTER TABLE msg_dispatch_jobs ADD COLUMN IF NOT EXISTS ack_deadline TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_msg_dispatch_jobs_ack_deadline
+    ON msg_dispatch_jobs (ack_deadline) WHERE ack_deadline IS NOT NULL;
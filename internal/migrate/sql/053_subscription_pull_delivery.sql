@@ -0,0 +1,12 @@
+-- +goose Up
+-- Pull delivery: a PULL subscription's dispatch jobs are not pushed to a
+-- webhook. They wait in msg_dispatch_jobs (protocol 'PULL', skipped by the
+-- scheduler) until the consumer leases them through
+-- GET /api/consume/{subscriptionId}; the lease reuses the async-ack
+-- columns from migration 052.
+
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS delivery_mode VARCHAR(10) NOT NULL DEFAULT 'PUSH';
+
+CREATE INDEX IF NOT EXISTS idx_msg_dispatch_jobs_pull_pending
+    ON msg_dispatch_jobs (subscription_id, sequence, created_at)
+    WHERE protocol = 'PULL' AND status = 'PENDING';
@@ -0,0 +1,7 @@
+-- +goose Up
+-- A streaming pull consumer leases every job under one token and the
+-- platform counts its unacked jobs by that token each poll, so the count
+-- and the renewal on resume look jobs up by ack_token.
+
+CREATE INDEX IF NOT EXISTS idx_msg_dispatch_jobs_ack_token
+    ON msg_dispatch_jobs (ack_token) WHERE ack_token IS NOT NULL;
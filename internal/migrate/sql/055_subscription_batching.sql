@@ -0,0 +1,9 @@
+-- +goose Up
+-- Delivery batching: a subscription with batch_max_items > 1 has its due
+-- dispatch jobs POSTed together, as one JSON array, instead of one request
+-- each. batch_max_bytes caps the array's size (0 = no cap) and
+-- batch_max_wait_seconds is how long a short batch may wait to fill.
+
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS batch_max_items INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS batch_max_bytes INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS batch_max_wait_seconds INTEGER NOT NULL DEFAULT 0;
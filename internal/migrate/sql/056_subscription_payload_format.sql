@@ -0,0 +1,6 @@
+-- +goose Up
+-- Payload formats: how a push subscription's deliveries are serialized.
+-- 'JSON' is the body every delivery had before; 'CLOUDEVENTS_JSON',
+-- 'NDJSON' and 'PROTOBUF' are opt-in (see processing/format.go).
+
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS payload_format VARCHAR(20) NOT NULL DEFAULT 'JSON';
@@ -0,0 +1,28 @@
+-- +goose Up
+-- Hourly delivery analytics per subscription, rolled up from
+-- msg_dispatch_job_attempts by the stream processor's delivery-stats
+-- rollup (internal/stream/delivery_stats.go). Each row is recomputed whole
+-- while its hour is still receiving attempts, so the rollup is idempotent.
+--
+-- latency_histogram counts attempts per duration bucket (bounds in
+-- stream.LatencyBucketsMs, the last bucket open-ended); retry_histogram
+-- counts attempts by attempt number, the last slot holding that number
+-- and above. errors maps a failure key — the response code, or the error
+-- type when there was no response — to its count.
+
+CREATE TABLE IF NOT EXISTS msg_subscription_delivery_stats (
+    subscription_id   VARCHAR(17) NOT NULL,
+    bucket_start      TIMESTAMPTZ NOT NULL,
+    attempts          BIGINT      NOT NULL DEFAULT 0,
+    successes         BIGINT      NOT NULL DEFAULT 0,
+    failures          BIGINT      NOT NULL DEFAULT 0,
+    duration_ms_total BIGINT      NOT NULL DEFAULT 0,
+    latency_histogram BIGINT[]    NOT NULL DEFAULT '{}',
+    retry_histogram   BIGINT[]    NOT NULL DEFAULT '{}',
+    errors            JSONB       NOT NULL DEFAULT '{}',
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (subscription_id, bucket_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_msg_subscription_delivery_stats_bucket
+    ON msg_subscription_delivery_stats (bucket_start);
@@ -0,0 +1,62 @@
+-- +goose Up
+-- Anomaly detection on ingestion and delivery rates (stream processor,
+-- internal/stream/anomalies.go). Every window the detector compares each
+-- event type's ingestion count and each subscription's delivery failure
+-- rate against an exponentially weighted baseline, and records an anomaly
+-- when an event type that is normally busy receives nothing or a
+-- subscription's failure rate spikes beyond its norm.
+
+-- Per-entity overrides, edited through the UpdateAnomalySetting use case.
+-- entity_kind is EVENT_TYPE (entity_key: the event type code) or
+-- SUBSCRIPTION (entity_key: the subscription id). sensitivity is the
+-- z-score threshold, NULL for the default; a disabled or snoozed entity
+-- keeps learning but raises nothing.
+CREATE TABLE IF NOT EXISTS msg_anomaly_settings (
+    id VARCHAR(17) PRIMARY KEY,
+    entity_kind VARCHAR(20) NOT NULL,
+    entity_key VARCHAR(255) NOT NULL,
+    sensitivity DOUBLE PRECISION,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    snoozed_until TIMESTAMPTZ,
+    updated_by VARCHAR(17),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_msg_anomaly_settings_entity
+    ON msg_anomaly_settings (entity_kind, entity_key);
+
+-- The detector's EWMA mean and variance per entity. window_end is the end
+-- of the last window folded in, so a new leader does not count a window
+-- twice.
+CREATE TABLE IF NOT EXISTS msg_anomaly_baselines (
+    entity_kind VARCHAR(20) NOT NULL,
+    entity_key VARCHAR(255) NOT NULL,
+    mean DOUBLE PRECISION NOT NULL DEFAULT 0,
+    variance DOUBLE PRECISION NOT NULL DEFAULT 0,
+    samples INTEGER NOT NULL DEFAULT 0,
+    window_end TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (entity_kind, entity_key)
+);
+
+-- Raised anomalies. An entity has at most one OPEN row; it is RESOLVED when
+-- the rate recovers or the entity is snoozed or disabled.
+CREATE TABLE IF NOT EXISTS msg_anomalies (
+    id VARCHAR(17) PRIMARY KEY,
+    entity_kind VARCHAR(20) NOT NULL,
+    entity_key VARCHAR(255) NOT NULL,
+    kind VARCHAR(30) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'OPEN',
+    message TEXT NOT NULL,
+    observed DOUBLE PRECISION NOT NULL,
+    expected DOUBLE PRECISION NOT NULL,
+    z_score DOUBLE PRECISION NOT NULL,
+    raised_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_msg_anomalies_open
+    ON msg_anomalies (entity_kind, entity_key) WHERE status = 'OPEN';
+CREATE INDEX IF NOT EXISTS idx_msg_anomalies_raised ON msg_anomalies (raised_at DESC);
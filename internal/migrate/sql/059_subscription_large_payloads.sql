@@ -0,0 +1,7 @@
+-- +goose Up
+-- Large payloads: how a push subscription receives an event payload that
+-- was offloaded to the blob store (FC_BLOB_STORE_URI). 'INLINE' rehydrates
+-- it before delivery; 'PRESIGNED_URL' delivers the reference with a
+-- pre-signed URL (see processing/blob.go).
+
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS large_payloads VARCHAR(20) NOT NULL DEFAULT 'INLINE';
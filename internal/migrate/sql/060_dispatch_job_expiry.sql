@@ -0,0 +1,6 @@
+-- +goose Up
+-- The scheduler marks PENDING jobs past their expires_at EXPIRED on every
+-- poll; only jobs that carry an expiry need to be looked at.
+
+CREATE INDEX IF NOT EXISTS idx_msg_dispatch_jobs_pending_expiry
+    ON msg_dispatch_jobs (expires_at) WHERE status = 'PENDING' AND expires_at IS NOT NULL;
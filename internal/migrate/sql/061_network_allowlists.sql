@@ -0,0 +1,13 @@
+-- +goose Up
+-- Network allowlists: the CIDR ranges an OAuth client's credentials and a
+-- service account's tokens may be used from. Empty / NULL = anywhere.
+-- /oauth/token checks the client's list; the auth middleware checks the
+-- service account's on every request (see shared/netpolicy).
+
+CREATE TABLE IF NOT EXISTS oauth_client_allowed_cidrs (
+    oauth_client_id VARCHAR(17) NOT NULL REFERENCES oauth_clients(id) ON DELETE CASCADE,
+    allowed_cidr VARCHAR(50) NOT NULL,
+    PRIMARY KEY (oauth_client_id, allowed_cidr)
+);
+
+ALTER TABLE iam_service_accounts ADD COLUMN IF NOT EXISTS allowed_cidrs TEXT[];
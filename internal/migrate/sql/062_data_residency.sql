@@ -0,0 +1,10 @@
+-- +goose Up
+-- Data residency: a client tagged with a region (e.g. 'eu') must have its
+-- deliveries egress from that region's infrastructure. Pools carry the
+-- region they run in; subscription create/update rejects a regional
+-- client's subscription unless its pool is in the same region, and the
+-- scheduler only publishes a regional pool's jobs to that region's queue.
+-- NULL = unrestricted.
+
+ALTER TABLE tnt_clients ADD COLUMN IF NOT EXISTS region VARCHAR(32);
+ALTER TABLE msg_dispatch_pools ADD COLUMN IF NOT EXISTS region VARCHAR(32);
@@ -0,0 +1,44 @@
+-- +goose Up
+-- Multi-cluster federation (internal/platform/federation). An instance
+-- subscribes to a peer FlowCatalyst instance for a set of event types: the
+-- leader pulls the peer's GET /api/federation/feed with the peer's OAuth
+-- client credentials and ingests what it receives as local events, keeping
+-- their ids. Loop prevention travels in each event's context_data
+-- (federationOrigin, federationHops), so no msg_events column changes.
+
+-- A peer this instance pulls from. client_secret_ref is stored like an
+-- OIDC client secret: sealed inline with FLOWCATALYST_APP_KEY
+-- ("encrypted:…") or an external secret-manager reference. status is
+-- ACTIVE or PAUSED.
+CREATE TABLE IF NOT EXISTS msg_federation_peers (
+    id VARCHAR(17) PRIMARY KEY,
+    code VARCHAR(100) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    base_url TEXT NOT NULL,
+    client_id VARCHAR(255) NOT NULL,
+    client_secret_ref TEXT NOT NULL,
+    event_types TEXT[] NOT NULL DEFAULT '{}',
+    status VARCHAR(20) NOT NULL DEFAULT 'ACTIVE',
+    created_by VARCHAR(17),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_msg_federation_peers_code ON msg_federation_peers (code);
+
+-- The puller's position in each peer's feed, kept apart from the peer so
+-- a pull never bumps the peer's updated_at. feed_cursor is the feed's
+-- opaque cursor; NULL starts from the beginning of the peer's retained
+-- events.
+CREATE TABLE IF NOT EXISTS msg_federation_peer_states (
+    peer_id VARCHAR(17) PRIMARY KEY REFERENCES msg_federation_peers (id) ON DELETE CASCADE,
+    feed_cursor TEXT,
+    last_pulled_at TIMESTAMPTZ,
+    last_error TEXT,
+    received BIGINT NOT NULL DEFAULT 0,
+    skipped BIGINT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- The feed reads msg_events in (created_at, id) order per event type.
+CREATE INDEX IF NOT EXISTS idx_msg_events_type_created ON msg_events (type, created_at, id);
@@ -0,0 +1,16 @@
+-- +goose Up
+-- Schema registry integration (internal/schemaregistry). An event type may
+-- name a subject in a Confluent-compatible registry (Confluent, or
+-- Apicurio through its ccompat API): ingestion then resolves each event's
+-- schema id against that subject — the subject's latest when the producer
+-- supplies none — and, in VALIDATE mode, checks the payload against the
+-- schema. registry_mode is RESOLVE or VALIDATE; NULL registry_subject
+-- leaves the event type unbound.
+
+ALTER TABLE msg_event_types
+    ADD COLUMN IF NOT EXISTS registry_subject VARCHAR(255),
+    ADD COLUMN IF NOT EXISTS registry_mode VARCHAR(20) NOT NULL DEFAULT 'RESOLVE';
+
+-- The registry schema id an event was produced with. Fan-out copies it to
+-- msg_dispatch_jobs.schema_id, which deliveries carry to consumers.
+ALTER TABLE msg_events ADD COLUMN IF NOT EXISTS schema_id VARCHAR(17);
@@ -0,0 +1,18 @@
+-- +goose Up
+-- Change data capture connectors (internal/connect). Connectors are
+-- declared in the FC_CONNECT_CONFIG_PATH file, not here; this table keeps
+-- each one's progress so a restart resumes where the last stored batch
+-- ended. position is the source's own resume point: a Mongo change-stream
+-- resume token (extended JSON) or a Postgres commit LSN. snapshot_status
+-- is REQUESTED, RUNNING or COMPLETED; NULL means no snapshot was taken.
+CREATE TABLE IF NOT EXISTS msg_connector_checkpoints (
+    connector VARCHAR(100) PRIMARY KEY,
+    position TEXT,
+    snapshot_status VARCHAR(20),
+    snapshot_started_at TIMESTAMPTZ,
+    snapshot_completed_at TIMESTAMPTZ,
+    last_change_at TIMESTAMPTZ,
+    last_error TEXT,
+    emitted BIGINT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,40 @@
+-- +goose Up
+-- Event lake (internal/platform/archive). The archiver copies every
+-- ingested event, and every dispatch job that reaches a terminal status,
+-- to gzipped JSONL objects in FC_ARCHIVE_STORE_URI. msg_archive_cursors
+-- keeps where each stream (EVENTS, DELIVERIES) has got to, in the
+-- (time, id) order it is read in; msg_archive_files is the manifest of
+-- the objects written, one row per object, inserted in the same
+-- transaction that advances the cursor past its records.
+CREATE TABLE IF NOT EXISTS msg_archive_cursors (
+    kind VARCHAR(20) PRIMARY KEY,
+    after_time TIMESTAMPTZ NOT NULL,
+    after_id VARCHAR(20) NOT NULL,
+    archived BIGINT NOT NULL DEFAULT 0,
+    last_run_at TIMESTAMPTZ,
+    last_error TEXT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS msg_archive_files (
+    id VARCHAR(17) PRIMARY KEY,
+    kind VARCHAR(20) NOT NULL,
+    object_key VARCHAR(500) NOT NULL UNIQUE,
+    schema_version INTEGER NOT NULL,
+    partition_hour TIMESTAMPTZ NOT NULL,
+    records INTEGER NOT NULL,
+    bytes BIGINT NOT NULL,
+    first_id VARCHAR(20) NOT NULL,
+    last_id VARCHAR(20) NOT NULL,
+    min_time TIMESTAMPTZ NOT NULL,
+    max_time TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_msg_archive_files_partition
+    ON msg_archive_files (kind, partition_hour);
+
+-- The DELIVERIES stream reads terminal jobs in (updated_at, id) order.
+CREATE INDEX IF NOT EXISTS idx_msg_dispatch_jobs_terminal_updated
+    ON msg_dispatch_jobs (updated_at, id)
+    WHERE status IN ('COMPLETED', 'FAILED', 'CANCELLED', 'EXPIRED');
@@ -0,0 +1,33 @@
+-- +goose Up
+-- Replay from the event lake (internal/platform/archive). A replay reads
+-- the EVENTS objects in msg_archive_files whose partition hour overlaps
+-- [from_time, to_time) and re-ingests the matching events as new rows of
+-- msg_events. after_file_id is the last manifest entry it has finished,
+-- so a replay resumes where it stopped after a restart or leader change.
+CREATE TABLE IF NOT EXISTS msg_archive_replays (
+    id VARCHAR(17) PRIMARY KEY,
+    from_time TIMESTAMPTZ NOT NULL,
+    to_time TIMESTAMPTZ NOT NULL,
+    event_types TEXT[] NOT NULL DEFAULT '{}',
+    client_id VARCHAR(17),
+    subscription_id VARCHAR(17),
+    status VARCHAR(20) NOT NULL DEFAULT 'PENDING',
+    after_file_id VARCHAR(17),
+    files_read INTEGER NOT NULL DEFAULT 0,
+    events_read BIGINT NOT NULL DEFAULT 0,
+    events_replayed BIGINT NOT NULL DEFAULT 0,
+    error TEXT,
+    requested_by VARCHAR(17),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    started_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_msg_archive_replays_open
+    ON msg_archive_replays (created_at)
+    WHERE status IN ('PENDING', 'RUNNING');
+
+-- A replayed event aimed at one subscription. Fan-out matches it against
+-- that subscription only; NULL fans out as usual.
+ALTER TABLE msg_events ADD COLUMN IF NOT EXISTS replay_subscription_id VARCHAR(17);
@@ -0,0 +1,30 @@
+-- +goose Up
+-- Blue/green subscriptions: a push subscription may name a shadow
+-- endpoint that receives a copy of every delivery. The shadow's answer is
+-- recorded next to the primary's in msg_subscription_shadow_deliveries
+-- and never changes the dispatch job (see processing/shadow.go).
+-- Promoting the shadow makes it the subscription's target.
+
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS shadow_target VARCHAR(500);
+
+-- One row per delivery copied to the shadow. Rows of any endpoint but the
+-- subscription's current shadow are deleted when the subscription is
+-- saved, so the table only holds the comparison in progress.
+CREATE TABLE IF NOT EXISTS msg_subscription_shadow_deliveries (
+    id                  VARCHAR(17)  PRIMARY KEY,
+    subscription_id     VARCHAR(17)  NOT NULL,
+    dispatch_job_id     VARCHAR(17)  NOT NULL,
+    shadow_target       VARCHAR(500) NOT NULL,
+    primary_status_code INTEGER,
+    primary_success     BOOLEAN      NOT NULL,
+    primary_duration_ms BIGINT       NOT NULL DEFAULT 0,
+    primary_error       TEXT,
+    shadow_status_code  INTEGER,
+    shadow_success      BOOLEAN      NOT NULL,
+    shadow_duration_ms  BIGINT       NOT NULL DEFAULT 0,
+    shadow_error        TEXT,
+    created_at          TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_msg_subscription_shadow_deliveries_sub
+    ON msg_subscription_shadow_deliveries (subscription_id, created_at);
@@ -0,0 +1,17 @@
+-- +goose Up
+-- Traffic splitting: a push subscription may route a percentage of its
+-- message groups to other endpoints, e.g. 10% to a v2 consumer while the
+-- rest stays on the subscription's target. Each row is one extra endpoint
+-- and its weight in percent; the target keeps what the rows leave of 100.
+-- Fan-out picks a job's endpoint by hashing its message group, so a group
+-- always lands on the same endpoint (see internal/stream/fan_out.go).
+
+CREATE TABLE IF NOT EXISTS msg_subscription_targets (
+    id SERIAL PRIMARY KEY,
+    subscription_id VARCHAR(17) NOT NULL,
+    target VARCHAR(500) NOT NULL,
+    weight INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_msg_sub_targets_subscription ON msg_subscription_targets (subscription_id);
+
@@ -0,0 +1,9 @@
+-- +goose Up
+-- Per-subscription TLS trust for receivers behind a private CA: an extra
+-- PEM root bundle, SPKI pins ("sha256/<base64>") one of which the verified
+-- chain must carry, and a minimum TLS version. All three layer over the
+-- deployment's FC_OUTBOUND_TLS_* trust (see internal/outbound/tls.go).
+
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS tls_ca_bundle TEXT;
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS tls_spki_pins TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE msg_subscriptions ADD COLUMN IF NOT EXISTS tls_min_version VARCHAR(8);
@@ -0,0 +1,10 @@
+-- +goose Up
+-- Event type lifecycle beyond CURRENT/ARCHIVED. A deprecated event type
+-- (deprecated_at set) still ingests, with a warning to the producer, but
+-- no new subscription may bind it; from sunset_at on ingestion rejects it.
+-- migration_hint tells producers what to move to.
+
+ALTER TABLE msg_event_types
+    ADD COLUMN IF NOT EXISTS deprecated_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS sunset_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS migration_hint TEXT;
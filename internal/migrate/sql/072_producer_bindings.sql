@@ -0,0 +1,18 @@
+-- +goose Up
+-- Producer bindings: which applications, and which of their service
+-- accounts, may publish which event types. event_type_pattern is an
+-- event type code with `*` matching any one segment. A NULL principal_id
+-- binds the application's own service account (app_applications
+-- .service_account_id); otherwise the named SERVICE principal. An event
+-- type matched by any binding may only be published by its producers;
+-- one matched by none stays open to every caller with events-write.
+CREATE TABLE IF NOT EXISTS app_producer_bindings (
+    id                 VARCHAR(17)  PRIMARY KEY,
+    application_id     VARCHAR(17)  NOT NULL REFERENCES app_applications (id) ON DELETE CASCADE,
+    principal_id       VARCHAR(17)  REFERENCES iam_principals (id) ON DELETE CASCADE,
+    event_type_pattern VARCHAR(255) NOT NULL,
+    created_at         TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_app_producer_bindings
+    ON app_producer_bindings (application_id, COALESCE(principal_id, ''), event_type_pattern);
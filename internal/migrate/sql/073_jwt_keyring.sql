@@ -0,0 +1,10 @@
+-- +goose Up
+-- The JWT signing key ring (internal/platform/auth/keyring), shared by
+-- every instance so they all sign and verify with the same keys. One row
+-- per ring; ring_enc is the encryption.Service envelope of the ring's
+-- JSON. Writes happen under the keyring advisory lock.
+CREATE TABLE IF NOT EXISTS iam_jwt_keyrings (
+    id         VARCHAR(64) PRIMARY KEY,
+    ring_enc   TEXT        NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,124 @@
+package migrate
+
+import (
+	"cmp"
+	"context"
+	"database/sql"
+	"fmt"
+	"io/fs"
+	"path"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+	"github.com/jackc/pgx/v5/stdlib"
+)
+
+// Migration is one embedded migration and, once applied, when.
+type Migration struct {
+	Version   int64      `json:"version"`
+	Name      string     `json:"name"`
+	AppliedAt *time.Time `json:"appliedAt,omitempty"`
+}
+
+// MigrationStatus is where a database stands against the embedded
+// migrations. Unknown lists versions the ledger records that this binary
+// doesn't ship — a database migrated by a newer build.
+type MigrationStatus struct {
+	Current int64       `json:"current"`
+	Applied []Migration `json:"applied"`
+	Pending []Migration `json:"pending"`
+	Unknown []int64     `json:"unknown,omitempty"`
+}
+
+// UpToDate reports whether nothing is pending.
+func (s MigrationStatus) UpToDate() bool { return len(s.Pending) == 0 }
+
+// CheckMigrations reads goose's ledger and compares it with the embedded
+// migrations. It doesn't bootstrap: a database still on a legacy tracker
+// reports everything pending until Run has seeded the ledger from it.
+func CheckMigrations(ctx context.Context, pool *pgxpool.Pool) (MigrationStatus, error) {
+	shipped, err := shippedMigrations()
+	if err != nil {
+		return MigrationStatus{}, err
+	}
+	db := stdlib.OpenDBFromPool(pool)
+	defer db.Close()
+	applied, err := appliedVersions(ctx, db)
+	if err != nil {
+		return MigrationStatus{}, err
+	}
+	return compareMigrations(shipped, applied), nil
+}
+
+// shippedMigrations lists the embedded migrations in version order.
+func shippedMigrations() ([]Migration, error) {
+	files, err := fs.Glob(migrationsFS, "sql/*.sql")
+	if err != nil {
+		return nil, fmt.Errorf("read embedded migrations: %w", err)
+	}
+	var out []Migration
+	for _, f := range files {
+		name := strings.TrimSuffix(path.Base(f), ".sql")
+		if v, ok := versionPrefix(name); ok {
+			out = append(out, Migration{Version: v, Name: name})
+		}
+	}
+	slices.SortFunc(out, func(a, b Migration) int { return cmp.Compare(a.Version, b.Version) })
+	return out, nil
+}
+
+// appliedVersions reads goose's ledger: each applied version and when.
+// Versions seeded by bootstrap carry the time they were seeded.
+func appliedVersions(ctx context.Context, db *sql.DB) (map[int64]time.Time, error) {
+	applied := map[int64]time.Time{}
+	if ok, err := tableExists(ctx, db, "goose_db_version"); err != nil || !ok {
+		return applied, err
+	}
+	rows, err := db.QueryContext(ctx,
+		`SELECT version_id, MAX(tstamp) FROM goose_db_version
+		 WHERE version_id > 0 AND is_applied
+		 GROUP BY version_id`)
+	if err != nil {
+		return nil, fmt.Errorf("read goose_db_version: %w", err)
+	}
+	defer rows.Close()
+	for rows.Next() {
+		var (
+			v  int64
+			at sql.NullTime
+		)
+		if err := rows.Scan(&v, &at); err != nil {
+			return nil, err
+		}
+		applied[v] = at.Time
+	}
+	return applied, rows.Err()
+}
+
+// compareMigrations splits shipped into applied and pending.
+func compareMigrations(shipped []Migration, applied map[int64]time.Time) MigrationStatus {
+	st := MigrationStatus{Applied: []Migration{}, Pending: []Migration{}}
+	known := make(map[int64]bool, len(shipped))
+	for _, m := range shipped {
+		known[m.Version] = true
+		at, ok := applied[m.Version]
+		if !ok {
+			st.Pending = append(st.Pending, m)
+			continue
+		}
+		if !at.IsZero() {
+			m.AppliedAt = &at
+		}
+		st.Applied = append(st.Applied, m)
+	}
+	for v := range applied {
+		st.Current = max(st.Current, v)
+		if !known[v] {
+			st.Unknown = append(st.Unknown, v)
+		}
+	}
+	slices.Sort(st.Unknown)
+	return st
+}
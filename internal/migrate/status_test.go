@@ -0,0 +1,44 @@
+package migrate
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestShippedMigrations(t *testing.T) {
+	shipped, err := shippedMigrations()
+	require.NoError(t, err)
+	require.NotEmpty(t, shipped)
+	assert.Equal(t, int64(1), shipped[0].Version)
+	for i := 1; i < len(shipped); i++ {
+		assert.Less(t, shipped[i-1].Version, shipped[i].Version, "in version order, no duplicates")
+	}
+}
+
+func TestCompareMigrations(t *testing.T) {
+	shipped := []Migration{{Version: 1, Name: "001_a"}, {Version: 2, Name: "002_b"}, {Version: 3, Name: "003_c"}}
+	at := time.Date(2026, 5, 1, 12, 0, 0, 0, time.UTC)
+
+	st := compareMigrations(shipped, map[int64]time.Time{1: at, 2: {}})
+	assert.False(t, st.UpToDate())
+	assert.Equal(t, int64(2), st.Current)
+	require.Len(t, st.Applied, 2)
+	assert.Equal(t, &at, st.Applied[0].AppliedAt)
+	assert.Nil(t, st.Applied[1].AppliedAt, "a ledger row without a timestamp")
+	assert.Equal(t, []Migration{{Version: 3, Name: "003_c"}}, st.Pending)
+	assert.Empty(t, st.Unknown)
+
+	// A ledger ahead of the binary: everything shipped is applied, and
+	// the newer version is reported rather than hidden.
+	st = compareMigrations(shipped, map[int64]time.Time{1: at, 2: at, 3: at, 4: at})
+	assert.True(t, st.UpToDate())
+	assert.Equal(t, int64(4), st.Current)
+	assert.Equal(t, []int64{4}, st.Unknown)
+
+	st = compareMigrations(shipped, map[int64]time.Time{})
+	assert.Zero(t, st.Current)
+	assert.Len(t, st.Pending, 3)
+}
@@ -0,0 +1,278 @@
+// Package outbound guards webhook deliveries and router mediation calls
+// against server-side request forgery: a subscription endpoint or
+// mediation target pointed at the cloud metadata service, loopback or a
+// private network.
+//
+// Targets are checked twice. CheckURL runs when a target is saved and
+// again before each delivery (scheme, host allowlist, literal addresses).
+// Control runs inside the dialer against the address actually being
+// connected — after DNS resolution — so a name that later resolves
+// somewhere private (DNS rebinding) is still refused: the address checked
+// is the address dialled.
+//
+// A delivery may also leave through a named Transport — an SSH bastion or
+// SOCKS5 proxy — instead of dialling the target directly; the bastion or
+// proxy is then the address checked.
+package outbound
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net"
+	"net/netip"
+	"net/url"
+	"slices"
+	"strconv"
+	"strings"
+	"syscall"
+)
+
+// ErrBlocked matches every *BlockedError via errors.Is.
+var ErrBlocked = errors.New("outbound target blocked")
+
+// BlockedError reports a target the policy refuses and why.
+type BlockedError struct {
+	Target string
+	Reason string
+}
+
+func (e *BlockedError) Error() string {
+	return fmt.Sprintf("outbound target %s blocked: %s", e.Target, e.Reason)
+}
+
+// Is makes errors.Is(err, ErrBlocked) true.
+func (e *BlockedError) Is(target error) bool { return target == ErrBlocked }
+
+// reservedNets are the ranges no delivery may reach unless the policy
+// allows them. Addresses are unmapped first, so ::ffff:10.0.0.1 is
+// caught by the IPv4 entry. NAT64 and 6to4 addresses embed an arbitrary
+// IPv4 address, private ones included, so both prefixes are refused
+// outright.
+var reservedNets = []struct {
+	prefix netip.Prefix
+	reason string
+}{
+	{netip.MustParsePrefix("0.0.0.0/8"), "unspecified address"},
+	{netip.MustParsePrefix("10.0.0.0/8"), "private network"},
+	{netip.MustParsePrefix("100.64.0.0/10"), "shared address space"},
+	{netip.MustParsePrefix("127.0.0.0/8"), "loopback"},
+	{netip.MustParsePrefix("169.254.0.0/16"), "link-local (cloud metadata)"},
+	{netip.MustParsePrefix("172.16.0.0/12"), "private network"},
+	{netip.MustParsePrefix("192.0.0.0/24"), "IETF protocol assignment"},
+	{netip.MustParsePrefix("192.168.0.0/16"), "private network"},
+	{netip.MustParsePrefix("198.18.0.0/15"), "benchmarking network"},
+	{netip.MustParsePrefix("224.0.0.0/4"), "multicast"},
+	{netip.MustParsePrefix("240.0.0.0/4"), "reserved"},
+	{netip.MustParsePrefix("::/128"), "unspecified address"},
+	{netip.MustParsePrefix("::1/128"), "loopback"},
+	{netip.MustParsePrefix("64:ff9b::/96"), "NAT64 (embedded IPv4)"},
+	{netip.MustParsePrefix("2002::/16"), "6to4 (embedded IPv4)"},
+	{netip.MustParsePrefix("fc00::/7"), "private network"},
+	{netip.MustParsePrefix("fe80::/10"), "link-local"},
+	{netip.MustParsePrefix("ff00::/8"), "multicast"},
+}
+
+// Policy decides which targets a delivery may reach. The zero value
+// allows http and https to any public address.
+type Policy struct {
+	// Schemes a target URL may use; empty allows http and https.
+	Schemes []string
+	// AllowedHosts, when set, restricts targets to these host names.
+	// "*.example.com" matches any subdomain of example.com.
+	AllowedHosts []string
+	// AllowedNets are reserved ranges this policy may still reach — an
+	// internal service network, a peered VPC.
+	AllowedNets []netip.Prefix
+	// TrustedAddrs are exact address:port pairs exempt from the address
+	// checks, for the platform's own callback on loopback.
+	TrustedAddrs []netip.AddrPort
+	// AllowPrivate switches the address checks off (local development).
+	AllowPrivate bool
+}
+
+// CheckURL validates a target URL without resolving it: scheme, host
+// allowlist, and — for an IP literal or a localhost name — the address.
+func (p Policy) CheckURL(raw string) error {
+	u, err := url.Parse(raw)
+	if err != nil {
+		return &BlockedError{Target: raw, Reason: "not a valid URL"}
+	}
+	schemes := p.Schemes
+	if len(schemes) == 0 {
+		schemes = []string{"https", "http"}
+	}
+	if !slices.Contains(schemes, strings.ToLower(u.Scheme)) {
+		return &BlockedError{Target: raw, Reason: fmt.Sprintf("scheme %q not allowed (allowed: %s)", u.Scheme, strings.Join(schemes, ", "))}
+	}
+	host := strings.ToLower(strings.TrimSuffix(u.Hostname(), "."))
+	if host == "" {
+		return &BlockedError{Target: raw, Reason: "missing host"}
+	}
+	if len(p.AllowedHosts) > 0 && !slices.ContainsFunc(p.AllowedHosts, func(pattern string) bool { return hostMatches(pattern, host) }) {
+		return &BlockedError{Target: raw, Reason: fmt.Sprintf("host %q is not in the allowed hosts", host)}
+	}
+
+	port := uint16(443)
+	if strings.EqualFold(u.Scheme, "http") {
+		port = 80
+	}
+	if ps := u.Port(); ps != "" {
+		n, err := strconv.ParseUint(ps, 10, 16)
+		if err != nil {
+			return &BlockedError{Target: raw, Reason: fmt.Sprintf("invalid port %q", ps)}
+		}
+		port = uint16(n)
+	}
+	var addrs []netip.Addr
+	switch {
+	case host == "localhost" || strings.HasSuffix(host, ".localhost"):
+		addrs = []netip.Addr{netip.IPv6Loopback(), netip.AddrFrom4([4]byte{127, 0, 0, 1})}
+	default:
+		if a, err := netip.ParseAddr(host); err == nil {
+			addrs = []netip.Addr{a}
+		}
+	}
+	for _, a := range addrs {
+		if reason := p.blockedReason(netip.AddrPortFrom(a, port)); reason != "" {
+			return &BlockedError{Target: raw, Reason: reason}
+		}
+	}
+	return nil
+}
+
+// Control is a net.Dialer Control hook: it refuses the connection when
+// the resolved address is reserved and not allowed by the policy.
+func (p Policy) Control(_, address string, _ syscall.RawConn) error {
+	ap, err := netip.ParseAddrPort(address)
+	if err != nil {
+		return &BlockedError{Target: address, Reason: "unparseable dial address"}
+	}
+	if reason := p.blockedReason(ap); reason != "" {
+		return &BlockedError{Target: ap.String(), Reason: reason}
+	}
+	return nil
+}
+
+// Guard installs the policy on d, so every connection it makes — direct
+// or via a copy, like the router's egress dialer — is checked.
+func (p Policy) Guard(d *net.Dialer) {
+	if !p.AllowPrivate {
+		d.Control = p.Control
+	}
+}
+
+// blockedReason is why ap may not be dialled, or "" when it may.
+func (p Policy) blockedReason(ap netip.AddrPort) string {
+	if p.AllowPrivate {
+		return ""
+	}
+	addr := ap.Addr().Unmap().WithZone("")
+	if slices.Contains(p.TrustedAddrs, netip.AddrPortFrom(addr, ap.Port())) {
+		return ""
+	}
+	for _, n := range p.AllowedNets {
+		if n.Contains(addr) {
+			return ""
+		}
+	}
+	return reservedReason(addr)
+}
+
+func reservedReason(addr netip.Addr) string {
+	for _, r := range reservedNets {
+		if r.prefix.Contains(addr) {
+			return r.reason
+		}
+	}
+	return ""
+}
+
+func hostMatches(pattern, host string) bool {
+	pattern = strings.ToLower(strings.TrimSpace(pattern))
+	if suffix, ok := strings.CutPrefix(pattern, "*."); ok {
+		return strings.HasSuffix(host, "."+suffix)
+	}
+	return host == pattern
+}
+
+// ParseNets parses a comma-separated list of CIDR ranges or single
+// addresses.
+func ParseNets(raw string) ([]netip.Prefix, error) {
+	var out []netip.Prefix
+	for _, s := range strings.Split(raw, ",") {
+		if s = strings.TrimSpace(s); s == "" {
+			continue
+		}
+		if a, err := netip.ParseAddr(s); err == nil {
+			out = append(out, netip.PrefixFrom(a.Unmap(), a.Unmap().BitLen()))
+			continue
+		}
+		p, err := netip.ParsePrefix(s)
+		if err != nil {
+			return nil, fmt.Errorf("allowed net %q: not a CIDR range or address", s)
+		}
+		out = append(out, p.Masked())
+	}
+	return out, nil
+}
+
+// ClientPolicy is one client's adjustment to the deployment policy:
+// AllowedHosts replaces the deployment's host allowlist, AllowedNets adds
+// to its allowed ranges.
+type ClientPolicy struct {
+	AllowedHosts []string `json:"allowedHosts,omitempty"`
+	AllowedNets  []string `json:"allowedNets,omitempty"`
+}
+
+// Policies holds the deployment policy and per-client overrides.
+type Policies struct {
+	Default Policy
+	Clients map[string]Policy
+}
+
+// NewPolicies merges the FC_OUTBOUND_CLIENT_POLICIES JSON object (client
+// id → ClientPolicy) onto base. Empty raw yields base for every client.
+func NewPolicies(base Policy, raw string) (*Policies, error) {
+	ps := &Policies{Default: base, Clients: map[string]Policy{}}
+	if strings.TrimSpace(raw) == "" {
+		return ps, nil
+	}
+	var byClient map[string]ClientPolicy
+	if err := json.Unmarshal([]byte(raw), &byClient); err != nil {
+		return nil, fmt.Errorf("parse client outbound policies: %w", err)
+	}
+	for clientID, cp := range byClient {
+		nets, err := ParseNets(strings.Join(cp.AllowedNets, ","))
+		if err != nil {
+			return nil, fmt.Errorf("client %q: %w", clientID, err)
+		}
+		p := base
+		p.AllowedNets = append(slices.Clip(base.AllowedNets), nets...)
+		if len(cp.AllowedHosts) > 0 {
+			p.AllowedHosts = cp.AllowedHosts
+		}
+		ps.Clients[clientID] = p
+	}
+	return ps, nil
+}
+
+// For returns the policy for clientID; nil (a platform-wide target) or a
+// client without an override gets the deployment policy.
+func (ps *Policies) For(clientID *string) Policy {
+	if clientID != nil {
+		if p, ok := ps.Clients[*clientID]; ok {
+			return p
+		}
+	}
+	return ps.Default
+}
+
+// CheckURL validates raw against clientID's policy. A nil receiver
+// allows everything, so callers can wire it unconditionally.
+func (ps *Policies) CheckURL(clientID *string, raw string) error {
+	if ps == nil {
+		return nil
+	}
+	return ps.For(clientID).CheckURL(raw)
+}
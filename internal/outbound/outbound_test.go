@@ -0,0 +1,121 @@
+package outbound_test
+
+import (
+	"errors"
+	"net"
+	"net/netip"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+)
+
+func TestPolicyCheckURL(t *testing.T) {
+	p := outbound.Policy{}
+	for _, tc := range []struct {
+		url     string
+		blocked bool
+	}{
+		{"https://hooks.example.com/in", false},
+		{"http://hooks.example.com:8080/in", false},
+		{"https://203.0.113.7/in", false},
+		{"ftp://hooks.example.com/in", true},
+		{"file:///etc/passwd", true},
+		{"http://169.254.169.254/latest/meta-data/", true},
+		{"http://127.0.0.1:8080/", true},
+		{"http://[::1]/", true},
+		{"http://[::ffff:10.0.0.1]/", true},
+		{"http://[64:ff9b::a9fe:a9fe]/", true},
+		{"http://[2002:c0a8:10a::1]/", true},
+		{"https://[2606:4700::1111]/", false},
+		{"http://localhost:9000/", true},
+		{"http://api.localhost/", true},
+		{"http://192.168.1.10/", true},
+		{"https:///no-host", true},
+	} {
+		err := p.CheckURL(tc.url)
+		if tc.blocked {
+			assert.ErrorIs(t, err, outbound.ErrBlocked, tc.url)
+		} else {
+			assert.NoError(t, err, tc.url)
+		}
+	}
+}
+
+func TestPolicyAllowances(t *testing.T) {
+	hosts := outbound.Policy{AllowedHosts: []string{"hooks.example.com", "*.partner.io"}}
+	assert.NoError(t, hosts.CheckURL("https://hooks.example.com/x"))
+	assert.NoError(t, hosts.CheckURL("https://eu.partner.io/x"))
+	assert.Error(t, hosts.CheckURL("https://partner.io/x"), "wildcard matches subdomains only")
+	assert.Error(t, hosts.CheckURL("https://evil.example.com/x"))
+
+	nets, err := outbound.ParseNets("10.20.0.0/16, 192.168.1.5")
+	require.NoError(t, err)
+	internal := outbound.Policy{AllowedNets: nets}
+	assert.NoError(t, internal.CheckURL("http://10.20.3.4/x"))
+	assert.NoError(t, internal.CheckURL("http://192.168.1.5/x"))
+	assert.Error(t, internal.CheckURL("http://10.21.0.1/x"))
+	assert.Error(t, internal.CheckURL("http://169.254.169.254/x"))
+
+	trusted := outbound.Policy{TrustedAddrs: []netip.AddrPort{netip.MustParseAddrPort("127.0.0.1:8080")}}
+	assert.NoError(t, trusted.CheckURL("http://127.0.0.1:8080/api/dispatch/process"))
+	assert.Error(t, trusted.CheckURL("http://127.0.0.1:5432/"), "trust is per port")
+
+	dev := outbound.Policy{AllowPrivate: true}
+	assert.NoError(t, dev.CheckURL("http://localhost:9000/"))
+	assert.Error(t, dev.CheckURL("gopher://localhost/"), "schemes still apply")
+
+	_, err = outbound.ParseNets("10.0.0.0/33")
+	assert.Error(t, err)
+}
+
+func TestGuardRefusesResolvedAddress(t *testing.T) {
+	ln, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+	defer ln.Close()
+
+	// The URL check cannot see through a name; the dialer checks the
+	// address the name resolved to.
+	d := &net.Dialer{}
+	outbound.Policy{}.Guard(d)
+	_, err = d.Dial("tcp", ln.Addr().String())
+	require.Error(t, err)
+	var blocked *outbound.BlockedError
+	require.True(t, errors.As(err, &blocked), "got %v", err)
+	assert.Equal(t, "loopback", blocked.Reason)
+
+	d = &net.Dialer{}
+	outbound.Policy{AllowPrivate: true}.Guard(d)
+	conn, err := d.Dial("tcp", ln.Addr().String())
+	require.NoError(t, err)
+	_ = conn.Close()
+}
+
+func TestNewPolicies(t *testing.T) {
+	base := outbound.Policy{
+		AllowedHosts: []string{"hooks.example.com"},
+		AllowedNets:  []netip.Prefix{netip.MustParsePrefix("10.1.0.0/16")},
+	}
+	ps, err := outbound.NewPolicies(base, `{"clt_A":{"allowedHosts":["*.acme.test"],"allowedNets":["10.9.0.0/16"]}}`)
+	require.NoError(t, err)
+
+	acme := "clt_A"
+	other := "clt_B"
+	assert.NoError(t, ps.CheckURL(&acme, "https://in.acme.test/x"))
+	assert.Error(t, ps.CheckURL(&acme, "https://hooks.example.com/x"), "client hosts replace the deployment list")
+	assert.NoError(t, ps.For(&acme).CheckURL("http://10.9.0.1/x"))
+	assert.NoError(t, ps.For(&acme).CheckURL("http://10.1.0.1/x"), "client nets add to the deployment's")
+	assert.NoError(t, ps.CheckURL(&other, "https://hooks.example.com/x"))
+	assert.Error(t, ps.CheckURL(nil, "https://in.acme.test/x"))
+	assert.Len(t, base.AllowedNets, 1, "base policy is not mutated")
+
+	var none *outbound.Policies
+	assert.NoError(t, none.CheckURL(nil, "http://169.254.169.254/"))
+
+	_, err = outbound.NewPolicies(base, `{"clt_A":`)
+	assert.Error(t, err)
+	_, err = outbound.NewPolicies(base, `{"clt_A":{"allowedNets":["nope"]}}`)
+	assert.Error(t, err)
+}
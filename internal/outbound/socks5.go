@@ -0,0 +1,54 @@
+package outbound
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net"
+
+	"golang.org/x/net/proxy"
+)
+
+// socks5Transport dials deliveries through a SOCKS5 proxy — the customer's
+// own, or `ssh -D` on a host that holds the bastion session. The proxy
+// resolves target names.
+type socks5Transport struct {
+	name string
+	spec TransportSpec
+	env  TransportEnv
+}
+
+func newSOCKS5Transport(name string, spec TransportSpec, env TransportEnv) (Transport, error) {
+	if spec.Password != "" && spec.User == "" {
+		return nil, errors.New("socks5 transport has a password but no user")
+	}
+	return &socks5Transport{name: name, spec: spec, env: env}, nil
+}
+
+// DialContext resolves the password per connection: the HTTP client pools
+// connections, so this is once per new connection, not per delivery.
+func (t *socks5Transport) DialContext(ctx context.Context, network, addr string) (net.Conn, error) {
+	var auth *proxy.Auth
+	if t.spec.User != "" {
+		pass, err := t.env.resolve(ctx, t.spec.Password)
+		if err != nil {
+			return nil, fmt.Errorf("socks5 transport %s: resolve password: %w", t.name, err)
+		}
+		auth = &proxy.Auth{User: t.spec.User, Password: pass}
+	}
+	d, err := proxy.SOCKS5("tcp", t.spec.Address, auth, t.env.dialer())
+	if err != nil {
+		return nil, fmt.Errorf("socks5 transport %s: %w", t.name, err)
+	}
+	cd, ok := d.(proxy.ContextDialer)
+	if !ok {
+		return nil, fmt.Errorf("socks5 transport %s: dialer does not support contexts", t.name)
+	}
+	conn, err := cd.DialContext(ctx, network, addr)
+	if err != nil {
+		return nil, fmt.Errorf("socks5 transport %s: dial %s via %s: %w", t.name, addr, t.spec.Address, err)
+	}
+	return conn, nil
+}
+
+func (t *socks5Transport) Close() error { return nil }
@@ -0,0 +1,143 @@
+package outbound
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net"
+	"sync"
+	"time"
+
+	"golang.org/x/crypto/ssh"
+)
+
+// sshHandshakeTimeout bounds connecting to the bastion when the delivery
+// that triggered it has no deadline of its own.
+const sshHandshakeTimeout = 30 * time.Second
+
+// sshTransport tunnels deliveries through an SSH bastion, each connection
+// a direct-tcpip channel (what `ssh -W` uses). One SSH session carries
+// every delivery; it is opened on first use and reopened after it drops.
+type sshTransport struct {
+	name string
+	spec TransportSpec
+	env  TransportEnv
+
+	mu     sync.Mutex
+	client *ssh.Client
+	closed bool
+}
+
+func newSSHTransport(name string, spec TransportSpec, env TransportEnv) (Transport, error) {
+	switch {
+	case spec.User == "":
+		return nil, errors.New("ssh transport needs a user")
+	case spec.PrivateKey == "":
+		return nil, errors.New("ssh transport needs a privateKey")
+	case spec.HostKey == "":
+		return nil, errors.New("ssh transport needs the bastion's hostKey")
+	}
+	return &sshTransport{name: name, spec: spec, env: env}, nil
+}
+
+func (t *sshTransport) DialContext(ctx context.Context, network, addr string) (net.Conn, error) {
+	c, err := t.session(ctx)
+	if err != nil {
+		return nil, err
+	}
+	conn, err := c.DialContext(ctx, network, addr)
+	if err != nil {
+		return nil, fmt.Errorf("ssh transport %s: dial %s via %s: %w", t.name, addr, t.spec.Address, err)
+	}
+	return conn, nil
+}
+
+func (t *sshTransport) Close() error {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	t.closed = true
+	if t.client == nil {
+		return nil
+	}
+	err := t.client.Close()
+	t.client = nil
+	return err
+}
+
+// session returns the live SSH client, connecting if there is none.
+// Concurrent callers wait for the one connection attempt.
+func (t *sshTransport) session(ctx context.Context) (*ssh.Client, error) {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	if t.closed {
+		return nil, net.ErrClosed
+	}
+	if t.client != nil {
+		return t.client, nil
+	}
+	c, err := t.connect(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("ssh transport %s: %w", t.name, err)
+	}
+	t.client = c
+	go func() {
+		_ = c.Wait()
+		t.mu.Lock()
+		if t.client == c {
+			t.client = nil
+		}
+		t.mu.Unlock()
+	}()
+	return c, nil
+}
+
+// connect resolves the key material, dials the bastion and authenticates,
+// refusing any host key but the pinned one.
+func (t *sshTransport) connect(ctx context.Context) (*ssh.Client, error) {
+	key, err := t.env.resolve(ctx, t.spec.PrivateKey)
+	if err != nil {
+		return nil, fmt.Errorf("resolve private key: %w", err)
+	}
+	var signer ssh.Signer
+	if t.spec.Passphrase != "" {
+		pass, err := t.env.resolve(ctx, t.spec.Passphrase)
+		if err != nil {
+			return nil, fmt.Errorf("resolve passphrase: %w", err)
+		}
+		signer, err = ssh.ParsePrivateKeyWithPassphrase([]byte(key), []byte(pass))
+		if err != nil {
+			return nil, fmt.Errorf("parse private key: %w", err)
+		}
+	} else if signer, err = ssh.ParsePrivateKey([]byte(key)); err != nil {
+		return nil, fmt.Errorf("parse private key: %w", err)
+	}
+	hostKey, err := t.env.resolve(ctx, t.spec.HostKey)
+	if err != nil {
+		return nil, fmt.Errorf("resolve host key: %w", err)
+	}
+	pinned, _, _, _, err := ssh.ParseAuthorizedKey([]byte(hostKey))
+	if err != nil {
+		return nil, fmt.Errorf("parse host key: %w", err)
+	}
+
+	raw, err := t.env.dialer().DialContext(ctx, "tcp", t.spec.Address)
+	if err != nil {
+		return nil, err
+	}
+	deadline, ok := ctx.Deadline()
+	if !ok {
+		deadline = time.Now().Add(sshHandshakeTimeout)
+	}
+	_ = raw.SetDeadline(deadline)
+	conn, chans, reqs, err := ssh.NewClientConn(raw, t.spec.Address, &ssh.ClientConfig{
+		User:            t.spec.User,
+		Auth:            []ssh.AuthMethod{ssh.PublicKeys(signer)},
+		HostKeyCallback: ssh.FixedHostKey(pinned),
+	})
+	if err != nil {
+		_ = raw.Close()
+		return nil, fmt.Errorf("handshake with %s: %w", t.spec.Address, err)
+	}
+	_ = raw.SetDeadline(time.Time{})
+	return ssh.NewClient(conn, chans, reqs), nil
+}
@@ -0,0 +1,175 @@
+package outbound
+
+import (
+	"crypto/sha256"
+	"crypto/tls"
+	"crypto/x509"
+	"encoding/base64"
+	"errors"
+	"fmt"
+	"slices"
+	"strings"
+)
+
+// TLS trust. A receiver behind an internal CA fails verification against
+// the system roots; TLSTrust adds roots for it, optionally pins the keys
+// its chain may carry, and can raise the minimum protocol version. The
+// deployment has one (FC_OUTBOUND_TLS_*), and a subscription may add its
+// own on top.
+
+// MaxCABundleBytes bounds a CA bundle.
+const MaxCABundleBytes = 256 << 10
+
+// MaxSPKIPins bounds the pins a trust may list.
+const MaxSPKIPins = 10
+
+// spkiPinPrefix starts every pin: the hash algorithm, as in HPKP.
+const spkiPinPrefix = "sha256/"
+
+// ErrPinMismatch matches a handshake refused because no certificate in the
+// verified chain carries a pinned key.
+var ErrPinMismatch = errors.New("no certificate in the chain matches a pinned SPKI")
+
+// TLSTrust is extra trust configuration for a delivery target. The zero
+// value changes nothing.
+type TLSTrust struct {
+	// CABundle is PEM root certificates trusted in addition to the system
+	// roots.
+	CABundle string `json:"caBundle,omitempty"`
+	// SPKIPins are "sha256/<base64>" hashes of subject public key infos.
+	// When set, a certificate in the verified chain must match one.
+	SPKIPins []string `json:"spkiPins,omitempty"`
+	// MinVersion is the lowest TLS version accepted: "1.2" or "1.3".
+	// Empty keeps the Go default (1.2).
+	MinVersion string `json:"minVersion,omitempty"`
+}
+
+// IsZero reports whether t adds nothing. Nil-safe.
+func (t *TLSTrust) IsZero() bool {
+	return t == nil || (t.CABundle == "" && len(t.SPKIPins) == 0 && t.MinVersion == "")
+}
+
+// Validate checks the bundle parses, the pins are well formed and the
+// version is known. Nil-safe.
+func (t *TLSTrust) Validate() error {
+	if t.IsZero() {
+		return nil
+	}
+	_, err := t.ClientConfig()
+	return err
+}
+
+// With layers o over t: both bundles are trusted, both sets of pins
+// accepted, and the higher minimum version applies.
+func (t TLSTrust) With(o *TLSTrust) TLSTrust {
+	if o.IsZero() {
+		return t
+	}
+	out := TLSTrust{
+		CABundle:   strings.TrimSpace(t.CABundle + "\n" + o.CABundle),
+		SPKIPins:   append(slices.Clone(t.SPKIPins), o.SPKIPins...),
+		MinVersion: t.MinVersion,
+	}
+	if tlsVersions[o.MinVersion] > tlsVersions[out.MinVersion] {
+		out.MinVersion = o.MinVersion
+	}
+	return out
+}
+
+// tlsVersions are the accepted MinVersion values.
+var tlsVersions = map[string]uint16{
+	"1.2": tls.VersionTLS12,
+	"1.3": tls.VersionTLS13,
+}
+
+// ClientConfig builds the client TLS config t describes.
+func (t TLSTrust) ClientConfig() (*tls.Config, error) {
+	cfg := &tls.Config{MinVersion: tls.VersionTLS12}
+	if t.MinVersion != "" {
+		v, ok := tlsVersions[t.MinVersion]
+		if !ok {
+			return nil, fmt.Errorf("minVersion %q must be 1.2 or 1.3", t.MinVersion)
+		}
+		cfg.MinVersion = v
+	}
+	if t.CABundle != "" {
+		if len(t.CABundle) > MaxCABundleBytes {
+			return nil, fmt.Errorf("caBundle is larger than %d bytes", MaxCABundleBytes)
+		}
+		pool, err := x509.SystemCertPool()
+		if err != nil {
+			pool = x509.NewCertPool()
+		}
+		if !pool.AppendCertsFromPEM([]byte(t.CABundle)) {
+			return nil, errors.New("caBundle holds no PEM certificates")
+		}
+		cfg.RootCAs = pool
+	}
+	if len(t.SPKIPins) > 0 {
+		pins, err := parsePins(t.SPKIPins)
+		if err != nil {
+			return nil, err
+		}
+		cfg.VerifyConnection = func(cs tls.ConnectionState) error {
+			for _, chain := range cs.VerifiedChains {
+				for _, cert := range chain {
+					if _, ok := pins[sha256.Sum256(cert.RawSubjectPublicKeyInfo)]; ok {
+						return nil
+					}
+				}
+			}
+			return fmt.Errorf("tls: %s: %w", cs.ServerName, ErrPinMismatch)
+		}
+	}
+	return cfg, nil
+}
+
+func parsePins(raw []string) (map[[sha256.Size]byte]struct{}, error) {
+	if len(raw) > MaxSPKIPins {
+		return nil, fmt.Errorf("at most %d spkiPins", MaxSPKIPins)
+	}
+	pins := make(map[[sha256.Size]byte]struct{}, len(raw))
+	for _, p := range raw {
+		enc, ok := strings.CutPrefix(p, spkiPinPrefix)
+		if !ok {
+			return nil, fmt.Errorf("spkiPin %q must start with %q", p, spkiPinPrefix)
+		}
+		sum, err := base64.StdEncoding.DecodeString(enc)
+		if err != nil || len(sum) != sha256.Size {
+			return nil, fmt.Errorf("spkiPin %q is not a base64 SHA-256 hash", p)
+		}
+		pins[[sha256.Size]byte(sum)] = struct{}{}
+	}
+	return pins, nil
+}
+
+// SPKIPin returns the pin for cert's public key.
+func SPKIPin(cert *x509.Certificate) string {
+	sum := sha256.Sum256(cert.RawSubjectPublicKeyInfo)
+	return spkiPinPrefix + base64.StdEncoding.EncodeToString(sum[:])
+}
+
+// IsTrustFailure reports whether err is the target failing the client's
+// trust checks: a certificate that didn't verify, a pin mismatch, or no
+// protocol version both sides accept. These are configuration — of the
+// target or of its trust — and retrying won't fix them.
+func IsTrustFailure(err error) bool {
+	if err == nil {
+		return false
+	}
+	var (
+		verifyErr   *tls.CertificateVerificationError
+		authErr     x509.UnknownAuthorityError
+		hostnameErr x509.HostnameError
+		invalidErr  x509.CertificateInvalidError
+	)
+	if errors.Is(err, ErrPinMismatch) || errors.As(err, &verifyErr) || errors.As(err, &authErr) ||
+		errors.As(err, &hostnameErr) || errors.As(err, &invalidErr) {
+		return true
+	}
+	// Version mismatches have no typed error: the server's protocol_version
+	// alert, or the client refusing the version the server picked.
+	msg := err.Error()
+	return strings.Contains(msg, "tls: protocol version not supported") ||
+		strings.Contains(msg, "tls: server selected unsupported protocol version")
+}
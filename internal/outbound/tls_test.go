@@ -0,0 +1,87 @@
+package outbound_test
+
+import (
+	"crypto/tls"
+	"encoding/pem"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+)
+
+// get requests srv through a client built from trust.
+func get(t *testing.T, srv *httptest.Server, trust outbound.TLSTrust) error {
+	t.Helper()
+	cfg, err := trust.ClientConfig()
+	require.NoError(t, err)
+	client := &http.Client{Transport: &http.Transport{TLSClientConfig: cfg}}
+	defer client.CloseIdleConnections()
+	resp, err := client.Get(srv.URL)
+	if err == nil {
+		resp.Body.Close()
+	}
+	return err
+}
+
+func TestTLSTrust(t *testing.T) {
+	srv := httptest.NewTLSServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {}))
+	defer srv.Close()
+	bundle := string(pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: srv.Certificate().Raw}))
+	pin := outbound.SPKIPin(srv.Certificate())
+	otherPin := "sha256/" + strings.Repeat("A", 43) + "="
+
+	// The test CA isn't a system root.
+	err := get(t, srv, outbound.TLSTrust{})
+	require.Error(t, err)
+	assert.True(t, outbound.IsTrustFailure(err), "%v", err)
+
+	assert.NoError(t, get(t, srv, outbound.TLSTrust{CABundle: bundle}))
+	assert.NoError(t, get(t, srv, outbound.TLSTrust{CABundle: bundle, SPKIPins: []string{otherPin, pin}}))
+
+	err = get(t, srv, outbound.TLSTrust{CABundle: bundle, SPKIPins: []string{otherPin}})
+	require.ErrorIs(t, err, outbound.ErrPinMismatch)
+	assert.True(t, outbound.IsTrustFailure(err))
+}
+
+func TestTLSTrustMinVersion(t *testing.T) {
+	srv := httptest.NewUnstartedServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {}))
+	srv.TLS = &tls.Config{MaxVersion: tls.VersionTLS12}
+	srv.StartTLS()
+	defer srv.Close()
+	bundle := string(pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: srv.Certificate().Raw}))
+
+	assert.NoError(t, get(t, srv, outbound.TLSTrust{CABundle: bundle, MinVersion: "1.2"}))
+	err := get(t, srv, outbound.TLSTrust{CABundle: bundle, MinVersion: "1.3"})
+	require.Error(t, err)
+	assert.True(t, outbound.IsTrustFailure(err), "%v", err)
+}
+
+func TestTLSTrustValidate(t *testing.T) {
+	var none *outbound.TLSTrust
+	assert.True(t, none.IsZero())
+	assert.NoError(t, none.Validate())
+
+	for name, trust := range map[string]outbound.TLSTrust{
+		"not pem":       {CABundle: "hello"},
+		"pin prefix":    {SPKIPins: []string{"sha1/" + strings.Repeat("A", 27) + "="}},
+		"pin length":    {SPKIPins: []string{"sha256/AAAA"}},
+		"old version":   {MinVersion: "1.1"},
+		"too many pins": {SPKIPins: make([]string, outbound.MaxSPKIPins+1)},
+	} {
+		assert.Error(t, trust.Validate(), name)
+	}
+	assert.False(t, outbound.IsTrustFailure(nil))
+}
+
+func TestTLSTrustWith(t *testing.T) {
+	base := outbound.TLSTrust{CABundle: "A", MinVersion: "1.3"}
+	assert.Equal(t, base, base.With(nil))
+	assert.Equal(t, outbound.TLSTrust{CABundle: "A\nB", SPKIPins: []string{"p"}, MinVersion: "1.3"},
+		base.With(&outbound.TLSTrust{CABundle: "B", SPKIPins: []string{"p"}, MinVersion: "1.2"}))
+	assert.Equal(t, "1.3", outbound.TLSTrust{}.With(&outbound.TLSTrust{MinVersion: "1.3"}).MinVersion)
+}
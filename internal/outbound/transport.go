@@ -0,0 +1,179 @@
+package outbound
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net"
+	"slices"
+	"strings"
+	"time"
+)
+
+// Transport carries a delivery's connections somewhere other than straight
+// out of this host — through a customer's SSH bastion or a SOCKS5 proxy —
+// for receivers that only accept traffic from their own network. A
+// subscription selects one by name; deliveries without one dial directly.
+type Transport interface {
+	// DialContext opens a connection to addr (host:port) through the
+	// transport. The name is resolved on the far side.
+	DialContext(ctx context.Context, network, addr string) (net.Conn, error)
+	// Close releases any long-lived session (an SSH connection).
+	Close() error
+}
+
+// TransportSpec configures one named transport. Secret fields may be
+// secrets references (env://, aws-sm://, encrypted:...) and are resolved
+// whenever the transport (re)connects, so a rotated key is picked up
+// without a restart.
+type TransportSpec struct {
+	// Type selects the factory: "ssh", "socks5", or a registered plugin.
+	Type string `json:"type"`
+	// Address is the bastion or proxy, host:port.
+	Address string `json:"address"`
+	// User is the SSH login, or the SOCKS5 username.
+	User string `json:"user,omitempty"`
+	// Password is the SOCKS5 password. Secret.
+	Password string `json:"password,omitempty"`
+	// PrivateKey is the SSH private key, PEM or OpenSSH format. Secret.
+	PrivateKey string `json:"privateKey,omitempty"`
+	// Passphrase decrypts PrivateKey, when it is encrypted. Secret.
+	Passphrase string `json:"passphrase,omitempty"`
+	// HostKey pins the bastion's host key, in authorized_keys format
+	// ("ssh-ed25519 AAAA..."). Required for ssh.
+	HostKey string `json:"hostKey,omitempty"`
+	// Options carries settings for plugin transports.
+	Options map[string]string `json:"options,omitempty"`
+}
+
+// SecretResolver resolves a secrets reference, or returns a literal
+// unchanged. Satisfied by *secrets.Service.
+type SecretResolver interface {
+	Resolve(ctx context.Context, ref string) (string, error)
+}
+
+// TransportEnv is what a factory gets besides its spec.
+type TransportEnv struct {
+	// Dialer reaches the bastion or proxy. It carries the deployment's
+	// address policy, so a transport cannot point at the metadata service.
+	Dialer *net.Dialer
+	// Secrets resolves the spec's secret fields.
+	Secrets SecretResolver
+}
+
+// TransportFactory builds a transport from its spec. It should validate
+// the spec but not connect: the bastion may be down at startup.
+type TransportFactory func(name string, spec TransportSpec, env TransportEnv) (Transport, error)
+
+var transportFactories = map[string]TransportFactory{
+	"ssh":    newSSHTransport,
+	"socks5": newSOCKS5Transport,
+}
+
+// RegisterTransport adds a transport type, for a deployment that needs one
+// beyond ssh and socks5. Call it from an init function: the registry is
+// not safe for concurrent use.
+func RegisterTransport(typ string, f TransportFactory) {
+	transportFactories[typ] = f
+}
+
+// Transports are the named transports subscriptions may select.
+type Transports struct {
+	byName map[string]Transport
+}
+
+// NewTransports builds the transports in the FC_OUTBOUND_TRANSPORTS JSON
+// object (name → TransportSpec). Empty raw yields none.
+func NewTransports(raw string, env TransportEnv) (*Transports, error) {
+	ts := &Transports{byName: map[string]Transport{}}
+	if strings.TrimSpace(raw) == "" {
+		return ts, nil
+	}
+	var specs map[string]TransportSpec
+	if err := json.Unmarshal([]byte(raw), &specs); err != nil {
+		return nil, fmt.Errorf("parse outbound transports: %w", err)
+	}
+	for name, spec := range specs {
+		factory, ok := transportFactories[spec.Type]
+		if !ok {
+			return nil, fmt.Errorf("transport %q: unknown type %q", name, spec.Type)
+		}
+		if _, _, err := net.SplitHostPort(spec.Address); err != nil {
+			return nil, fmt.Errorf("transport %q: address must be host:port: %w", name, err)
+		}
+		t, err := factory(name, spec, env)
+		if err != nil {
+			return nil, fmt.Errorf("transport %q: %w", name, err)
+		}
+		ts.byName[name] = t
+	}
+	return ts, nil
+}
+
+// Get returns the named transport.
+func (ts *Transports) Get(name string) (Transport, bool) {
+	if ts == nil {
+		return nil, false
+	}
+	t, ok := ts.byName[name]
+	return t, ok
+}
+
+// Names lists the configured transports, sorted.
+func (ts *Transports) Names() []string {
+	if ts == nil {
+		return nil
+	}
+	names := make([]string, 0, len(ts.byName))
+	for name := range ts.byName {
+		names = append(names, name)
+	}
+	slices.Sort(names)
+	return names
+}
+
+// Check reports whether a subscription may select name. nil or empty
+// (direct delivery) always passes; so does anything on a nil receiver,
+// so callers can wire it unconditionally.
+func (ts *Transports) Check(name *string) error {
+	if ts == nil || name == nil || *name == "" {
+		return nil
+	}
+	if _, ok := ts.byName[*name]; !ok {
+		configured := strings.Join(ts.Names(), ", ")
+		if configured == "" {
+			configured = "none"
+		}
+		return fmt.Errorf("transport %q is not configured (configured: %s)", *name, configured)
+	}
+	return nil
+}
+
+// Close closes every transport.
+func (ts *Transports) Close() error {
+	if ts == nil {
+		return nil
+	}
+	var errs []error
+	for _, t := range ts.byName {
+		errs = append(errs, t.Close())
+	}
+	return errors.Join(errs...)
+}
+
+// resolve returns ref's secret, or ref itself with no resolver configured.
+func (e TransportEnv) resolve(ctx context.Context, ref string) (string, error) {
+	if e.Secrets == nil || ref == "" {
+		return ref, nil
+	}
+	return e.Secrets.Resolve(ctx, ref)
+}
+
+// dialer is e.Dialer, or a plain one.
+func (e TransportEnv) dialer() *net.Dialer {
+	if e.Dialer == nil {
+		return &net.Dialer{Timeout: 30 * time.Second}
+	}
+	return e.Dialer
+}
@@ -0,0 +1,199 @@
+package outbound_test
+
+import (
+	"bytes"
+	"context"
+	"crypto/ed25519"
+	"crypto/rand"
+	"encoding/json"
+	"encoding/pem"
+	"errors"
+	"io"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"strconv"
+	"sync/atomic"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"golang.org/x/crypto/ssh"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+)
+
+func TestNewTransportsValidatesSpecs(t *testing.T) {
+	for name, raw := range map[string]string{
+		"bad json":     `{"b":`,
+		"unknown type": `{"b":{"type":"carrier-pigeon","address":"b.example.com:22"}}`,
+		"no port":      `{"b":{"type":"socks5","address":"proxy.example.com"}}`,
+		"no host key":  `{"b":{"type":"ssh","address":"b.example.com:22","user":"fc","privateKey":"env://K"}}`,
+		"no user":      `{"b":{"type":"ssh","address":"b.example.com:22","privateKey":"env://K","hostKey":"ssh-ed25519 AAAA"}}`,
+	} {
+		_, err := outbound.NewTransports(raw, outbound.TransportEnv{})
+		assert.Error(t, err, name)
+	}
+
+	ts, err := outbound.NewTransports(`{"corp":{"type":"socks5","address":"proxy.example.com:1080"}}`, outbound.TransportEnv{})
+	require.NoError(t, err)
+	assert.Equal(t, []string{"corp"}, ts.Names())
+	corp, gone, empty := "corp", "gone", ""
+	assert.NoError(t, ts.Check(&corp))
+	assert.NoError(t, ts.Check(nil))
+	assert.NoError(t, ts.Check(&empty))
+	assert.ErrorContains(t, ts.Check(&gone), `"gone" is not configured (configured: corp)`)
+
+	var none *outbound.Transports
+	assert.NoError(t, none.Check(&gone))
+}
+
+// mapSecrets resolves references from a fixed map.
+type mapSecrets map[string]string
+
+func (m mapSecrets) Resolve(_ context.Context, ref string) (string, error) {
+	if v, ok := m[ref]; ok {
+		return v, nil
+	}
+	return "", errors.New("no secret " + ref)
+}
+
+func TestSSHTransportTunnelsThroughBastion(t *testing.T) {
+	target := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		_, _ = w.Write([]byte("via bastion"))
+	}))
+	defer target.Close()
+
+	_, clientKey, err := ed25519.GenerateKey(rand.Reader)
+	require.NoError(t, err)
+	clientSigner, err := ssh.NewSignerFromKey(clientKey)
+	require.NoError(t, err)
+	block, err := ssh.MarshalPrivateKey(clientKey, "")
+	require.NoError(t, err)
+	bastion, hostKey, channels := startBastion(t, clientSigner.PublicKey())
+
+	spec := func(hostKey string) string {
+		raw, _ := json.Marshal(map[string]outbound.TransportSpec{"acme": {
+			Type: "ssh", Address: bastion, User: "fc",
+			PrivateKey: "env://ACME_BASTION_KEY", HostKey: hostKey,
+		}})
+		return string(raw)
+	}
+	env := outbound.TransportEnv{Secrets: mapSecrets{"env://ACME_BASTION_KEY": string(pem.EncodeToMemory(block))}}
+
+	ts, err := outbound.NewTransports(spec(string(ssh.MarshalAuthorizedKey(hostKey))), env)
+	require.NoError(t, err)
+	tr, ok := ts.Get("acme")
+	require.True(t, ok)
+	client := &http.Client{Transport: &http.Transport{DialContext: tr.DialContext}}
+	for range 2 {
+		resp, err := client.Get(target.URL)
+		require.NoError(t, err)
+		body, _ := io.ReadAll(resp.Body)
+		_ = resp.Body.Close()
+		assert.Equal(t, "via bastion", string(body))
+	}
+	assert.Equal(t, int32(1), channels.Load(), "the pooled connection is reused")
+
+	require.NoError(t, ts.Close())
+	_, err = tr.DialContext(context.Background(), "tcp", target.Listener.Addr().String())
+	assert.ErrorIs(t, err, net.ErrClosed)
+
+	// A bastion presenting any other host key is refused.
+	_, otherKey, _ := ed25519.GenerateKey(rand.Reader)
+	otherSigner, _ := ssh.NewSignerFromKey(otherKey)
+	ts, err = outbound.NewTransports(spec(string(ssh.MarshalAuthorizedKey(otherSigner.PublicKey()))), env)
+	require.NoError(t, err)
+	tr, _ = ts.Get("acme")
+	_, err = tr.DialContext(context.Background(), "tcp", target.Listener.Addr().String())
+	assert.ErrorContains(t, err, "handshake")
+
+	// The bastion itself is subject to the address policy.
+	d := &net.Dialer{}
+	outbound.Policy{}.Guard(d)
+	env.Dialer = d
+	ts, err = outbound.NewTransports(spec(string(ssh.MarshalAuthorizedKey(hostKey))), env)
+	require.NoError(t, err)
+	tr, _ = ts.Get("acme")
+	_, err = tr.DialContext(context.Background(), "tcp", target.Listener.Addr().String())
+	assert.ErrorIs(t, err, outbound.ErrBlocked)
+}
+
+// startBastion runs an SSH server on loopback that accepts only
+// authorized and forwards direct-tcpip channels, counting them.
+func startBastion(t *testing.T, authorized ssh.PublicKey) (string, ssh.PublicKey, *atomic.Int32) {
+	t.Helper()
+	_, hostKey, err := ed25519.GenerateKey(rand.Reader)
+	require.NoError(t, err)
+	hostSigner, err := ssh.NewSignerFromKey(hostKey)
+	require.NoError(t, err)
+	cfg := &ssh.ServerConfig{
+		PublicKeyCallback: func(_ ssh.ConnMetadata, k ssh.PublicKey) (*ssh.Permissions, error) {
+			if bytes.Equal(k.Marshal(), authorized.Marshal()) {
+				return &ssh.Permissions{}, nil
+			}
+			return nil, errors.New("unknown key")
+		},
+	}
+	cfg.AddHostKey(hostSigner)
+
+	ln, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+	t.Cleanup(func() { _ = ln.Close() })
+	var channels atomic.Int32
+	go func() {
+		for {
+			c, err := ln.Accept()
+			if err != nil {
+				return
+			}
+			go serveBastion(c, cfg, &channels)
+		}
+	}()
+	return ln.Addr().String(), hostSigner.PublicKey(), &channels
+}
+
+func serveBastion(c net.Conn, cfg *ssh.ServerConfig, channels *atomic.Int32) {
+	_, chans, reqs, err := ssh.NewServerConn(c, cfg)
+	if err != nil {
+		_ = c.Close()
+		return
+	}
+	go ssh.DiscardRequests(reqs)
+	for nc := range chans {
+		if nc.ChannelType() != "direct-tcpip" {
+			_ = nc.Reject(ssh.UnknownChannelType, "")
+			continue
+		}
+		var dest struct {
+			Host     string
+			Port     uint32
+			OrigHost string
+			OrigPort uint32
+		}
+		if err := ssh.Unmarshal(nc.ExtraData(), &dest); err != nil {
+			_ = nc.Reject(ssh.ConnectionFailed, err.Error())
+			continue
+		}
+		conn, err := net.Dial("tcp", net.JoinHostPort(dest.Host, strconv.Itoa(int(dest.Port))))
+		if err != nil {
+			_ = nc.Reject(ssh.ConnectionFailed, err.Error())
+			continue
+		}
+		ch, chReqs, err := nc.Accept()
+		if err != nil {
+			_ = conn.Close()
+			continue
+		}
+		channels.Add(1)
+		go ssh.DiscardRequests(chReqs)
+		go func() {
+			_, _ = io.Copy(ch, conn)
+			_ = ch.CloseWrite()
+		}()
+		go func() {
+			_, _ = io.Copy(conn, ch)
+			_ = conn.Close()
+		}()
+	}
+}
@@ -3,6 +3,7 @@ package outbox
 import (
 	"encoding/json"
 	"net/http"
+	"time"
 
 	"github.com/go-chi/chi/v5"
 )
@@ -13,7 +14,10 @@ import (
 // (the Rust equivalent is the GroupDistributor's programmatic controls — no
 // HTTP — so this is a Go convenience that makes them operable):
 //
-//	GET  /outbox/groups               — non-default (Paused/Blocked) group states
+//	GET  /groups                      — debug view: every group with claimed items
+//	                                    or a non-Running state, with in-flight
+//	                                    counts, oldest item age and stuck flag
+//	GET  /outbox/groups               — non-default (Paused/Blocked/Parked) group states
 //	GET  /outbox/groups/blocked       — Blocked groups only
 //	POST /outbox/groups/{group}/pause
 //	POST /outbox/groups/{group}/resume
@@ -21,6 +25,9 @@ import (
 //	POST /outbox/groups/{group}/skip     — clear + leave the poison failed
 func (p *Processor) AdminHandler() http.Handler {
 	r := chi.NewRouter()
+	r.Get("/groups", func(w http.ResponseWriter, _ *http.Request) {
+		writeAdminJSON(w, http.StatusOK, map[string]any{"groups": p.GroupsReport(time.Now())})
+	})
 	r.Get("/outbox/groups", func(w http.ResponseWriter, _ *http.Request) {
 		writeAdminJSON(w, http.StatusOK, map[string]any{"groups": p.GroupStates()})
 	})
@@ -1,6 +1,9 @@
 package outbox
 
-import "sync"
+import (
+	"sync"
+	"time"
+)
 
 // GroupDistributor enforces FIFO ordering within a message group. When
 // blockOnError is set it also stops advancing a group as soon as one of its
@@ -29,11 +32,29 @@ type groupWork struct {
 	// onAbort releases an undispatched item back to PENDING when the group
 	// blocks before reaching it.
 	onAbort func()
+	// createdAt is the item's created_at, for the group's oldest-item age.
+	createdAt time.Time
 }
 
 type groupQueue struct {
 	pending []groupWork
 	running bool
+	// current is the item being dispatched, started at since; nil between
+	// items.
+	current *groupWork
+	since   time.Time
+}
+
+// GroupActivity is a snapshot of one message group holding claimed items.
+type GroupActivity struct {
+	Group string
+	// InFlight counts the group's claimed items, queued and dispatching.
+	InFlight int
+	// Oldest is the created_at of the group's oldest claimed item.
+	Oldest time.Time
+	// DispatchingSince is when the item being sent now started; zero
+	// between items.
+	DispatchingSince time.Time
 }
 
 // NewGroupDistributor builds a distributor. maxConcurrentGroups <= 0 leaves
@@ -64,7 +85,7 @@ func (d *GroupDistributor) Submit(item Item, dispatch func() bool, onAbort func(
 		q = &groupQueue{}
 		d.groups[group] = q
 	}
-	q.pending = append(q.pending, groupWork{dispatch: dispatch, onAbort: onAbort})
+	q.pending = append(q.pending, groupWork{dispatch: dispatch, onAbort: onAbort, createdAt: item.CreatedAt})
 	shouldStart := !q.running
 	if shouldStart {
 		q.running = true
@@ -97,9 +118,14 @@ func (d *GroupDistributor) drain(group string) {
 		}
 		work := q.pending[0]
 		q.pending = q.pending[1:]
+		q.current, q.since = &work, time.Now()
 		d.mu.Unlock()
 
-		if work.dispatch() || !d.blockOnError {
+		ok := work.dispatch()
+		d.mu.Lock()
+		q.current = nil
+		d.mu.Unlock()
+		if ok || !d.blockOnError {
 			continue
 		}
 		// Block-on-error: this item failed. Release the rest of the group's
@@ -120,3 +146,26 @@ func (d *GroupDistributor) drain(group string) {
 		return
 	}
 }
+
+// Activity snapshots every group with claimed items.
+func (d *GroupDistributor) Activity() []GroupActivity {
+	d.mu.Lock()
+	defer d.mu.Unlock()
+	out := make([]GroupActivity, 0, len(d.groups))
+	for g, q := range d.groups {
+		a := GroupActivity{Group: g, InFlight: len(q.pending)}
+		works := q.pending
+		if q.current != nil {
+			a.InFlight++
+			a.DispatchingSince = q.since
+			works = append([]groupWork{*q.current}, works...)
+		}
+		for _, w := range works {
+			if a.Oldest.IsZero() || (!w.createdAt.IsZero() && w.createdAt.Before(a.Oldest)) {
+				a.Oldest = w.createdAt
+			}
+		}
+		out = append(out, a)
+	}
+	return out
+}
@@ -0,0 +1,81 @@
+package outbox
+
+import (
+	"fmt"
+	"strings"
+)
+
+// GroupPolicy is what a message group does once one of its items fails
+// permanently (non-retryable, or out of retries). Retryable failures always
+// keep the group ordered — its remaining items are released and re-run
+// behind the failed one — except under GroupPolicySkip.
+type GroupPolicy string
+
+const (
+	// GroupPolicyBlock stops the group until an operator unblocks or skips
+	// the poison item. The default (BlockOnError).
+	GroupPolicyBlock GroupPolicy = "block"
+	// GroupPolicySkip leaves the item failed and carries on with the rest
+	// of the group, giving up ordering (BlockOnError=false).
+	GroupPolicySkip GroupPolicy = "skip"
+	// GroupPolicyPark stops the group for ParkDuration, then re-queues the
+	// poison item and resumes: an automatic, delayed unblock.
+	GroupPolicyPark GroupPolicy = "park"
+)
+
+// ParseGroupPolicy parses block, skip or park.
+func ParseGroupPolicy(s string) (GroupPolicy, error) {
+	switch p := GroupPolicy(strings.ToLower(strings.TrimSpace(s))); p {
+	case GroupPolicyBlock, GroupPolicySkip, GroupPolicyPark:
+		return p, nil
+	}
+	return "", fmt.Errorf("unknown message group policy %q (want block|skip|park)", s)
+}
+
+// ParseGroupPolicies parses per-group overrides written as
+// "orders=park,billing-*=skip": exact group names, or prefixes ending in "*".
+func ParseGroupPolicies(raw string) (map[string]GroupPolicy, error) {
+	out := make(map[string]GroupPolicy)
+	for _, entry := range strings.Split(raw, ",") {
+		entry = strings.TrimSpace(entry)
+		if entry == "" {
+			continue
+		}
+		group, policy, ok := strings.Cut(entry, "=")
+		group = strings.TrimSpace(group)
+		if !ok || group == "" {
+			return nil, fmt.Errorf("message group policy %q: want group=policy", entry)
+		}
+		p, err := ParseGroupPolicy(policy)
+		if err != nil {
+			return nil, err
+		}
+		out[group] = p
+	}
+	return out, nil
+}
+
+// policyFor resolves a group's policy: an exact override, else the longest
+// matching prefix override, else the default.
+func (c Config) policyFor(group string) GroupPolicy {
+	if p, ok := c.GroupPolicies[group]; ok {
+		return p
+	}
+	best, bestLen := GroupPolicy(""), -1
+	for pattern, p := range c.GroupPolicies {
+		prefix, ok := strings.CutSuffix(pattern, "*")
+		if ok && strings.HasPrefix(group, prefix) && len(prefix) > bestLen {
+			best, bestLen = p, len(prefix)
+		}
+	}
+	if best != "" {
+		return best
+	}
+	if c.GroupPolicy != "" {
+		return c.GroupPolicy
+	}
+	if c.BlockOnError {
+		return GroupPolicyBlock
+	}
+	return GroupPolicySkip
+}
@@ -0,0 +1,33 @@
+package outbox
+
+import "testing"
+
+func TestGroupPolicyResolution(t *testing.T) {
+	overrides, err := ParseGroupPolicies("orders=park, billing-*=skip,billing-eu-*=block")
+	if err != nil {
+		t.Fatal(err)
+	}
+	cfg := Config{BlockOnError: true, GroupPolicies: overrides}
+	for group, want := range map[string]GroupPolicy{
+		"orders":        GroupPolicyPark,
+		"orders-2":      GroupPolicyBlock, // exact names do not match as prefixes
+		"billing-us":    GroupPolicySkip,
+		"billing-eu-de": GroupPolicyBlock, // the longest prefix wins
+	} {
+		if got := cfg.policyFor(group); got != want {
+			t.Errorf("policyFor(%q) = %s, want %s", group, got, want)
+		}
+	}
+	if got := (Config{}).policyFor("g"); got != GroupPolicySkip {
+		t.Errorf("BlockOnError=false defaults to skip, got %s", got)
+	}
+	if got := (Config{GroupPolicy: GroupPolicyPark, BlockOnError: true}).policyFor("g"); got != GroupPolicyPark {
+		t.Errorf("GroupPolicy overrides BlockOnError, got %s", got)
+	}
+
+	for _, bad := range []string{"orders", "=skip", "orders=drop"} {
+		if _, err := ParseGroupPolicies(bad); err == nil {
+			t.Errorf("ParseGroupPolicies(%q) must fail", bad)
+		}
+	}
+}
@@ -1,6 +1,9 @@
 package outbox
 
-import "sync"
+import (
+	"sync"
+	"time"
+)
 
 // GroupStatus is a message group's processing state in the operational state
 // machine. Mirrors Rust message_group_processor's ProcessorState.
@@ -10,6 +13,9 @@ const (
 	GroupRunning GroupStatus = "RUNNING"
 	GroupPaused  GroupStatus = "PAUSED"
 	GroupBlocked GroupStatus = "BLOCKED"
+	// GroupParked is a timed block (GroupPolicyPark): the group resumes on
+	// its own at ParkedUntil.
+	GroupParked GroupStatus = "PARKED"
 )
 
 // GroupInfo is a snapshot of one group's state (for the status/admin surface).
@@ -18,12 +24,25 @@ type GroupInfo struct {
 	Status        GroupStatus `json:"status"`
 	BlockedItemID string      `json:"blockedItemId,omitempty"`
 	Error         string      `json:"error,omitempty"`
+	// Since is when the group entered Status.
+	Since       time.Time  `json:"since"`
+	ParkedUntil *time.Time `json:"parkedUntil,omitempty"`
 }
 
 type groupState struct {
 	status        GroupStatus
 	blockedItemID string
 	err           string
+	since, until  time.Time
+}
+
+func (s *groupState) info(group string) GroupInfo {
+	g := GroupInfo{Group: group, Status: s.status, BlockedItemID: s.blockedItemID, Error: s.err, Since: s.since}
+	if s.status == GroupParked {
+		until := s.until
+		g.ParkedUntil = &until
+	}
+	return g
 }
 
 // GroupStateManager holds per-message-group processing state — the operational
@@ -55,7 +74,30 @@ func (m *GroupStateManager) IsActive(group string) bool {
 func (m *GroupStateManager) Block(group, itemID, errMsg string) {
 	m.mu.Lock()
 	defer m.mu.Unlock()
-	m.groups[group] = &groupState{status: GroupBlocked, blockedItemID: itemID, err: errMsg}
+	m.groups[group] = &groupState{status: GroupBlocked, blockedItemID: itemID, err: errMsg, since: time.Now()}
+}
+
+// Park marks a group Parked on a poison item until the given time, when
+// Unpark releases it.
+func (m *GroupStateManager) Park(group, itemID, errMsg string, until time.Time) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.groups[group] = &groupState{status: GroupParked, blockedItemID: itemID, err: errMsg, since: time.Now(), until: until}
+}
+
+// Unpark transitions every group parked until now or earlier back to
+// Running and returns them, so the caller can re-queue their poison items.
+func (m *GroupStateManager) Unpark(now time.Time) []GroupInfo {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	var out []GroupInfo
+	for g, s := range m.groups {
+		if s.status == GroupParked && !s.until.After(now) {
+			out = append(out, s.info(g))
+			delete(m.groups, g)
+		}
+	}
+	return out
 }
 
 // Pause transitions Running → Paused (no-op when Blocked or already Paused).
@@ -64,11 +106,12 @@ func (m *GroupStateManager) Pause(group string) {
 	defer m.mu.Unlock()
 	g := m.groups[group]
 	if g == nil {
-		m.groups[group] = &groupState{status: GroupPaused}
+		m.groups[group] = &groupState{status: GroupPaused, since: time.Now()}
 		return
 	}
 	if g.status == GroupRunning {
 		g.status = GroupPaused
+		g.since = time.Now()
 	}
 }
 
@@ -81,14 +124,15 @@ func (m *GroupStateManager) Resume(group string) {
 	}
 }
 
-// ClearBlock transitions Blocked → Running and returns the blocked item id (so
-// the caller can decide whether to re-queue it). Returns ok=false if the group
-// wasn't Blocked. Backs both Unblock (re-queue the poison) and Skip (abandon it).
+// ClearBlock transitions Blocked or Parked → Running and returns the blocked
+// item id (so the caller can decide whether to re-queue it). Returns ok=false
+// if the group wasn't Blocked or Parked. Backs both Unblock (re-queue the
+// poison) and Skip (abandon it).
 func (m *GroupStateManager) ClearBlock(group string) (itemID string, ok bool) {
 	m.mu.Lock()
 	defer m.mu.Unlock()
 	g := m.groups[group]
-	if g == nil || g.status != GroupBlocked {
+	if g == nil || (g.status != GroupBlocked && g.status != GroupParked) {
 		return "", false
 	}
 	itemID = g.blockedItemID
@@ -102,7 +146,7 @@ func (m *GroupStateManager) Snapshot() []GroupInfo {
 	defer m.mu.RUnlock()
 	out := make([]GroupInfo, 0, len(m.groups))
 	for g, s := range m.groups {
-		out = append(out, GroupInfo{Group: g, Status: s.status, BlockedItemID: s.blockedItemID, Error: s.err})
+		out = append(out, s.info(g))
 	}
 	return out
 }
@@ -114,7 +158,7 @@ func (m *GroupStateManager) Blocked() []GroupInfo {
 	var out []GroupInfo
 	for g, s := range m.groups {
 		if s.status == GroupBlocked {
-			out = append(out, GroupInfo{Group: g, Status: s.status, BlockedItemID: s.blockedItemID, Error: s.err})
+			out = append(out, s.info(g))
 		}
 	}
 	return out
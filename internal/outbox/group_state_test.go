@@ -1,6 +1,9 @@
 package outbox
 
-import "testing"
+import (
+	"testing"
+	"time"
+)
 
 func TestGroupStateManager(t *testing.T) {
 	m := NewGroupStateManager()
@@ -46,3 +49,31 @@ func TestGroupStateManager(t *testing.T) {
 		t.Fatal("ClearBlock on a non-blocked group must be ok=false")
 	}
 }
+
+func TestGroupStateManagerPark(t *testing.T) {
+	m := NewGroupStateManager()
+	now := time.Now()
+	m.Park("g1", "itm-poison", "boom", now.Add(time.Minute))
+	if m.IsActive("g1") {
+		t.Fatal("parked group must be inactive")
+	}
+	if s := m.Snapshot(); len(s) != 1 || s[0].Status != GroupParked || s[0].ParkedUntil == nil {
+		t.Fatalf("Snapshot() = %+v, want g1 PARKED with parkedUntil", s)
+	}
+	if u := m.Unpark(now); len(u) != 0 {
+		t.Fatalf("Unpark before the deadline = %+v, want none", u)
+	}
+	u := m.Unpark(now.Add(time.Minute))
+	if len(u) != 1 || u[0].BlockedItemID != "itm-poison" {
+		t.Fatalf("Unpark at the deadline = %+v, want g1/itm-poison", u)
+	}
+	if !m.IsActive("g1") {
+		t.Fatal("unparked group must be active")
+	}
+
+	// An operator may unblock or skip a parked group early.
+	m.Park("g2", "itm2", "boom", now.Add(time.Hour))
+	if id, ok := m.ClearBlock("g2"); !ok || id != "itm2" {
+		t.Fatalf("ClearBlock on a parked group = (%q,%v), want (itm2,true)", id, ok)
+	}
+}
@@ -9,6 +9,7 @@ import (
 	"net/http"
 	"time"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
 )
 
@@ -78,6 +79,7 @@ type HTTPDispatcher struct {
 	authToken   string
 	tokenSource TokenSource
 	client      *http.Client
+	blobs       *blobstore.Offloader // nil sends every payload inline
 }
 
 // NewHTTPDispatcher wires a dispatcher.
@@ -122,6 +124,9 @@ func (d *HTTPDispatcher) onUnauthorized() {
 type DispatchOutcome struct {
 	Status  common.OutboxStatus
 	Message string
+	// PlatformID is the id the platform stored the item under (the result
+	// `id`), when it reported one.
+	PlatformID string
 }
 
 // SendBatch POSTs one or more items of the SAME ItemType in a single request
@@ -143,7 +148,12 @@ func (d *HTTPDispatcher) SendBatch(ctx context.Context, items []Item) map[string
 	endpoint := d.platformURL + items[0].ItemType.APIPath()
 	payloads := make([]json.RawMessage, len(items))
 	for i, it := range items {
-		payloads[i] = it.Payload
+		p, err := d.offload(ctx, it)
+		if err != nil {
+			// The store is unreachable or refused the write: retryable.
+			return failAll(items, common.OutboxGatewayError, "blob store: "+err.Error())
+		}
+		payloads[i] = p
 	}
 	body, err := json.Marshal(map[string]any{"items": payloads})
 	if err != nil {
@@ -194,7 +204,7 @@ func (d *HTTPDispatcher) SendBatch(ctx context.Context, items []Item) map[string
 				out[it.ID] = DispatchOutcome{Status: common.OutboxInternalError, Message: "unknown item status: " + r.Status}
 				continue
 			}
-			out[it.ID] = DispatchOutcome{Status: st, Message: r.Error}
+			out[it.ID] = DispatchOutcome{Status: st, Message: r.Error, PlatformID: r.ID}
 		}
 		return out
 	case resp.StatusCode == http.StatusUnauthorized:
@@ -219,6 +229,30 @@ func (d *HTTPDispatcher) SendBatch(ctx context.Context, items []Item) map[string
 	}
 }
 
+// offload moves an event item's data to the blob store when it is over the
+// threshold, sending a {"$blobRef": …} reference the platform accepts in
+// its place; its key is derived from the data, so a retry rewrites the same
+// object. The platform and this outbox must share the store. Other item
+// types, and every item when no store is configured, go as they are.
+func (d *HTTPDispatcher) offload(ctx context.Context, it Item) (json.RawMessage, error) {
+	if it.ItemType != common.OutboxItemEvent || !d.blobs.Over(len(it.Payload)) {
+		return it.Payload, nil
+	}
+	var fields map[string]json.RawMessage
+	if json.Unmarshal(it.Payload, &fields) != nil || !d.blobs.Over(len(fields["data"])) {
+		// Not an object (the platform answers BAD_REQUEST), or its bulk is
+		// not the data.
+		return it.Payload, nil
+	}
+	data := fields["data"]
+	ref, err := d.blobs.Offload(ctx, blobstore.ContentKey(blobstore.OutboxPrefix, data), data)
+	if err != nil {
+		return nil, err
+	}
+	fields["data"] = ref
+	return json.Marshal(fields)
+}
+
 // failAll assigns the same outcome to every item (transport/HTTP-level failure).
 func failAll(items []Item, st common.OutboxStatus, msg string) map[string]DispatchOutcome {
 	m := make(map[string]DispatchOutcome, len(items))
@@ -5,9 +5,11 @@ import (
 	"encoding/json"
 	"net/http"
 	"net/http/httptest"
+	"strings"
 	"testing"
 	"time"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
 )
 
@@ -243,3 +245,53 @@ func TestParseItemStatus(t *testing.T) {
 		t.Error("parseItemStatus(WAT) should be ok=false")
 	}
 }
+
+// An event whose data is over the blob threshold is sent as a reference to
+// the object the dispatcher stored; smaller events go inline.
+func TestSendBatch_OffloadsLargeEventData(t *testing.T) {
+	var items []json.RawMessage
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		var body struct {
+			Items []json.RawMessage `json:"items"`
+		}
+		_ = json.NewDecoder(r.Body).Decode(&body)
+		items = body.Items
+		_ = json.NewEncoder(w).Encode(map[string]any{"results": []map[string]any{
+			{"id": "evt_1", "status": "SUCCESS"}, {"id": "evt_2", "status": "SUCCESS"},
+		}})
+	}))
+	defer srv.Close()
+	ctx := context.Background()
+	store, err := blobstore.Open(ctx, "file://"+t.TempDir())
+	if err != nil {
+		t.Fatal(err)
+	}
+	blobs := blobstore.NewOffloader(store, 32, 0)
+
+	d := NewHTTPDispatcher(srv.URL, "", 5*time.Second)
+	d.blobs = blobs
+	big := `{"lines":["a","b","c","d","e","f","g","h"]}`
+	out := d.SendBatch(ctx, []Item{
+		{ID: "a", ItemType: common.OutboxItemEvent, Payload: json.RawMessage(`{"type":"t","data":` + big + `}`)},
+		{ID: "b", ItemType: common.OutboxItemEvent, Payload: json.RawMessage(`{"type":"t","data":{"k":"v"}}`)},
+	})
+	if out["a"].Status != common.OutboxSuccess || out["b"].Status != common.OutboxSuccess {
+		t.Fatalf("outcomes = %+v", out)
+	}
+	var first, second struct {
+		Data json.RawMessage `json:"data"`
+	}
+	_ = json.Unmarshal(items[0], &first)
+	_ = json.Unmarshal(items[1], &second)
+	ref, ok := blobstore.ParseRef(first.Data)
+	if !ok || !strings.HasPrefix(ref.Key, blobstore.OutboxPrefix) {
+		t.Fatalf("large data not sent as an outbox reference: %s", first.Data)
+	}
+	data, err := blobs.Fetch(ctx, ref)
+	if err != nil || string(data) != big {
+		t.Fatalf("stored data = %s, %v", data, err)
+	}
+	if string(second.Data) != `{"k":"v"}` {
+		t.Errorf("small data = %s, want inline", second.Data)
+	}
+}
@@ -16,19 +16,28 @@
 // processor claims PENDING (0), DELETEs on success, and on failure bumps
 // retry_count + records error_message (retryable -> back to PENDING).
 //
-// Unlike the SQL backends there is no FOR UPDATE SKIP LOCKED; the claim is a
-// find-then-update (mirroring Rust). Run a single active instance (the
-// fc-server outbox subsystem is leader-gated) to avoid double-claims.
+// Unlike the SQL backends there is no FOR UPDATE SKIP LOCKED. Each document
+// is claimed by its own findOneAndUpdate, which is atomic, so two processors
+// racing through a failover never claim the same document. A claim is a
+// lease: the claimer records itself in claimed_by and the lease end in
+// claim_expires_at (both Go additions the SDK ignores). Failure marks only
+// touch documents the processor still holds, and RecoverStuck returns
+// documents whose lease has run out.
 package mongo
 
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
+	"log/slog"
 	"time"
 
+	"github.com/google/uuid"
 	"go.mongodb.org/mongo-driver/bson"
+	"go.mongodb.org/mongo-driver/event"
 	"go.mongodb.org/mongo-driver/mongo"
+	"go.mongodb.org/mongo-driver/mongo/description"
 	"go.mongodb.org/mongo-driver/mongo/options"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
@@ -37,10 +46,22 @@ import (
 
 const collectionName = "outbox_messages"
 
+// claimIndex serves the claim query: a partial index over PENDING documents
+// only, in claim order. Claimed and failed documents drop out of it, so it
+// stays as small as the backlog however large the collection grows.
+const claimIndex = "idx_claim"
+
+// DefaultClaimLease is how long a claim holds a document before RecoverStuck
+// may hand it to another processor. Matches the processor's default
+// RecoveryThreshold.
+const DefaultClaimLease = 5 * time.Minute
+
 // Repository is the MongoDB outbox repository.
 type Repository struct {
 	client *mongo.Client
 	coll   *mongo.Collection
+	owner  string // claimed_by written on this process's claims
+	lease  time.Duration
 }
 
 // New wires a repository against an existing client + database name.
@@ -48,17 +69,81 @@ func New(client *mongo.Client, dbName string) *Repository {
 	return &Repository{
 		client: client,
 		coll:   client.Database(dbName).Collection(collectionName),
+		owner:  uuid.NewString(),
+		lease:  DefaultClaimLease,
 	}
 }
 
-// Connect dials the supplied URI and returns a repository. The caller owns
-// the returned client's lifetime via Close.
+// SetClaimLease changes how long a claim holds a document. Call before the
+// processor starts; zero or negative keeps the current lease.
+func (r *Repository) SetClaimLease(d time.Duration) {
+	if d > 0 {
+		r.lease = d
+	}
+}
+
+// Owner is the claimed_by value this repository writes.
+func (r *Repository) Owner() string { return r.owner }
+
+// Connect dials the supplied URI with the default MongoConfig and returns a
+// repository. The caller owns the returned client's lifetime via Close.
 func Connect(ctx context.Context, uri, dbName string) (*Repository, error) {
-	client, err := mongo.Connect(ctx, options.Client().ApplyURI(uri))
+	return ConnectWithConfig(ctx, common.NewMongoConfig(uri, dbName))
+}
+
+// ConnectWithConfig dials with the pool, timeout, retry and compression
+// settings from cfg.
+func ConnectWithConfig(ctx context.Context, cfg common.MongoConfig) (*Repository, error) {
+	client, err := mongo.Connect(ctx, ClientOptions(cfg))
 	if err != nil {
 		return nil, fmt.Errorf("mongo connect: %w", err)
 	}
-	return New(client, dbName), nil
+	return New(client, cfg.Database), nil
+}
+
+// ClientOptions maps cfg onto driver options, plus a server monitor that
+// logs when a server becomes unreachable and when it comes back — the
+// driver reconnects on its own, silently, so without it an outage only
+// shows up as failing operations.
+func ClientOptions(cfg common.MongoConfig) *options.ClientOptions {
+	opts := options.Client().ApplyURI(cfg.URI).
+		SetRetryWrites(cfg.RetryWrites).
+		SetRetryReads(cfg.RetryReads).
+		SetServerMonitor(reconnectMonitor())
+	if cfg.MaxPoolSize > 0 {
+		opts.SetMaxPoolSize(cfg.MaxPoolSize)
+	}
+	if cfg.MinPoolSize > 0 {
+		opts.SetMinPoolSize(cfg.MinPoolSize)
+	}
+	if cfg.ConnectTimeoutMS > 0 {
+		opts.SetConnectTimeout(time.Duration(cfg.ConnectTimeoutMS) * time.Millisecond)
+	}
+	if cfg.ServerSelectionTimeoutMS > 0 {
+		opts.SetServerSelectionTimeout(time.Duration(cfg.ServerSelectionTimeoutMS) * time.Millisecond)
+	}
+	if len(cfg.Compressors) > 0 {
+		opts.SetCompressors(cfg.Compressors)
+	}
+	return opts
+}
+
+// reconnectMonitor logs server reachability transitions. A description of
+// kind Unknown means the monitor lost the server; one carrying LastError is
+// a failed heartbeat rather than the initial not-yet-checked state.
+func reconnectMonitor() *event.ServerMonitor {
+	return &event.ServerMonitor{
+		ServerDescriptionChanged: func(e *event.ServerDescriptionChangedEvent) {
+			was, now := e.PreviousDescription.Kind, e.NewDescription.Kind
+			switch {
+			case was != description.Unknown && now == description.Unknown:
+				slog.Warn("mongo server unreachable; driver will reconnect",
+					"addr", e.Address.String(), "err", e.NewDescription.LastError)
+			case was == description.Unknown && now != description.Unknown && e.PreviousDescription.LastError != nil:
+				slog.Info("mongo server reconnected", "addr", e.Address.String(), "kind", now.String())
+			}
+		},
+	}
 }
 
 // Close disconnects the underlying client.
@@ -112,6 +197,12 @@ func (r *Repository) InitSchema(ctx context.Context) error {
 			Keys:    bson.D{{Key: "client_id", Value: 1}, {Key: "status", Value: 1}, {Key: "created_at", Value: 1}},
 			Options: options.Index().SetName("idx_client_pending"),
 		},
+		{
+			// The claim neither filters nor sorts on type, so type is not a key.
+			Keys:    bson.D{{Key: "status", Value: 1}, {Key: "message_group", Value: 1}, {Key: "created_at", Value: 1}},
+			Options: options.Index().SetName(claimIndex).
+				SetPartialFilterExpression(bson.M{"status": int(common.OutboxPending)}),
+		},
 	})
 	if err != nil {
 		return fmt.Errorf("mongo create indexes: %w", err)
@@ -119,46 +210,55 @@ func (r *Repository) InitSchema(ctx context.Context) error {
 	return nil
 }
 
-// ClaimPending finds up to batchSize PENDING docs (ordered by message_group +
-// created_at, like the SQL backends) and flips them to IN_PROGRESS. Mongo has
-// no atomic batch claim, so this is a find-then-update (mirrors Rust); the
-// fc-server outbox subsystem is leader-gated to keep it single-active.
+// ClaimPending claims up to batchSize PENDING docs, oldest first within
+// message_group like the SQL backends, flipping each to IN_PROGRESS under
+// this repository's lease. Each doc is claimed by one atomic
+// findOneAndUpdate, hinted to the partial claim index (InitSchema creates
+// it), so concurrent claimers split the backlog instead of sharing it. If a
+// claim fails after some succeeded, the docs already claimed are returned
+// rather than stranded until their lease expires.
 func (r *Repository) ClaimPending(ctx context.Context, batchSize int) ([]outbox.Item, error) {
-	cur, err := r.coll.Find(ctx,
-		bson.M{"status": int(common.OutboxPending)},
-		options.Find().
-			SetSort(bson.D{{Key: "message_group", Value: 1}, {Key: "created_at", Value: 1}}).
-			SetLimit(int64(batchSize)))
-	if err != nil {
-		return nil, fmt.Errorf("mongo find pending: %w", err)
-	}
-	defer cur.Close(ctx)
+	now := time.Now().UTC()
+	update := bson.M{"$set": bson.M{
+		"status":           int(common.OutboxInProgress),
+		"updated_at":       now.Format(time.RFC3339),
+		"claimed_by":       r.owner,
+		"claim_expires_at": now.Add(r.lease).Format(time.RFC3339),
+	}}
+	opts := options.FindOneAndUpdate().
+		SetSort(bson.D{{Key: "status", Value: 1}, {Key: "message_group", Value: 1}, {Key: "created_at", Value: 1}}).
+		SetHint(claimIndex).
+		SetReturnDocument(options.After)
 
 	var items []outbox.Item
-	var ids []string
-	for cur.Next(ctx) {
+	for len(items) < batchSize {
 		var d doc
-		if err := cur.Decode(&d); err != nil {
-			return nil, fmt.Errorf("mongo decode: %w", err)
+		err := r.coll.FindOneAndUpdate(ctx, bson.M{"status": int(common.OutboxPending)}, update, opts).Decode(&d)
+		if errors.Is(err, mongo.ErrNoDocuments) {
+			break
+		}
+		if err != nil {
+			if len(items) > 0 {
+				slog.Warn("mongo outbox claim cut short", "claimed", len(items), "err", err)
+				break
+			}
+			return nil, fmt.Errorf("mongo claim pending: %w", err)
 		}
 		items = append(items, d.toItem())
-		ids = append(ids, d.ID)
-	}
-	if err := cur.Err(); err != nil {
-		return nil, err
-	}
-	if len(ids) == 0 {
-		return nil, nil
-	}
-
-	if _, err := r.coll.UpdateMany(ctx,
-		bson.M{"id": bson.M{"$in": ids}},
-		bson.M{"$set": bson.M{"status": int(common.OutboxInProgress), "updated_at": nowISO()}}); err != nil {
-		return nil, fmt.Errorf("mongo mark in_progress: %w", err)
 	}
 	return items, nil
 }
 
+// held matches docs this repository may still resolve: claimed by it, or
+// by a claimer that records no owner (Rust, or Go before leases). A doc a
+// failover handed to another processor is left to that processor.
+func (r *Repository) held(ids []string) bson.M {
+	return bson.M{"id": bson.M{"$in": ids}, "claimed_by": bson.M{"$in": bson.A{r.owner, nil}}}
+}
+
+// releaseLease clears the claim fields.
+var releaseLease = bson.M{"claimed_by": "", "claim_expires_at": ""}
+
 // MarkSuccess deletes successfully dispatched docs (SUCCESS is terminal; the
 // platform now owns the message, so the customer collection stays bounded).
 func (r *Repository) MarkSuccess(ctx context.Context, ids []string) error {
@@ -180,18 +280,15 @@ func (r *Repository) MarkFailed(ctx context.Context, ids []string, status common
 	if requeue {
 		newStatus = int(common.OutboxPending)
 	}
-	_, err := r.coll.UpdateMany(ctx,
-		bson.M{"id": bson.M{"$in": ids}},
+	_, err := r.coll.UpdateMany(ctx, r.held(ids),
 		bson.M{
-			"$set": bson.M{"status": newStatus, "error_message": msg, "updated_at": nowISO()},
-			"$inc": bson.M{"retry_count": 1},
+			"$set":   bson.M{"status": newStatus, "error_message": msg, "updated_at": nowISO()},
+			"$inc":   bson.M{"retry_count": 1},
+			"$unset": releaseLease,
 		})
 	return err
 }
 
-// RecoverStuck resets IN_PROGRESS docs older than olderThan back to PENDING.
-// updated_at is an RFC3339 string, so the cutoff is compared lexically (RFC3339
-// is lexicographically ordered for a fixed offset — the SDK writes UTC "Z").
 // Release returns claimed (IN_PROGRESS) docs to PENDING without a failure
 // penalty. Used by block-on-error to re-run a group's undispatched items in
 // order behind a failed one.
@@ -199,9 +296,10 @@ func (r *Repository) Release(ctx context.Context, ids []string) error {
 	if len(ids) == 0 {
 		return nil
 	}
-	_, err := r.coll.UpdateMany(ctx,
-		bson.M{"id": bson.M{"$in": ids}, "status": int(common.OutboxInProgress)},
-		bson.M{"$set": bson.M{"status": int(common.OutboxPending), "updated_at": nowISO()}})
+	filter := r.held(ids)
+	filter["status"] = int(common.OutboxInProgress)
+	_, err := r.coll.UpdateMany(ctx, filter,
+		bson.M{"$set": bson.M{"status": int(common.OutboxPending), "updated_at": nowISO()}, "$unset": releaseLease})
 	return err
 }
 
@@ -213,21 +311,66 @@ func (r *Repository) Requeue(ctx context.Context, ids []string) error {
 	}
 	_, err := r.coll.UpdateMany(ctx,
 		bson.M{"id": bson.M{"$in": ids}},
-		bson.M{"$set": bson.M{"status": int(common.OutboxPending), "retry_count": 0, "error_message": "", "updated_at": nowISO()}})
+		bson.M{
+			"$set":   bson.M{"status": int(common.OutboxPending), "retry_count": 0, "error_message": "", "updated_at": nowISO()},
+			"$unset": releaseLease,
+		})
 	return err
 }
 
+// RecoverStuck resets IN_PROGRESS docs back to PENDING once their claim
+// lease has expired, or, for docs claimed without a lease, once updated_at
+// is older than olderThan. Both are RFC3339 strings compared lexically
+// (lexicographic order is chronological for a fixed offset — the SDK and
+// this package write UTC "Z").
 func (r *Repository) RecoverStuck(ctx context.Context, olderThan time.Duration) (int, error) {
-	cutoff := time.Now().UTC().Add(-olderThan).Format(time.RFC3339)
+	now := time.Now().UTC()
+	cutoff := now.Add(-olderThan).Format(time.RFC3339)
 	res, err := r.coll.UpdateMany(ctx,
-		bson.M{"status": int(common.OutboxInProgress), "updated_at": bson.M{"$lt": cutoff}},
-		bson.M{"$set": bson.M{"status": int(common.OutboxPending), "updated_at": nowISO()}})
+		bson.M{"status": int(common.OutboxInProgress), "$or": bson.A{
+			bson.M{"claim_expires_at": bson.M{"$lt": now.Format(time.RFC3339)}},
+			bson.M{"claim_expires_at": bson.M{"$exists": false}, "updated_at": bson.M{"$lt": cutoff}},
+		}},
+		bson.M{"$set": bson.M{"status": int(common.OutboxPending), "updated_at": nowISO()}, "$unset": releaseLease})
 	if err != nil {
 		return 0, err
 	}
 	return int(res.ModifiedCount), nil
 }
 
+// OldestPending returns the created_at of the oldest PENDING doc. created_at
+// is a fixed-width UTC RFC3339 string, so sorting it lexically is
+// chronological.
+func (r *Repository) OldestPending(ctx context.Context) (time.Time, bool, error) {
+	var d doc
+	err := r.coll.FindOne(ctx,
+		bson.M{"status": int(common.OutboxPending)},
+		options.FindOne().SetSort(bson.D{{Key: "created_at", Value: 1}})).Decode(&d)
+	if errors.Is(err, mongo.ErrNoDocuments) {
+		return time.Time{}, false, nil
+	}
+	if err != nil {
+		return time.Time{}, false, fmt.Errorf("mongo find oldest pending: %w", err)
+	}
+	at, err := time.Parse(time.RFC3339, d.CreatedAt)
+	if err != nil {
+		return time.Time{}, false, fmt.Errorf("mongo parse created_at %q: %w", d.CreatedAt, err)
+	}
+	return at, true, nil
+}
+
+// CountPending counts PENDING docs.
+func (r *Repository) CountPending(ctx context.Context) (int64, error) {
+	n, err := r.coll.CountDocuments(ctx, bson.M{"status": int(common.OutboxPending)})
+	if err != nil {
+		return 0, fmt.Errorf("mongo count pending: %w", err)
+	}
+	return n, nil
+}
+
+// Ping checks the server is reachable.
+func (r *Repository) Ping(ctx context.Context) error { return r.client.Ping(ctx, nil) }
+
 // Healthy pings the server.
 func (r *Repository) Healthy(ctx context.Context) bool {
 	c, cancel := context.WithTimeout(ctx, 2*time.Second)
@@ -239,4 +382,8 @@ func (r *Repository) Healthy(ctx context.Context) bool {
 // updated_at, kept consistent so cross-runtime reads parse cleanly.
 func nowISO() string { return time.Now().UTC().Format(time.RFC3339) }
 
-var _ outbox.Repository = (*Repository)(nil)
+var (
+	_ outbox.Repository     = (*Repository)(nil)
+	_ outbox.LagReporter    = (*Repository)(nil)
+	_ outbox.PendingCounter = (*Repository)(nil)
+)
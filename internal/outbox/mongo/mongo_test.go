@@ -0,0 +1,48 @@
+package mongo
+
+import (
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+func TestClientOptions(t *testing.T) {
+	cfg := common.NewMongoConfig("mongodb://localhost:27017", "fc")
+	cfg.MinPoolSize = 5
+	cfg.RetryReads = false
+	cfg.Compressors = []string{"zstd", "snappy"}
+
+	opts := ClientOptions(cfg)
+	if opts.MaxPoolSize == nil || *opts.MaxPoolSize != 50 {
+		t.Errorf("MaxPoolSize = %v, want 50", opts.MaxPoolSize)
+	}
+	if opts.MinPoolSize == nil || *opts.MinPoolSize != 5 {
+		t.Errorf("MinPoolSize = %v, want 5", opts.MinPoolSize)
+	}
+	if opts.ServerSelectionTimeout == nil || *opts.ServerSelectionTimeout != 10*time.Second {
+		t.Errorf("ServerSelectionTimeout = %v, want 10s", opts.ServerSelectionTimeout)
+	}
+	if opts.RetryWrites == nil || !*opts.RetryWrites || opts.RetryReads == nil || *opts.RetryReads {
+		t.Errorf("RetryWrites/RetryReads = %v/%v, want true/false", opts.RetryWrites, opts.RetryReads)
+	}
+	if len(opts.Compressors) != 2 || opts.Compressors[0] != "zstd" {
+		t.Errorf("Compressors = %v", opts.Compressors)
+	}
+	if opts.ServerMonitor == nil {
+		t.Error("reconnect monitor not installed")
+	}
+}
+
+func TestSetClaimLeaseIgnoresNonPositive(t *testing.T) {
+	r := &Repository{lease: DefaultClaimLease}
+	r.SetClaimLease(0)
+	r.SetClaimLease(-time.Second)
+	if r.lease != DefaultClaimLease {
+		t.Errorf("lease = %v, want %v", r.lease, DefaultClaimLease)
+	}
+	r.SetClaimLease(30 * time.Second)
+	if r.lease != 30*time.Second {
+		t.Errorf("lease = %v, want 30s", r.lease)
+	}
+}
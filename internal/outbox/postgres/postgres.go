@@ -24,12 +24,22 @@
 // status 0 = PENDING, 9 = IN_PROGRESS (claimed). The processor claims
 // PENDING rows (status=0), DELETEs them on success, and on failure bumps
 // retry_count + records error_message (retryable -> back to PENDING).
+//
+// NewWithTable points the repository at a legacy table instead: other
+// names, missing columns, custom status values and a row filter (see
+// outbox.TableConfig). InitSchema leaves such a table alone.
+//
+// With a published-id column (columns.publishedId, or published_id added
+// to the SDK table by FC_OUTBOX_TWO_PHASE_PUBLISH) the repository is an
+// outbox.PublishTracker: delivered rows record the platform id before
+// they are completed, and stuck recovery leaves them to CompletePublished.
 package postgres
 
 import (
 	"context"
 	"encoding/json"
 	"fmt"
+	"strings"
 	"time"
 
 	"github.com/jackc/pgx/v5/pgxpool"
@@ -40,14 +50,165 @@ import (
 
 // Repository is the Postgres outbox repository.
 type Repository struct {
-	pool *pgxpool.Pool
+	pool  *pgxpool.Pool
+	table outbox.TableConfig
+	q     queries
+}
+
+// New wires a repository against an existing pool and the SDK table.
+func New(pool *pgxpool.Pool) *Repository { return NewWithTable(pool, outbox.TableConfig{}) }
+
+// NewWithTable wires a repository against the table cfg describes. cfg
+// must have passed Validate (ParseTableConfig does this).
+func NewWithTable(pool *pgxpool.Pool, cfg outbox.TableConfig) *Repository {
+	return &Repository{pool: pool, table: cfg, q: buildQueries(cfg)}
 }
 
-// New wires a repository against an existing pool.
-func New(pool *pgxpool.Pool) *Repository { return &Repository{pool: pool} }
+// queries are the repository's statements, rendered once from the table
+// config. Status values are literals; ids and messages are parameters.
+type queries struct {
+	claim, markSuccess, release, requeue, recover, oldestPending string
+	countPending                                                 string
+	// markPublished and completePublished are empty without a
+	// published-id column.
+	markPublished, completePublished string
+	// markFailed has a %s for the status literal, chosen per call; $2,
+	// the message, only when the table has an error column.
+	markFailed    string
+	markFailedMsg bool
+	// itemType stands in for an absent type column.
+	itemType common.OutboxItemType
+}
+
+func buildQueries(cfg outbox.TableConfig) queries {
+	n := cfg.Names()
+	t := cfg.TableName()
+	pending, inProgress := cfg.Status(common.OutboxPending), cfg.Status(common.OutboxInProgress)
+	idType := "text"
+	if cfg.IDType != "" {
+		idType = cfg.IDType
+	}
+	ids := fmt.Sprintf("%s = ANY($1::%s[])", n.ID, idType)
+	filter := ""
+	if cfg.Filter != "" {
+		filter = " AND (" + cfg.Filter + ")"
+	}
+	// or returns col, or the fallback expression when the table lacks it.
+	or := func(col, fallback string) string {
+		if col == "" {
+			return fallback
+		}
+		return "m." + col
+	}
+	// touch is the "updated_at = NOW()" assignment, when there is one.
+	touch := ""
+	if n.UpdatedAt != "" {
+		touch = ", " + n.UpdatedAt + " = NOW()"
+	}
+
+	var q queries
+	if n.Type == "" {
+		q.itemType = cfg.ItemType
+	}
+	q.claim = fmt.Sprintf(`
+WITH claimed AS (
+  SELECT %[2]s FROM %[1]s
+   WHERE %[3]s = %[4]s%[5]s
+   ORDER BY %[6]s
+   LIMIT $1
+   FOR UPDATE SKIP LOCKED
+)
+UPDATE %[1]s m
+   SET %[3]s = %[7]s%[8]s
+  FROM claimed
+ WHERE m.%[2]s = claimed.%[2]s
+ RETURNING m.%[2]s::text, %[9]s, %[10]s, m.%[11]s, %[12]s,
+           %[13]s, m.%[14]s, %[15]s, %[16]s
+`, t, n.ID, n.Status, pending, filter, orderBy(n), inProgress, touch,
+		or(n.Type, "''"), or(n.MessageGroup, "NULL::text"), n.Payload, or(n.RetryCount, "0"),
+		or(n.ErrorMessage, "NULL::text"), n.CreatedAt, or(n.UpdatedAt, "m."+n.CreatedAt),
+		or(n.PublishedID, "NULL::text"))
+
+	if cfg.DeletesOnSuccess() {
+		q.markSuccess = fmt.Sprintf(`DELETE FROM %s WHERE %s`, t, ids)
+	} else {
+		q.markSuccess = fmt.Sprintf(`UPDATE %s SET %s = %s%s WHERE %s`,
+			t, n.Status, cfg.Status(common.OutboxSuccess), touch, ids)
+	}
+
+	failed := []string{n.Status + " = %s"}
+	if n.ErrorMessage != "" {
+		failed = append(failed, n.ErrorMessage+" = $2")
+	}
+	if n.RetryCount != "" {
+		failed = append(failed, n.RetryCount+" = "+n.RetryCount+" + 1")
+	}
+	if n.UpdatedAt != "" {
+		failed = append(failed, n.UpdatedAt+" = NOW()")
+	}
+	q.markFailed = fmt.Sprintf(`UPDATE %s SET %s WHERE %s`, t, strings.Join(failed, ", "), ids)
+	q.markFailedMsg = n.ErrorMessage != ""
+
+	q.release = fmt.Sprintf(`UPDATE %s SET %s = %s%s WHERE %s AND %s = %s`,
+		t, n.Status, pending, touch, ids, n.Status, inProgress)
 
-// InitSchema creates the outbox table and indexes if missing.
+	requeue := []string{n.Status + " = " + pending}
+	if n.RetryCount != "" {
+		requeue = append(requeue, n.RetryCount+" = 0")
+	}
+	if n.ErrorMessage != "" {
+		requeue = append(requeue, n.ErrorMessage+" = NULL")
+	}
+	if n.PublishedID != "" {
+		requeue = append(requeue, n.PublishedID+" = NULL")
+	}
+	q.requeue = fmt.Sprintf(`UPDATE %s SET %s%s WHERE %s`, t, strings.Join(requeue, ", "), touch, ids)
+
+	// Without updated_at there is no claim time to age: stuck rows wait
+	// for an operator (Requeue).
+	// Published rows were delivered; they are completed, never re-sent.
+	if n.UpdatedAt != "" {
+		unpublished := ""
+		if n.PublishedID != "" {
+			unpublished = " AND " + n.PublishedID + " IS NULL"
+		}
+		q.recover = fmt.Sprintf(`UPDATE %s SET %s = %s%s WHERE %s = %s AND %s < $1%s%s`,
+			t, n.Status, pending, touch, n.Status, inProgress, n.UpdatedAt, unpublished, filter)
+	}
+	if n.PublishedID != "" {
+		q.markPublished = fmt.Sprintf(`UPDATE %[1]s m SET %[2]s = v.platform_id
+  FROM unnest($1::text[], $2::text[]) AS v(id, platform_id)
+ WHERE m.%[3]s = v.id::%[4]s`, t, n.PublishedID, n.ID, idType)
+		orphans := fmt.Sprintf(`%s = %s AND %s IS NOT NULL%s`, n.Status, inProgress, n.PublishedID, filter)
+		if cfg.DeletesOnSuccess() {
+			q.completePublished = fmt.Sprintf(`DELETE FROM %s WHERE %s`, t, orphans)
+		} else {
+			q.completePublished = fmt.Sprintf(`UPDATE %s SET %s = %s%s WHERE %s`,
+				t, n.Status, cfg.Status(common.OutboxSuccess), touch, orphans)
+		}
+	}
+	q.oldestPending = fmt.Sprintf(`SELECT min(%s) FROM %s WHERE %s = %s%s`,
+		n.CreatedAt, t, n.Status, pending, filter)
+	q.countPending = fmt.Sprintf(`SELECT count(*) FROM %s WHERE %s = %s%s`,
+		t, n.Status, pending, filter)
+	return q
+}
+
+// orderBy claims in group order, oldest first, like the SDK table.
+func orderBy(n outbox.ColumnNames) string {
+	if n.MessageGroup == "" {
+		return n.CreatedAt
+	}
+	return n.MessageGroup + ", " + n.CreatedAt
+}
+
+// InitSchema creates the outbox table and indexes if missing, plus the
+// published-id column when one is configured. A custom table belongs to
+// the application and is left as it is.
 func (r *Repository) InitSchema(ctx context.Context) error {
+	if r.table.IsCustom() {
+		return nil
+	}
 	const ddl = `
 CREATE TABLE IF NOT EXISTS outbox_messages (
     id            VARCHAR(26) PRIMARY KEY,
@@ -70,8 +231,14 @@ CREATE INDEX IF NOT EXISTS idx_outbox_messages_stuck
 CREATE INDEX IF NOT EXISTS idx_outbox_client_pending
     ON outbox_messages (client_id, status, created_at);
 `
-	_, err := r.pool.Exec(ctx, ddl)
-	return err
+	if _, err := r.pool.Exec(ctx, ddl); err != nil {
+		return err
+	}
+	if col := r.table.Names().PublishedID; col != "" {
+		_, err := r.pool.Exec(ctx, fmt.Sprintf(`ALTER TABLE outbox_messages ADD COLUMN IF NOT EXISTS %s VARCHAR(255)`, col))
+		return err
+	}
+	return nil
 }
 
 // ClaimPending claims a batch of pending items via FOR UPDATE SKIP LOCKED.
@@ -82,21 +249,7 @@ func (r *Repository) ClaimPending(ctx context.Context, batchSize int) ([]outbox.
 	}
 	defer func() { _ = tx.Rollback(ctx) }()
 
-	rows, err := tx.Query(ctx, `
-WITH claimed AS (
-  SELECT id FROM outbox_messages
-   WHERE status = 0
-   ORDER BY message_group, created_at
-   LIMIT $1
-   FOR UPDATE SKIP LOCKED
-)
-UPDATE outbox_messages m
-   SET status = 9, updated_at = NOW()
-  FROM claimed
- WHERE m.id = claimed.id
- RETURNING m.id, m.type, m.message_group, m.payload, m.status, m.retry_count,
-           m.error_message, m.created_at, m.updated_at
-`, batchSize)
+	rows, err := tx.Query(ctx, r.q.claim, batchSize)
 	if err != nil {
 		return nil, fmt.Errorf("claim: %w", err)
 	}
@@ -108,19 +261,25 @@ UPDATE outbox_messages m
 		var itemType string
 		var msgGroup *string
 		var payload []byte
-		var statusInt int
-		var errMsg *string
-		if err := rows.Scan(&item.ID, &itemType, &msgGroup, &payload, &statusInt, &item.AttemptCount,
-			&errMsg, &item.CreatedAt, &item.UpdatedAt); err != nil {
+		var errMsg, published *string
+		if err := rows.Scan(&item.ID, &itemType, &msgGroup, &payload, &item.AttemptCount,
+			&errMsg, &item.CreatedAt, &item.UpdatedAt, &published); err != nil {
 			return nil, err
 		}
 		item.ItemType = common.OutboxItemType(itemType)
+		if r.q.itemType != "" {
+			item.ItemType = r.q.itemType
+		}
 		item.MessageGroup = msgGroup
 		item.Payload = json.RawMessage(payload)
-		item.Status = common.FromOutboxCode(statusInt)
+		// The claim just set it; the stored value may be a custom code.
+		item.Status = common.OutboxInProgress
 		if errMsg != nil {
 			item.StatusMessage = *errMsg
 		}
+		if published != nil {
+			item.PublishedID = *published
+		}
 		out = append(out, item)
 	}
 	if err := rows.Err(); err != nil {
@@ -133,11 +292,10 @@ UPDATE outbox_messages m
 }
 
 // MarkSuccess deletes successfully dispatched rows (the upstream Java/Rust
-// model DELETEs on success to keep the customer outbox table bounded).
+// model DELETEs on success to keep the customer outbox table bounded), or
+// marks them SUCCESS when the table config maps that status.
 func (r *Repository) MarkSuccess(ctx context.Context, ids []string) error {
-	_, err := r.pool.Exec(ctx,
-		`DELETE FROM outbox_messages WHERE id = ANY($1)`,
-		ids)
+	_, err := r.pool.Exec(ctx, r.q.markSuccess, ids)
 	return err
 }
 
@@ -146,15 +304,14 @@ func (r *Repository) MarkSuccess(ctx context.Context, ids []string) error {
 // them (matching Rust increment_retry_count); terminal statuses keep their
 // code so they are not re-claimed. There is no next_retry_at column upstream.
 func (r *Repository) MarkFailed(ctx context.Context, ids []string, status common.OutboxStatus, msg string, requeue bool) error {
-	newStatus := status.Code()
 	if requeue {
-		newStatus = int(common.OutboxPending)
+		status = common.OutboxPending
 	}
-	_, err := r.pool.Exec(ctx,
-		`UPDATE outbox_messages
-		    SET status = $1, error_message = $2, retry_count = retry_count + 1, updated_at = NOW()
-		  WHERE id = ANY($3)`,
-		newStatus, msg, ids)
+	args := []any{ids}
+	if r.q.markFailedMsg {
+		args = append(args, msg)
+	}
+	_, err := r.pool.Exec(ctx, fmt.Sprintf(r.q.markFailed, r.table.Status(status)), args...)
 	return err
 }
 
@@ -167,9 +324,7 @@ func (r *Repository) Release(ctx context.Context, ids []string) error {
 	if len(ids) == 0 {
 		return nil
 	}
-	_, err := r.pool.Exec(ctx,
-		`UPDATE outbox_messages SET status = 0, updated_at = NOW()
-		  WHERE id = ANY($1) AND status = 9`, ids)
+	_, err := r.pool.Exec(ctx, r.q.release, ids)
 	return err
 }
 
@@ -179,26 +334,83 @@ func (r *Repository) Requeue(ctx context.Context, ids []string) error {
 	if len(ids) == 0 {
 		return nil
 	}
-	_, err := r.pool.Exec(ctx,
-		`UPDATE outbox_messages SET status = 0, retry_count = 0, error_message = NULL, updated_at = NOW()
-		  WHERE id = ANY($1)`, ids)
+	_, err := r.pool.Exec(ctx, r.q.requeue, ids)
 	return err
 }
 
 func (r *Repository) RecoverStuck(ctx context.Context, olderThan time.Duration) (int, error) {
+	if r.q.recover == "" {
+		return 0, nil
+	}
 	cutoff := time.Now().Add(-olderThan)
-	tag, err := r.pool.Exec(ctx,
-		`UPDATE outbox_messages SET status = 0, updated_at = NOW()
-		  WHERE status = 9 AND updated_at < $1`, cutoff)
+	tag, err := r.pool.Exec(ctx, r.q.recover, cutoff)
 	if err != nil {
 		return 0, err
 	}
 	return int(tag.RowsAffected()), nil
 }
 
+// TracksPublished reports whether the table has a published-id column.
+func (r *Repository) TracksPublished() bool { return r.q.markPublished != "" }
+
+// MarkPublished records the platform id of delivered rows in one statement.
+func (r *Repository) MarkPublished(ctx context.Context, published map[string]string) error {
+	if len(published) == 0 {
+		return nil
+	}
+	ids := make([]string, 0, len(published))
+	platformIDs := make([]string, 0, len(published))
+	for id, pid := range published {
+		ids = append(ids, id)
+		platformIDs = append(platformIDs, pid)
+	}
+	_, err := r.pool.Exec(ctx, r.q.markPublished, ids, platformIDs)
+	return err
+}
+
+// CompletePublished finishes IN_PROGRESS rows that have a platform id —
+// delivered, but their MarkSuccess never landed — the way MarkSuccess
+// would have.
+func (r *Repository) CompletePublished(ctx context.Context) (int, error) {
+	if r.q.completePublished == "" {
+		return 0, nil
+	}
+	tag, err := r.pool.Exec(ctx, r.q.completePublished)
+	if err != nil {
+		return 0, err
+	}
+	return int(tag.RowsAffected()), nil
+}
+
+// OldestPending returns the created_at of the oldest PENDING row; served by
+// idx_outbox_messages_pending.
+func (r *Repository) OldestPending(ctx context.Context) (time.Time, bool, error) {
+	var at *time.Time
+	if err := r.pool.QueryRow(ctx, r.q.oldestPending).Scan(&at); err != nil {
+		return time.Time{}, false, err
+	}
+	if at == nil {
+		return time.Time{}, false, nil
+	}
+	return *at, true, nil
+}
+
+// CountPending counts PENDING rows, on the same index as OldestPending.
+func (r *Repository) CountPending(ctx context.Context) (int64, error) {
+	var n int64
+	err := r.pool.QueryRow(ctx, r.q.countPending).Scan(&n)
+	return n, err
+}
+
 // Healthy pings the pool.
 func (r *Repository) Healthy(ctx context.Context) bool {
 	c, cancel := context.WithTimeout(ctx, 2*time.Second)
 	defer cancel()
 	return r.pool.Ping(c) == nil
 }
+
+var (
+	_ outbox.LagReporter    = (*Repository)(nil)
+	_ outbox.PendingCounter = (*Repository)(nil)
+	_ outbox.PublishTracker = (*Repository)(nil)
+)
@@ -0,0 +1,83 @@
+package postgres
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbox"
+)
+
+// squash collapses whitespace so the statements compare on content.
+func squash(s string) string { return strings.Join(strings.Fields(s), " ") }
+
+func TestBuildQueriesSDKTable(t *testing.T) {
+	q := buildQueries(outbox.TableConfig{})
+	assert.Equal(t, squash(`
+WITH claimed AS (
+  SELECT id FROM outbox_messages WHERE status = 0 ORDER BY message_group, created_at LIMIT $1 FOR UPDATE SKIP LOCKED
+)
+UPDATE outbox_messages m SET status = 9, updated_at = NOW() FROM claimed WHERE m.id = claimed.id
+RETURNING m.id::text, m.type, m.message_group, m.payload, m.retry_count, m.error_message, m.created_at, m.updated_at, NULL::text`),
+		squash(q.claim))
+	assert.Equal(t, `DELETE FROM outbox_messages WHERE id = ANY($1::text[])`, q.markSuccess)
+	assert.Equal(t, `UPDATE outbox_messages SET status = %s, error_message = $2, retry_count = retry_count + 1, updated_at = NOW() WHERE id = ANY($1::text[])`, q.markFailed)
+	assert.True(t, q.markFailedMsg)
+	assert.Equal(t, `UPDATE outbox_messages SET status = 0, updated_at = NOW() WHERE id = ANY($1::text[]) AND status = 9`, q.release)
+	assert.Equal(t, `UPDATE outbox_messages SET status = 0, retry_count = 0, error_message = NULL, updated_at = NOW() WHERE id = ANY($1::text[])`, q.requeue)
+	assert.Equal(t, `UPDATE outbox_messages SET status = 0, updated_at = NOW() WHERE status = 9 AND updated_at < $1`, q.recover)
+	assert.Equal(t, `SELECT min(created_at) FROM outbox_messages WHERE status = 0`, q.oldestPending)
+	assert.Empty(t, q.markPublished, "no published-id column, no two-phase publish")
+	assert.Empty(t, q.completePublished)
+}
+
+func TestBuildQueriesPublishedColumn(t *testing.T) {
+	col := "published_id"
+	q := buildQueries(outbox.TableConfig{Columns: outbox.Columns{PublishedID: &col}})
+	assert.True(t, strings.HasSuffix(squash(q.claim), "m.created_at, m.updated_at, m.published_id"))
+	assert.Equal(t, squash(`UPDATE outbox_messages m SET published_id = v.platform_id
+FROM unnest($1::text[], $2::text[]) AS v(id, platform_id) WHERE m.id = v.id::text`), squash(q.markPublished))
+	assert.Equal(t, `DELETE FROM outbox_messages WHERE status = 9 AND published_id IS NOT NULL`, q.completePublished)
+	assert.Equal(t, `UPDATE outbox_messages SET status = 0, updated_at = NOW() WHERE status = 9 AND updated_at < $1 AND published_id IS NULL`, q.recover,
+		"published rows are completed, never reset for re-send")
+	assert.Equal(t, `UPDATE outbox_messages SET status = 0, retry_count = 0, error_message = NULL, published_id = NULL, updated_at = NOW() WHERE id = ANY($1::text[])`, q.requeue)
+
+	marked, err := outbox.ParseTableConfig(`{"columns":{"publishedId":"broker_id","updatedAt":""},
+		"statusCodes":{"PENDING":0,"IN_PROGRESS":1,"SUCCESS":2},"filter":"tenant = 'acme'"}`)
+	require.NoError(t, err)
+	q = buildQueries(marked)
+	assert.Equal(t, `UPDATE outbox_messages SET status = 2 WHERE status = 1 AND broker_id IS NOT NULL AND (tenant = 'acme')`, q.completePublished)
+}
+
+func TestBuildQueriesLegacyTable(t *testing.T) {
+	cfg, err := outbox.ParseTableConfig(`{
+		"table": "legacy.event_outbox",
+		"columns": {"id": "event_id", "type": "", "messageGroup": "aggregate_id", "payload": "body",
+		            "status": "state", "retryCount": "", "errorMessage": "last_error", "updatedAt": ""},
+		"idType": "bigint",
+		"itemType": "EVENT",
+		"statusCodes": {"PENDING": "NEW", "IN_PROGRESS": "SENDING", "SUCCESS": "SENT",
+		                "BAD_REQUEST": "FAILED", "INTERNAL_ERROR": "FAILED", "UNAUTHORIZED": "FAILED",
+		                "FORBIDDEN": "FAILED", "GATEWAY_ERROR": "FAILED"},
+		"filter": "tenant = 'acme'"
+	}`)
+	require.NoError(t, err)
+	q := buildQueries(cfg)
+
+	assert.Equal(t, squash(`
+WITH claimed AS (
+  SELECT event_id FROM legacy.event_outbox WHERE state = 'NEW' AND (tenant = 'acme')
+   ORDER BY aggregate_id, created_at LIMIT $1 FOR UPDATE SKIP LOCKED
+)
+UPDATE legacy.event_outbox m SET state = 'SENDING' FROM claimed WHERE m.event_id = claimed.event_id
+RETURNING m.event_id::text, '', m.aggregate_id, m.body, 0, m.last_error, m.created_at, m.created_at`),
+		squash(q.claim))
+	assert.Equal(t, "EVENT", string(q.itemType))
+	assert.Equal(t, `UPDATE legacy.event_outbox SET state = 'SENT' WHERE event_id = ANY($1::bigint[])`, q.markSuccess)
+	assert.Equal(t, `UPDATE legacy.event_outbox SET state = %s, last_error = $2 WHERE event_id = ANY($1::bigint[])`, q.markFailed)
+	assert.Equal(t, `UPDATE legacy.event_outbox SET state = 'NEW', last_error = NULL WHERE event_id = ANY($1::bigint[])`, q.requeue)
+	assert.Empty(t, q.recover, "no updated_at, no stuck recovery")
+	assert.Equal(t, `SELECT min(created_at) FROM legacy.event_outbox WHERE state = 'NEW' AND (tenant = 'acme')`, q.oldestPending)
+}
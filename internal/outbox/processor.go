@@ -3,10 +3,13 @@ package outbox
 import (
 	"context"
 	"log/slog"
+	"sort"
 	"sync/atomic"
 	"time"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/logging"
 )
 
 // Config tunes the outbox processor.
@@ -38,6 +41,33 @@ type Config struct {
 	// releasing the rest to re-run in order behind it (OB4 ordering guarantee).
 	// Default true, matching Rust block_on_error. Ungrouped items are unaffected.
 	BlockOnError bool
+	// GroupPolicy is what a message group does when one of its items fails
+	// permanently; empty derives it from BlockOnError (block, else skip).
+	// GroupPolicies overrides it per group, by exact name or by a prefix
+	// ending in "*".
+	GroupPolicy   GroupPolicy
+	GroupPolicies map[string]GroupPolicy
+	// ParkDuration is how long GroupPolicyPark stops a group.
+	ParkDuration time.Duration
+	// StuckGroupThreshold flags a message group as stuck — warned about on
+	// every recovery pass and marked in GroupsReport — once one item has
+	// been dispatching, or the group has been blocked or parked, longer.
+	StuckGroupThreshold time.Duration
+	// Routes and Targets send some items elsewhere than PlatformURL: to
+	// other platforms or to queues, by item type, pool or payload field
+	// (see RoutingConfig). Empty sends everything to PlatformURL.
+	Routes  []Route
+	Targets map[string]Sender
+	// Blobs, when set, offloads event data over its threshold to the blob
+	// store the platform reads from (FC_BLOB_STORE_URI), so oversized
+	// events stay under the platform's request limit. Other targets get
+	// data inline.
+	Blobs *blobstore.Offloader
+	// TwoPhasePublish records each delivered item's platform id before
+	// completing it, so an item whose MarkSuccess fails is completed rather
+	// than re-sent after recovery. Needs a repository that is a
+	// PublishTracker with a published-id column; otherwise it is ignored.
+	TwoPhasePublish bool
 }
 
 // DefaultConfig matches the Rust outbox defaults.
@@ -52,23 +82,27 @@ func DefaultConfig() Config {
 		RecoveryThreshold:   5 * time.Minute,
 		MaxConcurrentGroups: 10,
 		BlockOnError:        true,
+		ParkDuration:        5 * time.Minute,
+		StuckGroupThreshold: 5 * time.Minute,
 	}
 }
 
 // Processor wires the outbox pipeline:
 //
-//	repo.ClaimPending → groupDistributor → httpDispatcher → repo.MarkSuccess/Failed
+//	repo.ClaimPending → groupDistributor → router → target → repo.MarkSuccess/Failed
 //
 // Mirrors fc-outbox/src/enhanced_processor.rs.
 type Processor struct {
 	cfg          Config
 	repo         Repository
-	dispatcher   *HTTPDispatcher
+	tracker      PublishTracker // nil unless two-phase publish is on
+	router       *Router
 	distributor  *GroupDistributor
 	groups       *GroupStateManager
 	inFlight     atomic.Int64
 	totalSucceed atomic.Uint64
 	totalFailed  atomic.Uint64
+	totalExpired atomic.Uint64
 
 	// IsLeader gates polling; nil means always-leader (single instance /
 	// standby disabled). When standby is enabled only the leader polls — the
@@ -81,17 +115,29 @@ type Processor struct {
 func NewProcessor(cfg Config, repo Repository) *Processor {
 	d := NewHTTPDispatcher(cfg.PlatformURL, cfg.AuthToken, cfg.HTTPTimeout)
 	d.tokenSource = cfg.TokenSource
-	return &Processor{
+	d.blobs = cfg.Blobs
+	// The distributor always stops a group on failure; GroupPolicySkip
+	// groups report failures as success to it (see tick).
+	p := &Processor{
 		cfg:         cfg,
 		repo:        repo,
-		dispatcher:  d,
-		distributor: NewGroupDistributor(cfg.MaxConcurrentGroups, cfg.BlockOnError),
+		router:      NewRouter(d, cfg.Routes, cfg.Targets),
+		distributor: NewGroupDistributor(cfg.MaxConcurrentGroups, true),
 		groups:      NewGroupStateManager(),
 	}
+	if cfg.TwoPhasePublish {
+		if t, ok := repo.(PublishTracker); ok && t.TracksPublished() {
+			p.tracker = t
+		} else {
+			slog.Warn("outbox two-phase publish needs a published-id column; publishing single-phase")
+		}
+	}
+	return p
 }
 
 // Run drives the processor until ctx is cancelled. Two tickers: the poll
-// loop (claim + dispatch) and the crash-recovery loop (reset stuck rows).
+// loop (claim + dispatch) and the crash-recovery loop (complete published
+// orphans, reset stuck rows).
 func (p *Processor) Run(ctx context.Context) {
 	tick := time.NewTicker(p.cfg.PollInterval)
 	defer tick.Stop()
@@ -118,20 +164,36 @@ func (p *Processor) Run(ctx context.Context) {
 			if p.IsLeader != nil && !p.IsLeader() {
 				continue
 			}
-			threshold := p.cfg.RecoveryThreshold
-			if threshold <= 0 {
-				threshold = 5 * time.Minute
-			}
-			if n, err := p.repo.RecoverStuck(ctx, threshold); err != nil {
-				slog.Warn("outbox recover stuck failed", "err", err)
-			} else if n > 0 {
-				slog.Info("outbox recovered stuck items", "count", n)
-			}
+			p.runRecovery(ctx)
 		}
 	}
 }
 
+// runRecovery is one crash-recovery pass. Under two-phase publish, rows that
+// were delivered but never completed are completed first; the stuck reset
+// then only re-sends rows the platform never acknowledged.
+func (p *Processor) runRecovery(ctx context.Context) {
+	p.warnStuckGroups(time.Now())
+	if p.tracker != nil {
+		if n, err := p.tracker.CompletePublished(ctx); err != nil {
+			slog.Warn("outbox complete published failed", "err", err)
+		} else if n > 0 {
+			slog.Info("outbox completed published items", "count", n)
+		}
+	}
+	threshold := p.cfg.RecoveryThreshold
+	if threshold <= 0 {
+		threshold = 5 * time.Minute
+	}
+	if n, err := p.repo.RecoverStuck(ctx, threshold); err != nil {
+		slog.Warn("outbox recover stuck failed", "err", err)
+	} else if n > 0 {
+		slog.Info("outbox recovered stuck items", "count", n)
+	}
+}
+
 func (p *Processor) tick(ctx context.Context) {
+	p.unparkGroups(ctx, time.Now())
 	items, err := p.repo.ClaimPending(ctx, p.cfg.BatchSize)
 	if err != nil {
 		slog.Warn("outbox claim failed", "err", err)
@@ -143,11 +205,30 @@ func (p *Processor) tick(ctx context.Context) {
 
 	// Partition the claim: grouped items keep strict per-group FIFO +
 	// block-on-error (serial via the distributor, OB7-bounded); ungrouped items
-	// are batched by ItemType into a single HTTP call each (OB4 throughput —
-	// there's no ordering to preserve for them).
-	byType := make(map[common.OutboxItemType][]Item)
+	// are batched by target and ItemType into a single call each (OB4
+	// throughput — there's no ordering to preserve for them).
+	type batchKey struct {
+		target   string
+		itemType common.OutboxItemType
+	}
+	byType := make(map[batchKey][]Item)
+	senders := make(map[string]Sender)
+	var published, expired []string
+	now := time.Now()
 	for _, item := range items {
 		item := item
+		if item.PublishedID != "" {
+			// Delivered by an earlier attempt whose completion was lost:
+			// complete it, never re-send it.
+			published = append(published, item.ID)
+			continue
+		}
+		if item.Expired(now) {
+			// Past its expiresAt: a stale notification is worse than none,
+			// so it is not sent. Not a failure — its group carries on.
+			expired = append(expired, item.ID)
+			continue
+		}
 		if item.MessageGroup != nil && *item.MessageGroup != "" {
 			// State machine: skip a Paused/Blocked group — release its claimed
 			// items back to PENDING (re-claimed once the group is resumed/
@@ -157,10 +238,11 @@ func (p *Processor) tick(ctx context.Context) {
 				continue
 			}
 			p.inFlight.Add(1)
+			skip := p.cfg.policyFor(*item.MessageGroup) == GroupPolicySkip
 			p.distributor.Submit(item,
 				func() bool {
 					defer p.inFlight.Add(-1)
-					return p.dispatch(ctx, item)
+					return p.dispatch(ctx, item) || skip
 				},
 				func() {
 					defer p.inFlight.Add(-1)
@@ -168,26 +250,38 @@ func (p *Processor) tick(ctx context.Context) {
 				})
 			continue
 		}
-		byType[item.ItemType] = append(byType[item.ItemType], item)
+		target, sender := p.router.Route(item)
+		senders[target] = sender
+		key := batchKey{target, item.ItemType}
+		byType[key] = append(byType[key], item)
 	}
-	for _, batch := range byType {
-		batch := batch
+	if len(published) > 0 {
+		if err := p.repo.MarkSuccess(ctx, published); err != nil {
+			slog.Warn("outbox mark success failed (published)", "count", len(published), "err", err)
+		}
+	}
+	if len(expired) > 0 {
+		p.markExpired(ctx, expired)
+	}
+	for key, batch := range byType {
+		batch, sender := batch, senders[key.target]
 		p.inFlight.Add(int64(len(batch)))
-		go p.dispatchBatch(ctx, batch)
+		go p.dispatchBatch(ctx, sender, batch)
 	}
 }
 
-// dispatchBatch sends a batch of ungrouped, same-ItemType items in one HTTP
-// call (OB4) and records each item's outcome — MarkSuccess in bulk, MarkFailed
+// dispatchBatch sends a batch of ungrouped, same-ItemType items in one call to
+// their target (OB4) and records each item's outcome — MarkSuccess in bulk, MarkFailed
 // per item (same retryable + max-retries requeue rule as dispatch).
-func (p *Processor) dispatchBatch(ctx context.Context, batch []Item) {
+func (p *Processor) dispatchBatch(ctx context.Context, sender Sender, batch []Item) {
 	defer p.inFlight.Add(-int64(len(batch)))
-	outcomes := p.dispatcher.SendBatch(ctx, batch)
+	outcomes := sender.SendBatch(ctx, batch)
 	maxRetries := p.cfg.MaxRetries
 	if maxRetries <= 0 {
 		maxRetries = 3
 	}
 	var succeeded []string
+	published := make(map[string]string)
 	for _, item := range batch {
 		out, ok := outcomes[item.ID]
 		if !ok {
@@ -195,6 +289,7 @@ func (p *Processor) dispatchBatch(ctx context.Context, batch []Item) {
 		}
 		if out.Status == common.OutboxSuccess {
 			succeeded = append(succeeded, item.ID)
+			published[item.ID] = out.PlatformID
 			p.totalSucceed.Add(1)
 			continue
 		}
@@ -205,12 +300,42 @@ func (p *Processor) dispatchBatch(ctx context.Context, batch []Item) {
 		p.totalFailed.Add(1)
 	}
 	if len(succeeded) > 0 {
+		p.markPublished(ctx, published)
 		if err := p.repo.MarkSuccess(ctx, succeeded); err != nil {
 			slog.Warn("outbox mark success failed (batch)", "count", len(succeeded), "err", err)
 		}
 	}
 }
 
+// markPublished is two-phase publish's first phase: record the platform ids
+// of delivered items so that, if MarkSuccess fails, they are completed
+// instead of re-sent. An item acknowledged without an id (SKIPPED) is
+// recorded as "-". A failure here only loses that protection.
+func (p *Processor) markPublished(ctx context.Context, published map[string]string) {
+	if p.tracker == nil {
+		return
+	}
+	for id, pid := range published {
+		if pid == "" {
+			published[id] = "-"
+		}
+	}
+	if err := p.tracker.MarkPublished(ctx, published); err != nil {
+		slog.WarnContext(ctx, "outbox mark published failed", "count", len(published), "err", err)
+	}
+}
+
+// markExpired records items that expired before delivery as EXPIRED
+// (terminal, never re-claimed).
+func (p *Processor) markExpired(ctx context.Context, ids []string) {
+	if err := p.repo.MarkFailed(ctx, ids, common.OutboxExpired, "expired before delivery", false); err != nil {
+		slog.Warn("outbox mark expired failed", "count", len(ids), "err", err)
+		return
+	}
+	p.totalExpired.Add(uint64(len(ids)))
+	slog.Warn("outbox items expired before delivery; not sent", "count", len(ids))
+}
+
 // release returns an undispatched, group-blocked item to PENDING (no failure
 // penalty) so the next poll re-claims it in order behind the failed item.
 func (p *Processor) release(ctx context.Context, item Item) {
@@ -219,13 +344,25 @@ func (p *Processor) release(ctx context.Context, item Item) {
 	}
 }
 
+// itemLogContext attaches an item's identity to ctx so the dispatcher and
+// the outcome bookkeeping log it without repeating it at each call.
+func itemLogContext(ctx context.Context, item Item) context.Context {
+	args := []any{"id", item.ID, "item_type", item.ItemType}
+	if item.MessageGroup != nil && *item.MessageGroup != "" {
+		args = append(args, "group", *item.MessageGroup)
+	}
+	return logging.With(ctx, args...)
+}
+
 // dispatch sends one item and records its outcome. Returns true on success,
 // false on any failure (so a message group blocks on it when BlockOnError).
 func (p *Processor) dispatch(ctx context.Context, item Item) bool {
-	out := p.dispatcher.Send(ctx, item)
+	ctx = itemLogContext(ctx, item)
+	out := p.send(ctx, item)
 	if out.Status == common.OutboxSuccess {
+		p.markPublished(ctx, map[string]string{item.ID: out.PlatformID})
 		if err := p.repo.MarkSuccess(ctx, []string{item.ID}); err != nil {
-			slog.Warn("outbox mark success failed", "id", item.ID, "err", err)
+			slog.WarnContext(ctx, "outbox mark success failed", "err", err)
 			return false
 		}
 		p.totalSucceed.Add(1)
@@ -242,19 +379,51 @@ func (p *Processor) dispatch(ctx context.Context, item Item) bool {
 	}
 	requeue := out.Status.IsRetryable() && item.AttemptCount+1 < maxRetries
 	if err := p.repo.MarkFailed(ctx, []string{item.ID}, out.Status, out.Message, requeue); err != nil {
-		slog.Warn("outbox mark failed", "id", item.ID, "err", err)
+		slog.WarnContext(ctx, "outbox mark failed", "err", err)
 	}
 	p.totalFailed.Add(1)
 	// State machine: a PERMANENT failure (non-retryable or retry-exhausted) of a
-	// grouped item blocks its group until an operator unblocks (retry) or skips
-	// (abandon) the poison item — so the group never silently advances past it.
-	if !requeue && p.cfg.BlockOnError && item.MessageGroup != nil && *item.MessageGroup != "" {
-		p.groups.Block(*item.MessageGroup, item.ID, out.Message)
-		slog.Warn("outbox message group blocked", "group", *item.MessageGroup, "id", item.ID, "error", out.Message)
+	// grouped item blocks or parks its group per its GroupPolicy — so the group
+	// never silently advances past the poison item unless told to skip it.
+	if !requeue && item.MessageGroup != nil && *item.MessageGroup != "" {
+		group := *item.MessageGroup
+		switch p.cfg.policyFor(group) {
+		case GroupPolicyBlock:
+			p.groups.Block(group, item.ID, out.Message)
+			slog.WarnContext(ctx, "outbox message group blocked", "error", out.Message)
+		case GroupPolicyPark:
+			park := p.cfg.ParkDuration
+			if park <= 0 {
+				park = 5 * time.Minute
+			}
+			p.groups.Park(group, item.ID, out.Message, time.Now().Add(park))
+			slog.WarnContext(ctx, "outbox message group parked", "error", out.Message, "for", park)
+		}
 	}
 	return false
 }
 
+// send delivers one item to its route's target.
+func (p *Processor) send(ctx context.Context, item Item) DispatchOutcome {
+	_, sender := p.router.Route(item)
+	if o, ok := sender.SendBatch(ctx, []Item{item})[item.ID]; ok {
+		return o
+	}
+	// Never a zero-value SUCCESS by accident — treat as retryable.
+	return DispatchOutcome{Status: common.OutboxInternalError, Message: "no outcome for item"}
+}
+
+// unparkGroups resumes groups whose park has expired, re-queuing the poison
+// item so the group retries it first.
+func (p *Processor) unparkGroups(ctx context.Context, now time.Time) {
+	for _, g := range p.groups.Unpark(now) {
+		if err := p.repo.Requeue(ctx, []string{g.BlockedItemID}); err != nil {
+			slog.Warn("outbox unpark requeue failed", "group", g.Group, "id", g.BlockedItemID, "err", err)
+		}
+		slog.Info("outbox group unparked (poison re-queued)", "group", g.Group, "id", g.BlockedItemID)
+	}
+}
+
 // ── Operational state machine controls (Rust message_group_processor parity) ──
 
 // PauseGroup stops dispatching a message group; its items are released to
@@ -264,7 +433,7 @@ func (p *Processor) PauseGroup(group string) { p.groups.Pause(group) }
 // ResumeGroup resumes a Paused group.
 func (p *Processor) ResumeGroup(group string) { p.groups.Resume(group) }
 
-// UnblockGroup clears a Blocked group and RE-QUEUES the poison item (a fresh
+// UnblockGroup clears a Blocked (or Parked) group and RE-QUEUES the poison item (a fresh
 // retry), so the whole group runs again in order. Returns false if not Blocked.
 func (p *Processor) UnblockGroup(ctx context.Context, group string) bool {
 	itemID, ok := p.groups.ClearBlock(group)
@@ -280,7 +449,7 @@ func (p *Processor) UnblockGroup(ctx context.Context, group string) bool {
 	return true
 }
 
-// SkipGroup clears a Blocked group WITHOUT re-queuing the poison item — it stays
+// SkipGroup clears a Blocked (or Parked) group WITHOUT re-queuing the poison item — it stays
 // terminally failed and the group advances past it. Returns false if not Blocked.
 func (p *Processor) SkipGroup(group string) bool {
 	itemID, ok := p.groups.ClearBlock(group)
@@ -296,6 +465,76 @@ func (p *Processor) GroupStates() []GroupInfo { return p.groups.Snapshot() }
 // BlockedGroups returns only the Blocked message groups.
 func (p *Processor) BlockedGroups() []GroupInfo { return p.groups.Blocked() }
 
+// GroupReport is one message group in GET /groups: groups with claimed
+// items, and groups that are not Running.
+type GroupReport struct {
+	Group  string      `json:"group"`
+	Status GroupStatus `json:"status"`
+	// InFlight counts the group's claimed items, queued and dispatching.
+	InFlight int `json:"inFlight"`
+	// OldestItemAgeSeconds is the age of the oldest claimed item.
+	OldestItemAgeSeconds float64 `json:"oldestItemAgeSeconds,omitempty"`
+	// DispatchingSeconds is how long the current item has been sending.
+	DispatchingSeconds float64 `json:"dispatchingSeconds,omitempty"`
+	// StatusSeconds is how long the group has been in a non-Running Status.
+	StatusSeconds float64    `json:"statusSeconds,omitempty"`
+	BlockedItemID string     `json:"blockedItemId,omitempty"`
+	Error         string     `json:"error,omitempty"`
+	ParkedUntil   *time.Time `json:"parkedUntil,omitempty"`
+	Stuck         bool       `json:"stuck"`
+}
+
+// GroupsReport merges the distributor's live groups with the state
+// machine's non-Running ones, sorted by group.
+func (p *Processor) GroupsReport(now time.Time) []GroupReport {
+	threshold := p.cfg.StuckGroupThreshold
+	if threshold <= 0 {
+		threshold = 5 * time.Minute
+	}
+	byGroup := make(map[string]*GroupReport)
+	for _, a := range p.distributor.Activity() {
+		r := &GroupReport{Group: a.Group, Status: GroupRunning, InFlight: a.InFlight}
+		if !a.Oldest.IsZero() {
+			r.OldestItemAgeSeconds = now.Sub(a.Oldest).Seconds()
+		}
+		if !a.DispatchingSince.IsZero() {
+			r.DispatchingSeconds = now.Sub(a.DispatchingSince).Seconds()
+			r.Stuck = now.Sub(a.DispatchingSince) > threshold
+		}
+		byGroup[a.Group] = r
+	}
+	for _, g := range p.groups.Snapshot() {
+		r := byGroup[g.Group]
+		if r == nil {
+			r = &GroupReport{Group: g.Group}
+			byGroup[g.Group] = r
+		}
+		r.Status, r.BlockedItemID, r.Error, r.ParkedUntil = g.Status, g.BlockedItemID, g.Error, g.ParkedUntil
+		r.StatusSeconds = now.Sub(g.Since).Seconds()
+		if g.Status == GroupBlocked || g.Status == GroupParked {
+			r.Stuck = r.Stuck || now.Sub(g.Since) > threshold
+		}
+	}
+	out := make([]GroupReport, 0, len(byGroup))
+	for _, r := range byGroup {
+		out = append(out, *r)
+	}
+	sort.Slice(out, func(i, j int) bool { return out[i].Group < out[j].Group })
+	return out
+}
+
+// warnStuckGroups logs every stuck group; run on each recovery pass.
+func (p *Processor) warnStuckGroups(now time.Time) {
+	for _, r := range p.GroupsReport(now) {
+		if !r.Stuck {
+			continue
+		}
+		slog.Warn("outbox message group stuck", "group", r.Group, "status", r.Status,
+			"in_flight", r.InFlight, "dispatching_s", int(r.DispatchingSeconds),
+			"status_s", int(r.StatusSeconds), "blocked_item", r.BlockedItemID)
+	}
+}
+
 // InFlight returns the count of items currently in dispatch.
 func (p *Processor) InFlight() int64 { return p.inFlight.Load() }
 
@@ -303,3 +542,7 @@ func (p *Processor) InFlight() int64 { return p.inFlight.Load() }
 func (p *Processor) Totals() (uint64, uint64) {
 	return p.totalSucceed.Load(), p.totalFailed.Load()
 }
+
+// Expired returns how many items this processor has marked EXPIRED since
+// process start.
+func (p *Processor) Expired() uint64 { return p.totalExpired.Load() }
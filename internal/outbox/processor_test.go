@@ -3,8 +3,11 @@ package outbox
 import (
 	"context"
 	"encoding/json"
+	"errors"
+	"maps"
 	"net/http"
 	"net/http/httptest"
+	"sync/atomic"
 	"testing"
 	"time"
 
@@ -99,3 +102,181 @@ func TestProcessorRetryableDoesNotBlock(t *testing.T) {
 		t.Fatal("a retryable failure (attempt 1 < max 3) must NOT block the group")
 	}
 }
+
+// trackerRepo is a stubRepo that claims fixed items, tracks published ids
+// and can fail MarkSuccess.
+type trackerRepo struct {
+	stubRepo
+	claim       []Item
+	published   map[string]string
+	succeeded   []string
+	failSuccess bool
+	completed   int
+}
+
+func (r *trackerRepo) ClaimPending(context.Context, int) ([]Item, error) {
+	items := r.claim
+	r.claim = nil
+	return items, nil
+}
+
+func (r *trackerRepo) MarkSuccess(_ context.Context, ids []string) error {
+	if r.failSuccess {
+		return errors.New("connection reset")
+	}
+	r.succeeded = append(r.succeeded, ids...)
+	return nil
+}
+
+func (r *trackerRepo) TracksPublished() bool { return true }
+
+func (r *trackerRepo) MarkPublished(_ context.Context, published map[string]string) error {
+	maps.Copy(r.published, published)
+	return nil
+}
+
+func (r *trackerRepo) CompletePublished(context.Context) (int, error) {
+	n := len(r.published)
+	r.completed += n
+	r.published = map[string]string{}
+	return n, nil
+}
+
+// Two-phase publish: the platform id is recorded before MarkSuccess, so a
+// failed MarkSuccess leaves a row the recovery sweep completes; a published
+// row that is claimed again is completed without being re-sent.
+func TestProcessorTwoPhasePublish(t *testing.T) {
+	var posts atomic.Int32
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		posts.Add(1)
+		_ = json.NewEncoder(w).Encode(map[string]any{"results": []map[string]any{{"id": "evt-1", "status": "SUCCESS"}}})
+	}))
+	defer srv.Close()
+
+	repo := &trackerRepo{published: map[string]string{}, failSuccess: true}
+	cfg := DefaultConfig()
+	cfg.PlatformURL = srv.URL
+	cfg.TwoPhasePublish = true
+	p := NewProcessor(cfg, repo)
+
+	item := Item{ID: "itm1", ItemType: common.OutboxItemEvent, Payload: json.RawMessage(`{}`)}
+	if ok := p.dispatch(context.Background(), item); ok {
+		t.Fatal("a failed MarkSuccess must return false")
+	}
+	if repo.published["itm1"] != "evt-1" {
+		t.Fatalf("published = %v, want itm1 → evt-1", repo.published)
+	}
+	p.runRecovery(context.Background())
+	if repo.completed != 1 {
+		t.Fatalf("recovery must complete the published orphan; completed=%d", repo.completed)
+	}
+
+	repo.failSuccess = false
+	item.PublishedID = "evt-1"
+	repo.claim = []Item{item}
+	p.tick(context.Background())
+	if n := posts.Load(); n != 1 {
+		t.Fatalf("a published item must not be re-sent; posts=%d", n)
+	}
+	if len(repo.succeeded) != 1 || repo.succeeded[0] != "itm1" {
+		t.Fatalf("a published item must be completed; succeeded=%v", repo.succeeded)
+	}
+}
+
+// Without TwoPhasePublish the tracker is never consulted.
+func TestProcessorSinglePhaseIgnoresTracker(t *testing.T) {
+	repo := &trackerRepo{published: map[string]string{}}
+	p := NewProcessor(DefaultConfig(), repo)
+	if p.tracker != nil {
+		t.Fatal("tracker must be nil unless TwoPhasePublish is set")
+	}
+}
+
+// The park policy stops a group on a permanent failure and, once the park
+// expires, re-queues the poison item and resumes the group. GroupsReport
+// flags a group parked past the stuck threshold.
+func TestProcessorParksGroup(t *testing.T) {
+	item, srv := groupedItem("itm3", "g3", "BAD_REQUEST")
+	defer srv.Close()
+
+	repo := &stubRepo{}
+	cfg := DefaultConfig()
+	cfg.PlatformURL = srv.URL
+	cfg.GroupPolicies = map[string]GroupPolicy{"g*": GroupPolicyPark}
+	cfg.ParkDuration = time.Minute
+	cfg.StuckGroupThreshold = 30 * time.Second
+	p := NewProcessor(cfg, repo)
+
+	p.dispatch(context.Background(), item)
+	if p.groups.IsActive("g3") {
+		t.Fatal("group must be Parked after a permanent failure")
+	}
+	r := p.GroupsReport(time.Now().Add(45 * time.Second))
+	if len(r) != 1 || r[0].Status != GroupParked || !r[0].Stuck || r[0].BlockedItemID != "itm3" {
+		t.Fatalf("GroupsReport = %+v, want g3 parked, stuck, on itm3", r)
+	}
+
+	p.unparkGroups(context.Background(), time.Now())
+	if len(repo.requeued) != 0 {
+		t.Fatal("a park must not end before ParkDuration")
+	}
+	p.unparkGroups(context.Background(), time.Now().Add(time.Minute))
+	if !p.groups.IsActive("g3") || len(repo.requeued) != 1 || repo.requeued[0] != "itm3" {
+		t.Fatalf("an expired park must resume the group and re-queue the poison; requeued=%v", repo.requeued)
+	}
+}
+
+// expiryRepo is a trackerRepo that records the status of each MarkFailed.
+type expiryRepo struct {
+	trackerRepo
+	failed map[string]common.OutboxStatus
+}
+
+func (r *expiryRepo) MarkFailed(_ context.Context, ids []string, status common.OutboxStatus, _ string, _ bool) error {
+	for _, id := range ids {
+		r.failed[id] = status
+	}
+	return nil
+}
+
+// An item whose payload expiresAt has passed is marked EXPIRED without
+// being sent, and does not block its group; unexpired items still go out.
+func TestProcessorExpiresStaleItems(t *testing.T) {
+	var posts atomic.Int32
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		posts.Add(1)
+		_ = json.NewEncoder(w).Encode(map[string]any{"results": []map[string]any{{"id": "fresh", "status": "SUCCESS"}}})
+	}))
+	defer srv.Close()
+
+	group := "g-exp"
+	past := time.Now().Add(-time.Hour).Format(time.RFC3339)
+	future := time.Now().Add(time.Hour).Format(time.RFC3339)
+	repo := &expiryRepo{failed: map[string]common.OutboxStatus{}}
+	repo.claim = []Item{
+		{ID: "stale", ItemType: common.OutboxItemEvent, MessageGroup: &group, Payload: json.RawMessage(`{"expiresAt":"` + past + `"}`)},
+		{ID: "fresh", ItemType: common.OutboxItemEvent, Payload: json.RawMessage(`{"expiresAt":"` + future + `"}`)},
+	}
+	cfg := DefaultConfig()
+	cfg.PlatformURL = srv.URL
+	p := NewProcessor(cfg, repo)
+
+	p.tick(context.Background())
+	deadline := time.Now().Add(2 * time.Second)
+	for p.InFlight() > 0 && time.Now().Before(deadline) {
+		time.Sleep(5 * time.Millisecond)
+	}
+
+	if repo.failed["stale"] != common.OutboxExpired {
+		t.Fatalf("stale item status = %v, want EXPIRED", repo.failed["stale"])
+	}
+	if p.Expired() != 1 {
+		t.Fatalf("Expired() = %d, want 1", p.Expired())
+	}
+	if !p.groups.IsActive(group) {
+		t.Fatal("an expired item must not block its group")
+	}
+	if posts.Load() != 1 || len(repo.succeeded) != 1 || repo.succeeded[0] != "fresh" {
+		t.Fatalf("fresh item: posts=%d succeeded=%v, want it sent", posts.Load(), repo.succeeded)
+	}
+}
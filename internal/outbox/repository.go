@@ -21,16 +21,33 @@ import (
 // error_message, created_at, updated_at — plus SDK-owned columns
 // (client_id, payload_size, headers) the processor reads-around and never
 // writes. AttemptCount maps to retry_count; StatusMessage to error_message.
+// PublishedID is set once the platform has stored the item under two-phase
+// publish (see PublishTracker).
 type Item struct {
 	ID            string                `json:"id"`
 	ItemType      common.OutboxItemType `json:"itemType"`
 	MessageGroup  *string               `json:"messageGroup,omitempty"`
 	Payload       json.RawMessage       `json:"payload"`
-	Status        common.OutboxStatus
-	StatusMessage string    `json:"statusMessage,omitempty"`
-	AttemptCount  int       `json:"attemptCount"`
-	CreatedAt     time.Time `json:"createdAt"`
-	UpdatedAt     time.Time `json:"updatedAt"`
+	Status        common.OutboxStatus   `json:"status"`
+	StatusMessage string                `json:"statusMessage,omitempty"`
+	AttemptCount  int                   `json:"attemptCount"`
+	CreatedAt     time.Time             `json:"createdAt"`
+	UpdatedAt     time.Time             `json:"updatedAt"`
+	PublishedID   string                `json:"publishedId,omitempty"`
+}
+
+// Expired reports whether the item's payload carries an expiresAt (RFC
+// 3339) at or before now. The processor marks such items EXPIRED instead
+// of delivering them. A payload without a parseable expiresAt never
+// expires.
+func (it Item) Expired(now time.Time) bool {
+	var p struct {
+		ExpiresAt *time.Time `json:"expiresAt"`
+	}
+	if json.Unmarshal(it.Payload, &p) != nil || p.ExpiresAt == nil {
+		return false
+	}
+	return !p.ExpiresAt.After(now)
 }
 
 // Repository is the per-backend storage interface.
@@ -72,3 +89,33 @@ type Repository interface {
 	// InitSchema ensures the outbox table/collection exists.
 	InitSchema(ctx context.Context) error
 }
+
+// LagReporter is optionally implemented by backends that can cheaply report
+// the created_at of the oldest PENDING item — the outbox lag fc-server
+// surfaces on /q/health. ok is false when nothing is pending.
+type LagReporter interface {
+	OldestPending(ctx context.Context) (at time.Time, ok bool, err error)
+}
+
+// PendingCounter is optionally implemented by backends that can count
+// PENDING items — the outbox backlog fc-server exports as
+// fc_outbox_pending_messages for autoscalers.
+type PendingCounter interface {
+	CountPending(ctx context.Context) (int64, error)
+}
+
+// PublishTracker is optionally implemented by backends that can record the
+// platform id of a delivered item before completing it — two-phase publish.
+// A crash or failed MarkSuccess after delivery then leaves a row that is
+// known to be delivered: it is completed, not re-sent, on the next claim or
+// by the periodic CompletePublished sweep.
+type PublishTracker interface {
+	// TracksPublished reports whether the table has somewhere to store the
+	// platform id; without it the processor publishes single-phase.
+	TracksPublished() bool
+	// MarkPublished stores the platform id per outbox item id.
+	MarkPublished(ctx context.Context, published map[string]string) error
+	// CompletePublished marks every published but unfinished row as
+	// succeeded, returning the number of orphans it completed.
+	CompletePublished(ctx context.Context) (int, error)
+}
@@ -0,0 +1,84 @@
+package outbox
+
+import (
+	"encoding/json"
+	"testing"
+	"time"
+	"unicode/utf8"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// itemKeys is every key an Item may encode, in camelCase.
+var itemKeys = map[string]bool{
+	"id": true, "itemType": true, "messageGroup": true, "payload": true, "status": true,
+	"statusMessage": true, "attemptCount": true, "createdAt": true, "updatedAt": true,
+	"publishedId": true,
+}
+
+// FuzzItemJSONRoundTrip checks that an outbox item survives encoding with
+// camelCase keys, whatever the customer wrote into its row.
+func FuzzItemJSONRoundTrip(f *testing.F) {
+	f.Add("ob1", "EVENT", "group-1", []byte(`{"k":"v"}`), 3, "boom", 2, int64(1_700_000_000))
+	f.Add("", "", "", []byte(`null`), 0, "", 0, int64(0))
+	f.Add("ob2", "AUDIT_LOG", "", []byte(` [1, "<&>", {"a" : null}] `), 9, " ", -1, int64(253402300799))
+	f.Fuzz(func(t *testing.T, id, itemType, group string, payload []byte, code int, msg string, attempts int, created int64) {
+		for _, s := range []string{id, itemType, group, msg} {
+			if !utf8.ValidString(s) {
+				return // encoding/json replaces invalid UTF-8; not a round-trip
+			}
+		}
+		if !json.Valid(payload) || created < 0 || created > 253402300799 {
+			return // Marshal rejects both: not a row the processor can hold
+		}
+		it := Item{
+			ID:            id,
+			ItemType:      common.OutboxItemType(itemType),
+			Payload:       payload,
+			Status:        common.FromOutboxCode(code),
+			StatusMessage: msg,
+			AttemptCount:  attempts,
+			CreatedAt:     time.Unix(created, 0).UTC(),
+			UpdatedAt:     time.Unix(created, 0).UTC(),
+		}
+		if group != "" {
+			it.MessageGroup = &group
+		}
+		b, err := json.Marshal(it)
+		require.NoError(t, err)
+		var keys map[string]json.RawMessage
+		require.NoError(t, json.Unmarshal(b, &keys))
+		for k := range keys {
+			assert.True(t, itemKeys[k], "unexpected key %q", k)
+		}
+
+		var back Item
+		require.NoError(t, json.Unmarshal(b, &back))
+		assert.JSONEq(t, string(it.Payload), string(back.Payload))
+		assert.True(t, it.CreatedAt.Equal(back.CreatedAt))
+		it.Payload, back.Payload = nil, nil
+		it.CreatedAt, back.CreatedAt = time.Time{}, time.Time{}
+		it.UpdatedAt, back.UpdatedAt = time.Time{}, time.Time{}
+		assert.Equal(t, it, back)
+	})
+}
+
+// TestParseItemStatusInvertsString pins the per-item wire mapping to
+// OutboxStatus.String for every status the platform reports.
+func TestParseItemStatusInvertsString(t *testing.T) {
+	for _, s := range []common.OutboxStatus{
+		common.OutboxSuccess, common.OutboxBadRequest, common.OutboxInternalError,
+		common.OutboxUnauthorized, common.OutboxForbidden, common.OutboxGatewayError,
+	} {
+		got, ok := parseItemStatus(s.String())
+		assert.True(t, ok, s.String())
+		assert.Equal(t, s, got)
+	}
+	for _, s := range []string{"PENDING", "IN_PROGRESS", "success", ""} {
+		_, ok := parseItemStatus(s)
+		assert.False(t, ok, s)
+	}
+}
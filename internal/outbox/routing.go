@@ -0,0 +1,289 @@
+package outbox
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"strings"
+	"time"
+
+	"gopkg.in/yaml.v3"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+// DefaultTarget names the processor's own platform (Config.PlatformURL):
+// where items no route matches go.
+const DefaultTarget = "default"
+
+// Sender delivers a batch of same-ItemType items and reports an outcome
+// per item id. HTTPDispatcher and QueueSender implement it.
+type Sender interface {
+	SendBatch(ctx context.Context, items []Item) map[string]DispatchOutcome
+}
+
+// RoutingConfig is the FC_OUTBOX_ROUTES_PATH file (YAML or JSON): named
+// delivery targets and the routes that choose between them.
+//
+//	targets:
+//	  audit:  {url: https://audit.example.com, authToken: secret}
+//	  pool-x: {queue: "sqs://...", poolCode: POOL_X}
+//	routes:
+//	  - {itemType: AUDIT_LOG, target: audit}
+//	  - {itemType: DISPATCH_JOB, pool: 0HZXEQ5Y8JY5Z, target: pool-x}
+//	  - {payload: {source: billing}, target: pool-x}
+//
+// Routes are tried in order and the first match wins; unmatched items go
+// to DefaultTarget.
+type RoutingConfig struct {
+	Targets map[string]TargetConfig `json:"targets"`
+	Routes  []Route                 `json:"routes"`
+}
+
+// TargetConfig is one delivery target: another platform's batch API (URL)
+// or a queue (Queue), never both.
+type TargetConfig struct {
+	URL       string `json:"url,omitempty"`
+	AuthToken string `json:"authToken,omitempty"`
+	// Queue is a queue URI for queue.NewPublisher; items are published as
+	// message pointers (see QueueSender).
+	Queue           string `json:"queue,omitempty"`
+	PoolCode        string `json:"poolCode,omitempty"`
+	MediationTarget string `json:"mediationTarget,omitempty"`
+}
+
+// Route sends the items matching all of its conditions to Target. A route
+// without conditions matches everything.
+type Route struct {
+	ItemType common.OutboxItemType `json:"itemType,omitempty"`
+	// Pool matches the dispatch pool a DISPATCH_JOB payload names
+	// (dispatchPoolId).
+	Pool string `json:"pool,omitempty"`
+	// Payload matches payload fields, by dotted path, on their value as a
+	// string.
+	Payload map[string]string `json:"payload,omitempty"`
+	Target  string            `json:"target"`
+}
+
+// LoadRoutingConfig reads and validates a routing file.
+func LoadRoutingConfig(path string) (RoutingConfig, error) {
+	raw, err := os.ReadFile(path)
+	if err != nil {
+		return RoutingConfig{}, fmt.Errorf("read outbox routes: %w", err)
+	}
+	return ParseRoutingConfig(raw)
+}
+
+// ParseRoutingConfig decodes a YAML or JSON routing document (JSON is
+// YAML) and validates it.
+func ParseRoutingConfig(raw []byte) (RoutingConfig, error) {
+	var doc any
+	if err := yaml.Unmarshal(raw, &doc); err != nil {
+		return RoutingConfig{}, fmt.Errorf("parse outbox routes: %w", err)
+	}
+	asJSON, err := json.Marshal(doc)
+	if err != nil {
+		return RoutingConfig{}, fmt.Errorf("parse outbox routes: %w", err)
+	}
+	var c RoutingConfig
+	dec := json.NewDecoder(bytes.NewReader(asJSON))
+	dec.DisallowUnknownFields()
+	if err := dec.Decode(&c); err != nil {
+		return RoutingConfig{}, fmt.Errorf("parse outbox routes: %w", err)
+	}
+	return c, c.Validate()
+}
+
+// Validate checks that every target is one kind and every route names a
+// known target.
+func (c RoutingConfig) Validate() error {
+	for name, t := range c.Targets {
+		switch {
+		case name == DefaultTarget:
+			return fmt.Errorf("outbox routes: target %q is the processor's platform and cannot be redefined", name)
+		case (t.URL == "") == (t.Queue == ""):
+			return fmt.Errorf("outbox routes: target %q needs exactly one of url or queue", name)
+		}
+	}
+	for i, r := range c.Routes {
+		if r.Target == "" {
+			return fmt.Errorf("outbox routes: route %d has no target", i)
+		}
+		if _, ok := c.Targets[r.Target]; !ok && r.Target != DefaultTarget {
+			return fmt.Errorf("outbox routes: route %d: unknown target %q", i, r.Target)
+		}
+	}
+	return nil
+}
+
+// BuildSenders constructs a Sender per target. Queue publishers are opened
+// here, so this runs at startup with the queue backends registered.
+func BuildSenders(ctx context.Context, c RoutingConfig, httpTimeout time.Duration) (map[string]Sender, error) {
+	out := make(map[string]Sender, len(c.Targets))
+	for name, t := range c.Targets {
+		if t.URL != "" {
+			out[name] = NewHTTPDispatcher(strings.TrimRight(t.URL, "/"), t.AuthToken, httpTimeout)
+			continue
+		}
+		pub, err := queue.NewPublisher(ctx, common.QueueConfig{Name: name, URI: t.Queue, Connections: 1})
+		if err != nil {
+			return nil, fmt.Errorf("outbox target %q: %w", name, err)
+		}
+		out[name] = &QueueSender{Publisher: pub, PoolCode: t.PoolCode, MediationTarget: t.MediationTarget}
+	}
+	return out, nil
+}
+
+// Router picks each item's target.
+type Router struct {
+	routes  []Route
+	senders map[string]Sender
+}
+
+// NewRouter routes over senders, with def as DefaultTarget.
+func NewRouter(def Sender, routes []Route, senders map[string]Sender) *Router {
+	all := make(map[string]Sender, len(senders)+1)
+	for name, s := range senders {
+		all[name] = s
+	}
+	all[DefaultTarget] = def
+	return &Router{routes: routes, senders: all}
+}
+
+// Route returns the name and Sender of item's target.
+func (r *Router) Route(item Item) (string, Sender) {
+	var payload map[string]any
+	decoded := false
+	for _, rt := range r.routes {
+		if rt.ItemType != "" && rt.ItemType != item.ItemType {
+			continue
+		}
+		if rt.Pool != "" || len(rt.Payload) > 0 {
+			if !decoded {
+				_ = json.Unmarshal(item.Payload, &payload)
+				decoded = true
+			}
+			if !rt.matchesPayload(payload) {
+				continue
+			}
+		}
+		if s, ok := r.senders[rt.Target]; ok {
+			return rt.Target, s
+		}
+	}
+	return DefaultTarget, r.senders[DefaultTarget]
+}
+
+func (rt Route) matchesPayload(payload map[string]any) bool {
+	if rt.Pool != "" && payloadField(payload, "dispatchPoolId") != rt.Pool {
+		return false
+	}
+	for path, want := range rt.Payload {
+		if payloadField(payload, path) != want {
+			return false
+		}
+	}
+	return true
+}
+
+// payloadField renders the value at a dotted path as a string; "" when it
+// is absent or not a scalar.
+func payloadField(payload map[string]any, path string) string {
+	var v any = payload
+	for _, key := range strings.Split(path, ".") {
+		m, ok := v.(map[string]any)
+		if !ok {
+			return ""
+		}
+		v = m[key]
+	}
+	switch v := v.(type) {
+	case string:
+		return v
+	case float64, bool:
+		return fmt.Sprint(v)
+	}
+	return ""
+}
+
+// QueueSender publishes items to a queue as message pointers, the way the
+// platform publishes dispatch jobs: the router later POSTs
+// {"messageId": ...} to the mediation target. The message id is the
+// payload's id (a DISPATCH_JOB's own id) or the outbox id, and the target
+// is the payload's targetUrl or MediationTarget. The payload itself is not
+// carried; the receiving application looks it up by id.
+type QueueSender struct {
+	Publisher       queue.Publisher
+	PoolCode        string
+	MediationTarget string
+}
+
+// SendBatch publishes the batch in one call. The broker's message ids come
+// back as each outcome's PlatformID.
+func (s *QueueSender) SendBatch(ctx context.Context, items []Item) map[string]DispatchOutcome {
+	out := make(map[string]DispatchOutcome, len(items))
+	msgs := make([]common.Message, 0, len(items))
+	sent := make([]Item, 0, len(items))
+	for _, it := range items {
+		m, err := s.message(it)
+		if err != nil {
+			out[it.ID] = DispatchOutcome{Status: common.OutboxBadRequest, Message: err.Error()}
+			continue
+		}
+		msgs = append(msgs, m)
+		sent = append(sent, it)
+	}
+	if len(msgs) == 0 {
+		return out
+	}
+	ids, err := s.Publisher.PublishBatch(ctx, msgs)
+	if err != nil {
+		for _, it := range sent {
+			out[it.ID] = DispatchOutcome{Status: common.OutboxGatewayError, Message: "publish: " + err.Error()}
+		}
+		return out
+	}
+	for i, it := range sent {
+		o := DispatchOutcome{Status: common.OutboxSuccess}
+		if i < len(ids) {
+			o.PlatformID = ids[i]
+		}
+		out[it.ID] = o
+	}
+	return out
+}
+
+func (s *QueueSender) message(it Item) (common.Message, error) {
+	var p struct {
+		ID        string     `json:"id"`
+		TargetURL string     `json:"targetUrl"`
+		Mode      string     `json:"mode"`
+		ExpiresAt *time.Time `json:"expiresAt"`
+	}
+	if err := json.Unmarshal(it.Payload, &p); err != nil {
+		return common.Message{}, fmt.Errorf("payload: %w", err)
+	}
+	m := common.Message{
+		ID:              p.ID,
+		PoolCode:        s.PoolCode,
+		MediationType:   common.MediationTypeHTTP,
+		MediationTarget: p.TargetURL,
+		MessageGroupID:  it.MessageGroup,
+		DispatchMode:    common.ParseDispatchMode(p.Mode),
+		ExpiresAt:       p.ExpiresAt,
+	}
+	if m.ID == "" {
+		m.ID = it.ID
+	}
+	if m.MediationTarget == "" {
+		m.MediationTarget = s.MediationTarget
+	}
+	if m.MediationTarget == "" {
+		return common.Message{}, errors.New("no targetUrl in payload and no mediationTarget on the queue target")
+	}
+	return m, nil
+}
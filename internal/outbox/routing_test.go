@@ -0,0 +1,130 @@
+package outbox_test
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbox"
+)
+
+const routesYAML = `
+targets:
+  audit: {url: "https://audit.example.com/", authToken: secret}
+  pool-x: {queue: "sqs://pool-x", poolCode: POOL_X, mediationTarget: "https://app.example.com/jobs"}
+routes:
+  - {itemType: AUDIT_LOG, target: audit}
+  - {itemType: DISPATCH_JOB, pool: 0HZXEQ5Y8JY5Z, target: pool-x}
+  - payload: {meta.source: billing}
+    target: pool-x
+`
+
+// namedSender is a Sender that is only compared by identity.
+type namedSender struct{ name string }
+
+func (namedSender) SendBatch(context.Context, []outbox.Item) map[string]outbox.DispatchOutcome {
+	return nil
+}
+
+func TestRouterPicksFirstMatchingRoute(t *testing.T) {
+	cfg, err := outbox.ParseRoutingConfig([]byte(routesYAML))
+	require.NoError(t, err)
+	require.Len(t, cfg.Routes, 3)
+
+	def, audit, poolX := namedSender{"default"}, namedSender{"audit"}, namedSender{"pool-x"}
+	r := outbox.NewRouter(def, cfg.Routes, map[string]outbox.Sender{"audit": audit, "pool-x": poolX})
+	for _, tc := range []struct {
+		itemType common.OutboxItemType
+		payload  string
+		want     string
+	}{
+		{common.OutboxItemAuditLog, `{}`, "audit"},
+		{common.OutboxItemDispatchJob, `{"dispatchPoolId":"0HZXEQ5Y8JY5Z"}`, "pool-x"},
+		{common.OutboxItemDispatchJob, `{"dispatchPoolId":"other"}`, outbox.DefaultTarget},
+		{common.OutboxItemEvent, `{"meta":{"source":"billing"}}`, "pool-x"},
+		{common.OutboxItemEvent, `{"meta":{"source":"orders"}}`, outbox.DefaultTarget},
+		{common.OutboxItemEvent, `not json`, outbox.DefaultTarget},
+	} {
+		name, s := r.Route(outbox.Item{ItemType: tc.itemType, Payload: json.RawMessage(tc.payload)})
+		assert.Equal(t, tc.want, name, "%s %s", tc.itemType, tc.payload)
+		assert.Equal(t, tc.want, s.(namedSender).name)
+	}
+}
+
+func TestParseRoutingConfigRejects(t *testing.T) {
+	for name, raw := range map[string]string{
+		"unknown key":      `targets: {a: {uri: "https://x"}}`,
+		"both kinds":       `targets: {a: {url: "https://x", queue: "sqs://q"}}`,
+		"neither kind":     `targets: {a: {poolCode: X}}`,
+		"default redefine": `targets: {default: {url: "https://x"}}`,
+		"unknown target":   `routes: [{itemType: EVENT, target: nowhere}]`,
+		"no target":        `routes: [{itemType: EVENT}]`,
+	} {
+		_, err := outbox.ParseRoutingConfig([]byte(raw))
+		assert.Error(t, err, name)
+	}
+	_, err := outbox.ParseRoutingConfig([]byte(`routes: [{itemType: EVENT, target: default}]`))
+	assert.NoError(t, err, "routing back to the platform is allowed")
+}
+
+// fakePublisher records published messages and returns broker ids.
+type fakePublisher struct {
+	msgs []common.Message
+	err  error
+}
+
+func (*fakePublisher) Identifier() string { return "fake" }
+
+func (p *fakePublisher) Publish(ctx context.Context, m common.Message) (string, error) {
+	ids, err := p.PublishBatch(ctx, []common.Message{m})
+	if err != nil {
+		return "", err
+	}
+	return ids[0], nil
+}
+
+func (p *fakePublisher) PublishBatch(_ context.Context, msgs []common.Message) ([]string, error) {
+	if p.err != nil {
+		return nil, p.err
+	}
+	ids := make([]string, len(msgs))
+	for i, m := range msgs {
+		p.msgs = append(p.msgs, m)
+		ids[i] = "broker-" + m.ID
+	}
+	return ids, nil
+}
+
+func TestQueueSenderPublishesPointers(t *testing.T) {
+	pub := &fakePublisher{}
+	s := &outbox.QueueSender{Publisher: pub, PoolCode: "POOL_X", MediationTarget: "https://app.example.com/jobs"}
+	group := "g1"
+	out := s.SendBatch(context.Background(), []outbox.Item{
+		{ID: "ob1", MessageGroup: &group, Payload: json.RawMessage(`{"id":"job1","targetUrl":"https://hook.example.com","mode":"BLOCK_ON_ERROR"}`)},
+		{ID: "ob2", Payload: json.RawMessage(`{"kind":"x"}`)},
+		{ID: "ob3", Payload: json.RawMessage(`nope`)},
+	})
+
+	assert.Equal(t, outbox.DispatchOutcome{Status: common.OutboxSuccess, PlatformID: "broker-job1"}, out["ob1"])
+	assert.Equal(t, outbox.DispatchOutcome{Status: common.OutboxSuccess, PlatformID: "broker-ob2"}, out["ob2"])
+	assert.Equal(t, common.OutboxBadRequest, out["ob3"].Status)
+	require.Len(t, pub.msgs, 2)
+	assert.Equal(t, common.Message{
+		ID:              "job1",
+		PoolCode:        "POOL_X",
+		MediationType:   common.MediationTypeHTTP,
+		MediationTarget: "https://hook.example.com",
+		MessageGroupID:  &group,
+		DispatchMode:    common.DispatchBlockOnError,
+	}, pub.msgs[0])
+	assert.Equal(t, "https://app.example.com/jobs", pub.msgs[1].MediationTarget, "falls back to the target's mediationTarget")
+
+	pub.err = errors.New("broker down")
+	out = s.SendBatch(context.Background(), []outbox.Item{{ID: "ob4", Payload: json.RawMessage(`{}`)}})
+	assert.Equal(t, common.OutboxGatewayError, out["ob4"].Status, "publish failures are retryable")
+}
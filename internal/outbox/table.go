@@ -0,0 +1,262 @@
+package outbox
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"reflect"
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// TableConfig maps the processor onto an existing outbox table whose
+// names, status values or contents differ from the SDK migration, so a
+// legacy schema can be drained unmodified. The zero value is the SDK
+// table; FC_OUTBOX_TABLE_CONFIG supplies it as JSON:
+//
+//	{"table": "legacy.event_outbox",
+//	 "columns": {"id": "event_id", "payload": "body", "status": "state",
+//	             "type": "", "retryCount": "attempts", "updatedAt": ""},
+//	 "idType": "bigint",
+//	 "itemType": "EVENT",
+//	 "statusCodes": {"PENDING": "NEW", "IN_PROGRESS": "SENDING",
+//	                 "SUCCESS": "SENT", "BAD_REQUEST": "FAILED", ...},
+//	 "filter": "tenant = 'acme'"}
+//
+// Columns left out keep their SDK name; a column mapped to "" does not
+// exist in the table. Extra columns are never read or written.
+type TableConfig struct {
+	// Table is the table name, optionally schema-qualified.
+	Table   string  `json:"table,omitempty"`
+	Columns Columns `json:"columns"`
+	// IDType is the id column's SQL type when it is not text (bigint,
+	// uuid), so id lists bind as that type's array.
+	IDType string `json:"idType,omitempty"`
+	// ItemType is every row's type when the table has no type column.
+	ItemType common.OutboxItemType `json:"itemType,omitempty"`
+	// StatusCodes overrides the stored value per status. Statuses left
+	// out keep their integer code; SUCCESS, when mapped, is written on
+	// success instead of deleting the row. EXPIRED is optional: unmapped
+	// in a custom mapping, expired rows store BAD_REQUEST's value.
+	StatusCodes map[string]StatusValue `json:"statusCodes,omitempty"`
+	// Filter is an SQL predicate limiting which rows this processor
+	// claims, e.g. one tenant's rows in a shared table. It is operator
+	// configuration, spliced into the query verbatim.
+	Filter string `json:"filter,omitempty"`
+}
+
+// Columns names the outbox columns. Nil fields keep their SDK name; an
+// empty string marks a column the table lacks. id, payload, status and
+// created_at are required.
+type Columns struct {
+	ID           *string `json:"id,omitempty"`
+	Type         *string `json:"type,omitempty"`
+	MessageGroup *string `json:"messageGroup,omitempty"`
+	Payload      *string `json:"payload,omitempty"`
+	Status       *string `json:"status,omitempty"`
+	RetryCount   *string `json:"retryCount,omitempty"`
+	ErrorMessage *string `json:"errorMessage,omitempty"`
+	CreatedAt    *string `json:"createdAt,omitempty"`
+	UpdatedAt    *string `json:"updatedAt,omitempty"`
+	// PublishedID holds the platform id of a delivered row for two-phase
+	// publish. The SDK table has no such column: nil means absent.
+	PublishedID *string `json:"publishedId,omitempty"`
+}
+
+// StatusValue is a stored status: a JSON number for integer columns, a
+// JSON string for text ones.
+type StatusValue struct {
+	Raw  string
+	Text bool
+}
+
+// UnmarshalJSON accepts a number or a string.
+func (v *StatusValue) UnmarshalJSON(b []byte) error {
+	var s string
+	if err := json.Unmarshal(b, &s); err == nil {
+		*v = StatusValue{Raw: s, Text: true}
+		return nil
+	}
+	var n int64
+	if err := json.Unmarshal(b, &n); err != nil {
+		return fmt.Errorf("status value must be an integer or a string, got %s", b)
+	}
+	*v = StatusValue{Raw: strconv.FormatInt(n, 10)}
+	return nil
+}
+
+// MarshalJSON writes the value back as a number or a string.
+func (v StatusValue) MarshalJSON() ([]byte, error) {
+	if v.Text {
+		return json.Marshal(v.Raw)
+	}
+	return []byte(v.Raw), nil
+}
+
+// SQL renders the value as a literal.
+func (v StatusValue) SQL() string {
+	if v.Text {
+		return "'" + strings.ReplaceAll(v.Raw, "'", "''") + "'"
+	}
+	return v.Raw
+}
+
+// ParseTableConfig decodes and validates FC_OUTBOX_TABLE_CONFIG. Empty
+// raw is the SDK table.
+func ParseTableConfig(raw string) (TableConfig, error) {
+	var c TableConfig
+	if strings.TrimSpace(raw) == "" {
+		return c, nil
+	}
+	dec := json.NewDecoder(strings.NewReader(raw))
+	dec.DisallowUnknownFields()
+	if err := dec.Decode(&c); err != nil {
+		return TableConfig{}, fmt.Errorf("parse outbox table config: %w", err)
+	}
+	if err := c.Validate(); err != nil {
+		return TableConfig{}, err
+	}
+	return c, nil
+}
+
+var (
+	identRE   = regexp.MustCompile(`^[A-Za-z_][A-Za-z0-9_$]*(\.[A-Za-z_][A-Za-z0-9_$]*)?$`)
+	sqlTypeRE = regexp.MustCompile(`^[A-Za-z][A-Za-z0-9_ ]*$`)
+)
+
+// writtenStatuses are the statuses the processor stores. SUCCESS is
+// optional: unmapped, a delivered row is deleted.
+var writtenStatuses = []common.OutboxStatus{
+	common.OutboxPending, common.OutboxInProgress, common.OutboxBadRequest,
+	common.OutboxInternalError, common.OutboxUnauthorized, common.OutboxForbidden,
+	common.OutboxGatewayError,
+}
+
+// Validate checks names against SQL injection and the mapping for
+// completeness.
+func (c TableConfig) Validate() error {
+	if !identRE.MatchString(c.TableName()) {
+		return fmt.Errorf("outbox table %q is not a valid identifier", c.TableName())
+	}
+	for field, col := range c.columnNames() {
+		if col != "" && !identRE.MatchString(col) {
+			return fmt.Errorf("outbox column %s: %q is not a valid identifier", field, col)
+		}
+	}
+	for _, required := range []string{"id", "payload", "status", "createdAt"} {
+		if c.columnNames()[required] == "" {
+			return fmt.Errorf("outbox column %s is required", required)
+		}
+	}
+	if c.Names().Type == "" && c.ItemType == "" {
+		return errors.New("outbox table has no type column: set itemType")
+	}
+	if c.IDType != "" && !sqlTypeRE.MatchString(c.IDType) {
+		return fmt.Errorf("outbox idType %q is not a valid type name", c.IDType)
+	}
+	anyText := false
+	for name, v := range c.StatusCodes {
+		if !isOutboxStatusName(name) {
+			return fmt.Errorf("outbox statusCodes: unknown status %q", name)
+		}
+		anyText = anyText || v.Text
+	}
+	if anyText {
+		// Integer defaults cannot stand in for a text column.
+		for _, s := range writtenStatuses {
+			if _, ok := c.StatusCodes[s.String()]; !ok {
+				return fmt.Errorf("outbox statusCodes: text status values need every status mapped, missing %s", s)
+			}
+		}
+	}
+	if c.Status(common.OutboxPending) == c.Status(common.OutboxInProgress) {
+		return errors.New("outbox statusCodes: PENDING and IN_PROGRESS must differ")
+	}
+	return nil
+}
+
+// IsCustom reports whether c departs from the SDK table. A published-id
+// column alone does not: InitSchema adds it to the SDK table.
+func (c TableConfig) IsCustom() bool {
+	c.Columns.PublishedID = nil
+	return !reflect.DeepEqual(c, TableConfig{})
+}
+
+// TableName is the configured table, or outbox_messages.
+func (c TableConfig) TableName() string {
+	if c.Table == "" {
+		return "outbox_messages"
+	}
+	return c.Table
+}
+
+// Col resolves a column: nil is the SDK name, "" an absent column.
+func (c TableConfig) Col(name *string, sdk string) string {
+	if name == nil {
+		return sdk
+	}
+	return *name
+}
+
+// Status renders a status as the table stores it.
+func (c TableConfig) Status(s common.OutboxStatus) string {
+	if v, ok := c.StatusCodes[s.String()]; ok {
+		return v.SQL()
+	}
+	if s == common.OutboxExpired && len(c.StatusCodes) > 0 {
+		return c.Status(common.OutboxBadRequest)
+	}
+	return strconv.Itoa(s.Code())
+}
+
+// DeletesOnSuccess reports whether delivered rows are deleted (the SDK
+// model) rather than marked SUCCESS.
+func (c TableConfig) DeletesOnSuccess() bool {
+	_, ok := c.StatusCodes[common.OutboxSuccess.String()]
+	return !ok
+}
+
+// ColumnNames are resolved column names; "" is a column the table lacks.
+type ColumnNames struct {
+	ID, Type, MessageGroup, Payload, Status string
+	RetryCount, ErrorMessage               string
+	CreatedAt, UpdatedAt, PublishedID      string
+}
+
+// Names resolves the configured columns.
+func (c TableConfig) Names() ColumnNames {
+	cols := c.Columns
+	return ColumnNames{
+		ID:           c.Col(cols.ID, "id"),
+		Type:         c.Col(cols.Type, "type"),
+		MessageGroup: c.Col(cols.MessageGroup, "message_group"),
+		Payload:      c.Col(cols.Payload, "payload"),
+		Status:       c.Col(cols.Status, "status"),
+		RetryCount:   c.Col(cols.RetryCount, "retry_count"),
+		ErrorMessage: c.Col(cols.ErrorMessage, "error_message"),
+		CreatedAt:    c.Col(cols.CreatedAt, "created_at"),
+		UpdatedAt:    c.Col(cols.UpdatedAt, "updated_at"),
+		PublishedID:  c.Col(cols.PublishedID, ""),
+	}
+}
+
+func (c TableConfig) columnNames() map[string]string {
+	n := c.Names()
+	return map[string]string{
+		"id": n.ID, "type": n.Type, "messageGroup": n.MessageGroup, "payload": n.Payload,
+		"status": n.Status, "retryCount": n.RetryCount, "errorMessage": n.ErrorMessage,
+		"createdAt": n.CreatedAt, "updatedAt": n.UpdatedAt, "publishedId": n.PublishedID,
+	}
+}
+
+func isOutboxStatusName(name string) bool {
+	for _, s := range append(writtenStatuses, common.OutboxSuccess, common.OutboxExpired) {
+		if s.String() == name {
+			return true
+		}
+	}
+	return false
+}
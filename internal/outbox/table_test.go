@@ -0,0 +1,58 @@
+package outbox_test
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbox"
+)
+
+func TestParseTableConfig(t *testing.T) {
+	sdk, err := outbox.ParseTableConfig("")
+	require.NoError(t, err)
+	assert.False(t, sdk.IsCustom())
+	assert.Equal(t, "outbox_messages", sdk.TableName())
+	assert.Equal(t, "9", sdk.Status(common.OutboxInProgress))
+	assert.Equal(t, "7", sdk.Status(common.OutboxExpired))
+	assert.True(t, sdk.DeletesOnSuccess())
+	assert.Empty(t, sdk.Names().PublishedID, "the SDK table has no published-id column")
+
+	published, err := outbox.ParseTableConfig(`{"columns":{"publishedId":"published_id"}}`)
+	require.NoError(t, err)
+	assert.False(t, published.IsCustom(), "a published-id column alone keeps the SDK table")
+	assert.Equal(t, "published_id", published.Names().PublishedID)
+
+	c, err := outbox.ParseTableConfig(`{"columns":{"payload":"body"},"statusCodes":{"PENDING":10,"IN_PROGRESS":11}}`)
+	require.NoError(t, err)
+	assert.True(t, c.IsCustom())
+	assert.Equal(t, "body", c.Names().Payload)
+	assert.Equal(t, "message_group", c.Names().MessageGroup, "unmapped columns keep their SDK name")
+	assert.Equal(t, "11", c.Status(common.OutboxInProgress))
+	assert.Equal(t, "2", c.Status(common.OutboxBadRequest), "unmapped integer statuses keep their code")
+
+	text, err := outbox.ParseTableConfig(`{"statusCodes":{"PENDING":"it's new","IN_PROGRESS":"busy","BAD_REQUEST":"x",
+		"INTERNAL_ERROR":"x","UNAUTHORIZED":"x","FORBIDDEN":"x","GATEWAY_ERROR":"x"}}`)
+	require.NoError(t, err)
+	assert.Equal(t, `'it''s new'`, text.Status(common.OutboxPending))
+	assert.Equal(t, `'x'`, text.Status(common.OutboxExpired), "unmapped EXPIRED stores BAD_REQUEST's value")
+
+	for name, raw := range map[string]string{
+		"bad json":             `{"table":`,
+		"unknown key":          `{"tabel":"x"}`,
+		"injected table":       `{"table":"outbox; DROP TABLE users"}`,
+		"injected column":      `{"columns":{"status":"status = 0 OR 1"}}`,
+		"missing payload":      `{"columns":{"payload":""}}`,
+		"no type, no itemType": `{"columns":{"type":""}}`,
+		"bad idType":           `{"idType":"bigint); --"}`,
+		"unknown status":       `{"statusCodes":{"DONE":1}}`,
+		"partial text mapping": `{"statusCodes":{"PENDING":"NEW","IN_PROGRESS":"SENDING"}}`,
+		"ambiguous claim":      `{"statusCodes":{"IN_PROGRESS":0}}`,
+		"non-scalar status":    `{"statusCodes":{"PENDING":[0]}}`,
+	} {
+		_, err := outbox.ParseTableConfig(raw)
+		assert.Error(t, err, name)
+	}
+}
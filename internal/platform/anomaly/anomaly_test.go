@@ -0,0 +1,57 @@
+package anomaly
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestBaselineUpdate(t *testing.T) {
+	b := Baseline{}.Update(10, 0.1)
+	assert.Equal(t, Baseline{Mean: 10, Samples: 1}, b, "the first sample is the mean")
+
+	b = b.Update(20, 0.1)
+	assert.InDelta(t, 11, b.Mean, 1e-9)
+	assert.InDelta(t, 9, b.Variance, 1e-9)
+	assert.Equal(t, 2, b.Samples)
+
+	// A steady value pulls the mean in and the variance down.
+	for range 200 {
+		b = b.Update(50, 0.1)
+	}
+	assert.InDelta(t, 50, b.Mean, 1e-6)
+	assert.InDelta(t, 0, b.Variance, 1e-6)
+}
+
+func TestBaselineZScore(t *testing.T) {
+	b := Baseline{Mean: 11, Variance: 9, Samples: 2}
+	assert.InDelta(t, 2, b.ZScore(17, 1), 1e-9)
+	assert.InDelta(t, -1, b.ZScore(8, 1), 1e-9)
+	assert.InDelta(t, 0.1, b.ZScore(11.5, 5), 1e-9, "the floor caps a tight spread")
+	assert.Zero(t, Baseline{}.ZScore(3, 0), "no spread and no floor scores nothing")
+}
+
+func TestSettingThresholdAndSuppressed(t *testing.T) {
+	now := time.Now()
+	var none *Setting
+	assert.Equal(t, DefaultSensitivity, none.Threshold())
+	assert.False(t, none.Suppressed(now))
+
+	s := NewSetting(EntityEventType, "orders:order:created")
+	assert.Equal(t, DefaultSensitivity, s.Threshold())
+	assert.False(t, s.Suppressed(now))
+
+	five := 5.0
+	s.Sensitivity = &five
+	assert.Equal(t, 5.0, s.Threshold())
+
+	until := now.Add(time.Hour)
+	s.SnoozedUntil = &until
+	assert.True(t, s.Suppressed(now))
+	assert.False(t, s.Suppressed(until), "a snooze ends at its instant")
+
+	s.SnoozedUntil = nil
+	s.Enabled = false
+	assert.True(t, s.Suppressed(now))
+}
@@ -0,0 +1,103 @@
+// Package api wires the HTTP routes for ingestion and delivery anomalies
+// via huma.
+package api
+
+import (
+	"context"
+	"net/http"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// State bundles the dependencies.
+type State struct {
+	Repo *anomaly.Repository
+	UoW  *usecasepgx.UnitOfWork
+}
+
+const tag = "anomalies"
+
+// Register mounts the anomaly endpoints. All are anchor-only.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "listAnomalies", "/api/anomalies", "List ingestion and delivery anomalies", s.list)
+	apiroute.Get(g, "listAnomalySettings", "/api/anomaly-settings", "List per-entity anomaly detection settings", s.listSettings)
+	apiroute.Put(g, "updateAnomalySetting", "/api/anomaly-settings/{entityKind}/{entityKey}", "Set an event type's or subscription's anomaly sensitivity or snooze", http.StatusOK, s.updateSetting)
+}
+
+type listInput struct {
+	Status string `query:"status" doc:"OPEN (default) or RESOLVED"`
+	Limit  int    `query:"limit" doc:"Maximum anomalies to return (default 100, max 500)"`
+}
+
+func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[AnomalyListResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	status := anomaly.StatusOpen
+	if in.Status != "" {
+		var ok bool
+		if status, ok = anomaly.ParseStatus(in.Status); !ok {
+			return nil, httperror.BadRequest("INVALID_STATUS", "status must be OPEN or RESOLVED")
+		}
+	}
+	limit := in.Limit
+	if limit <= 0 {
+		limit = 100
+	}
+	limit = min(limit, 500)
+	rows, err := s.Repo.FindAnomalies(ctx, status, int32(limit))
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_anomalies failed", err)
+	}
+	out := apicommon.MapSlice(rows, anomalyFromEntity)
+	return &apicommon.Out[AnomalyListResponse]{Body: AnomalyListResponse{Anomalies: out}}, nil
+}
+
+func (s *State) listSettings(ctx context.Context, _ *struct{}) (*apicommon.Out[AnomalySettingListResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	rows, err := s.Repo.FindAllSettings(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_all_settings failed", err)
+	}
+	out := apicommon.MapSlice(rows, settingFromEntity)
+	return &apicommon.Out[AnomalySettingListResponse]{Body: AnomalySettingListResponse{Settings: out}}, nil
+}
+
+type updateSettingInput struct {
+	EntityKind string `path:"entityKind" doc:"EVENT_TYPE or SUBSCRIPTION"`
+	EntityKey  string `path:"entityKey" doc:"Event type code or subscription id"`
+	Body       UpdateAnomalySettingRequest
+}
+
+func (s *State) updateSetting(ctx context.Context, in *updateSettingInput) (*apicommon.Out[AnomalySettingResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	cmd := in.Body.toCommand(in.EntityKind, in.EntityKey)
+	event, err := usecaseop.Run(ctx, s.UoW, operations.UpdateAnomalySetting(s.Repo), cmd, ec)
+	if err != nil {
+		return nil, err
+	}
+	setting, err := s.Repo.FindSettingByEntity(ctx, event.EntityKind, event.EntityKey)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "post-update reload failed", err)
+	}
+	if setting == nil {
+		return nil, httperror.NotFound("AnomalySetting", event.SettingID)
+	}
+	return &apicommon.Out[AnomalySettingResponse]{Body: settingFromEntity(setting)}, nil
+}
@@ -0,0 +1,108 @@
+// dto.go contains the wire-format types for the anomaly API.
+package api
+
+import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+)
+
+// UpdateAnomalySettingRequest is the wire body for
+// PUT /api/anomaly-settings/{entityKind}/{entityKey}. It replaces the
+// entity's setting whole.
+type UpdateAnomalySettingRequest struct {
+	Sensitivity  *float64   `json:"sensitivity,omitempty" doc:"Z-score a window's value must reach to raise an anomaly, 2-10 (default 3). Lower is more sensitive."`
+	Enabled      bool       `json:"enabled" doc:"False stops the entity raising anomalies; its baseline keeps learning"`
+	SnoozedUntil *time.Time `json:"snoozedUntil,omitempty" doc:"Raise nothing for the entity until then"`
+}
+
+func (r UpdateAnomalySettingRequest) toCommand(kind, key string) operations.UpdateSettingCommand {
+	return operations.UpdateSettingCommand{
+		EntityKind:   kind,
+		EntityKey:    key,
+		Sensitivity:  r.Sensitivity,
+		Enabled:      r.Enabled,
+		SnoozedUntil: r.SnoozedUntil,
+	}
+}
+
+// AnomalySettingResponse mirrors anomaly.Setting.
+type AnomalySettingResponse struct {
+	ID           string           `json:"id"`
+	EntityKind   string           `json:"entityKind" doc:"EVENT_TYPE or SUBSCRIPTION"`
+	EntityKey    string           `json:"entityKey" doc:"Event type code or subscription id"`
+	Sensitivity  *float64         `json:"sensitivity,omitempty" doc:"Z-score threshold; absent means the default"`
+	Enabled      bool             `json:"enabled"`
+	SnoozedUntil *httpcompat.Time `json:"snoozedUntil,omitempty"`
+	UpdatedBy    *string          `json:"updatedBy,omitempty" doc:"Principal that last changed the setting"`
+	UpdatedAt    httpcompat.Time  `json:"updatedAt"`
+}
+
+func settingFromEntity(s *anomaly.Setting) AnomalySettingResponse {
+	var snoozed *httpcompat.Time
+	if s.SnoozedUntil != nil {
+		v := jsontime.New(*s.SnoozedUntil)
+		snoozed = &v
+	}
+	return AnomalySettingResponse{
+		ID:           s.ID,
+		EntityKind:   string(s.EntityKind),
+		EntityKey:    s.EntityKey,
+		Sensitivity:  s.Sensitivity,
+		Enabled:      s.Enabled,
+		SnoozedUntil: snoozed,
+		UpdatedBy:    s.UpdatedBy,
+		UpdatedAt:    jsontime.New(s.UpdatedAt),
+	}
+}
+
+// AnomalySettingListResponse is the wire shape for GET /api/anomaly-settings.
+type AnomalySettingListResponse struct {
+	Settings []AnomalySettingResponse `json:"settings"`
+}
+
+// AnomalyResponse mirrors anomaly.Anomaly.
+type AnomalyResponse struct {
+	ID         string           `json:"id"`
+	EntityKind string           `json:"entityKind" doc:"EVENT_TYPE or SUBSCRIPTION"`
+	EntityKey  string           `json:"entityKey" doc:"Event type code or subscription id"`
+	Kind       string           `json:"kind" doc:"INGESTION_DROP or FAILURE_SPIKE"`
+	Status     string           `json:"status" doc:"OPEN or RESOLVED"`
+	Message    string           `json:"message"`
+	Observed   float64          `json:"observed" doc:"The window's value: events ingested, or the failure rate (0-1)"`
+	Expected   float64          `json:"expected" doc:"The baseline's mean for the same measure"`
+	ZScore     float64          `json:"zScore" doc:"Standard deviations between observed and expected"`
+	RaisedAt   httpcompat.Time  `json:"raisedAt"`
+	LastSeenAt httpcompat.Time  `json:"lastSeenAt"`
+	ResolvedAt *httpcompat.Time `json:"resolvedAt,omitempty"`
+}
+
+func anomalyFromEntity(a *anomaly.Anomaly) AnomalyResponse {
+	var resolved *httpcompat.Time
+	if a.ResolvedAt != nil {
+		v := jsontime.New(*a.ResolvedAt)
+		resolved = &v
+	}
+	return AnomalyResponse{
+		ID:         a.ID,
+		EntityKind: string(a.EntityKind),
+		EntityKey:  a.EntityKey,
+		Kind:       string(a.Kind),
+		Status:     string(a.Status),
+		Message:    a.Message,
+		Observed:   a.Observed,
+		Expected:   a.Expected,
+		ZScore:     a.ZScore,
+		RaisedAt:   jsontime.New(a.RaisedAt),
+		LastSeenAt: jsontime.New(a.LastSeenAt),
+		ResolvedAt: resolved,
+	}
+}
+
+// AnomalyListResponse is the wire shape for GET /api/anomalies.
+type AnomalyListResponse struct {
+	Anomalies []AnomalyResponse `json:"anomalies"`
+}
@@ -0,0 +1,173 @@
+// Package anomaly flags unusual ingestion and delivery rates: an event type
+// that is normally busy receiving nothing, or a subscription whose
+// delivery failure rate spikes beyond its norm. The stream processor's
+// detector (stream.AnomalyDetector) keeps an exponentially weighted mean
+// and variance per entity and raises an anomaly when a window's value is
+// too many standard deviations from it. Sensitivity and snoozing are set
+// per entity through a use case, so the audit log records who muted what.
+package anomaly
+
+import (
+	"math"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// EntityKind is what a baseline, setting or anomaly is about.
+type EntityKind string
+
+const (
+	// EntityEventType is keyed by event type code; its rate is events
+	// ingested per window.
+	EntityEventType EntityKind = "EVENT_TYPE"
+	// EntitySubscription is keyed by subscription id; its rate is the
+	// share of delivery attempts in a window that failed.
+	EntitySubscription EntityKind = "SUBSCRIPTION"
+)
+
+// ParseEntityKind is the strict parser; ok is false for anything else.
+func ParseEntityKind(s string) (EntityKind, bool) {
+	switch k := EntityKind(s); k {
+	case EntityEventType, EntitySubscription:
+		return k, true
+	}
+	return "", false
+}
+
+// Kind is what went wrong.
+type Kind string
+
+const (
+	KindIngestionDrop Kind = "INGESTION_DROP"
+	KindFailureSpike  Kind = "FAILURE_SPIKE"
+)
+
+// Status is an anomaly's lifecycle state.
+type Status string
+
+const (
+	StatusOpen     Status = "OPEN"
+	StatusResolved Status = "RESOLVED"
+)
+
+// ParseStatus is the strict parser; ok is false for anything else.
+func ParseStatus(s string) (Status, bool) {
+	switch st := Status(s); st {
+	case StatusOpen, StatusResolved:
+		return st, true
+	}
+	return "", false
+}
+
+// DefaultSensitivity is the z-score an entity's value must reach when its
+// setting leaves sensitivity unset.
+const DefaultSensitivity = 3.0
+
+// Sensitivity bounds: below 2 a healthy entity warns every few hours,
+// above 10 nothing short of an outage does.
+const (
+	MinSensitivity = 2.0
+	MaxSensitivity = 10.0
+)
+
+// Setting is the aggregate root: one entity's detector overrides.
+type Setting struct {
+	ID         string     `json:"id"`
+	EntityKind EntityKind `json:"entityKind"`
+	EntityKey  string     `json:"entityKey"`
+	// Sensitivity is the z-score threshold; nil uses DefaultSensitivity.
+	Sensitivity *float64 `json:"sensitivity,omitempty"`
+	// Enabled false stops the entity raising anomalies altogether.
+	Enabled      bool       `json:"enabled"`
+	SnoozedUntil *time.Time `json:"snoozedUntil,omitempty"`
+	UpdatedBy    *string    `json:"updatedBy,omitempty"`
+	CreatedAt    time.Time  `json:"createdAt"`
+	UpdatedAt    time.Time  `json:"updatedAt"`
+}
+
+// IDStr satisfies usecase.HasID.
+func (s Setting) IDStr() string { return s.ID }
+
+// NewSetting constructs an entity's setting with the defaults.
+func NewSetting(kind EntityKind, key string) *Setting {
+	now := time.Now().UTC()
+	return &Setting{
+		ID:         tsid.Generate(tsid.AnomalySetting),
+		EntityKind: kind,
+		EntityKey:  key,
+		Enabled:    true,
+		CreatedAt:  now,
+		UpdatedAt:  now,
+	}
+}
+
+// Threshold is the z-score the entity's value must reach. A nil setting
+// is the defaults.
+func (s *Setting) Threshold() float64 {
+	if s == nil || s.Sensitivity == nil {
+		return DefaultSensitivity
+	}
+	return *s.Sensitivity
+}
+
+// Suppressed reports whether the entity may not raise anomalies at now:
+// it is disabled or snoozed. It still learns its baseline meanwhile.
+func (s *Setting) Suppressed(now time.Time) bool {
+	if s == nil {
+		return false
+	}
+	return !s.Enabled || (s.SnoozedUntil != nil && now.Before(*s.SnoozedUntil))
+}
+
+// Anomaly is one raised anomaly, written by the detector.
+type Anomaly struct {
+	ID         string     `json:"id"`
+	EntityKind EntityKind `json:"entityKind"`
+	EntityKey  string     `json:"entityKey"`
+	Kind       Kind       `json:"kind"`
+	Status     Status     `json:"status"`
+	Message    string     `json:"message"`
+	// Observed and Expected are the window's value and the baseline mean:
+	// events per window for an event type, a 0–1 failure rate for a
+	// subscription.
+	Observed   float64    `json:"observed"`
+	Expected   float64    `json:"expected"`
+	ZScore     float64    `json:"zScore"`
+	RaisedAt   time.Time  `json:"raisedAt"`
+	LastSeenAt time.Time  `json:"lastSeenAt"`
+	ResolvedAt *time.Time `json:"resolvedAt,omitempty"`
+}
+
+// Baseline is an entity's exponentially weighted mean and variance.
+type Baseline struct {
+	Mean     float64
+	Variance float64
+	Samples  int
+}
+
+// Update folds x in with smoothing factor alpha (0 < alpha ≤ 1). The first
+// sample becomes the mean.
+func (b Baseline) Update(x, alpha float64) Baseline {
+	if b.Samples == 0 {
+		return Baseline{Mean: x, Samples: 1}
+	}
+	diff := x - b.Mean
+	incr := alpha * diff
+	return Baseline{
+		Mean:     b.Mean + incr,
+		Variance: (1 - alpha) * (b.Variance + diff*incr),
+		Samples:  b.Samples + 1,
+	}
+}
+
+// ZScore is how many standard deviations x is from the mean. The standard
+// deviation is at least floor, so a baseline that has never varied does
+// not turn the smallest change into an anomaly.
+func (b Baseline) ZScore(x, floor float64) float64 {
+	sd := math.Max(math.Sqrt(b.Variance), floor)
+	if sd == 0 {
+		return 0
+	}
+	return (x - b.Mean) / sd
+}
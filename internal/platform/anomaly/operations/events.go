@@ -0,0 +1,57 @@
+package operations
+
+import (
+	"encoding/json"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+const (
+	AnomalySettingUpdatedType = "platform:admin:anomaly-setting:updated"
+	Source                    = "platform:admin"
+)
+
+func subjectFor(id string) string { return "platform.anomaly-setting." + id }
+
+// AnomalySettingUpdated is emitted when an entity's detector sensitivity,
+// enablement or snooze changes.
+type AnomalySettingUpdated struct {
+	Metadata     usecase.EventMetadata
+	SettingID    string
+	EntityKind   anomaly.EntityKind
+	EntityKey    string
+	Sensitivity  *float64
+	Enabled      bool
+	SnoozedUntil *time.Time
+}
+
+func (e AnomalySettingUpdated) EventID() string       { return e.Metadata.EventID }
+func (e AnomalySettingUpdated) EventType() string     { return AnomalySettingUpdatedType }
+func (e AnomalySettingUpdated) SpecVersion() string   { return "1.0" }
+func (e AnomalySettingUpdated) Source() string        { return Source }
+func (e AnomalySettingUpdated) Subject() string       { return subjectFor(e.SettingID) }
+func (e AnomalySettingUpdated) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e AnomalySettingUpdated) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e AnomalySettingUpdated) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e AnomalySettingUpdated) CausationID() string   { return e.Metadata.CausationID }
+func (e AnomalySettingUpdated) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e AnomalySettingUpdated) MessageGroup() string {
+	return "platform:anomaly-setting:" + string(e.EntityKind) + ":" + e.EntityKey
+}
+func (e AnomalySettingUpdated) ToDataJSON() ([]byte, error) {
+	var snoozedUntil *string
+	if e.SnoozedUntil != nil {
+		s := e.SnoozedUntil.UTC().Format(time.RFC3339)
+		snoozedUntil = &s
+	}
+	return json.Marshal(struct {
+		SettingID    string             `json:"settingId"`
+		EntityKind   anomaly.EntityKind `json:"entityKind"`
+		EntityKey    string             `json:"entityKey"`
+		Sensitivity  *float64           `json:"sensitivity"`
+		Enabled      bool               `json:"enabled"`
+		SnoozedUntil *string            `json:"snoozedUntil"`
+	}{e.SettingID, e.EntityKind, e.EntityKey, e.Sensitivity, e.Enabled, snoozedUntil})
+}
@@ -0,0 +1,107 @@
+//go:build integration
+
+package operations_test
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+func TestMain(m *testing.M) { testpg.RunMain(m) }
+
+// runAuthorized drives op through the full use-case envelope as an anchor
+// principal; the anchor check itself is controller-gated.
+func runAuthorized[C any, E usecase.DomainEvent](
+	uow *usecasepgx.UnitOfWork, op usecaseop.Operation[C, E], cmd C,
+) (E, error) {
+	return usecaseop.Run(testpg.AnchorCtx(), uow, op, cmd, testpg.TestEC())
+}
+
+// seedEventType inserts a bare event type row to aim settings at. Codes
+// are hand-unique per test: the fixture never truncates between tests.
+func seedEventType(t *testing.T, id, code string) {
+	t.Helper()
+	_, err := testpg.Pool(t).Exec(context.Background(),
+		`INSERT INTO msg_event_types (id, code, name, application, subdomain, aggregate)
+		 VALUES ($1, $2, $2, 'test', 'anomaly', 'setting') ON CONFLICT DO NOTHING`, id, code)
+	require.NoError(t, err)
+}
+
+func TestUpdateAnomalySetting_HappyPath(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := anomaly.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seedEventType(t, "evt_anSetting0001", "test:anomaly:setting:created")
+
+	five := 5.0
+	until := time.Now().Add(2 * time.Hour)
+	ev, err := runAuthorized(uow, operations.UpdateAnomalySetting(repo), operations.UpdateSettingCommand{
+		EntityKind: "EVENT_TYPE", EntityKey: " test:anomaly:setting:created ",
+		Sensitivity: &five, Enabled: true, SnoozedUntil: &until,
+	})
+	require.NoError(t, err)
+	assert.Equal(t, "test:anomaly:setting:created", ev.EntityKey)
+
+	got, err := repo.FindSettingByEntity(ctx, anomaly.EntityEventType, "test:anomaly:setting:created")
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	assert.Equal(t, ev.SettingID, got.ID)
+	assert.Equal(t, 5.0, got.Threshold())
+	assert.True(t, got.Suppressed(time.Now()))
+	require.NotNil(t, got.UpdatedBy)
+	assert.Equal(t, testpg.TestEC().PrincipalID, *got.UpdatedBy)
+
+	// A second PUT replaces the setting whole, in place.
+	ev2, err := runAuthorized(uow, operations.UpdateAnomalySetting(repo), operations.UpdateSettingCommand{
+		EntityKind: "EVENT_TYPE", EntityKey: "test:anomaly:setting:created", Enabled: true,
+	})
+	require.NoError(t, err)
+	assert.Equal(t, ev.SettingID, ev2.SettingID)
+	got, err = repo.FindSettingByEntity(ctx, anomaly.EntityEventType, "test:anomaly:setting:created")
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	assert.Nil(t, got.Sensitivity)
+	assert.Nil(t, got.SnoozedUntil)
+	assert.False(t, got.Suppressed(time.Now()))
+}
+
+func TestUpdateAnomalySetting_Validation(t *testing.T) {
+	t.Parallel()
+	repo := anomaly.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	one, eleven := 1.0, 11.0
+	past := time.Now().Add(-time.Minute)
+
+	cases := []struct {
+		name string
+		cmd  operations.UpdateSettingCommand
+		kind usecase.Kind
+		code string
+	}{
+		{"bad kind", operations.UpdateSettingCommand{EntityKind: "CLIENT", EntityKey: "x", Enabled: true}, usecase.KindValidation, "INVALID_ENTITY_KIND"},
+		{"no key", operations.UpdateSettingCommand{EntityKind: "EVENT_TYPE", EntityKey: " ", Enabled: true}, usecase.KindValidation, "ENTITY_KEY_REQUIRED"},
+		{"too sensitive", operations.UpdateSettingCommand{EntityKind: "EVENT_TYPE", EntityKey: "x", Sensitivity: &one, Enabled: true}, usecase.KindValidation, "INVALID_SENSITIVITY"},
+		{"too dull", operations.UpdateSettingCommand{EntityKind: "EVENT_TYPE", EntityKey: "x", Sensitivity: &eleven, Enabled: true}, usecase.KindValidation, "INVALID_SENSITIVITY"},
+		{"past snooze", operations.UpdateSettingCommand{EntityKind: "EVENT_TYPE", EntityKey: "x", Enabled: true, SnoozedUntil: &past}, usecase.KindValidation, "SNOOZE_IN_PAST"},
+		{"unknown subscription", operations.UpdateSettingCommand{EntityKind: "SUBSCRIPTION", EntityKey: "sub_anMissing001", Enabled: true}, usecase.KindNotFound, "Subscription_NOT_FOUND"},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			t.Parallel()
+			_, err := runAuthorized(uow, operations.UpdateAnomalySetting(repo), tc.cmd)
+			testpg.RequireUsecaseError(t, err, tc.kind, tc.code)
+		})
+	}
+}
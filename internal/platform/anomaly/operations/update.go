@@ -0,0 +1,99 @@
+package operations
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// UpdateSettingCommand is the input DTO. It replaces the entity's setting
+// whole: a nil Sensitivity restores the default, a nil SnoozedUntil ends a
+// snooze.
+type UpdateSettingCommand struct {
+	// EntityKind is EVENT_TYPE or SUBSCRIPTION.
+	EntityKind string `json:"entityKind"`
+	// EntityKey is the event type code or subscription id.
+	EntityKey    string     `json:"entityKey"`
+	Sensitivity  *float64   `json:"sensitivity,omitempty"`
+	Enabled      bool       `json:"enabled"`
+	SnoozedUntil *time.Time `json:"snoozedUntil,omitempty"`
+}
+
+// UpdateAnomalySetting sets an event type's or subscription's detector
+// sensitivity, turns its anomalies off or snoozes them. Emits
+// [AnomalySettingUpdated].
+func UpdateAnomalySetting(repo *anomaly.Repository) usecaseop.Operation[UpdateSettingCommand, AnomalySettingUpdated] {
+	return usecaseop.Operation[UpdateSettingCommand, AnomalySettingUpdated]{
+		Name: "UpdateAnomalySetting",
+		Validate: func(_ context.Context, cmd UpdateSettingCommand) error {
+			if _, ok := anomaly.ParseEntityKind(cmd.EntityKind); !ok {
+				return usecase.Validation("INVALID_ENTITY_KIND", "entityKind must be EVENT_TYPE or SUBSCRIPTION")
+			}
+			if strings.TrimSpace(cmd.EntityKey) == "" {
+				return usecase.Validation("ENTITY_KEY_REQUIRED", "entityKey is required")
+			}
+			if s := cmd.Sensitivity; s != nil && (*s < anomaly.MinSensitivity || *s > anomaly.MaxSensitivity) {
+				return usecase.Validation("INVALID_SENSITIVITY",
+					fmt.Sprintf("sensitivity must be between %g and %g", anomaly.MinSensitivity, anomaly.MaxSensitivity))
+			}
+			if cmd.SnoozedUntil != nil && !cmd.SnoozedUntil.After(time.Now()) {
+				return usecase.Validation("SNOOZE_IN_PAST", "snoozedUntil must be in the future")
+			}
+			return nil
+		},
+		// Anomaly settings are anchor-only; the controller enforces it.
+		Authorize: usecaseop.Public[UpdateSettingCommand],
+		Execute: func(ctx context.Context, cmd UpdateSettingCommand, ec usecase.ExecutionContext) (usecaseop.Plan[AnomalySettingUpdated], error) {
+			kind, _ := anomaly.ParseEntityKind(cmd.EntityKind)
+			key := strings.TrimSpace(cmd.EntityKey)
+			exists, err := repo.EntityExists(ctx, kind, key)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "entity lookup failed", err)
+			}
+			if !exists {
+				return nil, httperror.NotFound(entityName(kind), key)
+			}
+			s, err := repo.FindSettingByEntity(ctx, kind, key)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_entity failed", err)
+			}
+			if s == nil {
+				s = anomaly.NewSetting(kind, key)
+			}
+			s.Sensitivity = cmd.Sensitivity
+			s.Enabled = cmd.Enabled
+			s.SnoozedUntil = nil
+			if cmd.SnoozedUntil != nil {
+				until := cmd.SnoozedUntil.UTC()
+				s.SnoozedUntil = &until
+			}
+			if ec.PrincipalID != "" {
+				s.UpdatedBy = &ec.PrincipalID
+			}
+			event := AnomalySettingUpdated{
+				Metadata:     usecase.NewEventMetadata(ec, AnomalySettingUpdatedType, Source, subjectFor(s.ID)),
+				SettingID:    s.ID,
+				EntityKind:   s.EntityKind,
+				EntityKey:    s.EntityKey,
+				Sensitivity:  s.Sensitivity,
+				Enabled:      s.Enabled,
+				SnoozedUntil: s.SnoozedUntil,
+			}
+			return usecaseop.Save(s, repo, event), nil
+		},
+	}
+}
+
+// entityName names an entity kind in a not-found error.
+func entityName(kind anomaly.EntityKind) string {
+	if kind == anomaly.EntityEventType {
+		return "EventType"
+	}
+	return "Subscription"
+}
@@ -0,0 +1,129 @@
+package anomaly
+
+import (
+	"context"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Repository is the Postgres-backed repo. Tables: msg_anomaly_settings,
+// msg_anomalies. Baselines belong to the detector and are not exposed.
+type Repository struct {
+	pool *pgxpool.Pool // retained for EntityExists
+	q    *dbq.Queries
+}
+
+// NewRepository wires a repo.
+func NewRepository(pool *pgxpool.Pool) *Repository {
+	return &Repository{pool: pool, q: dbq.New(pool)}
+}
+
+// FindSettingByEntity returns the setting for (kind, key), if any.
+func (r *Repository) FindSettingByEntity(ctx context.Context, kind EntityKind, key string) (*Setting, error) {
+	res, err := r.q.AnomalySettingFindByEntity(ctx, dbq.AnomalySettingFindByEntityParams{
+		EntityKind: string(kind), EntityKey: key,
+	})
+	row, err := repocommon.One(res, err, "anomaly_setting repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToSetting(*row), nil
+}
+
+// FindAllSettings returns every stored setting.
+func (r *Repository) FindAllSettings(ctx context.Context) ([]Setting, error) {
+	rows, err := r.q.AnomalySettingFindAll(ctx)
+	if err != nil {
+		return nil, err
+	}
+	out := make([]Setting, 0, len(rows))
+	for _, row := range rows {
+		out = append(out, *rowToSetting(row))
+	}
+	return out, nil
+}
+
+// FindAnomalies returns up to limit anomalies in status, newest first.
+func (r *Repository) FindAnomalies(ctx context.Context, status Status, limit int32) ([]Anomaly, error) {
+	rows, err := r.q.AnomalyFindByStatus(ctx, dbq.AnomalyFindByStatusParams{
+		Status: string(status), Limit: limit,
+	})
+	if err != nil {
+		return nil, err
+	}
+	out := make([]Anomaly, 0, len(rows))
+	for _, row := range rows {
+		out = append(out, rowToAnomaly(row))
+	}
+	return out, nil
+}
+
+// entityQueries checks an entity key against the table it lives in.
+var entityQueries = map[EntityKind]string{
+	EntityEventType:    `SELECT EXISTS (SELECT 1 FROM msg_event_types WHERE code = $1)`,
+	EntitySubscription: `SELECT EXISTS (SELECT 1 FROM msg_subscriptions WHERE id = $1)`,
+}
+
+// EntityExists reports whether key names an event type or subscription,
+// per kind, so a mistyped key is refused rather than silently muting
+// nothing.
+func (r *Repository) EntityExists(ctx context.Context, kind EntityKind, key string) (bool, error) {
+	q, ok := entityQueries[kind]
+	if !ok {
+		return false, nil
+	}
+	var exists bool
+	err := r.pool.QueryRow(ctx, q, key).Scan(&exists)
+	return exists, err
+}
+
+// Persist implements usecasepgx.Persist[Setting].
+func (r *Repository) Persist(ctx context.Context, s *Setting, tx *usecasepgx.DbTx) error {
+	return r.q.WithTx(tx.Inner()).AnomalySettingUpsert(ctx, dbq.AnomalySettingUpsertParams{
+		ID:           s.ID,
+		EntityKind:   string(s.EntityKind),
+		EntityKey:    s.EntityKey,
+		Sensitivity:  s.Sensitivity,
+		Enabled:      s.Enabled,
+		SnoozedUntil: s.SnoozedUntil,
+		UpdatedBy:    s.UpdatedBy,
+		CreatedAt:    s.CreatedAt,
+		UpdatedAt:    time.Now().UTC(),
+	})
+}
+
+func rowToSetting(row dbq.MsgAnomalySetting) *Setting {
+	return &Setting{
+		ID:           row.ID,
+		EntityKind:   EntityKind(row.EntityKind),
+		EntityKey:    row.EntityKey,
+		Sensitivity:  row.Sensitivity,
+		Enabled:      row.Enabled,
+		SnoozedUntil: row.SnoozedUntil,
+		UpdatedBy:    row.UpdatedBy,
+		CreatedAt:    row.CreatedAt,
+		UpdatedAt:    row.UpdatedAt,
+	}
+}
+
+func rowToAnomaly(row dbq.MsgAnomaly) Anomaly {
+	return Anomaly{
+		ID:         row.ID,
+		EntityKind: EntityKind(row.EntityKind),
+		EntityKey:  row.EntityKey,
+		Kind:       Kind(row.Kind),
+		Status:     Status(row.Status),
+		Message:    row.Message,
+		Observed:   row.Observed,
+		Expected:   row.Expected,
+		ZScore:     row.ZScore,
+		RaisedAt:   row.RaisedAt,
+		LastSeenAt: row.LastSeenAt,
+		ResolvedAt: row.ResolvedAt,
+	}
+}
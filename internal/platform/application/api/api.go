@@ -12,6 +12,7 @@ import (
 	platformauth "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
 	authops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/operations"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/role"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
@@ -34,7 +35,10 @@ type State struct {
 	Roles            *role.Repository
 	ServiceAccounts  *serviceaccount.Repository
 	OAuthClients     *platformauth.OAuthClientRepo
-	UoW              *usecasepgx.UnitOfWork
+	ProducerBindings *application.ProducerBindingRepo
+	// EventTypes lists the event types the producers report covers.
+	EventTypes *eventtype.Repository
+	UoW        *usecasepgx.UnitOfWork
 }
 
 const tag = "applications"
@@ -58,6 +62,10 @@ func Register(api huma.API, s *State) {
 	apiroute.Post(g, "provisionApplicationLoginClient", "/api/applications/{id}/provision-login-client", "Create a public OAuth login client for the application", http.StatusCreated, s.provisionLoginClient)
 	apiroute.Get(g, "listApplicationRoles", "/api/applications/by-id/{id}/roles", "List roles registered against an application", s.listApplicationRoles)
 	apiroute.Get(g, "getApplicationClientConfig", "/api/applications/{id}/clients/{clientId}", "Get a single application-client config", s.getClientConfig)
+	apiroute.Get(g, "getProducersReport", "/api/applications/producers", "Report which applications produce which event types", s.producersReport)
+	apiroute.Get(g, "listApplicationProducerBindings", "/api/applications/{id}/producers", "List an application's producer bindings", s.listProducerBindings)
+	apiroute.Post(g, "addApplicationProducerBinding", "/api/applications/{id}/producers", "Register an application as a producer of event types", http.StatusCreated, s.addProducerBinding)
+	apiroute.Delete(g, "removeApplicationProducerBinding", "/api/applications/{id}/producers/{bindingId}", "Remove a producer binding", http.StatusNoContent, s.removeProducerBinding)
 }
 
 type listInput struct {
@@ -403,3 +411,93 @@ func (s *State) getClientConfig(ctx context.Context, in *singleConfigInput) (*ap
 	}
 	return &apicommon.Out[ClientConfigResponse]{Body: clientConfigFromEntity(cfg)}, nil
 }
+
+// ── producer bindings ───────────────────────────────────────────────────
+
+func (s *State) listProducerBindings(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[ProducerBindingListResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanReadApplications(ac); err != nil {
+		return nil, err
+	}
+	rows, err := s.ProducerBindings.FindByApplication(ctx, in.ID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "list producer bindings failed", err)
+	}
+	out := apicommon.MapSlice(rows, producerBindingFromEntity)
+	return &apicommon.Out[ProducerBindingListResponse]{Body: ProducerBindingListResponse{Items: out}}, nil
+}
+
+type addProducerBindingInput struct {
+	ID   string `path:"id"`
+	Body AddProducerBindingRequest
+}
+
+func (s *State) addProducerBinding(ctx context.Context, in *addProducerBindingInput) (*apicommon.Out[apicommon.CreatedResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanWriteApplications(ac); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	event, err := usecaseop.Run(ctx, s.UoW, operations.AddProducerBinding(s.Repo, s.Principals, s.ProducerBindings), in.Body.toCommand(in.ID), ec)
+	if err != nil {
+		return nil, err
+	}
+	return &apicommon.Out[apicommon.CreatedResponse]{Body: apicommon.CreatedResponse{ID: event.BindingID}}, nil
+}
+
+type producerBindingInput struct {
+	ID        string `path:"id"`
+	BindingID string `path:"bindingId"`
+}
+
+func (s *State) removeProducerBinding(ctx context.Context, in *producerBindingInput) (*apicommon.Empty, error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanWriteApplications(ac); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.RemoveProducerBinding(s.ProducerBindings),
+		operations.RemoveProducerBindingCommand{ApplicationID: in.ID, BindingID: in.BindingID}, ec); err != nil {
+		return nil, err
+	}
+	return &apicommon.Empty{}, nil
+}
+
+// producersReport is the governance view: every current event type with
+// the bindings that cover it, and the bindings that cover none.
+func (s *State) producersReport(ctx context.Context, _ *struct{}) (*apicommon.Out[ProducersReportResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanReadApplications(ac); err != nil {
+		return nil, err
+	}
+	grants, err := s.ProducerBindings.Grants(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "producer grants failed", err)
+	}
+	ets, err := s.EventTypes.FindWithFilters(ctx, nil, nil, nil, nil, nil)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find event types failed", err)
+	}
+	out := ProducersReportResponse{EventTypes: []EventTypeProducersResponse{}, UnmatchedBindings: []ProducerResponse{}}
+	matched := make([]bool, len(grants))
+	for _, et := range ets {
+		if et.Status == eventtype.StatusArchived {
+			continue
+		}
+		item := EventTypeProducersResponse{EventTypeCode: et.Code, Producers: []ProducerResponse{}}
+		for i, g := range grants {
+			if g.Matches(et.Code) {
+				matched[i] = true
+				item.Producers = append(item.Producers, producerFromGrant(g))
+			}
+		}
+		item.Restricted = len(item.Producers) > 0
+		out.EventTypes = append(out.EventTypes, item)
+	}
+	for i, g := range grants {
+		if !matched[i] {
+			out.UnmatchedBindings = append(out.UnmatchedBindings, producerFromGrant(g))
+		}
+	}
+	return &apicommon.Out[ProducersReportResponse]{Body: out}, nil
+}
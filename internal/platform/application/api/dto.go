@@ -204,3 +204,80 @@ type ProvisionLoginClientRequest struct {
 type ApplicationRolesResponse struct {
 	Roles []string `json:"roles"`
 }
+
+// AddProducerBindingRequest is the wire body for
+// POST /api/applications/{id}/producers.
+type AddProducerBindingRequest struct {
+	EventTypePattern string  `json:"eventTypePattern" doc:"Event type code the application may publish; * matches any one segment (e.g. orders:sales:order:*). Ingestion enforces a new or removed binding at once when FC_REDIS_URL is set, else on other replicas within 30 seconds"`
+	PrincipalID      *string `json:"principalId,omitempty" doc:"Service account principal allowed to publish. Omit for the application's own service account"`
+}
+
+func (r AddProducerBindingRequest) toCommand(applicationID string) operations.AddProducerBindingCommand {
+	return operations.AddProducerBindingCommand{
+		ApplicationID:    applicationID,
+		PrincipalID:      r.PrincipalID,
+		EventTypePattern: r.EventTypePattern,
+	}
+}
+
+// ProducerBindingResponse mirrors application.ProducerBinding.
+type ProducerBindingResponse struct {
+	ID               string          `json:"id"`
+	ApplicationID    string          `json:"applicationId"`
+	PrincipalID      *string         `json:"principalId,omitempty"`
+	EventTypePattern string          `json:"eventTypePattern"`
+	CreatedAt        httpcompat.Time `json:"createdAt"`
+}
+
+func producerBindingFromEntity(b *application.ProducerBinding) ProducerBindingResponse {
+	return ProducerBindingResponse{
+		ID:               b.ID,
+		ApplicationID:    b.ApplicationID,
+		PrincipalID:      b.PrincipalID,
+		EventTypePattern: b.EventTypePattern,
+		CreatedAt:        jsontime.New(b.CreatedAt),
+	}
+}
+
+// ProducerBindingListResponse is the body for
+// GET /api/applications/{id}/producers.
+type ProducerBindingListResponse struct {
+	Items []ProducerBindingResponse `json:"items"`
+}
+
+// ProducerResponse is one binding that covers an event type in the
+// producers report, with the principal it admits.
+type ProducerResponse struct {
+	BindingID         string  `json:"bindingId"`
+	ApplicationID     string  `json:"applicationId"`
+	ApplicationCode   string  `json:"applicationCode"`
+	ApplicationActive bool    `json:"applicationActive"`
+	PrincipalID       *string `json:"principalId,omitempty" doc:"Principal allowed to publish; absent when the application has no service account"`
+	EventTypePattern  string  `json:"eventTypePattern"`
+}
+
+// EventTypeProducersResponse is one event type in the producers report.
+type EventTypeProducersResponse struct {
+	EventTypeCode string             `json:"eventTypeCode"`
+	Restricted    bool               `json:"restricted" doc:"Whether only the listed producers may publish it; false means any caller with events-write may"`
+	Producers     []ProducerResponse `json:"producers"`
+}
+
+// ProducersReportResponse is the body for GET /api/applications/producers.
+type ProducersReportResponse struct {
+	EventTypes []EventTypeProducersResponse `json:"eventTypes"`
+	// UnmatchedBindings are bindings whose pattern matches no current
+	// event type.
+	UnmatchedBindings []ProducerResponse `json:"unmatchedBindings"`
+}
+
+func producerFromGrant(g application.ProducerGrant) ProducerResponse {
+	return ProducerResponse{
+		BindingID:         g.BindingID,
+		ApplicationID:     g.ApplicationID,
+		ApplicationCode:   g.ApplicationCode,
+		ApplicationActive: g.ApplicationActive,
+		PrincipalID:       g.PrincipalID,
+		EventTypePattern:  g.EventTypePattern,
+	}
+}
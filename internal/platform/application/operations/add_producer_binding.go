@@ -0,0 +1,107 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// AddProducerBindingCommand registers an application as a producer of the
+// event types matching EventTypePattern. PrincipalID names the service
+// account allowed to publish; empty means the application's own.
+type AddProducerBindingCommand struct {
+	ApplicationID    string  `json:"applicationId"`
+	PrincipalID      *string `json:"principalId,omitempty"`
+	EventTypePattern string  `json:"eventTypePattern"`
+}
+
+// AddProducerBinding persists a [application.ProducerBinding] and emits
+// [ProducerBindingAdded]. From then on the matching event types accept
+// events only from their bound producers.
+//
+// An application is platform-level, so there is no resource-level access
+// check; the coarse permission check is enforced at the controller.
+func AddProducerBinding(
+	apps *application.Repository,
+	principals *principal.Repository,
+	bindings *application.ProducerBindingRepo,
+) usecaseop.Operation[AddProducerBindingCommand, ProducerBindingAdded] {
+	return usecaseop.Operation[AddProducerBindingCommand, ProducerBindingAdded]{
+		Name: "AddProducerBinding",
+		Validate: func(_ context.Context, cmd AddProducerBindingCommand) error {
+			if strings.TrimSpace(cmd.ApplicationID) == "" {
+				return usecase.Validation("APPLICATION_ID_REQUIRED", "Application ID is required")
+			}
+			if err := application.ValidateEventTypePattern(strings.TrimSpace(cmd.EventTypePattern)); err != nil {
+				return usecase.Validation("INVALID_EVENT_TYPE_PATTERN", err.Error())
+			}
+			return nil
+		},
+		Authorize: usecaseop.Public[AddProducerBindingCommand],
+		Execute: func(ctx context.Context, cmd AddProducerBindingCommand, ec usecase.ExecutionContext) (usecaseop.Plan[ProducerBindingAdded], error) {
+			app, err := apps.FindByID(ctx, cmd.ApplicationID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_application failed", err)
+			}
+			if app == nil {
+				return nil, httperror.NotFound("Application", cmd.ApplicationID)
+			}
+
+			var producer *string
+			if cmd.PrincipalID != nil && strings.TrimSpace(*cmd.PrincipalID) != "" {
+				id := strings.TrimSpace(*cmd.PrincipalID)
+				p, err := principals.FindByID(ctx, id)
+				if err != nil {
+					return nil, usecase.Internal("REPO", "find_principal failed", err)
+				}
+				if p == nil {
+					return nil, httperror.NotFound("Principal", id)
+				}
+				if !p.IsService() {
+					return nil, usecase.Validation("PRODUCER_NOT_SERVICE_ACCOUNT",
+						"Only service account principals can be registered as producers")
+				}
+				producer = &id
+			} else if app.ServiceAccountID == nil {
+				// A binding without a principal stands for the application's
+				// service account; with none it would admit no one.
+				return nil, usecase.BusinessRule("APPLICATION_HAS_NO_SERVICE_ACCOUNT",
+					"Application has no service account; provision one or name a principal")
+			}
+
+			pattern := strings.TrimSpace(cmd.EventTypePattern)
+			existing, err := bindings.FindByApplication(ctx, app.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_producer_bindings failed", err)
+			}
+			for _, b := range existing {
+				if b.EventTypePattern == pattern && samePrincipal(b.PrincipalID, producer) {
+					return nil, usecase.BusinessRule("PRODUCER_BINDING_EXISTS",
+						"Application is already a producer of "+pattern)
+				}
+			}
+
+			b := application.NewProducerBinding(app.ID, producer, pattern)
+			event := ProducerBindingAdded{
+				Metadata:         usecase.NewEventMetadata(ec, ProducerBindingAddedType, Source, subjectFor(app.ID)),
+				ApplicationID:    app.ID,
+				BindingID:        b.ID,
+				ProducerID:       b.PrincipalID,
+				EventTypePattern: b.EventTypePattern,
+			}
+			return usecaseop.Save(b, bindings, event), nil
+		},
+	}
+}
+
+func samePrincipal(a, b *string) bool {
+	if a == nil || b == nil {
+		return a == b
+	}
+	return *a == *b
+}
@@ -16,6 +16,8 @@ const (
 	ApplicationServiceAccountProvisioned = "platform:iam:application:service-account-provisioned"
 	ApplicationEnabledForClientType      = "platform:iam:application:enabled-for-client"
 	ApplicationDisabledForClientType     = "platform:iam:application:disabled-for-client"
+	ProducerBindingAddedType             = "platform:iam:application:producer-binding-added"
+	ProducerBindingRemovedType           = "platform:iam:application:producer-binding-removed"
 	ClientApplicationsUpdatedType        = "platform:iam:client:applications-updated"
 	Source                               = "platform:iam"
 )
@@ -247,6 +249,64 @@ func (e ApplicationDisabledForClient) ToDataJSON() ([]byte, error) {
 	}{e.ApplicationID, e.ClientID, e.ConfigID})
 }
 
+type ProducerBindingAdded struct {
+	Metadata         usecase.EventMetadata
+	ApplicationID    string
+	BindingID        string
+	// ProducerID is the binding's principal (nil: the application's
+	// service account); PrincipalID() is whoever made the change.
+	ProducerID       *string
+	EventTypePattern string
+}
+
+func (e ProducerBindingAdded) EventID() string       { return e.Metadata.EventID }
+func (e ProducerBindingAdded) EventType() string     { return ProducerBindingAddedType }
+func (e ProducerBindingAdded) SpecVersion() string   { return "1.0" }
+func (e ProducerBindingAdded) Source() string        { return Source }
+func (e ProducerBindingAdded) Subject() string       { return subjectFor(e.ApplicationID) }
+func (e ProducerBindingAdded) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e ProducerBindingAdded) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e ProducerBindingAdded) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e ProducerBindingAdded) CausationID() string   { return e.Metadata.CausationID }
+func (e ProducerBindingAdded) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e ProducerBindingAdded) MessageGroup() string  { return groupFor(e.ApplicationID) }
+func (e ProducerBindingAdded) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		ApplicationID    string  `json:"applicationId"`
+		BindingID        string  `json:"bindingId"`
+		PrincipalID      *string `json:"principalId"`
+		EventTypePattern string  `json:"eventTypePattern"`
+	}{e.ApplicationID, e.BindingID, e.ProducerID, e.EventTypePattern})
+}
+
+type ProducerBindingRemoved struct {
+	Metadata         usecase.EventMetadata
+	ApplicationID    string
+	BindingID        string
+	ProducerID       *string
+	EventTypePattern string
+}
+
+func (e ProducerBindingRemoved) EventID() string       { return e.Metadata.EventID }
+func (e ProducerBindingRemoved) EventType() string     { return ProducerBindingRemovedType }
+func (e ProducerBindingRemoved) SpecVersion() string   { return "1.0" }
+func (e ProducerBindingRemoved) Source() string        { return Source }
+func (e ProducerBindingRemoved) Subject() string       { return subjectFor(e.ApplicationID) }
+func (e ProducerBindingRemoved) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e ProducerBindingRemoved) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e ProducerBindingRemoved) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e ProducerBindingRemoved) CausationID() string   { return e.Metadata.CausationID }
+func (e ProducerBindingRemoved) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e ProducerBindingRemoved) MessageGroup() string  { return groupFor(e.ApplicationID) }
+func (e ProducerBindingRemoved) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		ApplicationID    string  `json:"applicationId"`
+		BindingID        string  `json:"bindingId"`
+		PrincipalID      *string `json:"principalId"`
+		EventTypePattern string  `json:"eventTypePattern"`
+	}{e.ApplicationID, e.BindingID, e.ProducerID, e.EventTypePattern})
+}
+
 // ClientApplicationsUpdated — emitted by the bulk update endpoint
 // (`PUT /api/clients/{id}/applications`). Replaces the per-row enable/
 // disable events with a single rollup describing the diff. Mirrors Rust
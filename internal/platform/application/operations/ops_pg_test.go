@@ -572,3 +572,67 @@ func TestProvisionServiceAccount_AssignsRoleAndScopesClient(t *testing.T) {
 	assert.Equal(t, []string{app.ApplicationID}, oc.ApplicationIDs,
 		"SA OAuth client is scoped to its application")
 }
+
+// ── Producer bindings ─────────────────────────────────────────────────────
+
+func TestProducerBindings_AddResolveAndRemove(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+	appRepo := application.NewRepository(pool)
+	saRepo := serviceaccount.NewRepository(pool)
+	principals := principal.NewRepository(pool)
+	oauthRepo := platformauth.NewRepository(pool).OAuthClients
+	bindings := application.NewProducerBindingRepo(pool)
+	uow := testpg.NewUoW(t)
+
+	app := mustCreateApp(t, appRepo, uow, "appprod-1", "Producer App")
+	add := operations.AddProducerBinding(appRepo, principals, bindings)
+	cmd := operations.AddProducerBindingCommand{ApplicationID: app.ApplicationID, EventTypePattern: "appprod-1:orders:order:*"}
+
+	// Bound to its own service account, an application needs one.
+	_, err := runAuthorized(uow, add, cmd)
+	testpg.RequireUsecaseError(t, err, usecase.KindBusinessRule, "APPLICATION_HAS_NO_SERVICE_ACCOUNT")
+
+	sa, err := usecaseop.RunTx(testpg.AnchorCtx(), uow,
+		operations.ProvisionServiceAccount(appRepo, saRepo, principals, oauthRepo),
+		operations.ProvisionServiceAccountCommand{ApplicationID: app.ApplicationID},
+		testpg.TestEC())
+	require.NoError(t, err)
+
+	ev, err := runAuthorized(uow, add, cmd)
+	require.NoError(t, err)
+	assert.Nil(t, ev.ProducerID)
+	_, err = runAuthorized(uow, add, cmd)
+	testpg.RequireUsecaseError(t, err, usecase.KindBusinessRule, "PRODUCER_BINDING_EXISTS")
+	_, err = runAuthorized(uow, add, operations.AddProducerBindingCommand{ApplicationID: app.ApplicationID, EventTypePattern: "appprod-1:orders:ord*"})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_EVENT_TYPE_PATTERN")
+
+	// The binding resolves to the service account's principal.
+	grants, err := bindings.Grants(ctx)
+	require.NoError(t, err)
+	var own []application.ProducerGrant
+	for _, g := range grants {
+		if g.ApplicationID == app.ApplicationID {
+			own = append(own, g)
+		}
+	}
+	require.Len(t, own, 1)
+	require.NotNil(t, own[0].PrincipalID)
+	assert.Equal(t, sa.ServicePrincipalID, *own[0].PrincipalID)
+	chk := application.CheckProducer(own, "appprod-1:orders:order:placed", sa.ServicePrincipalID)
+	assert.True(t, chk.Restricted)
+	assert.True(t, chk.Allowed)
+	assert.Equal(t, []string{"appprod-1"}, chk.Producers)
+	assert.False(t, application.CheckProducer(own, "appprod-1:orders:order:placed", "prn_someoneelse1").Allowed)
+
+	_, err = runAuthorized(uow, operations.RemoveProducerBinding(bindings),
+		operations.RemoveProducerBindingCommand{ApplicationID: "app_doesnotexist1", BindingID: ev.BindingID})
+	testpg.RequireUsecaseError(t, err, usecase.KindNotFound, "ProducerBinding_NOT_FOUND")
+	_, err = runAuthorized(uow, operations.RemoveProducerBinding(bindings),
+		operations.RemoveProducerBindingCommand{ApplicationID: app.ApplicationID, BindingID: ev.BindingID})
+	require.NoError(t, err)
+	left, err := bindings.FindByApplication(ctx, app.ApplicationID)
+	require.NoError(t, err)
+	assert.Empty(t, left)
+}
@@ -132,7 +132,7 @@ func ProvisionServiceAccount(
 
 			// 1. Service account.
 			if r := usecasepgx.CommitScoped(ctx, s, sa, saRepo,
-				saops.NewServiceAccountCreatedEvent(ec, sa.ID, sa.Code, sa.Name), cmd); !usecase.IsSuccess(r) {
+				saops.NewServiceAccountCreatedEvent(ec, sa.ID, sa.Code, sa.Name, sa.ClientIDs), cmd); !usecase.IsSuccess(r) {
 				_, e := usecase.Into(r)
 				return zero, e
 			}
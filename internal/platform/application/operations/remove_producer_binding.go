@@ -0,0 +1,55 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// RemoveProducerBindingCommand removes one of an application's producer
+// bindings.
+type RemoveProducerBindingCommand struct {
+	ApplicationID string `json:"applicationId"`
+	BindingID     string `json:"bindingId"`
+}
+
+// RemoveProducerBinding deletes the binding and emits
+// [ProducerBindingRemoved]. An event type left with no binding is open to
+// every publisher again.
+func RemoveProducerBinding(bindings *application.ProducerBindingRepo) usecaseop.Operation[RemoveProducerBindingCommand, ProducerBindingRemoved] {
+	return usecaseop.Operation[RemoveProducerBindingCommand, ProducerBindingRemoved]{
+		Name: "RemoveProducerBinding",
+		Validate: func(_ context.Context, cmd RemoveProducerBindingCommand) error {
+			if strings.TrimSpace(cmd.ApplicationID) == "" {
+				return usecase.Validation("APPLICATION_ID_REQUIRED", "Application ID is required")
+			}
+			if strings.TrimSpace(cmd.BindingID) == "" {
+				return usecase.Validation("BINDING_ID_REQUIRED", "Binding ID is required")
+			}
+			return nil
+		},
+		Authorize: usecaseop.Public[RemoveProducerBindingCommand],
+		Execute: func(ctx context.Context, cmd RemoveProducerBindingCommand, ec usecase.ExecutionContext) (usecaseop.Plan[ProducerBindingRemoved], error) {
+			b, err := bindings.FindByID(ctx, cmd.BindingID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_producer_binding failed", err)
+			}
+			if b == nil || b.ApplicationID != cmd.ApplicationID {
+				return nil, httperror.NotFound("ProducerBinding", cmd.BindingID)
+			}
+
+			event := ProducerBindingRemoved{
+				Metadata:         usecase.NewEventMetadata(ec, ProducerBindingRemovedType, Source, subjectFor(b.ApplicationID)),
+				ApplicationID:    b.ApplicationID,
+				BindingID:        b.ID,
+				ProducerID:       b.PrincipalID,
+				EventTypePattern: b.EventTypePattern,
+			}
+			return usecaseop.Delete(b, bindings, event), nil
+		},
+	}
+}
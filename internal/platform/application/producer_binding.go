@@ -0,0 +1,250 @@
+package application
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/snapshot"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// ProducerBinding registers an application as a producer of the event
+// types matching EventTypePattern, in app_producer_bindings. PrincipalID
+// names the SERVICE principal allowed to publish; nil means the
+// application's own service account. Once any binding matches an event
+// type, only the principals its bindings admit may publish it.
+type ProducerBinding struct {
+	ID               string    `json:"id"`
+	ApplicationID    string    `json:"applicationId"`
+	PrincipalID      *string   `json:"principalId,omitempty"`
+	EventTypePattern string    `json:"eventTypePattern"`
+	CreatedAt        time.Time `json:"createdAt"`
+}
+
+// IDStr satisfies usecase.HasID.
+func (b ProducerBinding) IDStr() string { return b.ID }
+
+// NewProducerBinding constructs a binding.
+func NewProducerBinding(applicationID string, principalID *string, pattern string) *ProducerBinding {
+	return &ProducerBinding{
+		ID:               tsid.Generate(tsid.ProducerBinding),
+		ApplicationID:    applicationID,
+		PrincipalID:      principalID,
+		EventTypePattern: pattern,
+		CreatedAt:        time.Now().UTC(),
+	}
+}
+
+// ValidateEventTypePattern checks pattern is an event type code
+// (application:subdomain:aggregate:event) whose segments may each be `*`.
+func ValidateEventTypePattern(pattern string) error {
+	parts := strings.Split(pattern, ":")
+	if len(parts) != 4 {
+		return errors.New("event type pattern must follow format: application:subdomain:aggregate:event")
+	}
+	for _, p := range parts {
+		if strings.TrimSpace(p) == "" {
+			return errors.New("event type pattern segments cannot be empty")
+		}
+		if p != "*" && strings.Contains(p, "*") {
+			return errors.New("a wildcard must be a whole segment")
+		}
+	}
+	return nil
+}
+
+// patternMatches reports whether an event type pattern matches code; `*`
+// matches any one segment.
+func patternMatches(pattern, code string) bool {
+	pp := strings.Split(pattern, ":")
+	cp := strings.Split(code, ":")
+	if len(pp) != len(cp) {
+		return false
+	}
+	for i, p := range pp {
+		if p != "*" && p != cp[i] {
+			return false
+		}
+	}
+	return true
+}
+
+// ProducerGrant is a binding resolved for enforcement: its application
+// and the principal it admits, if any — a binding to an application
+// without a service account admits nobody.
+type ProducerGrant struct {
+	BindingID         string  `json:"bindingId"`
+	ApplicationID     string  `json:"applicationId"`
+	ApplicationCode   string  `json:"applicationCode"`
+	ApplicationActive bool    `json:"applicationActive"`
+	PrincipalID       *string `json:"principalId,omitempty"`
+	EventTypePattern  string  `json:"eventTypePattern"`
+}
+
+// Matches reports whether the grant covers event type code.
+func (g ProducerGrant) Matches(code string) bool { return patternMatches(g.EventTypePattern, code) }
+
+// Admits reports whether the grant lets principalID publish. An inactive
+// application's bindings still restrict their event types but admit no one.
+func (g ProducerGrant) Admits(principalID string) bool {
+	return g.ApplicationActive && g.PrincipalID != nil && *g.PrincipalID == principalID
+}
+
+// ProducerCheck is the outcome of checking a publisher against the
+// bindings. Restricted is false for an event type no binding matches;
+// Producers lists the codes of the applications bound to it.
+type ProducerCheck struct {
+	Restricted bool
+	Allowed    bool
+	Producers  []string
+}
+
+// CheckProducer decides whether principalID may publish event type code
+// under grants.
+func CheckProducer(grants []ProducerGrant, code, principalID string) ProducerCheck {
+	out := ProducerCheck{Allowed: true}
+	for _, g := range grants {
+		if !g.Matches(code) {
+			continue
+		}
+		if !out.Restricted {
+			out.Restricted = true
+			out.Allowed = false
+		}
+		if g.Admits(principalID) {
+			out.Allowed = true
+		}
+		if !slices.Contains(out.Producers, g.ApplicationCode) {
+			out.Producers = append(out.Producers, g.ApplicationCode)
+		}
+	}
+	return out
+}
+
+// ProducerRegistry is a cached view of every producer grant, for the
+// ingestion path. A nil *ProducerRegistry leaves every event type open,
+// so callers can hold one unconditionally.
+type ProducerRegistry struct {
+	grants *snapshot.Value[[]ProducerGrant]
+}
+
+// NewProducerRegistry wires a registry over load (typically
+// ProducerBindingRepo.Grants). ttl <= 0 means 30s.
+func NewProducerRegistry(load func(context.Context) ([]ProducerGrant, error), ttl time.Duration) *ProducerRegistry {
+	if ttl <= 0 {
+		ttl = 30 * time.Second
+	}
+	return &ProducerRegistry{grants: snapshot.New(load, ttl)}
+}
+
+// Check decides whether principalID may publish event type code. A failed
+// refresh keeps serving the previous grants; with nothing cached the
+// error is returned.
+func (r *ProducerRegistry) Check(ctx context.Context, code, principalID string) (ProducerCheck, error) {
+	if r == nil {
+		return ProducerCheck{Allowed: true}, nil
+	}
+	grants, err := r.grants.Get(ctx)
+	if err != nil {
+		return ProducerCheck{}, err
+	}
+	return CheckProducer(grants, code, principalID), nil
+}
+
+// Invalidate drops the cached grants; the next Check reloads them. It
+// only clears this replica: the server attaches the registry to the
+// read-cache registry (readcache.Registry.Attach), whose shared store
+// relays binding and application changes to every replica. Without that
+// store, other replicas pick a change up when ttl runs out.
+func (r *ProducerRegistry) Invalidate() {
+	if r != nil {
+		r.grants.Invalidate()
+	}
+}
+
+// ProducerBindingRepo is the pgx-backed repo. Backed by app_producer_bindings.
+type ProducerBindingRepo struct{ q *dbq.Queries }
+
+// NewProducerBindingRepo wires the repo.
+func NewProducerBindingRepo(pool *pgxpool.Pool) *ProducerBindingRepo {
+	return &ProducerBindingRepo{q: dbq.New(pool)}
+}
+
+// FindByID returns a binding or nil.
+func (r *ProducerBindingRepo) FindByID(ctx context.Context, id string) (*ProducerBinding, error) {
+	res, err := r.q.ProducerBindingFindByID(ctx, id)
+	row, err := repocommon.One(res, err, "app_producer_bindings repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToProducerBinding(*row), nil
+}
+
+// FindByApplication lists an application's bindings by pattern.
+func (r *ProducerBindingRepo) FindByApplication(ctx context.Context, applicationID string) ([]ProducerBinding, error) {
+	rows, err := r.q.ProducerBindingFindByApp(ctx, applicationID)
+	if err != nil {
+		return nil, err
+	}
+	out := make([]ProducerBinding, 0, len(rows))
+	for _, row := range rows {
+		out = append(out, *rowToProducerBinding(row))
+	}
+	return out, nil
+}
+
+// Grants lists every binding resolved to the principal it admits. Feeds
+// the ProducerRegistry and the producers report.
+func (r *ProducerBindingRepo) Grants(ctx context.Context) ([]ProducerGrant, error) {
+	rows, err := r.q.ProducerBindingGrants(ctx)
+	if err != nil {
+		return nil, err
+	}
+	out := make([]ProducerGrant, 0, len(rows))
+	for _, row := range rows {
+		out = append(out, ProducerGrant{
+			BindingID:         row.ID,
+			ApplicationID:     row.ApplicationID,
+			ApplicationCode:   row.ApplicationCode,
+			ApplicationActive: row.ApplicationActive,
+			PrincipalID:       row.PrincipalID,
+			EventTypePattern:  row.EventTypePattern,
+		})
+	}
+	return out, nil
+}
+
+// Persist implements usecasepgx.Persist[ProducerBinding]. Bindings are
+// immutable; a second persist of the same binding is a no-op.
+func (r *ProducerBindingRepo) Persist(ctx context.Context, b *ProducerBinding, tx *usecasepgx.DbTx) error {
+	return r.q.WithTx(tx.Inner()).ProducerBindingInsert(ctx, dbq.ProducerBindingInsertParams{
+		ID:               b.ID,
+		ApplicationID:    b.ApplicationID,
+		PrincipalID:      b.PrincipalID,
+		EventTypePattern: b.EventTypePattern,
+		CreatedAt:        b.CreatedAt,
+	})
+}
+
+// Delete removes a binding.
+func (r *ProducerBindingRepo) Delete(ctx context.Context, b *ProducerBinding, tx *usecasepgx.DbTx) error {
+	return r.q.WithTx(tx.Inner()).ProducerBindingDelete(ctx, b.ID)
+}
+
+func rowToProducerBinding(row dbq.AppProducerBinding) *ProducerBinding {
+	return &ProducerBinding{
+		ID:               row.ID,
+		ApplicationID:    row.ApplicationID,
+		PrincipalID:      row.PrincipalID,
+		EventTypePattern: row.EventTypePattern,
+		CreatedAt:        row.CreatedAt,
+	}
+}
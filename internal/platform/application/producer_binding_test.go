@@ -0,0 +1,32 @@
+package application
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestCheckProducer(t *testing.T) {
+	sa1, sa2 := "prn_sa1", "prn_sa2"
+	grants := []ProducerGrant{
+		{ApplicationCode: "orders", ApplicationActive: true, PrincipalID: &sa1, EventTypePattern: "orders:*:order:*"},
+		{ApplicationCode: "legacy", ApplicationActive: false, PrincipalID: &sa2, EventTypePattern: "orders:sales:order:placed"},
+		{ApplicationCode: "billing", ApplicationActive: true, EventTypePattern: "billing:*:*:*"}, // no service account
+	}
+
+	chk := CheckProducer(grants, "orders:sales:order:placed", sa1)
+	assert.Equal(t, ProducerCheck{Restricted: true, Allowed: true, Producers: []string{"orders", "legacy"}}, chk)
+	assert.False(t, CheckProducer(grants, "orders:sales:order:placed", sa2).Allowed, "an inactive application admits no one")
+	assert.False(t, CheckProducer(grants, "billing:ar:invoice:issued", sa1).Allowed)
+
+	chk = CheckProducer(grants, "shipping:parcel:label:printed", sa2)
+	assert.Equal(t, ProducerCheck{Allowed: true}, chk, "unbound event types stay open")
+}
+
+func TestValidateEventTypePattern(t *testing.T) {
+	assert.NoError(t, ValidateEventTypePattern("orders:sales:order:placed"))
+	assert.NoError(t, ValidateEventTypePattern("*:*:*:*"))
+	assert.Error(t, ValidateEventTypePattern("orders:sales:order"))
+	assert.Error(t, ValidateEventTypePattern("orders::order:placed"))
+	assert.Error(t, ValidateEventTypePattern("orders:sales:ord*:placed"))
+}
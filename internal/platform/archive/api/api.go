@@ -0,0 +1,196 @@
+// Package api wires the HTTP routes for the event archive via huma: the
+// manifest of objects written, each stream's progress, and replays from
+// it. Anchor-only.
+package api
+
+import (
+	"context"
+	"net/http"
+	"time"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// State bundles the dependencies.
+type State struct {
+	Repo          *archive.Repository
+	Subscriptions *subscription.Repository
+	UoW           *usecasepgx.UnitOfWork
+	// StoreURI is FC_ARCHIVE_STORE_URI; the archive is disabled without
+	// it.
+	StoreURI string
+}
+
+const tag = "archive"
+
+// Register mounts the archive endpoints.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "listArchiveFiles", "/api/archive/files", "List the objects the event archive has written", s.files)
+	apiroute.Get(g, "getArchiveStatus", "/api/archive/status", "Get the event archive's progress and record schemas", s.status)
+	apiroute.Post(g, "startArchiveReplay", "/api/archive/replays", "Replay archived events back into the platform", http.StatusAccepted, s.startReplay)
+	apiroute.Get(g, "listArchiveReplays", "/api/archive/replays", "List recent archive replays", s.listReplays)
+	apiroute.Get(g, "getArchiveReplay", "/api/archive/replays/{id}", "Get an archive replay and its progress", s.getReplay)
+	apiroute.Post(g, "cancelArchiveReplay", "/api/archive/replays/{id}/cancel", "Cancel an archive replay", http.StatusOK, s.cancelReplay)
+}
+
+type filesInput struct {
+	Kind  string `query:"kind" doc:"EVENTS or DELIVERIES; both when omitted"`
+	Since string `query:"since" doc:"RFC3339; objects whose partition hour is at or after it"`
+	Until string `query:"until" doc:"RFC3339; objects whose partition hour is before it"`
+	After string `query:"after" doc:"Opaque cursor from a previous page"`
+	Limit int    `query:"limit" doc:"Maximum objects to return (default 500, max 5000)"`
+}
+
+func (s *State) files(ctx context.Context, in *filesInput) (*apicommon.Out[ArchiveManifestResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	var p archive.ManifestParams
+	if in.Kind != "" {
+		k, ok := archive.ParseKind(in.Kind)
+		if !ok {
+			return nil, httperror.BadRequest("INVALID_KIND", "kind must be EVENTS or DELIVERIES")
+		}
+		p.Kind = &k
+	}
+	var err error
+	if p.Since, err = parseTime(in.Since); err != nil {
+		return nil, httperror.BadRequest("INVALID_SINCE", "since is not an RFC3339 timestamp")
+	}
+	if p.Until, err = parseTime(in.Until); err != nil {
+		return nil, httperror.BadRequest("INVALID_UNTIL", "until is not an RFC3339 timestamp")
+	}
+	if in.After != "" {
+		parts, err := apicommon.DecodeCursor(in.After, 1)
+		if err != nil {
+			return nil, httperror.BadRequest("INVALID_CURSOR", "after is not a cursor this endpoint issued")
+		}
+		p.AfterID = &parts[0]
+	}
+	limit := in.Limit
+	if limit <= 0 {
+		limit = 500
+	}
+	limit = min(limit, 5000)
+	p.Limit = limit + 1
+	rows, err := s.Repo.Manifest(ctx, p)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "manifest failed", err)
+	}
+	page, next := apicommon.CursorPage(rows, limit, func(f *archive.File) []string { return []string{f.ID} })
+	out := ArchiveManifestResponse{
+		Files:   apicommon.MapSlice(page, fromFile),
+		Cursor:  next,
+		HasMore: next != "",
+	}
+	return &apicommon.Out[ArchiveManifestResponse]{Body: out}, nil
+}
+
+func (s *State) status(ctx context.Context, _ *struct{}) (*apicommon.Out[ArchiveStatusResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	cursors, err := s.Repo.Cursors(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_cursors failed", err)
+	}
+	out := ArchiveStatusResponse{Enabled: s.StoreURI != "", Streams: []ArchiveStreamResponse{}}
+	now := time.Now()
+	for _, kind := range archive.Kinds() {
+		c, ok := cursors[kind]
+		out.Streams = append(out.Streams, fromCursor(kind, c, ok, now))
+	}
+	return &apicommon.Out[ArchiveStatusResponse]{Body: out}, nil
+}
+
+func (s *State) startReplay(ctx context.Context, in *apicommon.In[StartArchiveReplayRequest]) (*apicommon.Out[ArchiveReplayResponse], error) {
+	if s.StoreURI == "" {
+		return nil, httperror.BadRequest("ARCHIVE_DISABLED", "the event archive is not configured (FC_ARCHIVE_STORE_URI)")
+	}
+	// Anchor-only authorization runs inside the use case's Authorize phase.
+	ec := auth.NewExecutionContext(ctx)
+	event, err := usecaseop.Run(ctx, s.UoW, operations.StartReplay(s.Repo, s.Subscriptions), in.Body.toCommand(), ec)
+	if err != nil {
+		return nil, err
+	}
+	return s.replayResponse(ctx, event.ReplayID)
+}
+
+type replaysInput struct {
+	Limit int `query:"limit" doc:"Maximum replays to return (default 50, max 200)"`
+}
+
+func (s *State) listReplays(ctx context.Context, in *replaysInput) (*apicommon.Out[ArchiveReplayListResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	limit := in.Limit
+	if limit <= 0 {
+		limit = 50
+	}
+	limit = min(limit, 200)
+	rows, err := s.Repo.ListReplays(ctx, limit)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "list_replays failed", err)
+	}
+	out := apicommon.MapSlice(rows, fromReplay)
+	return &apicommon.Out[ArchiveReplayListResponse]{Body: ArchiveReplayListResponse{Items: out}}, nil
+}
+
+func (s *State) getReplay(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[ArchiveReplayResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	rp, err := s.Repo.FindReplay(ctx, in.ID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_replay failed", err)
+	}
+	if rp == nil {
+		return nil, httperror.NotFound("ArchiveReplay", in.ID)
+	}
+	return &apicommon.Out[ArchiveReplayResponse]{Body: fromReplay(rp)}, nil
+}
+
+func (s *State) cancelReplay(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[ArchiveReplayResponse], error) {
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.CancelReplay(s.Repo), operations.CancelReplayCommand{ID: in.ID}, ec); err != nil {
+		return nil, err
+	}
+	return s.replayResponse(ctx, in.ID)
+}
+
+// replayResponse reads a replay back after a write.
+func (s *State) replayResponse(ctx context.Context, id string) (*apicommon.Out[ArchiveReplayResponse], error) {
+	rp, err := s.Repo.FindReplay(ctx, id)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_replay failed", err)
+	}
+	if rp == nil {
+		return nil, usecase.Internal("REPO", "archive replay missing after write", nil)
+	}
+	return &apicommon.Out[ArchiveReplayResponse]{Body: fromReplay(rp)}, nil
+}
+
+// parseTime reads an optional RFC3339 query parameter.
+func parseTime(s string) (*time.Time, error) {
+	if s == "" {
+		return nil, nil
+	}
+	t, err := time.Parse(time.RFC3339, s)
+	if err != nil {
+		return nil, err
+	}
+	return &t, nil
+}
@@ -0,0 +1,172 @@
+// dto.go contains the wire-format types for the archive API.
+package api
+
+import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+)
+
+// ArchiveFileResponse is one manifest entry.
+type ArchiveFileResponse struct {
+	ID            string          `json:"id"`
+	Kind          string          `json:"kind" doc:"EVENTS or DELIVERIES"`
+	Key           string          `json:"key" doc:"Object key in FC_ARCHIVE_STORE_URI"`
+	SchemaVersion int             `json:"schemaVersion" doc:"Schema version the object's records are written with"`
+	PartitionHour httpcompat.Time `json:"partitionHour" doc:"UTC hour the records fall in"`
+	Records       int             `json:"records"`
+	Bytes         int64           `json:"bytes" doc:"Compressed size"`
+	FirstID       string          `json:"firstId"`
+	LastID        string          `json:"lastId"`
+	MinTime       httpcompat.Time `json:"minTime"`
+	MaxTime       httpcompat.Time `json:"maxTime"`
+	CreatedAt     httpcompat.Time `json:"createdAt"`
+}
+
+func fromFile(f *archive.File) ArchiveFileResponse {
+	return ArchiveFileResponse{
+		ID:            f.ID,
+		Kind:          string(f.Kind),
+		Key:           f.Key,
+		SchemaVersion: f.SchemaVersion,
+		PartitionHour: jsontime.New(f.PartitionHour),
+		Records:       f.Records,
+		Bytes:         f.Bytes,
+		FirstID:       f.FirstID,
+		LastID:        f.LastID,
+		MinTime:       jsontime.New(f.MinTime),
+		MaxTime:       jsontime.New(f.MaxTime),
+		CreatedAt:     jsontime.New(f.CreatedAt),
+	}
+}
+
+// ArchiveManifestResponse is the wire shape for GET /api/archive/files.
+type ArchiveManifestResponse struct {
+	Files   []ArchiveFileResponse `json:"files"`
+	Cursor  string                `json:"cursor" doc:"Pass as after to read the next page; empty on the last"`
+	HasMore bool                  `json:"hasMore"`
+}
+
+// ArchiveFieldResponse is one field of a record schema.
+type ArchiveFieldResponse struct {
+	Name  string `json:"name"`
+	Type  string `json:"type" doc:"string, timestamp, integer, boolean or json"`
+	Since int    `json:"since" doc:"Schema version that added the field"`
+}
+
+// ArchiveStreamResponse is one stream's progress and current schema.
+type ArchiveStreamResponse struct {
+	Kind          string                 `json:"kind"`
+	ArchivedUntil *httpcompat.Time       `json:"archivedUntil,omitempty" doc:"Timestamp of the last record archived"`
+	LagSeconds    *int64                 `json:"lagSeconds,omitempty" doc:"How far behind now the last record archived is"`
+	Archived      int64                  `json:"archived" doc:"Records archived since the stream started"`
+	LastRunAt     *httpcompat.Time       `json:"lastRunAt,omitempty"`
+	LastError     *string                `json:"lastError,omitempty" doc:"Why the last run failed; absent after a clean one"`
+	SchemaVersion int                    `json:"schemaVersion"`
+	Fields        []ArchiveFieldResponse `json:"fields"`
+}
+
+func fromCursor(kind archive.Kind, c archive.Cursor, ok bool, now time.Time) ArchiveStreamResponse {
+	s := archive.CurrentSchema(kind)
+	out := ArchiveStreamResponse{
+		Kind:          string(kind),
+		Archived:      c.Archived,
+		LastError:     c.LastError,
+		SchemaVersion: s.Version,
+		Fields:        make([]ArchiveFieldResponse, 0, len(s.Fields)),
+	}
+	for _, f := range s.Fields {
+		out.Fields = append(out.Fields, ArchiveFieldResponse{Name: f.Name, Type: f.Type, Since: f.Since})
+	}
+	if ok && c.AfterID != "" {
+		t := jsontime.New(c.AfterTime)
+		lag := int64(now.Sub(c.AfterTime).Seconds())
+		out.ArchivedUntil, out.LagSeconds = &t, &lag
+	}
+	if c.LastRunAt != nil {
+		t := jsontime.New(*c.LastRunAt)
+		out.LastRunAt = &t
+	}
+	return out
+}
+
+// ArchiveStatusResponse is the wire shape for GET /api/archive/status.
+type ArchiveStatusResponse struct {
+	Enabled bool                    `json:"enabled" doc:"Whether FC_ARCHIVE_STORE_URI is set"`
+	Streams []ArchiveStreamResponse `json:"streams"`
+}
+
+// StartArchiveReplayRequest is the wire body for POST /api/archive/replays.
+type StartArchiveReplayRequest struct {
+	From           time.Time `json:"from" doc:"Replay events ingested at or after this time"`
+	To             time.Time `json:"to" doc:"Replay events ingested before this time; must already be archived"`
+	EventTypes     []string  `json:"eventTypes,omitempty" doc:"Event type patterns (* matches a segment); every type when omitted"`
+	ClientID       *string   `json:"clientId,omitempty" doc:"Replay only this client's events"`
+	SubscriptionID *string   `json:"subscriptionId,omitempty" doc:"Deliver the replayed events to this subscription only"`
+}
+
+func (r StartArchiveReplayRequest) toCommand() operations.StartReplayCommand {
+	return operations.StartReplayCommand{
+		From:           r.From,
+		To:             r.To,
+		EventTypes:     r.EventTypes,
+		ClientID:       r.ClientID,
+		SubscriptionID: r.SubscriptionID,
+	}
+}
+
+// ArchiveReplayResponse mirrors archive.Replay.
+type ArchiveReplayResponse struct {
+	ID             string           `json:"id"`
+	From           httpcompat.Time  `json:"from"`
+	To             httpcompat.Time  `json:"to"`
+	EventTypes     []string         `json:"eventTypes"`
+	ClientID       *string          `json:"clientId,omitempty"`
+	SubscriptionID *string          `json:"subscriptionId,omitempty"`
+	Status         string           `json:"status" doc:"PENDING, RUNNING, COMPLETED, FAILED or CANCELLED"`
+	FilesRead      int              `json:"filesRead" doc:"Archive objects read so far"`
+	EventsRead     int64            `json:"eventsRead" doc:"Archived events read so far"`
+	EventsReplayed int64            `json:"eventsReplayed" doc:"Events re-ingested so far"`
+	Error          *string          `json:"error,omitempty"`
+	RequestedBy    *string          `json:"requestedBy,omitempty"`
+	CreatedAt      httpcompat.Time  `json:"createdAt"`
+	StartedAt      *httpcompat.Time `json:"startedAt,omitempty"`
+	CompletedAt    *httpcompat.Time `json:"completedAt,omitempty"`
+	UpdatedAt      httpcompat.Time  `json:"updatedAt"`
+}
+
+func fromReplay(r *archive.Replay) ArchiveReplayResponse {
+	tp := func(t *time.Time) *httpcompat.Time {
+		if t == nil {
+			return nil
+		}
+		v := jsontime.New(*t)
+		return &v
+	}
+	return ArchiveReplayResponse{
+		ID:             r.ID,
+		From:           jsontime.New(r.From),
+		To:             jsontime.New(r.To),
+		EventTypes:     r.EventTypes,
+		ClientID:       r.ClientID,
+		SubscriptionID: r.SubscriptionID,
+		Status:         string(r.Status),
+		FilesRead:      r.FilesRead,
+		EventsRead:     r.EventsRead,
+		EventsReplayed: r.EventsReplayed,
+		Error:          r.Error,
+		RequestedBy:    r.RequestedBy,
+		CreatedAt:      jsontime.New(r.CreatedAt),
+		StartedAt:      tp(r.StartedAt),
+		CompletedAt:    tp(r.CompletedAt),
+		UpdatedAt:      jsontime.New(r.UpdatedAt),
+	}
+}
+
+// ArchiveReplayListResponse is the wire shape for GET /api/archive/replays.
+type ArchiveReplayListResponse struct {
+	Items []ArchiveReplayResponse `json:"items"`
+}
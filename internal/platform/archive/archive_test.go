@@ -0,0 +1,154 @@
+package archive
+
+import (
+	"bufio"
+	"bytes"
+	"compress/gzip"
+	"encoding/json"
+	"reflect"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+func TestBuildFilesPartitionsByHour(t *testing.T) {
+	at := func(h, m int) time.Time { return time.Date(2026, 10, 16, h, m, 0, 0, time.UTC) }
+	items := []item{}
+	for i, ts := range []time.Time{at(9, 1), at(9, 59), at(10, 0), at(10, 30), at(12, 5)} {
+		e := &event.Event{ID: "0HZ" + string(rune('A'+i)), Type: "t", Data: json.RawMessage(`{"n":1}`), CreatedAt: ts}
+		items = append(items, item{id: e.ID, at: ts, record: NewEventRecord(e)})
+	}
+
+	files, err := buildFiles(KindEvents, items)
+	require.NoError(t, err)
+	require.Len(t, files, 3)
+	assert.Equal(t, "events/2026/10/16/09/0HZA-0HZB.jsonl.gz", files[0].Key)
+	assert.Equal(t, "events/2026/10/16/10/0HZC-0HZD.jsonl.gz", files[1].Key)
+	assert.Equal(t, "events/2026/10/16/12/0HZE-0HZE.jsonl.gz", files[2].Key)
+	assert.Equal(t, at(10, 0), files[1].PartitionHour)
+	assert.Equal(t, 2, files[1].Records)
+	assert.Equal(t, at(10, 30), files[1].MaxTime)
+	assert.Equal(t, EventSchemaVersion, files[0].SchemaVersion)
+	assert.Equal(t, int64(len(files[0].body)), files[0].Bytes)
+
+	zr, err := gzip.NewReader(bytes.NewReader(files[0].body))
+	require.NoError(t, err)
+	sc := bufio.NewScanner(zr)
+	var lines []map[string]any
+	for sc.Scan() {
+		var m map[string]any
+		require.NoError(t, json.Unmarshal(sc.Bytes(), &m))
+		lines = append(lines, m)
+	}
+	require.NoError(t, sc.Err())
+	require.Len(t, lines, 2)
+	assert.Equal(t, "0HZA", lines[0]["id"])
+	assert.Equal(t, float64(EventSchemaVersion), lines[0]["schemaVersion"])
+	assert.Equal(t, map[string]any{"n": float64(1)}, lines[0]["data"])
+}
+
+func TestKeySafe(t *testing.T) {
+	assert.Equal(t, "evt_0HZ_a_b", keySafe("evt_0HZ/a.b"))
+}
+
+// TestSchemasMatchRecords keeps each published schema in step with the
+// record it describes: a field added to a record must be added to the
+// schema, with the version that introduced it.
+func TestSchemasMatchRecords(t *testing.T) {
+	for kind, record := range map[Kind]any{KindEvents: EventRecord{}, KindDeliveries: DeliveryRecord{}} {
+		s := CurrentSchema(kind)
+		assert.Equal(t, kind, s.Kind)
+		var names []string
+		maxSince := 0
+		for _, f := range s.Fields {
+			names = append(names, f.Name)
+			assert.LessOrEqual(t, f.Since, s.Version, "%s.%s", kind, f.Name)
+			maxSince = max(maxSince, f.Since)
+		}
+		assert.Equal(t, s.Version, maxSince, "%s: the version is the newest field's", kind)
+		assert.Equal(t, jsonNames(record), names, "%s", kind)
+	}
+}
+
+func jsonNames(v any) []string {
+	rt := reflect.TypeOf(v)
+	out := make([]string, 0, rt.NumField())
+	for i := range rt.NumField() {
+		name, _, _ := strings.Cut(rt.Field(i).Tag.Get("json"), ",")
+		out = append(out, name)
+	}
+	return out
+}
+
+func TestParseKind(t *testing.T) {
+	k, ok := ParseKind("DELIVERIES")
+	assert.True(t, ok)
+	assert.Equal(t, KindDeliveries, k)
+	_, ok = ParseKind("events")
+	assert.False(t, ok)
+	assert.Equal(t, "_schemas/deliveries/v1.json", SchemaKey(KindDeliveries, 1))
+}
+
+func TestReplaySelects(t *testing.T) {
+	at := func(h int) time.Time { return time.Date(2026, 10, 16, h, 0, 0, 0, time.UTC) }
+	acme, other := "clt_acme", "clt_other"
+	rp := NewReplay(at(9), at(11), []string{"orders:*:created"}, &acme, nil)
+	rec := func(typ string, client *string, created time.Time) *EventRecord {
+		return &EventRecord{ID: "0HZA", Type: typ, ClientID: client, CreatedAt: created}
+	}
+
+	assert.True(t, rp.Selects(rec("orders:eu:created", &acme, at(9)), nil), "from is inclusive")
+	assert.False(t, rp.Selects(rec("orders:eu:created", &acme, at(11)), nil), "to is exclusive")
+	assert.False(t, rp.Selects(rec("orders:eu:shipped", &acme, at(10)), nil), "type not selected")
+	assert.False(t, rp.Selects(rec("orders:eu:created", &other, at(10)), nil), "other client")
+	assert.False(t, rp.Selects(rec("orders:eu:created", nil, at(10)), nil), "no client")
+
+	sub := &subscription.Subscription{EventTypes: []subscription.EventTypeBinding{subscription.NewEventTypeBinding("orders:us:*")}}
+	assert.False(t, rp.Selects(rec("orders:eu:created", &acme, at(10)), sub), "target doesn't match")
+	assert.True(t, rp.Selects(rec("orders:us:created", &acme, at(10)), sub))
+
+	all := NewReplay(at(9), at(11), nil, nil, nil)
+	assert.True(t, all.Selects(rec("anything", nil, at(10)), nil))
+}
+
+func TestReplayedEvent(t *testing.T) {
+	subID := "sub_0HZ"
+	rp := NewReplay(time.Unix(0, 0), time.Now(), nil, nil, &subID)
+	orig := time.Date(2026, 10, 16, 9, 0, 0, 0, time.UTC)
+	rec := &EventRecord{
+		ID:        "0HZORIG",
+		Type:      "orders:created",
+		Source:    "shop",
+		Time:      orig,
+		Data:      json.RawMessage(`{"n":1}`),
+		Context:   []event.ContextEntry{{Key: "tenant", Value: "t1"}, {Key: ContextReplay, Value: "arp_OLD"}},
+		CreatedAt: orig,
+	}
+	now := time.Date(2026, 10, 17, 0, 0, 0, 0, time.UTC)
+
+	e := rp.ReplayedEvent(rec, now)
+	assert.NotEqual(t, rec.ID, e.ID)
+	assert.Equal(t, "1.0", e.SpecVersion)
+	assert.Equal(t, orig, e.Time)
+	assert.Equal(t, now, e.CreatedAt)
+	assert.Equal(t, "replay:"+rp.ID+":0HZORIG", e.DeduplicationID)
+	assert.Equal(t, &subID, e.ReplaySubscriptionID)
+	assert.Equal(t, []event.ContextEntry{
+		{Key: "tenant", Value: "t1"},
+		{Key: ContextReplay, Value: rp.ID},
+		{Key: ContextReplayOf, Value: "0HZORIG"},
+	}, e.Context)
+}
+
+func TestParseReplayStatus(t *testing.T) {
+	assert.Equal(t, ReplayCancelled, ParseReplayStatus("CANCELLED"))
+	assert.Equal(t, ReplayPending, ParseReplayStatus("bogus"))
+	assert.True(t, ReplayFailed.IsTerminal())
+	assert.False(t, ReplayRunning.IsTerminal())
+}
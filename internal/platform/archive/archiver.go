@@ -0,0 +1,305 @@
+package archive
+
+import (
+	"bytes"
+	"compress/gzip"
+	"context"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// contentType is what archive objects are stored as: gzipped JSON lines.
+const contentType = "application/gzip"
+
+// Config tunes the archiver.
+type Config struct {
+	// Interval between runs. Defaults to 5m.
+	Interval time.Duration
+	// Settle is how old a record must be before it is archived, so one
+	// whose transaction commits late with an earlier timestamp doesn't
+	// land behind the cursor. Defaults to 1m.
+	Settle time.Duration
+	// BatchSize is the records read per batch. Defaults to 5000.
+	BatchSize int
+	// MaxBatches bounds the batches per stream per run, so a backlog is
+	// worked through over several runs. Defaults to 20.
+	MaxBatches int
+}
+
+// Deps are the archiver's collaborators.
+type Deps struct {
+	Repo   *Repository
+	Events *event.Repository
+	Jobs   *dispatchjob.Repository
+	// Store is the archive's object store (FC_ARCHIVE_STORE_URI).
+	Store blobstore.Store
+	// Blobs reads offloaded event data back so the archive holds it
+	// inline; nil when no payload blob store is configured, in which case
+	// a reference is archived as it is.
+	Blobs *blobstore.Offloader
+}
+
+// Archiver copies each stream's new records to the store on a schedule.
+type Archiver struct {
+	deps Deps
+	cfg  Config
+
+	// IsLeader gates archiving: a cursor has one writer. Nil means always
+	// leader.
+	IsLeader func() bool
+
+	schemasWritten bool
+}
+
+// NewArchiver builds an archiver.
+func NewArchiver(deps Deps, cfg Config) *Archiver {
+	if cfg.Interval <= 0 {
+		cfg.Interval = 5 * time.Minute
+	}
+	if cfg.Settle <= 0 {
+		cfg.Settle = time.Minute
+	}
+	if cfg.BatchSize <= 0 {
+		cfg.BatchSize = 5000
+	}
+	if cfg.MaxBatches <= 0 {
+		cfg.MaxBatches = 20
+	}
+	return &Archiver{deps: deps, cfg: cfg}
+}
+
+// Run archives every Interval until ctx is cancelled.
+func (a *Archiver) Run(ctx context.Context) {
+	slog.Info("event archiver started", "interval", a.cfg.Interval)
+	tick := time.NewTicker(a.cfg.Interval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("event archiver stopped")
+			return
+		case <-tick.C:
+			if a.IsLeader != nil && !a.IsLeader() {
+				continue
+			}
+			a.ArchiveAll(ctx)
+		}
+	}
+}
+
+// ArchiveAll runs each stream once. A failing stream is recorded and
+// logged; the others still run.
+func (a *Archiver) ArchiveAll(ctx context.Context) {
+	if !a.schemasWritten {
+		if err := a.writeSchemas(ctx); err != nil {
+			slog.Warn("archive: write schemas failed", "err", err)
+		} else {
+			a.schemasWritten = true
+		}
+	}
+	cursors, err := a.deps.Repo.Cursors(ctx)
+	if err != nil {
+		slog.Error("archive: load cursors failed", "err", err)
+		return
+	}
+	for _, kind := range Kinds() {
+		n, runErr := a.archive(ctx, kind, cursors[kind])
+		if runErr != nil {
+			slog.Warn("archive: run failed", "kind", kind, "archived", n, "err", runErr)
+			msg := runErr.Error()
+			if err := a.deps.Repo.RecordRun(ctx, kind, &msg); err != nil {
+				slog.Error("archive: record run failed", "kind", kind, "err", err)
+			}
+			continue
+		}
+		if n == 0 {
+			if err := a.deps.Repo.RecordRun(ctx, kind, nil); err != nil {
+				slog.Error("archive: record run failed", "kind", kind, "err", err)
+			}
+			continue
+		}
+		slog.Info("archive: records archived", "kind", kind, "records", n)
+	}
+}
+
+// archive writes up to MaxBatches batches of kind after cur, committing
+// each before reading the next, and returns the records archived.
+func (a *Archiver) archive(ctx context.Context, kind Kind, cur Cursor) (int, error) {
+	total := 0
+	for range a.cfg.MaxBatches {
+		items, err := a.read(ctx, kind, cur)
+		if err != nil {
+			return total, fmt.Errorf("read: %w", err)
+		}
+		if len(items) == 0 {
+			break
+		}
+		pending, err := buildFiles(kind, items)
+		if err != nil {
+			return total, err
+		}
+		files := make([]File, 0, len(pending))
+		for _, p := range pending {
+			if err := a.deps.Store.Put(ctx, p.Key, p.body, contentType); err != nil {
+				return total, fmt.Errorf("write %s: %w", p.Key, err)
+			}
+			files = append(files, p.File)
+		}
+		last := items[len(items)-1]
+		if err := a.deps.Repo.Commit(ctx, kind, files, last.at, last.id); err != nil {
+			return total, fmt.Errorf("commit: %w", err)
+		}
+		cur.AfterTime, cur.AfterID = last.at, last.id
+		total += len(items)
+		if len(items) < a.cfg.BatchSize {
+			break
+		}
+	}
+	return total, nil
+}
+
+// item is one record ready to archive: its id, the time it is ordered and
+// partitioned by, and the record written for it.
+type item struct {
+	id     string
+	at     time.Time
+	record any
+}
+
+// read returns the next batch of kind after cur.
+func (a *Archiver) read(ctx context.Context, kind Kind, cur Cursor) ([]item, error) {
+	if kind == KindDeliveries {
+		jobs, err := a.deps.Jobs.FindTerminalPage(ctx, dispatchjob.TerminalParams{
+			AfterTime: cur.AfterTime, AfterID: cur.AfterID, Settle: a.cfg.Settle, Limit: a.cfg.BatchSize,
+		})
+		if err != nil {
+			return nil, err
+		}
+		items := make([]item, 0, len(jobs))
+		for i := range jobs {
+			j := &jobs[i]
+			items = append(items, item{id: j.ID, at: j.UpdatedAt, record: NewDeliveryRecord(j)})
+		}
+		return items, nil
+	}
+	events, err := a.deps.Events.FindFeed(ctx, event.FeedParams{
+		AfterTime: cur.AfterTime, AfterID: cur.AfterID, Settle: a.cfg.Settle, Limit: a.cfg.BatchSize,
+	})
+	if err != nil {
+		return nil, err
+	}
+	items := make([]item, 0, len(events))
+	for i := range events {
+		e := &events[i]
+		if ref, ok := blobstore.ParseRef(e.Data); ok && a.deps.Blobs.Enabled() {
+			data, err := a.deps.Blobs.Fetch(ctx, ref)
+			if err != nil {
+				return nil, fmt.Errorf("event %s: %w", e.ID, err)
+			}
+			e.Data = data
+		}
+		items = append(items, item{id: e.ID, at: e.CreatedAt, record: NewEventRecord(e)})
+	}
+	return items, nil
+}
+
+// writeSchemas publishes each stream's current schema under _schemas/.
+func (a *Archiver) writeSchemas(ctx context.Context) error {
+	for _, kind := range Kinds() {
+		s := CurrentSchema(kind)
+		body, err := json.MarshalIndent(s, "", "  ")
+		if err != nil {
+			return err
+		}
+		if err := a.deps.Store.Put(ctx, SchemaKey(kind, s.Version), body, "application/json"); err != nil {
+			return fmt.Errorf("write %s schema: %w", kind, err)
+		}
+	}
+	return nil
+}
+
+// SchemaKey is where the schema of kind at version is published.
+func SchemaKey(kind Kind, version int) string {
+	return "_schemas/" + kind.dir() + "/v" + strconv.Itoa(version) + ".json"
+}
+
+// pendingFile is an object built but not yet written.
+type pendingFile struct {
+	File
+	body []byte
+}
+
+// buildFiles encodes items, which are in stream order, into one object
+// per partition hour they span.
+func buildFiles(kind Kind, items []item) ([]pendingFile, error) {
+	var out []pendingFile
+	start := 0
+	for i := 1; i <= len(items); i++ {
+		if i < len(items) && partitionHour(items[i].at).Equal(partitionHour(items[start].at)) {
+			continue
+		}
+		f, err := encodeFile(kind, items[start:i])
+		if err != nil {
+			return nil, err
+		}
+		out = append(out, f)
+		start = i
+	}
+	return out, nil
+}
+
+// encodeFile encodes items of one partition hour as gzipped JSON lines.
+func encodeFile(kind Kind, items []item) (pendingFile, error) {
+	var buf bytes.Buffer
+	zw := gzip.NewWriter(&buf)
+	enc := json.NewEncoder(zw)
+	enc.SetEscapeHTML(false)
+	for _, it := range items {
+		if err := enc.Encode(it.record); err != nil {
+			return pendingFile{}, fmt.Errorf("encode %s: %w", it.id, err)
+		}
+	}
+	if err := zw.Close(); err != nil {
+		return pendingFile{}, err
+	}
+	first, last := items[0], items[len(items)-1]
+	hour := partitionHour(first.at)
+	return pendingFile{
+		File: File{
+			ID:            tsid.Generate(tsid.ArchiveFile),
+			Kind:          kind,
+			Key:           kind.dir() + "/" + hour.Format("2006/01/02/15") + "/" + keySafe(first.id) + "-" + keySafe(last.id) + ".jsonl.gz",
+			SchemaVersion: CurrentSchema(kind).Version,
+			PartitionHour: hour,
+			Records:       len(items),
+			Bytes:         int64(buf.Len()),
+			FirstID:       first.id,
+			LastID:        last.id,
+			MinTime:       first.at,
+			MaxTime:       last.at,
+		},
+		body: buf.Bytes(),
+	}, nil
+}
+
+func partitionHour(t time.Time) time.Time { return t.UTC().Truncate(time.Hour) }
+
+// keySafe maps an id onto the characters object keys are made of.
+func keySafe(id string) string {
+	return strings.Map(func(r rune) rune {
+		switch {
+		case r >= 'a' && r <= 'z', r >= 'A' && r <= 'Z', r >= '0' && r <= '9', r == '_':
+			return r
+		}
+		return '_'
+	}, id)
+}
@@ -0,0 +1,114 @@
+// Package archive is the event lake. A leader-gated archiver copies every
+// ingested event, and every dispatch job's final status, out of Postgres
+// into an object store as gzipped JSONL, partitioned by hour, and keeps a
+// manifest of the objects it wrote in msg_archive_files. The archive
+// outlives the msg_events / msg_dispatch_jobs retention window and is what
+// analytics engines (Athena, BigQuery external tables, DuckDB, Spark) read.
+//
+// Objects are laid out under the store FC_ARCHIVE_STORE_URI names as
+//
+//	events/2026/10/16/09/<firstId>-<lastId>.jsonl.gz
+//	deliveries/2026/10/16/09/<firstId>-<lastId>.jsonl.gz
+//	_schemas/events/v1.json
+//
+// An event is partitioned by the UTC hour it was ingested; a delivery, by
+// the hour it reached its final status. The manifest, not a bucket
+// listing, says what is archived: an object missing from it is left over
+// from a run that failed before it committed, and is rewritten.
+//
+// Schemas evolve additively. Every record carries its schemaVersion;
+// fields are never renamed, retyped or removed; and each field in a
+// stream's schema records the version that introduced it, so a reader
+// takes a field missing from an older object as null. Each stream's
+// current schema is written to _schemas/ and served by the manifest API.
+//
+// A Replay reads the EVENTS objects of a time range back and re-ingests
+// the events it selects as new ones; see [Replay] and [Replayer].
+package archive
+
+import (
+	"time"
+)
+
+// Kind names an archived stream.
+type Kind string
+
+const (
+	// KindEvents is every ingested event, as stored in msg_events.
+	KindEvents Kind = "EVENTS"
+	// KindDeliveries is every dispatch job that reached COMPLETED, FAILED,
+	// CANCELLED or EXPIRED. A job requeued afterwards is archived again
+	// when it next ends; the record with the latest updatedAt wins.
+	KindDeliveries Kind = "DELIVERIES"
+)
+
+// Kinds lists the streams, in the order the archiver runs them.
+func Kinds() []Kind { return []Kind{KindEvents, KindDeliveries} }
+
+// ParseKind returns the kind s names, or false.
+func ParseKind(s string) (Kind, bool) {
+	switch Kind(s) {
+	case KindEvents, KindDeliveries:
+		return Kind(s), true
+	}
+	return "", false
+}
+
+// dir is the kind's top-level directory in the store.
+func (k Kind) dir() string {
+	if k == KindDeliveries {
+		return "deliveries"
+	}
+	return "events"
+}
+
+// Field is one field of a stream's records.
+type Field struct {
+	Name string `json:"name"`
+	// Type is string, timestamp (RFC 3339), integer, boolean, or json
+	// (any JSON value, kept as written).
+	Type string `json:"type"`
+	// Since is the schema version that added the field.
+	Since int `json:"since"`
+}
+
+// Schema is a stream's record layout at a version.
+type Schema struct {
+	Kind    Kind    `json:"kind"`
+	Version int     `json:"version"`
+	Fields  []Field `json:"fields"`
+}
+
+// File is a manifest entry: one object written to the store.
+type File struct {
+	ID            string
+	Kind          Kind
+	Key           string
+	SchemaVersion int
+	// PartitionHour is the UTC hour the object's records fall in.
+	PartitionHour time.Time
+	Records       int
+	Bytes         int64
+	// FirstID and LastID are the first and last record ids, in the order
+	// the stream is read.
+	FirstID string
+	LastID  string
+	// MinTime and MaxTime bound the records' partitioning timestamps.
+	MinTime   time.Time
+	MaxTime   time.Time
+	CreatedAt time.Time
+}
+
+// Cursor is how far a stream has been archived: everything up to and
+// including (AfterTime, AfterID) in the stream's (time, id) order.
+type Cursor struct {
+	Kind      Kind
+	AfterTime time.Time
+	AfterID   string
+	// Archived counts the records written since the stream started.
+	Archived  int64
+	LastRunAt *time.Time
+	// LastError is why the last run failed; nil after a clean one.
+	LastError *string
+	UpdatedAt time.Time
+}
@@ -0,0 +1,55 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// CancelReplayCommand is the input DTO.
+type CancelReplayCommand struct {
+	ID string `json:"id"`
+}
+
+// CancelReplay stops a replay that hasn't finished. The replayer notices
+// at the end of its current pass; events already replayed stay. Emits
+// [ArchiveReplayCancelled].
+func CancelReplay(repo *archive.Repository) usecaseop.Operation[CancelReplayCommand, ArchiveReplayCancelled] {
+	return usecaseop.Operation[CancelReplayCommand, ArchiveReplayCancelled]{
+		Name: "CancelArchiveReplay",
+		Validate: func(_ context.Context, cmd CancelReplayCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "id is required")
+			}
+			return nil
+		},
+		Authorize: func(ctx context.Context, _ CancelReplayCommand) error {
+			return auth.RequireAnchor(auth.FromContext(ctx))
+		},
+		Execute: func(ctx context.Context, cmd CancelReplayCommand, ec usecase.ExecutionContext) (usecaseop.Plan[ArchiveReplayCancelled], error) {
+			rp, err := repo.FindReplay(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if rp == nil {
+				return nil, httperror.NotFound("ArchiveReplay", cmd.ID)
+			}
+			if rp.Status.IsTerminal() {
+				return nil, usecase.Conflict("REPLAY_FINISHED",
+					"Archive replay '"+rp.ID+"' is already "+string(rp.Status))
+			}
+			rp.Cancel()
+			event := ArchiveReplayCancelled{
+				Metadata:       usecase.NewEventMetadata(ec, ArchiveReplayCancelledType, Source, subjectFor(rp.ID)),
+				ReplayID:       rp.ID,
+				EventsReplayed: rp.EventsReplayed,
+			}
+			return usecaseop.Save(rp, repo, event), nil
+		},
+	}
+}
@@ -0,0 +1,83 @@
+// Package operations holds the archive replay use cases.
+package operations
+
+import (
+	"encoding/json"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+const (
+	ArchiveReplayRequestedType = "platform:admin:archive-replay:requested"
+	ArchiveReplayCancelledType = "platform:admin:archive-replay:cancelled"
+	Source                     = "platform:admin"
+)
+
+func subjectFor(id string) string { return "platform.archive-replay." + id }
+
+func groupFor(id string) string { return "platform:archive-replay:" + id }
+
+// ArchiveReplayRequested is emitted when a replay from the archive is
+// accepted.
+type ArchiveReplayRequested struct {
+	Metadata       usecase.EventMetadata
+	ReplayID       string
+	From           time.Time
+	To             time.Time
+	EventTypes     []string
+	ClientID       *string
+	SubscriptionID *string
+}
+
+func (e ArchiveReplayRequested) EventID() string       { return e.Metadata.EventID }
+func (e ArchiveReplayRequested) EventType() string     { return ArchiveReplayRequestedType }
+func (e ArchiveReplayRequested) SpecVersion() string   { return "1.0" }
+func (e ArchiveReplayRequested) Source() string        { return Source }
+func (e ArchiveReplayRequested) Subject() string       { return subjectFor(e.ReplayID) }
+func (e ArchiveReplayRequested) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e ArchiveReplayRequested) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e ArchiveReplayRequested) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e ArchiveReplayRequested) CausationID() string   { return e.Metadata.CausationID }
+func (e ArchiveReplayRequested) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e ArchiveReplayRequested) MessageGroup() string  { return groupFor(e.ReplayID) }
+func (e ArchiveReplayRequested) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		ReplayID       string   `json:"replayId"`
+		From           string   `json:"from"`
+		To             string   `json:"to"`
+		EventTypes     []string `json:"eventTypes"`
+		ClientID       *string  `json:"clientId,omitempty"`
+		SubscriptionID *string  `json:"subscriptionId,omitempty"`
+	}{
+		e.ReplayID, e.From.UTC().Format(time.RFC3339), e.To.UTC().Format(time.RFC3339),
+		e.EventTypes, e.ClientID, e.SubscriptionID,
+	})
+}
+
+// ArchiveReplayCancelled is emitted when a replay is stopped before it
+// finished. EventsReplayed is how many events it had re-ingested by the
+// time the cancellation was accepted.
+type ArchiveReplayCancelled struct {
+	Metadata       usecase.EventMetadata
+	ReplayID       string
+	EventsReplayed int64
+}
+
+func (e ArchiveReplayCancelled) EventID() string       { return e.Metadata.EventID }
+func (e ArchiveReplayCancelled) EventType() string     { return ArchiveReplayCancelledType }
+func (e ArchiveReplayCancelled) SpecVersion() string   { return "1.0" }
+func (e ArchiveReplayCancelled) Source() string        { return Source }
+func (e ArchiveReplayCancelled) Subject() string       { return subjectFor(e.ReplayID) }
+func (e ArchiveReplayCancelled) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e ArchiveReplayCancelled) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e ArchiveReplayCancelled) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e ArchiveReplayCancelled) CausationID() string   { return e.Metadata.CausationID }
+func (e ArchiveReplayCancelled) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e ArchiveReplayCancelled) MessageGroup() string  { return groupFor(e.ReplayID) }
+func (e ArchiveReplayCancelled) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		ReplayID       string `json:"replayId"`
+		EventsReplayed int64  `json:"eventsReplayed"`
+	}{e.ReplayID, e.EventsReplayed})
+}
@@ -0,0 +1,107 @@
+package operations
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// StartReplayCommand is the input DTO. From and To bound the ingestion
+// time of the archived events: From inclusive, To exclusive.
+type StartReplayCommand struct {
+	From           time.Time `json:"from"`
+	To             time.Time `json:"to"`
+	EventTypes     []string  `json:"eventTypes"`
+	ClientID       *string   `json:"clientId,omitempty"`
+	SubscriptionID *string   `json:"subscriptionId,omitempty"`
+}
+
+// StartReplay records a PENDING replay from the archive and emits
+// [ArchiveReplayRequested]; the replayer does the work asynchronously. The
+// range must already be archived, and a target subscription must exist.
+// Anchor-only: a replay crosses every tenant's events.
+func StartReplay(repo *archive.Repository, subs *subscription.Repository) usecaseop.Operation[StartReplayCommand, ArchiveReplayRequested] {
+	return usecaseop.Operation[StartReplayCommand, ArchiveReplayRequested]{
+		Name: "StartArchiveReplay",
+		Validate: func(_ context.Context, cmd StartReplayCommand) error {
+			if cmd.From.IsZero() || cmd.To.IsZero() {
+				return usecase.Validation("RANGE_REQUIRED", "from and to are required")
+			}
+			if !cmd.From.Before(cmd.To) {
+				return usecase.Validation("INVALID_RANGE", "from must be before to")
+			}
+			if len(cmd.EventTypes) > archive.MaxReplayEventTypes {
+				return usecase.Validation("TOO_MANY_EVENT_TYPES",
+					fmt.Sprintf("at most %d event types are allowed", archive.MaxReplayEventTypes))
+			}
+			for _, t := range cmd.EventTypes {
+				if strings.TrimSpace(t) == "" {
+					return usecase.Validation("INVALID_EVENT_TYPE", "event types must not be blank")
+				}
+			}
+			return nil
+		},
+		Authorize: func(ctx context.Context, _ StartReplayCommand) error {
+			return auth.RequireAnchor(auth.FromContext(ctx))
+		},
+		Execute: func(ctx context.Context, cmd StartReplayCommand, ec usecase.ExecutionContext) (usecaseop.Plan[ArchiveReplayRequested], error) {
+			if cmd.SubscriptionID != nil {
+				sub, err := subs.FindByID(ctx, *cmd.SubscriptionID)
+				if err != nil {
+					return nil, usecase.Internal("REPO", "find_subscription failed", err)
+				}
+				if sub == nil {
+					return nil, httperror.NotFound("Subscription", *cmd.SubscriptionID)
+				}
+			}
+			cursors, err := repo.Cursors(ctx)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_cursors failed", err)
+			}
+			// The replayer reads the manifest as it stands; a range the
+			// archiver hasn't reached would end early without saying so.
+			if c, ok := cursors[archive.KindEvents]; !ok || c.AfterID == "" || c.AfterTime.Before(cmd.To) {
+				until := "nothing yet"
+				if ok && c.AfterID != "" {
+					until = c.AfterTime.UTC().Format(time.RFC3339)
+				}
+				return nil, usecase.Validation("RANGE_NOT_ARCHIVED",
+					"to is later than the last archived event ("+until+")")
+			}
+
+			types := make([]string, 0, len(cmd.EventTypes))
+			seen := map[string]bool{}
+			for _, t := range cmd.EventTypes {
+				t = strings.TrimSpace(t)
+				if !seen[t] {
+					seen[t] = true
+					types = append(types, t)
+				}
+			}
+			rp := archive.NewReplay(cmd.From, cmd.To, types, cmd.ClientID, cmd.SubscriptionID)
+			if ec.PrincipalID != "" {
+				by := ec.PrincipalID
+				rp.RequestedBy = &by
+			}
+
+			event := ArchiveReplayRequested{
+				Metadata:       usecase.NewEventMetadata(ec, ArchiveReplayRequestedType, Source, subjectFor(rp.ID)),
+				ReplayID:       rp.ID,
+				From:           rp.From,
+				To:             rp.To,
+				EventTypes:     rp.EventTypes,
+				ClientID:       rp.ClientID,
+				SubscriptionID: rp.SubscriptionID,
+			}
+			return usecaseop.Save(rp, repo, event), nil
+		},
+	}
+}
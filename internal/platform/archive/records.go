@@ -0,0 +1,161 @@
+package archive
+
+import (
+	"encoding/json"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+)
+
+// Current schema versions. Adding a field to a record means adding it to
+// the stream's fields below with the next version as its Since, and
+// bumping the constant; nothing else about a published field may change.
+const (
+	EventSchemaVersion    = 1
+	DeliverySchemaVersion = 1
+)
+
+var eventFields = []Field{
+	{"schemaVersion", "integer", 1},
+	{"id", "string", 1},
+	{"specVersion", "string", 1},
+	{"type", "string", 1},
+	{"source", "string", 1},
+	{"subject", "string", 1},
+	{"time", "timestamp", 1},
+	{"data", "json", 1},
+	{"deduplicationId", "string", 1},
+	{"clientId", "string", 1},
+	{"messageGroup", "string", 1},
+	{"correlationId", "string", 1},
+	{"causationId", "string", 1},
+	{"context", "json", 1},
+	{"createdAt", "timestamp", 1},
+}
+
+var deliveryFields = []Field{
+	{"schemaVersion", "integer", 1},
+	{"id", "string", 1},
+	{"eventId", "string", 1},
+	{"kind", "string", 1},
+	{"code", "string", 1},
+	{"subject", "string", 1},
+	{"clientId", "string", 1},
+	{"subscriptionId", "string", 1},
+	{"dispatchPoolId", "string", 1},
+	{"messageGroup", "string", 1},
+	{"correlationId", "string", 1},
+	{"targetUrl", "string", 1},
+	{"protocol", "string", 1},
+	{"mode", "string", 1},
+	{"status", "string", 1},
+	{"attemptCount", "integer", 1},
+	{"lastError", "string", 1},
+	{"durationMillis", "integer", 1},
+	{"createdAt", "timestamp", 1},
+	{"completedAt", "timestamp", 1},
+	{"updatedAt", "timestamp", 1},
+}
+
+// CurrentSchema returns the schema records of kind are written with.
+func CurrentSchema(k Kind) Schema {
+	if k == KindDeliveries {
+		return Schema{Kind: k, Version: DeliverySchemaVersion, Fields: deliveryFields}
+	}
+	return Schema{Kind: KindEvents, Version: EventSchemaVersion, Fields: eventFields}
+}
+
+// EventRecord is one line of an EVENTS object.
+type EventRecord struct {
+	SchemaVersion   int                  `json:"schemaVersion"`
+	ID              string               `json:"id"`
+	SpecVersion     string               `json:"specVersion"`
+	Type            string               `json:"type"`
+	Source          string               `json:"source"`
+	Subject         string               `json:"subject,omitempty"`
+	Time            time.Time            `json:"time"`
+	Data            json.RawMessage      `json:"data,omitempty"`
+	DeduplicationID string               `json:"deduplicationId,omitempty"`
+	ClientID        *string              `json:"clientId,omitempty"`
+	MessageGroup    *string              `json:"messageGroup,omitempty"`
+	CorrelationID   *string              `json:"correlationId,omitempty"`
+	CausationID     *string              `json:"causationId,omitempty"`
+	Context         []event.ContextEntry `json:"context,omitempty"`
+	CreatedAt       time.Time            `json:"createdAt"`
+}
+
+// NewEventRecord maps e, whose data the caller has already read back from
+// the blob store if it was offloaded.
+func NewEventRecord(e *event.Event) EventRecord {
+	return EventRecord{
+		SchemaVersion:   EventSchemaVersion,
+		ID:              e.ID,
+		SpecVersion:     e.SpecVersion,
+		Type:            e.Type,
+		Source:          e.Source,
+		Subject:         e.Subject,
+		Time:            e.Time.UTC(),
+		Data:            e.Data,
+		DeduplicationID: e.DeduplicationID,
+		ClientID:        e.ClientID,
+		MessageGroup:    e.MessageGroup,
+		CorrelationID:   e.CorrelationID,
+		CausationID:     e.CausationID,
+		Context:         e.Context,
+		CreatedAt:       e.CreatedAt.UTC(),
+	}
+}
+
+// DeliveryRecord is one line of a DELIVERIES object: a dispatch job's
+// final status, without its payload (the event record carries the data).
+type DeliveryRecord struct {
+	SchemaVersion  int        `json:"schemaVersion"`
+	ID             string     `json:"id"`
+	EventID        *string    `json:"eventId,omitempty"`
+	Kind           string     `json:"kind"`
+	Code           string     `json:"code"`
+	Subject        *string    `json:"subject,omitempty"`
+	ClientID       *string    `json:"clientId,omitempty"`
+	SubscriptionID *string    `json:"subscriptionId,omitempty"`
+	DispatchPoolID *string    `json:"dispatchPoolId,omitempty"`
+	MessageGroup   *string    `json:"messageGroup,omitempty"`
+	CorrelationID  *string    `json:"correlationId,omitempty"`
+	TargetURL      string     `json:"targetUrl"`
+	Protocol       string     `json:"protocol"`
+	Mode           string     `json:"mode"`
+	Status         string     `json:"status"`
+	AttemptCount   int32      `json:"attemptCount"`
+	LastError      *string    `json:"lastError,omitempty"`
+	DurationMillis *int64     `json:"durationMillis,omitempty"`
+	CreatedAt      time.Time  `json:"createdAt"`
+	CompletedAt    *time.Time `json:"completedAt,omitempty"`
+	UpdatedAt      time.Time  `json:"updatedAt"`
+}
+
+// NewDeliveryRecord maps a job read in a terminal status.
+func NewDeliveryRecord(j *dispatchjob.DispatchJob) DeliveryRecord {
+	return DeliveryRecord{
+		SchemaVersion:  DeliverySchemaVersion,
+		ID:             j.ID,
+		EventID:        j.EventID,
+		Kind:           string(j.Kind),
+		Code:           j.Code,
+		Subject:        j.Subject,
+		ClientID:       j.ClientID,
+		SubscriptionID: j.SubscriptionID,
+		DispatchPoolID: j.DispatchPoolID,
+		MessageGroup:   j.MessageGroup,
+		CorrelationID:  j.CorrelationID,
+		TargetURL:      j.TargetURL,
+		Protocol:       string(j.Protocol),
+		Mode:           string(j.Mode),
+		Status:         string(j.Status),
+		AttemptCount:   j.AttemptCount,
+		LastError:      j.LastError,
+		DurationMillis: j.DurationMillis,
+		CreatedAt:      j.CreatedAt.UTC(),
+		CompletedAt:    j.CompletedAt,
+		UpdatedAt:      j.UpdatedAt.UTC(),
+	}
+}
@@ -0,0 +1,173 @@
+package archive
+
+import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// ReplayStatus is a replay's lifecycle.
+type ReplayStatus string
+
+const (
+	// ReplayPending: accepted, not yet picked up by the replayer.
+	ReplayPending ReplayStatus = "PENDING"
+	// ReplayRunning: the replayer has read some of its objects.
+	ReplayRunning ReplayStatus = "RUNNING"
+	// ReplayCompleted: every object in range was read.
+	ReplayCompleted ReplayStatus = "COMPLETED"
+	// ReplayFailed: a pass errored; the error is on the replay.
+	ReplayFailed ReplayStatus = "FAILED"
+	// ReplayCancelled: stopped on request. Events already replayed stay.
+	ReplayCancelled ReplayStatus = "CANCELLED"
+)
+
+// ParseReplayStatus is the lenient parser. Unknown → PENDING.
+func ParseReplayStatus(s string) ReplayStatus {
+	switch ReplayStatus(s) {
+	case ReplayRunning, ReplayCompleted, ReplayFailed, ReplayCancelled:
+		return ReplayStatus(s)
+	}
+	return ReplayPending
+}
+
+// IsTerminal reports whether the replayer is done with the replay.
+func (s ReplayStatus) IsTerminal() bool {
+	return s == ReplayCompleted || s == ReplayFailed || s == ReplayCancelled
+}
+
+// Context keys a replayed event is stamped with: the replay that wrote it
+// and the id of the archived event it is a copy of.
+const (
+	ContextReplay   = "archiveReplay"
+	ContextReplayOf = "archiveReplayOf"
+)
+
+// MaxReplayEventTypes bounds the event type patterns one replay may name.
+const MaxReplayEventTypes = 50
+
+// Replay is one msg_archive_replays row: a request to re-ingest the
+// archived events ingested in [From, To).
+//
+// Replayed events are new events — fresh ids, ingested now, with the
+// original event time, data and context plus the ContextReplay and
+// ContextReplayOf stamps — so they never collide with originals still in
+// msg_events, and fan out to today's subscriptions. With SubscriptionID
+// set they fan out to that subscription only, and only the events it
+// matches are replayed.
+type Replay struct {
+	ID   string
+	From time.Time
+	To   time.Time
+	// EventTypes are subscription-style patterns (`*` matches a segment);
+	// empty replays every type.
+	EventTypes     []string
+	ClientID       *string
+	SubscriptionID *string
+	Status         ReplayStatus
+	// AfterFileID is the last manifest entry fully replayed.
+	AfterFileID    *string
+	FilesRead      int
+	EventsRead     int64
+	EventsReplayed int64
+	Error          *string
+	RequestedBy    *string
+	CreatedAt      time.Time
+	StartedAt      *time.Time
+	CompletedAt    *time.Time
+	UpdatedAt      time.Time
+}
+
+// IDStr satisfies usecase.HasID.
+func (r Replay) IDStr() string { return r.ID }
+
+// NewReplay constructs a PENDING replay.
+func NewReplay(from, to time.Time, eventTypes []string, clientID, subscriptionID *string) *Replay {
+	now := time.Now().UTC()
+	if eventTypes == nil {
+		eventTypes = []string{}
+	}
+	return &Replay{
+		ID:             tsid.Generate(tsid.ArchiveReplay),
+		From:           from.UTC(),
+		To:             to.UTC(),
+		EventTypes:     eventTypes,
+		ClientID:       clientID,
+		SubscriptionID: subscriptionID,
+		Status:         ReplayPending,
+		CreatedAt:      now,
+		UpdatedAt:      now,
+	}
+}
+
+// Cancel stops the replay.
+func (r *Replay) Cancel() {
+	now := time.Now().UTC()
+	r.Status = ReplayCancelled
+	r.CompletedAt = &now
+	r.UpdatedAt = now
+}
+
+// Selects reports whether rec is one the replay re-ingests: ingested in
+// range, of a selected type, and of the replay's client. sub, when the
+// replay targets one, must also match it.
+func (r *Replay) Selects(rec *EventRecord, sub *subscription.Subscription) bool {
+	if rec.CreatedAt.Before(r.From) || !rec.CreatedAt.Before(r.To) {
+		return false
+	}
+	if r.ClientID != nil && (rec.ClientID == nil || *rec.ClientID != *r.ClientID) {
+		return false
+	}
+	if len(r.EventTypes) > 0 {
+		matched := false
+		for _, p := range r.EventTypes {
+			if subscription.NewEventTypeBinding(p).Matches(rec.Type) {
+				matched = true
+				break
+			}
+		}
+		if !matched {
+			return false
+		}
+	}
+	return sub == nil || (sub.MatchesEventType(rec.Type) && sub.MatchesClient(rec.ClientID))
+}
+
+// ReplayedEvent maps an archived record to the event the replay ingests
+// for it at now.
+func (r *Replay) ReplayedEvent(rec *EventRecord, now time.Time) event.Event {
+	ctx := make([]event.ContextEntry, 0, len(rec.Context)+2)
+	for _, c := range rec.Context {
+		if c.Key == ContextReplay || c.Key == ContextReplayOf {
+			continue
+		}
+		ctx = append(ctx, c)
+	}
+	ctx = append(ctx,
+		event.ContextEntry{Key: ContextReplay, Value: r.ID},
+		event.ContextEntry{Key: ContextReplayOf, Value: rec.ID},
+	)
+	specVersion := rec.SpecVersion
+	if specVersion == "" {
+		specVersion = "1.0"
+	}
+	return event.Event{
+		ID:                   tsid.GenerateUntyped(),
+		SpecVersion:          specVersion,
+		Type:                 rec.Type,
+		Source:               rec.Source,
+		Subject:              rec.Subject,
+		Time:                 rec.Time,
+		Data:                 rec.Data,
+		Context:              ctx,
+		DeduplicationID:      "replay:" + r.ID + ":" + rec.ID,
+		ClientID:             rec.ClientID,
+		MessageGroup:         rec.MessageGroup,
+		CorrelationID:        rec.CorrelationID,
+		CausationID:          rec.CausationID,
+		CreatedAt:            now,
+		ReplaySubscriptionID: r.SubscriptionID,
+	}
+}
@@ -0,0 +1,237 @@
+package archive
+
+import (
+	"bytes"
+	"compress/gzip"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"log/slog"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+// ReplayConfig tunes the replayer.
+type ReplayConfig struct {
+	// Interval between checks for an open replay. Defaults to 10s.
+	Interval time.Duration
+	// FilesPerPass is the objects read between progress writes, which is
+	// also how often a cancellation is noticed. Defaults to 10.
+	FilesPerPass int
+	// BatchSize is the events inserted per round trip. Defaults to 500.
+	BatchSize int
+}
+
+// ReplayDeps are the replayer's collaborators.
+type ReplayDeps struct {
+	Repo          *Repository
+	Events        *event.Repository
+	Subscriptions *subscription.Repository
+	// Store is the archive's object store (FC_ARCHIVE_STORE_URI).
+	Store blobstore.Store
+	// Blobs offloads large replayed payloads like ingest does; nil keeps
+	// them inline.
+	Blobs *blobstore.Offloader
+}
+
+// Replayer works open replays, oldest first, one pass of FilesPerPass
+// objects at a time, recording progress after each pass so a restart or
+// a change of leader resumes from the last object finished. Delivery is
+// at least once: an object part-way through when the process stops is
+// replayed again in full.
+type Replayer struct {
+	deps ReplayDeps
+	cfg  ReplayConfig
+
+	// IsLeader gates replaying: a replay has one reader. Nil means always
+	// leader.
+	IsLeader func() bool
+}
+
+// NewReplayer builds a replayer.
+func NewReplayer(deps ReplayDeps, cfg ReplayConfig) *Replayer {
+	if cfg.Interval <= 0 {
+		cfg.Interval = 10 * time.Second
+	}
+	if cfg.FilesPerPass <= 0 {
+		cfg.FilesPerPass = 10
+	}
+	if cfg.BatchSize <= 0 {
+		cfg.BatchSize = 500
+	}
+	return &Replayer{deps: deps, cfg: cfg}
+}
+
+// Run works open replays every Interval until ctx is cancelled.
+func (r *Replayer) Run(ctx context.Context) {
+	slog.Info("archive replayer started", "interval", r.cfg.Interval)
+	tick := time.NewTicker(r.cfg.Interval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("archive replayer stopped")
+			return
+		case <-tick.C:
+			r.drain(ctx)
+		}
+	}
+}
+
+// drain runs passes while there is an open replay and this instance leads.
+func (r *Replayer) drain(ctx context.Context) {
+	for ctx.Err() == nil && (r.IsLeader == nil || r.IsLeader()) {
+		more, err := r.Step(ctx)
+		if err != nil {
+			slog.Warn("archive replay: pass failed", "err", err)
+			return
+		}
+		if !more {
+			return
+		}
+	}
+}
+
+// Step runs one pass over the oldest open replay and records the outcome.
+// It reports false when there was nothing open. A pass that fails marks
+// the replay FAILED rather than returning an error; the error return is
+// for failures to load or record a replay.
+func (r *Replayer) Step(ctx context.Context) (bool, error) {
+	rp, err := r.deps.Repo.NextOpenReplay(ctx)
+	if err != nil {
+		return false, fmt.Errorf("load replay: %w", err)
+	}
+	if rp == nil {
+		return false, nil
+	}
+	now := time.Now().UTC()
+	if rp.StartedAt == nil {
+		rp.StartedAt = &now
+		slog.Info("archive replay started", "replay", rp.ID, "from", rp.From, "to", rp.To)
+	}
+	rp.Status = ReplayRunning
+
+	done, passErr := r.pass(ctx, rp)
+	switch {
+	case passErr != nil && ctx.Err() != nil:
+		// Shutting down: keep the objects finished so far and resume from
+		// them next time.
+		if _, err := r.deps.Repo.RecordProgress(context.WithoutCancel(ctx), rp); err != nil {
+			return false, fmt.Errorf("record progress: %w", err)
+		}
+		return false, ctx.Err()
+	case passErr != nil:
+		msg := passErr.Error()
+		rp.Status, rp.Error, rp.CompletedAt = ReplayFailed, &msg, &now
+		slog.Warn("archive replay failed", "replay", rp.ID, "err", passErr)
+	case done:
+		rp.Status, rp.CompletedAt = ReplayCompleted, &now
+		slog.Info("archive replay completed", "replay", rp.ID,
+			"files", rp.FilesRead, "read", rp.EventsRead, "replayed", rp.EventsReplayed)
+	}
+	ok, err := r.deps.Repo.RecordProgress(ctx, rp)
+	if err != nil {
+		return false, fmt.Errorf("record progress: %w", err)
+	}
+	if !ok {
+		slog.Info("archive replay cancelled", "replay", rp.ID, "replayed", rp.EventsReplayed)
+	}
+	return true, nil
+}
+
+// pass replays the next FilesPerPass EVENTS objects of rp's range,
+// advancing rp past each one, and reports whether the range is done.
+func (r *Replayer) pass(ctx context.Context, rp *Replay) (bool, error) {
+	var sub *subscription.Subscription
+	if rp.SubscriptionID != nil {
+		s, err := r.deps.Subscriptions.FindByID(ctx, *rp.SubscriptionID)
+		if err != nil {
+			return false, fmt.Errorf("load subscription: %w", err)
+		}
+		if s == nil {
+			return false, fmt.Errorf("subscription %s no longer exists", *rp.SubscriptionID)
+		}
+		sub = s
+	}
+	kind := KindEvents
+	since, until := partitionHour(rp.From), rp.To
+	files, err := r.deps.Repo.Manifest(ctx, ManifestParams{
+		Kind: &kind, Since: &since, Until: &until, AfterID: rp.AfterFileID, Limit: r.cfg.FilesPerPass,
+	})
+	if err != nil {
+		return false, fmt.Errorf("read manifest: %w", err)
+	}
+	for i := range files {
+		f := &files[i]
+		// An object of a boundary hour may hold nothing in range.
+		if !f.MaxTime.Before(rp.From) && f.MinTime.Before(rp.To) {
+			read, replayed, err := r.replayFile(ctx, rp, f, sub)
+			rp.EventsRead += read
+			rp.EventsReplayed += replayed
+			if err != nil {
+				return false, fmt.Errorf("%s: %w", f.Key, err)
+			}
+		}
+		id := f.ID
+		rp.AfterFileID = &id
+		rp.FilesRead++
+	}
+	return len(files) < r.cfg.FilesPerPass, nil
+}
+
+// replayFile re-ingests the records of f that rp selects and returns how
+// many records it read and how many events it inserted.
+func (r *Replayer) replayFile(ctx context.Context, rp *Replay, f *File, sub *subscription.Subscription) (read, replayed int64, err error) {
+	body, err := r.deps.Store.Get(ctx, f.Key)
+	if err != nil {
+		return 0, 0, fmt.Errorf("read object: %w", err)
+	}
+	zr, err := gzip.NewReader(bytes.NewReader(body))
+	if err != nil {
+		return 0, 0, fmt.Errorf("open object: %w", err)
+	}
+	defer func() { _ = zr.Close() }()
+
+	now := time.Now().UTC()
+	batch := make([]event.Event, 0, r.cfg.BatchSize)
+	flush := func() error {
+		n, err := r.deps.Events.InsertBatch(ctx, batch)
+		replayed += int64(n)
+		batch = batch[:0]
+		if err != nil {
+			return fmt.Errorf("insert events: %w", err)
+		}
+		return nil
+	}
+	dec := json.NewDecoder(zr)
+	for {
+		var rec EventRecord
+		if err := dec.Decode(&rec); err != nil {
+			if errors.Is(err, io.EOF) {
+				break
+			}
+			return read, replayed, fmt.Errorf("decode record %d: %w", read+1, err)
+		}
+		read++
+		if !rp.Selects(&rec, sub) {
+			continue
+		}
+		e := rp.ReplayedEvent(&rec, now)
+		if e.Data, err = r.deps.Blobs.Offload(ctx, blobstore.ContentKey(blobstore.EventsPrefix, e.Data), e.Data); err != nil {
+			return read, replayed, fmt.Errorf("event %s: offload data: %w", rec.ID, err)
+		}
+		batch = append(batch, e)
+		if len(batch) >= r.cfg.BatchSize {
+			if err := flush(); err != nil {
+				return read, replayed, err
+			}
+		}
+	}
+	err = flush()
+	return read, replayed, err
+}
@@ -0,0 +1,244 @@
+package archive
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Repository is the Postgres-backed repo. Tables: msg_archive_cursors,
+// msg_archive_files (written only by the archiver) and
+// msg_archive_replays.
+type Repository struct {
+	pool *pgxpool.Pool // retained for Commit's transaction
+	q    *dbq.Queries
+}
+
+// NewRepository wires a repo.
+func NewRepository(pool *pgxpool.Pool) *Repository {
+	return &Repository{pool: pool, q: dbq.New(pool)}
+}
+
+// Cursors returns each stream's cursor. A stream never run has no entry.
+func (r *Repository) Cursors(ctx context.Context) (map[Kind]Cursor, error) {
+	rows, err := r.q.ArchiveCursorFindAll(ctx)
+	if err != nil {
+		return nil, err
+	}
+	out := make(map[Kind]Cursor, len(rows))
+	for _, row := range rows {
+		out[Kind(row.Kind)] = Cursor{
+			Kind:      Kind(row.Kind),
+			AfterTime: row.AfterTime,
+			AfterID:   row.AfterID,
+			Archived:  row.Archived,
+			LastRunAt: row.LastRunAt,
+			LastError: row.LastError,
+			UpdatedAt: row.UpdatedAt,
+		}
+	}
+	return out, nil
+}
+
+// Commit records files in the manifest and moves kind's cursor to
+// (afterTime, afterID) in one transaction, so the manifest never lists a
+// record the cursor will hand out again.
+func (r *Repository) Commit(ctx context.Context, kind Kind, files []File, afterTime time.Time, afterID string) error {
+	tx, err := r.pool.Begin(ctx)
+	if err != nil {
+		return err
+	}
+	defer func() { _ = tx.Rollback(ctx) }()
+	q := r.q.WithTx(tx)
+	var records int64
+	for i := range files {
+		f := &files[i]
+		if err := q.ArchiveFileInsert(ctx, dbq.ArchiveFileInsertParams{
+			ID:            f.ID,
+			Kind:          string(f.Kind),
+			ObjectKey:     f.Key,
+			SchemaVersion: int32(f.SchemaVersion),
+			PartitionHour: f.PartitionHour,
+			Records:       int32(f.Records),
+			Bytes:         f.Bytes,
+			FirstID:       f.FirstID,
+			LastID:        f.LastID,
+			MinTime:       f.MinTime,
+			MaxTime:       f.MaxTime,
+		}); err != nil {
+			return err
+		}
+		records += int64(f.Records)
+	}
+	if err := q.ArchiveCursorAdvance(ctx, dbq.ArchiveCursorAdvanceParams{
+		Kind: string(kind), AfterTime: afterTime, AfterID: afterID, Archived: records,
+	}); err != nil {
+		return err
+	}
+	return tx.Commit(ctx)
+}
+
+// RecordRun stamps a run of kind that archived nothing, with lastError
+// when it failed.
+func (r *Repository) RecordRun(ctx context.Context, kind Kind, lastError *string) error {
+	return r.q.ArchiveCursorRecordRun(ctx, dbq.ArchiveCursorRecordRunParams{
+		Kind: string(kind), LastError: lastError,
+	})
+}
+
+// ManifestParams filters the manifest. Every field is optional but Limit.
+type ManifestParams struct {
+	Kind *Kind
+	// Since and Until bound the partition hour: Since inclusive, Until
+	// exclusive.
+	Since *time.Time
+	Until *time.Time
+	// AfterID pages: files with a greater id, in id (write) order.
+	AfterID *string
+	Limit   int
+}
+
+// Manifest lists manifest entries in the order they were written.
+func (r *Repository) Manifest(ctx context.Context, p ManifestParams) ([]File, error) {
+	var kind *string
+	if p.Kind != nil {
+		k := string(*p.Kind)
+		kind = &k
+	}
+	rows, err := r.q.ArchiveFileList(ctx, dbq.ArchiveFileListParams{
+		Kind:    kind,
+		Since:   p.Since,
+		Until:   p.Until,
+		AfterID: p.AfterID,
+		Lim:     int32(p.Limit),
+	})
+	if err != nil {
+		return nil, err
+	}
+	out := make([]File, 0, len(rows))
+	for _, row := range rows {
+		out = append(out, File{
+			ID:            row.ID,
+			Kind:          Kind(row.Kind),
+			Key:           row.ObjectKey,
+			SchemaVersion: int(row.SchemaVersion),
+			PartitionHour: row.PartitionHour,
+			Records:       int(row.Records),
+			Bytes:         row.Bytes,
+			FirstID:       row.FirstID,
+			LastID:        row.LastID,
+			MinTime:       row.MinTime,
+			MaxTime:       row.MaxTime,
+			CreatedAt:     row.CreatedAt,
+		})
+	}
+	return out, nil
+}
+
+// FindReplay loads a replay by id.
+func (r *Repository) FindReplay(ctx context.Context, id string) (*Replay, error) {
+	res, err := r.q.ArchiveReplayFindByID(ctx, id)
+	row, err := repocommon.One(res, err, "archive_replay repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToReplay(*row), nil
+}
+
+// ListReplays returns the most recent replays, newest first.
+func (r *Repository) ListReplays(ctx context.Context, limit int) ([]Replay, error) {
+	rows, err := r.q.ArchiveReplayList(ctx, int32(limit))
+	if err != nil {
+		return nil, err
+	}
+	out := make([]Replay, 0, len(rows))
+	for _, row := range rows {
+		out = append(out, *rowToReplay(row))
+	}
+	return out, nil
+}
+
+// NextOpenReplay returns the oldest replay not yet terminal, or nil.
+func (r *Repository) NextOpenReplay(ctx context.Context) (*Replay, error) {
+	res, err := r.q.ArchiveReplayFindNextOpen(ctx)
+	row, err := repocommon.One(res, err, "archive_replay repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToReplay(*row), nil
+}
+
+// Persist implements usecasepgx.Persist[Replay]. Only a new replay and its
+// status are written; progress is the replayer's (RecordProgress).
+func (r *Repository) Persist(ctx context.Context, rp *Replay, tx *usecasepgx.DbTx) error {
+	return r.q.WithTx(tx.Inner()).ArchiveReplayUpsert(ctx, dbq.ArchiveReplayUpsertParams{
+		ID:             rp.ID,
+		FromTime:       rp.From,
+		ToTime:         rp.To,
+		EventTypes:     append([]string{}, rp.EventTypes...),
+		ClientID:       rp.ClientID,
+		SubscriptionID: rp.SubscriptionID,
+		Status:         string(rp.Status),
+		Error:          rp.Error,
+		RequestedBy:    rp.RequestedBy,
+		CreatedAt:      rp.CreatedAt,
+		CompletedAt:    rp.CompletedAt,
+		UpdatedAt:      rp.UpdatedAt,
+	})
+}
+
+// Delete implements usecasepgx.Persist[Replay].Delete. A replay is the
+// record of what was re-ingested and is never deleted.
+func (r *Repository) Delete(_ context.Context, _ *Replay, _ *usecasepgx.DbTx) error {
+	return errors.New("archive replays cannot be deleted")
+}
+
+// RecordProgress writes the replayer's progress on rp. It reports false,
+// writing nothing, when the replay was already terminal — cancelled while
+// the pass ran.
+func (r *Repository) RecordProgress(ctx context.Context, rp *Replay) (bool, error) {
+	n, err := r.q.ArchiveReplayRecordProgress(ctx, dbq.ArchiveReplayRecordProgressParams{
+		ID:             rp.ID,
+		Status:         string(rp.Status),
+		AfterFileID:    rp.AfterFileID,
+		FilesRead:      int32(rp.FilesRead),
+		EventsRead:     rp.EventsRead,
+		EventsReplayed: rp.EventsReplayed,
+		Error:          rp.Error,
+		StartedAt:      rp.StartedAt,
+		CompletedAt:    rp.CompletedAt,
+	})
+	return n > 0, err
+}
+
+func rowToReplay(row dbq.MsgArchiveReplay) *Replay {
+	rp := &Replay{
+		ID:             row.ID,
+		From:           row.FromTime,
+		To:             row.ToTime,
+		EventTypes:     row.EventTypes,
+		ClientID:       row.ClientID,
+		SubscriptionID: row.SubscriptionID,
+		Status:         ParseReplayStatus(row.Status),
+		AfterFileID:    row.AfterFileID,
+		FilesRead:      int(row.FilesRead),
+		EventsRead:     row.EventsRead,
+		EventsReplayed: row.EventsReplayed,
+		Error:          row.Error,
+		RequestedBy:    row.RequestedBy,
+		CreatedAt:      row.CreatedAt,
+		StartedAt:      row.StartedAt,
+		CompletedAt:    row.CompletedAt,
+		UpdatedAt:      row.UpdatedAt,
+	}
+	if rp.EventTypes == nil {
+		rp.EventTypes = []string{}
+	}
+	return rp
+}
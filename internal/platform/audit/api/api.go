@@ -3,8 +3,6 @@ package api
 
 import (
 	"context"
-	"encoding/base64"
-	"errors"
 	"strings"
 	"time"
 
@@ -106,46 +104,33 @@ func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[AuditLo
 		return nil, usecase.Internal("REPO", "find_with_cursor failed", err)
 	}
 
-	hasMore := len(rows) > size
-	if hasMore {
-		rows = rows[:size]
-	}
-
-	body := AuditLogListResponse{AuditLogs: apicommon.MapSlice(rows, fromEntity), HasMore: hasMore}
-	if hasMore && len(rows) > 0 {
-		last := rows[len(rows)-1]
-		cur := encodeCursor(audit.Cursor{PerformedAt: last.PerformedAt, ID: last.ID})
-		body.NextCursor = &cur
+	page, next := apicommon.CursorPage(rows, size, func(l *audit.Log) []string {
+		return []string{l.PerformedAt.UTC().Format(time.RFC3339Nano), l.ID}
+	})
+	body := AuditLogListResponse{AuditLogs: apicommon.MapSlice(page, fromEntity), HasMore: next != ""}
+	if next != "" {
+		body.NextCursor = &next
 	}
 	return &apicommon.Out[AuditLogListResponse]{Body: body}, nil
 }
 
-// encodeCursor serializes a keyset position into an opaque base64 token of
-// the form "<rfc3339nano>|<id>".
-func encodeCursor(c audit.Cursor) string {
-	raw := c.PerformedAt.UTC().Format(time.RFC3339Nano) + "|" + c.ID
-	return base64.RawURLEncoding.EncodeToString([]byte(raw))
-}
-
-// decodeCursor reverses encodeCursor.
+// decodeCursor reads the apicommon cursor CursorPage mints above: the
+// (performed_at, id) keyset of the last row served. Audit pages key on
+// performed_at rather than the TSID alone because SDK batch ingest
+// (POST /api/audit-logs/batch) can backfill entries with an older
+// performed_at than their id implies.
 func decodeCursor(s string) (*audit.Cursor, error) {
-	b, err := base64.RawURLEncoding.DecodeString(s)
+	parts, err := apicommon.DecodeCursor(s, 2)
 	if err != nil {
 		return nil, err
 	}
-	parts := strings.SplitN(string(b), "|", 2)
-	if len(parts) != 2 {
-		return nil, errBadCursor
-	}
 	t, err := time.Parse(time.RFC3339Nano, parts[0])
 	if err != nil {
-		return nil, err
+		return nil, apicommon.ErrBadCursor
 	}
 	return &audit.Cursor{PerformedAt: t, ID: parts[1]}, nil
 }
 
-var errBadCursor = errors.New("malformed cursor")
-
 func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[AuditLogResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanWritePermission(ac, viewPerm); err != nil {
@@ -37,6 +37,8 @@ type CreateOAuthClientRequest struct {
 	PostLogoutRedirectURIs []string `json:"postLogoutRedirectUris,omitempty"`
 	// AllowedOrigins is the CORS origin allowlist (persisted).
 	AllowedOrigins []string `json:"allowedOrigins,omitempty"`
+	// AllowedCIDRs restricts where the client's credentials may be used from.
+	AllowedCIDRs []string `json:"allowedCidrs,omitempty"`
 	// ApplicationIDs scopes the client to specific applications (persisted).
 	ApplicationIDs []string `json:"applicationIds,omitempty"`
 	PrincipalID    *string  `json:"principalId,omitempty"`
@@ -57,6 +59,7 @@ func (r CreateOAuthClientRequest) toCommand() operations.CreateOAuthClientComman
 		GrantTypes:             r.GrantTypes,
 		Scopes:                 scopes,
 		AllowedOrigins:         r.AllowedOrigins,
+		AllowedCIDRs:           r.AllowedCIDRs,
 		ApplicationIDs:         r.ApplicationIDs,
 		PrincipalID:            r.PrincipalID,
 		PKCERequired:           r.PKCERequired,
@@ -78,6 +81,7 @@ type UpdateOAuthClientRequest struct {
 	DefaultScopes  []string `json:"defaultScopes,omitempty"`
 	Scopes         []string `json:"scopes,omitempty"`
 	AllowedOrigins []string `json:"allowedOrigins,omitempty"`
+	AllowedCIDRs   []string `json:"allowedCidrs,omitempty"`
 	ApplicationIDs []string `json:"applicationIds,omitempty"`
 	// PKCERequired toggles whether /oauth/authorize demands a code_challenge.
 	PKCERequired *bool `json:"pkceRequired,omitempty"`
@@ -96,6 +100,7 @@ func (r UpdateOAuthClientRequest) toCommand(id string) operations.UpdateOAuthCli
 		GrantTypes:             r.GrantTypes,
 		Scopes:                 scopes,
 		AllowedOrigins:         r.AllowedOrigins,
+		AllowedCIDRs:           r.AllowedCIDRs,
 		ApplicationIDs:         r.ApplicationIDs,
 		PKCERequired:           r.PKCERequired,
 	}
@@ -121,6 +126,7 @@ type OAuthClientResponse struct {
 	RedirectURIs           []string `json:"redirectUris"`
 	PostLogoutRedirectURIs []string `json:"postLogoutRedirectUris"`
 	AllowedOrigins         []string `json:"allowedOrigins"`
+	AllowedCIDRs           []string `json:"allowedCidrs"`
 	GrantTypes             []string `json:"grantTypes"`
 	// DefaultScopes is the entity's Scopes slice (renamed for the SPA).
 	DefaultScopes []string `json:"defaultScopes"`
@@ -160,6 +166,10 @@ func oauthClientFromEntity(c *auth.OAuthClient) OAuthClientResponse {
 	if origins == nil {
 		origins = []string{}
 	}
+	cidrs := c.AllowedCIDRs
+	if cidrs == nil {
+		cidrs = []string{}
+	}
 	appIDs := c.ApplicationIDs
 	if appIDs == nil {
 		appIDs = []string{}
@@ -172,6 +182,7 @@ func oauthClientFromEntity(c *auth.OAuthClient) OAuthClientResponse {
 		RedirectURIs:              uris,
 		PostLogoutRedirectURIs:    plUris,
 		AllowedOrigins:            origins,
+		AllowedCIDRs:              cidrs,
 		GrantTypes:                grants,
 		DefaultScopes:             scopes,
 		PKCERequired:              c.PKCERequired,
@@ -24,6 +24,7 @@ import (
 	"fmt"
 	"math/big"
 	"strings"
+	"sync/atomic"
 	"time"
 
 	"github.com/golang-jwt/jwt/v5"
@@ -145,6 +146,10 @@ type Config struct {
 	RSAPublicKeyPEM string
 	// RSAPublicKeyPreviousPEM enables validation-only rotation when set.
 	RSAPublicKeyPreviousPEM string
+	// RSAVerificationKeysPEM are further validation-only public keys (the
+	// keyring's staged and retired keys). Like the previous key they verify
+	// tokens and are published in JWKS, but never sign.
+	RSAVerificationKeysPEM []string
 
 	// SecretKey is the HS256 fallback secret (used when RSA keys absent).
 	SecretKey string
@@ -211,6 +216,19 @@ type keyEntry struct {
 	rsaComponents *RsaPublicKeyComponents
 }
 
+// keySet is the service's key material. It is replaced whole, never
+// mutated in place, so a token is signed or verified against one
+// consistent set while SetRSAKeys swaps in another.
+type keySet struct {
+	signKey any // *rsa.PrivateKey (RS256) or []byte (HS256)
+
+	currentVerify any // *rsa.PublicKey (RS256) or []byte (HS256)
+	keyID         string
+	rsaComponents *RsaPublicKeyComponents
+
+	previousKeys []keyEntry
+}
+
 // AuthService manages token generation + validation. Construct via New,
 // NewWithRSA, or NewWithSecret.
 type AuthService struct {
@@ -218,13 +236,8 @@ type AuthService struct {
 
 	algorithm     string // "RS256" | "HS256"
 	signingMethod jwt.SigningMethod
-	signKey       any // *rsa.PrivateKey (RS256) or []byte (HS256)
 
-	currentVerify any // *rsa.PublicKey (RS256) or []byte (HS256)
-	keyID         string
-	rsaComponents *RsaPublicKeyComponents
-
-	previousKeys []keyEntry
+	keys atomic.Pointer[keySet]
 }
 
 // NewWithRSA builds an RS256 service from PEM key material.
@@ -244,28 +257,31 @@ func NewWithRSA(config Config, privateKeyPEM, publicKeyPEM string) (*AuthService
 		return nil, err
 	}
 
-	return &AuthService{
+	s := &AuthService{
 		config:        config,
 		algorithm:     "RS256",
 		signingMethod: jwt.SigningMethodRS256,
+	}
+	s.keys.Store(&keySet{
 		signKey:       priv,
 		currentVerify: pub,
 		keyID:         generateKeyID(publicKeyPEM),
 		rsaComponents: components,
-	}, nil
+	})
+	return s, nil
 }
 
 // NewWithSecret builds an HS256 service from the config's SecretKey.
 func NewWithSecret(config Config) *AuthService {
 	config.applyDefaults()
 	secret := []byte(config.SecretKey)
-	return &AuthService{
+	s := &AuthService{
 		config:        config,
 		algorithm:     "HS256",
 		signingMethod: jwt.SigningMethodHS256,
-		signKey:       secret,
-		currentVerify: secret,
 	}
+	s.keys.Store(&keySet{signKey: secret, currentVerify: secret})
+	return s
 }
 
 // New builds a service using RSA when an RSA private key is present
@@ -298,6 +314,11 @@ func New(config Config) (*AuthService, error) {
 				return nil, fmt.Errorf("load previous RSA key: %w", err)
 			}
 		}
+		for i, pub := range config.RSAVerificationKeysPEM {
+			if err := svc.AddPreviousRSAKey(pub); err != nil {
+				return nil, fmt.Errorf("load verification RSA key %d: %w", i, err)
+			}
+		}
 		return svc, nil
 	}
 	// No RSA configured → HS256 (development only). Refuse an empty secret,
@@ -319,22 +340,55 @@ func (s *AuthService) AddPreviousRSAKey(publicKeyPEM string) error {
 	if err != nil {
 		return err
 	}
-	s.previousKeys = append(s.previousKeys, keyEntry{
+	cur := s.keys.Load()
+	next := *cur
+	next.previousKeys = append(append([]keyEntry(nil), cur.previousKeys...), keyEntry{
 		verifyKey:     pub,
 		keyID:         generateKeyID(publicKeyPEM),
 		rsaComponents: components,
 	})
+	s.keys.Store(&next)
+	return nil
+}
+
+// SetRSAKeys replaces the RS256 key material at runtime — the keyring
+// refresh after a rotation. privateKeyPEM becomes the signer and
+// verificationPEMs the validation-only keys, after the configured
+// RSAPublicKeyPreviousPEM. Tokens in flight are signed and verified
+// against either the old set or the new, never a mix.
+func (s *AuthService) SetRSAKeys(privateKeyPEM string, verificationPEMs []string) error {
+	if s.algorithm != "RS256" {
+		return errors.New("SetRSAKeys: service is not RS256")
+	}
+	publicKeyPEM, err := publicPEMFromPrivatePEM(privateKeyPEM)
+	if err != nil {
+		return fmt.Errorf("derive RSA public key from private key: %w", err)
+	}
+	fresh, err := NewWithRSA(s.config, privateKeyPEM, publicKeyPEM)
+	if err != nil {
+		return err
+	}
+	previous := verificationPEMs
+	if s.config.RSAPublicKeyPreviousPEM != "" {
+		previous = append([]string{s.config.RSAPublicKeyPreviousPEM}, verificationPEMs...)
+	}
+	for i, pub := range previous {
+		if err := fresh.AddPreviousRSAKey(pub); err != nil {
+			return fmt.Errorf("load verification RSA key %d: %w", i, err)
+		}
+	}
+	s.keys.Store(fresh.keys.Load())
 	return nil
 }
 
 // KeyID returns the current key id, or "" for HS256.
-func (s *AuthService) KeyID() string { return s.keyID }
+func (s *AuthService) KeyID() string { return s.keys.Load().keyID }
 
 // Algorithm returns "RS256" or "HS256".
 func (s *AuthService) Algorithm() string { return s.algorithm }
 
 // RSAComponents returns the current key's JWKS components, or nil for HS256.
-func (s *AuthService) RSAComponents() *RsaPublicKeyComponents { return s.rsaComponents }
+func (s *AuthService) RSAComponents() *RsaPublicKeyComponents { return s.keys.Load().rsaComponents }
 
 // JWKSKey pairs a key id with its RSA components for the JWKS endpoint.
 type JWKSKey struct {
@@ -345,11 +399,12 @@ type JWKSKey struct {
 // AllJWKSKeys returns the current key plus any previous keys (rotation).
 // Empty for HS256.
 func (s *AuthService) AllJWKSKeys() []JWKSKey {
+	ks := s.keys.Load()
 	var keys []JWKSKey
-	if s.keyID != "" && s.rsaComponents != nil {
-		keys = append(keys, JWKSKey{KeyID: s.keyID, Components: *s.rsaComponents})
+	if ks.keyID != "" && ks.rsaComponents != nil {
+		keys = append(keys, JWKSKey{KeyID: ks.keyID, Components: *ks.rsaComponents})
 	}
-	for _, prev := range s.previousKeys {
+	for _, prev := range ks.previousKeys {
 		if prev.rsaComponents != nil {
 			keys = append(keys, JWKSKey{KeyID: prev.keyID, Components: *prev.rsaComponents})
 		}
@@ -465,11 +520,12 @@ func (s *AuthService) generateIDToken(p *principal.Principal, clientID string, n
 // sign serializes and signs the supplied claims with the current key,
 // stamping the kid header when using RS256.
 func (s *AuthService) sign(claims jwt.Claims) (string, error) {
+	ks := s.keys.Load()
 	tok := jwt.NewWithClaims(s.signingMethod, claims)
-	if s.keyID != "" {
-		tok.Header["kid"] = s.keyID
+	if ks.keyID != "" {
+		tok.Header["kid"] = ks.keyID
 	}
-	signed, err := tok.SignedString(s.signKey)
+	signed, err := tok.SignedString(ks.signKey)
 	if err != nil {
 		return "", fmt.Errorf("encode JWT: %w", err)
 	}
@@ -479,9 +535,10 @@ func (s *AuthService) sign(claims jwt.Claims) (string, error) {
 // ValidateToken verifies an access token's signature, issuer, audience,
 // and expiry, trying the current key first then previous keys (rotation).
 func (s *AuthService) ValidateToken(token string) (*AccessTokenClaims, error) {
-	verifyKeys := make([]any, 0, 1+len(s.previousKeys))
-	verifyKeys = append(verifyKeys, s.currentVerify)
-	for _, k := range s.previousKeys {
+	ks := s.keys.Load()
+	verifyKeys := make([]any, 0, 1+len(ks.previousKeys))
+	verifyKeys = append(verifyKeys, ks.currentVerify)
+	for _, k := range ks.previousKeys {
 		verifyKeys = append(verifyKeys, k.verifyKey)
 	}
 
@@ -368,3 +368,71 @@ func independentKeyID(pubPEM string) string {
 	h := sha256.Sum256([]byte(pubPEM))
 	return base64.RawURLEncoding.EncodeToString(h[:16])
 }
+
+func TestVerificationKeysValidateAndPublish(t *testing.T) {
+	old := newRS256(t)
+	tok, err := old.GenerateAccessToken(anchorUser())
+	if err != nil {
+		t.Fatalf("generate: %v", err)
+	}
+
+	// Rotate: a new signer that keeps the old key as validation-only.
+	priv, _ := genRSAPEMs(t)
+	oldPub, err := publicPEMFromPrivatePEM(old.config.RSAPrivateKeyPEM)
+	if err != nil {
+		t.Fatalf("derive old public key: %v", err)
+	}
+	cfg := DefaultConfig()
+	cfg.RSAPrivateKeyPEM = priv
+	cfg.RSAVerificationKeysPEM = []string{oldPub}
+	rotated, err := New(cfg)
+	if err != nil {
+		t.Fatalf("New: %v", err)
+	}
+
+	if _, err := rotated.ValidateToken(tok); err != nil {
+		t.Fatalf("token signed by the retired key should still validate: %v", err)
+	}
+	keys := rotated.AllJWKSKeys()
+	if len(keys) != 2 {
+		t.Fatalf("JWKS keys = %d, want 2 (current + retired)", len(keys))
+	}
+	if keys[0].KeyID == keys[1].KeyID {
+		t.Errorf("current and retired keys share kid %q", keys[0].KeyID)
+	}
+}
+
+func TestSetRSAKeysSwapsSignerInPlace(t *testing.T) {
+	svc := newRS256(t)
+	oldKID := svc.KeyID()
+	tok, err := svc.GenerateAccessToken(anchorUser())
+	if err != nil {
+		t.Fatalf("generate: %v", err)
+	}
+	oldPub, err := publicPEMFromPrivatePEM(svc.config.RSAPrivateKeyPEM)
+	if err != nil {
+		t.Fatalf("derive old public key: %v", err)
+	}
+
+	// A keyring refresh after a peer rotated: new signer, old key retired.
+	priv, _ := genRSAPEMs(t)
+	if err := svc.SetRSAKeys(priv, []string{oldPub}); err != nil {
+		t.Fatalf("SetRSAKeys: %v", err)
+	}
+	if svc.KeyID() == oldKID {
+		t.Fatalf("kid unchanged after SetRSAKeys")
+	}
+	if _, err := svc.ValidateToken(tok); err != nil {
+		t.Fatalf("token signed before the swap should still validate: %v", err)
+	}
+	fresh, err := svc.GenerateAccessToken(anchorUser())
+	if err != nil {
+		t.Fatalf("generate: %v", err)
+	}
+	if _, err := svc.ValidateToken(fresh); err != nil {
+		t.Fatalf("token signed after the swap: %v", err)
+	}
+	if got := len(svc.AllJWKSKeys()); got != 2 {
+		t.Fatalf("JWKS keys = %d, want 2 (current + retired)", got)
+	}
+}
@@ -168,7 +168,7 @@ func (s *State) switchClient(w http.ResponseWriter, r *http.Request) {
 	}
 	var req switchClientRequest
 	if err := json.NewDecoder(r.Body).Decode(&req); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	p, err := s.loadPrincipal(r, ac)
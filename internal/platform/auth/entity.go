@@ -63,6 +63,10 @@ type OAuthClient struct {
 	// AllowedOrigins is the CORS origin allowlist
 	// (oauth_client_allowed_origins).
 	AllowedOrigins []string `json:"allowedOrigins"`
+	// AllowedCIDRs restricts where the client's credentials may be
+	// presented from (oauth_client_allowed_cidrs); empty allows anywhere.
+	// Checked at /oauth/token against the trusted-proxy-resolved caller IP.
+	AllowedCIDRs []string `json:"allowedCidrs"`
 	// ApplicationIDs scopes the client to specific applications
 	// (oauth_client_application_ids).
 	ApplicationIDs []string `json:"applicationIds"`
@@ -91,6 +95,7 @@ func NewOAuthClient(clientID, name string, t OAuthClientType) *OAuthClient {
 		GrantTypes:             []string{},
 		Scopes:                 []string{},
 		AllowedOrigins:         []string{},
+		AllowedCIDRs:           []string{},
 		ApplicationIDs:         []string{},
 		PKCERequired:           true,
 		Active:                 true,
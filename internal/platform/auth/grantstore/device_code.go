@@ -0,0 +1,322 @@
+package grantstore
+
+import (
+	"context"
+	"crypto/rand"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"math/big"
+	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// Device authorization grant (RFC 8628) state. A device code lives in
+// oauth_oidc_payloads (type = "DeviceCode", id "DeviceCode:{device_code}")
+// with the human-typed user code mirrored into the user_code column so the
+// verification page can resolve it without a JSONB scan. The row moves
+// pending → approved|denied when the user acts on the verification page,
+// and is consumed (consumed_at) by the single token poll that redeems it.
+
+const (
+	deviceCodePayloadType = "DeviceCode"
+
+	deviceCodeDefaultExpiry = 10 * time.Minute
+	// DeviceCodeDefaultInterval is the minimum polling interval advertised
+	// to the device (RFC 8628 §3.2 default).
+	DeviceCodeDefaultInterval = 5 * time.Second
+	// DeviceCodeSlowDownStep is added to a device code's polling interval
+	// each time it is told to slow_down (RFC 8628 §3.5).
+	DeviceCodeSlowDownStep = 5 * time.Second
+
+	// userCodeAlphabet is the RFC 8628 §6.1 recommended charset: consonants
+	// only (no vowels → no accidental words), no easily-confused glyphs.
+	userCodeAlphabet = "BCDFGHJKLMNPQRSTVWXZ"
+	userCodeLength   = 8
+)
+
+// DeviceCodeStatus is the user's decision on a pending device code.
+type DeviceCodeStatus string
+
+const (
+	DeviceCodePending  DeviceCodeStatus = "pending"
+	DeviceCodeApproved DeviceCodeStatus = "approved"
+	DeviceCodeDenied   DeviceCodeStatus = "denied"
+)
+
+// DeviceCode is one in-flight device authorization.
+type DeviceCode struct {
+	DeviceCode   string
+	UserCode     string
+	ClientID     string
+	Scope        *string
+	Status       DeviceCodeStatus
+	PrincipalID  *string
+	Interval     time.Duration
+	LastPolledAt *time.Time
+	CreatedAt    time.Time
+	ExpiresAt    time.Time
+	Consumed     bool
+}
+
+// NewDeviceCode builds a pending device code with the default 10-minute
+// expiry and 5-second polling interval.
+func NewDeviceCode(deviceCode, userCode, clientID string) *DeviceCode {
+	now := time.Now().UTC()
+	return &DeviceCode{
+		DeviceCode: deviceCode,
+		UserCode:   userCode,
+		ClientID:   clientID,
+		Status:     DeviceCodePending,
+		Interval:   DeviceCodeDefaultInterval,
+		CreatedAt:  now,
+		ExpiresAt:  now.Add(deviceCodeDefaultExpiry),
+	}
+}
+
+// IsExpired reports whether the device code's expiry has passed.
+func (d *DeviceCode) IsExpired() bool { return time.Now().UTC().After(d.ExpiresAt) }
+
+// GenerateUserCode returns a random 8-character user code from the RFC 8628
+// consonant alphabet, formatted XXXX-XXXX for readability.
+func GenerateUserCode() (string, error) {
+	var b strings.Builder
+	alphabetLen := big.NewInt(int64(len(userCodeAlphabet)))
+	for i := 0; i < userCodeLength; i++ {
+		if i == userCodeLength/2 {
+			b.WriteByte('-')
+		}
+		n, err := rand.Int(rand.Reader, alphabetLen)
+		if err != nil {
+			return "", err
+		}
+		b.WriteByte(userCodeAlphabet[n.Int64()])
+	}
+	return b.String(), nil
+}
+
+// NormalizeUserCode canonicalises what a user typed: upper-cased, with
+// separators and whitespace stripped, re-hyphenated in the middle. Users
+// routinely type "bcdf ghjk" or "BCDFGHJK" for BCDF-GHJK.
+func NormalizeUserCode(raw string) string {
+	var b strings.Builder
+	for _, r := range strings.ToUpper(raw) {
+		if r >= 'A' && r <= 'Z' {
+			b.WriteRune(r)
+		}
+	}
+	s := b.String()
+	if len(s) != userCodeLength {
+		return s
+	}
+	return s[:userCodeLength/2] + "-" + s[userCodeLength/2:]
+}
+
+// deviceCodePayload is the camelCase JSONB stored under payload.
+type deviceCodePayload struct {
+	ClientID     string  `json:"clientId"`
+	Scope        *string `json:"scope"`
+	Status       string  `json:"status"`
+	AccountID    *string `json:"accountId"`
+	IntervalSecs int64   `json:"interval"`
+	LastPolledAt *string `json:"lastPolledAt"`
+	Kind         string  `json:"kind"`
+	IAT          int64   `json:"iat"`
+	EXP          int64   `json:"exp"`
+}
+
+// DeviceCodeRepository persists device codes in oauth_oidc_payloads.
+type DeviceCodeRepository struct{ pool *pgxpool.Pool }
+
+// NewDeviceCodeRepository wires the repo against pool.
+func NewDeviceCodeRepository(pool *pgxpool.Pool) *DeviceCodeRepository {
+	return &DeviceCodeRepository{pool: pool}
+}
+
+func deviceCodeID(code string) string { return deviceCodePayloadType + ":" + code }
+
+// Insert writes a new pending device code.
+func (r *DeviceCodeRepository) Insert(ctx context.Context, d *DeviceCode) error {
+	payload, err := json.Marshal(deviceCodePayload{
+		ClientID:     d.ClientID,
+		Scope:        d.Scope,
+		Status:       string(d.Status),
+		AccountID:    d.PrincipalID,
+		IntervalSecs: int64(d.Interval / time.Second),
+		Kind:         deviceCodePayloadType,
+		IAT:          d.CreatedAt.Unix(),
+		EXP:          d.ExpiresAt.Unix(),
+	})
+	if err != nil {
+		return fmt.Errorf("marshal device-code payload: %w", err)
+	}
+	_, err = r.pool.Exec(ctx,
+		`INSERT INTO oauth_oidc_payloads
+			(id, type, payload, grant_id, user_code, uid, expires_at, consumed_at, created_at)
+		VALUES ($1, $2, $3, NULL, $4, NULL, $5, NULL, $6)`,
+		deviceCodeID(d.DeviceCode), deviceCodePayloadType, payload, d.UserCode, d.ExpiresAt, d.CreatedAt)
+	if err != nil {
+		return fmt.Errorf("insert device code: %w", err)
+	}
+	return nil
+}
+
+// FindPendingByUserCode loads a still-pending, unexpired, unconsumed device
+// code by its (normalised) user code — the verification page lookup.
+// Returns (nil, nil) when none matches.
+func (r *DeviceCodeRepository) FindPendingByUserCode(ctx context.Context, userCode string) (*DeviceCode, error) {
+	row := r.pool.QueryRow(ctx,
+		`SELECT id, user_code, payload, expires_at, consumed_at, created_at
+		FROM oauth_oidc_payloads
+		WHERE type = $1 AND user_code = $2
+		  AND consumed_at IS NULL AND expires_at > NOW()
+		  AND payload->>'status' = $3`,
+		deviceCodePayloadType, userCode, string(DeviceCodePending))
+	return scanDeviceCode(row)
+}
+
+// Decide records the user's approve/deny decision on a pending code. The
+// status guard makes the transition one-shot: a second decision (or one
+// racing the expiry) affects no row and returns false.
+func (r *DeviceCodeRepository) Decide(ctx context.Context, userCode string, status DeviceCodeStatus, principalID string) (bool, error) {
+	tag, err := r.pool.Exec(ctx,
+		`UPDATE oauth_oidc_payloads
+		SET payload = payload || jsonb_build_object('status', $3::text, 'accountId', $4::text)
+		WHERE type = $1 AND user_code = $2
+		  AND consumed_at IS NULL AND expires_at > NOW()
+		  AND payload->>'status' = $5`,
+		deviceCodePayloadType, userCode, string(status), principalID, string(DeviceCodePending))
+	if err != nil {
+		return false, fmt.Errorf("decide device code: %w", err)
+	}
+	return tag.RowsAffected() > 0, nil
+}
+
+// Poll stamps lastPolledAt on a device code and returns the code together
+// with the PREVIOUS poll time, so the token endpoint can answer slow_down
+// when the device polls faster than its interval. Returns (nil, nil, nil)
+// for an unknown device code. Expired/consumed rows are returned as-is so
+// the caller can distinguish expired_token from invalid_grant.
+func (r *DeviceCodeRepository) Poll(ctx context.Context, deviceCode string) (*DeviceCode, *time.Time, error) {
+	row := r.pool.QueryRow(ctx,
+		`WITH prev AS (
+			SELECT id, payload->>'lastPolledAt' AS last_polled
+			FROM oauth_oidc_payloads WHERE id = $1 AND type = $2
+			FOR UPDATE
+		)
+		UPDATE oauth_oidc_payloads p
+		SET payload = jsonb_set(p.payload, '{lastPolledAt}', to_jsonb($3::text))
+		FROM prev
+		WHERE p.id = prev.id
+		RETURNING p.id, p.user_code, p.payload, p.expires_at, p.consumed_at, p.created_at, prev.last_polled`,
+		deviceCodeID(deviceCode), deviceCodePayloadType, time.Now().UTC().Format(time.RFC3339Nano))
+	var lastPolled *string
+	d, err := scanDeviceCodeWith(row, &lastPolled)
+	if err != nil || d == nil {
+		return nil, nil, err
+	}
+	return d, parseRFC3339Ptr(lastPolled), nil
+}
+
+// SlowDown adds DeviceCodeSlowDownStep to a device code's stored polling
+// interval, which its later polls are held to, and returns the new
+// interval. Returns 0 for an unknown device code.
+func (r *DeviceCodeRepository) SlowDown(ctx context.Context, deviceCode string) (time.Duration, error) {
+	var secs int64
+	err := r.pool.QueryRow(ctx,
+		`UPDATE oauth_oidc_payloads
+		SET payload = jsonb_set(payload, '{interval}',
+			to_jsonb(COALESCE(NULLIF((payload->>'interval')::bigint, 0), $3::bigint) + $4::bigint))
+		WHERE id = $1 AND type = $2
+		RETURNING (payload->>'interval')::bigint`,
+		deviceCodeID(deviceCode), deviceCodePayloadType,
+		int64(DeviceCodeDefaultInterval/time.Second), int64(DeviceCodeSlowDownStep/time.Second)).Scan(&secs)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return 0, nil
+	}
+	if err != nil {
+		return 0, fmt.Errorf("slow down device code: %w", err)
+	}
+	return time.Duration(secs) * time.Second, nil
+}
+
+// ConsumeApproved atomically marks an approved device code consumed and
+// returns it, so two racing polls can't both redeem one approval. Returns
+// (nil, nil) when the code is not (or no longer) redeemable.
+func (r *DeviceCodeRepository) ConsumeApproved(ctx context.Context, deviceCode string) (*DeviceCode, error) {
+	row := r.pool.QueryRow(ctx,
+		`UPDATE oauth_oidc_payloads
+		SET consumed_at = NOW()
+		WHERE id = $1 AND type = $2 AND consumed_at IS NULL AND expires_at > NOW()
+		  AND payload->>'status' = $3
+		RETURNING id, user_code, payload, expires_at, consumed_at, created_at`,
+		deviceCodeID(deviceCode), deviceCodePayloadType, string(DeviceCodeApproved))
+	return scanDeviceCode(row)
+}
+
+// DeleteExpired removes expired device-code rows. Returns the number
+// deleted.
+func (r *DeviceCodeRepository) DeleteExpired(ctx context.Context) (int64, error) {
+	tag, err := r.pool.Exec(ctx,
+		`DELETE FROM oauth_oidc_payloads WHERE type = $1 AND expires_at < NOW()`,
+		deviceCodePayloadType)
+	if err != nil {
+		return 0, err
+	}
+	return tag.RowsAffected(), nil
+}
+
+func scanDeviceCode(row pgx.Row) (*DeviceCode, error) {
+	return scanDeviceCodeWith(row)
+}
+
+// scanDeviceCodeWith scans the standard device-code columns plus any
+// trailing extra destinations (Poll's previous-poll column).
+func scanDeviceCodeWith(row pgx.Row, extra ...any) (*DeviceCode, error) {
+	var (
+		id         string
+		userCode   *string
+		payload    []byte
+		expiresAt  *time.Time
+		consumedAt *time.Time
+		createdAt  time.Time
+	)
+	dest := append([]any{&id, &userCode, &payload, &expiresAt, &consumedAt, &createdAt}, extra...)
+	if err := row.Scan(dest...); err != nil {
+		if errors.Is(err, pgx.ErrNoRows) {
+			return nil, nil
+		}
+		return nil, fmt.Errorf("scan device code: %w", err)
+	}
+	var p deviceCodePayload
+	if err := json.Unmarshal(payload, &p); err != nil {
+		return nil, fmt.Errorf("unmarshal device-code payload: %w", err)
+	}
+	exp := createdAt.Add(deviceCodeDefaultExpiry)
+	if expiresAt != nil {
+		exp = *expiresAt
+	}
+	interval := time.Duration(p.IntervalSecs) * time.Second
+	if interval <= 0 {
+		interval = DeviceCodeDefaultInterval
+	}
+	d := &DeviceCode{
+		DeviceCode:   strings.TrimPrefix(id, deviceCodePayloadType+":"),
+		ClientID:     p.ClientID,
+		Scope:        p.Scope,
+		Status:       DeviceCodeStatus(p.Status),
+		PrincipalID:  p.AccountID,
+		Interval:     interval,
+		LastPolledAt: parseRFC3339Ptr(p.LastPolledAt),
+		CreatedAt:    createdAt,
+		ExpiresAt:    exp,
+		Consumed:     consumedAt != nil,
+	}
+	if userCode != nil {
+		d.UserCode = *userCode
+	}
+	return d, nil
+}
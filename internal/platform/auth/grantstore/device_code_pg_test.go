@@ -0,0 +1,62 @@
+//go:build integration
+
+package grantstore_test
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/grantstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+)
+
+func TestDeviceCode_SlowDownLengthensInterval(t *testing.T) {
+	ctx := context.Background()
+	repo := grantstore.NewDeviceCodeRepository(testpg.Pool(t))
+	userCode, err := grantstore.GenerateUserCode()
+	require.NoError(t, err)
+	dc := grantstore.NewDeviceCode("dc_slowdown_test1", userCode, "oac_device_slow")
+	require.NoError(t, repo.Insert(ctx, dc))
+
+	interval, err := repo.SlowDown(ctx, dc.DeviceCode)
+	require.NoError(t, err)
+	assert.Equal(t, grantstore.DeviceCodeDefaultInterval+grantstore.DeviceCodeSlowDownStep, interval)
+	interval, err = repo.SlowDown(ctx, dc.DeviceCode)
+	require.NoError(t, err)
+	assert.Equal(t, grantstore.DeviceCodeDefaultInterval+2*grantstore.DeviceCodeSlowDownStep, interval)
+
+	got, prev, err := repo.Poll(ctx, dc.DeviceCode)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	assert.Nil(t, prev, "first poll")
+	assert.Equal(t, interval, got.Interval, "polls are held to the lengthened interval")
+
+	interval, err = repo.SlowDown(ctx, "dc_doesnotexist")
+	require.NoError(t, err)
+	assert.Zero(t, interval)
+}
+
+func TestDeviceCode_DeleteExpired(t *testing.T) {
+	ctx := context.Background()
+	repo := grantstore.NewDeviceCodeRepository(testpg.Pool(t))
+	live := grantstore.NewDeviceCode("dc_sweep_live1", "BCDF-GHJK", "oac_device_sweep")
+	expired := grantstore.NewDeviceCode("dc_sweep_expired1", "LMNP-QRST", "oac_device_sweep")
+	expired.ExpiresAt = time.Now().Add(-time.Minute)
+	require.NoError(t, repo.Insert(ctx, live))
+	require.NoError(t, repo.Insert(ctx, expired))
+
+	n, err := repo.DeleteExpired(ctx)
+	require.NoError(t, err)
+	assert.GreaterOrEqual(t, n, int64(1))
+
+	got, _, err := repo.Poll(ctx, expired.DeviceCode)
+	require.NoError(t, err)
+	assert.Nil(t, got, "expired row swept")
+	got, _, err = repo.Poll(ctx, live.DeviceCode)
+	require.NoError(t, err)
+	assert.NotNil(t, got, "live row kept")
+}
@@ -0,0 +1,35 @@
+package grantstore
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestGenerateUserCode_Shape(t *testing.T) {
+	for i := 0; i < 50; i++ {
+		code, err := GenerateUserCode()
+		require.NoError(t, err)
+		require.Len(t, code, userCodeLength+1)
+		assert.Equal(t, byte('-'), code[userCodeLength/2])
+		for _, r := range strings.ReplaceAll(code, "-", "") {
+			assert.Contains(t, userCodeAlphabet, string(r))
+		}
+	}
+}
+
+func TestNormalizeUserCode(t *testing.T) {
+	cases := map[string]string{
+		"BCDF-GHJK":   "BCDF-GHJK",
+		"bcdf-ghjk":   "BCDF-GHJK",
+		"bcdfghjk":    "BCDF-GHJK",
+		" bcdf ghjk ": "BCDF-GHJK",
+		"bcd":         "BCD",
+		"":            "",
+	}
+	for in, want := range cases {
+		assert.Equal(t, want, NormalizeUserCode(in), "input %q", in)
+	}
+}
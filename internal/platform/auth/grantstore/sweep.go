@@ -0,0 +1,35 @@
+package grantstore
+
+import (
+	"context"
+	"log/slog"
+	"time"
+)
+
+// Expiring is a grant store whose expired rows can be deleted outright:
+// nothing redeems or looks up an artifact past its expiry.
+type Expiring interface {
+	DeleteExpired(ctx context.Context) (int64, error)
+}
+
+// Sweep deletes the expired rows of each named store every interval until
+// ctx is cancelled. Run it in a goroutine. Every replica may sweep; the
+// deletes are idempotent.
+func Sweep(ctx context.Context, interval time.Duration, stores map[string]Expiring) {
+	tick := time.NewTicker(interval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-tick.C:
+			for name, store := range stores {
+				if n, err := store.DeleteExpired(ctx); err != nil {
+					slog.Warn("grantstore: sweep failed", "store", name, "err", err)
+				} else if n > 0 {
+					slog.Debug("grantstore: swept expired rows", "store", name, "count", n)
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,326 @@
+// Package keyring persists the platform's JWT signing keys in shared
+// storage (a Store — in production the iam_jwt_keyrings table, see
+// PgStore) so every instance and every restart signs and validates with
+// the same key set, and rotates them on a schedule.
+//
+// A ring holds three tiers:
+//
+//	current  — signs every token; published in JWKS.
+//	next     — pre-staged successor; validation-only and published in JWKS
+//	           for a full rotation period before it starts signing, so any
+//	           instance booted after it was staged already accepts tokens
+//	           signed with it once a peer promotes it.
+//	previous — retired signers; validation-only and published in JWKS until
+//	           their grace period lapses, so tokens minted just before a
+//	           rotation keep verifying until they expire.
+//
+// Rotation happens when the ring is loaded — at boot and then on every
+// refresh tick, so a long-running fleet rotates without a restart: a
+// current key older than Policy.RotateAfter is retired, next is promoted,
+// and a fresh next is staged. The whole ring is one JSON document, written
+// back only when it changed and only under the store's lock, so instances
+// loading together can't each stage or promote a different key. Instances
+// pick up a peer's rotation on their next refresh; the staged next key is
+// already in their JWKS by then.
+package keyring
+
+import (
+	"context"
+	"crypto/rand"
+	"crypto/rsa"
+	"crypto/x509"
+	"encoding/json"
+	"encoding/pem"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/secrets"
+)
+
+// SecretName is the secrets-backend key the ring was stored under before
+// it moved to shared storage; ReadLegacy reads it to carry a ring over.
+const SecretName = "jwt-signing-keyring"
+
+// Store is where the ring lives. Every instance must see the same one.
+type Store interface {
+	// Read returns the ring's JSON, or "" when none is stored yet.
+	Read(ctx context.Context) (string, error)
+	// Write replaces the stored ring.
+	Write(ctx context.Context, raw string) error
+	// Lock serialises ring writes across instances, waiting while a peer
+	// holds it. The returned func releases it.
+	Lock(ctx context.Context) (func(), error)
+}
+
+// rsaBits is the modulus size for generated keys (matches the ephemeral
+// dev key and the Rust platform).
+const rsaBits = 2048
+
+// Policy tunes rotation. The zero value never rotates and never prunes.
+type Policy struct {
+	// RotateAfter is the signing lifetime of a key. Zero disables rotation
+	// (the ring still persists the key so restarts/replicas agree).
+	RotateAfter time.Duration
+	// Grace is how long a retired key stays valid for verification. It must
+	// cover the longest-lived token the key signed (session cookies: 24h).
+	// Zero keeps retired keys forever.
+	Grace time.Duration
+}
+
+// SigningKey is the active signer.
+type SigningKey struct {
+	PrivateKeyPEM string    `json:"privateKeyPem"`
+	CreatedAt     time.Time `json:"createdAt"`
+}
+
+// RetiredKey is a validation-only key that no longer signs.
+type RetiredKey struct {
+	PublicKeyPEM string    `json:"publicKeyPem"`
+	RetiredAt    time.Time `json:"retiredAt"`
+}
+
+// Ring is the persisted key set.
+type Ring struct {
+	Current  SigningKey   `json:"current"`
+	Next     *SigningKey  `json:"next,omitempty"`
+	Previous []RetiredKey `json:"previous"`
+}
+
+// SigningKeyPEM returns the current private key, PEM-encoded.
+func (r *Ring) SigningKeyPEM() []byte { return []byte(r.Current.PrivateKeyPEM) }
+
+// VerificationPublicKeyPEMs returns the public halves of every
+// validation-only key — the staged next key first, then retired keys
+// newest-first. The current key is not included (callers derive it from
+// the signing key).
+func (r *Ring) VerificationPublicKeyPEMs() ([]string, error) {
+	out := make([]string, 0, 1+len(r.Previous))
+	if r.Next != nil {
+		pub, err := publicPEM(r.Next.PrivateKeyPEM)
+		if err != nil {
+			return nil, fmt.Errorf("next key: %w", err)
+		}
+		out = append(out, pub)
+	}
+	for i := len(r.Previous) - 1; i >= 0; i-- {
+		out = append(out, r.Previous[i].PublicKeyPEM)
+	}
+	return out, nil
+}
+
+// Seed supplies the first ring when the store has none.
+type Seed struct {
+	// Ring is carried over as-is — the ring an instance kept in its local
+	// secrets file before the ring moved to shared storage.
+	Ring *Ring
+	// KeyPEM, when Ring is nil, becomes the current key of a new ring so
+	// switching an existing deployment onto the keyring keeps its
+	// configured key (and every token already in flight) rather than
+	// minting a new one. Empty generates a key.
+	KeyPEM []byte
+}
+
+// Load reads the ring from st, creating it from seed when absent, then
+// applies the rotation policy and writes it back if anything changed. A
+// ring that needs no change is returned without taking the lock; one that
+// does is re-read under it, so only the first of several instances racing
+// to rotate does so and the rest load its result.
+func Load(ctx context.Context, st Store, seed Seed, policy Policy, now time.Time) (*Ring, error) {
+	ring, err := read(ctx, st)
+	if err != nil {
+		return nil, err
+	}
+	if ring != nil && !ring.due(policy, now) {
+		return ring, nil
+	}
+
+	unlock, err := st.Lock(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("keyring lock: %w", err)
+	}
+	defer unlock()
+	if ring, err = read(ctx, st); err != nil {
+		return nil, err
+	}
+	changed := false
+	if ring == nil {
+		if ring, err = seed.ring(now); err != nil {
+			return nil, err
+		}
+		changed = true
+	}
+	rotated, err := ring.apply(policy, now)
+	if err != nil {
+		return nil, err
+	}
+	if changed || rotated {
+		if err := write(ctx, st, ring); err != nil {
+			return nil, err
+		}
+	}
+	return ring, nil
+}
+
+// ReadLegacy reads a ring from the secrets backend it used to be kept in,
+// or nil when there is none.
+func ReadLegacy(ctx context.Context, p secrets.Provider) (*Ring, error) {
+	raw, err := p.Get(ctx, SecretName)
+	if errors.Is(err, secrets.ErrNotFound) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("read legacy keyring: %w", err)
+	}
+	return decode(raw)
+}
+
+func (s Seed) ring(now time.Time) (*Ring, error) {
+	if s.Ring != nil {
+		return s.Ring, nil
+	}
+	current := string(s.KeyPEM)
+	if current == "" {
+		var err error
+		if current, err = generatePEM(); err != nil {
+			return nil, err
+		}
+	}
+	if _, err := publicPEM(current); err != nil {
+		return nil, fmt.Errorf("seed key: %w", err)
+	}
+	return &Ring{Current: SigningKey{PrivateKeyPEM: current, CreatedAt: now}, Previous: []RetiredKey{}}, nil
+}
+
+// due reports whether apply would change the ring.
+func (r *Ring) due(policy Policy, now time.Time) bool {
+	if policy.RotateAfter > 0 &&
+		(r.Next == nil || !now.Before(r.Current.CreatedAt.Add(policy.RotateAfter))) {
+		return true
+	}
+	if policy.Grace > 0 {
+		for _, k := range r.Previous {
+			if !now.Before(k.RetiredAt.Add(policy.Grace)) {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+// apply stages/promotes/prunes per policy. Reports whether the ring changed.
+func (r *Ring) apply(policy Policy, now time.Time) (bool, error) {
+	changed := false
+	if policy.RotateAfter > 0 {
+		if r.Next == nil {
+			next, err := generatePEM()
+			if err != nil {
+				return false, err
+			}
+			r.Next = &SigningKey{PrivateKeyPEM: next, CreatedAt: now}
+			changed = true
+		}
+		if !now.Before(r.Current.CreatedAt.Add(policy.RotateAfter)) {
+			retired, err := publicPEM(r.Current.PrivateKeyPEM)
+			if err != nil {
+				return false, fmt.Errorf("current key: %w", err)
+			}
+			r.Previous = append(r.Previous, RetiredKey{PublicKeyPEM: retired, RetiredAt: now})
+			r.Current = SigningKey{PrivateKeyPEM: r.Next.PrivateKeyPEM, CreatedAt: now}
+			next, err := generatePEM()
+			if err != nil {
+				return false, err
+			}
+			r.Next = &SigningKey{PrivateKeyPEM: next, CreatedAt: now}
+			changed = true
+		}
+	}
+	if policy.Grace > 0 {
+		kept := r.Previous[:0]
+		for _, k := range r.Previous {
+			if now.Before(k.RetiredAt.Add(policy.Grace)) {
+				kept = append(kept, k)
+			}
+		}
+		if len(kept) != len(r.Previous) {
+			changed = true
+		}
+		r.Previous = kept
+	}
+	return changed, nil
+}
+
+func read(ctx context.Context, st Store) (*Ring, error) {
+	raw, err := st.Read(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("read keyring: %w", err)
+	}
+	if raw == "" {
+		return nil, nil
+	}
+	return decode(raw)
+}
+
+func decode(raw string) (*Ring, error) {
+	var ring Ring
+	if err := json.Unmarshal([]byte(raw), &ring); err != nil {
+		return nil, fmt.Errorf("decode keyring: %w", err)
+	}
+	if ring.Current.PrivateKeyPEM == "" {
+		return nil, errors.New("decode keyring: no current key")
+	}
+	if ring.Previous == nil {
+		ring.Previous = []RetiredKey{}
+	}
+	return &ring, nil
+}
+
+func write(ctx context.Context, st Store, ring *Ring) error {
+	raw, err := json.Marshal(ring)
+	if err != nil {
+		return fmt.Errorf("encode keyring: %w", err)
+	}
+	if err := st.Write(ctx, string(raw)); err != nil {
+		return fmt.Errorf("write keyring: %w", err)
+	}
+	return nil
+}
+
+// generatePEM mints a fresh RSA private key in PKCS#1 PEM.
+func generatePEM() (string, error) {
+	key, err := rsa.GenerateKey(rand.Reader, rsaBits)
+	if err != nil {
+		return "", fmt.Errorf("generate RSA key: %w", err)
+	}
+	der := x509.MarshalPKCS1PrivateKey(key)
+	return string(pem.EncodeToMemory(&pem.Block{Type: "RSA PRIVATE KEY", Bytes: der})), nil
+}
+
+// publicPEM derives the PKIX public-key PEM from an RSA private-key PEM —
+// the same encoding authservice derives for the current key, so a key's kid
+// is stable as it moves between tiers.
+func publicPEM(privPEM string) (string, error) {
+	block, _ := pem.Decode([]byte(privPEM))
+	if block == nil {
+		return "", errors.New("no PEM block found")
+	}
+	var priv *rsa.PrivateKey
+	if k, err := x509.ParsePKCS1PrivateKey(block.Bytes); err == nil {
+		priv = k
+	} else {
+		k8, err := x509.ParsePKCS8PrivateKey(block.Bytes)
+		if err != nil {
+			return "", fmt.Errorf("parse private key: %w", err)
+		}
+		rk, ok := k8.(*rsa.PrivateKey)
+		if !ok {
+			return "", errors.New("private key is not RSA")
+		}
+		priv = rk
+	}
+	der, err := x509.MarshalPKIXPublicKey(&priv.PublicKey)
+	if err != nil {
+		return "", err
+	}
+	return string(pem.EncodeToMemory(&pem.Block{Type: "PUBLIC KEY", Bytes: der})), nil
+}
@@ -0,0 +1,162 @@
+package keyring
+
+import (
+	"context"
+	"encoding/json"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/secrets"
+)
+
+// memStore is a Store shared by every "instance" in a test.
+type memStore struct {
+	lock   sync.Mutex
+	mu     sync.Mutex
+	raw    string
+	writes int
+}
+
+func (s *memStore) Read(context.Context) (string, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.raw, nil
+}
+
+func (s *memStore) Write(_ context.Context, raw string) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.raw = raw
+	s.writes++
+	return nil
+}
+
+func (s *memStore) Lock(context.Context) (func(), error) {
+	s.lock.Lock()
+	return s.lock.Unlock, nil
+}
+
+func TestLoad_PersistsAcrossLoads(t *testing.T) {
+	ctx := context.Background()
+	p := &memStore{}
+	now := time.Now().UTC()
+
+	first, err := Load(ctx, p, Seed{}, Policy{}, now)
+	require.NoError(t, err)
+	second, err := Load(ctx, p, Seed{}, Policy{}, now.Add(time.Hour))
+	require.NoError(t, err)
+
+	assert.Equal(t, first.Current.PrivateKeyPEM, second.Current.PrivateKeyPEM,
+		"a second instance/restart must sign with the same key")
+	assert.Nil(t, second.Next, "no staged key when rotation is disabled")
+}
+
+func TestLoad_SeedsFromConfiguredKey(t *testing.T) {
+	seed, err := generatePEM()
+	require.NoError(t, err)
+
+	ring, err := Load(context.Background(), &memStore{}, Seed{KeyPEM: []byte(seed)}, Policy{}, time.Now().UTC())
+	require.NoError(t, err)
+	assert.Equal(t, seed, ring.Current.PrivateKeyPEM)
+}
+
+func TestLoad_RotatesAndPrunes(t *testing.T) {
+	ctx := context.Background()
+	p := &memStore{}
+	policy := Policy{RotateAfter: 30 * 24 * time.Hour, Grace: 48 * time.Hour}
+	t0 := time.Now().UTC()
+
+	initial, err := Load(ctx, p, Seed{}, policy, t0)
+	require.NoError(t, err)
+	require.NotNil(t, initial.Next, "rotation pre-stages the successor")
+	staged := initial.Next.PrivateKeyPEM
+
+	rotated, err := Load(ctx, p, Seed{}, policy, t0.Add(policy.RotateAfter))
+	require.NoError(t, err)
+	assert.Equal(t, staged, rotated.Current.PrivateKeyPEM, "the staged key is promoted")
+	require.Len(t, rotated.Previous, 1)
+	oldPub, err := publicPEM(initial.Current.PrivateKeyPEM)
+	require.NoError(t, err)
+	assert.Equal(t, oldPub, rotated.Previous[0].PublicKeyPEM)
+
+	verify, err := rotated.VerificationPublicKeyPEMs()
+	require.NoError(t, err)
+	assert.Len(t, verify, 2, "staged next + retired key")
+	assert.Contains(t, verify, oldPub)
+
+	pruned, err := Load(ctx, p, Seed{}, policy, t0.Add(policy.RotateAfter+policy.Grace))
+	require.NoError(t, err)
+	assert.Empty(t, pruned.Previous, "retired key dropped after its grace period")
+	assert.Equal(t, staged, pruned.Current.PrivateKeyPEM)
+}
+
+func TestLoad_WritesOnlyWhenChanged(t *testing.T) {
+	ctx := context.Background()
+	p := &memStore{}
+	policy := Policy{RotateAfter: 30 * 24 * time.Hour, Grace: 48 * time.Hour}
+	t0 := time.Now().UTC()
+
+	_, err := Load(ctx, p, Seed{}, policy, t0)
+	require.NoError(t, err)
+	_, err = Load(ctx, p, Seed{}, policy, t0.Add(time.Hour))
+	require.NoError(t, err)
+	assert.Equal(t, 1, p.writes, "a refresh with nothing due must not rewrite the ring")
+}
+
+func TestLoad_ConcurrentRotationAgrees(t *testing.T) {
+	ctx := context.Background()
+	p := &memStore{}
+	policy := Policy{RotateAfter: 30 * 24 * time.Hour}
+	t0 := time.Now().UTC()
+	_, err := Load(ctx, p, Seed{}, policy, t0)
+	require.NoError(t, err)
+
+	// Several instances notice the rotation is due at once: one rotates,
+	// the others load its result rather than promoting keys of their own.
+	rotateAt := t0.Add(policy.RotateAfter)
+	rings := make([]*Ring, 4)
+	var wg sync.WaitGroup
+	for i := range rings {
+		wg.Add(1)
+		go func(i int) {
+			defer wg.Done()
+			r, err := Load(ctx, p, Seed{}, policy, rotateAt)
+			assert.NoError(t, err)
+			rings[i] = r
+		}(i)
+	}
+	wg.Wait()
+	for _, r := range rings[1:] {
+		require.NotNil(t, r)
+		assert.Equal(t, rings[0].Current.PrivateKeyPEM, r.Current.PrivateKeyPEM)
+		assert.Equal(t, rings[0].Next.PrivateKeyPEM, r.Next.PrivateKeyPEM)
+	}
+	assert.Equal(t, 2, p.writes, "created once, rotated once")
+}
+
+func TestLoad_CarriesOverLegacyRing(t *testing.T) {
+	ctx := context.Background()
+	key, err := secrets.GenerateKey()
+	require.NoError(t, err)
+	legacyStore, err := secrets.NewEncryptedFileProviderFromBase64Key(t.TempDir(), key)
+	require.NoError(t, err)
+	none, err := ReadLegacy(ctx, legacyStore)
+	require.NoError(t, err)
+	assert.Nil(t, none)
+
+	seed, err := generatePEM()
+	require.NoError(t, err)
+	raw, err := json.Marshal(Ring{Current: SigningKey{PrivateKeyPEM: seed, CreatedAt: time.Now().UTC()}})
+	require.NoError(t, err)
+	require.NoError(t, legacyStore.Set(ctx, SecretName, string(raw)))
+
+	legacy, err := ReadLegacy(ctx, legacyStore)
+	require.NoError(t, err)
+	ring, err := Load(ctx, &memStore{}, Seed{Ring: legacy, KeyPEM: []byte("ignored")}, Policy{}, time.Now().UTC())
+	require.NoError(t, err)
+	assert.Equal(t, seed, ring.Current.PrivateKeyPEM, "the per-instance ring moves to shared storage unchanged")
+}
@@ -0,0 +1,70 @@
+package keyring
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/database"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+)
+
+// ringID is the iam_jwt_keyrings row the platform's ring is stored in.
+const ringID = "platform"
+
+// lockKey is the advisory lock held while the ring is written ("fckeyrng").
+const lockKey int64 = 0x66636b6579726e67
+
+// PgStore keeps the ring in iam_jwt_keyrings, sealed with the platform's
+// encryption.Service (FLOWCATALYST_APP_KEY), and serialises writes with a
+// Postgres advisory lock — the same pattern as the migration lock.
+type PgStore struct {
+	pool *pgxpool.Pool
+	q    *dbq.Queries
+	enc  *encryption.Service
+}
+
+// NewPgStore wires the store. enc must be non-nil: the ring holds private
+// keys and is never stored in the clear.
+func NewPgStore(pool *pgxpool.Pool, enc *encryption.Service) *PgStore {
+	return &PgStore{pool: pool, q: dbq.New(pool), enc: enc}
+}
+
+// Read implements Store.
+func (s *PgStore) Read(ctx context.Context) (string, error) {
+	row, err := s.q.JwtKeyringFind(ctx, ringID)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return "", nil
+	}
+	if err != nil {
+		return "", err
+	}
+	raw, err := s.enc.Decrypt(row.RingEnc)
+	if err != nil {
+		return "", fmt.Errorf("open keyring: %w", err)
+	}
+	return raw, nil
+}
+
+// Write implements Store.
+func (s *PgStore) Write(ctx context.Context, raw string) error {
+	sealed, err := s.enc.Encrypt(raw)
+	if err != nil {
+		return fmt.Errorf("seal keyring: %w", err)
+	}
+	return s.q.JwtKeyringUpsert(ctx, dbq.JwtKeyringUpsertParams{
+		ID:        ringID,
+		RingEnc:   sealed,
+		UpdatedAt: time.Now().UTC(),
+	})
+}
+
+// Lock implements Store.
+func (s *PgStore) Lock(ctx context.Context) (func(), error) {
+	return database.AdvisoryLock(ctx, s.pool, lockKey, "keyring")
+}
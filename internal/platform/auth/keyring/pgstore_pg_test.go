@@ -0,0 +1,62 @@
+//go:build integration
+
+package keyring_test
+
+import (
+	"context"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/keyring"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+)
+
+func TestMain(m *testing.M) { testpg.RunMain(m) }
+
+// TestPgStore_InstancesShareOneRotation loads a due ring from several
+// stores at once — one per "instance", sharing the database — and checks
+// they all end up signing with the same key, stored sealed.
+func TestPgStore_InstancesShareOneRotation(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+	key, err := encryption.GenerateKey()
+	require.NoError(t, err)
+	enc, err := encryption.New(key)
+	require.NoError(t, err)
+
+	_, err = pool.Exec(ctx, `DELETE FROM iam_jwt_keyrings`)
+	require.NoError(t, err)
+
+	policy := keyring.Policy{RotateAfter: 30 * 24 * time.Hour, Grace: 48 * time.Hour}
+	t0 := time.Now().UTC()
+	initial, err := keyring.Load(ctx, keyring.NewPgStore(pool, enc), keyring.Seed{}, policy, t0)
+	require.NoError(t, err)
+
+	rings := make([]*keyring.Ring, 4)
+	var wg sync.WaitGroup
+	for i := range rings {
+		wg.Add(1)
+		go func(i int) {
+			defer wg.Done()
+			r, err := keyring.Load(ctx, keyring.NewPgStore(pool, enc), keyring.Seed{}, policy, t0.Add(policy.RotateAfter))
+			assert.NoError(t, err)
+			rings[i] = r
+		}(i)
+	}
+	wg.Wait()
+	for _, r := range rings {
+		require.NotNil(t, r)
+		assert.Equal(t, initial.Next.PrivateKeyPEM, r.Current.PrivateKeyPEM, "the staged key is promoted once")
+		assert.Equal(t, rings[0].Next.PrivateKeyPEM, r.Next.PrivateKeyPEM)
+	}
+
+	var sealed string
+	require.NoError(t, pool.QueryRow(ctx, `SELECT ring_enc FROM iam_jwt_keyrings WHERE id = 'platform'`).Scan(&sealed))
+	assert.False(t, strings.Contains(sealed, "PRIVATE KEY"), "the ring is stored sealed")
+}
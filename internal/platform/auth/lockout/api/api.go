@@ -0,0 +1,93 @@
+// Package api wires the HTTP routes for credential lockouts via huma.
+package api
+
+import (
+	"context"
+	"net/http"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+// State bundles the dependencies.
+type State struct {
+	Guard *lockout.Guard
+}
+
+const tag = "lockouts"
+
+// Register mounts the lockout endpoints. Both are anchor-only.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "listLockouts", "/api/lockouts", "List locked-out principals, clients and IPs", s.list)
+	apiroute.Delete(g, "releaseLockout", "/api/lockouts/{kind}/{key}", "Release a lockout and reset its escalation", http.StatusNoContent, s.release)
+}
+
+// LockoutResponse mirrors lockout.Lock.
+type LockoutResponse struct {
+	Kind        string          `json:"kind" doc:"PRINCIPAL, CLIENT or IP"`
+	Key         string          `json:"key" doc:"Login email, OAuth client_id or IP address, per kind"`
+	Level       uint32          `json:"level" doc:"Locks within the escalation window; each doubles the lock duration"`
+	LockedUntil httpcompat.Time `json:"lockedUntil"`
+}
+
+// LockoutListResponse is the wire shape for GET /api/lockouts.
+type LockoutListResponse struct {
+	Lockouts []LockoutResponse `json:"lockouts"`
+}
+
+type releaseInput struct {
+	Kind string `path:"kind" doc:"PRINCIPAL, CLIENT or IP"`
+	Key  string `path:"key"`
+}
+
+func (s *State) list(ctx context.Context, _ *struct{}) (*apicommon.Out[LockoutListResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	locks, err := s.Guard.Locks(ctx)
+	if err != nil {
+		return nil, usecase.Internal("STORE", "list lockouts failed", err)
+	}
+	out := make([]LockoutResponse, 0, len(locks))
+	for _, l := range locks {
+		out = append(out, LockoutResponse{
+			Kind:        string(l.Subject.Kind),
+			Key:         l.Subject.Key,
+			Level:       l.Level,
+			LockedUntil: jsontime.New(l.LockedUntil),
+		})
+	}
+	return &apicommon.Out[LockoutListResponse]{Body: LockoutListResponse{Lockouts: out}}, nil
+}
+
+func (s *State) release(ctx context.Context, in *releaseInput) (*apicommon.Empty, error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.RequireAnchor(ac); err != nil {
+		return nil, err
+	}
+	kind, ok := lockout.ParseKind(in.Kind)
+	if !ok {
+		return nil, usecase.Validation("INVALID_KIND", "kind must be PRINCIPAL, CLIENT or IP")
+	}
+	sub := lockout.Subject{Kind: kind, Key: in.Key}
+	if kind == lockout.KindPrincipal {
+		sub = lockout.Principal(in.Key)
+	}
+	released, err := s.Guard.Unlock(ctx, sub, ac.PrincipalID)
+	if err != nil {
+		return nil, usecase.Internal("STORE", "release lockout failed", err)
+	}
+	if !released {
+		return nil, httperror.NotFound("Lockout", sub.String())
+	}
+	return &apicommon.Empty{}, nil
+}
@@ -0,0 +1,211 @@
+package lockout
+
+import (
+	"context"
+	"encoding/json"
+	"log/slog"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// ChallengeVerifier checks a solved challenge (a CAPTCHA response token or
+// similar) presented with a login attempt. Implementations call out to the
+// provider; ip is the caller's source IP, "" when unknown.
+type ChallengeVerifier interface {
+	Verify(ctx context.Context, token, ip string) (bool, error)
+}
+
+// auditSink is the subset of audit.Repository the guard writes through.
+type auditSink interface {
+	Insert(ctx context.Context, l *audit.Log) error
+}
+
+var _ auditSink = (*audit.Repository)(nil)
+
+// Config bundles a Guard's dependencies.
+type Config struct {
+	Store  Store
+	Policy Policy
+	// Challenge verifies challenge tokens once a principal has
+	// ChallengeAfter failures. Optional — nil never asks for one.
+	Challenge ChallengeVerifier
+	// Audit records locks and admin unlocks. Optional.
+	Audit auditSink
+}
+
+// Guard applies the lockout policy to the credential endpoints. The
+// attempt-path methods fail open on a store error: a degraded store must
+// never take down authentication (loginbackoff and the rate limits still
+// apply).
+type Guard struct {
+	cfg Config
+
+	mu        sync.Mutex
+	onLockout func(Lock)
+}
+
+// New builds a Guard.
+func New(cfg Config) *Guard { return &Guard{cfg: cfg} }
+
+// OnLockout registers fn to be called after a subject is locked, e.g. to
+// raise a warning. fn must not block.
+func (g *Guard) OnLockout(fn func(Lock)) {
+	g.mu.Lock()
+	defer g.mu.Unlock()
+	g.onLockout = fn
+}
+
+// Decision is the outcome of Check. Allowed=false carries the locked
+// subject and the seconds until its lock expires.
+type Decision struct {
+	Allowed        bool
+	Subject        Subject
+	RetryAfterSecs uint32
+}
+
+// Check rejects the attempt when any of subs is locked. Subjects with an
+// empty key (an unknown IP) are skipped.
+func (g *Guard) Check(ctx context.Context, subs ...Subject) Decision {
+	now := time.Now()
+	for _, sub := range subs {
+		if sub.Key == "" {
+			continue
+		}
+		st, err := g.cfg.Store.Get(ctx, sub)
+		if err != nil {
+			slog.Warn("lockout store error; failing open", "subject", sub.Kind, "err", err)
+			continue
+		}
+		if st.Locked(now) {
+			return Decision{Subject: sub, RetryAfterSecs: retryAfter(st.LockedUntil.Sub(now))}
+		}
+	}
+	return Decision{Allowed: true}
+}
+
+// ChallengeRequired reports whether sub has failed often enough that the
+// next attempt must carry a solved challenge. Always false without a
+// ChallengeVerifier.
+func (g *Guard) ChallengeRequired(ctx context.Context, sub Subject) bool {
+	if g.cfg.Challenge == nil || g.cfg.Policy.ChallengeAfter == 0 || sub.Key == "" {
+		return false
+	}
+	st, err := g.cfg.Store.Get(ctx, sub)
+	if err != nil {
+		slog.Warn("lockout store error; skipping challenge", "subject", sub.Kind, "err", err)
+		return false
+	}
+	return st.Failures >= g.cfg.Policy.ChallengeAfter
+}
+
+// VerifyChallenge checks a challenge token. A verifier error counts as a
+// failed challenge: asking again is safer than waving the attempt through.
+func (g *Guard) VerifyChallenge(ctx context.Context, token, ip string) bool {
+	if g.cfg.Challenge == nil {
+		return true
+	}
+	if token == "" {
+		return false
+	}
+	ok, err := g.cfg.Challenge.Verify(ctx, token, ip)
+	if err != nil {
+		slog.Warn("challenge verification failed", "err", err)
+		return false
+	}
+	return ok
+}
+
+// Failure records a failed attempt against each of subs, locking any that
+// reach their threshold.
+func (g *Guard) Failure(ctx context.Context, subs ...Subject) {
+	for _, sub := range subs {
+		if sub.Key == "" {
+			continue
+		}
+		st, locked, err := g.cfg.Store.RecordFailure(ctx, sub, g.cfg.Policy.ThresholdFor(sub.Kind), g.cfg.Policy)
+		if err != nil {
+			slog.Warn("lockout store error; failure not counted", "subject", sub.Kind, "err", err)
+			continue
+		}
+		if !locked {
+			continue
+		}
+		lock := Lock{Subject: sub, Level: st.Level, LockedUntil: st.LockedUntil}
+		slog.Warn("credential lockout", "subject", sub.String(), "level", lock.Level, "locked_until", lock.LockedUntil)
+		g.audit(ctx, sub, "CredentialLockout", nil, map[string]any{
+			"level":       lock.Level,
+			"lockedUntil": lock.LockedUntil,
+		})
+		g.mu.Lock()
+		fn := g.onLockout
+		g.mu.Unlock()
+		if fn != nil {
+			fn(lock)
+		}
+	}
+}
+
+// Success clears the failure count of each of subs after a successful
+// attempt. Locks and levels are left alone.
+func (g *Guard) Success(ctx context.Context, subs ...Subject) {
+	for _, sub := range subs {
+		if sub.Key == "" {
+			continue
+		}
+		if err := g.cfg.Store.ResetFailures(ctx, sub); err != nil {
+			slog.Warn("lockout store error; failures not reset", "subject", sub.Kind, "err", err)
+		}
+	}
+}
+
+// Locks lists the subjects currently locked.
+func (g *Guard) Locks(ctx context.Context) ([]Lock, error) { return g.cfg.Store.Locks(ctx) }
+
+// Unlock releases sub's lock and resets its escalation, recording who did
+// it. Reports whether a lock was in place. Unlike the attempt-path
+// methods, a store error is returned.
+func (g *Guard) Unlock(ctx context.Context, sub Subject, by string) (bool, error) {
+	released, err := g.cfg.Store.Unlock(ctx, sub)
+	if err != nil {
+		return false, err
+	}
+	if released {
+		g.audit(ctx, sub, "CredentialLockoutReleased", &by, nil)
+	}
+	return released, nil
+}
+
+func (g *Guard) audit(ctx context.Context, sub Subject, operation string, by *string, detail map[string]any) {
+	if g.cfg.Audit == nil {
+		return
+	}
+	l := &audit.Log{
+		ID:          tsid.Generate(tsid.AuditLog),
+		EntityType:  "LOCKOUT",
+		EntityID:    sub.String(),
+		Operation:   operation,
+		PrincipalID: by,
+		PerformedAt: time.Now().UTC(),
+	}
+	if detail != nil {
+		l.OperationJSON, _ = json.Marshal(detail)
+	}
+	if err := g.cfg.Audit.Insert(ctx, l); err != nil {
+		slog.Warn("lockout audit insert failed", "subject", sub.String(), "err", err)
+	}
+}
+
+// retryAfter rounds d up to whole seconds, at least 1.
+func retryAfter(d time.Duration) uint32 {
+	s := (d + time.Second - 1) / time.Second
+	if s < 1 {
+		return 1
+	}
+	if s > time.Duration(^uint32(0)) {
+		return ^uint32(0)
+	}
+	return uint32(s)
+}
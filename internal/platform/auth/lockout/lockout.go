@@ -0,0 +1,169 @@
+// Package lockout is progressive account and source lockout for the
+// credential endpoints (password login, /oauth/token).
+//
+// It complements loginbackoff, which slows a single (identifier, IP) pair
+// from the login-attempt history: lockout counts failures per subject — a
+// login identifier, an OAuth client_id, or a source IP — in a shared store,
+// and once a subject reaches its threshold inside the window it is locked
+// outright. Each further lock within LevelTTL doubles the lock duration up
+// to MaxLock. Locks are written to the audit log, surfaced to the warning
+// system through Guard's OnLockout hook, and released early by an admin
+// through the api subpackage.
+//
+// Before a principal is locked, ChallengeAfter failures switch the login
+// endpoint to requiring a solved challenge (CAPTCHA or similar) on every
+// attempt; the check itself is a ChallengeVerifier hook, so the platform
+// ships no CAPTCHA provider of its own.
+package lockout
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/envutil"
+)
+
+// Kind classifies what a Subject's key identifies.
+type Kind string
+
+const (
+	// KindPrincipal keys on a lower-cased login identifier (email).
+	KindPrincipal Kind = "PRINCIPAL"
+	// KindClient keys on an OAuth client_id.
+	KindClient Kind = "CLIENT"
+	// KindIP keys on the caller's source IP.
+	KindIP Kind = "IP"
+)
+
+// ParseKind returns the Kind named by s (case-insensitive).
+func ParseKind(s string) (Kind, bool) {
+	switch k := Kind(strings.ToUpper(s)); k {
+	case KindPrincipal, KindClient, KindIP:
+		return k, true
+	}
+	return "", false
+}
+
+// Subject is one thing failures are counted against.
+type Subject struct {
+	Kind Kind
+	Key  string
+}
+
+// Principal is the subject for a login identifier. The identifier is
+// lower-cased so rotating its case cannot dodge the count.
+func Principal(identifier string) Subject {
+	return Subject{Kind: KindPrincipal, Key: strings.ToLower(strings.TrimSpace(identifier))}
+}
+
+// Client is the subject for an OAuth client_id.
+func Client(clientID string) Subject { return Subject{Kind: KindClient, Key: clientID} }
+
+// IP is the subject for a source IP.
+func IP(ip string) Subject { return Subject{Kind: KindIP, Key: ip} }
+
+func (s Subject) String() string { return string(s.Kind) + ":" + s.Key }
+
+// Policy holds the lockout knobs (all env-overridable).
+type Policy struct {
+	Threshold      uint32        // failures in Window that lock a principal or client
+	IPThreshold    uint32        // failures in Window that lock a source IP
+	Window         time.Duration // failure counting window
+	BaseLock       time.Duration // first lock duration; doubles per further lock
+	MaxLock        time.Duration // cap on the lock duration
+	LevelTTL       time.Duration // how long a lock counts towards escalation
+	ChallengeAfter uint32        // principal failures before a challenge is required (0 = never)
+}
+
+// PolicyFromEnv builds a Policy from FC_LOCKOUT_* env vars.
+func PolicyFromEnv() Policy {
+	return Policy{
+		Threshold:      envutil.Uint32("FC_LOCKOUT_THRESHOLD", 10),
+		IPThreshold:    envutil.Uint32("FC_LOCKOUT_IP_THRESHOLD", 50),
+		Window:         time.Duration(envutil.Int("FC_LOCKOUT_WINDOW_SECS", 900)) * time.Second,
+		BaseLock:       time.Duration(envutil.Int("FC_LOCKOUT_BASE_SECS", 300)) * time.Second,
+		MaxLock:        time.Duration(envutil.Int("FC_LOCKOUT_MAX_SECS", 86400)) * time.Second,
+		LevelTTL:       time.Duration(envutil.Int("FC_LOCKOUT_LEVEL_TTL_SECS", 86400)) * time.Second,
+		ChallengeAfter: envutil.Uint32("FC_LOCKOUT_CHALLENGE_AFTER", 3),
+	}
+}
+
+// ThresholdFor returns the failure count that locks a subject of kind k.
+func (p Policy) ThresholdFor(k Kind) uint32 {
+	if k == KindIP {
+		return p.IPThreshold
+	}
+	return p.Threshold
+}
+
+// LockDuration returns how long the level'th lock (1-based) lasts:
+// BaseLock·2^(level-1), capped at MaxLock.
+func (p Policy) LockDuration(level uint32) time.Duration {
+	d := p.BaseLock
+	for i := uint32(1); i < level && d < p.MaxLock; i++ {
+		d *= 2
+	}
+	if d > p.MaxLock {
+		return p.MaxLock
+	}
+	return d
+}
+
+// State is a subject's current standing.
+type State struct {
+	Failures    uint32    // failures in the current window
+	Level       uint32    // locks within LevelTTL; 0 when never locked
+	LockedUntil time.Time // zero when not locked
+}
+
+// Locked reports whether the subject is locked at now.
+func (s State) Locked(now time.Time) bool { return now.Before(s.LockedUntil) }
+
+// Lock is an active lock on a subject.
+type Lock struct {
+	Subject     Subject
+	Level       uint32
+	LockedUntil time.Time
+}
+
+// Store holds failure counts and locks. RecordFailure must be atomic for
+// replicas sharing the store.
+type Store interface {
+	// RecordFailure counts one failure against sub within p.Window. When
+	// the count reaches threshold it locks sub for the next level's
+	// LockDuration and resets the count; locked reports that this call
+	// placed the lock.
+	RecordFailure(ctx context.Context, sub Subject, threshold uint32, p Policy) (st State, locked bool, err error)
+	// Get returns sub's current state.
+	Get(ctx context.Context, sub Subject) (State, error)
+	// ResetFailures zeroes sub's failure count, keeping any lock and level.
+	ResetFailures(ctx context.Context, sub Subject) error
+	// Unlock removes sub's lock, failure count and level, reporting
+	// whether a lock was in place.
+	Unlock(ctx context.Context, sub Subject) (bool, error)
+	// Locks lists the subjects currently locked.
+	Locks(ctx context.Context) ([]Lock, error)
+}
+
+// Build selects the backend: Redis when FC_REDIS_URL is set, so every
+// replica sees the same counts and locks, else a per-instance in-memory
+// store. A configured but unreachable Redis is an error rather than a
+// quiet fall back to per-instance counts, which would multiply every
+// threshold by the replica count.
+func Build(ctx context.Context) (Store, error) {
+	url := os.Getenv("FC_REDIS_URL")
+	if url == "" {
+		slog.Info("FC_REDIS_URL not set; login lockout counts are per instance")
+		return NewMemoryStore(), nil
+	}
+	s, err := NewRedisStore(ctx, url)
+	if err != nil {
+		return nil, fmt.Errorf("login lockout store: FC_REDIS_URL set but Redis unreachable: %w", err)
+	}
+	slog.Info("login lockout store: Redis")
+	return s, nil
+}
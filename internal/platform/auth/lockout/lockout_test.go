@@ -0,0 +1,159 @@
+package lockout
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit"
+)
+
+func testPolicy() Policy {
+	return Policy{
+		Threshold:      3,
+		IPThreshold:    5,
+		Window:         time.Minute,
+		BaseLock:       10 * time.Second,
+		MaxLock:        time.Minute,
+		LevelTTL:       time.Hour,
+		ChallengeAfter: 2,
+	}
+}
+
+// clockStore is a MemoryStore on a settable clock.
+func clockStore() (*MemoryStore, *time.Time) {
+	now := time.Unix(1_700_000_000, 0)
+	s := NewMemoryStore()
+	s.now = func() time.Time { return now }
+	return s, &now
+}
+
+type fakeAudit struct{ logs []*audit.Log }
+
+func (f *fakeAudit) Insert(_ context.Context, l *audit.Log) error {
+	f.logs = append(f.logs, l)
+	return nil
+}
+
+type fakeChallenge struct{ valid string }
+
+func (f fakeChallenge) Verify(_ context.Context, token, _ string) (bool, error) {
+	return token == f.valid, nil
+}
+
+func TestLockDuration(t *testing.T) {
+	p := testPolicy()
+	assert.Equal(t, 10*time.Second, p.LockDuration(1))
+	assert.Equal(t, 20*time.Second, p.LockDuration(2))
+	assert.Equal(t, 40*time.Second, p.LockDuration(3))
+	assert.Equal(t, time.Minute, p.LockDuration(4), "capped")
+	assert.Equal(t, time.Minute, p.LockDuration(^uint32(0)), "no overflow")
+}
+
+func TestMemoryStore_LocksAndEscalates(t *testing.T) {
+	ctx := context.Background()
+	s, now := clockStore()
+	p := testPolicy()
+	sub := Principal("A@Example.com")
+	require.Equal(t, "a@example.com", sub.Key)
+
+	for i := 0; i < 2; i++ {
+		_, locked, err := s.RecordFailure(ctx, sub, p.Threshold, p)
+		require.NoError(t, err)
+		require.False(t, locked)
+	}
+	st, locked, err := s.RecordFailure(ctx, sub, p.Threshold, p)
+	require.NoError(t, err)
+	require.True(t, locked)
+	assert.Equal(t, uint32(1), st.Level)
+	assert.Equal(t, uint32(0), st.Failures, "the count restarts after a lock")
+	assert.Equal(t, now.Add(10*time.Second), st.LockedUntil)
+
+	*now = now.Add(11 * time.Second)
+	st, err = s.Get(ctx, sub)
+	require.NoError(t, err)
+	assert.False(t, st.Locked(*now), "lock expired")
+
+	for i := 0; i < 3; i++ {
+		st, _, err = s.RecordFailure(ctx, sub, p.Threshold, p)
+		require.NoError(t, err)
+	}
+	assert.Equal(t, uint32(2), st.Level)
+	assert.Equal(t, now.Add(20*time.Second), st.LockedUntil, "second lock doubles")
+
+	// Failures outside the window do not accumulate.
+	other := Principal("b@example.com")
+	_, _, _ = s.RecordFailure(ctx, other, p.Threshold, p)
+	_, _, _ = s.RecordFailure(ctx, other, p.Threshold, p)
+	*now = now.Add(2 * time.Minute)
+	_, locked, _ = s.RecordFailure(ctx, other, p.Threshold, p)
+	assert.False(t, locked)
+}
+
+func TestGuard_FailureLocksAuditsAndNotifies(t *testing.T) {
+	ctx := context.Background()
+	s := NewMemoryStore()
+	aud := &fakeAudit{}
+	var notified []Lock
+	g := New(Config{Store: s, Policy: testPolicy(), Audit: aud})
+	g.OnLockout(func(l Lock) { notified = append(notified, l) })
+	user, ip := Principal("a@example.com"), IP("10.0.0.1")
+
+	for i := 0; i < 3; i++ {
+		require.True(t, g.Check(ctx, user, ip).Allowed)
+		g.Failure(ctx, user, ip)
+	}
+	d := g.Check(ctx, user, ip)
+	assert.False(t, d.Allowed)
+	assert.Equal(t, user, d.Subject)
+	assert.Equal(t, uint32(10), d.RetryAfterSecs)
+	assert.True(t, g.Check(ctx, Principal("b@example.com"), IP("10.0.0.2")).Allowed)
+
+	require.Len(t, notified, 1, "the IP is below its own threshold")
+	assert.Equal(t, user, notified[0].Subject)
+	require.Len(t, aud.logs, 1)
+	assert.Equal(t, "LOCKOUT", aud.logs[0].EntityType)
+	assert.Equal(t, "PRINCIPAL:a@example.com", aud.logs[0].EntityID)
+	assert.Equal(t, "CredentialLockout", aud.logs[0].Operation)
+
+	locks, err := g.Locks(ctx)
+	require.NoError(t, err)
+	require.Len(t, locks, 1)
+
+	released, err := g.Unlock(ctx, user, "prn_admin")
+	require.NoError(t, err)
+	assert.True(t, released)
+	assert.True(t, g.Check(ctx, user).Allowed)
+	require.Len(t, aud.logs, 2)
+	assert.Equal(t, "CredentialLockoutReleased", aud.logs[1].Operation)
+	assert.Equal(t, "prn_admin", *aud.logs[1].PrincipalID)
+
+	released, err = g.Unlock(ctx, user, "prn_admin")
+	require.NoError(t, err)
+	assert.False(t, released, "nothing left to release")
+	assert.Len(t, aud.logs, 2)
+}
+
+func TestGuard_Challenge(t *testing.T) {
+	ctx := context.Background()
+	s := NewMemoryStore()
+	user := Principal("a@example.com")
+
+	plain := New(Config{Store: s, Policy: testPolicy()})
+	plain.Failure(ctx, user)
+	plain.Failure(ctx, user)
+	assert.False(t, plain.ChallengeRequired(ctx, user), "no verifier, no challenge")
+	assert.True(t, plain.VerifyChallenge(ctx, "", ""))
+
+	g := New(Config{Store: s, Policy: testPolicy(), Challenge: fakeChallenge{valid: "solved"}})
+	assert.True(t, g.ChallengeRequired(ctx, user))
+	assert.False(t, g.VerifyChallenge(ctx, "", ""))
+	assert.False(t, g.VerifyChallenge(ctx, "wrong", ""))
+	assert.True(t, g.VerifyChallenge(ctx, "solved", ""))
+
+	g.Success(ctx, user)
+	assert.False(t, g.ChallengeRequired(ctx, user), "a success resets the count")
+}
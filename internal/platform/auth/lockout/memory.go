@@ -0,0 +1,142 @@
+package lockout
+
+import (
+	"context"
+	"sort"
+	"sync"
+	"time"
+)
+
+// MemoryStore keeps counts and locks in process. Each replica counts on
+// its own, so the effective threshold scales with the replica count; use
+// Redis when running more than one.
+type MemoryStore struct {
+	mu        sync.Mutex
+	entries   map[Subject]*memEntry
+	nextSweep time.Time
+	now       func() time.Time
+}
+
+type memEntry struct {
+	failures     uint32
+	windowEnds   time.Time
+	level        uint32
+	levelExpires time.Time
+	lockedUntil  time.Time
+}
+
+// NewMemoryStore returns an empty store.
+func NewMemoryStore() *MemoryStore {
+	return &MemoryStore{entries: map[Subject]*memEntry{}, now: time.Now}
+}
+
+// state returns e as seen at now, with expired parts zeroed.
+func (e *memEntry) state(now time.Time) State {
+	var st State
+	if now.Before(e.windowEnds) {
+		st.Failures = e.failures
+	}
+	if now.Before(e.levelExpires) {
+		st.Level = e.level
+	}
+	if now.Before(e.lockedUntil) {
+		st.LockedUntil = e.lockedUntil
+	}
+	return st
+}
+
+// RecordFailure implements Store.
+func (m *MemoryStore) RecordFailure(_ context.Context, sub Subject, threshold uint32, p Policy) (State, bool, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	now := m.now()
+	m.sweepLocked(now)
+
+	e := m.entries[sub]
+	if e == nil {
+		e = &memEntry{}
+		m.entries[sub] = e
+	}
+	st := e.state(now)
+	if st.Failures == 0 {
+		e.windowEnds = now.Add(p.Window)
+	}
+	e.failures = st.Failures + 1
+	if e.failures < threshold {
+		return e.state(now), false, nil
+	}
+	e.failures = 0
+	e.level = st.Level + 1
+	e.levelExpires = now.Add(p.LevelTTL)
+	e.lockedUntil = now.Add(p.LockDuration(e.level))
+	return e.state(now), true, nil
+}
+
+// Get implements Store.
+func (m *MemoryStore) Get(_ context.Context, sub Subject) (State, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	if e := m.entries[sub]; e != nil {
+		return e.state(m.now()), nil
+	}
+	return State{}, nil
+}
+
+// ResetFailures implements Store.
+func (m *MemoryStore) ResetFailures(_ context.Context, sub Subject) error {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	if e := m.entries[sub]; e != nil {
+		e.failures = 0
+	}
+	return nil
+}
+
+// Unlock implements Store.
+func (m *MemoryStore) Unlock(_ context.Context, sub Subject) (bool, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	e := m.entries[sub]
+	if e == nil {
+		return false, nil
+	}
+	delete(m.entries, sub)
+	return e.state(m.now()).Locked(m.now()), nil
+}
+
+// Locks implements Store, ordered by lock expiry.
+func (m *MemoryStore) Locks(context.Context) ([]Lock, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	now := m.now()
+	var out []Lock
+	for sub, e := range m.entries {
+		if st := e.state(now); st.Locked(now) {
+			out = append(out, Lock{Subject: sub, Level: st.Level, LockedUntil: st.LockedUntil})
+		}
+	}
+	sortLocks(out)
+	return out, nil
+}
+
+// sortLocks orders locks by expiry, soonest first.
+func sortLocks(locks []Lock) {
+	sort.Slice(locks, func(i, j int) bool { return locks[i].LockedUntil.Before(locks[j].LockedUntil) })
+}
+
+// memSweepInterval spaces out sweepLocked's full scan.
+const memSweepInterval = time.Minute
+
+// sweepLocked drops entries with nothing left to remember, bounding the
+// map under a spray of one-off identifiers or IPs.
+func (m *MemoryStore) sweepLocked(now time.Time) {
+	if now.Before(m.nextSweep) {
+		return
+	}
+	m.nextSweep = now.Add(memSweepInterval)
+	for sub, e := range m.entries {
+		if e.state(now) == (State{}) {
+			delete(m.entries, sub)
+		}
+	}
+}
@@ -0,0 +1,179 @@
+package lockout
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/redis/go-redis/v9"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/redisconn"
+)
+
+const redisConnectTimeout = 2 * time.Second
+
+// Key layout, per subject "<KIND>:<key>":
+//
+//	fc:lockout:f:<subject>  failure count, TTL Window
+//	fc:lockout:v:<subject>  lock level, TTL LevelTTL
+//	fc:lockout:l:<subject>  locked-until (unix seconds), TTL the lock
+const (
+	failuresPrefix = "fc:lockout:f:"
+	levelPrefix    = "fc:lockout:v:"
+	lockPrefix     = "fc:lockout:l:"
+)
+
+// RedisStore shares counts and locks across replicas. Every key carries a
+// TTL, so nothing needs reaping.
+type RedisStore struct {
+	client redis.UniversalClient
+}
+
+// NewRedisStore connects and PINGs to confirm liveness.
+func NewRedisStore(ctx context.Context, url string) (*RedisStore, error) {
+	client, err := redisconn.Open(url)
+	if err != nil {
+		return nil, fmt.Errorf("invalid redis url: %w", err)
+	}
+	pingCtx, cancel := context.WithTimeout(ctx, redisConnectTimeout)
+	defer cancel()
+	if err := client.Ping(pingCtx).Err(); err != nil {
+		_ = client.Close()
+		return nil, fmt.Errorf("redis ping: %w", err)
+	}
+	return &RedisStore{client: client}, nil
+}
+
+func redisKeys(sub Subject) []string {
+	s := sub.String()
+	return []string{failuresPrefix + s, levelPrefix + s, lockPrefix + s}
+}
+
+// recordFailure increments the failure count and, at the threshold, bumps
+// the level and places the lock in the same step, so concurrent failures
+// on two replicas cannot both escalate. The lock duration doubles per level
+// from ARGV[3], capped at ARGV[4].
+//
+// KEYS: failures, level, lock. ARGV: window, threshold, base, max,
+// level TTL (seconds), now (unix seconds).
+// Returns {failures, level, lockedUntil, lockedNow}.
+var recordFailure = redis.NewScript(`
+local n = redis.call("INCR", KEYS[1])
+if n == 1 then
+  redis.call("EXPIRE", KEYS[1], ARGV[1])
+end
+if n < tonumber(ARGV[2]) then
+  return {n, tonumber(redis.call("GET", KEYS[2]) or "0"), tonumber(redis.call("GET", KEYS[3]) or "0"), 0}
+end
+redis.call("DEL", KEYS[1])
+local level = redis.call("INCR", KEYS[2])
+redis.call("EXPIRE", KEYS[2], ARGV[5])
+local secs = tonumber(ARGV[3])
+local max = tonumber(ARGV[4])
+for i = 2, level do
+  if secs >= max then break end
+  secs = secs * 2
+end
+if secs > max then secs = max end
+if secs < 1 then secs = 1 end
+local untilTs = tonumber(ARGV[6]) + secs
+redis.call("SET", KEYS[3], untilTs, "EX", secs)
+return {0, level, untilTs, 1}
+`)
+
+// RecordFailure implements Store.
+func (s *RedisStore) RecordFailure(ctx context.Context, sub Subject, threshold uint32, p Policy) (State, bool, error) {
+	res, err := recordFailure.Run(ctx, s.client, redisKeys(sub),
+		secs(p.Window), threshold, secs(p.BaseLock), secs(p.MaxLock), secs(p.LevelTTL), time.Now().Unix(),
+	).Int64Slice()
+	if err != nil {
+		return State{}, false, fmt.Errorf("redis record failure: %w", err)
+	}
+	if len(res) != 4 {
+		return State{}, false, fmt.Errorf("redis record failure: unexpected reply %v", res)
+	}
+	return stateFrom(res[0], res[1], res[2]), res[3] == 1, nil
+}
+
+// Get implements Store.
+func (s *RedisStore) Get(ctx context.Context, sub Subject) (State, error) {
+	vals, err := s.client.MGet(ctx, redisKeys(sub)...).Result()
+	if err != nil {
+		return State{}, fmt.Errorf("redis get lockout: %w", err)
+	}
+	n := make([]int64, len(vals))
+	for i, v := range vals {
+		if str, ok := v.(string); ok {
+			n[i], _ = strconv.ParseInt(str, 10, 64)
+		}
+	}
+	return stateFrom(n[0], n[1], n[2]), nil
+}
+
+// ResetFailures implements Store.
+func (s *RedisStore) ResetFailures(ctx context.Context, sub Subject) error {
+	if err := s.client.Del(ctx, redisKeys(sub)[0]).Err(); err != nil {
+		return fmt.Errorf("redis reset failures: %w", err)
+	}
+	return nil
+}
+
+// Unlock implements Store.
+func (s *RedisStore) Unlock(ctx context.Context, sub Subject) (bool, error) {
+	keys := redisKeys(sub)
+	var lockDel *redis.IntCmd
+	_, err := s.client.TxPipelined(ctx, func(pipe redis.Pipeliner) error {
+		pipe.Del(ctx, keys[0], keys[1])
+		lockDel = pipe.Del(ctx, keys[2])
+		return nil
+	})
+	if err != nil {
+		return false, fmt.Errorf("redis unlock: %w", err)
+	}
+	return lockDel.Val() > 0, nil
+}
+
+// Locks implements Store. It SCANs the lock keys, so it is meant for the
+// admin listing, not a hot path.
+func (s *RedisStore) Locks(ctx context.Context) ([]Lock, error) {
+	var out []Lock
+	iter := s.client.Scan(ctx, 0, lockPrefix+"*", 200).Iterator()
+	for iter.Next(ctx) {
+		rest := strings.TrimPrefix(iter.Val(), lockPrefix)
+		kind, key, ok := strings.Cut(rest, ":")
+		if !ok {
+			continue
+		}
+		sub := Subject{Kind: Kind(kind), Key: key}
+		st, err := s.Get(ctx, sub)
+		if err != nil {
+			return nil, err
+		}
+		if st.Locked(time.Now()) {
+			out = append(out, Lock{Subject: sub, Level: st.Level, LockedUntil: st.LockedUntil})
+		}
+	}
+	if err := iter.Err(); err != nil {
+		return nil, fmt.Errorf("redis scan locks: %w", err)
+	}
+	sortLocks(out)
+	return out, nil
+}
+
+func stateFrom(failures, level, lockedUntil int64) State {
+	st := State{Failures: uint32(failures), Level: uint32(level)}
+	if lockedUntil > 0 {
+		st.LockedUntil = time.Unix(lockedUntil, 0).UTC()
+	}
+	return st
+}
+
+// secs is d in whole seconds, at least 1 (EXPIRE rejects 0).
+func secs(d time.Duration) int64 {
+	if s := int64(d / time.Second); s > 1 {
+		return s
+	}
+	return 1
+}
@@ -0,0 +1,59 @@
+package lockout
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"os"
+	"strings"
+	"time"
+)
+
+// SiteVerify is a ChallengeVerifier for the "siteverify" protocol shared by
+// reCAPTCHA, hCaptcha and Cloudflare Turnstile: POST secret, response and
+// remoteip as a form, read {"success": bool} back.
+type SiteVerify struct {
+	URL    string
+	Secret string
+	Client *http.Client
+}
+
+// SiteVerifyFromEnv returns a SiteVerify from FC_LOGIN_CHALLENGE_VERIFY_URL
+// and FC_LOGIN_CHALLENGE_SECRET, or nil when either is unset.
+func SiteVerifyFromEnv() *SiteVerify {
+	u, secret := os.Getenv("FC_LOGIN_CHALLENGE_VERIFY_URL"), os.Getenv("FC_LOGIN_CHALLENGE_SECRET")
+	if u == "" || secret == "" {
+		return nil
+	}
+	return &SiteVerify{URL: u, Secret: secret, Client: &http.Client{Timeout: 5 * time.Second}}
+}
+
+// Verify implements ChallengeVerifier.
+func (v *SiteVerify) Verify(ctx context.Context, token, ip string) (bool, error) {
+	form := url.Values{"secret": {v.Secret}, "response": {token}}
+	if ip != "" {
+		form.Set("remoteip", ip)
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, v.URL, strings.NewReader(form.Encode()))
+	if err != nil {
+		return false, err
+	}
+	req.Header.Set("Content-Type", "application/x-www-form-urlencoded")
+	resp, err := v.Client.Do(req)
+	if err != nil {
+		return false, fmt.Errorf("siteverify: %w", err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+	if resp.StatusCode != http.StatusOK {
+		return false, fmt.Errorf("siteverify: status %d", resp.StatusCode)
+	}
+	var out struct {
+		Success bool `json:"success"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&out); err != nil {
+		return false, fmt.Errorf("siteverify: decode: %w", err)
+	}
+	return out.Success, nil
+}
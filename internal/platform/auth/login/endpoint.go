@@ -26,10 +26,12 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/authservice"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/grantstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/loginbackoff"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/mfatoken"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/passwordhash"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/provider"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/emaildomainmapping"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/identityprovider"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/loginattempt"
@@ -39,7 +41,8 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
 	platformmw "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/middleware"
-	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/ratelimit"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/netpolicy"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/webauthn"
 )
 
 // SessionTTL is the cookie lifetime fc-server uses. Matches the Rust
@@ -48,6 +51,11 @@ import (
 // thread it through env. Override at link time if you need to.
 var SessionTTL = 24 * time.Hour
 
+// PasskeyEnrollmentTTL is the lifetime of the enrollment-only session an
+// admin gets when their domain requires passkeys and they have none yet:
+// long enough to register one, no longer.
+var PasskeyEnrollmentTTL = 15 * time.Minute
+
 // Config bundles the dependencies the handlers need.
 type Config struct {
 	Provider          *provider.Provider
@@ -69,15 +77,25 @@ type Config struct {
 	LoginAttempts *loginattempt.Repository
 	// BackoffPolicy tunes the failed-login backoff/ceiling.
 	BackoffPolicy loginbackoff.Policy
+	// Lockout locks an email or source IP after repeated failures and asks
+	// for a solved challenge before that. Optional (nil disables it).
+	Lockout *lockout.Guard
+	// Proxies resolves the caller address the backoff, lockout and login
+	// attempts are keyed on (FC_TRUSTED_PROXIES). Nil trusts no proxy.
+	Proxies netpolicy.Proxies
 
 	// MFA + MFATokens back the 2FA flow. When MFA or MFATokens is nil the
 	// /auth/2fa/* routes are not mounted and login never challenges (2FA
 	// disabled). A passkey does NOT exempt the password path: a passkey user
-	// who chooses to sign in with a password must still complete 2FA — so the
-	// webauthn repo plays no part here (passkey LOGIN bypasses 2FA on its own
-	// route).
+	// who chooses to sign in with a password must still complete 2FA (passkey
+	// LOGIN bypasses 2FA on its own route).
 	MFA       *mfa.Service
 	MFATokens *mfatoken.Issuer
+	// Passkeys + Clients back the per-client RequirePasskeyForAdmins
+	// policy: an admin-scope user holding a passkey is refused the password
+	// path. Optional — nil Passkeys or Clients disables the policy.
+	Passkeys *webauthn.Repository
+	Clients  *client.Repository
 	// Notifier sends best-effort 2FA security emails (enrolled, recovery codes,
 	// trusted device). Optional — nil is a safe no-op.
 	Notifier *notify.Notifier
@@ -163,7 +181,7 @@ func (e *Endpoint) handleCheckDomain(w http.ResponseWriter, r *http.Request) {
 		Email string `json:"email"`
 	}
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	email := strings.TrimSpace(body.Email)
@@ -273,7 +291,7 @@ var errOAuthBoundToken = errors.New("token is bound to an OAuth client")
 func (e *Endpoint) handleRefresh(w http.ResponseWriter, r *http.Request) {
 	var req refreshRequest
 	if err := json.NewDecoder(r.Body).Decode(&req); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	if req.RefreshToken == "" {
@@ -335,6 +353,9 @@ type loginRequest struct {
 	Email      string `json:"email"`
 	Password   string `json:"password"`
 	RememberMe bool   `json:"rememberMe"`
+	// ChallengeToken is the solved challenge (CAPTCHA response) the SPA
+	// sends once a login has answered CHALLENGE_REQUIRED.
+	ChallengeToken string `json:"challengeToken,omitempty"`
 }
 
 // loginResponse matches the Java/Rust LoginResponse the SPA expects,
@@ -343,8 +364,10 @@ type loginRequest struct {
 // auth port: include the flattened permission set so the SPA's
 // permission store + router guards have what they need at sign-in.
 type loginResponse struct {
-	// Status is "ok" for a completed login. The 2FA-pending responses use
-	// twoFactorResponse with status "mfa_required" / "enrollment_required".
+	// Status is "ok" for a completed login, "passkey_enrollment_required"
+	// for an enrollment-only session (see completePasskeyEnrollment). The
+	// 2FA-pending responses use twoFactorResponse with status
+	// "mfa_required" / "enrollment_required".
 	Status      string   `json:"status"`
 	PrincipalID string   `json:"principalId"`
 	Name        string   `json:"name"`
@@ -379,7 +402,7 @@ func buildPermissionList(claims *provider.Claims) []string {
 func (e *Endpoint) handleLogin(w http.ResponseWriter, r *http.Request) {
 	var req loginRequest
 	if err := json.NewDecoder(r.Body).Decode(&req); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	// Lower-case up front so the backoff identifier matches across attempts:
@@ -392,7 +415,7 @@ func (e *Endpoint) handleLogin(w http.ResponseWriter, r *http.Request) {
 		writeUnauthorized(w, "Invalid credentials")
 		return
 	}
-	ip := clientIP(r)
+	ip := e.clientIP(r)
 
 	// Brute-force backoff: per-(email, IP) exponential delay + per-email
 	// global ceiling. Runs before credentials are evaluated.
@@ -403,11 +426,32 @@ func (e *Endpoint) handleLogin(w http.ResponseWriter, r *http.Request) {
 		}
 	}
 
-	// rejectInvalid records a failed USER_LOGIN attempt and returns 401.
-	rejectInvalid := func() {
-		e.recordAttempt(r.Context(), loginattempt.OutcomeFailure, email, nil, ip, "Invalid credentials")
+	// Lockout: a locked email or source IP is refused outright, and past
+	// the challenge threshold the attempt must carry a solved challenge.
+	// Counted per identifier whether or not it exists, so neither response
+	// reveals an account.
+	if g := e.cfg.Lockout; g != nil {
+		if d := g.Check(r.Context(), lockout.Principal(email), lockout.IP(ip)); !d.Allowed {
+			e.recordAttempt(r.Context(), loginattempt.OutcomeFailure, email, nil, ip, "Locked out")
+			writeLockedOut(w, d.RetryAfterSecs)
+			return
+		}
+		if g.ChallengeRequired(r.Context(), lockout.Principal(email)) && !g.VerifyChallenge(r.Context(), req.ChallengeToken, ip) {
+			writeChallengeRequired(w)
+			return
+		}
+	}
+
+	// reject records a failed USER_LOGIN attempt with reason and returns
+	// the generic 401; rejectInvalid is the bad-credentials case.
+	reject := func(principalID *string, reason string) {
+		e.recordAttempt(r.Context(), loginattempt.OutcomeFailure, email, principalID, ip, reason)
+		if e.cfg.Lockout != nil {
+			e.cfg.Lockout.Failure(r.Context(), lockout.Principal(email), lockout.IP(ip))
+		}
 		writeUnauthorized(w, "Invalid credentials")
 	}
+	rejectInvalid := func() { reject(nil, "Invalid credentials") }
 
 	p, err := e.cfg.Principals.FindByEmail(r.Context(), email)
 	if err != nil || p == nil || !p.Active {
@@ -441,6 +485,23 @@ func (e *Endpoint) handleLogin(w http.ResponseWriter, r *http.Request) {
 		slog.Warn("email lowercase self-heal failed; login continues", "principal", p.ID, "err", herr)
 	}
 
+	// Admin passkey policy: an admin-scope user of a client that requires
+	// passkeys for admins must use the passkey they hold. The refusal is a
+	// failed login to the caller — same response, same lockout count — so
+	// it doesn't confirm the password; only the attempt log records why.
+	// An admin without a passkey yet continues, and completeLogin limits
+	// the session to enrolling one. Fails closed like the 2FA gate below.
+	gate, err := e.passkeyPolicy(r.Context(), p)
+	if err != nil {
+		slog.Error("passkey policy evaluation failed; denying login", "principal", p.ID, "err", err)
+		writePasskeyEvalFailed(w)
+		return
+	}
+	if gate == passkeyOnly {
+		reject(&p.ID, "Passkey required")
+		return
+	}
+
 	// Second-factor gate. When MFA is wired and applies to this user, return a
 	// pending/enrollment challenge instead of a session — the /auth/2fa/* flow
 	// then completes the login (and records the success). We fail CLOSED: an
@@ -472,6 +533,27 @@ func (e *Endpoint) completeLogin(w http.ResponseWriter, r *http.Request, p *prin
 	if p.UserIdentity != nil {
 		email = p.UserIdentity.Email
 	}
+	// Re-evaluated here rather than trusted from the password step: the 2FA
+	// handlers complete logins too, and a passkey enrolled in between must
+	// now be used.
+	gate, err := e.passkeyPolicy(r.Context(), p)
+	if err != nil {
+		slog.Error("passkey policy evaluation failed; denying login", "principal", p.ID, "err", err)
+		writePasskeyEvalFailed(w)
+		return
+	}
+	switch gate {
+	case passkeyOnly:
+		e.recordAttempt(r.Context(), loginattempt.OutcomeFailure, email, &p.ID, e.clientIP(r), "Passkey required")
+		if e.cfg.Lockout != nil {
+			e.cfg.Lockout.Failure(r.Context(), lockout.Principal(email), lockout.IP(e.clientIP(r)))
+		}
+		writeUnauthorized(w, "Invalid credentials")
+		return
+	case passkeyEnroll:
+		e.completePasskeyEnrollment(w, r, p, email, recoveryCodes)
+		return
+	}
 	token, err := e.cfg.Provider.MintSessionToken(r.Context(), p.ID, SessionTTL)
 	if err != nil {
 		httperror.Write(w, httperror.BadRequest("MINT_FAILED", err.Error()))
@@ -487,7 +569,10 @@ func (e *Endpoint) completeLogin(w http.ResponseWriter, r *http.Request, p *prin
 		Expires:  time.Now().Add(SessionTTL),
 		MaxAge:   int(SessionTTL.Seconds()),
 	})
-	e.recordAttempt(r.Context(), loginattempt.OutcomeSuccess, email, &p.ID, clientIP(r), "")
+	e.recordAttempt(r.Context(), loginattempt.OutcomeSuccess, email, &p.ID, e.clientIP(r), "")
+	if e.cfg.Lockout != nil {
+		e.cfg.Lockout.Success(r.Context(), lockout.Principal(email))
+	}
 	claims, err := e.cfg.Provider.ResolveClaims(r.Context(), p.ID)
 	if err != nil {
 		// Auth succeeded but we couldn't load roles/permissions — log
@@ -511,6 +596,51 @@ func (e *Endpoint) completeLogin(w http.ResponseWriter, r *http.Request, p *prin
 	})
 }
 
+// completePasskeyEnrollment signs in an admin who must use a passkey but
+// has none yet: the cookie is an enrollment-only session
+// (provider.MintPasskeyEnrollmentToken) that can register a passkey and
+// nothing else, and the payload carries status "passkey_enrollment_required"
+// with no roles or permissions. The admin then signs in with the new
+// passkey for a full session.
+func (e *Endpoint) completePasskeyEnrollment(w http.ResponseWriter, r *http.Request, p *principal.Principal, email string, recoveryCodes []string) {
+	token, err := e.cfg.Provider.MintPasskeyEnrollmentToken(r.Context(), p.ID, PasskeyEnrollmentTTL)
+	if err != nil {
+		httperror.Write(w, httperror.BadRequest("MINT_FAILED", err.Error()))
+		return
+	}
+	http.SetCookie(w, &http.Cookie{
+		Name:     platformmw.SessionCookieName,
+		Value:    token,
+		Path:     "/",
+		HttpOnly: true,
+		Secure:   e.cfg.CookieSecure,
+		SameSite: http.SameSiteLaxMode,
+		Expires:  time.Now().Add(PasskeyEnrollmentTTL),
+		MaxAge:   int(PasskeyEnrollmentTTL.Seconds()),
+	})
+	e.recordAttempt(r.Context(), loginattempt.OutcomeSuccess, email, &p.ID, e.clientIP(r), "")
+	if e.cfg.Lockout != nil {
+		e.cfg.Lockout.Success(r.Context(), lockout.Principal(email))
+	}
+	writeJSON(w, http.StatusOK, loginResponse{
+		Status:        "passkey_enrollment_required",
+		PrincipalID:   p.ID,
+		Name:          p.Name,
+		Email:         email,
+		Roles:         []string{},
+		Permissions:   []string{},
+		ClientID:      p.ClientID,
+		RecoveryCodes: recoveryCodes,
+	})
+}
+
+func writePasskeyEvalFailed(w http.ResponseWriter) {
+	writeJSON(w, http.StatusInternalServerError, map[string]any{
+		"code":    "PASSKEY_EVAL_FAILED",
+		"message": "could not evaluate passkey requirement",
+	})
+}
+
 // ── /auth/logout ─────────────────────────────────────────────────────────
 
 func (e *Endpoint) handleLogout(w http.ResponseWriter, r *http.Request) {
@@ -548,6 +678,18 @@ func (e *Endpoint) handleMe(w http.ResponseWriter, r *http.Request) {
 		writeUnauthorized(w, "Not authenticated")
 		return
 	}
+	if ac.PasskeyEnrollmentOnly {
+		writeJSON(w, http.StatusOK, loginResponse{
+			Status:      "passkey_enrollment_required",
+			PrincipalID: p.ID,
+			Name:        p.Name,
+			Email:       emailOf(p),
+			Roles:       []string{},
+			Permissions: []string{},
+			ClientID:    p.ClientID,
+		})
+		return
+	}
 	claims, err := e.cfg.Provider.ResolveClaims(r.Context(), p.ID)
 	if err != nil {
 		claims = &provider.Claims{}
@@ -601,11 +743,10 @@ func (e *Endpoint) recordAttempt(ctx context.Context, outcome loginattempt.Outco
 	_ = e.cfg.LoginAttempts.Record(ctx, a)
 }
 
-// clientIP extracts the best-effort client IP. Delegates to the canonical
-// ratelimit.ClientIP (rightmost X-Forwarded-For hop — see its doc for why
-// leftmost is spoofable) so the backoff keys and the per-IP rate limiter
-// always agree on what "the client's IP" means.
-func clientIP(r *http.Request) string { return ratelimit.ClientIP(r) }
+// clientIP resolves the caller address through the trusted proxies. An
+// X-Forwarded-For from any other peer is ignored, so rotating or forging
+// it can neither dodge the per-IP lockout nor lock out someone else.
+func (e *Endpoint) clientIP(r *http.Request) string { return e.cfg.Proxies.ClientIP(r) }
 
 // writeTooManyRequests emits a 429 with a Retry-After header, mirroring
 // Rust's backoff rejection.
@@ -619,6 +760,29 @@ func writeTooManyRequests(w http.ResponseWriter, retryAfterSecs uint32) {
 	})
 }
 
+// writeLockedOut emits a 429 with a Retry-After header for a locked email
+// or source IP.
+func writeLockedOut(w http.ResponseWriter, retryAfterSecs uint32) {
+	w.Header().Set("Content-Type", "application/json")
+	w.Header().Set("Retry-After", strconv.FormatUint(uint64(retryAfterSecs), 10))
+	w.WriteHeader(http.StatusTooManyRequests)
+	_ = json.NewEncoder(w).Encode(map[string]any{
+		"code":    "ACCOUNT_LOCKED",
+		"message": "sign-in is temporarily locked after repeated failures; try again later",
+	})
+}
+
+// writeChallengeRequired emits a 401 telling the SPA to solve a challenge
+// and resend the login with challengeToken.
+func writeChallengeRequired(w http.ResponseWriter) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(http.StatusUnauthorized)
+	_ = json.NewEncoder(w).Encode(map[string]any{
+		"code":    "CHALLENGE_REQUIRED",
+		"message": "solve the challenge and retry",
+	})
+}
+
 // writeUnauthorized emits a 401 with the platform's error envelope.
 // 401 isn't part of the usecase.Error → status table (which only maps
 // validation/forbidden/notfound/conflict/businessrule), so we render
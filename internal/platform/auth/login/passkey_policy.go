@@ -0,0 +1,48 @@
+package login
+
+import (
+	"context"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
+)
+
+// passkeyGate is what the admin passkey policy allows a password sign-in.
+type passkeyGate int
+
+const (
+	// passkeyNotRequired: the policy doesn't apply; sign in as usual.
+	passkeyNotRequired passkeyGate = iota
+	// passkeyEnroll: the policy applies and p has no passkey yet. The
+	// password (and 2FA) still sign p in, but only to a session that can
+	// register a passkey and nothing else.
+	passkeyEnroll
+	// passkeyOnly: the policy applies and p has a passkey; password sign-in
+	// is refused.
+	passkeyOnly
+)
+
+// passkeyPolicy evaluates the admin passkey policy for p: p is
+// admin-scope and one of its clients requires passkeys for admins. Its
+// clients are its home client and assigned clients; an anchor-scope user
+// administers every client, so any client requiring passkeys binds it.
+func (e *Endpoint) passkeyPolicy(ctx context.Context, p *principal.Principal) (passkeyGate, error) {
+	if e.cfg.Passkeys == nil || e.cfg.Clients == nil || !p.IsAdminScope() {
+		return passkeyNotRequired, nil
+	}
+	ids := p.AssignedClients
+	if p.ClientID != nil {
+		ids = append([]string{*p.ClientID}, ids...)
+	}
+	required, err := e.cfg.Clients.RequiresPasskeyForAdmins(ctx, p.Scope == principal.ScopeAnchor, ids)
+	if err != nil || !required {
+		return passkeyNotRequired, err
+	}
+	creds, err := e.cfg.Passkeys.FindByPrincipal(ctx, p.ID)
+	if err != nil {
+		return passkeyNotRequired, err
+	}
+	if len(creds) > 0 {
+		return passkeyOnly, nil
+	}
+	return passkeyEnroll, nil
+}
@@ -166,7 +166,7 @@ func (e *Endpoint) handle2FAVerify(w http.ResponseWriter, r *http.Request) {
 	// Brute-force backoff on the second factor, keyed per (email, IP) — same
 	// store + policy as the password step. Failed verifies are recorded below,
 	// so repeated wrong codes throttle just like wrong passwords.
-	ip := clientIP(r)
+	ip := e.clientIP(r)
 	email := emailOf(p)
 	if e.cfg.LoginAttempts != nil {
 		if d, derr := loginbackoff.Check(r.Context(), e.cfg.LoginAttempts, e.cfg.BackoffPolicy, email, ip); derr == nil && !d.Allowed {
@@ -12,6 +12,8 @@
 //
 //   - Stable across instances/restarts (same RSA key → same secret), so a token
 //     minted by one node validates on another — unlike a random per-process key.
+//     After a signing-key rotation (Rekey) tokens under the previous secret
+//     still parse, so a login in progress spans the switch.
 //   - Rejected by the session middleware, which only accepts RS256
 //     (sessiontoken.Validate enforces WithValidMethods{RS256}). A pending/enroll
 //     token therefore can NEVER be replayed as a session cookie.
@@ -22,6 +24,7 @@ import (
 	"crypto/sha256"
 	"errors"
 	"fmt"
+	"sync/atomic"
 	"time"
 
 	"github.com/golang-jwt/jwt/v5"
@@ -39,15 +42,38 @@ const (
 
 // Issuer mints + validates tokens.
 type Issuer struct {
-	secret []byte
-	issuer string
+	secrets atomic.Pointer[secretPair]
+	issuer  string
+}
+
+// secretPair is the minting secret plus, after a Rekey, the one before it.
+type secretPair struct {
+	current  []byte
+	previous []byte
 }
 
 // NewIssuer derives a stable HMAC secret from the RSA signing key. Panics only
 // if key is nil (a programmer error at wiring time).
 func NewIssuer(key *rsa.PrivateKey, issuer string) *Issuer {
+	i := &Issuer{issuer: issuer}
+	i.secrets.Store(&secretPair{current: deriveSecret(key)})
+	return i
+}
+
+// Rekey switches minting to the secret derived from key — the signing key
+// after a keyring rotation — keeping the current secret for Parse.
+func (i *Issuer) Rekey(key *rsa.PrivateKey) {
+	cur := i.secrets.Load()
+	next := deriveSecret(key)
+	if string(next) == string(cur.current) {
+		return
+	}
+	i.secrets.Store(&secretPair{current: next, previous: cur.current})
+}
+
+func deriveSecret(key *rsa.PrivateKey) []byte {
 	sum := sha256.Sum256(append([]byte("fc-mfa-token-v1|"), key.D.Bytes()...))
-	return &Issuer{secret: sum[:], issuer: issuer}
+	return sum[:]
 }
 
 // Claims is the validated payload.
@@ -70,21 +96,17 @@ func (i *Issuer) Mint(subject string, purpose Purpose, ttl time.Duration) (strin
 		"nbf": now.Unix(),
 		"exp": now.Add(ttl).Unix(),
 	})
-	return tok.SignedString(i.secret)
+	return tok.SignedString(i.secrets.Load().current)
 }
 
 // Parse validates the signature, expiry, issuer and that the purpose matches
 // want. Returns the claims on success.
 func (i *Issuer) Parse(token string, want Purpose) (*Claims, error) {
-	parsed, err := jwt.Parse(token, func(t *jwt.Token) (interface{}, error) {
-		if _, ok := t.Method.(*jwt.SigningMethodHMAC); !ok {
-			return nil, fmt.Errorf("unexpected signing method %v", t.Header["alg"])
-		}
-		return i.secret, nil
-	},
-		jwt.WithValidMethods([]string{jwt.SigningMethodHS256.Alg()}),
-		jwt.WithIssuer(i.issuer),
-	)
+	pair := i.secrets.Load()
+	parsed, err := i.parse(token, pair.current)
+	if errors.Is(err, jwt.ErrTokenSignatureInvalid) && pair.previous != nil {
+		parsed, err = i.parse(token, pair.previous)
+	}
 	if err != nil {
 		return nil, err
 	}
@@ -102,3 +124,15 @@ func (i *Issuer) Parse(token string, want Purpose) (*Claims, error) {
 	}
 	return &Claims{Subject: sub, Purpose: Purpose(prp)}, nil
 }
+
+func (i *Issuer) parse(token string, secret []byte) (*jwt.Token, error) {
+	return jwt.Parse(token, func(t *jwt.Token) (interface{}, error) {
+		if _, ok := t.Method.(*jwt.SigningMethodHMAC); !ok {
+			return nil, fmt.Errorf("unexpected signing method %v", t.Header["alg"])
+		}
+		return secret, nil
+	},
+		jwt.WithValidMethods([]string{jwt.SigningMethodHS256.Alg()}),
+		jwt.WithIssuer(i.issuer),
+	)
+}
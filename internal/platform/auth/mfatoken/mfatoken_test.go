@@ -78,3 +78,22 @@ func TestRejectedBySessionValidator(t *testing.T) {
 		t.Fatal("mfa pending token must NOT validate as a session token")
 	}
 }
+
+func TestRekeyKeepsPreviousSecret(t *testing.T) {
+	iss := NewIssuer(testKey(t), "iss")
+	before, _ := iss.Mint("prn_1", PurposePending, time.Minute)
+
+	iss.Rekey(testKey(t))
+	after, _ := iss.Mint("prn_1", PurposePending, time.Minute)
+	if _, err := iss.Parse(before, PurposePending); err != nil {
+		t.Fatalf("token minted before the rotation must still parse: %v", err)
+	}
+	if _, err := iss.Parse(after, PurposePending); err != nil {
+		t.Fatalf("parse after rekey: %v", err)
+	}
+
+	iss.Rekey(testKey(t))
+	if _, err := iss.Parse(before, PurposePending); err == nil {
+		t.Fatal("only the secret immediately before the current one is kept")
+	}
+}
@@ -0,0 +1,314 @@
+package oauthapi
+
+import (
+	"encoding/json"
+	"net/http"
+	"strings"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/grantstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
+)
+
+// DeviceCodeGrantType is the RFC 8628 grant_type URN the device polls
+// /oauth/token with. Clients must list it in their grant-type allowlist
+// (or have none) to start a device flow.
+const DeviceCodeGrantType = "urn:ietf:params:oauth:grant-type:device_code"
+
+// deviceVerificationPath is the SPA page the user opens on a second device
+// to enter the user code. The SPA resolves and decides the code through the
+// /oauth/device/verify endpoints below.
+const deviceVerificationPath = "/device"
+
+// RegisterDeviceAuthorizationRoutes mounts POST /oauth/device_authorization
+// (client-authenticated like /oauth/token — mount it with the same per-IP
+// throttle).
+func (s *State) RegisterDeviceAuthorizationRoutes(r chi.Router) {
+	r.Post("/oauth/device_authorization", s.DeviceAuthorization)
+}
+
+// RegisterDeviceVerificationRoutes mounts the user-facing verification
+// endpoints. Like /oauth/authorize they MUST be mounted OUTSIDE the
+// session-auth middleware: they validate the session cookie themselves and
+// answer login_required rather than a platform 401.
+func (s *State) RegisterDeviceVerificationRoutes(r chi.Router) {
+	r.Get("/oauth/device/verify", s.DeviceVerifyLookup)
+	r.Post("/oauth/device/verify", s.DeviceVerifyDecide)
+}
+
+// deviceAuthorizationResponse is the RFC 8628 §3.2 response.
+type deviceAuthorizationResponse struct {
+	DeviceCode              string `json:"device_code"`
+	UserCode                string `json:"user_code"`
+	VerificationURI         string `json:"verification_uri"`
+	VerificationURIComplete string `json:"verification_uri_complete"`
+	ExpiresIn               int64  `json:"expires_in"`
+	Interval                int64  `json:"interval"`
+}
+
+// DeviceAuthorization is POST /oauth/device_authorization (RFC 8628 §3.1).
+// It authenticates the client (public clients present only client_id),
+// validates the requested scope against the client's registered scopes, and
+// issues a device_code / user_code pair.
+func (s *State) DeviceAuthorization(w http.ResponseWriter, r *http.Request) {
+	if s.DeviceCodes == nil {
+		writeOAuthError(w, http.StatusBadRequest, "unsupported_grant_type", "Device authorization is not enabled")
+		return
+	}
+	if err := r.ParseForm(); err != nil {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_request", "Malformed form body")
+		return
+	}
+	client, errResp := s.authenticateClient(r, r.PostFormValue("client_id"), r.PostFormValue("client_secret"))
+	if errResp != nil {
+		errResp.write(w)
+		return
+	}
+	if !grantAllowed(client, DeviceCodeGrantType) {
+		writeOAuthError(w, http.StatusBadRequest, "unauthorized_client",
+			"Client is not permitted to use the device_code grant type")
+		return
+	}
+	scope := r.PostFormValue("scope")
+	if scope != "" {
+		if invalid := invalidScopes(scope, client.Scopes); len(invalid) > 0 {
+			writeOAuthError(w, http.StatusBadRequest, "invalid_scope", "Invalid scope(s): "+strings.Join(invalid, ", "))
+			return
+		}
+	}
+
+	userCode, err := grantstore.GenerateUserCode()
+	if err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return
+	}
+	dc := grantstore.NewDeviceCode(randomString(32), userCode, client.ClientID)
+	dc.Scope = strPtrOrNil(scope)
+	if err := s.DeviceCodes.Insert(r.Context(), dc); err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return
+	}
+
+	verificationURI := s.BaseURL + deviceVerificationPath
+	w.Header().Set("Cache-Control", "no-store")
+	writeJSON(w, http.StatusOK, deviceAuthorizationResponse{
+		DeviceCode:              dc.DeviceCode,
+		UserCode:                dc.UserCode,
+		VerificationURI:         verificationURI,
+		VerificationURIComplete: verificationURI + "?user_code=" + pctEncode(dc.UserCode),
+		ExpiresIn:               int64(time.Until(dc.ExpiresAt).Seconds()),
+		Interval:                int64(dc.Interval / time.Second),
+	})
+}
+
+// handleDeviceCodeGrant is the device's polling leg (RFC 8628 §3.4/§3.5).
+// Pending and too-fast polls answer with the RFC's distinct error codes; an
+// approved code is consumed atomically and redeemed exactly once.
+func (s *State) handleDeviceCodeGrant(w http.ResponseWriter, r *http.Request, req tokenRequest, client *auth.OAuthClient) {
+	if s.DeviceCodes == nil {
+		writeOAuthError(w, http.StatusBadRequest, "unsupported_grant_type", "Device authorization is not enabled")
+		return
+	}
+	if req.DeviceCode == "" {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_request", "Missing 'device_code' parameter")
+		return
+	}
+
+	dc, prevPoll, err := s.DeviceCodes.Poll(r.Context(), req.DeviceCode)
+	if err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return
+	}
+	// Bind to the AUTHENTICATED client, as the authorization_code grant does.
+	if dc == nil || client == nil || client.ClientID != dc.ClientID {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_grant", "Invalid device code")
+		return
+	}
+	if dc.Consumed {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_grant", "Device code has already been used")
+		return
+	}
+	if dc.IsExpired() {
+		writeOAuthError(w, http.StatusBadRequest, "expired_token", "Device code has expired")
+		return
+	}
+
+	switch dc.Status {
+	case grantstore.DeviceCodeDenied:
+		writeOAuthError(w, http.StatusBadRequest, "access_denied", "The user denied the authorization request")
+		return
+	case grantstore.DeviceCodePending:
+		// Each slow_down lengthens the interval later polls are held to.
+		if prevPoll != nil && time.Since(*prevPoll) < dc.Interval {
+			if _, err := s.DeviceCodes.SlowDown(r.Context(), req.DeviceCode); err != nil {
+				writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+				return
+			}
+			writeOAuthError(w, http.StatusBadRequest, "slow_down", "Polling too frequently")
+			return
+		}
+		writeOAuthError(w, http.StatusBadRequest, "authorization_pending", "")
+		return
+	}
+
+	redeemed, err := s.DeviceCodes.ConsumeApproved(r.Context(), req.DeviceCode)
+	if err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return
+	}
+	if redeemed == nil || redeemed.PrincipalID == nil {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_grant", "Device code has already been used")
+		return
+	}
+
+	p, err := s.Principals.FindByID(r.Context(), *redeemed.PrincipalID)
+	if err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return
+	}
+	if p == nil || !p.Active {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_grant", "Principal not found")
+		return
+	}
+
+	scope := ""
+	if redeemed.Scope != nil {
+		scope = *redeemed.Scope
+	}
+	s.issueDelegatedTokens(w, r, p, redeemed.ClientID, client, scope, nil)
+}
+
+// deviceVerifyResponse describes a pending device authorization to the
+// verification page so the user can see what they are approving.
+type deviceVerifyResponse struct {
+	UserCode   string  `json:"userCode"`
+	ClientID   string  `json:"clientId"`
+	ClientName string  `json:"clientName"`
+	Scope      *string `json:"scope,omitempty"`
+	ExpiresAt  string  `json:"expiresAt"`
+}
+
+// DeviceVerifyLookup is GET /oauth/device/verify?user_code=… — resolves a
+// pending user code for the signed-in user.
+func (s *State) DeviceVerifyLookup(w http.ResponseWriter, r *http.Request) {
+	if _, ok := s.deviceVerifier(w, r); !ok {
+		return
+	}
+	dc, client, ok := s.resolvePendingDeviceCode(w, r, r.URL.Query().Get("user_code"))
+	if !ok {
+		return
+	}
+	writeJSON(w, http.StatusOK, deviceVerifyResponse{
+		UserCode:   dc.UserCode,
+		ClientID:   dc.ClientID,
+		ClientName: client.ClientName,
+		Scope:      dc.Scope,
+		ExpiresAt:  dc.ExpiresAt.Format(time.RFC3339),
+	})
+}
+
+type deviceDecisionRequest struct {
+	UserCode string `json:"userCode"`
+	Action   string `json:"action"` // "approve" | "deny"
+}
+
+// DeviceVerifyDecide is POST /oauth/device/verify {userCode, action} — the
+// signed-in user approves or denies the device. The decision is one-shot.
+func (s *State) DeviceVerifyDecide(w http.ResponseWriter, r *http.Request) {
+	p, ok := s.deviceVerifier(w, r)
+	if !ok {
+		return
+	}
+	var body deviceDecisionRequest
+	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_request", "Malformed JSON body")
+		return
+	}
+	var status grantstore.DeviceCodeStatus
+	switch body.Action {
+	case "approve":
+		status = grantstore.DeviceCodeApproved
+	case "deny":
+		status = grantstore.DeviceCodeDenied
+	default:
+		writeOAuthError(w, http.StatusBadRequest, "invalid_request", "action must be 'approve' or 'deny'")
+		return
+	}
+	dc, _, ok := s.resolvePendingDeviceCode(w, r, body.UserCode)
+	if !ok {
+		return
+	}
+	decided, err := s.DeviceCodes.Decide(r.Context(), dc.UserCode, status, p.ID)
+	if err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return
+	}
+	if !decided {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_grant", "Code is no longer pending")
+		return
+	}
+	writeJSON(w, http.StatusOK, map[string]string{"status": string(status)})
+}
+
+// deviceVerifier resolves the signed-in user from the session cookie /
+// bearer. Writes the error response and returns ok=false when there is no
+// usable session or the principal cannot approve.
+func (s *State) deviceVerifier(w http.ResponseWriter, r *http.Request) (*principal.Principal, bool) {
+	if s.DeviceCodes == nil {
+		writeOAuthError(w, http.StatusNotFound, "invalid_request", "Device authorization is not enabled")
+		return nil, false
+	}
+	tok := s.sessionToken(r)
+	if tok == "" || s.ValidateSession == nil {
+		writeOAuthError(w, http.StatusUnauthorized, "login_required", "User is not authenticated")
+		return nil, false
+	}
+	subject, _, ok := s.ValidateSession(tok)
+	if !ok {
+		writeOAuthError(w, http.StatusUnauthorized, "login_required", "User is not authenticated")
+		return nil, false
+	}
+	p, err := s.Principals.FindByID(r.Context(), subject)
+	if err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return nil, false
+	}
+	if p == nil || !p.Active || p.Type != principal.TypeUser {
+		writeOAuthError(w, http.StatusForbidden, "access_denied", "Only active users can authorize devices")
+		return nil, false
+	}
+	return p, true
+}
+
+// resolvePendingDeviceCode normalises a typed user code and loads the
+// pending device code plus its (still active) client. Writes the error
+// response and returns ok=false on any miss.
+func (s *State) resolvePendingDeviceCode(w http.ResponseWriter, r *http.Request, rawUserCode string) (*grantstore.DeviceCode, *auth.OAuthClient, bool) {
+	userCode := grantstore.NormalizeUserCode(rawUserCode)
+	if userCode == "" {
+		writeOAuthError(w, http.StatusBadRequest, "invalid_request", "Missing user_code")
+		return nil, nil, false
+	}
+	dc, err := s.DeviceCodes.FindPendingByUserCode(r.Context(), userCode)
+	if err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return nil, nil, false
+	}
+	if dc == nil {
+		writeOAuthError(w, http.StatusNotFound, "invalid_grant", "Unknown or expired code")
+		return nil, nil, false
+	}
+	client, err := s.OAuthClients.FindByClientID(r.Context(), dc.ClientID)
+	if err != nil {
+		writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
+		return nil, nil, false
+	}
+	if client == nil || !client.Active {
+		writeOAuthError(w, http.StatusNotFound, "invalid_grant", "Unknown or expired code")
+		return nil, nil, false
+	}
+	return dc, client, true
+}
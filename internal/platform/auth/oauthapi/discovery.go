@@ -25,6 +25,7 @@ type openIDConfiguration struct {
 	EndSessionEndpoint                string   `json:"end_session_endpoint,omitempty"`
 	IntrospectionEndpoint             string   `json:"introspection_endpoint,omitempty"`
 	RevocationEndpoint                string   `json:"revocation_endpoint,omitempty"`
+	DeviceAuthorizationEndpoint       string   `json:"device_authorization_endpoint,omitempty"`
 	JwksURI                           string   `json:"jwks_uri"`
 	ResponseTypesSupported            []string `json:"response_types_supported"`
 	SubjectTypesSupported             []string `json:"subject_types_supported"`
@@ -41,15 +42,26 @@ type openIDConfiguration struct {
 // OpenIDConfiguration serves GET /.well-known/openid-configuration.
 func (s *State) OpenIDConfiguration(w http.ResponseWriter, _ *http.Request) {
 	base := s.BaseURL
+	grantTypes := []string{
+		"authorization_code", "refresh_token", "client_credentials",
+	}
+	// The device grant (RFC 8628) is a Go-side addition, advertised only
+	// when its store is wired.
+	var deviceEndpoint string
+	if s.DeviceCodes != nil {
+		grantTypes = append(grantTypes, DeviceCodeGrantType)
+		deviceEndpoint = base + "/oauth/device_authorization"
+	}
 	writeJSON(w, http.StatusOK, openIDConfiguration{
-		Issuer:                base,
-		AuthorizationEndpoint: base + "/oauth/authorize",
-		TokenEndpoint:         base + "/oauth/token",
-		UserinfoEndpoint:      base + "/oauth/userinfo",
-		EndSessionEndpoint:    base + "/auth/oidc/session/end",
-		IntrospectionEndpoint: base + "/oauth/introspect",
-		RevocationEndpoint:    base + "/oauth/revoke",
-		JwksURI:               base + "/.well-known/jwks.json",
+		Issuer:                      base,
+		AuthorizationEndpoint:       base + "/oauth/authorize",
+		TokenEndpoint:               base + "/oauth/token",
+		UserinfoEndpoint:            base + "/oauth/userinfo",
+		EndSessionEndpoint:          base + "/auth/oidc/session/end",
+		IntrospectionEndpoint:       base + "/oauth/introspect",
+		RevocationEndpoint:          base + "/oauth/revoke",
+		DeviceAuthorizationEndpoint: deviceEndpoint,
+		JwksURI:                     base + "/.well-known/jwks.json",
 		ResponseTypesSupported: []string{
 			"code", "token", "id_token",
 			"code token", "code id_token", "token id_token",
@@ -61,9 +73,7 @@ func (s *State) OpenIDConfiguration(w http.ResponseWriter, _ *http.Request) {
 		TokenEndpointAuthMethodsSupported: []string{
 			"client_secret_basic", "client_secret_post",
 		},
-		GrantTypesSupported: []string{
-			"authorization_code", "refresh_token", "client_credentials",
-		},
+		GrantTypesSupported: grantTypes,
 		ClaimsSupported: []string{
 			"sub", "iss", "aud", "exp", "iat", "auth_time", "nonce",
 			"name", "email", "email_verified", "acr", "amr", "azp",
@@ -10,6 +10,7 @@ import (
 	"testing"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/authservice"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/grantstore"
 )
 
 func testState(t *testing.T) *State {
@@ -72,6 +73,31 @@ func TestOpenIDConfiguration(t *testing.T) {
 	assertContains(t, doc, "claims_supported", "type")
 }
 
+func TestOpenIDConfiguration_DeviceGrantAdvertisedWhenWired(t *testing.T) {
+	s := testState(t)
+	rec := httptest.NewRecorder()
+	s.OpenIDConfiguration(rec, httptest.NewRequest("GET", "/.well-known/openid-configuration", nil))
+	var doc map[string]any
+	if err := json.Unmarshal(rec.Body.Bytes(), &doc); err != nil {
+		t.Fatalf("unmarshal: %v", err)
+	}
+	if _, ok := doc["device_authorization_endpoint"]; ok {
+		t.Errorf("device_authorization_endpoint advertised without a device-code store")
+	}
+
+	s.DeviceCodes = grantstore.NewDeviceCodeRepository(nil)
+	rec = httptest.NewRecorder()
+	s.OpenIDConfiguration(rec, httptest.NewRequest("GET", "/.well-known/openid-configuration", nil))
+	doc = nil
+	if err := json.Unmarshal(rec.Body.Bytes(), &doc); err != nil {
+		t.Fatalf("unmarshal: %v", err)
+	}
+	if doc["device_authorization_endpoint"] != "https://fc.example/oauth/device_authorization" {
+		t.Errorf("device_authorization_endpoint = %v", doc["device_authorization_endpoint"])
+	}
+	assertContains(t, doc, "grant_types_supported", DeviceCodeGrantType)
+}
+
 func TestJWKS(t *testing.T) {
 	s := testState(t)
 	rec := httptest.NewRecorder()
@@ -28,10 +28,12 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/authservice"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/grantstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/loginattempt"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
 	sharedauth "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/netpolicy"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/ratelimit"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
 )
@@ -52,6 +54,9 @@ type State struct {
 	AuthCodes     *grantstore.AuthorizationCodeRepository
 	RefreshTokens *grantstore.RefreshTokenRepository
 	PendingAuth   *grantstore.PendingAuthRepository
+	// DeviceCodes backs the RFC 8628 device authorization grant. Optional
+	// (nil disables /oauth/device_authorization and the device_code grant).
+	DeviceCodes *grantstore.DeviceCodeRepository
 	// ValidateSession resolves the principal id + token issue time from a
 	// session-cookie / bearer token on /oauth/authorize, returning ok=false
 	// when the token is absent, invalid, or expired (authorize then
@@ -92,6 +97,39 @@ type State struct {
 	// checked before the distributed RateLimit on /oauth/token (sheds a
 	// flood locally before the network round-trip). Optional (nil skips it).
 	ClientGovernor *ratelimit.Governor
+	// Lockout locks a client_id or source IP after repeated client
+	// authentication failures on /oauth/token. Optional (nil disables it).
+	Lockout *lockout.Guard
+	// NetworkPolicy applies OAuth client and service account CIDR
+	// allowlists to the caller's address. Optional (nil applies none).
+	NetworkPolicy *netpolicy.Enforcer
+}
+
+// clientNetworkAllowed applies c's CIDR allowlist to the caller's address;
+// rejections are audited by the enforcer.
+func (s *State) clientNetworkAllowed(r *http.Request, c *auth.OAuthClient) bool {
+	return s.NetworkPolicy.AllowClient(r.Context(), c.ID, c.ClientID, c.AllowedCIDRs, s.NetworkPolicy.ClientIP(r))
+}
+
+// lockoutIP is the caller's per-IP lockout subject, resolved through the
+// trusted proxies so a forged X-Forwarded-For can't move it.
+func (s *State) lockoutIP(r *http.Request) lockout.Subject {
+	return lockout.IP(s.NetworkPolicy.ClientIP(r))
+}
+
+// clientAuthFailed counts a failed client authentication towards the
+// client_id's and the caller IP's lockout. No-op without a Lockout guard.
+func (s *State) clientAuthFailed(r *http.Request, clientID string) {
+	if s.Lockout != nil {
+		s.Lockout.Failure(r.Context(), lockout.Client(clientID), s.lockoutIP(r))
+	}
+}
+
+// clientAuthSucceeded resets the client_id's failure count.
+func (s *State) clientAuthSucceeded(r *http.Request, clientID string) {
+	if s.Lockout != nil {
+		s.Lockout.Success(r.Context(), lockout.Client(clientID))
+	}
 }
 
 // recordAttempt best-effort logs a login attempt; failures are swallowed
@@ -123,6 +161,7 @@ type tokenRequest struct {
 	CodeVerifier string
 	RefreshToken string
 	Scope        string
+	DeviceCode   string
 }
 
 func parseTokenRequest(r *http.Request) (tokenRequest, error) {
@@ -138,6 +177,7 @@ func parseTokenRequest(r *http.Request) (tokenRequest, error) {
 		CodeVerifier: r.PostFormValue("code_verifier"),
 		RefreshToken: r.PostFormValue("refresh_token"),
 		Scope:        r.PostFormValue("scope"),
+		DeviceCode:   r.PostFormValue("device_code"),
 	}, nil
 }
 
@@ -179,6 +219,19 @@ func (s *State) Token(w http.ResponseWriter, r *http.Request) {
 		}
 	}
 
+	// Lockout after repeated bad client credentials, keyed on the client_id
+	// however it was presented and on the caller's IP.
+	if s.Lockout != nil {
+		clientID := req.ClientID
+		if id, _, ok := basicAuthCreds(r); ok {
+			clientID = id
+		}
+		if d := s.Lockout.Check(r.Context(), lockout.Client(clientID), s.lockoutIP(r)); !d.Allowed {
+			writeOAuthRateLimited(w, d.RetryAfterSecs, "too many failed client authentications; try again later")
+			return
+		}
+	}
+
 	// Authenticate the client up-front for code/refresh grants;
 	// client_credentials does its own auth (including the CONFIDENTIAL check).
 	var authenticatedClient *auth.OAuthClient
@@ -218,6 +271,8 @@ func (s *State) Token(w http.ResponseWriter, r *http.Request) {
 		s.handleRefreshTokenGrant(w, r, req, authenticatedClient)
 	case "client_credentials":
 		s.handleClientCredentialsGrant(w, r, req)
+	case DeviceCodeGrantType:
+		s.handleDeviceCodeGrant(w, r, req, authenticatedClient)
 	default:
 		writeOAuthError(w, http.StatusBadRequest, "unsupported_grant_type",
 			"Grant type '"+req.GrantType+"' is not supported")
@@ -244,6 +299,7 @@ func (s *State) authenticateClient(r *http.Request, clientIDBody, clientSecretBo
 		return nil, newOAuthError(http.StatusInternalServerError, "server_error", "")
 	}
 	if client == nil {
+		s.clientAuthFailed(r, clientID)
 		return nil, newOAuthError(http.StatusUnauthorized, "invalid_client", "Unknown client")
 	}
 	if !client.Active {
@@ -256,6 +312,9 @@ func (s *State) authenticateClient(r *http.Request, clientIDBody, clientSecretBo
 			return nil, newOAuthError(http.StatusUnauthorized, "invalid_client",
 				"Public clients must not provide a client_secret")
 		}
+		if !s.clientNetworkAllowed(r, client) {
+			return nil, errNetworkDenied()
+		}
 		return client, nil
 	}
 
@@ -266,11 +325,24 @@ func (s *State) authenticateClient(r *http.Request, clientIDBody, clientSecretBo
 			"Client secret required for confidential clients")
 	}
 	if !s.verifyClientSecret(*client.SecretRef, clientSecret) {
+		s.clientAuthFailed(r, clientID)
 		return nil, newOAuthError(http.StatusUnauthorized, "invalid_client", "Invalid client credentials")
 	}
+	s.clientAuthSucceeded(r, clientID)
+	if !s.clientNetworkAllowed(r, client) {
+		return nil, errNetworkDenied()
+	}
 	return client, nil
 }
 
+// errNetworkDenied is the rejection for credentials presented from outside
+// the client's or service account's allowlist. Sent after authentication
+// succeeds, so it doesn't help an attacker guess secrets.
+func errNetworkDenied() *oauthError {
+	return newOAuthError(http.StatusForbidden, "access_denied",
+		"Client credentials may not be used from this network")
+}
+
 // verifyClientSecret decrypts the stored ref and compares it to the
 // provided secret in constant time (a naive == short-circuits on the first
 // differing byte, leaking prefix length to a timing observer). Fails closed
@@ -346,6 +418,7 @@ func (s *State) handleClientCredentialsGrant(w http.ResponseWriter, r *http.Requ
 			s.handleDeveloperCredentialGrant(w, r, req)
 			return
 		}
+		s.clientAuthFailed(r, req.ClientID)
 		writeOAuthError(w, http.StatusUnauthorized, "invalid_client", "Invalid client credentials")
 		return
 	}
@@ -372,9 +445,15 @@ func (s *State) handleClientCredentialsGrant(w http.ResponseWriter, r *http.Requ
 	if !s.verifyClientSecret(*client.SecretRef, req.ClientSecret) {
 		reason := "Invalid client secret"
 		s.recordAttempt(r.Context(), loginattempt.AttemptServiceAccountToken, loginattempt.OutcomeFailure, req.ClientID, nil, &reason)
+		s.clientAuthFailed(r, req.ClientID)
 		writeOAuthError(w, http.StatusUnauthorized, "invalid_client", "Invalid client credentials")
 		return
 	}
+	s.clientAuthSucceeded(r, req.ClientID)
+	if !s.clientNetworkAllowed(r, client) {
+		errNetworkDenied().write(w)
+		return
+	}
 
 	if client.PrincipalID == nil {
 		writeOAuthError(w, http.StatusInternalServerError, "server_error", "Client not properly configured")
@@ -393,6 +472,10 @@ func (s *State) handleClientCredentialsGrant(w http.ResponseWriter, r *http.Requ
 		writeOAuthError(w, http.StatusUnauthorized, "invalid_client", "Service account is not active")
 		return
 	}
+	if !s.NetworkPolicy.AllowPrincipal(r.Context(), p.ID, s.NetworkPolicy.ClientIP(r)) {
+		errNetworkDenied().write(w)
+		return
+	}
 
 	s.mintClientCredentialsToken(w, r, p, req, loginattempt.AttemptServiceAccountToken,
 		"the service account's granted permissions")
@@ -436,9 +519,11 @@ func (s *State) handleDeveloperCredentialGrant(w http.ResponseWriter, r *http.Re
 	if !s.verifyClientSecret(*p.UserIdentity.DevClientSecretRef, req.ClientSecret) {
 		reason := "Invalid developer client secret"
 		s.recordAttempt(r.Context(), loginattempt.AttemptDeveloperToken, loginattempt.OutcomeFailure, req.ClientID, &p.ID, &reason)
+		s.clientAuthFailed(r, req.ClientID)
 		writeOAuthError(w, http.StatusUnauthorized, "invalid_client", "Invalid client credentials")
 		return
 	}
+	s.clientAuthSucceeded(r, req.ClientID)
 
 	s.mintClientCredentialsToken(w, r, p, req, loginattempt.AttemptDeveloperToken,
 		"your granted permissions")
@@ -542,7 +627,18 @@ func (s *State) handleAuthorizationCodeGrant(w http.ResponseWriter, r *http.Requ
 	if code.Scope != nil {
 		scope = *code.Scope
 	}
+	s.issueDelegatedTokens(w, r, p, code.ClientID, client, scope, code.Nonce)
+}
 
+// issueDelegatedTokens is the mint + response tail shared by the grants that
+// redeem a user's delegated consent (authorization_code and device_code): an
+// access token narrowed to the consented scope, an ID token when openid was
+// requested, and a fresh refresh-token family when offline_access was.
+func (s *State) issueDelegatedTokens(
+	w http.ResponseWriter, r *http.Request,
+	p *principal.Principal, clientID string, client *auth.OAuthClient,
+	scope string, nonce *string,
+) {
 	// Narrow the token's permission scope to the ceiling ∩ the scope captured
 	// at authorize time. Interactive logins commonly request only OIDC scopes
 	// (openid/profile/…), which leaves the permission request empty → the
@@ -560,7 +656,7 @@ func (s *State) handleAuthorizationCodeGrant(w http.ResponseWriter, r *http.Requ
 
 	var idToken *string
 	if scopeHas(scope, "openid") {
-		t, err := s.mintIDToken(r.Context(), p, code.ClientID, client, code.Nonce)
+		t, err := s.mintIDToken(r.Context(), p, clientID, client, nonce)
 		if err != nil {
 			writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
 			return
@@ -575,7 +671,7 @@ func (s *State) handleAuthorizationCodeGrant(w http.ResponseWriter, r *http.Requ
 			writeOAuthError(w, http.StatusInternalServerError, "server_error", "")
 			return
 		}
-		cid := code.ClientID
+		cid := clientID
 		entity.OAuthClientID = &cid
 		entity.Scopes = strings.Fields(scope)
 		// Root a rotation family on this first token so every later rotation
@@ -596,7 +692,7 @@ func (s *State) handleAuthorizationCodeGrant(w http.ResponseWriter, r *http.Requ
 		ExpiresIn:    3600,
 		RefreshToken: refreshToken,
 		IDToken:      idToken,
-		Scope:        code.Scope,
+		Scope:        strPtrOrNil(scope),
 	})
 }
 
@@ -0,0 +1,68 @@
+package oauthapi
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/netpolicy"
+)
+
+func tokenRequestFrom(peer, forwardedFor string) *http.Request {
+	r := httptest.NewRequest(http.MethodPost, "/oauth/token", nil)
+	r.RemoteAddr = peer + ":40000"
+	if forwardedFor != "" {
+		r.Header.Set("X-Forwarded-For", forwardedFor)
+	}
+	return r
+}
+
+// TestLockoutIP_SpoofedForwardedForIgnored: from an untrusted peer the
+// lockout subject is the peer, whatever X-Forwarded-For claims, so rotating
+// the header doesn't dodge the IP lock and forging one locks nobody else.
+func TestLockoutIP_SpoofedForwardedForIgnored(t *testing.T) {
+	ctx := context.Background()
+	proxies, err := netpolicy.ParseProxies("10.0.0.1")
+	if err != nil {
+		t.Fatal(err)
+	}
+	s := &State{
+		Lockout: lockout.New(lockout.Config{
+			Store: lockout.NewMemoryStore(),
+			Policy: lockout.Policy{
+				Threshold:   10,
+				IPThreshold: 3,
+				Window:      time.Minute,
+				BaseLock:    time.Minute,
+				MaxLock:     time.Hour,
+				LevelTTL:    time.Hour,
+			},
+		}),
+		NetworkPolicy: netpolicy.New(netpolicy.Config{Proxies: proxies}),
+	}
+
+	const attacker, victim = "203.0.113.7", "198.51.100.20"
+	for i := 0; i < 3; i++ {
+		r := tokenRequestFrom(attacker, fmt.Sprintf("198.51.100.%d, %s", i, victim))
+		if got := s.lockoutIP(r).Key; got != attacker {
+			t.Fatalf("lockout subject = %q, want the peer %q", got, attacker)
+		}
+		s.clientAuthFailed(r, fmt.Sprintf("oac_spoof_%d", i))
+	}
+
+	if d := s.Lockout.Check(ctx, s.lockoutIP(tokenRequestFrom(attacker, "192.0.2.1"))); d.Allowed {
+		t.Error("rotating X-Forwarded-For dodged the IP lock")
+	}
+	if d := s.Lockout.Check(ctx, s.lockoutIP(tokenRequestFrom(victim, ""))); !d.Allowed {
+		t.Error("a forged X-Forwarded-For locked out the victim's address")
+	}
+
+	// Behind a trusted proxy the forwarded address is the subject.
+	if got := s.lockoutIP(tokenRequestFrom("10.0.0.1", victim)).Key; got != victim {
+		t.Errorf("behind a trusted proxy: subject = %q, want %q", got, victim)
+	}
+}
@@ -274,6 +274,26 @@ func (e AnchorDomainDeleted) ToDataJSON() ([]byte, error) {
 
 // ── AuthConfig events ─────────────────────────────────────────────────────
 
+// NewAuthConfigCreatedEvent / NewAuthConfigUpdatedEvent build the canonical
+// events with their subject. Exported for cross-aggregate orchestrations (the
+// configuration bundle import) that write auth configs inside their own
+// transaction.
+func NewAuthConfigCreatedEvent(ec usecase.ExecutionContext, authConfigID, emailDomain string) AuthConfigCreated {
+	return AuthConfigCreated{
+		Metadata:     usecase.NewEventMetadata(ec, AuthConfigCreatedType, Source, configSubject(authConfigID)),
+		AuthConfigID: authConfigID,
+		EmailDomain:  emailDomain,
+	}
+}
+
+func NewAuthConfigUpdatedEvent(ec usecase.ExecutionContext, authConfigID, emailDomain string) AuthConfigUpdated {
+	return AuthConfigUpdated{
+		Metadata:     usecase.NewEventMetadata(ec, AuthConfigUpdatedType, Source, configSubject(authConfigID)),
+		AuthConfigID: authConfigID,
+		EmailDomain:  emailDomain,
+	}
+}
+
 type AuthConfigCreated struct {
 	Metadata     usecase.EventMetadata
 	AuthConfigID string
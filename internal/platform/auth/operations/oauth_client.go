@@ -10,6 +10,7 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/netpolicy"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
@@ -26,6 +27,7 @@ type CreateOAuthClientCommand struct {
 	GrantTypes             []string `json:"grantTypes,omitempty"`
 	Scopes                 []string `json:"scopes,omitempty"`
 	AllowedOrigins         []string `json:"allowedOrigins,omitempty"`
+	AllowedCIDRs           []string `json:"allowedCidrs,omitempty"`
 	ApplicationIDs         []string `json:"applicationIds,omitempty"`
 	PrincipalID            *string  `json:"principalId,omitempty"`
 	PKCERequired           *bool    `json:"pkceRequired,omitempty"`
@@ -69,6 +71,9 @@ func CreateOAuthClient(repo *auth.OAuthClientRepo) usecaseop.Operation[CreateOAu
 			c.GrantTypes = cmd.GrantTypes
 			c.Scopes = cmd.Scopes
 			c.AllowedOrigins = cmd.AllowedOrigins
+			if c.AllowedCIDRs, err = allowedCIDRs(cmd.AllowedCIDRs); err != nil {
+				return nil, err
+			}
 			c.ApplicationIDs = cmd.ApplicationIDs
 			c.PrincipalID = cmd.PrincipalID
 			if cmd.PKCERequired != nil {
@@ -104,6 +109,7 @@ type UpdateOAuthClientCommand struct {
 	GrantTypes             []string `json:"grantTypes,omitempty"`
 	Scopes                 []string `json:"scopes,omitempty"`
 	AllowedOrigins         []string `json:"allowedOrigins,omitempty"`
+	AllowedCIDRs           []string `json:"allowedCidrs,omitempty"`
 	ApplicationIDs         []string `json:"applicationIds,omitempty"`
 	PKCERequired           *bool    `json:"pkceRequired,omitempty"`
 }
@@ -149,6 +155,11 @@ func UpdateOAuthClient(repo *auth.OAuthClientRepo) usecaseop.Operation[UpdateOAu
 			if cmd.AllowedOrigins != nil {
 				c.AllowedOrigins = cmd.AllowedOrigins
 			}
+			if cmd.AllowedCIDRs != nil {
+				if c.AllowedCIDRs, err = allowedCIDRs(cmd.AllowedCIDRs); err != nil {
+					return nil, err
+				}
+			}
 			if cmd.ApplicationIDs != nil {
 				c.ApplicationIDs = cmd.ApplicationIDs
 			}
@@ -166,6 +177,15 @@ func UpdateOAuthClient(repo *auth.OAuthClientRepo) usecaseop.Operation[UpdateOAu
 	}
 }
 
+// allowedCIDRs canonicalises a CIDR allowlist, rejecting malformed entries.
+func allowedCIDRs(in []string) ([]string, error) {
+	out, err := netpolicy.NormalizeCIDRs(in)
+	if err != nil {
+		return nil, usecase.Validation("INVALID_CIDR", err.Error())
+	}
+	return out, nil
+}
+
 // ── Activate ──────────────────────────────────────────────────────────────
 
 type ActivateOAuthClientCommand struct {
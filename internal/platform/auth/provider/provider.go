@@ -20,8 +20,11 @@ import (
 	"encoding/pem"
 	"errors"
 	"fmt"
+	"sync/atomic"
 	"time"
 
+	"github.com/golang-jwt/jwt/v5"
+
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/sessiontoken"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/role"
@@ -45,6 +48,12 @@ type Config struct {
 
 	// SigningKey is the RS256 private key used to sign JWTs. PEM-encoded.
 	SigningKey []byte
+
+	// VerificationKeys are additional PEM public keys accepted (never used
+	// to sign) on session/bearer validation: the previous key during a
+	// rotation, plus the keyring's staged and retired keys. Tokens signed
+	// by any of them keep verifying until they expire.
+	VerificationKeys [][]byte
 }
 
 // Claims is the FlowCatalyst-specific JWT payload. These fields are
@@ -224,37 +233,60 @@ type Provider struct {
 	cfg        Config
 	principals *principal.Repository
 	roles      *role.Repository
-	// signingKey is the RSA key shared with the sessiontoken package (for
+	// keys is swapped whole by SetKeys (keyring refresh).
+	keys atomic.Pointer[providerKeys]
+}
+
+// providerKeys is the provider's key material.
+type providerKeys struct {
+	// signing is the RSA key shared with the sessiontoken package (for
 	// /auth/login cookies) and authservice (for /oauth/token JWTs) — all
 	// three sign with the same pair so JWKS + cookie validation line up.
-	signingKey *rsa.PrivateKey
+	signing *rsa.PrivateKey
+	// verify are the validation-only keys from Config.VerificationKeys.
+	verify []*rsa.PublicKey
 }
 
 // NewProvider parses the RSA signing key and wires the claims/session
 // helpers. Returns an error if the RSA key is missing or malformed.
 func NewProvider(cfg Config, principals *principal.Repository, roles *role.Repository) (*Provider, error) {
-	key, err := parseRSAPrivateKey(cfg.SigningKey)
-	if err != nil {
-		return nil, fmt.Errorf("auth provider: %w", err)
-	}
 	if cfg.AccessTokenTTL == 0 {
 		cfg.AccessTokenTTL = 1 * time.Hour
 	}
 	if cfg.Audience == "" {
 		cfg.Audience = cfg.Issuer
 	}
-	return &Provider{
-		cfg:        cfg,
-		signingKey: key,
-		principals: principals,
-		roles:      roles,
-	}, nil
+	p := &Provider{cfg: cfg, principals: principals, roles: roles}
+	if err := p.SetKeys(cfg.SigningKey, cfg.VerificationKeys); err != nil {
+		return nil, err
+	}
+	return p, nil
+}
+
+// SetKeys replaces the signing key and validation-only keys (PEM) — at
+// construction, and at runtime when the keyring refresh picks up a
+// rotation.
+func (p *Provider) SetKeys(signingKey []byte, verificationKeys [][]byte) error {
+	key, err := parseRSAPrivateKey(signingKey)
+	if err != nil {
+		return fmt.Errorf("auth provider: %w", err)
+	}
+	verify := make([]*rsa.PublicKey, 0, len(verificationKeys))
+	for i, raw := range verificationKeys {
+		pub, err := parseRSAPublicKey(raw)
+		if err != nil {
+			return fmt.Errorf("auth provider: verification key %d: %w", i, err)
+		}
+		verify = append(verify, pub)
+	}
+	p.keys.Store(&providerKeys{signing: key, verify: verify})
+	return nil
 }
 
-// SigningKey exposes the RSA private key the provider was constructed
-// with. Used by sessiontoken-aware callers (the auth middleware, the
-// /auth/login handler) to share the same key pair token issuance uses.
-func (p *Provider) SigningKey() *rsa.PrivateKey { return p.signingKey }
+// SigningKey exposes the provider's current RSA private key. Used by
+// sessiontoken-aware callers (the auth middleware, the /auth/login
+// handler) to share the same key pair token issuance uses.
+func (p *Provider) SigningKey() *rsa.PrivateKey { return p.keys.Load().signing }
 
 // Issuer returns the configured JWT issuer claim.
 func (p *Provider) Issuer() string { return p.cfg.Issuer }
@@ -297,7 +329,24 @@ func (p *Provider) MintSessionToken(ctx context.Context, principalID string, ttl
 	return sessiontoken.Mint(sessiontoken.Claims{
 		Subject: c.Subject,
 		Email:   c.Email,
-	}, p.signingKey, p.cfg.Issuer, ttl)
+	}, p.SigningKey(), p.cfg.Issuer, ttl)
+}
+
+// MintPasskeyEnrollmentToken issues a session token that only lets its
+// holder register a passkey (sessiontoken.PurposePasskeyEnrollment): the
+// auth middleware authenticates it for the registration endpoints and
+// nothing else. Used by /auth/login for an admin whose domain requires
+// passkeys and who has yet to enroll one.
+func (p *Provider) MintPasskeyEnrollmentToken(ctx context.Context, principalID string, ttl time.Duration) (string, error) {
+	c, err := BuildClaims(ctx, p.cfg, p.principals, p.roles, principalID)
+	if err != nil {
+		return "", fmt.Errorf("build claims: %w", err)
+	}
+	return sessiontoken.Mint(sessiontoken.Claims{
+		Subject: c.Subject,
+		Email:   c.Email,
+		Purpose: sessiontoken.PurposePasskeyEnrollment,
+	}, p.SigningKey(), p.cfg.Issuer, ttl)
 }
 
 // ValidateSessionToken verifies a session-cookie JWT (signature + std
@@ -308,11 +357,25 @@ func (p *Provider) MintSessionToken(ctx context.Context, principalID string, ttl
 // authservice for /oauth/token), so the signature path lines up — which is
 // exactly why the audience expectation matters: OIDC ID tokens minted for
 // third-party RPs share that key too and must not validate here.
+//
+// The current key is tried first, then each validation-only key; the
+// current key's error is returned when none verifies.
 func (p *Provider) ValidateSessionToken(_ context.Context, token string) (*sessiontoken.Claims, error) {
-	return sessiontoken.Validate(token, &p.signingKey.PublicKey, sessiontoken.Expect{
+	expect := sessiontoken.Expect{
 		Issuer:   p.cfg.Issuer,
 		Audience: p.cfg.Audience,
-	})
+	}
+	keys := p.keys.Load()
+	c, err := sessiontoken.Validate(token, &keys.signing.PublicKey, expect)
+	if err == nil || !errors.Is(err, jwt.ErrTokenSignatureInvalid) {
+		return c, err
+	}
+	for _, k := range keys.verify {
+		if c, verr := sessiontoken.Validate(token, k, expect); verr == nil {
+			return c, nil
+		}
+	}
+	return nil, err
 }
 
 // parseRSAPrivateKey accepts PKCS#1 or PKCS#8 PEM blocks.
@@ -337,3 +400,21 @@ func parseRSAPrivateKey(pemBytes []byte) (*rsa.PrivateKey, error) {
 	}
 	return rsaKey, nil
 }
+
+// parseRSAPublicKey accepts PKIX or PKCS#1 PEM blocks.
+func parseRSAPublicKey(pemBytes []byte) (*rsa.PublicKey, error) {
+	block, _ := pem.Decode(pemBytes)
+	if block == nil {
+		return nil, errors.New("no PEM block found")
+	}
+	if pub, err := x509.ParsePKIXPublicKey(block.Bytes); err == nil {
+		if rsaPub, ok := pub.(*rsa.PublicKey); ok {
+			return rsaPub, nil
+		}
+		return nil, errors.New("public key is not RSA")
+	}
+	if rsaPub, err := x509.ParsePKCS1PublicKey(block.Bytes); err == nil {
+		return rsaPub, nil
+	}
+	return nil, errors.New("unparseable RSA public key")
+}
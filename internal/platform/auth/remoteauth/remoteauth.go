@@ -0,0 +1,408 @@
+// Package remoteauth validates bearer tokens minted by external identity
+// providers — the platform's "remote" auth mode (FC_AUTH_MODE=remote).
+// Each trusted issuer publishes its signing keys as a JWKS; tokens are
+// verified against that set, checked against the issuer's accepted
+// audiences (with a clock-skew allowance on exp/nbf/iat), and their role
+// claim is mapped onto platform role names so the middleware can flatten
+// them into the Permission model exactly like a locally-minted token.
+//
+// Key sets are cached per issuer and refreshed on a fixed interval. A token
+// whose kid is not in the cached set forces an early re-fetch (the IdP
+// rotated), rate-limited by MinRefreshInterval so a flood of tokens with a
+// bogus kid cannot hammer the IdP.
+package remoteauth
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"slices"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/lestrrat-go/jwx/v2/jwa"
+	"github.com/lestrrat-go/jwx/v2/jwk"
+	"github.com/lestrrat-go/jwx/v2/jws"
+	"github.com/lestrrat-go/jwx/v2/jwt"
+)
+
+// Defaults applied by New when the corresponding Config field is zero.
+const (
+	DefaultClockSkew          = 60 * time.Second
+	DefaultRefreshInterval    = time.Hour
+	DefaultMinRefreshInterval = 30 * time.Second
+	defaultRoleClaim          = "roles"
+)
+
+// ErrUntrustedIssuer is returned for tokens whose iss is not configured.
+var ErrUntrustedIssuer = errors.New("token issuer is not trusted")
+
+// Issuer configures one trusted external identity provider. The JSON
+// shape is what FC_AUTH_REMOTE_ISSUERS carries.
+type Issuer struct {
+	// Issuer is the exact iss claim value. Required.
+	Issuer string `json:"issuer"`
+	// JWKSURI is the key-set endpoint. When empty it is discovered from
+	// <issuer>/.well-known/openid-configuration on first use.
+	JWKSURI string `json:"jwksUri,omitempty"`
+	// Audiences lists the accepted aud values; a token must carry at least
+	// one of them. Required — an issuer with no audience check would accept
+	// tokens minted for any other relying party.
+	Audiences []string `json:"audiences"`
+	// RoleClaim is the claim holding the caller's roles, as a dotted path
+	// into nested objects (e.g. "realm_access.roles" for Keycloak). Defaults
+	// to "roles". A single string is treated as a one-role list.
+	RoleClaim string `json:"roleClaim,omitempty"`
+	// RoleMapping maps an external role to the platform role names it
+	// grants. External roles without an entry grant nothing.
+	RoleMapping map[string][]string `json:"roleMapping,omitempty"`
+	// Scope is the platform scope (ANCHOR | PARTNER | CLIENT) assigned to
+	// this issuer's callers. Defaults to CLIENT.
+	Scope string `json:"scope,omitempty"`
+	// ClientsClaim optionally names a claim listing the client (tenant) IDs
+	// the caller may access. Clients is the static fallback when the claim
+	// is unset or absent from the token.
+	ClientsClaim string   `json:"clientsClaim,omitempty"`
+	Clients      []string `json:"clients,omitempty"`
+	// AllApplications grants this issuer's callers every application; when
+	// false they reach none (remote callers have no application bindings).
+	AllApplications bool `json:"allApplications,omitempty"`
+}
+
+// Config configures a Validator.
+type Config struct {
+	Issuers []Issuer
+	// ClockSkew is the tolerance applied to exp/nbf/iat. Defaults to 60s.
+	ClockSkew time.Duration
+	// RefreshInterval is the maximum age of a cached key set. Defaults to 1h.
+	RefreshInterval time.Duration
+	// MinRefreshInterval is the minimum gap between fetches for one issuer,
+	// bounding unknown-kid refreshes. Defaults to 30s.
+	MinRefreshInterval time.Duration
+	// HTTPClient fetches discovery documents and key sets. Defaults to a
+	// 10-second-timeout client.
+	HTTPClient *http.Client
+}
+
+// Identity is the verified, mapped view of an external token.
+type Identity struct {
+	Issuer  string
+	Subject string
+	Email   string
+	Scope   string
+	Clients []string
+	// Roles are platform role names, already mapped from the issuer's
+	// role claim and de-duplicated.
+	Roles           []string
+	AllApplications bool
+}
+
+// Validator verifies tokens from the configured issuers.
+type Validator struct {
+	cfg     Config
+	issuers map[string]*issuerState
+	now     func() time.Time
+}
+
+type issuerState struct {
+	cfg Issuer
+
+	mu        sync.Mutex
+	jwksURI   string
+	set       jwk.Set
+	fetchedAt time.Time
+}
+
+// New validates cfg and builds a Validator. No network calls are made
+// until the first token from an issuer arrives.
+func New(cfg Config) (*Validator, error) {
+	if len(cfg.Issuers) == 0 {
+		return nil, errors.New("remoteauth: at least one trusted issuer is required")
+	}
+	if cfg.ClockSkew == 0 {
+		cfg.ClockSkew = DefaultClockSkew
+	}
+	if cfg.RefreshInterval == 0 {
+		cfg.RefreshInterval = DefaultRefreshInterval
+	}
+	if cfg.MinRefreshInterval == 0 {
+		cfg.MinRefreshInterval = DefaultMinRefreshInterval
+	}
+	if cfg.HTTPClient == nil {
+		cfg.HTTPClient = &http.Client{Timeout: 10 * time.Second}
+	}
+	v := &Validator{cfg: cfg, issuers: make(map[string]*issuerState, len(cfg.Issuers)), now: time.Now}
+	for _, iss := range cfg.Issuers {
+		if iss.Issuer == "" {
+			return nil, errors.New("remoteauth: issuer is required")
+		}
+		if len(iss.Audiences) == 0 {
+			return nil, fmt.Errorf("remoteauth: issuer %q has no audiences", iss.Issuer)
+		}
+		if _, dup := v.issuers[iss.Issuer]; dup {
+			return nil, fmt.Errorf("remoteauth: issuer %q configured twice", iss.Issuer)
+		}
+		if iss.RoleClaim == "" {
+			iss.RoleClaim = defaultRoleClaim
+		}
+		if iss.Scope == "" {
+			iss.Scope = "CLIENT"
+		}
+		v.issuers[iss.Issuer] = &issuerState{cfg: iss, jwksURI: iss.JWKSURI}
+	}
+	return v, nil
+}
+
+// ParseIssuers decodes the FC_AUTH_REMOTE_ISSUERS JSON array.
+func ParseIssuers(raw string) ([]Issuer, error) {
+	var out []Issuer
+	if err := json.Unmarshal([]byte(raw), &out); err != nil {
+		return nil, fmt.Errorf("parse trusted issuers: %w", err)
+	}
+	return out, nil
+}
+
+// Handles reports whether the token's (unverified) iss belongs to a trusted
+// remote issuer — the middleware's routing decision between this validator
+// and the platform's own keys. It never verifies anything.
+func (v *Validator) Handles(token string) bool {
+	iss, err := unverifiedIssuer(token)
+	if err != nil {
+		return false
+	}
+	_, ok := v.issuers[iss]
+	return ok
+}
+
+// Validate verifies the token's signature against its issuer's JWKS, then
+// iss, aud, and the time claims, and maps the result onto an Identity.
+func (v *Validator) Validate(ctx context.Context, token string) (*Identity, error) {
+	iss, err := unverifiedIssuer(token)
+	if err != nil {
+		return nil, err
+	}
+	st, ok := v.issuers[iss]
+	if !ok {
+		return nil, ErrUntrustedIssuer
+	}
+	msg, err := jws.ParseString(token)
+	if err != nil {
+		return nil, fmt.Errorf("parse token: %w", err)
+	}
+	if len(msg.Signatures()) != 1 {
+		return nil, errors.New("token must carry exactly one signature")
+	}
+	hdr := msg.Signatures()[0].ProtectedHeaders()
+	if !allowedAlgorithm(hdr.Algorithm()) {
+		return nil, fmt.Errorf("unsupported signing algorithm %q", hdr.Algorithm())
+	}
+
+	key, err := v.keyFor(ctx, st, hdr.KeyID())
+	if err != nil {
+		return nil, err
+	}
+	tok, err := jwt.ParseString(token,
+		jwt.WithKey(hdr.Algorithm(), key),
+		jwt.WithIssuer(iss),
+		jwt.WithAcceptableSkew(v.cfg.ClockSkew),
+		jwt.WithClock(jwt.ClockFunc(v.now)),
+		jwt.WithValidate(true),
+	)
+	if err != nil {
+		return nil, fmt.Errorf("invalid token: %w", err)
+	}
+	if !slices.ContainsFunc(tok.Audience(), func(a string) bool { return slices.Contains(st.cfg.Audiences, a) }) {
+		return nil, errors.New("invalid token: audience not accepted")
+	}
+	if tok.Subject() == "" {
+		return nil, errors.New("invalid token: missing sub")
+	}
+
+	claims, err := tok.AsMap(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("read claims: %w", err)
+	}
+	id := &Identity{
+		Issuer:  iss,
+		Subject: tok.Subject(),
+		Scope:   st.cfg.Scope,
+		Roles:   st.cfg.mapRoles(stringList(claimAt(claims, st.cfg.RoleClaim))),
+		Clients: st.cfg.Clients,
+
+		AllApplications: st.cfg.AllApplications,
+	}
+	if email, ok := claims["email"].(string); ok {
+		id.Email = email
+	}
+	if st.cfg.ClientsClaim != "" {
+		if c := stringList(claimAt(claims, st.cfg.ClientsClaim)); c != nil {
+			id.Clients = c
+		}
+	}
+	return id, nil
+}
+
+// keyFor returns the verification key for kid, refreshing the cached set
+// when it is stale or doesn't know the kid. An empty kid is accepted only
+// when the issuer publishes exactly one key.
+func (v *Validator) keyFor(ctx context.Context, st *issuerState, kid string) (jwk.Key, error) {
+	st.mu.Lock()
+	defer st.mu.Unlock()
+
+	now := v.now()
+	if st.set == nil || now.Sub(st.fetchedAt) >= v.cfg.RefreshInterval {
+		if err := v.fetch(ctx, st, now); err != nil {
+			return nil, err
+		}
+	}
+	if key, ok := lookup(st.set, kid); ok {
+		return key, nil
+	}
+	if now.Sub(st.fetchedAt) < v.cfg.MinRefreshInterval {
+		return nil, fmt.Errorf("no signing key %q for issuer %s", kid, st.cfg.Issuer)
+	}
+	if err := v.fetch(ctx, st, now); err != nil {
+		return nil, err
+	}
+	if key, ok := lookup(st.set, kid); ok {
+		return key, nil
+	}
+	return nil, fmt.Errorf("no signing key %q for issuer %s", kid, st.cfg.Issuer)
+}
+
+// fetch refreshes st.set. Callers hold st.mu. A failed fetch keeps the
+// previous set (and its age) so a brief IdP outage doesn't reject tokens
+// signed with keys we already know.
+func (v *Validator) fetch(ctx context.Context, st *issuerState, now time.Time) error {
+	if st.jwksURI == "" {
+		uri, err := v.discover(ctx, st.cfg.Issuer)
+		if err != nil {
+			return err
+		}
+		st.jwksURI = uri
+	}
+	set, err := jwk.Fetch(ctx, st.jwksURI, jwk.WithHTTPClient(v.cfg.HTTPClient))
+	if err != nil {
+		if st.set != nil {
+			return nil
+		}
+		return fmt.Errorf("fetch JWKS for %s: %w", st.cfg.Issuer, err)
+	}
+	st.set = set
+	st.fetchedAt = now
+	return nil
+}
+
+// discover reads jwks_uri from the issuer's OIDC discovery document.
+func (v *Validator) discover(ctx context.Context, issuer string) (string, error) {
+	url := strings.TrimRight(issuer, "/") + "/.well-known/openid-configuration"
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, url, nil)
+	if err != nil {
+		return "", err
+	}
+	req.Header.Set("Accept", "application/json")
+	resp, err := v.cfg.HTTPClient.Do(req)
+	if err != nil {
+		return "", fmt.Errorf("discover %s: %w", issuer, err)
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return "", fmt.Errorf("discover %s: HTTP %d", issuer, resp.StatusCode)
+	}
+	var doc struct {
+		JwksURI string `json:"jwks_uri"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&doc); err != nil {
+		return "", fmt.Errorf("discover %s: %w", issuer, err)
+	}
+	if doc.JwksURI == "" {
+		return "", fmt.Errorf("discover %s: no jwks_uri", issuer)
+	}
+	return doc.JwksURI, nil
+}
+
+func lookup(set jwk.Set, kid string) (jwk.Key, bool) {
+	if kid == "" {
+		if set.Len() != 1 {
+			return nil, false
+		}
+		return set.Key(0)
+	}
+	return set.LookupKeyID(kid)
+}
+
+// allowedAlgorithm restricts verification to asymmetric algorithms — a JWKS
+// only ever carries public keys, and accepting HS*/none would let a caller
+// pick the algorithm.
+func allowedAlgorithm(alg jwa.SignatureAlgorithm) bool {
+	switch alg {
+	case jwa.RS256, jwa.RS384, jwa.RS512,
+		jwa.PS256, jwa.PS384, jwa.PS512,
+		jwa.ES256, jwa.ES384, jwa.ES512, jwa.EdDSA:
+		return true
+	}
+	return false
+}
+
+// mapRoles translates external roles to platform role names, dropping
+// unmapped roles and duplicates.
+func (i Issuer) mapRoles(external []string) []string {
+	var out []string
+	for _, r := range external {
+		for _, mapped := range i.RoleMapping[r] {
+			if !slices.Contains(out, mapped) {
+				out = append(out, mapped)
+			}
+		}
+	}
+	return out
+}
+
+// unverifiedIssuer reads iss from the payload without checking the
+// signature. Only used to pick the issuer whose keys then verify it.
+func unverifiedIssuer(token string) (string, error) {
+	tok, err := jwt.ParseString(token, jwt.WithVerify(false), jwt.WithValidate(false))
+	if err != nil {
+		return "", fmt.Errorf("parse token: %w", err)
+	}
+	if tok.Issuer() == "" {
+		return "", errors.New("token has no iss claim")
+	}
+	return tok.Issuer(), nil
+}
+
+// claimAt walks a dotted path through nested claim objects.
+func claimAt(claims map[string]any, path string) any {
+	var cur any = claims
+	for _, part := range strings.Split(path, ".") {
+		m, ok := cur.(map[string]any)
+		if !ok {
+			return nil
+		}
+		cur = m[part]
+	}
+	return cur
+}
+
+// stringList coerces a claim into []string. A lone string is a one-element
+// list (some IdPs emit a single role that way); anything else is nil.
+func stringList(v any) []string {
+	switch x := v.(type) {
+	case string:
+		return []string{x}
+	case []string:
+		return append([]string(nil), x...)
+	case []any:
+		out := make([]string, 0, len(x))
+		for _, e := range x {
+			if s, ok := e.(string); ok {
+				out = append(out, s)
+			}
+		}
+		return out
+	}
+	return nil
+}
@@ -41,9 +41,10 @@ func NewRepository(pool *pgxpool.Pool) *Repository {
 // Schema: oauth_clients + oauth_client_redirect_uris +
 // oauth_client_grant_types + oauth_client_post_logout_redirect_uris (the
 // OIDC RP-Initiated Logout whitelist consulted by /auth/oidc/session/end) +
-// oauth_client_allowed_origins + oauth_client_application_ids. The
-// post-logout, allowed-origins, and application-ids junctions are
-// loaded/persisted via raw pgx (they aren't wired through sqlc).
+// oauth_client_allowed_origins + oauth_client_allowed_cidrs +
+// oauth_client_application_ids. The post-logout, allowed-origins,
+// allowed-cidrs and application-ids junctions are loaded/persisted via raw
+// pgx (they aren't wired through sqlc).
 // client_secret_ref holds the reversibly-encrypted client secret
 // (AES-256-GCM under FLOWCATALYST_APP_KEY, "encrypted:"-prefixed),
 // matching Rust; it is verified at /oauth/token by decrypt-and-compare,
@@ -156,6 +157,17 @@ func (r *OAuthClientRepo) Persist(ctx context.Context, c *OAuthClient, tx *useca
 			return fmt.Errorf("allowed_origin insert: %w", err)
 		}
 	}
+	if _, err := tx.Inner().Exec(ctx,
+		`DELETE FROM oauth_client_allowed_cidrs WHERE oauth_client_id = $1`, c.ID); err != nil {
+		return fmt.Errorf("allowed_cidrs clear: %w", err)
+	}
+	for _, n := range c.AllowedCIDRs {
+		if _, err := tx.Inner().Exec(ctx,
+			`INSERT INTO oauth_client_allowed_cidrs (oauth_client_id, allowed_cidr)
+			 VALUES ($1, $2) ON CONFLICT DO NOTHING`, c.ID, n); err != nil {
+			return fmt.Errorf("allowed_cidr insert: %w", err)
+		}
+	}
 	if _, err := tx.Inner().Exec(ctx,
 		`DELETE FROM oauth_client_application_ids WHERE oauth_client_id = $1`, c.ID); err != nil {
 		return fmt.Errorf("application_ids clear: %w", err)
@@ -188,6 +200,10 @@ func (r *OAuthClientRepo) Delete(ctx context.Context, c *OAuthClient, tx *usecas
 		`DELETE FROM oauth_client_allowed_origins WHERE oauth_client_id = $1`, c.ID); err != nil {
 		return err
 	}
+	if _, err := tx.Inner().Exec(ctx,
+		`DELETE FROM oauth_client_allowed_cidrs WHERE oauth_client_id = $1`, c.ID); err != nil {
+		return err
+	}
 	if _, err := tx.Inner().Exec(ctx,
 		`DELETE FROM oauth_client_application_ids WHERE oauth_client_id = $1`, c.ID); err != nil {
 		return err
@@ -242,12 +258,17 @@ func (r *OAuthClientRepo) hydrateAll(ctx context.Context, clients []OAuthClient)
 	if err := plRows.Err(); err != nil {
 		return nil, err
 	}
-	// Allowed origins + application ids — raw pgx (not wired through sqlc).
+	// Allowed origins, CIDRs + application ids — raw pgx (not wired through sqlc).
 	originsByID, err := r.loadClientStringJunction(ctx,
 		`SELECT oauth_client_id, allowed_origin FROM oauth_client_allowed_origins WHERE oauth_client_id = ANY($1)`, ids)
 	if err != nil {
 		return nil, fmt.Errorf("allowed_origins load: %w", err)
 	}
+	cidrsByID, err := r.loadClientStringJunction(ctx,
+		`SELECT oauth_client_id, allowed_cidr FROM oauth_client_allowed_cidrs WHERE oauth_client_id = ANY($1)`, ids)
+	if err != nil {
+		return nil, fmt.Errorf("allowed_cidrs load: %w", err)
+	}
 	appsByID, err := r.loadClientStringJunction(ctx,
 		`SELECT oauth_client_id, application_id FROM oauth_client_application_ids WHERE oauth_client_id = ANY($1)`, ids)
 	if err != nil {
@@ -266,6 +287,7 @@ func (r *OAuthClientRepo) hydrateAll(ctx context.Context, clients []OAuthClient)
 		clients[i].GrantTypes = grantsByID[clients[i].ID]
 		clients[i].PostLogoutRedirectURIs = plByID[clients[i].ID]
 		clients[i].AllowedOrigins = originsByID[clients[i].ID]
+		clients[i].AllowedCIDRs = cidrsByID[clients[i].ID]
 		clients[i].ApplicationIDs = appsByID[clients[i].ID]
 		if clients[i].RedirectURIs == nil {
 			clients[i].RedirectURIs = []string{}
@@ -279,6 +301,9 @@ func (r *OAuthClientRepo) hydrateAll(ctx context.Context, clients []OAuthClient)
 		if clients[i].AllowedOrigins == nil {
 			clients[i].AllowedOrigins = []string{}
 		}
+		if clients[i].AllowedCIDRs == nil {
+			clients[i].AllowedCIDRs = []string{}
+		}
 		if clients[i].ApplicationIDs == nil {
 			clients[i].ApplicationIDs = []string{}
 		}
@@ -288,7 +313,8 @@ func (r *OAuthClientRepo) hydrateAll(ctx context.Context, clients []OAuthClient)
 
 // loadClientStringJunction runs a (oauth_client_id, value) query against a
 // raw-pgx junction table and returns the values grouped by client id.
-// Shared by the allowed-origins and application-ids hydration paths.
+// Shared by the allowed-origins, allowed-cidrs and application-ids
+// hydration paths.
 func (r *OAuthClientRepo) loadClientStringJunction(ctx context.Context, query string, ids []string) (map[string][]string, error) {
 	rows, err := r.pool.Query(ctx, query, ids)
 	if err != nil {
@@ -323,6 +349,7 @@ func rowToOAuthClient(row dbq.OauthClient) *OAuthClient {
 		GrantTypes:             []string{},
 		Scopes:                 []string{},
 		AllowedOrigins:         []string{},
+		AllowedCIDRs:           []string{},
 		ApplicationIDs:         []string{},
 	}
 	if row.DefaultScopes != nil && *row.DefaultScopes != "" {
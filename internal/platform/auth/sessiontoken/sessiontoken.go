@@ -19,7 +19,8 @@
 //	  "email": "...",
 //	  "clients":     [...],
 //	  "roles":       [...],
-//	  "applications": [...]
+//	  "applications": [...],
+//	  "purpose":     "passkey_enrollment"   (restricted sessions only)
 //	}
 //
 // Same claim names + types the auth middleware reads, so session-cookie
@@ -39,6 +40,12 @@ import (
 	"github.com/golang-jwt/jwt/v5"
 )
 
+// PurposePasskeyEnrollment marks a session that may only register a
+// passkey: an admin whose domain requires passkeys signing in with a
+// password before holding one. The auth middleware authenticates it for
+// the registration endpoints alone.
+const PurposePasskeyEnrollment = "passkey_enrollment"
+
 // Claims is the payload sessiontoken mints + reads. Mirrors the
 // fields populated by provider.BuildClaims so callers don't have to
 // translate.
@@ -57,6 +64,9 @@ type Claims struct {
 	// IssuedAt is the token's `iat` (when it was minted ≈ login time).
 	// Zero if the token carried no iat. Used for OIDC max_age enforcement.
 	IssuedAt time.Time
+	// Purpose restricts what the session may do; empty is a full session.
+	// Rides the "purpose" claim. See PurposePasskeyEnrollment.
+	Purpose string
 }
 
 // Mint signs a JWT with the supplied claims using key. ttl == 0 mints a
@@ -100,6 +110,9 @@ func Mint(c Claims, key *rsa.PrivateKey, issuer string, ttl time.Duration) (stri
 	if len(c.Permissions) > 0 {
 		mc["scope"] = strings.Join(c.Permissions, " ")
 	}
+	if c.Purpose != "" {
+		mc["purpose"] = c.Purpose
+	}
 
 	tok := jwt.NewWithClaims(jwt.SigningMethodRS256, mc)
 	signed, err := tok.SignedString(key)
@@ -181,6 +194,7 @@ func Validate(token string, key *rsa.PublicKey, expect Expect) (*Claims, error)
 		// Granted permissions arrive on the space-delimited "scope" claim.
 		Permissions: strings.Fields(stringClaim(mc, "scope")),
 		IssuedAt:    unixClaim(mc, "iat"),
+		Purpose:     stringClaim(mc, "purpose"),
 	}
 	if out.Subject == "" {
 		return nil, errors.New("sessiontoken: token is missing sub claim")
@@ -63,6 +63,34 @@ func TestMintAndValidate_RoundTrip(t *testing.T) {
 	}
 }
 
+func TestMintAndValidate_Purpose(t *testing.T) {
+	key := mustKey(t)
+
+	full, err := sessiontoken.Mint(sessiontoken.Claims{Subject: "prn_abc"}, key, "iss", time.Hour)
+	if err != nil {
+		t.Fatalf("mint: %v", err)
+	}
+	out, err := sessiontoken.Validate(full, &key.PublicKey, sessiontoken.Expect{})
+	if err != nil {
+		t.Fatalf("validate: %v", err)
+	}
+	if out.Purpose != "" {
+		t.Errorf("full session Purpose=%q want empty", out.Purpose)
+	}
+
+	restricted, err := sessiontoken.Mint(sessiontoken.Claims{Subject: "prn_abc", Purpose: sessiontoken.PurposePasskeyEnrollment}, key, "iss", time.Hour)
+	if err != nil {
+		t.Fatalf("mint: %v", err)
+	}
+	out, err = sessiontoken.Validate(restricted, &key.PublicKey, sessiontoken.Expect{})
+	if err != nil {
+		t.Fatalf("validate: %v", err)
+	}
+	if out.Purpose != sessiontoken.PurposePasskeyEnrollment {
+		t.Errorf("Purpose=%q want %q", out.Purpose, sessiontoken.PurposePasskeyEnrollment)
+	}
+}
+
 func TestValidate_RejectsBadSignature(t *testing.T) {
 	k1 := mustKey(t)
 	k2 := mustKey(t)
@@ -12,22 +12,29 @@ import (
 type CreateClientRequest struct {
 	Name       string `json:"name"`
 	Identifier string `json:"identifier" doc:"URL-safe identifier (lowercase alphanumeric, hyphens)"`
+	Region     string `json:"region,omitempty" doc:"Data-residency region (e.g. eu); subscriptions must use a dispatch pool in the same region"`
+	// Require the client's admin-scope users to sign in with a passkey.
+	RequirePasskeyForAdmins bool `json:"requirePasskeyForAdmins,omitempty" doc:"The client's admin-scope users must sign in with a passkey; without one, password sign-in only allows registering one."`
 }
 
 func (r CreateClientRequest) toCommand() operations.CreateCommand {
 	return operations.CreateCommand{
-		Name:       r.Name,
-		Identifier: r.Identifier,
+		Name:                    r.Name,
+		Identifier:              r.Identifier,
+		Region:                  r.Region,
+		RequirePasskeyForAdmins: r.RequirePasskeyForAdmins,
 	}
 }
 
 // UpdateClientRequest is the wire body for PUT /api/clients/{id}.
 type UpdateClientRequest struct {
-	Name *string `json:"name,omitempty"`
+	Name                    *string `json:"name,omitempty"`
+	Region                  *string `json:"region,omitempty" doc:"Data-residency region; empty string clears it"`
+	RequirePasskeyForAdmins *bool   `json:"requirePasskeyForAdmins,omitempty"`
 }
 
 func (r UpdateClientRequest) toCommand(id string) operations.UpdateCommand {
-	return operations.UpdateCommand{ID: id, Name: r.Name}
+	return operations.UpdateCommand{ID: id, Name: r.Name, Region: r.Region, RequirePasskeyForAdmins: r.RequirePasskeyForAdmins}
 }
 
 // SuspendClientRequest is the wire body for POST /api/clients/{id}/suspend.
@@ -56,15 +63,17 @@ type NoteResponse struct {
 
 // ClientResponse mirrors client.Client.
 type ClientResponse struct {
-	ID              string           `json:"id"`
-	Name            string           `json:"name"`
-	Identifier      string           `json:"identifier"`
-	Status          string           `json:"status"`
-	StatusReason    *string          `json:"statusReason,omitempty"`
-	StatusChangedAt *httpcompat.Time `json:"statusChangedAt,omitempty"`
-	Notes           []NoteResponse   `json:"notes"`
-	CreatedAt       httpcompat.Time  `json:"createdAt"`
-	UpdatedAt       httpcompat.Time  `json:"updatedAt"`
+	ID                      string           `json:"id"`
+	Name                    string           `json:"name"`
+	Identifier              string           `json:"identifier"`
+	Status                  string           `json:"status"`
+	StatusReason            *string          `json:"statusReason,omitempty"`
+	StatusChangedAt         *httpcompat.Time `json:"statusChangedAt,omitempty"`
+	Notes                   []NoteResponse   `json:"notes"`
+	Region                  *string          `json:"region,omitempty"`
+	RequirePasskeyForAdmins bool             `json:"requirePasskeyForAdmins"`
+	CreatedAt               httpcompat.Time  `json:"createdAt"`
+	UpdatedAt               httpcompat.Time  `json:"updatedAt"`
 }
 
 func fromEntity(c *client.Client) ClientResponse {
@@ -83,15 +92,17 @@ func fromEntity(c *client.Client) ClientResponse {
 		statusChanged = &v
 	}
 	return ClientResponse{
-		ID:              c.ID,
-		Name:            c.Name,
-		Identifier:      c.Identifier,
-		Status:          string(c.Status),
-		StatusReason:    c.StatusReason,
-		StatusChangedAt: statusChanged,
-		Notes:           notes,
-		CreatedAt:       jsontime.New(c.CreatedAt),
-		UpdatedAt:       jsontime.New(c.UpdatedAt),
+		ID:                      c.ID,
+		Name:                    c.Name,
+		Identifier:              c.Identifier,
+		Status:                  string(c.Status),
+		StatusReason:            c.StatusReason,
+		StatusChangedAt:         statusChanged,
+		Notes:                   notes,
+		Region:                  c.Region,
+		RequirePasskeyForAdmins: c.RequirePasskeyForAdmins,
+		CreatedAt:               jsontime.New(c.CreatedAt),
+		UpdatedAt:               jsontime.New(c.UpdatedAt),
 	}
 }
 
@@ -58,8 +58,16 @@ type Client struct {
 	StatusReason    *string    `json:"statusReason,omitempty"`
 	StatusChangedAt *time.Time `json:"statusChangedAt,omitempty"`
 	Notes           []Note     `json:"notes"`
-	CreatedAt       time.Time  `json:"createdAt"`
-	UpdatedAt       time.Time  `json:"updatedAt"`
+	// Region pins deliveries to dispatch pools in the same data-residency
+	// region. nil → unrestricted.
+	Region *string `json:"region,omitempty"`
+	// RequirePasskeyForAdmins refuses password sign-in to the client's
+	// admin-scope principals (see principal.IsAdminScope) who hold a
+	// passkey, so they must use the WebAuthn ceremony; one without a
+	// passkey gets a password session that can only register one.
+	RequirePasskeyForAdmins bool      `json:"requirePasskeyForAdmins"`
+	CreatedAt               time.Time `json:"createdAt"`
+	UpdatedAt               time.Time `json:"updatedAt"`
 }
 
 // IDStr satisfies usecase.HasID.
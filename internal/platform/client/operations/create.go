@@ -6,6 +6,7 @@ import (
 	"strings"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/validate"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
 )
@@ -16,6 +17,10 @@ var identifierPattern = regexp.MustCompile(`^[a-z0-9][a-z0-9-]*[a-z0-9]$|^[a-z0-
 type CreateCommand struct {
 	Name       string `json:"name"`
 	Identifier string `json:"identifier"`
+	// Region is the data-residency region ("eu"); blank = unrestricted.
+	Region string `json:"region,omitempty"`
+	// RequirePasskeyForAdmins: see client.Client.
+	RequirePasskeyForAdmins bool `json:"requirePasskeyForAdmins,omitempty"`
 }
 
 // CreateClient validates cmd, enforces identifier uniqueness, persists
@@ -37,7 +42,8 @@ func CreateClient(repo *client.Repository) usecaseop.Operation[CreateCommand, Cl
 				return usecase.Validation("INVALID_IDENTIFIER",
 					"identifier must be lowercase alphanumeric with optional hyphens (URL-safe)")
 			}
-			return nil
+			_, err := validate.Region(cmd.Region)
+			return err
 		},
 		Authorize: usecaseop.Public[CreateCommand],
 		Execute: func(ctx context.Context, cmd CreateCommand, ec usecase.ExecutionContext) (usecaseop.Plan[ClientCreated], error) {
@@ -52,12 +58,18 @@ func CreateClient(repo *client.Repository) usecaseop.Operation[CreateCommand, Cl
 					"IDENTIFIER_EXISTS", "Client with identifier '"+id+"' already exists")
 			}
 			c := client.New(strings.TrimSpace(cmd.Name), id)
+			if region, _ := validate.Region(cmd.Region); region != "" {
+				c.Region = &region
+			}
+			c.RequirePasskeyForAdmins = cmd.RequirePasskeyForAdmins
 
 			event := ClientCreated{
-				Metadata:   usecase.NewEventMetadata(ec, ClientCreatedType, Source, subjectFor(c.ID)),
-				ClientID:   c.ID,
-				Name:       c.Name,
-				Identifier: c.Identifier,
+				Metadata:                usecase.NewEventMetadata(ec, ClientCreatedType, Source, subjectFor(c.ID)),
+				ClientID:                c.ID,
+				Name:                    c.Name,
+				Identifier:              c.Identifier,
+				Region:                  c.Region,
+				RequirePasskeyForAdmins: c.RequirePasskeyForAdmins,
 			}
 			return usecaseop.Save(c, repo, event), nil
 		},
@@ -21,10 +21,12 @@ func subjectFor(id string) string { return "platform.client." + id }
 func groupFor(id string) string   { return "platform:client:" + id }
 
 type ClientCreated struct {
-	Metadata   usecase.EventMetadata
-	ClientID   string
-	Name       string
-	Identifier string
+	Metadata                usecase.EventMetadata
+	ClientID                string
+	Name                    string
+	Identifier              string
+	Region                  *string
+	RequirePasskeyForAdmins bool
 }
 
 func (e ClientCreated) EventID() string       { return e.Metadata.EventID }
@@ -40,16 +42,20 @@ func (e ClientCreated) ExecutionID() string   { return e.Metadata.ExecutionID }
 func (e ClientCreated) MessageGroup() string  { return groupFor(e.ClientID) }
 func (e ClientCreated) ToDataJSON() ([]byte, error) {
 	return json.Marshal(struct {
-		ClientID   string `json:"clientId"`
-		Name       string `json:"name"`
-		Identifier string `json:"identifier"`
-	}{e.ClientID, e.Name, e.Identifier})
+		ClientID                string  `json:"clientId"`
+		Name                    string  `json:"name"`
+		Identifier              string  `json:"identifier"`
+		Region                  *string `json:"region,omitempty"`
+		RequirePasskeyForAdmins bool    `json:"requirePasskeyForAdmins"`
+	}{e.ClientID, e.Name, e.Identifier, e.Region, e.RequirePasskeyForAdmins})
 }
 
 type ClientUpdated struct {
-	Metadata usecase.EventMetadata
-	ClientID string
-	Name     string
+	Metadata                usecase.EventMetadata
+	ClientID                string
+	Name                    string
+	Region                  *string
+	RequirePasskeyForAdmins bool
 }
 
 func (e ClientUpdated) EventID() string       { return e.Metadata.EventID }
@@ -65,9 +71,11 @@ func (e ClientUpdated) ExecutionID() string   { return e.Metadata.ExecutionID }
 func (e ClientUpdated) MessageGroup() string  { return groupFor(e.ClientID) }
 func (e ClientUpdated) ToDataJSON() ([]byte, error) {
 	return json.Marshal(struct {
-		ClientID string `json:"clientId"`
-		Name     string `json:"name"`
-	}{e.ClientID, e.Name})
+		ClientID                string  `json:"clientId"`
+		Name                    string  `json:"name"`
+		Region                  *string `json:"region,omitempty"`
+		RequirePasskeyForAdmins bool    `json:"requirePasskeyForAdmins"`
+	}{e.ClientID, e.Name, e.Region, e.RequirePasskeyForAdmins})
 }
 
 type ClientActivated struct {
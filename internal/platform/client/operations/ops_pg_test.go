@@ -133,6 +133,37 @@ func TestUpdateClient_HappyPath(t *testing.T) {
 	assert.Equal(t, "cl-upd-happy", got.Identifier, "identifier is immutable on update")
 }
 
+func TestUpdateClient_RequirePasskeyForAdmins(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := client.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	strict := mustCreate(t, repo, uow, "Strict", "cl-upd-passkey-strict")
+	lax := mustCreate(t, repo, uow, "Lax", "cl-upd-passkey-lax")
+
+	on := true
+	ev, err := runAuthorized(uow, operations.UpdateClient(repo), operations.UpdateCommand{
+		ID: strict.ClientID, RequirePasskeyForAdmins: &on,
+	})
+	require.NoError(t, err)
+	assert.True(t, ev.RequirePasskeyForAdmins)
+
+	got, err := repo.FindByID(ctx, strict.ClientID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	assert.True(t, got.RequirePasskeyForAdmins)
+
+	required, err := repo.RequiresPasskeyForAdmins(ctx, false, []string{lax.ClientID, strict.ClientID})
+	require.NoError(t, err)
+	assert.True(t, required, "one strict client among them binds")
+	required, err = repo.RequiresPasskeyForAdmins(ctx, false, []string{lax.ClientID})
+	require.NoError(t, err)
+	assert.False(t, required)
+	required, err = repo.RequiresPasskeyForAdmins(ctx, true, nil)
+	require.NoError(t, err)
+	assert.True(t, required, "all clients includes the strict one")
+}
+
 func TestUpdateClient_Errors(t *testing.T) {
 	t.Parallel()
 	repo := client.NewRepository(testpg.Pool(t))
@@ -6,6 +6,7 @@ import (
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/validate"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
 )
@@ -14,9 +15,14 @@ import (
 type UpdateCommand struct {
 	ID   string  `json:"id"`
 	Name *string `json:"name,omitempty"`
+	// Region: nil = unchanged, "" = clear (unrestricted).
+	Region *string `json:"region,omitempty"`
+	// RequirePasskeyForAdmins: nil = unchanged.
+	RequirePasskeyForAdmins *bool `json:"requirePasskeyForAdmins,omitempty"`
 }
 
-// UpdateClient mutates the client name and emits [ClientUpdated].
+// UpdateClient mutates the client name, region and passkey policy and
+// emits [ClientUpdated].
 func UpdateClient(repo *client.Repository) usecaseop.Operation[UpdateCommand, ClientUpdated] {
 	return usecaseop.Operation[UpdateCommand, ClientUpdated]{
 		Name: "UpdateClient",
@@ -27,6 +33,11 @@ func UpdateClient(repo *client.Repository) usecaseop.Operation[UpdateCommand, Cl
 			if cmd.Name != nil && strings.TrimSpace(*cmd.Name) == "" {
 				return usecase.Validation("NAME_REQUIRED", "name cannot be empty")
 			}
+			if cmd.Region != nil {
+				if _, err := validate.Region(*cmd.Region); err != nil {
+					return err
+				}
+			}
 			return nil
 		},
 		Authorize: usecaseop.Public[UpdateCommand],
@@ -41,11 +52,22 @@ func UpdateClient(repo *client.Repository) usecaseop.Operation[UpdateCommand, Cl
 			if cmd.Name != nil {
 				c.Name = strings.TrimSpace(*cmd.Name)
 			}
+			if cmd.Region != nil {
+				c.Region = nil
+				if region, _ := validate.Region(*cmd.Region); region != "" {
+					c.Region = &region
+				}
+			}
+			if cmd.RequirePasskeyForAdmins != nil {
+				c.RequirePasskeyForAdmins = *cmd.RequirePasskeyForAdmins
+			}
 
 			event := ClientUpdated{
-				Metadata: usecase.NewEventMetadata(ec, ClientUpdatedType, Source, subjectFor(c.ID)),
-				ClientID: c.ID,
-				Name:     c.Name,
+				Metadata:                usecase.NewEventMetadata(ec, ClientUpdatedType, Source, subjectFor(c.ID)),
+				ClientID:                c.ID,
+				Name:                    c.Name,
+				Region:                  c.Region,
+				RequirePasskeyForAdmins: c.RequirePasskeyForAdmins,
 			}
 			return usecaseop.Save(c, repo, event), nil
 		},
@@ -67,6 +67,16 @@ func (r *Repository) FindAll(ctx context.Context) ([]Client, error) {
 	return rowsToClients(rows)
 }
 
+// RequiresPasskeyForAdmins reports whether any of the clients ids, or
+// any client at all when allClients is set, requires passkeys for its
+// admin-scope principals.
+func (r *Repository) RequiresPasskeyForAdmins(ctx context.Context, allClients bool, ids []string) (bool, error) {
+	if !allClients && len(ids) == 0 {
+		return false, nil
+	}
+	return r.q.ClientRequiresPasskeyForAdmins(ctx, dbq.ClientRequiresPasskeyForAdminsParams{AllClients: allClients, Ids: ids})
+}
+
 // Persist implements usecasepgx.Persist[Client].
 func (r *Repository) Persist(ctx context.Context, c *Client, tx *usecasepgx.DbTx) error {
 	notesJSON, err := json.Marshal(c.Notes)
@@ -74,15 +84,17 @@ func (r *Repository) Persist(ctx context.Context, c *Client, tx *usecasepgx.DbTx
 		return fmt.Errorf("marshal notes: %w", err)
 	}
 	return r.q.WithTx(tx.Inner()).ClientUpsert(ctx, dbq.ClientUpsertParams{
-		ID:              c.ID,
-		Name:            c.Name,
-		Identifier:      c.Identifier,
-		Status:          string(c.Status),
-		StatusReason:    c.StatusReason,
-		StatusChangedAt: c.StatusChangedAt,
-		Notes:           notesJSON,
-		CreatedAt:       c.CreatedAt,
-		UpdatedAt:       time.Now().UTC(),
+		ID:                      c.ID,
+		Name:                    c.Name,
+		Identifier:              c.Identifier,
+		Status:                  string(c.Status),
+		StatusReason:            c.StatusReason,
+		StatusChangedAt:         c.StatusChangedAt,
+		Notes:                   notesJSON,
+		CreatedAt:               c.CreatedAt,
+		UpdatedAt:               time.Now().UTC(),
+		RequirePasskeyForAdmins: c.RequirePasskeyForAdmins,
+		Region:                  c.Region,
 	})
 }
 
@@ -94,15 +106,17 @@ func (r *Repository) Delete(ctx context.Context, c *Client, tx *usecasepgx.DbTx)
 // rowToClient projects a sqlc-generated row onto the aggregate's Client.
 func rowToClient(row dbq.TntClient) (*Client, error) {
 	c := Client{
-		ID:              row.ID,
-		Name:            row.Name,
-		Identifier:      row.Identifier,
-		Status:          ParseStatus(row.Status),
-		StatusReason:    row.StatusReason,
-		StatusChangedAt: row.StatusChangedAt,
-		CreatedAt:       row.CreatedAt,
-		UpdatedAt:       row.UpdatedAt,
-		Notes:           []Note{},
+		ID:                      row.ID,
+		Name:                    row.Name,
+		Identifier:              row.Identifier,
+		Status:                  ParseStatus(row.Status),
+		StatusReason:            row.StatusReason,
+		StatusChangedAt:         row.StatusChangedAt,
+		Region:                  row.Region,
+		RequirePasskeyForAdmins: row.RequirePasskeyForAdmins,
+		CreatedAt:               row.CreatedAt,
+		UpdatedAt:               row.UpdatedAt,
+		Notes:                   []Note{},
 	}
 	if len(row.Notes) > 0 {
 		if err := json.Unmarshal(row.Notes, &c.Notes); err != nil {
@@ -0,0 +1,83 @@
+// Package api wires HTTP routes for configuration export/import via huma.
+package api
+
+import (
+	"context"
+	"net/http"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// State bundles deps.
+type State struct {
+	Repos configbundle.Repos
+	// Guards are the subscription checks import applies; see
+	// configbundle.Guards.
+	Guards configbundle.Guards
+	UoW    *usecasepgx.UnitOfWork
+}
+
+const tag = "configuration"
+
+// Register mounts the export/import endpoints. Both are anchor-only.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "exportConfiguration", "/api/admin/export", "Export platform configuration as a bundle", s.export)
+	apiroute.Post(g, "importConfiguration", "/api/admin/import", "Import a configuration bundle", http.StatusOK, s.importBundle)
+}
+
+func (s *State) export(ctx context.Context, _ *struct{}) (*apicommon.Out[configbundle.Bundle], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	snap, err := s.Repos.Load(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "load configuration failed", err)
+	}
+	return &apicommon.Out[configbundle.Bundle]{Body: *configbundle.Export(snap)}, nil
+}
+
+type importInput struct {
+	DryRun     bool   `query:"dryRun" doc:"Diff the bundle against this environment and report, without writing"`
+	OnConflict string `query:"onConflict" doc:"What to do with items that exist here with different values: skip (default), overwrite, or fail the whole import"`
+	Body       configbundle.Bundle
+}
+
+func (s *State) importBundle(ctx context.Context, in *importInput) (*apicommon.Out[ImportResponse], error) {
+	strategy := configbundle.Strategy(in.OnConflict)
+	if strategy == "" {
+		strategy = configbundle.OnConflictSkip
+	}
+	if !in.DryRun {
+		// Anchor-only authorization runs inside the use case's Authorize phase.
+		ec := auth.NewExecutionContext(ctx)
+		plan, err := usecaseop.RunTx(ctx, s.UoW, operations.ImportBundle(s.Repos, s.Guards),
+			operations.ImportCommand{Bundle: &in.Body, OnConflict: strategy}, ec)
+		if err != nil {
+			return nil, err
+		}
+		return &apicommon.Out[ImportResponse]{Body: fromPlan(plan, false, strategy)}, nil
+	}
+
+	// A dry run is a read: the same diff, nothing written.
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	if err := configbundle.Check(&in.Body, strategy); err != nil {
+		return nil, err
+	}
+	snap, err := s.Repos.Load(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "load configuration failed", err)
+	}
+	return &apicommon.Out[ImportResponse]{Body: fromPlan(configbundle.Diff(ctx, &in.Body, snap, strategy, s.Guards), true, strategy)}, nil
+}
@@ -0,0 +1,56 @@
+// dto.go contains the wire-format types for the configuration bundle API.
+// The bundle itself is its own wire format (configbundle.Bundle).
+package api
+
+import "github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+
+// ImportItemResponse mirrors configbundle.ItemResult.
+type ImportItemResponse struct {
+	Kind    string   `json:"kind" doc:"dispatchPool, eventType, role, authConfig or subscription"`
+	Key     string   `json:"key" doc:"Code, role name or email domain"`
+	Action  string   `json:"action" doc:"create, update, unchanged, conflict or invalid"`
+	Changed []string `json:"changed,omitempty" doc:"Fields that differ from the existing item"`
+	Message string   `json:"message,omitempty"`
+}
+
+// ImportSummaryResponse counts items per action.
+type ImportSummaryResponse struct {
+	Create    int `json:"create"`
+	Update    int `json:"update"`
+	Unchanged int `json:"unchanged"`
+	Conflict  int `json:"conflict"`
+	Invalid   int `json:"invalid"`
+}
+
+// ImportResponse is the wire shape for POST /api/admin/import.
+type ImportResponse struct {
+	DryRun     bool                  `json:"dryRun"`
+	OnConflict string                `json:"onConflict"`
+	Summary    ImportSummaryResponse `json:"summary"`
+	Items      []ImportItemResponse  `json:"items"`
+}
+
+func fromPlan(p *configbundle.Plan, dryRun bool, strategy configbundle.Strategy) ImportResponse {
+	items := make([]ImportItemResponse, 0, len(p.Items))
+	for _, it := range p.Items {
+		items = append(items, ImportItemResponse{
+			Kind:    string(it.Kind),
+			Key:     it.Key,
+			Action:  string(it.Action),
+			Changed: it.Changed,
+			Message: it.Message,
+		})
+	}
+	return ImportResponse{
+		DryRun:     dryRun,
+		OnConflict: string(strategy),
+		Summary: ImportSummaryResponse{
+			Create:    p.Count(configbundle.ActionCreate),
+			Update:    p.Count(configbundle.ActionUpdate),
+			Unchanged: p.Count(configbundle.ActionUnchanged),
+			Conflict:  p.Count(configbundle.ActionConflict),
+			Invalid:   p.Count(configbundle.ActionInvalid),
+		},
+		Items: items,
+	}
+}
@@ -0,0 +1,381 @@
+// Package configbundle moves platform configuration between environments:
+// dispatch pools, event types, roles, auth configs and subscriptions,
+// serialized as one versioned JSON bundle.
+//
+// A bundle carries no row ids. Items are keyed by their natural identity
+// (code, role name, email domain) and reference each other — and the
+// clients, connections and applications they depend on — by code or
+// identifier, so a bundle exported from one environment applies cleanly to
+// another whose ids were minted independently. Client-owned pools and
+// subscriptions are tenant data rather than configuration and are left out,
+// as are secrets (an OIDC client secret is encrypted with the source
+// environment's key and can't travel).
+package configbundle
+
+import (
+	"cmp"
+	"context"
+	"encoding/json"
+	"fmt"
+	"slices"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/role"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+// Version is the bundle format this build reads and writes. Import
+// rejects any other version rather than guess at what its fields mean.
+const Version = 1
+
+// Bundle is the export/import document. Sections are listed in the order
+// import applies them: a section only references the ones before it.
+type Bundle struct {
+	Version       int                `json:"version"`
+	ExportedAt    *time.Time         `json:"exportedAt,omitempty"`
+	DispatchPools []DispatchPoolItem `json:"dispatchPools,omitempty"`
+	EventTypes    []EventTypeItem    `json:"eventTypes,omitempty"`
+	Roles         []RoleItem         `json:"roles,omitempty"`
+	AuthConfigs   []AuthConfigItem   `json:"authConfigs,omitempty"`
+	Subscriptions []SubscriptionItem `json:"subscriptions,omitempty"`
+}
+
+// DispatchPoolItem is a platform-level dispatch pool, keyed by code.
+type DispatchPoolItem struct {
+	Code        string  `json:"code"`
+	Name        string  `json:"name"`
+	Description *string `json:"description,omitempty"`
+	RateLimit   *int32  `json:"rateLimit,omitempty"`
+	Concurrency int32   `json:"concurrency"`
+	Status      string  `json:"status,omitempty"`
+}
+
+// SchemaItem is one spec version of an event type, keyed by version.
+type SchemaItem struct {
+	Version    string          `json:"version"`
+	MimeType   string          `json:"mimeType,omitempty"`
+	SchemaType string          `json:"schemaType,omitempty"`
+	Status     string          `json:"status,omitempty"`
+	Content    json.RawMessage `json:"content,omitempty"`
+}
+
+// EventTypeItem is an event type with its schemas and data policy, keyed
+// by code.
+type EventTypeItem struct {
+	Code                  string       `json:"code"`
+	Name                  string       `json:"name"`
+	Description           *string      `json:"description,omitempty"`
+	Status                string       `json:"status,omitempty"`
+	Source                string       `json:"source,omitempty"`
+	ClientScoped          bool         `json:"clientScoped,omitempty"`
+	Schemas               []SchemaItem `json:"schemas,omitempty"`
+	PayloadRetentionDays  *int         `json:"payloadRetentionDays,omitempty"`
+	MetadataRetentionDays *int         `json:"metadataRetentionDays,omitempty"`
+	RedactFields          []string     `json:"redactFields,omitempty"`
+}
+
+// RoleItem is a role, keyed by its full "{applicationCode}:{role}" name.
+type RoleItem struct {
+	Name            string   `json:"name"`
+	ApplicationCode string   `json:"applicationCode"`
+	DisplayName     string   `json:"displayName"`
+	Description     *string  `json:"description,omitempty"`
+	Permissions     []string `json:"permissions,omitempty"`
+	Source          string   `json:"source,omitempty"`
+	ClientManaged   bool     `json:"clientManaged,omitempty"`
+}
+
+// AuthConfigItem is a per-domain auth config, keyed by email domain.
+// Clients are referenced by identifier. The OIDC client secret is not
+// carried; set it on the target after the first import.
+type AuthConfigItem struct {
+	EmailDomain       string   `json:"emailDomain"`
+	ConfigType        string   `json:"configType"`
+	PrimaryClient     *string  `json:"primaryClient,omitempty"`
+	AdditionalClients []string `json:"additionalClients,omitempty"`
+	GrantedClients    []string `json:"grantedClients,omitempty"`
+	AuthProvider      string   `json:"authProvider,omitempty"`
+	OIDCIssuerURL     *string  `json:"oidcIssuerUrl,omitempty"`
+	OIDCClientID      *string  `json:"oidcClientId,omitempty"`
+	OIDCMultiTenant   bool     `json:"oidcMultiTenant,omitempty"`
+	OIDCIssuerPattern *string  `json:"oidcIssuerPattern,omitempty"`
+}
+
+// BindingItem is one event-type binding of a subscription.
+type BindingItem struct {
+	EventTypeCode string  `json:"eventTypeCode"`
+	SpecVersion   *string `json:"specVersion,omitempty"`
+	Filter        *string `json:"filter,omitempty"`
+}
+
+// SubscriptionItem is a platform-level subscription, keyed by code. The
+// connection and dispatch pool are referenced by code; the service account
+// (which holds credentials) is not carried. Headers travel as stored, so a
+// secret reference is exported, never the secret it names; the transport
+// travels by name and must be configured in the target environment.
+type SubscriptionItem struct {
+	Code             string                     `json:"code"`
+	ApplicationCode  *string                    `json:"applicationCode,omitempty"`
+	Name             string                     `json:"name"`
+	Description      *string                    `json:"description,omitempty"`
+	ClientScoped     bool                       `json:"clientScoped,omitempty"`
+	EventTypes       []BindingItem              `json:"eventTypes"`
+	ConnectionCode   *string                    `json:"connectionCode,omitempty"`
+	Endpoint         string                     `json:"endpoint,omitempty"`
+	ShadowEndpoint   *string                    `json:"shadowEndpoint,omitempty"`
+	TrafficSplit     []subscription.SplitTarget `json:"trafficSplit,omitempty"`
+	Queue            *string                    `json:"queue,omitempty"`
+	CustomConfig     []subscription.ConfigEntry `json:"customConfig,omitempty"`
+	Headers          []subscription.ConfigEntry `json:"headers,omitempty"`
+	Transport        *string                    `json:"transport,omitempty"`
+	TLS              *outbound.TLSTrust         `json:"tls,omitempty"`
+	Delivery         string                     `json:"delivery,omitempty"`
+	Batching         *subscription.Batching     `json:"batching,omitempty"`
+	PayloadFormat    string                     `json:"payloadFormat,omitempty"`
+	LargePayloads    string                     `json:"largePayloads,omitempty"`
+	Source           string                     `json:"source,omitempty"`
+	Status           string                     `json:"status,omitempty"`
+	DispatchPoolCode *string                    `json:"dispatchPoolCode,omitempty"`
+	Mode             string                     `json:"mode,omitempty"`
+	MaxAgeSeconds    int32                      `json:"maxAgeSeconds"`
+	DelaySeconds     int32                      `json:"delaySeconds"`
+	Sequence         int32                      `json:"sequence"`
+	TimeoutSeconds   int32                      `json:"timeoutSeconds"`
+	MaxRetries       int32                      `json:"maxRetries"`
+	DataOnly         bool                       `json:"dataOnly,omitempty"`
+}
+
+// Snapshot is an environment's current configuration plus the lookups a
+// bundle's references resolve against. Export serializes it; Diff compares
+// a bundle with it.
+type Snapshot struct {
+	DispatchPools []dispatchpool.DispatchPool
+	EventTypes    []eventtype.EventType
+	Roles         []role.Role
+	AuthConfigs   []auth.ClientAuthConfig
+	Subscriptions []subscription.Subscription
+	Clients       []client.Client
+	Connections   []connection.Connection
+	Applications  []application.Application
+}
+
+// Repos are the repositories a Snapshot is loaded from and an import
+// writes to.
+type Repos struct {
+	DispatchPools *dispatchpool.Repository
+	EventTypes    *eventtype.Repository
+	Roles         *role.Repository
+	AuthConfigs   *auth.ClientAuthConfigRepo
+	Subscriptions *subscription.Repository
+	Clients       *client.Repository
+	Connections   *connection.Repository
+	Applications  *application.Repository
+}
+
+// Load reads the current configuration.
+func (r Repos) Load(ctx context.Context) (*Snapshot, error) {
+	var (
+		s   Snapshot
+		err error
+	)
+	if s.DispatchPools, err = r.DispatchPools.FindWithFilters(ctx, nil, nil); err != nil {
+		return nil, fmt.Errorf("load dispatch pools: %w", err)
+	}
+	if s.EventTypes, err = r.EventTypes.FindWithFilters(ctx, nil, nil, nil, nil, nil); err != nil {
+		return nil, fmt.Errorf("load event types: %w", err)
+	}
+	if s.Roles, err = r.Roles.FindAll(ctx); err != nil {
+		return nil, fmt.Errorf("load roles: %w", err)
+	}
+	if s.AuthConfigs, err = r.AuthConfigs.FindAll(ctx); err != nil {
+		return nil, fmt.Errorf("load auth configs: %w", err)
+	}
+	if s.Subscriptions, err = r.Subscriptions.FindAll(ctx); err != nil {
+		return nil, fmt.Errorf("load subscriptions: %w", err)
+	}
+	if s.Clients, err = r.Clients.FindAll(ctx); err != nil {
+		return nil, fmt.Errorf("load clients: %w", err)
+	}
+	if s.Connections, err = r.Connections.FindAll(ctx); err != nil {
+		return nil, fmt.Errorf("load connections: %w", err)
+	}
+	if s.Applications, err = r.Applications.FindWithFilters(ctx, nil, nil); err != nil {
+		return nil, fmt.Errorf("load applications: %w", err)
+	}
+	return &s, nil
+}
+
+// Export serializes the snapshot's configuration, each section sorted by
+// key so two exports of the same state are byte-identical apart from
+// ExportedAt.
+func Export(s *Snapshot) *Bundle {
+	now := time.Now().UTC()
+	b := &Bundle{Version: Version, ExportedAt: &now}
+	identifiers := make(map[string]string, len(s.Clients))
+	for _, c := range s.Clients {
+		identifiers[c.ID] = c.Identifier
+	}
+	connCodes := make(map[string]string, len(s.Connections))
+	for _, c := range s.Connections {
+		connCodes[c.ID] = c.Code
+	}
+
+	for i := range s.DispatchPools {
+		if s.DispatchPools[i].ClientID == nil {
+			b.DispatchPools = append(b.DispatchPools, poolItem(&s.DispatchPools[i]))
+		}
+	}
+	for i := range s.EventTypes {
+		b.EventTypes = append(b.EventTypes, eventTypeItem(&s.EventTypes[i]))
+	}
+	for i := range s.Roles {
+		b.Roles = append(b.Roles, roleItem(&s.Roles[i]))
+	}
+	for i := range s.AuthConfigs {
+		b.AuthConfigs = append(b.AuthConfigs, authConfigItem(&s.AuthConfigs[i], identifiers))
+	}
+	for i := range s.Subscriptions {
+		if s.Subscriptions[i].ClientID == nil {
+			b.Subscriptions = append(b.Subscriptions, subscriptionItem(&s.Subscriptions[i], connCodes))
+		}
+	}
+
+	slices.SortFunc(b.DispatchPools, func(x, y DispatchPoolItem) int { return cmp.Compare(x.Code, y.Code) })
+	slices.SortFunc(b.EventTypes, func(x, y EventTypeItem) int { return cmp.Compare(x.Code, y.Code) })
+	slices.SortFunc(b.Roles, func(x, y RoleItem) int { return cmp.Compare(x.Name, y.Name) })
+	slices.SortFunc(b.AuthConfigs, func(x, y AuthConfigItem) int { return cmp.Compare(x.EmailDomain, y.EmailDomain) })
+	slices.SortFunc(b.Subscriptions, func(x, y SubscriptionItem) int { return cmp.Compare(x.Code, y.Code) })
+	return b
+}
+
+// ── entity → item ────────────────────────────────────────────────────────
+//
+// These are also the comparison form for Diff: two aggregates are the same
+// configuration exactly when their items marshal to the same JSON.
+
+func poolItem(p *dispatchpool.DispatchPool) DispatchPoolItem {
+	return DispatchPoolItem{
+		Code:        p.Code,
+		Name:        p.Name,
+		Description: p.Description,
+		RateLimit:   p.RateLimit,
+		Concurrency: p.Concurrency,
+		Status:      string(p.Status),
+	}
+}
+
+func eventTypeItem(et *eventtype.EventType) EventTypeItem {
+	it := EventTypeItem{
+		Code:                  et.Code,
+		Name:                  et.Name,
+		Description:           et.Description,
+		Status:                string(et.Status),
+		Source:                string(et.Source),
+		ClientScoped:          et.ClientScoped,
+		PayloadRetentionDays:  et.PayloadRetentionDays,
+		MetadataRetentionDays: et.MetadataRetentionDays,
+		RedactFields:          et.RedactFields,
+	}
+	for _, sv := range et.SpecVersions {
+		it.Schemas = append(it.Schemas, SchemaItem{
+			Version:    sv.Version,
+			MimeType:   sv.MimeType,
+			SchemaType: string(sv.SchemaType),
+			Status:     string(sv.Status),
+			Content:    sv.SchemaContent,
+		})
+	}
+	return it
+}
+
+func roleItem(r *role.Role) RoleItem {
+	return RoleItem{
+		Name:            r.Name,
+		ApplicationCode: r.ApplicationCode,
+		DisplayName:     r.DisplayName,
+		Description:     r.Description,
+		Permissions:     r.Permissions,
+		Source:          string(r.Source),
+		ClientManaged:   r.ClientManaged,
+	}
+}
+
+// authConfigItem swaps client ids for identifiers; an id whose client no
+// longer exists is dropped.
+func authConfigItem(c *auth.ClientAuthConfig, identifiers map[string]string) AuthConfigItem {
+	toIdentifiers := func(ids []string) []string {
+		var out []string
+		for _, id := range ids {
+			if ident, ok := identifiers[id]; ok {
+				out = append(out, ident)
+			}
+		}
+		return out
+	}
+	it := AuthConfigItem{
+		EmailDomain:       c.EmailDomain,
+		ConfigType:        string(c.ConfigType),
+		AdditionalClients: toIdentifiers(c.AdditionalClientIDs),
+		GrantedClients:    toIdentifiers(c.GrantedClientIDs),
+		AuthProvider:      string(c.AuthProvider),
+		OIDCIssuerURL:     c.OIDCIssuerURL,
+		OIDCClientID:      c.OIDCClientID,
+		OIDCMultiTenant:   c.OIDCMultiTenant,
+		OIDCIssuerPattern: c.OIDCIssuerPattern,
+	}
+	if c.PrimaryClientID != nil {
+		if ident, ok := identifiers[*c.PrimaryClientID]; ok {
+			it.PrimaryClient = &ident
+		}
+	}
+	return it
+}
+
+func subscriptionItem(s *subscription.Subscription, connCodes map[string]string) SubscriptionItem {
+	it := SubscriptionItem{
+		Code:             s.Code,
+		ApplicationCode:  s.ApplicationCode,
+		Name:             s.Name,
+		Description:      s.Description,
+		ClientScoped:     s.ClientScoped,
+		EventTypes:       make([]BindingItem, 0, len(s.EventTypes)),
+		Endpoint:         s.Endpoint,
+		ShadowEndpoint:   s.ShadowEndpoint,
+		TrafficSplit:     s.TrafficSplit,
+		Queue:            s.Queue,
+		CustomConfig:     s.CustomConfig,
+		Headers:          s.Headers,
+		Transport:        s.Transport,
+		TLS:              s.TLS,
+		Delivery:         string(s.Delivery),
+		Batching:         s.Batching,
+		PayloadFormat:    string(s.PayloadFormat),
+		LargePayloads:    string(s.LargePayloads),
+		Source:           string(s.Source),
+		Status:           string(s.Status),
+		DispatchPoolCode: s.DispatchPoolCode,
+		Mode:             string(s.Mode),
+		MaxAgeSeconds:    s.MaxAgeSeconds,
+		DelaySeconds:     s.DelaySeconds,
+		Sequence:         s.Sequence,
+		TimeoutSeconds:   s.TimeoutSeconds,
+		MaxRetries:       s.MaxRetries,
+		DataOnly:         s.DataOnly,
+	}
+	for _, b := range s.EventTypes {
+		it.EventTypes = append(it.EventTypes, BindingItem{EventTypeCode: b.EventTypeCode, SpecVersion: b.SpecVersion, Filter: b.Filter})
+	}
+	if s.ConnectionID != nil {
+		if code, ok := connCodes[*s.ConnectionID]; ok {
+			it.ConnectionCode = &code
+		}
+	}
+	return it
+}
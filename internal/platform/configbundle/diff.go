@@ -0,0 +1,718 @@
+package configbundle
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"maps"
+	"reflect"
+	"slices"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/role"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/validate"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+// Strategy decides what import does with an item that already exists on
+// the target with different values.
+type Strategy string
+
+const (
+	// OnConflictSkip leaves the existing item alone and reports a conflict.
+	OnConflictSkip Strategy = "skip"
+	// OnConflictOverwrite replaces the existing item with the bundle's.
+	OnConflictOverwrite Strategy = "overwrite"
+	// OnConflictFail rejects the whole import if any item conflicts.
+	OnConflictFail Strategy = "fail"
+)
+
+// Action is the diff outcome for one bundle item.
+type Action string
+
+const (
+	ActionCreate    Action = "create"
+	ActionUpdate    Action = "update"
+	ActionUnchanged Action = "unchanged"
+	ActionConflict  Action = "conflict"
+	ActionInvalid   Action = "invalid"
+)
+
+// Kind names a bundle section in diff results.
+type Kind string
+
+const (
+	KindDispatchPool Kind = "dispatchPool"
+	KindEventType    Kind = "eventType"
+	KindRole         Kind = "role"
+	KindAuthConfig   Kind = "authConfig"
+	KindSubscription Kind = "subscription"
+)
+
+// ItemResult is the diff outcome for one bundle item. Changed lists the
+// bundle fields whose values differ from the target's (update and
+// conflict only).
+type ItemResult struct {
+	Kind    Kind     `json:"kind"`
+	Key     string   `json:"key"`
+	Action  Action   `json:"action"`
+	Changed []string `json:"changed,omitempty"`
+	Message string   `json:"message,omitempty"`
+}
+
+// Write is one aggregate an import persists.
+type Write[A any] struct {
+	Aggregate *A
+	Created   bool
+}
+
+// Plan is a bundle diffed against a snapshot: a result per item, in bundle
+// order, and the aggregates to write per section. Aggregates that are
+// created carry freshly minted ids, and later sections' references to them
+// are already resolved, so the writes can be applied section by section.
+type Plan struct {
+	Items         []ItemResult
+	DispatchPools []Write[dispatchpool.DispatchPool]
+	EventTypes    []Write[eventtype.EventType]
+	Roles         []Write[role.Role]
+	AuthConfigs   []Write[auth.ClientAuthConfig]
+	Subscriptions []Write[subscription.Subscription]
+}
+
+// Count returns how many items ended with action a.
+func (p *Plan) Count(a Action) int {
+	n := 0
+	for _, it := range p.Items {
+		if it.Action == a {
+			n++
+		}
+	}
+	return n
+}
+
+// Check validates the bundle envelope and the strategy. Item-level
+// problems are reported per item by Diff instead.
+func Check(b *Bundle, strategy Strategy) error {
+	if b.Version != Version {
+		return usecase.Validation("UNSUPPORTED_BUNDLE_VERSION",
+			fmt.Sprintf("bundle version %d is not supported (expected %d)", b.Version, Version))
+	}
+	switch strategy {
+	case OnConflictSkip, OnConflictOverwrite, OnConflictFail:
+		return nil
+	default:
+		return usecase.Validation("INVALID_CONFLICT_STRATEGY", "onConflict must be skip, overwrite or fail")
+	}
+}
+
+// Guards are the deployment checks an imported subscription must pass —
+// the ones CreateSubscription and UpdateSubscription apply. A nil field
+// is unchecked.
+type Guards struct {
+	// Targets refuses endpoints the outbound policy blocks.
+	Targets *outbound.Policies
+	// Transports refuses a transport the deployment doesn't configure.
+	Transports *outbound.Transports
+	// Lifecycles refuses new bindings to deprecated event types.
+	Lifecycles *eventtype.LifecycleCache
+}
+
+// Diff compares b with the snapshot, section by section in dependency
+// order (pools → event types → roles → auth configs → subscriptions), and
+// plans the writes strategy allows. Subscriptions failing g are invalid.
+// It writes nothing; a dry run is Diff on its own.
+func Diff(ctx context.Context, b *Bundle, s *Snapshot, strategy Strategy, g Guards) *Plan {
+	d := newDiffer(s, strategy, g)
+	for _, it := range b.DispatchPools {
+		d.pool(it)
+	}
+	for _, it := range b.EventTypes {
+		d.eventType(it)
+	}
+	for _, it := range b.Roles {
+		d.role(it)
+	}
+	for _, it := range b.AuthConfigs {
+		d.authConfig(it)
+	}
+	for _, it := range b.Subscriptions {
+		d.subscription(ctx, it)
+	}
+	return d.plan
+}
+
+type differ struct {
+	strategy Strategy
+	guards   Guards
+	plan     *Plan
+	seen     map[Kind]map[string]bool
+
+	// Target state by key, updated as the plan creates or replaces
+	// aggregates so later sections resolve against the post-import state.
+	pools       map[string]*dispatchpool.DispatchPool
+	eventTypes  map[string]*eventtype.EventType
+	roles       map[string]*role.Role
+	authConfigs map[string]*auth.ClientAuthConfig
+	subs        map[string]*subscription.Subscription
+
+	clientIDs   map[string]string // identifier → id
+	identifiers map[string]string // id → identifier
+	connIDs     map[string]string // platform-level connection code → id
+	connCodes   map[string]string // id → code
+	appIDs      map[string]string // application code → id
+}
+
+func newDiffer(s *Snapshot, strategy Strategy, g Guards) *differ {
+	d := &differ{
+		strategy:    strategy,
+		guards:      g,
+		plan:        &Plan{Items: []ItemResult{}},
+		seen:        map[Kind]map[string]bool{},
+		pools:       map[string]*dispatchpool.DispatchPool{},
+		eventTypes:  map[string]*eventtype.EventType{},
+		roles:       map[string]*role.Role{},
+		authConfigs: map[string]*auth.ClientAuthConfig{},
+		subs:        map[string]*subscription.Subscription{},
+		clientIDs:   map[string]string{},
+		identifiers: map[string]string{},
+		connIDs:     map[string]string{},
+		connCodes:   map[string]string{},
+		appIDs:      map[string]string{},
+	}
+	for i := range s.DispatchPools {
+		if s.DispatchPools[i].ClientID == nil {
+			d.pools[s.DispatchPools[i].Code] = &s.DispatchPools[i]
+		}
+	}
+	for i := range s.EventTypes {
+		d.eventTypes[s.EventTypes[i].Code] = &s.EventTypes[i]
+	}
+	for i := range s.Roles {
+		d.roles[s.Roles[i].Name] = &s.Roles[i]
+	}
+	for i := range s.AuthConfigs {
+		d.authConfigs[s.AuthConfigs[i].EmailDomain] = &s.AuthConfigs[i]
+	}
+	for i := range s.Subscriptions {
+		if s.Subscriptions[i].ClientID == nil {
+			d.subs[s.Subscriptions[i].Code] = &s.Subscriptions[i]
+		}
+	}
+	for _, c := range s.Clients {
+		d.clientIDs[c.Identifier] = c.ID
+		d.identifiers[c.ID] = c.Identifier
+	}
+	for _, c := range s.Connections {
+		d.connCodes[c.ID] = c.Code
+		if c.ClientID == nil {
+			d.connIDs[c.Code] = c.ID
+		}
+	}
+	for _, a := range s.Applications {
+		d.appIDs[a.Code] = a.ID
+	}
+	return d
+}
+
+// admit rejects a second item with the same key in one section.
+func (d *differ) admit(kind Kind, key string) bool {
+	if d.seen[kind] == nil {
+		d.seen[kind] = map[string]bool{}
+	}
+	if d.seen[kind][key] {
+		d.invalid(kind, key, "duplicate "+string(kind)+" '"+key+"' in bundle")
+		return false
+	}
+	d.seen[kind][key] = true
+	return true
+}
+
+func (d *differ) invalid(kind Kind, key, msg string) {
+	d.plan.Items = append(d.plan.Items, ItemResult{Kind: kind, Key: key, Action: ActionInvalid, Message: msg})
+}
+
+// record compares the comparison forms of the target item before and after
+// the bundle is applied (before is nil when the item is new), appends the
+// result, and reports whether the aggregate should be written.
+func (d *differ) record(kind Kind, key string, before, after any) bool {
+	if before == nil {
+		d.plan.Items = append(d.plan.Items, ItemResult{Kind: kind, Key: key, Action: ActionCreate})
+		return true
+	}
+	changed := ChangedFields(before, after)
+	switch {
+	case len(changed) == 0:
+		d.plan.Items = append(d.plan.Items, ItemResult{Kind: kind, Key: key, Action: ActionUnchanged})
+		return false
+	case d.strategy == OnConflictOverwrite:
+		d.plan.Items = append(d.plan.Items, ItemResult{Kind: kind, Key: key, Action: ActionUpdate, Changed: changed})
+		return true
+	default:
+		d.plan.Items = append(d.plan.Items, ItemResult{Kind: kind, Key: key, Action: ActionConflict, Changed: changed})
+		return false
+	}
+}
+
+// ChangedFields lists the top-level JSON fields that differ between two
+// items. Comparing decoded JSON (rather than the structs) treats nil and
+// empty slices alike and compares schema content semantically, so a
+// schema the database re-serialized doesn't show up as a change.
+func ChangedFields(before, after any) []string {
+	x, y := asMap(before), asMap(after)
+	keys := map[string]bool{}
+	for k := range x {
+		keys[k] = true
+	}
+	for k := range y {
+		keys[k] = true
+	}
+	var out []string
+	for _, k := range slices.Sorted(maps.Keys(keys)) {
+		if !reflect.DeepEqual(x[k], y[k]) {
+			out = append(out, k)
+		}
+	}
+	return out
+}
+
+func asMap(v any) map[string]any {
+	raw, _ := json.Marshal(v)
+	var m map[string]any
+	_ = json.Unmarshal(raw, &m)
+	return m
+}
+
+// ── sections ──────────────────────────────────────────────────────────────
+
+func (d *differ) pool(it DispatchPoolItem) {
+	if !d.admit(KindDispatchPool, it.Code) {
+		return
+	}
+	switch {
+	case !validate.CodeUnderscorePattern.MatchString(it.Code):
+		d.invalid(KindDispatchPool, it.Code, "pool code must start with a lowercase letter and contain only lowercase alphanumerics, hyphens and underscores")
+		return
+	case strings.TrimSpace(it.Name) == "":
+		d.invalid(KindDispatchPool, it.Code, "name is required")
+		return
+	case it.RateLimit != nil && *it.RateLimit < 1:
+		d.invalid(KindDispatchPool, it.Code, "rateLimit, when set, must be at least 1")
+		return
+	case it.Concurrency < 1:
+		d.invalid(KindDispatchPool, it.Code, "concurrency must be at least 1")
+		return
+	}
+
+	cur := d.pools[it.Code]
+	var next dispatchpool.DispatchPool
+	var before any
+	if cur != nil {
+		next, before = *cur, poolItem(cur)
+	} else {
+		next = *dispatchpool.New(it.Code, it.Name)
+	}
+	next.Name = it.Name
+	next.Description = it.Description
+	next.RateLimit = it.RateLimit
+	next.Concurrency = it.Concurrency
+	next.Status = dispatchpool.ParseStatus(it.Status)
+
+	if d.record(KindDispatchPool, it.Code, before, poolItem(&next)) {
+		d.plan.DispatchPools = append(d.plan.DispatchPools, Write[dispatchpool.DispatchPool]{Aggregate: &next, Created: cur == nil})
+		d.pools[it.Code] = &next
+	}
+}
+
+func (d *differ) eventType(it EventTypeItem) {
+	if !d.admit(KindEventType, it.Code) {
+		return
+	}
+	if strings.TrimSpace(it.Name) == "" {
+		d.invalid(KindEventType, it.Code, "name is required")
+		return
+	}
+	versions := map[string]bool{}
+	for _, s := range it.Schemas {
+		if strings.TrimSpace(s.Version) == "" {
+			d.invalid(KindEventType, it.Code, "schema version is required")
+			return
+		}
+		if versions[s.Version] {
+			d.invalid(KindEventType, it.Code, "duplicate schema version '"+s.Version+"'")
+			return
+		}
+		versions[s.Version] = true
+		if len(s.Content) > 0 && !json.Valid(s.Content) {
+			d.invalid(KindEventType, it.Code, "schema "+s.Version+" content is not valid JSON")
+			return
+		}
+	}
+
+	cur := d.eventTypes[it.Code]
+	var next eventtype.EventType
+	var before any
+	if cur != nil {
+		next, before = *cur, eventTypeItem(cur)
+	} else {
+		et, err := eventtype.New(it.Code, it.Name)
+		if err != nil {
+			d.invalid(KindEventType, it.Code, err.Error())
+			return
+		}
+		next = *et
+	}
+	next.Name = it.Name
+	next.Description = it.Description
+	next.Status = eventtype.ParseStatus(it.Status)
+	next.Source = eventtype.ParseSource(it.Source)
+	next.ClientScoped = it.ClientScoped
+	if err := next.SetDataPolicy(it.PayloadRetentionDays, it.MetadataRetentionDays, it.RedactFields); err != nil {
+		d.invalid(KindEventType, it.Code, err.Error())
+		return
+	}
+	// Schemas merge by version: listed versions are created or replaced,
+	// versions only the target has are kept — events may still reference
+	// them.
+	specs := slices.Clone(next.SpecVersions)
+	for _, s := range it.Schemas {
+		i := slices.IndexFunc(specs, func(sv eventtype.SpecVersion) bool { return sv.Version == s.Version })
+		if i < 0 {
+			specs = append(specs, eventtype.NewSpecVersion(next.ID, s.Version, s.Content))
+			i = len(specs) - 1
+		}
+		if s.MimeType != "" {
+			specs[i].MimeType = s.MimeType
+		}
+		specs[i].SchemaType = eventtype.ParseSchemaType(s.SchemaType)
+		specs[i].Status = eventtype.ParseSpecVersionStatus(s.Status)
+		specs[i].SchemaContent = s.Content
+	}
+	next.SpecVersions = specs
+
+	if d.record(KindEventType, it.Code, before, eventTypeItem(&next)) {
+		d.plan.EventTypes = append(d.plan.EventTypes, Write[eventtype.EventType]{Aggregate: &next, Created: cur == nil})
+		d.eventTypes[it.Code] = &next
+	}
+}
+
+func (d *differ) role(it RoleItem) {
+	if !d.admit(KindRole, it.Name) {
+		return
+	}
+	short, ok := strings.CutPrefix(it.Name, it.ApplicationCode+":")
+	switch {
+	case strings.TrimSpace(it.ApplicationCode) == "":
+		d.invalid(KindRole, it.Name, "applicationCode is required")
+		return
+	case !ok || short == "":
+		d.invalid(KindRole, it.Name, "role name must be '{applicationCode}:{role}'")
+		return
+	case strings.TrimSpace(it.DisplayName) == "":
+		d.invalid(KindRole, it.Name, "displayName is required")
+		return
+	}
+
+	cur := d.roles[it.Name]
+	var next role.Role
+	var before any
+	if cur != nil {
+		next, before = *cur, roleItem(cur)
+	} else {
+		next = *role.New(it.ApplicationCode, short, it.DisplayName)
+	}
+	next.DisplayName = it.DisplayName
+	next.Description = it.Description
+	next.Permissions = slices.Compact(slices.Sorted(slices.Values(it.Permissions)))
+	if next.Permissions == nil {
+		next.Permissions = []string{}
+	}
+	next.Source = role.ParseSource(it.Source)
+	next.ClientManaged = it.ClientManaged
+	if id, ok := d.appIDs[it.ApplicationCode]; ok {
+		next.ApplicationID = &id
+	}
+
+	// Roles defined in code are owned by their application's role sync;
+	// the admin API refuses to edit them, and so does import.
+	if cur != nil && cur.Source == role.SourceCode && d.strategy == OnConflictOverwrite {
+		if changed := ChangedFields(before, roleItem(&next)); len(changed) > 0 {
+			d.plan.Items = append(d.plan.Items, ItemResult{Kind: KindRole, Key: it.Name, Action: ActionConflict, Changed: changed,
+				Message: "role is defined in code; change it through the application's role sync"})
+			return
+		}
+	}
+	if d.record(KindRole, it.Name, before, roleItem(&next)) {
+		d.plan.Roles = append(d.plan.Roles, Write[role.Role]{Aggregate: &next, Created: cur == nil})
+		d.roles[it.Name] = &next
+	}
+}
+
+func (d *differ) authConfig(it AuthConfigItem) {
+	domain := strings.ToLower(strings.TrimSpace(it.EmailDomain))
+	if !d.admit(KindAuthConfig, domain) {
+		return
+	}
+	switch it.ConfigType {
+	case string(auth.ConfigAnchor), string(auth.ConfigPartner), string(auth.ConfigClient):
+	default:
+		d.invalid(KindAuthConfig, domain, "configType must be ANCHOR, PARTNER, or CLIENT")
+		return
+	}
+	provider := auth.ParseAuthProvider(it.AuthProvider)
+	switch {
+	case !strings.Contains(domain, "."):
+		d.invalid(KindAuthConfig, domain, "emailDomain must be a valid DNS name")
+		return
+	case provider == auth.ProviderOIDC && (it.OIDCIssuerURL == nil || strings.TrimSpace(*it.OIDCIssuerURL) == ""):
+		d.invalid(KindAuthConfig, domain, "OIDC provider requires oidcIssuerUrl")
+		return
+	case provider == auth.ProviderOIDC && (it.OIDCClientID == nil || strings.TrimSpace(*it.OIDCClientID) == ""):
+		d.invalid(KindAuthConfig, domain, "OIDC provider requires oidcClientId")
+		return
+	}
+	resolve := func(identifiers []string) ([]string, string) {
+		ids := make([]string, 0, len(identifiers))
+		for _, ident := range identifiers {
+			id, ok := d.clientIDs[ident]
+			if !ok {
+				return nil, ident
+			}
+			ids = append(ids, id)
+		}
+		return ids, ""
+	}
+	additional, missing := resolve(it.AdditionalClients)
+	if missing != "" {
+		d.invalid(KindAuthConfig, domain, "unknown client '"+missing+"'")
+		return
+	}
+	granted, missing := resolve(it.GrantedClients)
+	if missing != "" {
+		d.invalid(KindAuthConfig, domain, "unknown client '"+missing+"'")
+		return
+	}
+	var primary *string
+	if it.PrimaryClient != nil {
+		id, ok := d.clientIDs[*it.PrimaryClient]
+		if !ok {
+			d.invalid(KindAuthConfig, domain, "unknown client '"+*it.PrimaryClient+"'")
+			return
+		}
+		primary = &id
+	}
+
+	cur := d.authConfigs[domain]
+	var next auth.ClientAuthConfig
+	var before any
+	if cur != nil {
+		next, before = *cur, authConfigItem(cur, d.identifiers)
+	} else {
+		next = *auth.NewClientAuthConfig(domain, auth.ParseAuthConfigType(it.ConfigType))
+	}
+	next.ConfigType = auth.ParseAuthConfigType(it.ConfigType)
+	next.PrimaryClientID = primary
+	next.AdditionalClientIDs = additional
+	next.GrantedClientIDs = granted
+	next.AuthProvider = provider
+	next.OIDCIssuerURL = it.OIDCIssuerURL
+	next.OIDCClientID = it.OIDCClientID
+	next.OIDCMultiTenant = it.OIDCMultiTenant
+	next.OIDCIssuerPattern = it.OIDCIssuerPattern
+
+	if d.record(KindAuthConfig, domain, before, authConfigItem(&next, d.identifiers)) {
+		d.plan.AuthConfigs = append(d.plan.AuthConfigs, Write[auth.ClientAuthConfig]{Aggregate: &next, Created: cur == nil})
+		d.authConfigs[domain] = &next
+		if provider == auth.ProviderOIDC && next.OIDCClientSecretRef == nil {
+			d.plan.Items[len(d.plan.Items)-1].Message = "OIDC client secret is not carried by bundles; set it after import"
+		}
+	}
+}
+
+func (d *differ) subscription(ctx context.Context, it SubscriptionItem) {
+	if !d.admit(KindSubscription, it.Code) {
+		return
+	}
+	switch {
+	case strings.TrimSpace(it.Code) == "":
+		d.invalid(KindSubscription, it.Code, "code is required")
+		return
+	case strings.TrimSpace(it.Name) == "":
+		d.invalid(KindSubscription, it.Code, "name is required")
+		return
+	case strings.TrimSpace(it.Endpoint) == "" && subscription.ParseDeliveryMode(it.Delivery) == subscription.DeliveryPush:
+		d.invalid(KindSubscription, it.Code, "endpoint is required")
+		return
+	case it.ShadowEndpoint != nil && subscription.ParseDeliveryMode(it.Delivery) != subscription.DeliveryPush:
+		d.invalid(KindSubscription, it.Code, "only a push subscription can have a shadow endpoint")
+		return
+	case len(it.TrafficSplit) > 0 && subscription.ParseDeliveryMode(it.Delivery) != subscription.DeliveryPush:
+		d.invalid(KindSubscription, it.Code, "only a push subscription can split its traffic")
+		return
+	case len(it.EventTypes) == 0:
+		d.invalid(KindSubscription, it.Code, "at least one event type binding is required")
+		return
+	}
+	if err := subscription.ValidateHeaders(it.Headers); err != nil {
+		d.invalid(KindSubscription, it.Code, err.Error())
+		return
+	}
+	if err := subscription.ValidateBatching(it.Batching); err != nil {
+		d.invalid(KindSubscription, it.Code, err.Error())
+		return
+	}
+	if err := it.TLS.Validate(); err != nil {
+		d.invalid(KindSubscription, it.Code, "tls: "+err.Error())
+		return
+	}
+	if err := subscription.ValidateTrafficSplit(it.Endpoint, it.TrafficSplit); err != nil {
+		d.invalid(KindSubscription, it.Code, err.Error())
+		return
+	}
+	if msg := d.guard(ctx, it); msg != "" {
+		d.invalid(KindSubscription, it.Code, msg)
+		return
+	}
+	var connID *string
+	if it.ConnectionCode != nil {
+		id, ok := d.connIDs[*it.ConnectionCode]
+		if !ok {
+			d.invalid(KindSubscription, it.Code, "unknown connection '"+*it.ConnectionCode+"'")
+			return
+		}
+		connID = &id
+	}
+	var poolID *string
+	if it.DispatchPoolCode != nil {
+		p, ok := d.pools[*it.DispatchPoolCode]
+		if !ok {
+			d.invalid(KindSubscription, it.Code, "unknown dispatch pool '"+*it.DispatchPoolCode+"'")
+			return
+		}
+		poolID = &p.ID
+	}
+	bindings := make([]subscription.EventTypeBinding, 0, len(it.EventTypes))
+	for _, b := range it.EventTypes {
+		if strings.TrimSpace(b.EventTypeCode) == "" {
+			d.invalid(KindSubscription, it.Code, "event type binding code is required")
+			return
+		}
+		binding := subscription.NewEventTypeBinding(b.EventTypeCode)
+		binding.SpecVersion = b.SpecVersion
+		binding.Filter = b.Filter
+		if et, ok := d.eventTypes[b.EventTypeCode]; ok {
+			binding.EventTypeID = &et.ID
+		}
+		bindings = append(bindings, binding)
+	}
+
+	cur := d.subs[it.Code]
+	var next subscription.Subscription
+	var before any
+	if cur != nil {
+		next, before = *cur, subscriptionItem(cur, d.connCodes)
+	} else {
+		next = *subscription.New(it.Code, it.Name, it.Endpoint)
+	}
+	next.ApplicationCode = it.ApplicationCode
+	next.Name = it.Name
+	next.Description = it.Description
+	next.ClientScoped = it.ClientScoped
+	next.EventTypes = bindings
+	next.ConnectionID = connID
+	next.Endpoint = it.Endpoint
+	next.ShadowEndpoint = it.ShadowEndpoint
+	next.TrafficSplit = it.TrafficSplit
+	if next.TrafficSplit == nil {
+		next.TrafficSplit = []subscription.SplitTarget{}
+	}
+	next.Queue = it.Queue
+	next.CustomConfig = it.CustomConfig
+	if next.CustomConfig == nil {
+		next.CustomConfig = []subscription.ConfigEntry{}
+	}
+	next.Headers = it.Headers
+	if next.Headers == nil {
+		next.Headers = []subscription.ConfigEntry{}
+	}
+	next.Transport = it.Transport
+	next.TLS = nil
+	if !it.TLS.IsZero() {
+		next.TLS = it.TLS
+	}
+	next.Delivery = subscription.ParseDeliveryMode(it.Delivery)
+	next.Batching = nil
+	if it.Batching.Enabled() {
+		next.Batching = it.Batching
+	}
+	next.PayloadFormat = subscription.ParsePayloadFormat(it.PayloadFormat)
+	next.LargePayloads = subscription.ParseLargePayloads(it.LargePayloads)
+	next.Source = subscription.ParseSource(it.Source)
+	next.Status = subscription.ParseStatus(it.Status)
+	next.DispatchPoolID = poolID
+	next.DispatchPoolCode = it.DispatchPoolCode
+	next.Mode = common.ParseDispatchMode(it.Mode)
+	next.MaxAgeSeconds = it.MaxAgeSeconds
+	next.DelaySeconds = it.DelaySeconds
+	next.Sequence = it.Sequence
+	next.TimeoutSeconds = it.TimeoutSeconds
+	next.MaxRetries = it.MaxRetries
+	next.DataOnly = it.DataOnly
+
+	if d.record(KindSubscription, it.Code, before, subscriptionItem(&next, d.connCodes)) {
+		d.plan.Subscriptions = append(d.plan.Subscriptions, Write[subscription.Subscription]{Aggregate: &next, Created: cur == nil})
+		d.subs[it.Code] = &next
+	}
+}
+
+// guard applies the deployment checks to a subscription item and returns
+// why it is refused, or "". Bundle subscriptions are platform-level, so
+// endpoints are checked against the deployment's outbound policy. As with
+// UpdateSubscription, a binding the target subscription already has may
+// stay when its event type has since been deprecated.
+func (d *differ) guard(ctx context.Context, it SubscriptionItem) string {
+	if subscription.ParseDeliveryMode(it.Delivery) == subscription.DeliveryPush {
+		if err := d.guards.Targets.CheckURL(nil, it.Endpoint); err != nil {
+			return err.Error()
+		}
+	}
+	if it.ShadowEndpoint != nil && *it.ShadowEndpoint != "" {
+		if err := d.guards.Targets.CheckURL(nil, *it.ShadowEndpoint); err != nil {
+			return "shadowEndpoint: " + err.Error()
+		}
+	}
+	for _, t := range it.TrafficSplit {
+		if err := d.guards.Targets.CheckURL(nil, t.Endpoint); err != nil {
+			return "trafficSplit: " + err.Error()
+		}
+	}
+	if err := d.guards.Transports.Check(it.Transport); err != nil {
+		return err.Error()
+	}
+	var kept []subscription.EventTypeBinding
+	if cur := d.subs[it.Code]; cur != nil {
+		kept = cur.EventTypes
+	}
+	for _, b := range it.EventTypes {
+		if slices.ContainsFunc(kept, func(k subscription.EventTypeBinding) bool { return k.EventTypeCode == b.EventTypeCode }) {
+			continue
+		}
+		l, deprecated, err := d.guards.Lifecycles.Lookup(ctx, b.EventTypeCode)
+		if err != nil {
+			return "cannot check event type lifecycles: " + err.Error()
+		}
+		if deprecated {
+			msg := "event type " + b.EventTypeCode + " is deprecated and takes no new subscriptions"
+			if l.MigrationHint != nil {
+				msg += ": " + *l.MigrationHint
+			}
+			return msg
+		}
+	}
+	return ""
+}
@@ -0,0 +1,302 @@
+package configbundle
+
+import (
+	"context"
+	"encoding/json"
+	"slices"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/role"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+func ptr[T any](v T) *T { return &v }
+
+// populated is a target environment with one of everything, wired to each
+// other the way a real one would be.
+func populated(t *testing.T) *Snapshot {
+	t.Helper()
+	pool := dispatchpool.New("orders-pool", "Orders")
+	et, err := eventtype.New("orders:sales:order:created", "Order created")
+	if err != nil {
+		t.Fatal(err)
+	}
+	sv := eventtype.NewSpecVersion(et.ID, "1.0", json.RawMessage(`{"type":"object","required":["id"]}`))
+	sv.Status = eventtype.SpecCurrent
+	et.SpecVersions = []eventtype.SpecVersion{sv}
+	app := application.New("orders", "Orders")
+	r := role.New("orders", "viewer", "Viewer")
+	r.ApplicationID = &app.ID
+	r.Permissions = []string{"orders:read"}
+	acme := client.New("Acme", "acme")
+	cfg := auth.NewClientAuthConfig("acme.example.com", auth.ConfigClient)
+	cfg.PrimaryClientID = &acme.ID
+	conn := connection.New("orders-webhook", "Orders webhook", "sac_1")
+	sub := subscription.New("orders-sync", "Orders sync", "https://example.com/hook")
+	sub.EventTypes = []subscription.EventTypeBinding{{EventTypeID: &et.ID, EventTypeCode: et.Code}}
+	sub.ConnectionID = &conn.ID
+	sub.DispatchPoolID = &pool.ID
+	sub.DispatchPoolCode = &pool.Code
+
+	return &Snapshot{
+		DispatchPools: []dispatchpool.DispatchPool{*pool},
+		EventTypes:    []eventtype.EventType{*et},
+		Roles:         []role.Role{*r},
+		AuthConfigs:   []auth.ClientAuthConfig{*cfg},
+		Subscriptions: []subscription.Subscription{*sub},
+		Clients:       []client.Client{*acme},
+		Connections:   []connection.Connection{*conn},
+		Applications:  []application.Application{*app},
+	}
+}
+
+func actions(p *Plan) map[string]Action {
+	out := map[string]Action{}
+	for _, it := range p.Items {
+		out[string(it.Kind)+"/"+it.Key] = it.Action
+	}
+	return out
+}
+
+func TestExportRoundTripIsUnchanged(t *testing.T) {
+	snap := populated(t)
+	b := Export(snap)
+
+	// Through JSON, as a real export/import would travel.
+	raw, err := json.Marshal(b)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var decoded Bundle
+	if err := json.Unmarshal(raw, &decoded); err != nil {
+		t.Fatal(err)
+	}
+
+	plan := Diff(context.Background(), &decoded, snap, OnConflictFail, Guards{})
+	if len(plan.Items) != 5 {
+		t.Fatalf("expected 5 items, got %+v", plan.Items)
+	}
+	for _, it := range plan.Items {
+		if it.Action != ActionUnchanged {
+			t.Errorf("%s %s: %s %v %s", it.Kind, it.Key, it.Action, it.Changed, it.Message)
+		}
+	}
+	if len(plan.DispatchPools)+len(plan.EventTypes)+len(plan.Roles)+len(plan.AuthConfigs)+len(plan.Subscriptions) != 0 {
+		t.Error("an unchanged bundle must plan no writes")
+	}
+}
+
+func TestExportOmitsClientOwnedItems(t *testing.T) {
+	snap := populated(t)
+	owned := dispatchpool.New("acme-pool", "Acme")
+	owned.ClientID = &snap.Clients[0].ID
+	snap.DispatchPools = append(snap.DispatchPools, *owned)
+
+	b := Export(snap)
+	if len(b.DispatchPools) != 1 || b.DispatchPools[0].Code != "orders-pool" {
+		t.Errorf("exported pools = %+v", b.DispatchPools)
+	}
+	if b.AuthConfigs[0].PrimaryClient == nil || *b.AuthConfigs[0].PrimaryClient != "acme" {
+		t.Errorf("primary client should export as its identifier, got %v", b.AuthConfigs[0].PrimaryClient)
+	}
+}
+
+func TestDiffResolvesReferencesToItemsCreatedEarlier(t *testing.T) {
+	b := &Bundle{
+		Version:       Version,
+		DispatchPools: []DispatchPoolItem{{Code: "billing-pool", Name: "Billing", Concurrency: 5}},
+		EventTypes:    []EventTypeItem{{Code: "billing:invoices:invoice:issued", Name: "Invoice issued"}},
+		Subscriptions: []SubscriptionItem{{
+			Code:             "billing-sync",
+			Name:             "Billing sync",
+			Endpoint:         "https://example.com/billing",
+			EventTypes:       []BindingItem{{EventTypeCode: "billing:invoices:invoice:issued"}},
+			DispatchPoolCode: ptr("billing-pool"),
+		}},
+	}
+	plan := Diff(context.Background(), b, &Snapshot{}, OnConflictSkip, Guards{})
+
+	for key, a := range actions(plan) {
+		if a != ActionCreate {
+			t.Errorf("%s: got %s, want create", key, a)
+		}
+	}
+	if len(plan.DispatchPools) != 1 || len(plan.EventTypes) != 1 || len(plan.Subscriptions) != 1 {
+		t.Fatalf("writes: %d pools, %d event types, %d subscriptions",
+			len(plan.DispatchPools), len(plan.EventTypes), len(plan.Subscriptions))
+	}
+	sub := plan.Subscriptions[0].Aggregate
+	if sub.DispatchPoolID == nil || *sub.DispatchPoolID != plan.DispatchPools[0].Aggregate.ID {
+		t.Errorf("subscription pool id = %v, want the new pool's id", sub.DispatchPoolID)
+	}
+	if id := sub.EventTypes[0].EventTypeID; id == nil || *id != plan.EventTypes[0].Aggregate.ID {
+		t.Errorf("binding event type id = %v, want the new event type's id", id)
+	}
+}
+
+func TestDiffConflictStrategies(t *testing.T) {
+	bundle := func() *Bundle {
+		b := Export(populated(t))
+		b.DispatchPools[0].Name = "Orders (renamed)"
+		b.DispatchPools[0].Concurrency = 20
+		return b
+	}
+
+	t.Run("skip", func(t *testing.T) {
+		plan := Diff(context.Background(), bundle(), populated(t), OnConflictSkip, Guards{})
+		it := plan.Items[0]
+		if it.Action != ActionConflict || !slices.Equal(it.Changed, []string{"concurrency", "name"}) {
+			t.Errorf("got %+v", it)
+		}
+		if len(plan.DispatchPools) != 0 {
+			t.Error("skip must not write the conflicting pool")
+		}
+	})
+	t.Run("overwrite", func(t *testing.T) {
+		snap := populated(t)
+		plan := Diff(context.Background(), bundle(), snap, OnConflictOverwrite, Guards{})
+		if plan.Items[0].Action != ActionUpdate || len(plan.DispatchPools) != 1 {
+			t.Fatalf("got %+v", plan.Items[0])
+		}
+		w := plan.DispatchPools[0]
+		if w.Created || w.Aggregate.ID != snap.DispatchPools[0].ID || w.Aggregate.Name != "Orders (renamed)" {
+			t.Errorf("update should keep the target's id: %+v", w)
+		}
+		if snap.DispatchPools[0].Name != "Orders" {
+			t.Error("diff must not mutate the snapshot")
+		}
+	})
+	t.Run("fail", func(t *testing.T) {
+		plan := Diff(context.Background(), bundle(), populated(t), OnConflictFail, Guards{})
+		if plan.Count(ActionConflict) != 1 || len(plan.DispatchPools) != 0 {
+			t.Errorf("got %+v", plan.Items)
+		}
+	})
+}
+
+func TestDiffReportsInvalidItems(t *testing.T) {
+	b := &Bundle{
+		Version: Version,
+		DispatchPools: []DispatchPoolItem{
+			{Code: "p", Name: "P", Concurrency: 1},
+			{Code: "p", Name: "P again", Concurrency: 1},
+		},
+		AuthConfigs: []AuthConfigItem{{EmailDomain: "globex.example.com", ConfigType: "CLIENT", PrimaryClient: ptr("globex")}},
+		Subscriptions: []SubscriptionItem{{
+			Code: "s", Name: "S", Endpoint: "https://example.com",
+			EventTypes:       []BindingItem{{EventTypeCode: "a:b:c:d"}},
+			DispatchPoolCode: ptr("missing-pool"),
+		}},
+	}
+	got := actions(Diff(context.Background(), b, &Snapshot{}, OnConflictOverwrite, Guards{}))
+	want := map[string]Action{
+		"dispatchPool/p":                ActionInvalid, // the duplicate; the first is created
+		"authConfig/globex.example.com": ActionInvalid,
+		"subscription/s":                ActionInvalid,
+	}
+	for key, a := range want {
+		if got[key] != a {
+			t.Errorf("%s: got %s, want %s", key, got[key], a)
+		}
+	}
+}
+
+func TestDiffAppliesSubscriptionGuards(t *testing.T) {
+	snap := populated(t)
+	hint := "subscribe to orders:sales:order:placed"
+	g := Guards{
+		Targets:    &outbound.Policies{},
+		Lifecycles: eventtype.NewLifecycleCache(func(context.Context) (map[string]eventtype.Lifecycle, error) {
+			return map[string]eventtype.Lifecycle{
+				"orders:sales:order:created":  {DeprecatedAt: time.Now()},
+				"orders:sales:order:archived": {DeprecatedAt: time.Now(), MigrationHint: &hint},
+			}, nil
+		}, 0),
+	}
+	sub := func(code, endpoint, eventType string) SubscriptionItem {
+		return SubscriptionItem{Code: code, Name: code, Endpoint: endpoint, EventTypes: []BindingItem{{EventTypeCode: eventType}}}
+	}
+	b := Export(snap)
+	b.Subscriptions = append(b.Subscriptions,
+		sub("metadata", "http://169.254.169.254/latest/meta-data/", "orders:sales:order:paid"),
+		sub("nat64", "http://[64:ff9b::a00:1]/", "orders:sales:order:paid"),
+		sub("archived", "https://example.com/hook", "orders:sales:order:archived"),
+	)
+	b.Subscriptions[0].Name = "Renamed" // keeps its binding to the now-deprecated type
+
+	got := actions(Diff(context.Background(), b, snap, OnConflictOverwrite, g))
+	want := map[string]Action{
+		"subscription/orders-sync": ActionUpdate,
+		"subscription/metadata":    ActionInvalid,
+		"subscription/nat64":       ActionInvalid,
+		"subscription/archived":    ActionInvalid,
+	}
+	for key, a := range want {
+		if got[key] != a {
+			t.Errorf("%s: got %s, want %s", key, got[key], a)
+		}
+	}
+}
+
+func TestDiffSchemas(t *testing.T) {
+	snap := populated(t)
+	b := Export(snap)
+	// Same schema, different key order and whitespace: jsonb doesn't keep
+	// either, so this must not read as a change.
+	b.EventTypes[0].Schemas[0].Content = json.RawMessage(`{ "required": ["id"], "type": "object" }`)
+	if a := actions(Diff(context.Background(), b, snap, OnConflictFail, Guards{}))["eventType/orders:sales:order:created"]; a != ActionUnchanged {
+		t.Fatalf("reformatted schema: got %s", a)
+	}
+
+	// A new version merges in; the target's own version is kept.
+	b.EventTypes[0].Schemas = []SchemaItem{{Version: "2.0", Status: "CURRENT", Content: json.RawMessage(`{"type":"object"}`)}}
+	plan := Diff(context.Background(), b, snap, OnConflictOverwrite, Guards{})
+	if len(plan.EventTypes) != 1 {
+		t.Fatalf("got %+v", plan.Items)
+	}
+	var versions []string
+	for _, sv := range plan.EventTypes[0].Aggregate.SpecVersions {
+		versions = append(versions, sv.Version)
+		if sv.EventTypeID != snap.EventTypes[0].ID {
+			t.Errorf("spec version %s belongs to %s", sv.Version, sv.EventTypeID)
+		}
+	}
+	if !slices.Equal(versions, []string{"1.0", "2.0"}) {
+		t.Errorf("versions = %v", versions)
+	}
+}
+
+func TestDiffNeverOverwritesCodeRoles(t *testing.T) {
+	snap := populated(t)
+	snap.Roles[0].Source = role.SourceCode
+	b := Export(snap)
+	b.Roles[0].DisplayName = "Changed"
+
+	plan := Diff(context.Background(), b, snap, OnConflictOverwrite, Guards{})
+	if a := actions(plan)["role/orders:viewer"]; a != ActionConflict {
+		t.Errorf("got %s, want conflict", a)
+	}
+	if len(plan.Roles) != 0 {
+		t.Error("a code-defined role must not be written")
+	}
+}
+
+func TestCheck(t *testing.T) {
+	if err := Check(&Bundle{Version: Version}, OnConflictSkip); err != nil {
+		t.Errorf("valid bundle: %v", err)
+	}
+	if err := Check(&Bundle{Version: Version + 1}, OnConflictSkip); err == nil {
+		t.Error("expected an unsupported version to be rejected")
+	}
+	if err := Check(&Bundle{Version: Version}, "merge"); err == nil {
+		t.Error("expected an unknown strategy to be rejected")
+	}
+}
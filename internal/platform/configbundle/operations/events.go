@@ -0,0 +1,51 @@
+// Package operations holds the configuration bundle use cases.
+package operations
+
+import (
+	"encoding/json"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+const (
+	ConfigBundleImportedType = "platform:admin:config-bundle:imported"
+	Source                   = "platform:admin"
+
+	subject = "platform.configbundle"
+	group   = "platform:configbundle"
+)
+
+// ConfigBundleImported is the rollup emitted once per applied import, after
+// the per-aggregate created/updated events it wrote.
+type ConfigBundleImported struct {
+	Metadata   usecase.EventMetadata
+	Version    int
+	OnConflict string
+	Created    uint32
+	Updated    uint32
+	Unchanged  uint32
+	Conflicts  uint32
+}
+
+func (e ConfigBundleImported) EventID() string       { return e.Metadata.EventID }
+func (e ConfigBundleImported) EventType() string     { return ConfigBundleImportedType }
+func (e ConfigBundleImported) SpecVersion() string   { return "1.0" }
+func (e ConfigBundleImported) Source() string        { return Source }
+func (e ConfigBundleImported) Subject() string       { return subject }
+func (e ConfigBundleImported) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e ConfigBundleImported) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e ConfigBundleImported) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e ConfigBundleImported) CausationID() string   { return e.Metadata.CausationID }
+func (e ConfigBundleImported) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e ConfigBundleImported) MessageGroup() string  { return group }
+func (e ConfigBundleImported) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		Version    int    `json:"version"`
+		OnConflict string `json:"onConflict"`
+		Created    uint32 `json:"created"`
+		Updated    uint32 `json:"updated"`
+		Unchanged  uint32 `json:"unchanged"`
+		Conflicts  uint32 `json:"conflicts"`
+	}{e.Version, e.OnConflict, e.Created, e.Updated, e.Unchanged, e.Conflicts})
+}
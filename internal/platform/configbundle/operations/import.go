@@ -0,0 +1,160 @@
+package operations
+
+import (
+	"context"
+	"fmt"
+
+	authops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+	poolops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool/operations"
+	eventtypeops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype/operations"
+	roleops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/role/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	subscriptionops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// ImportCommand is the input DTO. The bundle itself is excluded from JSON
+// so the audit log records the strategy, not a copy of the configuration
+// (the per-aggregate events already describe what changed).
+type ImportCommand struct {
+	Bundle     *configbundle.Bundle  `json:"-"`
+	OnConflict configbundle.Strategy `json:"onConflict"`
+}
+
+// ImportBundle applies a configuration bundle in one transaction: it diffs
+// the bundle against the current configuration (configbundle.Diff), then
+// writes dispatch pools, event types, roles, auth configs and subscriptions
+// in that order, each with its regular created/updated event, and emits one
+// [ConfigBundleImported] rollup.
+//
+// Nothing is written when any item is invalid — including a subscription
+// guards refuse — or when strategy is fail and any item conflicts.
+// Anchor-only: a bundle spans every application.
+func ImportBundle(repos configbundle.Repos, guards configbundle.Guards) usecaseop.TxOperation[ImportCommand, *configbundle.Plan] {
+	return usecaseop.TxOperation[ImportCommand, *configbundle.Plan]{
+		Name: "ImportConfigBundle",
+		Validate: func(_ context.Context, cmd ImportCommand) error {
+			if cmd.Bundle == nil {
+				return usecase.Validation("BUNDLE_REQUIRED", "bundle is required")
+			}
+			return configbundle.Check(cmd.Bundle, cmd.OnConflict)
+		},
+		Authorize: func(ctx context.Context, _ ImportCommand) error {
+			return auth.RequireAnchor(auth.FromContext(ctx))
+		},
+		Execute: func(ctx context.Context, s *usecasepgx.TxScopedUnitOfWork, cmd ImportCommand, ec usecase.ExecutionContext) (*configbundle.Plan, error) {
+			snap, err := repos.Load(ctx)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "load configuration failed", err)
+			}
+			plan := configbundle.Diff(ctx, cmd.Bundle, snap, cmd.OnConflict, guards)
+			if n := plan.Count(configbundle.ActionInvalid); n > 0 {
+				first := firstWith(plan, configbundle.ActionInvalid)
+				return nil, usecase.Validation("INVALID_BUNDLE", fmt.Sprintf(
+					"%d bundle item(s) are invalid, e.g. %s '%s': %s. Import with dryRun=true for the full report.",
+					n, first.Kind, first.Key, first.Message))
+			}
+			if n := plan.Count(configbundle.ActionConflict); n > 0 && cmd.OnConflict == configbundle.OnConflictFail {
+				first := firstWith(plan, configbundle.ActionConflict)
+				return nil, usecase.Conflict("BUNDLE_CONFLICT", fmt.Sprintf(
+					"%d bundle item(s) differ from the existing configuration, e.g. %s '%s'. Import with dryRun=true for the full report.",
+					n, first.Kind, first.Key))
+			}
+
+			for _, w := range plan.DispatchPools {
+				p := w.Aggregate
+				if w.Created {
+					err = commit(ctx, s, p, repos.DispatchPools, poolops.NewDispatchPoolCreatedEvent(ec, p.ID, p.Code, p.Name, p.ClientID), cmd)
+				} else {
+					err = commit(ctx, s, p, repos.DispatchPools, poolops.NewDispatchPoolUpdatedEvent(ec, p.ID, p.Name, p.ClientID), cmd)
+				}
+				if err != nil {
+					return nil, err
+				}
+			}
+			for _, w := range plan.EventTypes {
+				et := w.Aggregate
+				if w.Created {
+					err = commit(ctx, s, et, repos.EventTypes, eventtypeops.NewEventTypeCreatedEvent(ec, et), cmd)
+				} else {
+					err = commit(ctx, s, et, repos.EventTypes, eventtypeops.NewEventTypeUpdatedEvent(ec, et), cmd)
+				}
+				if err != nil {
+					return nil, err
+				}
+			}
+			for _, w := range plan.Roles {
+				r := w.Aggregate
+				if w.Created {
+					err = commit(ctx, s, r, repos.Roles, roleops.NewRoleCreatedEvent(ec, r.ID, r.Name), cmd)
+				} else {
+					err = commit(ctx, s, r, repos.Roles, roleops.NewRoleUpdatedEvent(ec, r.ID, r.Name), cmd)
+				}
+				if err != nil {
+					return nil, err
+				}
+			}
+			for _, w := range plan.AuthConfigs {
+				c := w.Aggregate
+				if w.Created {
+					err = commit(ctx, s, c, repos.AuthConfigs, authops.NewAuthConfigCreatedEvent(ec, c.ID, c.EmailDomain), cmd)
+				} else {
+					err = commit(ctx, s, c, repos.AuthConfigs, authops.NewAuthConfigUpdatedEvent(ec, c.ID, c.EmailDomain), cmd)
+				}
+				if err != nil {
+					return nil, err
+				}
+			}
+			for _, w := range plan.Subscriptions {
+				sub := w.Aggregate
+				if w.Created {
+					err = commit(ctx, s, sub, repos.Subscriptions, subscriptionops.NewSubscriptionCreatedEvent(ec, sub.ID, sub.Code, sub.Name, sub.ClientID), cmd)
+				} else {
+					err = commit(ctx, s, sub, repos.Subscriptions, subscriptionops.NewSubscriptionUpdatedEvent(ec, sub.ID, sub.Name, sub.ClientID), cmd)
+				}
+				if err != nil {
+					return nil, err
+				}
+			}
+
+			rollup := ConfigBundleImported{
+				Metadata:   usecase.NewEventMetadata(ec, ConfigBundleImportedType, Source, subject),
+				Version:    cmd.Bundle.Version,
+				OnConflict: string(cmd.OnConflict),
+				Created:    uint32(plan.Count(configbundle.ActionCreate)),
+				Updated:    uint32(plan.Count(configbundle.ActionUpdate)),
+				Unchanged:  uint32(plan.Count(configbundle.ActionUnchanged)),
+				Conflicts:  uint32(plan.Count(configbundle.ActionConflict)),
+			}
+			if r := usecasepgx.EmitEventScoped(ctx, s, rollup, cmd); !usecase.IsSuccess(r) {
+				_, e := usecase.Into(r)
+				return nil, e
+			}
+			return plan, nil
+		},
+	}
+}
+
+// commit writes one aggregate with its event and audit row inside the
+// import's transaction.
+func commit[A usecase.HasID, E usecase.DomainEvent](
+	ctx context.Context, s *usecasepgx.TxScopedUnitOfWork, aggregate *A, repo usecasepgx.Persist[A], event E, cmd ImportCommand,
+) error {
+	if r := usecasepgx.CommitScoped(ctx, s, aggregate, repo, event, cmd); !usecase.IsSuccess(r) {
+		_, e := usecase.Into(r)
+		return e
+	}
+	return nil
+}
+
+func firstWith(plan *configbundle.Plan, a configbundle.Action) configbundle.ItemResult {
+	for _, it := range plan.Items {
+		if it.Action == a {
+			return it
+		}
+	}
+	return configbundle.ItemResult{}
+}
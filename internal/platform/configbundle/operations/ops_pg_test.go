@@ -0,0 +1,129 @@
+//go:build integration
+
+package operations_test
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/role"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+func TestMain(m *testing.M) { testpg.RunMain(m) }
+
+func ptr(s string) *string { return &s }
+
+func repos(t *testing.T) configbundle.Repos {
+	pool := testpg.Pool(t)
+	return configbundle.Repos{
+		DispatchPools: dispatchpool.NewRepository(pool),
+		EventTypes:    eventtype.NewRepository(pool),
+		Roles:         role.NewRepository(pool),
+		AuthConfigs:   auth.NewRepository(pool).ClientAuthConfigs,
+		Subscriptions: subscription.NewRepository(pool),
+		Clients:       client.NewRepository(pool),
+		Connections:   connection.NewRepository(pool),
+		Applications:  application.NewRepository(pool),
+	}
+}
+
+func runImport(t *testing.T, r configbundle.Repos, b *configbundle.Bundle, strategy configbundle.Strategy) (*configbundle.Plan, error) {
+	t.Helper()
+	return usecaseop.RunTx(testpg.AnchorCtx(), testpg.NewUoW(t), operations.ImportBundle(r, configbundle.Guards{}),
+		operations.ImportCommand{Bundle: b, OnConflict: strategy}, testpg.TestEC())
+}
+
+// bundle builds a small bundle whose subscription depends on a pool and an
+// event type introduced by the same bundle. Codes are hand-unique per test:
+// the fixture never truncates between tests.
+func bundle(prefix string) *configbundle.Bundle {
+	code := prefix + ":sales:order:created"
+	return &configbundle.Bundle{
+		Version:       configbundle.Version,
+		DispatchPools: []configbundle.DispatchPoolItem{{Code: prefix + "-pool", Name: "Pool", Concurrency: 4}},
+		EventTypes:    []configbundle.EventTypeItem{{Code: code, Name: "Order created"}},
+		Roles: []configbundle.RoleItem{{
+			Name: prefix + ":viewer", ApplicationCode: prefix, DisplayName: "Viewer", Permissions: []string{prefix + ":read"},
+		}},
+		Subscriptions: []configbundle.SubscriptionItem{{
+			Code: prefix + "-sync", Name: "Sync", Endpoint: "https://example.com/hook",
+			EventTypes:       []configbundle.BindingItem{{EventTypeCode: code}},
+			DispatchPoolCode: ptr(prefix + "-pool"),
+			MaxAgeSeconds:    86400, Sequence: 99, TimeoutSeconds: 30, MaxRetries: 3, DataOnly: true,
+		}},
+	}
+}
+
+func TestImportBundle_CreatesThenIsIdempotent(t *testing.T) {
+	ctx := context.Background()
+	r := repos(t)
+
+	plan, err := runImport(t, r, bundle("cbimp"), configbundle.OnConflictSkip)
+	require.NoError(t, err)
+	assert.Equal(t, 4, plan.Count(configbundle.ActionCreate), "items: %+v", plan.Items)
+
+	pool, err := r.DispatchPools.FindByCode(ctx, "cbimp-pool", nil)
+	require.NoError(t, err)
+	require.NotNil(t, pool)
+	sub, err := r.Subscriptions.FindByCode(ctx, "cbimp-sync", nil)
+	require.NoError(t, err)
+	require.NotNil(t, sub)
+	require.NotNil(t, sub.DispatchPoolID)
+	assert.Equal(t, pool.ID, *sub.DispatchPoolID, "subscription must point at the pool created in the same import")
+
+	again, err := runImport(t, r, bundle("cbimp"), configbundle.OnConflictFail)
+	require.NoError(t, err)
+	assert.Equal(t, 4, again.Count(configbundle.ActionUnchanged), "items: %+v", again.Items)
+}
+
+func TestImportBundle_FailStrategyWritesNothing(t *testing.T) {
+	ctx := context.Background()
+	r := repos(t)
+	_, err := runImport(t, r, bundle("cbfail"), configbundle.OnConflictSkip)
+	require.NoError(t, err)
+
+	b := bundle("cbfail")
+	b.DispatchPools[0].Concurrency = 8
+	b.DispatchPools = append(b.DispatchPools, configbundle.DispatchPoolItem{Code: "cbfail-extra", Name: "Extra", Concurrency: 1})
+	_, err = runImport(t, r, b, configbundle.OnConflictFail)
+	testpg.RequireUsecaseError(t, err, usecase.KindConflict, "BUNDLE_CONFLICT")
+
+	extra, err := r.DispatchPools.FindByCode(ctx, "cbfail-extra", nil)
+	require.NoError(t, err)
+	assert.Nil(t, extra, "a rejected import must not create anything")
+
+	plan, err := runImport(t, r, b, configbundle.OnConflictOverwrite)
+	require.NoError(t, err)
+	assert.Equal(t, 1, plan.Count(configbundle.ActionUpdate))
+	pool, err := r.DispatchPools.FindByCode(ctx, "cbfail-pool", nil)
+	require.NoError(t, err)
+	assert.Equal(t, int32(8), pool.Concurrency)
+}
+
+func TestImportBundle_InvalidItemRejectsImport(t *testing.T) {
+	b := bundle("cbinv")
+	b.Subscriptions[0].DispatchPoolCode = ptr("cbinv-missing")
+	_, err := runImport(t, repos(t), b, configbundle.OnConflictSkip)
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_BUNDLE")
+}
+
+func TestImportBundle_RequiresAnchor(t *testing.T) {
+	_, err := usecaseop.RunTx(context.Background(), testpg.NewUoW(t), operations.ImportBundle(repos(t), configbundle.Guards{}),
+		operations.ImportCommand{Bundle: bundle("cbauth"), OnConflict: configbundle.OnConflictSkip}, testpg.TestEC())
+	testpg.RequireUsecaseError(t, err, usecase.KindAuthorization, "UNAUTHENTICATED")
+}
@@ -77,6 +77,7 @@ func CreateConnection(repo *connection.Repository) usecaseop.Operation[CreateCom
 				ConnectionID: c.ID,
 				Code:         c.Code,
 				Name:         c.Name,
+				ClientID:     c.ClientID,
 			}
 			return usecaseop.Save(c, repo, event), nil
 		},
@@ -44,6 +44,7 @@ func DeleteConnection(repo *connection.Repository) usecaseop.Operation[DeleteCom
 				Metadata:     usecase.NewEventMetadata(ec, ConnectionDeletedType, Source, subjectFor(c.ID)),
 				ConnectionID: c.ID,
 				Code:         c.Code,
+				ClientID:     c.ClientID,
 			}
 			return usecaseop.Delete(c, repo, event), nil
 		},
@@ -17,12 +17,22 @@ const (
 func subjectFor(id string) string { return "platform.connection." + id }
 func groupFor(id string) string   { return "platform:connection:" + id }
 
+// owners scopes the public copy of an event (see publicevents) to the
+// client the connection belongs to; a platform-wide connection has none.
+func owners(clientID *string) []string {
+	if clientID == nil {
+		return nil
+	}
+	return []string{*clientID}
+}
+
 // ConnectionCreated event.
 type ConnectionCreated struct {
 	Metadata     usecase.EventMetadata
 	ConnectionID string
 	Code         string
 	Name         string
+	ClientID     *string
 }
 
 func (e ConnectionCreated) EventID() string       { return e.Metadata.EventID }
@@ -44,11 +54,14 @@ func (e ConnectionCreated) ToDataJSON() ([]byte, error) {
 	}{e.ConnectionID, e.Code, e.Name})
 }
 
+func (e ConnectionCreated) OwningClientIDs() []string { return owners(e.ClientID) }
+
 // ConnectionUpdated event.
 type ConnectionUpdated struct {
 	Metadata     usecase.EventMetadata
 	ConnectionID string
 	Name         string
+	ClientID     *string
 }
 
 func (e ConnectionUpdated) EventID() string       { return e.Metadata.EventID }
@@ -69,11 +82,14 @@ func (e ConnectionUpdated) ToDataJSON() ([]byte, error) {
 	}{e.ConnectionID, e.Name})
 }
 
+func (e ConnectionUpdated) OwningClientIDs() []string { return owners(e.ClientID) }
+
 // ConnectionDeleted event.
 type ConnectionDeleted struct {
 	Metadata     usecase.EventMetadata
 	ConnectionID string
 	Code         string
+	ClientID     *string
 }
 
 func (e ConnectionDeleted) EventID() string       { return e.Metadata.EventID }
@@ -93,3 +109,5 @@ func (e ConnectionDeleted) ToDataJSON() ([]byte, error) {
 		Code         string `json:"code"`
 	}{e.ConnectionID, e.Code})
 }
+
+func (e ConnectionDeleted) OwningClientIDs() []string { return owners(e.ClientID) }
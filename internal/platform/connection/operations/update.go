@@ -58,6 +58,7 @@ func UpdateConnection(repo *connection.Repository) usecaseop.Operation[UpdateCom
 				Metadata:     usecase.NewEventMetadata(ec, ConnectionUpdatedType, Source, subjectFor(c.ID)),
 				ConnectionID: c.ID,
 				Name:         c.Name,
+				ClientID:     c.ClientID,
 			}
 			return usecaseop.Save(c, repo, event), nil
 		},
@@ -108,6 +109,7 @@ func flipStatus(
 				Metadata:     usecase.NewEventMetadata(ec, ConnectionUpdatedType, Source, subjectFor(c.ID)),
 				ConnectionID: c.ID,
 				Name:         c.Name,
+				ClientID:     c.ClientID,
 			}
 			return usecaseop.Save(c, repo, event), nil
 		},
@@ -3,23 +3,43 @@ package api
 
 import (
 	"context"
+	"errors"
+	"log/slog"
 	"net/http"
 	"strings"
 	"time"
 
 	"github.com/danielgtaylor/huma/v2"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/dispatchcancel"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/processing"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/redact"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 )
 
 // State bundles deps.
 type State struct {
 	Repo *dispatchjob.Repository
+	// Redact masks the source event type's redact fields in returned
+	// payloads. Optional: nil returns payloads unredacted.
+	Redact *redact.Rules
+	// Cancels receives the tombstones of cancelled jobs so the router can
+	// drop their queued copies. Optional: nil records none.
+	Cancels dispatchcancel.Store
+	// Acks settles deliveries their receivers acknowledge asynchronously,
+	// and pulled jobs. Optional: nil refuses every ack as not awaited.
+	Acks *processing.Acks
+	// Pull and Subscriptions serve GET /api/consume. Optional: nil Pull
+	// refuses every consume request.
+	Pull          *processing.Pull
+	Subscriptions *subscription.Repository
 }
 
 const (
@@ -39,6 +59,11 @@ func Register(api huma.API, s *State) {
 	apiroute.Get(g, "getDispatchJobRaw", "/api/dispatch-jobs/{id}/raw", "Get a dispatch job (raw)", s.getRaw)
 	apiroute.Get(g, "listDispatchJobAttempts", "/api/dispatch-jobs/{id}/attempts", "List a dispatch job's attempt history", s.attempts)
 	apiroute.Post(g, "requeueDispatchJobs", "/api/dispatch-jobs/requeue", "Reset dispatch jobs to PENDING for re-dispatch", http.StatusOK, s.requeue)
+	apiroute.Post(g, "cancelDispatchJobs", "/api/dispatch-jobs/cancel", "Cancel dispatch jobs, including copies already queued", http.StatusOK, s.cancel)
+	apiroute.Post(g, "ackDispatchJob", "/api/dispatch-jobs/{id}/ack", "Acknowledge a delivery accepted for asynchronous processing", http.StatusOK, s.ack)
+	apiroute.Post(g, "nackDispatchJob", "/api/dispatch-jobs/{id}/nack", "Reject a delivery accepted for asynchronous processing", http.StatusOK, s.nack)
+
+	registerConsume(api, s)
 
 	// SDK-compatibility aliases. The Laravel/Rust client addresses these as
 	// /api/dispatch-jobs/by-event/{eventId} and the collection-level
@@ -71,6 +96,7 @@ func registerBFF(api huma.API, s *State, base, opPrefix, tag string) {
 	apiroute.Get(g, "getDispatchJobRaw"+opPrefix, base+"/{id}/raw", "Get a dispatch job with raw row", s.getRaw)
 	apiroute.Get(g, "listDispatchJobAttempts"+opPrefix, base+"/{id}/attempts", "List a dispatch job's attempt history", s.attempts)
 	apiroute.Post(g, "requeueDispatchJobs"+opPrefix, base+"/requeue", "Reset dispatch jobs to PENDING for re-dispatch", http.StatusOK, s.requeue)
+	apiroute.Post(g, "cancelDispatchJobs"+opPrefix, base+"/cancel", "Cancel dispatch jobs, including copies already queued", http.StatusOK, s.cancel)
 }
 
 type listInput struct {
@@ -94,6 +120,8 @@ type listInput struct {
 	Aggregates   string `query:"aggregates" doc:"CSV of aggregates"`
 	Codes        string `query:"codes" doc:"CSV of codes"`
 	Source       string `query:"source" doc:"Free-text source filter"`
+
+	apicommon.CursorQuery
 }
 
 // splitCSV mirrors Rust's split_csv (dispatch_job/api.rs): trim, drop empties.
@@ -161,34 +189,66 @@ func scopeFilters(ac *auth.AuthContext, f dispatchjob.FilterParams) dispatchjob.
 	return f
 }
 
+// find runs the list query in the mode the caller asked for (see
+// apicommon.CursorQuery): the keyset page plus the next cursor, or the
+// legacy offset page with no cursor.
+func (s *State) find(ctx context.Context, ac *auth.AuthContext, in *listInput) ([]dispatchjob.DispatchJob, string, error) {
+	f := scopeFilters(ac, in.toFilters())
+	if in.OffsetMode(in.Offset) {
+		rows, err := s.Repo.FindWithFilters(ctx, f)
+		if err != nil {
+			return nil, "", usecase.Internal("REPO", "find_with_filters failed", err)
+		}
+		return rows, "", nil
+	}
+	var after string
+	if in.After != "" {
+		parts, err := apicommon.DecodeCursor(in.After, 1)
+		if err != nil {
+			return nil, "", usecase.Validation("CURSOR", "invalid cursor")
+		}
+		after = parts[0]
+	}
+	limit := f.Limit
+	if limit <= 0 || limit > 1000 {
+		limit = 100
+	}
+	f.Limit = limit + 1
+	rows, err := s.Repo.FindWithCursor(ctx, f, after)
+	if err != nil {
+		return nil, "", usecase.Internal("REPO", "find_with_cursor failed", err)
+	}
+	page, next := apicommon.CursorPage(rows, limit, func(j *dispatchjob.DispatchJob) []string { return []string{j.ID} })
+	return page, next, nil
+}
+
 // list's Body is a bare JSON array — the SPA's DispatchJobListPage binds
 // the returned array directly to its DataTable, so {items:[...]} would
 // render zero rows. Mirrors Rust's list_dispatch_jobs returning
-// Vec<DispatchJobReadResponse>. (Shared by listRaw + byEvent.)
-func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[[]DispatchJobRead], error) {
+// Vec<DispatchJobReadResponse>; the next-page cursor travels in the
+// X-Next-Cursor header. (listRaw shares the shape.)
+func (s *State) list(ctx context.Context, in *listInput) (*apicommon.CursorOut[DispatchJobRead], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanWritePermission(ac, viewPerm); err != nil {
 		return nil, err
 	}
-	rows, err := s.Repo.FindWithFilters(ctx, scopeFilters(ac, in.toFilters()))
+	rows, next, err := s.find(ctx, ac, in)
 	if err != nil {
-		return nil, usecase.Internal("REPO", "find_with_filters failed", err)
+		return nil, err
 	}
-	out := apicommon.MapSlice(rows, readFromEntity)
-	return &apicommon.Out[[]DispatchJobRead]{Body: out}, nil
+	return &apicommon.CursorOut[DispatchJobRead]{NextCursor: next, Body: apicommon.MapSlice(rows, readFromEntity)}, nil
 }
 
-func (s *State) listRaw(ctx context.Context, in *listInput) (*apicommon.Out[[]DispatchJobRead], error) {
+func (s *State) listRaw(ctx context.Context, in *listInput) (*apicommon.CursorOut[DispatchJobRead], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanWritePermission(ac, viewRawPerm); err != nil {
 		return nil, err
 	}
-	rows, err := s.Repo.FindWithFilters(ctx, scopeFilters(ac, in.toFilters()))
+	rows, next, err := s.find(ctx, ac, in)
 	if err != nil {
-		return nil, usecase.Internal("REPO", "find_raw failed", err)
+		return nil, err
 	}
-	out := apicommon.MapSlice(rows, readFromEntity)
-	return &apicommon.Out[[]DispatchJobRead]{Body: out}, nil
+	return &apicommon.CursorOut[DispatchJobRead]{NextCursor: next, Body: apicommon.MapSlice(rows, readFromEntity)}, nil
 }
 
 // ── debug raw dispatch jobs ──────────────────────────────────────────────
@@ -235,6 +295,9 @@ func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.
 	if err := auth.CheckScopeAccess(ac, j.ClientID); err != nil { // A2: per-resource client scope
 		return nil, err
 	}
+	if err := s.redactPayload(ctx, j); err != nil {
+		return nil, err
+	}
 	return &apicommon.Out[DispatchJobResponse]{Body: fromEntity(j)}, nil
 }
 
@@ -253,9 +316,27 @@ func (s *State) getRaw(ctx context.Context, in *apicommon.IDInput) (*apicommon.O
 	if err := auth.CheckScopeAccess(ac, j.ClientID); err != nil { // A2: per-resource client scope
 		return nil, err
 	}
+	if err := s.redactPayload(ctx, j); err != nil {
+		return nil, err
+	}
 	return &apicommon.Out[DispatchJobResponse]{Body: fromEntity(j)}, nil
 }
 
+// redactPayload masks an event job's payload per the event type's data
+// policy. The repository copy is a fresh value, so the stored payload and
+// the delivery path are unaffected.
+func (s *State) redactPayload(ctx context.Context, j *dispatchjob.DispatchJob) error {
+	if j.Kind != dispatchjob.KindEvent {
+		return nil
+	}
+	p, err := s.Redact.String(ctx, j.Code, j.Payload)
+	if err != nil {
+		return usecase.Internal("REDACT", "load redaction rules failed", err)
+	}
+	j.Payload = p
+	return nil
+}
+
 // attempts' Body is a bare JSON array — the Rust shape for
 // GET /api/dispatch-jobs/{id}/attempts.
 func (s *State) attempts(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[[]AttemptDTO], error) {
@@ -345,9 +426,150 @@ func (s *State) requeue(ctx context.Context, in *apicommon.In[RequeueRequest]) (
 	if err != nil {
 		return nil, usecase.Internal("REPO", "requeue failed", err)
 	}
+	// A requeued job that was cancelled must not be dropped by the router.
+	// An id Requeue skipped (out of scope) only loses its tombstone: its
+	// row stays CANCELLED, so the processing callback still drops it.
+	if s.Cancels != nil && n > 0 {
+		if err := s.Cancels.Clear(ctx, in.Body.IDs); err != nil {
+			return nil, usecase.Internal("CANCELS", "clear cancellation tombstones failed", err)
+		}
+	}
 	return &apicommon.Out[RequeueResponse]{Body: RequeueResponse{Requeued: n}}, nil
 }
 
+// CancelRequest is the body of POST /dispatch-jobs/cancel.
+type CancelRequest struct {
+	IDs []string `json:"ids" doc:"Dispatch job ids to cancel"`
+}
+
+// CancelResponse lists the jobs actually cancelled; ids that were already
+// terminal or outside the caller's clients are omitted.
+type CancelResponse struct {
+	Cancelled []string `json:"cancelled"`
+}
+
+// cancel flips the given jobs to CANCELLED, which the scheduler never
+// re-dispatches, then tombstones them so the router ACKs any copy already
+// sitting in a queue without delivering it. Scoped and permissioned like
+// requeue.
+//
+// The row update is what cancels: if the tombstone write fails, the
+// processing callback still ACKs a queued copy without delivering it on
+// seeing the terminal status — the tombstone only saves that round-trip —
+// so the failure is logged rather than returned.
+func (s *State) cancel(ctx context.Context, in *apicommon.In[CancelRequest]) (*apicommon.Out[CancelResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanWritePermission(ac, viewPerm); err != nil {
+		return nil, err
+	}
+	var scope *[]string
+	if !ac.IsAnchor() {
+		clients := ac.Clients
+		scope = &clients
+	}
+	ids, err := s.Repo.Cancel(ctx, in.Body.IDs, scope)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "cancel failed", err)
+	}
+	if s.Cancels != nil {
+		if err := s.Cancels.Mark(ctx, ids); err != nil {
+			slog.Warn("write dispatch cancellation tombstones failed", "count", len(ids), "err", err)
+		}
+	}
+	if ids == nil {
+		ids = []string{}
+	}
+	return &apicommon.Out[CancelResponse]{Body: CancelResponse{Cancelled: ids}}, nil
+}
+
+// AckRequest is the body of POST /dispatch-jobs/{id}/ack.
+type AckRequest struct {
+	AckToken string `json:"ackToken" doc:"Token the receiver returned with its 202"`
+}
+
+// NackRequest is the body of POST /dispatch-jobs/{id}/nack.
+type NackRequest struct {
+	AckToken string `json:"ackToken" doc:"Token the receiver returned with its 202"`
+	Error    string `json:"error,omitempty" doc:"Why processing failed; recorded as the job's last error"`
+}
+
+// AckResponse is the job's status once settled: COMPLETED after an ack;
+// PENDING (retry scheduled) or FAILED (retries exhausted) after a nack.
+type AckResponse struct {
+	ID     string `json:"id"`
+	Status string `json:"status"`
+}
+
+type ackInput struct {
+	ID   string `path:"id"`
+	Body AckRequest
+}
+
+type nackInput struct {
+	ID   string `path:"id"`
+	Body NackRequest
+}
+
+// ack completes a delivery its receiver accepted with 202 and an ack
+// token. Scoped and permissioned like cancel.
+func (s *State) ack(ctx context.Context, in *ackInput) (*apicommon.Out[AckResponse], error) {
+	if strings.TrimSpace(in.Body.AckToken) == "" {
+		return nil, httperror.BadRequest("VALIDATION", "ackToken is required")
+	}
+	return s.settle(ctx, in.ID, "", func(a *processing.Acks) (common.DispatchStatus, error) {
+		return a.Ack(ctx, in.ID, in.Body.AckToken)
+	})
+}
+
+// nack fails such a delivery's attempt: it is retried, or FAILED on its
+// last attempt.
+func (s *State) nack(ctx context.Context, in *nackInput) (*apicommon.Out[AckResponse], error) {
+	if strings.TrimSpace(in.Body.AckToken) == "" {
+		return nil, httperror.BadRequest("VALIDATION", "ackToken is required")
+	}
+	reason := nackReason(in.Body.Error)
+	return s.settle(ctx, in.ID, "", func(a *processing.Acks) (common.DispatchStatus, error) {
+		return a.Nack(ctx, in.ID, in.Body.AckToken, reason)
+	})
+}
+
+func nackReason(e string) string {
+	if reason := strings.TrimSpace(e); reason != "" {
+		return reason
+	}
+	return "rejected by consumer"
+}
+
+// settle applies an ack or nack to job id once the caller may touch it.
+// A non-empty subscriptionID also requires the job to belong to it.
+func (s *State) settle(ctx context.Context, id, subscriptionID string, apply func(*processing.Acks) (common.DispatchStatus, error)) (*apicommon.Out[AckResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanWritePermission(ac, viewPerm); err != nil {
+		return nil, err
+	}
+	j, err := s.Repo.FindByID(ctx, id)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if j == nil || (subscriptionID != "" && (j.SubscriptionID == nil || *j.SubscriptionID != subscriptionID)) {
+		return nil, httperror.NotFound("DispatchJob", id)
+	}
+	if err := auth.CheckScopeAccess(ac, j.ClientID); err != nil {
+		return nil, err
+	}
+	if s.Acks == nil {
+		return nil, usecase.Conflict("NOT_AWAITING_ACK", processing.ErrNotAwaitingAck.Error())
+	}
+	status, err := apply(s.Acks)
+	if errors.Is(err, processing.ErrNotAwaitingAck) {
+		return nil, usecase.Conflict("NOT_AWAITING_ACK", err.Error())
+	}
+	if err != nil {
+		return nil, usecase.Internal("REPO", "settle ack failed", err)
+	}
+	return &apicommon.Out[AckResponse]{Body: AckResponse{ID: id, Status: string(status)}}, nil
+}
+
 func (s *State) filterOptions(ctx context.Context, _ *apicommon.Empty) (*apicommon.Out[DispatchJobFilterOptionsResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanWritePermission(ac, viewPerm); err != nil {
@@ -0,0 +1,192 @@
+package api
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"strings"
+	"time"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/processing"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+// registerConsume mounts pull delivery: a PULL subscription's consumer
+// leases its dispatch jobs here instead of receiving webhooks, then acks
+// or nacks each one. See processing/pull.go.
+func registerConsume(api huma.API, s *State) {
+	g := apiroute.New(api, "consume")
+	apiroute.Get(g, "consumeSubscription", "/api/consume/{subscriptionId}", "Lease a pull subscription's due messages, waiting for one if asked", s.consume)
+	apiroute.Post(g, "ackConsumedMessage", "/api/consume/{subscriptionId}/ack", "Acknowledge a leased message", http.StatusOK, s.consumeAck)
+	apiroute.Post(g, "nackConsumedMessage", "/api/consume/{subscriptionId}/nack", "Reject a leased message; it is retried like a failed delivery", http.StatusOK, s.consumeNack)
+}
+
+type consumeInput struct {
+	SubscriptionID string `path:"subscriptionId"`
+	Wait           string `query:"wait" doc:"How long to wait when no message is due, e.g. 30s (at most 1m). Default: answer at once"`
+	Max            int    `query:"max" doc:"Most messages to lease (default 10, max 100)"`
+	Lease          string `query:"lease" doc:"How long the messages stay leased before they count as failed, e.g. 5m (default 1m, at most 24h)"`
+}
+
+// ConsumedMessage is one leased dispatch job.
+type ConsumedMessage struct {
+	ID             string          `json:"id" doc:"Dispatch job id"`
+	LeaseToken     string          `json:"leaseToken" doc:"Presented with the ack or nack"`
+	LeaseExpiresAt httpcompat.Time `json:"leaseExpiresAt" doc:"Unsettled by then, the message counts as failed"`
+	Type           string          `json:"type" doc:"Event type code"`
+	EventID        *string         `json:"eventId,omitempty"`
+	AttemptNumber  int32           `json:"attemptNumber"`
+	Body           json.RawMessage `json:"body" doc:"What a push subscription's webhook would receive; a non-JSON payload arrives as a JSON string"`
+}
+
+// ConsumeResponse is the body of GET /api/consume/{subscriptionId}.
+type ConsumeResponse struct {
+	Messages []ConsumedMessage `json:"messages"`
+}
+
+// ConsumeAckRequest is the body of POST /api/consume/{subscriptionId}/ack.
+type ConsumeAckRequest struct {
+	ID         string `json:"id" doc:"Dispatch job id of the message"`
+	LeaseToken string `json:"leaseToken"`
+}
+
+// ConsumeNackRequest is the body of POST /api/consume/{subscriptionId}/nack.
+type ConsumeNackRequest struct {
+	ID         string `json:"id" doc:"Dispatch job id of the message"`
+	LeaseToken string `json:"leaseToken"`
+	Error      string `json:"error,omitempty" doc:"Why processing failed; recorded as the job's last error"`
+}
+
+type consumeAckInput struct {
+	SubscriptionID string `path:"subscriptionId"`
+	Body           ConsumeAckRequest
+}
+
+type consumeNackInput struct {
+	SubscriptionID string `path:"subscriptionId"`
+	Body           ConsumeNackRequest
+}
+
+// consume leases the subscription's due messages to its consumer. Scoped
+// to the subscription's client; permissioned like the dispatch-job acks.
+func (s *State) consume(ctx context.Context, in *consumeInput) (*apicommon.Out[ConsumeResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanWritePermission(ac, viewPerm); err != nil {
+		return nil, err
+	}
+	wait, err := durationParam("wait", in.Wait, 0, processing.MaxPullWait)
+	if err != nil {
+		return nil, err
+	}
+	lease, err := durationParam("lease", in.Lease, processing.DefaultPullLease, processing.MaxPullLease)
+	if err != nil {
+		return nil, err
+	}
+	limit := in.Max
+	if limit <= 0 {
+		limit = processing.DefaultPullBatch
+	}
+	limit = min(limit, processing.MaxPullBatch)
+
+	sub, err := s.pullSubscription(ctx, ac, in.SubscriptionID)
+	if err != nil {
+		return nil, err
+	}
+	l, err := s.Pull.Lease(ctx, sub.ID, sub.ClientID, limit, lease, wait)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "lease failed", err)
+	}
+	return &apicommon.Out[ConsumeResponse]{Body: ConsumeResponse{Messages: consumedMessages(l)}}, nil
+}
+
+// pullSubscription loads a PULL subscription the caller may consume.
+func (s *State) pullSubscription(ctx context.Context, ac *auth.AuthContext, id string) (*subscription.Subscription, error) {
+	if s.Pull == nil || s.Subscriptions == nil {
+		return nil, usecase.Conflict("PULL_DISABLED", "pull delivery is not enabled on this server")
+	}
+	sub, err := s.Subscriptions.FindByID(ctx, id)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if sub == nil {
+		return nil, httperror.NotFound("Subscription", id)
+	}
+	if err := auth.CheckScopeAccess(ac, sub.ClientID); err != nil {
+		return nil, err
+	}
+	if sub.Delivery != subscription.DeliveryPull {
+		return nil, usecase.Conflict("NOT_PULL_SUBSCRIPTION", "subscription '"+sub.Code+"' delivers by webhook")
+	}
+	return sub, nil
+}
+
+func consumedMessages(l *processing.Lease) []ConsumedMessage {
+	out := make([]ConsumedMessage, 0, len(l.Jobs))
+	for _, j := range l.Jobs {
+		out = append(out, ConsumedMessage{
+			ID:             j.Job.ID,
+			LeaseToken:     l.Token,
+			LeaseExpiresAt: jsontime.New(l.Deadline),
+			Type:           j.Job.Code,
+			EventID:        j.Job.EventID,
+			AttemptNumber:  j.Job.AttemptCount + 1,
+			Body:           consumedBody(j.Body),
+		})
+	}
+	return out
+}
+
+// consumeAck completes a leased message.
+func (s *State) consumeAck(ctx context.Context, in *consumeAckInput) (*apicommon.Out[AckResponse], error) {
+	if strings.TrimSpace(in.Body.ID) == "" || strings.TrimSpace(in.Body.LeaseToken) == "" {
+		return nil, httperror.BadRequest("VALIDATION", "id and leaseToken are required")
+	}
+	return s.settle(ctx, in.Body.ID, in.SubscriptionID, func(a *processing.Acks) (common.DispatchStatus, error) {
+		return a.Ack(ctx, in.Body.ID, in.Body.LeaseToken)
+	})
+}
+
+// consumeNack fails a leased message's attempt: it is retried after the
+// usual backoff, or FAILED on its last attempt.
+func (s *State) consumeNack(ctx context.Context, in *consumeNackInput) (*apicommon.Out[AckResponse], error) {
+	if strings.TrimSpace(in.Body.ID) == "" || strings.TrimSpace(in.Body.LeaseToken) == "" {
+		return nil, httperror.BadRequest("VALIDATION", "id and leaseToken are required")
+	}
+	reason := nackReason(in.Body.Error)
+	return s.settle(ctx, in.Body.ID, in.SubscriptionID, func(a *processing.Acks) (common.DispatchStatus, error) {
+		return a.Nack(ctx, in.Body.ID, in.Body.LeaseToken, reason)
+	})
+}
+
+// consumedBody embeds a JSON body as is and quotes anything else, so a
+// data-only job with a non-JSON payload still yields a valid response.
+func consumedBody(b []byte) json.RawMessage {
+	if json.Valid(b) {
+		return b
+	}
+	quoted, _ := json.Marshal(string(b))
+	return quoted
+}
+
+// durationParam parses a Go duration query parameter ("30s", "5m"); ""
+// yields def. Values above maxD are clamped.
+func durationParam(name, v string, def, maxD time.Duration) (time.Duration, error) {
+	if v == "" {
+		return def, nil
+	}
+	d, err := time.ParseDuration(v)
+	if err != nil || d < 0 {
+		return 0, httperror.BadRequest("VALIDATION", name+" must be a duration such as 30s")
+	}
+	return min(d, maxD), nil
+}
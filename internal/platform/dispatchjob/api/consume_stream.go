@@ -0,0 +1,146 @@
+package api
+
+import (
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/processing"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+)
+
+const (
+	// consumeStreamPoll is how often a stream looks for due jobs.
+	consumeStreamPoll = time.Second
+	// consumeStreamHeartbeat is how often an idle stream sends an SSE
+	// comment so proxies and load balancers don't reap the connection.
+	consumeStreamHeartbeat = 15 * time.Second
+)
+
+// MountConsumeStream registers GET /api/consume/{subscriptionId}/stream,
+// a Server-Sent Events feed of a PULL subscription's messages. Mounted on
+// the chi router inside the authenticated group because huma operations
+// are request/response JSON.
+//
+// The first frame is `event: open` with the stream's cursor. Each message
+// is then one `event: message` frame whose data is a ConsumedMessage and
+// whose id is `<cursor>:<job id>`. The consumer acks or nacks it through
+// POST /api/consume/{subscriptionId}/ack or /nack with the cursor as the
+// lease token. ?window= caps the unacked messages in flight (default 10,
+// max 100): the stream sends nothing more until acks make room. ?lease=
+// is each message's lease, as for GET /api/consume. Reconnecting with the
+// cursor (?cursor=, or the Last-Event-ID header EventSource sends) first
+// re-sends the messages still unacked, so delivery is at least once.
+func MountConsumeStream(r chi.Router, s *State) {
+	r.Get("/api/consume/{subscriptionId}/stream", s.consumeStream)
+}
+
+func (s *State) consumeStream(w http.ResponseWriter, r *http.Request) {
+	ctx := r.Context()
+	ac := auth.FromContext(ctx)
+	if err := auth.CanWritePermission(ac, viewPerm); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	q := r.URL.Query()
+	lease, err := durationParam("lease", q.Get("lease"), processing.DefaultPullLease, processing.MaxPullLease)
+	if err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	window := processing.DefaultStreamWindow
+	if v := q.Get("window"); v != "" {
+		n, err := strconv.Atoi(v)
+		if err != nil || n <= 0 {
+			httperror.Write(w, httperror.BadRequest("VALIDATION", "window must be a positive integer"))
+			return
+		}
+		window = min(n, processing.MaxPullBatch)
+	}
+	cursor := q.Get("cursor")
+	if cursor == "" {
+		// EventSource resends the last frame's id: "<cursor>:<job id>".
+		cursor, _, _ = strings.Cut(r.Header.Get("Last-Event-ID"), ":")
+	}
+	if len(cursor) > 255 {
+		httperror.Write(w, httperror.BadRequest("VALIDATION", "cursor is too long"))
+		return
+	}
+	sub, err := s.pullSubscription(ctx, ac, chi.URLParam(r, "subscriptionId"))
+	if err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	stream, resumed, err := s.Pull.OpenStream(ctx, sub.ID, sub.ClientID, cursor, window, lease)
+	if err != nil {
+		slog.Error("consume stream: open failed", "subscription_id", sub.ID, "err", err)
+		httperror.Write(w, err)
+		return
+	}
+
+	rc := http.NewResponseController(w)
+	// The stream outlives any server-wide write timeout.
+	_ = rc.SetWriteDeadline(time.Time{})
+	w.Header().Set("Content-Type", "text/event-stream")
+	w.Header().Set("Cache-Control", "no-cache")
+	w.Header().Set("X-Accel-Buffering", "no")
+	w.WriteHeader(http.StatusOK)
+	open, _ := json.Marshal(map[string]any{"cursor": stream.Token, "window": window})
+	if _, err := fmt.Fprintf(w, "event: open\ndata: %s\n\n", open); err != nil {
+		return
+	}
+	if !writeMessages(w, resumed) || rc.Flush() != nil {
+		return
+	}
+
+	poll := time.NewTicker(consumeStreamPoll)
+	defer poll.Stop()
+	heartbeat := time.NewTicker(consumeStreamHeartbeat)
+	defer heartbeat.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-heartbeat.C:
+			if _, err := fmt.Fprint(w, ": ping\n\n"); err != nil {
+				return
+			}
+		case <-poll.C:
+			l, err := stream.Next(ctx)
+			if err != nil {
+				if ctx.Err() == nil {
+					slog.Warn("consume stream: lease failed", "subscription_id", sub.ID, "err", err)
+				}
+				continue
+			}
+			if !writeMessages(w, l) {
+				return
+			}
+		}
+		if rc.Flush() != nil {
+			return
+		}
+	}
+}
+
+// writeMessages writes one `event: message` frame per leased job; false
+// once the connection is gone.
+func writeMessages(w http.ResponseWriter, l *processing.Lease) bool {
+	for _, m := range consumedMessages(l) {
+		data, err := json.Marshal(m)
+		if err != nil {
+			continue
+		}
+		if _, err := fmt.Fprintf(w, "event: message\nid: %s:%s\ndata: %s\n\n", l.Token, m.ID, data); err != nil {
+			return false
+		}
+	}
+	return true
+}
@@ -166,23 +166,28 @@ func fromEntity(j *dispatchjob.DispatchJob) DispatchJobResponse {
 // endpoints (GET /api/dispatch-jobs, /bff/dispatch-jobs). Matches the
 // SPA's `DispatchJobRead` interface (frontend/src/api/dispatch-jobs.ts):
 // the list grid binds id/code/source/status/mode/targetUrl/createdAt, and
-// the interface also carries the projection facets. Mirrors Rust's
+// the interface also carries the projection facets and the display names
+// msg_dispatch_jobs_read denormalizes (nil when unknown). Mirrors Rust's
 // DispatchJobReadResponse (dispatch_job/api.rs:104).
 type DispatchJobRead struct {
 	ID               string           `json:"id"`
 	EventID          *string          `json:"eventId,omitempty"`
 	SubscriptionID   *string          `json:"subscriptionId,omitempty"`
+	SubscriptionName *string          `json:"subscriptionName,omitempty"`
 	ClientID         *string          `json:"clientId,omitempty"`
+	ClientName       *string          `json:"clientName,omitempty"`
 	ClientIdentifier *string          `json:"clientIdentifier,omitempty"`
 	Application      *string          `json:"application,omitempty"`
 	Subdomain        *string          `json:"subdomain,omitempty"`
 	Aggregate        *string          `json:"aggregate,omitempty"`
 	Code             string           `json:"code"`
+	EventTypeName    *string          `json:"eventTypeName,omitempty"`
 	Source           *string          `json:"source,omitempty"`
 	Subject          *string          `json:"subject,omitempty"`
 	Status           string           `json:"status"`
 	Kind             string           `json:"kind"`
 	TargetURL        string           `json:"targetUrl"`
+	TargetHost       *string          `json:"targetHost,omitempty"`
 	Mode             string           `json:"mode"`
 	DispatchMode     *string          `json:"dispatchMode,omitempty"`
 	Priority         *int32           `json:"priority,omitempty"`
@@ -205,7 +210,7 @@ func readFromEntity(j *dispatchjob.DispatchJob) DispatchJobRead {
 	}
 	mode := string(j.Mode)
 	app, sub, agg := splitCode(j.Code)
-	return DispatchJobRead{
+	out := DispatchJobRead{
 		ID:             j.ID,
 		EventID:        j.EventID,
 		SubscriptionID: j.SubscriptionID,
@@ -229,6 +234,13 @@ func readFromEntity(j *dispatchjob.DispatchJob) DispatchJobRead {
 		LastAttemptAt:  tp(j.LastAttemptAt),
 		AttemptCount:   j.AttemptCount,
 	}
+	if n := j.Names; n != nil {
+		out.SubscriptionName = n.SubscriptionName
+		out.ClientName = n.ClientName
+		out.EventTypeName = n.EventTypeName
+		out.TargetHost = n.TargetHost
+	}
+	return out
 }
 
 // splitCode derives application/subdomain/aggregate from the colon-
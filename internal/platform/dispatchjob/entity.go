@@ -34,10 +34,17 @@ func ParseKind(s string) Kind {
 	return KindEvent
 }
 
-// Protocol identifies the delivery transport. Currently HTTP_WEBHOOK only.
+// Protocol identifies the delivery transport.
 type Protocol string
 
-const ProtocolHTTPWebhook Protocol = "HTTP_WEBHOOK"
+const (
+	// ProtocolHTTPWebhook is pushed to the target URL by the scheduler.
+	ProtocolHTTPWebhook Protocol = "HTTP_WEBHOOK"
+	// ProtocolPull waits, PENDING, for its subscription's consumer to
+	// lease it through GET /api/consume/{subscriptionId}. The scheduler
+	// never dispatches it.
+	ProtocolPull Protocol = "PULL"
+)
 
 // RetryStrategy controls backoff between attempts.
 type RetryStrategy string
@@ -178,6 +185,22 @@ type DispatchJob struct {
 	LastAttemptAt      *time.Time            `json:"lastAttemptAt,omitempty"`
 	CompletedAt        *time.Time            `json:"completedAt,omitempty"`
 	DurationMillis     *int64                `json:"durationMillis,omitempty"`
+	// AckDeadline is set while the job's receiver holds it for an
+	// asynchronous ack (a 202 with an ack token); read from msg_dispatch_jobs
+	// only.
+	AckDeadline *time.Time `json:"ackDeadline,omitempty"`
+	// Names is set only on jobs read from msg_dispatch_jobs_read.
+	Names *ReadNames `json:"-"`
+}
+
+// ReadNames are the display values msg_dispatch_jobs_read denormalizes
+// next to a job's ids (migration 049). Nil fields are unknown: the source
+// row is gone, or the sync has not reached the job yet.
+type ReadNames struct {
+	SubscriptionName *string
+	ClientName       *string
+	EventTypeName    *string
+	TargetHost       *string
 }
 
 // PayloadJSON returns the payload parsed as JSON when ContentType is
@@ -0,0 +1,158 @@
+package processing
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"log/slog"
+	"net/http"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+)
+
+// Consumer-managed acknowledgement. A receiver that needs longer than the
+// delivery timeout answers 202 with {"ackToken": "...", "ackTimeoutSeconds": n}.
+// The job stays PROCESSING under that token until the consumer settles it
+// through POST /api/dispatch-jobs/{id}/ack or /nack, presenting the token.
+// A deadline that passes counts as a nack, so the job is retried — or
+// fails, on its last attempt. A 202 without a token is a plain success.
+
+const (
+	// defaultAckTimeout applies when the 202 names no ackTimeoutSeconds.
+	defaultAckTimeout = 15 * time.Minute
+	// maxAckTimeout caps what a receiver may ask for.
+	maxAckTimeout = 24 * time.Hour
+	// maxAckTokenLen is the width of msg_dispatch_jobs.ack_token.
+	maxAckTokenLen = 255
+	// ackExpiryBatch bounds the expired acks settled per sweep.
+	ackExpiryBatch = 100
+	// ackTimeoutError is the last_error of a job whose ack never came.
+	ackTimeoutError = "acknowledgement timed out"
+)
+
+// ErrNotAwaitingAck is returned when a job isn't awaiting an ack under the
+// presented token: it was never accepted asynchronously, was already
+// settled or cancelled, or timed out and has been re-attempted since.
+var ErrNotAwaitingAck = errors.New("dispatch job is not awaiting an acknowledgement with this token")
+
+// parseAckRequest reports a 202 body carrying an ack token as a promise to
+// acknowledge later, with the deadline the receiver asked for.
+func parseAckRequest(status int, body []byte) (string, time.Duration, bool) {
+	if status != http.StatusAccepted || len(body) == 0 {
+		return "", 0, false
+	}
+	var r struct {
+		AckToken          string  `json:"ackToken"`
+		AckTimeoutSeconds *uint32 `json:"ackTimeoutSeconds"`
+	}
+	if err := json.Unmarshal(body, &r); err != nil || strings.TrimSpace(r.AckToken) == "" {
+		return "", 0, false
+	}
+	timeout := defaultAckTimeout
+	if r.AckTimeoutSeconds != nil && *r.AckTimeoutSeconds > 0 {
+		timeout = min(time.Duration(*r.AckTimeoutSeconds)*time.Second, maxAckTimeout)
+	}
+	return r.AckToken, timeout, true
+}
+
+// Acks settles deliveries their receivers acknowledge asynchronously.
+type Acks struct {
+	repo     *dispatchjob.Repository
+	receipts Receipts
+}
+
+// NewAcks wires the settler. receipts may be nil: no receipts are sent.
+func NewAcks(repo *dispatchjob.Repository, receipts Receipts) *Acks {
+	return &Acks{repo: repo, receipts: receipts}
+}
+
+// Ack completes the job accepted under token.
+func (a *Acks) Ack(ctx context.Context, jobID, token string) (common.DispatchStatus, error) {
+	return a.settle(ctx, jobID, token, nil)
+}
+
+// Nack fails the attempt accepted under token. Like a failed delivery, the
+// job is retried after the usual backoff, or FAILED on its last attempt.
+func (a *Acks) Nack(ctx context.Context, jobID, token, reason string) (common.DispatchStatus, error) {
+	return a.settle(ctx, jobID, token, &reason)
+}
+
+// settle records the outcome of an asynchronously acknowledged attempt:
+// success when failure is nil. The update is conditional on the token, so
+// racing settlements — an ack against the expiry sweep — apply once.
+func (a *Acks) settle(ctx context.Context, jobID, token string, failure *string) (common.DispatchStatus, error) {
+	job, err := a.repo.FindByID(ctx, jobID)
+	if err != nil {
+		return "", err
+	}
+	if job == nil {
+		return "", ErrNotAwaitingAck
+	}
+	attemptNumber := job.AttemptCount + 1
+	var status common.DispatchStatus
+	var settled bool
+	switch {
+	case failure == nil:
+		status = common.DispatchCompleted
+		settled, err = a.repo.CompleteAck(ctx, jobID, token, status, nil)
+	case int(attemptNumber) >= int(job.MaxRetries):
+		status = common.DispatchFailed
+		settled, err = a.repo.CompleteAck(ctx, jobID, token, status, failure)
+	default:
+		status = common.DispatchPending
+		settled, err = a.repo.RetryAck(ctx, jobID, token, time.Now().Add(backoffFor(attemptNumber)), failure)
+	}
+	if err != nil {
+		return "", err
+	}
+	if !settled {
+		return "", ErrNotAwaitingAck
+	}
+	if status.IsTerminal() {
+		sendReceipt(ctx, a.receipts, job, status, attemptNumber, failure)
+	}
+	return status, nil
+}
+
+// ExpireAcks nacks the jobs whose ack deadline has passed and returns how
+// many it settled. One acked or nacked meanwhile is skipped.
+func (a *Acks) ExpireAcks(ctx context.Context) (int, error) {
+	expired, err := a.repo.ExpiredAcks(ctx, ackExpiryBatch)
+	if err != nil {
+		return 0, err
+	}
+	n := 0
+	for _, e := range expired {
+		_, err := a.Nack(ctx, e.ID, e.Token, ackTimeoutError)
+		if errors.Is(err, ErrNotAwaitingAck) {
+			continue
+		}
+		if err != nil {
+			return n, err
+		}
+		n++
+	}
+	return n, nil
+}
+
+// RunExpiry sweeps for expired acks every interval until ctx is cancelled.
+// Safe on every replica: each expired ack is settled once.
+func (a *Acks) RunExpiry(ctx context.Context, interval time.Duration) {
+	tick := time.NewTicker(interval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-tick.C:
+			if n, err := a.ExpireAcks(ctx); err != nil {
+				slog.Warn("dispatch ack expiry failed", "err", err)
+			} else if n > 0 {
+				slog.Info("dispatch acks timed out", "count", n)
+			}
+		}
+	}
+}
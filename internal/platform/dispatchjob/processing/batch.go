@@ -0,0 +1,348 @@
+package processing
+
+import (
+	"bytes"
+	"context"
+	"crypto/rand"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+// Delivery batching. When a subscription batches (subscription.Batching),
+// the queued copy of any one of its jobs — the leader — carries the others
+// along: serve claims up to MaxItems-1 more of the subscription's due jobs
+// for the same target and POSTs them all as one batch — the leader first,
+// then the others in sequence then creation order — in the subscription's
+// payload format (format.go): for JSON, an array whose elements are the
+// bodies the jobs would have had on their own. A claimed job waits
+// PROCESSING under a batch token, so its own queued copy is dropped as a
+// duplicate and a crash mid-delivery leaves it to the ack expiry sweep
+// (ack.go).
+//
+// The receiver settles each job on its own by answering 2xx with
+//
+//	{"results": [{"id": "<job id>", "status": 200},
+//	             {"id": "<job id>", "status": 500, "error": "..."}]}
+//
+// where status reads as an HTTP status: 2xx completes the job, 429 defers
+// it (delaySeconds, default 30) and anything else fails its attempt. A job
+// the results leave out, and every job of a response without results,
+// takes the response's own outcome — a 202 with an ack token included.
+
+const (
+	// batchTokenPrefix marks the ack_token of a job claimed for a batch.
+	batchTokenPrefix = "batch:"
+	// batchClaimMargin pads a claim's deadline past the delivery timeout,
+	// so the expiry sweep only collects jobs a crashed batch left behind.
+	batchClaimMargin = time.Minute
+	// defaultItemDelay applies to a deferred item naming no delaySeconds.
+	defaultItemDelay = 30 * time.Second
+)
+
+// BatchSource supplies a subscription's batching limits, nil when it
+// delivers one job at a time. Satisfied by *subscription.Repository.
+type BatchSource interface {
+	BatchingFor(ctx context.Context, subscriptionID string) (*subscription.Batching, error)
+}
+
+// SetBatching delivers batching subscriptions' jobs in batches. Without
+// it every job is delivered alone.
+func (h *Handler) SetBatching(src BatchSource) { h.batching = src }
+
+// batchingFor returns the batching job's delivery follows, or nil to
+// deliver it alone. Ordered modes are never batched: a batch would let a
+// group's later jobs overtake a failing one.
+func (h *Handler) batchingFor(ctx context.Context, job *dispatchjob.DispatchJob) *subscription.Batching {
+	if h.batching == nil || job.SubscriptionID == nil || job.Protocol != dispatchjob.ProtocolHTTPWebhook || job.Mode.RequiresOrdering() {
+		return nil
+	}
+	b, err := h.batching.BatchingFor(ctx, *job.SubscriptionID)
+	if err != nil {
+		slog.Warn("dispatch process: load batching failed; delivering alone", "job_id", job.ID, "err", err)
+		return nil
+	}
+	if !b.Enabled() {
+		return nil
+	}
+	return b
+}
+
+// serveBatch delivers leader together with the due jobs it claims, then
+// advances each job on its own result. A batch short of MaxItems instead
+// waits, rescheduled, until its oldest job has waited MaxWaitSeconds.
+// Returns the message for the router's ack; an error means nothing was
+// delivered.
+func (h *Handler) serveBatch(ctx context.Context, leader *dispatchjob.DispatchJob, b *subscription.Batching) (string, error) {
+	token := batchTokenPrefix + rand.Text()
+	deadline := time.Now().Add(jobTimeout(leader) + batchClaimMargin)
+	ids, err := h.repo.ClaimBatch(ctx, leader.ID, *leader.SubscriptionID, leader.TargetURL, token, deadline, int(b.MaxItems)-1)
+	if err != nil {
+		return "", fmt.Errorf("claim: %w", err)
+	}
+	jobs := []*dispatchjob.DispatchJob{leader}
+	for _, id := range ids {
+		j, err := h.repo.FindByID(ctx, id)
+		if err != nil {
+			h.release(ctx, ids, token, time.Now())
+			return "", fmt.Errorf("load %s: %w", id, err)
+		}
+		if j != nil {
+			jobs = append(jobs, j)
+		}
+	}
+	others := jobs[1:]
+	sort.Slice(others, func(i, j int) bool {
+		if others[i].Sequence != others[j].Sequence {
+			return others[i].Sequence < others[j].Sequence
+		}
+		return others[i].CreatedAt.Before(others[j].CreatedAt)
+	})
+
+	if len(jobs) < int(b.MaxItems) && b.MaxWaitSeconds > 0 {
+		oldest := leader.CreatedAt
+		for _, j := range others {
+			if j.CreatedAt.Before(oldest) {
+				oldest = j.CreatedAt
+			}
+		}
+		if due := oldest.Add(time.Duration(b.MaxWaitSeconds) * time.Second); time.Now().Before(due) {
+			// All come due together, so whichever copy the poller
+			// dispatches first collects the rest.
+			h.release(ctx, ids, token, due)
+			if err := h.repo.Reschedule(ctx, leader.ID, due); err != nil {
+				return "", fmt.Errorf("wait: %w", err)
+			}
+			slog.Debug("dispatch batch waiting to fill", "job_id", leader.ID, "size", len(jobs), "until", due)
+			return "batch waiting", nil
+		}
+	}
+
+	// A job that can't be encoded stays out of the body and fails its
+	// attempt with the others' settling.
+	enc := h.encoderFor(ctx, leader)
+	unsent := map[string]deliveryResult{}
+	var sent []*dispatchjob.DispatchJob
+	var items [][]byte
+	for _, j := range jobs {
+		payload, err := h.resolvePayload(ctx, j, enc.format)
+		if err != nil {
+			unsent[j.ID] = deliveryResult{errMessage: "offloaded payload: " + err.Error(), errType: dispatchjob.ErrorConnection}
+			continue
+		}
+		item, err := enc.item(ctx, payload)
+		if err != nil {
+			unsent[j.ID] = deliveryResult{errMessage: "encode payload: " + err.Error(), errType: dispatchjob.ErrorValidation}
+			continue
+		}
+		sent = append(sent, j)
+		items = append(items, item)
+	}
+	if n := batchFit(items, int(b.MaxBytes), enc.array()); n < len(sent) {
+		// Over the byte cap: the rest go back for the next batch.
+		rest := make([]string, 0, len(sent)-n)
+		over := map[string]bool{}
+		for _, j := range sent[n:] {
+			rest = append(rest, j.ID)
+			over[j.ID] = true
+		}
+		h.release(ctx, rest, token, time.Now())
+		sent, items = sent[:n], items[:n]
+		kept := jobs[:0]
+		for _, j := range jobs {
+			if !over[j.ID] {
+				kept = append(kept, j)
+			}
+		}
+		jobs = kept
+	}
+
+	if err := h.repo.MarkInProgress(ctx, leader.ID); err != nil {
+		slog.Warn("dispatch process: mark in-progress failed", "job_id", leader.ID, "err", err)
+	}
+	attempts := make([]*dispatchjob.Attempt, len(jobs))
+	for i, j := range jobs {
+		attempts[i] = dispatchjob.NewAttempt(j.AttemptCount + 1)
+	}
+	var res deliveryResult
+	results := map[string]batchItemResult{}
+	if len(sent) > 0 {
+		body, contentType := enc.batch(items)
+		hdr := batchHeaders(sent, contentType)
+		if len(sent) == 1 {
+			h.setSchemaHeaders(ctx, sent[0], hdr)
+		}
+		res = h.post(ctx, sent[0], body, hdr)
+		if res.success && res.ackToken == "" && res.body != nil {
+			results = parseBatchResults([]byte(*res.body))
+		}
+	}
+
+	// Settle only the jobs still claimed: one cancelled meanwhile stays
+	// cancelled.
+	claimed := map[string]bool{leader.ID: true}
+	if len(jobs) > 1 {
+		siblings := make([]string, 0, len(jobs)-1)
+		for _, j := range jobs[1:] {
+			siblings = append(siblings, j.ID)
+		}
+		still, err := h.repo.EndBatchClaim(ctx, siblings, token)
+		if err != nil {
+			slog.Warn("dispatch process: end batch claim failed; the expiry sweep settles the batch", "job_id", leader.ID, "err", err)
+		}
+		for _, id := range still {
+			claimed[id] = true
+		}
+	}
+	for i, j := range jobs {
+		if !claimed[j.ID] {
+			continue
+		}
+		r := res
+		if u, ok := unsent[j.ID]; ok {
+			r = u
+		} else if item, ok := results[j.ID]; ok {
+			r = itemOutcome(res, item)
+		}
+		attempt := attempts[i]
+		if r.success {
+			attempt.CompleteSuccess(r.statusCode, r.body)
+		} else {
+			attempt.CompleteFailure(r.errMessage, r.errType, r.statusCodePtr())
+		}
+		if err := h.repo.RecordAttempt(ctx, j.ID, attempt); err != nil {
+			slog.Warn("dispatch process: record attempt failed", "job_id", j.ID, "err", err)
+		}
+		h.advance(ctx, j, attempt.AttemptNumber, r, attempt)
+	}
+	slog.Debug("dispatch batch delivered", "job_id", leader.ID, "size", len(sent), "status", res.statusCode)
+	return "batch of " + strconv.Itoa(len(sent)), nil
+}
+
+// release puts claimed jobs back to PENDING for scheduledFor; a failure
+// leaves them to the expiry sweep, which retries them.
+func (h *Handler) release(ctx context.Context, ids []string, token string, scheduledFor time.Time) {
+	if len(ids) == 0 {
+		return
+	}
+	if err := h.repo.ReleaseBatch(ctx, ids, token, scheduledFor); err != nil {
+		slog.Warn("dispatch process: release batch failed", "count", len(ids), "err", err)
+	}
+}
+
+// batchHeaders are a batch's platform headers. A batch of one is
+// indistinguishable from an unbatched delivery but for the size header
+// and, for a non-JSON format, the batch encoding; its schema headers are
+// added by the caller.
+func batchHeaders(jobs []*dispatchjob.DispatchJob, contentType string) http.Header {
+	ids := make([]string, len(jobs))
+	for i, j := range jobs {
+		ids[i] = j.ID
+	}
+	h := http.Header{
+		"Content-Type":          {contentType},
+		"X-Dispatch-Batch-Size": {strconv.Itoa(len(jobs))},
+		"X-Dispatch-Job-Ids":    {strings.Join(ids, ",")},
+	}
+	if len(jobs) == 1 {
+		h.Set("X-Dispatch-Job-Id", jobs[0].ID)
+		h.Set("X-Event-Type", jobs[0].Code)
+	}
+	return h
+}
+
+// batchItem embeds a JSON body as is and quotes anything else, so a
+// data-only job with a non-JSON payload still yields a valid array.
+func batchItem(body []byte) []byte {
+	if json.Valid(body) {
+		return body
+	}
+	quoted, _ := json.Marshal(string(body))
+	return quoted
+}
+
+// batchFit returns how many leading items fit in a maxBytes batch, a JSON
+// array when array is set and the items back to back otherwise; 0 means
+// no cap. At least one always fits.
+func batchFit(items [][]byte, maxBytes int, array bool) int {
+	if maxBytes <= 0 {
+		return len(items)
+	}
+	size, sep := 0, 0
+	if array {
+		size, sep = 1, 1 // "[", then "," or "]"
+	}
+	for i, it := range items {
+		size += len(it) + sep
+		if size > maxBytes && i > 0 {
+			return i
+		}
+	}
+	return len(items)
+}
+
+// batchBody joins items into a JSON array.
+func batchBody(items [][]byte) []byte {
+	return append(append([]byte{'['}, bytes.Join(items, []byte{','})...), ']')
+}
+
+// batchItemResult is one job's entry in a batch response's results.
+type batchItemResult struct {
+	ID           string  `json:"id"`
+	Status       int     `json:"status"`
+	Error        string  `json:"error"`
+	DelaySeconds *uint32 `json:"delaySeconds"`
+}
+
+// parseBatchResults reads the per-job results of a 2xx batch response,
+// keyed by job id; empty when the body reports none.
+func parseBatchResults(body []byte) map[string]batchItemResult {
+	var r struct {
+		Results []batchItemResult `json:"results"`
+	}
+	out := map[string]batchItemResult{}
+	if len(body) == 0 || json.Unmarshal(body, &r) != nil {
+		return out
+	}
+	for _, it := range r.Results {
+		if it.ID != "" {
+			out[it.ID] = it
+		}
+	}
+	return out
+}
+
+// itemOutcome is one job's result within a successful batch delivery res.
+func itemOutcome(res deliveryResult, item batchItemResult) deliveryResult {
+	switch {
+	case item.Status >= 200 && item.Status < 300:
+		return res
+	case item.Status == http.StatusTooManyRequests:
+		r := res
+		r.success = false
+		r.deferral = true
+		r.retryAfter = defaultItemDelay
+		if item.DelaySeconds != nil {
+			r.retryAfter = time.Duration(*item.DelaySeconds) * time.Second
+		}
+		r.errMessage = "subscriber deferred item (429)"
+		return r
+	default:
+		r := res
+		r.success = false
+		r.errMessage = fmt.Sprintf("item status %d", item.Status)
+		if item.Error != "" {
+			r.errMessage += ": " + item.Error
+		}
+		r.errType = dispatchjob.ErrorHTTPError
+		return r
+	}
+}
@@ -0,0 +1,100 @@
+package processing
+
+import (
+	"context"
+	"log/slog"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+// Offloaded payloads. An event whose data exceeded FC_BLOB_THRESHOLD_BYTES
+// was stored in the blob store at ingestion, and its jobs carry the
+// {"$blobRef": …} placeholder as their payload. Just before encoding, the
+// placeholder is replaced per the subscription's largePayloads:
+//
+//	INLINE         the payload, read back and checked against its sha256.
+//	PRESIGNED_URL  the placeholder with a pre-signed "url" and "expiresAt"
+//	               added. Stores that cannot pre-sign (file://) and
+//	               PROTOBUF deliveries, which need the data to transcode,
+//	               get the payload inline instead.
+//
+// A payload that cannot be read fails the attempt as a connection error,
+// so the retry reads again. Pull consumers always get the payload inline.
+
+// LargePayloadSource supplies how a subscription receives offloaded
+// payloads. Satisfied by *subscription.Repository.
+type LargePayloadSource interface {
+	LargePayloadsFor(ctx context.Context, subscriptionID string) (subscription.LargePayloads, error)
+}
+
+// SetBlobs resolves offloaded payloads from blobs before delivery, per
+// prefs. Without it an offloaded payload fails every attempt.
+func (h *Handler) SetBlobs(blobs *blobstore.Offloader, prefs LargePayloadSource) {
+	h.blobs = blobs
+	h.largePayloads = prefs
+}
+
+// SetBlobs rehydrates offloaded payloads from blobs.
+func (p *Pull) SetBlobs(blobs *blobstore.Offloader) { p.blobs = blobs }
+
+// resolvePayload returns job ready to encode in format: with an offloaded
+// payload replaced as described above, otherwise job itself.
+func (h *Handler) resolvePayload(ctx context.Context, job *dispatchjob.DispatchJob, format subscription.PayloadFormat) (*dispatchjob.DispatchJob, error) {
+	ref, ok := payloadRef(job)
+	if !ok {
+		return job, nil
+	}
+	if format != subscription.FormatProtobuf && h.presigned(ctx, job) {
+		signed, ok, err := h.blobs.Presign(ctx, ref)
+		if err != nil {
+			return nil, err
+		}
+		if ok {
+			return withPayload(job, signed.JSON()), nil
+		}
+	}
+	return rehydrate(ctx, h.blobs, job, ref)
+}
+
+// presigned reports whether job's subscription takes pre-signed URLs;
+// false when the lookup fails, as inline delivery works for everyone.
+func (h *Handler) presigned(ctx context.Context, job *dispatchjob.DispatchJob) bool {
+	if h.largePayloads == nil || job.SubscriptionID == nil {
+		return false
+	}
+	lp, err := h.largePayloads.LargePayloadsFor(ctx, *job.SubscriptionID)
+	if err != nil {
+		slog.Warn("dispatch process: load large payload preference failed; delivering inline", "job_id", job.ID, "err", err)
+		return false
+	}
+	return lp == subscription.LargePayloadsPresignedURL
+}
+
+// payloadRef reports whether job's payload is an offloaded payload's
+// placeholder.
+func payloadRef(job *dispatchjob.DispatchJob) (blobstore.Ref, bool) {
+	if job.Payload == nil {
+		return blobstore.Ref{}, false
+	}
+	return blobstore.ParseRef([]byte(*job.Payload))
+}
+
+// rehydrate returns job with ref's payload read back from blobs.
+func rehydrate(ctx context.Context, blobs *blobstore.Offloader, job *dispatchjob.DispatchJob, ref blobstore.Ref) (*dispatchjob.DispatchJob, error) {
+	data, err := blobs.Fetch(ctx, ref)
+	if err != nil {
+		return nil, err
+	}
+	return withPayload(job, data), nil
+}
+
+// withPayload is a copy of job carrying payload; the original is what the
+// attempt is recorded against.
+func withPayload(job *dispatchjob.DispatchJob, payload []byte) *dispatchjob.DispatchJob {
+	cp := *job
+	s := string(payload)
+	cp.Payload = &s
+	return &cp
+}
@@ -0,0 +1,296 @@
+package processing
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"log/slog"
+	"sync"
+	"time"
+
+	"google.golang.org/protobuf/encoding/protojson"
+	"google.golang.org/protobuf/encoding/protowire"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/reflect/protoreflect"
+	"google.golang.org/protobuf/types/dynamicpb"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+// Payload formats. A subscription's payloadFormat picks how its deliveries
+// are serialized; the batch results a receiver answers with stay JSON.
+//
+//	JSON              the job's body (buildPayload): its payload when
+//	                  data-only, else the envelope. A batch is a JSON array.
+//	CLOUDEVENTS_JSON  a structured-mode CloudEvents 1.0 event, whatever
+//	                  dataOnly says; a batch is a JSON array of them.
+//	NDJSON            the JSON body on one line; a batch is a line per job.
+//	PROTOBUF          the payload transcoded to the message the event
+//	                  type's CURRENT PROTO schema names. A batch is a
+//	                  flowcatalyst.delivery.v1.Batch (api/delivery/batch.proto).
+//
+// A job whose payload cannot be encoded fails its attempt as a validation
+// error, so registering a missing schema lets the retry through.
+
+const (
+	contentTypeJSON             = "application/json"
+	contentTypeCloudEvents      = "application/cloudevents+json"
+	contentTypeCloudEventsBatch = "application/cloudevents-batch+json"
+	contentTypeNDJSON           = "application/x-ndjson"
+	// batchMessageType is the protobuf message of a PROTOBUF batch.
+	batchMessageType = "flowcatalyst.delivery.v1.Batch"
+	// cloudEventSource applies to a job without a source.
+	cloudEventSource = "/flowcatalyst"
+)
+
+// Field numbers of flowcatalyst.delivery.v1 (api/delivery/batch.proto).
+const (
+	pbBatchItems      protowire.Number = 1
+	pbItemID          protowire.Number = 1
+	pbItemType        protowire.Number = 2
+	pbItemMessageType protowire.Number = 3
+	pbItemData        protowire.Number = 4
+)
+
+// FormatSource supplies a subscription's payload format. Satisfied by
+// *subscription.Repository.
+type FormatSource interface {
+	PayloadFormatFor(ctx context.Context, subscriptionID string) (subscription.PayloadFormat, error)
+}
+
+// SchemaSource loads an event type with its schema versions, for PROTOBUF
+// deliveries. Satisfied by *eventtype.Repository.
+type SchemaSource interface {
+	FindByCode(ctx context.Context, code string) (*eventtype.EventType, error)
+}
+
+// SetPayloadFormats serializes deliveries in their subscription's payload
+// format, transcoding PROTOBUF ones with schemas' PROTO schemas. Without
+// it every delivery is JSON.
+func (h *Handler) SetPayloadFormats(formats FormatSource, schemas SchemaSource) {
+	h.formats = formats
+	h.protos = &protoSchemas{src: schemas, compiled: map[string]protoreflect.MessageDescriptor{}}
+}
+
+// encoderFor returns the encoder of job's subscription; JSON when it has
+// none or the lookup fails.
+func (h *Handler) encoderFor(ctx context.Context, job *dispatchjob.DispatchJob) encoder {
+	e := encoder{format: subscription.FormatJSON, protos: h.protos}
+	if h.formats == nil || job.SubscriptionID == nil {
+		return e
+	}
+	f, err := h.formats.PayloadFormatFor(ctx, *job.SubscriptionID)
+	if err != nil {
+		// A receiver expecting another format rejects the delivery, and
+		// the retry looks again.
+		slog.Warn("dispatch process: load payload format failed; delivering JSON", "job_id", job.ID, "err", err)
+		return e
+	}
+	e.format = f
+	return e
+}
+
+// encoder serializes deliveries in one payload format.
+type encoder struct {
+	format subscription.PayloadFormat
+	protos *protoSchemas
+}
+
+// one encodes job as a delivery of its own: the body and its Content-Type.
+func (e encoder) one(ctx context.Context, job *dispatchjob.DispatchJob) ([]byte, string, error) {
+	switch e.format {
+	case subscription.FormatCloudEvents:
+		b, err := cloudEvent(job)
+		return b, contentTypeCloudEvents, err
+	case subscription.FormatNDJSON:
+		return ndjsonLine(buildPayload(job)), contentTypeNDJSON, nil
+	case subscription.FormatProtobuf:
+		data, md, err := e.protos.encode(ctx, job)
+		if err != nil {
+			return nil, "", err
+		}
+		return data, protoContentType(string(md.FullName())), nil
+	default:
+		return buildPayload(job), contentTypeJSON, nil
+	}
+}
+
+// item encodes job as one element of a batch; batch joins the elements.
+func (e encoder) item(ctx context.Context, job *dispatchjob.DispatchJob) ([]byte, error) {
+	switch e.format {
+	case subscription.FormatCloudEvents:
+		return cloudEvent(job)
+	case subscription.FormatNDJSON:
+		return ndjsonLine(buildPayload(job)), nil
+	case subscription.FormatProtobuf:
+		data, md, err := e.protos.encode(ctx, job)
+		if err != nil {
+			return nil, err
+		}
+		return appendBatchItem(nil, job, string(md.FullName()), data), nil
+	default:
+		return batchItem(buildPayload(job)), nil
+	}
+}
+
+// batch joins items into a batch body and names its Content-Type.
+func (e encoder) batch(items [][]byte) ([]byte, string) {
+	switch e.format {
+	case subscription.FormatCloudEvents:
+		return batchBody(items), contentTypeCloudEventsBatch
+	case subscription.FormatNDJSON:
+		return bytes.Join(items, nil), contentTypeNDJSON
+	case subscription.FormatProtobuf:
+		return bytes.Join(items, nil), protoContentType(batchMessageType)
+	default:
+		return batchBody(items), contentTypeJSON
+	}
+}
+
+// array reports whether batch wraps its items in a JSON array, which
+// batchFit counts as a byte per item plus one.
+func (e encoder) array() bool {
+	return e.format != subscription.FormatNDJSON && e.format != subscription.FormatProtobuf
+}
+
+func protoContentType(messageType string) string {
+	return eventtype.ProtoMimeType + "; messageType=" + messageType
+}
+
+// cloudEvent is job as a structured-mode CloudEvents 1.0 event. The event
+// id is the platform event's, so a receiver dedupes retries on it; the
+// job's own fields travel as extension attributes.
+func cloudEvent(job *dispatchjob.DispatchJob) ([]byte, error) {
+	ce := map[string]any{
+		"specversion":   "1.0",
+		"id":            job.ID,
+		"source":        cloudEventSource,
+		"type":          job.Code,
+		"time":          job.CreatedAt.UTC().Format(time.RFC3339Nano),
+		"dispatchjobid": job.ID,
+		"attemptnumber": job.AttemptCount + 1,
+	}
+	if job.EventID != nil {
+		ce["id"] = *job.EventID
+	}
+	if job.Source != nil && *job.Source != "" {
+		ce["source"] = *job.Source
+	}
+	if job.Subject != nil {
+		ce["subject"] = *job.Subject
+	}
+	if job.CorrelationID != nil {
+		ce["correlationid"] = *job.CorrelationID
+	}
+	if job.MessageGroup != nil {
+		ce["messagegroup"] = *job.MessageGroup
+	}
+	if job.ClientID != nil {
+		ce["clientid"] = *job.ClientID
+	}
+	if job.SchemaID != nil {
+		ce["schemaid"] = *job.SchemaID
+	}
+	if job.Payload != nil {
+		if json.Valid([]byte(*job.Payload)) {
+			ce["datacontenttype"] = contentTypeJSON
+			ce["data"] = json.RawMessage(*job.Payload)
+		} else {
+			ce["datacontenttype"] = "text/plain"
+			ce["data"] = *job.Payload
+		}
+	}
+	return json.Marshal(ce)
+}
+
+// ndjsonLine is body as one NDJSON line: compacted JSON, or a JSON string
+// when body isn't JSON.
+func ndjsonLine(body []byte) []byte {
+	var buf bytes.Buffer
+	if json.Compact(&buf, body) != nil {
+		buf.Reset()
+		quoted, _ := json.Marshal(string(body))
+		buf.Write(quoted)
+	}
+	buf.WriteByte('\n')
+	return buf.Bytes()
+}
+
+// appendBatchItem appends job as a Batch.items entry to b.
+func appendBatchItem(b []byte, job *dispatchjob.DispatchJob, messageType string, data []byte) []byte {
+	var item []byte
+	item = protowire.AppendTag(item, pbItemID, protowire.BytesType)
+	item = protowire.AppendString(item, job.ID)
+	item = protowire.AppendTag(item, pbItemType, protowire.BytesType)
+	item = protowire.AppendString(item, job.Code)
+	item = protowire.AppendTag(item, pbItemMessageType, protowire.BytesType)
+	item = protowire.AppendString(item, messageType)
+	item = protowire.AppendTag(item, pbItemData, protowire.BytesType)
+	item = protowire.AppendBytes(item, data)
+	b = protowire.AppendTag(b, pbBatchItems, protowire.BytesType)
+	return protowire.AppendBytes(b, item)
+}
+
+// protoSchemas transcodes payloads with event types' PROTO schemas,
+// compiling each schema version once.
+type protoSchemas struct {
+	src SchemaSource
+
+	mu       sync.Mutex
+	compiled map[string]protoreflect.MessageDescriptor // by spec version id
+}
+
+// encode transcodes job's JSON payload to the message its event type's
+// CURRENT PROTO schema names. Fields the schema lacks are dropped, so a
+// producer may add fields before the schema catches up.
+func (p *protoSchemas) encode(ctx context.Context, job *dispatchjob.DispatchJob) ([]byte, protoreflect.MessageDescriptor, error) {
+	if p == nil || p.src == nil {
+		return nil, nil, errors.New("protobuf delivery is not configured")
+	}
+	md, err := p.message(ctx, job.Code)
+	if err != nil {
+		return nil, nil, err
+	}
+	payload := []byte("{}")
+	if job.Payload != nil {
+		payload = []byte(*job.Payload)
+	}
+	msg := dynamicpb.NewMessage(md)
+	if err := (protojson.UnmarshalOptions{DiscardUnknown: true}).Unmarshal(payload, msg); err != nil {
+		return nil, nil, fmt.Errorf("payload does not fit %s: %w", md.FullName(), err)
+	}
+	data, err := proto.MarshalOptions{Deterministic: true}.Marshal(msg)
+	if err != nil {
+		return nil, nil, fmt.Errorf("marshal %s: %w", md.FullName(), err)
+	}
+	return data, md, nil
+}
+
+func (p *protoSchemas) message(ctx context.Context, code string) (protoreflect.MessageDescriptor, error) {
+	et, err := p.src.FindByCode(ctx, code)
+	if err != nil {
+		return nil, fmt.Errorf("load event type %s: %w", code, err)
+	}
+	if et == nil {
+		return nil, fmt.Errorf("event type %s not found", code)
+	}
+	sv := et.ProtoSpec()
+	if sv == nil {
+		return nil, fmt.Errorf("event type %s has no CURRENT PROTO schema", code)
+	}
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if md, ok := p.compiled[sv.ID]; ok {
+		return md, nil
+	}
+	md, err := eventtype.CompileProtoSchema(sv.SchemaContent)
+	if err != nil {
+		return nil, fmt.Errorf("event type %s schema %s: %w", code, sv.Version, err)
+	}
+	p.compiled[sv.ID] = md
+	return md, nil
+}
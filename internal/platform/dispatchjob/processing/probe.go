@@ -0,0 +1,89 @@
+package processing
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/tsid"
+)
+
+// ProbeEventType is the event type of a test delivery; with the
+// X-Dispatch-Test header it tells a receiver the request is not traffic.
+const ProbeEventType = "flowcatalyst:portal:subscription:test"
+
+// maxProbeResponse caps the receiver's response body a ProbeResult echoes.
+const maxProbeResponse = 2048
+
+// ProbeResult is the outcome of a test delivery.
+type ProbeResult struct {
+	Success    bool   `json:"success"`
+	StatusCode *int   `json:"statusCode,omitempty"`
+	Error      string `json:"error,omitempty"`
+	Response   string `json:"response,omitempty"`
+	DurationMs int64  `json:"durationMs"`
+}
+
+// Probe sends sub's endpoint a test delivery the way its real ones go out —
+// custom headers, signature, transport, outbound policy, kill switches and
+// payload format — and reports the response. Nothing is recorded, so a
+// receiver answering with an ack token has nothing to ack. A PROTOBUF
+// subscription is probed with JSON: the test event has no schema.
+func (h *Handler) Probe(ctx context.Context, sub *subscription.Subscription) ProbeResult {
+	if sub.Delivery == subscription.DeliveryPull || sub.Endpoint == "" {
+		return ProbeResult{Error: "subscription has no endpoint to test"}
+	}
+	now := time.Now().UTC()
+	payload, _ := json.Marshal(map[string]any{
+		"test":             true,
+		"subscriptionCode": sub.Code,
+		"sentAt":           now.Format(time.RFC3339),
+	})
+	data := string(payload)
+	job := &dispatchjob.DispatchJob{
+		ID:                 tsid.Generate(tsid.DispatchJob),
+		Code:               ProbeEventType,
+		TargetURL:          sub.Endpoint,
+		Payload:            &data,
+		PayloadContentType: "application/json",
+		DataOnly:           sub.DataOnly,
+		ClientID:           sub.ClientID,
+		SubscriptionID:     &sub.ID,
+		DispatchPoolID:     sub.DispatchPoolID,
+		TimeoutSeconds:     uint32(max(sub.TimeoutSeconds, 0)),
+		CreatedAt:          now,
+	}
+	if h.killed(ctx, job) {
+		return ProbeResult{Error: "deliveries are stopped by a kill switch"}
+	}
+	enc := h.encoderFor(ctx, job)
+	if enc.format == subscription.FormatProtobuf {
+		enc.format = subscription.FormatJSON
+	}
+	body, contentType, err := enc.one(ctx, job)
+	if err != nil {
+		return ProbeResult{Error: "encode payload: " + err.Error()}
+	}
+
+	start := time.Now()
+	res := h.post(ctx, job, body, http.Header{
+		"Content-Type":      {contentType},
+		"X-Dispatch-Job-Id": {job.ID},
+		"X-Event-Type":      {job.Code},
+		"X-Dispatch-Test":   {"true"},
+	})
+	out := ProbeResult{Success: res.success, StatusCode: res.statusCodePtr(), DurationMs: time.Since(start).Milliseconds()}
+	if !res.success {
+		out.Error = res.errMessage
+	}
+	if res.body != nil {
+		out.Response = *res.body
+		if len(out.Response) > maxProbeResponse {
+			out.Response = out.Response[:maxProbeResponse]
+		}
+	}
+	return out
+}
@@ -9,7 +9,8 @@
 //  2. marks it PROCESSING,
 //  3. delivers the real webhook to the subscriber's target_url,
 //  4. records the attempt in msg_dispatch_job_attempts,
-//  5. advances the job status (COMPLETED / retry-scheduled / FAILED),
+//  5. advances the job status (COMPLETED / retry-scheduled / FAILED, or
+//     left PROCESSING when the receiver will ack asynchronously — ack.go),
 //  6. returns {"ack": true} so the router removes the queue message.
 //
 // Retries are driven by the scheduler poller via scheduled_for, NOT by the
@@ -24,15 +25,22 @@ import (
 	"context"
 	"encoding/json"
 	"errors"
+	"fmt"
 	"io"
 	"log/slog"
+	"net"
 	"net/http"
 	"strings"
 	"time"
 
 	"github.com/go-chi/chi/v5"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/schemaregistry"
 )
 
 // maxResponseBody caps how much of a subscriber response we read into the
@@ -53,17 +61,106 @@ var retryBackoff = []time.Duration{
 	120 * time.Second,
 }
 
+// killHoldDelay is how far a held job's scheduled_for is pushed. The
+// poller skips killed targets anyway; this only keeps a release from
+// racing the copy that was just put back.
+const killHoldDelay = 5 * time.Second
+
 // Verifier checks the HMAC bearer token the router forwards (the scheduler
 // signed the job id). Satisfied by *scheduler.DispatchAuthService.
 type Verifier interface {
 	Verify(jobID, token string) bool
 }
 
+// HeaderSource supplies a subscription's custom delivery headers, with any
+// secret references already resolved. Satisfied by
+// *subscription.HeaderResolver.
+type HeaderSource interface {
+	DeliveryHeaders(ctx context.Context, subscriptionID string) (http.Header, error)
+}
+
+// Signer signs a delivery body with the client's signing key, returning
+// the signature, timestamp and key-id headers — or none when the client
+// has no key. Satisfied by *signingkey.Signer.
+type Signer interface {
+	SignDelivery(ctx context.Context, clientID string, body []byte) (http.Header, error)
+}
+
+// TransportSource names the outbound transport a subscription delivers
+// through, or nil for direct. Satisfied by *subscription.Repository.
+type TransportSource interface {
+	TransportFor(ctx context.Context, subscriptionID string) (*string, error)
+}
+
+// KillSwitches reports the engaged kill switches. Satisfied by
+// *killswitch.Cache.
+type KillSwitches interface {
+	Snapshot(ctx context.Context) (killswitch.Set, error)
+}
+
+// Receipts sends the delivery receipt a job's event asked for, once the
+// job is terminal. Satisfied by *receipt.Emitter.
+type Receipts interface {
+	Emit(ctx context.Context, job *dispatchjob.DispatchJob, status common.DispatchStatus, attempts int32, lastError *string) error
+}
+
 // Handler serves the dispatch-processing callback.
 type Handler struct {
 	repo     *dispatchjob.Repository
 	verifier Verifier
+	headers  HeaderSource
+	signer   Signer
 	client   *http.Client
+
+	// outbound and clientsByID are set by SetOutbound: a client with its
+	// own policy gets its own connection pool, so a connection one
+	// client's policy allowed is never reused under another's.
+	outbound    *outbound.Policies
+	clientsByID map[string]*http.Client
+
+	// transports and clientsByTransport are set by SetTransports: one
+	// client per named transport, dialling through it.
+	transports         TransportSource
+	clientsByTransport map[string]*http.Client
+
+	// tlsTrust, trusts and trusted are set by SetTLSTrust: the
+	// deployment's trust, each subscription's own, and the clients built
+	// for the latter (tls.go).
+	tlsTrust outbound.TLSTrust
+	trusts   TrustSource
+	trusted  *trustedClients
+
+	// killSwitches, when set, holds back deliveries for killed clients,
+	// subscriptions and pools.
+	killSwitches KillSwitches
+
+	// receipts, when set, tells producers how their events' deliveries
+	// ended.
+	receipts Receipts
+
+	// batching, when set, lets a subscription have its jobs delivered in
+	// batches (batch.go).
+	batching BatchSource
+
+	// formats and protos are set by SetPayloadFormats: how each
+	// subscription's deliveries are serialized (format.go).
+	formats FormatSource
+	protos  *protoSchemas
+
+	// blobs and largePayloads are set by SetBlobs: where offloaded
+	// payloads are read back from, and how each subscription receives
+	// them (blob.go).
+	blobs         *blobstore.Offloader
+	largePayloads LargePayloadSource
+
+	// registry, when set, fingerprints the schema ids deliveries carry
+	// (schema.go).
+	registry *schemaregistry.Client
+
+	// shadows and shadowSlots are set by SetShadows: where deliveries are
+	// copied to, and the copies in flight (shadow.go).
+	shadows     ShadowSource
+	shadowSlots chan struct{}
 }
 
 // New wires the handler. verifier may be nil (dev/no-auth), in which case the
@@ -73,17 +170,139 @@ func New(repo *dispatchjob.Repository, verifier Verifier) *Handler {
 	return &Handler{
 		repo:     repo,
 		verifier: verifier,
-		// Outer ceiling only; each delivery uses a per-job context timeout.
-		// No redirect-following: a 3xx from a webhook target is not a success.
-		client: &http.Client{
-			Timeout: 2 * time.Minute,
-			CheckRedirect: func(*http.Request, []*http.Request) error {
-				return http.ErrUseLastResponse
-			},
+		client:   newDeliveryClient(nil),
+	}
+}
+
+// newDeliveryClient builds the direct webhook client. With a policy, every
+// dialled address is checked and the environment proxy is ignored — the
+// proxy would be the address dialled.
+func newDeliveryClient(policy *outbound.Policy) *http.Client {
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+	if policy != nil {
+		dialer := &net.Dialer{Timeout: 30 * time.Second, KeepAlive: 30 * time.Second}
+		policy.Guard(dialer)
+		transport.DialContext = dialer.DialContext
+		transport.Proxy = nil
+	}
+	return webhookClient(transport)
+}
+
+// newTunnelClient builds a webhook client that dials through t (an SSH
+// bastion or SOCKS5 proxy). TLS still runs end to end with the receiver.
+func newTunnelClient(t outbound.Transport) *http.Client {
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+	transport.DialContext = t.DialContext
+	transport.Proxy = nil
+	return webhookClient(transport)
+}
+
+// webhookClient wraps transport for deliveries. Outer timeout only; each
+// delivery uses a per-job context timeout. No redirect-following: a 3xx
+// from a webhook target is not a success (and would bypass the policy's
+// URL check).
+func webhookClient(transport *http.Transport) *http.Client {
+	return &http.Client{
+		Timeout:   2 * time.Minute,
+		Transport: transport,
+		CheckRedirect: func(*http.Request, []*http.Request) error {
+			return http.ErrUseLastResponse
 		},
 	}
 }
 
+// SetHeaderSource enables per-subscription custom headers. Without one,
+// deliveries carry only the platform's own headers.
+func (h *Handler) SetHeaderSource(src HeaderSource) { h.headers = src }
+
+// SetSigner enables per-client payload signing. Without one, deliveries
+// are unsigned.
+func (h *Handler) SetSigner(s Signer) { h.signer = s }
+
+// SetOutbound enforces the deployment's outbound policy, and each
+// client's override, on every delivery. Without one, any target URL is
+// dialled.
+func (h *Handler) SetOutbound(ps *outbound.Policies) {
+	h.outbound = ps
+	h.client = newDeliveryClient(&ps.Default)
+	h.clientsByID = make(map[string]*http.Client, len(ps.Clients))
+	for id, p := range ps.Clients {
+		h.clientsByID[id] = newDeliveryClient(&p)
+	}
+}
+
+// SetTransports lets subscriptions deliver through a named transport
+// instead of dialling the target directly. Without it, every delivery is
+// direct.
+func (h *Handler) SetTransports(src TransportSource, ts *outbound.Transports) {
+	h.transports = src
+	h.clientsByTransport = make(map[string]*http.Client)
+	for _, name := range ts.Names() {
+		t, _ := ts.Get(name)
+		h.clientsByTransport[name] = newTunnelClient(t)
+	}
+}
+
+// SetKillSwitches holds back a queued copy whose client, subscription or
+// pool is killed: the job goes back to PENDING, where the poller leaves
+// it until the switch is released. Without it every copy is delivered.
+func (h *Handler) SetKillSwitches(ks KillSwitches) { h.killSwitches = ks }
+
+// SetReceipts sends delivery receipts for events that asked for one.
+// Without it none are sent.
+func (h *Handler) SetReceipts(r Receipts) { h.receipts = r }
+
+// killed reports whether a kill switch stops job. A failed reload keeps
+// enforcing the last switches the cache knew.
+func (h *Handler) killed(ctx context.Context, job *dispatchjob.DispatchJob) bool {
+	if h.killSwitches == nil {
+		return false
+	}
+	set, err := h.killSwitches.Snapshot(ctx)
+	if err != nil {
+		slog.Warn("dispatch process: kill switch reload failed; using last known", "job_id", job.ID, "err", err)
+	}
+	return set.Stops(deref(job.ClientID), deref(job.SubscriptionID), deref(job.DispatchPoolID))
+}
+
+func deref(s *string) string {
+	if s == nil {
+		return ""
+	}
+	return *s
+}
+
+// clientFor returns the delivery client for the job: its subscription's
+// transport, if it names one, else its owning client's policy; either
+// with the subscription's TLS trust, if it has one.
+func (h *Handler) clientFor(ctx context.Context, job *dispatchjob.DispatchJob) (*http.Client, error) {
+	if h.transports != nil && job.SubscriptionID != nil {
+		name, err := h.transports.TransportFor(ctx, *job.SubscriptionID)
+		if err != nil {
+			return nil, fmt.Errorf("load transport: %w", err)
+		}
+		if name != nil && *name != "" {
+			c, ok := h.clientsByTransport[*name]
+			if !ok {
+				return nil, fmt.Errorf("transport %q is not configured", *name)
+			}
+			return h.withTrust(ctx, job, c)
+		}
+	}
+	return h.withTrust(ctx, job, h.clientForOwner(job.ClientID))
+}
+
+// clientForOwner returns the direct delivery client for the job's owning
+// client.
+func (h *Handler) clientForOwner(clientID *string) *http.Client {
+	if clientID != nil {
+		if c, ok := h.clientsByID[*clientID]; ok {
+			return c
+		}
+	}
+	return h.client
+}
+
 // Mount attaches POST /api/dispatch/process to the given (unauthenticated)
 // chi router. The handler self-verifies the scheduler HMAC bearer, so it must
 // live OUTSIDE the platform JWT middleware.
@@ -150,6 +369,55 @@ func (h *Handler) serve(w http.ResponseWriter, r *http.Request) {
 		writeJSON(w, http.StatusOK, processResponse{Ack: true})
 		return
 	}
+	if job.Status == common.DispatchProcessing && job.AckDeadline != nil {
+		// Accepted asynchronously and not settled yet (a duplicate
+		// redelivery): the receiver is still working on it.
+		writeJSON(w, http.StatusOK, processResponse{Ack: true, Message: "awaiting consumer ack"})
+		return
+	}
+	if job.ExpiresAt != nil && !job.ExpiresAt.After(time.Now()) {
+		// Reached its expires_at while queued or between retries: drop it
+		// rather than deliver a stale notification.
+		expired, err := h.repo.MarkExpired(ctx, jobID)
+		if err != nil {
+			slog.Error("dispatch process: mark expired failed", "job_id", jobID, "err", err)
+			writeJSON(w, http.StatusInternalServerError, processResponse{Ack: false, Message: "expire failed"})
+			return
+		}
+		if expired {
+			errMsg := "expired before delivery"
+			sendReceipt(ctx, h.receipts, job, common.DispatchExpired, job.AttemptCount, &errMsg)
+			slog.Warn("dispatch expired before delivery", "job_id", jobID, "expires_at", job.ExpiresAt.UTC())
+		}
+		writeJSON(w, http.StatusOK, processResponse{Ack: true, Message: "expired"})
+		return
+	}
+	if h.killed(ctx, job) {
+		// Queued before the switch was engaged. Put it back without
+		// spending an attempt and drop this copy; the poller re-dispatches
+		// it after the release.
+		if err := h.repo.Reschedule(ctx, jobID, time.Now().Add(killHoldDelay)); err != nil {
+			// Not put back: NACK so the queue redelivers and we try again.
+			slog.Error("dispatch process: hold for kill switch failed", "job_id", jobID, "err", err)
+			writeJSON(w, http.StatusInternalServerError, processResponse{Ack: false, Message: "hold failed"})
+			return
+		}
+		slog.Info("dispatch held by kill switch", "job_id", jobID)
+		writeJSON(w, http.StatusOK, processResponse{Ack: true, Message: "held by kill switch"})
+		return
+	}
+	if b := h.batchingFor(ctx, job); b != nil {
+		msg, err := h.serveBatch(ctx, job, b)
+		if err != nil {
+			// Nothing delivered and the claimed jobs released (or left to
+			// the expiry sweep): NACK so the queue redelivers.
+			slog.Error("dispatch process: batch failed", "job_id", jobID, "err", err)
+			writeJSON(w, http.StatusInternalServerError, processResponse{Ack: false, Message: "batch failed"})
+			return
+		}
+		writeJSON(w, http.StatusOK, processResponse{Ack: true, Message: msg})
+		return
+	}
 
 	if err := h.repo.MarkInProgress(ctx, jobID); err != nil {
 		slog.Warn("dispatch process: mark in-progress failed", "job_id", jobID, "err", err)
@@ -171,6 +439,7 @@ func (h *Handler) serve(w http.ResponseWriter, r *http.Request) {
 	}
 
 	h.advance(ctx, job, attemptNumber, res, attempt)
+	h.shadow(ctx, job, res, attempt)
 
 	writeJSON(w, http.StatusOK, processResponse{Ack: true})
 }
@@ -184,9 +453,20 @@ func (h *Handler) advance(ctx context.Context, job *dispatchjob.DispatchJob, att
 	}
 
 	switch {
+	case res.ackToken != "":
+		// Accepted for asynchronous processing: the job stays PROCESSING
+		// until the consumer acks or nacks it, or the deadline passes.
+		deadline := time.Now().Add(res.ackTimeout)
+		if err := h.repo.AwaitAck(ctx, jobID, res.ackToken, deadline); err != nil {
+			slog.Warn("dispatch process: await ack failed", "job_id", jobID, "err", err)
+		}
+		slog.Debug("dispatch accepted, awaiting ack", "job_id", jobID, "attempt", attemptNumber, "deadline", deadline)
+
 	case res.success:
 		if err := h.repo.MarkCompleted(ctx, jobID, dur); err != nil {
 			slog.Warn("dispatch process: mark completed failed", "job_id", jobID, "err", err)
+		} else {
+			sendReceipt(ctx, h.receipts, job, common.DispatchCompleted, attemptNumber, nil)
 		}
 		slog.Debug("dispatch delivered", "job_id", jobID, "status", res.statusCode, "attempt", attemptNumber)
 
@@ -203,6 +483,8 @@ func (h *Handler) advance(ctx context.Context, job *dispatchjob.DispatchJob, att
 		errMsg := res.errMessage
 		if err := h.repo.MarkFailed(ctx, jobID, &errMsg, dur); err != nil {
 			slog.Warn("dispatch process: mark failed failed", "job_id", jobID, "err", err)
+		} else {
+			sendReceipt(ctx, h.receipts, job, common.DispatchFailed, attemptNumber, &errMsg)
 		}
 		slog.Warn("dispatch failed (retries exhausted)", "job_id", jobID, "attempts", attemptNumber, "max", job.MaxRetries, "err", errMsg)
 
@@ -216,6 +498,18 @@ func (h *Handler) advance(ctx context.Context, job *dispatchjob.DispatchJob, att
 	}
 }
 
+// sendReceipt sends job's delivery receipt through r, if its event asked
+// for one. Only after the terminal status is recorded, and best-effort:
+// the job is done either way, so a failure is logged, not retried.
+func sendReceipt(ctx context.Context, r Receipts, job *dispatchjob.DispatchJob, status common.DispatchStatus, attempts int32, lastError *string) {
+	if r == nil {
+		return
+	}
+	if err := r.Emit(ctx, job, status, attempts, lastError); err != nil {
+		slog.Warn("dispatch process: receipt failed", "job_id", job.ID, "err", err)
+	}
+}
+
 func backoffFor(attemptNumber int32) time.Duration {
 	i := int(attemptNumber) - 1
 	if i < 0 {
@@ -232,6 +526,8 @@ type deliveryResult struct {
 	success    bool
 	deferral   bool // cooperative back-pressure (retry, no budget spend)
 	retryAfter time.Duration
+	ackToken   string // set when the receiver will ack asynchronously
+	ackTimeout time.Duration
 	statusCode int
 	hasStatus  bool
 	body       *string
@@ -247,26 +543,99 @@ func (r deliveryResult) statusCodePtr() *int {
 	return &s
 }
 
-// deliver POSTs the real event to the subscriber's target_url and classifies
-// the response.
+// deliver POSTs the real event to the subscriber's target_url, in its
+// subscription's payload format, and classifies the response.
 func (h *Handler) deliver(ctx context.Context, job *dispatchjob.DispatchJob) deliveryResult {
-	timeout := defaultTimeout
+	body, hdr, failed := h.render(ctx, job)
+	if failed != nil {
+		return *failed
+	}
+	return h.post(ctx, job, body, hdr)
+}
+
+// render builds job's body and platform headers. When it can't, the
+// result of the failed attempt is returned instead.
+func (h *Handler) render(ctx context.Context, job *dispatchjob.DispatchJob) ([]byte, http.Header, *deliveryResult) {
+	enc := h.encoderFor(ctx, job)
+	payload, err := h.resolvePayload(ctx, job, enc.format)
+	if err != nil {
+		return nil, nil, &deliveryResult{errMessage: "offloaded payload: " + err.Error(), errType: dispatchjob.ErrorConnection}
+	}
+	body, contentType, err := enc.one(ctx, payload)
+	if err != nil {
+		return nil, nil, &deliveryResult{errMessage: "encode payload: " + err.Error(), errType: dispatchjob.ErrorValidation}
+	}
+	hdr := http.Header{
+		"Content-Type":      {contentType},
+		"X-Dispatch-Job-Id": {job.ID},
+		"X-Event-Type":      {job.Code},
+	}
+	h.setSchemaHeaders(ctx, job, hdr)
+	return body, hdr, nil
+}
+
+// jobTimeout is the delivery timeout for job.
+func jobTimeout(job *dispatchjob.DispatchJob) time.Duration {
 	if job.TimeoutSeconds > 0 {
-		timeout = time.Duration(job.TimeoutSeconds) * time.Second
+		return time.Duration(job.TimeoutSeconds) * time.Second
 	}
-	ctx, cancel := context.WithTimeout(ctx, timeout)
+	return defaultTimeout
+}
+
+// post POSTs body to job's target_url with the platform headers in
+// platform, and classifies the response. job supplies the target, timeout,
+// owning client and subscription; for a batch, that of any of its jobs.
+func (h *Handler) post(ctx context.Context, job *dispatchjob.DispatchJob, body []byte, platform http.Header) deliveryResult {
+	ctx, cancel := context.WithTimeout(ctx, jobTimeout(job))
 	defer cancel()
 
-	body := buildPayload(job)
+	// Re-checked on every attempt: the policy may have tightened since the
+	// subscription was saved.
+	if err := h.outbound.CheckURL(job.ClientID, job.TargetURL); err != nil {
+		return deliveryResult{errMessage: err.Error(), errType: dispatchjob.ErrorValidation}
+	}
+
 	req, err := http.NewRequestWithContext(ctx, http.MethodPost, job.TargetURL, bytes.NewReader(body))
 	if err != nil {
 		return deliveryResult{errMessage: "build request: " + err.Error(), errType: dispatchjob.ErrorConnection}
 	}
+	// Custom headers go on first so the platform's own always win; the
+	// subscription API refuses reserved names, this just keeps it so.
+	if h.headers != nil && job.SubscriptionID != nil {
+		extra, err := h.headers.DeliveryHeaders(ctx, *job.SubscriptionID)
+		if err != nil {
+			// Delivering without, say, the receiver's API key is pointless:
+			// fail the attempt and let the retry pick up a fixed secret.
+			return deliveryResult{errMessage: "custom headers: " + err.Error(), errType: dispatchjob.ErrorValidation}
+		}
+		for k, v := range extra {
+			req.Header[k] = v
+		}
+	}
 	req.Header.Set("Content-Type", "application/json")
-	req.Header.Set("X-Dispatch-Job-Id", job.ID)
-	req.Header.Set("X-Event-Type", job.Code)
+	for k, v := range platform {
+		req.Header[k] = v
+	}
+	if h.signer != nil && job.ClientID != nil {
+		sig, err := h.signer.SignDelivery(ctx, *job.ClientID, body)
+		if err != nil {
+			// An unsigned delivery would be rejected by a receiver that
+			// verifies; fail the attempt instead.
+			return deliveryResult{errMessage: "sign payload: " + err.Error(), errType: dispatchjob.ErrorValidation}
+		}
+		for k, v := range sig {
+			req.Header[k] = v
+		}
+	}
 
-	resp, err := h.client.Do(req)
+	client, err := h.clientFor(ctx, job)
+	if err != nil {
+		// A subscription pointed at a transport this deployment lacks:
+		// dialling directly would break the receiver's allowlist anyway.
+		// Likewise a TLS trust that no longer builds.
+		return deliveryResult{errMessage: err.Error(), errType: dispatchjob.ErrorValidation}
+	}
+	resp, err := client.Do(req)
 	if err != nil {
 		msg, et := classifyTransportErr(err)
 		return deliveryResult{errMessage: msg, errType: et}
@@ -291,6 +660,18 @@ func (h *Handler) deliver(ctx context.Context, job *dispatchjob.DispatchJob) del
 				errMessage: "subscriber deferred (ack=false)",
 			}
 		}
+		if token, timeout, ok := parseAckRequest(status, raw); ok {
+			if len(token) > maxAckTokenLen {
+				return deliveryResult{
+					statusCode: status,
+					hasStatus:  true,
+					body:       &bodyStr,
+					errMessage: fmt.Sprintf("ack token longer than %d characters", maxAckTokenLen),
+					errType:    dispatchjob.ErrorValidation,
+				}
+			}
+			return deliveryResult{success: true, ackToken: token, ackTimeout: timeout, statusCode: status, hasStatus: true, body: &bodyStr}
+		}
 		return deliveryResult{success: true, statusCode: status, hasStatus: true, body: &bodyStr}
 
 	case status == http.StatusTooManyRequests: // 429 → back-pressure, not a failure
@@ -344,6 +725,9 @@ func buildPayload(job *dispatchjob.DispatchJob) []byte {
 	if job.ClientID != nil {
 		env["clientId"] = *job.ClientID
 	}
+	if job.SchemaID != nil {
+		env["schemaId"] = *job.SchemaID
+	}
 	if job.Payload != nil {
 		// Embed as JSON when it parses; otherwise pass the raw string through
 		// so a non-JSON payload isn't silently dropped.
@@ -391,6 +775,14 @@ func retryAfterOrDefault(resp *http.Response) time.Duration {
 }
 
 func classifyTransportErr(err error) (string, dispatchjob.ErrorType) {
+	if errors.Is(err, outbound.ErrBlocked) {
+		return "Connection refused by outbound policy: " + err.Error(), dispatchjob.ErrorValidation
+	}
+	// A certificate the receiver's trust doesn't accept won't verify on
+	// retry either.
+	if outbound.IsTrustFailure(err) {
+		return "TLS trust failure: " + err.Error(), dispatchjob.ErrorValidation
+	}
 	var netErr interface{ Timeout() bool }
 	if errors.As(err, &netErr) && netErr.Timeout() {
 		return "Connection timeout", dispatchjob.ErrorTimeout
@@ -20,7 +20,11 @@ import (
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/processing"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/receipt"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduler"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
 )
 
@@ -213,3 +217,345 @@ func TestProcess_AlreadyTerminalAcksWithoutRedelivery(t *testing.T) {
 	assert.Equal(t, true, out["ack"])
 	assert.EqualValues(t, 0, atomic.LoadInt32(&hits), "terminal job is not re-delivered")
 }
+
+func TestProcess_ExpiredJobIsNotDelivered(t *testing.T) {
+	pool := testpg.Pool(t)
+	base, auth := harness(t, pool)
+
+	var hits int32
+	sub := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		atomic.AddInt32(&hits, 1)
+		w.WriteHeader(http.StatusOK)
+	}))
+	t.Cleanup(sub.Close)
+
+	seedJob(t, pool, "djproc_expired", sub.URL, 3, 1)
+	_, err := pool.Exec(context.Background(),
+		`UPDATE msg_dispatch_jobs SET expires_at = NOW() - INTERVAL '1 minute' WHERE id = 'djproc_expired'`)
+	require.NoError(t, err)
+
+	code, out := callProcess(t, base, "djproc_expired", auth.Sign("djproc_expired"))
+	assert.Equal(t, http.StatusOK, code)
+	assert.Equal(t, true, out["ack"])
+	assert.EqualValues(t, 0, atomic.LoadInt32(&hits), "an expired job is not delivered")
+
+	status, attempts, _ := jobRow(t, pool, "djproc_expired")
+	assert.Equal(t, "EXPIRED", status)
+	assert.EqualValues(t, 1, attempts, "expiry spends no attempt")
+	assert.Zero(t, attemptCount(t, pool, "djproc_expired"))
+}
+
+type fixedKillSwitches struct{ set killswitch.Set }
+
+func (f fixedKillSwitches) Snapshot(context.Context) (killswitch.Set, error) { return f.set, nil }
+
+func TestProcess_KillSwitchHoldsJob(t *testing.T) {
+	pool := testpg.Pool(t)
+	auth := scheduler.NewDispatchAuthService(testSecret)
+	h := processing.New(dispatchjob.NewRepository(pool), auth)
+	h.SetKillSwitches(fixedKillSwitches{killswitch.NewSet([]killswitch.KillSwitch{
+		{Scope: killswitch.ScopeClient, TargetID: "clt_procKilled01"},
+	})})
+	r := chi.NewRouter()
+	h.Mount(r)
+	ts := httptest.NewServer(r)
+	t.Cleanup(ts.Close)
+
+	var hits int32
+	sub := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		atomic.AddInt32(&hits, 1)
+		w.WriteHeader(http.StatusOK)
+	}))
+	t.Cleanup(sub.Close)
+
+	seedJob(t, pool, "djproc_killed", sub.URL, 3, 0)
+	_, err := pool.Exec(context.Background(),
+		`UPDATE msg_dispatch_jobs SET client_id = 'clt_procKilled01' WHERE id = 'djproc_killed'`)
+	require.NoError(t, err)
+
+	code, out := callProcess(t, ts.URL, "djproc_killed", auth.Sign("djproc_killed"))
+	assert.Equal(t, http.StatusOK, code)
+	assert.Equal(t, true, out["ack"], "the queued copy is dropped")
+	assert.EqualValues(t, 0, atomic.LoadInt32(&hits), "a killed client's job is not delivered")
+
+	status, attempts, scheduled := jobRow(t, pool, "djproc_killed")
+	assert.Equal(t, "PENDING", status, "held for the poller to dispatch after the release")
+	assert.EqualValues(t, 0, attempts, "holding spends no attempt")
+	require.NotNil(t, scheduled)
+	assert.Zero(t, attemptCount(t, pool, "djproc_killed"))
+}
+
+func TestProcess_TerminalStatusSendsReceipts(t *testing.T) {
+	pool := testpg.Pool(t)
+	ctx := context.Background()
+	auth := scheduler.NewDispatchAuthService(testSecret)
+	jobs := dispatchjob.NewRepository(pool)
+	events := event.NewRepository(pool)
+	h := processing.New(jobs, auth)
+	h.SetReceipts(receipt.NewEmitter(events, jobs))
+	r := chi.NewRouter()
+	h.Mount(r)
+	ts := httptest.NewServer(r)
+	t.Cleanup(ts.Close)
+
+	sub := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		w.WriteHeader(http.StatusOK)
+	}))
+	t.Cleanup(sub.Close)
+
+	callback, receiptType := "https://producer.example/receipts", "proc:test:delivery:receipted"
+	ev := event.New("proc:test:order:placed", "test", "order.1", json.RawMessage(`{}`))
+	ev.ID = "evproc_rcpt01"
+	ev.Receipt = &event.Receipt{CallbackURL: &callback, EventType: &receiptType}
+	_, err := events.InsertBatch(ctx, []event.Event{*ev})
+	require.NoError(t, err)
+
+	seedJob(t, pool, "djproc_rcpt01", sub.URL, 3, 0)
+	_, err = pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs SET event_id = 'evproc_rcpt01', subscription_id = 'sub_procRcpt01' WHERE id = 'djproc_rcpt01'`)
+	require.NoError(t, err)
+
+	code, _ := callProcess(t, ts.URL, "djproc_rcpt01", auth.Sign("djproc_rcpt01"))
+	assert.Equal(t, http.StatusOK, code)
+
+	var hookURL string
+	var dataOnly bool
+	var hookEventID *string
+	var payload string
+	require.NoError(t, pool.QueryRow(ctx,
+		`SELECT target_url, data_only, event_id, payload FROM msg_dispatch_jobs WHERE idempotency_key = 'receipt:djproc_rcpt01'`).
+		Scan(&hookURL, &dataOnly, &hookEventID, &payload))
+	assert.Equal(t, callback, hookURL)
+	assert.True(t, dataOnly, "the receiver gets the receipt itself")
+	assert.Nil(t, hookEventID, "a receipt webhook sends no receipt of its own")
+
+	var got receipt.Receipt
+	require.NoError(t, json.Unmarshal([]byte(payload), &got))
+	assert.Equal(t, "evproc_rcpt01", got.EventID)
+	assert.Equal(t, "djproc_rcpt01", got.DispatchJobID)
+	require.NotNil(t, got.SubscriptionID)
+	assert.Equal(t, "sub_procRcpt01", *got.SubscriptionID)
+	assert.Equal(t, "COMPLETED", got.Status)
+	assert.EqualValues(t, 1, got.Attempts)
+
+	var causation *string
+	require.NoError(t, pool.QueryRow(ctx,
+		`SELECT causation_id FROM msg_events WHERE type = $1 AND deduplication_id = 'receipt-djproc_rcpt01'`, receiptType).
+		Scan(&causation))
+	require.NotNil(t, causation)
+	assert.Equal(t, "evproc_rcpt01", *causation, "the receipt event is caused by the original")
+}
+
+type fixedBatching struct{ b *subscription.Batching }
+
+func (f fixedBatching) BatchingFor(context.Context, string) (*subscription.Batching, error) {
+	return f.b, nil
+}
+
+func TestProcess_BatchSettlesEachJob(t *testing.T) {
+	pool := testpg.Pool(t)
+	ctx := context.Background()
+	auth := scheduler.NewDispatchAuthService(testSecret)
+	h := processing.New(dispatchjob.NewRepository(pool), auth)
+	h.SetBatching(fixedBatching{&subscription.Batching{MaxItems: 10}})
+	r := chi.NewRouter()
+	h.Mount(r)
+	ts := httptest.NewServer(r)
+	t.Cleanup(ts.Close)
+
+	var deliveries atomic.Int32
+	sub := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		deliveries.Add(1)
+		var items []map[string]any
+		assert.NoError(t, json.NewDecoder(r.Body).Decode(&items))
+		if assert.Len(t, items, 3) {
+			assert.Equal(t, "djproc_batch2", items[0]["id"], "the leader goes first")
+		}
+		assert.Equal(t, "3", r.Header.Get("X-Dispatch-Batch-Size"))
+		assert.Empty(t, r.Header.Get("X-Dispatch-Job-Id"), "a batch names its jobs in X-Dispatch-Job-Ids")
+		w.Header().Set("Content-Type", "application/json")
+		_, _ = w.Write([]byte(`{"results":[{"id":"djproc_batch1","status":200},{"id":"djproc_batch3","status":500,"error":"bad row"}]}`))
+	}))
+	t.Cleanup(sub.Close)
+
+	for _, id := range []string{"djproc_batch1", "djproc_batch2", "djproc_batch3"} {
+		seedJob(t, pool, id, sub.URL, 3, 0)
+	}
+	_, err := pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs SET subscription_id = 'sub_procBatch01' WHERE id LIKE 'djproc_batch%'`)
+	require.NoError(t, err)
+
+	code, out := callProcess(t, ts.URL, "djproc_batch2", auth.Sign("djproc_batch2"))
+	assert.Equal(t, http.StatusOK, code)
+	assert.Equal(t, true, out["ack"])
+	assert.EqualValues(t, 1, deliveries.Load(), "one request carries the whole batch")
+
+	status, _, _ := jobRow(t, pool, "djproc_batch1")
+	assert.Equal(t, "COMPLETED", status, "reported 200")
+	status, _, _ = jobRow(t, pool, "djproc_batch2")
+	assert.Equal(t, "COMPLETED", status, "not reported: takes the response's 200")
+	status, attempts, scheduled := jobRow(t, pool, "djproc_batch3")
+	assert.Equal(t, "PENDING", status, "reported 500: retried alone")
+	assert.EqualValues(t, 1, attempts)
+	require.NotNil(t, scheduled)
+	for _, id := range []string{"djproc_batch1", "djproc_batch2", "djproc_batch3"} {
+		assert.Equal(t, 1, attemptCount(t, pool, id), id)
+	}
+
+	// A sibling's own queued copy finds it settled and is dropped.
+	code, _ = callProcess(t, ts.URL, "djproc_batch1", auth.Sign("djproc_batch1"))
+	assert.Equal(t, http.StatusOK, code)
+	assert.EqualValues(t, 1, deliveries.Load())
+}
+
+func TestProcess_AsyncAck(t *testing.T) {
+	pool := testpg.Pool(t)
+	base, auth := harness(t, pool)
+	ctx := context.Background()
+	acks := processing.NewAcks(dispatchjob.NewRepository(pool), nil)
+
+	var deliveries atomic.Int32
+	sub := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		deliveries.Add(1)
+		w.WriteHeader(http.StatusAccepted)
+		_, _ = w.Write([]byte(`{"ackToken":"tok-` + r.Header.Get("X-Dispatch-Job-Id") + `","ackTimeoutSeconds":600}`))
+	}))
+	t.Cleanup(sub.Close)
+
+	// A 202 with a token leaves the job PROCESSING; a redelivered copy is
+	// dropped without calling the receiver again.
+	seedJob(t, pool, "djproc_ack1", sub.URL, 3, 0)
+	callProcess(t, base, "djproc_ack1", auth.Sign("djproc_ack1"))
+	callProcess(t, base, "djproc_ack1", auth.Sign("djproc_ack1"))
+	status, _, _ := jobRow(t, pool, "djproc_ack1")
+	assert.Equal(t, "PROCESSING", status)
+	assert.EqualValues(t, 1, deliveries.Load())
+
+	_, err := acks.Ack(ctx, "djproc_ack1", "wrong-token")
+	require.ErrorIs(t, err, processing.ErrNotAwaitingAck)
+	st, err := acks.Ack(ctx, "djproc_ack1", "tok-djproc_ack1")
+	require.NoError(t, err)
+	assert.Equal(t, "COMPLETED", string(st))
+	status, _, _ = jobRow(t, pool, "djproc_ack1")
+	assert.Equal(t, "COMPLETED", status)
+	_, err = acks.Ack(ctx, "djproc_ack1", "tok-djproc_ack1")
+	require.ErrorIs(t, err, processing.ErrNotAwaitingAck, "a settled job takes no second ack")
+
+	// A nack spends the attempt and schedules a retry.
+	seedJob(t, pool, "djproc_nack1", sub.URL, 3, 0)
+	callProcess(t, base, "djproc_nack1", auth.Sign("djproc_nack1"))
+	st, err = acks.Nack(ctx, "djproc_nack1", "tok-djproc_nack1", "downstream rejected")
+	require.NoError(t, err)
+	assert.Equal(t, "PENDING", string(st))
+	status, attempts, scheduled := jobRow(t, pool, "djproc_nack1")
+	assert.Equal(t, "PENDING", status)
+	assert.EqualValues(t, 1, attempts)
+	require.NotNil(t, scheduled)
+
+	// A passed deadline counts as a nack: on the last attempt, FAILED.
+	seedJob(t, pool, "djproc_expire1", sub.URL, 1, 0)
+	callProcess(t, base, "djproc_expire1", auth.Sign("djproc_expire1"))
+	_, err = pool.Exec(ctx, `UPDATE msg_dispatch_jobs SET ack_deadline = NOW() - INTERVAL '1 second' WHERE id = 'djproc_expire1'`)
+	require.NoError(t, err)
+	n, err := acks.ExpireAcks(ctx)
+	require.NoError(t, err)
+	assert.GreaterOrEqual(t, n, 1)
+	status, _, _ = jobRow(t, pool, "djproc_expire1")
+	assert.Equal(t, "FAILED", status)
+}
+
+func TestPull_LeaseSettlesLikeAsyncAck(t *testing.T) {
+	pool := testpg.Pool(t)
+	ctx := context.Background()
+	repo := dispatchjob.NewRepository(pool)
+	acks := processing.NewAcks(repo, nil)
+	pull := processing.NewPull(repo, nil)
+
+	for i, id := range []string{"djpull_b", "djpull_a"} {
+		_, err := pool.Exec(ctx,
+			`INSERT INTO msg_dispatch_jobs
+			     (id, code, target_url, protocol, status, data_only, payload, max_retries, subscription_id, sequence)
+			 VALUES ($1, 'proc:test:evt', '', 'PULL', 'PENDING', TRUE, '{"hello":"world"}', 3, 'sub_pullTest01', $2)`,
+			id, 2-i)
+		require.NoError(t, err)
+	}
+
+	l, err := pull.Lease(ctx, "sub_pullTest01", nil, 10, time.Minute, 0)
+	require.NoError(t, err)
+	require.Len(t, l.Jobs, 2)
+	assert.Equal(t, "djpull_a", l.Jobs[0].Job.ID, "leased in sequence order")
+	assert.Equal(t, dispatchjob.ProtocolPull, l.Jobs[0].Job.Protocol)
+	assert.JSONEq(t, `{"hello":"world"}`, string(l.Jobs[0].Body))
+	status, _, _ := jobRow(t, pool, "djpull_a")
+	assert.Equal(t, "PROCESSING", status)
+
+	again, err := pull.Lease(ctx, "sub_pullTest01", nil, 10, time.Minute, 0)
+	require.NoError(t, err)
+	assert.Empty(t, again.Jobs, "a leased job is not handed out twice")
+
+	st, err := acks.Ack(ctx, "djpull_a", l.Token)
+	require.NoError(t, err)
+	assert.Equal(t, "COMPLETED", string(st))
+
+	// A nack retries after the backoff: not leasable until then.
+	st, err = acks.Nack(ctx, "djpull_b", l.Token, "consumer failed")
+	require.NoError(t, err)
+	assert.Equal(t, "PENDING", string(st))
+	status, attempts, scheduled := jobRow(t, pool, "djpull_b")
+	assert.Equal(t, "PENDING", status)
+	assert.EqualValues(t, 1, attempts)
+	require.NotNil(t, scheduled)
+
+	start := time.Now()
+	again, err = pull.Lease(ctx, "sub_pullTest01", nil, 10, time.Minute, 1500*time.Millisecond)
+	require.NoError(t, err)
+	assert.Empty(t, again.Jobs)
+	assert.GreaterOrEqual(t, time.Since(start), time.Second, "an empty lease waits before answering")
+}
+
+func TestPull_StreamWindowAndResume(t *testing.T) {
+	pool := testpg.Pool(t)
+	ctx := context.Background()
+	repo := dispatchjob.NewRepository(pool)
+	acks := processing.NewAcks(repo, nil)
+	pull := processing.NewPull(repo, nil)
+
+	for i, id := range []string{"djstream_1", "djstream_2", "djstream_3"} {
+		_, err := pool.Exec(ctx,
+			`INSERT INTO msg_dispatch_jobs
+			     (id, code, target_url, protocol, status, data_only, payload, max_retries, subscription_id, sequence)
+			 VALUES ($1, 'proc:test:evt', '', 'PULL', 'PENDING', TRUE, '{}', 3, 'sub_pullStream01', $2)`,
+			id, i)
+		require.NoError(t, err)
+	}
+
+	s, resumed, err := pull.OpenStream(ctx, "sub_pullStream01", nil, "", 2, time.Minute)
+	require.NoError(t, err)
+	assert.Empty(t, resumed.Jobs, "a fresh stream has nothing to resend")
+
+	l, err := s.Next(ctx)
+	require.NoError(t, err)
+	require.Len(t, l.Jobs, 2, "the window caps what is in flight")
+	assert.Equal(t, s.Token, l.Token)
+	l, err = s.Next(ctx)
+	require.NoError(t, err)
+	assert.Empty(t, l.Jobs, "nothing more until an ack makes room")
+
+	_, err = acks.Ack(ctx, "djstream_1", s.Token)
+	require.NoError(t, err)
+	l, err = s.Next(ctx)
+	require.NoError(t, err)
+	require.Len(t, l.Jobs, 1)
+	assert.Equal(t, "djstream_3", l.Jobs[0].Job.ID)
+
+	// Reconnecting with the cursor takes back the unacked jobs, in order.
+	_, resumed, err = pull.OpenStream(ctx, "sub_pullStream01", nil, s.Token, 2, time.Minute)
+	require.NoError(t, err)
+	require.Len(t, resumed.Jobs, 2)
+	assert.Equal(t, "djstream_2", resumed.Jobs[0].Job.ID)
+	assert.Equal(t, "djstream_3", resumed.Jobs[1].Job.ID)
+
+	_, resumed, err = pull.OpenStream(ctx, "sub_otherStream1", nil, s.Token, 2, time.Minute)
+	require.NoError(t, err)
+	assert.Empty(t, resumed.Jobs, "a cursor only resumes its own subscription")
+}
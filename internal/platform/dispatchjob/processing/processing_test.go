@@ -1,14 +1,31 @@
 package processing
 
 import (
+	"context"
 	"encoding/json"
+	"encoding/pem"
+	"errors"
+	"io"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"sync/atomic"
 	"testing"
 	"time"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/dynamicpb"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/schemaregistry"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/webhook"
 )
 
 func strp(s string) *string { return &s }
@@ -78,9 +95,484 @@ func TestParseDeferral(t *testing.T) {
 	assert.False(t, ok)
 }
 
+func TestParseAckRequest(t *testing.T) {
+	tok, d, ok := parseAckRequest(http.StatusAccepted, []byte(`{"ackToken":"t-1","ackTimeoutSeconds":60}`))
+	require.True(t, ok)
+	assert.Equal(t, "t-1", tok)
+	assert.Equal(t, time.Minute, d)
+
+	_, d, ok = parseAckRequest(http.StatusAccepted, []byte(`{"ackToken":"t-1"}`))
+	require.True(t, ok)
+	assert.Equal(t, defaultAckTimeout, d, "default deadline when ackTimeoutSeconds absent")
+
+	_, d, ok = parseAckRequest(http.StatusAccepted, []byte(`{"ackToken":"t-1","ackTimeoutSeconds":999999}`))
+	require.True(t, ok)
+	assert.Equal(t, maxAckTimeout, d, "clamps to the maximum deadline")
+
+	_, _, ok = parseAckRequest(http.StatusOK, []byte(`{"ackToken":"t-1"}`))
+	assert.False(t, ok, "only a 202 defers the outcome")
+
+	_, _, ok = parseAckRequest(http.StatusAccepted, []byte(`{"ack":true}`))
+	assert.False(t, ok, "a 202 without a token is a plain success")
+
+	_, _, ok = parseAckRequest(http.StatusAccepted, nil)
+	assert.False(t, ok)
+}
+
+func TestBatchBodyAndResults(t *testing.T) {
+	items := [][]byte{batchItem([]byte(`{"a":1}`)), batchItem([]byte("plain text"))}
+	assert.JSONEq(t, `[{"a":1},"plain text"]`, string(batchBody(items)), "a non-JSON body is quoted")
+
+	assert.Equal(t, 2, batchFit(items, 0, true), "no cap")
+	assert.Equal(t, 1, batchFit(items, 10, true), `[{"a":1}] fits, the second does not`)
+	assert.Equal(t, 1, batchFit(items, 1, true), "the first item always goes")
+	assert.Equal(t, 2, batchFit(items, 19, false), "without the array's brackets and commas both fit")
+
+	results := parseBatchResults([]byte(`{"results":[{"id":"dsj_1","status":200},{"id":"dsj_2","status":429,"delaySeconds":5},{"id":"dsj_3","status":422,"error":"bad amount"},{"status":500}]}`))
+	require.Len(t, results, 3, "an entry without an id is ignored")
+	ok := deliveryResult{success: true, statusCode: 200, hasStatus: true}
+
+	assert.True(t, itemOutcome(ok, results["dsj_1"]).success)
+	deferred := itemOutcome(ok, results["dsj_2"])
+	assert.True(t, deferred.deferral)
+	assert.Equal(t, 5*time.Second, deferred.retryAfter)
+	failed := itemOutcome(ok, results["dsj_3"])
+	assert.False(t, failed.success)
+	assert.Equal(t, "item status 422: bad amount", failed.errMessage)
+
+	assert.Empty(t, parseBatchResults([]byte(`{"ok":true}`)))
+	assert.Empty(t, parseBatchResults([]byte("not json")))
+}
+
 func TestBackoffFor(t *testing.T) {
 	assert.Equal(t, 5*time.Second, backoffFor(1))
 	assert.Equal(t, 15*time.Second, backoffFor(2))
 	assert.Equal(t, 120*time.Second, backoffFor(99), "clamps to the last backoff")
 	assert.Equal(t, 5*time.Second, backoffFor(0), "guards a non-positive attempt number")
 }
+
+type fakeHeaders struct {
+	h   http.Header
+	err error
+}
+
+func (f fakeHeaders) DeliveryHeaders(context.Context, string) (http.Header, error) { return f.h, f.err }
+
+func TestDeliver_CustomHeaders(t *testing.T) {
+	var got http.Header
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		got = r.Header.Clone()
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	job := &dispatchjob.DispatchJob{ID: "dsj_1", Code: "app:sub:agg:created", TargetURL: srv.URL, SubscriptionID: strp("sub_1"), DataOnly: true}
+
+	h := New(nil, nil)
+	h.SetHeaderSource(fakeHeaders{h: http.Header{"X-Tenant": {"acme"}, "X-Event-Type": {"spoofed"}}})
+	res := h.deliver(context.Background(), job)
+	require.True(t, res.success)
+	assert.Equal(t, "acme", got.Get("X-Tenant"))
+	assert.Equal(t, "app:sub:agg:created", got.Get("X-Event-Type"), "platform headers win over custom ones")
+
+	// An unresolvable secret fails the attempt instead of delivering
+	// without the header.
+	got = nil
+	h.SetHeaderSource(fakeHeaders{err: errors.New("no provider for scheme \"vault\"")})
+	res = h.deliver(context.Background(), job)
+	assert.False(t, res.success)
+	assert.Equal(t, dispatchjob.ErrorValidation, res.errType)
+	assert.Nil(t, got, "nothing is sent")
+}
+
+// fakeSigner signs like signingkey.Signer, with a fixed key.
+type fakeSigner struct{ kid, secret string }
+
+func (f fakeSigner) SignDelivery(_ context.Context, _ string, body []byte) (http.Header, error) {
+	ts := time.Now().UTC().Format("2006-01-02T15:04:05.000Z")
+	return http.Header{
+		webhook.SignatureHeader: {webhook.Sign(f.secret, ts, body)},
+		webhook.TimestampHeader: {ts},
+		webhook.KeyIDHeader:     {f.kid},
+	}, nil
+}
+
+func TestDeliver_SignsWithClientKey(t *testing.T) {
+	ring := webhook.NewKeyRing(map[string]string{"2026-01": "old", "2026-10": "new"})
+	var verifyErr error
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		body, _ := io.ReadAll(r.Body)
+		verifyErr = ring.Verify(body, r.Header.Get(webhook.SignatureHeader),
+			r.Header.Get(webhook.TimestampHeader), r.Header.Get(webhook.KeyIDHeader))
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	job := &dispatchjob.DispatchJob{ID: "dsj_1", Code: "app:sub:agg:created", TargetURL: srv.URL, ClientID: strp("clt_1"), Payload: strp(`{"a":1}`), DataOnly: true}
+
+	h := New(nil, nil)
+	h.SetSigner(fakeSigner{kid: "2026-10", secret: "new"})
+	require.True(t, h.deliver(context.Background(), job).success)
+	assert.NoError(t, verifyErr, "the receiver verifies with the key the delivery names")
+
+	h.SetSigner(fakeSigner{kid: "2026-10", secret: "leaked"})
+	require.True(t, h.deliver(context.Background(), job).success)
+	assert.ErrorIs(t, verifyErr, webhook.ErrBadSignature)
+}
+
+// countingTransport dials directly, counting what goes through it.
+type countingTransport struct{ dials *atomic.Int32 }
+
+func (c countingTransport) DialContext(ctx context.Context, network, addr string) (net.Conn, error) {
+	c.dials.Add(1)
+	var d net.Dialer
+	return d.DialContext(ctx, network, addr)
+}
+
+func (countingTransport) Close() error { return nil }
+
+type fakeTransportSource struct{ name *string }
+
+func (f fakeTransportSource) TransportFor(context.Context, string) (*string, error) { return f.name, nil }
+
+func TestDeliver_ViaTransport(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	var dials atomic.Int32
+	outbound.RegisterTransport("test-counting", func(string, outbound.TransportSpec, outbound.TransportEnv) (outbound.Transport, error) {
+		return countingTransport{dials: &dials}, nil
+	})
+	ts, err := outbound.NewTransports(`{"bastion":{"type":"test-counting","address":"bastion.test:22"}}`, outbound.TransportEnv{})
+	require.NoError(t, err)
+	job := &dispatchjob.DispatchJob{ID: "dsj_1", Code: "app:sub:agg:created", TargetURL: srv.URL, SubscriptionID: strp("sub_1"), DataOnly: true}
+
+	h := New(nil, nil)
+	h.SetTransports(fakeTransportSource{name: strp("bastion")}, ts)
+	require.True(t, h.deliver(context.Background(), job).success)
+	assert.Equal(t, int32(1), dials.Load())
+
+	// A transport the deployment no longer has fails the attempt rather
+	// than falling back to direct egress.
+	h.SetTransports(fakeTransportSource{name: strp("gone")}, ts)
+	res := h.deliver(context.Background(), job)
+	assert.False(t, res.success)
+	assert.Equal(t, dispatchjob.ErrorValidation, res.errType)
+
+	h.SetTransports(fakeTransportSource{}, ts)
+	require.True(t, h.deliver(context.Background(), job).success)
+	assert.Equal(t, int32(1), dials.Load(), "no transport delivers directly")
+}
+
+type fakeTrustSource struct{ trust *outbound.TLSTrust }
+
+func (f fakeTrustSource) TLSTrustFor(context.Context, string) (*outbound.TLSTrust, error) {
+	return f.trust, nil
+}
+
+func TestDeliver_TLSTrust(t *testing.T) {
+	srv := httptest.NewTLSServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	bundle := string(pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: srv.Certificate().Raw}))
+	job := &dispatchjob.DispatchJob{ID: "dsj_1", Code: "app:sub:agg:created", TargetURL: srv.URL, SubscriptionID: strp("sub_1"), DataOnly: true}
+
+	// The test CA isn't a system root; retrying won't change that.
+	h := New(nil, nil)
+	h.SetTLSTrust(outbound.TLSTrust{}, fakeTrustSource{})
+	res := h.deliver(context.Background(), job)
+	assert.False(t, res.success)
+	assert.Equal(t, dispatchjob.ErrorValidation, res.errType)
+	assert.Contains(t, res.errMessage, "TLS trust failure")
+
+	h.SetTLSTrust(outbound.TLSTrust{}, fakeTrustSource{trust: &outbound.TLSTrust{CABundle: bundle}})
+	require.True(t, h.deliver(context.Background(), job).success, "the subscription's bundle trusts it")
+
+	h = New(nil, nil)
+	h.SetTLSTrust(outbound.TLSTrust{CABundle: bundle}, fakeTrustSource{})
+	require.True(t, h.deliver(context.Background(), job).success, "so does the deployment's")
+
+	// A subscription's pins apply on top of the deployment's roots.
+	pinned := &outbound.TLSTrust{SPKIPins: []string{"sha256/" + strings.Repeat("A", 43) + "="}}
+	h.SetTLSTrust(outbound.TLSTrust{CABundle: bundle}, fakeTrustSource{trust: pinned})
+	res = h.deliver(context.Background(), job)
+	assert.False(t, res.success)
+	assert.Equal(t, dispatchjob.ErrorValidation, res.errType)
+
+	pinned.SPKIPins = append(pinned.SPKIPins, outbound.SPKIPin(srv.Certificate()))
+	require.True(t, h.deliver(context.Background(), job).success)
+}
+
+type fixedFormat subscription.PayloadFormat
+
+func (f fixedFormat) PayloadFormatFor(context.Context, string) (subscription.PayloadFormat, error) {
+	return subscription.PayloadFormat(f), nil
+}
+
+type fakeSchemas struct{ et *eventtype.EventType }
+
+func (f fakeSchemas) FindByCode(context.Context, string) (*eventtype.EventType, error) { return f.et, nil }
+
+const orderSchema = `{"messageType":"acme.orders.v1.OrderCreated","fileDescriptorSet":{"file":[{"name":"orders.proto","package":"acme.orders.v1","syntax":"proto3",
+	"messageType":[{"name":"OrderCreated","field":[
+		{"name":"order_id","number":1,"label":"LABEL_OPTIONAL","type":"TYPE_STRING"},
+		{"name":"total","number":2,"label":"LABEL_OPTIONAL","type":"TYPE_INT64"}]}]}]}}`
+
+func TestDeliver_PayloadFormats(t *testing.T) {
+	var got http.Header
+	var body []byte
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		got = r.Header.Clone()
+		body, _ = io.ReadAll(r.Body)
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	job := &dispatchjob.DispatchJob{
+		ID: "dsj_1", Code: "orders:sales:order:created", TargetURL: srv.URL, SubscriptionID: strp("sub_1"),
+		EventID: strp("evt_1"), Payload: strp(`{"orderId":"o1","total":"42","note":"dropped"}`), DataOnly: true,
+	}
+	h := New(nil, nil)
+
+	h.SetPayloadFormats(fixedFormat(subscription.FormatCloudEvents), fakeSchemas{})
+	require.True(t, h.deliver(context.Background(), job).success)
+	assert.Equal(t, "application/cloudevents+json", got.Get("Content-Type"))
+	msgs, err := webhook.Decode(got, body)
+	require.NoError(t, err)
+	require.Len(t, msgs, 1)
+	assert.Equal(t, "evt_1", msgs[0].CloudEvent.ID)
+	assert.Equal(t, "dsj_1", msgs[0].JobID)
+	assert.JSONEq(t, *job.Payload, string(msgs[0].Data))
+
+	h.SetPayloadFormats(fixedFormat(subscription.FormatNDJSON), fakeSchemas{})
+	require.True(t, h.deliver(context.Background(), job).success)
+	assert.Equal(t, "application/x-ndjson", got.Get("Content-Type"))
+	assert.Equal(t, `{"orderId":"o1","total":"42","note":"dropped"}`+"\n", string(body))
+
+	// PROTOBUF needs a CURRENT PROTO schema; without one the attempt fails
+	// as a validation error and nothing is sent.
+	et, err := eventtype.New(job.Code, "Order Created")
+	require.NoError(t, err)
+	h.SetPayloadFormats(fixedFormat(subscription.FormatProtobuf), fakeSchemas{et: et})
+	got = nil
+	res := h.deliver(context.Background(), job)
+	assert.False(t, res.success)
+	assert.Equal(t, dispatchjob.ErrorValidation, res.errType)
+	assert.Nil(t, got, "nothing is sent")
+
+	et.SpecVersions = []eventtype.SpecVersion{{ID: "sch_1", Version: "1.0", SchemaContent: json.RawMessage(orderSchema),
+		SchemaType: eventtype.SchemaProto, Status: eventtype.SpecCurrent}}
+	require.True(t, h.deliver(context.Background(), job).success)
+	assert.Equal(t, "application/x-protobuf; messageType=acme.orders.v1.OrderCreated", got.Get("Content-Type"))
+	msgs, err = webhook.Decode(got, body)
+	require.NoError(t, err)
+	require.Len(t, msgs, 1)
+	assert.Equal(t, "dsj_1", msgs[0].JobID)
+	md, err := eventtype.CompileProtoSchema(json.RawMessage(orderSchema))
+	require.NoError(t, err)
+	order := dynamicpb.NewMessage(md)
+	require.NoError(t, proto.Unmarshal(msgs[0].Data, order))
+	assert.Equal(t, "o1", order.Get(md.Fields().ByName("order_id")).String())
+	assert.Equal(t, int64(42), order.Get(md.Fields().ByName("total")).Int())
+}
+
+type fixedLargePayloads subscription.LargePayloads
+
+func (f fixedLargePayloads) LargePayloadsFor(context.Context, string) (subscription.LargePayloads, error) {
+	return subscription.LargePayloads(f), nil
+}
+
+// presigningStore is a file store that pre-signs.
+type presigningStore struct{ blobstore.Store }
+
+func (presigningStore) PresignGet(_ context.Context, key string, _ time.Duration) (string, error) {
+	return "https://blobs.example.test/" + key + "?sig=x", nil
+}
+
+func TestDeliver_OffloadedPayload(t *testing.T) {
+	var body []byte
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		body, _ = io.ReadAll(r.Body)
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	ctx := context.Background()
+	files, err := blobstore.Open(ctx, "file://"+t.TempDir())
+	require.NoError(t, err)
+	big := `{"orderId":"o1","lines":["a","b","c","d","e","f"]}`
+	ref, err := blobstore.NewOffloader(files, 8, 0).Offload(ctx, "events/evt_1", json.RawMessage(big))
+	require.NoError(t, err)
+	job := &dispatchjob.DispatchJob{
+		ID: "dsj_1", Code: "orders:sales:order:created", TargetURL: srv.URL, SubscriptionID: strp("sub_1"),
+		Payload: strp(string(ref)), DataOnly: true,
+	}
+	h := New(nil, nil)
+
+	res := h.deliver(ctx, job)
+	assert.False(t, res.success, "no blob store configured")
+	assert.Equal(t, dispatchjob.ErrorConnection, res.errType)
+
+	h.SetBlobs(blobstore.NewOffloader(files, 8, 0), fixedLargePayloads(subscription.LargePayloadsPresignedURL))
+	require.True(t, h.deliver(ctx, job).success)
+	assert.JSONEq(t, big, string(body), "a store that cannot pre-sign delivers inline")
+	assert.Equal(t, string(ref), *job.Payload, "the job keeps its reference")
+
+	h.SetBlobs(blobstore.NewOffloader(presigningStore{files}, 8, time.Minute), fixedLargePayloads(subscription.LargePayloadsPresignedURL))
+	require.True(t, h.deliver(ctx, job).success)
+	signed, ok := blobstore.ParseRef(body)
+	require.True(t, ok, "delivered the reference: %s", body)
+	assert.Equal(t, "https://blobs.example.test/events/evt_1?sig=x", signed.URL)
+	assert.NotNil(t, signed.ExpiresAt)
+
+	h.SetBlobs(blobstore.NewOffloader(presigningStore{files}, 8, 0), fixedLargePayloads(subscription.LargePayloadsInline))
+	require.True(t, h.deliver(ctx, job).success)
+	assert.JSONEq(t, big, string(body))
+}
+
+func TestDeliver_SchemaHeaders(t *testing.T) {
+	const schema = `{"type":"record","name":"Order","fields":[{"name":"id","type":"string"}]}`
+	var registryUp atomic.Bool
+	registryUp.Store(true)
+	registry := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if !registryUp.Load() || r.URL.Path != "/schemas/ids/12" {
+			w.WriteHeader(http.StatusServiceUnavailable)
+			return
+		}
+		_ = json.NewEncoder(w).Encode(map[string]string{"schema": schema})
+	}))
+	defer registry.Close()
+	var got http.Header
+	var body []byte
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		got = r.Header.Clone()
+		body, _ = io.ReadAll(r.Body)
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	ctx := context.Background()
+	job := &dispatchjob.DispatchJob{ID: "dsj_1", Code: "orders:sales:order:created", TargetURL: srv.URL, SchemaID: strp("12"), Payload: strp(`{"id":"o1"}`)}
+
+	h := New(nil, nil)
+	require.True(t, h.deliver(ctx, job).success)
+	assert.Equal(t, "12", got.Get("X-Schema-Id"))
+	assert.Empty(t, got.Get("X-Schema-Fingerprint"), "no registry configured")
+	var env map[string]any
+	require.NoError(t, json.Unmarshal(body, &env))
+	assert.Equal(t, "12", env["schemaId"])
+
+	h.SetSchemaRegistry(schemaregistry.New(schemaregistry.Config{URL: registry.URL}))
+	require.True(t, h.deliver(ctx, job).success)
+	assert.Equal(t, schemaregistry.Fingerprint(schema), got.Get("X-Schema-Fingerprint"))
+
+	// The fingerprint is cached; a registry outage doesn't hold deliveries back.
+	registryUp.Store(false)
+	require.True(t, h.deliver(ctx, job).success)
+	assert.Equal(t, schemaregistry.Fingerprint(schema), got.Get("X-Schema-Fingerprint"))
+	h.SetSchemaRegistry(schemaregistry.New(schemaregistry.Config{URL: registry.URL}))
+	require.True(t, h.deliver(ctx, job).success)
+	assert.Equal(t, "12", got.Get("X-Schema-Id"))
+	assert.Empty(t, got.Get("X-Schema-Fingerprint"))
+}
+
+func TestProbe(t *testing.T) {
+	var got http.Header
+	var body []byte
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		got = r.Header.Clone()
+		body, _ = io.ReadAll(r.Body)
+		w.WriteHeader(http.StatusBadRequest)
+		_, _ = w.Write([]byte("bad signature"))
+	}))
+	defer srv.Close()
+	sub := &subscription.Subscription{ID: "sub_1", Code: "orders-hook", Endpoint: srv.URL, DataOnly: true}
+
+	h := New(nil, nil)
+	h.SetHeaderSource(fakeHeaders{h: http.Header{"X-Tenant": {"acme"}}})
+	h.SetPayloadFormats(fixedFormat(subscription.FormatProtobuf), fakeSchemas{})
+	res := h.Probe(context.Background(), sub)
+	assert.False(t, res.Success)
+	require.NotNil(t, res.StatusCode)
+	assert.Equal(t, http.StatusBadRequest, *res.StatusCode)
+	assert.Equal(t, "bad signature", res.Response)
+	assert.Equal(t, "true", got.Get("X-Dispatch-Test"))
+	assert.Equal(t, ProbeEventType, got.Get("X-Event-Type"))
+	assert.Equal(t, "acme", got.Get("X-Tenant"))
+	assert.Equal(t, "application/json", got.Get("Content-Type"), "PROTOBUF probes go as JSON")
+	assert.JSONEq(t, `"orders-hook"`, mustField(t, body, "subscriptionCode"))
+
+	pull := &subscription.Subscription{ID: "sub_2", Delivery: subscription.DeliveryPull}
+	assert.Equal(t, "subscription has no endpoint to test", h.Probe(context.Background(), pull).Error)
+}
+
+func mustField(t *testing.T, body []byte, key string) string {
+	t.Helper()
+	var m map[string]json.RawMessage
+	require.NoError(t, json.Unmarshal(body, &m))
+	return string(m[key])
+}
+
+// fakeShadows serves one shadow endpoint and hands recorded outcomes to
+// the test.
+type fakeShadows struct {
+	endpoint *string
+	recorded chan *subscription.ShadowDelivery
+}
+
+func (f fakeShadows) ShadowEndpointFor(context.Context, string) (*string, error) {
+	return f.endpoint, nil
+}
+
+func (f fakeShadows) RecordShadow(_ context.Context, d *subscription.ShadowDelivery) error {
+	f.recorded <- d
+	return nil
+}
+
+func TestShadow(t *testing.T) {
+	var shadowHeader atomic.Value
+	primary := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer primary.Close()
+	shadow := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		shadowHeader.Store(r.Header.Get("X-Dispatch-Shadow"))
+		w.WriteHeader(http.StatusBadGateway)
+	}))
+	defer shadow.Close()
+	job := &dispatchjob.DispatchJob{
+		ID: "dsj_1", Code: "app:sub:agg:created", TargetURL: primary.URL, SubscriptionID: strp("sub_1"),
+		Protocol: dispatchjob.ProtocolHTTPWebhook, DataOnly: true,
+	}
+
+	h := New(nil, nil)
+	src := fakeShadows{endpoint: strp(shadow.URL), recorded: make(chan *subscription.ShadowDelivery, 1)}
+	h.SetShadows(src)
+	attempt := dispatchjob.NewAttempt(1)
+	res := h.deliver(context.Background(), job)
+	attempt.CompleteSuccess(res.statusCode, res.body)
+	require.True(t, res.success)
+	h.shadow(context.Background(), job, res, attempt)
+
+	select {
+	case d := <-src.recorded:
+		assert.Equal(t, "sub_1", d.SubscriptionID)
+		assert.Equal(t, "dsj_1", d.DispatchJobID)
+		assert.Equal(t, shadow.URL, d.ShadowEndpoint)
+		assert.True(t, d.Primary.Success)
+		assert.Equal(t, int32(200), *d.Primary.StatusCode)
+		assert.False(t, d.Shadow.Success)
+		assert.Equal(t, int32(502), *d.Shadow.StatusCode)
+		require.NotNil(t, d.Shadow.Error)
+	case <-time.After(5 * time.Second):
+		t.Fatal("shadow outcome not recorded")
+	}
+	assert.Equal(t, "true", shadowHeader.Load())
+	assert.Equal(t, primary.URL, job.TargetURL, "the job itself is untouched")
+
+	// No shadow endpoint, nothing copied.
+	h.SetShadows(fakeShadows{recorded: src.recorded})
+	h.shadow(context.Background(), job, res, attempt)
+	select {
+	case <-src.recorded:
+		t.Fatal("recorded a shadow for a subscription without one")
+	case <-time.After(50 * time.Millisecond):
+	}
+}
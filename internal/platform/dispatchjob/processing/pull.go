@@ -0,0 +1,190 @@
+package processing
+
+import (
+	"context"
+	"crypto/rand"
+	"log/slog"
+	"sort"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+)
+
+// Pull delivery. A PULL subscription's dispatch jobs are never pushed:
+// they wait PENDING until the consumer leases them through
+// GET /api/consume/{subscriptionId}. A lease puts a job in the state an
+// asynchronously acknowledged delivery waits in (ack.go) — PROCESSING
+// under a token until a deadline — so an ack, a nack and an expired lease
+// settle it exactly as they settle a push: completed, retried after the
+// usual backoff, or FAILED on its last attempt. A stream (Stream) leases
+// the same way, continuously, over one connection.
+
+const (
+	// DefaultPullLease applies when the consumer names no lease.
+	DefaultPullLease = time.Minute
+	// MaxPullLease caps the lease, like maxAckTimeout caps an async ack.
+	MaxPullLease = maxAckTimeout
+	// DefaultPullBatch and MaxPullBatch bound the jobs one lease hands out.
+	DefaultPullBatch = 10
+	MaxPullBatch     = 100
+	// DefaultStreamWindow is a stream's unacked-job limit when the
+	// consumer names none; it is capped at MaxPullBatch.
+	DefaultStreamWindow = 10
+	// MaxPullWait caps how long a consume request may wait for a job.
+	MaxPullWait = time.Minute
+	// pullPollInterval is how often a waiting consume request looks again.
+	pullPollInterval = time.Second
+)
+
+// Leased is one job handed to a pull consumer, with the body a webhook
+// would have received.
+type Leased struct {
+	Job  *dispatchjob.DispatchJob
+	Body []byte
+}
+
+// Lease is the jobs leased together under one token.
+type Lease struct {
+	Token    string
+	Deadline time.Time
+	Jobs     []Leased
+}
+
+// Pull leases pull subscriptions' jobs to their consumers.
+type Pull struct {
+	repo         *dispatchjob.Repository
+	killSwitches KillSwitches
+	blobs        *blobstore.Offloader // set by SetBlobs (blob.go)
+}
+
+// NewPull wires the leaser. killSwitches may be nil: nothing is held.
+func NewPull(repo *dispatchjob.Repository, killSwitches KillSwitches) *Pull {
+	return &Pull{repo: repo, killSwitches: killSwitches}
+}
+
+// Lease leases up to limit of subscriptionID's due jobs for lease, in
+// sequence then creation order. When none is due it looks again every
+// second until wait has passed or ctx is cancelled, then returns an empty
+// lease. clientID is the subscription's owner, for the kill switch check.
+func (p *Pull) Lease(ctx context.Context, subscriptionID string, clientID *string, limit int, lease, wait time.Duration) (*Lease, error) {
+	giveUp := time.Now().Add(wait)
+	for {
+		l, err := p.leaseOnce(ctx, subscriptionID, clientID, rand.Text(), limit, lease)
+		if err != nil || len(l.Jobs) > 0 {
+			return l, err
+		}
+		pause := min(pullPollInterval, time.Until(giveUp))
+		if pause <= 0 {
+			return l, nil
+		}
+		select {
+		case <-ctx.Done():
+			return l, nil
+		case <-time.After(pause):
+		}
+	}
+}
+
+func (p *Pull) leaseOnce(ctx context.Context, subscriptionID string, clientID *string, token string, limit int, lease time.Duration) (*Lease, error) {
+	l := &Lease{Token: token, Deadline: time.Now().Add(lease), Jobs: []Leased{}}
+	if p.killed(ctx, subscriptionID, clientID) {
+		return l, nil
+	}
+	ids, err := p.repo.LeasePull(ctx, subscriptionID, l.Token, l.Deadline, limit)
+	if err != nil {
+		return nil, err
+	}
+	return p.load(ctx, l, ids)
+}
+
+// load fills l with the jobs behind ids, in sequence then creation order.
+func (p *Pull) load(ctx context.Context, l *Lease, ids []string) (*Lease, error) {
+	for _, id := range ids {
+		job, err := p.repo.FindByID(ctx, id)
+		if err != nil {
+			// Already leased: the lease runs out and the job is retried.
+			return nil, err
+		}
+		if job == nil {
+			continue
+		}
+		payload := job
+		if ref, ok := payloadRef(job); ok {
+			if payload, err = rehydrate(ctx, p.blobs, job, ref); err != nil {
+				return nil, err
+			}
+		}
+		l.Jobs = append(l.Jobs, Leased{Job: job, Body: buildPayload(payload)})
+	}
+	sort.Slice(l.Jobs, func(i, j int) bool {
+		a, b := l.Jobs[i].Job, l.Jobs[j].Job
+		if a.Sequence != b.Sequence {
+			return a.Sequence < b.Sequence
+		}
+		return a.CreatedAt.Before(b.CreatedAt)
+	})
+	return l, nil
+}
+
+// Stream is one streaming consumer's connection (GET
+// /api/consume/{subscriptionId}/stream). Every job it leases shares the
+// stream's token, which is also its resume cursor: the consumer acks
+// with it, and a reconnect presenting it takes the unacked jobs back.
+type Stream struct {
+	p              *Pull
+	subscriptionID string
+	clientID       *string
+	// Token is the lease token and resume cursor.
+	Token  string
+	window int
+	lease  time.Duration
+}
+
+// OpenStream starts a stream holding at most window unacked jobs, each
+// leased for lease. A non-empty cursor resumes an earlier stream of the
+// subscription: its unacked jobs are returned, their leases renewed, to
+// be sent again before anything new.
+func (p *Pull) OpenStream(ctx context.Context, subscriptionID string, clientID *string, cursor string, window int, lease time.Duration) (*Stream, *Lease, error) {
+	s := &Stream{p: p, subscriptionID: subscriptionID, clientID: clientID, Token: cursor, window: window, lease: lease}
+	l := &Lease{Token: cursor, Deadline: time.Now().Add(lease), Jobs: []Leased{}}
+	if cursor == "" {
+		s.Token = rand.Text()
+		l.Token = s.Token
+		return s, l, nil
+	}
+	ids, err := p.repo.RenewPullLease(ctx, subscriptionID, cursor, l.Deadline)
+	if err != nil {
+		return nil, nil, err
+	}
+	l, err = p.load(ctx, l, ids)
+	if err != nil {
+		return nil, nil, err
+	}
+	return s, l, nil
+}
+
+// Next leases the due jobs that fit in the stream's window — none while
+// the consumer has window jobs unacked, which is its flow control.
+func (s *Stream) Next(ctx context.Context) (*Lease, error) {
+	inFlight, err := s.p.repo.CountPullLeased(ctx, s.subscriptionID, s.Token)
+	if err != nil {
+		return nil, err
+	}
+	if inFlight >= s.window {
+		return &Lease{Token: s.Token, Jobs: []Leased{}}, nil
+	}
+	return s.p.leaseOnce(ctx, s.subscriptionID, s.clientID, s.Token, s.window-inFlight, s.lease)
+}
+
+// killed reports whether a kill switch holds the subscription's jobs.
+func (p *Pull) killed(ctx context.Context, subscriptionID string, clientID *string) bool {
+	if p.killSwitches == nil {
+		return false
+	}
+	set, err := p.killSwitches.Snapshot(ctx)
+	if err != nil {
+		slog.Warn("dispatch pull: kill switch reload failed; using last known", "subscription_id", subscriptionID, "err", err)
+	}
+	return set.Stops(deref(clientID), subscriptionID, "")
+}
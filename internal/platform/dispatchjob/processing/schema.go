@@ -0,0 +1,52 @@
+package processing
+
+import (
+	"context"
+	"log/slog"
+	"net/http"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/schemaregistry"
+)
+
+// Schema registry ids. A job whose event was ingested with a registry
+// schema id (package schemaregistry) carries it to the receiver:
+//
+//	X-Schema-Id           the id, on every push of the job (and a batch of
+//	                      one). The JSON envelope has it as "schemaId", a
+//	                      CloudEvents event as the "schemaid" extension.
+//	X-Schema-Fingerprint  the schema's content fingerprint, when the
+//	                      registry is configured and knows the id.
+//
+// The fingerprint is best effort: the registry being down doesn't hold
+// deliveries back, it only leaves the header off.
+
+const (
+	headerSchemaID          = "X-Schema-Id"
+	headerSchemaFingerprint = "X-Schema-Fingerprint"
+)
+
+// SetSchemaRegistry adds schema fingerprints, looked up in registry, to
+// deliveries of jobs with a schema id. Without it they carry the id only.
+func (h *Handler) SetSchemaRegistry(registry *schemaregistry.Client) { h.registry = registry }
+
+// setSchemaHeaders sets job's schema headers on hdr.
+func (h *Handler) setSchemaHeaders(ctx context.Context, job *dispatchjob.DispatchJob, hdr http.Header) {
+	if job.SchemaID == nil || *job.SchemaID == "" {
+		return
+	}
+	hdr.Set(headerSchemaID, *job.SchemaID)
+	if h.registry == nil {
+		return
+	}
+	id, err := schemaregistry.ParseID(*job.SchemaID)
+	if err != nil {
+		return
+	}
+	s, err := h.registry.ByID(ctx, id)
+	if err != nil {
+		slog.Warn("dispatch process: schema fingerprint lookup failed", "job_id", job.ID, "schema_id", id, "err", err)
+		return
+	}
+	hdr.Set(headerSchemaFingerprint, s.Fingerprint)
+}
@@ -0,0 +1,98 @@
+package processing
+
+import (
+	"context"
+	"log/slog"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+// Shadow deliveries. A subscription with a shadow endpoint has each job
+// delivered on its own copied there once the primary attempt is recorded:
+// the same body and headers, plus X-Dispatch-Shadow, POSTed in the
+// background. Both outcomes are stored for the shadow report; the shadow's
+// never touches the job. Batched deliveries are not copied.
+
+// maxShadowsInFlight bounds the background shadow deliveries; a copy that
+// finds them all busy is skipped, so a slow shadow can't pile up work.
+const maxShadowsInFlight = 64
+
+// ShadowSource supplies a subscription's shadow endpoint, nil when it has
+// none, and stores shadow outcomes. Satisfied by *subscription.Repository.
+type ShadowSource interface {
+	ShadowEndpointFor(ctx context.Context, subscriptionID string) (*string, error)
+	RecordShadow(ctx context.Context, d *subscription.ShadowDelivery) error
+}
+
+// SetShadows copies deliveries to subscriptions' shadow endpoints. Without
+// it nothing is shadowed.
+func (h *Handler) SetShadows(src ShadowSource) {
+	h.shadows = src
+	h.shadowSlots = make(chan struct{}, maxShadowsInFlight)
+}
+
+// shadow copies job's delivery to its subscription's shadow endpoint, if it
+// has one, given the primary's result and attempt.
+func (h *Handler) shadow(ctx context.Context, job *dispatchjob.DispatchJob, res deliveryResult, attempt *dispatchjob.Attempt) {
+	if h.shadows == nil || job.SubscriptionID == nil || job.Protocol != dispatchjob.ProtocolHTTPWebhook {
+		return
+	}
+	endpoint, err := h.shadows.ShadowEndpointFor(ctx, *job.SubscriptionID)
+	if err != nil {
+		slog.Warn("dispatch process: load shadow endpoint failed", "job_id", job.ID, "err", err)
+		return
+	}
+	if endpoint == nil {
+		return
+	}
+	src, slots := h.shadows, h.shadowSlots
+	select {
+	case slots <- struct{}{}:
+	default:
+		slog.Debug("dispatch process: shadow skipped, too many in flight", "job_id", job.ID)
+		return
+	}
+	primary := shadowOutcome(res, attempt.DurationMillis)
+	copied := *job
+	copied.TargetURL = *endpoint
+	ctx = context.WithoutCancel(ctx)
+	go func() {
+		defer func() { <-slots }()
+		started := time.Now()
+		out := h.deliverShadow(ctx, &copied)
+		ms := time.Since(started).Milliseconds()
+		d := subscription.NewShadowDelivery(*job.SubscriptionID, job.ID, *endpoint, primary, shadowOutcome(out, &ms))
+		if err := src.RecordShadow(ctx, d); err != nil {
+			slog.Warn("dispatch process: record shadow failed", "job_id", job.ID, "err", err)
+		}
+	}()
+}
+
+// deliverShadow is deliver for a copy of a job aimed at a shadow endpoint.
+func (h *Handler) deliverShadow(ctx context.Context, job *dispatchjob.DispatchJob) deliveryResult {
+	body, hdr, failed := h.render(ctx, job)
+	if failed != nil {
+		return *failed
+	}
+	hdr.Set("X-Dispatch-Shadow", "true")
+	return h.post(ctx, job, body, hdr)
+}
+
+// shadowOutcome maps a delivery result onto the stored outcome.
+func shadowOutcome(res deliveryResult, durationMs *int64) subscription.ShadowOutcome {
+	out := subscription.ShadowOutcome{Success: res.success}
+	if durationMs != nil {
+		out.DurationMs = *durationMs
+	}
+	if res.hasStatus {
+		code := int32(res.statusCode)
+		out.StatusCode = &code
+	}
+	if !res.success && res.errMessage != "" {
+		msg := res.errMessage
+		out.Error = &msg
+	}
+	return out
+}
@@ -0,0 +1,108 @@
+package processing
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"sync"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+)
+
+// TLS trust. The deployment's trust (FC_OUTBOUND_TLS_*) is applied to every
+// delivery client; a subscription with its own gets a copy of the client it
+// would have used, its transport carrying both. The copies are cached per
+// base client and trust, so deliveries to one receiver share connections.
+
+// maxTrustedClients bounds the cached per-trust clients. Once full the
+// cache is dropped and rebuilt as deliveries need it.
+const maxTrustedClients = 256
+
+// TrustSource supplies a subscription's own TLS trust, nil when it has
+// none. Satisfied by *subscription.Repository.
+type TrustSource interface {
+	TLSTrustFor(ctx context.Context, subscriptionID string) (*outbound.TLSTrust, error)
+}
+
+// trustKey identifies a cached client: the client it was copied from and
+// a hash of the subscription's trust.
+type trustKey struct {
+	base  *http.Client
+	trust [sha256.Size]byte
+}
+
+// trustedClients caches the per-trust delivery clients.
+type trustedClients struct {
+	mu      sync.Mutex
+	clients map[trustKey]*http.Client
+}
+
+// SetTLSTrust applies the deployment's TLS trust to the delivery clients,
+// and layers each subscription's own over it. Call it after SetOutbound
+// and SetTransports, whose clients it amends. Without it deliveries trust
+// the system roots.
+func (h *Handler) SetTLSTrust(deployment outbound.TLSTrust, src TrustSource) {
+	h.tlsTrust = deployment
+	h.trusts = src
+	h.trusted = &trustedClients{clients: make(map[trustKey]*http.Client)}
+	if deployment.IsZero() {
+		return
+	}
+	cfg, err := deployment.ClientConfig()
+	if err != nil {
+		slog.Error("dispatch process: deployment TLS trust is invalid; using system roots", "err", err)
+		return
+	}
+	clients := []*http.Client{h.client}
+	for _, c := range h.clientsByID {
+		clients = append(clients, c)
+	}
+	for _, c := range h.clientsByTransport {
+		clients = append(clients, c)
+	}
+	for _, c := range clients {
+		c.Transport.(*http.Transport).TLSClientConfig = cfg.Clone()
+	}
+}
+
+// withTrust returns base, or a copy of it trusting what job's subscription
+// adds to the deployment's trust.
+func (h *Handler) withTrust(ctx context.Context, job *dispatchjob.DispatchJob, base *http.Client) (*http.Client, error) {
+	if h.trusts == nil || job.SubscriptionID == nil {
+		return base, nil
+	}
+	t, err := h.trusts.TLSTrustFor(ctx, *job.SubscriptionID)
+	if err != nil {
+		return nil, fmt.Errorf("load TLS trust: %w", err)
+	}
+	if t.IsZero() {
+		return base, nil
+	}
+	raw, _ := json.Marshal(t)
+	key := trustKey{base: base, trust: sha256.Sum256(raw)}
+
+	h.trusted.mu.Lock()
+	defer h.trusted.mu.Unlock()
+	if c, ok := h.trusted.clients[key]; ok {
+		return c, nil
+	}
+	cfg, err := h.tlsTrust.With(t).ClientConfig()
+	if err != nil {
+		return nil, fmt.Errorf("subscription TLS trust: %w", err)
+	}
+	if len(h.trusted.clients) >= maxTrustedClients {
+		for _, c := range h.trusted.clients {
+			c.CloseIdleConnections()
+		}
+		clear(h.trusted.clients)
+	}
+	transport := base.Transport.(*http.Transport).Clone()
+	transport.TLSClientConfig = cfg
+	c := webhookClient(transport)
+	h.trusted.clients[key] = c
+	return c, nil
+}
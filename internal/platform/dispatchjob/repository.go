@@ -11,6 +11,7 @@ import (
 	"github.com/jackc/pgx/v5/pgxpool"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/payloadcrypt"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
@@ -26,11 +27,12 @@ import (
 // the write table because they need the un-projected payload/metadata.
 //
 // FindWithFilters + DistinctValues + FindByEventID + FindRecentRaw +
-// InsertBatch stay hand-rolled (dynamic SQL / pgx.Batch); everything else
+// FindTerminalPage + InsertBatch stay hand-rolled (dynamic SQL / pgx.Batch); everything else
 // goes through *dbq.Queries.
 type Repository struct {
-	pool *pgxpool.Pool // retained for FindWithFilters + DistinctValues + InsertBatch
-	q    *dbq.Queries
+	pool   *pgxpool.Pool // retained for FindWithFilters + DistinctValues + InsertBatch
+	q      *dbq.Queries
+	cipher *payloadcrypt.Cipher
 }
 
 // NewRepository wires a repo.
@@ -38,6 +40,12 @@ func NewRepository(pool *pgxpool.Pool) *Repository {
 	return &Repository{pool: pool, q: dbq.New(pool)}
 }
 
+// SetPayloadCipher enables encrypted-at-rest payloads: inserts seal
+// `payload` for opted-in clients and the payload-bearing reads (FindByID,
+// FindRecentRaw) open it. Jobs fanned out from a sealed event already carry
+// the event's envelope and are opened the same way.
+func (r *Repository) SetPayloadCipher(c *payloadcrypt.Cipher) { r.cipher = c }
+
 // FilterParams is the query DTO for /api/dispatch-jobs.
 //
 // The plural slice fields back the SPA's CSV multi-filters
@@ -81,7 +89,21 @@ func (r *Repository) FindByID(ctx context.Context, id string) (*DispatchJob, err
 	if row == nil || err != nil {
 		return nil, err
 	}
-	return findByIDRowToJob(*row), nil
+	j := findByIDRowToJob(*row)
+	if err := r.openPayload(ctx, j); err != nil {
+		return nil, err
+	}
+	return j, nil
+}
+
+// openPayload decrypts a sealed payload in place.
+func (r *Repository) openPayload(ctx context.Context, j *DispatchJob) error {
+	p, err := r.cipher.OpenString(ctx, j.ClientID, j.Payload)
+	if err != nil {
+		return fmt.Errorf("dispatch job %s: %w", j.ID, err)
+	}
+	j.Payload = p
+	return nil
 }
 
 // FindByEventID lists jobs spawned by a single event. Used for the
@@ -113,14 +135,13 @@ const readSelect = `SELECT id, external_id, source, kind, code, subject,
 	client_id, subscription_id, mode, dispatch_pool_id, message_group,
 	sequence, timeout_seconds, status, max_retries, retry_strategy,
 	scheduled_for, expires_at, attempt_count, last_attempt_at, completed_at,
-	duration_millis, last_error, idempotency_key, created_at, updated_at
+	duration_millis, last_error, idempotency_key, subscription_name,
+	client_name, event_type_name, target_host, created_at, updated_at
 	FROM msg_dispatch_jobs_read`
 
-// FindWithFilters returns dispatch jobs matching non-nil filters, ordered
-// most-recent first. Powers the frontend's job list view (GET
-// /api/dispatch-jobs). Reads the msg_dispatch_jobs_read projection — the write
-// table carries no query indexes (migration 015). Hand-rolled dynamic query.
-func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]DispatchJob, error) {
+// filter builds the WHERE clause shared by FindWithFilters and
+// FindWithCursor.
+func (p FilterParams) filter() *repocommon.Filter {
 	var f repocommon.Filter
 	f.EqPtr("status", p.Status)
 	f.Any("status", p.Statuses)
@@ -148,7 +169,17 @@ func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]Dis
 	if p.Until != nil {
 		f.Clause("created_at <= $%d", *p.Until)
 	}
-	q := readSelect + f.Where() + " ORDER BY created_at DESC"
+	return &f
+}
+
+// FindWithFilters returns dispatch jobs matching non-nil filters, ordered
+// most-recent first (id breaks created_at ties so offset pages are
+// stable). Powers the frontend's job list view (GET /api/dispatch-jobs)
+// in offset mode. Reads the msg_dispatch_jobs_read projection — the write
+// table carries no query indexes (migration 015). Hand-rolled dynamic query.
+func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]DispatchJob, error) {
+	f := p.filter()
+	q := readSelect + f.Where() + " ORDER BY created_at DESC, id DESC"
 	limit := p.Limit
 	if limit <= 0 || limit > 1000 {
 		limit = 100
@@ -157,7 +188,28 @@ func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]Dis
 	if p.Offset > 0 {
 		q += fmt.Sprintf(" OFFSET $%d", f.Arg(p.Offset))
 	}
-	rows, err := r.pool.Query(ctx, q, f.Args()...)
+	return r.queryRead(ctx, q, f.Args())
+}
+
+// FindWithCursor is the keyset twin of FindWithFilters: jobs ordered by
+// id DESC — TSIDs sort by creation time — starting strictly after
+// afterID ("" for the first page). Offset is ignored. Limit should
+// already include the +1 over-fetch apicommon.CursorPage trims.
+func (r *Repository) FindWithCursor(ctx context.Context, p FilterParams, afterID string) ([]DispatchJob, error) {
+	f := p.filter()
+	if afterID != "" {
+		f.Clause("id < $%d", afterID)
+	}
+	limit := p.Limit
+	if limit <= 0 || limit > 1001 {
+		limit = 101
+	}
+	q := readSelect + f.Where() + fmt.Sprintf(" ORDER BY id DESC LIMIT $%d", f.Arg(limit))
+	return r.queryRead(ctx, q, f.Args())
+}
+
+func (r *Repository) queryRead(ctx context.Context, q string, args []any) ([]DispatchJob, error) {
+	rows, err := r.pool.Query(ctx, q, args...)
 	if err != nil {
 		return nil, err
 	}
@@ -201,6 +253,54 @@ func (r *Repository) FindRecentRaw(ctx context.Context, limit int) ([]DispatchJo
 		return nil, err
 	}
 	out := make([]DispatchJob, 0, len(collected))
+	for _, row := range collected {
+		j := findByIDRowToJob(row)
+		if err := r.openPayload(ctx, j); err != nil {
+			return nil, err
+		}
+		out = append(out, *j)
+	}
+	return out, nil
+}
+
+// TerminalParams selects a page of jobs in a terminal status, positioned
+// after (AfterTime, AfterID) in (updated_at, id) order and updated at
+// least Settle ago.
+type TerminalParams struct {
+	AfterTime time.Time
+	AfterID   string
+	Settle    time.Duration
+	Limit     int
+}
+
+// FindTerminalPage returns the next page of jobs that reached COMPLETED,
+// FAILED, CANCELLED or EXPIRED, from the write-side msg_dispatch_jobs
+// without their payload. Powers the event archive's DELIVERIES stream;
+// a job requeued after it is read comes round again when it next ends.
+func (r *Repository) FindTerminalPage(ctx context.Context, p TerminalParams) ([]DispatchJob, error) {
+	rows, err := r.pool.Query(ctx,
+		`SELECT id, external_id, source, kind, code, subject, event_id,
+		        correlation_id, metadata, target_url, protocol, NULL::text AS payload,
+		        payload_content_type, data_only, service_account_id, client_id,
+		        subscription_id, mode, dispatch_pool_id, message_group, sequence,
+		        timeout_seconds, schema_id, status, max_retries, retry_strategy,
+		        scheduled_for, expires_at, attempt_count, last_attempt_at,
+		        completed_at, duration_millis, last_error, idempotency_key,
+		        created_at, updated_at, ack_deadline
+		   FROM msg_dispatch_jobs
+		  WHERE status IN ('COMPLETED', 'FAILED', 'CANCELLED', 'EXPIRED')
+		    AND (updated_at, id) > ($1, $2)
+		    AND updated_at <= NOW() - make_interval(secs => $3)
+		  ORDER BY updated_at, id
+		  LIMIT $4`, p.AfterTime, p.AfterID, p.Settle.Seconds(), p.Limit)
+	if err != nil {
+		return nil, err
+	}
+	collected, err := pgx.CollectRows(rows, pgx.RowToStructByName[dbq.DispatchJobFindByIDRow])
+	if err != nil {
+		return nil, err
+	}
+	out := make([]DispatchJob, 0, len(collected))
 	for _, row := range collected {
 		out = append(out, *findByIDRowToJob(row))
 	}
@@ -255,6 +355,10 @@ func (r *Repository) Insert(ctx context.Context, j *DispatchJob) error {
 	}
 	retry := string(j.RetryStrategy)
 	pct := j.PayloadContentType
+	payload, err := r.cipher.SealString(ctx, j.ClientID, j.Payload)
+	if err != nil {
+		return fmt.Errorf("seal payload: %w", err)
+	}
 	return r.q.DispatchJobInsert(ctx, dbq.DispatchJobInsertParams{
 		ID:                 j.ID,
 		ExternalID:         j.ExternalID,
@@ -267,7 +371,7 @@ func (r *Repository) Insert(ctx context.Context, j *DispatchJob) error {
 		Metadata:           metaJSON,
 		TargetUrl:          j.TargetURL,
 		Protocol:           string(j.Protocol),
-		Payload:            j.Payload,
+		Payload:            payload,
 		PayloadContentType: &pct,
 		DataOnly:           j.DataOnly,
 		ServiceAccountID:   j.ServiceAccountID,
@@ -310,6 +414,10 @@ func (r *Repository) InsertBatch(ctx context.Context, jobs []DispatchJob) error
 			j.CreatedAt = now
 		}
 		metaJSON, _ := json.Marshal(metadataOrEmpty(j.Metadata))
+		payload, err := r.cipher.SealString(ctx, j.ClientID, j.Payload)
+		if err != nil {
+			return fmt.Errorf("seal payload: %w", err)
+		}
 		batch.Queue(
 			`INSERT INTO msg_dispatch_jobs
 			     (id, external_id, source, kind, code, subject, event_id, correlation_id,
@@ -321,7 +429,7 @@ func (r *Repository) InsertBatch(ctx context.Context, jobs []DispatchJob) error
 			 VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9::jsonb,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23,$24,$25,$26,$27,$28,$29,$30,$31,$32,$33,$34,$35,$36)
 			 ON CONFLICT (id, created_at) DO NOTHING`,
 			j.ID, j.ExternalID, j.Source, string(j.Kind), j.Code, j.Subject, j.EventID,
-			j.CorrelationID, metaJSON, j.TargetURL, string(j.Protocol), j.Payload,
+			j.CorrelationID, metaJSON, j.TargetURL, string(j.Protocol), payload,
 			j.PayloadContentType, j.DataOnly, j.ServiceAccountID, j.ClientID,
 			j.SubscriptionID, string(j.Mode), j.DispatchPoolID, j.MessageGroup,
 			j.Sequence, j.TimeoutSeconds, j.SchemaID, string(j.Status), j.MaxRetries,
@@ -384,10 +492,231 @@ func (r *Repository) Reschedule(ctx context.Context, id string, scheduledFor tim
 	_, err := r.pool.Exec(ctx,
 		`UPDATE msg_dispatch_jobs
 		    SET status = 'PENDING', scheduled_for = $2, updated_at = NOW()
-		  WHERE id = $1`, id, scheduledFor.UTC())
+		  WHERE id = $1 AND status <> 'CANCELLED'`, id, scheduledFor.UTC())
+	return err
+}
+
+// MarkExpired records a job that reached its expires_at undelivered as
+// EXPIRED, stamping last_error and completed_at. Only a PENDING, QUEUED
+// or PROCESSING job moves; reports whether this call expired it.
+func (r *Repository) MarkExpired(ctx context.Context, id string) (bool, error) {
+	tag, err := r.pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET status = 'EXPIRED', last_error = 'expired before delivery',
+		        completed_at = NOW(), updated_at = NOW()
+		  WHERE id = $1 AND status IN ('PENDING', 'QUEUED', 'PROCESSING')`, id)
+	if err != nil {
+		return false, err
+	}
+	return tag.RowsAffected() > 0, nil
+}
+
+// AwaitAck keeps a PROCESSING job in flight after its receiver accepted
+// the delivery with 202 and an ack token: the job waits for an ack or nack
+// presenting token until deadline.
+func (r *Repository) AwaitAck(ctx context.Context, id, token string, deadline time.Time) error {
+	_, err := r.pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET ack_token = $2, ack_deadline = $3, updated_at = NOW()
+		  WHERE id = $1 AND status = 'PROCESSING'`, id, token, deadline.UTC())
+	return err
+}
+
+// CompleteAck settles a job awaiting the ack presented with token as
+// COMPLETED or FAILED (status), stamping completed_at and the end-to-end
+// duration since the attempt started. Reports false — changing nothing —
+// when the job isn't awaiting that token: never accepted asynchronously,
+// already settled, cancelled, or re-attempted since.
+func (r *Repository) CompleteAck(ctx context.Context, id, token string, status common.DispatchStatus, lastError *string) (bool, error) {
+	tag, err := r.pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET status = $3,
+		        last_error = COALESCE($4, last_error),
+		        completed_at = NOW(),
+		        duration_millis = (EXTRACT(EPOCH FROM NOW() - last_attempt_at) * 1000)::BIGINT,
+		        ack_token = NULL,
+		        ack_deadline = NULL,
+		        updated_at = NOW()
+		  WHERE id = $1 AND status = 'PROCESSING' AND ack_token = $2`,
+		id, token, string(status), lastError)
+	if err != nil {
+		return false, err
+	}
+	return tag.RowsAffected() > 0, nil
+}
+
+// RetryAck settles a job awaiting the ack presented with token as a
+// failed attempt: like ScheduleRetry it bumps attempt_count and puts the
+// job back to PENDING for scheduledFor. Reports false as CompleteAck does.
+func (r *Repository) RetryAck(ctx context.Context, id, token string, scheduledFor time.Time, lastError *string) (bool, error) {
+	tag, err := r.pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET status = 'PENDING',
+		        attempt_count = attempt_count + 1,
+		        scheduled_for = $3,
+		        last_error = $4,
+		        ack_token = NULL,
+		        ack_deadline = NULL,
+		        updated_at = NOW()
+		  WHERE id = $1 AND status = 'PROCESSING' AND ack_token = $2`,
+		id, token, scheduledFor.UTC(), lastError)
+	if err != nil {
+		return false, err
+	}
+	return tag.RowsAffected() > 0, nil
+}
+
+// PendingAck is a job whose ack deadline has passed, with the token it
+// was accepted under.
+type PendingAck struct {
+	ID    string
+	Token string
+}
+
+// ExpiredAcks returns up to limit jobs still awaiting an ack past their
+// deadline, oldest deadline first.
+func (r *Repository) ExpiredAcks(ctx context.Context, limit int) ([]PendingAck, error) {
+	rows, err := r.pool.Query(ctx,
+		`SELECT id, ack_token FROM msg_dispatch_jobs
+		  WHERE ack_deadline < NOW() AND status = 'PROCESSING' AND ack_token IS NOT NULL
+		  ORDER BY ack_deadline
+		  LIMIT $1`, limit)
+	if err != nil {
+		return nil, err
+	}
+	return pgx.CollectRows(rows, func(row pgx.CollectableRow) (PendingAck, error) {
+		var p PendingAck
+		err := row.Scan(&p.ID, &p.Token)
+		return p, err
+	})
+}
+
+// LeasePull leases up to limit of a pull subscription's due PENDING jobs
+// to its consumer: each goes PROCESSING under token until deadline — the
+// state an asynchronously acknowledged delivery waits in, so CompleteAck,
+// RetryAck and ExpiredAcks settle it. Returns the leased ids, unordered.
+func (r *Repository) LeasePull(ctx context.Context, subscriptionID, token string, deadline time.Time, limit int) ([]string, error) {
+	rows, err := r.pool.Query(ctx,
+		`WITH due AS (
+		     SELECT id, created_at FROM msg_dispatch_jobs
+		      WHERE subscription_id = $1 AND protocol = 'PULL' AND status = 'PENDING'
+		        AND (scheduled_for IS NULL OR scheduled_for <= NOW())
+		        AND (expires_at IS NULL OR expires_at > NOW())
+		      ORDER BY sequence, created_at
+		      LIMIT $4
+		      FOR UPDATE SKIP LOCKED)
+		 UPDATE msg_dispatch_jobs j
+		    SET status = 'PROCESSING',
+		        ack_token = $2,
+		        ack_deadline = $3,
+		        last_attempt_at = NOW(),
+		        updated_at = NOW()
+		   FROM due
+		  WHERE j.id = due.id AND j.created_at = due.created_at
+		 RETURNING j.id`,
+		subscriptionID, token, deadline.UTC(), limit)
+	if err != nil {
+		return nil, err
+	}
+	return pgx.CollectRows(rows, pgx.RowTo[string])
+}
+
+// RenewPullLease extends to deadline the leases a pull subscription's jobs
+// still hold under token, for a consumer resuming a stream. Returns their
+// ids, unordered.
+func (r *Repository) RenewPullLease(ctx context.Context, subscriptionID, token string, deadline time.Time) ([]string, error) {
+	rows, err := r.pool.Query(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET ack_deadline = $3, updated_at = NOW()
+		  WHERE subscription_id = $1 AND ack_token = $2
+		    AND protocol = 'PULL' AND status = 'PROCESSING'
+		 RETURNING id`,
+		subscriptionID, token, deadline.UTC())
+	if err != nil {
+		return nil, err
+	}
+	return pgx.CollectRows(rows, pgx.RowTo[string])
+}
+
+// CountPullLeased counts a pull subscription's jobs leased under token
+// and not yet settled.
+func (r *Repository) CountPullLeased(ctx context.Context, subscriptionID, token string) (int, error) {
+	var n int
+	err := r.pool.QueryRow(ctx,
+		`SELECT COUNT(*) FROM msg_dispatch_jobs
+		  WHERE subscription_id = $1 AND ack_token = $2
+		    AND protocol = 'PULL' AND status = 'PROCESSING'`,
+		subscriptionID, token).Scan(&n)
+	return n, err
+}
+
+// ClaimBatch claims up to limit of a subscription's other due webhook jobs
+// for the same target, to be delivered in one batch with leaderID: PENDING
+// ones whose scheduled_for has come and QUEUED ones, IMMEDIATE mode only,
+// in sequence then creation order. Each goes PROCESSING under token until
+// deadline, so its own queued copy is dropped as a duplicate and, should
+// the delivery never finish, ExpiredAcks collects it. Returns the claimed
+// ids, unordered.
+func (r *Repository) ClaimBatch(ctx context.Context, leaderID, subscriptionID, targetURL, token string, deadline time.Time, limit int) ([]string, error) {
+	rows, err := r.pool.Query(ctx,
+		`WITH due AS (
+		     SELECT id, created_at FROM msg_dispatch_jobs
+		      WHERE subscription_id = $1 AND target_url = $2 AND id <> $3
+		        AND protocol = 'HTTP_WEBHOOK' AND mode = 'IMMEDIATE'
+		        AND (status = 'QUEUED'
+		             OR (status = 'PENDING' AND (scheduled_for IS NULL OR scheduled_for <= NOW())))
+		        AND (expires_at IS NULL OR expires_at > NOW())
+		      ORDER BY sequence, created_at
+		      LIMIT $6
+		      FOR UPDATE SKIP LOCKED)
+		 UPDATE msg_dispatch_jobs j
+		    SET status = 'PROCESSING',
+		        ack_token = $4,
+		        ack_deadline = $5,
+		        last_attempt_at = NOW(),
+		        updated_at = NOW()
+		   FROM due
+		  WHERE j.id = due.id AND j.created_at = due.created_at
+		 RETURNING j.id`,
+		subscriptionID, targetURL, leaderID, token, deadline.UTC(), limit)
+	if err != nil {
+		return nil, err
+	}
+	return pgx.CollectRows(rows, pgx.RowTo[string])
+}
+
+// ReleaseBatch puts jobs claimed under token back to PENDING for
+// scheduledFor, unattempted. For jobs a batch left out.
+func (r *Repository) ReleaseBatch(ctx context.Context, ids []string, token string, scheduledFor time.Time) error {
+	_, err := r.pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET status = 'PENDING',
+		        scheduled_for = $3,
+		        ack_token = NULL,
+		        ack_deadline = NULL,
+		        updated_at = NOW()
+		  WHERE id = ANY($1) AND ack_token = $2 AND status = 'PROCESSING'`,
+		ids, token, scheduledFor.UTC())
 	return err
 }
 
+// EndBatchClaim drops the token from jobs claimed under it once their
+// batch was delivered, leaving them PROCESSING for the outcome to be
+// recorded. Returns the ids still claimed; one cancelled meanwhile, or
+// already collected by the expiry sweep, is not.
+func (r *Repository) EndBatchClaim(ctx context.Context, ids []string, token string) ([]string, error) {
+	rows, err := r.pool.Query(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET ack_token = NULL, ack_deadline = NULL, updated_at = NOW()
+		  WHERE id = ANY($1) AND ack_token = $2 AND status = 'PROCESSING'
+		 RETURNING id`,
+		ids, token)
+	if err != nil {
+		return nil, err
+	}
+	return pgx.CollectRows(rows, pgx.RowTo[string])
+}
+
 // Requeue resets the given jobs to PENDING for a fresh delivery cycle:
 // clears scheduled_for (immediate eligibility), zeroes attempt_count so a
 // job that had exhausted its retries gets a full budget again, and clears
@@ -408,6 +737,8 @@ func (r *Repository) Requeue(ctx context.Context, ids []string, accessibleClient
 		        completed_at = NULL,
 		        duration_millis = NULL,
 		        last_error = NULL,
+		        ack_token = NULL,
+		        ack_deadline = NULL,
 		        updated_at = NOW()
 		  WHERE id = ANY($1)`
 	var tag pgconn.CommandTag
@@ -423,6 +754,39 @@ func (r *Repository) Requeue(ctx context.Context, ids []string, accessibleClient
 	return tag.RowsAffected(), nil
 }
 
+// Cancel flips the given non-terminal jobs to CANCELLED and stamps
+// completed_at. The poller only claims PENDING rows and every later status
+// transition (MarkInProgress/Completed/Failed, ScheduleRetry, Reschedule)
+// skips CANCELLED ones, so a cancelled job is never dispatched again and a
+// delivery already in flight cannot resurrect it. Operator action behind
+// POST /api/dispatch-jobs/cancel.
+//
+// accessibleClientIDs scopes the update exactly as for Requeue. Returns the
+// ids actually cancelled — already-terminal and out-of-scope ids are left
+// alone and omitted.
+func (r *Repository) Cancel(ctx context.Context, ids []string, accessibleClientIDs *[]string) ([]string, error) {
+	if len(ids) == 0 {
+		return nil, nil
+	}
+	const base = `UPDATE msg_dispatch_jobs
+		    SET status = 'CANCELLED',
+		        completed_at = NOW(),
+		        updated_at = NOW()
+		  WHERE id = ANY($1)
+		    AND status IN ('PENDING', 'QUEUED', 'PROCESSING')`
+	var rows pgx.Rows
+	var err error
+	if accessibleClientIDs == nil {
+		rows, err = r.pool.Query(ctx, base+` RETURNING id`, ids)
+	} else {
+		rows, err = r.pool.Query(ctx, base+` AND client_id = ANY($2) RETURNING id`, ids, *accessibleClientIDs)
+	}
+	if err != nil {
+		return nil, err
+	}
+	return pgx.CollectRows(rows, pgx.RowTo[string])
+}
+
 // RecordAttempt inserts a row into msg_dispatch_job_attempts. Mirrors
 // Rust's insert_attempt — generates an untyped TSID for the row id and
 // derives the `status` column from the entity's Success bool
@@ -515,7 +879,7 @@ func findByIDRowToJob(r dbq.DispatchJobFindByIDRow) *DispatchJob {
 		LastAttemptAt: r.LastAttemptAt, CompletedAt: r.CompletedAt,
 		DurationMillis: r.DurationMillis, LastError: r.LastError,
 		IdempotencyKey: r.IdempotencyKey, CreatedAt: r.CreatedAt,
-		UpdatedAt: r.UpdatedAt,
+		UpdatedAt: r.UpdatedAt, AckDeadline: r.AckDeadline,
 	})
 }
 
@@ -555,12 +919,16 @@ type readRow struct {
 	DurationMillis   *int64     `db:"duration_millis"`
 	LastError        *string    `db:"last_error"`
 	IdempotencyKey   *string    `db:"idempotency_key"`
+	SubscriptionName *string    `db:"subscription_name"`
+	ClientName       *string    `db:"client_name"`
+	EventTypeName    *string    `db:"event_type_name"`
+	TargetHost       *string    `db:"target_host"`
 	CreatedAt        time.Time  `db:"created_at"`
 	UpdatedAt        time.Time  `db:"updated_at"`
 }
 
 func readRowToJob(r readRow) *DispatchJob {
-	return rowToJob(rawRow{
+	j := rowToJob(rawRow{
 		ID: r.ID, ExternalID: r.ExternalID, Source: r.Source, Kind: r.Kind,
 		Code: r.Code, Subject: r.Subject, EventID: r.EventID,
 		CorrelationID: r.CorrelationID,
@@ -578,6 +946,13 @@ func readRowToJob(r readRow) *DispatchJob {
 		UpdatedAt: r.UpdatedAt,
 		// Payload / Metadata / SchemaID / PayloadContentType / DataOnly absent.
 	})
+	j.Names = &ReadNames{
+		SubscriptionName: r.SubscriptionName,
+		ClientName:       r.ClientName,
+		EventTypeName:    r.EventTypeName,
+		TargetHost:       r.TargetHost,
+	}
+	return j
 }
 
 // rawRow is the union of every sqlc-generated row's field set — lets the
@@ -619,6 +994,7 @@ type rawRow struct {
 	IdempotencyKey     *string
 	CreatedAt          time.Time
 	UpdatedAt          time.Time
+	AckDeadline        *time.Time
 }
 
 func rowToJob(r rawRow) *DispatchJob {
@@ -656,6 +1032,7 @@ func rowToJob(r rawRow) *DispatchJob {
 		LastAttemptAt:    r.LastAttemptAt,
 		CompletedAt:      r.CompletedAt,
 		DurationMillis:   r.DurationMillis,
+		AckDeadline:      r.AckDeadline,
 	}
 	if r.PayloadContentType != nil {
 		j.PayloadContentType = *r.PayloadContentType
@@ -670,7 +1047,9 @@ func rowToJob(r rawRow) *DispatchJob {
 	if len(r.Metadata) > 0 {
 		_ = json.Unmarshal(r.Metadata, &j.Metadata)
 	}
-	_ = r.Protocol // single protocol today — see Rust DispatchProtocol::from_str
+	if Protocol(r.Protocol) == ProtocolPull {
+		j.Protocol = ProtocolPull
+	}
 	return j
 }
 
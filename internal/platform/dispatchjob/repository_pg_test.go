@@ -5,6 +5,7 @@ package dispatchjob_test
 import (
 	"context"
 	"testing"
+	"time"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -76,3 +77,79 @@ func TestFindWithFilters_TenantScoping(t *testing.T) {
 	require.NoError(t, err)
 	assert.Empty(t, ids(rows), "cross-tenant filter must not leak another tenant's jobs")
 }
+
+// TestFindWithCursor_PagesStablyByID mirrors the event repo's keyset
+// test: pages cover every row once, newest TSID first, and a row
+// inserted between pages doesn't shift the next one.
+func TestFindWithCursor_PagesStablyByID(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+	repo := dispatchjob.NewRepository(pool)
+
+	const code = "cursortest:jobs:list"
+	seed := func(id string) {
+		t.Helper()
+		_, err := pool.Exec(ctx,
+			`INSERT INTO msg_dispatch_jobs_read
+			     (id, code, target_url, kind, protocol, mode, status, max_retries, updated_at)
+			 VALUES ($1, $2, 'http://example.invalid/hook',
+			         'EVENT', 'HTTP_WEBHOOK', 'IMMEDIATE', 'PENDING', 3, NOW())`,
+			id, code)
+		require.NoError(t, err)
+	}
+	for _, id := range []string{"djcursortst01", "djcursortst02", "djcursortst03"} {
+		seed(id)
+	}
+	f := dispatchjob.FilterParams{Codes: []string{code}, Limit: 2}
+
+	first, err := repo.FindWithCursor(ctx, f, "")
+	require.NoError(t, err)
+	require.Len(t, first, 2)
+	assert.Equal(t, []string{"djcursortst03", "djcursortst02"}, []string{first[0].ID, first[1].ID})
+
+	seed("djcursortst04")
+
+	second, err := repo.FindWithCursor(ctx, f, first[1].ID)
+	require.NoError(t, err)
+	require.Len(t, second, 1)
+	assert.Equal(t, "djcursortst01", second[0].ID)
+}
+
+// TestCancel_ScopedAndSticky pins the cancellation contract: only
+// non-terminal jobs in the caller's tenants are cancelled, and no later
+// status transition from an in-flight delivery or retry revives them.
+func TestCancel_ScopedAndSticky(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+	repo := dispatchjob.NewRepository(pool)
+
+	seed := func(id, status, clientID string) {
+		t.Helper()
+		_, err := pool.Exec(ctx,
+			`INSERT INTO msg_dispatch_jobs (id, code, target_url, status, client_id)
+			 VALUES ($1, 'canceltest:jobs:cancel', 'http://example.invalid/hook', $2, $3)`,
+			id, status, clientID)
+		require.NoError(t, err)
+	}
+	status := func(id string) string {
+		t.Helper()
+		var s string
+		require.NoError(t, pool.QueryRow(ctx, `SELECT status FROM msg_dispatch_jobs WHERE id = $1`, id).Scan(&s))
+		return s
+	}
+	seed("djcanceltst01", "QUEUED", "clt_canceljob001")
+	seed("djcanceltst02", "COMPLETED", "clt_canceljob001")
+	seed("djcanceltst03", "PENDING", "clt_canceljob002")
+
+	scope := []string{"clt_canceljob001"}
+	got, err := repo.Cancel(ctx, []string{"djcanceltst01", "djcanceltst02", "djcanceltst03"}, &scope)
+	require.NoError(t, err)
+	assert.Equal(t, []string{"djcanceltst01"}, got)
+	assert.Equal(t, "COMPLETED", status("djcanceltst02"))
+	assert.Equal(t, "PENDING", status("djcanceltst03"))
+
+	require.NoError(t, repo.MarkInProgress(ctx, "djcanceltst01"))
+	require.NoError(t, repo.MarkCompleted(ctx, "djcanceltst01", 10))
+	require.NoError(t, repo.Reschedule(ctx, "djcanceltst01", time.Now()))
+	assert.Equal(t, "CANCELLED", status("djcanceltst01"))
+}
@@ -12,7 +12,10 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/idempotency"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/readcache"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
@@ -20,8 +23,10 @@ import (
 
 // State bundles deps.
 type State struct {
-	Repo *dispatchpool.Repository
-	UoW  *usecasepgx.UnitOfWork
+	Repo  *dispatchpool.Repository
+	UoW   *usecasepgx.UnitOfWork
+	Cache *readcache.Cache[[]dispatchpool.DispatchPool] // optional; nil reads the repo every time
+	Idem  *idempotency.Store                            // optional; nil ignores Idempotency-Key
 }
 
 const tag = "dispatch-pools"
@@ -32,6 +37,7 @@ func Register(api huma.API, s *State) {
 	apiroute.Get(g, "listDispatchPools", "/api/dispatch-pools", "List dispatch pools", s.list)
 	apiroute.Post(g, "createDispatchPool", "/api/dispatch-pools", "Create a dispatch pool", http.StatusCreated, s.create)
 	apiroute.Get(g, "getDispatchPool", "/api/dispatch-pools/{id}", "Get a dispatch pool by id", s.getByID)
+	apiroute.Put(g, "upsertDispatchPool", "/api/dispatch-pools/by-code/{code}", "Create or update a dispatch pool by code", http.StatusOK, s.upsert)
 	apiroute.Put(g, "updateDispatchPool", "/api/dispatch-pools/{id}", "Update a dispatch pool", http.StatusNoContent, s.update)
 	apiroute.Post(g, "archiveDispatchPool", "/api/dispatch-pools/{id}/archive", "Archive a dispatch pool", http.StatusNoContent, s.archive)
 	apiroute.Post(g, "suspendDispatchPool", "/api/dispatch-pools/{id}/suspend", "Suspend dispatch into a pool", http.StatusNoContent, s.suspend)
@@ -51,7 +57,10 @@ func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[Dispatc
 	}
 	status := apicommon.OptStr(in.Status)
 	clientID := apicommon.OptStr(in.ClientID)
-	rows, err := s.Repo.FindWithFilters(ctx, status, clientID)
+	// rows may be shared through the cache; FilterClientScoped copies.
+	rows, err := s.Cache.Get(ctx, readcache.Key(status, clientID), func(ctx context.Context) ([]dispatchpool.DispatchPool, error) {
+		return s.Repo.FindWithFilters(ctx, status, clientID)
+	})
 	if err != nil {
 		return nil, usecase.Internal("REPO", "find_with_filters failed", err)
 	}
@@ -60,7 +69,7 @@ func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[Dispatc
 	return &apicommon.Out[DispatchPoolListResponse]{Body: DispatchPoolListResponse{Pools: out, Total: len(out)}}, nil
 }
 
-func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[DispatchPoolResponse], error) {
+func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.TaggedOut[DispatchPoolResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanReadDispatchPools(ac); err != nil {
 		return nil, err
@@ -75,27 +84,81 @@ func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.
 	if p.ClientID != nil && !ac.CanAccessClient(*p.ClientID) {
 		return nil, httperror.Forbidden("No access to this dispatch pool")
 	}
-	return &apicommon.Out[DispatchPoolResponse]{Body: fromEntity(p)}, nil
+	return &apicommon.TaggedOut[DispatchPoolResponse]{ETag: etag.Of(p.UpdatedAt), Body: fromEntity(p)}, nil
 }
 
-func (s *State) create(ctx context.Context, in *apicommon.In[CreateDispatchPoolRequest]) (*apicommon.Out[apicommon.CreatedResponse], error) {
+func (s *State) create(ctx context.Context, in *apicommon.IdempotentIn[CreateDispatchPoolRequest]) (*apicommon.Out[apicommon.CreatedResponse], error) {
 	// Coarse permission at the controller; the use case enforces per-client
 	// resource access (you may only bind a pool to a client you can access;
 	// platform-wide requires anchor).
 	if err := auth.CanWriteDispatchPools(auth.FromContext(ctx)); err != nil {
 		return nil, err
 	}
-	ec := auth.NewExecutionContext(ctx)
-	event, err := usecaseop.Run(ctx, s.UoW, operations.CreateDispatchPool(s.Repo), in.Body.toCommand(), ec)
+	req := idempotency.Request{Key: in.IdempotencyKey, Scope: "createDispatchPool", Body: in.Body}
+	created, err := idempotency.Do(ctx, s.Idem, req, func(ctx context.Context) (apicommon.CreatedResponse, error) {
+		ec := auth.NewExecutionContext(ctx)
+		event, err := usecaseop.Run(ctx, s.UoW, operations.CreateDispatchPool(s.Repo), in.Body.toCommand(), ec)
+		if err != nil {
+			return apicommon.CreatedResponse{}, err
+		}
+		return apicommon.CreatedResponse{ID: event.PoolID}, nil
+	})
 	if err != nil {
 		return nil, err
 	}
-	return &apicommon.Out[apicommon.CreatedResponse]{Body: apicommon.CreatedResponse{ID: event.PoolID}}, nil
+	return &apicommon.Out[apicommon.CreatedResponse]{Body: created}, nil
+}
+
+type upsertInput struct {
+	Code        string `path:"code" doc:"Pool code"`
+	ClientID    string `query:"clientId" doc:"Client the pool belongs to; absent means anchor-level"`
+	IfMatch     string `header:"If-Match" doc:"Update only if the pool exists with this ETag"`
+	IfNoneMatch string `header:"If-None-Match" doc:"Send * to create only, failing if the code is taken"`
+	Body        UpsertDispatchPoolRequest
+}
+
+// upsert creates or updates the pool with this code in the client's scope:
+// 201 if it created it, 200 if it updated it.
+func (s *State) upsert(ctx context.Context, in *upsertInput) (*apicommon.UpsertOut[DispatchPoolResponse], error) {
+	if err := auth.CanWriteDispatchPools(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	clientID := apicommon.OptStr(in.ClientID)
+	existing, err := s.Repo.FindByCode(ctx, in.Code, clientID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_code failed", err)
+	}
+	if err := etag.CheckUpsert(in.IfMatch, in.IfNoneMatch, existing != nil); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	status, id := http.StatusOK, ""
+	if existing == nil {
+		event, err := usecaseop.Run(ctx, s.UoW, operations.CreateDispatchPool(s.Repo), in.Body.toCreateCommand(in.Code, clientID), ec)
+		if err != nil {
+			return nil, err
+		}
+		status, id = http.StatusCreated, event.PoolID
+	} else {
+		id = existing.ID
+		if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateDispatchPool(s.Repo), in.Body.toUpdateCommand(id, in.IfMatch), ec); err != nil {
+			return nil, err
+		}
+	}
+	p, err := s.Repo.FindByID(ctx, id)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if p == nil {
+		return nil, httperror.NotFound("DispatchPool", id)
+	}
+	return &apicommon.UpsertOut[DispatchPoolResponse]{Status: status, ETag: etag.Of(p.UpdatedAt), Body: fromEntity(p)}, nil
 }
 
 type updateInput struct {
-	ID   string `path:"id"`
-	Body UpdateDispatchPoolRequest
+	ID      string `path:"id"`
+	IfMatch string `header:"If-Match" doc:"Proceed only if the pool's current ETag matches"`
+	Body    UpdateDispatchPoolRequest
 }
 
 func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Empty, error) {
@@ -103,7 +166,7 @@ func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Empty,
 		return nil, err
 	}
 	ec := auth.NewExecutionContext(ctx)
-	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateDispatchPool(s.Repo), in.Body.toCommand(in.ID), ec); err != nil {
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateDispatchPool(s.Repo), in.Body.toCommand(in.ID, in.IfMatch), ec); err != nil {
 		return nil, err
 	}
 	return &apicommon.Empty{}, nil
@@ -142,12 +205,12 @@ func (s *State) activate(ctx context.Context, in *apicommon.IDInput) (*apicommon
 	return &apicommon.Empty{}, nil
 }
 
-func (s *State) delete(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+func (s *State) delete(ctx context.Context, in *apicommon.IDMatchInput) (*apicommon.Empty, error) {
 	if err := auth.CanDeleteDispatchPools(auth.FromContext(ctx)); err != nil {
 		return nil, err
 	}
 	ec := auth.NewExecutionContext(ctx)
-	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeleteDispatchPool(s.Repo), operations.DeleteCommand{ID: in.ID}, ec); err != nil {
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeleteDispatchPool(s.Repo), operations.DeleteCommand{ID: in.ID, IfMatch: in.IfMatch}, ec); err != nil {
 		return nil, err
 	}
 	return &apicommon.Empty{}, nil
@@ -16,6 +16,7 @@ type CreateDispatchPoolRequest struct {
 	RateLimit   *int32  `json:"rateLimit,omitempty" doc:"Messages per minute (nil = no rate limit)"`
 	Concurrency *int32  `json:"concurrency,omitempty" doc:"Max concurrent dispatches (default 10)"`
 	ClientID    *string `json:"clientId,omitempty"`
+	Region      string  `json:"region,omitempty" doc:"Data-residency region deliveries egress from (e.g. eu); blank = unrestricted"`
 }
 
 func (r CreateDispatchPoolRequest) toCommand() operations.CreateCommand {
@@ -26,6 +27,7 @@ func (r CreateDispatchPoolRequest) toCommand() operations.CreateCommand {
 		RateLimit:   r.RateLimit,
 		Concurrency: r.Concurrency,
 		ClientID:    r.ClientID,
+		Region:      r.Region,
 	}
 }
 
@@ -35,15 +37,50 @@ type UpdateDispatchPoolRequest struct {
 	Description *string `json:"description,omitempty"`
 	RateLimit   *int32  `json:"rateLimit,omitempty"`
 	Concurrency *int32  `json:"concurrency,omitempty"`
+	Region      *string `json:"region,omitempty" doc:"Data-residency region; empty string clears it"`
 }
 
-func (r UpdateDispatchPoolRequest) toCommand(id string) operations.UpdateCommand {
+func (r UpdateDispatchPoolRequest) toCommand(id, ifMatch string) operations.UpdateCommand {
 	return operations.UpdateCommand{
 		ID:          id,
 		Name:        r.Name,
 		Description: r.Description,
 		RateLimit:   r.RateLimit,
 		Concurrency: r.Concurrency,
+		Region:      r.Region,
+		IfMatch:     ifMatch,
+	}
+}
+
+// UpsertDispatchPoolRequest is the wire body for
+// PUT /api/dispatch-pools/by-code/{code}. On update, absent optional
+// fields keep their current values.
+type UpsertDispatchPoolRequest struct {
+	Name        string  `json:"name"`
+	Description *string `json:"description,omitempty"`
+	RateLimit   *int32  `json:"rateLimit,omitempty" doc:"Messages per minute (nil = no rate limit)"`
+	Concurrency *int32  `json:"concurrency,omitempty" doc:"Max concurrent dispatches (default 10)"`
+}
+
+func (r UpsertDispatchPoolRequest) toCreateCommand(code string, clientID *string) operations.CreateCommand {
+	return operations.CreateCommand{
+		Code:        code,
+		Name:        r.Name,
+		Description: r.Description,
+		RateLimit:   r.RateLimit,
+		Concurrency: r.Concurrency,
+		ClientID:    clientID,
+	}
+}
+
+func (r UpsertDispatchPoolRequest) toUpdateCommand(id, ifMatch string) operations.UpdateCommand {
+	return operations.UpdateCommand{
+		ID:          id,
+		Name:        &r.Name,
+		Description: r.Description,
+		RateLimit:   r.RateLimit,
+		Concurrency: r.Concurrency,
+		IfMatch:     ifMatch,
 	}
 }
 
@@ -57,6 +94,7 @@ type DispatchPoolResponse struct {
 	Concurrency      int32           `json:"concurrency"`
 	ClientID         *string         `json:"clientId,omitempty"`
 	ClientIdentifier *string         `json:"clientIdentifier,omitempty"`
+	Region           *string         `json:"region,omitempty"`
 	Status           string          `json:"status"`
 	CreatedAt        httpcompat.Time `json:"createdAt"`
 	UpdatedAt        httpcompat.Time `json:"updatedAt"`
@@ -72,6 +110,7 @@ func fromEntity(p *dispatchpool.DispatchPool) DispatchPoolResponse {
 		Concurrency:      p.Concurrency,
 		ClientID:         p.ClientID,
 		ClientIdentifier: p.ClientIdentifier,
+		Region:           p.Region,
 		Status:           string(p.Status),
 		CreatedAt:        jsontime.New(p.CreatedAt),
 		UpdatedAt:        jsontime.New(p.UpdatedAt),
@@ -40,9 +40,12 @@ type DispatchPool struct {
 	Concurrency      int32     `json:"concurrency"`
 	ClientID         *string   `json:"clientId,omitempty"`
 	ClientIdentifier *string   `json:"clientIdentifier,omitempty"`
-	Status           Status    `json:"status"`
-	CreatedAt        time.Time `json:"createdAt"`
-	UpdatedAt        time.Time `json:"updatedAt"`
+	// Region is the data-residency region the pool's deliveries egress
+	// from. nil → unrestricted; only unrestricted clients may use it.
+	Region    *string   `json:"region,omitempty"`
+	Status    Status    `json:"status"`
+	CreatedAt time.Time `json:"createdAt"`
+	UpdatedAt time.Time `json:"updatedAt"`
 }
 
 // IDStr satisfies usecase.HasID.
@@ -48,6 +48,7 @@ func ArchiveDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Arch
 				Metadata: usecase.NewEventMetadata(ec, DispatchPoolArchivedType, Source, subjectFor(p.ID)),
 				PoolID:   p.ID,
 				Code:     p.Code,
+				ClientID: p.ClientID,
 			}
 			return usecaseop.Save(p, repo, event), nil
 		},
@@ -19,6 +19,8 @@ type CreateCommand struct {
 	RateLimit   *int32  `json:"rateLimit,omitempty"`
 	Concurrency *int32  `json:"concurrency,omitempty"`
 	ClientID    *string `json:"clientId,omitempty"`
+	// Region is the data-residency region ("eu"); blank = unrestricted.
+	Region string `json:"region,omitempty"`
 }
 
 // CreateDispatchPool validates cmd, enforces per-resource client scope,
@@ -47,7 +49,8 @@ func CreateDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Creat
 			if cmd.RateLimit != nil && *cmd.RateLimit < 0 {
 				return usecase.Validation("INVALID_RATE_LIMIT", "rateLimit cannot be negative")
 			}
-			return nil
+			_, err := validate.Region(cmd.Region)
+			return err
 		},
 		// Resource-level authorization (the coarse "may write dispatch pools"
 		// permission is enforced at the controller). A pool bound to a client
@@ -75,12 +78,17 @@ func CreateDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Creat
 				p.Concurrency = *cmd.Concurrency
 			}
 			p.ClientID = cmd.ClientID
+			if region, _ := validate.Region(cmd.Region); region != "" {
+				p.Region = &region
+			}
 
 			event := DispatchPoolCreated{
 				Metadata: usecase.NewEventMetadata(ec, DispatchPoolCreatedType, Source, subjectFor(p.ID)),
 				PoolID:   p.ID,
 				Code:     p.Code,
 				Name:     p.Name,
+				ClientID: p.ClientID,
+				Region:   p.Region,
 			}
 			return usecaseop.Save(p, repo, event), nil
 		},
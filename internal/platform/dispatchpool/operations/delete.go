@@ -6,6 +6,7 @@ import (
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
@@ -13,7 +14,8 @@ import (
 
 // DeleteCommand is the input DTO.
 type DeleteCommand struct {
-	ID string `json:"id"`
+	ID      string `json:"id"`
+	IfMatch string `json:"-"` // If-Match header; empty skips the check
 }
 
 // DeleteDispatchPool removes a dispatch pool and atomically emits
@@ -41,13 +43,17 @@ func DeleteDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Delet
 			if err := auth.CheckScopeAccess(auth.FromContext(ctx), p.ClientID); err != nil {
 				return nil, err
 			}
+			if err := etag.Check(cmd.IfMatch, p.UpdatedAt); err != nil {
+				return nil, err
+			}
 
 			event := DispatchPoolDeleted{
 				Metadata: usecase.NewEventMetadata(ec, DispatchPoolDeletedType, Source, subjectFor(p.ID)),
 				PoolID:   p.ID,
 				Code:     p.Code,
+				ClientID: p.ClientID,
 			}
-			return usecaseop.Delete(p, repo, event), nil
+			return usecaseop.Delete(p, etag.Guard[dispatchpool.DispatchPool](repo, "msg_dispatch_pools", cmd.IfMatch), event), nil
 		},
 	}
 }
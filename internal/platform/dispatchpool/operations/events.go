@@ -21,11 +21,45 @@ const (
 func subjectFor(id string) string { return "platform.dispatchpool." + id }
 func groupFor(id string) string   { return "platform:dispatchpool:" + id }
 
+// owners scopes the public copy of an event (see publicevents) to the
+// client the pool belongs to; a platform-wide pool has none.
+func owners(clientID *string) []string {
+	if clientID == nil {
+		return nil
+	}
+	return []string{*clientID}
+}
+
+// NewDispatchPoolCreatedEvent / NewDispatchPoolUpdatedEvent build the
+// canonical events with their subject. Exported for cross-aggregate
+// orchestrations (the configuration bundle import) that write pools inside
+// their own transaction.
+func NewDispatchPoolCreatedEvent(ec usecase.ExecutionContext, poolID, code, name string, clientID *string) DispatchPoolCreated {
+	return DispatchPoolCreated{
+		Metadata: usecase.NewEventMetadata(ec, DispatchPoolCreatedType, Source, subjectFor(poolID)),
+		PoolID:   poolID,
+		Code:     code,
+		Name:     name,
+		ClientID: clientID,
+	}
+}
+
+func NewDispatchPoolUpdatedEvent(ec usecase.ExecutionContext, poolID, name string, clientID *string) DispatchPoolUpdated {
+	return DispatchPoolUpdated{
+		Metadata: usecase.NewEventMetadata(ec, DispatchPoolUpdatedType, Source, subjectFor(poolID)),
+		PoolID:   poolID,
+		Name:     name,
+		ClientID: clientID,
+	}
+}
+
 type DispatchPoolCreated struct {
 	Metadata usecase.EventMetadata
 	PoolID   string
 	Code     string
 	Name     string
+	ClientID *string
+	Region   *string
 }
 
 func (e DispatchPoolCreated) EventID() string       { return e.Metadata.EventID }
@@ -41,16 +75,21 @@ func (e DispatchPoolCreated) ExecutionID() string   { return e.Metadata.Executio
 func (e DispatchPoolCreated) MessageGroup() string  { return groupFor(e.PoolID) }
 func (e DispatchPoolCreated) ToDataJSON() ([]byte, error) {
 	return json.Marshal(struct {
-		PoolID string `json:"poolId"`
-		Code   string `json:"code"`
-		Name   string `json:"name"`
-	}{e.PoolID, e.Code, e.Name})
+		PoolID string  `json:"poolId"`
+		Code   string  `json:"code"`
+		Name   string  `json:"name"`
+		Region *string `json:"region,omitempty"`
+	}{e.PoolID, e.Code, e.Name, e.Region})
 }
 
+func (e DispatchPoolCreated) OwningClientIDs() []string { return owners(e.ClientID) }
+
 type DispatchPoolUpdated struct {
 	Metadata usecase.EventMetadata
 	PoolID   string
 	Name     string
+	ClientID *string
+	Region   *string
 }
 
 func (e DispatchPoolUpdated) EventID() string       { return e.Metadata.EventID }
@@ -66,15 +105,19 @@ func (e DispatchPoolUpdated) ExecutionID() string   { return e.Metadata.Executio
 func (e DispatchPoolUpdated) MessageGroup() string  { return groupFor(e.PoolID) }
 func (e DispatchPoolUpdated) ToDataJSON() ([]byte, error) {
 	return json.Marshal(struct {
-		PoolID string `json:"poolId"`
-		Name   string `json:"name"`
-	}{e.PoolID, e.Name})
+		PoolID string  `json:"poolId"`
+		Name   string  `json:"name"`
+		Region *string `json:"region,omitempty"`
+	}{e.PoolID, e.Name, e.Region})
 }
 
+func (e DispatchPoolUpdated) OwningClientIDs() []string { return owners(e.ClientID) }
+
 type DispatchPoolArchived struct {
 	Metadata usecase.EventMetadata
 	PoolID   string
 	Code     string
+	ClientID *string
 }
 
 func (e DispatchPoolArchived) EventID() string       { return e.Metadata.EventID }
@@ -95,10 +138,13 @@ func (e DispatchPoolArchived) ToDataJSON() ([]byte, error) {
 	}{e.PoolID, e.Code})
 }
 
+func (e DispatchPoolArchived) OwningClientIDs() []string { return owners(e.ClientID) }
+
 type DispatchPoolDeleted struct {
 	Metadata usecase.EventMetadata
 	PoolID   string
 	Code     string
+	ClientID *string
 }
 
 func (e DispatchPoolDeleted) EventID() string       { return e.Metadata.EventID }
@@ -119,10 +165,13 @@ func (e DispatchPoolDeleted) ToDataJSON() ([]byte, error) {
 	}{e.PoolID, e.Code})
 }
 
+func (e DispatchPoolDeleted) OwningClientIDs() []string { return owners(e.ClientID) }
+
 type DispatchPoolSuspended struct {
 	Metadata usecase.EventMetadata
 	PoolID   string
 	Code     string
+	ClientID *string
 }
 
 func (e DispatchPoolSuspended) EventID() string       { return e.Metadata.EventID }
@@ -143,10 +192,13 @@ func (e DispatchPoolSuspended) ToDataJSON() ([]byte, error) {
 	}{e.PoolID, e.Code})
 }
 
+func (e DispatchPoolSuspended) OwningClientIDs() []string { return owners(e.ClientID) }
+
 type DispatchPoolActivated struct {
 	Metadata usecase.EventMetadata
 	PoolID   string
 	Code     string
+	ClientID *string
 }
 
 func (e DispatchPoolActivated) EventID() string       { return e.Metadata.EventID }
@@ -167,6 +219,8 @@ func (e DispatchPoolActivated) ToDataJSON() ([]byte, error) {
 	}{e.PoolID, e.Code})
 }
 
+func (e DispatchPoolActivated) OwningClientIDs() []string { return owners(e.ClientID) }
+
 // DispatchPoolsSynced is the rollup emitted by the SDK dispatch-pool sync
 // (SyncDispatchPools). Dispatch pools are global (matched by code), so the
 // ApplicationCode is carried for audit/event provenance only. Mirrors the
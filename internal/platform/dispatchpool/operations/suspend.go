@@ -49,6 +49,7 @@ func SuspendDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Susp
 				Metadata: usecase.NewEventMetadata(ec, DispatchPoolSuspendedType, Source, subjectFor(p.ID)),
 				PoolID:   p.ID,
 				Code:     p.Code,
+				ClientID: p.ClientID,
 			}
 			return usecaseop.Save(p, repo, event), nil
 		},
@@ -92,6 +93,7 @@ func ActivateDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Act
 				Metadata: usecase.NewEventMetadata(ec, DispatchPoolActivatedType, Source, subjectFor(p.ID)),
 				PoolID:   p.ID,
 				Code:     p.Code,
+				ClientID: p.ClientID,
 			}
 			return usecaseop.Save(p, repo, event), nil
 		},
@@ -116,6 +116,8 @@ func SyncDispatchPools(repo *dispatchpool.Repository) usecaseop.Operation[SyncDi
 							Metadata: usecase.NewEventMetadata(ec, DispatchPoolUpdatedType, Source, subjectFor(cur.ID)),
 							PoolID:   cur.ID,
 							Name:     cur.Name,
+							ClientID: cur.ClientID,
+							Region:   cur.Region,
 						},
 					})
 					updated++
@@ -133,6 +135,7 @@ func SyncDispatchPools(repo *dispatchpool.Repository) usecaseop.Operation[SyncDi
 						PoolID:   p.ID,
 						Code:     p.Code,
 						Name:     p.Name,
+						ClientID: p.ClientID,
 					},
 				})
 				created++
@@ -154,6 +157,7 @@ func SyncDispatchPools(repo *dispatchpool.Repository) usecaseop.Operation[SyncDi
 							Metadata: usecase.NewEventMetadata(ec, DispatchPoolArchivedType, Source, subjectFor(cur.ID)),
 							PoolID:   cur.ID,
 							Code:     cur.Code,
+							ClientID: cur.ClientID,
 						},
 					})
 					deleted++
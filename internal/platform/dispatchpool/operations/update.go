@@ -6,7 +6,9 @@ import (
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/validate"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
 )
@@ -18,6 +20,10 @@ type UpdateCommand struct {
 	Description *string `json:"description,omitempty"`
 	RateLimit   *int32  `json:"rateLimit,omitempty"`
 	Concurrency *int32  `json:"concurrency,omitempty"`
+	// Region: nil = unchanged, "" = clear (unrestricted).
+	Region *string `json:"region,omitempty"`
+	// IfMatch is the caller's If-Match header; empty skips the check.
+	IfMatch string `json:"-"`
 }
 
 // UpdateDispatchPool mutates an existing dispatch pool and atomically emits
@@ -38,6 +44,11 @@ func UpdateDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Updat
 			if cmd.RateLimit != nil && *cmd.RateLimit < 0 {
 				return usecase.Validation("INVALID_RATE_LIMIT", "rateLimit cannot be negative")
 			}
+			if cmd.Region != nil {
+				if _, err := validate.Region(*cmd.Region); err != nil {
+					return err
+				}
+			}
 			return nil
 		},
 		// Per-resource authz needs the loaded row, so it runs post-load in
@@ -55,6 +66,9 @@ func UpdateDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Updat
 			if err := auth.CheckScopeAccess(auth.FromContext(ctx), p.ClientID); err != nil {
 				return nil, err
 			}
+			if err := etag.Check(cmd.IfMatch, p.UpdatedAt); err != nil {
+				return nil, err
+			}
 
 			if cmd.Name != nil {
 				p.Name = strings.TrimSpace(*cmd.Name)
@@ -68,13 +82,21 @@ func UpdateDispatchPool(repo *dispatchpool.Repository) usecaseop.Operation[Updat
 			if cmd.Concurrency != nil {
 				p.Concurrency = *cmd.Concurrency
 			}
+			if cmd.Region != nil {
+				p.Region = nil
+				if region, _ := validate.Region(*cmd.Region); region != "" {
+					p.Region = &region
+				}
+			}
 
 			event := DispatchPoolUpdated{
 				Metadata: usecase.NewEventMetadata(ec, DispatchPoolUpdatedType, Source, subjectFor(p.ID)),
 				PoolID:   p.ID,
 				Name:     p.Name,
+				ClientID: p.ClientID,
+				Region:   p.Region,
 			}
-			return usecaseop.Save(p, repo, event), nil
+			return usecaseop.Save(p, etag.Guard[dispatchpool.DispatchPool](repo, "msg_dispatch_pools", cmd.IfMatch), event), nil
 		},
 	}
 }
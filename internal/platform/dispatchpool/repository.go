@@ -61,7 +61,7 @@ func (r *Repository) FindWithFilters(ctx context.Context, status, clientID *stri
 	f.EqPtr("client_id", clientID)
 
 	q := `SELECT id, code, name, description, rate_limit, concurrency,
-		client_id, client_identifier, status, created_at, updated_at
+		client_id, client_identifier, status, created_at, updated_at, region
 	  FROM msg_dispatch_pools` + f.Where() + ` ORDER BY code`
 
 	rows, err := r.pool.Query(ctx, q, f.Args()...)
@@ -93,6 +93,7 @@ func (r *Repository) Persist(ctx context.Context, p *DispatchPool, tx *usecasepg
 		Status:           string(p.Status),
 		CreatedAt:        p.CreatedAt,
 		UpdatedAt:        time.Now().UTC(),
+		Region:           p.Region,
 	})
 }
 
@@ -111,6 +112,7 @@ func rowToDispatchPool(row dbq.MsgDispatchPool) *DispatchPool {
 		Concurrency:      row.Concurrency,
 		ClientID:         row.ClientID,
 		ClientIdentifier: row.ClientIdentifier,
+		Region:           row.Region,
 		Status:           ParseStatus(row.Status),
 		CreatedAt:        row.CreatedAt,
 		UpdatedAt:        row.UpdatedAt,
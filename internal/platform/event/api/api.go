@@ -3,18 +3,28 @@ package api
 
 import (
 	"context"
+	"errors"
+	"fmt"
 	"net/http"
+	"net/url"
+	"slices"
 	"strings"
 	"time"
 
 	"github.com/danielgtaylor/huma/v2"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/redact"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/schemaregistry"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 )
 
@@ -24,6 +34,37 @@ type State struct {
 	// Clients resolves a clientCode → client_id on ingest (client-centric
 	// linkage). Optional: when nil, clientCode is ignored.
 	Clients *client.Repository
+	// Redact masks each event type's redact fields in returned data.
+	// Optional: nil returns data unredacted.
+	Redact *redact.Rules
+	// Outbound vets receipt callback URLs against the egress policy.
+	// Optional: nil allows any URL.
+	Outbound *outbound.Policies
+	// Blobs offloads data over FC_BLOB_THRESHOLD_BYTES to the blob store,
+	// keeping a reference in the row. Optional: nil stores all data inline
+	// and refuses references.
+	Blobs *blobstore.Offloader
+	// Schemas resolves and validates each event's schema registry id
+	// against its event type's binding. Optional: nil only checks the
+	// format of a supplied schemaId.
+	Schemas *schemaregistry.Resolver
+	// Lifecycles flags events of deprecated event types and rejects those
+	// past their sunset. Optional: nil treats every event type as current.
+	Lifecycles *eventtype.LifecycleCache
+	// Producers restricts event types with producer bindings to their
+	// bound service accounts. Optional: nil lets any caller publish any
+	// event type.
+	Producers *application.ProducerRegistry
+}
+
+// ingestOut is the ingest endpoints' output: the body plus, when an event's
+// type is deprecated, the Deprecation (RFC 9745) and Sunset (RFC 8594)
+// headers and a Warning naming it. Empty headers aren't sent.
+type ingestOut[T any] struct {
+	Deprecation string `header:"Deprecation"`
+	Sunset      string `header:"Sunset"`
+	Warning     string `header:"Warning"`
+	Body        T
 }
 
 const tag = "events"
@@ -82,7 +123,7 @@ func registerBFF(api huma.API, s *State, base, opPrefix, tag string) {
 // The Laravel SDK decodes CreateEventResponse on both 200 and 201, so the
 // contract holds either way. dispatchJobCount is always 0, exactly like
 // Rust (jobs are fanned out by the stream processor, not inline).
-func (s *State) create(ctx context.Context, in *apicommon.In[CreateEventRequest]) (*apicommon.Out[CreateEventResponse], error) {
+func (s *State) create(ctx context.Context, in *apicommon.In[CreateEventRequest]) (*ingestOut[CreateEventResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanWritePermission(ac, "platform:messaging:batch:events-write"); err != nil {
 		return nil, err
@@ -105,6 +146,14 @@ func (s *State) create(ctx context.Context, in *apicommon.In[CreateEventRequest]
 		return nil, httperror.Forbidden("No access to client: " + *clientID)
 	}
 
+	if err := s.checkProducer(ctx, ac, req.EventType); err != nil {
+		return nil, err
+	}
+	var notices deprecations
+	if err := s.checkLifecycle(ctx, ac, req.EventType, &notices); err != nil {
+		return nil, err
+	}
+
 	ev := event.New(req.EventType, req.Source, req.Subject, req.Data)
 	if req.DeduplicationID != "" {
 		ev.DeduplicationID = req.DeduplicationID
@@ -116,20 +165,34 @@ func (s *State) create(ctx context.Context, in *apicommon.In[CreateEventRequest]
 	for _, c := range req.ContextData {
 		ev.Context = append(ev.Context, event.ContextEntry{Key: c.Key, Value: c.Value})
 	}
+	receipt, err := s.receiptFor(clientID, req.Receipt)
+	if err != nil {
+		return nil, err
+	}
+	ev.Receipt = receipt
+	if err := s.resolveSchema(ctx, ev, req.SchemaID); err != nil {
+		return nil, err
+	}
+	if err := s.offload(ctx, ev); err != nil {
+		return nil, err
+	}
 
 	if _, err := s.Repo.InsertBatch(ctx, []event.Event{*ev}); err != nil {
 		return nil, usecase.Internal("REPO", "insert failed", err)
 	}
-	return &apicommon.Out[CreateEventResponse]{Body: CreateEventResponse{
+	out := &ingestOut[CreateEventResponse]{Body: CreateEventResponse{
 		Event:            createdFromEntity(ev),
 		DispatchJobCount: 0,
 		IsDuplicate:      false,
-	}}, nil
+		Warnings:         notices.warnings,
+	}}
+	out.Deprecation, out.Sunset, out.Warning = notices.headers()
+	return out, nil
 }
 
 // ── batch ingest ─────────────────────────────────────────────────────────
 
-func (s *State) batchIngest(ctx context.Context, in *apicommon.In[BatchRequest]) (*apicommon.Out[BatchResponse], error) {
+func (s *State) batchIngest(ctx context.Context, in *apicommon.In[BatchRequest]) (*ingestOut[BatchResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanWritePermission(ac, "platform:messaging:batch:events-write"); err != nil {
 		return nil, err
@@ -141,7 +204,16 @@ func (s *State) batchIngest(ctx context.Context, in *apicommon.In[BatchRequest])
 	// Per-batch cache of clientCode → client_id (a batch usually shares one
 	// client). A nil entry means "looked up, not found" so we don't re-query.
 	clientByCode := map[string]*string{}
+	// One unauthorized or sunset item fails the whole batch, like any other
+	// invalid item.
+	var notices deprecations
 	for _, it := range in.Body.Items {
+		if err := s.checkProducer(ctx, ac, it.Type); err != nil {
+			return nil, err
+		}
+		if err := s.checkLifecycle(ctx, ac, it.Type, &notices); err != nil {
+			return nil, err
+		}
 		ev := event.New(it.Type, it.Source, it.Subject, it.Data)
 		if it.ID != "" {
 			ev.ID = it.ID
@@ -178,6 +250,17 @@ func (s *State) batchIngest(ctx context.Context, in *apicommon.In[BatchRequest])
 		for _, c := range it.Context {
 			ev.Context = append(ev.Context, event.ContextEntry{Key: c.Key, Value: c.Value})
 		}
+		receipt, err := s.receiptFor(ev.ClientID, it.Receipt)
+		if err != nil {
+			return nil, err
+		}
+		ev.Receipt = receipt
+		if err := s.resolveSchema(ctx, ev, it.SchemaID); err != nil {
+			return nil, err
+		}
+		if err := s.offload(ctx, ev); err != nil {
+			return nil, err
+		}
 		events = append(events, *ev)
 	}
 	if _, err := s.Repo.InsertBatch(ctx, events); err != nil {
@@ -188,7 +271,178 @@ func (s *State) batchIngest(ctx context.Context, in *apicommon.In[BatchRequest])
 	results := apicommon.MapSlice(events, func(e *event.Event) BatchResultItem {
 		return BatchResultItem{ID: e.ID, Status: "SUCCESS"}
 	})
-	return &apicommon.Out[BatchResponse]{Body: BatchResponse{Results: results}}, nil
+	out := &ingestOut[BatchResponse]{Body: BatchResponse{Results: results, Warnings: notices.warnings}}
+	out.Deprecation, out.Sunset, out.Warning = notices.headers()
+	return out, nil
+}
+
+// checkProducer rejects an event whose type has producer bindings when the
+// caller isn't one of the principals they admit, naming the applications
+// that are.
+func (s *State) checkProducer(ctx context.Context, ac *auth.AuthContext, code string) error {
+	chk, err := s.Producers.Check(ctx, code, ac.PrincipalID)
+	if err != nil {
+		return usecase.Internal("REPO", "load producer bindings failed", err)
+	}
+	if chk.Allowed {
+		return nil
+	}
+	msg := "principal " + ac.PrincipalID + " is not a registered producer of event type " + code +
+		"; registered producers: " + strings.Join(chk.Producers, ", ")
+	details := map[string]any{"eventType": code, "principalId": ac.PrincipalID, "producers": chk.Producers}
+	return usecase.Authorization("PRODUCER_NOT_REGISTERED", msg).WithDetails(details)
+}
+
+// checkLifecycle rejects an event whose type is past its sunset, with the
+// type's migration hint, and notes one whose type is deprecated. Either
+// way the use is reported to the lifecycle cache's OnUse hook.
+func (s *State) checkLifecycle(ctx context.Context, ac *auth.AuthContext, code string, notices *deprecations) error {
+	l, deprecated, err := s.Lifecycles.Lookup(ctx, code)
+	if err != nil {
+		return usecase.Internal("REPO", "load event type lifecycles failed", err)
+	}
+	if !deprecated {
+		return nil
+	}
+	sunset := l.IsSunset(time.Now())
+	s.Lifecycles.ReportUse(eventtype.LifecycleUse{PrincipalID: ac.PrincipalID, Code: code, Lifecycle: l, Sunset: sunset})
+	if sunset {
+		msg := "event type " + code + " was sunset on " + l.SunsetAt.UTC().Format(time.RFC3339)
+		details := map[string]any{"eventType": code, "sunsetAt": l.SunsetAt.UTC()}
+		if l.MigrationHint != nil {
+			msg += ": " + *l.MigrationHint
+			details["migrationHint"] = *l.MigrationHint
+		}
+		return usecase.Validation("EVENT_TYPE_SUNSET", msg).WithDetails(details)
+	}
+	notices.add(code, l)
+	return nil
+}
+
+// deprecations collects the deprecated event types one request ingested:
+// a warning per type, and the earliest deprecation and sunset for the
+// headers.
+type deprecations struct {
+	seen     map[string]bool
+	warnings []string
+	since    time.Time
+	sunset   *time.Time
+}
+
+func (d *deprecations) add(code string, l eventtype.Lifecycle) {
+	if d.seen[code] {
+		return
+	}
+	if d.seen == nil {
+		d.seen = map[string]bool{}
+	}
+	d.seen[code] = true
+	msg := "event type " + code + " is deprecated"
+	if l.SunsetAt != nil {
+		msg += " and will be rejected from " + l.SunsetAt.UTC().Format(time.RFC3339)
+	}
+	if l.MigrationHint != nil {
+		msg += ": " + *l.MigrationHint
+	}
+	d.warnings = append(d.warnings, msg)
+	if d.since.IsZero() || l.DeprecatedAt.Before(d.since) {
+		d.since = l.DeprecatedAt
+	}
+	if l.SunsetAt != nil && (d.sunset == nil || l.SunsetAt.Before(*d.sunset)) {
+		d.sunset = l.SunsetAt
+	}
+}
+
+// headers returns the Deprecation, Sunset and Warning header values, all
+// empty when nothing ingested was deprecated.
+func (d *deprecations) headers() (deprecation, sunset, warning string) {
+	if len(d.warnings) == 0 {
+		return "", "", ""
+	}
+	if d.sunset != nil {
+		sunset = d.sunset.UTC().Format(http.TimeFormat)
+	}
+	text := d.warnings[0]
+	if len(d.warnings) > 1 {
+		text = fmt.Sprintf("%s (and %d more deprecated event types)", text, len(d.warnings)-1)
+	}
+	return fmt.Sprintf("@%d", d.since.Unix()), sunset, fmt.Sprintf("299 - %q", text)
+}
+
+// resolveSchema sets ev's schema id from the one the producer supplied and
+// its event type's registry binding. Runs before offload so validation
+// sees the inline data; data a producer already offloaded isn't fetched
+// back to validate.
+func (s *State) resolveSchema(ctx context.Context, ev *event.Event, schemaID *string) error {
+	data := ev.Data
+	if _, ok := blobstore.ParseRef(data); ok {
+		data = nil
+	}
+	id, err := s.Schemas.Resolve(ctx, ev.Type, schemaID, data)
+	if err != nil {
+		var rej *schemaregistry.Rejection
+		if errors.As(err, &rej) {
+			return httperror.BadRequest(rej.Code, rej.Message)
+		}
+		return usecase.Internal("SCHEMA_REGISTRY", "resolve schema id failed", err)
+	}
+	ev.SchemaID = id
+	return nil
+}
+
+// offload moves ev's data to the blob store when it is over the threshold.
+// Data that is already a reference must point at an object an outbox
+// offloaded (OutboxPrefix): a client may hand over its own large payload,
+// not another event's.
+func (s *State) offload(ctx context.Context, ev *event.Event) error {
+	if ref, ok := blobstore.ParseRef(ev.Data); ok {
+		if !s.Blobs.Enabled() {
+			return httperror.BadRequest("BLOB_STORE_DISABLED", "data is a blob reference but no blob store is configured")
+		}
+		if !strings.HasPrefix(ref.Key, blobstore.OutboxPrefix) {
+			return httperror.BadRequest("INVALID_BLOB_REF", "a blob reference must point under "+blobstore.OutboxPrefix)
+		}
+		return nil
+	}
+	data, err := s.Blobs.Offload(ctx, blobstore.ContentKey(blobstore.EventsPrefix, ev.Data), ev.Data)
+	if err != nil {
+		return usecase.Internal("BLOB_STORE", "offload event data failed", err)
+	}
+	ev.Data = data
+	return nil
+}
+
+// receiptFor validates a requested receipt: a callback URL the egress
+// policy allows and/or a four-part event type code. nil asks for none.
+func (s *State) receiptFor(clientID *string, r *ReceiptRequest) (*event.Receipt, error) {
+	if r == nil {
+		return nil, nil
+	}
+	callbackURL := strings.TrimSpace(r.CallbackURL)
+	eventType := strings.TrimSpace(r.EventType)
+	if callbackURL == "" && eventType == "" {
+		return nil, httperror.BadRequest("VALIDATION", "receipt needs a callbackUrl or an eventType")
+	}
+	out := &event.Receipt{}
+	if callbackURL != "" {
+		u, err := url.Parse(callbackURL)
+		if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+			return nil, httperror.BadRequest("VALIDATION", "receipt callbackUrl must be an absolute http(s) URL")
+		}
+		if err := s.Outbound.CheckURL(clientID, callbackURL); err != nil {
+			return nil, httperror.BadRequest("TARGET_URL_BLOCKED", err.Error())
+		}
+		out.CallbackURL = &callbackURL
+	}
+	if eventType != "" {
+		parts := strings.Split(eventType, ":")
+		if len(parts) != 4 || slices.Contains(parts, "") {
+			return nil, httperror.BadRequest("VALIDATION",
+				"receipt eventType must follow format: application:subdomain:aggregate:event")
+		}
+		out.EventType = &eventType
+	}
+	return out, nil
 }
 
 // ── list / detail ────────────────────────────────────────────────────────
@@ -213,6 +467,8 @@ type listInput struct {
 	Subdomains   string `query:"subdomains" doc:"CSV of subdomains"`
 	Aggregates   string `query:"aggregates" doc:"CSV of aggregates"`
 	Types        string `query:"types" doc:"CSV of event types"`
+
+	apicommon.CursorQuery
 }
 
 // splitCSV mirrors Rust's split_csv (event/api.rs): trim, drop empties.
@@ -266,7 +522,8 @@ func (in *listInput) toFilters() event.FilterParams {
 
 // The list endpoints' Body is a bare JSON array — the SPA's EventListPage
 // binds the returned array directly to its DataTable, so {items:[...]} would
-// render zero rows. Mirrors Rust's list_events returning Vec<EventRead>.
+// render zero rows. Mirrors Rust's list_events returning Vec<EventRead>. The
+// next-page cursor therefore travels in the X-Next-Cursor header.
 
 // scopeFilters applies SQL-side tenant scoping (anchor sees all → no
 // scoping). Without it a non-anchor holding event:view could read any
@@ -281,30 +538,64 @@ func scopeFilters(ac *auth.AuthContext, f event.FilterParams) event.FilterParams
 	return f
 }
 
-func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[[]EventRead], error) {
+// find runs the list query in the mode the caller asked for (see
+// apicommon.CursorQuery): the keyset page plus the next cursor, or the
+// legacy offset page with no cursor.
+func (s *State) find(ctx context.Context, ac *auth.AuthContext, in *listInput) ([]event.Event, string, error) {
+	f := scopeFilters(ac, in.toFilters())
+	if in.OffsetMode(in.Offset) {
+		rows, err := s.Repo.FindWithFilters(ctx, f)
+		if err != nil {
+			return nil, "", usecase.Internal("REPO", "find_with_filters failed", err)
+		}
+		return rows, "", nil
+	}
+	var after string
+	if in.After != "" {
+		parts, err := apicommon.DecodeCursor(in.After, 1)
+		if err != nil {
+			return nil, "", usecase.Validation("CURSOR", "invalid cursor")
+		}
+		after = parts[0]
+	}
+	limit := f.Limit
+	if limit <= 0 || limit > 1000 {
+		limit = 100
+	}
+	f.Limit = limit + 1
+	rows, err := s.Repo.FindWithCursor(ctx, f, after)
+	if err != nil {
+		return nil, "", usecase.Internal("REPO", "find_with_cursor failed", err)
+	}
+	page, next := apicommon.CursorPage(rows, limit, func(e *event.Event) []string { return []string{e.ID} })
+	return page, next, nil
+}
+
+func (s *State) list(ctx context.Context, in *listInput) (*apicommon.CursorOut[EventRead], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanWritePermission(ac, "platform:messaging:event:view"); err != nil {
 		return nil, err
 	}
-	rows, err := s.Repo.FindWithFilters(ctx, scopeFilters(ac, in.toFilters()))
-	if err != nil {
-		return nil, usecase.Internal("REPO", "find_with_filters failed", err)
-	}
-	out := apicommon.MapSlice(rows, readFromEntity)
-	return &apicommon.Out[[]EventRead]{Body: out}, nil
+	return s.listPage(ctx, ac, in)
 }
 
-func (s *State) listRaw(ctx context.Context, in *listInput) (*apicommon.Out[[]EventRead], error) {
+func (s *State) listRaw(ctx context.Context, in *listInput) (*apicommon.CursorOut[EventRead], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanWritePermission(ac, "platform:messaging:event:view-raw"); err != nil {
 		return nil, err
 	}
-	rows, err := s.Repo.FindWithFilters(ctx, scopeFilters(ac, in.toFilters()))
+	return s.listPage(ctx, ac, in)
+}
+
+func (s *State) listPage(ctx context.Context, ac *auth.AuthContext, in *listInput) (*apicommon.CursorOut[EventRead], error) {
+	rows, next, err := s.find(ctx, ac, in)
 	if err != nil {
-		return nil, usecase.Internal("REPO", "find_raw failed", err)
+		return nil, err
+	}
+	if err := s.redactAll(ctx, rows); err != nil {
+		return nil, err
 	}
-	out := apicommon.MapSlice(rows, readFromEntity)
-	return &apicommon.Out[[]EventRead]{Body: out}, nil
+	return &apicommon.CursorOut[EventRead]{NextCursor: next, Body: apicommon.MapSlice(rows, readFromEntity)}, nil
 }
 
 // ── debug raw events ─────────────────────────────────────────────────────
@@ -329,6 +620,9 @@ func (s *State) listDebugRaw(ctx context.Context, in *rawListInput) (*apicommon.
 	if err != nil {
 		return nil, usecase.Internal("REPO", "find_recent_raw failed", err)
 	}
+	if err := s.redactAll(ctx, rows); err != nil {
+		return nil, err
+	}
 	out := apicommon.MapSlice(rows, rawFromEntity)
 	return &apicommon.Out[[]RawEventResponse]{Body: out}, nil
 }
@@ -352,7 +646,25 @@ func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.
 	if ev.ClientID != nil && !ac.CanAccessClient(*ev.ClientID) {
 		return nil, httperror.Forbidden("No access to this event")
 	}
-	return &apicommon.Out[EventResponse]{Body: fromEntity(ev)}, nil
+	one := []event.Event{*ev}
+	if err := s.redactAll(ctx, one); err != nil {
+		return nil, err
+	}
+	return &apicommon.Out[EventResponse]{Body: fromEntity(&one[0])}, nil
+}
+
+// redactAll masks data in place per each event type's data policy. Every
+// handler that returns event data goes through here — list, detail and the
+// debug raw view alike.
+func (s *State) redactAll(ctx context.Context, evs []event.Event) error {
+	for i := range evs {
+		data, err := s.Redact.JSON(ctx, evs[i].Type, evs[i].Data)
+		if err != nil {
+			return usecase.Internal("REDACT", "load redaction rules failed", err)
+		}
+		evs[i].Data = data
+	}
+	return nil
 }
 
 // ── filter-options ───────────────────────────────────────────────────────
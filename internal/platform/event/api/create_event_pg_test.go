@@ -8,17 +8,24 @@ import (
 	"net/http"
 	"strings"
 	"testing"
+	"time"
 
 	"github.com/danielgtaylor/huma/v2/humatest"
 	"github.com/jackc/pgx/v5/pgxpool"
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/payloadcrypt"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 )
 
 func TestMain(m *testing.M) { testpg.RunMain(m) }
@@ -170,6 +177,49 @@ func TestCreateEvent_ClientDefaultingAndTenantGuard(t *testing.T) {
 	assert.Contains(t, err.Error(), "No access to client")
 }
 
+// TestCreateEvent_ForgedEnvelopeIsSealed: data shaped like a payload
+// envelope for the producer's own client is sealed like any other payload,
+// so the stored row opens back to exactly what was posted.
+func TestCreateEvent_ForgedEnvelopeIsSealed(t *testing.T) {
+	pool := testpg.Pool(t)
+	key, err := encryption.GenerateKey()
+	require.NoError(t, err)
+	kek, err := encryption.New(key)
+	require.NoError(t, err)
+	cipher, err := payloadcrypt.New(payloadcrypt.Config{
+		Keys:    payloadcrypt.NewPgKeyStore(pool),
+		KEK:     kek,
+		Clients: []string{"clt_evtseal1"},
+	})
+	require.NoError(t, err)
+	repo := event.NewRepository(pool)
+	repo.SetPayloadCipher(cipher)
+	s := &State{Repo: repo}
+	ctx := auth.WithContext(context.Background(), &auth.AuthContext{
+		PrincipalID: "p_evt_seal",
+		Scope:       auth.ScopeClient,
+		Clients:     []string{"clt_evtseal1"},
+		Permissions: []string{"platform:messaging:batch:events-write"},
+	})
+
+	forged := `{"fcenc":1,"cid":"clt_evtseal1","kid":"x","ct":"AAAA"}`
+	out, err := s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:singular:event:sealed",
+		Source:    "test://sealed",
+		Data:      json.RawMessage(forged),
+	}})
+	require.NoError(t, err)
+
+	row := fetchEventRow(t, ctx, pool, out.Body.Event.ID)
+	assert.True(t, payloadcrypt.IsSealed([]byte(row.Data)))
+	assert.NotContains(t, row.Data, `"kid": "x"`, "stored sealed, not as posted")
+
+	got, err := repo.FindByID(ctx, out.Body.Event.ID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	assert.JSONEq(t, forged, string(got.Data), "opens back to the posted data")
+}
+
 // TestCreateEvent_BadPayloadEnvelopeMatchesBatch pins that a bad singular
 // payload is rejected with the SAME {error:"VALIDATION", message} envelope
 // (and 400 status) the batch endpoint produces — both flow through the
@@ -197,3 +247,198 @@ func TestCreateEvent_BadPayloadEnvelopeMatchesBatch(t *testing.T) {
 	assert.True(t, strings.Contains(senv.Message, "validation failed") || senv.Message != "",
 		"message must be populated")
 }
+
+// TestCreateEvent_ReceiptValidatedAndPersisted pins that a requested
+// receipt lands in msg_events (singular and batch, snake_case alias form
+// included) and that an empty or malformed one is refused.
+func TestCreateEvent_ReceiptValidatedAndPersisted(t *testing.T) {
+	ctx := anchorCtx()
+	pool := testpg.Pool(t)
+	repo := event.NewRepository(pool)
+	s := &State{Repo: repo}
+
+	out, err := s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:singular:event:receipted",
+		Source:    "test://receipt",
+		Data:      json.RawMessage(`{}`),
+		Receipt:   &ReceiptRequest{CallbackURL: "https://producer.example/receipts"},
+	}})
+	require.NoError(t, err)
+	got, err := repo.ReceiptFor(ctx, out.Body.Event.ID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	require.NotNil(t, got.CallbackURL)
+	assert.Equal(t, "https://producer.example/receipts", *got.CallbackURL)
+	assert.Nil(t, got.EventType)
+
+	var item BatchEventItem
+	require.NoError(t, json.Unmarshal([]byte(`{"event_type":"it:batch:event:receipted","source":"test://receipt","data":{},
+		"receipt":{"eventType":"it:batch:delivery:receipted"}}`), &item))
+	bout, err := s.batchIngest(ctx, &apicommon.In[BatchRequest]{Body: BatchRequest{Items: []BatchEventItem{item}}})
+	require.NoError(t, err)
+	got, err = repo.ReceiptFor(ctx, bout.Body.Results[0].ID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	require.NotNil(t, got.EventType)
+	assert.Equal(t, "it:batch:delivery:receipted", *got.EventType)
+
+	none, err := repo.ReceiptFor(ctx, out.Body.Event.ID+"x")
+	require.NoError(t, err)
+	assert.Nil(t, none, "an unknown event asked for nothing")
+
+	for _, bad := range []ReceiptRequest{
+		{},
+		{CallbackURL: "/relative"},
+		{CallbackURL: "ftp://producer.example/receipts"},
+		{EventType: "not:four:parts"},
+	} {
+		_, err := s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+			EventType: "it:singular:event:receipted",
+			Source:    "test://receipt",
+			Data:      json.RawMessage(`{}`),
+			Receipt:   &bad,
+		}})
+		assert.Error(t, err, "receipt %+v must be refused", bad)
+	}
+}
+
+// TestCreateEvent_OffloadsLargeData pins that data over the blob threshold
+// is stored in the blob store with a reference in the row, and that a
+// client may only reference an object under the outbox prefix.
+func TestCreateEvent_OffloadsLargeData(t *testing.T) {
+	ctx := anchorCtx()
+	pool := testpg.Pool(t)
+	store, err := blobstore.Open(ctx, "file://"+t.TempDir())
+	require.NoError(t, err)
+	blobs := blobstore.NewOffloader(store, 16, 0)
+	s := &State{Repo: event.NewRepository(pool), Blobs: blobs}
+
+	big := `{"lines":["a","b","c","d","e","f","g"]}`
+	out, err := s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:singular:event:large",
+		Source:    "test://large",
+		Data:      json.RawMessage(big),
+	}})
+	require.NoError(t, err)
+	row := fetchEventRow(t, ctx, pool, out.Body.Event.ID)
+	ref, ok := blobstore.ParseRef([]byte(row.Data))
+	require.True(t, ok, "row holds a reference: %s", row.Data)
+	assert.True(t, strings.HasPrefix(ref.Key, blobstore.EventsPrefix))
+	data, err := blobs.Fetch(ctx, ref)
+	require.NoError(t, err)
+	assert.JSONEq(t, big, string(data))
+
+	_, err = s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:singular:event:large",
+		Source:    "test://large",
+		Data:      ref.JSON(),
+	}})
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), blobstore.OutboxPrefix)
+
+	ref.Key = blobstore.OutboxPrefix + "abc"
+	_, err = s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:singular:event:large",
+		Source:    "test://large",
+		Data:      ref.JSON(),
+	}})
+	require.NoError(t, err)
+}
+
+// TestCreateEvent_DeprecatedAndSunsetTypes pins the lifecycle checks: a
+// deprecated type ingests with a warning and the deprecation headers; a
+// type past its sunset is rejected with its migration hint.
+func TestCreateEvent_DeprecatedAndSunsetTypes(t *testing.T) {
+	ctx := anchorCtx()
+	pool := testpg.Pool(t)
+	since := time.Date(2026, 1, 1, 0, 0, 0, 0, time.UTC)
+	sunset, past := time.Now().Add(24*time.Hour).UTC(), time.Now().Add(-time.Hour).UTC()
+	hint := "publish it:orders:order:placed instead"
+	lifecycles := eventtype.NewLifecycleCache(func(context.Context) (map[string]eventtype.Lifecycle, error) {
+		return map[string]eventtype.Lifecycle{
+			"it:orders:order:created": {DeprecatedAt: since, SunsetAt: &sunset, MigrationHint: &hint},
+			"it:orders:order:legacy":  {DeprecatedAt: since, SunsetAt: &past, MigrationHint: &hint},
+		}, nil
+	}, 0)
+	s := &State{Repo: event.NewRepository(pool), Lifecycles: lifecycles}
+
+	out, err := s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:orders:order:created",
+		Source:    "test://lifecycle",
+		Data:      json.RawMessage(`{}`),
+	}})
+	require.NoError(t, err)
+	require.Len(t, out.Body.Warnings, 1)
+	assert.Contains(t, out.Body.Warnings[0], hint)
+	assert.Equal(t, "@1767225600", out.Deprecation)
+	assert.Equal(t, sunset.Format(http.TimeFormat), out.Sunset)
+	assert.True(t, strings.HasPrefix(out.Warning, `299 - "event type it:orders:order:created is deprecated`), out.Warning)
+
+	bout, err := s.batchIngest(ctx, &apicommon.In[BatchRequest]{Body: BatchRequest{Items: []BatchEventItem{
+		{Type: "it:orders:order:created", Source: "test://lifecycle", Data: json.RawMessage(`{}`)},
+		{Type: "it:orders:order:created", Source: "test://lifecycle", Data: json.RawMessage(`{}`)},
+		{Type: "it:orders:order:shipped", Source: "test://lifecycle", Data: json.RawMessage(`{}`)},
+	}}})
+	require.NoError(t, err)
+	assert.Len(t, bout.Body.Results, 3)
+	assert.Len(t, bout.Body.Warnings, 1, "one warning per deprecated type")
+
+	_, err = s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:orders:order:legacy",
+		Source:    "test://lifecycle",
+		Data:      json.RawMessage(`{}`),
+	}})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "EVENT_TYPE_SUNSET")
+	assert.Equal(t, hint, usecase.AsError(err).Details["migrationHint"])
+
+	out, err = s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:orders:order:shipped",
+		Source:    "test://lifecycle",
+		Data:      json.RawMessage(`{}`),
+	}})
+	require.NoError(t, err)
+	assert.Empty(t, out.Body.Warnings)
+	assert.Empty(t, out.Deprecation)
+}
+
+func TestCreateEvent_ProducerBindings(t *testing.T) {
+	ctx := anchorCtx() // principal p_evt_test
+	pool := testpg.Pool(t)
+	bound, other := "p_evt_test", "prn_other"
+	producers := application.NewProducerRegistry(func(context.Context) ([]application.ProducerGrant, error) {
+		return []application.ProducerGrant{
+			{ApplicationCode: "orders", ApplicationActive: true, PrincipalID: &bound, EventTypePattern: "it:orders:order:*"},
+			{ApplicationCode: "billing", ApplicationActive: true, PrincipalID: &other, EventTypePattern: "it:billing:invoice:*"},
+		}, nil
+	}, 0)
+	s := &State{Repo: event.NewRepository(pool), Producers: producers}
+
+	_, err := s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:orders:order:placed",
+		Source:    "test://producers",
+		Data:      json.RawMessage(`{}`),
+	}})
+	require.NoError(t, err, "a bound producer publishes")
+
+	_, err = s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:shipping:parcel:sent",
+		Source:    "test://producers",
+		Data:      json.RawMessage(`{}`),
+	}})
+	require.NoError(t, err, "an event type without bindings stays open")
+
+	_, err = s.create(ctx, &apicommon.In[CreateEventRequest]{Body: CreateEventRequest{
+		EventType: "it:billing:invoice:issued",
+		Source:    "test://producers",
+		Data:      json.RawMessage(`{}`),
+	}})
+	testpg.RequireUsecaseError(t, err, usecase.KindAuthorization, "PRODUCER_NOT_REGISTERED")
+	assert.Equal(t, []string{"billing"}, usecase.AsError(err).Details["producers"])
+
+	// One unauthorized item fails the batch.
+	_, err = s.batchIngest(ctx, &apicommon.In[BatchRequest]{Body: BatchRequest{Items: []BatchEventItem{
+		{Type: "it:orders:order:placed", Source: "test://producers", Data: json.RawMessage(`{}`)},
+		{Type: "it:billing:invoice:issued", Source: "test://producers", Data: json.RawMessage(`{}`)},
+	}}})
+	testpg.RequireUsecaseError(t, err, usecase.KindAuthorization, "PRODUCER_NOT_REGISTERED")
+}
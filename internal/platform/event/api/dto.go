@@ -191,6 +191,15 @@ type CreateEventRequest struct {
 	DeduplicationID string            `json:"deduplicationId,omitempty" doc:"Deduplication ID for exactly-once delivery"`
 	ClientID        *string           `json:"clientId,omitempty" doc:"Client ID (optional, defaults to caller's client)"`
 	ContextData     []ContextEntryDTO `json:"contextData,omitempty" doc:"Context data for filtering/searching"`
+	Receipt         *ReceiptRequest   `json:"receipt,omitempty"`
+	SchemaID        *string           `json:"schemaId,omitempty" doc:"Schema registry id the data was produced with. Defaults to the bound subject's latest version"`
+}
+
+// ReceiptRequest asks for a delivery receipt per dispatch job of the event.
+// At least one of the two must be set.
+type ReceiptRequest struct {
+	CallbackURL string `json:"callbackUrl,omitempty" doc:"Webhook the receipt is POSTed to"`
+	EventType   string `json:"eventType,omitempty" doc:"Event type code the receipt is emitted as, delivered to that type's subscriptions"`
 }
 
 // CreatedEvent is the event envelope inside CreateEventResponse. It
@@ -213,6 +222,7 @@ type CreatedEvent struct {
 	DeduplicationID string            `json:"deduplicationId,omitempty"`
 	ClientID        *string           `json:"clientId,omitempty"`
 	ContextData     []ContextEntryDTO `json:"contextData,omitempty"`
+	SchemaID        *string           `json:"schemaId,omitempty"`
 	CreatedAt       httpcompat.Time   `json:"createdAt"`
 }
 
@@ -223,6 +233,7 @@ type CreateEventResponse struct {
 	Event            CreatedEvent `json:"event"`
 	DispatchJobCount int          `json:"dispatchJobCount" doc:"Number of dispatch jobs created for matching subscriptions"`
 	IsDuplicate      bool         `json:"isDuplicate" doc:"True if this was a deduplicated request (event already existed)"`
+	Warnings         []string     `json:"warnings,omitempty" doc:"Set when the event type is deprecated: its sunset and what to migrate to"`
 }
 
 func createdFromEntity(e *event.Event) CreatedEvent {
@@ -247,6 +258,7 @@ func createdFromEntity(e *event.Event) CreatedEvent {
 		DeduplicationID: e.DeduplicationID,
 		ClientID:        e.ClientID,
 		ContextData:     ctx,
+		SchemaID:        e.SchemaID,
 		CreatedAt:       jsontime.New(e.CreatedAt),
 	}
 }
@@ -275,11 +287,15 @@ type BatchEventItem struct {
 	// sends these as `contextData`; mirrors the single-event create + the event
 	// entity's context array (stored in context_data).
 	Context []ContextEntryDTO `json:"contextData,omitempty"`
+	Receipt *ReceiptRequest   `json:"receipt,omitempty"`
+	// SchemaID is the schema registry id the data was produced with; it
+	// defaults to the event type's bound subject's latest version.
+	SchemaID *string `json:"schemaId,omitempty"`
 }
 
 // UnmarshalJSON accepts both the camelCase API keys and the snake_case SDK
 // outbox-payload keys (event_type, spec_version, correlation_id, causation_id,
-// deduplication_id, message_group, client_id). Mirrors the serde aliases on the
+// deduplication_id, message_group, client_id, schema_id). Mirrors the serde aliases on the
 // Rust BatchEventItem so the platform ingests whatever a deployed outbox sends.
 func (b *BatchEventItem) UnmarshalJSON(data []byte) error {
 	var r struct {
@@ -305,6 +321,9 @@ func (b *BatchEventItem) UnmarshalJSON(data []byte) error {
 		CausationIDAlt     *string           `json:"causation_id"`
 		ContextData        []ContextEntryDTO `json:"contextData"`
 		ContextDataAlt     []ContextEntryDTO `json:"context_data"`
+		Receipt            *ReceiptRequest   `json:"receipt"`
+		SchemaID           *string           `json:"schemaId"`
+		SchemaIDAlt        *string           `json:"schema_id"`
 	}
 	if err := json.Unmarshal(data, &r); err != nil {
 		return err
@@ -325,6 +344,8 @@ func (b *BatchEventItem) UnmarshalJSON(data []byte) error {
 	if b.Context == nil {
 		b.Context = r.ContextDataAlt
 	}
+	b.Receipt = r.Receipt
+	b.SchemaID = coalescePtr(r.SchemaID, r.SchemaIDAlt)
 	return nil
 }
 
@@ -360,7 +381,8 @@ type BatchResultItem struct {
 // list, 1:1 with Rust BatchResponse {results:[…]} and the contract the outbox
 // dispatcher (fc-outbox/http_dispatcher.rs) requires on a 2xx.
 type BatchResponse struct {
-	Results []BatchResultItem `json:"results"`
+	Results  []BatchResultItem `json:"results"`
+	Warnings []string          `json:"warnings,omitempty" doc:"One per deprecated event type in the batch: its sunset and what to migrate to"`
 }
 
 // EventFilterOption is one {value,label} pair for the SPA's cascading
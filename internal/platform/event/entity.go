@@ -21,6 +21,14 @@ type ContextEntry struct {
 	Value string `json:"value"`
 }
 
+// Receipt is a producer's request to be told when each of an event's
+// dispatch jobs reaches a terminal status: by a webhook to CallbackURL, by
+// an event of type EventType, or both. See package receipt.
+type Receipt struct {
+	CallbackURL *string `json:"callbackUrl,omitempty"`
+	EventType   *string `json:"eventType,omitempty"`
+}
+
 // Event is the CloudEvents-shaped envelope. Maps to msg_events on the
 // write side; reads come from the denormalised msg_events_read.
 //
@@ -46,6 +54,17 @@ type Event struct {
 	CausationID     *string         `json:"causationId,omitempty"`
 	CreatedAt       time.Time       `json:"createdAt"`
 
+	// Receipt is write-side only, like Context: msg_events_read doesn't
+	// carry it, so it is nil on events read back through the API.
+	Receipt *Receipt `json:"receipt,omitempty"`
+	// SchemaID is the schema registry id the data was produced with,
+	// resolved at ingest (package schemaregistry). Write-side only.
+	SchemaID *string `json:"schemaId,omitempty"`
+	// ReplaySubscriptionID aims an event replayed from the archive at one
+	// subscription: fan-out matches it against that subscription only
+	// (package archive). Write-side only.
+	ReplaySubscriptionID *string `json:"replaySubscriptionId,omitempty"`
+
 	// Read-projection fields (msg_events_read). Empty/zero on the write
 	// side; populated by the read queries.
 	Application *string    `json:"application,omitempty"`
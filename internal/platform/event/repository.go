@@ -10,6 +10,7 @@ import (
 	"github.com/jackc/pgx/v5"
 	"github.com/jackc/pgx/v5/pgxpool"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/payloadcrypt"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
 )
 
@@ -17,11 +18,19 @@ import (
 // tables. Writes happen via the UoW sink (platformsink.Sink); this
 // repository exposes batch-ingest (from consumer apps via SDK outbox)
 // and the read API.
-type Repository struct{ pool *pgxpool.Pool }
+type Repository struct {
+	pool   *pgxpool.Pool
+	cipher *payloadcrypt.Cipher
+}
 
 // NewRepository wires a repo.
 func NewRepository(pool *pgxpool.Pool) *Repository { return &Repository{pool: pool} }
 
+// SetPayloadCipher enables encrypted-at-rest event data: InsertBatch seals
+// `data` for opted-in clients and every read opens it. Sealed rows stay
+// readable only while a cipher is set, so wiring sets it before serving.
+func (r *Repository) SetPayloadCipher(c *payloadcrypt.Cipher) { r.cipher = c }
+
 // InsertBatch writes a batch of events to msg_events. Used by the
 // POST /api/events/batch endpoint that consumer apps' outbox processors
 // send to. Idempotent via deduplication_id.
@@ -39,7 +48,17 @@ func (r *Repository) InsertBatch(ctx context.Context, events []Event) (int, erro
 		if t.IsZero() {
 			t = e.CreatedAt
 		}
-		// Column set matches the corrected platformsink.Sink shape.
+		data, err := r.cipher.Seal(ctx, e.ClientID, rawJSON(e.Data))
+		if err != nil {
+			return 0, fmt.Errorf("seal data: %w", err)
+		}
+		var receiptURL, receiptType *string
+		if e.Receipt != nil {
+			receiptURL, receiptType = e.Receipt.CallbackURL, e.Receipt.EventType
+		}
+		// Column set matches the corrected platformsink.Sink shape, plus
+		// the receipt, schema id and replay target columns only ingested
+		// events carry.
 		// No ON CONFLICT — dedup duplicates bubble as tx failures
 		// (matches Rust; the unique index is composite on
 		// (deduplication_id, created_at), which we can't always infer
@@ -48,12 +67,15 @@ func (r *Repository) InsertBatch(ctx context.Context, events []Event) (int, erro
 			`INSERT INTO msg_events
 			     (id, spec_version, type, source, subject, time, data,
 			      correlation_id, causation_id, deduplication_id, message_group,
-			      client_id, context_data, created_at)
-			 VALUES ($1, $2, $3, $4, $5, $6, $7::jsonb, $8, $9, $10, $11, $12, $13::jsonb, $14)`,
+			      client_id, context_data, created_at,
+			      receipt_callback_url, receipt_event_type, schema_id,
+			      replay_subscription_id)
+			 VALUES ($1, $2, $3, $4, $5, $6, $7::jsonb, $8, $9, $10, $11, $12, $13::jsonb, $14, $15, $16, $17, $18)`,
 			e.ID, e.SpecVersion, e.Type, e.Source, e.Subject,
-			t, rawJSON(e.Data),
+			t, data,
 			e.CorrelationID, e.CausationID, e.DeduplicationID, e.MessageGroup,
-			e.ClientID, ctxJSON, e.CreatedAt)
+			e.ClientID, ctxJSON, e.CreatedAt,
+			receiptURL, receiptType, e.SchemaID, e.ReplaySubscriptionID)
 	}
 	br := r.pool.SendBatch(ctx, batch)
 	defer br.Close()
@@ -70,6 +92,25 @@ func (r *Repository) InsertBatch(ctx context.Context, events []Event) (int, erro
 	return inserted, nil
 }
 
+// ReceiptFor returns the receipt event id asked for, or nil when it asked
+// for none or is gone. Reads msg_events: the read table doesn't carry it.
+func (r *Repository) ReceiptFor(ctx context.Context, id string) (*Receipt, error) {
+	var rc Receipt
+	err := r.pool.QueryRow(ctx,
+		`SELECT receipt_callback_url, receipt_event_type FROM msg_events WHERE id = $1 LIMIT 1`, id).
+		Scan(&rc.CallbackURL, &rc.EventType)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	if rc.CallbackURL == nil && rc.EventType == nil {
+		return nil, nil
+	}
+	return &rc, nil
+}
+
 // FindByID loads an event from the read table. `context` isn't denormalised
 // into msg_events_read (only msg_events carries it) — Context comes back
 // as an empty slice. Use the dedicated raw endpoint if you need it.
@@ -117,9 +158,9 @@ type FilterParams struct {
 	AccessibleClientIDs *[]string
 }
 
-// FindWithFilters returns events from the read table matching non-nil
-// filters, ordered most-recent first.
-func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]Event, error) {
+// filter builds the WHERE clause shared by FindWithFilters and
+// FindWithCursor.
+func (p FilterParams) filter() *repocommon.Filter {
 	var f repocommon.Filter
 	f.EqPtr("type", p.Type)
 	f.Any("type", p.Types)
@@ -141,11 +182,21 @@ func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]Eve
 	if p.Until != nil {
 		f.Clause("created_at <= $%d", *p.Until)
 	}
-	q := `SELECT id, spec_version, type, source, subject, time, data,
+	return &f
+}
+
+const readSelect = `SELECT id, spec_version, type, source, subject, time, data,
 		     deduplication_id, client_id, message_group, correlation_id,
 		     causation_id, created_at, application, subdomain, aggregate,
 		     projected_at
-		  FROM msg_events_read` + f.Where() + " ORDER BY created_at DESC"
+		  FROM msg_events_read`
+
+// FindWithFilters returns events from the read table matching non-nil
+// filters, ordered most-recent first (id breaks created_at ties so
+// offset pages are stable).
+func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]Event, error) {
+	f := p.filter()
+	q := readSelect + f.Where() + " ORDER BY created_at DESC, id DESC"
 	limit := p.Limit
 	if limit <= 0 || limit > 1000 {
 		limit = 100
@@ -154,7 +205,29 @@ func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]Eve
 	if p.Offset > 0 {
 		q += fmt.Sprintf(" OFFSET $%d", f.Arg(p.Offset))
 	}
-	rows, err := r.pool.Query(ctx, q, f.Args()...)
+	return r.queryRead(ctx, q, f.Args())
+}
+
+// FindWithCursor is the keyset twin of FindWithFilters: events ordered
+// by id DESC — TSIDs sort by creation time — starting strictly after
+// afterID ("" for the first page). Offset is ignored. Limit should
+// already include the +1 over-fetch apicommon.CursorPage trims. The
+// (id, created_at) primary key serves the ordering on every partition.
+func (r *Repository) FindWithCursor(ctx context.Context, p FilterParams, afterID string) ([]Event, error) {
+	f := p.filter()
+	if afterID != "" {
+		f.Clause("id < $%d", afterID)
+	}
+	limit := p.Limit
+	if limit <= 0 || limit > 1001 {
+		limit = 101
+	}
+	q := readSelect + f.Where() + fmt.Sprintf(" ORDER BY id DESC LIMIT $%d", f.Arg(limit))
+	return r.queryRead(ctx, q, f.Args())
+}
+
+func (r *Repository) queryRead(ctx context.Context, q string, args []any) ([]Event, error) {
+	rows, err := r.pool.Query(ctx, q, args...)
 	if err != nil {
 		return nil, err
 	}
@@ -165,6 +238,9 @@ func (r *Repository) FindWithFilters(ctx context.Context, p FilterParams) ([]Eve
 		if err != nil {
 			return nil, err
 		}
+		if err := r.openData(ctx, e); err != nil {
+			return nil, err
+		}
 		out = append(out, *e)
 	}
 	return out, rows.Err()
@@ -195,11 +271,81 @@ func (r *Repository) FindRecentRaw(ctx context.Context, limit int) ([]Event, err
 		if err != nil {
 			return nil, err
 		}
+		if err := r.openData(ctx, e); err != nil {
+			return nil, err
+		}
+		out = append(out, *e)
+	}
+	return out, rows.Err()
+}
+
+// FeedParams selects a page of the federation feed: events of Types
+// positioned after (AfterTime, AfterID) in (created_at, id) order and
+// created at least Settle ago. Empty Types selects every type, which is
+// how the event archive reads.
+type FeedParams struct {
+	Types     []string
+	AfterTime time.Time
+	AfterID   string
+	Settle    time.Duration
+	Limit     int
+}
+
+// FindFeed returns the next page of the federation feed (or the event
+// archive) from the write-side msg_events, including context_data. Settle holds back rows
+// whose creating transaction may still be overtaken by an earlier
+// created_at committing late, so a cursor never skips past one.
+func (r *Repository) FindFeed(ctx context.Context, p FeedParams) ([]Event, error) {
+	rows, err := r.pool.Query(ctx,
+		`SELECT id, spec_version, type, source, subject, time, data,
+		        deduplication_id, client_id, message_group, correlation_id,
+		        causation_id, context_data, created_at
+		   FROM msg_events
+		  WHERE (COALESCE(cardinality($1::text[]), 0) = 0 OR type = ANY($1))
+		    AND (created_at, id) > ($2, $3)
+		    AND created_at <= NOW() - make_interval(secs => $4)
+		  ORDER BY created_at, id
+		  LIMIT $5`, p.Types, p.AfterTime, p.AfterID, p.Settle.Seconds(), p.Limit)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var out []Event
+	for rows.Next() {
+		e, err := scanRawRow(rows)
+		if err != nil {
+			return nil, err
+		}
+		if err := r.openData(ctx, e); err != nil {
+			return nil, err
+		}
 		out = append(out, *e)
 	}
 	return out, rows.Err()
 }
 
+// ExistingIDs returns which of ids are already in msg_events. Federation
+// ingest keeps the peer's event ids and skips the ones it already has.
+func (r *Repository) ExistingIDs(ctx context.Context, ids []string) (map[string]bool, error) {
+	out := make(map[string]bool, len(ids))
+	if len(ids) == 0 {
+		return out, nil
+	}
+	rows, err := r.pool.Query(ctx, `SELECT id FROM msg_events WHERE id = ANY($1)`, ids)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	for rows.Next() {
+		var id string
+		if err := rows.Scan(&id); err != nil {
+			return nil, err
+		}
+		out[id] = true
+	}
+	return out, rows.Err()
+}
+
 // scanRawRow scans a write-side msg_events row, including context_data.
 func scanRawRow(rows pgx.Rows) (*Event, error) {
 	var e Event
@@ -281,9 +427,26 @@ func (r *Repository) fetchOne(ctx context.Context, sql string, args ...any) (*Ev
 		}
 		return nil, err
 	}
+	if err := r.openData(ctx, e); err != nil {
+		return nil, err
+	}
 	return e, rows.Err()
 }
 
+// openData decrypts a sealed `data` column in place. Plaintext rows pass
+// through untouched.
+func (r *Repository) openData(ctx context.Context, e *Event) error {
+	if len(e.Data) == 0 {
+		return nil
+	}
+	data, err := r.cipher.Open(ctx, e.ClientID, e.Data)
+	if err != nil {
+		return fmt.Errorf("event %s: %w", e.ID, err)
+	}
+	e.Data = json.RawMessage(data)
+	return nil
+}
+
 func scanRow(rows pgx.Rows) (*Event, error) {
 	var e Event
 	var dataBytes []byte
@@ -312,7 +475,7 @@ func scanRow(rows pgx.Rows) (*Event, error) {
 	return &e, nil
 }
 
-func rawJSON(rm json.RawMessage) any {
+func rawJSON(rm json.RawMessage) []byte {
 	if len(rm) == 0 {
 		return []byte("{}")
 	}
@@ -79,3 +79,42 @@ func TestFindWithFilters_TenantScoping(t *testing.T) {
 	require.NoError(t, err)
 	assert.ElementsMatch(t, []string{"evtscopetest1"}, ids(rows))
 }
+
+// TestFindWithCursor_PagesStablyByID walks the keyset pages and checks
+// they cover every row exactly once, newest TSID first, even when a row
+// lands between pages — the failure mode offset paging has.
+func TestFindWithCursor_PagesStablyByID(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+	repo := event.NewRepository(pool)
+
+	const typ = "cursor.test.event"
+	seed := func(id string) {
+		t.Helper()
+		_, err := pool.Exec(ctx,
+			`INSERT INTO msg_events_read (id, type, source, time, created_at)
+			 VALUES ($1, $2, 'test://cursor', NOW(), $3)`,
+			id, typ, time.Now().UTC())
+		require.NoError(t, err)
+	}
+	for _, id := range []string{"evtcursortst1", "evtcursortst2", "evtcursortst3", "evtcursortst4"} {
+		seed(id)
+	}
+	f := event.FilterParams{Types: []string{typ}, Limit: 2}
+
+	first, err := repo.FindWithCursor(ctx, f, "")
+	require.NoError(t, err)
+	require.Len(t, first, 2)
+	assert.Equal(t, []string{"evtcursortst4", "evtcursortst3"}, []string{first[0].ID, first[1].ID})
+
+	seed("evtcursortst5") // newer than the cursor: must not shift the next page
+
+	second, err := repo.FindWithCursor(ctx, f, first[1].ID)
+	require.NoError(t, err)
+	require.Len(t, second, 2)
+	assert.Equal(t, []string{"evtcursortst2", "evtcursortst1"}, []string{second[0].ID, second[1].ID})
+
+	rest, err := repo.FindWithCursor(ctx, f, second[1].ID)
+	require.NoError(t, err)
+	assert.Empty(t, rest)
+}
@@ -6,7 +6,9 @@ package api
 
 import (
 	"context"
+	"errors"
 	"net/http"
+	"strings"
 
 	"github.com/danielgtaylor/huma/v2"
 
@@ -15,7 +17,10 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/idempotency"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/schemaregistry"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
@@ -25,6 +30,10 @@ import (
 type State struct {
 	Repo *eventtype.Repository
 	UoW  *usecasepgx.UnitOfWork
+	Idem *idempotency.Store // optional; nil ignores Idempotency-Key
+	// Registry checks a subject exists before an event type is bound to
+	// it. Optional: nil refuses bindings.
+	Registry *schemaregistry.Client
 }
 
 const tag = "event-types"
@@ -38,7 +47,11 @@ func Register(api huma.API, s *State) {
 	apiroute.Post(g, "createEventType", "/api/event-types", "Create an event type", http.StatusCreated, s.create)
 	apiroute.Get(g, "getEventType", "/api/event-types/{id}", "Get an event type by id", s.getByID)
 	apiroute.Get(g, "getEventTypeByCode", "/api/event-types/by-code/{code}", "Get an event type by code", s.getByCode)
+	apiroute.Put(g, "upsertEventType", "/api/event-types/by-code/{code}", "Create or update an event type by code", http.StatusOK, s.upsert)
 	apiroute.Put(g, "updateEventType", "/api/event-types/{id}", "Update an event type", http.StatusNoContent, s.update)
+	apiroute.Put(g, "updateEventTypeDataPolicy", "/api/event-types/{id}/data-policy", "Replace an event type's retention and redaction policy", http.StatusNoContent, s.updateDataPolicy)
+	apiroute.Put(g, "updateEventTypeRegistryBinding", "/api/event-types/{id}/schema-registry", "Bind an event type to a schema registry subject", http.StatusNoContent, s.updateRegistryBinding)
+	apiroute.Put(g, "updateEventTypeLifecycle", "/api/event-types/{id}/lifecycle", "Deprecate an event type, with an optional sunset and migration hint, or undeprecate it", http.StatusNoContent, s.updateLifecycle)
 	apiroute.Delete(g, "deleteEventType", "/api/event-types/{id}", "Archive an event type", http.StatusNoContent, s.delete)
 	apiroute.Post(g, "addEventTypeSchema", "/api/event-types/{id}/schemas", "Add a schema version to an event type (Go-historical alias)", http.StatusOK, s.addSchema)
 	// /versions is the Rust-canonical path. Same handler; both paths
@@ -85,7 +98,7 @@ type getByIDInput struct {
 	ID string `path:"id" doc:"Event type id (TSID)"`
 }
 
-func (s *State) getByID(ctx context.Context, in *getByIDInput) (*apicommon.Out[EventTypeResponse], error) {
+func (s *State) getByID(ctx context.Context, in *getByIDInput) (*apicommon.TaggedOut[EventTypeResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanReadEventTypes(ac); err != nil {
 		return nil, err
@@ -100,14 +113,14 @@ func (s *State) getByID(ctx context.Context, in *getByIDInput) (*apicommon.Out[E
 	if et.ClientID != nil && !ac.CanAccessClient(*et.ClientID) {
 		return nil, httperror.Forbidden("No access to this event type")
 	}
-	return &apicommon.Out[EventTypeResponse]{Body: fromEntity(et)}, nil
+	return &apicommon.TaggedOut[EventTypeResponse]{ETag: etag.Of(et.UpdatedAt), Body: fromEntity(et)}, nil
 }
 
 type getByCodeInput struct {
 	Code string `path:"code" doc:"Event type code (e.g. platform:iam:user:created)"`
 }
 
-func (s *State) getByCode(ctx context.Context, in *getByCodeInput) (*apicommon.Out[EventTypeResponse], error) {
+func (s *State) getByCode(ctx context.Context, in *getByCodeInput) (*apicommon.TaggedOut[EventTypeResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanReadEventTypes(ac); err != nil {
 		return nil, err
@@ -122,27 +135,95 @@ func (s *State) getByCode(ctx context.Context, in *getByCodeInput) (*apicommon.O
 	if et.ClientID != nil && !ac.CanAccessClient(*et.ClientID) {
 		return nil, httperror.Forbidden("No access to this event type")
 	}
-	return &apicommon.Out[EventTypeResponse]{Body: fromEntity(et)}, nil
+	return &apicommon.TaggedOut[EventTypeResponse]{ETag: etag.Of(et.UpdatedAt), Body: fromEntity(et)}, nil
 }
 
-func (s *State) create(ctx context.Context, in *apicommon.In[CreateEventTypeRequest]) (*apicommon.Out[apicommon.CreatedResponse], error) {
+func (s *State) create(ctx context.Context, in *apicommon.IdempotentIn[CreateEventTypeRequest]) (*apicommon.Out[apicommon.CreatedResponse], error) {
 	// Coarse permission at the controller; the use case enforces per-client
 	// resource access (you may only bind an event type to a client you can
 	// access; platform-wide requires anchor).
 	if err := auth.CanWriteEventTypes(auth.FromContext(ctx)); err != nil {
 		return nil, err
 	}
-	ec := auth.NewExecutionContext(ctx)
-	event, err := usecaseop.Run(ctx, s.UoW, operations.CreateEventType(s.Repo), in.Body.toCommand(), ec)
+	req := idempotency.Request{Key: in.IdempotencyKey, Scope: "createEventType", Body: in.Body}
+	created, err := idempotency.Do(ctx, s.Idem, req, func(ctx context.Context) (apicommon.CreatedResponse, error) {
+		ec := auth.NewExecutionContext(ctx)
+		event, err := usecaseop.Run(ctx, s.UoW, operations.CreateEventType(s.Repo), in.Body.toCommand(), ec)
+		if err != nil {
+			return apicommon.CreatedResponse{}, err
+		}
+		return apicommon.CreatedResponse{ID: event.EventTypeID}, nil
+	})
+	if err != nil {
+		return nil, err
+	}
+	return &apicommon.Out[apicommon.CreatedResponse]{Body: created}, nil
+}
+
+type upsertInput struct {
+	Code        string `path:"code" doc:"Event type code (e.g. platform:iam:user:created)"`
+	ClientID    string `query:"clientId" doc:"Client scope; absent means anchor-level. Fixed at creation"`
+	IfMatch     string `header:"If-Match" doc:"Update only if the event type exists with this ETag"`
+	IfNoneMatch string `header:"If-None-Match" doc:"Send * to create only, failing if the code is taken"`
+	Body        UpsertEventTypeRequest
+}
+
+// upsert is the declarative entry point for infrastructure-as-code tools:
+// the caller names the event type by its code and states what it should
+// look like, and gets 201 if that created it or 200 if it updated it.
+func (s *State) upsert(ctx context.Context, in *upsertInput) (*apicommon.UpsertOut[EventTypeResponse], error) {
+	if err := auth.CanWriteEventTypes(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	existing, err := s.Repo.FindByCode(ctx, in.Code)
 	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_code failed", err)
+	}
+	if err := etag.CheckUpsert(in.IfMatch, in.IfNoneMatch, existing != nil); err != nil {
 		return nil, err
 	}
-	return &apicommon.Out[apicommon.CreatedResponse]{Body: apicommon.CreatedResponse{ID: event.EventTypeID}}, nil
+	clientID := apicommon.OptStr(in.ClientID)
+	ec := auth.NewExecutionContext(ctx)
+	status, id := http.StatusOK, ""
+	if existing == nil {
+		event, err := usecaseop.Run(ctx, s.UoW, operations.CreateEventType(s.Repo), in.Body.toCreateCommand(in.Code, clientID), ec)
+		if err != nil {
+			return nil, err
+		}
+		status, id = http.StatusCreated, event.EventTypeID
+	} else {
+		// Codes are unique platform-wide, so a code already owned by
+		// another scope can't be claimed by moving it.
+		if !sameClient(existing.ClientID, clientID) {
+			return nil, usecase.Conflict("CLIENT_MISMATCH",
+				"Event type '"+in.Code+"' exists under a different client scope")
+		}
+		id = existing.ID
+		if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateEventType(s.Repo), in.Body.toUpdateCommand(id, in.IfMatch), ec); err != nil {
+			return nil, err
+		}
+	}
+	et, err := s.Repo.FindByID(ctx, id)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if et == nil {
+		return nil, httperror.NotFound("EventType", id)
+	}
+	return &apicommon.UpsertOut[EventTypeResponse]{Status: status, ETag: etag.Of(et.UpdatedAt), Body: fromEntity(et)}, nil
+}
+
+func sameClient(a, b *string) bool {
+	if a == nil || b == nil {
+		return a == nil && b == nil
+	}
+	return *a == *b
 }
 
 type updateInput struct {
-	ID   string `path:"id"`
-	Body UpdateEventTypeRequest
+	ID      string `path:"id"`
+	IfMatch string `header:"If-Match" doc:"Proceed only if the event type's current ETag matches"`
+	Body    UpdateEventTypeRequest
 }
 
 func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Empty, error) {
@@ -150,18 +231,79 @@ func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Empty,
 		return nil, err
 	}
 	ec := auth.NewExecutionContext(ctx)
-	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateEventType(s.Repo), in.Body.toCommand(in.ID), ec); err != nil {
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateEventType(s.Repo), in.Body.toCommand(in.ID, in.IfMatch), ec); err != nil {
+		return nil, err
+	}
+	return &apicommon.Empty{}, nil
+}
+
+type updateDataPolicyInput struct {
+	ID   string `path:"id"`
+	Body UpdateDataPolicyRequest
+}
+
+func (s *State) updateDataPolicy(ctx context.Context, in *updateDataPolicyInput) (*apicommon.Empty, error) {
+	if err := auth.CanWriteEventTypes(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateEventTypeDataPolicy(s.Repo), in.Body.toCommand(in.ID), ec); err != nil {
+		return nil, err
+	}
+	return &apicommon.Empty{}, nil
+}
+
+type updateRegistryBindingInput struct {
+	ID   string `path:"id"`
+	Body UpdateRegistryBindingRequest
+}
+
+func (s *State) updateRegistryBinding(ctx context.Context, in *updateRegistryBindingInput) (*apicommon.Empty, error) {
+	if err := auth.CanWriteEventTypes(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	// Binding to a subject the registry doesn't have would reject every
+	// event of the type; unbinding needs no registry.
+	if subject := in.Body.Subject; subject != nil && strings.TrimSpace(*subject) != "" {
+		if s.Registry == nil {
+			return nil, httperror.BadRequest("SCHEMA_REGISTRY_DISABLED", "no schema registry is configured (FC_SCHEMA_REGISTRY_URL)")
+		}
+		if _, err := s.Registry.Latest(ctx, strings.TrimSpace(*subject)); err != nil {
+			if errors.Is(err, schemaregistry.ErrNotFound) {
+				return nil, httperror.BadRequest("REGISTRY_SUBJECT_NOT_FOUND", "schema registry has no subject "+strings.TrimSpace(*subject))
+			}
+			return nil, usecase.Internal("SCHEMA_REGISTRY", "look up registry subject failed", err)
+		}
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateEventTypeRegistryBinding(s.Repo), in.Body.toCommand(in.ID), ec); err != nil {
+		return nil, err
+	}
+	return &apicommon.Empty{}, nil
+}
+
+type updateLifecycleInput struct {
+	ID   string `path:"id"`
+	Body UpdateLifecycleRequest
+}
+
+func (s *State) updateLifecycle(ctx context.Context, in *updateLifecycleInput) (*apicommon.Empty, error) {
+	if err := auth.CanWriteEventTypes(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateEventTypeLifecycle(s.Repo), in.Body.toCommand(in.ID), ec); err != nil {
 		return nil, err
 	}
 	return &apicommon.Empty{}, nil
 }
 
-func (s *State) delete(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+func (s *State) delete(ctx context.Context, in *apicommon.IDMatchInput) (*apicommon.Empty, error) {
 	if err := auth.CanDeleteEventTypes(auth.FromContext(ctx)); err != nil {
 		return nil, err
 	}
 	ec := auth.NewExecutionContext(ctx)
-	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeleteEventType(s.Repo), operations.DeleteCommand{ID: in.ID}, ec); err != nil {
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeleteEventType(s.Repo), operations.DeleteCommand{ID: in.ID, IfMatch: in.IfMatch}, ec); err != nil {
 		return nil, err
 	}
 	return &apicommon.Empty{}, nil
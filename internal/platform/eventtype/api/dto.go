@@ -6,6 +6,7 @@ package api
 
 import (
 	"encoding/json"
+	"time"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype/operations"
@@ -39,18 +40,83 @@ type UpdateEventTypeRequest struct {
 	Description *string `json:"description,omitempty"`
 }
 
-func (r UpdateEventTypeRequest) toCommand(id string) operations.UpdateCommand {
-	return operations.UpdateCommand{ID: id, Name: r.Name, Description: r.Description}
+func (r UpdateEventTypeRequest) toCommand(id, ifMatch string) operations.UpdateCommand {
+	return operations.UpdateCommand{ID: id, Name: r.Name, Description: r.Description, IfMatch: ifMatch}
+}
+
+// UpsertEventTypeRequest is the wire body for
+// PUT /api/event-types/by-code/{code}. The schema seeds version 1.0 when
+// the call creates the event type and is ignored on update; later
+// versions go through /versions.
+type UpsertEventTypeRequest struct {
+	Name        string          `json:"name" doc:"Human-readable event type name"`
+	Description *string         `json:"description,omitempty"`
+	Schema      json.RawMessage `json:"schema,omitempty" doc:"Optional JSON Schema for the initial spec version; used only on create"`
+}
+
+func (r UpsertEventTypeRequest) toCreateCommand(code string, clientID *string) operations.CreateCommand {
+	return operations.CreateCommand{
+		Code:        code,
+		Name:        r.Name,
+		Description: r.Description,
+		ClientID:    clientID,
+		Schema:      r.Schema,
+	}
+}
+
+func (r UpsertEventTypeRequest) toUpdateCommand(id, ifMatch string) operations.UpdateCommand {
+	return operations.UpdateCommand{ID: id, Name: r.Name, Description: r.Description, IfMatch: ifMatch}
+}
+
+// UpdateDataPolicyRequest is the wire body for
+// PUT /api/event-types/{id}/data-policy. The policy is replaced wholesale.
+type UpdateDataPolicyRequest struct {
+	PayloadRetentionDays  *int     `json:"payloadRetentionDays,omitempty" doc:"Purge event data and dispatch payloads after this many days; absent keeps them until partition drop"`
+	MetadataRetentionDays *int     `json:"metadataRetentionDays,omitempty" doc:"Delete events and their dispatch jobs after this many days; absent keeps them until partition drop"`
+	RedactFields          []string `json:"redactFields,omitempty" doc:"Dotted JSON paths masked on read and debug views (arrays are walked; * matches any key)"`
+}
+
+func (r UpdateDataPolicyRequest) toCommand(id string) operations.UpdateDataPolicyCommand {
+	return operations.UpdateDataPolicyCommand{
+		ID:                    id,
+		PayloadRetentionDays:  r.PayloadRetentionDays,
+		MetadataRetentionDays: r.MetadataRetentionDays,
+		RedactFields:          r.RedactFields,
+	}
+}
+
+// UpdateRegistryBindingRequest is the wire body for
+// PUT /api/event-types/{id}/schema-registry.
+type UpdateRegistryBindingRequest struct {
+	Subject *string `json:"subject,omitempty" doc:"Schema registry subject the event type's payloads are registered under; absent or blank unbinds"`
+	Mode    string  `json:"mode,omitempty" doc:"RESOLVE (default) checks and stamps each event's schema id; VALIDATE also validates its data against the schema"`
+}
+
+func (r UpdateRegistryBindingRequest) toCommand(id string) operations.UpdateRegistryBindingCommand {
+	return operations.UpdateRegistryBindingCommand{ID: id, Subject: r.Subject, Mode: r.Mode}
+}
+
+// UpdateLifecycleRequest is the wire body for
+// PUT /api/event-types/{id}/lifecycle.
+type UpdateLifecycleRequest struct {
+	Deprecated    bool       `json:"deprecated" doc:"true deprecates the event type; false undeprecates it and clears the sunset and hint"`
+	SunsetAt      *time.Time `json:"sunsetAt,omitempty" doc:"When ingestion starts rejecting the event type's events; must be in the future. Absent: no sunset"`
+	MigrationHint *string    `json:"migrationHint,omitempty" doc:"What producers and subscribers should move to, e.g. the replacement event type; returned with every warning and rejection"`
+}
+
+func (r UpdateLifecycleRequest) toCommand(id string) operations.UpdateLifecycleCommand {
+	return operations.UpdateLifecycleCommand{ID: id, Deprecated: r.Deprecated, SunsetAt: r.SunsetAt, MigrationHint: r.MigrationHint}
 }
 
 // AddSchemaRequest is the wire body for POST /api/event-types/{id}/schemas.
 type AddSchemaRequest struct {
-	Version string          `json:"version" doc:"Schema version (typically semver)" example:"1.0"`
-	Schema  json.RawMessage `json:"schema" doc:"JSON Schema document"`
+	Version    string          `json:"version" doc:"Schema version (typically semver)" example:"1.0"`
+	Schema     json.RawMessage `json:"schema" doc:"JSON Schema document, or for PROTO {messageType, fileDescriptorSet} with the set in protobuf's JSON mapping"`
+	SchemaType string          `json:"schemaType,omitempty" doc:"JSON_SCHEMA (default) or PROTO. PROTOBUF subscriptions transcode payloads with the event type's CURRENT PROTO schema"`
 }
 
 func (r AddSchemaRequest) toCommand(id string) operations.AddSchemaCommand {
-	return operations.AddSchemaCommand{EventTypeID: id, Version: r.Version, Schema: r.Schema}
+	return operations.AddSchemaCommand{EventTypeID: id, Version: r.Version, Schema: r.Schema, SchemaType: r.SchemaType}
 }
 
 // EventTypeResponse is the wire shape for GET /api/event-types/{id}
@@ -58,28 +124,37 @@ func (r AddSchemaRequest) toCommand(id string) operations.AddSchemaCommand {
 // with explicit JSON tags so the wire format is stable independent of
 // entity-field renames.
 type EventTypeResponse struct {
-	ID           string                `json:"id"`
-	Code         string                `json:"code"`
-	Name         string                `json:"name"`
-	Application  string                `json:"application"`
-	Subdomain    string                `json:"subdomain"`
-	Aggregate    string                `json:"aggregate"`
-	EventName    string                `json:"eventName"`
-	Description  *string               `json:"description,omitempty"`
-	Status       string                `json:"status"`
-	Source       string                `json:"source"`
-	ClientID     *string               `json:"clientId,omitempty"`
-	CreatedBy    *string               `json:"createdBy,omitempty"`
-	CreatedAt    httpcompat.Time       `json:"createdAt"`
-	UpdatedAt    httpcompat.Time       `json:"updatedAt"`
-	SpecVersions []specVersionResponse `json:"specVersions"`
+	ID                    string                `json:"id"`
+	Code                  string                `json:"code"`
+	Name                  string                `json:"name"`
+	Application           string                `json:"application"`
+	Subdomain             string                `json:"subdomain"`
+	Aggregate             string                `json:"aggregate"`
+	EventName             string                `json:"eventName"`
+	Description           *string               `json:"description,omitempty"`
+	Status                string                `json:"status"`
+	Source                string                `json:"source"`
+	ClientID              *string               `json:"clientId,omitempty"`
+	CreatedBy             *string               `json:"createdBy,omitempty"`
+	CreatedAt             httpcompat.Time       `json:"createdAt"`
+	UpdatedAt             httpcompat.Time       `json:"updatedAt"`
+	PayloadRetentionDays  *int                  `json:"payloadRetentionDays,omitempty"`
+	MetadataRetentionDays *int                  `json:"metadataRetentionDays,omitempty"`
+	RedactFields          []string              `json:"redactFields"`
+	RegistrySubject       *string               `json:"registrySubject,omitempty"`
+	RegistryMode          string                `json:"registryMode"`
+	DeprecatedAt          *httpcompat.Time      `json:"deprecatedAt,omitempty"`
+	SunsetAt              *httpcompat.Time      `json:"sunsetAt,omitempty"`
+	MigrationHint         *string               `json:"migrationHint,omitempty"`
+	SpecVersions          []specVersionResponse `json:"specVersions"`
 }
 
 type specVersionResponse struct {
-	Version   string          `json:"version"`
-	Schema    json.RawMessage `json:"schema"`
-	Status    string          `json:"status"`
-	CreatedAt httpcompat.Time `json:"createdAt"`
+	Version    string          `json:"version"`
+	Schema     json.RawMessage `json:"schema"`
+	SchemaType string          `json:"schemaType"`
+	Status     string          `json:"status"`
+	CreatedAt  httpcompat.Time `json:"createdAt"`
 }
 
 func fromEntity(et *eventtype.EventType) EventTypeResponse {
@@ -98,19 +173,40 @@ func fromEntity(et *eventtype.EventType) EventTypeResponse {
 		CreatedBy:   et.CreatedBy,
 		CreatedAt:   jsontime.New(et.CreatedAt),
 		UpdatedAt:   jsontime.New(et.UpdatedAt),
+
+		PayloadRetentionDays:  et.PayloadRetentionDays,
+		MetadataRetentionDays: et.MetadataRetentionDays,
+		RedactFields:          et.RedactFields,
+		RegistrySubject:       et.RegistrySubject,
+		RegistryMode:          string(et.RegistryMode),
+		DeprecatedAt:          optTime(et.DeprecatedAt),
+		SunsetAt:              optTime(et.SunsetAt),
+		MigrationHint:         et.MigrationHint,
+	}
+	if resp.RedactFields == nil {
+		resp.RedactFields = []string{}
 	}
 	resp.SpecVersions = make([]specVersionResponse, 0, len(et.SpecVersions))
 	for _, sv := range et.SpecVersions {
 		resp.SpecVersions = append(resp.SpecVersions, specVersionResponse{
-			Version:   sv.Version,
-			Schema:    sv.SchemaContent,
-			Status:    string(sv.Status),
-			CreatedAt: jsontime.New(sv.CreatedAt),
+			Version:    sv.Version,
+			Schema:     sv.SchemaContent,
+			SchemaType: string(sv.SchemaType),
+			Status:     string(sv.Status),
+			CreatedAt:  jsontime.New(sv.CreatedAt),
 		})
 	}
 	return resp
 }
 
+func optTime(t *time.Time) *httpcompat.Time {
+	if t == nil {
+		return nil
+	}
+	v := jsontime.New(*t)
+	return &v
+}
+
 // EventTypeListResponse is the wire shape for GET /api/event-types.
 type EventTypeListResponse struct {
 	Items []EventTypeResponse `json:"items"`
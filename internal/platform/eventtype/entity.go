@@ -161,6 +161,20 @@ type EventType struct {
 	CreatedBy    *string       `json:"createdBy,omitempty"`
 	CreatedAt    time.Time     `json:"createdAt"`
 	UpdatedAt    time.Time     `json:"updatedAt"`
+
+	// Data policy. See SetDataPolicy.
+	PayloadRetentionDays  *int     `json:"payloadRetentionDays,omitempty"`
+	MetadataRetentionDays *int     `json:"metadataRetentionDays,omitempty"`
+	RedactFields          []string `json:"redactFields"`
+
+	// Schema registry binding. See SetRegistryBinding.
+	RegistrySubject *string      `json:"registrySubject,omitempty"`
+	RegistryMode    RegistryMode `json:"registryMode"`
+
+	// Lifecycle. See Deprecate.
+	DeprecatedAt  *time.Time `json:"deprecatedAt,omitempty"`
+	SunsetAt      *time.Time `json:"sunsetAt,omitempty"`
+	MigrationHint *string    `json:"migrationHint,omitempty"`
 }
 
 // IDStr returns the aggregate ID. Method exists because usecase.HasID
@@ -197,6 +211,8 @@ func New(code, name string) (*EventType, error) {
 		EventName:    parts[3],
 		CreatedAt:    now,
 		UpdatedAt:    now,
+		RedactFields: []string{},
+		RegistryMode: RegistryModeResolve,
 	}, nil
 }
 
@@ -211,3 +227,135 @@ func (e *EventType) AddSchemaVersion(sv SpecVersion) {
 	e.SpecVersions = append(e.SpecVersions, sv)
 	e.UpdatedAt = time.Now().UTC()
 }
+
+// SetDataPolicy replaces the retention and redaction policy.
+//
+// payloadDays purges event data and dispatch job payloads older than the
+// window; metadataDays deletes the rows outright. nil leaves the data to
+// the partition manager's global retention. fields are dotted JSON paths
+// (e.g. "customer.email") masked wherever payloads are shown to operators;
+// arrays are walked transparently and "*" matches any key.
+func (e *EventType) SetDataPolicy(payloadDays, metadataDays *int, fields []string) error {
+	if payloadDays != nil && *payloadDays < 1 {
+		return errors.New("payload retention must be at least 1 day")
+	}
+	if metadataDays != nil && *metadataDays < 1 {
+		return errors.New("metadata retention must be at least 1 day")
+	}
+	if payloadDays != nil && metadataDays != nil && *payloadDays > *metadataDays {
+		return errors.New("payload retention cannot exceed metadata retention")
+	}
+	clean := make([]string, 0, len(fields))
+	seen := map[string]bool{}
+	for _, f := range fields {
+		f = strings.TrimSpace(f)
+		if f == "" || strings.HasPrefix(f, ".") || strings.HasSuffix(f, ".") || strings.Contains(f, "..") {
+			return errors.New("redact field '" + f + "' must be a dotted JSON path")
+		}
+		if !seen[f] {
+			seen[f] = true
+			clean = append(clean, f)
+		}
+	}
+	e.PayloadRetentionDays = payloadDays
+	e.MetadataRetentionDays = metadataDays
+	e.RedactFields = clean
+	e.UpdatedAt = time.Now().UTC()
+	return nil
+}
+
+// RegistryMode is what ingestion does with a bound event type's events.
+type RegistryMode string
+
+const (
+	// RegistryModeResolve checks a supplied schema id belongs to the
+	// subject and stamps the subject's latest id when none is supplied.
+	RegistryModeResolve RegistryMode = "RESOLVE"
+	// RegistryModeValidate also validates the payload against the schema.
+	RegistryModeValidate RegistryMode = "VALIDATE"
+)
+
+// ParseRegistryMode is the lenient parser. Unknown → RESOLVE.
+func ParseRegistryMode(s string) RegistryMode {
+	if s == string(RegistryModeValidate) {
+		return RegistryModeValidate
+	}
+	return RegistryModeResolve
+}
+
+// SetRegistryBinding binds the event type to a schema registry subject, or
+// unbinds it when subject is nil or blank. mode must be RESOLVE or
+// VALIDATE; empty means RESOLVE.
+func (e *EventType) SetRegistryBinding(subject *string, mode string) error {
+	switch mode {
+	case "":
+		mode = string(RegistryModeResolve)
+	case string(RegistryModeResolve), string(RegistryModeValidate):
+	default:
+		return errors.New("registry mode must be RESOLVE or VALIDATE")
+	}
+	var clean *string
+	if subject != nil {
+		if s := strings.TrimSpace(*subject); s != "" {
+			if len(s) > 255 {
+				return errors.New("registry subject must be at most 255 characters")
+			}
+			clean = &s
+		}
+	}
+	e.RegistrySubject = clean
+	e.RegistryMode = RegistryMode(mode)
+	e.UpdatedAt = time.Now().UTC()
+	return nil
+}
+
+// Deprecate marks the event type deprecated: ingestion still accepts its
+// events but warns the producer, and no new subscription may bind it. From
+// sunset on (when set) ingestion rejects them. hint tells producers what to
+// move to — typically the replacement event type. Re-deprecating keeps the
+// original deprecation time.
+func (e *EventType) Deprecate(sunset *time.Time, hint *string) error {
+	if e.Status == StatusArchived {
+		return errors.New("an archived event type cannot be deprecated")
+	}
+	now := time.Now().UTC()
+	if sunset != nil {
+		s := sunset.UTC()
+		if !s.After(now) {
+			return errors.New("sunset must be in the future")
+		}
+		sunset = &s
+	}
+	var clean *string
+	if hint != nil {
+		if h := strings.TrimSpace(*hint); h != "" {
+			if len(h) > 1000 {
+				return errors.New("migration hint must be at most 1000 characters")
+			}
+			clean = &h
+		}
+	}
+	if e.DeprecatedAt == nil {
+		e.DeprecatedAt = &now
+	}
+	e.SunsetAt = sunset
+	e.MigrationHint = clean
+	e.UpdatedAt = now
+	return nil
+}
+
+// Undeprecate clears the deprecation, sunset and hint.
+func (e *EventType) Undeprecate() {
+	e.DeprecatedAt = nil
+	e.SunsetAt = nil
+	e.MigrationHint = nil
+	e.UpdatedAt = time.Now().UTC()
+}
+
+// IsDeprecated reports whether the event type has been deprecated.
+func (e *EventType) IsDeprecated() bool { return e.DeprecatedAt != nil }
+
+// IsSunset reports whether the event type's sunset has passed at now.
+func (e *EventType) IsSunset(now time.Time) bool {
+	return e.SunsetAt != nil && !now.Before(*e.SunsetAt)
+}
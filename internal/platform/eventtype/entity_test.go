@@ -67,6 +67,72 @@ func TestArchiveFlipsStatus(t *testing.T) {
 	assert.True(t, et.UpdatedAt.After(before))
 }
 
+func TestSetDataPolicy(t *testing.T) {
+	et, _ := eventtype.New("a:b:c:d", "Name")
+	assert.Empty(t, et.RedactFields)
+
+	days := func(n int) *int { return &n }
+	require.NoError(t, et.SetDataPolicy(days(30), days(365), []string{" customer.email ", "customer.email", "items.*.card"}))
+	assert.Equal(t, 30, *et.PayloadRetentionDays)
+	assert.Equal(t, 365, *et.MetadataRetentionDays)
+	assert.Equal(t, []string{"customer.email", "items.*.card"}, et.RedactFields)
+
+	assert.Error(t, et.SetDataPolicy(days(0), nil, nil))
+	assert.Error(t, et.SetDataPolicy(days(400), days(365), nil))
+	assert.Error(t, et.SetDataPolicy(nil, nil, []string{"customer..email"}))
+	assert.Equal(t, 30, *et.PayloadRetentionDays, "a rejected policy leaves the old one in place")
+}
+
+func TestSetRegistryBinding(t *testing.T) {
+	et, _ := eventtype.New("a:b:c:d", "Name")
+	assert.Nil(t, et.RegistrySubject)
+	assert.Equal(t, eventtype.RegistryModeResolve, et.RegistryMode)
+
+	subject := " orders-value "
+	require.NoError(t, et.SetRegistryBinding(&subject, "VALIDATE"))
+	require.NotNil(t, et.RegistrySubject)
+	assert.Equal(t, "orders-value", *et.RegistrySubject)
+	assert.Equal(t, eventtype.RegistryModeValidate, et.RegistryMode)
+
+	assert.Error(t, et.SetRegistryBinding(&subject, "STRICT"))
+	assert.Equal(t, eventtype.RegistryModeValidate, et.RegistryMode, "a rejected binding leaves the old one in place")
+
+	blank := "  "
+	require.NoError(t, et.SetRegistryBinding(&blank, ""))
+	assert.Nil(t, et.RegistrySubject)
+	assert.Equal(t, eventtype.RegistryModeResolve, et.RegistryMode)
+}
+
+func TestDeprecate(t *testing.T) {
+	et, _ := eventtype.New("a:b:c:d", "Name")
+	assert.False(t, et.IsDeprecated())
+
+	sunset := time.Now().Add(time.Hour)
+	hint := " use a:b:c:e "
+	require.NoError(t, et.Deprecate(&sunset, &hint))
+	require.True(t, et.IsDeprecated())
+	since := *et.DeprecatedAt
+	require.NotNil(t, et.MigrationHint)
+	assert.Equal(t, "use a:b:c:e", *et.MigrationHint)
+	assert.False(t, et.IsSunset(time.Now()))
+	assert.True(t, et.IsSunset(sunset))
+
+	past := time.Now().Add(-time.Minute)
+	assert.Error(t, et.Deprecate(&past, nil))
+	assert.Equal(t, sunset.UTC(), *et.SunsetAt, "a rejected sunset leaves the old one in place")
+
+	require.NoError(t, et.Deprecate(nil, nil))
+	assert.Equal(t, since, *et.DeprecatedAt, "re-deprecating keeps the original deprecation time")
+	assert.Nil(t, et.SunsetAt)
+	assert.Nil(t, et.MigrationHint)
+
+	et.Undeprecate()
+	assert.False(t, et.IsDeprecated())
+
+	et.Archive()
+	assert.Error(t, et.Deprecate(nil, nil))
+}
+
 func TestStatusRoundTripWithFallback(t *testing.T) {
 	assert.Equal(t, eventtype.StatusCurrent, eventtype.ParseStatus("CURRENT"))
 	assert.Equal(t, eventtype.StatusArchived, eventtype.ParseStatus("ARCHIVED"))
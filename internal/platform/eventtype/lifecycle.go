@@ -0,0 +1,94 @@
+package eventtype
+
+import (
+	"context"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/snapshot"
+)
+
+// Lifecycle is a deprecated event type's deprecation, sunset and
+// migration hint. See EventType.Deprecate.
+type Lifecycle struct {
+	DeprecatedAt  time.Time
+	SunsetAt      *time.Time
+	MigrationHint *string
+}
+
+// IsSunset reports whether the sunset has passed at now.
+func (l Lifecycle) IsSunset(now time.Time) bool {
+	return l.SunsetAt != nil && !now.Before(*l.SunsetAt)
+}
+
+// LifecycleCache is a cached code → Lifecycle lookup over the deprecated
+// event types, for the ingestion and subscription paths. A nil
+// *LifecycleCache reports every event type current, so callers can hold
+// one unconditionally.
+type LifecycleCache struct {
+	byCode *snapshot.Value[map[string]Lifecycle]
+
+	mu    sync.Mutex
+	onUse func(LifecycleUse)
+}
+
+// LifecycleUse is a principal publishing an event of a deprecated type;
+// Sunset is set when the event was rejected for being past its sunset.
+type LifecycleUse struct {
+	PrincipalID string
+	Code        string
+	Lifecycle   Lifecycle
+	Sunset      bool
+}
+
+// NewLifecycleCache wires a cache over load (typically
+// Repository.Lifecycles). ttl <= 0 means 30s.
+func NewLifecycleCache(load func(context.Context) (map[string]Lifecycle, error), ttl time.Duration) *LifecycleCache {
+	if ttl <= 0 {
+		ttl = 30 * time.Second
+	}
+	return &LifecycleCache{byCode: snapshot.New(load, ttl)}
+}
+
+// Lookup returns code's lifecycle and whether it is deprecated. A failed
+// refresh keeps serving the previous set; with nothing cached the error is
+// returned.
+func (c *LifecycleCache) Lookup(ctx context.Context, code string) (Lifecycle, bool, error) {
+	if c == nil {
+		return Lifecycle{}, false, nil
+	}
+	byCode, err := c.byCode.Get(ctx)
+	if err != nil {
+		return Lifecycle{}, false, err
+	}
+	l, ok := byCode[code]
+	return l, ok, nil
+}
+
+// OnUse registers fn to be called on each ReportUse, e.g. to raise a
+// warning. fn must not block.
+func (c *LifecycleCache) OnUse(fn func(LifecycleUse)) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.onUse = fn
+}
+
+// ReportUse hands u to the OnUse hook, if one is registered.
+func (c *LifecycleCache) ReportUse(u LifecycleUse) {
+	if c == nil {
+		return
+	}
+	c.mu.Lock()
+	fn := c.onUse
+	c.mu.Unlock()
+	if fn != nil {
+		fn(u)
+	}
+}
+
+// Invalidate drops the cached set; the next Lookup reloads it.
+func (c *LifecycleCache) Invalidate() {
+	if c != nil {
+		c.byCode.Invalidate()
+	}
+}
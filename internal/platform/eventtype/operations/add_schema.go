@@ -17,6 +17,7 @@ type AddSchemaCommand struct {
 	EventTypeID string          `json:"eventTypeId"`
 	Version     string          `json:"version"`
 	Schema      json.RawMessage `json:"schema"`
+	SchemaType  string          `json:"schemaType,omitempty"`
 }
 
 // AddSchema appends a new schema version to an event type and atomically
@@ -35,6 +36,11 @@ func AddSchema(repo *eventtype.Repository) usecaseop.Operation[AddSchemaCommand,
 			if len(cmd.Schema) == 0 {
 				return usecase.Validation("SCHEMA_REQUIRED", "schema payload is required")
 			}
+			if eventtype.ParseSchemaType(cmd.SchemaType) == eventtype.SchemaProto {
+				if _, err := eventtype.CompileProtoSchema(cmd.Schema); err != nil {
+					return usecase.Validation("INVALID_SCHEMA", err.Error())
+				}
+			}
 			return nil
 		},
 		// Per-resource authz runs post-load in Execute; the coarse write
@@ -60,6 +66,10 @@ func AddSchema(repo *eventtype.Repository) usecaseop.Operation[AddSchemaCommand,
 			}
 
 			sv := eventtype.NewSpecVersion(et.ID, cmd.Version, cmd.Schema)
+			if eventtype.ParseSchemaType(cmd.SchemaType) == eventtype.SchemaProto {
+				sv.SchemaType = eventtype.SchemaProto
+				sv.MimeType = eventtype.ProtoMimeType
+			}
 			et.AddSchemaVersion(sv)
 
 			event := EventTypeSchemaAdded{
@@ -6,6 +6,7 @@ import (
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
@@ -13,7 +14,8 @@ import (
 
 // DeleteCommand is the input DTO for DeleteEventType.
 type DeleteCommand struct {
-	ID string `json:"id"`
+	ID      string `json:"id"`
+	IfMatch string `json:"-"` // If-Match header; empty skips the check
 }
 
 // DeleteEventType removes an event type and atomically emits an
@@ -41,13 +43,16 @@ func DeleteEventType(repo *eventtype.Repository) usecaseop.Operation[DeleteComma
 			if err := auth.CheckScopeAccess(auth.FromContext(ctx), et.ClientID); err != nil {
 				return nil, err
 			}
+			if err := etag.Check(cmd.IfMatch, et.UpdatedAt); err != nil {
+				return nil, err
+			}
 
 			event := EventTypeDeleted{
 				Metadata:    usecase.NewEventMetadata(ec, EventTypeDeletedType, EventTypeSourceConst, subjectFor(et.ID)),
 				EventTypeID: et.ID,
 				Code:        et.Code,
 			}
-			return usecaseop.Delete(et, repo, event), nil
+			return usecaseop.Delete(et, etag.Guard[eventtype.EventType](repo, "msg_event_types", cmd.IfMatch), event), nil
 		},
 	}
 }
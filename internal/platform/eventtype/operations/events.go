@@ -8,6 +8,7 @@ import (
 	"fmt"
 	"time"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 )
 
@@ -54,15 +55,18 @@ type EventTypeSchemaAdded struct {
 // Event type strings, source, subject builders. Matches the
 // platform_event_types.rs catalog byte-for-byte (drop-in parity).
 const (
-	EventTypeCreatedType          = "platform:admin:eventtype:created"
-	EventTypeUpdatedType          = "platform:admin:eventtype:updated"
-	EventTypeDeletedType          = "platform:admin:eventtype:deleted"
-	EventTypeArchivedType         = "platform:admin:eventtype:archived"
-	EventTypeSchemaAddedType      = "platform:admin:eventtype:schema-added"
-	EventTypeSchemaFinalisedType  = "platform:admin:eventtype:schema-finalised"
-	EventTypeSchemaDeprecatedType = "platform:admin:eventtype:schema-deprecated"
-	EventTypesSyncedType          = "platform:admin:eventtypes:synced"
-	EventTypeSourceConst          = "platform:admin"
+	EventTypeCreatedType                = "platform:admin:eventtype:created"
+	EventTypeUpdatedType                = "platform:admin:eventtype:updated"
+	EventTypeDeletedType                = "platform:admin:eventtype:deleted"
+	EventTypeArchivedType               = "platform:admin:eventtype:archived"
+	EventTypeSchemaAddedType            = "platform:admin:eventtype:schema-added"
+	EventTypeSchemaFinalisedType        = "platform:admin:eventtype:schema-finalised"
+	EventTypeSchemaDeprecatedType       = "platform:admin:eventtype:schema-deprecated"
+	EventTypeDataPolicyUpdatedType      = "platform:admin:eventtype:data-policy-updated"
+	EventTypeRegistryBindingUpdatedType = "platform:admin:eventtype:registry-binding-updated"
+	EventTypeLifecycleUpdatedType       = "platform:admin:eventtype:lifecycle-updated"
+	EventTypesSyncedType                = "platform:admin:eventtypes:synced"
+	EventTypeSourceConst                = "platform:admin"
 )
 
 // EventTypesSynced is the rollup event emitted by SyncEventTypesUseCase.
@@ -106,6 +110,34 @@ var _ = fmt.Sprintf
 
 func subjectFor(id string) string { return "platform.eventtype." + id }
 
+// NewEventTypeCreatedEvent / NewEventTypeUpdatedEvent build the canonical
+// created/updated events for et. Exported for cross-aggregate orchestrations
+// (the configuration bundle import) that write event types inside their own
+// transaction.
+func NewEventTypeCreatedEvent(ec usecase.ExecutionContext, et *eventtype.EventType) EventTypeCreated {
+	return EventTypeCreated{
+		Metadata:    usecase.NewEventMetadata(ec, EventTypeCreatedType, EventTypeSourceConst, subjectFor(et.ID)),
+		EventTypeID: et.ID,
+		Code:        et.Code,
+		Name:        et.Name,
+		Application: et.Application,
+		Subdomain:   et.Subdomain,
+		Aggregate:   et.Aggregate,
+		EventName:   et.EventName,
+		Description: et.Description,
+		ClientID:    et.ClientID,
+	}
+}
+
+func NewEventTypeUpdatedEvent(ec usecase.ExecutionContext, et *eventtype.EventType) EventTypeUpdated {
+	return EventTypeUpdated{
+		Metadata:    usecase.NewEventMetadata(ec, EventTypeUpdatedType, EventTypeSourceConst, subjectFor(et.ID)),
+		EventTypeID: et.ID,
+		Name:        et.Name,
+		Description: et.Description,
+	}
+}
+
 // ── DomainEvent impls ─────────────────────────────────────────────────────
 
 func (e EventTypeCreated) EventID() string       { return e.Metadata.EventID }
@@ -269,3 +301,101 @@ func (e EventTypeSchemaDeprecated) ToDataJSON() ([]byte, error) {
 		Version     string `json:"specVersion"`
 	}{e.EventTypeID, e.Version})
 }
+
+// EventTypeDataPolicyUpdated is emitted when an event type's retention /
+// redaction policy is replaced.
+type EventTypeDataPolicyUpdated struct {
+	Metadata              usecase.EventMetadata
+	EventTypeID           string
+	Code                  string
+	PayloadRetentionDays  *int
+	MetadataRetentionDays *int
+	RedactFields          []string
+}
+
+func (e EventTypeDataPolicyUpdated) EventID() string       { return e.Metadata.EventID }
+func (e EventTypeDataPolicyUpdated) EventType() string     { return EventTypeDataPolicyUpdatedType }
+func (e EventTypeDataPolicyUpdated) SpecVersion() string   { return "1.0" }
+func (e EventTypeDataPolicyUpdated) Source() string        { return EventTypeSourceConst }
+func (e EventTypeDataPolicyUpdated) Subject() string       { return subjectFor(e.EventTypeID) }
+func (e EventTypeDataPolicyUpdated) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e EventTypeDataPolicyUpdated) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e EventTypeDataPolicyUpdated) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e EventTypeDataPolicyUpdated) CausationID() string   { return e.Metadata.CausationID }
+func (e EventTypeDataPolicyUpdated) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e EventTypeDataPolicyUpdated) MessageGroup() string  { return e.Metadata.MessageGroup }
+func (e EventTypeDataPolicyUpdated) ToDataJSON() ([]byte, error) {
+	fields := e.RedactFields
+	if fields == nil {
+		fields = []string{}
+	}
+	return json.Marshal(struct {
+		EventTypeID           string   `json:"eventTypeId"`
+		Code                  string   `json:"code"`
+		PayloadRetentionDays  *int     `json:"payloadRetentionDays"`
+		MetadataRetentionDays *int     `json:"metadataRetentionDays"`
+		RedactFields          []string `json:"redactFields"`
+	}{e.EventTypeID, e.Code, e.PayloadRetentionDays, e.MetadataRetentionDays, fields})
+}
+
+// EventTypeRegistryBindingUpdated is emitted when an event type is bound
+// to a schema registry subject, rebound, or unbound.
+type EventTypeRegistryBindingUpdated struct {
+	Metadata        usecase.EventMetadata
+	EventTypeID     string
+	Code            string
+	RegistrySubject *string
+	RegistryMode    string
+}
+
+func (e EventTypeRegistryBindingUpdated) EventID() string       { return e.Metadata.EventID }
+func (e EventTypeRegistryBindingUpdated) EventType() string     { return EventTypeRegistryBindingUpdatedType }
+func (e EventTypeRegistryBindingUpdated) SpecVersion() string   { return "1.0" }
+func (e EventTypeRegistryBindingUpdated) Source() string        { return EventTypeSourceConst }
+func (e EventTypeRegistryBindingUpdated) Subject() string       { return subjectFor(e.EventTypeID) }
+func (e EventTypeRegistryBindingUpdated) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e EventTypeRegistryBindingUpdated) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e EventTypeRegistryBindingUpdated) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e EventTypeRegistryBindingUpdated) CausationID() string   { return e.Metadata.CausationID }
+func (e EventTypeRegistryBindingUpdated) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e EventTypeRegistryBindingUpdated) MessageGroup() string  { return e.Metadata.MessageGroup }
+func (e EventTypeRegistryBindingUpdated) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		EventTypeID     string  `json:"eventTypeId"`
+		Code            string  `json:"code"`
+		RegistrySubject *string `json:"registrySubject"`
+		RegistryMode    string  `json:"registryMode"`
+	}{e.EventTypeID, e.Code, e.RegistrySubject, e.RegistryMode})
+}
+
+// EventTypeLifecycleUpdated is emitted when an event type is deprecated,
+// its sunset or migration hint changes, or it is undeprecated.
+type EventTypeLifecycleUpdated struct {
+	Metadata      usecase.EventMetadata
+	EventTypeID   string
+	Code          string
+	DeprecatedAt  *time.Time
+	SunsetAt      *time.Time
+	MigrationHint *string
+}
+
+func (e EventTypeLifecycleUpdated) EventID() string       { return e.Metadata.EventID }
+func (e EventTypeLifecycleUpdated) EventType() string     { return EventTypeLifecycleUpdatedType }
+func (e EventTypeLifecycleUpdated) SpecVersion() string   { return "1.0" }
+func (e EventTypeLifecycleUpdated) Source() string        { return EventTypeSourceConst }
+func (e EventTypeLifecycleUpdated) Subject() string       { return subjectFor(e.EventTypeID) }
+func (e EventTypeLifecycleUpdated) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e EventTypeLifecycleUpdated) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e EventTypeLifecycleUpdated) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e EventTypeLifecycleUpdated) CausationID() string   { return e.Metadata.CausationID }
+func (e EventTypeLifecycleUpdated) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e EventTypeLifecycleUpdated) MessageGroup() string  { return e.Metadata.MessageGroup }
+func (e EventTypeLifecycleUpdated) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		EventTypeID   string     `json:"eventTypeId"`
+		Code          string     `json:"code"`
+		DeprecatedAt  *time.Time `json:"deprecatedAt"`
+		SunsetAt      *time.Time `json:"sunsetAt"`
+		MigrationHint *string    `json:"migrationHint"`
+	}{e.EventTypeID, e.Code, e.DeprecatedAt, e.SunsetAt, e.MigrationHint})
+}
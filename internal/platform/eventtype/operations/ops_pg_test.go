@@ -6,6 +6,7 @@ import (
 	"context"
 	"encoding/json"
 	"testing"
+	"time"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -267,6 +268,128 @@ func TestArchiveEventType_HappyPathAndAlreadyArchived(t *testing.T) {
 	testpg.RequireUsecaseError(t, err, usecase.KindConflict, "ALREADY_ARCHIVED")
 }
 
+// ── Data policy ───────────────────────────────────────────────────────────
+
+func TestUpdateEventTypeDataPolicy_PersistsAndSurvivesSync(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := eventtype.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seeded := mustCreate(t, repo, uow, "etpol:orders:order:created", "Policy")
+
+	payload, metadata := 30, 365
+	ev, err := runAuthorized(uow, operations.UpdateEventTypeDataPolicy(repo), operations.UpdateDataPolicyCommand{
+		ID: seeded.EventTypeID, PayloadRetentionDays: &payload, MetadataRetentionDays: &metadata,
+		RedactFields: []string{"customer.email"},
+	})
+	require.NoError(t, err)
+	assert.Equal(t, []string{"customer.email"}, ev.RedactFields)
+
+	got, err := repo.FindByID(ctx, seeded.EventTypeID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	require.NotNil(t, got.PayloadRetentionDays)
+	assert.Equal(t, 30, *got.PayloadRetentionDays)
+	assert.Equal(t, []string{"customer.email"}, got.RedactFields)
+
+	rules, err := repo.RedactionRules(ctx)
+	require.NoError(t, err)
+	assert.Equal(t, []string{"customer.email"}, rules["etpol:orders:order:created"])
+
+	// An invalid policy is rejected as a validation error.
+	bad := 400
+	_, err = runAuthorized(uow, operations.UpdateEventTypeDataPolicy(repo), operations.UpdateDataPolicyCommand{
+		ID: seeded.EventTypeID, PayloadRetentionDays: &bad, MetadataRetentionDays: &metadata,
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_DATA_POLICY")
+}
+
+func TestUpdateEventTypeRegistryBinding_PersistsAndUnbinds(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := eventtype.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seeded := mustCreate(t, repo, uow, "etreg:orders:order:created", "Registry")
+
+	subject := "orders-order-created-value"
+	ev, err := runAuthorized(uow, operations.UpdateEventTypeRegistryBinding(repo), operations.UpdateRegistryBindingCommand{
+		ID: seeded.EventTypeID, Subject: &subject, Mode: "VALIDATE",
+	})
+	require.NoError(t, err)
+	assert.Equal(t, "VALIDATE", ev.RegistryMode)
+
+	got, err := repo.FindByID(ctx, seeded.EventTypeID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	require.NotNil(t, got.RegistrySubject)
+	assert.Equal(t, subject, *got.RegistrySubject)
+	assert.Equal(t, eventtype.RegistryModeValidate, got.RegistryMode)
+
+	bindings, err := repo.RegistryBindings(ctx)
+	require.NoError(t, err)
+	assert.Equal(t, eventtype.RegistryBinding{Subject: subject, Mode: eventtype.RegistryModeValidate}, bindings["etreg:orders:order:created"])
+
+	_, err = runAuthorized(uow, operations.UpdateEventTypeRegistryBinding(repo), operations.UpdateRegistryBindingCommand{
+		ID: seeded.EventTypeID, Subject: &subject, Mode: "STRICT",
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_REGISTRY_BINDING")
+
+	// Unbinding drops the event type from the bindings.
+	_, err = runAuthorized(uow, operations.UpdateEventTypeRegistryBinding(repo), operations.UpdateRegistryBindingCommand{ID: seeded.EventTypeID})
+	require.NoError(t, err)
+	bindings, err = repo.RegistryBindings(ctx)
+	require.NoError(t, err)
+	assert.NotContains(t, bindings, "etreg:orders:order:created")
+}
+
+func TestUpdateEventTypeLifecycle_DeprecatesAndUndeprecates(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := eventtype.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seeded := mustCreate(t, repo, uow, "etlife:orders:order:created", "Lifecycle")
+
+	sunset := time.Now().Add(48 * time.Hour).UTC().Truncate(time.Microsecond)
+	hint := "publish etlife:orders:order:placed"
+	ev, err := runAuthorized(uow, operations.UpdateEventTypeLifecycle(repo), operations.UpdateLifecycleCommand{
+		ID: seeded.EventTypeID, Deprecated: true, SunsetAt: &sunset, MigrationHint: &hint,
+	})
+	require.NoError(t, err)
+	require.NotNil(t, ev.DeprecatedAt)
+
+	got, err := repo.FindByID(ctx, seeded.EventTypeID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	require.True(t, got.IsDeprecated())
+	assert.True(t, sunset.Equal(*got.SunsetAt))
+	assert.Equal(t, hint, *got.MigrationHint)
+
+	byApp, err := repo.FindByApplication(ctx, "etlife")
+	require.NoError(t, err)
+	require.Len(t, byApp, 1)
+	require.True(t, byApp[0].IsDeprecated())
+	assert.True(t, sunset.Equal(*byApp[0].SunsetAt))
+	assert.Equal(t, hint, *byApp[0].MigrationHint)
+
+	lifecycles, err := repo.Lifecycles(ctx)
+	require.NoError(t, err)
+	l, ok := lifecycles["etlife:orders:order:created"]
+	require.True(t, ok)
+	assert.Equal(t, hint, *l.MigrationHint)
+
+	past := time.Now().Add(-time.Hour)
+	_, err = runAuthorized(uow, operations.UpdateEventTypeLifecycle(repo), operations.UpdateLifecycleCommand{
+		ID: seeded.EventTypeID, Deprecated: true, SunsetAt: &past,
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_LIFECYCLE")
+
+	_, err = runAuthorized(uow, operations.UpdateEventTypeLifecycle(repo), operations.UpdateLifecycleCommand{ID: seeded.EventTypeID})
+	require.NoError(t, err)
+	lifecycles, err = repo.Lifecycles(ctx)
+	require.NoError(t, err)
+	assert.NotContains(t, lifecycles, "etlife:orders:order:created")
+}
+
 // ── Sync (app-scoped; created/updated/deleted; API-source-only removal) ───
 
 func TestSyncEventTypes_UpsertAndRemoveUnlisted(t *testing.T) {
@@ -6,6 +6,7 @@ import (
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
@@ -16,6 +17,8 @@ type UpdateCommand struct {
 	ID          string  `json:"id"`
 	Name        string  `json:"name"`
 	Description *string `json:"description,omitempty"`
+	// IfMatch is the caller's If-Match header; empty skips the check.
+	IfMatch string `json:"-"`
 }
 
 // UpdateEventType mutates name + description on an existing event type
@@ -47,6 +50,9 @@ func UpdateEventType(repo *eventtype.Repository) usecaseop.Operation[UpdateComma
 			if err := auth.CheckScopeAccess(auth.FromContext(ctx), et.ClientID); err != nil {
 				return nil, err
 			}
+			if err := etag.Check(cmd.IfMatch, et.UpdatedAt); err != nil {
+				return nil, err
+			}
 
 			et.Name = cmd.Name
 			et.Description = cmd.Description
@@ -57,7 +63,7 @@ func UpdateEventType(repo *eventtype.Repository) usecaseop.Operation[UpdateComma
 				Name:        et.Name,
 				Description: et.Description,
 			}
-			return usecaseop.Save(et, repo, event), nil
+			return usecaseop.Save(et, etag.Guard[eventtype.EventType](repo, "msg_event_types", cmd.IfMatch), event), nil
 		},
 	}
 }
@@ -0,0 +1,65 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// UpdateDataPolicyCommand is the input DTO for UpdateEventTypeDataPolicy.
+// The policy is replaced wholesale: omitted retention fields clear it.
+type UpdateDataPolicyCommand struct {
+	ID                    string   `json:"id"`
+	PayloadRetentionDays  *int     `json:"payloadRetentionDays,omitempty"`
+	MetadataRetentionDays *int     `json:"metadataRetentionDays,omitempty"`
+	RedactFields          []string `json:"redactFields"`
+}
+
+// UpdateEventTypeDataPolicy sets an event type's retention windows and
+// redaction paths and atomically emits an [EventTypeDataPolicyUpdated]
+// event. The retention reaper and the read-side redactor pick the change
+// up on their next refresh.
+func UpdateEventTypeDataPolicy(repo *eventtype.Repository) usecaseop.Operation[UpdateDataPolicyCommand, EventTypeDataPolicyUpdated] {
+	return usecaseop.Operation[UpdateDataPolicyCommand, EventTypeDataPolicyUpdated]{
+		Name: "UpdateEventTypeDataPolicy",
+		Validate: func(_ context.Context, cmd UpdateDataPolicyCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "Event type id is required")
+			}
+			return nil
+		},
+		// Per-resource authz runs post-load in Execute; the coarse write
+		// permission is on the controller.
+		Authorize: usecaseop.Public[UpdateDataPolicyCommand],
+		Execute: func(ctx context.Context, cmd UpdateDataPolicyCommand, ec usecase.ExecutionContext) (usecaseop.Plan[EventTypeDataPolicyUpdated], error) {
+			et, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if et == nil {
+				return nil, httperror.NotFound("EventType", cmd.ID)
+			}
+			if err := auth.CheckScopeAccess(auth.FromContext(ctx), et.ClientID); err != nil {
+				return nil, err
+			}
+			if err := et.SetDataPolicy(cmd.PayloadRetentionDays, cmd.MetadataRetentionDays, cmd.RedactFields); err != nil {
+				return nil, usecase.Validation("INVALID_DATA_POLICY", err.Error())
+			}
+
+			event := EventTypeDataPolicyUpdated{
+				Metadata:              usecase.NewEventMetadata(ec, EventTypeDataPolicyUpdatedType, EventTypeSourceConst, subjectFor(et.ID)),
+				EventTypeID:           et.ID,
+				Code:                  et.Code,
+				PayloadRetentionDays:  et.PayloadRetentionDays,
+				MetadataRetentionDays: et.MetadataRetentionDays,
+				RedactFields:          et.RedactFields,
+			}
+			return usecaseop.Save(et, repo, event), nil
+		},
+	}
+}
@@ -0,0 +1,70 @@
+package operations
+
+import (
+	"context"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// UpdateLifecycleCommand is the input DTO for UpdateEventTypeLifecycle.
+// Deprecated false undeprecates and ignores the other fields.
+type UpdateLifecycleCommand struct {
+	ID            string     `json:"id"`
+	Deprecated    bool       `json:"deprecated"`
+	SunsetAt      *time.Time `json:"sunsetAt,omitempty"`
+	MigrationHint *string    `json:"migrationHint,omitempty"`
+}
+
+// UpdateEventTypeLifecycle deprecates an event type (with an optional
+// sunset and migration hint) or undeprecates it, and atomically emits an
+// [EventTypeLifecycleUpdated] event. Ingestion and subscription checks
+// pick the change up on their next lifecycle refresh.
+func UpdateEventTypeLifecycle(repo *eventtype.Repository) usecaseop.Operation[UpdateLifecycleCommand, EventTypeLifecycleUpdated] {
+	return usecaseop.Operation[UpdateLifecycleCommand, EventTypeLifecycleUpdated]{
+		Name: "UpdateEventTypeLifecycle",
+		Validate: func(_ context.Context, cmd UpdateLifecycleCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "Event type id is required")
+			}
+			return nil
+		},
+		// Per-resource authz runs post-load in Execute; the coarse write
+		// permission is on the controller.
+		Authorize: usecaseop.Public[UpdateLifecycleCommand],
+		Execute: func(ctx context.Context, cmd UpdateLifecycleCommand, ec usecase.ExecutionContext) (usecaseop.Plan[EventTypeLifecycleUpdated], error) {
+			et, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if et == nil {
+				return nil, httperror.NotFound("EventType", cmd.ID)
+			}
+			if err := auth.CheckScopeAccess(auth.FromContext(ctx), et.ClientID); err != nil {
+				return nil, err
+			}
+			if cmd.Deprecated {
+				if err := et.Deprecate(cmd.SunsetAt, cmd.MigrationHint); err != nil {
+					return nil, usecase.Validation("INVALID_LIFECYCLE", err.Error())
+				}
+			} else {
+				et.Undeprecate()
+			}
+
+			event := EventTypeLifecycleUpdated{
+				Metadata:      usecase.NewEventMetadata(ec, EventTypeLifecycleUpdatedType, EventTypeSourceConst, subjectFor(et.ID)),
+				EventTypeID:   et.ID,
+				Code:          et.Code,
+				DeprecatedAt:  et.DeprecatedAt,
+				SunsetAt:      et.SunsetAt,
+				MigrationHint: et.MigrationHint,
+			}
+			return usecaseop.Save(et, repo, event), nil
+		},
+	}
+}
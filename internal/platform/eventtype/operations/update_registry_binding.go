@@ -0,0 +1,63 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// UpdateRegistryBindingCommand is the input DTO for
+// UpdateEventTypeRegistryBinding. A nil or blank Subject unbinds.
+type UpdateRegistryBindingCommand struct {
+	ID      string  `json:"id"`
+	Subject *string `json:"subject,omitempty"`
+	Mode    string  `json:"mode"`
+}
+
+// UpdateEventTypeRegistryBinding binds an event type to a schema registry
+// subject (or unbinds it) and atomically emits an
+// [EventTypeRegistryBindingUpdated] event. Ingestion picks the change up
+// on its next binding refresh.
+func UpdateEventTypeRegistryBinding(repo *eventtype.Repository) usecaseop.Operation[UpdateRegistryBindingCommand, EventTypeRegistryBindingUpdated] {
+	return usecaseop.Operation[UpdateRegistryBindingCommand, EventTypeRegistryBindingUpdated]{
+		Name: "UpdateEventTypeRegistryBinding",
+		Validate: func(_ context.Context, cmd UpdateRegistryBindingCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "Event type id is required")
+			}
+			return nil
+		},
+		// Per-resource authz runs post-load in Execute; the coarse write
+		// permission is on the controller.
+		Authorize: usecaseop.Public[UpdateRegistryBindingCommand],
+		Execute: func(ctx context.Context, cmd UpdateRegistryBindingCommand, ec usecase.ExecutionContext) (usecaseop.Plan[EventTypeRegistryBindingUpdated], error) {
+			et, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if et == nil {
+				return nil, httperror.NotFound("EventType", cmd.ID)
+			}
+			if err := auth.CheckScopeAccess(auth.FromContext(ctx), et.ClientID); err != nil {
+				return nil, err
+			}
+			if err := et.SetRegistryBinding(cmd.Subject, cmd.Mode); err != nil {
+				return nil, usecase.Validation("INVALID_REGISTRY_BINDING", err.Error())
+			}
+
+			event := EventTypeRegistryBindingUpdated{
+				Metadata:        usecase.NewEventMetadata(ec, EventTypeRegistryBindingUpdatedType, EventTypeSourceConst, subjectFor(et.ID)),
+				EventTypeID:     et.ID,
+				Code:            et.Code,
+				RegistrySubject: et.RegistrySubject,
+				RegistryMode:    string(et.RegistryMode),
+			}
+			return usecaseop.Save(et, repo, event), nil
+		},
+	}
+}
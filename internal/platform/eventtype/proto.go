@@ -0,0 +1,69 @@
+package eventtype
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"google.golang.org/protobuf/encoding/protojson"
+	"google.golang.org/protobuf/reflect/protodesc"
+	"google.golang.org/protobuf/reflect/protoreflect"
+	"google.golang.org/protobuf/types/descriptorpb"
+)
+
+// ProtoMimeType is the mime type of PROTO schema versions.
+const ProtoMimeType = "application/x-protobuf"
+
+// ProtoSchema is the content of a PROTO schema version: the message an
+// event's data is transcoded to for PROTOBUF subscriptions, and a
+// FileDescriptorSet defining it and everything it imports, in protobuf's
+// JSON mapping (what `buf build -o image.json` writes).
+type ProtoSchema struct {
+	MessageType       string          `json:"messageType"`
+	FileDescriptorSet json.RawMessage `json:"fileDescriptorSet"`
+}
+
+// CompileProtoSchema resolves a PROTO schema version's content to its
+// message descriptor.
+func CompileProtoSchema(content json.RawMessage) (protoreflect.MessageDescriptor, error) {
+	var s ProtoSchema
+	if err := json.Unmarshal(content, &s); err != nil {
+		return nil, fmt.Errorf("proto schema: %w", err)
+	}
+	if s.MessageType == "" || len(s.FileDescriptorSet) == 0 {
+		return nil, errors.New("proto schema needs messageType and fileDescriptorSet")
+	}
+	var set descriptorpb.FileDescriptorSet
+	if err := protojson.Unmarshal(s.FileDescriptorSet, &set); err != nil {
+		return nil, fmt.Errorf("proto schema fileDescriptorSet: %w", err)
+	}
+	files, err := protodesc.NewFiles(&set)
+	if err != nil {
+		return nil, fmt.Errorf("proto schema fileDescriptorSet: %w", err)
+	}
+	d, err := files.FindDescriptorByName(protoreflect.FullName(s.MessageType))
+	if err != nil {
+		return nil, fmt.Errorf("proto schema: message %s: %w", s.MessageType, err)
+	}
+	md, ok := d.(protoreflect.MessageDescriptor)
+	if !ok {
+		return nil, fmt.Errorf("proto schema: %s is not a message", s.MessageType)
+	}
+	return md, nil
+}
+
+// ProtoSpec returns the event type's newest CURRENT PROTO schema version,
+// or nil when it has none.
+func (e *EventType) ProtoSpec() *SpecVersion {
+	var out *SpecVersion
+	for i := range e.SpecVersions {
+		sv := &e.SpecVersions[i]
+		if sv.SchemaType != SchemaProto || !sv.IsCurrent() {
+			continue
+		}
+		if out == nil || sv.CreatedAt.After(out.CreatedAt) {
+			out = sv
+		}
+	}
+	return out
+}
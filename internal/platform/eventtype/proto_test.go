@@ -0,0 +1,51 @@
+package eventtype_test
+
+import (
+	"encoding/json"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+)
+
+const orderDescriptors = `{"file":[{"name":"orders.proto","package":"acme.orders.v1","syntax":"proto3",
+	"messageType":[{"name":"OrderCreated","field":[
+		{"name":"order_id","number":1,"label":"LABEL_OPTIONAL","type":"TYPE_STRING"},
+		{"name":"total","number":2,"label":"LABEL_OPTIONAL","type":"TYPE_INT64"}]}]}]}`
+
+func TestCompileProtoSchema(t *testing.T) {
+	md, err := eventtype.CompileProtoSchema(json.RawMessage(`{"messageType":"acme.orders.v1.OrderCreated","fileDescriptorSet":` + orderDescriptors + `}`))
+	require.NoError(t, err)
+	assert.Equal(t, "acme.orders.v1.OrderCreated", string(md.FullName()))
+	assert.Equal(t, 2, md.Fields().Len())
+
+	for name, content := range map[string]string{
+		"unknown message":    `{"messageType":"acme.orders.v1.Missing","fileDescriptorSet":` + orderDescriptors + `}`,
+		"not a message":      `{"messageType":"acme.orders.v1.OrderCreated.order_id","fileDescriptorSet":` + orderDescriptors + `}`,
+		"no descriptors":     `{"messageType":"acme.orders.v1.OrderCreated"}`,
+		"bad descriptors":    `{"messageType":"acme.orders.v1.OrderCreated","fileDescriptorSet":{"file":[{"name":7}]}}`,
+		"not a proto schema": `{"type":"object"}`,
+	} {
+		_, err := eventtype.CompileProtoSchema(json.RawMessage(content))
+		assert.Error(t, err, name)
+	}
+}
+
+func TestProtoSpecIsNewestCurrentProtoVersion(t *testing.T) {
+	et, err := eventtype.New("orders:sales:order:created", "Order Created")
+	require.NoError(t, err)
+	assert.Nil(t, et.ProtoSpec())
+
+	now := time.Now()
+	et.SpecVersions = []eventtype.SpecVersion{
+		{Version: "1.0", SchemaType: eventtype.SchemaProto, Status: eventtype.SpecCurrent, CreatedAt: now.Add(-time.Hour)},
+		{Version: "2.0", SchemaType: eventtype.SchemaProto, Status: eventtype.SpecCurrent, CreatedAt: now},
+		{Version: "3.0", SchemaType: eventtype.SchemaProto, Status: eventtype.SpecFinalising, CreatedAt: now.Add(time.Hour)},
+		{Version: "4.0", SchemaType: eventtype.SchemaJSON, Status: eventtype.SpecCurrent, CreatedAt: now.Add(2 * time.Hour)},
+	}
+	require.NotNil(t, et.ProtoSpec())
+	assert.Equal(t, "2.0", et.ProtoSpec().Version)
+}
@@ -75,7 +75,9 @@ func (r *Repository) FindWithFilters(
 	_ = clientID // not a column on msg_event_types
 
 	q := `SELECT id, code, name, description, status, source, client_scoped,
-		         application, subdomain, aggregate, created_by, created_at, updated_at
+		         application, subdomain, aggregate, created_by, created_at, updated_at,
+		         payload_retention_days, metadata_retention_days, redact_fields,
+		         registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint
 		  FROM msg_event_types` + f.Where() + " ORDER BY code ASC"
 
 	rows, err := r.pool.Query(ctx, q, f.Args()...)
@@ -93,6 +95,60 @@ func (r *Repository) FindWithFilters(
 	return r.hydrateAll(ctx, bare)
 }
 
+// RedactionRules maps event type code → redact field paths for every
+// event type that has any. Feeds the redact.Rules cache.
+func (r *Repository) RedactionRules(ctx context.Context) (map[string][]string, error) {
+	rows, err := r.q.EventTypeRedactionRules(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("event_types RedactionRules: %w", err)
+	}
+	out := make(map[string][]string, len(rows))
+	for _, row := range rows {
+		out[row.Code] = row.RedactFields
+	}
+	return out, nil
+}
+
+// RegistryBinding is an event type's schema registry subject and mode.
+type RegistryBinding struct {
+	Subject string
+	Mode    RegistryMode
+}
+
+// RegistryBindings maps event type code → registry binding for every
+// event type bound to a subject. Feeds the schemaregistry.Resolver cache.
+func (r *Repository) RegistryBindings(ctx context.Context) (map[string]RegistryBinding, error) {
+	rows, err := r.q.EventTypeRegistryBindings(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("event_types RegistryBindings: %w", err)
+	}
+	out := make(map[string]RegistryBinding, len(rows))
+	for _, row := range rows {
+		if row.RegistrySubject == nil {
+			continue
+		}
+		out[row.Code] = RegistryBinding{Subject: *row.RegistrySubject, Mode: ParseRegistryMode(row.RegistryMode)}
+	}
+	return out, nil
+}
+
+// Lifecycles maps event type code → lifecycle for every deprecated event
+// type. Feeds the LifecycleCache.
+func (r *Repository) Lifecycles(ctx context.Context) (map[string]Lifecycle, error) {
+	rows, err := r.q.EventTypeLifecycles(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("event_types Lifecycles: %w", err)
+	}
+	out := make(map[string]Lifecycle, len(rows))
+	for _, row := range rows {
+		if row.DeprecatedAt == nil {
+			continue
+		}
+		out[row.Code] = Lifecycle{DeprecatedAt: *row.DeprecatedAt, SunsetAt: row.SunsetAt, MigrationHint: row.MigrationHint}
+	}
+	return out, nil
+}
+
 // Pool exposes the underlying pgxpool so use cases that need an
 // orchestrated transaction (e.g. sync) can run multiple writes atomically.
 func (r *Repository) Pool() *pgxpool.Pool { return r.pool }
@@ -217,6 +273,20 @@ func rowToEventType(row dbq.MsgEventType) *EventType {
 		CreatedBy:    row.CreatedBy,
 		CreatedAt:    row.CreatedAt,
 		UpdatedAt:    row.UpdatedAt,
+
+		PayloadRetentionDays:  intPtr(row.PayloadRetentionDays),
+		MetadataRetentionDays: intPtr(row.MetadataRetentionDays),
+		RedactFields:          row.RedactFields,
+
+		RegistrySubject: row.RegistrySubject,
+		RegistryMode:    ParseRegistryMode(row.RegistryMode),
+
+		DeprecatedAt:  row.DeprecatedAt,
+		SunsetAt:      row.SunsetAt,
+		MigrationHint: row.MigrationHint,
+	}
+	if et.RedactFields == nil {
+		et.RedactFields = []string{}
 	}
 	parts := strings.Split(et.Code, ":")
 	if len(parts) == 4 {
@@ -242,7 +312,35 @@ func eventTypeUpsertParams(et *EventType) dbq.EventTypeUpsertByIDParams {
 		CreatedBy:    et.CreatedBy,
 		CreatedAt:    et.CreatedAt,
 		UpdatedAt:    time.Now().UTC(),
+
+		PayloadRetentionDays:  int32Ptr(et.PayloadRetentionDays),
+		MetadataRetentionDays: int32Ptr(et.MetadataRetentionDays),
+		// redact_fields is NOT NULL; a nil slice would encode as NULL.
+		RedactFields: append([]string{}, et.RedactFields...),
+
+		RegistrySubject: et.RegistrySubject,
+		RegistryMode:    string(ParseRegistryMode(string(et.RegistryMode))),
+
+		DeprecatedAt:  et.DeprecatedAt,
+		SunsetAt:      et.SunsetAt,
+		MigrationHint: et.MigrationHint,
+	}
+}
+
+func intPtr(v *int32) *int {
+	if v == nil {
+		return nil
+	}
+	n := int(*v)
+	return &n
+}
+
+func int32Ptr(v *int) *int32 {
+	if v == nil {
+		return nil
 	}
+	n := int32(*v)
+	return &n
 }
 
 func specContentBytes(rm json.RawMessage) []byte {
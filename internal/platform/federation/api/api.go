@@ -0,0 +1,268 @@
+// Package api wires the HTTP routes for federation via huma: the feed
+// peer instances pull events from, and the peers this instance pulls
+// from. All are anchor-only.
+package api
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"time"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// FeedSettle is how old an event must be before the feed serves it. A
+// transaction that committed late with an earlier created_at would
+// otherwise land behind a cursor already handed out.
+const FeedSettle = 5 * time.Second
+
+// State bundles the dependencies.
+type State struct {
+	Repo    *federation.Repository
+	Events  *event.Repository
+	Clients *client.Repository
+	UoW     *usecasepgx.UnitOfWork
+	// Enc seals peer client secrets before they are stored. May be nil
+	// when FLOWCATALYST_APP_KEY is unset, in which case a plaintext secret
+	// is rejected.
+	Enc *encryption.Service
+	// Blobs reads offloaded event data back so peers get it inline; nil
+	// when no blob store is configured.
+	Blobs *blobstore.Offloader
+	// InstanceID is FC_FEDERATION_INSTANCE_ID; the feed is disabled
+	// without it.
+	InstanceID string
+}
+
+// sealSecret seals a plaintext peer client secret inline before it is
+// stored (see encryption.EncryptSecretRef), mapping its errors to the
+// API's validation/internal envelopes.
+func sealSecret(enc *encryption.Service, ref *string) (*string, error) {
+	out, err := encryption.EncryptSecretRef(enc, ref)
+	switch {
+	case errors.Is(err, encryption.ErrNotConfigured):
+		return nil, usecase.Validation("ENCRYPTION_NOT_CONFIGURED",
+			"cannot store peer client secret: FLOWCATALYST_APP_KEY is not configured")
+	case err != nil:
+		return nil, usecase.Internal("ENCRYPT", "encrypt peer client secret", err)
+	}
+	return out, nil
+}
+
+const tag = "federation"
+
+// Register mounts the federation endpoints.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "getFederationFeed", "/api/federation/feed", "Read this instance's events of the given types, for a peer instance", s.feed)
+	apiroute.Get(g, "listFederationPeers", "/api/federation/peers", "List the peers this instance pulls events from", s.list)
+	apiroute.Post(g, "createFederationPeer", "/api/federation/peers", "Pull events of the given types from a peer instance", http.StatusCreated, s.create)
+	apiroute.Get(g, "getFederationPeer", "/api/federation/peers/{id}", "Get a federation peer by id", s.getByID)
+	apiroute.Put(g, "updateFederationPeer", "/api/federation/peers/{id}", "Update a federation peer", http.StatusOK, s.update)
+	apiroute.Delete(g, "deleteFederationPeer", "/api/federation/peers/{id}", "Stop pulling from a federation peer", http.StatusNoContent, s.delete)
+}
+
+type feedInput struct {
+	Types string `query:"types" doc:"Comma-separated event type codes"`
+	After string `query:"after" doc:"Opaque cursor from a previous page"`
+	Limit int    `query:"limit" doc:"Maximum events to return (default 100, max 500)"`
+}
+
+func (s *State) feed(ctx context.Context, in *feedInput) (*apicommon.Out[FederationFeedResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	if s.InstanceID == "" {
+		return nil, usecase.Conflict("FEDERATION_DISABLED", "federation is not enabled; set FC_FEDERATION_INSTANCE_ID")
+	}
+	types := splitTypes(in.Types)
+	if len(types) == 0 {
+		return nil, httperror.BadRequest("TYPES_REQUIRED", "types is required")
+	}
+	afterTime, afterID, err := federation.DecodeCursor(in.After)
+	if err != nil {
+		return nil, httperror.BadRequest("INVALID_CURSOR", "after is not a cursor this feed issued")
+	}
+	limit := in.Limit
+	if limit <= 0 {
+		limit = 100
+	}
+	limit = min(limit, 500)
+	rows, err := s.Events.FindFeed(ctx, event.FeedParams{
+		Types: types, AfterTime: afterTime, AfterID: afterID, Settle: FeedSettle, Limit: limit,
+	})
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_feed failed", err)
+	}
+
+	out := FederationFeedResponse{InstanceID: s.InstanceID, Events: make([]FederatedEventResponse, 0, len(rows)), Cursor: in.After}
+	identifiers := map[string]*string{}
+	for i := range rows {
+		e := &rows[i]
+		item, err := s.feedItem(ctx, e, identifiers)
+		if err != nil {
+			return nil, err
+		}
+		out.Events = append(out.Events, item)
+		out.Cursor = federation.EncodeCursor(e.CreatedAt, e.ID)
+	}
+	out.HasMore = len(rows) == limit
+	return &apicommon.Out[FederationFeedResponse]{Body: out}, nil
+}
+
+// feedItem renders e for a peer: offloaded data inline, the client by
+// identifier (looked up once per request through identifiers), and the
+// federation provenance lifted out of the context.
+func (s *State) feedItem(ctx context.Context, e *event.Event, identifiers map[string]*string) (FederatedEventResponse, error) {
+	data := e.Data
+	if ref, ok := blobstore.ParseRef(data); ok {
+		raw, err := s.Blobs.Fetch(ctx, ref)
+		if err != nil {
+			return FederatedEventResponse{}, usecase.Internal("BLOB_STORE", "fetch event data failed", err)
+		}
+		data = raw
+	}
+	var identifier *string
+	if e.ClientID != nil {
+		cached, seen := identifiers[*e.ClientID]
+		if !seen {
+			c, err := s.Clients.FindByID(ctx, *e.ClientID)
+			if err != nil {
+				return FederatedEventResponse{}, usecase.Internal("REPO", "client lookup failed", err)
+			}
+			if c != nil {
+				cached = &c.Identifier
+			}
+			identifiers[*e.ClientID] = cached
+		}
+		identifier = cached
+	}
+	origin, hops := federation.Provenance(e.Context, s.InstanceID)
+	entries := federation.WithoutProvenance(e.Context)
+	ctxOut := make([]FederationContextEntry, 0, len(entries))
+	for _, c := range entries {
+		ctxOut = append(ctxOut, FederationContextEntry{Key: c.Key, Value: c.Value})
+	}
+	return FederatedEventResponse{
+		ID:               e.ID,
+		SpecVersion:      e.SpecVersion,
+		Type:             e.Type,
+		Source:           e.Source,
+		Subject:          e.Subject,
+		Time:             jsontime.New(e.Time),
+		Data:             data,
+		DeduplicationID:  e.DeduplicationID,
+		ClientIdentifier: identifier,
+		MessageGroup:     e.MessageGroup,
+		CorrelationID:    e.CorrelationID,
+		CausationID:      e.CausationID,
+		Context:          ctxOut,
+		Origin:           origin,
+		Hops:             hops,
+	}, nil
+}
+
+func (s *State) list(ctx context.Context, _ *struct{}) (*apicommon.Out[FederationPeerListResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	rows, err := s.Repo.FindAll(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_all failed", err)
+	}
+	states, err := s.Repo.States(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_states failed", err)
+	}
+	out := make([]FederationPeerResponse, 0, len(rows))
+	for i := range rows {
+		out = append(out, fromEntity(&rows[i], states[rows[i].ID]))
+	}
+	return &apicommon.Out[FederationPeerListResponse]{Body: FederationPeerListResponse{Peers: out}}, nil
+}
+
+func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[FederationPeerResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	return s.load(ctx, in.ID)
+}
+
+func (s *State) create(ctx context.Context, in *apicommon.In[CreateFederationPeerRequest]) (*apicommon.Out[FederationPeerResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	secretRef, err := sealSecret(s.Enc, &in.Body.ClientSecret)
+	if err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	created, err := usecaseop.Run(ctx, s.UoW, operations.CreatePeer(s.Repo), in.Body.toCommand(*secretRef), ec)
+	if err != nil {
+		return nil, err
+	}
+	return s.load(ctx, created.PeerID)
+}
+
+type updateInput struct {
+	ID   string `path:"id"`
+	Body UpdateFederationPeerRequest
+}
+
+func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Out[FederationPeerResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	secretRef, err := sealSecret(s.Enc, in.Body.ClientSecret)
+	if err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdatePeer(s.Repo), in.Body.toCommand(in.ID, secretRef), ec); err != nil {
+		return nil, err
+	}
+	return s.load(ctx, in.ID)
+}
+
+func (s *State) delete(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeletePeer(s.Repo), operations.DeleteCommand{ID: in.ID}, ec); err != nil {
+		return nil, err
+	}
+	return &apicommon.Empty{}, nil
+}
+
+// load renders a peer with its pull state.
+func (s *State) load(ctx context.Context, id string) (*apicommon.Out[FederationPeerResponse], error) {
+	p, err := s.Repo.FindByID(ctx, id)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if p == nil {
+		return nil, httperror.NotFound("FederationPeer", id)
+	}
+	states, err := s.Repo.States(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_states failed", err)
+	}
+	return &apicommon.Out[FederationPeerResponse]{Body: fromEntity(p, states[p.ID])}, nil
+}
@@ -0,0 +1,149 @@
+// dto.go contains the wire-format types for the federation API.
+package api
+
+import (
+	"encoding/json"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+)
+
+// CreateFederationPeerRequest is the wire body for POST
+// /api/federation/peers.
+type CreateFederationPeerRequest struct {
+	Code         string   `json:"code"`
+	Name         string   `json:"name"`
+	BaseURL      string   `json:"baseUrl" doc:"The peer instance's public URL, e.g. https://eu.flowcatalyst.example"`
+	ClientID     string   `json:"clientId" doc:"OAuth client id of an anchor service account on the peer"`
+	ClientSecret string   `json:"clientSecret" doc:"Its client secret, or a secret-manager reference; sealed before it is stored"`
+	EventTypes   []string `json:"eventTypes" doc:"Event type codes to pull from the peer"`
+}
+
+func (r CreateFederationPeerRequest) toCommand(secretRef string) operations.CreateCommand {
+	return operations.CreateCommand{
+		Code:            r.Code,
+		Name:            r.Name,
+		BaseURL:         r.BaseURL,
+		ClientID:        r.ClientID,
+		ClientSecretRef: secretRef,
+		EventTypes:      r.EventTypes,
+	}
+}
+
+// UpdateFederationPeerRequest is the wire body for PUT
+// /api/federation/peers/{id}.
+type UpdateFederationPeerRequest struct {
+	Name         string   `json:"name"`
+	BaseURL      string   `json:"baseUrl"`
+	ClientID     string   `json:"clientId"`
+	ClientSecret *string  `json:"clientSecret,omitempty" doc:"A new client secret; omit to keep the stored one"`
+	EventTypes   []string `json:"eventTypes"`
+	Status       string   `json:"status" doc:"ACTIVE or PAUSED"`
+}
+
+func (r UpdateFederationPeerRequest) toCommand(id string, secretRef *string) operations.UpdateCommand {
+	return operations.UpdateCommand{
+		ID:              id,
+		Name:            r.Name,
+		BaseURL:         r.BaseURL,
+		ClientID:        r.ClientID,
+		ClientSecretRef: secretRef,
+		EventTypes:      r.EventTypes,
+		Status:          r.Status,
+	}
+}
+
+// FederationPeerResponse mirrors federation.Peer plus the puller's
+// progress. The client secret is never returned.
+type FederationPeerResponse struct {
+	ID           string           `json:"id"`
+	Code         string           `json:"code"`
+	Name         string           `json:"name"`
+	BaseURL      string           `json:"baseUrl"`
+	ClientID     string           `json:"clientId"`
+	EventTypes   []string         `json:"eventTypes"`
+	Status       string           `json:"status"`
+	LastPulledAt *httpcompat.Time `json:"lastPulledAt,omitempty" doc:"When the peer's feed was last read"`
+	LastError    *string          `json:"lastError,omitempty" doc:"Why the last pull failed; absent after a successful one"`
+	Received     int64            `json:"received" doc:"Events ingested from the peer"`
+	Skipped      int64            `json:"skipped" doc:"Events dropped by loop prevention or already present"`
+	CreatedAt    httpcompat.Time  `json:"createdAt"`
+	UpdatedAt    httpcompat.Time  `json:"updatedAt"`
+}
+
+func fromEntity(p *federation.Peer, st federation.State) FederationPeerResponse {
+	out := FederationPeerResponse{
+		ID:         p.ID,
+		Code:       p.Code,
+		Name:       p.Name,
+		BaseURL:    p.BaseURL,
+		ClientID:   p.ClientID,
+		EventTypes: p.EventTypes,
+		Status:     string(p.Status),
+		LastError:  st.LastError,
+		Received:   st.Received,
+		Skipped:    st.Skipped,
+		CreatedAt:  jsontime.New(p.CreatedAt),
+		UpdatedAt:  jsontime.New(p.UpdatedAt),
+	}
+	if st.LastPulledAt != nil {
+		t := jsontime.New(*st.LastPulledAt)
+		out.LastPulledAt = &t
+	}
+	return out
+}
+
+// FederationPeerListResponse is the wire shape for GET
+// /api/federation/peers.
+type FederationPeerListResponse struct {
+	Peers []FederationPeerResponse `json:"peers"`
+}
+
+// FederationContextEntry is a key/value pair of a federated event's
+// context.
+type FederationContextEntry struct {
+	Key   string `json:"key"`
+	Value string `json:"value"`
+}
+
+// FederatedEventResponse is one event of the feed. The client is named by
+// its identifier, which peers share, rather than by its instance-local id.
+type FederatedEventResponse struct {
+	ID               string                   `json:"id"`
+	SpecVersion      string                   `json:"specVersion"`
+	Type             string                   `json:"type"`
+	Source           string                   `json:"source"`
+	Subject          string                   `json:"subject"`
+	Time             httpcompat.Time          `json:"time"`
+	Data             json.RawMessage          `json:"data,omitempty"`
+	DeduplicationID  string                   `json:"deduplicationId"`
+	ClientIdentifier *string                  `json:"clientIdentifier,omitempty"`
+	MessageGroup     *string                  `json:"messageGroup,omitempty"`
+	CorrelationID    *string                  `json:"correlationId,omitempty"`
+	CausationID      *string                  `json:"causationId,omitempty"`
+	Context          []FederationContextEntry `json:"context" doc:"The event's context, without the federation provenance keys"`
+	Origin           string                   `json:"origin" doc:"Instance id the event was first produced on"`
+	Hops             int                      `json:"hops" doc:"Instances the event was forwarded across before this one"`
+}
+
+// FederationFeedResponse is the wire shape for GET /api/federation/feed.
+type FederationFeedResponse struct {
+	InstanceID string                   `json:"instanceId" doc:"This instance's FC_FEDERATION_INSTANCE_ID"`
+	Events     []FederatedEventResponse `json:"events"`
+	Cursor     string                   `json:"cursor" doc:"Pass as after to read the next page"`
+	HasMore    bool                     `json:"hasMore"`
+}
+
+// splitTypes parses the feed's comma-separated types parameter.
+func splitTypes(s string) []string {
+	var out []string
+	for _, t := range strings.Split(s, ",") {
+		if t = strings.TrimSpace(t); t != "" {
+			out = append(out, t)
+		}
+	}
+	return out
+}
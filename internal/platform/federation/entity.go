@@ -0,0 +1,192 @@
+// Package federation forwards events between FlowCatalyst instances. An
+// instance subscribes to a peer for a set of event types: the leader pulls
+// the peer's feed (GET /api/federation/feed) with the peer's OAuth client
+// credentials and ingests each event it receives as a local event with the
+// same id, so regional clusters can share selected event types with a
+// central one. Each forwarded event records where it was first produced
+// and how many instances it has crossed, in its context, so an event that
+// comes back around or travels too far is dropped instead of looping.
+package federation
+
+import (
+	"encoding/base64"
+	"errors"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// Status is whether a peer is pulled.
+type Status string
+
+const (
+	StatusActive Status = "ACTIVE"
+	StatusPaused Status = "PAUSED"
+)
+
+// ParseStatus is the strict parser; ok is false for anything else.
+func ParseStatus(s string) (Status, bool) {
+	switch st := Status(s); st {
+	case StatusActive, StatusPaused:
+		return st, true
+	}
+	return "", false
+}
+
+// Peer is the aggregate root: an instance this one pulls events from.
+// ClientSecretRef is an OIDC-style secret reference, sealed inline with
+// the app key or naming an external secret manager; it is never returned.
+type Peer struct {
+	ID              string    `json:"id"`
+	Code            string    `json:"code"`
+	Name            string    `json:"name"`
+	BaseURL         string    `json:"baseUrl"`
+	ClientID        string    `json:"clientId"`
+	ClientSecretRef string    `json:"-"`
+	EventTypes      []string  `json:"eventTypes"`
+	Status          Status    `json:"status"`
+	CreatedBy       *string   `json:"createdBy,omitempty"`
+	CreatedAt       time.Time `json:"createdAt"`
+	UpdatedAt       time.Time `json:"updatedAt"`
+}
+
+// IDStr satisfies usecase.HasID.
+func (p Peer) IDStr() string { return p.ID }
+
+// New constructs an active peer.
+func New(code, name, baseURL, clientID, clientSecretRef string, eventTypes []string) *Peer {
+	now := time.Now().UTC()
+	return &Peer{
+		ID:              tsid.Generate(tsid.FederationPeer),
+		Code:            code,
+		Name:            name,
+		BaseURL:         baseURL,
+		ClientID:        clientID,
+		ClientSecretRef: clientSecretRef,
+		EventTypes:      eventTypes,
+		Status:          StatusActive,
+		CreatedAt:       now,
+		UpdatedAt:       now,
+	}
+}
+
+// State is the puller's progress through one peer's feed. Received and
+// Skipped are running totals.
+type State struct {
+	PeerID       string
+	Cursor       *string
+	LastPulledAt *time.Time
+	LastError    *string
+	Received     int64
+	Skipped      int64
+}
+
+// Context keys a forwarded event carries. Origin is the instance id the
+// event was first produced on, Hops how many instances it has been
+// forwarded across, and Peer the code of the peer it was pulled from.
+const (
+	ContextOrigin = "federationOrigin"
+	ContextHops   = "federationHops"
+	ContextPeer   = "federationPeer"
+)
+
+// DefaultMaxHops bounds how far an event is forwarded when
+// FC_FEDERATION_MAX_HOPS is unset.
+const DefaultMaxHops = 4
+
+// Provenance returns where an event was first produced and how many hops
+// it has made. An event without federation context was produced on
+// local, the instance serving it, and has made none.
+func Provenance(entries []event.ContextEntry, local string) (origin string, hops int) {
+	origin = local
+	for _, c := range entries {
+		switch c.Key {
+		case ContextOrigin:
+			if c.Value != "" {
+				origin = c.Value
+			}
+		case ContextHops:
+			if n, err := strconv.Atoi(c.Value); err == nil && n > 0 {
+				hops = n
+			}
+		}
+	}
+	return origin, hops
+}
+
+// WithoutProvenance returns entries minus the federation keys.
+func WithoutProvenance(entries []event.ContextEntry) []event.ContextEntry {
+	out := make([]event.ContextEntry, 0, len(entries))
+	for _, c := range entries {
+		switch c.Key {
+		case ContextOrigin, ContextHops, ContextPeer:
+			continue
+		}
+		out = append(out, c)
+	}
+	return out
+}
+
+// Stamp returns entries with the provenance of an event received from
+// peer: its origin, one more hop than the peer reported, and the peer.
+func Stamp(entries []event.ContextEntry, origin string, hops int, peer string) []event.ContextEntry {
+	return append(WithoutProvenance(entries),
+		event.ContextEntry{Key: ContextOrigin, Value: origin},
+		event.ContextEntry{Key: ContextHops, Value: strconv.Itoa(hops + 1)},
+		event.ContextEntry{Key: ContextPeer, Value: peer},
+	)
+}
+
+// Skip reasons reported by Admit.
+const (
+	SkipOwnOrigin = "own origin"
+	SkipTooFar    = "hop limit"
+)
+
+// Admit decides whether instance self ingests an event that arrives with
+// origin and hops: not when it was first produced here (it came back
+// around a cycle of peers), nor when one more hop would exceed maxHops.
+// reason is "" when the event is admitted.
+func Admit(self string, maxHops int, origin string, hops int) (reason string) {
+	if origin != "" && origin == self {
+		return SkipOwnOrigin
+	}
+	if hops+1 > maxHops {
+		return SkipTooFar
+	}
+	return ""
+}
+
+// ErrInvalidCursor is returned by DecodeCursor for a malformed cursor.
+var ErrInvalidCursor = errors.New("invalid feed cursor")
+
+// EncodeCursor renders the feed position after the event created at t
+// with id as the opaque cursor the feed hands out.
+func EncodeCursor(t time.Time, id string) string {
+	raw := strconv.FormatInt(t.UTC().UnixNano(), 10) + ":" + id
+	return base64.RawURLEncoding.EncodeToString([]byte(raw))
+}
+
+// DecodeCursor parses a cursor from EncodeCursor. "" is the start of the
+// feed: the zero time and no id.
+func DecodeCursor(s string) (time.Time, string, error) {
+	if s == "" {
+		return time.Time{}, "", nil
+	}
+	raw, err := base64.RawURLEncoding.DecodeString(s)
+	if err != nil {
+		return time.Time{}, "", ErrInvalidCursor
+	}
+	nanos, id, ok := strings.Cut(string(raw), ":")
+	if !ok || id == "" {
+		return time.Time{}, "", ErrInvalidCursor
+	}
+	n, err := strconv.ParseInt(nanos, 10, 64)
+	if err != nil {
+		return time.Time{}, "", ErrInvalidCursor
+	}
+	return time.Unix(0, n).UTC(), id, nil
+}
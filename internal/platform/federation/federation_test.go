@@ -0,0 +1,62 @@
+package federation
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+)
+
+func TestAdmit(t *testing.T) {
+	assert.Equal(t, "", Admit("central", 4, "eu", 0))
+	assert.Equal(t, "", Admit("central", 4, "eu", 3), "the fourth hop is still within the limit")
+	assert.Equal(t, SkipTooFar, Admit("central", 4, "eu", 4))
+	assert.Equal(t, SkipOwnOrigin, Admit("central", 4, "central", 1), "an event that came back around is dropped")
+	assert.Equal(t, SkipOwnOrigin, Admit("central", 4, "central", 9), "own origin wins over the hop limit")
+}
+
+func TestProvenanceAndStamp(t *testing.T) {
+	local := []event.ContextEntry{{Key: "tenant", Value: "acme"}}
+	origin, hops := Provenance(local, "eu")
+	assert.Equal(t, "eu", origin, "an event without provenance was produced where it is served")
+	assert.Equal(t, 0, hops)
+
+	stamped := Stamp(local, origin, hops, "eu-cluster")
+	assert.Equal(t, []event.ContextEntry{
+		{Key: "tenant", Value: "acme"},
+		{Key: ContextOrigin, Value: "eu"},
+		{Key: ContextHops, Value: "1"},
+		{Key: ContextPeer, Value: "eu-cluster"},
+	}, stamped)
+
+	origin, hops = Provenance(stamped, "central")
+	assert.Equal(t, "eu", origin, "the origin survives forwarding")
+	assert.Equal(t, 1, hops)
+
+	again := Stamp(stamped, origin, hops, "central")
+	assert.Len(t, again, 4, "restamping replaces the keys rather than repeating them")
+	_, hops = Provenance(again, "us")
+	assert.Equal(t, 2, hops)
+	assert.Equal(t, local, WithoutProvenance(again))
+}
+
+func TestCursorRoundTrip(t *testing.T) {
+	at := time.Date(2026, 10, 16, 9, 30, 0, 123456000, time.UTC)
+	gotTime, gotID, err := DecodeCursor(EncodeCursor(at, "0HZX3J5K1M2N3"))
+	require.NoError(t, err)
+	assert.True(t, at.Equal(gotTime))
+	assert.Equal(t, "0HZX3J5K1M2N3", gotID)
+
+	gotTime, gotID, err = DecodeCursor("")
+	require.NoError(t, err)
+	assert.True(t, gotTime.IsZero(), "the empty cursor is the start of the feed")
+	assert.Empty(t, gotID)
+
+	for _, bad := range []string{"!!", "bm8tY29sb24", "MTIzOg", "YWJjOmlk"} {
+		_, _, err := DecodeCursor(bad)
+		assert.ErrorIs(t, err, ErrInvalidCursor, bad)
+	}
+}
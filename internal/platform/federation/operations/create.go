@@ -0,0 +1,125 @@
+package operations
+
+import (
+	"context"
+	"net/url"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/validate"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// CreateCommand is the input DTO. ClientSecretRef arrives already sealed
+// by the API layer.
+type CreateCommand struct {
+	Code            string   `json:"code"`
+	Name            string   `json:"name"`
+	BaseURL         string   `json:"baseUrl"`
+	ClientID        string   `json:"clientId"`
+	ClientSecretRef string   `json:"-"`
+	EventTypes      []string `json:"eventTypes"`
+}
+
+// CreatePeer subscribes this instance to a peer's events of the given
+// types; the puller picks it up on its next tick. Codes are unique. Emits
+// [FederationPeerCreated].
+func CreatePeer(repo *federation.Repository) usecaseop.Operation[CreateCommand, FederationPeerCreated] {
+	return usecaseop.Operation[CreateCommand, FederationPeerCreated]{
+		Name: "CreateFederationPeer",
+		Validate: func(_ context.Context, cmd CreateCommand) error {
+			code := strings.ToLower(strings.TrimSpace(cmd.Code))
+			if code == "" {
+				return usecase.Validation("CODE_REQUIRED", "Peer code is required")
+			}
+			if !validate.CodePattern.MatchString(code) {
+				return usecase.Validation("INVALID_CODE_FORMAT",
+					"Code must start with lowercase letter, contain only lowercase alphanumeric and hyphens")
+			}
+			if strings.TrimSpace(cmd.Name) == "" {
+				return usecase.Validation("NAME_REQUIRED", "Peer name is required")
+			}
+			if strings.TrimSpace(cmd.ClientSecretRef) == "" {
+				return usecase.Validation("CLIENT_SECRET_REQUIRED", "clientSecret is required")
+			}
+			return validateConnection(cmd.BaseURL, cmd.ClientID, cmd.EventTypes)
+		},
+		// Federation is anchor-only; the controller enforces it.
+		Authorize: usecaseop.Public[CreateCommand],
+		Execute: func(ctx context.Context, cmd CreateCommand, ec usecase.ExecutionContext) (usecaseop.Plan[FederationPeerCreated], error) {
+			code := strings.ToLower(strings.TrimSpace(cmd.Code))
+			existing, err := repo.FindByCode(ctx, code)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_code failed", err)
+			}
+			if existing != nil {
+				return nil, usecase.Conflict("CODE_EXISTS",
+					"Federation peer with code '"+code+"' already exists")
+			}
+
+			p := federation.New(code, strings.TrimSpace(cmd.Name), normalizeBaseURL(cmd.BaseURL),
+				strings.TrimSpace(cmd.ClientID), cmd.ClientSecretRef, normalizeEventTypes(cmd.EventTypes))
+			if ec.PrincipalID != "" {
+				p.CreatedBy = &ec.PrincipalID
+			}
+			event := FederationPeerCreated{
+				Metadata:   usecase.NewEventMetadata(ec, FederationPeerCreatedType, Source, subjectFor(p.ID)),
+				PeerID:     p.ID,
+				Code:       p.Code,
+				BaseURL:    p.BaseURL,
+				EventTypes: p.EventTypes,
+			}
+			return usecaseop.Save(p, repo, event), nil
+		},
+	}
+}
+
+// validateConnection checks the fields create and update share: an
+// absolute http(s) base URL, a client id, and at least one four-part
+// event type code.
+func validateConnection(baseURL, clientID string, eventTypes []string) error {
+	u, err := url.Parse(strings.TrimSpace(baseURL))
+	if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+		return usecase.Validation("INVALID_BASE_URL", "baseUrl must be an absolute http(s) URL")
+	}
+	if strings.TrimSpace(clientID) == "" {
+		return usecase.Validation("CLIENT_ID_REQUIRED", "clientId is required")
+	}
+	if len(eventTypes) == 0 {
+		return usecase.Validation("EVENT_TYPES_REQUIRED", "at least one event type is required")
+	}
+	for _, code := range eventTypes {
+		parts := strings.Split(strings.TrimSpace(code), ":")
+		ok := len(parts) == 4
+		for _, p := range parts {
+			ok = ok && p != ""
+		}
+		if !ok {
+			return usecase.Validation("INVALID_EVENT_TYPE",
+				"event type '"+code+"' must follow format: application:subdomain:aggregate:event")
+		}
+	}
+	return nil
+}
+
+// normalizeBaseURL trims whitespace and the trailing slash, so the SDK
+// client can append paths.
+func normalizeBaseURL(s string) string {
+	return strings.TrimRight(strings.TrimSpace(s), "/")
+}
+
+// normalizeEventTypes trims and de-duplicates codes, keeping their order.
+func normalizeEventTypes(codes []string) []string {
+	out := make([]string, 0, len(codes))
+	seen := make(map[string]bool, len(codes))
+	for _, c := range codes {
+		c = strings.TrimSpace(c)
+		if seen[c] {
+			continue
+		}
+		seen[c] = true
+		out = append(out, c)
+	}
+	return out
+}
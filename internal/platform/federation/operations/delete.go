@@ -0,0 +1,47 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// DeleteCommand is the input DTO.
+type DeleteCommand struct {
+	ID string `json:"id"`
+}
+
+// DeletePeer stops pulling from a peer and drops its pull state. Events
+// already received stay. Emits [FederationPeerDeleted].
+func DeletePeer(repo *federation.Repository) usecaseop.Operation[DeleteCommand, FederationPeerDeleted] {
+	return usecaseop.Operation[DeleteCommand, FederationPeerDeleted]{
+		Name: "DeleteFederationPeer",
+		Validate: func(_ context.Context, cmd DeleteCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "id is required")
+			}
+			return nil
+		},
+		// Federation is anchor-only; the controller enforces it.
+		Authorize: usecaseop.Public[DeleteCommand],
+		Execute: func(ctx context.Context, cmd DeleteCommand, ec usecase.ExecutionContext) (usecaseop.Plan[FederationPeerDeleted], error) {
+			p, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if p == nil {
+				return nil, httperror.NotFound("FederationPeer", cmd.ID)
+			}
+			event := FederationPeerDeleted{
+				Metadata: usecase.NewEventMetadata(ec, FederationPeerDeletedType, Source, subjectFor(p.ID)),
+				PeerID:   p.ID,
+				Code:     p.Code,
+			}
+			return usecaseop.Delete(p, repo, event), nil
+		},
+	}
+}
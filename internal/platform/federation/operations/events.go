@@ -0,0 +1,109 @@
+package operations
+
+import (
+	"encoding/json"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+const (
+	FederationPeerCreatedType = "platform:admin:federation-peer:created"
+	FederationPeerUpdatedType = "platform:admin:federation-peer:updated"
+	FederationPeerDeletedType = "platform:admin:federation-peer:deleted"
+	Source                    = "platform:admin"
+)
+
+func subjectFor(id string) string { return "platform.federation-peer." + id }
+
+func groupFor(id string) string { return "platform:federation-peer:" + id }
+
+// FederationPeerCreated is emitted when an instance starts pulling from a
+// peer.
+type FederationPeerCreated struct {
+	Metadata   usecase.EventMetadata
+	PeerID     string
+	Code       string
+	BaseURL    string
+	EventTypes []string
+}
+
+func (e FederationPeerCreated) EventID() string       { return e.Metadata.EventID }
+func (e FederationPeerCreated) EventType() string     { return FederationPeerCreatedType }
+func (e FederationPeerCreated) SpecVersion() string   { return "1.0" }
+func (e FederationPeerCreated) Source() string        { return Source }
+func (e FederationPeerCreated) Subject() string       { return subjectFor(e.PeerID) }
+func (e FederationPeerCreated) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e FederationPeerCreated) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e FederationPeerCreated) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e FederationPeerCreated) CausationID() string   { return e.Metadata.CausationID }
+func (e FederationPeerCreated) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e FederationPeerCreated) MessageGroup() string  { return groupFor(e.PeerID) }
+func (e FederationPeerCreated) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		PeerID     string   `json:"peerId"`
+		Code       string   `json:"code"`
+		BaseURL    string   `json:"baseUrl"`
+		EventTypes []string `json:"eventTypes"`
+	}{e.PeerID, e.Code, e.BaseURL, e.EventTypes})
+}
+
+// FederationPeerUpdated is emitted when a peer's connection details, event
+// types or status change. SecretChanged reports a new client secret
+// without carrying it.
+type FederationPeerUpdated struct {
+	Metadata      usecase.EventMetadata
+	PeerID        string
+	BaseURL       string
+	EventTypes    []string
+	Status        federation.Status
+	SecretChanged bool
+}
+
+func (e FederationPeerUpdated) EventID() string       { return e.Metadata.EventID }
+func (e FederationPeerUpdated) EventType() string     { return FederationPeerUpdatedType }
+func (e FederationPeerUpdated) SpecVersion() string   { return "1.0" }
+func (e FederationPeerUpdated) Source() string        { return Source }
+func (e FederationPeerUpdated) Subject() string       { return subjectFor(e.PeerID) }
+func (e FederationPeerUpdated) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e FederationPeerUpdated) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e FederationPeerUpdated) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e FederationPeerUpdated) CausationID() string   { return e.Metadata.CausationID }
+func (e FederationPeerUpdated) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e FederationPeerUpdated) MessageGroup() string  { return groupFor(e.PeerID) }
+func (e FederationPeerUpdated) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		PeerID        string            `json:"peerId"`
+		BaseURL       string            `json:"baseUrl"`
+		EventTypes    []string          `json:"eventTypes"`
+		Status        federation.Status `json:"status"`
+		SecretChanged bool              `json:"secretChanged"`
+	}{e.PeerID, e.BaseURL, e.EventTypes, e.Status, e.SecretChanged})
+}
+
+// FederationPeerDeleted is emitted when an instance stops pulling from a
+// peer. Events already received stay.
+type FederationPeerDeleted struct {
+	Metadata usecase.EventMetadata
+	PeerID   string
+	Code     string
+}
+
+func (e FederationPeerDeleted) EventID() string       { return e.Metadata.EventID }
+func (e FederationPeerDeleted) EventType() string     { return FederationPeerDeletedType }
+func (e FederationPeerDeleted) SpecVersion() string   { return "1.0" }
+func (e FederationPeerDeleted) Source() string        { return Source }
+func (e FederationPeerDeleted) Subject() string       { return subjectFor(e.PeerID) }
+func (e FederationPeerDeleted) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e FederationPeerDeleted) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e FederationPeerDeleted) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e FederationPeerDeleted) CausationID() string   { return e.Metadata.CausationID }
+func (e FederationPeerDeleted) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e FederationPeerDeleted) MessageGroup() string  { return groupFor(e.PeerID) }
+func (e FederationPeerDeleted) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		PeerID string `json:"peerId"`
+		Code   string `json:"code"`
+	}{e.PeerID, e.Code})
+}
@@ -0,0 +1,172 @@
+//go:build integration
+
+package operations_test
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+func TestMain(m *testing.M) { testpg.RunMain(m) }
+
+// runAuthorized drives op through the full use-case envelope as an anchor
+// principal; the anchor check itself is controller-gated.
+func runAuthorized[C any, E usecase.DomainEvent](
+	uow *usecasepgx.UnitOfWork, op usecaseop.Operation[C, E], cmd C,
+) (E, error) {
+	return usecaseop.Run(testpg.AnchorCtx(), uow, op, cmd, testpg.TestEC())
+}
+
+// createCmd is a valid create command; codes are hand-unique per test
+// since the fixture never truncates between tests.
+func createCmd(code string) operations.CreateCommand {
+	return operations.CreateCommand{
+		Code:            code,
+		Name:            "EU cluster",
+		BaseURL:         "https://eu.example.com/",
+		ClientID:        "oac_eu",
+		ClientSecretRef: "encrypted:c2VhbGVk",
+		EventTypes:      []string{"orders:sales:order:placed", " orders:sales:order:placed "},
+	}
+}
+
+// ── CreatePeer ────────────────────────────────────────────────────────────
+
+func TestCreatePeer_HappyPath(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := federation.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+
+	ev, err := runAuthorized(uow, operations.CreatePeer(repo), createCmd("Fed-Create"))
+	require.NoError(t, err)
+	assert.Equal(t, "fed-create", ev.Code)
+
+	got, err := repo.FindByID(ctx, ev.PeerID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	assert.Equal(t, "https://eu.example.com", got.BaseURL, "trailing slash is trimmed")
+	assert.Equal(t, []string{"orders:sales:order:placed"}, got.EventTypes, "codes are trimmed and de-duplicated")
+	assert.Equal(t, "encrypted:c2VhbGVk", got.ClientSecretRef)
+	assert.Equal(t, federation.StatusActive, got.Status)
+	require.NotNil(t, got.CreatedBy)
+	assert.Equal(t, testpg.TestEC().PrincipalID, *got.CreatedBy)
+
+	_, err = runAuthorized(uow, operations.CreatePeer(repo), createCmd("fed-create"))
+	testpg.RequireUsecaseError(t, err, usecase.KindConflict, "CODE_EXISTS")
+}
+
+func TestCreatePeer_Validation(t *testing.T) {
+	t.Parallel()
+	repo := federation.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+
+	with := func(mut func(*operations.CreateCommand)) operations.CreateCommand {
+		cmd := createCmd("fed-invalid")
+		mut(&cmd)
+		return cmd
+	}
+	cases := []struct {
+		name string
+		cmd  operations.CreateCommand
+		code string
+	}{
+		{"bad code", with(func(c *operations.CreateCommand) { c.Code = "9eu" }), "INVALID_CODE_FORMAT"},
+		{"no name", with(func(c *operations.CreateCommand) { c.Name = " " }), "NAME_REQUIRED"},
+		{"no secret", with(func(c *operations.CreateCommand) { c.ClientSecretRef = "" }), "CLIENT_SECRET_REQUIRED"},
+		{"relative url", with(func(c *operations.CreateCommand) { c.BaseURL = "/eu" }), "INVALID_BASE_URL"},
+		{"ftp url", with(func(c *operations.CreateCommand) { c.BaseURL = "ftp://eu.example.com" }), "INVALID_BASE_URL"},
+		{"no client id", with(func(c *operations.CreateCommand) { c.ClientID = "" }), "CLIENT_ID_REQUIRED"},
+		{"no event types", with(func(c *operations.CreateCommand) { c.EventTypes = nil }), "EVENT_TYPES_REQUIRED"},
+		{"short event type", with(func(c *operations.CreateCommand) { c.EventTypes = []string{"orders:placed"} }), "INVALID_EVENT_TYPE"},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			t.Parallel()
+			_, err := runAuthorized(uow, operations.CreatePeer(repo), tc.cmd)
+			testpg.RequireUsecaseError(t, err, usecase.KindValidation, tc.code)
+		})
+	}
+}
+
+// ── UpdatePeer ────────────────────────────────────────────────────────────
+
+func TestUpdatePeer(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := federation.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	created, err := runAuthorized(uow, operations.CreatePeer(repo), createCmd("fed-update"))
+	require.NoError(t, err)
+
+	update := operations.UpdateCommand{
+		ID:         created.PeerID,
+		Name:       "EU cluster (paused)",
+		BaseURL:    "https://eu2.example.com",
+		ClientID:   "oac_eu2",
+		EventTypes: []string{"orders:sales:order:shipped"},
+		Status:     "PAUSED",
+	}
+	ev, err := runAuthorized(uow, operations.UpdatePeer(repo), update)
+	require.NoError(t, err)
+	assert.False(t, ev.SecretChanged)
+
+	got, err := repo.FindByID(ctx, created.PeerID)
+	require.NoError(t, err)
+	assert.Equal(t, federation.StatusPaused, got.Status)
+	assert.Equal(t, "oac_eu2", got.ClientID)
+	assert.Equal(t, "encrypted:c2VhbGVk", got.ClientSecretRef, "an omitted secret is kept")
+
+	secret := "encrypted:bmV3"
+	update.ClientSecretRef = &secret
+	ev, err = runAuthorized(uow, operations.UpdatePeer(repo), update)
+	require.NoError(t, err)
+	assert.True(t, ev.SecretChanged)
+	got, err = repo.FindByID(ctx, created.PeerID)
+	require.NoError(t, err)
+	assert.Equal(t, secret, got.ClientSecretRef)
+
+	update.Status = "STOPPED"
+	_, err = runAuthorized(uow, operations.UpdatePeer(repo), update)
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_STATUS")
+
+	update.Status, update.ID = "ACTIVE", "fdp_missing00001"
+	_, err = runAuthorized(uow, operations.UpdatePeer(repo), update)
+	testpg.RequireUsecaseError(t, err, usecase.KindNotFound, "FederationPeer_NOT_FOUND")
+}
+
+// ── DeletePeer ────────────────────────────────────────────────────────────
+
+func TestDeletePeer(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := federation.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	created, err := runAuthorized(uow, operations.CreatePeer(repo), createCmd("fed-delete"))
+	require.NoError(t, err)
+	cursor := "c"
+	require.NoError(t, repo.RecordPull(ctx, created.PeerID, &cursor, nil, 3, 1))
+
+	_, err = runAuthorized(uow, operations.DeletePeer(repo), operations.DeleteCommand{ID: created.PeerID})
+	require.NoError(t, err)
+
+	got, err := repo.FindByID(ctx, created.PeerID)
+	require.NoError(t, err)
+	assert.Nil(t, got)
+	states, err := repo.States(ctx)
+	require.NoError(t, err)
+	assert.NotContains(t, states, created.PeerID, "pull state goes with the peer")
+
+	_, err = runAuthorized(uow, operations.DeletePeer(repo), operations.DeleteCommand{ID: created.PeerID})
+	testpg.RequireUsecaseError(t, err, usecase.KindNotFound, "FederationPeer_NOT_FOUND")
+}
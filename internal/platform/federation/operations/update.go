@@ -0,0 +1,78 @@
+package operations
+
+import (
+	"context"
+	"slices"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// UpdateCommand is the input DTO. A nil ClientSecretRef keeps the stored
+// secret; otherwise it arrives already sealed by the API layer.
+type UpdateCommand struct {
+	ID              string   `json:"id"`
+	Name            string   `json:"name"`
+	BaseURL         string   `json:"baseUrl"`
+	ClientID        string   `json:"clientId"`
+	ClientSecretRef *string  `json:"-"`
+	EventTypes      []string `json:"eventTypes"`
+	Status          string   `json:"status"`
+}
+
+// UpdatePeer replaces a peer's connection details, event types and
+// status. The pull cursor is kept: narrowing or widening the event types
+// applies from where the puller is, not from the start of the peer's
+// feed. Emits [FederationPeerUpdated].
+func UpdatePeer(repo *federation.Repository) usecaseop.Operation[UpdateCommand, FederationPeerUpdated] {
+	return usecaseop.Operation[UpdateCommand, FederationPeerUpdated]{
+		Name: "UpdateFederationPeer",
+		Validate: func(_ context.Context, cmd UpdateCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "id is required")
+			}
+			if strings.TrimSpace(cmd.Name) == "" {
+				return usecase.Validation("NAME_REQUIRED", "Peer name is required")
+			}
+			if _, ok := federation.ParseStatus(cmd.Status); !ok {
+				return usecase.Validation("INVALID_STATUS", "status must be ACTIVE or PAUSED")
+			}
+			if cmd.ClientSecretRef != nil && strings.TrimSpace(*cmd.ClientSecretRef) == "" {
+				return usecase.Validation("CLIENT_SECRET_REQUIRED", "clientSecret must not be empty")
+			}
+			return validateConnection(cmd.BaseURL, cmd.ClientID, cmd.EventTypes)
+		},
+		// Federation is anchor-only; the controller enforces it.
+		Authorize: usecaseop.Public[UpdateCommand],
+		Execute: func(ctx context.Context, cmd UpdateCommand, ec usecase.ExecutionContext) (usecaseop.Plan[FederationPeerUpdated], error) {
+			p, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if p == nil {
+				return nil, httperror.NotFound("FederationPeer", cmd.ID)
+			}
+			p.Name = strings.TrimSpace(cmd.Name)
+			p.BaseURL = normalizeBaseURL(cmd.BaseURL)
+			p.ClientID = strings.TrimSpace(cmd.ClientID)
+			p.EventTypes = normalizeEventTypes(cmd.EventTypes)
+			p.Status, _ = federation.ParseStatus(cmd.Status)
+			secretChanged := cmd.ClientSecretRef != nil
+			if secretChanged {
+				p.ClientSecretRef = *cmd.ClientSecretRef
+			}
+			event := FederationPeerUpdated{
+				Metadata:      usecase.NewEventMetadata(ec, FederationPeerUpdatedType, Source, subjectFor(p.ID)),
+				PeerID:        p.ID,
+				BaseURL:       p.BaseURL,
+				EventTypes:    slices.Clone(p.EventTypes),
+				Status:        p.Status,
+				SecretChanged: secretChanged,
+			}
+			return usecaseop.Save(p, repo, event), nil
+		},
+	}
+}
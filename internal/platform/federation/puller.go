@@ -0,0 +1,337 @@
+package federation
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"log/slog"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/secrets"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/auth"
+	fcclient "github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/client"
+)
+
+// PullerConfig tunes the puller.
+type PullerConfig struct {
+	// InstanceID is this instance's FC_FEDERATION_INSTANCE_ID. Required.
+	InstanceID string
+	// MaxHops is how many instances an event may be forwarded across.
+	// Defaults to DefaultMaxHops.
+	MaxHops int
+	// Interval between pulls. Defaults to 5s.
+	Interval time.Duration
+	// PageSize is the limit asked of the peer's feed. Defaults to 100.
+	PageSize int
+	// MaxPages bounds the pages read from one peer per tick, so one busy
+	// peer doesn't starve the others. Defaults to 10.
+	MaxPages int
+}
+
+// PullerDeps are the puller's collaborators.
+type PullerDeps struct {
+	Repo    *Repository
+	Events  *event.Repository
+	Clients *client.Repository
+	// Enc opens secrets sealed inline; Secrets resolves secret-manager
+	// references. Either may be nil when unconfigured.
+	Enc     *encryption.Service
+	Secrets *secrets.Service
+	// Blobs offloads large received payloads like ingest does; nil keeps
+	// them inline.
+	Blobs *blobstore.Offloader
+}
+
+// Puller pulls each active peer's feed and ingests what it receives.
+type Puller struct {
+	deps PullerDeps
+	cfg  PullerConfig
+
+	// IsLeader gates pulling: a cursor has one reader. Nil means always
+	// leader.
+	IsLeader func() bool
+
+	mu      sync.Mutex // guards clients
+	clients map[string]peerClient
+}
+
+// peerClient is a cached SDK client for a peer, valid while the peer is
+// unchanged.
+type peerClient struct {
+	updatedAt time.Time
+	client    *fcclient.FlowCatalystClient
+}
+
+// NewPuller builds a puller.
+func NewPuller(deps PullerDeps, cfg PullerConfig) *Puller {
+	if cfg.MaxHops <= 0 {
+		cfg.MaxHops = DefaultMaxHops
+	}
+	if cfg.Interval <= 0 {
+		cfg.Interval = 5 * time.Second
+	}
+	if cfg.PageSize <= 0 {
+		cfg.PageSize = 100
+	}
+	if cfg.MaxPages <= 0 {
+		cfg.MaxPages = 10
+	}
+	return &Puller{deps: deps, cfg: cfg, clients: map[string]peerClient{}}
+}
+
+// Run pulls every Interval until ctx is cancelled.
+func (p *Puller) Run(ctx context.Context) {
+	slog.Info("federation puller started", "instanceId", p.cfg.InstanceID, "interval", p.cfg.Interval)
+	tick := time.NewTicker(p.cfg.Interval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("federation puller stopped")
+			return
+		case <-tick.C:
+			if p.IsLeader != nil && !p.IsLeader() {
+				continue
+			}
+			p.PullAll(ctx)
+		}
+	}
+}
+
+// PullAll pulls each active peer once. A failing peer is recorded and
+// logged; the others still run.
+func (p *Puller) PullAll(ctx context.Context) {
+	peers, err := p.deps.Repo.FindAll(ctx)
+	if err != nil {
+		slog.Error("federation: load peers failed", "err", err)
+		return
+	}
+	states, err := p.deps.Repo.States(ctx)
+	if err != nil {
+		slog.Error("federation: load pull states failed", "err", err)
+		return
+	}
+	for i := range peers {
+		peer := &peers[i]
+		if peer.Status != StatusActive {
+			continue
+		}
+		var cursor string
+		if st, ok := states[peer.ID]; ok && st.Cursor != nil {
+			cursor = *st.Cursor
+		}
+		next, received, skipped, pullErr := p.pull(ctx, peer, cursor)
+		var lastError *string
+		if pullErr != nil {
+			msg := pullErr.Error()
+			lastError = &msg
+			slog.Warn("federation: pull failed", "peer", peer.Code, "err", pullErr)
+		}
+		var cursorRef *string
+		if next != "" {
+			cursorRef = &next
+		}
+		if err := p.deps.Repo.RecordPull(ctx, peer.ID, cursorRef, lastError, received, skipped); err != nil {
+			slog.Error("federation: record pull failed", "peer", peer.Code, "err", err)
+		}
+	}
+}
+
+// pull reads up to MaxPages of peer's feed from cursor and ingests them.
+// It returns the cursor after the last page ingested, which stays put on
+// a failed page so the page is read again next tick.
+func (p *Puller) pull(ctx context.Context, peer *Peer, cursor string) (next string, received, skipped int, err error) {
+	next = cursor
+	c, err := p.client(ctx, peer)
+	if err != nil {
+		return next, 0, 0, err
+	}
+	identifiers := map[string]*string{}
+	for range p.cfg.MaxPages {
+		page, err := c.Federation().Feed(ctx, fcclient.FederationFeedOptions{
+			Types: peer.EventTypes, After: next, Limit: p.cfg.PageSize,
+		})
+		if err != nil {
+			return next, received, skipped, fmt.Errorf("read feed: %w", err)
+		}
+		if page.InstanceID == p.cfg.InstanceID {
+			return next, received, skipped, errors.New("peer reports this instance's own FC_FEDERATION_INSTANCE_ID")
+		}
+		r, s, err := p.ingest(ctx, peer, page, identifiers)
+		if err != nil {
+			return next, received, skipped, err
+		}
+		received, skipped = received+r, skipped+s
+		if page.Cursor != "" {
+			next = page.Cursor
+		}
+		if !page.HasMore {
+			break
+		}
+	}
+	return next, received, skipped, nil
+}
+
+// ingest stores the page's admitted events that aren't already here.
+func (p *Puller) ingest(ctx context.Context, peer *Peer, page *fcclient.FederationFeedResponse, identifiers map[string]*string) (received, skipped int, err error) {
+	type admitted struct {
+		fe     fcclient.FederatedEvent
+		origin string
+		hops   int
+	}
+	candidates := make([]admitted, 0, len(page.Events))
+	ids := make([]string, 0, len(page.Events))
+	for _, fe := range page.Events {
+		origin := fe.Origin
+		if origin == "" {
+			origin = page.InstanceID
+		}
+		if reason := Admit(p.cfg.InstanceID, p.cfg.MaxHops, origin, fe.Hops); reason != "" {
+			slog.Debug("federation: event skipped", "peer", peer.Code, "eventId", fe.ID, "reason", reason)
+			skipped++
+			continue
+		}
+		candidates = append(candidates, admitted{fe: fe, origin: origin, hops: fe.Hops})
+		ids = append(ids, fe.ID)
+	}
+	existing, err := p.deps.Events.ExistingIDs(ctx, ids)
+	if err != nil {
+		return 0, skipped, fmt.Errorf("check existing events: %w", err)
+	}
+
+	now := time.Now().UTC()
+	events := make([]event.Event, 0, len(candidates))
+	for _, a := range candidates {
+		if existing[a.fe.ID] {
+			skipped++
+			continue
+		}
+		existing[a.fe.ID] = true
+		e, err := p.toEvent(ctx, peer, a.fe, a.origin, a.hops, now, identifiers)
+		if err != nil {
+			return 0, skipped, err
+		}
+		events = append(events, *e)
+	}
+	if _, err := p.deps.Events.InsertBatch(ctx, events); err != nil {
+		return 0, skipped, fmt.Errorf("insert events: %w", err)
+	}
+	return len(events), skipped, nil
+}
+
+// toEvent maps a received event to a local one: the peer's id, time and
+// deduplication id are kept, the client is matched by identifier (an
+// event for a client this instance doesn't have is stored unlinked), and
+// the context gains the provenance Admit reads on the next hop.
+func (p *Puller) toEvent(ctx context.Context, peer *Peer, fe fcclient.FederatedEvent, origin string, hops int, now time.Time, identifiers map[string]*string) (*event.Event, error) {
+	t, err := time.Parse(time.RFC3339Nano, fe.Time)
+	if err != nil {
+		return nil, fmt.Errorf("event %s: bad time %q", fe.ID, fe.Time)
+	}
+	data, err := p.deps.Blobs.Offload(ctx, blobstore.ContentKey(blobstore.EventsPrefix, fe.Data), fe.Data)
+	if err != nil {
+		return nil, fmt.Errorf("event %s: offload data: %w", fe.ID, err)
+	}
+	entries := make([]event.ContextEntry, 0, len(fe.Context)+3)
+	for _, c := range fe.Context {
+		entries = append(entries, event.ContextEntry{Key: c.Key, Value: c.Value})
+	}
+	clientID, err := p.clientID(ctx, fe.ClientIdentifier, identifiers)
+	if err != nil {
+		return nil, err
+	}
+	dedupID := fe.DeduplicationID
+	if dedupID == "" {
+		dedupID = fe.ID
+	}
+	return &event.Event{
+		ID:              fe.ID,
+		SpecVersion:     fe.SpecVersion,
+		Type:            fe.Type,
+		Source:          fe.Source,
+		Subject:         fe.Subject,
+		Time:            t,
+		Data:            data,
+		Context:         Stamp(entries, origin, hops, peer.Code),
+		DeduplicationID: dedupID,
+		ClientID:        clientID,
+		MessageGroup:    optional(fe.MessageGroup),
+		CorrelationID:   optional(fe.CorrelationID),
+		CausationID:     optional(fe.CausationID),
+		CreatedAt:       now,
+	}, nil
+}
+
+// clientID resolves a client identifier to the local client id, once per
+// pull through identifiers.
+func (p *Puller) clientID(ctx context.Context, identifier string, identifiers map[string]*string) (*string, error) {
+	if identifier == "" {
+		return nil, nil
+	}
+	if id, seen := identifiers[identifier]; seen {
+		return id, nil
+	}
+	c, err := p.deps.Clients.FindByIdentifier(ctx, identifier)
+	if err != nil {
+		return nil, fmt.Errorf("client lookup: %w", err)
+	}
+	var id *string
+	if c != nil {
+		id = &c.ID
+	}
+	identifiers[identifier] = id
+	return id, nil
+}
+
+// client returns the SDK client for peer, building it when the peer is
+// new or has changed since.
+func (p *Puller) client(ctx context.Context, peer *Peer) (*fcclient.FlowCatalystClient, error) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if pc, ok := p.clients[peer.ID]; ok && pc.updatedAt.Equal(peer.UpdatedAt) {
+		return pc.client, nil
+	}
+	secret, err := p.openSecret(ctx, peer.ClientSecretRef)
+	if err != nil {
+		return nil, fmt.Errorf("open client secret: %w", err)
+	}
+	tokens := auth.NewClientCredentialsProvider(auth.ClientCredentialsConfig{
+		IssuerURL:    peer.BaseURL,
+		ClientID:     peer.ClientID,
+		ClientSecret: secret,
+	})
+	c := fcclient.New(peer.BaseURL, fcclient.WithTokenProvider(tokens.Token))
+	p.clients[peer.ID] = peerClient{updatedAt: peer.UpdatedAt, client: c}
+	return c, nil
+}
+
+// openSecret reads a stored client secret: sealed inline or a
+// secret-manager reference; anything else is used as is.
+func (p *Puller) openSecret(ctx context.Context, ref string) (string, error) {
+	switch {
+	case strings.HasPrefix(ref, "encrypted:"):
+		if p.deps.Enc == nil {
+			return "", encryption.ErrNotConfigured
+		}
+		return p.deps.Enc.Decrypt(ref)
+	case secrets.IsReference(ref):
+		if p.deps.Secrets == nil {
+			return "", errors.New("secret reference given but no secrets service is configured")
+		}
+		return p.deps.Secrets.Resolve(ctx, ref)
+	}
+	return ref, nil
+}
+
+func optional(s string) *string {
+	if s == "" {
+		return nil
+	}
+	return &s
+}
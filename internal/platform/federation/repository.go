@@ -0,0 +1,135 @@
+package federation
+
+import (
+	"context"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Repository is the Postgres-backed repo. Tables: msg_federation_peers,
+// msg_federation_peer_states.
+type Repository struct {
+	q *dbq.Queries
+}
+
+// NewRepository wires a repo.
+func NewRepository(pool *pgxpool.Pool) *Repository {
+	return &Repository{q: dbq.New(pool)}
+}
+
+// FindByID loads by primary key.
+func (r *Repository) FindByID(ctx context.Context, id string) (*Peer, error) {
+	res, err := r.q.FederationPeerFindByID(ctx, id)
+	row, err := repocommon.One(res, err, "federation_peer repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToPeer(*row), nil
+}
+
+// FindByCode loads by the unique code.
+func (r *Repository) FindByCode(ctx context.Context, code string) (*Peer, error) {
+	res, err := r.q.FederationPeerFindByCode(ctx, code)
+	row, err := repocommon.One(res, err, "federation_peer repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToPeer(*row), nil
+}
+
+// FindAll returns every peer, by code.
+func (r *Repository) FindAll(ctx context.Context) ([]Peer, error) {
+	rows, err := r.q.FederationPeerFindAll(ctx)
+	if err != nil {
+		return nil, err
+	}
+	out := make([]Peer, 0, len(rows))
+	for _, row := range rows {
+		out = append(out, *rowToPeer(row))
+	}
+	return out, nil
+}
+
+// States returns the puller's progress per peer id. A peer never pulled
+// has no entry.
+func (r *Repository) States(ctx context.Context) (map[string]State, error) {
+	rows, err := r.q.FederationPeerStateFindAll(ctx)
+	if err != nil {
+		return nil, err
+	}
+	out := make(map[string]State, len(rows))
+	for _, row := range rows {
+		out[row.PeerID] = State{
+			PeerID:       row.PeerID,
+			Cursor:       row.FeedCursor,
+			LastPulledAt: row.LastPulledAt,
+			LastError:    row.LastError,
+			Received:     row.Received,
+			Skipped:      row.Skipped,
+		}
+	}
+	return out, nil
+}
+
+// RecordPull stores the outcome of one pull of peerID: the cursor reached
+// and the error, if any, replace the stored ones; received and skipped are
+// added to the totals. Written outside the use-case envelope — it is the
+// puller's bookkeeping, not a change anyone makes.
+func (r *Repository) RecordPull(ctx context.Context, peerID string, cursor, lastError *string, received, skipped int) error {
+	now := time.Now().UTC()
+	return r.q.FederationPeerStateUpsert(ctx, dbq.FederationPeerStateUpsertParams{
+		PeerID:       peerID,
+		FeedCursor:   cursor,
+		LastPulledAt: &now,
+		LastError:    lastError,
+		Received:     int64(received),
+		Skipped:      int64(skipped),
+	})
+}
+
+// Persist implements usecasepgx.Persist[Peer].
+func (r *Repository) Persist(ctx context.Context, p *Peer, tx *usecasepgx.DbTx) error {
+	return r.q.WithTx(tx.Inner()).FederationPeerUpsert(ctx, dbq.FederationPeerUpsertParams{
+		ID:              p.ID,
+		Code:            p.Code,
+		Name:            p.Name,
+		BaseUrl:         p.BaseURL,
+		ClientID:        p.ClientID,
+		ClientSecretRef: p.ClientSecretRef,
+		EventTypes:      p.EventTypes,
+		Status:          string(p.Status),
+		CreatedBy:       p.CreatedBy,
+		CreatedAt:       p.CreatedAt,
+		UpdatedAt:       time.Now().UTC(),
+	})
+}
+
+// Delete removes the peer; its pull state goes with it.
+func (r *Repository) Delete(ctx context.Context, p *Peer, tx *usecasepgx.DbTx) error {
+	return r.q.WithTx(tx.Inner()).FederationPeerDelete(ctx, p.ID)
+}
+
+func rowToPeer(row dbq.MsgFederationPeer) *Peer {
+	eventTypes := row.EventTypes
+	if eventTypes == nil {
+		eventTypes = []string{}
+	}
+	return &Peer{
+		ID:              row.ID,
+		Code:            row.Code,
+		Name:            row.Name,
+		BaseURL:         row.BaseUrl,
+		ClientID:        row.ClientID,
+		ClientSecretRef: row.ClientSecretRef,
+		EventTypes:      eventTypes,
+		Status:          Status(row.Status),
+		CreatedBy:       row.CreatedBy,
+		CreatedAt:       row.CreatedAt,
+		UpdatedAt:       row.UpdatedAt,
+	}
+}
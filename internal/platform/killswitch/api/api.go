@@ -0,0 +1,90 @@
+// Package api wires the HTTP routes for incident kill switches via huma.
+package api
+
+import (
+	"context"
+	"net/http"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// State bundles the dependencies.
+type State struct {
+	Repo *killswitch.Repository
+	UoW  *usecasepgx.UnitOfWork
+	// Signal tells every replica's cache a switch flipped; nil leaves them
+	// to their TTL.
+	Signal killswitch.Signal
+}
+
+const tag = "kill-switches"
+
+// Register mounts the kill switch endpoints. All are anchor-only.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "listKillSwitches", "/api/kill-switches", "List engaged kill switches", s.list)
+	apiroute.Post(g, "engageKillSwitch", "/api/kill-switches", "Stop delivery for a client, subscription or dispatch pool", http.StatusCreated, s.engage)
+	apiroute.Delete(g, "releaseKillSwitch", "/api/kill-switches/{id}", "Release a kill switch, resuming delivery", http.StatusNoContent, s.release)
+}
+
+func (s *State) list(ctx context.Context, _ *struct{}) (*apicommon.Out[KillSwitchListResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	rows, err := s.Repo.FindAll(ctx)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_all failed", err)
+	}
+	out := apicommon.MapSlice(rows, fromEntity)
+	return &apicommon.Out[KillSwitchListResponse]{Body: KillSwitchListResponse{KillSwitches: out}}, nil
+}
+
+func (s *State) engage(ctx context.Context, in *apicommon.In[EngageKillSwitchRequest]) (*apicommon.Out[KillSwitchResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	event, err := usecaseop.Run(ctx, s.UoW, operations.EngageKillSwitch(s.Repo), in.Body.toCommand(), ec)
+	if err != nil {
+		return nil, err
+	}
+	s.notify(ctx)
+	k, err := s.Repo.FindByID(ctx, event.KillSwitchID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "post-create reload failed", err)
+	}
+	if k == nil {
+		return nil, httperror.NotFound("KillSwitch", event.KillSwitchID)
+	}
+	return &apicommon.Out[KillSwitchResponse]{Body: fromEntity(k)}, nil
+}
+
+func (s *State) release(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.ReleaseKillSwitch(s.Repo), operations.ReleaseCommand{ID: in.ID}, ec); err != nil {
+		return nil, err
+	}
+	s.notify(ctx)
+	return &apicommon.Empty{}, nil
+}
+
+// notify runs after the commit, so a replica that reloads on the signal
+// sees the change.
+func (s *State) notify(ctx context.Context) {
+	if s.Signal != nil {
+		s.Signal.Notify(ctx)
+	}
+}
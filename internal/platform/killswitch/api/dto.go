@@ -0,0 +1,46 @@
+// dto.go contains the wire-format types for the kill switch API.
+package api
+
+import (
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+)
+
+// EngageKillSwitchRequest is the wire body for POST /api/kill-switches.
+type EngageKillSwitchRequest struct {
+	Scope    string `json:"scope" doc:"What the switch stops: CLIENT, SUBSCRIPTION or POOL"`
+	TargetID string `json:"targetId" doc:"Client, subscription or dispatch pool id, per scope"`
+	Reason   string `json:"reason" doc:"Why delivery is stopped; kept in the audit log"`
+}
+
+func (r EngageKillSwitchRequest) toCommand() operations.EngageCommand {
+	return operations.EngageCommand{Scope: r.Scope, TargetID: r.TargetID, Reason: r.Reason}
+}
+
+// KillSwitchResponse mirrors killswitch.KillSwitch.
+type KillSwitchResponse struct {
+	ID        string          `json:"id"`
+	Scope     string          `json:"scope"`
+	TargetID  string          `json:"targetId"`
+	Reason    string          `json:"reason"`
+	EngagedBy *string         `json:"engagedBy,omitempty" doc:"Principal that engaged the switch"`
+	EngagedAt httpcompat.Time `json:"engagedAt"`
+}
+
+func fromEntity(k *killswitch.KillSwitch) KillSwitchResponse {
+	return KillSwitchResponse{
+		ID:        k.ID,
+		Scope:     string(k.Scope),
+		TargetID:  k.TargetID,
+		Reason:    k.Reason,
+		EngagedBy: k.EngagedBy,
+		EngagedAt: jsontime.New(k.CreatedAt),
+	}
+}
+
+// KillSwitchListResponse is the wire shape for GET /api/kill-switches.
+type KillSwitchListResponse struct {
+	KillSwitches []KillSwitchResponse `json:"killSwitches"`
+}
@@ -0,0 +1,89 @@
+package killswitch
+
+import (
+	"context"
+	"log/slog"
+	"sync"
+	"time"
+)
+
+// DefaultCacheTTL bounds how stale a Cache may get when no Signal reaches
+// it — a replica without Redis, or a write that bypassed the use cases.
+const DefaultCacheTTL = 30 * time.Second
+
+// Cache holds the engaged switches for the hot paths that check every job:
+// the scheduler's poller and the processing callback. It reloads after its
+// TTL, and on the next read after Invalidate — which Listen calls whenever
+// any replica engages or releases a switch.
+type Cache struct {
+	load func(context.Context) ([]KillSwitch, error)
+	ttl  time.Duration
+
+	mu       sync.Mutex
+	set      Set
+	loadedAt time.Time
+}
+
+// NewCache wires a cache over repo.
+func NewCache(repo *Repository, ttl time.Duration) *Cache {
+	return &Cache{load: repo.FindAll, ttl: ttl}
+}
+
+// Snapshot returns the engaged switches, reloading when stale. On a failed
+// reload it returns the last good snapshot with the error, so a caller may
+// keep enforcing what it already knew.
+func (c *Cache) Snapshot(ctx context.Context) (Set, error) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	if !c.loadedAt.IsZero() && time.Since(c.loadedAt) < c.ttl {
+		return c.set, nil
+	}
+	switches, err := c.load(ctx)
+	if err != nil {
+		return c.set, err
+	}
+	next := NewSet(switches)
+	if changed(c.set, next) {
+		// Each change is announced once per replica, so an operator sees in
+		// the logs that deliveries are held (or flowing again) without
+		// querying the API.
+		if next.Empty() {
+			slog.Info("kill switches released; deliveries resume")
+		} else {
+			slog.Warn("kill switches engaged; holding deliveries",
+				"clients", len(next.Clients), "subscriptions", len(next.Subscriptions), "pools", len(next.Pools))
+		}
+	}
+	c.set = next
+	c.loadedAt = time.Now()
+	return next, nil
+}
+
+// Invalidate makes the next Snapshot reload.
+func (c *Cache) Invalidate() {
+	c.mu.Lock()
+	c.loadedAt = time.Time{}
+	c.mu.Unlock()
+}
+
+// Listen invalidates the cache whenever sig reports a change, until ctx is
+// cancelled. Run it in a goroutine.
+func (c *Cache) Listen(ctx context.Context, sig Signal) {
+	sig.Subscribe(ctx, c.Invalidate)
+}
+
+func changed(a, b Set) bool {
+	return !sameKeys(a.Clients, b.Clients) || !sameKeys(a.Subscriptions, b.Subscriptions) || !sameKeys(a.Pools, b.Pools)
+}
+
+func sameKeys(a, b map[string]struct{}) bool {
+	if len(a) != len(b) {
+		return false
+	}
+	for k := range a {
+		if _, ok := b[k]; !ok {
+			return false
+		}
+	}
+	return true
+}
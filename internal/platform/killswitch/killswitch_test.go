@@ -0,0 +1,63 @@
+package killswitch
+
+import (
+	"context"
+	"errors"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestSetStops(t *testing.T) {
+	s := NewSet([]KillSwitch{
+		{Scope: ScopeClient, TargetID: "clt_a"},
+		{Scope: ScopeSubscription, TargetID: "sub_b"},
+		{Scope: ScopePool, TargetID: "dpl_c"},
+	})
+	assert.True(t, s.Stops("clt_a", "sub_x", "dpl_x"))
+	assert.True(t, s.Stops("clt_x", "sub_b", "dpl_x"))
+	assert.True(t, s.Stops("clt_x", "sub_x", "dpl_c"))
+	assert.False(t, s.Stops("clt_x", "sub_x", "dpl_x"))
+	assert.False(t, s.Stops("", "", ""), "a NULL column matches no switch")
+	assert.False(t, s.Stops("sub_b", "clt_a", ""), "ids only match their own scope")
+
+	assert.True(t, Set{}.Empty())
+	assert.False(t, Set{}.Stops("clt_a", "sub_b", "dpl_c"))
+}
+
+func TestCacheReloadsWhenInvalidated(t *testing.T) {
+	ctx := context.Background()
+	engaged := []KillSwitch{{Scope: ScopeClient, TargetID: "clt_a"}}
+	var loads int
+	var failNext bool
+	c := &Cache{ttl: time.Hour, load: func(context.Context) ([]KillSwitch, error) {
+		loads++
+		if failNext {
+			return nil, errors.New("db down")
+		}
+		return engaged, nil
+	}}
+
+	s, err := c.Snapshot(ctx)
+	require.NoError(t, err)
+	assert.True(t, s.Stops("clt_a", "", ""))
+	_, _ = c.Snapshot(ctx)
+	assert.Equal(t, 1, loads, "a fresh snapshot is served from memory")
+
+	engaged = nil
+	c.Invalidate()
+	s, err = c.Snapshot(ctx)
+	require.NoError(t, err)
+	assert.True(t, s.Empty(), "a release is seen on the read after Invalidate")
+
+	engaged = []KillSwitch{{Scope: ScopePool, TargetID: "dpl_c"}}
+	c.Invalidate()
+	_, _ = c.Snapshot(ctx)
+	failNext = true
+	c.Invalidate()
+	s, err = c.Snapshot(ctx)
+	require.Error(t, err)
+	assert.True(t, s.Stops("", "", "dpl_c"), "a failed reload keeps the last good snapshot")
+}
@@ -0,0 +1,94 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// MaxReasonLength bounds the free-text reason.
+const MaxReasonLength = 1000
+
+// EngageCommand is the input DTO.
+type EngageCommand struct {
+	// Scope is CLIENT, SUBSCRIPTION or POOL.
+	Scope    string `json:"scope"`
+	TargetID string `json:"targetId"`
+	// Reason is shown next to the switch and kept in the audit log.
+	Reason string `json:"reason"`
+}
+
+// EngageKillSwitch stops delivery for a client, subscription or dispatch
+// pool until the switch is released. A target has at most one switch.
+// Emits [KillSwitchEngaged].
+func EngageKillSwitch(repo *killswitch.Repository) usecaseop.Operation[EngageCommand, KillSwitchEngaged] {
+	return usecaseop.Operation[EngageCommand, KillSwitchEngaged]{
+		Name: "EngageKillSwitch",
+		Validate: func(_ context.Context, cmd EngageCommand) error {
+			if _, ok := killswitch.ParseScope(cmd.Scope); !ok {
+				return usecase.Validation("INVALID_SCOPE", "scope must be CLIENT, SUBSCRIPTION or POOL")
+			}
+			if strings.TrimSpace(cmd.TargetID) == "" {
+				return usecase.Validation("TARGET_ID_REQUIRED", "targetId is required")
+			}
+			reason := strings.TrimSpace(cmd.Reason)
+			if reason == "" {
+				return usecase.Validation("REASON_REQUIRED", "reason is required")
+			}
+			if len(reason) > MaxReasonLength {
+				return usecase.Validation("REASON_TOO_LONG", "reason must be at most 1000 characters")
+			}
+			return nil
+		},
+		// Kill switches are anchor-only; the controller enforces it.
+		Authorize: usecaseop.Public[EngageCommand],
+		Execute: func(ctx context.Context, cmd EngageCommand, ec usecase.ExecutionContext) (usecaseop.Plan[KillSwitchEngaged], error) {
+			scope, _ := killswitch.ParseScope(cmd.Scope)
+			targetID := strings.TrimSpace(cmd.TargetID)
+			exists, err := repo.TargetExists(ctx, scope, targetID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "target lookup failed", err)
+			}
+			if !exists {
+				return nil, httperror.NotFound(targetKind(scope), targetID)
+			}
+			existing, err := repo.FindByTarget(ctx, scope, targetID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_target failed", err)
+			}
+			if existing != nil {
+				return nil, usecase.Conflict("ALREADY_ENGAGED",
+					"A kill switch is already engaged for "+string(scope)+" '"+targetID+"'")
+			}
+
+			k := killswitch.New(scope, targetID, strings.TrimSpace(cmd.Reason))
+			if ec.PrincipalID != "" {
+				k.EngagedBy = &ec.PrincipalID
+			}
+			event := KillSwitchEngaged{
+				Metadata:     usecase.NewEventMetadata(ec, KillSwitchEngagedType, Source, subjectFor(k.ID)),
+				KillSwitchID: k.ID,
+				Scope:        k.Scope,
+				TargetID:     k.TargetID,
+				Reason:       k.Reason,
+			}
+			return usecaseop.Save(k, repo, event), nil
+		},
+	}
+}
+
+// targetKind names a scope's target in a not-found error.
+func targetKind(scope killswitch.Scope) string {
+	switch scope {
+	case killswitch.ScopeClient:
+		return "Client"
+	case killswitch.ScopeSubscription:
+		return "Subscription"
+	default:
+		return "DispatchPool"
+	}
+}
@@ -0,0 +1,82 @@
+package operations
+
+import (
+	"encoding/json"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+const (
+	KillSwitchEngagedType  = "platform:admin:kill-switch:engaged"
+	KillSwitchReleasedType = "platform:admin:kill-switch:released"
+	Source                 = "platform:admin"
+)
+
+func subjectFor(id string) string { return "platform.kill-switch." + id }
+
+// groupFor orders the switches of one target together: an engage and the
+// release that follows must be seen in that order.
+func groupFor(scope killswitch.Scope, targetID string) string {
+	return "platform:kill-switch:" + string(scope) + ":" + targetID
+}
+
+// KillSwitchEngaged is emitted when delivery for a target is stopped.
+type KillSwitchEngaged struct {
+	Metadata     usecase.EventMetadata
+	KillSwitchID string
+	Scope        killswitch.Scope
+	TargetID     string
+	Reason       string
+}
+
+func (e KillSwitchEngaged) EventID() string       { return e.Metadata.EventID }
+func (e KillSwitchEngaged) EventType() string     { return KillSwitchEngagedType }
+func (e KillSwitchEngaged) SpecVersion() string   { return "1.0" }
+func (e KillSwitchEngaged) Source() string        { return Source }
+func (e KillSwitchEngaged) Subject() string       { return subjectFor(e.KillSwitchID) }
+func (e KillSwitchEngaged) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e KillSwitchEngaged) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e KillSwitchEngaged) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e KillSwitchEngaged) CausationID() string   { return e.Metadata.CausationID }
+func (e KillSwitchEngaged) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e KillSwitchEngaged) MessageGroup() string  { return groupFor(e.Scope, e.TargetID) }
+func (e KillSwitchEngaged) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		KillSwitchID string           `json:"killSwitchId"`
+		Scope        killswitch.Scope `json:"scope"`
+		TargetID     string           `json:"targetId"`
+		Reason       string           `json:"reason"`
+	}{e.KillSwitchID, e.Scope, e.TargetID, e.Reason})
+}
+
+// KillSwitchReleased is emitted when delivery for a target resumes.
+type KillSwitchReleased struct {
+	Metadata     usecase.EventMetadata
+	KillSwitchID string
+	Scope        killswitch.Scope
+	TargetID     string
+	// EngagedFor is how long the switch was engaged.
+	EngagedFor time.Duration
+}
+
+func (e KillSwitchReleased) EventID() string       { return e.Metadata.EventID }
+func (e KillSwitchReleased) EventType() string     { return KillSwitchReleasedType }
+func (e KillSwitchReleased) SpecVersion() string   { return "1.0" }
+func (e KillSwitchReleased) Source() string        { return Source }
+func (e KillSwitchReleased) Subject() string       { return subjectFor(e.KillSwitchID) }
+func (e KillSwitchReleased) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e KillSwitchReleased) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e KillSwitchReleased) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e KillSwitchReleased) CausationID() string   { return e.Metadata.CausationID }
+func (e KillSwitchReleased) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e KillSwitchReleased) MessageGroup() string  { return groupFor(e.Scope, e.TargetID) }
+func (e KillSwitchReleased) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		KillSwitchID      string           `json:"killSwitchId"`
+		Scope             killswitch.Scope `json:"scope"`
+		TargetID          string           `json:"targetId"`
+		EngagedForSeconds int64            `json:"engagedForSeconds"`
+	}{e.KillSwitchID, e.Scope, e.TargetID, int64(e.EngagedFor / time.Second)})
+}
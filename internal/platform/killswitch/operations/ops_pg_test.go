@@ -0,0 +1,118 @@
+//go:build integration
+
+package operations_test
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+func TestMain(m *testing.M) { testpg.RunMain(m) }
+
+// runAuthorized drives op through the full use-case envelope as an anchor
+// principal; the anchor check itself is controller-gated.
+func runAuthorized[C any, E usecase.DomainEvent](
+	uow *usecasepgx.UnitOfWork, op usecaseop.Operation[C, E], cmd C,
+) (E, error) {
+	return usecaseop.Run(testpg.AnchorCtx(), uow, op, cmd, testpg.TestEC())
+}
+
+// seedClient inserts a bare client row to aim switches at. Ids are
+// hand-unique per test: the fixture never truncates between tests.
+func seedClient(t *testing.T, id string) {
+	t.Helper()
+	_, err := testpg.Pool(t).Exec(context.Background(),
+		`INSERT INTO tnt_clients (id, name, identifier) VALUES ($1, $1, $1) ON CONFLICT DO NOTHING`, id)
+	require.NoError(t, err)
+}
+
+// ── EngageKillSwitch ──────────────────────────────────────────────────────
+
+func TestEngageKillSwitch_HappyPath(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := killswitch.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seedClient(t, "clt_ksEngage0001")
+
+	ev, err := runAuthorized(uow, operations.EngageKillSwitch(repo), operations.EngageCommand{
+		Scope: "CLIENT", TargetID: "clt_ksEngage0001", Reason: "  receiver melting down  ",
+	})
+	require.NoError(t, err)
+	assert.Equal(t, killswitch.ScopeClient, ev.Scope)
+	assert.Equal(t, "receiver melting down", ev.Reason)
+
+	got, err := repo.FindByTarget(ctx, killswitch.ScopeClient, "clt_ksEngage0001")
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	assert.Equal(t, ev.KillSwitchID, got.ID)
+	require.NotNil(t, got.EngagedBy)
+	assert.Equal(t, testpg.TestEC().PrincipalID, *got.EngagedBy)
+
+	_, err = runAuthorized(uow, operations.EngageKillSwitch(repo), operations.EngageCommand{
+		Scope: "CLIENT", TargetID: "clt_ksEngage0001", Reason: "again",
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindConflict, "ALREADY_ENGAGED")
+}
+
+func TestEngageKillSwitch_Validation(t *testing.T) {
+	t.Parallel()
+	repo := killswitch.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+
+	cases := []struct {
+		name string
+		cmd  operations.EngageCommand
+		kind usecase.Kind
+		code string
+	}{
+		{"bad scope", operations.EngageCommand{Scope: "TENANT", TargetID: "x", Reason: "r"}, usecase.KindValidation, "INVALID_SCOPE"},
+		{"no target", operations.EngageCommand{Scope: "CLIENT", TargetID: " ", Reason: "r"}, usecase.KindValidation, "TARGET_ID_REQUIRED"},
+		{"no reason", operations.EngageCommand{Scope: "CLIENT", TargetID: "x", Reason: " "}, usecase.KindValidation, "REASON_REQUIRED"},
+		{"unknown target", operations.EngageCommand{Scope: "SUBSCRIPTION", TargetID: "sub_ksMissing001", Reason: "r"}, usecase.KindNotFound, "Subscription_NOT_FOUND"},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			t.Parallel()
+			_, err := runAuthorized(uow, operations.EngageKillSwitch(repo), tc.cmd)
+			testpg.RequireUsecaseError(t, err, tc.kind, tc.code)
+		})
+	}
+}
+
+// ── ReleaseKillSwitch ─────────────────────────────────────────────────────
+
+func TestReleaseKillSwitch(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := killswitch.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seedClient(t, "clt_ksRelease001")
+	engaged, err := runAuthorized(uow, operations.EngageKillSwitch(repo), operations.EngageCommand{
+		Scope: "CLIENT", TargetID: "clt_ksRelease001", Reason: "incident",
+	})
+	require.NoError(t, err)
+
+	ev, err := runAuthorized(uow, operations.ReleaseKillSwitch(repo),
+		operations.ReleaseCommand{ID: engaged.KillSwitchID})
+	require.NoError(t, err)
+	assert.Equal(t, "clt_ksRelease001", ev.TargetID)
+
+	got, err := repo.FindByID(ctx, engaged.KillSwitchID)
+	require.NoError(t, err)
+	assert.Nil(t, got, "released switch must be gone")
+
+	_, err = runAuthorized(uow, operations.ReleaseKillSwitch(repo),
+		operations.ReleaseCommand{ID: engaged.KillSwitchID})
+	testpg.RequireUsecaseError(t, err, usecase.KindNotFound, "KillSwitch_NOT_FOUND")
+}
@@ -0,0 +1,50 @@
+package operations
+
+import (
+	"context"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// ReleaseCommand is the input DTO.
+type ReleaseCommand struct {
+	ID string `json:"id"`
+}
+
+// ReleaseKillSwitch removes a switch; the target's held jobs are picked up
+// by the next poll. Emits [KillSwitchReleased].
+func ReleaseKillSwitch(repo *killswitch.Repository) usecaseop.Operation[ReleaseCommand, KillSwitchReleased] {
+	return usecaseop.Operation[ReleaseCommand, KillSwitchReleased]{
+		Name: "ReleaseKillSwitch",
+		Validate: func(_ context.Context, cmd ReleaseCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "id is required")
+			}
+			return nil
+		},
+		// Kill switches are anchor-only; the controller enforces it.
+		Authorize: usecaseop.Public[ReleaseCommand],
+		Execute: func(ctx context.Context, cmd ReleaseCommand, ec usecase.ExecutionContext) (usecaseop.Plan[KillSwitchReleased], error) {
+			k, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if k == nil {
+				return nil, httperror.NotFound("KillSwitch", cmd.ID)
+			}
+			event := KillSwitchReleased{
+				Metadata:     usecase.NewEventMetadata(ec, KillSwitchReleasedType, Source, subjectFor(k.ID)),
+				KillSwitchID: k.ID,
+				Scope:        k.Scope,
+				TargetID:     k.TargetID,
+				EngagedFor:   time.Since(k.CreatedAt),
+			}
+			return usecaseop.Delete(k, repo, event), nil
+		},
+	}
+}
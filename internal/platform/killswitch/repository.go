@@ -0,0 +1,107 @@
+package killswitch
+
+import (
+	"context"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Repository is the Postgres-backed repo. Table: msg_kill_switches.
+type Repository struct {
+	pool *pgxpool.Pool // retained for TargetExists
+	q    *dbq.Queries
+}
+
+// NewRepository wires a repo.
+func NewRepository(pool *pgxpool.Pool) *Repository {
+	return &Repository{pool: pool, q: dbq.New(pool)}
+}
+
+// FindByID loads by primary key.
+func (r *Repository) FindByID(ctx context.Context, id string) (*KillSwitch, error) {
+	res, err := r.q.KillSwitchFindByID(ctx, id)
+	row, err := repocommon.One(res, err, "kill_switch repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToKillSwitch(*row), nil
+}
+
+// FindByTarget returns the switch engaged on (scope, targetID), if any.
+func (r *Repository) FindByTarget(ctx context.Context, scope Scope, targetID string) (*KillSwitch, error) {
+	res, err := r.q.KillSwitchFindByTarget(ctx, dbq.KillSwitchFindByTargetParams{
+		Scope: string(scope), TargetID: targetID,
+	})
+	row, err := repocommon.One(res, err, "kill_switch repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToKillSwitch(*row), nil
+}
+
+// FindAll returns every engaged switch, newest first.
+func (r *Repository) FindAll(ctx context.Context) ([]KillSwitch, error) {
+	rows, err := r.q.KillSwitchFindAll(ctx)
+	if err != nil {
+		return nil, err
+	}
+	out := make([]KillSwitch, 0, len(rows))
+	for _, row := range rows {
+		out = append(out, *rowToKillSwitch(row))
+	}
+	return out, nil
+}
+
+// targetTables maps a scope to the table its target ids live in.
+var targetTables = map[Scope]string{
+	ScopeClient:       "tnt_clients",
+	ScopeSubscription: "msg_subscriptions",
+	ScopePool:         "msg_dispatch_pools",
+}
+
+// TargetExists reports whether targetID names a row of scope's table, so
+// a mistyped id is refused rather than silently stopping nothing.
+func (r *Repository) TargetExists(ctx context.Context, scope Scope, targetID string) (bool, error) {
+	table, ok := targetTables[scope]
+	if !ok {
+		return false, nil
+	}
+	var exists bool
+	err := r.pool.QueryRow(ctx, `SELECT EXISTS (SELECT 1 FROM `+table+` WHERE id = $1)`, targetID).Scan(&exists)
+	return exists, err
+}
+
+// Persist implements usecasepgx.Persist[KillSwitch].
+func (r *Repository) Persist(ctx context.Context, k *KillSwitch, tx *usecasepgx.DbTx) error {
+	return r.q.WithTx(tx.Inner()).KillSwitchUpsert(ctx, dbq.KillSwitchUpsertParams{
+		ID:        k.ID,
+		Scope:     string(k.Scope),
+		TargetID:  k.TargetID,
+		Reason:    k.Reason,
+		EngagedBy: k.EngagedBy,
+		CreatedAt: k.CreatedAt,
+		UpdatedAt: time.Now().UTC(),
+	})
+}
+
+// Delete removes the row, releasing the switch.
+func (r *Repository) Delete(ctx context.Context, k *KillSwitch, tx *usecasepgx.DbTx) error {
+	return r.q.WithTx(tx.Inner()).KillSwitchDelete(ctx, k.ID)
+}
+
+func rowToKillSwitch(row dbq.MsgKillSwitch) *KillSwitch {
+	return &KillSwitch{
+		ID:        row.ID,
+		Scope:     Scope(row.Scope),
+		TargetID:  row.TargetID,
+		Reason:    row.Reason,
+		EngagedBy: row.EngagedBy,
+		CreatedAt: row.CreatedAt,
+		UpdatedAt: row.UpdatedAt,
+	}
+}
@@ -0,0 +1,102 @@
+package killswitch
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"os"
+	"time"
+
+	"github.com/redis/go-redis/v9"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/redisconn"
+)
+
+// changedChannel carries one message per engaged or released switch.
+const changedChannel = "fc:ks:changed"
+
+// redisConnectTimeout bounds the startup PING.
+const redisConnectTimeout = 2 * time.Second
+
+// Signal tells every replica's Cache that a switch flipped, so an engaged
+// switch takes effect within a poll tick instead of a cache TTL.
+type Signal interface {
+	// Notify announces a change. Best-effort: a lost message only delays
+	// the change until the caches' TTL.
+	Notify(ctx context.Context)
+	// Subscribe calls fn for each change announced by any replica, until
+	// ctx is cancelled.
+	Subscribe(ctx context.Context, fn func())
+}
+
+// NoopSignal announces nothing; caches converge by TTL.
+type NoopSignal struct{}
+
+// Notify does nothing.
+func (NoopSignal) Notify(context.Context) {}
+
+// Subscribe blocks until ctx is cancelled; there are no peers to hear from.
+func (NoopSignal) Subscribe(ctx context.Context, _ func()) { <-ctx.Done() }
+
+// BuildSignal selects the backend: Redis pub/sub when FC_REDIS_URL is set
+// and reachable (PING within a short timeout), else NoopSignal. The choice
+// is logged at startup.
+func BuildSignal(ctx context.Context) Signal {
+	url := os.Getenv("FC_REDIS_URL")
+	if url == "" {
+		slog.Info("FC_REDIS_URL not set; kill switches propagate by cache TTL", "ttl", DefaultCacheTTL)
+		return NoopSignal{}
+	}
+	s, err := NewRedisSignal(ctx, url)
+	if err != nil {
+		slog.Warn("FC_REDIS_URL set but Redis unreachable; kill switches propagate by cache TTL", "ttl", DefaultCacheTTL, "err", err)
+		return NoopSignal{}
+	}
+	slog.Info("kill switch propagation: Redis pub/sub")
+	return s
+}
+
+// RedisSignal publishes changes on one channel every replica subscribes to.
+type RedisSignal struct {
+	client redis.UniversalClient
+}
+
+// NewRedisSignal connects, PINGs to confirm liveness, and returns the signal.
+func NewRedisSignal(ctx context.Context, url string) (*RedisSignal, error) {
+	client, err := redisconn.Open(url)
+	if err != nil {
+		return nil, fmt.Errorf("invalid redis url: %w", err)
+	}
+	pingCtx, cancel := context.WithTimeout(ctx, redisConnectTimeout)
+	defer cancel()
+	if err := client.Ping(pingCtx).Err(); err != nil {
+		_ = client.Close()
+		return nil, fmt.Errorf("redis ping: %w", err)
+	}
+	return &RedisSignal{client: client}, nil
+}
+
+// Notify publishes a change.
+func (s *RedisSignal) Notify(ctx context.Context) {
+	if err := s.client.Publish(ctx, changedChannel, "1").Err(); err != nil {
+		slog.Warn("killswitch: change broadcast failed", "err", err)
+	}
+}
+
+// Subscribe relays changes published by any replica, this one included.
+func (s *RedisSignal) Subscribe(ctx context.Context, fn func()) {
+	sub := s.client.Subscribe(ctx, changedChannel)
+	defer func() { _ = sub.Close() }()
+	ch := sub.Channel()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case _, ok := <-ch:
+			if !ok {
+				return
+			}
+			fn()
+		}
+	}
+}
@@ -158,6 +158,27 @@ func (p Principal) IsUser() bool { return p.Type == TypeUser }
 // IsService reports whether this principal is a SERVICE account.
 func (p Principal) IsService() bool { return p.Type == TypeService }
 
+// Seeded administrator role names. Holders count as admin-scope for
+// per-client authentication policy (client.Client.RequirePasskeyForAdmins).
+const (
+	RoleSuperAdmin  = "platform:super-admin"
+	RoleClientAdmin = "platform:client-admin"
+)
+
+// IsAdminScope reports whether p administers the platform or a tenant: an
+// anchor-scope user, or a holder of the super-admin or client-admin role.
+func (p Principal) IsAdminScope() bool {
+	if p.Scope == ScopeAnchor {
+		return true
+	}
+	for _, r := range p.Roles {
+		if r.Role == RoleSuperAdmin || r.Role == RoleClientAdmin {
+			return true
+		}
+	}
+	return false
+}
+
 // NewUser constructs a USER-type Principal with the supplied email/scope.
 func NewUser(email string, scope UserScope) *Principal {
 	now := time.Now().UTC()
@@ -0,0 +1,21 @@
+package principal_test
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+)
+
+func TestIsAdminScope(t *testing.T) {
+	anchor := principal.NewUser("root@example.com", principal.ScopeAnchor)
+	member := principal.NewUser("member@example.com", principal.ScopeClient)
+	clientAdmin := principal.NewUser("admin@example.com", principal.ScopeClient)
+	clientAdmin.Roles = []serviceaccount.RoleAssignment{{Role: principal.RoleClientAdmin}}
+
+	assert.True(t, anchor.IsAdminScope())
+	assert.True(t, clientAdmin.IsAdminScope())
+	assert.False(t, member.IsAdminScope(), "non-admins keep the password path")
+}
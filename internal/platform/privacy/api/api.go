@@ -0,0 +1,87 @@
+// Package api wires HTTP routes for data-subject erasure via huma.
+package api
+
+import (
+	"context"
+	"net/http"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// State bundles deps.
+type State struct {
+	Repo *privacy.Repository
+	UoW  *usecasepgx.UnitOfWork
+}
+
+const tag = "privacy"
+
+// Register mounts the erasure endpoints. All are anchor-only.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Post(g, "requestErasure", "/api/admin/privacy/erasure", "Request erasure of a data subject", http.StatusAccepted, s.request)
+	apiroute.Get(g, "listErasureRequests", "/api/admin/privacy/erasure", "List recent erasure requests", s.list)
+	apiroute.Get(g, "getErasureRequest", "/api/admin/privacy/erasure/{id}", "Get an erasure request and its report", s.getByID)
+}
+
+func (s *State) request(ctx context.Context, in *apicommon.In[RequestErasureRequest]) (*apicommon.Out[ErasureRequestResponse], error) {
+	// Anchor-only authorization runs inside the use case's Authorize phase.
+	ec := auth.NewExecutionContext(ctx)
+	event, err := usecaseop.Run(ctx, s.UoW, operations.RequestErasure(s.Repo), in.Body.toCommand(), ec)
+	if err != nil {
+		return nil, err
+	}
+	req, err := s.Repo.FindByID(ctx, event.ErasureRequestID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if req == nil {
+		return nil, usecase.Internal("REPO", "erasure request missing after create", nil)
+	}
+	return &apicommon.Out[ErasureRequestResponse]{Body: requestFromEntity(req)}, nil
+}
+
+type listInput struct {
+	Limit int `query:"limit" doc:"Maximum requests to return (default 50, max 200)"`
+}
+
+func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[ErasureRequestListResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	limit := in.Limit
+	if limit <= 0 {
+		limit = 50
+	}
+	limit = min(limit, 200)
+	rows, err := s.Repo.List(ctx, limit)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "list failed", err)
+	}
+	out := apicommon.MapSlice(rows, requestFromEntity)
+	return &apicommon.Out[ErasureRequestListResponse]{Body: ErasureRequestListResponse{Items: out}}, nil
+}
+
+func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[ErasureRequestResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	req, err := s.Repo.FindByID(ctx, in.ID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if req == nil {
+		return nil, httperror.NotFound("ErasureRequest", in.ID)
+	}
+	return &apicommon.Out[ErasureRequestResponse]{Body: requestFromEntity(req)}, nil
+}
@@ -0,0 +1,117 @@
+// dto.go contains the wire-format types for the privacy API.
+package api
+
+import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+)
+
+// RequestErasureRequest is the wire body for POST /api/admin/privacy/erasure.
+type RequestErasureRequest struct {
+	Subject     string   `json:"subject" doc:"Subject identifier to erase; for subjectType CLIENT, the client id"`
+	SubjectType *string  `json:"subjectType,omitempty" doc:"SUBJECT (default) or CLIENT"`
+	ContextKeys []string `json:"contextKeys,omitempty" doc:"Event context keys that also hold the identifier (SUBJECT only)"`
+	ClientID    *string  `json:"clientId,omitempty" doc:"Restrict a SUBJECT erasure to one client"`
+}
+
+func (r RequestErasureRequest) toCommand() operations.RequestErasureCommand {
+	subjectType := string(privacy.SubjectTypeSubject)
+	if r.SubjectType != nil {
+		subjectType = *r.SubjectType
+	}
+	return operations.RequestErasureCommand{
+		SubjectType: subjectType,
+		Subject:     r.Subject,
+		ContextKeys: r.ContextKeys,
+		ClientID:    r.ClientID,
+	}
+}
+
+// ErasureRemainingResponse mirrors privacy.Remaining.
+type ErasureRemainingResponse struct {
+	Events           int64 `json:"events"`
+	EventReads       int64 `json:"eventReads"`
+	DispatchJobs     int64 `json:"dispatchJobs"`
+	DispatchJobReads int64 `json:"dispatchJobReads"`
+	DataKeys         int64 `json:"dataKeys"`
+}
+
+// ErasureReportResponse mirrors privacy.Report.
+type ErasureReportResponse struct {
+	Passes                 int                      `json:"passes"`
+	EventsErased           int64                    `json:"eventsErased"`
+	EventReadsErased       int64                    `json:"eventReadsErased"`
+	DispatchJobsErased     int64                    `json:"dispatchJobsErased"`
+	DispatchJobReadsErased int64                    `json:"dispatchJobReadsErased"`
+	DataKeysDestroyed      int64                    `json:"dataKeysDestroyed"`
+	Remaining              ErasureRemainingResponse `json:"remaining"`
+	VerifiedAt             *httpcompat.Time         `json:"verifiedAt,omitempty"`
+}
+
+// ErasureRequestResponse mirrors privacy.ErasureRequest. The subject
+// identifier itself is never returned.
+type ErasureRequestResponse struct {
+	ID          string                `json:"id"`
+	SubjectType string                `json:"subjectType"`
+	SubjectHash string                `json:"subjectHash" doc:"SHA-256 (hex) of the subject identifier"`
+	ContextKeys []string              `json:"contextKeys"`
+	ClientID    *string               `json:"clientId,omitempty"`
+	Status      string                `json:"status" doc:"PENDING, IN_PROGRESS, COMPLETED or FAILED"`
+	Report      ErasureReportResponse `json:"report"`
+	Error       *string               `json:"error,omitempty"`
+	RequestedBy *string               `json:"requestedBy,omitempty"`
+	CreatedAt   httpcompat.Time       `json:"createdAt"`
+	StartedAt   *httpcompat.Time      `json:"startedAt,omitempty"`
+	CompletedAt *httpcompat.Time      `json:"completedAt,omitempty"`
+	UpdatedAt   httpcompat.Time       `json:"updatedAt"`
+}
+
+func requestFromEntity(r *privacy.ErasureRequest) ErasureRequestResponse {
+	tp := func(t *time.Time) *httpcompat.Time {
+		if t == nil {
+			return nil
+		}
+		v := jsontime.New(*t)
+		return &v
+	}
+	rem := r.Report.Remaining
+	return ErasureRequestResponse{
+		ID:          r.ID,
+		SubjectType: string(r.SubjectType),
+		SubjectHash: r.SubjectHash,
+		ContextKeys: r.ContextKeys,
+		ClientID:    r.ClientID,
+		Status:      string(r.Status),
+		Report: ErasureReportResponse{
+			Passes:                 r.Report.Passes,
+			EventsErased:           r.Report.EventsErased,
+			EventReadsErased:       r.Report.EventReadsErased,
+			DispatchJobsErased:     r.Report.DispatchJobsErased,
+			DispatchJobReadsErased: r.Report.DispatchJobReadsErased,
+			DataKeysDestroyed:      r.Report.DataKeysDestroyed,
+			Remaining: ErasureRemainingResponse{
+				Events:           rem.Events,
+				EventReads:       rem.EventReads,
+				DispatchJobs:     rem.DispatchJobs,
+				DispatchJobReads: rem.DispatchJobReads,
+				DataKeys:         rem.DataKeys,
+			},
+			VerifiedAt: tp(r.Report.VerifiedAt),
+		},
+		Error:       r.Error,
+		RequestedBy: r.RequestedBy,
+		CreatedAt:   jsontime.New(r.CreatedAt),
+		StartedAt:   tp(r.StartedAt),
+		CompletedAt: tp(r.CompletedAt),
+		UpdatedAt:   jsontime.New(r.UpdatedAt),
+	}
+}
+
+// ErasureRequestListResponse is the wire shape for GET /api/admin/privacy/erasure.
+type ErasureRequestListResponse struct {
+	Items []ErasureRequestResponse `json:"items"`
+}
@@ -0,0 +1,147 @@
+// Package privacy implements data-subject erasure: a request names a
+// subject identifier, and the erasure worker redacts every event, dispatch
+// job and read projection that carries it, keeping a progress and
+// verification report on the request row (aud_erasure_requests).
+//
+// Two kinds of subject are supported:
+//
+//   - SUBJECT: an identifier matched against the CloudEvents subject of
+//     events and dispatch jobs, and optionally against named event context
+//     entries (e.g. "customerId"). Matching rows have their payload,
+//     context and subject cleared.
+//   - CLIENT: a whole tenant. Its payload data keys are destroyed
+//     (crypto-shredding — sealed payloads in backups and replicas become
+//     unreadable) and its rows are redacted as above.
+//
+// Rows still in flight (events not yet projected or fanned out, dispatch
+// jobs not yet terminal) are left for a later pass, so erasure never hands
+// the pipeline a payload it has already started delivering.
+package privacy
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// SubjectType says what an erasure request's subject identifies.
+type SubjectType string
+
+const (
+	SubjectTypeSubject SubjectType = "SUBJECT"
+	SubjectTypeClient  SubjectType = "CLIENT"
+)
+
+// ParseSubjectType is the lenient parser. Unknown → SUBJECT.
+func ParseSubjectType(s string) SubjectType {
+	if s == string(SubjectTypeClient) {
+		return SubjectTypeClient
+	}
+	return SubjectTypeSubject
+}
+
+// Status is the request lifecycle.
+type Status string
+
+const (
+	// StatusPending: accepted, no pass run yet.
+	StatusPending Status = "PENDING"
+	// StatusInProgress: at least one pass ran but matching rows remain
+	// (typically rows still in flight).
+	StatusInProgress Status = "IN_PROGRESS"
+	// StatusCompleted: a verification pass found nothing left.
+	StatusCompleted Status = "COMPLETED"
+	// StatusFailed: a pass errored; the error is on the request.
+	StatusFailed Status = "FAILED"
+)
+
+// ParseStatus is the lenient parser. Unknown → PENDING.
+func ParseStatus(s string) Status {
+	switch Status(s) {
+	case StatusInProgress, StatusCompleted, StatusFailed:
+		return Status(s)
+	}
+	return StatusPending
+}
+
+// IsTerminal reports whether the worker is done with the request.
+func (s Status) IsTerminal() bool { return s == StatusCompleted || s == StatusFailed }
+
+// MaxContextKeys bounds the context keys one request may match on; each
+// key is a separate index probe per batch.
+const MaxContextKeys = 10
+
+// Report is the cumulative progress of a request plus the result of the
+// most recent verification.
+type Report struct {
+	Passes                 int       `json:"passes"`
+	EventsErased           int64     `json:"eventsErased"`
+	EventReadsErased       int64     `json:"eventReadsErased"`
+	DispatchJobsErased     int64     `json:"dispatchJobsErased"`
+	DispatchJobReadsErased int64     `json:"dispatchJobReadsErased"`
+	DataKeysDestroyed      int64     `json:"dataKeysDestroyed"`
+	Remaining              Remaining `json:"remaining"`
+	// VerifiedAt is when Remaining was last counted.
+	VerifiedAt *time.Time `json:"verifiedAt,omitempty"`
+}
+
+// Remaining counts rows that still carry the subject.
+type Remaining struct {
+	Events           int64 `json:"events"`
+	EventReads       int64 `json:"eventReads"`
+	DispatchJobs     int64 `json:"dispatchJobs"`
+	DispatchJobReads int64 `json:"dispatchJobReads"`
+	DataKeys         int64 `json:"dataKeys"`
+}
+
+// Zero reports whether nothing remains.
+func (r Remaining) Zero() bool { return r == Remaining{} }
+
+// ErasureRequest is one aud_erasure_requests row. Subject is the raw
+// identifier and is nil once the request is terminal; SubjectHash stays.
+type ErasureRequest struct {
+	ID          string      `json:"id"`
+	SubjectType SubjectType `json:"subjectType"`
+	Subject     *string     `json:"-"`
+	SubjectHash string      `json:"subjectHash"`
+	ContextKeys []string    `json:"contextKeys"`
+	ClientID    *string     `json:"clientId,omitempty"`
+	Status      Status      `json:"status"`
+	Report      Report      `json:"report"`
+	Error       *string     `json:"error,omitempty"`
+	RequestedBy *string     `json:"requestedBy,omitempty"`
+	CreatedAt   time.Time   `json:"createdAt"`
+	StartedAt   *time.Time  `json:"startedAt,omitempty"`
+	CompletedAt *time.Time  `json:"completedAt,omitempty"`
+	UpdatedAt   time.Time   `json:"updatedAt"`
+}
+
+// IDStr satisfies usecase.HasID.
+func (r ErasureRequest) IDStr() string { return r.ID }
+
+// NewErasureRequest constructs a PENDING request.
+func NewErasureRequest(subjectType SubjectType, subject string, contextKeys []string, clientID *string) *ErasureRequest {
+	now := time.Now().UTC()
+	if contextKeys == nil {
+		contextKeys = []string{}
+	}
+	return &ErasureRequest{
+		ID:          tsid.Generate(tsid.ErasureRequest),
+		SubjectType: subjectType,
+		Subject:     &subject,
+		SubjectHash: HashSubject(subject),
+		ContextKeys: contextKeys,
+		ClientID:    clientID,
+		Status:      StatusPending,
+		CreatedAt:   now,
+		UpdatedAt:   now,
+	}
+}
+
+// HashSubject is the digest stored alongside (and after) the identifier.
+func HashSubject(subject string) string {
+	sum := sha256.Sum256([]byte(subject))
+	return hex.EncodeToString(sum[:])
+}
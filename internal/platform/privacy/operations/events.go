@@ -0,0 +1,50 @@
+// Package operations holds the privacy use cases.
+package operations
+
+import (
+	"encoding/json"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+const (
+	ErasureRequestedType = "platform:admin:erasure:requested"
+	Source               = "platform:admin"
+)
+
+func subjectFor(id string) string { return "platform.erasurerequest." + id }
+func groupFor(id string) string   { return "platform:erasurerequest:" + id }
+
+// ErasureRequested is emitted when an erasure request is accepted. It
+// carries the subject's digest, never the identifier itself: the event
+// outlives the erasure.
+type ErasureRequested struct {
+	Metadata         usecase.EventMetadata
+	ErasureRequestID string
+	SubjectType      string
+	SubjectHash      string
+	ContextKeys      []string
+	ClientID         *string
+}
+
+func (e ErasureRequested) EventID() string       { return e.Metadata.EventID }
+func (e ErasureRequested) EventType() string     { return ErasureRequestedType }
+func (e ErasureRequested) SpecVersion() string   { return "1.0" }
+func (e ErasureRequested) Source() string        { return Source }
+func (e ErasureRequested) Subject() string       { return subjectFor(e.ErasureRequestID) }
+func (e ErasureRequested) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e ErasureRequested) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e ErasureRequested) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e ErasureRequested) CausationID() string   { return e.Metadata.CausationID }
+func (e ErasureRequested) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e ErasureRequested) MessageGroup() string  { return groupFor(e.ErasureRequestID) }
+func (e ErasureRequested) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		ErasureRequestID string   `json:"erasureRequestId"`
+		SubjectType      string   `json:"subjectType"`
+		SubjectHash      string   `json:"subjectHash"`
+		ContextKeys      []string `json:"contextKeys"`
+		ClientID         *string  `json:"clientId,omitempty"`
+	}{e.ErasureRequestID, e.SubjectType, e.SubjectHash, e.ContextKeys, e.ClientID})
+}
@@ -0,0 +1,84 @@
+package operations
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// RequestErasureCommand is the input DTO. Subject is excluded from JSON so
+// the identifier never reaches the audit log's command snapshot.
+type RequestErasureCommand struct {
+	SubjectType string   `json:"subjectType"`
+	Subject     string   `json:"-"`
+	ContextKeys []string `json:"contextKeys"`
+	ClientID    *string  `json:"clientId,omitempty"`
+}
+
+// RequestErasure records a PENDING erasure request and emits
+// [ErasureRequested]. The erasure worker does the work asynchronously.
+// Anchor-only: erasure crosses every tenant's data.
+func RequestErasure(repo *privacy.Repository) usecaseop.Operation[RequestErasureCommand, ErasureRequested] {
+	return usecaseop.Operation[RequestErasureCommand, ErasureRequested]{
+		Name: "RequestErasure",
+		Validate: func(_ context.Context, cmd RequestErasureCommand) error {
+			if strings.TrimSpace(cmd.Subject) == "" {
+				return usecase.Validation("SUBJECT_REQUIRED", "subject is required")
+			}
+			switch privacy.SubjectType(cmd.SubjectType) {
+			case privacy.SubjectTypeSubject:
+				if len(cmd.ContextKeys) > privacy.MaxContextKeys {
+					return usecase.Validation("TOO_MANY_CONTEXT_KEYS",
+						fmt.Sprintf("at most %d context keys are allowed", privacy.MaxContextKeys))
+				}
+				for _, k := range cmd.ContextKeys {
+					if strings.TrimSpace(k) == "" {
+						return usecase.Validation("INVALID_CONTEXT_KEY", "context keys must not be blank")
+					}
+				}
+			case privacy.SubjectTypeClient:
+				if len(cmd.ContextKeys) > 0 || cmd.ClientID != nil {
+					return usecase.Validation("INVALID_CLIENT_ERASURE",
+						"a CLIENT erasure takes the client id as its subject and no contextKeys or clientId")
+				}
+			default:
+				return usecase.Validation("INVALID_SUBJECT_TYPE", "subjectType must be SUBJECT or CLIENT")
+			}
+			return nil
+		},
+		Authorize: func(ctx context.Context, _ RequestErasureCommand) error {
+			return auth.RequireAnchor(auth.FromContext(ctx))
+		},
+		Execute: func(_ context.Context, cmd RequestErasureCommand, ec usecase.ExecutionContext) (usecaseop.Plan[ErasureRequested], error) {
+			keys := make([]string, 0, len(cmd.ContextKeys))
+			seen := map[string]bool{}
+			for _, k := range cmd.ContextKeys {
+				k = strings.TrimSpace(k)
+				if !seen[k] {
+					seen[k] = true
+					keys = append(keys, k)
+				}
+			}
+			req := privacy.NewErasureRequest(privacy.SubjectType(cmd.SubjectType), strings.TrimSpace(cmd.Subject), keys, cmd.ClientID)
+			if ec.PrincipalID != "" {
+				by := ec.PrincipalID
+				req.RequestedBy = &by
+			}
+
+			event := ErasureRequested{
+				Metadata:         usecase.NewEventMetadata(ec, ErasureRequestedType, Source, subjectFor(req.ID)),
+				ErasureRequestID: req.ID,
+				SubjectType:      string(req.SubjectType),
+				SubjectHash:      req.SubjectHash,
+				ContextKeys:      req.ContextKeys,
+				ClientID:         req.ClientID,
+			}
+			return usecaseop.Save(req, repo, event), nil
+		},
+	}
+}
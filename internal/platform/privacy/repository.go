@@ -0,0 +1,114 @@
+package privacy
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Repository is the Postgres-backed repo for aud_erasure_requests.
+type Repository struct{ q *dbq.Queries }
+
+// NewRepository wires a repo.
+func NewRepository(pool *pgxpool.Pool) *Repository {
+	return &Repository{q: dbq.New(pool)}
+}
+
+// FindByID loads a request by id.
+func (r *Repository) FindByID(ctx context.Context, id string) (*ErasureRequest, error) {
+	res, err := r.q.ErasureRequestFindByID(ctx, id)
+	row, err := repocommon.One(res, err, "erasure_request repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return rowToRequest(*row)
+}
+
+// List returns the most recent requests, newest first.
+func (r *Repository) List(ctx context.Context, limit int) ([]ErasureRequest, error) {
+	rows, err := r.q.ErasureRequestList(ctx, int32(limit))
+	if err != nil {
+		return nil, err
+	}
+	out := make([]ErasureRequest, 0, len(rows))
+	for _, row := range rows {
+		req, err := rowToRequest(row)
+		if err != nil {
+			return nil, err
+		}
+		out = append(out, *req)
+	}
+	return out, nil
+}
+
+// Persist implements usecasepgx.Persist[ErasureRequest].
+func (r *Repository) Persist(ctx context.Context, req *ErasureRequest, tx *usecasepgx.DbTx) error {
+	params, err := upsertParams(req)
+	if err != nil {
+		return err
+	}
+	return r.q.WithTx(tx.Inner()).ErasureRequestUpsert(ctx, params)
+}
+
+// Delete implements usecasepgx.Persist[ErasureRequest].Delete. Requests
+// are the compliance record of an erasure and are never deleted.
+func (r *Repository) Delete(_ context.Context, _ *ErasureRequest, _ *usecasepgx.DbTx) error {
+	return errors.New("erasure requests cannot be deleted")
+}
+
+func upsertParams(req *ErasureRequest) (dbq.ErasureRequestUpsertParams, error) {
+	report, err := json.Marshal(req.Report)
+	if err != nil {
+		return dbq.ErasureRequestUpsertParams{}, fmt.Errorf("marshal erasure report: %w", err)
+	}
+	return dbq.ErasureRequestUpsertParams{
+		ID:          req.ID,
+		SubjectType: string(req.SubjectType),
+		Subject:     req.Subject,
+		SubjectHash: req.SubjectHash,
+		ContextKeys: append([]string{}, req.ContextKeys...),
+		ClientID:    req.ClientID,
+		Status:      string(req.Status),
+		Report:      report,
+		Error:       req.Error,
+		RequestedBy: req.RequestedBy,
+		CreatedAt:   req.CreatedAt,
+		StartedAt:   req.StartedAt,
+		CompletedAt: req.CompletedAt,
+		UpdatedAt:   req.UpdatedAt,
+	}, nil
+}
+
+func rowToRequest(row dbq.AudErasureRequest) (*ErasureRequest, error) {
+	req := &ErasureRequest{
+		ID:          row.ID,
+		SubjectType: ParseSubjectType(row.SubjectType),
+		Subject:     row.Subject,
+		SubjectHash: row.SubjectHash,
+		ContextKeys: row.ContextKeys,
+		ClientID:    row.ClientID,
+		Status:      ParseStatus(row.Status),
+		Error:       row.Error,
+		RequestedBy: row.RequestedBy,
+		CreatedAt:   row.CreatedAt,
+		StartedAt:   row.StartedAt,
+		CompletedAt: row.CompletedAt,
+		UpdatedAt:   row.UpdatedAt,
+	}
+	if req.ContextKeys == nil {
+		req.ContextKeys = []string{}
+	}
+	if len(row.Report) > 0 {
+		if err := json.Unmarshal(row.Report, &req.Report); err != nil {
+			return nil, fmt.Errorf("decode erasure report %s: %w", row.ID, err)
+		}
+	}
+	return req, nil
+}
@@ -0,0 +1,447 @@
+package privacy
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"log/slog"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/payloadcrypt"
+)
+
+// Worker drains open erasure requests. Each tick it claims every PENDING or
+// IN_PROGRESS request not yet touched this tick (FOR UPDATE SKIP LOCKED, so
+// any number of instances can run it), runs one bounded pass, re-counts
+// what is left and records the result. A request completes on the first
+// pass whose verification finds nothing remaining; until then it stays
+// IN_PROGRESS and is retried next tick.
+type Worker struct {
+	pool *pgxpool.Pool
+	keys *payloadcrypt.PgKeyStore
+
+	Config WorkerConfig
+}
+
+// WorkerConfig tunes the worker.
+type WorkerConfig struct {
+	BatchSize    int           // rows per statement (default 500)
+	MaxBatches   int           // statements per step per pass (default 20)
+	TickInterval time.Duration // pass cadence (default 30s)
+}
+
+// DefaultWorkerConfig returns the defaults.
+func DefaultWorkerConfig() WorkerConfig {
+	return WorkerConfig{BatchSize: 500, MaxBatches: 20, TickInterval: 30 * time.Second}
+}
+
+// NewWorker wires a worker with the default config.
+func NewWorker(pool *pgxpool.Pool) *Worker {
+	return &Worker{pool: pool, keys: payloadcrypt.NewPgKeyStore(pool)}
+}
+
+func (w *Worker) cfg() WorkerConfig {
+	c := w.Config
+	d := DefaultWorkerConfig()
+	if c.BatchSize <= 0 {
+		c.BatchSize = d.BatchSize
+	}
+	if c.MaxBatches <= 0 {
+		c.MaxBatches = d.MaxBatches
+	}
+	if c.TickInterval <= 0 {
+		c.TickInterval = d.TickInterval
+	}
+	return c
+}
+
+// Run ticks once on startup, then every Config.TickInterval, until ctx is
+// cancelled.
+func (w *Worker) Run(ctx context.Context) {
+	cfg := w.cfg()
+	slog.Info("erasure worker started")
+	w.tick(ctx)
+
+	tick := time.NewTicker(cfg.TickInterval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("erasure worker stopped")
+			return
+		case <-tick.C:
+			w.tick(ctx)
+		}
+	}
+}
+
+func (w *Worker) tick(ctx context.Context) {
+	start := time.Now().UTC()
+	for ctx.Err() == nil {
+		ok, err := w.ProcessNext(ctx, start)
+		if err != nil {
+			slog.Warn("erasure worker pass failed", "err", err)
+			return
+		}
+		if !ok {
+			return
+		}
+	}
+}
+
+const claimRequestSQL = `
+SELECT id, subject_type, subject, context_keys, client_id, report, started_at
+  FROM aud_erasure_requests
+ WHERE status IN ('PENDING', 'IN_PROGRESS') AND updated_at < $1
+ ORDER BY created_at
+ LIMIT 1
+   FOR UPDATE SKIP LOCKED`
+
+const finishRequestSQL = `
+UPDATE aud_erasure_requests
+   SET status = $2, report = $3, error = $4, started_at = $5,
+       completed_at = $6, updated_at = $7,
+       subject = CASE WHEN $2 IN ('COMPLETED', 'FAILED') THEN NULL ELSE subject END
+ WHERE id = $1`
+
+// ProcessNext claims one open request last updated before `before`, runs a
+// pass over it and records the outcome. It reports false when there was
+// nothing to claim. The request row stays locked for the duration of the
+// pass so no other instance works the same request. A failing pass marks
+// the request FAILED rather than returning an error; the error return is
+// for failures to claim or record.
+func (w *Worker) ProcessNext(ctx context.Context, before time.Time) (bool, error) {
+	tx, err := w.pool.Begin(ctx)
+	if err != nil {
+		return false, err
+	}
+	defer func() { _ = tx.Rollback(ctx) }()
+
+	var (
+		req    ErasureRequest
+		st     string
+		report []byte
+	)
+	err = tx.QueryRow(ctx, claimRequestSQL, before).Scan(
+		&req.ID, &st, &req.Subject, &req.ContextKeys, &req.ClientID, &report, &req.StartedAt)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return false, nil
+	}
+	if err != nil {
+		return false, fmt.Errorf("claim: %w", err)
+	}
+	req.SubjectType = ParseSubjectType(st)
+	if len(report) > 0 {
+		if err := json.Unmarshal(report, &req.Report); err != nil {
+			return false, fmt.Errorf("decode report %s: %w", req.ID, err)
+		}
+	}
+
+	now := time.Now().UTC()
+	if req.StartedAt == nil {
+		req.StartedAt = &now
+	}
+	status, errMsg := StatusInProgress, (*string)(nil)
+	if err := w.Pass(ctx, &req); err != nil {
+		status = StatusFailed
+		msg := err.Error()
+		errMsg = &msg
+		slog.Warn("erasure pass failed", "erasure_request_id", req.ID, "err", err)
+	} else if req.Report.Remaining.Zero() {
+		status = StatusCompleted
+	}
+	var completedAt *time.Time
+	if status.IsTerminal() {
+		done := time.Now().UTC()
+		completedAt = &done
+	}
+
+	out, err := json.Marshal(req.Report)
+	if err != nil {
+		return false, fmt.Errorf("encode report %s: %w", req.ID, err)
+	}
+	if _, err := tx.Exec(ctx, finishRequestSQL,
+		req.ID, string(status), out, errMsg, req.StartedAt, completedAt, time.Now().UTC()); err != nil {
+		return false, fmt.Errorf("record %s: %w", req.ID, err)
+	}
+	if err := tx.Commit(ctx); err != nil {
+		return false, err
+	}
+	slog.Info("erasure pass", "erasure_request_id", req.ID, "status", status,
+		"events_erased", req.Report.EventsErased, "dispatch_jobs_erased", req.Report.DispatchJobsErased)
+	return true, nil
+}
+
+// Pass runs one bounded erasure sweep for req, adds what it did to
+// req.Report and then re-counts what remains. Exposed for tests and
+// one-off runs.
+func (w *Worker) Pass(ctx context.Context, req *ErasureRequest) error {
+	if req.Subject == nil || *req.Subject == "" {
+		return errors.New("request has no subject (already closed?)")
+	}
+	m, err := newMatcher(req)
+	if err != nil {
+		return err
+	}
+	rep := &req.Report
+	rep.Passes++
+
+	if req.SubjectType == SubjectTypeClient {
+		n, err := w.keys.DestroyClient(ctx, *req.Subject)
+		rep.DataKeysDestroyed += n
+		if err != nil {
+			return fmt.Errorf("destroy data keys: %w", err)
+		}
+	}
+	// Events first: a batch takes its (terminal) dispatch jobs with it, so
+	// jobs only linked to the subject through their event are not lost once
+	// the event's subject and context are cleared.
+	if err := w.repeat(ctx, m, eraseEventsSQL,
+		&rep.EventsErased, &rep.EventReadsErased, &rep.DispatchJobsErased, &rep.DispatchJobReadsErased); err != nil {
+		return fmt.Errorf("erase events: %w", err)
+	}
+	if err := w.repeat(ctx, m, eraseJobsSQL,
+		&rep.DispatchJobsErased, &rep.DispatchJobReadsErased); err != nil {
+		return fmt.Errorf("erase dispatch jobs: %w", err)
+	}
+	if err := w.repeat(ctx, m, eraseEventReadsSQL, &rep.EventReadsErased); err != nil {
+		return fmt.Errorf("erase event projections: %w", err)
+	}
+	if err := w.repeat(ctx, m, eraseJobReadsSQL, &rep.DispatchJobReadsErased); err != nil {
+		return fmt.Errorf("erase dispatch job projections: %w", err)
+	}
+
+	remaining, err := w.verify(ctx, req, m)
+	if err != nil {
+		return fmt.Errorf("verify: %w", err)
+	}
+	now := time.Now().UTC()
+	rep.Remaining = remaining
+	rep.VerifiedAt = &now
+	return nil
+}
+
+func (w *Worker) verify(ctx context.Context, req *ErasureRequest, m *matcher) (Remaining, error) {
+	var r Remaining
+	sql, args := m.render(verifySQL, 0)
+	if err := w.pool.QueryRow(ctx, sql, args...).Scan(
+		&r.Events, &r.EventReads, &r.DispatchJobs, &r.DispatchJobReads); err != nil {
+		return r, err
+	}
+	if req.SubjectType == SubjectTypeClient {
+		keys, err := w.keys.List(ctx, *req.Subject)
+		if err != nil {
+			return r, err
+		}
+		r.DataKeys = int64(len(keys))
+	}
+	return r, nil
+}
+
+// repeat runs a batched statement until its first count falls below
+// BatchSize or MaxBatches is reached, adding each returned count to the
+// matching counter.
+func (w *Worker) repeat(ctx context.Context, m *matcher, tmpl string, counters ...*int64) error {
+	cfg := w.cfg()
+	sql, args := m.render(tmpl, cfg.BatchSize)
+	got := make([]int64, len(counters))
+	dest := make([]any, len(counters))
+	for i := range got {
+		dest[i] = &got[i]
+	}
+	for i := 0; i < cfg.MaxBatches; i++ {
+		if err := w.pool.QueryRow(ctx, sql, args...).Scan(dest...); err != nil {
+			return err
+		}
+		for j, c := range counters {
+			*c += got[j]
+		}
+		if got[0] < int64(cfg.BatchSize) {
+			return nil
+		}
+	}
+	return nil
+}
+
+// matcher holds the per-request predicates spliced into the statement
+// templates below. Only SQL fragments are spliced; every value goes
+// through a bind parameter, numbered per statement in order of appearance
+// so each statement binds exactly the parameters it references.
+type matcher struct {
+	parts map[string]func(b *binder) string
+}
+
+type binder struct{ args []any }
+
+func (b *binder) bind(v any) string {
+	b.args = append(b.args, v)
+	return "$" + strconv.Itoa(len(b.args))
+}
+
+var placeholder = regexp.MustCompile(`\{[A-Za-z]+\}`)
+
+// render expands a template's {placeholders}; {limit} binds limit.
+func (m *matcher) render(tmpl string, limit int) (string, []any) {
+	b := &binder{}
+	sql := placeholder.ReplaceAllStringFunc(tmpl, func(tok string) string {
+		name := tok[1 : len(tok)-1]
+		if name == "limit" {
+			return b.bind(limit)
+		}
+		return m.parts[name](b)
+	})
+	return sql, b.args
+}
+
+func newMatcher(req *ErasureRequest) (*matcher, error) {
+	subject := *req.Subject
+	m := &matcher{parts: map[string]func(b *binder) string{}}
+
+	if req.SubjectType == SubjectTypeClient {
+		m.parts["event"] = func(b *binder) string {
+			return `e.client_id = ` + b.bind(subject) +
+				` AND (e.data IS NOT NULL OR e.subject IS NOT NULL OR e.context_data IS NOT NULL)`
+		}
+		m.parts["eventRead"] = func(b *binder) string {
+			return `r.client_id = ` + b.bind(subject) + ` AND (r.data IS NOT NULL OR r.subject IS NOT NULL)`
+		}
+		m.parts["job"] = func(b *binder) string {
+			return `j.client_id = ` + b.bind(subject) + ` AND (j.payload IS NOT NULL OR j.subject IS NOT NULL)`
+		}
+		m.parts["jobRead"] = func(b *binder) string {
+			return `r.client_id = ` + b.bind(subject) + ` AND r.subject IS NOT NULL`
+		}
+		for _, alias := range []string{"E", "J", "R"} {
+			m.parts["clear"+alias] = func(*binder) string { return `NULL` }
+		}
+		return m, nil
+	}
+
+	// One containment probe per context key so each can use the GIN index
+	// on context_data (migration 043).
+	probes := make([]string, 0, len(req.ContextKeys))
+	for _, k := range req.ContextKeys {
+		probe, err := json.Marshal([]map[string]string{{"key": k, "value": subject}})
+		if err != nil {
+			return nil, err
+		}
+		probes = append(probes, string(probe))
+	}
+	scope := func(alias string, b *binder) string {
+		if req.ClientID == nil {
+			return ``
+		}
+		return ` AND ` + alias + `.client_id = ` + b.bind(*req.ClientID)
+	}
+	m.parts["event"] = func(b *binder) string {
+		alts := []string{`e.subject = ` + b.bind(subject)}
+		for _, p := range probes {
+			alts = append(alts, `e.context_data @> `+b.bind(p)+`::jsonb`)
+		}
+		return `(` + strings.Join(alts, ` OR `) + `)` + scope("e", b)
+	}
+	m.parts["eventRead"] = func(b *binder) string { return `r.subject = ` + b.bind(subject) + scope("r", b) }
+	m.parts["job"] = func(b *binder) string { return `j.subject = ` + b.bind(subject) + scope("j", b) }
+	m.parts["jobRead"] = func(b *binder) string { return `r.subject = ` + b.bind(subject) + scope("r", b) }
+	for _, alias := range []string{"e", "j", "r"} {
+		m.parts["clear"+strings.ToUpper(alias)] = func(b *binder) string {
+			return `CASE WHEN ` + alias + `.subject = ` + b.bind(subject) + ` THEN NULL ELSE ` + alias + `.subject END`
+		}
+	}
+	return m, nil
+}
+
+// Statement templates. The write tables are partitioned on (id,
+// created_at), so batches are picked as (id, created_at) pairs and joined
+// back on both; read rows share their source row's id. Events are only
+// taken once projected and fanned out with every dispatch job terminal,
+// and jobs only once terminal (common.DispatchStatus.IsTerminal), so the
+// pipeline never sees a row change under it. Attempt response bodies are
+// cleared too: receivers often echo the payload.
+const (
+	eraseEventsSQL = `
+WITH batch AS (
+    SELECT e.id, e.created_at FROM msg_events e
+     WHERE {event}
+       AND e.projected_at IS NOT NULL AND e.fanned_out_at IS NOT NULL
+       AND NOT EXISTS (
+           SELECT 1 FROM msg_dispatch_jobs j
+            WHERE j.event_id = e.id
+              AND j.status NOT IN ('COMPLETED', 'FAILED', 'CANCELLED', 'EXPIRED'))
+     LIMIT {limit}
+), jobs AS (
+    UPDATE msg_dispatch_jobs j SET payload = NULL, subject = {clearJ}, updated_at = NOW()
+      FROM batch b WHERE j.event_id = b.id
+    RETURNING j.id
+), attempts AS (
+    UPDATE msg_dispatch_job_attempts a SET response_body = NULL
+     WHERE a.dispatch_job_id IN (SELECT id FROM jobs) AND a.response_body IS NOT NULL
+), job_reads AS (
+    UPDATE msg_dispatch_jobs_read r SET subject = {clearR}
+      FROM jobs WHERE r.id = jobs.id
+    RETURNING 1
+), event_reads AS (
+    UPDATE msg_events_read r SET data = NULL, subject = {clearR}
+      FROM batch b WHERE r.id = b.id AND r.created_at = b.created_at
+    RETURNING 1
+), events AS (
+    UPDATE msg_events e SET data = NULL, context_data = NULL, subject = {clearE}
+      FROM batch b WHERE e.id = b.id AND e.created_at = b.created_at
+    RETURNING 1
+)
+SELECT (SELECT count(*) FROM events), (SELECT count(*) FROM event_reads),
+       (SELECT count(*) FROM jobs), (SELECT count(*) FROM job_reads)`
+
+	eraseJobsSQL = `
+WITH batch AS (
+    SELECT j.id, j.created_at FROM msg_dispatch_jobs j
+     WHERE {job}
+       AND j.status IN ('COMPLETED', 'FAILED', 'CANCELLED', 'EXPIRED')
+     LIMIT {limit}
+), attempts AS (
+    UPDATE msg_dispatch_job_attempts a SET response_body = NULL
+     WHERE a.dispatch_job_id IN (SELECT id FROM batch) AND a.response_body IS NOT NULL
+), job_reads AS (
+    UPDATE msg_dispatch_jobs_read r SET subject = {clearR}
+      FROM batch b WHERE r.id = b.id
+    RETURNING 1
+), jobs AS (
+    UPDATE msg_dispatch_jobs j SET payload = NULL, subject = {clearJ}, updated_at = NOW()
+      FROM batch b WHERE j.id = b.id AND j.created_at = b.created_at
+    RETURNING 1
+)
+SELECT (SELECT count(*) FROM jobs), (SELECT count(*) FROM job_reads)`
+
+	// Projections whose source row is already gone (or was matched by a
+	// path the write-side statements do not see).
+	eraseEventReadsSQL = `
+WITH erased AS (
+    UPDATE msg_events_read r SET data = NULL, subject = {clearR}
+     WHERE (r.id, r.created_at) IN (
+        SELECT r.id, r.created_at FROM msg_events_read r WHERE {eventRead} LIMIT {limit})
+    RETURNING 1
+)
+SELECT count(*) FROM erased`
+
+	eraseJobReadsSQL = `
+WITH erased AS (
+    UPDATE msg_dispatch_jobs_read r SET subject = {clearR}
+     WHERE (r.id, r.created_at) IN (
+        SELECT r.id, r.created_at FROM msg_dispatch_jobs_read r WHERE {jobRead} LIMIT {limit})
+    RETURNING 1
+)
+SELECT count(*) FROM erased`
+
+	verifySQL = `
+SELECT (SELECT count(*) FROM msg_events e WHERE {event}),
+       (SELECT count(*) FROM msg_events_read r WHERE {eventRead}),
+       (SELECT count(*) FROM msg_dispatch_jobs j WHERE {job}),
+       (SELECT count(*) FROM msg_dispatch_jobs_read r WHERE {jobRead})`
+)
@@ -0,0 +1,73 @@
+package privacy
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestRenderNumbersParamsPerStatement(t *testing.T) {
+	client := "clt_1"
+	req := NewErasureRequest(SubjectTypeSubject, "user-42", []string{"email", "customerId"}, &client)
+	m, err := newMatcher(req)
+	require.NoError(t, err)
+
+	sql, args := m.render(`SELECT 1 FROM msg_events e WHERE {event} LIMIT {limit}`, 25)
+	assert.Equal(t,
+		`SELECT 1 FROM msg_events e WHERE (e.subject = $1 OR e.context_data @> $2::jsonb OR e.context_data @> $3::jsonb) AND e.client_id = $4 LIMIT $5`,
+		sql)
+	require.Len(t, args, 5)
+	assert.Equal(t, "user-42", args[0])
+	assert.JSONEq(t, `[{"key":"email","value":"user-42"}]`, args[1].(string))
+	assert.JSONEq(t, `[{"key":"customerId","value":"user-42"}]`, args[2].(string))
+	assert.Equal(t, "clt_1", args[3])
+	assert.Equal(t, 25, args[4])
+
+	// A statement that references fewer parts binds fewer parameters.
+	sql, args = m.render(`UPDATE t r SET subject = {clearR} WHERE {jobRead}`, 0)
+	assert.Equal(t,
+		`UPDATE t r SET subject = CASE WHEN r.subject = $1 THEN NULL ELSE r.subject END WHERE r.subject = $2 AND r.client_id = $3`,
+		sql)
+	assert.Equal(t, []any{"user-42", "user-42", "clt_1"}, args)
+}
+
+func TestRenderClientErasure(t *testing.T) {
+	req := NewErasureRequest(SubjectTypeClient, "clt_9", nil, nil)
+	m, err := newMatcher(req)
+	require.NoError(t, err)
+
+	sql, args := m.render(`UPDATE x j SET subject = {clearJ} WHERE {job}`, 0)
+	assert.Equal(t,
+		`UPDATE x j SET subject = NULL WHERE j.client_id = $1 AND (j.payload IS NOT NULL OR j.subject IS NOT NULL)`,
+		sql)
+	assert.Equal(t, []any{"clt_9"}, args)
+}
+
+func TestEveryTemplatePlaceholderIsKnown(t *testing.T) {
+	for _, typ := range []SubjectType{SubjectTypeSubject, SubjectTypeClient} {
+		m, err := newMatcher(NewErasureRequest(typ, "s", []string{"k"}, nil))
+		require.NoError(t, err)
+		for _, tmpl := range []string{eraseEventsSQL, eraseJobsSQL, eraseEventReadsSQL, eraseJobReadsSQL, verifySQL} {
+			for _, tok := range placeholder.FindAllString(tmpl, -1) {
+				name := tok[1 : len(tok)-1]
+				if name == "limit" {
+					continue
+				}
+				assert.Contains(t, m.parts, name, "%s: unknown placeholder %s", typ, tok)
+			}
+		}
+	}
+}
+
+func TestHashSubjectIsStableHex(t *testing.T) {
+	h := HashSubject("user-42")
+	assert.Len(t, h, 64)
+	assert.Equal(t, h, HashSubject("user-42"))
+	assert.NotEqual(t, h, HashSubject("user-43"))
+}
+
+func TestRemainingZero(t *testing.T) {
+	assert.True(t, Remaining{}.Zero())
+	assert.False(t, Remaining{DispatchJobReads: 1}.Zero())
+}
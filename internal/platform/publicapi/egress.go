@@ -0,0 +1,176 @@
+package publicapi
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"log/slog"
+	"net/http"
+	"net/netip"
+	"slices"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+)
+
+// EgressIPs serves GET /.well-known/flowcatalyst/egress-ips: the addresses
+// deliveries leave from, for receivers that allowlist their callers.
+//
+// The list is the configured FC_EGRESS_IPS (addresses or CIDR ranges) plus,
+// when FC_EGRESS_IPS_DISCOVER_URL is set, the address an external echo
+// service (https://checkip.amazonaws.com or similar) reports for this
+// host. Behind a NAT gateway that is the gateway's public IP, so the list
+// follows the gateway without anyone copying its address into config.
+type EgressIPs struct {
+	static      []netip.Prefix
+	discoverURL string
+	client      *http.Client
+
+	mu         sync.RWMutex
+	discovered []netip.Prefix
+	updatedAt  time.Time
+}
+
+// NewEgressIPs parses the configured list. An entry that is neither an
+// address nor a CIDR range is an error, so a typo fails startup instead
+// of publishing a wrong allowlist.
+func NewEgressIPs(entries []string, discoverURL string) (*EgressIPs, error) {
+	static, err := parsePrefixes(entries)
+	if err != nil {
+		return nil, fmt.Errorf("FC_EGRESS_IPS: %w", err)
+	}
+	return &EgressIPs{
+		static:      static,
+		discoverURL: discoverURL,
+		client:      &http.Client{Timeout: 10 * time.Second},
+		updatedAt:   time.Now().UTC(),
+	}, nil
+}
+
+// RegisterRoutes mounts the egress list on r. Callers MUST mount r outside
+// any bearer-auth middleware: receivers fetch it anonymously.
+func (e *EgressIPs) RegisterRoutes(r chi.Router) {
+	r.Get("/.well-known/flowcatalyst/egress-ips", e.handle)
+}
+
+// Run refreshes the discovered address every interval until ctx is
+// cancelled. A no-op without a discovery URL.
+func (e *EgressIPs) Run(ctx context.Context, interval time.Duration) {
+	if e.discoverURL == "" {
+		return
+	}
+	e.refresh(ctx)
+	tick := time.NewTicker(interval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-tick.C:
+			e.refresh(ctx)
+		}
+	}
+}
+
+// refresh asks the echo service for our address. A failure keeps the last
+// good answer: a transient outage must not shrink the published list.
+func (e *EgressIPs) refresh(ctx context.Context) {
+	addr, err := e.discover(ctx)
+	if err != nil {
+		slog.Warn("egress ips: discovery failed", "url", e.discoverURL, "err", err)
+		return
+	}
+	e.mu.Lock()
+	defer e.mu.Unlock()
+	next := []netip.Prefix{netip.PrefixFrom(addr, addr.BitLen())}
+	if !slices.Equal(next, e.discovered) {
+		e.discovered = next
+		e.updatedAt = time.Now().UTC()
+		slog.Info("egress ips: discovered address", "addr", addr.String())
+	}
+}
+
+func (e *EgressIPs) discover(ctx context.Context) (netip.Addr, error) {
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, e.discoverURL, nil)
+	if err != nil {
+		return netip.Addr{}, err
+	}
+	resp, err := e.client.Do(req)
+	if err != nil {
+		return netip.Addr{}, err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return netip.Addr{}, fmt.Errorf("status %d", resp.StatusCode)
+	}
+	body, err := io.ReadAll(io.LimitReader(resp.Body, 256))
+	if err != nil {
+		return netip.Addr{}, err
+	}
+	addr, err := netip.ParseAddr(strings.TrimSpace(string(body)))
+	if err != nil {
+		return netip.Addr{}, err
+	}
+	return addr.Unmap(), nil
+}
+
+// egressResponse lists the ranges by family, ready to paste into a
+// firewall rule. Single addresses are given as /32 or /128.
+type egressResponse struct {
+	IPv4      []string  `json:"ipv4"`
+	IPv6      []string  `json:"ipv6"`
+	UpdatedAt time.Time `json:"updatedAt"`
+}
+
+func (e *EgressIPs) handle(w http.ResponseWriter, _ *http.Request) {
+	w.Header().Set("Cache-Control", "public, max-age=300")
+	writeJSON(w, http.StatusOK, e.snapshot())
+}
+
+func (e *EgressIPs) snapshot() egressResponse {
+	e.mu.RLock()
+	defer e.mu.RUnlock()
+	out := egressResponse{IPv4: []string{}, IPv6: []string{}, UpdatedAt: e.updatedAt}
+	seen := map[netip.Prefix]bool{}
+	for _, p := range append(slices.Clone(e.static), e.discovered...) {
+		if seen[p] {
+			continue
+		}
+		seen[p] = true
+		if p.Addr().Is4() {
+			out.IPv4 = append(out.IPv4, p.String())
+		} else {
+			out.IPv6 = append(out.IPv6, p.String())
+		}
+	}
+	return out
+}
+
+// parsePrefixes accepts addresses and CIDR ranges, normalising both to
+// masked prefixes. IPv4-mapped IPv6 addresses are unmapped.
+func parsePrefixes(entries []string) ([]netip.Prefix, error) {
+	out := make([]netip.Prefix, 0, len(entries))
+	for _, raw := range entries {
+		s := strings.TrimSpace(raw)
+		if s == "" {
+			continue
+		}
+		if strings.Contains(s, "/") {
+			p, err := netip.ParsePrefix(s)
+			if err != nil {
+				return nil, fmt.Errorf("%q is not a CIDR range", s)
+			}
+			out = append(out, p.Masked())
+			continue
+		}
+		addr, err := netip.ParseAddr(s)
+		if err != nil {
+			return nil, fmt.Errorf("%q is not an IP address", s)
+		}
+		addr = addr.Unmap()
+		out = append(out, netip.PrefixFrom(addr, addr.BitLen()))
+	}
+	return out, nil
+}
@@ -3,10 +3,11 @@
 //
 //	GET /api/public/platform     — feature flags shown on the login page
 //	GET /api/public/login-theme  — branded login-page theme (logo, colours, …)
+//	GET /.well-known/flowcatalyst/egress-ips — addresses deliveries come from
 //
-// Mirrors crates/fc-platform/src/shared/public_api.rs. Both endpoints
-// are read-only and intentionally low-privilege — the SPA hits them
-// before the user signs in.
+// The first two mirror crates/fc-platform/src/shared/public_api.rs. All
+// are read-only and intentionally low-privilege — the SPA hits the first
+// two before the user signs in, webhook receivers the last (egress.go).
 package publicapi
 
 import (
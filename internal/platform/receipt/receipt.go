@@ -0,0 +1,140 @@
+// Package receipt tells producers how their events' deliveries ended. An
+// event may ask for a receipt (event.Receipt); once each of its dispatch
+// jobs reaches a terminal status — delivered, or failed with its retries
+// spent — the processing callback hands the job to an Emitter, which sends
+// the receipt as a webhook, as an event of the requested type, or both.
+//
+// A receipt webhook is a dispatch job of its own, so it is retried and
+// signed like any delivery. Neither form carries a receipt request of its
+// own, so receipts never beget receipts.
+package receipt
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+const (
+	// Source is the CloudEvents source of receipt events and webhooks.
+	Source = "flowcatalyst:receipts"
+	// WebhookCode is the code of receipt webhook dispatch jobs, sent as the
+	// delivery's event type.
+	WebhookCode = "flowcatalyst:dispatch:receipt"
+)
+
+// Receipt is the body of a receipt, webhook or event: which delivery of
+// which event ended how.
+type Receipt struct {
+	EventID        string    `json:"eventId"`
+	EventType      string    `json:"eventType"`
+	DispatchJobID  string    `json:"dispatchJobId"`
+	SubscriptionID *string   `json:"subscriptionId,omitempty"`
+	Status         string    `json:"status"`
+	Attempts       int32     `json:"attempts"`
+	LastError      *string   `json:"lastError,omitempty"`
+	CompletedAt    time.Time `json:"completedAt"`
+}
+
+// Emitter sends the receipts events asked for.
+type Emitter struct {
+	events *event.Repository
+	jobs   *dispatchjob.Repository
+}
+
+// NewEmitter wires an emitter.
+func NewEmitter(events *event.Repository, jobs *dispatchjob.Repository) *Emitter {
+	return &Emitter{events: events, jobs: jobs}
+}
+
+// Emit sends the receipts job's event asked for, if any. job ended in
+// status after attempts attempts; lastError is the final failure, if it
+// failed. Jobs no event spawned — receipt webhooks among them — get none.
+func (e *Emitter) Emit(ctx context.Context, job *dispatchjob.DispatchJob, status common.DispatchStatus, attempts int32, lastError *string) error {
+	if job.EventID == nil {
+		return nil
+	}
+	want, err := e.events.ReceiptFor(ctx, *job.EventID)
+	if err != nil {
+		return fmt.Errorf("load receipt request: %w", err)
+	}
+	if want == nil {
+		return nil
+	}
+	body, err := json.Marshal(Receipt{
+		EventID:        *job.EventID,
+		EventType:      job.Code,
+		DispatchJobID:  job.ID,
+		SubscriptionID: job.SubscriptionID,
+		Status:         string(status),
+		Attempts:       attempts,
+		LastError:      lastError,
+		CompletedAt:    time.Now().UTC(),
+	})
+	if err != nil {
+		return fmt.Errorf("marshal receipt: %w", err)
+	}
+	if want.EventType != nil {
+		if _, err := e.events.InsertBatch(ctx, []event.Event{*receiptEvent(job, *want.EventType, body)}); err != nil {
+			return fmt.Errorf("insert receipt event: %w", err)
+		}
+	}
+	if want.CallbackURL != nil {
+		if err := e.jobs.InsertBatch(ctx, []dispatchjob.DispatchJob{webhookJob(job, *want.CallbackURL, body)}); err != nil {
+			return fmt.Errorf("insert receipt webhook: %w", err)
+		}
+	}
+	return nil
+}
+
+// receiptEvent builds the receipt event for job: owned by the job's
+// client, caused by the original event, in its correlation chain.
+func receiptEvent(job *dispatchjob.DispatchJob, eventType string, body []byte) *event.Event {
+	subject := ""
+	if job.Subject != nil {
+		subject = *job.Subject
+	}
+	ev := event.New(eventType, Source, subject, body)
+	ev.DeduplicationID = "receipt-" + job.ID
+	ev.ClientID = job.ClientID
+	ev.CorrelationID = job.CorrelationID
+	ev.CausationID = job.EventID
+	return ev
+}
+
+// webhookJob builds the dispatch job that POSTs the receipt to url. It is
+// data-only — the receiver gets the receipt itself, not an envelope — and
+// carries no event id, so its own terminal status sends nothing.
+func webhookJob(job *dispatchjob.DispatchJob, url string, body []byte) dispatchjob.DispatchJob {
+	payload := string(body)
+	source := Source
+	key := "receipt:" + job.ID
+	return dispatchjob.DispatchJob{
+		// 13-char untyped TSID — `msg_dispatch_jobs.id` is VARCHAR(13).
+		ID:                 tsid.GenerateUntyped(),
+		Kind:               dispatchjob.KindTask,
+		Code:               WebhookCode,
+		Source:             &source,
+		Subject:            job.Subject,
+		TargetURL:          url,
+		Protocol:           dispatchjob.ProtocolHTTPWebhook,
+		Payload:            &payload,
+		PayloadContentType: "application/json",
+		DataOnly:           true,
+		CorrelationID:      job.CorrelationID,
+		ClientID:           job.ClientID,
+		Mode:               common.DispatchImmediate,
+		Sequence:           99,
+		TimeoutSeconds:     30,
+		MaxRetries:         3,
+		RetryStrategy:      dispatchjob.RetryExponentialBackoff,
+		Status:             common.DispatchPending,
+		IdempotencyKey:     &key,
+	}
+}
@@ -0,0 +1,50 @@
+// Package api wires HTTP routes for the configuration reconciler via huma.
+package api
+
+import (
+	"context"
+	"net/http"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/reconciler"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+// State bundles deps. Reconciler is nil when no spec path is configured;
+// the status endpoint then reports the reconciler disabled.
+type State struct {
+	Reconciler *reconciler.Reconciler
+}
+
+const tag = "configuration"
+
+// Register mounts the reconciler endpoints. Both are anchor-only.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "getReconcilerStatus", "/api/admin/reconciler", "Get the configuration reconciler's last pass and drift", s.status)
+	apiroute.Post(g, "runReconciler", "/api/admin/reconciler/run", "Run a configuration reconcile pass now", http.StatusOK, s.run)
+}
+
+func (s *State) status(ctx context.Context, _ *struct{}) (*apicommon.Out[ReconcilerStatusResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	return &apicommon.Out[ReconcilerStatusResponse]{Body: fromStatus(s.Reconciler.Status())}, nil
+}
+
+// run reconciles immediately instead of waiting for the next tick. The
+// pass follows the configured mode: a report-only reconciler still only
+// reports.
+func (s *State) run(ctx context.Context, _ *struct{}) (*apicommon.Out[ReconcilerStatusResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	if s.Reconciler == nil {
+		return nil, usecase.Conflict("RECONCILER_DISABLED", "no reconciler spec is configured; set FC_RECONCILE_SPEC_PATH")
+	}
+	return &apicommon.Out[ReconcilerStatusResponse]{Body: fromStatus(s.Reconciler.Reconcile(ctx))}, nil
+}
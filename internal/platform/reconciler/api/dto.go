@@ -0,0 +1,71 @@
+// dto.go contains the wire-format types for the reconciler API.
+package api
+
+import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/reconciler"
+)
+
+// DriftItemResponse mirrors configbundle.ItemResult.
+type DriftItemResponse struct {
+	Kind    string   `json:"kind" doc:"dispatchPool, eventType, role, authConfig, subscription or serviceAccount"`
+	Key     string   `json:"key" doc:"Code, role name or email domain"`
+	Action  string   `json:"action" doc:"create, update, conflict or invalid"`
+	Changed []string `json:"changed,omitempty" doc:"Fields that differ from the spec"`
+	Message string   `json:"message,omitempty"`
+}
+
+// SpecSourceResponse describes the spec the last pass read.
+type SpecSourceResponse struct {
+	Path     string   `json:"path"`
+	Files    []string `json:"files"`
+	Digest   string   `json:"digest,omitempty" doc:"SHA-256 over the spec files"`
+	Revision string   `json:"revision,omitempty" doc:"Git commit checked out at the spec path, when it is a git working tree"`
+}
+
+// ReconcilerStatusResponse is the wire shape for GET /api/admin/reconciler.
+type ReconcilerStatusResponse struct {
+	Enabled       bool                `json:"enabled"`
+	Mode          string              `json:"mode,omitempty" doc:"apply or report"`
+	Source        SpecSourceResponse  `json:"source"`
+	InSync        bool                `json:"inSync"`
+	Drift         []DriftItemResponse `json:"drift"`
+	Applied       bool                `json:"applied" doc:"Whether the last pass wrote the drift back"`
+	LastRunAt     *time.Time          `json:"lastRunAt,omitempty"`
+	LastAppliedAt *time.Time          `json:"lastAppliedAt,omitempty"`
+	Error         string              `json:"error,omitempty"`
+}
+
+func fromStatus(st reconciler.Status) ReconcilerStatusResponse {
+	drift := make([]DriftItemResponse, 0, len(st.Drift))
+	for _, it := range st.Drift {
+		drift = append(drift, DriftItemResponse{
+			Kind:    string(it.Kind),
+			Key:     it.Key,
+			Action:  string(it.Action),
+			Changed: it.Changed,
+			Message: it.Message,
+		})
+	}
+	files := st.Source.Files
+	if files == nil {
+		files = []string{}
+	}
+	return ReconcilerStatusResponse{
+		Enabled: st.Enabled,
+		Mode:    st.Mode,
+		Source: SpecSourceResponse{
+			Path:     st.Source.Path,
+			Files:    files,
+			Digest:   st.Source.Digest,
+			Revision: st.Source.Revision,
+		},
+		InSync:        st.InSync,
+		Drift:         drift,
+		Applied:       st.Applied,
+		LastRunAt:     st.LastRunAt,
+		LastAppliedAt: st.LastAppliedAt,
+		Error:         st.Error,
+	}
+}
@@ -0,0 +1,289 @@
+package reconciler
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"slices"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	platformauth "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+	bundleops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	saops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// PrincipalID is the actor recorded on the events and audit rows the
+// reconciler writes.
+const PrincipalID = "system:reconciler"
+
+// Config tunes the reconciler.
+type Config struct {
+	Path     string        // spec file or directory
+	Interval time.Duration // pass cadence (default 60s)
+	// Apply writes drift back to the database. Off, the reconciler only
+	// reports it.
+	Apply bool
+}
+
+// Deps are the repositories and unit of work the reconciler reads and
+// writes through.
+type Deps struct {
+	Bundle          configbundle.Repos
+	Guards          configbundle.Guards
+	ServiceAccounts *serviceaccount.Repository
+	Principals      *principal.Repository
+	OAuthClients    *platformauth.OAuthClientRepo
+	UoW             *usecasepgx.UnitOfWork
+}
+
+// Status is the outcome of the most recent pass.
+type Status struct {
+	Enabled bool   `json:"enabled"`
+	Mode    string `json:"mode,omitempty"` // "apply" or "report"
+	Source  Source `json:"source"`
+	// InSync is true when the last pass found nothing to change.
+	InSync bool `json:"inSync"`
+	// Drift lists every spec item that differed from the database at the
+	// start of the last pass (create, update, conflict or invalid).
+	Drift []configbundle.ItemResult `json:"drift"`
+	// Applied is true when the last pass wrote Drift back.
+	Applied   bool       `json:"applied"`
+	LastRunAt *time.Time `json:"lastRunAt,omitempty"`
+	// LastAppliedAt is the last pass that wrote anything.
+	LastAppliedAt *time.Time `json:"lastAppliedAt,omitempty"`
+	Error         string     `json:"error,omitempty"`
+}
+
+// Reconciler runs reconcile passes on an interval and keeps the last
+// outcome for the status API.
+type Reconciler struct {
+	deps Deps
+	cfg  Config
+
+	// IsLeader gates writes: only the leader applies drift, every replica
+	// still reports it. Nil means always leader.
+	IsLeader func() bool
+
+	mu     sync.Mutex // one pass at a time
+	status atomic.Pointer[Status]
+}
+
+// New builds a reconciler.
+func New(deps Deps, cfg Config) *Reconciler {
+	if cfg.Interval <= 0 {
+		cfg.Interval = time.Minute
+	}
+	r := &Reconciler{deps: deps, cfg: cfg}
+	r.status.Store(&Status{Enabled: true, Mode: r.mode(), Source: Source{Path: cfg.Path}, Drift: []configbundle.ItemResult{}})
+	return r
+}
+
+func (r *Reconciler) mode() string {
+	if r.cfg.Apply {
+		return "apply"
+	}
+	return "report"
+}
+
+// Status returns the last pass's outcome. A nil reconciler reports itself
+// disabled, so the status endpoint works whether or not a spec is
+// configured.
+func (r *Reconciler) Status() Status {
+	if r == nil {
+		return Status{Drift: []configbundle.ItemResult{}}
+	}
+	return *r.status.Load()
+}
+
+// Run reconciles once on startup, then every Config.Interval, until ctx is
+// cancelled.
+func (r *Reconciler) Run(ctx context.Context) {
+	slog.Info("config reconciler started", "path", r.cfg.Path, "mode", r.mode(), "interval", r.cfg.Interval)
+	r.Reconcile(ctx)
+
+	tick := time.NewTicker(r.cfg.Interval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("config reconciler stopped")
+			return
+		case <-tick.C:
+			r.Reconcile(ctx)
+		}
+	}
+}
+
+// Reconcile runs one pass: load the spec, diff it, warn about every
+// difference and, in apply mode on the leader, write them. Invalid items
+// block the whole apply — a half-applied spec is harder to reason about
+// than a stale one.
+func (r *Reconciler) Reconcile(ctx context.Context) Status {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	now := time.Now().UTC()
+	prev := r.status.Load()
+	st := Status{Enabled: true, Mode: r.mode(), Source: Source{Path: r.cfg.Path}, Drift: []configbundle.ItemResult{}, LastRunAt: &now, LastAppliedAt: prev.LastAppliedAt}
+	defer func() { r.status.Store(&st) }()
+
+	spec, src, err := LoadSpec(r.cfg.Path)
+	st.Source = src
+	if err != nil {
+		st.Error = err.Error()
+		slog.Warn("config reconciler: cannot load spec", "path", r.cfg.Path, "err", err)
+		return st
+	}
+	snap, err := r.deps.Bundle.Load(ctx)
+	if err != nil {
+		st.Error = err.Error()
+		slog.Warn("config reconciler: cannot load configuration", "err", err)
+		return st
+	}
+	bundle := spec.Bundle()
+	plan := configbundle.Diff(ctx, bundle, snap, configbundle.OnConflictOverwrite, r.deps.Guards)
+
+	accounts, err := r.loadAccounts(ctx)
+	if err != nil {
+		st.Error = err.Error()
+		slog.Warn("config reconciler: cannot load service accounts", "err", err)
+		return st
+	}
+	clientIDs := make(map[string]string, len(snap.Clients))
+	for _, c := range snap.Clients {
+		clientIDs[c.Identifier] = c.ID
+	}
+	roles := make(map[string]bool, len(snap.Roles)+len(bundle.Roles))
+	for _, ro := range snap.Roles {
+		roles[ro.Name] = true
+	}
+	for _, ro := range bundle.Roles {
+		roles[ro.Name] = true
+	}
+	accountItems, accountWrites := DiffServiceAccounts(spec.ServiceAccounts, accounts, clientIDs, roles)
+
+	invalid := 0
+	for _, it := range slices.Concat(plan.Items, accountItems) {
+		switch it.Action {
+		case configbundle.ActionUnchanged:
+			continue
+		case configbundle.ActionInvalid:
+			invalid++
+		}
+		st.Drift = append(st.Drift, it)
+		slog.Warn("config drift", "kind", it.Kind, "key", it.Key, "action", it.Action, "changed", it.Changed, "message", it.Message)
+	}
+	st.InSync = len(st.Drift) == 0
+	if st.InSync || !r.cfg.Apply {
+		return st
+	}
+	if invalid > 0 {
+		st.Error = fmt.Sprintf("%d spec item(s) are invalid; nothing applied", invalid)
+		slog.Error("config reconciler: spec has invalid items; not applying", "invalid", invalid, "revision", src.Revision)
+		return st
+	}
+	if r.IsLeader != nil && !r.IsLeader() {
+		return st
+	}
+
+	sysCtx := auth.WithContext(ctx, &auth.AuthContext{PrincipalID: PrincipalID, Scope: auth.ScopeAnchor})
+	ec := usecase.NewExecutionContext(PrincipalID)
+	if hasWrites(plan) {
+		if _, err := usecaseop.RunTx(sysCtx, r.deps.UoW, bundleops.ImportBundle(r.deps.Bundle, r.deps.Guards),
+			bundleops.ImportCommand{Bundle: bundle, OnConflict: configbundle.OnConflictOverwrite}, ec); err != nil {
+			st.Error = "apply configuration: " + err.Error()
+			slog.Error("config reconciler: apply failed", "err", err, "revision", src.Revision)
+			return st
+		}
+	}
+	for _, w := range accountWrites {
+		if err := r.applyAccount(sysCtx, w, ec); err != nil {
+			st.Error = "apply service account '" + w.Item.Code + "': " + err.Error()
+			slog.Error("config reconciler: service account apply failed", "code", w.Item.Code, "err", err)
+			return st
+		}
+	}
+	st.Applied = true
+	st.LastAppliedAt = &now
+	slog.Info("config reconciler applied spec", "changes", len(st.Drift), "revision", src.Revision)
+	return st
+}
+
+func hasWrites(p *configbundle.Plan) bool {
+	return len(p.DispatchPools)+len(p.EventTypes)+len(p.Roles)+len(p.AuthConfigs)+len(p.Subscriptions) > 0
+}
+
+func (r *Reconciler) loadAccounts(ctx context.Context) ([]AccountState, error) {
+	all, err := r.deps.ServiceAccounts.FindAll(ctx)
+	if err != nil {
+		return nil, err
+	}
+	out := make([]AccountState, 0, len(all))
+	for _, sa := range all {
+		st := AccountState{Account: sa}
+		p, err := r.deps.Principals.FindByServiceAccount(ctx, sa.ID)
+		if err != nil {
+			return nil, err
+		}
+		if p != nil {
+			for _, ra := range p.Roles {
+				st.Roles = append(st.Roles, ra.Role)
+			}
+		}
+		out = append(out, st)
+	}
+	return out, nil
+}
+
+// applyAccount writes one service account through its regular operations.
+// A created account's one-time credentials are discarded; an operator
+// regenerates them through the admin API when they're needed.
+func (r *Reconciler) applyAccount(ctx context.Context, w AccountWrite, ec usecase.ExecutionContext) error {
+	id := ""
+	if w.Current == nil {
+		res, err := usecaseop.RunTx(ctx, r.deps.UoW,
+			saops.CreateServiceAccountWithCredentials(r.deps.ServiceAccounts, r.deps.Principals, r.deps.OAuthClients),
+			saops.CreateCommand{
+				Code:        w.Item.Code,
+				Name:        w.Item.Name,
+				Description: w.Item.Description,
+				Scope:       w.Item.Scope,
+				ClientIDs:   w.ClientIDs,
+			}, ec)
+		if err != nil {
+			return err
+		}
+		id = res.ServiceAccount.ID
+		slog.Info("config reconciler created service account; regenerate its credentials to use it", "code", w.Item.Code, "id", id)
+	} else {
+		id = w.Current.ID
+		if w.FieldsChanged {
+			name := w.Item.Name
+			if _, err := usecaseop.Run(ctx, r.deps.UoW, saops.UpdateServiceAccount(r.deps.ServiceAccounts), saops.UpdateCommand{
+				ID:          id,
+				Name:        &name,
+				Description: w.Item.Description,
+				Scope:       w.Item.Scope,
+				ClientIDs:   w.ClientIDs,
+			}, ec); err != nil {
+				return err
+			}
+		}
+	}
+	if w.RolesChanged {
+		if _, err := usecaseop.Run(ctx, r.deps.UoW, saops.AssignRolesToServiceAccount(r.deps.ServiceAccounts, r.deps.Principals),
+			saops.AssignRolesCommand{ServiceAccountID: id, Roles: w.Roles}, ec); err != nil {
+			return err
+		}
+	}
+	return nil
+}
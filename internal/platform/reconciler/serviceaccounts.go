@@ -0,0 +1,162 @@
+package reconciler
+
+import (
+	"slices"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/validate"
+)
+
+// KindServiceAccount names the service-account section in drift results,
+// alongside the configbundle kinds.
+const KindServiceAccount configbundle.Kind = "serviceAccount"
+
+// AccountState is an existing service account with the roles held by its
+// linked SERVICE principal, which is where role assignments live.
+type AccountState struct {
+	Account serviceaccount.ServiceAccount
+	Roles   []string
+}
+
+// AccountWrite is one service account the reconciler creates or updates.
+// Current is nil for a create. ClientIDs and Roles are the resolved target
+// values; RolesChanged says whether the assignment needs replacing.
+type AccountWrite struct {
+	Item          ServiceAccountItem
+	Current       *serviceaccount.ServiceAccount
+	ClientIDs     []string
+	Roles         []string
+	FieldsChanged bool
+	RolesChanged  bool
+}
+
+// accountItem is the comparison form of a service account: clients by
+// identifier and both lists sorted, so ordering never reads as drift.
+type accountItem struct {
+	Name        string   `json:"name"`
+	Description *string  `json:"description,omitempty"`
+	Scope       *string  `json:"scope,omitempty"`
+	Clients     []string `json:"clients,omitempty"`
+	Roles       []string `json:"roles,omitempty"`
+}
+
+// DiffServiceAccounts compares the spec's accounts with the current ones.
+// clientIDs maps client identifier → id; roles is the set of role names
+// that exist once the bundle sections are applied. Accounts the spec
+// doesn't list are not touched, and an unset description or scope leaves
+// the account's current value in place.
+func DiffServiceAccounts(
+	items []ServiceAccountItem,
+	current []AccountState,
+	clientIDs map[string]string,
+	roles map[string]bool,
+) ([]configbundle.ItemResult, []AccountWrite) {
+	byCode := make(map[string]*AccountState, len(current))
+	for i := range current {
+		byCode[current[i].Account.Code] = &current[i]
+	}
+	identifiers := make(map[string]string, len(clientIDs))
+	for ident, id := range clientIDs {
+		identifiers[id] = ident
+	}
+
+	var results []configbundle.ItemResult
+	var writes []AccountWrite
+	seen := map[string]bool{}
+	invalid := func(key, msg string) {
+		results = append(results, configbundle.ItemResult{Kind: KindServiceAccount, Key: key, Action: configbundle.ActionInvalid, Message: msg})
+	}
+	for _, it := range items {
+		code := strings.ToLower(strings.TrimSpace(it.Code))
+		if seen[code] {
+			invalid(code, "duplicate serviceAccount '"+code+"' in spec")
+			continue
+		}
+		seen[code] = true
+		switch {
+		case !validate.CodePattern.MatchString(code):
+			invalid(code, "code must start with a lowercase letter and contain only lowercase alphanumeric and hyphens")
+			continue
+		case strings.TrimSpace(it.Name) == "":
+			invalid(code, "name is required")
+			continue
+		}
+		ids := make([]string, 0, len(it.Clients))
+		missing := ""
+		for _, ident := range it.Clients {
+			id, ok := clientIDs[ident]
+			if !ok {
+				missing = ident
+				break
+			}
+			ids = append(ids, id)
+		}
+		if missing != "" {
+			invalid(code, "unknown client '"+missing+"'")
+			continue
+		}
+		want := slices.Compact(slices.Sorted(slices.Values(it.Roles)))
+		if i := slices.IndexFunc(want, func(r string) bool { return !roles[r] }); i >= 0 {
+			invalid(code, "unknown role '"+want[i]+"'")
+			continue
+		}
+
+		it.Code = code
+		it.Name = strings.TrimSpace(it.Name)
+		after := accountItem{
+			Name:        it.Name,
+			Description: it.Description,
+			Scope:       it.Scope,
+			Clients:     slices.Sorted(slices.Values(it.Clients)),
+			Roles:       want,
+		}
+		cur := byCode[code]
+		if cur == nil {
+			results = append(results, configbundle.ItemResult{Kind: KindServiceAccount, Key: code, Action: configbundle.ActionCreate})
+			writes = append(writes, AccountWrite{Item: it, ClientIDs: ids, Roles: want, FieldsChanged: true, RolesChanged: len(want) > 0})
+			continue
+		}
+
+		if after.Description == nil {
+			after.Description = cur.Account.Description
+		}
+		if after.Scope == nil {
+			after.Scope = cur.Account.Scope
+		}
+		before := accountItem{
+			Name:        cur.Account.Name,
+			Description: cur.Account.Description,
+			Scope:       cur.Account.Scope,
+			Roles:       slices.Compact(slices.Sorted(slices.Values(cur.Roles))),
+		}
+		for _, id := range cur.Account.ClientIDs {
+			// A client id with no identifier points at a deleted client;
+			// keep it visible so it reads as drift.
+			if ident, ok := identifiers[id]; ok {
+				before.Clients = append(before.Clients, ident)
+			} else {
+				before.Clients = append(before.Clients, id)
+			}
+		}
+		slices.Sort(before.Clients)
+
+		changed := configbundle.ChangedFields(before, after)
+		if len(changed) == 0 {
+			results = append(results, configbundle.ItemResult{Kind: KindServiceAccount, Key: code, Action: configbundle.ActionUnchanged})
+			continue
+		}
+		results = append(results, configbundle.ItemResult{Kind: KindServiceAccount, Key: code, Action: configbundle.ActionUpdate, Changed: changed})
+		rolesChanged := slices.Contains(changed, "roles")
+		writes = append(writes, AccountWrite{
+			Item:          it,
+			Current:       &cur.Account,
+			ClientIDs:     ids,
+			Roles:         want,
+			FieldsChanged: len(changed) > 1 || !rolesChanged,
+			RolesChanged:  rolesChanged,
+		})
+	}
+	return results, writes
+}
@@ -0,0 +1,103 @@
+package reconciler
+
+import (
+	"slices"
+	"testing"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+)
+
+func ptr[T any](v T) *T { return &v }
+
+var (
+	clients = map[string]string{"acme": "clt_1", "globex": "clt_2"}
+	roles   = map[string]bool{"orders:viewer": true, "orders:admin": true}
+)
+
+func existing() []AccountState {
+	sa := serviceaccount.New("billing", "Billing")
+	sa.Description = ptr("Billing sync")
+	sa.ClientIDs = []string{"clt_1"}
+	return []AccountState{{Account: *sa, Roles: []string{"orders:viewer"}}}
+}
+
+func TestDiffServiceAccountsUnchanged(t *testing.T) {
+	items := []ServiceAccountItem{{Code: "billing", Name: "Billing", Clients: []string{"acme"}, Roles: []string{"orders:viewer"}}}
+	results, writes := DiffServiceAccounts(items, existing(), clients, roles)
+	if len(results) != 1 || results[0].Action != configbundle.ActionUnchanged {
+		t.Fatalf("results = %+v", results)
+	}
+	if len(writes) != 0 {
+		t.Errorf("writes = %+v, want none", writes)
+	}
+}
+
+func TestDiffServiceAccountsCreate(t *testing.T) {
+	items := []ServiceAccountItem{{Code: "Reports", Name: "Reports", Clients: []string{"globex"}, Roles: []string{"orders:admin"}}}
+	results, writes := DiffServiceAccounts(items, existing(), clients, roles)
+	if len(results) != 1 || results[0].Action != configbundle.ActionCreate || results[0].Key != "reports" {
+		t.Fatalf("results = %+v", results)
+	}
+	w := writes[0]
+	if w.Current != nil || !slices.Equal(w.ClientIDs, []string{"clt_2"}) || !w.RolesChanged {
+		t.Errorf("write = %+v", w)
+	}
+}
+
+func TestDiffServiceAccountsUpdate(t *testing.T) {
+	items := []ServiceAccountItem{{Code: "billing", Name: "Billing", Clients: []string{"acme"}, Roles: []string{"orders:admin"}}}
+	results, writes := DiffServiceAccounts(items, existing(), clients, roles)
+	if results[0].Action != configbundle.ActionUpdate || !slices.Equal(results[0].Changed, []string{"roles"}) {
+		t.Fatalf("results = %+v", results)
+	}
+	if w := writes[0]; w.FieldsChanged || !w.RolesChanged {
+		t.Errorf("roles-only change should skip the account update: %+v", w)
+	}
+
+	items[0].Name = "Billing v2"
+	items[0].Roles = []string{"orders:viewer"}
+	results, writes = DiffServiceAccounts(items, existing(), clients, roles)
+	if !slices.Equal(results[0].Changed, []string{"name"}) {
+		t.Fatalf("changed = %v", results[0].Changed)
+	}
+	if w := writes[0]; !w.FieldsChanged || w.RolesChanged {
+		t.Errorf("write = %+v", w)
+	}
+}
+
+func TestDiffServiceAccountsUnsetFieldsKeepCurrent(t *testing.T) {
+	// The current account has a description; the spec leaves it out.
+	items := []ServiceAccountItem{{Code: "billing", Name: "Billing", Clients: []string{"acme"}, Roles: []string{"orders:viewer"}}}
+	results, _ := DiffServiceAccounts(items, existing(), clients, roles)
+	if results[0].Action != configbundle.ActionUnchanged {
+		t.Errorf("unset description read as drift: %+v", results[0])
+	}
+
+	items[0].Description = ptr("Something else")
+	results, _ = DiffServiceAccounts(items, existing(), clients, roles)
+	if !slices.Equal(results[0].Changed, []string{"description"}) {
+		t.Errorf("changed = %v", results[0].Changed)
+	}
+}
+
+func TestDiffServiceAccountsInvalid(t *testing.T) {
+	cases := map[string]ServiceAccountItem{
+		"bad code":       {Code: "9lives", Name: "X"},
+		"missing name":   {Code: "nameless"},
+		"unknown client": {Code: "ghost", Name: "Ghost", Clients: []string{"initech"}},
+		"unknown role":   {Code: "roleless", Name: "Roleless", Roles: []string{"orders:owner"}},
+	}
+	for name, it := range cases {
+		results, writes := DiffServiceAccounts([]ServiceAccountItem{it}, nil, clients, roles)
+		if len(results) != 1 || results[0].Action != configbundle.ActionInvalid || len(writes) != 0 {
+			t.Errorf("%s: results = %+v, writes = %+v", name, results, writes)
+		}
+	}
+
+	dup := []ServiceAccountItem{{Code: "twin", Name: "A"}, {Code: "TWIN", Name: "B"}}
+	results, writes := DiffServiceAccounts(dup, nil, clients, roles)
+	if results[1].Action != configbundle.ActionInvalid || len(writes) != 1 {
+		t.Errorf("duplicate: results = %+v", results)
+	}
+}
@@ -0,0 +1,260 @@
+// Package reconciler keeps platform configuration in line with a
+// declarative spec: YAML or JSON files in a mounted directory (typically a
+// git checkout kept current by a sidecar) describing dispatch pools, event
+// types, roles, auth configs, subscriptions and service accounts.
+//
+// On an interval the reconciler diffs the spec against the database and
+// reports every difference as drift. In apply mode it then writes the
+// differences through the regular use cases — configbundle's import for
+// the bundle sections, the service-account operations for accounts — so
+// each change carries its domain event and audit row like an admin edit
+// would. The spec is additive: items the spec doesn't mention are left
+// alone, never deleted.
+package reconciler
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"gopkg.in/yaml.v3"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+)
+
+// Spec is the declarative document. Its sections use the configbundle item
+// shapes, so a configuration export is a valid spec as it stands; service
+// accounts are the one section bundles don't carry.
+type Spec struct {
+	// Version and ExportedAt are accepted so an export drops in unchanged.
+	// A zero Version means the current bundle version.
+	Version         int                             `json:"version,omitempty"`
+	ExportedAt      *time.Time                      `json:"exportedAt,omitempty"`
+	DispatchPools   []configbundle.DispatchPoolItem `json:"dispatchPools,omitempty"`
+	EventTypes      []configbundle.EventTypeItem    `json:"eventTypes,omitempty"`
+	Roles           []configbundle.RoleItem         `json:"roles,omitempty"`
+	AuthConfigs     []configbundle.AuthConfigItem   `json:"authConfigs,omitempty"`
+	Subscriptions   []configbundle.SubscriptionItem `json:"subscriptions,omitempty"`
+	ServiceAccounts []ServiceAccountItem            `json:"serviceAccounts,omitempty"`
+}
+
+// ServiceAccountItem is a service account, keyed by code. Clients are
+// referenced by identifier and roles by full name. Credentials are never
+// part of the spec: an account the reconciler creates gets fresh ones,
+// which an operator retrieves by regenerating them through the admin API.
+type ServiceAccountItem struct {
+	Code        string   `json:"code"`
+	Name        string   `json:"name"`
+	Description *string  `json:"description,omitempty"`
+	Scope       *string  `json:"scope,omitempty"`
+	Clients     []string `json:"clients,omitempty"`
+	Roles       []string `json:"roles,omitempty"`
+}
+
+// Bundle returns the spec's bundle sections.
+func (s *Spec) Bundle() *configbundle.Bundle {
+	return &configbundle.Bundle{
+		Version:       configbundle.Version,
+		DispatchPools: s.DispatchPools,
+		EventTypes:    s.EventTypes,
+		Roles:         s.Roles,
+		AuthConfigs:   s.AuthConfigs,
+		Subscriptions: s.Subscriptions,
+	}
+}
+
+// merge appends other's sections to s. Duplicate keys across files are not
+// resolved here; the diff reports them as invalid items.
+func (s *Spec) merge(other *Spec) {
+	s.DispatchPools = append(s.DispatchPools, other.DispatchPools...)
+	s.EventTypes = append(s.EventTypes, other.EventTypes...)
+	s.Roles = append(s.Roles, other.Roles...)
+	s.AuthConfigs = append(s.AuthConfigs, other.AuthConfigs...)
+	s.Subscriptions = append(s.Subscriptions, other.Subscriptions...)
+	s.ServiceAccounts = append(s.ServiceAccounts, other.ServiceAccounts...)
+}
+
+// Source describes where a loaded spec came from.
+type Source struct {
+	Path  string   `json:"path"`
+	Files []string `json:"files"`
+	// Digest is a SHA-256 over every file's name and content, so an
+	// unchanged spec keeps its digest across passes.
+	Digest string `json:"digest"`
+	// Revision is the commit checked out at Path when it lies inside a git
+	// working tree; empty otherwise.
+	Revision string `json:"revision,omitempty"`
+}
+
+// LoadSpec reads the spec at path: a single file, or every .yaml, .yml and
+// .json file under a directory (recursively, in lexical order, skipping
+// dot-directories such as .git). YAML files may hold several documents
+// separated by "---". Unknown fields are rejected so a misspelt key fails
+// loudly instead of silently reconciling nothing.
+func LoadSpec(path string) (*Spec, Source, error) {
+	src := Source{Path: path}
+	info, err := os.Stat(path)
+	if err != nil {
+		return nil, src, fmt.Errorf("stat spec path: %w", err)
+	}
+	if info.IsDir() {
+		err = filepath.WalkDir(path, func(p string, d fs.DirEntry, err error) error {
+			if err != nil {
+				return err
+			}
+			if d.IsDir() {
+				if p != path && strings.HasPrefix(d.Name(), ".") {
+					return filepath.SkipDir
+				}
+				return nil
+			}
+			if isSpecFile(p) {
+				src.Files = append(src.Files, p)
+			}
+			return nil
+		})
+		if err != nil {
+			return nil, src, fmt.Errorf("walk spec directory: %w", err)
+		}
+		if len(src.Files) == 0 {
+			return nil, src, fmt.Errorf("no .yaml, .yml or .json files under %s", path)
+		}
+	} else {
+		src.Files = []string{path}
+	}
+
+	spec := &Spec{}
+	h := sha256.New()
+	for _, f := range src.Files {
+		raw, err := os.ReadFile(f)
+		if err != nil {
+			return nil, src, fmt.Errorf("read %s: %w", f, err)
+		}
+		rel, _ := filepath.Rel(path, f)
+		fmt.Fprintf(h, "%s\x00%d\x00", rel, len(raw))
+		h.Write(raw)
+
+		docs, err := decodeFile(f, raw)
+		if err != nil {
+			return nil, src, fmt.Errorf("%s: %w", f, err)
+		}
+		for _, doc := range docs {
+			if doc.Version != 0 && doc.Version != configbundle.Version {
+				return nil, src, fmt.Errorf("%s: spec version %d is not supported (expected %d)", f, doc.Version, configbundle.Version)
+			}
+			spec.merge(doc)
+		}
+	}
+	src.Digest = hex.EncodeToString(h.Sum(nil))
+	src.Revision = gitRevision(path)
+	return spec, src, nil
+}
+
+func isSpecFile(p string) bool {
+	switch strings.ToLower(filepath.Ext(p)) {
+	case ".yaml", ".yml", ".json":
+		return true
+	}
+	return false
+}
+
+// decodeFile parses one file into its documents. YAML goes through a
+// generic value and back out as JSON so both formats share the bundle's
+// json field names and decoding rules.
+func decodeFile(name string, raw []byte) ([]*Spec, error) {
+	if strings.EqualFold(filepath.Ext(name), ".json") {
+		doc, err := decodeJSON(raw)
+		if err != nil {
+			return nil, err
+		}
+		return []*Spec{doc}, nil
+	}
+	var docs []*Spec
+	dec := yaml.NewDecoder(bytes.NewReader(raw))
+	for {
+		var v any
+		if err := dec.Decode(&v); err != nil {
+			if errors.Is(err, io.EOF) {
+				return docs, nil
+			}
+			return nil, err
+		}
+		if v == nil {
+			continue
+		}
+		asJSON, err := json.Marshal(v)
+		if err != nil {
+			return nil, fmt.Errorf("document %d: %w", len(docs)+1, err)
+		}
+		doc, err := decodeJSON(asJSON)
+		if err != nil {
+			return nil, fmt.Errorf("document %d: %w", len(docs)+1, err)
+		}
+		docs = append(docs, doc)
+	}
+}
+
+func decodeJSON(raw []byte) (*Spec, error) {
+	dec := json.NewDecoder(bytes.NewReader(raw))
+	dec.DisallowUnknownFields()
+	var s Spec
+	if err := dec.Decode(&s); err != nil {
+		return nil, err
+	}
+	return &s, nil
+}
+
+// gitRevision returns the commit HEAD points at in the git working tree
+// containing path, read straight from .git so the reconciler needs no git
+// binary. Empty when path isn't in a working tree or HEAD can't be read.
+func gitRevision(path string) string {
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return ""
+	}
+	if info, err := os.Stat(abs); err == nil && !info.IsDir() {
+		abs = filepath.Dir(abs)
+	}
+	for dir := abs; ; dir = filepath.Dir(dir) {
+		gitDir := filepath.Join(dir, ".git")
+		if info, err := os.Stat(gitDir); err == nil && info.IsDir() {
+			return resolveHead(gitDir)
+		}
+		if filepath.Dir(dir) == dir {
+			return ""
+		}
+	}
+}
+
+func resolveHead(gitDir string) string {
+	head, err := os.ReadFile(filepath.Join(gitDir, "HEAD"))
+	if err != nil {
+		return ""
+	}
+	ref, ok := strings.CutPrefix(strings.TrimSpace(string(head)), "ref: ")
+	if !ok {
+		return strings.TrimSpace(string(head)) // detached HEAD
+	}
+	if sha, err := os.ReadFile(filepath.Join(gitDir, filepath.FromSlash(ref))); err == nil {
+		return strings.TrimSpace(string(sha))
+	}
+	packed, err := os.ReadFile(filepath.Join(gitDir, "packed-refs"))
+	if err != nil {
+		return ""
+	}
+	for _, line := range strings.Split(string(packed), "\n") {
+		if sha, name, ok := strings.Cut(strings.TrimSpace(line), " "); ok && name == ref {
+			return sha
+		}
+	}
+	return ""
+}
@@ -0,0 +1,138 @@
+package reconciler
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+)
+
+func writeFile(t *testing.T, dir, name, content string) string {
+	t.Helper()
+	p := filepath.Join(dir, name)
+	if err := os.MkdirAll(filepath.Dir(p), 0o755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(p, []byte(content), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	return p
+}
+
+func TestLoadSpecYAMLDocuments(t *testing.T) {
+	dir := t.TempDir()
+	p := writeFile(t, dir, "spec.yaml", `
+dispatchPools:
+  - code: orders-pool
+    name: Orders
+    concurrency: 4
+---
+serviceAccounts:
+  - code: billing
+    name: Billing
+    clients: [acme]
+    roles: [orders:viewer]
+`)
+	spec, src, err := LoadSpec(p)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(spec.DispatchPools) != 1 || spec.DispatchPools[0].Concurrency != 4 {
+		t.Errorf("dispatchPools = %+v", spec.DispatchPools)
+	}
+	if len(spec.ServiceAccounts) != 1 || spec.ServiceAccounts[0].Roles[0] != "orders:viewer" {
+		t.Errorf("serviceAccounts = %+v", spec.ServiceAccounts)
+	}
+	if len(src.Files) != 1 || src.Digest == "" {
+		t.Errorf("source = %+v", src)
+	}
+}
+
+func TestLoadSpecDirectory(t *testing.T) {
+	dir := t.TempDir()
+	writeFile(t, dir, "pools.yml", "dispatchPools:\n  - {code: a-pool, name: A, concurrency: 1}\n")
+	writeFile(t, dir, "nested/types.json", `{"eventTypes":[{"code":"orders:sales:order:created","name":"Order created"}]}`)
+	writeFile(t, dir, ".git/config.yaml", "nonsense: true\n")
+	writeFile(t, dir, "README.md", "not a spec")
+
+	spec, src, err := LoadSpec(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(src.Files) != 2 {
+		t.Errorf("files = %v, want the two spec files", src.Files)
+	}
+	if len(spec.DispatchPools) != 1 || len(spec.EventTypes) != 1 {
+		t.Errorf("spec = %+v", spec)
+	}
+
+	// The digest follows content, not load time.
+	_, again, err := LoadSpec(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if again.Digest != src.Digest {
+		t.Error("digest changed for an unchanged spec")
+	}
+	writeFile(t, dir, "pools.yml", "dispatchPools:\n  - {code: a-pool, name: A, concurrency: 2}\n")
+	if _, changed, _ := LoadSpec(dir); changed.Digest == src.Digest {
+		t.Error("digest unchanged after an edit")
+	}
+}
+
+func TestLoadSpecRejectsUnknownFields(t *testing.T) {
+	dir := t.TempDir()
+	p := writeFile(t, dir, "spec.yaml", "dispatchPool:\n  - {code: a-pool, name: A}\n")
+	if _, _, err := LoadSpec(p); err == nil || !strings.Contains(err.Error(), "dispatchPool") {
+		t.Errorf("err = %v, want unknown field", err)
+	}
+}
+
+func TestLoadSpecRejectsOtherVersions(t *testing.T) {
+	dir := t.TempDir()
+	p := writeFile(t, dir, "spec.json", `{"version":99}`)
+	if _, _, err := LoadSpec(p); err == nil {
+		t.Error("expected version error")
+	}
+}
+
+func TestExportIsAValidSpec(t *testing.T) {
+	now := time.Now().UTC()
+	b := &configbundle.Bundle{
+		Version:       configbundle.Version,
+		ExportedAt:    &now,
+		DispatchPools: []configbundle.DispatchPoolItem{{Code: "orders-pool", Name: "Orders", Concurrency: 2}},
+	}
+	raw, err := json.Marshal(b)
+	if err != nil {
+		t.Fatal(err)
+	}
+	dir := t.TempDir()
+	spec, _, err := LoadSpec(writeFile(t, dir, "export.json", string(raw)))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(spec.Bundle().DispatchPools) != 1 {
+		t.Errorf("bundle = %+v", spec.Bundle())
+	}
+}
+
+func TestGitRevision(t *testing.T) {
+	dir := t.TempDir()
+	writeFile(t, dir, ".git/HEAD", "ref: refs/heads/main\n")
+	writeFile(t, dir, ".git/refs/heads/main", "0123abcd\n")
+	p := writeFile(t, dir, "config/spec.yaml", "roles: []\n")
+	if got := gitRevision(p); got != "0123abcd" {
+		t.Errorf("revision = %q", got)
+	}
+
+	writeFile(t, dir, ".git/HEAD", "ref: refs/heads/release\n")
+	writeFile(t, dir, ".git/packed-refs", "# pack-refs with: peeled\nfeedbeef refs/heads/release\n")
+	if got := gitRevision(p); got != "feedbeef" {
+		t.Errorf("packed revision = %q", got)
+	}
+}
@@ -14,6 +14,7 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	platformmw "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/middleware"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
@@ -54,6 +55,8 @@ func Register(api huma.API, s *State) {
 	apiroute.Get(g, "listPermissions", "/api/roles/permissions", "List the platform permission catalog", s.listPermissions)
 	apiroute.Get(g, "getPermission", "/api/roles/permissions/{permission}", "Get a single permission catalog entry", s.getPermission)
 	apiroute.Delete(g, "deletePermission", "/api/roles/permissions/{permission}", "Delete a permission from the catalog", http.StatusNoContent, s.deletePermission)
+	// Route→permission registry enforced by the platform middleware.
+	apiroute.Get(g, "getPermissionMatrix", "/api/admin/permissions/matrix", "List the permission each platform route requires", s.permissionMatrix)
 }
 
 // ── Handlers ──────────────────────────────────────────────────────────
@@ -377,3 +380,24 @@ func (s *State) deletePermission(ctx context.Context, in *permissionPathInput) (
 	}
 	return &apicommon.Empty{}, nil
 }
+
+func (s *State) permissionMatrix(ctx context.Context, _ *apicommon.Empty) (*apicommon.Out[PermissionMatrixResponse], error) {
+	if err := auth.CanReadRoles(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	rows := platformmw.PermissionMatrix()
+	out := make([]RoutePermissionResponse, 0, len(rows))
+	for _, rr := range rows {
+		perms := rr.Requirement.Permissions
+		if perms == nil {
+			perms = []string{}
+		}
+		out = append(out, RoutePermissionResponse{
+			Method:      rr.Method,
+			Path:        rr.Path,
+			Access:      string(rr.Requirement.Access),
+			Permissions: perms,
+		})
+	}
+	return &apicommon.Out[PermissionMatrixResponse]{Body: PermissionMatrixResponse{Routes: out, Total: len(out)}}, nil
+}
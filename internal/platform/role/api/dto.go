@@ -133,3 +133,21 @@ type PermissionListResponse struct {
 type ApplicationFilterListResponse struct {
 	ApplicationCodes []string `json:"applicationCodes"`
 }
+
+// RoutePermissionResponse is one row of the permission matrix: what a
+// route demands before its handler runs. Access is PUBLIC,
+// AUTHENTICATED, ANCHOR, ADMIN or PERMISSION; a PERMISSION route admits
+// an anchor or a holder of any one of Permissions.
+type RoutePermissionResponse struct {
+	Method      string   `json:"method"`
+	Path        string   `json:"path"`
+	Access      string   `json:"access"`
+	Permissions []string `json:"permissions"`
+}
+
+// PermissionMatrixResponse is the wire shape for
+// GET /api/admin/permissions/matrix.
+type PermissionMatrixResponse struct {
+	Routes []RoutePermissionResponse `json:"routes"`
+	Total  int                       `json:"total"`
+}
@@ -20,6 +20,25 @@ const (
 func subjectFor(id string) string { return "platform.role." + id }
 func groupFor(id string) string   { return "platform:role:" + id }
 
+// NewRoleCreatedEvent / NewRoleUpdatedEvent build the canonical events with
+// their subject. Exported for cross-aggregate orchestrations (the
+// configuration bundle import) that write roles inside their own transaction.
+func NewRoleCreatedEvent(ec usecase.ExecutionContext, roleID, name string) RoleCreated {
+	return RoleCreated{
+		Metadata: usecase.NewEventMetadata(ec, RoleCreatedType, Source, subjectFor(roleID)),
+		RoleID:   roleID,
+		Name:     name,
+	}
+}
+
+func NewRoleUpdatedEvent(ec usecase.ExecutionContext, roleID, name string) RoleUpdated {
+	return RoleUpdated{
+		Metadata: usecase.NewEventMetadata(ec, RoleUpdatedType, Source, subjectFor(roleID)),
+		RoleID:   roleID,
+		Name:     name,
+	}
+}
+
 type RoleCreated struct {
 	Metadata usecase.EventMetadata
 	RoleID   string
@@ -2,7 +2,9 @@ package scheduler
 
 import (
 	"context"
+	"fmt"
 	"log/slog"
+	"strings"
 
 	"github.com/jackc/pgx/v5/pgxpool"
 
@@ -23,11 +25,18 @@ import (
 // group. Publishing a whole ordered batch is inherently in-order, so this is a
 // strictly cheaper way to get the same guarantee the old per-group serial
 // dispatcher provided.
+//
+// A job whose pool is tagged with a data-residency region is published to
+// that region's queue instead (SetRegionPublishers), so its delivery
+// egresses from the region's router.
 type MessageGroupDispatcher struct {
 	pool               *pgxpool.Pool
 	publisher          queue.Publisher
 	authSvc            *DispatchAuthService
 	processingEndpoint string
+	// regional maps a data-residency region to the publisher for its
+	// queue. Set once before Run; read-only after.
+	regional map[string]queue.Publisher
 }
 
 // NewMessageGroupDispatcher wires the dispatcher.
@@ -40,6 +49,23 @@ func NewMessageGroupDispatcher(pool *pgxpool.Pool, publisher queue.Publisher, au
 	}
 }
 
+// SetRegionPublishers routes the jobs of pools tagged with a region to
+// that region's publisher. Set once before Run; a region without one
+// holds its jobs PENDING.
+func (d *MessageGroupDispatcher) SetRegionPublishers(pubs map[string]queue.Publisher) {
+	d.regional = pubs
+}
+
+// ServesRegion reports whether jobs of region can be published: always
+// for "" (the default queue), otherwise only with a regional publisher.
+func (d *MessageGroupDispatcher) ServesRegion(region string) bool {
+	if region == "" {
+		return true
+	}
+	_, ok := d.regional[region]
+	return ok
+}
+
 // SubmitBatch publishes a batch of claimed jobs in one PublishBatch call. `toks`
 // MUST already be in dispatch order (the poller claims them ordered by
 // message_group, sequence, created_at); that order is preserved into the batch,
@@ -51,15 +77,38 @@ func NewMessageGroupDispatcher(pool *pgxpool.Pool, publisher queue.Publisher, au
 // harmless (FIFO content-dedup + the endpoint's terminal-status check). A crash
 // between the caller's commit and this publish leaves rows QUEUED for stale
 // recovery — the same failure mode the recovery loop already covers.
+//
+// Jobs for different regions go out as one PublishBatch per region, each in
+// claim order, and fail and revert independently.
 func (d *MessageGroupDispatcher) SubmitBatch(ctx context.Context, toks []DispatchJobToken) {
 	if len(toks) == 0 {
 		return
 	}
+	var order []string
+	byRegion := make(map[string][]DispatchJobToken)
+	for _, tok := range toks {
+		if _, seen := byRegion[tok.Region]; !seen {
+			order = append(order, tok.Region)
+		}
+		byRegion[tok.Region] = append(byRegion[tok.Region], tok)
+	}
+	for _, region := range order {
+		pub := d.publisher
+		if region != "" {
+			pub = d.regional[region]
+		}
+		d.publishBatch(ctx, pub, byRegion[region])
+	}
+}
+
+// publishBatch publishes toks through pub, reverting them QUEUED→PENDING
+// on failure.
+func (d *MessageGroupDispatcher) publishBatch(ctx context.Context, pub queue.Publisher, toks []DispatchJobToken) {
 	msgs := make([]common.Message, len(toks))
 	for i, tok := range toks {
 		msgs[i] = d.buildMessage(tok)
 	}
-	if _, err := d.publisher.PublishBatch(ctx, msgs); err != nil {
+	if _, err := pub.PublishBatch(ctx, msgs); err != nil {
 		ids := make([]string, len(toks))
 		for i, tok := range toks {
 			ids[i] = tok.JobID
@@ -90,5 +139,32 @@ func (d *MessageGroupDispatcher) buildMessage(tok DispatchJobToken) common.Messa
 		group := tok.MessageGroup // copy: don't alias the loop/param variable
 		msg.MessageGroupID = &group
 	}
+	if tok.Region != "" {
+		region := tok.Region
+		msg.Region = &region
+	}
 	return msg
 }
+
+// ParseRegionQueues parses region → queue URI pairs written as
+// "eu=sqs://...,us=sqs://...". Regions are lowercased.
+func ParseRegionQueues(raw string) (map[string]string, error) {
+	out := make(map[string]string)
+	for _, entry := range strings.Split(raw, ",") {
+		entry = strings.TrimSpace(entry)
+		if entry == "" {
+			continue
+		}
+		region, uri, ok := strings.Cut(entry, "=")
+		region = strings.ToLower(strings.TrimSpace(region))
+		uri = strings.TrimSpace(uri)
+		if !ok || region == "" || uri == "" {
+			return nil, fmt.Errorf("region queue %q: want region=uri", entry)
+		}
+		if _, dup := out[region]; dup {
+			return nil, fmt.Errorf("region queue %q: region %q listed twice", entry, region)
+		}
+		out[region] = uri
+	}
+	return out, nil
+}
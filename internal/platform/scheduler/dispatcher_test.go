@@ -0,0 +1,77 @@
+package scheduler
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+// recordingPublisher records each PublishBatch as the message IDs and
+// regions it carried.
+type recordingPublisher struct {
+	batches [][]string
+	regions []*string
+}
+
+func (p *recordingPublisher) Identifier() string { return "recording" }
+
+func (p *recordingPublisher) Publish(ctx context.Context, m common.Message) (string, error) {
+	_, err := p.PublishBatch(ctx, []common.Message{m})
+	return m.ID, err
+}
+
+func (p *recordingPublisher) PublishBatch(_ context.Context, msgs []common.Message) ([]string, error) {
+	ids := make([]string, 0, len(msgs))
+	for _, m := range msgs {
+		ids = append(ids, m.ID)
+		p.regions = append(p.regions, m.Region)
+	}
+	p.batches = append(p.batches, ids)
+	return ids, nil
+}
+
+func TestSubmitBatchPublishesPerRegion(t *testing.T) {
+	def, eu := &recordingPublisher{}, &recordingPublisher{}
+	d := NewMessageGroupDispatcher(nil, def, NewDispatchAuthService("s"), "http://localhost/api/dispatch/process")
+	d.SetRegionPublishers(map[string]queue.Publisher{"eu": eu})
+
+	assert.True(t, d.ServesRegion(""))
+	assert.True(t, d.ServesRegion("eu"))
+	assert.False(t, d.ServesRegion("us"))
+
+	d.SubmitBatch(context.Background(), []DispatchJobToken{
+		{JobID: "j1"},
+		{JobID: "j2", Region: "eu"},
+		{JobID: "j3"},
+		{JobID: "j4", Region: "eu"},
+	})
+	assert.Equal(t, [][]string{{"j1", "j3"}}, def.batches)
+	assert.Equal(t, [][]string{{"j2", "j4"}}, eu.batches, "claim order is kept within a region")
+	for _, r := range def.regions {
+		assert.Nil(t, r)
+	}
+	for _, r := range eu.regions {
+		require.NotNil(t, r)
+		assert.Equal(t, "eu", *r)
+	}
+}
+
+func TestParseRegionQueues(t *testing.T) {
+	got, err := ParseRegionQueues(" EU = sqs://eu-dispatch , us=postgres://db?sslmode=disable,")
+	require.NoError(t, err)
+	assert.Equal(t, map[string]string{"eu": "sqs://eu-dispatch", "us": "postgres://db?sslmode=disable"}, got)
+
+	got, err = ParseRegionQueues("")
+	require.NoError(t, err)
+	assert.Empty(t, got)
+
+	for _, bad := range []string{"eu", "=sqs://x", "eu=", "eu=a,EU=b"} {
+		_, err := ParseRegionQueues(bad)
+		assert.Error(t, err, bad)
+	}
+}
@@ -10,6 +10,7 @@ import (
 	"github.com/jackc/pgx/v5/pgxpool"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
 )
 
 // defaultMessageGroup is the grouping key for jobs without a
@@ -90,6 +91,12 @@ func (c *PausedConnectionCache) refresh(ctx context.Context) error {
 	return nil
 }
 
+// KillSwitches reports the engaged kill switches. Satisfied by
+// *killswitch.Cache.
+type KillSwitches interface {
+	Snapshot(ctx context.Context) (killswitch.Set, error)
+}
+
 // PendingJobPoller polls msg_dispatch_jobs for PENDING jobs ready to
 // dispatch (next_retry_at <= NOW or null), filters them through the
 // pause + block-on-error checks, and submits to the MessageGroupDispatcher.
@@ -98,6 +105,9 @@ type PendingJobPoller struct {
 	pool        *pgxpool.Pool
 	dispatcher  *MessageGroupDispatcher
 	pausedCache *PausedConnectionCache
+	// killSwitches, when set, holds back the jobs of killed clients,
+	// subscriptions and pools. Set by Scheduler.SetKillSwitches.
+	killSwitches KillSwitches
 	// IsLeader gates claiming: when non-nil and false, the poller idles.
 	// The per-group FIFO dispatcher is in-process only, so within-group
 	// ordering requires a single active scheduler — concurrent SKIP-LOCKED
@@ -138,6 +148,17 @@ func (p *PendingJobPoller) pollOnce(ctx context.Context) error {
 	if err != nil {
 		return err
 	}
+	var killed killswitch.Set
+	if p.killSwitches != nil {
+		// An unreadable switch set skips the tick: dispatching a killed
+		// target's jobs is worse than dispatching nothing for a second.
+		if killed, err = p.killSwitches.Snapshot(ctx); err != nil {
+			return err
+		}
+	}
+	if err := p.expireDue(ctx); err != nil {
+		return err
+	}
 	tx, err := p.pool.Begin(ctx)
 	if err != nil {
 		return err
@@ -156,12 +177,18 @@ func (p *PendingJobPoller) pollOnce(ctx context.Context) error {
 	// failed job to NOW()+backoff (status back to PENDING) and ACKs the queue
 	// message, so the poller is the single re-dispatch driver — no queue-NACK
 	// racing the poll. A NULL scheduled_for (every freshly-created job) is
-	// always eligible.
+	// always eligible. Pull jobs are never pushed: their consumer leases
+	// them through GET /api/consume. The pool's and client's data-residency
+	// regions ride along for filterResidency.
 	rows, err := tx.Query(ctx,
-		`SELECT id, subscription_id, message_group, mode, attempt_count, target_url
-		   FROM msg_dispatch_jobs
+		`SELECT id, subscription_id, client_id, dispatch_pool_id, message_group, mode, attempt_count, target_url,
+		        (SELECT p.region FROM msg_dispatch_pools p WHERE p.id = j.dispatch_pool_id),
+		        (SELECT c.region FROM tnt_clients c WHERE c.id = j.client_id)
+		   FROM msg_dispatch_jobs j
 		  WHERE status = 'PENDING'
+		    AND protocol <> 'PULL'
 		    AND (scheduled_for IS NULL OR scheduled_for <= NOW())
+		    AND (expires_at IS NULL OR expires_at > NOW())
 		  ORDER BY message_group ASC NULLS LAST, sequence ASC, created_at ASC
 		  LIMIT $1
 		  FOR UPDATE SKIP LOCKED`,
@@ -172,18 +199,29 @@ func (p *PendingJobPoller) pollOnce(ctx context.Context) error {
 	var claims []dispatchClaim
 	for rows.Next() {
 		var c dispatchClaim
-		var msgGroup *string
-		var subID *string
-		if err := rows.Scan(&c.id, &subID, &msgGroup, &c.mode, &c.attempt, &c.target); err != nil {
+		var msgGroup, subID, clientID, poolID, poolRegion, clientRegion *string
+		if err := rows.Scan(&c.id, &subID, &clientID, &poolID, &msgGroup, &c.mode, &c.attempt, &c.target, &poolRegion, &clientRegion); err != nil {
 			rows.Close()
 			return err
 		}
 		if subID != nil {
 			c.subID = *subID
 		}
+		if clientID != nil {
+			c.clientID = *clientID
+		}
+		if poolID != nil {
+			c.poolID = *poolID
+		}
 		if msgGroup != nil {
 			c.group = *msgGroup
 		}
+		if poolRegion != nil {
+			c.poolRegion = *poolRegion
+		}
+		if clientRegion != nil {
+			c.clientRegion = *clientRegion
+		}
 		claims = append(claims, c)
 	}
 	rows.Close()
@@ -201,9 +239,16 @@ func (p *PendingJobPoller) pollOnce(ctx context.Context) error {
 	//
 	// Filter order mirrors the Rust poll (poller.rs): paused-subscription
 	// filter, then group, then the blocked-group hold-back, then the
-	// per-mode filter. Skipped claims are simply left PENDING — their row
-	// locks release at commit and the next poll retries them.
+	// per-mode filter. The kill-switch and residency filters (Go-only) run
+	// with the pause filter. Skipped claims are simply left PENDING — their
+	// row locks release at commit and the next poll retries them.
 	live, skippedPaused := filterPausedSubscriptions(claims, paused)
+	live, skippedKilled := filterKilled(live, killed)
+	live, skippedResidency := filterResidency(live, p.dispatcher.ServesRegion)
+	if skippedResidency > 0 {
+		slog.Warn("dispatch jobs held: no queue for their data-residency region, or pool outside the client's region",
+			"count", skippedResidency)
+	}
 
 	byGroup := groupByMessageGroup(live)
 	candidates := make([]string, 0, len(byGroup))
@@ -235,6 +280,7 @@ func (p *PendingJobPoller) pollOnce(ctx context.Context) error {
 				JobID:        c.id,
 				MessageGroup: c.group,
 				TargetURL:    c.target,
+				Region:       c.poolRegion,
 			})
 		}
 	}
@@ -257,20 +303,48 @@ func (p *PendingJobPoller) pollOnce(ctx context.Context) error {
 	// the same failure mode the recovery loop already covers.
 	p.dispatcher.SubmitBatch(ctx, tokens)
 
-	if len(queued) > 0 || skippedPaused > 0 || skippedBlocked > 0 {
+	if len(queued) > 0 || skippedPaused > 0 || skippedKilled > 0 || skippedResidency > 0 || skippedBlocked > 0 {
 		slog.Debug("poll tick",
 			"queued", len(queued),
 			"skipped_paused", skippedPaused,
+			"skipped_killed", skippedKilled,
+			"skipped_residency", skippedResidency,
 			"skipped_blocked", skippedBlocked)
 	}
 	return nil
 }
 
-// dispatchClaim is one PENDING row claimed by the poll query. group and
-// subID are "" when the column is NULL.
+// expireDue marks up to a batch of PENDING jobs whose expires_at has
+// passed EXPIRED, so a backlog that built up during an outage is dropped
+// instead of reaching consumers late. It runs in its own statement ahead
+// of the claim, which also skips expired rows; a larger backlog drains a
+// batch per tick. Pull jobs expire the same way.
+func (p *PendingJobPoller) expireDue(ctx context.Context) error {
+	tag, err := p.pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET status = 'EXPIRED', last_error = 'expired before delivery',
+		        completed_at = NOW(), updated_at = NOW()
+		  WHERE id IN (SELECT id FROM msg_dispatch_jobs
+		                WHERE status = 'PENDING' AND expires_at <= NOW()
+		                LIMIT $1
+		                FOR UPDATE SKIP LOCKED)`,
+		p.cfg.BatchSize)
+	if err != nil {
+		return err
+	}
+	if n := tag.RowsAffected(); n > 0 {
+		slog.Warn("dispatch jobs expired before delivery", "count", n)
+	}
+	return nil
+}
+
+// dispatchClaim is one PENDING row claimed by the poll query. group,
+// subID, clientID and poolID are "" when the column is NULL; poolRegion
+// and clientRegion are "" when unrestricted.
 type dispatchClaim struct {
-	id, subID, group, mode, target string
-	attempt                        int32
+	id, subID, clientID, poolID, group, mode, target string
+	poolRegion, clientRegion                         string
+	attempt                                          int32
 }
 
 // messageGroupKey maps a claim's message_group to its grouping key: jobs
@@ -315,6 +389,42 @@ func filterPausedSubscriptions(claims []dispatchClaim, paused map[string]struct{
 	return kept, len(claims) - len(kept)
 }
 
+// filterKilled drops claims whose client, subscription or dispatch pool has
+// a kill switch engaged; like paused claims they stay PENDING until it is
+// released. Returns the survivors and the dropped count.
+func filterKilled(claims []dispatchClaim, killed killswitch.Set) ([]dispatchClaim, int) {
+	if killed.Empty() {
+		return claims, 0
+	}
+	kept := make([]dispatchClaim, 0, len(claims))
+	for _, c := range claims {
+		if killed.Stops(c.clientID, c.subID, c.poolID) {
+			continue
+		}
+		kept = append(kept, c)
+	}
+	return kept, len(claims) - len(kept)
+}
+
+// filterResidency drops claims that would egress outside their data-residency
+// region: a client's jobs routed through a pool in another region (or an
+// unrestricted one), and a regional pool's jobs when this scheduler has no
+// queue for that region. Like paused claims they stay PENDING until the
+// configuration is fixed. Returns the survivors and the dropped count.
+func filterResidency(claims []dispatchClaim, servesRegion func(string) bool) ([]dispatchClaim, int) {
+	kept := make([]dispatchClaim, 0, len(claims))
+	for _, c := range claims {
+		if c.clientRegion != "" && c.poolRegion != c.clientRegion {
+			continue
+		}
+		if !servesRegion(c.poolRegion) {
+			continue
+		}
+		kept = append(kept, c)
+	}
+	return kept, len(claims) - len(kept)
+}
+
 // filterByDispatchMode keeps the claims whose mode allows dispatch given
 // the blocked groups: IMMEDIATE always dispatches; NEXT_ON_ERROR and
 // BLOCK_ON_ERROR hold back while their group is blocked. 1:1 port of
@@ -376,4 +486,7 @@ type DispatchJobToken struct {
 	JobID        string
 	MessageGroup string
 	TargetURL    string
+	// Region is the job's pool's data-residency region; "" publishes to
+	// the default queue.
+	Region string
 }
@@ -145,6 +145,31 @@ func TestPollOnce_BlockedGroupHoldback(t *testing.T) {
 		"resolved group must dispatch on the next poll")
 }
 
+// TestPollOnce_ExpiresStaleJobs pins expiry: a PENDING job past its
+// expires_at is marked EXPIRED and never queued, while one that has not
+// expired is claimed as usual.
+func TestPollOnce_ExpiresStaleJobs(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+	poller := newTestPoller(pool)
+
+	const (
+		staleID = "djexpstale001"
+		freshID = "djexpfresh001"
+	)
+	seedJob(t, pool, staleID, "PENDING", "", "")
+	seedJob(t, pool, freshID, "PENDING", "", "")
+	_, err := pool.Exec(ctx,
+		`UPDATE msg_dispatch_jobs
+		    SET expires_at = CASE id WHEN $1 THEN NOW() - INTERVAL '1 hour' ELSE NOW() + INTERVAL '1 hour' END
+		  WHERE id IN ($1, $2)`, staleID, freshID)
+	require.NoError(t, err)
+
+	require.NoError(t, poller.pollOnce(ctx))
+	require.Equal(t, "EXPIRED", jobStatus(t, pool, staleID), "an expired job must not be dispatched")
+	require.Equal(t, "QUEUED", jobStatus(t, pool, freshID), "an unexpired job is claimed as usual")
+}
+
 // TestPollOnce_NullGroupFailureDoesNotBlock pins the NULL semantics of
 // the blocked-group query: `message_group = ANY($1)` never matches NULL,
 // so a failed ungrouped job must not hold back other ungrouped jobs
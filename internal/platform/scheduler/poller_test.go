@@ -4,6 +4,8 @@ import (
 	"testing"
 
 	"github.com/stretchr/testify/assert"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
 )
 
 // These tests mirror the Rust poller's unit tests
@@ -150,3 +152,49 @@ func TestFilterPausedSubscriptions_NothingPaused(t *testing.T) {
 	assert.Equal(t, []string{"j1"}, claimIDs(kept))
 	assert.Zero(t, skipped)
 }
+
+func TestFilterKilled(t *testing.T) {
+	killed := killswitch.NewSet([]killswitch.KillSwitch{
+		{Scope: killswitch.ScopeClient, TargetID: "clt_killed"},
+		{Scope: killswitch.ScopeSubscription, TargetID: "sub_killed"},
+		{Scope: killswitch.ScopePool, TargetID: "dpl_killed"},
+	})
+	mk := func(id, clientID, subID, poolID string) dispatchClaim {
+		c := mkClaimWithSub(id, "g", subID)
+		c.clientID, c.poolID = clientID, poolID
+		return c
+	}
+	kept, skipped := filterKilled([]dispatchClaim{
+		mk("j1", "clt_live", "sub_live", "dpl_live"),
+		mk("j2", "clt_killed", "sub_live", "dpl_live"),
+		mk("j3", "clt_live", "sub_killed", ""),
+		mk("j4", "", "", "dpl_killed"),
+		mk("j5", "", "", ""), // platform job without a subscription or pool
+	}, killed)
+	assert.Equal(t, []string{"j1", "j5"}, claimIDs(kept))
+	assert.Equal(t, 3, skipped)
+
+	claims := []dispatchClaim{mk("j1", "clt_killed", "", "")}
+	kept, skipped = filterKilled(claims, killswitch.Set{})
+	assert.Equal(t, []string{"j1"}, claimIDs(kept))
+	assert.Zero(t, skipped)
+}
+
+func TestFilterResidency(t *testing.T) {
+	mk := func(id, clientRegion, poolRegion string) dispatchClaim {
+		c := mkClaim(id, "g", "IMMEDIATE")
+		c.clientRegion, c.poolRegion = clientRegion, poolRegion
+		return c
+	}
+	serves := func(region string) bool { return region == "" || region == "eu" }
+	kept, skipped := filterResidency([]dispatchClaim{
+		mk("j1", "", ""),
+		mk("j2", "eu", "eu"),
+		mk("j3", "", "eu"),   // unrestricted client on a regional pool
+		mk("j4", "eu", ""),   // regional client on an unrestricted pool
+		mk("j5", "eu", "us"), // pool in the wrong region
+		mk("j6", "", "us"),   // no queue for the pool's region
+	}, serves)
+	assert.Equal(t, []string{"j1", "j2", "j3"}, claimIDs(kept))
+	assert.Equal(t, 3, skipped)
+}
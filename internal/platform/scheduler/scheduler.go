@@ -120,6 +120,18 @@ func (s *Scheduler) Dispatcher() *MessageGroupDispatcher { return s.dispatcher }
 // AuthService exposes the dispatch-callback HMAC service.
 func (s *Scheduler) AuthService() *DispatchAuthService { return s.authService }
 
+// SetKillSwitches makes the poller hold back the jobs of killed clients,
+// subscriptions and pools. Set once before Run; without it nothing is
+// held.
+func (s *Scheduler) SetKillSwitches(ks KillSwitches) { s.poller.killSwitches = ks }
+
+// SetRegionPublishers publishes the jobs of pools tagged with a
+// data-residency region to that region's queue. Set once before Run;
+// without one, a region's jobs are held PENDING.
+func (s *Scheduler) SetRegionPublishers(pubs map[string]queue.Publisher) {
+	s.dispatcher.SetRegionPublishers(pubs)
+}
+
 // Run starts the poller + stale-recovery loops and blocks until ctx is
 // cancelled. The dispatcher is event-driven via Submit calls from the
 // poller, so it doesn't need its own loop. fc-server uses this entry
@@ -26,6 +26,7 @@ import (
 
 	"github.com/danielgtaylor/huma/v2"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
@@ -67,6 +68,9 @@ type State struct {
 	ScheduledJobs *scheduledjob.Repository
 	Specs         *openapispecs.Repository
 	UoW           *usecasepgx.UnitOfWork
+	// Targets is the outbound policy synced subscription endpoints must
+	// pass; nil leaves them unchecked.
+	Targets *outbound.Policies
 }
 
 // SyncResultResponse is the shared result for the list-based sync
@@ -323,7 +327,7 @@ func (s *State) syncSubscriptions(ctx context.Context, in *syncSubscriptionsInpu
 		RemoveUnlisted:  in.RemoveUnlisted,
 	}
 	ec := usecase.NewExecutionContext(ac.PrincipalID)
-	ev, err := usecaseop.Run(ctx, s.UoW, subscriptionops.SyncSubscriptions(s.Subscriptions, s.Connections, s.DispatchPools), cmd, ec)
+	ev, err := usecaseop.Run(ctx, s.UoW, subscriptionops.SyncSubscriptions(s.Subscriptions, s.Connections, s.DispatchPools, s.Targets), cmd, ec)
 	if err != nil {
 		return nil, err
 	}
@@ -122,9 +122,10 @@ func platformEventSchemas() map[string]json.RawMessage {
 	// ── platform:iam:client ─────────────────────────────────────────────
 	m["platform:iam:client:created"] = obj(
 		reqStr("clientId"), reqStr("name"), reqStr("identifier"), optStr("description"),
+		optStr("region"),
 	)
 	m["platform:iam:client:updated"] = obj(
-		reqStr("clientId"), optStr("name"), optStr("description"),
+		reqStr("clientId"), optStr("name"), optStr("description"), optStr("region"),
 	)
 	m["platform:iam:client:activated"] = obj(reqStr("clientId"), reqStr("previousStatus"))
 	m["platform:iam:client:suspended"] = obj(reqStr("clientId"), reqStr("reason"))
@@ -171,6 +172,12 @@ func platformEventSchemas() map[string]json.RawMessage {
 	m["platform:iam:application:disabled-for-client"] = obj(
 		reqStr("applicationId"), reqStr("clientId"), reqStr("configId"),
 	)
+	m["platform:iam:application:producer-binding-added"] = obj(
+		reqStr("applicationId"), reqStr("bindingId"), optStr("principalId"), reqStr("eventTypePattern"),
+	)
+	m["platform:iam:application:producer-binding-removed"] = obj(
+		reqStr("applicationId"), reqStr("bindingId"), optStr("principalId"), reqStr("eventTypePattern"),
+	)
 
 	// ── platform:iam:anchor-domain ──────────────────────────────────────
 	m["platform:iam:anchor-domain:created"] = obj(reqStr("anchorDomainId"), reqStr("domain"))
@@ -220,6 +227,18 @@ func platformEventSchemas() map[string]json.RawMessage {
 		reqStr("eventTypeId"), reqStr("version"), optStr("deprecatedVersion"),
 	)
 	m["platform:admin:eventtype:schema-deprecated"] = obj(reqStr("eventTypeId"), reqStr("version"))
+	m["platform:admin:eventtype:data-policy-updated"] = obj(
+		reqStr("eventTypeId"), reqStr("code"),
+		optU32("payloadRetentionDays"), optU32("metadataRetentionDays"),
+		reqStrArray("redactFields"),
+	)
+	m["platform:admin:eventtype:registry-binding-updated"] = obj(
+		reqStr("eventTypeId"), reqStr("code"), optStr("registrySubject"), reqStr("registryMode"),
+	)
+	m["platform:admin:eventtype:lifecycle-updated"] = obj(
+		reqStr("eventTypeId"), reqStr("code"),
+		optStr("deprecatedAt"), optStr("sunsetAt"), optStr("migrationHint"),
+	)
 	m["platform:admin:eventtypes:synced"] = obj(
 		reqStr("applicationCode"),
 		reqU32("created"), reqU32("updated"), reqU32("deleted"),
@@ -242,10 +261,11 @@ func platformEventSchemas() map[string]json.RawMessage {
 	// ── platform:admin:dispatch-pool ────────────────────────────────────
 	m["platform:admin:dispatch-pool:created"] = obj(
 		reqStr("dispatchPoolId"), reqStr("code"), reqStr("name"), optStr("clientId"),
+		optStr("region"),
 	)
 	m["platform:admin:dispatch-pool:updated"] = obj(
 		reqStr("dispatchPoolId"), optStr("name"),
-		optU32("rateLimit"), optU32("concurrency"),
+		optU32("rateLimit"), optU32("concurrency"), optStr("region"),
 	)
 	m["platform:admin:dispatch-pool:archived"] = obj(reqStr("dispatchPoolId"), reqStr("code"))
 	m["platform:admin:dispatch-pool:deleted"] = obj(reqStr("dispatchPoolId"), reqStr("code"))
@@ -272,6 +292,97 @@ func platformEventSchemas() map[string]json.RawMessage {
 		reqU32("created"), reqU32("updated"), reqU32("deleted"),
 		reqStrArray("syncedCodes"),
 	)
+	m["platform:admin:subscription:shadow-promoted"] = obj(
+		reqStr("subscriptionId"), reqStr("previousEndpoint"), reqStr("endpoint"),
+	)
+	m["platform:admin:subscription:traffic-split-set"] = obj(
+		reqStr("subscriptionId"), reqStr("endpoint"), reqU32("endpointWeight"),
+		prop{name: "split", required: true, schema: map[string]any{
+			"type": "array",
+			"items": map[string]any{
+				"type": "object",
+				"properties": map[string]any{
+					"endpoint": map[string]any{"type": "string"},
+					"weight":   map[string]any{"type": "integer", "minimum": 1, "maximum": 100},
+				},
+				"required": []string{"endpoint", "weight"},
+			},
+		}},
+	)
+
+	// ── platform:admin:erasure ──────────────────────────────────────────
+	m["platform:admin:erasure:requested"] = obj(
+		reqStr("erasureRequestId"), reqStr("subjectType"), reqStr("subjectHash"),
+		reqStrArray("contextKeys"), optStr("clientId"),
+	)
+
+	// ── platform:admin:signing-key ──────────────────────────────────────
+	m["platform:admin:signing-key:rotated"] = obj(
+		reqStr("signingKeyId"), reqStr("clientId"), reqStr("keyId"), reqStr("activeFrom"),
+		reqStrArray("retiringKeyIds"), optStr("retireAt"),
+	)
+	m["platform:admin:signing-key:expired"] = obj(
+		reqStr("signingKeyId"), reqStr("clientId"), reqStr("keyId"), reqStr("expiresAt"),
+	)
+
+	// ── platform:admin:kill-switch ──────────────────────────────────────
+	m["platform:admin:kill-switch:engaged"] = obj(
+		reqStr("killSwitchId"), reqStr("scope"), reqStr("targetId"), reqStr("reason"),
+	)
+	m["platform:admin:kill-switch:released"] = obj(
+		reqStr("killSwitchId"), reqStr("scope"), reqStr("targetId"), reqU32("engagedForSeconds"),
+	)
+
+	// ── platform:admin:anomaly-setting ──────────────────────────────────
+	m["platform:admin:anomaly-setting:updated"] = obj(
+		reqStr("settingId"), reqStr("entityKind"), reqStr("entityKey"),
+		optNum("sensitivity"), reqBool("enabled"), optStr("snoozedUntil"),
+	)
+
+	// ── platform:admin:federation-peer ──────────────────────────────────
+	m["platform:admin:federation-peer:created"] = obj(
+		reqStr("peerId"), reqStr("code"), reqStr("baseUrl"), reqStrArray("eventTypes"),
+	)
+	m["platform:admin:federation-peer:updated"] = obj(
+		reqStr("peerId"), reqStr("baseUrl"), reqStrArray("eventTypes"), reqStr("status"), reqBool("secretChanged"),
+	)
+	m["platform:admin:federation-peer:deleted"] = obj(reqStr("peerId"), reqStr("code"))
+
+	// ── platform:admin:archive-replay ───────────────────────────────────
+	m["platform:admin:archive-replay:requested"] = obj(
+		reqStr("replayId"), reqStr("from"), reqStr("to"), reqStrArray("eventTypes"),
+		optStr("clientId"), optStr("subscriptionId"),
+	)
+	m["platform:admin:archive-replay:cancelled"] = obj(reqStr("replayId"), reqU32("eventsReplayed"))
+
+	// ── flowcatalyst:* — public copies (see publicevents) ──────────────
+	// These describe the data customers receive, so they track the events'
+	// actual payloads.
+	for _, a := range []string{"created", "updated", "paused", "resumed", "deleted"} {
+		m["flowcatalyst:config:subscription:"+a] = obj(
+			reqStr("subscriptionId"), optStr("code"), optStr("name"),
+		)
+	}
+	for _, a := range []string{"created", "updated", "suspended", "activated", "archived", "deleted"} {
+		m["flowcatalyst:config:dispatch-pool:"+a] = obj(
+			reqStr("poolId"), optStr("code"), optStr("name"),
+		)
+	}
+	for _, a := range []string{"created", "updated", "deleted"} {
+		m["flowcatalyst:config:connection:"+a] = obj(
+			reqStr("connectionId"), optStr("code"), optStr("name"),
+		)
+	}
+	for _, a := range []string{"created", "updated", "token-rotated", "secret-rotated", "deleted"} {
+		m["flowcatalyst:iam:service-account:"+a] = obj(
+			reqStr("serviceAccountId"), optStr("code"), optStr("name"),
+		)
+	}
+	m["flowcatalyst:iam:service-account:roles-assigned"] = obj(
+		reqStr("serviceAccountId"), reqStrArray("rolesAdded"), reqStrArray("rolesRemoved"),
+	)
+	m["flowcatalyst:iam:signing-key:rotated"] = m["platform:admin:signing-key:rotated"]
+	m["flowcatalyst:iam:signing-key:expired"] = m["platform:admin:signing-key:expired"]
 
 	return m
 }
@@ -322,6 +433,10 @@ func optU32(name string) prop {
 	return prop{name: name, schema: map[string]any{"type": []string{"integer", "null"}, "minimum": 0}}
 }
 
+func optNum(name string) prop {
+	return prop{name: name, schema: map[string]any{"type": []string{"number", "null"}}}
+}
+
 func reqStrArray(name string) prop {
 	return prop{
 		name:     name,
@@ -9,14 +9,16 @@ import (
 	"time"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/publicevents"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
 )
 
 // PlatformEventTypeDef mirrors fc-platform/src/event_type/operations::SyncEventTypeInput.
 type PlatformEventTypeDef struct {
-	Code   string
-	Name   string
-	Schema json.RawMessage // nil-OK; empty means "no schema attached yet"
+	Code         string
+	Name         string
+	Schema       json.RawMessage // nil-OK; empty means "no schema attached yet"
+	ClientScoped bool            // events carry the owning client (the public flowcatalyst:* types)
 }
 
 // PlatformEventTypes returns the full catalog in the same order as
@@ -65,7 +67,8 @@ func PlatformEventTypes() []PlatformEventTypeDef {
 
 	group("platform:iam:application",
 		"created", "updated", "activated", "deactivated", "deleted",
-		"service-account-provisioned", "enabled-for-client", "disabled-for-client")
+		"service-account-provisioned", "enabled-for-client", "disabled-for-client",
+		"producer-binding-added", "producer-binding-removed")
 
 	group("platform:iam:anchor-domain", "created", "deleted")
 
@@ -80,7 +83,8 @@ func PlatformEventTypes() []PlatformEventTypeDef {
 
 	group("platform:admin:eventtype",
 		"created", "updated", "archived", "deleted",
-		"schema-added", "schema-finalised", "schema-deprecated")
+		"schema-added", "schema-finalised", "schema-deprecated",
+		"data-policy-updated", "registry-binding-updated", "lifecycle-updated")
 	push("platform:admin:eventtypes:synced", "Event Types Synced")
 
 	group("platform:admin:connection", "created", "updated", "deleted")
@@ -90,7 +94,31 @@ func PlatformEventTypes() []PlatformEventTypeDef {
 	push("platform:admin:dispatch-pools:synced", "Dispatch Pools Synced")
 
 	group("platform:admin:subscription",
-		"created", "updated", "paused", "resumed", "deleted", "synced")
+		"created", "updated", "paused", "resumed", "deleted", "synced", "shadow-promoted",
+		"traffic-split-set")
+
+	group("platform:admin:erasure", "requested")
+
+	group("platform:admin:signing-key", "rotated", "expired")
+
+	group("platform:admin:kill-switch", "engaged", "released")
+
+	group("platform:admin:anomaly-setting", "updated")
+
+	group("platform:admin:federation-peer", "created", "updated", "deleted")
+
+	group("platform:admin:archive-replay", "requested", "cancelled")
+
+	// ── flowcatalyst:* ──────────────────────────────────────────────────
+	// Public copies of configuration changes, written per owning client.
+	for _, t := range publicevents.Types() {
+		out = append(out, PlatformEventTypeDef{
+			Code:         t.Code,
+			Name:         t.Name,
+			Schema:       schemas[t.Code],
+			ClientScoped: true,
+		})
+	}
 
 	return out
 }
@@ -120,10 +148,10 @@ func (s *Seeder) seedPlatformEventTypes(ctx context.Context) error {
 				`INSERT INTO msg_event_types
 				     (id, code, name, description, status, source, client_scoped,
 				      application, subdomain, aggregate, created_at, updated_at)
-				 VALUES ($1, $2, $3, $4, 'CURRENT', 'UI', false, $5, $6, $7, $8, $8)
+				 VALUES ($1, $2, $3, $4, 'CURRENT', 'UI', $9, $5, $6, $7, $8, $8)
 				 ON CONFLICT (code) DO NOTHING`,
 				id, et.Code, et.Name, et.Description,
-				et.Application, et.Subdomain, et.Aggregate, now); err != nil {
+				et.Application, et.Subdomain, et.Aggregate, now, d.ClientScoped); err != nil {
 				return fmt.Errorf("insert event type %s: %w", d.Code, err)
 			}
 			// race-safe re-fetch
@@ -17,8 +17,12 @@ func TestPlatformEventTypesCatalogShape(t *testing.T) {
 			t.Fatalf("duplicate definition: %s", d.Code)
 		}
 		seen[d.Code] = true
-		if !strings.HasPrefix(d.Code, "platform:iam:") && !strings.HasPrefix(d.Code, "platform:admin:") {
-			t.Fatalf("unexpected prefix on %s — must be platform:iam or platform:admin", d.Code)
+		public := strings.HasPrefix(d.Code, "flowcatalyst:")
+		if !public && !strings.HasPrefix(d.Code, "platform:iam:") && !strings.HasPrefix(d.Code, "platform:admin:") {
+			t.Fatalf("unexpected prefix on %s — must be platform:iam, platform:admin or flowcatalyst", d.Code)
+		}
+		if d.ClientScoped != public {
+			t.Fatalf("%s: only the public flowcatalyst:* types are client-scoped", d.Code)
 		}
 		if d.Name == "" {
 			t.Fatalf("%s: empty name", d.Code)
@@ -14,7 +14,9 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/idempotency"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
@@ -28,6 +30,7 @@ type State struct {
 	Principals   *principal.Repository
 	OAuthClients *platformauth.OAuthClientRepo
 	UoW          *usecasepgx.UnitOfWork
+	Idem         *idempotency.Store // optional; nil ignores Idempotency-Key
 }
 
 const tag = "service-accounts"
@@ -38,6 +41,7 @@ func Register(api huma.API, s *State) {
 	apiroute.Get(g, "listServiceAccounts", "/api/service-accounts", "List service accounts", s.list)
 	apiroute.Post(g, "createServiceAccount", "/api/service-accounts", "Create a service account", http.StatusCreated, s.create)
 	apiroute.Get(g, "getServiceAccountByCode", "/api/service-accounts/code/{code}", "Get a service account by code", s.getByCode)
+	apiroute.Put(g, "upsertServiceAccount", "/api/service-accounts/code/{code}", "Create or update a service account by code", http.StatusOK, s.upsert)
 	apiroute.Get(g, "getServiceAccount", "/api/service-accounts/{id}", "Get a service account by id", s.getByID)
 	apiroute.Put(g, "updateServiceAccount", "/api/service-accounts/{id}", "Update a service account", http.StatusNoContent, s.update)
 	apiroute.Post(g, "deactivateServiceAccount", "/api/service-accounts/{id}/deactivate", "Deactivate a service account", http.StatusNoContent, s.deactivate)
@@ -76,7 +80,7 @@ type getByCodeInput struct {
 	Code string `path:"code"`
 }
 
-func (s *State) getByCode(ctx context.Context, in *getByCodeInput) (*apicommon.Out[ServiceAccountResponse], error) {
+func (s *State) getByCode(ctx context.Context, in *getByCodeInput) (*apicommon.TaggedOut[ServiceAccountResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanReadServiceAccounts(ac); err != nil {
 		return nil, err
@@ -88,10 +92,10 @@ func (s *State) getByCode(ctx context.Context, in *getByCodeInput) (*apicommon.O
 	if sa == nil {
 		return nil, httperror.NotFound("ServiceAccount", in.Code)
 	}
-	return &apicommon.Out[ServiceAccountResponse]{Body: fromEntity(sa)}, nil
+	return &apicommon.TaggedOut[ServiceAccountResponse]{ETag: etag.Of(sa.UpdatedAt), Body: fromEntity(sa)}, nil
 }
 
-func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[ServiceAccountResponse], error) {
+func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.TaggedOut[ServiceAccountResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanReadServiceAccounts(ac); err != nil {
 		return nil, err
@@ -110,33 +114,101 @@ func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.
 	if p, err := s.Principals.FindByServiceAccount(ctx, in.ID); err == nil && p != nil {
 		resp.PrincipalID = &p.ID
 	}
-	return &apicommon.Out[ServiceAccountResponse]{Body: resp}, nil
+	return &apicommon.TaggedOut[ServiceAccountResponse]{ETag: etag.Of(sa.UpdatedAt), Body: resp}, nil
 }
 
-func (s *State) create(ctx context.Context, in *apicommon.In[CreateServiceAccountRequest]) (*apicommon.Out[CreateServiceAccountResponse], error) {
+func (s *State) create(ctx context.Context, in *apicommon.IdempotentIn[CreateServiceAccountRequest]) (*apicommon.Out[CreateServiceAccountResponse], error) {
 	// Coarse permission at the controller; the orchestration runs inside one
 	// transaction and has no per-client resource check (admin-managed create).
 	if err := auth.CanWriteServiceAccounts(auth.FromContext(ctx)); err != nil {
 		return nil, err
 	}
+	// The response carries the one-time secrets, so it is only kept for
+	// replay when it can be sealed.
+	req := idempotency.Request{Key: in.IdempotencyKey, Scope: "createServiceAccount", Body: in.Body, Sensitive: true}
+	created, err := idempotency.Do(ctx, s.Idem, req, func(ctx context.Context) (CreateServiceAccountResponse, error) {
+		res, err := s.runCreate(ctx, in.Body.toCommand())
+		if err != nil {
+			return CreateServiceAccountResponse{}, err
+		}
+		return CreateServiceAccountResponse{
+			ServiceAccount: fromEntity(res.ServiceAccount),
+			PrincipalID:    res.PrincipalID,
+			OAuth:          ServiceAccountOAuthSecrets{ClientID: res.OAuthClientID, ClientSecret: res.OAuthClientSecret},
+			Webhook:        ServiceAccountWebhookSecrets{AuthToken: res.AuthToken, SigningSecret: res.SigningSecret},
+		}, nil
+	})
+	if err != nil {
+		return nil, err
+	}
+	return &apicommon.Out[CreateServiceAccountResponse]{Body: created}, nil
+}
+
+func (s *State) runCreate(ctx context.Context, cmd operations.CreateCommand) (operations.CreateWithCredentialsResult, error) {
 	ec := auth.NewExecutionContext(ctx)
-	res, err := usecaseop.RunTx(ctx, s.UoW,
+	return usecaseop.RunTx(ctx, s.UoW,
 		operations.CreateServiceAccountWithCredentials(s.Repo, s.Principals, s.OAuthClients),
-		in.Body.toCommand(), ec)
+		cmd, ec)
+}
+
+type upsertInput struct {
+	Code        string `path:"code"`
+	IfMatch     string `header:"If-Match" doc:"Update only if the service account exists with this ETag"`
+	IfNoneMatch string `header:"If-None-Match" doc:"Send * to create only, failing if the code is taken"`
+	Body        UpsertServiceAccountRequest
+}
+
+// upsert creates or updates the service account with this code: 201 with
+// the one-time credentials if it created it, 200 without them if it
+// updated it.
+func (s *State) upsert(ctx context.Context, in *upsertInput) (*apicommon.UpsertOut[UpsertServiceAccountResponse], error) {
+	if err := auth.CanWriteServiceAccounts(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	existing, err := s.Repo.FindByCode(ctx, in.Code)
 	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_code failed", err)
+	}
+	if err := etag.CheckUpsert(in.IfMatch, in.IfNoneMatch, existing != nil); err != nil {
 		return nil, err
 	}
-	return &apicommon.Out[CreateServiceAccountResponse]{Body: CreateServiceAccountResponse{
-		ServiceAccount: fromEntity(res.ServiceAccount),
-		PrincipalID:    res.PrincipalID,
-		OAuth:          ServiceAccountOAuthSecrets{ClientID: res.OAuthClientID, ClientSecret: res.OAuthClientSecret},
-		Webhook:        ServiceAccountWebhookSecrets{AuthToken: res.AuthToken, SigningSecret: res.SigningSecret},
-	}}, nil
+	var out UpsertServiceAccountResponse
+	status, id := http.StatusOK, ""
+	if existing == nil {
+		res, err := s.runCreate(ctx, in.Body.toCreateCommand(in.Code))
+		if err != nil {
+			return nil, err
+		}
+		status, id = http.StatusCreated, res.ServiceAccount.ID
+		out.PrincipalID = &res.PrincipalID
+		out.OAuth = &ServiceAccountOAuthSecrets{ClientID: res.OAuthClientID, ClientSecret: res.OAuthClientSecret}
+		out.Webhook = &ServiceAccountWebhookSecrets{AuthToken: res.AuthToken, SigningSecret: res.SigningSecret}
+	} else {
+		id = existing.ID
+		ec := auth.NewExecutionContext(ctx)
+		if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateServiceAccount(s.Repo), in.Body.toUpdateCommand(id, in.IfMatch), ec); err != nil {
+			return nil, err
+		}
+		if p, err := s.Principals.FindByServiceAccount(ctx, id); err == nil && p != nil {
+			out.PrincipalID = &p.ID
+		}
+	}
+	sa, err := s.Repo.FindByID(ctx, id)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if sa == nil {
+		return nil, httperror.NotFound("ServiceAccount", id)
+	}
+	out.ServiceAccount = fromEntity(sa)
+	out.ServiceAccount.PrincipalID = out.PrincipalID
+	return &apicommon.UpsertOut[UpsertServiceAccountResponse]{Status: status, ETag: etag.Of(sa.UpdatedAt), Body: out}, nil
 }
 
 type updateInput struct {
-	ID   string `path:"id"`
-	Body UpdateServiceAccountRequest
+	ID      string `path:"id"`
+	IfMatch string `header:"If-Match" doc:"Proceed only if the service account's current ETag matches"`
+	Body    UpdateServiceAccountRequest
 }
 
 func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Empty, error) {
@@ -144,7 +216,7 @@ func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Empty,
 		return nil, err
 	}
 	ec := auth.NewExecutionContext(ctx)
-	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateServiceAccount(s.Repo), in.Body.toCommand(in.ID), ec); err != nil {
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateServiceAccount(s.Repo), in.Body.toCommand(in.ID, in.IfMatch), ec); err != nil {
 		return nil, err
 	}
 	return &apicommon.Empty{}, nil
@@ -161,12 +233,12 @@ func (s *State) deactivate(ctx context.Context, in *apicommon.IDInput) (*apicomm
 	return &apicommon.Empty{}, nil
 }
 
-func (s *State) delete(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+func (s *State) delete(ctx context.Context, in *apicommon.IDMatchInput) (*apicommon.Empty, error) {
 	if err := auth.CanDeleteServiceAccounts(auth.FromContext(ctx)); err != nil {
 		return nil, err
 	}
 	ec := auth.NewExecutionContext(ctx)
-	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeleteServiceAccount(s.Repo), operations.DeleteCommand{ID: in.ID}, ec); err != nil {
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeleteServiceAccount(s.Repo), operations.DeleteCommand{ID: in.ID, IfMatch: in.IfMatch}, ec); err != nil {
 		return nil, err
 	}
 	return &apicommon.Empty{}, nil
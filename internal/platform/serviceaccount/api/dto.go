@@ -64,6 +64,7 @@ type CreateServiceAccountRequest struct {
 	Description        *string                `json:"description,omitempty"`
 	Scope              *string                `json:"scope,omitempty"`
 	ClientIDs          []string               `json:"clientIds,omitempty"`
+	AllowedCIDRs       []string               `json:"allowedCidrs,omitempty"`
 	ApplicationID      *string                `json:"applicationId,omitempty"`
 	WebhookCredentials *WebhookCredentialsDTO `json:"webhookCredentials,omitempty"`
 }
@@ -80,6 +81,7 @@ func (r CreateServiceAccountRequest) toCommand() operations.CreateCommand {
 		Description:        r.Description,
 		Scope:              r.Scope,
 		ClientIDs:          r.ClientIDs,
+		AllowedCIDRs:       r.AllowedCIDRs,
 		ApplicationID:      r.ApplicationID,
 		WebhookCredentials: creds,
 	}
@@ -91,10 +93,11 @@ type UpdateServiceAccountRequest struct {
 	Description        *string                `json:"description,omitempty"`
 	Scope              *string                `json:"scope,omitempty"`
 	ClientIDs          []string               `json:"clientIds,omitempty"`
+	AllowedCIDRs       []string               `json:"allowedCidrs,omitempty"`
 	WebhookCredentials *WebhookCredentialsDTO `json:"webhookCredentials,omitempty"`
 }
 
-func (r UpdateServiceAccountRequest) toCommand(id string) operations.UpdateCommand {
+func (r UpdateServiceAccountRequest) toCommand(id, ifMatch string) operations.UpdateCommand {
 	var creds *serviceaccount.WebhookCredentials
 	if r.WebhookCredentials != nil {
 		c := r.WebhookCredentials.toEntity()
@@ -106,10 +109,50 @@ func (r UpdateServiceAccountRequest) toCommand(id string) operations.UpdateComma
 		Description:        r.Description,
 		Scope:              r.Scope,
 		ClientIDs:          r.ClientIDs,
+		AllowedCIDRs:       r.AllowedCIDRs,
 		WebhookCredentials: creds,
+		IfMatch:            ifMatch,
 	}
 }
 
+// UpsertServiceAccountRequest is the wire body for
+// PUT /api/service-accounts/code/{code}. applicationId is fixed at
+// creation and ignored on update; other absent optional fields keep their
+// current values.
+type UpsertServiceAccountRequest struct {
+	Name               string                 `json:"name"`
+	Description        *string                `json:"description,omitempty"`
+	Scope              *string                `json:"scope,omitempty"`
+	ClientIDs          []string               `json:"clientIds,omitempty"`
+	AllowedCIDRs       []string               `json:"allowedCidrs,omitempty"`
+	ApplicationID      *string                `json:"applicationId,omitempty"`
+	WebhookCredentials *WebhookCredentialsDTO `json:"webhookCredentials,omitempty"`
+}
+
+func (r UpsertServiceAccountRequest) toCreateCommand(code string) operations.CreateCommand {
+	return CreateServiceAccountRequest{
+		Code:               code,
+		Name:               r.Name,
+		Description:        r.Description,
+		Scope:              r.Scope,
+		ClientIDs:          r.ClientIDs,
+		AllowedCIDRs:       r.AllowedCIDRs,
+		ApplicationID:      r.ApplicationID,
+		WebhookCredentials: r.WebhookCredentials,
+	}.toCommand()
+}
+
+func (r UpsertServiceAccountRequest) toUpdateCommand(id, ifMatch string) operations.UpdateCommand {
+	return UpdateServiceAccountRequest{
+		Name:               &r.Name,
+		Description:        r.Description,
+		Scope:              r.Scope,
+		ClientIDs:          r.ClientIDs,
+		AllowedCIDRs:       r.AllowedCIDRs,
+		WebhookCredentials: r.WebhookCredentials,
+	}.toCommand(id, ifMatch)
+}
+
 // AssignRolesRequest is the wire body for PUT /api/service-accounts/{id}/roles.
 type AssignRolesRequest struct {
 	Roles []string `json:"roles"`
@@ -136,6 +179,16 @@ type CreateServiceAccountResponse struct {
 	Webhook        ServiceAccountWebhookSecrets `json:"webhook"`
 }
 
+// UpsertServiceAccountResponse is the body for
+// PUT /api/service-accounts/code/{code}. The credentials are present only
+// when the call created the account, and are returned exactly once.
+type UpsertServiceAccountResponse struct {
+	ServiceAccount ServiceAccountResponse        `json:"serviceAccount"`
+	PrincipalID    *string                       `json:"principalId,omitempty"`
+	OAuth          *ServiceAccountOAuthSecrets   `json:"oauth,omitempty"`
+	Webhook        *ServiceAccountWebhookSecrets `json:"webhook,omitempty"`
+}
+
 // ServiceAccountResponse is the wire shape the SPA + fcsdk expect: flat,
 // with `authType` hoisted out of the webhook credentials and `roles` as a
 // plain name list. Webhook secrets (token/signingSecret/password) are NEVER
@@ -147,6 +200,7 @@ type ServiceAccountResponse struct {
 	Description   *string  `json:"description,omitempty"`
 	Active        bool     `json:"active"`
 	ClientIDs     []string `json:"clientIds"`
+	AllowedCIDRs  []string `json:"allowedCidrs"`
 	Scope         *string  `json:"scope,omitempty"`
 	ApplicationID *string  `json:"applicationId,omitempty"`
 	AuthType      string   `json:"authType"`
@@ -167,6 +221,10 @@ func fromEntity(sa *serviceaccount.ServiceAccount) ServiceAccountResponse {
 	if clientIDs == nil {
 		clientIDs = []string{}
 	}
+	allowedCIDRs := sa.AllowedCIDRs
+	if allowedCIDRs == nil {
+		allowedCIDRs = []string{}
+	}
 	roles := make([]string, 0, len(sa.Roles))
 	for _, r := range sa.Roles {
 		roles = append(roles, r.Role)
@@ -183,6 +241,7 @@ func fromEntity(sa *serviceaccount.ServiceAccount) ServiceAccountResponse {
 		Description:   sa.Description,
 		Active:        sa.Active,
 		ClientIDs:     clientIDs,
+		AllowedCIDRs:  allowedCIDRs,
 		Scope:         sa.Scope,
 		ApplicationID: sa.ApplicationID,
 		AuthType:      string(sa.WebhookCredentials.AuthType),
@@ -0,0 +1,102 @@
+package serviceaccount
+
+import (
+	"crypto/rand"
+	"crypto/sha256"
+	"encoding/base64"
+	"fmt"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+)
+
+// At-rest form of WebhookCredentials. The auth token is verify-only, so it
+// is stored as a salted hash; the signing secret has to be recoverable to
+// sign with, so it is sealed with the app key (FLOWCATALYST_APP_KEY) as an
+// inline "encrypted:" reference, the same envelope as OAuth client
+// secrets. Plaintext of either is only ever returned once, by the create
+// and regenerate operations.
+
+// tokenHashPrefix marks a stored auth token that is a hash, not the token.
+const tokenHashPrefix = "sha256$"
+
+// HashAuthToken returns the at-rest form of an auth token:
+// "sha256$<salt>$<digest>", the SHA-256 of a 16-byte random salt followed
+// by the token, both base64url. Tokens carry 160+ bits of randomness, so
+// a fast hash is enough; the salt keeps equal tokens from sharing a digest.
+func HashAuthToken(token string) (string, error) {
+	salt := make([]byte, 16)
+	if _, err := rand.Read(salt); err != nil {
+		return "", fmt.Errorf("serviceaccount: read salt: %w", err)
+	}
+	return tokenHashPrefix + base64.RawURLEncoding.EncodeToString(salt) + "$" + tokenDigest(salt, token), nil
+}
+
+func tokenDigest(salt []byte, token string) string {
+	h := sha256.New()
+	h.Write(salt)
+	h.Write([]byte(token))
+	return base64.RawURLEncoding.EncodeToString(h.Sum(nil))
+}
+
+// IsHashedAuthToken reports whether a stored token is already hashed.
+func IsHashedAuthToken(stored string) bool {
+	return strings.HasPrefix(stored, tokenHashPrefix)
+}
+
+// Seal returns c in its at-rest form: the token hashed and a plaintext
+// signing secret encrypted with enc. Values already in at-rest form, and
+// signing secrets that reference an external secret manager, pass
+// through. Returns encryption.ErrNotConfigured when a signing secret needs
+// encrypting and enc is nil.
+func (c WebhookCredentials) Seal(enc *encryption.Service) (WebhookCredentials, error) {
+	if c.Token != nil && *c.Token != "" && !IsHashedAuthToken(*c.Token) {
+		hashed, err := HashAuthToken(*c.Token)
+		if err != nil {
+			return c, err
+		}
+		c.Token = &hashed
+	}
+	secret, err := encryption.EncryptSecretRef(enc, c.SigningSecret)
+	if err != nil {
+		return c, err
+	}
+	c.SigningSecret = secret
+	return c, nil
+}
+
+// NeedsReseal reports whether c holds a plaintext token or signing secret,
+// or a signing secret sealed under a previous app key.
+func (c WebhookCredentials) NeedsReseal(enc *encryption.Service) bool {
+	if c.Token != nil && *c.Token != "" && !IsHashedAuthToken(*c.Token) {
+		return true
+	}
+	if c.SigningSecret == nil || *c.SigningSecret == "" {
+		return false
+	}
+	if v := *c.SigningSecret; strings.HasPrefix(v, "encrypted:") {
+		return enc != nil && enc.NeedsReEncryption(v)
+	}
+	// Without a key EncryptSecretRef passes external references through
+	// and refuses plaintext, which is what is left to detect.
+	_, err := encryption.EncryptSecretRef(nil, c.SigningSecret)
+	return err != nil
+}
+
+// Reseal is Seal plus re-encrypting a signing secret sealed under a
+// previous app key with the current one. Used by the credential migration.
+func (c WebhookCredentials) Reseal(enc *encryption.Service) (WebhookCredentials, error) {
+	c, err := c.Seal(enc)
+	if err != nil {
+		return c, err
+	}
+	if v := c.SigningSecret; enc != nil && v != nil && strings.HasPrefix(*v, "encrypted:") && enc.NeedsReEncryption(*v) {
+		blob, err := enc.ReEncrypt(*v)
+		if err != nil {
+			return c, err
+		}
+		sealed := "encrypted:" + blob
+		c.SigningSecret = &sealed
+	}
+	return c, nil
+}
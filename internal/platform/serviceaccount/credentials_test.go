@@ -0,0 +1,100 @@
+package serviceaccount_test
+
+import (
+	"crypto/sha256"
+	"encoding/base64"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+)
+
+func newEnc(t *testing.T) *encryption.Service {
+	t.Helper()
+	key, err := encryption.GenerateKey()
+	require.NoError(t, err)
+	enc, err := encryption.New(key)
+	require.NoError(t, err)
+	return enc
+}
+
+// tokenMatches recomputes a stored "sha256$<salt>$<digest>" for token.
+func tokenMatches(stored, token string) bool {
+	saltB64, digest, ok := strings.Cut(strings.TrimPrefix(stored, "sha256$"), "$")
+	if !ok {
+		return false
+	}
+	salt, err := base64.RawURLEncoding.DecodeString(saltB64)
+	if err != nil {
+		return false
+	}
+	sum := sha256.Sum256(append(salt, token...))
+	return base64.RawURLEncoding.EncodeToString(sum[:]) == digest
+}
+
+func TestAuthTokenHashing(t *testing.T) {
+	a, err := serviceaccount.HashAuthToken("fc_token")
+	require.NoError(t, err)
+	b, err := serviceaccount.HashAuthToken("fc_token")
+	require.NoError(t, err)
+
+	assert.True(t, serviceaccount.IsHashedAuthToken(a))
+	assert.NotEqual(t, a, b, "salted: equal tokens hash differently")
+	assert.True(t, tokenMatches(a, "fc_token"))
+	assert.False(t, tokenMatches(a, "fc_other"))
+	assert.False(t, serviceaccount.IsHashedAuthToken("fc_token"))
+}
+
+func TestWebhookCredentialsSeal(t *testing.T) {
+	enc := newEnc(t)
+	token, secret := "fc_token", "signing-secret"
+	creds := serviceaccount.WebhookCredentials{AuthType: serviceaccount.AuthBearer, Token: &token, SigningSecret: &secret}
+	require.True(t, creds.NeedsReseal(enc))
+
+	sealed, err := creds.Seal(enc)
+	require.NoError(t, err)
+	assert.True(t, tokenMatches(*sealed.Token, token))
+	assert.Contains(t, *sealed.SigningSecret, "encrypted:")
+	opened, err := enc.Decrypt(*sealed.SigningSecret)
+	require.NoError(t, err)
+	assert.Equal(t, secret, opened)
+	assert.False(t, sealed.NeedsReseal(enc))
+
+	again, err := sealed.Seal(enc)
+	require.NoError(t, err)
+	assert.Equal(t, sealed, again, "sealing is idempotent")
+
+	_, err = creds.Seal(nil)
+	assert.ErrorIs(t, err, encryption.ErrNotConfigured, "a plaintext signing secret needs the app key")
+
+	ref := "vault://webhooks#secret"
+	external := serviceaccount.WebhookCredentials{SigningSecret: &ref}
+	assert.False(t, external.NeedsReseal(nil), "external references are stored as given")
+}
+
+func TestWebhookCredentialsReseal_RotatesKey(t *testing.T) {
+	oldKey, err := encryption.GenerateKey()
+	require.NoError(t, err)
+	newKey, err := encryption.GenerateKey()
+	require.NoError(t, err)
+	oldEnc, err := encryption.New(oldKey)
+	require.NoError(t, err)
+	rotated, err := encryption.WithPreviousKeys(newKey, []string{oldKey})
+	require.NoError(t, err)
+
+	secret := "signing-secret"
+	sealed, err := serviceaccount.WebhookCredentials{SigningSecret: &secret}.Seal(oldEnc)
+	require.NoError(t, err)
+	require.True(t, sealed.NeedsReseal(rotated))
+
+	resealed, err := sealed.Reseal(rotated)
+	require.NoError(t, err)
+	assert.False(t, resealed.NeedsReseal(rotated))
+	opened, err := rotated.Decrypt(*resealed.SigningSecret)
+	require.NoError(t, err)
+	assert.Equal(t, secret, opened)
+}
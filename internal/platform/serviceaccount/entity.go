@@ -40,6 +40,8 @@ func ParseAuthType(s string) WebhookAuthType {
 }
 
 // WebhookCredentials carries the per-account auth details used by outbound calls.
+// As loaded and persisted, Token and SigningSecret are in their at-rest form
+// (hashed and encrypted respectively); see Seal.
 type WebhookCredentials struct {
 	AuthType         WebhookAuthType `json:"authType"`
 	Token            *string         `json:"token,omitempty"`
@@ -78,9 +80,13 @@ type ServiceAccount struct {
 	WebhookCredentials    WebhookCredentials `json:"webhookCredentials"`
 	ServiceAccountTableID *string            `json:"-"`
 	Roles                 []RoleAssignment   `json:"roles"`
-	LastUsedAt            *time.Time         `json:"lastUsedAt,omitempty"`
-	CreatedAt             time.Time          `json:"createdAt"`
-	UpdatedAt             time.Time          `json:"updatedAt"`
+	// AllowedCIDRs restricts where the account's tokens may be used from;
+	// empty allows anywhere. Enforced by the auth middleware and at
+	// /oauth/token (see shared/netpolicy).
+	AllowedCIDRs []string   `json:"allowedCidrs"`
+	LastUsedAt   *time.Time `json:"lastUsedAt,omitempty"`
+	CreatedAt    time.Time  `json:"createdAt"`
+	UpdatedAt    time.Time  `json:"updatedAt"`
 }
 
 // IDStr satisfies usecase.HasID.
@@ -95,6 +101,7 @@ func New(code, name string) *ServiceAccount {
 		Name:               name,
 		Active:             true,
 		ClientIDs:          []string{},
+		AllowedCIDRs:       []string{},
 		WebhookCredentials: NoCredentials(),
 		Roles:              []RoleAssignment{},
 		CreatedAt:          now,
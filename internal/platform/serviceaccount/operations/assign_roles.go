@@ -111,6 +111,7 @@ func AssignRolesToServiceAccount(
 				ServiceAccountID: sa.ID,
 				RolesAdded:       added,
 				RolesRemoved:     removed,
+				ClientIDs:        sa.ClientIDs,
 			}
 			return usecaseop.Save(p, principal.RolesPersister{Repository: principals}, event), nil
 		},
@@ -5,6 +5,7 @@ import (
 	"strings"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/netpolicy"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/validate"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
@@ -17,6 +18,7 @@ type CreateCommand struct {
 	Description        *string                            `json:"description,omitempty"`
 	Scope              *string                            `json:"scope,omitempty"`
 	ClientIDs          []string                           `json:"clientIds,omitempty"`
+	AllowedCIDRs       []string                           `json:"allowedCidrs,omitempty"`
 	ApplicationID      *string                            `json:"applicationId,omitempty"`
 	WebhookCredentials *serviceaccount.WebhookCredentials `json:"webhookCredentials,omitempty"`
 }
@@ -63,8 +65,13 @@ func CreateServiceAccount(repo *serviceaccount.Repository) usecaseop.Operation[C
 			if cmd.ClientIDs != nil {
 				sa.ClientIDs = cmd.ClientIDs
 			}
+			if sa.AllowedCIDRs, err = allowedCIDRs(cmd.AllowedCIDRs); err != nil {
+				return nil, err
+			}
 			if cmd.WebhookCredentials != nil {
-				sa.WebhookCredentials = *cmd.WebhookCredentials
+				if sa.WebhookCredentials, err = sealCredentials(*cmd.WebhookCredentials); err != nil {
+					return nil, err
+				}
 			}
 
 			event := ServiceAccountCreated{
@@ -72,8 +79,18 @@ func CreateServiceAccount(repo *serviceaccount.Repository) usecaseop.Operation[C
 				ServiceAccountID: sa.ID,
 				Code:             sa.Code,
 				Name:             sa.Name,
+				ClientIDs:        sa.ClientIDs,
 			}
 			return usecaseop.Save(sa, repo, event), nil
 		},
 	}
 }
+
+// allowedCIDRs canonicalises a CIDR allowlist, rejecting malformed entries.
+func allowedCIDRs(in []string) ([]string, error) {
+	out, err := netpolicy.NormalizeCIDRs(in)
+	if err != nil {
+		return nil, usecase.Validation("INVALID_CIDR", err.Error())
+	}
+	return out, nil
+}
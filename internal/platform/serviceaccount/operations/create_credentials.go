@@ -24,6 +24,7 @@ import (
 // CreateWithCredentialsResult carries the freshly-minted account plus the
 // one-time plaintext secrets. The OAuth client secret + webhook secrets are
 // stored hashed/at-rest; the plaintext is only ever returned here, once.
+// ServiceAccount carries the at-rest credentials.
 type CreateWithCredentialsResult struct {
 	ServiceAccount    *serviceaccount.ServiceAccount
 	PrincipalID       string
@@ -91,10 +92,13 @@ func CreateServiceAccountWithCredentials(
 			}
 			authToken := generateAuthToken()
 			signingSecret := generateSigningSecret()
-			sa.WebhookCredentials = serviceaccount.WebhookCredentials{
+			sa.WebhookCredentials, err = sealCredentials(serviceaccount.WebhookCredentials{
 				AuthType:      serviceaccount.AuthBearer,
 				Token:         &authToken,
 				SigningSecret: &signingSecret,
+			})
+			if err != nil {
+				return zero, err
 			}
 
 			saPrincipal := principal.NewService(sa.ID, sa.Name)
@@ -112,7 +116,7 @@ func CreateServiceAccountWithCredentials(
 
 			// 1. Service account.
 			if r := usecasepgx.CommitScoped(ctx, s, sa, saRepo,
-				NewServiceAccountCreatedEvent(ec, sa.ID, sa.Code, sa.Name), cmd); !usecase.IsSuccess(r) {
+				NewServiceAccountCreatedEvent(ec, sa.ID, sa.Code, sa.Name, sa.ClientIDs), cmd); !usecase.IsSuccess(r) {
 				_, e := usecase.Into(r)
 				return zero, e
 			}
@@ -158,6 +162,25 @@ func CreateServiceAccountWithCredentials(
 	}
 }
 
+// sealCredentials puts creds in their at-rest form
+// (WebhookCredentials.Seal) under the app key, mapping errors to API
+// envelopes. A signing secret cannot be stored without FLOWCATALYST_APP_KEY.
+func sealCredentials(creds serviceaccount.WebhookCredentials) (serviceaccount.WebhookCredentials, error) {
+	enc, err := encryption.FromEnv()
+	if err != nil {
+		return creds, usecase.Internal("ENCRYPT", "load app key", err)
+	}
+	sealed, err := creds.Seal(enc)
+	switch {
+	case errors.Is(err, encryption.ErrNotConfigured):
+		return creds, usecase.Validation("ENCRYPTION_NOT_CONFIGURED",
+			"cannot store webhook signing secret: FLOWCATALYST_APP_KEY is not configured")
+	case err != nil:
+		return creds, usecase.Internal("ENCRYPT", "seal webhook credentials", err)
+	}
+	return sealed, nil
+}
+
 // generateOAuthClientSecret returns a fresh URL-safe secret + its
 // encrypted reference (stored in client_secret_ref; verified at
 // /oauth/token by decrypt-and-compare — Rust parity).
@@ -5,6 +5,7 @@ import (
 	"strings"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
@@ -12,7 +13,8 @@ import (
 
 // DeleteCommand is the input DTO.
 type DeleteCommand struct {
-	ID string `json:"id"`
+	ID      string `json:"id"`
+	IfMatch string `json:"-"` // If-Match header; empty skips the check
 }
 
 // DeleteServiceAccount removes a service account and emits [ServiceAccountDeleted].
@@ -37,12 +39,16 @@ func DeleteServiceAccount(repo *serviceaccount.Repository) usecaseop.Operation[D
 			if sa == nil {
 				return nil, httperror.NotFound("ServiceAccount", cmd.ID)
 			}
+			if err := etag.Check(cmd.IfMatch, sa.UpdatedAt); err != nil {
+				return nil, err
+			}
 			event := ServiceAccountDeleted{
 				Metadata:         usecase.NewEventMetadata(ec, ServiceAccountDeletedType, Source, subjectFor(sa.ID)),
 				ServiceAccountID: sa.ID,
 				Code:             sa.Code,
+				ClientIDs:        sa.ClientIDs,
 			}
-			return usecaseop.Delete(sa, repo, event), nil
+			return usecaseop.Delete(sa, etag.Guard[serviceaccount.ServiceAccount](repo, "iam_service_accounts", cmd.IfMatch), event), nil
 		},
 	}
 }
@@ -27,12 +27,13 @@ func groupFor(id string) string   { return "platform:serviceaccount:" + id }
 // canonical subject. Exported so cross-aggregate orchestrations (e.g.
 // application provision-service-account) can emit it inside their own
 // transaction without reaching into this package's private helpers.
-func NewServiceAccountCreatedEvent(ec usecase.ExecutionContext, saID, code, name string) ServiceAccountCreated {
+func NewServiceAccountCreatedEvent(ec usecase.ExecutionContext, saID, code, name string, clientIDs []string) ServiceAccountCreated {
 	return ServiceAccountCreated{
 		Metadata:         usecase.NewEventMetadata(ec, ServiceAccountCreatedType, Source, subjectFor(saID)),
 		ServiceAccountID: saID,
 		Code:             code,
 		Name:             name,
+		ClientIDs:        clientIDs,
 	}
 }
 
@@ -41,6 +42,7 @@ type ServiceAccountCreated struct {
 	ServiceAccountID string
 	Code             string
 	Name             string
+	ClientIDs        []string
 }
 
 func (e ServiceAccountCreated) EventID() string       { return e.Metadata.EventID }
@@ -62,10 +64,13 @@ func (e ServiceAccountCreated) ToDataJSON() ([]byte, error) {
 	}{e.ServiceAccountID, e.Code, e.Name})
 }
 
+func (e ServiceAccountCreated) OwningClientIDs() []string { return e.ClientIDs }
+
 type ServiceAccountUpdated struct {
 	Metadata         usecase.EventMetadata
 	ServiceAccountID string
 	Name             string
+	ClientIDs        []string
 }
 
 func (e ServiceAccountUpdated) EventID() string       { return e.Metadata.EventID }
@@ -86,6 +91,8 @@ func (e ServiceAccountUpdated) ToDataJSON() ([]byte, error) {
 	}{e.ServiceAccountID, e.Name})
 }
 
+func (e ServiceAccountUpdated) OwningClientIDs() []string { return e.ClientIDs }
+
 type ServiceAccountDeactivated struct {
 	Metadata         usecase.EventMetadata
 	ServiceAccountID string
@@ -112,6 +119,7 @@ type ServiceAccountDeleted struct {
 	Metadata         usecase.EventMetadata
 	ServiceAccountID string
 	Code             string
+	ClientIDs        []string
 }
 
 func (e ServiceAccountDeleted) EventID() string       { return e.Metadata.EventID }
@@ -132,6 +140,8 @@ func (e ServiceAccountDeleted) ToDataJSON() ([]byte, error) {
 	}{e.ServiceAccountID, e.Code})
 }
 
+func (e ServiceAccountDeleted) OwningClientIDs() []string { return e.ClientIDs }
+
 // ServiceAccountRolesAssigned — emitted by assign_roles after the
 // role list is replaced. Payload carries the deltas only (the new full
 // list is implicit from the aggregate's state).
@@ -140,6 +150,7 @@ type ServiceAccountRolesAssigned struct {
 	ServiceAccountID string
 	RolesAdded       []string
 	RolesRemoved     []string
+	ClientIDs        []string
 }
 
 func (e ServiceAccountRolesAssigned) EventID() string       { return e.Metadata.EventID }
@@ -161,12 +172,15 @@ func (e ServiceAccountRolesAssigned) ToDataJSON() ([]byte, error) {
 	}{e.ServiceAccountID, defaultEmpty(e.RolesAdded), defaultEmpty(e.RolesRemoved)})
 }
 
+func (e ServiceAccountRolesAssigned) OwningClientIDs() []string { return e.ClientIDs }
+
 // ServiceAccountTokenRegenerated — bearer token rotation. The plaintext
 // token is returned out-of-band via a sync.Map (see stashToken below).
 type ServiceAccountTokenRegenerated struct {
 	Metadata         usecase.EventMetadata
 	ServiceAccountID string
 	Code             string
+	ClientIDs        []string
 }
 
 func (e ServiceAccountTokenRegenerated) EventID() string       { return e.Metadata.EventID }
@@ -187,12 +201,15 @@ func (e ServiceAccountTokenRegenerated) ToDataJSON() ([]byte, error) {
 	}{e.ServiceAccountID, e.Code})
 }
 
+func (e ServiceAccountTokenRegenerated) OwningClientIDs() []string { return e.ClientIDs }
+
 // ServiceAccountSecretRegenerated — signing-secret rotation. Plaintext
 // returned out-of-band like the token.
 type ServiceAccountSecretRegenerated struct {
 	Metadata         usecase.EventMetadata
 	ServiceAccountID string
 	Code             string
+	ClientIDs        []string
 }
 
 func (e ServiceAccountSecretRegenerated) EventID() string { return e.Metadata.EventID }
@@ -215,6 +232,8 @@ func (e ServiceAccountSecretRegenerated) ToDataJSON() ([]byte, error) {
 	}{e.ServiceAccountID, e.Code})
 }
 
+func (e ServiceAccountSecretRegenerated) OwningClientIDs() []string { return e.ClientIDs }
+
 func defaultEmpty(xs []string) []string {
 	if xs == nil {
 		return []string{}
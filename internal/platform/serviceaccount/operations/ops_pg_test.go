@@ -214,9 +214,15 @@ func TestCreateServiceAccountWithCredentials_HappyPath(t *testing.T) {
 	assert.True(t, sa.Active)
 	assert.Equal(t, serviceaccount.AuthBearer, sa.WebhookCredentials.AuthType)
 	require.NotNil(t, sa.WebhookCredentials.Token)
-	assert.Equal(t, res.AuthToken, *sa.WebhookCredentials.Token)
+	assert.True(t, serviceaccount.IsHashedAuthToken(*sa.WebhookCredentials.Token), "token stored hashed")
+	assert.NotContains(t, *sa.WebhookCredentials.Token, res.AuthToken)
 	require.NotNil(t, sa.WebhookCredentials.SigningSecret)
-	assert.Equal(t, res.SigningSecret, *sa.WebhookCredentials.SigningSecret)
+	assert.NotEqual(t, res.SigningSecret, *sa.WebhookCredentials.SigningSecret, "signing secret stored encrypted")
+	enc, err := encryption.FromEnv()
+	require.NoError(t, err)
+	opened, err := enc.Decrypt(*sa.WebhookCredentials.SigningSecret)
+	require.NoError(t, err)
+	assert.Equal(t, res.SigningSecret, opened)
 	require.NotNil(t, sa.ApplicationID)
 	assert.Equal(t, appID, *sa.ApplicationID)
 	require.NotNil(t, sa.Scope, "scope persists since migration 035")
@@ -523,7 +529,8 @@ func TestRegenerateAuthToken_HappyPathAndStash(t *testing.T) {
 	assert.Equal(t, serviceaccount.AuthBearer, got.WebhookCredentials.AuthType,
 		"regenerate forces BEARER_TOKEN")
 	require.NotNil(t, got.WebhookCredentials.Token)
-	assert.Equal(t, token, *got.WebhookCredentials.Token, "persisted token == stashed plaintext")
+	assert.NotEqual(t, token, *got.WebhookCredentials.Token, "only the hash is persisted")
+	assert.True(t, serviceaccount.IsHashedAuthToken(*got.WebhookCredentials.Token), "persisted as a hash")
 }
 
 func TestRegenerateAuthToken_Errors(t *testing.T) {
@@ -567,8 +574,50 @@ func TestRegenerateSigningSecret_HappyPathAndStash(t *testing.T) {
 	require.NoError(t, err)
 	require.NotNil(t, got)
 	require.NotNil(t, got.WebhookCredentials.SigningSecret)
-	assert.Equal(t, secret, *got.WebhookCredentials.SigningSecret,
-		"persisted signing secret == stashed plaintext")
+	enc, err := encryption.FromEnv()
+	require.NoError(t, err)
+	opened, err := enc.Decrypt(*got.WebhookCredentials.SigningSecret)
+	require.NoError(t, err)
+	assert.Equal(t, secret, opened, "persisted signing secret opens to the stashed plaintext")
+}
+
+func TestResealCredentials_MigratesPlaintextRows(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := serviceaccount.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seeded := mustCreate(t, repo, uow, "sareseal-legacy", "Legacy Creds")
+
+	// A row written before credentials were sealed.
+	sa, err := repo.FindByID(ctx, seeded.ServiceAccountID)
+	require.NoError(t, err)
+	token, secret := "fc_legacytoken", "legacy-signing-secret"
+	sa.WebhookCredentials = serviceaccount.WebhookCredentials{
+		AuthType: serviceaccount.AuthBearer, Token: &token, SigningSecret: &secret,
+	}
+	tx, err := testpg.Pool(t).Begin(ctx)
+	require.NoError(t, err)
+	defer func() { _ = tx.Rollback(ctx) }()
+	require.NoError(t, repo.Persist(ctx, sa, usecasepgx.WrapTxForBootstrap(tx)))
+	require.NoError(t, tx.Commit(ctx))
+	before, err := repo.FindByID(ctx, sa.ID)
+	require.NoError(t, err)
+
+	enc, err := encryption.FromEnv()
+	require.NoError(t, err)
+	rep, err := repo.ResealCredentials(ctx, enc)
+	require.NoError(t, err)
+	assert.GreaterOrEqual(t, rep.Resealed, 1)
+
+	got, err := repo.FindByID(ctx, sa.ID)
+	require.NoError(t, err)
+	assert.True(t, serviceaccount.IsHashedAuthToken(*got.WebhookCredentials.Token))
+	assert.NotContains(t, *got.WebhookCredentials.Token, token)
+	opened, err := enc.Decrypt(*got.WebhookCredentials.SigningSecret)
+	require.NoError(t, err)
+	assert.Equal(t, secret, opened)
+	assert.False(t, got.WebhookCredentials.NeedsReseal(enc))
+	assert.Equal(t, before.UpdatedAt, got.UpdatedAt, "reseal leaves updated_at (the ETag) alone")
 }
 
 func TestRegenerateSigningSecret_Errors(t *testing.T) {
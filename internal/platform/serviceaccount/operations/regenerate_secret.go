@@ -18,8 +18,9 @@ type RegenerateSigningSecretCommand struct {
 	ServiceAccountID string `json:"serviceAccountId"`
 }
 
-// RegenerateSigningSecret rotates the signing secret. Plaintext lands in
-// the process-local stash for the HTTP handler to read once.
+// RegenerateSigningSecret rotates the signing secret, stored encrypted
+// under the app key. Plaintext lands in the process-local stash for the
+// HTTP handler to read once.
 func RegenerateSigningSecret(repo *serviceaccount.Repository) usecaseop.Operation[RegenerateSigningSecretCommand, ServiceAccountSecretRegenerated] {
 	return usecaseop.Operation[RegenerateSigningSecretCommand, ServiceAccountSecretRegenerated]{
 		Name: "RegenerateSigningSecret",
@@ -43,7 +44,11 @@ func RegenerateSigningSecret(repo *serviceaccount.Repository) usecaseop.Operatio
 			}
 
 			secret := generateSigningSecret()
-			sa.WebhookCredentials.SigningSecret = &secret
+			creds := sa.WebhookCredentials
+			creds.SigningSecret = &secret
+			if sa.WebhookCredentials, err = sealCredentials(creds); err != nil {
+				return nil, err
+			}
 			sa.UpdatedAt = time.Now().UTC()
 			stashSecret(sa.ID, "signing_secret", secret)
 
@@ -51,6 +56,7 @@ func RegenerateSigningSecret(repo *serviceaccount.Repository) usecaseop.Operatio
 				Metadata:         usecase.NewEventMetadata(ec, ServiceAccountSecretRegeneratedType, Source, subjectFor(sa.ID)),
 				ServiceAccountID: sa.ID,
 				Code:             sa.Code,
+				ClientIDs:        sa.ClientIDs,
 			}
 			return usecaseop.Save(sa, repo, event), nil
 		},
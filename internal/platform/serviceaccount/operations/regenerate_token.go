@@ -19,9 +19,9 @@ type RegenerateAuthTokenCommand struct {
 	ServiceAccountID string `json:"serviceAccountId"`
 }
 
-// RegenerateAuthToken rotates the service account's bearer token. After
-// the commit, the plaintext token lands in a process-local stash so the
-// HTTP handler can return it once and only once.
+// RegenerateAuthToken rotates the service account's bearer token. Only its
+// hash is stored; after the commit, the plaintext token lands in a
+// process-local stash so the HTTP handler can return it once and only once.
 func RegenerateAuthToken(repo *serviceaccount.Repository) usecaseop.Operation[RegenerateAuthTokenCommand, ServiceAccountTokenRegenerated] {
 	return usecaseop.Operation[RegenerateAuthTokenCommand, ServiceAccountTokenRegenerated]{
 		Name: "RegenerateAuthToken",
@@ -45,7 +45,11 @@ func RegenerateAuthToken(repo *serviceaccount.Repository) usecaseop.Operation[Re
 			}
 
 			token := generateAuthToken()
-			sa.WebhookCredentials.Token = &token
+			hashed, err := serviceaccount.HashAuthToken(token)
+			if err != nil {
+				return nil, usecase.Internal("SECRET", "hash auth token failed", err)
+			}
+			sa.WebhookCredentials.Token = &hashed
 			sa.WebhookCredentials.AuthType = serviceaccount.AuthBearer
 			sa.UpdatedAt = time.Now().UTC()
 
@@ -55,6 +59,7 @@ func RegenerateAuthToken(repo *serviceaccount.Repository) usecaseop.Operation[Re
 				Metadata:         usecase.NewEventMetadata(ec, ServiceAccountTokenRegeneratedType, Source, subjectFor(sa.ID)),
 				ServiceAccountID: sa.ID,
 				Code:             sa.Code,
+				ClientIDs:        sa.ClientIDs,
 			}
 			return usecaseop.Save(sa, repo, event), nil
 		},
@@ -5,6 +5,7 @@ import (
 	"strings"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
@@ -17,7 +18,10 @@ type UpdateCommand struct {
 	Description        *string                            `json:"description,omitempty"`
 	Scope              *string                            `json:"scope,omitempty"`
 	ClientIDs          []string                           `json:"clientIds,omitempty"`
+	AllowedCIDRs       []string                           `json:"allowedCidrs,omitempty"`
 	WebhookCredentials *serviceaccount.WebhookCredentials `json:"webhookCredentials,omitempty"`
+	// IfMatch is the caller's If-Match header; empty skips the check.
+	IfMatch string `json:"-"`
 }
 
 // UpdateServiceAccount mutates mutable fields and emits [ServiceAccountUpdated].
@@ -45,6 +49,9 @@ func UpdateServiceAccount(repo *serviceaccount.Repository) usecaseop.Operation[U
 			if sa == nil {
 				return nil, httperror.NotFound("ServiceAccount", cmd.ID)
 			}
+			if err := etag.Check(cmd.IfMatch, sa.UpdatedAt); err != nil {
+				return nil, err
+			}
 			if cmd.Name != nil {
 				sa.Name = strings.TrimSpace(*cmd.Name)
 			}
@@ -57,16 +64,24 @@ func UpdateServiceAccount(repo *serviceaccount.Repository) usecaseop.Operation[U
 			if cmd.ClientIDs != nil {
 				sa.ClientIDs = cmd.ClientIDs
 			}
+			if cmd.AllowedCIDRs != nil {
+				if sa.AllowedCIDRs, err = allowedCIDRs(cmd.AllowedCIDRs); err != nil {
+					return nil, err
+				}
+			}
 			if cmd.WebhookCredentials != nil {
-				sa.WebhookCredentials = *cmd.WebhookCredentials
+				if sa.WebhookCredentials, err = sealCredentials(*cmd.WebhookCredentials); err != nil {
+					return nil, err
+				}
 			}
 
 			event := ServiceAccountUpdated{
 				Metadata:         usecase.NewEventMetadata(ec, ServiceAccountUpdatedType, Source, subjectFor(sa.ID)),
 				ServiceAccountID: sa.ID,
 				Name:             sa.Name,
+				ClientIDs:        sa.ClientIDs,
 			}
-			return usecaseop.Save(sa, repo, event), nil
+			return usecaseop.Save(sa, etag.Guard[serviceaccount.ServiceAccount](repo, "iam_service_accounts", cmd.IfMatch), event), nil
 		},
 	}
 }
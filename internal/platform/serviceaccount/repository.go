@@ -2,10 +2,14 @@ package serviceaccount
 
 import (
 	"context"
+	"errors"
+	"fmt"
 	"time"
 
+	"github.com/jackc/pgx/v5"
 	"github.com/jackc/pgx/v5/pgxpool"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
@@ -14,7 +18,8 @@ import (
 // Repository is the Postgres-backed repo. Table: iam_service_accounts.
 // Webhook credentials live as flat wh_* columns on the row (matches
 // Rust schema); the entity's WebhookCredentials struct is reconstituted
-// from those columns on read.
+// from those columns on read. The repo stores credentials as given: the
+// operations seal them (WebhookCredentials.Seal) before persisting.
 //
 // Role assignments (RoleAssignment) live in iam_principal_roles and are
 // owned by the principal subdomain — they are not persisted by this
@@ -47,6 +52,20 @@ func (r *Repository) FindByCode(ctx context.Context, code string) (*ServiceAccou
 	return rowToServiceAccount(*row), nil
 }
 
+// AllowedCIDRsForPrincipal returns the id and CIDR allowlist of the service
+// account behind principalID, or "" for principals that aren't service
+// accounts. Backs the network policy check on every authenticated request.
+func (r *Repository) AllowedCIDRsForPrincipal(ctx context.Context, principalID string) (string, []string, error) {
+	row, err := r.q.ServiceAccountAllowedCIDRsForPrincipal(ctx, principalID)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return "", nil, nil
+	}
+	if err != nil {
+		return "", nil, fmt.Errorf("service_account repo: %w", err)
+	}
+	return row.ID, row.AllowedCidrs, nil
+}
+
 // FindAll returns every service account.
 func (r *Repository) FindAll(ctx context.Context) ([]ServiceAccount, error) {
 	rows, err := r.q.ServiceAccountFindAll(ctx)
@@ -85,9 +104,48 @@ func (r *Repository) Persist(ctx context.Context, sa *ServiceAccount, tx *usecas
 		LastUsedAt:                 sa.LastUsedAt,
 		CreatedAt:                  sa.CreatedAt,
 		UpdatedAt:                  time.Now().UTC(),
+		AllowedCidrs:               sa.AllowedCIDRs,
 	})
 }
 
+// ResealReport is what ResealCredentials did.
+type ResealReport struct {
+	Scanned  int `json:"scanned"`
+	Resealed int `json:"resealed"`
+}
+
+// ResealCredentials brings every row's webhook credentials to their
+// at-rest form (see WebhookCredentials.Reseal): plaintext tokens are
+// hashed, plaintext signing secrets encrypted, and secrets sealed under a
+// previous app key re-encrypted. Only the two credential columns are
+// written, so updated_at and the row's ETag are left alone. Safe to re-run.
+func (r *Repository) ResealCredentials(ctx context.Context, enc *encryption.Service) (ResealReport, error) {
+	var rep ResealReport
+	all, err := r.FindAll(ctx)
+	if err != nil {
+		return rep, err
+	}
+	for _, sa := range all {
+		rep.Scanned++
+		if !sa.WebhookCredentials.NeedsReseal(enc) {
+			continue
+		}
+		creds, err := sa.WebhookCredentials.Reseal(enc)
+		if err != nil {
+			return rep, fmt.Errorf("service_account repo: reseal %s: %w", sa.ID, err)
+		}
+		if err := r.q.ServiceAccountSetCredentialRefs(ctx, dbq.ServiceAccountSetCredentialRefsParams{
+			ID:                 sa.ID,
+			WhAuthTokenRef:     creds.Token,
+			WhSigningSecretRef: creds.SigningSecret,
+		}); err != nil {
+			return rep, fmt.Errorf("service_account repo: reseal %s: %w", sa.ID, err)
+		}
+		rep.Resealed++
+	}
+	return rep, nil
+}
+
 // Delete removes the row.
 func (r *Repository) Delete(ctx context.Context, sa *ServiceAccount, tx *usecasepgx.DbTx) error {
 	return r.q.WithTx(tx.Inner()).ServiceAccountDelete(ctx, sa.ID)
@@ -106,6 +164,7 @@ func rowToServiceAccount(row dbq.IamServiceAccount) *ServiceAccount {
 		CreatedAt:     row.CreatedAt,
 		UpdatedAt:     row.UpdatedAt,
 		ClientIDs:     append([]string{}, row.ClientIds...),
+		AllowedCIDRs:  append([]string{}, row.AllowedCidrs...),
 		Roles:         []RoleAssignment{},
 		WebhookCredentials: WebhookCredentials{
 			AuthType:         WebhookAuthType(stringDerefOrEmpty(row.WhAuthType)),
@@ -0,0 +1,74 @@
+package apicommon
+
+import (
+	"encoding/base64"
+	"errors"
+	"strings"
+)
+
+// CursorQuery is the embeddable keyset-pagination query for the firehose
+// lists (events, dispatch jobs). Cursor mode is the default: rows come
+// newest first by TSID and each page's X-Next-Cursor response header is
+// passed back as `after`. Unlike an offset, a cursor doesn't skip or
+// repeat rows when new ones arrive between pages.
+//
+// Offset mode is kept for callers that page with `offset` (older SDKs):
+// `pagination=offset`, or any non-zero `offset` without `after`, selects
+// it.
+type CursorQuery struct {
+	After      string `query:"after" doc:"Opaque cursor from the previous page's X-Next-Cursor header"`
+	Pagination string `query:"pagination" doc:"cursor (default) or offset (legacy limit/offset paging)"`
+}
+
+// OffsetMode reports whether the caller asked for legacy offset paging.
+func (q CursorQuery) OffsetMode(offset int) bool {
+	return q.Pagination == "offset" || (q.After == "" && offset > 0)
+}
+
+// CursorOut is Out for a cursor-paginated bare-array list. The page stays
+// the JSON body (the SPA's tables bind the array directly); the position
+// of the next page travels in the X-Next-Cursor header, empty on the last
+// page.
+type CursorOut[T any] struct {
+	NextCursor string `header:"X-Next-Cursor"`
+	Body       []T
+}
+
+// CursorPage trims a page fetched with one row of over-fetch (limit+1)
+// to limit and returns it with the cursor for the page after it, built
+// from the last row's key ("" when nothing follows).
+func CursorPage[T any](rows []T, limit int, key func(*T) []string) ([]T, string) {
+	if len(rows) <= limit {
+		return rows, ""
+	}
+	rows = rows[:limit]
+	return rows, EncodeCursor(key(&rows[len(rows)-1])...)
+}
+
+// ErrBadCursor is returned by DecodeCursor for a token it didn't mint.
+var ErrBadCursor = errors.New("malformed cursor")
+
+// EncodeCursor serializes a keyset position (the sort-key values of the
+// last row served) into an opaque base64url token of the form
+// "<part>|<part>…". Parts must not contain "|".
+func EncodeCursor(parts ...string) string {
+	return base64.RawURLEncoding.EncodeToString([]byte(strings.Join(parts, "|")))
+}
+
+// DecodeCursor reverses EncodeCursor, requiring exactly n parts.
+func DecodeCursor(s string, n int) ([]string, error) {
+	b, err := base64.RawURLEncoding.DecodeString(s)
+	if err != nil {
+		return nil, ErrBadCursor
+	}
+	parts := strings.SplitN(string(b), "|", n)
+	if len(parts) != n {
+		return nil, ErrBadCursor
+	}
+	for _, p := range parts {
+		if p == "" {
+			return nil, ErrBadCursor
+		}
+	}
+	return parts, nil
+}
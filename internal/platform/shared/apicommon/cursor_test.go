@@ -0,0 +1,65 @@
+package apicommon_test
+
+import (
+	"testing"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+)
+
+func TestCursor_RoundTrip(t *testing.T) {
+	tok := apicommon.EncodeCursor("2026-10-15T12:00:00.5Z", "aud_0HZXEQ5Y8JY5Z")
+	parts, err := apicommon.DecodeCursor(tok, 2)
+	if err != nil {
+		t.Fatalf("DecodeCursor: %v", err)
+	}
+	if parts[0] != "2026-10-15T12:00:00.5Z" || parts[1] != "aud_0HZXEQ5Y8JY5Z" {
+		t.Errorf("parts = %v", parts)
+	}
+}
+
+func TestCursor_RejectsForeignTokens(t *testing.T) {
+	for name, tok := range map[string]string{
+		"not base64":  "!!!",
+		"wrong arity": apicommon.EncodeCursor("only-one"),
+		"empty part":  apicommon.EncodeCursor("", "id"),
+	} {
+		if _, err := apicommon.DecodeCursor(tok, 2); err == nil {
+			t.Errorf("%s: expected an error", name)
+		}
+	}
+}
+
+func TestCursorPage_TrimsOverFetch(t *testing.T) {
+	key := func(s *string) []string { return []string{*s} }
+
+	page, next := apicommon.CursorPage([]string{"c", "b", "a"}, 2, key)
+	if len(page) != 2 || next == "" {
+		t.Fatalf("page=%v next=%q", page, next)
+	}
+	if parts, _ := apicommon.DecodeCursor(next, 1); parts[0] != "b" {
+		t.Errorf("next cursor points at %v, want the last row served", parts)
+	}
+
+	page, next = apicommon.CursorPage([]string{"b", "a"}, 2, key)
+	if len(page) != 2 || next != "" {
+		t.Errorf("last page: page=%v next=%q", page, next)
+	}
+}
+
+func TestCursorQuery_OffsetMode(t *testing.T) {
+	cases := []struct {
+		q      apicommon.CursorQuery
+		offset int
+		want   bool
+	}{
+		{apicommon.CursorQuery{}, 0, false},
+		{apicommon.CursorQuery{}, 50, true},
+		{apicommon.CursorQuery{Pagination: "offset"}, 0, true},
+		{apicommon.CursorQuery{After: "x"}, 50, false},
+	}
+	for _, c := range cases {
+		if got := c.q.OffsetMode(c.offset); got != c.want {
+			t.Errorf("%+v offset=%d: got %v, want %v", c.q, c.offset, got, c.want)
+		}
+	}
+}
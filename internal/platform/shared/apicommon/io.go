@@ -18,6 +18,28 @@ type In[T any] struct {
 	Body T
 }
 
+// TaggedOut is Out with the resource's entity tag in the ETag header, for
+// the GETs whose tag a caller sends back in If-Match.
+type TaggedOut[T any] struct {
+	ETag string `header:"ETag"`
+	Body T
+}
+
+// UpsertOut answers a PUT-by-code upsert: 201 when the call created the
+// resource, 200 when it updated or confirmed it, with the new entity tag.
+type UpsertOut[T any] struct {
+	Status int
+	ETag   string `header:"ETag"`
+	Body   T
+}
+
+// IdempotentIn is In for create endpoints that honour an Idempotency-Key
+// header; see the idempotency package.
+type IdempotentIn[T any] struct {
+	IdempotencyKey string `header:"Idempotency-Key" doc:"Client-chosen key that makes retries of this create return the first response instead of creating again" maxLength:"255"`
+	Body           T
+}
+
 // Empty is the field-less input/output for handlers that take no
 // parameters or respond 204 No Content.
 type Empty struct{}
@@ -30,6 +52,14 @@ type IDInput struct {
 	ID string `path:"id"`
 }
 
+// IDMatchInput is IDInput for handlers that honour If-Match: a delete
+// (or other write with no body) that only proceeds while the resource
+// still carries the entity tag the caller last read.
+type IDMatchInput struct {
+	ID      string `path:"id"`
+	IfMatch string `header:"If-Match" doc:"Proceed only if the resource's current ETag matches"`
+}
+
 // OptStr maps an optional query filter to the repository convention:
 // "" means "not supplied" and becomes nil.
 func OptStr(s string) *string {
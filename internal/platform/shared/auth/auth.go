@@ -60,6 +60,8 @@ const (
 	permSubscriptionDelete = "platform:messaging:subscription:delete"
 	permSubscriptionSync   = "platform:messaging:subscription:sync"
 	permSubscriptionManage = "platform:messaging:subscription:manage"
+	// DispatchJob (messaging)
+	permDispatchJobView = "platform:messaging:dispatch-job:view"
 	// DispatchPool (messaging)
 	permDispatchPoolView   = "platform:messaging:dispatch-pool:view"
 	permDispatchPoolCreate = "platform:messaging:dispatch-pool:create"
@@ -151,6 +153,10 @@ type AuthContext struct {
 	AllApplications bool
 	// Permissions is the flattened set of permission codes from all roles.
 	Permissions []string
+	// PasskeyEnrollmentOnly marks a session that may only register a
+	// passkey (see sessiontoken.PurposePasskeyEnrollment). It carries no
+	// scope, roles or permissions.
+	PasskeyEnrollmentOnly bool
 }
 
 // The boolean methods below are nil-receiver-safe and fail closed: an
@@ -461,6 +467,12 @@ func CanWriteConnections(a *AuthContext) error {
 	return requireAny(a, permConnectionCreate, permConnectionUpdate, permConnectionDelete)
 }
 
+// ── Signing key permissions ──────────────────────────────────────────────
+// Client signing keys secure what connections deliver, so they ride on the
+// connection permissions rather than adding a catalogue entry.
+func CanReadSigningKeys(a *AuthContext) error   { return requirePermission(a, permConnectionView) }
+func CanManageSigningKeys(a *AuthContext) error { return requirePermission(a, permConnectionUpdate) }
+
 // ── Subscription permissions ─────────────────────────────────────────────
 func CanReadSubscriptions(a *AuthContext) error { return requirePermission(a, permSubscriptionView) }
 
@@ -480,6 +492,9 @@ func CanWriteSubscriptions(a *AuthContext) error {
 	return requireAny(a, permSubscriptionCreate, permSubscriptionUpdate, permSubscriptionDelete)
 }
 
+// ── Dispatch job permissions ─────────────────────────────────────────────
+func CanReadDispatchJobs(a *AuthContext) error { return requirePermission(a, permDispatchJobView) }
+
 // ── Dispatch pool permissions ────────────────────────────────────────────
 func CanReadDispatchPools(a *AuthContext) error { return requirePermission(a, permDispatchPoolView) }
 
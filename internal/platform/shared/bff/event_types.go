@@ -1,9 +1,11 @@
 package bff
 
 import (
+	"context"
 	"encoding/json"
 	"net/http"
 	"sort"
+	"strings"
 	"time"
 
 	"github.com/go-chi/chi/v5"
@@ -13,6 +15,7 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/seed"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/readcache"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
@@ -20,10 +23,12 @@ import (
 
 // EventTypesState holds the deps the BFF event-type endpoints reach into.
 // Hold by reference so future BFF endpoints can grow the state without
-// re-threading every caller.
+// re-threading every caller. Cache is optional; nil reads the repo on
+// every request.
 type EventTypesState struct {
-	Repo *eventtype.Repository
-	UoW  *usecasepgx.UnitOfWork
+	Repo  *eventtype.Repository
+	UoW   *usecasepgx.UnitOfWork
+	Cache *readcache.Cache[[]eventtype.EventType]
 }
 
 // RegisterEventTypes mounts the dashboard's `/bff/event-types/*`
@@ -134,7 +139,7 @@ func (s *EventTypesState) list(w http.ResponseWriter, r *http.Request) {
 	subdomain := strPtr(q.Get("subdomain"))
 	aggregate := strPtr(q.Get("aggregate"))
 
-	rows, err := s.Repo.FindWithFilters(r.Context(), application, nil, status, subdomain, aggregate)
+	rows, err := s.find(r.Context(), application, status, subdomain, aggregate)
 	if err != nil {
 		httperror.Write(w, usecase.Internal("REPO", "list event types failed", err))
 		return
@@ -176,7 +181,7 @@ func (s *EventTypesState) create(w http.ResponseWriter, r *http.Request) {
 	}
 	var body bffCreateEventTypeRequest
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	cmd := operations.CreateCommand{
@@ -214,7 +219,7 @@ func (s *EventTypesState) update(w http.ResponseWriter, r *http.Request) {
 	}
 	var body bffUpdateEventTypeRequest
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	cmd := operations.UpdateCommand{ID: chi.URLParam(r, "id")}
@@ -262,7 +267,7 @@ func (s *EventTypesState) addSchema(w http.ResponseWriter, r *http.Request) {
 	}
 	var body bffAddSchemaRequest
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	if body.Version == "" {
@@ -294,7 +299,7 @@ func (s *EventTypesState) addSchema(w http.ResponseWriter, r *http.Request) {
 // distinct subdomain strings.
 func (s *EventTypesState) filterSubdomains(w http.ResponseWriter, r *http.Request) {
 	app := strPtr(r.URL.Query().Get("application"))
-	rows, err := s.Repo.FindWithFilters(r.Context(), app, nil, nil, nil, nil)
+	rows, err := s.find(r.Context(), app, nil, nil, nil)
 	if err != nil {
 		httperror.Write(w, usecase.Internal("REPO", "list event types failed", err))
 		return
@@ -309,7 +314,7 @@ func (s *EventTypesState) filterAggregates(w http.ResponseWriter, r *http.Reques
 	q := r.URL.Query()
 	app := strPtr(q.Get("application"))
 	sub := strPtr(q.Get("subdomain"))
-	rows, err := s.Repo.FindWithFilters(r.Context(), app, nil, nil, sub, nil)
+	rows, err := s.find(r.Context(), app, nil, sub, nil)
 	if err != nil {
 		httperror.Write(w, usecase.Internal("REPO", "list event types failed", err))
 		return
@@ -319,6 +324,14 @@ func (s *EventTypesState) filterAggregates(w http.ResponseWriter, r *http.Reques
 
 // ── Helpers ──────────────────────────────────────────────────────────────
 
+// find is FindWithFilters through the read cache. The returned slice is
+// shared with other requests — map it, never modify it in place.
+func (s *EventTypesState) find(ctx context.Context, application, status, subdomain, aggregate *string) ([]eventtype.EventType, error) {
+	return s.Cache.Get(ctx, readcache.Key(application, status, subdomain, aggregate), func(ctx context.Context) ([]eventtype.EventType, error) {
+		return s.Repo.FindWithFilters(ctx, application, nil, status, subdomain, aggregate)
+	})
+}
+
 func toBffEventType(et eventtype.EventType) bffEventTypeResponse {
 	versions := make([]bffSpecVersionResponse, 0, len(et.SpecVersions))
 	for _, v := range et.SpecVersions {
@@ -513,31 +526,38 @@ func (s *EventTypesState) syncPlatform(w http.ResponseWriter, r *http.Request) {
 		applicationCode = "platform"
 	}
 
+	// The public flowcatalyst:* types belong to their own application.
+	// Sync them separately so neither run re-creates or removes the
+	// other's rows.
 	defs := seed.PlatformEventTypes()
-	inputs := make([]operations.SyncEventTypeInput, 0, len(defs))
+	var inputs, public []operations.SyncEventTypeInput
 	for _, d := range defs {
-		inputs = append(inputs, operations.SyncEventTypeInput{
+		in := operations.SyncEventTypeInput{
 			Code:   d.Code,
 			Name:   d.Name,
 			Schema: d.Schema,
-		})
+		}
+		if strings.HasPrefix(d.Code, "flowcatalyst:") {
+			public = append(public, in)
+		} else {
+			inputs = append(inputs, in)
+		}
 	}
 
-	cmd := operations.SyncEventTypesCommand{
-		ApplicationCode: applicationCode,
-		EventTypes:      inputs,
-		RemoveUnlisted:  true,
-	}
 	ec := usecase.NewExecutionContext(ac.PrincipalID)
-	ev, err := usecaseop.Run(r.Context(), s.UoW, operations.SyncEventTypes(s.Repo), cmd, ec)
-	if err != nil {
-		httperror.Write(w, err)
-		return
+	resp := bffSyncPlatformResponse{Total: uint32(len(defs))}
+	for _, cmd := range []operations.SyncEventTypesCommand{
+		{ApplicationCode: applicationCode, EventTypes: inputs, RemoveUnlisted: true},
+		{ApplicationCode: "flowcatalyst", EventTypes: public, RemoveUnlisted: true},
+	} {
+		ev, err := usecaseop.Run(r.Context(), s.UoW, operations.SyncEventTypes(s.Repo), cmd, ec)
+		if err != nil {
+			httperror.Write(w, err)
+			return
+		}
+		resp.Created += ev.Created
+		resp.Updated += ev.Updated
+		resp.Deleted += ev.Deleted
 	}
-	writeJSON(w, http.StatusOK, bffSyncPlatformResponse{
-		Created: ev.Created,
-		Updated: ev.Updated,
-		Deleted: ev.Deleted,
-		Total:   uint32(len(defs)),
-	})
+	writeJSON(w, http.StatusOK, resp)
 }
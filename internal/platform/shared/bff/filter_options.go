@@ -1,6 +1,7 @@
 package bff
 
 import (
+	"context"
 	"encoding/json"
 	"net/http"
 	"sort"
@@ -12,6 +13,7 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/readcache"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 )
 
@@ -24,10 +26,13 @@ type FilterOption struct {
 
 // FilterOptionsState bundles the repos the BFF filter endpoints reach
 // into. Held by reference so future endpoints can grow the state
-// without re-threading every caller.
+// without re-threading every caller. The caches are optional; nil reads
+// the repo on every request.
 type FilterOptionsState struct {
-	Clients    *client.Repository
-	EventTypes *eventtype.Repository
+	Clients         *client.Repository
+	EventTypes      *eventtype.Repository
+	ClientCache     *readcache.Cache[[]client.Client]
+	EventTypesCache *readcache.Cache[[]eventtype.EventType]
 }
 
 // RegisterFilterOptions mounts the most-called BFF dropdown endpoints:
@@ -39,7 +44,8 @@ type FilterOptionsState struct {
 // `find_active` + in-memory filter pattern — cheap given low row
 // counts; revisit if these endpoints ever become hot). Output is
 // alphabetically sorted on label so the dropdown is stable across
-// reloads.
+// reloads. With the read caches wired, repeat loads skip Postgres until
+// a client or event-type write invalidates them.
 //
 // Other BFF filter endpoints (subdomains, aggregates, dispatch-jobs,
 // events) are not yet ported — they follow the same shape. See
@@ -56,7 +62,7 @@ func RegisterFilterOptions(r chi.Router, s *FilterOptionsState) {
 // sees only clients in their access set.
 func (s *FilterOptionsState) clientOptions(w http.ResponseWriter, r *http.Request) {
 	ac := auth.FromContext(r.Context())
-	rows, err := s.Clients.FindAll(r.Context())
+	rows, err := s.ClientCache.Get(r.Context(), readcache.Key(), s.Clients.FindAll)
 	if err != nil {
 		httperror.Write(w, usecase.Internal("REPO", "list clients failed", err))
 		return
@@ -88,7 +94,11 @@ func (s *FilterOptionsState) eventTypeApplications(w http.ResponseWriter, r *htt
 	// unconstrained: the Rust handler uses `find_active_shallow` but
 	// the goal is "give me every application that has at least one
 	// event type," for which CURRENT+ARCHIVED is fine.
-	rows, err := s.EventTypes.FindWithFilters(r.Context(), nil, nil, nil, nil, nil)
+	// Same key as the unfiltered /bff/event-types list, so the two share
+	// an entry.
+	rows, err := s.EventTypesCache.Get(r.Context(), readcache.Key(nil, nil, nil, nil), func(ctx context.Context) ([]eventtype.EventType, error) {
+		return s.EventTypes.FindWithFilters(ctx, nil, nil, nil, nil, nil)
+	})
 	if err != nil {
 		httperror.Write(w, usecase.Internal("REPO", "list event types failed", err))
 		return
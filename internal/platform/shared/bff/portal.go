@@ -0,0 +1,565 @@
+package bff
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"io"
+	"net/http"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/processing"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey"
+	signingkeyops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	subscriptionops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/secrets"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Prober sends a subscription's endpoint a test delivery. Satisfied by
+// *processing.Handler.
+type Prober interface {
+	Probe(ctx context.Context, sub *subscription.Subscription) processing.ProbeResult
+}
+
+// PortalState holds the deps the tenant portal endpoints reach into.
+// Targets, Transports and Lifecycles check edits as the subscription API
+// does; Prober is optional, and without one there is no test endpoint.
+type PortalState struct {
+	Subscriptions *subscription.Repository
+	DispatchJobs  *dispatchjob.Repository
+	SigningKeys   *signingkey.Repository
+	Clients       *client.Repository
+	UoW           *usecasepgx.UnitOfWork
+	Targets       *outbound.Policies
+	Transports    *outbound.Transports
+	Lifecycles    *eventtype.LifecycleCache
+	Prober        Prober
+}
+
+// RegisterPortal mounts the tenant self-service portal's `/bff/portal/*`
+// endpoints: a client's own users managing that client's subscriptions,
+// delivery history and webhook signing keys.
+//
+// Routes:
+//
+//	GET  /bff/portal/clients/{clientId}/subscriptions
+//	GET  /bff/portal/clients/{clientId}/subscriptions/{id}
+//	PUT  /bff/portal/clients/{clientId}/subscriptions/{id}
+//	POST /bff/portal/clients/{clientId}/subscriptions/{id}/pause
+//	POST /bff/portal/clients/{clientId}/subscriptions/{id}/resume
+//	POST /bff/portal/clients/{clientId}/subscriptions/{id}/test
+//	GET  /bff/portal/clients/{clientId}/deliveries
+//	GET  /bff/portal/clients/{clientId}/deliveries/{id}
+//	GET  /bff/portal/clients/{clientId}/signing-keys
+//	POST /bff/portal/clients/{clientId}/signing-keys/rotate
+//
+// Access scoping: every route first requires access to {clientId}, then
+// the usual permission for what it does. Resources are the client's own
+// only — a platform-wide subscription or another client's job is a 404
+// here even for an anchor, so the portal never shows what the tenant
+// doesn't own.
+func RegisterPortal(r chi.Router, s *PortalState) {
+	r.Route("/bff/portal/clients/{clientId}", func(r chi.Router) {
+		r.Use(requirePortalClient)
+		r.Get("/subscriptions", s.listSubscriptions)
+		r.Get("/subscriptions/{id}", s.getSubscription)
+		r.Put("/subscriptions/{id}", s.updateSubscription)
+		r.Post("/subscriptions/{id}/pause", s.pauseSubscription)
+		r.Post("/subscriptions/{id}/resume", s.resumeSubscription)
+		if s.Prober != nil {
+			r.Post("/subscriptions/{id}/test", s.testSubscription)
+		}
+		r.Get("/deliveries", s.listDeliveries)
+		r.Get("/deliveries/{id}", s.getDelivery)
+		r.Get("/signing-keys", s.listSigningKeys)
+		r.Post("/signing-keys/rotate", s.rotateSigningKey)
+	})
+}
+
+// requirePortalClient rejects a caller without access to {clientId}.
+func requirePortalClient(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if !auth.FromContext(r.Context()).CanAccessClient(chi.URLParam(r, "clientId")) {
+			httperror.Write(w, httperror.Forbidden("No access to this client"))
+			return
+		}
+		next.ServeHTTP(w, r)
+	})
+}
+
+// ── Wire DTOs ────────────────────────────────────────────────────────────
+
+type bffPortalSubscription struct {
+	ID             string                     `json:"id"`
+	Code           string                     `json:"code"`
+	Name           string                     `json:"name"`
+	Description    *string                    `json:"description,omitempty"`
+	Status         string                     `json:"status"`
+	Delivery       string                     `json:"delivery"`
+	Endpoint       string                     `json:"endpoint"`
+	PayloadFormat  string                     `json:"payloadFormat"`
+	DataOnly       bool                       `json:"dataOnly"`
+	EventTypes     []string                   `json:"eventTypes"`
+	Headers        []subscription.ConfigEntry `json:"headers"`
+	TimeoutSeconds int32                      `json:"timeoutSeconds"`
+	MaxRetries     int32                      `json:"maxRetries"`
+	CreatedAt      time.Time                  `json:"createdAt"`
+	UpdatedAt      time.Time                  `json:"updatedAt"`
+}
+
+// bffPortalUpdateSubscriptionRequest is what a tenant may change; pools,
+// transports, connections and service accounts stay with the operator.
+type bffPortalUpdateSubscriptionRequest struct {
+	Name           *string                    `json:"name,omitempty"`
+	Description    *string                    `json:"description,omitempty"`
+	Endpoint       *string                    `json:"endpoint,omitempty"`
+	Headers        []subscription.ConfigEntry `json:"headers,omitempty"`
+	PayloadFormat  *string                    `json:"payloadFormat,omitempty"`
+	DataOnly       *bool                      `json:"dataOnly,omitempty"`
+	TimeoutSeconds *int32                     `json:"timeoutSeconds,omitempty"`
+	MaxRetries     *int32                     `json:"maxRetries,omitempty"`
+}
+
+type bffPortalDelivery struct {
+	ID             string     `json:"id"`
+	SubscriptionID *string    `json:"subscriptionId,omitempty"`
+	EventID        *string    `json:"eventId,omitempty"`
+	EventType      string     `json:"eventType"`
+	Status         string     `json:"status"`
+	AttemptCount   int32      `json:"attemptCount"`
+	MaxRetries     uint32     `json:"maxRetries"`
+	LastError      *string    `json:"lastError,omitempty"`
+	LastAttemptAt  *time.Time `json:"lastAttemptAt,omitempty"`
+	ScheduledFor   *time.Time `json:"scheduledFor,omitempty"`
+	CompletedAt    *time.Time `json:"completedAt,omitempty"`
+	CreatedAt      time.Time  `json:"createdAt"`
+}
+
+type bffPortalDeliveryDetail struct {
+	bffPortalDelivery
+	Attempts []bffPortalAttempt `json:"attempts"`
+}
+
+type bffPortalAttempt struct {
+	AttemptNumber  int32      `json:"attemptNumber"`
+	AttemptedAt    time.Time  `json:"attemptedAt"`
+	DurationMillis *int64     `json:"durationMillis,omitempty"`
+	Success        bool       `json:"success"`
+	ResponseCode   *int       `json:"responseCode,omitempty"`
+	ResponseBody   *string    `json:"responseBody,omitempty"`
+	ErrorType      *string    `json:"errorType,omitempty"`
+	ErrorMessage   *string    `json:"errorMessage,omitempty"`
+	CompletedAt    *time.Time `json:"completedAt,omitempty"`
+}
+
+// bffPortalDeliveryPage pages without a count: the job table is too big
+// to count per request, so the page says whether another follows.
+type bffPortalDeliveryPage struct {
+	Items   []bffPortalDelivery `json:"items"`
+	Page    uint32              `json:"page"`
+	Size    uint32              `json:"size"`
+	HasMore bool                `json:"hasMore"`
+}
+
+type bffPortalSigningKey struct {
+	ID         string     `json:"id"`
+	KeyID      string     `json:"keyId"`
+	Status     string     `json:"status"`
+	Signing    bool       `json:"signing"`
+	ActiveFrom time.Time  `json:"activeFrom"`
+	ExpiresAt  *time.Time `json:"expiresAt,omitempty"`
+	CreatedAt  time.Time  `json:"createdAt"`
+}
+
+type bffPortalRotateSigningKeyRequest struct {
+	KeyID          string     `json:"keyId,omitempty"`
+	ActiveFrom     *time.Time `json:"activeFrom,omitempty"`
+	OverlapSeconds *int64     `json:"overlapSeconds,omitempty"`
+}
+
+// ── Handlers ─────────────────────────────────────────────────────────────
+
+// GET /bff/portal/clients/{clientId}/subscriptions?status=
+func (s *PortalState) listSubscriptions(w http.ResponseWriter, r *http.Request) {
+	if err := auth.CanReadSubscriptions(auth.FromContext(r.Context())); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	clientID := chi.URLParam(r, "clientId")
+	subs, err := s.Subscriptions.FindWithFilters(r.Context(), strPtr(r.URL.Query().Get("status")), &clientID)
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "list subscriptions failed", err))
+		return
+	}
+	items := make([]bffPortalSubscription, 0, len(subs))
+	for i := range subs {
+		items = append(items, toPortalSubscription(&subs[i]))
+	}
+	writeJSON(w, http.StatusOK, map[string]any{"items": items, "total": len(items)})
+}
+
+// GET /bff/portal/clients/{clientId}/subscriptions/{id}
+func (s *PortalState) getSubscription(w http.ResponseWriter, r *http.Request) {
+	if err := auth.CanReadSubscriptions(auth.FromContext(r.Context())); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	sub, ok := s.ownSubscription(w, r)
+	if !ok {
+		return
+	}
+	writeJSON(w, http.StatusOK, toPortalSubscription(sub))
+}
+
+// PUT /bff/portal/clients/{clientId}/subscriptions/{id}
+//
+// Runs the regular UpdateSubscription use case, so endpoint checks,
+// events and audit behave as for an admin edit. A header may keep a
+// secret reference it already had but not introduce one: env:// and
+// friends resolve against the platform's own secrets.
+func (s *PortalState) updateSubscription(w http.ResponseWriter, r *http.Request) {
+	ac := auth.FromContext(r.Context())
+	if err := auth.CanUpdateSubscriptions(ac); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	sub, ok := s.ownSubscription(w, r)
+	if !ok {
+		return
+	}
+	var body bffPortalUpdateSubscriptionRequest
+	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
+		httperror.WriteDecodeError(w, err)
+		return
+	}
+	if err := checkPortalHeaders(sub.Headers, body.Headers); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	cmd := subscriptionops.UpdateCommand{
+		ID:             sub.ID,
+		Name:           body.Name,
+		Description:    body.Description,
+		Endpoint:       body.Endpoint,
+		Headers:        body.Headers,
+		PayloadFormat:  body.PayloadFormat,
+		DataOnly:       body.DataOnly,
+		TimeoutSeconds: body.TimeoutSeconds,
+		MaxRetries:     body.MaxRetries,
+	}
+	ec := usecase.NewExecutionContext(ac.PrincipalID)
+	if _, err := usecaseop.Run(r.Context(), s.UoW, subscriptionops.UpdateSubscription(s.Subscriptions, s.Targets, s.Transports, s.Lifecycles), cmd, ec); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	w.WriteHeader(http.StatusNoContent)
+}
+
+// POST /bff/portal/clients/{clientId}/subscriptions/{id}/pause
+func (s *PortalState) pauseSubscription(w http.ResponseWriter, r *http.Request) {
+	ac := auth.FromContext(r.Context())
+	if err := auth.CanUpdateSubscriptions(ac); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	sub, ok := s.ownSubscription(w, r)
+	if !ok {
+		return
+	}
+	ec := usecase.NewExecutionContext(ac.PrincipalID)
+	if _, err := usecaseop.Run(r.Context(), s.UoW, subscriptionops.PauseSubscription(s.Subscriptions), subscriptionops.PauseCommand{ID: sub.ID}, ec); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	w.WriteHeader(http.StatusNoContent)
+}
+
+// POST /bff/portal/clients/{clientId}/subscriptions/{id}/resume
+func (s *PortalState) resumeSubscription(w http.ResponseWriter, r *http.Request) {
+	ac := auth.FromContext(r.Context())
+	if err := auth.CanUpdateSubscriptions(ac); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	sub, ok := s.ownSubscription(w, r)
+	if !ok {
+		return
+	}
+	ec := usecase.NewExecutionContext(ac.PrincipalID)
+	if _, err := usecaseop.Run(r.Context(), s.UoW, subscriptionops.ResumeSubscription(s.Subscriptions), subscriptionops.ResumeCommand{ID: sub.ID}, ec); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	w.WriteHeader(http.StatusNoContent)
+}
+
+// POST /bff/portal/clients/{clientId}/subscriptions/{id}/test
+//
+// Sends the endpoint a test delivery (processing.Probe) and returns what
+// it answered. Always 200: a failed delivery is a result, not an error.
+func (s *PortalState) testSubscription(w http.ResponseWriter, r *http.Request) {
+	if err := auth.CanUpdateSubscriptions(auth.FromContext(r.Context())); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	sub, ok := s.ownSubscription(w, r)
+	if !ok {
+		return
+	}
+	writeJSON(w, http.StatusOK, s.Prober.Probe(r.Context(), sub))
+}
+
+// GET /bff/portal/clients/{clientId}/deliveries?subscriptionId=&status=&page=&size=
+//
+// Newest first. Filters narrow within the client: a subscriptionId of
+// another client's subscription matches nothing.
+func (s *PortalState) listDeliveries(w http.ResponseWriter, r *http.Request) {
+	if err := auth.CanReadDispatchJobs(auth.FromContext(r.Context())); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	q := r.URL.Query()
+	page, size := parsePagination(q)
+	clientID := chi.URLParam(r, "clientId")
+	jobs, err := s.DispatchJobs.FindWithFilters(r.Context(), dispatchjob.FilterParams{
+		ClientID:       &clientID,
+		SubscriptionID: strPtr(q.Get("subscriptionId")),
+		Status:         strPtr(q.Get("status")),
+		Limit:          int(size) + 1,
+		Offset:         int(page) * int(size),
+	})
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "list deliveries failed", err))
+		return
+	}
+	out := bffPortalDeliveryPage{Items: make([]bffPortalDelivery, 0, len(jobs)), Page: page, Size: size}
+	if len(jobs) > int(size) {
+		jobs, out.HasMore = jobs[:size], true
+	}
+	for i := range jobs {
+		out.Items = append(out.Items, toPortalDelivery(&jobs[i]))
+	}
+	writeJSON(w, http.StatusOK, out)
+}
+
+// GET /bff/portal/clients/{clientId}/deliveries/{id}
+//
+// The job with every attempt: status code, response body and error.
+func (s *PortalState) getDelivery(w http.ResponseWriter, r *http.Request) {
+	if err := auth.CanReadDispatchJobs(auth.FromContext(r.Context())); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	id := chi.URLParam(r, "id")
+	job, err := s.DispatchJobs.FindByID(r.Context(), id)
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "find delivery failed", err))
+		return
+	}
+	if job == nil || job.ClientID == nil || *job.ClientID != chi.URLParam(r, "clientId") {
+		httperror.Write(w, httperror.NotFound("DispatchJob", id))
+		return
+	}
+	attempts, err := s.DispatchJobs.AttemptsByJob(r.Context(), id)
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "load attempts failed", err))
+		return
+	}
+	out := bffPortalDeliveryDetail{bffPortalDelivery: toPortalDelivery(job), Attempts: make([]bffPortalAttempt, 0, len(attempts))}
+	for i := range attempts {
+		out.Attempts = append(out.Attempts, toPortalAttempt(&attempts[i]))
+	}
+	writeJSON(w, http.StatusOK, out)
+}
+
+// GET /bff/portal/clients/{clientId}/signing-keys
+func (s *PortalState) listSigningKeys(w http.ResponseWriter, r *http.Request) {
+	if err := auth.CanReadSigningKeys(auth.FromContext(r.Context())); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	keys, err := s.SigningKeys.FindByClient(r.Context(), chi.URLParam(r, "clientId"))
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "list signing keys failed", err))
+		return
+	}
+	now := time.Now().UTC()
+	items := make([]bffPortalSigningKey, 0, len(keys))
+	signing := false
+	for i := range keys {
+		// Newest window first, so the first key in effect is the signer.
+		isSigner := !signing && keys[i].ActiveAt(now)
+		signing = signing || isSigner
+		items = append(items, toPortalSigningKey(&keys[i], now, isSigner))
+	}
+	writeJSON(w, http.StatusOK, map[string]any{"items": items})
+}
+
+// POST /bff/portal/clients/{clientId}/signing-keys/rotate
+//
+// An empty body rotates with the defaults. The new secret is in this
+// response only.
+func (s *PortalState) rotateSigningKey(w http.ResponseWriter, r *http.Request) {
+	ac := auth.FromContext(r.Context())
+	if err := auth.CanManageSigningKeys(ac); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	var body bffPortalRotateSigningKeyRequest
+	if err := json.NewDecoder(r.Body).Decode(&body); err != nil && !errors.Is(err, io.EOF) {
+		httperror.WriteDecodeError(w, err)
+		return
+	}
+	cmd := signingkeyops.RotateCommand{
+		ClientID:       chi.URLParam(r, "clientId"),
+		KeyID:          body.KeyID,
+		ActiveFrom:     body.ActiveFrom,
+		OverlapSeconds: body.OverlapSeconds,
+	}
+	ec := usecase.NewExecutionContext(ac.PrincipalID)
+	event, err := usecaseop.Run(r.Context(), s.UoW, signingkeyops.RotateSigningKey(s.SigningKeys, s.Clients), cmd, ec)
+	if err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	k, err := s.SigningKeys.FindByID(r.Context(), event.SigningKeyID)
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "post-create reload failed", err))
+		return
+	}
+	if k == nil {
+		httperror.Write(w, httperror.NotFound("SigningKey", event.SigningKeyID))
+		return
+	}
+	now := time.Now().UTC()
+	cur, err := s.SigningKeys.Current(r.Context(), k.ClientID, now)
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "current signing key failed", err))
+		return
+	}
+	writeJSON(w, http.StatusCreated, map[string]any{
+		"signingKey": toPortalSigningKey(k, now, cur != nil && cur.ID == k.ID),
+		"secret":     k.Secret,
+	})
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────
+
+// ownSubscription loads {id}, writing a 404 unless it belongs to
+// {clientId}.
+func (s *PortalState) ownSubscription(w http.ResponseWriter, r *http.Request) (*subscription.Subscription, bool) {
+	id := chi.URLParam(r, "id")
+	sub, err := s.Subscriptions.FindByID(r.Context(), id)
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "find subscription failed", err))
+		return nil, false
+	}
+	if sub == nil || sub.ClientID == nil || *sub.ClientID != chi.URLParam(r, "clientId") {
+		httperror.Write(w, httperror.NotFound("Subscription", id))
+		return nil, false
+	}
+	return sub, true
+}
+
+// checkPortalHeaders refuses a secret reference in next that current
+// doesn't already carry under the same name.
+func checkPortalHeaders(current, next []subscription.ConfigEntry) error {
+	had := make(map[string]string, len(current))
+	for _, h := range current {
+		had[http.CanonicalHeaderKey(h.Key)] = h.Value
+	}
+	for _, h := range next {
+		if secrets.IsReference(h.Value) && had[http.CanonicalHeaderKey(h.Key)] != h.Value {
+			return usecase.Validation("SECRET_REFERENCE_FORBIDDEN",
+				"header "+h.Key+": secret references are set by the platform operator")
+		}
+	}
+	return nil
+}
+
+func toPortalSubscription(s *subscription.Subscription) bffPortalSubscription {
+	codes := make([]string, 0, len(s.EventTypes))
+	for _, b := range s.EventTypes {
+		codes = append(codes, b.EventTypeCode)
+	}
+	headers := s.Headers
+	if headers == nil {
+		headers = []subscription.ConfigEntry{}
+	}
+	return bffPortalSubscription{
+		ID:             s.ID,
+		Code:           s.Code,
+		Name:           s.Name,
+		Description:    s.Description,
+		Status:         string(s.Status),
+		Delivery:       string(s.Delivery),
+		Endpoint:       s.Endpoint,
+		PayloadFormat:  string(s.PayloadFormat),
+		DataOnly:       s.DataOnly,
+		EventTypes:     codes,
+		Headers:        headers,
+		TimeoutSeconds: s.TimeoutSeconds,
+		MaxRetries:     s.MaxRetries,
+		CreatedAt:      s.CreatedAt,
+		UpdatedAt:      s.UpdatedAt,
+	}
+}
+
+func toPortalDelivery(j *dispatchjob.DispatchJob) bffPortalDelivery {
+	return bffPortalDelivery{
+		ID:             j.ID,
+		SubscriptionID: j.SubscriptionID,
+		EventID:        j.EventID,
+		EventType:      j.Code,
+		Status:         string(j.Status),
+		AttemptCount:   j.AttemptCount,
+		MaxRetries:     j.MaxRetries,
+		LastError:      j.LastError,
+		LastAttemptAt:  j.LastAttemptAt,
+		ScheduledFor:   j.ScheduledFor,
+		CompletedAt:    j.CompletedAt,
+		CreatedAt:      j.CreatedAt,
+	}
+}
+
+func toPortalAttempt(a *dispatchjob.Attempt) bffPortalAttempt {
+	out := bffPortalAttempt{
+		AttemptNumber:  a.AttemptNumber,
+		AttemptedAt:    a.AttemptedAt,
+		DurationMillis: a.DurationMillis,
+		Success:        a.Success,
+		ResponseCode:   a.ResponseCode,
+		ResponseBody:   a.ResponseBody,
+		ErrorMessage:   a.ErrorMessage,
+		CompletedAt:    a.CompletedAt,
+	}
+	if a.ErrorType != nil {
+		et := string(*a.ErrorType)
+		out.ErrorType = &et
+	}
+	return out
+}
+
+func toPortalSigningKey(k *signingkey.SigningKey, now time.Time, signing bool) bffPortalSigningKey {
+	return bffPortalSigningKey{
+		ID:         k.ID,
+		KeyID:      k.KeyID,
+		Status:     k.StatusAt(now),
+		Signing:    signing,
+		ActiveFrom: k.ActiveFrom,
+		ExpiresAt:  k.ExpiresAt,
+		CreatedAt:  k.CreatedAt,
+	}
+}
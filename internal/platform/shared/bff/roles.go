@@ -341,7 +341,7 @@ func (s *RolesState) createPermission(w http.ResponseWriter, r *http.Request) {
 	}
 	var body bffCreatePermissionRequest
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	app := strings.TrimSpace(body.Application)
@@ -449,7 +449,7 @@ func (s *RolesState) create(w http.ResponseWriter, r *http.Request) {
 	}
 	var body bffCreateRoleRequest
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	cmd := operations.CreateCommand{
@@ -478,7 +478,7 @@ func (s *RolesState) update(w http.ResponseWriter, r *http.Request) {
 	}
 	var body bffUpdateRoleRequest
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	role, err := s.resolveRole(r, chi.URLParam(r, "roleName"))
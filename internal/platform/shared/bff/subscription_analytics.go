@@ -0,0 +1,243 @@
+package bff
+
+import (
+	"net/http"
+	"sort"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/stream"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+// SubscriptionAnalyticsState holds the deps of the subscription analytics
+// endpoint. The numbers come from msg_subscription_delivery_stats, the
+// hourly rollup the stream processor keeps (stream.DeliveryStatsRollup),
+// so they trail live deliveries by up to a rollup tick.
+type SubscriptionAnalyticsState struct {
+	Pool          *pgxpool.Pool
+	Subscriptions *subscription.Repository
+}
+
+// RegisterSubscriptionAnalytics mounts
+//
+//	GET /bff/subscriptions/{id}/analytics?range=24h
+func RegisterSubscriptionAnalytics(r chi.Router, s *SubscriptionAnalyticsState) {
+	r.Get("/bff/subscriptions/{id}/analytics", s.analytics)
+}
+
+// analyticsRanges are the windows the charts offer; the rollup keeps 30
+// days by default.
+var analyticsRanges = map[string]time.Duration{
+	"6h":  6 * time.Hour,
+	"24h": 24 * time.Hour,
+	"7d":  7 * 24 * time.Hour,
+	"30d": 30 * 24 * time.Hour,
+}
+
+// topErrorCount caps the error codes listed.
+const topErrorCount = 10
+
+type bffSubscriptionAnalytics struct {
+	SubscriptionID string                   `json:"subscriptionId"`
+	Range          string                   `json:"range"`
+	From           time.Time                `json:"from"`
+	To             time.Time                `json:"to"`
+	Summary        bffAnalyticsSummary      `json:"summary"`
+	Series         []bffAnalyticsBucket     `json:"series"`
+	TopErrors      []bffAnalyticsErrorCount `json:"topErrors"`
+	Retries        []bffAnalyticsRetryCount `json:"retries"`
+}
+
+type bffAnalyticsSummary struct {
+	Attempts     int64                 `json:"attempts"`
+	Successes    int64                 `json:"successes"`
+	Failures     int64                 `json:"failures"`
+	SuccessRate  *float64              `json:"successRate,omitempty"`
+	AvgLatencyMs *int64                `json:"avgLatencyMs,omitempty"`
+	LatencyMs    bffLatencyPercentiles `json:"latencyMs"`
+}
+
+// bffLatencyPercentiles are estimates from the rollup's latency histogram.
+type bffLatencyPercentiles struct {
+	P50 *int64 `json:"p50,omitempty"`
+	P90 *int64 `json:"p90,omitempty"`
+	P95 *int64 `json:"p95,omitempty"`
+	P99 *int64 `json:"p99,omitempty"`
+}
+
+// bffAnalyticsBucket is one hour; hours without attempts are present with
+// zero counts so the chart's time axis has no gaps.
+type bffAnalyticsBucket struct {
+	BucketStart  time.Time `json:"bucketStart"`
+	Attempts     int64     `json:"attempts"`
+	Successes    int64     `json:"successes"`
+	Failures     int64     `json:"failures"`
+	SuccessRate  *float64  `json:"successRate,omitempty"`
+	LatencyP50Ms *int64    `json:"latencyP50Ms,omitempty"`
+	LatencyP95Ms *int64    `json:"latencyP95Ms,omitempty"`
+}
+
+// bffAnalyticsErrorCount is a failure key — a response code such as
+// "503", or an error type such as "TIMEOUT" when there was no response.
+type bffAnalyticsErrorCount struct {
+	Code  string `json:"code"`
+	Count int64  `json:"count"`
+}
+
+// bffAnalyticsRetryCount counts attempts by attempt number; OrMore marks
+// the last entry, which takes that number and above.
+type bffAnalyticsRetryCount struct {
+	Attempt int   `json:"attempt"`
+	Count   int64 `json:"count"`
+	OrMore  bool  `json:"orMore,omitempty"`
+}
+
+// GET /bff/subscriptions/{id}/analytics?range=6h|24h|7d|30d
+//
+// Deliveries over time in hourly buckets, success rate, latency
+// percentiles, the most frequent failure codes and how many attempts
+// deliveries took. range defaults to 24h.
+func (s *SubscriptionAnalyticsState) analytics(w http.ResponseWriter, r *http.Request) {
+	ac := auth.FromContext(r.Context())
+	if err := auth.CanReadSubscriptions(ac); err != nil {
+		httperror.Write(w, err)
+		return
+	}
+	rangeParam := r.URL.Query().Get("range")
+	if rangeParam == "" {
+		rangeParam = "24h"
+	}
+	window, ok := analyticsRanges[rangeParam]
+	if !ok {
+		httperror.Write(w, httperror.BadRequest("INVALID_RANGE", "range must be one of 6h, 24h, 7d, 30d"))
+		return
+	}
+	id := chi.URLParam(r, "id")
+	sub, err := s.Subscriptions.FindByID(r.Context(), id)
+	if err != nil {
+		httperror.Write(w, usecase.Internal("REPO", "find subscription failed", err))
+		return
+	}
+	if sub == nil {
+		httperror.Write(w, httperror.NotFound("Subscription", id))
+		return
+	}
+	if sub.ClientID != nil && !ac.CanAccessClient(*sub.ClientID) {
+		httperror.Write(w, httperror.Forbidden("No access to this subscription"))
+		return
+	}
+
+	to := time.Now().UTC()
+	from := to.Add(-window).Truncate(time.Hour)
+	buckets, err := stream.LoadDeliveryStats(r.Context(), s.Pool, sub.ID, from)
+	if err != nil {
+		httperror.Write(w, usecase.Internal("DB", "load delivery stats failed", err))
+		return
+	}
+	writeJSON(w, http.StatusOK, buildSubscriptionAnalytics(sub.ID, rangeParam, from, to, buckets))
+}
+
+// buildSubscriptionAnalytics shapes stored hours into the response, from
+// from's hour through to's.
+func buildSubscriptionAnalytics(subID, rangeParam string, from, to time.Time, buckets []stream.DeliveryStatsBucket) bffSubscriptionAnalytics {
+	out := bffSubscriptionAnalytics{
+		SubscriptionID: subID,
+		Range:          rangeParam,
+		From:           from,
+		To:             to,
+		Series:         []bffAnalyticsBucket{},
+		TopErrors:      []bffAnalyticsErrorCount{},
+		Retries:        make([]bffAnalyticsRetryCount, stream.RetrySlots),
+	}
+	byHour := make(map[time.Time]*stream.DeliveryStatsBucket, len(buckets))
+	latency := make([]int64, len(stream.LatencyBucketsMs)+1)
+	errCounts := map[string]int64{}
+	var durationTotal int64
+	for i := range buckets {
+		b := &buckets[i]
+		byHour[b.Start.UTC()] = b
+		out.Summary.Attempts += b.Attempts
+		out.Summary.Successes += b.Successes
+		out.Summary.Failures += b.Failures
+		durationTotal += b.DurationMsTotal
+		addInto(latency, b.LatencyHistogram)
+		for code, n := range b.Errors {
+			errCounts[code] += n
+		}
+		for slot := 0; slot < len(b.RetryHistogram) && slot < stream.RetrySlots; slot++ {
+			out.Retries[slot].Count += b.RetryHistogram[slot]
+		}
+	}
+
+	for hour := from.Truncate(time.Hour); !hour.After(to); hour = hour.Add(time.Hour) {
+		point := bffAnalyticsBucket{BucketStart: hour}
+		if b, ok := byHour[hour]; ok {
+			point.Attempts, point.Successes, point.Failures = b.Attempts, b.Successes, b.Failures
+			point.SuccessRate = successRate(b.Successes, b.Attempts)
+			point.LatencyP50Ms = stream.LatencyPercentile(b.LatencyHistogram, 0.50)
+			point.LatencyP95Ms = stream.LatencyPercentile(b.LatencyHistogram, 0.95)
+		}
+		out.Series = append(out.Series, point)
+	}
+
+	out.Summary.SuccessRate = successRate(out.Summary.Successes, out.Summary.Attempts)
+	if timed := sum(latency); timed > 0 {
+		avg := durationTotal / timed
+		out.Summary.AvgLatencyMs = &avg
+	}
+	out.Summary.LatencyMs = bffLatencyPercentiles{
+		P50: stream.LatencyPercentile(latency, 0.50),
+		P90: stream.LatencyPercentile(latency, 0.90),
+		P95: stream.LatencyPercentile(latency, 0.95),
+		P99: stream.LatencyPercentile(latency, 0.99),
+	}
+
+	for code, n := range errCounts {
+		out.TopErrors = append(out.TopErrors, bffAnalyticsErrorCount{Code: code, Count: n})
+	}
+	sort.Slice(out.TopErrors, func(i, j int) bool {
+		if out.TopErrors[i].Count != out.TopErrors[j].Count {
+			return out.TopErrors[i].Count > out.TopErrors[j].Count
+		}
+		return out.TopErrors[i].Code < out.TopErrors[j].Code
+	})
+	if len(out.TopErrors) > topErrorCount {
+		out.TopErrors = out.TopErrors[:topErrorCount]
+	}
+
+	for slot := range out.Retries {
+		out.Retries[slot].Attempt = slot + 1
+	}
+	out.Retries[len(out.Retries)-1].OrMore = true
+	return out
+}
+
+func successRate(successes, attempts int64) *float64 {
+	if attempts == 0 {
+		return nil
+	}
+	rate := float64(successes) / float64(attempts)
+	return &rate
+}
+
+// addInto adds src into dst element-wise, ignoring what dst has no room
+// for.
+func addInto(dst, src []int64) {
+	for i := 0; i < len(dst) && i < len(src); i++ {
+		dst[i] += src[i]
+	}
+}
+
+func sum(xs []int64) int64 {
+	var n int64
+	for _, x := range xs {
+		n += x
+	}
+	return n
+}
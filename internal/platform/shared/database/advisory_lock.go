@@ -0,0 +1,41 @@
+package database
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// AdvisoryLock takes the session-level advisory lock key on a connection
+// of its own, waiting while another instance holds it. name labels the
+// lock in logs and errors. The returned func releases it; should the
+// unlock fail, the connection is closed rather than returned to the pool
+// still holding the lock.
+func AdvisoryLock(ctx context.Context, pool *pgxpool.Pool, key int64, name string) (func(), error) {
+	conn, err := pool.Acquire(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("acquire %s lock connection: %w", name, err)
+	}
+	var got bool
+	if err := conn.QueryRow(ctx, `SELECT pg_try_advisory_lock($1)`, key).Scan(&got); err != nil {
+		conn.Release()
+		return nil, fmt.Errorf("%s lock: %w", name, err)
+	}
+	if !got {
+		slog.Info("another instance holds the "+name+" lock; waiting for it", "lock", name)
+		if _, err := conn.Exec(ctx, `SELECT pg_advisory_lock($1)`, key); err != nil {
+			conn.Release()
+			return nil, fmt.Errorf("%s lock: %w", name, err)
+		}
+	}
+	return func() {
+		ctx := context.WithoutCancel(ctx)
+		if _, err := conn.Exec(ctx, `SELECT pg_advisory_unlock($1)`, key); err != nil {
+			slog.Warn("releasing advisory lock failed; dropping its connection", "lock", name, "err", err)
+			_ = conn.Conn().Close(ctx)
+		}
+		conn.Release()
+	}, nil
+}
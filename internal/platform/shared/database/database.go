@@ -18,6 +18,9 @@ type Config struct {
 	MaxConnections     int
 	MinConnections     int
 	MaxLifetimeSeconds int
+	// Tracer, when set, bounds and times every statement on the pool
+	// (see QueryTracer).
+	Tracer *QueryTracer
 }
 
 // NewPool creates a pgxpool.Pool tuned to FlowCatalyst defaults.
@@ -41,6 +44,9 @@ func NewPoolWithBeforeConnect(ctx context.Context, cfg Config, beforeConnect fun
 	if beforeConnect != nil {
 		pgCfg.BeforeConnect = beforeConnect
 	}
+	if cfg.Tracer != nil {
+		pgCfg.ConnConfig.Tracer = cfg.Tracer
+	}
 	if cfg.MaxConnections > 0 {
 		pgCfg.MaxConns = int32(cfg.MaxConnections)
 	}
@@ -0,0 +1,158 @@
+package database
+
+import (
+	"context"
+	"log/slog"
+	"regexp"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+)
+
+// SlowQuery describes one statement that ran past the slow threshold.
+// It carries the statement's shape, never its arguments: placeholders
+// stay as $n so no tenant data reaches logs or warnings.
+type SlowQuery struct {
+	Operation string // sqlc query name ("ClientFindByID"); "" for hand-written SQL
+	Table     string // first table the statement reads or writes
+	Filter    string // the WHERE clause, whitespace-collapsed; "" when there is none
+	Duration  time.Duration
+	Err       error // non-nil when the statement failed (including by timing out)
+}
+
+// QueryTracer is a pgx.QueryTracer that bounds every statement on the
+// pool with a default timeout and reports statements slower than a
+// threshold, so a missing index shows up as a warning instead of a BFF
+// that quietly gets slower. Install it via Config.Tracer.
+//
+// The timeout only ever tightens a deadline: a caller whose context
+// already expires sooner keeps its own. WithQueryTimeout overrides the
+// default for one call path (migrations disable it).
+type QueryTracer struct {
+	timeout time.Duration
+	slow    time.Duration
+
+	mu     sync.RWMutex
+	onSlow func(SlowQuery)
+}
+
+// NewQueryTracer builds a tracer. A zero timeout leaves statements
+// unbounded; a zero slowThreshold disables slow-query reporting.
+func NewQueryTracer(timeout, slowThreshold time.Duration) *QueryTracer {
+	return &QueryTracer{timeout: timeout, slow: slowThreshold}
+}
+
+// OnSlowQuery registers fn to receive every slow query in addition to
+// the log line. Set after the pool exists (the warning service is built
+// later than the pool); nil detaches.
+func (t *QueryTracer) OnSlowQuery(fn func(SlowQuery)) {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	t.onSlow = fn
+}
+
+type queryTimeoutKey struct{}
+
+// WithQueryTimeout overrides the tracer's default statement timeout for
+// queries issued with ctx. 0 disables it (migrations, bulk backfills).
+func WithQueryTimeout(ctx context.Context, d time.Duration) context.Context {
+	return context.WithValue(ctx, queryTimeoutKey{}, d)
+}
+
+type traceKey struct{}
+
+type traceState struct {
+	start  time.Time
+	sql    string
+	cancel context.CancelFunc
+}
+
+// TraceQueryStart implements pgx.QueryTracer. pgx runs the statement
+// (and, for Query, reads its rows) with the context returned here, so
+// the timeout applies until TraceQueryEnd releases it.
+func (t *QueryTracer) TraceQueryStart(ctx context.Context, _ *pgx.Conn, data pgx.TraceQueryStartData) context.Context {
+	st := &traceState{start: time.Now(), sql: data.SQL}
+	timeout := t.timeout
+	if d, ok := ctx.Value(queryTimeoutKey{}).(time.Duration); ok {
+		timeout = d
+	}
+	if timeout > 0 {
+		if deadline, ok := ctx.Deadline(); !ok || time.Until(deadline) > timeout {
+			ctx, st.cancel = context.WithTimeout(ctx, timeout)
+		}
+	}
+	return context.WithValue(ctx, traceKey{}, st)
+}
+
+// TraceQueryEnd implements pgx.QueryTracer.
+func (t *QueryTracer) TraceQueryEnd(ctx context.Context, _ *pgx.Conn, data pgx.TraceQueryEndData) {
+	st, ok := ctx.Value(traceKey{}).(*traceState)
+	if !ok {
+		return
+	}
+	if st.cancel != nil {
+		st.cancel()
+	}
+	elapsed := time.Since(st.start)
+	if t.slow <= 0 || elapsed < t.slow {
+		return
+	}
+	q := DescribeQuery(st.sql)
+	q.Duration, q.Err = elapsed, data.Err
+	slog.Warn("slow query",
+		"operation", q.Operation, "table", q.Table, "filter", q.Filter,
+		"duration_ms", elapsed.Milliseconds(), "err", q.Err)
+
+	t.mu.RLock()
+	fn := t.onSlow
+	t.mu.RUnlock()
+	if fn != nil {
+		fn(q)
+	}
+}
+
+var (
+	sqlcNameRe  = regexp.MustCompile(`^--\s*name:\s*(\w+)`)
+	tableRe     = regexp.MustCompile(`(?i)\b(?:FROM|INTO|UPDATE|JOIN)\s+([a-z_][a-z0-9_.]*)`)
+	whereRe     = regexp.MustCompile(`(?i)\sWHERE\s`)
+	filterEndRe = regexp.MustCompile(`(?i)\s(?:ORDER BY|GROUP BY|LIMIT|OFFSET|FOR UPDATE|FOR SHARE|RETURNING|ON CONFLICT)\b`)
+)
+
+// maxFilterLen keeps a pathological generated WHERE clause from
+// swamping a warning.
+const maxFilterLen = 300
+
+// DescribeQuery extracts the shape of sql: the sqlc query name when the
+// statement carries sqlc's "-- name:" header, the first table it
+// touches, and its first WHERE clause. Duration and Err are left
+// zero.
+func DescribeQuery(sql string) SlowQuery {
+	var q SlowQuery
+	if m := sqlcNameRe.FindStringSubmatch(sql); m != nil {
+		q.Operation = m[1]
+	}
+	// Drop comment lines (the sqlc header) before collapsing whitespace.
+	var lines []string
+	for _, l := range strings.Split(sql, "\n") {
+		if !strings.HasPrefix(strings.TrimSpace(l), "--") {
+			lines = append(lines, l)
+		}
+	}
+	flat := strings.Join(strings.Fields(strings.Join(lines, " ")), " ")
+	if m := tableRe.FindStringSubmatch(flat); m != nil {
+		q.Table = m[1]
+	}
+	if loc := whereRe.FindStringIndex(flat); loc != nil {
+		filter := flat[loc[1]:]
+		if end := filterEndRe.FindStringIndex(filter); end != nil {
+			filter = filter[:end[0]]
+		}
+		if len(filter) > maxFilterLen {
+			filter = filter[:maxFilterLen] + "…"
+		}
+		q.Filter = filter
+	}
+	return q
+}
@@ -0,0 +1,95 @@
+package database
+
+import (
+	"context"
+	"errors"
+	"testing"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+)
+
+func TestDescribeQuery(t *testing.T) {
+	t.Parallel()
+
+	cases := []struct {
+		name string
+		sql  string
+		want SlowQuery
+	}{
+		{
+			name: "sqlc",
+			sql:  "-- name: ClientSearch :many\nSELECT id, name\nFROM tnt_clients\nWHERE name ILIKE $1 OR identifier ILIKE $1\nORDER BY name\nLIMIT 50\n",
+			want: SlowQuery{Operation: "ClientSearch", Table: "tnt_clients", Filter: "name ILIKE $1 OR identifier ILIKE $1"},
+		},
+		{
+			name: "hand-written",
+			sql:  "SELECT count(*) FROM msg_events e JOIN msg_event_types t ON t.code = e.type where e.client_id = ANY($1) AND e.created_at >= $2",
+			want: SlowQuery{Table: "msg_events", Filter: "e.client_id = ANY($1) AND e.created_at >= $2"},
+		},
+		{
+			name: "update returning",
+			sql:  "UPDATE msg_dispatch_jobs SET status = $2 WHERE id = $1 RETURNING id",
+			want: SlowQuery{Table: "msg_dispatch_jobs", Filter: "id = $1"},
+		},
+		{
+			name: "no filter",
+			sql:  "INSERT INTO tnt_clients (id) VALUES ($1)",
+			want: SlowQuery{Table: "tnt_clients"},
+		},
+	}
+	for _, tc := range cases {
+		if got := DescribeQuery(tc.sql); got != tc.want {
+			t.Errorf("%s: DescribeQuery = %+v, want %+v", tc.name, got, tc.want)
+		}
+	}
+}
+
+func TestQueryTracerTimeout(t *testing.T) {
+	t.Parallel()
+
+	tr := NewQueryTracer(time.Second, 0)
+	start := pgx.TraceQueryStartData{SQL: "SELECT 1"}
+
+	ctx := tr.TraceQueryStart(context.Background(), nil, start)
+	if d, ok := ctx.Deadline(); !ok || time.Until(d) > time.Second {
+		t.Errorf("default deadline = %v, %v; want within 1s", d, ok)
+	}
+	tr.TraceQueryEnd(ctx, nil, pgx.TraceQueryEndData{})
+	if !errors.Is(ctx.Err(), context.Canceled) {
+		t.Errorf("ctx.Err after end = %v, want Canceled", ctx.Err())
+	}
+
+	// A sooner caller deadline is kept as is.
+	parent, cancel := context.WithTimeout(context.Background(), 10*time.Millisecond)
+	defer cancel()
+	want, _ := parent.Deadline()
+	ctx = tr.TraceQueryStart(parent, nil, start)
+	if d, _ := ctx.Deadline(); !d.Equal(want) {
+		t.Errorf("deadline = %v, want caller's %v", d, want)
+	}
+	tr.TraceQueryEnd(ctx, nil, pgx.TraceQueryEndData{})
+
+	// WithQueryTimeout(0) disables the bound.
+	ctx = tr.TraceQueryStart(WithQueryTimeout(context.Background(), 0), nil, start)
+	if _, ok := ctx.Deadline(); ok {
+		t.Error("WithQueryTimeout(0) still set a deadline")
+	}
+	tr.TraceQueryEnd(ctx, nil, pgx.TraceQueryEndData{})
+}
+
+func TestQueryTracerSlowQuery(t *testing.T) {
+	t.Parallel()
+
+	tr := NewQueryTracer(0, time.Nanosecond)
+	var got []SlowQuery
+	tr.OnSlowQuery(func(q SlowQuery) { got = append(got, q) })
+
+	ctx := tr.TraceQueryStart(context.Background(), nil, pgx.TraceQueryStartData{SQL: "-- name: ClientFindByID :one\nSELECT * FROM tnt_clients WHERE id = $1"})
+	time.Sleep(time.Millisecond)
+	tr.TraceQueryEnd(ctx, nil, pgx.TraceQueryEndData{})
+
+	if len(got) != 1 || got[0].Operation != "ClientFindByID" || got[0].Filter != "id = $1" || got[0].Duration <= 0 {
+		t.Errorf("slow queries = %+v", got)
+	}
+}
@@ -0,0 +1,124 @@
+// Package etag carries optimistic concurrency for the admin API. A
+// resource's entity tag is derived from its updated_at, which every
+// repository stamps on each write, so the tag changes whenever the row
+// does. GET responses carry it in ETag; PUT and DELETE accept it back in
+// If-Match and fail with 412 when the row has moved on, instead of
+// silently overwriting someone else's change.
+//
+// The check runs twice: once in the operation's Execute against the row
+// it loaded (cheap, and it fails before any event is built), and again by
+// [Guard] on the row locked inside the commit transaction, which closes
+// the window between the load and the write.
+package etag
+
+import (
+	"context"
+	"errors"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Of returns the strong entity tag of a row last written at updatedAt.
+// Postgres keeps microseconds, so the tag does too: a freshly written
+// entity and the same row read back produce the same tag.
+func Of(updatedAt time.Time) string {
+	return `"` + strconv.FormatInt(updatedAt.UnixMicro(), 36) + `"`
+}
+
+// Matches reports whether an If-Match header value admits current. An
+// empty header admits anything (the check is opt-in), as does "*". A
+// list matches if any member does; weak tags compare by their opaque
+// part.
+func Matches(ifMatch, current string) bool {
+	ifMatch = strings.TrimSpace(ifMatch)
+	if ifMatch == "" || ifMatch == "*" {
+		return true
+	}
+	for tag := range strings.SplitSeq(ifMatch, ",") {
+		tag = strings.TrimPrefix(strings.TrimSpace(tag), "W/")
+		if tag == current {
+			return true
+		}
+	}
+	return false
+}
+
+// Check fails with a precondition error when ifMatch doesn't admit a row
+// last written at updatedAt.
+func Check(ifMatch string, updatedAt time.Time) error {
+	if Matches(ifMatch, Of(updatedAt)) {
+		return nil
+	}
+	return stale()
+}
+
+// CheckUpsert applies a PUT-by-code's preconditions before it decides
+// between create and update. If-Match needs a resource to match against,
+// so it fails when there is none (the update re-checks the tag itself);
+// If-None-Match: * asks for create-only and fails when the code is taken.
+func CheckUpsert(ifMatch, ifNoneMatch string, exists bool) error {
+	if !exists && strings.TrimSpace(ifMatch) != "" {
+		return usecase.PreconditionFailed("PRECONDITION_FAILED",
+			"If-Match was sent but no resource with this code exists")
+	}
+	if exists && strings.TrimSpace(ifNoneMatch) == "*" {
+		return usecase.PreconditionFailed("PRECONDITION_FAILED",
+			"If-None-Match: * was sent but a resource with this code already exists")
+	}
+	return nil
+}
+
+func stale() error {
+	return usecase.PreconditionFailed("PRECONDITION_FAILED",
+		"The resource was modified since it was read; fetch it again and retry with the new ETag")
+}
+
+// Guard wraps repo so Persist and Delete first lock the aggregate's row
+// in table and re-check ifMatch against it, in the commit transaction.
+// With no If-Match the repository is returned as is.
+func Guard[A usecase.HasID](repo usecasepgx.Persist[A], table, ifMatch string) usecasepgx.Persist[A] {
+	if strings.TrimSpace(ifMatch) == "" {
+		return repo
+	}
+	return guard[A]{repo: repo, table: table, ifMatch: ifMatch}
+}
+
+type guard[A usecase.HasID] struct {
+	repo    usecasepgx.Persist[A]
+	table   string
+	ifMatch string
+}
+
+func (g guard[A]) Persist(ctx context.Context, agg *A, tx *usecasepgx.DbTx) error {
+	if err := g.recheck(ctx, (*agg).IDStr(), tx); err != nil {
+		return err
+	}
+	return g.repo.Persist(ctx, agg, tx)
+}
+
+func (g guard[A]) Delete(ctx context.Context, agg *A, tx *usecasepgx.DbTx) error {
+	if err := g.recheck(ctx, (*agg).IDStr(), tx); err != nil {
+		return err
+	}
+	return g.repo.Delete(ctx, agg, tx)
+}
+
+func (g guard[A]) recheck(ctx context.Context, id string, tx *usecasepgx.DbTx) error {
+	var updatedAt time.Time
+	err := tx.Inner().QueryRow(ctx,
+		`SELECT updated_at FROM `+g.table+` WHERE id = $1 FOR UPDATE`, id).Scan(&updatedAt)
+	if errors.Is(err, pgx.ErrNoRows) {
+		// Deleted since the load: nothing left for the tag to match.
+		return stale()
+	}
+	if err != nil {
+		return err
+	}
+	return Check(g.ifMatch, updatedAt)
+}
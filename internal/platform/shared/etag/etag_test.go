@@ -0,0 +1,68 @@
+package etag_test
+
+import (
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+func TestOf_MicrosecondPrecision(t *testing.T) {
+	at := time.Date(2026, 10, 15, 12, 0, 0, 123456789, time.UTC)
+	if etag.Of(at) != etag.Of(at.Truncate(time.Microsecond)) {
+		t.Error("tag should ignore sub-microsecond precision Postgres drops")
+	}
+	if etag.Of(at) == etag.Of(at.Add(time.Microsecond)) {
+		t.Error("tag should change with every write")
+	}
+}
+
+func TestMatches(t *testing.T) {
+	cur := etag.Of(time.Unix(1_700_000_000, 0))
+	for ifMatch, want := range map[string]bool{
+		"":                  true,
+		"*":                 true,
+		cur:                 true,
+		"W/" + cur:          true,
+		`"other", ` + cur:   true,
+		`"other"`:           false,
+		`"other", "again"`:  false,
+		cur[1 : len(cur)-1]: false, // unquoted
+	} {
+		if got := etag.Matches(ifMatch, cur); got != want {
+			t.Errorf("Matches(%q) = %v, want %v", ifMatch, got, want)
+		}
+	}
+}
+
+func TestCheck_StaleIsPreconditionFailed(t *testing.T) {
+	at := time.Unix(1_700_000_000, 0)
+	if err := etag.Check(etag.Of(at), at); err != nil {
+		t.Fatalf("current tag rejected: %v", err)
+	}
+	err := etag.Check(etag.Of(at), at.Add(time.Second))
+	if ue := usecase.AsError(err); ue == nil || ue.HTTPStatus() != 412 {
+		t.Errorf("err = %v, want a 412", err)
+	}
+}
+
+func TestCheckUpsert(t *testing.T) {
+	for _, tc := range []struct {
+		ifMatch, ifNoneMatch string
+		exists, ok           bool
+	}{
+		{"", "", false, true},
+		{"", "", true, true},
+		{`"x"`, "", false, false},
+		{"*", "", false, false},
+		{`"x"`, "", true, true}, // the update checks the tag itself
+		{"", "*", false, true},
+		{"", "*", true, false},
+	} {
+		err := etag.CheckUpsert(tc.ifMatch, tc.ifNoneMatch, tc.exists)
+		if (err == nil) != tc.ok {
+			t.Errorf("CheckUpsert(%q, %q, %v) = %v", tc.ifMatch, tc.ifNoneMatch, tc.exists, err)
+		}
+	}
+}
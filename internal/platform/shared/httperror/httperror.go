@@ -12,6 +12,7 @@ package httperror
 import (
 	"encoding/json"
 	"errors"
+	"net"
 	"net/http"
 
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
@@ -42,6 +43,8 @@ func Status(err error) int {
 		return http.StatusNotFound
 	case usecase.KindConflict:
 		return http.StatusConflict
+	case usecase.KindPrecondition:
+		return http.StatusPreconditionFailed
 	case usecase.KindBusinessRule:
 		// Rust maps business-rule violations to 409 (typical: uniqueness)
 		// or 422 (state transitions). Default to 409 since uniqueness
@@ -71,6 +74,24 @@ func Write(w http.ResponseWriter, err error) {
 	_ = json.NewEncoder(w).Encode(env)
 }
 
+// WriteDecodeError renders a request-body decode failure: 413 when the
+// body ran past the listener's size limit, 408 when it stopped arriving
+// before the read deadline, and 400 INVALID_JSON for anything else.
+func WriteDecodeError(w http.ResponseWriter, err error) {
+	var tooLarge *http.MaxBytesError
+	var netErr net.Error
+	status, env := http.StatusBadRequest, Envelope{Code: "INVALID_JSON", Message: err.Error()}
+	switch {
+	case errors.As(err, &tooLarge):
+		status, env = http.StatusRequestEntityTooLarge, Envelope{Code: "PAYLOAD_TOO_LARGE", Message: err.Error()}
+	case errors.As(err, &netErr) && netErr.Timeout():
+		status, env = http.StatusRequestTimeout, Envelope{Code: "REQUEST_TIMEOUT", Message: "Request body not received in time"}
+	}
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	_ = json.NewEncoder(w).Encode(env)
+}
+
 // Forbidden is a convenience for handler-layer permission rejections.
 func Forbidden(msg string) error {
 	return usecase.Authorization("FORBIDDEN", msg)
@@ -0,0 +1,275 @@
+// Package idempotency makes the admin create endpoints safe to retry. A
+// caller sends an Idempotency-Key header with a create; the first request
+// carrying the key runs and its response is recorded, and a retry with the
+// same key and body gets the recorded response back instead of creating a
+// second resource. Infrastructure-as-code tools lean on this: a create
+// whose response was lost to a timeout can be replayed blindly.
+//
+// Keys are scoped to the calling principal and the operation, and expire
+// after the store's TTL. Reusing a live key with a different body is a
+// 409, as is retrying while the first request is still running.
+package idempotency
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"log/slog"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+const (
+	// MaxKeyLength bounds the Idempotency-Key header.
+	MaxKeyLength = 255
+	// DefaultTTL is how long a recorded response is replayed.
+	DefaultTTL = 24 * time.Hour
+	// pendingTimeout is how long a claim may stay PENDING before another
+	// request with the key may take it over: its holder died between
+	// claiming the key and recording the response.
+	pendingTimeout = 5 * time.Minute
+)
+
+// Store records idempotent responses in iam_idempotency_keys.
+type Store struct {
+	pool *pgxpool.Pool
+	// enc seals recorded responses. Nil stores them as plain JSON, and
+	// sensitive ones not at all.
+	enc *encryption.Service
+	ttl time.Duration
+}
+
+// NewStore wires the store. A ttl of zero means DefaultTTL.
+func NewStore(pool *pgxpool.Pool, enc *encryption.Service, ttl time.Duration) *Store {
+	if ttl <= 0 {
+		ttl = DefaultTTL
+	}
+	return &Store{pool: pool, enc: enc, ttl: ttl}
+}
+
+// Request identifies one idempotent call.
+type Request struct {
+	Key   string // the Idempotency-Key header; empty skips idempotency
+	Scope string // the operation id
+	// Body is hashed so a key reused for a different request is refused.
+	Body any
+	// Sensitive marks a response carrying one-time secrets. It is only
+	// recorded when it can be sealed; otherwise a replay reports the
+	// resource as created but can't repeat the secrets.
+	Sensitive bool
+}
+
+// Do runs fn at most once per key. The first call claims the key, runs fn
+// and records its result; later calls with the key get the recorded result.
+// A failed fn releases the key so a corrected request can reuse it.
+func Do[T any](ctx context.Context, s *Store, req Request, fn func(context.Context) (T, error)) (T, error) {
+	var zero T
+	if s == nil || req.Key == "" {
+		return fn(ctx)
+	}
+	if len(req.Key) > MaxKeyLength {
+		return zero, usecase.Validation("IDEMPOTENCY_KEY_TOO_LONG",
+			fmt.Sprintf("Idempotency-Key must be at most %d characters", MaxKeyLength))
+	}
+	principal := ""
+	if ac := auth.FromContext(ctx); ac != nil {
+		principal = ac.PrincipalID
+	}
+	hash, err := hashBody(req.Body)
+	if err != nil {
+		return zero, usecase.Internal("IDEMPOTENCY", "could not hash request body", err)
+	}
+
+	claimed, err := s.claim(ctx, principal, req, hash)
+	if err != nil {
+		return zero, usecase.Internal("IDEMPOTENCY", "idempotency key claim failed", err)
+	}
+	if !claimed {
+		prior, err := s.load(ctx, principal, req)
+		if err != nil {
+			return zero, usecase.Internal("IDEMPOTENCY", "idempotency key lookup failed", err)
+		}
+		raw, err := s.replay(prior, hash)
+		if err != nil {
+			return zero, err
+		}
+		var out T
+		if err := json.Unmarshal(raw, &out); err != nil {
+			return zero, usecase.Internal("IDEMPOTENCY", "recorded response is unreadable", err)
+		}
+		return out, nil
+	}
+
+	out, err := fn(ctx)
+	// The request's outcome is settled; record it even if the caller has
+	// gone away, so its retry finds it.
+	bg := context.WithoutCancel(ctx)
+	if err != nil {
+		s.release(bg, principal, req)
+		return zero, err
+	}
+	s.record(bg, principal, req, out)
+	return out, nil
+}
+
+func hashBody(body any) (string, error) {
+	raw, err := json.Marshal(body)
+	if err != nil {
+		return "", err
+	}
+	sum := sha256.Sum256(raw)
+	return hex.EncodeToString(sum[:]), nil
+}
+
+// claim inserts a PENDING row for the key, taking over an expired row or
+// an abandoned claim. False means a live row already holds the key.
+func (s *Store) claim(ctx context.Context, principal string, req Request, hash string) (bool, error) {
+	now := time.Now().UTC()
+	var ok bool
+	err := s.pool.QueryRow(ctx,
+		`INSERT INTO iam_idempotency_keys (principal_id, scope, key, request_hash, expires_at)
+		VALUES ($1, $2, $3, $4, $5)
+		ON CONFLICT (principal_id, scope, key) DO UPDATE
+		SET request_hash = EXCLUDED.request_hash, status = 'PENDING', response = NULL,
+		    sealed = FALSE, created_at = NOW(), expires_at = EXCLUDED.expires_at
+		WHERE iam_idempotency_keys.expires_at < NOW()
+		   OR (iam_idempotency_keys.status = 'PENDING' AND iam_idempotency_keys.created_at < $6)
+		RETURNING TRUE`,
+		principal, req.Scope, req.Key, hash, now.Add(s.ttl), now.Add(-pendingTimeout)).Scan(&ok)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return false, nil
+	}
+	return ok, err
+}
+
+// prior is a key's recorded state.
+type prior struct {
+	hash     string
+	status   string
+	response *string
+	sealed   bool
+}
+
+func (s *Store) load(ctx context.Context, principal string, req Request) (*prior, error) {
+	var p prior
+	err := s.pool.QueryRow(ctx,
+		`SELECT request_hash, status, response, sealed FROM iam_idempotency_keys
+		WHERE principal_id = $1 AND scope = $2 AND key = $3`,
+		principal, req.Scope, req.Key).Scan(&p.hash, &p.status, &p.response, &p.sealed)
+	if errors.Is(err, pgx.ErrNoRows) {
+		// Released between our claim attempt and this read: the first
+		// request failed and the caller may simply retry.
+		return &prior{hash: "", status: "PENDING"}, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	return &p, nil
+}
+
+// replay decides what a repeated key gets back: the recorded response, or
+// the reason it can't have one.
+func (s *Store) replay(p *prior, hash string) ([]byte, error) {
+	switch {
+	case p.status == "PENDING":
+		return nil, usecase.Conflict("IDEMPOTENCY_KEY_IN_FLIGHT",
+			"A request with this Idempotency-Key is still in progress; retry shortly")
+	case p.hash != hash:
+		return nil, usecase.Conflict("IDEMPOTENCY_KEY_REUSED",
+			"This Idempotency-Key was already used with a different request body")
+	case p.response == nil:
+		return nil, usecase.Conflict("IDEMPOTENCY_REPLAY_UNAVAILABLE",
+			"The request with this Idempotency-Key succeeded, but its response carried secrets that were not retained")
+	case !p.sealed:
+		return []byte(*p.response), nil
+	case s.enc == nil:
+		return nil, usecase.Internal("IDEMPOTENCY", "recorded response is sealed but no application key is configured", nil)
+	}
+	plain, err := s.enc.Decrypt(*p.response)
+	if err != nil {
+		return nil, usecase.Internal("IDEMPOTENCY", "could not unseal recorded response", err)
+	}
+	return []byte(plain), nil
+}
+
+// seal prepares a response for storage: sealed when the store has a key,
+// plain JSON otherwise, and nothing at all for a sensitive response that
+// can't be sealed.
+func (s *Store) seal(out any, sensitive bool) (*string, bool, error) {
+	raw, err := json.Marshal(out)
+	if err != nil {
+		return nil, false, err
+	}
+	if s.enc == nil {
+		if sensitive {
+			return nil, false, nil
+		}
+		v := string(raw)
+		return &v, false, nil
+	}
+	v, err := s.enc.Encrypt(string(raw))
+	if err != nil {
+		return nil, false, err
+	}
+	return &v, true, nil
+}
+
+func (s *Store) record(ctx context.Context, principal string, req Request, out any) {
+	response, sealed, err := s.seal(out, req.Sensitive)
+	if err != nil {
+		// The key stays PENDING and is taken over after pendingTimeout.
+		slog.Warn("idempotency: could not seal response", "scope", req.Scope, "err", err)
+		return
+	}
+	if _, err := s.pool.Exec(ctx,
+		`UPDATE iam_idempotency_keys SET status = 'DONE', response = $4, sealed = $5
+		WHERE principal_id = $1 AND scope = $2 AND key = $3`,
+		principal, req.Scope, req.Key, response, sealed); err != nil {
+		slog.Warn("idempotency: could not record response", "scope", req.Scope, "err", err)
+	}
+}
+
+func (s *Store) release(ctx context.Context, principal string, req Request) {
+	if _, err := s.pool.Exec(ctx,
+		`DELETE FROM iam_idempotency_keys
+		WHERE principal_id = $1 AND scope = $2 AND key = $3 AND status = 'PENDING'`,
+		principal, req.Scope, req.Key); err != nil {
+		slog.Warn("idempotency: could not release key", "scope", req.Scope, "err", err)
+	}
+}
+
+// Prune deletes expired keys and returns how many it removed.
+func (s *Store) Prune(ctx context.Context) (int64, error) {
+	tag, err := s.pool.Exec(ctx, `DELETE FROM iam_idempotency_keys WHERE expires_at < NOW()`)
+	if err != nil {
+		return 0, err
+	}
+	return tag.RowsAffected(), nil
+}
+
+// Run prunes expired keys every interval until ctx is cancelled.
+func (s *Store) Run(ctx context.Context, interval time.Duration) {
+	tick := time.NewTicker(interval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-tick.C:
+			if n, err := s.Prune(ctx); err != nil {
+				slog.Warn("idempotency: prune failed", "err", err)
+			} else if n > 0 {
+				slog.Debug("idempotency: pruned expired keys", "count", n)
+			}
+		}
+	}
+}
@@ -0,0 +1,105 @@
+package idempotency
+
+import (
+	"context"
+	"testing"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+type created struct {
+	ID     string `json:"id"`
+	Secret string `json:"secret,omitempty"`
+}
+
+func code(err error) string {
+	if ue := usecase.AsError(err); ue != nil {
+		return ue.Code
+	}
+	return ""
+}
+
+func TestDo_WithoutKeyRunsDirectly(t *testing.T) {
+	calls := 0
+	fn := func(context.Context) (created, error) { calls++; return created{ID: "a"}, nil }
+	// A nil store and an empty key both bypass the table entirely.
+	for _, s := range []*Store{nil, {}} {
+		if _, err := Do(context.Background(), s, Request{Scope: "createThing"}, fn); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if calls != 2 {
+		t.Errorf("calls = %d, want 2", calls)
+	}
+}
+
+func TestDo_RejectsLongKeys(t *testing.T) {
+	long := make([]byte, MaxKeyLength+1)
+	for i := range long {
+		long[i] = 'k'
+	}
+	_, err := Do(context.Background(), &Store{}, Request{Key: string(long)}, func(context.Context) (created, error) {
+		t.Fatal("fn should not run")
+		return created{}, nil
+	})
+	if code(err) != "IDEMPOTENCY_KEY_TOO_LONG" {
+		t.Errorf("err = %v", err)
+	}
+}
+
+func TestHashBody_FollowsContent(t *testing.T) {
+	a, _ := hashBody(created{ID: "a"})
+	again, _ := hashBody(created{ID: "a"})
+	b, _ := hashBody(created{ID: "b"})
+	if a != again || a == b {
+		t.Errorf("hashes: %s %s %s", a, again, b)
+	}
+}
+
+func TestReplay(t *testing.T) {
+	s := &Store{}
+	body := `{"id":"a"}`
+	for name, tc := range map[string]struct {
+		p    prior
+		hash string
+		code string
+	}{
+		"recorded":        {prior{hash: "h", status: "DONE", response: &body}, "h", ""},
+		"in flight":       {prior{hash: "h", status: "PENDING"}, "h", "IDEMPOTENCY_KEY_IN_FLIGHT"},
+		"different body":  {prior{hash: "h", status: "DONE", response: &body}, "other", "IDEMPOTENCY_KEY_REUSED"},
+		"secrets dropped": {prior{hash: "h", status: "DONE"}, "h", "IDEMPOTENCY_REPLAY_UNAVAILABLE"},
+	} {
+		raw, err := s.replay(&tc.p, tc.hash)
+		if code(err) != tc.code {
+			t.Errorf("%s: err = %v, want %s", name, err, tc.code)
+		}
+		if tc.code == "" && string(raw) != body {
+			t.Errorf("%s: replayed %q", name, raw)
+		}
+	}
+}
+
+func TestSeal(t *testing.T) {
+	out := created{ID: "a", Secret: "s3cret"}
+
+	plain := &Store{}
+	if v, sealed, _ := plain.seal(out, false); v == nil || sealed {
+		t.Error("non-sensitive response should be kept as plain JSON without a key")
+	}
+	if v, _, _ := plain.seal(out, true); v != nil {
+		t.Error("sensitive response must not be kept unsealed")
+	}
+
+	key, _ := encryption.GenerateKey()
+	enc, _ := encryption.New(key)
+	s := &Store{enc: enc}
+	v, sealed, err := s.seal(out, true)
+	if err != nil || v == nil || !sealed {
+		t.Fatalf("seal = %v, %v, %v", v, sealed, err)
+	}
+	raw, err := s.replay(&prior{hash: "h", status: "DONE", response: v, sealed: true}, "h")
+	if err != nil || string(raw) != `{"id":"a","secret":"s3cret"}` {
+		t.Errorf("unsealed %q, %v", raw, err)
+	}
+}
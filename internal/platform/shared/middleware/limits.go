@@ -0,0 +1,132 @@
+package middleware
+
+import (
+	"encoding/json"
+	"errors"
+	"io"
+	"net"
+	"net/http"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+)
+
+// Rejection reasons reported to LimitsConfig.OnReject.
+const (
+	RejectBodyTooLarge = "body_too_large"
+	RejectBodyTimeout  = "body_timeout"
+)
+
+// BodyLimitGroup raises (or lowers) the body limit for every path under
+// Prefix.
+type BodyLimitGroup struct {
+	Prefix   string
+	MaxBytes int64
+}
+
+// LimitsConfig governs the Limits middleware.
+type LimitsConfig struct {
+	// MaxBodyBytes caps request bodies outside any group; 0 disables the
+	// cap.
+	MaxBodyBytes int64
+	// Groups override MaxBodyBytes by path prefix; the longest match wins.
+	Groups []BodyLimitGroup
+	// BodyReadTimeout bounds reading the body, from the moment the
+	// handler starts; 0 leaves only the server's ReadTimeout.
+	BodyReadTimeout time.Duration
+	// OnReject, when set, is told about each request refused (reason is a
+	// Reject* constant), for metrics.
+	OnReject func(reason string)
+}
+
+// MaxBodyBytesFor returns the body limit for path.
+func (c LimitsConfig) MaxBodyBytesFor(path string) int64 {
+	limit, matched := c.MaxBodyBytes, -1
+	for _, g := range c.Groups {
+		if strings.HasPrefix(path, g.Prefix) && len(g.Prefix) > matched {
+			limit, matched = g.MaxBytes, len(g.Prefix)
+		}
+	}
+	return limit
+}
+
+// Limits bounds request bodies in size and read time. A declared
+// Content-Length over the limit is refused with 413 before the handler
+// runs; a chunked body is cut off at the limit (the handler's read fails
+// with *http.MaxBytesError). A body still arriving after BodyReadTimeout
+// fails the read with a timeout, which huma answers with 408.
+//
+// Once the body is read — at once for a bodyless request — the read
+// deadline is lifted, so the server's ReadTimeout bounds only the request
+// itself and never cuts off a long-lived response such as an SSE stream.
+func Limits(cfg LimitsConfig) func(http.Handler) http.Handler {
+	reject := func(reason string) {
+		if cfg.OnReject != nil {
+			cfg.OnReject(reason)
+		}
+	}
+	return func(next http.Handler) http.Handler {
+		return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			// Deadlines are best effort: HTTP/2 streams and test recorders
+			// don't support them, and the server's own timeouts apply.
+			rc := http.NewResponseController(w)
+			if r.Body == nil || r.Body == http.NoBody {
+				_ = rc.SetReadDeadline(time.Time{})
+				next.ServeHTTP(w, r)
+				return
+			}
+			if limit := cfg.MaxBodyBytesFor(r.URL.Path); limit > 0 {
+				if r.ContentLength > limit {
+					reject(RejectBodyTooLarge)
+					writeEnvelope(w, http.StatusRequestEntityTooLarge, "PAYLOAD_TOO_LARGE",
+						"Request body exceeds "+strconv.FormatInt(limit, 10)+" bytes")
+					return
+				}
+				r.Body = http.MaxBytesReader(w, r.Body, limit)
+			}
+			if cfg.BodyReadTimeout > 0 {
+				_ = rc.SetReadDeadline(time.Now().Add(cfg.BodyReadTimeout))
+			}
+			r.Body = &countingBody{ReadCloser: r.Body, reject: reject, rc: rc}
+			next.ServeHTTP(w, r)
+		})
+	}
+}
+
+// countingBody reports the first limit a handler's read runs into and
+// lifts the read deadline when the body is exhausted.
+type countingBody struct {
+	io.ReadCloser
+	reject   func(string)
+	rc       *http.ResponseController
+	reported bool
+}
+
+func (b *countingBody) Read(p []byte) (int, error) {
+	n, err := b.ReadCloser.Read(p)
+	if errors.Is(err, io.EOF) {
+		_ = b.rc.SetReadDeadline(time.Time{})
+	} else if err != nil && !b.reported {
+		var tooLarge *http.MaxBytesError
+		var netErr net.Error
+		switch {
+		case errors.As(err, &tooLarge):
+			b.reported = true
+			b.reject(RejectBodyTooLarge)
+		case errors.As(err, &netErr) && netErr.Timeout():
+			b.reported = true
+			b.reject(RejectBodyTimeout)
+		}
+	}
+	return n, err
+}
+
+// writeEnvelope renders the platform's {error, message} envelope for
+// rejections made before any handler runs.
+func writeEnvelope(w http.ResponseWriter, status int, code, message string) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	_ = json.NewEncoder(w).Encode(httperror.Envelope{Code: code, Message: message})
+}
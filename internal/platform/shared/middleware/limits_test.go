@@ -0,0 +1,73 @@
+package middleware
+
+import (
+	"errors"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+func TestLimitsConfigMaxBodyBytesFor(t *testing.T) {
+	cfg := LimitsConfig{
+		MaxBodyBytes: 100,
+		Groups: []BodyLimitGroup{
+			{Prefix: "/api/events", MaxBytes: 200},
+			{Prefix: "/api/events/batch", MaxBytes: 1000},
+		},
+	}
+	for path, want := range map[string]int64{
+		"/api/clients":      100,
+		"/api/events":       200,
+		"/api/events/batch": 1000,
+	} {
+		if got := cfg.MaxBodyBytesFor(path); got != want {
+			t.Errorf("MaxBodyBytesFor(%q)=%d want %d", path, got, want)
+		}
+	}
+}
+
+func TestLimits(t *testing.T) {
+	var rejected []string
+	var readErr error
+	h := Limits(LimitsConfig{
+		MaxBodyBytes: 8,
+		Groups:       []BodyLimitGroup{{Prefix: "/batch", MaxBytes: 64}},
+		OnReject:     func(reason string) { rejected = append(rejected, reason) },
+	})(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		// Read twice: a handler retrying the read must not be counted twice.
+		_, readErr = io.ReadAll(r.Body)
+		_, _ = io.ReadAll(r.Body)
+		w.WriteHeader(http.StatusOK)
+	}))
+
+	call := func(path, body string, chunked bool) int {
+		r := httptest.NewRequest(http.MethodPost, path, strings.NewReader(body))
+		if chunked {
+			r.ContentLength = -1
+		}
+		w := httptest.NewRecorder()
+		readErr = nil
+		h.ServeHTTP(w, r)
+		return w.Code
+	}
+
+	if got := call("/api/clients", "small", false); got != http.StatusOK || readErr != nil {
+		t.Errorf("under limit: status=%d err=%v", got, readErr)
+	}
+	if got := call("/api/clients", strings.Repeat("x", 9), false); got != http.StatusRequestEntityTooLarge {
+		t.Errorf("declared length over limit: status=%d want 413", got)
+	}
+	if got := call("/batch", strings.Repeat("x", 9), false); got != http.StatusOK || readErr != nil {
+		t.Errorf("group limit: status=%d err=%v", got, readErr)
+	}
+	call("/api/clients", strings.Repeat("x", 9), true)
+	var tooLarge *http.MaxBytesError
+	if !errors.As(readErr, &tooLarge) {
+		t.Errorf("chunked over limit: read err=%v want *http.MaxBytesError", readErr)
+	}
+	if len(rejected) != 2 || rejected[0] != RejectBodyTooLarge || rejected[1] != RejectBodyTooLarge {
+		t.Errorf("rejections=%v want two %s", rejected, RejectBodyTooLarge)
+	}
+}
@@ -4,6 +4,7 @@ package middleware
 import (
 	"context"
 	"encoding/json"
+	"fmt"
 	"net/http"
 	"strings"
 
@@ -11,7 +12,10 @@ import (
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/logging"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/provider"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/remoteauth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/sessiontoken"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/netpolicy"
 )
 
 // CorrelationID extracts X-Correlation-ID from the inbound request or
@@ -45,6 +49,18 @@ type AuthConfig struct {
 	// 401 immediately. Set true to strip the token and proceed without
 	// an AuthContext (per-handler permission checks will then reject).
 	IgnoreInvalidTokens bool
+
+	// Remote validates bearer tokens from trusted external issuers
+	// (FC_AUTH_MODE=remote). Optional — nil accepts only tokens this
+	// platform minted. Tokens whose iss isn't a configured remote issuer
+	// keep the local path, so the SPA session and OAuth tokens still work.
+	Remote *remoteauth.Validator
+
+	// NetworkPolicy rejects tokens of a service account whose CIDR
+	// allowlist doesn't cover the caller's (trusted-proxy-resolved)
+	// address. Optional — nil applies no allowlists. Remote-issuer tokens
+	// have no platform principal and are not checked.
+	NetworkPolicy *netpolicy.Enforcer
 }
 
 // Authenticator validates the inbound Authorization: Bearer <jwt>,
@@ -68,7 +84,14 @@ func Authenticator(cfg AuthConfig) func(http.Handler) http.Handler {
 			token, fromCookie := extractToken(r)
 			switch {
 			case token != "":
-				ac, err := introspect(ctx, cfg.Provider, token, fromCookie)
+				var ac *auth.AuthContext
+				var err error
+				remote := !fromCookie && cfg.Remote != nil && cfg.Remote.Handles(token)
+				if remote {
+					ac, err = introspectRemote(ctx, cfg.Provider, cfg.Remote, token)
+				} else {
+					ac, err = introspect(ctx, cfg.Provider, token, fromCookie)
+				}
 				if err != nil {
 					// A stale / invalid fc_session cookie must not hard-fail the
 					// request: the browser replays it on every call — including
@@ -83,6 +106,17 @@ func Authenticator(cfg AuthConfig) func(http.Handler) http.Handler {
 					}
 					// Strip the token and proceed unauthenticated.
 				} else if ac != nil {
+					if ac.PasskeyEnrollmentOnly && !passkeyEnrollmentRoutes[r.Method+" "+r.URL.Path] {
+						// An enrollment-only session authenticates the passkey
+						// registration routes and nothing else; elsewhere the
+						// request proceeds unauthenticated, like a stale cookie.
+						break
+					}
+					if !remote && !cfg.NetworkPolicy.AllowPrincipal(ctx, ac.PrincipalID, cfg.NetworkPolicy.ClientIP(r)) {
+						writeEnvelope(w, http.StatusForbidden, "NETWORK_POLICY_DENIED",
+							"these credentials may not be used from this network")
+						return
+					}
 					ctx = auth.WithContext(ctx, ac)
 					ctx = logging.WithPrincipalID(ctx, ac.PrincipalID)
 				}
@@ -98,6 +132,16 @@ func Authenticator(cfg AuthConfig) func(http.Handler) http.Handler {
 	}
 }
 
+// passkeyEnrollmentRoutes are the routes an enrollment-only session
+// authenticates: registering a passkey, listing the result, and /auth/me
+// so the SPA can tell it is in the enrollment step.
+var passkeyEnrollmentRoutes = map[string]bool{
+	"POST /auth/webauthn/register/begin":    true,
+	"POST /auth/webauthn/register/complete": true,
+	"GET /auth/webauthn/credentials":        true,
+	"GET /auth/me":                          true,
+}
+
 // SessionCookieName is the cookie carrying the platform's JWT for
 // browser sessions. The OIDC bridge / interactive authorize flow sets
 // this on success; the Vue frontend round-trips it transparently. Same
@@ -144,6 +188,14 @@ func introspect(ctx context.Context, p *provider.Provider, token string, fromCoo
 	if c == nil {
 		return nil, nil
 	}
+	switch c.Purpose {
+	case "":
+	case sessiontoken.PurposePasskeyEnrollment:
+		// Identity only — no authorization data to resolve.
+		return &auth.AuthContext{PrincipalID: c.Subject, Email: c.Email, PasskeyEnrollmentOnly: true}, nil
+	default:
+		return nil, fmt.Errorf("session purpose %q not accepted", c.Purpose)
+	}
 
 	// Session cookies (the SPA) carry only identity (subject). Resolve the
 	// mutable authorization data — scope, roles, clients, applications,
@@ -192,6 +244,31 @@ func introspect(ctx context.Context, p *provider.Provider, token string, fromCoo
 	}, nil
 }
 
+// introspectRemote validates an external issuer's token and projects it onto
+// an AuthContext. The issuer's role mapping has already translated its roles
+// into platform role names; permissions are flattened from those exactly as
+// for a locally-minted bearer. Remote callers have no platform principal, so
+// PrincipalID is the issuer's subject.
+func introspectRemote(ctx context.Context, p *provider.Provider, v *remoteauth.Validator, token string) (*auth.AuthContext, error) {
+	id, err := v.Validate(ctx, token)
+	if err != nil {
+		return nil, err
+	}
+	perms, err := p.FlattenPermissions(ctx, id.Roles)
+	if err != nil {
+		return nil, err
+	}
+	return &auth.AuthContext{
+		PrincipalID:     id.Subject,
+		Scope:           auth.Scope(id.Scope),
+		Email:           id.Email,
+		Clients:         id.Clients,
+		Roles:           id.Roles,
+		AllApplications: id.AllApplications,
+		Permissions:     perms,
+	}, nil
+}
+
 // stringSlice coerces a claim into []string — kept here for any future
 // adapter that needs it. Tokens we mint already arrive as []string.
 func stringSlice(v any) []string {
@@ -0,0 +1,746 @@
+package middleware
+
+import (
+	"net/http"
+	"sort"
+	"strings"
+	"sync"
+
+	"github.com/go-chi/chi/v5"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+// Access is the kind of check a route requires.
+type Access string
+
+const (
+	// AccessPublic routes are served to anyone, signed in or not.
+	AccessPublic Access = "PUBLIC"
+	// AccessAuthenticated routes need a principal; what it may see is
+	// decided per row by the handler (own profile, granted config).
+	AccessAuthenticated Access = "AUTHENTICATED"
+	// AccessPermission routes need an anchor or any one of Permissions.
+	AccessPermission Access = "PERMISSION"
+	// AccessAnchor routes are platform operations only an anchor may run.
+	AccessAnchor Access = "ANCHOR"
+	// AccessAdmin routes need an anchor or the platform:*:*:* wildcard.
+	AccessAdmin Access = "ADMIN"
+)
+
+// Requirement is what a route demands of the caller before its handler
+// runs. Handlers keep their own checks for row-level scope (client
+// access, application access); this is the coarse gate in front of them.
+type Requirement struct {
+	Access      Access   `json:"access"`
+	Permissions []string `json:"permissions,omitempty"`
+}
+
+// Check returns nil if a satisfies the requirement. Failures are the
+// same usecase.Authorization errors the auth.Can* helpers return.
+func (q Requirement) Check(a *auth.AuthContext) error {
+	switch q.Access {
+	case AccessPublic:
+		return nil
+	case AccessAnchor:
+		return auth.RequireAnchor(a)
+	case AccessAdmin:
+		return auth.IsAdmin(a)
+	}
+	if a == nil {
+		return usecase.Authorization("UNAUTHENTICATED", "authentication required")
+	}
+	if q.Access == AccessAuthenticated || a.IsAnchor() {
+		return nil
+	}
+	for _, p := range q.Permissions {
+		if a.HasPermission(p) {
+			return nil
+		}
+	}
+	if len(q.Permissions) == 1 {
+		return usecase.Authorization("PERMISSION_REQUIRED", "permission required: "+q.Permissions[0])
+	}
+	return usecase.Authorization("PERMISSION_REQUIRED", "one of: "+strings.Join(q.Permissions, ", "))
+}
+
+// RouteRequirement pairs a route pattern with its Requirement.
+type RouteRequirement struct {
+	Method      string
+	Path        string
+	Requirement Requirement
+}
+
+// Permission codes the registry refers to. Byte-identical to
+// seed/permissions.go, like the ones in shared/auth.
+const (
+	permApplicationView   = "platform:admin:application:view"
+	permApplicationCreate = "platform:admin:application:create"
+	permApplicationUpdate = "platform:admin:application:update"
+	permApplicationDelete = "platform:admin:application:delete"
+
+	permAppOpenApiSync   = "platform:developer:application-openapi:sync"
+	permAppOpenApiManage = "platform:developer:application-openapi:manage"
+
+	permAuditLogView = "platform:admin:audit-log:view"
+
+	permConnectionView   = "platform:messaging:connection:view"
+	permConnectionCreate = "platform:messaging:connection:create"
+	permConnectionUpdate = "platform:messaging:connection:update"
+	permConnectionDelete = "platform:messaging:connection:delete"
+
+	permDispatchJobView    = "platform:messaging:dispatch-job:view"
+	permDispatchJobViewRaw = "platform:messaging:dispatch-job:view-raw"
+
+	permLegacyDispatchJobsWrite = "WRITE_DISPATCH_JOBS"
+
+	permDispatchPoolView   = "platform:messaging:dispatch-pool:view"
+	permDispatchPoolCreate = "platform:messaging:dispatch-pool:create"
+	permDispatchPoolUpdate = "platform:messaging:dispatch-pool:update"
+	permDispatchPoolDelete = "platform:messaging:dispatch-pool:delete"
+	permDispatchPoolSync   = "platform:messaging:dispatch-pool:sync"
+	permDispatchPoolManage = "platform:messaging:dispatch-pool:manage"
+
+	permEventView    = "platform:messaging:event:view"
+	permEventViewRaw = "platform:messaging:event:view-raw"
+
+	permBatchEventsWrite = "platform:messaging:batch:events-write"
+
+	permEventTypeView   = "platform:messaging:event-type:view"
+	permEventTypeCreate = "platform:messaging:event-type:create"
+	permEventTypeUpdate = "platform:messaging:event-type:update"
+	permEventTypeDelete = "platform:messaging:event-type:delete"
+	permEventTypeSync   = "platform:messaging:event-type:sync"
+	permEventTypeManage = "platform:messaging:event-type:manage"
+
+	permAppSvcEventTypeCreate = "platform:application-service:event-type:create"
+	permAppSvcEventTypeUpdate = "platform:application-service:event-type:update"
+	permAppSvcEventTypeDelete = "platform:application-service:event-type:delete"
+
+	permProcessView   = "platform:messaging:process:view"
+	permProcessCreate = "platform:messaging:process:create"
+	permProcessUpdate = "platform:messaging:process:update"
+	permProcessDelete = "platform:messaging:process:delete"
+	permProcessSync   = "platform:messaging:process:sync"
+
+	permAppSvcProcessSync = "platform:application-service:process:sync"
+
+	permRoleView   = "platform:iam:role:view"
+	permRoleCreate = "platform:iam:role:create"
+	permRoleUpdate = "platform:iam:role:update"
+	permRoleDelete = "platform:iam:role:delete"
+	permRoleManage = "platform:iam:role:manage"
+
+	permAppSvcRoleCreate = "platform:application-service:role:create"
+	permAppSvcRoleUpdate = "platform:application-service:role:update"
+	permAppSvcRoleDelete = "platform:application-service:role:delete"
+
+	permScheduledJobView   = "platform:messaging:scheduled-job:view"
+	permScheduledJobCreate = "platform:messaging:scheduled-job:create"
+	permScheduledJobUpdate = "platform:messaging:scheduled-job:update"
+	permScheduledJobDelete = "platform:messaging:scheduled-job:delete"
+	permScheduledJobFire   = "platform:messaging:scheduled-job:fire"
+
+	permAppSvcScheduledJobSync = "platform:application-service:scheduled-job:sync"
+
+	permScheduledJobSync   = "platform:messaging:scheduled-job:sync"
+	permScheduledJobManage = "platform:messaging:scheduled-job:manage"
+
+	permServiceAccountView   = "platform:iam:service-account:view"
+	permServiceAccountCreate = "platform:iam:service-account:create"
+	permServiceAccountUpdate = "platform:iam:service-account:update"
+	permServiceAccountDelete = "platform:iam:service-account:delete"
+
+	permSubscriptionView   = "platform:messaging:subscription:view"
+	permSubscriptionUpdate = "platform:messaging:subscription:update"
+	permSubscriptionCreate = "platform:messaging:subscription:create"
+	permSubscriptionDelete = "platform:messaging:subscription:delete"
+	permSubscriptionSync   = "platform:messaging:subscription:sync"
+	permSubscriptionManage = "platform:messaging:subscription:manage"
+
+	permAppSvcSubscriptionCreate = "platform:application-service:subscription:create"
+	permAppSvcSubscriptionUpdate = "platform:application-service:subscription:update"
+	permAppSvcSubscriptionDelete = "platform:application-service:subscription:delete"
+
+	permUserView        = "platform:iam:user:view"
+	permUserCreate      = "platform:iam:user:create"
+	permUserUpdate      = "platform:iam:user:update"
+	permUserDelete      = "platform:iam:user:delete"
+	permUserManage      = "platform:iam:user:manage"
+	permUserAssignRoles = "platform:iam:user:assign-roles"
+
+	permDeveloperAPICredentialManage = "platform:developer:api-credential:manage"
+)
+
+func perm(p ...string) Requirement { return Requirement{Access: AccessPermission, Permissions: p} }
+
+var (
+	public        = Requirement{Access: AccessPublic}
+	authenticated = Requirement{Access: AccessAuthenticated}
+	anchorOnly    = Requirement{Access: AccessAnchor}
+	adminOnly     = Requirement{Access: AccessAdmin}
+)
+
+// Permission sets. A composite set mirrors the auth.Can* helper its
+// handler calls (CanWriteX accepts any of create/update/delete,
+// CanSyncX the SDK service-account grants), so the gate never refuses a
+// caller the handler would admit.
+var (
+	applicationRead        = perm(permApplicationView)
+	applicationWrite       = perm(permApplicationCreate, permApplicationUpdate, permApplicationDelete)
+	applicationDelete      = perm(permApplicationDelete)
+	applicationOpenAPISync = perm(permAppOpenApiSync, permAppOpenApiManage)
+
+	auditLogRead = perm(permAuditLogView)
+
+	connectionRead   = perm(permConnectionView)
+	connectionCreate = perm(permConnectionCreate)
+	connectionUpdate = perm(permConnectionUpdate)
+	connectionDelete = perm(permConnectionDelete)
+
+	dispatchJobRead    = perm(permDispatchJobView)
+	dispatchJobReadRaw = perm(permDispatchJobViewRaw)
+	dispatchJobIngest  = perm(permLegacyDispatchJobsWrite)
+
+	dispatchPoolRead   = perm(permDispatchPoolView)
+	dispatchPoolWrite  = perm(permDispatchPoolCreate, permDispatchPoolUpdate, permDispatchPoolDelete)
+	dispatchPoolDelete = perm(permDispatchPoolDelete)
+	dispatchPoolSync   = perm(permDispatchPoolSync, permDispatchPoolManage)
+
+	eventRead    = perm(permEventView)
+	eventReadRaw = perm(permEventViewRaw)
+	eventWrite   = perm(permBatchEventsWrite)
+
+	eventTypeRead   = perm(permEventTypeView)
+	eventTypeCreate = perm(permEventTypeCreate)
+	eventTypeUpdate = perm(permEventTypeUpdate)
+	eventTypeWrite  = perm(permEventTypeCreate, permEventTypeUpdate, permEventTypeDelete)
+	eventTypeDelete = perm(permEventTypeDelete)
+	eventTypeSync   = perm(permEventTypeSync, permEventTypeManage, permAppSvcEventTypeCreate, permAppSvcEventTypeUpdate, permAppSvcEventTypeDelete)
+
+	processRead   = perm(permProcessView)
+	processWrite  = perm(permProcessCreate, permProcessUpdate, permProcessDelete)
+	processDelete = perm(permProcessDelete)
+	processSync   = perm(permProcessSync, permAppSvcProcessSync)
+
+	roleRead   = perm(permRoleView)
+	roleWrite  = perm(permRoleCreate, permRoleUpdate, permRoleDelete)
+	roleDelete = perm(permRoleDelete)
+	roleSync   = perm(permRoleManage, permRoleCreate, permRoleUpdate, permRoleDelete, permAppSvcRoleCreate, permAppSvcRoleUpdate, permAppSvcRoleDelete)
+
+	scheduledJobRead   = perm(permScheduledJobView)
+	scheduledJobWrite  = perm(permScheduledJobCreate, permScheduledJobUpdate, permScheduledJobDelete)
+	scheduledJobDelete = perm(permScheduledJobDelete)
+	scheduledJobFire   = perm(permScheduledJobFire)
+	scheduledJobSync   = perm(permAppSvcScheduledJobSync, permScheduledJobSync, permScheduledJobManage)
+
+	serviceAccountRead   = perm(permServiceAccountView)
+	serviceAccountWrite  = perm(permServiceAccountCreate, permServiceAccountUpdate, permServiceAccountDelete)
+	serviceAccountDelete = perm(permServiceAccountDelete)
+
+	subscriptionRead   = perm(permSubscriptionView)
+	subscriptionUpdate = perm(permSubscriptionUpdate)
+	subscriptionWrite  = perm(permSubscriptionCreate, permSubscriptionUpdate, permSubscriptionDelete)
+	subscriptionDelete = perm(permSubscriptionDelete)
+	subscriptionSync   = perm(permSubscriptionSync, permSubscriptionManage, permAppSvcSubscriptionCreate, permAppSvcSubscriptionUpdate, permAppSvcSubscriptionDelete)
+
+	userRead            = perm(permUserView)
+	userWrite           = perm(permUserCreate, permUserUpdate, permUserDelete)
+	userDelete          = perm(permUserDelete)
+	userSync            = perm(permUserManage, permUserCreate, permUserUpdate, permUserDelete, permUserAssignRoles)
+	developerCredential = perm(permDeveloperAPICredentialManage)
+)
+
+// routeRequirements is the registry: every /api and /bff route served
+// behind the Authenticator, keyed by the pattern it registers with.
+// Adding a route without an entry here fails TestRoutePermissions in
+// internal/server, and the route answers 403 until it has one.
+var routeRequirements = []RouteRequirement{
+	{http.MethodGet, "/api/admin/export", anchorOnly},
+	{http.MethodPost, "/api/admin/import", anchorOnly},
+	{http.MethodGet, "/api/admin/permissions/matrix", roleRead},
+	{http.MethodGet, "/api/admin/privacy/erasure", anchorOnly},
+	{http.MethodPost, "/api/admin/privacy/erasure", anchorOnly},
+	{http.MethodGet, "/api/admin/privacy/erasure/{id}", anchorOnly},
+	{http.MethodGet, "/api/admin/reconciler", anchorOnly},
+	{http.MethodPost, "/api/admin/reconciler/run", anchorOnly},
+	{http.MethodGet, "/api/admin/stream-dlq", anchorOnly},
+	{http.MethodDelete, "/api/admin/stream-dlq/{id}", anchorOnly},
+	{http.MethodPost, "/api/admin/stream-dlq/{id}/reprocess", anchorOnly},
+
+	{http.MethodGet, "/api/anchor-domains", anchorOnly},
+	{http.MethodPost, "/api/anchor-domains", anchorOnly},
+	{http.MethodPut, "/api/anchor-domains/{id}", anchorOnly},
+	{http.MethodDelete, "/api/anchor-domains/{id}", anchorOnly},
+
+	{http.MethodGet, "/api/anomalies", anchorOnly},
+
+	{http.MethodGet, "/api/anomaly-settings", anchorOnly},
+	{http.MethodPut, "/api/anomaly-settings/{entityKind}/{entityKey}", anchorOnly},
+
+	{http.MethodGet, "/api/applications", applicationRead},
+	{http.MethodPost, "/api/applications", applicationWrite},
+	{http.MethodGet, "/api/applications/by-code/{code}", applicationRead},
+	{http.MethodGet, "/api/applications/by-id/{id}/roles", applicationRead},
+	{http.MethodGet, "/api/applications/producers", applicationRead},
+	{http.MethodPost, "/api/applications/{appCode}/dispatch-pools/sync", dispatchPoolSync},
+	{http.MethodPost, "/api/applications/{appCode}/event-types/sync", eventTypeSync},
+	{http.MethodPost, "/api/applications/{appCode}/openapi/sync", applicationOpenAPISync},
+	{http.MethodPost, "/api/applications/{appCode}/principals/sync", userSync},
+	{http.MethodPost, "/api/applications/{appCode}/processes/sync", processSync},
+	{http.MethodPost, "/api/applications/{appCode}/roles/sync", roleSync},
+	{http.MethodPost, "/api/applications/{appCode}/scheduled-jobs/sync", scheduledJobSync},
+	{http.MethodPost, "/api/applications/{appCode}/subscriptions/sync", subscriptionSync},
+	{http.MethodGet, "/api/applications/{id}", applicationRead},
+	{http.MethodPut, "/api/applications/{id}", applicationWrite},
+	{http.MethodDelete, "/api/applications/{id}", applicationDelete},
+	{http.MethodPost, "/api/applications/{id}/activate", applicationWrite},
+	{http.MethodGet, "/api/applications/{id}/clients", applicationRead},
+	{http.MethodGet, "/api/applications/{id}/clients/{clientId}", applicationRead},
+	{http.MethodPost, "/api/applications/{id}/clients/{clientId}/disable", anchorOnly},
+	{http.MethodPost, "/api/applications/{id}/clients/{clientId}/enable", anchorOnly},
+	{http.MethodPost, "/api/applications/{id}/deactivate", applicationWrite},
+	{http.MethodGet, "/api/applications/{id}/producers", applicationRead},
+	{http.MethodPost, "/api/applications/{id}/producers", applicationWrite},
+	{http.MethodDelete, "/api/applications/{id}/producers/{bindingId}", applicationWrite},
+	{http.MethodPost, "/api/applications/{id}/provision-login-client", anchorOnly},
+	{http.MethodPost, "/api/applications/{id}/provision-service-account", anchorOnly},
+	{http.MethodPost, "/api/applications/{id}/service-account", anchorOnly},
+
+	{http.MethodGet, "/api/archive/files", anchorOnly},
+	{http.MethodGet, "/api/archive/replays", anchorOnly},
+	{http.MethodPost, "/api/archive/replays", anchorOnly},
+	{http.MethodGet, "/api/archive/replays/{id}", anchorOnly},
+	{http.MethodPost, "/api/archive/replays/{id}/cancel", anchorOnly},
+	{http.MethodGet, "/api/archive/status", anchorOnly},
+
+	{http.MethodGet, "/api/audit-logs", auditLogRead},
+	{http.MethodGet, "/api/audit-logs/application-ids", auditLogRead},
+	{http.MethodPost, "/api/audit-logs/batch", authenticated},
+	{http.MethodGet, "/api/audit-logs/client-ids", auditLogRead},
+	{http.MethodGet, "/api/audit-logs/entity-types", auditLogRead},
+	{http.MethodGet, "/api/audit-logs/entity/{entityType}/{entityId}", auditLogRead},
+	{http.MethodGet, "/api/audit-logs/operations", auditLogRead},
+	{http.MethodGet, "/api/audit-logs/principal/{principalId}", auditLogRead},
+	{http.MethodGet, "/api/audit-logs/recent", auditLogRead},
+	{http.MethodGet, "/api/audit-logs/{id}", auditLogRead},
+
+	{http.MethodGet, "/api/auth-configs", anchorOnly},
+	{http.MethodPost, "/api/auth-configs", anchorOnly},
+	{http.MethodPut, "/api/auth-configs/{id}", anchorOnly},
+	{http.MethodDelete, "/api/auth-configs/{id}", anchorOnly},
+
+	{http.MethodGet, "/api/clients", anchorOnly},
+	{http.MethodPost, "/api/clients", anchorOnly},
+	{http.MethodGet, "/api/clients/by-identifier/{identifier}", anchorOnly},
+	{http.MethodGet, "/api/clients/search", anchorOnly},
+	{http.MethodPost, "/api/clients/search", anchorOnly},
+	{http.MethodGet, "/api/clients/{id}", anchorOnly},
+	{http.MethodPut, "/api/clients/{id}", anchorOnly},
+	{http.MethodDelete, "/api/clients/{id}", anchorOnly},
+	{http.MethodPost, "/api/clients/{id}/activate", anchorOnly},
+	{http.MethodGet, "/api/clients/{id}/applications", authenticated},
+	{http.MethodPut, "/api/clients/{id}/applications", anchorOnly},
+	{http.MethodPost, "/api/clients/{id}/applications/{applicationId}/disable", anchorOnly},
+	{http.MethodPost, "/api/clients/{id}/applications/{applicationId}/enable", anchorOnly},
+	{http.MethodPost, "/api/clients/{id}/deactivate", anchorOnly},
+	{http.MethodPost, "/api/clients/{id}/notes", anchorOnly},
+	{http.MethodGet, "/api/clients/{id}/signing-keys", connectionRead},
+	{http.MethodPost, "/api/clients/{id}/signing-keys", connectionUpdate},
+	{http.MethodPost, "/api/clients/{id}/signing-keys/{signingKeyId}/expire", connectionUpdate},
+	{http.MethodPost, "/api/clients/{id}/suspend", anchorOnly},
+
+	{http.MethodGet, "/api/config/{app}/{section}/{property}", authenticated},
+	{http.MethodPut, "/api/config/{app}/{section}/{property}", authenticated},
+	{http.MethodDelete, "/api/config/{app}/{section}/{property}", authenticated},
+
+	{http.MethodGet, "/api/connections", connectionRead},
+	{http.MethodPost, "/api/connections", connectionCreate},
+	{http.MethodGet, "/api/connections/{id}", connectionRead},
+	{http.MethodPut, "/api/connections/{id}", connectionUpdate},
+	{http.MethodDelete, "/api/connections/{id}", connectionDelete},
+	{http.MethodPost, "/api/connections/{id}/activate", connectionUpdate},
+	{http.MethodPost, "/api/connections/{id}/pause", connectionUpdate},
+
+	{http.MethodGet, "/api/consume/{subscriptionId}", dispatchJobRead},
+	{http.MethodPost, "/api/consume/{subscriptionId}/ack", dispatchJobRead},
+	{http.MethodPost, "/api/consume/{subscriptionId}/nack", dispatchJobRead},
+	{http.MethodGet, "/api/consume/{subscriptionId}/stream", dispatchJobRead},
+
+	{http.MethodGet, "/api/dispatch-jobs", dispatchJobRead},
+	{http.MethodPost, "/api/dispatch-jobs", dispatchJobIngest},
+	{http.MethodPost, "/api/dispatch-jobs/batch", dispatchJobIngest},
+	{http.MethodGet, "/api/dispatch-jobs/by-event/{eventId}", dispatchJobRead},
+	{http.MethodPost, "/api/dispatch-jobs/cancel", dispatchJobRead},
+	{http.MethodGet, "/api/dispatch-jobs/event/{eventId}", dispatchJobRead},
+	{http.MethodGet, "/api/dispatch-jobs/filter-options", dispatchJobRead},
+	{http.MethodGet, "/api/dispatch-jobs/list-raw", dispatchJobReadRaw},
+	{http.MethodGet, "/api/dispatch-jobs/raw", dispatchJobReadRaw},
+	{http.MethodPost, "/api/dispatch-jobs/requeue", dispatchJobRead},
+	{http.MethodGet, "/api/dispatch-jobs/{id}", dispatchJobRead},
+	{http.MethodPost, "/api/dispatch-jobs/{id}/ack", dispatchJobRead},
+	{http.MethodGet, "/api/dispatch-jobs/{id}/attempts", dispatchJobRead},
+	{http.MethodPost, "/api/dispatch-jobs/{id}/nack", dispatchJobRead},
+	{http.MethodGet, "/api/dispatch-jobs/{id}/raw", dispatchJobReadRaw},
+
+	{http.MethodGet, "/api/dispatch-pools", dispatchPoolRead},
+	{http.MethodPost, "/api/dispatch-pools", dispatchPoolWrite},
+	{http.MethodPut, "/api/dispatch-pools/by-code/{code}", dispatchPoolWrite},
+	{http.MethodGet, "/api/dispatch-pools/{id}", dispatchPoolRead},
+	{http.MethodPut, "/api/dispatch-pools/{id}", dispatchPoolWrite},
+	{http.MethodDelete, "/api/dispatch-pools/{id}", dispatchPoolDelete},
+	{http.MethodPost, "/api/dispatch-pools/{id}/activate", dispatchPoolWrite},
+	{http.MethodPost, "/api/dispatch-pools/{id}/archive", dispatchPoolWrite},
+	{http.MethodPost, "/api/dispatch-pools/{id}/suspend", dispatchPoolWrite},
+
+	{http.MethodGet, "/api/email-domain-mappings", anchorOnly},
+	{http.MethodPost, "/api/email-domain-mappings", anchorOnly},
+	{http.MethodGet, "/api/email-domain-mappings/by-domain/{domain}", anchorOnly},
+	{http.MethodGet, "/api/email-domain-mappings/lookup", public},
+	{http.MethodGet, "/api/email-domain-mappings/{id}", anchorOnly},
+	{http.MethodPut, "/api/email-domain-mappings/{id}", anchorOnly},
+	{http.MethodDelete, "/api/email-domain-mappings/{id}", anchorOnly},
+
+	{http.MethodGet, "/api/event-types", eventTypeRead},
+	{http.MethodPost, "/api/event-types", eventTypeWrite},
+	{http.MethodGet, "/api/event-types/by-code/{code}", eventTypeRead},
+	{http.MethodPut, "/api/event-types/by-code/{code}", eventTypeWrite},
+	{http.MethodGet, "/api/event-types/{id}", eventTypeRead},
+	{http.MethodPut, "/api/event-types/{id}", eventTypeWrite},
+	{http.MethodDelete, "/api/event-types/{id}", eventTypeDelete},
+	{http.MethodPut, "/api/event-types/{id}/data-policy", eventTypeWrite},
+	{http.MethodPut, "/api/event-types/{id}/schema-registry", eventTypeWrite},
+	{http.MethodPut, "/api/event-types/{id}/lifecycle", eventTypeWrite},
+	{http.MethodPost, "/api/event-types/{id}/schemas", eventTypeWrite},
+	{http.MethodPost, "/api/event-types/{id}/versions", eventTypeWrite},
+
+	{http.MethodGet, "/api/events", eventRead},
+	{http.MethodPost, "/api/events", eventWrite},
+	{http.MethodPost, "/api/events/batch", eventWrite},
+	{http.MethodGet, "/api/events/filter-options", eventRead},
+	{http.MethodGet, "/api/events/list-raw", eventReadRaw},
+	{http.MethodGet, "/api/events/raw", eventReadRaw},
+	{http.MethodGet, "/api/events/{id}", eventRead},
+
+	{http.MethodGet, "/api/federation/feed", anchorOnly},
+	{http.MethodGet, "/api/federation/peers", anchorOnly},
+	{http.MethodPost, "/api/federation/peers", anchorOnly},
+	{http.MethodGet, "/api/federation/peers/{id}", anchorOnly},
+	{http.MethodPut, "/api/federation/peers/{id}", anchorOnly},
+	{http.MethodDelete, "/api/federation/peers/{id}", anchorOnly},
+
+	{http.MethodGet, "/api/identity-providers", anchorOnly},
+	{http.MethodPost, "/api/identity-providers", anchorOnly},
+	{http.MethodGet, "/api/identity-providers/{id}", anchorOnly},
+	{http.MethodPut, "/api/identity-providers/{id}", anchorOnly},
+	{http.MethodDelete, "/api/identity-providers/{id}", anchorOnly},
+
+	{http.MethodGet, "/api/idp-role-mappings", anchorOnly},
+	{http.MethodPost, "/api/idp-role-mappings", anchorOnly},
+	{http.MethodDelete, "/api/idp-role-mappings/{id}", anchorOnly},
+
+	{http.MethodGet, "/api/kill-switches", anchorOnly},
+	{http.MethodPost, "/api/kill-switches", anchorOnly},
+	{http.MethodDelete, "/api/kill-switches/{id}", anchorOnly},
+
+	{http.MethodGet, "/api/lockouts", anchorOnly},
+	{http.MethodDelete, "/api/lockouts/{kind}/{key}", anchorOnly},
+
+	{http.MethodGet, "/api/login-attempts", anchorOnly},
+
+	{http.MethodGet, "/api/me", authenticated},
+	{http.MethodGet, "/api/me/applications", authenticated},
+	{http.MethodGet, "/api/me/clients", authenticated},
+	{http.MethodGet, "/api/me/clients/{clientId}", authenticated},
+	{http.MethodGet, "/api/me/clients/{clientId}/applications", authenticated},
+
+	{http.MethodGet, "/api/oauth-clients", anchorOnly},
+	{http.MethodPost, "/api/oauth-clients", anchorOnly},
+	{http.MethodGet, "/api/oauth-clients/by-client-id/{clientId}", anchorOnly},
+	{http.MethodGet, "/api/oauth-clients/{id}", anchorOnly},
+	{http.MethodPut, "/api/oauth-clients/{id}", anchorOnly},
+	{http.MethodDelete, "/api/oauth-clients/{id}", anchorOnly},
+	{http.MethodPost, "/api/oauth-clients/{id}/activate", anchorOnly},
+	{http.MethodPost, "/api/oauth-clients/{id}/deactivate", anchorOnly},
+	{http.MethodPost, "/api/oauth-clients/{id}/regenerate-secret", anchorOnly},
+	{http.MethodPost, "/api/oauth-clients/{id}/rotate-secret", anchorOnly},
+
+	{http.MethodGet, "/api/platform/cors", anchorOnly},
+	{http.MethodPost, "/api/platform/cors", anchorOnly},
+	{http.MethodGet, "/api/platform/cors/allowed", public},
+	{http.MethodGet, "/api/platform/cors/{id}", anchorOnly},
+	{http.MethodDelete, "/api/platform/cors/{id}", anchorOnly},
+
+	{http.MethodDelete, "/api/platform-config/access/{id}", anchorOnly},
+	{http.MethodGet, "/api/platform-config/{app}", authenticated},
+	{http.MethodGet, "/api/platform-config/{app}/access", anchorOnly},
+	{http.MethodPost, "/api/platform-config/{app}/access", anchorOnly},
+
+	{http.MethodGet, "/api/principals", userRead},
+	{http.MethodPost, "/api/principals", userWrite},
+	{http.MethodPost, "/api/principals/bulk-import", userWrite},
+	{http.MethodGet, "/api/principals/check-email-domain", userRead},
+	{http.MethodGet, "/api/principals/developer-users", userRead},
+	{http.MethodPost, "/api/principals/sync", userSync},
+	{http.MethodPost, "/api/principals/users", userWrite},
+	{http.MethodGet, "/api/principals/{id}", userRead},
+	{http.MethodPut, "/api/principals/{id}", userWrite},
+	{http.MethodDelete, "/api/principals/{id}", userDelete},
+	{http.MethodPost, "/api/principals/{id}/activate", userWrite},
+	{http.MethodGet, "/api/principals/{id}/application-access", userRead},
+	{http.MethodPut, "/api/principals/{id}/application-access", userWrite},
+	{http.MethodGet, "/api/principals/{id}/available-applications", userRead},
+	{http.MethodGet, "/api/principals/{id}/client-access", anchorOnly},
+	{http.MethodPost, "/api/principals/{id}/client-access", anchorOnly},
+	{http.MethodDelete, "/api/principals/{id}/client-access/{clientId}", anchorOnly},
+	{http.MethodPut, "/api/principals/{id}/client-association", anchorOnly},
+	{http.MethodPost, "/api/principals/{id}/deactivate", userWrite},
+	{http.MethodPost, "/api/principals/{id}/developer-credential", developerCredential},
+	{http.MethodDelete, "/api/principals/{id}/developer-credential", developerCredential},
+	{http.MethodPost, "/api/principals/{id}/reset-2fa", userWrite},
+	{http.MethodPost, "/api/principals/{id}/reset-password", userWrite},
+	{http.MethodGet, "/api/principals/{id}/roles", userRead},
+	{http.MethodPost, "/api/principals/{id}/roles", userWrite},
+	{http.MethodPut, "/api/principals/{id}/roles", userWrite},
+	{http.MethodDelete, "/api/principals/{id}/roles/{role}", userWrite},
+	{http.MethodPost, "/api/principals/{id}/send-password-reset", userWrite},
+	{http.MethodGet, "/api/principals/{id}/version", userRead},
+
+	{http.MethodGet, "/api/processes", processRead},
+	{http.MethodPost, "/api/processes", processWrite},
+	{http.MethodGet, "/api/processes/by-code/{code}", processRead},
+	{http.MethodPost, "/api/processes/sync", processSync},
+	{http.MethodGet, "/api/processes/{id}", processRead},
+	{http.MethodPut, "/api/processes/{id}", processWrite},
+	{http.MethodDelete, "/api/processes/{id}", processDelete},
+	{http.MethodPost, "/api/processes/{id}/archive", processWrite},
+
+	{http.MethodGet, "/api/reset-approvals", userWrite},
+	{http.MethodPost, "/api/reset-approvals/{id}/approve", userWrite},
+	{http.MethodPost, "/api/reset-approvals/{id}/deny", userWrite},
+
+	{http.MethodGet, "/api/roles", roleRead},
+	{http.MethodPost, "/api/roles", roleWrite},
+	{http.MethodGet, "/api/roles/by-application/{applicationId}", roleRead},
+	{http.MethodGet, "/api/roles/by-code/{code}", roleRead},
+	{http.MethodGet, "/api/roles/by-source/{source}", roleRead},
+	{http.MethodGet, "/api/roles/filters/applications", roleRead},
+	{http.MethodGet, "/api/roles/permissions", roleRead},
+	{http.MethodGet, "/api/roles/permissions/{permission}", roleRead},
+	{http.MethodDelete, "/api/roles/permissions/{permission}", roleDelete},
+	{http.MethodGet, "/api/roles/{id}", roleRead},
+	{http.MethodPut, "/api/roles/{id}", roleWrite},
+	{http.MethodDelete, "/api/roles/{id}", roleDelete},
+	{http.MethodGet, "/api/roles/{roleName}/permissions", roleRead},
+	{http.MethodPost, "/api/roles/{roleName}/permissions", roleWrite},
+	{http.MethodPost, "/api/roles/{roleName}/permissions/{permission}", roleWrite},
+	{http.MethodDelete, "/api/roles/{roleName}/permissions/{permission}", roleWrite},
+
+	{http.MethodGet, "/api/scheduled-jobs", scheduledJobRead},
+	{http.MethodPost, "/api/scheduled-jobs", scheduledJobWrite},
+	{http.MethodGet, "/api/scheduled-jobs/by-code/{code}", scheduledJobRead},
+	{http.MethodGet, "/api/scheduled-jobs/instances/{instanceId}", scheduledJobRead},
+	{http.MethodPost, "/api/scheduled-jobs/instances/{instanceId}/complete", scheduledJobWrite},
+	{http.MethodPost, "/api/scheduled-jobs/instances/{instanceId}/log", scheduledJobWrite},
+	{http.MethodGet, "/api/scheduled-jobs/instances/{instanceId}/logs", scheduledJobRead},
+	{http.MethodGet, "/api/scheduled-jobs/{id}", scheduledJobRead},
+	{http.MethodPut, "/api/scheduled-jobs/{id}", scheduledJobWrite},
+	{http.MethodDelete, "/api/scheduled-jobs/{id}", scheduledJobDelete},
+	{http.MethodPost, "/api/scheduled-jobs/{id}/archive", scheduledJobWrite},
+	{http.MethodPost, "/api/scheduled-jobs/{id}/fire", scheduledJobFire},
+	{http.MethodGet, "/api/scheduled-jobs/{id}/instances", scheduledJobRead},
+	{http.MethodPost, "/api/scheduled-jobs/{id}/pause", scheduledJobWrite},
+	{http.MethodPost, "/api/scheduled-jobs/{id}/resume", scheduledJobWrite},
+
+	{http.MethodGet, "/api/service-accounts", serviceAccountRead},
+	{http.MethodPost, "/api/service-accounts", serviceAccountWrite},
+	{http.MethodGet, "/api/service-accounts/code/{code}", serviceAccountRead},
+	{http.MethodPut, "/api/service-accounts/code/{code}", serviceAccountWrite},
+	{http.MethodGet, "/api/service-accounts/{id}", serviceAccountRead},
+	{http.MethodPut, "/api/service-accounts/{id}", serviceAccountWrite},
+	{http.MethodDelete, "/api/service-accounts/{id}", serviceAccountDelete},
+	{http.MethodPost, "/api/service-accounts/{id}/deactivate", serviceAccountWrite},
+	{http.MethodPost, "/api/service-accounts/{id}/regenerate-auth-token", anchorOnly},
+	{http.MethodPost, "/api/service-accounts/{id}/regenerate-secret", anchorOnly},
+	{http.MethodPost, "/api/service-accounts/{id}/regenerate-signing-secret", anchorOnly},
+	{http.MethodPost, "/api/service-accounts/{id}/regenerate-token", anchorOnly},
+	{http.MethodGet, "/api/service-accounts/{id}/roles", serviceAccountRead},
+	{http.MethodPut, "/api/service-accounts/{id}/roles", anchorOnly},
+
+	{http.MethodGet, "/api/subscriptions", subscriptionRead},
+	{http.MethodPost, "/api/subscriptions", subscriptionWrite},
+	{http.MethodPut, "/api/subscriptions/by-code/{code}", subscriptionWrite},
+	{http.MethodGet, "/api/subscriptions/{id}", subscriptionRead},
+	{http.MethodPut, "/api/subscriptions/{id}", subscriptionWrite},
+	{http.MethodDelete, "/api/subscriptions/{id}", subscriptionDelete},
+	{http.MethodPost, "/api/subscriptions/{id}/pause", subscriptionWrite},
+	{http.MethodPost, "/api/subscriptions/{id}/resume", subscriptionWrite},
+	{http.MethodGet, "/api/subscriptions/{id}/shadow-report", subscriptionRead},
+	{http.MethodPost, "/api/subscriptions/{id}/promote-shadow", subscriptionWrite},
+	{http.MethodPut, "/api/subscriptions/{id}/traffic-split", subscriptionWrite},
+	{http.MethodGet, "/api/subscriptions/{id}/target-stats", subscriptionRead},
+
+	{http.MethodGet, "/bff/dashboard/stats", adminOnly},
+
+	{http.MethodGet, "/bff/debug/dispatch-jobs", dispatchJobReadRaw},
+	{http.MethodGet, "/bff/debug/events", eventReadRaw},
+
+	{http.MethodGet, "/bff/developer/applications", anchorOnly},
+	{http.MethodGet, "/bff/developer/applications/{appId}", anchorOnly},
+	{http.MethodGet, "/bff/developer/applications/{appId}/event-types", anchorOnly},
+	{http.MethodGet, "/bff/developer/applications/{appId}/openapi/current", anchorOnly},
+	{http.MethodGet, "/bff/developer/applications/{appId}/openapi/versions", anchorOnly},
+	{http.MethodGet, "/bff/developer/applications/{appId}/openapi/versions/{specId}", anchorOnly},
+	{http.MethodPost, "/bff/developer/sync-platform-openapi", anchorOnly},
+
+	{http.MethodGet, "/bff/dispatch-jobs", dispatchJobRead},
+	{http.MethodPost, "/bff/dispatch-jobs/cancel", dispatchJobRead},
+	{http.MethodGet, "/bff/dispatch-jobs/event/{eventId}", dispatchJobRead},
+	{http.MethodGet, "/bff/dispatch-jobs/filter-options", dispatchJobRead},
+	{http.MethodGet, "/bff/dispatch-jobs/list-raw", dispatchJobReadRaw},
+	{http.MethodPost, "/bff/dispatch-jobs/requeue", dispatchJobRead},
+	{http.MethodGet, "/bff/dispatch-jobs/{id}", dispatchJobRead},
+	{http.MethodGet, "/bff/dispatch-jobs/{id}/attempts", dispatchJobRead},
+	{http.MethodGet, "/bff/dispatch-jobs/{id}/raw", dispatchJobReadRaw},
+
+	{http.MethodGet, "/bff/event-types", eventTypeRead},
+	{http.MethodPost, "/bff/event-types", eventTypeCreate},
+	{http.MethodGet, "/bff/event-types/filters/aggregates", eventTypeRead},
+	{http.MethodGet, "/bff/event-types/filters/applications", eventTypeRead},
+	{http.MethodGet, "/bff/event-types/filters/subdomains", eventTypeRead},
+	{http.MethodPost, "/bff/event-types/sync-platform", anchorOnly},
+	{http.MethodGet, "/bff/event-types/{id}", eventTypeRead},
+	{http.MethodPut, "/bff/event-types/{id}", eventTypeUpdate},
+	{http.MethodPatch, "/bff/event-types/{id}", eventTypeUpdate},
+	{http.MethodDelete, "/bff/event-types/{id}", eventTypeDelete},
+	{http.MethodPost, "/bff/event-types/{id}/archive", eventTypeUpdate},
+	{http.MethodPost, "/bff/event-types/{id}/schemas", eventTypeUpdate},
+	{http.MethodPost, "/bff/event-types/{id}/schemas/{version}/deprecate", eventTypeUpdate},
+	{http.MethodPost, "/bff/event-types/{id}/schemas/{version}/finalise", eventTypeUpdate},
+
+	{http.MethodGet, "/bff/events", eventRead},
+	{http.MethodPost, "/bff/events/batch", eventWrite},
+	{http.MethodGet, "/bff/events/filter-options", eventRead},
+	{http.MethodGet, "/bff/events/list-raw", eventReadRaw},
+	{http.MethodGet, "/bff/events/{id}", eventRead},
+
+	{http.MethodGet, "/bff/filter-options/clients", authenticated},
+
+	{http.MethodGet, "/bff/portal/clients/{clientId}/deliveries", dispatchJobRead},
+	{http.MethodGet, "/bff/portal/clients/{clientId}/deliveries/{id}", dispatchJobRead},
+	{http.MethodGet, "/bff/portal/clients/{clientId}/signing-keys", connectionRead},
+	{http.MethodPost, "/bff/portal/clients/{clientId}/signing-keys/rotate", connectionUpdate},
+	{http.MethodGet, "/bff/portal/clients/{clientId}/subscriptions", subscriptionRead},
+	{http.MethodGet, "/bff/portal/clients/{clientId}/subscriptions/{id}", subscriptionRead},
+	{http.MethodPut, "/bff/portal/clients/{clientId}/subscriptions/{id}", subscriptionUpdate},
+	{http.MethodPost, "/bff/portal/clients/{clientId}/subscriptions/{id}/pause", subscriptionUpdate},
+	{http.MethodPost, "/bff/portal/clients/{clientId}/subscriptions/{id}/resume", subscriptionUpdate},
+	{http.MethodPost, "/bff/portal/clients/{clientId}/subscriptions/{id}/test", subscriptionUpdate},
+
+	{http.MethodGet, "/bff/processes", processRead},
+	{http.MethodPost, "/bff/processes", processWrite},
+	{http.MethodGet, "/bff/processes/by-code/{code}", processRead},
+	{http.MethodGet, "/bff/processes/{id}", processRead},
+	{http.MethodPut, "/bff/processes/{id}", processWrite},
+	{http.MethodDelete, "/bff/processes/{id}", processDelete},
+	{http.MethodPost, "/bff/processes/{id}/archive", processWrite},
+
+	{http.MethodGet, "/bff/roles", roleRead},
+	{http.MethodPost, "/bff/roles", anchorOnly},
+	{http.MethodGet, "/bff/roles/filters/applications", roleRead},
+	{http.MethodGet, "/bff/roles/permissions", roleRead},
+	{http.MethodPost, "/bff/roles/permissions", anchorOnly},
+	{http.MethodGet, "/bff/roles/permissions/{permission}", roleRead},
+	{http.MethodPost, "/bff/roles/sync-platform", anchorOnly},
+	{http.MethodGet, "/bff/roles/{roleName}", roleRead},
+	{http.MethodPut, "/bff/roles/{roleName}", anchorOnly},
+	{http.MethodDelete, "/bff/roles/{roleName}", anchorOnly},
+
+	{http.MethodGet, "/bff/scheduled-jobs", scheduledJobRead},
+	{http.MethodGet, "/bff/scheduled-jobs/filter-options", scheduledJobRead},
+	{http.MethodGet, "/bff/scheduled-jobs/instances/{instanceId}", scheduledJobRead},
+	{http.MethodGet, "/bff/scheduled-jobs/instances/{instanceId}/logs", scheduledJobRead},
+	{http.MethodGet, "/bff/scheduled-jobs/{id}", scheduledJobRead},
+	{http.MethodGet, "/bff/scheduled-jobs/{id}/instances", scheduledJobRead},
+
+	{http.MethodGet, "/bff/subscriptions/{id}/analytics", subscriptionRead},
+}
+
+// routeIndex resolves a request to its registry entry through a chi mux
+// holding every registered pattern, so "{id}" segments and static
+// siblings ("/by-code/{code}" vs "/{id}") match exactly as the real
+// router matches them.
+var routeIndex = sync.OnceValues(func() (*chi.Mux, map[string]Requirement) {
+	mux := chi.NewMux()
+	byKey := make(map[string]Requirement, len(routeRequirements))
+	noop := http.HandlerFunc(func(http.ResponseWriter, *http.Request) {})
+	for _, rr := range routeRequirements {
+		mux.Method(rr.Method, rr.Path, noop)
+		byKey[rr.Method+" "+rr.Path] = rr.Requirement
+	}
+	return mux, byKey
+})
+
+// RequirementFor returns the requirement declared for a method and route
+// pattern, exactly as registered.
+func RequirementFor(method, pattern string) (Requirement, bool) {
+	_, byKey := routeIndex()
+	q, ok := byKey[method+" "+pattern]
+	return q, ok
+}
+
+// PermissionMatrix lists every declared route, ordered by path then
+// method.
+func PermissionMatrix() []RouteRequirement {
+	out := make([]RouteRequirement, len(routeRequirements))
+	copy(out, routeRequirements)
+	sort.SliceStable(out, func(i, j int) bool {
+		if out[i].Path != out[j].Path {
+			return out[i].Path < out[j].Path
+		}
+		return out[i].Method < out[j].Method
+	})
+	return out
+}
+
+// isGuardedPath reports whether path sits under a prefix every route of
+// which must be declared.
+func isGuardedPath(path string) bool {
+	return strings.HasPrefix(path, "/api/") || strings.HasPrefix(path, "/bff/")
+}
+
+// RequireRoutePermissions enforces the registry. Mount it after
+// Authenticator. An /api or /bff request matching no declared route is
+// refused, so a handler added without an entry fails closed instead of
+// relying on its own check; other paths (/auth, /oauth) pass through to
+// their handlers.
+func RequireRoutePermissions(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		path := r.URL.RawPath
+		if path == "" {
+			path = r.URL.Path
+		}
+		if len(path) > 1 {
+			path = strings.TrimSuffix(path, "/")
+		}
+		mux, byKey := routeIndex()
+		pattern := mux.Find(chi.NewRouteContext(), r.Method, path)
+		q, ok := byKey[r.Method+" "+pattern]
+		if !ok {
+			if isGuardedPath(path) {
+				writeEnvelope(w, http.StatusForbidden, "ROUTE_PERMISSION_UNDECLARED",
+					"No permission is declared for this route")
+				return
+			}
+			next.ServeHTTP(w, r)
+			return
+		}
+		if err := q.Check(auth.FromContext(r.Context())); err != nil {
+			httperror.Write(w, err)
+			return
+		}
+		next.ServeHTTP(w, r)
+	})
+}
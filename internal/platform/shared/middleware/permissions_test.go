@@ -0,0 +1,98 @@
+package middleware
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+)
+
+func TestRequireRoutePermissions(t *testing.T) {
+	h := RequireRoutePermissions(okHandler)
+	clientUser := &auth.AuthContext{PrincipalID: "prn_1", Scope: auth.ScopeClient, Clients: []string{"clt_1"}}
+	roleViewer := &auth.AuthContext{PrincipalID: "prn_2", Scope: auth.ScopeClient, Permissions: []string{"platform:iam:role:view"}}
+	wildcard := &auth.AuthContext{PrincipalID: "prn_3", Scope: auth.ScopeClient, Permissions: []string{"platform:iam:*:*"}}
+	anchor := &auth.AuthContext{PrincipalID: "prn_4", Scope: auth.ScopeAnchor}
+
+	cases := []struct {
+		name   string
+		method string
+		path   string
+		ac     *auth.AuthContext
+		status int
+		code   string
+	}{
+		{"unauthenticated", http.MethodGet, "/api/roles", nil, http.StatusForbidden, "UNAUTHENTICATED"},
+		{"missing permission", http.MethodGet, "/api/roles", clientUser, http.StatusForbidden, "PERMISSION_REQUIRED"},
+		{"held permission", http.MethodGet, "/api/roles/rol_1", roleViewer, http.StatusOK, ""},
+		{"wildcard permission", http.MethodGet, "/api/roles/by-code/admin", wildcard, http.StatusOK, ""},
+		{"read grant does not cover writes", http.MethodPost, "/api/roles", roleViewer, http.StatusForbidden, "PERMISSION_REQUIRED"},
+		{"anchor passes permission routes", http.MethodDelete, "/api/roles/rol_1", anchor, http.StatusOK, ""},
+		{"anchor-only route", http.MethodGet, "/api/oauth-clients", wildcard, http.StatusForbidden, "ANCHOR_REQUIRED"},
+		{"admin route", http.MethodGet, "/bff/dashboard/stats", roleViewer, http.StatusForbidden, "ADMIN_REQUIRED"},
+		{"authenticated route", http.MethodGet, "/api/me", clientUser, http.StatusOK, ""},
+		{"public route", http.MethodGet, "/api/platform/cors/allowed", nil, http.StatusOK, ""},
+		{"trailing slash", http.MethodGet, "/bff/roles/", clientUser, http.StatusForbidden, "PERMISSION_REQUIRED"},
+		{"undeclared api route", http.MethodGet, "/api/not-a-route", anchor, http.StatusForbidden, "ROUTE_PERMISSION_UNDECLARED"},
+		{"undeclared method", http.MethodPatch, "/api/roles/rol_1", anchor, http.StatusForbidden, "ROUTE_PERMISSION_UNDECLARED"},
+		{"outside the registry", http.MethodPost, "/auth/login", nil, http.StatusOK, ""},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			r := httptest.NewRequest(tc.method, tc.path, nil)
+			if tc.ac != nil {
+				r = r.WithContext(auth.WithContext(r.Context(), tc.ac))
+			}
+			w := httptest.NewRecorder()
+			h.ServeHTTP(w, r)
+			if w.Code != tc.status {
+				t.Fatalf("status=%d want %d (body %s)", w.Code, tc.status, w.Body.String())
+			}
+			if tc.code == "" {
+				return
+			}
+			var env httperror.Envelope
+			if err := json.Unmarshal(w.Body.Bytes(), &env); err != nil {
+				t.Fatalf("decode envelope: %v", err)
+			}
+			if env.Code != tc.code {
+				t.Errorf("code=%q want %q", env.Code, tc.code)
+			}
+		})
+	}
+}
+
+func TestPermissionMatrix(t *testing.T) {
+	rows := PermissionMatrix()
+	seen := make(map[string]bool, len(rows))
+	for i, rr := range rows {
+		key := rr.Method + " " + rr.Path
+		if seen[key] {
+			t.Errorf("%s declared twice", key)
+		}
+		seen[key] = true
+		if i > 0 && (rows[i-1].Path > rr.Path || rows[i-1].Path == rr.Path && rows[i-1].Method > rr.Method) {
+			t.Errorf("%s out of order", key)
+		}
+		switch rr.Requirement.Access {
+		case AccessPermission:
+			if len(rr.Requirement.Permissions) == 0 {
+				t.Errorf("%s: PERMISSION access with no permissions", key)
+			}
+		case AccessPublic, AccessAuthenticated, AccessAnchor, AccessAdmin:
+			if len(rr.Requirement.Permissions) != 0 {
+				t.Errorf("%s: %s access lists permissions", key, rr.Requirement.Access)
+			}
+		default:
+			t.Errorf("%s: unknown access %q", key, rr.Requirement.Access)
+		}
+	}
+
+	q, ok := RequirementFor(http.MethodGet, "/api/admin/permissions/matrix")
+	if !ok || q.Access != AccessPermission || len(q.Permissions) != 1 || q.Permissions[0] != "platform:iam:role:view" {
+		t.Errorf("matrix route requirement = %+v, %v", q, ok)
+	}
+}
@@ -0,0 +1,227 @@
+package middleware
+
+import (
+	"crypto/rand"
+	"crypto/subtle"
+	"encoding/base64"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"slices"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// CORSConfig governs the CORS middleware. The zero value allows no
+// cross-origin callers: the SPA is served same-origin, so only origins an
+// operator lists get CORS headers.
+type CORSConfig struct {
+	// AllowedOrigins are exact origins (scheme://host[:port]). "*" allows
+	// any origin and cannot be combined with AllowCredentials.
+	AllowedOrigins []string
+	// AllowCredentials lets listed origins send cookies and Authorization.
+	AllowCredentials bool
+	// MaxAge is how long a browser may cache a preflight answer.
+	MaxAge time.Duration
+}
+
+// What a cross-origin caller may send (methods, request headers) and
+// which response headers it may read.
+var (
+	corsAllowedHeaders = "Authorization, Content-Type, If-Match, If-None-Match, Idempotency-Key, X-Correlation-ID, " + CSRFHeaderName
+	corsExposedHeaders = "ETag, Location, X-Correlation-ID"
+	corsAllowedMethods = "GET, HEAD, POST, PUT, PATCH, DELETE, OPTIONS"
+)
+
+// ParseCORSOrigins splits a comma-separated origin list, dropping blanks.
+func ParseCORSOrigins(raw string) []string {
+	var out []string
+	for _, o := range strings.Split(raw, ",") {
+		if o = strings.TrimRight(strings.TrimSpace(o), "/"); o != "" {
+			out = append(out, o)
+		}
+	}
+	return out
+}
+
+// Validate rejects origins that can never match a browser's Origin header
+// and a wildcard with credentials, which browsers refuse anyway.
+func (c CORSConfig) Validate() error {
+	for _, o := range c.AllowedOrigins {
+		if o == "*" {
+			if c.AllowCredentials {
+				return errors.New(`origin "*" cannot be combined with credentials`)
+			}
+			continue
+		}
+		u, err := url.Parse(o)
+		if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" ||
+			u.Path != "" || u.RawQuery != "" || u.Fragment != "" || u.User != nil {
+			return fmt.Errorf("origin %q must be scheme://host[:port]", o)
+		}
+	}
+	return nil
+}
+
+// CORS answers preflights and decorates responses for the configured
+// origins. Requests from other origins pass through without CORS headers,
+// so the browser blocks the response; a preflight from one is refused
+// with 403 so the actual request is never sent.
+func CORS(cfg CORSConfig) func(http.Handler) http.Handler {
+	anyOrigin := slices.Contains(cfg.AllowedOrigins, "*")
+	maxAge := strconv.Itoa(int(cfg.MaxAge / time.Second))
+	allowed := func(origin string) bool {
+		return anyOrigin || slices.Contains(cfg.AllowedOrigins, origin)
+	}
+
+	return func(next http.Handler) http.Handler {
+		return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			origin := r.Header.Get("Origin")
+			if origin == "" {
+				next.ServeHTTP(w, r)
+				return
+			}
+			h := w.Header()
+			h.Add("Vary", "Origin")
+			preflight := r.Method == http.MethodOptions && r.Header.Get("Access-Control-Request-Method") != ""
+			if !allowed(origin) {
+				if preflight {
+					w.WriteHeader(http.StatusForbidden)
+					return
+				}
+				next.ServeHTTP(w, r)
+				return
+			}
+
+			if anyOrigin && !cfg.AllowCredentials {
+				h.Set("Access-Control-Allow-Origin", "*")
+			} else {
+				h.Set("Access-Control-Allow-Origin", origin)
+			}
+			if cfg.AllowCredentials {
+				h.Set("Access-Control-Allow-Credentials", "true")
+			}
+			if !preflight {
+				h.Set("Access-Control-Expose-Headers", corsExposedHeaders)
+				next.ServeHTTP(w, r)
+				return
+			}
+			h.Add("Vary", "Access-Control-Request-Method")
+			h.Add("Vary", "Access-Control-Request-Headers")
+			h.Set("Access-Control-Allow-Methods", corsAllowedMethods)
+			h.Set("Access-Control-Allow-Headers", corsAllowedHeaders)
+			if cfg.MaxAge > 0 {
+				h.Set("Access-Control-Max-Age", maxAge)
+			}
+			w.WriteHeader(http.StatusNoContent)
+		})
+	}
+}
+
+// CSRFCookieName and CSRFHeaderName carry the double-submit token: the
+// cookie is readable by the SPA (not HttpOnly), which echoes it in the
+// header. A cross-site page can make the browser send the cookie but
+// cannot read it to set the header.
+const (
+	CSRFCookieName = "fc_csrf"
+	CSRFHeaderName = "X-CSRF-Token"
+)
+
+// CSRFConfig governs the CSRF middleware.
+type CSRFConfig struct {
+	// ExemptPrefixes are path prefixes never checked — endpoints with
+	// their own CSRF defence (the OAuth flows' state/PKCE and client
+	// authentication) or that browsers never call with the session.
+	ExemptPrefixes []string
+}
+
+// CSRF enforces double-submit protection on state-changing requests
+// authenticated by the fc_session cookie. Bearer and BasicAuth callers
+// are exempt — a cross-site page cannot make the browser attach those —
+// as are safe methods. Any request without a token cookie or an
+// Authorization header (i.e. a browser) is issued one, so the SPA has it
+// before its first mutation.
+func CSRF(cfg CSRFConfig) func(http.Handler) http.Handler {
+	return func(next http.Handler) http.Handler {
+		return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			cookie := ""
+			if c, err := r.Cookie(CSRFCookieName); err == nil {
+				cookie = c.Value
+			}
+			if cookie == "" && r.Header.Get("Authorization") == "" {
+				issueCSRFCookie(w, r)
+			}
+
+			switch r.Method {
+			case http.MethodGet, http.MethodHead, http.MethodOptions, http.MethodTrace:
+				next.ServeHTTP(w, r)
+				return
+			}
+			if _, fromCookie := extractToken(r); !fromCookie {
+				next.ServeHTTP(w, r)
+				return
+			}
+			for _, p := range cfg.ExemptPrefixes {
+				if strings.HasPrefix(r.URL.Path, p) {
+					next.ServeHTTP(w, r)
+					return
+				}
+			}
+			header := r.Header.Get(CSRFHeaderName)
+			if cookie == "" || header == "" || subtle.ConstantTimeCompare([]byte(cookie), []byte(header)) != 1 {
+				writeEnvelope(w, http.StatusForbidden, "CSRF_TOKEN_INVALID",
+					"Missing or invalid "+CSRFHeaderName+" header; reload the page and retry")
+				return
+			}
+			next.ServeHTTP(w, r)
+		})
+	}
+}
+
+// issueCSRFCookie sets a fresh token, scoped like the session cookie.
+func issueCSRFCookie(w http.ResponseWriter, r *http.Request) {
+	b := make([]byte, 32)
+	_, _ = rand.Read(b)
+	http.SetCookie(w, &http.Cookie{
+		Name:     CSRFCookieName,
+		Value:    base64.RawURLEncoding.EncodeToString(b),
+		Path:     "/",
+		Secure:   isHTTPS(r),
+		SameSite: http.SameSiteLaxMode,
+	})
+}
+
+// SecurityHeadersConfig governs the SecurityHeaders middleware.
+type SecurityHeadersConfig struct {
+	// HSTSMaxAge is the Strict-Transport-Security max-age; 0 omits the
+	// header. It is only sent on HTTPS requests (directly or via a
+	// proxy's X-Forwarded-Proto), since browsers ignore it over HTTP.
+	HSTSMaxAge time.Duration
+}
+
+// SecurityHeaders sets the baseline hardening headers on every response:
+// no MIME sniffing, no framing, a conservative referrer policy and, over
+// HTTPS, HSTS.
+func SecurityHeaders(cfg SecurityHeadersConfig) func(http.Handler) http.Handler {
+	hsts := "max-age=" + strconv.Itoa(int(cfg.HSTSMaxAge/time.Second)) + "; includeSubDomains"
+	return func(next http.Handler) http.Handler {
+		return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			h := w.Header()
+			h.Set("X-Content-Type-Options", "nosniff")
+			h.Set("X-Frame-Options", "DENY")
+			h.Set("Referrer-Policy", "strict-origin-when-cross-origin")
+			if cfg.HSTSMaxAge > 0 && isHTTPS(r) {
+				h.Set("Strict-Transport-Security", hsts)
+			}
+			next.ServeHTTP(w, r)
+		})
+	}
+}
+
+// isHTTPS reports whether the client reached us over TLS, directly or
+// through a TLS-terminating proxy.
+func isHTTPS(r *http.Request) bool {
+	return r.TLS != nil || strings.EqualFold(r.Header.Get("X-Forwarded-Proto"), "https")
+}
@@ -0,0 +1,155 @@
+package middleware
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+	"time"
+)
+
+var okHandler = http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) { w.WriteHeader(http.StatusOK) })
+
+func TestCORS(t *testing.T) {
+	h := CORS(CORSConfig{
+		AllowedOrigins:   []string{"https://app.example.com"},
+		AllowCredentials: true,
+		MaxAge:           10 * time.Minute,
+	})(okHandler)
+
+	// Preflight from a listed origin.
+	r := httptest.NewRequest(http.MethodOptions, "/api/clients", nil)
+	r.Header.Set("Origin", "https://app.example.com")
+	r.Header.Set("Access-Control-Request-Method", "POST")
+	w := httptest.NewRecorder()
+	h.ServeHTTP(w, r)
+	if w.Code != http.StatusNoContent {
+		t.Fatalf("preflight status=%d want 204", w.Code)
+	}
+	for k, want := range map[string]string{
+		"Access-Control-Allow-Origin":      "https://app.example.com",
+		"Access-Control-Allow-Credentials": "true",
+		"Access-Control-Max-Age":           "600",
+	} {
+		if got := w.Header().Get(k); got != want {
+			t.Errorf("%s=%q want %q", k, got, want)
+		}
+	}
+	if !strings.Contains(w.Header().Get("Access-Control-Allow-Headers"), CSRFHeaderName) {
+		t.Errorf("Access-Control-Allow-Headers=%q should allow %s", w.Header().Get("Access-Control-Allow-Headers"), CSRFHeaderName)
+	}
+
+	// Preflight from anywhere else is refused.
+	r = httptest.NewRequest(http.MethodOptions, "/api/clients", nil)
+	r.Header.Set("Origin", "https://evil.example.net")
+	r.Header.Set("Access-Control-Request-Method", "POST")
+	w = httptest.NewRecorder()
+	h.ServeHTTP(w, r)
+	if w.Code != http.StatusForbidden || w.Header().Get("Access-Control-Allow-Origin") != "" {
+		t.Errorf("foreign preflight: status=%d ACAO=%q want 403 and no ACAO", w.Code, w.Header().Get("Access-Control-Allow-Origin"))
+	}
+
+	// A simple request from elsewhere runs but carries no CORS grant.
+	r = httptest.NewRequest(http.MethodGet, "/api/clients", nil)
+	r.Header.Set("Origin", "https://evil.example.net")
+	w = httptest.NewRecorder()
+	h.ServeHTTP(w, r)
+	if w.Code != http.StatusOK || w.Header().Get("Access-Control-Allow-Origin") != "" {
+		t.Errorf("foreign GET: status=%d ACAO=%q want 200 and no ACAO", w.Code, w.Header().Get("Access-Control-Allow-Origin"))
+	}
+}
+
+func TestCORSConfigValidate(t *testing.T) {
+	for _, tc := range []struct {
+		cfg CORSConfig
+		ok  bool
+	}{
+		{CORSConfig{AllowedOrigins: ParseCORSOrigins("https://a.example.com, http://localhost:5173/")}, true},
+		{CORSConfig{AllowedOrigins: []string{"*"}}, true},
+		{CORSConfig{AllowedOrigins: []string{"*"}, AllowCredentials: true}, false},
+		{CORSConfig{AllowedOrigins: []string{"https://a.example.com/app"}}, false},
+		{CORSConfig{AllowedOrigins: []string{"a.example.com"}}, false},
+	} {
+		if err := tc.cfg.Validate(); (err == nil) != tc.ok {
+			t.Errorf("Validate(%v) err=%v want ok=%v", tc.cfg.AllowedOrigins, err, tc.ok)
+		}
+	}
+}
+
+func TestCSRF(t *testing.T) {
+	h := CSRF(CSRFConfig{ExemptPrefixes: []string{"/oauth/token"}})(okHandler)
+	const token = "csrf-token-value"
+	session := &http.Cookie{Name: SessionCookieName, Value: "jwt"}
+	csrf := &http.Cookie{Name: CSRFCookieName, Value: token}
+
+	cases := []struct {
+		name    string
+		method  string
+		path    string
+		cookies []*http.Cookie
+		headers map[string]string
+		want    int
+	}{
+		{"safe method", http.MethodGet, "/api/clients", []*http.Cookie{session}, nil, http.StatusOK},
+		{"no session", http.MethodPost, "/api/clients", []*http.Cookie{csrf}, nil, http.StatusOK},
+		{"bearer", http.MethodPost, "/api/clients", []*http.Cookie{session}, map[string]string{"Authorization": "Bearer x"}, http.StatusOK},
+		{"missing header", http.MethodPost, "/api/clients", []*http.Cookie{session, csrf}, nil, http.StatusForbidden},
+		{"missing cookie", http.MethodPost, "/api/clients", []*http.Cookie{session}, map[string]string{CSRFHeaderName: token}, http.StatusForbidden},
+		{"mismatch", http.MethodDelete, "/api/clients/x", []*http.Cookie{session, csrf}, map[string]string{CSRFHeaderName: "other"}, http.StatusForbidden},
+		{"match", http.MethodPost, "/api/clients", []*http.Cookie{session, csrf}, map[string]string{CSRFHeaderName: token}, http.StatusOK},
+		{"exempt", http.MethodPost, "/oauth/token", []*http.Cookie{session}, nil, http.StatusOK},
+	}
+	for _, tc := range cases {
+		r := httptest.NewRequest(tc.method, tc.path, nil)
+		for _, c := range tc.cookies {
+			r.AddCookie(c)
+		}
+		for k, v := range tc.headers {
+			r.Header.Set(k, v)
+		}
+		w := httptest.NewRecorder()
+		h.ServeHTTP(w, r)
+		if w.Code != tc.want {
+			t.Errorf("%s: status=%d want %d", tc.name, w.Code, tc.want)
+		}
+	}
+}
+
+func TestCSRFIssuesCookieToBrowsers(t *testing.T) {
+	h := CSRF(CSRFConfig{})(okHandler)
+
+	w := httptest.NewRecorder()
+	h.ServeHTTP(w, httptest.NewRequest(http.MethodGet, "/", nil))
+	if c := w.Result().Cookies(); len(c) != 1 || c[0].Name != CSRFCookieName || c[0].HttpOnly || c[0].Value == "" {
+		t.Errorf("browser GET cookies=%v want one readable %s", c, CSRFCookieName)
+	}
+
+	r := httptest.NewRequest(http.MethodGet, "/api/clients", nil)
+	r.Header.Set("Authorization", "Bearer x")
+	w = httptest.NewRecorder()
+	h.ServeHTTP(w, r)
+	if c := w.Result().Cookies(); len(c) != 0 {
+		t.Errorf("API client cookies=%v want none", c)
+	}
+}
+
+func TestSecurityHeaders(t *testing.T) {
+	h := SecurityHeaders(SecurityHeadersConfig{HSTSMaxAge: 365 * 24 * time.Hour})(okHandler)
+
+	w := httptest.NewRecorder()
+	h.ServeHTTP(w, httptest.NewRequest(http.MethodGet, "/", nil))
+	if w.Header().Get("X-Content-Type-Options") != "nosniff" || w.Header().Get("X-Frame-Options") != "DENY" {
+		t.Errorf("headers=%v want nosniff + DENY", w.Header())
+	}
+	if got := w.Header().Get("Strict-Transport-Security"); got != "" {
+		t.Errorf("HSTS over plain HTTP = %q, want none", got)
+	}
+
+	r := httptest.NewRequest(http.MethodGet, "/", nil)
+	r.Header.Set("X-Forwarded-Proto", "https")
+	w = httptest.NewRecorder()
+	h.ServeHTTP(w, r)
+	if got := w.Header().Get("Strict-Transport-Security"); got != "max-age=31536000; includeSubDomains" {
+		t.Errorf("HSTS = %q", got)
+	}
+}
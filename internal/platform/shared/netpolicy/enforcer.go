@@ -0,0 +1,141 @@
+package netpolicy
+
+import (
+	"context"
+	"encoding/json"
+	"log/slog"
+	"net/http"
+	"time"
+
+	lru "github.com/hashicorp/golang-lru/v2/expirable"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+const (
+	// cacheTTL bounds how long a service account's allowlist is trusted
+	// after a change; the lookup sits on every authenticated request.
+	cacheTTL  = 30 * time.Second
+	cacheSize = 10_000
+	// auditEvery collapses repeated rejections of one subject from one
+	// address into a single audit row, so a misconfigured or hostile
+	// caller can't flood the log.
+	auditEvery = time.Minute
+)
+
+// auditSink is the subset of audit.Repository the enforcer writes through.
+type auditSink interface {
+	Insert(ctx context.Context, l *audit.Log) error
+}
+
+var _ auditSink = (*audit.Repository)(nil)
+
+// LookupFunc resolves the service account behind principalID and its
+// allowlist. serviceAccountID is "" for principals that aren't service
+// accounts.
+type LookupFunc func(ctx context.Context, principalID string) (serviceAccountID string, cidrs []string, err error)
+
+// Config bundles an Enforcer's dependencies.
+type Config struct {
+	Proxies Proxies
+	Lookup  LookupFunc
+	// Audit records rejected attempts. Optional.
+	Audit auditSink
+}
+
+type principalPolicy struct {
+	serviceAccountID string
+	cidrs            []string
+}
+
+// Enforcer applies the allowlists. A nil *Enforcer allows everything, so
+// callers holding an optional one need no branch of their own.
+type Enforcer struct {
+	cfg     Config
+	cache   *lru.LRU[string, principalPolicy]
+	audited *lru.LRU[string, struct{}]
+}
+
+// New builds an Enforcer.
+func New(cfg Config) *Enforcer {
+	return &Enforcer{
+		cfg:     cfg,
+		cache:   lru.NewLRU[string, principalPolicy](cacheSize, nil, cacheTTL),
+		audited: lru.NewLRU[string, struct{}](cacheSize, nil, auditEvery),
+	}
+}
+
+// ClientIP resolves r's caller address through the trusted proxies.
+func (e *Enforcer) ClientIP(r *http.Request) string {
+	if e == nil {
+		return Peer(r)
+	}
+	return e.cfg.Proxies.ClientIP(r)
+}
+
+// AllowPrincipal reports whether principalID may act from ip. Only
+// service-account principals carry an allowlist. A lookup error denies:
+// the check exists to stop credentials working from the wrong network,
+// and can't tell that apart while the database is unreachable.
+func (e *Enforcer) AllowPrincipal(ctx context.Context, principalID, ip string) bool {
+	if e == nil || e.cfg.Lookup == nil || principalID == "" {
+		return true
+	}
+	pol, ok := e.cache.Get(principalID)
+	if !ok {
+		saID, cidrs, err := e.cfg.Lookup(ctx, principalID)
+		if err != nil {
+			slog.Warn("network policy lookup failed; denying", "principal_id", principalID, "err", err)
+			return false
+		}
+		pol = principalPolicy{serviceAccountID: saID, cidrs: cidrs}
+		e.cache.Add(principalID, pol)
+	}
+	if pol.serviceAccountID == "" || Allowed(pol.cidrs, ip) {
+		return true
+	}
+	e.reject(ctx, "SERVICE_ACCOUNT", pol.serviceAccountID, ip, &principalID, map[string]any{
+		"ip":          ip,
+		"principalId": principalID,
+	})
+	return false
+}
+
+// AllowClient reports whether an OAuth client's credentials may be
+// presented from ip. id is the client's row id, clientID its public
+// client_id.
+func (e *Enforcer) AllowClient(ctx context.Context, id, clientID string, cidrs []string, ip string) bool {
+	if e == nil || Allowed(cidrs, ip) {
+		return true
+	}
+	e.reject(ctx, "OAUTH_CLIENT", id, ip, nil, map[string]any{
+		"ip":       ip,
+		"clientId": clientID,
+	})
+	return false
+}
+
+func (e *Enforcer) reject(ctx context.Context, entityType, entityID, ip string, principalID *string, detail map[string]any) {
+	slog.Warn("network policy rejected credential use", "entity_type", entityType, "entity_id", entityID, "ip", ip)
+	if e.cfg.Audit == nil {
+		return
+	}
+	key := entityType + ":" + entityID + "@" + ip
+	if e.audited.Contains(key) {
+		return
+	}
+	e.audited.Add(key, struct{}{})
+	l := &audit.Log{
+		ID:          tsid.Generate(tsid.AuditLog),
+		EntityType:  entityType,
+		EntityID:    entityID,
+		Operation:   "NetworkPolicyRejected",
+		PrincipalID: principalID,
+		PerformedAt: time.Now().UTC(),
+	}
+	l.OperationJSON, _ = json.Marshal(detail)
+	if err := e.cfg.Audit.Insert(ctx, l); err != nil {
+		slog.Warn("network policy audit insert failed", "entity_id", entityID, "err", err)
+	}
+}
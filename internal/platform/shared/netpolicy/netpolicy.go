@@ -0,0 +1,168 @@
+// Package netpolicy restricts where credentials may be used from: CIDR
+// allowlists on OAuth clients and service accounts, matched against the
+// caller's IP as resolved through the trusted reverse proxies
+// (FC_TRUSTED_PROXIES).
+package netpolicy
+
+import (
+	"context"
+	"fmt"
+	"net"
+	"net/http"
+	"net/netip"
+	"strings"
+)
+
+// NormalizeCIDRs validates an allowlist and canonicalises each entry: a
+// bare address becomes a single-host prefix and host bits are masked off,
+// so "10.1.2.3/8" is stored as "10.0.0.0/8". Duplicates are dropped.
+func NormalizeCIDRs(in []string) ([]string, error) {
+	out := make([]string, 0, len(in))
+	seen := make(map[string]bool, len(in))
+	for _, raw := range in {
+		p, err := parsePrefix(raw)
+		if err != nil {
+			return nil, err
+		}
+		s := p.String()
+		if !seen[s] {
+			seen[s] = true
+			out = append(out, s)
+		}
+	}
+	return out, nil
+}
+
+// Allowed reports whether ip falls inside one of cidrs. An empty list
+// allows every address; an unparseable ip matches nothing.
+func Allowed(cidrs []string, ip string) bool {
+	if len(cidrs) == 0 {
+		return true
+	}
+	addr, err := netip.ParseAddr(ip)
+	if err != nil {
+		return false
+	}
+	addr = addr.Unmap()
+	for _, c := range cidrs {
+		if p, err := parsePrefix(c); err == nil && p.Contains(addr) {
+			return true
+		}
+	}
+	return false
+}
+
+func parsePrefix(s string) (netip.Prefix, error) {
+	s = strings.TrimSpace(s)
+	if !strings.Contains(s, "/") {
+		addr, err := netip.ParseAddr(s)
+		if err != nil {
+			return netip.Prefix{}, fmt.Errorf("invalid address or CIDR %q", s)
+		}
+		addr = addr.Unmap()
+		return netip.PrefixFrom(addr, addr.BitLen()), nil
+	}
+	p, err := netip.ParsePrefix(s)
+	if err != nil {
+		return netip.Prefix{}, fmt.Errorf("invalid address or CIDR %q", s)
+	}
+	if p.Addr().Is4In6() {
+		bits := p.Bits() - 96
+		if bits < 0 {
+			return netip.Prefix{}, fmt.Errorf("invalid address or CIDR %q", s)
+		}
+		p = netip.PrefixFrom(p.Addr().Unmap(), bits)
+	}
+	return p.Masked(), nil
+}
+
+// Proxies is the set of reverse proxies whose X-Forwarded-For entries are
+// believed.
+type Proxies []netip.Prefix
+
+// ParseProxies parses a comma-separated list of addresses and CIDRs, as
+// FC_TRUSTED_PROXIES carries it. Empty trusts no proxy.
+func ParseProxies(s string) (Proxies, error) {
+	var out Proxies
+	for _, part := range strings.Split(s, ",") {
+		if strings.TrimSpace(part) == "" {
+			continue
+		}
+		p, err := parsePrefix(part)
+		if err != nil {
+			return nil, err
+		}
+		out = append(out, p)
+	}
+	return out, nil
+}
+
+func (p Proxies) trusts(ip string) bool {
+	addr, err := netip.ParseAddr(ip)
+	if err != nil {
+		return false
+	}
+	addr = addr.Unmap()
+	for _, pfx := range p {
+		if pfx.Contains(addr) {
+			return true
+		}
+	}
+	return false
+}
+
+// ClientIP resolves the caller's address. It is the TCP peer unless the
+// peer is a trusted proxy, in which case X-Forwarded-For is walked right
+// to left past trusted hops to the first address that isn't one. Entries
+// left of that are client-supplied and never consulted, so a forged header
+// cannot place a request inside an allowlist.
+func (p Proxies) ClientIP(r *http.Request) string {
+	ip := Peer(r)
+	if !p.trusts(ip) {
+		return ip
+	}
+	hops := strings.Split(strings.Join(r.Header.Values("X-Forwarded-For"), ","), ",")
+	for i := len(hops) - 1; i >= 0; i-- {
+		hop := strings.TrimSpace(hops[i])
+		if hop == "" {
+			continue
+		}
+		if _, err := netip.ParseAddr(hop); err != nil {
+			// Garbage in the chain: stop at the last address we could trust.
+			return ip
+		}
+		ip = hop
+		if !p.trusts(hop) {
+			return hop
+		}
+	}
+	return ip
+}
+
+type peerKey struct{}
+
+// CapturePeer records the TCP peer address before anything rewrites
+// RemoteAddr (chi's RealIP trusts X-Forwarded-For and X-Real-IP
+// unconditionally). Mount it first.
+func CapturePeer(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		ctx := context.WithValue(r.Context(), peerKey{}, hostOf(r.RemoteAddr))
+		next.ServeHTTP(w, r.WithContext(ctx))
+	})
+}
+
+// Peer returns the TCP peer address recorded by CapturePeer, falling back
+// to RemoteAddr when the middleware isn't mounted.
+func Peer(r *http.Request) string {
+	if ip, ok := r.Context().Value(peerKey{}).(string); ok {
+		return ip
+	}
+	return hostOf(r.RemoteAddr)
+}
+
+func hostOf(addr string) string {
+	if host, _, err := net.SplitHostPort(addr); err == nil {
+		return host
+	}
+	return strings.TrimSpace(addr)
+}
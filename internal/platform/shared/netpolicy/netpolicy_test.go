@@ -0,0 +1,117 @@
+package netpolicy
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit"
+)
+
+type fakeAudit struct{ logs []*audit.Log }
+
+func (f *fakeAudit) Insert(_ context.Context, l *audit.Log) error {
+	f.logs = append(f.logs, l)
+	return nil
+}
+
+func TestNormalizeCIDRs(t *testing.T) {
+	got, err := NormalizeCIDRs([]string{"10.1.2.3/8", " 192.0.2.7 ", "2001:db8::1/32", "10.0.0.0/8", "::ffff:198.51.100.0/120"})
+	require.NoError(t, err)
+	assert.Equal(t, []string{"10.0.0.0/8", "192.0.2.7/32", "2001:db8::/32", "198.51.100.0/24"}, got)
+
+	_, err = NormalizeCIDRs([]string{"10.0.0.0/33"})
+	assert.Error(t, err)
+	_, err = NormalizeCIDRs([]string{"example.com"})
+	assert.Error(t, err)
+}
+
+func TestAllowed(t *testing.T) {
+	assert.True(t, Allowed(nil, "203.0.113.9"), "empty list allows anywhere")
+	cidrs := []string{"10.0.0.0/8", "2001:db8::/32"}
+	assert.True(t, Allowed(cidrs, "10.20.30.40"))
+	assert.True(t, Allowed(cidrs, "::ffff:10.0.0.1"), "v4-mapped addresses match v4 ranges")
+	assert.True(t, Allowed(cidrs, "2001:db8::5"))
+	assert.False(t, Allowed(cidrs, "203.0.113.9"))
+	assert.False(t, Allowed(cidrs, ""))
+}
+
+func TestProxies_ClientIP(t *testing.T) {
+	proxies, err := ParseProxies("10.0.0.0/8, 192.0.2.1")
+	require.NoError(t, err)
+
+	req := func(peer, xff string) *http.Request {
+		r := httptest.NewRequest(http.MethodGet, "/", nil)
+		r.RemoteAddr = peer + ":4321"
+		if xff != "" {
+			r.Header.Set("X-Forwarded-For", xff)
+		}
+		return r
+	}
+
+	assert.Equal(t, "203.0.113.9", proxies.ClientIP(req("203.0.113.9", "10.1.1.1")),
+		"an untrusted peer's header is ignored")
+	assert.Equal(t, "198.51.100.4", proxies.ClientIP(req("10.0.0.2", "1.2.3.4, 198.51.100.4, 192.0.2.1")),
+		"walks past trusted hops, never to the client-supplied left")
+	assert.Equal(t, "10.9.9.9", proxies.ClientIP(req("10.0.0.2", "10.9.9.9")),
+		"all hops trusted: the leftmost one")
+	assert.Equal(t, "10.0.0.2", proxies.ClientIP(req("10.0.0.2", "")))
+	assert.Equal(t, "10.0.0.2", proxies.ClientIP(req("10.0.0.2", "bogus")))
+
+	// CapturePeer keeps the real peer when something later rewrites
+	// RemoteAddr from the headers.
+	var got string
+	h := CapturePeer(http.HandlerFunc(func(_ http.ResponseWriter, r *http.Request) {
+		r.RemoteAddr = "10.1.1.1"
+		got = proxies.ClientIP(r)
+	}))
+	h.ServeHTTP(httptest.NewRecorder(), req("203.0.113.9", "10.1.1.1"))
+	assert.Equal(t, "203.0.113.9", got)
+}
+
+func TestEnforcer_AllowPrincipal(t *testing.T) {
+	ctx := context.Background()
+	aud := &fakeAudit{}
+	lookups := 0
+	e := New(Config{
+		Audit: aud,
+		Lookup: func(_ context.Context, principalID string) (string, []string, error) {
+			lookups++
+			switch principalID {
+			case "prn_sa":
+				return "sac_1", []string{"10.0.0.0/8"}, nil
+			case "prn_broken":
+				return "", nil, errors.New("db down")
+			}
+			return "", nil, nil
+		},
+	})
+
+	assert.True(t, e.AllowPrincipal(ctx, "prn_user", "203.0.113.9"), "not a service account")
+	assert.True(t, e.AllowPrincipal(ctx, "prn_sa", "10.2.3.4"))
+	assert.False(t, e.AllowPrincipal(ctx, "prn_sa", "203.0.113.9"))
+	assert.False(t, e.AllowPrincipal(ctx, "prn_sa", "203.0.113.9"))
+	assert.Equal(t, 2, lookups, "allowlists are cached")
+
+	require.Len(t, aud.logs, 1, "repeat rejections from one address are audited once")
+	assert.Equal(t, "SERVICE_ACCOUNT", aud.logs[0].EntityType)
+	assert.Equal(t, "sac_1", aud.logs[0].EntityID)
+	assert.Equal(t, "NetworkPolicyRejected", aud.logs[0].Operation)
+	assert.JSONEq(t, `{"ip":"203.0.113.9","principalId":"prn_sa"}`, string(aud.logs[0].OperationJSON))
+
+	assert.False(t, e.AllowPrincipal(ctx, "prn_broken", "10.2.3.4"), "lookup errors deny")
+
+	assert.False(t, e.AllowClient(ctx, "oac_1", "billing", []string{"10.0.0.0/8"}, "203.0.113.9"))
+	assert.True(t, e.AllowClient(ctx, "oac_1", "billing", nil, "203.0.113.9"))
+	require.Len(t, aud.logs, 2)
+	assert.Equal(t, "OAUTH_CLIENT", aud.logs[1].EntityType)
+
+	var none *Enforcer
+	assert.True(t, none.AllowPrincipal(ctx, "prn_sa", "203.0.113.9"), "nil enforcer allows")
+	assert.True(t, none.AllowClient(ctx, "oac_1", "billing", []string{"10.0.0.0/8"}, "203.0.113.9"))
+}
@@ -0,0 +1,156 @@
+package payloadcrypt
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// Data-key lifecycle states (tnt_client_data_keys.status).
+const (
+	StatusActive  = "ACTIVE"
+	StatusRetired = "RETIRED"
+)
+
+// DataKey is one tnt_client_data_keys row. WrappedKey is the
+// encryption.Service envelope of the base64 raw key.
+type DataKey struct {
+	ID         string
+	ClientID   string
+	WrappedKey string
+	Status     string
+	CreatedAt  time.Time
+	RetiredAt  *time.Time
+}
+
+// KeyStore persists per-client data keys.
+type KeyStore interface {
+	// Active returns the client's ACTIVE key, or nil when it has none.
+	Active(ctx context.Context, clientID string) (*DataKey, error)
+	// ByID returns any key (active or retired), or nil when absent.
+	ByID(ctx context.Context, id string) (*DataKey, error)
+	// Create stores wrapped as the client's ACTIVE key unless one already
+	// exists, and returns whichever key is active afterwards.
+	Create(ctx context.Context, clientID, wrapped string) (*DataKey, error)
+	// Rotate retires the client's ACTIVE key (if any) and stores wrapped as
+	// the new ACTIVE key, atomically.
+	Rotate(ctx context.Context, clientID, wrapped string) (*DataKey, error)
+	// List returns every key for a client, newest first.
+	List(ctx context.Context, clientID string) ([]DataKey, error)
+}
+
+// PgKeyStore is the Postgres KeyStore over tnt_client_data_keys.
+type PgKeyStore struct{ pool *pgxpool.Pool }
+
+// NewPgKeyStore wires a key store.
+func NewPgKeyStore(pool *pgxpool.Pool) *PgKeyStore { return &PgKeyStore{pool: pool} }
+
+const keyColumns = `id, client_id, wrapped_key, status, created_at, retired_at`
+
+// Active implements KeyStore.
+func (s *PgKeyStore) Active(ctx context.Context, clientID string) (*DataKey, error) {
+	return s.one(ctx, `SELECT `+keyColumns+` FROM tnt_client_data_keys
+		WHERE client_id = $1 AND status = 'ACTIVE'`, clientID)
+}
+
+// ByID implements KeyStore.
+func (s *PgKeyStore) ByID(ctx context.Context, id string) (*DataKey, error) {
+	return s.one(ctx, `SELECT `+keyColumns+` FROM tnt_client_data_keys WHERE id = $1`, id)
+}
+
+// Create implements KeyStore. The partial unique index on ACTIVE keys makes
+// concurrent first-writes safe: the loser's insert is a no-op and both read
+// back the winner.
+func (s *PgKeyStore) Create(ctx context.Context, clientID, wrapped string) (*DataKey, error) {
+	if _, err := s.pool.Exec(ctx,
+		`INSERT INTO tnt_client_data_keys (id, client_id, wrapped_key, status, created_at)
+		 VALUES ($1, $2, $3, 'ACTIVE', NOW())
+		 ON CONFLICT (client_id) WHERE status = 'ACTIVE' DO NOTHING`,
+		tsid.Generate(tsid.ClientDataKey), clientID, wrapped); err != nil {
+		return nil, err
+	}
+	k, err := s.Active(ctx, clientID)
+	if err != nil {
+		return nil, err
+	}
+	if k == nil {
+		return nil, errors.New("active data key vanished after create")
+	}
+	return k, nil
+}
+
+// Rotate implements KeyStore.
+func (s *PgKeyStore) Rotate(ctx context.Context, clientID, wrapped string) (*DataKey, error) {
+	tx, err := s.pool.Begin(ctx)
+	if err != nil {
+		return nil, err
+	}
+	defer func() { _ = tx.Rollback(ctx) }()
+	if _, err := tx.Exec(ctx,
+		`UPDATE tnt_client_data_keys SET status = 'RETIRED', retired_at = NOW()
+		  WHERE client_id = $1 AND status = 'ACTIVE'`, clientID); err != nil {
+		return nil, err
+	}
+	var k DataKey
+	if err := tx.QueryRow(ctx,
+		`INSERT INTO tnt_client_data_keys (id, client_id, wrapped_key, status, created_at)
+		 VALUES ($1, $2, $3, 'ACTIVE', NOW())
+		 RETURNING `+keyColumns,
+		tsid.Generate(tsid.ClientDataKey), clientID, wrapped).Scan(
+		&k.ID, &k.ClientID, &k.WrappedKey, &k.Status, &k.CreatedAt, &k.RetiredAt); err != nil {
+		return nil, err
+	}
+	if err := tx.Commit(ctx); err != nil {
+		return nil, err
+	}
+	return &k, nil
+}
+
+// List implements KeyStore.
+func (s *PgKeyStore) List(ctx context.Context, clientID string) ([]DataKey, error) {
+	rows, err := s.pool.Query(ctx, `SELECT `+keyColumns+` FROM tnt_client_data_keys
+		WHERE client_id = $1 ORDER BY created_at DESC`, clientID)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var out []DataKey
+	for rows.Next() {
+		var k DataKey
+		if err := rows.Scan(&k.ID, &k.ClientID, &k.WrappedKey, &k.Status, &k.CreatedAt, &k.RetiredAt); err != nil {
+			return nil, err
+		}
+		out = append(out, k)
+	}
+	return out, rows.Err()
+}
+
+// DestroyClient deletes every key a client has, active or retired. This is
+// crypto-shredding: payloads sealed under those keys — including copies in
+// backups and replicas — can no longer be opened. Processes that still
+// cache a key keep it until restart; callers redact the live rows as well.
+// Not part of KeyStore: only whole-client erasure uses it.
+func (s *PgKeyStore) DestroyClient(ctx context.Context, clientID string) (int64, error) {
+	tag, err := s.pool.Exec(ctx, `DELETE FROM tnt_client_data_keys WHERE client_id = $1`, clientID)
+	if err != nil {
+		return 0, err
+	}
+	return tag.RowsAffected(), nil
+}
+
+func (s *PgKeyStore) one(ctx context.Context, sql string, arg string) (*DataKey, error) {
+	var k DataKey
+	err := s.pool.QueryRow(ctx, sql, arg).Scan(&k.ID, &k.ClientID, &k.WrappedKey, &k.Status, &k.CreatedAt, &k.RetiredAt)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	return &k, nil
+}
@@ -0,0 +1,375 @@
+// Package payloadcrypt is the optional envelope encryption for tenant
+// payloads at rest: msg_events.data (and the msg_events_read copy the
+// projection makes) and msg_dispatch_jobs.payload.
+//
+// Each opted-in client gets its own AES-256-GCM data key, stored wrapped
+// with the platform app key (encryption.Service / FLOWCATALYST_APP_KEY) in
+// tnt_client_data_keys. A sealed payload replaces the plaintext JSON with an
+// envelope object that is itself valid JSON, so it fits the existing jsonb
+// and text columns unchanged:
+//
+//	{"fcenc":1,"cid":"<clientId>","kid":"<dataKeyId>","ct":"<base64(nonce||ciphertext)>"}
+//
+// The client and key IDs are bound into the GCM additional data, so an
+// envelope cannot be replayed under another tenant's row. Because the
+// envelope is opaque JSON, the read projection and the stream fan-out copy
+// it verbatim — it is sealed once on ingest and opened only where a payload
+// leaves the platform (read APIs, webhook delivery).
+//
+// Rotation retires a client's active key and mints a fresh one; retired
+// keys keep decrypting until Migrator re-seals the rows that use them.
+package payloadcrypt
+
+import (
+	"bytes"
+	"context"
+	"crypto/aes"
+	"crypto/cipher"
+	"crypto/rand"
+	"encoding/base64"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+)
+
+// envelopeVersion is the "fcenc" marker of the current envelope format.
+const envelopeVersion = 1
+
+// activeKeyTTL bounds how long an instance keeps sealing with a cached
+// active key, so a rotation performed elsewhere is picked up without a
+// restart. Retired keys still decrypt, so a stale window is harmless.
+const activeKeyTTL = 5 * time.Minute
+
+// ErrNoCipher is returned when an encrypted payload is read by a process
+// that has no Cipher configured (FLOWCATALYST_APP_KEY unset).
+var ErrNoCipher = errors.New("payloadcrypt: payload is encrypted but no payload cipher is configured")
+
+// Config configures a Cipher.
+type Config struct {
+	// Keys persists the per-client data keys. Required.
+	Keys KeyStore
+	// KEK wraps data keys at rest. Required.
+	KEK *encryption.Service
+	// Clients lists the client IDs whose payloads are sealed. AllClients
+	// seals every client-scoped payload instead. Platform-scoped payloads
+	// (no client) are never sealed.
+	Clients    []string
+	AllClients bool
+}
+
+// Cipher seals and opens payloads. A nil *Cipher is valid: it passes
+// plaintext through on Seal and fails Open only for sealed payloads.
+type Cipher struct {
+	keys       KeyStore
+	kek        *encryption.Service
+	clients    map[string]bool
+	allClients bool
+	now        func() time.Time
+
+	mu     sync.Mutex
+	active map[string]activeKey   // clientID → current sealing key
+	byID   map[string]cipher.AEAD // dataKeyID → key (active or retired)
+}
+
+type activeKey struct {
+	id       string
+	aead     cipher.AEAD
+	loadedAt time.Time
+}
+
+// envelope is the stored shape of a sealed payload.
+type envelope struct {
+	Version    int    `json:"fcenc"`
+	ClientID   string `json:"cid"`
+	KeyID      string `json:"kid"`
+	Ciphertext string `json:"ct"`
+}
+
+// New builds a Cipher.
+func New(cfg Config) (*Cipher, error) {
+	if cfg.Keys == nil {
+		return nil, errors.New("payloadcrypt: key store is required")
+	}
+	if cfg.KEK == nil {
+		return nil, errors.New("payloadcrypt: key-encryption key is required (FLOWCATALYST_APP_KEY)")
+	}
+	c := &Cipher{
+		keys:       cfg.Keys,
+		kek:        cfg.KEK,
+		clients:    make(map[string]bool, len(cfg.Clients)),
+		allClients: cfg.AllClients,
+		now:        time.Now,
+		active:     map[string]activeKey{},
+		byID:       map[string]cipher.AEAD{},
+	}
+	for _, id := range cfg.Clients {
+		c.clients[id] = true
+	}
+	return c, nil
+}
+
+// Encrypts reports whether payloads for clientID are sealed on write.
+func (c *Cipher) Encrypts(clientID *string) bool {
+	if c == nil || clientID == nil || *clientID == "" {
+		return false
+	}
+	return c.allClients || c.clients[*clientID]
+}
+
+// IsSealed reports whether stored is a payload envelope.
+func IsSealed(stored []byte) bool {
+	_, ok := parseEnvelope(stored)
+	return ok
+}
+
+// Seal encrypts plaintext under clientID's active data key, minting the
+// client's first key on demand. Payloads for clients that aren't opted in
+// are returned unchanged. Input that merely looks like an envelope is
+// sealed like any other: at ingest it is producer-controlled, and storing
+// it as is would leave a row that fails Open on every read. Stored values
+// go through Reseal instead.
+func (c *Cipher) Seal(ctx context.Context, clientID *string, plaintext []byte) ([]byte, error) {
+	if !c.Encrypts(clientID) {
+		return plaintext, nil
+	}
+	key, err := c.activeKey(ctx, *clientID)
+	if err != nil {
+		return nil, err
+	}
+	nonce := make([]byte, key.aead.NonceSize())
+	if _, err := rand.Read(nonce); err != nil {
+		return nil, fmt.Errorf("payloadcrypt: read nonce: %w", err)
+	}
+	ct := key.aead.Seal(nonce, nonce, plaintext, additionalData(*clientID, key.id))
+	return json.Marshal(envelope{
+		Version:    envelopeVersion,
+		ClientID:   *clientID,
+		KeyID:      key.id,
+		Ciphertext: base64.StdEncoding.EncodeToString(ct),
+	})
+}
+
+// SealString is Seal for text columns (dispatch-job payloads).
+func (c *Cipher) SealString(ctx context.Context, clientID *string, plaintext *string) (*string, error) {
+	if plaintext == nil || !c.Encrypts(clientID) {
+		return plaintext, nil
+	}
+	out, err := c.Seal(ctx, clientID, []byte(*plaintext))
+	if err != nil {
+		return nil, err
+	}
+	s := string(out)
+	return &s, nil
+}
+
+// Open returns the plaintext of a sealed payload; anything else (legacy
+// plaintext rows, clients that aren't opted in) is returned unchanged.
+// clientID is the owning row's client: an envelope sealed for any other
+// client is not this row's ciphertext and comes back as the opaque bytes it
+// is, so a copied envelope can never be decrypted through another tenant.
+func (c *Cipher) Open(ctx context.Context, clientID *string, stored []byte) ([]byte, error) {
+	env, ok := parseEnvelope(stored)
+	if !ok || clientID == nil || *clientID != env.ClientID {
+		return stored, nil
+	}
+	if c == nil {
+		return nil, ErrNoCipher
+	}
+	aead, err := c.keyByID(ctx, env.KeyID, env.ClientID)
+	if err != nil {
+		return nil, err
+	}
+	raw, err := base64.StdEncoding.DecodeString(env.Ciphertext)
+	if err != nil {
+		return nil, fmt.Errorf("payloadcrypt: invalid ciphertext encoding: %w", err)
+	}
+	if len(raw) < aead.NonceSize() {
+		return nil, errors.New("payloadcrypt: ciphertext too short")
+	}
+	pt, err := aead.Open(nil, raw[:aead.NonceSize()], raw[aead.NonceSize():], additionalData(env.ClientID, env.KeyID))
+	if err != nil {
+		return nil, errors.New("payloadcrypt: payload authentication failed")
+	}
+	return pt, nil
+}
+
+// OpenString is Open for text columns.
+func (c *Cipher) OpenString(ctx context.Context, clientID *string, stored *string) (*string, error) {
+	if stored == nil {
+		return nil, nil
+	}
+	out, err := c.Open(ctx, clientID, []byte(*stored))
+	if err != nil {
+		return nil, err
+	}
+	s := string(out)
+	return &s, nil
+}
+
+// NeedsReseal reports whether a stored payload for clientID should be
+// rewritten by the migration job: plaintext for an opted-in client, or an
+// envelope sealed under a key other than the client's active one.
+func (c *Cipher) NeedsReseal(ctx context.Context, clientID *string, stored []byte) (bool, error) {
+	if !c.Encrypts(clientID) {
+		return false, nil
+	}
+	env, ok := parseEnvelope(stored)
+	if !ok || env.ClientID != *clientID {
+		return true, nil
+	}
+	key, err := c.activeKey(ctx, *clientID)
+	if err != nil {
+		return false, err
+	}
+	return env.KeyID != key.id, nil
+}
+
+// Reseal opens stored (if sealed) and seals it again under the active key.
+func (c *Cipher) Reseal(ctx context.Context, clientID *string, stored []byte) ([]byte, error) {
+	pt, err := c.Open(ctx, clientID, stored)
+	if err != nil {
+		return nil, err
+	}
+	return c.Seal(ctx, clientID, pt)
+}
+
+// Rotate retires clientID's active data key (if any) and mints a new one.
+// Payloads sealed under the retired key keep opening; run the Migrator to
+// re-seal them. Returns the new key's ID.
+func (c *Cipher) Rotate(ctx context.Context, clientID string) (string, error) {
+	raw, wrapped, err := c.newWrappedKey()
+	if err != nil {
+		return "", err
+	}
+	k, err := c.keys.Rotate(ctx, clientID, wrapped)
+	if err != nil {
+		return "", fmt.Errorf("payloadcrypt: rotate key: %w", err)
+	}
+	aead, err := newAEAD(raw)
+	if err != nil {
+		return "", err
+	}
+	c.mu.Lock()
+	c.active[clientID] = activeKey{id: k.ID, aead: aead, loadedAt: c.now()}
+	c.byID[k.ID] = aead
+	c.mu.Unlock()
+	return k.ID, nil
+}
+
+func (c *Cipher) activeKey(ctx context.Context, clientID string) (activeKey, error) {
+	c.mu.Lock()
+	cached, ok := c.active[clientID]
+	c.mu.Unlock()
+	if ok && c.now().Sub(cached.loadedAt) < activeKeyTTL {
+		return cached, nil
+	}
+
+	k, err := c.keys.Active(ctx, clientID)
+	if err != nil {
+		return activeKey{}, fmt.Errorf("payloadcrypt: load active key: %w", err)
+	}
+	if k == nil {
+		// First payload for this client. Create is race-safe: a concurrent
+		// creator's key wins and is returned to both.
+		_, wrapped, werr := c.newWrappedKey()
+		if werr != nil {
+			return activeKey{}, werr
+		}
+		if k, err = c.keys.Create(ctx, clientID, wrapped); err != nil {
+			return activeKey{}, fmt.Errorf("payloadcrypt: create key: %w", err)
+		}
+	}
+	aead, err := c.unwrap(k)
+	if err != nil {
+		return activeKey{}, err
+	}
+	out := activeKey{id: k.ID, aead: aead, loadedAt: c.now()}
+	c.mu.Lock()
+	c.active[clientID] = out
+	c.byID[k.ID] = aead
+	c.mu.Unlock()
+	return out, nil
+}
+
+func (c *Cipher) keyByID(ctx context.Context, id, clientID string) (cipher.AEAD, error) {
+	c.mu.Lock()
+	aead, ok := c.byID[id]
+	c.mu.Unlock()
+	if ok {
+		return aead, nil
+	}
+	k, err := c.keys.ByID(ctx, id)
+	if err != nil {
+		return nil, fmt.Errorf("payloadcrypt: load key %s: %w", id, err)
+	}
+	if k == nil || k.ClientID != clientID {
+		return nil, fmt.Errorf("payloadcrypt: data key %s not found for client %s", id, clientID)
+	}
+	if aead, err = c.unwrap(k); err != nil {
+		return nil, err
+	}
+	c.mu.Lock()
+	c.byID[id] = aead
+	c.mu.Unlock()
+	return aead, nil
+}
+
+// newWrappedKey returns a fresh raw data key and its KEK-wrapped form.
+func (c *Cipher) newWrappedKey() ([]byte, string, error) {
+	raw := make([]byte, 32)
+	if _, err := rand.Read(raw); err != nil {
+		return nil, "", fmt.Errorf("payloadcrypt: generate key: %w", err)
+	}
+	wrapped, err := c.kek.Encrypt(base64.StdEncoding.EncodeToString(raw))
+	if err != nil {
+		return nil, "", fmt.Errorf("payloadcrypt: wrap key: %w", err)
+	}
+	return raw, wrapped, nil
+}
+
+func (c *Cipher) unwrap(k *DataKey) (cipher.AEAD, error) {
+	b64, err := c.kek.Decrypt(k.WrappedKey)
+	if err != nil {
+		return nil, fmt.Errorf("payloadcrypt: unwrap key %s: %w", k.ID, err)
+	}
+	raw, err := base64.StdEncoding.DecodeString(b64)
+	if err != nil {
+		return nil, fmt.Errorf("payloadcrypt: unwrap key %s: %w", k.ID, err)
+	}
+	return newAEAD(raw)
+}
+
+func newAEAD(raw []byte) (cipher.AEAD, error) {
+	if len(raw) != 32 {
+		return nil, fmt.Errorf("payloadcrypt: data key must be 32 bytes, got %d", len(raw))
+	}
+	block, err := aes.NewCipher(raw)
+	if err != nil {
+		return nil, fmt.Errorf("payloadcrypt: aes cipher: %w", err)
+	}
+	return cipher.NewGCM(block)
+}
+
+func additionalData(clientID, keyID string) []byte {
+	return []byte(clientID + "\x00" + keyID)
+}
+
+// parseEnvelope recognises a sealed payload. The substring probe keeps the
+// common (plaintext) case from paying for a JSON decode.
+func parseEnvelope(stored []byte) (envelope, bool) {
+	if !bytes.Contains(stored, []byte(`"fcenc"`)) {
+		return envelope{}, false
+	}
+	var env envelope
+	if err := json.Unmarshal(stored, &env); err != nil {
+		return envelope{}, false
+	}
+	if env.Version != envelopeVersion || env.ClientID == "" || env.KeyID == "" || env.Ciphertext == "" {
+		return envelope{}, false
+	}
+	return env, true
+}
@@ -0,0 +1,197 @@
+package payloadcrypt
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+)
+
+// memKeys is an in-memory KeyStore.
+type memKeys struct {
+	mu   sync.Mutex
+	keys []DataKey
+}
+
+func (m *memKeys) Active(_ context.Context, clientID string) (*DataKey, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	for i := range m.keys {
+		if m.keys[i].ClientID == clientID && m.keys[i].Status == StatusActive {
+			k := m.keys[i]
+			return &k, nil
+		}
+	}
+	return nil, nil
+}
+
+func (m *memKeys) ByID(_ context.Context, id string) (*DataKey, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	for i := range m.keys {
+		if m.keys[i].ID == id {
+			k := m.keys[i]
+			return &k, nil
+		}
+	}
+	return nil, nil
+}
+
+func (m *memKeys) Create(ctx context.Context, clientID, wrapped string) (*DataKey, error) {
+	if k, _ := m.Active(ctx, clientID); k != nil {
+		return k, nil
+	}
+	m.mu.Lock()
+	k := DataKey{ID: fmt.Sprintf("cdk_%d", len(m.keys)), ClientID: clientID, WrappedKey: wrapped, Status: StatusActive, CreatedAt: time.Now()}
+	m.keys = append(m.keys, k)
+	m.mu.Unlock()
+	return &k, nil
+}
+
+func (m *memKeys) Rotate(_ context.Context, clientID, wrapped string) (*DataKey, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	for i := range m.keys {
+		if m.keys[i].ClientID == clientID && m.keys[i].Status == StatusActive {
+			m.keys[i].Status = StatusRetired
+		}
+	}
+	k := DataKey{ID: fmt.Sprintf("cdk_%d", len(m.keys)), ClientID: clientID, WrappedKey: wrapped, Status: StatusActive, CreatedAt: time.Now()}
+	m.keys = append(m.keys, k)
+	return &k, nil
+}
+
+func (m *memKeys) List(_ context.Context, clientID string) ([]DataKey, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	var out []DataKey
+	for _, k := range m.keys {
+		if k.ClientID == clientID {
+			out = append(out, k)
+		}
+	}
+	return out, nil
+}
+
+func newCipher(t *testing.T, keys KeyStore, clients ...string) *Cipher {
+	t.Helper()
+	kek, err := encryption.GenerateKey()
+	require.NoError(t, err)
+	svc, err := encryption.New(kek)
+	require.NoError(t, err)
+	c, err := New(Config{Keys: keys, KEK: svc, Clients: clients})
+	require.NoError(t, err)
+	return c
+}
+
+func ptr(s string) *string { return &s }
+
+func TestSealOpen_RoundTrip(t *testing.T) {
+	ctx := context.Background()
+	c := newCipher(t, &memKeys{}, "clt_A")
+	plain := []byte(`{"email":"alice@example.com"}`)
+
+	sealed, err := c.Seal(ctx, ptr("clt_A"), plain)
+	require.NoError(t, err)
+	assert.True(t, IsSealed(sealed))
+	assert.NotContains(t, string(sealed), "alice")
+	assert.True(t, json.Valid(sealed), "envelope must fit a jsonb column")
+
+	opened, err := c.Open(ctx, ptr("clt_A"), sealed)
+	require.NoError(t, err)
+	assert.Equal(t, plain, opened)
+}
+
+func TestSeal_ForgedEnvelopeIsSealed(t *testing.T) {
+	ctx := context.Background()
+	c := newCipher(t, &memKeys{}, "clt_A")
+	forged := []byte(`{"fcenc":1,"cid":"clt_A","kid":"x","ct":"AAAA"}`)
+
+	sealed, err := c.Seal(ctx, ptr("clt_A"), forged)
+	require.NoError(t, err)
+	assert.NotEqual(t, forged, sealed, "a producer-supplied envelope is not trusted")
+
+	opened, err := c.Open(ctx, ptr("clt_A"), sealed)
+	require.NoError(t, err)
+	assert.Equal(t, forged, opened)
+}
+
+func TestSeal_PassThroughForOtherClients(t *testing.T) {
+	ctx := context.Background()
+	c := newCipher(t, &memKeys{}, "clt_A")
+	plain := []byte(`{"x":1}`)
+
+	out, err := c.Seal(ctx, ptr("clt_B"), plain)
+	require.NoError(t, err)
+	assert.Equal(t, plain, out)
+	out, err = c.Seal(ctx, nil, plain)
+	require.NoError(t, err)
+	assert.Equal(t, plain, out, "platform-scoped payloads are never sealed")
+
+	var nilCipher *Cipher
+	out, err = nilCipher.Seal(ctx, ptr("clt_A"), plain)
+	require.NoError(t, err)
+	assert.Equal(t, plain, out)
+}
+
+func TestOpen_ForeignEnvelopeIsOpaque(t *testing.T) {
+	ctx := context.Background()
+	c := newCipher(t, &memKeys{}, "clt_A", "clt_B")
+	sealed, err := c.Seal(ctx, ptr("clt_A"), []byte(`{"secret":true}`))
+	require.NoError(t, err)
+
+	out, err := c.Open(ctx, ptr("clt_B"), sealed)
+	require.NoError(t, err)
+	assert.Equal(t, sealed, out, "another tenant's envelope is never decrypted")
+
+	var nilCipher *Cipher
+	_, err = nilCipher.Open(ctx, ptr("clt_A"), sealed)
+	assert.ErrorIs(t, err, ErrNoCipher)
+}
+
+func TestRotate_OldPayloadsOpenAndNeedReseal(t *testing.T) {
+	ctx := context.Background()
+	keys := &memKeys{}
+	c := newCipher(t, keys, "clt_A")
+	plain := []byte(`{"n":42}`)
+	old, err := c.Seal(ctx, ptr("clt_A"), plain)
+	require.NoError(t, err)
+
+	need, err := c.NeedsReseal(ctx, ptr("clt_A"), old)
+	require.NoError(t, err)
+	assert.False(t, need)
+
+	_, err = c.Rotate(ctx, "clt_A")
+	require.NoError(t, err)
+
+	opened, err := c.Open(ctx, ptr("clt_A"), old)
+	require.NoError(t, err)
+	assert.Equal(t, plain, opened, "retired keys keep decrypting")
+
+	need, err = c.NeedsReseal(ctx, ptr("clt_A"), old)
+	require.NoError(t, err)
+	assert.True(t, need)
+	resealed, err := c.Reseal(ctx, ptr("clt_A"), old)
+	require.NoError(t, err)
+	need, err = c.NeedsReseal(ctx, ptr("clt_A"), resealed)
+	require.NoError(t, err)
+	assert.False(t, need)
+
+	need, err = c.NeedsReseal(ctx, ptr("clt_A"), plain)
+	require.NoError(t, err)
+	assert.True(t, need, "plaintext written before opt-in needs sealing")
+
+	// A second instance (fresh cache) reads the same keys from the store.
+	other, err := New(Config{Keys: keys, KEK: c.kek, Clients: []string{"clt_A"}})
+	require.NoError(t, err)
+	opened, err = other.Open(ctx, ptr("clt_A"), resealed)
+	require.NoError(t, err)
+	assert.Equal(t, plain, opened)
+}
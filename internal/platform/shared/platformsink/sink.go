@@ -34,6 +34,14 @@ func New() *Sink { return &Sink{} }
 // Compile-time check that *Sink satisfies usecasepgx.Sink.
 var _ usecasepgx.Sink = (*Sink)(nil)
 
+// ClientScoped is implemented by events written on behalf of one client.
+// The row's client_id is what the stream fan-out matches client-scoped
+// subscriptions against; an event without one is only seen by
+// platform-wide subscriptions.
+type ClientScoped interface {
+	ClientID() *string
+}
+
 // WriteEvent inserts the domain event into msg_events. The shape of
 // the row matches the Rust fc-platform PgUnitOfWork::persist_event.
 func (*Sink) WriteEvent(ctx context.Context, tx *usecasepgx.DbTx, event usecase.DomainEvent) error {
@@ -55,6 +63,11 @@ func (*Sink) WriteEvent(ctx context.Context, tx *usecasepgx.DbTx, event usecase.
 
 	dedupID := event.EventType() + "-" + event.EventID()
 
+	var clientID *string
+	if cs, ok := event.(ClientScoped); ok {
+		clientID = cs.ClientID()
+	}
+
 	now := time.Now().UTC()
 	// No ON CONFLICT here — msg_events is partitioned by created_at and
 	// the dedup unique index is composite (deduplication_id, created_at),
@@ -71,7 +84,7 @@ func (*Sink) WriteEvent(ctx context.Context, tx *usecasepgx.DbTx, event usecase.
 		event.EventID(), event.SpecVersion(), event.EventType(),
 		event.Source(), event.Subject(),
 		eventTime(event), data, nullIfEmpty(event.CorrelationID()), nullIfEmpty(event.CausationID()),
-		dedupID, nullIfEmpty(event.MessageGroup()), nil,
+		dedupID, nullIfEmpty(event.MessageGroup()), clientID,
 		contextData, now,
 	)
 	if err != nil {
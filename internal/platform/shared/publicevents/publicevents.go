@@ -0,0 +1,144 @@
+// Package publicevents republishes changes to customer-owned platform
+// configuration as first-class event types under flowcatalyst:*.
+//
+// The platform's own domain events (platform:admin:subscription:created,
+// platform:iam:serviceaccount:token-regenerated, ...) are internal: they
+// land in msg_events with no client, so only platform-wide subscriptions
+// can see them, and their codes are free to change with the platform.
+// For the changes a customer cares about — their subscriptions, dispatch
+// pools, connections, service accounts and signing keys — Sink writes a
+// second event in the same transaction, with a stable public code and the
+// owning client stamped on the row. The stream fan-out then delivers it
+// like any other event, so a client can subscribe to meta-events about its
+// own configuration and never sees another client's.
+package publicevents
+
+import (
+	"context"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Type is one public event type and the internal event it mirrors.
+type Type struct {
+	Code     string // flowcatalyst:{subdomain}:{aggregate}:{event}
+	Name     string
+	Internal string // the platform event type republished under Code
+}
+
+// types is the public catalogue. Codes are a customer contract: add to
+// it freely, but never rename or drop an entry.
+var types = []Type{
+	{"flowcatalyst:config:subscription:created", "Subscription Created", "platform:admin:subscription:created"},
+	{"flowcatalyst:config:subscription:updated", "Subscription Updated", "platform:admin:subscription:updated"},
+	{"flowcatalyst:config:subscription:paused", "Subscription Paused", "platform:admin:subscription:paused"},
+	{"flowcatalyst:config:subscription:resumed", "Subscription Resumed", "platform:admin:subscription:resumed"},
+	{"flowcatalyst:config:subscription:deleted", "Subscription Deleted", "platform:admin:subscription:deleted"},
+
+	{"flowcatalyst:config:dispatch-pool:created", "Dispatch Pool Created", "platform:admin:dispatch-pool:created"},
+	{"flowcatalyst:config:dispatch-pool:updated", "Dispatch Pool Updated", "platform:admin:dispatch-pool:updated"},
+	{"flowcatalyst:config:dispatch-pool:suspended", "Dispatch Pool Suspended", "platform:admin:dispatch-pool:suspended"},
+	{"flowcatalyst:config:dispatch-pool:activated", "Dispatch Pool Activated", "platform:admin:dispatch-pool:activated"},
+	{"flowcatalyst:config:dispatch-pool:archived", "Dispatch Pool Archived", "platform:admin:dispatch-pool:archived"},
+	{"flowcatalyst:config:dispatch-pool:deleted", "Dispatch Pool Deleted", "platform:admin:dispatch-pool:deleted"},
+
+	{"flowcatalyst:config:connection:created", "Connection Created", "platform:admin:connection:created"},
+	{"flowcatalyst:config:connection:updated", "Connection Updated", "platform:admin:connection:updated"},
+	{"flowcatalyst:config:connection:deleted", "Connection Deleted", "platform:admin:connection:deleted"},
+
+	{"flowcatalyst:iam:service-account:created", "Service Account Created", "platform:iam:serviceaccount:created"},
+	{"flowcatalyst:iam:service-account:updated", "Service Account Updated", "platform:iam:serviceaccount:updated"},
+	{"flowcatalyst:iam:service-account:roles-assigned", "Service Account Roles Assigned", "platform:iam:serviceaccount:roles-assigned"},
+	{"flowcatalyst:iam:service-account:token-rotated", "Service Account Token Rotated", "platform:iam:serviceaccount:token-regenerated"},
+	{"flowcatalyst:iam:service-account:secret-rotated", "Service Account Secret Rotated", "platform:iam:serviceaccount:secret-regenerated"},
+	{"flowcatalyst:iam:service-account:deleted", "Service Account Deleted", "platform:iam:serviceaccount:deleted"},
+
+	{"flowcatalyst:iam:signing-key:rotated", "Signing Key Rotated", "platform:admin:signing-key:rotated"},
+	{"flowcatalyst:iam:signing-key:expired", "Signing Key Expired", "platform:admin:signing-key:expired"},
+}
+
+var byInternal = func() map[string]string {
+	m := make(map[string]string, len(types))
+	for _, t := range types {
+		m[t.Internal] = t.Code
+	}
+	return m
+}()
+
+// Types returns the public catalogue, for seeding into msg_event_types.
+func Types() []Type {
+	return append([]Type(nil), types...)
+}
+
+// ClientOwned is implemented by platform events whose aggregate belongs
+// to clients. The public copy is written once per owning client; an event
+// with no owners (a platform-wide resource) is written once, unscoped.
+type ClientOwned interface {
+	OwningClientIDs() []string
+}
+
+// Sink wraps inner so every catalogued platform event is also written as
+// its public type. The copies go through inner.WriteEvent in the same
+// transaction, so they commit or roll back with the change itself; the
+// audit row is written once, for the internal event.
+func Sink(inner usecasepgx.Sink) usecasepgx.Sink {
+	return &publishingSink{Sink: inner}
+}
+
+type publishingSink struct {
+	usecasepgx.Sink
+}
+
+func (s *publishingSink) WriteEvent(ctx context.Context, tx *usecasepgx.DbTx, event usecase.DomainEvent) error {
+	if err := s.Sink.WriteEvent(ctx, tx, event); err != nil {
+		return err
+	}
+	for _, pub := range publish(event) {
+		if err := s.Sink.WriteEvent(ctx, tx, pub); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// publish builds the public copies of event: none for an uncatalogued
+// type, otherwise one per owning client.
+func publish(event usecase.DomainEvent) []usecase.DomainEvent {
+	code, ok := byInternal[event.EventType()]
+	if !ok {
+		return nil
+	}
+	var owners []string
+	if co, ok := event.(ClientOwned); ok {
+		owners = co.OwningClientIDs()
+	}
+	if len(owners) == 0 {
+		return []usecase.DomainEvent{newPublicEvent(event, code, nil)}
+	}
+	out := make([]usecase.DomainEvent, 0, len(owners))
+	for _, c := range owners {
+		out = append(out, newPublicEvent(event, code, &c))
+	}
+	return out
+}
+
+// publicEvent is the customer-facing copy of a platform event. It carries
+// the same subject, time, message group and data; it gets its own id, and
+// names the internal event as its cause.
+type publicEvent struct {
+	usecase.DomainEvent
+	id       string
+	code     string
+	clientID *string
+}
+
+func newPublicEvent(inner usecase.DomainEvent, code string, clientID *string) publicEvent {
+	return publicEvent{DomainEvent: inner, id: tsid.Generate(tsid.Event), code: code, clientID: clientID}
+}
+
+func (e publicEvent) EventID() string     { return e.id }
+func (e publicEvent) EventType() string   { return e.code }
+func (e publicEvent) CausationID() string { return e.DomainEvent.EventID() }
+func (e publicEvent) ClientID() *string   { return e.clientID }
@@ -0,0 +1,93 @@
+package publicevents
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	connectionops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection/operations"
+	poolops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool/operations"
+	saops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/platformsink"
+	subscriptionops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+// Every aggregate republished here must say who owns it, or its public
+// copies would only ever reach platform-wide subscriptions.
+var (
+	_ ClientOwned = subscriptionops.SubscriptionCreated{}
+	_ ClientOwned = poolops.DispatchPoolArchived{}
+	_ ClientOwned = connectionops.ConnectionDeleted{}
+	_ ClientOwned = saops.ServiceAccountTokenRegenerated{}
+
+	_ platformsink.ClientScoped = publicEvent{}
+)
+
+func TestCatalogueShape(t *testing.T) {
+	internal := map[string]bool{}
+	for _, ty := range Types() {
+		if !strings.HasPrefix(ty.Code, "flowcatalyst:") || strings.Count(ty.Code, ":") != 3 {
+			t.Errorf("%s: want flowcatalyst:{subdomain}:{aggregate}:{event}", ty.Code)
+		}
+		if internal[ty.Internal] {
+			t.Errorf("%s republished twice", ty.Internal)
+		}
+		internal[ty.Internal] = true
+	}
+}
+
+type fakeEvent struct {
+	typ    string
+	owners []string
+}
+
+func (e fakeEvent) EventID() string             { return "evn_inner" }
+func (e fakeEvent) EventType() string           { return e.typ }
+func (e fakeEvent) SpecVersion() string         { return "1.0" }
+func (e fakeEvent) Source() string              { return "platform:admin" }
+func (e fakeEvent) Subject() string             { return "platform.subscription.sub_1" }
+func (e fakeEvent) Time() time.Time             { return time.Time{} }
+func (e fakeEvent) PrincipalID() string         { return "prn_1" }
+func (e fakeEvent) CorrelationID() string       { return "cor_1" }
+func (e fakeEvent) CausationID() string         { return "" }
+func (e fakeEvent) ExecutionID() string         { return "" }
+func (e fakeEvent) MessageGroup() string        { return "platform:subscription:sub_1" }
+func (e fakeEvent) ToDataJSON() ([]byte, error) { return []byte(`{}`), nil }
+func (e fakeEvent) OwningClientIDs() []string   { return e.owners }
+
+var _ usecase.DomainEvent = fakeEvent{}
+
+func TestPublish(t *testing.T) {
+	if got := publish(fakeEvent{typ: "platform:admin:subscription:synced"}); len(got) != 0 {
+		t.Errorf("uncatalogued type published %d copies", len(got))
+	}
+
+	unscoped := publish(fakeEvent{typ: subscriptionops.SubscriptionCreatedType})
+	if len(unscoped) != 1 || unscoped[0].(publicEvent).ClientID() != nil {
+		t.Fatalf("platform-wide resource: want one unscoped copy, got %+v", unscoped)
+	}
+
+	copies := publish(fakeEvent{typ: subscriptionops.SubscriptionCreatedType, owners: []string{"clt_a", "clt_b"}})
+	if len(copies) != 2 {
+		t.Fatalf("want one copy per owner, got %d", len(copies))
+	}
+	for i, want := range []string{"clt_a", "clt_b"} {
+		pub := copies[i].(publicEvent)
+		if c := pub.ClientID(); c == nil || *c != want {
+			t.Errorf("copy %d: client = %v, want %s", i, c, want)
+		}
+		if pub.EventType() != "flowcatalyst:config:subscription:created" {
+			t.Errorf("copy %d: type = %s", i, pub.EventType())
+		}
+		if pub.EventID() == "evn_inner" || pub.CausationID() != "evn_inner" {
+			t.Errorf("copy %d: id %s, causation %s", i, pub.EventID(), pub.CausationID())
+		}
+		if pub.Subject() != "platform.subscription.sub_1" || pub.CorrelationID() != "cor_1" {
+			t.Errorf("copy %d should keep the inner subject and correlation", i)
+		}
+	}
+	if copies[0].EventID() == copies[1].EventID() {
+		t.Error("copies share an event id")
+	}
+}
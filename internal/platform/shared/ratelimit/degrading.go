@@ -0,0 +1,65 @@
+package ratelimit
+
+import (
+	"context"
+	"sync/atomic"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/degrade"
+)
+
+// redisRecheckInterval is how long a degraded store waits before trying
+// Redis again.
+const redisRecheckInterval = 10 * time.Second
+
+// DegradingStore fronts the Redis store with a degrade.Feature. While
+// Redis fails it applies the feature's policy — FailOpen answers from the
+// fallback store (Postgres), FailClosed rejects — and retries Redis once
+// per recheck interval, switching back on the first success.
+type DegradingStore struct {
+	primary  Store
+	fallback Store
+	feature  *degrade.Feature
+	recheck  time.Duration
+
+	retryAt atomic.Int64 // unix nanos; Redis is skipped until then
+}
+
+// NewDegradingStore wraps primary. A nil fallback makes FailOpen allow.
+func NewDegradingStore(primary, fallback Store, feature *degrade.Feature, recheck time.Duration) *DegradingStore {
+	if fallback == nil {
+		fallback = NoopStore{}
+	}
+	return &DegradingStore{primary: primary, fallback: fallback, feature: feature, recheck: recheck}
+}
+
+// CheckAndRecord asks Redis unless it failed within the recheck interval.
+func (s *DegradingStore) CheckAndRecord(ctx context.Context, bucket Bucket, key string, policy Policy) (Decision, error) {
+	if s.feature.Degraded() && time.Now().UnixNano() < s.retryAt.Load() {
+		return s.degraded(ctx, bucket, key, policy)
+	}
+	d, err := s.primary.CheckAndRecord(ctx, bucket, key, policy)
+	s.feature.Observe(err)
+	if err != nil {
+		s.markDown()
+		return s.degraded(ctx, bucket, key, policy)
+	}
+	return d, nil
+}
+
+// Prune prunes the fallback, which holds whatever was recorded while
+// Redis was down.
+func (s *DegradingStore) Prune(ctx context.Context, olderThan time.Duration) (int64, error) {
+	return s.fallback.Prune(ctx, olderThan)
+}
+
+func (s *DegradingStore) markDown() {
+	s.retryAt.Store(time.Now().Add(s.recheck).UnixNano())
+}
+
+func (s *DegradingStore) degraded(ctx context.Context, bucket Bucket, key string, policy Policy) (Decision, error) {
+	if s.feature.Policy() == degrade.FailClosed {
+		return Decision{Allowed: false, RetryAfterSecs: clampU32(int64(s.recheck.Seconds()))}, nil
+	}
+	return s.fallback.CheckAndRecord(ctx, bucket, key, policy)
+}
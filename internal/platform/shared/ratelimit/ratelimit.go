@@ -6,10 +6,13 @@
 // compose — see Governor in governor.go.
 //
 // Two backends implement one Store contract, selected at startup by
-// Build: Redis (fixed-window INCR+EXPIRE) when FC_REDIS_URL is reachable,
-// else Postgres (the iam_rate_limit_events table), else a Noop store when
-// FC_RATE_LIMIT_DISABLE=1. The rest of the platform depends only on the
-// Store interface and is indifferent to which backend won.
+// Build: Redis (fixed-window INCR+EXPIRE) when FC_REDIS_URL is set, else
+// Postgres (the iam_rate_limit_events table), else a Noop store when
+// FC_RATE_LIMIT_DISABLE=1. Redis sits behind a DegradingStore, so an
+// outage falls back to Postgres (or rejects, under a fail-closed
+// "rate-limit" policy) until Redis returns. The rest of the platform
+// depends only on the Store interface and is indifferent to which backend
+// won.
 package ratelimit
 
 import (
@@ -23,6 +26,7 @@ import (
 
 	"github.com/jackc/pgx/v5/pgxpool"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/degrade"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/envutil"
 )
 
@@ -115,21 +119,28 @@ func (NoopStore) CheckAndRecord(context.Context, Bucket, string, Policy) (Decisi
 // Prune is a no-op.
 func (NoopStore) Prune(context.Context, time.Duration) (int64, error) { return 0, nil }
 
-// Build selects the backend: Redis when FC_REDIS_URL is set and reachable
-// (PING within a short timeout), else Postgres, else Noop when
-// FC_RATE_LIMIT_DISABLE=1. The choice is logged at startup.
+// Build selects the backend: Redis when FC_REDIS_URL is set, else
+// Postgres, else Noop when FC_RATE_LIMIT_DISABLE=1. The choice is logged
+// at startup. A Redis unreachable at startup starts the store degraded
+// rather than giving up on it.
 func Build(ctx context.Context, pool *pgxpool.Pool) Store {
 	if os.Getenv("FC_RATE_LIMIT_DISABLE") == "1" {
 		slog.Info("distributed rate-limit store: DISABLED (FC_RATE_LIMIT_DISABLE=1)")
 		return NoopStore{}
 	}
 	if url := os.Getenv("FC_REDIS_URL"); url != "" {
-		s, err := NewRedisStore(ctx, url)
+		rs, err := newRedisStore(url)
 		if err == nil {
-			slog.Info("distributed rate-limit store: Redis", "redis_url", redactURL(url))
+			feature := degrade.Register("rate-limit", degrade.FailOpen, false)
+			s := NewDegradingStore(rs, NewPostgresStore(pool), feature, redisRecheckInterval)
+			if err := rs.ping(ctx); err != nil {
+				feature.Observe(err)
+				s.markDown()
+			}
+			slog.Info("distributed rate-limit store: Redis", "redis_url", redactURL(url), "degrade_policy", string(feature.Policy()))
 			return s
 		}
-		slog.Warn("FC_REDIS_URL set but Redis unreachable; falling back to Postgres rate-limit store", "err", err)
+		slog.Warn("FC_REDIS_URL is invalid; falling back to Postgres rate-limit store", "err", err)
 	} else {
 		slog.Info("FC_REDIS_URL not set; using Postgres rate-limit store")
 	}
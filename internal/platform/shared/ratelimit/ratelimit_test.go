@@ -7,6 +7,8 @@ import (
 	"net/http/httptest"
 	"testing"
 	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/degrade"
 )
 
 func TestPoliciesFromEnvDefaults(t *testing.T) {
@@ -151,3 +153,50 @@ func TestIPLimitMiddleware(t *testing.T) {
 		t.Error("no IP should pass through to next")
 	}
 }
+
+// countingStore records calls and returns a fixed decision/error.
+type countingStore struct {
+	calls int
+	fakeStore
+}
+
+func (c *countingStore) CheckAndRecord(ctx context.Context, b Bucket, k string, p Policy) (Decision, error) {
+	c.calls++
+	return c.fakeStore.CheckAndRecord(ctx, b, k, p)
+}
+
+func TestDegradingStore(t *testing.T) {
+	ctx := context.Background()
+	pol := Policy{time.Minute, 10}
+	for _, policy := range []degrade.Policy{degrade.FailOpen, degrade.FailClosed} {
+		redis := &countingStore{fakeStore: fakeStore{err: errors.New("connection refused")}}
+		pg := &countingStore{fakeStore: fakeStore{decision: Decision{Allowed: false, RetryAfterSecs: 3}}}
+		f := degrade.NewRegistry(nil).Register("rate-limit", policy, false)
+		s := NewDegradingStore(redis, pg, f, time.Hour)
+
+		// Redis fails: the policy answers, and Redis is not retried
+		// until the recheck interval passes.
+		for range 2 {
+			d, err := s.CheckAndRecord(ctx, BucketOAuthTokenIP, "k", pol)
+			if err != nil || d.Allowed {
+				t.Fatalf("%s: degraded decision = %+v err=%v", policy, d, err)
+			}
+			if policy == degrade.FailOpen && d.RetryAfterSecs != 3 {
+				t.Fatalf("open should answer from the fallback, got %+v", d)
+			}
+			if policy == degrade.FailClosed && d.RetryAfterSecs != 3600 {
+				t.Fatalf("closed should reject until the recheck, got %+v", d)
+			}
+		}
+		if redis.calls != 1 || !f.Degraded() {
+			t.Fatalf("%s: redis calls = %d, degraded = %v", policy, redis.calls, f.Degraded())
+		}
+
+		// Redis is back: the next call after the interval recovers.
+		redis.err, redis.decision = nil, Decision{Allowed: true}
+		s.retryAt.Store(0)
+		if d, err := s.CheckAndRecord(ctx, BucketOAuthTokenIP, "k", pol); err != nil || !d.Allowed || f.Degraded() {
+			t.Fatalf("%s: after recovery = %+v err=%v degraded=%v", policy, d, err, f.Degraded())
+		}
+	}
+}
@@ -8,6 +8,8 @@ import (
 	"time"
 
 	"github.com/redis/go-redis/v9"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/redisconn"
 )
 
 const redisConnectTimeout = 2 * time.Second
@@ -18,29 +20,44 @@ const redisConnectTimeout = 2 * time.Second
 // so the worst case is a 2× spike at the window boundary, acceptable for a
 // cluster-wide ceiling.
 type RedisStore struct {
-	client *redis.Client
+	client redis.UniversalClient
 }
 
 // NewRedisStore connects, PINGs to confirm liveness, and returns the
-// store. Returns an error (never panics) so Build can fall back to
+// store. Returns an error (never panics) so callers can fall back to
 // Postgres.
 func NewRedisStore(ctx context.Context, url string) (*RedisStore, error) {
-	opts, err := redis.ParseURL(url)
+	s, err := newRedisStore(url)
+	if err != nil {
+		return nil, err
+	}
+	if err := s.ping(ctx); err != nil {
+		_ = s.client.Close()
+		return nil, err
+	}
+	return s, nil
+}
+
+// newRedisStore builds the client without connecting; go-redis dials on
+// first use, so a store built while Redis is down starts working when it
+// comes back.
+func newRedisStore(url string) (*RedisStore, error) {
+	client, err := redisconn.Open(url)
 	if err != nil {
 		return nil, fmt.Errorf("invalid redis url: %w", err)
 	}
-	client := redis.NewClient(opts)
+	return &RedisStore{client: client}, nil
+}
 
+func (s *RedisStore) ping(ctx context.Context) error {
 	pingCtx, cancel := context.WithTimeout(ctx, redisConnectTimeout)
 	defer cancel()
-	if pong, err := client.Ping(pingCtx).Result(); err != nil {
-		_ = client.Close()
-		return nil, fmt.Errorf("redis ping: %w", err)
+	if pong, err := s.client.Ping(pingCtx).Result(); err != nil {
+		return fmt.Errorf("redis ping: %w", err)
 	} else if pong != "PONG" {
-		_ = client.Close()
-		return nil, fmt.Errorf("unexpected redis PING response: %s", pong)
+		return fmt.Errorf("unexpected redis PING response: %s", pong)
 	}
-	return &RedisStore{client: client}, nil
+	return nil
 }
 
 func redisKey(bucket Bucket, key string, windowIndex int64) string {
@@ -0,0 +1,245 @@
+package readcache
+
+import (
+	"context"
+	"encoding/json"
+	"log/slog"
+	"sort"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	lru "github.com/hashicorp/golang-lru/v2/expirable"
+)
+
+// Cache is one named read model. Values are shared between callers and
+// must be treated as read-only — a handler that needs to reshape a cached
+// slice copies it first.
+type Cache[T any] struct {
+	name  string
+	ttl   time.Duration
+	store Store
+	local *lru.LRU[string, T]
+
+	localHits     atomic.Uint64
+	sharedHits    atomic.Uint64
+	misses        atomic.Uint64
+	invalidations atomic.Uint64
+}
+
+// NewCache registers a cache on r. size bounds the local tier's entry
+// count; ttl bounds how long an entry is trusted in either tier.
+func NewCache[T any](r *Registry, name string, size int, ttl time.Duration) *Cache[T] {
+	c := &Cache[T]{
+		name:  name,
+		ttl:   ttl,
+		store: r.store,
+		local: lru.NewLRU[string, T](size, nil, ttl),
+	}
+	r.mu.Lock()
+	r.caches[name] = c
+	r.mu.Unlock()
+	return c
+}
+
+// Get returns key's value: local hit, else shared-store hit (populating
+// the local tier), else load (populating both). A load error is returned
+// as is and nothing is cached. A nil Cache just calls load, so callers
+// holding an optional cache need no branch of their own.
+func (c *Cache[T]) Get(ctx context.Context, key string, load func(context.Context) (T, error)) (T, error) {
+	if c == nil {
+		return load(ctx)
+	}
+	if v, ok := c.local.Get(key); ok {
+		c.localHits.Add(1)
+		return v, nil
+	}
+
+	raw, ok, err := c.store.Get(ctx, c.name, key)
+	if err != nil {
+		slog.Warn("readcache: store get failed; loading from source", "cache", c.name, "err", err)
+	} else if ok {
+		var v T
+		if err := json.Unmarshal(raw, &v); err == nil {
+			c.sharedHits.Add(1)
+			c.local.Add(key, v)
+			return v, nil
+		}
+		slog.Warn("readcache: undecodable shared entry; loading from source", "cache", c.name, "err", err)
+	}
+
+	c.misses.Add(1)
+	v, err := load(ctx)
+	if err != nil {
+		return v, err
+	}
+	c.local.Add(key, v)
+	if _, noop := c.store.(NoopStore); !noop {
+		if raw, err := json.Marshal(v); err == nil {
+			c.store.Set(ctx, c.name, key, raw, c.ttl)
+		}
+	}
+	return v, nil
+}
+
+// Key joins optional filter values into a cache key; nil and "" are
+// distinct from each other so "no filter" never collides with an empty
+// one.
+func Key(parts ...*string) string {
+	var b strings.Builder
+	for i, p := range parts {
+		if i > 0 {
+			b.WriteByte('|')
+		}
+		if p == nil {
+			b.WriteByte('*')
+			continue
+		}
+		b.WriteByte('=')
+		b.WriteString(*p)
+	}
+	return b.String()
+}
+
+func (c *Cache[T]) purge() { c.local.Purge() }
+
+func (c *Cache[T]) countInvalidation() { c.invalidations.Add(1) }
+
+func (c *Cache[T]) stats() Stats {
+	return Stats{
+		Name:          c.name,
+		LocalHits:     c.localHits.Load(),
+		SharedHits:    c.sharedHits.Load(),
+		Misses:        c.misses.Load(),
+		Invalidations: c.invalidations.Load(),
+		Entries:       c.local.Len(),
+	}
+}
+
+// Stats is one cache's counters since startup.
+type Stats struct {
+	Name          string
+	LocalHits     uint64
+	SharedHits    uint64
+	Misses        uint64
+	Invalidations uint64 // committed writes that dropped this cache (on this replica)
+	Entries       int    // local tier
+}
+
+type cacheHandle interface {
+	purge()
+	countInvalidation()
+	stats() Stats
+}
+
+type invalidationRule struct {
+	eventTypePrefix string
+	caches          []string
+}
+
+// Registry owns the shared Store, every cache built on it, and the
+// event-type → cache invalidation rules. Safe for concurrent use.
+type Registry struct {
+	store Store
+
+	mu     sync.RWMutex
+	caches map[string]cacheHandle
+	rules  []invalidationRule
+}
+
+// NewRegistry builds a registry over store (nil = NoopStore).
+func NewRegistry(store Store) *Registry {
+	if store == nil {
+		store = NoopStore{}
+	}
+	return &Registry{store: store, caches: map[string]cacheHandle{}}
+}
+
+// InvalidateOn drops caches whenever a committed domain event's type
+// starts with eventTypePrefix (e.g. "platform:admin:eventtype").
+func (r *Registry) InvalidateOn(eventTypePrefix string, caches ...string) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.rules = append(r.rules, invalidationRule{eventTypePrefix: eventTypePrefix, caches: caches})
+}
+
+// cachesFor lists the caches eventType invalidates.
+func (r *Registry) cachesFor(eventType string) []string {
+	r.mu.RLock()
+	defer r.mu.RUnlock()
+	var out []string
+	for _, rule := range r.rules {
+		if strings.HasPrefix(eventType, rule.eventTypePrefix) {
+			out = append(out, rule.caches...)
+		}
+	}
+	return out
+}
+
+// Invalidate drops the named cache here and in the shared store (which
+// broadcasts it to the other replicas).
+func (r *Registry) Invalidate(ctx context.Context, cache string) {
+	r.mu.RLock()
+	c, ok := r.caches[cache]
+	r.mu.RUnlock()
+	if ok {
+		c.purge()
+		c.countInvalidation()
+	}
+	r.store.Invalidate(ctx, cache)
+}
+
+func (r *Registry) purgeLocal(cache string) {
+	r.mu.RLock()
+	c, ok := r.caches[cache]
+	r.mu.RUnlock()
+	if ok {
+		c.purge()
+	}
+}
+
+// Invalidatable is an in-process view kept outside the registry's tiers
+// that still wants dropping on a committed event, here and on every
+// replica.
+type Invalidatable interface{ Invalidate() }
+
+// Attach registers v under name, so InvalidateOn rules and peer
+// broadcasts drop it like a Cache.
+func (r *Registry) Attach(name string, v Invalidatable) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.caches[name] = &attached{name: name, v: v}
+}
+
+type attached struct {
+	name          string
+	v             Invalidatable
+	invalidations atomic.Uint64
+}
+
+func (a *attached) purge() { a.v.Invalidate() }
+
+func (a *attached) countInvalidation() { a.invalidations.Add(1) }
+
+func (a *attached) stats() Stats {
+	return Stats{Name: a.name, Invalidations: a.invalidations.Load()}
+}
+
+// Listen applies invalidations broadcast by other replicas until ctx is
+// cancelled. Run it in a goroutine; with NoopStore it just waits.
+func (r *Registry) Listen(ctx context.Context) {
+	r.store.Subscribe(ctx, r.purgeLocal)
+}
+
+// Stats snapshots every cache, sorted by name.
+func (r *Registry) Stats() []Stats {
+	r.mu.RLock()
+	out := make([]Stats, 0, len(r.caches))
+	for _, c := range r.caches {
+		out = append(out, c.stats())
+	}
+	r.mu.RUnlock()
+	sort.Slice(out, func(i, j int) bool { return out[i].Name < out[j].Name })
+	return out
+}
@@ -0,0 +1,269 @@
+package readcache
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+type fakeStore struct {
+	values      map[string][]byte
+	getErr      error
+	sets        int
+	invalidated []string
+}
+
+func newFakeStore() *fakeStore { return &fakeStore{values: map[string][]byte{}} }
+
+func (s *fakeStore) Get(_ context.Context, cache, key string) ([]byte, bool, error) {
+	if s.getErr != nil {
+		return nil, false, s.getErr
+	}
+	v, ok := s.values[cache+"/"+key]
+	return v, ok, nil
+}
+
+func (s *fakeStore) Set(_ context.Context, cache, key string, value []byte, _ time.Duration) {
+	s.sets++
+	s.values[cache+"/"+key] = value
+}
+
+func (s *fakeStore) Invalidate(_ context.Context, cache string) {
+	s.invalidated = append(s.invalidated, cache)
+	for k := range s.values {
+		if strings.HasPrefix(k, cache+"/") {
+			delete(s.values, k)
+		}
+	}
+}
+
+func (s *fakeStore) Subscribe(ctx context.Context, _ func(string)) { <-ctx.Done() }
+
+func counting(v []string, calls *int) func(context.Context) ([]string, error) {
+	return func(context.Context) ([]string, error) {
+		*calls++
+		return v, nil
+	}
+}
+
+func TestCache_LocalHitSkipsStoreAndLoader(t *testing.T) {
+	store := newFakeStore()
+	c := NewCache[[]string](NewRegistry(store), "things", 10, time.Minute)
+	calls := 0
+
+	for i := 0; i < 3; i++ {
+		got, err := c.Get(context.Background(), "k", counting([]string{"a"}, &calls))
+		if err != nil {
+			t.Fatalf("Get: %v", err)
+		}
+		if len(got) != 1 || got[0] != "a" {
+			t.Fatalf("got %v", got)
+		}
+	}
+	if calls != 1 {
+		t.Errorf("expected 1 load, got %d", calls)
+	}
+	if store.sets != 1 {
+		t.Errorf("expected the load written through to the store once, got %d", store.sets)
+	}
+	s := c.stats()
+	if s.LocalHits != 2 || s.Misses != 1 || s.SharedHits != 0 {
+		t.Errorf("stats = %+v", s)
+	}
+}
+
+func TestCache_SharedHitPopulatesLocal(t *testing.T) {
+	store := newFakeStore()
+	raw, _ := json.Marshal([]string{"from-peer"})
+	store.values["things/k"] = raw
+	c := NewCache[[]string](NewRegistry(store), "things", 10, time.Minute)
+	calls := 0
+
+	got, err := c.Get(context.Background(), "k", counting(nil, &calls))
+	if err != nil {
+		t.Fatalf("Get: %v", err)
+	}
+	if len(got) != 1 || got[0] != "from-peer" {
+		t.Errorf("got %v, want the shared entry", got)
+	}
+	if calls != 0 {
+		t.Errorf("loader should not run on a shared hit, ran %d times", calls)
+	}
+	if s := c.stats(); s.SharedHits != 1 || s.Entries != 1 {
+		t.Errorf("stats = %+v", s)
+	}
+}
+
+func TestCache_StoreErrorFallsThroughToLoader(t *testing.T) {
+	store := newFakeStore()
+	store.getErr = errors.New("redis down")
+	c := NewCache[[]string](NewRegistry(store), "things", 10, time.Minute)
+	calls := 0
+
+	if _, err := c.Get(context.Background(), "k", counting([]string{"a"}, &calls)); err != nil {
+		t.Fatalf("a store failure must not fail the read: %v", err)
+	}
+	if calls != 1 {
+		t.Errorf("expected the loader to run, got %d calls", calls)
+	}
+}
+
+func TestCache_LoadErrorIsNotCached(t *testing.T) {
+	c := NewCache[[]string](NewRegistry(nil), "things", 10, time.Minute)
+	boom := errors.New("boom")
+
+	if _, err := c.Get(context.Background(), "k", func(context.Context) ([]string, error) { return nil, boom }); !errors.Is(err, boom) {
+		t.Fatalf("got %v, want the load error", err)
+	}
+	calls := 0
+	if _, err := c.Get(context.Background(), "k", counting([]string{"a"}, &calls)); err != nil {
+		t.Fatalf("Get: %v", err)
+	}
+	if calls != 1 {
+		t.Errorf("a failed load must not be cached; loader ran %d times", calls)
+	}
+}
+
+func TestCache_NilCacheCallsLoader(t *testing.T) {
+	var c *Cache[[]string]
+	calls := 0
+	for i := 0; i < 2; i++ {
+		if _, err := c.Get(context.Background(), "k", counting([]string{"a"}, &calls)); err != nil {
+			t.Fatalf("Get: %v", err)
+		}
+	}
+	if calls != 2 {
+		t.Errorf("nil cache should load every time, got %d calls", calls)
+	}
+}
+
+func TestKey_DistinguishesNilFromEmpty(t *testing.T) {
+	empty, app := "", "orders"
+	cases := map[string]string{
+		"nil":   Key(nil, nil),
+		"empty": Key(&empty, nil),
+		"app":   Key(&app, nil),
+		"app2":  Key(nil, &app),
+	}
+	seen := map[string]string{}
+	for name, k := range cases {
+		if other, dup := seen[k]; dup {
+			t.Errorf("%s and %s share key %q", name, other, k)
+		}
+		seen[k] = name
+	}
+}
+
+func TestRegistry_InvalidateDropsBothTiers(t *testing.T) {
+	store := newFakeStore()
+	r := NewRegistry(store)
+	c := NewCache[[]string](r, "things", 10, time.Minute)
+	other := NewCache[[]string](r, "others", 10, time.Minute)
+	calls := 0
+	_, _ = c.Get(context.Background(), "k", counting([]string{"a"}, &calls))
+	_, _ = other.Get(context.Background(), "k", counting([]string{"b"}, &calls))
+
+	r.Invalidate(context.Background(), "things")
+
+	if len(store.invalidated) != 1 || store.invalidated[0] != "things" {
+		t.Errorf("store invalidations = %v", store.invalidated)
+	}
+	if s := c.stats(); s.Entries != 0 || s.Invalidations != 1 {
+		t.Errorf("invalidated cache stats = %+v", s)
+	}
+	if s := other.stats(); s.Entries != 1 || s.Invalidations != 0 {
+		t.Errorf("unrelated cache was touched: %+v", s)
+	}
+	_, _ = c.Get(context.Background(), "k", counting([]string{"a2"}, &calls))
+	if calls != 3 {
+		t.Errorf("expected a reload after invalidation, loads = %d", calls)
+	}
+}
+
+type countingView struct{ dropped int }
+
+func (v *countingView) Invalidate() { v.dropped++ }
+
+func TestRegistry_AttachedViewIsInvalidated(t *testing.T) {
+	store := newFakeStore()
+	r := NewRegistry(store)
+	v := &countingView{}
+	r.Attach("view", v)
+
+	r.Invalidate(context.Background(), "view")
+	r.purgeLocal("view")
+
+	if v.dropped != 2 {
+		t.Errorf("dropped = %d, want 2", v.dropped)
+	}
+	if len(store.invalidated) != 1 || store.invalidated[0] != "view" {
+		t.Errorf("store invalidations = %v", store.invalidated)
+	}
+	stats := r.Stats()
+	if len(stats) != 1 || stats[0].Name != "view" || stats[0].Invalidations != 1 {
+		t.Errorf("stats = %+v", stats)
+	}
+}
+
+func TestRegistry_CachesForMatchesPrefixes(t *testing.T) {
+	r := NewRegistry(nil)
+	r.InvalidateOn("platform:admin:eventtype", "event-types")
+	r.InvalidateOn("platform:admin:client:", "clients")
+
+	if got := r.cachesFor("platform:admin:eventtypes:synced"); len(got) != 1 || got[0] != "event-types" {
+		t.Errorf("synced event: got %v", got)
+	}
+	if got := r.cachesFor("platform:admin:client:updated"); len(got) != 1 || got[0] != "clients" {
+		t.Errorf("client event: got %v", got)
+	}
+	if got := r.cachesFor("platform:admin:role:created"); len(got) != 0 {
+		t.Errorf("unrelated event: got %v", got)
+	}
+}
+
+type fakeEvent struct {
+	usecase.DomainEvent
+	eventType string
+}
+
+func (e fakeEvent) EventType() string { return e.eventType }
+
+type recordingSink struct {
+	usecasepgx.Sink
+	events []string
+}
+
+func (s *recordingSink) WriteEvent(_ context.Context, _ *usecasepgx.DbTx, e usecase.DomainEvent) error {
+	s.events = append(s.events, e.EventType())
+	return nil
+}
+
+func TestSink_InvalidatesOnlyAfterCommit(t *testing.T) {
+	store := newFakeStore()
+	r := NewRegistry(store)
+	r.InvalidateOn("platform:admin:eventtype", "event-types")
+	c := NewCache[[]string](r, "event-types", 10, time.Minute)
+	calls := 0
+	_, _ = c.Get(context.Background(), "k", counting([]string{"a"}, &calls))
+
+	inner := &recordingSink{}
+	// A bootstrap DbTx never commits through the SDK, so its hooks never
+	// fire — which is exactly the "still inside the transaction" state.
+	tx := usecasepgx.WrapTxForBootstrap(nil)
+	if err := r.Sink(inner).WriteEvent(context.Background(), tx, fakeEvent{eventType: "platform:admin:eventtype:updated"}); err != nil {
+		t.Fatalf("WriteEvent: %v", err)
+	}
+
+	if len(inner.events) != 1 {
+		t.Errorf("event not written through: %v", inner.events)
+	}
+	if len(store.invalidated) != 0 || c.stats().Entries != 1 {
+		t.Errorf("cache invalidated before commit: store=%v stats=%+v", store.invalidated, c.stats())
+	}
+}
@@ -0,0 +1,40 @@
+package readcache
+
+import "github.com/prometheus/client_golang/prometheus"
+
+var (
+	hitsDesc = prometheus.NewDesc("fc_read_cache_hits_total",
+		"Read-model cache hits, by tier (local = in-process, shared = Redis).",
+		[]string{"cache", "tier"}, nil)
+	missesDesc = prometheus.NewDesc("fc_read_cache_misses_total",
+		"Read-model cache misses that fell through to Postgres.",
+		[]string{"cache"}, nil)
+	invalidationsDesc = prometheus.NewDesc("fc_read_cache_invalidations_total",
+		"Committed writes that dropped the cache.",
+		[]string{"cache"}, nil)
+	entriesDesc = prometheus.NewDesc("fc_read_cache_entries",
+		"Entries in the in-process tier.",
+		[]string{"cache"}, nil)
+)
+
+// Collector exposes every cache's Stats as Prometheus series.
+func (r *Registry) Collector() prometheus.Collector { return registryCollector{r} }
+
+type registryCollector struct{ r *Registry }
+
+func (c registryCollector) Describe(ch chan<- *prometheus.Desc) {
+	ch <- hitsDesc
+	ch <- missesDesc
+	ch <- invalidationsDesc
+	ch <- entriesDesc
+}
+
+func (c registryCollector) Collect(ch chan<- prometheus.Metric) {
+	for _, s := range c.r.Stats() {
+		ch <- prometheus.MustNewConstMetric(hitsDesc, prometheus.CounterValue, float64(s.LocalHits), s.Name, "local")
+		ch <- prometheus.MustNewConstMetric(hitsDesc, prometheus.CounterValue, float64(s.SharedHits), s.Name, "shared")
+		ch <- prometheus.MustNewConstMetric(missesDesc, prometheus.CounterValue, float64(s.Misses), s.Name)
+		ch <- prometheus.MustNewConstMetric(invalidationsDesc, prometheus.CounterValue, float64(s.Invalidations), s.Name)
+		ch <- prometheus.MustNewConstMetric(entriesDesc, prometheus.GaugeValue, float64(s.Entries), s.Name)
+	}
+}
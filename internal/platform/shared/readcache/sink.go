@@ -0,0 +1,38 @@
+package readcache
+
+import (
+	"context"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Sink wraps inner so every committed domain event drops the caches
+// registered for its type (see InvalidateOn). This is the invalidation
+// hook for the use cases: any operation that changes a cached read model
+// already emits an event, so no use case needs to know about the cache.
+func (r *Registry) Sink(inner usecasepgx.Sink) usecasepgx.Sink {
+	return &invalidatingSink{Sink: inner, registry: r}
+}
+
+type invalidatingSink struct {
+	usecasepgx.Sink
+	registry *Registry
+}
+
+// WriteEvent writes through, then defers the invalidation to after the
+// commit — dropping the cache inside the transaction would let a
+// concurrent reader re-cache the pre-commit rows.
+func (s *invalidatingSink) WriteEvent(ctx context.Context, tx *usecasepgx.DbTx, event usecase.DomainEvent) error {
+	if err := s.Sink.WriteEvent(ctx, tx, event); err != nil {
+		return err
+	}
+	if caches := s.registry.cachesFor(event.EventType()); len(caches) > 0 {
+		tx.AfterCommit(func(ctx context.Context) {
+			for _, c := range caches {
+				s.registry.Invalidate(ctx, c)
+			}
+		})
+	}
+	return nil
+}
@@ -0,0 +1,161 @@
+// Package readcache caches the platform's hot, rarely-written read models —
+// the event-type lists, filter options and dispatch-pool lookups every
+// dashboard load issues — so they stop costing a Postgres round-trip each.
+//
+// Two tiers, mirroring internal/platform/shared/versioncache: a bounded
+// in-process LRU with a per-entry TTL, in front of an optional distributed
+// Store (Redis, shared by every replica), in front of the caller's loader.
+// Build selects Redis when FC_REDIS_URL is reachable, else a Noop store;
+// the local tier alone is still correct, each replica just warms its own.
+//
+// Invalidation is event-driven: Registry.Sink wraps the platform Sink and,
+// when a use case commits a domain event matching a registered prefix,
+// drops the affected caches after the commit lands. With Redis the drop is
+// broadcast, so other replicas purge their local tier too; without it they
+// converge within the local TTL. Writes that bypass the use cases (seeders,
+// SQL by hand) are only picked up by TTL expiry.
+package readcache
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"os"
+	"time"
+
+	"github.com/redis/go-redis/v9"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/redisconn"
+)
+
+const (
+	keyPrefix           = "fc:rc:"
+	invalidateChannel   = "fc:rc:invalidate"
+	redisConnectTimeout = 2 * time.Second
+)
+
+// Store is the distributed tier. Set and Invalidate are best-effort and
+// never surface an error — a degraded cache must never fail a read or a
+// write. Get reports a miss (ok=false) rather than an error when the key
+// is simply absent.
+type Store interface {
+	Get(ctx context.Context, cache, key string) (value []byte, ok bool, err error)
+	Set(ctx context.Context, cache, key string, value []byte, ttl time.Duration)
+	// Invalidate drops every entry of cache and tells the other replicas
+	// (Subscribe) to purge their local tier.
+	Invalidate(ctx context.Context, cache string)
+	// Subscribe calls fn with the name of each cache another replica
+	// invalidated, until ctx is cancelled.
+	Subscribe(ctx context.Context, fn func(cache string))
+}
+
+// NoopStore always misses and never records — used when Redis isn't
+// configured or unreachable.
+type NoopStore struct{}
+
+// Get always misses.
+func (NoopStore) Get(context.Context, string, string) ([]byte, bool, error) {
+	return nil, false, nil
+}
+
+// Set does nothing.
+func (NoopStore) Set(context.Context, string, string, []byte, time.Duration) {}
+
+// Invalidate does nothing.
+func (NoopStore) Invalidate(context.Context, string) {}
+
+// Subscribe blocks until ctx is cancelled; there are no peers to hear from.
+func (NoopStore) Subscribe(ctx context.Context, _ func(string)) { <-ctx.Done() }
+
+// Build selects the backend: Redis when FC_REDIS_URL is set and reachable
+// (PING within a short timeout), else NoopStore. The choice is logged at
+// startup.
+func Build(ctx context.Context) Store {
+	url := os.Getenv("FC_REDIS_URL")
+	if url == "" {
+		slog.Info("FC_REDIS_URL not set; read-model cache is per-instance only")
+		return NoopStore{}
+	}
+	s, err := NewRedisStore(ctx, url)
+	if err != nil {
+		slog.Warn("FC_REDIS_URL set but Redis unreachable; read-model cache is per-instance only", "err", err)
+		return NoopStore{}
+	}
+	slog.Info("read-model cache: Redis")
+	return s
+}
+
+// RedisStore keeps each cache as one hash at "fc:rc:{cache}" (field = key,
+// value = JSON), so Invalidate is a single DEL. The hash's TTL is reset on
+// every Set; it bounds staleness for writes no domain event announces.
+type RedisStore struct {
+	client redis.UniversalClient
+}
+
+// NewRedisStore connects, PINGs to confirm liveness, and returns the store.
+// Returns an error (never panics) so Build can fall back to NoopStore.
+func NewRedisStore(ctx context.Context, url string) (*RedisStore, error) {
+	client, err := redisconn.Open(url)
+	if err != nil {
+		return nil, fmt.Errorf("invalid redis url: %w", err)
+	}
+
+	pingCtx, cancel := context.WithTimeout(ctx, redisConnectTimeout)
+	defer cancel()
+	if err := client.Ping(pingCtx).Err(); err != nil {
+		_ = client.Close()
+		return nil, fmt.Errorf("redis ping: %w", err)
+	}
+	return &RedisStore{client: client}, nil
+}
+
+// Get reads one cached value. A missing key is a clean miss.
+func (s *RedisStore) Get(ctx context.Context, cache, key string) ([]byte, bool, error) {
+	v, err := s.client.HGet(ctx, keyPrefix+cache, key).Bytes()
+	if err == redis.Nil {
+		return nil, false, nil
+	}
+	if err != nil {
+		return nil, false, fmt.Errorf("redis hget: %w", err)
+	}
+	return v, true, nil
+}
+
+// Set stores one value and refreshes the cache's TTL.
+func (s *RedisStore) Set(ctx context.Context, cache, key string, value []byte, ttl time.Duration) {
+	pipe := s.client.TxPipeline()
+	pipe.HSet(ctx, keyPrefix+cache, key, value)
+	pipe.Expire(ctx, keyPrefix+cache, ttl)
+	if _, err := pipe.Exec(ctx); err != nil {
+		slog.Warn("readcache: redis set failed", "cache", cache, "err", err)
+	}
+}
+
+// Invalidate deletes the cache's hash and publishes its name.
+func (s *RedisStore) Invalidate(ctx context.Context, cache string) {
+	if err := s.client.Del(ctx, keyPrefix+cache).Err(); err != nil {
+		slog.Warn("readcache: redis invalidate failed", "cache", cache, "err", err)
+	}
+	if err := s.client.Publish(ctx, invalidateChannel, cache).Err(); err != nil {
+		slog.Warn("readcache: redis invalidate broadcast failed", "cache", cache, "err", err)
+	}
+}
+
+// Subscribe relays invalidations published by any replica, this one
+// included (a repeated local purge is harmless).
+func (s *RedisStore) Subscribe(ctx context.Context, fn func(cache string)) {
+	sub := s.client.Subscribe(ctx, invalidateChannel)
+	defer func() { _ = sub.Close() }()
+	ch := sub.Channel()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case msg, ok := <-ch:
+			if !ok {
+				return
+			}
+			fn(msg.Payload)
+		}
+	}
+}
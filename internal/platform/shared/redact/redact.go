@@ -0,0 +1,158 @@
+// Package redact masks configured fields in JSON payloads before they are
+// shown to operators. The rules come from the per-event-type data policy
+// (msg_event_types.redact_fields) and are keyed by event type code, which
+// is also the dispatch job code for event-kind jobs.
+//
+// Redaction is a view concern: stored payloads, webhook deliveries and the
+// outbox are untouched. The read API and the debug views call Rules before
+// serialising a payload.
+package redact
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"strings"
+	"sync"
+	"time"
+)
+
+// Placeholder replaces every redacted value.
+const Placeholder = "[REDACTED]"
+
+// Apply masks each dotted path in fields. Objects are descended by key,
+// "*" matches every key at that level, and arrays are walked element by
+// element without consuming a path segment ("items.card" masks the card of
+// every item). Input that is not a JSON object or array is returned as-is,
+// as is input where no path matched.
+func Apply(data []byte, fields []string) []byte {
+	if len(fields) == 0 || len(data) == 0 {
+		return data
+	}
+	dec := json.NewDecoder(bytes.NewReader(data))
+	dec.UseNumber()
+	var doc any
+	if err := dec.Decode(&doc); err != nil {
+		return data
+	}
+	hit := false
+	for _, f := range fields {
+		if f == "" {
+			continue
+		}
+		if mask(doc, strings.Split(f, ".")) {
+			hit = true
+		}
+	}
+	if !hit {
+		return data
+	}
+	out, err := json.Marshal(doc)
+	if err != nil {
+		return data
+	}
+	return out
+}
+
+// mask applies one split path to v and reports whether anything changed.
+func mask(v any, path []string) bool {
+	switch node := v.(type) {
+	case []any:
+		hit := false
+		for _, el := range node {
+			if mask(el, path) {
+				hit = true
+			}
+		}
+		return hit
+	case map[string]any:
+		key, rest := path[0], path[1:]
+		hit := false
+		for k, child := range node {
+			if key != "*" && k != key {
+				continue
+			}
+			if len(rest) == 0 {
+				node[k] = Placeholder
+				hit = true
+				continue
+			}
+			if mask(child, rest) {
+				hit = true
+			}
+		}
+		return hit
+	}
+	return false
+}
+
+// Rules is a cached code → fields lookup. A nil *Rules redacts nothing, so
+// callers can hold one unconditionally.
+type Rules struct {
+	load func(context.Context) (map[string][]string, error)
+	ttl  time.Duration
+
+	mu       sync.Mutex
+	byCode   map[string][]string
+	loadedAt time.Time
+}
+
+// NewRules wires a cache over load (typically
+// eventtype.Repository.RedactionRules). ttl <= 0 means 30s.
+func NewRules(load func(context.Context) (map[string][]string, error), ttl time.Duration) *Rules {
+	if ttl <= 0 {
+		ttl = 30 * time.Second
+	}
+	return &Rules{load: load, ttl: ttl}
+}
+
+// Fields returns the redact paths for an event type code. A failed refresh
+// keeps serving the previous rule set; with nothing cached the error is
+// returned so callers fail closed rather than show unredacted data.
+func (r *Rules) Fields(ctx context.Context, code string) ([]string, error) {
+	if r == nil {
+		return nil, nil
+	}
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	if r.byCode == nil || time.Since(r.loadedAt) > r.ttl {
+		m, err := r.load(ctx)
+		if err != nil {
+			if r.byCode == nil {
+				return nil, err
+			}
+		} else {
+			r.byCode = m
+			r.loadedAt = time.Now()
+		}
+	}
+	return r.byCode[code], nil
+}
+
+// JSON redacts data according to code's rules.
+func (r *Rules) JSON(ctx context.Context, code string, data json.RawMessage) (json.RawMessage, error) {
+	if len(data) == 0 {
+		return data, nil
+	}
+	fields, err := r.Fields(ctx, code)
+	if err != nil {
+		return nil, err
+	}
+	return json.RawMessage(Apply(data, fields)), nil
+}
+
+// String is JSON for text columns (dispatch job payloads).
+func (r *Rules) String(ctx context.Context, code string, s *string) (*string, error) {
+	if s == nil || *s == "" {
+		return s, nil
+	}
+	fields, err := r.Fields(ctx, code)
+	if err != nil {
+		return nil, err
+	}
+	if len(fields) == 0 {
+		return s, nil
+	}
+	out := string(Apply([]byte(*s), fields))
+	return &out, nil
+}
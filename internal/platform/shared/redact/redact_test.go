@@ -0,0 +1,74 @@
+package redact
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestApply_Paths(t *testing.T) {
+	in := []byte(`{"customer":{"email":"a@example.com","name":"Alice"},"items":[{"card":"4111","sku":"X"},{"card":"4242","sku":"Y"}],"total":12.50}`)
+	out := Apply(in, []string{"customer.email", "items.card", "missing.path"})
+
+	var got map[string]any
+	require.NoError(t, json.Unmarshal(out, &got))
+	customer := got["customer"].(map[string]any)
+	assert.Equal(t, Placeholder, customer["email"])
+	assert.Equal(t, "Alice", customer["name"])
+	for _, it := range got["items"].([]any) {
+		assert.Equal(t, Placeholder, it.(map[string]any)["card"])
+	}
+	assert.Contains(t, string(out), `"total":12.50`, "numbers keep their original text")
+}
+
+func TestApply_WildcardAndNoMatch(t *testing.T) {
+	in := []byte(`{"contacts":{"home":{"phone":"1"},"work":{"phone":"2"}}}`)
+	out := Apply(in, []string{"contacts.*.phone"})
+	assert.NotContains(t, string(out), `"1"`)
+	assert.NotContains(t, string(out), `"2"`)
+
+	untouched := []byte(`{ "a": 1 }`)
+	assert.Equal(t, untouched, Apply(untouched, []string{"b"}), "no match keeps the original bytes")
+	assert.Equal(t, []byte(`not json`), Apply([]byte(`not json`), []string{"a"}))
+}
+
+func TestRules_CacheAndFailClosed(t *testing.T) {
+	ctx := context.Background()
+	calls := 0
+	fail := false
+	r := NewRules(func(context.Context) (map[string][]string, error) {
+		calls++
+		if fail {
+			return nil, errors.New("db down")
+		}
+		return map[string][]string{"a:b:c:d": {"email"}}, nil
+	}, time.Millisecond)
+
+	out, err := r.JSON(ctx, "a:b:c:d", json.RawMessage(`{"email":"x"}`))
+	require.NoError(t, err)
+	assert.JSONEq(t, `{"email":"[REDACTED]"}`, string(out))
+
+	// A failed refresh keeps the last good rule set.
+	fail = true
+	time.Sleep(2 * time.Millisecond)
+	out, err = r.JSON(ctx, "a:b:c:d", json.RawMessage(`{"email":"x"}`))
+	require.NoError(t, err)
+	assert.JSONEq(t, `{"email":"[REDACTED]"}`, string(out))
+	assert.Equal(t, 2, calls)
+
+	// With nothing cached, the error surfaces.
+	cold := NewRules(func(context.Context) (map[string][]string, error) { return nil, errors.New("db down") }, 0)
+	_, err = cold.JSON(ctx, "a:b:c:d", json.RawMessage(`{"email":"x"}`))
+	assert.Error(t, err)
+
+	var none *Rules
+	s := `{"email":"x"}`
+	got, err := none.String(ctx, "a:b:c:d", &s)
+	require.NoError(t, err)
+	assert.Equal(t, s, *got)
+}
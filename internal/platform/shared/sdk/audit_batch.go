@@ -62,7 +62,7 @@ func (s *AuditBatchState) batchIngest(w http.ResponseWriter, r *http.Request) {
 
 	var body AuditBatchRequest
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	if len(body.Items) > 100 {
@@ -9,6 +9,7 @@ import (
 	"encoding/json"
 	"net/http"
 	"sort"
+	"time"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
@@ -44,6 +45,7 @@ type CreateDispatchJobRequest struct {
 	RetryStrategy      string            `json:"retryStrategy,omitempty"`
 	IdempotencyKey     *string           `json:"idempotencyKey,omitempty"`
 	ExternalID         *string           `json:"externalId,omitempty"`
+	ExpiresAt          *time.Time        `json:"expiresAt,omitempty"`
 	Metadata           map[string]string `json:"metadata,omitempty"`
 }
 
@@ -66,7 +68,7 @@ func (s *DispatchJobsBatchState) createOne(w http.ResponseWriter, r *http.Reques
 
 	var req CreateDispatchJobRequest
 	if err := json.NewDecoder(r.Body).Decode(&req); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	// Rust deserializes these as non-Option fields — a missing one is a
@@ -91,6 +93,10 @@ func (s *DispatchJobsBatchState) createOne(w http.ResponseWriter, r *http.Reques
 		httperror.Write(w, httperror.Forbidden("No access to client: "+*req.ClientID))
 		return
 	}
+	if err := s.Outbound.CheckURL(req.ClientID, req.TargetURL); err != nil {
+		httperror.Write(w, httperror.BadRequest("TARGET_URL_BLOCKED", err.Error()))
+		return
+	}
 
 	// Delegate through the batch item mapping so the singular create and a
 	// batch-of-1 persist identically, then layer on the fields only the
@@ -115,6 +121,7 @@ func (s *DispatchJobsBatchState) createOne(w http.ResponseWriter, r *http.Reques
 		Mode:               req.Mode,
 		TimeoutSeconds:     req.TimeoutSeconds,
 		MaxRetries:         req.MaxRetries,
+		ExpiresAt:          req.ExpiresAt,
 	})
 	if req.Sequence != nil {
 		// Pointer on the singular DTO so an explicit `"sequence": 0` is
@@ -12,10 +12,12 @@ package sdk
 import (
 	"encoding/json"
 	"net/http"
+	"time"
 
 	"github.com/go-chi/chi/v5"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
@@ -26,6 +28,8 @@ import (
 // DispatchJobsBatchState bundles deps.
 type DispatchJobsBatchState struct {
 	Repo *dispatchjob.Repository
+	// Outbound refuses target URLs the SSRF policy blocks. nil = unchecked.
+	Outbound *outbound.Policies
 }
 
 // BatchItem is one row in the inbound batch.
@@ -51,6 +55,7 @@ type BatchItem struct {
 	Sequence           int32                  `json:"sequence,omitempty"`
 	TimeoutSeconds     uint32                 `json:"timeoutSeconds,omitempty"`
 	MaxRetries         uint32                 `json:"maxRetries,omitempty"`
+	ExpiresAt          *time.Time             `json:"expiresAt,omitempty"` // undelivered by then → EXPIRED, never sent
 	Metadata           []dispatchjob.Metadata `json:"metadata,omitempty"`
 }
 
@@ -107,6 +112,7 @@ func jobFromItem(it BatchItem) dispatchjob.DispatchJob {
 		Sequence:           defaultI32(it.Sequence, 99),
 		TimeoutSeconds:     defaultU32(it.TimeoutSeconds, 30),
 		MaxRetries:         defaultU32(it.MaxRetries, 3),
+		ExpiresAt:          it.ExpiresAt,
 		RetryStrategy:      dispatchjob.RetryExponentialBackoff,
 		Status:             common.DispatchPending,
 		Metadata:           it.Metadata,
@@ -131,7 +137,7 @@ func (s *DispatchJobsBatchState) batchIngest(w http.ResponseWriter, r *http.Requ
 
 	var body BatchRequest
 	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
-		httperror.Write(w, httperror.BadRequest("INVALID_JSON", err.Error()))
+		httperror.WriteDecodeError(w, err)
 		return
 	}
 	if len(body.Items) == 0 {
@@ -153,6 +159,10 @@ func (s *DispatchJobsBatchState) batchIngest(w http.ResponseWriter, r *http.Requ
 			httperror.Write(w, httperror.Forbidden("No access to client: "+*j.ClientID))
 			return
 		}
+		if err := s.Outbound.CheckURL(j.ClientID, j.TargetURL); err != nil {
+			httperror.Write(w, httperror.BadRequest("TARGET_URL_BLOCKED", err.Error()))
+			return
+		}
 		jobs = append(jobs, j)
 	}
 
@@ -0,0 +1,117 @@
+// Package snapshot holds a whole-table read model reloaded on a TTL, for
+// hot paths (event ingestion) that consult it on every request.
+package snapshot
+
+import (
+	"context"
+	"sync"
+	"time"
+)
+
+// Value is the cached result of load, refreshed at most once per ttl.
+// One caller runs each refresh; while a value is cached the others keep
+// reading it instead of waiting. A failed refresh counts as a refresh
+// too, so a database outage costs one load per ttl rather than one per
+// call. Safe for concurrent use.
+type Value[T any] struct {
+	load func(context.Context) (T, error)
+	ttl  time.Duration
+
+	mu       sync.RWMutex
+	v        T
+	loaded   bool
+	err      error     // last failed load; returned while nothing is loaded
+	loadedAt time.Time // last load attempt; zero forces a refresh
+	gen      uint64    // bumped by Invalidate
+	inflight chan struct{}
+}
+
+// New wires a Value over load.
+func New[T any](load func(context.Context) (T, error), ttl time.Duration) *Value[T] {
+	return &Value[T]{load: load, ttl: ttl}
+}
+
+// Get returns the cached value, refreshing it first when it is older than
+// ttl. A failed refresh keeps serving the previous value; with nothing
+// loaded yet the error is returned.
+func (s *Value[T]) Get(ctx context.Context) (T, error) {
+	s.mu.RLock()
+	if s.fresh() {
+		v, err := s.current()
+		s.mu.RUnlock()
+		return v, err
+	}
+	s.mu.RUnlock()
+	return s.refresh(ctx)
+}
+
+// Invalidate makes the next Get reload. A load already running when it
+// is called still publishes its value but not as fresh.
+func (s *Value[T]) Invalidate() {
+	s.mu.Lock()
+	s.loadedAt = time.Time{}
+	s.gen++
+	s.mu.Unlock()
+}
+
+func (s *Value[T]) refresh(ctx context.Context) (T, error) {
+	s.mu.Lock()
+	if s.fresh() {
+		v, err := s.current()
+		s.mu.Unlock()
+		return v, err
+	}
+	if wait := s.inflight; wait != nil {
+		loaded := s.loaded
+		v, err := s.current()
+		s.mu.Unlock()
+		if loaded {
+			return v, err
+		}
+		select {
+		case <-wait:
+		case <-ctx.Done():
+			var zero T
+			return zero, ctx.Err()
+		}
+		s.mu.RLock()
+		defer s.mu.RUnlock()
+		return s.current()
+	}
+	done := make(chan struct{})
+	s.inflight = done
+	gen := s.gen
+	s.mu.Unlock()
+
+	// The load outlives the caller that happened to start it.
+	v, err := s.load(context.WithoutCancel(ctx))
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	defer close(done)
+	s.inflight = nil
+	if err != nil {
+		s.err = err
+	} else {
+		s.v, s.loaded, s.err = v, true, nil
+	}
+	if gen == s.gen {
+		s.loadedAt = time.Now()
+	}
+	return s.current()
+}
+
+// fresh reports whether the last load attempt is within ttl. Callers
+// hold mu.
+func (s *Value[T]) fresh() bool {
+	return !s.loadedAt.IsZero() && time.Since(s.loadedAt) <= s.ttl
+}
+
+// current is what Get returns from the cached state. Callers hold mu.
+func (s *Value[T]) current() (T, error) {
+	if !s.loaded {
+		var zero T
+		return zero, s.err
+	}
+	return s.v, nil
+}
@@ -0,0 +1,132 @@
+package snapshot
+
+import (
+	"context"
+	"errors"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+)
+
+func TestValue_CachesWithinTTL(t *testing.T) {
+	var calls atomic.Int32
+	v := New(func(context.Context) (int, error) { return int(calls.Add(1)), nil }, time.Minute)
+
+	for i := 0; i < 3; i++ {
+		got, err := v.Get(context.Background())
+		if err != nil || got != 1 {
+			t.Fatalf("Get = %d, %v", got, err)
+		}
+	}
+	if calls.Load() != 1 {
+		t.Errorf("loads = %d, want 1", calls.Load())
+	}
+}
+
+func TestValue_ConcurrentMissesShareOneLoad(t *testing.T) {
+	var calls atomic.Int32
+	release := make(chan struct{})
+	v := New(func(context.Context) (int, error) {
+		calls.Add(1)
+		<-release
+		return 7, nil
+	}, time.Minute)
+
+	var wg sync.WaitGroup
+	results := make([]int, 8)
+	for i := range results {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			results[i], _ = v.Get(context.Background())
+		}()
+	}
+	time.Sleep(20 * time.Millisecond)
+	close(release)
+	wg.Wait()
+
+	if calls.Load() != 1 {
+		t.Errorf("loads = %d, want 1", calls.Load())
+	}
+	for _, r := range results {
+		if r != 7 {
+			t.Errorf("results = %v", results)
+			break
+		}
+	}
+}
+
+func TestValue_StaleReadersDoNotWaitOnRefresh(t *testing.T) {
+	var calls atomic.Int32
+	block := make(chan struct{})
+	v := New(func(context.Context) (int, error) {
+		if calls.Add(1) > 1 {
+			<-block
+		}
+		return int(calls.Load()), nil
+	}, time.Minute)
+	defer close(block)
+
+	if got, _ := v.Get(context.Background()); got != 1 {
+		t.Fatalf("first Get = %d", got)
+	}
+	v.Invalidate()
+	go func() { _, _ = v.Get(context.Background()) }() // blocks in load
+	time.Sleep(20 * time.Millisecond)
+
+	done := make(chan int)
+	go func() {
+		got, _ := v.Get(context.Background())
+		done <- got
+	}()
+	select {
+	case got := <-done:
+		if got != 1 {
+			t.Errorf("stale Get = %d, want 1", got)
+		}
+	case <-time.After(time.Second):
+		t.Fatal("Get waited on the running refresh")
+	}
+}
+
+func TestValue_FailedRefreshIsNotRetriedWithinTTL(t *testing.T) {
+	var calls atomic.Int32
+	fail := errors.New("db down")
+	v := New(func(context.Context) (int, error) {
+		if calls.Add(1) == 1 {
+			return 0, fail
+		}
+		return 2, nil
+	}, time.Minute)
+
+	for i := 0; i < 3; i++ {
+		if _, err := v.Get(context.Background()); !errors.Is(err, fail) {
+			t.Fatalf("Get err = %v, want %v", err, fail)
+		}
+	}
+	if calls.Load() != 1 {
+		t.Errorf("loads = %d, want 1", calls.Load())
+	}
+
+	v.Invalidate()
+	if got, err := v.Get(context.Background()); err != nil || got != 2 {
+		t.Errorf("after Invalidate: Get = %d, %v", got, err)
+	}
+}
+
+func TestValue_FailedRefreshKeepsPreviousValue(t *testing.T) {
+	var calls atomic.Int32
+	v := New(func(context.Context) (int, error) {
+		if calls.Add(1) > 1 {
+			return 0, errors.New("db down")
+		}
+		return 1, nil
+	}, time.Minute)
+
+	_, _ = v.Get(context.Background())
+	v.Invalidate()
+	if got, err := v.Get(context.Background()); err != nil || got != 1 {
+		t.Errorf("Get = %d, %v; want the previous value", got, err)
+	}
+}
@@ -39,3 +39,22 @@ func Match(re *regexp.Regexp, v, code, message string) error {
 	}
 	return nil
 }
+
+// RegionPattern is the data-residency region rule: a lowercase slug such
+// as "eu" or "us-east", at most 32 characters (the column width).
+var RegionPattern = regexp.MustCompile(`^[a-z][a-z0-9-]{0,31}$`)
+
+// Region normalises a client or dispatch-pool region: trimmed and
+// lowercased, "" when blank (unrestricted). Anything else must match
+// RegionPattern.
+func Region(v string) (string, error) {
+	r := strings.ToLower(strings.TrimSpace(v))
+	if r == "" {
+		return "", nil
+	}
+	if !RegionPattern.MatchString(r) {
+		return "", usecase.Validation("INVALID_REGION",
+			"region must be a lowercase slug of at most 32 characters (e.g. eu, us-east)")
+	}
+	return r, nil
+}
@@ -66,3 +66,19 @@ func TestCodePatterns(t *testing.T) {
 		}
 	}
 }
+
+func TestRegion(t *testing.T) {
+	t.Parallel()
+
+	for in, want := range map[string]string{"": "", "  ": "", "eu": "eu", " EU ": "eu", "us-east": "us-east"} {
+		got, err := Region(in)
+		if err != nil || got != want {
+			t.Errorf("Region(%q) = (%q, %v), want (%q, nil)", in, got, err, want)
+		}
+	}
+	for _, in := range []string{"1eu", "eu_west", "-eu", "a23456789012345678901234567890123"} {
+		_, err := Region(in)
+		assertValidation(t, err, "INVALID_REGION",
+			"region must be a lowercase slug of at most 32 characters (e.g. eu, us-east)")
+	}
+}
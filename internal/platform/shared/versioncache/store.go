@@ -18,6 +18,8 @@ import (
 	"time"
 
 	"github.com/redis/go-redis/v9"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/redisconn"
 )
 
 const (
@@ -72,17 +74,16 @@ func Build(ctx context.Context) Store {
 // "fc:pv:{principalID}", written by Bump on every principal/role mutation
 // and read by Reader on a local-cache miss.
 type RedisStore struct {
-	client *redis.Client
+	client redis.UniversalClient
 }
 
 // NewRedisStore connects, PINGs to confirm liveness, and returns the store.
 // Returns an error (never panics) so Build can fall back to NoopStore.
 func NewRedisStore(ctx context.Context, url string) (*RedisStore, error) {
-	opts, err := redis.ParseURL(url)
+	client, err := redisconn.Open(url)
 	if err != nil {
 		return nil, fmt.Errorf("invalid redis url: %w", err)
 	}
-	client := redis.NewClient(opts)
 
 	pingCtx, cancel := context.WithTimeout(ctx, redisConnectTimeout)
 	defer cancel()
@@ -0,0 +1,148 @@
+// Package api wires the HTTP routes for client signing keys via huma.
+package api
+
+import (
+	"context"
+	"net/http"
+	"time"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// State bundles the dependencies.
+type State struct {
+	Repo    *signingkey.Repository
+	Clients *client.Repository
+	UoW     *usecasepgx.UnitOfWork
+}
+
+const tag = "signing-keys"
+
+// Register mounts the signing key endpoints.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "listClientSigningKeys", "/api/clients/{id}/signing-keys", "List a client's webhook signing keys", s.list)
+	apiroute.Post(g, "rotateClientSigningKey", "/api/clients/{id}/signing-keys",
+		"Add a signing key, retiring the current ones after an overlap", http.StatusCreated, s.rotate)
+	apiroute.Post(g, "expireClientSigningKey", "/api/clients/{id}/signing-keys/{signingKeyId}/expire",
+		"Expire a signing key", http.StatusOK, s.expire)
+}
+
+func (s *State) list(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[SigningKeyListResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanReadSigningKeys(ac); err != nil {
+		return nil, err
+	}
+	if !ac.CanAccessClient(in.ID) {
+		return nil, httperror.Forbidden("No access to this client")
+	}
+	keys, err := s.Repo.FindByClient(ctx, in.ID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_client failed", err)
+	}
+	now := time.Now().UTC()
+	out := make([]SigningKeyResponse, 0, len(keys))
+	signing := false
+	for i := range keys {
+		// Newest window first, so the first key in effect is the signer.
+		isSigner := !signing && keys[i].ActiveAt(now)
+		signing = signing || isSigner
+		out = append(out, fromEntity(&keys[i], now, isSigner))
+	}
+	return &apicommon.Out[SigningKeyListResponse]{Body: SigningKeyListResponse{SigningKeys: out}}, nil
+}
+
+// Both bodies are pointers so a bare POST rotates (or expires) with the
+// defaults.
+type rotateInput struct {
+	ID   string `path:"id"`
+	Body *RotateSigningKeyRequest
+}
+
+// rotate returns the new key with its secret — the only time it is shown.
+func (s *State) rotate(ctx context.Context, in *rotateInput) (*apicommon.Out[SigningKeyCreatedResponse], error) {
+	if err := auth.CanManageSigningKeys(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	var body RotateSigningKeyRequest
+	if in.Body != nil {
+		body = *in.Body
+	}
+	ec := auth.NewExecutionContext(ctx)
+	event, err := usecaseop.Run(ctx, s.UoW, operations.RotateSigningKey(s.Repo, s.Clients), body.toCommand(in.ID), ec)
+	if err != nil {
+		return nil, err
+	}
+	k, err := s.Repo.FindByID(ctx, event.SigningKeyID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "post-create reload failed", err)
+	}
+	if k == nil {
+		return nil, httperror.NotFound("SigningKey", event.SigningKeyID)
+	}
+	resp, err := s.response(ctx, k)
+	if err != nil {
+		return nil, err
+	}
+	return &apicommon.Out[SigningKeyCreatedResponse]{Body: SigningKeyCreatedResponse{SigningKey: resp, Secret: k.Secret}}, nil
+}
+
+type expireInput struct {
+	ID           string `path:"id"`
+	SigningKeyID string `path:"signingKeyId"`
+	Body         *ExpireSigningKeyRequest
+}
+
+func (s *State) expire(ctx context.Context, in *expireInput) (*apicommon.Out[SigningKeyResponse], error) {
+	if err := auth.CanManageSigningKeys(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	k, err := s.Repo.FindByID(ctx, in.SigningKeyID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if k == nil || k.ClientID != in.ID {
+		return nil, httperror.NotFound("SigningKey", in.SigningKeyID)
+	}
+	cmd := operations.ExpireCommand{ID: in.SigningKeyID}
+	if in.Body != nil {
+		cmd.At = in.Body.At
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.ExpireSigningKey(s.Repo), cmd, ec); err != nil {
+		return nil, err
+	}
+	k, err = s.Repo.FindByID(ctx, in.SigningKeyID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if k == nil {
+		return nil, httperror.NotFound("SigningKey", in.SigningKeyID)
+	}
+	resp, err := s.response(ctx, k)
+	if err != nil {
+		return nil, err
+	}
+	return &apicommon.Out[SigningKeyResponse]{Body: resp}, nil
+}
+
+// response renders one key, asking the repo whether it is the signer.
+func (s *State) response(ctx context.Context, k *signingkey.SigningKey) (SigningKeyResponse, error) {
+	now := time.Now().UTC()
+	cur, err := s.Repo.Current(ctx, k.ClientID, now)
+	if err != nil {
+		return SigningKeyResponse{}, usecase.Internal("REPO", "current failed", err)
+	}
+	return fromEntity(k, now, cur != nil && cur.ID == k.ID), nil
+}
@@ -0,0 +1,80 @@
+// dto.go contains the wire-format types for the signing key API.
+package api
+
+import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey/operations"
+)
+
+// RotateSigningKeyRequest is the wire body for
+// POST /api/clients/{id}/signing-keys.
+type RotateSigningKeyRequest struct {
+	KeyID          string     `json:"keyId,omitempty" doc:"Name of the new key, sent as X-FLOWCATALYST-KEY-ID (default v<n>)"`
+	ActiveFrom     *time.Time `json:"activeFrom,omitempty" doc:"When the new key starts signing (default now, at most 30 days ahead)"`
+	OverlapSeconds *int64     `json:"overlapSeconds,omitempty" doc:"How long the replaced keys stay valid after activeFrom (default 86400)"`
+}
+
+func (r RotateSigningKeyRequest) toCommand(clientID string) operations.RotateCommand {
+	return operations.RotateCommand{
+		ClientID:       clientID,
+		KeyID:          r.KeyID,
+		ActiveFrom:     r.ActiveFrom,
+		OverlapSeconds: r.OverlapSeconds,
+	}
+}
+
+// ExpireSigningKeyRequest is the wire body for
+// POST /api/clients/{id}/signing-keys/{signingKeyId}/expire.
+type ExpireSigningKeyRequest struct {
+	At *time.Time `json:"at,omitempty" doc:"When the key stops being valid (default now)"`
+}
+
+// SigningKeyResponse mirrors signingkey.SigningKey without its secret.
+// Signing marks the key deliveries are signed with right now.
+type SigningKeyResponse struct {
+	ID         string           `json:"id"`
+	ClientID   string           `json:"clientId"`
+	KeyID      string           `json:"keyId"`
+	Status     string           `json:"status" doc:"PENDING, ACTIVE or EXPIRED"`
+	Signing    bool             `json:"signing"`
+	ActiveFrom httpcompat.Time  `json:"activeFrom"`
+	ExpiresAt  *httpcompat.Time `json:"expiresAt,omitempty"`
+	CreatedBy  *string          `json:"createdBy,omitempty"`
+	CreatedAt  httpcompat.Time  `json:"createdAt"`
+}
+
+func fromEntity(k *signingkey.SigningKey, now time.Time, signing bool) SigningKeyResponse {
+	var expires *httpcompat.Time
+	if k.ExpiresAt != nil {
+		v := jsontime.New(*k.ExpiresAt)
+		expires = &v
+	}
+	return SigningKeyResponse{
+		ID:         k.ID,
+		ClientID:   k.ClientID,
+		KeyID:      k.KeyID,
+		Status:     k.StatusAt(now),
+		Signing:    signing,
+		ActiveFrom: jsontime.New(k.ActiveFrom),
+		ExpiresAt:  expires,
+		CreatedBy:  k.CreatedBy,
+		CreatedAt:  jsontime.New(k.CreatedAt),
+	}
+}
+
+// SigningKeyListResponse is the wire shape for
+// GET /api/clients/{id}/signing-keys.
+type SigningKeyListResponse struct {
+	SigningKeys []SigningKeyResponse `json:"signingKeys"`
+}
+
+// SigningKeyCreatedResponse is returned once, when a key is created. The
+// secret cannot be read back afterwards.
+type SigningKeyCreatedResponse struct {
+	SigningKey SigningKeyResponse `json:"signingKey"`
+	Secret     string             `json:"secret" doc:"HMAC-SHA256 signing secret. Shown only in this response."`
+}
@@ -0,0 +1,97 @@
+// Package signingkey manages per-client webhook signing keys. Deliveries
+// for a client are signed with its newest key in effect and carry the key's
+// id in X-FLOWCATALYST-KEY-ID; rotation overlaps old and new keys so
+// receivers can switch secrets without dropping deliveries.
+package signingkey
+
+import (
+	"crypto/rand"
+	"encoding/base64"
+	"regexp"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// KeyIDPattern is the rule for a key id: it travels in a header and in
+// receivers' config, so it stays short and unambiguous.
+var KeyIDPattern = regexp.MustCompile(`^[A-Za-z0-9][A-Za-z0-9._-]{0,63}$`)
+
+// Key states, derived from the window and the time asked about.
+const (
+	StatusPending = "PENDING"
+	StatusActive  = "ACTIVE"
+	StatusExpired = "EXPIRED"
+)
+
+// SigningKey is the aggregate root. Secret is the plaintext; the repository
+// seals it at rest and never returns it over the API after creation.
+type SigningKey struct {
+	ID         string     `json:"id"`
+	ClientID   string     `json:"clientId"`
+	KeyID      string     `json:"keyId"`
+	Secret     string     `json:"-"`
+	ActiveFrom time.Time  `json:"activeFrom"`
+	ExpiresAt  *time.Time `json:"expiresAt,omitempty"`
+	CreatedBy  *string    `json:"createdBy,omitempty"`
+	CreatedAt  time.Time  `json:"createdAt"`
+	UpdatedAt  time.Time  `json:"updatedAt"`
+}
+
+// IDStr satisfies usecase.HasID.
+func (k SigningKey) IDStr() string { return k.ID }
+
+// New constructs a key with a fresh secret, in effect from activeFrom.
+func New(clientID, keyID string, activeFrom time.Time) *SigningKey {
+	now := time.Now().UTC()
+	return &SigningKey{
+		ID:         tsid.Generate(tsid.ClientSigningKey),
+		ClientID:   clientID,
+		KeyID:      keyID,
+		Secret:     generateSecret(),
+		ActiveFrom: activeFrom.UTC(),
+		CreatedAt:  now,
+		UpdatedAt:  now,
+	}
+}
+
+// ActiveAt reports whether t falls in the key's window [ActiveFrom, ExpiresAt).
+func (k *SigningKey) ActiveAt(t time.Time) bool {
+	return !t.Before(k.ActiveFrom) && (k.ExpiresAt == nil || t.Before(*k.ExpiresAt))
+}
+
+// Expired reports whether the key can no longer sign or verify at t.
+func (k *SigningKey) Expired(t time.Time) bool {
+	return k.ExpiresAt != nil && !t.Before(*k.ExpiresAt)
+}
+
+// StatusAt reports where t falls relative to the key's window.
+func (k *SigningKey) StatusAt(t time.Time) string {
+	switch {
+	case k.Expired(t):
+		return StatusExpired
+	case t.Before(k.ActiveFrom):
+		return StatusPending
+	default:
+		return StatusActive
+	}
+}
+
+// ExpireAt ends the key's window at t. An earlier expiry already set wins:
+// rotation never extends a key's life.
+func (k *SigningKey) ExpireAt(t time.Time) {
+	t = t.UTC()
+	if k.ExpiresAt != nil && k.ExpiresAt.Before(t) {
+		return
+	}
+	k.ExpiresAt = &t
+	k.UpdatedAt = time.Now().UTC()
+}
+
+// generateSecret returns 32 random bytes URL-safe-base64 encoded without
+// padding, the same shape as a service account signing secret.
+func generateSecret() string {
+	b := make([]byte, 32)
+	_, _ = rand.Read(b)
+	return base64.RawURLEncoding.EncodeToString(b)
+}
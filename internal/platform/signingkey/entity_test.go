@@ -0,0 +1,37 @@
+package signingkey
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestSigningKeyWindow(t *testing.T) {
+	t0 := time.Date(2026, 10, 1, 12, 0, 0, 0, time.UTC)
+	k := New("clt_0000000000001", "v1", t0)
+	assert.NotEmpty(t, k.Secret)
+	assert.NotEqual(t, k.Secret, New("clt_0000000000001", "v2", t0).Secret)
+
+	assert.Equal(t, StatusPending, k.StatusAt(t0.Add(-time.Second)))
+	assert.False(t, k.ActiveAt(t0.Add(-time.Second)))
+	assert.Equal(t, StatusActive, k.StatusAt(t0))
+	assert.True(t, k.ActiveAt(t0.Add(365*24*time.Hour)), "no expiry means open-ended")
+
+	k.ExpireAt(t0.Add(time.Hour))
+	assert.True(t, k.ActiveAt(t0.Add(59*time.Minute)))
+	assert.False(t, k.ActiveAt(t0.Add(time.Hour)), "the window is half-open")
+	assert.Equal(t, StatusExpired, k.StatusAt(t0.Add(time.Hour)))
+}
+
+func TestSigningKeyExpireAtNeverExtends(t *testing.T) {
+	t0 := time.Date(2026, 10, 1, 12, 0, 0, 0, time.UTC)
+	k := New("clt_0000000000001", "v1", t0)
+
+	k.ExpireAt(t0.Add(time.Hour))
+	k.ExpireAt(t0.Add(24 * time.Hour))
+	assert.Equal(t, t0.Add(time.Hour), *k.ExpiresAt)
+
+	k.ExpireAt(t0.Add(time.Minute))
+	assert.Equal(t, t0.Add(time.Minute), *k.ExpiresAt, "an earlier expiry shortens the window")
+}
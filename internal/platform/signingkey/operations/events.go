@@ -0,0 +1,87 @@
+package operations
+
+import (
+	"encoding/json"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+const (
+	SigningKeyRotatedType = "platform:admin:signing-key:rotated"
+	SigningKeyExpiredType = "platform:admin:signing-key:expired"
+	Source                = "platform:admin"
+)
+
+func subjectFor(id string) string { return "platform.signing-key." + id }
+
+// groupFor orders a client's key events together: a rotation and the
+// expiry that follows must be seen in that order.
+func groupFor(clientID string) string { return "platform:signing-key:" + clientID }
+
+// SigningKeyRotated is emitted when a key is added. RetiringKeyIDs are the
+// keys given RetireAt as their expiry. The secret is never in the event.
+type SigningKeyRotated struct {
+	Metadata       usecase.EventMetadata
+	SigningKeyID   string
+	ClientID       string
+	KeyID          string
+	ActiveFrom     time.Time
+	RetiringKeyIDs []string
+	RetireAt       *time.Time
+}
+
+func (e SigningKeyRotated) EventID() string       { return e.Metadata.EventID }
+func (e SigningKeyRotated) EventType() string     { return SigningKeyRotatedType }
+func (e SigningKeyRotated) SpecVersion() string   { return "1.0" }
+func (e SigningKeyRotated) Source() string        { return Source }
+func (e SigningKeyRotated) Subject() string       { return subjectFor(e.SigningKeyID) }
+func (e SigningKeyRotated) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e SigningKeyRotated) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e SigningKeyRotated) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e SigningKeyRotated) CausationID() string   { return e.Metadata.CausationID }
+func (e SigningKeyRotated) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e SigningKeyRotated) MessageGroup() string  { return groupFor(e.ClientID) }
+func (e SigningKeyRotated) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		SigningKeyID   string     `json:"signingKeyId"`
+		ClientID       string     `json:"clientId"`
+		KeyID          string     `json:"keyId"`
+		ActiveFrom     time.Time  `json:"activeFrom"`
+		RetiringKeyIDs []string   `json:"retiringKeyIds"`
+		RetireAt       *time.Time `json:"retireAt,omitempty"`
+	}{e.SigningKeyID, e.ClientID, e.KeyID, e.ActiveFrom, e.RetiringKeyIDs, e.RetireAt})
+}
+
+func (e SigningKeyRotated) OwningClientIDs() []string { return []string{e.ClientID} }
+
+// SigningKeyExpired is emitted when a key's expiry is set directly.
+type SigningKeyExpired struct {
+	Metadata     usecase.EventMetadata
+	SigningKeyID string
+	ClientID     string
+	KeyID        string
+	ExpiresAt    time.Time
+}
+
+func (e SigningKeyExpired) EventID() string       { return e.Metadata.EventID }
+func (e SigningKeyExpired) EventType() string     { return SigningKeyExpiredType }
+func (e SigningKeyExpired) SpecVersion() string   { return "1.0" }
+func (e SigningKeyExpired) Source() string        { return Source }
+func (e SigningKeyExpired) Subject() string       { return subjectFor(e.SigningKeyID) }
+func (e SigningKeyExpired) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e SigningKeyExpired) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e SigningKeyExpired) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e SigningKeyExpired) CausationID() string   { return e.Metadata.CausationID }
+func (e SigningKeyExpired) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e SigningKeyExpired) MessageGroup() string  { return groupFor(e.ClientID) }
+func (e SigningKeyExpired) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		SigningKeyID string    `json:"signingKeyId"`
+		ClientID     string    `json:"clientId"`
+		KeyID        string    `json:"keyId"`
+		ExpiresAt    time.Time `json:"expiresAt"`
+	}{e.SigningKeyID, e.ClientID, e.KeyID, e.ExpiresAt})
+}
+
+func (e SigningKeyExpired) OwningClientIDs() []string { return []string{e.ClientID} }
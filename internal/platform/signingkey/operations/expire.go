@@ -0,0 +1,71 @@
+package operations
+
+import (
+	"context"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// ExpireCommand is the input DTO.
+type ExpireCommand struct {
+	ID string `json:"id"`
+	// At ends the key's window; defaults to now (revocation).
+	At *time.Time `json:"at,omitempty"`
+}
+
+// ExpireSigningKey ends a key's window early — to revoke a leaked secret,
+// or to close a rotation overlap once receivers have switched. It never
+// extends a window. Emits [SigningKeyExpired].
+func ExpireSigningKey(repo *signingkey.Repository) usecaseop.Operation[ExpireCommand, SigningKeyExpired] {
+	return usecaseop.Operation[ExpireCommand, SigningKeyExpired]{
+		Name: "ExpireSigningKey",
+		Validate: func(_ context.Context, cmd ExpireCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "id is required")
+			}
+			if cmd.At != nil && cmd.At.After(time.Now().Add(MaxOverlap)) {
+				return usecase.Validation("INVALID_EXPIRY", "at must be within 90 days")
+			}
+			return nil
+		},
+		// Per-resource authz runs post-load in Execute; the coarse "may
+		// manage signing keys" permission is on the controller.
+		Authorize: usecaseop.Public[ExpireCommand],
+		Execute: func(ctx context.Context, cmd ExpireCommand, ec usecase.ExecutionContext) (usecaseop.Plan[SigningKeyExpired], error) {
+			k, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if k == nil {
+				return nil, httperror.NotFound("SigningKey", cmd.ID)
+			}
+			if err := auth.CheckScopeAccess(auth.FromContext(ctx), &k.ClientID); err != nil {
+				return nil, err
+			}
+			now := time.Now().UTC()
+			if k.Expired(now) {
+				return nil, usecase.BusinessRule("ALREADY_EXPIRED", "Signing key '"+k.KeyID+"' has already expired")
+			}
+			at := now
+			if cmd.At != nil && cmd.At.After(now) {
+				at = cmd.At.UTC()
+			}
+			k.ExpireAt(at)
+
+			event := SigningKeyExpired{
+				Metadata:     usecase.NewEventMetadata(ec, SigningKeyExpiredType, Source, subjectFor(k.ID)),
+				SigningKeyID: k.ID,
+				ClientID:     k.ClientID,
+				KeyID:        k.KeyID,
+				ExpiresAt:    *k.ExpiresAt,
+			}
+			return usecaseop.Save(k, repo, event), nil
+		},
+	}
+}
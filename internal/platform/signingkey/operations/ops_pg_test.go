@@ -0,0 +1,174 @@
+//go:build integration
+
+package operations_test
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	clientops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/client/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/webhook"
+)
+
+func TestMain(m *testing.M) { testpg.RunMain(m) }
+
+func runAuthorized[C any, E usecase.DomainEvent](
+	uow *usecasepgx.UnitOfWork, op usecaseop.Operation[C, E], cmd C,
+) (E, error) {
+	return usecaseop.Run(testpg.AnchorCtx(), uow, op, cmd, testpg.TestEC())
+}
+
+func newRepo(t *testing.T) *signingkey.Repository {
+	t.Helper()
+	key, err := encryption.GenerateKey()
+	require.NoError(t, err)
+	enc, err := encryption.New(key)
+	require.NoError(t, err)
+	return signingkey.NewRepository(testpg.Pool(t), enc)
+}
+
+// mustCreateClient seeds a real client: rotation checks the client exists.
+func mustCreateClient(t *testing.T, uow *usecasepgx.UnitOfWork, identifier string) string {
+	t.Helper()
+	ev, err := usecaseop.Run(testpg.AnchorCtx(), uow, clientops.CreateClient(client.NewRepository(testpg.Pool(t))),
+		clientops.CreateCommand{Name: identifier, Identifier: identifier}, testpg.TestEC())
+	require.NoError(t, err)
+	return ev.ClientID
+}
+
+func TestRotateSigningKey_OverlapsOldAndNew(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := newRepo(t)
+	uow := testpg.NewUoW(t)
+	clients := client.NewRepository(testpg.Pool(t))
+	clientID := mustCreateClient(t, uow, "sigkey-rotate")
+
+	first, err := runAuthorized(uow, operations.RotateSigningKey(repo, clients), operations.RotateCommand{ClientID: clientID})
+	require.NoError(t, err)
+	assert.Equal(t, "v1", first.KeyID)
+	assert.Empty(t, first.RetiringKeyIDs, "the first key replaces nothing")
+
+	overlap := int64(3600)
+	second, err := runAuthorized(uow, operations.RotateSigningKey(repo, clients),
+		operations.RotateCommand{ClientID: clientID, KeyID: "2026-10", OverlapSeconds: &overlap})
+	require.NoError(t, err)
+	assert.Equal(t, []string{"v1"}, second.RetiringKeyIDs)
+	require.NotNil(t, second.RetireAt)
+	assert.WithinDuration(t, second.ActiveFrom.Add(time.Hour), *second.RetireAt, time.Second)
+
+	now := time.Now().UTC()
+	old, err := repo.FindByID(ctx, first.SigningKeyID)
+	require.NoError(t, err)
+	assert.True(t, old.ActiveAt(now), "the old key stays valid through the overlap")
+	assert.False(t, old.ActiveAt(now.Add(2*time.Hour)))
+
+	cur, err := repo.Current(ctx, clientID, now)
+	require.NoError(t, err)
+	require.NotNil(t, cur)
+	assert.Equal(t, "2026-10", cur.KeyID, "the newest key signs")
+	assert.NotEqual(t, old.Secret, cur.Secret)
+
+	// A receiver holding both secrets verifies deliveries from either key.
+	signer := signingkey.NewSigner(repo)
+	body := []byte(`{"a":1}`)
+	h, err := signer.SignDelivery(ctx, clientID, body)
+	require.NoError(t, err)
+	assert.Equal(t, "2026-10", h.Get(webhook.KeyIDHeader))
+	ring := webhook.NewKeyRing(map[string]string{old.KeyID: old.Secret, cur.KeyID: cur.Secret})
+	assert.NoError(t, ring.Verify(body, h.Get(webhook.SignatureHeader), h.Get(webhook.TimestampHeader), h.Get(webhook.KeyIDHeader)))
+
+	_, err = runAuthorized(uow, operations.RotateSigningKey(repo, clients),
+		operations.RotateCommand{ClientID: clientID, KeyID: "2026-10"})
+	testpg.RequireUsecaseError(t, err, usecase.KindConflict, "KEY_ID_EXISTS")
+}
+
+func TestRotateSigningKey_ScheduledActivation(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := newRepo(t)
+	uow := testpg.NewUoW(t)
+	clients := client.NewRepository(testpg.Pool(t))
+	clientID := mustCreateClient(t, uow, "sigkey-scheduled")
+
+	_, err := runAuthorized(uow, operations.RotateSigningKey(repo, clients), operations.RotateCommand{ClientID: clientID})
+	require.NoError(t, err)
+	later := time.Now().Add(48 * time.Hour)
+	_, err = runAuthorized(uow, operations.RotateSigningKey(repo, clients),
+		operations.RotateCommand{ClientID: clientID, ActiveFrom: &later})
+	require.NoError(t, err)
+
+	cur, err := repo.Current(ctx, clientID, time.Now())
+	require.NoError(t, err)
+	require.NotNil(t, cur)
+	assert.Equal(t, "v1", cur.KeyID, "the old key signs until the new one activates")
+
+	cur, err = repo.Current(ctx, clientID, later.Add(time.Minute))
+	require.NoError(t, err)
+	require.NotNil(t, cur)
+	assert.Equal(t, "v2", cur.KeyID)
+}
+
+func TestRotateSigningKey_Validation(t *testing.T) {
+	t.Parallel()
+	repo := newRepo(t)
+	uow := testpg.NewUoW(t)
+	clients := client.NewRepository(testpg.Pool(t))
+	op := operations.RotateSigningKey(repo, clients)
+
+	negative := int64(-1)
+	past := time.Now().Add(-time.Hour)
+	cases := []struct {
+		name string
+		cmd  operations.RotateCommand
+		code string
+	}{
+		{"missing client", operations.RotateCommand{}, "CLIENT_ID_REQUIRED"},
+		{"bad key id", operations.RotateCommand{ClientID: "clt_x", KeyID: "has space"}, "INVALID_KEY_ID"},
+		{"negative overlap", operations.RotateCommand{ClientID: "clt_x", OverlapSeconds: &negative}, "INVALID_OVERLAP"},
+		{"past activation", operations.RotateCommand{ClientID: "clt_x", ActiveFrom: &past}, "INVALID_ACTIVE_FROM"},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			_, err := runAuthorized(uow, op, tc.cmd)
+			testpg.RequireUsecaseError(t, err, usecase.KindValidation, tc.code)
+		})
+	}
+
+	_, err := runAuthorized(uow, op, operations.RotateCommand{ClientID: "clt_0000000000000"})
+	testpg.RequireUsecaseError(t, err, usecase.KindNotFound, "Client_NOT_FOUND")
+}
+
+func TestExpireSigningKey(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := newRepo(t)
+	uow := testpg.NewUoW(t)
+	clients := client.NewRepository(testpg.Pool(t))
+	clientID := mustCreateClient(t, uow, "sigkey-expire")
+
+	ev, err := runAuthorized(uow, operations.RotateSigningKey(repo, clients), operations.RotateCommand{ClientID: clientID})
+	require.NoError(t, err)
+
+	_, err = runAuthorized(uow, operations.ExpireSigningKey(repo), operations.ExpireCommand{ID: ev.SigningKeyID})
+	require.NoError(t, err)
+
+	cur, err := repo.Current(ctx, clientID, time.Now().Add(time.Second))
+	require.NoError(t, err)
+	assert.Nil(t, cur, "a revoked key no longer signs")
+
+	_, err = runAuthorized(uow, operations.ExpireSigningKey(repo), operations.ExpireCommand{ID: ev.SigningKeyID})
+	testpg.RequireUsecaseError(t, err, usecase.KindBusinessRule, "ALREADY_EXPIRED")
+}
@@ -0,0 +1,139 @@
+package operations
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// DefaultOverlap is how long the keys a rotation replaces stay valid after
+// the new key takes over, when the command doesn't say.
+const DefaultOverlap = 24 * time.Hour
+
+// MaxOverlap and MaxActivationDelay bound the windows a rotation may set.
+const (
+	MaxOverlap         = 90 * 24 * time.Hour
+	MaxActivationDelay = 30 * 24 * time.Hour
+)
+
+// RotateCommand is the input DTO.
+type RotateCommand struct {
+	ClientID string `json:"clientId"`
+	// KeyID names the new key; defaults to "v<n>".
+	KeyID string `json:"keyId,omitempty"`
+	// ActiveFrom schedules the new key; defaults to now. Until then the
+	// current key keeps signing, so receivers can load the new secret first.
+	ActiveFrom *time.Time `json:"activeFrom,omitempty"`
+	// OverlapSeconds is how long after ActiveFrom the replaced keys stay
+	// valid; defaults to DefaultOverlap.
+	OverlapSeconds *int64 `json:"overlapSeconds,omitempty"`
+}
+
+// RotateSigningKey adds a signing key for a client and schedules every key
+// still in effect to expire OverlapSeconds after the new one takes over.
+// The first key of a client is the same operation with nothing to retire.
+// Emits [SigningKeyRotated]; the caller reloads the key to show its secret.
+func RotateSigningKey(repo *signingkey.Repository, clients *client.Repository) usecaseop.Operation[RotateCommand, SigningKeyRotated] {
+	return usecaseop.Operation[RotateCommand, SigningKeyRotated]{
+		Name: "RotateSigningKey",
+		Validate: func(_ context.Context, cmd RotateCommand) error {
+			if strings.TrimSpace(cmd.ClientID) == "" {
+				return usecase.Validation("CLIENT_ID_REQUIRED", "clientId is required")
+			}
+			if cmd.KeyID != "" && !signingkey.KeyIDPattern.MatchString(cmd.KeyID) {
+				return usecase.Validation("INVALID_KEY_ID",
+					"keyId must be 1-64 letters, digits, '.', '_' or '-', starting with a letter or digit")
+			}
+			if cmd.OverlapSeconds != nil {
+				if o := time.Duration(*cmd.OverlapSeconds) * time.Second; o < 0 || o > MaxOverlap {
+					return usecase.Validation("INVALID_OVERLAP",
+						fmt.Sprintf("overlapSeconds must be between 0 and %d", int64(MaxOverlap/time.Second)))
+				}
+			}
+			if cmd.ActiveFrom != nil {
+				// A minute of slack for clock drift between the caller and us.
+				now := time.Now()
+				if cmd.ActiveFrom.Before(now.Add(-time.Minute)) || cmd.ActiveFrom.After(now.Add(MaxActivationDelay)) {
+					return usecase.Validation("INVALID_ACTIVE_FROM",
+						"activeFrom must be between now and 30 days from now")
+				}
+			}
+			return nil
+		},
+		Authorize: func(ctx context.Context, cmd RotateCommand) error {
+			return auth.CheckScopeAccess(auth.FromContext(ctx), &cmd.ClientID)
+		},
+		Execute: func(ctx context.Context, cmd RotateCommand, ec usecase.ExecutionContext) (usecaseop.Plan[SigningKeyRotated], error) {
+			c, err := clients.FindByID(ctx, cmd.ClientID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find client failed", err)
+			}
+			if c == nil {
+				return nil, httperror.NotFound("Client", cmd.ClientID)
+			}
+			existing, err := repo.FindByClient(ctx, cmd.ClientID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_client failed", err)
+			}
+
+			keyID := cmd.KeyID
+			if keyID == "" {
+				keyID = "v" + strconv.Itoa(len(existing)+1)
+			}
+			for _, k := range existing {
+				if k.KeyID == keyID {
+					return nil, usecase.Conflict("KEY_ID_EXISTS",
+						"Signing key '"+keyID+"' already exists for this client")
+				}
+			}
+
+			now := time.Now().UTC()
+			activeFrom := now
+			if cmd.ActiveFrom != nil && cmd.ActiveFrom.After(now) {
+				activeFrom = cmd.ActiveFrom.UTC()
+			}
+			overlap := DefaultOverlap
+			if cmd.OverlapSeconds != nil {
+				overlap = time.Duration(*cmd.OverlapSeconds) * time.Second
+			}
+
+			key := signingkey.New(cmd.ClientID, keyID, activeFrom)
+			if ec.PrincipalID != "" {
+				key.CreatedBy = &ec.PrincipalID
+			}
+			save := []signingkey.SigningKey{*key}
+			retiring := []string{}
+			var retireAt *time.Time
+			for _, k := range existing {
+				if k.Expired(now) {
+					continue
+				}
+				at := activeFrom.Add(overlap)
+				k.ExpireAt(at)
+				save = append(save, k)
+				retiring = append(retiring, k.KeyID)
+				retireAt = &at
+			}
+
+			event := SigningKeyRotated{
+				Metadata:       usecase.NewEventMetadata(ec, SigningKeyRotatedType, Source, subjectFor(key.ID)),
+				SigningKeyID:   key.ID,
+				ClientID:       key.ClientID,
+				KeyID:          key.KeyID,
+				ActiveFrom:     key.ActiveFrom,
+				RetiringKeyIDs: retiring,
+				RetireAt:       retireAt,
+			}
+			return usecaseop.SaveAll(save, repo, event), nil
+		},
+	}
+}
@@ -0,0 +1,121 @@
+package signingkey
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// Repository is the Postgres-backed repo. Table: tnt_client_signing_keys.
+// Secrets are sealed with the app key on write and opened on read; without
+// one (FLOWCATALYST_APP_KEY unset) writes fail with
+// encryption.ErrNotConfigured rather than storing a plaintext secret.
+type Repository struct {
+	q   *dbq.Queries
+	enc *encryption.Service
+}
+
+// NewRepository wires a repo. enc may be nil; see Repository.
+func NewRepository(pool *pgxpool.Pool, enc *encryption.Service) *Repository {
+	return &Repository{q: dbq.New(pool), enc: enc}
+}
+
+// FindByID loads by primary key.
+func (r *Repository) FindByID(ctx context.Context, id string) (*SigningKey, error) {
+	res, err := r.q.SigningKeyFindByID(ctx, id)
+	row, err := repocommon.One(res, err, "signing_key repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return r.rowToKey(*row)
+}
+
+// FindByClient returns every key of a client, newest window first.
+func (r *Repository) FindByClient(ctx context.Context, clientID string) ([]SigningKey, error) {
+	rows, err := r.q.SigningKeyFindByClient(ctx, clientID)
+	if err != nil {
+		return nil, err
+	}
+	out := make([]SigningKey, 0, len(rows))
+	for _, row := range rows {
+		k, err := r.rowToKey(row)
+		if err != nil {
+			return nil, err
+		}
+		out = append(out, *k)
+	}
+	return out, nil
+}
+
+// Current returns the key that signs the client's deliveries at t: the
+// newest whose window contains t. Nil when the client has none.
+func (r *Repository) Current(ctx context.Context, clientID string, t time.Time) (*SigningKey, error) {
+	keys, err := r.FindByClient(ctx, clientID)
+	if err != nil {
+		return nil, err
+	}
+	for i := range keys {
+		if keys[i].ActiveAt(t) {
+			return &keys[i], nil
+		}
+	}
+	return nil, nil
+}
+
+// Persist implements usecasepgx.Persist[SigningKey].
+func (r *Repository) Persist(ctx context.Context, k *SigningKey, tx *usecasepgx.DbTx) error {
+	if r.enc == nil {
+		return encryption.ErrNotConfigured
+	}
+	sealed, err := r.enc.Encrypt(k.Secret)
+	if err != nil {
+		return fmt.Errorf("signing_key repo: seal secret: %w", err)
+	}
+	return r.q.WithTx(tx.Inner()).SigningKeyUpsert(ctx, dbq.SigningKeyUpsertParams{
+		ID:         k.ID,
+		ClientID:   k.ClientID,
+		KeyID:      k.KeyID,
+		SecretEnc:  sealed,
+		ActiveFrom: k.ActiveFrom,
+		ExpiresAt:  k.ExpiresAt,
+		CreatedBy:  k.CreatedBy,
+		CreatedAt:  k.CreatedAt,
+		UpdatedAt:  time.Now().UTC(),
+	})
+}
+
+// Delete implements usecasepgx.Persist[SigningKey].Delete. Keys are never
+// deleted, only expired, so the audit trail keeps every key id a receiver
+// may have seen.
+func (r *Repository) Delete(_ context.Context, _ *SigningKey, _ *usecasepgx.DbTx) error {
+	return errors.New("signing keys cannot be deleted")
+}
+
+func (r *Repository) rowToKey(row dbq.TntClientSigningKey) (*SigningKey, error) {
+	if r.enc == nil {
+		return nil, encryption.ErrNotConfigured
+	}
+	secret, err := r.enc.Decrypt(row.SecretEnc)
+	if err != nil {
+		return nil, fmt.Errorf("signing_key repo: open secret of %s: %w", row.ID, err)
+	}
+	return &SigningKey{
+		ID:         row.ID,
+		ClientID:   row.ClientID,
+		KeyID:      row.KeyID,
+		Secret:     secret,
+		ActiveFrom: row.ActiveFrom,
+		ExpiresAt:  row.ExpiresAt,
+		CreatedBy:  row.CreatedBy,
+		CreatedAt:  row.CreatedAt,
+		UpdatedAt:  row.UpdatedAt,
+	}, nil
+}
@@ -0,0 +1,39 @@
+package signingkey
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/webhook"
+)
+
+// Signer signs deliveries with the client's current key. Satisfies
+// processing.Signer.
+type Signer struct{ repo *Repository }
+
+// NewSigner wires a signer.
+func NewSigner(repo *Repository) *Signer {
+	return &Signer{repo: repo}
+}
+
+// SignDelivery returns the signature, timestamp and key-id headers for
+// body. A client with no key in effect gets no headers: signing is opt-in
+// per client.
+func (s *Signer) SignDelivery(ctx context.Context, clientID string, body []byte) (http.Header, error) {
+	now := time.Now().UTC()
+	key, err := s.repo.Current(ctx, clientID, now)
+	if err != nil {
+		return nil, fmt.Errorf("load signing key: %w", err)
+	}
+	if key == nil {
+		return nil, nil
+	}
+	ts := now.Format("2006-01-02T15:04:05.000Z")
+	h := make(http.Header, 3)
+	h.Set(webhook.SignatureHeader, webhook.Sign(key.Secret, ts, body))
+	h.Set(webhook.TimestampHeader, ts)
+	h.Set(webhook.KeyIDHeader, key.KeyID)
+	return h, nil
+}
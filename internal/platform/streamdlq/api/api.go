@@ -0,0 +1,82 @@
+// Package api wires HTTP routes for the stream processor's dead-letter
+// queue via huma.
+package api
+
+import (
+	"context"
+	"net/http"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/streamdlq"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+)
+
+// State bundles deps.
+type State struct {
+	Repo *streamdlq.Repository
+}
+
+const tag = "stream-dlq"
+
+// Register mounts the DLQ endpoints. All are anchor-only.
+func Register(api huma.API, s *State) {
+	g := apiroute.New(api, tag)
+	apiroute.Get(g, "listStreamDlq", "/api/admin/stream-dlq", "List events the stream fan-out dead-lettered", s.list)
+	apiroute.Post(g, "reprocessStreamDlqEntry", "/api/admin/stream-dlq/{id}/reprocess", "Return a dead-lettered event to fan-out", http.StatusNoContent, s.reprocess)
+	apiroute.Delete(g, "discardStreamDlqEntry", "/api/admin/stream-dlq/{id}", "Discard a dead-lettered event", http.StatusNoContent, s.discard)
+}
+
+type listInput struct {
+	Limit int `query:"limit" doc:"Maximum entries to return (default 50, max 500)"`
+}
+
+func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[StreamDLQListResponse], error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	limit := in.Limit
+	if limit <= 0 {
+		limit = 50
+	}
+	limit = min(limit, 500)
+	rows, err := s.Repo.List(ctx, limit)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "list failed", err)
+	}
+	out := apicommon.MapSlice(rows, entryFromEntity)
+	return &apicommon.Out[StreamDLQListResponse]{Body: StreamDLQListResponse{Items: out}}, nil
+}
+
+// reprocess un-stamps the event; fan-out retries it on its next pass.
+func (s *State) reprocess(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	found, err := s.Repo.Reprocess(ctx, in.ID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "reprocess failed", err)
+	}
+	if !found {
+		return nil, httperror.NotFound("StreamDlqEntry", in.ID)
+	}
+	return &apicommon.Empty{}, nil
+}
+
+func (s *State) discard(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+	if err := auth.RequireAnchor(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	found, err := s.Repo.Discard(ctx, in.ID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "discard failed", err)
+	}
+	if !found {
+		return nil, httperror.NotFound("StreamDlqEntry", in.ID)
+	}
+	return &apicommon.Empty{}, nil
+}
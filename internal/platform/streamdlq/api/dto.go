@@ -0,0 +1,41 @@
+// dto.go contains the wire-format types for the stream DLQ API.
+package api
+
+import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/streamdlq"
+)
+
+// StreamDLQEntryResponse is one dead-lettered event. The id is the
+// event's id.
+type StreamDLQEntryResponse struct {
+	ID             string    `json:"id"`
+	EventCreatedAt time.Time `json:"eventCreatedAt"`
+	EventType      string    `json:"eventType"`
+	MessageGroup   *string   `json:"messageGroup,omitempty"`
+	Stage          string    `json:"stage" doc:"Pipeline step that failed: FAN_OUT"`
+	Error          string    `json:"error" doc:"The last failure"`
+	Attempts       int       `json:"attempts" doc:"Failed attempts, summed across reprocessing"`
+	CreatedAt      time.Time `json:"createdAt"`
+	UpdatedAt      time.Time `json:"updatedAt"`
+}
+
+// StreamDLQListResponse is the wire shape for GET /api/admin/stream-dlq.
+type StreamDLQListResponse struct {
+	Items []StreamDLQEntryResponse `json:"items"`
+}
+
+func entryFromEntity(e *streamdlq.Entry) StreamDLQEntryResponse {
+	return StreamDLQEntryResponse{
+		ID:             e.EventID,
+		EventCreatedAt: e.EventCreatedAt,
+		EventType:      e.EventType,
+		MessageGroup:   e.MessageGroup,
+		Stage:          e.Stage,
+		Error:          e.Error,
+		Attempts:       e.Attempts,
+		CreatedAt:      e.CreatedAt,
+		UpdatedAt:      e.UpdatedAt,
+	}
+}
@@ -0,0 +1,97 @@
+// Package streamdlq exposes msg_stream_dlq: events the stream processor's
+// fan-out gave up on after repeated failures to insert their dispatch
+// jobs. The stream package writes entries (it does not import the
+// platform); this package lists them and lets an operator reprocess or
+// discard them. Writes are infrastructure processing — no UoW, like the
+// stream projections themselves.
+package streamdlq
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// Entry is one dead-lettered event.
+type Entry struct {
+	EventID        string
+	EventCreatedAt time.Time
+	EventType      string
+	MessageGroup   *string
+	// Stage is the pipeline step that failed; FAN_OUT today.
+	Stage     string
+	Error     string
+	Attempts  int
+	CreatedAt time.Time
+	UpdatedAt time.Time
+}
+
+// Repository reads and resolves DLQ entries.
+type Repository struct{ pool *pgxpool.Pool }
+
+// NewRepository wires a repo.
+func NewRepository(pool *pgxpool.Pool) *Repository { return &Repository{pool: pool} }
+
+// List returns up to limit entries, oldest first.
+func (r *Repository) List(ctx context.Context, limit int) ([]Entry, error) {
+	rows, err := r.pool.Query(ctx,
+		`SELECT event_id, event_created_at, event_type, message_group, stage,
+		        error, attempts, created_at, updated_at
+		   FROM msg_stream_dlq
+		  ORDER BY created_at, event_id
+		  LIMIT $1`, limit)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	out := []Entry{}
+	for rows.Next() {
+		var e Entry
+		if err := rows.Scan(&e.EventID, &e.EventCreatedAt, &e.EventType, &e.MessageGroup, &e.Stage,
+			&e.Error, &e.Attempts, &e.CreatedAt, &e.UpdatedAt); err != nil {
+			return nil, err
+		}
+		out = append(out, e)
+	}
+	return out, rows.Err()
+}
+
+// Reprocess removes the entry and clears the event's fanned_out_at so the
+// next fan-out pass picks it up again, with a fresh attempt budget. False
+// when the event has no entry.
+func (r *Repository) Reprocess(ctx context.Context, eventID string) (bool, error) {
+	tx, err := r.pool.Begin(ctx)
+	if err != nil {
+		return false, err
+	}
+	defer func() { _ = tx.Rollback(ctx) }()
+	var createdAt time.Time
+	err = tx.QueryRow(ctx,
+		`DELETE FROM msg_stream_dlq WHERE event_id = $1 RETURNING event_created_at`,
+		eventID).Scan(&createdAt)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return false, nil
+	}
+	if err != nil {
+		return false, err
+	}
+	if _, err := tx.Exec(ctx,
+		`UPDATE msg_events SET fanned_out_at = NULL WHERE id = $1 AND created_at = $2`,
+		eventID, createdAt); err != nil {
+		return false, err
+	}
+	return true, tx.Commit(ctx)
+}
+
+// Discard removes the entry and leaves the event fanned out: none of its
+// dispatch jobs will be created. False when the event has no entry.
+func (r *Repository) Discard(ctx context.Context, eventID string) (bool, error) {
+	tag, err := r.pool.Exec(ctx, `DELETE FROM msg_stream_dlq WHERE event_id = $1`, eventID)
+	if err != nil {
+		return false, err
+	}
+	return tag.RowsAffected() > 0, nil
+}
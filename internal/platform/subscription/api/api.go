@@ -4,13 +4,18 @@ package api
 import (
 	"context"
 	"net/http"
+	"time"
 
 	"github.com/danielgtaylor/huma/v2"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apiroute"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/idempotency"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription/operations"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
@@ -22,6 +27,16 @@ import (
 type State struct {
 	Repo *subscription.Repository
 	UoW  *usecasepgx.UnitOfWork
+	Idem *idempotency.Store // optional; nil ignores Idempotency-Key
+	// Targets is the outbound (SSRF) policy endpoints must pass; nil
+	// leaves them unchecked.
+	Targets *outbound.Policies
+	// Transports are the delivery transports a subscription may select;
+	// nil leaves the name unchecked.
+	Transports *outbound.Transports
+	// Lifecycles refuses new bindings to deprecated event types; nil
+	// leaves them unchecked.
+	Lifecycles *eventtype.LifecycleCache
 }
 
 const tag = "subscriptions"
@@ -32,10 +47,15 @@ func Register(api huma.API, s *State) {
 	apiroute.Get(g, "listSubscriptions", "/api/subscriptions", "List subscriptions", s.list)
 	apiroute.Post(g, "createSubscription", "/api/subscriptions", "Create a subscription", http.StatusCreated, s.create)
 	apiroute.Get(g, "getSubscription", "/api/subscriptions/{id}", "Get a subscription by id", s.getByID)
+	apiroute.Put(g, "upsertSubscription", "/api/subscriptions/by-code/{code}", "Create or update a subscription by code", http.StatusOK, s.upsert)
 	apiroute.Put(g, "updateSubscription", "/api/subscriptions/{id}", "Update a subscription", http.StatusNoContent, s.update)
 	apiroute.Delete(g, "deleteSubscription", "/api/subscriptions/{id}", "Delete a subscription", http.StatusNoContent, s.delete)
 	apiroute.Post(g, "pauseSubscription", "/api/subscriptions/{id}/pause", "Pause a subscription", http.StatusNoContent, s.pause)
 	apiroute.Post(g, "resumeSubscription", "/api/subscriptions/{id}/resume", "Resume a subscription", http.StatusNoContent, s.resume)
+	apiroute.Get(g, "getSubscriptionShadowReport", "/api/subscriptions/{id}/shadow-report", "Compare a subscription's endpoint with its shadow endpoint", s.shadowReport)
+	apiroute.Post(g, "promoteSubscriptionShadow", "/api/subscriptions/{id}/promote-shadow", "Make the shadow endpoint the subscription's endpoint", http.StatusNoContent, s.promoteShadow)
+	apiroute.Put(g, "setSubscriptionTrafficSplit", "/api/subscriptions/{id}/traffic-split", "Set the share of message groups routed to each of a subscription's endpoints", http.StatusNoContent, s.setTrafficSplit)
+	apiroute.Get(g, "getSubscriptionTargetStats", "/api/subscriptions/{id}/target-stats", "Delivery metrics for each of a subscription's endpoints", s.targetStats)
 }
 
 type listInput struct {
@@ -59,7 +79,7 @@ func (s *State) list(ctx context.Context, in *listInput) (*apicommon.Out[Subscri
 	return &apicommon.Out[SubscriptionListResponse]{Body: SubscriptionListResponse{Subscriptions: out, Total: len(out)}}, nil
 }
 
-func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.Out[SubscriptionResponse], error) {
+func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.TaggedOut[SubscriptionResponse], error) {
 	ac := auth.FromContext(ctx)
 	if err := auth.CanReadSubscriptions(ac); err != nil {
 		return nil, err
@@ -74,27 +94,81 @@ func (s *State) getByID(ctx context.Context, in *apicommon.IDInput) (*apicommon.
 	if sub.ClientID != nil && !ac.CanAccessClient(*sub.ClientID) {
 		return nil, httperror.Forbidden("No access to this subscription")
 	}
-	return &apicommon.Out[SubscriptionResponse]{Body: fromEntity(sub)}, nil
+	return &apicommon.TaggedOut[SubscriptionResponse]{ETag: etag.Of(sub.UpdatedAt), Body: fromEntity(sub)}, nil
 }
 
-func (s *State) create(ctx context.Context, in *apicommon.In[CreateSubscriptionRequest]) (*apicommon.Out[apicommon.CreatedResponse], error) {
+func (s *State) create(ctx context.Context, in *apicommon.IdempotentIn[CreateSubscriptionRequest]) (*apicommon.Out[apicommon.CreatedResponse], error) {
 	// Coarse write permission at the controller; the use case enforces per-client
 	// resource access on the requested clientId (you may only bind a subscription
 	// to a client you can access; platform-wide requires anchor).
 	if err := auth.CanWriteSubscriptions(auth.FromContext(ctx)); err != nil {
 		return nil, err
 	}
-	ec := auth.NewExecutionContext(ctx)
-	event, err := usecaseop.Run(ctx, s.UoW, operations.CreateSubscription(s.Repo), in.Body.toCommand(), ec)
+	req := idempotency.Request{Key: in.IdempotencyKey, Scope: "createSubscription", Body: in.Body}
+	created, err := idempotency.Do(ctx, s.Idem, req, func(ctx context.Context) (apicommon.CreatedResponse, error) {
+		ec := auth.NewExecutionContext(ctx)
+		event, err := usecaseop.Run(ctx, s.UoW, operations.CreateSubscription(s.Repo, s.Targets, s.Transports, s.Lifecycles), in.Body.toCommand(), ec)
+		if err != nil {
+			return apicommon.CreatedResponse{}, err
+		}
+		return apicommon.CreatedResponse{ID: event.SubscriptionID}, nil
+	})
+	if err != nil {
+		return nil, err
+	}
+	return &apicommon.Out[apicommon.CreatedResponse]{Body: created}, nil
+}
+
+type upsertInput struct {
+	Code        string `path:"code" doc:"Subscription code"`
+	ClientID    string `query:"clientId" doc:"Client the subscription belongs to; absent means anchor-level"`
+	IfMatch     string `header:"If-Match" doc:"Update only if the subscription exists with this ETag"`
+	IfNoneMatch string `header:"If-None-Match" doc:"Send * to create only, failing if the code is taken"`
+	Body        UpsertSubscriptionRequest
+}
+
+// upsert creates or updates the subscription with this code in the
+// client's scope: 201 if it created it, 200 if it updated it.
+func (s *State) upsert(ctx context.Context, in *upsertInput) (*apicommon.UpsertOut[SubscriptionResponse], error) {
+	if err := auth.CanWriteSubscriptions(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	clientID := apicommon.OptStr(in.ClientID)
+	existing, err := s.Repo.FindByCode(ctx, in.Code, clientID)
 	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_code failed", err)
+	}
+	if err := etag.CheckUpsert(in.IfMatch, in.IfNoneMatch, existing != nil); err != nil {
 		return nil, err
 	}
-	return &apicommon.Out[apicommon.CreatedResponse]{Body: apicommon.CreatedResponse{ID: event.SubscriptionID}}, nil
+	ec := auth.NewExecutionContext(ctx)
+	status, id := http.StatusOK, ""
+	if existing == nil {
+		event, err := usecaseop.Run(ctx, s.UoW, operations.CreateSubscription(s.Repo, s.Targets, s.Transports, s.Lifecycles), in.Body.toCreateCommand(in.Code, clientID), ec)
+		if err != nil {
+			return nil, err
+		}
+		status, id = http.StatusCreated, event.SubscriptionID
+	} else {
+		id = existing.ID
+		if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateSubscription(s.Repo, s.Targets, s.Transports, s.Lifecycles), in.Body.toUpdateCommand(id, in.IfMatch), ec); err != nil {
+			return nil, err
+		}
+	}
+	sub, err := s.Repo.FindByID(ctx, id)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if sub == nil {
+		return nil, httperror.NotFound("Subscription", id)
+	}
+	return &apicommon.UpsertOut[SubscriptionResponse]{Status: status, ETag: etag.Of(sub.UpdatedAt), Body: fromEntity(sub)}, nil
 }
 
 type updateInput struct {
-	ID   string `path:"id"`
-	Body UpdateSubscriptionRequest
+	ID      string `path:"id"`
+	IfMatch string `header:"If-Match" doc:"Proceed only if the subscription's current ETag matches"`
+	Body    UpdateSubscriptionRequest
 }
 
 func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Empty, error) {
@@ -102,18 +176,18 @@ func (s *State) update(ctx context.Context, in *updateInput) (*apicommon.Empty,
 		return nil, err
 	}
 	ec := auth.NewExecutionContext(ctx)
-	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateSubscription(s.Repo), in.Body.toCommand(in.ID), ec); err != nil {
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.UpdateSubscription(s.Repo, s.Targets, s.Transports, s.Lifecycles), in.Body.toCommand(in.ID, in.IfMatch), ec); err != nil {
 		return nil, err
 	}
 	return &apicommon.Empty{}, nil
 }
 
-func (s *State) delete(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+func (s *State) delete(ctx context.Context, in *apicommon.IDMatchInput) (*apicommon.Empty, error) {
 	if err := auth.CanDeleteSubscriptions(auth.FromContext(ctx)); err != nil {
 		return nil, err
 	}
 	ec := auth.NewExecutionContext(ctx)
-	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeleteSubscription(s.Repo), operations.DeleteCommand{ID: in.ID}, ec); err != nil {
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.DeleteSubscription(s.Repo), operations.DeleteCommand{ID: in.ID, IfMatch: in.IfMatch}, ec); err != nil {
 		return nil, err
 	}
 	return &apicommon.Empty{}, nil
@@ -140,3 +214,111 @@ func (s *State) resume(ctx context.Context, in *apicommon.IDInput) (*apicommon.E
 	}
 	return &apicommon.Empty{}, nil
 }
+
+// shadowReportWindow is how far back a report, shadow or per-target,
+// looks when since is absent.
+const shadowReportWindow = 24 * time.Hour
+
+type shadowReportInput struct {
+	ID    string `path:"id"`
+	Since string `query:"since" doc:"RFC3339; compare deliveries copied at or after it. Defaults to the last 24 hours"`
+}
+
+// shadowReport compares the subscription's endpoint with its current
+// shadow endpoint; deliveries copied to an earlier shadow don't count.
+func (s *State) shadowReport(ctx context.Context, in *shadowReportInput) (*apicommon.Out[ShadowReportResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanReadSubscriptions(ac); err != nil {
+		return nil, err
+	}
+	since := time.Now().UTC().Add(-shadowReportWindow)
+	if in.Since != "" {
+		t, err := time.Parse(time.RFC3339, in.Since)
+		if err != nil {
+			return nil, httperror.BadRequest("INVALID_SINCE", "since is not an RFC3339 timestamp")
+		}
+		since = t
+	}
+	sub, err := s.Repo.FindByID(ctx, in.ID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if sub == nil {
+		return nil, httperror.NotFound("Subscription", in.ID)
+	}
+	if sub.ClientID != nil && !ac.CanAccessClient(*sub.ClientID) {
+		return nil, httperror.Forbidden("No access to this subscription")
+	}
+	if sub.ShadowEndpoint == nil {
+		return nil, usecase.NotFound("NO_SHADOW", "subscription has no shadow endpoint: "+in.ID)
+	}
+	rep, err := s.Repo.ShadowReport(ctx, sub.ID, *sub.ShadowEndpoint, since)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "shadow_report failed", err)
+	}
+	return &apicommon.Out[ShadowReportResponse]{Body: shadowReportFromEntity(rep)}, nil
+}
+
+func (s *State) promoteShadow(ctx context.Context, in *apicommon.IDInput) (*apicommon.Empty, error) {
+	if err := auth.CanWriteSubscriptions(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.PromoteShadow(s.Repo, s.Targets), operations.PromoteShadowCommand{ID: in.ID}, ec); err != nil {
+		return nil, err
+	}
+	return &apicommon.Empty{}, nil
+}
+
+type setTrafficSplitInput struct {
+	ID   string `path:"id"`
+	Body SetTrafficSplitRequest
+}
+
+func (s *State) setTrafficSplit(ctx context.Context, in *setTrafficSplitInput) (*apicommon.Empty, error) {
+	if err := auth.CanWriteSubscriptions(auth.FromContext(ctx)); err != nil {
+		return nil, err
+	}
+	ec := auth.NewExecutionContext(ctx)
+	if _, err := usecaseop.Run(ctx, s.UoW, operations.SetTrafficSplit(s.Repo, s.Targets), in.Body.toCommand(in.ID), ec); err != nil {
+		return nil, err
+	}
+	return &apicommon.Empty{}, nil
+}
+
+type targetStatsInput struct {
+	ID    string `path:"id"`
+	Since string `query:"since" doc:"RFC3339; count jobs created at or after it. Defaults to the last 24 hours"`
+}
+
+// targetStats reports how each of the subscription's endpoints fared, for
+// following a traffic split's weights as they change.
+func (s *State) targetStats(ctx context.Context, in *targetStatsInput) (*apicommon.Out[TargetStatsResponse], error) {
+	ac := auth.FromContext(ctx)
+	if err := auth.CanReadSubscriptions(ac); err != nil {
+		return nil, err
+	}
+	since := time.Now().UTC().Add(-shadowReportWindow)
+	if in.Since != "" {
+		t, err := time.Parse(time.RFC3339, in.Since)
+		if err != nil {
+			return nil, httperror.BadRequest("INVALID_SINCE", "since is not an RFC3339 timestamp")
+		}
+		since = t
+	}
+	sub, err := s.Repo.FindByID(ctx, in.ID)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "find_by_id failed", err)
+	}
+	if sub == nil {
+		return nil, httperror.NotFound("Subscription", in.ID)
+	}
+	if sub.ClientID != nil && !ac.CanAccessClient(*sub.ClientID) {
+		return nil, httperror.Forbidden("No access to this subscription")
+	}
+	stats, err := s.Repo.TargetStats(ctx, sub, since)
+	if err != nil {
+		return nil, usecase.Internal("REPO", "target_stats failed", err)
+	}
+	return &apicommon.Out[TargetStatsResponse]{Body: targetStatsFromEntity(since, stats)}, nil
+}
@@ -2,6 +2,10 @@
 package api
 
 import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/apicommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/jsontime"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
@@ -48,11 +52,77 @@ func configEntryFromEntity(c subscription.ConfigEntry) ConfigEntryDTO {
 	return ConfigEntryDTO{Key: c.Key, Value: c.Value}
 }
 
+// SplitTargetDTO mirrors subscription.SplitTarget.
+type SplitTargetDTO struct {
+	Endpoint string `json:"endpoint"`
+	Weight   int32  `json:"weight" doc:"Percentage of message groups routed to this endpoint, 1-100"`
+}
+
+// configEntriesToEntity keeps nil as nil: on update an absent list means
+// "leave unchanged", an empty one clears it.
+func configEntriesToEntity(in []ConfigEntryDTO) []subscription.ConfigEntry {
+	if in == nil {
+		return nil
+	}
+	out := make([]subscription.ConfigEntry, 0, len(in))
+	for _, c := range in {
+		out = append(out, c.toEntity())
+	}
+	return out
+}
+
+// BatchingDTO mirrors subscription.Batching: a push subscription's due
+// dispatch jobs delivered together, as one JSON array. On update it
+// replaces the current limits; maxItems 0 switches batching off.
+type BatchingDTO struct {
+	MaxItems       int32 `json:"maxItems" doc:"Most dispatch jobs POSTed together as one JSON array (at most 100); 0 or 1 delivers one at a time"`
+	MaxBytes       int32 `json:"maxBytes,omitempty" doc:"Cap on the array's size in bytes; 0 means no cap"`
+	MaxWaitSeconds int32 `json:"maxWaitSeconds,omitempty" doc:"How long a short batch may wait for more jobs, from its oldest job's creation (at most 300); 0 sends at once"`
+}
+
+func (b *BatchingDTO) toEntity() *subscription.Batching {
+	if b == nil {
+		return nil
+	}
+	return &subscription.Batching{MaxItems: b.MaxItems, MaxBytes: b.MaxBytes, MaxWaitSeconds: b.MaxWaitSeconds}
+}
+
+func batchingFromEntity(b *subscription.Batching) *BatchingDTO {
+	if b == nil {
+		return nil
+	}
+	return &BatchingDTO{MaxItems: b.MaxItems, MaxBytes: b.MaxBytes, MaxWaitSeconds: b.MaxWaitSeconds}
+}
+
+// TLSTrustDTO mirrors outbound.TLSTrust: trust for a receiver behind a
+// private CA, layered over the deployment's FC_OUTBOUND_TLS_* trust. On
+// update it replaces the current trust; {} drops it.
+type TLSTrustDTO struct {
+	CABundle   string   `json:"caBundle,omitempty" doc:"PEM root certificates trusted in addition to the system roots (at most 256 KiB)"`
+	SPKIPins   []string `json:"spkiPins,omitempty" doc:"\"sha256/<base64>\" hashes of subject public key infos; when set, a certificate in the verified chain must match one (at most 10)"`
+	MinVersion string   `json:"minVersion,omitempty" doc:"Lowest TLS version accepted: 1.2 or 1.3"`
+}
+
+func (t *TLSTrustDTO) toEntity() *outbound.TLSTrust {
+	if t == nil {
+		return nil
+	}
+	return &outbound.TLSTrust{CABundle: t.CABundle, SPKIPins: t.SPKIPins, MinVersion: t.MinVersion}
+}
+
+func tlsTrustFromEntity(t *outbound.TLSTrust) *TLSTrustDTO {
+	if t == nil {
+		return nil
+	}
+	return &TLSTrustDTO{CABundle: t.CABundle, SPKIPins: t.SPKIPins, MinVersion: t.MinVersion}
+}
+
 // CreateSubscriptionRequest is the wire body for POST /api/subscriptions.
 type CreateSubscriptionRequest struct {
 	Code             string                `json:"code"`
 	Name             string                `json:"name"`
-	Endpoint         string                `json:"endpoint" doc:"http(s) URL delivery target"`
+	Endpoint         string                `json:"endpoint,omitempty" doc:"http(s) URL delivery target; required unless delivery is PULL"`
+	ShadowEndpoint   *string               `json:"shadowEndpoint,omitempty" doc:"http(s) URL each push delivery is also copied to, for comparing a new endpoint before promoting it; its answers never affect the delivery"`
 	Description      *string               `json:"description,omitempty"`
 	ClientID         *string               `json:"clientId,omitempty"`
 	ConnectionID     *string               `json:"connectionId,omitempty"`
@@ -60,6 +130,13 @@ type CreateSubscriptionRequest struct {
 	ServiceAccountID *string               `json:"serviceAccountId,omitempty"`
 	EventTypes       []EventTypeBindingDTO `json:"eventTypes,omitempty"`
 	CustomConfig     []ConfigEntryDTO      `json:"customConfig,omitempty"`
+	Headers          []ConfigEntryDTO      `json:"headers,omitempty" doc:"Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time"`
+	Transport        *string               `json:"transport,omitempty" doc:"Named outbound transport (SSH bastion or SOCKS5 proxy, see FC_OUTBOUND_TRANSPORTS) deliveries go through; omit to deliver directly"`
+	TLS              *TLSTrustDTO          `json:"tls,omitempty"`
+	Delivery         string                `json:"delivery,omitempty" doc:"PUSH (default) POSTs each dispatch job to the endpoint; PULL holds them for the consumer to fetch from GET /api/consume/{subscriptionId}"`
+	Batching         *BatchingDTO          `json:"batching,omitempty"`
+	PayloadFormat    string                `json:"payloadFormat,omitempty" doc:"How deliveries are serialized: JSON (default), CLOUDEVENTS_JSON, NDJSON or PROTOBUF (transcoded with the event type's current PROTO schema). Pull consumers always receive JSON"`
+	LargePayloads    string                `json:"largePayloads,omitempty" doc:"How a payload offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES is delivered: INLINE (default) rehydrates it; PRESIGNED_URL sends its $blobRef reference with a pre-signed url to fetch it from"`
 	Mode             string                `json:"mode,omitempty" doc:"Dispatch mode (IMMEDIATE, NEXT_ON_ERROR, BLOCK_ON_ERROR)"`
 	TimeoutSeconds   *int32                `json:"timeoutSeconds,omitempty"`
 	MaxRetries       *int32                `json:"maxRetries,omitempty"`
@@ -84,6 +161,7 @@ func (r CreateSubscriptionRequest) toCommand() operations.CreateCommand {
 		Code:             r.Code,
 		Name:             r.Name,
 		Endpoint:         r.Endpoint,
+		ShadowEndpoint:   r.ShadowEndpoint,
 		Description:      r.Description,
 		ClientID:         r.ClientID,
 		ConnectionID:     r.ConnectionID,
@@ -91,6 +169,13 @@ func (r CreateSubscriptionRequest) toCommand() operations.CreateCommand {
 		ServiceAccountID: r.ServiceAccountID,
 		EventTypes:       events,
 		CustomConfig:     config,
+		Headers:          configEntriesToEntity(r.Headers),
+		Transport:        r.Transport,
+		TLS:              r.TLS.toEntity(),
+		Delivery:         r.Delivery,
+		Batching:         r.Batching.toEntity(),
+		PayloadFormat:    r.PayloadFormat,
+		LargePayloads:    r.LargePayloads,
 		Mode:             r.Mode,
 		TimeoutSeconds:   r.TimeoutSeconds,
 		MaxRetries:       r.MaxRetries,
@@ -109,9 +194,17 @@ type UpdateSubscriptionRequest struct {
 	Name             *string               `json:"name,omitempty"`
 	Description      *string               `json:"description,omitempty"`
 	Endpoint         *string               `json:"endpoint,omitempty"`
+	ShadowEndpoint   *string               `json:"shadowEndpoint,omitempty" doc:"http(s) URL push deliveries are also copied to; an empty string stops shadowing"`
 	ConnectionID     *string               `json:"connectionId,omitempty"`
 	EventTypes       []EventTypeBindingDTO `json:"eventTypes,omitempty"`
 	CustomConfig     []ConfigEntryDTO      `json:"customConfig,omitempty"`
+	Headers          []ConfigEntryDTO      `json:"headers,omitempty" doc:"Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time"`
+	Transport        *string               `json:"transport,omitempty" doc:"Named outbound transport deliveries go through; an empty string switches back to direct delivery"`
+	TLS              *TLSTrustDTO          `json:"tls,omitempty"`
+	Delivery         *string               `json:"delivery,omitempty" doc:"PUSH or PULL; switching to PUSH needs a valid endpoint"`
+	Batching         *BatchingDTO          `json:"batching,omitempty"`
+	PayloadFormat    *string               `json:"payloadFormat,omitempty" doc:"JSON, CLOUDEVENTS_JSON, NDJSON or PROTOBUF"`
+	LargePayloads    *string               `json:"largePayloads,omitempty" doc:"INLINE or PRESIGNED_URL"`
 	Mode             *string               `json:"mode,omitempty"`
 	TimeoutSeconds   *int32                `json:"timeoutSeconds,omitempty"`
 	MaxRetries       *int32                `json:"maxRetries,omitempty"`
@@ -122,7 +215,7 @@ type UpdateSubscriptionRequest struct {
 	DataOnly         *bool                 `json:"dataOnly,omitempty"`
 }
 
-func (r UpdateSubscriptionRequest) toCommand(id string) operations.UpdateCommand {
+func (r UpdateSubscriptionRequest) toCommand(id, ifMatch string) operations.UpdateCommand {
 	var events []subscription.EventTypeBinding
 	if r.EventTypes != nil {
 		events = make([]subscription.EventTypeBinding, 0, len(r.EventTypes))
@@ -142,9 +235,17 @@ func (r UpdateSubscriptionRequest) toCommand(id string) operations.UpdateCommand
 		Name:             r.Name,
 		Description:      r.Description,
 		Endpoint:         r.Endpoint,
+		ShadowEndpoint:   r.ShadowEndpoint,
 		ConnectionID:     r.ConnectionID,
 		EventTypes:       events,
 		CustomConfig:     config,
+		Headers:          configEntriesToEntity(r.Headers),
+		Transport:        r.Transport,
+		TLS:              r.TLS.toEntity(),
+		Delivery:         r.Delivery,
+		Batching:         r.Batching.toEntity(),
+		PayloadFormat:    r.PayloadFormat,
+		LargePayloads:    r.LargePayloads,
 		Mode:             r.Mode,
 		TimeoutSeconds:   r.TimeoutSeconds,
 		MaxRetries:       r.MaxRetries,
@@ -153,9 +254,95 @@ func (r UpdateSubscriptionRequest) toCommand(id string) operations.UpdateCommand
 		DispatchPoolID:   r.DispatchPoolID,
 		ServiceAccountID: r.ServiceAccountID,
 		DataOnly:         r.DataOnly,
+		IfMatch:          ifMatch,
 	}
 }
 
+// UpsertSubscriptionRequest is the wire body for
+// PUT /api/subscriptions/by-code/{code}: the create body without the code
+// and client, which the path and query carry. On update, absent optional
+// fields keep their current values.
+type UpsertSubscriptionRequest struct {
+	Name             string                `json:"name"`
+	Endpoint         string                `json:"endpoint,omitempty" doc:"http(s) URL delivery target; required unless delivery is PULL"`
+	ShadowEndpoint   *string               `json:"shadowEndpoint,omitempty" doc:"http(s) URL each push delivery is also copied to, for comparing a new endpoint before promoting it; its answers never affect the delivery"`
+	Description      *string               `json:"description,omitempty"`
+	ConnectionID     *string               `json:"connectionId,omitempty"`
+	DispatchPoolID   *string               `json:"dispatchPoolId,omitempty"`
+	ServiceAccountID *string               `json:"serviceAccountId,omitempty"`
+	EventTypes       []EventTypeBindingDTO `json:"eventTypes,omitempty"`
+	CustomConfig     []ConfigEntryDTO      `json:"customConfig,omitempty"`
+	Headers          []ConfigEntryDTO      `json:"headers,omitempty" doc:"Headers added to every delivery. A value may be a secret reference (env://, aws-sm://, encrypted:...) resolved at delivery time"`
+	Transport        *string               `json:"transport,omitempty" doc:"Named outbound transport (SSH bastion or SOCKS5 proxy, see FC_OUTBOUND_TRANSPORTS) deliveries go through; omit to deliver directly"`
+	TLS              *TLSTrustDTO          `json:"tls,omitempty"`
+	Delivery         string                `json:"delivery,omitempty" doc:"PUSH (default) POSTs each dispatch job to the endpoint; PULL holds them for the consumer to fetch from GET /api/consume/{subscriptionId}"`
+	Batching         *BatchingDTO          `json:"batching,omitempty"`
+	PayloadFormat    string                `json:"payloadFormat,omitempty" doc:"How deliveries are serialized: JSON (default), CLOUDEVENTS_JSON, NDJSON or PROTOBUF (transcoded with the event type's current PROTO schema). Pull consumers always receive JSON"`
+	LargePayloads    string                `json:"largePayloads,omitempty" doc:"How a payload offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES is delivered: INLINE (default) rehydrates it; PRESIGNED_URL sends its $blobRef reference with a pre-signed url to fetch it from"`
+	Mode             string                `json:"mode,omitempty" doc:"Dispatch mode (IMMEDIATE, NEXT_ON_ERROR, BLOCK_ON_ERROR)"`
+	TimeoutSeconds   *int32                `json:"timeoutSeconds,omitempty"`
+	MaxRetries       *int32                `json:"maxRetries,omitempty"`
+	DelaySeconds     *int32                `json:"delaySeconds,omitempty"`
+	MaxAgeSeconds    *int32                `json:"maxAgeSeconds,omitempty"`
+	DataOnly         *bool                 `json:"dataOnly,omitempty"`
+}
+
+func (r UpsertSubscriptionRequest) toCreateCommand(code string, clientID *string) operations.CreateCommand {
+	return CreateSubscriptionRequest{
+		Code:             code,
+		Name:             r.Name,
+		Endpoint:         r.Endpoint,
+		ShadowEndpoint:   r.ShadowEndpoint,
+		Description:      r.Description,
+		ClientID:         clientID,
+		ConnectionID:     r.ConnectionID,
+		DispatchPoolID:   r.DispatchPoolID,
+		ServiceAccountID: r.ServiceAccountID,
+		EventTypes:       r.EventTypes,
+		CustomConfig:     r.CustomConfig,
+		Headers:          r.Headers,
+		Transport:        r.Transport,
+		TLS:              r.TLS,
+		Delivery:         r.Delivery,
+		Batching:         r.Batching,
+		PayloadFormat:    r.PayloadFormat,
+		LargePayloads:    r.LargePayloads,
+		Mode:             r.Mode,
+		TimeoutSeconds:   r.TimeoutSeconds,
+		MaxRetries:       r.MaxRetries,
+		DelaySeconds:     r.DelaySeconds,
+		MaxAgeSeconds:    r.MaxAgeSeconds,
+		DataOnly:         r.DataOnly,
+	}.toCommand()
+}
+
+func (r UpsertSubscriptionRequest) toUpdateCommand(id, ifMatch string) operations.UpdateCommand {
+	return UpdateSubscriptionRequest{
+		Name:             &r.Name,
+		Description:      r.Description,
+		Endpoint:         apicommon.OptStr(r.Endpoint),
+		ShadowEndpoint:   r.ShadowEndpoint,
+		ConnectionID:     r.ConnectionID,
+		EventTypes:       r.EventTypes,
+		CustomConfig:     r.CustomConfig,
+		Headers:          r.Headers,
+		Transport:        r.Transport,
+		TLS:              r.TLS,
+		Delivery:         apicommon.OptStr(r.Delivery),
+		Batching:         r.Batching,
+		PayloadFormat:    apicommon.OptStr(r.PayloadFormat),
+		LargePayloads:    apicommon.OptStr(r.LargePayloads),
+		Mode:             apicommon.OptStr(r.Mode),
+		TimeoutSeconds:   r.TimeoutSeconds,
+		MaxRetries:       r.MaxRetries,
+		DelaySeconds:     r.DelaySeconds,
+		MaxAgeSeconds:    r.MaxAgeSeconds,
+		DispatchPoolID:   r.DispatchPoolID,
+		ServiceAccountID: r.ServiceAccountID,
+		DataOnly:         r.DataOnly,
+	}.toCommand(id, ifMatch)
+}
+
 // SubscriptionResponse mirrors subscription.Subscription.
 type SubscriptionResponse struct {
 	ID               string                `json:"id"`
@@ -169,8 +356,17 @@ type SubscriptionResponse struct {
 	EventTypes       []EventTypeBindingDTO `json:"eventTypes"`
 	ConnectionID     *string               `json:"connectionId,omitempty"`
 	Endpoint         string                `json:"endpoint"`
+	ShadowEndpoint   *string               `json:"shadowEndpoint,omitempty"`
+	TrafficSplit     []SplitTargetDTO      `json:"trafficSplit" doc:"Endpoints taking a share of the message groups; the endpoint keeps the rest"`
 	Queue            *string               `json:"queue,omitempty"`
 	CustomConfig     []ConfigEntryDTO      `json:"customConfig"`
+	Headers          []ConfigEntryDTO      `json:"headers"`
+	Transport        *string               `json:"transport,omitempty"`
+	TLS              *TLSTrustDTO          `json:"tls,omitempty"`
+	Delivery         string                `json:"delivery"`
+	Batching         *BatchingDTO          `json:"batching,omitempty"`
+	PayloadFormat    string                `json:"payloadFormat"`
+	LargePayloads    string                `json:"largePayloads"`
 	Source           string                `json:"source"`
 	Status           string                `json:"status"`
 	MaxAgeSeconds    int32                 `json:"maxAgeSeconds"`
@@ -197,6 +393,14 @@ func fromEntity(s *subscription.Subscription) SubscriptionResponse {
 	for _, c := range s.CustomConfig {
 		config = append(config, configEntryFromEntity(c))
 	}
+	headers := make([]ConfigEntryDTO, 0, len(s.Headers))
+	for _, h := range s.Headers {
+		headers = append(headers, configEntryFromEntity(h))
+	}
+	split := make([]SplitTargetDTO, 0, len(s.TrafficSplit))
+	for _, t := range s.TrafficSplit {
+		split = append(split, SplitTargetDTO(t))
+	}
 	return SubscriptionResponse{
 		ID:               s.ID,
 		Code:             s.Code,
@@ -209,8 +413,17 @@ func fromEntity(s *subscription.Subscription) SubscriptionResponse {
 		EventTypes:       events,
 		ConnectionID:     s.ConnectionID,
 		Endpoint:         s.Endpoint,
+		ShadowEndpoint:   s.ShadowEndpoint,
+		TrafficSplit:     split,
 		Queue:            s.Queue,
 		CustomConfig:     config,
+		Headers:          headers,
+		Transport:        s.Transport,
+		TLS:              tlsTrustFromEntity(s.TLS),
+		Delivery:         string(s.Delivery),
+		Batching:         batchingFromEntity(s.Batching),
+		PayloadFormat:    string(s.PayloadFormat),
+		LargePayloads:    string(s.LargePayloads),
 		Source:           string(s.Source),
 		Status:           string(s.Status),
 		MaxAgeSeconds:    s.MaxAgeSeconds,
@@ -235,3 +448,94 @@ type SubscriptionListResponse struct {
 	Subscriptions []SubscriptionResponse `json:"subscriptions"`
 	Total         int                    `json:"total"`
 }
+
+// ShadowSideDTO is one endpoint's side of a shadow report.
+type ShadowSideDTO struct {
+	Successes int64 `json:"successes" doc:"Deliveries the endpoint accepted"`
+	AvgMs     int64 `json:"avgMs" doc:"Mean attempt latency in milliseconds"`
+	P50Ms     int64 `json:"p50Ms"`
+	P95Ms     int64 `json:"p95Ms"`
+}
+
+func shadowSideFromEntity(s subscription.ShadowSummary) ShadowSideDTO {
+	return ShadowSideDTO{Successes: s.Successes, AvgMs: s.AvgMs, P50Ms: s.P50Ms, P95Ms: s.P95Ms}
+}
+
+// ShadowOutcomeDTO is how many deliveries got one combination of answers.
+type ShadowOutcomeDTO struct {
+	PrimaryStatusCode *int32 `json:"primaryStatusCode,omitempty" doc:"Absent when no response came back"`
+	PrimarySuccess    bool   `json:"primarySuccess"`
+	ShadowStatusCode  *int32 `json:"shadowStatusCode,omitempty" doc:"Absent when no response came back"`
+	ShadowSuccess     bool   `json:"shadowSuccess"`
+	Deliveries        int64  `json:"deliveries"`
+}
+
+// ShadowReportResponse is the wire shape for
+// GET /api/subscriptions/{id}/shadow-report.
+type ShadowReportResponse struct {
+	ShadowEndpoint string             `json:"shadowEndpoint"`
+	Since          httpcompat.Time    `json:"since"`
+	Deliveries     int64              `json:"deliveries" doc:"Deliveries copied to the shadow endpoint since the given time"`
+	Agreed         int64              `json:"agreed" doc:"Deliveries both endpoints accepted or both refused"`
+	Primary        ShadowSideDTO      `json:"primary"`
+	Shadow         ShadowSideDTO      `json:"shadow"`
+	Outcomes       []ShadowOutcomeDTO `json:"outcomes" doc:"Status code combinations, most common first"`
+}
+
+func shadowReportFromEntity(r *subscription.ShadowReport) ShadowReportResponse {
+	outcomes := make([]ShadowOutcomeDTO, 0, len(r.Outcomes))
+	for _, o := range r.Outcomes {
+		outcomes = append(outcomes, ShadowOutcomeDTO(o))
+	}
+	return ShadowReportResponse{
+		ShadowEndpoint: r.ShadowEndpoint,
+		Since:          jsontime.New(r.Since),
+		Deliveries:     r.Deliveries,
+		Agreed:         r.Agreed,
+		Primary:        shadowSideFromEntity(r.Primary),
+		Shadow:         shadowSideFromEntity(r.Shadow),
+		Outcomes:       outcomes,
+	}
+}
+
+// SetTrafficSplitRequest is the body of
+// PUT /api/subscriptions/{id}/traffic-split.
+type SetTrafficSplitRequest struct {
+	Split []SplitTargetDTO `json:"split" doc:"Replaces the split; empty sends every message group to the endpoint"`
+}
+
+func (r SetTrafficSplitRequest) toCommand(id string) operations.SetTrafficSplitCommand {
+	split := make([]subscription.SplitTarget, 0, len(r.Split))
+	for _, t := range r.Split {
+		split = append(split, subscription.SplitTarget(t))
+	}
+	return operations.SetTrafficSplitCommand{ID: id, Split: split}
+}
+
+// TargetStatsDTO is how one endpoint fared with the jobs routed to it.
+type TargetStatsDTO struct {
+	Endpoint  string `json:"endpoint"`
+	Weight    int32  `json:"weight" doc:"Current share of message groups; 0 for an endpoint no longer routed to"`
+	Jobs      int64  `json:"jobs" doc:"Dispatch jobs created for the endpoint"`
+	Completed int64  `json:"completed"`
+	Failed    int64  `json:"failed" doc:"Jobs that failed or expired"`
+	Attempts  int64  `json:"attempts"`
+	Successes int64  `json:"successes" doc:"Attempts the endpoint accepted"`
+	AvgMs     int64  `json:"avgMs" doc:"Mean attempt latency in milliseconds"`
+	P95Ms     int64  `json:"p95Ms"`
+}
+
+// TargetStatsResponse is the wire shape for
+// GET /api/subscriptions/{id}/target-stats.
+type TargetStatsResponse struct {
+	Since   httpcompat.Time  `json:"since"`
+	Targets []TargetStatsDTO `json:"targets" doc:"The endpoint, then its traffic split, then any endpoint routed to earlier in the window"`
+}
+
+func targetStatsFromEntity(since time.Time, stats []subscription.TargetStats) TargetStatsResponse {
+	targets := make([]TargetStatsDTO, 0, len(stats))
+	for _, st := range stats {
+		targets = append(targets, TargetStatsDTO(st))
+	}
+	return TargetStatsResponse{Since: jsontime.New(since), Targets: targets}
+}
@@ -3,10 +3,14 @@
 package subscription
 
 import (
+	"errors"
+	"fmt"
+	"net/http"
 	"strings"
 	"time"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
 )
 
@@ -26,6 +30,119 @@ func ParseStatus(s string) Status {
 	return StatusActive
 }
 
+// DeliveryMode is how a subscription's dispatch jobs reach the consumer.
+type DeliveryMode string
+
+const (
+	// DeliveryPush POSTs each job to the endpoint.
+	DeliveryPush DeliveryMode = "PUSH"
+	// DeliveryPull holds each job until the consumer leases it through
+	// GET /api/consume/{subscriptionId}; the endpoint is unused.
+	DeliveryPull DeliveryMode = "PULL"
+)
+
+// ParseDeliveryMode is the lenient parser. Unknown → PUSH.
+func ParseDeliveryMode(s string) DeliveryMode {
+	if s == string(DeliveryPull) {
+		return DeliveryPull
+	}
+	return DeliveryPush
+}
+
+// PayloadFormat is how a push subscription's deliveries are serialized
+// (see processing/format.go). Pull consumers always receive JSON.
+type PayloadFormat string
+
+const (
+	// FormatJSON is the job's payload when data-only, otherwise the
+	// FlowCatalyst envelope; a batch is a JSON array.
+	FormatJSON PayloadFormat = "JSON"
+	// FormatCloudEvents is a structured-mode CloudEvents 1.0 event; a batch
+	// is a CloudEvents JSON batch.
+	FormatCloudEvents PayloadFormat = "CLOUDEVENTS_JSON"
+	// FormatNDJSON is FormatJSON's body on one line; a batch is one line
+	// per job.
+	FormatNDJSON PayloadFormat = "NDJSON"
+	// FormatProtobuf is the payload transcoded to the message the event
+	// type's current PROTO schema names.
+	FormatProtobuf PayloadFormat = "PROTOBUF"
+)
+
+// ParsePayloadFormat is the lenient parser. Unknown → JSON.
+func ParsePayloadFormat(s string) PayloadFormat {
+	switch PayloadFormat(s) {
+	case FormatCloudEvents, FormatNDJSON, FormatProtobuf:
+		return PayloadFormat(s)
+	default:
+		return FormatJSON
+	}
+}
+
+// LargePayloads is how a push subscription receives a payload that was
+// offloaded to the blob store for exceeding FC_BLOB_THRESHOLD_BYTES (see
+// processing/blob.go).
+type LargePayloads string
+
+const (
+	// LargePayloadsInline rehydrates the payload, so the receiver cannot
+	// tell it was offloaded.
+	LargePayloadsInline LargePayloads = "INLINE"
+	// LargePayloadsPresignedURL delivers the {"$blobRef": …} reference with
+	// a pre-signed "url" and "expiresAt" for the receiver to fetch from.
+	// Stores that cannot pre-sign, and PROTOBUF deliveries, fall back to
+	// inline.
+	LargePayloadsPresignedURL LargePayloads = "PRESIGNED_URL"
+)
+
+// ParseLargePayloads is the lenient parser. Unknown → INLINE.
+func ParseLargePayloads(s string) LargePayloads {
+	if LargePayloads(s) == LargePayloadsPresignedURL {
+		return LargePayloadsPresignedURL
+	}
+	return LargePayloadsInline
+}
+
+// Batching groups a push subscription's due dispatch jobs into one
+// delivery: a JSON array of up to MaxItems payloads, whose response may
+// report a result per job (see processing/batch.go).
+type Batching struct {
+	// MaxItems is the most jobs in one delivery; 0 or 1 turns batching off.
+	MaxItems int32 `json:"maxItems"`
+	// MaxBytes caps the array's size; 0 means no cap. A single job larger
+	// than the cap is still delivered, alone.
+	MaxBytes int32 `json:"maxBytes"`
+	// MaxWaitSeconds is how long a short batch may wait for more jobs,
+	// counted from its oldest job's creation; 0 delivers at once.
+	MaxWaitSeconds int32 `json:"maxWaitSeconds"`
+}
+
+// Batching limits.
+const (
+	MaxBatchItems       = 100
+	MaxBatchBytes       = 5 << 20
+	MaxBatchWaitSeconds = 300
+)
+
+// Enabled reports whether b groups jobs at all; nil is off.
+func (b *Batching) Enabled() bool { return b != nil && b.MaxItems > 1 }
+
+// ValidateBatching checks batching limits. nil is valid (off).
+func ValidateBatching(b *Batching) error {
+	if b == nil {
+		return nil
+	}
+	if b.MaxItems < 0 || b.MaxItems > MaxBatchItems {
+		return fmt.Errorf("batching.maxItems must be between 0 and %d", MaxBatchItems)
+	}
+	if b.MaxBytes < 0 || b.MaxBytes > MaxBatchBytes {
+		return fmt.Errorf("batching.maxBytes must be between 0 and %d", MaxBatchBytes)
+	}
+	if b.MaxWaitSeconds < 0 || b.MaxWaitSeconds > MaxBatchWaitSeconds {
+		return fmt.Errorf("batching.maxWaitSeconds must be between 0 and %d", MaxBatchWaitSeconds)
+	}
+	return nil
+}
+
 // Source identifies where the subscription was authored.
 type Source string
 
@@ -77,12 +194,85 @@ func (b EventTypeBinding) Matches(eventTypeCode string) bool {
 	return true
 }
 
-// ConfigEntry is a key/value pair stored in msg_subscription_custom_configs.
+// ConfigEntry is a key/value pair stored in msg_subscription_custom_configs,
+// or, as a custom delivery header, in msg_subscription_headers. A header
+// value may be a secrets reference (see secrets.IsReference), resolved when
+// delivering.
 type ConfigEntry struct {
 	Key   string `json:"key"`
 	Value string `json:"value"`
 }
 
+// MaxHeaders caps the custom headers on one subscription.
+const MaxHeaders = 20
+
+// reservedHeaders are set by the platform or the HTTP stack on every
+// delivery; a subscription may not supply them. Canonical form.
+var reservedHeaders = map[string]bool{
+	"Host":                  true,
+	"Content-Type":          true,
+	"Content-Length":        true,
+	"Content-Encoding":      true,
+	"Transfer-Encoding":     true,
+	"Connection":            true,
+	"Keep-Alive":            true,
+	"Upgrade":               true,
+	"Te":                    true,
+	"Trailer":               true,
+	"Expect":                true,
+	"Cookie":                true,
+	"X-Dispatch-Job-Id":     true,
+	"X-Event-Type":          true,
+	"X-Dispatch-Job-Ids":    true,
+	"X-Dispatch-Batch-Size": true,
+	"X-Dispatch-Test":       true,
+	"X-Schema-Id":           true,
+	"X-Schema-Fingerprint":  true,
+}
+
+// ValidateHeaders checks custom delivery headers: a valid, unreserved,
+// unique name and a single-line value. The X-FlowCatalyst- and Proxy-
+// prefixes are reserved as a whole.
+func ValidateHeaders(headers []ConfigEntry) error {
+	if len(headers) > MaxHeaders {
+		return fmt.Errorf("at most %d custom headers are allowed", MaxHeaders)
+	}
+	seen := make(map[string]bool, len(headers))
+	for _, h := range headers {
+		if !validHeaderName(h.Key) {
+			return errors.New("header name '" + h.Key + "' is not a valid HTTP header name")
+		}
+		name := http.CanonicalHeaderKey(h.Key)
+		if reservedHeaders[name] || strings.HasPrefix(name, "X-Flowcatalyst-") || strings.HasPrefix(name, "Proxy-") {
+			return errors.New("header '" + name + "' is reserved")
+		}
+		if seen[name] {
+			return errors.New("header '" + name + "' is set twice")
+		}
+		seen[name] = true
+		if h.Value == "" || len(h.Value) > 1000 || strings.ContainsAny(h.Value, "\r\n\x00") {
+			return errors.New("header '" + name + "' needs a single-line value of at most 1000 characters")
+		}
+	}
+	return nil
+}
+
+// validHeaderName reports whether s is an RFC 9110 token.
+func validHeaderName(s string) bool {
+	if s == "" || len(s) > 100 {
+		return false
+	}
+	for _, c := range s {
+		switch {
+		case c >= 'a' && c <= 'z', c >= 'A' && c <= 'Z', c >= '0' && c <= '9':
+		case strings.ContainsRune("!#$%&'*+-.^_`|~", c):
+		default:
+			return false
+		}
+	}
+	return true
+}
+
 // Subscription is the aggregate root.
 type Subscription struct {
 	ID               string              `json:"id"`
@@ -96,8 +286,17 @@ type Subscription struct {
 	EventTypes       []EventTypeBinding  `json:"eventTypes"`
 	ConnectionID     *string             `json:"connectionId,omitempty"`
 	Endpoint         string              `json:"endpoint"`
+	ShadowEndpoint   *string             `json:"shadowEndpoint,omitempty"`
+	TrafficSplit     []SplitTarget       `json:"trafficSplit"`
 	Queue            *string             `json:"queue,omitempty"`
 	CustomConfig     []ConfigEntry       `json:"customConfig"`
+	Headers          []ConfigEntry       `json:"headers"`
+	Transport        *string             `json:"transport,omitempty"`
+	TLS              *outbound.TLSTrust  `json:"tls,omitempty"`
+	Delivery         DeliveryMode        `json:"delivery"`
+	Batching         *Batching           `json:"batching,omitempty"`
+	PayloadFormat    PayloadFormat       `json:"payloadFormat"`
+	LargePayloads    LargePayloads       `json:"largePayloads"`
 	Source           Source              `json:"source"`
 	Status           Status              `json:"status"`
 	MaxAgeSeconds    int32               `json:"maxAgeSeconds"`
@@ -128,12 +327,17 @@ func New(code, name, endpoint string) *Subscription {
 		Endpoint:       endpoint,
 		EventTypes:     []EventTypeBinding{},
 		CustomConfig:   []ConfigEntry{},
+		Headers:        []ConfigEntry{},
+		TrafficSplit:   []SplitTarget{},
 		Source:         SourceUI,
 		Status:         StatusActive,
 		MaxAgeSeconds:  86400,
 		DelaySeconds:   0,
 		Sequence:       99,
 		Mode:           common.DispatchImmediate,
+		Delivery:       DeliveryPush,
+		PayloadFormat:  FormatJSON,
+		LargePayloads:  LargePayloadsInline,
 		TimeoutSeconds: 30,
 		MaxRetries:     3,
 		DataOnly:       true,
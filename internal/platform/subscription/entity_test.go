@@ -0,0 +1,106 @@
+package subscription_test
+
+import (
+	"net/http"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+func TestValidateHeaders(t *testing.T) {
+	ok := []subscription.ConfigEntry{
+		{Key: "X-Tenant", Value: "acme"},
+		{Key: "Authorization", Value: "env://ACME_TOKEN"},
+	}
+	assert.NoError(t, subscription.ValidateHeaders(ok))
+	assert.NoError(t, subscription.ValidateHeaders(nil))
+
+	bad := map[string]subscription.ConfigEntry{
+		"reserved":         {Key: "content-type", Value: "text/plain"},
+		"platform-set":     {Key: "X-Event-Type", Value: "x"},
+		"reserved prefix":  {Key: "x-flowcatalyst-signature", Value: "x"},
+		"proxy":            {Key: "Proxy-Authorization", Value: "x"},
+		"invalid name":     {Key: "X Tenant", Value: "acme"},
+		"empty value":      {Key: "X-Tenant", Value: ""},
+		"header injection": {Key: "X-Tenant", Value: "acme\r\nX-Evil: 1"},
+		"oversized value":  {Key: "X-Tenant", Value: strings.Repeat("a", 1001)},
+		"oversized name":   {Key: strings.Repeat("X", 101), Value: "a"},
+	}
+	for name, h := range bad {
+		assert.Error(t, subscription.ValidateHeaders([]subscription.ConfigEntry{h}), name)
+	}
+
+	dup := []subscription.ConfigEntry{{Key: "X-Tenant", Value: "a"}, {Key: "x-tenant", Value: "b"}}
+	assert.Error(t, subscription.ValidateHeaders(dup), "names compare case-insensitively")
+
+	many := make([]subscription.ConfigEntry, subscription.MaxHeaders+1)
+	for i := range many {
+		many[i] = subscription.ConfigEntry{Key: "X-H" + strings.Repeat("a", i), Value: "v"}
+	}
+	assert.Error(t, subscription.ValidateHeaders(many))
+}
+
+// FuzzValidateHeaders checks that whatever the subscription API accepts
+// as a custom header cannot split or forge headers on the delivery.
+func FuzzValidateHeaders(f *testing.F) {
+	f.Add("X-Tenant", "acme")
+	f.Add("x-flowcatalyst-key-id", "k")
+	f.Add("X Tenant", "a\r\nb")
+	f.Add("Ü", "\x00")
+	f.Fuzz(func(t *testing.T, name, value string) {
+		h := subscription.ConfigEntry{Key: name, Value: value}
+		if subscription.ValidateHeaders([]subscription.ConfigEntry{h}) != nil {
+			return
+		}
+		canonical := http.CanonicalHeaderKey(name)
+		assert.NotEmpty(t, name)
+		assert.False(t, strings.ContainsAny(name, " \t\r\n:\x00"), "name %q", name)
+		assert.False(t, strings.ContainsAny(value, "\r\n\x00"), "value %q", value)
+		assert.False(t, strings.HasPrefix(canonical, "X-Flowcatalyst-"), canonical)
+		assert.NotEqual(t, "Content-Type", canonical)
+
+		dup := subscription.ConfigEntry{Key: strings.ToLower(name), Value: value}
+		assert.Error(t, subscription.ValidateHeaders([]subscription.ConfigEntry{h, dup}))
+	})
+}
+
+func TestPromoteShadow(t *testing.T) {
+	s := subscription.New("orders", "Orders", "https://old.example.com/hook")
+	_, ok := s.PromoteShadow()
+	assert.False(t, ok, "nothing to promote")
+
+	shadow := "https://new.example.com/hook"
+	s.ShadowEndpoint = &shadow
+	previous, ok := s.PromoteShadow()
+	assert.True(t, ok)
+	assert.Equal(t, "https://old.example.com/hook", previous)
+	assert.Equal(t, shadow, s.Endpoint)
+	assert.Nil(t, s.ShadowEndpoint)
+}
+
+func TestValidateTrafficSplit(t *testing.T) {
+	const endpoint = "https://v1.example.com/hook"
+	split := func(pairs ...any) []subscription.SplitTarget {
+		var out []subscription.SplitTarget
+		for i := 0; i < len(pairs); i += 2 {
+			out = append(out, subscription.SplitTarget{Endpoint: pairs[i].(string), Weight: int32(pairs[i+1].(int))})
+		}
+		return out
+	}
+	assert.NoError(t, subscription.ValidateTrafficSplit(endpoint, nil))
+	assert.NoError(t, subscription.ValidateTrafficSplit(endpoint, split("https://v2.example.com", 10)))
+	assert.NoError(t, subscription.ValidateTrafficSplit(endpoint, split("https://v2.example.com", 100)), "a finished migration")
+
+	assert.ErrorContains(t, subscription.ValidateTrafficSplit(endpoint, split(endpoint, 10)), "can't be in its traffic split")
+	assert.ErrorContains(t, subscription.ValidateTrafficSplit(endpoint, split("https://v2.example.com", 10, "https://v2.example.com", 5)), "twice")
+	assert.ErrorContains(t, subscription.ValidateTrafficSplit(endpoint, split("https://v2.example.com", 0)), "between 1 and 100")
+	assert.ErrorContains(t, subscription.ValidateTrafficSplit(endpoint, split("https://v2.example.com", 60, "https://v3.example.com", 50)), "add up to 110")
+
+	s := subscription.New("orders", "Orders", endpoint)
+	assert.Equal(t, int32(100), s.EndpointWeight())
+	s.SetTrafficSplit(split("https://v2.example.com", 10, "https://v3.example.com", 5))
+	assert.Equal(t, int32(85), s.EndpointWeight())
+}
@@ -0,0 +1,43 @@
+package subscription
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/secrets"
+)
+
+// HeaderResolver turns a subscription's stored headers into the ones a
+// delivery carries, resolving secret references through the secrets
+// service. Satisfies processing.HeaderSource.
+type HeaderResolver struct {
+	repo    *Repository
+	secrets *secrets.Service
+}
+
+// NewHeaderResolver wires a resolver.
+func NewHeaderResolver(repo *Repository, svc *secrets.Service) *HeaderResolver {
+	return &HeaderResolver{repo: repo, secrets: svc}
+}
+
+// DeliveryHeaders loads the subscription's headers and resolves them. A
+// reference that doesn't resolve is an error rather than a dropped header.
+func (r *HeaderResolver) DeliveryHeaders(ctx context.Context, subscriptionID string) (http.Header, error) {
+	entries, err := r.repo.HeadersFor(ctx, subscriptionID)
+	if err != nil {
+		return nil, fmt.Errorf("load headers: %w", err)
+	}
+	out := make(http.Header, len(entries))
+	for _, e := range entries {
+		value := e.Value
+		if secrets.IsReference(value) {
+			if value, err = r.secrets.Resolve(ctx, value); err != nil {
+				// The error names the backend, never the value.
+				return nil, fmt.Errorf("header %s: %w", e.Key, err)
+			}
+		}
+		out.Set(e.Key, value)
+	}
+	return out, nil
+}
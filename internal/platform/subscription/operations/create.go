@@ -3,9 +3,12 @@ package operations
 import (
 	"context"
 	"regexp"
+	"slices"
 	"strings"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/validate"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
@@ -20,6 +23,7 @@ type CreateCommand struct {
 	Code             string                          `json:"code"`
 	Name             string                          `json:"name"`
 	Endpoint         string                          `json:"endpoint"`
+	ShadowEndpoint   *string                         `json:"shadowEndpoint,omitempty"`
 	Description      *string                         `json:"description,omitempty"`
 	ClientID         *string                         `json:"clientId,omitempty"`
 	ConnectionID     *string                         `json:"connectionId,omitempty"`
@@ -27,6 +31,13 @@ type CreateCommand struct {
 	ServiceAccountID *string                         `json:"serviceAccountId,omitempty"`
 	EventTypes       []subscription.EventTypeBinding `json:"eventTypes,omitempty"`
 	CustomConfig     []subscription.ConfigEntry      `json:"customConfig,omitempty"`
+	Headers          []subscription.ConfigEntry      `json:"headers,omitempty"`
+	Transport        *string                         `json:"transport,omitempty"`
+	TLS              *outbound.TLSTrust              `json:"tls,omitempty"`
+	Delivery         string                          `json:"delivery,omitempty"`
+	Batching         *subscription.Batching          `json:"batching,omitempty"`
+	PayloadFormat    string                          `json:"payloadFormat,omitempty"`
+	LargePayloads    string                          `json:"largePayloads,omitempty"`
 	Mode             string                          `json:"mode,omitempty"`
 	TimeoutSeconds   *int32                          `json:"timeoutSeconds,omitempty"`
 	MaxRetries       *int32                          `json:"maxRetries,omitempty"`
@@ -37,7 +48,11 @@ type CreateCommand struct {
 
 // CreateSubscription validates cmd, enforces code uniqueness within the
 // client scope, persists the subscription, and emits [SubscriptionCreated].
-func CreateSubscription(repo *subscription.Repository) usecaseop.Operation[CreateCommand, SubscriptionCreated] {
+// targets (nil = unchecked) refuses endpoints the client's outbound policy
+// blocks; transports (nil = unchecked) refuses a transport the deployment
+// doesn't configure; lifecycles (nil = unchecked) refuses bindings to
+// deprecated event types.
+func CreateSubscription(repo *subscription.Repository, targets *outbound.Policies, transports *outbound.Transports, lifecycles *eventtype.LifecycleCache) usecaseop.Operation[CreateCommand, SubscriptionCreated] {
 	return usecaseop.Operation[CreateCommand, SubscriptionCreated]{
 		Name: "CreateSubscription",
 		Validate: func(_ context.Context, cmd CreateCommand) error {
@@ -52,12 +67,45 @@ func CreateSubscription(repo *subscription.Repository) usecaseop.Operation[Creat
 			if strings.TrimSpace(cmd.Name) == "" {
 				return usecase.Validation("NAME_REQUIRED", "name is required")
 			}
-			if !urlPattern.MatchString(cmd.Endpoint) {
-				return usecase.Validation("INVALID_ENDPOINT", "endpoint must be a http(s) URL")
+			if !validDelivery(cmd.Delivery) {
+				return usecase.Validation("INVALID_DELIVERY", "delivery must be PUSH or PULL")
+			}
+			// A pull subscription's consumer fetches its jobs; there is
+			// nothing to deliver to.
+			if subscription.ParseDeliveryMode(cmd.Delivery) == subscription.DeliveryPush {
+				if !urlPattern.MatchString(cmd.Endpoint) {
+					return usecase.Validation("INVALID_ENDPOINT", "endpoint must be a http(s) URL")
+				}
+				if err := targets.CheckURL(cmd.ClientID, cmd.Endpoint); err != nil {
+					return usecase.Validation("ENDPOINT_BLOCKED", err.Error())
+				}
+			}
+			if cmd.ShadowEndpoint != nil && *cmd.ShadowEndpoint != "" {
+				if err := checkShadow(cmd.ClientID, *cmd.ShadowEndpoint, subscription.ParseDeliveryMode(cmd.Delivery), targets); err != nil {
+					return err
+				}
 			}
 			if len(cmd.EventTypes) == 0 {
 				return usecase.Validation("EVENT_TYPES_REQUIRED", "at least one event type binding is required")
 			}
+			if err := subscription.ValidateHeaders(cmd.Headers); err != nil {
+				return usecase.Validation("INVALID_HEADERS", err.Error())
+			}
+			if err := transports.Check(cmd.Transport); err != nil {
+				return usecase.Validation("UNKNOWN_TRANSPORT", err.Error())
+			}
+			if err := cmd.TLS.Validate(); err != nil {
+				return usecase.Validation("INVALID_TLS_TRUST", err.Error())
+			}
+			if err := subscription.ValidateBatching(cmd.Batching); err != nil {
+				return usecase.Validation("INVALID_BATCHING", err.Error())
+			}
+			if !validPayloadFormat(cmd.PayloadFormat) {
+				return usecase.Validation("INVALID_PAYLOAD_FORMAT", payloadFormatMessage)
+			}
+			if !validLargePayloads(cmd.LargePayloads) {
+				return usecase.Validation("INVALID_LARGE_PAYLOADS", largePayloadsMessage)
+			}
 			return nil
 		},
 		// Resource-level authorization (the coarse "may write subscriptions"
@@ -81,16 +129,42 @@ func CreateSubscription(repo *subscription.Repository) usecaseop.Operation[Creat
 					"Subscription with code '"+code+"' already exists")
 			}
 
+			if err := checkDeprecated(ctx, lifecycles, cmd.EventTypes, nil); err != nil {
+				return nil, err
+			}
+
 			s := subscription.New(code, strings.TrimSpace(cmd.Name), cmd.Endpoint)
 			s.Description = cmd.Description
 			s.ClientID = cmd.ClientID
 			s.ConnectionID = cmd.ConnectionID
+			if cmd.ShadowEndpoint != nil && *cmd.ShadowEndpoint != "" {
+				s.ShadowEndpoint = cmd.ShadowEndpoint
+			}
 			s.DispatchPoolID = cmd.DispatchPoolID
 			s.ServiceAccountID = cmd.ServiceAccountID
 			s.EventTypes = cmd.EventTypes
 			if cmd.CustomConfig != nil {
 				s.CustomConfig = cmd.CustomConfig
 			}
+			if cmd.Headers != nil {
+				s.Headers = cmd.Headers
+			}
+			if cmd.Transport != nil && *cmd.Transport != "" {
+				s.Transport = cmd.Transport
+			}
+			if !cmd.TLS.IsZero() {
+				s.TLS = cmd.TLS
+			}
+			s.Delivery = subscription.ParseDeliveryMode(cmd.Delivery)
+			if cmd.Batching.Enabled() {
+				s.Batching = cmd.Batching
+			}
+			if cmd.PayloadFormat != "" {
+				s.PayloadFormat = subscription.ParsePayloadFormat(cmd.PayloadFormat)
+			}
+			if cmd.LargePayloads != "" {
+				s.LargePayloads = subscription.ParseLargePayloads(cmd.LargePayloads)
+			}
 			if cmd.Mode != "" {
 				s.Mode = common.ParseDispatchMode(cmd.Mode)
 			}
@@ -110,14 +184,123 @@ func CreateSubscription(repo *subscription.Repository) usecaseop.Operation[Creat
 				s.DataOnly = *cmd.DataOnly
 			}
 			s.CreatedBy = &ec.PrincipalID
+			if err := checkResidency(ctx, repo, s); err != nil {
+				return nil, err
+			}
 
 			event := SubscriptionCreated{
 				Metadata:       usecase.NewEventMetadata(ec, SubscriptionCreatedType, Source, subjectFor(s.ID)),
 				SubscriptionID: s.ID,
 				Code:           s.Code,
 				Name:           s.Name,
+				ClientID:       s.ClientID,
 			}
 			return usecaseop.Save(s, repo, event), nil
 		},
 	}
 }
+
+// checkResidency refuses a subscription whose client is pinned to a
+// data-residency region unless its dispatch pool runs in that region. The
+// scheduler publishes a pool's jobs to its region's queue, so any other
+// pool would egress the client's deliveries from the wrong place.
+func checkResidency(ctx context.Context, repo *subscription.Repository, s *subscription.Subscription) error {
+	if s.ClientID == nil {
+		return nil
+	}
+	clientRegion, poolRegion, err := repo.RegionsFor(ctx, s.ClientID, s.DispatchPoolID)
+	if err != nil {
+		return usecase.Internal("REPO", "regions_for failed", err)
+	}
+	if clientRegion == nil {
+		return nil
+	}
+	if s.DispatchPoolID == nil {
+		return usecase.Validation("DATA_RESIDENCY_POOL_REQUIRED",
+			"client is restricted to region '"+*clientRegion+"': set dispatchPoolId to a pool in that region")
+	}
+	if poolRegion == nil || *poolRegion != *clientRegion {
+		got := "unrestricted"
+		if poolRegion != nil {
+			got = "'" + *poolRegion + "'"
+		}
+		return usecase.Validation("DATA_RESIDENCY_MISMATCH",
+			"client is restricted to region '"+*clientRegion+"' but dispatch pool "+*s.DispatchPoolID+" is "+got)
+	}
+	return nil
+}
+
+// checkDeprecated refuses a binding to a deprecated event type unless kept
+// (the subscription's current bindings) already has it: existing
+// subscribers keep receiving until the sunset, new ones go to the
+// replacement. Only exact codes are checked; a wildcard binding isn't
+// refused for matching a deprecated type.
+func checkDeprecated(ctx context.Context, lifecycles *eventtype.LifecycleCache, bindings, kept []subscription.EventTypeBinding) error {
+	for _, b := range bindings {
+		if slices.ContainsFunc(kept, func(k subscription.EventTypeBinding) bool { return k.EventTypeCode == b.EventTypeCode }) {
+			continue
+		}
+		l, deprecated, err := lifecycles.Lookup(ctx, b.EventTypeCode)
+		if err != nil {
+			return usecase.Internal("REPO", "load event type lifecycles failed", err)
+		}
+		if !deprecated {
+			continue
+		}
+		msg := "event type " + b.EventTypeCode + " is deprecated and takes no new subscriptions"
+		if l.MigrationHint != nil {
+			msg += ": " + *l.MigrationHint
+		}
+		return usecase.Validation("EVENT_TYPE_DEPRECATED", msg)
+	}
+	return nil
+}
+
+// checkShadow checks a shadow endpoint like the endpoint itself. Only a
+// push subscription has deliveries to copy.
+func checkShadow(clientID *string, endpoint string, delivery subscription.DeliveryMode, targets *outbound.Policies) error {
+	if delivery != subscription.DeliveryPush {
+		return usecase.Validation("SHADOW_NEEDS_PUSH", "only a push subscription can have a shadow endpoint")
+	}
+	if !urlPattern.MatchString(endpoint) {
+		return usecase.Validation("INVALID_SHADOW_ENDPOINT", "shadowEndpoint must be a http(s) URL")
+	}
+	if err := targets.CheckURL(clientID, endpoint); err != nil {
+		return usecase.Validation("SHADOW_ENDPOINT_BLOCKED", err.Error())
+	}
+	return nil
+}
+
+// validDelivery reports whether d names a delivery mode; "" keeps the
+// current one (PUSH for a new subscription).
+func validDelivery(d string) bool {
+	switch subscription.DeliveryMode(d) {
+	case "", subscription.DeliveryPush, subscription.DeliveryPull:
+		return true
+	}
+	return false
+}
+
+const payloadFormatMessage = "payloadFormat must be JSON, CLOUDEVENTS_JSON, NDJSON or PROTOBUF"
+
+// validPayloadFormat reports whether f names a payload format; "" keeps
+// the current one (JSON for a new subscription).
+func validPayloadFormat(f string) bool {
+	switch subscription.PayloadFormat(f) {
+	case "", subscription.FormatJSON, subscription.FormatCloudEvents, subscription.FormatNDJSON, subscription.FormatProtobuf:
+		return true
+	}
+	return false
+}
+
+const largePayloadsMessage = "largePayloads must be INLINE or PRESIGNED_URL"
+
+// validLargePayloads reports whether lp names a large-payload delivery; ""
+// keeps the current one (INLINE for a new subscription).
+func validLargePayloads(lp string) bool {
+	switch subscription.LargePayloads(lp) {
+	case "", subscription.LargePayloadsInline, subscription.LargePayloadsPresignedURL:
+		return true
+	}
+	return false
+}
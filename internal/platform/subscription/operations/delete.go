@@ -5,6 +5,7 @@ import (
 	"strings"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
@@ -13,7 +14,8 @@ import (
 
 // DeleteCommand is the input DTO.
 type DeleteCommand struct {
-	ID string `json:"id"`
+	ID      string `json:"id"`
+	IfMatch string `json:"-"` // If-Match header; empty skips the check
 }
 
 // DeleteSubscription removes a subscription and emits [SubscriptionDeleted].
@@ -41,12 +43,16 @@ func DeleteSubscription(repo *subscription.Repository) usecaseop.Operation[Delet
 			if err := auth.CheckScopeAccess(auth.FromContext(ctx), s.ClientID); err != nil {
 				return nil, err
 			}
+			if err := etag.Check(cmd.IfMatch, s.UpdatedAt); err != nil {
+				return nil, err
+			}
 			event := SubscriptionDeleted{
 				Metadata:       usecase.NewEventMetadata(ec, SubscriptionDeletedType, Source, subjectFor(s.ID)),
 				SubscriptionID: s.ID,
 				Code:           s.Code,
+				ClientID:       s.ClientID,
 			}
-			return usecaseop.Delete(s, repo, event), nil
+			return usecaseop.Delete(s, etag.Guard[subscription.Subscription](repo, "msg_subscriptions", cmd.IfMatch), event), nil
 		},
 	}
 }
@@ -4,6 +4,7 @@ import (
 	"encoding/json"
 	"time"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
 )
 
@@ -14,18 +15,53 @@ const (
 	SubscriptionPausedType  = "platform:admin:subscription:paused"
 	SubscriptionResumedType = "platform:admin:subscription:resumed"
 	SubscriptionsSyncedType = "platform:admin:subscription:synced"
+	ShadowPromotedType      = "platform:admin:subscription:shadow-promoted"
+	TrafficSplitSetType     = "platform:admin:subscription:traffic-split-set"
 	Source                  = "platform:admin"
 )
 
 func subjectFor(id string) string { return "platform.subscription." + id }
 func groupFor(id string) string   { return "platform:subscription:" + id }
 
+// owners scopes the public copy of an event (see publicevents) to the
+// client the subscription belongs to; a platform-wide subscription has none.
+func owners(clientID *string) []string {
+	if clientID == nil {
+		return nil
+	}
+	return []string{*clientID}
+}
+
+// NewSubscriptionCreatedEvent / NewSubscriptionUpdatedEvent build the
+// canonical events with their subject. Exported for cross-aggregate
+// orchestrations (the configuration bundle import) that write subscriptions
+// inside their own transaction.
+func NewSubscriptionCreatedEvent(ec usecase.ExecutionContext, subscriptionID, code, name string, clientID *string) SubscriptionCreated {
+	return SubscriptionCreated{
+		Metadata:       usecase.NewEventMetadata(ec, SubscriptionCreatedType, Source, subjectFor(subscriptionID)),
+		SubscriptionID: subscriptionID,
+		Code:           code,
+		Name:           name,
+		ClientID:       clientID,
+	}
+}
+
+func NewSubscriptionUpdatedEvent(ec usecase.ExecutionContext, subscriptionID, name string, clientID *string) SubscriptionUpdated {
+	return SubscriptionUpdated{
+		Metadata:       usecase.NewEventMetadata(ec, SubscriptionUpdatedType, Source, subjectFor(subscriptionID)),
+		SubscriptionID: subscriptionID,
+		Name:           name,
+		ClientID:       clientID,
+	}
+}
+
 // SubscriptionCreated is emitted on create.
 type SubscriptionCreated struct {
 	Metadata       usecase.EventMetadata
 	SubscriptionID string
 	Code           string
 	Name           string
+	ClientID       *string
 }
 
 func (e SubscriptionCreated) EventID() string       { return e.Metadata.EventID }
@@ -47,11 +83,14 @@ func (e SubscriptionCreated) ToDataJSON() ([]byte, error) {
 	}{e.SubscriptionID, e.Code, e.Name})
 }
 
+func (e SubscriptionCreated) OwningClientIDs() []string { return owners(e.ClientID) }
+
 // SubscriptionUpdated emitted on update.
 type SubscriptionUpdated struct {
 	Metadata       usecase.EventMetadata
 	SubscriptionID string
 	Name           string
+	ClientID       *string
 }
 
 func (e SubscriptionUpdated) EventID() string       { return e.Metadata.EventID }
@@ -72,11 +111,14 @@ func (e SubscriptionUpdated) ToDataJSON() ([]byte, error) {
 	}{e.SubscriptionID, e.Name})
 }
 
+func (e SubscriptionUpdated) OwningClientIDs() []string { return owners(e.ClientID) }
+
 // SubscriptionDeleted emitted on delete.
 type SubscriptionDeleted struct {
 	Metadata       usecase.EventMetadata
 	SubscriptionID string
 	Code           string
+	ClientID       *string
 }
 
 func (e SubscriptionDeleted) EventID() string       { return e.Metadata.EventID }
@@ -97,10 +139,13 @@ func (e SubscriptionDeleted) ToDataJSON() ([]byte, error) {
 	}{e.SubscriptionID, e.Code})
 }
 
+func (e SubscriptionDeleted) OwningClientIDs() []string { return owners(e.ClientID) }
+
 // SubscriptionPaused emitted on pause.
 type SubscriptionPaused struct {
 	Metadata       usecase.EventMetadata
 	SubscriptionID string
+	ClientID       *string
 }
 
 func (e SubscriptionPaused) EventID() string       { return e.Metadata.EventID }
@@ -120,10 +165,13 @@ func (e SubscriptionPaused) ToDataJSON() ([]byte, error) {
 	}{e.SubscriptionID})
 }
 
+func (e SubscriptionPaused) OwningClientIDs() []string { return owners(e.ClientID) }
+
 // SubscriptionResumed emitted on resume.
 type SubscriptionResumed struct {
 	Metadata       usecase.EventMetadata
 	SubscriptionID string
+	ClientID       *string
 }
 
 func (e SubscriptionResumed) EventID() string       { return e.Metadata.EventID }
@@ -143,6 +191,72 @@ func (e SubscriptionResumed) ToDataJSON() ([]byte, error) {
 	}{e.SubscriptionID})
 }
 
+func (e SubscriptionResumed) OwningClientIDs() []string { return owners(e.ClientID) }
+
+// SubscriptionShadowPromoted emitted when a shadow endpoint replaces the
+// endpoint.
+type SubscriptionShadowPromoted struct {
+	Metadata         usecase.EventMetadata
+	SubscriptionID   string
+	PreviousEndpoint string
+	Endpoint         string
+	ClientID         *string
+}
+
+func (e SubscriptionShadowPromoted) EventID() string       { return e.Metadata.EventID }
+func (e SubscriptionShadowPromoted) EventType() string     { return ShadowPromotedType }
+func (e SubscriptionShadowPromoted) SpecVersion() string   { return "1.0" }
+func (e SubscriptionShadowPromoted) Source() string        { return Source }
+func (e SubscriptionShadowPromoted) Subject() string       { return subjectFor(e.SubscriptionID) }
+func (e SubscriptionShadowPromoted) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e SubscriptionShadowPromoted) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e SubscriptionShadowPromoted) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e SubscriptionShadowPromoted) CausationID() string   { return e.Metadata.CausationID }
+func (e SubscriptionShadowPromoted) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e SubscriptionShadowPromoted) MessageGroup() string  { return groupFor(e.SubscriptionID) }
+func (e SubscriptionShadowPromoted) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		SubscriptionID   string `json:"subscriptionId"`
+		PreviousEndpoint string `json:"previousEndpoint"`
+		Endpoint         string `json:"endpoint"`
+	}{e.SubscriptionID, e.PreviousEndpoint, e.Endpoint})
+}
+
+func (e SubscriptionShadowPromoted) OwningClientIDs() []string { return owners(e.ClientID) }
+
+// SubscriptionTrafficSplitSet emitted when a subscription's traffic split
+// is replaced. EndpointWeight is the share the endpoint keeps.
+type SubscriptionTrafficSplitSet struct {
+	Metadata       usecase.EventMetadata
+	SubscriptionID string
+	Endpoint       string
+	EndpointWeight int32
+	Split          []subscription.SplitTarget
+	ClientID       *string
+}
+
+func (e SubscriptionTrafficSplitSet) EventID() string       { return e.Metadata.EventID }
+func (e SubscriptionTrafficSplitSet) EventType() string     { return TrafficSplitSetType }
+func (e SubscriptionTrafficSplitSet) SpecVersion() string   { return "1.0" }
+func (e SubscriptionTrafficSplitSet) Source() string        { return Source }
+func (e SubscriptionTrafficSplitSet) Subject() string       { return subjectFor(e.SubscriptionID) }
+func (e SubscriptionTrafficSplitSet) Time() time.Time       { return e.Metadata.OccurredAt }
+func (e SubscriptionTrafficSplitSet) PrincipalID() string   { return e.Metadata.PrincipalID }
+func (e SubscriptionTrafficSplitSet) CorrelationID() string { return e.Metadata.CorrelationID }
+func (e SubscriptionTrafficSplitSet) CausationID() string   { return e.Metadata.CausationID }
+func (e SubscriptionTrafficSplitSet) ExecutionID() string   { return e.Metadata.ExecutionID }
+func (e SubscriptionTrafficSplitSet) MessageGroup() string  { return groupFor(e.SubscriptionID) }
+func (e SubscriptionTrafficSplitSet) ToDataJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		SubscriptionID string                     `json:"subscriptionId"`
+		Endpoint       string                     `json:"endpoint"`
+		EndpointWeight int32                      `json:"endpointWeight"`
+		Split          []subscription.SplitTarget `json:"split"`
+	}{e.SubscriptionID, e.Endpoint, e.EndpointWeight, e.Split})
+}
+
+func (e SubscriptionTrafficSplitSet) OwningClientIDs() []string { return owners(e.ClientID) }
+
 // SubscriptionsSynced is the rollup emitted by the SDK app-scoped
 // subscription sync (SyncSubscriptions). Mirrors the Rust SubscriptionsSynced
 // event.
@@ -4,16 +4,20 @@ package operations_test
 
 import (
 	"context"
+	"strings"
 	"testing"
+	"time"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection"
 	connops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection/operations"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
 	poolops "github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool/operations"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription/operations"
@@ -57,7 +61,7 @@ func appAccessCtx() context.Context {
 // so no event type needs to exist.
 func mustCreate(t *testing.T, repo *subscription.Repository, uow *usecasepgx.UnitOfWork, code, name string) operations.SubscriptionCreated {
 	t.Helper()
-	ev, err := runAuthorized(uow, operations.CreateSubscription(repo),
+	ev, err := runAuthorized(uow, operations.CreateSubscription(repo, nil, nil, nil),
 		operations.CreateCommand{
 			Code:     code,
 			Name:     name,
@@ -79,7 +83,7 @@ func TestCreateSubscription_HappyPath(t *testing.T) {
 	uow := testpg.NewUoW(t)
 
 	desc := "delivers order events"
-	ev, err := runAuthorized(uow, operations.CreateSubscription(repo), operations.CreateCommand{
+	ev, err := runAuthorized(uow, operations.CreateSubscription(repo, nil, nil, nil), operations.CreateCommand{
 		Code:             "  SUBCRT-Happy  ", // op must trim + lowercase
 		Name:             "  Sub Create Happy  ",
 		Endpoint:         "https://orders.example.test/hook",
@@ -90,6 +94,8 @@ func TestCreateSubscription_HappyPath(t *testing.T) {
 			{EventTypeCode: "subcrt:orders:order:*"},
 		},
 		CustomConfig:   []subscription.ConfigEntry{{Key: "X-Env", Value: "test"}},
+		Headers:        []subscription.ConfigEntry{{Key: "X-Tenant", Value: "acme"}, {Key: "X-Api-Key", Value: "env://SUBCRT_API_KEY"}},
+		Transport:      ptr("acme-bastion"),
 		Mode:           "BLOCK_ON_ERROR",
 		TimeoutSeconds: ptr(int32(60)),
 		MaxRetries:     ptr(int32(5)),
@@ -131,6 +137,31 @@ func TestCreateSubscription_HappyPath(t *testing.T) {
 	assert.ElementsMatch(t, []string{"subcrt:orders:order:created", "subcrt:orders:order:*"}, codes)
 	require.Len(t, got.CustomConfig, 1)
 	assert.Equal(t, subscription.ConfigEntry{Key: "X-Env", Value: "test"}, got.CustomConfig[0])
+	// Headers keep their order and are stored unresolved.
+	assert.Equal(t, []subscription.ConfigEntry{
+		{Key: "X-Tenant", Value: "acme"},
+		{Key: "X-Api-Key", Value: "env://SUBCRT_API_KEY"},
+	}, got.Headers)
+	require.NotNil(t, got.Transport)
+	assert.Equal(t, "acme-bastion", *got.Transport)
+	transport, err := repo.TransportFor(ctx, got.ID)
+	require.NoError(t, err)
+	assert.Equal(t, got.Transport, transport)
+}
+
+func TestCreateSubscription_UnknownTransport(t *testing.T) {
+	t.Parallel()
+	repo := subscription.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	transports, err := outbound.NewTransports(`{"corp-proxy":{"type":"socks5","address":"proxy.example.test:1080"}}`, outbound.TransportEnv{})
+	require.NoError(t, err)
+
+	_, err = runAuthorized(uow, operations.CreateSubscription(repo, nil, transports, nil), operations.CreateCommand{
+		Code: "subcrt-tunnel", Name: "X", Endpoint: "https://x.example.test",
+		EventTypes: []subscription.EventTypeBinding{subscription.NewEventTypeBinding("subcrt:tunnel:x:y")},
+		Transport:  ptr("acme-bastion"),
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "UNKNOWN_TRANSPORT")
 }
 
 func TestCreateSubscription_Validation(t *testing.T) {
@@ -139,6 +170,7 @@ func TestCreateSubscription_Validation(t *testing.T) {
 	uow := testpg.NewUoW(t)
 
 	bindings := []subscription.EventTypeBinding{subscription.NewEventTypeBinding("subcrt:bad:input:case")}
+	reserved := []subscription.ConfigEntry{{Key: "content-type", Value: "text/plain"}}
 	cases := []struct {
 		name string
 		cmd  operations.CreateCommand
@@ -165,11 +197,32 @@ func TestCreateSubscription_Validation(t *testing.T) {
 		{"no event types", operations.CreateCommand{
 			Code: "subcrt-noet", Name: "X", Endpoint: "https://x.example.test",
 		}, "EVENT_TYPES_REQUIRED"},
+		{"reserved header", operations.CreateCommand{
+			Code: "subcrt-hdr", Name: "X", Endpoint: "https://x.example.test", EventTypes: bindings, Headers: reserved,
+		}, "INVALID_HEADERS"},
+		{"unknown delivery", operations.CreateCommand{
+			Code: "subcrt-delivery", Name: "X", Endpoint: "https://x.example.test", EventTypes: bindings, Delivery: "SMOKE",
+		}, "INVALID_DELIVERY"},
+		{"oversized batch", operations.CreateCommand{
+			Code: "subcrt-batch", Name: "X", Endpoint: "https://x.example.test", EventTypes: bindings, Batching: &subscription.Batching{MaxItems: subscription.MaxBatchItems + 1},
+		}, "INVALID_BATCHING"},
+		{"unknown payload format", operations.CreateCommand{
+			Code: "subcrt-format", Name: "X", Endpoint: "https://x.example.test", EventTypes: bindings, PayloadFormat: "XML",
+		}, "INVALID_PAYLOAD_FORMAT"},
+		{"unknown large payloads", operations.CreateCommand{
+			Code: "subcrt-large", Name: "X", Endpoint: "https://x.example.test", EventTypes: bindings, LargePayloads: "S3",
+		}, "INVALID_LARGE_PAYLOADS"},
+		{"non-http shadow", operations.CreateCommand{
+			Code: "subcrt-shadow", Name: "X", Endpoint: "https://x.example.test", EventTypes: bindings, ShadowEndpoint: ptr("ftp://files.example.test"),
+		}, "INVALID_SHADOW_ENDPOINT"},
+		{"shadow on pull", operations.CreateCommand{
+			Code: "subcrt-pullshadow", Name: "X", Delivery: "PULL", EventTypes: bindings, ShadowEndpoint: ptr("https://y.example.test"),
+		}, "SHADOW_NEEDS_PUSH"},
 	}
 	for _, tc := range cases {
 		t.Run(tc.name, func(t *testing.T) {
 			t.Parallel()
-			_, err := runAuthorized(uow, operations.CreateSubscription(repo), tc.cmd)
+			_, err := runAuthorized(uow, operations.CreateSubscription(repo, nil, nil, nil), tc.cmd)
 			testpg.RequireUsecaseError(t, err, usecase.KindValidation, tc.code)
 		})
 	}
@@ -177,13 +230,87 @@ func TestCreateSubscription_Validation(t *testing.T) {
 
 // Conflict is pinned by seeding through the operation itself: the first
 // create IS the seed for the second (both anchor-scoped: nil ClientID).
+func TestCreateSubscription_PullNeedsNoEndpoint(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := subscription.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+
+	ev, err := runAuthorized(uow, operations.CreateSubscription(repo, nil, nil, nil), operations.CreateCommand{
+		Code: "subcrt-pull", Name: "Pull", Delivery: "PULL",
+		EventTypes: []subscription.EventTypeBinding{subscription.NewEventTypeBinding("subcrt:pull:x:y")},
+	})
+	require.NoError(t, err)
+	got, err := repo.FindByID(ctx, ev.SubscriptionID)
+	require.NoError(t, err)
+	require.NotNil(t, got)
+	assert.Equal(t, subscription.DeliveryPull, got.Delivery)
+
+	// Back to push only with an endpoint to push to.
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
+		ID: got.ID, Delivery: ptr("PUSH"),
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_ENDPOINT")
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
+		ID: got.ID, Delivery: ptr("PUSH"), Endpoint: ptr("https://pull.example.test/hook"),
+	})
+	require.NoError(t, err)
+	got, err = repo.FindByID(ctx, ev.SubscriptionID)
+	require.NoError(t, err)
+	assert.Equal(t, subscription.DeliveryPush, got.Delivery)
+}
+
+func TestSubscription_DeprecatedEventTypesTakeNoNewBindings(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := subscription.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	hint := "bind subdep:orders:order:placed"
+	lifecycles := eventtype.NewLifecycleCache(func(context.Context) (map[string]eventtype.Lifecycle, error) {
+		return map[string]eventtype.Lifecycle{"subdep:orders:order:created": {DeprecatedAt: time.Now(), MigrationHint: &hint}}, nil
+	}, 0)
+	deprecated := subscription.NewEventTypeBinding("subdep:orders:order:created")
+	current := subscription.NewEventTypeBinding("subdep:orders:order:shipped")
+
+	_, err := runAuthorized(uow, operations.CreateSubscription(repo, nil, nil, lifecycles), operations.CreateCommand{
+		Code: "subdep-new", Name: "New", Endpoint: "https://dep.example.test/hook",
+		EventTypes: []subscription.EventTypeBinding{current, deprecated},
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "EVENT_TYPE_DEPRECATED")
+	assert.Contains(t, err.Error(), hint)
+
+	// A subscription that already had the binding keeps it through updates.
+	ev, err := runAuthorized(uow, operations.CreateSubscription(repo, nil, nil, nil), operations.CreateCommand{
+		Code: "subdep-old", Name: "Old", Endpoint: "https://dep.example.test/hook",
+		EventTypes: []subscription.EventTypeBinding{deprecated},
+	})
+	require.NoError(t, err)
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, lifecycles), operations.UpdateCommand{
+		ID: ev.SubscriptionID, EventTypes: []subscription.EventTypeBinding{deprecated, current},
+	})
+	require.NoError(t, err)
+	got, err := repo.FindByID(ctx, ev.SubscriptionID)
+	require.NoError(t, err)
+	assert.Len(t, got.EventTypes, 2)
+
+	ev, err = runAuthorized(uow, operations.CreateSubscription(repo, nil, nil, lifecycles), operations.CreateCommand{
+		Code: "subdep-add", Name: "Add", Endpoint: "https://dep.example.test/hook",
+		EventTypes: []subscription.EventTypeBinding{current},
+	})
+	require.NoError(t, err)
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, lifecycles), operations.UpdateCommand{
+		ID: ev.SubscriptionID, EventTypes: []subscription.EventTypeBinding{current, deprecated},
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "EVENT_TYPE_DEPRECATED")
+}
+
 func TestCreateSubscription_DuplicateCode_Conflict(t *testing.T) {
 	t.Parallel()
 	repo := subscription.NewRepository(testpg.Pool(t))
 	uow := testpg.NewUoW(t)
 	mustCreate(t, repo, uow, "subdup-code", "First")
 
-	_, err := runAuthorized(uow, operations.CreateSubscription(repo),
+	_, err := runAuthorized(uow, operations.CreateSubscription(repo, nil, nil, nil),
 		operations.CreateCommand{
 			Code: "subdup-code", Name: "Second", Endpoint: "https://dup.example.test",
 			EventTypes: []subscription.EventTypeBinding{subscription.NewEventTypeBinding("subdup:a:b:c")},
@@ -213,7 +340,7 @@ func TestCreateSubscription_ResourceScope(t *testing.T) {
 	})
 
 	// Platform-wide (nil ClientID) → cross-client → anchor required → denied.
-	_, err := usecaseop.Run(clientCtx, uow, operations.CreateSubscription(repo),
+	_, err := usecaseop.Run(clientCtx, uow, operations.CreateSubscription(repo, nil, nil, nil),
 		operations.CreateCommand{
 			Code: "subscope-platform", Name: "X", Endpoint: "https://x.example.test",
 			EventTypes: bindings,
@@ -222,7 +349,7 @@ func TestCreateSubscription_ResourceScope(t *testing.T) {
 
 	// Bound to a client the principal cannot access → denied.
 	other := "cli_subscope_other"
-	_, err = usecaseop.Run(clientCtx, uow, operations.CreateSubscription(repo),
+	_, err = usecaseop.Run(clientCtx, uow, operations.CreateSubscription(repo, nil, nil, nil),
 		operations.CreateCommand{
 			Code: "subscope-other", Name: "X", Endpoint: "https://x.example.test",
 			ClientID: &other, EventTypes: bindings,
@@ -230,7 +357,7 @@ func TestCreateSubscription_ResourceScope(t *testing.T) {
 	testpg.RequireUsecaseError(t, err, usecase.KindAuthorization, "SCOPE_FORBIDDEN")
 
 	// Bound to the principal's own client → allowed.
-	ev, err := usecaseop.Run(clientCtx, uow, operations.CreateSubscription(repo),
+	ev, err := usecaseop.Run(clientCtx, uow, operations.CreateSubscription(repo, nil, nil, nil),
 		operations.CreateCommand{
 			Code: "subscope-own", Name: "Mine", Endpoint: "https://x.example.test",
 			ClientID: &ownClient, EventTypes: bindings,
@@ -248,7 +375,7 @@ func TestUpdateSubscription_HappyPath(t *testing.T) {
 	uow := testpg.NewUoW(t)
 	seeded := mustCreate(t, repo, uow, "subupd-happy", "Before")
 
-	ev, err := runAuthorized(uow, operations.UpdateSubscription(repo), operations.UpdateCommand{
+	ev, err := runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
 		ID:          seeded.SubscriptionID,
 		Name:        ptr("  After  "), // op must trim
 		Description: ptr("after"),
@@ -308,7 +435,7 @@ func TestUpdateSubscription_Errors(t *testing.T) {
 	for _, tc := range cases {
 		t.Run(tc.name, func(t *testing.T) {
 			t.Parallel()
-			_, err := runAuthorized(uow, operations.UpdateSubscription(repo), tc.cmd)
+			_, err := runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), tc.cmd)
 			testpg.RequireUsecaseError(t, err, tc.kind, tc.code)
 		})
 	}
@@ -344,6 +471,132 @@ func TestPauseResumeSubscription_RoundTrip(t *testing.T) {
 	assert.Equal(t, subscription.StatusActive, got.Status)
 }
 
+func TestPromoteShadow(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := subscription.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seeded := mustCreate(t, repo, uow, "subshd-promote", "Shadow Me")
+
+	_, err := runAuthorized(uow, operations.PromoteShadow(repo, nil), operations.PromoteShadowCommand{ID: seeded.SubscriptionID})
+	testpg.RequireUsecaseError(t, err, usecase.KindConflict, "NO_SHADOW")
+
+	shadow := "https://green.example.test/subshd-promote"
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
+		ID: seeded.SubscriptionID, ShadowEndpoint: &shadow,
+	})
+	require.NoError(t, err)
+	code := int32(200)
+	require.NoError(t, repo.RecordShadow(ctx, subscription.NewShadowDelivery(seeded.SubscriptionID, "dsj_shd1", shadow,
+		subscription.ShadowOutcome{StatusCode: &code, Success: true, DurationMs: 40},
+		subscription.ShadowOutcome{StatusCode: &code, Success: true, DurationMs: 20})))
+	rep, err := repo.ShadowReport(ctx, seeded.SubscriptionID, shadow, time.Now().Add(-time.Hour))
+	require.NoError(t, err)
+	assert.Equal(t, int64(1), rep.Deliveries)
+	assert.Equal(t, int64(1), rep.Agreed)
+	assert.Equal(t, int64(20), rep.Shadow.AvgMs)
+	require.Len(t, rep.Outcomes, 1)
+
+	promoted, err := runAuthorized(uow, operations.PromoteShadow(repo, nil), operations.PromoteShadowCommand{ID: seeded.SubscriptionID})
+	require.NoError(t, err)
+	assert.Equal(t, "https://seed.example.test/subshd-promote", promoted.PreviousEndpoint)
+	got, err := repo.FindByID(ctx, seeded.SubscriptionID)
+	require.NoError(t, err)
+	assert.Equal(t, shadow, got.Endpoint)
+	assert.Nil(t, got.ShadowEndpoint)
+
+	// Saving without the shadow drops what was recorded for it.
+	rep, err = repo.ShadowReport(ctx, seeded.SubscriptionID, shadow, time.Now().Add(-time.Hour))
+	require.NoError(t, err)
+	assert.Zero(t, rep.Deliveries)
+}
+
+func TestSetTrafficSplit(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := subscription.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seeded := mustCreate(t, repo, uow, "subsplit-set", "Split Me")
+	v2 := "https://v2.example.test/subsplit-set"
+
+	_, err := runAuthorized(uow, operations.SetTrafficSplit(repo, nil), operations.SetTrafficSplitCommand{
+		ID: seeded.SubscriptionID, Split: []subscription.SplitTarget{{Endpoint: "ftp://v2", Weight: 10}},
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_SPLIT_ENDPOINT")
+	_, err = runAuthorized(uow, operations.SetTrafficSplit(repo, nil), operations.SetTrafficSplitCommand{
+		ID: seeded.SubscriptionID, Split: []subscription.SplitTarget{{Endpoint: v2, Weight: 101}},
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_TRAFFIC_SPLIT")
+
+	set, err := runAuthorized(uow, operations.SetTrafficSplit(repo, nil), operations.SetTrafficSplitCommand{
+		ID: seeded.SubscriptionID, Split: []subscription.SplitTarget{{Endpoint: v2, Weight: 10}},
+	})
+	require.NoError(t, err)
+	assert.Equal(t, int32(90), set.EndpointWeight)
+	got, err := repo.FindByID(ctx, seeded.SubscriptionID)
+	require.NoError(t, err)
+	assert.Equal(t, []subscription.SplitTarget{{Endpoint: v2, Weight: 10}}, got.TrafficSplit)
+
+	// A split endpoint can't become the endpoint, and a split subscription
+	// can't move to pull.
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
+		ID: seeded.SubscriptionID, Endpoint: &v2,
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_TRAFFIC_SPLIT")
+	pull := "PULL"
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
+		ID: seeded.SubscriptionID, Delivery: &pull,
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "TRAFFIC_SPLIT_NEEDS_PUSH")
+
+	stats, err := repo.TargetStats(ctx, got, time.Now().Add(-time.Hour))
+	require.NoError(t, err)
+	require.Len(t, stats, 2)
+	assert.Equal(t, "https://seed.example.test/subsplit-set", stats[0].Endpoint)
+	assert.Equal(t, int32(90), stats[0].Weight)
+	assert.Equal(t, subscription.TargetStats{Endpoint: v2, Weight: 10}, stats[1])
+
+	_, err = runAuthorized(uow, operations.SetTrafficSplit(repo, nil), operations.SetTrafficSplitCommand{ID: seeded.SubscriptionID})
+	require.NoError(t, err)
+	got, err = repo.FindByID(ctx, seeded.SubscriptionID)
+	require.NoError(t, err)
+	assert.Empty(t, got.TrafficSplit)
+}
+
+func TestUpdateSubscription_TLSTrust(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	repo := subscription.NewRepository(testpg.Pool(t))
+	uow := testpg.NewUoW(t)
+	seeded := mustCreate(t, repo, uow, "subtls-trust", "Private CA")
+
+	_, err := runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
+		ID: seeded.SubscriptionID, TLS: &outbound.TLSTrust{MinVersion: "1.1"},
+	})
+	testpg.RequireUsecaseError(t, err, usecase.KindValidation, "INVALID_TLS_TRUST")
+
+	trust := &outbound.TLSTrust{SPKIPins: []string{"sha256/" + strings.Repeat("A", 43) + "="}, MinVersion: "1.3"}
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
+		ID: seeded.SubscriptionID, TLS: trust,
+	})
+	require.NoError(t, err)
+	got, err := repo.FindByID(ctx, seeded.SubscriptionID)
+	require.NoError(t, err)
+	assert.Equal(t, trust, got.TLS)
+	loaded, err := repo.TLSTrustFor(ctx, seeded.SubscriptionID)
+	require.NoError(t, err)
+	assert.Equal(t, trust, loaded)
+
+	// An empty trust drops it.
+	_, err = runAuthorized(uow, operations.UpdateSubscription(repo, nil, nil, nil), operations.UpdateCommand{
+		ID: seeded.SubscriptionID, TLS: &outbound.TLSTrust{},
+	})
+	require.NoError(t, err)
+	loaded, err = repo.TLSTrustFor(ctx, seeded.SubscriptionID)
+	require.NoError(t, err)
+	assert.Nil(t, loaded)
+}
+
 func TestPauseSubscription_Errors(t *testing.T) {
 	t.Parallel()
 	repo := subscription.NewRepository(testpg.Pool(t))
@@ -446,7 +699,7 @@ func TestSyncSubscriptions_UpsertRemoveAndPoolResolution(t *testing.T) {
 		appCode, uiRow.SubscriptionID)
 	require.NoError(t, err)
 
-	first, err := usecaseop.Run(appAccessCtx(), uow, operations.SyncSubscriptions(subRepo, connRepo, poolRepo),
+	first, err := usecaseop.Run(appAccessCtx(), uow, operations.SyncSubscriptions(subRepo, connRepo, poolRepo, nil),
 		operations.SyncSubscriptionsCommand{
 			ApplicationCode: appCode,
 			Subscriptions: []operations.SyncSubscriptionInput{
@@ -490,7 +743,7 @@ func TestSyncSubscriptions_UpsertRemoveAndPoolResolution(t *testing.T) {
 	assert.Nil(t, subB.DispatchPoolID, "unresolvable pool code must leave the pool ref unset")
 	assert.Nil(t, subB.DispatchPoolCode)
 
-	second, err := usecaseop.Run(appAccessCtx(), uow, operations.SyncSubscriptions(subRepo, connRepo, poolRepo),
+	second, err := usecaseop.Run(appAccessCtx(), uow, operations.SyncSubscriptions(subRepo, connRepo, poolRepo, nil),
 		operations.SyncSubscriptionsCommand{
 			ApplicationCode: appCode,
 			Subscriptions: []operations.SyncSubscriptionInput{
@@ -567,7 +820,7 @@ func TestSyncSubscriptions_Validation(t *testing.T) {
 		t.Run(tc.name, func(t *testing.T) {
 			t.Parallel()
 			_, err := usecaseop.Run(appAccessCtx(), uow,
-				operations.SyncSubscriptions(subRepo, connRepo, poolRepo), tc.cmd, testpg.TestEC())
+				operations.SyncSubscriptions(subRepo, connRepo, poolRepo, nil), tc.cmd, testpg.TestEC())
 			testpg.RequireUsecaseError(t, err, usecase.KindValidation, tc.code)
 		})
 	}
@@ -583,7 +836,7 @@ func TestSyncSubscriptions_ConnectionNotFound(t *testing.T) {
 	poolRepo := dispatchpool.NewRepository(pool)
 	uow := testpg.NewUoW(t)
 
-	_, err := usecaseop.Run(appAccessCtx(), uow, operations.SyncSubscriptions(subRepo, connRepo, poolRepo),
+	_, err := usecaseop.Run(appAccessCtx(), uow, operations.SyncSubscriptions(subRepo, connRepo, poolRepo, nil),
 		operations.SyncSubscriptionsCommand{
 			ApplicationCode: "subsyncconn404",
 			Subscriptions: []operations.SyncSubscriptionInput{
@@ -612,7 +865,7 @@ func TestSyncSubscriptions_RequiresAppAccess(t *testing.T) {
 	noAccessCtx := testpg.WithAuth(context.Background(), &auth.AuthContext{
 		PrincipalID: "prn_noappaccess", Scope: auth.ScopeClient, Applications: []string{"app_other"},
 	})
-	_, err := usecaseop.Run(noAccessCtx, uow, operations.SyncSubscriptions(subRepo, connRepo, poolRepo),
+	_, err := usecaseop.Run(noAccessCtx, uow, operations.SyncSubscriptions(subRepo, connRepo, poolRepo, nil),
 		operations.SyncSubscriptionsCommand{
 			ApplicationID:   "app_subsyncnoaccess",
 			ApplicationCode: "subsyncnoaccess",
@@ -46,6 +46,7 @@ func PauseSubscription(repo *subscription.Repository) usecaseop.Operation[PauseC
 			event := SubscriptionPaused{
 				Metadata:       usecase.NewEventMetadata(ec, SubscriptionPausedType, Source, subjectFor(s.ID)),
 				SubscriptionID: s.ID,
+				ClientID:       s.ClientID,
 			}
 			return usecaseop.Save(s, repo, event), nil
 		},
@@ -0,0 +1,67 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// PromoteShadowCommand is the input DTO.
+type PromoteShadowCommand struct {
+	ID string `json:"id"`
+}
+
+// PromoteShadow makes a subscription's shadow endpoint its endpoint, stops
+// shadowing, and emits [SubscriptionShadowPromoted]. The shadow is checked
+// against the client's outbound policy again (targets; nil = unchecked),
+// since the policy may have changed since it was set.
+func PromoteShadow(repo *subscription.Repository, targets *outbound.Policies) usecaseop.Operation[PromoteShadowCommand, SubscriptionShadowPromoted] {
+	return usecaseop.Operation[PromoteShadowCommand, SubscriptionShadowPromoted]{
+		Name: "PromoteSubscriptionShadow",
+		Validate: func(_ context.Context, cmd PromoteShadowCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "id is required")
+			}
+			return nil
+		},
+		// Per-resource authz runs post-load in Execute; the coarse "may write
+		// subscriptions" permission is on the controller.
+		Authorize: usecaseop.Public[PromoteShadowCommand],
+		Execute: func(ctx context.Context, cmd PromoteShadowCommand, ec usecase.ExecutionContext) (usecaseop.Plan[SubscriptionShadowPromoted], error) {
+			s, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if s == nil {
+				return nil, httperror.NotFound("Subscription", cmd.ID)
+			}
+			if err := auth.CheckScopeAccess(auth.FromContext(ctx), s.ClientID); err != nil {
+				return nil, err
+			}
+			if s.ShadowEndpoint == nil {
+				return nil, usecase.Conflict("NO_SHADOW", "subscription has no shadow endpoint to promote")
+			}
+			if err := targets.CheckURL(s.ClientID, *s.ShadowEndpoint); err != nil {
+				return nil, usecase.Validation("SHADOW_ENDPOINT_BLOCKED", err.Error())
+			}
+			previous, _ := s.PromoteShadow()
+			if err := subscription.ValidateTrafficSplit(s.Endpoint, s.TrafficSplit); err != nil {
+				return nil, usecase.Validation("INVALID_TRAFFIC_SPLIT", err.Error())
+			}
+			event := SubscriptionShadowPromoted{
+				Metadata:         usecase.NewEventMetadata(ec, ShadowPromotedType, Source, subjectFor(s.ID)),
+				SubscriptionID:   s.ID,
+				PreviousEndpoint: previous,
+				Endpoint:         s.Endpoint,
+				ClientID:         s.ClientID,
+			}
+			return usecaseop.Save(s, repo, event), nil
+		},
+	}
+}
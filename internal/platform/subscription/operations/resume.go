@@ -45,6 +45,7 @@ func ResumeSubscription(repo *subscription.Repository) usecaseop.Operation[Resum
 			event := SubscriptionResumed{
 				Metadata:       usecase.NewEventMetadata(ec, SubscriptionResumedType, Source, subjectFor(s.ID)),
 				SubscriptionID: s.ID,
+				ClientID:       s.ClientID,
 			}
 			return usecaseop.Save(s, repo, event), nil
 		},
@@ -0,0 +1,78 @@
+package operations
+
+import (
+	"context"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecaseop"
+)
+
+// SetTrafficSplitCommand is the input DTO. An empty Split turns splitting
+// off, sending every message group back to the endpoint.
+type SetTrafficSplitCommand struct {
+	ID    string                     `json:"id"`
+	Split []subscription.SplitTarget `json:"split"`
+}
+
+// SetTrafficSplit replaces a push subscription's traffic split and emits
+// [SubscriptionTrafficSplitSet]. Each endpoint is checked like the
+// subscription's own, against the client's outbound policy (targets;
+// nil = unchecked). Fan-out picks the new weights up on its next
+// subscription refresh.
+func SetTrafficSplit(repo *subscription.Repository, targets *outbound.Policies) usecaseop.Operation[SetTrafficSplitCommand, SubscriptionTrafficSplitSet] {
+	return usecaseop.Operation[SetTrafficSplitCommand, SubscriptionTrafficSplitSet]{
+		Name: "SetSubscriptionTrafficSplit",
+		Validate: func(_ context.Context, cmd SetTrafficSplitCommand) error {
+			if strings.TrimSpace(cmd.ID) == "" {
+				return usecase.Validation("ID_REQUIRED", "id is required")
+			}
+			for _, t := range cmd.Split {
+				if !urlPattern.MatchString(t.Endpoint) {
+					return usecase.Validation("INVALID_SPLIT_ENDPOINT", "each traffic split endpoint must be a http(s) URL")
+				}
+			}
+			return nil
+		},
+		// Per-resource authz runs post-load in Execute; the coarse "may write
+		// subscriptions" permission is on the controller.
+		Authorize: usecaseop.Public[SetTrafficSplitCommand],
+		Execute: func(ctx context.Context, cmd SetTrafficSplitCommand, ec usecase.ExecutionContext) (usecaseop.Plan[SubscriptionTrafficSplitSet], error) {
+			s, err := repo.FindByID(ctx, cmd.ID)
+			if err != nil {
+				return nil, usecase.Internal("REPO", "find_by_id failed", err)
+			}
+			if s == nil {
+				return nil, httperror.NotFound("Subscription", cmd.ID)
+			}
+			if err := auth.CheckScopeAccess(auth.FromContext(ctx), s.ClientID); err != nil {
+				return nil, err
+			}
+			if len(cmd.Split) > 0 && s.Delivery != subscription.DeliveryPush {
+				return nil, usecase.Validation("TRAFFIC_SPLIT_NEEDS_PUSH", "only a push subscription can split its traffic")
+			}
+			if err := subscription.ValidateTrafficSplit(s.Endpoint, cmd.Split); err != nil {
+				return nil, usecase.Validation("INVALID_TRAFFIC_SPLIT", err.Error())
+			}
+			for _, t := range cmd.Split {
+				if err := targets.CheckURL(s.ClientID, t.Endpoint); err != nil {
+					return nil, usecase.Validation("SPLIT_ENDPOINT_BLOCKED", err.Error())
+				}
+			}
+			s.SetTrafficSplit(cmd.Split)
+			event := SubscriptionTrafficSplitSet{
+				Metadata:       usecase.NewEventMetadata(ec, TrafficSplitSetType, Source, subjectFor(s.ID)),
+				SubscriptionID: s.ID,
+				Endpoint:       s.Endpoint,
+				EndpointWeight: s.EndpointWeight(),
+				Split:          s.TrafficSplit,
+				ClientID:       s.ClientID,
+			}
+			return usecaseop.Save(s, repo, event), nil
+		},
+	}
+}
@@ -4,6 +4,7 @@ import (
 	"context"
 	"strings"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
@@ -65,6 +66,8 @@ type SyncSubscriptionsCommand struct {
 //     an unresolvable code is silently left unset (matches Rust).
 //   - maxRetries / timeoutSeconds are only overwritten when present.
 //   - RemoveUnlisted hard-deletes API/CODE rows absent from the payload.
+//   - Each target must pass the outbound policy (targets; nil = unchecked)
+//     of the row's client (400 ENDPOINT_BLOCKED).
 //
 // Authorization: the coarse "may sync subscriptions" permission and the app
 // resolution (code→id) are the controller's job; the use case enforces the
@@ -76,6 +79,7 @@ func SyncSubscriptions(
 	subRepo *subscription.Repository,
 	connRepo *connection.Repository,
 	poolRepo *dispatchpool.Repository,
+	targets *outbound.Policies,
 ) usecaseop.Operation[SyncSubscriptionsCommand, SubscriptionsSynced] {
 	return usecaseop.Operation[SyncSubscriptionsCommand, SubscriptionsSynced]{
 		Name: "SyncSubscriptions",
@@ -143,6 +147,14 @@ func SyncSubscriptions(
 				syncedCodes = append(syncedCodes, in.Code)
 				syncedSet[in.Code] = struct{}{}
 
+				var clientID *string
+				if cur, ok := existingByCode[in.Code]; ok {
+					clientID = cur.ClientID
+				}
+				if err := targets.CheckURL(clientID, in.Target); err != nil {
+					return nil, usecase.Validation("ENDPOINT_BLOCKED", "Subscription '"+in.Code+"': "+err.Error())
+				}
+
 				bindings := make([]subscription.EventTypeBinding, 0, len(in.EventTypes))
 				for _, et := range in.EventTypes {
 					b := subscription.NewEventTypeBinding(et.EventTypeCode)
@@ -167,12 +179,16 @@ func SyncSubscriptions(
 						cur.TimeoutSeconds = *in.TimeoutSeconds
 					}
 					resolveDispatchPool(ctx, poolRepo, in.DispatchPoolCode, &cur.DispatchPoolID, &cur.DispatchPoolCode)
+					if err := checkResidency(ctx, subRepo, cur); err != nil {
+						return nil, err
+					}
 					saves = append(saves, usecasepgx.SyncSaveItem[subscription.Subscription]{
 						Aggregate: cur,
 						Event: SubscriptionUpdated{
 							Metadata:       usecase.NewEventMetadata(ec, SubscriptionUpdatedType, Source, subjectFor(cur.ID)),
 							SubscriptionID: cur.ID,
 							Name:           cur.Name,
+							ClientID:       cur.ClientID,
 						},
 					})
 					updated++
@@ -203,6 +219,7 @@ func SyncSubscriptions(
 						SubscriptionID: sub.ID,
 						Code:           sub.Code,
 						Name:           sub.Name,
+						ClientID:       sub.ClientID,
 					},
 				})
 				created++
@@ -223,6 +240,7 @@ func SyncSubscriptions(
 							Metadata:       usecase.NewEventMetadata(ec, SubscriptionDeletedType, Source, subjectFor(cur.ID)),
 							SubscriptionID: cur.ID,
 							Code:           cur.Code,
+							ClientID:       cur.ClientID,
 						},
 					})
 					deleted++
@@ -5,7 +5,10 @@ import (
 	"strings"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/auth"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/etag"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httperror"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecase"
@@ -18,9 +21,17 @@ type UpdateCommand struct {
 	Name             *string                         `json:"name,omitempty"`
 	Description      *string                         `json:"description,omitempty"`
 	Endpoint         *string                         `json:"endpoint,omitempty"`
+	ShadowEndpoint   *string                         `json:"shadowEndpoint,omitempty"` // "" = stop shadowing
 	ConnectionID     *string                         `json:"connectionId,omitempty"`
 	EventTypes       []subscription.EventTypeBinding `json:"eventTypes,omitempty"`
 	CustomConfig     []subscription.ConfigEntry      `json:"customConfig,omitempty"`
+	Headers          []subscription.ConfigEntry      `json:"headers,omitempty"`
+	Transport        *string                         `json:"transport,omitempty"` // "" = deliver directly
+	TLS              *outbound.TLSTrust              `json:"tls,omitempty"`       // {} = drop it
+	Delivery         *string                         `json:"delivery,omitempty"`
+	Batching         *subscription.Batching          `json:"batching,omitempty"` // maxItems 0 = stop batching
+	PayloadFormat    *string                         `json:"payloadFormat,omitempty"`
+	LargePayloads    *string                         `json:"largePayloads,omitempty"`
 	Mode             *string                         `json:"mode,omitempty"`
 	TimeoutSeconds   *int32                          `json:"timeoutSeconds,omitempty"`
 	MaxRetries       *int32                          `json:"maxRetries,omitempty"`
@@ -29,10 +40,16 @@ type UpdateCommand struct {
 	DispatchPoolID   *string                         `json:"dispatchPoolId,omitempty"`
 	ServiceAccountID *string                         `json:"serviceAccountId,omitempty"`
 	DataOnly         *bool                           `json:"dataOnly,omitempty"`
+	// IfMatch is the caller's If-Match header; empty skips the check.
+	IfMatch string `json:"-"`
 }
 
 // UpdateSubscription mutates mutable fields and emits [SubscriptionUpdated].
-func UpdateSubscription(repo *subscription.Repository) usecaseop.Operation[UpdateCommand, SubscriptionUpdated] {
+// A new endpoint is checked against the owning client's outbound policy
+// (targets; nil = unchecked), a new transport against the configured ones
+// (transports; nil = unchecked), and a new event type binding against the
+// deprecated event types (lifecycles; nil = unchecked).
+func UpdateSubscription(repo *subscription.Repository, targets *outbound.Policies, transports *outbound.Transports, lifecycles *eventtype.LifecycleCache) usecaseop.Operation[UpdateCommand, SubscriptionUpdated] {
 	return usecaseop.Operation[UpdateCommand, SubscriptionUpdated]{
 		Name: "UpdateSubscription",
 		Validate: func(_ context.Context, cmd UpdateCommand) error {
@@ -45,6 +62,27 @@ func UpdateSubscription(repo *subscription.Repository) usecaseop.Operation[Updat
 			if cmd.Endpoint != nil && !urlPattern.MatchString(*cmd.Endpoint) {
 				return usecase.Validation("INVALID_ENDPOINT", "endpoint must be a http(s) URL")
 			}
+			if err := subscription.ValidateHeaders(cmd.Headers); err != nil {
+				return usecase.Validation("INVALID_HEADERS", err.Error())
+			}
+			if err := transports.Check(cmd.Transport); err != nil {
+				return usecase.Validation("UNKNOWN_TRANSPORT", err.Error())
+			}
+			if err := cmd.TLS.Validate(); err != nil {
+				return usecase.Validation("INVALID_TLS_TRUST", err.Error())
+			}
+			if cmd.Delivery != nil && !validDelivery(*cmd.Delivery) {
+				return usecase.Validation("INVALID_DELIVERY", "delivery must be PUSH or PULL")
+			}
+			if err := subscription.ValidateBatching(cmd.Batching); err != nil {
+				return usecase.Validation("INVALID_BATCHING", err.Error())
+			}
+			if cmd.PayloadFormat != nil && !validPayloadFormat(*cmd.PayloadFormat) {
+				return usecase.Validation("INVALID_PAYLOAD_FORMAT", payloadFormatMessage)
+			}
+			if cmd.LargePayloads != nil && !validLargePayloads(*cmd.LargePayloads) {
+				return usecase.Validation("INVALID_LARGE_PAYLOADS", largePayloadsMessage)
+			}
 			return nil
 		},
 		// Per-resource authz needs the loaded row, so it runs post-load in
@@ -64,6 +102,9 @@ func UpdateSubscription(repo *subscription.Repository) usecaseop.Operation[Updat
 			if err := auth.CheckScopeAccess(auth.FromContext(ctx), s.ClientID); err != nil {
 				return nil, err
 			}
+			if err := etag.Check(cmd.IfMatch, s.UpdatedAt); err != nil {
+				return nil, err
+			}
 
 			if cmd.Name != nil {
 				s.Name = strings.TrimSpace(*cmd.Name)
@@ -72,6 +113,9 @@ func UpdateSubscription(repo *subscription.Repository) usecaseop.Operation[Updat
 				s.Description = cmd.Description
 			}
 			if cmd.Endpoint != nil {
+				if err := targets.CheckURL(s.ClientID, *cmd.Endpoint); err != nil {
+					return nil, usecase.Validation("ENDPOINT_BLOCKED", err.Error())
+				}
 				s.Endpoint = *cmd.Endpoint
 			}
 			// Set-if-provided, matching Rust's update use case: a nil/omitted value
@@ -81,11 +125,76 @@ func UpdateSubscription(repo *subscription.Repository) usecaseop.Operation[Updat
 				s.ConnectionID = cmd.ConnectionID
 			}
 			if cmd.EventTypes != nil {
+				if err := checkDeprecated(ctx, lifecycles, cmd.EventTypes, s.EventTypes); err != nil {
+					return nil, err
+				}
 				s.EventTypes = cmd.EventTypes
 			}
 			if cmd.CustomConfig != nil {
 				s.CustomConfig = cmd.CustomConfig
 			}
+			if cmd.Headers != nil {
+				s.Headers = cmd.Headers
+			}
+			if cmd.Transport != nil {
+				s.Transport = cmd.Transport
+				if *cmd.Transport == "" {
+					s.Transport = nil
+				}
+			}
+			if cmd.TLS != nil {
+				s.TLS = cmd.TLS
+				if cmd.TLS.IsZero() {
+					s.TLS = nil
+				}
+			}
+			if cmd.Delivery != nil && *cmd.Delivery != "" {
+				next := subscription.ParseDeliveryMode(*cmd.Delivery)
+				// A pull subscription's endpoint was never checked; switching
+				// it to push needs one that passes.
+				if s.Delivery == subscription.DeliveryPull && next == subscription.DeliveryPush && cmd.Endpoint == nil {
+					if !urlPattern.MatchString(s.Endpoint) {
+						return nil, usecase.Validation("INVALID_ENDPOINT", "a push subscription needs a http(s) endpoint")
+					}
+					if err := targets.CheckURL(s.ClientID, s.Endpoint); err != nil {
+						return nil, usecase.Validation("ENDPOINT_BLOCKED", err.Error())
+					}
+				}
+				s.Delivery = next
+			}
+			if cmd.ShadowEndpoint != nil {
+				s.ShadowEndpoint = cmd.ShadowEndpoint
+				if *cmd.ShadowEndpoint == "" {
+					s.ShadowEndpoint = nil
+				}
+			}
+			// Checked after the delivery mode settles: a subscription moving
+			// to pull can't keep its shadow.
+			if s.ShadowEndpoint != nil && (cmd.ShadowEndpoint != nil || cmd.Delivery != nil) {
+				if err := checkShadow(s.ClientID, *s.ShadowEndpoint, s.Delivery, targets); err != nil {
+					return nil, err
+				}
+			}
+			if len(s.TrafficSplit) > 0 && (cmd.Endpoint != nil || cmd.Delivery != nil) {
+				if s.Delivery != subscription.DeliveryPush {
+					return nil, usecase.Validation("TRAFFIC_SPLIT_NEEDS_PUSH", "clear the traffic split before moving the subscription to pull")
+				}
+				if err := subscription.ValidateTrafficSplit(s.Endpoint, s.TrafficSplit); err != nil {
+					return nil, usecase.Validation("INVALID_TRAFFIC_SPLIT", err.Error())
+				}
+			}
+			if cmd.Batching != nil {
+				s.Batching = nil
+				if cmd.Batching.Enabled() {
+					s.Batching = cmd.Batching
+				}
+			}
+			if cmd.PayloadFormat != nil && *cmd.PayloadFormat != "" {
+				s.PayloadFormat = subscription.ParsePayloadFormat(*cmd.PayloadFormat)
+			}
+			if cmd.LargePayloads != nil && *cmd.LargePayloads != "" {
+				s.LargePayloads = subscription.ParseLargePayloads(*cmd.LargePayloads)
+			}
 			if cmd.Mode != nil {
 				s.Mode = common.ParseDispatchMode(*cmd.Mode)
 			}
@@ -110,13 +219,17 @@ func UpdateSubscription(repo *subscription.Repository) usecaseop.Operation[Updat
 			if cmd.DataOnly != nil {
 				s.DataOnly = *cmd.DataOnly
 			}
+			if err := checkResidency(ctx, repo, s); err != nil {
+				return nil, err
+			}
 
 			event := SubscriptionUpdated{
 				Metadata:       usecase.NewEventMetadata(ec, SubscriptionUpdatedType, Source, subjectFor(s.ID)),
 				SubscriptionID: s.ID,
 				Name:           s.Name,
+				ClientID:       s.ClientID,
 			}
-			return usecaseop.Save(s, repo, event), nil
+			return usecaseop.Save(s, etag.Guard[subscription.Subscription](repo, "msg_subscriptions", cmd.IfMatch), event), nil
 		},
 	}
 }
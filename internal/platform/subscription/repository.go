@@ -9,13 +9,15 @@ import (
 	"github.com/jackc/pgx/v5/pgxpool"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/repocommon"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/sqlc/dbq"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
 )
 
 // Repository is the Postgres-backed repository. Tables: msg_subscriptions
-// + msg_subscription_event_types + msg_subscription_custom_configs.
+// + msg_subscription_event_types + msg_subscription_custom_configs
+// + msg_subscription_headers + msg_subscription_targets.
 // EventTypeBinding.Filter is in-memory only — there's no column for it.
 type Repository struct {
 	pool *pgxpool.Pool // retained for FindWithFilters
@@ -80,7 +82,9 @@ func (r *Repository) FindWithFilters(ctx context.Context, status, clientID *stri
 		client_identifier, client_scoped, target, queue, source, status,
 		max_age_seconds, dispatch_pool_id, dispatch_pool_code, delay_seconds, sequence,
 		mode, timeout_seconds, max_retries, service_account_id, data_only,
-		created_by, created_at, updated_at, connection_id FROM msg_subscriptions` + f.Where() + ` ORDER BY code`
+		created_by, created_at, updated_at, connection_id, transport, delivery_mode,
+		batch_max_items, batch_max_bytes, batch_max_wait_seconds, payload_format, large_payloads, shadow_target,
+		tls_ca_bundle, tls_spki_pins, tls_min_version FROM msg_subscriptions` + f.Where() + ` ORDER BY code`
 
 	rows, err := r.pool.Query(ctx, q, f.Args()...)
 	if err != nil {
@@ -106,7 +110,9 @@ func (r *Repository) FindByApplicationCode(ctx context.Context, appCode string)
 		client_identifier, client_scoped, target, queue, source, status,
 		max_age_seconds, dispatch_pool_id, dispatch_pool_code, delay_seconds, sequence,
 		mode, timeout_seconds, max_retries, service_account_id, data_only,
-		created_by, created_at, updated_at, connection_id FROM msg_subscriptions
+		created_by, created_at, updated_at, connection_id, transport, delivery_mode,
+		batch_max_items, batch_max_bytes, batch_max_wait_seconds, payload_format, large_payloads, shadow_target,
+		tls_ca_bundle, tls_spki_pins, tls_min_version FROM msg_subscriptions
 		WHERE application_code = $1 ORDER BY code`
 	rows, err := r.pool.Query(ctx, baseSelect, appCode)
 	if err != nil {
@@ -123,46 +129,257 @@ func (r *Repository) FindByApplicationCode(ctx context.Context, appCode string)
 	return r.hydrateAll(ctx, bare)
 }
 
+// HeadersFor returns a subscription's custom delivery headers, unresolved.
+// Used on the delivery path, which needs nothing else from the row.
+func (r *Repository) HeadersFor(ctx context.Context, id string) ([]ConfigEntry, error) {
+	rows, err := r.q.SubscriptionHeadersForSubs(ctx, []string{id})
+	if err != nil {
+		return nil, err
+	}
+	out := make([]ConfigEntry, 0, len(rows))
+	for _, h := range rows {
+		out = append(out, ConfigEntry{Key: h.HeaderName, Value: h.HeaderValue})
+	}
+	return out, nil
+}
+
+// TransportFor returns the name of the outbound transport a subscription
+// delivers through, or nil for direct delivery (or a deleted row).
+func (r *Repository) TransportFor(ctx context.Context, id string) (*string, error) {
+	res, err := r.q.SubscriptionTransportFor(ctx, id)
+	t, err := repocommon.One(res, err, "subscription repo")
+	if t == nil || err != nil {
+		return nil, err
+	}
+	return *t, nil
+}
+
+// BatchingFor returns a subscription's batching limits, or nil when it
+// delivers one job at a time (or the row is gone). Used on the delivery
+// path, like TransportFor.
+func (r *Repository) BatchingFor(ctx context.Context, id string) (*Batching, error) {
+	res, err := r.q.SubscriptionBatchingFor(ctx, id)
+	row, err := repocommon.One(res, err, "subscription repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return batchingFromRow(row.BatchMaxItems, row.BatchMaxBytes, row.BatchMaxWaitSeconds), nil
+}
+
+// PayloadFormatFor returns how a subscription's deliveries are
+// serialized; JSON when the row is gone. Used on the delivery path.
+func (r *Repository) PayloadFormatFor(ctx context.Context, id string) (PayloadFormat, error) {
+	res, err := r.q.SubscriptionPayloadFormatFor(ctx, id)
+	f, err := repocommon.One(res, err, "subscription repo")
+	if f == nil || err != nil {
+		return FormatJSON, err
+	}
+	return ParsePayloadFormat(*f), nil
+}
+
+// LargePayloadsFor returns how a subscription receives offloaded payloads;
+// INLINE when the row is gone. Used on the delivery path.
+func (r *Repository) LargePayloadsFor(ctx context.Context, id string) (LargePayloads, error) {
+	res, err := r.q.SubscriptionLargePayloadsFor(ctx, id)
+	lp, err := repocommon.One(res, err, "subscription repo")
+	if lp == nil || err != nil {
+		return LargePayloadsInline, err
+	}
+	return ParseLargePayloads(*lp), nil
+}
+
+// ShadowEndpointFor returns the endpoint a subscription's deliveries are
+// copied to, or nil when it has none (or the row is gone). Used on the
+// delivery path, like TransportFor.
+func (r *Repository) ShadowEndpointFor(ctx context.Context, id string) (*string, error) {
+	res, err := r.q.SubscriptionShadowTargetFor(ctx, id)
+	t, err := repocommon.One(res, err, "subscription repo")
+	if t == nil || err != nil {
+		return nil, err
+	}
+	return *t, nil
+}
+
+// TLSTrustFor returns a subscription's own TLS trust, or nil when it has
+// none (or the row is gone). Used on the delivery path, like TransportFor.
+func (r *Repository) TLSTrustFor(ctx context.Context, id string) (*outbound.TLSTrust, error) {
+	res, err := r.q.SubscriptionTLSTrustFor(ctx, id)
+	row, err := repocommon.One(res, err, "subscription repo")
+	if row == nil || err != nil {
+		return nil, err
+	}
+	return tlsTrustFromRow(row.TlsCaBundle, row.TlsSpkiPins, row.TlsMinVersion), nil
+}
+
+// RecordShadow stores the outcome of one shadow delivery.
+func (r *Repository) RecordShadow(ctx context.Context, d *ShadowDelivery) error {
+	return r.q.SubscriptionShadowDeliveryInsert(ctx, dbq.SubscriptionShadowDeliveryInsertParams{
+		ID:                d.ID,
+		SubscriptionID:    d.SubscriptionID,
+		DispatchJobID:     d.DispatchJobID,
+		ShadowTarget:      d.ShadowEndpoint,
+		PrimaryStatusCode: d.Primary.StatusCode,
+		PrimarySuccess:    d.Primary.Success,
+		PrimaryDurationMs: d.Primary.DurationMs,
+		PrimaryError:      d.Primary.Error,
+		ShadowStatusCode:  d.Shadow.StatusCode,
+		ShadowSuccess:     d.Shadow.Success,
+		ShadowDurationMs:  d.Shadow.DurationMs,
+		ShadowError:       d.Shadow.Error,
+		CreatedAt:         d.CreatedAt,
+	})
+}
+
+// ShadowReport compares the primary and shadow outcomes of the deliveries
+// copied to endpoint since the given time.
+func (r *Repository) ShadowReport(ctx context.Context, id, endpoint string, since time.Time) (*ShadowReport, error) {
+	row, err := r.q.SubscriptionShadowReport(ctx, dbq.SubscriptionShadowReportParams{
+		SubscriptionID: id, ShadowTarget: endpoint, CreatedAt: since,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("subscription repo: %w", err)
+	}
+	outcomes, err := r.q.SubscriptionShadowOutcomes(ctx, dbq.SubscriptionShadowOutcomesParams{
+		SubscriptionID: id, ShadowTarget: endpoint, CreatedAt: since, Limit: shadowOutcomeLimit,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("subscription repo: %w", err)
+	}
+	rep := &ShadowReport{
+		ShadowEndpoint: endpoint,
+		Since:          since,
+		Deliveries:     row.Deliveries,
+		Agreed:         row.Agreed,
+		Primary:        ShadowSummary{Successes: row.PrimarySuccesses, AvgMs: row.PrimaryAvgMs, P50Ms: row.PrimaryP50Ms, P95Ms: row.PrimaryP95Ms},
+		Shadow:         ShadowSummary{Successes: row.ShadowSuccesses, AvgMs: row.ShadowAvgMs, P50Ms: row.ShadowP50Ms, P95Ms: row.ShadowP95Ms},
+		Outcomes:       make([]ShadowOutcomeCount, 0, len(outcomes)),
+	}
+	for _, o := range outcomes {
+		rep.Outcomes = append(rep.Outcomes, ShadowOutcomeCount{
+			PrimaryStatusCode: o.PrimaryStatusCode,
+			PrimarySuccess:    o.PrimarySuccess,
+			ShadowStatusCode:  o.ShadowStatusCode,
+			ShadowSuccess:     o.ShadowSuccess,
+			Deliveries:        o.Deliveries,
+		})
+	}
+	return rep, nil
+}
+
+// TargetStats reports how each of s's endpoints fared with the jobs
+// created since since: the endpoint and its split first, in that order and
+// whether or not they have jobs, then any endpoint the window still has
+// jobs for.
+func (r *Repository) TargetStats(ctx context.Context, s *Subscription, since time.Time) ([]TargetStats, error) {
+	rows, err := r.q.SubscriptionTargetStats(ctx, dbq.SubscriptionTargetStatsParams{
+		SubscriptionID: &s.ID, CreatedAt: since,
+	})
+	if err != nil {
+		return nil, err
+	}
+	out := make([]TargetStats, 0, 1+len(s.TrafficSplit)+len(rows))
+	index := make(map[string]int, cap(out))
+	add := func(endpoint string, weight int32) {
+		index[endpoint] = len(out)
+		out = append(out, TargetStats{Endpoint: endpoint, Weight: weight})
+	}
+	add(s.Endpoint, s.EndpointWeight())
+	for _, t := range s.TrafficSplit {
+		add(t.Endpoint, t.Weight)
+	}
+	for _, row := range rows {
+		i, ok := index[row.TargetUrl]
+		if !ok {
+			add(row.TargetUrl, 0)
+			i = len(out) - 1
+		}
+		st := &out[i]
+		st.Jobs, st.Completed, st.Failed = row.Jobs, row.Completed, row.Failed
+		st.Attempts, st.Successes = row.Attempts, row.Successes
+		st.AvgMs, st.P95Ms = row.AvgMs, row.P95Ms
+	}
+	return out, nil
+}
+
+// RegionsFor returns the data-residency regions of a client and of a
+// dispatch pool; each is nil when unset, when its id is nil, or when the
+// row is gone. Used by the create/update residency check.
+func (r *Repository) RegionsFor(ctx context.Context, clientID, poolID *string) (clientRegion, poolRegion *string, err error) {
+	err = r.pool.QueryRow(ctx, `SELECT
+		(SELECT region FROM tnt_clients WHERE id = $1),
+		(SELECT region FROM msg_dispatch_pools WHERE id = $2)`,
+		clientID, poolID).Scan(&clientRegion, &poolRegion)
+	return clientRegion, poolRegion, err
+}
+
 // Persist implements usecasepgx.Persist[Subscription]. Replaces the
-// junction-table rows (event_types, custom_config) wholesale.
+// junction-table rows (event_types, custom_config, headers) wholesale.
 func (r *Repository) Persist(ctx context.Context, s *Subscription, tx *usecasepgx.DbTx) error {
 	q := r.q.WithTx(tx.Inner())
+	var b Batching
+	if s.Batching != nil {
+		b = *s.Batching
+	}
+	caBundle, pins, minVersion := tlsTrustToRow(s.TLS)
 	if err := q.SubscriptionUpsert(ctx, dbq.SubscriptionUpsertParams{
-		ID:               s.ID,
-		Code:             s.Code,
-		ApplicationCode:  s.ApplicationCode,
-		Name:             s.Name,
-		Description:      s.Description,
-		ClientID:         s.ClientID,
-		ClientIdentifier: s.ClientIdentifier,
-		ClientScoped:     s.ClientScoped,
-		ConnectionID:     s.ConnectionID,
-		Target:           s.Endpoint,
-		Queue:            s.Queue,
-		Source:           string(s.Source),
-		Status:           string(s.Status),
-		MaxAgeSeconds:    s.MaxAgeSeconds,
-		DispatchPoolID:   s.DispatchPoolID,
-		DispatchPoolCode: s.DispatchPoolCode,
-		DelaySeconds:     s.DelaySeconds,
-		Sequence:         s.Sequence,
-		Mode:             string(s.Mode),
-		TimeoutSeconds:   s.TimeoutSeconds,
-		MaxRetries:       s.MaxRetries,
-		ServiceAccountID: s.ServiceAccountID,
-		DataOnly:         s.DataOnly,
-		CreatedBy:        s.CreatedBy,
-		CreatedAt:        s.CreatedAt,
-		UpdatedAt:        time.Now().UTC(),
+		ID:                  s.ID,
+		Code:                s.Code,
+		ApplicationCode:     s.ApplicationCode,
+		Name:                s.Name,
+		Description:         s.Description,
+		ClientID:            s.ClientID,
+		ClientIdentifier:    s.ClientIdentifier,
+		ClientScoped:        s.ClientScoped,
+		ConnectionID:        s.ConnectionID,
+		Target:              s.Endpoint,
+		Queue:               s.Queue,
+		Source:              string(s.Source),
+		Status:              string(s.Status),
+		MaxAgeSeconds:       s.MaxAgeSeconds,
+		DispatchPoolID:      s.DispatchPoolID,
+		DispatchPoolCode:    s.DispatchPoolCode,
+		DelaySeconds:        s.DelaySeconds,
+		Sequence:            s.Sequence,
+		Mode:                string(s.Mode),
+		TimeoutSeconds:      s.TimeoutSeconds,
+		MaxRetries:          s.MaxRetries,
+		ServiceAccountID:    s.ServiceAccountID,
+		DataOnly:            s.DataOnly,
+		CreatedBy:           s.CreatedBy,
+		CreatedAt:           s.CreatedAt,
+		UpdatedAt:           time.Now().UTC(),
+		Transport:           s.Transport,
+		DeliveryMode:        string(s.Delivery),
+		BatchMaxItems:       b.MaxItems,
+		BatchMaxBytes:       b.MaxBytes,
+		BatchMaxWaitSeconds: b.MaxWaitSeconds,
+		PayloadFormat:       string(s.PayloadFormat),
+		LargePayloads:       string(s.LargePayloads),
+		ShadowTarget:        s.ShadowEndpoint,
+		TlsCaBundle:         caBundle,
+		TlsSpkiPins:         pins,
+		TlsMinVersion:       minVersion,
 	}); err != nil {
 		return fmt.Errorf("subscription persist: %w", err)
 	}
+	if err := q.SubscriptionShadowDeliveriesPrune(ctx, dbq.SubscriptionShadowDeliveriesPruneParams{
+		SubscriptionID: s.ID,
+		ShadowTarget:   s.ShadowEndpoint,
+	}); err != nil {
+		return err
+	}
 	if err := q.SubscriptionEventTypesClear(ctx, s.ID); err != nil {
 		return err
 	}
 	if err := q.SubscriptionConfigsClear(ctx, s.ID); err != nil {
 		return err
 	}
+	if err := q.SubscriptionHeadersClear(ctx, s.ID); err != nil {
+		return err
+	}
+	if err := q.SubscriptionTargetsClear(ctx, s.ID); err != nil {
+		return err
+	}
 	for _, b := range s.EventTypes {
 		if err := q.SubscriptionEventTypeInsert(ctx, dbq.SubscriptionEventTypeInsertParams{
 			SubscriptionID: s.ID,
@@ -182,6 +399,24 @@ func (r *Repository) Persist(ctx context.Context, s *Subscription, tx *usecasepg
 			return err
 		}
 	}
+	for _, h := range s.Headers {
+		if err := q.SubscriptionHeaderInsert(ctx, dbq.SubscriptionHeaderInsertParams{
+			SubscriptionID: s.ID,
+			HeaderName:     h.Key,
+			HeaderValue:    h.Value,
+		}); err != nil {
+			return err
+		}
+	}
+	for _, t := range s.TrafficSplit {
+		if err := q.SubscriptionTargetInsert(ctx, dbq.SubscriptionTargetInsertParams{
+			SubscriptionID: s.ID,
+			Target:         t.Endpoint,
+			Weight:         t.Weight,
+		}); err != nil {
+			return err
+		}
+	}
 	return nil
 }
 
@@ -190,6 +425,9 @@ func (r *Repository) Delete(ctx context.Context, s *Subscription, tx *usecasepgx
 	q := r.q.WithTx(tx.Inner())
 	_ = q.SubscriptionEventTypesClear(ctx, s.ID)
 	_ = q.SubscriptionConfigsClear(ctx, s.ID)
+	_ = q.SubscriptionHeadersClear(ctx, s.ID)
+	_ = q.SubscriptionTargetsClear(ctx, s.ID)
+	_ = q.SubscriptionShadowDeliveriesPrune(ctx, dbq.SubscriptionShadowDeliveriesPruneParams{SubscriptionID: s.ID})
 	return q.SubscriptionDelete(ctx, s.ID)
 }
 
@@ -220,6 +458,14 @@ func (r *Repository) hydrateAll(ctx context.Context, subs []Subscription) ([]Sub
 	if err != nil {
 		return nil, err
 	}
+	headerRows, err := r.q.SubscriptionHeadersForSubs(ctx, ids)
+	if err != nil {
+		return nil, err
+	}
+	targetRows, err := r.q.SubscriptionTargetsForSubs(ctx, ids)
+	if err != nil {
+		return nil, err
+	}
 
 	bindingsByID := make(map[string][]EventTypeBinding)
 	for _, b := range bindingRows {
@@ -235,15 +481,35 @@ func (r *Repository) hydrateAll(ctx context.Context, subs []Subscription) ([]Sub
 			Key: c.ConfigKey, Value: c.ConfigValue,
 		})
 	}
+	headersByID := make(map[string][]ConfigEntry)
+	for _, h := range headerRows {
+		headersByID[h.SubscriptionID] = append(headersByID[h.SubscriptionID], ConfigEntry{
+			Key: h.HeaderName, Value: h.HeaderValue,
+		})
+	}
+	splitByID := make(map[string][]SplitTarget)
+	for _, t := range targetRows {
+		splitByID[t.SubscriptionID] = append(splitByID[t.SubscriptionID], SplitTarget{
+			Endpoint: t.Target, Weight: t.Weight,
+		})
+	}
 	for i := range subs {
 		subs[i].EventTypes = bindingsByID[subs[i].ID]
 		subs[i].CustomConfig = configsByID[subs[i].ID]
+		subs[i].Headers = headersByID[subs[i].ID]
+		subs[i].TrafficSplit = splitByID[subs[i].ID]
 		if subs[i].EventTypes == nil {
 			subs[i].EventTypes = []EventTypeBinding{}
 		}
 		if subs[i].CustomConfig == nil {
 			subs[i].CustomConfig = []ConfigEntry{}
 		}
+		if subs[i].Headers == nil {
+			subs[i].Headers = []ConfigEntry{}
+		}
+		if subs[i].TrafficSplit == nil {
+			subs[i].TrafficSplit = []SplitTarget{}
+		}
 	}
 	return subs, nil
 }
@@ -260,6 +526,7 @@ func rowToSubscription(row dbq.MsgSubscription) *Subscription {
 		ClientScoped:     row.ClientScoped,
 		ConnectionID:     row.ConnectionID,
 		Endpoint:         row.Target,
+		ShadowEndpoint:   row.ShadowTarget,
 		Queue:            row.Queue,
 		Source:           ParseSource(row.Source),
 		Status:           ParseStatus(row.Status),
@@ -276,7 +543,58 @@ func rowToSubscription(row dbq.MsgSubscription) *Subscription {
 		CreatedBy:        row.CreatedBy,
 		CreatedAt:        row.CreatedAt,
 		UpdatedAt:        row.UpdatedAt,
+		Transport:        row.Transport,
+		TLS:              tlsTrustFromRow(row.TlsCaBundle, row.TlsSpkiPins, row.TlsMinVersion),
+		Delivery:         ParseDeliveryMode(row.DeliveryMode),
+		Batching:         batchingFromRow(row.BatchMaxItems, row.BatchMaxBytes, row.BatchMaxWaitSeconds),
+		PayloadFormat:    ParsePayloadFormat(row.PayloadFormat),
+		LargePayloads:    ParseLargePayloads(row.LargePayloads),
 		EventTypes:       []EventTypeBinding{},
 		CustomConfig:     []ConfigEntry{},
+		Headers:          []ConfigEntry{},
+		TrafficSplit:     []SplitTarget{},
+	}
+}
+
+// tlsTrustFromRow maps the tls_* columns; nil when they add nothing.
+func tlsTrustFromRow(caBundle *string, pins []string, minVersion *string) *outbound.TLSTrust {
+	t := &outbound.TLSTrust{SPKIPins: pins}
+	if caBundle != nil {
+		t.CABundle = *caBundle
+	}
+	if minVersion != nil {
+		t.MinVersion = *minVersion
+	}
+	if t.IsZero() {
+		return nil
+	}
+	return t
+}
+
+// tlsTrustToRow is the inverse of tlsTrustFromRow. tls_spki_pins is NOT
+// NULL, so no pins is an empty array.
+func tlsTrustToRow(t *outbound.TLSTrust) (caBundle *string, pins []string, minVersion *string) {
+	if t.IsZero() {
+		return nil, []string{}, nil
+	}
+	if t.CABundle != "" {
+		caBundle = &t.CABundle
+	}
+	if t.MinVersion != "" {
+		minVersion = &t.MinVersion
+	}
+	pins = t.SPKIPins
+	if pins == nil {
+		pins = []string{}
+	}
+	return caBundle, pins, minVersion
+}
+
+// batchingFromRow maps the batch_max_* columns; nil when batching is off.
+func batchingFromRow(maxItems, maxBytes, maxWaitSeconds int32) *Batching {
+	b := &Batching{MaxItems: maxItems, MaxBytes: maxBytes, MaxWaitSeconds: maxWaitSeconds}
+	if !b.Enabled() {
+		return nil
 	}
+	return b
 }
@@ -0,0 +1,95 @@
+package subscription
+
+import (
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// Shadow deliveries: a push subscription with a ShadowEndpoint has each
+// non-batched delivery copied to it once the primary attempt is made. The
+// shadow's answer is recorded next to the primary's and never affects the
+// dispatch job; PromoteShadow swaps it in once the report looks right.
+
+// shadowOutcomeLimit bounds the outcome combinations a report lists.
+const shadowOutcomeLimit = 20
+
+// ShadowOutcome is one endpoint's answer to a delivery.
+type ShadowOutcome struct {
+	// StatusCode is nil when no response came back.
+	StatusCode *int32
+	Success    bool
+	DurationMs int64
+	Error      *string
+}
+
+// ShadowDelivery is one msg_subscription_shadow_deliveries row: a delivery
+// made to both the endpoint and the shadow endpoint.
+type ShadowDelivery struct {
+	ID             string
+	SubscriptionID string
+	DispatchJobID  string
+	ShadowEndpoint string
+	Primary        ShadowOutcome
+	Shadow         ShadowOutcome
+	CreatedAt      time.Time
+}
+
+// NewShadowDelivery records the two outcomes of a delivery.
+func NewShadowDelivery(subscriptionID, dispatchJobID, shadowEndpoint string, primary, shadow ShadowOutcome) *ShadowDelivery {
+	return &ShadowDelivery{
+		ID:             tsid.Generate(tsid.ShadowDelivery),
+		SubscriptionID: subscriptionID,
+		DispatchJobID:  dispatchJobID,
+		ShadowEndpoint: shadowEndpoint,
+		Primary:        primary,
+		Shadow:         shadow,
+		CreatedAt:      time.Now().UTC(),
+	}
+}
+
+// ShadowSummary is one side of a shadow report. Latencies are per attempt.
+type ShadowSummary struct {
+	Successes int64 `json:"successes"`
+	AvgMs     int64 `json:"avgMs"`
+	P50Ms     int64 `json:"p50Ms"`
+	P95Ms     int64 `json:"p95Ms"`
+}
+
+// ShadowOutcomeCount is how many deliveries got one combination of
+// primary and shadow answers.
+type ShadowOutcomeCount struct {
+	PrimaryStatusCode *int32 `json:"primaryStatusCode,omitempty"`
+	PrimarySuccess    bool   `json:"primarySuccess"`
+	ShadowStatusCode  *int32 `json:"shadowStatusCode,omitempty"`
+	ShadowSuccess     bool   `json:"shadowSuccess"`
+	Deliveries        int64  `json:"deliveries"`
+}
+
+// ShadowReport compares a subscription's endpoint with its shadow over the
+// deliveries copied since Since. Agreed counts the deliveries both
+// endpoints succeeded or both failed on; Outcomes lists the most common
+// status code combinations first.
+type ShadowReport struct {
+	ShadowEndpoint string               `json:"shadowEndpoint"`
+	Since          time.Time            `json:"since"`
+	Deliveries     int64                `json:"deliveries"`
+	Agreed         int64                `json:"agreed"`
+	Primary        ShadowSummary        `json:"primary"`
+	Shadow         ShadowSummary        `json:"shadow"`
+	Outcomes       []ShadowOutcomeCount `json:"outcomes"`
+}
+
+// PromoteShadow makes the shadow endpoint the subscription's endpoint and
+// returns the endpoint it replaced. It reports false when there is no
+// shadow to promote.
+func (s *Subscription) PromoteShadow() (string, bool) {
+	if s.ShadowEndpoint == nil {
+		return "", false
+	}
+	previous := s.Endpoint
+	s.Endpoint = *s.ShadowEndpoint
+	s.ShadowEndpoint = nil
+	s.UpdatedAt = time.Now().UTC()
+	return previous, true
+}
@@ -0,0 +1,86 @@
+package subscription
+
+import (
+	"errors"
+	"fmt"
+	"time"
+)
+
+// Traffic splitting: a push subscription's TrafficSplit routes a share of
+// its message groups to other endpoints, the endpoint keeping what the
+// split leaves of 100%. Fan-out picks each job's endpoint from a hash of
+// its message group (the event id when it has none), so a group stays on
+// one endpoint and a weight change moves only the groups it has to.
+
+// MaxSplitTargets caps the extra endpoints of a traffic split.
+const MaxSplitTargets = 10
+
+// SplitTarget is one extra endpoint of a traffic split. Stored in
+// msg_subscription_targets.
+type SplitTarget struct {
+	Endpoint string `json:"endpoint"`
+	// Weight is the percentage of message groups routed here, 1–100.
+	Weight int32 `json:"weight"`
+}
+
+// EndpointWeight is the percentage of message groups the subscription's
+// own endpoint keeps: what the split leaves of 100.
+func (s *Subscription) EndpointWeight() int32 {
+	w := int32(100)
+	for _, t := range s.TrafficSplit {
+		w -= t.Weight
+	}
+	return w
+}
+
+// ValidateTrafficSplit checks a traffic split against the subscription's
+// endpoint: distinct endpoints other than it, each weighted 1–100, the
+// weights adding up to at most 100. An empty split is valid (off). URLs
+// are checked by the caller, like the endpoint's.
+func ValidateTrafficSplit(endpoint string, split []SplitTarget) error {
+	if len(split) > MaxSplitTargets {
+		return fmt.Errorf("a traffic split has at most %d endpoints", MaxSplitTargets)
+	}
+	seen := make(map[string]bool, len(split))
+	var total int32
+	for _, t := range split {
+		if t.Endpoint == endpoint {
+			return errors.New("the subscription's endpoint can't be in its traffic split; it keeps what the split leaves")
+		}
+		if seen[t.Endpoint] {
+			return errors.New("endpoint '" + t.Endpoint + "' is in the traffic split twice")
+		}
+		seen[t.Endpoint] = true
+		if t.Weight < 1 || t.Weight > 100 {
+			return errors.New("endpoint '" + t.Endpoint + "' needs a weight between 1 and 100")
+		}
+		total += t.Weight
+	}
+	if total > 100 {
+		return fmt.Errorf("traffic split weights add up to %d; at most 100 is allowed", total)
+	}
+	return nil
+}
+
+// SetTrafficSplit replaces the subscription's traffic split. Call
+// ValidateTrafficSplit first.
+func (s *Subscription) SetTrafficSplit(split []SplitTarget) {
+	s.TrafficSplit = append([]SplitTarget{}, split...)
+	s.UpdatedAt = time.Now().UTC()
+}
+
+// TargetStats is how one endpoint of a subscription fared with the jobs
+// routed to it since Since. Latencies are per attempt.
+type TargetStats struct {
+	Endpoint string `json:"endpoint"`
+	// Weight is the endpoint's current share of message groups; 0 for an
+	// endpoint no longer in the split that still has jobs in the window.
+	Weight    int32 `json:"weight"`
+	Jobs      int64 `json:"jobs"`
+	Completed int64 `json:"completed"`
+	Failed    int64 `json:"failed"`
+	Attempts  int64 `json:"attempts"`
+	Successes int64 `json:"successes"`
+	AvgMs     int64 `json:"avgMs"`
+	P95Ms     int64 `json:"p95Ms"`
+}
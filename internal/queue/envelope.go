@@ -0,0 +1,124 @@
+package queue
+
+import (
+	"encoding/json"
+	"fmt"
+	"mime"
+	"strings"
+	"sync/atomic"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Envelope content types. A message without a content-type attribute is
+// JSON: that is what Java and pre-negotiation Rust producers write.
+const (
+	ContentTypeJSON     = "application/json"
+	ContentTypeProtobuf = "application/x-protobuf"
+	ContentTypeAvro     = "application/avro"
+)
+
+// ContentTypeAttribute is the message attribute (SQS) or header (NATS)
+// that carries the envelope's content type.
+const ContentTypeAttribute = "contentType"
+
+// Codec serializes the Message envelope.
+type Codec interface {
+	ContentType() string
+	Encode(m common.Message) ([]byte, error)
+	// Decode returns the envelope upgraded to the current schema version
+	// (common.Message.Upgrade).
+	Decode(b []byte) (common.Message, error)
+}
+
+// JSONCodec is the default, Java-compatible envelope.
+type JSONCodec struct{}
+
+func (JSONCodec) ContentType() string { return ContentTypeJSON }
+
+func (JSONCodec) Encode(m common.Message) ([]byte, error) { return json.Marshal(m) }
+
+func (JSONCodec) Decode(b []byte) (common.Message, error) {
+	var m common.Message
+	err := json.Unmarshal(b, &m)
+	return m, err
+}
+
+var (
+	encoding atomic.Pointer[Codec]
+	// avroDecoder decodes Avro envelopes. SetEncoding replaces it with the
+	// registry-backed codec, so consumers verify writer schemas whenever a
+	// registry is configured.
+	avroDecoder atomic.Pointer[AvroCodec]
+)
+
+// SetEncoding selects the envelope publishers write. Consumers decode
+// every content type regardless, so producers can switch one at a time.
+// Call it at startup, before publishers are built.
+func SetEncoding(c Codec) {
+	encoding.Store(&c)
+	if a, ok := c.(*AvroCodec); ok {
+		avroDecoder.Store(a)
+	}
+}
+
+// Encoding returns the codec publishers write; JSON unless SetEncoding
+// chose otherwise.
+func Encoding() Codec {
+	if c := encoding.Load(); c != nil {
+		return *c
+	}
+	return JSONCodec{}
+}
+
+// EncodeMessage serializes m with the configured codec and returns the
+// content type to attach to it.
+func EncodeMessage(m common.Message) ([]byte, string, error) {
+	c := Encoding()
+	b, err := c.Encode(m)
+	if err != nil {
+		return nil, "", err
+	}
+	return b, c.ContentType(), nil
+}
+
+// DecodeMessage deserializes an envelope by its content-type attribute.
+// An empty content type is JSON.
+func DecodeMessage(contentType string, b []byte) (common.Message, error) {
+	switch mediaType(contentType) {
+	case "", ContentTypeJSON:
+		return JSONCodec{}.Decode(b)
+	case ContentTypeProtobuf:
+		return ProtobufCodec{}.Decode(b)
+	case ContentTypeAvro:
+		if a := avroDecoder.Load(); a != nil {
+			return a.Decode(b)
+		}
+		return (&AvroCodec{}).Decode(b)
+	default:
+		return common.Message{}, fmt.Errorf("queue: unsupported envelope content type %q", contentType)
+	}
+}
+
+// IsBinary reports whether contentType's envelopes are binary, for
+// brokers whose bodies must be text (SQS).
+func IsBinary(contentType string) bool {
+	switch mediaType(contentType) {
+	case "", ContentTypeJSON:
+		return false
+	default:
+		return true
+	}
+}
+
+// mediaType strips parameters (charset, schema) from a content type.
+func mediaType(contentType string) string {
+	if contentType == "" {
+		return ""
+	}
+	mt, _, err := mime.ParseMediaType(contentType)
+	if err != nil {
+		return strings.ToLower(strings.TrimSpace(contentType))
+	}
+	return mt
+}
@@ -0,0 +1,295 @@
+package queue
+
+import (
+	"bytes"
+	"context"
+	"encoding/binary"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"reflect"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// AvroSchema is the envelope's Avro schema, also published as
+// api/queue/message.avsc for the Java side. Fields are only ever added,
+// with defaults, so older readers resolve newer writers.
+const AvroSchema = `{"type":"record","name":"Message","namespace":"flowcatalyst.queue.v1","fields":[` +
+	`{"name":"schemaVersion","type":"int","default":0},` +
+	`{"name":"id","type":"string"},` +
+	`{"name":"poolCode","type":"string","default":""},` +
+	`{"name":"authToken","type":["null","string"],"default":null},` +
+	`{"name":"signingSecret","type":["null","string"],"default":null},` +
+	`{"name":"mediationType","type":"string","default":"HTTP"},` +
+	`{"name":"mediationTarget","type":"string"},` +
+	`{"name":"messageGroupId","type":["null","string"],"default":null},` +
+	`{"name":"highPriority","type":"boolean","default":false},` +
+	`{"name":"dispatchMode","type":"string","default":""}]}`
+
+// avroMagic opens the Confluent wire format: magic byte, 4-byte
+// big-endian schema id, Avro binary body.
+const avroMagic = 0
+
+// AvroCodec writes the envelope as Avro binary in the Confluent wire
+// format. With a schema registry, the schema is registered under Subject
+// and writer schema ids on decode are looked up and must match
+// AvroSchema. Without one (SchemaID 0) frames are written with id 0 and
+// read as AvroSchema unchecked — fine when only FlowCatalyst writes the
+// queue.
+type AvroCodec struct {
+	// SchemaID frames encoded envelopes.
+	SchemaID uint32
+
+	registry *SchemaRegistry
+	mu       sync.Mutex
+	known    map[uint32]bool // writer schema id → matches AvroSchema
+}
+
+// NewAvroCodec registers AvroSchema under subject and returns a codec that
+// frames with the registry's id. A nil registry yields the unchecked codec.
+func NewAvroCodec(ctx context.Context, registry *SchemaRegistry, subject string) (*AvroCodec, error) {
+	if registry == nil {
+		return &AvroCodec{}, nil
+	}
+	id, err := registry.Register(ctx, subject, AvroSchema)
+	if err != nil {
+		return nil, err
+	}
+	return &AvroCodec{SchemaID: id, registry: registry, known: map[uint32]bool{id: true}}, nil
+}
+
+func (*AvroCodec) ContentType() string { return ContentTypeAvro }
+
+func (c *AvroCodec) Encode(m common.Message) ([]byte, error) {
+	v := m.SchemaVersion
+	if v == 0 {
+		v = common.MessageSchemaVersion
+	}
+	b := make([]byte, 5, 64+len(m.ID)+len(m.MediationTarget))
+	b[0] = avroMagic
+	binary.BigEndian.PutUint32(b[1:], c.SchemaID)
+	b = binary.AppendVarint(b, int64(v))
+	b = appendAvroString(b, m.ID)
+	b = appendAvroString(b, m.PoolCode)
+	b = appendAvroOptional(b, m.AuthToken)
+	b = appendAvroOptional(b, m.SigningSecret)
+	b = appendAvroString(b, string(m.MediationType))
+	b = appendAvroString(b, m.MediationTarget)
+	b = appendAvroOptional(b, m.MessageGroupID)
+	if m.HighPriority {
+		b = append(b, 1)
+	} else {
+		b = append(b, 0)
+	}
+	b = appendAvroString(b, string(m.DispatchMode))
+	return b, nil
+}
+
+func (c *AvroCodec) Decode(b []byte) (common.Message, error) {
+	if len(b) < 5 || b[0] != avroMagic {
+		return common.Message{}, errors.New("avro envelope: missing wire-format header")
+	}
+	if err := c.checkWriter(binary.BigEndian.Uint32(b[1:5])); err != nil {
+		return common.Message{}, err
+	}
+	r := avroReader{b: b[5:]}
+	var m common.Message
+	m.SchemaVersion = int(r.long())
+	m.ID = r.string()
+	m.PoolCode = r.string()
+	m.AuthToken = r.optional()
+	m.SigningSecret = r.optional()
+	m.MediationType = common.MediationType(r.string())
+	m.MediationTarget = r.string()
+	m.MessageGroupID = r.optional()
+	m.HighPriority = r.boolean()
+	m.DispatchMode = common.DispatchMode(r.string())
+	if r.err != nil {
+		return common.Message{}, fmt.Errorf("avro envelope: %w", r.err)
+	}
+	if err := m.Upgrade(); err != nil {
+		return common.Message{}, err
+	}
+	return m, nil
+}
+
+// checkWriter verifies, once per id, that the writer's schema is
+// AvroSchema. Without a registry every id is accepted.
+func (c *AvroCodec) checkWriter(id uint32) error {
+	if c.registry == nil {
+		return nil
+	}
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	if ok, seen := c.known[id]; seen {
+		if !ok {
+			return fmt.Errorf("avro envelope: writer schema %d is not the FlowCatalyst message schema", id)
+		}
+		return nil
+	}
+	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+	defer cancel()
+	schema, err := c.registry.Schema(ctx, id)
+	if err != nil {
+		return fmt.Errorf("avro envelope: %w", err)
+	}
+	c.known[id] = sameSchema(schema, AvroSchema)
+	if !c.known[id] {
+		return fmt.Errorf("avro envelope: writer schema %d is not the FlowCatalyst message schema", id)
+	}
+	return nil
+}
+
+// sameSchema compares two schemas as JSON, ignoring formatting.
+func sameSchema(a, b string) bool {
+	var av, bv any
+	if json.Unmarshal([]byte(a), &av) != nil || json.Unmarshal([]byte(b), &bv) != nil {
+		return false
+	}
+	return reflect.DeepEqual(av, bv)
+}
+
+func appendAvroString(b []byte, s string) []byte {
+	b = binary.AppendVarint(b, int64(len(s)))
+	return append(b, s...)
+}
+
+// appendAvroOptional writes a ["null","string"] union.
+func appendAvroOptional(b []byte, s *string) []byte {
+	if s == nil {
+		return binary.AppendVarint(b, 0)
+	}
+	b = binary.AppendVarint(b, 1)
+	return appendAvroString(b, *s)
+}
+
+// avroReader decodes Avro binary, keeping the first error.
+type avroReader struct {
+	b   []byte
+	err error
+}
+
+// long reads a zig-zag varint, Avro's int and long encoding (and Go's
+// binary.Varint).
+func (r *avroReader) long() int64 {
+	if r.err != nil {
+		return 0
+	}
+	v, n := binary.Varint(r.b)
+	if n <= 0 {
+		r.err = io.ErrUnexpectedEOF
+		return 0
+	}
+	r.b = r.b[n:]
+	return v
+}
+
+func (r *avroReader) string() string {
+	n := r.long()
+	if r.err != nil {
+		return ""
+	}
+	if n < 0 || n > int64(len(r.b)) {
+		r.err = io.ErrUnexpectedEOF
+		return ""
+	}
+	s := string(r.b[:n])
+	r.b = r.b[n:]
+	return s
+}
+
+func (r *avroReader) optional() *string {
+	switch r.long() {
+	case 0:
+		return nil
+	case 1:
+		s := r.string()
+		return &s
+	default:
+		if r.err == nil {
+			r.err = errors.New("bad union index")
+		}
+		return nil
+	}
+}
+
+func (r *avroReader) boolean() bool {
+	if r.err != nil {
+		return false
+	}
+	if len(r.b) == 0 {
+		r.err = io.ErrUnexpectedEOF
+		return false
+	}
+	v := r.b[0] != 0
+	r.b = r.b[1:]
+	return v
+}
+
+// SchemaRegistry is a minimal Confluent Schema Registry client: register
+// a schema, fetch one by id.
+type SchemaRegistry struct {
+	BaseURL string
+	Client  *http.Client
+}
+
+// NewSchemaRegistry returns a client for the registry at baseURL.
+func NewSchemaRegistry(baseURL string) *SchemaRegistry {
+	return &SchemaRegistry{
+		BaseURL: strings.TrimRight(baseURL, "/"),
+		Client:  &http.Client{Timeout: 10 * time.Second},
+	}
+}
+
+// Register adds schema under subject, returning its id. Registering a
+// schema the subject already has returns the existing id.
+func (r *SchemaRegistry) Register(ctx context.Context, subject, schema string) (uint32, error) {
+	body, _ := json.Marshal(map[string]string{"schema": schema})
+	var out struct {
+		ID uint32 `json:"id"`
+	}
+	path := "/subjects/" + url.PathEscape(subject) + "/versions"
+	if err := r.do(ctx, http.MethodPost, path, body, &out); err != nil {
+		return 0, fmt.Errorf("register schema under %s: %w", subject, err)
+	}
+	return out.ID, nil
+}
+
+// Schema fetches the schema with the given id.
+func (r *SchemaRegistry) Schema(ctx context.Context, id uint32) (string, error) {
+	var out struct {
+		Schema string `json:"schema"`
+	}
+	if err := r.do(ctx, http.MethodGet, fmt.Sprintf("/schemas/ids/%d", id), nil, &out); err != nil {
+		return "", fmt.Errorf("fetch schema %d: %w", id, err)
+	}
+	return out.Schema, nil
+}
+
+func (r *SchemaRegistry) do(ctx context.Context, method, path string, body []byte, out any) error {
+	req, err := http.NewRequestWithContext(ctx, method, r.BaseURL+path, bytes.NewReader(body))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Accept", "application/vnd.schemaregistry.v1+json")
+	if body != nil {
+		req.Header.Set("Content-Type", "application/vnd.schemaregistry.v1+json")
+	}
+	resp, err := r.Client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode/100 != 2 {
+		msg, _ := io.ReadAll(io.LimitReader(resp.Body, 512))
+		return fmt.Errorf("schema registry %s %s: %s: %s", method, path, resp.Status, strings.TrimSpace(string(msg)))
+	}
+	return json.NewDecoder(resp.Body).Decode(out)
+}
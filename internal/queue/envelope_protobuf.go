@@ -0,0 +1,131 @@
+package queue
+
+import (
+	"fmt"
+
+	"google.golang.org/protobuf/encoding/protowire"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Field numbers of flowcatalyst.queue.v1.Message (api/queue/message.proto).
+// Numbers are never reused; new fields take the next one.
+const (
+	pbSchemaVersion   protowire.Number = 1
+	pbID              protowire.Number = 2
+	pbPoolCode        protowire.Number = 3
+	pbAuthToken       protowire.Number = 4
+	pbSigningSecret   protowire.Number = 5
+	pbMediationType   protowire.Number = 6
+	pbMediationTarget protowire.Number = 7
+	pbMessageGroupID  protowire.Number = 8
+	pbHighPriority    protowire.Number = 9
+	pbDispatchMode    protowire.Number = 10
+)
+
+// ProtobufCodec writes the envelope as the proto3 message in
+// api/queue/message.proto, encoded by hand so the queue needs no
+// generated code. Unknown fields are skipped on decode.
+type ProtobufCodec struct{}
+
+func (ProtobufCodec) ContentType() string { return ContentTypeProtobuf }
+
+func (ProtobufCodec) Encode(m common.Message) ([]byte, error) {
+	v := m.SchemaVersion
+	if v == 0 {
+		v = common.MessageSchemaVersion
+	}
+	b := make([]byte, 0, 64+len(m.ID)+len(m.MediationTarget))
+	b = protowire.AppendTag(b, pbSchemaVersion, protowire.VarintType)
+	b = protowire.AppendVarint(b, uint64(v))
+	b = appendPBString(b, pbID, m.ID)
+	b = appendPBString(b, pbPoolCode, m.PoolCode)
+	b = appendPBOptional(b, pbAuthToken, m.AuthToken)
+	b = appendPBOptional(b, pbSigningSecret, m.SigningSecret)
+	b = appendPBString(b, pbMediationType, string(m.MediationType))
+	b = appendPBString(b, pbMediationTarget, m.MediationTarget)
+	b = appendPBOptional(b, pbMessageGroupID, m.MessageGroupID)
+	if m.HighPriority {
+		b = protowire.AppendTag(b, pbHighPriority, protowire.VarintType)
+		b = protowire.AppendVarint(b, 1)
+	}
+	b = appendPBString(b, pbDispatchMode, string(m.DispatchMode))
+	return b, nil
+}
+
+func (ProtobufCodec) Decode(b []byte) (common.Message, error) {
+	var m common.Message
+	for len(b) > 0 {
+		num, typ, n := protowire.ConsumeTag(b)
+		if n < 0 {
+			return common.Message{}, fmt.Errorf("protobuf envelope: %w", protowire.ParseError(n))
+		}
+		b = b[n:]
+		switch {
+		case num == pbSchemaVersion && typ == protowire.VarintType:
+			var v uint64
+			v, n = protowire.ConsumeVarint(b)
+			m.SchemaVersion = int(int32(v))
+		case num == pbHighPriority && typ == protowire.VarintType:
+			var v uint64
+			v, n = protowire.ConsumeVarint(b)
+			m.HighPriority = v != 0
+		case typ == protowire.BytesType && num >= pbID && num <= pbDispatchMode:
+			var s string
+			s, n = protowire.ConsumeString(b)
+			setPBString(&m, num, s)
+		default:
+			n = protowire.ConsumeFieldValue(num, typ, b)
+		}
+		if n < 0 {
+			return common.Message{}, fmt.Errorf("protobuf envelope field %d: %w", num, protowire.ParseError(n))
+		}
+		b = b[n:]
+	}
+	if err := m.Upgrade(); err != nil {
+		return common.Message{}, err
+	}
+	return m, nil
+}
+
+// setPBString sets string field num. A known number with the wrong wire
+// type lands in the default case and is dropped like an unknown field.
+func setPBString(m *common.Message, num protowire.Number, s string) {
+	switch num {
+	case pbID:
+		m.ID = s
+	case pbPoolCode:
+		m.PoolCode = s
+	case pbAuthToken:
+		m.AuthToken = &s
+	case pbSigningSecret:
+		m.SigningSecret = &s
+	case pbMediationType:
+		m.MediationType = common.MediationType(s)
+	case pbMediationTarget:
+		m.MediationTarget = s
+	case pbMessageGroupID:
+		m.MessageGroupID = &s
+	case pbDispatchMode:
+		m.DispatchMode = common.DispatchMode(s)
+	}
+}
+
+// appendPBString writes a proto3 string, omitting the default "".
+func appendPBString(b []byte, num protowire.Number, s string) []byte {
+	if s == "" {
+		return b
+	}
+	b = protowire.AppendTag(b, num, protowire.BytesType)
+	return protowire.AppendString(b, s)
+}
+
+// appendPBOptional writes a proto3 optional string: present, even when
+// empty, whenever the pointer is set.
+func appendPBOptional(b []byte, num protowire.Number, s *string) []byte {
+	if s == nil {
+		return b
+	}
+	b = protowire.AppendTag(b, num, protowire.BytesType)
+	return protowire.AppendString(b, *s)
+}
@@ -0,0 +1,214 @@
+package queue_test
+
+import (
+	"context"
+	"encoding/binary"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+// goldenMessage is the envelope in testdata/envelope_v1.*. The binary
+// fixtures were encoded independently of this package; the Java side
+// checks against the same files.
+func goldenMessage() common.Message {
+	token, group := "bearer-token", "group-1"
+	return common.Message{
+		SchemaVersion:   1,
+		ID:              "0HZXEQ5Y8JY62",
+		PoolCode:        "pool-a",
+		AuthToken:       &token,
+		MediationType:   common.MediationTypeHTTP,
+		MediationTarget: "https://example.com/webhook",
+		MessageGroupID:  &group,
+		HighPriority:    true,
+		DispatchMode:    common.DispatchNextOnError,
+	}
+}
+
+func readFixture(t *testing.T, name string) []byte {
+	t.Helper()
+	b, err := os.ReadFile(filepath.Join("testdata", name))
+	require.NoError(t, err)
+	return b
+}
+
+func TestEnvelopeCodecsMatchGoldenFixtures(t *testing.T) {
+	want := goldenMessage()
+	for _, tc := range []struct {
+		codec   queue.Codec
+		fixture string
+	}{
+		{queue.ProtobufCodec{}, "envelope_v1.pb"},
+		{&queue.AvroCodec{SchemaID: 1}, "envelope_v1.avro"},
+	} {
+		golden := readFixture(t, tc.fixture)
+		got, err := tc.codec.Encode(want)
+		require.NoError(t, err)
+		assert.Equal(t, golden, got, tc.fixture)
+
+		back, err := queue.DecodeMessage(tc.codec.ContentType(), golden)
+		require.NoError(t, err)
+		assert.Equal(t, want, back, tc.fixture)
+	}
+
+	back, err := queue.DecodeMessage("", readFixture(t, "envelope_v1.json"))
+	require.NoError(t, err)
+	assert.Equal(t, want, back, "no content type is JSON")
+}
+
+func TestProtobufEnvelopeSkipsUnknownFields(t *testing.T) {
+	m, err := queue.DecodeMessage(queue.ContentTypeProtobuf, readFixture(t, "envelope_v2_future.pb"))
+	require.NoError(t, err)
+	assert.Equal(t, common.Message{
+		SchemaVersion:   2,
+		ID:              "0HZXEQ5Y8JY63",
+		MediationType:   common.MediationTypeHTTP,
+		MediationTarget: "https://example.com/webhook",
+	}, m)
+}
+
+func TestEnvelopeUpgradesUnversionedBinary(t *testing.T) {
+	// A producer that sets neither schemaVersion nor mediationType.
+	const target = "https://example.com/in"
+	pb := append([]byte{0x12, 2, 'm', '1', 0x3a, byte(len(target))}, target...)
+	avro := []byte{0, 0, 0, 0, 0}
+	avro = binary.AppendVarint(avro, 0) // schemaVersion
+	avro = appendAvroString(avro, "m1")
+	avro = appendAvroString(avro, "")
+	avro = append(avro, 0, 0) // authToken, signingSecret: null
+	avro = appendAvroString(avro, "")
+	avro = appendAvroString(avro, target)
+	avro = append(avro, 0, 0) // messageGroupId: null; highPriority: false
+	avro = appendAvroString(avro, "")
+
+	for ct, b := range map[string][]byte{queue.ContentTypeProtobuf: pb, queue.ContentTypeAvro: avro} {
+		m, err := queue.DecodeMessage(ct, b)
+		require.NoError(t, err, ct)
+		assert.Equal(t, common.Message{
+			SchemaVersion:   common.MessageSchemaVersion,
+			ID:              "m1",
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: target,
+		}, m, ct)
+	}
+
+	_, err := queue.DecodeMessage("application/xml", []byte("<m/>"))
+	assert.Error(t, err)
+	_, err = queue.DecodeMessage(queue.ContentTypeAvro, avro[:len(avro)-3])
+	assert.Error(t, err, "truncated body")
+}
+
+func appendAvroString(b []byte, s string) []byte {
+	return append(binary.AppendVarint(b, int64(len(s))), s...)
+}
+
+func TestAvroSchemaMatchesPublishedSchema(t *testing.T) {
+	published, err := os.ReadFile(filepath.Join("..", "..", "api", "queue", "message.avsc"))
+	require.NoError(t, err)
+	assert.JSONEq(t, queue.AvroSchema, string(published))
+}
+
+func TestAvroCodecChecksWriterSchemaWithRegistry(t *testing.T) {
+	reg := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/subjects/fc-message/versions":
+			var body struct{ Schema string }
+			_ = json.NewDecoder(r.Body).Decode(&body)
+			assert.JSONEq(t, queue.AvroSchema, body.Schema)
+			_, _ = w.Write([]byte(`{"id":7}`))
+		case "/schemas/ids/8":
+			// The same schema, registered under another subject.
+			_ = json.NewEncoder(w).Encode(map[string]string{"schema": queue.AvroSchema})
+		case "/schemas/ids/9":
+			_, _ = w.Write([]byte(`{"schema":"{\"type\":\"string\"}"}`))
+		default:
+			http.NotFound(w, r)
+		}
+	}))
+	defer reg.Close()
+
+	codec, err := queue.NewAvroCodec(context.Background(), queue.NewSchemaRegistry(reg.URL), "fc-message")
+	require.NoError(t, err)
+	assert.Equal(t, uint32(7), codec.SchemaID)
+
+	b, err := codec.Encode(goldenMessage())
+	require.NoError(t, err)
+	_, err = codec.Decode(b)
+	require.NoError(t, err)
+
+	binary.BigEndian.PutUint32(b[1:5], 8)
+	_, err = codec.Decode(b)
+	assert.NoError(t, err)
+	binary.BigEndian.PutUint32(b[1:5], 9)
+	_, err = codec.Decode(b)
+	assert.ErrorContains(t, err, "writer schema 9")
+
+	_, err = queue.NewAvroCodec(context.Background(), queue.NewSchemaRegistry(reg.URL), "unknown")
+	assert.Error(t, err)
+}
+
+func TestSetEncodingSelectsPublishCodec(t *testing.T) {
+	t.Cleanup(func() { queue.SetEncoding(queue.JSONCodec{}) })
+	assert.Equal(t, queue.ContentTypeJSON, queue.Encoding().ContentType())
+
+	queue.SetEncoding(queue.ProtobufCodec{})
+	b, ct, err := queue.EncodeMessage(goldenMessage())
+	require.NoError(t, err)
+	assert.Equal(t, queue.ContentTypeProtobuf, ct)
+	assert.True(t, queue.IsBinary(ct))
+	assert.False(t, queue.IsBinary("application/json; charset=utf-8"))
+	m, err := queue.DecodeMessage(ct, b)
+	require.NoError(t, err)
+	assert.Equal(t, goldenMessage(), m)
+}
+
+// The benchmarks report the golden envelope's encoded size (bytes/msg) so
+// the encodings compare on size as well as CPU:
+//
+//	go test ./internal/queue -run '^$' -bench Envelope -benchmem
+func BenchmarkEnvelopeEncode(b *testing.B) {
+	for _, c := range benchCodecs() {
+		b.Run(c.ContentType(), func(b *testing.B) {
+			m := goldenMessage()
+			var size int
+			for b.Loop() {
+				out, err := c.Encode(m)
+				if err != nil {
+					b.Fatal(err)
+				}
+				size = len(out)
+			}
+			b.ReportMetric(float64(size), "bytes/msg")
+		})
+	}
+}
+
+func BenchmarkEnvelopeDecode(b *testing.B) {
+	for _, c := range benchCodecs() {
+		b.Run(c.ContentType(), func(b *testing.B) {
+			in, err := c.Encode(goldenMessage())
+			if err != nil {
+				b.Fatal(err)
+			}
+			for b.Loop() {
+				if _, err := c.Decode(in); err != nil {
+					b.Fatal(err)
+				}
+			}
+		})
+	}
+}
+
+func benchCodecs() []queue.Codec {
+	return []queue.Codec{queue.JSONCodec{}, queue.ProtobufCodec{}, &queue.AvroCodec{}}
+}
@@ -20,7 +20,6 @@ package nats
 
 import (
 	"context"
-	"encoding/json"
 	"errors"
 	"fmt"
 	"net/url"
@@ -267,9 +266,15 @@ func (q *Queue) Poll(ctx context.Context, max uint32) ([]common.QueuedMessage, e
 			_ = msg.Term()
 			continue
 		}
+		if wait := queue.NotBefore(msg.Headers().Get(queue.DeliverAtAttribute)); wait > 0 {
+			// Scheduled: JetStream has no publish delay, so hold it back
+			// with a delayed NAK. Costs one delivery against MaxDeliver.
+			_ = msg.NakWithDelay(wait)
+			continue
+		}
 		receipt := fmt.Sprintf("%s:%d", q.cfg.StreamName, meta.Sequence.Stream)
-		var m common.Message
-		if err := json.Unmarshal(msg.Data(), &m); err != nil {
+		m, err := queue.DecodeMessage(msg.Headers().Get(queue.ContentTypeAttribute), msg.Data())
+		if err != nil {
 			_ = msg.Term() // malformed
 			continue
 		}
@@ -403,14 +408,28 @@ func (q *Queue) Counters() *queue.Metrics {
 	}
 }
 
-// Publish marshals m to JSON and publishes to the configured subject.
-// The returned id is the JetStream stream sequence.
+// Publish encodes m with the configured envelope codec, tagged with its
+// content-type header, and publishes to the configured subject. The
+// returned id is the JetStream stream sequence.
 func (q *Queue) Publish(ctx context.Context, m common.Message) (string, error) {
-	body, err := json.Marshal(m)
+	return q.PublishAt(ctx, m, time.Time{})
+}
+
+// PublishAt publishes m with a queue.DeliverAtAttribute header when
+// deliverAt is in the future; Poll NAKs an early arrival with the
+// remaining delay.
+func (q *Queue) PublishAt(ctx context.Context, m common.Message, deliverAt time.Time) (string, error) {
+	body, contentType, err := queue.EncodeMessage(m)
 	if err != nil {
 		return "", fmt.Errorf("nats: marshal: %w", err)
 	}
-	ack, err := q.js.Publish(ctx, subjectFor(q.cfg.Subject, m), body)
+	msg := natsgo.NewMsg(subjectFor(q.cfg.Subject, m))
+	msg.Data = body
+	msg.Header.Set(queue.ContentTypeAttribute, contentType)
+	if time.Now().Before(deliverAt) {
+		msg.Header.Set(queue.DeliverAtAttribute, strconv.FormatInt(deliverAt.Unix(), 10))
+	}
+	ack, err := q.js.PublishMsg(ctx, msg)
 	if err != nil {
 		return "", fmt.Errorf("nats: publish: %w", err)
 	}
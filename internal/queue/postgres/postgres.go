@@ -227,7 +227,15 @@ func (q *Queue) ExtendVisibility(ctx context.Context, receipt string, seconds ui
 
 // Publish writes a single message. Uses ON CONFLICT DO NOTHING so a
 // duplicate id is a no-op (matches Rust at-least-once publish semantics).
+// The payload is always JSON, whatever queue.SetEncoding chose: the
+// column is TEXT and carries no content type.
 func (q *Queue) Publish(ctx context.Context, m common.Message) (string, error) {
+	return q.PublishAt(ctx, m, time.Now())
+}
+
+// PublishAt writes a message with visible_at = deliverAt, so Poll does
+// not claim it before then.
+func (q *Queue) PublishAt(ctx context.Context, m common.Message, deliverAt time.Time) (string, error) {
 	payload, err := json.Marshal(m)
 	if err != nil {
 		return "", err
@@ -238,7 +246,7 @@ func (q *Queue) Publish(ctx context.Context, m common.Message) (string, error) {
 		     (id, queue_name, message_group_id, visible_at, payload, created_at)
 		 VALUES ($1, $2, $3, $4, $5, $6)
 		 ON CONFLICT (queue_name, id) DO NOTHING`,
-		m.ID, q.cfg.Name, m.MessageGroupID, now, string(payload), now)
+		m.ID, q.cfg.Name, m.MessageGroupID, max(deliverAt.Unix(), now), string(payload), now)
 	return m.ID, err
 }
 
@@ -16,8 +16,10 @@ import (
 	"errors"
 	"fmt"
 	neturl "net/url"
+	"strconv"
 	"strings"
 	"sync"
+	"time"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
 )
@@ -75,6 +77,33 @@ type Publisher interface {
 	PublishBatch(ctx context.Context, msgs []common.Message) ([]string, error)
 }
 
+// ScheduledPublisher is implemented by publishers that can hold a message
+// back until a future time. Publish is PublishAt with deliverAt = now.
+type ScheduledPublisher interface {
+	Publisher
+	// PublishAt publishes m so that it is not delivered before deliverAt.
+	PublishAt(ctx context.Context, m common.Message, deliverAt time.Time) (string, error)
+}
+
+// DeliverAtAttribute carries a scheduled message's delivery time (unix
+// seconds) for backends whose native delay is shorter than the schedule
+// (SQS caps DelaySeconds at 15 minutes, NATS has none). The consumer
+// re-defers a message that arrives early until the time is reached.
+const DeliverAtAttribute = "fcDeliverAt"
+
+// NotBefore parses a DeliverAtAttribute value and returns how long is left
+// until it. Zero for a missing, malformed or past value.
+func NotBefore(v string) time.Duration {
+	if v == "" {
+		return 0
+	}
+	secs, err := strconv.ParseInt(v, 10, 64)
+	if err != nil {
+		return 0
+	}
+	return max(time.Until(time.Unix(secs, 0)), 0)
+}
+
 // Embedded combines Consumer + Publisher for in-process queue backends.
 type Embedded interface {
 	Consumer
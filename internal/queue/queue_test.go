@@ -2,7 +2,9 @@ package queue_test
 
 import (
 	"context"
+	"strconv"
 	"testing"
+	"time"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -49,3 +51,11 @@ func TestSQSHTTPSURLRoutesToSQSBackend(t *testing.T) {
 	require.Error(t, err)
 	assert.Contains(t, err.Error(), `scheme "https"`)
 }
+
+func TestNotBefore(t *testing.T) {
+	assert.Zero(t, queue.NotBefore(""))
+	assert.Zero(t, queue.NotBefore("soon"))
+	assert.Zero(t, queue.NotBefore(strconv.FormatInt(time.Now().Add(-time.Hour).Unix(), 10)))
+	wait := queue.NotBefore(strconv.FormatInt(time.Now().Add(time.Hour).Unix(), 10))
+	assert.InDelta(t, time.Hour.Seconds(), wait.Seconds(), 2)
+}
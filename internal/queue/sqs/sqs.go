@@ -11,9 +11,11 @@ package sqs
 
 import (
 	"context"
-	"encoding/json"
+	"encoding/base64"
 	"errors"
 	"fmt"
+	"log/slog"
+	"math"
 	neturl "net/url"
 	"strconv"
 	"strings"
@@ -35,7 +37,8 @@ import (
 // short-circuited to DeleteMessage. Matches Rust 15 minutes.
 const PendingDeleteTTL = 15 * time.Minute
 
-// DefaultWaitSeconds is the long-poll wait time. AWS max is 20s.
+// DefaultWaitSeconds is the long-poll wait time, and the most a queue's
+// WaitSeconds may ask for. AWS max is 20s.
 const DefaultWaitSeconds = 20
 
 func init() {
@@ -82,12 +85,16 @@ func build(ctx context.Context, cfg common.QueueConfig) (*Queue, error) {
 	if vt == 0 {
 		vt = 30
 	}
+	wait := int32(DefaultWaitSeconds)
+	if cfg.WaitSeconds > 0 && cfg.WaitSeconds < DefaultWaitSeconds {
+		wait = int32(cfg.WaitSeconds)
+	}
 	q := &Queue{
 		client:             client,
 		queueURL:           cfg.URI,
 		queueName:          queueName,
 		visibilityTimeout:  int32(vt),
-		waitSeconds:        DefaultWaitSeconds,
+		waitSeconds:        wait,
 		pendingDelete:      make(map[string]time.Time),
 		receiptToMessageID: make(map[string]receiptMapping),
 	}
@@ -190,6 +197,15 @@ func (q *Queue) Poll(ctx context.Context, maxMessages uint32) ([]common.QueuedMe
 			}
 		}
 
+		if wait := notBefore(sm); wait > 0 && sm.ReceiptHandle != nil {
+			// Scheduled past SQS's native delay: hide it again until due.
+			secs := uint32(min(math.Ceil(wait.Seconds()), maxVisibilitySeconds))
+			if err := q.changeVisibility(ctx, *sm.ReceiptHandle, &secs); err != nil {
+				slog.Warn("sqs re-defer of scheduled message failed", "queue", q.queueName, "err", err)
+			}
+			continue
+		}
+
 		msg, receipt, brokerID, perr := q.parseMessage(sm)
 		if perr != nil {
 			// Malformed — ACK it so it doesn't keep coming back.
@@ -219,8 +235,21 @@ func (q *Queue) parseMessage(sm sqstypes.Message) (common.Message, string, strin
 	if sm.Body == nil {
 		return common.Message{}, "", "", errors.New("empty body")
 	}
-	var m common.Message
-	if err := json.Unmarshal([]byte(*sm.Body), &m); err != nil {
+	contentType := ""
+	if a, ok := sm.MessageAttributes[queue.ContentTypeAttribute]; ok && a.StringValue != nil {
+		contentType = *a.StringValue
+	}
+	body := []byte(*sm.Body)
+	if queue.IsBinary(contentType) {
+		// SQS bodies are text; binary envelopes travel base64-encoded.
+		raw, err := base64.StdEncoding.DecodeString(*sm.Body)
+		if err != nil {
+			return common.Message{}, "", "", fmt.Errorf("decode %s body: %w", contentType, err)
+		}
+		body = raw
+	}
+	m, err := queue.DecodeMessage(contentType, body)
+	if err != nil {
 		return common.Message{}, "", "", fmt.Errorf("unmarshal: %w", err)
 	}
 	if sm.ReceiptHandle == nil {
@@ -299,17 +328,40 @@ func (q *Queue) changeVisibility(ctx context.Context, receipt string, delaySecon
 
 // Publish sends a single message via SendMessage.
 func (q *Queue) Publish(ctx context.Context, m common.Message) (string, error) {
-	body, err := json.Marshal(m)
+	return q.PublishAt(ctx, m, time.Time{})
+}
+
+// PublishAt sends a message that is not delivered before deliverAt. Up to
+// 15 minutes out on a standard queue this is SQS's own DelaySeconds. FIFO
+// queues have no per-message delay, and longer schedules exceed it, so
+// those carry queue.DeliverAtAttribute and Poll hides an early arrival
+// again (up to 12h at a time) until it is due.
+func (q *Queue) PublishAt(ctx context.Context, m common.Message, deliverAt time.Time) (string, error) {
+	body, attrs, err := encodeBody(m)
 	if err != nil {
 		return "", err
 	}
 	in := &sqs.SendMessageInput{
-		QueueUrl:    aws.String(q.queueURL),
-		MessageBody: aws.String(string(body)),
+		QueueUrl:          aws.String(q.queueURL),
+		MessageBody:       aws.String(body),
+		MessageAttributes: attrs,
 	}
 	if m.MessageGroupID != nil {
 		in.MessageGroupId = aws.String(*m.MessageGroupID)
 	}
+	if wait := time.Until(deliverAt); wait > 0 {
+		fifo := strings.HasSuffix(q.queueURL, ".fifo")
+		secs := math.Ceil(wait.Seconds())
+		if !fifo {
+			in.DelaySeconds = int32(min(secs, maxDelaySeconds))
+		}
+		if fifo || secs > maxDelaySeconds {
+			attrs[queue.DeliverAtAttribute] = sqstypes.MessageAttributeValue{
+				DataType:    aws.String("Number"),
+				StringValue: aws.String(strconv.FormatInt(deliverAt.Unix(), 10)),
+			}
+		}
+	}
 	out, err := q.client.SendMessage(ctx, in)
 	if err != nil {
 		return "", fmt.Errorf("sqs SendMessage: %w", err)
@@ -320,6 +372,21 @@ func (q *Queue) Publish(ctx context.Context, m common.Message) (string, error) {
 	return *out.MessageId, nil
 }
 
+// SQS limits: DelaySeconds (per message) and VisibilityTimeout.
+const (
+	maxDelaySeconds      = 900
+	maxVisibilitySeconds = 43200
+)
+
+// notBefore returns how long is left until a scheduled message is due.
+func notBefore(sm sqstypes.Message) time.Duration {
+	a, ok := sm.MessageAttributes[queue.DeliverAtAttribute]
+	if !ok || a.StringValue == nil {
+		return 0
+	}
+	return queue.NotBefore(*a.StringValue)
+}
+
 // PublishBatch sends in batches of 10 (SQS hard limit).
 func (q *Queue) PublishBatch(ctx context.Context, msgs []common.Message) ([]string, error) {
 	ids := make([]string, 0, len(msgs))
@@ -330,13 +397,14 @@ func (q *Queue) PublishBatch(ctx context.Context, msgs []common.Message) ([]stri
 		}
 		entries := make([]sqstypes.SendMessageBatchRequestEntry, 0, end-start)
 		for i := start; i < end; i++ {
-			body, err := json.Marshal(msgs[i])
+			body, attrs, err := encodeBody(msgs[i])
 			if err != nil {
 				return ids, err
 			}
 			e := sqstypes.SendMessageBatchRequestEntry{
-				Id:          aws.String(strconv.Itoa(i)),
-				MessageBody: aws.String(string(body)),
+				Id:                aws.String(strconv.Itoa(i)),
+				MessageBody:       aws.String(body),
+				MessageAttributes: attrs,
 			}
 			if msgs[i].MessageGroupID != nil {
 				e.MessageGroupId = aws.String(*msgs[i].MessageGroupID)
@@ -362,6 +430,23 @@ func (q *Queue) PublishBatch(ctx context.Context, msgs []common.Message) ([]stri
 	return ids, nil
 }
 
+// encodeBody serializes m with the configured envelope codec, base64-ing
+// binary envelopes, and returns the content-type attribute consumers
+// negotiate on.
+func encodeBody(m common.Message) (string, map[string]sqstypes.MessageAttributeValue, error) {
+	b, contentType, err := queue.EncodeMessage(m)
+	if err != nil {
+		return "", nil, err
+	}
+	body := string(b)
+	if queue.IsBinary(contentType) {
+		body = base64.StdEncoding.EncodeToString(b)
+	}
+	return body, map[string]sqstypes.MessageAttributeValue{
+		queue.ContentTypeAttribute: {DataType: aws.String("String"), StringValue: aws.String(contentType)},
+	}, nil
+}
+
 // Healthy reports running state.
 func (q *Queue) Healthy() bool { return q.running.Load() }
 
@@ -0,0 +1 @@
+0HZXEQ5Y8JY62pool-a"bearer-token2HTTP:https://example.com/webhookBgroup-1HRNEXT_ON_ERROR
\ No newline at end of file
@@ -0,0 +1 @@
+0HZXEQ5Y8JY632HTTP:https://example.com/webhookXb700-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01
\ No newline at end of file
@@ -0,0 +1,85 @@
+package redisconn
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/redis/go-redis/v9"
+)
+
+func TestParseURL(t *testing.T) {
+	cases := []struct {
+		url  string
+		want Config
+	}{
+		{"redis://127.0.0.1:6379", Config{Mode: ModeSingle, Addrs: []string{"127.0.0.1:6379"}}},
+		{"redis://:secret@cache/3", Config{Mode: ModeSingle, Addrs: []string{"cache:6379"}, DB: 3, Password: "secret"}},
+		{
+			"redis+sentinel://app:pw@s1,s2:26380,[::1]/mymaster/2?sentinel_password=spw",
+			Config{
+				Mode: ModeSentinel, Addrs: []string{"s1:26379", "s2:26380", "[::1]:26379"},
+				Master: "mymaster", DB: 2, Username: "app", Password: "pw", SentinelPassword: "spw",
+			},
+		},
+		{"redis+cluster://n1:7000,n2:7001", Config{Mode: ModeCluster, Addrs: []string{"n1:7000", "n2:7001"}}},
+	}
+	for _, c := range cases {
+		got, err := ParseURL(c.url)
+		if err != nil {
+			t.Errorf("ParseURL(%q): %v", c.url, err)
+			continue
+		}
+		if !reflect.DeepEqual(got, c.want) {
+			t.Errorf("ParseURL(%q) = %+v, want %+v", c.url, got, c.want)
+		}
+	}
+}
+
+func TestParseURLTLS(t *testing.T) {
+	cfg, err := ParseURL("rediss+cluster://n1:7000?tls_server_name=redis.internal&tls_insecure_skip_verify=true")
+	if err != nil {
+		t.Fatalf("ParseURL: %v", err)
+	}
+	if cfg.TLS == nil || cfg.TLS.ServerName != "redis.internal" || !cfg.TLS.InsecureSkipVerify {
+		t.Errorf("TLS = %+v", cfg.TLS)
+	}
+	if cfg, _ := ParseURL("rediss://cache:6380"); cfg.TLS == nil || cfg.TLS.InsecureSkipVerify {
+		t.Errorf("rediss TLS = %+v", cfg.TLS)
+	}
+}
+
+func TestParseURLRejects(t *testing.T) {
+	for _, u := range []string{
+		"cache:6379",
+		"http://cache",
+		"redis+ring://a,b",
+		"redis://a,b",
+		"redis+sentinel://s1",
+		"redis+cluster://n1/0",
+		"redis://cache/x",
+		"redis://cache?tls_ca_file=/ca.pem",
+		"redis+cluster://n1?sentinel_password=x",
+		"rediss://cache?tls_ca_file=/does/not/exist.pem",
+	} {
+		if _, err := ParseURL(u); err == nil {
+			t.Errorf("ParseURL(%q) accepted", u)
+		}
+	}
+}
+
+func TestNewClientTopology(t *testing.T) {
+	for url, want := range map[string]any{
+		"redis://cache":                 (*redis.Client)(nil),
+		"redis+sentinel://s1/mymaster":  (*redis.Client)(nil),
+		"redis+cluster://n1:7000,n2:70": (*redis.ClusterClient)(nil),
+	} {
+		c, err := Open(url)
+		if err != nil {
+			t.Fatalf("Open(%q): %v", url, err)
+		}
+		if reflect.TypeOf(c) != reflect.TypeOf(want) {
+			t.Errorf("Open(%q) = %T, want %T", url, c, want)
+		}
+		_ = c.Close()
+	}
+}
@@ -11,6 +11,7 @@
 //	api := humachi.New(r, huma.DefaultConfig("FlowCatalyst Router API", "dev"))
 //	routerapi.Register(api, routerapi.FromServer(srv))
 //	routerapi.MountDashboard(r) // HTML — not a huma operation
+//	routerapi.MountWarningStream(r, state) // SSE — not a huma operation
 package api
 
 import (
@@ -19,6 +20,7 @@ import (
 
 	"github.com/danielgtaylor/huma/v2"
 	"github.com/go-chi/chi/v5"
+	"golang.org/x/time/rate"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
@@ -80,10 +82,21 @@ type PoolUpdater interface {
 	UpdatePool(code string, concurrency uint32, rateLimitPerMinute *uint32, setRateLimit bool) bool
 }
 
-// PublisherProvider returns the publisher bound to a pool's queue.
-// Used by POST /messages.
+// PublisherProvider returns the publisher bound to a pool's queue, or to
+// a configured queue by name. Used by POST /messages.
 type PublisherProvider interface {
-	Publisher(ctx context.Context, poolCode string) (queue.Publisher, error)
+	Publisher(ctx context.Context, key string) (queue.Publisher, error)
+	// HasQueue reports whether a queue with this name is configured.
+	HasQueue(name string) bool
+}
+
+// RoutingRuleStore evaluates and edits the content-based routing rules.
+// Used by POST /messages when a message has no pool code, and by
+// /api/admin/routing-rules.
+type RoutingRuleStore interface {
+	Route(in router.RoutingInput) (common.RoutingRule, bool)
+	RoutingRules() []common.RoutingRule
+	SetRoutingRules(rules []common.RoutingRule) error
 }
 
 // LeaderInfo reports leadership / standby state.
@@ -106,6 +119,26 @@ type TrafficStatusProvider interface {
 	Status() router.TrafficStatus
 }
 
+// ConsumerPollStatsProvider exposes per-queue poll counters and idle
+// backoff. Optional — when nil no fc_consumer_polls_total series are
+// exported.
+type ConsumerPollStatsProvider interface {
+	ConsumerPollStats() []router.ConsumerPollStats
+}
+
+// AttemptSinkStatsProvider exposes the delivery-attempt sink's counters.
+// Optional — when nil no fc_attempt_sink_* series are exported.
+type AttemptSinkStatsProvider interface {
+	Stats() router.AttemptSinkStats
+}
+
+// InfrastructureProvider reports connectivity to the external stores the
+// process depends on (standby Redis, outbox Mongo). Optional — when nil
+// /monitoring/infrastructure reports no components.
+type InfrastructureProvider interface {
+	Infrastructure(ctx context.Context) router.InfrastructureHealth
+}
+
 // StreamHealth is the projection-level snapshot consumed by the stream
 // health endpoints. Kept package-local so api callers don't need to
 // import internal/stream — fc-server adapts its stream.HealthService
@@ -145,20 +178,34 @@ type StreamHealthProvider interface {
 // Warnings/Health is optional; handlers gracefully degrade when a
 // provider is nil (return 503 or an empty payload, matching Rust).
 type State struct {
-	Warnings     *router.WarningService
-	Health       *router.HealthService
-	PoolStats    PoolStatsProvider
-	OpenCount    CircuitBreakerOpenCounter
-	Breakers     BreakerSnapshotProvider
-	InFlight     InFlightSnapshotProvider
-	Mediating    MediatingProvider
-	BrokerStats  BrokerStatsProvider
-	PoolUpdater  PoolUpdater
-	Publisher    PublisherProvider
-	Leader       LeaderInfo
-	Reloader     ConfigReloader
-	Traffic      TrafficStatusProvider
-	StreamHealth StreamHealthProvider
+	Warnings       *router.WarningService
+	Health         *router.HealthService
+	PoolStats      PoolStatsProvider
+	OpenCount      CircuitBreakerOpenCounter
+	Breakers       BreakerSnapshotProvider
+	InFlight       InFlightSnapshotProvider
+	Mediating      MediatingProvider
+	BrokerStats    BrokerStatsProvider
+	PoolUpdater    PoolUpdater
+	Publisher      PublisherProvider
+	Routing        RoutingRuleStore
+	Leader         LeaderInfo
+	Reloader       ConfigReloader
+	ConfigHistory  router.ConfigHistory
+	Traffic        TrafficStatusProvider
+	StreamHealth   StreamHealthProvider
+	AttemptSink    AttemptSinkStatsProvider
+	Infrastructure InfrastructureProvider
+	ConsumerPolls  ConsumerPollStatsProvider
+	// Scale sets the messages-per-replica targets behind
+	// /scale/recommendation and fc_scale_desired_replicas.
+	Scale ScaleConfig
+	// PublishLimit caps messages accepted per second across POST
+	// /messages and /messages/batch; nil is unlimited.
+	PublishLimit *rate.Limiter
+	// published counts publish outcomes per queue for
+	// fc_messages_published_total.
+	published publishCounters
 
 	// Mocks is the counter set for /api/test/*. Created automatically by
 	// FromServer; tests can substitute their own.
@@ -167,22 +214,34 @@ type State struct {
 
 // FromServer builds a fully-populated State from a *router.Server.
 func FromServer(s *router.Server) *State {
-	return &State{
-		Warnings:    s.Warnings,
-		Health:      s.Health,
-		PoolStats:   managerPoolStatsAdapter{m: s.Manager},
-		OpenCount:   breakersAdapter{breakers: s.Breakers},
-		Breakers:    breakerSnapshotAdapter{breakers: s.Breakers},
-		InFlight:    inFlightAdapter{tracker: s.Tracker},
-		Mediating:   managerMediatingAdapter{m: s.Manager},
-		BrokerStats: brokerStatsAdapter{cache: s.BrokerStats},
-		PoolUpdater: poolUpdaterAdapter{m: s.Manager},
-		Publisher:   publisherAdapter{m: s.Manager},
-		Leader:      leaderAdapter{s: s},
-		Reloader:    reloaderAdapter{s: s},
-		Traffic:     trafficAdapter{traffic: s.Traffic},
-		Mocks:       NewMockState(),
+	st := &State{
+		Warnings:       s.Warnings,
+		Health:         s.Health,
+		PoolStats:      managerPoolStatsAdapter{m: s.Manager},
+		OpenCount:      breakersAdapter{breakers: s.Breakers},
+		Breakers:       breakerSnapshotAdapter{breakers: s.Breakers},
+		InFlight:       inFlightAdapter{tracker: s.Tracker},
+		Mediating:      managerMediatingAdapter{m: s.Manager},
+		BrokerStats:    brokerStatsAdapter{cache: s.BrokerStats},
+		PoolUpdater:    poolUpdaterAdapter{m: s.Manager},
+		Publisher:      publisherAdapter{m: s.Manager},
+		Routing:        s.Manager,
+		Leader:         leaderAdapter{s: s},
+		Reloader:       reloaderAdapter{s: s},
+		Traffic:        trafficAdapter{traffic: s.Traffic},
+		Infrastructure: infrastructureAdapter{s: s},
+		Mocks:          NewMockState(),
 	}
+	if s.Manager != nil {
+		st.ConsumerPolls = s.Manager
+	}
+	if s.Attempts != nil {
+		st.AttemptSink = s.Attempts
+	}
+	if s.Cfg.ConfigHistory != nil {
+		st.ConfigHistory = s.Cfg.ConfigHistory
+	}
+	return st
 }
 
 type trafficAdapter struct{ traffic *router.TrafficStrategy }
@@ -194,6 +253,12 @@ func (a trafficAdapter) Status() router.TrafficStatus {
 	return a.traffic.Status()
 }
 
+type infrastructureAdapter struct{ s *router.Server }
+
+func (a infrastructureAdapter) Infrastructure(ctx context.Context) router.InfrastructureHealth {
+	return router.CheckInfrastructure(ctx, a.s.InfrastructureChecks())
+}
+
 // Register mounts every router endpoint on the supplied huma API.
 // Call MountDashboard separately on the underlying chi router to serve
 // the embedded HTML.
@@ -205,10 +270,13 @@ func Register(api huma.API, s *State) {
 	registerMonitoring(api, s)
 	registerDashboardReads(api, s)
 	registerWarnings(api, s)
+	registerIncidents(api, s)
 	registerMutations(api, s)
 	registerMessages(api, s)
+	registerRouting(api, s)
 	registerMocks(api, s)
 	registerMisc(api, s)
+	registerScale(api, s)
 }
 
 // MountDashboard registers the embedded HTML dashboard on the chi
@@ -329,6 +397,10 @@ func (a publisherAdapter) Publisher(ctx context.Context, code string) (queue.Pub
 	return a.m.Publisher(ctx, code)
 }
 
+func (a publisherAdapter) HasQueue(name string) bool {
+	return a.m != nil && a.m.HasQueue(name)
+}
+
 type reloaderAdapter struct{ s *router.Server }
 
 func (a reloaderAdapter) Reload(ctx context.Context) error {
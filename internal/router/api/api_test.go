@@ -3,6 +3,7 @@ package api_test
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"net/http"
 	"net/http/httptest"
 	"strings"
@@ -11,6 +12,7 @@ import (
 
 	"github.com/danielgtaylor/huma/v2/humatest"
 	"github.com/go-chi/chi/v5"
+	"golang.org/x/time/rate"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
@@ -72,10 +74,11 @@ func (s *stubPoolUpdater) UpdatePool(code string, concurrency uint32, rate *uint
 }
 
 type stubPublisher struct {
-	identifier string
-	lastMsg    common.Message
-	brokerID   string
-	publishErr error
+	identifier    string
+	lastMsg       common.Message
+	lastDeliverAt time.Time
+	brokerID      string
+	publishErr    error
 }
 
 func (s *stubPublisher) Identifier() string { return s.identifier }
@@ -87,6 +90,11 @@ func (s *stubPublisher) Publish(_ context.Context, m common.Message) (string, er
 	return s.brokerID, nil
 }
 
+func (s *stubPublisher) PublishAt(ctx context.Context, m common.Message, at time.Time) (string, error) {
+	s.lastDeliverAt = at
+	return s.Publish(ctx, m)
+}
+
 func (s *stubPublisher) PublishBatch(_ context.Context, msgs []common.Message) ([]string, error) {
 	if s.publishErr != nil {
 		return nil, s.publishErr
@@ -104,6 +112,24 @@ func (s stubPublisherProvider) Publisher(_ context.Context, _ string) (queue.Pub
 	return s.pub, nil
 }
 
+func (s stubPublisherProvider) HasQueue(string) bool { return false }
+
+// stubQueuePublishers holds one publisher per configured queue name; any
+// other key resolves to the "" entry, like Manager's fallback.
+type stubQueuePublishers map[string]*stubPublisher
+
+func (s stubQueuePublishers) Publisher(_ context.Context, key string) (queue.Publisher, error) {
+	if p, ok := s[key]; ok {
+		return p, nil
+	}
+	return s[""], nil
+}
+
+func (s stubQueuePublishers) HasQueue(name string) bool {
+	_, ok := s[name]
+	return ok && name != ""
+}
+
 type stubLeader struct {
 	leader     bool
 	standby    bool
@@ -471,9 +497,212 @@ func TestPublishMessage(t *testing.T) {
 func TestPublishMessage_MissingPoolCode(t *testing.T) {
 	api, _, _, _, _, _ := setupAPI(t)
 	resp := api.Post("/messages", map[string]any{"mediation_target": "https://x.test"})
-	// huma schema-validates first: missing required field → 422.
-	if resp.Code != http.StatusUnprocessableEntity {
-		t.Errorf("status=%d want 422 body=%s", resp.Code, resp.Body.String())
+	// pool_code is optional on the wire (routing rules may supply it), so
+	// a message no rule matches fails validation → 400.
+	if resp.Code != http.StatusBadRequest {
+		t.Errorf("status=%d want 400 body=%s", resp.Code, resp.Body.String())
+	}
+}
+
+func TestPublishMessage_RoutingRules(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	pub := &stubPublisher{identifier: "q", brokerID: "b"}
+	_, api := humatest.New(t)
+	routerapi.Register(api, &routerapi.State{
+		Warnings:  ws,
+		Health:    router.NewHealthService(router.DefaultHealthServiceConfig(), ws),
+		Publisher: stubPublisherProvider{pub: pub},
+		Routing:   router.NewManager(nil, nil),
+	})
+
+	resp := api.Put("/api/admin/routing-rules/orders",
+		map[string]any{"eventType": "order.*", "attributes": map[string]string{"region": "eu"}, "poolCode": "ORDERS"})
+	if resp.Code != http.StatusOK {
+		t.Fatalf("put status %d body=%s", resp.Code, resp.Body.String())
+	}
+	if resp := api.Put("/api/admin/routing-rules/broken", map[string]any{"targetHost": "[", "poolCode": "X"}); resp.Code != http.StatusBadRequest {
+		t.Errorf("bad glob: status=%d want 400", resp.Code)
+	}
+
+	resp = api.Post("/messages", map[string]any{"mediation_target": "https://x.test",
+		"event_type": "order.created", "attributes": map[string]string{"region": "eu"}})
+	if resp.Code != http.StatusCreated {
+		t.Fatalf("status %d body=%s", resp.Code, resp.Body.String())
+	}
+	if pub.lastMsg.PoolCode != "ORDERS" {
+		t.Errorf("pool_code=%q want ORDERS from the rule", pub.lastMsg.PoolCode)
+	}
+	resp = api.Post("/messages", map[string]any{"pool_code": "OWN", "mediation_target": "https://x.test", "event_type": "order.created"})
+	if resp.Code != http.StatusCreated || pub.lastMsg.PoolCode != "OWN" {
+		t.Errorf("explicit pool_code should win: status=%d pool=%q", resp.Code, pub.lastMsg.PoolCode)
+	}
+
+	var list routerapi.RoutingRuleListResponse
+	decodeBody(t, api.Get("/api/admin/routing-rules").Body.Bytes(), &list)
+	if len(list.Rules) != 1 || list.Rules[0].Name != "orders" {
+		t.Errorf("rules=%+v", list.Rules)
+	}
+	if resp := api.Delete("/api/admin/routing-rules/orders"); resp.Code != http.StatusNoContent {
+		t.Errorf("delete status=%d", resp.Code)
+	}
+	if resp := api.Delete("/api/admin/routing-rules/orders"); resp.Code != http.StatusNotFound {
+		t.Errorf("second delete status=%d want 404", resp.Code)
+	}
+}
+
+func TestPublishMessage_Scheduled(t *testing.T) {
+	api, _, _, _, _, pub := setupAPI(t)
+	before := time.Now()
+	resp := api.Post("/messages",
+		map[string]any{"pool_code": "demo", "mediation_target": "https://x.test", "delay_seconds": 120})
+	if resp.Code != http.StatusCreated {
+		t.Fatalf("status %d body=%s", resp.Code, resp.Body.String())
+	}
+	if d := pub.lastDeliverAt.Sub(before); d < 120*time.Second || d > 125*time.Second {
+		t.Errorf("deliverAt=%v, want ~2m from now", pub.lastDeliverAt)
+	}
+	var body routerapi.PublishMessageResponse
+	decodeBody(t, resp.Body.Bytes(), &body)
+	if body.DeliverAt == nil {
+		t.Errorf("response should echo deliver_at")
+	}
+
+	at := time.Now().Add(time.Hour).UTC().Truncate(time.Second)
+	resp = api.Post("/messages",
+		map[string]any{"pool_code": "demo", "mediation_target": "https://x.test", "deliver_at": at.Format(time.RFC3339),
+			"expires_at": at.Add(time.Hour).Format(time.RFC3339)})
+	if resp.Code != http.StatusCreated {
+		t.Fatalf("status %d body=%s", resp.Code, resp.Body.String())
+	}
+	if !pub.lastDeliverAt.Equal(at) {
+		t.Errorf("deliverAt=%v want %v", pub.lastDeliverAt, at)
+	}
+	if e := pub.lastMsg.ExpiresAt; e == nil || !e.Equal(at.Add(time.Hour)) {
+		t.Errorf("expiresAt=%v want %v", e, at.Add(time.Hour))
+	}
+
+	for _, bad := range []map[string]any{
+		{"pool_code": "demo", "mediation_target": "https://x.test", "delay_seconds": 10, "deliver_at": at.Format(time.RFC3339)},
+		{"pool_code": "demo", "mediation_target": "https://x.test", "delay_seconds": 8 * 24 * 3600},
+		{"pool_code": "demo", "mediation_target": "https://x.test", "expires_at": time.Now().Add(-time.Minute).Format(time.RFC3339)},
+		{"pool_code": "demo", "mediation_target": "https://x.test", "deliver_at": at.Format(time.RFC3339), "expires_at": at.Add(-time.Minute).Format(time.RFC3339)},
+	} {
+		if resp := api.Post("/messages", bad); resp.Code != http.StatusBadRequest {
+			t.Errorf("%v: status=%d want 400", bad, resp.Code)
+		}
+	}
+}
+
+func TestPublishMessageBatch(t *testing.T) {
+	api, _, _, _, _, pub := setupAPI(t)
+	resp := api.Post("/messages/batch", map[string]any{"messages": []map[string]any{
+		{"pool_code": "demo", "mediation_target": "https://x.test/a", "id": "m-1"},
+		{"pool_code": "demo", "mediation_target": "https://x.test/b", "delay_seconds": -5},
+		{"pool_code": "demo", "mediation_target": "https://x.test/c", "delay_seconds": 60},
+	}})
+	if resp.Code != http.StatusOK {
+		t.Fatalf("status %d body=%s", resp.Code, resp.Body.String())
+	}
+	var body routerapi.PublishMessageBatchResponse
+	decodeBody(t, resp.Body.Bytes(), &body)
+	if body.Published != 2 || body.Failed != 1 || len(body.Results) != 3 {
+		t.Fatalf("published=%d failed=%d results=%d", body.Published, body.Failed, len(body.Results))
+	}
+	if r := body.Results[0]; r.MessageID != "m-1" || r.BrokerMessageID != "br-pub-1" || r.Error != "" {
+		t.Errorf("result 0 = %+v", r)
+	}
+	if r := body.Results[1]; r.Index != 1 || r.Error == "" {
+		t.Errorf("result 1 should carry the validation error: %+v", r)
+	}
+	if r := body.Results[2]; r.DeliverAt == nil || r.MessageID == "" {
+		t.Errorf("result 2 should be scheduled: %+v", r)
+	}
+	if pub.lastDeliverAt.IsZero() {
+		t.Errorf("scheduled message should go through PublishAt")
+	}
+}
+
+func TestPublishMessage_NamedQueue(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	def := &stubPublisher{identifier: "https://sqs.test/123/default", brokerID: "b-def"}
+	bulk := &stubPublisher{identifier: "https://sqs.test/123/bulk", brokerID: "b-bulk"}
+	state := &routerapi.State{
+		Warnings:  ws,
+		Health:    router.NewHealthService(router.DefaultHealthServiceConfig(), ws),
+		Publisher: stubQueuePublishers{"": def, "bulk": bulk},
+	}
+	_, api := humatest.New(t)
+	routerapi.Register(api, state)
+
+	resp := api.Post("/messages", map[string]any{"pool_code": "demo", "queue": "bulk", "mediation_target": "https://x.test"})
+	if resp.Code != http.StatusCreated {
+		t.Fatalf("status %d body=%s", resp.Code, resp.Body.String())
+	}
+	var body routerapi.PublishMessageResponse
+	decodeBody(t, resp.Body.Bytes(), &body)
+	if body.QueueIdentifier != bulk.identifier || bulk.lastMsg.PoolCode != "demo" {
+		t.Errorf("queue=%q pool=%q, want the bulk queue", body.QueueIdentifier, bulk.lastMsg.PoolCode)
+	}
+	if resp := api.Post("/messages", map[string]any{"pool_code": "demo", "queue": "nope", "mediation_target": "https://x.test"}); resp.Code != http.StatusBadRequest {
+		t.Errorf("unknown queue: status=%d want 400", resp.Code)
+	}
+
+	resp = api.Post("/messages/batch", map[string]any{"messages": []map[string]any{
+		{"pool_code": "demo", "mediation_target": "https://x.test/a"},
+		{"pool_code": "demo", "queue": "bulk", "mediation_target": "https://x.test/b"},
+		{"pool_code": "demo", "queue": "nope", "mediation_target": "https://x.test/c"},
+	}})
+	if resp.Code != http.StatusOK {
+		t.Fatalf("batch status %d body=%s", resp.Code, resp.Body.String())
+	}
+	var batch routerapi.PublishMessageBatchResponse
+	decodeBody(t, resp.Body.Bytes(), &batch)
+	if batch.Published != 2 || batch.Failed != 1 {
+		t.Fatalf("published=%d failed=%d", batch.Published, batch.Failed)
+	}
+	if batch.Results[0].QueueIdentifier != def.identifier || batch.Results[1].QueueIdentifier != bulk.identifier {
+		t.Errorf("results=%+v", batch.Results)
+	}
+	if !strings.Contains(batch.Results[2].Error, "not configured") {
+		t.Errorf("result 2 error=%q", batch.Results[2].Error)
+	}
+
+	bulk.publishErr = errors.New("broker down")
+	if resp := api.Post("/messages", map[string]any{"pool_code": "demo", "queue": "bulk", "mediation_target": "https://x.test"}); resp.Code != http.StatusBadGateway {
+		t.Errorf("failed publish: status=%d want 502", resp.Code)
+	}
+
+	rec := httptest.NewRecorder()
+	routerapi.PrometheusHandler(state).ServeHTTP(rec, httptest.NewRequest("GET", "/metrics", nil))
+	for _, want := range []string{
+		`fc_messages_published_total{outcome="published",queue="bulk"} 2`,
+		`fc_messages_published_total{outcome="failed",queue="bulk"} 1`,
+		`fc_messages_published_total{outcome="published",queue="default"} 1`,
+	} {
+		if !strings.Contains(rec.Body.String(), want) {
+			t.Errorf("missing %q in:\n%s", want, rec.Body.String())
+		}
+	}
+}
+
+func TestPublishRateLimit(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	_, api := humatest.New(t)
+	routerapi.Register(api, &routerapi.State{
+		Warnings:     ws,
+		Health:       router.NewHealthService(router.DefaultHealthServiceConfig(), ws),
+		Publisher:    stubPublisherProvider{pub: &stubPublisher{identifier: "q", brokerID: "b"}},
+		PublishLimit: rate.NewLimiter(rate.Every(time.Hour), 2),
+	})
+	msg := map[string]any{"pool_code": "demo", "mediation_target": "https://x.test"}
+	resp := api.Post("/messages/batch", map[string]any{"messages": []any{msg, msg, msg}})
+	if resp.Code != http.StatusTooManyRequests {
+		t.Errorf("batch over the burst: status=%d want 429", resp.Code)
+	}
+	for i, want := range []int{http.StatusCreated, http.StatusCreated, http.StatusTooManyRequests} {
+		if resp := api.Post("/messages", msg); resp.Code != want {
+			t.Errorf("publish %d: status=%d want %d", i, resp.Code, want)
+		}
 	}
 }
 
@@ -1,11 +1,60 @@
 package api
 
 import (
+	"crypto/sha256"
 	"crypto/subtle"
+	"encoding/json"
+	"errors"
+	"fmt"
 	"net/http"
+	"slices"
 	"strings"
+
+	"github.com/go-chi/chi/v5"
+)
+
+// Scopes a router API token can carry. Reads need only a valid token;
+// POST /messages (and /messages/batch) needs ScopePublish and every other
+// mutation ScopeAdmin.
+// ScopeAdmin implies ScopePublish.
+const (
+	ScopeAdmin   = "router:admin"
+	ScopePublish = "router:publish"
+)
+
+// AuthMode selects how the router HTTP surface authenticates callers.
+// Mirrors the Rust router's AuthMode, with TOKEN in place of its OIDC
+// modes.
+type AuthMode string
+
+const (
+	// AuthModeNone leaves every route open. Development only.
+	AuthModeNone AuthMode = "NONE"
+	// AuthModeBasic accepts one operator credential, which holds every
+	// scope.
+	AuthModeBasic AuthMode = "BASIC"
+	// AuthModeToken accepts bearer tokens from a static list, each with
+	// its own scopes.
+	AuthModeToken AuthMode = "TOKEN"
 )
 
+// ParseAuthMode reads an AUTH_MODE value (case-insensitive). BEARER is
+// accepted for TOKEN; empty returns "" so the caller can infer the mode
+// from the credentials it was given.
+func ParseAuthMode(s string) (AuthMode, error) {
+	switch strings.ToUpper(strings.TrimSpace(s)) {
+	case "":
+		return "", nil
+	case "NONE":
+		return AuthModeNone, nil
+	case "BASIC":
+		return AuthModeBasic, nil
+	case "TOKEN", "BEARER":
+		return AuthModeToken, nil
+	}
+	return "", fmt.Errorf("unknown auth mode %q (want NONE, BASIC or TOKEN)", s)
+}
+
 // BasicAuthConfig configures the optional HTTP BasicAuth middleware.
 // Empty Username disables auth entirely (matches Rust AuthMode::None).
 type BasicAuthConfig struct {
@@ -16,43 +65,107 @@ type BasicAuthConfig struct {
 	Realm string
 }
 
-// publicPaths are the URLs that bypass authentication. Mirrors Rust's
-// is_public_path: probes + Prometheus + the OpenAPI surface must be
-// reachable by orchestration tooling without credentials.
-var publicPaths = map[string]struct{}{
-	"/health":           {},
-	"/q/health":         {},
-	"/health/live":      {},
-	"/health/ready":     {},
-	"/health/startup":   {},
-	"/q/health/live":    {},
-	"/q/health/ready":   {},
-	"/metrics":          {},
-	"/q/metrics":        {},
-	"/ready":            {},
-	"/openapi.json":     {},
-	"/openapi.yaml":     {},
-	"/openapi-3.0.json": {},
-	"/openapi-3.0.yaml": {},
-	"/openapi-3.1.json": {},
-	"/openapi-3.1.yaml": {},
+// Token is one router API token. Name identifies it in logs and errors;
+// the token itself is never echoed.
+type Token struct {
+	Name   string   `json:"name"`
+	Token  string   `json:"token"`
+	Scopes []string `json:"scopes"`
+}
+
+// ParseTokens decodes the FC_ROUTER_AUTH_TOKENS JSON array and rejects
+// entries without a secret, with an unknown scope, or reusing a secret.
+func ParseTokens(raw string) ([]Token, error) {
+	var out []Token
+	if err := json.Unmarshal([]byte(raw), &out); err != nil {
+		return nil, fmt.Errorf("parse router tokens: %w", err)
+	}
+	seen := make(map[string]struct{}, len(out))
+	for i, t := range out {
+		if t.Name == "" {
+			return nil, fmt.Errorf("router token %d: name is required", i)
+		}
+		if len(t.Token) < 16 {
+			return nil, fmt.Errorf("router token %q: token must be at least 16 characters", t.Name)
+		}
+		for _, s := range t.Scopes {
+			if s != ScopeAdmin && s != ScopePublish {
+				return nil, fmt.Errorf("router token %q: unknown scope %q", t.Name, s)
+			}
+		}
+		if _, dup := seen[t.Token]; dup {
+			return nil, fmt.Errorf("router token %q: token reused", t.Name)
+		}
+		seen[t.Token] = struct{}{}
+	}
+	return out, nil
+}
+
+// AuthConfig configures AuthMiddleware.
+type AuthConfig struct {
+	Mode   AuthMode
+	Basic  BasicAuthConfig
+	Tokens []Token
+}
+
+// Validate rejects a mode without the credentials it needs: a BASIC or
+// TOKEN router that silently fell open would defeat the point of asking.
+func (c AuthConfig) Validate() error {
+	switch c.Mode {
+	case AuthModeNone:
+		return nil
+	case AuthModeBasic:
+		if c.Basic.Username == "" {
+			return errors.New("BASIC auth requires a username")
+		}
+		return nil
+	case AuthModeToken:
+		if len(c.Tokens) == 0 {
+			return errors.New("TOKEN auth requires at least one token")
+		}
+		return nil
+	}
+	return fmt.Errorf("unknown auth mode %q", c.Mode)
 }
 
-// publicPrefixes covers paths where any subroute is public — the docs
-// renderer serves arbitrary asset paths under /docs.
-var publicPrefixes = []string{"/docs"}
+// publicPaths are the URLs that bypass authentication: the health and
+// probe endpoints orchestration tooling polls without credentials.
+// Metrics, the OpenAPI surface and docs need a token like any other read.
+var publicPaths = map[string]struct{}{
+	"/health":         {},
+	"/q/health":       {},
+	"/health/live":    {},
+	"/health/ready":   {},
+	"/health/startup": {},
+	"/q/health/live":  {},
+	"/q/health/ready": {},
+	"/ready":          {},
+}
 
 // IsPublicPath reports whether path is exempt from auth.
 func IsPublicPath(path string) bool {
-	if _, ok := publicPaths[path]; ok {
-		return true
+	_, ok := publicPaths[path]
+	return ok
+}
+
+// RequiredScope returns the scope a request needs beyond a valid
+// credential: none for reads, ScopePublish to publish a message and
+// ScopeAdmin for every other mutation (pool config, breaker resets,
+// config reload, warning acknowledgement, seeding, mock resets).
+func RequiredScope(method, path string) string {
+	switch method {
+	case http.MethodGet, http.MethodHead, http.MethodOptions:
+		return ""
 	}
-	for _, p := range publicPrefixes {
-		if path == p || strings.HasPrefix(path, p+"/") {
-			return true
-		}
+	if path == "/messages" || path == "/messages/batch" {
+		return ScopePublish
 	}
-	return false
+	return ScopeAdmin
+}
+
+// grants reports whether scopes satisfy required.
+func grants(scopes []string, required string) bool {
+	return required == "" || slices.Contains(scopes, ScopeAdmin) || slices.Contains(scopes, required)
 }
 
 // BasicAuthMiddleware returns a chi-compatible middleware that enforces
@@ -64,28 +177,97 @@ func BasicAuthMiddleware(cfg BasicAuthConfig) func(http.Handler) http.Handler {
 		// No-op when not configured.
 		return func(next http.Handler) http.Handler { return next }
 	}
-	realm := cfg.Realm
-	if realm == "" {
-		realm = "FlowCatalyst Router"
+	return AuthMiddleware(AuthConfig{Mode: AuthModeBasic, Basic: cfg})
+}
+
+// AuthMiddleware returns a chi-compatible middleware that authenticates
+// every non-public route per cfg.Mode and then checks the caller holds
+// RequiredScope. Paths are matched relative to the mount point, so the
+// router prefix doesn't leak into the public list. Call cfg.Validate
+// first; a BASIC config without a username is treated as NONE here.
+func AuthMiddleware(cfg AuthConfig) func(http.Handler) http.Handler {
+	var authenticate func(w http.ResponseWriter, r *http.Request) ([]string, bool)
+	switch cfg.Mode {
+	case AuthModeBasic:
+		if cfg.Basic.Username == "" {
+			return func(next http.Handler) http.Handler { return next }
+		}
+		authenticate = basicAuthenticator(cfg.Basic)
+	case AuthModeToken:
+		authenticate = tokenAuthenticator(cfg.Tokens)
+	default:
+		return func(next http.Handler) http.Handler { return next }
 	}
-	expectedUser := []byte(cfg.Username)
-	expectedPass := []byte(cfg.Password)
 
 	return func(next http.Handler) http.Handler {
 		return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
-			if IsPublicPath(r.URL.Path) {
+			path := routePath(r)
+			if IsPublicPath(path) {
 				next.ServeHTTP(w, r)
 				return
 			}
-			user, pass, ok := r.BasicAuth()
-			if !ok ||
-				subtle.ConstantTimeCompare([]byte(user), expectedUser) != 1 ||
-				subtle.ConstantTimeCompare([]byte(pass), expectedPass) != 1 {
-				w.Header().Set("WWW-Authenticate", `Basic realm="`+realm+`", charset="UTF-8"`)
+			scopes, ok := authenticate(w, r)
+			if !ok {
 				http.Error(w, "unauthorized", http.StatusUnauthorized)
 				return
 			}
+			if required := RequiredScope(r.Method, path); !grants(scopes, required) {
+				w.Header().Set("WWW-Authenticate", `Bearer error="insufficient_scope", scope="`+required+`"`)
+				http.Error(w, "forbidden: requires scope "+required, http.StatusForbidden)
+				return
+			}
 			next.ServeHTTP(w, r)
 		})
 	}
 }
+
+// basicAuthenticator checks the single operator credential, which holds
+// every scope.
+func basicAuthenticator(cfg BasicAuthConfig) func(http.ResponseWriter, *http.Request) ([]string, bool) {
+	realm := cfg.Realm
+	if realm == "" {
+		realm = "FlowCatalyst Router"
+	}
+	expectedUser := []byte(cfg.Username)
+	expectedPass := []byte(cfg.Password)
+	all := []string{ScopeAdmin, ScopePublish}
+	return func(w http.ResponseWriter, r *http.Request) ([]string, bool) {
+		user, pass, ok := r.BasicAuth()
+		if !ok ||
+			subtle.ConstantTimeCompare([]byte(user), expectedUser) != 1 ||
+			subtle.ConstantTimeCompare([]byte(pass), expectedPass) != 1 {
+			w.Header().Set("WWW-Authenticate", `Basic realm="`+realm+`", charset="UTF-8"`)
+			return nil, false
+		}
+		return all, true
+	}
+}
+
+// tokenAuthenticator looks the bearer token up by its SHA-256, so the
+// lookup time doesn't depend on how much of a guess matches a real token.
+func tokenAuthenticator(tokens []Token) func(http.ResponseWriter, *http.Request) ([]string, bool) {
+	byHash := make(map[[sha256.Size]byte][]string, len(tokens))
+	for _, t := range tokens {
+		byHash[sha256.Sum256([]byte(t.Token))] = t.Scopes
+	}
+	return func(w http.ResponseWriter, r *http.Request) ([]string, bool) {
+		h := r.Header.Get("Authorization")
+		if len(h) > 7 && strings.EqualFold(h[:7], "Bearer ") {
+			if scopes, ok := byHash[sha256.Sum256([]byte(strings.TrimSpace(h[7:])))]; ok {
+				return scopes, true
+			}
+		}
+		w.Header().Set("WWW-Authenticate", `Bearer realm="FlowCatalyst Router"`)
+		return nil, false
+	}
+}
+
+// routePath is the request path below the router's mount point; chi
+// records it on the route context when the router is nested under a
+// prefix.
+func routePath(r *http.Request) string {
+	if rctx := chi.RouteContext(r.Context()); rctx != nil && rctx.RoutePath != "" {
+		return rctx.RoutePath
+	}
+	return r.URL.Path
+}
@@ -80,7 +80,7 @@ func TestBasicAuth_PublicPathsBypass(t *testing.T) {
 	r := chi.NewRouter()
 	r.Use(routerapi.BasicAuthMiddleware(routerapi.BasicAuthConfig{Username: "u", Password: "p"}))
 	for _, path := range []string{
-		"/health", "/health/live", "/health/ready", "/metrics", "/openapi.json", "/docs", "/docs/", "/docs/foo",
+		"/health", "/health/live", "/health/ready", "/q/health", "/ready",
 	} {
 		r.Get(path, func(w http.ResponseWriter, _ *http.Request) {
 			w.WriteHeader(http.StatusOK)
@@ -99,13 +99,15 @@ func TestIsPublicPath(t *testing.T) {
 	cases := map[string]bool{
 		"/health":              true,
 		"/health/live":         true,
-		"/metrics":             true,
-		"/openapi.json":        true,
-		"/docs":                true,
-		"/docs/swagger-ui.css": true,
+		"/q/health/ready":      true,
+		"/ready":               true,
+		"/metrics":             false,
+		"/openapi.json":        false,
+		"/docs":                false,
+		"/docs/swagger-ui.css": false,
 		"/messages":            false,
 		"/monitoring/pools":    false,
-		"/docsy":               false, // not a prefix match
+		"/healthz":             false,
 	}
 	for path, want := range cases {
 		if got := routerapi.IsPublicPath(path); got != want {
@@ -113,3 +115,108 @@ func TestIsPublicPath(t *testing.T) {
 		}
 	}
 }
+
+func TestRequiredScope(t *testing.T) {
+	cases := []struct {
+		method, path, want string
+	}{
+		{"GET", "/monitoring/pools", ""},
+		{"GET", "/messages", ""},
+		{"POST", "/messages", routerapi.ScopePublish},
+		{"POST", "/messages/batch", routerapi.ScopePublish},
+		{"POST", "/api/seed/messages", routerapi.ScopeAdmin},
+		{"PUT", "/monitoring/pools/DEFAULT", routerapi.ScopeAdmin},
+		{"POST", "/monitoring/circuit-breakers/reset-all", routerapi.ScopeAdmin},
+		{"POST", "/config/reload", routerapi.ScopeAdmin},
+	}
+	for _, c := range cases {
+		if got := routerapi.RequiredScope(c.method, c.path); got != c.want {
+			t.Errorf("RequiredScope(%s %s)=%q want %q", c.method, c.path, got, c.want)
+		}
+	}
+}
+
+// tokenRouter mounts a token-authenticated sub-router under /router, the
+// way the server does, so paths are matched below the prefix.
+func tokenRouter(t *testing.T) http.Handler {
+	t.Helper()
+	tokens, err := routerapi.ParseTokens(`[
+		{"name":"ops","token":"ops-token-0123456789","scopes":["router:admin"]},
+		{"name":"app","token":"app-token-0123456789","scopes":["router:publish"]},
+		{"name":"viewer","token":"view-token-0123456789","scopes":[]}
+	]`)
+	if err != nil {
+		t.Fatal(err)
+	}
+	ok := func(w http.ResponseWriter, _ *http.Request) { w.WriteHeader(http.StatusOK) }
+	r := chi.NewRouter()
+	r.Route("/router", func(sub chi.Router) {
+		sub.Use(routerapi.AuthMiddleware(routerapi.AuthConfig{Mode: routerapi.AuthModeToken, Tokens: tokens}))
+		sub.Get("/health", ok)
+		sub.Get("/monitoring/pools", ok)
+		sub.Post("/messages", ok)
+		sub.Post("/config/reload", ok)
+	})
+	return r
+}
+
+func TestTokenAuth_Scopes(t *testing.T) {
+	h := tokenRouter(t)
+	cases := []struct {
+		method, path, token string
+		want                int
+	}{
+		{"GET", "/router/health", "", http.StatusOK},
+		{"GET", "/router/monitoring/pools", "", http.StatusUnauthorized},
+		{"GET", "/router/monitoring/pools", "not-a-real-token-000", http.StatusUnauthorized},
+		{"GET", "/router/monitoring/pools", "view-token-0123456789", http.StatusOK},
+		{"POST", "/router/messages", "view-token-0123456789", http.StatusForbidden},
+		{"POST", "/router/messages", "app-token-0123456789", http.StatusOK},
+		{"POST", "/router/config/reload", "app-token-0123456789", http.StatusForbidden},
+		{"POST", "/router/config/reload", "ops-token-0123456789", http.StatusOK},
+		{"POST", "/router/messages", "ops-token-0123456789", http.StatusOK},
+	}
+	for _, c := range cases {
+		req := httptest.NewRequest(c.method, c.path, nil)
+		if c.token != "" {
+			req.Header.Set("Authorization", "Bearer "+c.token)
+		}
+		rec := httptest.NewRecorder()
+		h.ServeHTTP(rec, req)
+		if rec.Code != c.want {
+			t.Errorf("%s %s token=%q: status=%d want %d", c.method, c.path, c.token, rec.Code, c.want)
+		}
+		if rec.Code == http.StatusForbidden && !strings.Contains(rec.Header().Get("WWW-Authenticate"), "insufficient_scope") {
+			t.Errorf("%s %s: WWW-Authenticate=%q want insufficient_scope", c.method, c.path, rec.Header().Get("WWW-Authenticate"))
+		}
+	}
+}
+
+func TestParseTokens_Rejects(t *testing.T) {
+	for name, raw := range map[string]string{
+		"not json":      `nope`,
+		"no name":       `[{"token":"0123456789abcdef","scopes":[]}]`,
+		"short token":   `[{"name":"a","token":"short","scopes":[]}]`,
+		"unknown scope": `[{"name":"a","token":"0123456789abcdef","scopes":["router:root"]}]`,
+		"reused token":  `[{"name":"a","token":"0123456789abcdef"},{"name":"b","token":"0123456789abcdef"}]`,
+	} {
+		if _, err := routerapi.ParseTokens(raw); err == nil {
+			t.Errorf("%s: ParseTokens accepted %s", name, raw)
+		}
+	}
+}
+
+func TestAuthConfig_ValidateRequiresCredentials(t *testing.T) {
+	if err := (routerapi.AuthConfig{Mode: routerapi.AuthModeToken}).Validate(); err == nil {
+		t.Error("TOKEN without tokens validated")
+	}
+	if err := (routerapi.AuthConfig{Mode: routerapi.AuthModeBasic}).Validate(); err == nil {
+		t.Error("BASIC without a username validated")
+	}
+	if err := (routerapi.AuthConfig{Mode: routerapi.AuthModeNone}).Validate(); err != nil {
+		t.Errorf("NONE: %v", err)
+	}
+	if _, err := routerapi.ParseAuthMode("oidc"); err == nil {
+		t.Error("ParseAuthMode accepted an unknown mode")
+	}
+}
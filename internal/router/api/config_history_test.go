@@ -0,0 +1,96 @@
+package api_test
+
+import (
+	"context"
+	"net/http"
+	"testing"
+	"time"
+
+	"github.com/danielgtaylor/huma/v2/humatest"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+	routerapi "github.com/flowcatalyst/flowcatalyst-go/internal/router/api"
+)
+
+// stubConfigHistory holds snapshots oldest first.
+type stubConfigHistory struct{ snaps []router.ConfigSnapshot }
+
+func (h stubConfigHistory) Record(context.Context, router.ConfigSnapshot) error { return nil }
+
+func (h stubConfigHistory) At(_ context.Context, t time.Time) (router.ConfigSnapshot, error) {
+	for i := len(h.snaps) - 1; i >= 0; i-- {
+		if !h.snaps[i].TakenAt.After(t) {
+			return h.snaps[i], nil
+		}
+	}
+	return router.ConfigSnapshot{}, router.ErrNoConfigSnapshot
+}
+
+func configHistoryState() *routerapi.State {
+	t0 := time.Date(2026, 3, 1, 9, 0, 0, 0, time.UTC)
+	return &routerapi.State{
+		ConfigHistory: stubConfigHistory{snaps: []router.ConfigSnapshot{
+			{Hash: "h1", Source: "http://cfg", TakenAt: t0, LastSeenAt: t0.Add(time.Hour), Config: common.RouterConfig{
+				ProcessingPools: []common.PoolConfig{{Code: "orders", Concurrency: 20}},
+			}},
+			{Hash: "h2", Source: "http://cfg", TakenAt: t0.Add(2 * time.Hour), LastSeenAt: t0.Add(3 * time.Hour), Config: common.RouterConfig{
+				ProcessingPools: []common.PoolConfig{{Code: "orders", Concurrency: 5}, {Code: "billing", Concurrency: 2}},
+			}},
+		}},
+		Mocks: routerapi.NewMockState(),
+	}
+}
+
+func TestConfigHistoryAt(t *testing.T) {
+	_, api := humatest.New(t)
+	routerapi.Register(api, configHistoryState())
+
+	resp := api.Get("/config/history/2026-03-01T10:30:00Z")
+	if resp.Code != http.StatusOK {
+		t.Fatalf("status %d body=%s", resp.Code, resp.Body.String())
+	}
+	var body routerapi.ConfigSnapshotResponse
+	decodeBody(t, resp.Body.Bytes(), &body)
+	if body.Hash != "h1" || len(body.Config.ProcessingPools) != 1 || body.Config.ProcessingPools[0].Concurrency != 20 {
+		t.Errorf("snapshot = %+v, want h1 with orders at 20", body)
+	}
+
+	if resp := api.Get("/config/history/2026-03-01T08:00:00Z"); resp.Code != http.StatusNotFound {
+		t.Errorf("before the first snapshot: status %d, want 404", resp.Code)
+	}
+	if resp := api.Get("/config/history/yesterday"); resp.Code != http.StatusBadRequest {
+		t.Errorf("bad timestamp: status %d, want 400", resp.Code)
+	}
+}
+
+func TestConfigHistoryDiff(t *testing.T) {
+	_, api := humatest.New(t)
+	routerapi.Register(api, configHistoryState())
+
+	resp := api.Get("/config/history/diff?from=2026-03-01T09:30:00Z&to=2026-03-01T12:00:00Z")
+	if resp.Code != http.StatusOK {
+		t.Fatalf("status %d body=%s", resp.Code, resp.Body.String())
+	}
+	var body routerapi.ConfigDiffResponse
+	decodeBody(t, resp.Body.Bytes(), &body)
+	if body.From.Hash != "h1" || body.To.Hash != "h2" {
+		t.Errorf("from/to = %s/%s, want h1/h2", body.From.Hash, body.To.Hash)
+	}
+	if len(body.Pools) != 2 ||
+		body.Pools[0].Key != "billing" || body.Pools[0].Change != router.ConfigAdded ||
+		body.Pools[1].Key != "orders" || body.Pools[1].Change != router.ConfigChanged {
+		t.Errorf("pools = %+v, want billing added and orders changed", body.Pools)
+	}
+	if len(body.Queues) != 0 || len(body.RoutingRules) != 0 {
+		t.Errorf("queues/rules = %+v/%+v, want none", body.Queues, body.RoutingRules)
+	}
+}
+
+func TestConfigHistoryNotConfigured(t *testing.T) {
+	_, api := humatest.New(t)
+	routerapi.Register(api, &routerapi.State{Mocks: routerapi.NewMockState()})
+	if resp := api.Get("/config/history/2026-03-01T10:30:00Z"); resp.Code != http.StatusServiceUnavailable {
+		t.Errorf("status %d, want 503", resp.Code)
+	}
+}
@@ -6,6 +6,8 @@
 //   - `/monitoring` (overview) + `/monitoring/pools` + `/warnings`     → snake_case (Rust serde default)
 //   - `/monitoring/health` + dashboard endpoints (pool-stats/queue-stats/circuit-breakers/in-flight) → camelCase (explicit Rust renames)
 //   - `/api/config` + reload / publish responses                        → snake_case
+//   - `/config/history` (Go extension)                                  → camelCase, like the config it serves
+//   - `/incidents` (Go extension)                                       → camelCase, like the dashboard endpoints
 package api
 
 import (
@@ -79,8 +81,11 @@ type WirePoolStats struct {
 	QueueSize          uint32                      `json:"queue_size"`
 	QueueCapacity      uint32                      `json:"queue_capacity"`
 	MessageGroupCount  uint32                      `json:"message_group_count"`
+	GroupStateBytes    int64                       `json:"group_state_bytes"`
+	GroupEvictions     uint64                      `json:"group_evictions"`
 	RateLimitPerMinute *uint32                     `json:"rate_limit_per_minute,omitempty"`
 	IsRateLimited      bool                        `json:"is_rate_limited"`
+	RateProfile        string                      `json:"rate_profile,omitempty"`
 	Metrics            *common.EnhancedPoolMetrics `json:"metrics,omitempty"`
 }
 
@@ -94,8 +99,11 @@ func fromPoolStats(s []router.PoolStats) []WirePoolStats {
 			QueueSize:          p.QueueSize,
 			QueueCapacity:      p.QueueCapacity,
 			MessageGroupCount:  p.MessageGroupCount,
+			GroupStateBytes:    p.GroupStateBytes,
+			GroupEvictions:     p.GroupEvictions,
 			RateLimitPerMinute: p.RateLimitPerMinute,
 			IsRateLimited:      p.IsRateLimited,
+			RateProfile:        p.RateProfile,
 			Metrics:            p.Metrics,
 		}
 	}
@@ -267,6 +275,7 @@ type WireWarning struct {
 	CreatedAt      time.Time  `json:"created_at"`
 	Acknowledged   bool       `json:"acknowledged"`
 	AcknowledgedAt *time.Time `json:"acknowledged_at,omitempty"`
+	IncidentID     string     `json:"incident_id,omitempty"`
 }
 
 func fromWarning(w router.Warning) WireWarning {
@@ -279,6 +288,7 @@ func fromWarning(w router.Warning) WireWarning {
 		CreatedAt:      w.CreatedAt,
 		Acknowledged:   w.Acknowledged,
 		AcknowledgedAt: w.AcknowledgedAt,
+		IncidentID:     w.IncidentID,
 	}
 }
 
@@ -305,6 +315,38 @@ type AcknowledgedCountResponse struct {
 	Acknowledged uint64 `json:"acknowledged"`
 }
 
+// ── Incidents (/incidents, /monitoring/incidents) ─────────────────────────
+//
+// Responses are router.Incident, whose JSON tags are already camelCase.
+
+// IncidentAcknowledgeRequest is the body for POST /incidents/{id}/acknowledge.
+type IncidentAcknowledgeRequest struct {
+	By string `json:"by,omitempty" doc:"Who acknowledged the incident"`
+}
+
+// IncidentAssignRequest is the body for POST /incidents/{id}/assign.
+type IncidentAssignRequest struct {
+	Assignee string `json:"assignee" doc:"New owner; empty clears the assignment"`
+}
+
+// IncidentResolveRequest is the body for POST /incidents/{id}/resolve.
+type IncidentResolveRequest struct {
+	By   string `json:"by,omitempty" doc:"Who resolved the incident"`
+	Note string `json:"note,omitempty" doc:"Resolution note, recorded in the incident's notes"`
+}
+
+// IncidentNoteRequest is the body for POST /incidents/{id}/notes.
+type IncidentNoteRequest struct {
+	Author string `json:"author,omitempty"`
+	Text   string `json:"text" minLength:"1"`
+}
+
+// IncidentLinkRequest is the body for POST /incidents/{id}/links.
+type IncidentLinkRequest struct {
+	Title string `json:"title,omitempty"`
+	URL   string `json:"url" format:"uri" doc:"Runbook, ticket or dashboard URL"`
+}
+
 // ── Mutations: PUT pool, broker refresh, breaker reset ───────────────────
 
 // PoolConfigUpdateRequest is the body for PUT /monitoring/pools/{poolCode}.
@@ -350,7 +392,8 @@ type BreakerResetAllResponse struct {
 // PublishMessageRequest is the body for POST /messages.
 type PublishMessageRequest struct {
 	ID              string `json:"id,omitempty" doc:"Message ID; auto-generated when empty"`
-	PoolCode        string `json:"pool_code" doc:"Target pool (must match a registered pool)"`
+	PoolCode        string `json:"pool_code,omitempty" doc:"Target pool; when empty the routing rules pick one"`
+	Queue           string `json:"queue,omitempty" doc:"Configured queue to publish to; overrides the routing rules' queue and must exist. Empty resolves the queue by pool code"`
 	MediationType   string `json:"mediation_type,omitempty" doc:"Mediation type; defaults to HTTP"`
 	MediationTarget string `json:"mediation_target" doc:"Target URL"`
 	MessageGroupID  string `json:"message_group_id,omitempty" doc:"Optional FIFO group ID"`
@@ -358,14 +401,81 @@ type PublishMessageRequest struct {
 	DispatchMode    string `json:"dispatch_mode,omitempty" doc:"IMMEDIATE | NEXT_ON_ERROR | BLOCK_ON_ERROR"`
 	AuthToken       string `json:"auth_token,omitempty"`
 	SigningSecret   string `json:"signing_secret,omitempty"`
+	// DeliverAt / DelaySeconds schedule the message; at most one may be set.
+	DeliverAt    *time.Time `json:"deliver_at,omitempty" doc:"Do not deliver before this time (RFC 3339, at most 7 days ahead)"`
+	DelaySeconds int        `json:"delay_seconds,omitempty" doc:"Do not deliver for this many seconds (at most 7 days)"`
+	ExpiresAt    *time.Time `json:"expires_at,omitempty" doc:"Drop the message undelivered if it is still queued at this time (RFC 3339)"`
+	// Routing inputs, matched by the routing rules when PoolCode is empty.
+	// They are not part of the published message.
+	EventType  string            `json:"event_type,omitempty" doc:"Event type, for routing rules"`
+	ClientID   string            `json:"client_id,omitempty" doc:"Client id, for routing rules"`
+	Attributes map[string]string `json:"attributes,omitempty" doc:"Payload fields, for routing rules"`
 }
 
 // PublishMessageResponse echoes the resulting broker IDs.
 type PublishMessageResponse struct {
-	MessageID       string `json:"message_id"`
-	BrokerMessageID string `json:"broker_message_id"`
-	PoolCode        string `json:"pool_code"`
-	QueueIdentifier string `json:"queue_identifier"`
+	MessageID       string     `json:"message_id"`
+	BrokerMessageID string     `json:"broker_message_id"`
+	PoolCode        string     `json:"pool_code"`
+	QueueIdentifier string     `json:"queue_identifier"`
+	DeliverAt       *time.Time `json:"deliver_at,omitempty"`
+}
+
+// PublishMessageBatchRequest is the body for POST /messages/batch
+// (at most MaxBatchItems messages).
+type PublishMessageBatchRequest struct {
+	Messages []PublishMessageRequest `json:"messages" minItems:"1" maxItems:"1000"`
+}
+
+// PublishMessageBatchResult is one message's outcome, in request order.
+// Error is set when the message was rejected or failed to publish.
+type PublishMessageBatchResult struct {
+	Index           int        `json:"index"`
+	MessageID       string     `json:"message_id,omitempty"`
+	BrokerMessageID string     `json:"broker_message_id,omitempty"`
+	PoolCode        string     `json:"pool_code,omitempty"`
+	QueueIdentifier string     `json:"queue_identifier,omitempty"`
+	DeliverAt       *time.Time `json:"deliver_at,omitempty"`
+	Error           string     `json:"error,omitempty"`
+}
+
+// PublishMessageBatchResponse counts the batch's outcomes.
+type PublishMessageBatchResponse struct {
+	Published int                         `json:"published"`
+	Failed    int                         `json:"failed"`
+	Results   []PublishMessageBatchResult `json:"results"`
+}
+
+// RoutingRuleRequest is the body for PUT /api/admin/routing-rules/{name}:
+// a rule in the config-sync shape (camelCase, see common.RoutingRule),
+// named by the path.
+type RoutingRuleRequest struct {
+	Priority   int               `json:"priority,omitempty" doc:"Lower runs first; ties keep insertion order"`
+	TargetHost string            `json:"targetHost,omitempty" doc:"Glob on the mediation target's hostname"`
+	EventType  string            `json:"eventType,omitempty" doc:"Glob on the publish request's event_type"`
+	ClientID   string            `json:"clientId,omitempty" doc:"Glob on the publish request's client_id"`
+	Attributes map[string]string `json:"attributes,omitempty" doc:"Globs on the publish request's attributes, by key"`
+	PoolCode   string            `json:"poolCode" doc:"Pool assigned to matching messages"`
+	QueueName  string            `json:"queueName,omitempty" doc:"Queue to publish matching messages to; empty resolves the queue by pool code"`
+}
+
+func (r RoutingRuleRequest) toRule(name string) common.RoutingRule {
+	return common.RoutingRule{
+		Name:       name,
+		Priority:   r.Priority,
+		TargetHost: r.TargetHost,
+		EventType:  r.EventType,
+		ClientID:   r.ClientID,
+		Attributes: r.Attributes,
+		PoolCode:   r.PoolCode,
+		QueueName:  r.QueueName,
+	}
+}
+
+// RoutingRuleListResponse is the body for GET /api/admin/routing-rules,
+// in evaluation order.
+type RoutingRuleListResponse struct {
+	Rules []common.RoutingRule `json:"rules"`
 }
 
 // SeedMessagesRequest is the body for /api/seed/messages.
@@ -424,6 +534,32 @@ type ConfigReloadResponse struct {
 	Note    string `json:"note,omitempty"`
 }
 
+// ConfigSnapshotSummary identifies a config history snapshot.
+type ConfigSnapshotSummary struct {
+	Hash       string    `json:"hash"`
+	Source     string    `json:"source"`
+	TakenAt    time.Time `json:"takenAt"`
+	LastSeenAt time.Time `json:"lastSeenAt"`
+}
+
+// ConfigSnapshotResponse is the body for GET /config/history/{timestamp}.
+// Queue URIs have their passwords redacted.
+type ConfigSnapshotResponse struct {
+	ConfigSnapshotSummary
+	Config common.RouterConfig `json:"config"`
+}
+
+// ConfigDiffResponse is the body for GET /config/history/diff: the
+// snapshots in force at from and to, and the pools, queues and routing
+// rules that differ between them.
+type ConfigDiffResponse struct {
+	From         ConfigSnapshotSummary `json:"from"`
+	To           ConfigSnapshotSummary `json:"to"`
+	Pools        []router.ConfigChange `json:"pools"`
+	Queues       []router.ConfigChange `json:"queues"`
+	RoutingRules []router.ConfigChange `json:"routingRules"`
+}
+
 // StandbyStatusResponse mirrors Rust StandbyStatusResponse.
 type StandbyStatusResponse struct {
 	Enabled    bool   `json:"enabled"`
@@ -441,6 +577,37 @@ type TrafficStatusResponse struct {
 	LastError     string `json:"lastError,omitempty"`
 }
 
+// ScaleRecommendationResponse is the body for GET /scale/recommendation:
+// the desired replica count and the signals it was computed from.
+type ScaleRecommendationResponse struct {
+	DesiredReplicas int                `json:"desiredReplicas"`
+	MinReplicas     int                `json:"minReplicas"`
+	MaxReplicas     int                `json:"maxReplicas,omitempty"`
+	TotalBacklog    uint64             `json:"totalBacklog"`
+	Queues          []ScaleQueueSignal `json:"queues"`
+	Pools           []ScalePoolSignal  `json:"pools"`
+}
+
+// ScaleQueueSignal is one queue's share of the recommendation: Replicas
+// is Backlog / MessagesPerReplica, before rounding.
+type ScaleQueueSignal struct {
+	Queue              string  `json:"queue"`
+	Pending            uint64  `json:"pending"`
+	InFlight           uint64  `json:"inFlight"`
+	Backlog            uint64  `json:"backlog"`
+	MessagesPerReplica int     `json:"messagesPerReplica"`
+	Replicas           float64 `json:"replicas"`
+}
+
+// ScalePoolSignal is one pool's worker saturation (busy / concurrency).
+// Informational: the recommendation is driven by queue backlog.
+type ScalePoolSignal struct {
+	Pool          string  `json:"pool"`
+	ActiveWorkers uint32  `json:"activeWorkers"`
+	Concurrency   uint32  `json:"concurrency"`
+	Saturation    float64 `json:"saturation"`
+}
+
 // StreamHealthResponse is the body for /monitoring/stream-health. When
 // no StreamHealthProvider is wired the response is `enabled: false`
 // and `status: NOT_CONFIGURED`; otherwise the live aggregate from the
@@ -97,6 +97,10 @@ func registerMonitoring(api huma.API, s *State) {
 		OperationID: "consumerHealth", Method: http.MethodGet, Path: "/monitoring/consumer-health",
 		Summary: "Per-consumer health", Tags: []string{tagMonitoring}, DefaultStatus: http.StatusOK,
 	}, s.consumerHealth)
+	huma.Register(api, huma.Operation{
+		OperationID: "monitoringInfrastructure", Method: http.MethodGet, Path: "/monitoring/infrastructure",
+		Summary: "Mongo/Redis connectivity", Tags: []string{tagMonitoring}, DefaultStatus: http.StatusOK,
+	}, s.infrastructure)
 }
 
 type monitoringOutput struct {
@@ -187,6 +191,20 @@ func (s *State) consumerHealth(_ context.Context, _ *emptyInput) (*consumerHealt
 	}}, nil
 }
 
+type infrastructureOutput struct {
+	Body router.InfrastructureHealth
+}
+
+func (s *State) infrastructure(ctx context.Context, _ *emptyInput) (*infrastructureOutput, error) {
+	if s.Infrastructure == nil {
+		return &infrastructureOutput{Body: router.InfrastructureHealth{
+			Healthy:    true,
+			Components: []router.InfrastructureComponent{},
+		}}, nil
+	}
+	return &infrastructureOutput{Body: s.Infrastructure.Infrastructure(ctx)}, nil
+}
+
 // ── helpers ──────────────────────────────────────────────────────────────
 
 func (s *State) poolStatsSnap() []router.PoolStats {
@@ -0,0 +1,159 @@
+package api
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"strings"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+const tagIncidents = "incidents"
+
+func registerIncidents(api huma.API, s *State) {
+	huma.Register(api, huma.Operation{
+		OperationID: "listIncidents", Method: http.MethodGet, Path: "/incidents",
+		Summary: "List incidents (warnings grouped by fingerprint)", Tags: []string{tagIncidents}, DefaultStatus: http.StatusOK,
+	}, s.listIncidents)
+	huma.Register(api, huma.Operation{
+		OperationID: "getIncident", Method: http.MethodGet, Path: "/incidents/{id}",
+		Summary: "Get an incident", Tags: []string{tagIncidents}, DefaultStatus: http.StatusOK,
+	}, s.getIncident)
+	huma.Register(api, huma.Operation{
+		OperationID: "acknowledgeIncident", Method: http.MethodPost, Path: "/incidents/{id}/acknowledge",
+		Summary: "Acknowledge an incident and its warnings", Tags: []string{tagIncidents}, DefaultStatus: http.StatusOK,
+	}, s.acknowledgeIncident)
+	huma.Register(api, huma.Operation{
+		OperationID: "assignIncident", Method: http.MethodPost, Path: "/incidents/{id}/assign",
+		Summary: "Assign an incident", Tags: []string{tagIncidents}, DefaultStatus: http.StatusOK,
+	}, s.assignIncident)
+	huma.Register(api, huma.Operation{
+		OperationID: "resolveIncident", Method: http.MethodPost, Path: "/incidents/{id}/resolve",
+		Summary: "Resolve an incident, with an optional note", Tags: []string{tagIncidents}, DefaultStatus: http.StatusOK,
+	}, s.resolveIncident)
+	huma.Register(api, huma.Operation{
+		OperationID: "addIncidentNote", Method: http.MethodPost, Path: "/incidents/{id}/notes",
+		Summary: "Add an operator note to an incident", Tags: []string{tagIncidents}, DefaultStatus: http.StatusOK,
+	}, s.addIncidentNote)
+	huma.Register(api, huma.Operation{
+		OperationID: "addIncidentLink", Method: http.MethodPost, Path: "/incidents/{id}/links",
+		Summary: "Attach a link to an incident", Tags: []string{tagIncidents}, DefaultStatus: http.StatusOK,
+	}, s.addIncidentLink)
+	huma.Register(api, huma.Operation{
+		OperationID: "monitoringIncidents", Method: http.MethodGet, Path: "/monitoring/incidents",
+		Summary: "List incidents (dashboard alias)", Tags: []string{tagMonitoring}, DefaultStatus: http.StatusOK,
+	}, s.listIncidents)
+}
+
+type listIncidentsInput struct {
+	State    string `query:"state" doc:"OPEN, ACKNOWLEDGED, RESOLVED, or UNRESOLVED for OPEN and ACKNOWLEDGED"`
+	Category string `query:"category"`
+}
+
+type incidentsListOutput struct {
+	Body []router.Incident
+}
+
+func (s *State) listIncidents(_ context.Context, in *listIncidentsInput) (*incidentsListOutput, error) {
+	state := strings.ToUpper(in.State)
+	all := s.Warnings.Incidents()
+	out := make([]router.Incident, 0, len(all))
+	for _, inc := range all {
+		switch state {
+		case "":
+		case "UNRESOLVED":
+			if inc.State == router.IncidentResolved {
+				continue
+			}
+		default:
+			if string(inc.State) != state {
+				continue
+			}
+		}
+		if in.Category != "" && !strings.EqualFold(string(inc.Category), in.Category) {
+			continue
+		}
+		out = append(out, inc)
+	}
+	return &incidentsListOutput{Body: out}, nil
+}
+
+type incidentIDInput struct {
+	ID string `path:"id"`
+}
+
+type incidentOutput struct {
+	Body router.Incident
+}
+
+func (s *State) getIncident(_ context.Context, in *incidentIDInput) (*incidentOutput, error) {
+	inc, ok := s.Warnings.Incident(in.ID)
+	if !ok {
+		return nil, huma.Error404NotFound("Incident not found: " + in.ID)
+	}
+	return &incidentOutput{Body: inc}, nil
+}
+
+type acknowledgeIncidentInput struct {
+	ID   string `path:"id"`
+	Body IncidentAcknowledgeRequest
+}
+
+func (s *State) acknowledgeIncident(_ context.Context, in *acknowledgeIncidentInput) (*incidentOutput, error) {
+	return incidentResult(in.ID)(s.Warnings.AcknowledgeIncident(in.ID, in.Body.By))
+}
+
+type assignIncidentInput struct {
+	ID   string `path:"id"`
+	Body IncidentAssignRequest
+}
+
+func (s *State) assignIncident(_ context.Context, in *assignIncidentInput) (*incidentOutput, error) {
+	return incidentResult(in.ID)(s.Warnings.AssignIncident(in.ID, in.Body.Assignee))
+}
+
+type resolveIncidentInput struct {
+	ID   string `path:"id"`
+	Body IncidentResolveRequest
+}
+
+func (s *State) resolveIncident(_ context.Context, in *resolveIncidentInput) (*incidentOutput, error) {
+	return incidentResult(in.ID)(s.Warnings.ResolveIncident(in.ID, in.Body.By, in.Body.Note))
+}
+
+type addIncidentNoteInput struct {
+	ID   string `path:"id"`
+	Body IncidentNoteRequest
+}
+
+func (s *State) addIncidentNote(_ context.Context, in *addIncidentNoteInput) (*incidentOutput, error) {
+	return incidentResult(in.ID)(s.Warnings.AddIncidentNote(in.ID, in.Body.Author, in.Body.Text))
+}
+
+type addIncidentLinkInput struct {
+	ID   string `path:"id"`
+	Body IncidentLinkRequest
+}
+
+func (s *State) addIncidentLink(_ context.Context, in *addIncidentLinkInput) (*incidentOutput, error) {
+	return incidentResult(in.ID)(s.Warnings.AddIncidentLink(in.ID, in.Body.Title, in.Body.URL))
+}
+
+// incidentResult maps a WarningService incident mutation onto the HTTP
+// response: 404 for an unknown id, 409 for acting on a resolved incident.
+func incidentResult(id string) func(router.Incident, error) (*incidentOutput, error) {
+	return func(inc router.Incident, err error) (*incidentOutput, error) {
+		switch {
+		case errors.Is(err, router.ErrIncidentNotFound):
+			return nil, huma.Error404NotFound("Incident not found: " + id)
+		case errors.Is(err, router.ErrIncidentResolved):
+			return nil, huma.Error409Conflict("Incident already resolved: " + id)
+		case err != nil:
+			return nil, huma.Error500InternalServerError(err.Error())
+		}
+		return &incidentOutput{Body: inc}, nil
+	}
+}
@@ -4,11 +4,16 @@ import (
 	"context"
 	"log/slog"
 	"net/http"
+	"sort"
+	"sync"
+	"time"
 
 	"github.com/danielgtaylor/huma/v2"
 	"github.com/google/uuid"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
 )
 
 const tagMessages = "messages"
@@ -17,12 +22,25 @@ func registerMessages(api huma.API, s *State) {
 	huma.Register(api, huma.Operation{
 		OperationID: "publishMessage", Method: http.MethodPost, Path: "/messages",
 		Summary:       "Publish a message to a pool's queue",
-		Description:   "Looks up the queue config bound to PoolCode and publishes via the matching backend (SQS / Postgres / ...). Reuses the same broker the consumer reads from.",
+		Description:   "Looks up the queue config bound to PoolCode, or the configured queue named by queue, and publishes via the matching backend (SQS / Postgres / ...). Reuses the same broker the consumer reads from.",
 		Tags:          []string{tagMessages},
 		DefaultStatus: http.StatusCreated,
 	}, s.publishMessage)
+	huma.Register(api, huma.Operation{
+		OperationID: "publishMessageBatch", Method: http.MethodPost, Path: "/messages/batch",
+		Summary:      "Publish up to 1000 messages",
+		Description:  "Validates each message, then publishes them per pool with the backend's batch publish. Returns a result per message, in request order; one bad message does not fail the others.",
+		Tags:         []string{tagMessages},
+		MaxBodyBytes: maxBatchBodyBytes,
+	}, s.publishMessageBatch)
 }
 
+// Batch publish limits: item count and request body size.
+const (
+	MaxBatchItems     = 1000
+	maxBatchBodyBytes = 5 << 20
+)
+
 type publishMessageInput struct {
 	Body PublishMessageRequest
 }
@@ -34,26 +52,44 @@ type publishMessageOutput struct {
 // publishMessage is the POST /messages handler. The caller supplies a
 // PoolCode + the message body; the router looks up the pool's queue
 // config (via Manager.QueueConfig) and publishes through a cached
-// Publisher for that backend.
+// Publisher for that backend. Without a PoolCode the routing rules pick
+// the pool (and possibly the queue). A Queue names the configured queue
+// outright, and must exist.
 func (s *State) publishMessage(ctx context.Context, in *publishMessageInput) (*publishMessageOutput, error) {
 	if s.Publisher == nil {
 		return nil, notConfigured("publisher")
 	}
 	req := in.Body
+	queueKey := s.route(&req)
 	if err := req.validate(); err != nil {
 		return nil, huma.Error400BadRequest(err.Error())
 	}
+	if err := s.checkQueue(req.Queue); err != nil {
+		return nil, err
+	}
+	if !s.allowPublish(1) {
+		return nil, huma.Error429TooManyRequests("publish rate limit exceeded")
+	}
 
 	msg := req.toMessage()
 	if msg.ID == "" {
 		msg.ID = uuid.NewString()
 	}
 
-	pub, err := s.Publisher.Publisher(ctx, msg.PoolCode)
+	pub, err := s.Publisher.Publisher(ctx, queueKey)
 	if err != nil {
 		return nil, huma.Error502BadGateway("publisher: " + err.Error())
 	}
-	brokerID, err := pub.Publish(ctx, msg)
+	deliverAt := req.deliverAt(time.Now())
+	var brokerID string
+	if deliverAt == nil {
+		brokerID, err = pub.Publish(ctx, msg)
+	} else if sp, ok := pub.(queue.ScheduledPublisher); ok {
+		brokerID, err = sp.PublishAt(ctx, msg, *deliverAt)
+	} else {
+		return nil, huma.Error400BadRequest("the queue for " + queueKey + " does not support scheduled delivery")
+	}
+	s.published.add(pub.Identifier(), err == nil, 1)
 	if err != nil {
 		slog.Warn("publish failed", "pool", msg.PoolCode, "msg_id", msg.ID, "err", err)
 		return nil, huma.Error502BadGateway("publish: " + err.Error())
@@ -64,6 +100,7 @@ func (s *State) publishMessage(ctx context.Context, in *publishMessageInput) (*p
 		BrokerMessageID: brokerID,
 		PoolCode:        msg.PoolCode,
 		QueueIdentifier: pub.Identifier(),
+		DeliverAt:       deliverAt,
 	}}, nil
 }
 
@@ -105,15 +142,263 @@ func (r PublishMessageRequest) toMessage() common.Message {
 		MessageGroupID:  msgGroup,
 		HighPriority:    r.HighPriority,
 		DispatchMode:    dispatchMode,
+		ExpiresAt:       r.ExpiresAt,
 	}
 }
 
 func (r PublishMessageRequest) validate() error {
 	if r.PoolCode == "" {
-		return huma.Error400BadRequest("poolCode is required")
+		return huma.Error400BadRequest("poolCode is required when no routing rule matches")
 	}
 	if r.MediationTarget == "" {
 		return huma.Error400BadRequest("mediationTarget is required")
 	}
+	if r.DeliverAt != nil && r.DelaySeconds != 0 {
+		return huma.Error400BadRequest("deliver_at and delay_seconds are mutually exclusive")
+	}
+	if r.DelaySeconds < 0 || time.Duration(r.DelaySeconds)*time.Second > maxScheduleAhead {
+		return huma.Error400BadRequest("delay_seconds must be between 0 and 604800")
+	}
+	if r.DeliverAt != nil && time.Until(*r.DeliverAt) > maxScheduleAhead {
+		return huma.Error400BadRequest("deliver_at must be at most 7 days ahead")
+	}
+	if r.ExpiresAt != nil {
+		if !r.ExpiresAt.After(time.Now()) {
+			return huma.Error400BadRequest("expires_at must be in the future")
+		}
+		if at := r.deliverAt(time.Now()); at != nil && !r.ExpiresAt.After(*at) {
+			return huma.Error400BadRequest("expires_at must be after the scheduled delivery time")
+		}
+	}
 	return nil
 }
+
+// maxScheduleAhead bounds scheduled delivery: NATS streams drop messages
+// older than their max age (7 days by default), so a later schedule could
+// silently never arrive.
+const maxScheduleAhead = 7 * 24 * time.Hour
+
+// deliverAt resolves the request's schedule, or nil for immediate delivery
+// (no schedule, or a deliver_at already in the past).
+func (r PublishMessageRequest) deliverAt(now time.Time) *time.Time {
+	var at time.Time
+	switch {
+	case r.DeliverAt != nil:
+		at = *r.DeliverAt
+	case r.DelaySeconds > 0:
+		at = now.Add(time.Duration(r.DelaySeconds) * time.Second)
+	}
+	if !at.After(now) {
+		return nil
+	}
+	return &at
+}
+
+type publishMessageBatchInput struct {
+	Body PublishMessageBatchRequest
+}
+
+type publishMessageBatchOutput struct {
+	Body PublishMessageBatchResponse
+}
+
+// publishMessageBatch is the POST /messages/batch handler. Messages are
+// routed like POST /messages, grouped by queue, and each group goes
+// through its publisher in one PublishBatch call; scheduled messages are
+// published one at a time with PublishAt. PublishBatch reports a failure for the whole call, so a
+// failed group is retried message by message to give each its own
+// result — messages that had landed may then be published twice, which
+// delivery is at-least-once about anyway.
+func (s *State) publishMessageBatch(ctx context.Context, in *publishMessageBatchInput) (*publishMessageBatchOutput, error) {
+	if s.Publisher == nil {
+		return nil, notConfigured("publisher")
+	}
+	reqs := in.Body.Messages
+	if !s.allowPublish(len(reqs)) {
+		return nil, huma.Error429TooManyRequests("publish rate limit exceeded")
+	}
+
+	now := time.Now()
+	results := make([]PublishMessageBatchResult, len(reqs))
+	msgs := make([]common.Message, len(reqs))
+	var queueKeys []string
+	byQueue := make(map[string][]int)
+	for i, req := range reqs {
+		results[i].Index = i
+		key := s.route(&req)
+		if err := req.validate(); err != nil {
+			results[i].Error = err.Error()
+			continue
+		}
+		if err := s.checkQueue(req.Queue); err != nil {
+			results[i].Error = err.Error()
+			continue
+		}
+		msgs[i] = req.toMessage()
+		if msgs[i].ID == "" {
+			msgs[i].ID = uuid.NewString()
+		}
+		results[i].MessageID = msgs[i].ID
+		results[i].PoolCode = msgs[i].PoolCode
+		results[i].DeliverAt = req.deliverAt(now)
+		if _, ok := byQueue[key]; !ok {
+			queueKeys = append(queueKeys, key)
+		}
+		byQueue[key] = append(byQueue[key], i)
+	}
+
+	for _, key := range queueKeys {
+		idx := byQueue[key]
+		pub, err := s.Publisher.Publisher(ctx, key)
+		if err != nil {
+			for _, i := range idx {
+				results[i].Error = "publisher: " + err.Error()
+			}
+			continue
+		}
+		var immediate []int
+		for _, i := range idx {
+			results[i].QueueIdentifier = pub.Identifier()
+			if results[i].DeliverAt == nil {
+				immediate = append(immediate, i)
+				continue
+			}
+			sp, ok := pub.(queue.ScheduledPublisher)
+			if !ok {
+				results[i].Error = "the queue for " + key + " does not support scheduled delivery"
+				continue
+			}
+			results[i].setPublished(sp.PublishAt(ctx, msgs[i], *results[i].DeliverAt))
+			s.published.add(pub.Identifier(), results[i].Error == "", 1)
+		}
+		if len(immediate) == 0 {
+			continue
+		}
+		batch := make([]common.Message, len(immediate))
+		for j, i := range immediate {
+			batch[j] = msgs[i]
+		}
+		ids, err := pub.PublishBatch(ctx, batch)
+		if err == nil && len(ids) == len(batch) {
+			for j, i := range immediate {
+				results[i].setPublished(ids[j], nil)
+			}
+			s.published.add(pub.Identifier(), true, len(batch))
+			continue
+		}
+		slog.Warn("batch publish failed, retrying per message", "queue", key, "messages", len(batch), "err", err)
+		for _, i := range immediate {
+			results[i].setPublished(pub.Publish(ctx, msgs[i]))
+			s.published.add(pub.Identifier(), results[i].Error == "", 1)
+		}
+	}
+
+	out := PublishMessageBatchResponse{Results: results}
+	for _, r := range results {
+		if r.Error == "" {
+			out.Published++
+		} else {
+			out.Failed++
+		}
+	}
+	slog.Info("message batch published", "published", out.Published, "failed", out.Failed)
+	return &publishMessageBatchOutput{Body: out}, nil
+}
+
+func (r *PublishMessageBatchResult) setPublished(brokerID string, err error) {
+	if err != nil {
+		r.Error = "publish: " + err.Error()
+		return
+	}
+	r.BrokerMessageID = brokerID
+}
+
+// route fills in req's pool code from the first matching routing rule
+// when the producer didn't set one, and returns the key to resolve its
+// publisher with: the request's queue if it names one, else the rule's
+// queue if it names one, else the pool code.
+func (s *State) route(req *PublishMessageRequest) string {
+	key := s.routePool(req)
+	if req.Queue != "" {
+		return req.Queue
+	}
+	return key
+}
+
+// routePool is route without the request's queue override.
+func (s *State) routePool(req *PublishMessageRequest) string {
+	if req.PoolCode != "" || s.Routing == nil {
+		return req.PoolCode
+	}
+	in := router.RoutingInputForTarget(req.MediationTarget)
+	in.EventType, in.ClientID, in.Attributes = req.EventType, req.ClientID, req.Attributes
+	r, ok := s.Routing.Route(in)
+	if !ok {
+		return ""
+	}
+	req.PoolCode = r.PoolCode
+	if r.QueueName != "" {
+		return r.QueueName
+	}
+	return r.PoolCode
+}
+
+// checkQueue refuses a queue the router has no config for; publishing by
+// pool code falls back to any queue, but a named queue must be exact.
+func (s *State) checkQueue(name string) error {
+	if name == "" || s.Publisher.HasQueue(name) {
+		return nil
+	}
+	return huma.Error400BadRequest("queue " + name + " is not configured")
+}
+
+// allowPublish takes n messages from the publish rate limit, if one is set.
+func (s *State) allowPublish(n int) bool {
+	return s.PublishLimit == nil || s.PublishLimit.AllowN(time.Now(), n)
+}
+
+// publishCounters counts publish outcomes by queue identifier. The zero
+// value is ready to use.
+type publishCounters struct {
+	mu     sync.Mutex
+	counts map[string]*publishCount
+}
+
+type publishCount struct{ published, failed uint64 }
+
+// queuePublishCount is one queue's totals, as exported to /metrics.
+type queuePublishCount struct {
+	Queue     string
+	Published uint64
+	Failed    uint64
+}
+
+func (c *publishCounters) add(queueID string, ok bool, n int) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	if c.counts == nil {
+		c.counts = make(map[string]*publishCount)
+	}
+	pc := c.counts[queueID]
+	if pc == nil {
+		pc = &publishCount{}
+		c.counts[queueID] = pc
+	}
+	if ok {
+		pc.published += uint64(n)
+	} else {
+		pc.failed += uint64(n)
+	}
+}
+
+// snapshot returns every queue's totals, sorted by queue.
+func (c *publishCounters) snapshot() []queuePublishCount {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	out := make([]queuePublishCount, 0, len(c.counts))
+	for q, pc := range c.counts {
+		out = append(out, queuePublishCount{Queue: q, Published: pc.published, Failed: pc.failed})
+	}
+	sort.Slice(out, func(i, j int) bool { return out[i].Queue < out[j].Queue })
+	return out
+}
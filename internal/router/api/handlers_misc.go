@@ -2,13 +2,16 @@ package api
 
 import (
 	"context"
+	"errors"
 	"log/slog"
 	"net/http"
+	"time"
 
 	"github.com/danielgtaylor/huma/v2"
 	"github.com/google/uuid"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
 )
 
 const (
@@ -27,6 +30,14 @@ func registerMisc(api huma.API, s *State) {
 		OperationID: "configReload", Method: http.MethodPost, Path: "/config/reload",
 		Summary: "Trigger a config refresh", Tags: []string{tagConfig}, DefaultStatus: http.StatusOK,
 	}, s.configReload)
+	huma.Register(api, huma.Operation{
+		OperationID: "configHistoryDiff", Method: http.MethodGet, Path: "/config/history/diff",
+		Summary: "What changed in the synced config between two times", Tags: []string{tagConfig}, DefaultStatus: http.StatusOK,
+	}, s.configHistoryDiff)
+	huma.Register(api, huma.Operation{
+		OperationID: "configHistoryAt", Method: http.MethodGet, Path: "/config/history/{timestamp}",
+		Summary: "The synced config in force at a time", Tags: []string{tagConfig}, DefaultStatus: http.StatusOK,
+	}, s.configHistoryAt)
 	huma.Register(api, huma.Operation{
 		OperationID: "seedMessages", Method: http.MethodPost, Path: "/api/seed/messages",
 		Summary: "Bulk publish synthetic messages (dev only)", Tags: []string{tagSeed}, DefaultStatus: http.StatusOK,
@@ -160,6 +171,77 @@ func (s *State) configReload(ctx context.Context, _ *emptyInput) (*configReloadO
 	return &configReloadOutput{Body: ConfigReloadResponse{Success: true}}, nil
 }
 
+type configHistoryAtInput struct {
+	Timestamp string `path:"timestamp" doc:"RFC 3339 time"`
+}
+
+type configHistoryAtOutput struct {
+	Body ConfigSnapshotResponse
+}
+
+func (s *State) configHistoryAt(ctx context.Context, in *configHistoryAtInput) (*configHistoryAtOutput, error) {
+	snap, err := s.snapshotAt(ctx, "timestamp", in.Timestamp)
+	if err != nil {
+		return nil, err
+	}
+	return &configHistoryAtOutput{Body: ConfigSnapshotResponse{
+		ConfigSnapshotSummary: snapshotSummary(snap),
+		Config:                snap.Config,
+	}}, nil
+}
+
+type configHistoryDiffInput struct {
+	From string `query:"from" required:"true" doc:"RFC 3339 time"`
+	To   string `query:"to" required:"true" doc:"RFC 3339 time"`
+}
+
+type configHistoryDiffOutput struct {
+	Body ConfigDiffResponse
+}
+
+func (s *State) configHistoryDiff(ctx context.Context, in *configHistoryDiffInput) (*configHistoryDiffOutput, error) {
+	from, err := s.snapshotAt(ctx, "from", in.From)
+	if err != nil {
+		return nil, err
+	}
+	to, err := s.snapshotAt(ctx, "to", in.To)
+	if err != nil {
+		return nil, err
+	}
+	diff := router.DiffConfigs(from.Config, to.Config)
+	return &configHistoryDiffOutput{Body: ConfigDiffResponse{
+		From:         snapshotSummary(from),
+		To:           snapshotSummary(to),
+		Pools:        diff.Pools,
+		Queues:       diff.Queues,
+		RoutingRules: diff.RoutingRules,
+	}}, nil
+}
+
+// snapshotAt looks up the snapshot in force at raw, the value of the
+// named parameter.
+func (s *State) snapshotAt(ctx context.Context, name, raw string) (router.ConfigSnapshot, error) {
+	if s.ConfigHistory == nil {
+		return router.ConfigSnapshot{}, notConfigured("config history")
+	}
+	t, err := time.Parse(time.RFC3339Nano, raw)
+	if err != nil {
+		return router.ConfigSnapshot{}, huma.Error400BadRequest(name + ": want an RFC 3339 time, e.g. 2026-01-02T15:04:05Z")
+	}
+	snap, err := s.ConfigHistory.At(ctx, t)
+	if errors.Is(err, router.ErrNoConfigSnapshot) {
+		return router.ConfigSnapshot{}, huma.Error404NotFound(name + ": no config snapshot at or before " + raw)
+	}
+	if err != nil {
+		return router.ConfigSnapshot{}, huma.Error500InternalServerError("config history: " + err.Error())
+	}
+	return snap, nil
+}
+
+func snapshotSummary(s router.ConfigSnapshot) ConfigSnapshotSummary {
+	return ConfigSnapshotSummary{Hash: s.Hash, Source: s.Source, TakenAt: s.TakenAt, LastSeenAt: s.LastSeenAt}
+}
+
 type seedMessagesInput struct {
 	Body SeedMessagesRequest
 }
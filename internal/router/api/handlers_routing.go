@@ -0,0 +1,101 @@
+package api
+
+import (
+	"context"
+	"log/slog"
+	"net/http"
+	"slices"
+	"sync"
+
+	"github.com/danielgtaylor/huma/v2"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+const tagRouting = "routing"
+
+func registerRouting(api huma.API, s *State) {
+	huma.Register(api, huma.Operation{
+		OperationID: "listRoutingRules", Method: http.MethodGet, Path: "/api/admin/routing-rules",
+		Summary: "List content-based routing rules in evaluation order", Tags: []string{tagRouting},
+	}, s.listRoutingRules)
+	huma.Register(api, huma.Operation{
+		OperationID: "putRoutingRule", Method: http.MethodPut, Path: "/api/admin/routing-rules/{name}",
+		Summary:     "Create or replace a routing rule",
+		Description: "Runtime edit: like a pool update, it lasts until config sync applies the next config change.",
+		Tags:        []string{tagRouting},
+	}, s.putRoutingRule)
+	huma.Register(api, huma.Operation{
+		OperationID: "deleteRoutingRule", Method: http.MethodDelete, Path: "/api/admin/routing-rules/{name}",
+		Summary: "Delete a routing rule", Tags: []string{tagRouting}, DefaultStatus: http.StatusNoContent,
+	}, s.deleteRoutingRule)
+}
+
+// routingMu serialises read-modify-write edits of the rule set.
+var routingMu sync.Mutex
+
+type routingRulesOutput struct {
+	Body RoutingRuleListResponse
+}
+
+func (s *State) listRoutingRules(_ context.Context, _ *emptyInput) (*routingRulesOutput, error) {
+	if s.Routing == nil {
+		return nil, notConfigured("routing rules")
+	}
+	return s.routingRulesOut(), nil
+}
+
+type putRoutingRuleInput struct {
+	Name string `path:"name"`
+	Body RoutingRuleRequest
+}
+
+func (s *State) putRoutingRule(_ context.Context, in *putRoutingRuleInput) (*routingRulesOutput, error) {
+	if s.Routing == nil {
+		return nil, notConfigured("routing rules")
+	}
+	rule := in.Body.toRule(in.Name)
+	routingMu.Lock()
+	defer routingMu.Unlock()
+	rules := s.Routing.RoutingRules()
+	if i := slices.IndexFunc(rules, func(r common.RoutingRule) bool { return r.Name == rule.Name }); i >= 0 {
+		rules[i] = rule
+	} else {
+		rules = append(rules, rule)
+	}
+	if err := s.Routing.SetRoutingRules(rules); err != nil {
+		return nil, huma.Error400BadRequest(err.Error())
+	}
+	slog.Info("routing rule set via API", "rule", rule.Name, "pool", rule.PoolCode, "queue", rule.QueueName)
+	return s.routingRulesOut(), nil
+}
+
+type deleteRoutingRuleInput struct {
+	Name string `path:"name"`
+}
+
+func (s *State) deleteRoutingRule(_ context.Context, in *deleteRoutingRuleInput) (*struct{}, error) {
+	if s.Routing == nil {
+		return nil, notConfigured("routing rules")
+	}
+	routingMu.Lock()
+	defer routingMu.Unlock()
+	rules := s.Routing.RoutingRules()
+	i := slices.IndexFunc(rules, func(r common.RoutingRule) bool { return r.Name == in.Name })
+	if i < 0 {
+		return nil, huma.Error404NotFound("routing rule not found: " + in.Name)
+	}
+	if err := s.Routing.SetRoutingRules(slices.Delete(rules, i, i+1)); err != nil {
+		return nil, huma.Error400BadRequest(err.Error())
+	}
+	slog.Info("routing rule deleted via API", "rule", in.Name)
+	return &struct{}{}, nil
+}
+
+func (s *State) routingRulesOut() *routingRulesOutput {
+	rules := s.Routing.RoutingRules()
+	if rules == nil {
+		rules = []common.RoutingRule{}
+	}
+	return &routingRulesOutput{Body: RoutingRuleListResponse{Rules: rules}}
+}
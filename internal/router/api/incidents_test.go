@@ -0,0 +1,65 @@
+package api_test
+
+import (
+	"net/http"
+	"testing"
+
+	"github.com/danielgtaylor/huma/v2/humatest"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+	routerapi "github.com/flowcatalyst/flowcatalyst-go/internal/router/api"
+)
+
+func TestIncidents_Lifecycle(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	for i := 0; i < 3; i++ {
+		ws.Add(router.WarningCategoryCircuitBreak, router.WarningError, "breaker open for target-a", "cb")
+	}
+	ws.Add(router.WarningCategoryRouting, router.WarningWarning, "no pool for target", "router")
+
+	_, api := humatest.New(t)
+	routerapi.Register(api, &routerapi.State{Warnings: ws, Mocks: routerapi.NewMockState()})
+
+	resp := api.Get("/monitoring/incidents?category=circuit_breaker")
+	if resp.Code != http.StatusOK {
+		t.Fatalf("status %d body=%s", resp.Code, resp.Body.String())
+	}
+	var list []router.Incident
+	decodeBody(t, resp.Body.Bytes(), &list)
+	if len(list) != 1 || list[0].Count != 3 || list[0].State != router.IncidentOpen {
+		t.Fatalf("incidents = %+v, want one OPEN circuit breaker incident with count 3", list)
+	}
+	id := list[0].ID
+
+	if resp := api.Post("/incidents/"+id+"/assign", map[string]any{"assignee": "bob"}); resp.Code != http.StatusOK {
+		t.Fatalf("assign: status %d body=%s", resp.Code, resp.Body.String())
+	}
+	if resp := api.Post("/incidents/"+id+"/links", map[string]any{"title": "runbook", "url": "https://wiki.example/cb"}); resp.Code != http.StatusOK {
+		t.Fatalf("link: status %d body=%s", resp.Code, resp.Body.String())
+	}
+	resp = api.Post("/incidents/"+id+"/resolve", map[string]any{"by": "bob", "note": "target redeployed"})
+	if resp.Code != http.StatusOK {
+		t.Fatalf("resolve: status %d body=%s", resp.Code, resp.Body.String())
+	}
+	var inc router.Incident
+	decodeBody(t, resp.Body.Bytes(), &inc)
+	if inc.State != router.IncidentResolved || inc.Assignee != "bob" || len(inc.Links) != 1 || len(inc.Notes) != 1 {
+		t.Fatalf("resolved incident = %+v", inc)
+	}
+	if got := ws.UnacknowledgedCount(); got != 1 {
+		t.Errorf("unacknowledged warnings = %d, want only the routing one", got)
+	}
+
+	if resp := api.Post("/incidents/"+id+"/acknowledge", map[string]any{}); resp.Code != http.StatusConflict {
+		t.Errorf("acknowledge resolved: status %d, want 409", resp.Code)
+	}
+	if resp := api.Get("/incidents/nope"); resp.Code != http.StatusNotFound {
+		t.Errorf("unknown id: status %d, want 404", resp.Code)
+	}
+
+	resp = api.Get("/incidents?state=unresolved")
+	decodeBody(t, resp.Body.Bytes(), &list)
+	if len(list) != 1 || list[0].Category != router.WarningCategoryRouting {
+		t.Errorf("unresolved incidents = %+v, want only the routing one", list)
+	}
+}
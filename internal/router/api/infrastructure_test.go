@@ -0,0 +1,62 @@
+package api_test
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"testing"
+
+	"github.com/danielgtaylor/huma/v2/humatest"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+	routerapi "github.com/flowcatalyst/flowcatalyst-go/internal/router/api"
+)
+
+type stubInfrastructure []router.InfrastructureCheck
+
+func (s stubInfrastructure) Infrastructure(ctx context.Context) router.InfrastructureHealth {
+	return router.CheckInfrastructure(ctx, s)
+}
+
+func TestMonitoringInfrastructure(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	hs := router.NewHealthService(router.DefaultHealthServiceConfig(), ws)
+	_, api := humatest.New(t)
+	routerapi.Register(api, &routerapi.State{
+		Warnings: ws, Health: hs, Mocks: routerapi.NewMockState(),
+		Infrastructure: stubInfrastructure{
+			{Name: "standby", Kind: "redis", Ping: func(context.Context) error { return nil }},
+			{Name: "outbox", Kind: "mongo", Ping: func(context.Context) error { return errors.New("server selection timeout") }},
+		},
+	})
+
+	resp := api.Get("/monitoring/infrastructure")
+	if resp.Code != http.StatusOK {
+		t.Fatalf("status %d", resp.Code)
+	}
+	var body router.InfrastructureHealth
+	decodeBody(t, resp.Body.Bytes(), &body)
+	if body.Healthy || len(body.Components) != 2 {
+		t.Fatalf("body = %+v", body)
+	}
+	if c := body.Components[0]; c.Name != "standby" || c.Kind != "redis" || !c.Connected {
+		t.Errorf("redis = %+v", c)
+	}
+	if c := body.Components[1]; c.Connected || c.Error != "server selection timeout" {
+		t.Errorf("mongo = %+v", c)
+	}
+}
+
+func TestMonitoringInfrastructure_NotConfigured(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	hs := router.NewHealthService(router.DefaultHealthServiceConfig(), ws)
+	_, api := humatest.New(t)
+	routerapi.Register(api, &routerapi.State{Warnings: ws, Health: hs, Mocks: routerapi.NewMockState()})
+
+	resp := api.Get("/monitoring/infrastructure")
+	var body router.InfrastructureHealth
+	decodeBody(t, resp.Body.Bytes(), &body)
+	if resp.Code != http.StatusOK || !body.Healthy || body.Components == nil || len(body.Components) != 0 {
+		t.Fatalf("got %d %+v", resp.Code, body)
+	}
+}
@@ -21,6 +21,9 @@ import (
 //   - fc_pool_queue_size, fc_pool_active_workers, fc_pool_message_groups (gauges)
 //   - fc_messages_processed_total{success}                              (counter)
 //   - fc_rate_limit_exceeded_total                                      (counter)
+//   - fc_messages_expired_total                                         (counter, Go-only)
+//   - fc_pool_group_state_bytes                                         (gauge, Go-only)
+//   - fc_pool_group_evictions_total                                     (counter, Go-only)
 //   - fc_mediation_duration_seconds                                     (histogram)
 //
 // Global:
@@ -29,15 +32,31 @@ import (
 // Per queue/consumer:
 //   - fc_queue_pending_messages, fc_queue_in_flight_messages           (gauges)
 //   - fc_consumer_messages_received_total{consumer}                    (counter)
+//   - fc_consumer_polls_total{consumer,outcome=messages|empty}         (counter)
+//   - fc_consumer_idle_backoff_seconds{consumer}                       (gauge, Go-only)
 //   - fc_queue_messages_total{queue,outcome=acked|nacked|deferred}     (counter)
+//   - fc_messages_published_total{queue,outcome=published|failed}      (counter, Go-only)
 //
 // Circuit breaker (label: target):
 //   - fc_circuit_breaker_open                                          (gauge)
 //   - fc_circuit_breaker_calls_total{outcome=success|failure}          (counter)
 //
+// Autoscaling signals (Go-only; see ScaleConfig):
+//   - fc_queue_backlog_messages{queue} (pending + in-flight)           (gauge)
+//   - fc_pool_saturation_ratio{pool} (active workers / concurrency)    (gauge)
+//   - fc_scale_desired_replicas                                        (gauge)
+//
+// Scheduling fairness (Go-only; see router/cpu_isolation.go):
+//   - fc_pool_schedule_delay_seconds{pool}                             (histogram)
+//   - fc_pool_cpu_wait_seconds{pool} (pools with CPU slots)            (histogram)
+//
+// Delivery-attempt sink (Go-only; only when a sink is configured):
+//   - fc_attempt_sink_records_total{outcome=recorded|written|dropped|failed} (counter)
+//   - fc_attempt_sink_buffered                                         (gauge)
+//
 // Note (Rust parity gap, dashboards only): Rust additionally emits
 // fc_messages_submitted_total, fc_messages_rejected_total{reason},
-// fc_consumer_polls_total / fc_consumer_errors_total{type}, the `result`
+// fc_consumer_errors_total{type}, the `result`
 // label on fc_messages_processed_total, and flowcatalyst_broker_*. Those are
 // event-time labeled counters the Go pull-based collector does not currently
 // track; emitting them faithfully needs the metrics collector reworked to the
@@ -62,8 +81,12 @@ func (c *routerCollector) Describe(_ chan<- *prometheus.Desc) {}
 func (c *routerCollector) Collect(ch chan<- prometheus.Metric) {
 	c.collectPools(ch)
 	c.collectQueues(ch)
+	c.collectPublishes(ch)
+	c.collectConsumerPolls(ch)
 	c.collectBreakers(ch)
 	c.collectInFlight(ch)
+	c.collectAttemptSink(ch)
+	c.collectScale(ch)
 }
 
 func (c *routerCollector) collectPools(ch chan<- prometheus.Metric) {
@@ -82,6 +105,15 @@ func (c *routerCollector) collectPools(ch chan<- prometheus.Metric) {
 		gauge(ch, "fc_pool_message_groups",
 			"Distinct message groups currently holding buffered work.",
 			float64(s.MessageGroupCount), poolLabel, lv)
+		gauge(ch, "fc_pool_group_state_bytes",
+			"Estimated memory held by the pool's message-group state.",
+			float64(s.GroupStateBytes), poolLabel, lv)
+		counter(ch, "fc_pool_group_evictions_total",
+			"Cumulative idle message groups evicted, their messages released to the broker.",
+			float64(s.GroupEvictions), poolLabel, lv)
+		gauge(ch, "fc_pool_saturation_ratio",
+			"Share of the pool's workers currently busy (active / concurrency).",
+			saturation(s.ActiveWorkers, s.Concurrency), poolLabel, lv)
 
 		if s.Metrics != nil {
 			m := s.Metrics
@@ -94,20 +126,19 @@ func (c *routerCollector) collectPools(ch chan<- prometheus.Metric) {
 			counter(ch, "fc_rate_limit_exceeded_total",
 				"Cumulative rate-limit events.",
 				float64(m.TotalRateLimited), poolLabel, lv)
+			counter(ch, "fc_messages_expired_total",
+				"Cumulative messages dropped undelivered because their expiresAt had passed.",
+				float64(m.TotalExpired), poolLabel, lv)
 		}
 
-		// fc_mediation_duration_seconds — cumulative histogram.
-		h := s.Histogram
-		if len(h.Bounds) > 0 {
-			buckets := make(map[float64]uint64, len(h.Bounds))
-			for i, b := range h.Bounds {
-				if i < len(h.Counts) {
-					buckets[b] = h.Counts[i]
-				}
-			}
-			desc := prometheus.NewDesc("fc_mediation_duration_seconds",
-				"Mediation latency in seconds.", poolLabel, nil)
-			ch <- prometheus.MustNewConstHistogram(desc, h.Count, h.SumSeconds, buckets, lv...)
+		histogram(ch, "fc_mediation_duration_seconds",
+			"Mediation latency in seconds.", s.Histogram, poolLabel, lv)
+		histogram(ch, "fc_pool_schedule_delay_seconds",
+			"Delay between handing a message to a worker goroutine and the goroutine running.",
+			s.ScheduleDelay, poolLabel, lv)
+		if s.CPUWait.Count > 0 {
+			histogram(ch, "fc_pool_cpu_wait_seconds",
+				"Time deliveries waited for one of the pool's CPU slots.", s.CPUWait, poolLabel, lv)
 		}
 	}
 }
@@ -124,6 +155,9 @@ func (c *routerCollector) collectQueues(ch chan<- prometheus.Metric) {
 		gauge(ch, "fc_queue_in_flight_messages",
 			"Approximate messages currently being processed by consumers.",
 			float64(m.InFlightMessages), []string{"queue"}, []string{q})
+		gauge(ch, "fc_queue_backlog_messages",
+			"Pending plus in-flight messages: the backlog autoscalers size the fleet by.",
+			float64(m.PendingMessages+m.InFlightMessages), []string{"queue"}, []string{q})
 		counter(ch, "fc_consumer_messages_received_total",
 			"Cumulative messages received from the broker by this consumer.",
 			float64(m.TotalPolled), []string{"consumer"}, []string{q})
@@ -140,6 +174,36 @@ func (c *routerCollector) collectQueues(ch chan<- prometheus.Metric) {
 	}
 }
 
+func (c *routerCollector) collectPublishes(ch chan<- prometheus.Metric) {
+	for _, p := range c.state.published.snapshot() {
+		q := normaliseQueueID(p.Queue)
+		counter(ch, "fc_messages_published_total",
+			"Cumulative messages published through POST /messages and /messages/batch, by outcome.",
+			float64(p.Published), []string{"queue", "outcome"}, []string{q, "published"})
+		counter(ch, "fc_messages_published_total",
+			"Cumulative messages published through POST /messages and /messages/batch, by outcome.",
+			float64(p.Failed), []string{"queue", "outcome"}, []string{q, "failed"})
+	}
+}
+
+func (c *routerCollector) collectConsumerPolls(ch chan<- prometheus.Metric) {
+	if c.state.ConsumerPolls == nil {
+		return
+	}
+	for _, p := range c.state.ConsumerPolls.ConsumerPollStats() {
+		q := normaliseQueueID(p.Queue)
+		counter(ch, "fc_consumer_polls_total",
+			"Cumulative broker polls by outcome; on SQS each is a billed receive call.",
+			float64(p.Polls-p.EmptyPolls), []string{"consumer", "outcome"}, []string{q, "messages"})
+		counter(ch, "fc_consumer_polls_total",
+			"Cumulative broker polls by outcome; on SQS each is a billed receive call.",
+			float64(p.EmptyPolls), []string{"consumer", "outcome"}, []string{q, "empty"})
+		gauge(ch, "fc_consumer_idle_backoff_seconds",
+			"Longest pause the consumer's poll loops are in after empty polls; 0 while messages flow.",
+			p.IdleBackoff.Seconds(), []string{"consumer"}, []string{q})
+	}
+}
+
 func (c *routerCollector) collectBreakers(ch chan<- prometheus.Metric) {
 	if c.state.Breakers == nil {
 		return
@@ -170,12 +234,57 @@ func (c *routerCollector) collectInFlight(ch chan<- prometheus.Metric) {
 		float64(count), nil, nil)
 }
 
+func (c *routerCollector) collectAttemptSink(ch chan<- prometheus.Metric) {
+	if c.state.AttemptSink == nil {
+		return
+	}
+	st := c.state.AttemptSink.Stats()
+	for outcome, v := range map[string]uint64{
+		"recorded": st.Recorded,
+		"written":  st.Written,
+		"dropped":  st.Dropped,
+		"failed":   st.Failed,
+	} {
+		counter(ch, "fc_attempt_sink_records_total",
+			"Cumulative delivery-attempt records by outcome (dropped = buffer full, failed = write error).",
+			float64(v), []string{"outcome"}, []string{outcome})
+	}
+	gauge(ch, "fc_attempt_sink_buffered",
+		"Delivery-attempt records waiting to be shipped.",
+		float64(st.Buffered), nil, nil)
+}
+
+func (c *routerCollector) collectScale(ch chan<- prometheus.Metric) {
+	if c.state.BrokerStats == nil {
+		return
+	}
+	gauge(ch, "fc_scale_desired_replicas",
+		"Router replicas recommended for the current queue backlog (see /scale/recommendation).",
+		float64(c.state.scaleRecommendation().DesiredReplicas), nil, nil)
+}
+
 // gauge emits a single typed gauge metric.
 func gauge(ch chan<- prometheus.Metric, name, help string, value float64, labels, labelValues []string) {
 	desc := prometheus.NewDesc(name, help, labels, nil)
 	ch <- prometheus.MustNewConstMetric(desc, prometheus.GaugeValue, value, labelValues...)
 }
 
+// histogram emits a cumulative histogram snapshot; empty snapshots
+// (no bounds) are skipped.
+func histogram(ch chan<- prometheus.Metric, name, help string, h router.MediationHistogram, labels, labelValues []string) {
+	if len(h.Bounds) == 0 {
+		return
+	}
+	buckets := make(map[float64]uint64, len(h.Bounds))
+	for i, b := range h.Bounds {
+		if i < len(h.Counts) {
+			buckets[b] = h.Counts[i]
+		}
+	}
+	desc := prometheus.NewDesc(name, help, labels, nil)
+	ch <- prometheus.MustNewConstHistogram(desc, h.Count, h.SumSeconds, buckets, labelValues...)
+}
+
 // counter emits a single typed counter metric (cumulative).
 func counter(ch chan<- prometheus.Metric, name, help string, value float64, labels, labelValues []string) {
 	desc := prometheus.NewDesc(name, help, labels, nil)
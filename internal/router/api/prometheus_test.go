@@ -5,6 +5,7 @@ import (
 	"net/http/httptest"
 	"strings"
 	"testing"
+	"time"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
@@ -22,7 +23,7 @@ func TestPrometheusHandler_EmitsExpectedMetrics(t *testing.T) {
 		MessageGroupCount:  2,
 		RateLimitPerMinute: &rl,
 		Metrics: &common.EnhancedPoolMetrics{
-			TotalSuccess: 100, TotalFailure: 2, TotalRateLimited: 1,
+			TotalSuccess: 100, TotalFailure: 2, TotalRateLimited: 1, TotalExpired: 4,
 			SuccessRate: 100.0 / 102.0,
 			ProcessingTime: common.ProcessingTimeMetrics{
 				AvgMs: 25.0, P50Ms: 20, P95Ms: 80, P99Ms: 95, SampleCount: 102,
@@ -53,6 +54,7 @@ func TestPrometheusHandler_EmitsExpectedMetrics(t *testing.T) {
 		`fc_messages_processed_total{pool="demo",success="true"} 100`,
 		`fc_messages_processed_total{pool="demo",success="false"} 2`,
 		`fc_rate_limit_exceeded_total{pool="demo"} 1`,
+		`fc_messages_expired_total{pool="demo"} 4`,
 		`fc_mediation_duration_seconds_bucket{pool="demo",le="0.1"} 80`,
 		`fc_mediation_duration_seconds_sum{pool="demo"} 2.5`,
 		`fc_mediation_duration_seconds_count{pool="demo"} 102`,
@@ -94,3 +96,24 @@ func TestPrometheusHandler_EmitsBreakerAndInFlight(t *testing.T) {
 		}
 	}
 }
+
+type stubConsumerPolls []router.ConsumerPollStats
+
+func (s stubConsumerPolls) ConsumerPollStats() []router.ConsumerPollStats { return s }
+
+func TestPrometheusHandler_EmitsConsumerPolls(t *testing.T) {
+	state := &routerapi.State{ConsumerPolls: stubConsumerPolls{{
+		Queue: "https://sqs.eu-west-1.amazonaws.com/123/orders", Polls: 50, EmptyPolls: 42, IdleBackoff: 4 * time.Second,
+	}}}
+	rec := httptest.NewRecorder()
+	routerapi.PrometheusHandler(state).ServeHTTP(rec, httptest.NewRequest("GET", "/metrics", nil))
+	for _, s := range []string{
+		`fc_consumer_polls_total{consumer="orders",outcome="messages"} 8`,
+		`fc_consumer_polls_total{consumer="orders",outcome="empty"} 42`,
+		`fc_consumer_idle_backoff_seconds{consumer="orders"} 4`,
+	} {
+		if !strings.Contains(rec.Body.String(), s) {
+			t.Errorf("missing %q in:\n%s", s, rec.Body.String())
+		}
+	}
+}
@@ -0,0 +1,142 @@
+package api
+
+import (
+	"context"
+	"fmt"
+	"math"
+	"net/http"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/danielgtaylor/huma/v2"
+)
+
+const tagScale = "scale"
+
+// ScaleConfig sizes the router fleet for autoscalers (KEDA, HPA on
+// external metrics): GET /scale/recommendation and the
+// fc_scale_desired_replicas gauge divide each queue's backlog (pending +
+// in-flight) by its messages-per-replica target and add the results up,
+// since every replica consumes every queue.
+type ScaleConfig struct {
+	// MessagesPerReplica is the backlog one replica is sized to carry
+	// (default 100).
+	MessagesPerReplica int
+	// QueueTargets overrides MessagesPerReplica per queue, keyed by the
+	// queue label the metrics use (the last segment of a queue URL).
+	QueueTargets map[string]int
+	// MinReplicas and MaxReplicas clamp the recommendation; MaxReplicas 0
+	// leaves it unbounded.
+	MinReplicas int
+	MaxReplicas int
+}
+
+// ParseQueueTargets parses per-queue targets written as
+// "orders=50,billing=200".
+func ParseQueueTargets(raw string) (map[string]int, error) {
+	out := make(map[string]int)
+	for _, entry := range strings.Split(raw, ",") {
+		entry = strings.TrimSpace(entry)
+		if entry == "" {
+			continue
+		}
+		queue, target, ok := strings.Cut(entry, "=")
+		queue = strings.TrimSpace(queue)
+		if !ok || queue == "" {
+			return nil, fmt.Errorf("queue target %q: want queue=messages", entry)
+		}
+		n, err := strconv.Atoi(strings.TrimSpace(target))
+		if err != nil || n <= 0 {
+			return nil, fmt.Errorf("queue target %q: messages must be a positive integer", entry)
+		}
+		out[queue] = n
+	}
+	return out, nil
+}
+
+func (c ScaleConfig) targetFor(queue string) int {
+	if n, ok := c.QueueTargets[queue]; ok && n > 0 {
+		return n
+	}
+	if c.MessagesPerReplica > 0 {
+		return c.MessagesPerReplica
+	}
+	return 100
+}
+
+// scaleRecommendation computes the recommendation from the current broker
+// and pool snapshots.
+func (s *State) scaleRecommendation() ScaleRecommendationResponse {
+	cfg := s.Scale
+	out := ScaleRecommendationResponse{
+		MinReplicas: cfg.MinReplicas,
+		MaxReplicas: cfg.MaxReplicas,
+		Queues:      []ScaleQueueSignal{},
+		Pools:       []ScalePoolSignal{},
+	}
+	demand := 0.0
+	if s.BrokerStats != nil {
+		for _, m := range s.BrokerStats.GetWindowed(0) {
+			q := normaliseQueueID(m.QueueIdentifier)
+			backlog := m.PendingMessages + m.InFlightMessages
+			target := cfg.targetFor(q)
+			replicas := float64(backlog) / float64(target)
+			demand += replicas
+			out.TotalBacklog += backlog
+			out.Queues = append(out.Queues, ScaleQueueSignal{
+				Queue:              q,
+				Pending:            m.PendingMessages,
+				InFlight:           m.InFlightMessages,
+				Backlog:            backlog,
+				MessagesPerReplica: target,
+				Replicas:           replicas,
+			})
+		}
+	}
+	sort.Slice(out.Queues, func(i, j int) bool { return out.Queues[i].Queue < out.Queues[j].Queue })
+	if s.PoolStats != nil {
+		for _, p := range s.PoolStats.PoolStats() {
+			out.Pools = append(out.Pools, ScalePoolSignal{
+				Pool:          p.PoolCode,
+				ActiveWorkers: p.ActiveWorkers,
+				Concurrency:   p.Concurrency,
+				Saturation:    saturation(p.ActiveWorkers, p.Concurrency),
+			})
+		}
+	}
+
+	desired := int(math.Ceil(demand))
+	if desired < cfg.MinReplicas {
+		desired = cfg.MinReplicas
+	}
+	if cfg.MaxReplicas > 0 && desired > cfg.MaxReplicas {
+		desired = cfg.MaxReplicas
+	}
+	out.DesiredReplicas = desired
+	return out
+}
+
+// saturation is the share of a pool's workers that are busy; 0 for a pool
+// with no concurrency configured.
+func saturation(active, concurrency uint32) float64 {
+	if concurrency == 0 {
+		return 0
+	}
+	return float64(active) / float64(concurrency)
+}
+
+func registerScale(api huma.API, s *State) {
+	huma.Register(api, huma.Operation{
+		OperationID: "scaleRecommendation", Method: http.MethodGet, Path: "/scale/recommendation",
+		Summary: "Desired router replica count from queue backlog", Tags: []string{tagScale}, DefaultStatus: http.StatusOK,
+	}, s.scaleRecommendationHandler)
+}
+
+type scaleRecommendationOutput struct {
+	Body ScaleRecommendationResponse
+}
+
+func (s *State) scaleRecommendationHandler(_ context.Context, _ *emptyInput) (*scaleRecommendationOutput, error) {
+	return &scaleRecommendationOutput{Body: s.scaleRecommendation()}, nil
+}
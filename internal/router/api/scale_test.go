@@ -0,0 +1,104 @@
+package api_test
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/danielgtaylor/huma/v2/humatest"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+	routerapi "github.com/flowcatalyst/flowcatalyst-go/internal/router/api"
+)
+
+func scaleState() *routerapi.State {
+	return &routerapi.State{
+		PoolStats: stubPoolStatsProvider{stats: []router.PoolStats{{
+			PoolCode: "demo", Concurrency: 8, ActiveWorkers: 6,
+		}}},
+		BrokerStats: &stubBrokerStatsProvider{metrics: []queue.Metrics{
+			{QueueIdentifier: "https://sqs.eu-west-1.amazonaws.com/123/orders", PendingMessages: 230, InFlightMessages: 20},
+			{QueueIdentifier: "billing", PendingMessages: 30, InFlightMessages: 10},
+		}},
+		Scale: routerapi.ScaleConfig{
+			MessagesPerReplica: 100,
+			QueueTargets:       map[string]int{"billing": 20},
+			MinReplicas:        1,
+		},
+		Mocks: routerapi.NewMockState(),
+	}
+}
+
+func TestScaleRecommendation(t *testing.T) {
+	state := scaleState()
+	_, api := humatest.New(t)
+	routerapi.Register(api, state)
+
+	resp := api.Get("/scale/recommendation")
+	if resp.Code != http.StatusOK {
+		t.Fatalf("status %d", resp.Code)
+	}
+	var body routerapi.ScaleRecommendationResponse
+	decodeBody(t, resp.Body.Bytes(), &body)
+	// orders: 250/100 = 2.5, billing: 40/20 = 2 → ceil(4.5) = 5.
+	if body.DesiredReplicas != 5 {
+		t.Errorf("DesiredReplicas=%d want 5", body.DesiredReplicas)
+	}
+	if body.TotalBacklog != 290 {
+		t.Errorf("TotalBacklog=%d want 290", body.TotalBacklog)
+	}
+	if len(body.Queues) != 2 || body.Queues[0].Queue != "billing" || body.Queues[1].Queue != "orders" {
+		t.Fatalf("queues=%+v", body.Queues)
+	}
+	if body.Queues[0].MessagesPerReplica != 20 {
+		t.Errorf("billing target=%d want 20", body.Queues[0].MessagesPerReplica)
+	}
+	if len(body.Pools) != 1 || body.Pools[0].Saturation != 0.75 {
+		t.Errorf("pools=%+v", body.Pools)
+	}
+
+	state.Scale.MaxReplicas = 3
+	decodeBody(t, api.Get("/scale/recommendation").Body.Bytes(), &body)
+	if body.DesiredReplicas != 3 {
+		t.Errorf("clamped DesiredReplicas=%d want 3", body.DesiredReplicas)
+	}
+
+	state.BrokerStats = &stubBrokerStatsProvider{}
+	decodeBody(t, api.Get("/scale/recommendation").Body.Bytes(), &body)
+	if body.DesiredReplicas != 1 {
+		t.Errorf("idle DesiredReplicas=%d want MinReplicas 1", body.DesiredReplicas)
+	}
+}
+
+func TestPrometheusHandler_EmitsScaleSignals(t *testing.T) {
+	rec := httptest.NewRecorder()
+	routerapi.PrometheusHandler(scaleState()).ServeHTTP(rec, httptest.NewRequest("GET", "/metrics", nil))
+	body := rec.Body.String()
+	for _, s := range []string{
+		`fc_queue_backlog_messages{queue="orders"} 250`,
+		`fc_queue_backlog_messages{queue="billing"} 40`,
+		`fc_pool_saturation_ratio{pool="demo"} 0.75`,
+		`fc_scale_desired_replicas 5`,
+	} {
+		if !strings.Contains(body, s) {
+			t.Errorf("expected body to contain %q; got:\n%s", s, body)
+		}
+	}
+}
+
+func TestParseQueueTargets(t *testing.T) {
+	got, err := routerapi.ParseQueueTargets(" orders=50, billing=200 ,")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(got) != 2 || got["orders"] != 50 || got["billing"] != 200 {
+		t.Errorf("got %v", got)
+	}
+	for _, bad := range []string{"orders", "=5", "orders=0", "orders=x"} {
+		if _, err := routerapi.ParseQueueTargets(bad); err == nil {
+			t.Errorf("%q: expected an error", bad)
+		}
+	}
+}
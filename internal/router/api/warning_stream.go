@@ -0,0 +1,82 @@
+package api
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"strings"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+)
+
+// warningStreamHeartbeat is how often an idle stream sends an SSE comment
+// so proxies and load balancers don't reap the connection.
+const warningStreamHeartbeat = 15 * time.Second
+
+// MountWarningStream registers GET /warnings/stream, a Server-Sent Events
+// feed of warnings as they are raised. Mounted on the chi router beside
+// the dashboard because huma operations are request/response JSON.
+//
+// Each warning is one `event: warning` frame whose data is the same
+// WireWarning JSON the list endpoints return. ?severity= narrows the feed
+// with the same matching as GET /warnings. Only new warnings are streamed;
+// clients wanting the backlog list first, then tail.
+func MountWarningStream(r chi.Router, s *State) {
+	r.Get("/warnings/stream", s.streamWarnings)
+}
+
+func (s *State) streamWarnings(w http.ResponseWriter, r *http.Request) {
+	if s.Warnings == nil {
+		http.Error(w, "warning service not configured", http.StatusServiceUnavailable)
+		return
+	}
+	rc := http.NewResponseController(w)
+	// The stream outlives any server-wide write timeout.
+	_ = rc.SetWriteDeadline(time.Time{})
+
+	sev := strings.ToUpper(r.URL.Query().Get("severity"))
+	ch, cancel := s.Warnings.Subscribe(0)
+	defer cancel()
+
+	w.Header().Set("Content-Type", "text/event-stream")
+	w.Header().Set("Cache-Control", "no-cache")
+	w.Header().Set("X-Accel-Buffering", "no")
+	w.WriteHeader(http.StatusOK)
+	if _, err := fmt.Fprint(w, ": connected\n\n"); err != nil {
+		return
+	}
+	if rc.Flush() != nil {
+		return
+	}
+
+	tick := time.NewTicker(warningStreamHeartbeat)
+	defer tick.Stop()
+	for {
+		select {
+		case <-r.Context().Done():
+			return
+		case <-tick.C:
+			if _, err := fmt.Fprint(w, ": ping\n\n"); err != nil {
+				return
+			}
+		case wn, ok := <-ch:
+			if !ok {
+				return
+			}
+			if sev != "" && !matchesSeverity(wn.Severity, sev) {
+				continue
+			}
+			data, err := json.Marshal(fromWarning(wn))
+			if err != nil {
+				continue
+			}
+			if _, err := fmt.Fprintf(w, "event: warning\nid: %s\ndata: %s\n\n", wn.ID, data); err != nil {
+				return
+			}
+		}
+		if rc.Flush() != nil {
+			return
+		}
+	}
+}
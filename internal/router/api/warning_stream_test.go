@@ -0,0 +1,71 @@
+package api_test
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+	routerapi "github.com/flowcatalyst/flowcatalyst-go/internal/router/api"
+)
+
+func TestWarningStreamDeliversNewWarnings(t *testing.T) {
+	warnings := router.NewWarningService(router.WarningServiceConfig{})
+	r := chi.NewRouter()
+	routerapi.MountWarningStream(r, &routerapi.State{Warnings: warnings})
+	srv := httptest.NewServer(r)
+	defer srv.Close()
+
+	ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+	defer cancel()
+	req, _ := http.NewRequestWithContext(ctx, http.MethodGet, srv.URL+"/warnings/stream?severity=critical", nil)
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		t.Fatalf("connect: %v", err)
+	}
+	defer resp.Body.Close()
+	if ct := resp.Header.Get("Content-Type"); ct != "text/event-stream" {
+		t.Fatalf("Content-Type: got %q", ct)
+	}
+
+	lines := bufio.NewScanner(resp.Body)
+	if !lines.Scan() || lines.Text() != ": connected" {
+		t.Fatalf("first line: got %q", lines.Text())
+	}
+
+	warnings.Add(router.WarningCategoryConnection, router.WarningWarning, "filtered out", "test")
+	id := warnings.Add(router.WarningCategoryConnection, router.WarningCritical, "broker down", "test")
+
+	var event, gotID, data string
+	for lines.Scan() {
+		line := lines.Text()
+		switch {
+		case strings.HasPrefix(line, "event: "):
+			event = strings.TrimPrefix(line, "event: ")
+		case strings.HasPrefix(line, "id: "):
+			gotID = strings.TrimPrefix(line, "id: ")
+		case strings.HasPrefix(line, "data: "):
+			data = strings.TrimPrefix(line, "data: ")
+		}
+		if line == "" && data != "" {
+			break
+		}
+	}
+	if event != "warning" || gotID != id {
+		t.Fatalf("frame: event=%q id=%q want warning/%s", event, gotID, id)
+	}
+	var w routerapi.WireWarning
+	if err := json.Unmarshal([]byte(data), &w); err != nil {
+		t.Fatalf("decode data: %v", err)
+	}
+	if w.Message != "broker down" || w.Severity != string(router.WarningCritical) {
+		t.Fatalf("data: got %+v", w)
+	}
+}
@@ -0,0 +1,359 @@
+package router
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"log/slog"
+	"net/http"
+	"net/url"
+	"os"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// DeliveryAttempt is the machine-readable record of one HTTP delivery
+// attempt, emitted to the attempt sink so downstream analytics need not
+// scrape logs. One message produces one record per attempt (the mediator
+// retries in-process), so Attempt numbers them from 1.
+type DeliveryAttempt struct {
+	MessageID      string    `json:"messageId"`
+	PoolCode       string    `json:"poolCode,omitempty"`
+	MessageGroupID string    `json:"messageGroupId,omitempty"`
+	Target         string    `json:"target"`
+	Attempt        int       `json:"attempt"`
+	Outcome        string    `json:"outcome"`
+	StatusCode     int       `json:"statusCode,omitempty"`
+	LatencyMs      int64     `json:"latencyMs"`
+	Error          string    `json:"error,omitempty"`
+	Timestamp      time.Time `json:"timestamp"`
+}
+
+// OutcomeName renders a MediationResult the way the attempt sink and the
+// Rust router name it.
+func OutcomeName(r common.MediationResult) string {
+	switch r {
+	case common.MediationSuccess:
+		return "SUCCESS"
+	case common.MediationErrorConfig:
+		return "ERROR_CONFIG"
+	case common.MediationErrorProcess:
+		return "ERROR_PROCESS"
+	case common.MediationErrorConnection:
+		return "ERROR_CONNECTION"
+	case common.MediationRateLimited:
+		return "RATE_LIMITED"
+	case common.MediationCircuitOpen:
+		return "CIRCUIT_OPEN"
+	}
+	return "UNKNOWN"
+}
+
+// AttemptWriter ships one batch of records. Implementations must be safe to
+// call from the sink's single flush goroutine; they are not called
+// concurrently.
+type AttemptWriter interface {
+	Write(ctx context.Context, batch []DeliveryAttempt) error
+	Close() error
+}
+
+// AttemptSinkConfig selects and tunes the sink. Kind "" disables it.
+type AttemptSinkConfig struct {
+	// Kind is "http", "kafka" or "file".
+	Kind string
+	// URL is the HTTP endpoint (http) or the Kafka REST Proxy base URL
+	// (kafka).
+	URL string
+	// Topic is the Kafka topic (kafka).
+	Topic string
+	// Path is the NDJSON file appended to (file).
+	Path string
+	// AuthToken, when set, is sent as a bearer token (http, kafka).
+	AuthToken string
+
+	// BufferSize bounds the records waiting to be shipped. Recording never
+	// blocks delivery: once the buffer is full new records are dropped and
+	// counted. Zero falls back to 10000.
+	BufferSize int
+	// BatchSize caps records per write. Zero falls back to 500.
+	BatchSize int
+	// FlushInterval ships a partial batch after this long. Zero falls back
+	// to 1s.
+	FlushInterval time.Duration
+}
+
+// AttemptSinkStats are cumulative counters since start.
+type AttemptSinkStats struct {
+	Recorded uint64 // accepted into the buffer
+	Written  uint64 // shipped successfully
+	Dropped  uint64 // rejected because the buffer was full
+	Failed   uint64 // lost to a failed write
+	Buffered int    // currently waiting
+}
+
+// AttemptSink buffers DeliveryAttempts and ships them in batches from one
+// goroutine. A slow or unavailable destination never slows message
+// delivery: the buffer absorbs bursts and overflow is dropped and counted.
+type AttemptSink struct {
+	cfg    AttemptSinkConfig
+	writer AttemptWriter
+	ch     chan DeliveryAttempt
+
+	recorded atomic.Uint64
+	written  atomic.Uint64
+	dropped  atomic.Uint64
+	failed   atomic.Uint64
+
+	startOnce sync.Once
+	stopOnce  sync.Once
+	stop      chan struct{}
+	done      chan struct{}
+}
+
+// NewAttemptSink builds the sink for cfg, or returns nil when cfg.Kind is
+// empty (sink disabled). Callers treat a nil *AttemptSink as a no-op.
+func NewAttemptSink(cfg AttemptSinkConfig) (*AttemptSink, error) {
+	var w AttemptWriter
+	switch strings.ToLower(cfg.Kind) {
+	case "":
+		return nil, nil
+	case "http":
+		if cfg.URL == "" {
+			return nil, errors.New("attempt sink http: URL is required")
+		}
+		w = &httpAttemptWriter{url: cfg.URL, token: cfg.AuthToken, client: &http.Client{Timeout: 10 * time.Second}}
+	case "kafka":
+		if cfg.URL == "" || cfg.Topic == "" {
+			return nil, errors.New("attempt sink kafka: REST Proxy URL and topic are required")
+		}
+		w = &kafkaRESTAttemptWriter{
+			url:    strings.TrimRight(cfg.URL, "/") + "/topics/" + url.PathEscape(cfg.Topic),
+			token:  cfg.AuthToken,
+			client: &http.Client{Timeout: 10 * time.Second},
+		}
+	case "file":
+		if cfg.Path == "" {
+			return nil, errors.New("attempt sink file: path is required")
+		}
+		f, err := os.OpenFile(cfg.Path, os.O_WRONLY|os.O_APPEND|os.O_CREATE, 0o600)
+		if err != nil {
+			return nil, fmt.Errorf("attempt sink file: %w", err)
+		}
+		w = &fileAttemptWriter{f: f}
+	default:
+		return nil, fmt.Errorf("attempt sink: unknown kind %q (want http, kafka or file)", cfg.Kind)
+	}
+	return newAttemptSink(cfg, w), nil
+}
+
+func newAttemptSink(cfg AttemptSinkConfig, w AttemptWriter) *AttemptSink {
+	if cfg.BufferSize <= 0 {
+		cfg.BufferSize = 10000
+	}
+	if cfg.BatchSize <= 0 {
+		cfg.BatchSize = 500
+	}
+	if cfg.FlushInterval <= 0 {
+		cfg.FlushInterval = time.Second
+	}
+	return &AttemptSink{
+		cfg:    cfg,
+		writer: w,
+		ch:     make(chan DeliveryAttempt, cfg.BufferSize),
+		stop:   make(chan struct{}),
+		done:   make(chan struct{}),
+	}
+}
+
+// Record enqueues a, or drops it when the buffer is full. Never blocks.
+func (s *AttemptSink) Record(a DeliveryAttempt) {
+	if s == nil {
+		return
+	}
+	select {
+	case s.ch <- a:
+		s.recorded.Add(1)
+	default:
+		s.dropped.Add(1)
+	}
+}
+
+// Stats returns the cumulative counters.
+func (s *AttemptSink) Stats() AttemptSinkStats {
+	if s == nil {
+		return AttemptSinkStats{}
+	}
+	return AttemptSinkStats{
+		Recorded: s.recorded.Load(),
+		Written:  s.written.Load(),
+		Dropped:  s.dropped.Load(),
+		Failed:   s.failed.Load(),
+		Buffered: len(s.ch),
+	}
+}
+
+// Start launches the flush loop. It runs until Close, not until a context
+// is cancelled, so attempts made while the router drains are still shipped.
+func (s *AttemptSink) Start() {
+	if s == nil {
+		return
+	}
+	s.startOnce.Do(func() { go s.loop() })
+}
+
+// Close stops the loop after shipping what is buffered, waiting at most
+// until ctx is done, then closes the writer.
+func (s *AttemptSink) Close(ctx context.Context) error {
+	if s == nil {
+		return nil
+	}
+	s.Start() // a never-started sink still drains its buffer
+	s.stopOnce.Do(func() { close(s.stop) })
+	select {
+	case <-s.done:
+	case <-ctx.Done():
+		return ctx.Err()
+	}
+	return s.writer.Close()
+}
+
+func (s *AttemptSink) loop() {
+	defer close(s.done)
+	tick := time.NewTicker(s.cfg.FlushInterval)
+	defer tick.Stop()
+	batch := make([]DeliveryAttempt, 0, s.cfg.BatchSize)
+	flush := func() {
+		if len(batch) == 0 {
+			return
+		}
+		s.ship(batch)
+		batch = batch[:0]
+	}
+	for {
+		select {
+		case a := <-s.ch:
+			batch = append(batch, a)
+			if len(batch) >= s.cfg.BatchSize {
+				flush()
+			}
+		case <-tick.C:
+			flush()
+		case <-s.stop:
+			for {
+				select {
+				case a := <-s.ch:
+					batch = append(batch, a)
+					if len(batch) >= s.cfg.BatchSize {
+						flush()
+					}
+				default:
+					flush()
+					return
+				}
+			}
+		}
+	}
+}
+
+func (s *AttemptSink) ship(batch []DeliveryAttempt) {
+	ctx, cancel := context.WithTimeout(context.Background(), 15*time.Second)
+	defer cancel()
+	if err := s.writer.Write(ctx, batch); err != nil {
+		s.failed.Add(uint64(len(batch)))
+		slog.Warn("attempt sink write failed; batch dropped",
+			"kind", s.cfg.Kind, "records", len(batch), "err", err)
+		return
+	}
+	s.written.Add(uint64(len(batch)))
+}
+
+// httpAttemptWriter POSTs {"attempts":[...]} to a collector endpoint.
+type httpAttemptWriter struct {
+	url    string
+	token  string
+	client *http.Client
+}
+
+func (w *httpAttemptWriter) Write(ctx context.Context, batch []DeliveryAttempt) error {
+	body, err := json.Marshal(map[string]any{"attempts": batch})
+	if err != nil {
+		return err
+	}
+	return postJSON(ctx, w.client, w.url, "application/json", w.token, body)
+}
+
+func (w *httpAttemptWriter) Close() error { return nil }
+
+// kafkaRESTAttemptWriter produces to a Kafka topic through a Confluent-style
+// REST Proxy (v2 JSON embedded format), keyed by message id so a message's
+// attempts land on one partition in order. The router carries no native
+// Kafka client; the proxy keeps it that way.
+type kafkaRESTAttemptWriter struct {
+	url    string
+	token  string
+	client *http.Client
+}
+
+type kafkaRecord struct {
+	Key   string          `json:"key"`
+	Value DeliveryAttempt `json:"value"`
+}
+
+func (w *kafkaRESTAttemptWriter) Write(ctx context.Context, batch []DeliveryAttempt) error {
+	records := make([]kafkaRecord, len(batch))
+	for i, a := range batch {
+		records[i] = kafkaRecord{Key: a.MessageID, Value: a}
+	}
+	body, err := json.Marshal(map[string]any{"records": records})
+	if err != nil {
+		return err
+	}
+	return postJSON(ctx, w.client, w.url, "application/vnd.kafka.json.v2+json", w.token, body)
+}
+
+func (w *kafkaRESTAttemptWriter) Close() error { return nil }
+
+func postJSON(ctx context.Context, client *http.Client, target, contentType, token string, body []byte) error {
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, target, bytes.NewReader(body))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Content-Type", contentType)
+	if token != "" {
+		req.Header.Set("Authorization", "Bearer "+token)
+	}
+	resp, err := client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	_, _ = io.Copy(io.Discard, io.LimitReader(resp.Body, 64<<10))
+	if resp.StatusCode >= 300 {
+		return fmt.Errorf("status %d", resp.StatusCode)
+	}
+	return nil
+}
+
+// fileAttemptWriter appends one JSON object per line.
+type fileAttemptWriter struct{ f *os.File }
+
+func (w *fileAttemptWriter) Write(_ context.Context, batch []DeliveryAttempt) error {
+	bw := bufio.NewWriter(w.f)
+	enc := json.NewEncoder(bw)
+	for _, a := range batch {
+		if err := enc.Encode(a); err != nil {
+			return err
+		}
+	}
+	return bw.Flush()
+}
+
+func (w *fileAttemptWriter) Close() error { return w.f.Close() }
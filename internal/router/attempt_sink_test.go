@@ -0,0 +1,198 @@
+package router
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"errors"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type recordingWriter struct {
+	mu      sync.Mutex
+	batches [][]DeliveryAttempt
+	err     error
+	closed  bool
+}
+
+func (w *recordingWriter) Write(_ context.Context, batch []DeliveryAttempt) error {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	if w.err != nil {
+		return w.err
+	}
+	w.batches = append(w.batches, append([]DeliveryAttempt(nil), batch...))
+	return nil
+}
+
+func (w *recordingWriter) Close() error {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	w.closed = true
+	return nil
+}
+
+func attempt(id string) DeliveryAttempt {
+	return DeliveryAttempt{MessageID: id, Target: "http://example.test", Attempt: 1, Outcome: "SUCCESS", Timestamp: time.Now()}
+}
+
+func TestAttemptSinkBatchesAndDrainsOnClose(t *testing.T) {
+	w := &recordingWriter{}
+	s := newAttemptSink(AttemptSinkConfig{Kind: "test", BatchSize: 2, FlushInterval: time.Hour}, w)
+	s.Start()
+	for _, id := range []string{"a", "b", "c"} {
+		s.Record(attempt(id))
+	}
+	require.NoError(t, s.Close(context.Background()))
+
+	var ids []string
+	for _, b := range w.batches {
+		assert.LessOrEqual(t, len(b), 2)
+		for _, a := range b {
+			ids = append(ids, a.MessageID)
+		}
+	}
+	assert.Equal(t, []string{"a", "b", "c"}, ids)
+	assert.True(t, w.closed)
+	st := s.Stats()
+	assert.Equal(t, uint64(3), st.Recorded)
+	assert.Equal(t, uint64(3), st.Written)
+	assert.Zero(t, st.Buffered)
+}
+
+func TestAttemptSinkDropsWhenFull(t *testing.T) {
+	s := newAttemptSink(AttemptSinkConfig{Kind: "test", BufferSize: 2}, &recordingWriter{})
+	for _, id := range []string{"a", "b", "c", "d"} {
+		s.Record(attempt(id))
+	}
+	st := s.Stats()
+	assert.Equal(t, uint64(2), st.Recorded)
+	assert.Equal(t, uint64(2), st.Dropped)
+	assert.Equal(t, 2, st.Buffered)
+	require.NoError(t, s.Close(context.Background()))
+	assert.Equal(t, uint64(2), s.Stats().Written)
+}
+
+func TestAttemptSinkCountsFailedWrites(t *testing.T) {
+	s := newAttemptSink(AttemptSinkConfig{Kind: "test"}, &recordingWriter{err: errors.New("down")})
+	s.Record(attempt("a"))
+	s.Record(attempt("b"))
+	require.NoError(t, s.Close(context.Background()))
+	st := s.Stats()
+	assert.Equal(t, uint64(2), st.Failed)
+	assert.Zero(t, st.Written)
+}
+
+func TestNilAttemptSinkIsNoop(t *testing.T) {
+	var s *AttemptSink
+	s.Record(attempt("a"))
+	s.Start()
+	assert.Equal(t, AttemptSinkStats{}, s.Stats())
+	assert.NoError(t, s.Close(context.Background()))
+}
+
+func TestNewAttemptSinkConfig(t *testing.T) {
+	s, err := NewAttemptSink(AttemptSinkConfig{})
+	require.NoError(t, err)
+	assert.Nil(t, s)
+
+	for _, cfg := range []AttemptSinkConfig{
+		{Kind: "http"},
+		{Kind: "kafka", URL: "http://proxy"},
+		{Kind: "file"},
+		{Kind: "syslog"},
+	} {
+		_, err := NewAttemptSink(cfg)
+		assert.Error(t, err, cfg.Kind)
+	}
+}
+
+func TestAttemptSinkHTTPWriter(t *testing.T) {
+	var (
+		body        map[string][]DeliveryAttempt
+		contentType string
+		auth        string
+	)
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		contentType = r.Header.Get("Content-Type")
+		auth = r.Header.Get("Authorization")
+		raw, _ := io.ReadAll(r.Body)
+		_ = json.Unmarshal(raw, &body)
+		w.WriteHeader(http.StatusAccepted)
+	}))
+	defer srv.Close()
+
+	s, err := NewAttemptSink(AttemptSinkConfig{Kind: "http", URL: srv.URL, AuthToken: "tok"})
+	require.NoError(t, err)
+	s.Record(attempt("m-1"))
+	require.NoError(t, s.Close(context.Background()))
+
+	assert.Equal(t, "application/json", contentType)
+	assert.Equal(t, "Bearer tok", auth)
+	require.Len(t, body["attempts"], 1)
+	assert.Equal(t, "m-1", body["attempts"][0].MessageID)
+	assert.Equal(t, uint64(1), s.Stats().Written)
+}
+
+func TestAttemptSinkKafkaRESTWriter(t *testing.T) {
+	var (
+		path        string
+		contentType string
+		body        struct {
+			Records []struct {
+				Key   string          `json:"key"`
+				Value DeliveryAttempt `json:"value"`
+			} `json:"records"`
+		}
+	)
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		path = r.URL.Path
+		contentType = r.Header.Get("Content-Type")
+		raw, _ := io.ReadAll(r.Body)
+		_ = json.Unmarshal(raw, &body)
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+
+	s, err := NewAttemptSink(AttemptSinkConfig{Kind: "kafka", URL: srv.URL + "/", Topic: "delivery-attempts"})
+	require.NoError(t, err)
+	s.Record(attempt("m-1"))
+	require.NoError(t, s.Close(context.Background()))
+
+	assert.Equal(t, "/topics/delivery-attempts", path)
+	assert.Equal(t, "application/vnd.kafka.json.v2+json", contentType)
+	require.Len(t, body.Records, 1)
+	assert.Equal(t, "m-1", body.Records[0].Key)
+	assert.Equal(t, "m-1", body.Records[0].Value.MessageID)
+}
+
+func TestAttemptSinkFileWriter(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "attempts.ndjson")
+	s, err := NewAttemptSink(AttemptSinkConfig{Kind: "file", Path: path})
+	require.NoError(t, err)
+	s.Record(attempt("a"))
+	s.Record(attempt("b"))
+	require.NoError(t, s.Close(context.Background()))
+
+	f, err := os.Open(path)
+	require.NoError(t, err)
+	defer f.Close()
+	var ids []string
+	sc := bufio.NewScanner(f)
+	for sc.Scan() {
+		var a DeliveryAttempt
+		require.NoError(t, json.Unmarshal(sc.Bytes(), &a))
+		ids = append(ids, a.MessageID)
+	}
+	assert.Equal(t, []string{"a", "b"}, ids)
+}
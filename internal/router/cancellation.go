@@ -0,0 +1,41 @@
+package router
+
+import (
+	"context"
+	"log/slog"
+	"time"
+)
+
+// Cancellations reports whether the dispatch job behind a message was
+// cancelled after it was queued. The pool checks it before mediating and
+// ACKs a cancelled message without delivering it. Satisfied by
+// dispatchcancel.Store.
+type Cancellations interface {
+	IsCancelled(ctx context.Context, messageID string) (bool, error)
+}
+
+// cancellationLookupTimeout bounds the lookup so a slow store delays a
+// delivery by at most this much.
+const cancellationLookupTimeout = 250 * time.Millisecond
+
+// SetCancellations wires the cancellation lookup every pool consults
+// before mediating. Opt-in; set once at startup before Start. nil (the
+// default) skips the check.
+func (m *Manager) SetCancellations(c Cancellations) { m.cancellations = c }
+
+// cancelled reports whether msg's job was cancelled. A failed or slow
+// lookup counts as not cancelled: the message is delivered, and the
+// platform's processing callback still refuses a cancelled job.
+func (p *Pool) cancelled(ctx context.Context, messageID string) bool {
+	if p.cancellations == nil {
+		return false
+	}
+	ctx, cancel := context.WithTimeout(ctx, cancellationLookupTimeout)
+	defer cancel()
+	ok, err := p.cancellations.IsCancelled(ctx, messageID)
+	if err != nil {
+		slog.WarnContext(ctx, "cancellation lookup failed; mediating", "err", err)
+		return false
+	}
+	return ok
+}
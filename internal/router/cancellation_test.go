@@ -0,0 +1,50 @@
+package router
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+type fakeCancellations struct {
+	ids map[string]bool
+	err error
+}
+
+func (f fakeCancellations) IsCancelled(_ context.Context, id string) (bool, error) {
+	return f.ids[id], f.err
+}
+
+func TestCancelledMessageIsAckedWithoutDelivery(t *testing.T) {
+	c := &grConsumer{id: "q1"}
+	med := &grMediator{outcome: common.Success()}
+	p := grPool(med, c)
+	p.cancellations = fakeCancellations{ids: map[string]bool{"job_x": true}}
+
+	res, _ := p.processOne(context.Background(), grMsg("job_x", "http://t/x"))
+	if res != processDone || c.acks.Load() != 1 {
+		t.Fatalf("cancelled message: res=%d acks=%d, want processDone and one ACK", res, c.acks.Load())
+	}
+	if med.called.Load() {
+		t.Fatal("cancelled message was mediated")
+	}
+}
+
+func TestCancellationLookupFailsOpen(t *testing.T) {
+	for name, cancels := range map[string]Cancellations{
+		"not cancelled": fakeCancellations{ids: map[string]bool{"other": true}},
+		"lookup error":  fakeCancellations{ids: map[string]bool{"job_y": true}, err: errors.New("redis down")},
+		"no lookup":     nil,
+	} {
+		c := &grConsumer{id: "q1"}
+		med := &grMediator{outcome: common.Success()}
+		p := grPool(med, c)
+		p.cancellations = cancels
+
+		if res, _ := p.processOne(context.Background(), grMsg("job_y", "http://t/y")); res != processDone || !med.called.Load() {
+			t.Errorf("%s: res=%d mediated=%v, want delivery", name, res, med.called.Load())
+		}
+	}
+}
@@ -227,6 +227,54 @@ func (cb *CircuitBreaker) Stats() BreakerStats {
 	}
 }
 
+// BreakerSnapshot is a breaker's state machine, persisted across restarts
+// so a rolling deploy does not close breakers on endpoints that are down.
+type BreakerSnapshot struct {
+	State             CircuitState `json:"state"`
+	Window            []bool       `json:"window,omitempty"` // oldest first; true=success
+	HalfOpenSuccesses int          `json:"halfOpenSuccesses,omitempty"`
+	LastFailure       time.Time    `json:"lastFailure,omitzero"`
+}
+
+// Export returns the breaker's state machine. The cumulative counters are
+// metrics and are not included.
+func (cb *CircuitBreaker) Export() BreakerSnapshot {
+	cb.mu.Lock()
+	defer cb.mu.Unlock()
+	snap := BreakerSnapshot{State: cb.state, HalfOpenSuccesses: cb.halfOpenSuccesses}
+	if cb.lastFailureNano != 0 {
+		snap.LastFailure = time.Unix(0, cb.lastFailureNano)
+	}
+	start := 0
+	if cb.count == len(cb.window) {
+		start = cb.head
+	}
+	for i := 0; i < cb.count; i++ {
+		snap.Window = append(snap.Window, cb.window[(start+i)%len(cb.window)])
+	}
+	return snap
+}
+
+// restore replaces the state machine with a snapshot. A window longer
+// than this breaker's buffer keeps its newest samples.
+func (cb *CircuitBreaker) restore(snap BreakerSnapshot) {
+	cb.mu.Lock()
+	defer cb.mu.Unlock()
+	w := snap.Window
+	if len(w) > len(cb.window) {
+		w = w[len(w)-len(cb.window):]
+	}
+	copy(cb.window, w)
+	cb.count = len(w)
+	cb.head = len(w) % len(cb.window)
+	cb.state = snap.State
+	cb.halfOpenSuccesses = snap.HalfOpenSuccesses
+	cb.lastFailureNano = 0
+	if !snap.LastFailure.IsZero() {
+		cb.lastFailureNano = snap.LastFailure.UnixNano()
+	}
+}
+
 // BreakerRegistry is a per-endpoint URL → breaker map.
 type BreakerRegistry struct {
 	cfg BreakerConfig
@@ -344,3 +392,26 @@ func (r *BreakerRegistry) Len() int {
 	defer r.mu.RUnlock()
 	return len(r.m)
 }
+
+// Export returns the state of every breaker that has seen traffic, keyed
+// by URL. Closed breakers with an empty window are left out.
+func (r *BreakerRegistry) Export() map[string]BreakerSnapshot {
+	r.mu.RLock()
+	defer r.mu.RUnlock()
+	out := make(map[string]BreakerSnapshot, len(r.m))
+	for url, cb := range r.m {
+		if snap := cb.Export(); snap.State != CircuitClosed || len(snap.Window) > 0 {
+			out[url] = snap
+		}
+	}
+	return out
+}
+
+// Restore installs exported breaker states, replacing any breaker already
+// registered for the same URL. Returns the number restored.
+func (r *BreakerRegistry) Restore(snaps map[string]BreakerSnapshot) int {
+	for url, snap := range snaps {
+		r.Get(url).restore(snap)
+	}
+	return len(snaps)
+}
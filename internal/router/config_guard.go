@@ -0,0 +1,179 @@
+package router
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Guarded config apply. A config carrying an ApplyGuard is applied like
+// any other, but the manager keeps the config it replaced and bakes the
+// new one: for BakeSeconds it compares mediation outcomes and buffered
+// depth against the guard's thresholds, and on a breach re-applies the
+// previous config and raises a CRITICAL warning. The rolled-back config
+// stays rejected until the source serves a different one — the config
+// source only reports changes, so the watcher does not re-apply it.
+
+const (
+	defaultGuardBake       = 2 * time.Minute
+	defaultGuardErrorRate  = 0.25
+	defaultGuardMinSamples = 20
+)
+
+// guardState is the manager's record of the config in force and of the
+// bake watching it, if any.
+type guardState struct {
+	mu      sync.Mutex
+	applied *common.RouterConfig // last config applied via Apply
+	cancel  context.CancelFunc   // stops the running bake; nil when none
+}
+
+// Apply reconfigures the manager with cfg and remembers it as the config
+// in force. A newer Apply supersedes a bake still running. When
+// cfg.ApplyGuard is set and a config was applied before, the new config
+// is baked and rolled back on a breach; see bakeConfig. The bake outlives
+// ctx's cancellation (Reload passes a request context) and ends at
+// Shutdown.
+func (m *Manager) Apply(ctx context.Context, cfg common.RouterConfig) error {
+	m.guard.mu.Lock()
+	m.stopBakeLocked()
+	prev := m.guard.applied
+	m.guard.mu.Unlock()
+
+	if err := m.Reconfigure(ctx, cfg); err != nil {
+		return err
+	}
+
+	m.guard.mu.Lock()
+	defer m.guard.mu.Unlock()
+	m.guard.applied = &cfg
+	if cfg.ApplyGuard == nil || prev == nil {
+		return nil
+	}
+	bakeCtx, cancel := context.WithCancel(context.WithoutCancel(ctx))
+	m.guard.cancel = cancel
+	go m.bakeConfig(bakeCtx, *prev, cfg, m.guardBaseline())
+	return nil
+}
+
+// stopBakeLocked cancels the running bake. Caller holds m.guard.mu.
+func (m *Manager) stopBakeLocked() {
+	if m.guard.cancel != nil {
+		m.guard.cancel()
+		m.guard.cancel = nil
+	}
+}
+
+// guardSample is one pool's mediation counters.
+type guardSample struct {
+	attempts, successes uint64
+}
+
+// guardBaseline snapshots every pool's counters at the start of a bake.
+func (m *Manager) guardBaseline() map[string]guardSample {
+	out := make(map[string]guardSample)
+	for _, s := range m.PoolStats() {
+		out[s.PoolCode] = poolGuardSample(s)
+	}
+	return out
+}
+
+func poolGuardSample(s PoolStats) guardSample {
+	g := guardSample{attempts: s.Histogram.Count}
+	if s.Metrics != nil {
+		g.successes = s.Metrics.TotalSuccess
+	}
+	return g
+}
+
+// bakeConfig watches cfg for its guard's bake period and rolls back to
+// prev on the first breach.
+func (m *Manager) bakeConfig(ctx context.Context, prev, cfg common.RouterConfig, baseline map[string]guardSample) {
+	g := *cfg.ApplyGuard
+	bake := time.Duration(g.BakeSeconds) * time.Second
+	if bake <= 0 {
+		bake = defaultGuardBake
+	}
+	slog.Info("config applied guarded; baking", "bake", bake)
+
+	check := time.NewTicker(min(max(bake/10, 10*time.Millisecond), 5*time.Second))
+	defer check.Stop()
+	done := time.NewTimer(bake)
+	defer done.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-done.C:
+			slog.Info("config bake passed", "bake", bake)
+			return
+		case <-check.C:
+			reason := guardBreach(g, baseline, m.PoolStats())
+			if reason == "" {
+				continue
+			}
+			m.rollback(ctx, prev, reason)
+			return
+		}
+	}
+}
+
+// guardBreach compares pool stats with the bake's baseline and returns
+// why the guard is breached, or "" when it is not.
+func guardBreach(g common.ApplyGuard, baseline map[string]guardSample, stats []PoolStats) string {
+	maxRate := g.MaxErrorRate
+	if maxRate <= 0 {
+		maxRate = defaultGuardErrorRate
+	}
+	minSamples := g.MinSamples
+	if minSamples == 0 {
+		minSamples = defaultGuardMinSamples
+	}
+
+	var attempts, successes uint64
+	var depth uint32
+	for _, s := range stats {
+		now, base := poolGuardSample(s), baseline[s.PoolCode]
+		if now.attempts < base.attempts || now.successes < base.successes {
+			base = guardSample{} // pool re-created: its counters restarted
+		}
+		attempts += now.attempts - base.attempts
+		successes += now.successes - base.successes
+		depth += s.QueueSize
+	}
+	if attempts >= minSamples {
+		if rate := 1 - float64(successes)/float64(attempts); rate > maxRate {
+			return fmt.Sprintf("error rate %.0f%% over %d mediations exceeds %.0f%%", rate*100, attempts, maxRate*100)
+		}
+	}
+	if g.MaxQueueDepth > 0 && depth > g.MaxQueueDepth {
+		return fmt.Sprintf("%d messages buffered exceeds %d", depth, g.MaxQueueDepth)
+	}
+	return ""
+}
+
+// rollback re-applies prev after a bake breach and raises a CRITICAL
+// warning. A newer Apply that has already superseded the bake wins.
+func (m *Manager) rollback(ctx context.Context, prev common.RouterConfig, reason string) {
+	m.guard.mu.Lock()
+	defer m.guard.mu.Unlock()
+	if ctx.Err() != nil {
+		return
+	}
+	m.guard.cancel = nil
+	slog.Error("config bake failed; rolling back to the previous config", "reason", reason)
+	// Consumers started by the rollback must outlive the bake's context.
+	if err := m.Reconfigure(context.WithoutCancel(ctx), prev); err != nil {
+		slog.Error("config rollback failed", "err", err)
+	} else {
+		m.guard.applied = &prev
+	}
+	if w := m.warnings.Load(); w != nil {
+		w.Add(WarningCategoryConfiguration, WarningCritical,
+			"config apply rolled back: "+reason, "config")
+	}
+}
@@ -0,0 +1,96 @@
+package router
+
+import (
+	"context"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+func guardedPools(concurrency uint32, guard *common.ApplyGuard) common.RouterConfig {
+	return common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "A", Concurrency: concurrency}},
+		ApplyGuard:      guard,
+	}
+}
+
+// TestApply_RollsBackOnErrorSpike verifies a guarded apply that is
+// followed by a failure spike re-applies the previous config and raises a
+// CRITICAL configuration warning.
+func TestApply_RollsBackOnErrorSpike(t *testing.T) {
+	m := NewManager(&cascadeMediator{}, nil)
+	ws := NewWarningService(DefaultWarningServiceConfig())
+	m.SetWarnings(ws)
+	ctx := context.Background()
+	defer func() { _ = m.Shutdown(ctx) }()
+
+	require.NoError(t, m.Apply(ctx, guardedPools(4, nil)))
+	require.NoError(t, m.Apply(ctx, guardedPools(8, &common.ApplyGuard{BakeSeconds: 5, MaxErrorRate: 0.5, MinSamples: 10})))
+	require.Equal(t, uint32(8), m.pools["A"].Concurrency())
+
+	metrics := m.pools["A"].Metrics()
+	for i := 0; i < 3; i++ {
+		metrics.RecordSuccess(5)
+	}
+	for i := 0; i < 9; i++ {
+		metrics.RecordFailure(5)
+	}
+
+	require.Eventually(t, func() bool {
+		m.mu.Lock()
+		defer m.mu.Unlock()
+		return m.pools["A"].Concurrency() == 4
+	}, 3*time.Second, 20*time.Millisecond, "previous concurrency should be restored")
+
+	warns := ws.ByCategory(WarningCategoryConfiguration)
+	require.Len(t, warns, 1)
+	assert.Equal(t, WarningCritical, warns[0].Severity)
+	assert.Contains(t, warns[0].Message, "error rate 75%")
+}
+
+// TestApply_UnguardedAndFirstApplyDoNotBake verifies only a guarded apply
+// that replaces an earlier one starts a bake, and that a newer apply
+// supersedes a bake in progress.
+func TestApply_UnguardedAndFirstApplyDoNotBake(t *testing.T) {
+	m := NewManager(&cascadeMediator{}, nil)
+	ctx := context.Background()
+	defer func() { _ = m.Shutdown(ctx) }()
+	guard := &common.ApplyGuard{BakeSeconds: 60}
+
+	require.NoError(t, m.Apply(ctx, guardedPools(4, guard)))
+	assert.Nil(t, m.guard.cancel, "first apply has nothing to roll back to")
+
+	require.NoError(t, m.Apply(ctx, guardedPools(8, guard)))
+	assert.NotNil(t, m.guard.cancel, "guarded apply should bake")
+
+	require.NoError(t, m.Apply(ctx, guardedPools(6, nil)))
+	assert.Nil(t, m.guard.cancel, "unguarded apply should end the bake")
+	assert.Equal(t, uint32(6), m.pools["A"].Concurrency())
+}
+
+func TestGuardBreach(t *testing.T) {
+	stats := func(attempts, successes uint64, depth uint32) []PoolStats {
+		return []PoolStats{{
+			PoolCode:  "A",
+			QueueSize: depth,
+			Metrics:   &common.EnhancedPoolMetrics{TotalSuccess: successes},
+			Histogram: MediationHistogram{Count: attempts},
+		}}
+	}
+	base := map[string]guardSample{"A": {attempts: 100, successes: 90}}
+	g := common.ApplyGuard{MaxErrorRate: 0.2, MinSamples: 10, MaxQueueDepth: 50}
+
+	assert.Empty(t, guardBreach(g, base, stats(105, 90, 0)), "below MinSamples")
+	assert.Empty(t, guardBreach(g, base, stats(120, 106, 0)), "20% errors is at, not over, the limit")
+	assert.True(t, strings.HasPrefix(guardBreach(g, base, stats(120, 100, 0)), "error rate 50%"))
+	assert.Contains(t, guardBreach(g, base, stats(100, 90, 51)), "51 messages buffered")
+	assert.Empty(t, guardBreach(common.ApplyGuard{}, base, stats(100, 90, 1000)), "zero MaxQueueDepth skips the depth check")
+
+	// A pool whose counters restarted is measured from zero.
+	assert.Contains(t, guardBreach(g, base, stats(20, 0, 0)), "over 20 mediations")
+}
@@ -0,0 +1,191 @@
+package router
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"log/slog"
+	"net/url"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Config history. Every successful config sync is recorded so an incident
+// review can ask which pools, limits and queues were active at a given
+// time. A sync that returns the config already in force does not add a
+// snapshot; it moves the newest snapshot's LastSeenAt forward instead, so
+// retention ages a snapshot from when it stopped being current, not from
+// when it was first seen.
+
+// ErrNoConfigSnapshot is returned by ConfigHistory.At when no snapshot was
+// taken at or before the requested time.
+var ErrNoConfigSnapshot = errors.New("no config snapshot at or before that time")
+
+// configHistoryTimeout bounds a history write so a slow store can't hold
+// up the sync that triggered it.
+const configHistoryTimeout = 5 * time.Second
+
+// ConfigSnapshot is one merged config as a sync delivered it.
+type ConfigSnapshot struct {
+	// Hash is the sha256 of the marshaled config.
+	Hash string `json:"hash"`
+	// Source lists the config URLs that answered, comma-separated.
+	Source string `json:"source"`
+	// TakenAt is when this config was first synced; LastSeenAt is the
+	// latest sync that returned it.
+	TakenAt    time.Time           `json:"takenAt"`
+	LastSeenAt time.Time           `json:"lastSeenAt"`
+	Config     common.RouterConfig `json:"config"`
+}
+
+// ConfigHistory stores config snapshots. Implemented by
+// internal/router/mongo.
+type ConfigHistory interface {
+	// Record stores s, or extends the newest snapshot's LastSeenAt to
+	// s.TakenAt when that snapshot has the same hash.
+	Record(ctx context.Context, s ConfigSnapshot) error
+	// At returns the snapshot in force at t: the newest taken at or
+	// before it.
+	At(ctx context.Context, t time.Time) (ConfigSnapshot, error)
+}
+
+// configHash is the snapshot hash of a marshaled config.
+func configHash(body []byte) string {
+	sum := sha256.Sum256(body)
+	return hex.EncodeToString(sum[:])
+}
+
+// record writes a synced config to cs.History; failures are logged, never
+// fail the sync.
+func (cs *ConfigSource) record(ctx context.Context, sources []sourceConfig, cfg common.RouterConfig, body []byte) {
+	if cs.History == nil {
+		return
+	}
+	urls := make([]string, len(sources))
+	for i, s := range sources {
+		urls[i] = redactURL(s.url)
+	}
+	now := time.Now().UTC()
+	snap := ConfigSnapshot{
+		Hash:       configHash(body),
+		Source:     strings.Join(urls, ","),
+		TakenAt:    now,
+		LastSeenAt: now,
+		Config:     redactConfig(cfg),
+	}
+	ctx, cancel := context.WithTimeout(ctx, configHistoryTimeout)
+	defer cancel()
+	if err := cs.History.Record(ctx, snap); err != nil {
+		slog.Warn("config history record failed", "hash", snap.Hash, "err", err)
+	}
+}
+
+// redactConfig drops the password from each queue URI: snapshots outlive
+// the credentials in them and are served to anyone who can read the API.
+// The hash is taken before redaction, so a rotated password still starts
+// a new snapshot.
+func redactConfig(cfg common.RouterConfig) common.RouterConfig {
+	queues := make([]common.QueueConfig, len(cfg.Queues))
+	for i, q := range cfg.Queues {
+		q.URI = redactURL(q.URI)
+		queues[i] = q
+	}
+	cfg.Queues = queues
+	return cfg
+}
+
+// redactURL is raw with any password replaced by "xxxxx".
+func redactURL(raw string) string {
+	if u, err := url.Parse(raw); err == nil && u.User != nil {
+		return u.Redacted()
+	}
+	return raw
+}
+
+// Config diff change kinds.
+const (
+	ConfigAdded   = "ADDED"
+	ConfigRemoved = "REMOVED"
+	ConfigChanged = "CHANGED"
+)
+
+// ConfigChange is one pool, queue or routing rule that differs between two
+// configs. Before is nil for an addition, After for a removal.
+type ConfigChange struct {
+	Key    string `json:"key"`
+	Change string `json:"change"`
+	Before any    `json:"before,omitempty"`
+	After  any    `json:"after,omitempty"`
+}
+
+// ConfigDiff is what changed from one config to another, keyed as config
+// sync merges: pools by code, queues by URI, routing rules by name. Each
+// list is in key order.
+type ConfigDiff struct {
+	Pools        []ConfigChange `json:"pools"`
+	Queues       []ConfigChange `json:"queues"`
+	RoutingRules []ConfigChange `json:"routingRules"`
+}
+
+// DiffConfigs compares from with to.
+func DiffConfigs(from, to common.RouterConfig) ConfigDiff {
+	poolKey := func(p common.PoolConfig) string { return p.Code }
+	queueKey := func(q common.QueueConfig) string { return q.URI }
+	ruleKey := func(r common.RoutingRule) string { return r.Name }
+	return ConfigDiff{
+		Pools:        diffKeyed(from.ProcessingPools, to.ProcessingPools, poolKey),
+		Queues:       diffKeyed(from.Queues, to.Queues, queueKey),
+		RoutingRules: diffKeyed(from.RoutingRules, to.RoutingRules, ruleKey),
+	}
+}
+
+// diffKeyed compares two lists by key; entries are equal when they
+// marshal to the same JSON. A key repeated within a list keeps its first
+// entry, as the merge does.
+func diffKeyed[T any](from, to []T, key func(T) string) []ConfigChange {
+	index := func(items []T) map[string]T {
+		m := make(map[string]T, len(items))
+		for _, it := range items {
+			if _, seen := m[key(it)]; !seen {
+				m[key(it)] = it
+			}
+		}
+		return m
+	}
+	before, after := index(from), index(to)
+	keys := make([]string, 0, len(before)+len(after))
+	for k := range before {
+		keys = append(keys, k)
+	}
+	for k := range after {
+		if _, ok := before[k]; !ok {
+			keys = append(keys, k)
+		}
+	}
+	slices.Sort(keys)
+
+	changes := []ConfigChange{}
+	for _, k := range keys {
+		b, inBefore := before[k]
+		a, inAfter := after[k]
+		switch {
+		case !inBefore:
+			changes = append(changes, ConfigChange{Key: k, Change: ConfigAdded, After: a})
+		case !inAfter:
+			changes = append(changes, ConfigChange{Key: k, Change: ConfigRemoved, Before: b})
+		default:
+			bj, _ := json.Marshal(b)
+			aj, _ := json.Marshal(a)
+			if !bytes.Equal(bj, aj) {
+				changes = append(changes, ConfigChange{Key: k, Change: ConfigChanged, Before: b, After: a})
+			}
+		}
+	}
+	return changes
+}
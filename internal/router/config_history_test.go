@@ -0,0 +1,110 @@
+package router
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// memHistory is an in-memory ConfigHistory with the same newest-hash
+// folding as the Mongo store.
+type memHistory struct {
+	mu    sync.Mutex
+	snaps []ConfigSnapshot
+}
+
+func (h *memHistory) Record(_ context.Context, s ConfigSnapshot) error {
+	h.mu.Lock()
+	defer h.mu.Unlock()
+	if n := len(h.snaps); n > 0 && h.snaps[n-1].Hash == s.Hash {
+		h.snaps[n-1].LastSeenAt = s.LastSeenAt
+		return nil
+	}
+	h.snaps = append(h.snaps, s)
+	return nil
+}
+
+func (h *memHistory) At(_ context.Context, t time.Time) (ConfigSnapshot, error) {
+	h.mu.Lock()
+	defer h.mu.Unlock()
+	for i := len(h.snaps) - 1; i >= 0; i-- {
+		if !h.snaps[i].TakenAt.After(t) {
+			return h.snaps[i], nil
+		}
+	}
+	return ConfigSnapshot{}, ErrNoConfigSnapshot
+}
+
+func TestConfigSourceRecordsSyncs(t *testing.T) {
+	var mu sync.Mutex
+	cfg := common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "P1", Concurrency: 5}},
+		Queues:          []common.QueueConfig{{Name: "q1", URI: "postgres://fc:s3cret@db/fc", Connections: 1}},
+	}
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		mu.Lock()
+		defer mu.Unlock()
+		_ = json.NewEncoder(w).Encode(cfg)
+	}))
+	defer srv.Close()
+
+	hist := &memHistory{}
+	cs := NewConfigSource(srv.URL)
+	cs.History = hist
+	ctx := context.Background()
+
+	live, err := cs.Fetch(ctx)
+	require.NoError(t, err)
+	_, err = cs.Fetch(ctx)
+	require.ErrorIs(t, err, ErrUnchanged)
+	require.Len(t, hist.snaps, 1, "an unchanged sync extends the snapshot")
+	first := hist.snaps[0]
+	assert.Equal(t, srv.URL, first.Source)
+	assert.Equal(t, "postgres://fc:xxxxx@db/fc", first.Config.Queues[0].URI, "queue passwords are redacted")
+	assert.Equal(t, "postgres://fc:s3cret@db/fc", live.Queues[0].URI, "the applied config keeps its password")
+
+	mu.Lock()
+	cfg.ProcessingPools[0].Concurrency = 20
+	mu.Unlock()
+	_, err = cs.Fetch(ctx)
+	require.NoError(t, err)
+	require.Len(t, hist.snaps, 2)
+	assert.NotEqual(t, first.Hash, hist.snaps[1].Hash)
+
+	at, err := hist.At(ctx, first.TakenAt)
+	require.NoError(t, err)
+	assert.Equal(t, uint32(5), at.Config.ProcessingPools[0].Concurrency)
+}
+
+func TestDiffConfigs(t *testing.T) {
+	r10 := uint32(10)
+	from := common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "A", Concurrency: 5}, {Code: "B", Concurrency: 5}},
+		Queues:          []common.QueueConfig{{Name: "q1", URI: "uri1", Connections: 1}},
+		RoutingRules:    []common.RoutingRule{{Name: "r1", PoolCode: "A"}},
+	}
+	to := common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "B", Concurrency: 5, RateLimitPerMinute: &r10}, {Code: "C", Concurrency: 1}},
+		Queues:          []common.QueueConfig{{Name: "q1", URI: "uri1", Connections: 1}},
+		RoutingRules:    []common.RoutingRule{{Name: "r1", PoolCode: "A"}},
+	}
+
+	d := DiffConfigs(from, to)
+	require.Len(t, d.Pools, 3)
+	assert.Equal(t, ConfigChange{Key: "A", Change: ConfigRemoved, Before: from.ProcessingPools[0]}, d.Pools[0])
+	assert.Equal(t, "B", d.Pools[1].Key)
+	assert.Equal(t, ConfigChanged, d.Pools[1].Change)
+	assert.Equal(t, ConfigChange{Key: "C", Change: ConfigAdded, After: to.ProcessingPools[1]}, d.Pools[2])
+	assert.Empty(t, d.Queues)
+	assert.Empty(t, d.RoutingRules)
+	assert.NotNil(t, d.Queues, "an empty list, not null")
+}
@@ -26,6 +26,9 @@ type ConfigSource struct {
 	// MaxAttempts/RetryDelay govern per-URL retry (Java/Rust defaults: 12 / 5s).
 	MaxAttempts int
 	RetryDelay  time.Duration
+	// History, when set, records every successful fetch (see
+	// ConfigHistory).
+	History ConfigHistory
 
 	mu   sync.Mutex
 	last []byte // last merged config (marshaled) for change detection
@@ -97,6 +100,7 @@ func (cs *ConfigSource) Fetch(ctx context.Context) (*common.RouterConfig, error)
 	if err != nil {
 		return nil, fmt.Errorf("config marshal: %w", err)
 	}
+	cs.record(ctx, ok, merged, body)
 	cs.mu.Lock()
 	unchanged := len(cs.last) > 0 && bytesEqual(cs.last, body)
 	cs.last = body
@@ -155,7 +159,9 @@ func (cs *ConfigSource) fetchOnce(ctx context.Context, url string) (*common.Rout
 // mergeConfigs unions multiple source configs, first-wins: a pool is keyed by
 // code, a queue by URI; the first source to define a key wins, later
 // duplicates are dropped (with a warning on a value conflict). 1:1 with Rust
-// merge_configs. A single source passes through unchanged.
+// merge_configs. Routing rules (a Go extension) merge the same way, keyed by
+// name, and the first source's apply guard wins. A single source passes
+// through unchanged.
 func mergeConfigs(sources []sourceConfig) common.RouterConfig {
 	if len(sources) == 1 {
 		return sources[0].cfg
@@ -163,7 +169,11 @@ func mergeConfigs(sources []sourceConfig) common.RouterConfig {
 	var merged common.RouterConfig
 	poolOrigin := map[string]string{}
 	queueOrigin := map[string]string{}
+	ruleOrigin := map[string]string{}
 	for _, s := range sources {
+		if merged.ApplyGuard == nil {
+			merged.ApplyGuard = s.cfg.ApplyGuard
+		}
 		for _, p := range s.cfg.ProcessingPools {
 			if orig, seen := poolOrigin[p.Code]; seen {
 				if conflictingPool(merged.ProcessingPools, p) {
@@ -186,6 +196,15 @@ func mergeConfigs(sources []sourceConfig) common.RouterConfig {
 			queueOrigin[q.URI] = s.url
 			merged.Queues = append(merged.Queues, q)
 		}
+		for _, r := range s.cfg.RoutingRules {
+			if orig, seen := ruleOrigin[r.Name]; seen {
+				slog.Warn("duplicate routing rule — keeping first",
+					"rule", r.Name, "kept_source", orig, "dropped_source", s.url)
+				continue
+			}
+			ruleOrigin[r.Name] = s.url
+			merged.RoutingRules = append(merged.RoutingRules, r)
+		}
 	}
 	return merged
 }
@@ -193,7 +212,7 @@ func mergeConfigs(sources []sourceConfig) common.RouterConfig {
 func conflictingPool(existing []common.PoolConfig, p common.PoolConfig) bool {
 	for _, e := range existing {
 		if e.Code == p.Code {
-			return e.Concurrency != p.Concurrency || !u32PtrEqual(e.RateLimitPerMinute, p.RateLimitPerMinute)
+			return e.Concurrency != p.Concurrency || !u32PtrEqual(e.RateLimitPerMinute, p.RateLimitPerMinute) || e.Killed != p.Killed
 		}
 	}
 	return false
@@ -215,8 +234,9 @@ func u32PtrEqual(a, b *uint32) bool {
 	return *a == *b
 }
 
-// Watch polls cs every interval and applies the result to manager.
-// Blocks until ctx is cancelled.
+// Watch polls cs every interval and applies the result to manager via
+// Manager.Apply, so a config carrying an ApplyGuard is baked. Blocks until
+// ctx is cancelled.
 func Watch(ctx context.Context, cs *ConfigSource, manager *Manager, interval time.Duration) {
 	tick := time.NewTicker(interval)
 	defer tick.Stop()
@@ -230,7 +250,7 @@ func Watch(ctx context.Context, cs *ConfigSource, manager *Manager, interval tim
 			slog.Warn("config fetch failed", "err", err)
 			return
 		}
-		if err := manager.Reconfigure(ctx, *cfg); err != nil {
+		if err := manager.Apply(ctx, *cfg); err != nil {
 			slog.Warn("manager reconfigure failed", "err", err)
 		}
 	}
@@ -0,0 +1,99 @@
+package router
+
+import (
+	"context"
+	"time"
+)
+
+// CPU isolation between pools. Goroutines are preemptively scheduled, so
+// one pool's CPU-heavy middleware cannot block another pool's workers
+// outright the way a busy task stalls a shared async runtime. It can still
+// occupy every P (GOMAXPROCS), so a pool's cheap deliveries queue behind
+// another pool's payload transformations. A pool configured with CPUSlots
+// runs its CPU-bound middleware hooks (see CPUBound) on at most that many
+// goroutines at once; the rest wait for a slot without holding a thread.
+//
+// fc_pool_schedule_delay_seconds shows whether it works: the time from a
+// message being handed to a worker goroutine until the goroutine runs.
+// It stays sub-millisecond while the process has spare CPU and rises for
+// every pool when something saturates it.
+
+// CPUBound is implemented by mediation middleware whose hooks are CPU-heavy
+// (payload transformation, compression, encryption). Their PreSend and
+// PostReceive run inside the delivering pool's CPU slots.
+type CPUBound interface {
+	CPUBound() bool
+}
+
+// cpuGate bounds concurrent CPU-bound work for one pool.
+type cpuGate struct {
+	slots   chan struct{}
+	metrics *PoolMetricsCollector
+}
+
+type cpuGateKey struct{}
+
+// withCPUGate attaches the pool's gate to a delivery's context.
+func withCPUGate(ctx context.Context, g *cpuGate) context.Context {
+	if g == nil {
+		return ctx
+	}
+	return context.WithValue(ctx, cpuGateKey{}, g)
+}
+
+// runCPUBound runs fn inside a CPU slot of the pool delivering ctx's
+// message, or directly when the pool has no CPU limit.
+func runCPUBound(ctx context.Context, fn func() error) error {
+	g, _ := ctx.Value(cpuGateKey{}).(*cpuGate)
+	if g == nil {
+		return fn()
+	}
+	start := time.Now()
+	select {
+	case g.slots <- struct{}{}:
+	case <-ctx.Done():
+		return ctx.Err()
+	}
+	g.metrics.RecordCPUWait(time.Since(start))
+	defer func() { <-g.slots }()
+	return fn()
+}
+
+// runHook runs a middleware hook, inside a CPU slot when mw is CPU-bound.
+func runHook(ctx context.Context, mw MediationMiddleware, hook func() error) error {
+	if c, ok := mw.(CPUBound); ok && c.CPUBound() {
+		return runCPUBound(ctx, hook)
+	}
+	return hook()
+}
+
+// SetCPUSlots changes the pool's CPU slot count; 0 removes the limit.
+// Deliveries already holding a gate finish against it.
+func (p *Pool) SetCPUSlots(n uint32) {
+	if n == 0 {
+		p.cpu.Store(nil)
+		return
+	}
+	if g := p.cpu.Load(); g != nil && cap(g.slots) == int(n) {
+		return
+	}
+	p.cpu.Store(&cpuGate{slots: make(chan struct{}, n), metrics: p.metrics})
+}
+
+// CPUSlots returns the pool's CPU slot count; 0 when uncapped.
+func (p *Pool) CPUSlots() uint32 {
+	if g := p.cpu.Load(); g != nil {
+		return uint32(cap(g.slots))
+	}
+	return 0
+}
+
+// goScheduled starts fn on a new goroutine and records how long the
+// scheduler took to run it.
+func (p *Pool) goScheduled(fn func()) {
+	handed := time.Now()
+	go func() {
+		p.metrics.RecordScheduleDelay(time.Since(handed))
+		fn()
+	}()
+}
@@ -0,0 +1,68 @@
+package router
+
+import (
+	"context"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+type cpuHeavy struct{ NopMiddleware }
+
+func (cpuHeavy) CPUBound() bool { return true }
+
+func TestCPUSlotsBoundCPUBoundHooks(t *testing.T) {
+	p := NewPool(common.PoolConfig{Code: "P", CPUSlots: 1}, &cascadeMediator{}, nil, func(string) queue.Consumer { return nil })
+	require.Equal(t, uint32(1), p.CPUSlots())
+	ctx := withCPUGate(context.Background(), p.cpu.Load())
+
+	var running, peak atomic.Int32
+	hook := func() error {
+		n := running.Add(1)
+		for {
+			cur := peak.Load()
+			if n <= cur || peak.CompareAndSwap(cur, n) {
+				break
+			}
+		}
+		time.Sleep(5 * time.Millisecond)
+		running.Add(-1)
+		return nil
+	}
+	var wg sync.WaitGroup
+	for range 4 {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			assert.NoError(t, runHook(ctx, cpuHeavy{}, hook))
+		}()
+	}
+	wg.Wait()
+	assert.Equal(t, int32(1), peak.Load())
+	assert.Equal(t, uint64(4), p.metrics.CPUWaitSnapshot().Count)
+
+	// Hooks of middleware that is not CPU-bound bypass the gate.
+	p.cpu.Load().slots <- struct{}{}
+	require.NoError(t, runHook(ctx, NopMiddleware{}, func() error { return nil }))
+	cancelled, cancel := context.WithCancel(ctx)
+	cancel()
+	assert.ErrorIs(t, runHook(cancelled, cpuHeavy{}, hook), context.Canceled)
+
+	p.SetCPUSlots(0)
+	assert.Zero(t, p.CPUSlots())
+}
+
+func TestGoScheduledRecordsDelay(t *testing.T) {
+	p := NewPool(common.PoolConfig{Code: "P"}, &cascadeMediator{}, nil, func(string) queue.Consumer { return nil })
+	done := make(chan struct{})
+	p.goScheduled(func() { close(done) })
+	<-done
+	assert.Equal(t, uint64(1), p.metrics.ScheduleDelaySnapshot().Count)
+}
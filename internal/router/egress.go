@@ -0,0 +1,102 @@
+package router
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net"
+	"net/netip"
+	"sync/atomic"
+)
+
+// ResolveEgressAddrs turns FC_ROUTER_EGRESS_BIND entries into the local
+// addresses deliveries may leave from. An entry is an IP literal or an
+// interface name; an interface contributes its non-link-local addresses.
+// Anything else is an error, so a typo fails startup rather than
+// silently delivering from the default route.
+func ResolveEgressAddrs(entries []string) ([]netip.Addr, error) {
+	var out []netip.Addr
+	for _, e := range entries {
+		if addr, err := netip.ParseAddr(e); err == nil {
+			out = append(out, addr.Unmap())
+			continue
+		}
+		iface, err := net.InterfaceByName(e)
+		if err != nil {
+			return nil, fmt.Errorf("egress bind %q: not an IP address or interface", e)
+		}
+		addrs, err := iface.Addrs()
+		if err != nil {
+			return nil, fmt.Errorf("egress bind %q: %w", e, err)
+		}
+		n := len(out)
+		for _, a := range addrs {
+			ipnet, ok := a.(*net.IPNet)
+			if !ok {
+				continue
+			}
+			addr, ok := netip.AddrFromSlice(ipnet.IP)
+			if !ok {
+				continue
+			}
+			addr = addr.Unmap()
+			// A link-local source can't reach a receiver off-link.
+			if addr.IsLinkLocalUnicast() || addr.IsMulticast() || addr.IsUnspecified() {
+				continue
+			}
+			out = append(out, addr)
+		}
+		if len(out) == n {
+			return nil, fmt.Errorf("egress bind %q: interface has no usable address", e)
+		}
+	}
+	return out, nil
+}
+
+// egressDialer dials from a fixed set of local addresses, so a
+// multi-homed host delivers from the IPs receivers have allowlisted.
+// Connections rotate through the addresses of the family being dialled;
+// IPv4 is tried before IPv6 when both are bound.
+type egressDialer struct {
+	base   net.Dialer
+	v4, v6 []netip.Addr
+	next   atomic.Uint64
+}
+
+func newEgressDialer(base net.Dialer, addrs []netip.Addr) *egressDialer {
+	d := &egressDialer{base: base}
+	for _, a := range addrs {
+		if a.Is4() {
+			d.v4 = append(d.v4, a)
+		} else {
+			d.v6 = append(d.v6, a)
+		}
+	}
+	return d
+}
+
+var errNoEgressAddr = errors.New("no bound egress address can reach the target")
+
+func (d *egressDialer) DialContext(ctx context.Context, _, address string) (net.Conn, error) {
+	err := errNoEgressAddr
+	for _, fam := range []struct {
+		network string
+		addrs   []netip.Addr
+	}{{"tcp4", d.v4}, {"tcp6", d.v6}} {
+		if len(fam.addrs) == 0 {
+			continue
+		}
+		local := fam.addrs[d.next.Add(1)%uint64(len(fam.addrs))]
+		dialer := d.base
+		dialer.LocalAddr = &net.TCPAddr{IP: local.AsSlice()}
+		conn, dialErr := dialer.DialContext(ctx, fam.network, address)
+		if dialErr == nil {
+			return conn, nil
+		}
+		err = dialErr
+		if ctx.Err() != nil {
+			break
+		}
+	}
+	return nil, err
+}
@@ -0,0 +1,69 @@
+package router_test
+
+import (
+	"context"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"net/netip"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+func TestResolveEgressAddrs(t *testing.T) {
+	addrs, err := router.ResolveEgressAddrs([]string{"127.0.0.1", "::ffff:10.0.0.1"})
+	require.NoError(t, err)
+	assert.Equal(t, []netip.Addr{netip.MustParseAddr("127.0.0.1"), netip.MustParseAddr("10.0.0.1")}, addrs)
+
+	_, err = router.ResolveEgressAddrs([]string{"no-such-iface0"})
+	assert.Error(t, err, "an unknown interface must fail startup")
+
+	ifaces, _ := net.Interfaces()
+	for _, iface := range ifaces {
+		if iface.Flags&net.FlagLoopback == 0 {
+			continue
+		}
+		addrs, err := router.ResolveEgressAddrs([]string{iface.Name})
+		require.NoError(t, err)
+		assert.NotEmpty(t, addrs)
+		return
+	}
+}
+
+func TestMediatorDeliversFromBoundAddress(t *testing.T) {
+	var remote string
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		remote, _, _ = net.SplitHostPort(r.RemoteAddr)
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+
+	mediate := func(egress string) common.MediationResult {
+		cfg := router.DevMediatorConfig()
+		cfg.EgressAddrs = []netip.Addr{netip.MustParseAddr(egress)}
+		cfg.MaxRetries = 0
+		m := router.NewHTTPMediator(cfg, router.NewBreakerRegistry(router.DefaultBreakerConfig()))
+		ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+		defer cancel()
+		return m.Mediate(ctx, &common.Message{
+			ID:              "msg_EGRESS",
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: srv.URL,
+		}).Result
+	}
+
+	require.Equal(t, common.MediationSuccess, mediate("127.0.0.1"))
+	assert.Equal(t, "127.0.0.1", remote)
+
+	// Only an IPv6 source is bound, so an IPv4 receiver is unreachable
+	// rather than silently reached from the default route.
+	remote = ""
+	assert.NotEqual(t, common.MediationSuccess, mediate("::1"))
+	assert.Empty(t, remote)
+}
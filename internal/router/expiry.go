@@ -0,0 +1,41 @@
+package router
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// expiryWarnInterval bounds how often a pool raises its expired-messages
+// warning; drops in between are folded into the next one's count.
+const expiryWarnInterval = time.Minute
+
+// expired reports whether qm's expiresAt has passed, counting the drop and
+// raising a throttled EXPIRED warning when it has. An expired message is
+// ACKed without delivery, so a backlog that built up during an outage does
+// not reach consumers as a burst of stale notifications.
+func (p *Pool) expired(ctx context.Context, qm common.QueuedMessage) bool {
+	now := time.Now()
+	if !qm.Message.Expired(now) {
+		return false
+	}
+	slog.InfoContext(ctx, "message expired before delivery; ACKing without delivery",
+		"expires_at", qm.Message.ExpiresAt.UTC().Format(time.RFC3339))
+	p.metrics.RecordExpired()
+	p.expiredSinceWarn.Add(1)
+
+	if p.warnings == nil {
+		return true
+	}
+	last := p.expiredWarnedAt.Load()
+	if now.UnixNano()-last < int64(expiryWarnInterval) || !p.expiredWarnedAt.CompareAndSwap(last, now.UnixNano()) {
+		return true
+	}
+	pending := p.expiredSinceWarn.Swap(0)
+	p.warnings.Add(WarningCategoryQueueHealth, WarningWarning,
+		fmt.Sprintf("pool %s dropped %d expired messages without delivery", p.cfg.Code, pending), "pool")
+	return true
+}
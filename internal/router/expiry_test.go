@@ -0,0 +1,54 @@
+package router
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+func TestExpiredMessageIsAckedWithoutDelivery(t *testing.T) {
+	c := &grConsumer{id: "q1"}
+	med := &grMediator{outcome: common.Success()}
+	p := grPool(med, c)
+	ws := NewWarningService(WarningServiceConfig{})
+	p.warnings = ws
+
+	past := time.Now().Add(-time.Hour)
+	for _, id := range []string{"old_1", "old_2"} {
+		qm := grMsg(id, "http://t/x")
+		qm.Message.ExpiresAt = &past
+		if res, _ := p.processOne(context.Background(), qm); res != processDone {
+			t.Fatalf("%s: res=%d, want processDone", id, res)
+		}
+	}
+	if med.called.Load() {
+		t.Fatal("expired message was mediated")
+	}
+	if c.acks.Load() != 2 {
+		t.Fatalf("acks=%d, want 2", c.acks.Load())
+	}
+	if got := p.metrics.Snapshot().TotalExpired; got != 2 {
+		t.Fatalf("TotalExpired=%d, want 2", got)
+	}
+	if got := len(ws.All()); got != 1 {
+		t.Fatalf("warnings=%d, want one (throttled)", got)
+	}
+}
+
+func TestUnexpiredMessageIsDelivered(t *testing.T) {
+	c := &grConsumer{id: "q1"}
+	med := &grMediator{outcome: common.Success()}
+	p := grPool(med, c)
+
+	future := time.Now().Add(time.Hour)
+	qm := grMsg("fresh", "http://t/y")
+	qm.Message.ExpiresAt = &future
+	if res, _ := p.processOne(context.Background(), qm); res != processDone || !med.called.Load() {
+		t.Fatalf("res=%d mediated=%v, want delivery", res, med.called.Load())
+	}
+	if got := p.metrics.Snapshot().TotalExpired; got != 0 {
+		t.Fatalf("TotalExpired=%d, want 0", got)
+	}
+}
@@ -0,0 +1,142 @@
+package router
+
+import (
+	"context"
+	"encoding/json"
+	"log/slog"
+	"math"
+	"sort"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// FIFO group handover across leadership changes. A leader that loses the
+// lock may still be mid-delivery for some ordered message groups while the
+// new leader starts polling the same queues; without coordination the two
+// could deliver one group's messages out of order. On leader-loss the old
+// leader records every group it is holding (buffered or in delivery) next
+// to the standby lock with a short lease; the new leader reads the list on
+// leader-gain and defers those groups' messages back to the broker until
+// the lease expires, by which time the old leader has finished or given up.
+//
+// A leader that crashes or is partitioned from Redis records nothing; its
+// in-flight deliveries die with it or time out while its lock TTL runs
+// down, before anyone else can take over.
+const groupHandoverKey = "fifo-groups"
+
+// groupHandover is the value stored under groupHandoverKey.
+type groupHandover struct {
+	Groups    []string  `json:"groups"`
+	ExpiresAt time.Time `json:"expiresAt"`
+}
+
+// groupLease is the set of groups deferred after takeover.
+type groupLease struct {
+	groups map[string]struct{}
+	until  time.Time
+}
+
+// ActiveGroups returns the ordered message groups any pool is holding —
+// buffered or in delivery — sorted. Ungrouped messages are not included.
+func (m *Manager) ActiveGroups() []string {
+	m.mu.Lock()
+	pools := make([]*Pool, 0, len(m.pools))
+	for _, p := range m.pools {
+		pools = append(pools, p)
+	}
+	m.mu.Unlock()
+
+	seen := make(map[string]struct{})
+	for _, p := range pools {
+		for _, g := range p.groups.keys() {
+			if g != "" {
+				seen[g] = struct{}{}
+			}
+		}
+	}
+	out := make([]string, 0, len(seen))
+	for g := range seen {
+		out = append(out, g)
+	}
+	sort.Strings(out)
+	return out
+}
+
+// DeferGroups makes route defer ordered messages in groups until the given
+// time, replacing any previous lease.
+func (m *Manager) DeferGroups(groups []string, until time.Time) {
+	if len(groups) == 0 || !time.Now().Before(until) {
+		return
+	}
+	l := &groupLease{groups: make(map[string]struct{}, len(groups)), until: until}
+	for _, g := range groups {
+		l.groups[g] = struct{}{}
+	}
+	m.handover.Store(l)
+}
+
+// groupDeferral reports whether msg belongs to a group under handover lease
+// and, if so, the delay (whole seconds, at least 1) until the lease expires.
+func (m *Manager) groupDeferral(msg common.QueuedMessage) (uint32, bool) {
+	l := m.handover.Load()
+	if l == nil || !msg.Message.DispatchMode.RequiresOrdering() || msg.Message.MessageGroupID == nil {
+		return 0, false
+	}
+	remaining := time.Until(l.until)
+	if remaining <= 0 {
+		m.handover.CompareAndSwap(l, nil)
+		return 0, false
+	}
+	if _, ok := l.groups[*msg.Message.MessageGroupID]; !ok {
+		return 0, false
+	}
+	return uint32(math.Ceil(remaining.Seconds())), true
+}
+
+// recordActiveGroups stores this instance's active groups for the next
+// leader. Called on leader-loss, before the pools are stopped.
+func (s *Server) recordActiveGroups(ctx context.Context) {
+	groups := s.Manager.ActiveGroups()
+	if len(groups) == 0 {
+		return
+	}
+	lease := s.Cfg.GroupHandoverLease
+	b, err := json.Marshal(groupHandover{Groups: groups, ExpiresAt: time.Now().Add(lease)})
+	if err != nil {
+		return
+	}
+	ctx, cancel := context.WithTimeout(ctx, 2*time.Second)
+	defer cancel()
+	if err := s.election.PutShared(ctx, groupHandoverKey, b, lease); err != nil {
+		slog.Warn("group handover record failed", "groups", len(groups), "err", err)
+		return
+	}
+	slog.Info("recorded active message groups for handover", "groups", len(groups), "lease", lease)
+}
+
+// adoptActiveGroups loads the previous leader's groups and defers them
+// until its lease expires. Called on leader-gain, before pools start.
+func (s *Server) adoptActiveGroups(ctx context.Context) {
+	ctx, cancel := context.WithTimeout(ctx, 2*time.Second)
+	defer cancel()
+	b, err := s.election.Shared(ctx, groupHandoverKey)
+	if err != nil {
+		slog.Warn("group handover load failed", "err", err)
+		return
+	}
+	if b == nil {
+		return
+	}
+	var h groupHandover
+	if err := json.Unmarshal(b, &h); err != nil {
+		slog.Warn("group handover decode failed", "err", err)
+		return
+	}
+	if !time.Now().Before(h.ExpiresAt) {
+		return
+	}
+	s.Manager.DeferGroups(h.Groups, h.ExpiresAt)
+	slog.Info("deferring message groups held by the previous leader",
+		"groups", len(h.Groups), "until", h.ExpiresAt)
+}
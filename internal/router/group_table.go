@@ -0,0 +1,183 @@
+package router
+
+import (
+	"container/list"
+	"context"
+	"hash/maphash"
+	"log/slog"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Message-group state. An ordered message's group has an entry in its
+// pool's group table while it holds buffered messages or a drainer; the
+// entry goes when the group drains, so the table tracks live groups rather
+// than every group ever seen. With millions of distinct groups a day two
+// costs remain: a single pool-wide lock taken on every submit and drain
+// step, and groups left idle — messages buffered, no drainer — after their
+// drainer's consumer was cancelled and before a redelivery restarts them.
+//
+// The table is split into shards by a hash of the group, each with its own
+// lock and a least-recently-touched list. A shard holding its share of
+// maxPoolGroups evicts its oldest idle groups before taking a new one: an
+// idle group untouched for groupEvictIdleAfter has its buffered messages
+// released to the broker, which redelivers them in order. A group with a
+// running drainer is never evicted, so FIFO delivery of active groups is
+// unaffected; with none idle the shard grows past its share instead of
+// refusing work (the pool's buffer capacity bounds that).
+
+const (
+	// groupShards is the number of lock shards per pool.
+	groupShards = 32
+	// maxPoolGroups is the group count per pool above which idle groups
+	// are evicted.
+	maxPoolGroups = 65536
+	// groupEvictIdleAfter is how long a group must sit idle before it may
+	// be evicted, so a group just buffered isn't released before its
+	// drainer starts.
+	groupEvictIdleAfter = 30 * time.Second
+	// groupEntryBytes estimates one entry's footprint beyond its key: the
+	// groupQueue, its list element and map slot.
+	groupEntryBytes = 192
+)
+
+// groupTable is a pool's sharded group state.
+type groupTable struct {
+	seed   maphash.Seed
+	shards [groupShards]groupShard
+
+	count     atomic.Int64
+	bytes     atomic.Int64
+	evictions atomic.Uint64
+}
+
+// groupShard is one lock's worth of groups. lru orders them by last touch,
+// least recent at the front.
+type groupShard struct {
+	mu     sync.Mutex
+	groups map[string]*groupQueue
+	lru    list.List
+}
+
+func newGroupTable() *groupTable {
+	t := &groupTable{seed: maphash.MakeSeed()}
+	for i := range t.shards {
+		t.shards[i].groups = make(map[string]*groupQueue)
+	}
+	return t
+}
+
+// shard returns the shard holding group.
+func (t *groupTable) shard(group string) *groupShard {
+	return &t.shards[maphash.String(t.seed, group)%groupShards]
+}
+
+// getOrCreateLocked returns group's entry, creating it, and touches it.
+// Caller holds s.mu.
+func (t *groupTable) getOrCreateLocked(s *groupShard, group string) *groupQueue {
+	gq := s.groups[group]
+	if gq == nil {
+		gq = &groupQueue{key: group}
+		gq.elem = s.lru.PushBack(gq)
+		s.groups[group] = gq
+		t.count.Add(1)
+		t.bytes.Add(groupEntryBytes + int64(len(group)))
+	} else {
+		s.lru.MoveToBack(gq.elem)
+	}
+	gq.touched = time.Now()
+	return gq
+}
+
+// touchLocked marks gq as just used. Caller holds s.mu.
+func (t *groupTable) touchLocked(s *groupShard, gq *groupQueue) {
+	s.lru.MoveToBack(gq.elem)
+	gq.touched = time.Now()
+}
+
+// removeLocked drops gq. Caller holds s.mu.
+func (t *groupTable) removeLocked(s *groupShard, gq *groupQueue) {
+	s.lru.Remove(gq.elem)
+	delete(s.groups, gq.key)
+	t.count.Add(-1)
+	t.bytes.Add(-(groupEntryBytes + int64(len(gq.key))))
+}
+
+// evictIdleLocked removes the shard's least recently touched idle groups
+// until it is under its share of maxPoolGroups or none is left to evict,
+// returning their buffered messages. Caller holds s.mu.
+func (t *groupTable) evictIdleLocked(s *groupShard) []common.QueuedMessage {
+	var released []common.QueuedMessage
+	cutoff := time.Now().Add(-groupEvictIdleAfter)
+	for e := s.lru.Front(); e != nil && len(s.groups) >= maxPoolGroups/groupShards; {
+		gq := e.Value.(*groupQueue)
+		if gq.touched.After(cutoff) {
+			break // everything behind it was touched later still
+		}
+		next := e.Next()
+		if !gq.working {
+			released = append(released, gq.msgs...)
+			t.removeLocked(s, gq)
+			t.evictions.Add(1)
+		}
+		e = next
+	}
+	return released
+}
+
+// keys returns every group in the table, unordered.
+func (t *groupTable) keys() []string {
+	out := make([]string, 0, t.count.Load())
+	for i := range t.shards {
+		s := &t.shards[i]
+		s.mu.Lock()
+		for g := range s.groups {
+			out = append(out, g)
+		}
+		s.mu.Unlock()
+	}
+	return out
+}
+
+// flush empties the table, returning every buffered message.
+func (t *groupTable) flush() []common.QueuedMessage {
+	var flushed []common.QueuedMessage
+	for i := range t.shards {
+		s := &t.shards[i]
+		s.mu.Lock()
+		for _, gq := range s.groups {
+			flushed = append(flushed, gq.msgs...)
+			gq.msgs = nil
+			t.removeLocked(s, gq)
+		}
+		s.mu.Unlock()
+	}
+	return flushed
+}
+
+// size returns the number of groups held.
+func (t *groupTable) size() int { return int(t.count.Load()) }
+
+// makeRoom evicts idle groups from group's shard when it is full, before
+// group is added, releasing their messages to the broker.
+func (p *Pool) makeRoom(ctx context.Context, group string) {
+	s := p.groups.shard(group)
+	s.mu.Lock()
+	if _, ok := s.groups[group]; ok || len(s.groups) < maxPoolGroups/groupShards {
+		s.mu.Unlock()
+		return
+	}
+	released := p.groups.evictIdleLocked(s)
+	s.mu.Unlock()
+	if len(released) == 0 {
+		return
+	}
+	slog.WarnContext(ctx, "pool: evicting idle message groups", "pool", p.cfg.Code, "released_messages", len(released))
+	for _, m := range released {
+		p.queueSize.Add(^uint32(0))
+		p.nackMsg(ctx, m, ptrU32(10), "idle message group evicted")
+	}
+}
@@ -0,0 +1,69 @@
+package router
+
+import (
+	"context"
+	"fmt"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// fillShard buffers one message in each of enough groups to fill the shard
+// holding "new", returning the groups in insertion order.
+func fillShard(p *Pool) []string {
+	s := p.groups.shard("new")
+	var groups []string
+	for i := 0; len(groups) < maxPoolGroups/groupShards; i++ {
+		g := fmt.Sprintf("g%d", i)
+		if p.groups.shard(g) != s {
+			continue
+		}
+		p.enqueue(g, common.QueuedMessage{Message: common.Message{ID: "m-" + g}})
+		groups = append(groups, g)
+	}
+	return groups
+}
+
+func TestGroupTableEvictsOldestIdleGroup(t *testing.T) {
+	p := &Pool{groups: newGroupTable()}
+	groups := fillShard(p)
+	s := p.groups.shard("new")
+	s.mu.Lock()
+	for _, gq := range s.groups {
+		gq.touched = time.Now().Add(-time.Minute)
+	}
+	s.groups[groups[0]].working = true // oldest, but has a drainer
+	s.mu.Unlock()
+	before := p.groups.bytes.Load()
+
+	p.makeRoom(context.Background(), "new")
+
+	s.mu.Lock()
+	_, kept := s.groups[groups[0]]
+	_, evicted := s.groups[groups[1]]
+	s.mu.Unlock()
+	assert.True(t, kept, "a group with a drainer is never evicted")
+	assert.False(t, evicted, "the oldest idle group goes")
+	assert.Equal(t, uint64(1), p.groups.evictions.Load())
+	assert.Equal(t, before-groupEntryBytes-int64(len(groups[1])), p.groups.bytes.Load())
+	assert.Equal(t, uint32(len(groups)-1), p.queueSize.Load(), "the evicted message left the buffer")
+}
+
+func TestGroupTableKeepsRecentlyTouchedGroups(t *testing.T) {
+	p := &Pool{groups: newGroupTable()}
+	groups := fillShard(p)
+
+	p.makeRoom(context.Background(), "new")
+
+	assert.Zero(t, p.groups.evictions.Load(), "groups buffered just now aren't idle yet")
+	assert.Equal(t, len(groups), p.groups.size())
+
+	flushed := p.groups.flush()
+	require.Len(t, flushed, len(groups))
+	assert.Zero(t, p.groups.size())
+	assert.Zero(t, p.groups.bytes.Load())
+}
@@ -169,10 +169,11 @@ func TestGuardrail_RetryOnPanic(t *testing.T) {
 
 // Hammer submit() from many goroutines across both dispatch paths (IMMEDIATE
 // goroutine-per-message + ordered per-group drainers) and overlapping groups.
-// Exercises groupQs (p.mu), the swappable semaphore and the atomic counters
-// concurrently. Under -race, any future edit that drops a lock fails here (or
-// panics on concurrent map write). All messages succeed here, so the invariant
-// is: every submitted message is ACKed exactly once (no loss, no double-ack).
+// Exercises the group table's shard locks, the swappable semaphore and the
+// atomic counters concurrently. Under -race, any future edit that drops a
+// lock fails here (or panics on concurrent map write). All messages succeed
+// here, so the invariant is: every submitted message is ACKed exactly once
+// (no loss, no double-ack).
 func TestGuardrail_ConcurrentSubmitNoRaceAndResolvesEach(t *testing.T) {
 	const n = 600
 	c := &grConsumer{id: "q1"}
@@ -46,11 +46,18 @@ type PoolStats struct {
 	QueueSize          uint32                      `json:"queueSize"`
 	QueueCapacity      uint32                      `json:"queueCapacity"`
 	MessageGroupCount  uint32                      `json:"messageGroupCount"`
+	GroupStateBytes    int64                       `json:"groupStateBytes"`
+	GroupEvictions     uint64                      `json:"groupEvictions"`
 	RateLimitPerMinute *uint32                     `json:"rateLimitPerMinute,omitempty"`
 	IsRateLimited      bool                        `json:"isRateLimited"`
+	RateProfile        string                      `json:"rateProfile,omitempty"`
 	Metrics            *common.EnhancedPoolMetrics `json:"metrics,omitempty"`
 	// Histogram is the cumulative mediation-latency histogram, emitted by the
 	// Prometheus collector as fc_mediation_duration_seconds. Not serialized to
 	// the dashboard JSON (the dashboard uses Metrics.ProcessingTime instead).
 	Histogram MediationHistogram `json:"-"`
+	// ScheduleDelay and CPUWait feed fc_pool_schedule_delay_seconds and
+	// fc_pool_cpu_wait_seconds (see PoolMetricsCollector).
+	ScheduleDelay MediationHistogram `json:"-"`
+	CPUWait       MediationHistogram `json:"-"`
 }
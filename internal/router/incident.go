@@ -0,0 +1,306 @@
+package router
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"regexp"
+	"sort"
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// IncidentState is where an incident sits in its lifecycle.
+type IncidentState string
+
+const (
+	IncidentOpen         IncidentState = "OPEN"
+	IncidentAcknowledged IncidentState = "ACKNOWLEDGED"
+	IncidentResolved     IncidentState = "RESOLVED"
+)
+
+// ErrIncidentNotFound is returned by the incident mutators for an unknown id.
+var ErrIncidentNotFound = errors.New("incident not found")
+
+// ErrIncidentResolved is returned when acknowledging or resolving an
+// incident that is already resolved. Notes and links may still be added.
+var ErrIncidentResolved = errors.New("incident already resolved")
+
+// maxIncidentWarningIDs caps Incident.WarningIDs; Count keeps the full tally.
+const maxIncidentWarningIDs = 50
+
+// IncidentNote is an operator note on an incident.
+type IncidentNote struct {
+	At     time.Time `json:"at"`
+	Author string    `json:"author,omitempty"`
+	Text   string    `json:"text"`
+}
+
+// IncidentLink points an incident at a runbook, ticket or dashboard.
+type IncidentLink struct {
+	Title string `json:"title,omitempty"`
+	URL   string `json:"url"`
+}
+
+// Incident groups repeated warnings that share a fingerprint, so a
+// circuit breaker that trips fifty times is one item to acknowledge,
+// assign and resolve rather than fifty warnings.
+//
+// While an incident is unresolved every matching warning joins it; once
+// resolved, the next matching warning opens a new one. Severity is the
+// highest seen, and a warning more severe than that reopens an
+// acknowledged incident.
+type Incident struct {
+	ID             string          `json:"id"`
+	Fingerprint    string          `json:"fingerprint"`
+	Category       WarningCategory `json:"category"`
+	Severity       WarningSeverity `json:"severity"`
+	Source         string          `json:"source"`
+	Title          string          `json:"title"`
+	LastMessage    string          `json:"lastMessage"`
+	State          IncidentState   `json:"state"`
+	Count          int             `json:"count"`
+	WarningIDs     []string        `json:"warningIds"`
+	OpenedAt       time.Time       `json:"openedAt"`
+	LastSeenAt     time.Time       `json:"lastSeenAt"`
+	Assignee       string          `json:"assignee,omitempty"`
+	AcknowledgedAt *time.Time      `json:"acknowledgedAt,omitempty"`
+	AcknowledgedBy string          `json:"acknowledgedBy,omitempty"`
+	ResolvedAt     *time.Time      `json:"resolvedAt,omitempty"`
+	ResolvedBy     string          `json:"resolvedBy,omitempty"`
+	Notes          []IncidentNote  `json:"notes"`
+	Links          []IncidentLink  `json:"links"`
+}
+
+func (i *Incident) clone() Incident {
+	out := *i
+	out.WarningIDs = append([]string{}, i.WarningIDs...)
+	out.Notes = append([]IncidentNote{}, i.Notes...)
+	out.Links = append([]IncidentLink{}, i.Links...)
+	return out
+}
+
+// volatileTokens matches the parts of a warning message that change
+// between otherwise identical warnings: UUIDs and digit runs (counts,
+// attempt numbers, durations, ports).
+var volatileTokens = regexp.MustCompile(`[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}|\d+`)
+
+// WarningFingerprint is the grouping key for incidents: category, source
+// and the message with volatile tokens masked, hashed to 16 hex chars.
+// "restart attempt 3" and "restart attempt 4" from the same source share
+// a fingerprint.
+func WarningFingerprint(category WarningCategory, source, message string) string {
+	h := sha256.New()
+	h.Write([]byte(category))
+	h.Write([]byte{0})
+	h.Write([]byte(source))
+	h.Write([]byte{0})
+	h.Write([]byte(volatileTokens.ReplaceAllString(message, "#")))
+	return hex.EncodeToString(h.Sum(nil))[:16]
+}
+
+// groupLocked files w under the unresolved incident with its fingerprint,
+// opening one if there is none, and stamps w.IncidentID. Caller must hold
+// s.mu (write).
+func (s *WarningService) groupLocked(w *Warning) {
+	fp := WarningFingerprint(w.Category, w.Source, w.Message)
+	if id, ok := s.openIncidents[fp]; ok {
+		inc := s.incidents[id]
+		inc.Count++
+		inc.LastSeenAt = w.CreatedAt
+		inc.LastMessage = w.Message
+		inc.WarningIDs = append(inc.WarningIDs, w.ID)
+		if len(inc.WarningIDs) > maxIncidentWarningIDs {
+			inc.WarningIDs = inc.WarningIDs[len(inc.WarningIDs)-maxIncidentWarningIDs:]
+		}
+		if severityRank(w.Severity) > severityRank(inc.Severity) {
+			inc.Severity = w.Severity
+			inc.State = IncidentOpen
+		}
+		w.IncidentID = inc.ID
+		return
+	}
+
+	if len(s.incidents) >= s.cfg.MaxWarnings {
+		s.evictIncidentsLocked()
+	}
+	inc := &Incident{
+		ID:          uuid.NewString(),
+		Fingerprint: fp,
+		Category:    w.Category,
+		Severity:    w.Severity,
+		Source:      w.Source,
+		Title:       w.Message,
+		LastMessage: w.Message,
+		State:       IncidentOpen,
+		Count:       1,
+		WarningIDs:  []string{w.ID},
+		OpenedAt:    w.CreatedAt,
+		LastSeenAt:  w.CreatedAt,
+	}
+	s.incidents[inc.ID] = inc
+	s.openIncidents[fp] = inc.ID
+	w.IncidentID = inc.ID
+}
+
+// Incidents returns a snapshot of every incident, most recently seen first.
+func (s *WarningService) Incidents() []Incident {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+	out := make([]Incident, 0, len(s.incidents))
+	for _, inc := range s.incidents {
+		out = append(out, inc.clone())
+	}
+	sort.Slice(out, func(i, j int) bool { return out[i].LastSeenAt.After(out[j].LastSeenAt) })
+	return out
+}
+
+// Incident returns one incident by id.
+func (s *WarningService) Incident(id string) (Incident, bool) {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+	inc, ok := s.incidents[id]
+	if !ok {
+		return Incident{}, false
+	}
+	return inc.clone(), true
+}
+
+// AcknowledgeIncident moves an open incident to ACKNOWLEDGED and acks its
+// member warnings. Acknowledging an acknowledged incident is a no-op.
+func (s *WarningService) AcknowledgeIncident(id, by string) (Incident, error) {
+	return s.updateIncident(id, func(inc *Incident, now time.Time) error {
+		if inc.State == IncidentResolved {
+			return ErrIncidentResolved
+		}
+		if inc.State == IncidentOpen {
+			inc.State = IncidentAcknowledged
+			inc.AcknowledgedAt = &now
+			inc.AcknowledgedBy = by
+		}
+		s.ackIncidentWarningsLocked(inc, now)
+		return nil
+	})
+}
+
+// AssignIncident sets (or, with an empty assignee, clears) the owner.
+func (s *WarningService) AssignIncident(id, assignee string) (Incident, error) {
+	return s.updateIncident(id, func(inc *Incident, _ time.Time) error {
+		inc.Assignee = assignee
+		return nil
+	})
+}
+
+// ResolveIncident closes an incident, acks its member warnings and, when
+// note is non-empty, records it as a resolution note. Later warnings with
+// the same fingerprint open a new incident.
+func (s *WarningService) ResolveIncident(id, by, note string) (Incident, error) {
+	return s.updateIncident(id, func(inc *Incident, now time.Time) error {
+		if inc.State == IncidentResolved {
+			return ErrIncidentResolved
+		}
+		if inc.AcknowledgedAt == nil {
+			inc.AcknowledgedAt = &now
+			inc.AcknowledgedBy = by
+		}
+		inc.State = IncidentResolved
+		inc.ResolvedAt = &now
+		inc.ResolvedBy = by
+		if note != "" {
+			inc.Notes = append(inc.Notes, IncidentNote{At: now, Author: by, Text: note})
+		}
+		if s.openIncidents[inc.Fingerprint] == inc.ID {
+			delete(s.openIncidents, inc.Fingerprint)
+		}
+		s.ackIncidentWarningsLocked(inc, now)
+		return nil
+	})
+}
+
+// AddIncidentNote appends an operator note.
+func (s *WarningService) AddIncidentNote(id, author, text string) (Incident, error) {
+	return s.updateIncident(id, func(inc *Incident, now time.Time) error {
+		inc.Notes = append(inc.Notes, IncidentNote{At: now, Author: author, Text: text})
+		return nil
+	})
+}
+
+// AddIncidentLink attaches a link (runbook, ticket, dashboard).
+func (s *WarningService) AddIncidentLink(id, title, url string) (Incident, error) {
+	return s.updateIncident(id, func(inc *Incident, _ time.Time) error {
+		inc.Links = append(inc.Links, IncidentLink{Title: title, URL: url})
+		return nil
+	})
+}
+
+func (s *WarningService) updateIncident(id string, fn func(*Incident, time.Time) error) (Incident, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	inc, ok := s.incidents[id]
+	if !ok {
+		return Incident{}, ErrIncidentNotFound
+	}
+	if err := fn(inc, time.Now().UTC()); err != nil {
+		return Incident{}, err
+	}
+	return inc.clone(), nil
+}
+
+// ackIncidentWarningsLocked acks every stored warning belonging to inc.
+// Caller must hold s.mu (write).
+func (s *WarningService) ackIncidentWarningsLocked(inc *Incident, now time.Time) {
+	for id, w := range s.warnings {
+		if w.IncidentID == inc.ID && !w.Acknowledged {
+			w.Acknowledged = true
+			w.AcknowledgedAt = &now
+			s.warnings[id] = w
+		}
+	}
+}
+
+// clearIncidentsOlderThan drops incidents not seen for longer than age.
+// Returns removed count.
+func (s *WarningService) clearIncidentsOlderThan(age time.Duration) int {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	cutoff := time.Now().UTC().Add(-age)
+	removed := 0
+	for id, inc := range s.incidents {
+		if inc.LastSeenAt.Before(cutoff) {
+			s.dropIncidentLocked(id, inc)
+			removed++
+		}
+	}
+	return removed
+}
+
+// evictIncidentsLocked removes 10% of stored incidents, resolved ones
+// first, then least recently seen. Caller must hold s.mu (write).
+func (s *WarningService) evictIncidentsLocked() {
+	toRemove := len(s.incidents) / 10
+	if toRemove == 0 {
+		return
+	}
+	all := make([]*Incident, 0, len(s.incidents))
+	for _, inc := range s.incidents {
+		all = append(all, inc)
+	}
+	sort.Slice(all, func(i, j int) bool {
+		ri, rj := all[i].State == IncidentResolved, all[j].State == IncidentResolved
+		if ri != rj {
+			return ri
+		}
+		return all[i].LastSeenAt.Before(all[j].LastSeenAt)
+	})
+	for _, inc := range all[:toRemove] {
+		s.dropIncidentLocked(inc.ID, inc)
+	}
+}
+
+func (s *WarningService) dropIncidentLocked(id string, inc *Incident) {
+	delete(s.incidents, id)
+	if s.openIncidents[inc.Fingerprint] == id {
+		delete(s.openIncidents, inc.Fingerprint)
+	}
+}
@@ -0,0 +1,135 @@
+package router
+
+import (
+	"errors"
+	"testing"
+	"time"
+)
+
+func TestWarningFingerprint_MasksVolatileTokens(t *testing.T) {
+	a := WarningFingerprint(WarningCategoryConsumerHealth, "QueueManager", "consumer q1 stalled, restart attempt 3")
+	b := WarningFingerprint(WarningCategoryConsumerHealth, "QueueManager", "consumer q7 stalled, restart attempt 12")
+	if a != b {
+		t.Fatalf("digit runs should not change the fingerprint: %s != %s", a, b)
+	}
+	c := WarningFingerprint(WarningCategoryConsumerHealth, "QueueManager", "consumer stopped")
+	d := WarningFingerprint(WarningCategoryConnection, "QueueManager", "consumer q1 stalled, restart attempt 3")
+	if a == c || a == d {
+		t.Fatal("different message or category should change the fingerprint")
+	}
+}
+
+func TestWarningService_GroupsRepeatsIntoOneIncident(t *testing.T) {
+	s := NewWarningService(WarningServiceConfig{})
+	first := s.Add(WarningCategoryCircuitBreak, WarningWarning, "breaker open for https://a.example (5 failures)", "cb")
+	for i := 0; i < 4; i++ {
+		s.Add(WarningCategoryCircuitBreak, WarningWarning, "breaker open for https://a.example (6 failures)", "cb")
+	}
+	s.Add(WarningCategoryCircuitBreak, WarningCritical, "breaker open for https://a.example (9 failures)", "cb")
+	s.Add(WarningCategoryRouting, WarningError, "no pool for target", "router")
+
+	incidents := s.Incidents()
+	if len(incidents) != 2 {
+		t.Fatalf("Incidents: got %d want 2", len(incidents))
+	}
+	var cb Incident
+	for _, inc := range incidents {
+		if inc.Category == WarningCategoryCircuitBreak {
+			cb = inc
+		}
+	}
+	if cb.Count != 6 || cb.Severity != WarningCritical || cb.State != IncidentOpen {
+		t.Fatalf("circuit breaker incident: got %+v want count 6, CRITICAL, OPEN", cb)
+	}
+	if cb.Title != "breaker open for https://a.example (5 failures)" || cb.LastMessage != "breaker open for https://a.example (9 failures)" {
+		t.Fatalf("title/lastMessage: got %q / %q", cb.Title, cb.LastMessage)
+	}
+	for _, w := range s.All() {
+		if w.ID == first && w.IncidentID != cb.ID {
+			t.Fatalf("warning %s: IncidentID %q want %q", w.ID, w.IncidentID, cb.ID)
+		}
+	}
+}
+
+func TestWarningService_IncidentLifecycle(t *testing.T) {
+	s := NewWarningService(WarningServiceConfig{})
+	s.Add(WarningCategoryStall, WarningError, "pool orders stalled", "pool")
+	s.Add(WarningCategoryStall, WarningError, "pool orders stalled", "pool")
+	id := s.Incidents()[0].ID
+
+	inc, err := s.AcknowledgeIncident(id, "alice")
+	if err != nil || inc.State != IncidentAcknowledged || inc.AcknowledgedBy != "alice" {
+		t.Fatalf("AcknowledgeIncident: got %+v, %v", inc, err)
+	}
+	if got := s.UnacknowledgedCount(); got != 0 {
+		t.Fatalf("member warnings should be acknowledged, %d are not", got)
+	}
+
+	// A repeat while acknowledged joins the incident without reopening it.
+	s.Add(WarningCategoryStall, WarningError, "pool orders stalled", "pool")
+	if inc, _ = s.Incident(id); inc.State != IncidentAcknowledged || inc.Count != 3 {
+		t.Fatalf("after repeat: got state %s count %d", inc.State, inc.Count)
+	}
+
+	if _, err := s.AssignIncident(id, "bob"); err != nil {
+		t.Fatalf("AssignIncident: %v", err)
+	}
+	if _, err := s.AddIncidentLink(id, "runbook", "https://wiki.example/stalls"); err != nil {
+		t.Fatalf("AddIncidentLink: %v", err)
+	}
+	inc, err = s.ResolveIncident(id, "bob", "raised pool concurrency")
+	if err != nil || inc.State != IncidentResolved || inc.ResolvedBy != "bob" || inc.ResolvedAt == nil {
+		t.Fatalf("ResolveIncident: got %+v, %v", inc, err)
+	}
+	if inc.Assignee != "bob" || len(inc.Links) != 1 || len(inc.Notes) != 1 || inc.Notes[0].Text != "raised pool concurrency" {
+		t.Fatalf("resolved incident lost details: %+v", inc)
+	}
+	if _, err := s.ResolveIncident(id, "bob", ""); !errors.Is(err, ErrIncidentResolved) {
+		t.Fatalf("second resolve: got %v want ErrIncidentResolved", err)
+	}
+	if _, err := s.AddIncidentNote(id, "carol", "post-mortem scheduled"); err != nil {
+		t.Fatalf("notes stay open after resolve: %v", err)
+	}
+
+	// After resolution the same fingerprint opens a fresh incident.
+	s.Add(WarningCategoryStall, WarningError, "pool orders stalled", "pool")
+	if got := len(s.Incidents()); got != 2 {
+		t.Fatalf("Incidents after recurrence: got %d want 2", got)
+	}
+	if _, err := s.AcknowledgeIncident("nope", ""); !errors.Is(err, ErrIncidentNotFound) {
+		t.Fatalf("unknown id: got %v want ErrIncidentNotFound", err)
+	}
+}
+
+func TestWarningService_EscalationReopensAcknowledgedIncident(t *testing.T) {
+	s := NewWarningService(WarningServiceConfig{})
+	s.Add(WarningCategoryConnection, WarningWarning, "broker slow", "sqs")
+	id := s.Incidents()[0].ID
+	if _, err := s.AcknowledgeIncident(id, ""); err != nil {
+		t.Fatal(err)
+	}
+	s.Add(WarningCategoryConnection, WarningCritical, "broker slow", "sqs")
+	inc, _ := s.Incident(id)
+	if inc.State != IncidentOpen || inc.Severity != WarningCritical {
+		t.Fatalf("escalation: got state %s severity %s want OPEN CRITICAL", inc.State, inc.Severity)
+	}
+}
+
+func TestWarningService_CleanupDropsStaleIncidents(t *testing.T) {
+	s := NewWarningService(WarningServiceConfig{MaxWarningAge: time.Hour})
+	s.Add(WarningCategoryResource, WarningError, "disk full", "host")
+	id := s.Incidents()[0].ID
+
+	s.mu.Lock()
+	s.incidents[id].LastSeenAt = time.Now().Add(-2 * time.Hour)
+	s.mu.Unlock()
+	s.Cleanup()
+
+	if _, ok := s.Incident(id); ok {
+		t.Fatal("stale incident should be dropped by Cleanup")
+	}
+	s.Add(WarningCategoryResource, WarningError, "disk full", "host")
+	if inc := s.Incidents(); len(inc) != 1 || inc[0].ID == id {
+		t.Fatalf("recurrence after cleanup should open a new incident, got %+v", inc)
+	}
+}
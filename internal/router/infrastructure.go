@@ -0,0 +1,84 @@
+package router
+
+import (
+	"context"
+	"sync"
+	"time"
+)
+
+// InfrastructureCheck is a named connectivity ping against one external
+// store (the standby Redis, an outbox Mongo, the schema registry).
+type InfrastructureCheck struct {
+	Name string
+	Kind string // "redis" | "mongo" | "schema-registry"
+	Ping func(ctx context.Context) error
+}
+
+// InfrastructureComponent is one check's result.
+type InfrastructureComponent struct {
+	Name      string `json:"name"`
+	Kind      string `json:"kind"`
+	Connected bool   `json:"connected"`
+	LatencyMs int64  `json:"latencyMs"`
+	Error     string `json:"error,omitempty"`
+}
+
+// InfrastructureHealth is the connectivity report served at
+// GET /monitoring/infrastructure. Healthy is true when every component is
+// connected (vacuously so with none configured).
+type InfrastructureHealth struct {
+	Healthy    bool                      `json:"healthy"`
+	Components []InfrastructureComponent `json:"components"`
+}
+
+// infrastructurePingTimeout bounds each ping so one unreachable store
+// can't hold the endpoint open.
+const infrastructurePingTimeout = 2 * time.Second
+
+// CheckInfrastructure pings every check concurrently and reports the
+// results in input order.
+func CheckInfrastructure(ctx context.Context, checks []InfrastructureCheck) InfrastructureHealth {
+	out := InfrastructureHealth{Healthy: true, Components: make([]InfrastructureComponent, len(checks))}
+	var wg sync.WaitGroup
+	for i, c := range checks {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			pingCtx, cancel := context.WithTimeout(ctx, infrastructurePingTimeout)
+			defer cancel()
+			start := time.Now()
+			err := c.Ping(pingCtx)
+			comp := InfrastructureComponent{
+				Name:      c.Name,
+				Kind:      c.Kind,
+				Connected: err == nil,
+				LatencyMs: time.Since(start).Milliseconds(),
+			}
+			if err != nil {
+				comp.Error = err.Error()
+			}
+			out.Components[i] = comp
+		}()
+	}
+	wg.Wait()
+	for _, c := range out.Components {
+		if !c.Connected {
+			out.Healthy = false
+		}
+	}
+	return out
+}
+
+// InfrastructureChecks returns the router's own external stores: the
+// standby Redis when standby is enabled, and the config history store when
+// it can be pinged.
+func (s *Server) InfrastructureChecks() []InfrastructureCheck {
+	var checks []InfrastructureCheck
+	if s.election != nil {
+		checks = append(checks, InfrastructureCheck{Name: "standby", Kind: "redis", Ping: s.PingStandby})
+	}
+	if p, ok := s.Cfg.ConfigHistory.(interface{ Ping(context.Context) error }); ok {
+		checks = append(checks, InfrastructureCheck{Name: "config-history", Kind: "mongo", Ping: p.Ping})
+	}
+	return checks
+}
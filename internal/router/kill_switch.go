@@ -0,0 +1,30 @@
+package router
+
+import (
+	"fmt"
+	"log/slog"
+	"time"
+)
+
+// killHoldDelay is how long a killed pool's held message waits before the
+// switch is checked again — the most a release delays its delivery.
+const killHoldDelay = 5 * time.Second
+
+// applyKillSwitch engages or releases p's kill switch, from the pool's
+// config. Engaging raises a critical warning, so the stop shows on
+// /warnings and in notifications, not only in the config source.
+func (m *Manager) applyKillSwitch(p *Pool, killed bool) {
+	if p.killed.Swap(killed) == killed {
+		return
+	}
+	code := p.cfg.Code
+	if !killed {
+		slog.Info("pool kill switch released; delivery resumes", "pool", code)
+		return
+	}
+	slog.Warn("pool kill switch engaged; holding its messages", "pool", code)
+	if w := m.warnings.Load(); w != nil {
+		w.Add(WarningCategoryConfiguration, WarningCritical,
+			fmt.Sprintf("pool %s is killed; its messages are held undelivered until the switch is released", code), "router")
+	}
+}
@@ -0,0 +1,38 @@
+package router
+
+import (
+	"context"
+	"testing"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+func TestKilledPoolHoldsMessageUndelivered(t *testing.T) {
+	c := &grConsumer{id: "q1"}
+	med := &grMediator{outcome: common.Success()}
+	p := grPool(med, c)
+	m := &Manager{}
+	ws := NewWarningService(DefaultWarningServiceConfig())
+	m.SetWarnings(ws)
+
+	m.applyKillSwitch(p, true)
+	res, delay := p.processOne(context.Background(), grMsg("job_k", "http://t/k"))
+	if res != processRetry || delay != killHoldDelay {
+		t.Fatalf("killed pool: res=%d delay=%v, want processRetry after %v", res, delay, killHoldDelay)
+	}
+	if med.called.Load() {
+		t.Fatal("killed pool mediated a message")
+	}
+	if n := len(ws.BySeverity(WarningCritical)); n != 1 {
+		t.Fatalf("engaging the switch must raise one critical warning, got %d", n)
+	}
+	m.applyKillSwitch(p, true)
+	if n := len(ws.BySeverity(WarningCritical)); n != 1 {
+		t.Fatalf("re-applying an engaged switch must not warn again, got %d", n)
+	}
+
+	m.applyKillSwitch(p, false)
+	if res, _ := p.processOne(context.Background(), grMsg("job_k", "http://t/k")); res != processDone || !med.called.Load() {
+		t.Fatalf("released pool: res=%d mediated=%v, want delivery", res, med.called.Load())
+	}
+}
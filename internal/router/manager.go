@@ -46,6 +46,9 @@ type Manager struct {
 	tracker  *InFlightTracker
 	warnings atomic.Pointer[WarningService] // optional; set via SetWarnings. nil → no-op.
 
+	// cancellations is handed to every pool; set via SetCancellations. nil → no check.
+	cancellations Cancellations
+
 	mu        sync.Mutex
 	pools     map[string]*Pool              // pool code → passive pool
 	consumers map[string]*runningConsumer   // queue name → consumer + poll loop
@@ -60,18 +63,111 @@ type Manager struct {
 
 	batchCounter atomic.Uint64
 
+	// slowStart is the ramp in progress after leader-gain; nil otherwise.
+	slowStart atomic.Pointer[slowStartRamp]
+	// handover defers the message groups the previous leader still held
+	// when it lost the lock; nil otherwise.
+	handover atomic.Pointer[groupLease]
+	// routing holds the content-based routing rules; nil means none.
+	routing atomic.Pointer[RoutingRules]
+	// guard tracks the config in force for Apply and its bake, if any.
+	guard guardState
+
 	pubMu      sync.Mutex
 	publishers map[string]queue.Publisher // queue name → publisher (lazy)
 }
 
+// runningConsumer is one queue's consumer and its poll loops: one loop per
+// configured connection, all polling the same consumer, so its counters
+// are shared and a single cancel stops them together.
 type runningConsumer struct {
 	consumer queue.Consumer
+	// ctx is the loops' parent context; cancel stops every loop.
+	ctx      context.Context
 	cancel   context.CancelFunc
 	queueCfg common.QueueConfig
-	// lastPoll is the unix-nano of the most recent completed poll; a poll
+	loops    []*pollLoop // guarded by Manager.mu
+
+	// polls / emptyPolls count completed polls across the loops.
+	polls      atomic.Uint64
+	emptyPolls atomic.Uint64
+	// wake ends the loops' idle pauses when one of them receives messages.
+	wake wakeup
+}
+
+// pollLoop is one of a consumer's poll loops.
+type pollLoop struct {
+	cancel context.CancelFunc
+	// lastPoll is the unix-nano of the loop's most recent completed poll; a
 	// loop wedged inside consumer.Poll leaves it stale, which the
 	// consumer-restart watchdog (RestartStalledConsumers) detects.
 	lastPoll atomic.Int64
+	// backoff is the idle pause the loop is in, in nanoseconds; 0 while
+	// messages flow.
+	backoff atomic.Int64
+}
+
+// maxQueueConnections caps the poll loops per queue. SQS long-polls return
+// at most 10 messages each, so throughput scales with loops; past a few
+// dozen the pools, not the receive calls, are the bottleneck.
+const maxQueueConnections = 32
+
+// connectionCount is the number of poll loops qc asks for, within
+// [1, maxQueueConnections].
+func connectionCount(qc common.QueueConfig) int {
+	return min(max(int(qc.Connections), 1), maxQueueConnections)
+}
+
+// sameConsumer reports whether a and b can share a consumer: they differ
+// at most in connections, which rescales the poll loops in place, and the
+// idle backoff and region, which are read as messages arrive.
+func sameConsumer(a, b common.QueueConfig) bool {
+	a.Connections, b.Connections = 0, 0
+	a.IdlePollMinMs, b.IdlePollMinMs = 0, 0
+	a.IdlePollMaxMs, b.IdlePollMaxMs = 0, 0
+	a.Region, b.Region = "", ""
+	return a == b
+}
+
+// startConsumer registers a running consumer for qc and starts its poll
+// loops. Caller holds m.mu.
+func (m *Manager) startConsumer(ctx context.Context, consumer queue.Consumer, qc common.QueueConfig) *runningConsumer {
+	cctx, cancel := context.WithCancel(ctx)
+	rc := &runningConsumer{consumer: consumer, ctx: cctx, cancel: cancel, queueCfg: qc}
+	m.scaleConsumer(rc, connectionCount(qc))
+	return rc
+}
+
+// scaleConsumer starts or stops poll loops until rc has n. A stopped loop
+// finishes routing the batch in hand; the consumer itself keeps running.
+// Caller holds m.mu.
+func (m *Manager) scaleConsumer(rc *runningConsumer, n int) {
+	for len(rc.loops) < n {
+		lctx, cancel := context.WithCancel(rc.ctx)
+		loop := &pollLoop{cancel: cancel}
+		loop.lastPoll.Store(time.Now().UnixNano())
+		rc.loops = append(rc.loops, loop)
+		m.wg.Add(1)
+		go m.runConsumer(lctx, rc, loop)
+	}
+	for len(rc.loops) > n {
+		last := len(rc.loops) - 1
+		rc.loops[last].cancel()
+		rc.loops[last] = nil
+		rc.loops = rc.loops[:last]
+	}
+}
+
+// oldestPoll is the stalest of rc's loop heartbeats, or 0 if it has none.
+// Caller holds m.mu.
+func (rc *runningConsumer) oldestPoll() int64 {
+	var oldest int64
+	for _, l := range rc.loops {
+		if lp := l.lastPoll.Load(); oldest == 0 || lp < oldest {
+			oldest = lp
+		}
+	}
+	return oldest
 }
 
 // NewManager builds a manager. The mediator (which now owns the per-endpoint
@@ -253,6 +349,15 @@ func (m *Manager) queueForPublish(key string) (common.QueueConfig, bool) {
 	return m.queues[names[0]], true
 }
 
+// HasQueue reports whether a queue named name is registered, so a publish
+// that names its queue can be refused instead of falling back to another.
+func (m *Manager) HasQueue(name string) bool {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	_, ok := m.queues[name]
+	return ok
+}
+
 // UpdatePool applies a runtime config update to an existing pool. See the
 // PUT /monitoring/pools/{poolCode} handler. Concurrency==0 leaves it
 // unchanged; setRateLimit toggles whether rateLimitPerMinute is applied.
@@ -277,9 +382,10 @@ func (m *Manager) UpdatePool(code string, concurrency uint32, rateLimitPerMinute
 // (claiming pipeline ownership BEFORE buffering/dispatch, so ordered-group
 // buffering windows dedupe too), drops broker redeliveries and ACK-drops
 // external-requeue duplicates, then routes each surviving message to the pool
-// named by its pool_code (DEFAULT-POOL fallback) and submits it. ack/nack of
-// the eventual outcome is the pool's job, against the message's source
-// consumer.
+// named by its pool_code (DEFAULT-POOL fallback) and submits it, holding back
+// a message whose data-residency region the pool or queue does not serve.
+// ack/nack of the eventual outcome is the pool's job, against the message's
+// source consumer.
 func (m *Manager) route(ctx context.Context, msgs []common.QueuedMessage, source queue.Consumer) {
 	if len(msgs) == 0 {
 		return
@@ -332,6 +438,19 @@ func (m *Manager) route(ctx context.Context, msgs []common.QueuedMessage, source
 			}
 		}
 
+		if delay, ok := m.groupDeferral(msg); ok {
+			// The previous leader may still be delivering this group: hand
+			// the message back until its handover lease expires. It is
+			// leaving the pipeline, so release its tracker entry.
+			if m.tracker != nil {
+				m.tracker.Remove(msg.Message.ID, msg.BrokerMessageID)
+			}
+			if err := source.Defer(ctx, msg.ReceiptHandle, &delay); err != nil {
+				slog.Warn("defer (group handover) failed", "message_id", msg.Message.ID, "err", err)
+			}
+			continue
+		}
+
 		pool := m.poolForMessage(msg)
 		if pool == nil {
 			// No pool at all (not even DEFAULT-POOL configured) — NACK so the
@@ -348,6 +467,10 @@ func (m *Manager) route(ctx context.Context, msgs []common.QueuedMessage, source
 			}
 			continue
 		}
+		if reason := m.residencyMismatch(msg, pool); reason != "" {
+			m.holdForResidency(ctx, msg, source, reason)
+			continue
+		}
 		pool.submit(ctx, msg)
 	}
 }
@@ -368,12 +491,20 @@ func (m *Manager) poolByCode(code string) *Pool {
 
 // poolForMessage resolves the destination pool for a message: the pool named
 // by pool_code, or DEFAULT-POOL when pool_code is empty or unknown (with a
-// routing warning for the unknown case). Returns nil only if even
-// DEFAULT-POOL is absent.
+// routing warning for the unknown case). A routing rule only applies when
+// its pool serves the message's data-residency region. Returns nil only
+// if even DEFAULT-POOL is absent.
 func (m *Manager) poolForMessage(msg common.QueuedMessage) *Pool {
 	code := msg.Message.PoolCode
 	m.mu.Lock()
 	defer m.mu.Unlock()
+	if _, known := m.pools[code]; !known {
+		if r, ok := m.Route(RoutingInputForTarget(msg.Message.MediationTarget)); ok {
+			if p, ok := m.pools[r.PoolCode]; ok && (messageRegion(msg) == "" || p.region == messageRegion(msg)) {
+				return p
+			}
+		}
+	}
 	if code != "" {
 		if p, ok := m.pools[code]; ok {
 			return p
@@ -390,14 +521,18 @@ func (m *Manager) poolForMessage(msg common.QueuedMessage) *Pool {
 	return m.pools[defaultPoolCode]
 }
 
-// runConsumer is the per-consumer poll loop (1:1 with Rust
-// spawn_consumer_poll_task). It pauses when all pools are at capacity to
-// avoid a hot poll-defer loop, polls up to 10, routes the batch, and paces
-// itself by batch fullness.
-func (m *Manager) runConsumer(ctx context.Context, rc *runningConsumer) {
+// runConsumer is one of a consumer's poll loops (1:1 with Rust
+// spawn_consumer_poll_task, which runs one per connection). It pauses when
+// all pools are at capacity to avoid a hot poll-defer loop, polls up to 10
+// (fewer during slow-start), routes the batch, and paces itself by batch
+// fullness. After empty polls it backs off exponentially within the
+// queue's idle bounds, and any loop of the queue receiving messages ends
+// the pause.
+func (m *Manager) runConsumer(ctx context.Context, rc *runningConsumer, loop *pollLoop) {
 	defer m.wg.Done()
 	const maxPoll = 10
 	wasFull := false
+	empties := 0
 	for {
 		if ctx.Err() != nil {
 			return
@@ -422,7 +557,8 @@ func (m *Manager) runConsumer(ctx context.Context, rc *runningConsumer) {
 		}
 		wasFull = false
 
-		msgs, err := rc.consumer.Poll(ctx, maxPoll)
+		batch := m.pollBatch(maxPoll)
+		msgs, err := rc.consumer.Poll(ctx, uint32(batch))
 		if err != nil {
 			if ctx.Err() != nil {
 				return
@@ -447,22 +583,35 @@ func (m *Manager) runConsumer(ctx context.Context, rc *runningConsumer) {
 		// Heartbeat only on a SUCCESSFUL poll (empty or not). Stamping it on an
 		// errored poll keeps a wedged, error-spinning consumer looking alive to
 		// the restart watchdog, so it is never rebuilt.
-		rc.lastPoll.Store(time.Now().UnixNano())
+		loop.lastPoll.Store(time.Now().UnixNano())
+		rc.polls.Add(1)
 
 		if len(msgs) == 0 {
+			rc.emptyPolls.Add(1)
+			empties++
+			lo, hi := m.idleBoundsFor(rc)
+			pause := idleDelay(empties, lo, hi)
+			loop.backoff.Store(int64(pause))
 			select {
 			case <-ctx.Done():
 				return
-			case <-time.After(time.Second):
+			case <-rc.wake.wait():
+				empties = 0
+			case <-time.After(pause):
 			}
 			continue
 		}
+		if empties > 0 {
+			empties = 0
+			loop.backoff.Store(0)
+		}
+		rc.wake.broadcast()
 
 		m.route(ctx, msgs, rc.consumer)
 
 		// Full batch → re-poll immediately (more likely waiting). Partial →
 		// brief pause (queue draining). Mirrors Rust's pacing.
-		if len(msgs) < maxPoll {
+		if len(msgs) < batch {
 			select {
 			case <-ctx.Done():
 				return
@@ -507,6 +656,9 @@ func (m *Manager) Reconfigure(ctx context.Context, cfg common.RouterConfig) erro
 	for _, q := range cfg.Queues {
 		wantQueues[q.Name] = q
 	}
+	if err := m.SetRoutingRules(cfg.RoutingRules); err != nil {
+		slog.Warn("manager: keeping previous routing rules", "err", err)
+	}
 
 	m.mu.Lock()
 	defer m.mu.Unlock()
@@ -526,25 +678,50 @@ func (m *Manager) Reconfigure(ctx context.Context, cfg common.RouterConfig) erro
 			if pc.RateLimitPerMinute != nil {
 				rate = *pc.RateLimitPerMinute
 			}
+			if err := p.SetRateProfiles(pc); err != nil {
+				slog.Warn("manager: keeping previous rate profiles", "pool", code, "err", err)
+			}
 			p.SetRateLimit(rate)
 			if pc.Concurrency != 0 {
 				p.UpdateConcurrency(pc.Concurrency)
 			}
+			p.SetCPUSlots(pc.CPUSlots)
+			m.applyKillSwitch(p, pc.Killed)
+			p.region = pc.Region
 			continue
 		}
-		m.pools[code] = NewPool(pc, m.mediator, m.tracker, m.resolveConsumer)
+		p := NewPool(pc, m.mediator, m.tracker, m.resolveConsumer)
+		p.cancellations = m.cancellations
+		p.warnings = m.warnings.Load()
+		m.applyKillSwitch(p, pc.Killed)
+		// A pool created mid-ramp starts throttled, not at full rate.
+		if f := m.slowStartFactor(); f < 1 {
+			p.throttle(uint32(scaled(int(p.Concurrency()), f)))
+		}
+		m.pools[code] = p
 	}
 
-	// Consumers: stop removed/changed, start new. A queue config change
-	// (URI/connections/visibility) restarts that consumer.
+	// Consumers: stop removed/changed, start new. A URI, visibility or wait
+	// change restarts that consumer; a connections change only adds or
+	// stops poll loops, and an idle backoff change applies to the next
+	// pause.
 	for name, rc := range m.consumers {
-		if wq, ok := wantQueues[name]; !ok || wq != rc.queueCfg {
-			slog.Info("manager: stopping consumer", "queue", name)
-			rc.cancel()
-			rc.consumer.Stop()
-			delete(m.consumers, name)
-			delete(m.queues, name)
+		wq, ok := wantQueues[name]
+		if ok && sameConsumer(wq, rc.queueCfg) {
+			if wq.Connections != rc.queueCfg.Connections {
+				slog.Info("manager: scaling consumer", "queue", name,
+					"from", len(rc.loops), "to", connectionCount(wq))
+				m.scaleConsumer(rc, connectionCount(wq))
+			}
+			rc.queueCfg = wq
+			m.queues[name] = wq
+			continue
 		}
+		slog.Info("manager: stopping consumer", "queue", name)
+		rc.cancel()
+		rc.consumer.Stop()
+		delete(m.consumers, name)
+		delete(m.queues, name)
 	}
 	for name, qc := range wantQueues {
 		if _, ok := m.consumers[name]; ok {
@@ -554,13 +731,8 @@ func (m *Manager) Reconfigure(ctx context.Context, cfg common.RouterConfig) erro
 		if err != nil {
 			return fmt.Errorf("build consumer for queue %s: %w", name, err)
 		}
-		cctx, cancel := context.WithCancel(ctx)
-		rc := &runningConsumer{consumer: consumer, cancel: cancel, queueCfg: qc}
-		rc.lastPoll.Store(time.Now().UnixNano())
-		m.consumers[name] = rc
+		m.consumers[name] = m.startConsumer(ctx, consumer, qc)
 		m.queues[name] = qc
-		m.wg.Add(1)
-		go m.runConsumer(cctx, rc)
 	}
 	return nil
 }
@@ -574,6 +746,10 @@ func (m *Manager) Reconfigure(ctx context.Context, cfg common.RouterConfig) erro
 // assigns into them, and writing to a nil map panics (in the Watch
 // goroutine, taking the process down on the designed failover path).
 func (m *Manager) Shutdown(ctx context.Context) error {
+	m.guard.mu.Lock()
+	m.stopBakeLocked()
+	m.guard.mu.Unlock()
+
 	m.mu.Lock()
 	for _, rc := range m.consumers {
 		rc.cancel()
@@ -597,10 +773,10 @@ func (m *Manager) Shutdown(ctx context.Context) error {
 	}
 }
 
-// RestartStalledConsumers re-spawns any consumer whose poll loop has not
-// completed a poll within threshold — a wedged loop (stuck inside
+// RestartStalledConsumers re-spawns any consumer with a poll loop that has
+// not completed a poll within threshold — a wedged loop (stuck inside
 // consumer.Poll) leaves its lastPoll stale. The stalled consumer is
-// cancelled and its connection rebuilt with a fresh poll loop. Returns the
+// cancelled and its connection rebuilt with fresh poll loops. Returns the
 // number restarted. Mirrors the Rust LifecycleManager consumer auto-restart.
 func (m *Manager) RestartStalledConsumers(ctx context.Context, threshold time.Duration) int {
 	if threshold <= 0 {
@@ -616,7 +792,7 @@ func (m *Manager) RestartStalledConsumers(ctx context.Context, threshold time.Du
 	m.mu.Lock()
 	var stalled []candidate
 	for name, rc := range m.consumers {
-		if lp := rc.lastPoll.Load(); lp != 0 && lp < cutoff {
+		if lp := rc.oldestPoll(); lp != 0 && lp < cutoff {
 			stalled = append(stalled, candidate{name: name, qc: rc.queueCfg, old: rc})
 		}
 	}
@@ -673,23 +849,16 @@ func (m *Manager) RestartStalledConsumers(ctx context.Context, threshold time.Du
 			slog.Error("failed to rebuild stalled consumer", "queue", c.name, "err", err)
 			continue
 		}
-		cctx, cancel := context.WithCancel(ctx)
-		rc := &runningConsumer{consumer: consumer, cancel: cancel, queueCfg: c.qc}
-		rc.lastPoll.Store(time.Now().UnixNano())
-
 		m.mu.Lock()
 		// Only replace if the entry is still the one we found stalled — a
 		// concurrent Reconfigure may have already swapped or removed it.
 		if cur, ok := m.consumers[c.name]; ok && cur == c.old {
-			m.consumers[c.name] = rc
+			m.consumers[c.name] = m.startConsumer(ctx, consumer, c.qc)
 			m.mu.Unlock()
-			m.wg.Add(1)
-			go m.runConsumer(cctx, rc)
 			m.restartAttempts[c.name]++
 			restarted++
 		} else {
 			m.mu.Unlock()
-			cancel()
 			consumer.Stop()
 		}
 	}
@@ -0,0 +1,87 @@
+package router
+
+import (
+	"context"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+// blockingConsumer parks every Poll until its context ends, counting the
+// polls in progress — one per live poll loop.
+type blockingConsumer struct {
+	pollErrConsumer
+	active atomic.Int64
+}
+
+func (c *blockingConsumer) Poll(ctx context.Context, _ uint32) ([]common.QueuedMessage, error) {
+	c.active.Add(1)
+	defer c.active.Add(-1)
+	<-ctx.Done()
+	return nil, ctx.Err()
+}
+
+func waitActive(t *testing.T, c *blockingConsumer, want int64) {
+	t.Helper()
+	deadline := time.Now().Add(2 * time.Second)
+	for c.active.Load() != want {
+		if time.Now().After(deadline) {
+			t.Fatalf("active polls=%d want %d", c.active.Load(), want)
+		}
+		time.Sleep(5 * time.Millisecond)
+	}
+}
+
+// A queue runs one poll loop per connection on a single consumer, and a
+// connections change from config sync rescales the loops without
+// rebuilding the consumer.
+func TestManagerScalesPollLoopsWithConnections(t *testing.T) {
+	var built atomic.Int64
+	cons := &blockingConsumer{pollErrConsumer: pollErrConsumer{id: "conntest://q"}}
+	queue.RegisterConsumer("conntest", func(context.Context, common.QueueConfig) (queue.Consumer, error) {
+		built.Add(1)
+		return cons, nil
+	})
+
+	ctx := context.Background()
+	m := NewManager(nil, nil)
+	qc := common.QueueConfig{Name: "q", URI: "conntest://q", Connections: 3, VisibilityTimeout: 30}
+	if err := m.Reconfigure(ctx, common.RouterConfig{Queues: []common.QueueConfig{qc}}); err != nil {
+		t.Fatal(err)
+	}
+	waitActive(t, cons, 3)
+
+	qc.Connections = 1
+	if err := m.Reconfigure(ctx, common.RouterConfig{Queues: []common.QueueConfig{qc}}); err != nil {
+		t.Fatal(err)
+	}
+	waitActive(t, cons, 1)
+	qc.Connections = 5
+	if err := m.Reconfigure(ctx, common.RouterConfig{Queues: []common.QueueConfig{qc}}); err != nil {
+		t.Fatal(err)
+	}
+	waitActive(t, cons, 5)
+	if n := built.Load(); n != 1 {
+		t.Errorf("consumer built %d times, want 1", n)
+	}
+
+	sctx, cancel := context.WithTimeout(ctx, 2*time.Second)
+	defer cancel()
+	if err := m.Shutdown(sctx); err != nil {
+		t.Fatalf("shutdown: %v", err)
+	}
+	if n := cons.active.Load(); n != 0 {
+		t.Errorf("active polls after shutdown=%d want 0", n)
+	}
+}
+
+func TestConnectionCount(t *testing.T) {
+	for in, want := range map[uint32]int{0: 1, 1: 1, 8: 8, 1000: maxQueueConnections} {
+		if got := connectionCount(common.QueueConfig{Connections: in}); got != want {
+			t.Errorf("connectionCount(%d)=%d want %d", in, got, want)
+		}
+	}
+}
@@ -137,9 +137,10 @@ func mkGrouped(id, brokerID, receipt string) common.QueuedMessage {
 // group's pre-dispatch buffer (the retrying head is re-fronted there during
 // its backoff, so total buffer size is not a stable dedup signal).
 func bufferedCopies(p *Pool, group, id string) int {
-	p.mu.Lock()
-	defer p.mu.Unlock()
-	gq := p.groupQs[group]
+	s := p.groups.shard(group)
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	gq := s.groups[group]
 	if gq == nil {
 		return 0
 	}
@@ -320,3 +321,34 @@ func TestPoolStopFlushesBufferedTrackerEntries(t *testing.T) {
 	im := common.NewInFlightMessage(&common.Message{ID: "m2"}, "b2", "q", "", "rh-m2-again")
 	assert.Equal(t, RegisterNew, tr.Register(im), "flushed m2 must be re-registrable on redelivery")
 }
+
+// TestManagerRouteDefersHandedOverGroups pins the FIFO group handover: a
+// new leader hands messages in a group the previous leader still held back
+// to the broker (Defer, not Nack) until the lease expires, and dispatches
+// other groups normally.
+func TestManagerRouteDefersHandedOverGroups(t *testing.T) {
+	med := &cascadeMediator{}
+	cons := &grConsumer{id: "q"}
+	m, tr, pool := newRouteHarness(med, cons)
+
+	require.True(t, pool.enqueue("g", mkGrouped("held", "b0", "rh0")))
+	assert.Equal(t, []string{"g"}, m.ActiveGroups())
+
+	m.DeferGroups([]string{"g"}, time.Now().Add(10*time.Second))
+	m.route(context.Background(), []common.QueuedMessage{mkGrouped("m1", "b1", "rh1")}, cons)
+	assert.Equal(t, int64(1), cons.defers.Load(), "handed-over group is deferred")
+	assert.Equal(t, int64(0), cons.nacks.Load(), "a deferral is not a failure")
+	assert.Zero(t, tr.Count(), "the deferred message leaves the pipeline")
+
+	other := "other"
+	msg := mkGrouped("m2", "b2", "rh2")
+	msg.Message.MessageGroupID = &other
+	delay, deferred := m.groupDeferral(msg)
+	assert.False(t, deferred)
+	assert.Zero(t, delay)
+
+	m.handover.Store(&groupLease{groups: map[string]struct{}{"g": {}}, until: time.Now().Add(-time.Second)})
+	_, deferred = m.groupDeferral(mkGrouped("m3", "b3", "rh3"))
+	assert.False(t, deferred, "an expired lease defers nothing")
+	assert.Nil(t, m.handover.Load())
+}
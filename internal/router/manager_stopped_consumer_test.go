@@ -58,19 +58,20 @@ func managerWithCapacity() *Manager {
 func TestRunConsumerExitsWhenConsumerStopped(t *testing.T) {
 	m := managerWithCapacity()
 	rc := &runningConsumer{consumer: &pollErrConsumer{id: "q-high.fifo", err: queue.ErrStopped}, cancel: func() {}}
+	loop := &pollLoop{cancel: func() {}}
 	const sentinel = int64(12345)
-	rc.lastPoll.Store(sentinel)
+	loop.lastPoll.Store(sentinel)
 
 	m.wg.Add(1)
 	done := make(chan struct{})
-	go func() { m.runConsumer(context.Background(), rc); close(done) }()
+	go func() { m.runConsumer(context.Background(), rc, loop); close(done) }()
 
 	select {
 	case <-done:
 	case <-time.After(2 * time.Second):
 		t.Fatal("runConsumer did not exit on queue.ErrStopped — it is spinning on a dead consumer")
 	}
-	if got := rc.lastPoll.Load(); got != sentinel {
+	if got := loop.lastPoll.Load(); got != sentinel {
 		t.Fatalf("heartbeat advanced on a stopped poll (got %d, want %d) — a wedged consumer would look alive to the watchdog", got, sentinel)
 	}
 }
@@ -82,13 +83,14 @@ func TestRunConsumerNoHeartbeatOnPollError(t *testing.T) {
 	m := managerWithCapacity()
 	fake := &pollErrConsumer{id: "q.fifo", err: errors.New("transient boom")}
 	rc := &runningConsumer{consumer: fake, cancel: func() {}}
+	loop := &pollLoop{cancel: func() {}}
 	const sentinel = int64(999)
-	rc.lastPoll.Store(sentinel)
+	loop.lastPoll.Store(sentinel)
 
 	ctx, cancel := context.WithCancel(context.Background())
 	m.wg.Add(1)
 	done := make(chan struct{})
-	go func() { m.runConsumer(ctx, rc); close(done) }()
+	go func() { m.runConsumer(ctx, rc, loop); close(done) }()
 
 	// Wait until at least one poll happened, then stop the loop.
 	deadline := time.Now().Add(2 * time.Second)
@@ -106,7 +108,7 @@ func TestRunConsumerNoHeartbeatOnPollError(t *testing.T) {
 	case <-time.After(2 * time.Second):
 		t.Fatal("runConsumer did not exit after context cancel")
 	}
-	if got := rc.lastPoll.Load(); got != sentinel {
+	if got := loop.lastPoll.Load(); got != sentinel {
 		t.Fatalf("heartbeat advanced on an errored poll (got %d, want %d)", got, sentinel)
 	}
 }
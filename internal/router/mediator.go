@@ -17,12 +17,14 @@ import (
 	"log/slog"
 	"net"
 	"net/http"
+	"net/netip"
 	"strconv"
 	"time"
 
 	"golang.org/x/net/http2"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 )
 
 // SignatureHeader matches the Rust SIGNATURE_HEADER constant.
@@ -59,6 +61,24 @@ type MediatorConfig struct {
 	// version" — DefaultHostPoolSizing for HTTP/2, HTTP1HostPoolSizing
 	// for HTTP/1.1.
 	HostPoolSizing HostPoolSizing
+	// EgressAddrs pins delivery connections to these local addresses
+	// (see ResolveEgressAddrs). Empty leaves source selection to the OS.
+	EgressAddrs []netip.Addr
+	// Outbound, when set, refuses mediation targets the SSRF policy
+	// blocks — checked on the URL and again on every dialled address.
+	// nil dials anything.
+	Outbound *outbound.Policy
+	// TLS is the client TLS config for deliveries — extra roots, pins, a
+	// minimum version (see outbound.TLSTrust). nil uses the Go defaults.
+	TLS *tls.Config
+	// Pipeline is the middleware every delivery attempt runs through.
+	// nil uses DefaultMediationPipeline (HMAC signing only); a custom
+	// pipeline that should still sign must include HMACSigning.
+	Pipeline *MediationPipeline
+	// Preflight caches targets that don't resolve or fail certificate
+	// verification, so later messages to them are refused as config
+	// errors without an attempt. Zero NegativeTTL disables it.
+	Preflight PreflightConfig
 }
 
 // DefaultMediatorConfig matches the Rust production defaults (15min timeout, HTTP/2).
@@ -71,6 +91,7 @@ func DefaultMediatorConfig() MediatorConfig {
 		MaxRetries:          3,
 		RetryDelays:         []time.Duration{1 * time.Second, 2 * time.Second, 3 * time.Second},
 		HostPoolSizing:      DefaultHostPoolSizing(),
+		Preflight:           DefaultPreflightConfig(),
 	}
 }
 
@@ -89,10 +110,13 @@ func DevMediatorConfig() MediatorConfig {
 // slots, each backed by its own *http.Transport so the slots' h2
 // connection pools are independent. Mirrors crates/fc-router/src/http_pool.rs.
 type HTTPMediator struct {
-	pools    *HostPoolRegistry
-	cfg      MediatorConfig
-	breakers *BreakerRegistry
-	warnings *WarningService // optional; set via SetWarnings. nil → no-op.
+	pools     *HostPoolRegistry
+	cfg       MediatorConfig
+	breakers  *BreakerRegistry
+	preflight *TargetPreflight // nil when cfg.Preflight is off
+	warnings  *WarningService  // optional; set via SetWarnings. nil → no-op.
+	attempts  *AttemptSink     // optional; set via SetAttemptSink. nil → no-op.
+	targets   targetTally      // deliveries per origin, for the warm-up hand-off
 }
 
 // NewHTTPMediator wires an HTTP mediator with the supplied config.
@@ -125,10 +149,13 @@ func NewHTTPMediator(cfg MediatorConfig, breakers *BreakerRegistry) *HTTPMediato
 		}
 		cfg.HostPoolSizing = sizing
 	}
+	if cfg.Pipeline == nil {
+		cfg.Pipeline = DefaultMediationPipeline()
+	}
 	builder := newClientBuilder(cfg)
 	pools := NewHostPoolRegistry(sizing, builder)
 	pools.StartSweep()
-	return &HTTPMediator{pools: pools, cfg: cfg, breakers: breakers}
+	return &HTTPMediator{pools: pools, cfg: cfg, breakers: breakers, preflight: NewTargetPreflight(cfg.Preflight)}
 }
 
 // Close stops the host-pool sweep goroutine. Safe to call multiple
@@ -149,11 +176,16 @@ func (m *HTTPMediator) HostPools() *HostPoolRegistry { return m.pools }
 // once at startup, before serving.
 func (m *HTTPMediator) SetWarnings(ws *WarningService) { m.warnings = ws }
 
+// SetAttemptSink wires the delivery-attempt sink: every HTTP attempt
+// (including in-process retries) is recorded to it. Set once at startup,
+// before serving.
+func (m *HTTPMediator) SetAttemptSink(s *AttemptSink) { m.attempts = s }
+
 // warnConfig logs a configuration-class warning and, when a WarningService is
 // wired, records it so it shows on /warnings and (for Critical, e.g. 501)
 // degrades health. Mirrors the Rust mediator's config-error warnings.
-func (m *HTTPMediator) warnConfig(severity WarningSeverity, message string, msg *common.Message) {
-	slog.Warn("mediation config error", "message_id", msg.ID, "target", msg.MediationTarget,
+func (m *HTTPMediator) warnConfig(ctx context.Context, severity WarningSeverity, message string, msg *common.Message) {
+	slog.WarnContext(ctx, "mediation config error", "message_id", msg.ID, "target", msg.MediationTarget,
 		"detail", message, "severity", severity)
 	if m.warnings != nil {
 		m.warnings.Add(WarningCategoryConfiguration, severity, message, "HttpMediator")
@@ -170,12 +202,22 @@ func newClientBuilder(cfg MediatorConfig) ClientBuilder {
 			Timeout:   cfg.ConnectTimeout,
 			KeepAlive: 30 * time.Second,
 		}
+		if cfg.Outbound != nil {
+			cfg.Outbound.Guard(dialer)
+		}
+		dial := dialer.DialContext
+		if len(cfg.EgressAddrs) > 0 {
+			dial = newEgressDialer(*dialer, cfg.EgressAddrs).DialContext
+		}
 		transport := &http.Transport{
-			DialContext:         dialer.DialContext,
+			DialContext:         dial,
 			MaxIdleConnsPerHost: 10,
 			IdleConnTimeout:     90 * time.Second,
 			TLSHandshakeTimeout: cfg.TLSHandshakeTimeout,
 		}
+		if cfg.TLS != nil {
+			transport.TLSClientConfig = cfg.TLS.Clone()
+		}
 		if cfg.HTTPVersion == HTTPVersion1 {
 			transport.ForceAttemptHTTP2 = false
 			transport.TLSNextProto = map[string]func(authority string, c *tls.Conn) http.RoundTripper{}
@@ -249,7 +291,9 @@ func (m *HTTPMediator) deliverWithRetry(ctx context.Context, msg *common.Message
 	// attempts (after attempt 1 and 2 for the default), never after the last.
 	attempts := 0
 	for {
+		start := time.Now()
 		last = m.mediateOnce(ctx, msg)
+		m.recordAttempt(msg, attempts+1, last, start)
 
 		// Don't retry on success, config errors, or rate-limit responses.
 		// For 429 the queue applies Retry-After delay rather than busy-waiting here.
@@ -278,11 +322,45 @@ func (m *HTTPMediator) deliverWithRetry(ctx context.Context, msg *common.Message
 	}
 }
 
+func (m *HTTPMediator) recordAttempt(msg *common.Message, attempt int, out common.MediationOutcome, start time.Time) {
+	if m.attempts == nil {
+		return
+	}
+	a := DeliveryAttempt{
+		MessageID:  msg.ID,
+		PoolCode:   msg.PoolCode,
+		Target:     msg.MediationTarget,
+		Attempt:    attempt,
+		Outcome:    OutcomeName(out.Result),
+		StatusCode: out.StatusCode,
+		LatencyMs:  time.Since(start).Milliseconds(),
+		Error:      out.ErrorMessage,
+		Timestamp:  start.UTC(),
+	}
+	if msg.MessageGroupID != nil {
+		a.MessageGroupID = *msg.MessageGroupID
+	}
+	m.attempts.Record(a)
+}
+
 func (m *HTTPMediator) mediateOnce(ctx context.Context, msg *common.Message) common.MediationOutcome {
 	if msg.MediationType != common.MediationTypeHTTP {
 		return common.ErrorConfig(0, fmt.Sprintf("Unsupported mediation type: %s", msg.MediationType))
 	}
 
+	if m.cfg.Outbound != nil {
+		if err := m.cfg.Outbound.CheckURL(msg.MediationTarget); err != nil {
+			m.warnConfig(ctx, WarningError, err.Error(), msg)
+			return common.ErrorConfig(0, err.Error())
+		}
+	}
+	// A host that didn't resolve or failed certificate verification
+	// recently: don't spend a lookup, handshake or retry on it.
+	if reason := m.preflight.Check(ctx, msg.MediationTarget); reason != "" {
+		m.warnConfig(ctx, WarningError, reason, msg)
+		return common.ErrorConfig(0, reason)
+	}
+
 	payload, err := json.Marshal(mediationPayload{MessageID: msg.ID})
 	if err != nil {
 		return common.ErrorConfig(0, fmt.Sprintf("payload marshal: %v", err))
@@ -295,19 +373,24 @@ func (m *HTTPMediator) mediateOnce(ctx context.Context, msg *common.Message) com
 	req.Header.Set("Content-Type", "application/json")
 	req.Header.Set("Accept", "application/json")
 
-	if msg.SigningSecret != nil {
-		sig, ts := signWebhook(payload, *msg.SigningSecret)
-		req.Header.Set(SignatureHeader, sig)
-		req.Header.Set(TimestampHeader, ts)
-	}
 	if msg.AuthToken != nil {
 		req.Header.Set("Authorization", "Bearer "+*msg.AuthToken)
 	}
+	// Middleware: signing (HMACSigning in the default pipeline) plus any
+	// deployment-specific hooks.
+	if err := m.cfg.Pipeline.preSend(ctx, req, msg); err != nil {
+		if errors.Is(err, ErrMiddlewareConfig) {
+			m.warnConfig(ctx, WarningError, err.Error(), msg)
+			return common.ErrorConfig(0, err.Error())
+		}
+		return common.ErrorProcess(30, fmt.Sprintf("Middleware failed: %v", err))
+	}
 
 	host, err := HostKeyFromURL(msg.MediationTarget)
 	if err != nil {
 		return common.ErrorConfig(0, fmt.Sprintf("invalid mediation target URL: %v", err))
 	}
+	m.targets.record(host)
 	guard := m.pools.Acquire(host)
 	defer guard.Release()
 
@@ -317,7 +400,22 @@ func (m *HTTPMediator) mediateOnce(ctx context.Context, msg *common.Message) com
 		// HTTP-status failures below they produce no response, and a silently
 		// unreachable target otherwise leaves no log evidence at all while
 		// every message retries in-pipeline.
-		slog.Warn("delivery request failed", "message_id", msg.ID, "target", msg.MediationTarget, "err", err)
+		slog.WarnContext(ctx, "delivery request failed", "message_id", msg.ID, "target", msg.MediationTarget, "err", err)
+		m.cfg.Pipeline.onError(ctx, msg, err)
+		m.preflight.Observe(msg.MediationTarget, err)
+		// The target resolved to an address the policy blocks: a config
+		// error, not an outage — don't retry or trip the breaker.
+		if errors.Is(err, outbound.ErrBlocked) {
+			m.warnConfig(ctx, WarningError, err.Error(), msg)
+			return common.ErrorConfig(0, err.Error())
+		}
+		// The target's certificate or TLS version fails our trust: fixing
+		// it takes a config change on one side or the other.
+		if outbound.IsTrustFailure(err) {
+			reason := fmt.Sprintf("TLS: trust check for %s failed: %v", host.Host, err)
+			m.warnConfig(ctx, WarningError, reason, msg)
+			return common.ErrorConfig(0, reason)
+		}
 		// Map common error types.
 		var netErr interface{ Timeout() bool }
 		if errors.As(err, &netErr) && netErr.Timeout() {
@@ -326,6 +424,13 @@ func (m *HTTPMediator) mediateOnce(ctx context.Context, msg *common.Message) com
 		return common.ErrorConnection(fmt.Sprintf("Request failed: %v", err))
 	}
 	defer resp.Body.Close()
+	m.preflight.Observe(msg.MediationTarget, nil)
+
+	if err := m.cfg.Pipeline.postReceive(ctx, resp, msg); err != nil {
+		out := common.ErrorProcess(30, fmt.Sprintf("Middleware failed: %v", err))
+		out.StatusCode = resp.StatusCode
+		return out
+	}
 
 	status := resp.StatusCode
 	switch {
@@ -347,15 +452,15 @@ func (m *HTTPMediator) mediateOnce(ctx context.Context, msg *common.Message) com
 		return common.Success()
 
 	case status == 400:
-		m.warnConfig(WarningError, "HTTP 400: Bad request", msg)
+		m.warnConfig(ctx, WarningError, "HTTP 400: Bad request", msg)
 		return common.ErrorConfig(status, "HTTP 400: Bad request")
 
 	case status == 401 || status == 403:
-		m.warnConfig(WarningError, fmt.Sprintf("HTTP %d: Auth error", status), msg)
+		m.warnConfig(ctx, WarningError, fmt.Sprintf("HTTP %d: Auth error", status), msg)
 		return common.ErrorConfig(status, fmt.Sprintf("HTTP %d: Auth error", status))
 
 	case status == 404:
-		m.warnConfig(WarningError, "HTTP 404: Not found", msg)
+		m.warnConfig(ctx, WarningError, "HTTP 404: Not found", msg)
 		return common.ErrorConfig(status, "HTTP 404: Not found")
 
 	case status == 429:
@@ -365,25 +470,25 @@ func (m *HTTPMediator) mediateOnce(ctx context.Context, msg *common.Message) com
 				retryAfter = n
 			}
 		}
-		slog.Warn("rate limited by target", "message_id", msg.ID, "retry_after", retryAfter)
+		slog.WarnContext(ctx, "rate limited by target", "message_id", msg.ID, "retry_after", retryAfter)
 		return common.RateLimited(retryAfter)
 
 	case status == 501:
-		m.warnConfig(WarningCritical, "HTTP 501: Not implemented", msg)
+		m.warnConfig(ctx, WarningCritical, "HTTP 501: Not implemented", msg)
 		return common.ErrorConfig(status, "HTTP 501: Not implemented")
 
 	case status >= 400 && status < 500:
-		slog.Warn("client error from target", "message_id", msg.ID, "status", status)
+		slog.WarnContext(ctx, "client error from target", "message_id", msg.ID, "status", status)
 		return common.ErrorConfig(status, fmt.Sprintf("HTTP %d: Client error", status))
 
 	case status >= 500:
-		slog.Warn("server error from target", "message_id", msg.ID, "status", status, "target", msg.MediationTarget)
+		slog.WarnContext(ctx, "server error from target", "message_id", msg.ID, "status", status, "target", msg.MediationTarget)
 		out := common.ErrorProcess(30, fmt.Sprintf("HTTP %d: Server error", status))
 		out.StatusCode = status
 		return out
 
 	default:
-		slog.Warn("unexpected status from target", "message_id", msg.ID, "status", status)
+		slog.WarnContext(ctx, "unexpected status from target", "message_id", msg.ID, "status", status)
 		return common.ErrorProcess(30, fmt.Sprintf("HTTP %d: Unexpected status", status))
 	}
 }
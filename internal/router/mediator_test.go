@@ -5,9 +5,14 @@ import (
 	"crypto/hmac"
 	"crypto/sha256"
 	"encoding/hex"
+	"encoding/pem"
 	"io"
+	"net"
 	"net/http"
 	"net/http/httptest"
+	"net/netip"
+	"strings"
+	"sync/atomic"
 	"testing"
 	"time"
 
@@ -15,6 +20,7 @@ import (
 	"github.com/stretchr/testify/require"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
 )
 
@@ -224,3 +230,89 @@ func TestMediatorAckFalseIsTransient(t *testing.T) {
 	assert.Equal(t, common.MediationErrorProcess, out.Result)
 	assert.Equal(t, 45, out.DelaySeconds)
 }
+
+func TestMediatorOutboundPolicy(t *testing.T) {
+	var hits atomic.Int32
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		hits.Add(1)
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	msg := &common.Message{ID: "msg_SSRF", MediationType: common.MediationTypeHTTP, MediationTarget: srv.URL}
+	ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+	defer cancel()
+
+	// Loopback is refused as a config error: no request, no retry.
+	cfg := router.DevMediatorConfig()
+	cfg.Outbound = &outbound.Policy{}
+	out := router.NewHTTPMediator(cfg, router.NewBreakerRegistry(router.DefaultBreakerConfig())).Mediate(ctx, msg)
+	assert.Equal(t, common.MediationErrorConfig, out.Result, "got %+v", out)
+	assert.Zero(t, hits.Load())
+
+	// Trusting the exact address (the platform callback) lets it through.
+	cfg.Outbound = &outbound.Policy{TrustedAddrs: []netip.AddrPort{netip.MustParseAddrPort(srv.Listener.Addr().String())}}
+	out = router.NewHTTPMediator(cfg, router.NewBreakerRegistry(router.DefaultBreakerConfig())).Mediate(ctx, msg)
+	assert.Equal(t, common.MediationSuccess, out.Result, "got %+v", out)
+	assert.Equal(t, int32(1), hits.Load())
+}
+
+func TestMediatorPreflight(t *testing.T) {
+	ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+	defer cancel()
+	breakers := router.NewBreakerRegistry(router.DefaultBreakerConfig())
+
+	// A target host that doesn't resolve is a config error before any dial.
+	cfg := router.DevMediatorConfig()
+	cfg.Preflight.Resolve = func(_ context.Context, host string) ([]string, error) {
+		return nil, &net.DNSError{Err: "no such host", Name: host, IsNotFound: true}
+	}
+	out := router.NewHTTPMediator(cfg, breakers).Mediate(ctx,
+		&common.Message{ID: "msg_DNS", MediationType: common.MediationTypeHTTP, MediationTarget: "https://typo.invalid/hook"})
+	assert.Equal(t, common.MediationErrorConfig, out.Result, "got %+v", out)
+	assert.Contains(t, out.ErrorMessage, "does not resolve")
+
+	// An untrusted certificate is a config error, not retried; the next
+	// message to the target is refused before any handshake.
+	var hits atomic.Int32
+	srv := httptest.NewTLSServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		hits.Add(1)
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	cfg = router.DevMediatorConfig()
+	cfg.RetryDelays = []time.Duration{10 * time.Millisecond, 10 * time.Millisecond}
+	m := router.NewHTTPMediator(cfg, breakers)
+	out = m.Mediate(ctx, &common.Message{ID: "msg_TLS1", MediationType: common.MediationTypeHTTP, MediationTarget: srv.URL})
+	assert.Equal(t, common.MediationErrorConfig, out.Result, "got %+v", out)
+	assert.Contains(t, out.ErrorMessage, "TLS: trust check for 127.0.0.1 failed")
+	out = m.Mediate(ctx, &common.Message{ID: "msg_TLS2", MediationType: common.MediationTypeHTTP, MediationTarget: srv.URL})
+	assert.Equal(t, common.MediationErrorConfig, out.Result, "got %+v", out)
+	assert.Contains(t, out.ErrorMessage, "TLS: trust check for "+srv.Listener.Addr().String()+" failed")
+	assert.Zero(t, hits.Load())
+}
+
+func TestMediatorTLSTrust(t *testing.T) {
+	srv := httptest.NewTLSServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer srv.Close()
+	bundle := string(pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: srv.Certificate().Raw}))
+	msg := &common.Message{ID: "msg_CA", MediationType: common.MediationTypeHTTP, MediationTarget: srv.URL}
+	ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+	defer cancel()
+
+	cfg := router.DevMediatorConfig()
+	tlsCfg, err := outbound.TLSTrust{CABundle: bundle}.ClientConfig()
+	require.NoError(t, err)
+	cfg.TLS = tlsCfg
+	out := router.NewHTTPMediator(cfg, router.NewBreakerRegistry(router.DefaultBreakerConfig())).Mediate(ctx, msg)
+	assert.Equal(t, common.MediationSuccess, out.Result, "got %+v", out)
+
+	// Pinned to some other key: a config error.
+	tlsCfg, err = outbound.TLSTrust{CABundle: bundle, SPKIPins: []string{"sha256/" + strings.Repeat("A", 43) + "="}}.ClientConfig()
+	require.NoError(t, err)
+	cfg.TLS = tlsCfg
+	out = router.NewHTTPMediator(cfg, router.NewBreakerRegistry(router.DefaultBreakerConfig())).Mediate(ctx, msg)
+	assert.Equal(t, common.MediationErrorConfig, out.Result, "got %+v", out)
+	assert.Contains(t, out.ErrorMessage, "pinned SPKI")
+}
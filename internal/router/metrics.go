@@ -48,6 +48,7 @@ type PoolMetricsCollector struct {
 	totalSuccess     atomic.Uint64
 	totalFailure     atomic.Uint64
 	totalRateLimited atomic.Uint64
+	totalExpired     atomic.Uint64
 
 	// Cumulative mediation-latency histogram, emitted as the Prometheus
 	// fc_mediation_duration_seconds histogram. Monotonic across the process
@@ -58,6 +59,12 @@ type PoolMetricsCollector struct {
 	durationSumMs   atomic.Uint64
 	durationBuckets [len(mediationBucketsSeconds)]atomic.Uint64
 
+	// Go-scheduler delay between handing a message to a worker goroutine
+	// and the goroutine starting, and time spent waiting for the pool's
+	// CPU slots (see cpu_isolation.go).
+	scheduleDelay delayHistogram
+	cpuWait       delayHistogram
+
 	mu                sync.Mutex
 	samples           []metricSample // ring-trimmed; oldest first
 	rateLimitedEvents []time.Time    // ring-trimmed; oldest first
@@ -138,16 +145,25 @@ func (c *PoolMetricsCollector) RecordRateLimited() {
 	c.rateLimitedEvents = append(c.rateLimitedEvents, now)
 }
 
+// RecordExpired counts a message dropped unmediated because its expiresAt
+// had passed. Not a delivery attempt, so no latency sample.
+func (c *PoolMetricsCollector) RecordExpired() {
+	c.totalExpired.Add(1)
+}
+
 // Reset clears every counter and sample. Test helper.
 func (c *PoolMetricsCollector) Reset() {
 	c.totalSuccess.Store(0)
 	c.totalFailure.Store(0)
 	c.totalRateLimited.Store(0)
+	c.totalExpired.Store(0)
 	c.durationCount.Store(0)
 	c.durationSumMs.Store(0)
 	for i := range c.durationBuckets {
 		c.durationBuckets[i].Store(0)
 	}
+	c.scheduleDelay.reset()
+	c.cpuWait.reset()
 	c.mu.Lock()
 	c.samples = c.samples[:0]
 	c.rateLimitedEvents = c.rateLimitedEvents[:0]
@@ -182,6 +198,65 @@ func (c *PoolMetricsCollector) HistogramSnapshot() MediationHistogram {
 	}
 }
 
+// delayBucketsSeconds are the bounds for the scheduling-delay histograms:
+// healthy values sit well under a millisecond.
+var delayBucketsSeconds = [...]float64{0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1}
+
+// delayHistogram is a cumulative histogram over delayBucketsSeconds.
+type delayHistogram struct {
+	count     atomic.Uint64
+	sumMicros atomic.Uint64
+	buckets   [len(delayBucketsSeconds)]atomic.Uint64
+}
+
+func (h *delayHistogram) observe(d time.Duration) {
+	h.count.Add(1)
+	h.sumMicros.Add(uint64(max(d, 0).Microseconds()))
+	secs := d.Seconds()
+	for i, ub := range delayBucketsSeconds {
+		if secs <= ub {
+			h.buckets[i].Add(1)
+		}
+	}
+}
+
+func (h *delayHistogram) snapshot() MediationHistogram {
+	counts := make([]uint64, len(h.buckets))
+	for i := range h.buckets {
+		counts[i] = h.buckets[i].Load()
+	}
+	bounds := make([]float64, len(delayBucketsSeconds))
+	copy(bounds, delayBucketsSeconds[:])
+	return MediationHistogram{
+		Bounds:     bounds,
+		Counts:     counts,
+		SumSeconds: float64(h.sumMicros.Load()) / 1e6,
+		Count:      h.count.Load(),
+	}
+}
+
+func (h *delayHistogram) reset() {
+	h.count.Store(0)
+	h.sumMicros.Store(0)
+	for i := range h.buckets {
+		h.buckets[i].Store(0)
+	}
+}
+
+// RecordScheduleDelay records how long a worker goroutine took to start.
+func (c *PoolMetricsCollector) RecordScheduleDelay(d time.Duration) { c.scheduleDelay.observe(d) }
+
+// RecordCPUWait records how long a delivery waited for a CPU slot.
+func (c *PoolMetricsCollector) RecordCPUWait(d time.Duration) { c.cpuWait.observe(d) }
+
+// ScheduleDelaySnapshot returns the cumulative scheduling-delay histogram.
+func (c *PoolMetricsCollector) ScheduleDelaySnapshot() MediationHistogram {
+	return c.scheduleDelay.snapshot()
+}
+
+// CPUWaitSnapshot returns the cumulative CPU-slot wait histogram.
+func (c *PoolMetricsCollector) CPUWaitSnapshot() MediationHistogram { return c.cpuWait.snapshot() }
+
 func (c *PoolMetricsCollector) addSample(durationMs uint64, success bool) {
 	c.observeDuration(durationMs)
 	c.mu.Lock()
@@ -243,6 +318,7 @@ func (c *PoolMetricsCollector) Snapshot() common.EnhancedPoolMetrics {
 		TotalSuccess:     totalSuccess,
 		TotalFailure:     totalFailure,
 		TotalRateLimited: totalRateLimited,
+		TotalExpired:     c.totalExpired.Load(),
 		SuccessRate:      successRate,
 		ProcessingTime:   processingTimeFromSamples(samples),
 		Last5Min:         last5,
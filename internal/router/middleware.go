@@ -0,0 +1,254 @@
+package router
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// MediationMiddleware hooks into every HTTP delivery attempt the
+// HTTPMediator makes, so a deployment can inject headers, rewrite the
+// request, exchange tokens or record metrics without forking the
+// mediator. Embed NopMiddleware to implement only the hooks you need.
+type MediationMiddleware interface {
+	// PreSend runs once the request is built (body, content headers,
+	// the message's bearer token) and before it is sent. An error fails
+	// the attempt: wrap ErrMiddlewareConfig for a non-retryable config
+	// error, anything else is retried like a target error.
+	PreSend(ctx context.Context, req *http.Request, msg *common.Message) error
+	// PostReceive runs on every response before its status is mapped to
+	// an outcome. The body is still unread. An error fails the attempt
+	// as a retryable process error.
+	PostReceive(ctx context.Context, resp *http.Response, msg *common.Message) error
+	// OnError runs when the request fails without a response (DNS,
+	// refused, timeout, blocked address).
+	OnError(ctx context.Context, msg *common.Message, err error)
+}
+
+// ErrMiddlewareConfig marks a PreSend error as a configuration problem:
+// the attempt fails with a config error instead of being retried.
+var ErrMiddlewareConfig = errors.New("mediation middleware config error")
+
+// NopMiddleware implements every MediationMiddleware hook as a no-op.
+type NopMiddleware struct{}
+
+func (NopMiddleware) PreSend(context.Context, *http.Request, *common.Message) error      { return nil }
+func (NopMiddleware) PostReceive(context.Context, *http.Response, *common.Message) error { return nil }
+func (NopMiddleware) OnError(context.Context, *common.Message, error)                    {}
+
+// MediationPipeline is an ordered chain of middleware. PreSend hooks run
+// in the order added; PostReceive and OnError run in reverse, so each
+// middleware sees the response after the ones added after it.
+type MediationPipeline struct {
+	mws []MediationMiddleware
+}
+
+// NewMediationPipeline builds a pipeline from mws.
+func NewMediationPipeline(mws ...MediationMiddleware) *MediationPipeline {
+	return &MediationPipeline{mws: mws}
+}
+
+// Use appends middleware and returns the pipeline for chaining.
+func (p *MediationPipeline) Use(mws ...MediationMiddleware) *MediationPipeline {
+	p.mws = append(p.mws, mws...)
+	return p
+}
+
+// DefaultMediationPipeline signs payloads with the message's signing
+// secret and nothing else — the behaviour of a mediator with no
+// middleware configured.
+func DefaultMediationPipeline() *MediationPipeline {
+	return NewMediationPipeline(HMACSigning{})
+}
+
+func (p *MediationPipeline) preSend(ctx context.Context, req *http.Request, msg *common.Message) error {
+	for _, mw := range p.mws {
+		if err := runHook(ctx, mw, func() error { return mw.PreSend(ctx, req, msg) }); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (p *MediationPipeline) postReceive(ctx context.Context, resp *http.Response, msg *common.Message) error {
+	for i := len(p.mws) - 1; i >= 0; i-- {
+		mw := p.mws[i]
+		if err := runHook(ctx, mw, func() error { return mw.PostReceive(ctx, resp, msg) }); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (p *MediationPipeline) onError(ctx context.Context, msg *common.Message, err error) {
+	for i := len(p.mws) - 1; i >= 0; i-- {
+		p.mws[i].OnError(ctx, msg, err)
+	}
+}
+
+// RequestBody returns a copy of the request body without consuming it.
+func RequestBody(req *http.Request) ([]byte, error) {
+	if req.GetBody == nil {
+		return nil, nil
+	}
+	rc, err := req.GetBody()
+	if err != nil {
+		return nil, err
+	}
+	defer rc.Close()
+	return io.ReadAll(rc)
+}
+
+// SetRequestBody replaces the request body, for middleware that
+// transforms the payload.
+func SetRequestBody(req *http.Request, body []byte) {
+	req.Body = io.NopCloser(bytes.NewReader(body))
+	req.GetBody = func() (io.ReadCloser, error) { return io.NopCloser(bytes.NewReader(body)), nil }
+	req.ContentLength = int64(len(body))
+}
+
+// HMACSigning signs the payload into SignatureHeader / TimestampHeader
+// (see signWebhook). Messages carrying a signing secret are signed with
+// it; others with Secret, or left unsigned when Secret is empty. Add it
+// last so it signs the body other middleware produced.
+type HMACSigning struct {
+	NopMiddleware
+	Secret string
+}
+
+// PreSend implements MediationMiddleware.
+func (h HMACSigning) PreSend(_ context.Context, req *http.Request, msg *common.Message) error {
+	secret := h.Secret
+	if msg.SigningSecret != nil {
+		secret = *msg.SigningSecret
+	} else if secret == "" {
+		return nil
+	}
+	body, err := RequestBody(req)
+	if err != nil {
+		return fmt.Errorf("read body for signing: %w", err)
+	}
+	sig, ts := signWebhook(body, secret)
+	req.Header.Set(SignatureHeader, sig)
+	req.Header.Set(TimestampHeader, ts)
+	return nil
+}
+
+// OAuthClientCredentials fetches an access token with the OAuth 2.0
+// client-credentials grant and sends it as a bearer token to the
+// configured hosts. Messages that carry their own auth token keep it.
+// The token is cached until shortly before it expires, and dropped when
+// a target answers 401 so the next attempt fetches a fresh one.
+type OAuthClientCredentials struct {
+	TokenURL     string
+	ClientID     string
+	ClientSecret string
+	Scopes       []string
+	// Hosts limits the middleware to these target hostnames. Empty
+	// applies it to every target — only sensible when every target
+	// trusts the same authorization server.
+	Hosts []string
+	// Client fetches tokens; nil uses a client with a 10s timeout.
+	Client *http.Client
+
+	mu      sync.Mutex
+	token   string
+	expires time.Time
+}
+
+// oauthExpirySkew refreshes a cached token this long before it expires.
+const oauthExpirySkew = 30 * time.Second
+
+func (o *OAuthClientCredentials) applies(req *http.Request) bool {
+	if len(o.Hosts) == 0 {
+		return true
+	}
+	host := req.URL.Hostname()
+	for _, h := range o.Hosts {
+		if strings.EqualFold(h, host) {
+			return true
+		}
+	}
+	return false
+}
+
+// PreSend implements MediationMiddleware.
+func (o *OAuthClientCredentials) PreSend(ctx context.Context, req *http.Request, _ *common.Message) error {
+	if req.Header.Get("Authorization") != "" || !o.applies(req) {
+		return nil
+	}
+	tok, err := o.accessToken(ctx)
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Authorization", "Bearer "+tok)
+	return nil
+}
+
+// PostReceive implements MediationMiddleware.
+func (o *OAuthClientCredentials) PostReceive(_ context.Context, resp *http.Response, _ *common.Message) error {
+	if resp.StatusCode == http.StatusUnauthorized && o.applies(resp.Request) {
+		o.mu.Lock()
+		o.token = ""
+		o.mu.Unlock()
+	}
+	return nil
+}
+
+// OnError implements MediationMiddleware.
+func (o *OAuthClientCredentials) OnError(context.Context, *common.Message, error) {}
+
+func (o *OAuthClientCredentials) accessToken(ctx context.Context) (string, error) {
+	o.mu.Lock()
+	defer o.mu.Unlock()
+	if o.token != "" && time.Now().Before(o.expires) {
+		return o.token, nil
+	}
+	form := url.Values{"grant_type": {"client_credentials"}}
+	if len(o.Scopes) > 0 {
+		form.Set("scope", strings.Join(o.Scopes, " "))
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, o.TokenURL, strings.NewReader(form.Encode()))
+	if err != nil {
+		return "", fmt.Errorf("%w: token request: %v", ErrMiddlewareConfig, err)
+	}
+	req.Header.Set("Content-Type", "application/x-www-form-urlencoded")
+	req.Header.Set("Accept", "application/json")
+	req.SetBasicAuth(url.QueryEscape(o.ClientID), url.QueryEscape(o.ClientSecret))
+	client := o.Client
+	if client == nil {
+		client = &http.Client{Timeout: 10 * time.Second}
+	}
+	resp, err := client.Do(req)
+	if err != nil {
+		return "", fmt.Errorf("token request: %w", err)
+	}
+	defer resp.Body.Close()
+	switch {
+	case resp.StatusCode == http.StatusBadRequest || resp.StatusCode == http.StatusUnauthorized:
+		// invalid_client / invalid_scope: retrying won't help.
+		return "", fmt.Errorf("%w: token endpoint returned %d", ErrMiddlewareConfig, resp.StatusCode)
+	case resp.StatusCode != http.StatusOK:
+		return "", fmt.Errorf("token endpoint returned %d", resp.StatusCode)
+	}
+	var body struct {
+		AccessToken string `json:"access_token"`
+		ExpiresIn   int    `json:"expires_in"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&body); err != nil || body.AccessToken == "" {
+		return "", fmt.Errorf("token endpoint returned no access_token")
+	}
+	o.token = body.AccessToken
+	o.expires = time.Now().Add(time.Duration(body.ExpiresIn)*time.Second - oauthExpirySkew)
+	return o.token, nil
+}
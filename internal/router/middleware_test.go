@@ -0,0 +1,121 @@
+package router_test
+
+import (
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"sync/atomic"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+// envelope injects a header and wraps the payload, and counts responses.
+type envelope struct {
+	router.NopMiddleware
+	responses atomic.Int32
+}
+
+func (e *envelope) PreSend(_ context.Context, req *http.Request, _ *common.Message) error {
+	body, err := router.RequestBody(req)
+	if err != nil {
+		return err
+	}
+	req.Header.Set("X-Tenant", "acme")
+	router.SetRequestBody(req, []byte(fmt.Sprintf(`{"wrapped":%s}`, body)))
+	return nil
+}
+
+func (e *envelope) PostReceive(context.Context, *http.Response, *common.Message) error {
+	e.responses.Add(1)
+	return nil
+}
+
+func mediatorWith(p *router.MediationPipeline) *router.HTTPMediator {
+	cfg := router.DevMediatorConfig()
+	cfg.Pipeline = p
+	return router.NewHTTPMediator(cfg, router.NewBreakerRegistry(router.DefaultBreakerConfig()))
+}
+
+func TestMediationPipelineTransformsThenSigns(t *testing.T) {
+	var gotBody []byte
+	var gotTenant, gotSig, gotTs string
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		gotBody, _ = io.ReadAll(r.Body)
+		gotTenant = r.Header.Get("X-Tenant")
+		gotSig = r.Header.Get(router.SignatureHeader)
+		gotTs = r.Header.Get(router.TimestampHeader)
+	}))
+	defer srv.Close()
+
+	env := &envelope{}
+	m := mediatorWith(router.NewMediationPipeline(env).Use(router.HMACSigning{Secret: "fallback"}))
+	out := m.Mediate(context.Background(), &common.Message{ID: "m1", MediationType: common.MediationTypeHTTP, MediationTarget: srv.URL})
+	require.Equal(t, common.MediationSuccess, out.Result, "%+v", out)
+
+	assert.Equal(t, `{"wrapped":{"messageId":"m1"}}`, string(gotBody))
+	assert.Equal(t, "acme", gotTenant)
+	assert.Equal(t, int32(1), env.responses.Load())
+	mac := hmac.New(sha256.New, []byte("fallback"))
+	mac.Write([]byte(gotTs))
+	mac.Write(gotBody)
+	assert.Equal(t, hex.EncodeToString(mac.Sum(nil)), gotSig, "signature covers the transformed body")
+}
+
+func TestOAuthClientCredentialsMiddleware(t *testing.T) {
+	var issued atomic.Int32
+	tokens := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		id, secret, _ := r.BasicAuth()
+		if id != "router" || secret != "s3cret" || r.FormValue("grant_type") != "client_credentials" {
+			w.WriteHeader(http.StatusUnauthorized)
+			return
+		}
+		n := issued.Add(1)
+		w.Header().Set("Content-Type", "application/json")
+		fmt.Fprintf(w, `{"access_token":"tok-%d","token_type":"Bearer","expires_in":3600}`, n)
+	}))
+	defer tokens.Close()
+
+	var auths []string
+	reject := false
+	target := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		auths = append(auths, r.Header.Get("Authorization"))
+		if reject {
+			w.WriteHeader(http.StatusUnauthorized)
+		}
+	}))
+	defer target.Close()
+
+	oauth := &router.OAuthClientCredentials{TokenURL: tokens.URL, ClientID: "router", ClientSecret: "s3cret"}
+	m := mediatorWith(router.NewMediationPipeline(oauth, router.HMACSigning{}))
+	msg := &common.Message{ID: "m1", MediationType: common.MediationTypeHTTP, MediationTarget: target.URL}
+
+	require.Equal(t, common.MediationSuccess, m.Mediate(context.Background(), msg).Result)
+	require.Equal(t, common.MediationSuccess, m.Mediate(context.Background(), msg).Result)
+	assert.Equal(t, []string{"Bearer tok-1", "Bearer tok-1"}, auths, "token is cached")
+
+	reject = true
+	assert.Equal(t, common.MediationErrorConfig, m.Mediate(context.Background(), msg).Result)
+	reject = false
+	require.Equal(t, common.MediationSuccess, m.Mediate(context.Background(), msg).Result)
+	assert.Equal(t, "Bearer tok-2", auths[len(auths)-1], "a 401 drops the cached token")
+
+	own := "mine"
+	msg.AuthToken = &own
+	require.Equal(t, common.MediationSuccess, m.Mediate(context.Background(), msg).Result)
+	assert.Equal(t, "Bearer mine", auths[len(auths)-1], "a message's own token wins")
+
+	bad := &router.OAuthClientCredentials{TokenURL: tokens.URL, ClientID: "router", ClientSecret: "wrong"}
+	out := mediatorWith(router.NewMediationPipeline(bad)).Mediate(context.Background(),
+		&common.Message{ID: "m2", MediationType: common.MediationTypeHTTP, MediationTarget: target.URL})
+	assert.Equal(t, common.MediationErrorConfig, out.Result, "rejected client credentials are a config error")
+}
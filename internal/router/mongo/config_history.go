@@ -0,0 +1,170 @@
+// Package mongo is the MongoDB-backed router config history
+// (router.ConfigHistory). One document per distinct config, in the
+// `router_config_history` collection:
+//
+//	{ hash, source, taken_at (date), last_seen_at (date), config (STRING json) }
+//
+// A TTL index on last_seen_at expires a snapshot once it has been
+// superseded for longer than the retention; the snapshot still in force
+// keeps being seen, so it never expires.
+package mongo
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"time"
+
+	"go.mongodb.org/mongo-driver/bson"
+	"go.mongodb.org/mongo-driver/mongo"
+	"go.mongodb.org/mongo-driver/mongo/options"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	outboxmongo "github.com/flowcatalyst/flowcatalyst-go/internal/outbox/mongo"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+const collectionName = "router_config_history"
+
+// DefaultRetention is how long a superseded snapshot is kept.
+const DefaultRetention = 30 * 24 * time.Hour
+
+// ConfigHistory is the MongoDB config history.
+type ConfigHistory struct {
+	client    *mongo.Client
+	coll      *mongo.Collection
+	retention time.Duration
+}
+
+var _ router.ConfigHistory = (*ConfigHistory)(nil)
+
+// NewConfigHistory wires a history against an existing client + database
+// name. Zero or negative retention keeps DefaultRetention.
+func NewConfigHistory(client *mongo.Client, dbName string, retention time.Duration) *ConfigHistory {
+	if retention <= 0 {
+		retention = DefaultRetention
+	}
+	return &ConfigHistory{
+		client:    client,
+		coll:      client.Database(dbName).Collection(collectionName),
+		retention: retention,
+	}
+}
+
+// ConnectConfigHistory dials with the settings from cfg and creates the
+// indexes.
+func ConnectConfigHistory(ctx context.Context, cfg common.MongoConfig, retention time.Duration) (*ConfigHistory, error) {
+	client, err := mongo.Connect(ctx, outboxmongo.ClientOptions(cfg))
+	if err != nil {
+		return nil, fmt.Errorf("mongo connect: %w", err)
+	}
+	h := NewConfigHistory(client, cfg.Database, retention)
+	if err := h.InitSchema(ctx); err != nil {
+		_ = client.Disconnect(ctx)
+		return nil, err
+	}
+	return h, nil
+}
+
+// Close disconnects the underlying client.
+func (h *ConfigHistory) Close(ctx context.Context) error { return h.client.Disconnect(ctx) }
+
+// Ping checks the connection, for /monitoring/infrastructure.
+func (h *ConfigHistory) Ping(ctx context.Context) error { return h.client.Ping(ctx, nil) }
+
+// InitSchema creates the lookup and TTL indexes (idempotent). A changed
+// retention is applied by collMod, as createIndexes will not alter an
+// existing TTL.
+func (h *ConfigHistory) InitSchema(ctx context.Context) error {
+	ttl := int32(h.retention / time.Second)
+	_, err := h.coll.Indexes().CreateMany(ctx, []mongo.IndexModel{
+		{
+			Keys:    bson.D{{Key: "taken_at", Value: -1}},
+			Options: options.Index().SetName("idx_taken_at"),
+		},
+		{
+			Keys:    bson.D{{Key: "last_seen_at", Value: 1}},
+			Options: options.Index().SetName("idx_ttl").SetExpireAfterSeconds(ttl),
+		},
+	})
+	var cmdErr mongo.CommandError
+	if errors.As(err, &cmdErr) && cmdErr.Name == "IndexOptionsConflict" {
+		return h.coll.Database().RunCommand(ctx, bson.D{
+			{Key: "collMod", Value: collectionName},
+			{Key: "index", Value: bson.D{{Key: "name", Value: "idx_ttl"}, {Key: "expireAfterSeconds", Value: ttl}}},
+		}).Err()
+	}
+	if err != nil {
+		return fmt.Errorf("config history indexes: %w", err)
+	}
+	return nil
+}
+
+// doc is the stored representation. The config is kept as a JSON string
+// so its field names stay the ones config sync serves.
+type doc struct {
+	Hash       string    `bson:"hash"`
+	Source     string    `bson:"source"`
+	TakenAt    time.Time `bson:"taken_at"`
+	LastSeenAt time.Time `bson:"last_seen_at"`
+	Config     string    `bson:"config"`
+}
+
+func (d doc) toSnapshot() (router.ConfigSnapshot, error) {
+	s := router.ConfigSnapshot{
+		Hash:       d.Hash,
+		Source:     d.Source,
+		TakenAt:    d.TakenAt.UTC(),
+		LastSeenAt: d.LastSeenAt.UTC(),
+	}
+	if err := json.Unmarshal([]byte(d.Config), &s.Config); err != nil {
+		return router.ConfigSnapshot{}, fmt.Errorf("config snapshot %s: %w", d.Hash, err)
+	}
+	return s, nil
+}
+
+// Record stores s, or moves the newest snapshot's last_seen_at forward
+// when it has the same hash. Only the leader syncs, so the read-then-write
+// does not race another router.
+func (h *ConfigHistory) Record(ctx context.Context, s router.ConfigSnapshot) error {
+	var newest doc
+	err := h.coll.FindOne(ctx, bson.M{}, options.FindOne().SetSort(bson.D{{Key: "taken_at", Value: -1}})).Decode(&newest)
+	switch {
+	case err == nil && newest.Hash == s.Hash:
+		_, err = h.coll.UpdateOne(ctx,
+			bson.M{"hash": newest.Hash, "taken_at": newest.TakenAt},
+			bson.M{"$set": bson.M{"last_seen_at": s.LastSeenAt, "source": s.Source}})
+		return err
+	case err != nil && !errors.Is(err, mongo.ErrNoDocuments):
+		return err
+	}
+	body, err := json.Marshal(s.Config)
+	if err != nil {
+		return err
+	}
+	_, err = h.coll.InsertOne(ctx, doc{
+		Hash:       s.Hash,
+		Source:     s.Source,
+		TakenAt:    s.TakenAt,
+		LastSeenAt: s.LastSeenAt,
+		Config:     string(body),
+	})
+	return err
+}
+
+// At returns the newest snapshot taken at or before t.
+func (h *ConfigHistory) At(ctx context.Context, t time.Time) (router.ConfigSnapshot, error) {
+	var d doc
+	err := h.coll.FindOne(ctx,
+		bson.M{"taken_at": bson.M{"$lte": t}},
+		options.FindOne().SetSort(bson.D{{Key: "taken_at", Value: -1}}),
+	).Decode(&d)
+	if errors.Is(err, mongo.ErrNoDocuments) {
+		return router.ConfigSnapshot{}, router.ErrNoConfigSnapshot
+	}
+	if err != nil {
+		return router.ConfigSnapshot{}, err
+	}
+	return d.toSnapshot()
+}
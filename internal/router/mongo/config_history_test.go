@@ -0,0 +1,29 @@
+package mongo
+
+import (
+	"testing"
+	"time"
+)
+
+func TestDocToSnapshot(t *testing.T) {
+	taken := time.Date(2026, 3, 1, 9, 0, 0, 0, time.UTC)
+	d := doc{
+		Hash:       "h1",
+		Source:     "http://cfg",
+		TakenAt:    taken,
+		LastSeenAt: taken.Add(time.Hour),
+		Config:     `{"processingPools":[{"code":"orders","concurrency":20}],"queues":[{"queueName":"q1","queueUri":"uri1"}]}`,
+	}
+	s, err := d.toSnapshot()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if s.Hash != "h1" || !s.TakenAt.Equal(taken) || len(s.Config.ProcessingPools) != 1 || s.Config.Queues[0].URI != "uri1" {
+		t.Errorf("snapshot = %+v", s)
+	}
+
+	d.Config = "not json"
+	if _, err := d.toSnapshot(); err == nil {
+		t.Error("a corrupt config should fail")
+	}
+}
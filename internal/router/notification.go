@@ -27,6 +27,7 @@ const (
 	WarningCategoryPoolCapacity   WarningCategory = "POOL_CAPACITY"
 	WarningCategoryQueueHealth    WarningCategory = "QUEUE_HEALTH"
 	WarningCategoryConsumerHealth WarningCategory = "CONSUMER_HEALTH"
+	WarningCategorySecurity       WarningCategory = "SECURITY"
 )
 
 // WarningSeverity mirrors the Rust enum.
@@ -51,6 +52,8 @@ type Warning struct {
 	CreatedAt      time.Time       `json:"createdAt"`
 	Acknowledged   bool            `json:"acknowledged"`
 	AcknowledgedAt *time.Time      `json:"acknowledgedAt,omitempty"`
+	// IncidentID is the incident WarningService grouped this warning into.
+	IncidentID string `json:"incidentId,omitempty"`
 }
 
 // NewWarning constructs a Warning with a freshly-minted UUID and the
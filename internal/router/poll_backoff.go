@@ -0,0 +1,104 @@
+package router
+
+import (
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Idle poll backoff defaults, used when a queue's config leaves
+// IdlePollMinMs / IdlePollMaxMs at 0. The minimum is the fixed pause the
+// poll loop used before backoff existed.
+const (
+	DefaultIdlePollMin = time.Second
+	DefaultIdlePollMax = 10 * time.Second
+)
+
+// idleBounds resolves qc's idle backoff range; a maximum below the
+// minimum is raised to it.
+func idleBounds(qc common.QueueConfig) (lo, hi time.Duration) {
+	lo, hi = DefaultIdlePollMin, DefaultIdlePollMax
+	if qc.IdlePollMinMs > 0 {
+		lo = time.Duration(qc.IdlePollMinMs) * time.Millisecond
+	}
+	if qc.IdlePollMaxMs > 0 {
+		hi = time.Duration(qc.IdlePollMaxMs) * time.Millisecond
+	}
+	return lo, max(lo, hi)
+}
+
+// idleDelay is the pause after the empties-th empty poll in a row: lo,
+// doubling each time, capped at hi.
+func idleDelay(empties int, lo, hi time.Duration) time.Duration {
+	d := lo
+	for i := 1; i < empties && d < hi; i++ {
+		d *= 2
+	}
+	return min(d, hi)
+}
+
+// wakeup lets a poll loop that received messages cut its sibling loops'
+// idle pauses short. The zero value is ready to use.
+type wakeup struct {
+	mu sync.Mutex
+	ch chan struct{}
+}
+
+// wait returns a channel closed by the next broadcast.
+func (w *wakeup) wait() <-chan struct{} {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	if w.ch == nil {
+		w.ch = make(chan struct{})
+	}
+	return w.ch
+}
+
+// broadcast wakes every loop waiting on the current channel.
+func (w *wakeup) broadcast() {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	if w.ch != nil {
+		close(w.ch)
+		w.ch = nil
+	}
+}
+
+// ConsumerPollStats is one queue's poll counters since its consumer was
+// built. On SQS every poll is a billed receive call, so EmptyPolls is the
+// idle cost.
+type ConsumerPollStats struct {
+	Queue      string
+	Polls      uint64
+	EmptyPolls uint64
+	// IdleBackoff is the longest pause any of the queue's poll loops is
+	// currently in after empty polls; 0 while messages flow.
+	IdleBackoff time.Duration
+}
+
+// ConsumerPollStats returns one entry per running consumer.
+func (m *Manager) ConsumerPollStats() []ConsumerPollStats {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	out := make([]ConsumerPollStats, 0, len(m.consumers))
+	for _, rc := range m.consumers {
+		// emptyPolls first: a loop counts a poll before its emptiness, so
+		// this order never reports more empty polls than polls.
+		empty := rc.emptyPolls.Load()
+		st := ConsumerPollStats{Queue: rc.consumer.Identifier(), Polls: rc.polls.Load(), EmptyPolls: empty}
+		for _, l := range rc.loops {
+			st.IdleBackoff = max(st.IdleBackoff, time.Duration(l.backoff.Load()))
+		}
+		out = append(out, st)
+	}
+	return out
+}
+
+// idleBoundsFor is idleBounds for rc's current config, which a config
+// sync may change while its loops run.
+func (m *Manager) idleBoundsFor(rc *runningConsumer) (lo, hi time.Duration) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return idleBounds(rc.queueCfg)
+}
@@ -0,0 +1,109 @@
+package router
+
+import (
+	"context"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+func TestIdleDelayDoublesUpToMax(t *testing.T) {
+	lo, hi := time.Second, 10*time.Second
+	for empties, want := range map[int]time.Duration{
+		1: time.Second, 2: 2 * time.Second, 3: 4 * time.Second, 4: 8 * time.Second, 5: 10 * time.Second, 50: 10 * time.Second,
+	} {
+		if got := idleDelay(empties, lo, hi); got != want {
+			t.Errorf("idleDelay(%d)=%v want %v", empties, got, want)
+		}
+	}
+}
+
+func TestIdleBounds(t *testing.T) {
+	if lo, hi := idleBounds(common.QueueConfig{}); lo != DefaultIdlePollMin || hi != DefaultIdlePollMax {
+		t.Errorf("defaults: %v..%v", lo, hi)
+	}
+	if lo, hi := idleBounds(common.QueueConfig{IdlePollMinMs: 500, IdlePollMaxMs: 60_000}); lo != 500*time.Millisecond || hi != time.Minute {
+		t.Errorf("configured: %v..%v", lo, hi)
+	}
+	if lo, hi := idleBounds(common.QueueConfig{IdlePollMinMs: 20_000}); lo != 20*time.Second || hi != 20*time.Second {
+		t.Errorf("max below min should be raised: %v..%v", lo, hi)
+	}
+}
+
+// emptyThenConsumer returns empty polls until messages is set, then one
+// message per poll.
+type emptyThenConsumer struct {
+	pollErrConsumer
+	messages atomic.Bool
+}
+
+func (c *emptyThenConsumer) Poll(context.Context, uint32) ([]common.QueuedMessage, error) {
+	c.polls.Add(1)
+	if !c.messages.Load() {
+		return nil, nil
+	}
+	return []common.QueuedMessage{{
+		Message:         common.Message{ID: "m", PoolCode: defaultPoolCode},
+		ReceiptHandle:   "rh",
+		QueueIdentifier: c.id,
+	}}, nil
+}
+
+// Empty polls back the loop off to the queue's maximum pause and count as
+// idle cost; a poll that returns messages resets the backoff.
+func TestRunConsumerBacksOffWhenIdle(t *testing.T) {
+	med := &cascadeMediator{}
+	m := NewManager(med, nil)
+	m.pools[defaultPoolCode] = NewPool(common.PoolConfig{Code: defaultPoolCode, Concurrency: 8}, med, nil, m.resolveConsumer)
+	cons := &emptyThenConsumer{pollErrConsumer: pollErrConsumer{id: "idle-q"}}
+	ctx, cancel := context.WithCancel(context.Background())
+	defer func() {
+		cancel()
+		m.wg.Wait()
+	}()
+	m.mu.Lock()
+	m.consumers["idle-q"] = m.startConsumer(ctx, cons,
+		common.QueueConfig{Name: "idle-q", Connections: 1, IdlePollMinMs: 5, IdlePollMaxMs: 20})
+	m.mu.Unlock()
+
+	waitFor := func(what string, ok func(ConsumerPollStats) bool) {
+		t.Helper()
+		deadline := time.Now().Add(2 * time.Second)
+		for {
+			if st := m.ConsumerPollStats(); len(st) == 1 && ok(st[0]) {
+				return
+			}
+			if time.Now().After(deadline) {
+				t.Fatalf("%s: stats=%+v", what, m.ConsumerPollStats())
+			}
+			time.Sleep(2 * time.Millisecond)
+		}
+	}
+	waitFor("backed off to the max", func(st ConsumerPollStats) bool {
+		return st.EmptyPolls >= 3 && st.IdleBackoff == 20*time.Millisecond
+	})
+	cons.messages.Store(true)
+	waitFor("reset on messages", func(st ConsumerPollStats) bool {
+		return st.Polls > st.EmptyPolls && st.IdleBackoff == 0
+	})
+}
+
+func TestWakeupBroadcast(t *testing.T) {
+	var w wakeup
+	a, b := w.wait(), w.wait()
+	w.broadcast()
+	for _, ch := range []<-chan struct{}{a, b} {
+		select {
+		case <-ch:
+		default:
+			t.Fatal("waiter not woken")
+		}
+	}
+	select {
+	case <-w.wait():
+		t.Fatal("a new wait should block until the next broadcast")
+	default:
+	}
+}
@@ -1,6 +1,7 @@
 package router
 
 import (
+	"container/list"
 	"context"
 	"log/slog"
 	"sync"
@@ -8,6 +9,7 @@ import (
 	"time"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/logging"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
 )
 
@@ -29,6 +31,24 @@ type Pool struct {
 	tracker  *InFlightTracker
 	metrics  *PoolMetricsCollector
 
+	// cancellations, when set, is consulted before each mediation; see
+	// Pool.cancelled.
+	cancellations Cancellations
+
+	// warnings, when set, receives the pool's expired-messages warning; see
+	// Pool.expired. expiredWarnedAt (unix nanos) throttles it, and
+	// expiredSinceWarn counts the drops it will report.
+	warnings         *WarningService
+	expiredWarnedAt  atomic.Int64
+	expiredSinceWarn atomic.Uint64
+
+	// killed is the pool's kill switch, from its config; see Manager.applyKillSwitch.
+	killed atomic.Bool
+
+	// region is the pool's data-residency region, from its config; see
+	// Manager.residencyMismatch. Guarded by Manager.mu.
+	region string
+
 	// resolveConsumer maps a message's origin queue (QueueIdentifier) to the
 	// consumer that delivered it. nil result → the queue was deregistered
 	// between routing and processing; the action is skipped (logged).
@@ -45,10 +65,9 @@ type Pool struct {
 	sem         atomic.Value // chan struct{}
 	concurrency atomic.Uint32
 
-	mu      sync.Mutex
-	groupQs map[string]*groupQueue // ordered FIFO queues per message-group
+	groups *groupTable // ordered FIFO queues per message-group; see group_table.go
 
-	queueSize     atomic.Uint32 // pending in groupQs (pre-dispatch)
+	queueSize     atomic.Uint32 // pending in groups (pre-dispatch)
 	activeWorkers atomic.Uint32 // currently inside processOne
 
 	// mediating is the live set of messages currently inside processOne — the
@@ -61,6 +80,19 @@ type Pool struct {
 	mediatingMu sync.Mutex
 	mediating   map[string]MediatingEntry
 
+	// cpu bounds concurrent CPU-bound middleware; nil when uncapped.
+	cpu atomic.Pointer[cpuGate]
+
+	// rates is the pool's rate profiles, nil when it has none. baseRate is
+	// the configured rate, in force outside every profile, and rateProfile
+	// the profile in force ("" for none); both guarded by rateMu.
+	// rateCheckedAt (unix seconds) throttles refreshRate.
+	rates         atomic.Pointer[RateSchedule]
+	rateMu        sync.Mutex
+	baseRate      uint32
+	rateProfile   string
+	rateCheckedAt atomic.Int64
+
 	stopped atomic.Bool
 }
 
@@ -87,10 +119,15 @@ type MediatingEntry struct {
 type groupQueue struct {
 	msgs    []common.QueuedMessage
 	working bool
+
+	// key, elem and touched place the queue in its groupTable shard.
+	key     string
+	elem    *list.Element
+	touched time.Time
 }
 
 // pop returns the next message to dispatch (FIFO) and whether the queue is now
-// empty. Caller holds p.mu.
+// empty. Caller holds its shard's lock.
 func (gq *groupQueue) pop() (common.QueuedMessage, bool) {
 	m := gq.msgs[0]
 	gq.msgs = gq.msgs[1:]
@@ -98,7 +135,7 @@ func (gq *groupQueue) pop() (common.QueuedMessage, bool) {
 }
 
 // empty reports whether the queue holds no pending messages. Caller
-// holds p.mu.
+// holds its shard's lock.
 func (gq *groupQueue) empty() bool {
 	return len(gq.msgs) == 0
 }
@@ -122,16 +159,22 @@ func NewPool(cfg common.PoolConfig, mediator Mediator, tracker *InFlightTracker,
 	}
 	p := &Pool{
 		cfg:             cfg,
+		region:          cfg.Region,
 		mediator:        mediator,
 		limiter:         NewRateLimiter(rate),
+		baseRate:        rate,
 		tracker:         tracker,
 		metrics:         NewPoolMetricsCollector(),
 		resolveConsumer: resolveConsumer,
-		groupQs:         make(map[string]*groupQueue),
+		groups:          newGroupTable(),
 		mediating:       make(map[string]MediatingEntry),
 	}
 	p.sem.Store(make(chan struct{}, concurrency))
 	p.concurrency.Store(concurrency)
+	p.SetCPUSlots(cfg.CPUSlots)
+	if err := p.SetRateProfiles(cfg); err != nil {
+		slog.Warn("pool: ignoring invalid rate profiles", "pool", cfg.Code, "err", err)
+	}
 	return p
 }
 
@@ -170,10 +213,10 @@ func (p *Pool) ackTracked(ctx context.Context, qm common.QueuedMessage) {
 	}
 	if c := p.consumerFor(qm); c != nil {
 		if err := c.Ack(ctx, receipt); err != nil {
-			slog.Warn("ack failed", "message_id", qm.Message.ID, "err", err)
+			slog.WarnContext(ctx, "ack failed", "message_id", qm.Message.ID, "err", err)
 		}
 	} else {
-		slog.Warn("ack: no consumer for queue", "queue", qm.QueueIdentifier, "message_id", qm.Message.ID)
+		slog.WarnContext(ctx, "ack: no consumer for queue", "queue", qm.QueueIdentifier, "message_id", qm.Message.ID)
 	}
 	if p.tracker != nil {
 		p.tracker.Remove(qm.Message.ID, qm.BrokerMessageID)
@@ -195,11 +238,11 @@ func (p *Pool) nackMsg(ctx context.Context, qm common.QueuedMessage, delay *uint
 	}
 	c := p.consumerFor(qm)
 	if c == nil {
-		slog.Warn("nack: no consumer for queue", "queue", qm.QueueIdentifier, "message_id", qm.Message.ID, "reason", reason)
+		slog.WarnContext(ctx, "nack: no consumer for queue", "queue", qm.QueueIdentifier, "message_id", qm.Message.ID, "reason", reason)
 		return
 	}
 	if err := c.Nack(ctx, qm.ReceiptHandle, delay); err != nil {
-		slog.Warn("nack failed", "reason", reason, "message_id", qm.Message.ID, "err", err)
+		slog.WarnContext(ctx, "nack failed", "reason", reason, "message_id", qm.Message.ID, "err", err)
 	}
 }
 
@@ -207,7 +250,13 @@ func (p *Pool) nackMsg(ctx context.Context, qm common.QueuedMessage, delay *uint
 func (p *Pool) Identifier() string { return p.cfg.Code }
 
 // SetRateLimit hot-swaps the rate-limit-per-minute value.
-func (p *Pool) SetRateLimit(perMinute uint32) { p.limiter.SetRate(perMinute) }
+// With rate profiles it is the rate outside their windows.
+func (p *Pool) SetRateLimit(perMinute uint32) {
+	p.rateMu.Lock()
+	p.baseRate = perMinute
+	p.rateMu.Unlock()
+	p.applyRate(time.Now(), true)
+}
 
 // UpdateRateLimit is the API-facing alias for SetRateLimit. A nil value
 // disables rate limiting (the Rust equivalent of `Option::None`).
@@ -216,7 +265,60 @@ func (p *Pool) UpdateRateLimit(perMinute *uint32) {
 	if perMinute != nil {
 		v = *perMinute
 	}
-	p.limiter.SetRate(v)
+	p.SetRateLimit(v)
+}
+
+// SetRateProfiles replaces the pool's rate profiles with pc's and applies
+// the rate now in force. An invalid set is refused, leaving the current
+// one.
+func (p *Pool) SetRateProfiles(pc common.PoolConfig) error {
+	s, err := NewRateSchedule(pc)
+	if err != nil {
+		return err
+	}
+	p.rates.Store(s)
+	p.applyRate(time.Now(), false)
+	return nil
+}
+
+// RateProfile returns the name of the rate profile in force, "" outside
+// every profile.
+func (p *Pool) RateProfile() string {
+	p.rateMu.Lock()
+	defer p.rateMu.Unlock()
+	return p.rateProfile
+}
+
+// refreshRate applies the rate in force at now, at most once a second.
+// No-op without rate profiles.
+func (p *Pool) refreshRate(now time.Time) {
+	if p.rates.Load() == nil {
+		return
+	}
+	if sec := now.Unix(); p.rateCheckedAt.Swap(sec) == sec {
+		return
+	}
+	p.applyRate(now, false)
+}
+
+// applyRate sets the limiter to the active profile's rate, or the base rate
+// outside every profile. The limiter — and its bucket — is only replaced
+// when that rate changed, unless force.
+func (p *Pool) applyRate(now time.Time, force bool) {
+	p.rateMu.Lock()
+	defer p.rateMu.Unlock()
+	name, rate := "", p.baseRate
+	if n, r, ok := p.rates.Load().At(now); ok {
+		name, rate = n, r
+	}
+	if name != p.rateProfile {
+		slog.Info("pool rate profile changed", "pool", p.cfg.Code,
+			"from", p.rateProfile, "to", name, "rate_per_minute", rate)
+		p.rateProfile = name
+	}
+	if force || rate != p.limiter.Rate() {
+		p.limiter.SetRate(rate)
+	}
 }
 
 // UpdateConcurrency swaps the semaphore to a new capacity. Returns false
@@ -238,6 +340,21 @@ func (p *Pool) UpdateConcurrency(n uint32) bool {
 	return true
 }
 
+// throttle caps in-flight work at n without changing the configured
+// concurrency (the slow-start ramp). n at or above Concurrency() restores
+// the full cap. Swaps the semaphore like UpdateConcurrency, but quietly:
+// the ramp calls it every tick.
+func (p *Pool) throttle(n uint32) {
+	c := p.concurrency.Load()
+	if n == 0 || n > c {
+		n = c
+	}
+	if uint32(cap(p.loadSem())) == n {
+		return
+	}
+	p.sem.Store(make(chan struct{}, n))
+}
+
 // Metrics exposes the pool's metric collector. The HTTP API hits this
 // when building EnhancedPoolMetrics for /monitoring/pool-stats.
 func (p *Pool) Metrics() *PoolMetricsCollector { return p.metrics }
@@ -272,7 +389,7 @@ func (p *Pool) submit(ctx context.Context, m common.QueuedMessage) {
 		// incremented here and decremented once the worker holds a semaphore
 		// slot, so the "queued (pre-dispatch)" gauge mirrors the ordered path.
 		p.queueSize.Add(1)
-		go p.runImmediate(ctx, m)
+		p.goScheduled(func() { p.runImmediate(ctx, m) })
 		return
 	}
 
@@ -280,6 +397,7 @@ func (p *Pool) submit(ctx context.Context, m common.QueuedMessage) {
 	if m.Message.MessageGroupID != nil {
 		group = *m.Message.MessageGroupID
 	}
+	p.makeRoom(ctx, group)
 	if !p.enqueue(group, m) {
 		// Raced with Stop: the buffer is flushed and nothing will drain it.
 		p.nackMsg(ctx, m, ptrU32(10), "pool stopped")
@@ -347,14 +465,7 @@ func (p *Pool) runImmediate(ctx context.Context, m common.QueuedMessage) {
 // In-flight workers drain out on their own and ack/remove per outcome.
 func (p *Pool) Stop() {
 	p.stopped.Store(true)
-	p.mu.Lock()
-	var flushed []common.QueuedMessage
-	for _, gq := range p.groupQs {
-		flushed = append(flushed, gq.msgs...)
-		gq.msgs = nil
-	}
-	p.groupQs = make(map[string]*groupQueue)
-	p.mu.Unlock()
+	flushed := p.groups.flush()
 	for i := range flushed {
 		p.queueSize.Add(^uint32(0))
 		if p.tracker != nil {
@@ -436,14 +547,11 @@ func (p *Pool) IsRateLimited() bool { return p.limiter.IsLimited() }
 
 // MessageGroupCount returns the number of message groups currently
 // holding buffered messages.
-func (p *Pool) MessageGroupCount() uint32 {
-	p.mu.Lock()
-	defer p.mu.Unlock()
-	return uint32(len(p.groupQs))
-}
+func (p *Pool) MessageGroupCount() uint32 { return uint32(p.groups.size()) }
 
 // Stats returns the dashboard-shaped snapshot of this pool.
 func (p *Pool) Stats() PoolStats {
+	p.refreshRate(time.Now())
 	concurrency := p.concurrency.Load()
 	capacity := concurrency * queueCapacityMultiplier
 	if capacity < minQueueCapacity {
@@ -457,10 +565,15 @@ func (p *Pool) Stats() PoolStats {
 		QueueSize:          p.queueSize.Load(),
 		QueueCapacity:      capacity,
 		MessageGroupCount:  p.MessageGroupCount(),
+		GroupStateBytes:    p.groups.bytes.Load(),
+		GroupEvictions:     p.groups.evictions.Load(),
 		RateLimitPerMinute: p.RateLimitPerMinute(),
 		IsRateLimited:      p.IsRateLimited(),
+		RateProfile:        p.RateProfile(),
 		Metrics:            &m,
 		Histogram:          p.metrics.HistogramSnapshot(),
+		ScheduleDelay:      p.metrics.ScheduleDelaySnapshot(),
+		CPUWait:            p.metrics.CPUWaitSnapshot(),
 	}
 }
 
@@ -474,21 +587,18 @@ const (
 
 // enqueue appends a newly-arrived message to the BACK of its group's FIFO.
 // Returns false without buffering when the pool has stopped — checked under
-// p.mu so it can't race Stop's buffer flush and strand a message (with a live
-// tracker entry) in an abandoned buffer.
+// the group's shard lock so it can't race Stop's buffer flush and strand a
+// message (with a live tracker entry) in an abandoned buffer.
 func (p *Pool) enqueue(group string, m common.QueuedMessage) bool {
-	p.mu.Lock()
+	s := p.groups.shard(group)
+	s.mu.Lock()
 	if p.stopped.Load() {
-		p.mu.Unlock()
+		s.mu.Unlock()
 		return false
 	}
-	gq, ok := p.groupQs[group]
-	if !ok {
-		gq = &groupQueue{}
-		p.groupQs[group] = gq
-	}
+	gq := p.groups.getOrCreateLocked(s, group)
 	gq.msgs = append(gq.msgs, m)
-	p.mu.Unlock()
+	s.mu.Unlock()
 	p.queueSize.Add(1)
 	return true
 }
@@ -498,18 +608,15 @@ func (p *Pool) enqueue(group string, m common.QueuedMessage) bool {
 // the same group. Used only by the ordered drainer on a retryable failure or
 // a cancellation park. Same stopped-pool contract as enqueue.
 func (p *Pool) enqueueFront(group string, m common.QueuedMessage) bool {
-	p.mu.Lock()
+	s := p.groups.shard(group)
+	s.mu.Lock()
 	if p.stopped.Load() {
-		p.mu.Unlock()
+		s.mu.Unlock()
 		return false
 	}
-	gq, ok := p.groupQs[group]
-	if !ok {
-		gq = &groupQueue{}
-		p.groupQs[group] = gq
-	}
+	gq := p.groups.getOrCreateLocked(s, group)
 	gq.msgs = append([]common.QueuedMessage{m}, gq.msgs...)
-	p.mu.Unlock()
+	s.mu.Unlock()
 	p.queueSize.Add(1)
 	return true
 }
@@ -520,16 +627,17 @@ func (p *Pool) enqueueFront(group string, m common.QueuedMessage) bool {
 // concurrently via runImmediate. The drainer processes one message per
 // group at a time to preserve FIFO order, bounded across groups by `sem`.
 func (p *Pool) tryDrainGroup(ctx context.Context, group string) {
-	p.mu.Lock()
-	gq := p.groupQs[group]
+	s := p.groups.shard(group)
+	s.mu.Lock()
+	gq := s.groups[group]
 	if gq == nil || gq.working || gq.empty() {
-		p.mu.Unlock()
+		s.mu.Unlock()
 		return
 	}
 	gq.working = true
-	p.mu.Unlock()
+	s.mu.Unlock()
 
-	go p.drainGroup(ctx, group)
+	p.goScheduled(func() { p.drainGroup(ctx, group) })
 }
 
 // drainGroup is the per-message-group worker goroutine spawned by
@@ -537,7 +645,7 @@ func (p *Pool) tryDrainGroup(ctx context.Context, group string) {
 // FIFO order within the group), gated by the pool-wide `sem` semaphore.
 //
 // Exit conditions:
-//   - the group buffer is empty (the groupQs entry is removed).
+//   - the group buffer is empty (the group's entry is removed).
 //   - ctx is cancelled while waiting for a semaphore slot or sitting out a
 //     retry backoff (the in-hand message is re-fronted and the working flag
 //     cleared so a replacement drainer resumes the group — spawned by the
@@ -557,21 +665,27 @@ func (p *Pool) tryDrainGroup(ctx context.Context, group string) {
 // flag off. A bare return with working still true wedges the group
 // permanently (tryDrainGroup will never spawn another drainer).
 func (p *Pool) drainGroup(ctx context.Context, group string) {
+	s := p.groups.shard(group)
 	for {
-		p.mu.Lock()
-		gq := p.groupQs[group]
-		if gq == nil || gq.empty() {
-			// Fully drained — remove the entry so groupQs doesn't accumulate
+		s.mu.Lock()
+		gq := s.groups[group]
+		if gq == nil {
+			s.mu.Unlock()
+			return
+		}
+		if gq.empty() {
+			// Fully drained — remove the entry so the table doesn't accumulate
 			// one empty groupQueue per group ID ever seen, and so
 			// MessageGroupCount reports only groups actually holding work.
-			delete(p.groupQs, group)
-			p.mu.Unlock()
+			p.groups.removeLocked(s, gq)
+			s.mu.Unlock()
 			return
 		}
 		msg, _ := gq.pop()
-		p.mu.Unlock()
-		// Pop happens under p.mu before any await, so queueSize stays
-		// consistent with what's actually buffered in groupQs.
+		p.groups.touchLocked(s, gq)
+		s.mu.Unlock()
+		// Pop happens under the shard lock before any await, so queueSize
+		// stays consistent with what's actually buffered in groups.
 		p.queueSize.Add(^uint32(0)) // atomic decrement
 
 		// Acquire a concurrency slot. Snapshot the channel locally so a
@@ -638,11 +752,12 @@ func (p *Pool) drainGroup(ctx context.Context, group string) {
 // tryDrainGroup spawns only when working is false, and only the drainer that
 // set the flag clears it.
 func (p *Pool) clearWorking(group string) {
-	p.mu.Lock()
-	if gq := p.groupQs[group]; gq != nil {
+	s := p.groups.shard(group)
+	s.mu.Lock()
+	if gq := s.groups[group]; gq != nil {
 		gq.working = false
 	}
-	p.mu.Unlock()
+	s.mu.Unlock()
 }
 
 // processResult is processOne's verdict, consumed by the caller (drainGroup /
@@ -692,6 +807,16 @@ func retryDelay(attempts uint, outcomeDelaySec int) time.Duration {
 	return d
 }
 
+// messageLogContext attaches the message's identity to ctx so every record
+// logged while it is in the pipeline (here and in the mediator) carries it.
+func messageLogContext(ctx context.Context, pool string, qm common.QueuedMessage) context.Context {
+	args := []any{"message_id", qm.Message.ID, "pool", pool, "queue", qm.QueueIdentifier}
+	if g := qm.Message.MessageGroupID; g != nil && *g != "" {
+		args = append(args, "message_group", *g)
+	}
+	return logging.With(ctx, args...)
+}
+
 // processOne runs the per-message pipeline: track (first dispatch only), rate
 // limit, mediate, and resolve by outcome. It does NOT release messages to the
 // broker on failure — a retryable outcome keeps the in-flight entry and returns
@@ -702,6 +827,7 @@ func (p *Pool) processOne(ctx context.Context, qm common.QueuedMessage) (result
 	defer p.activeWorkers.Add(^uint32(0)) // atomic decrement
 	p.trackMediating(qm)
 	defer p.untrackMediating(qm.Message.ID)
+	ctx = messageLogContext(ctx, p.cfg.Code, qm)
 
 	// Panic isolation: a panic mid-mediation must not crash the process (an
 	// unrecovered panic in a goroutine takes down the program) or strand the
@@ -710,8 +836,7 @@ func (p *Pool) processOne(ctx context.Context, qm common.QueuedMessage) (result
 	// let the deferred recover set the verdict.
 	defer func() {
 		if r := recover(); r != nil {
-			slog.Error("panic in processOne; retrying in-pipeline",
-				"message_id", qm.Message.ID, "panic", r)
+			slog.ErrorContext(ctx, "panic in processOne; retrying in-pipeline", "panic", r)
 			result = processRetry
 			retryAfter = panicRetryDelay
 			if p.tracker != nil {
@@ -733,19 +858,43 @@ func (p *Pool) processOne(ctx context.Context, qm common.QueuedMessage) (result
 			// requeue that slipped past route-time dedup). ACK-delete THIS
 			// copy with its own receipt handle — leaving it un-acked would let
 			// it redeliver forever — and leave the owner's entry alone.
-			slog.Info("external requeue duplicate (process-time backstop); ACKing copy",
-				"message_id", qm.Message.ID, "queue", qm.QueueIdentifier)
+			slog.InfoContext(ctx, "external requeue duplicate (process-time backstop); ACKing copy")
 			if c := p.consumerFor(qm); c != nil {
 				if err := c.Ack(ctx, qm.ReceiptHandle); err != nil {
-					slog.Warn("ack (requeue duplicate) failed", "message_id", qm.Message.ID, "err", err)
+					slog.WarnContext(ctx, "ack (requeue duplicate) failed", "err", err)
 				}
 			}
 			return processDuplicate, 0
 		}
 	}
 
+	// A job cancelled after it was queued is ACKed without delivery —
+	// before the rate limiter, so it doesn't spend a token.
+	if p.cancelled(ctx, qm.Message.ID) {
+		slog.InfoContext(ctx, "dispatch job cancelled; ACKing without delivery")
+		p.ackTracked(ctx, qm)
+		return processDone, 0
+	}
+
+	// Likewise a message past its expiresAt: stale by now, so dropped
+	// rather than delivered late.
+	if p.expired(ctx, qm) {
+		p.ackTracked(ctx, qm)
+		return processDone, 0
+	}
+
+	// A killed pool holds the message in-pipeline — undelivered, keeping its
+	// place in its group — and re-checks after killHoldDelay.
+	if p.killed.Load() {
+		if p.tracker != nil {
+			p.tracker.MarkRetrying(qm.Message.ID, qm.BrokerMessageID)
+		}
+		return processRetry, killHoldDelay
+	}
+
 	// Rate limit (per-pool token bucket). Record a rate-limited event when the
 	// limiter actually held us back (current tokens exhausted).
+	p.refreshRate(time.Now())
 	if p.limiter.IsLimited() {
 		p.metrics.RecordRateLimited()
 	}
@@ -758,7 +907,7 @@ func (p *Pool) processOne(ctx context.Context, qm common.QueuedMessage) (result
 	}
 
 	start := time.Now()
-	outcome := p.mediator.Mediate(ctx, &qm.Message)
+	outcome := p.mediator.Mediate(withCPUGate(ctx, p.cpu.Load()), &qm.Message)
 	durationMs := uint64(time.Since(start).Milliseconds())
 
 	switch outcome.Result {
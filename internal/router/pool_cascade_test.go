@@ -215,9 +215,10 @@ func mkOrdered(id string, group *string) common.QueuedMessage {
 // exactly n messages buffered — the resumable state a cancelled drainer must
 // leave behind.
 func groupIdleWithBuffered(p *Pool, group string, n int) bool {
-	p.mu.Lock()
-	defer p.mu.Unlock()
-	gq := p.groupQs[group]
+	s := p.groups.shard(group)
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	gq := s.groups[group]
 	return gq != nil && !gq.working && len(gq.msgs) == n
 }
 
@@ -268,7 +269,7 @@ func TestPoolOrderedGroupRecoversAfterCancelDuringSemWait(t *testing.T) {
 	med.mu.Unlock()
 	assert.Equal(t, []string{"m1", "m2"}, seen, "FIFO preserved across the drainer hand-off")
 
-	// Once fully drained the group's entry is GC'd from groupQs.
+	// Once fully drained the group's entry is GC'd from the group table.
 	require.Eventually(t, func() bool { return pool.MessageGroupCount() == 0 },
 		time.Second, 5*time.Millisecond, "drained group entry must be removed")
 }
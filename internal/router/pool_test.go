@@ -41,13 +41,13 @@ func TestGroupQueueEmptyAfterAllPopped(t *testing.T) {
 }
 
 func TestPoolEnqueueAppendsToBackEnqueueFrontPrepends(t *testing.T) {
-	p := &Pool{groupQs: map[string]*groupQueue{}}
+	p := &Pool{groups: newGroupTable()}
 	p.enqueue("g1", common.QueuedMessage{Message: common.Message{ID: "m1"}})
 	p.enqueue("g1", common.QueuedMessage{Message: common.Message{ID: "m2"}})
 	// A retry re-inserts at the front so it is attempted before m1/m2.
 	p.enqueueFront("g1", common.QueuedMessage{Message: common.Message{ID: "retry"}})
 
-	g1 := p.groupQs["g1"]
+	g1 := p.groups.shard("g1").groups["g1"]
 	got := make([]string, 0, len(g1.msgs))
 	for _, m := range g1.msgs {
 		got = append(got, m.Message.ID)
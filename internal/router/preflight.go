@@ -0,0 +1,209 @@
+package router
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net"
+	"net/netip"
+	"net/url"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+)
+
+// Pre-flight target validation. Most NACK storms start with a typo'd target
+// host: every message to it burns a DNS lookup or a TLS handshake, fails as
+// a connection error, retries in-process, and goes back to the queue to do
+// it all again. TargetPreflight remembers per target authority whether the
+// host resolved and whether its certificate verified, so messages to a host
+// known to be bad are ERROR_CONFIG'd straight away with a warning naming
+// the cause.
+//
+// Only definite answers are cached as failures: NXDOMAIN, and a target
+// failing the TLS trust checks (outbound.IsTrustFailure). Resolver timeouts
+// and SERVFAIL are outages, not configuration, and are left to the dial to
+// fail and retry as before.
+
+// preflightLookupTimeout bounds the pre-flight DNS lookup, whatever the
+// mediation's own timeout.
+const preflightLookupTimeout = 5 * time.Second
+
+// maxPreflightHosts bounds the cache. Once full, expired entries are swept
+// and new hosts that resolved are not remembered until there is room.
+const maxPreflightHosts = 10000
+
+// PreflightConfig configures TargetPreflight.
+type PreflightConfig struct {
+	// TTL is how long a host that resolved is trusted before it is looked
+	// up again. Zero uses 5 minutes.
+	TTL time.Duration
+	// NegativeTTL is how long a host that didn't resolve, or failed the
+	// TLS trust checks, is refused. Zero disables pre-flight.
+	NegativeTTL time.Duration
+	// Resolve looks a host up. nil uses net.DefaultResolver.
+	Resolve func(ctx context.Context, host string) ([]string, error)
+}
+
+// DefaultPreflightConfig trusts a resolved host for 5 minutes and refuses a
+// bad one for 1 minute.
+func DefaultPreflightConfig() PreflightConfig {
+	return PreflightConfig{TTL: 5 * time.Minute, NegativeTTL: time.Minute}
+}
+
+// preflightEntry is what is known about one target authority. A zero time
+// means "not known".
+type preflightEntry struct {
+	resolvedUntil time.Time
+	badUntil      time.Time
+	reason        string
+}
+
+// TargetPreflight caches per-host DNS and TLS outcomes for the mediator.
+// A nil *TargetPreflight checks nothing.
+type TargetPreflight struct {
+	cfg PreflightConfig
+
+	mu    sync.Mutex
+	hosts map[string]*preflightEntry
+}
+
+// NewTargetPreflight returns the cache for cfg, or nil when NegativeTTL is
+// zero.
+func NewTargetPreflight(cfg PreflightConfig) *TargetPreflight {
+	if cfg.NegativeTTL <= 0 {
+		return nil
+	}
+	if cfg.TTL <= 0 {
+		cfg.TTL = 5 * time.Minute
+	}
+	if cfg.Resolve == nil {
+		cfg.Resolve = net.DefaultResolver.LookupHost
+	}
+	return &TargetPreflight{cfg: cfg, hosts: make(map[string]*preflightEntry)}
+}
+
+// Check returns why target must not be attempted, or "" when it may be. A
+// host not looked up within TTL is resolved first; a lookup that fails for
+// any reason but NXDOMAIN lets the attempt go ahead.
+func (p *TargetPreflight) Check(ctx context.Context, target string) string {
+	if p == nil {
+		return ""
+	}
+	u, err := url.Parse(target)
+	if err != nil || u.Hostname() == "" {
+		return ""
+	}
+	key, host := u.Host, u.Hostname()
+	_, err = netip.ParseAddr(host)
+	isIP := err == nil
+
+	now := time.Now()
+	p.mu.Lock()
+	e := p.hosts[key]
+	switch {
+	case e != nil && now.Before(e.badUntil):
+		p.mu.Unlock()
+		return e.reason
+	case isIP || (e != nil && now.Before(e.resolvedUntil)):
+		p.mu.Unlock()
+		return ""
+	}
+	p.mu.Unlock()
+
+	lctx, cancel := context.WithTimeout(ctx, preflightLookupTimeout)
+	defer cancel()
+	_, err = p.cfg.Resolve(lctx, host)
+	if err == nil {
+		p.update(key, func(e *preflightEntry) { e.resolvedUntil = now.Add(p.cfg.TTL) })
+		return ""
+	}
+	if reason := dnsFailure(host, err); reason != "" {
+		p.fail(key, reason)
+		return reason
+	}
+	return ""
+}
+
+// Observe records the outcome of a request to target: nil clears a cached
+// TLS failure, a trust failure or NXDOMAIN from the dial is cached for
+// NegativeTTL. Other errors are ignored.
+func (p *TargetPreflight) Observe(target string, err error) {
+	if p == nil {
+		return
+	}
+	u, perr := url.Parse(target)
+	if perr != nil || u.Hostname() == "" {
+		return
+	}
+	if err == nil {
+		p.mu.Lock()
+		if e := p.hosts[u.Host]; e != nil {
+			e.badUntil, e.reason = time.Time{}, ""
+		}
+		p.mu.Unlock()
+		return
+	}
+	reason := dnsFailure(u.Hostname(), err)
+	if reason == "" {
+		reason = tlsFailure(u.Host, err)
+	}
+	if reason != "" {
+		p.fail(u.Host, reason)
+	}
+}
+
+func (p *TargetPreflight) fail(key, reason string) {
+	until := time.Now().Add(p.cfg.NegativeTTL)
+	p.update(key, func(e *preflightEntry) {
+		e.resolvedUntil = time.Time{}
+		e.badUntil, e.reason = until, reason
+	})
+}
+
+// update applies fn to key's entry, creating it when there is room.
+func (p *TargetPreflight) update(key string, fn func(*preflightEntry)) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	e := p.hosts[key]
+	if e == nil {
+		if len(p.hosts) >= maxPreflightHosts {
+			p.sweepLocked()
+			if len(p.hosts) >= maxPreflightHosts {
+				return
+			}
+		}
+		e = &preflightEntry{}
+		p.hosts[key] = e
+	}
+	fn(e)
+}
+
+// sweepLocked drops entries with nothing left to remember.
+func (p *TargetPreflight) sweepLocked() {
+	now := time.Now()
+	for k, e := range p.hosts {
+		if !now.Before(e.resolvedUntil) && !now.Before(e.badUntil) {
+			delete(p.hosts, k)
+		}
+	}
+}
+
+// dnsFailure describes err when it says host doesn't exist.
+func dnsFailure(host string, err error) string {
+	var dnsErr *net.DNSError
+	if errors.As(err, &dnsErr) && dnsErr.IsNotFound {
+		return fmt.Sprintf("DNS: target host %s does not resolve", host)
+	}
+	return ""
+}
+
+// tlsFailure describes err when the target failed the client's TLS trust
+// checks.
+func tlsFailure(authority string, err error) string {
+	if !outbound.IsTrustFailure(err) {
+		return ""
+	}
+	return fmt.Sprintf("TLS: trust check for %s failed: %v", authority, err)
+}
@@ -0,0 +1,100 @@
+package router_test
+
+import (
+	"context"
+	"crypto/tls"
+	"crypto/x509"
+	"errors"
+	"fmt"
+	"net"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+// fakeResolver answers typo.example with NXDOMAIN, flaky.example with a
+// timeout and anything else with an address, counting lookups.
+func fakeResolver(lookups *atomic.Int32) func(context.Context, string) ([]string, error) {
+	return func(_ context.Context, host string) ([]string, error) {
+		lookups.Add(1)
+		switch host {
+		case "typo.example":
+			return nil, &net.DNSError{Err: "no such host", Name: host, IsNotFound: true}
+		case "flaky.example":
+			return nil, &net.DNSError{Err: "i/o timeout", Name: host, IsTimeout: true, IsTemporary: true}
+		}
+		return []string{"203.0.113.7"}, nil
+	}
+}
+
+func TestPreflightDNS(t *testing.T) {
+	var lookups atomic.Int32
+	p := router.NewTargetPreflight(router.PreflightConfig{
+		TTL:         time.Minute,
+		NegativeTTL: time.Minute,
+		Resolve:     fakeResolver(&lookups),
+	})
+	ctx := context.Background()
+
+	// NXDOMAIN is refused, and remembered.
+	assert.Equal(t, "DNS: target host typo.example does not resolve", p.Check(ctx, "https://typo.example/hook"))
+	assert.Equal(t, "DNS: target host typo.example does not resolve", p.Check(ctx, "https://typo.example/other"))
+	assert.Equal(t, int32(1), lookups.Load())
+
+	// A host that resolved isn't looked up again within TTL.
+	assert.Empty(t, p.Check(ctx, "https://ok.example/hook"))
+	assert.Empty(t, p.Check(ctx, "https://ok.example/hook"))
+	assert.Equal(t, int32(2), lookups.Load())
+
+	// A resolver timeout isn't the target's fault: let it through, don't cache.
+	assert.Empty(t, p.Check(ctx, "https://flaky.example/hook"))
+	assert.Empty(t, p.Check(ctx, "https://flaky.example/hook"))
+	assert.Equal(t, int32(4), lookups.Load())
+
+	// Address literals have nothing to resolve.
+	assert.Empty(t, p.Check(ctx, "http://192.0.2.1:8080/hook"))
+	assert.Empty(t, p.Check(ctx, "http://[2001:db8::1]/hook"))
+	assert.Equal(t, int32(4), lookups.Load())
+}
+
+func TestPreflightNegativeTTLExpires(t *testing.T) {
+	var lookups atomic.Int32
+	p := router.NewTargetPreflight(router.PreflightConfig{NegativeTTL: 20 * time.Millisecond, Resolve: fakeResolver(&lookups)})
+	ctx := context.Background()
+
+	assert.NotEmpty(t, p.Check(ctx, "https://typo.example/hook"))
+	time.Sleep(40 * time.Millisecond)
+	assert.NotEmpty(t, p.Check(ctx, "https://typo.example/hook"))
+	assert.Equal(t, int32(2), lookups.Load(), "looked up again once the negative entry expired")
+}
+
+func TestPreflightTLS(t *testing.T) {
+	var lookups atomic.Int32
+	p := router.NewTargetPreflight(router.PreflightConfig{NegativeTTL: time.Minute, Resolve: fakeResolver(&lookups)})
+	ctx := context.Background()
+	target := "https://ok.example:8443/hook"
+
+	// Errors that say nothing about the certificate are not remembered.
+	p.Observe(target, errors.New("dial tcp: connection refused"))
+	assert.Empty(t, p.Check(ctx, target))
+
+	verifyErr := &tls.CertificateVerificationError{Err: x509.UnknownAuthorityError{}}
+	p.Observe(target, fmt.Errorf("post %q: %w", target, verifyErr))
+	assert.Contains(t, p.Check(ctx, target), "TLS: trust check for ok.example:8443 failed")
+	assert.Empty(t, p.Check(ctx, "https://ok.example/hook"), "other ports of the host are unaffected")
+
+	// A response from the target clears it.
+	p.Observe(target, nil)
+	assert.Empty(t, p.Check(ctx, target))
+}
+
+func TestPreflightDisabled(t *testing.T) {
+	p := router.NewTargetPreflight(router.PreflightConfig{TTL: time.Minute})
+	assert.Nil(t, p)
+	assert.Empty(t, p.Check(context.Background(), "https://typo.example/hook"))
+	p.Observe("https://typo.example/hook", &net.DNSError{IsNotFound: true})
+}
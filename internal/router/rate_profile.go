@@ -0,0 +1,160 @@
+package router
+
+import (
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Rate profiles. A receiver that can take a burst overnight but only a
+// trickle in its business hours gets a pool rate that follows the clock:
+// each profile (common.RateProfile) is a weekly window with its own rate,
+// and the pool's rateLimitPerMinute applies outside every window. Windows
+// are wall-clock times in the pool's timezone, so they follow daylight
+// saving there rather than drifting an hour twice a year.
+//
+// The pool re-evaluates its schedule at most once a second, before a
+// delivery takes a token and when its stats are read; the limiter is only
+// replaced when the rate in force changes.
+
+// maxRateProfiles bounds the profiles one pool may carry.
+const maxRateProfiles = 32
+
+// RateSchedule is a pool's compiled rate profiles. Immutable once built.
+type RateSchedule struct {
+	loc     *time.Location
+	windows []rateWindow
+}
+
+// rateWindow is one compiled profile. start and end are minutes past
+// midnight; end <= start wraps past midnight, the window belonging to the
+// day it starts.
+type rateWindow struct {
+	name       string
+	days       [7]bool // by time.Weekday
+	start, end int
+	rate       uint32 // 0 = unlimited
+}
+
+// NewRateSchedule compiles pc's rate profiles, or returns nil when it has
+// none. Profile names must be unique and non-empty, days a cron-style
+// weekday list, times "HH:MM", and the timezone an IANA name (empty is
+// UTC).
+func NewRateSchedule(pc common.PoolConfig) (*RateSchedule, error) {
+	if len(pc.RateProfiles) == 0 {
+		return nil, nil
+	}
+	if len(pc.RateProfiles) > maxRateProfiles {
+		return nil, fmt.Errorf("at most %d rate profiles", maxRateProfiles)
+	}
+	loc, err := time.LoadLocation(pc.Timezone)
+	if err != nil {
+		return nil, fmt.Errorf("timezone %q: %w", pc.Timezone, err)
+	}
+	s := &RateSchedule{loc: loc, windows: make([]rateWindow, 0, len(pc.RateProfiles))}
+	seen := make(map[string]struct{}, len(pc.RateProfiles))
+	for _, rp := range pc.RateProfiles {
+		if rp.Name == "" {
+			return nil, errors.New("rate profile: name is required")
+		}
+		if _, dup := seen[rp.Name]; dup {
+			return nil, fmt.Errorf("rate profile %q: duplicate name", rp.Name)
+		}
+		seen[rp.Name] = struct{}{}
+		w := rateWindow{name: rp.Name}
+		if w.days, err = parseWeekdays(rp.Days); err != nil {
+			return nil, fmt.Errorf("rate profile %q: %w", rp.Name, err)
+		}
+		if w.start, err = parseClock(rp.Start); err != nil {
+			return nil, fmt.Errorf("rate profile %q: start: %w", rp.Name, err)
+		}
+		if w.end, err = parseClock(rp.End); err != nil {
+			return nil, fmt.Errorf("rate profile %q: end: %w", rp.Name, err)
+		}
+		if rp.RateLimitPerMinute != nil {
+			w.rate = *rp.RateLimitPerMinute
+		}
+		s.windows = append(s.windows, w)
+	}
+	return s, nil
+}
+
+// At returns the profile in force at t and its rate (0 = unlimited). ok is
+// false outside every window. The first matching profile wins.
+func (s *RateSchedule) At(t time.Time) (name string, perMinute uint32, ok bool) {
+	if s == nil {
+		return "", 0, false
+	}
+	t = t.In(s.loc)
+	minute := t.Hour()*60 + t.Minute()
+	today := t.Weekday()
+	yesterday := (today + 6) % 7
+	for _, w := range s.windows {
+		if w.contains(today, yesterday, minute) {
+			return w.name, w.rate, true
+		}
+	}
+	return "", 0, false
+}
+
+func (w rateWindow) contains(today, yesterday time.Weekday, minute int) bool {
+	if w.start < w.end {
+		return w.days[today] && minute >= w.start && minute < w.end
+	}
+	// Past midnight: the evening belongs to today's window, the early
+	// hours to yesterday's.
+	return (w.days[today] && minute >= w.start) || (w.days[yesterday] && minute < w.end)
+}
+
+// parseWeekdays parses a cron day-of-week field: "*" or empty for every
+// day, else a comma list of days and ranges, 0 (or 7) being Sunday.
+func parseWeekdays(spec string) ([7]bool, error) {
+	var days [7]bool
+	if spec == "" || spec == "*" {
+		for i := range days {
+			days[i] = true
+		}
+		return days, nil
+	}
+	for part := range strings.SplitSeq(spec, ",") {
+		lo, hi, isRange := strings.Cut(strings.TrimSpace(part), "-")
+		from, err := parseWeekday(lo)
+		if err != nil {
+			return days, err
+		}
+		to := from
+		if isRange {
+			if to, err = parseWeekday(hi); err != nil {
+				return days, err
+			}
+			if to < from {
+				return days, fmt.Errorf("days %q: range %s runs backwards", spec, part)
+			}
+		}
+		for d := from; d <= to; d++ {
+			days[d%7] = true
+		}
+	}
+	return days, nil
+}
+
+func parseWeekday(s string) (int, error) {
+	d, err := strconv.Atoi(s)
+	if err != nil || d < 0 || d > 7 {
+		return 0, fmt.Errorf("day %q must be 0-7", s)
+	}
+	return d, nil
+}
+
+// parseClock parses "HH:MM" into minutes past midnight.
+func parseClock(s string) (int, error) {
+	t, err := time.Parse("15:04", s)
+	if err != nil {
+		return 0, fmt.Errorf("%q is not HH:MM", s)
+	}
+	return t.Hour()*60 + t.Minute(), nil
+}
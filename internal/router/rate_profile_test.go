@@ -0,0 +1,100 @@
+package router_test
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+func u32(v uint32) *uint32 { return &v }
+
+func TestRateScheduleWindows(t *testing.T) {
+	s, err := router.NewRateSchedule(common.PoolConfig{
+		Code:     "P",
+		Timezone: "Europe/Helsinki",
+		RateProfiles: []common.RateProfile{
+			{Name: "business", Days: "1-5", Start: "08:00", End: "17:00", RateLimitPerMinute: u32(60)},
+			{Name: "overnight", Start: "22:00", End: "06:00"},
+		},
+	})
+	require.NoError(t, err)
+	hel, err := time.LoadLocation("Europe/Helsinki")
+	require.NoError(t, err)
+
+	for _, tc := range []struct {
+		at   time.Time
+		name string
+		rate uint32
+		ok   bool
+	}{
+		{time.Date(2026, 3, 2, 9, 0, 0, 0, hel), "business", 60, true},   // Monday
+		{time.Date(2026, 3, 2, 17, 0, 0, 0, hel), "", 0, false},          // end is exclusive
+		{time.Date(2026, 3, 7, 9, 0, 0, 0, hel), "", 0, false},           // Saturday
+		{time.Date(2026, 3, 7, 23, 30, 0, 0, hel), "overnight", 0, true}, // evening half
+		{time.Date(2026, 3, 8, 5, 59, 0, 0, hel), "overnight", 0, true},  // early hours, Sunday
+		{time.Date(2026, 3, 8, 6, 0, 0, 0, hel), "", 0, false},
+		// 07:00 UTC is 09:00 in Helsinki.
+		{time.Date(2026, 3, 2, 7, 0, 0, 0, time.UTC), "business", 60, true},
+	} {
+		name, rate, ok := s.At(tc.at)
+		assert.Equal(t, tc.ok, ok, tc.at)
+		assert.Equal(t, tc.name, name, tc.at)
+		assert.Equal(t, tc.rate, rate, tc.at)
+	}
+}
+
+func TestRateScheduleOvernightBelongsToStartDay(t *testing.T) {
+	// Friday night only: the early hours of Saturday are in, Friday's are not.
+	s, err := router.NewRateSchedule(common.PoolConfig{RateProfiles: []common.RateProfile{
+		{Name: "fri", Days: "5", Start: "20:00", End: "04:00", RateLimitPerMinute: u32(600)},
+	}})
+	require.NoError(t, err)
+
+	_, _, ok := s.At(time.Date(2026, 3, 7, 3, 0, 0, 0, time.UTC)) // Saturday
+	assert.True(t, ok)
+	_, _, ok = s.At(time.Date(2026, 3, 6, 3, 0, 0, 0, time.UTC)) // Friday
+	assert.False(t, ok)
+}
+
+func TestRateScheduleValidation(t *testing.T) {
+	s, err := router.NewRateSchedule(common.PoolConfig{Timezone: "Nowhere/Special"})
+	assert.NoError(t, err, "no profiles, nothing to check")
+	assert.Nil(t, s)
+
+	for name, pc := range map[string]common.PoolConfig{
+		"no name":   {RateProfiles: []common.RateProfile{{Start: "00:00", End: "01:00"}}},
+		"duplicate": {RateProfiles: []common.RateProfile{{Name: "a", Start: "00:00", End: "01:00"}, {Name: "a", Start: "02:00", End: "03:00"}}},
+		"bad day":   {RateProfiles: []common.RateProfile{{Name: "a", Days: "mon", Start: "00:00", End: "01:00"}}},
+		"backwards": {RateProfiles: []common.RateProfile{{Name: "a", Days: "5-1", Start: "00:00", End: "01:00"}}},
+		"bad time":  {RateProfiles: []common.RateProfile{{Name: "a", Start: "25:00", End: "01:00"}}},
+		"timezone":  {Timezone: "Nowhere/Special", RateProfiles: []common.RateProfile{{Name: "a", Start: "00:00", End: "01:00"}}},
+	} {
+		_, err := router.NewRateSchedule(pc)
+		assert.Error(t, err, name)
+	}
+}
+
+func TestPoolStatsRateProfile(t *testing.T) {
+	p := router.NewPool(common.PoolConfig{
+		Code:               "P",
+		Concurrency:        1,
+		RateLimitPerMinute: u32(60),
+		RateProfiles:       []common.RateProfile{{Name: "always", Start: "00:00", End: "00:00", RateLimitPerMinute: u32(600)}},
+	}, nil, nil, nil)
+
+	st := p.Stats()
+	assert.Equal(t, "always", st.RateProfile)
+	require.NotNil(t, st.RateLimitPerMinute)
+	assert.Equal(t, uint32(600), *st.RateLimitPerMinute)
+
+	// Dropping the profiles restores the base rate.
+	require.NoError(t, p.SetRateProfiles(common.PoolConfig{Code: "P"}))
+	st = p.Stats()
+	assert.Empty(t, st.RateProfile)
+	assert.Equal(t, uint32(60), *st.RateLimitPerMinute)
+}
@@ -45,6 +45,29 @@ func (rl *RateLimiter) IsLimited() bool {
 	return rl.limiter.Load().Tokens() < 1.0
 }
 
+// Tokens returns the tokens currently in the bucket; 0 when unlimited.
+func (rl *RateLimiter) Tokens() float64 {
+	if rl.rpm.Load() == 0 {
+		return 0
+	}
+	return rl.limiter.Load().Tokens()
+}
+
+// RestoreTokens drains a fresh bucket down to what a previous process
+// left in it at `at`, plus what it would have refilled since. Without
+// this a restarted pool starts with a full burst. No-op when unlimited.
+func (rl *RateLimiter) RestoreTokens(tokens float64, at time.Time) {
+	if rl.rpm.Load() == 0 {
+		return
+	}
+	lim := rl.limiter.Load()
+	now := time.Now()
+	want := tokens + float64(lim.Limit())*now.Sub(at).Seconds()
+	if excess := int(lim.TokensAt(now) - want); excess > 0 {
+		lim.ReserveN(now, excess)
+	}
+}
+
 func (rl *RateLimiter) replaceUnsafe(perMinute uint32) {
 	rl.rpm.Store(perMinute)
 	if perMinute == 0 {
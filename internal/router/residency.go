@@ -0,0 +1,63 @@
+package router
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+// residencyHoldDelay is how long a message held for a data-residency
+// mismatch waits before it is redelivered and checked again.
+const residencyHoldDelay = 30 * time.Second
+
+// messageRegion is the data-residency region msg carries, "" when it is
+// unrestricted.
+func messageRegion(msg common.QueuedMessage) string {
+	if msg.Message.Region == nil {
+		return ""
+	}
+	return *msg.Message.Region
+}
+
+// residencyMismatch reports why msg may not be delivered by pool, or ""
+// when it may. A message carrying a region must not arrive on a queue
+// tagged with another region, and only a pool tagged with its region may
+// deliver it. Unrestricted messages go anywhere.
+func (m *Manager) residencyMismatch(msg common.QueuedMessage, pool *Pool) string {
+	region := messageRegion(msg)
+	if region == "" {
+		return ""
+	}
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	if qr := m.queues[msg.QueueIdentifier].Region; qr != "" && qr != region {
+		return fmt.Sprintf("queue %s is in region %q", msg.QueueIdentifier, qr)
+	}
+	if pool.region != region {
+		return fmt.Sprintf("pool %s is in region %q", pool.cfg.Code, pool.region)
+	}
+	return ""
+}
+
+// holdForResidency hands msg back to its queue undelivered and raises a
+// routing warning: delivering it would egress outside its region, so it
+// waits for the pool or queue regions to be fixed. It is leaving the
+// pipeline, so its tracker entry is released.
+func (m *Manager) holdForResidency(ctx context.Context, msg common.QueuedMessage, source queue.Consumer, reason string) {
+	region := messageRegion(msg)
+	slog.Warn("message held: data-residency mismatch", "message_id", msg.Message.ID, "region", region, "reason", reason)
+	if w := m.warnings.Load(); w != nil {
+		w.Add(WarningCategoryRouting, WarningCritical,
+			fmt.Sprintf("message %s for region %q held undelivered: %s", msg.Message.ID, region, reason), "router")
+	}
+	if m.tracker != nil {
+		m.tracker.Remove(msg.Message.ID, msg.BrokerMessageID)
+	}
+	if err := source.Nack(ctx, msg.ReceiptHandle, ptrU32(uint32(residencyHoldDelay/time.Second))); err != nil {
+		slog.Warn("nack (residency hold) failed", "message_id", msg.Message.ID, "err", err)
+	}
+}
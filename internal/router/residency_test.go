@@ -0,0 +1,81 @@
+package router
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+func regionMsg(region, queueID string) common.QueuedMessage {
+	msg := common.QueuedMessage{
+		Message:         common.Message{ID: "x", MediationTarget: "https://a.partner.example.com/hook"},
+		ReceiptHandle:   "rh-x",
+		QueueIdentifier: queueID,
+	}
+	if region != "" {
+		msg.Message.Region = &region
+	}
+	return msg
+}
+
+func TestResidencyMismatch(t *testing.T) {
+	med := &cascadeMediator{}
+	m := NewManager(med, nil)
+	resolve := func(string) queue.Consumer { return nil }
+	eu := NewPool(common.PoolConfig{Code: "EU", Region: "eu"}, med, nil, resolve)
+	global := NewPool(common.PoolConfig{Code: defaultPoolCode}, med, nil, resolve)
+	m.queues["eu-q"] = common.QueueConfig{Name: "eu-q", Region: "eu"}
+	m.queues["us-q"] = common.QueueConfig{Name: "us-q", Region: "us"}
+	m.queues["plain-q"] = common.QueueConfig{Name: "plain-q"}
+
+	assert.Empty(t, m.residencyMismatch(regionMsg("eu", "eu-q"), eu))
+	assert.Empty(t, m.residencyMismatch(regionMsg("eu", "plain-q"), eu), "an untagged queue carries any region")
+	assert.Empty(t, m.residencyMismatch(regionMsg("", "us-q"), global), "unrestricted messages go anywhere")
+	assert.Empty(t, m.residencyMismatch(regionMsg("", "plain-q"), eu))
+	assert.NotEmpty(t, m.residencyMismatch(regionMsg("eu", "eu-q"), global), "an untagged pool must not deliver a regional message")
+	assert.NotEmpty(t, m.residencyMismatch(regionMsg("eu", "us-q"), eu), "wrong-region queue")
+}
+
+func TestRoutingRulesOnlyPickRegionPools(t *testing.T) {
+	med := &cascadeMediator{}
+	m := NewManager(med, nil)
+	resolve := func(string) queue.Consumer { return nil }
+	partner := NewPool(common.PoolConfig{Code: "PARTNER", Region: "us"}, med, nil, resolve)
+	def := NewPool(common.PoolConfig{Code: defaultPoolCode, Region: "eu"}, med, nil, resolve)
+	m.pools["PARTNER"] = partner
+	m.pools[defaultPoolCode] = def
+	require.NoError(t, m.SetRoutingRules([]common.RoutingRule{
+		{Name: "partner", TargetHost: "*.partner.example.com", PoolCode: "PARTNER"},
+	}))
+
+	assert.Same(t, partner, m.poolForMessage(regionMsg("", "q")))
+	assert.Same(t, partner, m.poolForMessage(regionMsg("us", "q")))
+	assert.Same(t, def, m.poolForMessage(regionMsg("eu", "q")), "a rule whose pool is in another region is skipped")
+}
+
+func TestRouteHoldsResidencyMismatch(t *testing.T) {
+	med := &cascadeMediator{}
+	tr := NewInFlightTracker()
+	m := NewManager(med, tr)
+	ws := NewWarningService(DefaultWarningServiceConfig())
+	m.SetWarnings(ws)
+	m.pools[defaultPoolCode] = NewPool(common.PoolConfig{Code: defaultPoolCode}, med, tr, func(string) queue.Consumer { return nil })
+
+	cons := &cascadeConsumer{wantTotal: 1, done: make(chan struct{})}
+	m.route(context.Background(), []common.QueuedMessage{regionMsg("eu", "q")}, cons)
+
+	cons.mu.Lock()
+	nacked := append([]string(nil), cons.nacked...)
+	cons.mu.Unlock()
+	assert.Equal(t, []string{"rh-x"}, nacked, "the message is handed back, not delivered")
+	med.mu.Lock()
+	assert.Empty(t, med.seen, "nothing was mediated")
+	med.mu.Unlock()
+	assert.Zero(t, tr.Count(), "the held message leaves the pipeline")
+	assert.Len(t, ws.BySeverity(WarningCritical), 1)
+}
@@ -0,0 +1,101 @@
+package routersim
+
+import (
+	"context"
+	"hash/fnv"
+	"math/rand/v2"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Attempt is one mediation attempt as the simulated endpoint saw it.
+type Attempt struct {
+	MessageID string
+	Group     string
+	N         int // 1-based attempt number for this message
+	Start     time.Time
+	End       time.Time
+	Result    common.MediationResult
+}
+
+// Mediator is a router.Mediator whose latency and outcome for attempt n of
+// a message come from a generator seeded by (seed, message ID, n), so they
+// do not depend on the order goroutines happen to call it in.
+type Mediator struct {
+	seed        uint64
+	minLatency  time.Duration
+	maxLatency  time.Duration
+	failureRate float64
+	maxFailures int
+	failDelay   int
+	hang        map[string]time.Duration
+
+	mu       sync.Mutex
+	counts   map[string]int
+	attempts []Attempt
+}
+
+func newMediator(sc Scenario) *Mediator {
+	return &Mediator{
+		seed:        sc.Seed,
+		minLatency:  sc.MinLatency,
+		maxLatency:  sc.MaxLatency,
+		failureRate: sc.FailureRate,
+		maxFailures: sc.MaxFailures,
+		failDelay:   sc.FailDelaySeconds,
+		hang:        sc.Hang,
+		counts:      map[string]int{},
+	}
+}
+
+// Mediate sleeps for the attempt's latency and returns its outcome. A
+// cancelled ctx ends the attempt early as a connection error, and it is
+// left out of the trace: it is shutdown, not endpoint behaviour.
+func (md *Mediator) Mediate(ctx context.Context, m *common.Message) common.MediationOutcome {
+	md.mu.Lock()
+	md.counts[m.ID]++
+	n := md.counts[m.ID]
+	md.mu.Unlock()
+
+	rng := rand.New(rand.NewPCG(md.seed, attemptKey(m.ID, n)))
+	latency := md.minLatency
+	if span := md.maxLatency - md.minLatency; span > 0 {
+		latency += time.Duration(rng.Int64N(int64(span) + 1))
+	}
+	if d, ok := md.hang[m.ID]; ok && n == 1 {
+		latency = d
+	}
+	out := common.Success()
+	if n <= md.maxFailures && rng.Float64() < md.failureRate {
+		out = common.ErrorProcess(md.failDelay, "simulated failure")
+	}
+
+	start := time.Now()
+	select {
+	case <-ctx.Done():
+		return common.ErrorConnection("cancelled")
+	case <-time.After(latency):
+	}
+	a := Attempt{MessageID: m.ID, N: n, Start: start, End: time.Now(), Result: out.Result}
+	if g := m.MessageGroupID; g != nil {
+		a.Group = *g
+	}
+	md.mu.Lock()
+	md.attempts = append(md.attempts, a)
+	md.mu.Unlock()
+	return out
+}
+
+func (md *Mediator) snapshot() []Attempt {
+	md.mu.Lock()
+	defer md.mu.Unlock()
+	return append([]Attempt(nil), md.attempts...)
+}
+
+func attemptKey(messageID string, n int) uint64 {
+	h := fnv.New64a()
+	_, _ = h.Write([]byte(messageID))
+	return h.Sum64() ^ uint64(n)*0x9e3779b97f4a7c15
+}
@@ -0,0 +1,236 @@
+package routersim
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+// Scheme is the queue URI scheme of simulated queues: "sim://<name>".
+const Scheme = "sim"
+
+func init() {
+	queue.RegisterConsumer(Scheme, func(_ context.Context, cfg common.QueueConfig) (queue.Consumer, error) {
+		return lookup(cfg.URI)
+	})
+	queue.RegisterPublisher(Scheme, func(_ context.Context, cfg common.QueueConfig) (queue.Publisher, error) {
+		return lookup(cfg.URI)
+	})
+}
+
+var (
+	queuesMu sync.Mutex
+	queues   = map[string]*Queue{}
+)
+
+func lookup(uri string) (*Queue, error) {
+	name := strings.TrimPrefix(uri, Scheme+"://")
+	queuesMu.Lock()
+	defer queuesMu.Unlock()
+	q, ok := queues[name]
+	if !ok {
+		return nil, fmt.Errorf("routersim: no queue %q", name)
+	}
+	return q, nil
+}
+
+var errUnknownReceipt = errors.New("routersim: unknown receipt handle")
+
+// Queue is an in-memory queue with the Postgres backend's semantics: a
+// polled message is invisible for the visibility timeout and reappears
+// unless acked, and within a message group only the earliest visible
+// message is eligible. All timing reads time.Now, so inside a synctest
+// bubble visibility expiry follows the fake clock.
+type Queue struct {
+	name       string
+	visibility time.Duration
+
+	mu       sync.Mutex
+	seq      int
+	messages []*queued // publish order
+}
+
+type queued struct {
+	seq       int
+	msg       common.Message
+	visibleAt time.Time
+	receipt   string
+	receives  int
+}
+
+// NewQueue creates a queue and registers it so a router config entry
+// with URI "sim://<name>" consumes it. Names must be unique per process.
+func NewQueue(name string, visibility time.Duration) *Queue {
+	q := &Queue{name: name, visibility: visibility}
+	queuesMu.Lock()
+	queues[name] = q
+	queuesMu.Unlock()
+	return q
+}
+
+// Close unregisters the queue.
+func (q *Queue) Close() {
+	queuesMu.Lock()
+	delete(queues, q.name)
+	queuesMu.Unlock()
+}
+
+// Config is the router queue config for this queue.
+func (q *Queue) Config() common.QueueConfig {
+	return common.QueueConfig{
+		Name:              q.name,
+		URI:               Scheme + "://" + q.name,
+		Connections:       1,
+		VisibilityTimeout: uint32(q.visibility / time.Second),
+	}
+}
+
+// Len reports how many messages have not been acked.
+func (q *Queue) Len() int {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	return len(q.messages)
+}
+
+// Identifier returns the queue name.
+func (q *Queue) Identifier() string { return q.name }
+
+// Publish appends a message, visible at once.
+func (q *Queue) Publish(_ context.Context, m common.Message) (string, error) {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	q.seq++
+	q.messages = append(q.messages, &queued{seq: q.seq, msg: m, visibleAt: time.Now()})
+	return m.ID, nil
+}
+
+// PublishBatch publishes each message in order.
+func (q *Queue) PublishBatch(ctx context.Context, msgs []common.Message) ([]string, error) {
+	ids := make([]string, len(msgs))
+	for i, m := range msgs {
+		ids[i], _ = q.Publish(ctx, m)
+	}
+	return ids, nil
+}
+
+// Poll claims up to maxMessages visible messages, the earliest of each
+// group only.
+func (q *Queue) Poll(_ context.Context, maxMessages uint32) ([]common.QueuedMessage, error) {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	now := time.Now()
+	blocked := map[string]bool{}
+	var out []common.QueuedMessage
+	for _, m := range q.messages {
+		if uint32(len(out)) >= maxMessages {
+			break
+		}
+		if m.visibleAt.After(now) {
+			continue
+		}
+		group := m.msg.ID
+		if g := m.msg.MessageGroupID; g != nil {
+			group = *g
+		}
+		if blocked[group] {
+			continue
+		}
+		blocked[group] = true
+		m.receives++
+		m.receipt = fmt.Sprintf("%s:%d", m.msg.ID, m.receives)
+		m.visibleAt = now.Add(q.visibility)
+		out = append(out, common.QueuedMessage{
+			Message:         m.msg,
+			ReceiptHandle:   m.receipt,
+			BrokerMessageID: fmt.Sprintf("%s-%d", q.name, m.seq),
+			QueueIdentifier: q.name,
+		})
+	}
+	return out, nil
+}
+
+func (q *Queue) find(receipt string) (int, error) {
+	for i, m := range q.messages {
+		if m.receipt == receipt {
+			return i, nil
+		}
+	}
+	return 0, errUnknownReceipt
+}
+
+// Ack removes the message.
+func (q *Queue) Ack(_ context.Context, receipt string) error {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	i, err := q.find(receipt)
+	if err != nil {
+		return err
+	}
+	q.messages = append(q.messages[:i], q.messages[i+1:]...)
+	return nil
+}
+
+// Nack makes the message visible again after delaySeconds.
+func (q *Queue) Nack(_ context.Context, receipt string, delaySeconds *uint32) error {
+	var delay time.Duration
+	if delaySeconds != nil {
+		delay = time.Duration(*delaySeconds) * time.Second
+	}
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	i, err := q.find(receipt)
+	if err != nil {
+		return err
+	}
+	q.messages[i].visibleAt = time.Now().Add(delay)
+	return nil
+}
+
+// Defer is Nack.
+func (q *Queue) Defer(ctx context.Context, receipt string, delaySeconds *uint32) error {
+	return q.Nack(ctx, receipt, delaySeconds)
+}
+
+// ExtendVisibility hides the message for another seconds from now.
+func (q *Queue) ExtendVisibility(_ context.Context, receipt string, seconds uint32) error {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	i, err := q.find(receipt)
+	if err != nil {
+		return err
+	}
+	q.messages[i].visibleAt = time.Now().Add(time.Duration(seconds) * time.Second)
+	return nil
+}
+
+// Healthy is always true.
+func (q *Queue) Healthy() bool { return true }
+
+// Stop is a no-op: the queue outlives the consumers built on it, like a
+// broker outlives its connections.
+func (q *Queue) Stop() {}
+
+// Metrics reports pending and in-flight counts.
+func (q *Queue) Metrics(context.Context) (*queue.Metrics, error) {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	now := time.Now()
+	out := &queue.Metrics{QueueIdentifier: q.name}
+	for _, m := range q.messages {
+		if m.visibleAt.After(now) {
+			out.InFlightMessages++
+		} else {
+			out.PendingMessages++
+		}
+	}
+	return out, nil
+}
+
+// Counters is unsupported.
+func (q *Queue) Counters() *queue.Metrics { return nil }
@@ -0,0 +1,103 @@
+package routersim
+
+import (
+	"context"
+	"testing"
+	"testing/synctest"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+func run(t *testing.T, sc Scenario) *Result {
+	t.Helper()
+	var res *Result
+	synctest.Test(t, func(t *testing.T) {
+		var err error
+		res, err = Run(context.Background(), sc)
+		require.NoError(t, err)
+	})
+	return res
+}
+
+func TestGroupsDeliverInPublishOrder(t *testing.T) {
+	for seed := uint64(1); seed <= 20; seed++ {
+		sc := Scenario{
+			Seed: seed, Messages: 40, Groups: 4, Mode: common.DispatchNextOnError,
+			MinLatency: 10 * time.Millisecond, MaxLatency: 50 * time.Millisecond,
+			FailureRate: 0.3, MaxFailures: 3,
+		}
+		want := map[string][]string{}
+		for _, m := range sc.messages() {
+			want[*m.MessageGroupID] = append(want[*m.MessageGroupID], m.ID)
+		}
+		assert.Equal(t, want, run(t, sc).Delivered(), "seed %d", seed)
+	}
+}
+
+func TestRetryScheduleIsExponentialWithFloor(t *testing.T) {
+	for _, floor := range []int{0, 1} {
+		res := run(t, Scenario{
+			Seed: 7, Messages: 5, Mode: common.DispatchImmediate,
+			MinLatency: 20 * time.Millisecond, MaxLatency: 80 * time.Millisecond,
+			FailureRate: 1, MaxFailures: 4, FailDelaySeconds: floor,
+		})
+		last := map[string]Attempt{}
+		for _, a := range res.Attempts {
+			if prev, ok := last[a.MessageID]; ok {
+				require.Equal(t, prev.N+1, a.N)
+				want := max(100*time.Millisecond<<(prev.N-1), time.Duration(floor)*time.Second)
+				assert.Equal(t, want, a.Start.Sub(prev.End), "%s attempt %d, floor %ds", a.MessageID, a.N, floor)
+			}
+			last[a.MessageID] = a
+		}
+		require.Len(t, last, 5)
+		for id, a := range last {
+			assert.Equal(t, 5, a.N, id)
+			assert.Equal(t, common.MediationSuccess, a.Result, id)
+		}
+	}
+}
+
+func TestStalledMessageIsForceNacked(t *testing.T) {
+	stall := router.StallConfig{
+		Enabled: true, StallThresholdSeconds: 30, ForceNackStalled: true,
+		ForceNackAfterSeconds: 60, CheckInterval: 10 * time.Second,
+	}
+	res := run(t, Scenario{
+		Seed: 3, Messages: 3, Mode: common.DispatchImmediate,
+		MinLatency: 10 * time.Millisecond, MaxLatency: 10 * time.Millisecond,
+		Hang:  map[string]time.Duration{"m0001": time.Hour},
+		Stall: &stall,
+	})
+	assert.Equal(t, []string{"m0001"}, res.ForceNacked)
+
+	var hung []Attempt
+	for _, a := range res.Attempts {
+		if a.MessageID == "m0001" {
+			hung = append(hung, a)
+		}
+	}
+	require.Len(t, hung, 1, "the hung attempt never completes; only the redelivery is traced")
+	assert.Equal(t, 2, hung[0].N)
+	assert.Equal(t, common.MediationSuccess, hung[0].Result)
+	assert.Less(t, res.Elapsed, 2*time.Minute, "released after ForceNackAfterSeconds, not the visibility timeout")
+}
+
+func TestSameSeedSameTrace(t *testing.T) {
+	sc := Scenario{
+		Seed: 42, Messages: 30, Groups: 5, Mode: common.DispatchBlockOnError, Concurrency: 50,
+		MinLatency: 5 * time.Millisecond, MaxLatency: 200 * time.Millisecond,
+		FailureRate: 0.5, MaxFailures: 2,
+	}
+	a, b := run(t, sc), run(t, sc)
+	assert.Equal(t, a.Attempts, b.Attempts)
+	assert.Equal(t, a.Elapsed, b.Elapsed)
+
+	sc.Seed++
+	assert.NotEqual(t, a.Attempts, run(t, sc).Attempts)
+}
@@ -0,0 +1,188 @@
+// Package routersim runs the router's Manager, pools and stall detector
+// against an in-memory queue and a seeded fake endpoint, so properties
+// of the delivery pipeline — FIFO order within a group, the in-pipeline
+// retry schedule, force-NACK of stalled messages — can be tested without
+// a broker, a network or wall-clock waits.
+//
+// Run must be called inside a testing/synctest bubble. The bubble's fake
+// clock advances only when every goroutine is blocked, so a scenario that
+// spans hours of router time (poll pauses, backoffs, stall thresholds)
+// completes in milliseconds, and every timestamp in the trace is exact.
+//
+// Outcomes and latencies are a function of the seed, the message ID and
+// the attempt number. Goroutines that wake at the same fake instant are
+// still ordered by the Go scheduler, so a trace is reproducible as long as
+// that order cannot matter: Concurrency high enough that no delivery waits
+// for a pool slot.
+package routersim
+
+import (
+	"context"
+	"fmt"
+	"math/rand/v2"
+	"slices"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+// poolCode is the pool every simulated message is routed to.
+const poolCode = "SIM"
+
+var queueSeq atomic.Uint64
+
+// Scenario describes one simulated run.
+type Scenario struct {
+	Seed     uint64
+	Messages int
+	// Groups spreads messages over this many message groups; 0 leaves them
+	// ungrouped.
+	Groups      int
+	Mode        common.DispatchMode
+	Concurrency uint32
+	// Visibility is the queue's visibility timeout. Zero falls back to 5m.
+	Visibility time.Duration
+
+	// MinLatency and MaxLatency bound each attempt's simulated latency.
+	MinLatency time.Duration
+	MaxLatency time.Duration
+	// FailureRate is the probability an attempt fails with a retryable
+	// error, for a message's first MaxFailures attempts.
+	FailureRate      float64
+	MaxFailures      int
+	FailDelaySeconds int // delay hint carried by a failure
+	// Hang makes the first attempt of the named messages take this long.
+	Hang map[string]time.Duration
+
+	// Stall, when set, runs a StallDetector with this config whose
+	// force-NACKs go through Manager.NackInFlight.
+	Stall *router.StallConfig
+
+	// Timeout bounds the run in simulated time. Zero falls back to 1h.
+	Timeout time.Duration
+}
+
+// Result is the trace of a run.
+type Result struct {
+	// Attempts is every mediation attempt, ordered by start time, then
+	// message ID and attempt number.
+	Attempts []Attempt
+	// ForceNacked lists the message IDs the stall detector released, in
+	// order.
+	ForceNacked []string
+	// Elapsed is the simulated time until the queue drained.
+	Elapsed time.Duration
+}
+
+// Delivered returns the IDs of successfully delivered messages per group
+// (ungrouped messages under ""), in delivery order.
+func (r *Result) Delivered() map[string][]string {
+	out := map[string][]string{}
+	for _, a := range r.Attempts {
+		if a.Result == common.MediationSuccess {
+			out[a.Group] = append(out[a.Group], a.MessageID)
+		}
+	}
+	return out
+}
+
+// messages returns the messages a scenario publishes, in publish order.
+func (sc Scenario) messages() []common.Message {
+	rng := rand.New(rand.NewPCG(sc.Seed, 0))
+	msgs := make([]common.Message, sc.Messages)
+	for i := range msgs {
+		msgs[i] = common.Message{
+			ID:              fmt.Sprintf("m%04d", i),
+			PoolCode:        poolCode,
+			MediationType:   common.MediationTypeHTTP,
+			MediationTarget: "http://sim.invalid/hook",
+			DispatchMode:    sc.Mode,
+		}
+		if sc.Groups > 0 {
+			g := fmt.Sprintf("g%d", rng.IntN(sc.Groups))
+			msgs[i].MessageGroupID = &g
+		}
+	}
+	return msgs
+}
+
+// Run publishes the scenario's messages, runs a Manager over them until
+// the queue drains, and returns the trace. It errors if the queue has not
+// drained within the scenario's Timeout.
+func Run(ctx context.Context, sc Scenario) (*Result, error) {
+	if sc.Visibility == 0 {
+		sc.Visibility = 5 * time.Minute
+	}
+	if sc.Timeout == 0 {
+		sc.Timeout = time.Hour
+	}
+	if sc.Concurrency == 0 {
+		sc.Concurrency = 10
+	}
+	q := NewQueue(fmt.Sprintf("sim-%d", queueSeq.Add(1)), sc.Visibility)
+	defer q.Close()
+	if _, err := q.PublishBatch(ctx, sc.messages()); err != nil {
+		return nil, err
+	}
+
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+	med := newMediator(sc)
+	tracker := router.NewInFlightTracker()
+	m := router.NewManager(med, tracker)
+
+	var nackMu sync.Mutex
+	var forceNacked []string
+	var watch sync.WaitGroup
+	if sc.Stall != nil {
+		nack := func(ctx context.Context, queueID, receipt string, delaySeconds uint32) error {
+			if err := m.NackInFlight(ctx, queueID, receipt, delaySeconds); err != nil {
+				return err
+			}
+			nackMu.Lock()
+			forceNacked = append(forceNacked, receipt[:strings.LastIndexByte(receipt, ':')])
+			nackMu.Unlock()
+			return nil
+		}
+		d := router.NewStallDetector(*sc.Stall, tracker, nil, nack)
+		watch.Go(func() { d.Watch(ctx) })
+	}
+
+	start := time.Now()
+	err := m.Reconfigure(ctx, common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: poolCode, Concurrency: sc.Concurrency}},
+		Queues:          []common.QueueConfig{q.Config()},
+	})
+	if err == nil {
+		for q.Len() > 0 {
+			if time.Since(start) > sc.Timeout {
+				err = fmt.Errorf("routersim: %d messages left after %s", q.Len(), sc.Timeout)
+				break
+			}
+			time.Sleep(100 * time.Millisecond)
+		}
+	}
+	elapsed := time.Since(start)
+	cancel()
+	_ = m.Shutdown(context.Background())
+	watch.Wait()
+	if err != nil {
+		return nil, err
+	}
+
+	attempts := med.snapshot()
+	slices.SortFunc(attempts, func(a, b Attempt) int {
+		if c := a.Start.Compare(b.Start); c != 0 {
+			return c
+		}
+		if c := strings.Compare(a.MessageID, b.MessageID); c != 0 {
+			return c
+		}
+		return a.N - b.N
+	})
+	return &Result{Attempts: attempts, ForceNacked: forceNacked, Elapsed: elapsed}, nil
+}
@@ -0,0 +1,147 @@
+package router
+
+import (
+	"errors"
+	"fmt"
+	"net/url"
+	"path"
+	"slices"
+	"sort"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+// Content-based routing. A producer that publishes without a pool code
+// gets one from the first routing rule its message matches. The publish
+// API evaluates rules with everything the producer sent (target, event
+// type, client, payload attributes); the consume path only has the
+// target, so rules keyed on anything else never match a message that
+// arrived on a queue without a pool code.
+//
+// Rules arrive with the router config (RouterConfig.RoutingRules) and are
+// replaced whenever config sync applies a change. SetRoutingRules edits
+// them at runtime; like a pool update through the API, the edit lasts
+// until the next config change.
+
+// RoutingInput is what a message is matched on.
+type RoutingInput struct {
+	TargetHost string
+	EventType  string
+	ClientID   string
+	Attributes map[string]string
+}
+
+// RoutingInputForTarget builds an input from a mediation target URL.
+func RoutingInputForTarget(target string) RoutingInput {
+	u, err := url.Parse(target)
+	if err != nil {
+		return RoutingInput{}
+	}
+	return RoutingInput{TargetHost: u.Hostname()}
+}
+
+// RoutingRules is a validated, ordered rule set. Immutable once built.
+type RoutingRules struct {
+	rules []common.RoutingRule
+}
+
+// NewRoutingRules validates rules and orders them for evaluation: names
+// must be unique and non-empty, every rule needs a pool code, and every
+// glob must parse.
+func NewRoutingRules(rules []common.RoutingRule) (*RoutingRules, error) {
+	seen := make(map[string]struct{}, len(rules))
+	for _, r := range rules {
+		if err := ValidateRoutingRule(r); err != nil {
+			return nil, err
+		}
+		if _, dup := seen[r.Name]; dup {
+			return nil, fmt.Errorf("routing rule %q: duplicate name", r.Name)
+		}
+		seen[r.Name] = struct{}{}
+	}
+	sorted := slices.Clone(rules)
+	sort.SliceStable(sorted, func(i, j int) bool { return sorted[i].Priority < sorted[j].Priority })
+	return &RoutingRules{rules: sorted}, nil
+}
+
+// ValidateRoutingRule checks one rule in isolation.
+func ValidateRoutingRule(r common.RoutingRule) error {
+	if r.Name == "" {
+		return errors.New("routing rule: name is required")
+	}
+	if r.PoolCode == "" {
+		return fmt.Errorf("routing rule %q: poolCode is required", r.Name)
+	}
+	patterns := []string{r.TargetHost, r.EventType, r.ClientID}
+	for _, v := range r.Attributes {
+		patterns = append(patterns, v)
+	}
+	for _, p := range patterns {
+		if _, err := path.Match(p, ""); err != nil {
+			return fmt.Errorf("routing rule %q: bad pattern %q", r.Name, p)
+		}
+	}
+	return nil
+}
+
+// Rules returns the rules in evaluation order.
+func (rs *RoutingRules) Rules() []common.RoutingRule {
+	if rs == nil {
+		return nil
+	}
+	return slices.Clone(rs.rules)
+}
+
+// Match returns the first rule in matches.
+func (rs *RoutingRules) Match(in RoutingInput) (common.RoutingRule, bool) {
+	if rs == nil {
+		return common.RoutingRule{}, false
+	}
+	for _, r := range rs.rules {
+		if ruleMatches(r, in) {
+			return r, true
+		}
+	}
+	return common.RoutingRule{}, false
+}
+
+func ruleMatches(r common.RoutingRule, in RoutingInput) bool {
+	if !globMatch(r.TargetHost, in.TargetHost) || !globMatch(r.EventType, in.EventType) || !globMatch(r.ClientID, in.ClientID) {
+		return false
+	}
+	for k, want := range r.Attributes {
+		got, ok := in.Attributes[k]
+		if !ok || !globMatch(want, got) {
+			return false
+		}
+	}
+	return true
+}
+
+// globMatch treats an empty pattern as match-anything.
+func globMatch(pattern, v string) bool {
+	if pattern == "" {
+		return true
+	}
+	ok, _ := path.Match(pattern, v)
+	return ok
+}
+
+// SetRoutingRules replaces the routing rules. Invalid rules are rejected
+// and the current set kept.
+func (m *Manager) SetRoutingRules(rules []common.RoutingRule) error {
+	rs, err := NewRoutingRules(rules)
+	if err != nil {
+		return err
+	}
+	m.routing.Store(rs)
+	return nil
+}
+
+// RoutingRules returns the current rules in evaluation order.
+func (m *Manager) RoutingRules() []common.RoutingRule { return m.routing.Load().Rules() }
+
+// Route returns the first routing rule in matches.
+func (m *Manager) Route(in RoutingInput) (common.RoutingRule, bool) {
+	return m.routing.Load().Match(in)
+}
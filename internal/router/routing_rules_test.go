@@ -0,0 +1,92 @@
+package router
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+func TestRoutingRulesMatch(t *testing.T) {
+	rs, err := NewRoutingRules([]common.RoutingRule{
+		{Name: "fallback", Priority: 100, PoolCode: "BULK"},
+		{Name: "orders", EventType: "order.*", Attributes: map[string]string{"region": "eu-*"}, PoolCode: "ORDERS-EU", QueueName: "orders"},
+		{Name: "partner", TargetHost: "*.partner.example.com", PoolCode: "PARTNER"},
+		{Name: "acme", ClientID: "acme", PoolCode: "ACME"},
+	})
+	require.NoError(t, err)
+
+	match := func(in RoutingInput) string {
+		r, ok := rs.Match(in)
+		if !ok {
+			return ""
+		}
+		return r.Name
+	}
+	assert.Equal(t, "orders", match(RoutingInput{EventType: "order.created", Attributes: map[string]string{"region": "eu-west"}}))
+	assert.Equal(t, "fallback", match(RoutingInput{EventType: "order.created", Attributes: map[string]string{"region": "us-east"}}), "every predicate must hold")
+	assert.Equal(t, "fallback", match(RoutingInput{EventType: "order.created"}), "a missing attribute does not match")
+	assert.Equal(t, "partner", match(RoutingInputForTarget("https://hooks.partner.example.com/in")))
+	assert.Equal(t, "acme", match(RoutingInput{ClientID: "acme"}))
+	assert.Equal(t, "fallback", match(RoutingInput{}), "priority orders the catch-all last")
+
+	var none *RoutingRules
+	_, ok := none.Match(RoutingInput{})
+	assert.False(t, ok)
+}
+
+func TestRoutingRulesValidation(t *testing.T) {
+	for name, rules := range map[string][]common.RoutingRule{
+		"no name":     {{PoolCode: "A"}},
+		"no pool":     {{Name: "r"}},
+		"duplicate":   {{Name: "r", PoolCode: "A"}, {Name: "r", PoolCode: "B"}},
+		"bad pattern": {{Name: "r", TargetHost: "[a-", PoolCode: "A"}},
+	} {
+		_, err := NewRoutingRules(rules)
+		assert.Error(t, err, name)
+	}
+}
+
+func TestManagerRoutesUnpooledMessagesByRule(t *testing.T) {
+	med := &cascadeMediator{}
+	m := NewManager(med, nil)
+	resolve := func(string) queue.Consumer { return nil }
+	partner := NewPool(common.PoolConfig{Code: "PARTNER"}, med, nil, resolve)
+	def := NewPool(common.PoolConfig{Code: defaultPoolCode}, med, nil, resolve)
+	m.pools["PARTNER"] = partner
+	m.pools[defaultPoolCode] = def
+	require.NoError(t, m.SetRoutingRules([]common.RoutingRule{
+		{Name: "partner", TargetHost: "*.partner.example.com", PoolCode: "PARTNER"},
+		{Name: "events", EventType: "*", PoolCode: "PARTNER"},
+	}))
+
+	mk := func(poolCode, target string) common.QueuedMessage {
+		return common.QueuedMessage{Message: common.Message{ID: "x", PoolCode: poolCode, MediationTarget: target}}
+	}
+	assert.Same(t, partner, m.poolForMessage(mk("", "https://a.partner.example.com/hook")))
+	assert.Same(t, partner, m.poolForMessage(mk("NOPE", "https://a.partner.example.com/hook")), "unknown pool code is routed too")
+	assert.Same(t, def, m.poolForMessage(mk(defaultPoolCode, "https://a.partner.example.com/hook")), "a known pool code wins")
+	assert.Same(t, def, m.poolForMessage(mk("", "https://elsewhere.example.com/hook")), "event-type rules never match on the consume path")
+}
+
+func TestReconfigureReplacesRoutingRules(t *testing.T) {
+	m := NewManager(&cascadeMediator{}, nil)
+	ctx := context.Background()
+	require.NoError(t, m.Reconfigure(ctx, common.RouterConfig{
+		RoutingRules: []common.RoutingRule{{Name: "a", PoolCode: "A"}},
+	}))
+	assert.Len(t, m.RoutingRules(), 1)
+
+	require.NoError(t, m.Reconfigure(ctx, common.RouterConfig{
+		RoutingRules: []common.RoutingRule{{Name: "bad"}},
+	}))
+	assert.Equal(t, "a", m.RoutingRules()[0].Name, "invalid rules keep the previous set")
+
+	require.NoError(t, m.Reconfigure(ctx, common.RouterConfig{}))
+	assert.Empty(t, m.RoutingRules())
+	require.NoError(t, m.Shutdown(ctx))
+}
@@ -2,12 +2,15 @@ package router
 
 import (
 	"context"
+	"crypto/tls"
 	"errors"
 	"fmt"
 	"log/slog"
+	"net/netip"
 	"time"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/standby"
 )
 
@@ -52,10 +55,56 @@ type ServerConfig struct {
 	StandbyRedisURL string
 	StandbyLockKey  string
 
+	// SlowStart ramps pool concurrency and poll batch size after this
+	// instance takes over leadership, and pre-warms connections to the
+	// previous leader's busiest targets. Standby mode only.
+	SlowStart SlowStartConfig
+
+	// GroupHandoverLease is how long a new leader defers the ordered
+	// message groups the previous leader was still holding when it lost
+	// the lock. Zero disables the handover. Standby mode only.
+	GroupHandoverLease time.Duration
+
 	// Traffic management. When enabled, this instance is
 	// registered/deregistered with the ALB target group as it
 	// gains/loses leadership. Disabled by default.
 	Traffic TrafficConfig
+
+	// AttemptSink ships a record of every delivery attempt to an HTTP
+	// collector, a Kafka topic (via REST Proxy) or a file. Kind "" = off.
+	AttemptSink AttemptSinkConfig
+
+	// EgressBind pins deliveries to local IPs or interfaces on a
+	// multi-homed host (FC_ROUTER_EGRESS_BIND). Empty = OS default.
+	EgressBind []string
+
+	// Outbound is the SSRF policy for mediation targets. nil = unchecked.
+	Outbound *outbound.Policy
+
+	// TLS adds roots, pins or a minimum version to the mediation client's
+	// TLS (FC_OUTBOUND_TLS_*). Zero = Go defaults.
+	TLS outbound.TLSTrust
+
+	// Preflight caches targets that don't resolve or fail certificate
+	// verification (see TargetPreflight). Zero NegativeTTL = off.
+	Preflight PreflightConfig
+
+	// StateSnapshot saves breaker, rate limiter and in-flight state on
+	// shutdown and restores it on the next start. Target "" = off.
+	StateSnapshot StateSnapshotConfig
+
+	// Middleware runs on every delivery attempt, in order, ahead of the
+	// built-in HMAC signing (see MediationMiddleware). For deployments
+	// that embed the router; empty = signing only.
+	Middleware []MediationMiddleware
+
+	// Cancellations is looked up before each mediation; a message whose
+	// dispatch job was cancelled is ACKed undelivered. nil = no check.
+	Cancellations Cancellations
+
+	// ConfigHistory records every config sync, for GET /config/history.
+	// nil = no history.
+	ConfigHistory ConfigHistory
 }
 
 // Server is the reusable router wiring used by both cmd/fc-router (with
@@ -77,6 +126,7 @@ type Server struct {
 	BrokerStats  *CachedBrokerStats
 	ConfigSource *ConfigSource
 	Traffic      *TrafficStrategy
+	Attempts     *AttemptSink // nil when no sink is configured
 
 	election *standby.Election
 }
@@ -97,12 +147,29 @@ func NewServer(cfg ServerConfig) (*Server, error) {
 	if cfg.BreakerIdleMaxAge == 0 {
 		cfg.BreakerIdleMaxAge = time.Hour
 	}
+	if cfg.StateSnapshot.MaxAge == 0 {
+		cfg.StateSnapshot.MaxAge = 5 * time.Minute
+	}
+	if cfg.StateSnapshot.Target == "redis" && !cfg.StandbyEnabled {
+		return nil, errors.New("router state snapshot target \"redis\" requires standby mode")
+	}
+
+	egress, err := ResolveEgressAddrs(cfg.EgressBind)
+	if err != nil {
+		return nil, err
+	}
+	var tlsCfg *tls.Config
+	if !cfg.TLS.IsZero() {
+		if tlsCfg, err = cfg.TLS.ClientConfig(); err != nil {
+			return nil, fmt.Errorf("router TLS trust: %w", err)
+		}
+	}
 
 	breakers := NewBreakerRegistry(DefaultBreakerConfig())
 	s := &Server{
 		Cfg:      cfg,
 		Notifier: NewNotifier(cfg.NotifyWebhookURL, 20, 10*time.Second),
-		Mediator: pickMediator(cfg.DevMode, breakers),
+		Mediator: pickMediator(cfg.DevMode, egress, cfg.Outbound, tlsCfg, cfg.Preflight, cfg.Middleware, breakers),
 		Breakers: breakers,
 		Tracker:  NewInFlightTracker(),
 	}
@@ -110,6 +177,7 @@ func NewServer(cfg ServerConfig) (*Server, error) {
 	s.BrokerStats = NewCachedBrokerStats(s.Manager)
 	if cfg.ConfigURL != "" {
 		s.ConfigSource = NewConfigSource(cfg.ConfigURL)
+		s.ConfigSource.History = cfg.ConfigHistory
 	}
 
 	// Warning + health services back the deferred /monitoring/* and
@@ -118,13 +186,23 @@ func NewServer(cfg ServerConfig) (*Server, error) {
 	// Notifier so the webhook path stays consistent.
 	s.Warnings = NewWarningService(DefaultWarningServiceConfig())
 	s.Warnings.SetNotifier(s.Notifier)
+	attempts, err := NewAttemptSink(cfg.AttemptSink)
+	if err != nil {
+		return nil, err
+	}
+	s.Attempts = attempts
 	// Surface mediator config-error warnings (400/401/403/404, 501→Critical) on
-	// /warnings and into health. Opt-in setter avoids a constructor dependency.
+	// /warnings and into health, and record each delivery attempt to the sink.
+	// Opt-in setters avoid a constructor dependency.
 	if hm, ok := s.Mediator.(*HTTPMediator); ok {
 		hm.SetWarnings(s.Warnings)
+		if attempts != nil {
+			hm.SetAttemptSink(attempts)
+		}
 	}
 	// Surface manager routing/capacity warnings (unknown pool_code, all-pools-full).
 	s.Manager.SetWarnings(s.Warnings)
+	s.Manager.SetCancellations(cfg.Cancellations)
 	s.Health = NewHealthService(DefaultHealthServiceConfig(), s.Warnings)
 	s.Lifecycle = NewLifecycleManager(DefaultLifecycleConfig(), s.Warnings, s.Health)
 	// The Manager owns the consumer poll loops, so it is the consumer-restart
@@ -169,7 +247,7 @@ func (s *Server) Reload(ctx context.Context) error {
 		}
 		return err
 	}
-	return s.Manager.Reconfigure(ctx, *cfg)
+	return s.Manager.Apply(ctx, *cfg)
 }
 
 // IsLeader reports whether this instance currently holds the standby
@@ -181,11 +259,21 @@ func (s *Server) IsLeader() bool {
 	return s.election.IsLeader()
 }
 
+// PingStandby checks the standby Redis connection. Always nil when
+// standby is disabled.
+func (s *Server) PingStandby(ctx context.Context) error {
+	if s.election == nil {
+		return nil
+	}
+	return s.election.Ping(ctx)
+}
+
 // Run starts every subsystem and blocks until ctx is cancelled. On
 // cancellation it performs a graceful drain (up to DrainTimeout) and
 // then a full Manager + Notifier + Election shutdown.
 func (s *Server) Run(ctx context.Context) error {
 	go s.Notifier.Run(ctx)
+	s.Attempts.Start()
 	go NewStallDetector(DefaultStallConfig(), s.Tracker, s.Notifier, s.Manager.NackInFlight).Watch(ctx)
 	go NewQueueHealthMonitor(DefaultQueueHealthConfig(), s.Notifier).Watch(ctx, s.Manager.Consumers)
 	go s.reapInFlight(ctx)
@@ -214,8 +302,32 @@ func (s *Server) Run(ctx context.Context) error {
 		if err := s.election.Start(ctx); err != nil {
 			return err
 		}
-		go gateOnLeadership(ctx, s.election, s.Manager, s.Traffic, startPools)
+		hm, _ := s.Mediator.(*HTTPMediator)
+		warm := hm != nil && s.Cfg.SlowStart.WarmTargets > 0
+		if warm {
+			go s.shareWarmTargets(ctx, hm)
+		}
+		// A standby taking over ramps up from a fraction of full rate and
+		// pre-connects to the previous leader's busiest targets.
+		onLeader := func(c context.Context) {
+			if s.Cfg.GroupHandoverLease > 0 {
+				s.adoptActiveGroups(c)
+			}
+			s.restoreSnapshot(c)
+			s.Manager.StartSlowStart(c, s.Cfg.SlowStart)
+			if warm {
+				go s.prewarm(c, hm)
+			}
+			startPools(c)
+		}
+		onLoss := func(c context.Context) {
+			if s.Cfg.GroupHandoverLease > 0 {
+				s.recordActiveGroups(c)
+			}
+		}
+		go gateOnLeadership(ctx, s.election, s.Manager, s.Traffic, onLeader, onLoss)
 	} else {
+		s.restoreSnapshot(ctx)
 		startPools(ctx)
 		// Non-standby mode: still register with the ALB if traffic
 		// management is enabled (single-instance deployment).
@@ -238,6 +350,9 @@ func (s *Server) Run(ctx context.Context) error {
 
 	shutdownCtx, shutdownCancel := context.WithTimeout(context.Background(), 30*time.Second)
 	defer shutdownCancel()
+	// After the drain, before the pools stop: the snapshot reads their
+	// rate limiters and whatever the drain left in flight.
+	s.saveSnapshot(shutdownCtx)
 	// Deregister early in shutdown so the ALB stops routing new traffic
 	// before the drain finishes.
 	if err := s.Traffic.Deregister(shutdownCtx); err != nil {
@@ -255,6 +370,10 @@ func (s *Server) Run(ctx context.Context) error {
 		}
 	}
 	s.Notifier.Stop()
+	// After the manager: pools are stopped, so no further attempts arrive.
+	if err := s.Attempts.Close(shutdownCtx); err != nil {
+		slog.Warn("attempt sink close error", "err", err)
+	}
 
 	slog.Info("router stopped")
 	return nil
@@ -293,19 +412,27 @@ func (s *Server) reapInFlight(ctx context.Context) {
 	}
 }
 
-func pickMediator(devMode bool, breakers *BreakerRegistry) Mediator {
+func pickMediator(devMode bool, egress []netip.Addr, policy *outbound.Policy, tlsCfg *tls.Config, preflight PreflightConfig, middleware []MediationMiddleware, breakers *BreakerRegistry) Mediator {
+	cfg := DefaultMediatorConfig()
 	if devMode {
-		return NewHTTPMediator(DevMediatorConfig(), breakers)
+		cfg = DevMediatorConfig()
 	}
-	return NewHTTPMediator(DefaultMediatorConfig(), breakers)
+	cfg.EgressAddrs = egress
+	cfg.Outbound = policy
+	cfg.TLS = tlsCfg
+	cfg.Preflight = preflight
+	// Signing goes last so it covers any body a middleware rewrote.
+	cfg.Pipeline = NewMediationPipeline(middleware...).Use(HMACSigning{})
+	return NewHTTPMediator(cfg, breakers)
 }
 
 // gateOnLeadership starts the pool config watcher only when this
-// instance is the leader. On loss of leadership it cancels the
-// per-leadership context so pools wind down. Also drives the traffic
-// strategy: register on leader-gain, deregister on leader-loss so an
-// ALB stops routing requests to standing-by replicas.
-func gateOnLeadership(ctx context.Context, election *standby.Election, manager *Manager, traffic *TrafficStrategy, startPools func(context.Context)) {
+// instance is the leader. On loss of leadership it calls onLoss (while the
+// pools still hold their work), then cancels the per-leadership context so
+// pools wind down. Also drives the traffic strategy: register on
+// leader-gain, deregister on leader-loss so an ALB stops routing requests
+// to standing-by replicas.
+func gateOnLeadership(ctx context.Context, election *standby.Election, manager *Manager, traffic *TrafficStrategy, startPools, onLoss func(context.Context)) {
 	sub := election.Subscribe()
 	var poolCtx context.Context
 	var poolCancel context.CancelFunc
@@ -324,6 +451,7 @@ func gateOnLeadership(ctx context.Context, election *standby.Election, manager *
 			return
 		}
 		if poolCancel != nil {
+			onLoss(ctx)
 			poolCancel()
 			poolCtx, poolCancel = nil, nil
 			// Deregister BEFORE draining so the ALB stops routing new
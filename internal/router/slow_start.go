@@ -0,0 +1,145 @@
+package router
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"math"
+	"time"
+)
+
+// Slow-start curves.
+const (
+	SlowStartLinear      = "linear"
+	SlowStartExponential = "exponential"
+)
+
+// SlowStartConfig ramps a standby that has just taken over up to full
+// rate. For Duration after leader-gain, per-pool concurrency and the
+// consumer poll batch size are scaled by a factor that climbs from
+// MinFraction to 1 along Curve, so the new leader doesn't hit cold
+// connection pools (and downstream targets) with a full burst.
+type SlowStartConfig struct {
+	// Duration is the length of the ramp. Zero disables slow-start.
+	Duration time.Duration
+	// Curve is "linear" (default) or "exponential" (geometric growth
+	// from MinFraction: slow at first, fast at the end).
+	Curve string
+	// MinFraction is the share of full rate at the start of the ramp
+	// (default 0.1).
+	MinFraction float64
+	// WarmTargets is how many of the previous leader's most-used target
+	// origins to pre-connect on leader-gain. Zero disables warm-up.
+	WarmTargets int
+}
+
+// ValidateCurve rejects an unknown curve name; "" is the linear default.
+func ValidateCurve(curve string) error {
+	switch curve {
+	case "", SlowStartLinear, SlowStartExponential:
+		return nil
+	}
+	return fmt.Errorf("slow-start curve %q: want %s or %s", curve, SlowStartLinear, SlowStartExponential)
+}
+
+func (c SlowStartConfig) minFraction() float64 {
+	if c.MinFraction <= 0 || c.MinFraction > 1 {
+		return 0.1
+	}
+	return c.MinFraction
+}
+
+// Factor is the share of full rate allowed elapsed into the ramp, in
+// [MinFraction, 1]. 1 once the ramp is over (or when it's disabled).
+func (c SlowStartConfig) Factor(elapsed time.Duration) float64 {
+	if c.Duration <= 0 || elapsed >= c.Duration {
+		return 1
+	}
+	lo := c.minFraction()
+	t := 0.0
+	if elapsed > 0 {
+		t = float64(elapsed) / float64(c.Duration)
+	}
+	if c.Curve == SlowStartExponential {
+		return lo * math.Pow(1/lo, t)
+	}
+	return lo + (1-lo)*t
+}
+
+// scaled applies factor to a full-rate value, never going below 1.
+func scaled(full int, factor float64) int {
+	n := int(math.Ceil(float64(full) * factor))
+	if n < 1 {
+		return 1
+	}
+	if n > full {
+		return full
+	}
+	return n
+}
+
+// slowStartRamp is one ramp in progress.
+type slowStartRamp struct {
+	cfg     SlowStartConfig
+	started time.Time
+}
+
+func (r *slowStartRamp) factor() float64 { return r.cfg.Factor(time.Since(r.started)) }
+
+// StartSlowStart begins a ramp (a no-op when cfg.Duration is zero). Call
+// before pools start so the first polls are already throttled; the ramp
+// goroutine restores every pool to its configured concurrency when the
+// ramp ends or ctx is cancelled.
+func (m *Manager) StartSlowStart(ctx context.Context, cfg SlowStartConfig) {
+	if cfg.Duration <= 0 {
+		return
+	}
+	r := &slowStartRamp{cfg: cfg, started: time.Now()}
+	m.slowStart.Store(r)
+	slog.Info("router slow-start begun", "duration", cfg.Duration, "curve", cfg.Curve, "min_fraction", cfg.minFraction())
+	go m.runSlowStart(ctx, r)
+}
+
+func (m *Manager) runSlowStart(ctx context.Context, r *slowStartRamp) {
+	interval := r.cfg.Duration / 20
+	if interval < time.Second {
+		interval = time.Second
+	}
+	tick := time.NewTicker(interval)
+	defer tick.Stop()
+	m.throttlePools(r.factor())
+	for {
+		select {
+		case <-ctx.Done():
+		case <-tick.C:
+			if f := r.factor(); f < 1 {
+				m.throttlePools(f)
+				continue
+			}
+			slog.Info("router slow-start complete")
+		}
+		m.slowStart.CompareAndSwap(r, nil)
+		m.throttlePools(1)
+		return
+	}
+}
+
+// throttlePools caps every pool at factor × its configured concurrency.
+func (m *Manager) throttlePools(factor float64) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	for _, p := range m.pools {
+		p.throttle(uint32(scaled(int(p.Concurrency()), factor)))
+	}
+}
+
+// slowStartFactor is the current ramp factor, 1 outside a ramp.
+func (m *Manager) slowStartFactor() float64 {
+	if r := m.slowStart.Load(); r != nil {
+		return r.factor()
+	}
+	return 1
+}
+
+// pollBatch scales a consumer's poll batch by the slow-start factor.
+func (m *Manager) pollBatch(full int) int { return scaled(full, m.slowStartFactor()) }
@@ -0,0 +1,83 @@
+package router
+
+import (
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+)
+
+func TestSlowStartFactorCurves(t *testing.T) {
+	lin := SlowStartConfig{Duration: 100 * time.Second, MinFraction: 0.1}
+	assert.InDelta(t, 0.1, lin.Factor(0), 1e-9)
+	assert.InDelta(t, 0.55, lin.Factor(50*time.Second), 1e-9)
+	assert.Equal(t, 1.0, lin.Factor(100*time.Second))
+
+	exp := SlowStartConfig{Duration: 100 * time.Second, MinFraction: 0.1, Curve: SlowStartExponential}
+	assert.InDelta(t, 0.1, exp.Factor(0), 1e-9)
+	assert.Less(t, exp.Factor(50*time.Second), lin.Factor(50*time.Second), "exponential starts slower")
+	assert.Equal(t, 1.0, exp.Factor(200*time.Second))
+
+	assert.Equal(t, 1.0, SlowStartConfig{}.Factor(0), "disabled")
+	assert.InDelta(t, 0.1, SlowStartConfig{Duration: time.Minute, MinFraction: 7}.Factor(0), 1e-9, "bad fraction falls back to the default")
+
+	assert.NoError(t, ValidateCurve(""))
+	assert.Error(t, ValidateCurve("cubic"))
+}
+
+func TestSlowStartThrottlesPoolsAndPollBatch(t *testing.T) {
+	m := NewManager(&grMediator{outcome: common.Success()}, NewInFlightTracker())
+	p := NewPool(common.PoolConfig{Code: "A", Concurrency: 40}, m.mediator, m.tracker, m.resolveConsumer)
+	m.pools["A"] = p
+
+	m.slowStart.Store(&slowStartRamp{cfg: SlowStartConfig{Duration: time.Hour, MinFraction: 0.05}, started: time.Now()})
+	assert.Equal(t, 1, m.pollBatch(10))
+
+	m.throttlePools(0.1)
+	assert.Equal(t, 4, cap(p.loadSem()), "semaphore throttled to 10%")
+	assert.Equal(t, uint32(40), p.Concurrency(), "configured concurrency unchanged")
+
+	// A config reload mid-ramp raises the target the next tick throttles to.
+	p.UpdateConcurrency(80)
+	m.throttlePools(0.1)
+	assert.Equal(t, 8, cap(p.loadSem()))
+
+	m.slowStart.Store(nil)
+	m.throttlePools(1)
+	assert.Equal(t, 80, cap(p.loadSem()), "full concurrency restored")
+	assert.Equal(t, 10, m.pollBatch(10))
+}
+
+func TestWarmTargetsTopAndPrewarm(t *testing.T) {
+	var heads atomic.Int32
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.Method == http.MethodHead {
+			heads.Add(1)
+		}
+		w.WriteHeader(http.StatusMethodNotAllowed)
+	}))
+	defer srv.Close()
+
+	m := NewHTTPMediator(DevMediatorConfig(), NewBreakerRegistry(DefaultBreakerConfig()))
+	defer m.Close()
+	busy, err := HostKeyFromURL(srv.URL)
+	require.NoError(t, err)
+	quiet := HostKey{Scheme: "https", Host: "quiet.example.com", Port: 443}
+	m.targets.record(quiet)
+	for range 3 {
+		m.targets.record(busy)
+	}
+	assert.Equal(t, []HostKey{busy, quiet}, m.TopTargets(5))
+	assert.Equal(t, []HostKey{busy}, m.TopTargets(1))
+
+	assert.Equal(t, 1, m.Prewarm(context.Background(), []HostKey{busy}), "any response counts as warmed")
+	assert.Equal(t, int32(1), heads.Load())
+	assert.Equal(t, 1, m.HostPools().HostCount())
+}
@@ -0,0 +1,244 @@
+package router
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"log/slog"
+	"os"
+	"path/filepath"
+	"time"
+)
+
+// Runtime state snapshot. On shutdown the router saves what a fresh
+// process would otherwise lose — circuit breaker state machines, the
+// tokens left in each pool's rate limiter, and the messages that were
+// still in flight when the drain timed out — and the next process
+// restores it on startup (in standby mode: on leader-gain). A rolling
+// deploy then keeps breakers open on endpoints that are down instead of
+// sending every retry at them at once, and does not start each pool with
+// a full burst.
+//
+// In-flight messages cannot be resumed: their deliveries died with the
+// old process. The broker still holds them invisible until their
+// visibility deadline, so the new process releases them (NACK with no
+// delay) as soon as a consumer for their queue is running, rather than
+// leaving them to time out.
+//
+// The snapshot goes to a file or, in standby mode, to Redis beside the
+// lock (where the next leader, on another host, can read it). Snapshots
+// older than MaxAge are ignored.
+const (
+	runtimeStateKey         = "runtime-state"
+	runtimeStateRetryPeriod = time.Second
+)
+
+// StateSnapshotConfig controls runtime state snapshots.
+type StateSnapshotConfig struct {
+	// Target is "redis" (beside the standby lock; standby mode only) or a
+	// file path. Empty disables snapshots.
+	Target string
+	// MaxAge is how old a snapshot may be and still be restored. Zero
+	// falls back to 5m.
+	MaxAge time.Duration
+}
+
+// RuntimeSnapshot is the persisted router state.
+type RuntimeSnapshot struct {
+	TakenAt         time.Time                  `json:"takenAt"`
+	Breakers        map[string]BreakerSnapshot `json:"breakers,omitempty"`
+	RateLimitTokens map[string]float64         `json:"rateLimitTokens,omitempty"` // pool code → tokens
+	InFlight        []InFlightSnapshot         `json:"inFlight,omitempty"`
+}
+
+// InFlightSnapshot is the part of an in-flight message needed to release
+// it back to its queue.
+type InFlightSnapshot struct {
+	MessageID       string `json:"messageId"`
+	BrokerMessageID string `json:"brokerMessageId,omitempty"`
+	PoolCode        string `json:"poolCode"`
+	QueueIdentifier string `json:"queueIdentifier"`
+	ReceiptHandle   string `json:"receiptHandle"`
+}
+
+// RateLimitTokens returns the tokens left in each rate-limited pool's
+// bucket, keyed by pool code.
+func (m *Manager) RateLimitTokens() map[string]float64 {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	out := make(map[string]float64, len(m.pools))
+	for code, p := range m.pools {
+		if p.limiter.Rate() > 0 {
+			out[code] = p.limiter.Tokens()
+		}
+	}
+	return out
+}
+
+// TakeSnapshot captures the current runtime state.
+func (s *Server) TakeSnapshot() RuntimeSnapshot {
+	snap := RuntimeSnapshot{
+		TakenAt:         time.Now(),
+		Breakers:        s.Breakers.Export(),
+		RateLimitTokens: s.Manager.RateLimitTokens(),
+	}
+	for _, im := range s.Tracker.Snapshot() {
+		if im.ReceiptHandle == "" {
+			continue
+		}
+		snap.InFlight = append(snap.InFlight, InFlightSnapshot{
+			MessageID:       im.MessageID,
+			BrokerMessageID: im.BrokerMessageID,
+			PoolCode:        im.PoolCode,
+			QueueIdentifier: im.QueueIdentifier,
+			ReceiptHandle:   im.ReceiptHandle,
+		})
+	}
+	return snap
+}
+
+// saveSnapshot persists the runtime state. Called on shutdown after the
+// drain, while pools still exist.
+func (s *Server) saveSnapshot(ctx context.Context) {
+	if s.Cfg.StateSnapshot.Target == "" || !s.IsLeader() {
+		return
+	}
+	snap := s.TakeSnapshot()
+	b, err := json.Marshal(snap)
+	if err != nil {
+		return
+	}
+	if s.Cfg.StateSnapshot.Target == "redis" {
+		err = s.election.PutShared(ctx, runtimeStateKey, b, s.Cfg.StateSnapshot.MaxAge)
+	} else {
+		err = writeFileAtomic(s.Cfg.StateSnapshot.Target, b)
+	}
+	if err != nil {
+		slog.Warn("runtime state snapshot save failed", "err", err)
+		return
+	}
+	slog.Info("saved runtime state snapshot", "breakers", len(snap.Breakers),
+		"rate_limited_pools", len(snap.RateLimitTokens), "in_flight", len(snap.InFlight))
+}
+
+// loadSnapshot reads the previous process's snapshot. Returns nil when
+// there is none or it is older than MaxAge.
+func (s *Server) loadSnapshot(ctx context.Context) *RuntimeSnapshot {
+	var b []byte
+	var err error
+	if s.Cfg.StateSnapshot.Target == "redis" {
+		b, err = s.election.Shared(ctx, runtimeStateKey)
+	} else {
+		b, err = os.ReadFile(s.Cfg.StateSnapshot.Target)
+		if errors.Is(err, os.ErrNotExist) {
+			err = nil
+		}
+	}
+	if err != nil {
+		slog.Warn("runtime state snapshot load failed", "err", err)
+		return nil
+	}
+	if b == nil {
+		return nil
+	}
+	var snap RuntimeSnapshot
+	if err := json.Unmarshal(b, &snap); err != nil {
+		slog.Warn("runtime state snapshot decode failed", "err", err)
+		return nil
+	}
+	if age := time.Since(snap.TakenAt); age > s.Cfg.StateSnapshot.MaxAge {
+		slog.Info("ignoring stale runtime state snapshot", "age", age)
+		return nil
+	}
+	return &snap
+}
+
+// restoreSnapshot loads the previous process's snapshot and restores its
+// breakers. Rate limiter tokens and in-flight releases wait for their pool
+// or queue to start, so a goroutine retries them every second until they
+// are applied or the snapshot reaches MaxAge.
+func (s *Server) restoreSnapshot(ctx context.Context) {
+	if s.Cfg.StateSnapshot.Target == "" {
+		return
+	}
+	snap := s.loadSnapshot(ctx)
+	if snap == nil {
+		return
+	}
+	n := s.Breakers.Restore(snap.Breakers)
+	slog.Info("restoring runtime state snapshot", "taken_at", snap.TakenAt, "breakers", n,
+		"rate_limited_pools", len(snap.RateLimitTokens), "in_flight", len(snap.InFlight))
+	go s.applySnapshotPending(ctx, snap)
+}
+
+func (s *Server) applySnapshotPending(ctx context.Context, snap *RuntimeSnapshot) {
+	deadline := time.NewTimer(time.Until(snap.TakenAt.Add(s.Cfg.StateSnapshot.MaxAge)))
+	defer deadline.Stop()
+	tick := time.NewTicker(runtimeStateRetryPeriod)
+	defer tick.Stop()
+	for !s.applySnapshot(ctx, snap) {
+		select {
+		case <-ctx.Done():
+			return
+		case <-deadline.C:
+			slog.Warn("runtime state snapshot partly unapplied",
+				"pools", len(snap.RateLimitTokens), "in_flight", len(snap.InFlight))
+			return
+		case <-tick.C:
+		}
+	}
+}
+
+// applySnapshot applies what it can of snap's rate limiter tokens and
+// in-flight releases and removes them from snap. Reports whether nothing
+// is left.
+func (s *Server) applySnapshot(ctx context.Context, snap *RuntimeSnapshot) bool {
+	for code, tokens := range snap.RateLimitTokens {
+		if p := s.Manager.Pool(code); p != nil {
+			p.limiter.RestoreTokens(tokens, snap.TakenAt)
+			delete(snap.RateLimitTokens, code)
+		}
+	}
+	left := snap.InFlight[:0]
+	var noDelay uint32
+	for _, im := range snap.InFlight {
+		c := s.Manager.resolveConsumer(im.QueueIdentifier)
+		if c == nil {
+			left = append(left, im)
+			continue
+		}
+		// One attempt: a handle the broker no longer honours means the
+		// message is already visible again.
+		if err := c.Nack(ctx, im.ReceiptHandle, &noDelay); err != nil {
+			slog.Debug("release of pre-restart in-flight message failed",
+				"message_id", im.MessageID, "queue", im.QueueIdentifier, "err", err)
+			continue
+		}
+		slog.Info("released message in flight before restart",
+			"message_id", im.MessageID, "queue", im.QueueIdentifier)
+	}
+	snap.InFlight = left
+	return len(snap.RateLimitTokens) == 0 && len(snap.InFlight) == 0
+}
+
+// writeFileAtomic writes b to path via a temporary file and rename, so a
+// crash mid-write never leaves a truncated snapshot.
+func writeFileAtomic(path string, b []byte) error {
+	f, err := os.CreateTemp(filepath.Dir(path), filepath.Base(path)+".*")
+	if err != nil {
+		return err
+	}
+	defer func() { _ = os.Remove(f.Name()) }() // no-op once renamed
+	if _, err := f.Write(b); err != nil {
+		_ = f.Close()
+		return err
+	}
+	if err := f.Close(); err != nil {
+		return err
+	}
+	if err := os.Rename(f.Name(), path); err != nil {
+		return fmt.Errorf("rename snapshot: %w", err)
+	}
+	return nil
+}
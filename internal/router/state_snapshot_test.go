@@ -0,0 +1,100 @@
+package router
+
+import (
+	"context"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+func TestBreakerExportRestore(t *testing.T) {
+	cfg := DefaultBreakerConfig()
+	cfg.MinCalls = 2
+	cfg.BufferSize = 4
+	cb := NewCircuitBreaker(cfg)
+	for range 5 {
+		cb.RecordSuccess()
+	}
+	cb.RecordFailure()
+	cb.RecordFailure()
+	cb.RecordFailure()
+	require.Equal(t, CircuitOpen, cb.State())
+
+	snap := cb.Export()
+	assert.Equal(t, []bool{true, false, false, false}, snap.Window, "oldest first, newest samples only")
+
+	restored := NewCircuitBreaker(cfg)
+	restored.restore(snap)
+	assert.Equal(t, CircuitOpen, restored.State())
+	assert.Equal(t, 3, restored.Stats().RecentFailures)
+	assert.ErrorIs(t, restored.Allow(), ErrCircuitOpen, "reset timeout counts from the original failure")
+
+	reg := NewBreakerRegistry(cfg)
+	reg.Get("https://idle.example.com")
+	reg.Get("https://down.example.com").restore(snap)
+	assert.Equal(t, []string{"https://down.example.com"}, keys(reg.Export()), "untouched breakers are not exported")
+}
+
+func TestRateLimiterRestoreTokens(t *testing.T) {
+	rl := NewRateLimiter(600) // 10/s, burst 600
+	rl.RestoreTokens(3, time.Now())
+	assert.InDelta(t, 3, rl.Tokens(), 1)
+
+	rl = NewRateLimiter(600)
+	rl.RestoreTokens(0, time.Now().Add(-10*time.Second))
+	assert.InDelta(t, 100, rl.Tokens(), 1, "refills over the downtime")
+
+	unlimited := NewRateLimiter(0)
+	unlimited.RestoreTokens(0, time.Now())
+	assert.True(t, unlimited.Allow())
+}
+
+func TestRuntimeSnapshotRoundTrip(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "state.json")
+	cfg := ServerConfig{StateSnapshot: StateSnapshotConfig{Target: path}}
+	ctx := context.Background()
+
+	old, err := NewServer(cfg)
+	require.NoError(t, err)
+	resolve := func(string) queue.Consumer { return nil }
+	rpm := uint32(600)
+	old.Manager.pools["P"] = NewPool(common.PoolConfig{Code: "P", RateLimitPerMinute: &rpm}, old.Mediator, old.Tracker, resolve)
+	old.Manager.pools["P"].limiter.RestoreTokens(5, time.Now())
+	for range 10 {
+		old.Breakers.Get("https://down.example.com").RecordFailure()
+	}
+	old.Tracker.Register(&common.InFlightMessage{MessageID: "m1", QueueIdentifier: "q", ReceiptHandle: "rh-1"})
+	old.saveSnapshot(ctx)
+
+	cons := &cascadeConsumer{wantTotal: 1, done: make(chan struct{})}
+	srv, err := NewServer(cfg)
+	require.NoError(t, err)
+	snap := srv.loadSnapshot(ctx)
+	require.NotNil(t, snap)
+	assert.Equal(t, 1, srv.Breakers.Restore(snap.Breakers))
+	assert.Equal(t, CircuitOpen, srv.Breakers.Get("https://down.example.com").State())
+
+	assert.False(t, srv.applySnapshot(ctx, snap), "pool and queue not running yet")
+	srv.Manager.pools["P"] = NewPool(common.PoolConfig{Code: "P", RateLimitPerMinute: &rpm}, srv.Mediator, srv.Tracker, resolve)
+	srv.Manager.consumers["q"] = &runningConsumer{consumer: cons}
+	assert.True(t, srv.applySnapshot(ctx, snap))
+	assert.InDelta(t, 5, srv.Manager.pools["P"].limiter.Tokens(), 1)
+	assert.Equal(t, []string{"rh-1"}, cons.nacked, "in-flight message released")
+
+	srv.Cfg.StateSnapshot.MaxAge = time.Nanosecond
+	assert.Nil(t, srv.loadSnapshot(ctx), "stale snapshots are ignored")
+}
+
+func keys[V any](m map[string]V) []string {
+	out := make([]string, 0, len(m))
+	for k := range m {
+		out = append(out, k)
+	}
+	return out
+}
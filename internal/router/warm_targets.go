@@ -0,0 +1,171 @@
+package router
+
+import (
+	"context"
+	"encoding/json"
+	"io"
+	"log/slog"
+	"net/http"
+	"sort"
+	"sync"
+	"sync/atomic"
+	"time"
+)
+
+// Warm-up hand-off: while leading, the router periodically publishes
+// its most-delivered-to origins next to the standby lock; a standby
+// that takes over reads the list and opens a connection to each origin
+// before its pools start, so the first deliveries skip DNS, TCP and TLS
+// setup.
+const (
+	warmTargetsKey      = "warm-targets"
+	warmTargetsInterval = time.Minute
+	warmTargetsTTL      = time.Hour
+	prewarmTimeout      = 5 * time.Second
+)
+
+// targetTally counts deliveries per origin.
+type targetTally struct {
+	hits sync.Map // HostKey → *atomic.Uint64
+}
+
+func (t *targetTally) record(k HostKey) {
+	v, ok := t.hits.Load(k)
+	if !ok {
+		v, _ = t.hits.LoadOrStore(k, new(atomic.Uint64))
+	}
+	v.(*atomic.Uint64).Add(1)
+}
+
+// top returns up to n origins, most-delivered-to first.
+func (t *targetTally) top(n int) []HostKey {
+	type entry struct {
+		key  HostKey
+		hits uint64
+	}
+	var all []entry
+	t.hits.Range(func(k, v any) bool {
+		all = append(all, entry{k.(HostKey), v.(*atomic.Uint64).Load()})
+		return true
+	})
+	sort.Slice(all, func(i, j int) bool {
+		if all[i].hits != all[j].hits {
+			return all[i].hits > all[j].hits
+		}
+		return all[i].key.String() < all[j].key.String()
+	})
+	if len(all) > n {
+		all = all[:n]
+	}
+	out := make([]HostKey, len(all))
+	for i, e := range all {
+		out[i] = e.key
+	}
+	return out
+}
+
+// TopTargets returns up to n origins this mediator has delivered to the
+// most since startup.
+func (m *HTTPMediator) TopTargets(n int) []HostKey { return m.targets.top(n) }
+
+// Prewarm opens a connection to each origin through its host pool with
+// a HEAD / request (any response will do; the connection is what
+// counts). Origins the outbound policy refuses are skipped. Returns how
+// many origins answered.
+func (m *HTTPMediator) Prewarm(ctx context.Context, hosts []HostKey) int {
+	var wg sync.WaitGroup
+	var warmed atomic.Int32
+	for _, h := range hosts {
+		if m.cfg.Outbound != nil && m.cfg.Outbound.CheckURL(h.String()) != nil {
+			continue
+		}
+		wg.Go(func() {
+			if m.prewarmOne(ctx, h) {
+				warmed.Add(1)
+			}
+		})
+	}
+	wg.Wait()
+	return int(warmed.Load())
+}
+
+func (m *HTTPMediator) prewarmOne(ctx context.Context, h HostKey) bool {
+	ctx, cancel := context.WithTimeout(ctx, prewarmTimeout)
+	defer cancel()
+	req, err := http.NewRequestWithContext(ctx, http.MethodHead, h.String()+"/", nil)
+	if err != nil {
+		return false
+	}
+	guard := m.pools.Acquire(h)
+	defer guard.Release()
+	resp, err := guard.Client().Do(req)
+	if err != nil {
+		slog.Debug("prewarm failed", "origin", h.String(), "err", err)
+		return false
+	}
+	_, _ = io.Copy(io.Discard, resp.Body)
+	_ = resp.Body.Close()
+	return true
+}
+
+// shareWarmTargets publishes the top origins for the next leader every
+// warmTargetsInterval while this instance leads.
+func (s *Server) shareWarmTargets(ctx context.Context, hm *HTTPMediator) {
+	tick := time.NewTicker(warmTargetsInterval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-tick.C:
+		}
+		if !s.election.IsLeader() {
+			continue
+		}
+		top := hm.TopTargets(s.Cfg.SlowStart.WarmTargets)
+		if len(top) == 0 {
+			continue
+		}
+		origins := make([]string, len(top))
+		for i, k := range top {
+			origins[i] = k.String()
+		}
+		b, err := json.Marshal(origins)
+		if err != nil {
+			continue
+		}
+		if err := s.election.PutShared(ctx, warmTargetsKey, b, warmTargetsTTL); err != nil {
+			slog.Warn("warm targets publish failed", "err", err)
+		}
+	}
+}
+
+// prewarm loads the previous leader's origins and pre-connects to them.
+func (s *Server) prewarm(ctx context.Context, hm *HTTPMediator) {
+	b, err := s.election.Shared(ctx, warmTargetsKey)
+	if err != nil {
+		slog.Warn("warm targets load failed", "err", err)
+		return
+	}
+	if b == nil {
+		return
+	}
+	var origins []string
+	if err := json.Unmarshal(b, &origins); err != nil {
+		slog.Warn("warm targets decode failed", "err", err)
+		return
+	}
+	if len(origins) > s.Cfg.SlowStart.WarmTargets {
+		origins = origins[:s.Cfg.SlowStart.WarmTargets]
+	}
+	hosts := make([]HostKey, 0, len(origins))
+	for _, o := range origins {
+		if k, err := HostKeyFromURL(o); err == nil {
+			hosts = append(hosts, k)
+		}
+	}
+	started := time.Now()
+	warmed := hm.Prewarm(ctx, hosts)
+	slog.Info("router prewarmed target connections",
+		"origins", len(hosts), "warmed", warmed, "took", time.Since(started))
+}
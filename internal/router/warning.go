@@ -47,9 +47,16 @@ type WarningService struct {
 
 	mu       sync.RWMutex
 	warnings map[string]Warning
+	// incidents groups warnings by fingerprint (see incident.go);
+	// openIncidents indexes the unresolved incident per fingerprint.
+	incidents     map[string]*Incident
+	openIncidents map[string]string
 
 	notifyMu sync.RWMutex
 	notifier *Notifier
+
+	subMu sync.Mutex
+	subs  map[chan Warning]struct{}
 }
 
 // NewWarningService builds a service. Pass a zero-value Config to use defaults.
@@ -64,8 +71,10 @@ func NewWarningService(cfg WarningServiceConfig) *WarningService {
 		cfg.AutoAcknowledgeAge = cfg.MaxWarningAge
 	}
 	return &WarningService{
-		cfg:      cfg,
-		warnings: make(map[string]Warning),
+		cfg:           cfg,
+		warnings:      make(map[string]Warning),
+		incidents:     make(map[string]*Incident),
+		openIncidents: make(map[string]string),
 	}
 }
 
@@ -81,9 +90,9 @@ func (s *WarningService) SetNotifier(n *Notifier) {
 	s.notifier = n
 }
 
-// Add records a new warning and returns its id. Forwards to the
-// attached notifier (if any). Evicts the oldest 10% if the store is at
-// capacity.
+// Add records a new warning and returns its id, grouping it into the
+// matching incident. Forwards to the attached notifier (if any). Evicts
+// the oldest 10% if the store is at capacity.
 func (s *WarningService) Add(category WarningCategory, severity WarningSeverity, message, source string) string {
 	w := NewWarning(category, severity, message, source)
 
@@ -91,6 +100,7 @@ func (s *WarningService) Add(category WarningCategory, severity WarningSeverity,
 	if len(s.warnings) >= s.cfg.MaxWarnings {
 		s.evictOldestLocked()
 	}
+	s.groupLocked(&w)
 	s.warnings[w.ID] = w
 	s.mu.Unlock()
 
@@ -100,9 +110,48 @@ func (s *WarningService) Add(category WarningCategory, severity WarningSeverity,
 	if n != nil {
 		n.Add(w)
 	}
+	s.publish(w)
 	return w.ID
 }
 
+// Subscribe returns a channel receiving every warning added from now on,
+// and a cancel func that detaches and closes it. Delivery is best-effort:
+// a subscriber whose buffer is full misses warnings rather than blocking
+// Add. Backs the /warnings/stream SSE endpoint.
+func (s *WarningService) Subscribe(buffer int) (<-chan Warning, func()) {
+	if buffer <= 0 {
+		buffer = 64
+	}
+	ch := make(chan Warning, buffer)
+	s.subMu.Lock()
+	if s.subs == nil {
+		s.subs = make(map[chan Warning]struct{})
+	}
+	s.subs[ch] = struct{}{}
+	s.subMu.Unlock()
+
+	var once sync.Once
+	return ch, func() {
+		once.Do(func() {
+			s.subMu.Lock()
+			delete(s.subs, ch)
+			s.subMu.Unlock()
+			close(ch)
+		})
+	}
+}
+
+func (s *WarningService) publish(w Warning) {
+	s.subMu.Lock()
+	defer s.subMu.Unlock()
+	for ch := range s.subs {
+		select {
+		case ch <- w:
+		default:
+		}
+	}
+}
+
 // All returns a snapshot of every stored warning, in undefined order.
 func (s *WarningService) All() []Warning {
 	s.mu.RLock()
@@ -289,11 +338,13 @@ func (s *WarningService) CriticalCount() int {
 // HasCritical reports whether any unacknowledged critical warning exists.
 func (s *WarningService) HasCritical() bool { return s.CriticalCount() > 0 }
 
-// Cleanup auto-acks old warnings and drops very old ones. Idempotent;
-// call from a periodic ticker (LifecycleManager or a dedicated goroutine).
+// Cleanup auto-acks old warnings and drops very old ones, along with
+// incidents not seen for MaxWarningAge. Idempotent; call from a periodic
+// ticker (LifecycleManager or a dedicated goroutine).
 func (s *WarningService) Cleanup() {
 	s.AutoAcknowledgeOld()
 	s.ClearOlderThan(s.cfg.MaxWarningAge)
+	s.clearIncidentsOlderThan(s.cfg.MaxWarningAge)
 }
 
 // RunCleanupLoop drives Cleanup on a ticker until ctx is cancelled.
@@ -80,3 +80,28 @@ func TestWarningService_ActiveFiltersOnAcknowledgedAndAge(t *testing.T) {
 		t.Fatalf("Active: got %+v want only 'b'", active)
 	}
 }
+
+func TestWarningService_SubscribeReceivesNewWarnings(t *testing.T) {
+	s := NewWarningService(WarningServiceConfig{})
+	s.Add(WarningCategoryConnection, WarningError, "before", "t")
+
+	ch, cancel := s.Subscribe(1)
+	id := s.Add(WarningCategoryConnection, WarningError, "after", "t")
+	s.Add(WarningCategoryConnection, WarningError, "overflow", "t") // buffer full: dropped, Add must not block
+
+	select {
+	case w := <-ch:
+		if w.ID != id || w.Message != "after" {
+			t.Fatalf("Subscribe: got %+v want 'after'", w)
+		}
+	default:
+		t.Fatal("Subscribe: no warning delivered")
+	}
+
+	cancel()
+	cancel() // idempotent
+	if _, ok := <-ch; ok {
+		t.Fatal("Subscribe: channel not closed after cancel")
+	}
+	s.Add(WarningCategoryConnection, WarningError, "detached", "t")
+}
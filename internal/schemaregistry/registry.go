@@ -0,0 +1,256 @@
+// Package schemaregistry integrates event payloads with an external schema
+// registry speaking the Confluent Schema Registry REST API — Confluent
+// itself, or Apicurio through its Confluent-compatible API (point the URL
+// at …/apis/ccompat/v7).
+//
+// An event type bound to a registry subject (msg_event_types
+// .registry_subject) has each ingested event's schema id resolved against
+// that subject: a supplied id must be one of the subject's versions, and
+// an event without one is stamped with the subject's latest. In VALIDATE
+// mode the payload is also checked against the schema (validate.go). The
+// id travels with the event to its dispatch jobs, and deliveries carry it
+// with the schema's fingerprint so consumers decode with the right schema.
+//
+// Schemas by id are immutable and cached for the life of the process; a
+// subject's latest version and its membership answers are cached for
+// Config.CacheTTL.
+package schemaregistry
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+)
+
+// Schema types as the registry names them. AVRO is implied when a
+// response omits schemaType.
+const (
+	TypeAvro     = "AVRO"
+	TypeJSON     = "JSON"
+	TypeProtobuf = "PROTOBUF"
+)
+
+// ErrNotFound is a registry 404: no such schema id or subject.
+var ErrNotFound = errors.New("not found in schema registry")
+
+// Config configures a Client.
+type Config struct {
+	// URL is the registry's base URL (FC_SCHEMA_REGISTRY_URL).
+	URL string
+	// Username and Password authenticate with HTTP basic auth when
+	// Username is set.
+	Username string
+	Password string
+	// CacheTTL bounds how long a subject's latest version and subject
+	// membership answers are reused. Defaults to 5m.
+	CacheTTL time.Duration
+	// Timeout bounds each registry request. Defaults to 10s.
+	Timeout time.Duration
+}
+
+// Schema is a registered schema.
+type Schema struct {
+	ID     int64
+	Type   string
+	Schema string
+	// Fingerprint identifies the schema's content: "sha256:" and the hex
+	// SHA-256 of the schema text, compacted first when it is JSON.
+	Fingerprint string
+}
+
+// Client is a caching registry client. Safe for concurrent use.
+type Client struct {
+	cfg  Config
+	http *http.Client
+
+	mu      sync.Mutex
+	byID    map[int64]*Schema
+	latest  map[string]cachedSchema
+	members map[membership]cachedAnswer
+}
+
+type cachedSchema struct {
+	schema *Schema
+	at     time.Time
+}
+
+type membership struct {
+	subject string
+	id      int64
+}
+
+type cachedAnswer struct {
+	ok bool
+	at time.Time
+}
+
+// New returns a client for cfg.URL.
+func New(cfg Config) *Client {
+	cfg.URL = strings.TrimRight(cfg.URL, "/")
+	if cfg.CacheTTL <= 0 {
+		cfg.CacheTTL = 5 * time.Minute
+	}
+	if cfg.Timeout <= 0 {
+		cfg.Timeout = 10 * time.Second
+	}
+	return &Client{
+		cfg:     cfg,
+		http:    &http.Client{Timeout: cfg.Timeout},
+		byID:    map[int64]*Schema{},
+		latest:  map[string]cachedSchema{},
+		members: map[membership]cachedAnswer{},
+	}
+}
+
+// URL is the registry's base URL.
+func (c *Client) URL() string { return c.cfg.URL }
+
+// ByID returns the schema with id.
+func (c *Client) ByID(ctx context.Context, id int64) (*Schema, error) {
+	c.mu.Lock()
+	s, ok := c.byID[id]
+	c.mu.Unlock()
+	if ok {
+		return s, nil
+	}
+	var out struct {
+		Schema     string `json:"schema"`
+		SchemaType string `json:"schemaType"`
+	}
+	if err := c.get(ctx, "/schemas/ids/"+strconv.FormatInt(id, 10), &out); err != nil {
+		return nil, fmt.Errorf("schema %d: %w", id, err)
+	}
+	s = newSchema(id, out.SchemaType, out.Schema)
+	c.mu.Lock()
+	c.byID[id] = s
+	c.mu.Unlock()
+	return s, nil
+}
+
+// Latest returns subject's latest schema version.
+func (c *Client) Latest(ctx context.Context, subject string) (*Schema, error) {
+	c.mu.Lock()
+	cached, ok := c.latest[subject]
+	c.mu.Unlock()
+	if ok && time.Since(cached.at) < c.cfg.CacheTTL {
+		return cached.schema, nil
+	}
+	var out struct {
+		ID         int64  `json:"id"`
+		Schema     string `json:"schema"`
+		SchemaType string `json:"schemaType"`
+	}
+	if err := c.get(ctx, "/subjects/"+url.PathEscape(subject)+"/versions/latest", &out); err != nil {
+		return nil, fmt.Errorf("subject %s: %w", subject, err)
+	}
+	s := newSchema(out.ID, out.SchemaType, out.Schema)
+	c.mu.Lock()
+	c.latest[subject] = cachedSchema{schema: s, at: time.Now()}
+	c.byID[s.ID] = s
+	c.mu.Unlock()
+	return s, nil
+}
+
+// InSubject reports whether schema id is registered as a version of
+// subject.
+func (c *Client) InSubject(ctx context.Context, subject string, id int64) (bool, error) {
+	key := membership{subject: subject, id: id}
+	c.mu.Lock()
+	cached, ok := c.members[key]
+	c.mu.Unlock()
+	if ok && time.Since(cached.at) < c.cfg.CacheTTL {
+		return cached.ok, nil
+	}
+	var out []struct {
+		Subject string `json:"subject"`
+	}
+	err := c.get(ctx, "/schemas/ids/"+strconv.FormatInt(id, 10)+"/versions", &out)
+	if err != nil && !errors.Is(err, ErrNotFound) {
+		return false, fmt.Errorf("schema %d versions: %w", id, err)
+	}
+	found := false
+	for _, v := range out {
+		if v.Subject == subject {
+			found = true
+			break
+		}
+	}
+	c.mu.Lock()
+	c.members[key] = cachedAnswer{ok: found, at: time.Now()}
+	c.mu.Unlock()
+	return found, nil
+}
+
+// Ping checks the registry answers. Feeds the infrastructure health.
+func (c *Client) Ping(ctx context.Context) error {
+	var subjects []string
+	return c.get(ctx, "/subjects", &subjects)
+}
+
+func (c *Client) get(ctx context.Context, path string, out any) error {
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, c.cfg.URL+path, nil)
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Accept", "application/vnd.schemaregistry.v1+json")
+	if c.cfg.Username != "" {
+		req.SetBasicAuth(c.cfg.Username, c.cfg.Password)
+	}
+	resp, err := c.http.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode == http.StatusNotFound {
+		return ErrNotFound
+	}
+	if resp.StatusCode/100 != 2 {
+		msg, _ := io.ReadAll(io.LimitReader(resp.Body, 512))
+		return fmt.Errorf("schema registry GET %s: %s: %s", path, resp.Status, strings.TrimSpace(string(msg)))
+	}
+	return json.NewDecoder(resp.Body).Decode(out)
+}
+
+func newSchema(id int64, schemaType, text string) *Schema {
+	if schemaType == "" {
+		schemaType = TypeAvro
+	}
+	return &Schema{ID: id, Type: schemaType, Schema: text, Fingerprint: Fingerprint(text)}
+}
+
+// Fingerprint is the content fingerprint of schema text: "sha256:" and the
+// hex SHA-256 of the text, compacted first when it is JSON so whitespace
+// differences don't change it.
+func Fingerprint(text string) string {
+	b := []byte(text)
+	var buf bytes.Buffer
+	if json.Compact(&buf, b) == nil {
+		b = buf.Bytes()
+	}
+	sum := sha256.Sum256(b)
+	return "sha256:" + hex.EncodeToString(sum[:])
+}
+
+// maxID is the largest id that fits msg_events.schema_id (17 digits).
+const maxID = 99_999_999_999_999_999
+
+// ParseID parses a schema id as ingestion and deliveries carry it: a
+// positive decimal integer.
+func ParseID(s string) (int64, error) {
+	id, err := strconv.ParseInt(strings.TrimSpace(s), 10, 64)
+	if err != nil || id <= 0 || id > maxID {
+		return 0, fmt.Errorf("schema id %q must be a positive integer", s)
+	}
+	return id, nil
+}
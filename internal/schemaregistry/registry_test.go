@@ -0,0 +1,217 @@
+package schemaregistry
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"sync/atomic"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const customerSchema = `{"type":"record","name":"Customer","fields":[{"name":"email","type":"string"}]}`
+
+// fakeRegistry serves schema 7 as version 1 and schema 9 as the latest
+// version of subject "customers-value", and counts requests per path.
+func fakeRegistry(t *testing.T) (*httptest.Server, map[string]*atomic.Int32) {
+	t.Helper()
+	hits := map[string]*atomic.Int32{}
+	for _, p := range []string{"/schemas/ids/7", "/schemas/ids/9", "/schemas/ids/7/versions", "/schemas/ids/9/versions", "/subjects/customers-value/versions/latest", "/subjects"} {
+		hits[p] = &atomic.Int32{}
+	}
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if c, ok := hits[r.URL.Path]; ok {
+			c.Add(1)
+		}
+		if user, pass, ok := r.BasicAuth(); !ok || user != "key" || pass != "secret" {
+			w.WriteHeader(http.StatusUnauthorized)
+			return
+		}
+		write := func(v any) { _ = json.NewEncoder(w).Encode(v) }
+		switch r.URL.Path {
+		case "/schemas/ids/7", "/schemas/ids/9":
+			write(map[string]string{"schema": customerSchema})
+		case "/schemas/ids/7/versions":
+			write([]map[string]any{{"subject": "customers-value", "version": 1}})
+		case "/schemas/ids/9/versions":
+			write([]map[string]any{{"subject": "customers-value", "version": 2}})
+		case "/subjects/customers-value/versions/latest":
+			write(map[string]any{"id": 9, "version": 2, "schema": customerSchema})
+		case "/subjects":
+			write([]string{"customers-value"})
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = w.Write([]byte(`{"error_code":40403,"message":"Schema not found"}`))
+		}
+	}))
+	t.Cleanup(srv.Close)
+	return srv, hits
+}
+
+func newTestClient(srv *httptest.Server) *Client {
+	return New(Config{URL: srv.URL + "/", Username: "key", Password: "secret"})
+}
+
+func TestClient_CachesSchemasAndAnswers(t *testing.T) {
+	srv, hits := fakeRegistry(t)
+	c := newTestClient(srv)
+	ctx := context.Background()
+
+	s, err := c.ByID(ctx, 7)
+	require.NoError(t, err)
+	assert.Equal(t, TypeAvro, s.Type, "a response without schemaType is AVRO")
+	assert.Equal(t, Fingerprint(customerSchema), s.Fingerprint)
+	_, err = c.ByID(ctx, 7)
+	require.NoError(t, err)
+	assert.Equal(t, int32(1), hits["/schemas/ids/7"].Load(), "schemas by id are cached")
+
+	latest, err := c.Latest(ctx, "customers-value")
+	require.NoError(t, err)
+	assert.Equal(t, int64(9), latest.ID)
+	_, err = c.ByID(ctx, 9)
+	require.NoError(t, err)
+	assert.Equal(t, int32(0), hits["/schemas/ids/9"].Load(), "Latest fills the by-id cache")
+
+	ok, err := c.InSubject(ctx, "customers-value", 7)
+	require.NoError(t, err)
+	assert.True(t, ok)
+	ok, err = c.InSubject(ctx, "orders-value", 7)
+	require.NoError(t, err)
+	assert.False(t, ok)
+	ok, err = c.InSubject(ctx, "customers-value", 404)
+	require.NoError(t, err)
+	assert.False(t, ok, "an unknown id is in no subject")
+	_, err = c.InSubject(ctx, "customers-value", 7)
+	require.NoError(t, err)
+	assert.Equal(t, int32(2), hits["/schemas/ids/7/versions"].Load(), "membership is cached per subject")
+
+	_, err = c.ByID(ctx, 404)
+	assert.True(t, errors.Is(err, ErrNotFound))
+	require.NoError(t, c.Ping(ctx))
+}
+
+func TestClient_ReportsAuthFailures(t *testing.T) {
+	srv, _ := fakeRegistry(t)
+	c := New(Config{URL: srv.URL})
+	err := c.Ping(context.Background())
+	require.Error(t, err)
+	assert.False(t, errors.Is(err, ErrNotFound))
+	assert.Contains(t, err.Error(), "401")
+}
+
+func TestResolver(t *testing.T) {
+	srv, _ := fakeRegistry(t)
+	bindings := map[string]Binding{
+		"shop:crm:customer:created": {Subject: "customers-value"},
+		"shop:crm:customer:updated": {Subject: "customers-value", Validate: true},
+		"shop:crm:customer:deleted": {Subject: "missing-value"},
+	}
+	r := NewResolver(newTestClient(srv), func(context.Context) (map[string]Binding, error) { return bindings, nil }, 0)
+	ctx := context.Background()
+	id := func(s string) *string { return &s }
+	valid := json.RawMessage(`{"email":"a@example.com"}`)
+
+	cases := []struct {
+		name     string
+		code     string
+		schemaID *string
+		data     json.RawMessage
+		want     *string
+		reject   string
+	}{
+		{"unbound without id", "shop:crm:order:created", nil, valid, nil, ""},
+		{"unbound with a known id", "shop:crm:order:created", id("7"), valid, id("7"), ""},
+		{"unbound with an unknown id", "shop:crm:order:created", id("404"), valid, nil, "UNKNOWN_SCHEMA_ID"},
+		{"malformed id", "shop:crm:order:created", id("v1"), valid, nil, "INVALID_SCHEMA_ID"},
+		{"bound without id takes the latest", "shop:crm:customer:created", nil, valid, id("9"), ""},
+		{"bound with a version of the subject", "shop:crm:customer:created", id("7"), valid, id("7"), ""},
+		{"bound with an id outside the subject", "shop:crm:customer:created", id("404"), valid, nil, "SCHEMA_NOT_IN_SUBJECT"},
+		{"bound to a missing subject", "shop:crm:customer:deleted", nil, valid, nil, "REGISTRY_SUBJECT_NOT_FOUND"},
+		{"resolve mode skips validation", "shop:crm:customer:created", nil, json.RawMessage(`{}`), id("9"), ""},
+		{"validate mode accepts a match", "shop:crm:customer:updated", id("7"), valid, id("7"), ""},
+		{"validate mode rejects a mismatch", "shop:crm:customer:updated", nil, json.RawMessage(`{"email":5}`), nil, "SCHEMA_VALIDATION_FAILED"},
+		{"validate mode skips offloaded data", "shop:crm:customer:updated", nil, nil, id("9"), ""},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			got, err := r.Resolve(ctx, tc.code, tc.schemaID, tc.data)
+			if tc.reject != "" {
+				var rej *Rejection
+				require.True(t, errors.As(err, &rej), "want a rejection, got %v", err)
+				assert.Equal(t, tc.reject, rej.Code)
+				return
+			}
+			require.NoError(t, err)
+			assert.Equal(t, tc.want, got)
+		})
+	}
+}
+
+func TestResolver_WithoutRegistryOnlyChecksFormat(t *testing.T) {
+	var r *Resolver
+	got, err := r.Resolve(context.Background(), "a:b:c:d", nil, nil)
+	require.NoError(t, err)
+	assert.Nil(t, got)
+
+	s := " 42 "
+	got, err = r.Resolve(context.Background(), "a:b:c:d", &s, nil)
+	require.NoError(t, err)
+	assert.Equal(t, "42", *got)
+
+	bad := "0"
+	_, err = r.Resolve(context.Background(), "a:b:c:d", &bad, nil)
+	var rej *Rejection
+	require.True(t, errors.As(err, &rej))
+	assert.Equal(t, "INVALID_SCHEMA_ID", rej.Code)
+}
+
+func TestResolver_KeepsBindingsWhenRefreshFails(t *testing.T) {
+	srv, _ := fakeRegistry(t)
+	var fail atomic.Bool
+	load := func(context.Context) (map[string]Binding, error) {
+		if fail.Load() {
+			return nil, errors.New("db down")
+		}
+		return map[string]Binding{"a:b:c:d": {Subject: "customers-value"}}, nil
+	}
+	r := NewResolver(newTestClient(srv), load, 1)
+	ctx := context.Background()
+
+	got, err := r.Resolve(ctx, "a:b:c:d", nil, nil)
+	require.NoError(t, err)
+	assert.Equal(t, "9", *got)
+
+	fail.Store(true)
+	got, err = r.Resolve(ctx, "a:b:c:d", nil, nil)
+	require.NoError(t, err, "a failed refresh keeps the previous bindings")
+	assert.Equal(t, "9", *got)
+
+	cold := NewResolver(newTestClient(srv), load, 0)
+	_, err = cold.Resolve(ctx, "a:b:c:d", nil, nil)
+	assert.Error(t, err, "with nothing cached the load error surfaces")
+}
+
+func TestFingerprintIgnoresWhitespace(t *testing.T) {
+	compact := Fingerprint(customerSchema)
+	assert.True(t, strings.HasPrefix(compact, "sha256:"))
+	assert.Len(t, compact, len("sha256:")+64)
+	assert.Equal(t, compact, Fingerprint("{\n  \"type\": \"record\", \"name\": \"Customer\",\n  \"fields\": [{\"name\": \"email\", \"type\": \"string\"}]\n}"))
+	assert.NotEqual(t, compact, Fingerprint(`{"type":"string"}`))
+	assert.NotEqual(t, Fingerprint("syntax = \"proto3\";"), Fingerprint("syntax  =  \"proto3\";"), "non-JSON text is hashed as is")
+}
+
+func TestParseID(t *testing.T) {
+	for _, ok := range []string{"1", " 42 ", "99999999999999999"} {
+		_, err := ParseID(ok)
+		assert.NoError(t, err, ok)
+	}
+	for _, bad := range []string{"", "0", "-3", "1.5", "abc", "100000000000000000"} {
+		_, err := ParseID(bad)
+		assert.Error(t, err, bad)
+	}
+}
@@ -0,0 +1,158 @@
+package schemaregistry
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"strconv"
+	"sync"
+	"time"
+)
+
+// Binding is an event type's registry subject, and whether its payloads
+// are validated against the schema as well as resolved.
+type Binding struct {
+	Subject  string
+	Validate bool
+}
+
+// Rejection is an event ingestion refuses because of its schema id or
+// payload: the producer's error, not the registry's.
+type Rejection struct {
+	Code    string
+	Message string
+}
+
+func (r *Rejection) Error() string { return r.Message }
+
+// Resolver settles the schema id each ingested event is stored with. It
+// caches the event type bindings like redact.Rules caches redaction rules.
+// A nil *Resolver, or one without a client, only checks the format of a
+// supplied id, so callers can hold one unconditionally.
+type Resolver struct {
+	client *Client
+	load   func(context.Context) (map[string]Binding, error)
+	ttl    time.Duration
+
+	mu       sync.Mutex
+	byCode   map[string]Binding
+	loadedAt time.Time
+}
+
+// NewResolver wires a resolver over client (nil when no registry is
+// configured) and load (typically eventtype.Repository.RegistryBindings,
+// adapted). ttl <= 0 means 30s.
+func NewResolver(client *Client, load func(context.Context) (map[string]Binding, error), ttl time.Duration) *Resolver {
+	if ttl <= 0 {
+		ttl = 30 * time.Second
+	}
+	return &Resolver{client: client, load: load, ttl: ttl}
+}
+
+// Client is the registry client; nil when none is configured.
+func (r *Resolver) Client() *Client {
+	if r == nil {
+		return nil
+	}
+	return r.client
+}
+
+// Resolve returns the schema id to store with an event of type code whose
+// producer supplied schemaID (nil for none) and payload data.
+//
+// Unbound event types keep a supplied id once the registry knows it. A
+// bound event type's supplied id must be a version of its subject; without
+// one the subject's latest is used. VALIDATE bindings also check data
+// against the schema; nil data (a payload offloaded before ingestion)
+// skips the check. Producer mistakes come back as *Rejection; anything
+// else is the registry failing.
+func (r *Resolver) Resolve(ctx context.Context, code string, schemaID *string, data json.RawMessage) (*string, error) {
+	var id int64
+	if schemaID != nil {
+		var err error
+		if id, err = ParseID(*schemaID); err != nil {
+			return nil, &Rejection{Code: "INVALID_SCHEMA_ID", Message: err.Error()}
+		}
+	}
+	if r == nil || r.client == nil {
+		return formatID(id), nil
+	}
+	b, bound, err := r.binding(ctx, code)
+	if err != nil {
+		return nil, err
+	}
+	if !bound {
+		if schemaID == nil {
+			return nil, nil
+		}
+		if _, err := r.client.ByID(ctx, id); err != nil {
+			return nil, unknownID(id, err)
+		}
+		return formatID(id), nil
+	}
+
+	var schema *Schema
+	if schemaID == nil {
+		if schema, err = r.client.Latest(ctx, b.Subject); err != nil {
+			if errors.Is(err, ErrNotFound) {
+				return nil, &Rejection{Code: "REGISTRY_SUBJECT_NOT_FOUND", Message: "schema registry subject " + b.Subject + " has no versions"}
+			}
+			return nil, err
+		}
+		id = schema.ID
+	} else {
+		ok, err := r.client.InSubject(ctx, b.Subject, id)
+		if err != nil {
+			return nil, err
+		}
+		if !ok {
+			return nil, &Rejection{Code: "SCHEMA_NOT_IN_SUBJECT", Message: "schema " + strconv.FormatInt(id, 10) + " is not a version of subject " + b.Subject}
+		}
+	}
+	if b.Validate && data != nil {
+		if schema == nil {
+			if schema, err = r.client.ByID(ctx, id); err != nil {
+				return nil, unknownID(id, err)
+			}
+		}
+		if err := Validate(schema, data); err != nil {
+			return nil, &Rejection{Code: "SCHEMA_VALIDATION_FAILED", Message: "data does not match schema " + strconv.FormatInt(id, 10) + ": " + err.Error()}
+		}
+	}
+	return formatID(id), nil
+}
+
+// binding returns code's binding. A failed refresh keeps serving the
+// previous set; with nothing cached the error is returned.
+func (r *Resolver) binding(ctx context.Context, code string) (Binding, bool, error) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	if r.byCode == nil || time.Since(r.loadedAt) > r.ttl {
+		m, err := r.load(ctx)
+		if err != nil {
+			if r.byCode == nil {
+				return Binding{}, false, err
+			}
+		} else {
+			r.byCode = m
+			r.loadedAt = time.Now()
+		}
+	}
+	b, ok := r.byCode[code]
+	return b, ok, nil
+}
+
+func unknownID(id int64, err error) error {
+	if errors.Is(err, ErrNotFound) {
+		return &Rejection{Code: "UNKNOWN_SCHEMA_ID", Message: "schema " + strconv.FormatInt(id, 10) + " is not in the schema registry"}
+	}
+	return err
+}
+
+func formatID(id int64) *string {
+	if id == 0 {
+		return nil
+	}
+	s := strconv.FormatInt(id, 10)
+	return &s
+}
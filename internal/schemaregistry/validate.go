@@ -0,0 +1,499 @@
+package schemaregistry
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"math"
+	"reflect"
+	"regexp"
+	"strconv"
+	"strings"
+	"unicode/utf8"
+)
+
+// Validate checks data, an event payload, against s.
+//
+// AVRO schemas are checked against the payload's JSON shape: records are
+// objects whose fields must be present (unless they have a default or
+// admit null) and match their type, unions take a plain value matching
+// any branch, and fields the schema doesn't name are ignored, as an Avro
+// reader would. JSON schemas are checked for the structural keywords —
+// type, properties, required, additionalProperties, items, enum, const,
+// allOf/anyOf/oneOf, local $ref, length, range and pattern; formats and
+// other keywords are ignored. PROTOBUF schemas are not validated: the id
+// is still resolved and delivered.
+func Validate(s *Schema, data json.RawMessage) error {
+	if s.Type == TypeProtobuf {
+		return nil
+	}
+	value, err := decode(data)
+	if err != nil {
+		return fmt.Errorf("payload is not JSON: %w", err)
+	}
+	var schema any
+	if err := json.Unmarshal([]byte(s.Schema), &schema); err != nil {
+		return fmt.Errorf("schema %d is not JSON: %w", s.ID, err)
+	}
+	switch s.Type {
+	case TypeJSON:
+		return (&jsonValidator{root: schema}).check(schema, value, "$")
+	default:
+		v := &avroValidator{named: map[string]any{}}
+		v.collect(schema, "")
+		return v.check(schema, value, "$", "")
+	}
+}
+
+func decode(data []byte) (any, error) {
+	dec := json.NewDecoder(bytes.NewReader(data))
+	dec.UseNumber()
+	var v any
+	if err := dec.Decode(&v); err != nil {
+		return nil, err
+	}
+	return v, nil
+}
+
+// ── Avro ─────────────────────────────────────────────────────────────────
+
+type avroValidator struct {
+	// named holds the schema's named types (record, enum, fixed) by full
+	// name and by short name.
+	named map[string]any
+}
+
+func (v *avroValidator) check(schema, value any, path, namespace string) error {
+	switch s := schema.(type) {
+	case string:
+		return v.primitive(s, value, path, namespace)
+	case []any:
+		for _, branch := range s {
+			if v.check(branch, value, path, namespace) == nil {
+				return nil
+			}
+		}
+		return fmt.Errorf("%s: matches no branch of the union", path)
+	case map[string]any:
+		return v.complex(s, value, path, namespace)
+	}
+	return fmt.Errorf("%s: unsupported schema", path)
+}
+
+func (v *avroValidator) primitive(name string, value any, path, namespace string) error {
+	ok := false
+	switch name {
+	case "null":
+		ok = value == nil
+	case "boolean":
+		_, ok = value.(bool)
+	case "int":
+		ok = isInteger(value, math.MinInt32, math.MaxInt32)
+	case "long":
+		ok = isInteger(value, math.MinInt64, math.MaxInt64)
+	case "float", "double":
+		_, ok = value.(json.Number)
+	case "string", "bytes":
+		_, ok = value.(string)
+	default:
+		def, found := v.named[name]
+		if !found && namespace != "" {
+			def, found = v.named[namespace+"."+name]
+		}
+		if !found {
+			return fmt.Errorf("%s: unknown type %q", path, name)
+		}
+		return v.check(def, value, path, namespace)
+	}
+	if !ok {
+		return fmt.Errorf("%s: expected %s", path, name)
+	}
+	return nil
+}
+
+func (v *avroValidator) complex(s map[string]any, value any, path, namespace string) error {
+	typ, _ := s["type"].(string)
+	switch typ {
+	case "record", "error", "enum", "fixed":
+		namespace = v.define(s, namespace)
+	}
+	switch typ {
+	case "record", "error":
+		obj, ok := value.(map[string]any)
+		if !ok {
+			return fmt.Errorf("%s: expected a record object", path)
+		}
+		fields, _ := s["fields"].([]any)
+		for _, f := range fields {
+			field, _ := f.(map[string]any)
+			name, _ := field["name"].(string)
+			fv, present := obj[name]
+			if !present {
+				if _, hasDefault := field["default"]; hasDefault || v.check(field["type"], nil, path, namespace) == nil {
+					continue
+				}
+				return fmt.Errorf("%s: missing field %q", path, name)
+			}
+			if err := v.check(field["type"], fv, path+"."+name, namespace); err != nil {
+				return err
+			}
+		}
+		return nil
+	case "enum":
+		str, ok := value.(string)
+		if ok {
+			symbols, _ := s["symbols"].([]any)
+			for _, sym := range symbols {
+				if sym == str {
+					return nil
+				}
+			}
+		}
+		return fmt.Errorf("%s: expected one of the enum's symbols", path)
+	case "array":
+		arr, ok := value.([]any)
+		if !ok {
+			return fmt.Errorf("%s: expected an array", path)
+		}
+		for i, item := range arr {
+			if err := v.check(s["items"], item, path+"["+strconv.Itoa(i)+"]", namespace); err != nil {
+				return err
+			}
+		}
+		return nil
+	case "map":
+		obj, ok := value.(map[string]any)
+		if !ok {
+			return fmt.Errorf("%s: expected a map object", path)
+		}
+		for k, item := range obj {
+			if err := v.check(s["values"], item, path+"."+k, namespace); err != nil {
+				return err
+			}
+		}
+		return nil
+	case "fixed":
+		if _, ok := value.(string); !ok {
+			return fmt.Errorf("%s: expected a fixed string", path)
+		}
+		return nil
+	}
+	// {"type": "string", "logicalType": …} and the like.
+	return v.check(s["type"], value, path, namespace)
+}
+
+// collect defines every named type up front, so a reference resolves even
+// when the payload skips the field that defines it.
+func (v *avroValidator) collect(schema any, namespace string) {
+	switch s := schema.(type) {
+	case []any:
+		for _, branch := range s {
+			v.collect(branch, namespace)
+		}
+	case map[string]any:
+		typ, _ := s["type"].(string)
+		switch typ {
+		case "record", "error":
+			ns := v.define(s, namespace)
+			fields, _ := s["fields"].([]any)
+			for _, f := range fields {
+				if field, ok := f.(map[string]any); ok {
+					v.collect(field["type"], ns)
+				}
+			}
+		case "enum", "fixed":
+			v.define(s, namespace)
+		case "array":
+			v.collect(s["items"], namespace)
+		case "map":
+			v.collect(s["values"], namespace)
+		default:
+			v.collect(s["type"], namespace)
+		}
+	}
+}
+
+// define records a named type and returns the namespace its fields
+// resolve names in.
+func (v *avroValidator) define(s map[string]any, namespace string) string {
+	name, _ := s["name"].(string)
+	if ns, ok := s["namespace"].(string); ok {
+		namespace = ns
+	}
+	full := name
+	if i := strings.LastIndex(name, "."); i >= 0 {
+		namespace = name[:i]
+		name = name[i+1:]
+	} else if namespace != "" {
+		full = namespace + "." + name
+	}
+	v.named[full] = s
+	v.named[name] = s
+	return namespace
+}
+
+func isInteger(value any, lo, hi int64) bool {
+	n, ok := value.(json.Number)
+	if !ok {
+		return false
+	}
+	i, err := n.Int64()
+	return err == nil && i >= lo && i <= hi
+}
+
+// ── JSON Schema ──────────────────────────────────────────────────────────
+
+type jsonValidator struct {
+	root any
+}
+
+func (v *jsonValidator) check(schema, value any, path string) error {
+	switch s := schema.(type) {
+	case bool:
+		if !s {
+			return fmt.Errorf("%s: not allowed", path)
+		}
+		return nil
+	case map[string]any:
+		return v.object(s, value, path)
+	}
+	return nil
+}
+
+func (v *jsonValidator) object(s map[string]any, value any, path string) error {
+	if ref, ok := s["$ref"].(string); ok {
+		target, err := v.resolve(ref)
+		if err != nil {
+			return fmt.Errorf("%s: %w", path, err)
+		}
+		if err := v.check(target, value, path); err != nil {
+			return err
+		}
+	}
+	if t, ok := s["type"]; ok && !typeMatches(t, value) {
+		return fmt.Errorf("%s: expected %v", path, t)
+	}
+	if enum, ok := s["enum"].([]any); ok {
+		found := false
+		for _, e := range enum {
+			if jsonEqual(e, value) {
+				found = true
+				break
+			}
+		}
+		if !found {
+			return fmt.Errorf("%s: not one of the enum's values", path)
+		}
+	}
+	if c, ok := s["const"]; ok && !jsonEqual(c, value) {
+		return fmt.Errorf("%s: must equal the const value", path)
+	}
+	if err := v.combinators(s, value, path); err != nil {
+		return err
+	}
+	switch val := value.(type) {
+	case map[string]any:
+		return v.properties(s, val, path)
+	case []any:
+		if items, ok := s["items"]; ok {
+			for i, item := range val {
+				if err := v.check(items, item, path+"["+strconv.Itoa(i)+"]"); err != nil {
+					return err
+				}
+			}
+		}
+	case string:
+		n := float64(utf8.RuneCountInString(val))
+		if lo, ok := number(s["minLength"]); ok && n < lo {
+			return fmt.Errorf("%s: shorter than %v", path, s["minLength"])
+		}
+		if hi, ok := number(s["maxLength"]); ok && n > hi {
+			return fmt.Errorf("%s: longer than %v", path, s["maxLength"])
+		}
+		if p, ok := s["pattern"].(string); ok {
+			re, err := regexp.Compile(p)
+			if err == nil && !re.MatchString(val) {
+				return fmt.Errorf("%s: does not match %q", path, p)
+			}
+		}
+	case json.Number:
+		f, _ := val.Float64()
+		if lo, ok := number(s["minimum"]); ok && f < lo {
+			return fmt.Errorf("%s: below %v", path, s["minimum"])
+		}
+		if hi, ok := number(s["maximum"]); ok && f > hi {
+			return fmt.Errorf("%s: above %v", path, s["maximum"])
+		}
+	}
+	return nil
+}
+
+func (v *jsonValidator) combinators(s map[string]any, value any, path string) error {
+	if all, ok := s["allOf"].([]any); ok {
+		for _, sub := range all {
+			if err := v.check(sub, value, path); err != nil {
+				return err
+			}
+		}
+	}
+	if anyOf, ok := s["anyOf"].([]any); ok {
+		matched := false
+		for _, sub := range anyOf {
+			if v.check(sub, value, path) == nil {
+				matched = true
+				break
+			}
+		}
+		if !matched {
+			return fmt.Errorf("%s: matches none of anyOf", path)
+		}
+	}
+	if oneOf, ok := s["oneOf"].([]any); ok {
+		matched := 0
+		for _, sub := range oneOf {
+			if v.check(sub, value, path) == nil {
+				matched++
+			}
+		}
+		if matched != 1 {
+			return fmt.Errorf("%s: matches %d of oneOf, want exactly 1", path, matched)
+		}
+	}
+	return nil
+}
+
+func (v *jsonValidator) properties(s map[string]any, obj map[string]any, path string) error {
+	if required, ok := s["required"].([]any); ok {
+		for _, r := range required {
+			name, _ := r.(string)
+			if _, present := obj[name]; !present {
+				return fmt.Errorf("%s: missing property %q", path, name)
+			}
+		}
+	}
+	props, _ := s["properties"].(map[string]any)
+	for name, pv := range obj {
+		if sub, ok := props[name]; ok {
+			if err := v.check(sub, pv, path+"."+name); err != nil {
+				return err
+			}
+			continue
+		}
+		if extra, ok := s["additionalProperties"]; ok {
+			if err := v.check(extra, pv, path+"."+name); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
+}
+
+// resolve follows a local JSON pointer ("#/$defs/address").
+func (v *jsonValidator) resolve(ref string) (any, error) {
+	if ref == "#" {
+		return v.root, nil
+	}
+	if !strings.HasPrefix(ref, "#/") {
+		return nil, fmt.Errorf("only local $ref is supported, got %q", ref)
+	}
+	node := v.root
+	for _, part := range strings.Split(ref[2:], "/") {
+		part = strings.ReplaceAll(strings.ReplaceAll(part, "~1", "/"), "~0", "~")
+		m, ok := node.(map[string]any)
+		if !ok {
+			return nil, fmt.Errorf("unresolvable $ref %q", ref)
+		}
+		if node, ok = m[part]; !ok {
+			return nil, fmt.Errorf("unresolvable $ref %q", ref)
+		}
+	}
+	return node, nil
+}
+
+func typeMatches(t, value any) bool {
+	switch tt := t.(type) {
+	case string:
+		return isJSONType(tt, value)
+	case []any:
+		for _, x := range tt {
+			if name, ok := x.(string); ok && isJSONType(name, value) {
+				return true
+			}
+		}
+		return false
+	}
+	return true
+}
+
+func isJSONType(name string, value any) bool {
+	switch name {
+	case "null":
+		return value == nil
+	case "boolean":
+		_, ok := value.(bool)
+		return ok
+	case "string":
+		_, ok := value.(string)
+		return ok
+	case "number":
+		_, ok := value.(json.Number)
+		return ok
+	case "integer":
+		n, ok := value.(json.Number)
+		if !ok {
+			return false
+		}
+		f, err := n.Float64()
+		return err == nil && f == math.Trunc(f)
+	case "array":
+		_, ok := value.([]any)
+		return ok
+	case "object":
+		_, ok := value.(map[string]any)
+		return ok
+	}
+	return false
+}
+
+// number reads a numeric schema keyword; the schema is decoded without
+// UseNumber, so numbers arrive as float64.
+func number(v any) (float64, bool) {
+	f, ok := v.(float64)
+	return f, ok
+}
+
+// jsonEqual compares a schema value (numbers as float64) with a payload
+// value (numbers as json.Number).
+func jsonEqual(schema, value any) bool {
+	if n, ok := value.(json.Number); ok {
+		f, err := n.Float64()
+		sf, isNum := schema.(float64)
+		return err == nil && isNum && f == sf
+	}
+	switch sv := schema.(type) {
+	case []any:
+		arr, ok := value.([]any)
+		if !ok || len(arr) != len(sv) {
+			return false
+		}
+		for i := range sv {
+			if !jsonEqual(sv[i], arr[i]) {
+				return false
+			}
+		}
+		return true
+	case map[string]any:
+		obj, ok := value.(map[string]any)
+		if !ok || len(obj) != len(sv) {
+			return false
+		}
+		for k, x := range sv {
+			ov, present := obj[k]
+			if !present || !jsonEqual(x, ov) {
+				return false
+			}
+		}
+		return true
+	}
+	return reflect.DeepEqual(schema, value)
+}
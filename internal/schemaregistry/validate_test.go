@@ -0,0 +1,90 @@
+package schemaregistry
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+const avroOrder = `{
+  "type": "record", "name": "Order", "namespace": "shop",
+  "fields": [
+    {"name": "id", "type": "string"},
+    {"name": "quantity", "type": "int"},
+    {"name": "status", "type": {"type": "enum", "name": "Status", "symbols": ["NEW", "SHIPPED"]}},
+    {"name": "note", "type": ["null", "string"]},
+    {"name": "lines", "type": {"type": "array", "items": {"type": "record", "name": "Line", "fields": [
+      {"name": "sku", "type": "string"},
+      {"name": "price", "type": "double"}
+    ]}}, "default": []},
+    {"name": "previous", "type": ["null", "Status"], "default": null}
+  ]
+}`
+
+const jsonOrder = `{
+  "type": "object",
+  "required": ["id", "quantity"],
+  "properties": {
+    "id": {"type": "string", "pattern": "^o-[0-9]+$"},
+    "quantity": {"type": "integer", "minimum": 1},
+    "status": {"enum": ["NEW", "SHIPPED"]},
+    "address": {"$ref": "#/$defs/address"}
+  },
+  "additionalProperties": false,
+  "$defs": {"address": {"type": "object", "required": ["city"], "properties": {"city": {"type": "string", "minLength": 1}}}}
+}`
+
+func TestValidate(t *testing.T) {
+	avro := &Schema{ID: 1, Type: TypeAvro, Schema: avroOrder}
+	jsonSchema := &Schema{ID: 2, Type: TypeJSON, Schema: jsonOrder}
+	cases := []struct {
+		name   string
+		schema *Schema
+		data   string
+		ok     bool
+	}{
+		{"avro valid", avro, `{"id":"o-1","quantity":2,"status":"NEW","note":null,"lines":[{"sku":"X","price":1.5}]}`, true},
+		{"avro optional fields absent", avro, `{"id":"o-1","quantity":2,"status":"NEW"}`, true},
+		{"avro named type reference", avro, `{"id":"o-1","quantity":2,"status":"NEW","previous":"SHIPPED"}`, true},
+		{"avro unknown fields ignored", avro, `{"id":"o-1","quantity":2,"status":"NEW","extra":true}`, true},
+		{"avro missing required field", avro, `{"id":"o-1","status":"NEW"}`, false},
+		{"avro int out of range", avro, `{"id":"o-1","quantity":3000000000,"status":"NEW"}`, false},
+		{"avro bad enum symbol", avro, `{"id":"o-1","quantity":2,"status":"LOST"}`, false},
+		{"avro union mismatch", avro, `{"id":"o-1","quantity":2,"status":"NEW","note":5}`, false},
+		{"avro nested record mismatch", avro, `{"id":"o-1","quantity":2,"status":"NEW","lines":[{"sku":"X"}]}`, false},
+		{"json valid", jsonSchema, `{"id":"o-12","quantity":1,"status":"SHIPPED","address":{"city":"Oslo"}}`, true},
+		{"json missing required", jsonSchema, `{"id":"o-12"}`, false},
+		{"json additional property", jsonSchema, `{"id":"o-12","quantity":1,"colour":"red"}`, false},
+		{"json pattern", jsonSchema, `{"id":"x","quantity":1}`, false},
+		{"json minimum", jsonSchema, `{"id":"o-1","quantity":0}`, false},
+		{"json integer", jsonSchema, `{"id":"o-1","quantity":1.5}`, false},
+		{"json enum", jsonSchema, `{"id":"o-1","quantity":1,"status":"LOST"}`, false},
+		{"json $ref", jsonSchema, `{"id":"o-1","quantity":1,"address":{"city":""}}`, false},
+		{"protobuf skipped", &Schema{ID: 3, Type: TypeProtobuf, Schema: `syntax = "proto3";`}, `{}`, true},
+		{"payload not JSON", jsonSchema, `{`, false},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			err := Validate(tc.schema, json.RawMessage(tc.data))
+			if tc.ok {
+				assert.NoError(t, err)
+			} else {
+				assert.Error(t, err)
+			}
+		})
+	}
+}
+
+func TestValidate_JSONCombinatorsAndConst(t *testing.T) {
+	s := &Schema{ID: 4, Type: TypeJSON, Schema: `{
+	  "oneOf": [
+	    {"type": "object", "required": ["kind"], "properties": {"kind": {"const": "card"}, "last4": {"type": "string", "maxLength": 4}}},
+	    {"type": "object", "required": ["kind"], "properties": {"kind": {"const": "bank"}}}
+	  ]
+	}`}
+	assert.NoError(t, Validate(s, json.RawMessage(`{"kind":"card","last4":"4242"}`)))
+	assert.NoError(t, Validate(s, json.RawMessage(`{"kind":"bank"}`)))
+	assert.Error(t, Validate(s, json.RawMessage(`{"kind":"cash"}`)))
+	assert.Error(t, Validate(s, json.RawMessage(`{"kind":"card","last4":"42424"}`)))
+}
@@ -94,6 +94,23 @@ func (s *Service) Validate(ctx context.Context, ref string) error {
 	return nil
 }
 
+// referenceSchemes are the schemes IsReference recognises — the backends
+// listed on Service, whether or not this process registered them.
+var referenceSchemes = []string{"env://", "aws-sm://", "aws-ps://", "vault://", "encrypted:"}
+
+// IsReference reports whether v names a secret rather than carrying a
+// value. Unlike Resolve, which treats any "scheme://" as a reference, only
+// the known backend schemes count, so an ordinary value that happens to
+// contain a URL ("https://...") is not mistaken for one.
+func IsReference(v string) bool {
+	for _, s := range referenceSchemes {
+		if strings.HasPrefix(v, s) {
+			return true
+		}
+	}
+	return false
+}
+
 // parseRef extracts the scheme + key from a reference. Returns
 // isRef=false for bare literals.
 //
@@ -40,6 +40,15 @@ func TestServiceMissingProvider(t *testing.T) {
 	assert.Contains(t, err.Error(), "aws-sm")
 }
 
+func TestIsReference(t *testing.T) {
+	for _, ref := range []string{"env://TOKEN", "aws-sm://prod/token", "vault://kv/app#token", "encrypted:token"} {
+		assert.True(t, secrets.IsReference(ref), ref)
+	}
+	for _, v := range []string{"tenant-42", "https://example.com/callback", "literal:env://X", ""} {
+		assert.False(t, secrets.IsReference(v), v)
+	}
+}
+
 func TestEnvProviderNotFound(t *testing.T) {
 	p := secrets.NewEnvProvider()
 	_, err := p.Get(context.Background(), "DOES_NOT_EXIST_XYZ")
@@ -0,0 +1,47 @@
+package server
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive"
+)
+
+// startArchiver runs the event archiver and the archive replayer until
+// ctx is cancelled; a no-op when FC_ARCHIVE_STORE_URI is unset. Only the
+// elected leader archives or replays, so each stream's cursor and each
+// replay has one writer.
+func startArchiver(ctx context.Context, cfg EnvCfg, repos *repoSet, svcs *serviceSet) error {
+	if cfg.ArchiveStoreURI == "" {
+		return nil
+	}
+	store, err := blobstore.Open(ctx, cfg.ArchiveStoreURI)
+	if err != nil {
+		return fmt.Errorf("FC_ARCHIVE_STORE_URI: %w", err)
+	}
+	a := archive.NewArchiver(archive.Deps{
+		Repo:   repos.archiveRepo,
+		Events: repos.eventRepo,
+		Jobs:   repos.dispatchJobRepo,
+		Store:  store,
+		Blobs:  svcs.blobs,
+	}, archive.Config{
+		Interval: time.Duration(cfg.ArchiveIntervalSecs) * time.Second,
+		Settle:   time.Duration(cfg.ArchiveSettleSecs) * time.Second,
+	})
+	a.IsLeader = leaderGate(ctx, cfg, "archive")
+	go a.Run(ctx)
+
+	rp := archive.NewReplayer(archive.ReplayDeps{
+		Repo:          repos.archiveRepo,
+		Events:        repos.eventRepo,
+		Subscriptions: repos.subscriptionRepo,
+		Store:         store,
+		Blobs:         svcs.blobs,
+	}, archive.ReplayConfig{})
+	rp.IsLeader = leaderGate(ctx, cfg, "archive-replay")
+	go rp.Run(ctx)
+	return nil
+}
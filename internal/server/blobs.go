@@ -0,0 +1,23 @@
+package server
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+)
+
+// blobOffloader opens the FC_BLOB_STORE_URI store oversized event payloads
+// go to. Unset, it returns nil: every payload stays inline.
+func blobOffloader(ctx context.Context, cfg EnvCfg) (*blobstore.Offloader, error) {
+	if cfg.BlobStoreURI == "" {
+		return nil, nil
+	}
+	store, err := blobstore.Open(ctx, cfg.BlobStoreURI)
+	if err != nil {
+		return nil, fmt.Errorf("FC_BLOB_STORE_URI: %w", err)
+	}
+	ttl := time.Duration(cfg.BlobPresignTTLSeconds) * time.Second
+	return blobstore.NewOffloader(store, cfg.BlobThresholdBytes, ttl), nil
+}
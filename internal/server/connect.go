@@ -0,0 +1,72 @@
+package server
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/connect"
+)
+
+// startConnectors runs the FC_CONNECT_CONFIG_PATH change data capture
+// connectors until ctx is cancelled; nil when none are configured. Only
+// the elected leader reads the sources, so each slot or change stream
+// has one reader and each checkpoint one writer.
+func startConnectors(ctx context.Context, cfg EnvCfg, pool *pgxpool.Pool, repos *repoSet, svcs *serviceSet) (*connect.Runner, error) {
+	if cfg.ConnectConfigPath == "" {
+		return nil, nil
+	}
+	ccfg, err := connect.LoadConfig(cfg.ConnectConfigPath)
+	if err != nil {
+		return nil, fmt.Errorf("FC_CONNECT_CONFIG_PATH: %w", err)
+	}
+	r := connect.NewRunner(connect.RunnerDeps{
+		Store:  connect.NewPgStore(pool),
+		Events: repos.eventRepo,
+		Blobs:  svcs.blobs,
+	}, ccfg)
+	r.IsLeader = leaderGate(ctx, cfg, "connect")
+	go r.Run(ctx)
+
+	// Health and snapshot control, localhost-only, when
+	// FC_CONNECT_ADMIN_PORT is set.
+	if cfg.ConnectAdminPort > 0 {
+		addr := fmt.Sprintf("127.0.0.1:%d", cfg.ConnectAdminPort)
+		adminSrv := &http.Server{Addr: addr, Handler: r.AdminHandler(), ReadHeaderTimeout: 5 * time.Second}
+		go func() {
+			slog.Info("connector admin API listening", "addr", addr)
+			if err := adminSrv.ListenAndServe(); err != nil && !errors.Is(err, http.ErrServerClosed) {
+				slog.Error("connector admin listener exited", "err", err)
+			}
+		}()
+		go func() { //nolint:gosec // G118: shutdown drain: parent ctx is already done, so a fresh Background context is required
+			<-ctx.Done()
+			shutdownCtx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+			defer cancel()
+			_ = adminSrv.Shutdown(shutdownCtx)
+		}()
+	}
+	slog.Info("connectors started", "count", len(ccfg.Connectors))
+	return r, nil
+}
+
+// connectorHealth reports each connector's state, failing while any of
+// them is.
+func connectorHealth(r *connect.Runner) HealthProvider {
+	return func(context.Context) (map[string]any, error) {
+		statuses := r.Statuses()
+		data := map[string]any{}
+		for _, s := range statuses {
+			data[s.Name] = s.State
+		}
+		if n := connect.Failing(statuses); n > 0 {
+			return data, fmt.Errorf("%d of %d connectors failing", n, len(statuses))
+		}
+		return data, nil
+	}
+}
@@ -6,6 +6,8 @@ import (
 	"os"
 	"strconv"
 	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
 )
 
 // EnvCfg captures every env-driven knob fc-server reads. Mirrors the
@@ -16,9 +18,24 @@ type EnvCfg struct {
 	APIPort     int
 	MetricsPort int
 
+	// LogAdminToken, when set, is the bearer token for the metrics port's
+	// /admin/log-level endpoint; unset restricts it to loopback callers.
+	LogAdminToken string
+
 	DatabaseURL string
 	JWTIssuer   string
 
+	// Repository statement bounds (see database.QueryTracer): every query
+	// on the pool times out after DBQueryTimeoutMS unless its caller's
+	// deadline is sooner, and one running past DBSlowQueryMS is logged and
+	// raised as a RESOURCE warning. 0 disables either.
+	DBQueryTimeoutMS int
+	DBSlowQueryMS    int
+
+	// MigrateDryRun makes fc-server log the migrations it would apply and
+	// exit, without migrating, seeding or serving.
+	MigrateDryRun bool
+
 	// Subsystem toggles.
 	PlatformEnabled     bool
 	RouterEnabled       bool
@@ -46,6 +63,11 @@ type EnvCfg struct {
 	// Empty → derived from the local API listener at load time.
 	DispatchProcessingEndpoint string
 
+	// SchedulerRegionQueues maps data-residency regions to the queue URI
+	// their pools' dispatch jobs are published to, as "eu=sqs://...,
+	// us=sqs://...". A region without an entry holds its jobs PENDING.
+	SchedulerRegionQueues string
+
 	// MCPPort is the listener for the MCP subsystem. Default 8090.
 	MCPPort int
 
@@ -64,11 +86,33 @@ type EnvCfg struct {
 	// Fan-out subscription cache TTL in seconds (Rust
 	// FC_STREAM_FAN_OUT_SUBS_REFRESH_SECS; 0 = use the 5s default).
 	StreamFanOutSubsRefreshSecs int
+	// Fan-out attempts before an event whose dispatch jobs cannot be
+	// inserted goes to msg_stream_dlq (0 = use the default of 3).
+	StreamFanOutMaxAttempts int
 	// Partition manager tuning (months forward, retention, tick cadence).
 	// 0 = use the package default (3 / 90 / 24h).
 	StreamPartitionMonthsForward int
 	StreamPartitionRetentionDays int
 	StreamPartitionTickHours     int
+	// Retention reaper for per-event-type data policies. 0 = use the
+	// package default (1000 rows / 60 min).
+	StreamRetentionEnabled   bool
+	StreamRetentionBatchSize int
+	StreamRetentionTickMins  int
+	// Read-name sync: refreshes subscription/client/event-type names on
+	// msg_dispatch_jobs_read after renames. 0 = default (1 min).
+	StreamReadNamesEnabled  bool
+	StreamReadNamesTickSecs int
+	// Delivery-stats rollup: hourly per-subscription analytics from
+	// dispatch attempts. 0 = default (1 min tick, 30 day retention).
+	StreamDeliveryStatsEnabled       bool
+	StreamDeliveryStatsTickSecs      int
+	StreamDeliveryStatsRetentionDays int
+	// Anomaly detector: ingestion drops and delivery failure spikes
+	// scored per window. 0 = default (15 min window, 10 events).
+	StreamAnomaliesEnabled    bool
+	StreamAnomaliesWindowMins int
+	StreamAnomaliesMinEvents  int
 
 	// Outbox processor — only Postgres is supported in the unified
 	// binary; the standalone cmd/fc-outbox-processor remains the home
@@ -83,13 +127,44 @@ type EnvCfg struct {
 	// re-run in order behind it.
 	OutboxMaxConcurrentGroups int
 	OutboxBlockOnError        bool
+	// Per-group failure policy (block|skip|park; empty derives it from
+	// OutboxBlockOnError), overrides as "group=policy,prefix*=policy", the
+	// park length and the stuck-group warning threshold. 0 = defaults.
+	OutboxGroupPolicy       string
+	OutboxGroupPolicies     string
+	OutboxGroupParkSeconds  int
+	OutboxStuckGroupSeconds int
 	// OutboxAdminPort serves the operational state-machine admin API
 	// (pause/resume/unblock/skip message groups) on 127.0.0.1:<port>. 0 = off.
 	OutboxAdminPort int
+	// OutboxTableConfig is an outbox.TableConfig JSON object mapping the
+	// Postgres backend onto a legacy outbox table. Empty = the SDK table.
+	OutboxTableConfig string
+	// OutboxTwoPhasePublish records each delivered row's platform id before
+	// completing it (outbox.Config.TwoPhasePublish), adding published_id to
+	// the SDK table.
+	OutboxTwoPhasePublish bool
+	// OutboxRoutesPath is an outbox.RoutingConfig file (YAML or JSON)
+	// routing items to other platforms or queues. Empty = everything to
+	// OutboxPlatformURL.
+	OutboxRoutesPath string
 	// Backend selection: "postgres" (default, shared pool) or "mongo".
 	OutboxBackend  string
 	OutboxMongoURI string
 	OutboxMongoDB  string
+	// Mongo client tuning, see OutboxMongoConfig; 0 keeps the
+	// common.NewMongoConfig default. Compressors is comma-separated.
+	OutboxMongoMaxPoolSize              int
+	OutboxMongoMinPoolSize              int
+	OutboxMongoConnectTimeoutMS         int
+	OutboxMongoServerSelectionTimeoutMS int
+	OutboxMongoRetryWrites              bool
+	OutboxMongoRetryReads               bool
+	OutboxMongoCompressors              string
+	// OutboxMongoClaimLeaseSecs is how long a Mongo claim holds an item
+	// before recovery may hand it to another processor; 0 keeps the
+	// outboxmongo.DefaultClaimLease.
+	OutboxMongoClaimLeaseSecs int
 
 	// Router — used when FC_ROUTER_ENABLED=true. Mirrors the env vars
 	// the standalone cmd/fc-router binary reads.
@@ -98,6 +173,60 @@ type EnvCfg struct {
 	RouterNotifyWebhookURL string
 	RouterDrainTimeoutSec  int
 
+	// Router delivery-attempt sink (router.AttemptSinkConfig). Kind is
+	// http, kafka (via REST Proxy) or file; empty disables it.
+	RouterAttemptSinkKind      string
+	RouterAttemptSinkURL       string
+	RouterAttemptSinkTopic     string
+	RouterAttemptSinkPath      string
+	RouterAttemptSinkToken     string
+	RouterAttemptSinkBuffer    int
+	RouterAttemptSinkBatchSize int
+	RouterAttemptSinkFlushMS   int
+
+	// RouterEgressBind pins delivery connections to local addresses on a
+	// multi-homed host: comma-separated IPs or interface names.
+	RouterEgressBind string
+
+	// Router pre-flight target cache (router.PreflightConfig): how long a
+	// resolved host is trusted, and how long one that doesn't resolve or
+	// fails certificate verification is refused (0 disables the cache).
+	RouterPreflightSecs         int
+	RouterPreflightNegativeSecs int
+
+	// Router slow-start after a standby takes over (router.SlowStartConfig).
+	// SlowStartSecs 0 disables the ramp; WarmTargets 0 disables pre-warming.
+	// GroupHandoverSecs is the FIFO group handover lease; 0 disables it.
+	RouterSlowStartSecs       int
+	RouterSlowStartCurve      string
+	RouterSlowStartMinPercent int
+	RouterWarmTargets         int
+	RouterGroupHandoverSecs   int
+
+	// Autoscaling targets behind the router's /scale/recommendation and
+	// fc_scale_desired_replicas (routerapi.ScaleConfig). QueueTargets is
+	// "queue=messages,..."; MaxReplicas 0 = unbounded.
+	ScaleMessagesPerReplica int
+	ScaleQueueTargets       string
+	ScaleMinReplicas        int
+	ScaleMaxReplicas        int
+
+	// Router runtime state snapshot (router.StateSnapshotConfig): "redis"
+	// or a file path; empty disables it.
+	RouterStateSnapshot        string
+	RouterStateSnapshotMaxSecs int
+
+	// Router config history (routermongo.ConfigHistory), behind GET
+	// /config/history: the Mongo it is kept in, empty disables it, and how
+	// many days a superseded snapshot is kept.
+	RouterConfigHistoryMongoURI      string
+	RouterConfigHistoryMongoDB       string
+	RouterConfigHistoryRetentionDays int
+
+	// RouterPublishRatePerSec caps messages accepted per second by the
+	// router's POST /messages and /messages/batch; 0 = unlimited.
+	RouterPublishRatePerSec int
+
 	// ALB self-registration (router). When ALBEnabled, the router registers
 	// this instance's IP with the target group on leader-gain (or non-standby
 	// start) and deregisters on leader-loss / shutdown. Mirrors Rust FC_ALB_*.
@@ -120,6 +249,21 @@ type EnvCfg struct {
 	// PEM-normalized; empty when unset or not a real PEM — it's optional,
 	// so a missing or unparseable value must NOT stop the platform booting.
 	JWTPreviousPublicKey string
+	// JWTKeyring keeps the signing keys in the shared keyring
+	// (iam_jwt_keyrings, sealed with FLOWCATALYST_APP_KEY) instead of
+	// reading a single configured key, and rotates them every
+	// JWTKeyRotationDays (0 = never), keeping retired keys valid for
+	// JWTKeyGraceHours. Each instance reloads the ring (rotating it when
+	// due) every JWTKeyRefreshMinutes.
+	JWTKeyring           bool
+	JWTKeyRotationDays   int
+	JWTKeyGraceHours     int
+	JWTKeyRefreshMinutes int
+	// SecretsDataDir / SecretsEncryptionKey locate the encrypted secrets
+	// file (data_dir/secrets.enc, AES-256-GCM, base64 32-byte key) — the
+	// same layout as Rust fc-secrets' EncryptedProvider.
+	SecretsDataDir       string
+	SecretsEncryptionKey string
 
 	// MCP — the read-only MCP server proxies into the platform. URL is
 	// where it dials the platform itself; for fc-dev it's the local
@@ -132,6 +276,139 @@ type EnvCfg struct {
 	// in the platform Authenticator middleware. Defaults to false in
 	// production. fc-dev flips it on for the local embedded-PG flow.
 	AuthAllowTestHeaders bool
+
+	// AuthMode is "embedded" (default: only tokens this platform mints) or
+	// "remote", which additionally accepts bearer tokens from the trusted
+	// external issuers in AuthRemoteIssuers (a JSON array, see
+	// remoteauth.Issuer), validated against their JWKS with
+	// AuthClockSkewSecs of exp/nbf tolerance.
+	AuthMode            string
+	AuthRemoteIssuers   string
+	AuthClockSkewSecs   int
+	AuthJWKSRefreshSecs int
+
+	// PayloadEncryptionClients opts clients into encrypted-at-rest event
+	// data and dispatch-job payloads (comma-separated client IDs, or "*").
+	// Requires FLOWCATALYST_APP_KEY. See NewPayloadCipher.
+	PayloadEncryptionClients string
+
+	// Startup dependency probing (see StartupDependencies): attempts per
+	// dependency and the exponential backoff between them. 0 = defaults
+	// (10 attempts, 500ms doubling to 10s).
+	StartupProbeAttempts     int
+	StartupProbeBackoffMS    int
+	StartupProbeMaxBackoffMS int
+
+	// Declarative configuration reconciler (internal/platform/reconciler).
+	// ReconcileSpecPath is a spec file or directory; empty disables it.
+	// Drift is reported every ReconcileIntervalSecs and written back only
+	// when ReconcileApply is set.
+	ReconcileSpecPath     string
+	ReconcileIntervalSecs int
+	ReconcileApply        bool
+
+	// Multi-cluster federation (internal/platform/federation).
+	// FederationInstanceID names this instance to its peers; empty
+	// disables both the feed and the puller. FederationMaxHops bounds how
+	// far an event is forwarded.
+	FederationInstanceID   string
+	FederationMaxHops      int
+	FederationIntervalSecs int
+
+	// Change data capture connectors (internal/connect).
+	// ConnectConfigPath is the connector file; empty runs none.
+	// ConnectAdminPort serves their health and snapshot API on localhost.
+	ConnectConfigPath string
+	ConnectAdminPort  int
+
+	// Event lake (internal/platform/archive). ArchiveStoreURI is the
+	// object store events and final delivery statuses are archived to,
+	// in blob store URI form; empty disables archiving.
+	ArchiveStoreURI     string
+	ArchiveIntervalSecs int
+	ArchiveSettleSecs   int
+
+	// IdempotencyTTLHours is how long an admin create's Idempotency-Key
+	// replays its recorded response.
+	IdempotencyTTLHours int
+
+	// EnvStrict makes CheckEnv fail startup on FC_*/FLOWCATALYST_*
+	// variables that aren't documented (typos); off, they're only logged.
+	EnvStrict bool
+
+	// Published delivery source addresses (GET
+	// /.well-known/flowcatalyst/egress-ips). EgressIPs is comma-separated
+	// addresses or CIDR ranges; EgressIPsDiscoverURL names an echo service
+	// whose answer (the NAT gateway's public IP) is added to the list.
+	EgressIPs            string
+	EgressIPsDiscoverURL string
+
+	// Browser-facing HTTP policy on the API listener (see the CORS, CSRF
+	// and SecurityHeaders middleware). CORSOrigins is comma-separated;
+	// empty allows no cross-origin callers. HSTSMaxAgeSecs 0 omits HSTS.
+	CORSOrigins          string
+	CORSAllowCredentials bool
+	CORSMaxAgeSecs       int
+	CSRFEnabled          bool
+	HSTSMaxAgeSecs       int
+
+	// TrustedProxies is the comma-separated addresses / CIDRs of the
+	// reverse proxies whose X-Forwarded-For is believed when resolving the
+	// caller for OAuth client and service account network allowlists.
+	TrustedProxies string
+
+	// HTTP listener limits (API and metrics servers). Body caps are in
+	// bytes, 0 = uncapped; HTTPBatchMaxBodyBytes applies to the batch
+	// ingest endpoints. HTTPMaxConnections 0 = unlimited. There is no
+	// write timeout: SSE streams stay open indefinitely.
+	HTTPMaxBodyBytes          int64
+	HTTPBatchMaxBodyBytes     int64
+	HTTPBodyReadTimeoutSecs   int
+	HTTPReadHeaderTimeoutSecs int
+	HTTPReadTimeoutSecs       int
+	HTTPIdleTimeoutSecs       int
+	HTTPMaxConnections        int
+
+	// Outbound (SSRF) policy for webhook deliveries and router mediation
+	// targets; see internal/outbound. Schemes, nets and hosts are
+	// comma-separated; OutboundClientPolicies is a JSON object of client
+	// id → {"allowedHosts": [...], "allowedNets": [...]}.
+	// OutboundTransports is a JSON object of transport name →
+	// outbound.TransportSpec (SSH bastions, SOCKS5 proxies) subscriptions
+	// may deliver through. OutboundTLSCAFile names a PEM bundle of extra
+	// roots; OutboundTLSMinVersion is "1.2" or "1.3" (outbound.TLSTrust).
+	OutboundSchemes        string
+	OutboundAllowPrivate   bool
+	OutboundAllowedNets    string
+	OutboundAllowedHosts   string
+	OutboundClientPolicies string
+	OutboundTransports     string
+	OutboundTLSCAFile      string
+	OutboundTLSMinVersion  string
+
+	// QueueEncoding is the envelope publishers write to SQS and NATS:
+	// "json" (default, Java-compatible), "protobuf" or "avro". Consumers
+	// read every encoding. Avro registers its schema with the Confluent
+	// registry at QueueSchemaRegistryURL, when set.
+	QueueEncoding              string
+	QueueSchemaRegistryURL     string
+	QueueSchemaRegistrySubject string
+
+	// Blob store for oversized event payloads (internal/blobstore): event
+	// data over BlobThresholdBytes is stored at BlobStoreURI with only a
+	// reference in the row, the queues and the outbox request. Empty
+	// BlobStoreURI keeps every payload inline.
+	BlobStoreURI          string
+	BlobThresholdBytes    int
+	BlobPresignTTLSeconds int
+
+	// Schema registry for event payloads (internal/schemaregistry):
+	// Confluent, or Apicurio's ccompat API. Empty SchemaRegistryURL
+	// disables subject bindings; supplied schema ids are stored unchecked.
+	SchemaRegistryURL          string
+	SchemaRegistryUsername     string
+	SchemaRegistryPassword     string
+	SchemaRegistryCacheTTLSecs int
 }
 
 func LoadEnv() EnvCfg {
@@ -139,9 +416,15 @@ func LoadEnv() EnvCfg {
 		APIPort:     envIntAlias("FC_API_PORT", "PORT", 8080),
 		MetricsPort: envInt("FC_METRICS_PORT", 9090),
 
+		LogAdminToken: os.Getenv("FC_LOG_ADMIN_TOKEN"),
+
 		DatabaseURL: ResolveDatabaseURL(),
 		JWTIssuer:   envFirst("FC_JWT_ISSUER", "FC_EXTERNAL_BASE_URL", "EXTERNAL_BASE_URL", "http://localhost:8080"),
 
+		DBQueryTimeoutMS: envInt("FC_DB_QUERY_TIMEOUT_MS", 30000),
+		DBSlowQueryMS:    envInt("FC_DB_SLOW_QUERY_MS", 500),
+		MigrateDryRun:    envBool("FC_MIGRATE_DRY_RUN", false),
+
 		PlatformEnabled:     envBoolAlias("FC_PLATFORM_ENABLED", "PLATFORM_ENABLED", true),
 		RouterEnabled:       envBoolAlias("FC_ROUTER_ENABLED", "MESSAGE_ROUTER_ENABLED", false),
 		SchedulerEnabled:    envBoolAlias("FC_SCHEDULER_ENABLED", "DISPATCH_SCHEDULER_ENABLED", false),
@@ -165,9 +448,22 @@ func LoadEnv() EnvCfg {
 		StreamPartitionsEnabled:      envBoolAlias("FC_STREAM_PARTITION_MANAGER_ENABLED", "FC_STREAM_PARTITIONS_ENABLED", true),
 		StreamBatchSize:              envInt("FC_STREAM_BATCH_SIZE", 0),
 		StreamFanOutSubsRefreshSecs:  envInt("FC_STREAM_FAN_OUT_SUBS_REFRESH_SECS", 0),
+		StreamFanOutMaxAttempts:      envInt("FC_STREAM_FAN_OUT_MAX_ATTEMPTS", 0),
 		StreamPartitionMonthsForward: envInt("FC_STREAM_PARTITION_MONTHS_FORWARD", 0),
 		StreamPartitionRetentionDays: envInt("FC_STREAM_PARTITION_RETENTION_DAYS", 0),
 		StreamPartitionTickHours:     envInt("FC_STREAM_PARTITION_TICK_HOURS", 0),
+		StreamRetentionEnabled:       envBool("FC_STREAM_RETENTION_ENABLED", true),
+		StreamRetentionBatchSize:     envInt("FC_STREAM_RETENTION_BATCH_SIZE", 0),
+		StreamRetentionTickMins:      envInt("FC_STREAM_RETENTION_TICK_MINUTES", 0),
+		StreamReadNamesEnabled:       envBool("FC_STREAM_READ_NAMES_ENABLED", true),
+		StreamReadNamesTickSecs:      envInt("FC_STREAM_READ_NAMES_TICK_SECS", 0),
+
+		StreamDeliveryStatsEnabled:       envBool("FC_STREAM_DELIVERY_STATS_ENABLED", true),
+		StreamDeliveryStatsTickSecs:      envInt("FC_STREAM_DELIVERY_STATS_TICK_SECS", 0),
+		StreamDeliveryStatsRetentionDays: envInt("FC_STREAM_DELIVERY_STATS_RETENTION_DAYS", 0),
+		StreamAnomaliesEnabled:           envBool("FC_STREAM_ANOMALIES_ENABLED", true),
+		StreamAnomaliesWindowMins:        envInt("FC_STREAM_ANOMALIES_WINDOW_MINS", 0),
+		StreamAnomaliesMinEvents:         envInt("FC_STREAM_ANOMALIES_MIN_EVENTS", 0),
 
 		// FC_OUTBOX_API_URL / FC_OUTBOX_TOKEN align with the standalone Rust
 		// outbox CLI; FC_API_BASE_URL / FC_API_TOKEN align with the Rust
@@ -180,7 +476,14 @@ func LoadEnv() EnvCfg {
 		OutboxPollIntervalMS:      envInt("FC_OUTBOX_POLL_INTERVAL_MS", 0),
 		OutboxMaxConcurrentGroups: envIntAlias("FC_OUTBOX_MAX_CONCURRENT_GROUPS", "FC_MAX_CONCURRENT_GROUPS", 0),
 		OutboxBlockOnError:        envBool("FC_OUTBOX_BLOCK_ON_ERROR", true),
+		OutboxGroupPolicy:         os.Getenv("FC_OUTBOX_GROUP_POLICY"),
+		OutboxGroupPolicies:       os.Getenv("FC_OUTBOX_GROUP_POLICIES"),
+		OutboxGroupParkSeconds:    envInt("FC_OUTBOX_GROUP_PARK_SECONDS", 0),
+		OutboxStuckGroupSeconds:   envInt("FC_OUTBOX_STUCK_GROUP_SECONDS", 0),
 		OutboxAdminPort:           envInt("FC_OUTBOX_ADMIN_PORT", 0),
+		OutboxTableConfig:         os.Getenv("FC_OUTBOX_TABLE_CONFIG"),
+		OutboxTwoPhasePublish:     envBool("FC_OUTBOX_TWO_PHASE_PUBLISH", false),
+		OutboxRoutesPath:          os.Getenv("FC_OUTBOX_ROUTES_PATH"),
 		// FC_OUTBOX_DB_TYPE is the Rust fc-outbox-processor / fc-server var name,
 		// honoured as an alias so an existing Rust outbox env drops in unchanged
 		// (values: postgres|mongo; sqlite is out of scope and errors clearly).
@@ -188,11 +491,49 @@ func LoadEnv() EnvCfg {
 		OutboxMongoURI: envFirst("FC_OUTBOX_MONGO_URI", "FC_OUTBOX_DB_URL", "", ""),
 		OutboxMongoDB:  envOr("FC_OUTBOX_MONGO_DB", "flowcatalyst"),
 
+		OutboxMongoMaxPoolSize:              envInt("FC_OUTBOX_MONGO_MAX_POOL_SIZE", 0),
+		OutboxMongoMinPoolSize:              envInt("FC_OUTBOX_MONGO_MIN_POOL_SIZE", 0),
+		OutboxMongoConnectTimeoutMS:         envInt("FC_OUTBOX_MONGO_CONNECT_TIMEOUT_MS", 0),
+		OutboxMongoServerSelectionTimeoutMS: envInt("FC_OUTBOX_MONGO_SERVER_SELECTION_TIMEOUT_MS", 0),
+		OutboxMongoRetryWrites:              envBool("FC_OUTBOX_MONGO_RETRY_WRITES", true),
+		OutboxMongoRetryReads:               envBool("FC_OUTBOX_MONGO_RETRY_READS", true),
+		OutboxMongoCompressors:              os.Getenv("FC_OUTBOX_MONGO_COMPRESSORS"),
+		OutboxMongoClaimLeaseSecs:           envInt("FC_OUTBOX_MONGO_CLAIM_LEASE_SECS", 0),
+
 		RouterConfigURL:        os.Getenv("FLOWCATALYST_CONFIG_URL"),
 		RouterDevMode:          envBool("FLOWCATALYST_DEV_MODE", false),
 		RouterNotifyWebhookURL: os.Getenv("FC_NOTIFY_WEBHOOK_URL"),
 		RouterDrainTimeoutSec:  envInt("FC_DRAIN_TIMEOUT_SECONDS", 60),
 
+		RouterAttemptSinkKind:      os.Getenv("FC_ROUTER_ATTEMPT_SINK"),
+		RouterAttemptSinkURL:       os.Getenv("FC_ROUTER_ATTEMPT_SINK_URL"),
+		RouterAttemptSinkTopic:     os.Getenv("FC_ROUTER_ATTEMPT_SINK_TOPIC"),
+		RouterAttemptSinkPath:      os.Getenv("FC_ROUTER_ATTEMPT_SINK_PATH"),
+		RouterAttemptSinkToken:     os.Getenv("FC_ROUTER_ATTEMPT_SINK_TOKEN"),
+		RouterAttemptSinkBuffer:    envInt("FC_ROUTER_ATTEMPT_SINK_BUFFER", 0),
+		RouterAttemptSinkBatchSize: envInt("FC_ROUTER_ATTEMPT_SINK_BATCH_SIZE", 0),
+		RouterAttemptSinkFlushMS:   envInt("FC_ROUTER_ATTEMPT_SINK_FLUSH_MS", 0),
+		RouterEgressBind:           os.Getenv("FC_ROUTER_EGRESS_BIND"),
+		ScaleMessagesPerReplica:    envInt("FC_SCALE_MESSAGES_PER_REPLICA", 100),
+		ScaleQueueTargets:          os.Getenv("FC_SCALE_QUEUE_TARGETS"),
+		ScaleMinReplicas:           envInt("FC_SCALE_MIN_REPLICAS", 1),
+		ScaleMaxReplicas:           envInt("FC_SCALE_MAX_REPLICAS", 0),
+		RouterSlowStartSecs:        envInt("FC_ROUTER_SLOW_START_SECS", 0),
+		RouterSlowStartCurve:       envOr("FC_ROUTER_SLOW_START_CURVE", "linear"),
+		RouterSlowStartMinPercent:  envInt("FC_ROUTER_SLOW_START_MIN_PERCENT", 10),
+		RouterWarmTargets:          envInt("FC_ROUTER_WARM_TARGETS", 0),
+		RouterGroupHandoverSecs:    envInt("FC_ROUTER_GROUP_HANDOVER_SECS", 30),
+		RouterPublishRatePerSec:    envInt("FC_ROUTER_PUBLISH_RATE_PER_SEC", 0),
+		RouterStateSnapshot:        os.Getenv("FC_ROUTER_STATE_SNAPSHOT"),
+		RouterStateSnapshotMaxSecs: envInt("FC_ROUTER_STATE_SNAPSHOT_MAX_AGE_SECS", 300),
+
+		RouterPreflightSecs:         envInt("FC_ROUTER_PREFLIGHT_SECS", 300),
+		RouterPreflightNegativeSecs: envInt("FC_ROUTER_PREFLIGHT_NEGATIVE_SECS", 60),
+
+		RouterConfigHistoryMongoURI:      os.Getenv("FC_ROUTER_CONFIG_HISTORY_MONGO_URI"),
+		RouterConfigHistoryMongoDB:       envOr("FC_ROUTER_CONFIG_HISTORY_MONGO_DB", "flowcatalyst"),
+		RouterConfigHistoryRetentionDays: envInt("FC_ROUTER_CONFIG_HISTORY_RETENTION_DAYS", 30),
+
 		ALBEnabled:        envBool("FC_ALB_ENABLED", false),
 		ALBTargetGroupARN: os.Getenv("FC_ALB_TARGET_GROUP_ARN"),
 		ALBInstanceIP:     envFirst("FC_ALB_TARGET_ID", "FC_ALB_INSTANCE_IP", "", ""),
@@ -206,13 +547,90 @@ func LoadEnv() EnvCfg {
 
 		JWTSigningKeyPath:    os.Getenv("FC_JWT_SIGNING_KEY_PATH"),
 		JWTPreviousPublicKey: normalizedPreviousPublicKey(),
+		JWTKeyring:           envBool("FC_JWT_KEYRING_ENABLED", false),
+		JWTKeyRotationDays:   envInt("FC_JWT_KEY_ROTATION_DAYS", 0),
+		JWTKeyGraceHours:     envInt("FC_JWT_KEY_GRACE_HOURS", 48),
+		JWTKeyRefreshMinutes: envInt("FC_JWT_KEY_REFRESH_MINUTES", 5),
+		SecretsDataDir:       envOr("FC_SECRETS_DATA_DIR", "./data"),
+		SecretsEncryptionKey: os.Getenv("FC_SECRETS_ENCRYPTION_KEY"),
 		AuthAllowTestHeaders: envBool("FC_AUTH_ALLOW_TEST_HEADERS", false),
+		AuthMode:             envOr("FC_AUTH_MODE", "embedded"),
+		AuthRemoteIssuers:    os.Getenv("FC_AUTH_REMOTE_ISSUERS"),
+		AuthClockSkewSecs:    envInt("FC_AUTH_CLOCK_SKEW_SECS", 60),
+		AuthJWKSRefreshSecs:  envInt("FC_AUTH_JWKS_REFRESH_SECS", 3600),
+
+		PayloadEncryptionClients: os.Getenv("FC_PAYLOAD_ENCRYPTION_CLIENTS"),
+
+		StartupProbeAttempts:     envInt("FC_STARTUP_PROBE_ATTEMPTS", 0),
+		StartupProbeBackoffMS:    envInt("FC_STARTUP_PROBE_BACKOFF_MS", 0),
+		StartupProbeMaxBackoffMS: envInt("FC_STARTUP_PROBE_MAX_BACKOFF_MS", 0),
+
+		ReconcileSpecPath:     os.Getenv("FC_RECONCILE_SPEC_PATH"),
+		ReconcileIntervalSecs: envInt("FC_RECONCILE_INTERVAL_SECS", 60),
+		ReconcileApply:        envBool("FC_RECONCILE_APPLY", false),
+
+		FederationInstanceID:   os.Getenv("FC_FEDERATION_INSTANCE_ID"),
+		FederationMaxHops:      envInt("FC_FEDERATION_MAX_HOPS", 4),
+		FederationIntervalSecs: envInt("FC_FEDERATION_INTERVAL_SECS", 5),
+
+		ConnectConfigPath: os.Getenv("FC_CONNECT_CONFIG_PATH"),
+		ConnectAdminPort:  envInt("FC_CONNECT_ADMIN_PORT", 0),
+
+		ArchiveStoreURI:     os.Getenv("FC_ARCHIVE_STORE_URI"),
+		ArchiveIntervalSecs: envInt("FC_ARCHIVE_INTERVAL_SECS", 300),
+		ArchiveSettleSecs:   envInt("FC_ARCHIVE_SETTLE_SECS", 60),
+
+		IdempotencyTTLHours: envInt("FC_IDEMPOTENCY_TTL_HOURS", 24),
+
+		EnvStrict: envBool("FC_ENV_STRICT", false),
+
+		EgressIPs:            os.Getenv("FC_EGRESS_IPS"),
+		EgressIPsDiscoverURL: os.Getenv("FC_EGRESS_IPS_DISCOVER_URL"),
+
+		CORSOrigins:          envFirst("FC_CORS_ORIGINS", "CORS_ORIGINS", ""),
+		CORSAllowCredentials: envBool("FC_CORS_ALLOW_CREDENTIALS", false),
+		CORSMaxAgeSecs:       envInt("FC_CORS_MAX_AGE_SECS", 600),
+		CSRFEnabled:          envBool("FC_CSRF_ENABLED", true),
+		HSTSMaxAgeSecs:       envInt("FC_HSTS_MAX_AGE_SECS", 31_536_000),
+
+		TrustedProxies: os.Getenv("FC_TRUSTED_PROXIES"),
+
+		HTTPMaxBodyBytes:          int64(envInt("FC_HTTP_MAX_BODY_BYTES", 1<<20)),
+		HTTPBatchMaxBodyBytes:     int64(envInt("FC_HTTP_BATCH_MAX_BODY_BYTES", 10<<20)),
+		HTTPBodyReadTimeoutSecs:   envInt("FC_HTTP_BODY_READ_TIMEOUT_SECS", 30),
+		HTTPReadHeaderTimeoutSecs: envInt("FC_HTTP_READ_HEADER_TIMEOUT_SECS", 10),
+		HTTPReadTimeoutSecs:       envInt("FC_HTTP_READ_TIMEOUT_SECS", 60),
+		HTTPIdleTimeoutSecs:       envInt("FC_HTTP_IDLE_TIMEOUT_SECS", 120),
+		HTTPMaxConnections:        envInt("FC_HTTP_MAX_CONNECTIONS", 10_000),
+
+		OutboundSchemes:        envOr("FC_OUTBOUND_SCHEMES", "https,http"),
+		OutboundAllowPrivate:   envBool("FC_OUTBOUND_ALLOW_PRIVATE", false),
+		OutboundAllowedNets:    os.Getenv("FC_OUTBOUND_ALLOWED_NETS"),
+		OutboundAllowedHosts:   os.Getenv("FC_OUTBOUND_ALLOWED_HOSTS"),
+		OutboundClientPolicies: os.Getenv("FC_OUTBOUND_CLIENT_POLICIES"),
+		OutboundTransports:     os.Getenv("FC_OUTBOUND_TRANSPORTS"),
+		OutboundTLSCAFile:      os.Getenv("FC_OUTBOUND_TLS_CA_FILE"),
+		OutboundTLSMinVersion:  os.Getenv("FC_OUTBOUND_TLS_MIN_VERSION"),
+
+		QueueEncoding:              envOr("FC_QUEUE_ENCODING", "json"),
+		QueueSchemaRegistryURL:     os.Getenv("FC_QUEUE_SCHEMA_REGISTRY_URL"),
+		QueueSchemaRegistrySubject: envOr("FC_QUEUE_SCHEMA_REGISTRY_SUBJECT", "flowcatalyst-queue-message"),
+
+		BlobStoreURI:          os.Getenv("FC_BLOB_STORE_URI"),
+		BlobThresholdBytes:    envInt("FC_BLOB_THRESHOLD_BYTES", 0),
+		BlobPresignTTLSeconds: envInt("FC_BLOB_PRESIGN_TTL_SECONDS", 0),
+
+		SchemaRegistryURL:          os.Getenv("FC_SCHEMA_REGISTRY_URL"),
+		SchemaRegistryUsername:     os.Getenv("FC_SCHEMA_REGISTRY_USERNAME"),
+		SchemaRegistryPassword:     os.Getenv("FC_SCHEMA_REGISTRY_PASSWORD"),
+		SchemaRegistryCacheTTLSecs: envInt("FC_SCHEMA_REGISTRY_CACHE_TTL_SECS", 300),
 
 		MCPPlatformURL:  envFirst("FLOWCATALYST_URL", "FC_MCP_PLATFORM_URL", "", ""),
 		MCPClientID:     os.Getenv("FLOWCATALYST_CLIENT_ID"),
 		MCPClientSecret: os.Getenv("FLOWCATALYST_CLIENT_SECRET"),
 
 		DispatchProcessingEndpoint: envOr("FC_DISPATCH_PROCESSING_ENDPOINT", ""),
+		SchedulerRegionQueues:      os.Getenv("FC_SCHEDULER_REGION_QUEUES"),
 	}
 	// Default the dispatch callback to the local API listener: the router
 	// consumes a queued job and POSTs {messageId} here for delivery.
@@ -272,6 +690,32 @@ func envOr(key, def string) string {
 	return def
 }
 
+// OutboxMongoConfig assembles the outbox's Mongo client configuration:
+// common.NewMongoConfig defaults overridden by any FC_OUTBOX_MONGO_* knob.
+func (c EnvCfg) OutboxMongoConfig() common.MongoConfig {
+	m := common.NewMongoConfig(c.OutboxMongoURI, c.OutboxMongoDB)
+	if c.OutboxMongoMaxPoolSize > 0 {
+		m.MaxPoolSize = uint64(c.OutboxMongoMaxPoolSize)
+	}
+	if c.OutboxMongoMinPoolSize > 0 {
+		m.MinPoolSize = uint64(c.OutboxMongoMinPoolSize)
+	}
+	if c.OutboxMongoConnectTimeoutMS > 0 {
+		m.ConnectTimeoutMS = uint64(c.OutboxMongoConnectTimeoutMS)
+	}
+	if c.OutboxMongoServerSelectionTimeoutMS > 0 {
+		m.ServerSelectionTimeoutMS = uint64(c.OutboxMongoServerSelectionTimeoutMS)
+	}
+	m.RetryWrites = c.OutboxMongoRetryWrites
+	m.RetryReads = c.OutboxMongoRetryReads
+	for _, comp := range strings.Split(c.OutboxMongoCompressors, ",") {
+		if t := strings.TrimSpace(comp); t != "" {
+			m.Compressors = append(m.Compressors, t)
+		}
+	}
+	return m
+}
+
 // webauthnOrigins returns the allowed WebAuthn origins. It reads
 // FC_WEBAUTHN_ORIGINS (comma-separated — go-webauthn needs every origin listed
 // verbatim since it matches by exact scheme+host), falling back to the legacy
@@ -289,6 +733,17 @@ func webauthnOrigins() []string {
 	return out
 }
 
+// splitList splits a comma-separated env value, dropping blank entries.
+func splitList(raw string) []string {
+	var out []string
+	for _, p := range strings.Split(raw, ",") {
+		if t := strings.TrimSpace(p); t != "" {
+			out = append(out, t)
+		}
+	}
+	return out
+}
+
 func envFirst(keys ...string) string {
 	// Last argument is the default; everything else is a key in priority order.
 	def := keys[len(keys)-1]
@@ -0,0 +1,58 @@
+package server
+
+import (
+	"fmt"
+	"io"
+	"log/slog"
+	"os"
+	"strings"
+	"text/tabwriter"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/envutil"
+)
+
+// CheckEnv looks for FC_* and FLOWCATALYST_* variables that no binary
+// reads — almost always a typo, which would otherwise be silently
+// ignored. Each is logged with the closest documented name; with
+// FC_ENV_STRICT the check also fails.
+func CheckEnv(cfg EnvCfg) error {
+	unknown := envutil.Unknown(os.Environ())
+	if len(unknown) == 0 {
+		return nil
+	}
+	for _, name := range unknown {
+		attrs := []any{"var", name}
+		if s := envutil.Suggest(name); s != "" {
+			attrs = append(attrs, "did_you_mean", s)
+		}
+		slog.Warn("unknown environment variable; see docs/environment-variables.md", attrs...)
+	}
+	if cfg.EnvStrict {
+		return fmt.Errorf("unknown environment variables (FC_ENV_STRICT): %s", strings.Join(unknown, ", "))
+	}
+	return nil
+}
+
+// PrintEnv writes every documented variable with its effective value and
+// where it came from: the environment, an alias (named), or the
+// documented default. Sensitive values are masked; unknown FC_* /
+// FLOWCATALYST_* variables are listed after.
+func PrintEnv(w io.Writer) error {
+	tw := tabwriter.NewWriter(w, 0, 0, 2, ' ', 0)
+	fmt.Fprintln(tw, "VARIABLE\tVALUE\tSOURCE")
+	for _, v := range envutil.Known() {
+		value, src, from := v.Resolve()
+		source := string(src)
+		if src == envutil.SourceAlias {
+			source += " " + from
+		}
+		if src != envutil.SourceDefault {
+			value = envutil.Redact(v.Name, value)
+		}
+		fmt.Fprintf(tw, "%s\t%s\t%s\n", v.Name, value, source)
+	}
+	for _, name := range envutil.Unknown(os.Environ()) {
+		fmt.Fprintf(tw, "%s\t%s\tunknown\n", name, envutil.Redact(name, os.Getenv(name)))
+	}
+	return tw.Flush()
+}
@@ -0,0 +1,31 @@
+package server
+
+import (
+	"context"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
+)
+
+// startFederation runs the federation puller until ctx is cancelled; a
+// no-op when FC_FEDERATION_INSTANCE_ID is unset. Only the elected leader
+// pulls, so a peer's feed has one reader and its cursor one writer.
+func startFederation(ctx context.Context, cfg EnvCfg, repos *repoSet, svcs *serviceSet) {
+	if cfg.FederationInstanceID == "" {
+		return
+	}
+	p := federation.NewPuller(federation.PullerDeps{
+		Repo:    repos.federationRepo,
+		Events:  repos.eventRepo,
+		Clients: repos.clientRepo,
+		Enc:     svcs.encSvc,
+		Secrets: svcs.secrets,
+		Blobs:   svcs.blobs,
+	}, federation.PullerConfig{
+		InstanceID: cfg.FederationInstanceID,
+		MaxHops:    cfg.FederationMaxHops,
+		Interval:   time.Duration(cfg.FederationIntervalSecs) * time.Second,
+	})
+	p.IsLeader = leaderGate(ctx, cfg, "federation")
+	go p.Run(ctx)
+}
@@ -0,0 +1,339 @@
+package server
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"net/http"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/prometheus/client_golang/prometheus"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+// SubsystemHealth is one background component's point-in-time status as
+// reported by the metrics-port /ready endpoint. Leader is nil for
+// components that aren't leader-gated; a standby replica (leader=false) is
+// still healthy — it is waiting its turn.
+type SubsystemHealth struct {
+	Name    string `json:"name"`
+	Running bool   `json:"running"`
+	Leader  *bool  `json:"leader,omitempty"`
+	Healthy bool   `json:"healthy"`
+	Error   string `json:"error,omitempty"`
+}
+
+// subsystemRegistry tracks every component Run starts so a single
+// all-in-one process can report one aggregated readiness verdict instead
+// of "the binary is up". Safe for concurrent use.
+type subsystemRegistry struct {
+	mu      sync.RWMutex
+	entries []*subsystemEntry
+}
+
+type subsystemEntry struct {
+	name    string
+	running atomic.Bool
+	failure atomic.Pointer[string]
+	leader  atomic.Pointer[func() bool]
+	// probe is an optional extra check (DB ping, projection health) run on
+	// each snapshot while the component is running.
+	probe func(context.Context) error
+}
+
+func newSubsystemRegistry() *subsystemRegistry { return &subsystemRegistry{} }
+
+// add registers a component. Names are unique; re-adding returns the
+// existing entry.
+func (r *subsystemRegistry) add(name string) *subsystemEntry {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	for _, e := range r.entries {
+		if e.name == name {
+			return e
+		}
+	}
+	e := &subsystemEntry{name: name}
+	r.entries = append(r.entries, e)
+	return e
+}
+
+// setLeader attaches a leader predicate to a registered component. No-op
+// for unknown names (e.g. StartScheduler called outside Run).
+func (r *subsystemRegistry) setLeader(name string, isLeader func() bool) {
+	r.mu.RLock()
+	defer r.mu.RUnlock()
+	for _, e := range r.entries {
+		if e.name == name {
+			e.leader.Store(&isLeader)
+			return
+		}
+	}
+}
+
+func (e *subsystemEntry) fail(msg string) { e.failure.Store(&msg) }
+
+// Snapshot returns every component's status, in registration order, and
+// whether all of them are healthy. An empty registry is ready.
+func (r *subsystemRegistry) Snapshot(ctx context.Context) ([]SubsystemHealth, bool) {
+	r.mu.RLock()
+	entries := append([]*subsystemEntry(nil), r.entries...)
+	r.mu.RUnlock()
+
+	out := make([]SubsystemHealth, 0, len(entries))
+	ready := true
+	for _, e := range entries {
+		h := SubsystemHealth{Name: e.name, Running: e.running.Load()}
+		if f := e.failure.Load(); f != nil {
+			h.Error = *f
+		}
+		if l := e.leader.Load(); l != nil {
+			v := (*l)()
+			h.Leader = &v
+		}
+		h.Healthy = h.Running && h.Error == ""
+		if h.Healthy && e.probe != nil {
+			if err := e.probe(ctx); err != nil {
+				h.Healthy, h.Error = false, err.Error()
+			}
+		}
+		if !h.Healthy {
+			ready = false
+		}
+		out = append(out, h)
+	}
+	return out, ready
+}
+
+type healthAggregatorKey struct{}
+
+// withHealth carries the aggregator to the Start* functions so
+// newLeaderGate can attach each gate to its component, and subsystems can
+// register their own providers, without widening their signatures.
+func withHealth(ctx context.Context, a *HealthAggregator) context.Context {
+	return context.WithValue(ctx, healthAggregatorKey{}, a)
+}
+
+func healthFrom(ctx context.Context) *HealthAggregator {
+	a, _ := ctx.Value(healthAggregatorKey{}).(*HealthAggregator)
+	return a
+}
+
+type metricsRegistryKey struct{}
+
+// withMetrics carries the metrics-port registry the same way, for
+// subsystems that export their own series.
+func withMetrics(ctx context.Context, reg prometheus.Registerer) context.Context {
+	return context.WithValue(ctx, metricsRegistryKey{}, reg)
+}
+
+func metricsFrom(ctx context.Context) prometheus.Registerer {
+	reg, _ := ctx.Value(metricsRegistryKey{}).(prometheus.Registerer)
+	return reg
+}
+
+// Component statuses in the /q/health document (MicroProfile Health shape,
+// as the Quarkus services and the router's own /q/health report it).
+// DEGRADED is ours: a component running on its fallback, which still
+// answers 200.
+const (
+	healthUp       = "UP"
+	healthDegraded = "DEGRADED"
+	healthDown     = "DOWN"
+)
+
+// errDegraded marks a provider error as DEGRADED rather than DOWN; wrap
+// it with fmt.Errorf("...: %w", errDegraded).
+var errDegraded = errors.New("degraded")
+
+// healthCheckTimeout bounds each provider so one hung dependency can't stall
+// a Kubernetes probe past its timeoutSeconds.
+const healthCheckTimeout = 2 * time.Second
+
+// HealthProvider reports one component for /q/health. A non-nil error marks
+// it DOWN, or DEGRADED when it wraps errDegraded; data is included either
+// way.
+type HealthProvider func(ctx context.Context) (data map[string]any, err error)
+
+// HealthCheck is one component entry in a HealthReport.
+type HealthCheck struct {
+	Name   string         `json:"name"`
+	Status string         `json:"status"`
+	Data   map[string]any `json:"data,omitempty"`
+}
+
+// HealthReport is the /q/health response body.
+type HealthReport struct {
+	Status string        `json:"status"`
+	Checks []HealthCheck `json:"checks"`
+}
+
+// HealthAggregator combines the subsystem lifecycle registry with
+// component providers (router consumers and pools, stream checkpoints,
+// outbox lag, Mongo/Redis connectivity) into one /q/health document.
+//
+// Providers are registered as readiness checks or informational ones.
+// Readiness covers state local to this process — a subsystem that died, a
+// degraded router, an unreachable platform database — where pulling the pod
+// out of rotation helps. Shared dependencies that only affect background
+// work (the outbox's Mongo, the standby Redis) are informational: failing
+// readiness on every replica at once would take the API down with them.
+// Safe for concurrent use.
+type HealthAggregator struct {
+	subsystems *subsystemRegistry
+
+	mu             sync.RWMutex
+	components     []healthComponent
+	infrastructure []router.InfrastructureCheck
+}
+
+type healthComponent struct {
+	name      string
+	readiness bool
+	provider  HealthProvider
+}
+
+// NewHealthAggregator returns an aggregator with an empty subsystem registry.
+func NewHealthAggregator() *HealthAggregator {
+	return &HealthAggregator{subsystems: newSubsystemRegistry()}
+}
+
+// Register adds a component provider. Names are unique; the first
+// registration wins, so per-subsystem wiring can register a shared
+// dependency (e.g. the standby Redis) without coordinating.
+func (a *HealthAggregator) Register(name string, readiness bool, provider HealthProvider) {
+	a.mu.Lock()
+	defer a.mu.Unlock()
+	for _, c := range a.components {
+		if c.name == name {
+			return
+		}
+	}
+	a.components = append(a.components, healthComponent{name: name, readiness: readiness, provider: provider})
+}
+
+// addInfrastructure records a store connectivity ping for the router's
+// /monitoring/infrastructure (see infrastructureBridge).
+func (a *HealthAggregator) addInfrastructure(c router.InfrastructureCheck) {
+	a.mu.Lock()
+	defer a.mu.Unlock()
+	a.infrastructure = append(a.infrastructure, c)
+}
+
+func (a *HealthAggregator) infrastructureChecks() []router.InfrastructureCheck {
+	a.mu.RLock()
+	defer a.mu.RUnlock()
+	return append([]router.InfrastructureCheck(nil), a.infrastructure...)
+}
+
+// Report runs every check — subsystems first, in registration order, then
+// providers — and returns the aggregate. With readinessOnly, informational
+// providers are left out. Status is DOWN when any included check is, else
+// DEGRADED when any is, else UP.
+func (a *HealthAggregator) Report(ctx context.Context, readinessOnly bool) HealthReport {
+	a.mu.RLock()
+	components := append([]healthComponent(nil), a.components...)
+	a.mu.RUnlock()
+
+	snapshot, _ := a.subsystems.Snapshot(ctx)
+	checks := make([]HealthCheck, 0, len(snapshot)+len(components))
+	for _, s := range snapshot {
+		checks = append(checks, subsystemCheck(s))
+	}
+
+	var included []healthComponent
+	for _, c := range components {
+		if c.readiness || !readinessOnly {
+			included = append(included, c)
+		}
+	}
+	results := make([]HealthCheck, len(included))
+	var wg sync.WaitGroup
+	for i, c := range included {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			results[i] = runProvider(ctx, c)
+		}()
+	}
+	wg.Wait()
+	checks = append(checks, results...)
+
+	report := HealthReport{Status: healthUp, Checks: checks}
+	for _, c := range checks {
+		switch c.Status {
+		case healthDown:
+			report.Status = healthDown
+			return report
+		case healthDegraded:
+			report.Status = healthDegraded
+		}
+	}
+	return report
+}
+
+func subsystemCheck(s SubsystemHealth) HealthCheck {
+	c := HealthCheck{Name: s.Name, Status: healthUp, Data: map[string]any{"running": s.Running}}
+	if s.Leader != nil {
+		c.Data["leader"] = *s.Leader
+	}
+	if !s.Healthy {
+		c.Status = healthDown
+		c.Data["error"] = s.Error
+	}
+	return c
+}
+
+func runProvider(ctx context.Context, c healthComponent) HealthCheck {
+	ctx, cancel := context.WithTimeout(ctx, healthCheckTimeout)
+	defer cancel()
+	data, err := c.provider(ctx)
+	check := HealthCheck{Name: c.name, Status: healthUp, Data: data}
+	if err != nil {
+		check.Status = healthDown
+		if errors.Is(err, errDegraded) {
+			check.Status = healthDegraded
+		}
+		if check.Data == nil {
+			check.Data = map[string]any{}
+		}
+		check.Data["error"] = err.Error()
+	}
+	return check
+}
+
+// Mount serves the MicroProfile-style probe trio:
+//
+//   - /q/health — every check, informational ones included
+//   - /q/health/live — the process is serving; nothing here is fixed by a
+//     restart, so no component can fail liveness and crash-loop the pod
+//   - /q/health/ready — readiness checks only
+//
+// Any DOWN answers 503 with the full breakdown; DEGRADED still answers 200.
+func (a *HealthAggregator) Mount(r chi.Router) {
+	r.Get("/q/health", a.handler(false))
+	r.Get("/q/health/ready", a.handler(true))
+	r.Get("/q/health/live", func(w http.ResponseWriter, _ *http.Request) {
+		writeHealthReport(w, HealthReport{Status: healthUp, Checks: []HealthCheck{}})
+	})
+}
+
+func (a *HealthAggregator) handler(readinessOnly bool) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		writeHealthReport(w, a.Report(r.Context(), readinessOnly))
+	}
+}
+
+func writeHealthReport(w http.ResponseWriter, report HealthReport) {
+	code := http.StatusOK
+	if report.Status == healthDown {
+		code = http.StatusServiceUnavailable
+	}
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(code)
+	_ = json.NewEncoder(w).Encode(report)
+}
@@ -0,0 +1,135 @@
+package server
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/degrade"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbox"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/stream"
+)
+
+// pingHealth adapts a connectivity ping (Redis, Postgres) into a provider.
+func pingHealth(ping func(context.Context) error) HealthProvider {
+	return func(ctx context.Context) (map[string]any, error) {
+		return nil, ping(ctx)
+	}
+}
+
+// redisDegradationHealth reports each Redis-backed feature's degrade
+// state, DEGRADED while any of them operates without Redis.
+func redisDegradationHealth(reg *degrade.Registry) HealthProvider {
+	return func(context.Context) (map[string]any, error) {
+		features := reg.Snapshot()
+		var degraded []string
+		for _, f := range features {
+			if f.Degraded {
+				degraded = append(degraded, f.Name)
+			}
+		}
+		data := map[string]any{"features": features}
+		if len(degraded) > 0 {
+			return data, fmt.Errorf("operating without redis: %v: %w", degraded, errDegraded)
+		}
+		return data, nil
+	}
+}
+
+// routerHealth reports the in-process router's consumers and pools using
+// the same verdict as its own /health/ready: only DEGRADED (every pool or
+// every consumer failing, or a critical warning) is DOWN. A standby
+// follower runs no consumers, so it is UP regardless.
+func routerHealth(srv *router.Server) HealthProvider {
+	return func(context.Context) (map[string]any, error) {
+		stats := srv.Manager.PoolStats()
+		report := srv.Health.HealthReport(stats)
+		leader := srv.IsLeader()
+		data := map[string]any{
+			"leader":             leader,
+			"status":             string(report.Status),
+			"poolsHealthy":       report.PoolsHealthy,
+			"poolsUnhealthy":     report.PoolsUnhealthy,
+			"consumersHealthy":   report.ConsumersHealthy,
+			"consumersUnhealthy": report.ConsumersUnhealthy,
+			"stalledConsumers":   srv.Health.StalledConsumers(),
+			"activeWarnings":     report.ActiveWarnings,
+			"criticalWarnings":   report.CriticalWarnings,
+		}
+		if leader && report.Status == router.HealthDegraded {
+			return data, fmt.Errorf("router degraded: %v", report.Issues)
+		}
+		return data, nil
+	}
+}
+
+// streamCheckpointHealth reports each projection's checkpoint state plus
+// how far behind the oldest unprocessed row is for every poller. Lag is
+// informational — a burst legitimately builds a backlog — so the check is
+// DOWN only when the lag query itself fails. Projections that stopped are
+// already covered by the stream subsystem check.
+func streamCheckpointHealth(pool *pgxpool.Pool, svc *stream.HealthService) HealthProvider {
+	return func(ctx context.Context) (map[string]any, error) {
+		agg := svc.Aggregate()
+		data := map[string]any{"streams": agg.Streams}
+		// Each subquery is served by its partial index (migration 019).
+		var events, fanOut, dispatchJobs *float64
+		err := pool.QueryRow(ctx, `
+			SELECT
+			  (SELECT EXTRACT(EPOCH FROM now() - min(created_at))::float8 FROM msg_events WHERE projected_at IS NULL),
+			  (SELECT EXTRACT(EPOCH FROM now() - min(created_at))::float8 FROM msg_events WHERE fanned_out_at IS NULL),
+			  (SELECT EXTRACT(EPOCH FROM now() - min(created_at))::float8 FROM msg_dispatch_jobs WHERE projected_at IS NULL)`,
+		).Scan(&events, &fanOut, &dispatchJobs)
+		if err != nil {
+			return data, fmt.Errorf("checkpoint lag: %w", err)
+		}
+		data["lagSeconds"] = map[string]float64{
+			"events":       lagOrZero(events),
+			"fanOut":       lagOrZero(fanOut),
+			"dispatchJobs": lagOrZero(dispatchJobs),
+		}
+		return data, nil
+	}
+}
+
+func lagOrZero(v *float64) float64 {
+	if v == nil {
+		return 0
+	}
+	return *v
+}
+
+// outboxHealth reports the outbox processor's counters, backend
+// connectivity and, when the backend supports it, the age of the oldest
+// pending item.
+func outboxHealth(backend string, repo outbox.Repository, p *outbox.Processor) HealthProvider {
+	return func(ctx context.Context) (map[string]any, error) {
+		succeeded, failed := p.Totals()
+		data := map[string]any{
+			"backend":       backend,
+			"inFlight":      p.InFlight(),
+			"succeeded":     succeeded,
+			"failed":        failed,
+			"blockedGroups": len(p.BlockedGroups()),
+		}
+		if !repo.Healthy(ctx) {
+			return data, errors.New("outbox backend unreachable")
+		}
+		if lr, ok := repo.(outbox.LagReporter); ok {
+			at, pending, err := lr.OldestPending(ctx)
+			if err != nil {
+				return data, fmt.Errorf("outbox lag: %w", err)
+			}
+			lag := 0.0
+			if pending {
+				lag = time.Since(at).Seconds()
+			}
+			data["lagSeconds"] = lag
+		}
+		return data, nil
+	}
+}
@@ -0,0 +1,189 @@
+package server
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/go-chi/chi/v5"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/degrade"
+)
+
+func TestSubsystemRegistrySnapshot(t *testing.T) {
+	reg := newSubsystemRegistry()
+	if snap, ready := reg.Snapshot(context.Background()); !ready || len(snap) != 0 {
+		t.Fatalf("empty registry = %v, %v; want ready", snap, ready)
+	}
+
+	sched := reg.add("scheduler")
+	sched.running.Store(true)
+	reg.setLeader("scheduler", func() bool { return false })
+	reg.setLeader("unknown", func() bool { return true }) // no-op
+
+	snap, ready := reg.Snapshot(context.Background())
+	if !ready || len(snap) != 1 {
+		t.Fatalf("standby scheduler = %+v, %v; want ready", snap, ready)
+	}
+	if snap[0].Leader == nil || *snap[0].Leader {
+		t.Fatalf("leader = %v, want false", snap[0].Leader)
+	}
+
+	db := reg.add("platform")
+	db.running.Store(true)
+	db.probe = func(context.Context) error { return errors.New("ping: connection refused") }
+	if reg.add("platform") != db {
+		t.Fatal("re-adding a name should return the existing entry")
+	}
+	snap, ready = reg.Snapshot(context.Background())
+	if ready || snap[1].Healthy || snap[1].Error != "ping: connection refused" {
+		t.Fatalf("failing probe = %+v, %v", snap, ready)
+	}
+
+	db.probe = nil
+	sched.fail("exited unexpectedly; see logs")
+	sched.running.Store(false)
+	snap, ready = reg.Snapshot(context.Background())
+	if ready || snap[0].Healthy || snap[0].Running || snap[0].Error == "" {
+		t.Fatalf("exited scheduler = %+v, %v", snap, ready)
+	}
+}
+
+func TestReadyAggregatesSubsystems(t *testing.T) {
+	agg := NewHealthAggregator()
+	router := agg.subsystems.add("router")
+	router.running.Store(true)
+	h := metricsRouter(EnvCfg{RouterEnabled: true}, agg, nil, nil)
+
+	get := func() (int, map[string]any) {
+		rec := httptest.NewRecorder()
+		h.ServeHTTP(rec, httptest.NewRequest(http.MethodGet, "/ready", nil))
+		var body map[string]any
+		if err := json.Unmarshal(rec.Body.Bytes(), &body); err != nil {
+			t.Fatalf("decode /ready: %v", err)
+		}
+		return rec.Code, body
+	}
+
+	code, body := get()
+	if code != http.StatusOK || body["status"] != "ready" || body["router"] != true {
+		t.Fatalf("healthy /ready = %d %v", code, body)
+	}
+	if subs, _ := body["subsystems"].([]any); len(subs) != 1 {
+		t.Fatalf("subsystems = %v", body["subsystems"])
+	}
+
+	router.running.Store(false)
+	code, body = get()
+	if code != http.StatusServiceUnavailable || body["status"] != "not_ready" {
+		t.Fatalf("degraded /ready = %d %v", code, body)
+	}
+}
+
+func TestHealthAggregatorReport(t *testing.T) {
+	agg := NewHealthAggregator()
+	sched := agg.subsystems.add("scheduler")
+	sched.running.Store(true)
+	agg.subsystems.setLeader("scheduler", func() bool { return false })
+
+	redisErr := errors.New("dial tcp: connection refused")
+	agg.Register("standby-redis", false, pingHealth(func(context.Context) error { return redisErr }))
+	agg.Register("standby-redis", false, pingHealth(func(context.Context) error { return nil })) // first wins
+	routerDown := false
+	agg.Register("router-consumers", true, func(context.Context) (map[string]any, error) {
+		if routerDown {
+			return map[string]any{"consumersHealthy": 0}, errors.New("router degraded")
+		}
+		return map[string]any{"consumersHealthy": 2}, nil
+	})
+
+	// Informational components fail the full report but not readiness.
+	full := agg.Report(context.Background(), false)
+	if full.Status != healthDown || len(full.Checks) != 3 {
+		t.Fatalf("full report = %+v", full)
+	}
+	if c := full.Checks[0]; c.Name != "scheduler" || c.Status != healthUp || c.Data["leader"] != false {
+		t.Fatalf("standby scheduler check = %+v", c)
+	}
+	if c := full.Checks[1]; c.Name != "standby-redis" || c.Data["error"] != redisErr.Error() {
+		t.Fatalf("redis check = %+v", c)
+	}
+	ready := agg.Report(context.Background(), true)
+	if ready.Status != healthUp || len(ready.Checks) != 2 {
+		t.Fatalf("readiness report = %+v", ready)
+	}
+
+	routerDown = true
+	ready = agg.Report(context.Background(), true)
+	if ready.Status != healthDown || ready.Checks[1].Data["consumersHealthy"] != 0 {
+		t.Fatalf("degraded router readiness = %+v", ready)
+	}
+}
+
+func TestHealthAggregatorMount(t *testing.T) {
+	agg := NewHealthAggregator()
+	e := agg.subsystems.add("outbox")
+	e.running.Store(true)
+	r := chi.NewRouter()
+	agg.Mount(r)
+
+	get := func(path string) (int, HealthReport) {
+		rec := httptest.NewRecorder()
+		r.ServeHTTP(rec, httptest.NewRequest(http.MethodGet, path, nil))
+		var body HealthReport
+		if err := json.Unmarshal(rec.Body.Bytes(), &body); err != nil {
+			t.Fatalf("decode %s: %v", path, err)
+		}
+		return rec.Code, body
+	}
+
+	if code, body := get("/q/health/ready"); code != http.StatusOK || body.Status != healthUp || len(body.Checks) != 1 {
+		t.Fatalf("ready = %d %+v", code, body)
+	}
+	e.fail("exited unexpectedly; see logs")
+	if code, body := get("/q/health"); code != http.StatusServiceUnavailable || body.Status != healthDown {
+		t.Fatalf("health = %d %+v", code, body)
+	}
+	if code, body := get("/q/health/ready"); code != http.StatusServiceUnavailable || body.Checks[0].Data["error"] == nil {
+		t.Fatalf("ready after failure = %d %+v", code, body)
+	}
+	// Liveness never reflects components: restarting wouldn't fix them.
+	if code, body := get("/q/health/live"); code != http.StatusOK || body.Status != healthUp {
+		t.Fatalf("live = %d %+v", code, body)
+	}
+}
+
+func TestRedisDegradationIsDegradedNotDown(t *testing.T) {
+	reg := degrade.NewRegistry(nil)
+	f := reg.Register("rate-limit", degrade.FailOpen, false)
+	agg := NewHealthAggregator()
+	agg.Register("redis-degradation", false, redisDegradationHealth(reg))
+	r := chi.NewRouter()
+	agg.Mount(r)
+
+	get := func() (int, HealthReport) {
+		rec := httptest.NewRecorder()
+		r.ServeHTTP(rec, httptest.NewRequest(http.MethodGet, "/q/health", nil))
+		var body HealthReport
+		if err := json.Unmarshal(rec.Body.Bytes(), &body); err != nil {
+			t.Fatalf("decode: %v", err)
+		}
+		return rec.Code, body
+	}
+
+	if code, body := get(); code != http.StatusOK || body.Status != healthUp {
+		t.Fatalf("before outage = %d %+v", code, body)
+	}
+	f.Observe(errors.New("dial tcp: connection refused"))
+	code, body := get()
+	if code != http.StatusOK || body.Status != healthDegraded || body.Checks[0].Status != healthDegraded {
+		t.Fatalf("during outage = %d %+v", code, body)
+	}
+	f.Observe(nil)
+	if code, body := get(); code != http.StatusOK || body.Status != healthUp {
+		t.Fatalf("after recovery = %d %+v", code, body)
+	}
+}
@@ -1,10 +1,18 @@
 package server
 
 import (
+	"crypto/subtle"
 	"encoding/json"
+	"net"
 	"net/http"
+	"strings"
 
 	"github.com/go-chi/chi/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+	"github.com/prometheus/client_golang/prometheus"
+	"github.com/prometheus/client_golang/prometheus/promhttp"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/logging"
 )
 
 // swaggerUIHTML is a minimal Swagger UI page (served at /swagger-ui) that
@@ -38,17 +46,34 @@ func healthHandler(w http.ResponseWriter, _ *http.Request) {
 }
 
 // metricsRouter builds the /metrics + /ready + /health surface bound to
-// the metrics port. Detailed router/pool Prometheus series live under
-// the router prefix on the API port via routerapi.PrometheusHandler —
-// this router stays a small "is the binary up" target until we add
-// platform-level Prometheus exporters.
-func metricsRouter(cfg EnvCfg) http.Handler {
+// the metrics port, plus the /admin/log-level and (with a pool)
+// /admin/indexes operator endpoints. /metrics serves the platform-level
+// series registered on metrics (the read-model caches; nil serves an
+// empty registry). Detailed router/pool Prometheus series live under the
+// router prefix on the API port via routerapi.PrometheusHandler.
+//
+// /health is liveness (the process is serving). /ready aggregates every
+// subsystem Run started — running, leader state, DB ping, projection
+// health — and answers 503 when any of them is down, so an all-in-one
+// deployment fails its readiness probe instead of silently losing a
+// component. The /q/health trio adds the component breakdown (see
+// HealthAggregator.Mount) for probes that can't reach the API port.
+func metricsRouter(cfg EnvCfg, health *HealthAggregator, pool *pgxpool.Pool, metrics *prometheus.Registry) http.Handler {
 	r := chi.NewRouter()
 	r.Get("/health", healthHandler)
-	r.Get("/ready", func(w http.ResponseWriter, _ *http.Request) {
+	r.Get("/ready", func(w http.ResponseWriter, req *http.Request) {
+		snapshot, ready := []SubsystemHealth{}, true
+		if health != nil {
+			snapshot, ready = health.subsystems.Snapshot(req.Context())
+		}
+		status, code := "ready", http.StatusOK
+		if !ready {
+			status, code = "not_ready", http.StatusServiceUnavailable
+		}
 		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(code)
 		_ = json.NewEncoder(w).Encode(map[string]any{
-			"status":        "ready",
+			"status":        status,
 			"platform":      cfg.PlatformEnabled,
 			"router":        cfg.RouterEnabled,
 			"scheduler":     cfg.SchedulerEnabled,
@@ -56,14 +81,53 @@ func metricsRouter(cfg EnvCfg) http.Handler {
 			"stream":        cfg.StreamEnabled,
 			"outbox":        cfg.OutboxEnabled,
 			"mcp":           cfg.MCPEnabled,
+			"subsystems":    snapshot,
 		})
 	})
-	r.Get("/metrics", func(w http.ResponseWriter, _ *http.Request) {
-		w.Header().Set("Content-Type", "text/plain; charset=utf-8")
-		// Platform-level Prometheus exporters are still on the to-do
-		// list; router-level metrics live at <prefix>/metrics under the
-		// API port.
-		_, _ = w.Write([]byte("# fc-server metrics placeholder\n"))
-	})
+	if metrics == nil {
+		metrics = prometheus.NewRegistry()
+	}
+	r.Handle("/metrics", promhttp.HandlerFor(metrics, promhttp.HandlerOpts{
+		ErrorHandling: promhttp.ContinueOnError,
+	}))
+	if health != nil {
+		health.Mount(r)
+	}
+	levels := logAdminGuard(cfg.LogAdminToken, logging.AdminHandler(logging.Filter()))
+	r.Get("/admin/log-level", levels.ServeHTTP)
+	r.Put("/admin/log-level", levels.ServeHTTP)
+	if pool != nil {
+		r.Get("/admin/indexes", logAdminGuard(cfg.LogAdminToken, indexDriftHandler(pool)).ServeHTTP)
+		r.Get("/admin/migrations", logAdminGuard(cfg.LogAdminToken, migrationStatusHandler(pool)).ServeHTTP)
+	}
 	return r
 }
+
+// logAdminGuard gates the operator endpoints. The metrics port is usually
+// reachable by scrapers, so with no FC_LOG_ADMIN_TOKEN configured only
+// loopback callers (kubectl port-forward, ECS exec) get through; with a
+// token, any caller presenting it as a bearer token does.
+func logAdminGuard(token string, next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if token != "" {
+			got, ok := strings.CutPrefix(r.Header.Get("Authorization"), "Bearer ")
+			if !ok || subtle.ConstantTimeCompare([]byte(got), []byte(token)) != 1 {
+				w.WriteHeader(http.StatusUnauthorized)
+				return
+			}
+		} else if !isLoopback(r.RemoteAddr) {
+			w.WriteHeader(http.StatusForbidden)
+			return
+		}
+		next.ServeHTTP(w, r)
+	})
+}
+
+func isLoopback(remoteAddr string) bool {
+	host, _, err := net.SplitHostPort(remoteAddr)
+	if err != nil {
+		host = remoteAddr
+	}
+	ip := net.ParseIP(host)
+	return ip != nil && ip.IsLoopback()
+}
@@ -0,0 +1,160 @@
+package server
+
+import (
+	"fmt"
+	"net"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/danielgtaylor/huma/v2"
+	"github.com/prometheus/client_golang/prometheus"
+
+	platformmw "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/middleware"
+)
+
+// rejectConnectionLimit is the OnReject reason for a connection refused
+// by limitListener; the body-level reasons live in platformmw.
+const rejectConnectionLimit = "connection_limit"
+
+// batchBodyPrefixes are the bulk ingest endpoints, which get
+// FC_HTTP_BATCH_MAX_BODY_BYTES instead of the default cap. The /bff
+// event mirror is the SPA's fan-out of the same batch endpoint.
+var batchBodyPrefixes = []string{
+	"/api/events/batch",
+	"/bff/events/batch",
+	"/api/dispatch-jobs/batch",
+	"/api/audit-logs/batch",
+}
+
+// bodyLimits builds the API listener's body limits from cfg. OnReject is
+// left for Run to attach.
+func bodyLimits(cfg EnvCfg) platformmw.LimitsConfig {
+	lc := platformmw.LimitsConfig{
+		MaxBodyBytes:    cfg.HTTPMaxBodyBytes,
+		BodyReadTimeout: time.Duration(cfg.HTTPBodyReadTimeoutSecs) * time.Second,
+	}
+	for _, p := range batchBodyPrefixes {
+		lc.Groups = append(lc.Groups, platformmw.BodyLimitGroup{Prefix: p, MaxBytes: cfg.HTTPBatchMaxBodyBytes})
+	}
+	return lc
+}
+
+// applyBodyLimits makes huma enforce the same per-route limits as the
+// Limits middleware. huma otherwise caps every body at a flat 1 MiB with
+// a 5s read timeout, which would refuse a batch the listener let through.
+// prefix is where the API is mounted, since operation paths are relative
+// to it.
+func applyBodyLimits(api huma.API, prefix string, lc platformmw.LimitsConfig) {
+	oapi := api.OpenAPI()
+	oapi.OnAddOperation = append(oapi.OnAddOperation, func(_ *huma.OpenAPI, op *huma.Operation) {
+		// huma reads 0 as "use the default" and -1 as "no limit".
+		op.MaxBodyBytes, op.BodyReadTimeout = lc.MaxBodyBytesFor(prefix+op.Path), lc.BodyReadTimeout
+		if op.MaxBodyBytes == 0 {
+			op.MaxBodyBytes = -1
+		}
+		if op.BodyReadTimeout == 0 {
+			op.BodyReadTimeout = -1
+		}
+	})
+}
+
+// httpRejections counts requests and connections the listeners refused
+// before a handler answered them.
+type httpRejections struct {
+	total *prometheus.CounterVec
+}
+
+func newHTTPRejections(reg prometheus.Registerer) *httpRejections {
+	total := prometheus.NewCounterVec(prometheus.CounterOpts{
+		Name: "fc_http_rejected_requests_total",
+		Help: "Requests refused by listener limits (body size, body read timeout, connection cap).",
+	}, []string{"listener", "reason"})
+	reg.MustRegister(total)
+	return &httpRejections{total: total}
+}
+
+// For returns an OnReject callback labelled with listener.
+func (h *httpRejections) For(listener string) func(reason string) {
+	return func(reason string) { h.total.WithLabelValues(listener, reason).Inc() }
+}
+
+// newHTTPServer applies the configured header, read and idle timeouts.
+// There is deliberately no WriteTimeout: SSE and long-poll responses
+// stay open for as long as the client listens.
+func newHTTPServer(addr string, h http.Handler, cfg EnvCfg) *http.Server {
+	return &http.Server{
+		Addr:              addr,
+		Handler:           h,
+		ReadHeaderTimeout: time.Duration(cfg.HTTPReadHeaderTimeoutSecs) * time.Second,
+		ReadTimeout:       time.Duration(cfg.HTTPReadTimeoutSecs) * time.Second,
+		IdleTimeout:       time.Duration(cfg.HTTPIdleTimeoutSecs) * time.Second,
+	}
+}
+
+// listenLimited opens addr and, when maxConns > 0, caps concurrent
+// connections at maxConns.
+func listenLimited(addr string, maxConns int, onReject func(string)) (net.Listener, error) {
+	ln, err := net.Listen("tcp", addr)
+	if err != nil {
+		return nil, err
+	}
+	if maxConns <= 0 {
+		return ln, nil
+	}
+	return &limitListener{Listener: ln, slots: make(chan struct{}, maxConns), onReject: onReject}, nil
+}
+
+// limitListener refuses a connection over the cap with a bare 503 and
+// closes it, rather than leaving it queued in the accept backlog where a
+// flood of idle clients would starve everyone else.
+type limitListener struct {
+	net.Listener
+	slots    chan struct{}
+	onReject func(string)
+}
+
+func (l *limitListener) Accept() (net.Conn, error) {
+	for {
+		c, err := l.Listener.Accept()
+		if err != nil {
+			return nil, err
+		}
+		select {
+		case l.slots <- struct{}{}:
+			return &limitConn{Conn: c, release: func() { <-l.slots }}, nil
+		default:
+			if l.onReject != nil {
+				l.onReject(rejectConnectionLimit)
+			}
+			go refuseConn(c)
+		}
+	}
+}
+
+// connRefusal is written raw: the connection never reaches net/http.
+var connRefusal = func() []byte {
+	body := `{"error":"TOO_MANY_CONNECTIONS","message":"Server connection limit reached"}`
+	return []byte(fmt.Sprintf("HTTP/1.1 503 Service Unavailable\r\n"+
+		"Content-Type: application/json\r\nContent-Length: %d\r\nRetry-After: 1\r\nConnection: close\r\n\r\n%s",
+		len(body), body))
+}()
+
+func refuseConn(c net.Conn) {
+	_ = c.SetWriteDeadline(time.Now().Add(time.Second))
+	_, _ = c.Write(connRefusal)
+	_ = c.Close()
+}
+
+// limitConn frees its slot on the first Close (net/http may close twice).
+type limitConn struct {
+	net.Conn
+	once    sync.Once
+	release func()
+}
+
+func (c *limitConn) Close() error {
+	err := c.Conn.Close()
+	c.once.Do(c.release)
+	return err
+}
@@ -0,0 +1,89 @@
+package server
+
+import (
+	"bufio"
+	"net"
+	"net/http"
+	"sync/atomic"
+	"testing"
+	"time"
+)
+
+func TestLimitListenerRefusesOverCap(t *testing.T) {
+	var rejected atomic.Int32
+	ln, err := listenLimited("127.0.0.1:0", 1, func(reason string) {
+		if reason == rejectConnectionLimit {
+			rejected.Add(1)
+		}
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = ln.Close() }()
+
+	accepted := make(chan net.Conn, 2)
+	go func() {
+		for {
+			c, err := ln.Accept()
+			if err != nil {
+				return
+			}
+			accepted <- c
+		}
+	}()
+	dial := func() net.Conn {
+		c, err := net.Dial("tcp", ln.Addr().String())
+		if err != nil {
+			t.Fatal(err)
+		}
+		t.Cleanup(func() { _ = c.Close() })
+		return c
+	}
+	next := func() net.Conn {
+		select {
+		case c := <-accepted:
+			return c
+		case <-time.After(2 * time.Second):
+			t.Fatal("connection not accepted")
+			return nil
+		}
+	}
+
+	dial()
+	held := next()
+
+	over := dial()
+	_ = over.SetReadDeadline(time.Now().Add(2 * time.Second))
+	resp, err := http.ReadResponse(bufio.NewReader(over), nil)
+	if err != nil {
+		t.Fatalf("read refusal: %v", err)
+	}
+	_ = resp.Body.Close()
+	if resp.StatusCode != http.StatusServiceUnavailable || !resp.Close {
+		t.Errorf("refusal status=%d close=%v want 503 + Connection: close", resp.StatusCode, resp.Close)
+	}
+	if rejected.Load() != 1 {
+		t.Errorf("rejections=%d want 1", rejected.Load())
+	}
+
+	// Closing the held connection frees its slot.
+	_ = held.Close()
+	dial()
+	_ = next().Close()
+}
+
+func TestBodyLimits(t *testing.T) {
+	lc := bodyLimits(EnvCfg{HTTPMaxBodyBytes: 1 << 20, HTTPBatchMaxBodyBytes: 10 << 20})
+	for path, want := range map[string]int64{
+		"/api/clients":             1 << 20,
+		"/api/events":              1 << 20,
+		"/api/events/batch":        10 << 20,
+		"/bff/events/batch":        10 << 20,
+		"/api/dispatch-jobs/batch": 10 << 20,
+		"/api/audit-logs/batch":    10 << 20,
+	} {
+		if got := lc.MaxBodyBytesFor(path); got != want {
+			t.Errorf("MaxBodyBytesFor(%q)=%d want %d", path, got, want)
+		}
+	}
+}
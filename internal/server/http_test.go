@@ -0,0 +1,44 @@
+package server
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+)
+
+func TestLogAdminGuard(t *testing.T) {
+	ok := http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) { w.WriteHeader(http.StatusOK) })
+
+	call := func(h http.Handler, remote, auth string) int {
+		req := httptest.NewRequest(http.MethodGet, "/admin/log-level", nil)
+		req.RemoteAddr = remote
+		if auth != "" {
+			req.Header.Set("Authorization", auth)
+		}
+		rec := httptest.NewRecorder()
+		h.ServeHTTP(rec, req)
+		return rec.Code
+	}
+
+	open := logAdminGuard("", ok)
+	if got := call(open, "127.0.0.1:5000", ""); got != http.StatusOK {
+		t.Errorf("loopback without token: got %d", got)
+	}
+	if got := call(open, "[::1]:5000", ""); got != http.StatusOK {
+		t.Errorf("ipv6 loopback without token: got %d", got)
+	}
+	if got := call(open, "10.0.0.7:5000", ""); got != http.StatusForbidden {
+		t.Errorf("remote without token: got %d", got)
+	}
+
+	guarded := logAdminGuard("s3cret", ok)
+	if got := call(guarded, "10.0.0.7:5000", "Bearer s3cret"); got != http.StatusOK {
+		t.Errorf("remote with token: got %d", got)
+	}
+	if got := call(guarded, "127.0.0.1:5000", ""); got != http.StatusUnauthorized {
+		t.Errorf("loopback without token when one is configured: got %d", got)
+	}
+	if got := call(guarded, "10.0.0.7:5000", "Bearer wrong"); got != http.StatusUnauthorized {
+		t.Errorf("wrong token: got %d", got)
+	}
+}
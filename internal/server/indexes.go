@@ -0,0 +1,59 @@
+package server
+
+import (
+	"context"
+	"encoding/json"
+	"log/slog"
+	"net/http"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/migrate"
+)
+
+// LogIndexDrift compares the live indexes with the ones the migrations
+// declare and warns about any difference. Creating indexes stays with the
+// migrations (goose already makes that idempotent); this catches an index
+// dropped or hand-added out of band, which would otherwise only show up
+// as slow repository queries.
+func LogIndexDrift(ctx context.Context, pool *pgxpool.Pool) {
+	drift, err := migrate.CheckIndexes(ctx, pool)
+	if err != nil {
+		slog.Warn("index drift check failed", "err", err)
+		return
+	}
+	if drift.InSync() {
+		slog.Info("schema indexes match migrations", "indexes", drift.Expected)
+		return
+	}
+	for _, ix := range drift.Missing {
+		slog.Warn("index declared by migrations is missing", "index", ix.Name, "table", ix.Table, "definition", ix.Definition)
+	}
+	for _, ix := range drift.Extra {
+		slog.Warn("index not declared by any migration", "index", ix.Name, "table", ix.Table)
+	}
+}
+
+// indexDriftHandler serves the drift report: 200 when the schema matches
+// the migrations, 409 with the missing / extra lists otherwise. Missing
+// entries carry their CREATE INDEX statement so an operator can rebuild
+// them (CONCURRENTLY, on a live table).
+func indexDriftHandler(pool *pgxpool.Pool) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		drift, err := migrate.CheckIndexes(r.Context(), pool)
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+		w.Header().Set("Content-Type", "application/json")
+		if !drift.InSync() {
+			w.WriteHeader(http.StatusConflict)
+		}
+		_ = json.NewEncoder(w).Encode(map[string]any{
+			"inSync":   drift.InSync(),
+			"expected": drift.Expected,
+			"missing":  drift.Missing,
+			"extra":    drift.Extra,
+		})
+	}
+}
@@ -0,0 +1,54 @@
+package server
+
+import (
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+// lifecycleWarnSeenMax bounds the set of (principal, event type, state)
+// keys already warned about; past it the set starts over, so a key may
+// warn again after many distinct others have.
+const lifecycleWarnSeenMax = 10_000
+
+// lifecycleWarnings turns publishes of deprecated or sunset event types
+// into CONFIGURATION warnings on ws, once per principal, event type and
+// lifecycle state: a producer that keeps publishing a deprecated type
+// raises one warning, and another when the type reaches its sunset.
+func lifecycleWarnings(ws *router.WarningService) func(eventtype.LifecycleUse) {
+	type key struct {
+		principal, code string
+		sunset          bool
+	}
+	var mu sync.Mutex
+	seen := map[key]bool{}
+	return func(u eventtype.LifecycleUse) {
+		k := key{u.PrincipalID, u.Code, u.Sunset}
+		mu.Lock()
+		if seen[k] {
+			mu.Unlock()
+			return
+		}
+		if len(seen) >= lifecycleWarnSeenMax {
+			seen = map[key]bool{}
+		}
+		seen[k] = true
+		mu.Unlock()
+
+		severity := router.WarningWarning
+		msg := "principal " + u.PrincipalID + " is publishing deprecated event type " + u.Code
+		if u.Sunset {
+			severity = router.WarningError
+			msg = "principal " + u.PrincipalID + " is publishing event type " + u.Code +
+				", rejected since its sunset on " + u.Lifecycle.SunsetAt.UTC().Format(time.RFC3339)
+		} else if u.Lifecycle.SunsetAt != nil {
+			msg += " (sunset " + u.Lifecycle.SunsetAt.UTC().Format(time.RFC3339) + ")"
+		}
+		if u.Lifecycle.MigrationHint != nil {
+			msg += "; " + *u.Lifecycle.MigrationHint
+		}
+		ws.Add(router.WarningCategoryConfiguration, severity, msg, "platform-events")
+	}
+}
@@ -0,0 +1,45 @@
+package server
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+func TestLifecycleWarnings(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	cache := eventtype.NewLifecycleCache(nil, 0)
+	cache.OnUse(lifecycleWarnings(ws))
+
+	sunset := time.Now().Add(-time.Hour)
+	deprecated := eventtype.Lifecycle{DeprecatedAt: time.Now().Add(-24 * time.Hour)}
+	for i := 0; i < 3; i++ {
+		cache.ReportUse(eventtype.LifecycleUse{PrincipalID: "svc_a", Code: "orders:order:created", Lifecycle: deprecated})
+	}
+	if got := ws.Count(); got != 1 {
+		t.Fatalf("warnings after repeated use = %d, want 1", got)
+	}
+
+	cache.ReportUse(eventtype.LifecycleUse{PrincipalID: "svc_b", Code: "orders:order:created", Lifecycle: deprecated})
+	cache.ReportUse(eventtype.LifecycleUse{PrincipalID: "svc_a", Code: "orders:order:created",
+		Lifecycle: eventtype.Lifecycle{DeprecatedAt: deprecated.DeprecatedAt, SunsetAt: &sunset}, Sunset: true})
+	cache.ReportUse(eventtype.LifecycleUse{PrincipalID: "svc_a", Code: "orders:order:created",
+		Lifecycle: eventtype.Lifecycle{DeprecatedAt: deprecated.DeprecatedAt, SunsetAt: &sunset}, Sunset: true})
+
+	got := ws.ByCategory(router.WarningCategoryConfiguration)
+	if len(got) != 3 {
+		t.Fatalf("warnings = %d, want 3 (one per principal, event type and state)", len(got))
+	}
+	var sawSunset bool
+	for _, w := range got {
+		if strings.Contains(w.Message, "svc_a") && strings.Contains(w.Message, "sunset on") {
+			sawSunset = w.Severity == router.WarningError
+		}
+	}
+	if !sawSunset {
+		t.Errorf("messages = %+v", got)
+	}
+}
@@ -0,0 +1,45 @@
+package server
+
+import (
+	"fmt"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+// lockoutCooldown is how long lockouts of a given kind stay quiet after
+// one raised a warning. A credential spray locks many emails at once and
+// would otherwise raise a warning (and a webhook) per email; every lockout
+// is still logged and audited.
+const lockoutCooldown = time.Minute
+
+// lockoutEscalatedLevel is the lock level from which a warning is raised
+// as an ERROR: the subject kept failing through earlier locks.
+const lockoutEscalatedLevel = 3
+
+// lockoutWarnings turns credential lockouts into SECURITY warnings on ws,
+// at most one per subject kind per lockoutCooldown.
+func lockoutWarnings(ws *router.WarningService) func(lockout.Lock) {
+	var mu sync.Mutex
+	last := map[lockout.Kind]time.Time{}
+	return func(l lockout.Lock) {
+		now := time.Now()
+		mu.Lock()
+		if t, ok := last[l.Subject.Kind]; ok && now.Sub(t) < lockoutCooldown {
+			mu.Unlock()
+			return
+		}
+		last[l.Subject.Kind] = now
+		mu.Unlock()
+
+		severity := router.WarningWarning
+		if l.Level >= lockoutEscalatedLevel {
+			severity = router.WarningError
+		}
+		msg := fmt.Sprintf("%s locked out after repeated authentication failures (lock %d, until %s)",
+			l.Subject, l.Level, l.LockedUntil.UTC().Format(time.RFC3339))
+		ws.Add(router.WarningCategorySecurity, severity, msg, "platform-auth")
+	}
+}
@@ -0,0 +1,37 @@
+package server
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+func TestLockoutWarnings(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	warn := lockoutWarnings(ws)
+
+	until := time.Now().Add(5 * time.Minute)
+	warn(lockout.Lock{Subject: lockout.Principal("a@example.com"), Level: 1, LockedUntil: until})
+	warn(lockout.Lock{Subject: lockout.Principal("b@example.com"), Level: 1, LockedUntil: until}) // same kind inside the cooldown: suppressed
+	warn(lockout.Lock{Subject: lockout.IP("10.0.0.1"), Level: 3, LockedUntil: until})
+
+	got := ws.ByCategory(router.WarningCategorySecurity)
+	if len(got) != 2 {
+		t.Fatalf("warnings = %d, want 2", len(got))
+	}
+	var sawPrincipal, sawIP bool
+	for _, w := range got {
+		if strings.HasPrefix(w.Message, "PRINCIPAL:a@example.com locked out") {
+			sawPrincipal = w.Severity == router.WarningWarning
+		}
+		if strings.HasPrefix(w.Message, "IP:10.0.0.1 locked out") {
+			sawIP = w.Severity == router.WarningError
+		}
+	}
+	if !sawPrincipal || !sawIP {
+		t.Errorf("messages = %+v", got)
+	}
+}
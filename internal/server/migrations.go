@@ -0,0 +1,47 @@
+package server
+
+import (
+	"context"
+	"encoding/json"
+	"log/slog"
+	"net/http"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/migrate"
+)
+
+// LogMigrationPlan is FC_MIGRATE_DRY_RUN: it logs which migrations a real
+// start would apply, changing nothing.
+func LogMigrationPlan(ctx context.Context, pool *pgxpool.Pool) error {
+	st, err := migrate.CheckMigrations(ctx, pool)
+	if err != nil {
+		return err
+	}
+	for _, m := range st.Pending {
+		slog.Info("migrate (dry run): would apply", "version", m.Version, "name", m.Name)
+	}
+	if len(st.Unknown) > 0 {
+		slog.Warn("migrate (dry run): database has migrations this build doesn't ship", "versions", st.Unknown)
+	}
+	slog.Info("migrate (dry run): done", "current", st.Current, "pending", len(st.Pending))
+	return nil
+}
+
+// migrationStatusHandler serves the database's migration status: 200 when
+// nothing is pending, 409 with the pending list when the schema is behind
+// this build.
+func migrationStatusHandler(pool *pgxpool.Pool) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		st, err := migrate.CheckMigrations(r.Context(), pool)
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+		w.Header().Set("Content-Type", "application/json")
+		if !st.UpToDate() {
+			w.WriteHeader(http.StatusConflict)
+		}
+		_ = json.NewEncoder(w).Encode(st)
+	}
+}
@@ -0,0 +1,128 @@
+package server
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"net"
+	"net/netip"
+	"net/url"
+	"os"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+)
+
+// outboundPolicies builds the SSRF policy for webhook deliveries and
+// subscription endpoints from the FC_OUTBOUND_* settings.
+func outboundPolicies(cfg EnvCfg) (*outbound.Policies, error) {
+	nets, err := outbound.ParseNets(cfg.OutboundAllowedNets)
+	if err != nil {
+		return nil, fmt.Errorf("FC_OUTBOUND_ALLOWED_NETS: %w", err)
+	}
+	base := outbound.Policy{
+		Schemes:      splitList(strings.ToLower(cfg.OutboundSchemes)),
+		AllowedHosts: splitList(cfg.OutboundAllowedHosts),
+		AllowedNets:  nets,
+		AllowPrivate: cfg.OutboundAllowPrivate,
+	}
+	ps, err := outbound.NewPolicies(base, cfg.OutboundClientPolicies)
+	if err != nil {
+		return nil, fmt.Errorf("FC_OUTBOUND_CLIENT_POLICIES: %w", err)
+	}
+	return ps, nil
+}
+
+// outboundTransports builds the FC_OUTBOUND_TRANSPORTS delivery transports.
+// The bastions and proxies are dialled under the deployment's address
+// policy; their key material resolves through secrets on each connect.
+func outboundTransports(cfg EnvCfg, ps *outbound.Policies, secrets outbound.SecretResolver) (*outbound.Transports, error) {
+	dialer := &net.Dialer{Timeout: 30 * time.Second, KeepAlive: 30 * time.Second}
+	ps.Default.Guard(dialer)
+	ts, err := outbound.NewTransports(cfg.OutboundTransports, outbound.TransportEnv{Dialer: dialer, Secrets: secrets})
+	if err != nil {
+		return nil, fmt.Errorf("FC_OUTBOUND_TRANSPORTS: %w", err)
+	}
+	return ts, nil
+}
+
+// outboundTLS builds the deployment's TLS trust for webhook deliveries and
+// router mediation from FC_OUTBOUND_TLS_CA_FILE and
+// FC_OUTBOUND_TLS_MIN_VERSION.
+func outboundTLS(cfg EnvCfg) (outbound.TLSTrust, error) {
+	trust := outbound.TLSTrust{MinVersion: cfg.OutboundTLSMinVersion}
+	if cfg.OutboundTLSCAFile != "" {
+		pem, err := os.ReadFile(cfg.OutboundTLSCAFile)
+		if err != nil {
+			return outbound.TLSTrust{}, fmt.Errorf("FC_OUTBOUND_TLS_CA_FILE: %w", err)
+		}
+		trust.CABundle = string(pem)
+	}
+	if err := trust.Validate(); err != nil {
+		return outbound.TLSTrust{}, fmt.Errorf("FC_OUTBOUND_TLS_*: %w", err)
+	}
+	return trust, nil
+}
+
+// routerOutboundPolicy is the policy for router mediation targets: the
+// deployment's address rules, without its subscriber host allowlist, and
+// trusting the platform's own dispatch callback — on loopback by default,
+// which the address rules would otherwise refuse. Messages carry no
+// client, so per-client overrides apply when the platform delivers the
+// webhook, not here.
+func routerOutboundPolicy(cfg EnvCfg) (*outbound.Policy, error) {
+	ps, err := outboundPolicies(cfg)
+	if err != nil {
+		return nil, err
+	}
+	p := ps.Default
+	p.AllowedHosts = nil
+	p.TrustedAddrs = callbackAddrs(cfg.DispatchProcessingEndpoint)
+	return &p, nil
+}
+
+// callbackAddrs resolves the dispatch callback URL to the addresses the
+// router will dial for it. Resolution happens once, at startup; a name
+// that fails to resolve is logged and left untrusted.
+func callbackAddrs(raw string) []netip.AddrPort {
+	u, err := url.Parse(raw)
+	if err != nil || u.Hostname() == "" {
+		return nil
+	}
+	port := uint16(443)
+	if u.Scheme == "http" {
+		port = 80
+	}
+	if p, err := strconv.ParseUint(u.Port(), 10, 16); err == nil {
+		port = uint16(p)
+	}
+	host := u.Hostname()
+	var addrs []netip.Addr
+	switch {
+	case strings.EqualFold(host, "localhost"):
+		addrs = []netip.Addr{netip.AddrFrom4([4]byte{127, 0, 0, 1}), netip.IPv6Loopback()}
+	default:
+		if a, err := netip.ParseAddr(host); err == nil {
+			addrs = []netip.Addr{a.Unmap()}
+			break
+		}
+		ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+		defer cancel()
+		resolved, err := net.DefaultResolver.LookupNetIP(ctx, "ip", host)
+		if err != nil {
+			slog.Warn("dispatch callback host did not resolve; router deliveries to it are subject to the outbound policy",
+				"host", host, "err", err)
+			return nil
+		}
+		for _, a := range resolved {
+			addrs = append(addrs, a.Unmap())
+		}
+	}
+	out := make([]netip.AddrPort, 0, len(addrs))
+	for _, a := range addrs {
+		out = append(out, netip.AddrPortFrom(a, port))
+	}
+	return out
+}
@@ -0,0 +1,44 @@
+package server
+
+import (
+	"errors"
+	"strings"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/payloadcrypt"
+)
+
+// NewPayloadCipher builds the encrypted-at-rest payload cipher. Payloads
+// are sealed for the clients in FC_PAYLOAD_ENCRYPTION_CLIENTS
+// (comma-separated IDs, or "*" for every client). The cipher is built
+// whenever the app key is present, even with no client opted in, so rows
+// sealed before a client opted out stay readable. Returns (nil, nil) only
+// when there is no app key and nothing to seal. Exported for the
+// payload-keys maintenance command.
+func NewPayloadCipher(cfg EnvCfg, pool *pgxpool.Pool, kek *encryption.Service) (*payloadcrypt.Cipher, error) {
+	var clients []string
+	all := false
+	for _, c := range strings.Split(cfg.PayloadEncryptionClients, ",") {
+		switch c = strings.TrimSpace(c); c {
+		case "":
+		case "*":
+			all = true
+		default:
+			clients = append(clients, c)
+		}
+	}
+	if kek == nil {
+		if all || len(clients) > 0 {
+			return nil, errors.New("FC_PAYLOAD_ENCRYPTION_CLIENTS requires FLOWCATALYST_APP_KEY (it wraps the per-client data keys)")
+		}
+		return nil, nil
+	}
+	return payloadcrypt.New(payloadcrypt.Config{
+		Keys:       payloadcrypt.NewPgKeyStore(pool),
+		KEK:        kek,
+		Clients:    clients,
+		AllClients: all,
+	})
+}
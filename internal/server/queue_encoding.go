@@ -0,0 +1,38 @@
+package server
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"strings"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+)
+
+// configureQueueEncoding selects the envelope queue publishers write from
+// FC_QUEUE_ENCODING. Consumers decode every encoding by its content-type
+// attribute, so a fleet can switch producers over one at a time.
+func configureQueueEncoding(ctx context.Context, cfg EnvCfg) error {
+	switch strings.ToLower(strings.TrimSpace(cfg.QueueEncoding)) {
+	case "", "json":
+		queue.SetEncoding(queue.JSONCodec{})
+	case "protobuf", "proto":
+		queue.SetEncoding(queue.ProtobufCodec{})
+	case "avro":
+		var registry *queue.SchemaRegistry
+		if cfg.QueueSchemaRegistryURL != "" {
+			registry = queue.NewSchemaRegistry(cfg.QueueSchemaRegistryURL)
+		}
+		codec, err := queue.NewAvroCodec(ctx, registry, cfg.QueueSchemaRegistrySubject)
+		if err != nil {
+			return fmt.Errorf("FC_QUEUE_SCHEMA_REGISTRY_URL: %w", err)
+		}
+		queue.SetEncoding(codec)
+	default:
+		return fmt.Errorf("FC_QUEUE_ENCODING: unknown encoding %q (json, protobuf, avro)", cfg.QueueEncoding)
+	}
+	if ct := queue.Encoding().ContentType(); ct != queue.ContentTypeJSON {
+		slog.Info("queue: publishing binary envelopes", "contentType", ct)
+	}
+	return nil
+}
@@ -0,0 +1,36 @@
+package server
+
+import (
+	"context"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/reconciler"
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
+)
+
+// startReconciler runs the declarative configuration reconciler against
+// FC_RECONCILE_SPEC_PATH until ctx is cancelled; nil when no path is set.
+// Every replica reports drift, but only the elected leader applies it, so
+// two replicas never race to create the same item.
+func startReconciler(ctx context.Context, cfg EnvCfg, uow *usecasepgx.UnitOfWork, repos *repoSet, svcs *serviceSet) *reconciler.Reconciler {
+	if cfg.ReconcileSpecPath == "" {
+		return nil
+	}
+	rec := reconciler.New(reconciler.Deps{
+		Bundle:          repos.configBundle(),
+		Guards:          svcs.bundleGuards(),
+		ServiceAccounts: repos.serviceAccountRepo,
+		Principals:      repos.principalRepo,
+		OAuthClients:    repos.authRepo.OAuthClients,
+		UoW:             uow,
+	}, reconciler.Config{
+		Path:     cfg.ReconcileSpecPath,
+		Interval: time.Duration(cfg.ReconcileIntervalSecs) * time.Second,
+		Apply:    cfg.ReconcileApply,
+	})
+	if cfg.ReconcileApply {
+		rec.IsLeader = leaderGate(ctx, cfg, "reconciler")
+	}
+	go rec.Run(ctx)
+	return rec
+}
@@ -0,0 +1,40 @@
+package server
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/remoteauth"
+)
+
+// loadRemoteAuth builds the external-issuer validator for FC_AUTH_MODE=remote.
+// Returns (nil, nil) in embedded mode. Remote mode without a parseable
+// issuer list is a boot error — silently falling back to embedded would
+// reject every caller the operator meant to admit.
+func loadRemoteAuth(cfg EnvCfg) (*remoteauth.Validator, error) {
+	switch strings.ToLower(strings.TrimSpace(cfg.AuthMode)) {
+	case "", "embedded":
+		return nil, nil
+	case "remote":
+	default:
+		return nil, fmt.Errorf("FC_AUTH_MODE: unknown mode %q (want embedded or remote)", cfg.AuthMode)
+	}
+	if strings.TrimSpace(cfg.AuthRemoteIssuers) == "" {
+		return nil, errors.New("FC_AUTH_MODE=remote requires FC_AUTH_REMOTE_ISSUERS")
+	}
+	issuers, err := remoteauth.ParseIssuers(cfg.AuthRemoteIssuers)
+	if err != nil {
+		return nil, fmt.Errorf("FC_AUTH_REMOTE_ISSUERS: %w", err)
+	}
+	v, err := remoteauth.New(remoteauth.Config{
+		Issuers:         issuers,
+		ClockSkew:       time.Duration(cfg.AuthClockSkewSecs) * time.Second,
+		RefreshInterval: time.Duration(cfg.AuthJWKSRefreshSecs) * time.Second,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("remote auth init: %w", err)
+	}
+	return v, nil
+}
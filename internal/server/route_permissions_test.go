@@ -0,0 +1,176 @@
+package server
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"os"
+	"strings"
+	"testing"
+
+	"github.com/danielgtaylor/huma/v2"
+	"github.com/danielgtaylor/huma/v2/adapters/humachi"
+	"github.com/go-chi/chi/v5"
+
+	anomalyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly/api"
+	applicationapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/application/api"
+	archiveapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive/api"
+	auditapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit/api"
+	authapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/api"
+	lockoutapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout/api"
+	clientapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/client/api"
+	configbundleapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle/api"
+	connectionapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection/api"
+	corsapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/cors/api"
+	dispatchjobapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/api"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/processing"
+	dispatchpoolapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool/api"
+	emaildomainapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/emaildomainmapping/api"
+	eventapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/event/api"
+	eventtypeapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype/api"
+	federationapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation/api"
+	identityproviderapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/identityprovider/api"
+	killswitchapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch/api"
+	loginattemptapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/loginattempt/api"
+	platformconfigapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/platformconfig/api"
+	principalapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal/api"
+	privacyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy/api"
+	processapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/process/api"
+	reconcilerapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/reconciler/api"
+	resetapprovalapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/resetapproval/api"
+	roleapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/role/api"
+	scheduledjobapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduledjob/api"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/sdksync"
+	serviceaccountapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount/api"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/bff"
+	meapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/me"
+	platformmw "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/middleware"
+	sdkapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/sdk"
+	signingkeyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey/api"
+	streamdlqapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/streamdlq/api"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+	subscriptionapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription/api"
+	webauthnapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/webauthn/api"
+)
+
+type stubProber struct{}
+
+func (stubProber) Probe(context.Context, *subscription.Subscription) processing.ProbeResult {
+	return processing.ProbeResult{}
+}
+
+// platformRoutes mounts every /api and /bff handler registerPlatformAPI
+// serves, on zero-value state, so the router can be walked without a
+// database. Keep in sync with registerPlatformAPI.
+func platformRoutes() *chi.Mux {
+	r := chi.NewMux()
+	api := humachi.New(r, huma.DefaultConfig("FlowCatalyst Platform API", "dev"))
+
+	anomalyapi.Register(api, &anomalyapi.State{})
+	applicationapi.Register(api, &applicationapi.State{})
+	archiveapi.Register(api, &archiveapi.State{})
+	auditapi.Register(api, &auditapi.State{})
+	authapi.Register(api, &authapi.State{})
+	clientapi.Register(api, &clientapi.State{})
+	configbundleapi.Register(api, &configbundleapi.State{})
+	connectionapi.Register(api, &connectionapi.State{})
+	corsapi.Register(api, &corsapi.State{})
+	dispatchjobapi.Register(api, &dispatchjobapi.State{})
+	dispatchpoolapi.Register(api, &dispatchpoolapi.State{})
+	emaildomainapi.Register(api, &emaildomainapi.State{})
+	eventapi.Register(api, &eventapi.State{})
+	eventtypeapi.Register(api, &eventtypeapi.State{})
+	federationapi.Register(api, &federationapi.State{})
+	identityproviderapi.Register(api, &identityproviderapi.State{})
+	killswitchapi.Register(api, &killswitchapi.State{})
+	lockoutapi.Register(api, &lockoutapi.State{})
+	loginattemptapi.Register(api, &loginattemptapi.State{})
+	platformconfigapi.Register(api, &platformconfigapi.State{})
+	principalapi.Register(api, &principalapi.State{})
+	privacyapi.Register(api, &privacyapi.State{})
+	processapi.Register(api, &processapi.State{})
+	reconcilerapi.Register(api, &reconcilerapi.State{})
+	resetapprovalapi.Register(api, &resetapprovalapi.State{})
+	roleapi.Register(api, &roleapi.State{})
+	scheduledjobapi.Register(api, &scheduledjobapi.State{})
+	sdksync.Register(api, &sdksync.State{})
+	serviceaccountapi.Register(api, &serviceaccountapi.State{})
+	signingkeyapi.Register(api, &signingkeyapi.State{})
+	streamdlqapi.Register(api, &streamdlqapi.State{})
+	subscriptionapi.Register(api, &subscriptionapi.State{})
+	webauthnapi.Register(api, &webauthnapi.State{})
+
+	dispatchjobapi.MountConsumeStream(r, &dispatchjobapi.State{})
+	bff.RegisterRoutes(r, &bff.DashboardState{})
+	bff.RegisterFilterOptions(r, &bff.FilterOptionsState{})
+	bff.RegisterEventTypes(r, &bff.EventTypesState{})
+	bff.RegisterRoles(r, &bff.RolesState{})
+	bff.RegisterScheduledJobs(r, &bff.ScheduledJobsState{})
+	bff.RegisterDeveloper(r, &bff.DeveloperState{})
+	bff.RegisterSubscriptionAnalytics(r, &bff.SubscriptionAnalyticsState{})
+	bff.RegisterPortal(r, &bff.PortalState{Prober: stubProber{}})
+	meapi.RegisterRoutes(r, &meapi.State{})
+	sdkapi.RegisterRoutes(r, &sdkapi.DispatchJobsBatchState{})
+	sdkapi.RegisterAuditRoutes(r, &sdkapi.AuditBatchState{})
+	return r
+}
+
+// TestRoutePermissions fails when a platform route is added without a
+// registry entry in shared/middleware/permissions.go, or an entry
+// outlives its route.
+func TestRoutePermissions(t *testing.T) {
+	served := map[string]bool{}
+	err := chi.Walk(platformRoutes(), func(method, route string, _ http.Handler, _ ...func(http.Handler) http.Handler) error {
+		if len(route) > 1 {
+			route = strings.TrimSuffix(route, "/")
+		}
+		if !strings.HasPrefix(route, "/api/") && !strings.HasPrefix(route, "/bff/") {
+			return nil
+		}
+		served[method+" "+route] = true
+		if _, ok := platformmw.RequirementFor(method, route); !ok {
+			t.Errorf("%s %s has no entry in the route permission registry", method, route)
+		}
+		return nil
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, rr := range platformmw.PermissionMatrix() {
+		if !served[rr.Method+" "+rr.Path] {
+			t.Errorf("registry declares %s %s but no handler serves it", rr.Method, rr.Path)
+		}
+	}
+}
+
+// TestRoutePermissionsCoverSpec checks the committed OpenAPI lockfile
+// the same way, so a route served only in production wiring is caught
+// too.
+func TestRoutePermissionsCoverSpec(t *testing.T) {
+	raw, err := os.ReadFile("../../api/openapi.lock.json")
+	if err != nil {
+		t.Fatal(err)
+	}
+	var spec struct {
+		Paths map[string]map[string]json.RawMessage `json:"paths"`
+	}
+	if err := json.Unmarshal(raw, &spec); err != nil {
+		t.Fatal(err)
+	}
+	for path, ops := range spec.Paths {
+		if !strings.HasPrefix(path, "/api/") {
+			continue
+		}
+		for op := range ops {
+			method := strings.ToUpper(op)
+			switch method {
+			case http.MethodGet, http.MethodPost, http.MethodPut, http.MethodPatch, http.MethodDelete:
+			default:
+				continue
+			}
+			if _, ok := platformmw.RequirementFor(method, path); !ok {
+				t.Errorf("%s %s has no entry in the route permission registry", method, path)
+			}
+		}
+	}
+}
@@ -16,11 +16,20 @@ import (
 	"github.com/go-chi/chi/v5"
 	"github.com/go-chi/chi/v5/middleware"
 	"github.com/jackc/pgx/v5/pgxpool"
+	"github.com/prometheus/client_golang/prometheus"
+	"golang.org/x/time/rate"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/degrade"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/dispatchcancel"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/database"
+	platformmw "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/middleware"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/netpolicy"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
 	routerapi "github.com/flowcatalyst/flowcatalyst-go/internal/router/api"
+	routermongo "github.com/flowcatalyst/flowcatalyst-go/internal/router/mongo"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/startup"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/stream"
 )
 
@@ -31,6 +40,12 @@ import (
 type RunOptions struct {
 	ExtraAPIRoutes func(r chi.Router)
 	Fallback       http.Handler
+	// Startup, when set, is the finished dependency probe; its outcome is
+	// reported as the informational "startup" check on /q/health.
+	Startup *startup.Orchestrator
+	// QueryTracer, when set, is the pool's statement tracer; with the
+	// router enabled its slow queries also become RESOURCE warnings.
+	QueryTracer *database.QueryTracer
 }
 
 // Run is the single orchestrator that fc-server and fc-dev both call.
@@ -39,6 +54,8 @@ type RunOptions struct {
 //   - wire the platform aggregates (when cfg.PlatformEnabled)
 //   - mount the router HTTP surface under cfg.RouterHTTPPrefix (when cfg.RouterEnabled)
 //   - spawn background subsystems (scheduler, stream, outbox, router engine, mcp, purger)
+//     and aggregate their health on /q/health (API and metrics ports) and the
+//     metrics-port /ready
 //   - bridge stream.HealthService → router StreamHealthProvider so the
 //     dashboard reflects live projection state when co-tenanted
 //   - bind the API + metrics + (optional) MCP listeners
@@ -57,20 +74,69 @@ func Run(ctx context.Context, pool *pgxpool.Pool, cfg EnvCfg, opts RunOptions) e
 	// Always build a stream HealthService — empty when stream is off so
 	// the router's StreamHealthProvider reports zero streams gracefully.
 	streamHealth := stream.NewHealthService()
+	health := NewHealthAggregator()
+	if opts.Startup != nil {
+		health.Register("startup", false, startupHealth(opts.Startup))
+	}
+	// Features running without Redis report DEGRADED; informational, since
+	// every replica shares the outage.
+	health.Register("redis-degradation", false, redisDegradationHealth(degrade.Default()))
+
+	corsCfg := platformmw.CORSConfig{
+		AllowedOrigins:   platformmw.ParseCORSOrigins(cfg.CORSOrigins),
+		AllowCredentials: cfg.CORSAllowCredentials,
+		MaxAge:           time.Duration(cfg.CORSMaxAgeSecs) * time.Second,
+	}
+	if err := corsCfg.Validate(); err != nil {
+		return fmt.Errorf("FC_CORS_ORIGINS: %w", err)
+	}
+
+	if err := configureQueueEncoding(ctx, cfg); err != nil {
+		return err
+	}
+
+	// Platform-level Prometheus series, served on the metrics port.
+	metrics := prometheus.NewRegistry()
+	rejections := newHTTPRejections(metrics)
+
+	limits := bodyLimits(cfg)
+	limits.OnReject = rejections.For("api")
 
 	r := chi.NewRouter()
+	// Keep the TCP peer before RealIP rewrites RemoteAddr from the headers;
+	// network allowlists resolve the caller from it (see netpolicy).
+	r.Use(netpolicy.CapturePeer)
 	r.Use(middleware.RequestID)
 	r.Use(middleware.RealIP)
 	r.Use(middleware.Recoverer)
+	r.Use(platformmw.Limits(limits))
+	// Browser-facing policy for every route on the listener.
+	r.Use(platformmw.SecurityHeaders(platformmw.SecurityHeadersConfig{
+		HSTSMaxAge: time.Duration(cfg.HSTSMaxAgeSecs) * time.Second,
+	}))
+	r.Use(platformmw.CORS(corsCfg))
+	if cfg.CSRFEnabled {
+		r.Use(platformmw.CSRF(platformmw.CSRFConfig{ExemptPrefixes: csrfExemptPrefixes}))
+	}
 	r.Get("/health", healthHandler)
+	health.Mount(r)
 
 	var routerSrv *router.Server
 	var routerErr error
+	var platform *Platform
 
 	if cfg.PlatformEnabled {
-		if err := WirePlatform(r, pool, cfg); err != nil {
+		var err error
+		if platform, err = WirePlatform(ctx, r, pool, cfg, metrics); err != nil {
 			return fmt.Errorf("platform wiring: %w", err)
 		}
+		if reg := platform.SchemaRegistry; reg != nil {
+			health.Register("schema-registry", false, pingHealth(reg.Ping))
+			health.addInfrastructure(router.InfrastructureCheck{Name: "schema-registry", Kind: "schema-registry", Ping: reg.Ping})
+		}
+		if platform.Connectors != nil {
+			health.Register("connectors", false, connectorHealth(platform.Connectors))
+		}
 		slog.Info("platform API wired")
 	}
 
@@ -83,7 +149,9 @@ func Run(ctx context.Context, pool *pgxpool.Pool, cfg EnvCfg, opts RunOptions) e
 		if prefix == "" {
 			prefix = "/router"
 		}
-		MountRouterHTTP(r, prefix, routerSrv, streamHealth, cfg)
+		if err := MountRouterHTTP(r, prefix, routerSrv, streamHealth, health, cfg); err != nil {
+			return fmt.Errorf("router HTTP: %w", err)
+		}
 		slog.Info("router HTTP mounted", "prefix", prefix)
 	}
 
@@ -108,74 +176,103 @@ func Run(ctx context.Context, pool *pgxpool.Pool, cfg EnvCfg, opts RunOptions) e
 	}
 
 	// ── Background subsystems ─────────────────────────────────────────────
+	// Each component is registered with the subsystem registry so the
+	// metrics-port /ready aggregates them: a component that returns while
+	// ctx is still live (e.g. the scheduler refusing to start without
+	// FLOWCATALYST_APP_KEY) turns the instance not-ready instead of
+	// vanishing silently. Leader gates and subsystem-owned providers
+	// (outbox backend, standby Redis) attach themselves via ctx, as do
+	// subsystem metrics (the outbox backlog).
+	ctx = withMetrics(withHealth(ctx, health), metrics)
 	var wg sync.WaitGroup
+	spawn := func(name string, run func(context.Context)) *subsystemEntry {
+		e := health.subsystems.add(name)
+		e.running.Store(true)
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			defer e.running.Store(false)
+			run(ctx)
+			if ctx.Err() == nil {
+				e.fail("exited unexpectedly; see logs")
+			}
+		}()
+		slog.Info("subsystem started", "name", name)
+		return e
+	}
 	if cfg.PlatformEnabled {
-		go StartPurger(ctx, pool)
+		platform := health.subsystems.add("platform")
+		platform.running.Store(true)
+		platform.probe = func(ctx context.Context) error {
+			pingCtx, cancel := context.WithTimeout(ctx, 2*time.Second)
+			defer cancel()
+			return pool.Ping(pingCtx)
+		}
+		spawn("purger", func(ctx context.Context) { StartPurger(ctx, pool) })
+		spawn("erasure-worker", func(ctx context.Context) { StartErasureWorker(ctx, pool) })
 	}
 	if cfg.SchedulerEnabled {
-		wg.Add(1)
-		go func() { defer wg.Done(); StartScheduler(ctx, pool, cfg) }()
-		slog.Info("scheduler started")
+		spawn("scheduler", func(ctx context.Context) { StartScheduler(ctx, pool, cfg) })
 	}
 	if cfg.ScheduledJobEnabled {
-		wg.Add(1)
-		go func() { defer wg.Done(); StartScheduledJobScheduler(ctx, pool, cfg) }()
-		slog.Info("scheduled-job scheduler started")
+		spawn("scheduled-job", func(ctx context.Context) { StartScheduledJobScheduler(ctx, pool, cfg) })
 	}
 	if cfg.StreamEnabled {
-		wg.Add(1)
-		go func() {
-			defer wg.Done()
-			StartStreamProcessorWithHealth(ctx, pool, cfg, streamHealth)
-		}()
-		slog.Info("stream processor started")
+		e := spawn("stream", func(ctx context.Context) { StartStreamProcessorWithHealth(ctx, pool, cfg, streamHealth) })
+		e.probe = func(context.Context) error {
+			// No registered projections (every FC_STREAM_* toggle off) is
+			// nothing to wait for, not a failure.
+			if agg := streamHealth.Aggregate(); agg.TotalStreams > 0 && !agg.Healthy {
+				return fmt.Errorf("%d of %d stream projections not running", agg.UnhealthyStreams, agg.TotalStreams)
+			}
+			return nil
+		}
+		health.Register("stream-checkpoints", false, streamCheckpointHealth(pool, streamHealth))
 	}
 	if cfg.OutboxEnabled {
-		wg.Add(1)
-		go func() { defer wg.Done(); StartOutboxProcessor(ctx, pool, cfg) }()
-		slog.Info("outbox processor started")
+		spawn("outbox", func(ctx context.Context) { StartOutboxProcessor(ctx, pool, cfg) })
 	}
 	if cfg.RouterEnabled {
-		wg.Add(1)
-		go func() {
-			defer wg.Done()
+		spawn("router", func(ctx context.Context) {
 			if err := routerSrv.Run(ctx); err != nil {
 				slog.Warn("router run failed", "err", err)
 			}
-		}()
-		slog.Info("router engine started")
+		})
+		health.subsystems.setLeader("router", routerSrv.IsLeader)
+		health.Register("router-consumers", true, routerHealth(routerSrv))
+		if cfg.StandbyEnabled {
+			health.Register("standby-redis", false, pingHealth(routerSrv.PingStandby))
+		}
+		if opts.QueryTracer != nil {
+			opts.QueryTracer.OnSlowQuery(slowQueryWarnings(routerSrv.Warnings))
+		}
+		if platform != nil {
+			platform.Lockout.OnLockout(lockoutWarnings(routerSrv.Warnings))
+			platform.Lifecycles.OnUse(lifecycleWarnings(routerSrv.Warnings))
+		}
 	}
 	if cfg.MCPEnabled {
-		wg.Add(1)
-		go func() { defer wg.Done(); StartMCP(ctx, cfg) }()
-		slog.Info("mcp started")
+		spawn("mcp", func(ctx context.Context) { StartMCP(ctx, cfg) })
 	}
 
 	// ── Listeners ─────────────────────────────────────────────────────────
-	apiSrv := &http.Server{
-		Addr:              fmt.Sprintf(":%d", cfg.APIPort),
-		Handler:           r,
-		ReadHeaderTimeout: 10 * time.Second,
-	}
-	metricsSrv := &http.Server{
-		Addr:              fmt.Sprintf(":%d", cfg.MetricsPort),
-		Handler:           metricsRouter(cfg),
-		ReadHeaderTimeout: 5 * time.Second,
-	}
+	apiSrv := newHTTPServer(fmt.Sprintf(":%d", cfg.APIPort), r, cfg)
+	metricsSrv := newHTTPServer(fmt.Sprintf(":%d", cfg.MetricsPort), metricsRouter(cfg, health, pool, metrics), cfg)
 
 	listenErr := make(chan error, 2)
-	go func() {
-		slog.Info("api server listening", "addr", apiSrv.Addr)
-		if err := apiSrv.ListenAndServe(); err != nil && !errors.Is(err, http.ErrServerClosed) {
-			listenErr <- fmt.Errorf("api server: %w", err)
+	serve := func(name string, srv *http.Server) {
+		ln, err := listenLimited(srv.Addr, cfg.HTTPMaxConnections, rejections.For(name))
+		if err != nil {
+			listenErr <- fmt.Errorf("%s server: %w", name, err)
+			return
 		}
-	}()
-	go func() {
-		slog.Info("metrics server listening", "addr", metricsSrv.Addr)
-		if err := metricsSrv.ListenAndServe(); err != nil && !errors.Is(err, http.ErrServerClosed) {
-			listenErr <- fmt.Errorf("metrics server: %w", err)
+		slog.Info(name+" server listening", "addr", srv.Addr, "max_connections", cfg.HTTPMaxConnections)
+		if err := srv.Serve(ln); err != nil && !errors.Is(err, http.ErrServerClosed) {
+			listenErr <- fmt.Errorf("%s server: %w", name, err)
 		}
-	}()
+	}
+	go serve("api", apiSrv)
+	go serve("metrics", metricsSrv)
 
 	var runErr error
 	select {
@@ -200,18 +297,58 @@ func Run(ctx context.Context, pool *pgxpool.Pool, cfg EnvCfg, opts RunOptions) e
 	return runErr
 }
 
+// csrfExemptPrefixes are the OAuth back-channel endpoints: they
+// authenticate the client (secret, PKCE verifier or the token itself),
+// not the browser session, so a replayed fc_session cookie grants nothing.
+// The device-code approval (/oauth/device/verify) is a session action and
+// stays protected.
+var csrfExemptPrefixes = []string{
+	"/oauth/token",
+	"/oauth/introspect",
+	"/oauth/revoke",
+	"/oauth/device_authorization",
+	"/oauth/userinfo",
+}
+
 // MountRouterHTTP nests the router API + dashboard + Prometheus under
-// the supplied prefix. Authentication is BasicAuth (env-driven). The
+// the supplied prefix. Authentication is env-driven (resolveRouterAuth);
+// a config that asks for auth without credentials is an error. The
 // router engine itself must be started separately — this only wires
-// the HTTP surface that reads its state.
-func MountRouterHTTP(r chi.Router, prefix string, srv *router.Server, streamHealth *stream.HealthService, cfg EnvCfg) {
+// the HTTP surface that reads its state. health (optional) widens
+// /monitoring/infrastructure to the stores other subsystems registered.
+func MountRouterHTTP(r chi.Router, prefix string, srv *router.Server, streamHealth *stream.HealthService, health *HealthAggregator, cfg EnvCfg) error {
+	authCfg, err := resolveRouterAuth()
+	if err != nil {
+		return err
+	}
+	if authCfg.Mode == routerapi.AuthModeNone && !cfg.RouterDevMode {
+		slog.Warn("router HTTP API is unauthenticated: set FC_ROUTER_AUTH_MODE (BASIC or TOKEN) outside development",
+			"prefix", prefix)
+	}
 	state := routerapi.FromServer(srv)
+	targets, err := routerapi.ParseQueueTargets(cfg.ScaleQueueTargets)
+	if err != nil {
+		return fmt.Errorf("FC_SCALE_QUEUE_TARGETS: %w", err)
+	}
+	state.Scale = routerapi.ScaleConfig{
+		MessagesPerReplica: cfg.ScaleMessagesPerReplica,
+		QueueTargets:       targets,
+		MinReplicas:        cfg.ScaleMinReplicas,
+		MaxReplicas:        cfg.ScaleMaxReplicas,
+	}
+	if n := cfg.RouterPublishRatePerSec; n > 0 {
+		// Burst covers a full batch so one /messages/batch call can pass.
+		state.PublishLimit = rate.NewLimiter(rate.Limit(n), max(n, routerapi.MaxBatchItems))
+	}
 	if streamHealth != nil {
 		state.StreamHealth = streamHealthBridge{svc: streamHealth}
 	}
+	if health != nil {
+		state.Infrastructure = infrastructureBridge{srv: srv, health: health}
+	}
 	r.Route(prefix, func(sub chi.Router) {
-		// BasicAuth on the router prefix. Disabled when no creds set.
-		sub.Use(routerapi.BasicAuthMiddleware(resolveRouterAuth()))
+		// Auth on the router prefix; probes stay public.
+		sub.Use(routerapi.AuthMiddleware(authCfg))
 		humaCfg := huma.DefaultConfig("FlowCatalyst Router API", routerapi.Version)
 		// Nest the spec under the prefix so external tooling can grab
 		// the OpenAPI doc at <prefix>/openapi.json.
@@ -219,27 +356,66 @@ func MountRouterHTTP(r chi.Router, prefix string, srv *router.Server, streamHeal
 		// the platform API config in wire.go.
 		humaCfg.SchemasPath = ""
 		api := humachi.New(sub, humaCfg)
+		applyBodyLimits(api, prefix, bodyLimits(cfg))
 		routerapi.Register(api, state)
 		routerapi.MountDashboard(sub)
+		routerapi.MountWarningStream(sub, state)
 		sub.Mount("/metrics", routerapi.PrometheusHandler(state))
 	})
+	return nil
 }
 
-// resolveRouterAuth reads the router HTTP BasicAuth config, accepting the Rust
-// AUTH_BASIC_USERNAME / AUTH_BASIC_PASSWORD names as aliases for
-// FC_ROUTER_AUTH_USER / FC_ROUTER_AUTH_PASS. AUTH_MODE=NONE (case-insensitive)
-// forces auth off regardless of creds; any other value (incl. BASIC or unset)
-// uses the resolved creds — an empty username disables auth, mirroring the
-// Rust router's AuthMode::None. (The router HTTP surface supports basic/none
-// only; OIDC modes are not honoured here.)
-func resolveRouterAuth() routerapi.BasicAuthConfig {
-	if strings.EqualFold(strings.TrimSpace(os.Getenv("AUTH_MODE")), "NONE") {
-		return routerapi.BasicAuthConfig{}
-	}
-	return routerapi.BasicAuthConfig{
-		Username: envFirst("FC_ROUTER_AUTH_USER", "AUTH_BASIC_USERNAME", ""),
-		Password: envFirst("FC_ROUTER_AUTH_PASS", "AUTH_BASIC_PASSWORD", ""),
+// resolveRouterAuth reads the router HTTP auth config. FC_ROUTER_AUTH_MODE
+// (alias AUTH_MODE, the Rust name) picks NONE, BASIC or TOKEN; unset, the
+// mode follows the credentials present — FC_ROUTER_AUTH_TOKENS selects
+// TOKEN, a BasicAuth username (FC_ROUTER_AUTH_USER / AUTH_BASIC_USERNAME)
+// selects BASIC, and nothing leaves the surface open. An explicit mode
+// without its credentials is a boot error rather than an open router.
+func resolveRouterAuth() (routerapi.AuthConfig, error) {
+	mode, err := routerapi.ParseAuthMode(envFirst("FC_ROUTER_AUTH_MODE", "AUTH_MODE", ""))
+	if err != nil {
+		return routerapi.AuthConfig{}, fmt.Errorf("FC_ROUTER_AUTH_MODE: %w", err)
+	}
+	cfg := routerapi.AuthConfig{
+		Mode: mode,
+		Basic: routerapi.BasicAuthConfig{
+			Username: envFirst("FC_ROUTER_AUTH_USER", "AUTH_BASIC_USERNAME", ""),
+			Password: envFirst("FC_ROUTER_AUTH_PASS", "AUTH_BASIC_PASSWORD", ""),
+		},
+	}
+	if raw := strings.TrimSpace(os.Getenv("FC_ROUTER_AUTH_TOKENS")); raw != "" {
+		if cfg.Tokens, err = routerapi.ParseTokens(raw); err != nil {
+			return routerapi.AuthConfig{}, fmt.Errorf("FC_ROUTER_AUTH_TOKENS: %w", err)
+		}
+	}
+	if cfg.Mode == "" {
+		switch {
+		case len(cfg.Tokens) > 0:
+			cfg.Mode = routerapi.AuthModeToken
+		case cfg.Basic.Username != "":
+			cfg.Mode = routerapi.AuthModeBasic
+		default:
+			cfg.Mode = routerapi.AuthModeNone
+		}
+	}
+	if err := cfg.Validate(); err != nil {
+		return routerapi.AuthConfig{}, fmt.Errorf("router auth: %w", err)
 	}
+	return cfg, nil
+}
+
+// infrastructureBridge reports the router's own stores plus whatever
+// co-tenanted subsystems registered (the outbox's Mongo), so one endpoint
+// covers the whole process.
+type infrastructureBridge struct {
+	srv    *router.Server
+	health *HealthAggregator
+}
+
+func (b infrastructureBridge) Infrastructure(ctx context.Context) router.InfrastructureHealth {
+	checks := b.srv.InfrastructureChecks()
+	checks = append(checks, b.health.infrastructureChecks()...)
+	return router.CheckInfrastructure(ctx, checks)
 }
 
 // streamHealthBridge adapts the in-process stream.HealthService into
@@ -273,10 +449,55 @@ func (b streamHealthBridge) Aggregate() routerapi.StreamHealthAggregate {
 func (b streamHealthBridge) IsLive() bool  { return b.svc.IsLive() }
 func (b streamHealthBridge) IsReady() bool { return b.svc.IsReady() }
 
+// routerAttemptSinkConfig maps the FC_ROUTER_ATTEMPT_SINK_* env onto the
+// router's delivery-attempt sink. Zero sizes take the router defaults.
+func routerAttemptSinkConfig(cfg EnvCfg) router.AttemptSinkConfig {
+	return router.AttemptSinkConfig{
+		Kind:          cfg.RouterAttemptSinkKind,
+		URL:           cfg.RouterAttemptSinkURL,
+		Topic:         cfg.RouterAttemptSinkTopic,
+		Path:          cfg.RouterAttemptSinkPath,
+		AuthToken:     cfg.RouterAttemptSinkToken,
+		BufferSize:    cfg.RouterAttemptSinkBuffer,
+		BatchSize:     cfg.RouterAttemptSinkBatchSize,
+		FlushInterval: time.Duration(cfg.RouterAttemptSinkFlushMS) * time.Millisecond,
+	}
+}
+
+// routerConfigHistory connects the FC_ROUTER_CONFIG_HISTORY_MONGO_URI
+// config history. nil when unset, or when the store can't be reached: the
+// history is a debugging aid, not worth holding the router back for.
+func routerConfigHistory(cfg EnvCfg) router.ConfigHistory {
+	if cfg.RouterConfigHistoryMongoURI == "" {
+		return nil
+	}
+	ctx, cancel := context.WithTimeout(context.Background(), 15*time.Second)
+	defer cancel()
+	retention := time.Duration(cfg.RouterConfigHistoryRetentionDays) * 24 * time.Hour
+	mcfg := common.NewMongoConfig(cfg.RouterConfigHistoryMongoURI, cfg.RouterConfigHistoryMongoDB)
+	h, err := routermongo.ConnectConfigHistory(ctx, mcfg, retention)
+	if err != nil {
+		slog.Warn("router config history disabled", "err", err)
+		return nil
+	}
+	return h
+}
+
 // newRouterServer wraps router.NewServer with the env-driven router
 // config. When cfg.RouterConfigURL is empty we honour cfg.DefaultBroker
 // to synthesize an in-process Postgres pool config so fc-dev "just works".
 func newRouterServer(cfg EnvCfg, pool *pgxpool.Pool) (*router.Server, error) {
+	policy, err := routerOutboundPolicy(cfg)
+	if err != nil {
+		return nil, err
+	}
+	trust, err := outboundTLS(cfg)
+	if err != nil {
+		return nil, err
+	}
+	if err := router.ValidateCurve(cfg.RouterSlowStartCurve); err != nil {
+		return nil, err
+	}
 	rcfg := router.ServerConfig{
 		DevMode:          cfg.RouterDevMode,
 		ConfigURL:        cfg.RouterConfigURL,
@@ -285,6 +506,17 @@ func newRouterServer(cfg EnvCfg, pool *pgxpool.Pool) (*router.Server, error) {
 		StandbyEnabled:   cfg.StandbyEnabled,
 		StandbyRedisURL:  cfg.StandbyRedisURL,
 		StandbyLockKey:   cfg.StandbyLockKey,
+		SlowStart: router.SlowStartConfig{
+			Duration:    time.Duration(cfg.RouterSlowStartSecs) * time.Second,
+			Curve:       cfg.RouterSlowStartCurve,
+			MinFraction: float64(cfg.RouterSlowStartMinPercent) / 100,
+			WarmTargets: cfg.RouterWarmTargets,
+		},
+		GroupHandoverLease: time.Duration(cfg.RouterGroupHandoverSecs) * time.Second,
+		StateSnapshot: router.StateSnapshotConfig{
+			Target: cfg.RouterStateSnapshot,
+			MaxAge: time.Duration(cfg.RouterStateSnapshotMaxSecs) * time.Second,
+		},
 		// ALB self-registration: register on leader-gain / non-standby start,
 		// deregister on leader-loss / drain. No-op unless FC_ALB_ENABLED + the
 		// target group ARN + instance IP are set.
@@ -296,6 +528,19 @@ func newRouterServer(cfg EnvCfg, pool *pgxpool.Pool) (*router.Server, error) {
 			Region:                     cfg.ALBRegion,
 			DeregistrationDelaySeconds: int64(cfg.ALBDeregDelaySec),
 		},
+		AttemptSink: routerAttemptSinkConfig(cfg),
+		EgressBind:  splitList(cfg.RouterEgressBind),
+		Outbound:    policy,
+		TLS:         trust,
+		Preflight: router.PreflightConfig{
+			TTL:         time.Duration(cfg.RouterPreflightSecs) * time.Second,
+			NegativeTTL: time.Duration(cfg.RouterPreflightNegativeSecs) * time.Second,
+		},
+
+		// Tombstones the platform writes on cancel (FC_REDIS_URL).
+		Cancellations: dispatchcancel.Build(context.Background()),
+
+		ConfigHistory: routerConfigHistory(cfg),
 	}
 	srv, err := router.NewServer(rcfg)
 	if err != nil {
@@ -364,7 +609,9 @@ func defaultPostgresRouterConfig(databaseURL string) common.RouterConfig {
 			{Code: "default", Concurrency: 4},
 		},
 		Queues: []common.QueueConfig{
-			{Name: "default", URI: postgresQueueURI(databaseURL), VisibilityTimeout: 30},
+			// A local Postgres poll costs nothing, so idle polling keeps
+			// its fixed 1s cadence rather than backing off.
+			{Name: "default", URI: postgresQueueURI(databaseURL), VisibilityTimeout: 30, IdlePollMaxMs: 1000},
 		},
 	}
 }
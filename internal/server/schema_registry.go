@@ -0,0 +1,40 @@
+package server
+
+import (
+	"context"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/schemaregistry"
+)
+
+// schemaRegistry is the FC_SCHEMA_REGISTRY_URL client event payloads are
+// resolved against. Unset, it returns nil: no subject bindings.
+func schemaRegistry(cfg EnvCfg) *schemaregistry.Client {
+	if cfg.SchemaRegistryURL == "" {
+		return nil
+	}
+	return schemaregistry.New(schemaregistry.Config{
+		URL:      cfg.SchemaRegistryURL,
+		Username: cfg.SchemaRegistryUsername,
+		Password: cfg.SchemaRegistryPassword,
+		CacheTTL: time.Duration(cfg.SchemaRegistryCacheTTLSecs) * time.Second,
+	})
+}
+
+// schemaResolver resolves ingested events' schema ids against the event
+// types' registry bindings in repo.
+func schemaResolver(client *schemaregistry.Client, repo *eventtype.Repository) *schemaregistry.Resolver {
+	load := func(ctx context.Context) (map[string]schemaregistry.Binding, error) {
+		bindings, err := repo.RegistryBindings(ctx)
+		if err != nil {
+			return nil, err
+		}
+		out := make(map[string]schemaregistry.Binding, len(bindings))
+		for code, b := range bindings {
+			out[code] = schemaregistry.Binding{Subject: b.Subject, Validate: b.Mode == eventtype.RegistryModeValidate}
+		}
+		return out, nil
+	}
+	return schemaregistry.NewResolver(client, load, 0)
+}
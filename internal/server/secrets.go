@@ -0,0 +1,25 @@
+package server
+
+import (
+	"fmt"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/secrets"
+)
+
+// newSecretService builds the resolver for secret references held in
+// platform configuration (subscription delivery headers). env:// always
+// resolves; encrypted: needs FC_SECRETS_ENCRYPTION_KEY and reads the same
+// store as the JWT keyring. The cloud backends aren't built in yet, so
+// their references fail when resolved, not when saved.
+func newSecretService(cfg EnvCfg) (*secrets.Service, error) {
+	svc := secrets.NewService("env")
+	svc.Register(secrets.NewEnvProvider())
+	if cfg.SecretsEncryptionKey != "" {
+		store, err := secrets.NewEncryptedFileProviderFromBase64Key(cfg.SecretsDataDir, cfg.SecretsEncryptionKey)
+		if err != nil {
+			return nil, fmt.Errorf("open secrets store: %w", err)
+		}
+		svc.Register(store)
+	}
+	return svc, nil
+}
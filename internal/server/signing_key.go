@@ -1,6 +1,7 @@
 package server
 
 import (
+	"context"
 	"crypto/rand"
 	"crypto/rsa"
 	"crypto/x509"
@@ -11,6 +12,13 @@ import (
 	"os"
 	"path/filepath"
 	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/keyring"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/secrets"
 )
 
 // LoadSigningKeyOrEphemeral returns the PEM-encoded RSA private key for
@@ -25,6 +33,16 @@ import (
 //     token's signature on restart AND differ per instance (so a multi-replica
 //     service rejects each other's tokens). Production must supply (1) or (2).
 func LoadSigningKeyOrEphemeral(path string) []byte {
+	if key := loadConfiguredSigningKey(path); key != nil {
+		return key
+	}
+	slog.Warn("no JWT signing key configured — generating ephemeral RSA key (tokens won't survive restart)")
+	return generateRSAPEM()
+}
+
+// loadConfiguredSigningKey applies steps 1–2 of LoadSigningKeyOrEphemeral,
+// returning nil when no key is configured.
+func loadConfiguredSigningKey(path string) []byte {
 	if path != "" {
 		b, err := os.ReadFile(path)
 		if err == nil {
@@ -41,8 +59,150 @@ func LoadSigningKeyOrEphemeral(path string) []byte {
 			return []byte(NormalizePEM(pemStr))
 		}
 	}
-	slog.Warn("no JWT signing key configured — generating ephemeral RSA key (tokens won't survive restart)")
-	return generateRSAPEM()
+	return nil
+}
+
+// signingKeys is the resolved JWT key material: the signer plus any
+// validation-only public keys (PEM).
+type signingKeys struct {
+	signing      []byte
+	verification []string
+}
+
+// equal reports whether k and o are the same key set.
+func (k signingKeys) equal(o signingKeys) bool {
+	if string(k.signing) != string(o.signing) || len(k.verification) != len(o.verification) {
+		return false
+	}
+	for i := range k.verification {
+		if k.verification[i] != o.verification[i] {
+			return false
+		}
+	}
+	return true
+}
+
+// signingKeySource is where the keyring's keys come from; nil when the
+// keyring is off and the single configured key never changes.
+type signingKeySource struct {
+	store   keyring.Store
+	seed    keyring.Seed
+	policy  keyring.Policy
+	refresh time.Duration
+}
+
+// loadSigningKeys resolves the signing key set. With FC_JWT_KEYRING_ENABLED
+// the keys live in the shared keyring (iam_jwt_keyrings, sealed with the
+// app key), so every replica and restart signs with the same key, rotated
+// per FC_JWT_KEY_ROTATION_DAYS; the returned source reloads it (see
+// runSigningKeyRefresh). A brand-new ring is seeded from the ring this
+// instance kept in its local secrets file, if any, else from the
+// configured key (file/env), so switching over keeps in-flight tokens
+// valid. Otherwise the single configured (or ephemeral) key is used.
+func loadSigningKeys(ctx context.Context, cfg EnvCfg, pool *pgxpool.Pool, enc *encryption.Service) (signingKeys, *signingKeySource, error) {
+	if !cfg.JWTKeyring {
+		return signingKeys{signing: LoadSigningKeyOrEphemeral(cfg.JWTSigningKeyPath)}, nil, nil
+	}
+	if enc == nil {
+		return signingKeys{}, nil, fmt.Errorf("FC_JWT_KEYRING_ENABLED requires FLOWCATALYST_APP_KEY")
+	}
+	policy := keyring.Policy{
+		RotateAfter: time.Duration(cfg.JWTKeyRotationDays) * 24 * time.Hour,
+		Grace:       time.Duration(cfg.JWTKeyGraceHours) * time.Hour,
+	}
+	src := &signingKeySource{
+		store:   keyring.NewPgStore(pool, enc),
+		seed:    keyring.Seed{KeyPEM: loadConfiguredSigningKey(cfg.JWTSigningKeyPath)},
+		policy:  policy,
+		refresh: time.Duration(cfg.JWTKeyRefreshMinutes) * time.Minute,
+	}
+	if src.refresh <= 0 {
+		src.refresh = 5 * time.Minute
+	}
+	if cfg.SecretsEncryptionKey != "" {
+		legacy, err := secrets.NewEncryptedFileProviderFromBase64Key(cfg.SecretsDataDir, cfg.SecretsEncryptionKey)
+		if err != nil {
+			return signingKeys{}, nil, fmt.Errorf("open secrets store: %w", err)
+		}
+		if src.seed.Ring, err = keyring.ReadLegacy(ctx, legacy); err != nil {
+			return signingKeys{}, nil, err
+		}
+	}
+	keys, err := src.load(ctx)
+	if err != nil {
+		return signingKeys{}, nil, err
+	}
+	return keys, src, nil
+}
+
+// load reads the ring, rotating it first when due.
+func (s *signingKeySource) load(ctx context.Context) (signingKeys, error) {
+	ring, err := keyring.Load(ctx, s.store, s.seed, s.policy, time.Now().UTC())
+	if err != nil {
+		return signingKeys{}, fmt.Errorf("load JWT keyring: %w", err)
+	}
+	verification, err := ring.VerificationPublicKeyPEMs()
+	if err != nil {
+		return signingKeys{}, fmt.Errorf("JWT keyring: %w", err)
+	}
+	return signingKeys{signing: ring.SigningKeyPEM(), verification: verification}, nil
+}
+
+// runSigningKeyRefresh reloads the keyring every src.refresh until ctx is
+// done — rotating it when due, under the keyring lock — and installs a
+// changed key set in the auth provider, the token service and the MFA
+// token issuer. A peer's rotation reaches this instance within one
+// period; the promoted key was staged (and verifiable here) a full
+// rotation period earlier.
+func (s *serviceSet) runSigningKeyRefresh(ctx context.Context, cfg EnvCfg, src *signingKeySource, current signingKeys) {
+	t := time.NewTicker(src.refresh)
+	defer t.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-t.C:
+		}
+		keys, err := src.load(ctx)
+		if err != nil {
+			slog.Warn("JWT keyring refresh failed; keeping current keys", "err", err)
+			continue
+		}
+		if keys.equal(current) {
+			continue
+		}
+		if err := s.installSigningKeys(cfg, keys); err != nil {
+			slog.Error("JWT keyring refresh: installing keys failed; keeping current keys", "err", err)
+			continue
+		}
+		current = keys
+		slog.Info("JWT signing keys reloaded from keyring", "verification_keys", len(keys.verification))
+	}
+}
+
+// installSigningKeys swaps keys into every signer/verifier at runtime.
+func (s *serviceSet) installSigningKeys(cfg EnvCfg, keys signingKeys) error {
+	if err := s.authSvc.SetRSAKeys(string(keys.signing), keys.verification); err != nil {
+		return err
+	}
+	if err := s.authProvider.SetKeys(keys.signing, providerVerificationKeys(cfg, keys)); err != nil {
+		return err
+	}
+	s.mfaTokens.Rekey(s.authProvider.SigningKey())
+	return nil
+}
+
+// providerVerificationKeys is the auth provider's validation-only key set:
+// the configured previous key, then the keyring's.
+func providerVerificationKeys(cfg EnvCfg, keys signingKeys) [][]byte {
+	out := make([][]byte, 0, 1+len(keys.verification))
+	if cfg.JWTPreviousPublicKey != "" {
+		out = append(out, []byte(cfg.JWTPreviousPublicKey))
+	}
+	for _, k := range keys.verification {
+		out = append(out, []byte(k))
+	}
+	return out
 }
 
 // NormalizePEM repairs the common ways a PEM key gets mangled when carried in
@@ -0,0 +1,65 @@
+package server
+
+import (
+	"fmt"
+	"sync"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/database"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+// QueryTracer builds the pool's statement tracer from FC_DB_QUERY_TIMEOUT_MS
+// and FC_DB_SLOW_QUERY_MS.
+func QueryTracer(cfg EnvCfg) *database.QueryTracer {
+	return database.NewQueryTracer(
+		time.Duration(cfg.DBQueryTimeoutMS)*time.Millisecond,
+		time.Duration(cfg.DBSlowQueryMS)*time.Millisecond,
+	)
+}
+
+// slowQueryCooldown is how long a given query shape stays quiet after it
+// raised a warning. One missing index under load would otherwise raise a
+// warning (and a webhook) per request; every occurrence is still logged.
+const slowQueryCooldown = 5 * time.Minute
+
+// slowQueryWarnings turns slow queries into RESOURCE warnings on ws, at
+// most one per query shape per slowQueryCooldown.
+func slowQueryWarnings(ws *router.WarningService) func(database.SlowQuery) {
+	var mu sync.Mutex
+	last := map[string]time.Time{}
+	return func(q database.SlowQuery) {
+		key := q.Operation + "|" + q.Table + "|" + q.Filter
+		now := time.Now()
+		mu.Lock()
+		if t, ok := last[key]; ok && now.Sub(t) < slowQueryCooldown {
+			mu.Unlock()
+			return
+		}
+		last[key] = now
+		mu.Unlock()
+
+		severity := router.WarningWarning
+		if q.Err != nil {
+			severity = router.WarningError
+		}
+		ws.Add(router.WarningCategoryResource, severity, slowQueryMessage(q), "platform-repository")
+	}
+}
+
+func slowQueryMessage(q database.SlowQuery) string {
+	table := q.Table
+	if q.Operation != "" {
+		table = fmt.Sprintf("%s (%s)", q.Table, q.Operation)
+	}
+	msg := fmt.Sprintf("slow query on %s took %dms", table, q.Duration.Milliseconds())
+	if q.Filter != "" {
+		msg += "; filter: " + q.Filter
+	} else {
+		msg += "; no filter"
+	}
+	if q.Err != nil {
+		msg += "; failed: " + q.Err.Error()
+	}
+	return msg + " - check for a missing index"
+}
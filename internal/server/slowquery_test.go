@@ -0,0 +1,41 @@
+package server
+
+import (
+	"errors"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/database"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+)
+
+func TestSlowQueryWarnings(t *testing.T) {
+	ws := router.NewWarningService(router.WarningServiceConfig{})
+	warn := slowQueryWarnings(ws)
+
+	q := database.SlowQuery{Operation: "ClientSearch", Table: "tnt_clients", Filter: "name ILIKE $1", Duration: 1200 * time.Millisecond}
+	warn(q)
+	warn(q) // same shape inside the cooldown: suppressed
+	warn(database.SlowQuery{Table: "msg_events", Duration: 2 * time.Second, Err: errors.New("context deadline exceeded")})
+
+	got := ws.All()
+	if len(got) != 2 {
+		t.Fatalf("warnings = %d, want 2", len(got))
+	}
+	var sawSearch, sawFailed bool
+	for _, w := range got {
+		if w.Category != router.WarningCategoryResource || w.Source != "platform-repository" {
+			t.Errorf("warning = %+v", w)
+		}
+		if strings.Contains(w.Message, "tnt_clients (ClientSearch) took 1200ms; filter: name ILIKE $1") {
+			sawSearch = w.Severity == router.WarningWarning
+		}
+		if strings.Contains(w.Message, "msg_events took 2000ms; no filter; failed:") {
+			sawFailed = w.Severity == router.WarningError
+		}
+	}
+	if !sawSearch || !sawFailed {
+		t.Errorf("messages = %+v", got)
+	}
+}
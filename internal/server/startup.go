@@ -0,0 +1,113 @@
+package server
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+
+	outboxmongo "github.com/flowcatalyst/flowcatalyst-go/internal/outbox/mongo"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/startup"
+)
+
+// StartupConfig builds the dependency-probe retry budget from
+// FC_STARTUP_PROBE_*; zero values fall back to startup.DefaultConfig.
+func StartupConfig(cfg EnvCfg) startup.Config {
+	return startup.Config{
+		Attempts:       cfg.StartupProbeAttempts,
+		InitialBackoff: time.Duration(cfg.StartupProbeBackoffMS) * time.Millisecond,
+		MaxBackoff:     time.Duration(cfg.StartupProbeMaxBackoffMS) * time.Millisecond,
+	}
+}
+
+// StartupDependencies lists the external systems the enabled subsystems
+// need, other than Postgres (the binary probes that by opening its pool).
+//
+//   - standby Redis, when FC_STANDBY_ENABLED — optional: leader gates
+//     fail closed without it and take over once it is reachable, and the
+//     "redis-degradation" health check reports the outage
+//   - the outbox Mongo, when the outbox runs on the mongo backend
+//   - the router config service, when FC_ROUTER_ENABLED with a config URL
+//     and the platform is NOT in-process (it would be probing itself before
+//     it listens)
+//   - the router notification webhook — optional: the router starts
+//     degraded and the "startup" health check reports it
+//
+// Queue brokers (SQS, NATS) aren't probed: their addresses come from the
+// router config fetched after startup, and the consumers already retry.
+func StartupDependencies(cfg EnvCfg) []startup.Dependency {
+	var deps []startup.Dependency
+	if cfg.StandbyEnabled {
+		deps = append(deps, startup.Dependency{Name: "redis", Optional: true, Probe: startup.RedisProbe(cfg.StandbyRedisURL)})
+	}
+	if cfg.OutboxEnabled && (cfg.OutboxBackend == "mongo" || cfg.OutboxBackend == "mongodb") && cfg.OutboxMongoURI != "" {
+		deps = append(deps, startup.Dependency{Name: "mongo", Probe: startup.MongoProbe(outboxmongo.ClientOptions(cfg.OutboxMongoConfig()))})
+	}
+	if cfg.RouterEnabled && cfg.RouterConfigURL != "" && !cfg.PlatformEnabled {
+		deps = append(deps, startup.Dependency{
+			Name:  "router-config",
+			Probe: startup.HTTPProbe(cfg.RouterConfigURL, http.StatusInternalServerError),
+		})
+	}
+	if cfg.RouterEnabled && cfg.RouterNotifyWebhookURL != "" {
+		deps = append(deps, startup.Dependency{
+			Name:     "notify-webhook",
+			Optional: true,
+			Probe:    startup.HTTPProbe(cfg.RouterNotifyWebhookURL, 600),
+		})
+	}
+	return deps
+}
+
+// ServeStartupStatus answers the metrics-port probes while orch is still
+// probing, so Kubernetes (and whoever is watching the rollout) sees which
+// dependency startup is waiting on instead of a refused connection:
+// liveness is UP, readiness and /q/health are 503 with the per-dependency
+// state. The returned stop must be called before Run binds the port.
+func ServeStartupStatus(cfg EnvCfg, orch *startup.Orchestrator) (stop func()) {
+	r := chi.NewRouter()
+	r.Get("/health", healthHandler)
+	r.Get("/q/health/live", func(w http.ResponseWriter, _ *http.Request) {
+		writeHealthReport(w, HealthReport{Status: healthUp, Checks: []HealthCheck{}})
+	})
+	starting := func(w http.ResponseWriter, _ *http.Request) {
+		check := HealthCheck{Name: "startup", Status: healthDown, Data: map[string]any{"dependencies": orch.Status()}}
+		writeHealthReport(w, HealthReport{Status: healthDown, Checks: []HealthCheck{check}})
+	}
+	r.Get("/ready", starting)
+	r.Get("/q/health", starting)
+	r.Get("/q/health/ready", starting)
+
+	srv := &http.Server{
+		Addr:              fmt.Sprintf(":%d", cfg.MetricsPort),
+		Handler:           r,
+		ReadHeaderTimeout: 5 * time.Second,
+	}
+	go func() {
+		if err := srv.ListenAndServe(); err != nil && !errors.Is(err, http.ErrServerClosed) {
+			slog.Warn("startup status listener failed", "addr", srv.Addr, "err", err)
+		}
+	}()
+	return func() {
+		ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+		defer cancel()
+		_ = srv.Shutdown(ctx)
+	}
+}
+
+// startupHealth surfaces how startup went once the binary is serving.
+// Informational: a degraded optional dependency is worth seeing on
+// /q/health, but the instance chose to start without it.
+func startupHealth(orch *startup.Orchestrator) HealthProvider {
+	return func(context.Context) (map[string]any, error) {
+		data := map[string]any{"dependencies": orch.Status()}
+		if degraded := orch.Degraded(); len(degraded) > 0 {
+			return data, fmt.Errorf("started without optional dependencies: %v", degraded)
+		}
+		return data, nil
+	}
+}
@@ -18,12 +18,15 @@ import (
 	"github.com/jackc/pgx/v5/pgxpool"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/dispatchcancel"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/mcp"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/outbox"
 	outboxmongo "github.com/flowcatalyst/flowcatalyst-go/internal/outbox/mongo"
 	outboxpg "github.com/flowcatalyst/flowcatalyst-go/internal/outbox/postgres"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/bridge"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/payload"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduledjob"
 	sjscheduler "github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduledjob/scheduler"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduler"
@@ -69,10 +72,24 @@ func StartScheduler(ctx context.Context, pool *pgxpool.Pool, cfg EnvCfg) {
 	if c, ok := pub.(interface{ Stop() }); ok {
 		defer c.Stop()
 	}
+	regional, err := schedulerRegionPublishers(ctx, cfg)
+	if err != nil {
+		slog.Error("scheduler disabled: cannot build region dispatch publishers", "err", err)
+		return
+	}
+	for _, rp := range regional {
+		if c, ok := rp.(interface{ Stop() }); ok {
+			defer c.Stop()
+		}
+	}
 	scfg := scheduler.DefaultConfig()
 	scfg.ProcessingEndpoint = cfg.DispatchProcessingEndpoint
 	s := scheduler.New(scfg, pool, pub, secret)
 	s.IsLeader = newLeaderGate(ctx, cfg, "scheduler")
+	killSwitches := killswitch.NewCache(killswitch.NewRepository(pool), killswitch.DefaultCacheTTL)
+	go killSwitches.Listen(ctx, killswitch.BuildSignal(ctx))
+	s.SetKillSwitches(killSwitches)
+	s.SetRegionPublishers(regional)
 	s.Run(ctx)
 	slog.Info("scheduler stopped")
 }
@@ -111,6 +128,26 @@ func schedulerPublisher(ctx context.Context, cfg EnvCfg) (queue.Publisher, error
 	return NoopPublisher{}, nil
 }
 
+// schedulerRegionPublishers opens one publisher per FC_SCHEDULER_REGION_QUEUES
+// entry: the queue a data-residency region's router consumes, which the
+// dispatcher publishes that region's pools' jobs to.
+func schedulerRegionPublishers(ctx context.Context, cfg EnvCfg) (map[string]queue.Publisher, error) {
+	uris, err := scheduler.ParseRegionQueues(cfg.SchedulerRegionQueues)
+	if err != nil {
+		return nil, err
+	}
+	out := make(map[string]queue.Publisher, len(uris))
+	for region, uri := range uris {
+		pub, err := queue.NewPublisher(ctx, common.QueueConfig{Name: "dispatch-" + region, URI: uri, Connections: 1})
+		if err != nil {
+			return nil, fmt.Errorf("region %q dispatch publisher: %w", region, err)
+		}
+		out[region] = pub
+		slog.Info("scheduler: region dispatch queue", "region", region, "queue", "dispatch-"+region)
+	}
+	return out, nil
+}
+
 // dispatchAuthSecret derives the HMAC key for dispatch-job auth tokens from
 // FLOWCATALYST_APP_KEY via HKDF-SHA256 with a purpose-bound info string.
 // Deriving (rather than reusing the raw key) keeps the field-encryption key
@@ -148,8 +185,17 @@ func StartScheduledJobScheduler(ctx context.Context, pool *pgxpool.Pool, cfg Env
 // instance). When enabled it runs a dedicated Redis election on a
 // subsystem-suffixed lock key, so it elects independently of the router's own
 // election (sharing the router's exact key with a different instance id would
-// starve this gate). The election is stopped when ctx is cancelled.
+// starve this gate). The election is stopped when ctx is cancelled. Inside
+// Run the gate is also attached to the subsystem's /ready entry.
 func newLeaderGate(ctx context.Context, cfg EnvCfg, subsystem string) func() bool {
+	gate := leaderGate(ctx, cfg, subsystem)
+	if h := healthFrom(ctx); h != nil {
+		h.subsystems.setLeader(subsystem, gate)
+	}
+	return gate
+}
+
+func leaderGate(ctx context.Context, cfg EnvCfg, subsystem string) func() bool {
 	if !cfg.StandbyEnabled {
 		return func() bool { return true }
 	}
@@ -166,6 +212,9 @@ func newLeaderGate(ctx context.Context, cfg EnvCfg, subsystem string) func() boo
 		slog.Error("leader election init failed; failing closed (never leader)", "subsystem", subsystem, "err", err)
 		return func() bool { return false }
 	}
+	if h := healthFrom(ctx); h != nil {
+		h.Register("standby-redis", false, pingHealth(el.Ping))
+	}
 	if err := el.Start(ctx); err != nil {
 		slog.Error("leader election start failed; failing closed (never leader)", "subsystem", subsystem, "err", err)
 		return func() bool { return false }
@@ -257,6 +306,9 @@ func StartStreamProcessorWithHealth(ctx context.Context, pool *pgxpool.Pool, cfg
 		if cfg.StreamFanOutSubsRefreshSecs > 0 {
 			foCfg.SubscriptionTTL = time.Duration(cfg.StreamFanOutSubsRefreshSecs) * time.Second
 		}
+		if cfg.StreamFanOutMaxAttempts > 0 {
+			foCfg.MaxAttempts = cfg.StreamFanOutMaxAttempts
+		}
 		p := registerProjector("event_fan_out",
 			stream.NewFanOutWithConfig(pool, foCfg).Projector(projCfg("FC_STREAM_FAN_OUT_BATCH_SIZE", 200)))
 		launch("event_fan_out", p.Run)
@@ -280,6 +332,68 @@ func StartStreamProcessorWithHealth(ctx context.Context, pool *pgxpool.Pool, cfg
 		}
 		launch("partition_manager", pm.Run)
 	}
+	if cfg.StreamRetentionEnabled {
+		// Per-event-type retention (msg_event_types data policy). Leader-gated
+		// with the rest of the stream processor so only one replica sweeps.
+		rr := stream.NewRetentionReaper(pool)
+		rr.Config = stream.RetentionReaperConfig{
+			BatchSize:    cfg.StreamRetentionBatchSize,
+			TickInterval: time.Duration(cfg.StreamRetentionTickMins) * time.Minute,
+		}
+		rr.IsLeader = streamLeader
+		if healths != nil {
+			h := stream.NewHealth("retention_reaper")
+			rr.Health = h
+			healths.Register(h)
+		}
+		launch("retention_reaper", rr.Run)
+	}
+	if cfg.StreamReadNamesEnabled && cfg.StreamDispatchJobsEnabled {
+		// Keeps the display names on msg_dispatch_jobs_read current after
+		// renames; its first pass backfills rows projected before they
+		// existed.
+		rn := stream.NewReadNameSync(pool)
+		rn.Config = stream.ReadNameSyncConfig{TickInterval: time.Duration(cfg.StreamReadNamesTickSecs) * time.Second}
+		rn.IsLeader = streamLeader
+		if healths != nil {
+			h := stream.NewHealth("read_name_sync")
+			rn.Health = h
+			healths.Register(h)
+		}
+		launch("read_name_sync", rn.Run)
+	}
+	if cfg.StreamDeliveryStatsEnabled {
+		// Hourly per-subscription delivery analytics for the dashboard,
+		// rolled up from dispatch attempts.
+		ds := stream.NewDeliveryStatsRollup(pool)
+		ds.Config = stream.DeliveryStatsConfig{
+			TickInterval: time.Duration(cfg.StreamDeliveryStatsTickSecs) * time.Second,
+			Retention:    time.Duration(cfg.StreamDeliveryStatsRetentionDays) * 24 * time.Hour,
+		}
+		ds.IsLeader = streamLeader
+		if healths != nil {
+			h := stream.NewHealth("delivery_stats_rollup")
+			ds.Health = h
+			healths.Register(h)
+		}
+		launch("delivery_stats_rollup", ds.Run)
+	}
+	if cfg.StreamAnomaliesEnabled {
+		// Flags busy event types going silent and subscription failure-rate
+		// spikes; see GET /api/anomalies.
+		ad := stream.NewAnomalyDetector(pool)
+		ad.Config = stream.AnomalyConfig{
+			Window:    time.Duration(cfg.StreamAnomaliesWindowMins) * time.Minute,
+			MinEvents: float64(cfg.StreamAnomaliesMinEvents),
+		}
+		ad.IsLeader = streamLeader
+		if healths != nil {
+			h := stream.NewHealth("anomaly_detector")
+			ad.Health = h
+			healths.Register(h)
+		}
+		launch("anomaly_detector", ad.Run)
+	}
 
 	wg.Wait()
 	slog.Info("stream processor stopped")
@@ -327,9 +441,51 @@ func StartOutboxProcessor(ctx context.Context, pool *pgxpool.Pool, cfg EnvCfg) {
 		pcfg.MaxConcurrentGroups = cfg.OutboxMaxConcurrentGroups
 	}
 	pcfg.BlockOnError = cfg.OutboxBlockOnError
+	if cfg.OutboxGroupPolicy != "" {
+		if pcfg.GroupPolicy, err = outbox.ParseGroupPolicy(cfg.OutboxGroupPolicy); err != nil {
+			slog.Error("FC_OUTBOX_GROUP_POLICY invalid", "err", err)
+			return
+		}
+	}
+	if pcfg.GroupPolicies, err = outbox.ParseGroupPolicies(cfg.OutboxGroupPolicies); err != nil {
+		slog.Error("FC_OUTBOX_GROUP_POLICIES invalid", "err", err)
+		return
+	}
+	if cfg.OutboxGroupParkSeconds > 0 {
+		pcfg.ParkDuration = time.Duration(cfg.OutboxGroupParkSeconds) * time.Second
+	}
+	if cfg.OutboxStuckGroupSeconds > 0 {
+		pcfg.StuckGroupThreshold = time.Duration(cfg.OutboxStuckGroupSeconds) * time.Second
+	}
+	pcfg.TwoPhasePublish = cfg.OutboxTwoPhasePublish
+	if pcfg.Blobs, err = blobOffloader(ctx, cfg); err != nil {
+		slog.Error("outbox blob store init failed", "err", err)
+		return
+	}
+	if cfg.OutboxRoutesPath != "" {
+		routing, err := outbox.LoadRoutingConfig(cfg.OutboxRoutesPath)
+		if err != nil {
+			slog.Error("FC_OUTBOX_ROUTES_PATH invalid", "err", err)
+			return
+		}
+		if pcfg.Targets, err = outbox.BuildSenders(ctx, routing, pcfg.HTTPTimeout); err != nil {
+			slog.Error("outbox route targets init failed", "err", err)
+			return
+		}
+		pcfg.Routes = routing.Routes
+	}
 
 	p := outbox.NewProcessor(pcfg, repo)
 	p.IsLeader = newLeaderGate(ctx, cfg, "outbox")
+	if h := healthFrom(ctx); h != nil {
+		h.Register("outbox-backend", false, outboxHealth(cfg.OutboxBackend, repo, p))
+		if m, ok := repo.(*outboxmongo.Repository); ok {
+			h.addInfrastructure(router.InfrastructureCheck{Name: "outbox", Kind: "mongo", Ping: m.Ping})
+		}
+	}
+	if reg := metricsFrom(ctx); reg != nil {
+		reg.MustRegister(p.Collector())
+	}
 
 	// Operational state-machine admin API (pause/resume/unblock/skip groups),
 	// localhost-only, when FC_OUTBOX_ADMIN_PORT is set.
@@ -358,22 +514,37 @@ func StartOutboxProcessor(ctx context.Context, pool *pgxpool.Pool, cfg EnvCfg) {
 // buildOutboxRepo selects the outbox backend. Returns an optional cleanup
 // func (non-nil for Mongo, which owns a client connection).
 func buildOutboxRepo(ctx context.Context, pool *pgxpool.Pool, cfg EnvCfg) (outbox.Repository, func(), error) {
+	table, err := outbox.ParseTableConfig(cfg.OutboxTableConfig)
+	if err != nil {
+		return nil, nil, fmt.Errorf("FC_OUTBOX_TABLE_CONFIG: %w", err)
+	}
+	if cfg.OutboxTwoPhasePublish && table.Columns.PublishedID == nil {
+		if table.IsCustom() {
+			return nil, nil, fmt.Errorf("FC_OUTBOX_TWO_PHASE_PUBLISH on a custom outbox table needs columns.publishedId in FC_OUTBOX_TABLE_CONFIG")
+		}
+		col := "published_id"
+		table.Columns.PublishedID = &col
+	}
 	switch cfg.OutboxBackend {
 	case "mongo", "mongodb":
+		if table.IsCustom() {
+			return nil, nil, fmt.Errorf("FC_OUTBOX_TABLE_CONFIG applies to the postgres backend only")
+		}
 		if cfg.OutboxMongoURI == "" {
 			return nil, nil, fmt.Errorf("FC_OUTBOX_BACKEND=mongo requires FC_OUTBOX_MONGO_URI")
 		}
-		repo, err := outboxmongo.Connect(ctx, cfg.OutboxMongoURI, cfg.OutboxMongoDB)
+		repo, err := outboxmongo.ConnectWithConfig(ctx, cfg.OutboxMongoConfig())
 		if err != nil {
 			return nil, nil, err
 		}
+		repo.SetClaimLease(time.Duration(cfg.OutboxMongoClaimLeaseSecs) * time.Second)
 		return repo, func() {
 			cctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
 			defer cancel()
 			_ = repo.Close(cctx)
 		}, nil
 	case "", "postgres", "postgresql":
-		return outboxpg.New(pool), nil, nil
+		return outboxpg.NewWithTable(pool, table), nil, nil
 	default:
 		return nil, nil, fmt.Errorf("unknown FC_OUTBOX_BACKEND %q (want postgres|mongo)", cfg.OutboxBackend)
 	}
@@ -457,6 +628,8 @@ func StartRouter(ctx context.Context, _ *pgxpool.Pool, cfg EnvCfg) {
 		StandbyEnabled:   cfg.StandbyEnabled,
 		StandbyRedisURL:  cfg.StandbyRedisURL,
 		StandbyLockKey:   cfg.StandbyLockKey,
+		AttemptSink:      routerAttemptSinkConfig(cfg),
+		Cancellations:    dispatchcancel.Build(ctx),
 	}
 	srv, err := router.NewServer(rcfg)
 	if err != nil {
@@ -510,6 +683,13 @@ func StartPurger(ctx context.Context, pool *pgxpool.Pool) {
 	}
 }
 
+// StartErasureWorker runs the data-subject erasure worker
+// (internal/platform/privacy). Requests are claimed with SKIP LOCKED, so
+// every platform replica can run one.
+func StartErasureWorker(ctx context.Context, pool *pgxpool.Pool) {
+	privacy.NewWorker(pool).Run(ctx)
+}
+
 // NoopPublisher satisfies queue.Publisher without doing anything. Used
 // when the scheduler is enabled but no queue backend is configured —
 // the poller still runs (so QUEUED rows drain into the noop), but no
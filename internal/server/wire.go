@@ -1,11 +1,23 @@
 package server
 
 import (
+	"context"
+	"time"
+
 	"github.com/go-chi/chi/v5"
 	"github.com/jackc/pgx/v5/pgxpool"
+	"github.com/prometheus/client_golang/prometheus"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/connect"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/grantstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/publicapi"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/idempotency"
 	platformsink "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/platformsink"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/publicevents"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/schemaregistry"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
 )
 
@@ -27,22 +39,80 @@ import (
 //	                   repo, build the use cases, build the api.State,
 //	                   register it on the huma API.
 //	wire_spec.go     — registerSpecRoutes: unauthenticated OpenAPI/Swagger
-func WirePlatform(r chi.Router, pool *pgxpool.Pool, cfg EnvCfg) error {
+//
+// ctx bounds the read-cache invalidation listener, the configuration
+// reconciler, the federation puller, the change data capture
+// connectors, the event archiver, the idempotency-key pruner and
+// egress-IP discovery; metrics (nil = not exported) receives the
+// platform's Prometheus collectors. The returned
+// Platform carries what Run bridges to the router.
+func WirePlatform(ctx context.Context, r chi.Router, pool *pgxpool.Pool, cfg EnvCfg, metrics prometheus.Registerer) (*Platform, error) {
 	// Wire the huma error transformer so handler-returned *usecase.Error
 	// values flow out as the canonical {code, message, details} envelope.
 	httpcompat.Init()
 
-	sink := platformsink.New()
+	// The read caches wrap the sink so a committed domain event drops
+	// the cached read models it changes; publicevents adds the
+	// customer-facing flowcatalyst:* copy of configuration changes.
+	caches := buildReadCaches()
+	sink := caches.registry.Sink(publicevents.Sink(platformsink.New()))
 	uow := usecasepgx.New(pool, sink)
 
 	repos := buildRepos(pool)
 	svcs, err := buildServices(cfg, pool, repos)
 	if err != nil {
-		return err
+		return nil, err
+	}
+	svcs.readCaches = caches
+	caches.attach(svcs)
+	svcs.reconciler = startReconciler(ctx, cfg, uow, repos, svcs)
+	startFederation(ctx, cfg, repos, svcs)
+	connectors, err := startConnectors(ctx, cfg, pool, repos, svcs)
+	if err != nil {
+		return nil, err
+	}
+	if err := startArchiver(ctx, cfg, repos, svcs); err != nil {
+		return nil, err
+	}
+	svcs.idempotency = idempotency.NewStore(pool, svcs.encSvc, time.Duration(cfg.IdempotencyTTLHours)*time.Hour)
+	go svcs.idempotency.Run(ctx, time.Hour)
+	if svcs.egressIPs, err = publicapi.NewEgressIPs(splitList(cfg.EgressIPs), cfg.EgressIPsDiscoverURL); err != nil {
+		return nil, err
+	}
+	go svcs.egressIPs.Run(ctx, time.Hour)
+	go svcs.dispatchAcks.RunExpiry(ctx, 30*time.Second)
+	go grantstore.Sweep(ctx, 15*time.Minute, map[string]grantstore.Expiring{
+		"authorization-codes": svcs.oauthTokenEP.AuthCodes,
+		"refresh-tokens":      svcs.oauthTokenEP.RefreshTokens,
+		"pending-auth":        svcs.oauthTokenEP.PendingAuth,
+		"device-codes":        svcs.oauthTokenEP.DeviceCodes,
+	})
+	if svcs.signingKeySrc != nil {
+		go svcs.runSigningKeyRefresh(ctx, cfg, svcs.signingKeySrc, svcs.signingKeys)
+	}
+	go caches.registry.Listen(ctx)
+	if metrics != nil {
+		metrics.MustRegister(caches.registry.Collector())
 	}
 
 	registerPublicRoutes(r, cfg, pool, uow, repos, svcs)
 	humaAPI := registerPlatformAPI(r, cfg, pool, uow, repos, svcs)
 	registerSpecRoutes(r, humaAPI)
-	return nil
+	return &Platform{Lockout: svcs.lockout, Lifecycles: svcs.lifecycles, SchemaRegistry: svcs.schemaRegistry, Connectors: connectors}, nil
+}
+
+// Platform is the part of the wired platform Run bridges to the other
+// co-tenanted subsystems.
+type Platform struct {
+	// Lockout raises a warning per credential lockout when the router's
+	// warning service is available.
+	Lockout *lockout.Guard
+	// Lifecycles raises a warning when a principal publishes a deprecated
+	// or sunset event type, under the same condition.
+	Lifecycles *eventtype.LifecycleCache
+	// SchemaRegistry, when configured, is reported in the health and
+	// infrastructure checks.
+	SchemaRegistry *schemaregistry.Client
+	// Connectors, when configured, are reported in the health checks.
+	Connectors *connect.Runner
 }
@@ -12,6 +12,8 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/publicapi"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduler"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/ratelimit"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
 )
 
@@ -30,6 +32,9 @@ func registerPublicRoutes(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *use
 	// (login-theme branding, platform feature flags). Mounted outside
 	// the auth middleware for the same reason as the login surface.
 	publicapi.New(repos.platformConfigRepo).RegisterRoutes(r)
+	// Delivery source addresses, fetched anonymously by webhook receivers
+	// that allowlist their callers.
+	svcs.egressIPs.RegisterRoutes(r)
 
 	// Unauthenticated password-reset flow (request/validate/confirm). Public
 	// like /auth/login. Email is delivered via the SMTP_* env (SendGrid in
@@ -61,6 +66,10 @@ func registerPublicRoutes(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *use
 	// expired session must redirect to login (not 401), and the handler
 	// validates the session cookie itself. Wrapped in the per-IP throttle.
 	svcs.oauthTokenEP.RegisterAuthorizeRoutes(r.With(ratelimit.IPLimitMiddleware(svcs.rlStore, ratelimit.BucketOAuthAuthorizeIP, svcs.rlPolicies.OAuthAuthorizeIP)))
+	// Device-flow verification (the user's half of RFC 8628) validates the
+	// session itself for the same reason, answering login_required so the
+	// SPA can bounce through login and come back.
+	svcs.oauthTokenEP.RegisterDeviceVerificationRoutes(r.With(ratelimit.IPLimitMiddleware(svcs.rlStore, ratelimit.BucketOAuthAuthorizeIP, svcs.rlPolicies.OAuthAuthorizeIP)))
 
 	// POST /api/dispatch/process — the message router's delivery callback.
 	// MUST be outside the bearer middleware: the router authenticates with the
@@ -68,8 +77,29 @@ func registerPublicRoutes(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *use
 	// JWT. Skipped only when the dispatch-auth secret can't be derived (no
 	// FLOWCATALYST_APP_KEY) — same fail-closed condition as StartScheduler.
 	if secret, err := dispatchAuthSecret(); err == nil {
-		dispatchprocessing.New(repos.dispatchJobRepo, scheduler.NewDispatchAuthService(secret)).Mount(r)
+		processing := newDispatchHandler(secret, repos, svcs)
+		processing.Mount(r)
 	} else {
 		slog.Warn("dispatch-processing callback not mounted: cannot derive dispatch-auth secret", "err", err)
 	}
 }
+
+// newDispatchHandler builds the delivery handler the way the callback uses
+// it; the tenant portal reuses it to send test deliveries that go out
+// exactly as real ones would.
+func newDispatchHandler(secret string, repos *repoSet, svcs *serviceSet) *dispatchprocessing.Handler {
+	h := dispatchprocessing.New(repos.dispatchJobRepo, scheduler.NewDispatchAuthService(secret))
+	h.SetHeaderSource(subscription.NewHeaderResolver(repos.subscriptionRepo, svcs.secrets))
+	h.SetSigner(signingkey.NewSigner(svcs.signingKeys))
+	h.SetOutbound(svcs.outbound)
+	h.SetTransports(repos.subscriptionRepo, svcs.transports)
+	h.SetTLSTrust(svcs.tlsTrust, repos.subscriptionRepo)
+	h.SetKillSwitches(svcs.killSwitches)
+	h.SetReceipts(svcs.receipts)
+	h.SetBatching(repos.subscriptionRepo)
+	h.SetPayloadFormats(repos.subscriptionRepo, repos.eventTypeRepo)
+	h.SetBlobs(svcs.blobs, repos.subscriptionRepo)
+	h.SetSchemaRegistry(svcs.schemaRegistry)
+	h.SetShadows(repos.subscriptionRepo)
+	return h
+}
@@ -3,10 +3,13 @@ package server
 import (
 	"github.com/jackc/pgx/v5/pgxpool"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/cors"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob"
@@ -14,16 +17,20 @@ import (
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/emaildomainmapping"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/event"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/identityprovider"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/loginattempt"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/passwordreset"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/platformconfig"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/process"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/resetapproval"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/role"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduledjob"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/streamdlq"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/webauthn"
 )
@@ -36,6 +43,7 @@ type repoSet struct {
 	roleRepo                    *role.Repository
 	applicationRepo             *application.Repository
 	applicationClientConfigRepo *application.ClientConfigRepo
+	producerBindingRepo         *application.ProducerBindingRepo
 	principalRepo               *principal.Repository
 	principalGrantRepo          *principal.ClientAccessGrantRepo
 	serviceAccountRepo          *serviceaccount.Repository
@@ -58,6 +66,12 @@ type repoSet struct {
 	webauthnCeremonyRepo        *webauthn.CeremonyRepository
 	resetTokenRepo              *passwordreset.Repository
 	resetApprovalRepo           *resetapproval.Repository
+	erasureRepo                 *privacy.Repository
+	streamDLQRepo               *streamdlq.Repository
+	killSwitchRepo              *killswitch.Repository
+	anomalyRepo                 *anomaly.Repository
+	federationRepo              *federation.Repository
+	archiveRepo                 *archive.Repository
 }
 
 func buildRepos(pool *pgxpool.Pool) *repoSet {
@@ -66,6 +80,7 @@ func buildRepos(pool *pgxpool.Pool) *repoSet {
 		roleRepo:                    role.NewRepository(pool),
 		applicationRepo:             application.NewRepository(pool),
 		applicationClientConfigRepo: application.NewClientConfigRepo(pool),
+		producerBindingRepo:         application.NewProducerBindingRepo(pool),
 		principalRepo:               principal.NewRepository(pool),
 		principalGrantRepo:          principal.NewClientAccessGrantRepo(pool),
 		serviceAccountRepo:          serviceaccount.NewRepository(pool),
@@ -88,5 +103,26 @@ func buildRepos(pool *pgxpool.Pool) *repoSet {
 		webauthnCeremonyRepo:        webauthn.NewCeremonyRepository(pool),
 		resetTokenRepo:              passwordreset.NewRepository(pool),
 		resetApprovalRepo:           resetapproval.NewRepository(pool),
+		erasureRepo:                 privacy.NewRepository(pool),
+		streamDLQRepo:               streamdlq.NewRepository(pool),
+		killSwitchRepo:              killswitch.NewRepository(pool),
+		anomalyRepo:                 anomaly.NewRepository(pool),
+		federationRepo:              federation.NewRepository(pool),
+		archiveRepo:                 archive.NewRepository(pool),
+	}
+}
+
+// configBundle is the repository set configuration export/import and the
+// reconciler load and write through.
+func (r *repoSet) configBundle() configbundle.Repos {
+	return configbundle.Repos{
+		DispatchPools: r.dispatchPoolRepo,
+		EventTypes:    r.eventTypeRepo,
+		Roles:         r.roleRepo,
+		AuthConfigs:   r.authRepo.ClientAuthConfigs,
+		Subscriptions: r.subscriptionRepo,
+		Clients:       r.clientRepo,
+		Connections:   r.connectionRepo,
+		Applications:  r.applicationRepo,
 	}
 }
@@ -9,14 +9,18 @@ import (
 	"github.com/go-chi/chi/v5"
 	"github.com/jackc/pgx/v5/pgxpool"
 
+	anomalyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly/api"
 	applicationapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/application/api"
+	archiveapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive/api"
 	auditapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit/api"
 	authapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/api"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/bridge"
 	clientselectionapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/clientselection"
+	lockoutapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout/api"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/login"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/loginbackoff"
 	clientapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/client/api"
+	configbundleapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle/api"
 	connectionapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection/api"
 	corsapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/cors/api"
 	dispatchjobapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/api"
@@ -24,13 +28,17 @@ import (
 	emaildomainapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/emaildomainmapping/api"
 	eventapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/event/api"
 	eventtypeapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype/api"
+	federationapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation/api"
 	identityproviderapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/identityprovider/api"
+	killswitchapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch/api"
 	loginattemptapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/loginattempt/api"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/openapispecs"
 	passwordresetapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/passwordreset/api"
 	platformconfigapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/platformconfig/api"
 	principalapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal/api"
+	privacyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy/api"
 	processapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/process/api"
+	reconcilerapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/reconciler/api"
 	resetapprovalapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/resetapproval/api"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/role"
 	roleapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/role/api"
@@ -44,7 +52,10 @@ import (
 	meapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/me"
 	platformmw "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/middleware"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/ratelimit"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/redact"
 	sdkapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/sdk"
+	signingkeyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey/api"
+	streamdlqapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/streamdlq/api"
 	subscriptionapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription/api"
 	webauthnapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/webauthn/api"
 	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/usecasepgx"
@@ -80,7 +91,12 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 		r.Use(platformmw.Authenticator(platformmw.AuthConfig{
 			Provider:         svcs.authProvider,
 			AllowTestHeaders: cfg.AuthAllowTestHeaders,
+			Remote:           svcs.remoteAuth,
+			NetworkPolicy:    svcs.netPolicy,
 		}))
+		// Coarse route→permission gate from the central registry; every
+		// /api and /bff route below must be declared there.
+		r.Use(platformmw.RequireRoutePermissions)
 		// /auth/me — needs the AuthContext, so mounted INSIDE the auth
 		// group. /auth/check-domain + /auth/login + /auth/logout are
 		// public (see registerPublicRoutes).
@@ -102,6 +118,7 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 		// that want the schema can fetch it there.
 		humaCfg.SchemasPath = ""
 		humaAPI = humachi.New(r, humaCfg)
+		applyBodyLimits(humaAPI, "", bodyLimits(cfg))
 
 		// ── api.State + RegisterRoutes per subdomain ───────────────────
 		clientapi.Register(humaAPI, &clientapi.State{
@@ -125,6 +142,8 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 			Roles:            repos.roleRepo,
 			ServiceAccounts:  repos.serviceAccountRepo,
 			OAuthClients:     repos.authRepo.OAuthClients,
+			ProducerBindings: repos.producerBindingRepo,
+			EventTypes:       repos.eventTypeRepo,
 			UoW:              uow,
 		})
 
@@ -159,6 +178,7 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 			Principals:   repos.principalRepo,
 			OAuthClients: repos.authRepo.OAuthClients,
 			UoW:          uow,
+			Idem:         svcs.idempotency,
 		})
 
 		authapi.Register(humaAPI, &authapi.State{
@@ -175,6 +195,12 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 			ratelimit.GovernorMiddleware(svcs.oauthTokenIPGov, "rate limit exceeded for this IP"),
 			ratelimit.IPLimitMiddleware(svcs.rlStore, ratelimit.BucketOAuthTokenIP, svcs.rlPolicies.OAuthTokenIP),
 		))
+		// RFC 8628 device authorization shares /oauth/token's per-IP
+		// throttle: both are unauthenticated, client-credentialed entry points.
+		svcs.oauthTokenEP.RegisterDeviceAuthorizationRoutes(r.With(
+			ratelimit.GovernorMiddleware(svcs.oauthTokenIPGov, "rate limit exceeded for this IP"),
+			ratelimit.IPLimitMiddleware(svcs.rlStore, ratelimit.BucketOAuthTokenIP, svcs.rlPolicies.OAuthTokenIP),
+		))
 		svcs.oauthTokenEP.RegisterIntrospectRoutes(r)
 		svcs.oauthTokenEP.RegisterRevokeRoutes(r)
 		svcs.oauthTokenEP.RegisterUserinfoRoutes(r)
@@ -244,19 +270,33 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 			UoW:  uow,
 		})
 
+		signingkeyapi.Register(humaAPI, &signingkeyapi.State{
+			Repo:    svcs.signingKeys,
+			Clients: repos.clientRepo,
+			UoW:     uow,
+		})
+
 		subscriptionapi.Register(humaAPI, &subscriptionapi.State{
-			Repo: repos.subscriptionRepo,
-			UoW:  uow,
+			Repo:       repos.subscriptionRepo,
+			UoW:        uow,
+			Idem:       svcs.idempotency,
+			Targets:    svcs.outbound,
+			Transports: svcs.transports,
+			Lifecycles: svcs.lifecycles,
 		})
 
 		dispatchpoolapi.Register(humaAPI, &dispatchpoolapi.State{
-			Repo: repos.dispatchPoolRepo,
-			UoW:  uow,
+			Repo:  repos.dispatchPoolRepo,
+			UoW:   uow,
+			Cache: svcs.readCaches.dispatchPools,
+			Idem:  svcs.idempotency,
 		})
 
 		eventtypeapi.Register(humaAPI, &eventtypeapi.State{
-			Repo: repos.eventTypeRepo,
-			UoW:  uow,
+			Repo:     repos.eventTypeRepo,
+			UoW:      uow,
+			Idem:     svcs.idempotency,
+			Registry: svcs.schemaRegistry,
 		})
 
 		// SDK self-registration ("sync") endpoints, scoped under
@@ -273,11 +313,18 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 			ScheduledJobs: repos.scheduledJobRepo,
 			Specs:         openapispecs.NewRepository(pool),
 			UoW:           uow,
+			Targets:       svcs.outbound,
 		})
 
-		eventapi.Register(humaAPI, &eventapi.State{Repo: repos.eventRepo, Clients: repos.clientRepo})
+		// One redaction cache shared by the event and dispatch job views so
+		// a data-policy edit lands on both within the same refresh window.
+		redactRules := redact.NewRules(repos.eventTypeRepo.RedactionRules, 0)
+		eventapi.Register(humaAPI, &eventapi.State{Repo: repos.eventRepo, Clients: repos.clientRepo, Redact: redactRules, Outbound: svcs.outbound, Blobs: svcs.blobs, Schemas: schemaResolver(svcs.schemaRegistry, repos.eventTypeRepo), Lifecycles: svcs.lifecycles, Producers: svcs.producers})
 		auditapi.Register(humaAPI, &auditapi.State{Repo: repos.auditRepo})
-		dispatchjobapi.Register(humaAPI, &dispatchjobapi.State{Repo: repos.dispatchJobRepo})
+		dispatchJobState := &dispatchjobapi.State{Repo: repos.dispatchJobRepo, Redact: redactRules, Cancels: svcs.dispatchCancels, Acks: svcs.dispatchAcks, Pull: svcs.dispatchPull, Subscriptions: repos.subscriptionRepo}
+		dispatchjobapi.Register(humaAPI, dispatchJobState)
+		// The SSE consume stream is plain chi (huma is request/response).
+		dispatchjobapi.MountConsumeStream(r, dispatchJobState)
 
 		identityproviderapi.Register(humaAPI, &identityproviderapi.State{
 			Repo: repos.idpRepo,
@@ -293,11 +340,56 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 
 		loginattemptapi.Register(humaAPI, &loginattemptapi.State{Repo: repos.loginAttemptRepo})
 
+		lockoutapi.Register(humaAPI, &lockoutapi.State{Guard: svcs.lockout})
+
 		platformconfigapi.Register(humaAPI, &platformconfigapi.State{
 			Repo: repos.platformConfigRepo,
 			UoW:  uow,
 		})
 
+		privacyapi.Register(humaAPI, &privacyapi.State{
+			Repo: repos.erasureRepo,
+			UoW:  uow,
+		})
+
+		configbundleapi.Register(humaAPI, &configbundleapi.State{
+			Repos:  repos.configBundle(),
+			Guards: svcs.bundleGuards(),
+			UoW:    uow,
+		})
+
+		reconcilerapi.Register(humaAPI, &reconcilerapi.State{Reconciler: svcs.reconciler})
+
+		streamdlqapi.Register(humaAPI, &streamdlqapi.State{Repo: repos.streamDLQRepo})
+
+		killswitchapi.Register(humaAPI, &killswitchapi.State{
+			Repo:   repos.killSwitchRepo,
+			UoW:    uow,
+			Signal: svcs.killSwitchSignal,
+		})
+
+		anomalyapi.Register(humaAPI, &anomalyapi.State{
+			Repo: repos.anomalyRepo,
+			UoW:  uow,
+		})
+
+		federationapi.Register(humaAPI, &federationapi.State{
+			Repo:       repos.federationRepo,
+			Events:     repos.eventRepo,
+			Clients:    repos.clientRepo,
+			UoW:        uow,
+			Enc:        svcs.encSvc,
+			Blobs:      svcs.blobs,
+			InstanceID: cfg.FederationInstanceID,
+		})
+
+		archiveapi.Register(humaAPI, &archiveapi.State{
+			Repo:          repos.archiveRepo,
+			Subscriptions: repos.subscriptionRepo,
+			UoW:           uow,
+			StoreURI:      cfg.ArchiveStoreURI,
+		})
+
 		processapi.Register(humaAPI, &processapi.State{
 			Repo: repos.processRepo,
 			UoW:  uow,
@@ -327,12 +419,15 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 		// Shared BFF/SDK endpoints (dashboard + SDK ingest)
 		bff.RegisterRoutes(r, &bff.DashboardState{Pool: pool})
 		bff.RegisterFilterOptions(r, &bff.FilterOptionsState{
-			Clients:    repos.clientRepo,
-			EventTypes: repos.eventTypeRepo,
+			Clients:         repos.clientRepo,
+			EventTypes:      repos.eventTypeRepo,
+			ClientCache:     svcs.readCaches.clients,
+			EventTypesCache: svcs.readCaches.eventTypes,
 		})
 		bff.RegisterEventTypes(r, &bff.EventTypesState{
-			Repo: repos.eventTypeRepo,
-			UoW:  uow,
+			Repo:  repos.eventTypeRepo,
+			UoW:   uow,
+			Cache: svcs.readCaches.eventTypes,
 		})
 		bff.RegisterRoles(r, &bff.RolesState{
 			Roles:        repos.roleRepo,
@@ -357,6 +452,27 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 				return humaAPI.OpenAPI().MarshalJSON()
 			},
 		})
+		bff.RegisterSubscriptionAnalytics(r, &bff.SubscriptionAnalyticsState{
+			Pool:          pool,
+			Subscriptions: repos.subscriptionRepo,
+		})
+		// Tenant self-service portal. The test endpoint needs the delivery
+		// handler, so it is absent when the dispatch-auth secret is.
+		var prober bff.Prober
+		if secret, err := dispatchAuthSecret(); err == nil {
+			prober = newDispatchHandler(secret, repos, svcs)
+		}
+		bff.RegisterPortal(r, &bff.PortalState{
+			Subscriptions: repos.subscriptionRepo,
+			DispatchJobs:  repos.dispatchJobRepo,
+			SigningKeys:   svcs.signingKeys,
+			Clients:       repos.clientRepo,
+			UoW:           uow,
+			Targets:       svcs.outbound,
+			Transports:    svcs.transports,
+			Lifecycles:    svcs.lifecycles,
+			Prober:        prober,
+		})
 		meapi.RegisterRoutes(r, &meapi.State{Principals: repos.principalRepo, Applications: repos.applicationRepo, Clients: repos.clientRepo, AppConfigs: repos.applicationClientConfigRepo})
 		clientselectionapi.RegisterRoutes(r, &clientselectionapi.State{
 			Principals: repos.principalRepo,
@@ -365,7 +481,7 @@ func registerPlatformAPI(r chi.Router, cfg EnvCfg, pool *pgxpool.Pool, uow *usec
 			Grants:     repos.principalGrantRepo,
 			Auth:       svcs.authSvc,
 		})
-		sdkapi.RegisterRoutes(r, &sdkapi.DispatchJobsBatchState{Repo: repos.dispatchJobRepo})
+		sdkapi.RegisterRoutes(r, &sdkapi.DispatchJobsBatchState{Repo: repos.dispatchJobRepo, Outbound: svcs.outbound})
 		sdkapi.RegisterAuditRoutes(r, &sdkapi.AuditBatchState{Repo: repos.auditRepo, Apps: repos.applicationRepo, Clients: repos.clientRepo})
 	})
 
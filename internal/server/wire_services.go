@@ -7,23 +7,44 @@ import (
 
 	"github.com/jackc/pgx/v5/pgxpool"
 
+	"github.com/flowcatalyst/flowcatalyst-go/internal/blobstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/dispatchcancel"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/envutil"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/outbound"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/application"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/authservice"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/grantstore"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/login"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/loginbackoff"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/mfatoken"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/oauthapi"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/provider"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/remoteauth"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/twofa"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/branding"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/client"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle"
+	dispatchprocessing "github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/processing"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchpool"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/mfa"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/notify"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/publicapi"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/receipt"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/reconciler"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/email"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/encryption"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/idempotency"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/netpolicy"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/ratelimit"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/readcache"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/versioncache"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/webauthn"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/schemaregistry"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/secrets"
 )
 
 // serviceSet bundles the shared services WirePlatform threads through the
@@ -31,7 +52,12 @@ import (
 // original wire.go locals so the wiring code reads as `svcs.<old name>`.
 type serviceSet struct {
 	authProvider        *provider.Provider
+	remoteAuth          *remoteauth.Validator
 	authSvc             *authservice.AuthService
+	// signingKeys are the JWT keys in use; signingKeySrc, with the keyring
+	// on, reloads and rotates them (runSigningKeyRefresh).
+	signingKeys   signingKeys
+	signingKeySrc *signingKeySource
 	encSvc              *encryption.Service
 	rlStore             ratelimit.Store
 	rlPolicies          ratelimit.Policies
@@ -46,30 +72,69 @@ type serviceSet struct {
 	notifier            *notify.Notifier
 	twofaPolicy         twofa.Policy
 	loginEP             *login.Endpoint
+	lockout             *lockout.Guard
+	netPolicy           *netpolicy.Enforcer
 	principalVersions   *versioncache.Reader
+	readCaches          *readCacheSet
+	reconciler          *reconciler.Reconciler
+	idempotency         *idempotency.Store
+	egressIPs           *publicapi.EgressIPs
+	secrets             *secrets.Service
+	signingKeys         *signingkey.Repository
+	outbound            *outbound.Policies
+	transports          *outbound.Transports
+	tlsTrust            outbound.TLSTrust
+	dispatchCancels     dispatchcancel.Store
+	killSwitches        *killswitch.Cache
+	killSwitchSignal    killswitch.Signal
+	receipts            *receipt.Emitter
+	dispatchAcks        *dispatchprocessing.Acks
+	dispatchPull        *dispatchprocessing.Pull
+	blobs               *blobstore.Offloader
+	schemaRegistry      *schemaregistry.Client
+	lifecycles          *eventtype.LifecycleCache
+	producers           *application.ProducerRegistry
 }
 
 func buildServices(cfg EnvCfg, pool *pgxpool.Pool, repos *repoSet) (*serviceSet, error) {
 	svcs := &serviceSet{}
 
 	// ── Auth provider (claims projection + session JWTs) ───────────────
-	// SigningKey is supplied via cfg.JWTSigningKeyPath in production. In
-	// dev we fall back to a generated ephemeral key so the binary can
-	// boot without filesystem deps. See fc-dev for the persistent-key
-	// path used by local development.
-	signingKey := LoadSigningKeyOrEphemeral(cfg.JWTSigningKeyPath)
+	// SigningKey is supplied via cfg.JWTSigningKeyPath in production, or
+	// from the persisted keyring (FC_JWT_KEYRING_ENABLED) which also carries
+	// the rotation's validation-only keys. In dev we fall back to a
+	// generated ephemeral key so the binary can boot without filesystem
+	// deps. See fc-dev for the persistent-key path used by local development.
+	// encSvc is needed first: it seals the keyring at rest.
+	var err error
+	svcs.encSvc, err = encryption.FromEnv()
+	if err != nil {
+		return nil, fmt.Errorf("encryption init: %w", err)
+	}
+	keys, keySrc, err := loadSigningKeys(context.Background(), cfg, pool, svcs.encSvc)
+	if err != nil {
+		return nil, err
+	}
+	svcs.signingKeys, svcs.signingKeySrc = keys, keySrc
+	signingKey := keys.signing
+	verificationKeys := providerVerificationKeys(cfg, keys)
 	authProvider, err := provider.NewProvider(provider.Config{
 		Issuer: cfg.JWTIssuer,
 		// Must match authservice's access-token `aud` below so bearers it
 		// mints validate, while OIDC ID tokens (aud = an RP's client_id,
 		// same signing key) are rejected by the middleware.
-		Audience:   cfg.JWTIssuer,
-		SigningKey: signingKey,
+		Audience:         cfg.JWTIssuer,
+		SigningKey:       signingKey,
+		VerificationKeys: verificationKeys,
 	}, repos.principalRepo, repos.roleRepo)
 	if err != nil {
 		return nil, fmt.Errorf("auth provider init: %w", err)
 	}
 	svcs.authProvider = authProvider
+	// Remote mode: also accept bearer tokens from trusted external IdPs.
+	if svcs.remoteAuth, err = loadRemoteAuth(cfg); err != nil {
+		return nil, err
+	}
 
 	// ── Hand-rolled OAuth token service (/oauth/token) ────────────────
 	// authservice signs/validates with the same RSA key the auth provider
@@ -83,15 +148,41 @@ func buildServices(cfg EnvCfg, pool *pgxpool.Pool, repos *repoSet) (*serviceSet,
 		Audience:                cfg.JWTIssuer,
 		RSAPrivateKeyPEM:        string(signingKey),
 		RSAPublicKeyPreviousPEM: cfg.JWTPreviousPublicKey,
+		RSAVerificationKeysPEM:  keys.verification,
 		AccessTokenExpirySecs:   3600,
 		IDTokenExpirySecs:       300,
 	})
 	if err != nil {
 		return nil, fmt.Errorf("authservice init: %w", err)
 	}
-	svcs.encSvc, err = encryption.FromEnv()
+	// Encrypted-at-rest payloads (FC_PAYLOAD_ENCRYPTION_CLIENTS): per-client
+	// data keys wrapped with the app key. Set on the repositories before any
+	// route is served so sealed rows are always opened on read.
+	payloadCipher, err := NewPayloadCipher(cfg, pool, svcs.encSvc)
 	if err != nil {
-		return nil, fmt.Errorf("encryption init: %w", err)
+		return nil, fmt.Errorf("payload encryption init: %w", err)
+	}
+	repos.eventRepo.SetPayloadCipher(payloadCipher)
+	repos.dispatchJobRepo.SetPayloadCipher(payloadCipher)
+	// Client webhook signing keys are sealed with the same app key, so the
+	// repository lives here rather than in buildRepos.
+	svcs.signingKeys = signingkey.NewRepository(pool, svcs.encSvc)
+	// Secret references in configuration (subscription delivery headers),
+	// resolved at delivery time.
+	if svcs.secrets, err = newSecretService(cfg); err != nil {
+		return nil, err
+	}
+	// SSRF policy for subscription endpoints and webhook deliveries.
+	if svcs.outbound, err = outboundPolicies(cfg); err != nil {
+		return nil, err
+	}
+	// Named SSH/SOCKS5 transports subscriptions may deliver through.
+	if svcs.transports, err = outboundTransports(cfg, svcs.outbound, svcs.secrets); err != nil {
+		return nil, err
+	}
+	// Extra roots and minimum TLS version for webhook deliveries.
+	if svcs.tlsTrust, err = outboundTLS(cfg); err != nil {
+		return nil, err
 	}
 	// Distributed rate-limit store: Redis when FC_REDIS_URL is reachable,
 	// else Postgres, else Noop (FC_RATE_LIMIT_DISABLE=1). Throttles
@@ -105,6 +196,36 @@ func buildServices(cfg EnvCfg, pool *pgxpool.Pool, repos *repoSet) (*serviceSet,
 	svcs.oauthTokenIPGov = ratelimit.NewGovernor(ratelimit.OAuthTokenIPGovernorFromEnv())
 	svcs.oauthTokenClientGov = ratelimit.NewGovernor(ratelimit.OAuthTokenClientGovernorFromEnv())
 
+	// Progressive lockout on password login and /oauth/token, shared through
+	// the same Redis (per-instance without it). Locks are audited; Run also
+	// raises them as warnings when the router is co-tenanted.
+	lockoutStore, err := lockout.Build(context.Background())
+	if err != nil {
+		return nil, err
+	}
+	lockoutCfg := lockout.Config{
+		Store:  lockoutStore,
+		Policy: lockout.PolicyFromEnv(),
+		Audit:  repos.auditRepo,
+	}
+	if sv := lockout.SiteVerifyFromEnv(); sv != nil {
+		lockoutCfg.Challenge = sv
+	}
+	svcs.lockout = lockout.New(lockoutCfg)
+
+	// CIDR allowlists on OAuth clients and service accounts, matched against
+	// the caller address resolved through FC_TRUSTED_PROXIES. Checked at
+	// /oauth/token and, for service-account tokens, on every request.
+	proxies, err := netpolicy.ParseProxies(cfg.TrustedProxies)
+	if err != nil {
+		return nil, fmt.Errorf("FC_TRUSTED_PROXIES: %w", err)
+	}
+	svcs.netPolicy = netpolicy.New(netpolicy.Config{
+		Proxies: proxies,
+		Lookup:  repos.serviceAccountRepo.AllowedCIDRsForPrincipal,
+		Audit:   repos.auditRepo,
+	})
+
 	// Principal version cache: backs GET /api/principals/{id}/version, which
 	// SDKs (e.g. the Laravel SDK's opt-in revocation check) poll to catch a
 	// role/permission change before the caller's access token naturally
@@ -118,6 +239,42 @@ func buildServices(cfg EnvCfg, pool *pgxpool.Pool, repos *repoSet) (*serviceSet,
 		time.Duration(envutil.Int("FC_PRINCIPAL_VERSION_CACHE_TTL_SECS", 30))*time.Second,
 		repos.principalRepo.LookupVersion,
 	)
+
+	// Tombstones for cancelled dispatch jobs, checked by the router before
+	// mediating. Same Redis again; without it the processing callback still
+	// drops queued copies of cancelled jobs, one round-trip later.
+	svcs.dispatchCancels = dispatchcancel.Build(context.Background())
+
+	// Engaged kill switches, checked by the processing callback per job.
+	// The signal (Redis pub/sub when configured) invalidates every
+	// replica's cache on a flip; without it they converge by TTL.
+	svcs.killSwitchSignal = killswitch.BuildSignal(context.Background())
+	svcs.killSwitches = killswitch.NewCache(repos.killSwitchRepo, killswitch.DefaultCacheTTL)
+	go svcs.killSwitches.Listen(context.Background(), svcs.killSwitchSignal)
+
+	// Delivery receipts, sent by the processing callback and by consumer
+	// acks settling asynchronously accepted deliveries.
+	svcs.receipts = receipt.NewEmitter(repos.eventRepo, repos.dispatchJobRepo)
+	svcs.dispatchAcks = dispatchprocessing.NewAcks(repos.dispatchJobRepo, svcs.receipts)
+	// Pull subscriptions' consumers lease their jobs; the acks above settle them.
+	svcs.dispatchPull = dispatchprocessing.NewPull(repos.dispatchJobRepo, svcs.killSwitches)
+
+	// Oversized event payloads: offloaded at ingestion, read back (or
+	// pre-signed) on delivery. Nil when FC_BLOB_STORE_URI is unset.
+	if svcs.blobs, err = blobOffloader(context.Background(), cfg); err != nil {
+		return nil, err
+	}
+	svcs.dispatchPull.SetBlobs(svcs.blobs)
+
+	// Schema registry event types bind to. Nil when FC_SCHEMA_REGISTRY_URL
+	// is unset.
+	svcs.schemaRegistry = schemaRegistry(cfg)
+	// Deprecated event types, for the ingestion warnings and sunset
+	// rejections and the subscription binding check.
+	svcs.lifecycles = eventtype.NewLifecycleCache(repos.eventTypeRepo.Lifecycles, 0)
+	// Producer bindings, enforced on event ingestion.
+	svcs.producers = application.NewProducerRegistry(repos.producerBindingRepo.Grants, 0)
+
 	svcs.oauthTokenEP = &oauthapi.State{
 		OAuthClients:      repos.authRepo.OAuthClients,
 		Principals:        repos.principalRepo,
@@ -125,18 +282,22 @@ func buildServices(cfg EnvCfg, pool *pgxpool.Pool, repos *repoSet) (*serviceSet,
 		AuthCodes:         grantstore.NewAuthorizationCodeRepository(pool),
 		RefreshTokens:     grantstore.NewRefreshTokenRepository(pool),
 		PendingAuth:       grantstore.NewPendingAuthRepository(pool),
+		DeviceCodes:       grantstore.NewDeviceCodeRepository(pool),
 		Encryption:        svcs.encSvc,
 		BaseURL:           cfg.JWTIssuer,
 		LoginAttempts:     repos.loginAttemptRepo,
 		RateLimit:         svcs.rlStore,
 		RateLimitPolicies: svcs.rlPolicies,
 		ClientGovernor:    svcs.oauthTokenClientGov,
+		Lockout:           svcs.lockout,
+		NetworkPolicy:     svcs.netPolicy,
 		// /oauth/authorize treats an invalid/absent session as
 		// redirect-to-login, so it validates the session cookie itself
-		// (it's mounted outside the rejecting auth middleware).
+		// (it's mounted outside the rejecting auth middleware). A
+		// purpose-restricted session (passkey enrollment) is no session here.
 		ValidateSession: func(token string) (string, time.Time, bool) {
 			c, err := authProvider.ValidateSessionToken(context.Background(), token)
-			if err != nil || c == nil {
+			if err != nil || c == nil || c.Purpose != "" {
 				return "", time.Time{}, false
 			}
 			return c.Subject, c.IssuedAt, true
@@ -198,17 +359,79 @@ func buildServices(cfg EnvCfg, pool *pgxpool.Pool, repos *repoSet) (*serviceSet,
 		CookieSecure:      !cfg.AuthAllowTestHeaders,
 		LoginAttempts:     repos.loginAttemptRepo,
 		BackoffPolicy:     loginbackoff.PolicyFromEnv(),
+		Lockout:           svcs.lockout,
+		Proxies:           proxies,
 		// /auth/refresh shares the OAuth refresh-token store + access-token
 		// signer so a token issued via either path rotates identically.
 		RefreshTokens: svcs.oauthTokenEP.RefreshTokens,
 		Auth:          svcs.authSvc,
 		// 2FA: challenge/enroll endpoints. (A passkey does not exempt the
-		// password path, so no webauthn dependency here.)
+		// password path from 2FA.)
 		MFA:       svcs.mfaSvc,
 		MFATokens: svcs.mfaTokens,
 		Notifier:  svcs.notifier,
 		Audit:     repos.auditRepo,
+		// Per-client "require passkeys for admins" refuses the password path
+		// to admin-scope users who hold a passkey.
+		Passkeys: repos.webauthnCredRepo,
+		Clients:  repos.clientRepo,
 	})
 
 	return svcs, nil
 }
+
+// bundleGuards are the subscription checks configuration import and the
+// reconciler apply, the same ones the subscription API does.
+func (s *serviceSet) bundleGuards() configbundle.Guards {
+	return configbundle.Guards{Targets: s.outbound, Transports: s.transports, Lifecycles: s.lifecycles}
+}
+
+// readCacheSet is the read-model cache layer (see readcache): one
+// registry over the shared store, and the caches the dashboard's hottest
+// list/filter endpoints read through. Every cache is dropped after a
+// committed domain event of its subdomain, via the sink wrapper WirePlatform
+// installs on the unit of work.
+type readCacheSet struct {
+	registry      *readcache.Registry
+	eventTypes    *readcache.Cache[[]eventtype.EventType]
+	clients       *readcache.Cache[[]client.Client]
+	dispatchPools *readcache.Cache[[]dispatchpool.DispatchPool]
+}
+
+func buildReadCaches() *readCacheSet {
+	registry := readcache.NewRegistry(readcache.Build(context.Background()))
+	size := envutil.Int("FC_READ_CACHE_SIZE", 1_000)
+	ttl := time.Duration(envutil.Int("FC_READ_CACHE_TTL_SECS", 60)) * time.Second
+
+	c := &readCacheSet{
+		registry:      registry,
+		eventTypes:    readcache.NewCache[[]eventtype.EventType](registry, "event-types", size, ttl),
+		clients:       readcache.NewCache[[]client.Client](registry, "clients", size, ttl),
+		dispatchPools: readcache.NewCache[[]dispatchpool.DispatchPool](registry, "dispatch-pools", size, ttl),
+	}
+	// Prefixes cover both the per-row events and the bulk
+	// "...s:synced" events of the sync endpoints.
+	registry.InvalidateOn("platform:admin:eventtype", "event-types")
+	registry.InvalidateOn("platform:admin:client:", "clients")
+	registry.InvalidateOn("platform:admin:dispatch-pool", "dispatch-pools")
+	return c
+}
+
+// attach hooks the ingestion path's in-process views into the registry,
+// so the same committed events (and peer broadcasts) drop them. Grants
+// resolve through the application's active flag and service account as
+// well as the bindings themselves.
+func (c *readCacheSet) attach(svcs *serviceSet) {
+	c.registry.Attach("event-type-lifecycles", svcs.lifecycles)
+	c.registry.InvalidateOn("platform:admin:eventtype", "event-type-lifecycles")
+	c.registry.Attach("producer-grants", svcs.producers)
+	for _, prefix := range []string{
+		"platform:iam:application:producer-binding-",
+		"platform:iam:application:activated",
+		"platform:iam:application:deactivated",
+		"platform:iam:application:deleted",
+		"platform:iam:application:service-account-provisioned",
+	} {
+		c.registry.InvalidateOn(prefix, "producer-grants")
+	}
+}
@@ -0,0 +1,168 @@
+// Code generated by sqlc. DO NOT EDIT.
+// versions:
+//   sqlc v1.31.1
+// source: anomaly.sql
+
+package dbq
+
+import (
+	"context"
+	"time"
+)
+
+const anomalyFindByStatus = `-- name: AnomalyFindByStatus :many
+SELECT id, entity_kind, entity_key, kind, status, message, observed,
+       expected, z_score, raised_at, last_seen_at, resolved_at
+FROM msg_anomalies
+WHERE status = $1
+ORDER BY raised_at DESC, id
+LIMIT $2
+`
+
+type AnomalyFindByStatusParams struct {
+	Status string `db:"status"`
+	Limit  int32  `db:"limit"`
+}
+
+func (q *Queries) AnomalyFindByStatus(ctx context.Context, arg AnomalyFindByStatusParams) ([]MsgAnomaly, error) {
+	rows, err := q.db.Query(ctx, anomalyFindByStatus, arg.Status, arg.Limit)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []MsgAnomaly{}
+	for rows.Next() {
+		var i MsgAnomaly
+		if err := rows.Scan(
+			&i.ID,
+			&i.EntityKind,
+			&i.EntityKey,
+			&i.Kind,
+			&i.Status,
+			&i.Message,
+			&i.Observed,
+			&i.Expected,
+			&i.ZScore,
+			&i.RaisedAt,
+			&i.LastSeenAt,
+			&i.ResolvedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const anomalySettingFindAll = `-- name: AnomalySettingFindAll :many
+SELECT id, entity_kind, entity_key, sensitivity, enabled, snoozed_until,
+       updated_by, created_at, updated_at
+FROM msg_anomaly_settings
+ORDER BY entity_kind, entity_key
+`
+
+func (q *Queries) AnomalySettingFindAll(ctx context.Context) ([]MsgAnomalySetting, error) {
+	rows, err := q.db.Query(ctx, anomalySettingFindAll)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []MsgAnomalySetting{}
+	for rows.Next() {
+		var i MsgAnomalySetting
+		if err := rows.Scan(
+			&i.ID,
+			&i.EntityKind,
+			&i.EntityKey,
+			&i.Sensitivity,
+			&i.Enabled,
+			&i.SnoozedUntil,
+			&i.UpdatedBy,
+			&i.CreatedAt,
+			&i.UpdatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const anomalySettingFindByEntity = `-- name: AnomalySettingFindByEntity :one
+SELECT id, entity_kind, entity_key, sensitivity, enabled, snoozed_until,
+       updated_by, created_at, updated_at
+FROM msg_anomaly_settings
+WHERE entity_kind = $1 AND entity_key = $2
+`
+
+type AnomalySettingFindByEntityParams struct {
+	EntityKind string `db:"entity_kind"`
+	EntityKey  string `db:"entity_key"`
+}
+
+// Queries for msg_anomaly_settings and msg_anomalies. The detector
+// (internal/stream/anomalies.go) maintains baselines and raises and
+// resolves anomalies with its own SQL; these cover the use-case write path
+// and the API reads.
+func (q *Queries) AnomalySettingFindByEntity(ctx context.Context, arg AnomalySettingFindByEntityParams) (MsgAnomalySetting, error) {
+	row := q.db.QueryRow(ctx, anomalySettingFindByEntity, arg.EntityKind, arg.EntityKey)
+	var i MsgAnomalySetting
+	err := row.Scan(
+		&i.ID,
+		&i.EntityKind,
+		&i.EntityKey,
+		&i.Sensitivity,
+		&i.Enabled,
+		&i.SnoozedUntil,
+		&i.UpdatedBy,
+		&i.CreatedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const anomalySettingUpsert = `-- name: AnomalySettingUpsert :exec
+INSERT INTO msg_anomaly_settings
+    (id, entity_kind, entity_key, sensitivity, enabled, snoozed_until,
+     updated_by, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (id) DO UPDATE SET
+    sensitivity = EXCLUDED.sensitivity,
+    enabled = EXCLUDED.enabled,
+    snoozed_until = EXCLUDED.snoozed_until,
+    updated_by = EXCLUDED.updated_by,
+    updated_at = EXCLUDED.updated_at
+`
+
+type AnomalySettingUpsertParams struct {
+	ID           string     `db:"id"`
+	EntityKind   string     `db:"entity_kind"`
+	EntityKey    string     `db:"entity_key"`
+	Sensitivity  *float64   `db:"sensitivity"`
+	Enabled      bool       `db:"enabled"`
+	SnoozedUntil *time.Time `db:"snoozed_until"`
+	UpdatedBy    *string    `db:"updated_by"`
+	CreatedAt    time.Time  `db:"created_at"`
+	UpdatedAt    time.Time  `db:"updated_at"`
+}
+
+func (q *Queries) AnomalySettingUpsert(ctx context.Context, arg AnomalySettingUpsertParams) error {
+	_, err := q.db.Exec(ctx, anomalySettingUpsert,
+		arg.ID,
+		arg.EntityKind,
+		arg.EntityKey,
+		arg.Sensitivity,
+		arg.Enabled,
+		arg.SnoozedUntil,
+		arg.UpdatedBy,
+		arg.CreatedAt,
+		arg.UpdatedAt,
+	)
+	return err
+}
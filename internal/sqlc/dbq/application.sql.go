@@ -266,3 +266,137 @@ func (q *Queries) ClientConfigUpsert(ctx context.Context, arg ClientConfigUpsert
 	)
 	return err
 }
+
+const producerBindingDelete = `-- name: ProducerBindingDelete :exec
+DELETE FROM app_producer_bindings WHERE id = $1
+`
+
+func (q *Queries) ProducerBindingDelete(ctx context.Context, id string) error {
+	_, err := q.db.Exec(ctx, producerBindingDelete, id)
+	return err
+}
+
+const producerBindingFindByApp = `-- name: ProducerBindingFindByApp :many
+SELECT id, application_id, principal_id, event_type_pattern, created_at
+FROM app_producer_bindings
+WHERE application_id = $1
+ORDER BY event_type_pattern, created_at
+`
+
+func (q *Queries) ProducerBindingFindByApp(ctx context.Context, applicationID string) ([]AppProducerBinding, error) {
+	rows, err := q.db.Query(ctx, producerBindingFindByApp, applicationID)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []AppProducerBinding{}
+	for rows.Next() {
+		var i AppProducerBinding
+		if err := rows.Scan(
+			&i.ID,
+			&i.ApplicationID,
+			&i.PrincipalID,
+			&i.EventTypePattern,
+			&i.CreatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const producerBindingFindByID = `-- name: ProducerBindingFindByID :one
+SELECT id, application_id, principal_id, event_type_pattern, created_at
+FROM app_producer_bindings
+WHERE id = $1
+`
+
+func (q *Queries) ProducerBindingFindByID(ctx context.Context, id string) (AppProducerBinding, error) {
+	row := q.db.QueryRow(ctx, producerBindingFindByID, id)
+	var i AppProducerBinding
+	err := row.Scan(
+		&i.ID,
+		&i.ApplicationID,
+		&i.PrincipalID,
+		&i.EventTypePattern,
+		&i.CreatedAt,
+	)
+	return i, err
+}
+
+const producerBindingGrants = `-- name: ProducerBindingGrants :many
+SELECT b.id, b.application_id, a.code AS application_code, a.active AS application_active,
+       COALESCE(b.principal_id, a.service_account_id) AS principal_id,
+       b.event_type_pattern
+FROM app_producer_bindings b
+JOIN app_applications a ON a.id = b.application_id
+ORDER BY b.event_type_pattern, a.code
+`
+
+type ProducerBindingGrantsRow struct {
+	ID                string  `db:"id"`
+	ApplicationID     string  `db:"application_id"`
+	ApplicationCode   string  `db:"application_code"`
+	ApplicationActive bool    `db:"application_active"`
+	PrincipalID       *string `db:"principal_id"`
+	EventTypePattern  string  `db:"event_type_pattern"`
+}
+
+// Every binding with its application and the principal it admits: the
+// named one, or else the application's service account.
+func (q *Queries) ProducerBindingGrants(ctx context.Context) ([]ProducerBindingGrantsRow, error) {
+	rows, err := q.db.Query(ctx, producerBindingGrants)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []ProducerBindingGrantsRow{}
+	for rows.Next() {
+		var i ProducerBindingGrantsRow
+		if err := rows.Scan(
+			&i.ID,
+			&i.ApplicationID,
+			&i.ApplicationCode,
+			&i.ApplicationActive,
+			&i.PrincipalID,
+			&i.EventTypePattern,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const producerBindingInsert = `-- name: ProducerBindingInsert :exec
+INSERT INTO app_producer_bindings
+    (id, application_id, principal_id, event_type_pattern, created_at)
+VALUES ($1, $2, $3, $4, $5)
+ON CONFLICT (id) DO NOTHING
+`
+
+type ProducerBindingInsertParams struct {
+	ID               string    `db:"id"`
+	ApplicationID    string    `db:"application_id"`
+	PrincipalID      *string   `db:"principal_id"`
+	EventTypePattern string    `db:"event_type_pattern"`
+	CreatedAt        time.Time `db:"created_at"`
+}
+
+func (q *Queries) ProducerBindingInsert(ctx context.Context, arg ProducerBindingInsertParams) error {
+	_, err := q.db.Exec(ctx, producerBindingInsert,
+		arg.ID,
+		arg.ApplicationID,
+		arg.PrincipalID,
+		arg.EventTypePattern,
+		arg.CreatedAt,
+	)
+	return err
+}
@@ -0,0 +1,409 @@
+// Code generated by sqlc. DO NOT EDIT.
+// versions:
+//   sqlc v1.31.1
+// source: archive.sql
+
+package dbq
+
+import (
+	"context"
+	"time"
+)
+
+const archiveCursorAdvance = `-- name: ArchiveCursorAdvance :exec
+INSERT INTO msg_archive_cursors
+    (kind, after_time, after_id, archived, last_run_at, last_error, updated_at)
+VALUES ($1, $2, $3, $4, NOW(), NULL, NOW())
+ON CONFLICT (kind) DO UPDATE SET
+    after_time = EXCLUDED.after_time,
+    after_id = EXCLUDED.after_id,
+    archived = msg_archive_cursors.archived + EXCLUDED.archived,
+    last_run_at = NOW(),
+    last_error = NULL,
+    updated_at = NOW()
+`
+
+type ArchiveCursorAdvanceParams struct {
+	Kind      string    `db:"kind"`
+	AfterTime time.Time `db:"after_time"`
+	AfterID   string    `db:"after_id"`
+	Archived  int64     `db:"archived"`
+}
+
+// Moves a stream's cursor past a committed batch and clears its error.
+func (q *Queries) ArchiveCursorAdvance(ctx context.Context, arg ArchiveCursorAdvanceParams) error {
+	_, err := q.db.Exec(ctx, archiveCursorAdvance,
+		arg.Kind,
+		arg.AfterTime,
+		arg.AfterID,
+		arg.Archived,
+	)
+	return err
+}
+
+const archiveCursorFindAll = `-- name: ArchiveCursorFindAll :many
+
+SELECT kind, after_time, after_id, archived, last_run_at, last_error, updated_at
+FROM msg_archive_cursors
+ORDER BY kind
+`
+
+// Queries for msg_archive_cursors, msg_archive_files and msg_archive_replays.
+// The archiver (internal/platform/archive) writes the cursors and the
+// manifest, which the API reads; replays are written by their use cases
+// and the replayer.
+func (q *Queries) ArchiveCursorFindAll(ctx context.Context) ([]MsgArchiveCursor, error) {
+	rows, err := q.db.Query(ctx, archiveCursorFindAll)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []MsgArchiveCursor{}
+	for rows.Next() {
+		var i MsgArchiveCursor
+		if err := rows.Scan(
+			&i.Kind,
+			&i.AfterTime,
+			&i.AfterID,
+			&i.Archived,
+			&i.LastRunAt,
+			&i.LastError,
+			&i.UpdatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const archiveCursorRecordRun = `-- name: ArchiveCursorRecordRun :exec
+INSERT INTO msg_archive_cursors
+    (kind, after_time, after_id, archived, last_run_at, last_error, updated_at)
+VALUES ($1, 'epoch', '', 0, NOW(), $2, NOW())
+ON CONFLICT (kind) DO UPDATE SET
+    last_run_at = NOW(),
+    last_error = EXCLUDED.last_error,
+    updated_at = NOW()
+`
+
+type ArchiveCursorRecordRunParams struct {
+	Kind      string  `db:"kind"`
+	LastError *string `db:"last_error"`
+}
+
+// Stamps a run that archived nothing, with its error if it failed. A
+// stream never run before starts at the epoch.
+func (q *Queries) ArchiveCursorRecordRun(ctx context.Context, arg ArchiveCursorRecordRunParams) error {
+	_, err := q.db.Exec(ctx, archiveCursorRecordRun, arg.Kind, arg.LastError)
+	return err
+}
+
+const archiveFileInsert = `-- name: ArchiveFileInsert :exec
+INSERT INTO msg_archive_files
+    (id, kind, object_key, schema_version, partition_hour, records, bytes,
+     first_id, last_id, min_time, max_time, created_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NOW())
+`
+
+type ArchiveFileInsertParams struct {
+	ID            string    `db:"id"`
+	Kind          string    `db:"kind"`
+	ObjectKey     string    `db:"object_key"`
+	SchemaVersion int32     `db:"schema_version"`
+	PartitionHour time.Time `db:"partition_hour"`
+	Records       int32     `db:"records"`
+	Bytes         int64     `db:"bytes"`
+	FirstID       string    `db:"first_id"`
+	LastID        string    `db:"last_id"`
+	MinTime       time.Time `db:"min_time"`
+	MaxTime       time.Time `db:"max_time"`
+}
+
+func (q *Queries) ArchiveFileInsert(ctx context.Context, arg ArchiveFileInsertParams) error {
+	_, err := q.db.Exec(ctx, archiveFileInsert,
+		arg.ID,
+		arg.Kind,
+		arg.ObjectKey,
+		arg.SchemaVersion,
+		arg.PartitionHour,
+		arg.Records,
+		arg.Bytes,
+		arg.FirstID,
+		arg.LastID,
+		arg.MinTime,
+		arg.MaxTime,
+	)
+	return err
+}
+
+const archiveFileList = `-- name: ArchiveFileList :many
+SELECT id, kind, object_key, schema_version, partition_hour, records, bytes,
+       first_id, last_id, min_time, max_time, created_at
+FROM msg_archive_files
+WHERE ($1::text IS NULL OR kind = $1::text)
+  AND ($2::timestamptz IS NULL OR partition_hour >= $2::timestamptz)
+  AND ($3::timestamptz IS NULL OR partition_hour < $3::timestamptz)
+  AND ($4::text IS NULL OR id > $4::text)
+ORDER BY id
+LIMIT $5::int
+`
+
+type ArchiveFileListParams struct {
+	Kind    *string    `db:"kind"`
+	Since   *time.Time `db:"since"`
+	Until   *time.Time `db:"until"`
+	AfterID *string    `db:"after_id"`
+	Lim     int32      `db:"lim"`
+}
+
+func (q *Queries) ArchiveFileList(ctx context.Context, arg ArchiveFileListParams) ([]MsgArchiveFile, error) {
+	rows, err := q.db.Query(ctx, archiveFileList,
+		arg.Kind,
+		arg.Since,
+		arg.Until,
+		arg.AfterID,
+		arg.Lim,
+	)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []MsgArchiveFile{}
+	for rows.Next() {
+		var i MsgArchiveFile
+		if err := rows.Scan(
+			&i.ID,
+			&i.Kind,
+			&i.ObjectKey,
+			&i.SchemaVersion,
+			&i.PartitionHour,
+			&i.Records,
+			&i.Bytes,
+			&i.FirstID,
+			&i.LastID,
+			&i.MinTime,
+			&i.MaxTime,
+			&i.CreatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const archiveReplayFindByID = `-- name: ArchiveReplayFindByID :one
+SELECT id, from_time, to_time, event_types, client_id, subscription_id,
+       status, after_file_id, files_read, events_read, events_replayed,
+       error, requested_by, created_at, started_at, completed_at, updated_at
+FROM msg_archive_replays
+WHERE id = $1
+`
+
+func (q *Queries) ArchiveReplayFindByID(ctx context.Context, id string) (MsgArchiveReplay, error) {
+	row := q.db.QueryRow(ctx, archiveReplayFindByID, id)
+	var i MsgArchiveReplay
+	err := row.Scan(
+		&i.ID,
+		&i.FromTime,
+		&i.ToTime,
+		&i.EventTypes,
+		&i.ClientID,
+		&i.SubscriptionID,
+		&i.Status,
+		&i.AfterFileID,
+		&i.FilesRead,
+		&i.EventsRead,
+		&i.EventsReplayed,
+		&i.Error,
+		&i.RequestedBy,
+		&i.CreatedAt,
+		&i.StartedAt,
+		&i.CompletedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const archiveReplayFindNextOpen = `-- name: ArchiveReplayFindNextOpen :one
+SELECT id, from_time, to_time, event_types, client_id, subscription_id,
+       status, after_file_id, files_read, events_read, events_replayed,
+       error, requested_by, created_at, started_at, completed_at, updated_at
+FROM msg_archive_replays
+WHERE status IN ('PENDING', 'RUNNING')
+ORDER BY created_at
+LIMIT 1
+`
+
+// The oldest replay still to run; the replayer works them one at a time.
+func (q *Queries) ArchiveReplayFindNextOpen(ctx context.Context) (MsgArchiveReplay, error) {
+	row := q.db.QueryRow(ctx, archiveReplayFindNextOpen)
+	var i MsgArchiveReplay
+	err := row.Scan(
+		&i.ID,
+		&i.FromTime,
+		&i.ToTime,
+		&i.EventTypes,
+		&i.ClientID,
+		&i.SubscriptionID,
+		&i.Status,
+		&i.AfterFileID,
+		&i.FilesRead,
+		&i.EventsRead,
+		&i.EventsReplayed,
+		&i.Error,
+		&i.RequestedBy,
+		&i.CreatedAt,
+		&i.StartedAt,
+		&i.CompletedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const archiveReplayList = `-- name: ArchiveReplayList :many
+SELECT id, from_time, to_time, event_types, client_id, subscription_id,
+       status, after_file_id, files_read, events_read, events_replayed,
+       error, requested_by, created_at, started_at, completed_at, updated_at
+FROM msg_archive_replays
+ORDER BY created_at DESC
+LIMIT $1
+`
+
+func (q *Queries) ArchiveReplayList(ctx context.Context, limit int32) ([]MsgArchiveReplay, error) {
+	rows, err := q.db.Query(ctx, archiveReplayList, limit)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []MsgArchiveReplay{}
+	for rows.Next() {
+		var i MsgArchiveReplay
+		if err := rows.Scan(
+			&i.ID,
+			&i.FromTime,
+			&i.ToTime,
+			&i.EventTypes,
+			&i.ClientID,
+			&i.SubscriptionID,
+			&i.Status,
+			&i.AfterFileID,
+			&i.FilesRead,
+			&i.EventsRead,
+			&i.EventsReplayed,
+			&i.Error,
+			&i.RequestedBy,
+			&i.CreatedAt,
+			&i.StartedAt,
+			&i.CompletedAt,
+			&i.UpdatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const archiveReplayRecordProgress = `-- name: ArchiveReplayRecordProgress :execrows
+UPDATE msg_archive_replays
+SET status = $2,
+    after_file_id = $3,
+    files_read = $4,
+    events_read = $5,
+    events_replayed = $6,
+    error = $7,
+    started_at = $8,
+    completed_at = $9,
+    updated_at = NOW()
+WHERE id = $1 AND status IN ('PENDING', 'RUNNING')
+`
+
+type ArchiveReplayRecordProgressParams struct {
+	ID             string     `db:"id"`
+	Status         string     `db:"status"`
+	AfterFileID    *string    `db:"after_file_id"`
+	FilesRead      int32      `db:"files_read"`
+	EventsRead     int64      `db:"events_read"`
+	EventsReplayed int64      `db:"events_replayed"`
+	Error          *string    `db:"error"`
+	StartedAt      *time.Time `db:"started_at"`
+	CompletedAt    *time.Time `db:"completed_at"`
+}
+
+// Records a replayer pass. Matches nothing once the replay is terminal,
+// which is how the replayer notices a cancellation.
+func (q *Queries) ArchiveReplayRecordProgress(ctx context.Context, arg ArchiveReplayRecordProgressParams) (int64, error) {
+	result, err := q.db.Exec(ctx, archiveReplayRecordProgress,
+		arg.ID,
+		arg.Status,
+		arg.AfterFileID,
+		arg.FilesRead,
+		arg.EventsRead,
+		arg.EventsReplayed,
+		arg.Error,
+		arg.StartedAt,
+		arg.CompletedAt,
+	)
+	if err != nil {
+		return 0, err
+	}
+	return result.RowsAffected(), nil
+}
+
+const archiveReplayUpsert = `-- name: ArchiveReplayUpsert :exec
+INSERT INTO msg_archive_replays
+    (id, from_time, to_time, event_types, client_id, subscription_id,
+     status, error, requested_by, created_at, completed_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+ON CONFLICT (id) DO UPDATE SET
+    status = EXCLUDED.status,
+    error = EXCLUDED.error,
+    completed_at = EXCLUDED.completed_at,
+    updated_at = EXCLUDED.updated_at
+`
+
+type ArchiveReplayUpsertParams struct {
+	ID             string     `db:"id"`
+	FromTime       time.Time  `db:"from_time"`
+	ToTime         time.Time  `db:"to_time"`
+	EventTypes     []string   `db:"event_types"`
+	ClientID       *string    `db:"client_id"`
+	SubscriptionID *string    `db:"subscription_id"`
+	Status         string     `db:"status"`
+	Error          *string    `db:"error"`
+	RequestedBy    *string    `db:"requested_by"`
+	CreatedAt      time.Time  `db:"created_at"`
+	CompletedAt    *time.Time `db:"completed_at"`
+	UpdatedAt      time.Time  `db:"updated_at"`
+}
+
+// The use-case write path: a new replay, or a change of status. Progress
+// belongs to the replayer and is left alone.
+func (q *Queries) ArchiveReplayUpsert(ctx context.Context, arg ArchiveReplayUpsertParams) error {
+	_, err := q.db.Exec(ctx, archiveReplayUpsert,
+		arg.ID,
+		arg.FromTime,
+		arg.ToTime,
+		arg.EventTypes,
+		arg.ClientID,
+		arg.SubscriptionID,
+		arg.Status,
+		arg.Error,
+		arg.RequestedBy,
+		arg.CreatedAt,
+		arg.CompletedAt,
+		arg.UpdatedAt,
+	)
+	return err
+}
@@ -22,7 +22,7 @@ func (q *Queries) ClientDelete(ctx context.Context, id string) error {
 
 const clientFindAll = `-- name: ClientFindAll :many
 SELECT id, name, identifier, status, status_reason, status_changed_at,
-       notes, created_at, updated_at
+       notes, created_at, updated_at, require_passkey_for_admins, region
 FROM tnt_clients
 ORDER BY identifier
 `
@@ -46,6 +46,8 @@ func (q *Queries) ClientFindAll(ctx context.Context) ([]TntClient, error) {
 			&i.Notes,
 			&i.CreatedAt,
 			&i.UpdatedAt,
+			&i.RequirePasskeyForAdmins,
+			&i.Region,
 		); err != nil {
 			return nil, err
 		}
@@ -60,7 +62,7 @@ func (q *Queries) ClientFindAll(ctx context.Context) ([]TntClient, error) {
 const clientFindByID = `-- name: ClientFindByID :one
 
 SELECT id, name, identifier, status, status_reason, status_changed_at,
-       notes, created_at, updated_at
+       notes, created_at, updated_at, require_passkey_for_admins, region
 FROM tnt_clients
 WHERE id = $1
 `
@@ -81,13 +83,15 @@ func (q *Queries) ClientFindByID(ctx context.Context, id string) (TntClient, err
 		&i.Notes,
 		&i.CreatedAt,
 		&i.UpdatedAt,
+		&i.RequirePasskeyForAdmins,
+		&i.Region,
 	)
 	return i, err
 }
 
 const clientFindByIdentifier = `-- name: ClientFindByIdentifier :one
 SELECT id, name, identifier, status, status_reason, status_changed_at,
-       notes, created_at, updated_at
+       notes, created_at, updated_at, require_passkey_for_admins, region
 FROM tnt_clients
 WHERE identifier = $1
 `
@@ -105,13 +109,35 @@ func (q *Queries) ClientFindByIdentifier(ctx context.Context, identifier string)
 		&i.Notes,
 		&i.CreatedAt,
 		&i.UpdatedAt,
+		&i.RequirePasskeyForAdmins,
+		&i.Region,
 	)
 	return i, err
 }
 
+const clientRequiresPasskeyForAdmins = `-- name: ClientRequiresPasskeyForAdmins :one
+SELECT EXISTS(
+    SELECT 1 FROM tnt_clients
+    WHERE require_passkey_for_admins = true
+      AND ($1::boolean OR id = ANY($2::varchar[]))
+)
+`
+
+type ClientRequiresPasskeyForAdminsParams struct {
+	AllClients bool     `db:"all_clients"`
+	Ids        []string `db:"ids"`
+}
+
+func (q *Queries) ClientRequiresPasskeyForAdmins(ctx context.Context, arg ClientRequiresPasskeyForAdminsParams) (bool, error) {
+	row := q.db.QueryRow(ctx, clientRequiresPasskeyForAdmins, arg.AllClients, arg.Ids)
+	var exists bool
+	err := row.Scan(&exists)
+	return exists, err
+}
+
 const clientSearch = `-- name: ClientSearch :many
 SELECT id, name, identifier, status, status_reason, status_changed_at,
-       notes, created_at, updated_at
+       notes, created_at, updated_at, require_passkey_for_admins, region
 FROM tnt_clients
 WHERE name ILIKE $1 OR identifier ILIKE $1
 ORDER BY identifier
@@ -137,6 +163,8 @@ func (q *Queries) ClientSearch(ctx context.Context, pattern string) ([]TntClient
 			&i.Notes,
 			&i.CreatedAt,
 			&i.UpdatedAt,
+			&i.RequirePasskeyForAdmins,
+			&i.Region,
 		); err != nil {
 			return nil, err
 		}
@@ -151,9 +179,9 @@ func (q *Queries) ClientSearch(ctx context.Context, pattern string) ([]TntClient
 const clientUpsert = `-- name: ClientUpsert :exec
 INSERT INTO tnt_clients
     (id, name, identifier, status, status_reason, status_changed_at,
-     notes, created_at, updated_at)
+     notes, created_at, updated_at, require_passkey_for_admins, region)
 VALUES
-    ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
 ON CONFLICT (id) DO UPDATE SET
     name = EXCLUDED.name,
     identifier = EXCLUDED.identifier,
@@ -161,19 +189,23 @@ ON CONFLICT (id) DO UPDATE SET
     status_reason = EXCLUDED.status_reason,
     status_changed_at = EXCLUDED.status_changed_at,
     notes = EXCLUDED.notes,
-    updated_at = EXCLUDED.updated_at
+    updated_at = EXCLUDED.updated_at,
+    require_passkey_for_admins = EXCLUDED.require_passkey_for_admins,
+    region = EXCLUDED.region
 `
 
 type ClientUpsertParams struct {
-	ID              string          `db:"id"`
-	Name            string          `db:"name"`
-	Identifier      string          `db:"identifier"`
-	Status          string          `db:"status"`
-	StatusReason    *string         `db:"status_reason"`
-	StatusChangedAt *time.Time      `db:"status_changed_at"`
-	Notes           json.RawMessage `db:"notes"`
-	CreatedAt       time.Time       `db:"created_at"`
-	UpdatedAt       time.Time       `db:"updated_at"`
+	ID                      string          `db:"id"`
+	Name                    string          `db:"name"`
+	Identifier              string          `db:"identifier"`
+	Status                  string          `db:"status"`
+	StatusReason            *string         `db:"status_reason"`
+	StatusChangedAt         *time.Time      `db:"status_changed_at"`
+	Notes                   json.RawMessage `db:"notes"`
+	CreatedAt               time.Time       `db:"created_at"`
+	UpdatedAt               time.Time       `db:"updated_at"`
+	RequirePasskeyForAdmins bool            `db:"require_passkey_for_admins"`
+	Region                  *string         `db:"region"`
 }
 
 func (q *Queries) ClientUpsert(ctx context.Context, arg ClientUpsertParams) error {
@@ -187,6 +219,8 @@ func (q *Queries) ClientUpsert(ctx context.Context, arg ClientUpsertParams) erro
 		arg.Notes,
 		arg.CreatedAt,
 		arg.UpdatedAt,
+		arg.RequirePasskeyForAdmins,
+		arg.Region,
 	)
 	return err
 }
@@ -0,0 +1,79 @@
+// Code generated by sqlc. DO NOT EDIT.
+// versions:
+//   sqlc v1.31.1
+// source: connector.sql
+
+package dbq
+
+import (
+	"context"
+	"time"
+)
+
+const connectorCheckpointFind = `-- name: ConnectorCheckpointFind :one
+
+SELECT connector, position, snapshot_status, snapshot_started_at,
+       snapshot_completed_at, last_change_at, last_error, emitted, updated_at
+FROM msg_connector_checkpoints
+WHERE connector = $1
+`
+
+// Queries for msg_connector_checkpoints. Written only by the connector
+// runner (internal/connect), one writer per connector.
+func (q *Queries) ConnectorCheckpointFind(ctx context.Context, connector string) (MsgConnectorCheckpoint, error) {
+	row := q.db.QueryRow(ctx, connectorCheckpointFind, connector)
+	var i MsgConnectorCheckpoint
+	err := row.Scan(
+		&i.Connector,
+		&i.Position,
+		&i.SnapshotStatus,
+		&i.SnapshotStartedAt,
+		&i.SnapshotCompletedAt,
+		&i.LastChangeAt,
+		&i.LastError,
+		&i.Emitted,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const connectorCheckpointUpsert = `-- name: ConnectorCheckpointUpsert :exec
+INSERT INTO msg_connector_checkpoints
+    (connector, position, snapshot_status, snapshot_started_at,
+     snapshot_completed_at, last_change_at, last_error, emitted, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW())
+ON CONFLICT (connector) DO UPDATE SET
+    position = EXCLUDED.position,
+    snapshot_status = EXCLUDED.snapshot_status,
+    snapshot_started_at = EXCLUDED.snapshot_started_at,
+    snapshot_completed_at = EXCLUDED.snapshot_completed_at,
+    last_change_at = EXCLUDED.last_change_at,
+    last_error = EXCLUDED.last_error,
+    emitted = EXCLUDED.emitted,
+    updated_at = NOW()
+`
+
+type ConnectorCheckpointUpsertParams struct {
+	Connector           string     `db:"connector"`
+	Position            *string    `db:"position"`
+	SnapshotStatus      *string    `db:"snapshot_status"`
+	SnapshotStartedAt   *time.Time `db:"snapshot_started_at"`
+	SnapshotCompletedAt *time.Time `db:"snapshot_completed_at"`
+	LastChangeAt        *time.Time `db:"last_change_at"`
+	LastError           *string    `db:"last_error"`
+	Emitted             int64      `db:"emitted"`
+}
+
+func (q *Queries) ConnectorCheckpointUpsert(ctx context.Context, arg ConnectorCheckpointUpsertParams) error {
+	_, err := q.db.Exec(ctx, connectorCheckpointUpsert,
+		arg.Connector,
+		arg.Position,
+		arg.SnapshotStatus,
+		arg.SnapshotStartedAt,
+		arg.SnapshotCompletedAt,
+		arg.LastChangeAt,
+		arg.LastError,
+		arg.Emitted,
+	)
+	return err
+}
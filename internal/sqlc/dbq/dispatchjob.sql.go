@@ -114,7 +114,7 @@ SELECT id, external_id, source, kind, code, subject, event_id,
        timeout_seconds, schema_id, status, max_retries, retry_strategy,
        scheduled_for, expires_at, attempt_count, last_attempt_at,
        completed_at, duration_millis, last_error, idempotency_key,
-       created_at, updated_at
+       created_at, updated_at, ack_deadline
 FROM msg_dispatch_jobs
 WHERE id = $1
 `
@@ -156,6 +156,7 @@ type DispatchJobFindByIDRow struct {
 	IdempotencyKey     *string         `db:"idempotency_key"`
 	CreatedAt          time.Time       `db:"created_at"`
 	UpdatedAt          time.Time       `db:"updated_at"`
+	AckDeadline        *time.Time      `db:"ack_deadline"`
 }
 
 // Queries for msg_dispatch_jobs + msg_dispatch_job_attempts. The
@@ -210,6 +211,7 @@ func (q *Queries) DispatchJobFindByID(ctx context.Context, id string) (DispatchJ
 		&i.IdempotencyKey,
 		&i.CreatedAt,
 		&i.UpdatedAt,
+		&i.AckDeadline,
 	)
 	return i, err
 }
@@ -314,7 +316,7 @@ UPDATE msg_dispatch_jobs
        completed_at = $2,
        duration_millis = $3,
        updated_at = $2
- WHERE id = $1
+ WHERE id = $1 AND status <> 'CANCELLED'
 `
 
 type DispatchJobMarkCompletedParams struct {
@@ -336,7 +338,7 @@ UPDATE msg_dispatch_jobs
        duration_millis = $3,
        last_error = $4,
        updated_at = $2
- WHERE id = $1
+ WHERE id = $1 AND status <> 'CANCELLED'
 `
 
 type DispatchJobMarkFailedParams struct {
@@ -361,8 +363,10 @@ const dispatchJobMarkInProgress = `-- name: DispatchJobMarkInProgress :exec
 UPDATE msg_dispatch_jobs
    SET status = 'PROCESSING',
        last_attempt_at = $2,
+       ack_token = NULL,
+       ack_deadline = NULL,
        updated_at = $2
- WHERE id = $1
+ WHERE id = $1 AND status <> 'CANCELLED'
 `
 
 type DispatchJobMarkInProgressParams struct {
@@ -370,8 +374,9 @@ type DispatchJobMarkInProgressParams struct {
 	LastAttemptAt *time.Time `db:"last_attempt_at"`
 }
 
-// Status → PROCESSING. Stamps last_attempt_at. Called by the router
-// immediately before the first delivery attempt.
+// Status → PROCESSING. Stamps last_attempt_at and clears the previous
+// attempt's ack token, if any. Called by the router immediately before
+// each delivery attempt.
 func (q *Queries) DispatchJobMarkInProgress(ctx context.Context, arg DispatchJobMarkInProgressParams) error {
 	_, err := q.db.Exec(ctx, dispatchJobMarkInProgress, arg.ID, arg.LastAttemptAt)
 	return err
@@ -385,7 +390,7 @@ UPDATE msg_dispatch_jobs
        last_attempt_at = NOW(),
        status = 'PENDING',
        updated_at = NOW()
- WHERE id = $1
+ WHERE id = $1 AND status <> 'CANCELLED'
 `
 
 type DispatchJobScheduleRetryParams struct {
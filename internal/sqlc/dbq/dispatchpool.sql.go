@@ -21,7 +21,7 @@ func (q *Queries) DispatchPoolDelete(ctx context.Context, id string) error {
 
 const dispatchPoolFindAll = `-- name: DispatchPoolFindAll :many
 SELECT id, code, name, description, rate_limit, concurrency, client_id,
-       client_identifier, status, created_at, updated_at
+       client_identifier, status, created_at, updated_at, region
 FROM msg_dispatch_pools
 ORDER BY code
 `
@@ -47,6 +47,7 @@ func (q *Queries) DispatchPoolFindAll(ctx context.Context) ([]MsgDispatchPool, e
 			&i.Status,
 			&i.CreatedAt,
 			&i.UpdatedAt,
+			&i.Region,
 		); err != nil {
 			return nil, err
 		}
@@ -60,7 +61,7 @@ func (q *Queries) DispatchPoolFindAll(ctx context.Context) ([]MsgDispatchPool, e
 
 const dispatchPoolFindByCodeAnchor = `-- name: DispatchPoolFindByCodeAnchor :one
 SELECT id, code, name, description, rate_limit, concurrency, client_id,
-       client_identifier, status, created_at, updated_at
+       client_identifier, status, created_at, updated_at, region
 FROM msg_dispatch_pools
 WHERE code = $1 AND client_id IS NULL
 `
@@ -80,13 +81,14 @@ func (q *Queries) DispatchPoolFindByCodeAnchor(ctx context.Context, code string)
 		&i.Status,
 		&i.CreatedAt,
 		&i.UpdatedAt,
+		&i.Region,
 	)
 	return i, err
 }
 
 const dispatchPoolFindByCodeClient = `-- name: DispatchPoolFindByCodeClient :one
 SELECT id, code, name, description, rate_limit, concurrency, client_id,
-       client_identifier, status, created_at, updated_at
+       client_identifier, status, created_at, updated_at, region
 FROM msg_dispatch_pools
 WHERE code = $1 AND client_id = $2
 `
@@ -111,6 +113,7 @@ func (q *Queries) DispatchPoolFindByCodeClient(ctx context.Context, arg Dispatch
 		&i.Status,
 		&i.CreatedAt,
 		&i.UpdatedAt,
+		&i.Region,
 	)
 	return i, err
 }
@@ -118,7 +121,7 @@ func (q *Queries) DispatchPoolFindByCodeClient(ctx context.Context, arg Dispatch
 const dispatchPoolFindByID = `-- name: DispatchPoolFindByID :one
 
 SELECT id, code, name, description, rate_limit, concurrency, client_id,
-       client_identifier, status, created_at, updated_at
+       client_identifier, status, created_at, updated_at, region
 FROM msg_dispatch_pools
 WHERE id = $1
 `
@@ -139,6 +142,7 @@ func (q *Queries) DispatchPoolFindByID(ctx context.Context, id string) (MsgDispa
 		&i.Status,
 		&i.CreatedAt,
 		&i.UpdatedAt,
+		&i.Region,
 	)
 	return i, err
 }
@@ -146,8 +150,8 @@ func (q *Queries) DispatchPoolFindByID(ctx context.Context, id string) (MsgDispa
 const dispatchPoolUpsert = `-- name: DispatchPoolUpsert :exec
 INSERT INTO msg_dispatch_pools
     (id, code, name, description, rate_limit, concurrency, client_id,
-     client_identifier, status, created_at, updated_at)
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+     client_identifier, status, created_at, updated_at, region)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
 ON CONFLICT (id) DO UPDATE SET
     code = EXCLUDED.code,
     name = EXCLUDED.name,
@@ -157,7 +161,8 @@ ON CONFLICT (id) DO UPDATE SET
     client_id = EXCLUDED.client_id,
     client_identifier = EXCLUDED.client_identifier,
     status = EXCLUDED.status,
-    updated_at = EXCLUDED.updated_at
+    updated_at = EXCLUDED.updated_at,
+    region = EXCLUDED.region
 `
 
 type DispatchPoolUpsertParams struct {
@@ -172,6 +177,7 @@ type DispatchPoolUpsertParams struct {
 	Status           string    `db:"status"`
 	CreatedAt        time.Time `db:"created_at"`
 	UpdatedAt        time.Time `db:"updated_at"`
+	Region           *string   `db:"region"`
 }
 
 func (q *Queries) DispatchPoolUpsert(ctx context.Context, arg DispatchPoolUpsertParams) error {
@@ -187,6 +193,7 @@ func (q *Queries) DispatchPoolUpsert(ctx context.Context, arg DispatchPoolUpsert
 		arg.Status,
 		arg.CreatedAt,
 		arg.UpdatedAt,
+		arg.Region,
 	)
 	return err
 }
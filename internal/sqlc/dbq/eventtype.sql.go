@@ -22,7 +22,9 @@ func (q *Queries) EventTypeDelete(ctx context.Context, id string) error {
 
 const eventTypeFindByApplication = `-- name: EventTypeFindByApplication :many
 SELECT id, code, name, description, status, source, client_scoped,
-       application, subdomain, aggregate, created_at, updated_at, created_by
+       application, subdomain, aggregate, created_at, updated_at, created_by,
+       payload_retention_days, metadata_retention_days, redact_fields,
+       registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint
 FROM msg_event_types
 WHERE application = $1
 ORDER BY code
@@ -51,6 +53,14 @@ func (q *Queries) EventTypeFindByApplication(ctx context.Context, application st
 			&i.CreatedAt,
 			&i.UpdatedAt,
 			&i.CreatedBy,
+			&i.PayloadRetentionDays,
+			&i.MetadataRetentionDays,
+			&i.RedactFields,
+			&i.RegistrySubject,
+			&i.RegistryMode,
+			&i.DeprecatedAt,
+			&i.SunsetAt,
+			&i.MigrationHint,
 		); err != nil {
 			return nil, err
 		}
@@ -64,7 +74,9 @@ func (q *Queries) EventTypeFindByApplication(ctx context.Context, application st
 
 const eventTypeFindByCode = `-- name: EventTypeFindByCode :one
 SELECT id, code, name, description, status, source, client_scoped,
-       application, subdomain, aggregate, created_at, updated_at, created_by
+       application, subdomain, aggregate, created_at, updated_at, created_by,
+       payload_retention_days, metadata_retention_days, redact_fields,
+       registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint
 FROM msg_event_types
 WHERE code = $1
 `
@@ -86,6 +98,14 @@ func (q *Queries) EventTypeFindByCode(ctx context.Context, code string) (MsgEven
 		&i.CreatedAt,
 		&i.UpdatedAt,
 		&i.CreatedBy,
+		&i.PayloadRetentionDays,
+		&i.MetadataRetentionDays,
+		&i.RedactFields,
+		&i.RegistrySubject,
+		&i.RegistryMode,
+		&i.DeprecatedAt,
+		&i.SunsetAt,
+		&i.MigrationHint,
 	)
 	return i, err
 }
@@ -93,7 +113,9 @@ func (q *Queries) EventTypeFindByCode(ctx context.Context, code string) (MsgEven
 const eventTypeFindByID = `-- name: EventTypeFindByID :one
 
 SELECT id, code, name, description, status, source, client_scoped,
-       application, subdomain, aggregate, created_at, updated_at, created_by
+       application, subdomain, aggregate, created_at, updated_at, created_by,
+       payload_retention_days, metadata_retention_days, redact_fields,
+       registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint
 FROM msg_event_types
 WHERE id = $1
 `
@@ -118,15 +140,126 @@ func (q *Queries) EventTypeFindByID(ctx context.Context, id string) (MsgEventTyp
 		&i.CreatedAt,
 		&i.UpdatedAt,
 		&i.CreatedBy,
+		&i.PayloadRetentionDays,
+		&i.MetadataRetentionDays,
+		&i.RedactFields,
+		&i.RegistrySubject,
+		&i.RegistryMode,
+		&i.DeprecatedAt,
+		&i.SunsetAt,
+		&i.MigrationHint,
 	)
 	return i, err
 }
 
+const eventTypeLifecycles = `-- name: EventTypeLifecycles :many
+SELECT code, deprecated_at, sunset_at, migration_hint
+FROM msg_event_types
+WHERE deprecated_at IS NOT NULL
+`
+
+type EventTypeLifecyclesRow struct {
+	Code          string     `db:"code"`
+	DeprecatedAt  *time.Time `db:"deprecated_at"`
+	SunsetAt      *time.Time `db:"sunset_at"`
+	MigrationHint *string    `db:"migration_hint"`
+}
+
+func (q *Queries) EventTypeLifecycles(ctx context.Context) ([]EventTypeLifecyclesRow, error) {
+	rows, err := q.db.Query(ctx, eventTypeLifecycles)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []EventTypeLifecyclesRow{}
+	for rows.Next() {
+		var i EventTypeLifecyclesRow
+		if err := rows.Scan(
+			&i.Code,
+			&i.DeprecatedAt,
+			&i.SunsetAt,
+			&i.MigrationHint,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const eventTypeRedactionRules = `-- name: EventTypeRedactionRules :many
+SELECT code, redact_fields
+FROM msg_event_types
+WHERE cardinality(redact_fields) > 0
+`
+
+type EventTypeRedactionRulesRow struct {
+	Code         string   `db:"code"`
+	RedactFields []string `db:"redact_fields"`
+}
+
+func (q *Queries) EventTypeRedactionRules(ctx context.Context) ([]EventTypeRedactionRulesRow, error) {
+	rows, err := q.db.Query(ctx, eventTypeRedactionRules)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []EventTypeRedactionRulesRow{}
+	for rows.Next() {
+		var i EventTypeRedactionRulesRow
+		if err := rows.Scan(&i.Code, &i.RedactFields); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const eventTypeRegistryBindings = `-- name: EventTypeRegistryBindings :many
+SELECT code, registry_subject, registry_mode
+FROM msg_event_types
+WHERE registry_subject IS NOT NULL
+`
+
+type EventTypeRegistryBindingsRow struct {
+	Code            string  `db:"code"`
+	RegistrySubject *string `db:"registry_subject"`
+	RegistryMode    string  `db:"registry_mode"`
+}
+
+func (q *Queries) EventTypeRegistryBindings(ctx context.Context) ([]EventTypeRegistryBindingsRow, error) {
+	rows, err := q.db.Query(ctx, eventTypeRegistryBindings)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []EventTypeRegistryBindingsRow{}
+	for rows.Next() {
+		var i EventTypeRegistryBindingsRow
+		if err := rows.Scan(&i.Code, &i.RegistrySubject, &i.RegistryMode); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
 const eventTypeUpsertByCode = `-- name: EventTypeUpsertByCode :exec
 INSERT INTO msg_event_types
     (id, code, name, description, status, source, client_scoped,
-     application, subdomain, aggregate, created_by, created_at, updated_at)
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+     application, subdomain, aggregate, created_by, created_at, updated_at,
+     payload_retention_days, metadata_retention_days, redact_fields,
+     registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
 ON CONFLICT (code) DO UPDATE SET
     name = EXCLUDED.name,
     description = EXCLUDED.description,
@@ -137,19 +270,27 @@ ON CONFLICT (code) DO UPDATE SET
 `
 
 type EventTypeUpsertByCodeParams struct {
-	ID           string    `db:"id"`
-	Code         string    `db:"code"`
-	Name         string    `db:"name"`
-	Description  *string   `db:"description"`
-	Status       string    `db:"status"`
-	Source       string    `db:"source"`
-	ClientScoped bool      `db:"client_scoped"`
-	Application  string    `db:"application"`
-	Subdomain    string    `db:"subdomain"`
-	Aggregate    string    `db:"aggregate"`
-	CreatedBy    *string   `db:"created_by"`
-	CreatedAt    time.Time `db:"created_at"`
-	UpdatedAt    time.Time `db:"updated_at"`
+	ID                    string     `db:"id"`
+	Code                  string     `db:"code"`
+	Name                  string     `db:"name"`
+	Description           *string    `db:"description"`
+	Status                string     `db:"status"`
+	Source                string     `db:"source"`
+	ClientScoped          bool       `db:"client_scoped"`
+	Application           string     `db:"application"`
+	Subdomain             string     `db:"subdomain"`
+	Aggregate             string     `db:"aggregate"`
+	CreatedBy             *string    `db:"created_by"`
+	CreatedAt             time.Time  `db:"created_at"`
+	UpdatedAt             time.Time  `db:"updated_at"`
+	PayloadRetentionDays  *int32     `db:"payload_retention_days"`
+	MetadataRetentionDays *int32     `db:"metadata_retention_days"`
+	RedactFields          []string   `db:"redact_fields"`
+	RegistrySubject       *string    `db:"registry_subject"`
+	RegistryMode          string     `db:"registry_mode"`
+	DeprecatedAt          *time.Time `db:"deprecated_at"`
+	SunsetAt              *time.Time `db:"sunset_at"`
+	MigrationHint         *string    `db:"migration_hint"`
 }
 
 func (q *Queries) EventTypeUpsertByCode(ctx context.Context, arg EventTypeUpsertByCodeParams) error {
@@ -167,6 +308,14 @@ func (q *Queries) EventTypeUpsertByCode(ctx context.Context, arg EventTypeUpsert
 		arg.CreatedBy,
 		arg.CreatedAt,
 		arg.UpdatedAt,
+		arg.PayloadRetentionDays,
+		arg.MetadataRetentionDays,
+		arg.RedactFields,
+		arg.RegistrySubject,
+		arg.RegistryMode,
+		arg.DeprecatedAt,
+		arg.SunsetAt,
+		arg.MigrationHint,
 	)
 	return err
 }
@@ -174,8 +323,10 @@ func (q *Queries) EventTypeUpsertByCode(ctx context.Context, arg EventTypeUpsert
 const eventTypeUpsertByID = `-- name: EventTypeUpsertByID :exec
 INSERT INTO msg_event_types
     (id, code, name, description, status, source, client_scoped,
-     application, subdomain, aggregate, created_by, created_at, updated_at)
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+     application, subdomain, aggregate, created_by, created_at, updated_at,
+     payload_retention_days, metadata_retention_days, redact_fields,
+     registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
 ON CONFLICT (id) DO UPDATE SET
     code = EXCLUDED.code,
     name = EXCLUDED.name,
@@ -186,23 +337,39 @@ ON CONFLICT (id) DO UPDATE SET
     application = EXCLUDED.application,
     subdomain = EXCLUDED.subdomain,
     aggregate = EXCLUDED.aggregate,
+    payload_retention_days = EXCLUDED.payload_retention_days,
+    metadata_retention_days = EXCLUDED.metadata_retention_days,
+    redact_fields = EXCLUDED.redact_fields,
+    registry_subject = EXCLUDED.registry_subject,
+    registry_mode = EXCLUDED.registry_mode,
+    deprecated_at = EXCLUDED.deprecated_at,
+    sunset_at = EXCLUDED.sunset_at,
+    migration_hint = EXCLUDED.migration_hint,
     updated_at = EXCLUDED.updated_at
 `
 
 type EventTypeUpsertByIDParams struct {
-	ID           string    `db:"id"`
-	Code         string    `db:"code"`
-	Name         string    `db:"name"`
-	Description  *string   `db:"description"`
-	Status       string    `db:"status"`
-	Source       string    `db:"source"`
-	ClientScoped bool      `db:"client_scoped"`
-	Application  string    `db:"application"`
-	Subdomain    string    `db:"subdomain"`
-	Aggregate    string    `db:"aggregate"`
-	CreatedBy    *string   `db:"created_by"`
-	CreatedAt    time.Time `db:"created_at"`
-	UpdatedAt    time.Time `db:"updated_at"`
+	ID                    string     `db:"id"`
+	Code                  string     `db:"code"`
+	Name                  string     `db:"name"`
+	Description           *string    `db:"description"`
+	Status                string     `db:"status"`
+	Source                string     `db:"source"`
+	ClientScoped          bool       `db:"client_scoped"`
+	Application           string     `db:"application"`
+	Subdomain             string     `db:"subdomain"`
+	Aggregate             string     `db:"aggregate"`
+	CreatedBy             *string    `db:"created_by"`
+	CreatedAt             time.Time  `db:"created_at"`
+	UpdatedAt             time.Time  `db:"updated_at"`
+	PayloadRetentionDays  *int32     `db:"payload_retention_days"`
+	MetadataRetentionDays *int32     `db:"metadata_retention_days"`
+	RedactFields          []string   `db:"redact_fields"`
+	RegistrySubject       *string    `db:"registry_subject"`
+	RegistryMode          string     `db:"registry_mode"`
+	DeprecatedAt          *time.Time `db:"deprecated_at"`
+	SunsetAt              *time.Time `db:"sunset_at"`
+	MigrationHint         *string    `db:"migration_hint"`
 }
 
 func (q *Queries) EventTypeUpsertByID(ctx context.Context, arg EventTypeUpsertByIDParams) error {
@@ -220,6 +387,14 @@ func (q *Queries) EventTypeUpsertByID(ctx context.Context, arg EventTypeUpsertBy
 		arg.CreatedBy,
 		arg.CreatedAt,
 		arg.UpdatedAt,
+		arg.PayloadRetentionDays,
+		arg.MetadataRetentionDays,
+		arg.RedactFields,
+		arg.RegistrySubject,
+		arg.RegistryMode,
+		arg.DeprecatedAt,
+		arg.SunsetAt,
+		arg.MigrationHint,
 	)
 	return err
 }
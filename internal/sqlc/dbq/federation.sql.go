@@ -0,0 +1,226 @@
+// Code generated by sqlc. DO NOT EDIT.
+// versions:
+//   sqlc v1.31.1
+// source: federation.sql
+
+package dbq
+
+import (
+	"context"
+	"time"
+)
+
+const federationPeerDelete = `-- name: FederationPeerDelete :exec
+DELETE FROM msg_federation_peers WHERE id = $1
+`
+
+func (q *Queries) FederationPeerDelete(ctx context.Context, id string) error {
+	_, err := q.db.Exec(ctx, federationPeerDelete, id)
+	return err
+}
+
+const federationPeerFindAll = `-- name: FederationPeerFindAll :many
+SELECT id, code, name, base_url, client_id, client_secret_ref, event_types,
+       status, created_by, created_at, updated_at
+FROM msg_federation_peers
+ORDER BY code
+`
+
+func (q *Queries) FederationPeerFindAll(ctx context.Context) ([]MsgFederationPeer, error) {
+	rows, err := q.db.Query(ctx, federationPeerFindAll)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []MsgFederationPeer{}
+	for rows.Next() {
+		var i MsgFederationPeer
+		if err := rows.Scan(
+			&i.ID,
+			&i.Code,
+			&i.Name,
+			&i.BaseUrl,
+			&i.ClientID,
+			&i.ClientSecretRef,
+			&i.EventTypes,
+			&i.Status,
+			&i.CreatedBy,
+			&i.CreatedAt,
+			&i.UpdatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const federationPeerFindByCode = `-- name: FederationPeerFindByCode :one
+SELECT id, code, name, base_url, client_id, client_secret_ref, event_types,
+       status, created_by, created_at, updated_at
+FROM msg_federation_peers
+WHERE code = $1
+`
+
+func (q *Queries) FederationPeerFindByCode(ctx context.Context, code string) (MsgFederationPeer, error) {
+	row := q.db.QueryRow(ctx, federationPeerFindByCode, code)
+	var i MsgFederationPeer
+	err := row.Scan(
+		&i.ID,
+		&i.Code,
+		&i.Name,
+		&i.BaseUrl,
+		&i.ClientID,
+		&i.ClientSecretRef,
+		&i.EventTypes,
+		&i.Status,
+		&i.CreatedBy,
+		&i.CreatedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const federationPeerFindByID = `-- name: FederationPeerFindByID :one
+SELECT id, code, name, base_url, client_id, client_secret_ref, event_types,
+       status, created_by, created_at, updated_at
+FROM msg_federation_peers
+WHERE id = $1
+`
+
+// Queries for msg_federation_peers and msg_federation_peer_states. Peers
+// are written by use cases; states only by the federation puller.
+func (q *Queries) FederationPeerFindByID(ctx context.Context, id string) (MsgFederationPeer, error) {
+	row := q.db.QueryRow(ctx, federationPeerFindByID, id)
+	var i MsgFederationPeer
+	err := row.Scan(
+		&i.ID,
+		&i.Code,
+		&i.Name,
+		&i.BaseUrl,
+		&i.ClientID,
+		&i.ClientSecretRef,
+		&i.EventTypes,
+		&i.Status,
+		&i.CreatedBy,
+		&i.CreatedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const federationPeerStateFindAll = `-- name: FederationPeerStateFindAll :many
+SELECT peer_id, feed_cursor, last_pulled_at, last_error, received, skipped, updated_at
+FROM msg_federation_peer_states
+`
+
+func (q *Queries) FederationPeerStateFindAll(ctx context.Context) ([]MsgFederationPeerState, error) {
+	rows, err := q.db.Query(ctx, federationPeerStateFindAll)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []MsgFederationPeerState{}
+	for rows.Next() {
+		var i MsgFederationPeerState
+		if err := rows.Scan(
+			&i.PeerID,
+			&i.FeedCursor,
+			&i.LastPulledAt,
+			&i.LastError,
+			&i.Received,
+			&i.Skipped,
+			&i.UpdatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const federationPeerStateUpsert = `-- name: FederationPeerStateUpsert :exec
+INSERT INTO msg_federation_peer_states
+    (peer_id, feed_cursor, last_pulled_at, last_error, received, skipped, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, NOW())
+ON CONFLICT (peer_id) DO UPDATE SET
+    feed_cursor = EXCLUDED.feed_cursor,
+    last_pulled_at = EXCLUDED.last_pulled_at,
+    last_error = EXCLUDED.last_error,
+    received = msg_federation_peer_states.received + EXCLUDED.received,
+    skipped = msg_federation_peer_states.skipped + EXCLUDED.skipped,
+    updated_at = NOW()
+`
+
+type FederationPeerStateUpsertParams struct {
+	PeerID       string     `db:"peer_id"`
+	FeedCursor   *string    `db:"feed_cursor"`
+	LastPulledAt *time.Time `db:"last_pulled_at"`
+	LastError    *string    `db:"last_error"`
+	Received     int64      `db:"received"`
+	Skipped      int64      `db:"skipped"`
+}
+
+func (q *Queries) FederationPeerStateUpsert(ctx context.Context, arg FederationPeerStateUpsertParams) error {
+	_, err := q.db.Exec(ctx, federationPeerStateUpsert,
+		arg.PeerID,
+		arg.FeedCursor,
+		arg.LastPulledAt,
+		arg.LastError,
+		arg.Received,
+		arg.Skipped,
+	)
+	return err
+}
+
+const federationPeerUpsert = `-- name: FederationPeerUpsert :exec
+INSERT INTO msg_federation_peers
+    (id, code, name, base_url, client_id, client_secret_ref, event_types,
+     status, created_by, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+ON CONFLICT (id) DO UPDATE SET
+    name = EXCLUDED.name,
+    base_url = EXCLUDED.base_url,
+    client_id = EXCLUDED.client_id,
+    client_secret_ref = EXCLUDED.client_secret_ref,
+    event_types = EXCLUDED.event_types,
+    status = EXCLUDED.status,
+    updated_at = EXCLUDED.updated_at
+`
+
+type FederationPeerUpsertParams struct {
+	ID              string    `db:"id"`
+	Code            string    `db:"code"`
+	Name            string    `db:"name"`
+	BaseUrl         string    `db:"base_url"`
+	ClientID        string    `db:"client_id"`
+	ClientSecretRef string    `db:"client_secret_ref"`
+	EventTypes      []string  `db:"event_types"`
+	Status          string    `db:"status"`
+	CreatedBy       *string   `db:"created_by"`
+	CreatedAt       time.Time `db:"created_at"`
+	UpdatedAt       time.Time `db:"updated_at"`
+}
+
+func (q *Queries) FederationPeerUpsert(ctx context.Context, arg FederationPeerUpsertParams) error {
+	_, err := q.db.Exec(ctx, federationPeerUpsert,
+		arg.ID,
+		arg.Code,
+		arg.Name,
+		arg.BaseUrl,
+		arg.ClientID,
+		arg.ClientSecretRef,
+		arg.EventTypes,
+		arg.Status,
+		arg.CreatedBy,
+		arg.CreatedAt,
+		arg.UpdatedAt,
+	)
+	return err
+}
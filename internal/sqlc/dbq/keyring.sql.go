@@ -0,0 +1,45 @@
+// Code generated by sqlc. DO NOT EDIT.
+// versions:
+//   sqlc v1.31.1
+// source: keyring.sql
+
+package dbq
+
+import (
+	"context"
+	"time"
+)
+
+const jwtKeyringFind = `-- name: JwtKeyringFind :one
+SELECT id, ring_enc, updated_at
+FROM iam_jwt_keyrings
+WHERE id = $1
+`
+
+// Queries for iam_jwt_keyrings. ring_enc is the encryption.Service
+// envelope; the keyring store seals and opens it.
+func (q *Queries) JwtKeyringFind(ctx context.Context, id string) (IamJwtKeyring, error) {
+	row := q.db.QueryRow(ctx, jwtKeyringFind, id)
+	var i IamJwtKeyring
+	err := row.Scan(&i.ID, &i.RingEnc, &i.UpdatedAt)
+	return i, err
+}
+
+const jwtKeyringUpsert = `-- name: JwtKeyringUpsert :exec
+INSERT INTO iam_jwt_keyrings (id, ring_enc, updated_at)
+VALUES ($1, $2, $3)
+ON CONFLICT (id) DO UPDATE SET
+    ring_enc = EXCLUDED.ring_enc,
+    updated_at = EXCLUDED.updated_at
+`
+
+type JwtKeyringUpsertParams struct {
+	ID        string    `db:"id"`
+	RingEnc   string    `db:"ring_enc"`
+	UpdatedAt time.Time `db:"updated_at"`
+}
+
+func (q *Queries) JwtKeyringUpsert(ctx context.Context, arg JwtKeyringUpsertParams) error {
+	_, err := q.db.Exec(ctx, jwtKeyringUpsert, arg.ID, arg.RingEnc, arg.UpdatedAt)
+	return err
+}
@@ -0,0 +1,135 @@
+// Code generated by sqlc. DO NOT EDIT.
+// versions:
+//   sqlc v1.31.1
+// source: killswitch.sql
+
+package dbq
+
+import (
+	"context"
+	"time"
+)
+
+const killSwitchDelete = `-- name: KillSwitchDelete :exec
+DELETE FROM msg_kill_switches WHERE id = $1
+`
+
+func (q *Queries) KillSwitchDelete(ctx context.Context, id string) error {
+	_, err := q.db.Exec(ctx, killSwitchDelete, id)
+	return err
+}
+
+const killSwitchFindAll = `-- name: KillSwitchFindAll :many
+SELECT id, scope, target_id, reason, engaged_by, created_at, updated_at
+FROM msg_kill_switches
+ORDER BY created_at DESC, id
+`
+
+func (q *Queries) KillSwitchFindAll(ctx context.Context) ([]MsgKillSwitch, error) {
+	rows, err := q.db.Query(ctx, killSwitchFindAll)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []MsgKillSwitch{}
+	for rows.Next() {
+		var i MsgKillSwitch
+		if err := rows.Scan(
+			&i.ID,
+			&i.Scope,
+			&i.TargetID,
+			&i.Reason,
+			&i.EngagedBy,
+			&i.CreatedAt,
+			&i.UpdatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const killSwitchFindByID = `-- name: KillSwitchFindByID :one
+SELECT id, scope, target_id, reason, engaged_by, created_at, updated_at
+FROM msg_kill_switches
+WHERE id = $1
+`
+
+// Queries for msg_kill_switches. A switch is engaged by inserting its row
+// and released by deleting it; (scope, target_id) is unique.
+func (q *Queries) KillSwitchFindByID(ctx context.Context, id string) (MsgKillSwitch, error) {
+	row := q.db.QueryRow(ctx, killSwitchFindByID, id)
+	var i MsgKillSwitch
+	err := row.Scan(
+		&i.ID,
+		&i.Scope,
+		&i.TargetID,
+		&i.Reason,
+		&i.EngagedBy,
+		&i.CreatedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const killSwitchFindByTarget = `-- name: KillSwitchFindByTarget :one
+SELECT id, scope, target_id, reason, engaged_by, created_at, updated_at
+FROM msg_kill_switches
+WHERE scope = $1 AND target_id = $2
+`
+
+type KillSwitchFindByTargetParams struct {
+	Scope    string `db:"scope"`
+	TargetID string `db:"target_id"`
+}
+
+func (q *Queries) KillSwitchFindByTarget(ctx context.Context, arg KillSwitchFindByTargetParams) (MsgKillSwitch, error) {
+	row := q.db.QueryRow(ctx, killSwitchFindByTarget, arg.Scope, arg.TargetID)
+	var i MsgKillSwitch
+	err := row.Scan(
+		&i.ID,
+		&i.Scope,
+		&i.TargetID,
+		&i.Reason,
+		&i.EngagedBy,
+		&i.CreatedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const killSwitchUpsert = `-- name: KillSwitchUpsert :exec
+INSERT INTO msg_kill_switches
+    (id, scope, target_id, reason, engaged_by, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+ON CONFLICT (id) DO UPDATE SET
+    reason = EXCLUDED.reason,
+    updated_at = EXCLUDED.updated_at
+`
+
+type KillSwitchUpsertParams struct {
+	ID        string    `db:"id"`
+	Scope     string    `db:"scope"`
+	TargetID  string    `db:"target_id"`
+	Reason    string    `db:"reason"`
+	EngagedBy *string   `db:"engaged_by"`
+	CreatedAt time.Time `db:"created_at"`
+	UpdatedAt time.Time `db:"updated_at"`
+}
+
+func (q *Queries) KillSwitchUpsert(ctx context.Context, arg KillSwitchUpsertParams) error {
+	_, err := q.db.Exec(ctx, killSwitchUpsert,
+		arg.ID,
+		arg.Scope,
+		arg.TargetID,
+		arg.Reason,
+		arg.EngagedBy,
+		arg.CreatedAt,
+		arg.UpdatedAt,
+	)
+	return err
+}
@@ -73,6 +73,31 @@ type AppPlatformConfigAccess struct {
 	CreatedAt       time.Time `db:"created_at"`
 }
 
+type AppProducerBinding struct {
+	ID               string    `db:"id"`
+	ApplicationID    string    `db:"application_id"`
+	PrincipalID      *string   `db:"principal_id"`
+	EventTypePattern string    `db:"event_type_pattern"`
+	CreatedAt        time.Time `db:"created_at"`
+}
+
+type AudErasureRequest struct {
+	ID          string          `db:"id"`
+	SubjectType string          `db:"subject_type"`
+	Subject     *string         `db:"subject"`
+	SubjectHash string          `db:"subject_hash"`
+	ContextKeys []string        `db:"context_keys"`
+	ClientID    *string         `db:"client_id"`
+	Status      string          `db:"status"`
+	Report      json.RawMessage `db:"report"`
+	Error       *string         `db:"error"`
+	RequestedBy *string         `db:"requested_by"`
+	CreatedAt   time.Time       `db:"created_at"`
+	StartedAt   *time.Time      `db:"started_at"`
+	CompletedAt *time.Time      `db:"completed_at"`
+	UpdatedAt   time.Time       `db:"updated_at"`
+}
+
 type AudLog struct {
 	ID            string          `db:"id"`
 	EntityType    string          `db:"entity_type"`
@@ -111,6 +136,12 @@ type IamClientAccessGrant struct {
 	UpdatedAt   time.Time `db:"updated_at"`
 }
 
+type IamJwtKeyring struct {
+	ID        string    `db:"id"`
+	RingEnc   string    `db:"ring_enc"`
+	UpdatedAt time.Time `db:"updated_at"`
+}
+
 type IamLoginAttempt struct {
 	ID            string    `db:"id"`
 	AttemptType   string    `db:"attempt_type"`
@@ -294,6 +325,7 @@ type IamServiceAccount struct {
 	UpdatedAt                  time.Time  `db:"updated_at"`
 	Scope                      *string    `db:"scope"`
 	ClientIds                  []string   `db:"client_ids"`
+	AllowedCidrs               []string   `db:"allowed_cidrs"`
 }
 
 type IamUserMfaMethod struct {
@@ -314,6 +346,88 @@ type IamUserMfaRecoveryCode struct {
 	CreatedAt   time.Time  `db:"created_at"`
 }
 
+type MsgAnomaly struct {
+	ID         string     `db:"id"`
+	EntityKind string     `db:"entity_kind"`
+	EntityKey  string     `db:"entity_key"`
+	Kind       string     `db:"kind"`
+	Status     string     `db:"status"`
+	Message    string     `db:"message"`
+	Observed   float64    `db:"observed"`
+	Expected   float64    `db:"expected"`
+	ZScore     float64    `db:"z_score"`
+	RaisedAt   time.Time  `db:"raised_at"`
+	LastSeenAt time.Time  `db:"last_seen_at"`
+	ResolvedAt *time.Time `db:"resolved_at"`
+}
+
+type MsgAnomalyBaseline struct {
+	EntityKind string    `db:"entity_kind"`
+	EntityKey  string    `db:"entity_key"`
+	Mean       float64   `db:"mean"`
+	Variance   float64   `db:"variance"`
+	Samples    int32     `db:"samples"`
+	WindowEnd  time.Time `db:"window_end"`
+	UpdatedAt  time.Time `db:"updated_at"`
+}
+
+type MsgAnomalySetting struct {
+	ID           string     `db:"id"`
+	EntityKind   string     `db:"entity_kind"`
+	EntityKey    string     `db:"entity_key"`
+	Sensitivity  *float64   `db:"sensitivity"`
+	Enabled      bool       `db:"enabled"`
+	SnoozedUntil *time.Time `db:"snoozed_until"`
+	UpdatedBy    *string    `db:"updated_by"`
+	CreatedAt    time.Time  `db:"created_at"`
+	UpdatedAt    time.Time  `db:"updated_at"`
+}
+
+type MsgArchiveCursor struct {
+	Kind      string     `db:"kind"`
+	AfterTime time.Time  `db:"after_time"`
+	AfterID   string     `db:"after_id"`
+	Archived  int64      `db:"archived"`
+	LastRunAt *time.Time `db:"last_run_at"`
+	LastError *string    `db:"last_error"`
+	UpdatedAt time.Time  `db:"updated_at"`
+}
+
+type MsgArchiveFile struct {
+	ID            string    `db:"id"`
+	Kind          string    `db:"kind"`
+	ObjectKey     string    `db:"object_key"`
+	SchemaVersion int32     `db:"schema_version"`
+	PartitionHour time.Time `db:"partition_hour"`
+	Records       int32     `db:"records"`
+	Bytes         int64     `db:"bytes"`
+	FirstID       string    `db:"first_id"`
+	LastID        string    `db:"last_id"`
+	MinTime       time.Time `db:"min_time"`
+	MaxTime       time.Time `db:"max_time"`
+	CreatedAt     time.Time `db:"created_at"`
+}
+
+type MsgArchiveReplay struct {
+	ID             string     `db:"id"`
+	FromTime       time.Time  `db:"from_time"`
+	ToTime         time.Time  `db:"to_time"`
+	EventTypes     []string   `db:"event_types"`
+	ClientID       *string    `db:"client_id"`
+	SubscriptionID *string    `db:"subscription_id"`
+	Status         string     `db:"status"`
+	AfterFileID    *string    `db:"after_file_id"`
+	FilesRead      int32      `db:"files_read"`
+	EventsRead     int64      `db:"events_read"`
+	EventsReplayed int64      `db:"events_replayed"`
+	Error          *string    `db:"error"`
+	RequestedBy    *string    `db:"requested_by"`
+	CreatedAt      time.Time  `db:"created_at"`
+	StartedAt      *time.Time `db:"started_at"`
+	CompletedAt    *time.Time `db:"completed_at"`
+	UpdatedAt      time.Time  `db:"updated_at"`
+}
+
 type MsgConnection struct {
 	ID               string    `db:"id"`
 	Code             string    `db:"code"`
@@ -328,6 +442,18 @@ type MsgConnection struct {
 	UpdatedAt        time.Time `db:"updated_at"`
 }
 
+type MsgConnectorCheckpoint struct {
+	Connector           string     `db:"connector"`
+	Position            *string    `db:"position"`
+	SnapshotStatus      *string    `db:"snapshot_status"`
+	SnapshotStartedAt   *time.Time `db:"snapshot_started_at"`
+	SnapshotCompletedAt *time.Time `db:"snapshot_completed_at"`
+	LastChangeAt        *time.Time `db:"last_change_at"`
+	LastError           *string    `db:"last_error"`
+	Emitted             int64      `db:"emitted"`
+	UpdatedAt           time.Time  `db:"updated_at"`
+}
+
 type MsgDispatchJob struct {
 	ID                 string          `db:"id"`
 	ExternalID         *string         `db:"external_id"`
@@ -367,6 +493,8 @@ type MsgDispatchJob struct {
 	UpdatedAt          time.Time       `db:"updated_at"`
 	ProjectedAt        *time.Time      `db:"projected_at"`
 	QueuedAt           *time.Time      `db:"queued_at"`
+	AckToken           *string         `db:"ack_token"`
+	AckDeadline        *time.Time      `db:"ack_deadline"`
 }
 
 type MsgDispatchJobAttempt struct {
@@ -434,6 +562,10 @@ type MsgDispatchJobsRead struct {
 	CreatedAt        time.Time  `db:"created_at"`
 	UpdatedAt        time.Time  `db:"updated_at"`
 	ProjectedAt      *time.Time `db:"projected_at"`
+	SubscriptionName *string    `db:"subscription_name"`
+	ClientName       *string    `db:"client_name"`
+	EventTypeName    *string    `db:"event_type_name"`
+	TargetHost       *string    `db:"target_host"`
 }
 
 type MsgDispatchPool struct {
@@ -448,25 +580,30 @@ type MsgDispatchPool struct {
 	Status           string    `db:"status"`
 	CreatedAt        time.Time `db:"created_at"`
 	UpdatedAt        time.Time `db:"updated_at"`
+	Region           *string   `db:"region"`
 }
 
 type MsgEvent struct {
-	ID              string          `db:"id"`
-	SpecVersion     *string         `db:"spec_version"`
-	Type            string          `db:"type"`
-	Source          string          `db:"source"`
-	Subject         *string         `db:"subject"`
-	Time            time.Time       `db:"time"`
-	Data            json.RawMessage `db:"data"`
-	CorrelationID   *string         `db:"correlation_id"`
-	CausationID     *string         `db:"causation_id"`
-	DeduplicationID *string         `db:"deduplication_id"`
-	MessageGroup    *string         `db:"message_group"`
-	ClientID        *string         `db:"client_id"`
-	ContextData     json.RawMessage `db:"context_data"`
-	CreatedAt       time.Time       `db:"created_at"`
-	ProjectedAt     *time.Time      `db:"projected_at"`
-	FannedOutAt     *time.Time      `db:"fanned_out_at"`
+	ID                   string          `db:"id"`
+	SpecVersion          *string         `db:"spec_version"`
+	Type                 string          `db:"type"`
+	Source               string          `db:"source"`
+	Subject              *string         `db:"subject"`
+	Time                 time.Time       `db:"time"`
+	Data                 json.RawMessage `db:"data"`
+	CorrelationID        *string         `db:"correlation_id"`
+	CausationID          *string         `db:"causation_id"`
+	DeduplicationID      *string         `db:"deduplication_id"`
+	MessageGroup         *string         `db:"message_group"`
+	ClientID             *string         `db:"client_id"`
+	ContextData          json.RawMessage `db:"context_data"`
+	CreatedAt            time.Time       `db:"created_at"`
+	ProjectedAt          *time.Time      `db:"projected_at"`
+	FannedOutAt          *time.Time      `db:"fanned_out_at"`
+	ReceiptCallbackUrl   *string         `db:"receipt_callback_url"`
+	ReceiptEventType     *string         `db:"receipt_event_type"`
+	SchemaID             *string         `db:"schema_id"`
+	ReplaySubscriptionID *string         `db:"replay_subscription_id"`
 }
 
 type MsgEventProjectionFeed struct {
@@ -480,19 +617,27 @@ type MsgEventProjectionFeed struct {
 }
 
 type MsgEventType struct {
-	ID           string    `db:"id"`
-	Code         string    `db:"code"`
-	Name         string    `db:"name"`
-	Description  *string   `db:"description"`
-	Status       string    `db:"status"`
-	Source       string    `db:"source"`
-	ClientScoped bool      `db:"client_scoped"`
-	Application  string    `db:"application"`
-	Subdomain    string    `db:"subdomain"`
-	Aggregate    string    `db:"aggregate"`
-	CreatedAt    time.Time `db:"created_at"`
-	UpdatedAt    time.Time `db:"updated_at"`
-	CreatedBy    *string   `db:"created_by"`
+	ID                    string     `db:"id"`
+	Code                  string     `db:"code"`
+	Name                  string     `db:"name"`
+	Description           *string    `db:"description"`
+	Status                string     `db:"status"`
+	Source                string     `db:"source"`
+	ClientScoped          bool       `db:"client_scoped"`
+	Application           string     `db:"application"`
+	Subdomain             string     `db:"subdomain"`
+	Aggregate             string     `db:"aggregate"`
+	CreatedAt             time.Time  `db:"created_at"`
+	UpdatedAt             time.Time  `db:"updated_at"`
+	CreatedBy             *string    `db:"created_by"`
+	PayloadRetentionDays  *int32     `db:"payload_retention_days"`
+	MetadataRetentionDays *int32     `db:"metadata_retention_days"`
+	RedactFields          []string   `db:"redact_fields"`
+	RegistrySubject       *string    `db:"registry_subject"`
+	RegistryMode          string     `db:"registry_mode"`
+	DeprecatedAt          *time.Time `db:"deprecated_at"`
+	SunsetAt              *time.Time `db:"sunset_at"`
+	MigrationHint         *string    `db:"migration_hint"`
 }
 
 type MsgEventTypeSpecVersion struct {
@@ -527,6 +672,40 @@ type MsgEventsRead struct {
 	CreatedAt       time.Time `db:"created_at"`
 }
 
+type MsgFederationPeer struct {
+	ID              string    `db:"id"`
+	Code            string    `db:"code"`
+	Name            string    `db:"name"`
+	BaseUrl         string    `db:"base_url"`
+	ClientID        string    `db:"client_id"`
+	ClientSecretRef string    `db:"client_secret_ref"`
+	EventTypes      []string  `db:"event_types"`
+	Status          string    `db:"status"`
+	CreatedBy       *string   `db:"created_by"`
+	CreatedAt       time.Time `db:"created_at"`
+	UpdatedAt       time.Time `db:"updated_at"`
+}
+
+type MsgFederationPeerState struct {
+	PeerID       string     `db:"peer_id"`
+	FeedCursor   *string    `db:"feed_cursor"`
+	LastPulledAt *time.Time `db:"last_pulled_at"`
+	LastError    *string    `db:"last_error"`
+	Received     int64      `db:"received"`
+	Skipped      int64      `db:"skipped"`
+	UpdatedAt    time.Time  `db:"updated_at"`
+}
+
+type MsgKillSwitch struct {
+	ID        string    `db:"id"`
+	Scope     string    `db:"scope"`
+	TargetID  string    `db:"target_id"`
+	Reason    string    `db:"reason"`
+	EngagedBy *string   `db:"engaged_by"`
+	CreatedAt time.Time `db:"created_at"`
+	UpdatedAt time.Time `db:"updated_at"`
+}
+
 type MsgProcess struct {
 	ID          string    `db:"id"`
 	Code        string    `db:"code"`
@@ -598,33 +777,56 @@ type MsgScheduledJobInstanceLog struct {
 	CreatedAt      time.Time       `db:"created_at"`
 }
 
+type MsgStreamDlq struct {
+	EventID        string    `db:"event_id"`
+	EventCreatedAt time.Time `db:"event_created_at"`
+	EventType      string    `db:"event_type"`
+	MessageGroup   *string   `db:"message_group"`
+	Stage          string    `db:"stage"`
+	Error          string    `db:"error"`
+	Attempts       int32     `db:"attempts"`
+	CreatedAt      time.Time `db:"created_at"`
+	UpdatedAt      time.Time `db:"updated_at"`
+}
+
 type MsgSubscription struct {
-	ID               string    `db:"id"`
-	Code             string    `db:"code"`
-	ApplicationCode  *string   `db:"application_code"`
-	Name             string    `db:"name"`
-	Description      *string   `db:"description"`
-	ClientID         *string   `db:"client_id"`
-	ClientIdentifier *string   `db:"client_identifier"`
-	ClientScoped     bool      `db:"client_scoped"`
-	Target           string    `db:"target"`
-	Queue            *string   `db:"queue"`
-	Source           string    `db:"source"`
-	Status           string    `db:"status"`
-	MaxAgeSeconds    int32     `db:"max_age_seconds"`
-	DispatchPoolID   *string   `db:"dispatch_pool_id"`
-	DispatchPoolCode *string   `db:"dispatch_pool_code"`
-	DelaySeconds     int32     `db:"delay_seconds"`
-	Sequence         int32     `db:"sequence"`
-	Mode             string    `db:"mode"`
-	TimeoutSeconds   int32     `db:"timeout_seconds"`
-	MaxRetries       int32     `db:"max_retries"`
-	ServiceAccountID *string   `db:"service_account_id"`
-	DataOnly         bool      `db:"data_only"`
-	CreatedAt        time.Time `db:"created_at"`
-	UpdatedAt        time.Time `db:"updated_at"`
-	ConnectionID     *string   `db:"connection_id"`
-	CreatedBy        *string   `db:"created_by"`
+	ID                  string    `db:"id"`
+	Code                string    `db:"code"`
+	ApplicationCode     *string   `db:"application_code"`
+	Name                string    `db:"name"`
+	Description         *string   `db:"description"`
+	ClientID            *string   `db:"client_id"`
+	ClientIdentifier    *string   `db:"client_identifier"`
+	ClientScoped        bool      `db:"client_scoped"`
+	Target              string    `db:"target"`
+	Queue               *string   `db:"queue"`
+	Source              string    `db:"source"`
+	Status              string    `db:"status"`
+	MaxAgeSeconds       int32     `db:"max_age_seconds"`
+	DispatchPoolID      *string   `db:"dispatch_pool_id"`
+	DispatchPoolCode    *string   `db:"dispatch_pool_code"`
+	DelaySeconds        int32     `db:"delay_seconds"`
+	Sequence            int32     `db:"sequence"`
+	Mode                string    `db:"mode"`
+	TimeoutSeconds      int32     `db:"timeout_seconds"`
+	MaxRetries          int32     `db:"max_retries"`
+	ServiceAccountID    *string   `db:"service_account_id"`
+	DataOnly            bool      `db:"data_only"`
+	CreatedAt           time.Time `db:"created_at"`
+	UpdatedAt           time.Time `db:"updated_at"`
+	ConnectionID        *string   `db:"connection_id"`
+	CreatedBy           *string   `db:"created_by"`
+	Transport           *string   `db:"transport"`
+	DeliveryMode        string    `db:"delivery_mode"`
+	BatchMaxItems       int32     `db:"batch_max_items"`
+	BatchMaxBytes       int32     `db:"batch_max_bytes"`
+	BatchMaxWaitSeconds int32     `db:"batch_max_wait_seconds"`
+	PayloadFormat       string    `db:"payload_format"`
+	LargePayloads       string    `db:"large_payloads"`
+	ShadowTarget        *string   `db:"shadow_target"`
+	TlsCaBundle         *string   `db:"tls_ca_bundle"`
+	TlsSpkiPins         []string  `db:"tls_spki_pins"`
+	TlsMinVersion       *string   `db:"tls_min_version"`
 }
 
 type MsgSubscriptionCustomConfig struct {
@@ -642,6 +844,36 @@ type MsgSubscriptionEventType struct {
 	SpecVersion    *string `db:"spec_version"`
 }
 
+type MsgSubscriptionHeader struct {
+	ID             int32  `db:"id"`
+	SubscriptionID string `db:"subscription_id"`
+	HeaderName     string `db:"header_name"`
+	HeaderValue    string `db:"header_value"`
+}
+
+type MsgSubscriptionShadowDelivery struct {
+	ID                string    `db:"id"`
+	SubscriptionID    string    `db:"subscription_id"`
+	DispatchJobID     string    `db:"dispatch_job_id"`
+	ShadowTarget      string    `db:"shadow_target"`
+	PrimaryStatusCode *int32    `db:"primary_status_code"`
+	PrimarySuccess    bool      `db:"primary_success"`
+	PrimaryDurationMs int64     `db:"primary_duration_ms"`
+	PrimaryError      *string   `db:"primary_error"`
+	ShadowStatusCode  *int32    `db:"shadow_status_code"`
+	ShadowSuccess     bool      `db:"shadow_success"`
+	ShadowDurationMs  int64     `db:"shadow_duration_ms"`
+	ShadowError       *string   `db:"shadow_error"`
+	CreatedAt         time.Time `db:"created_at"`
+}
+
+type MsgSubscriptionTarget struct {
+	ID             int32  `db:"id"`
+	SubscriptionID string `db:"subscription_id"`
+	Target         string `db:"target"`
+	Weight         int32  `db:"weight"`
+}
+
 type OauthClient struct {
 	ID                        string    `db:"id"`
 	ClientID                  string    `db:"client_id"`
@@ -750,15 +982,17 @@ type TntAnchorDomain struct {
 }
 
 type TntClient struct {
-	ID              string          `db:"id"`
-	Name            string          `db:"name"`
-	Identifier      string          `db:"identifier"`
-	Status          string          `db:"status"`
-	StatusReason    *string         `db:"status_reason"`
-	StatusChangedAt *time.Time      `db:"status_changed_at"`
-	Notes           json.RawMessage `db:"notes"`
-	CreatedAt       time.Time       `db:"created_at"`
-	UpdatedAt       time.Time       `db:"updated_at"`
+	ID                      string          `db:"id"`
+	Name                    string          `db:"name"`
+	Identifier              string          `db:"identifier"`
+	Status                  string          `db:"status"`
+	StatusReason            *string         `db:"status_reason"`
+	StatusChangedAt         *time.Time      `db:"status_changed_at"`
+	Notes                   json.RawMessage `db:"notes"`
+	CreatedAt               time.Time       `db:"created_at"`
+	UpdatedAt               time.Time       `db:"updated_at"`
+	RequirePasskeyForAdmins bool            `db:"require_passkey_for_admins"`
+	Region                  *string         `db:"region"`
 }
 
 type TntClientAuthConfig struct {
@@ -778,6 +1012,18 @@ type TntClientAuthConfig struct {
 	UpdatedAt           time.Time       `db:"updated_at"`
 }
 
+type TntClientSigningKey struct {
+	ID         string     `db:"id"`
+	ClientID   string     `db:"client_id"`
+	KeyID      string     `db:"key_id"`
+	SecretEnc  string     `db:"secret_enc"`
+	ActiveFrom time.Time  `db:"active_from"`
+	ExpiresAt  *time.Time `db:"expires_at"`
+	CreatedBy  *string    `db:"created_by"`
+	CreatedAt  time.Time  `db:"created_at"`
+	UpdatedAt  time.Time  `db:"updated_at"`
+}
+
 type TntCorsAllowedOrigin struct {
 	ID          string    `db:"id"`
 	Origin      string    `db:"origin"`
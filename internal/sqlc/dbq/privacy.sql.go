@@ -0,0 +1,142 @@
+// Code generated by sqlc. DO NOT EDIT.
+// versions:
+//   sqlc v1.31.1
+// source: privacy.sql
+
+package dbq
+
+import (
+	"context"
+	"encoding/json"
+	"time"
+)
+
+const erasureRequestFindByID = `-- name: ErasureRequestFindByID :one
+SELECT id, subject_type, subject, subject_hash, context_keys, client_id,
+       status, report, error, requested_by, created_at, started_at,
+       completed_at, updated_at
+FROM aud_erasure_requests
+WHERE id = $1
+`
+
+// Queries for aud_erasure_requests. The erasure worker claims and updates
+// rows with its own SQL (internal/platform/privacy/worker.go); these cover
+// the use-case write path and the API reads.
+func (q *Queries) ErasureRequestFindByID(ctx context.Context, id string) (AudErasureRequest, error) {
+	row := q.db.QueryRow(ctx, erasureRequestFindByID, id)
+	var i AudErasureRequest
+	err := row.Scan(
+		&i.ID,
+		&i.SubjectType,
+		&i.Subject,
+		&i.SubjectHash,
+		&i.ContextKeys,
+		&i.ClientID,
+		&i.Status,
+		&i.Report,
+		&i.Error,
+		&i.RequestedBy,
+		&i.CreatedAt,
+		&i.StartedAt,
+		&i.CompletedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const erasureRequestList = `-- name: ErasureRequestList :many
+SELECT id, subject_type, subject, subject_hash, context_keys, client_id,
+       status, report, error, requested_by, created_at, started_at,
+       completed_at, updated_at
+FROM aud_erasure_requests
+ORDER BY created_at DESC
+LIMIT $1
+`
+
+func (q *Queries) ErasureRequestList(ctx context.Context, limit int32) ([]AudErasureRequest, error) {
+	rows, err := q.db.Query(ctx, erasureRequestList, limit)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []AudErasureRequest{}
+	for rows.Next() {
+		var i AudErasureRequest
+		if err := rows.Scan(
+			&i.ID,
+			&i.SubjectType,
+			&i.Subject,
+			&i.SubjectHash,
+			&i.ContextKeys,
+			&i.ClientID,
+			&i.Status,
+			&i.Report,
+			&i.Error,
+			&i.RequestedBy,
+			&i.CreatedAt,
+			&i.StartedAt,
+			&i.CompletedAt,
+			&i.UpdatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const erasureRequestUpsert = `-- name: ErasureRequestUpsert :exec
+INSERT INTO aud_erasure_requests
+    (id, subject_type, subject, subject_hash, context_keys, client_id,
+     status, report, error, requested_by, created_at, started_at,
+     completed_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+ON CONFLICT (id) DO UPDATE SET
+    subject = EXCLUDED.subject,
+    status = EXCLUDED.status,
+    report = EXCLUDED.report,
+    error = EXCLUDED.error,
+    started_at = EXCLUDED.started_at,
+    completed_at = EXCLUDED.completed_at,
+    updated_at = EXCLUDED.updated_at
+`
+
+type ErasureRequestUpsertParams struct {
+	ID          string          `db:"id"`
+	SubjectType string          `db:"subject_type"`
+	Subject     *string         `db:"subject"`
+	SubjectHash string          `db:"subject_hash"`
+	ContextKeys []string        `db:"context_keys"`
+	ClientID    *string         `db:"client_id"`
+	Status      string          `db:"status"`
+	Report      json.RawMessage `db:"report"`
+	Error       *string         `db:"error"`
+	RequestedBy *string         `db:"requested_by"`
+	CreatedAt   time.Time       `db:"created_at"`
+	StartedAt   *time.Time      `db:"started_at"`
+	CompletedAt *time.Time      `db:"completed_at"`
+	UpdatedAt   time.Time       `db:"updated_at"`
+}
+
+func (q *Queries) ErasureRequestUpsert(ctx context.Context, arg ErasureRequestUpsertParams) error {
+	_, err := q.db.Exec(ctx, erasureRequestUpsert,
+		arg.ID,
+		arg.SubjectType,
+		arg.Subject,
+		arg.SubjectHash,
+		arg.ContextKeys,
+		arg.ClientID,
+		arg.Status,
+		arg.Report,
+		arg.Error,
+		arg.RequestedBy,
+		arg.CreatedAt,
+		arg.StartedAt,
+		arg.CompletedAt,
+		arg.UpdatedAt,
+	)
+	return err
+}
@@ -16,11 +16,41 @@ type Querier interface {
 	// ── AnchorDomain (tnt_anchor_domains) ────────────────────────────────
 	AnchorDomainFindByID(ctx context.Context, id string) (TntAnchorDomain, error)
 	AnchorDomainUpsert(ctx context.Context, arg AnchorDomainUpsertParams) error
+	AnomalyFindByStatus(ctx context.Context, arg AnomalyFindByStatusParams) ([]MsgAnomaly, error)
+	AnomalySettingFindAll(ctx context.Context) ([]MsgAnomalySetting, error)
+	// Queries for msg_anomaly_settings and msg_anomalies. The detector
+	// (internal/stream/anomalies.go) maintains baselines and raises and
+	// resolves anomalies with its own SQL; these cover the use-case write path
+	// and the API reads.
+	AnomalySettingFindByEntity(ctx context.Context, arg AnomalySettingFindByEntityParams) (MsgAnomalySetting, error)
+	AnomalySettingUpsert(ctx context.Context, arg AnomalySettingUpsertParams) error
 	ApplicationDelete(ctx context.Context, id string) error
 	ApplicationFindByCode(ctx context.Context, code string) (AppApplication, error)
 	// Queries for app_applications.
 	ApplicationFindByID(ctx context.Context, id string) (AppApplication, error)
 	ApplicationUpsert(ctx context.Context, arg ApplicationUpsertParams) error
+	// Moves a stream's cursor past a committed batch and clears its error.
+	ArchiveCursorAdvance(ctx context.Context, arg ArchiveCursorAdvanceParams) error
+	// Queries for msg_archive_cursors, msg_archive_files and msg_archive_replays.
+	// The archiver (internal/platform/archive) writes the cursors and the
+	// manifest, which the API reads; replays are written by their use cases
+	// and the replayer.
+	ArchiveCursorFindAll(ctx context.Context) ([]MsgArchiveCursor, error)
+	// Stamps a run that archived nothing, with its error if it failed. A
+	// stream never run before starts at the epoch.
+	ArchiveCursorRecordRun(ctx context.Context, arg ArchiveCursorRecordRunParams) error
+	ArchiveFileInsert(ctx context.Context, arg ArchiveFileInsertParams) error
+	ArchiveFileList(ctx context.Context, arg ArchiveFileListParams) ([]MsgArchiveFile, error)
+	ArchiveReplayFindByID(ctx context.Context, id string) (MsgArchiveReplay, error)
+	// The oldest replay still to run; the replayer works them one at a time.
+	ArchiveReplayFindNextOpen(ctx context.Context) (MsgArchiveReplay, error)
+	ArchiveReplayList(ctx context.Context, limit int32) ([]MsgArchiveReplay, error)
+	// Records a replayer pass. Matches nothing once the replay is terminal,
+	// which is how the replayer notices a cancellation.
+	ArchiveReplayRecordProgress(ctx context.Context, arg ArchiveReplayRecordProgressParams) (int64, error)
+	// The use-case write path: a new replay, or a change of status. Progress
+	// belongs to the replayer and is left alone.
+	ArchiveReplayUpsert(ctx context.Context, arg ArchiveReplayUpsertParams) error
 	// Queries for aud_logs (read-only — writes happen in platformsink).
 	AuditFindByID(ctx context.Context, id string) (AuditFindByIDRow, error)
 	// All filters are optional via the IS-NULL-OR pattern. Limit + offset
@@ -44,6 +74,7 @@ type Querier interface {
 	// aggregate's Client struct.
 	ClientFindByID(ctx context.Context, id string) (TntClient, error)
 	ClientFindByIdentifier(ctx context.Context, identifier string) (TntClient, error)
+	ClientRequiresPasskeyForAdmins(ctx context.Context, arg ClientRequiresPasskeyForAdminsParams) (bool, error)
 	ClientSearch(ctx context.Context, pattern string) ([]TntClient, error)
 	ClientUpsert(ctx context.Context, arg ClientUpsertParams) error
 	ConnectionDelete(ctx context.Context, id string) error
@@ -53,6 +84,10 @@ type Querier interface {
 	// Queries for msg_connections.
 	ConnectionFindByID(ctx context.Context, id string) (MsgConnection, error)
 	ConnectionUpsert(ctx context.Context, arg ConnectionUpsertParams) error
+	// Queries for msg_connector_checkpoints. Written only by the connector
+	// runner (internal/connect), one writer per connector.
+	ConnectorCheckpointFind(ctx context.Context, connector string) (MsgConnectorCheckpoint, error)
+	ConnectorCheckpointUpsert(ctx context.Context, arg ConnectorCheckpointUpsertParams) error
 	CorsOriginDelete(ctx context.Context, id string) error
 	CorsOriginFindAll(ctx context.Context) ([]TntCorsAllowedOrigin, error)
 	// Queries for tnt_cors_allowed_origins. Simple single-table CRUD.
@@ -119,6 +154,12 @@ type Querier interface {
 	EmailDomainMappingGrantedClientsClear(ctx context.Context, emailDomainMappingID string) error
 	EmailDomainMappingGrantedClientsForMappings(ctx context.Context, dollar_1 []string) ([]EmailDomainMappingGrantedClientsForMappingsRow, error)
 	EmailDomainMappingUpsert(ctx context.Context, arg EmailDomainMappingUpsertParams) error
+	// Queries for aud_erasure_requests. The erasure worker claims and updates
+	// rows with its own SQL (internal/platform/privacy/worker.go); these cover
+	// the use-case write path and the API reads.
+	ErasureRequestFindByID(ctx context.Context, id string) (AudErasureRequest, error)
+	ErasureRequestList(ctx context.Context, limit int32) ([]AudErasureRequest, error)
+	ErasureRequestUpsert(ctx context.Context, arg ErasureRequestUpsertParams) error
 	EventTypeDelete(ctx context.Context, id string) error
 	EventTypeFindByApplication(ctx context.Context, application string) ([]MsgEventType, error)
 	EventTypeFindByCode(ctx context.Context, code string) (MsgEventType, error)
@@ -126,6 +167,9 @@ type Querier interface {
 	// Two upsert variants: ON CONFLICT (id) for the canonical path, and
 	// ON CONFLICT (code) for the sync use case which keys by code.
 	EventTypeFindByID(ctx context.Context, id string) (MsgEventType, error)
+	EventTypeLifecycles(ctx context.Context) ([]EventTypeLifecyclesRow, error)
+	EventTypeRedactionRules(ctx context.Context) ([]EventTypeRedactionRulesRow, error)
+	EventTypeRegistryBindings(ctx context.Context) ([]EventTypeRegistryBindingsRow, error)
 	EventTypeUpsertByCode(ctx context.Context, arg EventTypeUpsertByCodeParams) error
 	EventTypeUpsertByID(ctx context.Context, arg EventTypeUpsertByIDParams) error
 	IdentityProviderDelete(ctx context.Context, id string) error
@@ -150,6 +194,17 @@ type Querier interface {
 	IdpRoleMappingFindByID(ctx context.Context, id string) (OauthIdpRoleMapping, error)
 	IdpRoleMappingFindByIdpRole(ctx context.Context, idpRoleName string) ([]OauthIdpRoleMapping, error)
 	IdpRoleMappingUpsert(ctx context.Context, arg IdpRoleMappingUpsertParams) error
+	// Queries for iam_jwt_keyrings. ring_enc is the encryption.Service
+	// envelope; the keyring store seals and opens it.
+	JwtKeyringFind(ctx context.Context, id string) (IamJwtKeyring, error)
+	JwtKeyringUpsert(ctx context.Context, arg JwtKeyringUpsertParams) error
+	KillSwitchDelete(ctx context.Context, id string) error
+	KillSwitchFindAll(ctx context.Context) ([]MsgKillSwitch, error)
+	// Queries for msg_kill_switches. A switch is engaged by inserting its row
+	// and released by deleting it; (scope, target_id) is unique.
+	KillSwitchFindByID(ctx context.Context, id string) (MsgKillSwitch, error)
+	KillSwitchFindByTarget(ctx context.Context, arg KillSwitchFindByTargetParams) (MsgKillSwitch, error)
+	KillSwitchUpsert(ctx context.Context, arg KillSwitchUpsertParams) error
 	OAuthClientDelete(ctx context.Context, id string) error
 	OAuthClientFindAll(ctx context.Context) ([]OauthClient, error)
 	OAuthClientFindByClientID(ctx context.Context, clientID string) (OauthClient, error)
@@ -235,6 +290,13 @@ type Querier interface {
 	// matches the Rust source which hard-codes CreatedBy: None on read.
 	ProcessFindByID(ctx context.Context, id string) (MsgProcess, error)
 	ProcessUpsert(ctx context.Context, arg ProcessUpsertParams) error
+	ProducerBindingDelete(ctx context.Context, id string) error
+	ProducerBindingFindByApp(ctx context.Context, applicationID string) ([]AppProducerBinding, error)
+	ProducerBindingFindByID(ctx context.Context, id string) (AppProducerBinding, error)
+	// Every binding with its application and the principal it admits: the
+	// named one, or else the application's service account.
+	ProducerBindingGrants(ctx context.Context) ([]ProducerBindingGrantsRow, error)
+	ProducerBindingInsert(ctx context.Context, arg ProducerBindingInsertParams) error
 	RoleApplicationCodes(ctx context.Context) ([]*string, error)
 	RoleCountAssignments(ctx context.Context, roleName string) (int64, error)
 	RoleDelete(ctx context.Context, id string) error
@@ -269,6 +331,9 @@ type Querier interface {
 	// generating a bespoke per-query Row type.
 	ScheduledJobFindByID(ctx context.Context, id string) (MsgScheduledJob, error)
 	ScheduledJobUpsert(ctx context.Context, arg ScheduledJobUpsertParams) error
+	// The network allowlist of the service account behind a principal. No row
+	// for principals that aren't service accounts.
+	ServiceAccountAllowedCIDRsForPrincipal(ctx context.Context, id string) (ServiceAccountAllowedCIDRsForPrincipalRow, error)
 	ServiceAccountDelete(ctx context.Context, id string) error
 	ServiceAccountFindAll(ctx context.Context) ([]IamServiceAccount, error)
 	ServiceAccountFindByCode(ctx context.Context, code string) (IamServiceAccount, error)
@@ -277,7 +342,15 @@ type Querier interface {
 	// wh_signing_algorithm) matching Rust. The repository maps the flat
 	// columns into a single WebhookCredentials struct in the aggregate.
 	ServiceAccountFindByID(ctx context.Context, id string) (IamServiceAccount, error)
+	// Rewrites the stored credentials in place, leaving updated_at (and so the
+	// row's ETag) alone. Used by the credential reseal migration.
+	ServiceAccountSetCredentialRefs(ctx context.Context, arg ServiceAccountSetCredentialRefsParams) error
 	ServiceAccountUpsert(ctx context.Context, arg ServiceAccountUpsertParams) error
+	SigningKeyFindByClient(ctx context.Context, clientID string) ([]TntClientSigningKey, error)
+	// Queries for tnt_client_signing_keys. secret_enc is the encryption.Service
+	// envelope; the repository seals and opens it.
+	SigningKeyFindByID(ctx context.Context, id string) (TntClientSigningKey, error)
+	SigningKeyUpsert(ctx context.Context, arg SigningKeyUpsertParams) error
 	SpecVersionUpsert(ctx context.Context, arg SpecVersionUpsertParams) error
 	SpecVersionsClear(ctx context.Context, eventTypeID string) error
 	SpecVersionsForEventTypes(ctx context.Context, eventTypeIds []string) ([]MsgEventTypeSpecVersion, error)
@@ -302,6 +375,21 @@ type Querier interface {
 	// created_by was added Go-side in migration 035 (Rust never had it; its
 	// rows read back NULL).
 	SubscriptionFindByID(ctx context.Context, id string) (MsgSubscription, error)
+	SubscriptionHeaderInsert(ctx context.Context, arg SubscriptionHeaderInsertParams) error
+	SubscriptionHeadersClear(ctx context.Context, subscriptionID string) error
+	SubscriptionHeadersForSubs(ctx context.Context, subscriptionIds []string) ([]SubscriptionHeadersForSubsRow, error)
+	SubscriptionLargePayloadsFor(ctx context.Context, id string) (string, error)
+	SubscriptionShadowDeliveriesPrune(ctx context.Context, arg SubscriptionShadowDeliveriesPruneParams) error
+	SubscriptionShadowDeliveryInsert(ctx context.Context, arg SubscriptionShadowDeliveryInsertParams) error
+	SubscriptionShadowOutcomes(ctx context.Context, arg SubscriptionShadowOutcomesParams) ([]SubscriptionShadowOutcomesRow, error)
+	SubscriptionShadowReport(ctx context.Context, arg SubscriptionShadowReportParams) (SubscriptionShadowReportRow, error)
+	SubscriptionShadowTargetFor(ctx context.Context, id string) (*string, error)
+	SubscriptionTLSTrustFor(ctx context.Context, id string) (SubscriptionTLSTrustForRow, error)
+	SubscriptionTargetInsert(ctx context.Context, arg SubscriptionTargetInsertParams) error
+	SubscriptionTargetStats(ctx context.Context, arg SubscriptionTargetStatsParams) ([]SubscriptionTargetStatsRow, error)
+	SubscriptionTargetsClear(ctx context.Context, subscriptionID string) error
+	SubscriptionTargetsForSubs(ctx context.Context, subscriptionIds []string) ([]SubscriptionTargetsForSubsRow, error)
+	SubscriptionTransportFor(ctx context.Context, id string) (*string, error)
 	SubscriptionUpsert(ctx context.Context, arg SubscriptionUpsertParams) error
 	WebauthnCeremonyConsume(ctx context.Context, id string) (json.RawMessage, error)
 	WebauthnCeremonyPurgeExpired(ctx context.Context, arg WebauthnCeremonyPurgeExpiredParams) (int64, error)
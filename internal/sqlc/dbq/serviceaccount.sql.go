@@ -10,6 +10,27 @@ import (
 	"time"
 )
 
+const serviceAccountAllowedCIDRsForPrincipal = `-- name: ServiceAccountAllowedCIDRsForPrincipal :one
+SELECT sa.id, sa.allowed_cidrs
+FROM iam_service_accounts sa
+JOIN iam_principals p ON p.service_account_id = sa.id
+WHERE p.id = $1
+`
+
+type ServiceAccountAllowedCIDRsForPrincipalRow struct {
+	ID           string   `db:"id"`
+	AllowedCidrs []string `db:"allowed_cidrs"`
+}
+
+// The network allowlist of the service account behind a principal. No row
+// for principals that aren't service accounts.
+func (q *Queries) ServiceAccountAllowedCIDRsForPrincipal(ctx context.Context, id string) (ServiceAccountAllowedCIDRsForPrincipalRow, error) {
+	row := q.db.QueryRow(ctx, serviceAccountAllowedCIDRsForPrincipal, id)
+	var i ServiceAccountAllowedCIDRsForPrincipalRow
+	err := row.Scan(&i.ID, &i.AllowedCidrs)
+	return i, err
+}
+
 const serviceAccountDelete = `-- name: ServiceAccountDelete :exec
 DELETE FROM iam_service_accounts WHERE id = $1
 `
@@ -24,7 +45,7 @@ SELECT id, code, name, description, application_id, active,
        wh_auth_type, wh_auth_token_ref, wh_signing_secret_ref,
        wh_signing_algorithm, wh_credentials_created_at,
        wh_credentials_regenerated_at, last_used_at, created_at, updated_at,
-       scope, client_ids
+       scope, client_ids, allowed_cidrs
 FROM iam_service_accounts
 ORDER BY code
 `
@@ -56,6 +77,7 @@ func (q *Queries) ServiceAccountFindAll(ctx context.Context) ([]IamServiceAccoun
 			&i.UpdatedAt,
 			&i.Scope,
 			&i.ClientIds,
+			&i.AllowedCidrs,
 		); err != nil {
 			return nil, err
 		}
@@ -72,7 +94,7 @@ SELECT id, code, name, description, application_id, active,
        wh_auth_type, wh_auth_token_ref, wh_signing_secret_ref,
        wh_signing_algorithm, wh_credentials_created_at,
        wh_credentials_regenerated_at, last_used_at, created_at, updated_at,
-       scope, client_ids
+       scope, client_ids, allowed_cidrs
 FROM iam_service_accounts
 WHERE code = $1
 `
@@ -98,6 +120,7 @@ func (q *Queries) ServiceAccountFindByCode(ctx context.Context, code string) (Ia
 		&i.UpdatedAt,
 		&i.Scope,
 		&i.ClientIds,
+		&i.AllowedCidrs,
 	)
 	return i, err
 }
@@ -108,7 +131,7 @@ SELECT id, code, name, description, application_id, active,
        wh_auth_type, wh_auth_token_ref, wh_signing_secret_ref,
        wh_signing_algorithm, wh_credentials_created_at,
        wh_credentials_regenerated_at, last_used_at, created_at, updated_at,
-       scope, client_ids
+       scope, client_ids, allowed_cidrs
 FROM iam_service_accounts
 WHERE id = $1
 `
@@ -138,23 +161,45 @@ func (q *Queries) ServiceAccountFindByID(ctx context.Context, id string) (IamSer
 		&i.UpdatedAt,
 		&i.Scope,
 		&i.ClientIds,
+		&i.AllowedCidrs,
 	)
 	return i, err
 }
 
+const serviceAccountSetCredentialRefs = `-- name: ServiceAccountSetCredentialRefs :exec
+UPDATE iam_service_accounts
+SET wh_auth_token_ref = $2, wh_signing_secret_ref = $3
+WHERE id = $1
+`
+
+type ServiceAccountSetCredentialRefsParams struct {
+	ID                 string  `db:"id"`
+	WhAuthTokenRef     *string `db:"wh_auth_token_ref"`
+	WhSigningSecretRef *string `db:"wh_signing_secret_ref"`
+}
+
+// Rewrites the stored credentials in place, leaving updated_at (and so the
+// row's ETag) alone. Used by the credential reseal migration.
+func (q *Queries) ServiceAccountSetCredentialRefs(ctx context.Context, arg ServiceAccountSetCredentialRefsParams) error {
+	_, err := q.db.Exec(ctx, serviceAccountSetCredentialRefs, arg.ID, arg.WhAuthTokenRef, arg.WhSigningSecretRef)
+	return err
+}
+
 const serviceAccountUpsert = `-- name: ServiceAccountUpsert :exec
 INSERT INTO iam_service_accounts
     (id, code, name, description, application_id, scope, client_ids, active,
      wh_auth_type, wh_auth_token_ref, wh_signing_secret_ref,
      wh_signing_algorithm, wh_credentials_created_at,
-     wh_credentials_regenerated_at, last_used_at, created_at, updated_at)
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+     wh_credentials_regenerated_at, last_used_at, created_at, updated_at,
+     allowed_cidrs)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
 ON CONFLICT (id) DO UPDATE SET
     name = EXCLUDED.name,
     description = EXCLUDED.description,
     application_id = EXCLUDED.application_id,
     scope = EXCLUDED.scope,
     client_ids = EXCLUDED.client_ids,
+    allowed_cidrs = EXCLUDED.allowed_cidrs,
     active = EXCLUDED.active,
     wh_auth_type = EXCLUDED.wh_auth_type,
     wh_auth_token_ref = EXCLUDED.wh_auth_token_ref,
@@ -184,6 +229,7 @@ type ServiceAccountUpsertParams struct {
 	LastUsedAt                 *time.Time `db:"last_used_at"`
 	CreatedAt                  time.Time  `db:"created_at"`
 	UpdatedAt                  time.Time  `db:"updated_at"`
+	AllowedCidrs               []string   `db:"allowed_cidrs"`
 }
 
 func (q *Queries) ServiceAccountUpsert(ctx context.Context, arg ServiceAccountUpsertParams) error {
@@ -205,6 +251,7 @@ func (q *Queries) ServiceAccountUpsert(ctx context.Context, arg ServiceAccountUp
 		arg.LastUsedAt,
 		arg.CreatedAt,
 		arg.UpdatedAt,
+		arg.AllowedCidrs,
 	)
 	return err
 }
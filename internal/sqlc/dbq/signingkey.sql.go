@@ -0,0 +1,112 @@
+// Code generated by sqlc. DO NOT EDIT.
+// versions:
+//   sqlc v1.31.1
+// source: signingkey.sql
+
+package dbq
+
+import (
+	"context"
+	"time"
+)
+
+const signingKeyFindByClient = `-- name: SigningKeyFindByClient :many
+SELECT id, client_id, key_id, secret_enc, active_from, expires_at,
+       created_by, created_at, updated_at
+FROM tnt_client_signing_keys
+WHERE client_id = $1
+ORDER BY active_from DESC, created_at DESC
+`
+
+func (q *Queries) SigningKeyFindByClient(ctx context.Context, clientID string) ([]TntClientSigningKey, error) {
+	rows, err := q.db.Query(ctx, signingKeyFindByClient, clientID)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []TntClientSigningKey{}
+	for rows.Next() {
+		var i TntClientSigningKey
+		if err := rows.Scan(
+			&i.ID,
+			&i.ClientID,
+			&i.KeyID,
+			&i.SecretEnc,
+			&i.ActiveFrom,
+			&i.ExpiresAt,
+			&i.CreatedBy,
+			&i.CreatedAt,
+			&i.UpdatedAt,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const signingKeyFindByID = `-- name: SigningKeyFindByID :one
+SELECT id, client_id, key_id, secret_enc, active_from, expires_at,
+       created_by, created_at, updated_at
+FROM tnt_client_signing_keys
+WHERE id = $1
+`
+
+// Queries for tnt_client_signing_keys. secret_enc is the encryption.Service
+// envelope; the repository seals and opens it.
+func (q *Queries) SigningKeyFindByID(ctx context.Context, id string) (TntClientSigningKey, error) {
+	row := q.db.QueryRow(ctx, signingKeyFindByID, id)
+	var i TntClientSigningKey
+	err := row.Scan(
+		&i.ID,
+		&i.ClientID,
+		&i.KeyID,
+		&i.SecretEnc,
+		&i.ActiveFrom,
+		&i.ExpiresAt,
+		&i.CreatedBy,
+		&i.CreatedAt,
+		&i.UpdatedAt,
+	)
+	return i, err
+}
+
+const signingKeyUpsert = `-- name: SigningKeyUpsert :exec
+INSERT INTO tnt_client_signing_keys
+    (id, client_id, key_id, secret_enc, active_from, expires_at,
+     created_by, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (id) DO UPDATE SET
+    expires_at = EXCLUDED.expires_at,
+    updated_at = EXCLUDED.updated_at
+`
+
+type SigningKeyUpsertParams struct {
+	ID         string     `db:"id"`
+	ClientID   string     `db:"client_id"`
+	KeyID      string     `db:"key_id"`
+	SecretEnc  string     `db:"secret_enc"`
+	ActiveFrom time.Time  `db:"active_from"`
+	ExpiresAt  *time.Time `db:"expires_at"`
+	CreatedBy  *string    `db:"created_by"`
+	CreatedAt  time.Time  `db:"created_at"`
+	UpdatedAt  time.Time  `db:"updated_at"`
+}
+
+func (q *Queries) SigningKeyUpsert(ctx context.Context, arg SigningKeyUpsertParams) error {
+	_, err := q.db.Exec(ctx, signingKeyUpsert,
+		arg.ID,
+		arg.ClientID,
+		arg.KeyID,
+		arg.SecretEnc,
+		arg.ActiveFrom,
+		arg.ExpiresAt,
+		arg.CreatedBy,
+		arg.CreatedAt,
+		arg.UpdatedAt,
+	)
+	return err
+}
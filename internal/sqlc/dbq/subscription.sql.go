@@ -10,6 +10,28 @@ import (
 	"time"
 )
 
+const subscriptionBatchingFor = `-- name: SubscriptionBatchingFor :one
+SELECT batch_max_items, batch_max_bytes, batch_max_wait_seconds
+FROM msg_subscriptions WHERE id = $1
+`
+
+type SubscriptionBatchingForRow struct {
+	BatchMaxItems       int32 `db:"batch_max_items"`
+	BatchMaxBytes       int32 `db:"batch_max_bytes"`
+	BatchMaxWaitSeconds int32 `db:"batch_max_wait_seconds"`
+}
+
+func (q *Queries) SubscriptionBatchingFor(ctx context.Context, id string) (SubscriptionBatchingForRow, error) {
+	row := q.db.QueryRow(ctx, subscriptionBatchingFor, id)
+	var i SubscriptionBatchingForRow
+	err := row.Scan(
+		&i.BatchMaxItems,
+		&i.BatchMaxBytes,
+		&i.BatchMaxWaitSeconds,
+	)
+	return i, err
+}
+
 const subscriptionConfigInsert = `-- name: SubscriptionConfigInsert :exec
 INSERT INTO msg_subscription_custom_configs
     (subscription_id, config_key, config_value)
@@ -152,7 +174,9 @@ SELECT id, code, application_code, name, description, client_id,
        client_identifier, client_scoped, target, queue,
        source, status, max_age_seconds, dispatch_pool_id, dispatch_pool_code,
        delay_seconds, sequence, mode, timeout_seconds, max_retries,
-       service_account_id, data_only, created_at, updated_at, connection_id, created_by
+       service_account_id, data_only, created_at, updated_at, connection_id, created_by,
+       transport, delivery_mode, batch_max_items, batch_max_bytes, batch_max_wait_seconds,
+       payload_format, large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version
 FROM msg_subscriptions
 ORDER BY code
 `
@@ -193,6 +217,17 @@ func (q *Queries) SubscriptionFindAll(ctx context.Context) ([]MsgSubscription, e
 			&i.UpdatedAt,
 			&i.ConnectionID,
 			&i.CreatedBy,
+			&i.Transport,
+			&i.DeliveryMode,
+			&i.BatchMaxItems,
+			&i.BatchMaxBytes,
+			&i.BatchMaxWaitSeconds,
+			&i.PayloadFormat,
+			&i.LargePayloads,
+			&i.ShadowTarget,
+			&i.TlsCaBundle,
+			&i.TlsSpkiPins,
+			&i.TlsMinVersion,
 		); err != nil {
 			return nil, err
 		}
@@ -209,7 +244,9 @@ SELECT id, code, application_code, name, description, client_id,
        client_identifier, client_scoped, target, queue,
        source, status, max_age_seconds, dispatch_pool_id, dispatch_pool_code,
        delay_seconds, sequence, mode, timeout_seconds, max_retries,
-       service_account_id, data_only, created_at, updated_at, connection_id, created_by
+       service_account_id, data_only, created_at, updated_at, connection_id, created_by,
+       transport, delivery_mode, batch_max_items, batch_max_bytes, batch_max_wait_seconds,
+       payload_format, large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version
 FROM msg_subscriptions
 WHERE code = $1 AND client_id IS NULL
 `
@@ -244,6 +281,17 @@ func (q *Queries) SubscriptionFindByCodeAnchor(ctx context.Context, code string)
 		&i.UpdatedAt,
 		&i.ConnectionID,
 		&i.CreatedBy,
+		&i.Transport,
+		&i.DeliveryMode,
+		&i.BatchMaxItems,
+		&i.BatchMaxBytes,
+		&i.BatchMaxWaitSeconds,
+		&i.PayloadFormat,
+		&i.LargePayloads,
+		&i.ShadowTarget,
+		&i.TlsCaBundle,
+		&i.TlsSpkiPins,
+		&i.TlsMinVersion,
 	)
 	return i, err
 }
@@ -253,7 +301,9 @@ SELECT id, code, application_code, name, description, client_id,
        client_identifier, client_scoped, target, queue,
        source, status, max_age_seconds, dispatch_pool_id, dispatch_pool_code,
        delay_seconds, sequence, mode, timeout_seconds, max_retries,
-       service_account_id, data_only, created_at, updated_at, connection_id, created_by
+       service_account_id, data_only, created_at, updated_at, connection_id, created_by,
+       transport, delivery_mode, batch_max_items, batch_max_bytes, batch_max_wait_seconds,
+       payload_format, large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version
 FROM msg_subscriptions
 WHERE code = $1 AND client_id = $2
 `
@@ -293,6 +343,17 @@ func (q *Queries) SubscriptionFindByCodeClient(ctx context.Context, arg Subscrip
 		&i.UpdatedAt,
 		&i.ConnectionID,
 		&i.CreatedBy,
+		&i.Transport,
+		&i.DeliveryMode,
+		&i.BatchMaxItems,
+		&i.BatchMaxBytes,
+		&i.BatchMaxWaitSeconds,
+		&i.PayloadFormat,
+		&i.LargePayloads,
+		&i.ShadowTarget,
+		&i.TlsCaBundle,
+		&i.TlsSpkiPins,
+		&i.TlsMinVersion,
 	)
 	return i, err
 }
@@ -303,7 +364,9 @@ SELECT id, code, application_code, name, description, client_id,
        client_identifier, client_scoped, target, queue,
        source, status, max_age_seconds, dispatch_pool_id, dispatch_pool_code,
        delay_seconds, sequence, mode, timeout_seconds, max_retries,
-       service_account_id, data_only, created_at, updated_at, connection_id, created_by
+       service_account_id, data_only, created_at, updated_at, connection_id, created_by,
+       transport, delivery_mode, batch_max_items, batch_max_bytes, batch_max_wait_seconds,
+       payload_format, large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version
 FROM msg_subscriptions
 WHERE id = $1
 `
@@ -349,18 +412,442 @@ func (q *Queries) SubscriptionFindByID(ctx context.Context, id string) (MsgSubsc
 		&i.UpdatedAt,
 		&i.ConnectionID,
 		&i.CreatedBy,
+		&i.Transport,
+		&i.DeliveryMode,
+		&i.BatchMaxItems,
+		&i.BatchMaxBytes,
+		&i.BatchMaxWaitSeconds,
+		&i.PayloadFormat,
+		&i.LargePayloads,
+		&i.ShadowTarget,
+		&i.TlsCaBundle,
+		&i.TlsSpkiPins,
+		&i.TlsMinVersion,
 	)
 	return i, err
 }
 
+const subscriptionHeaderInsert = `-- name: SubscriptionHeaderInsert :exec
+INSERT INTO msg_subscription_headers
+    (subscription_id, header_name, header_value)
+VALUES ($1, $2, $3)
+`
+
+type SubscriptionHeaderInsertParams struct {
+	SubscriptionID string `db:"subscription_id"`
+	HeaderName     string `db:"header_name"`
+	HeaderValue    string `db:"header_value"`
+}
+
+func (q *Queries) SubscriptionHeaderInsert(ctx context.Context, arg SubscriptionHeaderInsertParams) error {
+	_, err := q.db.Exec(ctx, subscriptionHeaderInsert, arg.SubscriptionID, arg.HeaderName, arg.HeaderValue)
+	return err
+}
+
+const subscriptionHeadersClear = `-- name: SubscriptionHeadersClear :exec
+DELETE FROM msg_subscription_headers WHERE subscription_id = $1
+`
+
+func (q *Queries) SubscriptionHeadersClear(ctx context.Context, subscriptionID string) error {
+	_, err := q.db.Exec(ctx, subscriptionHeadersClear, subscriptionID)
+	return err
+}
+
+const subscriptionHeadersForSubs = `-- name: SubscriptionHeadersForSubs :many
+SELECT subscription_id, header_name, header_value
+FROM msg_subscription_headers
+WHERE subscription_id = ANY($1::text[])
+ORDER BY id
+`
+
+type SubscriptionHeadersForSubsRow struct {
+	SubscriptionID string `db:"subscription_id"`
+	HeaderName     string `db:"header_name"`
+	HeaderValue    string `db:"header_value"`
+}
+
+func (q *Queries) SubscriptionHeadersForSubs(ctx context.Context, subscriptionIds []string) ([]SubscriptionHeadersForSubsRow, error) {
+	rows, err := q.db.Query(ctx, subscriptionHeadersForSubs, subscriptionIds)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []SubscriptionHeadersForSubsRow{}
+	for rows.Next() {
+		var i SubscriptionHeadersForSubsRow
+		if err := rows.Scan(&i.SubscriptionID, &i.HeaderName, &i.HeaderValue); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const subscriptionLargePayloadsFor = `-- name: SubscriptionLargePayloadsFor :one
+SELECT large_payloads FROM msg_subscriptions WHERE id = $1
+`
+
+func (q *Queries) SubscriptionLargePayloadsFor(ctx context.Context, id string) (string, error) {
+	row := q.db.QueryRow(ctx, subscriptionLargePayloadsFor, id)
+	var large_payloads string
+	err := row.Scan(&large_payloads)
+	return large_payloads, err
+}
+
+const subscriptionPayloadFormatFor = `-- name: SubscriptionPayloadFormatFor :one
+SELECT payload_format FROM msg_subscriptions WHERE id = $1
+`
+
+func (q *Queries) SubscriptionPayloadFormatFor(ctx context.Context, id string) (string, error) {
+	row := q.db.QueryRow(ctx, subscriptionPayloadFormatFor, id)
+	var payload_format string
+	err := row.Scan(&payload_format)
+	return payload_format, err
+}
+
+const subscriptionShadowDeliveriesPrune = `-- name: SubscriptionShadowDeliveriesPrune :exec
+DELETE FROM msg_subscription_shadow_deliveries
+WHERE subscription_id = $1
+  AND shadow_target IS DISTINCT FROM $2
+`
+
+type SubscriptionShadowDeliveriesPruneParams struct {
+	SubscriptionID string  `db:"subscription_id"`
+	ShadowTarget   *string `db:"shadow_target"`
+}
+
+func (q *Queries) SubscriptionShadowDeliveriesPrune(ctx context.Context, arg SubscriptionShadowDeliveriesPruneParams) error {
+	_, err := q.db.Exec(ctx, subscriptionShadowDeliveriesPrune, arg.SubscriptionID, arg.ShadowTarget)
+	return err
+}
+
+const subscriptionShadowDeliveryInsert = `-- name: SubscriptionShadowDeliveryInsert :exec
+INSERT INTO msg_subscription_shadow_deliveries
+    (id, subscription_id, dispatch_job_id, shadow_target,
+     primary_status_code, primary_success, primary_duration_ms, primary_error,
+     shadow_status_code, shadow_success, shadow_duration_ms, shadow_error, created_at)
+VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13)
+`
+
+type SubscriptionShadowDeliveryInsertParams struct {
+	ID                string    `db:"id"`
+	SubscriptionID    string    `db:"subscription_id"`
+	DispatchJobID     string    `db:"dispatch_job_id"`
+	ShadowTarget      string    `db:"shadow_target"`
+	PrimaryStatusCode *int32    `db:"primary_status_code"`
+	PrimarySuccess    bool      `db:"primary_success"`
+	PrimaryDurationMs int64     `db:"primary_duration_ms"`
+	PrimaryError      *string   `db:"primary_error"`
+	ShadowStatusCode  *int32    `db:"shadow_status_code"`
+	ShadowSuccess     bool      `db:"shadow_success"`
+	ShadowDurationMs  int64     `db:"shadow_duration_ms"`
+	ShadowError       *string   `db:"shadow_error"`
+	CreatedAt         time.Time `db:"created_at"`
+}
+
+func (q *Queries) SubscriptionShadowDeliveryInsert(ctx context.Context, arg SubscriptionShadowDeliveryInsertParams) error {
+	_, err := q.db.Exec(ctx, subscriptionShadowDeliveryInsert,
+		arg.ID,
+		arg.SubscriptionID,
+		arg.DispatchJobID,
+		arg.ShadowTarget,
+		arg.PrimaryStatusCode,
+		arg.PrimarySuccess,
+		arg.PrimaryDurationMs,
+		arg.PrimaryError,
+		arg.ShadowStatusCode,
+		arg.ShadowSuccess,
+		arg.ShadowDurationMs,
+		arg.ShadowError,
+		arg.CreatedAt,
+	)
+	return err
+}
+
+const subscriptionShadowOutcomes = `-- name: SubscriptionShadowOutcomes :many
+SELECT primary_status_code, primary_success, shadow_status_code, shadow_success,
+       count(*)::bigint AS deliveries
+FROM msg_subscription_shadow_deliveries
+WHERE subscription_id = $1 AND shadow_target = $2 AND created_at >= $3
+GROUP BY primary_status_code, primary_success, shadow_status_code, shadow_success
+ORDER BY deliveries DESC
+LIMIT $4
+`
+
+type SubscriptionShadowOutcomesParams struct {
+	SubscriptionID string    `db:"subscription_id"`
+	ShadowTarget   string    `db:"shadow_target"`
+	CreatedAt      time.Time `db:"created_at"`
+	Limit          int32     `db:"limit"`
+}
+
+type SubscriptionShadowOutcomesRow struct {
+	PrimaryStatusCode *int32 `db:"primary_status_code"`
+	PrimarySuccess    bool   `db:"primary_success"`
+	ShadowStatusCode  *int32 `db:"shadow_status_code"`
+	ShadowSuccess     bool   `db:"shadow_success"`
+	Deliveries        int64  `db:"deliveries"`
+}
+
+func (q *Queries) SubscriptionShadowOutcomes(ctx context.Context, arg SubscriptionShadowOutcomesParams) ([]SubscriptionShadowOutcomesRow, error) {
+	rows, err := q.db.Query(ctx, subscriptionShadowOutcomes,
+		arg.SubscriptionID,
+		arg.ShadowTarget,
+		arg.CreatedAt,
+		arg.Limit,
+	)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []SubscriptionShadowOutcomesRow{}
+	for rows.Next() {
+		var i SubscriptionShadowOutcomesRow
+		if err := rows.Scan(
+			&i.PrimaryStatusCode,
+			&i.PrimarySuccess,
+			&i.ShadowStatusCode,
+			&i.ShadowSuccess,
+			&i.Deliveries,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const subscriptionShadowReport = `-- name: SubscriptionShadowReport :one
+SELECT count(*)::bigint AS deliveries,
+       count(*) FILTER (WHERE primary_success)::bigint AS primary_successes,
+       count(*) FILTER (WHERE shadow_success)::bigint AS shadow_successes,
+       count(*) FILTER (WHERE primary_success = shadow_success)::bigint AS agreed,
+       COALESCE(avg(primary_duration_ms), 0)::bigint AS primary_avg_ms,
+       COALESCE(percentile_cont(0.5) WITHIN GROUP (ORDER BY primary_duration_ms), 0)::bigint AS primary_p50_ms,
+       COALESCE(percentile_cont(0.95) WITHIN GROUP (ORDER BY primary_duration_ms), 0)::bigint AS primary_p95_ms,
+       COALESCE(avg(shadow_duration_ms), 0)::bigint AS shadow_avg_ms,
+       COALESCE(percentile_cont(0.5) WITHIN GROUP (ORDER BY shadow_duration_ms), 0)::bigint AS shadow_p50_ms,
+       COALESCE(percentile_cont(0.95) WITHIN GROUP (ORDER BY shadow_duration_ms), 0)::bigint AS shadow_p95_ms
+FROM msg_subscription_shadow_deliveries
+WHERE subscription_id = $1 AND shadow_target = $2 AND created_at >= $3
+`
+
+type SubscriptionShadowReportParams struct {
+	SubscriptionID string    `db:"subscription_id"`
+	ShadowTarget   string    `db:"shadow_target"`
+	CreatedAt      time.Time `db:"created_at"`
+}
+
+type SubscriptionShadowReportRow struct {
+	Deliveries       int64 `db:"deliveries"`
+	PrimarySuccesses int64 `db:"primary_successes"`
+	ShadowSuccesses  int64 `db:"shadow_successes"`
+	Agreed           int64 `db:"agreed"`
+	PrimaryAvgMs     int64 `db:"primary_avg_ms"`
+	PrimaryP50Ms     int64 `db:"primary_p50_ms"`
+	PrimaryP95Ms     int64 `db:"primary_p95_ms"`
+	ShadowAvgMs      int64 `db:"shadow_avg_ms"`
+	ShadowP50Ms      int64 `db:"shadow_p50_ms"`
+	ShadowP95Ms      int64 `db:"shadow_p95_ms"`
+}
+
+func (q *Queries) SubscriptionShadowReport(ctx context.Context, arg SubscriptionShadowReportParams) (SubscriptionShadowReportRow, error) {
+	row := q.db.QueryRow(ctx, subscriptionShadowReport, arg.SubscriptionID, arg.ShadowTarget, arg.CreatedAt)
+	var i SubscriptionShadowReportRow
+	err := row.Scan(
+		&i.Deliveries,
+		&i.PrimarySuccesses,
+		&i.ShadowSuccesses,
+		&i.Agreed,
+		&i.PrimaryAvgMs,
+		&i.PrimaryP50Ms,
+		&i.PrimaryP95Ms,
+		&i.ShadowAvgMs,
+		&i.ShadowP50Ms,
+		&i.ShadowP95Ms,
+	)
+	return i, err
+}
+
+const subscriptionShadowTargetFor = `-- name: SubscriptionShadowTargetFor :one
+SELECT shadow_target FROM msg_subscriptions WHERE id = $1
+`
+
+func (q *Queries) SubscriptionShadowTargetFor(ctx context.Context, id string) (*string, error) {
+	row := q.db.QueryRow(ctx, subscriptionShadowTargetFor, id)
+	var shadow_target *string
+	err := row.Scan(&shadow_target)
+	return shadow_target, err
+}
+
+const subscriptionTLSTrustFor = `-- name: SubscriptionTLSTrustFor :one
+SELECT tls_ca_bundle, tls_spki_pins, tls_min_version FROM msg_subscriptions WHERE id = $1
+`
+
+type SubscriptionTLSTrustForRow struct {
+	TlsCaBundle   *string  `db:"tls_ca_bundle"`
+	TlsSpkiPins   []string `db:"tls_spki_pins"`
+	TlsMinVersion *string  `db:"tls_min_version"`
+}
+
+func (q *Queries) SubscriptionTLSTrustFor(ctx context.Context, id string) (SubscriptionTLSTrustForRow, error) {
+	row := q.db.QueryRow(ctx, subscriptionTLSTrustFor, id)
+	var i SubscriptionTLSTrustForRow
+	err := row.Scan(
+		&i.TlsCaBundle,
+		&i.TlsSpkiPins,
+		&i.TlsMinVersion,
+	)
+	return i, err
+}
+
+const subscriptionTargetInsert = `-- name: SubscriptionTargetInsert :exec
+INSERT INTO msg_subscription_targets
+    (subscription_id, target, weight)
+VALUES ($1, $2, $3)
+`
+
+type SubscriptionTargetInsertParams struct {
+	SubscriptionID string `db:"subscription_id"`
+	Target         string `db:"target"`
+	Weight         int32  `db:"weight"`
+}
+
+func (q *Queries) SubscriptionTargetInsert(ctx context.Context, arg SubscriptionTargetInsertParams) error {
+	_, err := q.db.Exec(ctx, subscriptionTargetInsert, arg.SubscriptionID, arg.Target, arg.Weight)
+	return err
+}
+
+const subscriptionTargetStats = `-- name: SubscriptionTargetStats :many
+SELECT j.target_url,
+       count(DISTINCT j.id)::bigint AS jobs,
+       count(DISTINCT j.id) FILTER (WHERE j.status = 'COMPLETED')::bigint AS completed,
+       count(DISTINCT j.id) FILTER (WHERE j.status IN ('FAILED', 'EXPIRED'))::bigint AS failed,
+       count(a.id)::bigint AS attempts,
+       count(a.id) FILTER (WHERE a.status = 'SUCCESS')::bigint AS successes,
+       COALESCE(avg(a.duration_millis), 0)::bigint AS avg_ms,
+       COALESCE(percentile_cont(0.95) WITHIN GROUP (ORDER BY a.duration_millis), 0)::bigint AS p95_ms
+FROM msg_dispatch_jobs j
+LEFT JOIN msg_dispatch_job_attempts a ON a.dispatch_job_id = j.id
+WHERE j.subscription_id = $1 AND j.created_at >= $2
+GROUP BY j.target_url
+ORDER BY jobs DESC
+`
+
+type SubscriptionTargetStatsParams struct {
+	SubscriptionID *string   `db:"subscription_id"`
+	CreatedAt      time.Time `db:"created_at"`
+}
+
+type SubscriptionTargetStatsRow struct {
+	TargetUrl string `db:"target_url"`
+	Jobs      int64  `db:"jobs"`
+	Completed int64  `db:"completed"`
+	Failed    int64  `db:"failed"`
+	Attempts  int64  `db:"attempts"`
+	Successes int64  `db:"successes"`
+	AvgMs     int64  `db:"avg_ms"`
+	P95Ms     int64  `db:"p95_ms"`
+}
+
+func (q *Queries) SubscriptionTargetStats(ctx context.Context, arg SubscriptionTargetStatsParams) ([]SubscriptionTargetStatsRow, error) {
+	rows, err := q.db.Query(ctx, subscriptionTargetStats, arg.SubscriptionID, arg.CreatedAt)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []SubscriptionTargetStatsRow{}
+	for rows.Next() {
+		var i SubscriptionTargetStatsRow
+		if err := rows.Scan(
+			&i.TargetUrl,
+			&i.Jobs,
+			&i.Completed,
+			&i.Failed,
+			&i.Attempts,
+			&i.Successes,
+			&i.AvgMs,
+			&i.P95Ms,
+		); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const subscriptionTargetsClear = `-- name: SubscriptionTargetsClear :exec
+DELETE FROM msg_subscription_targets WHERE subscription_id = $1
+`
+
+func (q *Queries) SubscriptionTargetsClear(ctx context.Context, subscriptionID string) error {
+	_, err := q.db.Exec(ctx, subscriptionTargetsClear, subscriptionID)
+	return err
+}
+
+const subscriptionTargetsForSubs = `-- name: SubscriptionTargetsForSubs :many
+SELECT subscription_id, target, weight
+FROM msg_subscription_targets
+WHERE subscription_id = ANY($1::text[])
+ORDER BY id
+`
+
+type SubscriptionTargetsForSubsRow struct {
+	SubscriptionID string `db:"subscription_id"`
+	Target         string `db:"target"`
+	Weight         int32  `db:"weight"`
+}
+
+func (q *Queries) SubscriptionTargetsForSubs(ctx context.Context, subscriptionIds []string) ([]SubscriptionTargetsForSubsRow, error) {
+	rows, err := q.db.Query(ctx, subscriptionTargetsForSubs, subscriptionIds)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	items := []SubscriptionTargetsForSubsRow{}
+	for rows.Next() {
+		var i SubscriptionTargetsForSubsRow
+		if err := rows.Scan(&i.SubscriptionID, &i.Target, &i.Weight); err != nil {
+			return nil, err
+		}
+		items = append(items, i)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return items, nil
+}
+
+const subscriptionTransportFor = `-- name: SubscriptionTransportFor :one
+SELECT transport FROM msg_subscriptions WHERE id = $1
+`
+
+func (q *Queries) SubscriptionTransportFor(ctx context.Context, id string) (*string, error) {
+	row := q.db.QueryRow(ctx, subscriptionTransportFor, id)
+	var transport *string
+	err := row.Scan(&transport)
+	return transport, err
+}
+
 const subscriptionUpsert = `-- name: SubscriptionUpsert :exec
 INSERT INTO msg_subscriptions
     (id, code, application_code, name, description, client_id, client_identifier,
      client_scoped, connection_id, target, queue, source, status, max_age_seconds,
      dispatch_pool_id, dispatch_pool_code, delay_seconds, sequence, mode,
      timeout_seconds, max_retries, service_account_id, data_only,
-     created_by, created_at, updated_at)
-VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23,$24,$25,$26)
+     created_by, created_at, updated_at, transport, delivery_mode,
+     batch_max_items, batch_max_bytes, batch_max_wait_seconds, payload_format,
+     large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version)
+VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23,$24,$25,$26,$27,$28,$29,$30,$31,$32,$33,$34,$35,$36,$37)
 ON CONFLICT (id) DO UPDATE SET
     name = EXCLUDED.name,
     description = EXCLUDED.description,
@@ -376,36 +863,58 @@ ON CONFLICT (id) DO UPDATE SET
     max_retries = EXCLUDED.max_retries,
     service_account_id = EXCLUDED.service_account_id,
     data_only = EXCLUDED.data_only,
+    transport = EXCLUDED.transport,
+    delivery_mode = EXCLUDED.delivery_mode,
+    batch_max_items = EXCLUDED.batch_max_items,
+    batch_max_bytes = EXCLUDED.batch_max_bytes,
+    batch_max_wait_seconds = EXCLUDED.batch_max_wait_seconds,
+    payload_format = EXCLUDED.payload_format,
+    large_payloads = EXCLUDED.large_payloads,
+    shadow_target = EXCLUDED.shadow_target,
+    tls_ca_bundle = EXCLUDED.tls_ca_bundle,
+    tls_spki_pins = EXCLUDED.tls_spki_pins,
+    tls_min_version = EXCLUDED.tls_min_version,
     updated_at = EXCLUDED.updated_at
 `
 
 type SubscriptionUpsertParams struct {
-	ID               string    `db:"id"`
-	Code             string    `db:"code"`
-	ApplicationCode  *string   `db:"application_code"`
-	Name             string    `db:"name"`
-	Description      *string   `db:"description"`
-	ClientID         *string   `db:"client_id"`
-	ClientIdentifier *string   `db:"client_identifier"`
-	ClientScoped     bool      `db:"client_scoped"`
-	ConnectionID     *string   `db:"connection_id"`
-	Target           string    `db:"target"`
-	Queue            *string   `db:"queue"`
-	Source           string    `db:"source"`
-	Status           string    `db:"status"`
-	MaxAgeSeconds    int32     `db:"max_age_seconds"`
-	DispatchPoolID   *string   `db:"dispatch_pool_id"`
-	DispatchPoolCode *string   `db:"dispatch_pool_code"`
-	DelaySeconds     int32     `db:"delay_seconds"`
-	Sequence         int32     `db:"sequence"`
-	Mode             string    `db:"mode"`
-	TimeoutSeconds   int32     `db:"timeout_seconds"`
-	MaxRetries       int32     `db:"max_retries"`
-	ServiceAccountID *string   `db:"service_account_id"`
-	DataOnly         bool      `db:"data_only"`
-	CreatedBy        *string   `db:"created_by"`
-	CreatedAt        time.Time `db:"created_at"`
-	UpdatedAt        time.Time `db:"updated_at"`
+	ID                  string    `db:"id"`
+	Code                string    `db:"code"`
+	ApplicationCode     *string   `db:"application_code"`
+	Name                string    `db:"name"`
+	Description         *string   `db:"description"`
+	ClientID            *string   `db:"client_id"`
+	ClientIdentifier    *string   `db:"client_identifier"`
+	ClientScoped        bool      `db:"client_scoped"`
+	ConnectionID        *string   `db:"connection_id"`
+	Target              string    `db:"target"`
+	Queue               *string   `db:"queue"`
+	Source              string    `db:"source"`
+	Status              string    `db:"status"`
+	MaxAgeSeconds       int32     `db:"max_age_seconds"`
+	DispatchPoolID      *string   `db:"dispatch_pool_id"`
+	DispatchPoolCode    *string   `db:"dispatch_pool_code"`
+	DelaySeconds        int32     `db:"delay_seconds"`
+	Sequence            int32     `db:"sequence"`
+	Mode                string    `db:"mode"`
+	TimeoutSeconds      int32     `db:"timeout_seconds"`
+	MaxRetries          int32     `db:"max_retries"`
+	ServiceAccountID    *string   `db:"service_account_id"`
+	DataOnly            bool      `db:"data_only"`
+	CreatedBy           *string   `db:"created_by"`
+	CreatedAt           time.Time `db:"created_at"`
+	UpdatedAt           time.Time `db:"updated_at"`
+	Transport           *string   `db:"transport"`
+	DeliveryMode        string    `db:"delivery_mode"`
+	BatchMaxItems       int32     `db:"batch_max_items"`
+	BatchMaxBytes       int32     `db:"batch_max_bytes"`
+	BatchMaxWaitSeconds int32     `db:"batch_max_wait_seconds"`
+	PayloadFormat       string    `db:"payload_format"`
+	LargePayloads       string    `db:"large_payloads"`
+	ShadowTarget        *string   `db:"shadow_target"`
+	TlsCaBundle         *string   `db:"tls_ca_bundle"`
+	TlsSpkiPins         []string  `db:"tls_spki_pins"`
+	TlsMinVersion       *string   `db:"tls_min_version"`
 }
 
 func (q *Queries) SubscriptionUpsert(ctx context.Context, arg SubscriptionUpsertParams) error {
@@ -436,6 +945,17 @@ func (q *Queries) SubscriptionUpsert(ctx context.Context, arg SubscriptionUpsert
 		arg.CreatedBy,
 		arg.CreatedAt,
 		arg.UpdatedAt,
+		arg.Transport,
+		arg.DeliveryMode,
+		arg.BatchMaxItems,
+		arg.BatchMaxBytes,
+		arg.BatchMaxWaitSeconds,
+		arg.PayloadFormat,
+		arg.LargePayloads,
+		arg.ShadowTarget,
+		arg.TlsCaBundle,
+		arg.TlsSpkiPins,
+		arg.TlsMinVersion,
 	)
 	return err
 }
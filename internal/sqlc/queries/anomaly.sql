@@ -0,0 +1,36 @@
+-- Queries for msg_anomaly_settings and msg_anomalies. The detector
+-- (internal/stream/anomalies.go) maintains baselines and raises and
+-- resolves anomalies with its own SQL; these cover the use-case write path
+-- and the API reads.
+
+-- name: AnomalySettingFindByEntity :one
+SELECT id, entity_kind, entity_key, sensitivity, enabled, snoozed_until,
+       updated_by, created_at, updated_at
+FROM msg_anomaly_settings
+WHERE entity_kind = $1 AND entity_key = $2;
+
+-- name: AnomalySettingFindAll :many
+SELECT id, entity_kind, entity_key, sensitivity, enabled, snoozed_until,
+       updated_by, created_at, updated_at
+FROM msg_anomaly_settings
+ORDER BY entity_kind, entity_key;
+
+-- name: AnomalySettingUpsert :exec
+INSERT INTO msg_anomaly_settings
+    (id, entity_kind, entity_key, sensitivity, enabled, snoozed_until,
+     updated_by, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (id) DO UPDATE SET
+    sensitivity = EXCLUDED.sensitivity,
+    enabled = EXCLUDED.enabled,
+    snoozed_until = EXCLUDED.snoozed_until,
+    updated_by = EXCLUDED.updated_by,
+    updated_at = EXCLUDED.updated_at;
+
+-- name: AnomalyFindByStatus :many
+SELECT id, entity_kind, entity_key, kind, status, message, observed,
+       expected, z_score, raised_at, last_seen_at, resolved_at
+FROM msg_anomalies
+WHERE status = $1
+ORDER BY raised_at DESC, id
+LIMIT $2;
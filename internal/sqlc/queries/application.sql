@@ -60,3 +60,33 @@ ON CONFLICT (id) DO UPDATE SET
 
 -- name: ClientConfigDelete :exec
 DELETE FROM app_client_configs WHERE id = $1;
+
+-- name: ProducerBindingFindByID :one
+SELECT id, application_id, principal_id, event_type_pattern, created_at
+FROM app_producer_bindings
+WHERE id = $1;
+
+-- name: ProducerBindingFindByApp :many
+SELECT id, application_id, principal_id, event_type_pattern, created_at
+FROM app_producer_bindings
+WHERE application_id = $1
+ORDER BY event_type_pattern, created_at;
+
+-- name: ProducerBindingInsert :exec
+INSERT INTO app_producer_bindings
+    (id, application_id, principal_id, event_type_pattern, created_at)
+VALUES ($1, $2, $3, $4, $5)
+ON CONFLICT (id) DO NOTHING;
+
+-- name: ProducerBindingDelete :exec
+DELETE FROM app_producer_bindings WHERE id = $1;
+
+-- name: ProducerBindingGrants :many
+-- Every binding with its application and the principal it admits: the
+-- named one, or else the application's service account.
+SELECT b.id, b.application_id, a.code AS application_code, a.active AS application_active,
+       COALESCE(b.principal_id, a.service_account_id) AS principal_id,
+       b.event_type_pattern
+FROM app_producer_bindings b
+JOIN app_applications a ON a.id = b.application_id
+ORDER BY b.event_type_pattern, a.code;
@@ -0,0 +1,103 @@
+-- Queries for msg_archive_cursors, msg_archive_files and msg_archive_replays.
+-- The archiver (internal/platform/archive) writes the cursors and the
+-- manifest, which the API reads; replays are written by their use cases
+-- and the replayer.
+
+-- name: ArchiveCursorFindAll :many
+SELECT kind, after_time, after_id, archived, last_run_at, last_error, updated_at
+FROM msg_archive_cursors
+ORDER BY kind;
+
+-- name: ArchiveCursorAdvance :exec
+-- Moves a stream's cursor past a committed batch and clears its error.
+INSERT INTO msg_archive_cursors
+    (kind, after_time, after_id, archived, last_run_at, last_error, updated_at)
+VALUES ($1, $2, $3, $4, NOW(), NULL, NOW())
+ON CONFLICT (kind) DO UPDATE SET
+    after_time = EXCLUDED.after_time,
+    after_id = EXCLUDED.after_id,
+    archived = msg_archive_cursors.archived + EXCLUDED.archived,
+    last_run_at = NOW(),
+    last_error = NULL,
+    updated_at = NOW();
+
+-- name: ArchiveCursorRecordRun :exec
+-- Stamps a run that archived nothing, with its error if it failed. A
+-- stream never run before starts at the epoch.
+INSERT INTO msg_archive_cursors
+    (kind, after_time, after_id, archived, last_run_at, last_error, updated_at)
+VALUES ($1, 'epoch', '', 0, NOW(), $2, NOW())
+ON CONFLICT (kind) DO UPDATE SET
+    last_run_at = NOW(),
+    last_error = EXCLUDED.last_error,
+    updated_at = NOW();
+
+-- name: ArchiveFileInsert :exec
+INSERT INTO msg_archive_files
+    (id, kind, object_key, schema_version, partition_hour, records, bytes,
+     first_id, last_id, min_time, max_time, created_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NOW());
+
+-- name: ArchiveFileList :many
+SELECT id, kind, object_key, schema_version, partition_hour, records, bytes,
+       first_id, last_id, min_time, max_time, created_at
+FROM msg_archive_files
+WHERE (sqlc.narg('kind')::text IS NULL OR kind = sqlc.narg('kind')::text)
+  AND (sqlc.narg('since')::timestamptz IS NULL OR partition_hour >= sqlc.narg('since')::timestamptz)
+  AND (sqlc.narg('until')::timestamptz IS NULL OR partition_hour < sqlc.narg('until')::timestamptz)
+  AND (sqlc.narg('after_id')::text IS NULL OR id > sqlc.narg('after_id')::text)
+ORDER BY id
+LIMIT sqlc.arg('lim')::int;
+
+-- name: ArchiveReplayFindByID :one
+SELECT id, from_time, to_time, event_types, client_id, subscription_id,
+       status, after_file_id, files_read, events_read, events_replayed,
+       error, requested_by, created_at, started_at, completed_at, updated_at
+FROM msg_archive_replays
+WHERE id = $1;
+
+-- name: ArchiveReplayList :many
+SELECT id, from_time, to_time, event_types, client_id, subscription_id,
+       status, after_file_id, files_read, events_read, events_replayed,
+       error, requested_by, created_at, started_at, completed_at, updated_at
+FROM msg_archive_replays
+ORDER BY created_at DESC
+LIMIT $1;
+
+-- name: ArchiveReplayFindNextOpen :one
+-- The oldest replay still to run; the replayer works them one at a time.
+SELECT id, from_time, to_time, event_types, client_id, subscription_id,
+       status, after_file_id, files_read, events_read, events_replayed,
+       error, requested_by, created_at, started_at, completed_at, updated_at
+FROM msg_archive_replays
+WHERE status IN ('PENDING', 'RUNNING')
+ORDER BY created_at
+LIMIT 1;
+
+-- name: ArchiveReplayUpsert :exec
+-- The use-case write path: a new replay, or a change of status. Progress
+-- belongs to the replayer and is left alone.
+INSERT INTO msg_archive_replays
+    (id, from_time, to_time, event_types, client_id, subscription_id,
+     status, error, requested_by, created_at, completed_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+ON CONFLICT (id) DO UPDATE SET
+    status = EXCLUDED.status,
+    error = EXCLUDED.error,
+    completed_at = EXCLUDED.completed_at,
+    updated_at = EXCLUDED.updated_at;
+
+-- name: ArchiveReplayRecordProgress :execrows
+-- Records a replayer pass. Matches nothing once the replay is terminal,
+-- which is how the replayer notices a cancellation.
+UPDATE msg_archive_replays
+SET status = $2,
+    after_file_id = $3,
+    files_read = $4,
+    events_read = $5,
+    events_replayed = $6,
+    error = $7,
+    started_at = $8,
+    completed_at = $9,
+    updated_at = NOW()
+WHERE id = $1 AND status IN ('PENDING', 'RUNNING');
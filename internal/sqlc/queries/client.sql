@@ -4,19 +4,26 @@
 
 -- name: ClientFindByID :one
 SELECT id, name, identifier, status, status_reason, status_changed_at,
-       notes, created_at, updated_at
+       notes, created_at, updated_at, require_passkey_for_admins, region
 FROM tnt_clients
 WHERE id = $1;
 
 -- name: ClientFindByIdentifier :one
 SELECT id, name, identifier, status, status_reason, status_changed_at,
-       notes, created_at, updated_at
+       notes, created_at, updated_at, require_passkey_for_admins, region
 FROM tnt_clients
 WHERE identifier = $1;
 
+-- name: ClientRequiresPasskeyForAdmins :one
+SELECT EXISTS(
+    SELECT 1 FROM tnt_clients
+    WHERE require_passkey_for_admins = true
+      AND (@all_clients::boolean OR id = ANY(@ids::varchar[]))
+);
+
 -- name: ClientSearch :many
 SELECT id, name, identifier, status, status_reason, status_changed_at,
-       notes, created_at, updated_at
+       notes, created_at, updated_at, require_passkey_for_admins, region
 FROM tnt_clients
 WHERE name ILIKE @pattern OR identifier ILIKE @pattern
 ORDER BY identifier
@@ -24,16 +31,16 @@ LIMIT 50;
 
 -- name: ClientFindAll :many
 SELECT id, name, identifier, status, status_reason, status_changed_at,
-       notes, created_at, updated_at
+       notes, created_at, updated_at, require_passkey_for_admins, region
 FROM tnt_clients
 ORDER BY identifier;
 
 -- name: ClientUpsert :exec
 INSERT INTO tnt_clients
     (id, name, identifier, status, status_reason, status_changed_at,
-     notes, created_at, updated_at)
+     notes, created_at, updated_at, require_passkey_for_admins, region)
 VALUES
-    ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
 ON CONFLICT (id) DO UPDATE SET
     name = EXCLUDED.name,
     identifier = EXCLUDED.identifier,
@@ -41,7 +48,9 @@ ON CONFLICT (id) DO UPDATE SET
     status_reason = EXCLUDED.status_reason,
     status_changed_at = EXCLUDED.status_changed_at,
     notes = EXCLUDED.notes,
-    updated_at = EXCLUDED.updated_at;
+    updated_at = EXCLUDED.updated_at,
+    require_passkey_for_admins = EXCLUDED.require_passkey_for_admins,
+    region = EXCLUDED.region;
 
 -- name: ClientDelete :exec
 DELETE FROM tnt_clients WHERE id = $1;
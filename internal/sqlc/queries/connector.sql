@@ -0,0 +1,23 @@
+-- Queries for msg_connector_checkpoints. Written only by the connector
+-- runner (internal/connect), one writer per connector.
+
+-- name: ConnectorCheckpointFind :one
+SELECT connector, position, snapshot_status, snapshot_started_at,
+       snapshot_completed_at, last_change_at, last_error, emitted, updated_at
+FROM msg_connector_checkpoints
+WHERE connector = $1;
+
+-- name: ConnectorCheckpointUpsert :exec
+INSERT INTO msg_connector_checkpoints
+    (connector, position, snapshot_status, snapshot_started_at,
+     snapshot_completed_at, last_change_at, last_error, emitted, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW())
+ON CONFLICT (connector) DO UPDATE SET
+    position = EXCLUDED.position,
+    snapshot_status = EXCLUDED.snapshot_status,
+    snapshot_started_at = EXCLUDED.snapshot_started_at,
+    snapshot_completed_at = EXCLUDED.snapshot_completed_at,
+    last_change_at = EXCLUDED.last_change_at,
+    last_error = EXCLUDED.last_error,
+    emitted = EXCLUDED.emitted,
+    updated_at = NOW();
@@ -19,7 +19,7 @@ SELECT id, external_id, source, kind, code, subject, event_id,
        timeout_seconds, schema_id, status, max_retries, retry_strategy,
        scheduled_for, expires_at, attempt_count, last_attempt_at,
        completed_at, duration_millis, last_error, idempotency_key,
-       created_at, updated_at
+       created_at, updated_at, ack_deadline
 FROM msg_dispatch_jobs
 WHERE id = $1;
 
@@ -36,13 +36,16 @@ VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14,
         $27, $28, $29, $30, $31, $32, $33, $34, $35, $36);
 
 -- name: DispatchJobMarkInProgress :exec
--- Status → PROCESSING. Stamps last_attempt_at. Called by the router
--- immediately before the first delivery attempt.
+-- Status → PROCESSING. Stamps last_attempt_at and clears the previous
+-- attempt's ack token, if any. Called by the router immediately before
+-- each delivery attempt.
 UPDATE msg_dispatch_jobs
    SET status = 'PROCESSING',
        last_attempt_at = $2,
+       ack_token = NULL,
+       ack_deadline = NULL,
        updated_at = $2
- WHERE id = $1;
+ WHERE id = $1 AND status <> 'CANCELLED';
 
 -- name: DispatchJobMarkCompleted :exec
 -- Status → COMPLETED. Stamps completed_at + duration_millis.
@@ -51,7 +54,7 @@ UPDATE msg_dispatch_jobs
        completed_at = $2,
        duration_millis = $3,
        updated_at = $2
- WHERE id = $1;
+ WHERE id = $1 AND status <> 'CANCELLED';
 
 -- name: DispatchJobMarkFailed :exec
 -- Terminal failure. Stamps last_error + completed_at + duration_millis.
@@ -61,7 +64,7 @@ UPDATE msg_dispatch_jobs
        duration_millis = $3,
        last_error = $4,
        updated_at = $2
- WHERE id = $1;
+ WHERE id = $1 AND status <> 'CANCELLED';
 
 -- name: DispatchJobScheduleRetry :exec
 -- Bumps attempt_count + stamps scheduled_for so the next poll picks
@@ -73,7 +76,7 @@ UPDATE msg_dispatch_jobs
        last_attempt_at = NOW(),
        status = 'PENDING',
        updated_at = NOW()
- WHERE id = $1;
+ WHERE id = $1 AND status <> 'CANCELLED';
 
 -- name: DispatchJobAttemptInsert :exec
 -- One row per delivery attempt. The schema column `status` stores the
@@ -4,19 +4,25 @@
 
 -- name: EventTypeFindByID :one
 SELECT id, code, name, description, status, source, client_scoped,
-       application, subdomain, aggregate, created_at, updated_at, created_by
+       application, subdomain, aggregate, created_at, updated_at, created_by,
+       payload_retention_days, metadata_retention_days, redact_fields,
+       registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint
 FROM msg_event_types
 WHERE id = $1;
 
 -- name: EventTypeFindByCode :one
 SELECT id, code, name, description, status, source, client_scoped,
-       application, subdomain, aggregate, created_at, updated_at, created_by
+       application, subdomain, aggregate, created_at, updated_at, created_by,
+       payload_retention_days, metadata_retention_days, redact_fields,
+       registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint
 FROM msg_event_types
 WHERE code = $1;
 
 -- name: EventTypeFindByApplication :many
 SELECT id, code, name, description, status, source, client_scoped,
-       application, subdomain, aggregate, created_at, updated_at, created_by
+       application, subdomain, aggregate, created_at, updated_at, created_by,
+       payload_retention_days, metadata_retention_days, redact_fields,
+       registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint
 FROM msg_event_types
 WHERE application = $1
 ORDER BY code;
@@ -24,8 +30,10 @@ ORDER BY code;
 -- name: EventTypeUpsertByID :exec
 INSERT INTO msg_event_types
     (id, code, name, description, status, source, client_scoped,
-     application, subdomain, aggregate, created_by, created_at, updated_at)
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+     application, subdomain, aggregate, created_by, created_at, updated_at,
+     payload_retention_days, metadata_retention_days, redact_fields,
+     registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
 ON CONFLICT (id) DO UPDATE SET
     code = EXCLUDED.code,
     name = EXCLUDED.name,
@@ -36,13 +44,23 @@ ON CONFLICT (id) DO UPDATE SET
     application = EXCLUDED.application,
     subdomain = EXCLUDED.subdomain,
     aggregate = EXCLUDED.aggregate,
+    payload_retention_days = EXCLUDED.payload_retention_days,
+    metadata_retention_days = EXCLUDED.metadata_retention_days,
+    redact_fields = EXCLUDED.redact_fields,
+    registry_subject = EXCLUDED.registry_subject,
+    registry_mode = EXCLUDED.registry_mode,
+    deprecated_at = EXCLUDED.deprecated_at,
+    sunset_at = EXCLUDED.sunset_at,
+    migration_hint = EXCLUDED.migration_hint,
     updated_at = EXCLUDED.updated_at;
 
 -- name: EventTypeUpsertByCode :exec
 INSERT INTO msg_event_types
     (id, code, name, description, status, source, client_scoped,
-     application, subdomain, aggregate, created_by, created_at, updated_at)
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+     application, subdomain, aggregate, created_by, created_at, updated_at,
+     payload_retention_days, metadata_retention_days, redact_fields,
+     registry_subject, registry_mode, deprecated_at, sunset_at, migration_hint)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
 ON CONFLICT (code) DO UPDATE SET
     name = EXCLUDED.name,
     description = EXCLUDED.description,
@@ -51,6 +69,21 @@ ON CONFLICT (code) DO UPDATE SET
     client_scoped = EXCLUDED.client_scoped,
     updated_at = EXCLUDED.updated_at;
 
+-- name: EventTypeRedactionRules :many
+SELECT code, redact_fields
+FROM msg_event_types
+WHERE cardinality(redact_fields) > 0;
+
+-- name: EventTypeRegistryBindings :many
+SELECT code, registry_subject, registry_mode
+FROM msg_event_types
+WHERE registry_subject IS NOT NULL;
+
+-- name: EventTypeLifecycles :many
+SELECT code, deprecated_at, sunset_at, migration_hint
+FROM msg_event_types
+WHERE deprecated_at IS NOT NULL;
+
 -- name: EventTypeDelete :exec
 DELETE FROM msg_event_types WHERE id = $1;
 
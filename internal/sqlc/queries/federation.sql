@@ -0,0 +1,53 @@
+-- Queries for msg_federation_peers and msg_federation_peer_states. Peers
+-- are written by use cases; states only by the federation puller.
+
+-- name: FederationPeerFindByID :one
+SELECT id, code, name, base_url, client_id, client_secret_ref, event_types,
+       status, created_by, created_at, updated_at
+FROM msg_federation_peers
+WHERE id = $1;
+
+-- name: FederationPeerFindByCode :one
+SELECT id, code, name, base_url, client_id, client_secret_ref, event_types,
+       status, created_by, created_at, updated_at
+FROM msg_federation_peers
+WHERE code = $1;
+
+-- name: FederationPeerFindAll :many
+SELECT id, code, name, base_url, client_id, client_secret_ref, event_types,
+       status, created_by, created_at, updated_at
+FROM msg_federation_peers
+ORDER BY code;
+
+-- name: FederationPeerUpsert :exec
+INSERT INTO msg_federation_peers
+    (id, code, name, base_url, client_id, client_secret_ref, event_types,
+     status, created_by, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+ON CONFLICT (id) DO UPDATE SET
+    name = EXCLUDED.name,
+    base_url = EXCLUDED.base_url,
+    client_id = EXCLUDED.client_id,
+    client_secret_ref = EXCLUDED.client_secret_ref,
+    event_types = EXCLUDED.event_types,
+    status = EXCLUDED.status,
+    updated_at = EXCLUDED.updated_at;
+
+-- name: FederationPeerDelete :exec
+DELETE FROM msg_federation_peers WHERE id = $1;
+
+-- name: FederationPeerStateFindAll :many
+SELECT peer_id, feed_cursor, last_pulled_at, last_error, received, skipped, updated_at
+FROM msg_federation_peer_states;
+
+-- name: FederationPeerStateUpsert :exec
+INSERT INTO msg_federation_peer_states
+    (peer_id, feed_cursor, last_pulled_at, last_error, received, skipped, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, NOW())
+ON CONFLICT (peer_id) DO UPDATE SET
+    feed_cursor = EXCLUDED.feed_cursor,
+    last_pulled_at = EXCLUDED.last_pulled_at,
+    last_error = EXCLUDED.last_error,
+    received = msg_federation_peer_states.received + EXCLUDED.received,
+    skipped = msg_federation_peer_states.skipped + EXCLUDED.skipped,
+    updated_at = NOW();
@@ -0,0 +1,14 @@
+-- Queries for iam_jwt_keyrings. ring_enc is the encryption.Service
+-- envelope; the keyring store seals and opens it.
+
+-- name: JwtKeyringFind :one
+SELECT id, ring_enc, updated_at
+FROM iam_jwt_keyrings
+WHERE id = $1;
+
+-- name: JwtKeyringUpsert :exec
+INSERT INTO iam_jwt_keyrings (id, ring_enc, updated_at)
+VALUES ($1, $2, $3)
+ON CONFLICT (id) DO UPDATE SET
+    ring_enc = EXCLUDED.ring_enc,
+    updated_at = EXCLUDED.updated_at;
@@ -0,0 +1,28 @@
+-- Queries for msg_kill_switches. A switch is engaged by inserting its row
+-- and released by deleting it; (scope, target_id) is unique.
+
+-- name: KillSwitchFindByID :one
+SELECT id, scope, target_id, reason, engaged_by, created_at, updated_at
+FROM msg_kill_switches
+WHERE id = $1;
+
+-- name: KillSwitchFindByTarget :one
+SELECT id, scope, target_id, reason, engaged_by, created_at, updated_at
+FROM msg_kill_switches
+WHERE scope = $1 AND target_id = $2;
+
+-- name: KillSwitchFindAll :many
+SELECT id, scope, target_id, reason, engaged_by, created_at, updated_at
+FROM msg_kill_switches
+ORDER BY created_at DESC, id;
+
+-- name: KillSwitchUpsert :exec
+INSERT INTO msg_kill_switches
+    (id, scope, target_id, reason, engaged_by, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+ON CONFLICT (id) DO UPDATE SET
+    reason = EXCLUDED.reason,
+    updated_at = EXCLUDED.updated_at;
+
+-- name: KillSwitchDelete :exec
+DELETE FROM msg_kill_switches WHERE id = $1;
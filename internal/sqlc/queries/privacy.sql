@@ -0,0 +1,33 @@
+-- Queries for aud_erasure_requests. The erasure worker claims and updates
+-- rows with its own SQL (internal/platform/privacy/worker.go); these cover
+-- the use-case write path and the API reads.
+
+-- name: ErasureRequestFindByID :one
+SELECT id, subject_type, subject, subject_hash, context_keys, client_id,
+       status, report, error, requested_by, created_at, started_at,
+       completed_at, updated_at
+FROM aud_erasure_requests
+WHERE id = $1;
+
+-- name: ErasureRequestList :many
+SELECT id, subject_type, subject, subject_hash, context_keys, client_id,
+       status, report, error, requested_by, created_at, started_at,
+       completed_at, updated_at
+FROM aud_erasure_requests
+ORDER BY created_at DESC
+LIMIT $1;
+
+-- name: ErasureRequestUpsert :exec
+INSERT INTO aud_erasure_requests
+    (id, subject_type, subject, subject_hash, context_keys, client_id,
+     status, report, error, requested_by, created_at, started_at,
+     completed_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+ON CONFLICT (id) DO UPDATE SET
+    subject = EXCLUDED.subject,
+    status = EXCLUDED.status,
+    report = EXCLUDED.report,
+    error = EXCLUDED.error,
+    started_at = EXCLUDED.started_at,
+    completed_at = EXCLUDED.completed_at,
+    updated_at = EXCLUDED.updated_at;
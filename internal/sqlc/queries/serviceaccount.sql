@@ -8,7 +8,7 @@ SELECT id, code, name, description, application_id, active,
        wh_auth_type, wh_auth_token_ref, wh_signing_secret_ref,
        wh_signing_algorithm, wh_credentials_created_at,
        wh_credentials_regenerated_at, last_used_at, created_at, updated_at,
-       scope, client_ids
+       scope, client_ids, allowed_cidrs
 FROM iam_service_accounts
 WHERE id = $1;
 
@@ -17,7 +17,7 @@ SELECT id, code, name, description, application_id, active,
        wh_auth_type, wh_auth_token_ref, wh_signing_secret_ref,
        wh_signing_algorithm, wh_credentials_created_at,
        wh_credentials_regenerated_at, last_used_at, created_at, updated_at,
-       scope, client_ids
+       scope, client_ids, allowed_cidrs
 FROM iam_service_accounts
 WHERE code = $1;
 
@@ -26,7 +26,7 @@ SELECT id, code, name, description, application_id, active,
        wh_auth_type, wh_auth_token_ref, wh_signing_secret_ref,
        wh_signing_algorithm, wh_credentials_created_at,
        wh_credentials_regenerated_at, last_used_at, created_at, updated_at,
-       scope, client_ids
+       scope, client_ids, allowed_cidrs
 FROM iam_service_accounts
 ORDER BY code;
 
@@ -35,14 +35,16 @@ INSERT INTO iam_service_accounts
     (id, code, name, description, application_id, scope, client_ids, active,
      wh_auth_type, wh_auth_token_ref, wh_signing_secret_ref,
      wh_signing_algorithm, wh_credentials_created_at,
-     wh_credentials_regenerated_at, last_used_at, created_at, updated_at)
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+     wh_credentials_regenerated_at, last_used_at, created_at, updated_at,
+     allowed_cidrs)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
 ON CONFLICT (id) DO UPDATE SET
     name = EXCLUDED.name,
     description = EXCLUDED.description,
     application_id = EXCLUDED.application_id,
     scope = EXCLUDED.scope,
     client_ids = EXCLUDED.client_ids,
+    allowed_cidrs = EXCLUDED.allowed_cidrs,
     active = EXCLUDED.active,
     wh_auth_type = EXCLUDED.wh_auth_type,
     wh_auth_token_ref = EXCLUDED.wh_auth_token_ref,
@@ -53,5 +55,20 @@ ON CONFLICT (id) DO UPDATE SET
     last_used_at = EXCLUDED.last_used_at,
     updated_at = EXCLUDED.updated_at;
 
+-- name: ServiceAccountSetCredentialRefs :exec
+-- Rewrites the stored credentials in place, leaving updated_at (and so the
+-- row's ETag) alone. Used by the credential reseal migration.
+UPDATE iam_service_accounts
+SET wh_auth_token_ref = $2, wh_signing_secret_ref = $3
+WHERE id = $1;
+
+-- name: ServiceAccountAllowedCIDRsForPrincipal :one
+-- The network allowlist of the service account behind a principal. No row
+-- for principals that aren't service accounts.
+SELECT sa.id, sa.allowed_cidrs
+FROM iam_service_accounts sa
+JOIN iam_principals p ON p.service_account_id = sa.id
+WHERE p.id = $1;
+
 -- name: ServiceAccountDelete :exec
 DELETE FROM iam_service_accounts WHERE id = $1;
@@ -0,0 +1,24 @@
+-- Queries for tnt_client_signing_keys. secret_enc is the encryption.Service
+-- envelope; the repository seals and opens it.
+
+-- name: SigningKeyFindByID :one
+SELECT id, client_id, key_id, secret_enc, active_from, expires_at,
+       created_by, created_at, updated_at
+FROM tnt_client_signing_keys
+WHERE id = $1;
+
+-- name: SigningKeyFindByClient :many
+SELECT id, client_id, key_id, secret_enc, active_from, expires_at,
+       created_by, created_at, updated_at
+FROM tnt_client_signing_keys
+WHERE client_id = $1
+ORDER BY active_from DESC, created_at DESC;
+
+-- name: SigningKeyUpsert :exec
+INSERT INTO tnt_client_signing_keys
+    (id, client_id, key_id, secret_enc, active_from, expires_at,
+     created_by, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (id) DO UPDATE SET
+    expires_at = EXCLUDED.expires_at,
+    updated_at = EXCLUDED.updated_at;
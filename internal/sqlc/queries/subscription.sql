@@ -14,7 +14,9 @@ SELECT id, code, application_code, name, description, client_id,
        client_identifier, client_scoped, target, queue,
        source, status, max_age_seconds, dispatch_pool_id, dispatch_pool_code,
        delay_seconds, sequence, mode, timeout_seconds, max_retries,
-       service_account_id, data_only, created_at, updated_at, connection_id, created_by
+       service_account_id, data_only, created_at, updated_at, connection_id, created_by,
+       transport, delivery_mode, batch_max_items, batch_max_bytes, batch_max_wait_seconds,
+       payload_format, large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version
 FROM msg_subscriptions
 WHERE id = $1;
 
@@ -23,7 +25,9 @@ SELECT id, code, application_code, name, description, client_id,
        client_identifier, client_scoped, target, queue,
        source, status, max_age_seconds, dispatch_pool_id, dispatch_pool_code,
        delay_seconds, sequence, mode, timeout_seconds, max_retries,
-       service_account_id, data_only, created_at, updated_at, connection_id, created_by
+       service_account_id, data_only, created_at, updated_at, connection_id, created_by,
+       transport, delivery_mode, batch_max_items, batch_max_bytes, batch_max_wait_seconds,
+       payload_format, large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version
 FROM msg_subscriptions
 WHERE code = $1 AND client_id = $2;
 
@@ -32,7 +36,9 @@ SELECT id, code, application_code, name, description, client_id,
        client_identifier, client_scoped, target, queue,
        source, status, max_age_seconds, dispatch_pool_id, dispatch_pool_code,
        delay_seconds, sequence, mode, timeout_seconds, max_retries,
-       service_account_id, data_only, created_at, updated_at, connection_id, created_by
+       service_account_id, data_only, created_at, updated_at, connection_id, created_by,
+       transport, delivery_mode, batch_max_items, batch_max_bytes, batch_max_wait_seconds,
+       payload_format, large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version
 FROM msg_subscriptions
 WHERE code = $1 AND client_id IS NULL;
 
@@ -41,7 +47,9 @@ SELECT id, code, application_code, name, description, client_id,
        client_identifier, client_scoped, target, queue,
        source, status, max_age_seconds, dispatch_pool_id, dispatch_pool_code,
        delay_seconds, sequence, mode, timeout_seconds, max_retries,
-       service_account_id, data_only, created_at, updated_at, connection_id, created_by
+       service_account_id, data_only, created_at, updated_at, connection_id, created_by,
+       transport, delivery_mode, batch_max_items, batch_max_bytes, batch_max_wait_seconds,
+       payload_format, large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version
 FROM msg_subscriptions
 ORDER BY code;
 
@@ -51,8 +59,10 @@ INSERT INTO msg_subscriptions
      client_scoped, connection_id, target, queue, source, status, max_age_seconds,
      dispatch_pool_id, dispatch_pool_code, delay_seconds, sequence, mode,
      timeout_seconds, max_retries, service_account_id, data_only,
-     created_by, created_at, updated_at)
-VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23,$24,$25,$26)
+     created_by, created_at, updated_at, transport, delivery_mode,
+     batch_max_items, batch_max_bytes, batch_max_wait_seconds, payload_format,
+     large_payloads, shadow_target, tls_ca_bundle, tls_spki_pins, tls_min_version)
+VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23,$24,$25,$26,$27,$28,$29,$30,$31,$32,$33,$34,$35,$36,$37)
 ON CONFLICT (id) DO UPDATE SET
     name = EXCLUDED.name,
     description = EXCLUDED.description,
@@ -68,8 +78,38 @@ ON CONFLICT (id) DO UPDATE SET
     max_retries = EXCLUDED.max_retries,
     service_account_id = EXCLUDED.service_account_id,
     data_only = EXCLUDED.data_only,
+    transport = EXCLUDED.transport,
+    delivery_mode = EXCLUDED.delivery_mode,
+    batch_max_items = EXCLUDED.batch_max_items,
+    batch_max_bytes = EXCLUDED.batch_max_bytes,
+    batch_max_wait_seconds = EXCLUDED.batch_max_wait_seconds,
+    payload_format = EXCLUDED.payload_format,
+    large_payloads = EXCLUDED.large_payloads,
+    shadow_target = EXCLUDED.shadow_target,
+    tls_ca_bundle = EXCLUDED.tls_ca_bundle,
+    tls_spki_pins = EXCLUDED.tls_spki_pins,
+    tls_min_version = EXCLUDED.tls_min_version,
     updated_at = EXCLUDED.updated_at;
 
+-- name: SubscriptionTransportFor :one
+SELECT transport FROM msg_subscriptions WHERE id = $1;
+
+-- name: SubscriptionBatchingFor :one
+SELECT batch_max_items, batch_max_bytes, batch_max_wait_seconds
+FROM msg_subscriptions WHERE id = $1;
+
+-- name: SubscriptionPayloadFormatFor :one
+SELECT payload_format FROM msg_subscriptions WHERE id = $1;
+
+-- name: SubscriptionLargePayloadsFor :one
+SELECT large_payloads FROM msg_subscriptions WHERE id = $1;
+
+-- name: SubscriptionShadowTargetFor :one
+SELECT shadow_target FROM msg_subscriptions WHERE id = $1;
+
+-- name: SubscriptionTLSTrustFor :one
+SELECT tls_ca_bundle, tls_spki_pins, tls_min_version FROM msg_subscriptions WHERE id = $1;
+
 -- name: SubscriptionDelete :exec
 DELETE FROM msg_subscriptions WHERE id = $1;
 
@@ -98,3 +138,87 @@ VALUES (@subscription_id, @config_key, @config_value);
 SELECT subscription_id, config_key, config_value
 FROM msg_subscription_custom_configs
 WHERE subscription_id = ANY(@subscription_ids::text[]);
+
+-- name: SubscriptionHeadersClear :exec
+DELETE FROM msg_subscription_headers WHERE subscription_id = $1;
+
+-- name: SubscriptionHeaderInsert :exec
+INSERT INTO msg_subscription_headers
+    (subscription_id, header_name, header_value)
+VALUES (@subscription_id, @header_name, @header_value);
+
+-- name: SubscriptionHeadersForSubs :many
+SELECT subscription_id, header_name, header_value
+FROM msg_subscription_headers
+WHERE subscription_id = ANY(@subscription_ids::text[])
+ORDER BY id;
+
+-- Shadow deliveries (migration 068): one row per delivery copied to a
+-- subscription's shadow endpoint, with both endpoints' outcomes.
+
+-- name: SubscriptionShadowDeliveryInsert :exec
+INSERT INTO msg_subscription_shadow_deliveries
+    (id, subscription_id, dispatch_job_id, shadow_target,
+     primary_status_code, primary_success, primary_duration_ms, primary_error,
+     shadow_status_code, shadow_success, shadow_duration_ms, shadow_error, created_at)
+VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13);
+
+-- name: SubscriptionShadowDeliveriesPrune :exec
+DELETE FROM msg_subscription_shadow_deliveries
+WHERE subscription_id = @subscription_id
+  AND shadow_target IS DISTINCT FROM sqlc.narg('shadow_target');
+
+-- name: SubscriptionShadowReport :one
+SELECT count(*)::bigint AS deliveries,
+       count(*) FILTER (WHERE primary_success)::bigint AS primary_successes,
+       count(*) FILTER (WHERE shadow_success)::bigint AS shadow_successes,
+       count(*) FILTER (WHERE primary_success = shadow_success)::bigint AS agreed,
+       COALESCE(avg(primary_duration_ms), 0)::bigint AS primary_avg_ms,
+       COALESCE(percentile_cont(0.5) WITHIN GROUP (ORDER BY primary_duration_ms), 0)::bigint AS primary_p50_ms,
+       COALESCE(percentile_cont(0.95) WITHIN GROUP (ORDER BY primary_duration_ms), 0)::bigint AS primary_p95_ms,
+       COALESCE(avg(shadow_duration_ms), 0)::bigint AS shadow_avg_ms,
+       COALESCE(percentile_cont(0.5) WITHIN GROUP (ORDER BY shadow_duration_ms), 0)::bigint AS shadow_p50_ms,
+       COALESCE(percentile_cont(0.95) WITHIN GROUP (ORDER BY shadow_duration_ms), 0)::bigint AS shadow_p95_ms
+FROM msg_subscription_shadow_deliveries
+WHERE subscription_id = $1 AND shadow_target = $2 AND created_at >= $3;
+
+-- name: SubscriptionShadowOutcomes :many
+SELECT primary_status_code, primary_success, shadow_status_code, shadow_success,
+       count(*)::bigint AS deliveries
+FROM msg_subscription_shadow_deliveries
+WHERE subscription_id = $1 AND shadow_target = $2 AND created_at >= $3
+GROUP BY primary_status_code, primary_success, shadow_status_code, shadow_success
+ORDER BY deliveries DESC
+LIMIT $4;
+
+-- Traffic split (migration 069): extra endpoints of a push subscription
+-- and the percentage of message groups each receives.
+
+-- name: SubscriptionTargetsClear :exec
+DELETE FROM msg_subscription_targets WHERE subscription_id = $1;
+
+-- name: SubscriptionTargetInsert :exec
+INSERT INTO msg_subscription_targets
+    (subscription_id, target, weight)
+VALUES (@subscription_id, @target, @weight);
+
+-- name: SubscriptionTargetsForSubs :many
+SELECT subscription_id, target, weight
+FROM msg_subscription_targets
+WHERE subscription_id = ANY(@subscription_ids::text[])
+ORDER BY id;
+
+-- name: SubscriptionTargetStats :many
+SELECT j.target_url,
+       count(DISTINCT j.id)::bigint AS jobs,
+       count(DISTINCT j.id) FILTER (WHERE j.status = 'COMPLETED')::bigint AS completed,
+       count(DISTINCT j.id) FILTER (WHERE j.status IN ('FAILED', 'EXPIRED'))::bigint AS failed,
+       count(a.id)::bigint AS attempts,
+       count(a.id) FILTER (WHERE a.status = 'SUCCESS')::bigint AS successes,
+       COALESCE(avg(a.duration_millis), 0)::bigint AS avg_ms,
+       COALESCE(percentile_cont(0.95) WITHIN GROUP (ORDER BY a.duration_millis), 0)::bigint AS p95_ms
+FROM msg_dispatch_jobs j
+LEFT JOIN msg_dispatch_job_attempts a ON a.dispatch_job_id = j.id
+WHERE j.subscription_id = $1 AND j.created_at >= $2
+GROUP BY j.target_url
+ORDER BY jobs DESC;
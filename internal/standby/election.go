@@ -7,6 +7,11 @@
 //
 // Consumers query IsLeader() (atomic, lock-free) or subscribe to a
 // channel of LeadershipChange events.
+//
+// Redis outages fail closed: an instance that cannot reach Redis is never
+// leader, and keeps retrying on its heartbeat until Redis returns. The
+// election reports each attempt to its degrade.Feature, so the outage
+// shows as DEGRADED on /q/health instead of failing startup.
 package standby
 
 import (
@@ -20,6 +25,8 @@ import (
 	"github.com/redis/go-redis/v9"
 
 	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/degrade"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/redisconn"
 )
 
 // LeadershipChange is emitted on transitions.
@@ -30,8 +37,9 @@ type LeadershipChange struct {
 
 // Election is a single instance of the leader-election state machine.
 type Election struct {
-	cfg    common.LeaderElectionConfig
-	client *redis.Client
+	cfg     common.LeaderElectionConfig
+	client  redis.UniversalClient
+	feature *degrade.Feature
 
 	isLeader atomic.Bool
 	stopOnce sync.Once
@@ -45,15 +53,16 @@ type Election struct {
 // New constructs an Election. The caller is responsible for calling
 // Start to spawn the heartbeat goroutine and Stop on shutdown.
 func New(cfg common.LeaderElectionConfig) (*Election, error) {
-	opts, err := redis.ParseURL(cfg.RedisURL)
+	client, err := redisconn.Open(cfg.RedisURL)
 	if err != nil {
 		return nil, fmt.Errorf("parse redis url: %w", err)
 	}
 	return &Election{
-		cfg:    cfg,
-		client: redis.NewClient(opts),
-		stopCh: make(chan struct{}),
-		doneCh: make(chan struct{}),
+		cfg:     cfg,
+		client:  client,
+		feature: degrade.Register("leader-election("+cfg.LockKey+")", degrade.FailClosed, true),
+		stopCh:  make(chan struct{}),
+		doneCh:  make(chan struct{}),
 	}, nil
 }
 
@@ -61,6 +70,31 @@ func New(cfg common.LeaderElectionConfig) (*Election, error) {
 // Safe to call from any goroutine.
 func (e *Election) IsLeader() bool { return e.isLeader.Load() }
 
+// Degraded reports whether the last lock attempt could not reach Redis.
+func (e *Election) Degraded() bool { return e.feature.Degraded() }
+
+// Ping checks the Redis connection backing the lock. A follower that
+// can't reach Redis can never take over, which IsLeader alone can't show.
+func (e *Election) Ping(ctx context.Context) error { return e.client.Ping(ctx).Err() }
+
+// PutShared stores a value beside the lock (key "<LockKey>:<name>") so
+// whichever instance leads next can pick up state the current leader
+// accumulated. Writes are not fenced: callers check IsLeader first and
+// treat the value as a hint.
+func (e *Election) PutShared(ctx context.Context, name string, value []byte, ttl time.Duration) error {
+	return e.client.Set(ctx, e.cfg.LockKey+":"+name, value, ttl).Err()
+}
+
+// Shared reads a value written by PutShared. Returns nil, nil when the
+// key is absent or expired.
+func (e *Election) Shared(ctx context.Context, name string) ([]byte, error) {
+	b, err := e.client.Get(ctx, e.cfg.LockKey+":"+name).Bytes()
+	if errors.Is(err, redis.Nil) {
+		return nil, nil
+	}
+	return b, err
+}
+
 // Subscribe returns a channel that receives LeadershipChange events.
 // Buffer size 1; older events are dropped if the receiver lags.
 func (e *Election) Subscribe() <-chan LeadershipChange {
@@ -72,7 +106,8 @@ func (e *Election) Subscribe() <-chan LeadershipChange {
 }
 
 // Start spawns the lease loop. Returns immediately. The loop runs
-// until Stop is called or ctx is canceled.
+// until Stop is called or ctx is canceled. An unreachable Redis is not
+// an error: the instance starts as a follower and the loop keeps trying.
 func (e *Election) Start(ctx context.Context) error {
 	if !e.cfg.Enabled {
 		// Disabled: assume leader (single-instance mode).
@@ -81,7 +116,7 @@ func (e *Election) Start(ctx context.Context) error {
 		return nil
 	}
 	if err := e.client.Ping(ctx).Err(); err != nil {
-		return fmt.Errorf("redis ping: %w", err)
+		e.feature.Observe(fmt.Errorf("redis ping: %w", err))
 	}
 	go e.loop(ctx)
 	return nil
@@ -136,8 +171,9 @@ func (e *Election) tryAcquire(ctx context.Context) {
 	}
 	// Try acquire (NX). On success: we are the leader.
 	ok, err := e.client.SetNX(ctx, e.cfg.LockKey, e.cfg.InstanceID, ttl).Result()
+	e.feature.Observe(err)
 	if err != nil {
-		// Network blip; demote to safe.
+		// Network blip or outage; demote to safe.
 		e.setLeader(false)
 		return
 	}
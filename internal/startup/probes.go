@@ -0,0 +1,70 @@
+package startup
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+
+	"go.mongodb.org/mongo-driver/mongo"
+	"go.mongodb.org/mongo-driver/mongo/options"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/redisconn"
+)
+
+// RedisProbe pings the Redis at redisURL (any redisconn topology) with a
+// throwaway client.
+func RedisProbe(redisURL string) func(context.Context) error {
+	return func(ctx context.Context) error {
+		c, err := redisconn.Open(redisURL)
+		if err != nil {
+			return Permanent(fmt.Errorf("parse redis url: %w", err))
+		}
+		defer func() { _ = c.Close() }()
+		return c.Ping(ctx).Err()
+	}
+}
+
+// MongoProbe pings MongoDB with a throwaway client built from opts, so the
+// probe honours the same timeouts as the real client.
+func MongoProbe(opts *options.ClientOptions) func(context.Context) error {
+	return func(ctx context.Context) error {
+		c, err := mongo.Connect(ctx, opts)
+		if err != nil {
+			return fmt.Errorf("mongo connect: %w", err)
+		}
+		defer func() { _ = c.Disconnect(context.WithoutCancel(ctx)) }()
+		return c.Ping(ctx, nil)
+	}
+}
+
+// HTTPProbe GETs target. Any response below maxStatus counts as reachable —
+// pass http.StatusInternalServerError for a service that must be up, or 600
+// when any answer at all (a webhook replying 405 to GET) proves the host is
+// there. Errors name only the host: webhook URLs often embed a secret.
+func HTTPProbe(target string, maxStatus int) func(context.Context) error {
+	return func(ctx context.Context) error {
+		u, err := url.Parse(target)
+		if err != nil {
+			return Permanent(errors.New("invalid URL"))
+		}
+		req, err := http.NewRequestWithContext(ctx, http.MethodGet, target, nil)
+		if err != nil {
+			return Permanent(fmt.Errorf("%s: %w", u.Host, err))
+		}
+		resp, err := http.DefaultClient.Do(req)
+		if err != nil {
+			var uerr *url.Error
+			if errors.As(err, &uerr) {
+				err = uerr.Err
+			}
+			return fmt.Errorf("%s: %w", u.Host, err)
+		}
+		_ = resp.Body.Close()
+		if resp.StatusCode >= maxStatus {
+			return fmt.Errorf("%s: status %d", u.Host, resp.StatusCode)
+		}
+		return nil
+	}
+}
@@ -0,0 +1,220 @@
+// Package startup probes a binary's external dependencies (Postgres, the
+// standby Redis, the outbox Mongo, the router config service, ...) before
+// it starts serving. Each probe is retried with exponential backoff up to a
+// bounded number of attempts, so a dependency that is briefly unavailable
+// at boot — a database still coming up alongside the pod — delays startup
+// instead of killing it with a raw driver error.
+//
+// When a required dependency exhausts its attempts, Run returns a
+// *BlockedError naming it. Optional dependencies (e.g. the router's
+// notification webhook) never block: they are reported DEGRADED and the
+// binary starts without them. Status is safe to call while Run is in
+// progress, which is how the health endpoints show what startup is
+// waiting on.
+package startup
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"log/slog"
+	"sync"
+	"time"
+)
+
+// State is a dependency's probe state.
+type State string
+
+const (
+	StatePending  State = "PENDING"
+	StateProbing  State = "PROBING"
+	StateUp       State = "UP"
+	StateDegraded State = "DEGRADED" // optional dependency unavailable; started without it
+	StateFailed   State = "FAILED"   // required dependency unavailable; startup blocked
+)
+
+// Dependency is one external system to probe.
+type Dependency struct {
+	Name     string
+	Optional bool
+	// Probe makes one connectivity attempt. It is called with a context
+	// bounded by Config.ProbeTimeout.
+	Probe func(ctx context.Context) error
+}
+
+// Config bounds the retries.
+type Config struct {
+	Attempts       int           // attempts per dependency (default 10)
+	InitialBackoff time.Duration // delay after the first failure, doubled per attempt (default 500ms)
+	MaxBackoff     time.Duration // backoff ceiling (default 10s)
+	ProbeTimeout   time.Duration // per-attempt timeout (default 5s)
+}
+
+// DefaultConfig rides out roughly a minute of unavailability.
+func DefaultConfig() Config {
+	return Config{Attempts: 10, InitialBackoff: 500 * time.Millisecond, MaxBackoff: 10 * time.Second, ProbeTimeout: 5 * time.Second}
+}
+
+func (c Config) withDefaults() Config {
+	d := DefaultConfig()
+	if c.Attempts <= 0 {
+		c.Attempts = d.Attempts
+	}
+	if c.InitialBackoff <= 0 {
+		c.InitialBackoff = d.InitialBackoff
+	}
+	if c.MaxBackoff <= 0 {
+		c.MaxBackoff = d.MaxBackoff
+	}
+	if c.ProbeTimeout <= 0 {
+		c.ProbeTimeout = d.ProbeTimeout
+	}
+	return c
+}
+
+// backoff returns the delay after the given (1-based) failed attempt.
+func (c Config) backoff(attempt int) time.Duration {
+	d := c.InitialBackoff
+	for i := 1; i < attempt && d < c.MaxBackoff; i++ {
+		d *= 2
+	}
+	return min(d, c.MaxBackoff)
+}
+
+// DependencyStatus is one dependency's point-in-time probe state.
+type DependencyStatus struct {
+	Name      string `json:"name"`
+	Optional  bool   `json:"optional"`
+	State     State  `json:"state"`
+	Attempts  int    `json:"attempts"`
+	LastError string `json:"lastError,omitempty"`
+}
+
+// BlockedError reports the required dependency that stopped startup.
+type BlockedError struct {
+	Dependency string
+	Attempts   int
+	Err        error
+}
+
+func (e *BlockedError) Error() string {
+	return fmt.Sprintf("startup blocked by %s after %d attempts: %v", e.Dependency, e.Attempts, e.Err)
+}
+
+func (e *BlockedError) Unwrap() error { return e.Err }
+
+// Permanent marks a probe error that retrying can't fix (a malformed URL),
+// so the orchestrator gives up on the dependency at once.
+func Permanent(err error) error { return &permanentError{err} }
+
+type permanentError struct{ err error }
+
+func (e *permanentError) Error() string { return e.err.Error() }
+func (e *permanentError) Unwrap() error { return e.err }
+
+// Orchestrator probes a fixed set of dependencies. Safe for concurrent use.
+type Orchestrator struct {
+	cfg  Config
+	deps []Dependency
+
+	mu     sync.RWMutex
+	status []DependencyStatus
+}
+
+// New wires an orchestrator. Zero Config fields take DefaultConfig values.
+func New(cfg Config, deps ...Dependency) *Orchestrator {
+	status := make([]DependencyStatus, len(deps))
+	for i, d := range deps {
+		status[i] = DependencyStatus{Name: d.Name, Optional: d.Optional, State: StatePending}
+	}
+	return &Orchestrator{cfg: cfg.withDefaults(), deps: deps, status: status}
+}
+
+// Run probes every dependency concurrently and waits for all of them. It
+// returns the first (in declaration order) *BlockedError for a required
+// dependency, ctx's error if startup was cancelled, or nil.
+func (o *Orchestrator) Run(ctx context.Context) error {
+	errs := make([]error, len(o.deps))
+	var wg sync.WaitGroup
+	for i := range o.deps {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			errs[i] = o.probe(ctx, i)
+		}()
+	}
+	wg.Wait()
+	if err := ctx.Err(); err != nil {
+		return err
+	}
+	for _, err := range errs {
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (o *Orchestrator) probe(ctx context.Context, i int) error {
+	dep := o.deps[i]
+	var lastErr error
+	for attempt := 1; attempt <= o.cfg.Attempts; attempt++ {
+		o.update(i, func(s *DependencyStatus) { s.State, s.Attempts = StateProbing, attempt })
+		probeCtx, cancel := context.WithTimeout(ctx, o.cfg.ProbeTimeout)
+		lastErr = dep.Probe(probeCtx)
+		cancel()
+		if lastErr == nil {
+			o.update(i, func(s *DependencyStatus) { s.State, s.LastError = StateUp, "" })
+			if attempt > 1 {
+				slog.Info("startup dependency available", "dependency", dep.Name, "attempts", attempt)
+			}
+			return nil
+		}
+		o.update(i, func(s *DependencyStatus) { s.LastError = lastErr.Error() })
+		if attempt == o.cfg.Attempts || errors.As(lastErr, new(*permanentError)) {
+			break
+		}
+		delay := o.cfg.backoff(attempt)
+		slog.Warn("startup dependency unavailable; retrying",
+			"dependency", dep.Name, "attempt", attempt, "of", o.cfg.Attempts, "retry_in", delay, "err", lastErr)
+		select {
+		case <-ctx.Done():
+			return ctx.Err()
+		case <-time.After(delay):
+		}
+	}
+
+	attempts := o.Status()[i].Attempts
+	if dep.Optional {
+		o.update(i, func(s *DependencyStatus) { s.State = StateDegraded })
+		slog.Warn("optional startup dependency unavailable; starting degraded",
+			"dependency", dep.Name, "attempts", attempts, "err", lastErr)
+		return nil
+	}
+	o.update(i, func(s *DependencyStatus) { s.State = StateFailed })
+	return &BlockedError{Dependency: dep.Name, Attempts: attempts, Err: lastErr}
+}
+
+func (o *Orchestrator) update(i int, fn func(*DependencyStatus)) {
+	o.mu.Lock()
+	defer o.mu.Unlock()
+	fn(&o.status[i])
+}
+
+// Status returns every dependency's state, in declaration order.
+func (o *Orchestrator) Status() []DependencyStatus {
+	o.mu.RLock()
+	defer o.mu.RUnlock()
+	return append([]DependencyStatus(nil), o.status...)
+}
+
+// Degraded lists the optional dependencies startup gave up on.
+func (o *Orchestrator) Degraded() []string {
+	var out []string
+	for _, s := range o.Status() {
+		if s.State == StateDegraded {
+			out = append(out, s.Name)
+		}
+	}
+	return out
+}
@@ -0,0 +1,106 @@
+package startup
+
+import (
+	"context"
+	"errors"
+	"sync/atomic"
+	"testing"
+	"time"
+)
+
+func fastConfig(attempts int) Config {
+	return Config{Attempts: attempts, InitialBackoff: time.Millisecond, MaxBackoff: 2 * time.Millisecond, ProbeTimeout: time.Second}
+}
+
+// failing returns a probe that fails the first n calls.
+func failing(n int32) func(context.Context) error {
+	var calls atomic.Int32
+	return func(context.Context) error {
+		if calls.Add(1) <= n {
+			return errors.New("connection refused")
+		}
+		return nil
+	}
+}
+
+func TestRunRetriesUntilAvailable(t *testing.T) {
+	o := New(fastConfig(5),
+		Dependency{Name: "postgres", Probe: failing(3)},
+		Dependency{Name: "redis", Probe: failing(0)},
+	)
+	if err := o.Run(context.Background()); err != nil {
+		t.Fatalf("Run = %v", err)
+	}
+	st := o.Status()
+	if st[0].State != StateUp || st[0].Attempts != 4 || st[0].LastError != "" {
+		t.Errorf("postgres = %+v", st[0])
+	}
+	if st[1].State != StateUp || st[1].Attempts != 1 {
+		t.Errorf("redis = %+v", st[1])
+	}
+}
+
+func TestRunBlockedByRequiredDependency(t *testing.T) {
+	o := New(fastConfig(3),
+		Dependency{Name: "postgres", Probe: failing(0)},
+		Dependency{Name: "mongo", Probe: failing(10)},
+	)
+	err := o.Run(context.Background())
+	var blocked *BlockedError
+	if !errors.As(err, &blocked) {
+		t.Fatalf("Run = %v, want *BlockedError", err)
+	}
+	if blocked.Dependency != "mongo" || blocked.Attempts != 3 {
+		t.Errorf("blocked = %+v", blocked)
+	}
+	if st := o.Status()[1]; st.State != StateFailed || st.LastError != "connection refused" {
+		t.Errorf("mongo = %+v", st)
+	}
+}
+
+func TestRunOptionalDependencyDegrades(t *testing.T) {
+	o := New(fastConfig(2),
+		Dependency{Name: "postgres", Probe: failing(0)},
+		Dependency{Name: "notify-webhook", Optional: true, Probe: failing(10)},
+	)
+	if err := o.Run(context.Background()); err != nil {
+		t.Fatalf("Run = %v", err)
+	}
+	if got := o.Degraded(); len(got) != 1 || got[0] != "notify-webhook" {
+		t.Errorf("Degraded = %v", got)
+	}
+}
+
+func TestRunPermanentErrorStopsRetrying(t *testing.T) {
+	var calls atomic.Int32
+	o := New(fastConfig(5), Dependency{Name: "redis", Probe: func(context.Context) error {
+		calls.Add(1)
+		return Permanent(errors.New("parse redis url: invalid scheme"))
+	}})
+	var blocked *BlockedError
+	if err := o.Run(context.Background()); !errors.As(err, &blocked) || blocked.Attempts != 1 {
+		t.Fatalf("Run = %v, want blocked after 1 attempt", err)
+	}
+	if calls.Load() != 1 {
+		t.Errorf("probe called %d times", calls.Load())
+	}
+}
+
+func TestRunCancelled(t *testing.T) {
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+	o := New(Config{Attempts: 5, InitialBackoff: time.Hour}, Dependency{Name: "redis", Probe: failing(10)})
+	if err := o.Run(ctx); !errors.Is(err, context.Canceled) {
+		t.Fatalf("Run = %v, want context.Canceled", err)
+	}
+}
+
+func TestBackoff(t *testing.T) {
+	c := Config{InitialBackoff: 500 * time.Millisecond, MaxBackoff: 3 * time.Second}
+	want := []time.Duration{500 * time.Millisecond, time.Second, 2 * time.Second, 3 * time.Second, 3 * time.Second}
+	for i, w := range want {
+		if got := c.backoff(i + 1); got != w {
+			t.Errorf("backoff(%d) = %s, want %s", i+1, got, w)
+		}
+	}
+}
@@ -0,0 +1,451 @@
+package stream
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"math"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// AnomalyDetector watches ingestion and delivery rates (migration 058).
+// Once per Config.Window it counts each event type's events and each
+// subscription's delivery attempts and failures over the window just
+// closed, scores them against the entity's exponentially weighted mean
+// and variance (msg_anomaly_baselines), and raises an anomaly in
+// msg_anomalies when:
+//
+//   - an event type that normally receives at least Config.MinEvents per
+//     window receives none (INGESTION_DROP), or
+//   - a subscription's failure rate is at least 10 points above its norm
+//     over Config.MinAttempts or more attempts (FAILURE_SPIKE),
+//
+// and the z-score reaches the entity's sensitivity (msg_anomaly_settings,
+// default anomaly.DefaultSensitivity). An open anomaly is resolved by the
+// first window that is no longer anomalous.
+//
+// An anomalous window is kept out of the baseline, so an outage does not
+// become the norm, until the anomaly has been open for Config.Relearn;
+// after that the level is taken as the new normal. A disabled or snoozed
+// entity raises nothing and learns every window.
+type AnomalyDetector struct {
+	pool   *pgxpool.Pool
+	Health *Health
+
+	Config AnomalyConfig
+	// IsLeader gates each pass; nil means always-leader (single instance).
+	IsLeader func() bool
+
+	// last is the end of the last window evaluated by this replica.
+	last time.Time
+}
+
+// AnomalyConfig tunes the detector.
+type AnomalyConfig struct {
+	TickInterval time.Duration // how often to check for a closed window (default 1m)
+	Window       time.Duration // the measured interval (default 15m)
+	Alpha        float64       // EWMA smoothing factor (default 0.1)
+	Warmup       int           // windows learned before an entity can raise (default 12)
+	MinEvents    float64       // mean events per window for an event type to count as busy (default 10)
+	MinAttempts  int64         // attempts in a window for a failure rate to count (default 20)
+	Relearn      time.Duration // open this long, an anomaly's level is learned (default 24h)
+	Retention    time.Duration // resolved anomalies kept (default 30d)
+}
+
+// DefaultAnomalyConfig returns the defaults.
+func DefaultAnomalyConfig() AnomalyConfig {
+	return AnomalyConfig{
+		TickInterval: time.Minute,
+		Window:       15 * time.Minute,
+		Alpha:        0.1,
+		Warmup:       12,
+		MinEvents:    10,
+		MinAttempts:  20,
+		Relearn:      24 * time.Hour,
+		Retention:    30 * 24 * time.Hour,
+	}
+}
+
+// NewAnomalyDetector wires a detector with default config + always-leader.
+func NewAnomalyDetector(pool *pgxpool.Pool) *AnomalyDetector {
+	return &AnomalyDetector{pool: pool}
+}
+
+// anomalyGrace delays evaluating a window so rows committed just after it
+// closed are counted.
+const anomalyGrace = time.Minute
+
+// Failure-rate scoring: the standard deviation never counts as less than
+// failureRateSDFloor, and a spike must also clear minFailureRateIncrease,
+// so a subscription that never fails does not raise on one bad window.
+const (
+	failureRateSDFloor     = 0.05
+	minFailureRateIncrease = 0.1
+)
+
+func (d *AnomalyDetector) cfg() AnomalyConfig {
+	c := d.Config
+	def := DefaultAnomalyConfig()
+	if c.TickInterval <= 0 {
+		c.TickInterval = def.TickInterval
+	}
+	if c.Window <= 0 {
+		c.Window = def.Window
+	}
+	if c.Alpha <= 0 || c.Alpha > 1 {
+		c.Alpha = def.Alpha
+	}
+	if c.Warmup <= 0 {
+		c.Warmup = def.Warmup
+	}
+	if c.MinEvents <= 0 {
+		c.MinEvents = def.MinEvents
+	}
+	if c.MinAttempts <= 0 {
+		c.MinAttempts = def.MinAttempts
+	}
+	if c.Relearn <= 0 {
+		c.Relearn = def.Relearn
+	}
+	if c.Retention <= 0 {
+		c.Retention = def.Retention
+	}
+	return c
+}
+
+func (d *AnomalyDetector) leader() bool {
+	if d.IsLeader == nil {
+		return true
+	}
+	return d.IsLeader()
+}
+
+// Run ticks once on startup, then every Config.TickInterval, until ctx is
+// cancelled.
+func (d *AnomalyDetector) Run(ctx context.Context) {
+	cfg := d.cfg()
+	if d.Health != nil {
+		d.Health.SetRunning(true)
+		defer d.Health.SetRunning(false)
+	}
+	d.runPass(ctx)
+
+	tick := time.NewTicker(cfg.TickInterval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("anomaly detector stopped")
+			return
+		case <-tick.C:
+			d.runPass(ctx)
+		}
+	}
+}
+
+func (d *AnomalyDetector) runPass(ctx context.Context) {
+	if !d.leader() {
+		return
+	}
+	cfg := d.cfg()
+	end := time.Now().UTC().Add(-anomalyGrace).Truncate(cfg.Window)
+	if !end.After(d.last) {
+		return
+	}
+	n, err := d.Pass(ctx, end)
+	if err != nil {
+		slog.Warn("anomaly detector pass failed", "err", err)
+		if d.Health != nil {
+			d.Health.RecordError()
+		}
+		return
+	}
+	d.last = end
+	if d.Health != nil {
+		d.Health.AddProcessed(uint64(n))
+	}
+	if _, err := d.pool.Exec(ctx, `DELETE FROM msg_anomalies WHERE status = 'RESOLVED' AND resolved_at < $1`,
+		end.Add(-cfg.Retention)); err != nil {
+		slog.Warn("anomaly detector: prune failed", "err", err)
+	}
+}
+
+// anomalyEntity is one event type or subscription going into a window.
+type anomalyEntity struct {
+	kind     anomaly.EntityKind
+	key      string
+	baseline anomaly.Baseline
+	setting  *anomaly.Setting
+	open     *openAnomaly
+
+	// observed is false for a subscription without attempts in the
+	// window: it has no failure rate to learn.
+	observed bool
+	value    float64 // events, or the failure rate
+	volume   int64   // events, or attempts
+}
+
+type openAnomaly struct {
+	id       string
+	raisedAt time.Time
+}
+
+// anomalyOutcome is what a window does to an entity.
+type anomalyOutcome struct {
+	z         float64
+	anomalous bool
+	learn     bool
+	raise     bool // open a new anomaly
+	refresh   bool // update the open one
+	resolve   bool // resolve the open one
+}
+
+// evaluate scores e's window against its baseline at now.
+func (c AnomalyConfig) evaluate(e *anomalyEntity, now time.Time) anomalyOutcome {
+	var out anomalyOutcome
+	b := e.baseline
+	threshold := e.setting.Threshold()
+	warm := b.Samples >= c.Warmup
+	switch e.kind {
+	case anomaly.EntityEventType:
+		// Counts are roughly Poisson: the standard deviation is at least
+		// the square root of the mean.
+		out.z = b.ZScore(e.value, math.Max(math.Sqrt(b.Mean), 1))
+		out.anomalous = warm && b.Mean >= c.MinEvents && e.value == 0 && out.z <= -threshold
+	case anomaly.EntitySubscription:
+		out.z = b.ZScore(e.value, failureRateSDFloor)
+		out.anomalous = warm && e.volume >= c.MinAttempts &&
+			e.value-b.Mean >= minFailureRateIncrease && out.z >= threshold
+	}
+
+	suppressed := e.setting.Suppressed(now)
+	switch {
+	case out.anomalous && !suppressed && e.open == nil:
+		out.raise = true
+	case out.anomalous && !suppressed:
+		out.refresh = true
+	case e.open != nil:
+		out.resolve = true
+	}
+	out.learn = e.observed &&
+		(!out.anomalous || suppressed || (e.open != nil && now.Sub(e.open.raisedAt) >= c.Relearn))
+	return out
+}
+
+// Pass evaluates the window ending at end and returns the entities
+// evaluated. An entity whose baseline already covers end is skipped, so
+// re-running a window (or a new leader doing so) changes nothing. Exposed
+// for tests.
+func (d *AnomalyDetector) Pass(ctx context.Context, end time.Time) (int, error) {
+	cfg := d.cfg()
+	start := end.Add(-cfg.Window)
+	entities, err := d.load(ctx, start, end)
+	if err != nil {
+		return 0, err
+	}
+
+	batch := &pgx.Batch{}
+	for _, e := range entities {
+		out := cfg.evaluate(e, end)
+		if out.learn {
+			b := e.baseline.Update(e.value, cfg.Alpha)
+			batch.Queue(`
+INSERT INTO msg_anomaly_baselines (entity_kind, entity_key, mean, variance, samples, window_end, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, NOW())
+ON CONFLICT (entity_kind, entity_key) DO UPDATE SET
+    mean = EXCLUDED.mean, variance = EXCLUDED.variance, samples = EXCLUDED.samples,
+    window_end = EXCLUDED.window_end, updated_at = EXCLUDED.updated_at`,
+				string(e.kind), e.key, b.Mean, b.Variance, b.Samples, end)
+		} else if e.observed {
+			batch.Queue(`UPDATE msg_anomaly_baselines SET window_end = $3, updated_at = NOW()
+WHERE entity_kind = $1 AND entity_key = $2`, string(e.kind), e.key, end)
+		}
+		switch {
+		case out.raise:
+			kind, msg := anomalyMessage(e, cfg.Window)
+			slog.Warn("anomaly raised", "entityKind", e.kind, "entityKey", e.key, "kind", kind,
+				"observed", e.value, "expected", e.baseline.Mean, "z", out.z)
+			batch.Queue(`
+INSERT INTO msg_anomalies (id, entity_kind, entity_key, kind, status, message, observed, expected, z_score,
+                           raised_at, last_seen_at)
+VALUES ($1, $2, $3, $4, 'OPEN', $5, $6, $7, $8, $9, $9)
+ON CONFLICT (entity_kind, entity_key) WHERE status = 'OPEN' DO NOTHING`,
+				tsid.Generate(tsid.Anomaly), string(e.kind), e.key, string(kind), msg,
+				e.value, e.baseline.Mean, out.z, end)
+		case out.refresh:
+			batch.Queue(`UPDATE msg_anomalies SET observed = $2, z_score = $3, last_seen_at = $4 WHERE id = $1`,
+				e.open.id, e.value, out.z, end)
+		case out.resolve:
+			slog.Info("anomaly resolved", "entityKind", e.kind, "entityKey", e.key, "anomalyId", e.open.id)
+			batch.Queue(`UPDATE msg_anomalies SET status = 'RESOLVED', resolved_at = $2 WHERE id = $1`,
+				e.open.id, end)
+		}
+	}
+	if batch.Len() > 0 {
+		if err := d.pool.SendBatch(ctx, batch).Close(); err != nil {
+			return 0, fmt.Errorf("write anomaly state: %w", err)
+		}
+	}
+	return len(entities), nil
+}
+
+// load gathers every entity with something to say about [start, end):
+// event types ingested in it or with a baseline, and subscriptions with
+// attempts in it or an open anomaly.
+func (d *AnomalyDetector) load(ctx context.Context, start, end time.Time) ([]*anomalyEntity, error) {
+	type entityKey struct {
+		kind anomaly.EntityKind
+		key  string
+	}
+	byKey := map[entityKey]*anomalyEntity{}
+	entity := func(kind, key string) *anomalyEntity {
+		k := entityKey{anomaly.EntityKind(kind), key}
+		e, ok := byKey[k]
+		if !ok {
+			e = &anomalyEntity{kind: k.kind, key: key, observed: k.kind == anomaly.EntityEventType}
+			byKey[k] = e
+		}
+		return e
+	}
+	type storedBaseline struct {
+		anomaly.Baseline
+		windowEnd time.Time
+	}
+	baselines := map[entityKey]storedBaseline{}
+
+	err := d.scan(ctx, "load baselines",
+		`SELECT entity_kind, entity_key, mean, variance, samples, window_end FROM msg_anomaly_baselines`, nil,
+		func(rows pgx.Rows) error {
+			var kind, key string
+			var b storedBaseline
+			if err := rows.Scan(&kind, &key, &b.Mean, &b.Variance, &b.Samples, &b.windowEnd); err != nil {
+				return err
+			}
+			baselines[entityKey{anomaly.EntityKind(kind), key}] = b
+			if anomaly.EntityKind(kind) == anomaly.EntityEventType {
+				entity(kind, key)
+			}
+			return nil
+		})
+	if err != nil {
+		return nil, err
+	}
+
+	err = d.scan(ctx, "count events",
+		`SELECT type, count(*) FROM msg_events WHERE created_at >= $1 AND created_at < $2 GROUP BY type`,
+		[]any{start, end},
+		func(rows pgx.Rows) error {
+			var key string
+			var n int64
+			if err := rows.Scan(&key, &n); err != nil {
+				return err
+			}
+			e := entity(string(anomaly.EntityEventType), key)
+			e.value, e.volume = float64(n), n
+			return nil
+		})
+	if err != nil {
+		return nil, err
+	}
+
+	err = d.scan(ctx, "count attempts", `
+SELECT j.subscription_id, count(*), count(*) FILTER (WHERE a.status IS DISTINCT FROM 'SUCCESS')
+  FROM msg_dispatch_job_attempts a
+  JOIN msg_dispatch_jobs j ON j.id = a.dispatch_job_id
+ WHERE a.created_at >= $1 AND a.created_at < $2
+   AND j.subscription_id IS NOT NULL
+ GROUP BY 1`, []any{start, end},
+		func(rows pgx.Rows) error {
+			var key string
+			var attempts, failures int64
+			if err := rows.Scan(&key, &attempts, &failures); err != nil {
+				return err
+			}
+			e := entity(string(anomaly.EntitySubscription), key)
+			e.observed = true
+			e.value, e.volume = float64(failures)/float64(attempts), attempts
+			return nil
+		})
+	if err != nil {
+		return nil, err
+	}
+
+	err = d.scan(ctx, "load open anomalies",
+		`SELECT id, entity_kind, entity_key, raised_at FROM msg_anomalies WHERE status = 'OPEN'`, nil,
+		func(rows pgx.Rows) error {
+			var o openAnomaly
+			var kind, key string
+			if err := rows.Scan(&o.id, &kind, &key, &o.raisedAt); err != nil {
+				return err
+			}
+			entity(kind, key).open = &o
+			return nil
+		})
+	if err != nil {
+		return nil, err
+	}
+
+	err = d.scan(ctx, "load settings",
+		`SELECT entity_kind, entity_key, sensitivity, enabled, snoozed_until FROM msg_anomaly_settings`, nil,
+		func(rows pgx.Rows) error {
+			var kind, key string
+			s := &anomaly.Setting{}
+			if err := rows.Scan(&kind, &key, &s.Sensitivity, &s.Enabled, &s.SnoozedUntil); err != nil {
+				return err
+			}
+			if e, ok := byKey[entityKey{anomaly.EntityKind(kind), key}]; ok {
+				s.EntityKind, s.EntityKey = e.kind, key
+				e.setting = s
+			}
+			return nil
+		})
+	if err != nil {
+		return nil, err
+	}
+
+	out := make([]*anomalyEntity, 0, len(byKey))
+	for k, e := range byKey {
+		if b, ok := baselines[k]; ok {
+			if !b.windowEnd.Before(end) {
+				continue
+			}
+			e.baseline = b.Baseline
+		}
+		out = append(out, e)
+	}
+	return out, nil
+}
+
+// scan runs query and hands each row to fn, labelling errors with what.
+func (d *AnomalyDetector) scan(ctx context.Context, what, query string, args []any, fn func(pgx.Rows) error) error {
+	rows, err := d.pool.Query(ctx, query, args...)
+	if err != nil {
+		return fmt.Errorf("%s: %w", what, err)
+	}
+	defer rows.Close()
+	for rows.Next() {
+		if err := fn(rows); err != nil {
+			return fmt.Errorf("%s: %w", what, err)
+		}
+	}
+	if err := rows.Err(); err != nil {
+		return fmt.Errorf("%s: %w", what, err)
+	}
+	return nil
+}
+
+// anomalyMessage describes a raised anomaly.
+func anomalyMessage(e *anomalyEntity, window time.Duration) (anomaly.Kind, string) {
+	if e.kind == anomaly.EntityEventType {
+		return anomaly.KindIngestionDrop, fmt.Sprintf("no %s events in %s; usually about %.0f",
+			e.key, window, e.baseline.Mean)
+	}
+	return anomaly.KindFailureSpike, fmt.Sprintf("%.0f%% of %d delivery attempts for subscription %s failed in %s; usually %.0f%%",
+		e.value*100, e.volume, e.key, window, e.baseline.Mean*100)
+}
@@ -0,0 +1,69 @@
+//go:build integration
+
+package stream
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// TestAnomalyDetectorIngestionDrop pins a pass end to end: a busy event
+// type going silent raises one anomaly, re-running the window changes
+// nothing, and snoozing the type resolves it.
+func TestAnomalyDetectorIngestionDrop(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+	const eventType = "test:stream:anomaly:silent"
+
+	det := NewAnomalyDetector(pool)
+	window := det.cfg().Window
+	end := time.Now().UTC().Truncate(window)
+	_, err := pool.Exec(ctx,
+		`INSERT INTO msg_anomaly_baselines (entity_kind, entity_key, mean, variance, samples, window_end)
+		 VALUES ('EVENT_TYPE', $1, 40, 16, 50, $2)`, eventType, end.Add(-window))
+	require.NoError(t, err)
+
+	latest := func() (id, kind, status string) {
+		err := pool.QueryRow(ctx,
+			`SELECT id, kind, status FROM msg_anomalies WHERE entity_key = $1 ORDER BY raised_at DESC LIMIT 1`,
+			eventType).Scan(&id, &kind, &status)
+		require.NoError(t, err)
+		return id, kind, status
+	}
+
+	_, err = det.Pass(ctx, end)
+	require.NoError(t, err)
+	id, kind, status := latest()
+	assert.Equal(t, "INGESTION_DROP", kind)
+	assert.Equal(t, "OPEN", status)
+
+	var mean float64
+	require.NoError(t, pool.QueryRow(ctx,
+		`SELECT mean FROM msg_anomaly_baselines WHERE entity_key = $1`, eventType).Scan(&mean))
+	assert.Equal(t, 40.0, mean, "the silent window is not learned")
+
+	_, err = det.Pass(ctx, end)
+	require.NoError(t, err)
+	var n int
+	require.NoError(t, pool.QueryRow(ctx,
+		`SELECT count(*) FROM msg_anomalies WHERE entity_key = $1`, eventType).Scan(&n))
+	assert.Equal(t, 1, n, "a re-run window raises nothing new")
+
+	_, err = pool.Exec(ctx,
+		`INSERT INTO msg_anomaly_settings (id, entity_kind, entity_key, enabled, snoozed_until)
+		 VALUES ($1, 'EVENT_TYPE', $2, TRUE, $3)`,
+		tsid.Generate(tsid.AnomalySetting), eventType, end.Add(24*time.Hour))
+	require.NoError(t, err)
+	_, err = det.Pass(ctx, end.Add(window))
+	require.NoError(t, err)
+	gotID, _, status := latest()
+	assert.Equal(t, id, gotID)
+	assert.Equal(t, "RESOLVED", status, "snoozing resolves the anomaly")
+}
@@ -0,0 +1,98 @@
+package stream
+
+import (
+	"math"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly"
+)
+
+func TestAnomalyEvaluateIngestionDrop(t *testing.T) {
+	cfg := DefaultAnomalyConfig()
+	now := time.Now().UTC()
+	busy := anomaly.Baseline{Mean: 40, Variance: 16, Samples: 50}
+
+	silent := &anomalyEntity{kind: anomaly.EntityEventType, key: "a:b:c", baseline: busy, observed: true}
+	out := cfg.evaluate(silent, now)
+	assert.True(t, out.anomalous)
+	assert.True(t, out.raise)
+	assert.False(t, out.learn, "an anomalous window is kept out of the baseline")
+	assert.InDelta(t, -math.Sqrt(40), out.z, 1e-9, "the Poisson floor, sqrt(40), beats the learned spread")
+
+	ok := &anomalyEntity{kind: anomaly.EntityEventType, key: "a:b:c", baseline: busy, observed: true, value: 35, volume: 35}
+	out = cfg.evaluate(ok, now)
+	assert.False(t, out.anomalous)
+	assert.True(t, out.learn)
+
+	quiet := &anomalyEntity{kind: anomaly.EntityEventType, key: "a:b:c", observed: true,
+		baseline: anomaly.Baseline{Mean: 4, Variance: 1, Samples: 50}}
+	assert.False(t, cfg.evaluate(quiet, now).anomalous, "below MinEvents a silent window is normal")
+
+	cold := &anomalyEntity{kind: anomaly.EntityEventType, key: "a:b:c", observed: true,
+		baseline: anomaly.Baseline{Mean: 40, Variance: 16, Samples: 3}}
+	assert.False(t, cfg.evaluate(cold, now).anomalous, "nothing raises during warmup")
+}
+
+func TestAnomalyEvaluateFailureSpike(t *testing.T) {
+	cfg := DefaultAnomalyConfig()
+	now := time.Now().UTC()
+	healthy := anomaly.Baseline{Mean: 0.02, Variance: 0.0001, Samples: 50}
+	sub := func(rate float64, attempts int64) *anomalyEntity {
+		return &anomalyEntity{kind: anomaly.EntitySubscription, key: "sub_x", baseline: healthy,
+			observed: true, value: rate, volume: attempts}
+	}
+
+	out := cfg.evaluate(sub(0.5, 100), now)
+	assert.True(t, out.anomalous)
+	assert.InDelta(t, (0.5-0.02)/failureRateSDFloor, out.z, 1e-9)
+
+	assert.False(t, cfg.evaluate(sub(0.5, 5), now).anomalous, "too few attempts")
+	assert.False(t, cfg.evaluate(sub(0.1, 100), now).anomalous, "under the minimum increase")
+
+	seven := 7.0
+	e := sub(0.3, 100)
+	assert.True(t, cfg.evaluate(e, now).anomalous, "z of 5.6 clears the default 3")
+	e.setting = &anomaly.Setting{Enabled: true, Sensitivity: &seven}
+	assert.False(t, cfg.evaluate(e, now).anomalous, "but not a sensitivity of 7")
+}
+
+func TestAnomalyEvaluateLifecycle(t *testing.T) {
+	cfg := DefaultAnomalyConfig()
+	now := time.Now().UTC()
+	busy := anomaly.Baseline{Mean: 40, Variance: 16, Samples: 50}
+	open := &openAnomaly{id: "anm_1", raisedAt: now.Add(-time.Hour)}
+
+	still := &anomalyEntity{kind: anomaly.EntityEventType, baseline: busy, observed: true, open: open}
+	out := cfg.evaluate(still, now)
+	assert.True(t, out.refresh)
+	assert.False(t, out.raise)
+	assert.False(t, out.learn)
+
+	recovered := &anomalyEntity{kind: anomaly.EntityEventType, baseline: busy, observed: true, open: open, value: 40}
+	out = cfg.evaluate(recovered, now)
+	assert.True(t, out.resolve)
+	assert.True(t, out.learn)
+
+	old := &anomalyEntity{kind: anomaly.EntityEventType, baseline: busy, observed: true,
+		open: &openAnomaly{id: "anm_2", raisedAt: now.Add(-cfg.Relearn)}}
+	out = cfg.evaluate(old, now)
+	assert.True(t, out.refresh)
+	assert.True(t, out.learn, "after Relearn the level is learned")
+
+	until := now.Add(time.Hour)
+	snoozed := &anomalyEntity{kind: anomaly.EntityEventType, baseline: busy, observed: true, open: open,
+		setting: &anomaly.Setting{Enabled: true, SnoozedUntil: &until}}
+	out = cfg.evaluate(snoozed, now)
+	assert.True(t, out.anomalous)
+	assert.True(t, out.resolve, "snoozing resolves the open anomaly")
+	assert.True(t, out.learn, "a snoozed entity learns every window")
+
+	idle := &anomalyEntity{kind: anomaly.EntitySubscription, open: open,
+		baseline: anomaly.Baseline{Mean: 0.02, Variance: 0.0001, Samples: 50}}
+	out = cfg.evaluate(idle, now)
+	assert.True(t, out.resolve, "no attempts, nothing failing")
+	assert.False(t, out.learn, "and no rate to learn")
+}
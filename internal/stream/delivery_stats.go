@@ -0,0 +1,322 @@
+package stream
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"strings"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// DeliveryStatsRollup folds msg_dispatch_job_attempts into hourly
+// per-subscription rows in msg_subscription_delivery_stats (migration 057),
+// which the subscription analytics screen charts.
+//
+// Each pass recomputes every hour from the one the previous pass started
+// in (less a minute of overlap for late-committing attempts) up to now, so
+// the current hour is refreshed on every pass and a finished hour is
+// settled by the pass after it ends. The first pass after startup resumes
+// from the newest stored hour, or backfills Config.Backfill into an empty
+// table. Rows older than Config.Retention are deleted.
+type DeliveryStatsRollup struct {
+	pool   *pgxpool.Pool
+	Health *Health
+
+	Config DeliveryStatsConfig
+	// IsLeader gates each pass; nil means always-leader (single instance).
+	IsLeader func() bool
+
+	// since is the lower bound on attempt created_at for the next pass;
+	// the zero value resumes from the table.
+	since time.Time
+}
+
+// DeliveryStatsConfig tunes the rollup.
+type DeliveryStatsConfig struct {
+	TickInterval time.Duration // pass cadence (default 1m)
+	Backfill     time.Duration // first pass into an empty table (default 7d)
+	Retention    time.Duration // rows kept (default 30d)
+	Window       time.Duration // attempts aggregated per statement (default 24h)
+}
+
+// DefaultDeliveryStatsConfig returns the defaults.
+func DefaultDeliveryStatsConfig() DeliveryStatsConfig {
+	return DeliveryStatsConfig{
+		TickInterval: time.Minute,
+		Backfill:     7 * 24 * time.Hour,
+		Retention:    30 * 24 * time.Hour,
+		Window:       24 * time.Hour,
+	}
+}
+
+// NewDeliveryStatsRollup wires a rollup with default config + always-leader.
+func NewDeliveryStatsRollup(pool *pgxpool.Pool) *DeliveryStatsRollup {
+	return &DeliveryStatsRollup{pool: pool}
+}
+
+// deliveryStatsOverlap is subtracted from each pass's start to form the
+// next pass's lower bound, so an attempt committed just after a pass read
+// its hour is not missed.
+const deliveryStatsOverlap = time.Minute
+
+// LatencyBucketsMs are the upper bounds, in milliseconds, of the stored
+// latency histogram's buckets; a final open-ended bucket follows the last.
+var LatencyBucketsMs = []int64{50, 100, 250, 500, 1000, 2500, 5000, 10000, 30000}
+
+// RetrySlots is the length of the stored retry histogram: slot i counts
+// attempts numbered i+1, the last slot that number and above.
+const RetrySlots = 10
+
+func (s *DeliveryStatsRollup) cfg() DeliveryStatsConfig {
+	c := s.Config
+	d := DefaultDeliveryStatsConfig()
+	if c.TickInterval <= 0 {
+		c.TickInterval = d.TickInterval
+	}
+	if c.Backfill <= 0 {
+		c.Backfill = d.Backfill
+	}
+	if c.Retention <= 0 {
+		c.Retention = d.Retention
+	}
+	if c.Window <= 0 {
+		c.Window = d.Window
+	}
+	return c
+}
+
+func (s *DeliveryStatsRollup) leader() bool {
+	if s.IsLeader == nil {
+		return true
+	}
+	return s.IsLeader()
+}
+
+// Run ticks once on startup, then every Config.TickInterval, until ctx is
+// cancelled.
+func (s *DeliveryStatsRollup) Run(ctx context.Context) {
+	cfg := s.cfg()
+	if s.Health != nil {
+		s.Health.SetRunning(true)
+		defer s.Health.SetRunning(false)
+	}
+	s.runPass(ctx)
+
+	tick := time.NewTicker(cfg.TickInterval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("delivery stats rollup stopped")
+			return
+		case <-tick.C:
+			s.runPass(ctx)
+		}
+	}
+}
+
+func (s *DeliveryStatsRollup) runPass(ctx context.Context) {
+	if !s.leader() {
+		return
+	}
+	started := time.Now().UTC()
+	from := s.since
+	if from.IsZero() {
+		var err error
+		if from, err = s.resumeFrom(ctx, started); err != nil {
+			slog.Warn("delivery stats rollup: load resume point failed", "err", err)
+			if s.Health != nil {
+				s.Health.RecordError()
+			}
+			return
+		}
+	}
+	n, err := s.Pass(ctx, from, started)
+	if err != nil {
+		slog.Warn("delivery stats rollup pass failed", "err", err)
+		if s.Health != nil {
+			s.Health.RecordError()
+		}
+		return
+	}
+	s.since = started.Add(-deliveryStatsOverlap)
+	if s.Health != nil {
+		s.Health.AddProcessed(uint64(n))
+	}
+	if _, err := s.pool.Exec(ctx, `DELETE FROM msg_subscription_delivery_stats WHERE bucket_start < $1`,
+		started.Add(-s.cfg().Retention)); err != nil {
+		slog.Warn("delivery stats rollup: prune failed", "err", err)
+	}
+}
+
+// resumeFrom is the first pass's lower bound: the newest stored hour, or
+// Config.Backfill ago when nothing is stored yet.
+func (s *DeliveryStatsRollup) resumeFrom(ctx context.Context, now time.Time) (time.Time, error) {
+	var newest *time.Time
+	if err := s.pool.QueryRow(ctx, `SELECT max(bucket_start) FROM msg_subscription_delivery_stats`).Scan(&newest); err != nil {
+		return time.Time{}, err
+	}
+	if newest == nil {
+		return now.Add(-s.cfg().Backfill), nil
+	}
+	return *newest, nil
+}
+
+// Pass recomputes the hours from from's hour up to to, Config.Window of
+// attempts per statement, and returns the rows written. Exposed for tests
+// and one-off backfills.
+func (s *DeliveryStatsRollup) Pass(ctx context.Context, from, to time.Time) (int64, error) {
+	window := s.cfg().Window
+	var total int64
+	for start := from.UTC().Truncate(time.Hour); start.Before(to); start = start.Add(window) {
+		end := start.Add(window)
+		if end.After(to) {
+			end = to
+		}
+		tag, err := s.pool.Exec(ctx, rollupDeliveryStatsSQL, start, end)
+		if err != nil {
+			return total, fmt.Errorf("roll up %s: %w", start.Format(time.RFC3339), err)
+		}
+		total += tag.RowsAffected()
+	}
+	return total, nil
+}
+
+// DeliveryStatsBucket is one stored hour of a subscription's deliveries.
+type DeliveryStatsBucket struct {
+	Start           time.Time
+	Attempts        int64
+	Successes       int64
+	Failures        int64
+	DurationMsTotal int64
+	// LatencyHistogram is indexed like LatencyBucketsMs plus the open
+	// bucket; RetryHistogram has RetrySlots slots.
+	LatencyHistogram []int64
+	RetryHistogram   []int64
+	// Errors counts failures by response code, or by error type when the
+	// attempt got no response.
+	Errors map[string]int64
+}
+
+// LoadDeliveryStats returns a subscription's stored hours from from's hour
+// on, oldest first.
+func LoadDeliveryStats(ctx context.Context, pool *pgxpool.Pool, subscriptionID string, from time.Time) ([]DeliveryStatsBucket, error) {
+	rows, err := pool.Query(ctx, `
+SELECT bucket_start, attempts, successes, failures, duration_ms_total,
+       latency_histogram, retry_histogram, errors
+  FROM msg_subscription_delivery_stats
+ WHERE subscription_id = $1 AND bucket_start >= $2
+ ORDER BY bucket_start`, subscriptionID, from.UTC().Truncate(time.Hour))
+	if err != nil {
+		return nil, err
+	}
+	return pgx.CollectRows(rows, func(row pgx.CollectableRow) (DeliveryStatsBucket, error) {
+		var b DeliveryStatsBucket
+		err := row.Scan(&b.Start, &b.Attempts, &b.Successes, &b.Failures, &b.DurationMsTotal,
+			&b.LatencyHistogram, &b.RetryHistogram, &b.Errors)
+		return b, err
+	})
+}
+
+// LatencyPercentile estimates the p-th percentile (0 < p ≤ 1), in
+// milliseconds, from a latency histogram, interpolating linearly within
+// the bucket the rank falls in. The open-ended bucket reports its lower
+// bound. Nil when the histogram is empty.
+func LatencyPercentile(hist []int64, p float64) *int64 {
+	var total int64
+	for _, n := range hist {
+		total += n
+	}
+	if total == 0 {
+		return nil
+	}
+	rank := p * float64(total)
+	var seen int64
+	for i, n := range hist {
+		if n == 0 || float64(seen+n) < rank {
+			seen += n
+			continue
+		}
+		var lo int64
+		if i > 0 {
+			lo = LatencyBucketsMs[i-1]
+		}
+		if i >= len(LatencyBucketsMs) {
+			return &lo
+		}
+		v := lo + int64((rank-float64(seen))/float64(n)*float64(LatencyBucketsMs[i]-lo))
+		return &v
+	}
+	last := LatencyBucketsMs[len(LatencyBucketsMs)-1]
+	return &last
+}
+
+// rollupDeliveryStatsSQL recomputes the hours holding attempts created in
+// [$1, $2). $1 is hour-aligned, so every hour it touches is recomputed
+// from all of its attempts.
+var rollupDeliveryStatsSQL = fmt.Sprintf(`
+WITH a AS (
+    SELECT j.subscription_id,
+           date_trunc('hour', a.created_at) AS bucket_start,
+           a.status, a.response_code, a.error_type, a.duration_millis, a.attempt_number
+      FROM msg_dispatch_job_attempts a
+      JOIN msg_dispatch_jobs j ON j.id = a.dispatch_job_id
+     WHERE a.created_at >= $1 AND a.created_at < $2
+       AND j.subscription_id IS NOT NULL
+), e AS (
+    SELECT subscription_id, bucket_start, jsonb_object_agg(error_key, n) AS errors
+      FROM (SELECT subscription_id, bucket_start,
+                   COALESCE(response_code::text, error_type, 'UNKNOWN') AS error_key, count(*) AS n
+              FROM a
+             WHERE status IS DISTINCT FROM 'SUCCESS'
+             GROUP BY 1, 2, 3) k
+     GROUP BY 1, 2
+)
+INSERT INTO msg_subscription_delivery_stats
+    (subscription_id, bucket_start, attempts, successes, failures, duration_ms_total,
+     latency_histogram, retry_histogram, errors, updated_at)
+SELECT a.subscription_id, a.bucket_start, count(*),
+       count(*) FILTER (WHERE a.status = 'SUCCESS'),
+       count(*) FILTER (WHERE a.status IS DISTINCT FROM 'SUCCESS'),
+       COALESCE(sum(a.duration_millis), 0),
+       ARRAY[%s]::BIGINT[],
+       ARRAY[%s]::BIGINT[],
+       COALESCE(e.errors, '{}'), NOW()
+  FROM a LEFT JOIN e USING (subscription_id, bucket_start)
+ GROUP BY a.subscription_id, a.bucket_start, e.errors
+ON CONFLICT (subscription_id, bucket_start) DO UPDATE SET
+    attempts          = EXCLUDED.attempts,
+    successes         = EXCLUDED.successes,
+    failures          = EXCLUDED.failures,
+    duration_ms_total = EXCLUDED.duration_ms_total,
+    latency_histogram = EXCLUDED.latency_histogram,
+    retry_histogram   = EXCLUDED.retry_histogram,
+    errors            = EXCLUDED.errors,
+    updated_at        = EXCLUDED.updated_at`, latencyBucketColumns(), retrySlotColumns())
+
+// latencyBucketColumns counts attempts per LatencyBucketsMs bucket; an
+// attempt without a duration is in none.
+func latencyBucketColumns() string {
+	cols := make([]string, 0, len(LatencyBucketsMs)+1)
+	var lo int64
+	for _, hi := range LatencyBucketsMs {
+		cols = append(cols, fmt.Sprintf("count(*) FILTER (WHERE a.duration_millis >= %d AND a.duration_millis < %d)", lo, hi))
+		lo = hi
+	}
+	cols = append(cols, fmt.Sprintf("count(*) FILTER (WHERE a.duration_millis >= %d)", lo))
+	return strings.Join(cols, ", ")
+}
+
+// retrySlotColumns counts attempts per attempt number, the last slot
+// taking that number and above.
+func retrySlotColumns() string {
+	cols := make([]string, 0, RetrySlots)
+	for n := 1; n < RetrySlots; n++ {
+		cols = append(cols, fmt.Sprintf("count(*) FILTER (WHERE a.attempt_number = %d)", n))
+	}
+	cols = append(cols, fmt.Sprintf("count(*) FILTER (WHERE a.attempt_number >= %d)", RetrySlots))
+	return strings.Join(cols, ", ")
+}
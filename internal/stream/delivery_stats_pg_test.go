@@ -0,0 +1,69 @@
+//go:build integration
+
+package stream
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// TestDeliveryStatsRollup pins the hourly rollup: counts, histograms and
+// error keys, and that a re-run recomputes an hour instead of adding to it.
+func TestDeliveryStatsRollup(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+
+	const subID = "sub_dstats000001"
+	jobID := tsid.GenerateUntyped()
+	_, err := pool.Exec(ctx,
+		`INSERT INTO msg_dispatch_jobs (id, kind, code, target_url, protocol, mode, status, max_retries,
+		                                subscription_id, updated_at)
+		 VALUES ($1, 'EVENT', 'test:stream:stats:created', 'https://example.com/hook', 'HTTP_WEBHOOK',
+		         'IMMEDIATE', 'COMPLETED', 3, $2, NOW())`,
+		jobID, subID)
+	require.NoError(t, err)
+
+	hour := time.Now().UTC().Truncate(time.Hour)
+	attempt := func(n int, status string, code *int, errType *string, ms int64) {
+		_, err := pool.Exec(ctx,
+			`INSERT INTO msg_dispatch_job_attempts
+			     (id, dispatch_job_id, attempt_number, status, response_code, error_type, duration_millis,
+			      attempted_at, created_at)
+			 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $8)`,
+			tsid.GenerateUntyped(), jobID, n, status, code, errType, ms, hour.Add(time.Duration(n)*time.Second))
+		require.NoError(t, err)
+	}
+	c503, timeout := 503, "TIMEOUT"
+	attempt(1, "FAILURE", nil, &timeout, 30000)
+	attempt(2, "FAILURE", &c503, nil, 120)
+	c200 := 200
+	attempt(3, "SUCCESS", &c200, nil, 40)
+
+	rollup := NewDeliveryStatsRollup(pool)
+	_, err = rollup.Pass(ctx, hour, time.Now().Add(time.Second))
+	require.NoError(t, err)
+	_, err = rollup.Pass(ctx, hour, time.Now().Add(time.Second))
+	require.NoError(t, err)
+
+	buckets, err := LoadDeliveryStats(ctx, pool, subID, hour)
+	require.NoError(t, err)
+	require.Len(t, buckets, 1)
+	b := buckets[0]
+	assert.True(t, hour.Equal(b.Start))
+	assert.Equal(t, int64(3), b.Attempts, "a re-run replaces the hour")
+	assert.Equal(t, int64(1), b.Successes)
+	assert.Equal(t, int64(2), b.Failures)
+	assert.Equal(t, int64(30160), b.DurationMsTotal)
+	assert.Equal(t, map[string]int64{"503": 1, "TIMEOUT": 1}, b.Errors)
+	assert.Equal(t, []int64{1, 1, 1, 0, 0, 0, 0, 0, 0, 0}, b.RetryHistogram)
+	want := make([]int64, len(LatencyBucketsMs)+1)
+	want[0], want[2], want[len(want)-1] = 1, 1, 1
+	assert.Equal(t, want, b.LatencyHistogram)
+}
@@ -0,0 +1,35 @@
+package stream
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestLatencyPercentile(t *testing.T) {
+	assert.Nil(t, LatencyPercentile(make([]int64, len(LatencyBucketsMs)+1), 0.5), "empty histogram")
+
+	// 10 attempts under 50ms, 10 in [100, 250).
+	hist := make([]int64, len(LatencyBucketsMs)+1)
+	hist[0], hist[2] = 10, 10
+	p50 := LatencyPercentile(hist, 0.5)
+	require.NotNil(t, p50)
+	assert.Equal(t, int64(50), *p50, "rank 10 ends the first bucket")
+	p95 := LatencyPercentile(hist, 0.95)
+	require.NotNil(t, p95)
+	assert.Equal(t, int64(235), *p95, "interpolated within [100, 250)")
+
+	// The open-ended bucket reports its lower bound.
+	hist = make([]int64, len(LatencyBucketsMs)+1)
+	hist[len(hist)-1] = 3
+	p99 := LatencyPercentile(hist, 0.99)
+	require.NotNil(t, p99)
+	assert.Equal(t, LatencyBucketsMs[len(LatencyBucketsMs)-1], *p99)
+}
+
+func TestRollupColumnsMatchHistograms(t *testing.T) {
+	assert.Equal(t, len(LatencyBucketsMs)+1, strings.Count(latencyBucketColumns(), "FILTER"))
+	assert.Equal(t, RetrySlots, strings.Count(retrySlotColumns(), "FILTER"))
+}
@@ -15,7 +15,10 @@ import (
 // the last projection (`updated_at > projected_at`), upserts into the
 // read model, and stamps `projected_at`. Application/subdomain/aggregate
 // fields are derived from the dispatch job `code` (same
-// `application:subdomain:aggregate:verb` shape as event types).
+// `application:subdomain:aggregate:verb` shape as event types). The
+// subscription, client and event type display names are looked up at
+// projection time and target_host is parsed from target_url; ReadNameSync
+// refreshes the names when their source rows are renamed.
 type DispatchJobProjection struct {
 	pool *pgxpool.Pool
 }
@@ -74,6 +77,7 @@ func (p *DispatchJobProjection) step(ctx context.Context, batchSize int) (int, e
 		     attempt_count, last_attempt_at, completed_at, duration_millis, last_error,
 		     idempotency_key, is_completed, is_terminal,
 		     application, subdomain, aggregate,
+		     subscription_name, client_name, event_type_name, target_host,
 		     created_at, updated_at, projected_at)
 		 SELECT j.id, j.external_id, j.source, j.kind, j.code, j.subject,
 		        j.event_id, j.correlation_id, j.target_url, j.protocol,
@@ -94,6 +98,10 @@ func (p *DispatchJobProjection) step(ctx context.Context, batchSize int) (int, e
 		        split_part(j.code, ':', 1),
 		        NULLIF(split_part(j.code, ':', 2), ''),
 		        NULLIF(split_part(j.code, ':', 3), ''),
+		        (SELECT s.name FROM msg_subscriptions s WHERE s.id = j.subscription_id),
+		        (SELECT c.name FROM tnt_clients c WHERE c.id = j.client_id),
+		        (SELECT et.name FROM msg_event_types et WHERE et.code = j.code),
+		        substring(j.target_url from '^[a-zA-Z][a-zA-Z0-9+.-]*://(?:[^/?#@]*@)?([^/:?#@]+)'),
 		        j.created_at, j.updated_at, NOW()
 		   FROM msg_dispatch_jobs j
 		  WHERE j.id = ANY($1)
@@ -106,6 +114,9 @@ func (p *DispatchJobProjection) step(ctx context.Context, batchSize int) (int, e
 		     last_error = EXCLUDED.last_error,
 		     is_completed = EXCLUDED.is_completed,
 		     is_terminal = EXCLUDED.is_terminal,
+		     subscription_name = EXCLUDED.subscription_name,
+		     client_name = EXCLUDED.client_name,
+		     event_type_name = EXCLUDED.event_type_name,
 		     updated_at = EXCLUDED.updated_at,
 		     projected_at = NOW()`, ids); err != nil {
 		return 0, fmt.Errorf("insert read: %w", err)
@@ -4,6 +4,9 @@ import (
 	"context"
 	"encoding/json"
 	"fmt"
+	"hash/fnv"
+	"log/slog"
+	"math"
 	"strings"
 	"sync"
 	"time"
@@ -27,9 +30,20 @@ import (
 // At-least-once semantics: dispatch-job inserts and `fanned_out_at`
 // stamps land in one transaction. FOR UPDATE SKIP LOCKED on the claim
 // makes it safe to run multiple stream nodes against the same DB.
+//
+// An event whose jobs cannot be inserted (a constraint the row violates,
+// a missing partition) would otherwise roll the batch back forever. A
+// failed batch is retried one event at a time; an event that fails
+// MaxAttempts passes in a row is recorded in msg_stream_dlq and stamped
+// so the stream moves on. Later events of its message group wait until
+// then, keeping the group in order.
 type FanOut struct {
 	pool            *pgxpool.Pool
 	subscriptionTTL time.Duration
+	maxAttempts     int
+
+	failMu   sync.Mutex
+	failures map[string]int // event id → consecutive failed passes
 
 	cacheMu       sync.Mutex
 	subs          []cachedSubscription
@@ -41,11 +55,14 @@ type FanOutConfig struct {
 	// SubscriptionTTL controls how long the cached subscription set is
 	// reused before being refetched. Default 5s, matches Rust.
 	SubscriptionTTL time.Duration
+	// MaxAttempts is how many passes may fail to insert an event's
+	// dispatch jobs before it is dead-lettered. Default 3.
+	MaxAttempts int
 }
 
 // DefaultFanOutConfig returns the Rust defaults.
 func DefaultFanOutConfig() FanOutConfig {
-	return FanOutConfig{SubscriptionTTL: 5 * time.Second}
+	return FanOutConfig{SubscriptionTTL: 5 * time.Second, MaxAttempts: 3}
 }
 
 // NewFanOut wires the fan-out processor.
@@ -58,7 +75,15 @@ func NewFanOutWithConfig(pool *pgxpool.Pool, cfg FanOutConfig) *FanOut {
 	if cfg.SubscriptionTTL <= 0 {
 		cfg.SubscriptionTTL = 5 * time.Second
 	}
-	return &FanOut{pool: pool, subscriptionTTL: cfg.SubscriptionTTL}
+	if cfg.MaxAttempts <= 0 {
+		cfg.MaxAttempts = 3
+	}
+	return &FanOut{
+		pool:            pool,
+		subscriptionTTL: cfg.SubscriptionTTL,
+		maxAttempts:     cfg.MaxAttempts,
+		failures:        map[string]int{},
+	}
 }
 
 // Projector returns the configured Projector ready to Run.
@@ -116,7 +141,10 @@ func (f *FanOut) step(ctx context.Context, batchSize int) (int, error) {
 	jobs := buildJobs(claimed, subs)
 	if len(jobs) > 0 {
 		if err := insertJobsInTx(ctx, tx, jobs); err != nil {
-			return 0, fmt.Errorf("insert jobs: %w", err)
+			// Release the claim and find the event that broke the batch.
+			_ = tx.Rollback(ctx)
+			slog.Warn("fan-out batch insert failed; retrying events one by one", "events", len(claimed), "err", err)
+			return f.stepEach(ctx, claimed, subs)
 		}
 	}
 
@@ -126,6 +154,110 @@ func (f *FanOut) step(ctx context.Context, batchSize int) (int, error) {
 	return len(claimed), nil
 }
 
+// ── Poison events ────────────────────────────────────────────────────────
+
+// stepEach fans out events one transaction at a time after their batch
+// failed. A failing event counts an attempt and, at maxAttempts, is
+// dead-lettered; until then the rest of its message group is held back.
+func (f *FanOut) stepEach(ctx context.Context, events []claimedEvent, subs []cachedSubscription) (int, error) {
+	held := map[string]bool{}
+	n := 0
+	for _, e := range events {
+		if e.MessageGroup != nil && held[*e.MessageGroup] {
+			continue
+		}
+		err := f.fanOutOne(ctx, e, subs)
+		if err == nil {
+			f.clearFailure(e.ID)
+			n++
+			continue
+		}
+		if ctx.Err() != nil {
+			return n, ctx.Err()
+		}
+		attempts := f.recordFailure(e.ID)
+		if attempts < f.maxAttempts {
+			slog.Warn("fan-out event failed", "event_id", e.ID, "attempts", attempts, "err", err)
+		} else if derr := deadLetter(ctx, f.pool, e, err, attempts); derr != nil {
+			slog.Warn("fan-out dead-letter failed", "event_id", e.ID, "err", derr)
+		} else {
+			f.clearFailure(e.ID)
+			slog.Error("fan-out event dead-lettered", "event_id", e.ID, "attempts", attempts, "err", err)
+			n++
+			continue
+		}
+		if e.MessageGroup != nil {
+			held[*e.MessageGroup] = true
+		}
+	}
+	return n, nil
+}
+
+// fanOutOne claims and fans out a single event. An event another node
+// stamped in the meantime is left alone.
+func (f *FanOut) fanOutOne(ctx context.Context, e claimedEvent, subs []cachedSubscription) error {
+	tx, err := f.pool.Begin(ctx)
+	if err != nil {
+		return err
+	}
+	defer func() { _ = tx.Rollback(ctx) }()
+	tag, err := tx.Exec(ctx,
+		`UPDATE msg_events SET fanned_out_at = NOW()
+		  WHERE id = $1 AND created_at = $2 AND fanned_out_at IS NULL`,
+		e.ID, e.CreatedAt)
+	if err != nil {
+		return err
+	}
+	if tag.RowsAffected() == 0 {
+		return nil
+	}
+	if err := insertJobsInTx(ctx, tx, buildJobs([]claimedEvent{e}, subs)); err != nil {
+		return err
+	}
+	return tx.Commit(ctx)
+}
+
+func (f *FanOut) recordFailure(eventID string) int {
+	f.failMu.Lock()
+	defer f.failMu.Unlock()
+	f.failures[eventID]++
+	return f.failures[eventID]
+}
+
+func (f *FanOut) clearFailure(eventID string) {
+	f.failMu.Lock()
+	defer f.failMu.Unlock()
+	delete(f.failures, eventID)
+}
+
+// deadLetter records the event in msg_stream_dlq and stamps it fanned out
+// in one transaction. Re-dead-lettering a reprocessed event adds to its
+// attempt count.
+func deadLetter(ctx context.Context, pool *pgxpool.Pool, e claimedEvent, cause error, attempts int) error {
+	tx, err := pool.Begin(ctx)
+	if err != nil {
+		return err
+	}
+	defer func() { _ = tx.Rollback(ctx) }()
+	if _, err := tx.Exec(ctx,
+		`INSERT INTO msg_stream_dlq
+		     (event_id, event_created_at, event_type, message_group, stage, error, attempts)
+		 VALUES ($1, $2, $3, $4, 'FAN_OUT', $5, $6)
+		 ON CONFLICT (event_id) DO UPDATE
+		    SET error = EXCLUDED.error,
+		        attempts = msg_stream_dlq.attempts + EXCLUDED.attempts,
+		        updated_at = NOW()`,
+		e.ID, e.CreatedAt, e.EventType, e.MessageGroup, cause.Error(), attempts); err != nil {
+		return err
+	}
+	if _, err := tx.Exec(ctx,
+		`UPDATE msg_events SET fanned_out_at = NOW() WHERE id = $1 AND created_at = $2`,
+		e.ID, e.CreatedAt); err != nil {
+		return err
+	}
+	return tx.Commit(ctx)
+}
+
 // ── Event claim ──────────────────────────────────────────────────────────
 
 // claimedEvent carries just the columns fanout needs from msg_events.
@@ -138,7 +270,11 @@ type claimedEvent struct {
 	CorrelationID *string
 	MessageGroup  *string
 	ClientID      *string
+	SchemaID      *string
 	CreatedAt     time.Time
+	// ReplaySubscription is the one subscription an event replayed from
+	// the archive is for; nil matches every subscription as usual.
+	ReplaySubscription *string
 }
 
 // claimUnfannedEvents stamps `fanned_out_at` and returns the claimed
@@ -158,7 +294,8 @@ func claimUnfannedEvents(ctx context.Context, tx pgx.Tx, batchSize int) ([]claim
 		   FROM batch b
 		  WHERE e.id = b.id AND e.created_at = b.created_at
 		 RETURNING e.id, e.type, e.source, e.subject, e.data,
-		           e.correlation_id, e.message_group, e.client_id, e.created_at`,
+		           e.correlation_id, e.message_group, e.client_id, e.schema_id,
+		           e.created_at, e.replay_subscription_id`,
 		batchSize)
 	if err != nil {
 		return nil, err
@@ -169,7 +306,8 @@ func claimUnfannedEvents(ctx context.Context, tx pgx.Tx, batchSize int) ([]claim
 		var e claimedEvent
 		var data []byte
 		if err := rows.Scan(&e.ID, &e.EventType, &e.Source, &e.Subject, &data,
-			&e.CorrelationID, &e.MessageGroup, &e.ClientID, &e.CreatedAt); err != nil {
+			&e.CorrelationID, &e.MessageGroup, &e.ClientID, &e.SchemaID,
+			&e.CreatedAt, &e.ReplaySubscription); err != nil {
 			return nil, err
 		}
 		if len(data) > 0 {
@@ -195,7 +333,15 @@ type cachedSubscription struct {
 	MaxRetries        int32
 	TimeoutSeconds    int32
 	Sequence          int32
+	Pull              bool // jobs wait for GET /api/consume instead of a webhook
 	EventTypePatterns []string
+	Split             []splitTarget // traffic split; Target keeps what it leaves of 100
+}
+
+// splitTarget is one msg_subscription_targets row.
+type splitTarget struct {
+	Target string
+	Weight int32
 }
 
 func (s *cachedSubscription) matchesEventType(code string) bool {
@@ -217,6 +363,57 @@ func (s *cachedSubscription) matchesClient(eventClient *string) bool {
 	return *s.ClientID == *eventClient
 }
 
+// targetFor is the endpoint e's job is sent to. Without a traffic split
+// that is the subscription's target. With one, the target (weighted with
+// what the split leaves) and each split endpoint score e's message group,
+// or its id when it has none, by weighted rendezvous hashing and the
+// highest score wins: a group always lands on the same endpoint, each
+// endpoint gets its weight's share of groups, and changing a weight only
+// moves groups to or from the endpoints whose weight changed.
+func (s *cachedSubscription) targetFor(e *claimedEvent) string {
+	if len(s.Split) == 0 || s.Pull {
+		return s.Target
+	}
+	key := e.ID
+	if e.MessageGroup != nil && *e.MessageGroup != "" {
+		key = *e.MessageGroup
+	}
+	weight := int32(100)
+	for _, t := range s.Split {
+		weight -= t.Weight
+	}
+	best, bestScore := s.Target, rendezvousScore(key, s.Target, weight)
+	for _, t := range s.Split {
+		if score := rendezvousScore(key, t.Target, t.Weight); score > bestScore {
+			best, bestScore = t.Target, score
+		}
+	}
+	return best
+}
+
+// rendezvousScore is -weight/ln(u), with u uniform in (0, 1) drawn from
+// the hash of key and endpoint. The endpoint with the highest score wins a
+// key with probability weight/total. A weight of 0 never wins.
+func rendezvousScore(key, endpoint string, weight int32) float64 {
+	if weight <= 0 {
+		return math.Inf(-1)
+	}
+	h := fnv.New64a()
+	_, _ = h.Write([]byte(key))
+	_, _ = h.Write([]byte{0})
+	_, _ = h.Write([]byte(endpoint))
+	// FNV leaves similar inputs close together; a splitmix64 finalizer
+	// spreads them over the whole range.
+	x := h.Sum64()
+	x ^= x >> 30
+	x *= 0xbf58476d1ce4e5b9
+	x ^= x >> 27
+	x *= 0x94d049bb133111eb
+	x ^= x >> 31
+	u := (float64(x>>11) + 0.5) / (1 << 53)
+	return -float64(weight) / math.Log(u)
+}
+
 // patternMatches is the Rust-side `:`-separated wildcard match. Segment
 // count must agree; `*` matches a single segment.
 func patternMatches(pattern, code string) bool {
@@ -257,7 +454,7 @@ func loadActiveSubscriptions(ctx context.Context, pool *pgxpool.Pool) ([]cachedS
 	rows, err := pool.Query(ctx,
 		`SELECT s.id, s.client_id, s.target, s.mode, s.data_only,
 		        s.dispatch_pool_id, s.service_account_id, s.max_retries,
-		        s.timeout_seconds, s.sequence, e.event_type_code
+		        s.timeout_seconds, s.sequence, s.delivery_mode = 'PULL', e.event_type_code
 		   FROM msg_subscriptions s
 		   LEFT JOIN msg_subscription_event_types e ON e.subscription_id = s.id
 		  WHERE s.status = 'ACTIVE'
@@ -272,12 +469,12 @@ func loadActiveSubscriptions(ctx context.Context, pool *pgxpool.Pool) ([]cachedS
 		var (
 			id, target, mode                       string
 			clientID, dispatchPoolID, saID, etCode *string
-			dataOnly                               bool
+			dataOnly, pull                         bool
 			maxRetries, timeoutSeconds, sequence   int32
 		)
 		if err := rows.Scan(&id, &clientID, &target, &mode, &dataOnly,
 			&dispatchPoolID, &saID, &maxRetries, &timeoutSeconds,
-			&sequence, &etCode); err != nil {
+			&sequence, &pull, &etCode); err != nil {
 			return nil, err
 		}
 		entry, ok := byID[id]
@@ -293,6 +490,7 @@ func loadActiveSubscriptions(ctx context.Context, pool *pgxpool.Pool) ([]cachedS
 				MaxRetries:       maxRetries,
 				TimeoutSeconds:   timeoutSeconds,
 				Sequence:         sequence,
+				Pull:             pull,
 			}
 			byID[id] = entry
 			order = append(order, id)
@@ -304,6 +502,9 @@ func loadActiveSubscriptions(ctx context.Context, pool *pgxpool.Pool) ([]cachedS
 	if err := rows.Err(); err != nil {
 		return nil, err
 	}
+	if err := loadTrafficSplits(ctx, pool, byID); err != nil {
+		return nil, err
+	}
 	out := make([]cachedSubscription, 0, len(order))
 	for _, id := range order {
 		out = append(out, *byID[id])
@@ -311,6 +512,33 @@ func loadActiveSubscriptions(ctx context.Context, pool *pgxpool.Pool) ([]cachedS
 	return out, nil
 }
 
+// loadTrafficSplits attaches the active subscriptions' traffic splits.
+func loadTrafficSplits(ctx context.Context, pool *pgxpool.Pool, byID map[string]*cachedSubscription) error {
+	rows, err := pool.Query(ctx,
+		`SELECT t.subscription_id, t.target, t.weight
+		   FROM msg_subscription_targets t
+		   JOIN msg_subscriptions s ON s.id = t.subscription_id
+		  WHERE s.status = 'ACTIVE'
+		  ORDER BY t.id`)
+	if err != nil {
+		return err
+	}
+	defer rows.Close()
+	for rows.Next() {
+		var (
+			id string
+			t  splitTarget
+		)
+		if err := rows.Scan(&id, &t.Target, &t.Weight); err != nil {
+			return err
+		}
+		if entry, ok := byID[id]; ok {
+			entry.Split = append(entry.Split, t)
+		}
+	}
+	return rows.Err()
+}
+
 // ── Dispatch job assembly + insert ───────────────────────────────────────
 
 // newJob is the subset of msg_dispatch_jobs columns fanout sets. Other
@@ -324,6 +552,7 @@ type newJob struct {
 	EventID        string
 	CorrelationID  *string
 	TargetURL      string
+	Protocol       string
 	Payload        string
 	DataOnly       bool
 	ServiceAcctID  *string
@@ -337,6 +566,7 @@ type newJob struct {
 	Status         string
 	MaxRetries     int32
 	IdempotencyKey string
+	SchemaID       *string
 	CreatedAt      time.Time
 }
 
@@ -345,6 +575,9 @@ func buildJobs(events []claimedEvent, subs []cachedSubscription) []newJob {
 	for _, e := range events {
 		for i := range subs {
 			s := &subs[i]
+			if e.ReplaySubscription != nil && *e.ReplaySubscription != s.ID {
+				continue
+			}
 			if !s.matchesEventType(e.EventType) {
 				continue
 			}
@@ -355,6 +588,10 @@ func buildJobs(events []claimedEvent, subs []cachedSubscription) []newJob {
 			if len(e.Data) > 0 {
 				payload = string(e.Data)
 			}
+			protocol := "HTTP_WEBHOOK"
+			if s.Pull {
+				protocol = "PULL"
+			}
 			jobs = append(jobs, newJob{
 				// 13-char untyped TSID — `msg_dispatch_jobs.id` is
 				// VARCHAR(13). Using a typed prefix (`djb_...`) overflows
@@ -365,7 +602,8 @@ func buildJobs(events []claimedEvent, subs []cachedSubscription) []newJob {
 				Subject:        e.Subject,
 				EventID:        e.ID,
 				CorrelationID:  e.CorrelationID,
-				TargetURL:      s.Target,
+				TargetURL:      s.targetFor(&e),
+				Protocol:       protocol,
 				Payload:        payload,
 				DataOnly:       s.DataOnly,
 				ServiceAcctID:  s.ServiceAccountID,
@@ -379,6 +617,7 @@ func buildJobs(events []claimedEvent, subs []cachedSubscription) []newJob {
 				Status:         string(common.DispatchPending),
 				MaxRetries:     s.MaxRetries,
 				IdempotencyKey: fmt.Sprintf("%s:%s", e.ID, s.ID),
+				SchemaID:       e.SchemaID,
 				CreatedAt:      e.CreatedAt,
 			})
 		}
@@ -413,16 +652,17 @@ func insertJobsInTx(ctx context.Context, tx pgx.Tx, jobs []newJob) error {
 			    target_url, protocol, payload, data_only, service_account_id,
 			    client_id, subscription_id, mode, dispatch_pool_id, message_group,
 			    sequence, timeout_seconds, status, max_retries, idempotency_key,
-			    created_at, updated_at)
-			 VALUES ($1, $2, $3, $4, $5, $6, $7, 'HTTP_WEBHOOK', $8, $9,
+			    schema_id, created_at, updated_at)
+			 VALUES ($1, $2, $3, $4, $5, $6, $7, $22, $8, $9,
 			         $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20,
-			         $21, $21)
+			         $23, $21, $21)
 			 ON CONFLICT (id, created_at) DO NOTHING`,
 			j.ID, j.Code, j.Source, j.Subject, j.EventID, j.CorrelationID,
 			j.TargetURL, j.Payload, j.DataOnly, j.ServiceAcctID,
 			j.ClientID, j.SubscriptionID, j.Mode, j.DispatchPoolID,
 			j.MessageGroup, j.Sequence, j.TimeoutSeconds, j.Status,
-			j.MaxRetries, j.IdempotencyKey, j.CreatedAt)
+			j.MaxRetries, j.IdempotencyKey, j.CreatedAt, j.Protocol,
+			j.SchemaID)
 	}
 	br := tx.SendBatch(ctx, batch)
 	defer br.Close()
@@ -0,0 +1,114 @@
+//go:build integration
+
+package stream
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/streamdlq"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+func TestMain(m *testing.M) { testpg.RunMain(m) }
+
+// TestFanOutDeadLettersPoisonEvent pins the DLQ path: an event whose job
+// insert keeps failing holds back its message group but not other groups,
+// lands in msg_stream_dlq after MaxAttempts passes, and fans out normally
+// once reprocessed.
+func TestFanOutDeadLettersPoisonEvent(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+
+	// A constraint the poison event's jobs violate.
+	_, err := pool.Exec(ctx, `ALTER TABLE msg_dispatch_jobs
+		ADD CONSTRAINT test_fan_out_poison CHECK (subject IS DISTINCT FROM 'poison')`)
+	require.NoError(t, err)
+	dropPoison := func() {
+		_, _ = pool.Exec(ctx, `ALTER TABLE msg_dispatch_jobs DROP CONSTRAINT IF EXISTS test_fan_out_poison`)
+	}
+	t.Cleanup(dropPoison)
+
+	const eventType = "test:stream:dlq:created"
+	const subID = "sub_streamdlq0001"
+	_, err = pool.Exec(ctx,
+		`INSERT INTO msg_subscriptions (id, code, name, target) VALUES ($1, 'stream-dlq', 'Stream DLQ', 'https://example.com/hook')`,
+		subID)
+	require.NoError(t, err)
+	_, err = pool.Exec(ctx,
+		`INSERT INTO msg_subscription_event_types (subscription_id, event_type_code) VALUES ($1, $2)`,
+		subID, eventType)
+	require.NoError(t, err)
+
+	base := time.Now().UTC().Truncate(time.Millisecond)
+	insert := func(offset int, group, subject string) string {
+		id := tsid.GenerateUntyped()
+		_, err := pool.Exec(ctx,
+			`INSERT INTO msg_events (id, type, source, subject, time, message_group, created_at)
+			 VALUES ($1, $2, 'test', $3, $4, $5, $4)`,
+			id, eventType, subject, base.Add(time.Duration(offset)*time.Second), group)
+		require.NoError(t, err)
+		return id
+	}
+	before := insert(0, "grp-a", "ok")
+	poison := insert(1, "grp-a", "poison")
+	after := insert(2, "grp-a", "ok")
+	other := insert(3, "grp-b", "ok")
+
+	f := NewFanOutWithConfig(pool, FanOutConfig{SubscriptionTTL: time.Millisecond, MaxAttempts: 2})
+
+	// Pass 1: the poison event fails and holds back the rest of grp-a.
+	_, err = f.step(ctx, 100)
+	require.NoError(t, err)
+	assert.Equal(t, 1, jobCount(t, pool, before))
+	assert.Equal(t, 0, jobCount(t, pool, poison))
+	assert.Equal(t, 0, jobCount(t, pool, after), "held behind the poison event")
+	assert.Equal(t, 1, jobCount(t, pool, other), "another group is unaffected")
+
+	// Pass 2: out of attempts, the poison event is dead-lettered and the
+	// group resumes.
+	_, err = f.step(ctx, 100)
+	require.NoError(t, err)
+	assert.Equal(t, 1, jobCount(t, pool, after))
+
+	repo := streamdlq.NewRepository(pool)
+	entries, err := repo.List(ctx, 500)
+	require.NoError(t, err)
+	var entry *streamdlq.Entry
+	for i := range entries {
+		if entries[i].EventID == poison {
+			entry = &entries[i]
+		}
+	}
+	require.NotNil(t, entry)
+	assert.Equal(t, 2, entry.Attempts)
+	assert.Equal(t, "FAN_OUT", entry.Stage)
+	assert.Contains(t, entry.Error, "test_fan_out_poison")
+
+	// Fixed and reprocessed, the event fans out.
+	dropPoison()
+	found, err := repo.Reprocess(ctx, poison)
+	require.NoError(t, err)
+	assert.True(t, found)
+	_, err = f.step(ctx, 100)
+	require.NoError(t, err)
+	assert.Equal(t, 1, jobCount(t, pool, poison))
+
+	found, err = repo.Discard(ctx, poison)
+	require.NoError(t, err)
+	assert.False(t, found, "reprocessing removed the entry")
+}
+
+func jobCount(t *testing.T, pool *pgxpool.Pool, eventID string) int {
+	t.Helper()
+	var n int
+	require.NoError(t, pool.QueryRow(context.Background(),
+		`SELECT COUNT(*) FROM msg_dispatch_jobs WHERE event_id = $1`, eventID).Scan(&n))
+	return n
+}
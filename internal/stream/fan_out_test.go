@@ -0,0 +1,111 @@
+package stream
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription"
+)
+
+// FuzzPatternMatches checks the fan-out wildcard matcher against event
+// type codes taken from untrusted event payloads. It must agree with the
+// platform's EventTypeBinding.Matches, which the subscription API uses to
+// preview matches.
+func FuzzPatternMatches(f *testing.F) {
+	for _, s := range [][2]string{
+		{"orders:order:created", "orders:order:created"},
+		{"orders:*:created", "orders:order:created"},
+		{"orders:*", "orders:order:created"},
+		{"*", ""},
+		{"a::b", "a::b"},
+		{":", "*:*"},
+		{"**:x", "y:x"},
+	} {
+		f.Add(s[0], s[1])
+	}
+	f.Fuzz(func(t *testing.T, pattern, code string) {
+		got := patternMatches(pattern, code)
+		assert.Equal(t, subscription.NewEventTypeBinding(pattern).Matches(code), got)
+		if got {
+			assert.Equal(t, strings.Count(pattern, ":"), strings.Count(code, ":"))
+		}
+
+		assert.True(t, patternMatches(code, code), "a code matches itself")
+		segs := strings.Split(code, ":")
+		for i := range segs {
+			segs[i] = "*"
+		}
+		assert.True(t, patternMatches(strings.Join(segs, ":"), code), "all-wildcard pattern")
+	})
+}
+
+func TestBuildJobsReplayTargetsOneSubscription(t *testing.T) {
+	subs := []cachedSubscription{
+		{ID: "sub_a", Target: "https://a.example.com", EventTypePatterns: []string{"orders:order:created"}},
+		{ID: "sub_b", Target: "https://b.example.com", EventTypePatterns: []string{"orders:*:created"}},
+	}
+	target := "sub_b"
+	jobs := buildJobs([]claimedEvent{
+		{ID: "evt1", EventType: "orders:order:created"},
+		{ID: "evt2", EventType: "orders:order:created", ReplaySubscription: &target},
+	}, subs)
+
+	var got []string
+	for _, j := range jobs {
+		got = append(got, j.EventID+"→"+j.SubscriptionID)
+	}
+	assert.Equal(t, []string{"evt1→sub_a", "evt1→sub_b", "evt2→sub_b"}, got)
+}
+
+func TestTargetForSplitsByMessageGroup(t *testing.T) {
+	const v1, v2 = "https://v1.example.com", "https://v2.example.com"
+	sub := cachedSubscription{ID: "sub_a", Target: v1}
+	group := func(i int) *claimedEvent {
+		g := fmt.Sprintf("order-%d", i)
+		return &claimedEvent{ID: fmt.Sprintf("evt%d", i), MessageGroup: &g}
+	}
+	assert.Equal(t, v1, sub.targetFor(group(1)), "no split")
+
+	const groups = 20000
+	route := func(weight int32) map[int]string {
+		sub.Split = []splitTarget{{Target: v2, Weight: weight}}
+		out := make(map[int]string, groups)
+		for i := range groups {
+			out[i] = sub.targetFor(group(i))
+		}
+		return out
+	}
+	share := func(routed map[int]string) float64 {
+		n := 0
+		for _, target := range routed {
+			if target == v2 {
+				n++
+			}
+		}
+		return float64(n) / groups
+	}
+
+	at10 := route(10)
+	assert.InDelta(t, 0.10, share(at10), 0.01)
+	e := group(7)
+	e.ID = "another event of the same group"
+	assert.Equal(t, at10[7], sub.targetFor(e), "a group sticks to its endpoint")
+
+	// Raising the weight only moves groups onto v2.
+	at30 := route(30)
+	assert.InDelta(t, 0.30, share(at30), 0.015)
+	for i, target := range at10 {
+		if target == v2 {
+			assert.Equal(t, v2, at30[i], "group %d left v2", i)
+		}
+	}
+
+	assert.Zero(t, share(route(0)), "weight 0 gets nothing")
+	assert.Equal(t, 1.0, share(route(100)), "the endpoint keeps nothing")
+
+	sub.Pull = true
+	assert.Equal(t, v1, sub.targetFor(group(1)), "pull jobs have no endpoint to split")
+}
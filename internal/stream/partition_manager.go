@@ -170,6 +170,35 @@ func (m *PartitionManager) pass(ctx context.Context) (created, dropped int, err
 	return created, dropped, nil
 }
 
+// EnsureFrom creates the monthly partitions from from's month through the
+// current month + MonthsForward on every partitioned parent. For callers
+// that backfill historical rows before the first Run pass (fc-dev scenario
+// seeding); the drop pass is not run.
+func (m *PartitionManager) EnsureFrom(ctx context.Context, from time.Time) error {
+	cfg := m.cfg()
+	from = from.UTC()
+	months := monthsBetween(from, time.Now().UTC()) + cfg.MonthsForward
+	for _, parent := range PartitionedTables {
+		ok, err := m.isPartitioned(ctx, parent)
+		if err != nil {
+			return fmt.Errorf("is_partitioned(%s): %w", parent, err)
+		}
+		if !ok {
+			continue
+		}
+		if _, err := m.ensureForward(ctx, parent, from, months); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// monthsBetween counts calendar-month boundaries from from to to (0 when
+// both fall in the same month; negative when to is earlier).
+func monthsBetween(from, to time.Time) int {
+	return (to.Year()-from.Year())*12 + int(to.Month()) - int(from.Month())
+}
+
 // isPartitioned reports whether parent is a partitioned table (relkind 'p').
 func (m *PartitionManager) isPartitioned(ctx context.Context, parent string) (bool, error) {
 	var relkind string
@@ -56,3 +56,22 @@ func TestParsePartitionEndDriveRetention(t *testing.T) {
 		t.Errorf("expected 2026-05 partition (end %s) to be > cutoff %s", end2, cutoff)
 	}
 }
+
+func TestMonthsBetween(t *testing.T) {
+	d := func(s string) time.Time { v, _ := time.Parse("2006-01-02", s); return v }
+	cases := []struct {
+		from, to string
+		want     int
+	}{
+		{"2026-03-01", "2026-03-31", 0},
+		{"2026-02-27", "2026-03-02", 1},
+		{"2025-12-30", "2026-01-02", 1},
+		{"2025-11-15", "2026-03-15", 4},
+		{"2026-03-15", "2026-02-15", -1},
+	}
+	for _, c := range cases {
+		if got := monthsBetween(d(c.from), d(c.to)); got != c.want {
+			t.Errorf("monthsBetween(%s, %s) = %d, want %d", c.from, c.to, got, c.want)
+		}
+	}
+}
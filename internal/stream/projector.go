@@ -13,6 +13,8 @@ import (
 	"time"
 
 	"github.com/jackc/pgx/v5/pgxpool"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/logging"
 )
 
 // ProjectorConfig is the per-projection knob set.
@@ -75,7 +77,8 @@ func (p *Projector) Run(ctx context.Context) {
 		slog.Info("projector disabled", "name", p.Name)
 		return
 	}
-	slog.Info("projector starting", "name", p.Name, "batch_size", p.Cfg.BatchSize)
+	ctx = logging.With(ctx, "projector", p.Name)
+	slog.InfoContext(ctx, "projector starting", "batch_size", p.Cfg.BatchSize)
 	if p.Health != nil {
 		p.Health.SetRunning(true)
 		defer p.Health.SetRunning(false)
@@ -83,7 +86,7 @@ func (p *Projector) Run(ctx context.Context) {
 	for {
 		select {
 		case <-ctx.Done():
-			slog.Info("projector stopped", "name", p.Name)
+			slog.InfoContext(ctx, "projector stopped")
 			return
 		default:
 		}
@@ -95,7 +98,7 @@ func (p *Projector) Run(ctx context.Context) {
 
 		n, err := p.Step(ctx, p.Cfg.BatchSize)
 		if err != nil {
-			slog.Warn("projector step error", "name", p.Name, "err", err)
+			slog.WarnContext(ctx, "projector step error", "err", err)
 			if p.Health != nil {
 				p.Health.RecordError()
 			}
@@ -0,0 +1,301 @@
+package stream
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"log/slog"
+	"time"
+
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// ReadNameSync keeps the display names on msg_dispatch_jobs_read
+// (subscription_name, client_name, event_type_name, migration 049) in step
+// with their source rows. The dispatch-job projection writes the names
+// current at projection time; when a subscription, client or event type is
+// renamed afterwards, this loop rewrites the read rows that carry the old
+// name.
+//
+// Each pass looks at source rows updated since the previous pass (less a
+// minute of overlap for late-committing transactions). The first pass
+// after startup has no previous pass and checks every source row, which
+// backfills rows projected before migration 049 and catches renames made
+// while the processor was down. Updates only touch rows whose name differs,
+// so re-checking an unchanged entity is an index scan and nothing more.
+//
+// target_host needs no source row; it is parsed from target_url. Until
+// every read row has been visited once, passes that start from the zero
+// time also walk the read table in created_at order and fill target_host
+// where it is still NULL, MaxBatches batches per pass.
+type ReadNameSync struct {
+	pool   *pgxpool.Pool
+	Health *Health
+
+	Config ReadNameSyncConfig
+	// IsLeader gates each pass; nil means always-leader (single instance).
+	IsLeader func() bool
+
+	// since is the lower bound on source updated_at for the next pass; the
+	// zero value checks everything.
+	since time.Time
+
+	// hostsAfter is the (created_at, id) the target_host backfill has
+	// reached; hostsDone is set once it has walked off the end.
+	hostsAfter struct {
+		createdAt time.Time
+		id        string
+	}
+	hostsDone bool
+}
+
+// ReadNameSyncConfig tunes the sync.
+type ReadNameSyncConfig struct {
+	BatchSize    int           // rows per statement (default 1000)
+	MaxBatches   int           // statements per entity per pass (default 50)
+	TickInterval time.Duration // pass cadence (default 1m)
+}
+
+// DefaultReadNameSyncConfig returns the defaults.
+func DefaultReadNameSyncConfig() ReadNameSyncConfig {
+	return ReadNameSyncConfig{BatchSize: 1000, MaxBatches: 50, TickInterval: time.Minute}
+}
+
+// NewReadNameSync wires a sync with default config + always-leader.
+func NewReadNameSync(pool *pgxpool.Pool) *ReadNameSync {
+	return &ReadNameSync{pool: pool}
+}
+
+// readNameOverlap is subtracted from each pass's start to form the next
+// pass's lower bound, so a rename committed just after a pass read the
+// source table is not missed.
+const readNameOverlap = time.Minute
+
+func (s *ReadNameSync) cfg() ReadNameSyncConfig {
+	c := s.Config
+	d := DefaultReadNameSyncConfig()
+	if c.BatchSize <= 0 {
+		c.BatchSize = d.BatchSize
+	}
+	if c.MaxBatches <= 0 {
+		c.MaxBatches = d.MaxBatches
+	}
+	if c.TickInterval <= 0 {
+		c.TickInterval = d.TickInterval
+	}
+	return c
+}
+
+func (s *ReadNameSync) leader() bool {
+	if s.IsLeader == nil {
+		return true
+	}
+	return s.IsLeader()
+}
+
+// Run ticks once on startup, then every Config.TickInterval, until ctx is
+// cancelled.
+func (s *ReadNameSync) Run(ctx context.Context) {
+	cfg := s.cfg()
+	if s.Health != nil {
+		s.Health.SetRunning(true)
+		defer s.Health.SetRunning(false)
+	}
+	s.runPass(ctx)
+
+	tick := time.NewTicker(cfg.TickInterval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("read name sync stopped")
+			return
+		case <-tick.C:
+			s.runPass(ctx)
+		}
+	}
+}
+
+func (s *ReadNameSync) runPass(ctx context.Context) {
+	if !s.leader() {
+		return
+	}
+	started := time.Now().UTC()
+	res, err := s.Pass(ctx, s.since)
+	if err != nil {
+		slog.Warn("read name sync pass failed", "err", err)
+		if s.Health != nil {
+			s.Health.RecordError()
+		}
+		return
+	}
+	if !res.Incomplete {
+		s.since = started.Add(-readNameOverlap)
+	}
+	if s.Health != nil {
+		s.Health.AddProcessed(uint64(res.RowsUpdated))
+	}
+	if res.RowsUpdated > 0 {
+		slog.Info("read name sync pass", "rows_updated", res.RowsUpdated, "incomplete", res.Incomplete)
+	}
+}
+
+// ReadNameResult reports one pass.
+type ReadNameResult struct {
+	RowsUpdated int64
+	// Incomplete is set when some entity hit MaxBatches; the next pass
+	// re-checks from the same lower bound instead of moving it forward.
+	Incomplete bool
+}
+
+// readNameSource is one denormalized name: the source rows to watch and
+// the read-model update that copies a name onto the jobs referencing one.
+type readNameSource struct {
+	label   string
+	changed string // ($1 since) → (key, name)
+	update  string // ($1 key, $2 name, $3 limit)
+}
+
+var readNameSources = []readNameSource{
+	{"subscription", `SELECT id, name FROM msg_subscriptions WHERE updated_at > $1`, syncSubscriptionNameSQL},
+	{"client", `SELECT id, name FROM tnt_clients WHERE updated_at > $1`, syncClientNameSQL},
+	{"event type", `SELECT code, name FROM msg_event_types WHERE updated_at > $1`, syncEventTypeNameSQL},
+}
+
+// Pass rewrites the names of every source row updated after since (the
+// zero time checks all of them, and continues the target_host backfill).
+// Exposed for tests and one-off backfills.
+func (s *ReadNameSync) Pass(ctx context.Context, since time.Time) (ReadNameResult, error) {
+	var res ReadNameResult
+	if since.IsZero() && !s.hostsDone {
+		n, more, err := s.backfillHosts(ctx)
+		res.RowsUpdated += n
+		res.Incomplete = more
+		if err != nil {
+			return res, fmt.Errorf("target host backfill: %w", err)
+		}
+	}
+	for _, src := range readNameSources {
+		names, err := s.changed(ctx, src.changed, since)
+		if err != nil {
+			return res, fmt.Errorf("load %s names: %w", src.label, err)
+		}
+		for _, kv := range names {
+			n, more, err := s.repeat(ctx, src.update, kv[0], kv[1])
+			res.RowsUpdated += n
+			res.Incomplete = res.Incomplete || more
+			if err != nil {
+				return res, fmt.Errorf("%s %s: %w", src.label, kv[0], err)
+			}
+		}
+	}
+	return res, nil
+}
+
+func (s *ReadNameSync) changed(ctx context.Context, sql string, since time.Time) ([][2]string, error) {
+	rows, err := s.pool.Query(ctx, sql, since)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var out [][2]string
+	for rows.Next() {
+		var kv [2]string
+		if err := rows.Scan(&kv[0], &kv[1]); err != nil {
+			return nil, err
+		}
+		out = append(out, kv)
+	}
+	return out, rows.Err()
+}
+
+// repeat runs a batched update until it affects fewer than BatchSize rows
+// or MaxBatches is reached, reporting more=true in the latter case.
+func (s *ReadNameSync) repeat(ctx context.Context, sql, key, name string) (int64, bool, error) {
+	cfg := s.cfg()
+	var total int64
+	for i := 0; i < cfg.MaxBatches; i++ {
+		tag, err := s.pool.Exec(ctx, sql, key, name, cfg.BatchSize)
+		if err != nil {
+			return total, false, err
+		}
+		total += tag.RowsAffected()
+		if tag.RowsAffected() < int64(cfg.BatchSize) {
+			return total, false, nil
+		}
+	}
+	return total, true, nil
+}
+
+// backfillHosts advances the target_host walk by up to MaxBatches
+// batches, reporting more=true when it stopped short of the end.
+func (s *ReadNameSync) backfillHosts(ctx context.Context) (int64, bool, error) {
+	cfg := s.cfg()
+	var total int64
+	for i := 0; i < cfg.MaxBatches; i++ {
+		var seen, updated int64
+		err := s.pool.QueryRow(ctx, backfillTargetHostSQL,
+			s.hostsAfter.createdAt, s.hostsAfter.id, cfg.BatchSize).
+			Scan(&s.hostsAfter.createdAt, &s.hostsAfter.id, &seen, &updated)
+		if errors.Is(err, pgx.ErrNoRows) {
+			s.hostsDone = true
+			return total, false, nil
+		}
+		if err != nil {
+			return total, false, err
+		}
+		total += updated
+		if seen < int64(cfg.BatchSize) {
+			s.hostsDone = true
+			return total, false, nil
+		}
+	}
+	return total, true, nil
+}
+
+// backfillTargetHostSQL takes the next BatchSize read rows after
+// ($1 created_at, $2 id), fills target_host on those still missing it
+// (same expression as the projection), and returns the batch's last key
+// with the rows seen and updated. No rows means the walk is done.
+const backfillTargetHostSQL = `
+WITH batch AS (
+    SELECT id, created_at, target_url FROM msg_dispatch_jobs_read
+     WHERE (created_at, id) > ($1::timestamptz, $2::text)
+     ORDER BY created_at, id
+     LIMIT $3),
+upd AS (
+    UPDATE msg_dispatch_jobs_read r
+       SET target_host = substring(b.target_url from '^[a-zA-Z][a-zA-Z0-9+.-]*://(?:[^/?#@]*@)?([^/:?#@]+)')
+      FROM batch b
+     WHERE r.id = b.id AND r.created_at = b.created_at AND r.target_host IS NULL
+    RETURNING 1)
+SELECT b.created_at, b.id, (SELECT count(*) FROM batch), (SELECT count(*) FROM upd)
+  FROM batch b
+ ORDER BY b.created_at DESC, b.id DESC
+ LIMIT 1`
+
+// Each statement picks its batch as (id, created_at) pairs — the read
+// table is partitioned on created_at — among the rows whose name differs.
+const (
+	syncSubscriptionNameSQL = `
+UPDATE msg_dispatch_jobs_read r SET subscription_name = $2
+ WHERE (r.id, r.created_at) IN (
+    SELECT id, created_at FROM msg_dispatch_jobs_read
+     WHERE subscription_id = $1 AND subscription_name IS DISTINCT FROM $2
+     LIMIT $3)`
+
+	syncClientNameSQL = `
+UPDATE msg_dispatch_jobs_read r SET client_name = $2
+ WHERE (r.id, r.created_at) IN (
+    SELECT id, created_at FROM msg_dispatch_jobs_read
+     WHERE client_id = $1 AND client_name IS DISTINCT FROM $2
+     LIMIT $3)`
+
+	syncEventTypeNameSQL = `
+UPDATE msg_dispatch_jobs_read r SET event_type_name = $2
+ WHERE (r.id, r.created_at) IN (
+    SELECT id, created_at FROM msg_dispatch_jobs_read
+     WHERE code = $1 AND event_type_name IS DISTINCT FROM $2
+     LIMIT $3)`
+)
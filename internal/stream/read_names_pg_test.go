@@ -0,0 +1,119 @@
+//go:build integration
+
+package stream
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+// TestDispatchJobReadNames pins the denormalized names: the projection
+// copies them in with the job, and ReadNameSync rewrites them after a
+// rename.
+func TestDispatchJobReadNames(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+
+	const code = "test:stream:names:created"
+	const subID = "sub_readnames0001"
+	const clientID = "clt_readnames0001"
+	_, err := pool.Exec(ctx,
+		`INSERT INTO msg_subscriptions (id, code, name, target) VALUES ($1, 'read-names', 'Order hooks', 'https://example.com/hook')`,
+		subID)
+	require.NoError(t, err)
+	_, err = pool.Exec(ctx,
+		`INSERT INTO tnt_clients (id, name, identifier) VALUES ($1, 'Acme', 'read-names-acme')`, clientID)
+	require.NoError(t, err)
+	_, err = pool.Exec(ctx,
+		`INSERT INTO msg_event_types (id, code, name, application, subdomain, aggregate)
+		 VALUES ($1, $2, 'Names created', 'test', 'stream', 'names')`,
+		tsid.GenerateUntyped(), code)
+	require.NoError(t, err)
+
+	jobID := tsid.GenerateUntyped()
+	_, err = pool.Exec(ctx,
+		`INSERT INTO msg_dispatch_jobs (id, kind, code, target_url, protocol, mode, status, max_retries,
+		                                subscription_id, client_id, updated_at)
+		 VALUES ($1, 'EVENT', $2, 'https://user@hooks.example.com:8443/in?x=1', 'HTTP_WEBHOOK',
+		         'IMMEDIATE', 'PENDING', 3, $3, $4, NOW())`,
+		jobID, code, subID, clientID)
+	require.NoError(t, err)
+
+	_, err = NewDispatchJobProjection(pool).step(ctx, 100)
+	require.NoError(t, err)
+
+	names := func() [4]*string {
+		var n [4]*string
+		require.NoError(t, pool.QueryRow(ctx,
+			`SELECT subscription_name, client_name, event_type_name, target_host
+			   FROM msg_dispatch_jobs_read WHERE id = $1`, jobID).Scan(&n[0], &n[1], &n[2], &n[3]))
+		return n
+	}
+	got := names()
+	for i, want := range []string{"Order hooks", "Acme", "Names created", "hooks.example.com"} {
+		require.NotNil(t, got[i], want)
+		assert.Equal(t, want, *got[i])
+	}
+
+	_, err = pool.Exec(ctx, `UPDATE msg_subscriptions SET name = 'Order webhooks', updated_at = NOW() WHERE id = $1`, subID)
+	require.NoError(t, err)
+	_, err = pool.Exec(ctx, `UPDATE tnt_clients SET name = 'Acme Corp', updated_at = NOW() WHERE id = $1`, clientID)
+	require.NoError(t, err)
+
+	sync := NewReadNameSync(pool)
+	res, err := sync.Pass(ctx, time.Now().Add(-time.Minute))
+	require.NoError(t, err)
+	assert.GreaterOrEqual(t, res.RowsUpdated, int64(2))
+	assert.False(t, res.Incomplete)
+	got = names()
+	assert.Equal(t, "Order webhooks", *got[0])
+	assert.Equal(t, "Acme Corp", *got[1])
+	assert.Equal(t, "Names created", *got[2], "unchanged source rows are left alone")
+
+	// A second pass finds nothing left to rewrite.
+	res, err = sync.Pass(ctx, time.Now().Add(-time.Minute))
+	require.NoError(t, err)
+	assert.Zero(t, res.RowsUpdated)
+}
+
+// TestReadNameSync_BackfillsTargetHost: a read row projected before
+// target_host existed gets it from the first (zero-since) pass.
+func TestReadNameSync_BackfillsTargetHost(t *testing.T) {
+	ctx := context.Background()
+	pool := testpg.Pool(t)
+
+	jobID := tsid.GenerateUntyped()
+	_, err := pool.Exec(ctx,
+		`INSERT INTO msg_dispatch_jobs_read (id, kind, code, target_url, protocol, mode, status, max_retries,
+		                                     created_at, updated_at)
+		 VALUES ($1, 'EVENT', 'test:stream:hosts:created', 'https://api.example.org/in', 'HTTP_WEBHOOK',
+		         'IMMEDIATE', 'PENDING', 3, NOW(), NOW())`,
+		jobID)
+	require.NoError(t, err)
+
+	host := func() *string {
+		var h *string
+		require.NoError(t, pool.QueryRow(ctx,
+			`SELECT target_host FROM msg_dispatch_jobs_read WHERE id = $1`, jobID).Scan(&h))
+		return h
+	}
+	require.Nil(t, host())
+
+	sync := NewReadNameSync(pool)
+	_, err = sync.Pass(ctx, time.Now().Add(-time.Minute))
+	require.NoError(t, err)
+	assert.Nil(t, host(), "only the zero-since pass backfills")
+
+	res, err := sync.Pass(ctx, time.Time{})
+	require.NoError(t, err)
+	assert.False(t, res.Incomplete)
+	require.NotNil(t, host())
+	assert.Equal(t, "api.example.org", *host())
+}
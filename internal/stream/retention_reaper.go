@@ -0,0 +1,267 @@
+package stream
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"time"
+
+	"github.com/jackc/pgx/v5/pgxpool"
+)
+
+// RetentionReaper enforces the per-event-type data policy
+// (msg_event_types.payload_retention_days / metadata_retention_days,
+// migration 042). Each pass, for every event type with a policy:
+//
+//   - payload retention NULLs msg_events.data + msg_events_read.data and the
+//     payload of terminal EVENT dispatch jobs for that code once they are
+//     older than the window. The envelope (ids, type, subject, timestamps,
+//     status) stays queryable.
+//   - metadata retention deletes the events (write + read rows) and the
+//     terminal dispatch jobs (write + read rows + attempts) outright.
+//
+// Events are only touched once projected and fanned out, and jobs only once
+// terminal, so the reaper never races the pipeline. The partition manager's
+// global retention still applies on top: a per-type window longer than it
+// has no effect.
+//
+// Work is done in bounded batches so a first pass over a large backlog does
+// not hold long locks; the next tick picks up where this one stopped.
+type RetentionReaper struct {
+	pool   *pgxpool.Pool
+	Health *Health
+
+	Config RetentionReaperConfig
+	// IsLeader gates each pass; nil means always-leader (single instance).
+	IsLeader func() bool
+}
+
+// RetentionReaperConfig tunes the reaper.
+type RetentionReaperConfig struct {
+	BatchSize    int           // rows per statement (default 1000)
+	MaxBatches   int           // statements per table per event type per pass (default 50)
+	TickInterval time.Duration // pass cadence (default 1h)
+}
+
+// DefaultRetentionReaperConfig returns the defaults.
+func DefaultRetentionReaperConfig() RetentionReaperConfig {
+	return RetentionReaperConfig{BatchSize: 1000, MaxBatches: 50, TickInterval: time.Hour}
+}
+
+// NewRetentionReaper wires a reaper with default config + always-leader.
+func NewRetentionReaper(pool *pgxpool.Pool) *RetentionReaper {
+	return &RetentionReaper{pool: pool}
+}
+
+// retentionPolicy is one msg_event_types row with a policy set.
+type retentionPolicy struct {
+	code         string
+	payloadDays  *int32
+	metadataDays *int32
+}
+
+// RetentionResult counts what one pass did.
+type RetentionResult struct {
+	PayloadsPurged int64
+	RowsDeleted    int64
+}
+
+func (r *RetentionReaper) cfg() RetentionReaperConfig {
+	c := r.Config
+	d := DefaultRetentionReaperConfig()
+	if c.BatchSize <= 0 {
+		c.BatchSize = d.BatchSize
+	}
+	if c.MaxBatches <= 0 {
+		c.MaxBatches = d.MaxBatches
+	}
+	if c.TickInterval <= 0 {
+		c.TickInterval = d.TickInterval
+	}
+	return c
+}
+
+func (r *RetentionReaper) leader() bool {
+	if r.IsLeader == nil {
+		return true
+	}
+	return r.IsLeader()
+}
+
+// Run ticks once on startup, then every Config.TickInterval, until ctx is
+// cancelled.
+func (r *RetentionReaper) Run(ctx context.Context) {
+	cfg := r.cfg()
+	if r.Health != nil {
+		r.Health.SetRunning(true)
+		defer r.Health.SetRunning(false)
+	}
+	r.runPass(ctx)
+
+	tick := time.NewTicker(cfg.TickInterval)
+	defer tick.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("retention reaper stopped")
+			return
+		case <-tick.C:
+			r.runPass(ctx)
+		}
+	}
+}
+
+func (r *RetentionReaper) runPass(ctx context.Context) {
+	if !r.leader() {
+		return
+	}
+	res, err := r.Pass(ctx, time.Now().UTC())
+	if err != nil {
+		slog.Warn("retention reaper pass failed", "err", err)
+		if r.Health != nil {
+			r.Health.RecordError()
+		}
+		return
+	}
+	if r.Health != nil {
+		r.Health.AddProcessed(uint64(res.PayloadsPurged + res.RowsDeleted))
+	}
+	if res.PayloadsPurged > 0 || res.RowsDeleted > 0 {
+		slog.Info("retention reaper pass", "payloads_purged", res.PayloadsPurged, "rows_deleted", res.RowsDeleted)
+	}
+}
+
+// Pass runs one sweep over every event type with a policy, with cutoffs
+// relative to now. Exposed for tests and one-off runs.
+func (r *RetentionReaper) Pass(ctx context.Context, now time.Time) (RetentionResult, error) {
+	var res RetentionResult
+	policies, err := r.loadPolicies(ctx)
+	if err != nil {
+		return res, fmt.Errorf("load policies: %w", err)
+	}
+	for _, p := range policies {
+		// Deleting first means the payload purge skips rows that are about
+		// to go anyway.
+		if p.metadataDays != nil {
+			cutoff := now.AddDate(0, 0, -int(*p.metadataDays))
+			n, err := r.repeat(ctx, deleteEventsSQL, p.code, cutoff)
+			res.RowsDeleted += n
+			if err != nil {
+				return res, fmt.Errorf("%s: delete events: %w", p.code, err)
+			}
+			n, err = r.repeat(ctx, deleteJobsSQL, p.code, cutoff)
+			res.RowsDeleted += n
+			if err != nil {
+				return res, fmt.Errorf("%s: delete dispatch jobs: %w", p.code, err)
+			}
+		}
+		if p.payloadDays != nil {
+			cutoff := now.AddDate(0, 0, -int(*p.payloadDays))
+			n, err := r.repeat(ctx, purgeEventDataSQL, p.code, cutoff)
+			res.PayloadsPurged += n
+			if err != nil {
+				return res, fmt.Errorf("%s: purge event data: %w", p.code, err)
+			}
+			n, err = r.repeat(ctx, purgeJobPayloadSQL, p.code, cutoff)
+			res.PayloadsPurged += n
+			if err != nil {
+				return res, fmt.Errorf("%s: purge dispatch payloads: %w", p.code, err)
+			}
+		}
+	}
+	return res, nil
+}
+
+func (r *RetentionReaper) loadPolicies(ctx context.Context) ([]retentionPolicy, error) {
+	rows, err := r.pool.Query(ctx,
+		`SELECT code, payload_retention_days, metadata_retention_days
+		   FROM msg_event_types
+		  WHERE payload_retention_days IS NOT NULL OR metadata_retention_days IS NOT NULL`)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+	var out []retentionPolicy
+	for rows.Next() {
+		var p retentionPolicy
+		if err := rows.Scan(&p.code, &p.payloadDays, &p.metadataDays); err != nil {
+			return nil, err
+		}
+		out = append(out, p)
+	}
+	return out, rows.Err()
+}
+
+// repeat runs a batched statement until it affects fewer than BatchSize
+// rows or MaxBatches is reached.
+func (r *RetentionReaper) repeat(ctx context.Context, sql, code string, cutoff time.Time) (int64, error) {
+	cfg := r.cfg()
+	var total int64
+	for i := 0; i < cfg.MaxBatches; i++ {
+		tag, err := r.pool.Exec(ctx, sql, code, cutoff, cfg.BatchSize)
+		if err != nil {
+			return total, err
+		}
+		total += tag.RowsAffected()
+		if tag.RowsAffected() < int64(cfg.BatchSize) {
+			break
+		}
+	}
+	return total, nil
+}
+
+// The write tables are partitioned on (id, created_at), so every statement
+// picks its batch as (id, created_at) pairs and joins back on both. Read
+// rows share the id (and partition) of their source row. The status list
+// is common.DispatchStatus.IsTerminal.
+const (
+	purgeEventDataSQL = `
+WITH batch AS (
+    SELECT id, created_at FROM msg_events
+     WHERE type = $1 AND created_at < $2 AND data IS NOT NULL
+       AND projected_at IS NOT NULL AND fanned_out_at IS NOT NULL
+     LIMIT $3
+), read_rows AS (
+    UPDATE msg_events_read r SET data = NULL
+      FROM batch b WHERE r.id = b.id AND r.created_at = b.created_at
+)
+UPDATE msg_events e SET data = NULL
+  FROM batch b WHERE e.id = b.id AND e.created_at = b.created_at`
+
+	purgeJobPayloadSQL = `
+UPDATE msg_dispatch_jobs j SET payload = NULL
+ WHERE (j.id, j.created_at) IN (
+    SELECT id, created_at FROM msg_dispatch_jobs
+     WHERE kind = 'EVENT' AND code = $1 AND created_at < $2 AND payload IS NOT NULL
+       AND status IN ('COMPLETED', 'FAILED', 'CANCELLED', 'EXPIRED')
+     LIMIT $3)`
+
+	deleteEventsSQL = `
+WITH batch AS (
+    SELECT id, created_at FROM msg_events
+     WHERE type = $1 AND created_at < $2
+       AND projected_at IS NOT NULL AND fanned_out_at IS NOT NULL
+     LIMIT $3
+), read_rows AS (
+    DELETE FROM msg_events_read r
+     USING batch b WHERE r.id = b.id AND r.created_at = b.created_at
+)
+DELETE FROM msg_events e
+ USING batch b WHERE e.id = b.id AND e.created_at = b.created_at`
+
+	deleteJobsSQL = `
+WITH batch AS (
+    SELECT id, created_at FROM msg_dispatch_jobs
+     WHERE kind = 'EVENT' AND code = $1 AND created_at < $2
+       AND status IN ('COMPLETED', 'FAILED', 'CANCELLED', 'EXPIRED')
+     LIMIT $3
+), attempts AS (
+    DELETE FROM msg_dispatch_job_attempts a
+     USING batch b WHERE a.dispatch_job_id = b.id
+), read_rows AS (
+    DELETE FROM msg_dispatch_jobs_read r
+     USING batch b WHERE r.id = b.id
+)
+DELETE FROM msg_dispatch_jobs j
+ USING batch b WHERE j.id = b.id AND j.created_at = b.created_at`
+)
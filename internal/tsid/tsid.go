@@ -70,6 +70,26 @@ const (
 	MfaEmailPin
 	MfaTrustedDevice
 	ResetApprovalRequest
+	// Go-only: per-client payload data keys (internal/platform/shared/payloadcrypt).
+	ClientDataKey
+	// Go-only: data-subject erasure requests (internal/platform/privacy).
+	ErasureRequest
+	// Go-only: per-client webhook signing keys (internal/platform/signingkey).
+	ClientSigningKey
+	// Go-only: incident kill switches (internal/platform/killswitch).
+	KillSwitch
+	// Go-only: anomaly detection (internal/platform/anomaly).
+	AnomalySetting
+	Anomaly
+	// Go-only: multi-cluster federation peers (internal/platform/federation).
+	FederationPeer
+	// Go-only: event lake manifest entries and replays (internal/platform/archive).
+	ArchiveFile
+	ArchiveReplay
+	// Go-only: blue/green shadow deliveries (internal/platform/subscription).
+	ShadowDelivery
+	// Go-only: producer bindings (internal/platform/application).
+	ProducerBinding
 )
 
 // Prefix returns the 3-character prefix for this entity type. Mirrors
@@ -162,6 +182,28 @@ func (e EntityType) Prefix() string {
 		return "mtd"
 	case ResetApprovalRequest:
 		return "rar"
+	case ClientDataKey:
+		return "cdk"
+	case ErasureRequest:
+		return "era"
+	case ClientSigningKey:
+		return "csk"
+	case KillSwitch:
+		return "ksw"
+	case AnomalySetting:
+		return "ans"
+	case Anomaly:
+		return "anm"
+	case FederationPeer:
+		return "fdp"
+	case ArchiveFile:
+		return "arf"
+	case ArchiveReplay:
+		return "arp"
+	case ShadowDelivery:
+		return "shd"
+	case ProducerBinding:
+		return "pdb"
 	default:
 		return "unk"
 	}
@@ -19,6 +19,21 @@ func (c *FlowCatalystClient) DispatchPools() *DispatchPoolsResource {
 	return &DispatchPoolsResource{c: c}
 }
 
+// DispatchJobs returns the dispatch-jobs resource accessor — /api/dispatch-jobs/*.
+func (c *FlowCatalystClient) DispatchJobs() *DispatchJobsResource {
+	return &DispatchJobsResource{c: c}
+}
+
+// Consume returns the pull delivery accessor — /api/consume/*.
+func (c *FlowCatalystClient) Consume() *ConsumeResource {
+	return &ConsumeResource{c: c}
+}
+
+// Federation returns the federation feed accessor — /api/federation/*.
+func (c *FlowCatalystClient) Federation() *FederationResource {
+	return &FederationResource{c: c}
+}
+
 // Applications returns the applications resource accessor — /api/applications/*.
 func (c *FlowCatalystClient) Applications() *ApplicationsResource {
 	return &ApplicationsResource{c: c}
@@ -0,0 +1,102 @@
+package client
+
+import (
+	"context"
+	"encoding/json"
+	"strconv"
+	"time"
+)
+
+// ConsumeOptions tunes GET /api/consume/{subscriptionId}. Zero values take
+// the platform defaults.
+type ConsumeOptions struct {
+	// Wait is how long the platform holds the request when no message is
+	// due (at most 1m). Keep it below the client's timeout (WithTimeout,
+	// default 30s).
+	Wait time.Duration
+	// Max caps the messages leased (default 10, at most 100).
+	Max int
+	// Lease is how long the messages stay leased before they count as
+	// failed (default 1m).
+	Lease time.Duration
+}
+
+// ConsumedMessage is one leased message of a pull subscription.
+type ConsumedMessage struct {
+	ID             string          `json:"id"`
+	LeaseToken     string          `json:"leaseToken"`
+	LeaseExpiresAt string          `json:"leaseExpiresAt"`
+	Type           string          `json:"type"`
+	EventID        string          `json:"eventId,omitempty"`
+	AttemptNumber  int32           `json:"attemptNumber"`
+	Body           json.RawMessage `json:"body"`
+}
+
+// ConsumeResponse — GET /api/consume/{subscriptionId}.
+type ConsumeResponse struct {
+	Messages []ConsumedMessage `json:"messages"`
+}
+
+// ConsumeAckRequest — POST /api/consume/{subscriptionId}/ack.
+type ConsumeAckRequest struct {
+	ID         string `json:"id"`
+	LeaseToken string `json:"leaseToken"`
+}
+
+// ConsumeNackRequest — POST /api/consume/{subscriptionId}/nack.
+type ConsumeNackRequest struct {
+	ID         string `json:"id"`
+	LeaseToken string `json:"leaseToken"`
+	// Error is recorded as the job's last error.
+	Error string `json:"error,omitempty"`
+}
+
+// ConsumeResource — /api/consume/*, the pull delivery channel.
+type ConsumeResource struct {
+	c *FlowCatalystClient
+}
+
+// Fetch — GET /api/consume/{subscriptionId}. Leases the pull
+// subscription's due messages; an empty Messages means none arrived
+// within opts.Wait.
+func (r *ConsumeResource) Fetch(ctx context.Context, subscriptionID string, opts *ConsumeOptions) (*ConsumeResponse, error) {
+	q := ""
+	if opts != nil {
+		b := NewQuery()
+		if opts.Wait > 0 {
+			b.String("wait", opts.Wait.String())
+		}
+		if opts.Max > 0 {
+			b.String("max", strconv.Itoa(opts.Max))
+		}
+		if opts.Lease > 0 {
+			b.String("lease", opts.Lease.String())
+		}
+		q = b.Encode()
+	}
+	var out ConsumeResponse
+	if err := r.c.Get(ctx, "/api/consume/"+subscriptionID+q, &out); err != nil {
+		return nil, err
+	}
+	return &out, nil
+}
+
+// Ack — POST /api/consume/{subscriptionId}/ack. Completes a leased message.
+func (r *ConsumeResource) Ack(ctx context.Context, subscriptionID string, m ConsumedMessage) (*AckDispatchJobResponse, error) {
+	var out AckDispatchJobResponse
+	if err := r.c.Post(ctx, "/api/consume/"+subscriptionID+"/ack", &ConsumeAckRequest{ID: m.ID, LeaseToken: m.LeaseToken}, &out); err != nil {
+		return nil, err
+	}
+	return &out, nil
+}
+
+// Nack — POST /api/consume/{subscriptionId}/nack. Fails a leased
+// message's attempt; the platform redelivers it after the subscription's
+// backoff, or fails the job on its last attempt.
+func (r *ConsumeResource) Nack(ctx context.Context, subscriptionID string, m ConsumedMessage, reason string) (*AckDispatchJobResponse, error) {
+	var out AckDispatchJobResponse
+	if err := r.c.Post(ctx, "/api/consume/"+subscriptionID+"/nack", &ConsumeNackRequest{ID: m.ID, LeaseToken: m.LeaseToken, Error: reason}, &out); err != nil {
+		return nil, err
+	}
+	return &out, nil
+}
@@ -0,0 +1,48 @@
+package client
+
+import "context"
+
+// AckDispatchJobRequest — POST /api/dispatch-jobs/{id}/ack.
+type AckDispatchJobRequest struct {
+	// AckToken is the token the webhook handler returned with its 202.
+	AckToken string `json:"ackToken"`
+}
+
+// NackDispatchJobRequest — POST /api/dispatch-jobs/{id}/nack.
+type NackDispatchJobRequest struct {
+	AckToken string `json:"ackToken"`
+	// Error is recorded as the job's last error.
+	Error string `json:"error,omitempty"`
+}
+
+// AckDispatchJobResponse is the job's status once settled: COMPLETED
+// after an ack; PENDING (retry scheduled) or FAILED after a nack.
+type AckDispatchJobResponse struct {
+	ID     string `json:"id"`
+	Status string `json:"status"`
+}
+
+// DispatchJobsResource — /api/dispatch-jobs/*.
+type DispatchJobsResource struct {
+	c *FlowCatalystClient
+}
+
+// Ack — POST /api/dispatch-jobs/{id}/ack. Completes a delivery the
+// webhook handler accepted with 202 and an ack token.
+func (r *DispatchJobsResource) Ack(ctx context.Context, id, ackToken string) (*AckDispatchJobResponse, error) {
+	var out AckDispatchJobResponse
+	if err := r.c.Post(ctx, "/api/dispatch-jobs/"+id+"/ack", &AckDispatchJobRequest{AckToken: ackToken}, &out); err != nil {
+		return nil, err
+	}
+	return &out, nil
+}
+
+// Nack — POST /api/dispatch-jobs/{id}/nack. Fails such a delivery's
+// attempt; the platform retries it, or fails the job on its last attempt.
+func (r *DispatchJobsResource) Nack(ctx context.Context, id, ackToken, reason string) (*AckDispatchJobResponse, error) {
+	var out AckDispatchJobResponse
+	if err := r.c.Post(ctx, "/api/dispatch-jobs/"+id+"/nack", &NackDispatchJobRequest{AckToken: ackToken, Error: reason}, &out); err != nil {
+		return nil, err
+	}
+	return &out, nil
+}
@@ -70,6 +70,9 @@ func (e *APIError) IsForbidden() bool { return e.StatusCode == http.StatusForbid
 // IsConflict is a convenience for status 409.
 func (e *APIError) IsConflict() bool { return e.StatusCode == http.StatusConflict }
 
+// IsPreconditionFailed is a convenience for status 412 (a stale If-Match).
+func (e *APIError) IsPreconditionFailed() bool { return e.StatusCode == http.StatusPreconditionFailed }
+
 // codeAndMessage attempts to parse the body as a structured error
 // envelope. Falls back to ("","") on any parsing failure.
 func (e *APIError) codeAndMessage() (string, string) {
@@ -35,6 +35,9 @@ type UpdateEventTypeRequest struct {
 // AddSchemaVersionRequest is the body for POST /api/event-types/{id}/versions.
 type AddSchemaVersionRequest struct {
 	Schema json.RawMessage `json:"schema"`
+	// SchemaType is JSON_SCHEMA (default) or PROTO, whose Schema is
+	// {"messageType": ..., "fileDescriptorSet": ...}.
+	SchemaType string `json:"schemaType,omitempty"`
 }
 
 // EventTypeResponse is the platform's event-type representation.
@@ -55,9 +58,10 @@ type EventTypeResponse struct {
 
 // SpecVersionResponse is one schema version on an event type.
 type SpecVersionResponse struct {
-	Version string          `json:"version"`
-	Status  string          `json:"status"`
-	Schema  json.RawMessage `json:"schema,omitempty"`
+	Version    string          `json:"version"`
+	Status     string          `json:"status"`
+	Schema     json.RawMessage `json:"schema,omitempty"`
+	SchemaType string          `json:"schemaType,omitempty"`
 }
 
 // SyncEventTypesRequest is the body for the per-app sync endpoint.
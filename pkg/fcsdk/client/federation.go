@@ -0,0 +1,76 @@
+package client
+
+import (
+	"context"
+	"encoding/json"
+	"strconv"
+	"strings"
+)
+
+// FederationFeedOptions selects a page of GET /api/federation/feed.
+type FederationFeedOptions struct {
+	// Types are the event type codes to read. Required.
+	Types []string
+	// After is the Cursor of the previous page; "" starts from the
+	// beginning of the instance's retained events.
+	After string
+	// Limit caps the events returned (default 100, at most 500).
+	Limit int
+}
+
+// FederationContextEntry is a key/value pair of a federated event's
+// context.
+type FederationContextEntry struct {
+	Key   string `json:"key"`
+	Value string `json:"value"`
+}
+
+// FederatedEvent is one event of a peer's federation feed. Origin is the
+// instance it was first produced on and Hops how many instances it has
+// been forwarded across before this one.
+type FederatedEvent struct {
+	ID               string                   `json:"id"`
+	SpecVersion      string                   `json:"specVersion"`
+	Type             string                   `json:"type"`
+	Source           string                   `json:"source"`
+	Subject          string                   `json:"subject"`
+	Time             string                   `json:"time"`
+	Data             json.RawMessage          `json:"data,omitempty"`
+	DeduplicationID  string                   `json:"deduplicationId"`
+	ClientIdentifier string                   `json:"clientIdentifier,omitempty"`
+	MessageGroup     string                   `json:"messageGroup,omitempty"`
+	CorrelationID    string                   `json:"correlationId,omitempty"`
+	CausationID      string                   `json:"causationId,omitempty"`
+	Context          []FederationContextEntry `json:"context"`
+	Origin           string                   `json:"origin"`
+	Hops             int                      `json:"hops"`
+}
+
+// FederationFeedResponse — GET /api/federation/feed.
+type FederationFeedResponse struct {
+	// InstanceID is the serving instance's FC_FEDERATION_INSTANCE_ID.
+	InstanceID string           `json:"instanceId"`
+	Events     []FederatedEvent `json:"events"`
+	// Cursor is passed as After to read the next page.
+	Cursor  string `json:"cursor"`
+	HasMore bool   `json:"hasMore"`
+}
+
+// FederationResource — /api/federation/*, read by peer instances.
+type FederationResource struct {
+	c *FlowCatalystClient
+}
+
+// Feed — GET /api/federation/feed. Reads the next page of the instance's
+// events of opts.Types, oldest first. Anchor-only.
+func (r *FederationResource) Feed(ctx context.Context, opts FederationFeedOptions) (*FederationFeedResponse, error) {
+	b := NewQuery().String("types", strings.Join(opts.Types, ",")).String("after", opts.After)
+	if opts.Limit > 0 {
+		b.String("limit", strconv.Itoa(opts.Limit))
+	}
+	var out FederationFeedResponse
+	if err := r.c.Get(ctx, "/api/federation/feed"+b.Encode(), &out); err != nil {
+		return nil, err
+	}
+	return &out, nil
+}
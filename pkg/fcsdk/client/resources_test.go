@@ -8,6 +8,7 @@ import (
 	"net/http/httptest"
 	"net/url"
 	"testing"
+	"time"
 
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -175,6 +176,68 @@ func TestRouterInPipelineBatch(t *testing.T) {
 	assert.False(t, out["msg_2"])
 }
 
+func TestDispatchJobsAckAndNack(t *testing.T) {
+	srv, seen := newMockSrv(t, `{"id":"dj_1","status":"COMPLETED"}`)
+	c := client.New(srv.URL)
+
+	r, err := c.DispatchJobs().Ack(context.Background(), "dj_1", "tok-1")
+	require.NoError(t, err)
+	assert.Equal(t, http.MethodPost, seen.method)
+	assert.Equal(t, "/api/dispatch-jobs/dj_1/ack", seen.path)
+	assert.JSONEq(t, `{"ackToken":"tok-1"}`, seen.body)
+	assert.Equal(t, "COMPLETED", r.Status)
+
+	_, err = c.DispatchJobs().Nack(context.Background(), "dj_1", "tok-1", "downstream rejected")
+	require.NoError(t, err)
+	assert.Equal(t, "/api/dispatch-jobs/dj_1/nack", seen.path)
+	assert.JSONEq(t, `{"ackToken":"tok-1","error":"downstream rejected"}`, seen.body)
+}
+
+func TestConsumeFetchAckAndNack(t *testing.T) {
+	srv, seen := newMockSrv(t, `{"messages":[{"id":"dj_1","leaseToken":"lt-1","leaseExpiresAt":"","type":"orders:created","attemptNumber":1,"body":{"a":1}}]}`)
+	c := client.New(srv.URL)
+
+	r, err := c.Consume().Fetch(context.Background(), "sub_1", &client.ConsumeOptions{Wait: 20 * time.Second, Max: 5})
+	require.NoError(t, err)
+	assert.Equal(t, http.MethodGet, seen.method)
+	assert.Equal(t, "/api/consume/sub_1", seen.path)
+	assert.Equal(t, "20s", seen.query.Get("wait"))
+	assert.Equal(t, "5", seen.query.Get("max"))
+	assert.Empty(t, seen.query.Get("lease"))
+	require.Len(t, r.Messages, 1)
+	assert.JSONEq(t, `{"a":1}`, string(r.Messages[0].Body))
+
+	_, err = c.Consume().Ack(context.Background(), "sub_1", r.Messages[0])
+	require.NoError(t, err)
+	assert.Equal(t, "/api/consume/sub_1/ack", seen.path)
+	assert.JSONEq(t, `{"id":"dj_1","leaseToken":"lt-1"}`, seen.body)
+
+	_, err = c.Consume().Nack(context.Background(), "sub_1", r.Messages[0], "bad row")
+	require.NoError(t, err)
+	assert.Equal(t, "/api/consume/sub_1/nack", seen.path)
+	assert.JSONEq(t, `{"id":"dj_1","leaseToken":"lt-1","error":"bad row"}`, seen.body)
+}
+
+func TestFederationFeedBuildsQuery(t *testing.T) {
+	srv, seen := newMockSrv(t, `{"instanceId":"eu-1","events":[{"id":"evn_1","specVersion":"1.0","type":"orders:sales:order:created","source":"s","subject":"","time":"","deduplicationId":"d","context":[],"origin":"eu-1","hops":0}],"cursor":"c2","hasMore":true}`)
+	c := client.New(srv.URL)
+
+	r, err := c.Federation().Feed(context.Background(), client.FederationFeedOptions{
+		Types: []string{"orders:sales:order:created", "orders:sales:order:shipped"}, After: "c1", Limit: 50,
+	})
+	require.NoError(t, err)
+	assert.Equal(t, http.MethodGet, seen.method)
+	assert.Equal(t, "/api/federation/feed", seen.path)
+	assert.Equal(t, "orders:sales:order:created,orders:sales:order:shipped", seen.query.Get("types"))
+	assert.Equal(t, "c1", seen.query.Get("after"))
+	assert.Equal(t, "50", seen.query.Get("limit"))
+	assert.Equal(t, "eu-1", r.InstanceID)
+	assert.Equal(t, "c2", r.Cursor)
+	assert.True(t, r.HasMore)
+	require.Len(t, r.Events, 1)
+	assert.Equal(t, "eu-1", r.Events[0].Origin)
+}
+
 func TestScheduledJobsCreateAndFire(t *testing.T) {
 	srv, seen := newMockSrv(t, `{"id":"sjb_1"}`)
 	c := client.New(srv.URL)
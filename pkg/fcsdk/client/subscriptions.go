@@ -11,7 +11,9 @@ type SubscriptionListResponse struct {
 	Total         uint64                 `json:"total,omitempty"`
 }
 
-// ConfigEntry is a per-subscription custom config key/value.
+// ConfigEntry is a per-subscription custom config key/value, also used for
+// custom delivery headers (whose value may be a secret reference such as
+// "env://TENANT_API_KEY").
 type ConfigEntry struct {
 	Key   string `json:"key"`
 	Value string `json:"value"`
@@ -24,31 +26,87 @@ type EventTypeBinding struct {
 	Filter        string `json:"filter,omitempty"`
 }
 
+// Batching groups a push subscription's due dispatch jobs into one
+// webhook call whose body is a JSON array. The endpoint may answer with
+// {"results":[{"id","status","error","delaySeconds"}]} to settle each job
+// on its own.
+type Batching struct {
+	MaxItems       int32 `json:"maxItems"`
+	MaxBytes       int32 `json:"maxBytes,omitempty"`
+	MaxWaitSeconds int32 `json:"maxWaitSeconds,omitempty"`
+}
+
+// TLSTrust is extra TLS trust for a receiver behind a private CA, on top
+// of the platform's own: PEM roots, "sha256/<base64>" SPKI pins (one must
+// match a certificate in the verified chain) and a minimum version, "1.2"
+// or "1.3".
+type TLSTrust struct {
+	CABundle   string   `json:"caBundle,omitempty"`
+	SPKIPins   []string `json:"spkiPins,omitempty"`
+	MinVersion string   `json:"minVersion,omitempty"`
+}
+
 // CreateSubscriptionRequest — POST /api/subscriptions.
 type CreateSubscriptionRequest struct {
 	Code             string             `json:"code"`
 	Name             string             `json:"name"`
 	Description      string             `json:"description,omitempty"`
-	Endpoint         string             `json:"endpoint"`
+	Endpoint         string             `json:"endpoint,omitempty"`
 	ConnectionID     string             `json:"connectionId,omitempty"`
 	EventTypes       []EventTypeBinding `json:"eventTypes,omitempty"`
 	ClientID         string             `json:"clientId,omitempty"`
 	DispatchPoolID   string             `json:"dispatchPoolId,omitempty"`
 	ServiceAccountID string             `json:"serviceAccountId,omitempty"`
+	Headers          []ConfigEntry      `json:"headers,omitempty"`
+	Transport        string             `json:"transport,omitempty"`
 	Mode             string             `json:"mode,omitempty"`
 	TimeoutSeconds   *uint32            `json:"timeoutSeconds,omitempty"`
 	MaxRetries       *uint32            `json:"maxRetries,omitempty"`
 	DataOnly         bool               `json:"dataOnly,omitempty"`
+	// Delivery is PUSH (default) or PULL; a PULL subscription needs no
+	// endpoint, its consumer fetches through Consume().
+	Delivery string `json:"delivery,omitempty"`
+	// Batching, when MaxItems > 1, delivers jobs in arrays.
+	Batching *Batching `json:"batching,omitempty"`
+	// PayloadFormat is JSON (default), CLOUDEVENTS_JSON, NDJSON or
+	// PROTOBUF; the webhook package's Decode reads each of them.
+	PayloadFormat string `json:"payloadFormat,omitempty"`
+	// LargePayloads is INLINE (default) or PRESIGNED_URL: how a payload
+	// the platform offloaded to its blob store is delivered.
+	LargePayloads string `json:"largePayloads,omitempty"`
+	// ShadowEndpoint also receives a copy of each push delivery, so a new
+	// endpoint can be compared through ShadowReport before PromoteShadow.
+	ShadowEndpoint string `json:"shadowEndpoint,omitempty"`
+	// TLS trusts a private CA, or pins the endpoint's keys.
+	TLS *TLSTrust `json:"tls,omitempty"`
 }
 
 // UpdateSubscriptionRequest — PUT /api/subscriptions/{id}.
 type UpdateSubscriptionRequest struct {
-	Name           *string `json:"name,omitempty"`
-	Description    *string `json:"description,omitempty"`
-	Endpoint       *string `json:"endpoint,omitempty"`
-	ConnectionID   *string `json:"connectionId,omitempty"`
-	TimeoutSeconds *uint32 `json:"timeoutSeconds,omitempty"`
-	MaxRetries     *uint32 `json:"maxRetries,omitempty"`
+	Name           *string       `json:"name,omitempty"`
+	Description    *string       `json:"description,omitempty"`
+	Endpoint       *string       `json:"endpoint,omitempty"`
+	ConnectionID   *string       `json:"connectionId,omitempty"`
+	TimeoutSeconds *uint32       `json:"timeoutSeconds,omitempty"`
+	MaxRetries     *uint32       `json:"maxRetries,omitempty"`
+	Headers        []ConfigEntry `json:"headers,omitempty"`
+	// Transport names the outbound transport to deliver through; a
+	// pointer to "" switches back to direct delivery.
+	Transport *string `json:"transport,omitempty"`
+	// Delivery switches between PUSH and PULL; PUSH needs an endpoint.
+	Delivery *string `json:"delivery,omitempty"`
+	// Batching replaces the batching limits; MaxItems 0 switches it off.
+	Batching *Batching `json:"batching,omitempty"`
+	// PayloadFormat switches how deliveries are serialized.
+	PayloadFormat *string `json:"payloadFormat,omitempty"`
+	// LargePayloads switches how offloaded payloads are delivered.
+	LargePayloads *string `json:"largePayloads,omitempty"`
+	// ShadowEndpoint sets the endpoint deliveries are copied to; a
+	// pointer to "" stops shadowing.
+	ShadowEndpoint *string `json:"shadowEndpoint,omitempty"`
+	// TLS replaces the subscription's TLS trust; a pointer to an empty
+	// TLSTrust drops it.
+	TLS *TLSTrust `json:"tls,omitempty"`
 }
 
 // SubscriptionResponse is the platform's subscription representation.
@@ -78,10 +136,81 @@ type SubscriptionResponse struct {
 	ApplicationCode  string             `json:"applicationCode,omitempty"`
 	ClientScoped     bool               `json:"clientScoped,omitempty"`
 	CustomConfig     []ConfigEntry      `json:"customConfig,omitempty"`
+	Headers          []ConfigEntry      `json:"headers,omitempty"`
+	Transport        string             `json:"transport,omitempty"`
+	TLS              *TLSTrust          `json:"tls,omitempty"`
+	Delivery         string             `json:"delivery,omitempty"`
+	Batching         *Batching          `json:"batching,omitempty"`
+	PayloadFormat    string             `json:"payloadFormat,omitempty"`
+	LargePayloads    string             `json:"largePayloads,omitempty"`
+	ShadowEndpoint   string             `json:"shadowEndpoint,omitempty"`
+	TrafficSplit     []SplitTarget      `json:"trafficSplit,omitempty"`
 	CreatedAt        string             `json:"createdAt"`
 	UpdatedAt        string             `json:"updatedAt"`
 }
 
+// ShadowSide is one endpoint's side of a ShadowReport.
+type ShadowSide struct {
+	Successes int64 `json:"successes"`
+	AvgMs     int64 `json:"avgMs"`
+	P50Ms     int64 `json:"p50Ms"`
+	P95Ms     int64 `json:"p95Ms"`
+}
+
+// ShadowOutcome counts the deliveries that got one combination of answers.
+// A status code is nil when no response came back.
+type ShadowOutcome struct {
+	PrimaryStatusCode *int32 `json:"primaryStatusCode,omitempty"`
+	PrimarySuccess    bool   `json:"primarySuccess"`
+	ShadowStatusCode  *int32 `json:"shadowStatusCode,omitempty"`
+	ShadowSuccess     bool   `json:"shadowSuccess"`
+	Deliveries        int64  `json:"deliveries"`
+}
+
+// ShadowReport — GET /api/subscriptions/{id}/shadow-report.
+type ShadowReport struct {
+	ShadowEndpoint string          `json:"shadowEndpoint"`
+	Since          string          `json:"since"`
+	Deliveries     int64           `json:"deliveries"`
+	Agreed         int64           `json:"agreed"`
+	Primary        ShadowSide      `json:"primary"`
+	Shadow         ShadowSide      `json:"shadow"`
+	Outcomes       []ShadowOutcome `json:"outcomes"`
+}
+
+// SplitTarget is one extra endpoint of a subscription's traffic split and
+// the percentage of message groups routed to it; the endpoint keeps the
+// rest.
+type SplitTarget struct {
+	Endpoint string `json:"endpoint"`
+	Weight   int32  `json:"weight"`
+}
+
+// SetTrafficSplitRequest — PUT /api/subscriptions/{id}/traffic-split.
+type SetTrafficSplitRequest struct {
+	Split []SplitTarget `json:"split"`
+}
+
+// TargetStats is how one of a subscription's endpoints fared. Weight is 0
+// for an endpoint no longer routed to.
+type TargetStats struct {
+	Endpoint  string `json:"endpoint"`
+	Weight    int32  `json:"weight"`
+	Jobs      int64  `json:"jobs"`
+	Completed int64  `json:"completed"`
+	Failed    int64  `json:"failed"`
+	Attempts  int64  `json:"attempts"`
+	Successes int64  `json:"successes"`
+	AvgMs     int64  `json:"avgMs"`
+	P95Ms     int64  `json:"p95Ms"`
+}
+
+// TargetStatsResponse — GET /api/subscriptions/{id}/target-stats.
+type TargetStatsResponse struct {
+	Since   string        `json:"since"`
+	Targets []TargetStats `json:"targets"`
+}
+
 // SyncSubscriptionItem matches the platform's SyncSubscriptionInput.
 type SyncSubscriptionItem struct {
 	Code             string             `json:"code"`
@@ -154,6 +283,40 @@ func (r *SubscriptionsResource) Resume(ctx context.Context, id string) error {
 	return r.c.Post(ctx, "/api/subscriptions/"+id+"/resume", nil, nil)
 }
 
+// ShadowReport — GET /api/subscriptions/{id}/shadow-report?since=. An
+// empty since covers the last 24 hours.
+func (r *SubscriptionsResource) ShadowReport(ctx context.Context, id, since string) (*ShadowReport, error) {
+	var out ShadowReport
+	if err := r.c.Get(ctx, "/api/subscriptions/"+id+"/shadow-report"+EncodeQuery("since", since), &out); err != nil {
+		return nil, err
+	}
+	return &out, nil
+}
+
+// PromoteShadow — POST /api/subscriptions/{id}/promote-shadow.
+func (r *SubscriptionsResource) PromoteShadow(ctx context.Context, id string) error {
+	return r.c.Post(ctx, "/api/subscriptions/"+id+"/promote-shadow", nil, nil)
+}
+
+// SetTrafficSplit — PUT /api/subscriptions/{id}/traffic-split. An empty
+// split sends every message group back to the endpoint.
+func (r *SubscriptionsResource) SetTrafficSplit(ctx context.Context, id string, split []SplitTarget) error {
+	if split == nil {
+		split = []SplitTarget{}
+	}
+	return r.c.Put(ctx, "/api/subscriptions/"+id+"/traffic-split", &SetTrafficSplitRequest{Split: split}, nil)
+}
+
+// TargetStats — GET /api/subscriptions/{id}/target-stats?since=. An empty
+// since covers the last 24 hours.
+func (r *SubscriptionsResource) TargetStats(ctx context.Context, id, since string) (*TargetStatsResponse, error) {
+	var out TargetStatsResponse
+	if err := r.c.Get(ctx, "/api/subscriptions/"+id+"/target-stats"+EncodeQuery("since", since), &out); err != nil {
+		return nil, err
+	}
+	return &out, nil
+}
+
 // Delete — DELETE /api/subscriptions/{id}.
 func (r *SubscriptionsResource) Delete(ctx context.Context, id string) error {
 	return r.c.Delete(ctx, "/api/subscriptions/"+id, nil)
@@ -79,7 +79,8 @@
 //   - webhook    — Two HMAC-SHA256 validators. Verifier matches this Go
 //     platform's router (uppercase headers, ISO8601
 //     timestamps); Validator matches the Rust SDK shape
-//     (mixed-case headers, Unix-second timestamps).
+//     (mixed-case headers, Unix-second timestamps). Decode
+//     splits a delivery into its jobs in any payload format.
 //   - sync       — DefinitionSet + Synchronizer for declarative
 //     reconciliation. One call per category.
 //   - scheduledjobs — consumer-side Runner. Register HandlerFuncs by
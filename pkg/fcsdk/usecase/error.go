@@ -15,6 +15,7 @@ const (
 	KindAuthorization Kind = "authorization"
 	KindNotFound      Kind = "not_found"
 	KindConflict      Kind = "conflict"
+	KindPrecondition  Kind = "precondition_failed"
 	KindInternal      Kind = "internal"
 )
 
@@ -49,6 +50,8 @@ func (e *Error) HTTPStatus() int {
 		return 404
 	case KindBusinessRule, KindConflict:
 		return 409
+	case KindPrecondition:
+		return 412
 	default:
 		return 500
 	}
@@ -79,6 +82,12 @@ func Conflict(code, message string) *Error {
 	return &Error{Kind: KindConflict, Code: code, Message: message}
 }
 
+// PreconditionFailed builds a failed-precondition error: the caller's
+// If-Match (or similar) no longer matches the resource's current state.
+func PreconditionFailed(code, message string) *Error {
+	return &Error{Kind: KindPrecondition, Code: code, Message: message}
+}
+
 // Internal builds an internal error wrapping a lower-level cause.
 func Internal(code, message string, cause error) *Error {
 	return &Error{Kind: KindInternal, Code: code, Message: message, Cause: cause}
@@ -30,7 +30,7 @@ func Commit[A usecase.HasID, E usecase.DomainEvent, C any](
 
 	dbTx := newDbTx(tx)
 	if err := repo.Persist(ctx, aggregate, dbTx); err != nil {
-		return usecase.Failure[E](usecase.Internal("PERSIST", "repository persist failed", err))
+		return usecase.Failure[E](repoError("PERSIST", "repository persist failed", err))
 	}
 	if err := uow.sink.WriteEvent(ctx, dbTx, event); err != nil {
 		return usecase.Failure[E](usecase.Internal("EVENT_WRITE", "could not write domain event", err))
@@ -41,6 +41,7 @@ func Commit[A usecase.HasID, E usecase.DomainEvent, C any](
 	if err := tx.Commit(ctx); err != nil {
 		return usecase.Failure[E](usecase.Internal("TX_COMMIT", "could not commit transaction", err))
 	}
+	dbTx.hooks.run(ctx)
 
 	return usecase.Success[E](sealed.New(), event)
 }
@@ -63,7 +64,7 @@ func CommitDelete[A usecase.HasID, E usecase.DomainEvent, C any](
 
 	dbTx := newDbTx(tx)
 	if err := repo.Delete(ctx, aggregate, dbTx); err != nil {
-		return usecase.Failure[E](usecase.Internal("DELETE", "repository delete failed", err))
+		return usecase.Failure[E](repoError("DELETE", "repository delete failed", err))
 	}
 	if err := uow.sink.WriteEvent(ctx, dbTx, event); err != nil {
 		return usecase.Failure[E](usecase.Internal("EVENT_WRITE", "could not write domain event", err))
@@ -74,6 +75,7 @@ func CommitDelete[A usecase.HasID, E usecase.DomainEvent, C any](
 	if err := tx.Commit(ctx); err != nil {
 		return usecase.Failure[E](usecase.Internal("TX_COMMIT", "could not commit transaction", err))
 	}
+	dbTx.hooks.run(ctx)
 
 	return usecase.Success[E](sealed.New(), event)
 }
@@ -103,6 +105,7 @@ func EmitEvent[E usecase.DomainEvent, C any](
 	if err := tx.Commit(ctx); err != nil {
 		return usecase.Failure[E](usecase.Internal("TX_COMMIT", "could not commit transaction", err))
 	}
+	dbTx.hooks.run(ctx)
 
 	return usecase.Success[E](sealed.New(), event)
 }
@@ -140,6 +143,7 @@ func CommitAll[A usecase.HasID, E usecase.DomainEvent, C any](
 	if err := tx.Commit(ctx); err != nil {
 		return usecase.Failure[E](usecase.Internal("TX_COMMIT", "could not commit transaction", err))
 	}
+	dbTx.hooks.run(ctx)
 
 	return usecase.Success[E](sealed.New(), event)
 }
@@ -225,6 +229,7 @@ func CommitSync[A usecase.HasID, RE usecase.DomainEvent, C any](
 	if err := tx.Commit(ctx); err != nil {
 		return usecase.Failure[RE](usecase.Internal("TX_COMMIT", "could not commit transaction", err))
 	}
+	dbTx.hooks.run(ctx)
 
 	return usecase.Success[RE](sealed.New(), rollup)
 }
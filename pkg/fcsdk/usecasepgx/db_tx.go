@@ -9,7 +9,11 @@
 // usecase.Result outside this SDK.
 package usecasepgx
 
-import "github.com/jackc/pgx/v5"
+import (
+	"context"
+
+	"github.com/jackc/pgx/v5"
+)
 
 // DbTx is an opaque write handle passed to repository Persist methods.
 // Wraps a pgx.Tx so repository code doesn't import pgx directly through
@@ -19,14 +23,38 @@ import "github.com/jackc/pgx/v5"
 // Repositories access the underlying pgx.Tx via Inner().
 type DbTx struct {
 	inner pgx.Tx
+	hooks *commitHooks
 }
 
 // Inner exposes the underlying pgx.Tx. Repository methods call this to
 // execute SQL.
 func (t *DbTx) Inner() pgx.Tx { return t.inner }
 
+// AfterCommit registers fn to run once the transaction commits — never
+// on rollback. Use it for side effects that must not observe uncommitted
+// state, e.g. a Sink invalidating a read cache: invalidating inside the
+// transaction would let a concurrent reader re-cache the old rows before
+// the commit lands. Hooks run in registration order on the committing
+// goroutine, with the commit's ctx.
+func (t *DbTx) AfterCommit(fn func(ctx context.Context)) {
+	t.hooks.fns = append(t.hooks.fns, fn)
+}
+
+// commitHooks is shared by every DbTx over one transaction (a scoped
+// UoW hands out one DbTx per commit helper call).
+type commitHooks struct {
+	fns []func(ctx context.Context)
+}
+
+// run fires the hooks. Called only after a successful tx.Commit.
+func (h *commitHooks) run(ctx context.Context) {
+	for _, fn := range h.fns {
+		fn(ctx)
+	}
+}
+
 // newDbTx is internal to the SDK; only commit.go / run.go construct one.
-func newDbTx(tx pgx.Tx) *DbTx { return &DbTx{inner: tx} }
+func newDbTx(tx pgx.Tx) *DbTx { return &DbTx{inner: tx, hooks: &commitHooks{}} }
 
 // WrapTxForBootstrap exposes a DbTx around an externally-managed pgx.Tx
 // for infrastructure-bootstrap callers ONLY (init commands, seeders,
@@ -35,6 +63,9 @@ func newDbTx(tx pgx.Tx) *DbTx { return &DbTx{inner: tx} }
 // MUST go through Commit/CommitDelete/CommitAll/EmitEvent — those are
 // the only entry points that preserve the sealed-event guarantee.
 //
+// The caller owns the commit, so AfterCommit hooks registered on the
+// returned DbTx never run.
+//
 // If you find yourself reaching for this from a production code path,
 // you almost certainly want a real use case instead.
 func WrapTxForBootstrap(tx pgx.Tx) *DbTx { return newDbTx(tx) }
@@ -31,3 +31,13 @@ type Persist[A usecase.HasID] interface {
 	Persist(ctx context.Context, agg *A, tx *DbTx) error
 	Delete(ctx context.Context, agg *A, tx *DbTx) error
 }
+
+// repoError wraps a repository failure as an internal error, unless the
+// repository returned a *usecase.Error itself (a guard failing a
+// precondition, say), which passes through with its kind intact.
+func repoError(code, message string, err error) *usecase.Error {
+	if ue := usecase.AsError(err); ue != nil {
+		return ue
+	}
+	return usecase.Internal(code, message, err)
+}
@@ -17,8 +17,9 @@ import (
 // with the events the scoped UoW produces (e.g. updating a non-aggregate
 // row, raw SQL on a join table).
 type TxScopedUnitOfWork struct {
-	tx   pgx.Tx
-	sink Sink
+	tx    pgx.Tx
+	sink  Sink
+	hooks *commitHooks
 }
 
 // WithTx invokes the callback with the underlying pgx.Tx so callers can
@@ -33,6 +34,10 @@ func (s *TxScopedUnitOfWork) WithTx(ctx context.Context, fn func(pgx.Tx) error)
 // cases that take a Sink directly.
 func (s *TxScopedUnitOfWork) Sink() Sink { return s.sink }
 
+// dbTx hands a commit helper a DbTx over the shared transaction; its
+// AfterCommit hooks run when the surrounding Run commits.
+func (s *TxScopedUnitOfWork) dbTx() *DbTx { return &DbTx{inner: s.tx, hooks: s.hooks} }
+
 // Run opens one transaction on the pool, builds a TxScopedUnitOfWork
 // bound to it, and invokes fn. Commits on Success, rolls back on
 // Failure or panic. Use this when a handler needs to compose multiple
@@ -63,13 +68,14 @@ func Run[R any](
 		}
 	}()
 
-	scoped := &TxScopedUnitOfWork{tx: tx, sink: uow.sink}
+	scoped := &TxScopedUnitOfWork{tx: tx, sink: uow.sink, hooks: &commitHooks{}}
 	result := fn(scoped)
 
 	if usecase.IsSuccess(result) {
 		if err := tx.Commit(ctx); err != nil {
 			return usecase.Failure[R](usecase.Internal("TX_COMMIT", "could not commit orchestration tx", err))
 		}
+		scoped.hooks.run(ctx)
 		return result
 	}
 	_ = tx.Rollback(ctx)
@@ -99,7 +105,7 @@ func RunErr(
 		}
 	}()
 
-	scoped := &TxScopedUnitOfWork{tx: tx, sink: uow.sink}
+	scoped := &TxScopedUnitOfWork{tx: tx, sink: uow.sink, hooks: &commitHooks{}}
 	if err := fn(scoped); err != nil {
 		_ = tx.Rollback(ctx)
 		return err
@@ -107,6 +113,7 @@ func RunErr(
 	if err := tx.Commit(ctx); err != nil {
 		return usecase.Internal("TX_COMMIT", "could not commit orchestration tx", err)
 	}
+	scoped.hooks.run(ctx)
 	return nil
 }
 
@@ -121,9 +128,9 @@ func CommitScoped[A usecase.HasID, E usecase.DomainEvent, C any](
 	event E,
 	command C,
 ) usecase.Result[E] {
-	dbTx := newDbTx(scoped.tx)
+	dbTx := scoped.dbTx()
 	if err := repo.Persist(ctx, aggregate, dbTx); err != nil {
-		return usecase.Failure[E](usecase.Internal("PERSIST", "repository persist failed", err))
+		return usecase.Failure[E](repoError("PERSIST", "repository persist failed", err))
 	}
 	if err := scoped.sink.WriteEvent(ctx, dbTx, event); err != nil {
 		return usecase.Failure[E](usecase.Internal("EVENT_WRITE", "could not write domain event", err))
@@ -143,9 +150,9 @@ func CommitDeleteScoped[A usecase.HasID, E usecase.DomainEvent, C any](
 	event E,
 	command C,
 ) usecase.Result[E] {
-	dbTx := newDbTx(scoped.tx)
+	dbTx := scoped.dbTx()
 	if err := repo.Delete(ctx, aggregate, dbTx); err != nil {
-		return usecase.Failure[E](usecase.Internal("DELETE", "repository delete failed", err))
+		return usecase.Failure[E](repoError("DELETE", "repository delete failed", err))
 	}
 	if err := scoped.sink.WriteEvent(ctx, dbTx, event); err != nil {
 		return usecase.Failure[E](usecase.Internal("EVENT_WRITE", "could not write domain event", err))
@@ -163,7 +170,7 @@ func EmitEventScoped[E usecase.DomainEvent, C any](
 	event E,
 	command C,
 ) usecase.Result[E] {
-	dbTx := newDbTx(scoped.tx)
+	dbTx := scoped.dbTx()
 	if err := scoped.sink.WriteEvent(ctx, dbTx, event); err != nil {
 		return usecase.Failure[E](usecase.Internal("EVENT_WRITE", "could not write domain event", err))
 	}
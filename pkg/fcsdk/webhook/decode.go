@@ -0,0 +1,214 @@
+package webhook
+
+import (
+	"bytes"
+	"encoding/binary"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"mime"
+	"net/http"
+	"strings"
+)
+
+// Delivery headers set by the platform next to the signature.
+const (
+	// JobIDHeader is the dispatch job id of a single-job delivery.
+	JobIDHeader = "X-Dispatch-Job-Id"
+	// EventTypeHeader is the event type code of a single-job delivery.
+	EventTypeHeader = "X-Event-Type"
+	// JobIDsHeader lists a batch's dispatch job ids, comma-separated, in
+	// body order.
+	JobIDsHeader = "X-Dispatch-Job-Ids"
+	// BatchSizeHeader is set on batched deliveries only.
+	BatchSizeHeader = "X-Dispatch-Batch-Size"
+)
+
+// batchMessageType is the protobuf message of a batched PROTOBUF delivery,
+// flowcatalyst.delivery.v1.Batch in api/delivery/batch.proto.
+const batchMessageType = "flowcatalyst.delivery.v1.Batch"
+
+// ErrUnknownFormat is returned by Decode for a Content-Type no payload
+// format produces.
+var ErrUnknownFormat = errors.New("webhook: unknown payload format")
+
+// Message is one dispatch job of a delivery, whatever the subscription's
+// payload format.
+type Message struct {
+	// JobID is the dispatch job id.
+	JobID string
+	// EventType is the event type code; empty for a JSON or NDJSON batch,
+	// whose elements carry it only when not data-only.
+	EventType string
+	// Data is the job's body: the JSON a JSON subscription's job would
+	// have had on its own (one NDJSON line likewise), a CloudEvent's data,
+	// or the protobuf message for PROTOBUF — decode that with
+	// proto.Unmarshal into the type MessageType names.
+	Data []byte
+	// MessageType is the full name of the protobuf message in Data;
+	// PROTOBUF only.
+	MessageType string
+	// CloudEvent is the whole event; CLOUDEVENTS_JSON only.
+	CloudEvent *CloudEvent
+}
+
+// CloudEvent is a structured-mode CloudEvents 1.0 event as the platform
+// sends it, extension attributes included.
+type CloudEvent struct {
+	SpecVersion     string          `json:"specversion"`
+	ID              string          `json:"id"`
+	Source          string          `json:"source"`
+	Type            string          `json:"type"`
+	Subject         string          `json:"subject,omitempty"`
+	Time            string          `json:"time,omitempty"`
+	DataContentType string          `json:"datacontenttype,omitempty"`
+	Data            json.RawMessage `json:"data,omitempty"`
+	DispatchJobID   string          `json:"dispatchjobid"`
+	AttemptNumber   int32           `json:"attemptnumber"`
+	CorrelationID   string          `json:"correlationid,omitempty"`
+	MessageGroup    string          `json:"messagegroup,omitempty"`
+	ClientID        string          `json:"clientid,omitempty"`
+}
+
+// Decode splits a verified delivery into its jobs, reading the format off
+// the Content-Type header: one Message for a single-job delivery, one per
+// job for a batch. Verify the signature over the same body first.
+func Decode(header http.Header, body []byte) ([]Message, error) {
+	mediaType, params, err := mime.ParseMediaType(header.Get("Content-Type"))
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrUnknownFormat, err)
+	}
+	batched := header.Get(BatchSizeHeader) != ""
+	var msgs []Message
+	switch mediaType {
+	case "application/json":
+		if !batched {
+			return []Message{{JobID: header.Get(JobIDHeader), EventType: header.Get(EventTypeHeader), Data: body}}, nil
+		}
+		var items []json.RawMessage
+		if err := json.Unmarshal(body, &items); err != nil {
+			return nil, fmt.Errorf("webhook: JSON batch: %w", err)
+		}
+		for _, it := range items {
+			msgs = append(msgs, Message{Data: it})
+		}
+	case "application/x-ndjson":
+		for _, line := range bytes.Split(body, []byte{'\n'}) {
+			if len(bytes.TrimSpace(line)) > 0 {
+				msgs = append(msgs, Message{Data: line})
+			}
+		}
+	case "application/cloudevents+json", "application/cloudevents-batch+json":
+		var events []CloudEvent
+		if mediaType == "application/cloudevents+json" {
+			events = make([]CloudEvent, 1)
+			err = json.Unmarshal(body, &events[0])
+		} else {
+			err = json.Unmarshal(body, &events)
+		}
+		if err != nil {
+			return nil, fmt.Errorf("webhook: CloudEvents: %w", err)
+		}
+		for i := range events {
+			ce := &events[i]
+			msgs = append(msgs, Message{JobID: ce.DispatchJobID, EventType: ce.Type, Data: ce.Data, CloudEvent: ce})
+		}
+		return msgs, nil
+	case "application/x-protobuf":
+		if params["messagetype"] == batchMessageType {
+			return decodeProtoBatch(body)
+		}
+		return []Message{{
+			JobID:       header.Get(JobIDHeader),
+			EventType:   header.Get(EventTypeHeader),
+			Data:        body,
+			MessageType: params["messagetype"],
+		}}, nil
+	default:
+		return nil, fmt.Errorf("%w: %s", ErrUnknownFormat, mediaType)
+	}
+	// A JSON or NDJSON batch's ids come in a header, in body order.
+	if ids := strings.Split(header.Get(JobIDsHeader), ","); len(ids) == len(msgs) {
+		for i := range msgs {
+			msgs[i].JobID = ids[i]
+		}
+	}
+	if len(msgs) == 1 && header.Get(JobIDHeader) != "" {
+		msgs[0].JobID = header.Get(JobIDHeader)
+		msgs[0].EventType = header.Get(EventTypeHeader)
+	}
+	return msgs, nil
+}
+
+// decodeProtoBatch reads a flowcatalyst.delivery.v1.Batch with the
+// standard library; unknown fields are skipped.
+func decodeProtoBatch(b []byte) ([]Message, error) {
+	var msgs []Message
+	err := eachProtoField(b, func(num uint64, v []byte) error {
+		if num != 1 { // items
+			return nil
+		}
+		var m Message
+		err := eachProtoField(v, func(num uint64, v []byte) error {
+			switch num {
+			case 1:
+				m.JobID = string(v)
+			case 2:
+				m.EventType = string(v)
+			case 3:
+				m.MessageType = string(v)
+			case 4:
+				m.Data = v
+			}
+			return nil
+		})
+		msgs = append(msgs, m)
+		return err
+	})
+	if err != nil {
+		return nil, fmt.Errorf("webhook: protobuf batch: %w", err)
+	}
+	return msgs, nil
+}
+
+// eachProtoField calls fn with the number and bytes of every
+// length-delimited field in b, skipping the other wire types, and stops at
+// fn's first error.
+func eachProtoField(b []byte, fn func(num uint64, v []byte) error) error {
+	for len(b) > 0 {
+		tag, n := binary.Uvarint(b)
+		if n <= 0 {
+			return errors.New("bad tag")
+		}
+		b = b[n:]
+		switch tag & 7 {
+		case 0: // varint
+			if _, n = binary.Uvarint(b); n <= 0 {
+				return errors.New("bad varint")
+			}
+			b = b[n:]
+		case 1: // fixed64
+			if len(b) < 8 {
+				return errors.New("truncated fixed64")
+			}
+			b = b[8:]
+		case 5: // fixed32
+			if len(b) < 4 {
+				return errors.New("truncated fixed32")
+			}
+			b = b[4:]
+		case 2: // length-delimited
+			l, n := binary.Uvarint(b)
+			if n <= 0 || l > uint64(len(b)-n) {
+				return errors.New("truncated field")
+			}
+			if err := fn(tag>>3, b[n:n+int(l)]); err != nil {
+				return err
+			}
+			b = b[n+int(l):]
+		default:
+			return fmt.Errorf("unsupported wire type %d", tag&7)
+		}
+	}
+	return nil
+}
@@ -0,0 +1,111 @@
+package webhook_test
+
+import (
+	"net/http"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"google.golang.org/protobuf/encoding/protowire"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/webhook"
+)
+
+func TestDecodeSingleJSON(t *testing.T) {
+	msgs, err := webhook.Decode(http.Header{
+		"Content-Type":      {"application/json"},
+		"X-Dispatch-Job-Id": {"dj_1"},
+		"X-Event-Type":      {"orders:sales:order:created"},
+	}, []byte(`{"orderId":"o1"}`))
+	require.NoError(t, err)
+	require.Len(t, msgs, 1)
+	assert.Equal(t, "dj_1", msgs[0].JobID)
+	assert.Equal(t, "orders:sales:order:created", msgs[0].EventType)
+	assert.JSONEq(t, `{"orderId":"o1"}`, string(msgs[0].Data))
+}
+
+func TestDecodeBatches(t *testing.T) {
+	for name, tc := range map[string]struct {
+		contentType string
+		body        string
+	}{
+		"JSON":   {"application/json", `[{"n":1},{"n":2}]`},
+		"NDJSON": {"application/x-ndjson", "{\"n\":1}\n{\"n\":2}\n"},
+	} {
+		msgs, err := webhook.Decode(http.Header{
+			"Content-Type":          {tc.contentType},
+			"X-Dispatch-Batch-Size": {"2"},
+			"X-Dispatch-Job-Ids":    {"dj_1,dj_2"},
+		}, []byte(tc.body))
+		require.NoError(t, err, name)
+		require.Len(t, msgs, 2, name)
+		assert.Equal(t, "dj_1", msgs[0].JobID, name)
+		assert.Equal(t, "dj_2", msgs[1].JobID, name)
+		assert.JSONEq(t, `{"n":2}`, string(msgs[1].Data), name)
+	}
+}
+
+func TestDecodeCloudEvents(t *testing.T) {
+	event := `{"specversion":"1.0","id":"evt_1","source":"/orders","type":"orders:sales:order:created",
+		"datacontenttype":"application/json","data":{"orderId":"o1"},"dispatchjobid":"dj_1","attemptnumber":2}`
+
+	msgs, err := webhook.Decode(http.Header{"Content-Type": {"application/cloudevents+json"}}, []byte(event))
+	require.NoError(t, err)
+	require.Len(t, msgs, 1)
+	assert.Equal(t, "dj_1", msgs[0].JobID)
+	assert.Equal(t, "orders:sales:order:created", msgs[0].EventType)
+	assert.JSONEq(t, `{"orderId":"o1"}`, string(msgs[0].Data))
+	require.NotNil(t, msgs[0].CloudEvent)
+	assert.Equal(t, "evt_1", msgs[0].CloudEvent.ID)
+	assert.Equal(t, int32(2), msgs[0].CloudEvent.AttemptNumber)
+
+	msgs, err = webhook.Decode(http.Header{"Content-Type": {"application/cloudevents-batch+json"}}, []byte("["+event+","+event+"]"))
+	require.NoError(t, err)
+	assert.Len(t, msgs, 2)
+}
+
+func TestDecodeProtobuf(t *testing.T) {
+	msgs, err := webhook.Decode(http.Header{
+		"Content-Type":      {"application/x-protobuf; messageType=acme.orders.v1.OrderCreated"},
+		"X-Dispatch-Job-Id": {"dj_1"},
+		"X-Event-Type":      {"orders:sales:order:created"},
+	}, []byte{0x0a, 0x02, 'o', '1'})
+	require.NoError(t, err)
+	require.Len(t, msgs, 1)
+	assert.Equal(t, "dj_1", msgs[0].JobID)
+	assert.Equal(t, "acme.orders.v1.OrderCreated", msgs[0].MessageType)
+	assert.Equal(t, []byte{0x0a, 0x02, 'o', '1'}, msgs[0].Data)
+
+	var batch []byte
+	for _, id := range []string{"dj_1", "dj_2"} {
+		var item []byte
+		item = protowire.AppendTag(item, 1, protowire.BytesType)
+		item = protowire.AppendString(item, id)
+		item = protowire.AppendTag(item, 2, protowire.BytesType)
+		item = protowire.AppendString(item, "orders:sales:order:created")
+		item = protowire.AppendTag(item, 3, protowire.BytesType)
+		item = protowire.AppendString(item, "acme.orders.v1.OrderCreated")
+		item = protowire.AppendTag(item, 4, protowire.BytesType)
+		item = protowire.AppendBytes(item, []byte{0x0a, 0x02, 'o', '1'})
+		batch = protowire.AppendTag(batch, 1, protowire.BytesType)
+		batch = protowire.AppendBytes(batch, item)
+	}
+	msgs, err = webhook.Decode(http.Header{
+		"Content-Type":          {"application/x-protobuf; messageType=flowcatalyst.delivery.v1.Batch"},
+		"X-Dispatch-Batch-Size": {"2"},
+	}, batch)
+	require.NoError(t, err)
+	require.Len(t, msgs, 2)
+	assert.Equal(t, "dj_2", msgs[1].JobID)
+	assert.Equal(t, "orders:sales:order:created", msgs[1].EventType)
+	assert.Equal(t, "acme.orders.v1.OrderCreated", msgs[1].MessageType)
+	assert.Equal(t, []byte{0x0a, 0x02, 'o', '1'}, msgs[1].Data)
+
+	_, err = webhook.Decode(http.Header{"Content-Type": {"application/x-protobuf; messageType=flowcatalyst.delivery.v1.Batch"}}, []byte{0x0a, 0x09})
+	assert.Error(t, err, "truncated item")
+}
+
+func TestDecodeRejectsUnknownFormat(t *testing.T) {
+	_, err := webhook.Decode(http.Header{"Content-Type": {"text/xml"}}, []byte("<a/>"))
+	assert.ErrorIs(t, err, webhook.ErrUnknownFormat)
+}
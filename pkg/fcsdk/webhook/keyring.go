@@ -0,0 +1,56 @@
+package webhook
+
+import (
+	"errors"
+	"time"
+)
+
+// ErrUnknownKeyID is returned when the delivery names a key the ring
+// doesn't hold and no held key verifies it either.
+var ErrUnknownKeyID = errors.New("webhook: signing key id not recognised")
+
+// KeyRing verifies deliveries signed with per-client signing keys, where
+// more than one key is valid while a rotation overlaps. Load it with every
+// key the platform listed as not yet expired, keyed by key id.
+type KeyRing struct {
+	keys map[string][]byte
+	// MaxClockSkew is applied to every key; see Verifier.MaxClockSkew.
+	MaxClockSkew time.Duration
+}
+
+// NewKeyRing wires a ring from key id → secret.
+func NewKeyRing(keys map[string]string) *KeyRing {
+	r := &KeyRing{keys: make(map[string][]byte, len(keys)), MaxClockSkew: 5 * time.Minute}
+	for kid, secret := range keys {
+		r.keys[kid] = []byte(secret)
+	}
+	return r
+}
+
+// Verify checks a delivery against the key named by keyID (the
+// X-FLOWCATALYST-KEY-ID header). When keyID is empty or not in the ring,
+// every key is tried, so a receiver mid-rotation keeps working before it
+// has learned the new id.
+func (r *KeyRing) Verify(body []byte, signature, timestamp, keyID string) error {
+	if secret, ok := r.keys[keyID]; ok {
+		return r.verify(secret, body, signature, timestamp)
+	}
+	for _, secret := range r.keys {
+		switch e := r.verify(secret, body, signature, timestamp); {
+		case e == nil:
+			return nil
+		case !errors.Is(e, ErrBadSignature):
+			// Missing headers or a stale timestamp fail for every key.
+			return e
+		}
+	}
+	if keyID != "" {
+		return ErrUnknownKeyID
+	}
+	return ErrBadSignature
+}
+
+func (r *KeyRing) verify(secret, body []byte, signature, timestamp string) error {
+	v := Verifier{secret: secret, MaxClockSkew: r.MaxClockSkew}
+	return v.Verify(body, signature, timestamp)
+}
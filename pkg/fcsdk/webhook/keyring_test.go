@@ -0,0 +1,39 @@
+package webhook_test
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/flowcatalyst/flowcatalyst-go/pkg/fcsdk/webhook"
+)
+
+func TestKeyRingVerifiesAcrossRotation(t *testing.T) {
+	body := []byte(`{"messageId":"msg_TEST123456"}`)
+	timestamp := time.Now().UTC().Format("2006-01-02T15:04:05.000Z")
+	ring := webhook.NewKeyRing(map[string]string{"v1": "old-secret", "v2": "new-secret"})
+
+	oldSig := webhook.Sign("old-secret", timestamp, body)
+	newSig := webhook.Sign("new-secret", timestamp, body)
+
+	assert.NoError(t, ring.Verify(body, oldSig, timestamp, "v1"))
+	assert.NoError(t, ring.Verify(body, newSig, timestamp, "v2"))
+	assert.ErrorIs(t, ring.Verify(body, oldSig, timestamp, "v2"), webhook.ErrBadSignature,
+		"a named key is the only one tried")
+
+	// No key id, or one the receiver hasn't loaded yet: every key is tried.
+	assert.NoError(t, ring.Verify(body, newSig, timestamp, ""))
+	assert.NoError(t, ring.Verify(body, newSig, timestamp, "v3"))
+
+	forged := webhook.Sign("other", timestamp, body)
+	assert.ErrorIs(t, ring.Verify(body, forged, timestamp, ""), webhook.ErrBadSignature)
+	assert.ErrorIs(t, ring.Verify(body, forged, timestamp, "v3"), webhook.ErrUnknownKeyID)
+}
+
+func TestKeyRingRejectsStaleTimestamp(t *testing.T) {
+	body := []byte(`{}`)
+	old := time.Now().UTC().Add(-30 * time.Minute).Format("2006-01-02T15:04:05.000Z")
+	ring := webhook.NewKeyRing(map[string]string{"v1": "a", "v2": "b"})
+	assert.ErrorIs(t, ring.Verify(body, webhook.Sign("b", old, body), old, ""), webhook.ErrStaleTimestamp)
+}
@@ -83,3 +83,24 @@ func TestComputeSignatureIsStable(t *testing.T) {
 	assert.Equal(t, a, b)
 	assert.NotEqual(t, a, v.ComputeSignature("101", []byte("hello")))
 }
+
+// FuzzValidate is FuzzVerify for the Validator, whose timestamp parser
+// also accepts bare Unix seconds.
+func FuzzValidate(f *testing.F) {
+	now := time.Date(2026, 5, 24, 8, 30, 0, 0, time.UTC)
+	f.Add("test-secret", []byte(`{"x":1}`), "", "2026-05-24T08:29:59.123Z")
+	f.Add("s", []byte{}, "00", strconv.FormatInt(now.Unix(), 10))
+	f.Add("s", []byte("\x00"), "x", "-9223372036854775808")
+	f.Add("s", []byte(nil), "x", "9999-12-31T23:59:59.999999999Z")
+	f.Fuzz(func(t *testing.T, secret string, body []byte, signature, timestamp string) {
+		v := webhook.NewValidator(secret, webhook.WithClock(func() time.Time { return now }))
+		err := v.Validate(signature, timestamp, body)
+		if err == nil {
+			assert.Equal(t, v.ComputeSignature(timestamp, body), signature)
+			return
+		}
+		assert.True(t, isOneOf(err, webhook.ErrValidatorMissingSignature, webhook.ErrValidatorMissingTimestamp,
+			webhook.ErrValidatorInvalidTimestamp, webhook.ErrValidatorInvalidSignature,
+			webhook.ErrTimestampExpired, webhook.ErrTimestampInFuture), "unexpected error %v", err)
+	})
+}
@@ -20,6 +20,19 @@ const SignatureHeader = "X-FLOWCATALYST-SIGNATURE"
 // TimestampHeader matches the router's outbound header name.
 const TimestampHeader = "X-FLOWCATALYST-TIMESTAMP"
 
+// KeyIDHeader names the client signing key a delivery was signed with.
+// Only set when the platform signs with a per-client key; see KeyRing.
+const KeyIDHeader = "X-FLOWCATALYST-KEY-ID"
+
+// Sign returns the hex signature for body at timestamp:
+// HMAC-SHA256(secret, timestamp || body).
+func Sign(secret, timestamp string, body []byte) string {
+	mac := hmac.New(sha256.New, []byte(secret))
+	mac.Write([]byte(timestamp))
+	mac.Write(body)
+	return hex.EncodeToString(mac.Sum(nil))
+}
+
 // Verifier validates inbound webhook signatures.
 type Verifier struct {
 	secret []byte
@@ -66,11 +79,7 @@ func (v *Verifier) Verify(body []byte, signature, timestamp string) error {
 		}
 	}
 
-	mac := hmac.New(sha256.New, v.secret)
-	mac.Write([]byte(timestamp))
-	mac.Write(body)
-	want := hex.EncodeToString(mac.Sum(nil))
-
+	want := Sign(string(v.secret), timestamp, body)
 	if !hmac.Equal([]byte(want), []byte(signature)) {
 		return ErrBadSignature
 	}
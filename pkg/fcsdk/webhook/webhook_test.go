@@ -4,6 +4,7 @@ import (
 	"crypto/hmac"
 	"crypto/sha256"
 	"encoding/hex"
+	"errors"
 	"testing"
 	"time"
 
@@ -72,3 +73,40 @@ func TestVerifyMissingHeaders(t *testing.T) {
 	assert.ErrorIs(t, v.Verify(nil, "", "2026-01-01T00:00:00.000Z"), webhook.ErrMissingSignature)
 	assert.ErrorIs(t, v.Verify(nil, "abc", ""), webhook.ErrMissingTimestamp)
 }
+
+// FuzzVerify feeds the verifier arbitrary request parts, all of which an
+// attacker controls. It must only ever return one of its sentinel errors,
+// and only the exact signature for (timestamp, body) may pass.
+func FuzzVerify(f *testing.F) {
+	f.Add("test-secret", []byte(`{"messageId":"msg_TEST123456"}`), "", "2026-05-24T08:30:00.123Z")
+	f.Add("", []byte{}, "00", "2026-05-24T08:30:00Z")
+	f.Add("s", []byte("\x00\xff"), "ABCDEF", "2026-05-24T08:30:00.123456789+02:00")
+	f.Add("s", []byte(nil), "x", "not-a-time")
+	f.Fuzz(func(t *testing.T, secret string, body []byte, signature, timestamp string) {
+		v := webhook.NewVerifier(secret)
+		v.MaxClockSkew = 0 // skew is wall-clock dependent; covered above
+		want := webhook.Sign(secret, timestamp, body)
+
+		err := v.Verify(body, signature, timestamp)
+		if err == nil {
+			assert.Equal(t, want, signature)
+		} else {
+			assert.True(t, isOneOf(err, webhook.ErrMissingSignature, webhook.ErrMissingTimestamp,
+				webhook.ErrBadTimestamp, webhook.ErrBadSignature), "unexpected error %v", err)
+		}
+
+		if err := v.Verify(body, want, timestamp); err != nil {
+			assert.True(t, isOneOf(err, webhook.ErrMissingTimestamp, webhook.ErrBadTimestamp),
+				"the correct signature failed with %v", err)
+		}
+	})
+}
+
+func isOneOf(err error, targets ...error) bool {
+	for _, target := range targets {
+		if errors.Is(err, target) {
+			return true
+		}
+	}
+	return false
+}
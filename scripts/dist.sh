@@ -0,0 +1,70 @@
+#!/usr/bin/env bash
+# Build a local release bundle of every FlowCatalyst binary.
+#
+# Usage: scripts/dist.sh [version]
+#   [version]  defaults to cmd/fc-dev/VERSION plus the short commit
+#              (e.g. 0.6.20+a1b2c3d), or plain VERSION on a clean tag.
+#
+# Cross-compiles fc-server, fc-dev and fc-cli for the same platforms the
+# fc-dev release workflow ships (.github/workflows/release-fc-dev.yml),
+# one archive per platform holding all three, into
+# dist/flowcatalyst-v<version>/ with a SHA256SUMS file. Nothing is signed
+# or published — the release workflow stays the only path to a GitHub
+# release. Needs frontend/dist (`make frontend`) for the embedded SPA.
+set -euo pipefail
+
+repo_root="$(cd "$(dirname "$0")/.." && pwd)"
+cd "$repo_root"
+
+binaries="fc-server fc-dev fc-cli"
+targets="
+darwin arm64 tar.gz
+darwin amd64 tar.gz
+linux amd64 tar.gz
+linux arm64 tar.gz
+windows amd64 zip
+"
+
+version="${1:-}"
+if [ -z "$version" ]; then
+	version="$(tr -d '[:space:]' < cmd/fc-dev/VERSION)"
+	if ! git describe --exact-match --tags --match "fc-dev/v$version" >/dev/null 2>&1; then
+		version="$version+$(git rev-parse --short HEAD)"
+	fi
+fi
+
+if [ ! -d frontend/dist ] || [ -z "$(ls -A frontend/dist 2>/dev/null)" ]; then
+	echo "✗ frontend/dist is empty — run 'make frontend' first." >&2
+	exit 1
+fi
+
+out="dist/flowcatalyst-v$version"
+rm -rf "$out"
+mkdir -p "$out"
+work="$(mktemp -d)"
+trap 'rm -rf "$work"' EXIT
+
+echo "$targets" | while read -r goos goarch ext; do
+	[ -z "$goos" ] && continue
+	stage="flowcatalyst-v${version}-${goos}-${goarch}"
+	mkdir -p "$work/$stage"
+	for b in $binaries; do
+		bin="$b"; [ "$goos" = "windows" ] && bin="$b.exe"
+		echo ">> building $b ${goos}/${goarch}"
+		CGO_ENABLED=0 GOOS="$goos" GOARCH="$goarch" \
+			go build -trimpath -ldflags='-s -w' -o "$work/$stage/$bin" "./cmd/$b"
+	done
+	cp README.md "$work/$stage/" 2>/dev/null || true
+
+	case "$ext" in
+		zip)    (cd "$work" && zip -qr "$repo_root/$out/$stage.zip" "$stage") ;;
+		tar.gz) tar -C "$work" -czf "$out/$stage.tar.gz" "$stage" ;;
+	esac
+done
+
+sha256="sha256sum"; command -v sha256sum >/dev/null || sha256="shasum -a 256"
+(cd "$out" && $sha256 -- *.tar.gz *.zip > SHA256SUMS)
+
+echo ""
+echo "✓ Bundle in $out"
+ls -lh "$out"
@@ -17,19 +17,21 @@ kind="${1:-}"
 bump="${2:-}"
 
 usage() {
-	echo "usage: scripts/release.sh <dev|ts|laravel> <patch|minor|major|X.Y.Z[-suffix]>" >&2
+	echo "usage: scripts/release.sh <dev|ts|laravel|py> <patch|minor|major|X.Y.Z[-suffix]>" >&2
 	exit 2
 }
 [ -n "$kind" ] && [ -n "$bump" ] || usage
 
 # manifest (optional) is a package file whose "version" must track the tag:
 # TS package.json only. Laravel/composer.json and fc-dev have no such field
-# (Packagist reads the tag; fc-dev embeds cmd/fc-dev/VERSION).
+# (Packagist reads the tag; fc-dev embeds cmd/fc-dev/VERSION), and the
+# Python pyproject.toml reads its version from VERSION.
 manifest=""
 case "$kind" in
 	dev)     prefix="fc-dev";         version_file="cmd/fc-dev/VERSION";             label="fc-dev" ;;
 	ts)      prefix="typescript-sdk"; version_file="clients/typescript-sdk/VERSION"; label="typescript-sdk"; manifest="clients/typescript-sdk/package.json" ;;
 	laravel) prefix="laravel-sdk";    version_file="clients/laravel-sdk/VERSION";    label="laravel-sdk" ;;
+	py)      prefix="python-sdk";     version_file="clients/python-sdk/VERSION";     label="python-sdk" ;;
 	*)       echo "✗ unknown release kind: $kind" >&2; usage ;;
 esac
 
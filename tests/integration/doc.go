@@ -0,0 +1,25 @@
+// Package integration holds the cross-package end-to-end tests (build
+// tag `integration`, run via `make test-integration` or
+// `go test -tags=integration ./tests/integration/`). Where the package tests
+// of the router, stream processor and scheduler each stop at their own
+// boundary, these wire the real components together against the
+// embedded Postgres fixture (internal/testpg) and an httptest receiver
+// standing in for the webhook endpoint:
+//
+//   - queue → router → webhook: messages published to a Postgres queue
+//     are delivered by a router that picked up its pools from a config
+//     endpoint.
+//   - event → stream → dispatch job → delivery: an event fans out to a
+//     dispatch job, the scheduler queues it, and the router delivers it to
+//     the processing endpoint.
+//   - config reload: a pool and queue added to the config endpoint start
+//     delivering after Server.Reload (what POST /config/reload calls).
+//   - leader failover: with two routers in standby mode, the second takes
+//     over delivery when the leader shuts down. Needs a Redis server;
+//     skipped unless FC_TEST_REDIS_URL is set.
+//
+// There is no container harness: Postgres is embedded, the queue is the
+// Postgres backend, and the only external service (Redis) is opt-in. The
+// SQS and NATS backends are not exercised here, nor is the outbox
+// processor, which posts to the platform API rather than to a queue.
+package integration
@@ -0,0 +1,146 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"os"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduler"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/stream"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/tsid"
+)
+
+func TestQueueToWebhook(t *testing.T) {
+	hook := newReceiver(t)
+	qc, pub := newQueue(t, "e2e-delivery")
+	cs := newConfigServer(t, common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "E2E", Concurrency: 2}},
+		Queues:          []common.QueueConfig{qc},
+	})
+	startRouter(t, router.ServerConfig{ConfigURL: cs.URL})
+
+	publish(t, pub,
+		message("e2e-d1", "E2E", hook.URL),
+		message("e2e-d2", "E2E", hook.URL),
+		message("e2e-d3", "", hook.URL)) // no pool code: DEFAULT-POOL
+	hook.waitFor(t, "e2e-d1", "e2e-d2", "e2e-d3")
+}
+
+func TestEventToDispatchDelivery(t *testing.T) {
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+	pool := testpg.Pool(t)
+	processing := newReceiver(t) // stands in for POST /api/dispatch/process
+
+	const eventType = "e2e:orders:order:created"
+	subID := tsid.GenerateUntyped()
+	_, err := pool.Exec(ctx,
+		`INSERT INTO msg_subscriptions (id, code, name, target) VALUES ($1, 'e2e-orders', 'E2E orders', 'https://example.com/hook')`,
+		subID)
+	require.NoError(t, err)
+	_, err = pool.Exec(ctx,
+		`INSERT INTO msg_subscription_event_types (subscription_id, event_type_code) VALUES ($1, $2)`,
+		subID, eventType)
+	require.NoError(t, err)
+
+	qc, pub := newQueue(t, "e2e-dispatch")
+	cs := newConfigServer(t, common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "DEFAULT-POOL", Concurrency: 2}},
+		Queues:          []common.QueueConfig{qc},
+	})
+	startRouter(t, router.ServerConfig{ConfigURL: cs.URL})
+
+	fanOut := stream.NewFanOutWithConfig(pool, stream.FanOutConfig{SubscriptionTTL: time.Millisecond, MaxAttempts: 3})
+	pcfg := stream.DefaultProjectorConfig()
+	pcfg.IdleSleep = 100 * time.Millisecond
+	go fanOut.Projector(pcfg).Run(ctx)
+
+	scfg := scheduler.DefaultConfig()
+	scfg.PollInterval = 100 * time.Millisecond
+	scfg.ProcessingEndpoint = processing.URL
+	go scheduler.New(scfg, pool, pub, "e2e-secret").Run(ctx)
+
+	eventID := tsid.GenerateUntyped()
+	now := time.Now().UTC()
+	_, err = pool.Exec(ctx,
+		`INSERT INTO msg_events (id, type, source, subject, time, message_group, created_at)
+		 VALUES ($1, $2, 'e2e', 'order-1', $3, 'order-1', $3)`,
+		eventID, eventType, now)
+	require.NoError(t, err)
+
+	var jobID string
+	require.Eventually(t, func() bool {
+		return pool.QueryRow(ctx,
+			`SELECT id FROM msg_dispatch_jobs WHERE event_id = $1 AND subscription_id = $2`,
+			eventID, subID).Scan(&jobID) == nil
+	}, deliveryTimeout, 100*time.Millisecond, "event never fanned out to a dispatch job")
+	processing.waitFor(t, jobID)
+}
+
+func TestConfigReload(t *testing.T) {
+	hook := newReceiver(t)
+	qa, pubA := newQueue(t, "e2e-reload-a")
+	qb, pubB := newQueue(t, "e2e-reload-b")
+	cs := newConfigServer(t, common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "A", Concurrency: 1}},
+		Queues:          []common.QueueConfig{qa},
+	})
+	srv, _ := startRouter(t, router.ServerConfig{ConfigURL: cs.URL, ConfigPollInterval: time.Hour})
+
+	publish(t, pubA, message("e2e-r1", "A", hook.URL))
+	hook.waitFor(t, "e2e-r1")
+	assert.Nil(t, srv.Manager.Pool("B"))
+
+	cs.set(common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "A", Concurrency: 1}, {Code: "B", Concurrency: 3}},
+		Queues:          []common.QueueConfig{qa, qb},
+	})
+	require.NoError(t, srv.Reload(context.Background()))
+	require.NotNil(t, srv.Manager.Pool("B"))
+	assert.Equal(t, uint32(3), srv.Manager.Pool("B").Stats().Concurrency)
+
+	publish(t, pubB, message("e2e-r2", "B", hook.URL))
+	publish(t, pubA, message("e2e-r3", "A", hook.URL))
+	hook.waitFor(t, "e2e-r2", "e2e-r3")
+}
+
+func TestLeaderFailover(t *testing.T) {
+	redisURL := os.Getenv("FC_TEST_REDIS_URL")
+	if redisURL == "" {
+		t.Skip("FC_TEST_REDIS_URL not set")
+	}
+	hook := newReceiver(t)
+	qc, pub := newQueue(t, "e2e-failover")
+	cs := newConfigServer(t, common.RouterConfig{
+		ProcessingPools: []common.PoolConfig{{Code: "F", Concurrency: 1}},
+		Queues:          []common.QueueConfig{qc},
+	})
+	cfg := router.ServerConfig{
+		ConfigURL:       cs.URL,
+		StandbyEnabled:  true,
+		StandbyRedisURL: redisURL,
+		StandbyLockKey:  "fc:e2e:leader:" + tsid.GenerateUntyped(),
+	}
+
+	first, stopFirst := startRouter(t, cfg)
+	require.Eventually(t, first.IsLeader, deliveryTimeout, 100*time.Millisecond)
+	second, _ := startRouter(t, cfg)
+
+	publish(t, pub, message("e2e-f1", "F", hook.URL))
+	hook.waitFor(t, "e2e-f1")
+	assert.False(t, second.IsLeader())
+
+	stopFirst() // releases the lock
+	require.Eventually(t, second.IsLeader, 30*time.Second, 100*time.Millisecond, "standby never took over")
+	publish(t, pub, message("e2e-f2", "F", hook.URL))
+	hook.waitFor(t, "e2e-f2")
+}
@@ -0,0 +1,148 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"slices"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+
+	"github.com/flowcatalyst/flowcatalyst-go/internal/common"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/queue"
+	_ "github.com/flowcatalyst/flowcatalyst-go/internal/queue/postgres"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/router"
+	"github.com/flowcatalyst/flowcatalyst-go/internal/testpg"
+)
+
+func TestMain(m *testing.M) { testpg.RunMain(m) }
+
+const deliveryTimeout = 20 * time.Second
+
+// receiver is the mock webhook (or processing) endpoint. The router POSTs
+// {"messageId": ...}; receiver records the ids and answers 200.
+type receiver struct {
+	*httptest.Server
+	mu  sync.Mutex
+	ids []string
+}
+
+func newReceiver(t *testing.T) *receiver {
+	r := &receiver{}
+	r.Server = httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+		var body struct {
+			MessageID string `json:"messageId"`
+		}
+		if err := json.NewDecoder(req.Body).Decode(&body); err != nil {
+			w.WriteHeader(http.StatusBadRequest)
+			return
+		}
+		r.mu.Lock()
+		r.ids = append(r.ids, body.MessageID)
+		r.mu.Unlock()
+	}))
+	t.Cleanup(r.Close)
+	return r
+}
+
+func (r *receiver) received() []string {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	return slices.Clone(r.ids)
+}
+
+// waitFor fails the test unless every id is delivered within deliveryTimeout.
+func (r *receiver) waitFor(t *testing.T, ids ...string) {
+	t.Helper()
+	require.Eventually(t, func() bool {
+		got := r.received()
+		for _, id := range ids {
+			if !slices.Contains(got, id) {
+				return false
+			}
+		}
+		return true
+	}, deliveryTimeout, 100*time.Millisecond, "waiting for %v; received %v", ids, r.received())
+}
+
+// newQueue provisions a Postgres queue in the test database. Queue names
+// keep tests apart: they share the queue_messages table.
+func newQueue(t *testing.T, name string) (common.QueueConfig, queue.Publisher) {
+	t.Helper()
+	uri := testpg.Pool(t).Config().ConnString()
+	if rest, ok := strings.CutPrefix(uri, "postgresql://"); ok {
+		uri = "postgres://" + rest
+	}
+	qc := common.QueueConfig{Name: name, URI: uri, VisibilityTimeout: 30}
+	pub, err := queue.NewPublisher(context.Background(), qc)
+	require.NoError(t, err)
+	require.NoError(t, pub.(queue.Embedded).InitSchema(context.Background()))
+	return qc, pub
+}
+
+// configServer serves a router config the test can change.
+type configServer struct {
+	*httptest.Server
+	cfg atomic.Pointer[common.RouterConfig]
+}
+
+func newConfigServer(t *testing.T, cfg common.RouterConfig) *configServer {
+	cs := &configServer{}
+	cs.set(cfg)
+	cs.Server = httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, _ *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		_ = json.NewEncoder(w).Encode(cs.cfg.Load())
+	}))
+	t.Cleanup(cs.Close)
+	return cs
+}
+
+func (cs *configServer) set(cfg common.RouterConfig) { cs.cfg.Store(&cfg) }
+
+// startRouter runs a router until the returned stop is called or the test
+// ends.
+func startRouter(t *testing.T, cfg router.ServerConfig) (*router.Server, func()) {
+	t.Helper()
+	cfg.DevMode = true
+	if cfg.DrainTimeout == 0 {
+		cfg.DrainTimeout = 2 * time.Second
+	}
+	srv, err := router.NewServer(cfg)
+	require.NoError(t, err)
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan struct{})
+	go func() {
+		defer close(done)
+		if err := srv.Run(ctx); err != nil {
+			t.Errorf("router run: %v", err)
+		}
+	}()
+	stop := sync.OnceFunc(func() { cancel(); <-done })
+	t.Cleanup(stop)
+	return srv, stop
+}
+
+func message(id, poolCode, target string) common.Message {
+	return common.Message{
+		ID:              id,
+		PoolCode:        poolCode,
+		MediationType:   common.MediationTypeHTTP,
+		MediationTarget: target,
+	}
+}
+
+func publish(t *testing.T, pub queue.Publisher, msgs ...common.Message) {
+	t.Helper()
+	for _, m := range msgs {
+		_, err := pub.Publish(context.Background(), m)
+		require.NoError(t, err)
+	}
+}
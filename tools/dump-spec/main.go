@@ -17,10 +17,14 @@ import (
 	"github.com/danielgtaylor/huma/v2/adapters/humachi"
 	"github.com/go-chi/chi/v5"
 
+	anomalyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/anomaly/api"
 	applicationapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/application/api"
+	archiveapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/archive/api"
 	auditapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/audit/api"
 	authapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/api"
+	lockoutapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/auth/lockout/api"
 	clientapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/client/api"
+	configbundleapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/configbundle/api"
 	connectionapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/connection/api"
 	corsapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/cors/api"
 	dispatchjobapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/dispatchjob/api"
@@ -28,17 +32,23 @@ import (
 	emaildomainapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/emaildomainmapping/api"
 	eventapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/event/api"
 	eventtypeapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/eventtype/api"
+	federationapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/federation/api"
 	identityproviderapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/identityprovider/api"
+	killswitchapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/killswitch/api"
 	loginattemptapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/loginattempt/api"
 	platformconfigapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/platformconfig/api"
 	principalapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/principal/api"
+	privacyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/privacy/api"
 	processapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/process/api"
+	reconcilerapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/reconciler/api"
 	resetapprovalapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/resetapproval/api"
 	roleapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/role/api"
 	scheduledjobapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/scheduledjob/api"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/sdksync"
 	serviceaccountapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/serviceaccount/api"
 	"github.com/flowcatalyst/flowcatalyst-go/internal/platform/shared/httpcompat"
+	signingkeyapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/signingkey/api"
+	streamdlqapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/streamdlq/api"
 	subscriptionapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/subscription/api"
 	webauthnapi "github.com/flowcatalyst/flowcatalyst-go/internal/platform/webauthn/api"
 )
@@ -53,10 +63,13 @@ func main() {
 	// list in sync with WirePlatform; the parity-spec CI job dumps from
 	// here, so a missing line means the route is missing from the
 	// committed openapi.lock.json.
+	anomalyapi.Register(api, &anomalyapi.State{})
 	applicationapi.Register(api, &applicationapi.State{})
+	archiveapi.Register(api, &archiveapi.State{})
 	auditapi.Register(api, &auditapi.State{})
 	authapi.Register(api, &authapi.State{})
 	clientapi.Register(api, &clientapi.State{})
+	configbundleapi.Register(api, &configbundleapi.State{})
 	connectionapi.Register(api, &connectionapi.State{})
 	corsapi.Register(api, &corsapi.State{})
 	dispatchjobapi.Register(api, &dispatchjobapi.State{})
@@ -64,19 +77,26 @@ func main() {
 	emaildomainapi.Register(api, &emaildomainapi.State{})
 	eventapi.Register(api, &eventapi.State{})
 	eventtypeapi.Register(api, &eventtypeapi.State{})
+	federationapi.Register(api, &federationapi.State{})
 	identityproviderapi.Register(api, &identityproviderapi.State{})
+	killswitchapi.Register(api, &killswitchapi.State{})
 	platformconfigapi.Register(api, &platformconfigapi.State{})
 	principalapi.Register(api, &principalapi.State{})
+	privacyapi.Register(api, &privacyapi.State{})
 	processapi.Register(api, &processapi.State{})
+	reconcilerapi.Register(api, &reconcilerapi.State{})
 	resetapprovalapi.Register(api, &resetapprovalapi.State{})
 	roleapi.Register(api, &roleapi.State{})
 	scheduledjobapi.Register(api, &scheduledjobapi.State{})
 	serviceaccountapi.Register(api, &serviceaccountapi.State{})
+	signingkeyapi.Register(api, &signingkeyapi.State{})
+	streamdlqapi.Register(api, &streamdlqapi.State{})
 	subscriptionapi.Register(api, &subscriptionapi.State{})
 	webauthnapi.Register(api, &webauthnapi.State{})
 	// SDK self-registration ("sync") routes and the login-attempt admin
 	// routes are served by WirePlatform too; they were historically
 	// omitted here, so they were missing from the committed lockfile.
+	lockoutapi.Register(api, &lockoutapi.State{})
 	loginattemptapi.Register(api, &loginattemptapi.State{})
 	sdksync.Register(api, &sdksync.State{})
 
@@ -0,0 +1,104 @@
+package main
+
+import (
+	"io/fs"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+// TestKnownVarsUpToDate fails when internal/envutil/known_vars.go no
+// longer matches the document. Run `make env-vars` and commit the diff.
+func TestKnownVarsUpToDate(t *testing.T) {
+	root := findRepoRoot(t)
+	doc, err := os.ReadFile(filepath.Join(root, "docs", "environment-variables.md"))
+	require.NoError(t, err)
+	want, err := generate(doc)
+	require.NoError(t, err)
+	got, err := os.ReadFile(filepath.Join(root, "internal", "envutil", "known_vars.go"))
+	require.NoError(t, err)
+	require.Equal(t, string(want), string(got), "known_vars.go is stale; run `make env-vars`")
+}
+
+// notRead are FC_*/FLOWCATALYST_* literals the code writes (fc-dev init's
+// generated .env) rather than reads.
+var notRead = map[string]bool{
+	"FLOWCATALYST_APP_CODE": true,
+	"FLOWCATALYST_BASE_URL": true,
+}
+
+// TestEveryReadVariableIsDocumented keeps strict mode honest: a variable
+// some binary reads but the document lacks would be rejected as unknown.
+func TestEveryReadVariableIsDocumented(t *testing.T) {
+	root := findRepoRoot(t)
+	doc, err := os.ReadFile(filepath.Join(root, "docs", "environment-variables.md"))
+	require.NoError(t, err)
+	documented := map[string]bool{}
+	for _, r := range parse(doc) {
+		documented[r.name] = true
+		for _, a := range r.aliases {
+			documented[a] = true
+		}
+	}
+
+	lit := regexp.MustCompile(`"((?:FC|FLOWCATALYST)_[A-Z0-9_]+)"`)
+	missing := map[string]string{}
+	for _, dir := range []string{"cmd", "internal", "pkg"} {
+		err := filepath.WalkDir(filepath.Join(root, dir), func(path string, d fs.DirEntry, err error) error {
+			if err != nil || d.IsDir() || !strings.HasSuffix(path, ".go") || strings.HasSuffix(path, "_test.go") {
+				return err
+			}
+			src, err := os.ReadFile(path)
+			if err != nil {
+				return err
+			}
+			for _, m := range lit.FindAllStringSubmatch(string(src), -1) {
+				if !documented[m[1]] && !notRead[m[1]] {
+					missing[m[1]], _ = filepath.Rel(root, path)
+				}
+			}
+			return nil
+		})
+		require.NoError(t, err)
+	}
+	var lines []string
+	for name, file := range missing {
+		lines = append(lines, name+" ("+file+")")
+	}
+	sort.Strings(lines)
+	require.Empty(t, lines, "read but not in docs/environment-variables.md")
+}
+
+func TestParse(t *testing.T) {
+	rows := parse([]byte("| Variable | Default |\n" +
+		"| `FC_A` | `10` (ten) | `A`, `FC_OLD_A` (legacy) | x | a |\n" +
+		"| `FC_B` | — (off) | — | x | `open\\|closed` |\n" +
+		"| `FC_A` | `20` | — | x | again |\n" +
+		"| `not a var` | — | — | x | y |\n"))
+	require.Equal(t, []row{
+		{name: "FC_A", def: "10 (ten)", aliases: []string{"A", "FC_OLD_A"}},
+		{name: "FC_B", def: "(off)"},
+	}, rows)
+}
+
+func findRepoRoot(t *testing.T) string {
+	t.Helper()
+	wd, err := os.Getwd()
+	require.NoError(t, err)
+	dir := wd
+	for {
+		if _, err := os.Stat(filepath.Join(dir, "go.mod")); err == nil {
+			return dir
+		}
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			t.Fatalf("could not find go.mod from %s", wd)
+		}
+		dir = parent
+	}
+}
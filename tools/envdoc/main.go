@@ -0,0 +1,124 @@
+// envdoc generates internal/envutil/known_vars.go — the registry of every
+// supported environment variable — from the tables in
+// docs/environment-variables.md, so the documentation stays the single
+// source of truth. Used by `make env-vars`; the test in this package fails
+// when the committed registry no longer matches the document.
+//
+// Every table row whose first cell is a backticked upper-case name is a
+// variable. The Default cell is kept as written (backticks dropped, "—"
+// meaning none) and every backticked name in the Aliases cell is an alias.
+// A variable documented twice keeps its first row.
+package main
+
+import (
+	"bytes"
+	"fmt"
+	"go/format"
+	"os"
+	"regexp"
+	"strings"
+)
+
+var (
+	nameRE  = regexp.MustCompile("^[A-Z][A-Z0-9_]*$")
+	aliasRE = regexp.MustCompile("`([A-Z][A-Z0-9_]*)`")
+)
+
+func main() {
+	path := "docs/environment-variables.md"
+	if len(os.Args) > 1 {
+		path = os.Args[1]
+	}
+	doc, err := os.ReadFile(path)
+	if err != nil {
+		fmt.Fprintln(os.Stderr, "envdoc:", err)
+		os.Exit(1)
+	}
+	src, err := generate(doc)
+	if err != nil {
+		fmt.Fprintln(os.Stderr, "envdoc:", err)
+		os.Exit(1)
+	}
+	_, _ = os.Stdout.Write(src)
+}
+
+type row struct {
+	name, def string
+	aliases   []string
+}
+
+func parse(doc []byte) []row {
+	var rows []row
+	seen := map[string]bool{}
+	for _, line := range strings.Split(string(doc), "\n") {
+		if !strings.HasPrefix(line, "| `") {
+			continue
+		}
+		cells := splitRow(line)
+		if len(cells) < 3 {
+			continue
+		}
+		name := strings.Trim(cells[0], "`")
+		if !nameRE.MatchString(name) || seen[name] {
+			continue
+		}
+		seen[name] = true
+		def := strings.ReplaceAll(cells[1], "`", "")
+		def = strings.TrimSpace(strings.TrimPrefix(def, "—"))
+		r := row{name: name, def: def}
+		for _, m := range aliasRE.FindAllStringSubmatch(cells[2], -1) {
+			r.aliases = append(r.aliases, m[1])
+		}
+		rows = append(rows, r)
+	}
+	return rows
+}
+
+// splitRow splits a markdown table row into trimmed cells, honouring \|.
+func splitRow(line string) []string {
+	var cells []string
+	var cur strings.Builder
+	for i := 1; i < len(line); i++ {
+		switch {
+		case line[i] == '\\' && i+1 < len(line) && line[i+1] == '|':
+			cur.WriteByte('|')
+			i++
+		case line[i] == '|':
+			cells = append(cells, strings.TrimSpace(cur.String()))
+			cur.Reset()
+		default:
+			cur.WriteByte(line[i])
+		}
+	}
+	return cells
+}
+
+func generate(doc []byte) ([]byte, error) {
+	rows := parse(doc)
+	if len(rows) == 0 {
+		return nil, fmt.Errorf("no variables found")
+	}
+	var b bytes.Buffer
+	b.WriteString("// Code generated by tools/envdoc from docs/environment-variables.md; DO NOT EDIT.\n\n")
+	b.WriteString("package envutil\n\n")
+	b.WriteString("var known = []Var{\n")
+	for _, r := range rows {
+		fmt.Fprintf(&b, "\t{Name: %q", r.name)
+		if r.def != "" {
+			fmt.Fprintf(&b, ", Default: %q", r.def)
+		}
+		if len(r.aliases) > 0 {
+			b.WriteString(", Aliases: []string{")
+			for i, a := range r.aliases {
+				if i > 0 {
+					b.WriteString(", ")
+				}
+				fmt.Fprintf(&b, "%q", a)
+			}
+			b.WriteString("}")
+		}
+		b.WriteString("},\n")
+	}
+	b.WriteString("}\n")
+	return format.Source(b.Bytes())
+}